@@ -0,0 +1,386 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Parallel conversion of DBN files into a Nautilus Parquet data catalog.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Debug,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+};
+
+use chrono::{DateTime, NaiveDate};
+use databento::dbn::{self, decode::DbnMetadata, Schema};
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::{Bar, GetTsInit, OrderBookDelta, OrderBookDepth10, QuoteTick, TradeTick},
+    identifiers::InstrumentId,
+};
+use nautilus_serialization::{arrow::EncodeToRecordBatch, parquet::write_batch_to_parquet};
+
+use super::loader::DatabentoDataLoader;
+
+/// Progress of a DBN to catalog conversion, reported after each file completes.
+#[derive(Clone, Debug)]
+pub struct ConversionProgress {
+    /// The DBN file which was just converted.
+    pub filepath: PathBuf,
+    /// The number of records decoded from the file.
+    pub record_count: usize,
+    /// The Parquet files written for the file.
+    pub written: Vec<PathBuf>,
+    /// The number of files converted so far (including this one).
+    pub files_completed: usize,
+    /// The total number of files to convert.
+    pub files_total: usize,
+}
+
+/// A callback invoked from worker threads as each DBN file completes.
+pub type ProgressCallback = Arc<dyn Fn(&ConversionProgress) + Send + Sync>;
+
+/// Configuration for converting DBN files into a Nautilus Parquet data catalog.
+#[derive(Clone)]
+pub struct DbnCatalogConfig {
+    /// The catalog root path, data is written under `{output_path}/data`.
+    pub output_path: PathBuf,
+    /// The number of worker threads (defaults to the available parallelism).
+    pub num_workers: Option<usize>,
+    /// The instrument ID to assign to all records (otherwise decoded from symbology metadata).
+    pub instrument_id: Option<InstrumentId>,
+    /// The price precision for decoded records (defaults to USD precision).
+    pub price_precision: Option<u8>,
+    /// The optional callback to report progress as each file completes.
+    pub progress: Option<ProgressCallback>,
+}
+
+impl Debug for DbnCatalogConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(DbnCatalogConfig))
+            .field("output_path", &self.output_path)
+            .field("num_workers", &self.num_workers)
+            .field("instrument_id", &self.instrument_id)
+            .field("price_precision", &self.price_precision)
+            .field("progress", &self.progress.is_some())
+            .finish()
+    }
+}
+
+impl DbnCatalogConfig {
+    /// Creates a new [`DbnCatalogConfig`] instance with defaults for the given `output_path`.
+    #[must_use]
+    pub const fn new(output_path: PathBuf) -> Self {
+        Self {
+            output_path,
+            num_workers: None,
+            instrument_id: None,
+            price_precision: None,
+            progress: None,
+        }
+    }
+}
+
+/// Converts the DBN files at `filepaths` into Parquet files within a Nautilus data catalog.
+///
+/// Files are distributed across a pool of worker threads, each file being decoded according
+/// to the schema in its metadata. The decoded data is partitioned by instrument (or bar type)
+/// and UTC date of `ts_init`, with each partition written to
+/// `{output_path}/data/{type}/{instrument_id}/{date}-{file_stem}.parquet`.
+///
+/// Returns the paths of all Parquet files written, sorted.
+///
+/// # Errors
+///
+/// Returns an error if any file cannot be decoded or written, or has an unsupported schema.
+pub fn convert_dbn_to_catalog(
+    loader: &DatabentoDataLoader,
+    filepaths: &[PathBuf],
+    config: &DbnCatalogConfig,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let files_total = filepaths.len();
+    let num_workers = config
+        .num_workers
+        .unwrap_or_else(|| thread::available_parallelism().map_or(1, usize::from))
+        .clamp(1, files_total.max(1));
+
+    let next_index = AtomicUsize::new(0);
+    let files_completed = AtomicUsize::new(0);
+    let written = Mutex::new(Vec::new());
+    let errors = Mutex::new(Vec::new());
+
+    tracing::info!("Converting {files_total} DBN file(s) using {num_workers} worker(s)");
+
+    thread::scope(|s| {
+        for _ in 0..num_workers {
+            s.spawn(|| loop {
+                let index = next_index.fetch_add(1, Ordering::Relaxed);
+                let Some(filepath) = filepaths.get(index) else {
+                    break;
+                };
+
+                match convert_file(loader, filepath, config) {
+                    Ok((record_count, paths)) => {
+                        let files_completed = files_completed.fetch_add(1, Ordering::Relaxed) + 1;
+                        if let Some(progress) = &config.progress {
+                            progress(&ConversionProgress {
+                                filepath: filepath.clone(),
+                                record_count,
+                                written: paths.clone(),
+                                files_completed,
+                                files_total,
+                            });
+                        }
+                        written.lock().unwrap().extend(paths);
+                    }
+                    Err(e) => {
+                        tracing::error!("Error converting {}: {e}", filepath.display());
+                        errors
+                            .lock()
+                            .unwrap()
+                            .push(format!("{}: {e}", filepath.display()));
+                    }
+                }
+            });
+        }
+    });
+
+    let errors = errors.into_inner().unwrap();
+    if !errors.is_empty() {
+        anyhow::bail!(
+            "Failed to convert {} of {files_total} file(s): {}",
+            errors.len(),
+            errors.join("; ")
+        );
+    }
+
+    let mut written = written.into_inner().unwrap();
+    written.sort();
+    Ok(written)
+}
+
+fn convert_file(
+    loader: &DatabentoDataLoader,
+    filepath: &Path,
+    config: &DbnCatalogConfig,
+) -> anyhow::Result<(usize, Vec<PathBuf>)> {
+    let decoder = dbn::decode::dbn::Decoder::from_zstd_file(filepath)?;
+    let schema = decoder
+        .metadata()
+        .schema
+        .ok_or_else(|| anyhow::anyhow!("DBN metadata has no schema"))?;
+    drop(decoder);
+
+    let file_stem = file_stem(filepath);
+    let instrument_id = config.instrument_id;
+    let precision = config.price_precision;
+    let path = config.output_path.join("data");
+
+    match schema {
+        Schema::Mbo => {
+            let data = loader.load_order_book_deltas(filepath, instrument_id, precision)?;
+            write_partitioned(data, &path, &file_stem, |d| d.instrument_id.to_string())
+        }
+        Schema::Mbp1 | Schema::Bbo1S | Schema::Bbo1M => {
+            let data = match schema {
+                Schema::Mbp1 => loader.load_quotes(filepath, instrument_id, precision)?,
+                _ => loader.load_bbo_quotes(filepath, instrument_id, precision)?,
+            };
+            write_partitioned(data, &path, &file_stem, |q| q.instrument_id.to_string())
+        }
+        Schema::Mbp10 => {
+            let data = loader.load_order_book_depth10(filepath, instrument_id, precision)?;
+            write_partitioned(data, &path, &file_stem, |d| d.instrument_id.to_string())
+        }
+        Schema::Trades => {
+            let data = loader.load_trades(filepath, instrument_id, precision)?;
+            write_partitioned(data, &path, &file_stem, |t| t.instrument_id.to_string())
+        }
+        Schema::Tbbo => {
+            let data = loader.load_tbbo_trades(filepath, instrument_id, precision)?;
+            write_partitioned(data, &path, &file_stem, |t| t.instrument_id.to_string())
+        }
+        Schema::Ohlcv1S | Schema::Ohlcv1M | Schema::Ohlcv1H | Schema::Ohlcv1D => {
+            let data = loader.load_bars(filepath, instrument_id, precision)?;
+            write_partitioned(data, &path, &file_stem, |b| b.bar_type.to_string())
+        }
+        _ => anyhow::bail!("Unsupported schema for catalog conversion: {schema}"),
+    }
+}
+
+fn write_partitioned<T, F>(
+    data: Vec<T>,
+    path: &Path,
+    file_stem: &str,
+    partition_key: F,
+) -> anyhow::Result<(usize, Vec<PathBuf>)>
+where
+    T: GetTsInit + EncodeToRecordBatch + CatalogTypename,
+    F: Fn(&T) -> String,
+{
+    let record_count = data.len();
+
+    // BTreeMap keeps partitions (and so the written files) in a deterministic order
+    let mut partitions: BTreeMap<(String, NaiveDate), Vec<T>> = BTreeMap::new();
+    for item in data {
+        let key = (partition_key(&item), date_utc(item.ts_init()));
+        partitions.entry(key).or_default().push(item);
+    }
+
+    let mut written = Vec::with_capacity(partitions.len());
+    for ((key, date), mut chunk) in partitions {
+        chunk.sort_by_key(GetTsInit::ts_init);
+        let metadata = T::chunk_metadata(&chunk);
+        let batch = T::encode_batch(&metadata, &chunk)?;
+        let filepath = path.join(parquet_filepath(T::typename(), &key, date, file_stem));
+        write_batch_to_parquet(batch, &filepath, None)
+            .map_err(|e| anyhow::anyhow!("Error writing {}: {e}", filepath.display()))?;
+        tracing::debug!("File written: {}", filepath.display());
+        written.push(filepath);
+    }
+
+    Ok((record_count, written))
+}
+
+fn date_utc(ts: UnixNanos) -> NaiveDate {
+    DateTime::from_timestamp_nanos(ts.as_i64()).date_naive()
+}
+
+fn file_stem(filepath: &Path) -> String {
+    // Strip all extensions e.g. `glbx-mdp3-20240101.mbp-1.dbn.zst` -> `glbx-mdp3-20240101`
+    let name = filepath
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    name.split('.').next().unwrap_or_default().to_string()
+}
+
+fn parquet_filepath(typename: &str, key: &str, date: NaiveDate, file_stem: &str) -> PathBuf {
+    let key = key.replace('/', "");
+    let date_str = date.to_string().replace('-', "");
+    PathBuf::new()
+        .join(typename)
+        .join(key)
+        .join(format!("{date_str}-{file_stem}.parquet"))
+}
+
+/// Provides the catalog directory name for a data type.
+trait CatalogTypename {
+    fn typename() -> &'static str;
+}
+
+macro_rules! impl_catalog_typename {
+    ($type:ty, $name:expr) => {
+        impl CatalogTypename for $type {
+            fn typename() -> &'static str {
+                $name
+            }
+        }
+    };
+}
+
+impl_catalog_typename!(OrderBookDelta, "order_book_delta");
+impl_catalog_typename!(OrderBookDepth10, "order_book_depth10");
+impl_catalog_typename!(QuoteTick, "quote_tick");
+impl_catalog_typename!(TradeTick, "trade_tick");
+impl_catalog_typename!(Bar, "bar");
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use rstest::*;
+
+    use super::*;
+
+    fn test_data_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("test_data")
+    }
+
+    fn data_loader() -> DatabentoDataLoader {
+        let publishers_filepath = Path::new(env!("CARGO_MANIFEST_DIR")).join("publishers.json");
+        DatabentoDataLoader::new(Some(publishers_filepath)).unwrap()
+    }
+
+    #[rstest]
+    #[case("glbx-mdp3-20240101.mbp-1.dbn.zst", "glbx-mdp3-20240101")]
+    #[case("test_data.trades.dbn", "test_data")]
+    #[case("data", "data")]
+    fn test_file_stem(#[case] filename: &str, #[case] expected: &str) {
+        assert_eq!(file_stem(Path::new(filename)), expected);
+    }
+
+    #[rstest]
+    fn test_parquet_filepath() {
+        let date = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+        let path = parquet_filepath("quote_tick", "ESM4.GLBX", date, "test_data");
+        assert_eq!(
+            path,
+            PathBuf::from("quote_tick/ESM4.GLBX/20240601-test_data.parquet")
+        );
+    }
+
+    #[rstest]
+    fn test_convert_dbn_to_catalog() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let filepaths = vec![
+            test_data_path().join("test_data.mbp-1.dbn.zst"),
+            test_data_path().join("test_data.trades.dbn.zst"),
+            test_data_path().join("test_data.ohlcv-1m.dbn.zst"),
+        ];
+        let progress_events = Arc::new(Mutex::new(Vec::new()));
+        let events = progress_events.clone();
+
+        let mut config = DbnCatalogConfig::new(temp_dir.path().to_path_buf());
+        config.num_workers = Some(2);
+        config.instrument_id = Some(InstrumentId::from("ESM4.GLBX"));
+        config.progress = Some(Arc::new(move |p: &ConversionProgress| {
+            events.lock().unwrap().push(p.clone());
+        }));
+
+        let written = convert_dbn_to_catalog(&data_loader(), &filepaths, &config).unwrap();
+
+        assert_eq!(written.len(), 3);
+        assert!(written.iter().all(|p| p.exists()));
+        assert!(written
+            .iter()
+            .all(|p| p.starts_with(temp_dir.path().join("data"))));
+
+        let events = progress_events.lock().unwrap();
+        assert_eq!(events.len(), 3);
+        assert!(events.iter().all(|e| e.files_total == 3));
+        assert!(events.iter().all(|e| e.record_count == 2));
+        let mut completed: Vec<usize> = events.iter().map(|e| e.files_completed).collect();
+        completed.sort_unstable();
+        assert_eq!(completed, vec![1, 2, 3]);
+    }
+
+    #[rstest]
+    fn test_convert_dbn_to_catalog_unsupported_schema() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let filepaths = vec![test_data_path().join("test_data.status.dbn.zst")];
+        let config = DbnCatalogConfig::new(temp_dir.path().to_path_buf());
+
+        let result = convert_dbn_to_catalog(&data_loader(), &filepaths, &config);
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,474 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Consolidation of top-of-book quotes from multiple venues into a national best bid and offer.
+
+use indexmap::IndexMap;
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::QuoteTick,
+    identifiers::{InstrumentId, Venue},
+    types::{Price, Quantity},
+};
+
+/// Represents a consolidated best bid and offer (BBO/NBBO) with venue attribution.
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.databento")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConsolidatedQuote {
+    /// The consolidated quote for the output instrument ID.
+    pub quote: QuoteTick,
+    /// The venue at the best bid (earliest quote at the best price).
+    pub bid_venue: Venue,
+    /// The venue at the best ask (earliest quote at the best price).
+    pub ask_venue: Venue,
+    /// The number of venues quoting at the best bid.
+    pub bid_venue_count: u32,
+    /// The number of venues quoting at the best ask.
+    pub ask_venue_count: u32,
+}
+
+impl ConsolidatedQuote {
+    /// Returns whether the consolidated market is locked (best bid equals best ask).
+    #[must_use]
+    pub fn is_locked(&self) -> bool {
+        self.quote.bid_price == self.quote.ask_price
+    }
+
+    /// Returns whether the consolidated market is crossed (best bid above best ask).
+    #[must_use]
+    pub fn is_crossed(&self) -> bool {
+        self.quote.bid_price > self.quote.ask_price
+    }
+}
+
+/// Merges MBP-1 (top-of-book) quotes from multiple venues for the same instrument into a
+/// consolidated best bid and offer stream.
+///
+/// Each venue's latest quote is retained, and on every update the best bid (highest price)
+/// and best ask (lowest price) are selected across all venues. Sizes at the best price are
+/// aggregated across venues, and the venue with the earliest quote at the best price is
+/// attributed. A side with zero size is treated as absent for that venue.
+///
+/// The consolidator is independent of the data source, so it can be driven from a live
+/// client or from historical data in a backtest.
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.databento")
+)]
+#[derive(Debug)]
+pub struct NbboConsolidator {
+    instrument_id: InstrumentId,
+    stale_timeout_ns: Option<u64>,
+    venue_quotes: IndexMap<Venue, QuoteTick>,
+    last: Option<ConsolidatedQuote>,
+}
+
+impl NbboConsolidator {
+    /// Creates a new [`NbboConsolidator`] instance.
+    ///
+    /// The `instrument_id` is assigned to the consolidated quotes, and venue quotes are
+    /// matched on its symbol. If a `stale_timeout_ns` is given, venue quotes older than the
+    /// timeout (relative to the latest update) are excluded from the consolidation.
+    #[must_use]
+    pub fn new(instrument_id: InstrumentId, stale_timeout_ns: Option<u64>) -> Self {
+        Self {
+            instrument_id,
+            stale_timeout_ns,
+            venue_quotes: IndexMap::new(),
+            last: None,
+        }
+    }
+
+    /// Returns the output instrument ID for the consolidator.
+    #[must_use]
+    pub const fn instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+
+    /// Returns the venues which currently have a quote held by the consolidator.
+    #[must_use]
+    pub fn venues(&self) -> Vec<Venue> {
+        self.venue_quotes.keys().copied().collect()
+    }
+
+    /// Returns the latest quote for the given `venue` (if found).
+    #[must_use]
+    pub fn venue_quote(&self, venue: &Venue) -> Option<&QuoteTick> {
+        self.venue_quotes.get(venue)
+    }
+
+    /// Returns the last consolidated quote (if any).
+    #[must_use]
+    pub const fn last(&self) -> Option<&ConsolidatedQuote> {
+        self.last.as_ref()
+    }
+
+    /// Handles the given venue `quote`, returning a new consolidated quote if the
+    /// consolidated market changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the quote's symbol does not match the consolidator's instrument.
+    pub fn handle_quote(&mut self, quote: &QuoteTick) -> anyhow::Result<Option<ConsolidatedQuote>> {
+        anyhow::ensure!(
+            quote.instrument_id.symbol == self.instrument_id.symbol,
+            "Quote symbol {} does not match consolidator symbol {}",
+            quote.instrument_id.symbol,
+            self.instrument_id.symbol,
+        );
+
+        self.venue_quotes.insert(quote.instrument_id.venue, *quote);
+        Ok(self.consolidate(quote.ts_event, quote.ts_init))
+    }
+
+    /// Removes the quote for the given `venue` (e.g. on venue disconnect or halt), returning a
+    /// new consolidated quote if the consolidated market changed.
+    pub fn remove_venue(&mut self, venue: &Venue, ts: UnixNanos) -> Option<ConsolidatedQuote> {
+        self.venue_quotes.shift_remove(venue)?;
+        self.consolidate(ts, ts)
+    }
+
+    /// Resets the consolidator, clearing all venue quotes.
+    pub fn reset(&mut self) {
+        self.venue_quotes.clear();
+        self.last = None;
+    }
+
+    fn consolidate(
+        &mut self,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Option<ConsolidatedQuote> {
+        let is_live = |quote: &QuoteTick| match self.stale_timeout_ns {
+            Some(timeout) => ts_event.as_u64().saturating_sub(quote.ts_event.as_u64()) <= timeout,
+            None => true,
+        };
+
+        let mut best_bid: Option<BestSide> = None;
+        let mut best_ask: Option<BestSide> = None;
+
+        for (venue, quote) in self.venue_quotes.iter().filter(|(_, q)| is_live(q)) {
+            if quote.bid_size.is_positive() {
+                update_side(
+                    &mut best_bid,
+                    *venue,
+                    quote,
+                    quote.bid_price,
+                    quote.bid_size,
+                    |a, b| a > b,
+                );
+            }
+            if quote.ask_size.is_positive() {
+                update_side(
+                    &mut best_ask,
+                    *venue,
+                    quote,
+                    quote.ask_price,
+                    quote.ask_size,
+                    |a, b| a < b,
+                );
+            }
+        }
+
+        let (Some(bid), Some(ask)) = (best_bid, best_ask) else {
+            // No two-sided market can be formed
+            self.last = None;
+            return None;
+        };
+
+        let consolidated = ConsolidatedQuote {
+            quote: QuoteTick::new(
+                self.instrument_id,
+                bid.price,
+                ask.price,
+                bid.size,
+                ask.size,
+                ts_event,
+                ts_init,
+            ),
+            bid_venue: bid.venue,
+            ask_venue: ask.venue,
+            bid_venue_count: bid.venue_count,
+            ask_venue_count: ask.venue_count,
+        };
+
+        if self
+            .last
+            .is_some_and(|last| is_same_market(&last, &consolidated))
+        {
+            return None;
+        }
+
+        self.last = Some(consolidated);
+        Some(consolidated)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct BestSide {
+    venue: Venue,
+    price: Price,
+    size: Quantity,
+    ts_event: UnixNanos,
+    venue_count: u32,
+}
+
+fn update_side(
+    best: &mut Option<BestSide>,
+    venue: Venue,
+    quote: &QuoteTick,
+    price: Price,
+    size: Quantity,
+    is_better: impl Fn(Price, Price) -> bool,
+) {
+    match best {
+        Some(current) if price == current.price => {
+            current.size += size;
+            current.venue_count += 1;
+            if quote.ts_event < current.ts_event {
+                current.venue = venue;
+                current.ts_event = quote.ts_event;
+            }
+        }
+        Some(current) if !is_better(price, current.price) => {}
+        _ => {
+            *best = Some(BestSide {
+                venue,
+                price,
+                size,
+                ts_event: quote.ts_event,
+                venue_count: 1,
+            });
+        }
+    }
+}
+
+fn is_same_market(a: &ConsolidatedQuote, b: &ConsolidatedQuote) -> bool {
+    a.quote.bid_price == b.quote.bid_price
+        && a.quote.ask_price == b.quote.ask_price
+        && a.quote.bid_size == b.quote.bid_size
+        && a.quote.ask_size == b.quote.ask_size
+        && a.bid_venue == b.bid_venue
+        && a.ask_venue == b.ask_venue
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+
+    use super::*;
+
+    fn quote(
+        instrument_id: &str,
+        bid: &str,
+        ask: &str,
+        bid_size: &str,
+        ask_size: &str,
+        ts: u64,
+    ) -> QuoteTick {
+        QuoteTick::new(
+            InstrumentId::from(instrument_id),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(bid_size),
+            Quantity::from(ask_size),
+            ts.into(),
+            ts.into(),
+        )
+    }
+
+    fn consolidator(stale_timeout_ns: Option<u64>) -> NbboConsolidator {
+        NbboConsolidator::new(InstrumentId::from("AAPL.NBBO"), stale_timeout_ns)
+    }
+
+    #[rstest]
+    fn test_single_venue_passes_through() {
+        let mut consolidator = consolidator(None);
+
+        let result = consolidator
+            .handle_quote(&quote("AAPL.XNAS", "100.00", "100.02", "100", "200", 1))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(result.quote.instrument_id, InstrumentId::from("AAPL.NBBO"));
+        assert_eq!(result.quote.bid_price, Price::from("100.00"));
+        assert_eq!(result.quote.ask_price, Price::from("100.02"));
+        assert_eq!(result.bid_venue, Venue::from("XNAS"));
+        assert_eq!(result.ask_venue, Venue::from("XNAS"));
+        assert!(!result.is_locked());
+        assert!(!result.is_crossed());
+    }
+
+    #[rstest]
+    fn test_best_prices_across_venues_with_attribution() {
+        let mut consolidator = consolidator(None);
+        consolidator
+            .handle_quote(&quote("AAPL.XNAS", "100.00", "100.03", "100", "200", 1))
+            .unwrap();
+
+        let result = consolidator
+            .handle_quote(&quote("AAPL.ARCX", "99.99", "100.02", "300", "400", 2))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(result.quote.bid_price, Price::from("100.00"));
+        assert_eq!(result.quote.bid_size, Quantity::from("100"));
+        assert_eq!(result.bid_venue, Venue::from("XNAS"));
+        assert_eq!(result.quote.ask_price, Price::from("100.02"));
+        assert_eq!(result.quote.ask_size, Quantity::from("400"));
+        assert_eq!(result.ask_venue, Venue::from("ARCX"));
+        assert_eq!(consolidator.venues().len(), 2);
+    }
+
+    #[rstest]
+    fn test_sizes_aggregate_at_same_price_with_earliest_venue_attributed() {
+        let mut consolidator = consolidator(None);
+        consolidator
+            .handle_quote(&quote("AAPL.ARCX", "100.00", "100.02", "300", "400", 5))
+            .unwrap();
+
+        let result = consolidator
+            .handle_quote(&quote("AAPL.XNAS", "100.00", "100.02", "100", "200", 6))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(result.quote.bid_size, Quantity::from("400"));
+        assert_eq!(result.quote.ask_size, Quantity::from("600"));
+        assert_eq!(result.bid_venue, Venue::from("ARCX"));
+        assert_eq!(result.ask_venue, Venue::from("ARCX"));
+        assert_eq!(result.bid_venue_count, 2);
+        assert_eq!(result.ask_venue_count, 2);
+    }
+
+    #[rstest]
+    fn test_unchanged_market_emits_nothing() {
+        let mut consolidator = consolidator(None);
+        consolidator
+            .handle_quote(&quote("AAPL.XNAS", "100.00", "100.02", "100", "200", 1))
+            .unwrap();
+
+        // Inferior quote from another venue does not change the market
+        let result = consolidator
+            .handle_quote(&quote("AAPL.ARCX", "99.90", "100.10", "100", "200", 2))
+            .unwrap();
+
+        assert!(result.is_none());
+        assert_eq!(
+            consolidator.last().unwrap().quote.bid_price,
+            Price::from("100.00")
+        );
+    }
+
+    #[rstest]
+    fn test_zero_size_side_is_excluded() {
+        let mut consolidator = consolidator(None);
+        consolidator
+            .handle_quote(&quote("AAPL.XNAS", "100.05", "100.06", "0", "200", 1))
+            .unwrap();
+
+        // No bid on any venue yet
+        assert!(consolidator.last().is_none());
+
+        let result = consolidator
+            .handle_quote(&quote("AAPL.ARCX", "100.00", "100.10", "100", "200", 2))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(result.quote.bid_price, Price::from("100.00"));
+        assert_eq!(result.bid_venue, Venue::from("ARCX"));
+        assert_eq!(result.quote.ask_price, Price::from("100.06"));
+        assert_eq!(result.ask_venue, Venue::from("XNAS"));
+    }
+
+    #[rstest]
+    fn test_stale_venue_quotes_excluded() {
+        let mut consolidator = consolidator(Some(10));
+        consolidator
+            .handle_quote(&quote("AAPL.XNAS", "100.01", "100.02", "100", "200", 1))
+            .unwrap();
+
+        let result = consolidator
+            .handle_quote(&quote("AAPL.ARCX", "100.00", "100.03", "100", "200", 20))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(result.quote.bid_price, Price::from("100.00"));
+        assert_eq!(result.bid_venue, Venue::from("ARCX"));
+    }
+
+    #[rstest]
+    fn test_crossed_market() {
+        let mut consolidator = consolidator(None);
+        consolidator
+            .handle_quote(&quote("AAPL.XNAS", "100.05", "100.06", "100", "200", 1))
+            .unwrap();
+
+        let result = consolidator
+            .handle_quote(&quote("AAPL.ARCX", "100.00", "100.04", "100", "200", 2))
+            .unwrap()
+            .unwrap();
+
+        assert!(result.is_crossed());
+    }
+
+    #[rstest]
+    fn test_remove_venue() {
+        let mut consolidator = consolidator(None);
+        consolidator
+            .handle_quote(&quote("AAPL.XNAS", "100.01", "100.02", "100", "200", 1))
+            .unwrap();
+        consolidator
+            .handle_quote(&quote("AAPL.ARCX", "100.00", "100.03", "100", "200", 2))
+            .unwrap();
+
+        let result = consolidator
+            .remove_venue(&Venue::from("XNAS"), 3.into())
+            .unwrap();
+
+        assert_eq!(result.quote.bid_price, Price::from("100.00"));
+        assert_eq!(result.quote.ask_price, Price::from("100.03"));
+        assert_eq!(result.bid_venue, Venue::from("ARCX"));
+        assert!(consolidator.venue_quote(&Venue::from("XNAS")).is_none());
+    }
+
+    #[rstest]
+    fn test_mismatched_symbol_errors() {
+        let mut consolidator = consolidator(None);
+
+        let result =
+            consolidator.handle_quote(&quote("MSFT.XNAS", "100.00", "100.02", "100", "200", 1));
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut consolidator = consolidator(None);
+        consolidator
+            .handle_quote(&quote("AAPL.XNAS", "100.00", "100.02", "100", "200", 1))
+            .unwrap();
+
+        consolidator.reset();
+
+        assert!(consolidator.venues().is_empty());
+        assert!(consolidator.last().is_none());
+    }
+}
@@ -438,6 +438,9 @@ pub fn decode_option_contract_v1(
         None, // TBD
         None, // TBD
         None, // TBD
+        None, // TBD
+        None, // TBD
+        None, // TBD
         ts_event,
         ts_init,
     )
@@ -1101,6 +1104,9 @@ pub fn decode_option_contract(
         None, // TBD
         None, // TBD
         None, // TBD
+        None, // TBD
+        None, // TBD
+        None, // TBD
         ts_event,
         ts_init,
     )
@@ -21,7 +21,9 @@
 #![deny(rustdoc::broken_intra_doc_links)]
 // #![deny(clippy::missing_errors_doc)]
 
+pub mod catalog;
 pub mod common;
+pub mod consolidation;
 pub mod decode;
 pub mod enums;
 pub mod live;
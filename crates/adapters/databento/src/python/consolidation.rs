@@ -0,0 +1,124 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::python::to_pyvalue_err;
+use nautilus_model::{
+    data::QuoteTick,
+    identifiers::{InstrumentId, Venue},
+};
+use pyo3::prelude::*;
+
+use crate::consolidation::{ConsolidatedQuote, NbboConsolidator};
+
+#[pymethods]
+impl ConsolidatedQuote {
+    fn __repr__(&self) -> String {
+        format!(
+            "{}(quote={}, bid_venue={}, ask_venue={}, bid_venue_count={}, ask_venue_count={})",
+            stringify!(ConsolidatedQuote),
+            self.quote,
+            self.bid_venue,
+            self.ask_venue,
+            self.bid_venue_count,
+            self.ask_venue_count,
+        )
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
+    }
+
+    #[getter]
+    #[pyo3(name = "quote")]
+    const fn py_quote(&self) -> QuoteTick {
+        self.quote
+    }
+
+    #[getter]
+    #[pyo3(name = "bid_venue")]
+    const fn py_bid_venue(&self) -> Venue {
+        self.bid_venue
+    }
+
+    #[getter]
+    #[pyo3(name = "ask_venue")]
+    const fn py_ask_venue(&self) -> Venue {
+        self.ask_venue
+    }
+
+    #[getter]
+    #[pyo3(name = "bid_venue_count")]
+    const fn py_bid_venue_count(&self) -> u32 {
+        self.bid_venue_count
+    }
+
+    #[getter]
+    #[pyo3(name = "ask_venue_count")]
+    const fn py_ask_venue_count(&self) -> u32 {
+        self.ask_venue_count
+    }
+
+    #[getter]
+    #[pyo3(name = "is_locked")]
+    fn py_is_locked(&self) -> bool {
+        self.is_locked()
+    }
+
+    #[getter]
+    #[pyo3(name = "is_crossed")]
+    fn py_is_crossed(&self) -> bool {
+        self.is_crossed()
+    }
+}
+
+#[pymethods]
+impl NbboConsolidator {
+    #[new]
+    #[pyo3(signature = (instrument_id, stale_timeout_ns=None))]
+    fn py_new(instrument_id: InstrumentId, stale_timeout_ns: Option<u64>) -> Self {
+        Self::new(instrument_id, stale_timeout_ns)
+    }
+
+    #[getter]
+    #[pyo3(name = "instrument_id")]
+    const fn py_instrument_id(&self) -> InstrumentId {
+        self.instrument_id()
+    }
+
+    #[pyo3(name = "venues")]
+    fn py_venues(&self) -> Vec<Venue> {
+        self.venues()
+    }
+
+    #[pyo3(name = "last")]
+    fn py_last(&self) -> Option<ConsolidatedQuote> {
+        self.last().copied()
+    }
+
+    #[pyo3(name = "handle_quote")]
+    fn py_handle_quote(&mut self, quote: QuoteTick) -> PyResult<Option<ConsolidatedQuote>> {
+        self.handle_quote(&quote).map_err(to_pyvalue_err)
+    }
+
+    #[pyo3(name = "remove_venue")]
+    fn py_remove_venue(&mut self, venue: Venue, ts: u64) -> Option<ConsolidatedQuote> {
+        self.remove_venue(&venue, ts.into())
+    }
+
+    #[pyo3(name = "reset")]
+    fn py_reset(&mut self) {
+        self.reset();
+    }
+}
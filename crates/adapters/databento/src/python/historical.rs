@@ -22,9 +22,10 @@ use std::{
     sync::{Arc, RwLock},
 };
 
+use chrono::{DateTime, Days};
 use databento::{
     dbn::{self},
-    historical::timeseries::GetRangeParams,
+    historical::{symbology::ResolveParams, timeseries::GetRangeParams},
 };
 use indexmap::IndexMap;
 use nautilus_core::{
@@ -54,7 +55,7 @@ use crate::{
     },
     symbology::{
         check_consistent_symbology, decode_nautilus_instrument_id, infer_symbology_type,
-        instrument_id_to_symbol_string,
+        instrument_id_to_symbol_string, naive_date_to_time, DatabentoSymbologyMap,
     },
     types::{DatabentoImbalance, DatabentoPublisher, DatabentoStatistics, PublisherId},
 };
@@ -71,12 +72,19 @@ pub struct DatabentoHistoricalClient {
     inner: Arc<Mutex<databento::HistoricalClient>>,
     publisher_venue_map: Arc<IndexMap<PublisherId, Venue>>,
     symbol_venue_map: Arc<RwLock<HashMap<Symbol, Venue>>>,
+    symbology_map: Arc<RwLock<DatabentoSymbologyMap>>,
+    symbology_cache_path: Option<PathBuf>,
 }
 
 #[pymethods]
 impl DatabentoHistoricalClient {
     #[new]
-    fn py_new(key: String, publishers_filepath: PathBuf) -> PyResult<Self> {
+    #[pyo3(signature = (key, publishers_filepath, symbology_cache_path=None))]
+    fn py_new(
+        key: String,
+        publishers_filepath: PathBuf,
+        symbology_cache_path: Option<PathBuf>,
+    ) -> PyResult<Self> {
         let client = databento::HistoricalClient::builder()
             .key(key.clone())
             .map_err(to_pyvalue_err)?
@@ -92,15 +100,125 @@ impl DatabentoHistoricalClient {
             .map(|p| (p.publisher_id, Venue::from(p.venue.as_str())))
             .collect::<IndexMap<u16, Venue>>();
 
+        let symbology_map = match &symbology_cache_path {
+            Some(path) => DatabentoSymbologyMap::from_file(path).map_err(to_pyvalue_err)?,
+            None => DatabentoSymbologyMap::new(),
+        };
+
         Ok(Self {
             clock: get_atomic_clock_realtime(),
             inner: Arc::new(Mutex::new(client)),
             publisher_venue_map: Arc::new(publisher_venue_map),
             symbol_venue_map: Arc::new(RwLock::new(HashMap::new())),
+            symbology_map: Arc::new(RwLock::new(symbology_map)),
+            symbology_cache_path,
             key,
         })
     }
 
+    #[pyo3(name = "resolve_symbology")]
+    #[pyo3(signature = (dataset, symbols, start, end=None))]
+    fn py_resolve_symbology<'py>(
+        &self,
+        py: Python<'py>,
+        dataset: String,
+        symbols: Vec<String>,
+        start: u64,
+        end: Option<u64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let symbols_ref: Vec<&str> = symbols.iter().map(String::as_str).collect();
+        check_consistent_symbology(symbols_ref.as_slice()).map_err(to_pyvalue_err)?;
+        let stype_in = infer_symbology_type(symbols_ref.first().unwrap());
+
+        // Resolve whole UTC days, with the end date exclusive
+        let end = end.unwrap_or(self.clock.get_time_ns().as_u64());
+        let start_date = DateTime::from_timestamp_nanos(start as i64).date_naive();
+        let end_date = DateTime::from_timestamp_nanos(end as i64)
+            .date_naive()
+            .checked_add_days(Days::new(1))
+            .ok_or_else(|| to_pyvalue_err("Invalid `end`"))?;
+
+        // Group the symbols by each date range not already held in the map
+        let mut unresolved: IndexMap<_, Vec<String>> = IndexMap::new();
+        {
+            let symbology_map = self.symbology_map.read().unwrap();
+            for symbol in &symbols {
+                for range in symbology_map.unresolved_ranges(&dataset, symbol, start_date, end_date)
+                {
+                    unresolved.entry(range).or_default().push(symbol.clone());
+                }
+            }
+        }
+
+        let client = self.inner.clone();
+        let symbology_map = self.symbology_map.clone();
+        let symbology_cache_path = self.symbology_cache_path.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            if !unresolved.is_empty() {
+                let mut client = client.lock().await; // TODO: Use a client pool
+                for ((range_start, range_end), range_symbols) in unresolved {
+                    tracing::debug!(
+                        "Resolving {} symbol(s) for {dataset} from {range_start} to {range_end}",
+                        range_symbols.len()
+                    );
+                    let date_range = (
+                        naive_date_to_time(range_start).map_err(to_pyvalue_err)?,
+                        naive_date_to_time(range_end).map_err(to_pyvalue_err)?,
+                    );
+                    let params = ResolveParams::builder()
+                        .dataset(dataset.clone())
+                        .symbols(range_symbols)
+                        .stype_in(stype_in)
+                        .stype_out(dbn::SType::InstrumentId)
+                        .date_range(date_range)
+                        .build();
+                    let resolution = client
+                        .symbology()
+                        .resolve(&params)
+                        .await
+                        .map_err(to_pyvalue_err)?;
+
+                    symbology_map.write().unwrap().insert_resolution(
+                        &dataset,
+                        range_start,
+                        range_end,
+                        &resolution,
+                    );
+                }
+
+                if let Some(path) = symbology_cache_path {
+                    symbology_map
+                        .read()
+                        .unwrap()
+                        .to_file(&path)
+                        .map_err(to_pyvalue_err)?;
+                }
+            }
+
+            let symbology_map = symbology_map.read().unwrap();
+            Python::with_gil(|py| {
+                let dict = PyDict::new(py);
+                for symbol in &symbols {
+                    let intervals = symbology_map
+                        .intervals(&dataset, symbol)
+                        .iter()
+                        .filter(|i| i.start_date < end_date && i.end_date > start_date)
+                        .map(|i| {
+                            (
+                                i.start_date.to_string(),
+                                i.end_date.to_string(),
+                                i.symbol.clone(),
+                            )
+                        })
+                        .collect::<Vec<_>>();
+                    dict.set_item(symbol, intervals)?;
+                }
+                dict.into_py_any(py)
+            })
+        })
+    }
+
     #[pyo3(name = "get_dataset_range")]
     fn py_get_dataset_range<'py>(
         &self,
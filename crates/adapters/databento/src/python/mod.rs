@@ -15,6 +15,7 @@
 
 //! Python bindings from `pyo3`.
 
+pub mod consolidation;
 pub mod enums;
 pub mod historical;
 pub mod live;
@@ -36,6 +37,8 @@ pub fn databento(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<super::types::DatabentoStatistics>()?;
     m.add_class::<super::types::DatabentoImbalance>()?;
     m.add_class::<super::loader::DatabentoDataLoader>()?;
+    m.add_class::<super::consolidation::ConsolidatedQuote>()?;
+    m.add_class::<super::consolidation::NbboConsolidator>()?;
     m.add_class::<live::DatabentoLiveClient>()?;
     m.add_class::<historical::DatabentoHistoricalClient>()?;
     Ok(())
@@ -13,13 +13,22 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::collections::HashMap;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::Path,
+};
 
-use databento::dbn::{self, SType};
-use dbn::{Publisher, Record};
+use chrono::NaiveDate;
+use databento::{
+    dbn::{self, SType},
+    historical::symbology::Resolution,
+};
+use dbn::{MappingInterval, Publisher, Record};
 use indexmap::IndexMap;
 use nautilus_core::correctness::check_slice_not_empty;
 use nautilus_model::identifiers::{InstrumentId, Symbol, Venue};
+use serde::{Deserialize, Serialize};
 
 use super::types::PublisherId;
 
@@ -150,6 +159,288 @@ pub fn check_consistent_symbology(symbols: &[&str]) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// A resolved symbol for the half-open UTC date range `[start_date, end_date)`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SymbologyInterval {
+    /// The UTC start date of the interval (inclusive).
+    pub start_date: NaiveDate,
+    /// The UTC end date of the interval (exclusive).
+    pub end_date: NaiveDate,
+    /// The resolved symbol for the interval.
+    pub symbol: String,
+}
+
+impl SymbologyInterval {
+    /// Creates a new [`SymbologyInterval`] instance.
+    #[must_use]
+    pub const fn new(start_date: NaiveDate, end_date: NaiveDate, symbol: String) -> Self {
+        Self {
+            start_date,
+            end_date,
+            symbol,
+        }
+    }
+
+    /// Returns whether the interval contains the given `date`.
+    #[must_use]
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.start_date <= date && date < self.end_date
+    }
+}
+
+impl From<&MappingInterval> for SymbologyInterval {
+    fn from(value: &MappingInterval) -> Self {
+        Self::new(
+            time_date_to_naive(value.start_date),
+            time_date_to_naive(value.end_date),
+            value.symbol.clone(),
+        )
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct SymbologyEntry {
+    /// The date ranges which have been resolved, sorted and merged.
+    resolved: Vec<(NaiveDate, NaiveDate)>,
+    /// The resolved intervals, sorted by start date.
+    intervals: Vec<SymbologyInterval>,
+}
+
+/// A persistent symbology map from input symbols to resolved symbols per date range.
+///
+/// The map is populated from Databento `symbology.resolve` responses and tracks which date
+/// ranges have already been resolved for each symbol, so that only the missing ranges need
+/// to be requested. The map can be saved to and loaded from a local JSON file, which keeps
+/// instrument IDs consistent between repeated backtests and live sessions.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DatabentoSymbologyMap {
+    datasets: BTreeMap<String, BTreeMap<String, SymbologyEntry>>,
+}
+
+impl DatabentoSymbologyMap {
+    /// Creates a new empty [`DatabentoSymbologyMap`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads a symbology map from the JSON file at `filepath`.
+    ///
+    /// Returns an empty map if the file does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or deserialized.
+    pub fn from_file(filepath: &Path) -> anyhow::Result<Self> {
+        if !filepath.exists() {
+            return Ok(Self::new());
+        }
+
+        let content = fs::read_to_string(filepath)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Saves the symbology map as JSON to the file at `filepath`.
+    ///
+    /// The map is first written to a temporary file which is then renamed, so a concurrent
+    /// reader never observes a partially written file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be serialized or written.
+    pub fn to_file(&self, filepath: &Path) -> anyhow::Result<()> {
+        if let Some(parent) = filepath.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let temp_filepath = filepath.with_extension("json.tmp");
+        fs::write(&temp_filepath, serde_json::to_string_pretty(self)?)?;
+        fs::rename(&temp_filepath, filepath)?;
+        Ok(())
+    }
+
+    /// Returns whether the map contains no symbols.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.datasets.values().all(BTreeMap::is_empty)
+    }
+
+    /// Returns the date ranges within `[start, end)` which have not yet been resolved
+    /// for the given `dataset` and `symbol`.
+    #[must_use]
+    pub fn unresolved_ranges(
+        &self,
+        dataset: &str,
+        symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Vec<(NaiveDate, NaiveDate)> {
+        let Some(entry) = self.entry(dataset, symbol) else {
+            return if start < end {
+                vec![(start, end)]
+            } else {
+                vec![]
+            };
+        };
+
+        let mut ranges = Vec::new();
+        let mut cursor = start;
+        for &(resolved_start, resolved_end) in &entry.resolved {
+            if cursor >= end {
+                break;
+            }
+            if resolved_end <= cursor {
+                continue;
+            }
+            if resolved_start > cursor {
+                ranges.push((cursor, resolved_start.min(end)));
+            }
+            cursor = cursor.max(resolved_end);
+        }
+
+        if cursor < end {
+            ranges.push((cursor, end));
+        }
+
+        ranges
+    }
+
+    /// Inserts the resolved `intervals` for the given `dataset` and `symbol`, marking the
+    /// date range `[start, end)` as resolved (even if no intervals were found).
+    pub fn insert(
+        &mut self,
+        dataset: &str,
+        symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+        intervals: Vec<SymbologyInterval>,
+    ) {
+        let entry = self
+            .datasets
+            .entry(dataset.to_string())
+            .or_default()
+            .entry(symbol.to_string())
+            .or_default();
+
+        if start < end {
+            entry.resolved.push((start, end));
+            entry.resolved = merge_ranges(std::mem::take(&mut entry.resolved));
+        }
+
+        for interval in intervals {
+            if !entry.intervals.contains(&interval) {
+                entry.intervals.push(interval);
+            }
+        }
+        entry
+            .intervals
+            .sort_by(|a, b| (a.start_date, &a.symbol).cmp(&(b.start_date, &b.symbol)));
+    }
+
+    /// Inserts all mappings from the given symbology `resolution` for `dataset`, marking
+    /// the date range `[start, end)` as resolved for every requested symbol.
+    pub fn insert_resolution(
+        &mut self,
+        dataset: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+        resolution: &Resolution,
+    ) {
+        for (symbol, mappings) in &resolution.mappings {
+            let intervals = mappings.iter().map(SymbologyInterval::from).collect();
+            self.insert(dataset, symbol, start, end, intervals);
+        }
+
+        for symbol in &resolution.not_found {
+            self.insert(dataset, symbol, start, end, Vec::new());
+        }
+    }
+
+    /// Returns the resolved symbol for the given `dataset`, `symbol` and `date` (if found).
+    #[must_use]
+    pub fn get(&self, dataset: &str, symbol: &str, date: NaiveDate) -> Option<&str> {
+        self.entry(dataset, symbol)?
+            .intervals
+            .iter()
+            .find(|interval| interval.contains(date))
+            .map(|interval| interval.symbol.as_str())
+    }
+
+    /// Returns the input symbol which resolved to `resolved_symbol` on `date` for the given
+    /// `dataset` (if found), e.g. the raw symbol for a Databento instrument ID.
+    #[must_use]
+    pub fn get_reverse(
+        &self,
+        dataset: &str,
+        resolved_symbol: &str,
+        date: NaiveDate,
+    ) -> Option<&str> {
+        self.datasets
+            .get(dataset)?
+            .iter()
+            .find_map(|(symbol, entry)| {
+                entry
+                    .intervals
+                    .iter()
+                    .any(|i| i.symbol == resolved_symbol && i.contains(date))
+                    .then_some(symbol.as_str())
+            })
+    }
+
+    /// Returns all resolved intervals for the given `dataset` and `symbol`.
+    #[must_use]
+    pub fn intervals(&self, dataset: &str, symbol: &str) -> &[SymbologyInterval] {
+        self.entry(dataset, symbol)
+            .map_or(&[], |entry| entry.intervals.as_slice())
+    }
+
+    fn entry(&self, dataset: &str, symbol: &str) -> Option<&SymbologyEntry> {
+        self.datasets.get(dataset)?.get(symbol)
+    }
+}
+
+fn merge_ranges(mut ranges: Vec<(NaiveDate, NaiveDate)>) -> Vec<(NaiveDate, NaiveDate)> {
+    ranges.sort();
+
+    let mut merged: Vec<(NaiveDate, NaiveDate)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    merged
+}
+
+/// Converts the given `time::Date` into a `chrono::NaiveDate`.
+#[must_use]
+pub fn time_date_to_naive(date: time::Date) -> NaiveDate {
+    // SAFETY: A valid `time::Date` is always a valid `NaiveDate`
+    NaiveDate::from_ymd_opt(
+        date.year(),
+        u32::from(u8::from(date.month())),
+        u32::from(date.day()),
+    )
+    .unwrap()
+}
+
+/// Converts the given `chrono::NaiveDate` into a `time::Date`.
+///
+/// # Errors
+///
+/// Returns an error if the date is outside the range supported by `time::Date`.
+pub fn naive_date_to_time(date: NaiveDate) -> anyhow::Result<time::Date> {
+    use chrono::Datelike;
+
+    let month = time::Month::try_from(date.month() as u8)?;
+    Ok(time::Date::from_calendar_date(
+        date.year(),
+        month,
+        date.day() as u8,
+    )?)
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -204,4 +495,184 @@ mod tests {
         let result = check_consistent_symbology(&symbols);
         assert!(result.is_ok());
     }
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn interval(start: &str, end: &str, symbol: &str) -> SymbologyInterval {
+        SymbologyInterval::new(date(start), date(end), symbol.to_string())
+    }
+
+    #[rstest]
+    fn test_symbology_map_unresolved_when_empty() {
+        let map = DatabentoSymbologyMap::new();
+        let ranges =
+            map.unresolved_ranges("GLBX.MDP3", "ESM4", date("2024-01-01"), date("2024-01-10"));
+
+        assert!(map.is_empty());
+        assert_eq!(ranges, vec![(date("2024-01-01"), date("2024-01-10"))]);
+    }
+
+    #[rstest]
+    fn test_symbology_map_unresolved_gaps() {
+        let mut map = DatabentoSymbologyMap::new();
+        map.insert(
+            "GLBX.MDP3",
+            "ESM4",
+            date("2024-01-03"),
+            date("2024-01-05"),
+            vec![],
+        );
+        map.insert(
+            "GLBX.MDP3",
+            "ESM4",
+            date("2024-01-07"),
+            date("2024-01-08"),
+            vec![],
+        );
+
+        let ranges =
+            map.unresolved_ranges("GLBX.MDP3", "ESM4", date("2024-01-01"), date("2024-01-10"));
+
+        assert_eq!(
+            ranges,
+            vec![
+                (date("2024-01-01"), date("2024-01-03")),
+                (date("2024-01-05"), date("2024-01-07")),
+                (date("2024-01-08"), date("2024-01-10")),
+            ]
+        );
+        assert!(map
+            .unresolved_ranges("GLBX.MDP3", "ESM4", date("2024-01-03"), date("2024-01-05"))
+            .is_empty());
+        assert_eq!(
+            map.unresolved_ranges("XNAS.ITCH", "ESM4", date("2024-01-03"), date("2024-01-05")),
+            vec![(date("2024-01-03"), date("2024-01-05"))]
+        );
+    }
+
+    #[rstest]
+    fn test_symbology_map_merges_resolved_ranges() {
+        let mut map = DatabentoSymbologyMap::new();
+        map.insert(
+            "GLBX.MDP3",
+            "ESM4",
+            date("2024-01-01"),
+            date("2024-01-05"),
+            vec![],
+        );
+        map.insert(
+            "GLBX.MDP3",
+            "ESM4",
+            date("2024-01-05"),
+            date("2024-01-08"),
+            vec![],
+        );
+        map.insert(
+            "GLBX.MDP3",
+            "ESM4",
+            date("2024-01-02"),
+            date("2024-01-04"),
+            vec![],
+        );
+
+        let entry = map.entry("GLBX.MDP3", "ESM4").unwrap();
+
+        assert_eq!(
+            entry.resolved,
+            vec![(date("2024-01-01"), date("2024-01-08"))]
+        );
+    }
+
+    #[rstest]
+    fn test_symbology_map_get_and_reverse() {
+        let mut map = DatabentoSymbologyMap::new();
+        map.insert(
+            "GLBX.MDP3",
+            "ES.c.0",
+            date("2024-03-01"),
+            date("2024-04-01"),
+            vec![
+                interval("2024-03-01", "2024-03-15", "5002"),
+                interval("2024-03-15", "2024-04-01", "4916"),
+            ],
+        );
+
+        assert_eq!(
+            map.get("GLBX.MDP3", "ES.c.0", date("2024-03-14")),
+            Some("5002")
+        );
+        assert_eq!(
+            map.get("GLBX.MDP3", "ES.c.0", date("2024-03-15")),
+            Some("4916")
+        );
+        assert_eq!(map.get("GLBX.MDP3", "ES.c.0", date("2024-04-01")), None);
+        assert_eq!(
+            map.get_reverse("GLBX.MDP3", "4916", date("2024-03-20")),
+            Some("ES.c.0")
+        );
+        assert_eq!(
+            map.get_reverse("GLBX.MDP3", "4916", date("2024-03-01")),
+            None
+        );
+        assert_eq!(map.intervals("GLBX.MDP3", "ES.c.0").len(), 2);
+    }
+
+    #[rstest]
+    fn test_symbology_map_insert_deduplicates_intervals() {
+        let mut map = DatabentoSymbologyMap::new();
+        let intervals = vec![interval("2024-03-01", "2024-03-15", "5002")];
+        map.insert(
+            "GLBX.MDP3",
+            "ESH4",
+            date("2024-03-01"),
+            date("2024-03-15"),
+            intervals.clone(),
+        );
+        map.insert(
+            "GLBX.MDP3",
+            "ESH4",
+            date("2024-03-01"),
+            date("2024-03-15"),
+            intervals,
+        );
+
+        assert_eq!(map.intervals("GLBX.MDP3", "ESH4").len(), 1);
+    }
+
+    #[rstest]
+    fn test_symbology_map_file_round_trip() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let filepath = temp_dir.path().join("symbology.json");
+        let mut map = DatabentoSymbologyMap::new();
+        map.insert(
+            "XNAS.ITCH",
+            "AAPL",
+            date("2024-01-01"),
+            date("2024-02-01"),
+            vec![interval("2024-01-01", "2024-02-01", "38")],
+        );
+
+        map.to_file(&filepath).unwrap();
+        let loaded = DatabentoSymbologyMap::from_file(&filepath).unwrap();
+
+        assert_eq!(loaded, map);
+    }
+
+    #[rstest]
+    fn test_symbology_map_from_missing_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let map = DatabentoSymbologyMap::from_file(&temp_dir.path().join("missing.json")).unwrap();
+
+        assert!(map.is_empty());
+    }
+
+    #[rstest]
+    fn test_date_conversions_round_trip() {
+        let naive = date("2024-02-29");
+        let converted = naive_date_to_time(naive).unwrap();
+
+        assert_eq!(time_date_to_naive(converted), naive);
+    }
 }
@@ -0,0 +1,40 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use serde::{Deserialize, Serialize};
+
+/// Provides a configuration for an Interactive Brokers market data client connection.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InteractiveBrokersDataClientConfig {
+    /// The IB Gateway or TWS host.
+    pub host: String,
+    /// The IB Gateway or TWS API port.
+    pub port: u16,
+    /// The client ID for the API connection (must be unique per connection).
+    pub client_id: i32,
+    /// The timeout (seconds) for establishing the TCP connection.
+    pub connection_timeout_secs: u64,
+}
+
+impl Default for InteractiveBrokersDataClientConfig {
+    fn default() -> Self {
+        Self {
+            host: "127.0.0.1".to_string(),
+            port: 4002,
+            client_id: 1,
+            connection_timeout_secs: 10,
+        }
+    }
+}
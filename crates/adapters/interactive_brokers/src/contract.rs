@@ -0,0 +1,390 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Contract definitions and the raw IB instrument symbology.
+//!
+//! Instrument IDs use the `IB_RAW` symbology of the Python adapter, where the symbol is
+//! `{localSymbol}={secType}` and the venue is the (primary) exchange, which can be mapped
+//! back to a contract without ambiguity.
+
+use std::collections::HashMap;
+
+use nautilus_model::{
+    identifiers::{InstrumentId, Symbol, Venue},
+    orders::SpreadLeg,
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::protocol::{FieldReader, FieldWriter, Result};
+
+/// Venue used for IB CFD contracts (routed via `SMART`).
+pub const VENUE_IBCFD: &str = "IBCFD";
+/// Venue used for IB commodity contracts (routed via `SMART`).
+pub const VENUE_IBCMDTY: &str = "IBCMDTY";
+
+/// Represents an IB contract, as used to request data for a security.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct IBContract {
+    pub con_id: i32,
+    pub symbol: String,
+    pub sec_type: String,
+    pub last_trade_date_or_contract_month: String,
+    pub strike: f64,
+    pub right: String,
+    pub multiplier: String,
+    pub exchange: String,
+    pub primary_exchange: String,
+    pub currency: String,
+    pub local_symbol: String,
+    pub trading_class: String,
+    pub include_expired: bool,
+    pub sec_id_type: String,
+    pub sec_id: String,
+    pub issuer_id: String,
+    /// The legs of a combo (`BAG`) contract.
+    pub combo_legs: Vec<IBComboLeg>,
+}
+
+/// Represents a leg of an IB combo (`BAG`) contract.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IBComboLeg {
+    pub con_id: i32,
+    pub ratio: i32,
+    /// The action of the leg when buying the combo, being `BUY` or `SELL`.
+    pub action: String,
+    pub exchange: String,
+}
+
+impl IBContract {
+    /// Writes the contract fields common to market data requests.
+    pub fn encode(&self, writer: &mut FieldWriter) {
+        writer
+            .push_int(self.con_id)
+            .push_str(&self.symbol)
+            .push_str(&self.sec_type)
+            .push_str(&self.last_trade_date_or_contract_month)
+            .push_f64(self.strike)
+            .push_str(&self.right)
+            .push_str(&self.multiplier)
+            .push_str(&self.exchange)
+            .push_str(&self.primary_exchange)
+            .push_str(&self.currency)
+            .push_str(&self.local_symbol)
+            .push_str(&self.trading_class);
+    }
+}
+
+/// Represents the details of a qualified IB contract.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct IBContractDetails {
+    pub contract: IBContract,
+    pub market_name: String,
+    pub min_tick: f64,
+    pub order_types: String,
+    pub valid_exchanges: String,
+    pub price_magnifier: i32,
+    pub under_con_id: i32,
+    pub long_name: String,
+    pub contract_month: String,
+    pub industry: String,
+    pub category: String,
+    pub subcategory: String,
+    pub time_zone_id: String,
+    pub trading_hours: String,
+    pub liquid_hours: String,
+    pub ev_rule: String,
+    pub ev_multiplier: String,
+    pub sec_id_list: Vec<(String, String)>,
+    pub agg_group: i32,
+    pub under_symbol: String,
+    pub under_sec_type: String,
+    pub market_rule_ids: String,
+    pub real_expiration_date: String,
+    pub stock_type: String,
+    pub min_size: Option<Decimal>,
+    pub size_increment: Option<Decimal>,
+    pub suggested_size_increment: Option<Decimal>,
+}
+
+impl IBContractDetails {
+    /// Decodes the contract details fields of a `CONTRACT_DATA` message (following the request ID).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a field is missing or invalid.
+    pub fn decode(reader: &mut FieldReader) -> Result<Self> {
+        let mut details = Self::default();
+        let contract = &mut details.contract;
+
+        contract.symbol = reader.next_string("symbol")?;
+        contract.sec_type = reader.next_string("sec_type")?;
+        // May be suffixed with the last trade time and time zone
+        contract.last_trade_date_or_contract_month = reader
+            .next_str("last_trade_date_or_contract_month")?
+            .split([' ', '-'])
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        contract.strike = reader.next_f64("strike")?;
+        contract.right = reader.next_string("right")?;
+        contract.exchange = reader.next_string("exchange")?;
+        contract.currency = reader.next_string("currency")?;
+        contract.local_symbol = reader.next_string("local_symbol")?;
+        details.market_name = reader.next_string("market_name")?;
+        contract.trading_class = reader.next_string("trading_class")?;
+        contract.con_id = reader.next_int("con_id")?;
+        details.min_tick = reader.next_f64("min_tick")?;
+        contract.multiplier = reader.next_string("multiplier")?;
+        details.order_types = reader.next_string("order_types")?;
+        details.valid_exchanges = reader.next_string("valid_exchanges")?;
+        details.price_magnifier = reader.next_int("price_magnifier")?;
+        details.under_con_id = reader.next_int("under_con_id")?;
+        details.long_name = reader.next_string("long_name")?;
+        contract.primary_exchange = reader.next_string("primary_exchange")?;
+        details.contract_month = reader.next_string("contract_month")?;
+        details.industry = reader.next_string("industry")?;
+        details.category = reader.next_string("category")?;
+        details.subcategory = reader.next_string("subcategory")?;
+        details.time_zone_id = reader.next_string("time_zone_id")?;
+        details.trading_hours = reader.next_string("trading_hours")?;
+        details.liquid_hours = reader.next_string("liquid_hours")?;
+        details.ev_rule = reader.next_string("ev_rule")?;
+        details.ev_multiplier = reader.next_string("ev_multiplier")?;
+
+        let sec_id_count = reader.next_int("sec_id_list_count")?;
+        for _ in 0..sec_id_count {
+            let tag = reader.next_string("sec_id_tag")?;
+            let value = reader.next_string("sec_id_value")?;
+            details.sec_id_list.push((tag, value));
+        }
+
+        details.agg_group = reader.next_int("agg_group")?;
+        details.under_symbol = reader.next_string("under_symbol")?;
+        details.under_sec_type = reader.next_string("under_sec_type")?;
+        details.market_rule_ids = reader.next_string("market_rule_ids")?;
+        details.real_expiration_date = reader.next_string("real_expiration_date")?;
+        details.stock_type = reader.next_string("stock_type")?;
+        details.min_size = reader.next_decimal("min_size")?;
+        details.size_increment = reader.next_decimal("size_increment")?;
+        details.suggested_size_increment = reader.next_decimal("suggested_size_increment")?;
+
+        Ok(details)
+    }
+
+    /// Returns the security ID for the given tag (e.g. `ISIN`), if present.
+    #[must_use]
+    pub fn sec_id(&self, tag: &str) -> Option<&str> {
+        self.sec_id_list
+            .iter()
+            .find(|(t, _)| t == tag)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// Returns the instrument ID for the given contract using the raw IB symbology.
+#[must_use]
+pub fn ib_contract_to_instrument_id(contract: &IBContract) -> InstrumentId {
+    let symbol = format!("{}={}", contract.local_symbol, contract.sec_type);
+    let venue = match contract.sec_type.as_str() {
+        "CFD" => VENUE_IBCFD.to_string(),
+        "CMDTY" => VENUE_IBCMDTY.to_string(),
+        _ => {
+            let exchange = if contract.primary_exchange.is_empty() {
+                &contract.exchange
+            } else {
+                &contract.primary_exchange
+            };
+            exchange.replace('.', "/")
+        }
+    };
+    InstrumentId::new(Symbol::new(symbol), Venue::new(venue))
+}
+
+/// Returns the contract to qualify for the given instrument ID using the raw IB symbology.
+///
+/// # Errors
+///
+/// Returns an error if the symbol is not of the form `{localSymbol}={secType}`.
+pub fn instrument_id_to_ib_contract(instrument_id: &InstrumentId) -> anyhow::Result<IBContract> {
+    let (local_symbol, sec_type) = instrument_id
+        .symbol
+        .as_str()
+        .rsplit_once('=')
+        .ok_or_else(|| anyhow::anyhow!("Invalid IB raw symbol for {instrument_id}"))?;
+    let exchange = instrument_id.venue.as_str().replace('/', ".");
+
+    let mut contract = IBContract {
+        sec_type: sec_type.to_string(),
+        local_symbol: local_symbol.to_string(),
+        ..Default::default()
+    };
+    match sec_type {
+        "STK" => {
+            contract.exchange = "SMART".to_string();
+            contract.primary_exchange = exchange;
+        }
+        "CFD" | "CMDTY" => contract.exchange = "SMART".to_string(),
+        _ => contract.exchange = exchange,
+    }
+    Ok(contract)
+}
+
+/// Returns the combo (`BAG`) contract for a spread with the given `legs`, where
+/// `leg_contracts` are the qualified contracts of the legs.
+///
+/// IB combo leg ratios are unsigned, so the sign of each leg ratio maps to the leg action.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - A leg contract is missing or unqualified (no `con_id`).
+/// - The legs do not all have the same symbol and currency.
+/// - A leg ratio exceeds the IB ratio range.
+pub fn ib_combo_contract(
+    legs: &[SpreadLeg],
+    leg_contracts: &HashMap<InstrumentId, IBContract>,
+) -> anyhow::Result<IBContract> {
+    let mut combo = IBContract {
+        sec_type: "BAG".to_string(),
+        ..Default::default()
+    };
+    for leg in legs {
+        let contract = leg_contracts
+            .get(&leg.instrument_id)
+            .filter(|contract| contract.con_id != 0)
+            .ok_or_else(|| {
+                anyhow::anyhow!("No qualified contract for leg {}", leg.instrument_id)
+            })?;
+        if combo.combo_legs.is_empty() {
+            combo.symbol.clone_from(&contract.symbol);
+            combo.currency.clone_from(&contract.currency);
+            combo.exchange.clone_from(&contract.exchange);
+        } else if contract.symbol != combo.symbol || contract.currency != combo.currency {
+            anyhow::bail!(
+                "Invalid combo leg {}: {} {} differs from {} {}",
+                leg.instrument_id,
+                contract.symbol,
+                contract.currency,
+                combo.symbol,
+                combo.currency
+            );
+        }
+        combo.combo_legs.push(IBComboLeg {
+            con_id: contract.con_id,
+            ratio: i32::try_from(leg.ratio.unsigned_abs())?,
+            action: if leg.ratio > 0 { "BUY" } else { "SELL" }.to_string(),
+            exchange: contract.exchange.clone(),
+        });
+    }
+    Ok(combo)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("AAPL", "STK", "SMART", "NASDAQ", "AAPL=STK.NASDAQ")]
+    #[case("ESH5", "FUT", "CME", "", "ESH5=FUT.CME")]
+    #[case("EUR.USD", "CASH", "IDEALPRO", "", "EUR.USD=CASH.IDEALPRO")]
+    #[case("7203", "STK", "SMART", "TSEJ.T", "7203=STK.TSEJ/T")]
+    #[case("IBUS500", "CFD", "SMART", "", "IBUS500=CFD.IBCFD")]
+    fn test_instrument_id_round_trip(
+        #[case] local_symbol: &str,
+        #[case] sec_type: &str,
+        #[case] exchange: &str,
+        #[case] primary_exchange: &str,
+        #[case] expected: &str,
+    ) {
+        let contract = IBContract {
+            sec_type: sec_type.to_string(),
+            local_symbol: local_symbol.to_string(),
+            exchange: exchange.to_string(),
+            primary_exchange: primary_exchange.to_string(),
+            ..Default::default()
+        };
+
+        let instrument_id = ib_contract_to_instrument_id(&contract);
+        let contract = instrument_id_to_ib_contract(&instrument_id).unwrap();
+
+        assert_eq!(instrument_id, InstrumentId::from(expected));
+        assert_eq!(contract.local_symbol, local_symbol);
+        assert_eq!(contract.sec_type, sec_type);
+        assert_eq!(contract.exchange, exchange);
+        if sec_type == "STK" {
+            assert_eq!(contract.primary_exchange, primary_exchange);
+        }
+    }
+
+    #[rstest]
+    fn test_instrument_id_to_ib_contract_invalid_symbol() {
+        let instrument_id = InstrumentId::from("AAPL.NASDAQ");
+
+        assert!(instrument_id_to_ib_contract(&instrument_id).is_err());
+    }
+
+    #[rstest]
+    fn test_ib_combo_contract() {
+        let leg = |local_symbol: &str, con_id: i32| IBContract {
+            con_id,
+            symbol: "ES".to_string(),
+            sec_type: "FUT".to_string(),
+            exchange: "CME".to_string(),
+            currency: "USD".to_string(),
+            local_symbol: local_symbol.to_string(),
+            ..Default::default()
+        };
+        let front = InstrumentId::from("ESM5=FUT.CME");
+        let back = InstrumentId::from("ESU5=FUT.CME");
+        let legs = [
+            SpreadLeg::new(front, 1).unwrap(),
+            SpreadLeg::new(back, -2).unwrap(),
+        ];
+        let mut leg_contracts =
+            HashMap::from([(front, leg("ESM5", 101)), (back, leg("ESU5", 102))]);
+
+        let combo = ib_combo_contract(&legs, &leg_contracts).unwrap();
+
+        assert_eq!(combo.sec_type, "BAG");
+        assert_eq!(combo.symbol, "ES");
+        assert_eq!(combo.exchange, "CME");
+        assert_eq!(
+            combo.combo_legs,
+            vec![
+                IBComboLeg {
+                    con_id: 101,
+                    ratio: 1,
+                    action: "BUY".to_string(),
+                    exchange: "CME".to_string(),
+                },
+                IBComboLeg {
+                    con_id: 102,
+                    ratio: 2,
+                    action: "SELL".to_string(),
+                    exchange: "CME".to_string(),
+                },
+            ]
+        );
+
+        leg_contracts.insert(back, leg("ESU5", 0));
+        assert!(ib_combo_contract(&legs, &leg_contracts).is_err());
+    }
+}
@@ -0,0 +1,700 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, time::Duration};
+
+use nautilus_core::{time::get_atomic_clock_realtime, UnixNanos};
+use nautilus_model::{
+    data::{BarType, Data, OrderBookDeltas, OrderBookDeltas_API},
+    identifiers::InstrumentId,
+    instruments::InstrumentAny,
+};
+use tokio::{
+    io::{AsyncWrite, AsyncWriteExt},
+    net::TcpStream,
+    sync::mpsc::{Sender, UnboundedReceiver},
+};
+
+use crate::{
+    config::InteractiveBrokersDataClientConfig,
+    contract::{instrument_id_to_ib_contract, IBContract},
+    parse::{
+        bar_type_to_what_to_show, parse_bar, parse_instrument, parse_quote_tick, parse_trade_tick,
+        secs_to_nanos, DepthLadder,
+    },
+    protocol::{
+        handshake,
+        ids::{NOTICE_CODES, NO_SECURITY_DEFINITION},
+        messages::{
+            cancel_mkt_depth, cancel_real_time_bars, cancel_tick_by_tick_data, decode_message,
+            req_contract_details, req_mkt_depth, req_real_time_bars, req_tick_by_tick_data,
+            start_api, IBMessage, MarketDepthUpdate, TickByTick,
+        },
+        read_frame, Error, FieldReader, MIN_CLIENT_VERSION,
+    },
+};
+
+#[derive(Debug)]
+pub enum LiveCommand {
+    RequestInstrument(InstrumentId),
+    Subscribe(Subscription),
+    Unsubscribe(Subscription),
+    Close,
+}
+
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)] // Instruments are sent infrequently
+pub enum LiveMessage {
+    Data(Data),
+    Instrument(InstrumentAny),
+    Error(anyhow::Error),
+    Close,
+}
+
+/// Represents a market data subscription.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Subscription {
+    /// Level 1 quotes from tick-by-tick `BidAsk` data.
+    Quotes(InstrumentId),
+    /// Trades from tick-by-tick `AllLast` data.
+    Trades(InstrumentId),
+    /// Level 2 order book deltas from market depth data.
+    BookDeltas {
+        instrument_id: InstrumentId,
+        depth: i32,
+        is_smart_depth: bool,
+    },
+    /// 5-second real-time bars.
+    Bars(BarType),
+}
+
+impl Subscription {
+    /// Returns the instrument ID for the subscription.
+    #[must_use]
+    pub fn instrument_id(&self) -> InstrumentId {
+        match self {
+            Self::Quotes(instrument_id)
+            | Self::Trades(instrument_id)
+            | Self::BookDeltas { instrument_id, .. } => *instrument_id,
+            Self::Bars(bar_type) => bar_type.instrument_id(),
+        }
+    }
+
+    fn encode_request(&self, req_id: i32, contract: &IBContract) -> anyhow::Result<Vec<u8>> {
+        Ok(match self {
+            Self::Quotes(_) => req_tick_by_tick_data(req_id, contract, "BidAsk"),
+            Self::Trades(_) => req_tick_by_tick_data(req_id, contract, "AllLast"),
+            Self::BookDeltas {
+                depth,
+                is_smart_depth,
+                ..
+            } => req_mkt_depth(req_id, contract, *depth, *is_smart_depth),
+            Self::Bars(bar_type) => {
+                let what_to_show = bar_type_to_what_to_show(bar_type)?;
+                req_real_time_bars(req_id, contract, what_to_show, false)
+            }
+        })
+    }
+
+    fn encode_cancel(&self, req_id: i32) -> Vec<u8> {
+        match self {
+            Self::Quotes(_) | Self::Trades(_) => cancel_tick_by_tick_data(req_id),
+            Self::BookDeltas { is_smart_depth, .. } => cancel_mkt_depth(req_id, *is_smart_depth),
+            Self::Bars(_) => cancel_real_time_bars(req_id),
+        }
+    }
+}
+
+/// Handles a TCP socket connection to an IB Gateway or TWS for market data.
+///
+/// [`LiveCommand`] messages are received across an unbounded channel, and decoded
+/// data is sent on a bounded tokio channel as [`LiveMessage`]s back to a message
+/// processing task.
+///
+/// Instruments are qualified with contract details requests, and subscriptions for
+/// instruments which are not yet qualified are sent once the contract is qualified.
+#[derive(Debug)]
+pub struct InteractiveBrokersFeedHandler {
+    config: InteractiveBrokersDataClientConfig,
+    cmd_rx: UnboundedReceiver<LiveCommand>,
+    msg_tx: Sender<LiveMessage>,
+    is_ready: bool,
+    queued_cmds: Vec<LiveCommand>,
+    next_req_id: i32,
+    instruments: HashMap<InstrumentId, InstrumentAny>,
+    contracts: HashMap<InstrumentId, IBContract>,
+    contract_requests: HashMap<i32, InstrumentId>,
+    pending_subs: HashMap<InstrumentId, Vec<Subscription>>,
+    subscriptions: HashMap<i32, Subscription>,
+    subscription_ids: HashMap<Subscription, i32>,
+    ladders: HashMap<i32, DepthLadder>,
+}
+
+impl InteractiveBrokersFeedHandler {
+    /// Creates a new [`InteractiveBrokersFeedHandler`] instance.
+    #[must_use]
+    pub fn new(
+        config: InteractiveBrokersDataClientConfig,
+        cmd_rx: UnboundedReceiver<LiveCommand>,
+        msg_tx: Sender<LiveMessage>,
+    ) -> Self {
+        Self {
+            config,
+            cmd_rx,
+            msg_tx,
+            is_ready: false,
+            queued_cmds: Vec::new(),
+            next_req_id: 1,
+            instruments: HashMap::new(),
+            contracts: HashMap::new(),
+            contract_requests: HashMap::new(),
+            pending_subs: HashMap::new(),
+            subscriptions: HashMap::new(),
+            subscription_ids: HashMap::new(),
+            ladders: HashMap::new(),
+        }
+    }
+
+    /// Run the feed handler to connect, then listen for commands and process messages.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if connecting fails, or the connection is lost.
+    pub async fn run(&mut self) -> anyhow::Result<()> {
+        let addr = format!("{}:{}", self.config.host, self.config.port);
+        tracing::debug!("Connecting to {addr}");
+
+        let timeout = Duration::from_secs(self.config.connection_timeout_secs);
+        let stream = match tokio::time::timeout(timeout, TcpStream::connect(&addr)).await {
+            Ok(Ok(stream)) => stream,
+            Ok(Err(e)) => {
+                return self
+                    .fail(anyhow::anyhow!("Failed to connect to {addr}: {e}"))
+                    .await
+            }
+            Err(_) => {
+                return self
+                    .fail(anyhow::anyhow!("Timeout connecting to {addr}"))
+                    .await
+            }
+        };
+        let (mut reader, mut writer) = stream.into_split();
+
+        writer.write_all(&handshake()).await?;
+        let payload = read_frame(&mut reader).await?;
+        let mut fields = FieldReader::new(&payload)?;
+        let server_version = fields.next_int("server_version")?;
+        let connection_time = fields.next_string("connection_time")?;
+        if server_version < MIN_CLIENT_VERSION {
+            let e = Error::UnsupportedServerVersion(server_version);
+            return self.fail(e.into()).await;
+        }
+        tracing::info!("Connected to {addr} (server version {server_version}, {connection_time})");
+
+        writer.write_all(&start_api(self.config.client_id)).await?;
+
+        // Read frames on a separate task, as reading is not cancellation safe
+        let (frame_tx, mut frame_rx) = tokio::sync::mpsc::unbounded_channel();
+        let reader_task = tokio::spawn(async move {
+            loop {
+                let result = read_frame(&mut reader).await;
+                let is_err = result.is_err();
+                if frame_tx.send(result).is_err() || is_err {
+                    break;
+                }
+            }
+        });
+
+        let result = loop {
+            tokio::select! {
+                cmd = self.cmd_rx.recv() => match cmd {
+                    Some(LiveCommand::Close) | None => {
+                        tracing::debug!("Closing");
+                        break Ok(());
+                    }
+                    Some(cmd) => {
+                        if let Err(e) = self.handle_command(cmd, &mut writer).await {
+                            tracing::error!("Error handling command: {e}");
+                        }
+                    }
+                },
+                frame = frame_rx.recv() => match frame {
+                    Some(Ok(payload)) => {
+                        if let Err(e) = self.handle_payload(&payload, &mut writer).await {
+                            tracing::error!("Error handling message: {e}");
+                        }
+                    }
+                    Some(Err(e)) => break Err(anyhow::anyhow!("Connection lost: {e}")),
+                    None => break Err(anyhow::anyhow!("Connection closed")),
+                },
+            }
+        };
+
+        reader_task.abort();
+        self.cmd_rx.close();
+
+        match result {
+            Ok(()) => {
+                self.send_msg(LiveMessage::Close).await;
+                Ok(())
+            }
+            Err(e) => self.fail(e).await,
+        }
+    }
+
+    async fn fail(&mut self, e: anyhow::Error) -> anyhow::Result<()> {
+        tracing::error!("{e}");
+        self.send_msg(LiveMessage::Error(anyhow::anyhow!("{e}")))
+            .await;
+        self.cmd_rx.close();
+        Err(e)
+    }
+
+    async fn handle_command<W: AsyncWrite + Unpin>(
+        &mut self,
+        cmd: LiveCommand,
+        writer: &mut W,
+    ) -> anyhow::Result<()> {
+        if !self.is_ready {
+            tracing::debug!("Queuing command until ready: {cmd:?}");
+            self.queued_cmds.push(cmd);
+            return Ok(());
+        }
+
+        tracing::debug!("Received command: {cmd:?}");
+        match cmd {
+            LiveCommand::RequestInstrument(instrument_id) => {
+                self.request_contract_details(instrument_id, writer).await
+            }
+            LiveCommand::Subscribe(sub) => self.subscribe(sub, writer).await,
+            LiveCommand::Unsubscribe(sub) => self.unsubscribe(sub, writer).await,
+            LiveCommand::Close => Ok(()), // Handled by run loop
+        }
+    }
+
+    async fn request_contract_details<W: AsyncWrite + Unpin>(
+        &mut self,
+        instrument_id: InstrumentId,
+        writer: &mut W,
+    ) -> anyhow::Result<()> {
+        if self
+            .contract_requests
+            .values()
+            .any(|id| *id == instrument_id)
+        {
+            return Ok(()); // Already requested
+        }
+
+        let contract = instrument_id_to_ib_contract(&instrument_id)?;
+        let req_id = self.next_req_id();
+        self.contract_requests.insert(req_id, instrument_id);
+        writer
+            .write_all(&req_contract_details(req_id, &contract))
+            .await?;
+        Ok(())
+    }
+
+    async fn subscribe<W: AsyncWrite + Unpin>(
+        &mut self,
+        sub: Subscription,
+        writer: &mut W,
+    ) -> anyhow::Result<()> {
+        if self.subscription_ids.contains_key(&sub) {
+            tracing::warn!("Already subscribed to {sub:?}");
+            return Ok(());
+        }
+
+        let instrument_id = sub.instrument_id();
+        let Some(contract) = self.contracts.get(&instrument_id) else {
+            // Qualify the contract first
+            self.pending_subs
+                .entry(instrument_id)
+                .or_default()
+                .push(sub);
+            return self.request_contract_details(instrument_id, writer).await;
+        };
+
+        let req_id = self.next_req_id;
+        let request = sub.encode_request(req_id, contract)?;
+        self.next_req_id();
+
+        if let Subscription::BookDeltas { .. } = sub {
+            self.ladders.insert(req_id, DepthLadder::new(instrument_id));
+        }
+        self.subscriptions.insert(req_id, sub);
+        self.subscription_ids.insert(sub, req_id);
+        writer.write_all(&request).await?;
+        tracing::info!("Subscribed to {sub:?}");
+        Ok(())
+    }
+
+    async fn unsubscribe<W: AsyncWrite + Unpin>(
+        &mut self,
+        sub: Subscription,
+        writer: &mut W,
+    ) -> anyhow::Result<()> {
+        if let Some(pending) = self.pending_subs.get_mut(&sub.instrument_id()) {
+            pending.retain(|s| *s != sub);
+        }
+
+        let Some(req_id) = self.subscription_ids.remove(&sub) else {
+            tracing::warn!("Not subscribed to {sub:?}");
+            return Ok(());
+        };
+        self.subscriptions.remove(&req_id);
+        self.ladders.remove(&req_id);
+        writer.write_all(&sub.encode_cancel(req_id)).await?;
+        tracing::info!("Unsubscribed from {sub:?}");
+        Ok(())
+    }
+
+    async fn handle_payload<W: AsyncWrite + Unpin>(
+        &mut self,
+        payload: &[u8],
+        writer: &mut W,
+    ) -> anyhow::Result<()> {
+        let ts_init = get_atomic_clock_realtime().get_time_ns();
+
+        match decode_message(payload)? {
+            IBMessage::NextValidId(_) => {
+                if !self.is_ready {
+                    tracing::debug!("Ready");
+                    self.is_ready = true;
+                    for cmd in std::mem::take(&mut self.queued_cmds) {
+                        self.handle_command(cmd, writer).await?;
+                    }
+                }
+            }
+            IBMessage::ManagedAccounts(accounts) => {
+                tracing::debug!("Managed accounts: {accounts}");
+            }
+            IBMessage::CurrentTime(_) | IBMessage::Unhandled(_) => {}
+            IBMessage::Error {
+                req_id,
+                code,
+                message,
+            } => self.handle_error(req_id, code, &message),
+            IBMessage::ContractData { req_id, details } => {
+                let Some(instrument_id) = self.contract_requests.get(&req_id).copied() else {
+                    return Ok(());
+                };
+                if self.contracts.contains_key(&instrument_id) {
+                    tracing::warn!("Ambiguous contract for {instrument_id}, using first match");
+                    return Ok(());
+                }
+
+                let instrument = parse_instrument(&details, instrument_id, ts_init)?;
+                self.contracts
+                    .insert(instrument_id, details.contract.clone());
+                self.instruments.insert(instrument_id, instrument.clone());
+                self.send_msg(LiveMessage::Instrument(instrument)).await;
+            }
+            IBMessage::ContractDataEnd { req_id } => {
+                let Some(instrument_id) = self.contract_requests.remove(&req_id) else {
+                    return Ok(());
+                };
+                let pending = self.pending_subs.remove(&instrument_id).unwrap_or_default();
+                if !self.contracts.contains_key(&instrument_id) {
+                    tracing::error!("No contract found for {instrument_id}");
+                    return Ok(());
+                }
+                for sub in pending {
+                    self.subscribe(sub, writer).await?;
+                }
+            }
+            IBMessage::TickByTick { req_id, time, tick } => {
+                self.handle_tick_by_tick(req_id, secs_to_nanos(time), &tick, ts_init)
+                    .await?;
+            }
+            IBMessage::MarketDepth(update) => {
+                self.handle_market_depth(&update, ts_init).await;
+            }
+            IBMessage::RealTimeBar { req_id, bar } => {
+                let Some(Subscription::Bars(bar_type)) = self.subscriptions.get(&req_id).copied()
+                else {
+                    return Ok(());
+                };
+                let instrument = self.instrument(&bar_type.instrument_id())?;
+                let bar = parse_bar(instrument, bar_type, &bar, ts_init)?;
+                self.send_msg(LiveMessage::Data(Data::Bar(bar))).await;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn handle_tick_by_tick(
+        &mut self,
+        req_id: i32,
+        ts_event: UnixNanos,
+        tick: &TickByTick,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<()> {
+        let Some(sub) = self.subscriptions.get(&req_id) else {
+            return Ok(());
+        };
+        let instrument = self.instrument(&sub.instrument_id())?;
+
+        let data = match tick {
+            TickByTick::BidAsk {
+                bid_price,
+                ask_price,
+                bid_size,
+                ask_size,
+            } => Data::Quote(parse_quote_tick(
+                instrument, *bid_price, *ask_price, *bid_size, *ask_size, ts_event, ts_init,
+            )?),
+            TickByTick::Last { price, size, .. } => {
+                if size.is_none_or(|s| s.is_zero()) {
+                    return Ok(()); // Price only update
+                }
+                Data::Trade(parse_trade_tick(
+                    instrument, *price, *size, ts_event, ts_init,
+                )?)
+            }
+            TickByTick::MidPoint { .. } => return Ok(()),
+        };
+
+        self.send_msg(LiveMessage::Data(data)).await;
+        Ok(())
+    }
+
+    async fn handle_market_depth(&mut self, update: &MarketDepthUpdate, ts_init: UnixNanos) {
+        let Some(ladder) = self.ladders.get_mut(&update.req_id) else {
+            return;
+        };
+        let Some(sub) = self.subscriptions.get(&update.req_id) else {
+            return;
+        };
+        let Some(instrument) = self.instruments.get(&sub.instrument_id()) else {
+            return;
+        };
+
+        // Depth updates are not timestamped by IB
+        let deltas = ladder.apply(instrument, update, ts_init, ts_init);
+        if deltas.is_empty() {
+            return;
+        }
+
+        let deltas = OrderBookDeltas::new(instrument.id(), deltas);
+        let data = Data::Deltas(OrderBookDeltas_API::new(deltas));
+        self.send_msg(LiveMessage::Data(data)).await;
+    }
+
+    fn handle_error(&mut self, req_id: i32, code: i32, message: &str) {
+        if NOTICE_CODES.contains(&code) {
+            tracing::info!("{code}: {message}");
+        } else if let Some(instrument_id) = self.contract_requests.get(&req_id) {
+            tracing::error!(
+                "Contract details request for {instrument_id} failed {code}: {message}"
+            );
+            if code == NO_SECURITY_DEFINITION {
+                let instrument_id = *instrument_id;
+                self.contract_requests.remove(&req_id);
+                self.pending_subs.remove(&instrument_id);
+            }
+        } else if let Some(sub) = self.subscriptions.remove(&req_id) {
+            tracing::error!("Subscription {sub:?} failed {code}: {message}");
+            self.subscription_ids.remove(&sub);
+            self.ladders.remove(&req_id);
+        } else {
+            tracing::warn!("{code}: {message} (req_id={req_id})");
+        }
+    }
+
+    fn instrument(&self, instrument_id: &InstrumentId) -> anyhow::Result<&InstrumentAny> {
+        self.instruments
+            .get(instrument_id)
+            .ok_or_else(|| anyhow::anyhow!("Instrument {instrument_id} not found"))
+    }
+
+    fn next_req_id(&mut self) -> i32 {
+        let req_id = self.next_req_id;
+        self.next_req_id += 1;
+        req_id
+    }
+
+    async fn send_msg(&mut self, msg: LiveMessage) {
+        tracing::trace!("Sending {msg:?}");
+        match self.msg_tx.send(msg).await {
+            Ok(()) => {}
+            Err(e) => tracing::error!("Error sending message: {e}"),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{data::QuoteTick, types::Price};
+    use rstest::rstest;
+    use tokio::{net::TcpListener, sync::mpsc::Receiver};
+
+    use super::*;
+    use crate::protocol::{
+        frame,
+        messages::tests::{contract_data_fields, payload},
+    };
+
+    async fn drain_messages(mut msg_rx: Receiver<LiveMessage>) -> Vec<LiveMessage> {
+        let mut msgs = Vec::new();
+        while let Some(msg) = msg_rx.recv().await {
+            msgs.push(msg);
+        }
+        msgs
+    }
+
+    async fn expect_message(stream: &mut TcpStream, fields: &[&str]) {
+        let received = read_frame(stream).await.unwrap();
+        assert_eq!(received, payload(fields));
+    }
+
+    async fn send_message(stream: &mut TcpStream, fields: &[&str]) {
+        stream.write_all(&frame(&payload(fields))).await.unwrap();
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_feed_handler_qualifies_contract_and_streams_quotes() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        // Mock gateway
+        let gateway = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut prefix = [0; 4];
+            tokio::io::AsyncReadExt::read_exact(&mut stream, &mut prefix)
+                .await
+                .unwrap();
+            assert_eq!(&prefix, b"API\0");
+            assert_eq!(read_frame(&mut stream).await.unwrap(), b"v176..176");
+            stream
+                .write_all(&frame(&payload(&["176", "20250101 00:00:00 UTC"])))
+                .await
+                .unwrap();
+            expect_message(&mut stream, &["71", "2", "7", ""]).await;
+            send_message(&mut stream, &["9", "1", "1"]).await;
+
+            // Contract details request, then subscription once qualified
+            expect_message(
+                &mut stream,
+                &[
+                    "9", "8", "1", "0", "", "STK", "", "0.0", "", "", "SMART", "NASDAQ", "",
+                    "AAPL", "", "0", "", "", "",
+                ],
+            )
+            .await;
+            send_message(&mut stream, &contract_data_fields()).await;
+            send_message(&mut stream, &["52", "1", "1"]).await;
+            expect_message(
+                &mut stream,
+                &[
+                    "97", "2", "265598", "AAPL", "STK", "", "0.0", "", "", "SMART", "NASDAQ",
+                    "USD", "AAPL", "NMS", "BidAsk", "0", "0",
+                ],
+            )
+            .await;
+            send_message(
+                &mut stream,
+                &[
+                    "99",
+                    "2",
+                    "3",
+                    "1700000000",
+                    "189.5",
+                    "189.51",
+                    "300",
+                    "200",
+                    "0",
+                ],
+            )
+            .await;
+
+            // Hold the connection open until the client closes
+            let _ = read_frame(&mut stream).await;
+        });
+
+        let config = InteractiveBrokersDataClientConfig {
+            port,
+            client_id: 7,
+            ..Default::default()
+        };
+        let (cmd_tx, cmd_rx) = tokio::sync::mpsc::unbounded_channel();
+        let (msg_tx, mut msg_rx) = tokio::sync::mpsc::channel(100);
+        let mut handler = InteractiveBrokersFeedHandler::new(config, cmd_rx, msg_tx);
+        let instrument_id = InstrumentId::from("AAPL=STK.NASDAQ");
+        cmd_tx
+            .send(LiveCommand::Subscribe(Subscription::Quotes(instrument_id)))
+            .unwrap();
+        let handle = tokio::spawn(async move { handler.run().await });
+
+        let Some(LiveMessage::Instrument(instrument)) = msg_rx.recv().await else {
+            panic!("Expected instrument");
+        };
+        assert_eq!(instrument.id(), instrument_id);
+
+        let Some(LiveMessage::Data(Data::Quote(quote))) = msg_rx.recv().await else {
+            panic!("Expected quote");
+        };
+        assert_eq!(
+            quote,
+            QuoteTick {
+                ts_init: quote.ts_init,
+                ..QuoteTick::new(
+                    instrument_id,
+                    Price::from("189.50"),
+                    Price::from("189.51"),
+                    instrument.make_qty(300.0),
+                    instrument.make_qty(200.0),
+                    secs_to_nanos(1_700_000_000),
+                    quote.ts_init,
+                )
+            }
+        );
+
+        cmd_tx.send(LiveCommand::Close).unwrap();
+        handle.await.unwrap().unwrap();
+        assert!(matches!(
+            drain_messages(msg_rx).await.as_slice(),
+            [LiveMessage::Close]
+        ));
+        gateway.abort();
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_feed_handler_connection_refused() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        drop(listener);
+
+        let config = InteractiveBrokersDataClientConfig {
+            port,
+            ..Default::default()
+        };
+        let (_cmd_tx, cmd_rx) = tokio::sync::mpsc::unbounded_channel();
+        let (msg_tx, msg_rx) = tokio::sync::mpsc::channel(100);
+        let mut handler = InteractiveBrokersFeedHandler::new(config, cmd_rx, msg_tx);
+
+        assert!(handler.run().await.is_err());
+        drop(handler);
+        assert!(matches!(
+            drain_messages(msg_rx).await.as_slice(),
+            [LiveMessage::Error(_)]
+        ));
+    }
+}
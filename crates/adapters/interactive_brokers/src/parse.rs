@@ -0,0 +1,777 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Parsing functions to convert IB API messages into Nautilus domain objects.
+
+use std::str::FromStr;
+
+use chrono::{NaiveDate, TimeDelta};
+use nautilus_core::{parsing::precision_from_str, UnixNanos};
+use nautilus_model::{
+    data::{Bar, BarType, BookOrder, OrderBookDelta, QuoteTick, TradeTick},
+    enums::{
+        AggressorSide, AssetClass, BarAggregation, BookAction, OptionKind, OrderSide, PegPriceType,
+        PriceType, RecordFlag,
+    },
+    identifiers::{InstrumentId, Symbol, TradeId},
+    instruments::{CurrencyPair, Equity, FuturesContract, InstrumentAny, OptionContract},
+    orders::OrderAny,
+    types::{Currency, Price, Quantity},
+};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use ustr::Ustr;
+
+use crate::{
+    contract::IBContractDetails,
+    protocol::messages::{MarketDepthUpdate, RealTimeBar, REAL_TIME_BAR_SIZE_SECS},
+};
+
+/// Approximate activation period for derivatives, as IB does not provide listing dates.
+const ACTIVATION_PERIOD_DAYS: i64 = 90;
+
+const NANOS_PER_SEC: u64 = 1_000_000_000;
+
+/// Converts UNIX seconds (as sent by the API) to [`UnixNanos`].
+#[must_use]
+pub fn secs_to_nanos(secs: i64) -> UnixNanos {
+    UnixNanos::from(secs.max(0) as u64 * NANOS_PER_SEC)
+}
+
+/// Returns the IB `what_to_show` value for the given bar type.
+///
+/// # Errors
+///
+/// Returns an error if the bar type is not 5-SECOND externally aggregated bars.
+pub fn bar_type_to_what_to_show(bar_type: &BarType) -> anyhow::Result<&'static str> {
+    let spec = bar_type.spec();
+    if spec.aggregation != BarAggregation::Second
+        || spec.step.get() != REAL_TIME_BAR_SIZE_SECS as usize
+    {
+        anyhow::bail!("Only 5-SECOND real-time bars are supported, was {bar_type}");
+    }
+
+    Ok(match spec.price_type {
+        PriceType::Last => "TRADES",
+        PriceType::Mid => "MIDPOINT",
+        PriceType::Bid => "BID",
+        PriceType::Ask => "ASK",
+        price_type => anyhow::bail!("Unsupported price type for bars: {price_type:?}"),
+    })
+}
+
+/// Returns the IB order type and auxiliary (offset) price of a pegged limit order.
+///
+/// IB offsets pegs to the primary (`REL`) towards a more aggressive price, and pegs to the
+/// midpoint (`PEG MID`) or market (`PEG MKT`) towards a more passive price, so only peg offsets
+/// in those directions can be mapped.
+///
+/// # Errors
+///
+/// Returns an error if the order is not pegged, or its peg offset cannot be mapped.
+pub fn peg_order_type(order: &OrderAny) -> anyhow::Result<(&'static str, Decimal)> {
+    let Some(peg_price_type) = order.peg_price_type() else {
+        anyhow::bail!("Order {} is not pegged", order.client_order_id());
+    };
+    let offset = order.peg_offset().unwrap_or_default();
+
+    match peg_price_type {
+        PegPriceType::Primary if offset <= Decimal::ZERO => Ok(("REL", -offset)),
+        PegPriceType::Midpoint if offset >= Decimal::ZERO => Ok(("PEG MID", offset)),
+        PegPriceType::Market if offset >= Decimal::ZERO => Ok(("PEG MKT", offset)),
+        _ => anyhow::bail!("Unsupported peg offset {offset} for {peg_price_type} peg"),
+    }
+}
+
+/// Returns the IB display size of an iceberg order, or `None` if the whole order is displayed.
+///
+/// # Errors
+///
+/// Returns an error if the order is fully hidden (a zero `display_qty`).
+pub fn display_size(order: &OrderAny) -> anyhow::Result<Option<Decimal>> {
+    let Some(display_qty) = order.display_qty() else {
+        return Ok(None);
+    };
+    if display_qty >= order.quantity() {
+        return Ok(None);
+    }
+    if display_qty.is_zero() {
+        anyhow::bail!(
+            "Hidden orders are not supported, `display_qty` was 0 for {}",
+            order.client_order_id()
+        );
+    }
+
+    Ok(Some(display_qty.as_decimal()))
+}
+
+/// Parses a Nautilus instrument from the given contract details.
+///
+/// # Errors
+///
+/// Returns an error if the security type is not supported or a field is invalid.
+pub fn parse_instrument(
+    details: &IBContractDetails,
+    instrument_id: InstrumentId,
+    ts_init: UnixNanos,
+) -> anyhow::Result<InstrumentAny> {
+    let contract = &details.contract;
+    let raw_symbol = Symbol::new_checked(&contract.local_symbol)?;
+    let currency = Currency::from_str(&contract.currency)?;
+    let price_precision = precision_from_str(&details.min_tick.to_string());
+    let price_increment = Price::new(details.min_tick, price_precision);
+
+    let instrument = match contract.sec_type.as_str() {
+        "STK" => InstrumentAny::Equity(Equity::new_checked(
+            instrument_id,
+            raw_symbol,
+            details.sec_id("ISIN").map(Ustr::from),
+            currency,
+            price_precision,
+            price_increment,
+            Some(Quantity::from(100)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            ts_init,
+            ts_init,
+        )?),
+        "FUT" | "CONTFUT" => {
+            let expiration_ns = parse_expiration(details)?;
+            InstrumentAny::FuturesContract(FuturesContract::new_checked(
+                instrument_id,
+                raw_symbol,
+                parse_asset_class(&details.under_sec_type),
+                Some(Ustr::from(&contract.exchange)),
+                Ustr::from(&details.under_symbol),
+                activation_from_expiration(expiration_ns),
+                expiration_ns,
+                currency,
+                price_precision,
+                price_increment,
+                parse_multiplier(&contract.multiplier)?,
+                Quantity::from(1),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                ts_init,
+                ts_init,
+            )?)
+        }
+        "OPT" | "FOP" => {
+            let expiration_ns = parse_expiration(details)?;
+            let option_kind = match contract.right.as_str() {
+                "C" | "CALL" => OptionKind::Call,
+                "P" | "PUT" => OptionKind::Put,
+                right => anyhow::bail!("Invalid option right '{right}'"),
+            };
+            InstrumentAny::OptionContract(OptionContract::new_checked(
+                instrument_id,
+                raw_symbol,
+                parse_asset_class(&details.under_sec_type),
+                Some(Ustr::from(&contract.exchange)),
+                Ustr::from(&details.under_symbol),
+                option_kind,
+                Price::new(contract.strike, price_precision),
+                currency,
+                activation_from_expiration(expiration_ns),
+                expiration_ns,
+                price_precision,
+                price_increment,
+                parse_multiplier(&contract.multiplier)?,
+                Quantity::from(1),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                ts_init,
+                ts_init,
+            )?)
+        }
+        "CASH" => {
+            let size_increment = details
+                .size_increment
+                .or(details.min_size)
+                .unwrap_or(Decimal::ONE);
+            let size_precision = size_increment.normalize().scale() as u8;
+            InstrumentAny::CurrencyPair(CurrencyPair::new_checked(
+                instrument_id,
+                raw_symbol,
+                Currency::from_str(&contract.symbol)?,
+                currency,
+                price_precision,
+                size_precision,
+                price_increment,
+                Quantity::new(decimal_to_f64(size_increment), size_precision),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                ts_init,
+                ts_init,
+            )?)
+        }
+        sec_type => anyhow::bail!("Unsupported security type '{sec_type}'"),
+    };
+
+    Ok(instrument)
+}
+
+/// Parses a quote from a tick-by-tick `BidAsk` update.
+///
+/// # Errors
+///
+/// Returns an error if the quote is invalid for the instrument.
+pub fn parse_quote_tick(
+    instrument: &InstrumentAny,
+    bid_price: f64,
+    ask_price: f64,
+    bid_size: Option<Decimal>,
+    ask_size: Option<Decimal>,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> anyhow::Result<QuoteTick> {
+    QuoteTick::new_checked(
+        instrument.id(),
+        instrument.make_price(bid_price),
+        instrument.make_price(ask_price),
+        instrument.make_qty(bid_size.map_or(0.0, decimal_to_f64)),
+        instrument.make_qty(ask_size.map_or(0.0, decimal_to_f64)),
+        ts_event,
+        ts_init,
+    )
+}
+
+/// Parses a trade from a tick-by-tick `AllLast` update.
+///
+/// IB does not provide trade IDs, so an ID is derived from the time, price and size.
+///
+/// # Errors
+///
+/// Returns an error if the trade is invalid for the instrument (e.g. zero size).
+pub fn parse_trade_tick(
+    instrument: &InstrumentAny,
+    price: f64,
+    size: Option<Decimal>,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> anyhow::Result<TradeTick> {
+    let size = size.unwrap_or_default();
+    let trade_id = TradeId::new_checked(format!(
+        "{}-{price}-{}",
+        ts_event.as_u64() / NANOS_PER_SEC,
+        size.normalize()
+    ))?;
+
+    TradeTick::new_checked(
+        instrument.id(),
+        instrument.make_price(price),
+        instrument.make_qty(decimal_to_f64(size)),
+        AggressorSide::NoAggressor,
+        trade_id,
+        ts_event,
+        ts_init,
+    )
+}
+
+/// Parses a bar from a 5-second real-time bar, where `ts_event` is the bar close.
+///
+/// # Errors
+///
+/// Returns an error if the bar is invalid.
+pub fn parse_bar(
+    instrument: &InstrumentAny,
+    bar_type: BarType,
+    bar: &RealTimeBar,
+    ts_init: UnixNanos,
+) -> anyhow::Result<Bar> {
+    Bar::new_checked(
+        bar_type,
+        instrument.make_price(bar.open),
+        instrument.make_price(bar.high),
+        instrument.make_price(bar.low),
+        instrument.make_price(bar.close),
+        instrument.make_qty(bar.volume.map_or(0.0, decimal_to_f64)),
+        secs_to_nanos(bar.time + i64::from(REAL_TIME_BAR_SIZE_SECS)),
+        ts_init,
+    )
+}
+
+/// Maintains the positional market depth for a subscription, converting IB
+/// position-based updates into price level order book deltas.
+#[derive(Debug)]
+pub struct DepthLadder {
+    instrument_id: InstrumentId,
+    bids: Vec<(Price, Quantity)>,
+    asks: Vec<(Price, Quantity)>,
+    is_initialized: bool,
+    sequence: u64,
+}
+
+impl DepthLadder {
+    /// Creates a new [`DepthLadder`] instance.
+    #[must_use]
+    pub const fn new(instrument_id: InstrumentId) -> Self {
+        Self {
+            instrument_id,
+            bids: Vec::new(),
+            asks: Vec::new(),
+            is_initialized: false,
+            sequence: 0,
+        }
+    }
+
+    /// Applies the update and returns the resulting deltas.
+    ///
+    /// The first update for the ladder is preceded by a `Clear` delta.
+    pub fn apply(
+        &mut self,
+        instrument: &InstrumentAny,
+        update: &MarketDepthUpdate,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Vec<OrderBookDelta> {
+        let mut deltas = Vec::with_capacity(3);
+
+        if !self.is_initialized {
+            self.is_initialized = true;
+            deltas.push(OrderBookDelta::clear(
+                self.instrument_id,
+                self.sequence,
+                ts_event,
+                ts_init,
+            ));
+        }
+
+        let (side, levels) = if update.side == 1 {
+            (OrderSide::Buy, &mut self.bids)
+        } else {
+            (OrderSide::Sell, &mut self.asks)
+        };
+        let position = (update.position.max(0) as usize).min(levels.len());
+        let price = instrument.make_price(update.price);
+        let size = instrument.make_qty(update.size.map_or(0.0, decimal_to_f64));
+
+        let mut changes: Vec<(BookAction, Price, Quantity)> = Vec::with_capacity(2);
+        match update.operation {
+            1 if position < levels.len() => {
+                let (old_price, _) = levels[position];
+                levels[position] = (price, size);
+                if old_price == price {
+                    changes.push((BookAction::Update, price, size));
+                } else {
+                    changes.push((
+                        BookAction::Delete,
+                        old_price,
+                        Quantity::zero(size.precision),
+                    ));
+                    changes.push((BookAction::Add, price, size));
+                }
+            }
+            2 => {
+                if position < levels.len() {
+                    let (old_price, _) = levels.remove(position);
+                    changes.push((
+                        BookAction::Delete,
+                        old_price,
+                        Quantity::zero(size.precision),
+                    ));
+                }
+            }
+            _ => {
+                // Insert (or update beyond the current depth)
+                levels.insert(position, (price, size));
+                changes.push((BookAction::Add, price, size));
+            }
+        }
+
+        let count = changes.len();
+        for (i, (action, price, size)) in changes.into_iter().enumerate() {
+            self.sequence += 1;
+            let flags = if i == count - 1 {
+                RecordFlag::F_LAST as u8
+            } else {
+                0
+            };
+            deltas.push(OrderBookDelta::new(
+                self.instrument_id,
+                action,
+                BookOrder::new(side, price, size, 0),
+                flags,
+                self.sequence,
+                ts_event,
+                ts_init,
+            ));
+        }
+
+        deltas
+    }
+}
+
+fn decimal_to_f64(value: Decimal) -> f64 {
+    value.to_f64().unwrap_or_default()
+}
+
+fn parse_asset_class(under_sec_type: &str) -> AssetClass {
+    match under_sec_type {
+        "STK" => AssetClass::Equity,
+        "CASH" => AssetClass::FX,
+        "BOND" => AssetClass::Debt,
+        "CMDTY" => AssetClass::Commodity,
+        "CRYPTO" => AssetClass::Cryptocurrency,
+        _ => AssetClass::Index,
+    }
+}
+
+fn parse_multiplier(multiplier: &str) -> anyhow::Result<Quantity> {
+    if multiplier.is_empty() {
+        return Ok(Quantity::from(1));
+    }
+    Quantity::from_str(multiplier).map_err(|e| anyhow::anyhow!("Invalid multiplier: {e}"))
+}
+
+fn parse_expiration(details: &IBContractDetails) -> anyhow::Result<UnixNanos> {
+    let value = if details.real_expiration_date.is_empty() {
+        &details.contract.last_trade_date_or_contract_month
+    } else {
+        &details.real_expiration_date
+    };
+    let date = NaiveDate::parse_from_str(value, "%Y%m%d")
+        .map_err(|e| anyhow::anyhow!("Invalid expiration date '{value}': {e}"))?;
+    let datetime = date.and_hms_opt(0, 0, 0).expect("valid time").and_utc();
+    Ok(UnixNanos::from(
+        datetime.timestamp_nanos_opt().unwrap_or_default() as u64,
+    ))
+}
+
+fn activation_from_expiration(expiration_ns: UnixNanos) -> UnixNanos {
+    let period = TimeDelta::days(ACTIVATION_PERIOD_DAYS)
+        .num_nanoseconds()
+        .unwrap_or_default();
+    UnixNanos::from(expiration_ns.as_u64().saturating_sub(period as u64))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::OrderType, identifiers::InstrumentId, instruments::Instrument,
+        orders::OrderTestBuilder,
+    };
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::{
+        contract::IBContract,
+        protocol::messages::{decode_message, tests::contract_data_fields, IBMessage},
+    };
+
+    fn equity_details() -> IBContractDetails {
+        let payload = crate::protocol::messages::tests::payload(&contract_data_fields());
+        match decode_message(&payload).unwrap() {
+            IBMessage::ContractData { details, .. } => *details,
+            msg => panic!("Expected contract data, was {msg:?}"),
+        }
+    }
+
+    fn equity() -> InstrumentAny {
+        let details = equity_details();
+        parse_instrument(&details, InstrumentId::from("AAPL=STK.NASDAQ"), 0.into()).unwrap()
+    }
+
+    #[rstest]
+    fn test_parse_equity() {
+        let instrument = equity();
+
+        let InstrumentAny::Equity(equity) = &instrument else {
+            panic!("Expected equity, was {instrument:?}");
+        };
+        assert_eq!(equity.id(), InstrumentId::from("AAPL=STK.NASDAQ"));
+        assert_eq!(equity.raw_symbol().as_str(), "AAPL");
+        assert_eq!(equity.isin(), Some(Ustr::from("US0378331005")));
+        assert_eq!(equity.price_precision(), 2);
+        assert_eq!(equity.price_increment(), Price::from("0.01"));
+    }
+
+    #[rstest]
+    fn test_parse_futures_contract() {
+        let details = IBContractDetails {
+            contract: IBContract {
+                sec_type: "FUT".to_string(),
+                symbol: "ES".to_string(),
+                local_symbol: "ESH5".to_string(),
+                exchange: "CME".to_string(),
+                currency: "USD".to_string(),
+                multiplier: "50".to_string(),
+                last_trade_date_or_contract_month: "20250321".to_string(),
+                ..Default::default()
+            },
+            min_tick: 0.25,
+            under_symbol: "ES".to_string(),
+            under_sec_type: "IND".to_string(),
+            ..Default::default()
+        };
+
+        let instrument =
+            parse_instrument(&details, InstrumentId::from("ESH5=FUT.CME"), 0.into()).unwrap();
+
+        let InstrumentAny::FuturesContract(future) = &instrument else {
+            panic!("Expected futures contract, was {instrument:?}");
+        };
+        assert_eq!(future.asset_class(), AssetClass::Index);
+        assert_eq!(future.multiplier(), Quantity::from(50));
+        assert_eq!(future.price_increment(), Price::from("0.25"));
+        assert_eq!(
+            future.expiration_ns(),
+            Some(UnixNanos::from(1_742_515_200_000_000_000))
+        );
+    }
+
+    #[rstest]
+    fn test_parse_currency_pair() {
+        let details = IBContractDetails {
+            contract: IBContract {
+                sec_type: "CASH".to_string(),
+                symbol: "EUR".to_string(),
+                local_symbol: "EUR.USD".to_string(),
+                exchange: "IDEALPRO".to_string(),
+                currency: "USD".to_string(),
+                ..Default::default()
+            },
+            min_tick: 0.00005,
+            size_increment: Some(dec!(0.01)),
+            ..Default::default()
+        };
+
+        let instrument = parse_instrument(
+            &details,
+            InstrumentId::from("EUR.USD=CASH.IDEALPRO"),
+            0.into(),
+        )
+        .unwrap();
+
+        assert_eq!(instrument.price_precision(), 5);
+        assert_eq!(instrument.size_precision(), 2);
+        assert_eq!(instrument.base_currency(), Some(Currency::EUR()));
+    }
+
+    #[rstest]
+    fn test_parse_unsupported_sec_type() {
+        let details = IBContractDetails {
+            contract: IBContract {
+                local_symbol: "X".to_string(),
+                sec_type: "BAG".to_string(),
+                currency: "USD".to_string(),
+                ..Default::default()
+            },
+            min_tick: 0.01,
+            ..Default::default()
+        };
+
+        assert!(parse_instrument(&details, InstrumentId::from("X=BAG.SMART"), 0.into()).is_err());
+    }
+
+    #[rstest]
+    fn test_parse_trade_tick_derives_trade_id() {
+        let instrument = equity();
+
+        let trade = parse_trade_tick(
+            &instrument,
+            189.5,
+            Some(dec!(100)),
+            secs_to_nanos(1_700_000_000),
+            0.into(),
+        )
+        .unwrap();
+
+        assert_eq!(trade.trade_id.to_string(), "1700000000-189.5-100");
+        assert_eq!(trade.size, Quantity::from(100));
+        assert_eq!(trade.aggressor_side, AggressorSide::NoAggressor);
+    }
+
+    #[rstest]
+    fn test_parse_bar_uses_close_time() {
+        let instrument = equity();
+        let bar_type = BarType::from("AAPL=STK.NASDAQ-5-SECOND-LAST-EXTERNAL");
+        let rt_bar = RealTimeBar {
+            time: 1_700_000_000,
+            open: 189.5,
+            high: 189.6,
+            low: 189.4,
+            close: 189.55,
+            volume: Some(dec!(1200)),
+            wap: None,
+            count: 14,
+        };
+
+        let bar = parse_bar(&instrument, bar_type, &rt_bar, 0.into()).unwrap();
+
+        assert_eq!(bar.ts_event, secs_to_nanos(1_700_000_005));
+        assert_eq!(bar.close, Price::from("189.55"));
+    }
+
+    #[rstest]
+    #[case("AAPL=STK.NASDAQ-5-SECOND-LAST-EXTERNAL", Some("TRADES"))]
+    #[case("AAPL=STK.NASDAQ-5-SECOND-MID-EXTERNAL", Some("MIDPOINT"))]
+    #[case("AAPL=STK.NASDAQ-1-MINUTE-LAST-EXTERNAL", None)]
+    fn test_bar_type_to_what_to_show(#[case] bar_type: &str, #[case] expected: Option<&str>) {
+        let result = bar_type_to_what_to_show(&BarType::from(bar_type));
+
+        assert_eq!(result.ok(), expected);
+    }
+
+    #[rstest]
+    #[case(PegPriceType::Primary, dec!(-0.05), Some(("REL", dec!(0.05))))]
+    #[case(PegPriceType::Primary, dec!(0.05), None)]
+    #[case(PegPriceType::Midpoint, dec!(0.02), Some(("PEG MID", dec!(0.02))))]
+    #[case(PegPriceType::Market, dec!(0), Some(("PEG MKT", dec!(0))))]
+    #[case(PegPriceType::Market, dec!(-0.01), None)]
+    fn test_peg_order_type(
+        #[case] peg_price_type: PegPriceType,
+        #[case] offset: Decimal,
+        #[case] expected: Option<(&str, Decimal)>,
+    ) {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(InstrumentId::from("AAPL=STK.NASDAQ"))
+            .side(OrderSide::Buy)
+            .price(Price::from("189.50"))
+            .peg_price_type(peg_price_type)
+            .peg_offset(offset)
+            .quantity(Quantity::from(100))
+            .build();
+
+        assert_eq!(peg_order_type(&order).ok(), expected);
+    }
+
+    #[rstest]
+    fn test_peg_order_type_when_not_pegged() {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(InstrumentId::from("AAPL=STK.NASDAQ"))
+            .side(OrderSide::Buy)
+            .price(Price::from("189.50"))
+            .quantity(Quantity::from(100))
+            .build();
+
+        assert!(peg_order_type(&order).is_err());
+    }
+
+    #[rstest]
+    #[case(None, Ok(None))]
+    #[case(Some(100), Ok(None))]
+    #[case(Some(20), Ok(Some(dec!(20))))]
+    #[case(Some(0), Err(()))]
+    fn test_display_size(
+        #[case] display_qty: Option<u64>,
+        #[case] expected: Result<Option<Decimal>, ()>,
+    ) {
+        let mut builder = OrderTestBuilder::new(OrderType::Limit);
+        builder
+            .instrument_id(InstrumentId::from("AAPL=STK.NASDAQ"))
+            .side(OrderSide::Buy)
+            .price(Price::from("189.50"))
+            .quantity(Quantity::from(100));
+        if let Some(display_qty) = display_qty {
+            builder.display_qty(Quantity::from(display_qty));
+        }
+        let order = builder.build();
+
+        assert_eq!(display_size(&order).map_err(|_| ()), expected);
+    }
+
+    #[rstest]
+    fn test_depth_ladder() {
+        let instrument = equity();
+        let mut ladder = DepthLadder::new(instrument.id());
+        let update = |position, operation, price, size| MarketDepthUpdate {
+            req_id: 1,
+            position,
+            market_maker: String::new(),
+            operation,
+            side: 1,
+            price,
+            size: Some(size),
+        };
+
+        let deltas = ladder.apply(
+            &instrument,
+            &update(0, 0, 189.5, dec!(100)),
+            0.into(),
+            0.into(),
+        );
+        assert_eq!(deltas.len(), 2);
+        assert_eq!(deltas[0].action, BookAction::Clear);
+        assert_eq!(deltas[1].action, BookAction::Add);
+        assert_eq!(deltas[1].flags, RecordFlag::F_LAST as u8);
+
+        let deltas = ladder.apply(
+            &instrument,
+            &update(0, 1, 189.5, dec!(200)),
+            0.into(),
+            0.into(),
+        );
+        assert_eq!(deltas.len(), 1);
+        assert_eq!(deltas[0].action, BookAction::Update);
+        assert_eq!(deltas[0].order.size, Quantity::from(200));
+
+        let deltas = ladder.apply(
+            &instrument,
+            &update(0, 1, 189.6, dec!(50)),
+            0.into(),
+            0.into(),
+        );
+        assert_eq!(deltas.len(), 2);
+        assert_eq!(deltas[0].action, BookAction::Delete);
+        assert_eq!(deltas[0].order.price, Price::from("189.50"));
+        assert_eq!(deltas[0].flags, 0);
+        assert_eq!(deltas[1].action, BookAction::Add);
+        assert_eq!(deltas[1].order.price, Price::from("189.60"));
+
+        let deltas = ladder.apply(
+            &instrument,
+            &update(0, 2, 189.6, dec!(0)),
+            0.into(),
+            0.into(),
+        );
+        assert_eq!(deltas.len(), 1);
+        assert_eq!(deltas[0].action, BookAction::Delete);
+        assert_eq!(deltas[0].sequence, 5);
+    }
+}
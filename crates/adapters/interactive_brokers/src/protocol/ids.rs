@@ -0,0 +1,59 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Message identifiers and tick types for the IB API.
+
+/// Outgoing (client to server) message IDs.
+pub mod outgoing {
+    pub const REQ_CONTRACT_DATA: i32 = 9;
+    pub const REQ_MKT_DEPTH: i32 = 10;
+    pub const CANCEL_MKT_DEPTH: i32 = 11;
+    pub const REQ_CURRENT_TIME: i32 = 49;
+    pub const REQ_REAL_TIME_BARS: i32 = 50;
+    pub const CANCEL_REAL_TIME_BARS: i32 = 51;
+    pub const START_API: i32 = 71;
+    pub const REQ_TICK_BY_TICK_DATA: i32 = 97;
+    pub const CANCEL_TICK_BY_TICK_DATA: i32 = 98;
+}
+
+/// Incoming (server to client) message IDs.
+pub mod incoming {
+    pub const ERR_MSG: i32 = 4;
+    pub const NEXT_VALID_ID: i32 = 9;
+    pub const CONTRACT_DATA: i32 = 10;
+    pub const MARKET_DEPTH: i32 = 12;
+    pub const MARKET_DEPTH_L2: i32 = 13;
+    pub const MANAGED_ACCTS: i32 = 15;
+    pub const CURRENT_TIME: i32 = 49;
+    pub const REAL_TIME_BARS: i32 = 50;
+    pub const CONTRACT_DATA_END: i32 = 52;
+    pub const TICK_BY_TICK: i32 = 99;
+}
+
+/// Tick-by-tick data types (as returned in `TICK_BY_TICK` messages).
+pub mod tick_by_tick {
+    pub const LAST: i32 = 1;
+    pub const ALL_LAST: i32 = 2;
+    pub const BID_ASK: i32 = 3;
+    pub const MID_POINT: i32 = 4;
+}
+
+/// Error codes which are informational notices rather than failures.
+///
+/// These are sent on connection to report the state of the market data farms.
+pub const NOTICE_CODES: [i32; 7] = [2104, 2106, 2107, 2108, 2119, 2158, 2176];
+
+/// Error code when no contract matches a contract details request.
+pub const NO_SECURITY_DEFINITION: i32 = 200;
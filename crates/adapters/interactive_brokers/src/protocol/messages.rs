@@ -0,0 +1,616 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Encoding of API requests and decoding of API messages.
+
+use rust_decimal::Decimal;
+
+use super::{
+    ids::{incoming, outgoing, tick_by_tick},
+    FieldReader, FieldWriter, Result,
+};
+use crate::contract::{IBContract, IBContractDetails};
+
+/// The bar size (seconds) for real-time bars, which is the only size supported by the API.
+pub const REAL_TIME_BAR_SIZE_SECS: i32 = 5;
+
+/// Returns the `START_API` message for the given client ID.
+#[must_use]
+pub fn start_api(client_id: i32) -> Vec<u8> {
+    let mut writer = FieldWriter::new(outgoing::START_API);
+    writer.push_int(2).push_int(client_id).push_str(""); // Optional capabilities
+    writer.finish()
+}
+
+/// Returns the `REQ_CURRENT_TIME` message (used as a keep-alive).
+#[must_use]
+pub fn req_current_time() -> Vec<u8> {
+    let mut writer = FieldWriter::new(outgoing::REQ_CURRENT_TIME);
+    writer.push_int(1);
+    writer.finish()
+}
+
+/// Returns the `REQ_CONTRACT_DATA` message for the given contract.
+#[must_use]
+pub fn req_contract_details(req_id: i32, contract: &IBContract) -> Vec<u8> {
+    let mut writer = FieldWriter::new(outgoing::REQ_CONTRACT_DATA);
+    writer.push_int(8).push_int(req_id);
+    contract.encode(&mut writer);
+    writer
+        .push_bool(contract.include_expired)
+        .push_str(&contract.sec_id_type)
+        .push_str(&contract.sec_id)
+        .push_str(&contract.issuer_id);
+    writer.finish()
+}
+
+/// Returns the `REQ_TICK_BY_TICK_DATA` message.
+///
+/// The `tick_type` is one of `Last`, `AllLast`, `BidAsk` or `MidPoint`.
+#[must_use]
+pub fn req_tick_by_tick_data(req_id: i32, contract: &IBContract, tick_type: &str) -> Vec<u8> {
+    let mut writer = FieldWriter::new(outgoing::REQ_TICK_BY_TICK_DATA);
+    writer.push_int(req_id);
+    contract.encode(&mut writer);
+    writer
+        .push_str(tick_type)
+        .push_int(0) // Number of ticks (0 for streaming only)
+        .push_bool(false); // Ignore size
+    writer.finish()
+}
+
+/// Returns the `CANCEL_TICK_BY_TICK_DATA` message.
+#[must_use]
+pub fn cancel_tick_by_tick_data(req_id: i32) -> Vec<u8> {
+    let mut writer = FieldWriter::new(outgoing::CANCEL_TICK_BY_TICK_DATA);
+    writer.push_int(req_id);
+    writer.finish()
+}
+
+/// Returns the `REQ_MKT_DEPTH` message.
+#[must_use]
+pub fn req_mkt_depth(
+    req_id: i32,
+    contract: &IBContract,
+    num_rows: i32,
+    is_smart_depth: bool,
+) -> Vec<u8> {
+    let mut writer = FieldWriter::new(outgoing::REQ_MKT_DEPTH);
+    writer.push_int(5).push_int(req_id);
+    contract.encode(&mut writer);
+    writer
+        .push_int(num_rows)
+        .push_bool(is_smart_depth)
+        .push_str(""); // Market depth options
+    writer.finish()
+}
+
+/// Returns the `CANCEL_MKT_DEPTH` message.
+#[must_use]
+pub fn cancel_mkt_depth(req_id: i32, is_smart_depth: bool) -> Vec<u8> {
+    let mut writer = FieldWriter::new(outgoing::CANCEL_MKT_DEPTH);
+    writer
+        .push_int(1)
+        .push_int(req_id)
+        .push_bool(is_smart_depth);
+    writer.finish()
+}
+
+/// Returns the `REQ_REAL_TIME_BARS` message.
+///
+/// The `what_to_show` is one of `TRADES`, `MIDPOINT`, `BID` or `ASK`.
+#[must_use]
+pub fn req_real_time_bars(
+    req_id: i32,
+    contract: &IBContract,
+    what_to_show: &str,
+    use_rth: bool,
+) -> Vec<u8> {
+    let mut writer = FieldWriter::new(outgoing::REQ_REAL_TIME_BARS);
+    writer.push_int(3).push_int(req_id);
+    contract.encode(&mut writer);
+    writer
+        .push_int(REAL_TIME_BAR_SIZE_SECS)
+        .push_str(what_to_show)
+        .push_bool(use_rth)
+        .push_str(""); // Real-time bars options
+    writer.finish()
+}
+
+/// Returns the `CANCEL_REAL_TIME_BARS` message.
+#[must_use]
+pub fn cancel_real_time_bars(req_id: i32) -> Vec<u8> {
+    let mut writer = FieldWriter::new(outgoing::CANCEL_REAL_TIME_BARS);
+    writer.push_int(1).push_int(req_id);
+    writer.finish()
+}
+
+/// Represents a tick-by-tick data update.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TickByTick {
+    Last {
+        price: f64,
+        size: Option<Decimal>,
+        past_limit: bool,
+        unreported: bool,
+        exchange: String,
+        special_conditions: String,
+    },
+    BidAsk {
+        bid_price: f64,
+        ask_price: f64,
+        bid_size: Option<Decimal>,
+        ask_size: Option<Decimal>,
+    },
+    MidPoint {
+        mid_point: f64,
+    },
+}
+
+/// Represents a market depth (order book) update for a single position.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MarketDepthUpdate {
+    pub req_id: i32,
+    pub position: i32,
+    pub market_maker: String,
+    /// 0 = insert, 1 = update, 2 = delete.
+    pub operation: i32,
+    /// 0 = ask, 1 = bid.
+    pub side: i32,
+    pub price: f64,
+    pub size: Option<Decimal>,
+}
+
+/// Represents a 5-second real-time bar.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RealTimeBar {
+    /// The bar open time (UNIX seconds).
+    pub time: i64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: Option<Decimal>,
+    pub wap: Option<Decimal>,
+    pub count: i32,
+}
+
+/// Represents a decoded message received from the IB Gateway/TWS.
+#[derive(Clone, Debug, PartialEq)]
+pub enum IBMessage {
+    NextValidId(i32),
+    ManagedAccounts(String),
+    CurrentTime(i64),
+    Error {
+        req_id: i32,
+        code: i32,
+        message: String,
+    },
+    ContractData {
+        req_id: i32,
+        details: Box<IBContractDetails>,
+    },
+    ContractDataEnd {
+        req_id: i32,
+    },
+    TickByTick {
+        req_id: i32,
+        /// The tick time (UNIX seconds).
+        time: i64,
+        tick: TickByTick,
+    },
+    MarketDepth(MarketDepthUpdate),
+    RealTimeBar {
+        req_id: i32,
+        bar: RealTimeBar,
+    },
+    /// A message type not handled by this client.
+    Unhandled(i32),
+}
+
+/// Decodes an API message from the given (unframed) payload.
+///
+/// # Errors
+///
+/// Returns an error if the message is malformed.
+pub fn decode_message(payload: &[u8]) -> Result<IBMessage> {
+    let mut reader = FieldReader::new(payload)?;
+    let msg_id = reader.next_int("msg_id")?;
+
+    let msg = match msg_id {
+        incoming::NEXT_VALID_ID => {
+            reader.next_int("version")?;
+            IBMessage::NextValidId(reader.next_int("order_id")?)
+        }
+        incoming::MANAGED_ACCTS => {
+            reader.next_int("version")?;
+            IBMessage::ManagedAccounts(reader.next_string("accounts")?)
+        }
+        incoming::CURRENT_TIME => {
+            reader.next_int("version")?;
+            IBMessage::CurrentTime(reader.next_i64("time")?)
+        }
+        incoming::ERR_MSG => {
+            reader.next_int("version")?;
+            IBMessage::Error {
+                req_id: reader.next_int("req_id")?,
+                code: reader.next_int("code")?,
+                message: reader.next_string("message")?,
+            }
+        }
+        incoming::CONTRACT_DATA => {
+            let req_id = reader.next_int("req_id")?;
+            let details = IBContractDetails::decode(&mut reader)?;
+            IBMessage::ContractData {
+                req_id,
+                details: Box::new(details),
+            }
+        }
+        incoming::CONTRACT_DATA_END => {
+            reader.next_int("version")?;
+            IBMessage::ContractDataEnd {
+                req_id: reader.next_int("req_id")?,
+            }
+        }
+        incoming::TICK_BY_TICK => decode_tick_by_tick(&mut reader)?,
+        incoming::MARKET_DEPTH | incoming::MARKET_DEPTH_L2 => {
+            reader.next_int("version")?;
+            let req_id = reader.next_int("req_id")?;
+            let position = reader.next_int("position")?;
+            let market_maker = if msg_id == incoming::MARKET_DEPTH_L2 {
+                reader.next_string("market_maker")?
+            } else {
+                String::new()
+            };
+            IBMessage::MarketDepth(MarketDepthUpdate {
+                req_id,
+                position,
+                market_maker,
+                operation: reader.next_int("operation")?,
+                side: reader.next_int("side")?,
+                price: reader.next_f64("price")?,
+                size: reader.next_decimal("size")?,
+            })
+        }
+        incoming::REAL_TIME_BARS => {
+            reader.next_int("version")?;
+            IBMessage::RealTimeBar {
+                req_id: reader.next_int("req_id")?,
+                bar: RealTimeBar {
+                    time: reader.next_i64("time")?,
+                    open: reader.next_f64("open")?,
+                    high: reader.next_f64("high")?,
+                    low: reader.next_f64("low")?,
+                    close: reader.next_f64("close")?,
+                    volume: reader.next_decimal("volume")?,
+                    wap: reader.next_decimal("wap")?,
+                    count: reader.next_int("count")?,
+                },
+            }
+        }
+        _ => IBMessage::Unhandled(msg_id),
+    };
+
+    Ok(msg)
+}
+
+fn decode_tick_by_tick(reader: &mut FieldReader) -> Result<IBMessage> {
+    let req_id = reader.next_int("req_id")?;
+    let tick_type = reader.next_int("tick_type")?;
+    let time = reader.next_i64("time")?;
+
+    let tick = match tick_type {
+        tick_by_tick::LAST | tick_by_tick::ALL_LAST => {
+            let price = reader.next_f64("price")?;
+            let size = reader.next_decimal("size")?;
+            let mask = reader.next_int("mask")?;
+            TickByTick::Last {
+                price,
+                size,
+                past_limit: mask & 1 != 0,
+                unreported: mask & 2 != 0,
+                exchange: reader.next_string("exchange")?,
+                special_conditions: reader.next_string("special_conditions")?,
+            }
+        }
+        tick_by_tick::BID_ASK => {
+            let bid_price = reader.next_f64("bid_price")?;
+            let ask_price = reader.next_f64("ask_price")?;
+            let bid_size = reader.next_decimal("bid_size")?;
+            let ask_size = reader.next_decimal("ask_size")?;
+            reader.next_int("mask")?;
+            TickByTick::BidAsk {
+                bid_price,
+                ask_price,
+                bid_size,
+                ask_size,
+            }
+        }
+        tick_by_tick::MID_POINT => TickByTick::MidPoint {
+            mid_point: reader.next_f64("mid_point")?,
+        },
+        _ => return Ok(IBMessage::Unhandled(incoming::TICK_BY_TICK)),
+    };
+
+    Ok(IBMessage::TickByTick { req_id, time, tick })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+pub(crate) mod tests {
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    /// Returns the payload of a framed message.
+    pub(crate) fn payload(fields: &[&str]) -> Vec<u8> {
+        let mut writer = FieldWriter::default();
+        for field in fields {
+            writer.push_str(field);
+        }
+        writer.finish()[4..].to_vec()
+    }
+
+    pub(crate) fn contract_data_fields() -> Vec<&'static str> {
+        vec![
+            "10",
+            "1",
+            "AAPL",
+            "STK",
+            "",
+            "0",
+            "",
+            "SMART",
+            "USD",
+            "AAPL",
+            "NMS",
+            "NMS",
+            "265598",
+            "0.01",
+            "",
+            "ACTIVETIM,LMT,MKT",
+            "SMART,NASDAQ,NYSE",
+            "1",
+            "0",
+            "APPLE INC",
+            "NASDAQ",
+            "",
+            "Technology",
+            "Computers",
+            "Computers",
+            "US/Eastern",
+            "20250101:0400-20250101:2000",
+            "20250101:0930-20250101:1600",
+            "",
+            "",
+            "1",
+            "ISIN",
+            "US0378331005",
+            "1",
+            "",
+            "",
+            "26,26",
+            "",
+            "COMMON",
+            "0.0001",
+            "0.0001",
+            "100",
+        ]
+    }
+
+    #[rstest]
+    fn test_encode_req_contract_details() {
+        let contract = IBContract {
+            sec_type: "STK".to_string(),
+            local_symbol: "AAPL".to_string(),
+            exchange: "SMART".to_string(),
+            primary_exchange: "NASDAQ".to_string(),
+            ..Default::default()
+        };
+
+        let bytes = req_contract_details(7, &contract);
+
+        assert_eq!(
+            &bytes[4..],
+            payload(&[
+                "9", "8", "7", "0", "", "STK", "", "0.0", "", "", "SMART", "NASDAQ", "", "AAPL",
+                "", "0", "", "", "",
+            ])
+            .as_slice()
+        );
+    }
+
+    #[rstest]
+    fn test_encode_req_tick_by_tick_data() {
+        let contract = IBContract {
+            con_id: 265_598,
+            exchange: "SMART".to_string(),
+            ..Default::default()
+        };
+
+        let bytes = req_tick_by_tick_data(3, &contract, "BidAsk");
+
+        assert_eq!(
+            &bytes[4..],
+            payload(&[
+                "97", "3", "265598", "", "", "", "0.0", "", "", "SMART", "", "", "", "", "BidAsk",
+                "0", "0",
+            ])
+            .as_slice()
+        );
+    }
+
+    #[rstest]
+    fn test_decode_contract_data() {
+        let msg = decode_message(&payload(&contract_data_fields())).unwrap();
+
+        let IBMessage::ContractData { req_id, details } = msg else {
+            panic!("Expected contract data, was {msg:?}");
+        };
+        assert_eq!(req_id, 1);
+        assert_eq!(details.contract.con_id, 265_598);
+        assert_eq!(details.contract.local_symbol, "AAPL");
+        assert_eq!(details.contract.primary_exchange, "NASDAQ");
+        assert_eq!(details.min_tick, 0.01);
+        assert_eq!(details.sec_id("ISIN"), Some("US0378331005"));
+        assert_eq!(details.stock_type, "COMMON");
+        assert_eq!(details.size_increment, Some(dec!(0.0001)));
+        assert_eq!(details.suggested_size_increment, Some(dec!(100)));
+    }
+
+    #[rstest]
+    fn test_decode_tick_by_tick_bid_ask() {
+        let msg = decode_message(&payload(&[
+            "99",
+            "3",
+            "3",
+            "1700000000",
+            "189.5",
+            "189.51",
+            "300",
+            "200",
+            "0",
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            msg,
+            IBMessage::TickByTick {
+                req_id: 3,
+                time: 1_700_000_000,
+                tick: TickByTick::BidAsk {
+                    bid_price: 189.5,
+                    ask_price: 189.51,
+                    bid_size: Some(dec!(300)),
+                    ask_size: Some(dec!(200)),
+                },
+            }
+        );
+    }
+
+    #[rstest]
+    fn test_decode_tick_by_tick_all_last() {
+        let msg = decode_message(&payload(&[
+            "99",
+            "4",
+            "2",
+            "1700000000",
+            "189.5",
+            "100",
+            "2",
+            "NASDAQ",
+            "",
+        ]))
+        .unwrap();
+
+        let IBMessage::TickByTick {
+            tick:
+                TickByTick::Last {
+                    size,
+                    past_limit,
+                    unreported,
+                    exchange,
+                    ..
+                },
+            ..
+        } = msg
+        else {
+            panic!("Expected last tick, was {msg:?}");
+        };
+        assert_eq!(size, Some(dec!(100)));
+        assert!(!past_limit);
+        assert!(unreported);
+        assert_eq!(exchange, "NASDAQ");
+    }
+
+    #[rstest]
+    fn test_decode_market_depth_l2() {
+        let msg = decode_message(&payload(&[
+            "13", "1", "5", "2", "NSDQ", "0", "1", "189.49", "500", "1",
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            msg,
+            IBMessage::MarketDepth(MarketDepthUpdate {
+                req_id: 5,
+                position: 2,
+                market_maker: "NSDQ".to_string(),
+                operation: 0,
+                side: 1,
+                price: 189.49,
+                size: Some(dec!(500)),
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_decode_real_time_bar() {
+        let msg = decode_message(&payload(&[
+            "50",
+            "3",
+            "6",
+            "1700000000",
+            "189.5",
+            "189.6",
+            "189.4",
+            "189.55",
+            "1200",
+            "189.52",
+            "14",
+        ]))
+        .unwrap();
+
+        let IBMessage::RealTimeBar { req_id, bar } = msg else {
+            panic!("Expected real-time bar, was {msg:?}");
+        };
+        assert_eq!(req_id, 6);
+        assert_eq!(bar.time, 1_700_000_000);
+        assert_eq!(bar.volume, Some(dec!(1200)));
+        assert_eq!(bar.count, 14);
+    }
+
+    #[rstest]
+    fn test_decode_error() {
+        let msg = decode_message(&payload(&[
+            "4",
+            "2",
+            "1",
+            "200",
+            "No security definition has been found for the request",
+            "",
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            msg,
+            IBMessage::Error {
+                req_id: 1,
+                code: 200,
+                message: "No security definition has been found for the request".to_string(),
+            }
+        );
+    }
+
+    #[rstest]
+    fn test_decode_unhandled() {
+        let msg = decode_message(&payload(&["1", "6", "1", "1", "189.5", "100", "0"])).unwrap();
+
+        assert_eq!(msg, IBMessage::Unhandled(1));
+    }
+}
@@ -0,0 +1,320 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Wire protocol for the IB Gateway/TWS API socket.
+//!
+//! Messages are framed with a 4-byte big-endian length prefix, and each message payload
+//! is a sequence of NUL terminated ASCII fields, the first of which is the message ID.
+
+pub mod ids;
+pub mod messages;
+
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// The API version range requested during the connection handshake.
+///
+/// The range is pinned to a single server version so that the message layouts decoded
+/// by this client are fixed (equivalent to `MIN_SERVER_VER_BOND_ISSUERID`).
+pub const MIN_CLIENT_VERSION: i32 = 176;
+pub const MAX_CLIENT_VERSION: i32 = 176;
+
+/// The maximum message length accepted from the server.
+pub const MAX_MSG_LEN: usize = 0xFF_FFFF;
+
+/// The sentinel used by the API for unset integer values.
+pub const UNSET_INTEGER: i32 = i32::MAX;
+
+/// The sentinel used by the API for unset decimal values.
+pub const UNSET_DECIMAL: &str = "170141183460469231731687303715884105727";
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The error that could happen while encoding or decoding API messages.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// An I/O error on the socket.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// A message exceeded the maximum length.
+    #[error("Message length {0} exceeds maximum {MAX_MSG_LEN}")]
+    MessageTooLong(usize),
+    /// A message ended before all expected fields were read.
+    #[error("Message ended unexpectedly while reading '{0}'")]
+    MissingField(&'static str),
+    /// A field could not be parsed as the expected type.
+    #[error("Invalid value '{value}' for field '{field}'")]
+    InvalidField {
+        /// The name of the field.
+        field: &'static str,
+        /// The raw field value.
+        value: String,
+    },
+    /// The server does not support the API version required by this client.
+    #[error("Server version {0} not supported (requires {MIN_CLIENT_VERSION})")]
+    UnsupportedServerVersion(i32),
+}
+
+/// Wraps the given payload with the 4-byte big-endian length prefix.
+#[must_use]
+pub fn frame(payload: &[u8]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(payload.len() + 4);
+    buf.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+    buf.extend_from_slice(payload);
+    buf
+}
+
+/// Returns the initial handshake bytes sent by the client on connection.
+#[must_use]
+pub fn handshake() -> Vec<u8> {
+    let mut buf = b"API\0".to_vec();
+    buf.extend(frame(
+        format!("v{MIN_CLIENT_VERSION}..{MAX_CLIENT_VERSION}").as_bytes(),
+    ));
+    buf
+}
+
+/// Reads the next length-prefixed message payload from the given reader.
+///
+/// # Errors
+///
+/// Returns an error if reading from the socket fails, or the message is too long.
+pub async fn read_frame<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Vec<u8>> {
+    let len = reader.read_u32().await? as usize;
+    if len > MAX_MSG_LEN {
+        return Err(Error::MessageTooLong(len));
+    }
+
+    let mut buf = vec![0; len];
+    reader.read_exact(&mut buf).await?;
+    Ok(buf)
+}
+
+/// Builds a message payload from NUL terminated fields.
+#[derive(Debug, Default)]
+pub struct FieldWriter {
+    buf: Vec<u8>,
+}
+
+impl FieldWriter {
+    /// Creates a new [`FieldWriter`] instance starting with the given message ID.
+    #[must_use]
+    pub fn new(msg_id: i32) -> Self {
+        let mut writer = Self::default();
+        writer.push_int(msg_id);
+        writer
+    }
+
+    pub fn push_str(&mut self, value: &str) -> &mut Self {
+        self.buf.extend_from_slice(value.as_bytes());
+        self.buf.push(0);
+        self
+    }
+
+    pub fn push_int(&mut self, value: i32) -> &mut Self {
+        if value == UNSET_INTEGER {
+            self.push_str("")
+        } else {
+            self.push_str(&value.to_string())
+        }
+    }
+
+    pub fn push_f64(&mut self, value: f64) -> &mut Self {
+        if value == f64::MAX {
+            self.push_str("")
+        } else {
+            self.push_str(&format!("{value:?}"))
+        }
+    }
+
+    pub fn push_bool(&mut self, value: bool) -> &mut Self {
+        self.push_str(if value { "1" } else { "0" })
+    }
+
+    /// Consumes the writer and returns the framed message.
+    #[must_use]
+    pub fn finish(self) -> Vec<u8> {
+        frame(&self.buf)
+    }
+}
+
+/// Reads typed fields sequentially from a message payload.
+#[derive(Debug)]
+pub struct FieldReader<'a> {
+    fields: std::vec::IntoIter<&'a str>,
+}
+
+impl<'a> FieldReader<'a> {
+    /// Creates a new [`FieldReader`] instance for the given payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload is not valid UTF-8.
+    pub fn new(payload: &'a [u8]) -> Result<Self> {
+        let text = std::str::from_utf8(payload).map_err(|_| Error::InvalidField {
+            field: "payload",
+            value: String::from_utf8_lossy(payload).to_string(),
+        })?;
+        let mut fields: Vec<&str> = text.split('\0').collect();
+        if fields.last() == Some(&"") {
+            fields.pop(); // Trailing terminator
+        }
+        Ok(Self {
+            fields: fields.into_iter(),
+        })
+    }
+
+    /// Returns the number of fields remaining.
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.fields.len()
+    }
+
+    pub fn next_str(&mut self, field: &'static str) -> Result<&'a str> {
+        self.fields.next().ok_or(Error::MissingField(field))
+    }
+
+    pub fn next_string(&mut self, field: &'static str) -> Result<String> {
+        self.next_str(field).map(ToString::to_string)
+    }
+
+    pub fn next_int(&mut self, field: &'static str) -> Result<i32> {
+        self.next_parsed(field).map(|v| v.unwrap_or(0))
+    }
+
+    pub fn next_i64(&mut self, field: &'static str) -> Result<i64> {
+        self.next_parsed(field).map(|v| v.unwrap_or(0))
+    }
+
+    pub fn next_f64(&mut self, field: &'static str) -> Result<f64> {
+        self.next_parsed(field).map(|v| v.unwrap_or(0.0))
+    }
+
+    pub fn next_bool(&mut self, field: &'static str) -> Result<bool> {
+        self.next_int(field).map(|v| v != 0)
+    }
+
+    /// Reads a decimal field, returning `None` if the value is empty or unset.
+    pub fn next_decimal(&mut self, field: &'static str) -> Result<Option<Decimal>> {
+        let value = self.next_str(field)?;
+        if value.is_empty() || value == UNSET_DECIMAL {
+            return Ok(None);
+        }
+        Decimal::from_str(value)
+            .or_else(|_| Decimal::from_scientific(value))
+            .map(Some)
+            .map_err(|_| Error::InvalidField {
+                field,
+                value: value.to_string(),
+            })
+    }
+
+    fn next_parsed<T: FromStr>(&mut self, field: &'static str) -> Result<Option<T>> {
+        let value = self.next_str(field)?;
+        if value.is_empty() {
+            return Ok(None);
+        }
+        value.parse().map(Some).map_err(|_| Error::InvalidField {
+            field,
+            value: value.to_string(),
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[rstest]
+    fn test_handshake() {
+        let bytes = handshake();
+
+        assert_eq!(&bytes[..4], b"API\0");
+        assert_eq!(&bytes[4..8], &9_u32.to_be_bytes());
+        assert_eq!(&bytes[8..], b"v176..176");
+    }
+
+    #[rstest]
+    fn test_field_writer_frames_nul_terminated_fields() {
+        let mut writer = FieldWriter::new(71);
+        writer.push_int(2).push_int(1).push_str("").push_bool(true);
+
+        let bytes = writer.finish();
+
+        assert_eq!(&bytes[..4], &10_u32.to_be_bytes());
+        assert_eq!(&bytes[4..], b"71\x002\x001\x00\x001\x00");
+    }
+
+    #[rstest]
+    fn test_field_writer_unset_values_are_empty() {
+        let mut writer = FieldWriter::default();
+        writer
+            .push_int(UNSET_INTEGER)
+            .push_f64(f64::MAX)
+            .push_f64(0.5);
+
+        assert_eq!(&writer.finish()[4..], b"\0\x000.5\0");
+    }
+
+    #[rstest]
+    fn test_field_reader() {
+        let payload = b"1\x006\x00-1\x001.25\x00\x00100\x00";
+        let mut reader = FieldReader::new(payload).unwrap();
+
+        assert_eq!(reader.remaining(), 6);
+        assert_eq!(reader.next_int("msg_id").unwrap(), 1);
+        assert_eq!(reader.next_int("version").unwrap(), 6);
+        assert_eq!(reader.next_i64("req_id").unwrap(), -1);
+        assert_eq!(reader.next_f64("price").unwrap(), 1.25);
+        assert_eq!(reader.next_decimal("size").unwrap(), None);
+        assert_eq!(reader.next_decimal("size").unwrap(), Some(dec!(100)));
+        assert!(matches!(
+            reader.next_str("extra"),
+            Err(Error::MissingField("extra"))
+        ));
+    }
+
+    #[rstest]
+    fn test_field_reader_invalid_value() {
+        let mut reader = FieldReader::new(b"abc\0").unwrap();
+
+        assert!(matches!(
+            reader.next_int("msg_id"),
+            Err(Error::InvalidField {
+                field: "msg_id",
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_read_frame() {
+        let mut writer = FieldWriter::new(9);
+        writer.push_int(1).push_int(42);
+        let bytes = writer.finish();
+
+        let payload = read_frame(&mut bytes.as_slice()).await.unwrap();
+
+        assert_eq!(payload, b"9\x001\x0042\x00");
+    }
+}
@@ -0,0 +1,291 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::python::to_pyruntime_err;
+use nautilus_model::{
+    data::BarType,
+    identifiers::InstrumentId,
+    python::{data::data_to_pycapsule, instruments::instrument_any_to_pyobject},
+};
+use pyo3::prelude::*;
+
+use crate::{
+    config::InteractiveBrokersDataClientConfig,
+    live::{InteractiveBrokersFeedHandler, LiveCommand, LiveMessage, Subscription},
+};
+
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.interactive_brokers")
+)]
+#[derive(Debug)]
+pub struct InteractiveBrokersLiveClient {
+    config: InteractiveBrokersDataClientConfig,
+    is_running: bool,
+    is_closed: bool,
+    cmd_tx: tokio::sync::mpsc::UnboundedSender<LiveCommand>,
+    cmd_rx: Option<tokio::sync::mpsc::UnboundedReceiver<LiveCommand>>,
+    buffer_size: usize,
+}
+
+impl InteractiveBrokersLiveClient {
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        self.cmd_tx.is_closed()
+    }
+
+    async fn process_messages(
+        mut msg_rx: tokio::sync::mpsc::Receiver<LiveMessage>,
+        callback: PyObject,
+    ) -> PyResult<()> {
+        tracing::debug!("Processing messages...");
+        // Continue to process messages until channel is hung up
+        while let Some(msg) = msg_rx.recv().await {
+            tracing::trace!("Received message: {msg:?}");
+            match msg {
+                LiveMessage::Data(data) => Python::with_gil(|py| {
+                    let py_obj = data_to_pycapsule(py, data);
+                    call_python(py, &callback, py_obj);
+                }),
+                LiveMessage::Instrument(data) => Python::with_gil(|py| {
+                    let py_obj =
+                        instrument_any_to_pyobject(py, data).expect("Failed creating instrument");
+                    call_python(py, &callback, py_obj);
+                }),
+                LiveMessage::Close => {
+                    // Graceful close
+                    break;
+                }
+                LiveMessage::Error(e) => {
+                    // Return error to Python
+                    return Err(to_pyruntime_err(e));
+                }
+            }
+        }
+
+        msg_rx.close();
+        tracing::debug!("Closed message receiver");
+
+        Ok(())
+    }
+
+    fn send_command(&self, cmd: LiveCommand) -> PyResult<()> {
+        self.cmd_tx.send(cmd).map_err(to_pyruntime_err)
+    }
+}
+
+fn call_python(py: Python, callback: &PyObject, py_obj: PyObject) {
+    if let Err(e) = callback.call1(py, (py_obj,)) {
+        tracing::error!("Error calling Python: {e}");
+    }
+}
+
+#[pymethods]
+impl InteractiveBrokersLiveClient {
+    #[new]
+    #[pyo3(signature = (host=None, port=None, client_id=None, connection_timeout_secs=None))]
+    fn py_new(
+        host: Option<String>,
+        port: Option<u16>,
+        client_id: Option<i32>,
+        connection_timeout_secs: Option<u64>,
+    ) -> Self {
+        let default = InteractiveBrokersDataClientConfig::default();
+        let config = InteractiveBrokersDataClientConfig {
+            host: host.unwrap_or(default.host),
+            port: port.unwrap_or(default.port),
+            client_id: client_id.unwrap_or(default.client_id),
+            connection_timeout_secs: connection_timeout_secs
+                .unwrap_or(default.connection_timeout_secs),
+        };
+
+        let (cmd_tx, cmd_rx) = tokio::sync::mpsc::unbounded_channel::<LiveCommand>();
+
+        // Hard-coded to a reasonable size for now
+        let buffer_size = 100_000;
+
+        Self {
+            config,
+            is_running: false,
+            is_closed: false,
+            cmd_tx,
+            cmd_rx: Some(cmd_rx),
+            buffer_size,
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "host")]
+    fn py_host(&self) -> &str {
+        &self.config.host
+    }
+
+    #[getter]
+    #[pyo3(name = "port")]
+    const fn py_port(&self) -> u16 {
+        self.config.port
+    }
+
+    #[getter]
+    #[pyo3(name = "client_id")]
+    const fn py_client_id(&self) -> i32 {
+        self.config.client_id
+    }
+
+    #[pyo3(name = "is_running")]
+    const fn py_is_running(&self) -> bool {
+        self.is_running
+    }
+
+    #[pyo3(name = "is_closed")]
+    const fn py_is_closed(&self) -> bool {
+        self.is_closed
+    }
+
+    #[pyo3(name = "request_instrument")]
+    fn py_request_instrument(&self, instrument_id: InstrumentId) -> PyResult<()> {
+        self.send_command(LiveCommand::RequestInstrument(instrument_id))
+    }
+
+    #[pyo3(name = "subscribe_quotes")]
+    fn py_subscribe_quotes(&self, instrument_id: InstrumentId) -> PyResult<()> {
+        self.send_command(LiveCommand::Subscribe(Subscription::Quotes(instrument_id)))
+    }
+
+    #[pyo3(name = "subscribe_trades")]
+    fn py_subscribe_trades(&self, instrument_id: InstrumentId) -> PyResult<()> {
+        self.send_command(LiveCommand::Subscribe(Subscription::Trades(instrument_id)))
+    }
+
+    #[pyo3(name = "subscribe_book_deltas")]
+    #[pyo3(signature = (instrument_id, depth=None, is_smart_depth=None))]
+    fn py_subscribe_book_deltas(
+        &self,
+        instrument_id: InstrumentId,
+        depth: Option<i32>,
+        is_smart_depth: Option<bool>,
+    ) -> PyResult<()> {
+        self.send_command(LiveCommand::Subscribe(Subscription::BookDeltas {
+            instrument_id,
+            depth: depth.unwrap_or(20),
+            is_smart_depth: is_smart_depth.unwrap_or(true),
+        }))
+    }
+
+    #[pyo3(name = "subscribe_bars")]
+    fn py_subscribe_bars(&self, bar_type: BarType) -> PyResult<()> {
+        self.send_command(LiveCommand::Subscribe(Subscription::Bars(bar_type)))
+    }
+
+    #[pyo3(name = "unsubscribe_quotes")]
+    fn py_unsubscribe_quotes(&self, instrument_id: InstrumentId) -> PyResult<()> {
+        self.send_command(LiveCommand::Unsubscribe(Subscription::Quotes(
+            instrument_id,
+        )))
+    }
+
+    #[pyo3(name = "unsubscribe_trades")]
+    fn py_unsubscribe_trades(&self, instrument_id: InstrumentId) -> PyResult<()> {
+        self.send_command(LiveCommand::Unsubscribe(Subscription::Trades(
+            instrument_id,
+        )))
+    }
+
+    #[pyo3(name = "unsubscribe_book_deltas")]
+    #[pyo3(signature = (instrument_id, depth=None, is_smart_depth=None))]
+    fn py_unsubscribe_book_deltas(
+        &self,
+        instrument_id: InstrumentId,
+        depth: Option<i32>,
+        is_smart_depth: Option<bool>,
+    ) -> PyResult<()> {
+        self.send_command(LiveCommand::Unsubscribe(Subscription::BookDeltas {
+            instrument_id,
+            depth: depth.unwrap_or(20),
+            is_smart_depth: is_smart_depth.unwrap_or(true),
+        }))
+    }
+
+    #[pyo3(name = "unsubscribe_bars")]
+    fn py_unsubscribe_bars(&self, bar_type: BarType) -> PyResult<()> {
+        self.send_command(LiveCommand::Unsubscribe(Subscription::Bars(bar_type)))
+    }
+
+    #[pyo3(name = "start")]
+    fn py_start<'py>(
+        &mut self,
+        py: Python<'py>,
+        callback: PyObject,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        if self.is_closed {
+            return Err(to_pyruntime_err("Client already closed"));
+        }
+        if self.is_running {
+            return Err(to_pyruntime_err("Client already running"));
+        }
+
+        tracing::debug!("Starting client");
+
+        self.is_running = true;
+
+        let (msg_tx, msg_rx) = tokio::sync::mpsc::channel::<LiveMessage>(self.buffer_size);
+
+        // Consume the receiver
+        // SAFETY: We guard the client from being started more than once with the
+        // `is_running` flag, so here it is safe to unwrap the command receiver.
+        let cmd_rx = self.cmd_rx.take().unwrap();
+
+        let mut feed_handler =
+            InteractiveBrokersFeedHandler::new(self.config.clone(), cmd_rx, msg_tx);
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let (proc_handle, feed_handle) =
+                tokio::join!(Self::process_messages(msg_rx, callback), feed_handler.run(),);
+
+            match proc_handle {
+                Ok(()) => tracing::debug!("Message processor completed"),
+                Err(e) => tracing::error!("Message processor error: {e}"),
+            }
+
+            match feed_handle {
+                Ok(()) => tracing::debug!("Feed handler completed"),
+                Err(e) => tracing::error!("Feed handler error: {e}"),
+            }
+
+            Ok(())
+        })
+    }
+
+    #[pyo3(name = "close")]
+    fn py_close(&mut self) -> PyResult<()> {
+        if !self.is_running {
+            return Err(to_pyruntime_err("Client never started"));
+        }
+        if self.is_closed {
+            return Err(to_pyruntime_err("Client already closed"));
+        }
+
+        tracing::debug!("Closing client");
+
+        if !self.is_closed() {
+            self.send_command(LiveCommand::Close)?;
+        }
+
+        self.is_running = false;
+        self.is_closed = true;
+
+        Ok(())
+    }
+}
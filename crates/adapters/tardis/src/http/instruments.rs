@@ -16,7 +16,7 @@
 use nautilus_core::UnixNanos;
 use nautilus_model::{
     currencies::CURRENCY_MAP,
-    enums::{AssetClass, CurrencyType},
+    enums::{AssetClass, CurrencyType, ExerciseStyle, SettlementType},
     identifiers::{InstrumentId, Symbol},
     instruments::{CryptoFuture, CryptoPerpetual, CurrencyPair, InstrumentAny, OptionContract},
     types::{Currency, Price, Quantity},
@@ -222,6 +222,9 @@ pub fn create_option_contract(
         Some(margin_maint),
         Some(maker_fee),
         Some(taker_fee),
+        Some(ExerciseStyle::European), // Crypto options are European style and cash settled
+        Some(SettlementType::Cash),
+        None,
         ts_event,
         ts_init,
     ))
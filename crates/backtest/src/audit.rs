@@ -0,0 +1,392 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a determinism audit mode, to prove that backtest results are reproducible across
+//! machine architectures and code changes.
+//!
+//! While auditing, each event emitted by the simulated venues is recorded in sequence along with
+//! a running SHA-256 hash over the canonical JSON form of the events, which excludes the randomly
+//! generated event IDs. The audit of a run can be saved as a reference, and the audit of a later
+//! run compared against it to report the first divergent event.
+
+use std::{
+    any::Any,
+    cell::RefCell,
+    fmt::Display,
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+    rc::Rc,
+};
+
+use nautilus_common::{
+    messages::data::DataResponse,
+    msgbus::{
+        handler::{MessageHandler, ShareableMessageHandler},
+        MessageBus,
+    },
+};
+use nautilus_model::{
+    data::Data,
+    events::{AccountState, OrderEventAny},
+};
+use ring::digest;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use ustr::Ustr;
+
+/// The fields excluded from the canonical form of events, as they differ between runs.
+const EXCLUDED_FIELDS: [&str; 1] = ["event_id"];
+
+/// Represents an event recorded by a determinism audit.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    /// The position of the event in the sequence emitted.
+    pub sequence: u64,
+    /// The endpoint the event was sent to.
+    pub endpoint: String,
+    /// The canonical JSON form of the event.
+    pub event: String,
+    /// The hex encoded SHA-256 hash of the sequence up to and including the event.
+    pub hash: String,
+}
+
+/// Represents the first event at which an audited run diverges from the reference run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuditDivergence {
+    /// The position in the sequence of the first divergent event.
+    pub sequence: u64,
+    /// The event of the reference run, if it emitted one at the position.
+    pub expected: Option<AuditRecord>,
+    /// The event of the audited run, if it emitted one at the position.
+    pub actual: Option<AuditRecord>,
+}
+
+impl Display for AuditDivergence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let event = |record: &Option<AuditRecord>| {
+            record.as_ref().map_or_else(
+                || "no event".to_string(),
+                |record| format!("{} to '{}'", record.event, record.endpoint),
+            )
+        };
+        write!(
+            f,
+            "Divergence at event {}: expected {}, was {}",
+            self.sequence,
+            event(&self.expected),
+            event(&self.actual),
+        )
+    }
+}
+
+/// Provides a determinism audit of the events emitted during a backtest run.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeterminismAudit {
+    records: Vec<AuditRecord>,
+}
+
+impl DeterminismAudit {
+    /// Creates a new [`DeterminismAudit`] instance.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            records: Vec::new(),
+        }
+    }
+
+    /// Records the `event` sent to the `endpoint`, chaining it into the hash of the sequence.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the event cannot be serialized.
+    pub fn record<T: Serialize>(&mut self, endpoint: &str, event: &T) -> anyhow::Result<()> {
+        let mut value = serde_json::to_value(event)?;
+        remove_excluded_fields(&mut value);
+        // Object keys are sorted, so the form is independent of field declaration order
+        let event = serde_json::to_string(&value)?;
+
+        let mut context = digest::Context::new(&digest::SHA256);
+        if let Some(hash) = self.hash() {
+            context.update(hash.as_bytes());
+        }
+        context.update(endpoint.as_bytes());
+        context.update(event.as_bytes());
+
+        self.records.push(AuditRecord {
+            sequence: self.records.len() as u64,
+            endpoint: endpoint.to_string(),
+            event,
+            hash: hex::encode(context.finish().as_ref()),
+        });
+        Ok(())
+    }
+
+    /// Returns the events recorded.
+    #[must_use]
+    pub fn records(&self) -> &[AuditRecord] {
+        &self.records
+    }
+
+    /// Returns the number of events recorded.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns whether no events have been recorded.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Returns the hash of the whole sequence of events, if any have been recorded.
+    #[must_use]
+    pub fn hash(&self) -> Option<&str> {
+        self.records.last().map(|record| record.hash.as_str())
+    }
+
+    /// Clears the events recorded, to audit a new run.
+    pub fn clear(&mut self) {
+        self.records.clear();
+    }
+
+    /// Compares the audit against the audit of the `reference` run, returning the first
+    /// divergent event, or `None` if the runs emitted identical events.
+    #[must_use]
+    pub fn compare(&self, reference: &Self) -> Option<AuditDivergence> {
+        // Hashes are chained, so the first differing hash marks the first divergent event
+        let count = self.records.len().max(reference.records.len());
+        (0..count)
+            .map(|i| (reference.records.get(i), self.records.get(i)))
+            .position(|(expected, actual)| {
+                expected.map(|record| &record.hash) != actual.map(|record| &record.hash)
+            })
+            .map(|i| AuditDivergence {
+                sequence: i as u64,
+                expected: reference.records.get(i).cloned(),
+                actual: self.records.get(i).cloned(),
+            })
+    }
+
+    /// Saves the audit as JSON to the file at `path`, for use as a reference.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the file cannot be written.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(&mut writer, self)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Loads an audit from the JSON file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the file cannot be read or parsed.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let bytes = std::fs::read(path)?;
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    /// Taps the `endpoint` of the `msgbus`, so each event sent to it is recorded by the
+    /// `audit` before being passed on to the handler registered for the endpoint.
+    ///
+    /// Handlers registered for the endpoint after it is tapped replace the tap.
+    pub fn tap(audit: &Rc<RefCell<Self>>, msgbus: &mut MessageBus, endpoint: Ustr) {
+        let inner = msgbus.get_endpoint(endpoint).cloned();
+        let handler = AuditHandler {
+            id: Ustr::from(&format!("DeterminismAudit-{endpoint}")),
+            endpoint,
+            audit: audit.clone(),
+            inner,
+        };
+        msgbus.register(endpoint, ShareableMessageHandler(Rc::new(handler)));
+    }
+}
+
+/// Removes the excluded fields from the `value` and any values nested within it.
+fn remove_excluded_fields(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for field in EXCLUDED_FIELDS {
+                map.remove(field);
+            }
+            map.values_mut().for_each(remove_excluded_fields);
+        }
+        Value::Array(values) => values.iter_mut().for_each(remove_excluded_fields),
+        _ => {}
+    }
+}
+
+/// Handler which records the events sent to an endpoint, then passes them on.
+struct AuditHandler {
+    id: Ustr,
+    endpoint: Ustr,
+    audit: Rc<RefCell<DeterminismAudit>>,
+    inner: Option<ShareableMessageHandler>,
+}
+
+impl MessageHandler for AuditHandler {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, message: &dyn Any) {
+        let result = if let Some(event) = message.downcast_ref::<OrderEventAny>() {
+            self.audit.borrow_mut().record(&self.endpoint, event)
+        } else if let Some(event) = message.downcast_ref::<AccountState>() {
+            self.audit.borrow_mut().record(&self.endpoint, event)
+        } else {
+            Ok(())
+        };
+        if let Err(e) = result {
+            log::error!("Cannot audit event sent to '{}': {e}", self.endpoint);
+        }
+
+        if let Some(inner) = &self.inner {
+            inner.0.handle(message);
+        }
+    }
+
+    fn handle_response(&self, resp: DataResponse) {
+        if let Some(inner) = &self.inner {
+            inner.0.handle_response(resp);
+        }
+    }
+
+    fn handle_data(&self, data: Data) {
+        if let Some(inner) = &self.inner {
+            inner.0.handle_data(data);
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::msgbus::stubs::{get_message_saving_handler, get_saved_messages};
+    use nautilus_core::{UnixNanos, UUID4};
+    use nautilus_model::{
+        events::OrderRejected,
+        identifiers::{AccountId, ClientOrderId, InstrumentId, StrategyId, TraderId},
+    };
+    use rstest::rstest;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn rejected(client_order_id: &str, reason: &str) -> OrderEventAny {
+        OrderEventAny::Rejected(OrderRejected::new(
+            TraderId::default(),
+            StrategyId::default(),
+            InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            ClientOrderId::from(client_order_id),
+            AccountId::default(),
+            Ustr::from(reason),
+            UUID4::new(),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+            false,
+        ))
+    }
+
+    fn audit_of(events: &[OrderEventAny]) -> DeterminismAudit {
+        let mut audit = DeterminismAudit::new();
+        for event in events {
+            audit.record("ExecEngine.process", event).unwrap();
+        }
+        audit
+    }
+
+    #[rstest]
+    fn test_hash_excludes_event_ids() {
+        let reference = audit_of(&[rejected("O-1", "A"), rejected("O-2", "B")]);
+        let audit = audit_of(&[rejected("O-1", "A"), rejected("O-2", "B")]);
+
+        assert_eq!(audit.len(), 2);
+        assert_eq!(audit.hash(), reference.hash());
+        assert!(!audit.records()[0].event.contains("event_id"));
+        assert_eq!(audit.compare(&reference), None);
+    }
+
+    #[rstest]
+    fn test_compare_reports_first_divergent_event() {
+        let reference = audit_of(&[
+            rejected("O-1", "A"),
+            rejected("O-2", "B"),
+            rejected("O-3", "C"),
+        ]);
+        let audit = audit_of(&[
+            rejected("O-1", "A"),
+            rejected("O-2", "X"),
+            rejected("O-3", "C"),
+        ]);
+
+        let divergence = audit.compare(&reference).unwrap();
+
+        assert_eq!(divergence.sequence, 1);
+        assert_eq!(divergence.expected, Some(reference.records()[1].clone()));
+        assert_eq!(divergence.actual, Some(audit.records()[1].clone()));
+        // Later events diverge in hash, as the hashes are chained
+        assert_ne!(audit.hash(), reference.hash());
+    }
+
+    #[rstest]
+    fn test_compare_reports_missing_events() {
+        let reference = audit_of(&[rejected("O-1", "A"), rejected("O-2", "B")]);
+        let audit = audit_of(&[rejected("O-1", "A")]);
+
+        let divergence = audit.compare(&reference).unwrap();
+
+        assert_eq!(divergence.sequence, 1);
+        assert!(divergence.expected.is_some());
+        assert_eq!(divergence.actual, None);
+    }
+
+    #[rstest]
+    fn test_save_and_load() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("audit.json");
+        let audit = audit_of(&[rejected("O-1", "A")]);
+
+        audit.save(&path).unwrap();
+
+        assert_eq!(DeterminismAudit::load(&path).unwrap(), audit);
+    }
+
+    #[rstest]
+    fn test_tap_records_and_passes_on_events() {
+        let endpoint = Ustr::from("ExecEngine.process");
+        let mut msgbus = MessageBus::default();
+        let handler = get_message_saving_handler::<OrderEventAny>(None);
+        msgbus.register(endpoint, handler.clone());
+        let audit = Rc::new(RefCell::new(DeterminismAudit::new()));
+
+        DeterminismAudit::tap(&audit, &mut msgbus, endpoint);
+        msgbus.send(&endpoint, &rejected("O-1", "A"));
+
+        assert_eq!(audit.borrow().len(), 1);
+        assert_eq!(get_saved_messages::<OrderEventAny>(handler).len(), 1);
+    }
+}
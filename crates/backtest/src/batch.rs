@@ -0,0 +1,327 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a batch runner executing many backtests in parallel, such as for parameter sweeps.
+//!
+//! Each backtest is built and run on a worker thread from its parameters and a seed derived
+//! from the base seed, so that the results are reproducible whatever the number of workers.
+
+use std::{
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::engine::BacktestEngine;
+
+/// The outcome of a single backtest within a batch.
+#[derive(Debug)]
+pub struct BatchRun<P, R> {
+    /// The index of the run's parameters within the batch.
+    pub index: usize,
+    /// The parameters the backtest was built from.
+    pub params: P,
+    /// The RNG seed the backtest was built with.
+    pub seed: u64,
+    /// The number of data points processed by the backtest.
+    pub iterations: u64,
+    /// The wall time taken to build and run the backtest.
+    pub elapsed: Duration,
+    /// The result extracted from the backtest, or the error if it failed.
+    pub result: anyhow::Result<R>,
+}
+
+/// The aggregated outcomes of a batch of backtests, in parameter order.
+#[derive(Debug)]
+pub struct BatchReport<P, R> {
+    runs: Vec<BatchRun<P, R>>,
+    elapsed: Duration,
+}
+
+impl<P, R> BatchReport<P, R> {
+    /// Returns the runs in parameter order.
+    #[must_use]
+    pub fn runs(&self) -> &[BatchRun<P, R>] {
+        &self.runs
+    }
+
+    /// Returns the wall time taken to run the whole batch.
+    #[must_use]
+    pub const fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns the number of runs which failed.
+    #[must_use]
+    pub fn failed_count(&self) -> usize {
+        self.runs.iter().filter(|run| run.result.is_err()).count()
+    }
+
+    /// Returns the successful runs ranked by the `score` of their results, highest first.
+    ///
+    /// Runs with equal scores remain in parameter order.
+    pub fn ranked_by(&self, score: impl Fn(&R) -> f64) -> Vec<(&BatchRun<P, R>, &R)> {
+        let mut ranked: Vec<(&BatchRun<P, R>, &R, f64)> = self
+            .runs
+            .iter()
+            .filter_map(|run| {
+                let result = run.result.as_ref().ok()?;
+                Some((run, result, score(result)))
+            })
+            .collect();
+        ranked.sort_by(|a, b| b.2.total_cmp(&a.2));
+        ranked
+            .into_iter()
+            .map(|(run, result, _)| (run, result))
+            .collect()
+    }
+
+    /// Returns the successful run with the highest `score`, if any.
+    pub fn best_by(&self, score: impl Fn(&R) -> f64) -> Option<(&BatchRun<P, R>, &R)> {
+        self.ranked_by(score).into_iter().next()
+    }
+}
+
+/// Runs batches of backtests across a pool of worker threads.
+#[derive(Clone, Debug)]
+pub struct BatchBacktestRunner {
+    /// The number of worker threads, defaulting to the available parallelism.
+    pub num_workers: Option<usize>,
+    /// The seed from which the seed of each run is derived.
+    pub base_seed: u64,
+}
+
+impl BatchBacktestRunner {
+    /// Creates a new [`BatchBacktestRunner`] instance.
+    #[must_use]
+    pub const fn new(base_seed: u64) -> Self {
+        Self {
+            num_workers: None,
+            base_seed,
+        }
+    }
+
+    /// Returns the RNG seeds for a batch of `count` runs, in parameter order.
+    #[must_use]
+    pub fn seeds(&self, count: usize) -> Vec<u64> {
+        let mut rng = StdRng::seed_from_u64(self.base_seed);
+        (0..count).map(|_| rng.random()).collect()
+    }
+
+    /// Runs a backtest for each of the `params`, returning the report of their outcomes.
+    ///
+    /// Each engine is created by `build` from the run's parameters and seed on a worker
+    /// thread, which should use the seed for every source of randomness (such as the fill
+    /// model) and give each engine its own clock. Once run to completion, the run's result
+    /// is taken from its parameters and engine by `extract`. A run which fails to build or panics is
+    /// recorded as an error without stopping the batch.
+    pub fn run<P, R, B, E>(&self, params: &[P], build: B, extract: E) -> BatchReport<P, R>
+    where
+        P: Clone + Sync,
+        R: Send,
+        B: Fn(&P, u64) -> anyhow::Result<BacktestEngine> + Sync,
+        E: Fn(&P, &BacktestEngine) -> R + Sync,
+    {
+        let runs_total = params.len();
+        let num_workers = self
+            .num_workers
+            .unwrap_or_else(|| thread::available_parallelism().map_or(1, usize::from))
+            .clamp(1, runs_total.max(1));
+        let seeds = self.seeds(runs_total);
+
+        let next_index = AtomicUsize::new(0);
+        let outcomes = Mutex::new(Vec::with_capacity(runs_total));
+        let start = Instant::now();
+
+        log::info!("Running {runs_total} backtest(s) using {num_workers} worker(s)");
+
+        thread::scope(|s| {
+            for _ in 0..num_workers {
+                s.spawn(|| loop {
+                    let index = next_index.fetch_add(1, Ordering::Relaxed);
+                    let Some(run_params) = params.get(index) else {
+                        break;
+                    };
+
+                    let run_start = Instant::now();
+                    let (iterations, result) =
+                        run_single(run_params, seeds[index], &build, &extract);
+                    if let Err(e) = &result {
+                        log::error!("Error in backtest run {index}: {e}");
+                    }
+                    outcomes
+                        .lock()
+                        .unwrap()
+                        .push((index, iterations, run_start.elapsed(), result));
+                });
+            }
+        });
+
+        let mut outcomes = outcomes.into_inner().unwrap();
+        outcomes.sort_by_key(|(index, ..)| *index);
+        let runs = outcomes
+            .into_iter()
+            .map(|(index, iterations, elapsed, result)| BatchRun {
+                index,
+                params: params[index].clone(),
+                seed: seeds[index],
+                iterations,
+                elapsed,
+                result,
+            })
+            .collect();
+
+        BatchReport {
+            runs,
+            elapsed: start.elapsed(),
+        }
+    }
+}
+
+fn run_single<P, R>(
+    params: &P,
+    seed: u64,
+    build: &impl Fn(&P, u64) -> anyhow::Result<BacktestEngine>,
+    extract: &impl Fn(&P, &BacktestEngine) -> R,
+) -> (u64, anyhow::Result<R>) {
+    let outcome = catch_unwind(AssertUnwindSafe(|| {
+        let mut engine = build(params, seed)?;
+        engine.run(None);
+        Ok((engine.iteration(), extract(params, &engine)))
+    }));
+
+    match outcome {
+        Ok(Ok((iterations, result))) => (iterations, Ok(result)),
+        Ok(Err(e)) => (0, Err(e)),
+        Err(panic) => {
+            let message = panic
+                .downcast_ref::<&str>()
+                .map(ToString::to_string)
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            (0, Err(anyhow::anyhow!("Backtest panicked: {message}")))
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use rstest::rstest;
+
+    use super::BatchBacktestRunner;
+    use crate::engine::BacktestEngine;
+
+    // Scores each run with a draw from its seeded RNG, scaled by its parameter
+    fn run_grid(num_workers: usize) -> Vec<(u32, u64, f64)> {
+        let runner = BatchBacktestRunner {
+            num_workers: Some(num_workers),
+            base_seed: 42,
+        };
+        let params: Vec<u32> = (1..=16).collect();
+
+        let report = runner.run(
+            &params,
+            |_, _| Ok(BacktestEngine::new()),
+            |_, engine| engine.iteration(),
+        );
+
+        report
+            .runs()
+            .iter()
+            .map(|run| {
+                let draw: f64 = StdRng::seed_from_u64(run.seed).random();
+                (run.params, run.seed, draw * f64::from(run.params))
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_seeds_are_deterministic_and_distinct() {
+        let runner = BatchBacktestRunner::new(1);
+
+        let seeds = runner.seeds(4);
+
+        assert_eq!(seeds, runner.seeds(4));
+        assert_ne!(seeds, BatchBacktestRunner::new(2).seeds(4));
+        assert!(seeds
+            .iter()
+            .enumerate()
+            .all(|(i, a)| seeds[i + 1..].iter().all(|b| a != b)));
+    }
+
+    #[rstest]
+    fn test_results_independent_of_num_workers() {
+        assert_eq!(run_grid(1), run_grid(4));
+    }
+
+    #[rstest]
+    fn test_failed_runs_recorded() {
+        let runner = BatchBacktestRunner {
+            num_workers: Some(2),
+            base_seed: 0,
+        };
+        let params = vec![1, 2, 3, 4];
+
+        let report = runner.run(
+            &params,
+            |param, _| match param {
+                2 => anyhow::bail!("Invalid parameter"),
+                3 => panic!("Engine failed"),
+                _ => Ok(BacktestEngine::new()),
+            },
+            |_, _| (),
+        );
+
+        assert_eq!(report.runs().len(), 4);
+        assert_eq!(report.failed_count(), 2);
+        assert_eq!(
+            report.runs()[1].result.as_ref().unwrap_err().to_string(),
+            "Invalid parameter"
+        );
+        assert_eq!(
+            report.runs()[2].result.as_ref().unwrap_err().to_string(),
+            "Backtest panicked: Engine failed"
+        );
+    }
+
+    #[rstest]
+    fn test_ranked_by_score() {
+        let runner = BatchBacktestRunner::new(0);
+        let params = vec![3.0, 1.0, 2.0, 3.0];
+
+        let report = runner.run(&params, |_, _| Ok(BacktestEngine::new()), |p, _| *p);
+        let ranked: Vec<usize> = report
+            .ranked_by(|score| *score)
+            .iter()
+            .map(|(run, _)| run.index)
+            .collect();
+
+        assert_eq!(ranked, vec![0, 3, 2, 1]);
+        assert_eq!(
+            report.best_by(|score| -score).map(|(run, _)| run.index),
+            Some(1)
+        );
+    }
+}
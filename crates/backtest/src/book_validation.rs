@@ -0,0 +1,320 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides validation of order book integrity while replaying historical deltas.
+//!
+//! Invalid historical data, such as a missed delta, can leave a book crossed or out of sync
+//! with the venue, silently corrupting the fills of a backtest. Validation reports each
+//! offending delta as a [`BookDiagnostic`] so bad data can be found before it is relied on.
+
+use std::{collections::HashMap, fmt::Display};
+
+use nautilus_model::{
+    data::OrderBookDelta,
+    enums::{BookAction, RecordFlag},
+    identifiers::InstrumentId,
+    orderbook::{analysis::book_check_integrity, BookIntegrityError, OrderBook},
+};
+use ustr::Ustr;
+
+/// The kind of integrity problem found while replaying an order book delta.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BookDiagnosticKind {
+    /// The best bid is at or above the best ask once the delta is applied.
+    Crossed,
+    /// The book holds more levels or orders than its book type allows.
+    InvalidStructure,
+    /// An add or update delta has a non-positive size.
+    InvalidSize,
+    /// The delta sequence skips one or more sequence numbers.
+    SequenceGap,
+    /// The delta sequence is lower than the previous delta's.
+    SequenceOutOfOrder,
+}
+
+/// A diagnostic event for an order book delta failing validation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BookDiagnostic {
+    /// The kind of integrity problem.
+    pub kind: BookDiagnosticKind,
+    /// The offending delta.
+    pub delta: OrderBookDelta,
+    /// The description of the problem.
+    pub message: String,
+}
+
+impl BookDiagnostic {
+    fn new(kind: BookDiagnosticKind, delta: OrderBookDelta, message: String) -> Self {
+        Self {
+            kind,
+            delta,
+            message,
+        }
+    }
+}
+
+impl Display for BookDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({:?}, {}, delta={})",
+            stringify!(BookDiagnostic),
+            self.kind,
+            self.message,
+            self.delta
+        )
+    }
+}
+
+/// Returns the message bus topic on which book diagnostics are published for the instrument.
+#[must_use]
+pub fn get_book_diagnostics_topic(instrument_id: InstrumentId) -> Ustr {
+    Ustr::from(&format!(
+        "diagnostics.book.{}.{}",
+        instrument_id.venue, instrument_id.symbol
+    ))
+}
+
+/// Validates order book deltas and the books they are applied to.
+#[derive(Debug, Default)]
+pub struct BookValidator {
+    last_sequences: HashMap<InstrumentId, u64>,
+    diagnostic_count: usize,
+}
+
+impl BookValidator {
+    /// Creates a new [`BookValidator`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of diagnostics raised.
+    #[must_use]
+    pub const fn diagnostic_count(&self) -> usize {
+        self.diagnostic_count
+    }
+
+    /// Checks the `delta` before it is applied, for its size and sequence continuity.
+    ///
+    /// Deltas with a zero sequence are not checked for continuity.
+    pub fn check_delta(&mut self, delta: &OrderBookDelta) -> Vec<BookDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if matches!(delta.action, BookAction::Add | BookAction::Update)
+            && !delta.order.size.is_positive()
+        {
+            diagnostics.push(BookDiagnostic::new(
+                BookDiagnosticKind::InvalidSize,
+                *delta,
+                format!(
+                    "{:?} with non-positive size {}",
+                    delta.action, delta.order.size
+                ),
+            ));
+        }
+
+        if delta.sequence != 0 {
+            if let Some(last_sequence) = self
+                .last_sequences
+                .insert(delta.instrument_id, delta.sequence)
+            {
+                if delta.sequence < last_sequence {
+                    diagnostics.push(BookDiagnostic::new(
+                        BookDiagnosticKind::SequenceOutOfOrder,
+                        *delta,
+                        format!("Sequence {} after {last_sequence}", delta.sequence),
+                    ));
+                } else if delta.sequence > last_sequence + 1 {
+                    diagnostics.push(BookDiagnostic::new(
+                        BookDiagnosticKind::SequenceGap,
+                        *delta,
+                        format!(
+                            "Sequence gap of {} after {last_sequence}",
+                            delta.sequence - last_sequence - 1
+                        ),
+                    ));
+                }
+            }
+        }
+
+        self.diagnostic_count += diagnostics.len();
+        diagnostics
+    }
+
+    /// Checks the integrity of the `book` after the `delta` is applied.
+    ///
+    /// Books are only checked at the end of an event, as they may be transiently crossed
+    /// while the deltas of an event are applied.
+    pub fn check_book(
+        &mut self,
+        book: &OrderBook,
+        delta: &OrderBookDelta,
+    ) -> Option<BookDiagnostic> {
+        if delta.flags != 0 && !RecordFlag::F_LAST.matches(delta.flags) {
+            return None;
+        }
+
+        let error = book_check_integrity(book).err()?;
+        let kind = match error {
+            BookIntegrityError::OrdersCrossed(..) => BookDiagnosticKind::Crossed,
+            _ => BookDiagnosticKind::InvalidStructure,
+        };
+        self.diagnostic_count += 1;
+        Some(BookDiagnostic::new(kind, *delta, error.to_string()))
+    }
+
+    /// Resets the validator to its initial state.
+    pub fn reset(&mut self) {
+        self.last_sequences.clear();
+        self.diagnostic_count = 0;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::UnixNanos;
+    use nautilus_model::{
+        data::{BookOrder, OrderBookDelta},
+        enums::{BookAction, BookType, OrderSide, RecordFlag},
+        identifiers::InstrumentId,
+        orderbook::OrderBook,
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::{BookDiagnosticKind, BookValidator};
+
+    fn delta(
+        action: BookAction,
+        side: OrderSide,
+        price: &str,
+        size: &str,
+        order_id: u64,
+        flags: u8,
+        sequence: u64,
+    ) -> OrderBookDelta {
+        OrderBookDelta::new(
+            InstrumentId::from("AAPL.XNAS"),
+            action,
+            BookOrder::new(side, Price::from(price), Quantity::from(size), order_id),
+            flags,
+            sequence,
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    }
+
+    #[rstest]
+    fn test_valid_deltas_raise_no_diagnostics() {
+        let mut validator = BookValidator::new();
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L3_MBO);
+
+        for delta in [
+            delta(BookAction::Add, OrderSide::Buy, "100.00", "10", 1, 0, 1),
+            delta(BookAction::Add, OrderSide::Sell, "101.00", "10", 2, 0, 2),
+        ] {
+            assert!(validator.check_delta(&delta).is_empty());
+            book.apply_delta(&delta);
+            assert!(validator.check_book(&book, &delta).is_none());
+        }
+
+        assert_eq!(validator.diagnostic_count(), 0);
+    }
+
+    #[rstest]
+    #[case(BookAction::Add, BookDiagnosticKind::InvalidSize)]
+    #[case(BookAction::Update, BookDiagnosticKind::InvalidSize)]
+    fn test_zero_size(#[case] action: BookAction, #[case] expected: BookDiagnosticKind) {
+        let mut validator = BookValidator::new();
+        // Decoded historical data bypasses the size check of the delta constructor
+        let mut invalid = delta(action, OrderSide::Buy, "100.00", "1", 1, 0, 0);
+        invalid.order.size = Quantity::from(0);
+
+        let diagnostics = validator.check_delta(&invalid);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].kind, expected);
+    }
+
+    #[rstest]
+    #[case(2, None)]
+    #[case(3, None)]
+    #[case(5, Some(BookDiagnosticKind::SequenceGap))]
+    #[case(1, Some(BookDiagnosticKind::SequenceOutOfOrder))]
+    fn test_sequence_continuity(
+        #[case] sequence: u64,
+        #[case] expected: Option<BookDiagnosticKind>,
+    ) {
+        let mut validator = BookValidator::new();
+        validator.check_delta(&delta(
+            BookAction::Add,
+            OrderSide::Buy,
+            "100.00",
+            "1",
+            1,
+            0,
+            2,
+        ));
+
+        let diagnostics = validator.check_delta(&delta(
+            BookAction::Add,
+            OrderSide::Buy,
+            "100.00",
+            "1",
+            2,
+            0,
+            sequence,
+        ));
+
+        assert_eq!(diagnostics.first().map(|d| d.kind), expected);
+    }
+
+    #[rstest]
+    fn test_crossed_book_checked_at_event_end() {
+        let mut validator = BookValidator::new();
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L3_MBO);
+        let bid = delta(BookAction::Add, OrderSide::Buy, "101.00", "10", 1, 0, 0);
+        let ask = delta(BookAction::Add, OrderSide::Sell, "100.00", "10", 2, 0, 0);
+        let ask_last = delta(
+            BookAction::Add,
+            OrderSide::Sell,
+            "100.00",
+            "10",
+            3,
+            RecordFlag::F_LAST as u8,
+            0,
+        );
+        book.apply_delta(&bid);
+        book.apply_delta(&ask);
+
+        // Mid-event deltas are not checked
+        let mid_event = OrderBookDelta {
+            flags: RecordFlag::F_MBP as u8,
+            ..ask
+        };
+        assert!(validator.check_book(&book, &mid_event).is_none());
+
+        book.apply_delta(&ask_last);
+        let diagnostic = validator.check_book(&book, &ask_last).unwrap();
+
+        assert_eq!(diagnostic.kind, BookDiagnosticKind::Crossed);
+        assert_eq!(diagnostic.delta, ask_last);
+        assert_eq!(validator.diagnostic_count(), 1);
+    }
+}
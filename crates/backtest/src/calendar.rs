@@ -0,0 +1,380 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides venue trading calendars for the simulated exchange.
+//!
+//! A calendar defines the daily continuous trading sessions in the venue's local timezone,
+//! the trading days and holidays, and optional opening and closing auction windows.
+
+use std::collections::HashSet;
+
+use chrono::{Datelike, NaiveDate, NaiveTime, TimeDelta, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+use nautilus_core::UnixNanos;
+
+/// The trading phase of a venue at a point in time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SessionPhase {
+    /// Outside the trading sessions, or on a non-trading day.
+    Closed,
+    /// Orders are collected for the opening auction before the first session.
+    OpeningAuction,
+    /// Continuous trading within a session.
+    Continuous,
+    /// Orders are collected for the closing auction after the last session.
+    ClosingAuction,
+}
+
+/// How a simulated exchange handles orders submitted while its venue is closed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum OutOfSessionPolicy {
+    /// Orders are rejected while the venue is closed.
+    #[default]
+    Reject,
+    /// Orders are queued until the next session opens.
+    Queue,
+}
+
+/// A venue trading calendar of daily sessions in the venue's local timezone.
+#[derive(Clone, Debug)]
+pub struct TradingCalendar {
+    timezone: Tz,
+    sessions: Vec<(TimeDelta, TimeDelta)>,
+    trading_days: Vec<Weekday>,
+    holidays: HashSet<NaiveDate>,
+    opening_auction: TimeDelta,
+    closing_auction: TimeDelta,
+}
+
+impl TradingCalendar {
+    /// Creates a new [`TradingCalendar`] instance trading the `sessions` Monday to Friday.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - `sessions` is empty.
+    /// - A session does not open before it closes.
+    /// - A session opens before the previous session closes.
+    pub fn new(timezone: Tz, sessions: Vec<(NaiveTime, NaiveTime)>) -> anyhow::Result<Self> {
+        if sessions.is_empty() {
+            anyhow::bail!("Trading calendar requires at least one session")
+        }
+
+        let mut session_offsets: Vec<(TimeDelta, TimeDelta)> = Vec::with_capacity(sessions.len());
+        for (open, close) in sessions {
+            if open >= close {
+                anyhow::bail!("Session open {open} must be before close {close}")
+            }
+            let (open, close) = (since_midnight(open), since_midnight(close));
+            if session_offsets
+                .last()
+                .is_some_and(|(_, prev_close)| open < *prev_close)
+            {
+                anyhow::bail!("Sessions must be in order and not overlap")
+            }
+            session_offsets.push((open, close));
+        }
+
+        Ok(Self {
+            timezone,
+            sessions: session_offsets,
+            trading_days: vec![
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+            ],
+            holidays: HashSet::new(),
+            opening_auction: TimeDelta::zero(),
+            closing_auction: TimeDelta::zero(),
+        })
+    }
+
+    /// Sets the days of the week the venue trades.
+    #[must_use]
+    pub fn with_trading_days(mut self, trading_days: Vec<Weekday>) -> Self {
+        self.trading_days = trading_days;
+        self
+    }
+
+    /// Sets the local dates on which the venue does not trade.
+    #[must_use]
+    pub fn with_holidays(mut self, holidays: impl IntoIterator<Item = NaiveDate>) -> Self {
+        self.holidays = holidays.into_iter().collect();
+        self
+    }
+
+    /// Sets the duration of the opening auction before the first session of each day.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the auction would start before local midnight.
+    pub fn with_opening_auction(mut self, duration: TimeDelta) -> anyhow::Result<Self> {
+        let first_open = self.sessions[0].0;
+        if duration < TimeDelta::zero() || duration > first_open {
+            anyhow::bail!("Opening auction of {duration} must start on the trading day")
+        }
+        self.opening_auction = duration;
+        Ok(self)
+    }
+
+    /// Sets the duration of the closing auction after the last session of each day.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the auction would end after local midnight.
+    pub fn with_closing_auction(mut self, duration: TimeDelta) -> anyhow::Result<Self> {
+        let last_close = self.sessions[self.sessions.len() - 1].1;
+        if duration < TimeDelta::zero() || last_close + duration > TimeDelta::days(1) {
+            anyhow::bail!("Closing auction of {duration} must end on the trading day")
+        }
+        self.closing_auction = duration;
+        Ok(self)
+    }
+
+    /// Returns the timezone of the venue.
+    #[must_use]
+    pub const fn timezone(&self) -> Tz {
+        self.timezone
+    }
+
+    /// Returns whether the venue trades on the local `date`.
+    #[must_use]
+    pub fn is_trading_day(&self, date: NaiveDate) -> bool {
+        self.trading_days.contains(&date.weekday()) && !self.holidays.contains(&date)
+    }
+
+    /// Returns the trading phase of the venue at `ts`.
+    #[must_use]
+    pub fn phase(&self, ts: UnixNanos) -> SessionPhase {
+        let local = self
+            .timezone
+            .from_utc_datetime(&ts.to_datetime_utc().naive_utc());
+        if !self.is_trading_day(local.date_naive()) {
+            return SessionPhase::Closed;
+        }
+
+        let time = since_midnight(local.time());
+        let first_open = self.sessions[0].0;
+        let last_close = self.sessions[self.sessions.len() - 1].1;
+
+        if self
+            .sessions
+            .iter()
+            .any(|(open, close)| *open <= time && time < *close)
+        {
+            SessionPhase::Continuous
+        } else if first_open - self.opening_auction <= time && time < first_open {
+            SessionPhase::OpeningAuction
+        } else if last_close <= time && time < last_close + self.closing_auction {
+            SessionPhase::ClosingAuction
+        } else {
+            SessionPhase::Closed
+        }
+    }
+
+    /// Returns whether the venue is trading continuously at `ts`.
+    #[must_use]
+    pub fn is_open(&self, ts: UnixNanos) -> bool {
+        self.phase(ts) == SessionPhase::Continuous
+    }
+
+    /// Returns the open and close of the continuous session containing `ts`, if any.
+    #[must_use]
+    pub fn session_bounds(&self, ts: UnixNanos) -> Option<(UnixNanos, UnixNanos)> {
+        let local = self
+            .timezone
+            .from_utc_datetime(&ts.to_datetime_utc().naive_utc());
+        let date = local.date_naive();
+        if !self.is_trading_day(date) {
+            return None;
+        }
+
+        let time = since_midnight(local.time());
+        let (open, close) = self
+            .sessions
+            .iter()
+            .find(|(open, close)| *open <= time && time < *close)?;
+
+        let midnight = date.and_time(NaiveTime::MIN);
+        let to_unix_nanos = |offset: TimeDelta| {
+            self.timezone
+                .from_local_datetime(&(midnight + offset))
+                .earliest()
+                .map(|dt| UnixNanos::from(dt.with_timezone(&Utc)))
+        };
+        Some((to_unix_nanos(*open)?, to_unix_nanos(*close)?))
+    }
+
+    /// Returns the next close of a trading day after `ts`, being the venue-local close of the
+    /// last session (and closing auction) of the trading day, or `None` if there is no trading
+    /// day within a year.
+    #[must_use]
+    pub fn day_close(&self, ts: UnixNanos) -> Option<UnixNanos> {
+        let local = self
+            .timezone
+            .from_utc_datetime(&ts.to_datetime_utc().naive_utc());
+        let close = self.sessions[self.sessions.len() - 1].1 + self.closing_auction;
+        local
+            .date_naive()
+            .iter_days()
+            .take(366)
+            .filter(|date| self.is_trading_day(*date))
+            .filter_map(|date| {
+                self.timezone
+                    .from_local_datetime(&(date.and_time(NaiveTime::MIN) + close))
+                    .earliest()
+                    .map(|dt| UnixNanos::from(dt.with_timezone(&Utc)))
+            })
+            .find(|day_close| *day_close > ts)
+    }
+
+    /// Returns whether the interval from `start` to `end` lies within a single continuous
+    /// session, such as the interval of a bar which closes at `end`.
+    #[must_use]
+    pub fn contains_interval(&self, start: UnixNanos, end: UnixNanos) -> bool {
+        start < end
+            && self
+                .session_bounds(start)
+                .is_some_and(|(_, close)| end <= close)
+    }
+}
+
+fn since_midnight(time: NaiveTime) -> TimeDelta {
+    time.signed_duration_since(NaiveTime::MIN)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, NaiveDate, NaiveTime, TimeDelta, Utc, Weekday};
+    use chrono_tz::{America::New_York, Asia::Tokyo};
+    use nautilus_core::UnixNanos;
+    use rstest::rstest;
+
+    use super::{SessionPhase, TradingCalendar};
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    fn ts(datetime: &str) -> UnixNanos {
+        let datetime: DateTime<Utc> = datetime.parse().unwrap();
+        UnixNanos::from(datetime.timestamp_nanos_opt().unwrap() as u64)
+    }
+
+    fn nyse() -> TradingCalendar {
+        TradingCalendar::new(New_York, vec![(time(9, 30), time(16, 0))])
+            .unwrap()
+            .with_holidays([NaiveDate::from_ymd_opt(2024, 7, 4).unwrap()])
+            .with_opening_auction(TimeDelta::minutes(30))
+            .unwrap()
+            .with_closing_auction(TimeDelta::minutes(10))
+            .unwrap()
+    }
+
+    #[rstest]
+    #[case("2024-07-03T12:59:59Z", SessionPhase::Closed)]
+    #[case("2024-07-03T13:00:00Z", SessionPhase::OpeningAuction)]
+    #[case("2024-07-03T13:30:00Z", SessionPhase::Continuous)]
+    #[case("2024-07-03T19:59:59Z", SessionPhase::Continuous)]
+    #[case("2024-07-03T20:00:00Z", SessionPhase::ClosingAuction)]
+    #[case("2024-07-03T20:10:00Z", SessionPhase::Closed)]
+    #[case("2024-07-04T14:00:00Z", SessionPhase::Closed)] // Holiday
+    #[case("2024-07-06T14:00:00Z", SessionPhase::Closed)] // Saturday
+    #[case("2024-01-03T14:30:00Z", SessionPhase::Continuous)] // Standard time
+    #[case("2024-01-03T14:29:59Z", SessionPhase::OpeningAuction)]
+    fn test_phase(#[case] datetime: &str, #[case] expected: SessionPhase) {
+        assert_eq!(nyse().phase(ts(datetime)), expected);
+    }
+
+    #[rstest]
+    #[case("2024-07-03T02:00:00Z", true)] // 11:00 JST
+    #[case("2024-07-03T02:45:00Z", false)] // Lunch break
+    #[case("2024-07-03T03:30:00Z", true)] // 12:30 JST
+    #[case("2024-07-03T06:30:00Z", false)] // 15:30 JST
+    fn test_multiple_sessions(#[case] datetime: &str, #[case] expected: bool) {
+        let calendar = TradingCalendar::new(
+            Tokyo,
+            vec![(time(9, 0), time(11, 30)), (time(12, 30), time(15, 0))],
+        )
+        .unwrap();
+
+        assert_eq!(calendar.is_open(ts(datetime)), expected);
+    }
+
+    #[rstest]
+    fn test_trading_days() {
+        let calendar = TradingCalendar::new(New_York, vec![(time(9, 30), time(16, 0))])
+            .unwrap()
+            .with_trading_days(vec![Weekday::Sun]);
+
+        assert!(calendar.is_open(ts("2024-07-07T14:00:00Z")));
+        assert!(!calendar.is_open(ts("2024-07-08T14:00:00Z")));
+    }
+
+    #[rstest]
+    #[case(vec![])]
+    #[case(vec![(time(16, 0), time(9, 30))])]
+    #[case(vec![(time(9, 0), time(12, 0)), (time(11, 0), time(15, 0))])]
+    fn test_invalid_sessions(#[case] sessions: Vec<(NaiveTime, NaiveTime)>) {
+        assert!(TradingCalendar::new(New_York, sessions).is_err());
+    }
+
+    #[rstest]
+    fn test_invalid_auctions() {
+        let calendar = TradingCalendar::new(New_York, vec![(time(1, 0), time(23, 0))]).unwrap();
+
+        assert!(calendar
+            .clone()
+            .with_opening_auction(TimeDelta::hours(2))
+            .is_err());
+        assert!(calendar.with_closing_auction(TimeDelta::hours(2)).is_err());
+    }
+
+    #[rstest]
+    fn test_session_bounds() {
+        let calendar = nyse();
+
+        assert_eq!(
+            calendar.session_bounds(ts("2024-07-03T15:00:00Z")),
+            Some((ts("2024-07-03T13:30:00Z"), ts("2024-07-03T20:00:00Z")))
+        );
+        assert_eq!(calendar.session_bounds(ts("2024-07-03T13:15:00Z")), None);
+        assert_eq!(calendar.session_bounds(ts("2024-07-04T15:00:00Z")), None);
+    }
+
+    #[rstest]
+    #[case("2024-07-03T15:00:00Z", "2024-07-03T20:10:00Z")]
+    #[case("2024-07-03T03:00:00Z", "2024-07-03T20:10:00Z")] // Before the open
+    #[case("2024-07-03T20:10:00Z", "2024-07-05T20:10:00Z")] // At the close, then holiday
+    #[case("2024-07-05T22:00:00Z", "2024-07-08T20:10:00Z")] // Over the weekend
+    fn test_day_close(#[case] datetime: &str, #[case] expected: &str) {
+        assert_eq!(nyse().day_close(ts(datetime)), Some(ts(expected)));
+    }
+
+    #[rstest]
+    #[case("2024-07-03T13:30:00Z", "2024-07-03T13:31:00Z", true)]
+    #[case("2024-07-03T19:59:00Z", "2024-07-03T20:00:00Z", true)]
+    #[case("2024-07-03T13:29:00Z", "2024-07-03T13:30:00Z", false)] // Closes at the open
+    #[case("2024-07-03T13:00:00Z", "2024-07-03T14:00:00Z", false)] // Straddles the open
+    #[case("2024-07-03T19:30:00Z", "2024-07-03T20:30:00Z", false)] // Straddles the close
+    fn test_contains_interval(#[case] start: &str, #[case] end: &str, #[case] expected: bool) {
+        assert_eq!(nyse().contains_interval(ts(start), ts(end)), expected);
+    }
+}
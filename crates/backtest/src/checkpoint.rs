@@ -0,0 +1,181 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides checkpoints of backtest state, to resume long simulations after a crash or to
+//! bisect the point at which two runs diverge.
+//!
+//! A checkpoint holds the simulated state of each venue along with the position reached in the
+//! data, and is resumed by restoring it into an engine set up with the same venues and data.
+
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use nautilus_core::UnixNanos;
+use nautilus_execution::matching_engine::snapshot::MatchingEngineSnapshot;
+use nautilus_model::{
+    accounts::AccountAny,
+    identifiers::{InstrumentId, Venue},
+    orders::OrderAny,
+    position::Position,
+};
+use serde::{Deserialize, Serialize};
+
+const CHECKPOINT_PREFIX: &str = "checkpoint-";
+const CHECKPOINT_EXTENSION: &str = "json";
+
+/// Represents the state of a simulated exchange at a checkpoint.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ExchangeCheckpoint {
+    /// The venue of the exchange.
+    pub venue: Venue,
+    /// UNIX timestamp (nanoseconds) of the exchange clock.
+    pub ts: UnixNanos,
+    /// The state of the matching engine for each instrument.
+    pub matching_engines: Vec<MatchingEngineSnapshot>,
+    /// The orders at the venue held in the cache.
+    pub orders: Vec<OrderAny>,
+    /// The positions at the venue held in the cache.
+    pub positions: Vec<Position>,
+    /// The account for the venue held in the cache.
+    pub account: Option<AccountAny>,
+    /// UNIX timestamp (nanoseconds) of the next funding settlement.
+    pub next_funding_ts: Option<UnixNanos>,
+    /// The instruments which have expired and been settled.
+    #[serde(default)]
+    pub expired_instruments: Vec<InstrumentId>,
+    /// The number of expiry settlement fills generated.
+    #[serde(default)]
+    pub expiry_fill_count: u64,
+}
+
+/// Represents the state of a backtest at a checkpoint.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BacktestCheckpoint {
+    /// The number of data points processed.
+    pub iteration: u64,
+    /// UNIX timestamp (nanoseconds) of the last data delivered.
+    pub last_ns: UnixNanos,
+    /// The number of in-memory data points processed.
+    pub data_index: usize,
+    /// The number of data points processed from the data sources.
+    pub streamed_count: u64,
+    /// The number of corporate actions processed.
+    #[serde(default)]
+    pub corporate_action_index: usize,
+    /// The state of each venue.
+    pub venues: Vec<ExchangeCheckpoint>,
+}
+
+impl BacktestCheckpoint {
+    /// Saves the checkpoint as JSON to the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the file cannot be written.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        // Written to a temporary file first, so a crash never leaves a partial checkpoint
+        let tmp_path = path.with_extension("tmp");
+        let mut writer = BufWriter::new(File::create(&tmp_path)?);
+        serde_json::to_writer(&mut writer, self)?;
+        writer.flush()?;
+        drop(writer);
+        std::fs::rename(tmp_path, path)?;
+        Ok(())
+    }
+
+    /// Loads a checkpoint from the JSON file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the file cannot be read or parsed.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        // Read in full, as identifiers deserialize from borrowed strings
+        let bytes = std::fs::read(path)?;
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+}
+
+/// Returns the path of the checkpoint taken at the `iteration` in the `directory`.
+#[must_use]
+pub fn checkpoint_path(directory: &Path, iteration: u64) -> PathBuf {
+    directory.join(format!(
+        "{CHECKPOINT_PREFIX}{iteration:020}.{CHECKPOINT_EXTENSION}"
+    ))
+}
+
+/// Returns the path of the latest checkpoint in the `directory`, if any.
+///
+/// # Errors
+///
+/// This function returns an error if the directory cannot be read.
+pub fn find_latest_checkpoint(directory: &Path) -> anyhow::Result<Option<PathBuf>> {
+    let mut latest: Option<PathBuf> = None;
+    for entry in std::fs::read_dir(directory)? {
+        let path = entry?.path();
+        let is_checkpoint = path
+            .extension()
+            .is_some_and(|ext| ext == CHECKPOINT_EXTENSION)
+            && path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(CHECKPOINT_PREFIX));
+        // Iterations are zero padded, so the latest checkpoint sorts last
+        if is_checkpoint && latest.as_ref().is_none_or(|latest| path > *latest) {
+            latest = Some(path);
+        }
+    }
+    Ok(latest)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[rstest]
+    fn test_find_latest_checkpoint() {
+        let dir = TempDir::new().unwrap();
+        assert_eq!(find_latest_checkpoint(dir.path()).unwrap(), None);
+
+        for iteration in [9, 100, 20] {
+            let checkpoint = BacktestCheckpoint {
+                iteration,
+                last_ns: UnixNanos::from(iteration),
+                data_index: iteration as usize,
+                streamed_count: 0,
+                corporate_action_index: 0,
+                venues: Vec::new(),
+            };
+            checkpoint
+                .save(&checkpoint_path(dir.path(), iteration))
+                .unwrap();
+        }
+        std::fs::write(dir.path().join("notes.json"), "{}").unwrap();
+
+        let latest = find_latest_checkpoint(dir.path()).unwrap().unwrap();
+        let checkpoint = BacktestCheckpoint::load(&latest).unwrap();
+        assert_eq!(latest, checkpoint_path(dir.path(), 100));
+        assert_eq!(checkpoint.iteration, 100);
+        assert_eq!(checkpoint.last_ns, UnixNanos::from(100));
+    }
+}
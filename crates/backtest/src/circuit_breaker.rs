@@ -0,0 +1,233 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a limit up-limit down (LULD) style circuit breaker for simulated trading halts.
+
+use std::collections::VecDeque;
+
+use nautilus_core::{
+    correctness::{check_in_range_inclusive_f64, check_positive_u64},
+    UnixNanos,
+};
+use nautilus_model::types::Price;
+
+/// Halts trading in an instrument when a trade prints outside the price bands around a
+/// reference price, then resumes trading after the halt duration.
+///
+/// The reference price is the average trade price over the reference window, and is
+/// re-established from the trades after each halt.
+#[derive(Clone, Debug)]
+pub struct CircuitBreaker {
+    band_pct: f64,
+    reference_window_ns: u64,
+    halt_duration_ns: u64,
+    prices: VecDeque<(UnixNanos, f64)>,
+    halted_until: Option<UnixNanos>,
+}
+
+impl CircuitBreaker {
+    /// Creates a new [`CircuitBreaker`] instance.
+    ///
+    /// The `band_pct` is the fraction of the reference price either side of it within which
+    /// trades must print, e.g. 0.05 for 5% bands.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - `band_pct` is not in the range (0, 1].
+    /// - `reference_window_ns` or `halt_duration_ns` is zero.
+    pub fn new(
+        band_pct: f64,
+        reference_window_ns: u64,
+        halt_duration_ns: u64,
+    ) -> anyhow::Result<Self> {
+        check_in_range_inclusive_f64(band_pct, 0.0, 1.0, "band_pct")?;
+        if band_pct == 0.0 {
+            anyhow::bail!("invalid f64 for 'band_pct', was {band_pct}")
+        }
+        check_positive_u64(reference_window_ns, "reference_window_ns")?;
+        check_positive_u64(halt_duration_ns, "halt_duration_ns")?;
+
+        Ok(Self {
+            band_pct,
+            reference_window_ns,
+            halt_duration_ns,
+            prices: VecDeque::new(),
+            halted_until: None,
+        })
+    }
+
+    /// Returns whether trading is halted.
+    #[must_use]
+    pub const fn is_halted(&self) -> bool {
+        self.halted_until.is_some()
+    }
+
+    /// Returns the time at which the current halt ends.
+    #[must_use]
+    pub const fn halted_until(&self) -> Option<UnixNanos> {
+        self.halted_until
+    }
+
+    /// Returns the reference price for the bands, if any trades are in the reference window.
+    #[must_use]
+    pub fn reference_price(&self) -> Option<f64> {
+        if self.prices.is_empty() {
+            return None;
+        }
+        let total: f64 = self.prices.iter().map(|(_, px)| px).sum();
+        Some(total / self.prices.len() as f64)
+    }
+
+    /// Returns the lower and upper price bands, if a reference price is established.
+    #[must_use]
+    pub fn price_bands(&self) -> Option<(f64, f64)> {
+        self.reference_price().map(|reference_price| {
+            (
+                reference_price * (1.0 - self.band_pct),
+                reference_price * (1.0 + self.band_pct),
+            )
+        })
+    }
+
+    /// Updates the breaker with a trade at `price` and returns whether it triggers a halt.
+    ///
+    /// Trades while halted are ignored.
+    pub fn update(&mut self, ts: UnixNanos, price: Price) -> bool {
+        if self.is_halted() {
+            return false;
+        }
+
+        while self
+            .prices
+            .front()
+            .is_some_and(|(ts_price, _)| *ts_price + self.reference_window_ns <= ts)
+        {
+            self.prices.pop_front();
+        }
+
+        let price = price.as_f64();
+        if let Some((lower, upper)) = self.price_bands() {
+            if price < lower || price > upper {
+                log::warn!(
+                    "Trade at {price} outside price bands [{lower:.4}, {upper:.4}], halting until {}",
+                    ts + self.halt_duration_ns
+                );
+                self.halted_until = Some(ts + self.halt_duration_ns);
+                self.prices.clear();
+                return true;
+            }
+        }
+
+        self.prices.push_back((ts, price));
+        false
+    }
+
+    /// Ends the halt if its duration has elapsed by `ts` and returns whether trading resumed.
+    pub fn check_resume(&mut self, ts: UnixNanos) -> bool {
+        if self
+            .halted_until
+            .is_some_and(|halted_until| halted_until <= ts)
+        {
+            self.halted_until = None;
+            return true;
+        }
+        false
+    }
+
+    /// Resets the breaker to its initial state.
+    pub fn reset(&mut self) {
+        self.prices.clear();
+        self.halted_until = None;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::UnixNanos;
+    use nautilus_model::types::Price;
+    use rstest::rstest;
+
+    use super::CircuitBreaker;
+
+    fn breaker() -> CircuitBreaker {
+        CircuitBreaker::new(0.05, 1_000, 500).unwrap()
+    }
+
+    #[rstest]
+    fn test_trades_within_bands_do_not_halt() {
+        let mut breaker = breaker();
+
+        assert!(!breaker.update(UnixNanos::from(0), Price::from("100.00")));
+        assert!(!breaker.update(UnixNanos::from(1), Price::from("104.00")));
+
+        assert_eq!(breaker.reference_price(), Some(102.0));
+        assert!(!breaker.is_halted());
+    }
+
+    #[rstest]
+    #[case("94.00")]
+    #[case("106.00")]
+    fn test_trade_outside_bands_halts(#[case] price: &str) {
+        let mut breaker = breaker();
+        breaker.update(UnixNanos::from(0), Price::from("100.00"));
+
+        assert!(breaker.update(UnixNanos::from(10), Price::from(price)));
+        assert!(breaker.is_halted());
+        assert_eq!(breaker.halted_until(), Some(UnixNanos::from(510)));
+    }
+
+    #[rstest]
+    fn test_resumes_after_halt_duration() {
+        let mut breaker = breaker();
+        breaker.update(UnixNanos::from(0), Price::from("100.00"));
+        breaker.update(UnixNanos::from(10), Price::from("110.00"));
+
+        assert!(!breaker.check_resume(UnixNanos::from(509)));
+        assert!(breaker.check_resume(UnixNanos::from(510)));
+        assert!(!breaker.is_halted());
+
+        // The reference price is re-established after the halt
+        assert_eq!(breaker.reference_price(), None);
+        assert!(!breaker.update(UnixNanos::from(520), Price::from("110.00")));
+    }
+
+    #[rstest]
+    fn test_reference_price_rolls_with_window() {
+        let mut breaker = breaker();
+        breaker.update(UnixNanos::from(0), Price::from("100.00"));
+        breaker.update(UnixNanos::from(900), Price::from("104.00"));
+
+        // The first trade has left the reference window
+        assert!(!breaker.update(UnixNanos::from(1_000), Price::from("108.00")));
+        assert_eq!(breaker.reference_price(), Some(106.0));
+    }
+
+    #[rstest]
+    #[case(0.0, 1_000, 500)]
+    #[case(1.5, 1_000, 500)]
+    #[case(0.05, 0, 500)]
+    #[case(0.05, 1_000, 0)]
+    fn test_invalid_params(
+        #[case] band_pct: f64,
+        #[case] reference_window_ns: u64,
+        #[case] halt_duration_ns: u64,
+    ) {
+        assert!(CircuitBreaker::new(band_pct, reference_window_ns, halt_duration_ns).is_err());
+    }
+}
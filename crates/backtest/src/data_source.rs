@@ -0,0 +1,276 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides lazily loaded data sources for streaming historical data into a backtest.
+//!
+//! A source is any iterator of data in `ts_init` order, such as the query result of a Parquet
+//! catalog session or records decoded from a DBN file. Sources are merged by holding a single
+//! pending data point from each, so datasets larger than memory can be replayed.
+
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use nautilus_core::UnixNanos;
+use nautilus_model::data::Data;
+
+/// A boxed iterator of data in `ts_init` order.
+pub type DataSource = Box<dyn Iterator<Item = Data>>;
+
+/// A data source loading its data one chunk at a time, such as a file read in batches.
+pub struct ChunkedDataSource<F> {
+    load_chunk: F,
+    chunk: std::vec::IntoIter<Data>,
+}
+
+impl<F> ChunkedDataSource<F>
+where
+    F: FnMut() -> Option<Vec<Data>>,
+{
+    /// Creates a new [`ChunkedDataSource`] instance.
+    ///
+    /// The `load_chunk` function is called for the next chunk once the previous chunk has been
+    /// consumed, returning `None` when the source is exhausted.
+    pub fn new(load_chunk: F) -> Self {
+        Self {
+            load_chunk,
+            chunk: Vec::new().into_iter(),
+        }
+    }
+}
+
+impl<F> Iterator for ChunkedDataSource<F>
+where
+    F: FnMut() -> Option<Vec<Data>>,
+{
+    type Item = Data;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(data) = self.chunk.next() {
+                return Some(data);
+            }
+            self.chunk = (self.load_chunk)()?.into_iter();
+        }
+    }
+}
+
+struct PendingData {
+    ts: UnixNanos,
+    source: usize,
+    data: Data,
+}
+
+impl PartialEq for PendingData {
+    fn eq(&self, other: &Self) -> bool {
+        self.ts == other.ts && self.source == other.source
+    }
+}
+
+impl Eq for PendingData {}
+
+impl PartialOrd for PendingData {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PendingData {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Max heap ordering must be reversed, with ties taken in the order sources were added
+        other
+            .ts
+            .cmp(&self.ts)
+            .then_with(|| other.source.cmp(&self.source))
+    }
+}
+
+/// Merges data sources lazily by a timestamp key, such as the time the data is delivered.
+#[derive(Default)]
+pub struct DataSourceMerge {
+    sources: Vec<DataSource>,
+    heap: BinaryHeap<PendingData>,
+}
+
+impl DataSourceMerge {
+    /// Creates a new [`DataSourceMerge`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the `source` to the merge, pulling its first data point.
+    pub fn add_source(&mut self, mut source: DataSource, key: impl Fn(&Data) -> UnixNanos) {
+        let index = self.sources.len();
+        if let Some(data) = source.next() {
+            self.heap.push(PendingData {
+                ts: key(&data),
+                source: index,
+                data,
+            });
+        }
+        self.sources.push(source);
+    }
+
+    /// Returns the key of the next data point, if any.
+    #[must_use]
+    pub fn peek_ts(&self) -> Option<UnixNanos> {
+        self.heap.peek().map(|pending| pending.ts)
+    }
+
+    /// Returns the next data point across all sources, pulling the following data point from
+    /// its source.
+    pub fn next(&mut self, key: impl Fn(&Data) -> UnixNanos) -> Option<Data> {
+        let PendingData { source, data, .. } = self.heap.pop()?;
+        if let Some(next) = self.sources[source].next() {
+            self.heap.push(PendingData {
+                ts: key(&next),
+                source,
+                data: next,
+            });
+        }
+        Some(data)
+    }
+
+    /// Re-computes the keys of the pending data points, such as when clock skews change.
+    pub fn rekey(&mut self, key: impl Fn(&Data) -> UnixNanos) {
+        let pending = std::mem::take(&mut self.heap);
+        self.heap = pending
+            .into_iter()
+            .map(|mut pending| {
+                pending.ts = key(&pending.data);
+                pending
+            })
+            .collect();
+    }
+
+    /// Returns whether all sources are exhausted.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::UnixNanos;
+    use nautilus_model::{
+        data::{Data, GetTsInit, QuoteTick},
+        identifiers::InstrumentId,
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::{ChunkedDataSource, DataSourceMerge};
+
+    fn quote(symbol: &str, ts: u64) -> Data {
+        Data::Quote(QuoteTick::new(
+            InstrumentId::from(format!("{symbol}.SIM").as_str()),
+            Price::from("1.00"),
+            Price::from("1.01"),
+            Quantity::from(1),
+            Quantity::from(1),
+            ts.into(),
+            ts.into(),
+        ))
+    }
+
+    fn ts_init(data: &Data) -> UnixNanos {
+        data.ts_init()
+    }
+
+    #[rstest]
+    fn test_chunked_source_loads_lazily() {
+        let mut chunks = vec![
+            vec![quote("A", 3)],
+            vec![],
+            vec![quote("A", 1), quote("A", 2)],
+        ];
+        let mut loads = 0;
+        let mut source = ChunkedDataSource::new(|| {
+            loads += 1;
+            chunks.pop()
+        });
+
+        assert_eq!(source.next().map(|d| d.ts_init()), Some(UnixNanos::from(1)));
+        assert_eq!(source.next().map(|d| d.ts_init()), Some(UnixNanos::from(2)));
+        assert_eq!(source.next().map(|d| d.ts_init()), Some(UnixNanos::from(3)));
+        assert!(source.next().is_none());
+        drop(source);
+        assert_eq!(loads, 4);
+    }
+
+    #[rstest]
+    fn test_merge_orders_by_key_across_sources() {
+        let mut merge = DataSourceMerge::new();
+        merge.add_source(
+            Box::new(vec![quote("A", 1), quote("A", 4), quote("A", 5)].into_iter()),
+            ts_init,
+        );
+        merge.add_source(
+            Box::new(vec![quote("B", 2), quote("B", 4)].into_iter()),
+            ts_init,
+        );
+        merge.add_source(Box::new(std::iter::empty()), ts_init);
+
+        assert_eq!(merge.peek_ts(), Some(UnixNanos::from(1)));
+
+        let mut merged = Vec::new();
+        while let Some(data) = merge.next(ts_init) {
+            merged.push((
+                data.instrument_id().symbol.to_string(),
+                data.ts_init().as_u64(),
+            ));
+        }
+
+        assert_eq!(
+            merged,
+            vec![
+                ("A".to_string(), 1),
+                ("B".to_string(), 2),
+                ("A".to_string(), 4),
+                ("B".to_string(), 4),
+                ("A".to_string(), 5),
+            ]
+        );
+        assert!(merge.is_empty());
+    }
+
+    #[rstest]
+    fn test_rekey_reorders_pending_data() {
+        let mut merge = DataSourceMerge::new();
+        merge.add_source(Box::new(vec![quote("A", 1)].into_iter()), ts_init);
+        merge.add_source(Box::new(vec![quote("B", 2)].into_iter()), ts_init);
+
+        let shifted = |data: &Data| {
+            let ts = data.ts_init().as_u64();
+            UnixNanos::from(if data.instrument_id().symbol.as_str() == "A" {
+                ts + 10
+            } else {
+                ts
+            })
+        };
+        merge.rekey(shifted);
+
+        assert_eq!(merge.peek_ts(), Some(UnixNanos::from(2)));
+        assert_eq!(
+            merge
+                .next(shifted)
+                .map(|d| d.instrument_id().symbol.to_string()),
+            Some("B".to_string())
+        );
+    }
+}
@@ -15,8 +15,27 @@
 
 //! The core `BacktestEngine` for backtesting on historical data.
 
-use nautilus_common::{clock::TestClock, timer::TimeEventHandlerV2};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
+    path::PathBuf,
+    rc::Rc,
+};
+
+use nautilus_common::{clock::TestClock, msgbus::MessageBus, timer::TimeEventHandlerV2};
 use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::{CorporateAction, Data, GetTsInit},
+    identifiers::Venue,
+};
+use ustr::Ustr;
+
+use crate::{
+    audit::DeterminismAudit,
+    checkpoint::{checkpoint_path, BacktestCheckpoint},
+    data_source::{DataSource, DataSourceMerge},
+    exchange::SimulatedExchange,
+};
 
 /// Provides a means of accumulating and draining time event handlers.
 pub struct TimeEventAccumulator {
@@ -56,18 +75,639 @@ impl Default for TimeEventAccumulator {
     }
 }
 
+/// Provides a backtest engine running historical data through one or more simulated venues.
+///
+/// Each venue simulates its own command latency through the latency model of its exchange.
+/// The data feed of each venue may be given a clock skew relative to the other feeds, which
+/// shifts the time the venue's data is delivered, so that strategies trading across venues
+/// can be evaluated for opportunities which only exist due to unsynchronized timestamps.
+///
+/// Data is either held in memory, or streamed lazily from data sources so that datasets larger
+/// than memory can be replayed, with both merged in order of delivery time.
+///
+/// Corporate actions are processed by the venue of their instrument on their effective date,
+/// before any data delivered at or after that time.
+///
+/// Checkpoints of the backtest state may be written at intervals while running, so that a
+/// long simulation can be resumed by restoring the latest checkpoint into an engine set up with
+/// the same venues and data.
+///
+/// In the determinism audit mode the events sent by the venues are hashed in sequence, so that
+/// a run can be compared against a reference run to prove it is reproducible.
+pub struct BacktestEngine {
+    // Ordered, so venues process in the same order on every run
+    venues: BTreeMap<Venue, SimulatedExchange>,
+    clock_skews: HashMap<Venue, i64>,
+    data: Vec<Data>,
+    data_sorted: bool,
+    streams: DataSourceMerge,
+    index: usize,
+    streamed_count: u64,
+    corporate_actions: Vec<CorporateAction>,
+    corporate_action_index: usize,
+    iteration: u64,
+    last_ns: UnixNanos,
+    checkpoint_directory: Option<PathBuf>,
+    checkpoint_interval: u64,
+    next_checkpoint: u64,
+    audit: Option<Rc<RefCell<DeterminismAudit>>>,
+}
+
+impl BacktestEngine {
+    /// Creates a new [`BacktestEngine`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            venues: BTreeMap::new(),
+            clock_skews: HashMap::new(),
+            data: Vec::new(),
+            data_sorted: true,
+            streams: DataSourceMerge::new(),
+            index: 0,
+            streamed_count: 0,
+            corporate_actions: Vec::new(),
+            corporate_action_index: 0,
+            iteration: 0,
+            last_ns: UnixNanos::default(),
+            checkpoint_directory: None,
+            checkpoint_interval: 0,
+            next_checkpoint: 0,
+            audit: None,
+        }
+    }
+
+    /// Adds the simulated `exchange` as a venue of the backtest.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a venue with the same ID has already been added.
+    pub fn add_venue(&mut self, exchange: SimulatedExchange) -> anyhow::Result<()> {
+        let venue = exchange.id();
+        if self.venues.contains_key(&venue) {
+            anyhow::bail!("Venue {venue} already added")
+        }
+        self.venues.insert(venue, exchange);
+        log::info!("Added venue {venue}");
+        Ok(())
+    }
+
+    /// Sets the clock skew of the `venue` data feed in nanoseconds, relative to the other feeds.
+    ///
+    /// A positive skew delays the delivery of the venue's data, a negative skew advances it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the venue has not been added.
+    pub fn set_clock_skew(&mut self, venue: Venue, skew_ns: i64) -> anyhow::Result<()> {
+        if !self.venues.contains_key(&venue) {
+            anyhow::bail!("Venue {venue} not found")
+        }
+        self.clock_skews.insert(venue, skew_ns);
+        self.data_sorted = false;
+        log::info!("Setting clock skew for {venue} to {skew_ns}ns");
+        Ok(())
+    }
+
+    /// Returns the clock skew of the `venue` data feed in nanoseconds.
+    #[must_use]
+    pub fn clock_skew(&self, venue: Venue) -> i64 {
+        self.clock_skews.get(&venue).copied().unwrap_or_default()
+    }
+
+    /// Returns the simulated exchange for the `venue`.
+    #[must_use]
+    pub fn get_exchange(&self, venue: Venue) -> Option<&SimulatedExchange> {
+        self.venues.get(&venue)
+    }
+
+    /// Returns the mutable simulated exchange for the `venue`.
+    pub fn get_exchange_mut(&mut self, venue: Venue) -> Option<&mut SimulatedExchange> {
+        self.venues.get_mut(&venue)
+    }
+
+    /// Adds the `data` to the backtest, to be merged with any existing data by delivery time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any data is for a venue which has not been added.
+    pub fn add_data(&mut self, data: Vec<Data>) -> anyhow::Result<()> {
+        if let Some(unknown) = data
+            .iter()
+            .find(|data| !self.venues.contains_key(&data.instrument_id().venue))
+        {
+            anyhow::bail!("No venue added for {}", unknown.instrument_id())
+        }
+        self.data.extend(data);
+        self.data_sorted = false;
+        Ok(())
+    }
+
+    /// Adds the data `source` to be streamed lazily into the backtest, merged with the other
+    /// data by delivery time.
+    ///
+    /// The source must yield data in `ts_init` order, such as a Parquet catalog query result
+    /// or a [`ChunkedDataSource`](crate::data_source::ChunkedDataSource) over a DBN file.
+    /// Data for a venue which has not been added is skipped. A source is consumed by running
+    /// the backtest, so is not replayed after a reset.
+    pub fn add_data_source(&mut self, source: impl Iterator<Item = Data> + 'static) {
+        let source: DataSource = Box::new(source);
+        let clock_skews = &self.clock_skews;
+        self.streams
+            .add_source(source, |data| delivery_ts(clock_skews, data));
+    }
+
+    /// Adds the corporate `actions` to be processed on their effective dates.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Any action is for a venue which has not been added.
+    /// - The engine has already run past the effective date of any action.
+    pub fn add_corporate_actions(&mut self, actions: Vec<CorporateAction>) -> anyhow::Result<()> {
+        if let Some(unknown) = actions
+            .iter()
+            .find(|action| !self.venues.contains_key(&action.instrument_id.venue))
+        {
+            anyhow::bail!("No venue added for {}", unknown.instrument_id)
+        }
+        if let Some(past) = actions
+            .iter()
+            .find(|action| self.iteration > 0 && action.ts_event <= self.last_ns)
+        {
+            anyhow::bail!("Corporate action {past} effective before the current time")
+        }
+
+        // Only the actions not yet processed are re-ordered
+        self.corporate_actions.extend(actions);
+        self.corporate_actions[self.corporate_action_index..].sort_by_key(|action| action.ts_event);
+        Ok(())
+    }
+
+    /// Returns the number of data points processed.
+    #[must_use]
+    pub const fn iteration(&self) -> u64 {
+        self.iteration
+    }
+
+    /// Enables the determinism audit mode, recording each order event and account state sent
+    /// by the venues, and returns the audit.
+    ///
+    /// Should be enabled once the venues have been added and the handlers on their message
+    /// buses registered, as the audit taps the endpoints the events are sent to.
+    pub fn enable_audit(&mut self) -> Rc<RefCell<DeterminismAudit>> {
+        if let Some(audit) = &self.audit {
+            return audit.clone();
+        }
+
+        let audit = Rc::new(RefCell::new(DeterminismAudit::new()));
+        let mut tapped: Vec<&Rc<RefCell<MessageBus>>> = Vec::new();
+        for exchange in self.venues.values() {
+            // Venues may share a message bus, which is only tapped once
+            let msgbus = exchange.msgbus();
+            if tapped.iter().any(|tapped| Rc::ptr_eq(tapped, msgbus)) {
+                continue;
+            }
+            let mut bus = msgbus.borrow_mut();
+            let endpoint = bus.switchboard.exec_engine_process;
+            DeterminismAudit::tap(&audit, &mut bus, endpoint);
+            DeterminismAudit::tap(&audit, &mut bus, Ustr::from("Portfolio.update_account"));
+            tapped.push(msgbus);
+        }
+        log::info!("Enabled determinism audit");
+        self.audit = Some(audit.clone());
+        audit
+    }
+
+    /// Sets a checkpoint to be written to the `directory` every `interval` data points while
+    /// running.
+    ///
+    /// A checkpoint falling due while commands are pending at any venue is deferred until
+    /// they have been processed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `interval` is zero.
+    /// - The directory cannot be created.
+    pub fn set_checkpointing(
+        &mut self,
+        directory: impl Into<PathBuf>,
+        interval: u64,
+    ) -> anyhow::Result<()> {
+        if interval == 0 {
+            anyhow::bail!("Checkpoint interval must be positive")
+        }
+        let directory = directory.into();
+        std::fs::create_dir_all(&directory)?;
+        log::info!(
+            "Setting checkpoints every {interval} iterations to {}",
+            directory.display()
+        );
+        self.checkpoint_directory = Some(directory);
+        self.checkpoint_interval = interval;
+        self.next_checkpoint = self.iteration + interval;
+        Ok(())
+    }
+
+    /// Returns a checkpoint of the state of the backtest.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any venue has commands pending, which cannot be captured.
+    pub fn checkpoint(&self) -> anyhow::Result<BacktestCheckpoint> {
+        if let Some(exchange) = self
+            .venues
+            .values()
+            .find(|exchange| exchange.has_pending_commands())
+        {
+            anyhow::bail!("Commands pending at venue {}", exchange.id())
+        }
+
+        let mut venues: Vec<_> = self
+            .venues
+            .values()
+            .map(SimulatedExchange::checkpoint)
+            .collect();
+        venues.sort_by_key(|checkpoint| checkpoint.venue);
+
+        Ok(BacktestCheckpoint {
+            iteration: self.iteration,
+            last_ns: self.last_ns,
+            data_index: self.index,
+            streamed_count: self.streamed_count,
+            corporate_action_index: self.corporate_action_index,
+            venues,
+        })
+    }
+
+    /// Restores the state of the backtest from the `checkpoint`, so that running continues
+    /// from the point it was taken.
+    ///
+    /// The engine must have been set up with the same venues, data and data sources as the
+    /// engine the checkpoint was taken from, and not yet run.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The engine has already run.
+    /// - The checkpoint is beyond the data added to the engine.
+    /// - A venue of the checkpoint has not been added, or cannot be restored.
+    pub fn restore(&mut self, checkpoint: &BacktestCheckpoint) -> anyhow::Result<()> {
+        if self.iteration > 0 {
+            anyhow::bail!("Cannot restore a checkpoint after running")
+        }
+        if checkpoint.data_index > self.data.len() {
+            anyhow::bail!(
+                "Checkpoint data index {} beyond the {} data points added",
+                checkpoint.data_index,
+                self.data.len()
+            )
+        }
+        if checkpoint.corporate_action_index > self.corporate_actions.len() {
+            anyhow::bail!(
+                "Checkpoint corporate action index {} beyond the {} corporate actions added",
+                checkpoint.corporate_action_index,
+                self.corporate_actions.len()
+            )
+        }
+        if let Some(unknown) = checkpoint
+            .venues
+            .iter()
+            .find(|venue| !self.venues.contains_key(&venue.venue))
+        {
+            anyhow::bail!("Venue {} not found", unknown.venue)
+        }
+
+        self.sort_data();
+        let clock_skews = &self.clock_skews;
+        for _ in 0..checkpoint.streamed_count {
+            if self
+                .streams
+                .next(|data| delivery_ts(clock_skews, data))
+                .is_none()
+            {
+                anyhow::bail!(
+                    "Checkpoint streamed count {} beyond the data sources added",
+                    checkpoint.streamed_count
+                )
+            }
+        }
+
+        for venue in &checkpoint.venues {
+            if let Some(exchange) = self.venues.get_mut(&venue.venue) {
+                exchange.restore(venue)?;
+            }
+        }
+        self.index = checkpoint.data_index;
+        self.streamed_count = checkpoint.streamed_count;
+        self.corporate_action_index = checkpoint.corporate_action_index;
+        self.iteration = checkpoint.iteration;
+        self.last_ns = checkpoint.last_ns;
+        self.next_checkpoint = self.iteration + self.checkpoint_interval;
+
+        log::info!("Restored checkpoint at iteration {}", self.iteration);
+        Ok(())
+    }
+
+    /// Returns the time the `data` is delivered, being its `ts_init` shifted by the clock skew
+    /// of its venue feed.
+    #[must_use]
+    pub fn delivery_ts(&self, data: &Data) -> UnixNanos {
+        delivery_ts(&self.clock_skews, data)
+    }
+
+    /// Runs the backtest over the data delivered up to `end`, or all remaining data if `None`.
+    ///
+    /// Each data point is processed by the exchange of its venue, then every venue processes
+    /// the commands which have arrived by the delivery time.
+    pub fn run(&mut self, end: Option<UnixNanos>) {
+        self.sort_data();
+
+        while let Some(data) = self.next_data(end) {
+            // Delivery times cannot move backwards when skews change mid-run
+            let ts = self.delivery_ts(&data).max(self.last_ns);
+            self.process_corporate_actions(ts);
+
+            if let Some(exchange) = self.venues.get_mut(&data.instrument_id().venue) {
+                process_data(exchange, data);
+            } else {
+                log::warn!("No venue added for {}", data.instrument_id());
+            }
+            for exchange in self.venues.values_mut() {
+                exchange.process(ts);
+            }
+
+            self.last_ns = ts;
+            self.iteration += 1;
+            self.write_checkpoint_if_due();
+        }
+    }
+
+    /// Resets the engine and its venues, retaining the data to run again.
+    pub fn reset(&mut self) {
+        for exchange in self.venues.values_mut() {
+            exchange.reset();
+        }
+        self.index = 0;
+        self.corporate_action_index = 0;
+        self.iteration = 0;
+        self.last_ns = UnixNanos::default();
+        self.next_checkpoint = self.checkpoint_interval;
+        if let Some(audit) = &self.audit {
+            audit.borrow_mut().clear();
+        }
+        log::info!("Reset backtest engine");
+    }
+
+    /// Processes the corporate actions effective by `ts`, advancing the venues to the
+    /// effective time of each action first.
+    fn process_corporate_actions(&mut self, ts: UnixNanos) {
+        while let Some(action) = self
+            .corporate_actions
+            .get(self.corporate_action_index)
+            .filter(|action| action.ts_event <= ts)
+            .copied()
+        {
+            self.corporate_action_index += 1;
+
+            let ts_effective = action.ts_event.max(self.last_ns);
+            for exchange in self.venues.values_mut() {
+                exchange.process(ts_effective);
+            }
+            self.last_ns = ts_effective;
+
+            if let Some(exchange) = self.venues.get_mut(&action.instrument_id.venue) {
+                if let Err(e) = exchange.process_corporate_action(&action) {
+                    log::error!("Cannot process corporate action {action}: {e}");
+                }
+            }
+        }
+    }
+
+    fn write_checkpoint_if_due(&mut self) {
+        let Some(directory) = &self.checkpoint_directory else {
+            return;
+        };
+        if self.iteration < self.next_checkpoint {
+            return;
+        }
+        // Deferred until no commands are pending
+        let Ok(checkpoint) = self.checkpoint() else {
+            return;
+        };
+
+        let path = checkpoint_path(directory, self.iteration);
+        match checkpoint.save(&path) {
+            Ok(()) => log::info!("Wrote checkpoint {}", path.display()),
+            Err(e) => log::error!("Failed to write checkpoint {}: {e}", path.display()),
+        }
+        self.next_checkpoint = self.iteration + self.checkpoint_interval;
+    }
+
+    fn sort_data(&mut self) {
+        if self.data_sorted {
+            return;
+        }
+        // Only the data not yet processed is re-ordered
+        let mut remaining = self.data.split_off(self.index);
+        remaining.sort_by_cached_key(|data| self.delivery_ts(data));
+        self.data.extend(remaining);
+
+        let clock_skews = &self.clock_skews;
+        self.streams.rekey(|data| delivery_ts(clock_skews, data));
+        self.data_sorted = true;
+    }
+
+    /// Returns the next data delivered by `end` from memory or the data sources, preferring
+    /// the in-memory data when delivered at the same time.
+    fn next_data(&mut self, end: Option<UnixNanos>) -> Option<Data> {
+        let data_ts = self.data.get(self.index).map(|data| self.delivery_ts(data));
+        let stream_ts = self.streams.peek_ts();
+        let (ts, from_stream) = match (data_ts, stream_ts) {
+            (Some(data_ts), Some(stream_ts)) if stream_ts < data_ts => (stream_ts, true),
+            (Some(data_ts), _) => (data_ts, false),
+            (None, Some(stream_ts)) => (stream_ts, true),
+            (None, None) => return None,
+        };
+        if end.is_some_and(|end| ts > end) {
+            return None;
+        }
+
+        if from_stream {
+            let clock_skews = &self.clock_skews;
+            self.streamed_count += 1;
+            self.streams.next(|data| delivery_ts(clock_skews, data))
+        } else {
+            self.index += 1;
+            Some(self.data[self.index - 1].clone())
+        }
+    }
+}
+
+impl Default for BacktestEngine {
+    /// Creates a new default [`BacktestEngine`] instance.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn delivery_ts(clock_skews: &HashMap<Venue, i64>, data: &Data) -> UnixNanos {
+    let ts_init = data.ts_init().as_u64();
+    let skew_ns = clock_skews
+        .get(&data.instrument_id().venue)
+        .copied()
+        .unwrap_or_default();
+    UnixNanos::from(ts_init.saturating_add_signed(skew_ns))
+}
+
+fn process_data(exchange: &mut SimulatedExchange, data: Data) {
+    match data {
+        Data::Delta(delta) => exchange.process_order_book_delta(delta),
+        Data::Deltas(deltas) => exchange.process_order_book_deltas((*deltas).clone()),
+        Data::Quote(quote) => exchange.process_quote_tick(&quote),
+        Data::Trade(trade) => exchange.process_trade_tick(&trade),
+        Data::Bar(bar) => exchange.process_bar(bar),
+        Data::Depth10(depth) => {
+            log::warn!("Order book depth not supported by simulated exchange: {depth:?}");
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use nautilus_common::timer::{TimeEvent, TimeEventCallback};
-    use nautilus_core::UUID4;
+    use std::{cell::RefCell, rc::Rc, sync::LazyLock};
+
+    use nautilus_common::{
+        cache::Cache,
+        msgbus::MessageBus,
+        timer::{TimeEvent, TimeEventCallback},
+    };
+    use nautilus_core::{AtomicTime, UUID4};
+    use nautilus_execution::{
+        client::ExecutionClient,
+        messages::{cancel::CancelOrder, submit::SubmitOrder, TradingCommand},
+        models::{
+            fee::{FeeModelAny, MakerTakerFeeModel},
+            fill::FillModel,
+            latency::LatencyModel,
+        },
+    };
+    use nautilus_model::{
+        data::QuoteTick,
+        enums::{AccountType, BookType, OmsType, OrderSide, OrderType},
+        identifiers::{
+            AccountId, ClientId, ClientOrderId, InstrumentId, StrategyId, TraderId, VenueOrderId,
+        },
+        instruments::{
+            stubs::{crypto_perpetual_ethusdt, ethusdt_bitmex},
+            CryptoPerpetual, InstrumentAny,
+        },
+        orders::builder::OrderTestBuilder,
+        types::{Currency, Money, Price, Quantity},
+    };
     use pyo3::{prelude::*, types::PyList, Py, Python};
     use rstest::*;
+    use tempfile::TempDir;
     use ustr::Ustr;
 
     use super::*;
+    use crate::{
+        checkpoint::{checkpoint_path, find_latest_checkpoint},
+        data_source::ChunkedDataSource,
+    };
+
+    static ATOMIC_TIME: LazyLock<AtomicTime> =
+        LazyLock::new(|| AtomicTime::new(false, UnixNanos::default()));
+
+    fn get_exchange(instrument: CryptoPerpetual, latency_model: LatencyModel) -> SimulatedExchange {
+        get_exchange_with_clock(instrument, latency_model, &ATOMIC_TIME)
+    }
+
+    fn get_exchange_with_clock(
+        instrument: CryptoPerpetual,
+        latency_model: LatencyModel,
+        clock: &'static AtomicTime,
+    ) -> SimulatedExchange {
+        let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let venue = instrument.id.venue;
+
+        let mut exchange = SimulatedExchange::new(
+            venue,
+            OmsType::Netting,
+            AccountType::Margin,
+            vec![Money::new(1000.0, Currency::USD())],
+            None,
+            1.into(),
+            HashMap::new(),
+            vec![],
+            msgbus.clone(),
+            cache.clone(),
+            clock,
+            FillModel::default(),
+            FeeModelAny::MakerTaker(MakerTakerFeeModel),
+            latency_model,
+            BookType::L1_MBP,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let execution_client = ExecutionClient::new(
+            TraderId::default(),
+            ClientId::default(),
+            venue,
+            OmsType::Netting,
+            AccountId::default(),
+            AccountType::Margin,
+            None,
+            clock,
+            cache,
+            msgbus,
+        );
+        exchange.register_client(execution_client);
+        exchange
+            .add_instrument(InstrumentAny::CryptoPerpetual(instrument))
+            .unwrap();
+
+        exchange
+    }
+
+    fn quote(instrument_id: InstrumentId, bid: &str, ts: u64) -> Data {
+        Data::Quote(QuoteTick::new(
+            instrument_id,
+            Price::from(bid),
+            Price::from(bid),
+            Quantity::from("1.000"),
+            Quantity::from("1.000"),
+            ts.into(),
+            ts.into(),
+        ))
+    }
+
+    fn get_engine() -> BacktestEngine {
+        let mut engine = BacktestEngine::new();
+        engine
+            .add_venue(get_exchange(
+                crypto_perpetual_ethusdt(),
+                LatencyModel::fixed(100),
+            ))
+            .unwrap();
+        engine
+            .add_venue(get_exchange(ethusdt_bitmex(), LatencyModel::fixed(300)))
+            .unwrap();
+        engine
+    }
 
     #[rstest]
     fn test_accumulator_drain_sorted() {
@@ -119,4 +759,375 @@ mod tests {
             assert_eq!(drained_handlers[2].event.ts_event, time_event2.ts_event);
         });
     }
+
+    #[rstest]
+    fn test_add_venue_twice_fails() {
+        let mut engine = get_engine();
+
+        let result = engine.add_venue(get_exchange(
+            crypto_perpetual_ethusdt(),
+            LatencyModel::default(),
+        ));
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_set_clock_skew_for_unknown_venue_fails() {
+        let mut engine = get_engine();
+
+        assert!(engine.set_clock_skew(Venue::new("SIM"), 100).is_err());
+    }
+
+    #[rstest]
+    fn test_add_data_for_unknown_venue_fails() {
+        let mut engine = BacktestEngine::new();
+
+        let result = engine.add_data(vec![quote(crypto_perpetual_ethusdt().id, "1000.00", 0)]);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_add_corporate_actions_for_unknown_venue_fails() {
+        let mut engine = BacktestEngine::new();
+        let instrument_id = crypto_perpetual_ethusdt().id;
+        let action = CorporateAction::split(instrument_id, 2, 1, 0.into(), 0.into()).unwrap();
+
+        let result = engine.add_corporate_actions(vec![action]);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_run_processes_corporate_actions_on_effective_date() {
+        let mut engine = get_engine();
+        let instrument_id = crypto_perpetual_ethusdt().id;
+        let new_instrument_id = InstrumentId::from("ETHUSD-PERP.BINANCE");
+        engine
+            .add_data(vec![
+                quote(instrument_id, "1000.00", 1),
+                quote(instrument_id, "1000.00", 2),
+                quote(new_instrument_id, "1000.00", 3),
+            ])
+            .unwrap();
+        engine
+            .add_corporate_actions(vec![CorporateAction::symbol_change(
+                instrument_id,
+                new_instrument_id,
+                2.into(),
+                2.into(),
+            )
+            .unwrap()])
+            .unwrap();
+
+        engine.run(Some(1.into()));
+        let exchange = engine.get_exchange(instrument_id.venue).unwrap();
+        assert!(exchange.get_matching_engine(new_instrument_id).is_none());
+
+        engine.run(None);
+        let exchange = engine.get_exchange(instrument_id.venue).unwrap();
+        assert_eq!(
+            exchange.best_bid_price(new_instrument_id),
+            Some(Price::from("1000.00"))
+        );
+        assert_eq!(engine.checkpoint().unwrap().corporate_action_index, 1);
+    }
+
+    #[rstest]
+    #[case(0, 1, None)]
+    #[case(-60, 2, Some("2000.00"))]
+    fn test_run_delivers_data_by_skewed_time(
+        #[case] skew_ns: i64,
+        #[case] expected_iteration: u64,
+        #[case] expected_bitmex_bid: Option<&str>,
+    ) {
+        let binance_id = crypto_perpetual_ethusdt().id;
+        let bitmex_id = ethusdt_bitmex().id;
+        let mut engine = get_engine();
+        engine.set_clock_skew(bitmex_id.venue, skew_ns).unwrap();
+        engine
+            .add_data(vec![
+                quote(binance_id, "1000.00", 50),
+                quote(bitmex_id, "2000.00", 100),
+            ])
+            .unwrap();
+
+        engine.run(Some(UnixNanos::from(75)));
+
+        let bitmex_bid = engine
+            .get_exchange(bitmex_id.venue)
+            .unwrap()
+            .best_bid_price(bitmex_id);
+        assert_eq!(engine.iteration(), expected_iteration);
+        assert_eq!(bitmex_bid, expected_bitmex_bid.map(Price::from));
+    }
+
+    #[rstest]
+    fn test_run_processes_commands_with_venue_latency() {
+        let binance_id = crypto_perpetual_ethusdt().id;
+        let bitmex_id = ethusdt_bitmex().id;
+        let mut engine = get_engine();
+        engine
+            .add_data(vec![
+                quote(binance_id, "1000.00", 100),
+                quote(bitmex_id, "1000.00", 200),
+                quote(bitmex_id, "1000.00", 300),
+            ])
+            .unwrap();
+        for instrument_id in [binance_id, bitmex_id] {
+            let command = TradingCommand::CancelOrder(
+                CancelOrder::new(
+                    TraderId::default(),
+                    ClientId::default(),
+                    StrategyId::default(),
+                    instrument_id,
+                    ClientOrderId::from("O-1"),
+                    VenueOrderId::default(),
+                    UUID4::new(),
+                    UnixNanos::default(),
+                )
+                .unwrap(),
+            );
+            engine
+                .get_exchange_mut(instrument_id.venue)
+                .unwrap()
+                .send(command);
+        }
+
+        engine.run(Some(UnixNanos::from(200)));
+
+        let inflight_count = |venue| engine.get_exchange(venue).unwrap().inflight_count();
+        assert_eq!(inflight_count(binance_id.venue), 0);
+        assert_eq!(inflight_count(bitmex_id.venue), 1);
+
+        engine.run(None);
+
+        assert_eq!(engine.iteration(), 3);
+        assert_eq!(
+            engine
+                .get_exchange(bitmex_id.venue)
+                .unwrap()
+                .inflight_count(),
+            0
+        );
+    }
+
+    /// Returns the audit of a run cancelling an unknown order at each venue, with the command
+    /// latency of the Binance venue.
+    fn run_audited(binance_latency_ns: u64) -> DeterminismAudit {
+        static CLOCK: LazyLock<AtomicTime> =
+            LazyLock::new(|| AtomicTime::new(false, UnixNanos::default()));
+        let binance_id = crypto_perpetual_ethusdt().id;
+        let bitmex_id = ethusdt_bitmex().id;
+        let mut engine = BacktestEngine::new();
+        engine
+            .add_venue(get_exchange_with_clock(
+                crypto_perpetual_ethusdt(),
+                LatencyModel::fixed(binance_latency_ns),
+                &CLOCK,
+            ))
+            .unwrap();
+        engine
+            .add_venue(get_exchange_with_clock(
+                ethusdt_bitmex(),
+                LatencyModel::fixed(100),
+                &CLOCK,
+            ))
+            .unwrap();
+        engine
+            .add_data(vec![
+                quote(binance_id, "1000.00", 100),
+                quote(bitmex_id, "1000.00", 200),
+                quote(bitmex_id, "1000.00", 300),
+            ])
+            .unwrap();
+        let audit = engine.enable_audit();
+        for instrument_id in [binance_id, bitmex_id] {
+            let command = TradingCommand::CancelOrder(
+                CancelOrder::new(
+                    TraderId::default(),
+                    ClientId::default(),
+                    StrategyId::default(),
+                    instrument_id,
+                    ClientOrderId::from("O-1"),
+                    VenueOrderId::default(),
+                    UUID4::new(),
+                    UnixNanos::default(),
+                )
+                .unwrap(),
+            );
+            engine
+                .get_exchange_mut(instrument_id.venue)
+                .unwrap()
+                .send(command);
+        }
+
+        engine.run(None);
+
+        let audit = audit.borrow().clone();
+        audit
+    }
+
+    #[rstest]
+    fn test_audit_of_identical_runs_matches() {
+        let reference = run_audited(100);
+
+        let audit = run_audited(100);
+
+        assert_eq!(audit.len(), 2);
+        assert_eq!(audit.hash(), reference.hash());
+        assert_eq!(audit.compare(&reference), None);
+    }
+
+    #[rstest]
+    fn test_audit_reports_first_divergent_event() {
+        let reference = run_audited(100);
+
+        // The Binance cancel is rejected at a later time
+        let audit = run_audited(200);
+
+        let divergence = audit.compare(&reference).unwrap();
+        assert_eq!(divergence.sequence, 0);
+        assert_ne!(divergence.expected, divergence.actual);
+    }
+
+    #[rstest]
+    fn test_run_merges_data_sources_with_in_memory_data() {
+        let binance_id = crypto_perpetual_ethusdt().id;
+        let bitmex_id = ethusdt_bitmex().id;
+        let mut engine = get_engine();
+        engine
+            .add_data(vec![quote(binance_id, "1000.00", 200)])
+            .unwrap();
+        engine.add_data_source(ChunkedDataSource::new({
+            let mut chunks = vec![
+                vec![quote(bitmex_id, "2002.00", 300)],
+                vec![
+                    quote(bitmex_id, "2000.00", 100),
+                    quote(bitmex_id, "2001.00", 250),
+                ],
+            ];
+            move || chunks.pop()
+        }));
+
+        engine.run(Some(UnixNanos::from(100)));
+
+        let bitmex = engine.get_exchange(bitmex_id.venue).unwrap();
+        assert_eq!(engine.iteration(), 1);
+        assert_eq!(
+            bitmex.best_bid_price(bitmex_id),
+            Some(Price::from("2000.00"))
+        );
+
+        engine.run(Some(UnixNanos::from(250)));
+
+        let bitmex = engine.get_exchange(bitmex_id.venue).unwrap();
+        let binance = engine.get_exchange(binance_id.venue).unwrap();
+        assert_eq!(engine.iteration(), 3);
+        assert_eq!(
+            bitmex.best_bid_price(bitmex_id),
+            Some(Price::from("2001.00"))
+        );
+        assert_eq!(
+            binance.best_bid_price(binance_id),
+            Some(Price::from("1000.00"))
+        );
+
+        engine.run(None);
+
+        let bitmex = engine.get_exchange(bitmex_id.venue).unwrap();
+        assert_eq!(engine.iteration(), 4);
+        assert_eq!(
+            bitmex.best_bid_price(bitmex_id),
+            Some(Price::from("2002.00"))
+        );
+    }
+
+    #[rstest]
+    fn test_resume_from_checkpoint_matches_uninterrupted_run() {
+        let binance_id = crypto_perpetual_ethusdt().id;
+        let bitmex_id = ethusdt_bitmex().id;
+        let get_engine_with_data = || {
+            let mut engine = get_engine();
+            engine
+                .add_data(vec![
+                    quote(binance_id, "1010.00", 100),
+                    quote(bitmex_id, "2000.00", 150),
+                    quote(binance_id, "1005.00", 200),
+                    quote(binance_id, "1000.00", 300),
+                ])
+                .unwrap();
+            engine
+        };
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(binance_id)
+            .side(OrderSide::Buy)
+            .price(Price::from("1000.00"))
+            .quantity(Quantity::from("1.000"))
+            .build();
+        let command = TradingCommand::SubmitOrder(
+            SubmitOrder::new(
+                TraderId::default(),
+                ClientId::default(),
+                StrategyId::default(),
+                binance_id,
+                order.client_order_id(),
+                VenueOrderId::default(),
+                order,
+                None,
+                None,
+                UUID4::new(),
+                UnixNanos::default(),
+            )
+            .unwrap(),
+        );
+        let dir = TempDir::new().unwrap();
+
+        let mut engine = get_engine_with_data();
+        engine.set_checkpointing(dir.path(), 2).unwrap();
+        engine
+            .get_exchange_mut(binance_id.venue)
+            .unwrap()
+            .send(command);
+        engine.run(None);
+
+        // The order rests at the checkpoint taken mid-run and fills after it
+        let checkpoint = BacktestCheckpoint::load(&checkpoint_path(dir.path(), 2)).unwrap();
+        let binance = &checkpoint.venues[0];
+        assert_eq!(binance.venue, binance_id.venue);
+        assert_eq!(binance.matching_engines[0].orders.len(), 1);
+        assert_eq!(
+            find_latest_checkpoint(dir.path()).unwrap(),
+            Some(checkpoint_path(dir.path(), 4))
+        );
+
+        let mut resumed = get_engine_with_data();
+        resumed.restore(&checkpoint).unwrap();
+        resumed.run(None);
+
+        let to_json =
+            |engine: &BacktestEngine| serde_json::to_value(engine.checkpoint().unwrap()).unwrap();
+        assert_eq!(resumed.iteration(), 4);
+        assert!(resumed
+            .get_exchange(binance_id.venue)
+            .unwrap()
+            .get_matching_engine(binance_id)
+            .unwrap()
+            .get_open_orders()
+            .is_empty());
+        assert_eq!(to_json(&resumed), to_json(&engine));
+    }
+
+    #[rstest]
+    fn test_restore_checkpoint_after_running_fails() {
+        let mut engine = get_engine();
+        engine
+            .add_data(vec![quote(crypto_perpetual_ethusdt().id, "1000.00", 100)])
+            .unwrap();
+        let checkpoint = engine.checkpoint().unwrap();
+        engine.run(None);
+
+        assert!(engine.restore(&checkpoint).is_err());
+    }
 }
@@ -19,35 +19,101 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use std::{
+    cell::RefCell,
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    rc::Rc,
+};
 
-use nautilus_common::{cache::Cache, msgbus::MessageBus};
+use nautilus_common::{cache::Cache, msgbus::MessageBus, throttler::RateLimit};
 use nautilus_core::{
-    correctness::{check_equal, FAILED},
-    AtomicTime, UnixNanos,
+    correctness::{check_equal, check_in_range_inclusive_f64, FAILED},
+    datetime::NANOSECONDS_IN_SECOND,
+    AtomicTime, UnixNanos, UUID4,
 };
 use nautilus_execution::{
     client::ExecutionClient,
-    matching_engine::{config::OrderMatchingEngineConfig, engine::OrderMatchingEngine},
-    messages::TradingCommand,
-    models::{fee::FeeModelAny, fill::FillModel, latency::LatencyModel},
+    matching_engine::{
+        config::OrderMatchingEngineConfig, engine::OrderMatchingEngine,
+        snapshot::MatchingEngineSnapshot,
+    },
+    messages::{CancelAllOrders, TradingCommand},
+    models::{
+        fee::{FeeModel, FeeModelAny},
+        fill::FillModel,
+        latency::LatencyModel,
+        slippage::SlippageModel,
+    },
 };
 use nautilus_model::{
     accounts::AccountAny,
     data::{
-        Bar, Data, InstrumentStatus, OrderBookDelta, OrderBookDeltas, OrderBookDeltas_API,
-        QuoteTick, TradeTick,
+        Bar, CorporateAction, Data, InstrumentStatus, OrderBookDelta, OrderBookDeltas,
+        OrderBookDeltas_API, QuoteTick, TradeTick,
+    },
+    enums::{
+        AccountType, BarAggregation, BookType, CorporateActionType, LiquiditySide,
+        MarketStatusAction, OmsType, OptionKind, OrderSide, OrderType,
     },
-    enums::{AccountType, BookType, OmsType},
-    identifiers::{InstrumentId, Venue},
+    events::OrderFilled,
+    identifiers::{ClientOrderId, InstrumentId, PositionId, TradeId, Venue, VenueOrderId},
     instruments::InstrumentAny,
     orderbook::OrderBook,
-    orders::PassiveOrderAny,
-    types::{AccountBalance, Currency, Money, Price},
+    orders::{spread::implied_spread_quote, OrderAny, PassiveOrderAny, SpreadLeg},
+    position::Position,
+    types::{AccountBalance, Currency, Money, Price, Quantity},
 };
 use rust_decimal::{prelude::ToPrimitive, Decimal};
+use ustr::Ustr;
+
+use crate::{
+    book_validation::{get_book_diagnostics_topic, BookDiagnostic, BookValidator},
+    calendar::{OutOfSessionPolicy, SessionPhase, TradingCalendar},
+    checkpoint::ExchangeCheckpoint,
+    circuit_breaker::CircuitBreaker,
+    expiry::{get_expiry_topic, intrinsic_value, InstrumentExpiry, SettlementType},
+    modules::SimulationModule,
+    throttle::{MessageThrottle, RateLimitAction},
+};
+
+const NANOSECONDS_IN_DAY: u64 = 86_400 * NANOSECONDS_IN_SECOND;
+
+/// A trading command in flight to the simulated exchange, ordered by the time it arrives
+/// at the venue and then by the order it was sent.
+#[derive(Clone, Debug)]
+pub struct InflightCommand {
+    /// UNIX timestamp (nanoseconds) when the command arrives at the venue.
+    pub ts_arrival: UnixNanos,
+    /// The sequence number of the command, to preserve send order for equal arrival times.
+    pub sequence: u64,
+    /// The trading command.
+    pub command: TradingCommand,
+}
+
+impl PartialEq for InflightCommand {
+    fn eq(&self, other: &Self) -> bool {
+        self.ts_arrival == other.ts_arrival && self.sequence == other.sequence
+    }
+}
+
+impl Eq for InflightCommand {}
+
+impl PartialOrd for InflightCommand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
 
-use crate::modules::SimulationModule;
+impl Ord for InflightCommand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed for a min-heap
+        other
+            .ts_arrival
+            .cmp(&self.ts_arrival)
+            .then_with(|| other.sequence.cmp(&self.sequence))
+    }
+}
 
 pub struct SimulatedExchange {
     id: Venue,
@@ -60,8 +126,30 @@ pub struct SimulatedExchange {
     fee_model: FeeModelAny,
     fill_model: FillModel,
     latency_model: LatencyModel,
+    slippage_model: Option<SlippageModel>,
+    slippage_overrides: HashMap<InstrumentId, SlippageModel>,
+    inflight_queue: BinaryHeap<InflightCommand>,
+    inflight_sequence: u64,
+    next_funding_ts: Option<UnixNanos>,
+    next_day_close: Option<UnixNanos>,
+    calendar: Option<TradingCalendar>,
+    out_of_session_policy: OutOfSessionPolicy,
+    session_phase: Option<SessionPhase>,
+    session_queue: Vec<TradingCommand>,
+    circuit_breaker: Option<CircuitBreaker>,
+    circuit_breakers: HashMap<InstrumentId, CircuitBreaker>,
+    book_validator: Option<BookValidator>,
+    order_throttle: Option<MessageThrottle>,
+    message_throttle: Option<MessageThrottle>,
+    rate_limit_action: RateLimitAction,
+    throttled_queue: VecDeque<(UnixNanos, TradingCommand)>,
+    settlement_type: SettlementType,
+    settlement_prices: HashMap<InstrumentId, Price>,
+    expired_instruments: HashSet<InstrumentId>,
+    expiry_fill_count: u64,
     instruments: HashMap<InstrumentId, InstrumentAny>,
     matching_engines: HashMap<InstrumentId, OrderMatchingEngine>,
+    spreads: HashMap<InstrumentId, Vec<SpreadLeg>>,
     leverages: HashMap<InstrumentId, Decimal>,
     modules: Vec<Box<dyn SimulationModule>>,
     clock: &'static AtomicTime,
@@ -75,6 +163,8 @@ pub struct SimulatedExchange {
     use_position_ids: bool,
     use_random_ids: bool,
     use_reduce_only: bool,
+    support_iceberg_orders: bool,
+    max_participation_rate: Option<f64>,
     use_message_queue: bool,
 }
 
@@ -125,8 +215,30 @@ impl SimulatedExchange {
             fee_model,
             fill_model,
             latency_model,
+            inflight_queue: BinaryHeap::new(),
+            inflight_sequence: 0,
+            next_funding_ts: None,
+            next_day_close: None,
+            calendar: None,
+            out_of_session_policy: OutOfSessionPolicy::default(),
+            session_phase: None,
+            session_queue: Vec::new(),
+            circuit_breaker: None,
+            circuit_breakers: HashMap::new(),
+            book_validator: None,
+            order_throttle: None,
+            message_throttle: None,
+            rate_limit_action: RateLimitAction::default(),
+            throttled_queue: VecDeque::new(),
+            settlement_type: SettlementType::default(),
+            settlement_prices: HashMap::new(),
+            expired_instruments: HashSet::new(),
+            expiry_fill_count: 0,
+            slippage_model: None,
+            slippage_overrides: HashMap::new(),
             instruments: HashMap::new(),
             matching_engines: HashMap::new(),
+            spreads: HashMap::new(),
             leverages,
             modules,
             clock,
@@ -140,10 +252,24 @@ impl SimulatedExchange {
             use_position_ids: use_position_ids.unwrap_or(true),
             use_random_ids: use_random_ids.unwrap_or(false),
             use_reduce_only: use_reduce_only.unwrap_or(true),
+            support_iceberg_orders: false,
+            max_participation_rate: None,
             use_message_queue: use_message_queue.unwrap_or(true),
         })
     }
 
+    /// Returns the venue ID of the exchange.
+    #[must_use]
+    pub const fn id(&self) -> Venue {
+        self.id
+    }
+
+    /// Returns the message bus the exchange sends its events on.
+    #[must_use]
+    pub fn msgbus(&self) -> &Rc<RefCell<MessageBus>> {
+        &self.msgbus
+    }
+
     pub fn register_client(&mut self, client: ExecutionClient) {
         let client_id = client.client_id;
         self.exec_client = Some(client);
@@ -162,16 +288,241 @@ impl SimulatedExchange {
         self.fill_model = fill_model;
     }
 
+    pub fn set_fee_model(&mut self, fee_model: FeeModelAny) {
+        for matching_engine in self.matching_engines.values_mut() {
+            matching_engine.set_fee_model(fee_model.clone());
+        }
+        self.fee_model = fee_model;
+        self.next_funding_ts = None;
+        log::info!("Setting fee model to {:?}", self.fee_model);
+    }
+
     pub fn set_latency_model(&mut self, latency_model: LatencyModel) {
         self.latency_model = latency_model;
         log::info!("Setting latency model to {}", self.latency_model);
     }
 
+    /// Sets the slippage model for market order fills, for the given `instrument_id` only if
+    /// specified, otherwise as the default for all instruments without an override.
+    pub fn set_slippage_model(
+        &mut self,
+        slippage_model: SlippageModel,
+        instrument_id: Option<InstrumentId>,
+    ) {
+        match instrument_id {
+            Some(instrument_id) => {
+                log::info!("Setting slippage model for {instrument_id} to {slippage_model}");
+                if let Some(matching_engine) = self.matching_engines.get_mut(&instrument_id) {
+                    matching_engine.set_slippage_model(Some(slippage_model.clone()));
+                }
+                self.slippage_overrides
+                    .insert(instrument_id, slippage_model);
+            }
+            None => {
+                log::info!("Setting slippage model to {slippage_model}");
+                for (instrument_id, matching_engine) in &mut self.matching_engines {
+                    if !self.slippage_overrides.contains_key(instrument_id) {
+                        matching_engine.set_slippage_model(Some(slippage_model.clone()));
+                    }
+                }
+                self.slippage_model = Some(slippage_model);
+            }
+        }
+    }
+
+    /// Sets whether limit orders with a display quantity are matched as iceberg orders,
+    /// otherwise such orders are rejected.
+    pub fn set_support_iceberg_orders(&mut self, support_iceberg_orders: bool) {
+        for matching_engine in self.matching_engines.values_mut() {
+            matching_engine.config.support_iceberg_orders = support_iceberg_orders;
+        }
+        self.support_iceberg_orders = support_iceberg_orders;
+        log::info!("Setting support iceberg orders to {support_iceberg_orders}");
+    }
+
+    /// Sets the maximum fraction of the displayed liquidity at each price level an order may
+    /// take per iteration (`None` for no cap).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `max_participation_rate` is not in the range [0, 1].
+    pub fn set_max_participation_rate(
+        &mut self,
+        max_participation_rate: Option<f64>,
+    ) -> anyhow::Result<()> {
+        if let Some(rate) = max_participation_rate {
+            check_in_range_inclusive_f64(rate, 0.0, 1.0, "max_participation_rate")?;
+        }
+        for matching_engine in self.matching_engines.values_mut() {
+            matching_engine.config.max_participation_rate = max_participation_rate;
+        }
+        self.max_participation_rate = max_participation_rate;
+        log::info!("Setting max participation rate to {max_participation_rate:?}");
+        Ok(())
+    }
+
+    /// Sets the trading calendar of the venue, with orders submitted while it is closed
+    /// handled according to the `out_of_session_policy`.
+    ///
+    /// Orders submitted during an auction window are queued until the auction ends.
+    ///
+    /// DAY orders expire at the venue-local close of each trading day of the calendar, rather
+    /// than at UTC midnight.
+    pub fn set_trading_calendar(
+        &mut self,
+        calendar: TradingCalendar,
+        out_of_session_policy: OutOfSessionPolicy,
+    ) {
+        self.calendar = Some(calendar);
+        self.out_of_session_policy = out_of_session_policy;
+        self.session_phase = None;
+        self.next_day_close = None;
+        self.update_session(self.clock.get_time_ns());
+        log::info!("Setting trading calendar with {out_of_session_policy:?} out of session policy");
+    }
+
+    /// Sets the circuit breaker used to simulate trading halts for each instrument.
+    pub fn set_circuit_breaker(&mut self, circuit_breaker: CircuitBreaker) {
+        log::info!("Setting circuit breaker to {circuit_breaker:?}");
+        self.circuit_breaker = Some(circuit_breaker);
+        self.circuit_breakers.clear();
+    }
+
+    /// Sets whether order book deltas are validated as they are replayed.
+    ///
+    /// When enabled, each delta failing validation is logged and published as a
+    /// [`BookDiagnostic`] on the book diagnostics topic for its instrument.
+    pub fn set_book_validation(&mut self, enabled: bool) {
+        self.book_validator = enabled.then(BookValidator::new);
+        log::info!("Setting book validation to {enabled}");
+    }
+
+    /// Sets the rate limits of the venue for order submissions and for all trading messages,
+    /// with commands exceeding either limit handled according to the `action`.
+    ///
+    /// Each order of an order list counts towards the order limit, while the list counts as
+    /// a single message.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the limit or interval of a rate limit is zero.
+    pub fn set_rate_limits(
+        &mut self,
+        order_limit: Option<RateLimit>,
+        message_limit: Option<RateLimit>,
+        action: RateLimitAction,
+    ) -> anyhow::Result<()> {
+        log::info!(
+            "Setting rate limits to orders={order_limit:?}, messages={message_limit:?} with {action:?} action"
+        );
+        self.order_throttle = order_limit.map(MessageThrottle::new).transpose()?;
+        self.message_throttle = message_limit.map(MessageThrottle::new).transpose()?;
+        self.rate_limit_action = action;
+        Ok(())
+    }
+
+    /// Sets the method of settling positions in contracts at expiry.
+    pub fn set_settlement_type(&mut self, settlement_type: SettlementType) {
+        log::info!("Setting expiry settlement type to {settlement_type:?}");
+        self.settlement_type = settlement_type;
+    }
+
+    /// Sets the settlement `price` of the `instrument_id` at expiry, overriding its market price.
+    ///
+    /// For an option this is the price of the underlying, which otherwise is taken from the
+    /// market of the underlying instrument listed at the venue.
+    pub fn set_settlement_price(&mut self, instrument_id: InstrumentId, price: Price) {
+        log::info!("Setting settlement price for {instrument_id} to {price}");
+        self.settlement_prices.insert(instrument_id, price);
+    }
+
+    /// Returns whether the instrument has expired and been settled.
+    #[must_use]
+    pub fn is_expired(&self, instrument_id: InstrumentId) -> bool {
+        self.expired_instruments.contains(&instrument_id)
+    }
+
+    /// Returns the number of commands delayed by the rate limits of the venue.
+    #[must_use]
+    pub fn throttled_count(&self) -> usize {
+        self.throttled_queue.len()
+    }
+
+    /// Returns the number of book diagnostics raised, if book validation is enabled.
+    #[must_use]
+    pub fn book_diagnostic_count(&self) -> Option<usize> {
+        self.book_validator
+            .as_ref()
+            .map(BookValidator::diagnostic_count)
+    }
+
+    /// Returns the current trading session phase, if a trading calendar is set.
+    #[must_use]
+    pub const fn session_phase(&self) -> Option<SessionPhase> {
+        self.session_phase
+    }
+
+    /// Returns the number of commands queued until trading resumes.
+    #[must_use]
+    pub fn session_queue_count(&self) -> usize {
+        self.session_queue.len()
+    }
+
+    /// Returns whether trading in the instrument is halted by the circuit breaker.
+    #[must_use]
+    pub fn is_halted(&self, instrument_id: InstrumentId) -> bool {
+        self.circuit_breakers
+            .get(&instrument_id)
+            .is_some_and(CircuitBreaker::is_halted)
+    }
+
     pub fn initialize_account(&mut self) {
         self.generate_fresh_account_state();
     }
 
     pub fn add_instrument(&mut self, instrument: InstrumentAny) -> anyhow::Result<()> {
+        self.add_instrument_with_book_type(instrument, self.book_type)
+    }
+
+    /// Adds the spread `instrument` with the given `legs`, which is matched against the top of
+    /// book implied by the leg books whenever a leg book updates.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are less than two `legs`, or a leg is not for this venue.
+    pub fn add_spread(
+        &mut self,
+        instrument: InstrumentAny,
+        legs: Vec<SpreadLeg>,
+    ) -> anyhow::Result<()> {
+        if legs.len() < 2 {
+            anyhow::bail!(
+                "Invalid spread {}: {} legs, expected at least 2",
+                instrument.id(),
+                legs.len()
+            );
+        }
+        if let Some(leg) = legs.iter().find(|leg| leg.instrument_id.venue != self.id) {
+            anyhow::bail!(
+                "Invalid spread {}: leg {} not for venue {}",
+                instrument.id(),
+                leg.instrument_id,
+                self.id
+            );
+        }
+
+        let instrument_id = instrument.id();
+        // The implied book is top of book only
+        self.add_instrument_with_book_type(instrument, BookType::L1_MBP)?;
+        self.spreads.insert(instrument_id, legs);
+        Ok(())
+    }
+
+    fn add_instrument_with_book_type(
+        &mut self,
+        instrument: InstrumentAny,
+        book_type: BookType,
+    ) -> anyhow::Result<()> {
         check_equal(
             instrument.id().venue,
             self.id,
@@ -197,14 +548,16 @@ impl SimulatedExchange {
             self.use_position_ids,
             self.use_random_ids,
             self.use_reduce_only,
+            self.support_iceberg_orders,
+            self.max_participation_rate,
         );
         let instrument_id = instrument.id();
-        let matching_engine = OrderMatchingEngine::new(
+        let mut matching_engine = OrderMatchingEngine::new(
             instrument,
             self.instruments.len() as u32,
             self.fill_model.clone(),
             self.fee_model.clone(),
-            self.book_type,
+            book_type,
             self.oms_type,
             self.account_type,
             self.clock,
@@ -212,7 +565,16 @@ impl SimulatedExchange {
             Rc::clone(&self.cache),
             matching_engine_config,
         );
+        matching_engine.set_slippage_model(
+            self.slippage_overrides
+                .get(&instrument_id)
+                .or(self.slippage_model.as_ref())
+                .cloned(),
+        );
         self.matching_engines.insert(instrument_id, matching_engine);
+        if !self.is_session_open() {
+            self.update_market_status(instrument_id, MarketStatusAction::Close);
+        }
 
         log::info!("Added instrument {instrument_id} and created matching engine");
         Ok(())
@@ -357,12 +719,169 @@ impl SimulatedExchange {
         }
     }
 
-    pub fn send(&self, _command: TradingCommand) {
-        todo!("send")
+    /// Sends the trading `command` to the exchange.
+    ///
+    /// When using the message queue, the command is processed once it arrives at the venue
+    /// after the simulated latency, otherwise it is processed immediately.
+    pub fn send(&mut self, command: TradingCommand) {
+        if self.use_message_queue {
+            let inflight = self.generate_inflight_command(command);
+            self.inflight_queue.push(inflight);
+        } else {
+            self.process_trading_command(command);
+        }
+    }
+
+    /// Generates an in-flight command arriving at the venue after the latency sampled for
+    /// the command type.
+    pub fn generate_inflight_command(&mut self, command: TradingCommand) -> InflightCommand {
+        let latency_ns = match command {
+            TradingCommand::SubmitOrder(_) | TradingCommand::SubmitOrderList(_) => {
+                self.latency_model.insert_latency_ns()
+            }
+            TradingCommand::ModifyOrder(_)
+            | TradingCommand::BatchModifyOrders(_)
+            | TradingCommand::QueryOrder(_) => self.latency_model.update_latency_ns(),
+            TradingCommand::CancelOrder(_)
+            | TradingCommand::CancelAllOrders(_)
+            | TradingCommand::BatchCancelOrders(_) => self.latency_model.cancel_latency_ns(),
+        };
+        self.inflight_sequence += 1;
+
+        InflightCommand {
+            ts_arrival: command.ts_init() + latency_ns,
+            sequence: self.inflight_sequence,
+            command,
+        }
+    }
+
+    /// Returns the number of commands in flight to the venue.
+    #[must_use]
+    pub fn inflight_count(&self) -> usize {
+        self.inflight_queue.len()
+    }
+
+    /// Returns whether any commands are in flight to the venue or queued until trading resumes.
+    #[must_use]
+    pub fn has_pending_commands(&self) -> bool {
+        !self.inflight_queue.is_empty()
+            || !self.session_queue.is_empty()
+            || !self.throttled_queue.is_empty()
+    }
+
+    /// Returns a checkpoint of the state of the exchange.
+    ///
+    /// Pending commands are not captured, so a checkpoint should only be taken when there are
+    /// none. Circuit breaker and book validation state starts afresh when restored.
+    #[must_use]
+    pub fn checkpoint(&self) -> ExchangeCheckpoint {
+        let mut matching_engines: Vec<MatchingEngineSnapshot> = self
+            .matching_engines
+            .values()
+            .map(OrderMatchingEngine::snapshot)
+            .collect();
+        matching_engines.sort_by_key(|snapshot| snapshot.instrument_id);
+
+        let cache = self.cache.borrow();
+        let mut orders: Vec<OrderAny> = cache
+            .orders(Some(&self.id), None, None, None)
+            .into_iter()
+            .cloned()
+            .collect();
+        orders.sort_by_key(OrderAny::client_order_id);
+        let mut positions: Vec<Position> = cache
+            .positions(Some(&self.id), None, None, None)
+            .into_iter()
+            .cloned()
+            .collect();
+        positions.sort_by_key(|position| position.id);
+        let mut expired_instruments: Vec<InstrumentId> =
+            self.expired_instruments.iter().copied().collect();
+        expired_instruments.sort();
+
+        ExchangeCheckpoint {
+            venue: self.id,
+            ts: self.clock.get_time_ns(),
+            matching_engines,
+            orders,
+            positions,
+            account: cache.account_for_venue(&self.id).cloned(),
+            next_funding_ts: self.next_funding_ts,
+            expired_instruments,
+            expiry_fill_count: self.expiry_fill_count,
+        }
     }
 
-    pub fn generate_inflight_command(&self, _command: TradingCommand) {
-        todo!("generate inflight command")
+    /// Restores the state of the exchange from the `checkpoint`, setting the clock to the
+    /// time it was taken.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - The checkpoint is for a different venue.
+    /// - The checkpoint is for an instrument which has not been added.
+    /// - The orders, positions or account cannot be added to the cache.
+    pub fn restore(&mut self, checkpoint: &ExchangeCheckpoint) -> anyhow::Result<()> {
+        if checkpoint.venue != self.id {
+            anyhow::bail!(
+                "Checkpoint for venue {} restored to {}",
+                checkpoint.venue,
+                self.id
+            )
+        }
+        if let Some(snapshot) = checkpoint
+            .matching_engines
+            .iter()
+            .find(|snapshot| !self.matching_engines.contains_key(&snapshot.instrument_id))
+        {
+            anyhow::bail!("Instrument {} not added", snapshot.instrument_id)
+        }
+
+        self.clock.set_time(checkpoint.ts);
+        for snapshot in &checkpoint.matching_engines {
+            if let Some(matching_engine) = self.matching_engines.get_mut(&snapshot.instrument_id) {
+                matching_engine.restore(snapshot);
+            }
+        }
+
+        {
+            let mut cache = self.cache.borrow_mut();
+            for order in &checkpoint.orders {
+                cache.add_order(order.clone(), order.position_id(), None, true)?;
+            }
+            for position in &checkpoint.positions {
+                // Adding replaces any cached position, then updating indexes it as closed
+                cache.add_position(position.clone(), self.oms_type)?;
+                cache.update_position(position)?;
+            }
+            if let Some(account) = &checkpoint.account {
+                if cache.account(&account.id()).is_some() {
+                    cache.update_account(account.clone())?;
+                } else {
+                    cache.add_account(account.clone())?;
+                }
+            }
+        }
+
+        self.inflight_queue.clear();
+        self.throttled_queue.clear();
+        self.reset_throttles();
+        self.next_funding_ts = checkpoint.next_funding_ts;
+        self.expired_instruments = checkpoint.expired_instruments.iter().copied().collect();
+        self.expiry_fill_count = checkpoint.expiry_fill_count;
+        self.session_phase = self
+            .calendar
+            .as_ref()
+            .map(|calendar| calendar.phase(checkpoint.ts));
+        self.next_day_close = self.day_close(checkpoint.ts);
+        self.session_queue.clear();
+        self.circuit_breakers.clear();
+        if let Some(validator) = &mut self.book_validator {
+            validator.reset();
+        }
+
+        log::info!("Restored exchange state at {}", checkpoint.ts);
+        Ok(())
     }
 
     pub fn process_order_book_delta(&mut self, delta: OrderBookDelta) {
@@ -386,11 +905,19 @@ impl SimulatedExchange {
             }
         }
 
+        if let Some(validator) = &mut self.book_validator {
+            let diagnostics = validator.check_delta(&delta);
+            self.publish_book_diagnostics(diagnostics);
+        }
+
         if let Some(matching_engine) = self.matching_engines.get_mut(&delta.instrument_id) {
             matching_engine.process_order_book_delta(&delta);
+            self.check_book_integrity(&delta);
         } else {
             panic!("Matching engine should be initialized");
         }
+
+        self.update_implied_quotes(delta.instrument_id, delta.ts_event);
     }
 
     pub fn process_order_book_deltas(&mut self, deltas: OrderBookDeltas) {
@@ -414,11 +941,46 @@ impl SimulatedExchange {
             }
         }
 
+        if let Some(validator) = &mut self.book_validator {
+            let diagnostics = deltas
+                .deltas
+                .iter()
+                .flat_map(|delta| validator.check_delta(delta))
+                .collect();
+            self.publish_book_diagnostics(diagnostics);
+        }
+
         if let Some(matching_engine) = self.matching_engines.get_mut(&deltas.instrument_id) {
             matching_engine.process_order_book_deltas(&deltas);
+            if let Some(last_delta) = deltas.deltas.last() {
+                self.check_book_integrity(last_delta);
+            }
         } else {
             panic!("Matching engine should be initialized");
         }
+
+        self.update_implied_quotes(deltas.instrument_id, deltas.ts_event);
+    }
+
+    fn check_book_integrity(&mut self, delta: &OrderBookDelta) {
+        let (Some(validator), Some(matching_engine)) = (
+            &mut self.book_validator,
+            self.matching_engines.get(&delta.instrument_id),
+        ) else {
+            return;
+        };
+        if let Some(diagnostic) = validator.check_book(matching_engine.get_book(), delta) {
+            self.publish_book_diagnostics(vec![diagnostic]);
+        }
+    }
+
+    fn publish_book_diagnostics(&self, diagnostics: Vec<BookDiagnostic>) {
+        let msgbus = self.msgbus.borrow();
+        for diagnostic in diagnostics {
+            log::warn!("Book validation failed: {diagnostic}");
+            let topic = get_book_diagnostics_topic(diagnostic.delta.instrument_id);
+            msgbus.publish(&topic, &diagnostic);
+        }
     }
 
     pub fn process_quote_tick(&mut self, quote: &QuoteTick) {
@@ -447,6 +1009,60 @@ impl SimulatedExchange {
         } else {
             panic!("Matching engine should be initialized");
         }
+
+        self.update_implied_quotes(quote.instrument_id, quote.ts_event);
+    }
+
+    /// Processes the quotes implied by the leg books for each spread with the given leg.
+    fn update_implied_quotes(&mut self, leg_instrument_id: InstrumentId, ts_init: UnixNanos) {
+        let implied_quotes: Vec<QuoteTick> = self
+            .spreads
+            .iter()
+            .filter(|(_, legs)| {
+                legs.iter()
+                    .any(|leg| leg.instrument_id == leg_instrument_id)
+            })
+            .filter_map(|(spread_id, legs)| self.implied_quote(*spread_id, legs, ts_init))
+            .collect();
+
+        for quote in implied_quotes {
+            if let Some(matching_engine) = self.matching_engines.get_mut(&quote.instrument_id) {
+                matching_engine.process_quote_tick(&quote);
+            }
+        }
+    }
+
+    fn implied_quote(
+        &self,
+        spread_id: InstrumentId,
+        legs: &[SpreadLeg],
+        ts_init: UnixNanos,
+    ) -> Option<QuoteTick> {
+        let leg_quotes = legs
+            .iter()
+            .map(|leg| {
+                let book = self.get_book(leg.instrument_id)?;
+                Some(QuoteTick::new(
+                    leg.instrument_id,
+                    book.best_bid_price()?,
+                    book.best_ask_price()?,
+                    book.best_bid_size()?,
+                    book.best_ask_size()?,
+                    book.ts_last,
+                    ts_init,
+                ))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let instrument = self.instruments.get(&spread_id)?;
+
+        implied_spread_quote(
+            spread_id,
+            legs,
+            &leg_quotes,
+            instrument.price_precision(),
+            instrument.size_precision(),
+            ts_init,
+        )
     }
 
     pub fn process_trade_tick(&mut self, trade: &TradeTick) {
@@ -470,6 +1086,8 @@ impl SimulatedExchange {
             }
         }
 
+        self.update_circuit_breaker(trade.instrument_id, trade.ts_event, trade.price);
+
         if let Some(matching_engine) = self.matching_engines.get_mut(&trade.instrument_id) {
             matching_engine.process_trade_tick(trade);
         } else {
@@ -498,6 +1116,13 @@ impl SimulatedExchange {
             }
         }
 
+        self.update_circuit_breaker(bar.instrument_id(), bar.ts_event, bar.close);
+
+        if !self.is_bar_in_session(&bar) {
+            log::debug!("Ignoring bar outside trading session for execution: {bar}");
+            return;
+        }
+
         if let Some(matching_engine) = self.matching_engines.get_mut(&bar.instrument_id()) {
             matching_engine.process_bar(&bar);
         } else {
@@ -531,129 +1156,950 @@ impl SimulatedExchange {
         }
     }
 
-    pub fn process(&mut self, _ts_now: UnixNanos) {
-        todo!("process")
+    /// Processes the corporate `action` for an instrument of the venue on its effective date.
+    ///
+    /// - Splits scale the working orders and open positions of the instrument by the split
+    ///   ratio, and clear its book until data quoted after the split arrives.
+    /// - Dividends credit the account with the amount per share held, debiting it for short
+    ///   positions.
+    /// - Symbol changes list the instrument under its new ID and cancel the working orders
+    ///   for the old ID, while open positions remain under the old ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The instrument is not listed on the venue.
+    /// - The instrument under the new ID of a symbol change cannot be added.
+    pub fn process_corporate_action(&mut self, action: &CorporateAction) -> anyhow::Result<()> {
+        let Some(matching_engine) = self.matching_engines.get_mut(&action.instrument_id) else {
+            anyhow::bail!(
+                "No matching engine found for instrument {}",
+                action.instrument_id
+            )
+        };
+        log::info!("Processing corporate action {action}");
+
+        match action.action_type {
+            CorporateActionType::Split => {
+                matching_engine.process_split(action.split_to, action.split_from);
+
+                let mut cache = self.cache.borrow_mut();
+                let positions: Vec<Position> = cache
+                    .positions_open(None, Some(&action.instrument_id), None, None)
+                    .into_iter()
+                    .cloned()
+                    .collect();
+                for mut position in positions {
+                    position.apply_split(action.split_to, action.split_from);
+                    // Replaces the cached position, as updating only re-indexes it
+                    cache.add_position(position, self.oms_type)?;
+                }
+            }
+            CorporateActionType::Dividend => {
+                let Some(amount) = action.dividend else {
+                    anyhow::bail!("No dividend amount for {action}")
+                };
+                let multiplier = matching_engine.instrument.multiplier().as_f64();
+                let shares: f64 = self
+                    .cache
+                    .borrow()
+                    .positions_open(None, Some(&action.instrument_id), None, None)
+                    .iter()
+                    .map(|position| position.signed_qty)
+                    .sum();
+                if shares != 0.0 {
+                    self.adjust_account(Money::new(
+                        shares * multiplier * amount.as_f64(),
+                        amount.currency,
+                    ));
+                }
+            }
+            CorporateActionType::SymbolChange => {
+                let Some(new_instrument_id) = action.new_instrument_id else {
+                    anyhow::bail!("No new instrument ID for {action}")
+                };
+                matching_engine.cancel_open_orders();
+                let instrument = matching_engine.instrument.with_id(new_instrument_id);
+                self.cache.borrow_mut().add_instrument(instrument.clone())?;
+                self.add_instrument(instrument)?;
+            }
+        }
+        Ok(())
     }
 
-    pub fn reset(&mut self) {
+    /// Updates the trading session and halts at `ts_now` and expires DAY orders at the close of
+    /// the trading day, then processes the commands which have arrived at the venue by `ts_now`,
+    /// settles funding and expiries, then the modules.
+    pub fn process(&mut self, ts_now: UnixNanos) {
+        self.clock.set_time(ts_now);
+
+        self.update_session(ts_now);
+        self.check_halts_resume(ts_now);
+        self.expire_day_orders(ts_now);
+
+        while self
+            .throttled_queue
+            .front()
+            .is_some_and(|(ts_admitted, _)| *ts_admitted <= ts_now)
+        {
+            if let Some((_, command)) = self.throttled_queue.pop_front() {
+                self.admit_trading_command(command);
+            }
+        }
+
+        while self
+            .inflight_queue
+            .peek()
+            .is_some_and(|inflight| inflight.ts_arrival <= ts_now)
+        {
+            if let Some(inflight) = self.inflight_queue.pop() {
+                self.process_trading_command(inflight.command);
+            }
+        }
+
+        self.settle_funding(ts_now);
+        self.settle_expiries(ts_now);
+
         for module in &self.modules {
-            module.reset();
+            module.process(ts_now);
         }
+    }
 
-        self.generate_fresh_account_state();
+    /// Expires the working DAY orders once `ts_now` reaches the close of the trading day.
+    fn expire_day_orders(&mut self, ts_now: UnixNanos) {
+        let Some(day_close) = self.next_day_close else {
+            self.next_day_close = self.day_close(ts_now);
+            return;
+        };
+        if ts_now < day_close {
+            return;
+        }
 
+        log::info!("Expiring DAY orders at trading day close {day_close}");
         for matching_engine in self.matching_engines.values_mut() {
-            matching_engine.reset();
+            matching_engine.expire_day_orders();
         }
+        self.next_day_close = self.day_close(ts_now);
+    }
 
-        // TODO Clear the inflight and message queues
-        log::info!("Resetting exchange state");
+    /// Returns the close of the trading day after `ts`, which is the venue-local close of the
+    /// trading calendar if set, otherwise UTC midnight.
+    fn day_close(&self, ts: UnixNanos) -> Option<UnixNanos> {
+        match &self.calendar {
+            Some(calendar) => calendar.day_close(ts),
+            None => Some(UnixNanos::from(
+                (ts.as_u64() / NANOSECONDS_IN_DAY + 1) * NANOSECONDS_IN_DAY,
+            )),
+        }
     }
 
-    pub fn process_trading_command(&mut self, command: TradingCommand) {
-        if let Some(matching_engine) = self.matching_engines.get_mut(&command.instrument_id()) {
-            let account_id = if let Some(exec_client) = &self.exec_client {
-                exec_client.account_id
-            } else {
-                panic!("Execution client should be initialized");
-            };
-            match command {
-                TradingCommand::SubmitOrder(mut command) => {
-                    matching_engine.process_order(&mut command.order, account_id);
-                }
-                TradingCommand::ModifyOrder(ref command) => {
-                    matching_engine.process_modify(command, account_id);
-                }
-                TradingCommand::CancelOrder(ref command) => {
-                    matching_engine.process_cancel(command, account_id);
-                }
-                TradingCommand::CancelAllOrders(ref command) => {
-                    matching_engine.process_cancel_all(command, account_id);
-                }
-                TradingCommand::BatchCancelOrders(ref command) => {
-                    matching_engine.process_batch_cancel(command, account_id);
-                }
-                TradingCommand::QueryOrder(ref command) => {
-                    matching_engine.process_query_order(command, account_id);
-                }
-                TradingCommand::SubmitOrderList(mut command) => {
-                    for order in &mut command.order_list.orders {
-                        matching_engine.process_order(order, account_id);
-                    }
-                }
+    /// Applies the funding payments of the fee model for each funding time up to `ts_now`.
+    fn settle_funding(&mut self, ts_now: UnixNanos) {
+        let Some(mut funding_ts) = self
+            .next_funding_ts
+            .or_else(|| self.fee_model.next_funding_time(ts_now))
+        else {
+            return;
+        };
+
+        while funding_ts <= ts_now {
+            self.apply_funding();
+            match self.fee_model.next_funding_time(funding_ts) {
+                Some(next_funding_ts) => funding_ts = next_funding_ts,
+                None => break,
             }
-        } else {
-            panic!("Matching engine should be initialized");
         }
+        self.next_funding_ts = Some(funding_ts);
     }
 
-    pub fn generate_fresh_account_state(&self) {
-        let balances: Vec<AccountBalance> = self
-            .starting_balances
-            .iter()
-            .map(|money| AccountBalance::new(*money, Money::zero(money.currency), *money))
+    /// Settles each instrument which has expired by `ts_now`, in instrument ID order.
+    fn settle_expiries(&mut self, ts_now: UnixNanos) {
+        let mut expiring: Vec<InstrumentId> = self
+            .instruments
+            .values()
+            .filter(|instrument| {
+                instrument
+                    .expiration_ns()
+                    .is_some_and(|expiration_ns| expiration_ns <= ts_now)
+            })
+            .map(InstrumentAny::id)
+            .filter(|instrument_id| !self.expired_instruments.contains(instrument_id))
             .collect();
+        expiring.sort();
 
-        if let Some(exec_client) = &self.exec_client {
-            exec_client
-                .generate_account_state(balances, vec![], true, self.clock.get_time_ns())
-                .unwrap();
+        for instrument_id in expiring {
+            self.expired_instruments.insert(instrument_id);
+            self.settle_expiry(instrument_id, ts_now);
         }
+    }
 
-        // Set leverages
-        if let Some(AccountAny::Margin(mut margin_account)) = self.get_account() {
-            margin_account.set_default_leverage(self.default_leverage.to_f64().unwrap());
-
-            // Set instrument specific leverages
-            for (instrument_id, leverage) in &self.leverages {
-                margin_account.set_leverage(*instrument_id, leverage.to_f64().unwrap());
-            }
+    /// Expires the working orders of the instrument and settles its open positions, then
+    /// publishes the expiry event.
+    fn settle_expiry(&mut self, instrument_id: InstrumentId, ts_now: UnixNanos) {
+        let Some(instrument) = self.instruments.get(&instrument_id).cloned() else {
+            return;
+        };
+        if let Some(matching_engine) = self.matching_engines.get_mut(&instrument_id) {
+            matching_engine.expire_open_orders();
         }
-    }
-}
 
-////////////////////////////////////////////////////////////////////////////////
-// Tests
-////////////////////////////////////////////////////////////////////////////////
+        let option = match &instrument {
+            InstrumentAny::OptionContract(option) => Some(*option),
+            _ => None,
+        };
+        let underlying = self.underlying_instrument(&instrument);
+        let settlement_price = match (&option, &underlying) {
+            (Some(_), Some(underlying)) => self
+                .settlement_prices
+                .get(&instrument_id)
+                .copied()
+                .or_else(|| self.settlement_price(&underlying.id())),
+            _ => self.settlement_price(&instrument_id),
+        };
+        // Options are exercised (or assigned) automatically when in the money
+        let intrinsic = option.as_ref().zip(settlement_price).map(|(option, px)| {
+            instrument
+                .make_price(intrinsic_value(option.option_kind, option.strike_price, px).as_f64())
+        });
+        let exercised = match (&option, intrinsic) {
+            (Some(_), Some(intrinsic)) => intrinsic.raw > 0,
+            (Some(_), None) => false,
+            (None, _) => settlement_price.is_some(),
+        };
+
+        let delivery = match (self.settlement_type, underlying) {
+            (SettlementType::Physical, Some(underlying)) => Some(underlying),
+            (SettlementType::Physical, None) => {
+                log::warn!("No underlying listed for {instrument_id}, settling in cash");
+                None
+            }
+            (SettlementType::Cash, _) => None,
+        };
+        let settlement_type = if delivery.is_some() {
+            SettlementType::Physical
+        } else {
+            SettlementType::Cash
+        };
+
+        let mut positions: Vec<Position> = self
+            .cache
+            .borrow()
+            .positions_open(None, Some(&instrument_id), None, None)
+            .into_iter()
+            .cloned()
+            .collect();
+        positions.sort_by_key(|position| position.id);
+
+        let mut position_ids = Vec::new();
+        match settlement_price {
+            Some(settlement_price) => {
+                for position in positions {
+                    // Physically settled options close worthless, as their value is
+                    // delivered into the underlying at the strike price
+                    let close_px = match (intrinsic, &delivery) {
+                        (Some(_), Some(_)) => Price::zero(instrument.price_precision()),
+                        (Some(intrinsic), None) => intrinsic,
+                        (None, _) => settlement_price,
+                    };
+                    if let Some(underlying) = delivery.as_ref().filter(|_| exercised) {
+                        self.deliver_underlying(
+                            &position,
+                            &instrument,
+                            underlying,
+                            settlement_price,
+                            ts_now,
+                        );
+                    }
+                    position_ids.push(position.id);
+                    self.close_expired_position(position, close_px, ts_now);
+                }
+            }
+            None if !positions.is_empty() => {
+                log::error!("No settlement price for {instrument_id}, positions remain open");
+            }
+            None => {}
+        }
+
+        let expiry = InstrumentExpiry {
+            instrument_id,
+            settlement_price,
+            exercised,
+            settlement_type,
+            delivered_instrument_id: delivery.as_ref().map(InstrumentAny::id),
+            position_ids,
+            ts_event: ts_now,
+        };
+        log::info!("Instrument expired: {expiry}");
+        let msgbus = self.msgbus.borrow();
+        msgbus.publish(&get_expiry_topic(instrument_id), &expiry);
+    }
+
+    /// Returns the settlement price of the instrument, being the price set for it or otherwise
+    /// the mid price of its market, falling back to the last traded price.
+    fn settlement_price(&self, instrument_id: &InstrumentId) -> Option<Price> {
+        if let Some(price) = self.settlement_prices.get(instrument_id) {
+            return Some(*price);
+        }
+        let matching_engine = self.matching_engines.get(instrument_id)?;
+        match (
+            matching_engine.best_bid_price(),
+            matching_engine.best_ask_price(),
+        ) {
+            (Some(bid), Some(ask)) => Some(Price::from_raw((bid.raw + ask.raw) / 2, bid.precision)),
+            (Some(px), None) | (None, Some(px)) => Some(px),
+            (None, None) => matching_engine.core.last,
+        }
+    }
+
+    /// Returns the underlying instrument of an option or futures contract, if listed at the
+    /// venue under the underlying symbol.
+    fn underlying_instrument(&self, instrument: &InstrumentAny) -> Option<InstrumentAny> {
+        let underlying = match instrument {
+            InstrumentAny::OptionContract(option) => option.underlying,
+            InstrumentAny::FuturesContract(future) => future.underlying,
+            _ => return None,
+        };
+        self.instruments
+            .values()
+            .filter(|candidate| candidate.id().symbol.inner() == underlying)
+            .min_by_key(|candidate| candidate.id())
+            .cloned()
+    }
+
+    /// Closes the expired `position` at the `close_px`, adjusting the account by the PnL.
+    fn close_expired_position(
+        &mut self,
+        mut position: Position,
+        close_px: Price,
+        ts_now: UnixNanos,
+    ) {
+        let pnl =
+            position.calculate_pnl(position.avg_px_open, close_px.as_f64(), position.quantity);
+        let order_side = if position.is_long() {
+            OrderSide::Sell
+        } else {
+            OrderSide::Buy
+        };
+        let fill = self.generate_expiry_fill(
+            &position,
+            position.instrument_id,
+            order_side,
+            position.quantity,
+            close_px,
+            position.quote_currency,
+            position.id,
+            ts_now,
+        );
+        position.apply(&fill);
+
+        if let Err(e) = self.store_position(position, false) {
+            log::error!("Cannot close expired position: {e}");
+        }
+        if !pnl.is_zero() {
+            self.adjust_account(pnl);
+        }
+    }
+
+    /// Delivers the exercised contracts of the `position` into the `underlying`, bought (or
+    /// sold) at the strike price of an option, or the `settlement_price` of a future.
+    fn deliver_underlying(
+        &mut self,
+        position: &Position,
+        instrument: &InstrumentAny,
+        underlying: &InstrumentAny,
+        settlement_price: Price,
+        ts_now: UnixNanos,
+    ) {
+        let (buys, price) = match instrument {
+            InstrumentAny::OptionContract(option) => (
+                (option.option_kind == OptionKind::Call) == position.is_long(),
+                option.strike_price,
+            ),
+            _ => (position.is_long(), settlement_price),
+        };
+        let order_side = if buys {
+            OrderSide::Buy
+        } else {
+            OrderSide::Sell
+        };
+        let quantity =
+            underlying.make_qty(position.quantity.as_f64() * instrument.multiplier().as_f64());
+        let price = underlying.make_price(price.as_f64());
+
+        let position_id = match self.oms_type {
+            OmsType::Hedging => PositionId::new(format!("{}-DELIVERY", position.id)),
+            _ => PositionId::new(format!("{}-{}", underlying.id(), position.strategy_id)),
+        };
+        let fill = self.generate_expiry_fill(
+            position,
+            underlying.id(),
+            order_side,
+            quantity,
+            price,
+            underlying.quote_currency(),
+            position_id,
+            ts_now,
+        );
+
+        let existing = self.cache.borrow().position(&position_id).cloned();
+        let result = match existing {
+            Some(mut delivered) => {
+                delivered.apply(&fill);
+                self.store_position(delivered, false)
+            }
+            None => self.store_position(Position::new(underlying, fill), true),
+        };
+        if let Err(e) = result {
+            log::error!(
+                "Cannot deliver {} for expired position {}: {e}",
+                underlying.id(),
+                position.id
+            );
+        }
+    }
+
+    /// Stores the `position` in the cache, replacing any existing position with the same ID.
+    fn store_position(&self, position: Position, is_new: bool) -> anyhow::Result<()> {
+        let mut cache = self.cache.borrow_mut();
+        cache.add_position(position.clone(), self.oms_type)?;
+        if !is_new {
+            // Adding only indexes the position as open
+            cache.update_position(&position)?;
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn generate_expiry_fill(
+        &mut self,
+        position: &Position,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+        currency: Currency,
+        position_id: PositionId,
+        ts_now: UnixNanos,
+    ) -> OrderFilled {
+        self.expiry_fill_count += 1;
+        let id = format!("{}-EXPIRY-{}", self.id, self.expiry_fill_count);
+        OrderFilled::new(
+            position.trader_id,
+            position.strategy_id,
+            instrument_id,
+            ClientOrderId::new(&id),
+            VenueOrderId::new(&id),
+            position.account_id,
+            TradeId::new(&id),
+            order_side,
+            OrderType::Market,
+            quantity,
+            price,
+            currency,
+            LiquiditySide::NoLiquiditySide,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            Some(position_id),
+            None,
+        )
+    }
+
+    fn apply_funding(&mut self) {
+        let mut payments: HashMap<Currency, Money> = HashMap::new();
+        {
+            let cache = self.cache.borrow();
+            for (instrument_id, matching_engine) in &self.matching_engines {
+                let mark_px = match (
+                    matching_engine.best_bid_price(),
+                    matching_engine.best_ask_price(),
+                ) {
+                    (Some(bid), Some(ask)) => {
+                        Price::from_raw((bid.raw + ask.raw) / 2, bid.precision)
+                    }
+                    (Some(px), None) | (None, Some(px)) => px,
+                    (None, None) => continue,
+                };
+
+                for position in cache.positions_open(None, Some(instrument_id), None, None) {
+                    match self.fee_model.get_funding_payment(
+                        position,
+                        mark_px,
+                        &matching_engine.instrument,
+                    ) {
+                        Ok(Some(payment)) => {
+                            *payments
+                                .entry(payment.currency)
+                                .or_insert_with(|| Money::new(0.0, payment.currency)) += payment;
+                        }
+                        Ok(None) => {}
+                        Err(e) => log::error!("Cannot calculate funding for {}: {e}", position.id),
+                    }
+                }
+            }
+        }
+
+        for payment in payments.into_values() {
+            if !payment.is_zero() {
+                log::info!("Applying funding payment of {payment}");
+                self.adjust_account(payment);
+            }
+        }
+    }
+
+    /// Updates the session phase from the trading calendar, opening or closing the market
+    /// and releasing the queued commands on session transitions.
+    fn update_session(&mut self, ts_now: UnixNanos) {
+        let Some(calendar) = &self.calendar else {
+            return;
+        };
+        let phase = calendar.phase(ts_now);
+        let prev_phase = self.session_phase.replace(phase);
+        if prev_phase == Some(phase) {
+            return;
+        }
+
+        log::info!("Trading session phase {prev_phase:?} -> {phase:?}");
+        let instrument_ids: Vec<InstrumentId> = self.matching_engines.keys().copied().collect();
+
+        if phase == SessionPhase::Continuous {
+            for instrument_id in &instrument_ids {
+                self.update_market_status(*instrument_id, MarketStatusAction::Trading);
+            }
+            self.release_session_queue(None);
+            return;
+        }
+
+        if prev_phase == Some(SessionPhase::ClosingAuction) {
+            // Orders collected for the closing auction execute against the closing book
+            for instrument_id in &instrument_ids {
+                self.update_market_status(*instrument_id, MarketStatusAction::Trading);
+            }
+            self.release_session_queue(None);
+        }
+
+        for instrument_id in &instrument_ids {
+            self.update_market_status(*instrument_id, MarketStatusAction::Close);
+        }
+    }
+
+    /// Returns whether the trading calendar, if any, is in a continuous trading session.
+    fn is_session_open(&self) -> bool {
+        self.calendar.is_none() || self.session_phase == Some(SessionPhase::Continuous)
+    }
+
+    /// Returns whether the bar lies within a trading session for execution.
+    fn is_bar_in_session(&self, bar: &Bar) -> bool {
+        let Some(calendar) = &self.calendar else {
+            return true;
+        };
+        let spec = bar.bar_type.spec();
+        match spec.aggregation {
+            BarAggregation::Millisecond
+            | BarAggregation::Second
+            | BarAggregation::Minute
+            | BarAggregation::Hour => {
+                let interval_ns = spec.timedelta().num_nanoseconds().unwrap_or_default() as u64;
+                let ts_open = UnixNanos::from(bar.ts_event.saturating_sub(interval_ns));
+                calendar.contains_interval(ts_open, bar.ts_event)
+            }
+            // Bars spanning whole sessions or more are not bounded by a session
+            BarAggregation::Day | BarAggregation::Week | BarAggregation::Month => true,
+            // Otherwise the bar closes within a session
+            _ => calendar.is_open(UnixNanos::from(bar.ts_event.saturating_sub(1))),
+        }
+    }
+
+    /// Sends the market status `action` to the matching engine for the instrument, keeping
+    /// halted instruments closed.
+    fn update_market_status(&mut self, instrument_id: InstrumentId, action: MarketStatusAction) {
+        if action == MarketStatusAction::Trading && self.is_halted(instrument_id) {
+            return;
+        }
+        if let Some(matching_engine) = self.matching_engines.get_mut(&instrument_id) {
+            matching_engine.process_status(action);
+        }
+    }
+
+    /// Updates the circuit breaker for the instrument with a trade at `price`, halting
+    /// trading if the price breaches the bands.
+    fn update_circuit_breaker(&mut self, instrument_id: InstrumentId, ts: UnixNanos, price: Price) {
+        let Some(template) = &self.circuit_breaker else {
+            return;
+        };
+        let circuit_breaker = self
+            .circuit_breakers
+            .entry(instrument_id)
+            .or_insert_with(|| template.clone());
+
+        if circuit_breaker.check_resume(ts) {
+            self.resume_trading(instrument_id);
+        } else if circuit_breaker.update(ts, price) {
+            log::warn!("Trading halted for {instrument_id}");
+            self.update_market_status(instrument_id, MarketStatusAction::Halt);
+        }
+    }
+
+    /// Resumes trading for each halted instrument whose halt has ended by `ts_now`.
+    fn check_halts_resume(&mut self, ts_now: UnixNanos) {
+        let resumed: Vec<InstrumentId> = self
+            .circuit_breakers
+            .iter_mut()
+            .filter_map(|(instrument_id, circuit_breaker)| {
+                circuit_breaker
+                    .check_resume(ts_now)
+                    .then_some(*instrument_id)
+            })
+            .collect();
+
+        for instrument_id in resumed {
+            self.resume_trading(instrument_id);
+        }
+    }
+
+    fn resume_trading(&mut self, instrument_id: InstrumentId) {
+        log::info!("Trading resumed for {instrument_id}");
+        if self.is_session_open() {
+            self.update_market_status(instrument_id, MarketStatusAction::Trading);
+            self.release_session_queue(Some(instrument_id));
+        }
+    }
+
+    /// Processes the queued commands, for the instrument if given, in the order they arrived.
+    ///
+    /// Commands for halted instruments remain queued.
+    fn release_session_queue(&mut self, instrument_id: Option<InstrumentId>) {
+        let commands = std::mem::take(&mut self.session_queue);
+        for command in commands {
+            let command_instrument_id = command.instrument_id();
+            if instrument_id.is_some_and(|id| id != command_instrument_id)
+                || self.is_halted(command_instrument_id)
+            {
+                self.session_queue.push(command);
+                continue;
+            }
+            self.execute_trading_command(command);
+        }
+    }
+
+    /// Returns whether the command must be queued until trading resumes for its instrument.
+    fn should_queue(&self, command: &TradingCommand) -> bool {
+        let instrument_id = command.instrument_id();
+        let session_blocked = match self.session_phase {
+            Some(SessionPhase::OpeningAuction | SessionPhase::ClosingAuction) => true,
+            Some(SessionPhase::Closed) => self.out_of_session_policy == OutOfSessionPolicy::Queue,
+            Some(SessionPhase::Continuous) | None => false,
+        };
+        if !session_blocked && !self.is_halted(instrument_id) {
+            return false;
+        }
+
+        match command {
+            TradingCommand::SubmitOrder(_) | TradingCommand::SubmitOrderList(_) => true,
+            TradingCommand::ModifyOrder(command) => self.is_queued(command.client_order_id),
+            TradingCommand::BatchModifyOrders(command) => command
+                .modifies
+                .iter()
+                .any(|modify| self.is_queued(modify.client_order_id)),
+            TradingCommand::CancelOrder(command) => self.is_queued(command.client_order_id),
+            TradingCommand::BatchCancelOrders(command) => command
+                .cancels
+                .iter()
+                .any(|cancel| self.is_queued(cancel.client_order_id)),
+            TradingCommand::CancelAllOrders(command) => self
+                .session_queue
+                .iter()
+                .any(|queued| command.venue_wide || queued.instrument_id() == instrument_id),
+            TradingCommand::QueryOrder(_) => false,
+        }
+    }
+
+    /// Returns whether an order submission is queued for the client order ID.
+    fn is_queued(&self, client_order_id: ClientOrderId) -> bool {
+        self.session_queue.iter().any(|command| match command {
+            TradingCommand::SubmitOrder(submit) => submit.client_order_id == client_order_id,
+            TradingCommand::SubmitOrderList(submit) => submit
+                .order_list
+                .orders
+                .iter()
+                .any(|order| order.client_order_id() == client_order_id),
+            _ => false,
+        })
+    }
+
+    pub fn reset(&mut self) {
+        for module in &self.modules {
+            module.reset();
+        }
+
+        self.generate_fresh_account_state();
+
+        for matching_engine in self.matching_engines.values_mut() {
+            matching_engine.reset();
+        }
+
+        self.inflight_queue.clear();
+        self.inflight_sequence = 0;
+        self.throttled_queue.clear();
+        self.reset_throttles();
+        self.next_funding_ts = None;
+        self.next_day_close = None;
+        self.expired_instruments.clear();
+        self.expiry_fill_count = 0;
+        self.session_phase = None;
+        self.session_queue.clear();
+        self.circuit_breakers.clear();
+        if let Some(validator) = &mut self.book_validator {
+            validator.reset();
+        }
+
+        log::info!("Resetting exchange state");
+    }
+
+    /// Processes the trading `command`, rejecting or delaying it if it exceeds the rate limits
+    /// of the venue, and queuing it if the venue is not trading continuously or the instrument
+    /// is halted.
+    pub fn process_trading_command(&mut self, command: TradingCommand) {
+        let ts_now = self.clock.get_time_ns();
+        match (
+            self.rate_limited_until(&command, ts_now),
+            self.rate_limit_action,
+        ) {
+            (Some(ts_admitted), _) if ts_admitted <= ts_now => {
+                self.record_messages(&command, ts_now);
+                self.admit_trading_command(command);
+            }
+            (Some(ts_admitted), RateLimitAction::Delay) => {
+                log::info!("Delaying command until {ts_admitted} by rate limits: {command:?}");
+                self.record_messages(&command, ts_admitted);
+                self.throttled_queue.push_back((ts_admitted, command));
+            }
+            _ => self.reject_rate_limited(&command),
+        }
+    }
+
+    /// Returns the earliest time from `ts_now` the `command` is within the rate limits of the
+    /// venue, or `None` if it can never be.
+    fn rate_limited_until(&self, command: &TradingCommand, ts_now: UnixNanos) -> Option<UnixNanos> {
+        let order_count = command_order_count(command);
+        let mut ts = ts_now;
+        // Iterated to a fixed point, as waiting for one limit may breach the other
+        loop {
+            let mut ts_next = ts;
+            if let Some(throttle) = &self.order_throttle {
+                ts_next = ts_next.max(throttle.next_available(ts, order_count)?);
+            }
+            if let Some(throttle) = &self.message_throttle {
+                ts_next = ts_next.max(throttle.next_available(ts, 1)?);
+            }
+            if ts_next == ts {
+                return Some(ts);
+            }
+            ts = ts_next;
+        }
+    }
+
+    fn record_messages(&mut self, command: &TradingCommand, ts: UnixNanos) {
+        if let Some(throttle) = &mut self.order_throttle {
+            throttle.record(ts, command_order_count(command));
+        }
+        if let Some(throttle) = &mut self.message_throttle {
+            throttle.record(ts, 1);
+        }
+    }
+
+    fn reset_throttles(&mut self) {
+        for throttle in [&mut self.order_throttle, &mut self.message_throttle]
+            .into_iter()
+            .flatten()
+        {
+            throttle.reset();
+        }
+    }
+
+    fn reject_rate_limited(&mut self, command: &TradingCommand) {
+        log::warn!("Rejecting command exceeding rate limits: {command:?}");
+        let account_id = if let Some(exec_client) = &self.exec_client {
+            exec_client.account_id
+        } else {
+            panic!("Execution client should be initialized");
+        };
+        if let Some(matching_engine) = self.matching_engines.get_mut(&command.instrument_id()) {
+            matching_engine.reject_command(command, account_id, Ustr::from("Rate limit exceeded"));
+        } else {
+            panic!("Matching engine should be initialized");
+        }
+    }
+
+    /// Admits the trading `command` within the rate limits, queuing it if the venue is not
+    /// trading continuously or the instrument is halted.
+    fn admit_trading_command(&mut self, command: TradingCommand) {
+        if self.should_queue(&command) {
+            log::info!("Queuing command until trading resumes: {command:?}");
+            self.session_queue.push(command);
+            return;
+        }
+        self.execute_trading_command(command);
+    }
+
+    fn execute_trading_command(&mut self, command: TradingCommand) {
+        if let TradingCommand::CancelAllOrders(command) = &command {
+            if command.venue_wide {
+                self.cancel_all_venue_wide(command);
+                return;
+            }
+        }
+
+        if let Some(matching_engine) = self.matching_engines.get_mut(&command.instrument_id()) {
+            let account_id = if let Some(exec_client) = &self.exec_client {
+                exec_client.account_id
+            } else {
+                panic!("Execution client should be initialized");
+            };
+            match command {
+                TradingCommand::SubmitOrder(mut command) => {
+                    matching_engine.process_order(&mut command.order, account_id);
+                }
+                TradingCommand::ModifyOrder(ref command) => {
+                    matching_engine.process_modify(command, account_id);
+                }
+                TradingCommand::BatchModifyOrders(ref command) => {
+                    matching_engine.process_batch_modify(command, account_id);
+                }
+                TradingCommand::CancelOrder(ref command) => {
+                    matching_engine.process_cancel(command, account_id);
+                }
+                TradingCommand::CancelAllOrders(ref command) => {
+                    matching_engine.process_cancel_all(command, account_id);
+                }
+                TradingCommand::BatchCancelOrders(ref command) => {
+                    matching_engine.process_batch_cancel(command, account_id);
+                }
+                TradingCommand::QueryOrder(ref command) => {
+                    matching_engine.process_query_order(command, account_id);
+                }
+                TradingCommand::SubmitOrderList(mut command) => {
+                    for order in &mut command.order_list.orders {
+                        matching_engine.process_order(order, account_id);
+                    }
+                }
+            }
+        } else {
+            panic!("Matching engine should be initialized");
+        }
+    }
+
+    /// Cancels the open orders of every instrument at the venue matching the `command`.
+    fn cancel_all_venue_wide(&mut self, command: &CancelAllOrders) {
+        let account_id = if let Some(exec_client) = &self.exec_client {
+            exec_client.account_id
+        } else {
+            panic!("Execution client should be initialized");
+        };
+        for matching_engine in self.matching_engines.values_mut() {
+            matching_engine.process_cancel_all(command, account_id);
+        }
+    }
+
+    pub fn generate_fresh_account_state(&self) {
+        let balances: Vec<AccountBalance> = self
+            .starting_balances
+            .iter()
+            .map(|money| AccountBalance::new(*money, Money::zero(money.currency), *money))
+            .collect();
+
+        if let Some(exec_client) = &self.exec_client {
+            exec_client
+                .generate_account_state(balances, vec![], true, self.clock.get_time_ns())
+                .unwrap();
+        }
+
+        // Set leverages
+        if let Some(AccountAny::Margin(mut margin_account)) = self.get_account() {
+            margin_account.set_default_leverage(self.default_leverage.to_f64().unwrap());
+
+            // Set instrument specific leverages
+            for (instrument_id, leverage) in &self.leverages {
+                margin_account.set_leverage(*instrument_id, leverage.to_f64().unwrap());
+            }
+        }
+    }
+}
+
+fn command_order_count(command: &TradingCommand) -> usize {
+    match command {
+        TradingCommand::SubmitOrder(_) => 1,
+        TradingCommand::SubmitOrderList(command) => command.order_list.orders.len(),
+        _ => 0,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
     use std::{cell::RefCell, collections::HashMap, rc::Rc, sync::LazyLock};
 
+    use chrono::{DateTime, NaiveTime, TimeDelta, Utc};
     use nautilus_common::{
         cache::Cache,
         msgbus::{
+            handler::ShareableMessageHandler,
             stubs::{get_message_saving_handler, get_saved_messages},
             MessageBus,
         },
+        throttler::RateLimit,
     };
     use nautilus_core::{AtomicTime, UnixNanos, UUID4};
     use nautilus_execution::{
         client::ExecutionClient,
+        messages::{cancel::CancelOrder, submit::SubmitOrder, TradingCommand},
         models::{
-            fee::{FeeModelAny, MakerTakerFeeModel},
+            fee::{FeeModelAny, FundingFeeModel, MakerTakerFeeModel},
             fill::FillModel,
-            latency::LatencyModel,
+            latency::{LatencyDistribution, LatencyModel},
+            slippage::{SlippageKind, SlippageModel},
         },
     };
     use nautilus_model::{
         accounts::{AccountAny, MarginAccount},
         data::{
-            Bar, BarType, BookOrder, InstrumentStatus, OrderBookDelta, OrderBookDeltas, QuoteTick,
-            TradeTick,
+            Bar, BarType, BookOrder, CorporateAction, InstrumentStatus, OrderBookDelta,
+            OrderBookDeltas, QuoteTick, TradeTick,
         },
         enums::{
             AccountType, AggressorSide, BookAction, BookType, MarketStatus, MarketStatusAction,
-            OmsType, OrderSide,
+            OmsType, OrderSide, OrderType, TimeInForce,
         },
-        events::AccountState,
-        identifiers::{AccountId, ClientId, TradeId, TraderId, Venue},
-        instruments::{stubs::crypto_perpetual_ethusdt, CryptoPerpetual, InstrumentAny},
+        events::{AccountState, OrderEventAny},
+        identifiers::{
+            AccountId, ClientId, ClientOrderId, InstrumentId, StrategyId, TradeId, TraderId, Venue,
+            VenueOrderId,
+        },
+        instruments::{
+            stubs::{
+                crypto_perpetual_ethusdt, equity_aapl, futures_contract_es, futures_spread_es,
+                option_contract_appl,
+            },
+            CryptoPerpetual, InstrumentAny, OptionContract,
+        },
+        orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs, OrderAny, SpreadLeg},
+        position::Position,
         types::{AccountBalance, Currency, Money, Price, Quantity},
     };
     use rstest::rstest;
     use ustr::Ustr;
 
-    use crate::exchange::SimulatedExchange;
+    use crate::{
+        book_validation::{get_book_diagnostics_topic, BookDiagnostic, BookDiagnosticKind},
+        calendar::{OutOfSessionPolicy, SessionPhase, TradingCalendar},
+        circuit_breaker::CircuitBreaker,
+        exchange::SimulatedExchange,
+        expiry::{get_expiry_topic, InstrumentExpiry, SettlementType},
+        throttle::RateLimitAction,
+    };
 
     static ATOMIC_TIME: LazyLock<AtomicTime> =
         LazyLock::new(|| AtomicTime::new(true, UnixNanos::default()));
@@ -664,6 +2110,17 @@ mod tests {
         book_type: BookType,
         msgbus: Option<Rc<RefCell<MessageBus>>>,
         cache: Option<Rc<RefCell<Cache>>>,
+    ) -> SimulatedExchange {
+        get_exchange_with_clock(venue, account_type, book_type, msgbus, cache, &ATOMIC_TIME)
+    }
+
+    fn get_exchange_with_clock(
+        venue: Venue,
+        account_type: AccountType,
+        book_type: BookType,
+        msgbus: Option<Rc<RefCell<MessageBus>>>,
+        cache: Option<Rc<RefCell<Cache>>>,
+        clock: &'static AtomicTime,
     ) -> SimulatedExchange {
         let msgbus = msgbus.unwrap_or(Rc::new(RefCell::new(MessageBus::default())));
         let cache = cache.unwrap_or(Rc::new(RefCell::new(Cache::default())));
@@ -679,10 +2136,10 @@ mod tests {
             vec![],
             msgbus.clone(),
             cache.clone(),
-            &ATOMIC_TIME,
+            clock,
             FillModel::default(),
             FeeModelAny::MakerTaker(MakerTakerFeeModel),
-            LatencyModel,
+            LatencyModel::default(),
             book_type,
             None,
             None,
@@ -704,7 +2161,7 @@ mod tests {
             AccountId::default(),
             account_type,
             None,
-            &ATOMIC_TIME,
+            clock,
             cache,
             msgbus,
         );
@@ -713,16 +2170,258 @@ mod tests {
         exchange
     }
 
-    #[rstest]
-    #[should_panic(
-        expected = r#"Condition failed: 'Venue of instrument id' value of BINANCE was not equal to 'Venue of simulated exchange' value of SIM"#
-    )]
-    fn test_venue_mismatch_between_exchange_and_instrument(
-        crypto_perpetual_ethusdt: CryptoPerpetual,
-    ) {
-        let mut exchange: SimulatedExchange = get_exchange(
-            Venue::new("SIM"),
-            AccountType::Margin,
+    fn cancel_order_command(
+        instrument_id: InstrumentId,
+        client_order_id: &str,
+        ts_init: u64,
+    ) -> TradingCommand {
+        TradingCommand::CancelOrder(
+            CancelOrder::new(
+                TraderId::default(),
+                ClientId::default(),
+                StrategyId::default(),
+                instrument_id,
+                ClientOrderId::from(client_order_id),
+                VenueOrderId::default(),
+                UUID4::new(),
+                ts_init.into(),
+            )
+            .unwrap(),
+        )
+    }
+
+    fn submit_order_command(order: OrderAny, ts_init: UnixNanos) -> TradingCommand {
+        TradingCommand::SubmitOrder(
+            SubmitOrder::new(
+                TraderId::default(),
+                ClientId::default(),
+                StrategyId::default(),
+                order.instrument_id(),
+                order.client_order_id(),
+                VenueOrderId::default(),
+                order,
+                None,
+                None,
+                UUID4::new(),
+                ts_init,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn limit_order(instrument_id: InstrumentId, client_order_id: &str) -> OrderAny {
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_id)
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .side(OrderSide::Buy)
+            .price(Price::from("1000.00"))
+            .quantity(Quantity::from("1.000"))
+            .build()
+    }
+
+    fn trade_tick(instrument_id: InstrumentId, price: &str, ts: UnixNanos) -> TradeTick {
+        TradeTick::new(
+            instrument_id,
+            Price::from(price),
+            Quantity::from("1.000"),
+            AggressorSide::Buyer,
+            TradeId::from("1"),
+            ts,
+            ts,
+        )
+    }
+
+    fn ts(datetime: &str) -> UnixNanos {
+        let datetime: DateTime<Utc> = datetime.parse().unwrap();
+        UnixNanos::from(datetime)
+    }
+
+    fn utc_calendar() -> TradingCalendar {
+        TradingCalendar::new(
+            chrono_tz::UTC,
+            vec![(
+                NaiveTime::from_hms_opt(9, 30, 0).unwrap(),
+                NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+            )],
+        )
+        .unwrap()
+        .with_opening_auction(TimeDelta::minutes(30))
+        .unwrap()
+    }
+
+    /// Returns a cache with a margin account of 1000 USDT and a long position of 2 ETH.
+    fn get_cache_with_long_position(instrument: &InstrumentAny) -> Cache {
+        let mut cache = Cache::default();
+        let margin_account = MarginAccount::new(
+            AccountState::new(
+                AccountId::from("BINANCE-001"),
+                AccountType::Margin,
+                vec![AccountBalance::new(
+                    Money::from("1000 USDT"),
+                    Money::from("0 USDT"),
+                    Money::from("1000 USDT"),
+                )],
+                vec![],
+                false,
+                UUID4::default(),
+                UnixNanos::default(),
+                UnixNanos::default(),
+                None,
+            ),
+            false,
+        );
+        cache
+            .add_account(AccountAny::Margin(margin_account))
+            .unwrap();
+
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("2.000"))
+            .build();
+        let filled = TestOrderEventStubs::order_filled(
+            &order,
+            instrument,
+            None,
+            None,
+            Some(Price::from("1000.00")),
+            None,
+            None,
+            Some(Money::from("0 USDT")),
+            None,
+            None,
+        );
+        cache
+            .add_position(Position::new(instrument, filled.into()), OmsType::Netting)
+            .unwrap();
+        cache.build_index();
+        cache
+    }
+
+    /// Returns a cache with a margin account of 1000 USD and a long position of one contract
+    /// opened at `price`.
+    fn get_cache_with_long_contract(instrument: &InstrumentAny, price: &str) -> Cache {
+        let mut cache = Cache::default();
+        let margin_account = MarginAccount::new(
+            AccountState::new(
+                AccountId::new(format!("{}-001", instrument.id().venue)),
+                AccountType::Margin,
+                vec![AccountBalance::new(
+                    Money::from("1000 USD"),
+                    Money::from("0 USD"),
+                    Money::from("1000 USD"),
+                )],
+                vec![],
+                false,
+                UUID4::default(),
+                UnixNanos::default(),
+                UnixNanos::default(),
+                None,
+            ),
+            false,
+        );
+        cache
+            .add_account(AccountAny::Margin(margin_account))
+            .unwrap();
+
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(1))
+            .build();
+        let filled = TestOrderEventStubs::order_filled(
+            &order,
+            instrument,
+            None,
+            None,
+            Some(Price::from(price)),
+            None,
+            None,
+            Some(Money::from("0 USD")),
+            None,
+            None,
+        );
+        cache
+            .add_position(Position::new(instrument, filled.into()), OmsType::Netting)
+            .unwrap();
+        cache.build_index();
+        cache
+    }
+
+    /// Returns an exchange at the venue of the `instrument` holding its long position, with
+    /// the handlers of account states and expiry events.
+    fn get_exchange_with_long_contract(
+        instrument: &InstrumentAny,
+        price: &str,
+    ) -> (
+        SimulatedExchange,
+        Rc<RefCell<Cache>>,
+        ShareableMessageHandler,
+        ShareableMessageHandler,
+    ) {
+        let mut msgbus = MessageBus::default();
+        let account_handler = get_message_saving_handler::<AccountState>(None);
+        msgbus.register(
+            Ustr::from("Portfolio.update_account"),
+            account_handler.clone(),
+        );
+        let expiry_handler = get_message_saving_handler::<InstrumentExpiry>(None);
+        msgbus.subscribe(
+            get_expiry_topic(instrument.id()),
+            expiry_handler.clone(),
+            None,
+        );
+        let cache = Rc::new(RefCell::new(get_cache_with_long_contract(
+            instrument, price,
+        )));
+        let mut exchange = get_exchange(
+            instrument.id().venue,
+            AccountType::Margin,
+            BookType::L1_MBP,
+            Some(Rc::new(RefCell::new(msgbus))),
+            Some(cache.clone()),
+        );
+        exchange.add_instrument(instrument.clone()).unwrap();
+        (exchange, cache, account_handler, expiry_handler)
+    }
+
+    fn get_exchange_with_handler(
+        instrument: CryptoPerpetual,
+    ) -> (SimulatedExchange, ShareableMessageHandler) {
+        get_exchange_with_handler_and_clock(instrument, &ATOMIC_TIME)
+    }
+
+    fn get_exchange_with_handler_and_clock(
+        instrument: CryptoPerpetual,
+        clock: &'static AtomicTime,
+    ) -> (SimulatedExchange, ShareableMessageHandler) {
+        let mut msgbus = MessageBus::default();
+        let handler = get_message_saving_handler::<OrderEventAny>(None);
+        msgbus.register(msgbus.switchboard.exec_engine_process, handler.clone());
+        let mut exchange = get_exchange_with_clock(
+            Venue::new("BINANCE"),
+            AccountType::Margin,
+            BookType::L1_MBP,
+            Some(Rc::new(RefCell::new(msgbus))),
+            None,
+            clock,
+        );
+        exchange
+            .add_instrument(InstrumentAny::CryptoPerpetual(instrument))
+            .unwrap();
+        (exchange, handler)
+    }
+
+    #[rstest]
+    #[should_panic(
+        expected = r#"Condition failed: 'Venue of instrument id' value of BINANCE was not equal to 'Venue of simulated exchange' value of SIM"#
+    )]
+    fn test_venue_mismatch_between_exchange_and_instrument(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let mut exchange: SimulatedExchange = get_exchange(
+            Venue::new("SIM"),
+            AccountType::Margin,
             BookType::L1_MBP,
             None,
             None,
@@ -1102,4 +2801,967 @@ mod tests {
         assert_eq!(current_balance.locked, Money::new(0.0, Currency::USD()));
         assert_eq!(current_balance.total, Money::new(1500.0, Currency::USD()));
     }
+
+    #[rstest]
+    fn test_send_command_arrives_after_latency(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut msgbus = MessageBus::default();
+        let handler = get_message_saving_handler::<OrderEventAny>(None);
+        msgbus.register(msgbus.switchboard.exec_engine_process, handler.clone());
+        let mut exchange = get_exchange(
+            Venue::new("BINANCE"),
+            AccountType::Margin,
+            BookType::L1_MBP,
+            Some(Rc::new(RefCell::new(msgbus))),
+            None,
+        );
+        exchange
+            .add_instrument(InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt))
+            .unwrap();
+        exchange.set_latency_model(LatencyModel::fixed(100));
+
+        exchange.send(cancel_order_command(crypto_perpetual_ethusdt.id, "O-1", 0));
+        exchange.process(UnixNanos::from(50));
+
+        assert_eq!(exchange.inflight_count(), 1);
+        assert!(get_saved_messages::<OrderEventAny>(handler.clone()).is_empty());
+
+        exchange.process(UnixNanos::from(100));
+
+        assert_eq!(exchange.inflight_count(), 0);
+        let messages = get_saved_messages::<OrderEventAny>(handler);
+        assert_eq!(messages.len(), 1);
+        assert!(matches!(
+            messages.first().unwrap(),
+            OrderEventAny::CancelRejected(_)
+        ));
+    }
+
+    #[rstest]
+    fn test_inflight_commands_processed_in_arrival_order(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let mut msgbus = MessageBus::default();
+        let handler = get_message_saving_handler::<OrderEventAny>(None);
+        msgbus.register(msgbus.switchboard.exec_engine_process, handler.clone());
+        let mut exchange = get_exchange(
+            Venue::new("BINANCE"),
+            AccountType::Margin,
+            BookType::L1_MBP,
+            Some(Rc::new(RefCell::new(msgbus))),
+            None,
+        );
+        exchange
+            .add_instrument(InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt))
+            .unwrap();
+        exchange.set_latency_model(LatencyModel::new(
+            LatencyDistribution::Fixed(0),
+            LatencyDistribution::Fixed(0),
+            LatencyDistribution::Fixed(100),
+            LatencyDistribution::Fixed(0),
+            None,
+        ));
+
+        let instrument_id = crypto_perpetual_ethusdt.id;
+        exchange.send(cancel_order_command(instrument_id, "O-3", 20));
+        exchange.send(cancel_order_command(instrument_id, "O-1", 10));
+        exchange.send(cancel_order_command(instrument_id, "O-2", 20));
+        exchange.process(UnixNanos::from(120));
+
+        let client_order_ids: Vec<ClientOrderId> = get_saved_messages::<OrderEventAny>(handler)
+            .iter()
+            .map(OrderEventAny::client_order_id)
+            .collect();
+        assert_eq!(
+            client_order_ids,
+            vec![
+                ClientOrderId::from("O-1"),
+                ClientOrderId::from("O-3"),
+                ClientOrderId::from("O-2"),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_funding_settled_at_funding_times(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let account_type = AccountType::Margin;
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let mut msgbus = MessageBus::default();
+        let mut cache = Cache::default();
+        let handler = get_message_saving_handler::<AccountState>(None);
+        msgbus.register(Ustr::from("Portfolio.update_account"), handler.clone());
+        let margin_account = MarginAccount::new(
+            AccountState::new(
+                AccountId::from("BINANCE-001"),
+                account_type,
+                vec![AccountBalance::new(
+                    Money::from("1000 USDT"),
+                    Money::from("0 USDT"),
+                    Money::from("1000 USDT"),
+                )],
+                vec![],
+                false,
+                UUID4::default(),
+                UnixNanos::default(),
+                UnixNanos::default(),
+                None,
+            ),
+            false,
+        );
+        cache
+            .add_account(AccountAny::Margin(margin_account))
+            .unwrap();
+
+        // Long 2 ETH held through the funding time
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("2.000"))
+            .build();
+        let filled = TestOrderEventStubs::order_filled(
+            &order,
+            &instrument,
+            None,
+            None,
+            Some(Price::from("1000.00")),
+            None,
+            None,
+            Some(Money::from("0 USDT")),
+            None,
+            None,
+        );
+        cache
+            .add_position(Position::new(&instrument, filled.into()), OmsType::Netting)
+            .unwrap();
+        cache.build_index();
+
+        let mut exchange = get_exchange(
+            Venue::new("BINANCE"),
+            account_type,
+            BookType::L1_MBP,
+            Some(Rc::new(RefCell::new(msgbus))),
+            Some(Rc::new(RefCell::new(cache))),
+        );
+        exchange.add_instrument(instrument.clone()).unwrap();
+        let eight_hours_ns = 8 * 60 * 60 * 1_000_000_000;
+        exchange.set_fee_model(FeeModelAny::Funding(
+            FundingFeeModel::new(FeeModelAny::default(), 0.0001, eight_hours_ns).unwrap(),
+        ));
+        exchange.process_quote_tick(&QuoteTick::new(
+            instrument.id(),
+            Price::from("999.00"),
+            Price::from("1001.00"),
+            Quantity::from("1.000"),
+            Quantity::from("1.000"),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        ));
+
+        exchange.process(UnixNanos::from(1));
+        exchange.process(UnixNanos::from(eight_hours_ns - 1));
+        assert!(get_saved_messages::<AccountState>(handler.clone()).is_empty());
+
+        // The long pays the funding rate on the notional at the mid price
+        exchange.process(UnixNanos::from(eight_hours_ns));
+
+        let messages = get_saved_messages::<AccountState>(handler);
+        assert_eq!(messages.len(), 1);
+        let balance = messages[0].balances[0];
+        assert_eq!(balance.total, Money::from("999.8 USDT"));
+        assert_eq!(balance.free, Money::from("999.8 USDT"));
+    }
+
+    #[rstest]
+    fn test_order_rejected_outside_session(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let (mut exchange, handler) = get_exchange_with_handler(crypto_perpetual_ethusdt);
+        exchange.set_trading_calendar(utc_calendar(), OutOfSessionPolicy::Reject);
+        let ts_now = ts("2024-07-03T08:00:00Z");
+        exchange.process(ts_now);
+
+        let order = limit_order(crypto_perpetual_ethusdt.id, "O-1");
+        exchange.process_trading_command(submit_order_command(order, ts_now));
+
+        assert_eq!(exchange.session_phase(), Some(SessionPhase::Closed));
+        assert_eq!(exchange.session_queue_count(), 0);
+        let messages = get_saved_messages::<OrderEventAny>(handler);
+        assert_eq!(messages.len(), 1);
+        match messages.first().unwrap() {
+            OrderEventAny::Rejected(rejected) => {
+                assert_eq!(
+                    rejected.reason.as_str(),
+                    "Market for ETHUSDT-PERP.BINANCE is closed"
+                );
+            }
+            event => panic!("Expected rejected event, was {event:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_order_queued_outside_session_until_open(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let (mut exchange, handler) = get_exchange_with_handler(crypto_perpetual_ethusdt);
+        exchange.set_trading_calendar(utc_calendar(), OutOfSessionPolicy::Queue);
+        let ts_now = ts("2024-07-03T08:00:00Z");
+        exchange.process(ts_now);
+
+        let order = limit_order(crypto_perpetual_ethusdt.id, "O-1");
+        exchange.process_trading_command(submit_order_command(order, ts_now));
+
+        assert_eq!(exchange.session_queue_count(), 1);
+        assert!(get_saved_messages::<OrderEventAny>(handler.clone()).is_empty());
+
+        exchange.process(ts("2024-07-03T09:30:00Z"));
+
+        assert_eq!(exchange.session_phase(), Some(SessionPhase::Continuous));
+        assert_eq!(exchange.session_queue_count(), 0);
+        let messages = get_saved_messages::<OrderEventAny>(handler);
+        assert_eq!(messages.len(), 1);
+        assert!(matches!(
+            messages.first().unwrap(),
+            OrderEventAny::Accepted(_)
+        ));
+    }
+
+    #[rstest]
+    fn test_commands_queued_during_opening_auction(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let (mut exchange, handler) = get_exchange_with_handler(crypto_perpetual_ethusdt);
+        exchange.set_trading_calendar(utc_calendar(), OutOfSessionPolicy::Reject);
+        let ts_now = ts("2024-07-03T09:15:00Z");
+        exchange.process(ts_now);
+
+        let order = limit_order(crypto_perpetual_ethusdt.id, "O-1");
+        exchange.process_trading_command(submit_order_command(order, ts_now));
+        exchange.process_trading_command(cancel_order_command(
+            crypto_perpetual_ethusdt.id,
+            "O-1",
+            ts_now.as_u64(),
+        ));
+
+        assert_eq!(exchange.session_phase(), Some(SessionPhase::OpeningAuction));
+        assert_eq!(exchange.session_queue_count(), 2);
+
+        exchange.process(ts("2024-07-03T09:30:00Z"));
+
+        let messages = get_saved_messages::<OrderEventAny>(handler);
+        assert_eq!(messages.len(), 2);
+        assert!(matches!(messages[0], OrderEventAny::Accepted(_)));
+        assert!(matches!(messages[1], OrderEventAny::Canceled(_)));
+    }
+
+    #[rstest]
+    #[case(false, "2024-07-03T23:59:59Z", "2024-07-04T00:00:00Z")]
+    #[case(true, "2024-07-03T19:59:59Z", "2024-07-03T20:00:00Z")] // 16:00 New York
+    fn test_day_orders_expired_at_day_close(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] with_calendar: bool,
+        #[case] before_close: &str,
+        #[case] at_close: &str,
+    ) {
+        let (mut exchange, handler) = get_exchange_with_handler(crypto_perpetual_ethusdt);
+        if with_calendar {
+            let calendar = TradingCalendar::new(
+                chrono_tz::America::New_York,
+                vec![(
+                    NaiveTime::from_hms_opt(9, 30, 0).unwrap(),
+                    NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+                )],
+            )
+            .unwrap();
+            exchange.set_trading_calendar(calendar, OutOfSessionPolicy::Reject);
+        }
+        let ts_now = ts("2024-07-03T14:00:00Z");
+        exchange.process(ts_now);
+
+        let day_order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(crypto_perpetual_ethusdt.id)
+            .client_order_id(ClientOrderId::from("O-1"))
+            .side(OrderSide::Buy)
+            .price(Price::from("1000.00"))
+            .quantity(Quantity::from("1.000"))
+            .time_in_force(TimeInForce::Day)
+            .build();
+        let gtc_order = limit_order(crypto_perpetual_ethusdt.id, "O-2");
+        exchange.process_trading_command(submit_order_command(day_order, ts_now));
+        exchange.process_trading_command(submit_order_command(gtc_order, ts_now));
+        exchange.process(ts(before_close));
+
+        assert_eq!(
+            get_saved_messages::<OrderEventAny>(handler.clone()).len(),
+            2
+        );
+
+        exchange.process(ts(at_close));
+
+        let messages = get_saved_messages::<OrderEventAny>(handler);
+        assert_eq!(messages.len(), 3);
+        match &messages[2] {
+            OrderEventAny::Expired(expired) => {
+                assert_eq!(expired.client_order_id, ClientOrderId::from("O-1"));
+            }
+            event => panic!("Expected expired event, was {event:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_bar_outside_session_ignored(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let (mut exchange, _) = get_exchange_with_handler(crypto_perpetual_ethusdt);
+        exchange.set_trading_calendar(utc_calendar(), OutOfSessionPolicy::Reject);
+        let bar_type = BarType::from("ETHUSDT-PERP.BINANCE-1-HOUR-LAST-EXTERNAL");
+        let bar = |close: &str, ts_event: UnixNanos| {
+            Bar::new(
+                bar_type,
+                Price::from(close),
+                Price::from(close),
+                Price::from(close),
+                Price::from(close),
+                Quantity::from(100),
+                ts_event,
+                ts_event,
+            )
+        };
+
+        // The bar straddles the session open
+        exchange.process_bar(bar("1500.00", ts("2024-07-03T10:00:00Z")));
+
+        assert_eq!(exchange.best_bid_price(crypto_perpetual_ethusdt.id), None);
+
+        exchange.process_bar(bar("1502.00", ts("2024-07-03T11:00:00Z")));
+
+        assert_eq!(
+            exchange.best_bid_price(crypto_perpetual_ethusdt.id),
+            Some(Price::from("1502.00"))
+        );
+    }
+
+    #[rstest]
+    fn test_circuit_breaker_halts_and_resumes_trading(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let (mut exchange, handler) = get_exchange_with_handler(crypto_perpetual_ethusdt);
+        exchange.set_circuit_breaker(CircuitBreaker::new(0.05, 1_000, 500).unwrap());
+        let instrument_id = crypto_perpetual_ethusdt.id;
+
+        exchange.process_trade_tick(&trade_tick(instrument_id, "1000.00", UnixNanos::from(0)));
+        exchange.process_trade_tick(&trade_tick(instrument_id, "1100.00", UnixNanos::from(10)));
+
+        assert!(exchange.is_halted(instrument_id));
+        assert_eq!(
+            exchange
+                .get_matching_engine(instrument_id)
+                .unwrap()
+                .market_status,
+            MarketStatus::Closed
+        );
+
+        let order = limit_order(instrument_id, "O-1");
+        exchange.process_trading_command(submit_order_command(order, UnixNanos::from(20)));
+
+        assert_eq!(exchange.session_queue_count(), 1);
+
+        exchange.process(UnixNanos::from(509));
+
+        assert!(exchange.is_halted(instrument_id));
+
+        exchange.process(UnixNanos::from(510));
+
+        assert!(!exchange.is_halted(instrument_id));
+        assert_eq!(
+            exchange
+                .get_matching_engine(instrument_id)
+                .unwrap()
+                .market_status,
+            MarketStatus::Open
+        );
+        assert_eq!(exchange.session_queue_count(), 0);
+        let messages = get_saved_messages::<OrderEventAny>(handler);
+        assert_eq!(messages.len(), 1);
+        assert!(matches!(
+            messages.first().unwrap(),
+            OrderEventAny::Accepted(_)
+        ));
+    }
+
+    #[rstest]
+    fn test_book_validation_publishes_diagnostics(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument_id = crypto_perpetual_ethusdt.id;
+        let mut msgbus = MessageBus::default();
+        let handler = get_message_saving_handler::<BookDiagnostic>(None);
+        msgbus.subscribe(
+            get_book_diagnostics_topic(instrument_id),
+            handler.clone(),
+            None,
+        );
+        let mut exchange = get_exchange(
+            Venue::new("BINANCE"),
+            AccountType::Margin,
+            BookType::L2_MBP,
+            Some(Rc::new(RefCell::new(msgbus))),
+            None,
+        );
+        exchange
+            .add_instrument(InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt))
+            .unwrap();
+        exchange.set_book_validation(true);
+        let delta = |side, price: &str, sequence| {
+            OrderBookDelta::new(
+                instrument_id,
+                BookAction::Add,
+                BookOrder::new(side, Price::from(price), Quantity::from(1), 0),
+                0,
+                sequence,
+                UnixNanos::from(1),
+                UnixNanos::from(1),
+            )
+        };
+
+        exchange.process_order_book_delta(delta(OrderSide::Buy, "1001.00", 1));
+        exchange.process_order_book_delta(delta(OrderSide::Sell, "1000.00", 3));
+
+        let diagnostics = get_saved_messages::<BookDiagnostic>(handler);
+        let kinds: Vec<BookDiagnosticKind> = diagnostics.iter().map(|d| d.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![BookDiagnosticKind::SequenceGap, BookDiagnosticKind::Crossed]
+        );
+        assert_eq!(diagnostics[1].delta.sequence, 3);
+        assert_eq!(exchange.book_diagnostic_count(), Some(2));
+    }
+
+    #[rstest]
+    fn test_slippage_model_override_takes_precedence(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument_id = crypto_perpetual_ethusdt.id;
+        let mut exchange = get_exchange(
+            Venue::new("BINANCE"),
+            AccountType::Margin,
+            BookType::L2_MBP,
+            None,
+            None,
+        );
+        exchange.set_slippage_model(SlippageModel::walk_the_book(), None);
+        exchange
+            .add_instrument(InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt))
+            .unwrap();
+        let slippage_kind = |exchange: &SimulatedExchange| {
+            exchange
+                .get_matching_engine(instrument_id)
+                .and_then(|engine| engine.get_slippage_model())
+                .map(|model| model.kind().clone())
+        };
+        assert_eq!(slippage_kind(&exchange), Some(SlippageKind::WalkTheBook));
+
+        exchange.set_slippage_model(SlippageModel::fixed_bps(5.0).unwrap(), Some(instrument_id));
+        exchange.set_slippage_model(SlippageModel::volatility_scaled(1.0, 10).unwrap(), None);
+
+        assert_eq!(slippage_kind(&exchange), Some(SlippageKind::FixedBps(5.0)));
+    }
+
+    #[rstest]
+    fn test_max_participation_rate_applied_to_matching_engines(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let instrument_id = crypto_perpetual_ethusdt.id;
+        let mut exchange = get_exchange(
+            Venue::new("BINANCE"),
+            AccountType::Margin,
+            BookType::L2_MBP,
+            None,
+            None,
+        );
+        exchange
+            .add_instrument(InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt))
+            .unwrap();
+
+        assert!(exchange.set_max_participation_rate(Some(1.5)).is_err());
+        exchange.set_max_participation_rate(Some(0.25)).unwrap();
+
+        let matching_engine = exchange.get_matching_engine(instrument_id).unwrap();
+        assert_eq!(matching_engine.config.max_participation_rate, Some(0.25));
+    }
+
+    #[rstest]
+    fn test_support_iceberg_orders_applied_to_matching_engines(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let instrument_id = crypto_perpetual_ethusdt.id;
+        let mut exchange = get_exchange(
+            Venue::new("BINANCE"),
+            AccountType::Margin,
+            BookType::L2_MBP,
+            None,
+            None,
+        );
+        exchange
+            .add_instrument(InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt))
+            .unwrap();
+
+        exchange.set_support_iceberg_orders(true);
+
+        let matching_engine = exchange.get_matching_engine(instrument_id).unwrap();
+        assert!(matching_engine.config.support_iceberg_orders);
+    }
+
+    #[rstest]
+    fn test_commands_exceeding_rate_limit_rejected(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        // Rate limits are measured against the exchange clock
+        static CLOCK: LazyLock<AtomicTime> =
+            LazyLock::new(|| AtomicTime::new(false, UnixNanos::default()));
+        let instrument_id = crypto_perpetual_ethusdt.id;
+        let (mut exchange, handler) =
+            get_exchange_with_handler_and_clock(crypto_perpetual_ethusdt, &CLOCK);
+        exchange
+            .set_rate_limits(
+                None,
+                Some(RateLimit::new(2, 1_000)),
+                RateLimitAction::Reject,
+            )
+            .unwrap();
+        exchange.process(UnixNanos::from(1_000));
+
+        for client_order_id in ["O-1", "O-2", "O-3"] {
+            let order = limit_order(instrument_id, client_order_id);
+            exchange.process_trading_command(submit_order_command(order, UnixNanos::from(1_000)));
+        }
+        exchange.process(UnixNanos::from(2_000));
+        let order = limit_order(instrument_id, "O-4");
+        exchange.process_trading_command(submit_order_command(order, UnixNanos::from(2_000)));
+
+        let messages = get_saved_messages::<OrderEventAny>(handler);
+        assert_eq!(messages.len(), 4);
+        assert!(matches!(messages[0], OrderEventAny::Accepted(_)));
+        assert!(matches!(messages[1], OrderEventAny::Accepted(_)));
+        match &messages[2] {
+            OrderEventAny::Rejected(rejected) => {
+                assert_eq!(rejected.client_order_id, ClientOrderId::from("O-3"));
+                assert_eq!(rejected.reason, Ustr::from("Rate limit exceeded"));
+            }
+            event => panic!("Expected OrderRejected event, was {event:?}"),
+        }
+        assert!(matches!(messages[3], OrderEventAny::Accepted(_)));
+    }
+
+    #[rstest]
+    fn test_orders_exceeding_rate_limit_delayed(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        static CLOCK: LazyLock<AtomicTime> =
+            LazyLock::new(|| AtomicTime::new(false, UnixNanos::default()));
+        let instrument_id = crypto_perpetual_ethusdt.id;
+        let (mut exchange, handler) =
+            get_exchange_with_handler_and_clock(crypto_perpetual_ethusdt, &CLOCK);
+        exchange
+            .set_rate_limits(Some(RateLimit::new(1, 1_000)), None, RateLimitAction::Delay)
+            .unwrap();
+        exchange.process(UnixNanos::from(1_000));
+
+        for client_order_id in ["O-1", "O-2", "O-3"] {
+            let order = limit_order(instrument_id, client_order_id);
+            exchange.process_trading_command(submit_order_command(order, UnixNanos::from(1_000)));
+        }
+
+        assert_eq!(exchange.throttled_count(), 2);
+        assert!(exchange.has_pending_commands());
+        assert_eq!(
+            get_saved_messages::<OrderEventAny>(handler.clone()).len(),
+            1
+        );
+
+        exchange.process(UnixNanos::from(2_000));
+
+        assert_eq!(exchange.throttled_count(), 1);
+        assert_eq!(
+            get_saved_messages::<OrderEventAny>(handler.clone()).len(),
+            2
+        );
+
+        exchange.process(UnixNanos::from(3_000));
+
+        let accepted: Vec<ClientOrderId> = get_saved_messages::<OrderEventAny>(handler)
+            .iter()
+            .map(|event| match event {
+                OrderEventAny::Accepted(accepted) => accepted.client_order_id,
+                event => panic!("Expected OrderAccepted event, was {event:?}"),
+            })
+            .collect();
+        assert_eq!(
+            accepted,
+            vec![
+                ClientOrderId::from("O-1"),
+                ClientOrderId::from("O-2"),
+                ClientOrderId::from("O-3"),
+            ]
+        );
+        assert_eq!(exchange.throttled_count(), 0);
+    }
+
+    #[rstest]
+    fn test_split_adjusts_working_orders_and_positions(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let instrument_id = instrument.id();
+        let mut msgbus = MessageBus::default();
+        let handler = get_message_saving_handler::<OrderEventAny>(None);
+        msgbus.register(msgbus.switchboard.exec_engine_process, handler.clone());
+        let cache = Rc::new(RefCell::new(get_cache_with_long_position(&instrument)));
+        let mut exchange = get_exchange(
+            Venue::new("BINANCE"),
+            AccountType::Margin,
+            BookType::L1_MBP,
+            Some(Rc::new(RefCell::new(msgbus))),
+            Some(cache.clone()),
+        );
+        exchange.add_instrument(instrument.clone()).unwrap();
+        let order = limit_order(instrument_id, "O-1");
+        exchange.process_trading_command(submit_order_command(order, UnixNanos::default()));
+
+        let split =
+            CorporateAction::split(instrument_id, 2, 1, UnixNanos::from(1), UnixNanos::from(1))
+                .unwrap();
+        exchange.process_corporate_action(&split).unwrap();
+
+        let messages = get_saved_messages::<OrderEventAny>(handler);
+        assert_eq!(messages.len(), 2);
+        match &messages[1] {
+            OrderEventAny::Updated(updated) => {
+                assert_eq!(updated.quantity, Quantity::from("2.000"));
+                assert_eq!(updated.price, Some(Price::from("500.00")));
+            }
+            event => panic!("Expected OrderUpdated event, was {event:?}"),
+        }
+        let open_orders = exchange.get_open_orders(Some(instrument_id));
+        assert_eq!(open_orders.len(), 1);
+        assert_eq!(
+            OrderAny::from(open_orders[0].clone()).price(),
+            Some(Price::from("500.00"))
+        );
+
+        let cache = cache.borrow();
+        let positions = cache.positions_open(None, Some(&instrument_id), None, None);
+        assert_eq!(positions[0].quantity, Quantity::from("4.000"));
+        assert_eq!(positions[0].avg_px_open, 500.0);
+    }
+
+    #[rstest]
+    fn test_dividend_paid_on_open_positions(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let mut msgbus = MessageBus::default();
+        let handler = get_message_saving_handler::<AccountState>(None);
+        msgbus.register(Ustr::from("Portfolio.update_account"), handler.clone());
+        let mut exchange = get_exchange(
+            Venue::new("BINANCE"),
+            AccountType::Margin,
+            BookType::L1_MBP,
+            Some(Rc::new(RefCell::new(msgbus))),
+            Some(Rc::new(RefCell::new(get_cache_with_long_position(
+                &instrument,
+            )))),
+        );
+        exchange.add_instrument(instrument.clone()).unwrap();
+
+        let dividend = CorporateAction::dividend(
+            instrument.id(),
+            Money::from("0.5 USDT"),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        )
+        .unwrap();
+        exchange.process_corporate_action(&dividend).unwrap();
+
+        let messages = get_saved_messages::<AccountState>(handler);
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].balances[0].total, Money::from("1001 USDT"));
+    }
+
+    #[rstest]
+    fn test_symbol_change_lists_instrument_under_new_id(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument_id = crypto_perpetual_ethusdt.id;
+        let new_instrument_id = InstrumentId::from("ETHUSD-PERP.BINANCE");
+        let (mut exchange, handler) = get_exchange_with_handler(crypto_perpetual_ethusdt);
+        let order = limit_order(instrument_id, "O-1");
+        exchange.process_trading_command(submit_order_command(order, UnixNanos::default()));
+
+        let symbol_change = CorporateAction::symbol_change(
+            instrument_id,
+            new_instrument_id,
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        )
+        .unwrap();
+        exchange.process_corporate_action(&symbol_change).unwrap();
+
+        let messages = get_saved_messages::<OrderEventAny>(handler);
+        assert_eq!(messages.len(), 2);
+        assert!(matches!(messages[1], OrderEventAny::Canceled(_)));
+        let matching_engine = exchange.get_matching_engine(new_instrument_id).unwrap();
+        assert_eq!(matching_engine.instrument.id(), new_instrument_id);
+        assert_eq!(
+            matching_engine.instrument.raw_symbol(),
+            new_instrument_id.symbol
+        );
+        assert!(exchange.get_open_orders(Some(instrument_id)).is_empty());
+    }
+
+    #[rstest]
+    fn test_corporate_action_for_unknown_instrument_fails(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let (mut exchange, _) = get_exchange_with_handler(crypto_perpetual_ethusdt);
+        let split = CorporateAction::split(
+            InstrumentId::from("BTCUSDT-PERP.BINANCE"),
+            2,
+            1,
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        )
+        .unwrap();
+
+        assert!(exchange.process_corporate_action(&split).is_err());
+    }
+
+    #[rstest]
+    fn test_restore_replaces_cached_positions(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        static CLOCK: LazyLock<AtomicTime> =
+            LazyLock::new(|| AtomicTime::new(false, UnixNanos::default()));
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let instrument_id = instrument.id();
+        let cache = Rc::new(RefCell::new(get_cache_with_long_position(&instrument)));
+        let mut exchange = get_exchange_with_clock(
+            Venue::new("BINANCE"),
+            AccountType::Margin,
+            BookType::L1_MBP,
+            None,
+            Some(cache.clone()),
+            &CLOCK,
+        );
+        exchange.add_instrument(instrument.clone()).unwrap();
+        let checkpoint = exchange.checkpoint();
+        let split =
+            CorporateAction::split(instrument_id, 2, 1, UnixNanos::from(1), UnixNanos::from(1))
+                .unwrap();
+        exchange.process_corporate_action(&split).unwrap();
+
+        exchange.restore(&checkpoint).unwrap();
+
+        let cache = cache.borrow();
+        let positions = cache.positions_open(None, Some(&instrument_id), None, None);
+        assert_eq!(positions[0].quantity, Quantity::from("2.000"));
+        assert_eq!(positions[0].avg_px_open, 1000.0);
+    }
+
+    #[rstest]
+    fn test_futures_cash_settled_at_expiry() {
+        let instrument = InstrumentAny::FuturesContract(futures_contract_es(None, None));
+        let instrument_id = instrument.id();
+        let expiration = instrument.expiration_ns().unwrap();
+        let (mut exchange, cache, account_handler, expiry_handler) =
+            get_exchange_with_long_contract(&instrument, "4500.00");
+        exchange.set_settlement_price(instrument_id, Price::from("4510.00"));
+
+        exchange.process(expiration - 1);
+        assert!(!exchange.is_expired(instrument_id));
+
+        exchange.process(expiration);
+
+        assert!(exchange.is_expired(instrument_id));
+        let account_states = get_saved_messages::<AccountState>(account_handler);
+        assert_eq!(account_states.len(), 1);
+        assert_eq!(account_states[0].balances[0].total, Money::from("1010 USD"));
+        let cache = cache.borrow();
+        assert!(cache
+            .positions_open(None, Some(&instrument_id), None, None)
+            .is_empty());
+        let expiries = get_saved_messages::<InstrumentExpiry>(expiry_handler);
+        assert_eq!(expiries.len(), 1);
+        assert_eq!(expiries[0].settlement_price, Some(Price::from("4510.00")));
+        assert!(expiries[0].exercised);
+        assert_eq!(expiries[0].settlement_type, SettlementType::Cash);
+        assert_eq!(expiries[0].position_ids.len(), 1);
+    }
+
+    #[rstest]
+    fn test_itm_call_physically_delivers_underlying(option_contract_appl: OptionContract) {
+        let instrument = InstrumentAny::OptionContract(option_contract_appl);
+        let instrument_id = instrument.id();
+        let underlying =
+            InstrumentAny::Equity(equity_aapl()).with_id(InstrumentId::from("AAPL.OPRA"));
+        let (mut exchange, cache, account_handler, expiry_handler) =
+            get_exchange_with_long_contract(&instrument, "5.00");
+        exchange.add_instrument(underlying.clone()).unwrap();
+        exchange.set_settlement_type(SettlementType::Physical);
+        exchange.process_quote_tick(&QuoteTick::new(
+            underlying.id(),
+            Price::from("159.99"),
+            Price::from("160.01"),
+            Quantity::from(100),
+            Quantity::from(100),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        ));
+
+        exchange.process(instrument.expiration_ns().unwrap());
+
+        // The premium is lost as the call is exercised into the underlying at the strike
+        let account_states = get_saved_messages::<AccountState>(account_handler);
+        assert_eq!(account_states[0].balances[0].total, Money::from("995 USD"));
+        let cache = cache.borrow();
+        assert!(cache
+            .positions_open(None, Some(&instrument_id), None, None)
+            .is_empty());
+        let delivered = cache.positions_open(None, Some(&underlying.id()), None, None);
+        assert_eq!(delivered.len(), 1);
+        assert!(delivered[0].is_long());
+        assert_eq!(delivered[0].quantity, Quantity::from(1));
+        assert_eq!(delivered[0].avg_px_open, 149.0);
+        let expiries = get_saved_messages::<InstrumentExpiry>(expiry_handler);
+        assert_eq!(expiries[0].settlement_price, Some(Price::from("160.00")));
+        assert!(expiries[0].exercised);
+        assert_eq!(expiries[0].settlement_type, SettlementType::Physical);
+        assert_eq!(expiries[0].delivered_instrument_id, Some(underlying.id()));
+    }
+
+    #[rstest]
+    fn test_otm_option_expires_worthless(option_contract_appl: OptionContract) {
+        let instrument = InstrumentAny::OptionContract(option_contract_appl);
+        let instrument_id = instrument.id();
+        let (mut exchange, cache, account_handler, expiry_handler) =
+            get_exchange_with_long_contract(&instrument, "5.00");
+        exchange.set_settlement_price(instrument_id, Price::from("140.00"));
+
+        exchange.process(instrument.expiration_ns().unwrap());
+
+        let account_states = get_saved_messages::<AccountState>(account_handler);
+        assert_eq!(account_states[0].balances[0].total, Money::from("995 USD"));
+        assert!(cache
+            .borrow()
+            .positions_open(None, Some(&instrument_id), None, None)
+            .is_empty());
+        let expiries = get_saved_messages::<InstrumentExpiry>(expiry_handler);
+        assert!(!expiries[0].exercised);
+        assert_eq!(expiries[0].settlement_type, SettlementType::Cash);
+    }
+
+    #[rstest]
+    fn test_working_orders_expired_at_expiry() {
+        // Orders are only accepted while the contract is active on the exchange clock
+        static CLOCK: LazyLock<AtomicTime> =
+            LazyLock::new(|| AtomicTime::new(false, UnixNanos::default()));
+        let instrument = InstrumentAny::FuturesContract(futures_contract_es(None, None));
+        let instrument_id = instrument.id();
+        let expiration = instrument.expiration_ns().unwrap();
+        let mut msgbus = MessageBus::default();
+        let handler = get_message_saving_handler::<OrderEventAny>(None);
+        msgbus.register(msgbus.switchboard.exec_engine_process, handler.clone());
+        let mut exchange = get_exchange_with_clock(
+            instrument_id.venue,
+            AccountType::Margin,
+            BookType::L1_MBP,
+            Some(Rc::new(RefCell::new(msgbus))),
+            None,
+            &CLOCK,
+        );
+        exchange.add_instrument(instrument).unwrap();
+        CLOCK.set_time(expiration - 1);
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_id)
+            .side(OrderSide::Buy)
+            .price(Price::from("4000.00"))
+            .quantity(Quantity::from(1))
+            .build();
+        exchange.process_trading_command(submit_order_command(order, expiration - 1));
+
+        exchange.process(expiration);
+
+        let messages = get_saved_messages::<OrderEventAny>(handler);
+        assert_eq!(messages.len(), 2);
+        assert!(matches!(messages[0], OrderEventAny::Accepted(_)));
+        assert!(matches!(messages[1], OrderEventAny::Expired(_)));
+        assert!(exchange.get_open_orders(Some(instrument_id)).is_empty());
+    }
+
+    #[rstest]
+    fn test_spread_matched_against_implied_leg_book() {
+        // Orders are only accepted while the spread is active on the exchange clock
+        static CLOCK: LazyLock<AtomicTime> =
+            LazyLock::new(|| AtomicTime::new(false, UnixNanos::default()));
+        let spread = InstrumentAny::FuturesSpread(futures_spread_es());
+        let spread_id = spread.id();
+        let leg = |symbol: &str| {
+            InstrumentAny::FuturesContract(futures_contract_es(
+                spread.activation_ns(),
+                spread.expiration_ns(),
+            ))
+            .with_id(InstrumentId::from(symbol))
+        };
+        let (front, back) = (leg("ESM4.GLBX"), leg("ESU4.GLBX"));
+        let mut msgbus = MessageBus::default();
+        let handler = get_message_saving_handler::<OrderEventAny>(None);
+        msgbus.register(msgbus.switchboard.exec_engine_process, handler.clone());
+        let mut exchange = get_exchange_with_clock(
+            spread_id.venue,
+            AccountType::Margin,
+            BookType::L2_MBP,
+            Some(Rc::new(RefCell::new(msgbus))),
+            None,
+            &CLOCK,
+        );
+        let ts_init = spread.activation_ns().unwrap() + 1;
+        CLOCK.set_time(ts_init);
+        exchange.add_instrument(front.clone()).unwrap();
+        exchange.add_instrument(back.clone()).unwrap();
+        exchange
+            .add_spread(
+                spread,
+                vec![
+                    SpreadLeg::new(front.id(), 1).unwrap(),
+                    SpreadLeg::new(back.id(), -1).unwrap(),
+                ],
+            )
+            .unwrap();
+        let depth = |instrument_id: InstrumentId, side: OrderSide, price: &str| {
+            OrderBookDelta::new(
+                instrument_id,
+                BookAction::Add,
+                BookOrder::new(side, Price::from(price), Quantity::from(10), 1),
+                0,
+                0,
+                ts_init,
+                ts_init,
+            )
+        };
+
+        exchange.process_order_book_delta(depth(front.id(), OrderSide::Buy, "5050.00"));
+        exchange.process_order_book_delta(depth(front.id(), OrderSide::Sell, "5050.25"));
+        exchange.process_order_book_delta(depth(back.id(), OrderSide::Buy, "5000.00"));
+        exchange.process_order_book_delta(depth(back.id(), OrderSide::Sell, "5000.25"));
+
+        assert_eq!(
+            exchange.best_bid_price(spread_id),
+            Some(Price::from("49.75"))
+        );
+        assert_eq!(
+            exchange.best_ask_price(spread_id),
+            Some(Price::from("50.25"))
+        );
+
+        let book = exchange.get_book(spread_id).unwrap();
+        assert_eq!(book.book_type, BookType::L1_MBP);
+        assert_eq!(book.best_bid_size(), Some(Quantity::from(10)));
+
+        exchange.process_order_book_delta(depth(back.id(), OrderSide::Buy, "5000.50"));
+        assert_eq!(
+            exchange.best_bid_price(spread_id),
+            Some(Price::from("49.75"))
+        );
+        assert_eq!(
+            exchange.best_ask_price(spread_id),
+            Some(Price::from("49.75"))
+        );
+
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(spread_id)
+            .side(OrderSide::Sell)
+            .price(Price::from("50.00"))
+            .quantity(Quantity::from(1))
+            .time_in_force(TimeInForce::Gtd)
+            .expire_time(ts_init + 1_000_000_000)
+            .build();
+        exchange.process_trading_command(submit_order_command(order, ts_init));
+
+        let messages = get_saved_messages::<OrderEventAny>(handler);
+        assert_eq!(messages.len(), 1);
+        assert!(matches!(messages[0], OrderEventAny::Accepted(_)));
+        assert_eq!(exchange.get_open_orders(Some(spread_id)).len(), 1);
+    }
 }
@@ -0,0 +1,140 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides expiry settlement of options and futures at the simulated venue.
+//!
+//! When a contract expires its working orders are expired, and open positions are settled at
+//! the settlement price, with options automatically exercised (or assigned) when in the money.
+//! An [`InstrumentExpiry`] event is published for each expiry, so strategies can be tested
+//! around expiration.
+
+use std::fmt::Display;
+
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    enums::OptionKind,
+    identifiers::{InstrumentId, PositionId},
+    types::Price,
+};
+use ustr::Ustr;
+
+/// The method of settling positions in expiring contracts.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SettlementType {
+    /// Positions are closed at the settlement price (for options, their intrinsic value),
+    /// with the PnL paid in cash.
+    #[default]
+    Cash,
+    /// Positions are closed and exercised contracts are delivered into positions in the
+    /// underlying instrument, at the strike price for options and the settlement price for
+    /// futures.
+    Physical,
+}
+
+/// An event for the expiry of an instrument at the venue.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InstrumentExpiry {
+    /// The expired instrument ID.
+    pub instrument_id: InstrumentId,
+    /// The settlement price (for options, the price of the underlying), if known.
+    pub settlement_price: Option<Price>,
+    /// If the contract was exercised, being always for futures and when in the money for
+    /// options.
+    pub exercised: bool,
+    /// The settlement type applied.
+    pub settlement_type: SettlementType,
+    /// The underlying instrument delivered into, for physical settlement.
+    pub delivered_instrument_id: Option<InstrumentId>,
+    /// The IDs of the positions settled.
+    pub position_ids: Vec<PositionId>,
+    /// UNIX timestamp (nanoseconds) when the instrument expired.
+    pub ts_event: UnixNanos,
+}
+
+impl Display for InstrumentExpiry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(instrument_id={}, settlement_price={}, exercised={}, settlement_type={:?}, positions={})",
+            stringify!(InstrumentExpiry),
+            self.instrument_id,
+            self.settlement_price
+                .map_or_else(|| "None".to_string(), |px| px.to_string()),
+            self.exercised,
+            self.settlement_type,
+            self.position_ids.len(),
+        )
+    }
+}
+
+/// Returns the message bus topic on which expiry events are published for the instrument.
+#[must_use]
+pub fn get_expiry_topic(instrument_id: InstrumentId) -> Ustr {
+    Ustr::from(&format!(
+        "events.expiry.{}.{}",
+        instrument_id.venue, instrument_id.symbol
+    ))
+}
+
+/// Returns the intrinsic value of an option of `option_kind` struck at `strike_price` when the
+/// underlying is at `underlying_price`, with the precision of the strike price.
+#[must_use]
+pub fn intrinsic_value(
+    option_kind: OptionKind,
+    strike_price: Price,
+    underlying_price: Price,
+) -> Price {
+    let value = match option_kind {
+        OptionKind::Call => underlying_price.as_f64() - strike_price.as_f64(),
+        OptionKind::Put => strike_price.as_f64() - underlying_price.as_f64(),
+    };
+    Price::new(value.max(0.0), strike_price.precision)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(OptionKind::Call, "160.00", "11.00")]
+    #[case(OptionKind::Call, "140.00", "0.00")]
+    #[case(OptionKind::Put, "140.00", "9.00")]
+    #[case(OptionKind::Put, "160.00", "0.00")]
+    fn test_intrinsic_value(
+        #[case] option_kind: OptionKind,
+        #[case] underlying_price: &str,
+        #[case] expected: &str,
+    ) {
+        let value = intrinsic_value(
+            option_kind,
+            Price::from("149.00"),
+            Price::from(underlying_price),
+        );
+
+        assert_eq!(value, Price::from(expected));
+    }
+
+    #[rstest]
+    fn test_get_expiry_topic() {
+        let topic = get_expiry_topic(InstrumentId::from("ESZ21.GLBX"));
+
+        assert_eq!(topic, Ustr::from("events.expiry.GLBX.ESZ21"));
+    }
+}
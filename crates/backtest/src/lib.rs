@@ -35,11 +35,20 @@
 // Uncomment once we've added trivial `Debug` impls everywhere
 // #![warn(missing_debug_implementations)]
 
+pub mod audit;
+pub mod batch;
+pub mod book_validation;
+pub mod calendar;
+pub mod checkpoint;
+pub mod circuit_breaker;
 pub mod data_client;
+pub mod data_source;
 pub mod engine;
 pub mod exchange;
+pub mod expiry;
 pub mod modules;
 pub mod runner;
+pub mod throttle;
 
 #[cfg(feature = "ffi")]
 pub mod ffi;
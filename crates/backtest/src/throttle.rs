@@ -0,0 +1,160 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides message rate limits for simulated venues, mirroring the order and message rate
+//! limits of real venues.
+
+use std::collections::VecDeque;
+
+use nautilus_common::throttler::RateLimit;
+use nautilus_core::{correctness::check_positive_u64, UnixNanos};
+
+/// The action taken by a simulated venue for a command exceeding its rate limits.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RateLimitAction {
+    /// The command is rejected.
+    #[default]
+    Reject,
+    /// The command is delayed until it is within the rate limits, behind any commands
+    /// already delayed.
+    Delay,
+}
+
+/// Tracks the messages received by a venue against a rate limit over a rolling interval.
+#[derive(Clone, Debug)]
+pub struct MessageThrottle {
+    rate_limit: RateLimit,
+    timestamps: VecDeque<UnixNanos>,
+}
+
+impl MessageThrottle {
+    /// Creates a new [`MessageThrottle`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the limit or interval of the `rate_limit` is zero.
+    pub fn new(rate_limit: RateLimit) -> anyhow::Result<Self> {
+        check_positive_u64(rate_limit.limit as u64, "rate_limit.limit")?;
+        check_positive_u64(rate_limit.interval_ns, "rate_limit.interval_ns")?;
+
+        Ok(Self {
+            rate_limit,
+            timestamps: VecDeque::new(),
+        })
+    }
+
+    /// Returns the rate limit of the throttle.
+    #[must_use]
+    pub const fn rate_limit(&self) -> &RateLimit {
+        &self.rate_limit
+    }
+
+    /// Returns the earliest time from `ts` at which `count` messages are within the rate
+    /// limit, and after any messages already recorded, or `None` if `count` exceeds the limit.
+    #[must_use]
+    pub fn next_available(&self, ts: UnixNanos, count: usize) -> Option<UnixNanos> {
+        if count > self.rate_limit.limit {
+            return None;
+        }
+        if count == 0 {
+            return Some(ts);
+        }
+
+        let ts = self.timestamps.back().map_or(ts, |last| ts.max(*last));
+        let in_window: Vec<&UnixNanos> = self
+            .timestamps
+            .iter()
+            .filter(|recorded| self.is_in_window(**recorded, ts))
+            .collect();
+        let excess = (in_window.len() + count).saturating_sub(self.rate_limit.limit);
+        if excess == 0 {
+            return Some(ts);
+        }
+
+        // Available once enough of the messages in the window have aged out of it
+        let expiring = in_window[excess - 1].as_u64();
+        Some(UnixNanos::from(expiring + self.rate_limit.interval_ns))
+    }
+
+    /// Records `count` messages received at `ts`, which must not be before the messages
+    /// already recorded.
+    pub fn record(&mut self, ts: UnixNanos, count: usize) {
+        while self
+            .timestamps
+            .front()
+            .is_some_and(|recorded| !self.is_in_window(*recorded, ts))
+        {
+            self.timestamps.pop_front();
+        }
+        self.timestamps.extend(std::iter::repeat_n(ts, count));
+    }
+
+    /// Resets the throttle, clearing the recorded messages.
+    pub fn reset(&mut self) {
+        self.timestamps.clear();
+    }
+
+    fn is_in_window(&self, recorded: UnixNanos, ts: UnixNanos) -> bool {
+        recorded.as_u64() + self.rate_limit.interval_ns > ts.as_u64()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_invalid_rate_limit() {
+        assert!(MessageThrottle::new(RateLimit::new(0, 1_000)).is_err());
+        assert!(MessageThrottle::new(RateLimit::new(10, 0)).is_err());
+    }
+
+    #[rstest]
+    #[case(100, 1, Some(100))]
+    #[case(100, 2, Some(1_000))]
+    #[case(100, 3, Some(1_100))]
+    #[case(1_000, 2, Some(1_000))]
+    #[case(1_100, 3, Some(1_100))]
+    #[case(100, 4, None)]
+    fn test_next_available(#[case] ts: u64, #[case] count: usize, #[case] expected: Option<u64>) {
+        let mut throttle = MessageThrottle::new(RateLimit::new(3, 1_000)).unwrap();
+        throttle.record(UnixNanos::from(0), 1);
+        throttle.record(UnixNanos::from(100), 1);
+
+        assert_eq!(
+            throttle.next_available(UnixNanos::from(ts), count),
+            expected.map(UnixNanos::from)
+        );
+    }
+
+    #[rstest]
+    fn test_next_available_after_delayed_messages() {
+        let mut throttle = MessageThrottle::new(RateLimit::new(1, 1_000)).unwrap();
+        throttle.record(UnixNanos::from(0), 1);
+        let delayed = throttle.next_available(UnixNanos::from(10), 1).unwrap();
+        throttle.record(delayed, 1);
+
+        assert_eq!(delayed, UnixNanos::from(1_000));
+        assert_eq!(
+            throttle.next_available(UnixNanos::from(20), 1),
+            Some(UnixNanos::from(2_000))
+        );
+    }
+}
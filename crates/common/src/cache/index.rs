@@ -19,6 +19,7 @@ use nautilus_model::identifiers::{
     AccountId, ClientId, ClientOrderId, ComponentId, ExecAlgorithmId, InstrumentId, PositionId,
     StrategyId, Venue, VenueOrderId,
 };
+use ustr::Ustr;
 
 /// A key-value lookup index for a `Cache`.
 #[derive(Debug)]
@@ -39,6 +40,7 @@ pub struct CacheIndex {
     pub(crate) strategy_positions: HashMap<StrategyId, HashSet<PositionId>>,
     pub(crate) exec_algorithm_orders: HashMap<ExecAlgorithmId, HashSet<ClientOrderId>>,
     pub(crate) exec_spawn_orders: HashMap<ClientOrderId, HashSet<ClientOrderId>>,
+    pub(crate) metadata_orders: HashMap<(Ustr, Ustr), HashSet<ClientOrderId>>,
     pub(crate) orders: HashSet<ClientOrderId>,
     pub(crate) orders_open: HashSet<ClientOrderId>,
     pub(crate) orders_closed: HashSet<ClientOrderId>,
@@ -73,6 +75,7 @@ impl Default for CacheIndex {
             strategy_positions: HashMap::new(),
             exec_algorithm_orders: HashMap::new(),
             exec_spawn_orders: HashMap::new(),
+            metadata_orders: HashMap::new(),
             orders: HashSet::new(),
             orders_open: HashSet::new(),
             orders_closed: HashSet::new(),
@@ -108,6 +111,7 @@ impl CacheIndex {
         self.strategy_positions.clear();
         self.exec_algorithm_orders.clear();
         self.exec_spawn_orders.clear();
+        self.metadata_orders.clear();
         self.orders.clear();
         self.orders_open.clear();
         self.orders_closed.clear();
@@ -41,15 +41,15 @@ use nautilus_core::{
 };
 use nautilus_model::{
     accounts::AccountAny,
-    data::{Bar, BarType, QuoteTick, TradeTick},
+    data::{Bar, BarType, FundingRateUpdate, QuoteTick, TradeTick},
     enums::{AggregationSource, OmsType, OrderSide, PositionSide, PriceType, TriggerType},
     identifiers::{
         AccountId, ClientId, ClientOrderId, ComponentId, ExecAlgorithmId, InstrumentId,
         OrderListId, PositionId, StrategyId, Venue, VenueOrderId,
     },
-    instruments::{InstrumentAny, SyntheticInstrument},
+    instruments::{InstrumentAny, OptionChain, SyntheticInstrument},
     orderbook::OrderBook,
-    orders::{OrderAny, OrderList},
+    orders::{ExecSpawnProgress, OrderAny, OrderLatency, OrderList},
     position::Position,
     types::{Currency, Money, Price, Quantity},
 };
@@ -67,6 +67,7 @@ pub struct Cache {
     trades: HashMap<InstrumentId, VecDeque<TradeTick>>,
     mark_prices: HashMap<InstrumentId, Price>,
     mark_xrates: HashMap<(Currency, Currency), f64>,
+    funding_rates: HashMap<InstrumentId, FundingRateUpdate>,
     books: HashMap<InstrumentId, OrderBook>,
     bars: HashMap<BarType, VecDeque<Bar>>,
     currencies: HashMap<Ustr, Currency>,
@@ -75,6 +76,7 @@ pub struct Cache {
     accounts: HashMap<AccountId, AccountAny>,
     orders: HashMap<ClientOrderId, OrderAny>,
     order_lists: HashMap<OrderListId, OrderList>,
+    order_latencies: HashMap<ClientOrderId, OrderLatency>,
     positions: HashMap<PositionId, Position>,
     position_snapshots: HashMap<PositionId, Bytes>,
 }
@@ -100,6 +102,7 @@ impl Cache {
             general: HashMap::new(),
             mark_prices: HashMap::new(),
             mark_xrates: HashMap::new(),
+            funding_rates: HashMap::new(),
             quotes: HashMap::new(),
             trades: HashMap::new(),
             books: HashMap::new(),
@@ -110,6 +113,7 @@ impl Cache {
             accounts: HashMap::new(),
             orders: HashMap::new(),
             order_lists: HashMap::new(),
+            order_latencies: HashMap::new(),
             positions: HashMap::new(),
             position_snapshots: HashMap::new(),
         }
@@ -302,35 +306,46 @@ impl Cache {
                     .insert(*client_order_id);
             }
 
-            // 9: Build index.orders -> {ClientOrderId}
+            // 9: Build index.metadata_orders -> {(Ustr, Ustr), {ClientOrderId}}
+            if let Some(metadata) = order.metadata() {
+                for (key, value) in metadata {
+                    self.index
+                        .metadata_orders
+                        .entry((key, value))
+                        .or_default()
+                        .insert(*client_order_id);
+                }
+            }
+
+            // 10: Build index.orders -> {ClientOrderId}
             self.index.orders.insert(*client_order_id);
 
-            // 10: Build index.orders_open -> {ClientOrderId}
+            // 11: Build index.orders_open -> {ClientOrderId}
             if order.is_open() {
                 self.index.orders_open.insert(*client_order_id);
             }
 
-            // 11: Build index.orders_closed -> {ClientOrderId}
+            // 12: Build index.orders_closed -> {ClientOrderId}
             if order.is_closed() {
                 self.index.orders_closed.insert(*client_order_id);
             }
 
-            // 12: Build index.orders_emulated -> {ClientOrderId}
+            // 13: Build index.orders_emulated -> {ClientOrderId}
             if let Some(emulation_trigger) = order.emulation_trigger() {
                 if emulation_trigger != TriggerType::NoTrigger && !order.is_closed() {
                     self.index.orders_emulated.insert(*client_order_id);
                 }
             }
 
-            // 13: Build index.orders_inflight -> {ClientOrderId}
+            // 14: Build index.orders_inflight -> {ClientOrderId}
             if order.is_inflight() {
                 self.index.orders_inflight.insert(*client_order_id);
             }
 
-            // 14: Build index.strategies -> {StrategyId}
+            // 15: Build index.strategies -> {StrategyId}
             self.index.strategies.insert(strategy_id);
 
-            // 15: Build index.strategies -> {ExecAlgorithmId}
+            // 16: Build index.strategies -> {ExecAlgorithmId}
             if let Some(exec_algorithm_id) = order.exec_algorithm_id() {
                 self.index.exec_algorithms.insert(exec_algorithm_id);
             }
@@ -388,7 +403,7 @@ impl Cache {
                 self.index.positions_closed.insert(*position_id);
             }
 
-            // 9: Build index.strategies -> {StrategyId}
+            // 10: Build index.strategies -> {StrategyId}
             self.index.strategies.insert(strategy_id);
         }
     }
@@ -799,6 +814,7 @@ impl Cache {
         self.general.clear();
         self.mark_prices.clear();
         self.mark_xrates.clear();
+        self.funding_rates.clear();
         self.quotes.clear();
         self.trades.clear();
         self.books.clear();
@@ -809,6 +825,7 @@ impl Cache {
         self.accounts.clear();
         self.orders.clear();
         self.order_lists.clear();
+        self.order_latencies.clear();
         self.positions.clear();
         self.position_snapshots.clear();
 
@@ -869,6 +886,14 @@ impl Cache {
         self.mark_prices.insert(*instrument_id, price);
     }
 
+    /// Adds the given funding rate `update` to the cache, replacing any previous update for
+    /// the instrument.
+    pub fn add_funding_rate(&mut self, update: FundingRateUpdate) {
+        log::debug!("Adding `FundingRateUpdate` {}", update.instrument_id);
+
+        self.funding_rates.insert(update.instrument_id, update);
+    }
+
     /// Adds the given `quote` tick to the cache.
     pub fn add_quote(&mut self, quote: QuoteTick) -> anyhow::Result<()> {
         log::debug!("Adding `QuoteTick` {}", quote.instrument_id);
@@ -1186,6 +1211,17 @@ impl Cache {
                 .insert(client_order_id);
         }
 
+        // Update metadata -> orders index
+        if let Some(metadata) = order.metadata() {
+            for (key, value) in metadata {
+                self.index
+                    .metadata_orders
+                    .entry((key, value))
+                    .or_default()
+                    .insert(client_order_id);
+            }
+        }
+
         // Update emulation index
         match order.emulation_trigger() {
             Some(_) => {
@@ -1362,6 +1398,11 @@ impl Cache {
             // }
         }
 
+        // Record the lifecycle hop reached by the order
+        if let Some(latency) = self.order_latencies.get_mut(&client_order_id) {
+            latency.apply(order.last_event());
+        }
+
         // update the order in the cache
         self.orders.insert(client_order_id, order.clone());
 
@@ -1375,6 +1416,15 @@ impl Cache {
             .insert(order.client_order_id());
     }
 
+    /// Adds the given order `latency` to the cache, to be updated with the timestamps of the
+    /// order lifecycle as the order is updated.
+    pub fn add_order_latency(&mut self, latency: OrderLatency) {
+        log::debug!("Adding `OrderLatency` {}", latency.client_order_id);
+
+        self.order_latencies
+            .insert(latency.client_order_id, latency);
+    }
+
     /// Updates the given `position` in the cache.
     pub fn update_position(&mut self, position: &Position) -> anyhow::Result<()> {
         // Update open/closed state
@@ -2040,6 +2090,29 @@ impl Cache {
         self.order_lists.contains_key(order_list_id)
     }
 
+    /// Gets a reference to the latency breakdown of the order with the given `client_order_id`
+    /// (if tracked).
+    #[must_use]
+    pub fn order_latency(&self, client_order_id: &ClientOrderId) -> Option<&OrderLatency> {
+        self.order_latencies.get(client_order_id)
+    }
+
+    /// Returns references to the latency breakdowns of all tracked orders, optionally filtered
+    /// by `strategy_id`.
+    #[must_use]
+    pub fn order_latencies(&self, strategy_id: Option<&StrategyId>) -> Vec<&OrderLatency> {
+        let mut latencies = self
+            .order_latencies
+            .values()
+            .collect::<Vec<&OrderLatency>>();
+
+        if let Some(strategy_id) = strategy_id {
+            latencies.retain(|latency| &latency.strategy_id == strategy_id);
+        }
+
+        latencies
+    }
+
     // -- EXEC ALGORITHM QUERIES ------------------------------------------------------------------
 
     /// Returns references to all orders associated with the given `exec_algorithm_id` matching the given
@@ -2093,13 +2166,13 @@ impl Cache {
         let mut total_quantity: Option<Quantity> = None;
 
         for spawn_order in exec_spawn_orders {
-            if !active_only || !spawn_order.is_closed() {
-                if let Some(mut total_quantity) = total_quantity {
-                    total_quantity += spawn_order.quantity();
-                }
-            } else {
-                total_quantity = Some(spawn_order.quantity());
+            if active_only && spawn_order.is_closed() {
+                continue;
             }
+            total_quantity = Some(match total_quantity {
+                Some(total_quantity) => total_quantity + spawn_order.quantity(),
+                None => spawn_order.quantity(),
+            });
         }
 
         total_quantity
@@ -2117,13 +2190,13 @@ impl Cache {
         let mut total_quantity: Option<Quantity> = None;
 
         for spawn_order in exec_spawn_orders {
-            if !active_only || !spawn_order.is_closed() {
-                if let Some(mut total_quantity) = total_quantity {
-                    total_quantity += spawn_order.filled_qty();
-                }
-            } else {
-                total_quantity = Some(spawn_order.filled_qty());
+            if active_only && spawn_order.is_closed() {
+                continue;
             }
+            total_quantity = Some(match total_quantity {
+                Some(total_quantity) => total_quantity + spawn_order.filled_qty(),
+                None => spawn_order.filled_qty(),
+            });
         }
 
         total_quantity
@@ -2141,18 +2214,69 @@ impl Cache {
         let mut total_quantity: Option<Quantity> = None;
 
         for spawn_order in exec_spawn_orders {
-            if !active_only || !spawn_order.is_closed() {
-                if let Some(mut total_quantity) = total_quantity {
-                    total_quantity += spawn_order.leaves_qty();
-                }
-            } else {
-                total_quantity = Some(spawn_order.leaves_qty());
+            if active_only && spawn_order.is_closed() {
+                continue;
             }
+            total_quantity = Some(match total_quantity {
+                Some(total_quantity) => total_quantity + spawn_order.leaves_qty(),
+                None => spawn_order.leaves_qty(),
+            });
         }
 
         total_quantity
     }
 
+    /// Returns the execution progress of the primary order `exec_spawn_id`, aggregated over
+    /// the child orders spawned from it (if any orders are found).
+    #[must_use]
+    pub fn exec_spawn_progress(&self, exec_spawn_id: &ClientOrderId) -> Option<ExecSpawnProgress> {
+        let mut orders = self.orders_for_exec_spawn(exec_spawn_id);
+        if !orders
+            .iter()
+            .any(|order| order.client_order_id() == *exec_spawn_id)
+        {
+            // The primary order may be cached without an execution spawn ID
+            orders.extend(self.order(exec_spawn_id));
+        }
+        ExecSpawnProgress::from_orders(*exec_spawn_id, &orders)
+    }
+
+    // -- METADATA QUERIES ------------------------------------------------------------------------
+
+    /// Returns the client order IDs of all orders with the given metadata `key` set to `value`.
+    #[must_use]
+    pub fn client_order_ids_for_metadata(&self, key: &str, value: &str) -> HashSet<ClientOrderId> {
+        self.index
+            .metadata_orders
+            .get(&(Ustr::from(key), Ustr::from(value)))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Returns references to all orders with the given metadata `key` set to `value`, matching
+    /// the given optional filter parameters.
+    ///
+    /// Metadata is indexed when an order is added to the cache, so it should be set while the
+    /// order is still initialized and before it is added.
+    #[must_use]
+    pub fn orders_for_metadata(
+        &self,
+        key: &str,
+        value: &str,
+        venue: Option<&Venue>,
+        instrument_id: Option<&InstrumentId>,
+        strategy_id: Option<&StrategyId>,
+        side: Option<OrderSide>,
+    ) -> Vec<&OrderAny> {
+        let mut client_order_ids = self.client_order_ids_for_metadata(key, value);
+
+        if let Some(query) = self.build_order_query_filter_set(venue, instrument_id, strategy_id) {
+            client_order_ids.retain(|client_order_id| query.contains(client_order_id));
+        }
+
+        self.get_orders_for_ids(&client_order_ids, side)
+    }
+
     // -- POSITION QUERIES ------------------------------------------------------------------------
 
     /// Returns a reference to the position with the given `position_id` (if found).
@@ -2295,6 +2419,19 @@ impl Cache {
         Ok(self.general.get(key))
     }
 
+    /// Returns the general objects with keys starting with the `prefix`, sorted by key.
+    #[must_use]
+    pub fn get_prefixed(&self, prefix: &str) -> Vec<(&str, &Bytes)> {
+        let mut entries: Vec<(&str, &Bytes)> = self
+            .general
+            .iter()
+            .filter(|(key, _)| key.starts_with(prefix))
+            .map(|(key, value)| (key.as_str(), value))
+            .collect();
+        entries.sort_unstable_by_key(|(key, _)| *key);
+        entries
+    }
+
     // -- DATA QUERIES ----------------------------------------------------------------------------
 
     /// Returns the price for the given `instrument_id` and `price_type` (if found).
@@ -2369,6 +2506,12 @@ impl Cache {
             .and_then(|quotes| quotes.front())
     }
 
+    /// Gets a reference to the latest funding rate update for the given `instrument_id`.
+    #[must_use]
+    pub fn funding_rate(&self, instrument_id: &InstrumentId) -> Option<&FundingRateUpdate> {
+        self.funding_rates.get(instrument_id)
+    }
+
     /// Gets a refernece to the latest trade tick for the given `instrument_id`.
     #[must_use]
     pub fn trade(&self, instrument_id: &InstrumentId) -> Option<&TradeTick> {
@@ -2575,6 +2718,18 @@ impl Cache {
             .collect()
     }
 
+    /// Returns the option chain of the option contracts on the `underlying`, optionally for the
+    /// given `venue` only.
+    #[must_use]
+    pub fn option_chain(&self, underlying: &Ustr, venue: Option<&Venue>) -> OptionChain {
+        OptionChain::from_instruments(
+            *underlying,
+            self.instruments
+                .values()
+                .filter(|i| venue.is_none_or(|v| &i.id().venue == v)),
+        )
+    }
+
     /// Returns references to all bar types contained in the cache.
     #[must_use]
     pub fn bar_types(
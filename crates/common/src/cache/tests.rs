@@ -17,20 +17,29 @@
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashSet;
+
     use bytes::Bytes;
+    use indexmap::IndexMap;
+    use nautilus_core::UnixNanos;
     use nautilus_model::{
         accounts::AccountAny,
-        data::{Bar, QuoteTick, TradeTick},
+        data::{Bar, FundingRateUpdate, QuoteTick, TradeTick},
         enums::{BookType, OmsType, OrderSide, OrderStatus, OrderType, PriceType},
         events::{OrderAccepted, OrderEventAny, OrderRejected, OrderSubmitted},
-        identifiers::{AccountId, ClientOrderId, PositionId, Venue},
-        instruments::{stubs::*, CurrencyPair, InstrumentAny, SyntheticInstrument},
+        identifiers::{
+            AccountId, ClientOrderId, ExecAlgorithmId, InstrumentId, PositionId, StrategyId, Venue,
+            VenueOrderId,
+        },
+        instruments::{stubs::*, CurrencyPair, InstrumentAny, OptionContract, SyntheticInstrument},
         orderbook::OrderBook,
-        orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+        orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs, OrderLatency},
         position::Position,
         types::{Currency, Price, Quantity},
     };
     use rstest::{fixture, rstest};
+    use rust_decimal::Decimal;
+    use ustr::Ustr;
 
     use crate::cache::Cache;
 
@@ -341,6 +350,18 @@ mod tests {
         assert_eq!(result, Some(&value));
     }
 
+    #[rstest]
+    fn test_get_prefixed_general(mut cache: Cache) {
+        let value = Bytes::from_static(&[0_u8]);
+        cache.add("audit:2", value.clone()).unwrap();
+        cache.add("audit:1", value.clone()).unwrap();
+        cache.add("other", value.clone()).unwrap();
+
+        let result = cache.get_prefixed("audit:");
+
+        assert_eq!(result, vec![("audit:1", &value), ("audit:2", &value)]);
+    }
+
     #[rstest]
     fn test_orders_for_position(mut cache: Cache, audusd_sim: CurrencyPair) {
         let order = OrderTestBuilder::new(OrderType::Limit)
@@ -359,6 +380,161 @@ mod tests {
         assert_eq!(cache.orders_for_position(&position_id), vec![&order]);
     }
 
+    #[rstest]
+    fn test_exec_spawn_totals_and_progress(mut cache: Cache, audusd_sim: CurrencyPair) {
+        let exec_spawn_id = ClientOrderId::from("O-1");
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let build = |client_order_id: &str, quantity: u64| {
+            OrderTestBuilder::new(OrderType::Market)
+                .instrument_id(instrument.id())
+                .client_order_id(ClientOrderId::from(client_order_id))
+                .exec_algorithm_id(ExecAlgorithmId::from("TWAP"))
+                .exec_spawn_id(exec_spawn_id)
+                .side(OrderSide::Buy)
+                .quantity(Quantity::from(quantity))
+                .build()
+        };
+        let primary = build("O-1", 300_000);
+        let mut child = build("O-1-E1", 100_000);
+        let account_id = AccountId::from("SIM-001");
+        child
+            .apply(TestOrderEventStubs::order_submitted(&child, account_id))
+            .unwrap();
+        child
+            .apply(TestOrderEventStubs::order_accepted(
+                &child,
+                account_id,
+                VenueOrderId::from("V-1"),
+            ))
+            .unwrap();
+        let OrderEventAny::Filled(fill) = TestOrderEventStubs::order_filled(
+            &child,
+            &instrument,
+            None,
+            None,
+            Some(Price::from("1.00010")),
+            Some(Quantity::from(40_000)),
+            None,
+            None,
+            None,
+            None,
+        ) else {
+            unreachable!()
+        };
+        child.apply(OrderEventAny::PartiallyFilled(fill)).unwrap();
+        cache.add_order(primary, None, None, false).unwrap();
+        cache.add_order(child, None, None, false).unwrap();
+
+        assert_eq!(
+            cache.exec_spawn_total_quantity(&exec_spawn_id, false),
+            Some(Quantity::from(400_000))
+        );
+        assert_eq!(
+            cache.exec_spawn_total_filled_qty(&exec_spawn_id, true),
+            Some(Quantity::from(40_000))
+        );
+
+        let progress = cache.exec_spawn_progress(&exec_spawn_id).unwrap();
+        assert_eq!(progress.quantity, Quantity::from(300_000));
+        assert_eq!(progress.filled_qty, Quantity::from(40_000));
+        assert_eq!(progress.leaves_qty, Quantity::from(260_000));
+        assert_eq!(progress.avg_px, Some(1.0001));
+        assert_eq!(progress.open_child_count, 1);
+    }
+
+    #[rstest]
+    fn test_orders_for_metadata(mut cache: Cache, audusd_sim: CurrencyPair) {
+        let build = |client_order_id: &str, side: OrderSide, basket: &str| {
+            let mut order = OrderTestBuilder::new(OrderType::Market)
+                .instrument_id(audusd_sim.id)
+                .client_order_id(ClientOrderId::from(client_order_id))
+                .side(side)
+                .quantity(Quantity::from(100_000))
+                .build();
+            order
+                .set_metadata(IndexMap::from([(Ustr::from("basket"), Ustr::from(basket))]))
+                .unwrap();
+            order
+        };
+        cache
+            .add_order(build("O-1", OrderSide::Buy, "alpha1"), None, None, false)
+            .unwrap();
+        cache
+            .add_order(build("O-2", OrderSide::Sell, "alpha1"), None, None, false)
+            .unwrap();
+        cache
+            .add_order(build("O-3", OrderSide::Buy, "alpha2"), None, None, false)
+            .unwrap();
+
+        let orders = cache.orders_for_metadata("basket", "alpha1", None, None, None, None);
+        let buy_orders = cache.orders_for_metadata(
+            "basket",
+            "alpha1",
+            None,
+            Some(&audusd_sim.id),
+            None,
+            Some(OrderSide::Buy),
+        );
+
+        assert_eq!(orders.len(), 2);
+        assert_eq!(buy_orders.len(), 1);
+        assert_eq!(buy_orders[0].client_order_id(), ClientOrderId::from("O-1"));
+        assert_eq!(
+            cache.client_order_ids_for_metadata("basket", "alpha2"),
+            HashSet::from([ClientOrderId::from("O-3")])
+        );
+        assert!(cache
+            .orders_for_metadata("basket", "alpha3", None, None, None, None)
+            .is_empty());
+
+        cache.clear_index();
+        cache.build_index();
+        assert_eq!(
+            cache
+                .orders_for_metadata("basket", "alpha1", None, None, None, None)
+                .len(),
+            2
+        );
+    }
+
+    #[rstest]
+    fn test_order_latency_updated_with_order(mut cache: Cache, audusd_sim: CurrencyPair) {
+        let mut order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(audusd_sim.id)
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        let client_order_id = order.client_order_id();
+        cache.add_order(order.clone(), None, None, false).unwrap();
+        cache.add_order_latency(OrderLatency::new(
+            client_order_id,
+            order.strategy_id(),
+            order.instrument_id(),
+            UnixNanos::from(100),
+            UnixNanos::from(150),
+        ));
+
+        let submitted = OrderSubmitted {
+            ts_event: UnixNanos::from(200),
+            ..OrderSubmitted::default()
+        };
+        order.apply(OrderEventAny::Submitted(submitted)).unwrap();
+        cache.update_order(&order).unwrap();
+
+        let latency = cache.order_latency(&client_order_id).unwrap();
+        assert_eq!(latency.ts_sent, Some(UnixNanos::from(200)));
+        assert_eq!(latency.risk_latency(), 50);
+        assert_eq!(latency.send_latency(), Some(50));
+        assert_eq!(
+            cache.order_latencies(Some(&order.strategy_id())),
+            vec![latency]
+        );
+        assert!(cache
+            .order_latencies(Some(&StrategyId::from("S-002")))
+            .is_empty());
+    }
+
     #[rstest]
     #[tokio::test]
     async fn test_cache_positions_when_no_database(mut cache: Cache) {
@@ -467,6 +643,32 @@ mod tests {
         assert_eq!(result2, vec![&InstrumentAny::FuturesContract(esz1)]);
     }
 
+    #[rstest]
+    fn test_option_chain(mut cache: Cache, option_contract_appl: OptionContract) {
+        let esz1 = futures_contract_es(None, None);
+        cache
+            .add_instrument(InstrumentAny::FuturesContract(esz1))
+            .unwrap();
+        cache
+            .add_instrument(InstrumentAny::OptionContract(option_contract_appl))
+            .unwrap();
+
+        let chain = cache.option_chain(&option_contract_appl.underlying, None);
+        let other_venue =
+            cache.option_chain(&option_contract_appl.underlying, Some(&esz1.id.venue));
+
+        assert_eq!(chain.len(), 1);
+        assert_eq!(
+            chain.get(
+                option_contract_appl.expiration_ns,
+                option_contract_appl.strike_price,
+                option_contract_appl.option_kind,
+            ),
+            Some(&option_contract_appl)
+        );
+        assert!(other_venue.is_empty());
+    }
+
     #[rstest]
     #[tokio::test]
     async fn test_cache_synthetics_when_no_database(mut cache: Cache) {
@@ -553,6 +755,27 @@ mod tests {
         assert_eq!(result, Some(&quote));
     }
 
+    #[rstest]
+    fn test_funding_rate_replaces_previous_update(mut cache: Cache) {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let update = |rate: i64, ts: u64| {
+            FundingRateUpdate::new(
+                instrument_id,
+                Decimal::new(rate, 4),
+                None,
+                None,
+                UnixNanos::from(ts),
+                UnixNanos::from(ts),
+            )
+        };
+        assert!(cache.funding_rate(&instrument_id).is_none());
+
+        cache.add_funding_rate(update(1, 1));
+        cache.add_funding_rate(update(2, 2));
+
+        assert_eq!(cache.funding_rate(&instrument_id), Some(&update(2, 2)));
+    }
+
     #[rstest]
     fn test_quote_ticks_when_empty(cache: Cache, audusd_sim: CurrencyPair) {
         let result = cache.quotes(&audusd_sim.id);
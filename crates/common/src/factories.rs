@@ -18,19 +18,189 @@
 use indexmap::IndexMap;
 use nautilus_core::{AtomicTime, UUID4};
 use nautilus_model::{
-    enums::{ContingencyType, OrderSide, TimeInForce},
+    enums::{ContingencyType, OrderSide, OrderSideSpecified, TimeInForce, TriggerType},
     identifiers::{
-        ClientOrderId, ExecAlgorithmId, InstrumentId, OrderListId, StrategyId, TraderId,
+        ClientOrderId, ExecAlgorithmId, InstrumentId, OrderListId, StrategyId, TraderId, Venue,
     },
-    orders::{MarketOrder, OrderAny},
-    types::Quantity,
+    orders::{LimitOrder, MarketOrder, OrderAny, OrderList, StopMarketOrder},
+    types::{Price, Quantity},
 };
+use rust_decimal::{prelude::*, Decimal};
+use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
 use crate::generators::{
-    client_order_id::ClientOrderIdGenerator, order_list_id::OrderListIdGenerator,
+    client_order_id::{ClientOrderIdConfig, ClientOrderIdGenerator},
+    order_list_id::OrderListIdGenerator,
 };
 
+/// Represents the distance of a bracket take-profit or stop-loss price from its reference price.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BracketOffset {
+    /// A number of instrument price increments.
+    Ticks(u32),
+    /// A number of basis points of the reference price.
+    Bps(Decimal),
+    /// A multiple of the average true range (ATR).
+    Atr(Decimal),
+}
+
+impl BracketOffset {
+    /// Returns the price distance of the offset from the `reference` price.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the offset is not positive, or is an ATR multiple and no `atr` is given.
+    pub fn distance(
+        &self,
+        reference: Price,
+        price_increment: Price,
+        atr: Option<f64>,
+    ) -> anyhow::Result<Decimal> {
+        let distance = match self {
+            Self::Ticks(ticks) => price_increment.as_decimal() * Decimal::from(*ticks),
+            Self::Bps(bps) => reference.as_decimal() * bps / Decimal::from(10_000),
+            Self::Atr(multiple) => {
+                let Some(atr) = atr else {
+                    anyhow::bail!("No ATR value for bracket offset {self:?}");
+                };
+                let atr = Decimal::from_f64(atr)
+                    .ok_or_else(|| anyhow::anyhow!("Invalid ATR value {atr}"))?;
+                atr * multiple
+            }
+        };
+        if distance <= Decimal::ZERO {
+            anyhow::bail!("Invalid bracket offset {self:?}: distance {distance} not positive");
+        }
+        Ok(distance)
+    }
+}
+
+/// Represents how the take-profit and stop-loss prices of a bracket are re-priced once the entry
+/// order has filled.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BracketRepricing {
+    /// Prices are fixed at the reference price the bracket was created with.
+    #[default]
+    Static,
+    /// Prices are re-anchored to the average fill price of the entry order.
+    EntryFill,
+    /// Prices are re-anchored to the average fill price of the entry order, and the stop-loss is
+    /// moved to the fill price once the market has moved by the `trigger` offset in favor.
+    BreakEven { trigger: BracketOffset },
+}
+
+/// Represents the take-profit and stop-loss prices of a bracket.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BracketPrices {
+    pub take_profit: Price,
+    pub stop_loss: Price,
+}
+
+/// Represents the declarative rules deriving the take-profit and stop-loss prices of a bracket
+/// from its entry price.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BracketRules {
+    pub take_profit: BracketOffset,
+    pub stop_loss: BracketOffset,
+    pub sl_trigger_type: TriggerType,
+    pub repricing: BracketRepricing,
+}
+
+impl BracketRules {
+    /// Creates a new [`BracketRules`] instance with static prices and a default stop-loss
+    /// trigger type.
+    #[must_use]
+    pub const fn new(take_profit: BracketOffset, stop_loss: BracketOffset) -> Self {
+        Self {
+            take_profit,
+            stop_loss,
+            sl_trigger_type: TriggerType::Default,
+            repricing: BracketRepricing::Static,
+        }
+    }
+
+    /// Returns the take-profit and stop-loss prices for an entry on the `entry_side` at the
+    /// `reference` price, rounded to the nearest `price_increment`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an offset is invalid or the stop-loss price would not be positive.
+    pub fn prices(
+        &self,
+        entry_side: OrderSideSpecified,
+        reference: Price,
+        price_increment: Price,
+        atr: Option<f64>,
+    ) -> anyhow::Result<BracketPrices> {
+        let tp_distance = self.take_profit.distance(reference, price_increment, atr)?;
+        let sl_distance = self.stop_loss.distance(reference, price_increment, atr)?;
+        let reference = reference.as_decimal();
+        let (take_profit, stop_loss) = match entry_side {
+            OrderSideSpecified::Buy => (reference + tp_distance, reference - sl_distance),
+            OrderSideSpecified::Sell => (reference - tp_distance, reference + sl_distance),
+        };
+        Ok(BracketPrices {
+            take_profit: round_to_increment(take_profit, price_increment)?,
+            stop_loss: round_to_increment(stop_loss, price_increment)?,
+        })
+    }
+
+    /// Returns the re-priced take-profit and stop-loss prices for the filled `entry` order, given
+    /// the current `market_price`.
+    ///
+    /// Returns `None` if the prices are static or the entry order has no fills yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an offset is invalid or the stop-loss price would not be positive.
+    pub fn reprice(
+        &self,
+        entry: &OrderAny,
+        market_price: Option<Price>,
+        price_increment: Price,
+        atr: Option<f64>,
+    ) -> anyhow::Result<Option<BracketPrices>> {
+        if self.repricing == BracketRepricing::Static {
+            return Ok(None);
+        }
+        let Some(avg_px) = entry.avg_px() else {
+            return Ok(None);
+        };
+
+        let fill_price = Price::new(avg_px, price_increment.precision);
+        let entry_side = entry.order_side_specified();
+        let mut prices = self.prices(entry_side, fill_price, price_increment, atr)?;
+
+        if let (BracketRepricing::BreakEven { trigger }, Some(market_price)) =
+            (self.repricing, market_price)
+        {
+            let distance = trigger.distance(fill_price, price_increment, atr)?;
+            let favorable_move = match entry_side {
+                OrderSideSpecified::Buy => market_price.as_decimal() - fill_price.as_decimal(),
+                OrderSideSpecified::Sell => fill_price.as_decimal() - market_price.as_decimal(),
+            };
+            if favorable_move >= distance {
+                prices.stop_loss = round_to_increment(fill_price.as_decimal(), price_increment)?;
+            }
+        }
+
+        Ok(Some(prices))
+    }
+}
+
+fn round_to_increment(value: Decimal, price_increment: Price) -> anyhow::Result<Price> {
+    let increment = price_increment.as_decimal();
+    let value = (value / increment).round() * increment;
+    if value <= Decimal::ZERO {
+        anyhow::bail!("Invalid bracket price {value}: not positive");
+    }
+    Price::new_checked(
+        value.to_f64().expect("Invalid bracket price"),
+        price_increment.precision,
+    )
+}
+
 #[repr(C)]
 #[derive(Debug)]
 pub struct OrderFactory {
@@ -79,10 +249,40 @@ impl OrderFactory {
         self.order_list_id_generator.set_count(count);
     }
 
+    /// Sets the config for generating client order IDs for venues without a venue config.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `config` is invalid.
+    pub fn set_client_order_id_config(
+        &mut self,
+        config: ClientOrderIdConfig,
+    ) -> anyhow::Result<()> {
+        self.order_id_generator.set_config(config)
+    }
+
+    /// Sets the config for generating client order IDs for orders on the `venue`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `config` is invalid.
+    pub fn set_venue_client_order_id_config(
+        &mut self,
+        venue: Venue,
+        config: ClientOrderIdConfig,
+    ) -> anyhow::Result<()> {
+        self.order_id_generator.set_venue_config(venue, config)
+    }
+
     pub fn generate_client_order_id(&mut self) -> ClientOrderId {
         self.order_id_generator.generate()
     }
 
+    /// Generates a client order ID for an order on the `venue`.
+    pub fn generate_client_order_id_for_venue(&mut self, venue: &Venue) -> ClientOrderId {
+        self.order_id_generator.generate_for(Some(venue))
+    }
+
     pub fn generate_order_list_id(&mut self) -> OrderListId {
         self.order_list_id_generator.generate()
     }
@@ -106,7 +306,8 @@ impl OrderFactory {
         tags: Option<Vec<Ustr>>,
         client_order_id: Option<ClientOrderId>,
     ) -> OrderAny {
-        let client_order_id = client_order_id.unwrap_or_else(|| self.generate_client_order_id());
+        let client_order_id = client_order_id
+            .unwrap_or_else(|| self.generate_client_order_id_for_venue(&instrument_id.venue));
         let exec_spawn_id: Option<ClientOrderId> = if exec_algorithm_id.is_none() {
             None
         } else {
@@ -135,6 +336,167 @@ impl OrderFactory {
         );
         OrderAny::Market(order)
     }
+
+    /// Creates a bracket [`OrderList`] of an entry order, with a stop-loss and take-profit order
+    /// priced from the `rules`.
+    ///
+    /// The entry order is a limit order at the `entry_price` if given, otherwise a market order,
+    /// and the take-profit and stop-loss prices are derived from the `entry_price` (or the
+    /// `reference_price` for market entries). The entry triggers (OTO) the stop-loss and
+    /// take-profit orders, which cancel (OUO) each other once either is filled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no price to derive the bracket prices from, or the prices
+    /// cannot be derived from the `rules`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn bracket(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        entry_price: Option<Price>,
+        reference_price: Option<Price>,
+        price_increment: Price,
+        rules: &BracketRules,
+        atr: Option<f64>,
+        time_in_force: Option<TimeInForce>,
+        tags: Option<Vec<Ustr>>,
+    ) -> anyhow::Result<OrderList> {
+        let Some(reference) = entry_price.or(reference_price) else {
+            anyhow::bail!("No entry or reference price to derive bracket prices from");
+        };
+        if order_side == OrderSide::NoOrderSide {
+            anyhow::bail!("Invalid `OrderSide` {order_side} for bracket entry");
+        }
+        let entry_side = order_side.as_specified();
+        let prices = rules.prices(entry_side, reference, price_increment, atr)?;
+
+        let order_list_id = self.generate_order_list_id();
+        let entry_id = self.generate_client_order_id_for_venue(&instrument_id.venue);
+        let sl_id = self.generate_client_order_id_for_venue(&instrument_id.venue);
+        let tp_id = self.generate_client_order_id_for_venue(&instrument_id.venue);
+        let time_in_force = time_in_force.unwrap_or(TimeInForce::Gtc);
+        let exit_side = entry_side.opposite().as_order_side();
+        let ts_init = self.clock.get_time_ns();
+
+        let entry = match entry_price {
+            Some(price) => OrderAny::Limit(LimitOrder::new(
+                self.trader_id,
+                self.strategy_id,
+                instrument_id,
+                entry_id,
+                order_side,
+                quantity,
+                price,
+                None,
+                None,
+                time_in_force,
+                None,
+                false,
+                false,
+                false,
+                None,
+                None,
+                None,
+                Some(ContingencyType::Oto),
+                Some(order_list_id),
+                Some(vec![sl_id, tp_id]),
+                None,
+                None,
+                None,
+                None,
+                tags.clone(),
+                UUID4::new(),
+                ts_init,
+            )?),
+            None => OrderAny::Market(MarketOrder::new(
+                self.trader_id,
+                self.strategy_id,
+                instrument_id,
+                entry_id,
+                order_side,
+                quantity,
+                time_in_force,
+                UUID4::new(),
+                ts_init,
+                false,
+                false,
+                Some(ContingencyType::Oto),
+                Some(order_list_id),
+                Some(vec![sl_id, tp_id]),
+                None,
+                None,
+                None,
+                None,
+                tags.clone(),
+            )),
+        };
+        let stop_loss = OrderAny::StopMarket(StopMarketOrder::new(
+            self.trader_id,
+            self.strategy_id,
+            instrument_id,
+            sl_id,
+            exit_side,
+            quantity,
+            prices.stop_loss,
+            rules.sl_trigger_type,
+            time_in_force,
+            None,
+            true,
+            false,
+            None,
+            None,
+            None,
+            Some(ContingencyType::Ouo),
+            Some(order_list_id),
+            Some(vec![tp_id]),
+            Some(entry_id),
+            None,
+            None,
+            None,
+            tags.clone(),
+            UUID4::new(),
+            ts_init,
+        ));
+        let take_profit = OrderAny::Limit(LimitOrder::new(
+            self.trader_id,
+            self.strategy_id,
+            instrument_id,
+            tp_id,
+            exit_side,
+            quantity,
+            prices.take_profit,
+            None,
+            None,
+            time_in_force,
+            None,
+            false,
+            true,
+            false,
+            None,
+            None,
+            None,
+            Some(ContingencyType::Ouo),
+            Some(order_list_id),
+            Some(vec![sl_id]),
+            Some(entry_id),
+            None,
+            None,
+            None,
+            tags,
+            UUID4::new(),
+            ts_init,
+        )?);
+
+        Ok(OrderList::new(
+            order_list_id,
+            instrument_id,
+            self.strategy_id,
+            vec![entry, stop_loss, take_profit],
+            ts_init,
+        ))
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -144,15 +506,24 @@ impl OrderFactory {
 pub mod tests {
     use nautilus_core::time::get_atomic_clock_static;
     use nautilus_model::{
-        enums::{OrderSide, TimeInForce},
+        enums::{ContingencyType, OrderSide, OrderSideSpecified, OrderType, TimeInForce},
         identifiers::{
             stubs::{strategy_id_ema_cross, trader_id},
-            ClientOrderId, InstrumentId, OrderListId,
+            ClientOrderId, InstrumentId, OrderListId, Venue,
         },
+        instruments::{stubs::audusd_sim, CurrencyPair, InstrumentAny},
+        orders::stubs::{TestOrderEventStubs, TestOrderStubs},
+        types::Price,
     };
     use rstest::{fixture, rstest};
+    use rust_decimal_macros::dec;
 
-    use crate::factories::OrderFactory;
+    use crate::{
+        factories::{BracketOffset, BracketPrices, BracketRepricing, BracketRules, OrderFactory},
+        generators::client_order_id::{
+            ClientOrderIdCharset, ClientOrderIdConfig, ClientOrderIdPolicy,
+        },
+    };
 
     #[fixture]
     pub fn order_factory() -> OrderFactory {
@@ -253,4 +624,283 @@ pub mod tests {
         );
         // assert_eq!(market_order.order_list_id(), None);
     }
+
+    #[rstest]
+    #[case(
+        BracketOffset::Ticks(20),
+        BracketOffset::Ticks(10),
+        "1.00020",
+        "0.99990"
+    )]
+    #[case(BracketOffset::Bps(dec!(25)), BracketOffset::Bps(dec!(12.5)), "1.00250", "0.99875")]
+    #[case(BracketOffset::Atr(dec!(2)), BracketOffset::Atr(dec!(1)), "1.00300", "0.99850")]
+    fn test_bracket_prices(
+        #[case] take_profit: BracketOffset,
+        #[case] stop_loss: BracketOffset,
+        #[case] expected_tp: &str,
+        #[case] expected_sl: &str,
+    ) {
+        let rules = BracketRules::new(take_profit, stop_loss);
+
+        let prices = rules
+            .prices(
+                OrderSideSpecified::Buy,
+                Price::from("1.00000"),
+                Price::from("0.00001"),
+                Some(0.0015),
+            )
+            .unwrap();
+
+        assert_eq!(
+            prices,
+            BracketPrices {
+                take_profit: Price::from(expected_tp),
+                stop_loss: Price::from(expected_sl),
+            }
+        );
+    }
+
+    #[rstest]
+    fn test_bracket_prices_for_sell_rounds_to_increment() {
+        let rules = BracketRules::new(BracketOffset::Bps(dec!(3)), BracketOffset::Bps(dec!(1)));
+
+        let prices = rules
+            .prices(
+                OrderSideSpecified::Sell,
+                Price::from("101.00"),
+                Price::from("0.01"),
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(prices.take_profit, Price::from("100.97"));
+        assert_eq!(prices.stop_loss, Price::from("101.01"));
+    }
+
+    #[rstest]
+    fn test_bracket_prices_with_atr_offset_and_no_atr() {
+        let rules = BracketRules::new(BracketOffset::Atr(dec!(2)), BracketOffset::Ticks(10));
+
+        let result = rules.prices(
+            OrderSideSpecified::Buy,
+            Price::from("1.00000"),
+            Price::from("0.00001"),
+            None,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_bracket_limit_entry(mut order_factory: OrderFactory, audusd_sim: CurrencyPair) {
+        let rules = BracketRules::new(BracketOffset::Ticks(20), BracketOffset::Ticks(10));
+
+        let order_list = order_factory
+            .bracket(
+                audusd_sim.id,
+                OrderSide::Sell,
+                100_000.into(),
+                Some(Price::from("1.00000")),
+                None,
+                Price::from("0.00001"),
+                &rules,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        let [entry, stop_loss, take_profit] = order_list.orders.as_slice() else {
+            panic!("Expected three orders");
+        };
+        assert_eq!(
+            order_list.id,
+            OrderListId::new("OL-19700101-000000-001-001-1")
+        );
+        assert_eq!(entry.order_type(), OrderType::Limit);
+        assert_eq!(entry.contingency_type(), Some(ContingencyType::Oto));
+        assert_eq!(
+            entry.linked_order_ids(),
+            Some(vec![
+                stop_loss.client_order_id(),
+                take_profit.client_order_id()
+            ])
+        );
+        assert_eq!(stop_loss.order_type(), OrderType::StopMarket);
+        assert_eq!(stop_loss.order_side(), OrderSide::Buy);
+        assert_eq!(stop_loss.trigger_price(), Some(Price::from("1.00010")));
+        assert_eq!(stop_loss.contingency_type(), Some(ContingencyType::Ouo));
+        assert_eq!(stop_loss.parent_order_id(), Some(entry.client_order_id()));
+        assert!(stop_loss.is_reduce_only());
+        assert_eq!(take_profit.order_type(), OrderType::Limit);
+        assert_eq!(take_profit.price(), Some(Price::from("0.99980")));
+        assert_eq!(
+            take_profit.linked_order_ids(),
+            Some(vec![stop_loss.client_order_id()])
+        );
+        assert!(order_list
+            .orders
+            .iter()
+            .all(|order| order.order_list_id() == Some(order_list.id)));
+    }
+
+    #[rstest]
+    fn test_bracket_market_entry_requires_reference_price(
+        mut order_factory: OrderFactory,
+        audusd_sim: CurrencyPair,
+    ) {
+        let rules = BracketRules::new(BracketOffset::Ticks(20), BracketOffset::Ticks(10));
+
+        let result = order_factory.bracket(
+            audusd_sim.id,
+            OrderSide::Buy,
+            100_000.into(),
+            None,
+            None,
+            Price::from("0.00001"),
+            &rules,
+            None,
+            None,
+            None,
+        );
+        let order_list = order_factory
+            .bracket(
+                audusd_sim.id,
+                OrderSide::Buy,
+                100_000.into(),
+                None,
+                Some(Price::from("1.00000")),
+                Price::from("0.00001"),
+                &rules,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        assert!(result.is_err());
+        assert_eq!(order_list.orders[0].order_type(), OrderType::Market);
+        assert_eq!(order_list.orders[2].price(), Some(Price::from("1.00020")));
+    }
+
+    #[rstest]
+    #[case(BracketRepricing::Static, None, None)]
+    #[case(BracketRepricing::EntryFill, None, Some(("1.00120", "1.00090")))]
+    #[case(
+        BracketRepricing::BreakEven { trigger: BracketOffset::Ticks(5) },
+        Some("1.00104"),
+        Some(("1.00120", "1.00090"))
+    )]
+    #[case(
+        BracketRepricing::BreakEven { trigger: BracketOffset::Ticks(5) },
+        Some("1.00105"),
+        Some(("1.00120", "1.00100"))
+    )]
+    fn test_bracket_reprice_after_entry_fill(
+        mut order_factory: OrderFactory,
+        audusd_sim: CurrencyPair,
+        #[case] repricing: BracketRepricing,
+        #[case] market_price: Option<&str>,
+        #[case] expected: Option<(&str, &str)>,
+    ) {
+        let rules = BracketRules {
+            repricing,
+            ..BracketRules::new(BracketOffset::Ticks(20), BracketOffset::Ticks(10))
+        };
+        let order_list = order_factory
+            .bracket(
+                audusd_sim.id,
+                OrderSide::Buy,
+                100_000.into(),
+                None,
+                Some(Price::from("1.00000")),
+                Price::from("0.00001"),
+                &rules,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        let mut entry = TestOrderStubs::make_accepted_order(&order_list.orders[0]);
+        let unfilled = rules
+            .reprice(&entry, None, Price::from("0.00001"), None)
+            .unwrap();
+        let fill = TestOrderEventStubs::order_filled(
+            &entry,
+            &InstrumentAny::CurrencyPair(audusd_sim),
+            None,
+            None,
+            Some(Price::from("1.00100")),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        entry.apply(fill).unwrap();
+
+        let prices = rules
+            .reprice(
+                &entry,
+                market_price.map(Price::from),
+                Price::from("0.00001"),
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(unfilled, None);
+        assert_eq!(
+            prices,
+            expected.map(|(tp, sl)| BracketPrices {
+                take_profit: Price::from(tp),
+                stop_loss: Price::from(sl),
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_market_order_with_venue_client_order_id_config(mut order_factory: OrderFactory) {
+        let config = ClientOrderIdConfig::new(
+            ClientOrderIdPolicy::NanoId { length: 10 },
+            ClientOrderIdCharset::Numeric,
+            None,
+        )
+        .unwrap();
+        order_factory
+            .set_venue_client_order_id_config(Venue::from("BINANCE"), config)
+            .unwrap();
+
+        let binance_order = order_factory.market(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            OrderSide::Buy,
+            100.into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let sim_order = order_factory.market(
+            InstrumentId::from("AUD/USD.SIM"),
+            OrderSide::Buy,
+            100.into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        let binance_id = binance_order.client_order_id();
+        assert_eq!(binance_id.as_str().len(), 10);
+        assert!(binance_id.as_str().chars().all(|c| c.is_ascii_digit()));
+        assert_eq!(
+            sim_order.client_order_id(),
+            ClientOrderId::new("O-19700101-000000-001-001-2")
+        );
+    }
 }
@@ -13,11 +13,124 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_core::AtomicTime;
-use nautilus_model::identifiers::{ClientOrderId, StrategyId, TraderId};
+use std::collections::HashMap;
+
+use nautilus_core::{AtomicTime, UUID4};
+use nautilus_model::identifiers::{ClientOrderId, StrategyId, TraderId, Venue};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
 
 use super::get_datetime_tag;
 
+/// The alphabet of generated nano IDs, when the charset is not constrained.
+const NANOID_ALPHABET: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz_-";
+
+/// The policy for generating the value of client order IDs.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClientOrderIdPolicy {
+    /// A value tagged with the datetime, trader, strategy and sequence, e.g.
+    /// `O-20240101-000000-001-001-1`.
+    #[default]
+    Default,
+    /// A random UUID (version 4).
+    Uuid,
+    /// A random nano ID of the given length.
+    NanoId { length: usize },
+    /// A hex encoded hash of the trader, strategy and sequence, so the same value is generated
+    /// for the same sequence across runs.
+    Deterministic,
+}
+
+/// The set of characters a venue accepts in client order IDs.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClientOrderIdCharset {
+    /// Any characters valid for a client order ID.
+    #[default]
+    Any,
+    /// ASCII letters, digits and hyphens only.
+    AlphanumericHyphen,
+    /// ASCII letters and digits only.
+    Alphanumeric,
+    /// ASCII digits only.
+    Numeric,
+}
+
+impl ClientOrderIdCharset {
+    /// Returns whether the `c` is in the charset.
+    #[must_use]
+    pub const fn contains(&self, c: char) -> bool {
+        match self {
+            Self::Any => true,
+            Self::AlphanumericHyphen => c.is_ascii_alphanumeric() || c == '-',
+            Self::Alphanumeric => c.is_ascii_alphanumeric(),
+            Self::Numeric => c.is_ascii_digit(),
+        }
+    }
+}
+
+/// Configuration for generating client order IDs, such as for a venue which only accepts a
+/// constrained client order ID format.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientOrderIdConfig {
+    /// The policy for generating values.
+    pub policy: ClientOrderIdPolicy,
+    /// The set of characters generated values are constrained to.
+    pub charset: ClientOrderIdCharset,
+    /// The maximum length of generated values (unlimited if `None`).
+    pub max_length: Option<usize>,
+}
+
+impl ClientOrderIdConfig {
+    /// Creates a new [`ClientOrderIdConfig`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config is invalid.
+    pub fn new(
+        policy: ClientOrderIdPolicy,
+        charset: ClientOrderIdCharset,
+        max_length: Option<usize>,
+    ) -> anyhow::Result<Self> {
+        let config = Self {
+            policy,
+            charset,
+            max_length,
+        };
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Validates the config.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a nano ID or maximum length is zero.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if let ClientOrderIdPolicy::NanoId { length: 0 } = self.policy {
+            anyhow::bail!("Invalid `ClientOrderIdConfig`: nano ID length was zero");
+        }
+        if self.max_length == Some(0) {
+            anyhow::bail!("Invalid `ClientOrderIdConfig`: `max_length` was zero");
+        }
+        Ok(())
+    }
+
+    /// Constrains the `value` to the charset and maximum length, removing characters not in the
+    /// charset then keeping the trailing characters (which hold the sequence for the default
+    /// and deterministic policies).
+    #[must_use]
+    pub fn constrain(&self, value: &str) -> String {
+        let chars: Vec<char> = value
+            .chars()
+            .filter(|c| self.charset.contains(*c))
+            .collect();
+        let start = self
+            .max_length
+            .map_or(0, |max_length| chars.len().saturating_sub(max_length));
+        chars[start..].iter().collect()
+    }
+}
+
 #[repr(C)]
 #[derive(Debug)]
 pub struct ClientOrderIdGenerator {
@@ -25,12 +138,14 @@ pub struct ClientOrderIdGenerator {
     trader_id: TraderId,
     strategy_id: StrategyId,
     count: usize,
+    config: ClientOrderIdConfig,
+    venue_configs: HashMap<Venue, ClientOrderIdConfig>,
 }
 
 impl ClientOrderIdGenerator {
     /// Creates a new [`ClientOrderIdGenerator`] instance.
     #[must_use]
-    pub const fn new(
+    pub fn new(
         trader_id: TraderId,
         strategy_id: StrategyId,
         initial_count: usize,
@@ -41,6 +156,8 @@ impl ClientOrderIdGenerator {
             strategy_id,
             count: initial_count,
             clock,
+            config: ClientOrderIdConfig::default(),
+            venue_configs: HashMap::new(),
         }
     }
 
@@ -57,29 +174,104 @@ impl ClientOrderIdGenerator {
         self.count
     }
 
+    /// Sets the config for generating client order IDs for venues without a venue config.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `config` is invalid.
+    pub fn set_config(&mut self, config: ClientOrderIdConfig) -> anyhow::Result<()> {
+        config.validate()?;
+        self.config = config;
+        Ok(())
+    }
+
+    /// Sets the config for generating client order IDs for the `venue`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `config` is invalid.
+    pub fn set_venue_config(
+        &mut self,
+        venue: Venue,
+        config: ClientOrderIdConfig,
+    ) -> anyhow::Result<()> {
+        config.validate()?;
+        self.venue_configs.insert(venue, config);
+        Ok(())
+    }
+
+    /// Returns the config for generating client order IDs for the `venue`.
+    #[must_use]
+    pub fn config(&self, venue: Option<&Venue>) -> &ClientOrderIdConfig {
+        venue
+            .and_then(|venue| self.venue_configs.get(venue))
+            .unwrap_or(&self.config)
+    }
+
     pub fn generate(&mut self) -> ClientOrderId {
-        let datetime_tag = get_datetime_tag(self.clock.get_time_ms());
-        let trader_tag = self.trader_id.get_tag();
-        let strategy_tag = self.strategy_id.get_tag();
+        self.generate_for(None)
+    }
+
+    /// Generates a client order ID using the config for the `venue` (if any).
+    pub fn generate_for(&mut self, venue: Option<&Venue>) -> ClientOrderId {
         self.count += 1;
-        let value = format!(
-            "O-{}-{}-{}-{}",
-            datetime_tag, trader_tag, strategy_tag, self.count
-        );
-        ClientOrderId::from(value)
+        let config = *self.config(venue);
+        let value = match config.policy {
+            ClientOrderIdPolicy::Default => {
+                let datetime_tag = get_datetime_tag(self.clock.get_time_ms());
+                let trader_tag = self.trader_id.get_tag();
+                let strategy_tag = self.strategy_id.get_tag();
+                format!(
+                    "O-{}-{}-{}-{}",
+                    datetime_tag, trader_tag, strategy_tag, self.count
+                )
+            }
+            ClientOrderIdPolicy::Uuid => UUID4::new().to_string(),
+            ClientOrderIdPolicy::NanoId { length } => nanoid(length, config.charset),
+            ClientOrderIdPolicy::Deterministic => {
+                let hash = fnv1a_64(
+                    format!("{}-{}-{}", self.trader_id, self.strategy_id, self.count).as_bytes(),
+                );
+                format!("{hash:016x}")
+            }
+        };
+        ClientOrderId::from(config.constrain(&value).as_str())
     }
 }
 
+fn nanoid(length: usize, charset: ClientOrderIdCharset) -> String {
+    let alphabet: Vec<u8> = NANOID_ALPHABET
+        .iter()
+        .copied()
+        .filter(|c| charset.contains(char::from(*c)))
+        .collect();
+    let mut rng = rand::rng();
+    (0..length)
+        .map(|_| char::from(alphabet[rng.random_range(0..alphabet.len())]))
+        .collect()
+}
+
+/// Returns the 64-bit FNV-1a hash of the `bytes`, which is stable across platforms and runs.
+fn fnv1a_64(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    bytes.iter().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+    })
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
     use nautilus_core::time::get_atomic_clock_static;
-    use nautilus_model::identifiers::{ClientOrderId, StrategyId, TraderId};
+    use nautilus_model::identifiers::{ClientOrderId, StrategyId, TraderId, Venue};
     use rstest::rstest;
 
-    use crate::generators::client_order_id::ClientOrderIdGenerator;
+    use crate::generators::client_order_id::{
+        ClientOrderIdCharset, ClientOrderIdConfig, ClientOrderIdGenerator, ClientOrderIdPolicy,
+    };
 
     fn get_client_order_id_generator(initial_count: Option<usize>) -> ClientOrderIdGenerator {
         ClientOrderIdGenerator::new(
@@ -136,4 +328,87 @@ mod tests {
 
         assert_eq!(result, ClientOrderId::new("O-19700101-000000-001-001-1"));
     }
+
+    #[rstest]
+    fn test_generate_uuid() {
+        let mut generator = get_client_order_id_generator(None);
+        let config =
+            ClientOrderIdConfig::new(ClientOrderIdPolicy::Uuid, ClientOrderIdCharset::Any, None)
+                .unwrap();
+        generator.set_config(config).unwrap();
+
+        let result1 = generator.generate();
+        let result2 = generator.generate();
+
+        assert_eq!(result1.as_str().len(), 36);
+        assert_ne!(result1, result2);
+        assert_eq!(generator.count(), 2);
+    }
+
+    #[rstest]
+    #[case(ClientOrderIdCharset::Any)]
+    #[case(ClientOrderIdCharset::Alphanumeric)]
+    #[case(ClientOrderIdCharset::Numeric)]
+    fn test_generate_nanoid(#[case] charset: ClientOrderIdCharset) {
+        let mut generator = get_client_order_id_generator(None);
+        let config =
+            ClientOrderIdConfig::new(ClientOrderIdPolicy::NanoId { length: 21 }, charset, None)
+                .unwrap();
+        generator.set_config(config).unwrap();
+
+        let result = generator.generate();
+
+        assert_eq!(result.as_str().len(), 21);
+        assert!(result.as_str().chars().all(|c| charset.contains(c)));
+    }
+
+    #[rstest]
+    fn test_generate_deterministic() {
+        let config = ClientOrderIdConfig::new(
+            ClientOrderIdPolicy::Deterministic,
+            ClientOrderIdCharset::Any,
+            None,
+        )
+        .unwrap();
+        let mut generator1 = get_client_order_id_generator(None);
+        let mut generator2 = get_client_order_id_generator(None);
+        generator1.set_config(config).unwrap();
+        generator2.set_config(config).unwrap();
+
+        let result1 = generator1.generate();
+        let result2 = generator1.generate();
+
+        assert_eq!(result1.as_str().len(), 16);
+        assert_ne!(result1, result2);
+        assert_eq!(generator2.generate(), result1);
+        assert_eq!(generator2.generate(), result2);
+    }
+
+    #[rstest]
+    fn test_generate_for_venue_constrained() {
+        let mut generator = get_client_order_id_generator(Some(122));
+        let venue = Venue::from("XYZ");
+        let config = ClientOrderIdConfig::new(
+            ClientOrderIdPolicy::Default,
+            ClientOrderIdCharset::Alphanumeric,
+            Some(12),
+        )
+        .unwrap();
+        generator.set_venue_config(venue, config).unwrap();
+
+        let constrained = generator.generate_for(Some(&venue));
+        let other = generator.generate_for(Some(&Venue::from("SIM")));
+
+        assert_eq!(constrained, ClientOrderId::new("000001001123"));
+        assert_eq!(other, ClientOrderId::new("O-19700101-000000-001-001-124"));
+    }
+
+    #[rstest]
+    #[case(ClientOrderIdPolicy::NanoId { length: 0 }, None)]
+    #[case(ClientOrderIdPolicy::Default, Some(0))]
+    fn test_invalid_config(#[case] policy: ClientOrderIdPolicy, #[case] max_length: Option<usize>) {
+        let result = ClientOrderIdConfig::new(policy, ClientOrderIdCharset::Any, max_length);
+
+        assert!(result.is_err());
+    }
 }
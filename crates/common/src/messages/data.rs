@@ -34,7 +34,7 @@ pub struct DataRequest {
 
 pub type Payload = Arc<dyn Any + Send + Sync>;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct DataResponse {
     pub correlation_id: UUID4,
     pub client_id: ClientId,
@@ -118,4 +118,6 @@ pub enum DataCommand {
 pub enum DataEvent {
     Response(DataResponse),
     Data(Data),
+    /// Data from the feed of a specific client, arbitrated against any redundant feeds.
+    FeedData(ClientId, Data),
 }
@@ -428,6 +428,15 @@ impl MessageBus {
         }
     }
 
+    /// Publish a [`DataResponse`] to the handlers subscribed to a topic.
+    pub fn publish_response(&self, topic: &Ustr, message: &DataResponse) {
+        let matching_subs = self.matching_subscriptions(topic);
+
+        for sub in matching_subs {
+            sub.handler.0.handle_response(message.clone());
+        }
+    }
+
     /// Publish [`Data`] to a topic.
     pub fn publish_data(&self, topic: &Ustr, message: Data) {
         let matching_subs = self.matching_subscriptions(topic);
@@ -126,11 +126,12 @@ pub fn check_handler_was_called(call_check_handler: ShareableMessageHandler) ->
         .was_called()
 }
 
-// Handler which saves the messages it receives
+// Handler which saves the messages and responses it receives
 #[derive(Debug, Clone)]
 pub struct MessageSavingHandler<T> {
     id: Ustr,
     messages: Rc<RefCell<Vec<T>>>,
+    responses: Rc<RefCell<Vec<DataResponse>>>,
 }
 
 impl<T: Clone + 'static> MessageSavingHandler<T> {
@@ -138,6 +139,11 @@ impl<T: Clone + 'static> MessageSavingHandler<T> {
     pub fn get_messages(&self) -> Vec<T> {
         self.messages.borrow().clone()
     }
+
+    #[must_use]
+    pub fn get_responses(&self) -> Vec<DataResponse> {
+        self.responses.borrow().clone()
+    }
 }
 
 impl<T: Clone + 'static> MessageHandler for MessageSavingHandler<T> {
@@ -153,7 +159,9 @@ impl<T: Clone + 'static> MessageHandler for MessageSavingHandler<T> {
         }
     }
 
-    fn handle_response(&self, _resp: DataResponse) {}
+    fn handle_response(&self, resp: DataResponse) {
+        self.responses.borrow_mut().push(resp);
+    }
 
     fn handle_data(&self, _data: Data) {}
 
@@ -171,6 +179,7 @@ pub fn get_message_saving_handler<T: Clone + 'static>(id: Option<Ustr>) -> Share
     ShareableMessageHandler(Rc::new(MessageSavingHandler::<T> {
         id: unique_id,
         messages: Rc::new(RefCell::new(Vec::new())),
+        responses: Rc::new(RefCell::new(Vec::new())),
     }))
 }
 
@@ -184,3 +193,16 @@ pub fn get_saved_messages<T: Clone + 'static>(handler: ShareableMessageHandler)
         .unwrap()
         .get_messages()
 }
+
+#[must_use]
+pub fn get_saved_responses<T: Clone + 'static>(
+    handler: ShareableMessageHandler,
+) -> Vec<DataResponse> {
+    handler
+        .0
+        .as_ref()
+        .as_any()
+        .downcast_ref::<MessageSavingHandler<T>>()
+        .unwrap()
+        .get_responses()
+}
@@ -17,6 +17,7 @@ use std::collections::HashMap;
 
 use nautilus_model::{
     data::{BarType, DataType},
+    enums::ReferencePriceType,
     identifiers::{ClientOrderId, InstrumentId, PositionId, StrategyId},
 };
 use ustr::Ustr;
@@ -32,12 +33,21 @@ pub struct MessagingSwitchboard {
     instrument_topics: HashMap<InstrumentId, Ustr>,
     deltas_topics: HashMap<InstrumentId, Ustr>,
     book_snapshots_topics: HashMap<InstrumentId, Ustr>,
+    depth_snapshots_topics: HashMap<InstrumentId, Ustr>,
     event_orders_topics: HashMap<StrategyId, Ustr>,
     event_positions_topics: HashMap<StrategyId, Ustr>,
+    event_routing_topics: HashMap<StrategyId, Ustr>,
+    event_throttle_topics: HashMap<StrategyId, Ustr>,
+    event_spawn_topics: HashMap<StrategyId, Ustr>,
     depth_topics: HashMap<InstrumentId, Ustr>,
     quote_topics: HashMap<InstrumentId, Ustr>,
     trade_topics: HashMap<InstrumentId, Ustr>,
     bar_topics: HashMap<BarType, Ustr>,
+    conflated_topics: HashMap<(Ustr, Ustr, u64), Ustr>,
+    greeks_topics: HashMap<InstrumentId, Ustr>,
+    funding_rate_topics: HashMap<InstrumentId, Ustr>,
+    funding_pnl_topics: HashMap<InstrumentId, Ustr>,
+    reference_price_topics: HashMap<(InstrumentId, ReferencePriceType), Ustr>,
     order_snapshots_topics: HashMap<ClientOrderId, Ustr>,
     positions_snapshots_topics: HashMap<PositionId, Ustr>,
 }
@@ -54,13 +64,22 @@ impl Default for MessagingSwitchboard {
             instrument_topics: HashMap::new(),
             deltas_topics: HashMap::new(),
             book_snapshots_topics: HashMap::new(),
+            depth_snapshots_topics: HashMap::new(),
             depth_topics: HashMap::new(),
             quote_topics: HashMap::new(),
             trade_topics: HashMap::new(),
             bar_topics: HashMap::new(),
+            conflated_topics: HashMap::new(),
+            greeks_topics: HashMap::new(),
+            funding_rate_topics: HashMap::new(),
+            funding_pnl_topics: HashMap::new(),
+            reference_price_topics: HashMap::new(),
             order_snapshots_topics: HashMap::new(),
             event_orders_topics: HashMap::new(),
             event_positions_topics: HashMap::new(),
+            event_routing_topics: HashMap::new(),
+            event_throttle_topics: HashMap::new(),
+            event_spawn_topics: HashMap::new(),
             positions_snapshots_topics: HashMap::new(),
         }
     }
@@ -121,6 +140,19 @@ impl MessagingSwitchboard {
             })
     }
 
+    #[must_use]
+    pub fn get_depth_snapshots_topic(&mut self, instrument_id: InstrumentId) -> Ustr {
+        *self
+            .depth_snapshots_topics
+            .entry(instrument_id)
+            .or_insert_with(|| {
+                Ustr::from(&format!(
+                    "data.book.depth_snapshots.{}.{}",
+                    instrument_id.venue, instrument_id.symbol
+                ))
+            })
+    }
+
     #[must_use]
     pub fn get_quotes_topic(&mut self, instrument_id: InstrumentId) -> Ustr {
         *self.quote_topics.entry(instrument_id).or_insert_with(|| {
@@ -149,6 +181,78 @@ impl MessagingSwitchboard {
             .or_insert_with(|| Ustr::from(&format!("data.bars.{bar_type}")))
     }
 
+    /// Returns the topic of the `topic` stream conflated by `conflation` over `interval_ms`.
+    ///
+    /// The conflated topic is kept outside the namespace of the stream, so wildcard
+    /// subscriptions to the stream do not also receive it.
+    #[must_use]
+    pub fn get_conflated_topic(&mut self, topic: Ustr, conflation: &str, interval_ms: u64) -> Ustr {
+        *self
+            .conflated_topics
+            .entry((topic, Ustr::from(conflation), interval_ms))
+            .or_insert_with(|| {
+                let stream = topic.strip_prefix("data.").unwrap_or(&topic);
+                Ustr::from(&format!(
+                    "data.conflated.{conflation}.{interval_ms}.{stream}"
+                ))
+            })
+    }
+
+    #[must_use]
+    pub fn get_greeks_topic(&mut self, instrument_id: InstrumentId) -> Ustr {
+        *self.greeks_topics.entry(instrument_id).or_insert_with(|| {
+            Ustr::from(&format!(
+                "data.greeks.{}.{}",
+                instrument_id.venue, instrument_id.symbol
+            ))
+        })
+    }
+
+    #[must_use]
+    pub fn get_funding_rates_topic(&mut self, instrument_id: InstrumentId) -> Ustr {
+        *self
+            .funding_rate_topics
+            .entry(instrument_id)
+            .or_insert_with(|| {
+                Ustr::from(&format!(
+                    "data.funding_rates.{}.{}",
+                    instrument_id.venue, instrument_id.symbol
+                ))
+            })
+    }
+
+    #[must_use]
+    pub fn get_funding_pnl_topic(&mut self, instrument_id: InstrumentId) -> Ustr {
+        *self
+            .funding_pnl_topics
+            .entry(instrument_id)
+            .or_insert_with(|| {
+                Ustr::from(&format!(
+                    "data.funding_pnl.{}.{}",
+                    instrument_id.venue, instrument_id.symbol
+                ))
+            })
+    }
+
+    #[must_use]
+    pub fn get_reference_price_topic(
+        &mut self,
+        instrument_id: InstrumentId,
+        price_type: ReferencePriceType,
+    ) -> Ustr {
+        *self
+            .reference_price_topics
+            .entry((instrument_id, price_type))
+            .or_insert_with(|| {
+                Ustr::from(&format!(
+                    "data.{}.{}.{}",
+                    price_type.as_ref().to_lowercase(),
+                    instrument_id.venue,
+                    instrument_id.symbol
+                ))
+            })
+    }
+
     #[must_use]
     pub fn get_order_snapshots_topic(&mut self, client_order_id: ClientOrderId) -> Ustr {
         *self
@@ -180,6 +284,30 @@ impl MessagingSwitchboard {
             .entry(strategy_id)
             .or_insert_with(|| Ustr::from(&format!("events.position.{strategy_id}")))
     }
+
+    #[must_use]
+    pub fn get_event_routing_topic(&mut self, strategy_id: StrategyId) -> Ustr {
+        *self
+            .event_routing_topics
+            .entry(strategy_id)
+            .or_insert_with(|| Ustr::from(&format!("events.routing.{strategy_id}")))
+    }
+
+    #[must_use]
+    pub fn get_event_throttle_topic(&mut self, strategy_id: StrategyId) -> Ustr {
+        *self
+            .event_throttle_topics
+            .entry(strategy_id)
+            .or_insert_with(|| Ustr::from(&format!("events.throttle.{strategy_id}")))
+    }
+
+    #[must_use]
+    pub fn get_event_spawn_topic(&mut self, strategy_id: StrategyId) -> Ustr {
+        *self
+            .event_spawn_topics
+            .entry(strategy_id)
+            .or_insert_with(|| Ustr::from(&format!("events.spawn.{strategy_id}")))
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -254,6 +382,19 @@ mod tests {
         assert!(switchboard.depth_topics.contains_key(&instrument_id));
     }
 
+    #[rstest]
+    fn test_get_depth_snapshots_topic(
+        mut switchboard: MessagingSwitchboard,
+        instrument_id: InstrumentId,
+    ) {
+        let expected_topic = Ustr::from("data.book.depth_snapshots.XCME.ESZ24");
+        let result = switchboard.get_depth_snapshots_topic(instrument_id);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard
+            .depth_snapshots_topics
+            .contains_key(&instrument_id));
+    }
+
     #[rstest]
     fn test_get_quotes_topic(mut switchboard: MessagingSwitchboard, instrument_id: InstrumentId) {
         let expected_topic = Ustr::from("data.quotes.XCME.ESZ24");
@@ -270,6 +411,65 @@ mod tests {
         assert!(switchboard.trade_topics.contains_key(&instrument_id));
     }
 
+    #[rstest]
+    fn test_get_greeks_topic(mut switchboard: MessagingSwitchboard, instrument_id: InstrumentId) {
+        let expected_topic = Ustr::from("data.greeks.XCME.ESZ24");
+        let result = switchboard.get_greeks_topic(instrument_id);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard.greeks_topics.contains_key(&instrument_id));
+    }
+
+    #[rstest]
+    fn test_get_funding_rates_topic(
+        mut switchboard: MessagingSwitchboard,
+        instrument_id: InstrumentId,
+    ) {
+        let expected_topic = Ustr::from("data.funding_rates.XCME.ESZ24");
+        let result = switchboard.get_funding_rates_topic(instrument_id);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard.funding_rate_topics.contains_key(&instrument_id));
+    }
+
+    #[rstest]
+    fn test_get_funding_pnl_topic(
+        mut switchboard: MessagingSwitchboard,
+        instrument_id: InstrumentId,
+    ) {
+        let expected_topic = Ustr::from("data.funding_pnl.XCME.ESZ24");
+        let result = switchboard.get_funding_pnl_topic(instrument_id);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard.funding_pnl_topics.contains_key(&instrument_id));
+    }
+
+    #[rstest]
+    fn test_get_reference_price_topic(
+        mut switchboard: MessagingSwitchboard,
+        instrument_id: InstrumentId,
+    ) {
+        let expected_topic = Ustr::from("data.vwap.XCME.ESZ24");
+        let result = switchboard.get_reference_price_topic(instrument_id, ReferencePriceType::Vwap);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard
+            .reference_price_topics
+            .contains_key(&(instrument_id, ReferencePriceType::Vwap)));
+    }
+
+    #[rstest]
+    fn test_get_conflated_topic(
+        mut switchboard: MessagingSwitchboard,
+        instrument_id: InstrumentId,
+    ) {
+        let quotes_topic = switchboard.get_quotes_topic(instrument_id);
+        let expected_topic = Ustr::from("data.conflated.latest.500.quotes.XCME.ESZ24");
+        let result = switchboard.get_conflated_topic(quotes_topic, "latest", 500);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard.conflated_topics.contains_key(&(
+            quotes_topic,
+            Ustr::from("latest"),
+            500
+        )));
+    }
+
     #[rstest]
     fn test_get_bars_topic(mut switchboard: MessagingSwitchboard) {
         let bar_type = BarType::from("ESZ24.XCME-1-MINUTE-LAST-INTERNAL");
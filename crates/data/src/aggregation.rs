@@ -72,6 +72,8 @@ pub trait BarAggregator {
     fn stop_batch_update(&mut self);
     fn await_partial(&self) -> bool;
     fn set_partial(&mut self, partial_bar: Bar);
+    /// Stops the aggregator, cancelling any timer it has set.
+    fn stop(&mut self) {}
 }
 
 /// Provides a generic bar builder for aggregation.
@@ -318,12 +320,15 @@ where
 
     fn build_now_and_send(&mut self) {
         let bar = self.builder.build_now();
-        (self.handler)(bar);
+        self.send(bar);
     }
 
     fn build_and_send(&mut self, ts_event: UnixNanos, ts_init: UnixNanos) {
         let bar = self.builder.build(ts_event, ts_init);
+        self.send(bar);
+    }
 
+    fn send(&mut self, bar: Bar) {
         if self.batch_mode {
             if let Some(handler) = &mut self.batch_handler {
                 handler(bar);
@@ -1112,6 +1117,10 @@ where
     fn set_partial(&mut self, partial_bar: Bar) {
         self.core.set_partial(partial_bar);
     }
+
+    fn stop(&mut self) {
+        Self::stop(self);
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
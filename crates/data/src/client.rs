@@ -25,7 +25,6 @@ use std::{
     fmt::Debug,
     ops::{Deref, DerefMut},
     rc::Rc,
-    sync::Arc,
 };
 
 use indexmap::IndexMap;
@@ -338,7 +337,7 @@ impl DataClientAdapter {
         match command.data_type.type_name() {
             stringify!(InstrumentAny) => Self::subscribe_instrument(self, command),
             stringify!(OrderBookDelta) => Self::subscribe_order_book_deltas(self, command),
-            stringify!(OrderBookDeltas) | stringify!(OrderBookDepth10) => {
+            stringify!(OrderBook) | stringify!(OrderBookDeltas) | stringify!(OrderBookDepth10) => {
                 Self::subscribe_snapshots(self, command);
             }
             stringify!(QuoteTick) => Self::subscribe_quote_ticks(self, command),
@@ -353,7 +352,7 @@ impl DataClientAdapter {
         match command.data_type.type_name() {
             stringify!(InstrumentAny) => Self::unsubscribe_instrument(self, command),
             stringify!(OrderBookDelta) => Self::unsubscribe_order_book_deltas(self, command),
-            stringify!(OrderBookDeltas) | stringify!(OrderBookDepth10) => {
+            stringify!(OrderBook) | stringify!(OrderBookDeltas) | stringify!(OrderBookDepth10) => {
                 Self::unsubscribe_snapshots(self, command);
             }
             stringify!(QuoteTick) => Self::unsubscribe_quote_ticks(self, command),
@@ -683,14 +682,13 @@ impl DataClientAdapter {
         let instrument_id = instrument.id();
         let metadata = IndexMap::from([("instrument_id".to_string(), instrument_id.to_string())]);
         let data_type = DataType::new(stringify!(InstrumentAny), Some(metadata));
-        let data = Arc::new(instrument);
 
         DataResponse::new(
             correlation_id,
             self.client_id,
             instrument_id.venue,
             data_type,
-            data,
+            instrument,
             self.clock.borrow().timestamp_ns(),
             None,
         )
@@ -705,14 +703,13 @@ impl DataClientAdapter {
     ) -> DataResponse {
         let metadata = IndexMap::from([("venue".to_string(), venue.to_string())]);
         let data_type = DataType::new(stringify!(InstrumentAny), Some(metadata));
-        let data = Arc::new(instruments);
 
         DataResponse::new(
             correlation_id,
             self.client_id,
             venue,
             data_type,
-            data,
+            instruments,
             self.clock.borrow().timestamp_ns(),
             None,
         )
@@ -727,14 +724,13 @@ impl DataClientAdapter {
     ) -> DataResponse {
         let metadata = IndexMap::from([("instrument_id".to_string(), instrument_id.to_string())]);
         let data_type = DataType::new(stringify!(QuoteTick), Some(metadata));
-        let data = Arc::new(quotes);
 
         DataResponse::new(
             correlation_id,
             self.client_id,
             instrument_id.venue,
             data_type,
-            data,
+            quotes,
             self.clock.borrow().timestamp_ns(),
             None,
         )
@@ -749,14 +745,13 @@ impl DataClientAdapter {
     ) -> DataResponse {
         let metadata = IndexMap::from([("instrument_id".to_string(), instrument_id.to_string())]);
         let data_type = DataType::new(stringify!(TradeTick), Some(metadata));
-        let data = Arc::new(trades);
 
         DataResponse::new(
             correlation_id,
             self.client_id,
             instrument_id.venue,
             data_type,
-            data,
+            trades,
             self.clock.borrow().timestamp_ns(),
             None,
         )
@@ -771,14 +766,13 @@ impl DataClientAdapter {
     ) -> DataResponse {
         let metadata = IndexMap::from([("bar_type".to_string(), bar_type.to_string())]);
         let data_type = DataType::new(stringify!(Bar), Some(metadata));
-        let data = Arc::new(bars);
 
         DataResponse::new(
             correlation_id,
             self.client_id,
             bar_type.instrument_id().venue,
             data_type,
-            data,
+            bars,
             self.clock.borrow().timestamp_ns(),
             None,
         )
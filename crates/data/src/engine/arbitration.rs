@@ -0,0 +1,252 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Arbitration between redundant data feeds of the same instrument.
+
+use std::collections::HashMap;
+
+use indexmap::IndexMap;
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::Data,
+    identifiers::{ClientId, InstrumentId},
+};
+
+/// The weight of the latest sample in the smoothed latency of a feed.
+const LATENCY_SMOOTHING: f64 = 0.2;
+
+#[derive(Clone, Copy, Debug)]
+struct FeedState {
+    ts_last_recv: UnixNanos,
+    latency_ns: f64,
+}
+
+/// Arbitrates between the feeds of an instrument received from multiple data clients.
+///
+/// Only the data of the active feed is passed on. The active feed is the feed with the lowest
+/// smoothed latency (receipt time less `ts_event`) of those not stale, so a feed which stops
+/// sending for longer than the stale timeout is failed over from. On switching feeds, data
+/// the new feed sends which was already passed on from the previous feed is dropped, by
+/// sequence for order book data (where the venue provides one) and by `ts_event` otherwise.
+#[derive(Debug)]
+pub struct FeedArbiter {
+    pub instrument_id: InstrumentId,
+    pub stale_timeout_ns: u64,
+    feeds: IndexMap<ClientId, FeedState>,
+    active: Option<ClientId>,
+    last_keys: HashMap<&'static str, u64>,
+    watermarks: HashMap<&'static str, u64>,
+    switch_count: u64,
+}
+
+impl FeedArbiter {
+    /// Creates a new [`FeedArbiter`] instance.
+    #[must_use]
+    pub fn new(instrument_id: InstrumentId, stale_timeout_ns: u64) -> Self {
+        Self {
+            instrument_id,
+            stale_timeout_ns,
+            feeds: IndexMap::new(),
+            active: None,
+            last_keys: HashMap::new(),
+            watermarks: HashMap::new(),
+            switch_count: 0,
+        }
+    }
+
+    /// Returns the client of the active feed (if any data received).
+    #[must_use]
+    pub const fn active(&self) -> Option<ClientId> {
+        self.active
+    }
+
+    /// Returns the number of times the active feed has been switched.
+    #[must_use]
+    pub const fn switch_count(&self) -> u64 {
+        self.switch_count
+    }
+
+    /// Returns whether the `data` received from the `client_id` feed at `ts_now` should be
+    /// passed on.
+    pub fn arbitrate(&mut self, client_id: ClientId, data: &Data, ts_now: UnixNanos) -> bool {
+        let (kind, key, ts_event) = dedupe_key(data);
+        let latency_ns = ts_now.as_u64().saturating_sub(ts_event.as_u64()) as f64;
+        self.feeds
+            .entry(client_id)
+            .and_modify(|feed| {
+                feed.ts_last_recv = ts_now;
+                feed.latency_ns += LATENCY_SMOOTHING * (latency_ns - feed.latency_ns);
+            })
+            .or_insert(FeedState {
+                ts_last_recv: ts_now,
+                latency_ns,
+            });
+
+        let preferred = self.preferred_feed(ts_now);
+        if self.active != Some(preferred) {
+            self.switch_to(preferred, ts_now);
+        }
+
+        if self.active != Some(client_id) {
+            return false;
+        }
+
+        if let Some(watermark) = self.watermarks.get(kind) {
+            if key <= *watermark {
+                log::debug!(
+                    "Dropped duplicate {kind} for {} from {client_id}",
+                    self.instrument_id
+                );
+                return false;
+            }
+            self.watermarks.remove(kind);
+        }
+
+        self.last_keys.insert(kind, key);
+        true
+    }
+
+    fn is_stale(&self, feed: &FeedState, ts_now: UnixNanos) -> bool {
+        ts_now.as_u64().saturating_sub(feed.ts_last_recv.as_u64()) > self.stale_timeout_ns
+    }
+
+    fn preferred_feed(&self, ts_now: UnixNanos) -> ClientId {
+        let mut preferred: Option<(&ClientId, &FeedState)> = None;
+        for (client_id, feed) in &self.feeds {
+            if self.is_stale(feed, ts_now) {
+                continue;
+            }
+            // Ties go to the active feed, then to the first registered
+            let is_better = preferred.is_none_or(|(preferred_id, preferred_feed)| {
+                feed.latency_ns < preferred_feed.latency_ns
+                    || (feed.latency_ns == preferred_feed.latency_ns
+                        && Some(*client_id) == self.active
+                        && Some(*preferred_id) != self.active)
+            });
+            if is_better {
+                preferred = Some((client_id, feed));
+            }
+        }
+
+        // SAFETY: The feed which data was just received from is never stale
+        *preferred.expect("No feed which is not stale").0
+    }
+
+    fn switch_to(&mut self, client_id: ClientId, ts_now: UnixNanos) {
+        if let Some(previous) = self.active {
+            let is_stale = self
+                .feeds
+                .get(&previous)
+                .is_some_and(|feed| self.is_stale(feed, ts_now));
+            let reason = if is_stale { "stale" } else { "higher latency" };
+            log::warn!(
+                "Switched {} feed from {previous} to {client_id}: {previous} {reason}",
+                self.instrument_id
+            );
+            self.switch_count += 1;
+        }
+
+        self.active = Some(client_id);
+        self.watermarks = self.last_keys.clone();
+    }
+}
+
+/// Returns the kind of the `data`, along with its key for deduplication and its `ts_event`.
+fn dedupe_key(data: &Data) -> (&'static str, u64, UnixNanos) {
+    match data {
+        Data::Delta(delta) => (
+            "OrderBookDelta",
+            sequence_or_ts(delta.sequence, delta.ts_event),
+            delta.ts_event,
+        ),
+        Data::Deltas(deltas) => (
+            "OrderBookDelta",
+            sequence_or_ts(deltas.sequence, deltas.ts_event),
+            deltas.ts_event,
+        ),
+        Data::Depth10(depth) => (
+            "OrderBookDepth10",
+            sequence_or_ts(depth.sequence, depth.ts_event),
+            depth.ts_event,
+        ),
+        Data::Quote(quote) => ("QuoteTick", quote.ts_event.as_u64(), quote.ts_event),
+        Data::Trade(trade) => ("TradeTick", trade.ts_event.as_u64(), trade.ts_event),
+        Data::Bar(bar) => ("Bar", bar.ts_event.as_u64(), bar.ts_event),
+    }
+}
+
+/// Returns the `sequence`, or the `ts_event` for venues which do not sequence book data.
+fn sequence_or_ts(sequence: u64, ts_event: UnixNanos) -> u64 {
+    if sequence > 0 {
+        sequence
+    } else {
+        ts_event.as_u64()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::{stubs::stub_delta, QuoteTick};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn quote(ts_event: u64) -> Data {
+        Data::Quote(QuoteTick {
+            ts_event: UnixNanos::from(ts_event),
+            ..Default::default()
+        })
+    }
+
+    fn delta(sequence: u64, ts_event: u64) -> Data {
+        let mut delta = stub_delta();
+        delta.sequence = sequence;
+        delta.ts_event = UnixNanos::from(ts_event);
+        Data::Delta(delta)
+    }
+
+    #[rstest]
+    fn test_switches_to_lower_latency_feed_without_duplicates() {
+        let feed_a = ClientId::from("A");
+        let feed_b = ClientId::from("B");
+        let mut arbiter = FeedArbiter::new(InstrumentId::from("AUD/USD.SIM"), 1_000);
+
+        assert!(arbiter.arbitrate(feed_a, &quote(100), UnixNanos::from(200)));
+        assert!(!arbiter.arbitrate(feed_b, &quote(100), UnixNanos::from(110)));
+        assert_eq!(arbiter.active(), Some(feed_b));
+        assert!(arbiter.arbitrate(feed_b, &quote(200), UnixNanos::from(210)));
+        assert!(!arbiter.arbitrate(feed_a, &quote(200), UnixNanos::from(300)));
+        assert_eq!(arbiter.switch_count(), 1);
+    }
+
+    #[rstest]
+    fn test_fails_over_from_stale_feed_deduped_by_sequence() {
+        let feed_a = ClientId::from("A");
+        let feed_b = ClientId::from("B");
+        let mut arbiter = FeedArbiter::new(InstrumentId::from("AAPL.XNAS"), 1_000);
+
+        // Feed B lags, so feed A is preferred until it goes stale
+        assert!(arbiter.arbitrate(feed_a, &delta(1, 0), UnixNanos::from(10)));
+        assert!(arbiter.arbitrate(feed_a, &delta(2, 100), UnixNanos::from(110)));
+        assert!(!arbiter.arbitrate(feed_b, &delta(1, 0), UnixNanos::from(500)));
+        assert!(!arbiter.arbitrate(feed_b, &delta(2, 100), UnixNanos::from(1_105)));
+        assert!(!arbiter.arbitrate(feed_b, &delta(2, 100), UnixNanos::from(1_120)));
+        assert_eq!(arbiter.active(), Some(feed_b));
+        assert!(arbiter.arbitrate(feed_b, &delta(3, 1_000), UnixNanos::from(1_130)));
+    }
+}
@@ -0,0 +1,75 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{any::Any, cell::RefCell, rc::Rc};
+
+use nautilus_common::{messages::data::DataResponse, msgbus::handler::MessageHandler};
+use nautilus_model::data::{Bar, BarType, Data, QuoteTick, TradeTick};
+use ustr::Ustr;
+
+use crate::aggregation::BarAggregator;
+
+/// Passes the quotes, trades or bars published on the message bus to a bar aggregator.
+pub struct BarAggregatorHandler {
+    pub id: Ustr,
+    pub aggregator: Rc<RefCell<dyn BarAggregator>>,
+}
+
+impl BarAggregatorHandler {
+    /// Creates a new [`BarAggregatorHandler`] instance.
+    pub fn new(bar_type: BarType, aggregator: Rc<RefCell<dyn BarAggregator>>) -> Self {
+        Self {
+            id: Ustr::from(&format!(
+                "{}-{}",
+                stringify!(BarAggregatorHandler),
+                bar_type
+            )),
+            aggregator,
+        }
+    }
+}
+
+impl MessageHandler for BarAggregatorHandler {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, message: &dyn Any) {
+        if let Some(quote) = message.downcast_ref::<QuoteTick>() {
+            self.aggregator.borrow_mut().handle_quote(*quote);
+        } else if let Some(trade) = message.downcast_ref::<TradeTick>() {
+            self.aggregator.borrow_mut().handle_trade(*trade);
+        } else if let Some(bar) = message.downcast_ref::<Bar>() {
+            self.aggregator.borrow_mut().handle_bar(*bar);
+        } else {
+            log::error!("Invalid message type for bar aggregation, was {message:?}");
+        }
+    }
+
+    fn handle_response(&self, _resp: DataResponse) {}
+
+    fn handle_data(&self, data: Data) {
+        match data {
+            Data::Quote(quote) => self.aggregator.borrow_mut().handle_quote(quote),
+            Data::Trade(trade) => self.aggregator.borrow_mut().handle_trade(trade),
+            Data::Bar(bar) => self.aggregator.borrow_mut().handle_bar(bar),
+            _ => log::error!("Invalid data type for bar aggregation, was {data:?}"),
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -12,10 +12,10 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
-
 use std::{
     any::Any,
     cell::{Ref, RefCell},
+    collections::HashMap,
     num::NonZeroU64,
     rc::Rc,
 };
@@ -26,9 +26,13 @@ use nautilus_common::{
     msgbus::{handler::MessageHandler, MessageBus},
     timer::TimeEvent,
 };
+use nautilus_core::UnixNanos;
 use nautilus_model::{
-    data::Data,
+    data::{depth::DEPTH10_LEN, BookOrder, Data, OrderBookDeltas, OrderBookDepth10},
+    enums::RecordFlag,
     identifiers::{InstrumentId, Venue},
+    orderbook::{BookLevel, OrderBook},
+    types::Quantity,
 };
 use ustr::Ustr;
 
@@ -41,6 +45,8 @@ pub struct BookSnapshotInfo {
     pub root: Ustr,
     pub topic: Ustr,
     pub interval_ms: NonZeroU64,
+    /// If snapshots are published as `OrderBookDepth10` rather than `OrderBook`.
+    pub depth10: bool,
 }
 
 pub struct BookUpdater {
@@ -65,7 +71,20 @@ impl MessageHandler for BookUpdater {
         self.id
     }
 
-    fn handle(&self, message: &dyn Any) {}
+    fn handle(&self, message: &dyn Any) {
+        let mut cache = self.cache.borrow_mut();
+        let Some(book) = cache.order_book_mut(&self.instrument_id) else {
+            return; // Book not managed
+        };
+
+        if let Some(deltas) = message.downcast_ref::<OrderBookDeltas>() {
+            book.apply_deltas(deltas);
+        } else if let Some(depth) = message.downcast_ref::<OrderBookDepth10>() {
+            book.apply_depth(depth);
+        } else {
+            log::error!("Invalid message type for book update, was {message:?}");
+        }
+    }
     fn handle_response(&self, _resp: DataResponse) {}
     fn handle_data(&self, data: Data) {
         if let Some(book) = self
@@ -92,6 +111,7 @@ pub struct BookSnapshotter {
     pub snap_info: BookSnapshotInfo,
     pub cache: Rc<RefCell<Cache>>,
     pub msgbus: Rc<RefCell<MessageBus>>,
+    last_counts: RefCell<HashMap<InstrumentId, u64>>,
 }
 
 impl BookSnapshotter {
@@ -106,8 +126,13 @@ impl BookSnapshotter {
             stringify!(BookSnapshotter),
             snap_info.instrument_id
         );
+        let type_name = if snap_info.depth10 {
+            stringify!(OrderBookDepth10)
+        } else {
+            stringify!(OrderBook)
+        };
         let timer_name = format!(
-            "OrderBook|{}|{}",
+            "{type_name}|{}|{}",
             snap_info.instrument_id, snap_info.interval_ms
         );
 
@@ -117,25 +142,27 @@ impl BookSnapshotter {
             snap_info,
             cache,
             msgbus,
+            last_counts: RefCell::new(HashMap::new()),
         }
     }
 
     pub fn snapshot(&self, event: TimeEvent) {
         let cache = self.cache.borrow();
-        let mut msgbus = self.msgbus.borrow_mut();
+        let msgbus = self.msgbus.borrow();
 
         if self.snap_info.is_composite {
             let topic = self.snap_info.topic;
             let underlying = self.snap_info.root;
             for instrument in cache.instruments(&self.snap_info.venue, Some(&underlying)) {
-                self.publish_order_book(&instrument.id(), &topic, &cache, &mut msgbus);
+                self.publish_order_book(&instrument.id(), &topic, &cache, &msgbus, event.ts_init);
             }
         } else {
             self.publish_order_book(
                 &self.snap_info.instrument_id,
                 &self.snap_info.topic,
                 &cache,
-                &mut msgbus,
+                &msgbus,
+                event.ts_init,
             );
         }
     }
@@ -145,7 +172,8 @@ impl BookSnapshotter {
         instrument_id: &InstrumentId,
         topic: &Ustr,
         cache: &Ref<Cache>,
-        msgbus: &mut MessageBus,
+        msgbus: &MessageBus,
+        ts_init: UnixNanos,
     ) {
         let book = cache
             .order_book(instrument_id)
@@ -156,6 +184,61 @@ impl BookSnapshotter {
             return;
         }
 
-        msgbus.publish(topic, book as &dyn Any);
+        // Throttle to at most one snapshot per interval, only when the book has changed
+        let last_count = self
+            .last_counts
+            .borrow_mut()
+            .insert(*instrument_id, book.count);
+        if last_count == Some(book.count) {
+            log::debug!("OrderBook for {instrument_id} not updated since last snapshot");
+            return;
+        }
+
+        if self.snap_info.depth10 {
+            let depth = book_to_depth10(book, ts_init);
+            msgbus.publish(topic, &depth as &dyn Any);
+        } else {
+            msgbus.publish(topic, book as &dyn Any);
+        }
+    }
+}
+
+/// Returns an [`OrderBookDepth10`] of the top levels of the `book`, with any levels beyond
+/// those in the book left as null orders.
+fn book_to_depth10(book: &OrderBook, ts_init: UnixNanos) -> OrderBookDepth10 {
+    let mut bids = [BookOrder::default(); DEPTH10_LEN];
+    let mut asks = [BookOrder::default(); DEPTH10_LEN];
+    let mut bid_counts = [0; DEPTH10_LEN];
+    let mut ask_counts = [0; DEPTH10_LEN];
+
+    for (i, level) in book.bids(Some(DEPTH10_LEN)).enumerate() {
+        bids[i] = level_to_order(level);
+        bid_counts[i] = level.len() as u32;
+    }
+    for (i, level) in book.asks(Some(DEPTH10_LEN)).enumerate() {
+        asks[i] = level_to_order(level);
+        ask_counts[i] = level.len() as u32;
     }
+
+    OrderBookDepth10::new(
+        book.instrument_id,
+        bids,
+        asks,
+        bid_counts,
+        ask_counts,
+        RecordFlag::F_SNAPSHOT as u8,
+        book.sequence,
+        book.ts_last,
+        ts_init,
+    )
+}
+
+fn level_to_order(level: &BookLevel) -> BookOrder {
+    let precision = level.first().map_or(0, |order| order.size.precision);
+    BookOrder::new(
+        level.price.side,
+        level.price.value,
+        Quantity::from_raw(level.size_raw(), precision),
+        0,
+    )
 }
@@ -13,7 +13,14 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_model::{enums::BarIntervalType, identifiers::ClientId};
+use std::collections::HashMap;
+
+use nautilus_model::{
+    enums::BarIntervalType,
+    identifiers::{ClientId, InstrumentId},
+};
+
+use super::validation::DataValidationPolicy;
 
 /// Configuration for `DataEngine` instances.
 #[derive(Clone, Debug)]
@@ -24,6 +31,9 @@ pub struct DataEngineConfig {
     pub validate_data_sequence: bool,
     pub buffer_deltas: bool,
     pub external_clients: Option<Vec<ClientId>>,
+    pub validation_policy: Option<DataValidationPolicy>,
+    pub validation_policies: HashMap<InstrumentId, DataValidationPolicy>,
+    pub feed_stale_timeout_ms: u64,
     pub debug: bool,
 }
 
@@ -37,6 +47,9 @@ impl Default for DataEngineConfig {
             validate_data_sequence: false,
             buffer_deltas: false,
             external_clients: None,
+            validation_policy: None,
+            validation_policies: HashMap::new(),
+            feed_stale_timeout_ms: 2_000,
             debug: false,
         }
     }
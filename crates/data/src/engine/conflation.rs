@@ -0,0 +1,199 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Conflation of quote and trade streams to a reduced rate for slow consumers.
+
+use std::{any::Any, cell::RefCell, num::NonZeroU64, rc::Rc, str::FromStr};
+
+use nautilus_common::{
+    messages::data::DataResponse,
+    msgbus::{handler::MessageHandler, MessageBus},
+    timer::TimeEvent,
+};
+use nautilus_model::{
+    data::{BarSpecification, BarType, Data, QuoteTick, TradeTick},
+    enums::{AggregationSource, BarAggregation, PriceType},
+    identifiers::InstrumentId,
+};
+use ustr::Ustr;
+
+use crate::aggregation::BarBuilder;
+
+/// The method used to conflate a stream over each interval.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ConflationMode {
+    /// Publishes the latest quote or trade received in the interval.
+    #[default]
+    Latest,
+    /// Publishes a bar rolling up the quotes (mid price) or trades received in the interval.
+    Ohlc,
+}
+
+impl ConflationMode {
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Latest => "latest",
+            Self::Ohlc => "ohlc",
+        }
+    }
+}
+
+impl FromStr for ConflationMode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "latest" => Ok(Self::Latest),
+            "ohlc" => Ok(Self::Ohlc),
+            _ => anyhow::bail!("Invalid `ConflationMode`, was '{s}'"),
+        }
+    }
+}
+
+/// Contains information for conflating a specific quote or trade stream.
+#[derive(Clone, Debug)]
+pub struct ConflationInfo {
+    pub instrument_id: InstrumentId,
+    pub mode: ConflationMode,
+    pub interval_ms: NonZeroU64,
+    /// The topic the conflated stream is published on.
+    pub topic: Ustr,
+    /// If the stream is of quotes rather than trades.
+    pub quotes: bool,
+}
+
+enum Pending {
+    Quote(QuoteTick),
+    Trade(TradeTick),
+    Ohlc(BarBuilder),
+}
+
+/// Conflates the quotes or trades published on the message bus, publishing at most one
+/// conflated value per interval to the conflated topic.
+pub struct Conflator {
+    pub id: Ustr,
+    pub timer_name: Ustr,
+    pub info: ConflationInfo,
+    pub msgbus: Rc<RefCell<MessageBus>>,
+    pending: RefCell<Option<Pending>>,
+}
+
+impl Conflator {
+    /// Creates a new [`Conflator`] instance.
+    pub fn new(info: ConflationInfo, msgbus: Rc<RefCell<MessageBus>>) -> Self {
+        Self {
+            id: Ustr::from(&format!("{}-{}", stringify!(Conflator), info.topic)),
+            timer_name: Ustr::from(&format!("{}|{}", stringify!(Conflator), info.topic)),
+            info,
+            msgbus,
+            pending: RefCell::new(None),
+        }
+    }
+
+    /// Returns the bar type of the roll-ups for [`ConflationMode::Ohlc`].
+    #[must_use]
+    pub fn bar_type(&self) -> BarType {
+        let price_type = if self.info.quotes {
+            PriceType::Mid
+        } else {
+            PriceType::Last
+        };
+        BarType::new(
+            self.info.instrument_id,
+            BarSpecification::new(
+                self.info.interval_ms.get() as usize,
+                BarAggregation::Millisecond,
+                price_type,
+            ),
+            AggregationSource::Internal,
+        )
+    }
+
+    /// Publishes the value conflated over the interval ending at the `event` (if any received).
+    pub fn flush(&self, event: TimeEvent) {
+        let Some(pending) = self.pending.borrow_mut().take() else {
+            return; // Nothing received in the interval
+        };
+
+        let msgbus = self.msgbus.borrow();
+        let topic = &self.info.topic;
+        match pending {
+            Pending::Quote(quote) => msgbus.publish(topic, &quote as &dyn Any),
+            Pending::Trade(trade) => msgbus.publish(topic, &trade as &dyn Any),
+            Pending::Ohlc(mut builder) => {
+                let bar = builder.build(event.ts_event, event.ts_init);
+                msgbus.publish(topic, &bar as &dyn Any);
+            }
+        }
+    }
+
+    fn update_ohlc(&self, pending: &mut Option<Pending>, message: &dyn Any) {
+        let (price, size, ts_event) = if let Some(quote) = message.downcast_ref::<QuoteTick>() {
+            (
+                quote.extract_price(PriceType::Mid),
+                quote.extract_size(PriceType::Mid),
+                quote.ts_event,
+            )
+        } else if let Some(trade) = message.downcast_ref::<TradeTick>() {
+            (trade.price, trade.size, trade.ts_event)
+        } else {
+            log::error!("Invalid message type for conflation, was {message:?}");
+            return;
+        };
+
+        // The builder is created per interval, so no bar is built for an interval without updates
+        if !matches!(pending, Some(Pending::Ohlc(_))) {
+            let builder = BarBuilder::new(self.bar_type(), price.precision, size.precision);
+            *pending = Some(Pending::Ohlc(builder));
+        }
+        if let Some(Pending::Ohlc(builder)) = pending {
+            builder.update(price, size, ts_event);
+        }
+    }
+}
+
+impl MessageHandler for Conflator {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, message: &dyn Any) {
+        let mut pending = self.pending.borrow_mut();
+        match self.info.mode {
+            ConflationMode::Latest => {
+                if let Some(quote) = message.downcast_ref::<QuoteTick>() {
+                    *pending = Some(Pending::Quote(*quote));
+                } else if let Some(trade) = message.downcast_ref::<TradeTick>() {
+                    *pending = Some(Pending::Trade(*trade));
+                } else {
+                    log::error!("Invalid message type for conflation, was {message:?}");
+                }
+            }
+            ConflationMode::Ohlc => self.update_ohlc(&mut pending, message),
+        }
+    }
+    fn handle_response(&self, _resp: DataResponse) {}
+    fn handle_data(&self, data: Data) {
+        match data {
+            Data::Quote(quote) => self.handle(&quote as &dyn Any),
+            Data::Trade(trade) => self.handle(&trade as &dyn Any),
+            _ => log::error!("Invalid data type for conflation, was {data:?}"),
+        }
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -0,0 +1,213 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Projection of the funding PnL of open positions in perpetual instruments.
+
+use nautilus_common::cache::Cache;
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::{FundingRateUpdate, ProjectedFundingPnl},
+    enums::PriceType,
+    identifiers::InstrumentId,
+    instruments::InstrumentAny,
+    position::Position,
+    types::{Money, Price},
+};
+use rust_decimal::prelude::ToPrimitive;
+
+/// Returns the price to value positions at for funding, preferring the mark price.
+#[must_use]
+pub fn funding_price(cache: &Cache, instrument_id: &InstrumentId) -> Option<Price> {
+    [PriceType::Mark, PriceType::Mid, PriceType::Last]
+        .into_iter()
+        .find_map(|price_type| cache.price(instrument_id, price_type))
+}
+
+/// Projects the funding PnL of the open `position` at the next funding time, valuing the
+/// position at `price`.
+///
+/// Long positions pay a positive funding rate to short positions, so the PnL is the negated
+/// rate applied to the signed notional value of the position.
+#[must_use]
+pub fn project_funding_pnl(
+    update: &FundingRateUpdate,
+    instrument: &InstrumentAny,
+    position: &Position,
+    price: Price,
+    ts_init: UnixNanos,
+) -> ProjectedFundingPnl {
+    let notional = instrument.calculate_notional_value(position.quantity, price, None);
+    let direction = position.signed_qty.signum();
+    let funding = |rate: rust_decimal::Decimal| {
+        let rate = rate.to_f64().unwrap_or(0.0);
+        Money::new(-direction * rate * notional.as_f64(), notional.currency)
+    };
+
+    ProjectedFundingPnl {
+        instrument_id: update.instrument_id,
+        position_id: position.id,
+        notional,
+        pnl: funding(update.rate),
+        predicted_pnl: update.predicted_rate.map(funding),
+        next_funding_ns: update.next_funding_ns,
+        ts_event: update.ts_event,
+        ts_init,
+    }
+}
+
+/// Projects the funding PnL of all open positions in the instrument of the `update`.
+///
+/// Returns an empty vector if the instrument, or a price to value the positions at, is not
+/// in the `cache`.
+#[must_use]
+pub fn project_funding_pnls(
+    cache: &Cache,
+    update: &FundingRateUpdate,
+    ts_init: UnixNanos,
+) -> Vec<ProjectedFundingPnl> {
+    let instrument_id = update.instrument_id;
+    let (Some(instrument), Some(price)) = (
+        cache.instrument(&instrument_id),
+        funding_price(cache, &instrument_id),
+    ) else {
+        return Vec::new();
+    };
+
+    cache
+        .positions_open(None, Some(&instrument_id), None, None)
+        .into_iter()
+        .map(|position| project_funding_pnl(update, instrument, position, price, ts_init))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{OrderSide, OrderType},
+        identifiers::PositionId,
+        instruments::{
+            stubs::{crypto_perpetual_ethusdt, xbtusd_bitmex},
+            CryptoPerpetual,
+        },
+        orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+        types::Quantity,
+    };
+    use rstest::rstest;
+    use rust_decimal::Decimal;
+
+    use super::*;
+
+    fn position(instrument: &InstrumentAny, side: OrderSide, quantity: &str) -> Position {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(side)
+            .quantity(Quantity::from(quantity))
+            .build();
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            instrument,
+            None,
+            Some(PositionId::new("P-1")),
+            Some(Price::from("2000.00")),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        Position::new(instrument, fill.into())
+    }
+
+    fn update(instrument_id: InstrumentId, rate: Decimal) -> FundingRateUpdate {
+        FundingRateUpdate::new(
+            instrument_id,
+            rate,
+            Some(-rate),
+            Some(UnixNanos::from(10)),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        )
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, "-0.20", "0.20")]
+    #[case(OrderSide::Sell, "0.20", "-0.20")]
+    fn test_project_funding_pnl_linear(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] side: OrderSide,
+        #[case] expected_pnl: &str,
+        #[case] expected_predicted_pnl: &str,
+    ) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let position = position(&instrument, side, "1.000");
+        let update = update(instrument.id(), Decimal::new(1, 4));
+
+        let result = project_funding_pnl(
+            &update,
+            &instrument,
+            &position,
+            Price::from("2000.00"),
+            UnixNanos::from(2),
+        );
+
+        assert_eq!(result.position_id, position.id);
+        assert_eq!(result.notional, Money::from("2000.00 USDT"));
+        assert_eq!(
+            result.pnl,
+            Money::from(format!("{expected_pnl} USDT").as_str())
+        );
+        assert_eq!(
+            result.predicted_pnl,
+            Some(Money::from(
+                format!("{expected_predicted_pnl} USDT").as_str()
+            ))
+        );
+        assert_eq!(result.next_funding_ns, Some(UnixNanos::from(10)));
+        assert_eq!(result.ts_init, UnixNanos::from(2));
+    }
+
+    #[rstest]
+    fn test_project_funding_pnl_inverse(xbtusd_bitmex: CryptoPerpetual) {
+        let instrument = InstrumentAny::CryptoPerpetual(xbtusd_bitmex);
+        let position = position(&instrument, OrderSide::Buy, "10000");
+        let update = update(instrument.id(), Decimal::new(1, 4));
+
+        let result = project_funding_pnl(
+            &update,
+            &instrument,
+            &position,
+            Price::from("50000.0"),
+            UnixNanos::from(2),
+        );
+
+        assert_eq!(result.notional, Money::from("0.2 BTC"));
+        assert_eq!(result.pnl, Money::from("-0.00002 BTC"));
+    }
+
+    #[rstest]
+    fn test_project_funding_pnls_when_no_price(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let mut cache = Cache::default();
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        cache.add_instrument(instrument.clone()).unwrap();
+
+        let result = project_funding_pnls(
+            &cache,
+            &update(instrument.id(), Decimal::new(1, 4)),
+            UnixNanos::from(2),
+        );
+
+        assert!(result.is_empty());
+    }
+}
@@ -0,0 +1,241 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Calculation of the implied volatility and greeks of options from their quotes.
+
+use std::str::FromStr;
+
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::{greeks::imply_vol_and_greeks, GreeksData, QuoteTick},
+    enums::OptionKind,
+    identifiers::InstrumentId,
+    instruments::OptionContract,
+};
+use ustr::Ustr;
+
+const NANOSECONDS_IN_YEAR: f64 = 365.25 * 24.0 * 60.0 * 60.0 * 1_000_000_000.0;
+
+/// The pricing model used to imply volatility and calculate greeks.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum GreeksModel {
+    /// Black-Scholes, for options on a spot underlying paying a continuous dividend yield.
+    #[default]
+    BlackScholes,
+    /// Black-76, for options on a futures or forward underlying.
+    Black76,
+}
+
+impl GreeksModel {
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::BlackScholes => "black_scholes",
+            Self::Black76 => "black_76",
+        }
+    }
+}
+
+impl FromStr for GreeksModel {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "black_scholes" => Ok(Self::BlackScholes),
+            "black_76" => Ok(Self::Black76),
+            _ => anyhow::bail!("Invalid `GreeksModel`, was '{s}'"),
+        }
+    }
+}
+
+/// Contains information for calculating the greeks of a specific option.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GreeksInfo {
+    pub instrument_id: InstrumentId,
+    pub underlying_id: InstrumentId,
+    pub model: GreeksModel,
+    /// The continuously compounded risk-free interest rate.
+    pub interest_rate: f64,
+    /// The continuous dividend yield of the underlying, for [`GreeksModel::BlackScholes`].
+    pub dividend_yield: f64,
+    /// The topic the greeks are published on.
+    pub topic: Ustr,
+}
+
+impl GreeksInfo {
+    /// Returns the cost of carry of the underlying for the model.
+    #[must_use]
+    pub fn cost_of_carry(&self) -> f64 {
+        match self.model {
+            GreeksModel::BlackScholes => self.interest_rate - self.dividend_yield,
+            GreeksModel::Black76 => 0.0, // Futures carry no cost
+        }
+    }
+}
+
+/// Returns the mid price of the `quote`.
+#[must_use]
+pub fn mid_price(quote: &QuoteTick) -> f64 {
+    (quote.bid_price.as_f64() + quote.ask_price.as_f64()) / 2.0
+}
+
+/// Calculates the implied volatility and greeks of the `option` from its price and the
+/// price of its underlying, as of `ts_event`.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If the option has expired as of `ts_event`.
+/// - If a volatility cannot be implied from the prices.
+pub fn calculate_greeks(
+    info: &GreeksInfo,
+    option: &OptionContract,
+    option_price: f64,
+    underlying_price: f64,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> anyhow::Result<GreeksData> {
+    if option.expiration_ns <= ts_event {
+        anyhow::bail!("Option {} has expired", option.id);
+    }
+
+    let t = (option.expiration_ns.as_u64() - ts_event.as_u64()) as f64 / NANOSECONDS_IN_YEAR;
+    let is_call = option.option_kind == OptionKind::Call;
+    let result = imply_vol_and_greeks(
+        underlying_price,
+        info.interest_rate,
+        info.cost_of_carry(),
+        is_call,
+        option.strike_price.as_f64(),
+        t,
+        option_price,
+        option.multiplier.as_f64(),
+    );
+
+    if !result.vol.is_finite() || result.vol <= 0.0 {
+        anyhow::bail!(
+            "Cannot imply volatility for {} from price {option_price} with underlying price {underlying_price}",
+            option.id
+        );
+    }
+
+    Ok(GreeksData::new(
+        option.id,
+        underlying_price,
+        info.interest_rate,
+        t,
+        result,
+        ts_event,
+        ts_init,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::greeks::black_scholes_greeks, instruments::stubs::option_contract_appl,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn info(model: GreeksModel) -> GreeksInfo {
+        GreeksInfo {
+            instrument_id: InstrumentId::from("AAPL211217C00150000.OPRA"),
+            underlying_id: InstrumentId::from("AAPL.OPRA"),
+            model,
+            interest_rate: 0.05,
+            dividend_yield: 0.02,
+            topic: Ustr::from("data.greeks.OPRA.AAPL211217C00150000"),
+        }
+    }
+
+    #[rstest]
+    #[case("black_scholes", GreeksModel::BlackScholes)]
+    #[case("BLACK_76", GreeksModel::Black76)]
+    fn test_greeks_model_from_str(#[case] input: &str, #[case] expected: GreeksModel) {
+        assert_eq!(input.parse::<GreeksModel>().unwrap(), expected);
+    }
+
+    #[rstest]
+    fn test_greeks_model_from_str_invalid() {
+        assert!("binomial".parse::<GreeksModel>().is_err());
+    }
+
+    #[rstest]
+    #[case(GreeksModel::BlackScholes, 0.03)]
+    #[case(GreeksModel::Black76, 0.0)]
+    fn test_cost_of_carry(#[case] model: GreeksModel, #[case] expected: f64) {
+        assert!((info(model).cost_of_carry() - expected).abs() < 1e-12);
+    }
+
+    #[rstest]
+    #[case(GreeksModel::BlackScholes)]
+    #[case(GreeksModel::Black76)]
+    fn test_calculate_greeks_implies_model_volatility(
+        option_contract_appl: OptionContract,
+        #[case] model: GreeksModel,
+    ) {
+        let info = info(model);
+        let ts_event = UnixNanos::from(
+            option_contract_appl.expiration_ns.as_u64() - 90 * 24 * 60 * 60 * 1_000_000_000,
+        );
+        let t = (option_contract_appl.expiration_ns.as_u64() - ts_event.as_u64()) as f64
+            / NANOSECONDS_IN_YEAR;
+        let underlying_price = 150.0;
+        let option_price = black_scholes_greeks(
+            underlying_price,
+            info.interest_rate,
+            info.cost_of_carry(),
+            0.25,
+            true,
+            option_contract_appl.strike_price.as_f64(),
+            t,
+            1.0,
+        )
+        .price;
+
+        let greeks = calculate_greeks(
+            &info,
+            &option_contract_appl,
+            option_price,
+            underlying_price,
+            ts_event,
+            ts_event,
+        )
+        .unwrap();
+
+        assert_eq!(greeks.instrument_id, option_contract_appl.id);
+        assert!((greeks.expiry_in_years - t).abs() < 1e-12);
+        assert!((greeks.vol - 0.25).abs() < 1e-6);
+        assert!(greeks.delta > 0.0 && greeks.delta < 1.0);
+        assert!(greeks.gamma > 0.0);
+        assert!(greeks.theta < 0.0);
+    }
+
+    #[rstest]
+    fn test_calculate_greeks_when_expired(option_contract_appl: OptionContract) {
+        let result = calculate_greeks(
+            &info(GreeksModel::BlackScholes),
+            &option_contract_appl,
+            1.0,
+            150.0,
+            option_contract_appl.expiration_ns,
+            option_contract_appl.expiration_ns,
+        );
+
+        assert!(result.is_err());
+    }
+}
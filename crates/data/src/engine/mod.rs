@@ -33,8 +33,17 @@
 #![allow(unused_variables)]
 #![allow(unused_assignments)]
 
+pub mod arbitration;
+pub mod bar;
 pub mod book;
 pub mod config;
+pub mod conflation;
+pub mod funding;
+pub mod greeks;
+pub mod recorder;
+pub mod reference;
+pub mod resample;
+pub mod validation;
 
 #[cfg(test)]
 mod tests;
@@ -43,13 +52,18 @@ use std::{
     any::Any,
     cell::{Ref, RefCell},
     collections::{HashMap, HashSet, VecDeque},
-    num::NonZeroU64,
     rc::Rc,
+    str::FromStr,
     sync::Arc,
 };
 
+use arbitration::FeedArbiter;
+use bar::BarAggregatorHandler;
 use book::{BookSnapshotInfo, BookSnapshotter, BookUpdater};
+use chrono::{NaiveTime, Timelike};
 use config::DataEngineConfig;
+use conflation::{ConflationInfo, ConflationMode, Conflator};
+use greeks::{GreeksInfo, GreeksModel};
 use indexmap::IndexMap;
 use nautilus_common::{
     cache::Cache,
@@ -65,22 +79,33 @@ use nautilus_common::{
 use nautilus_core::{
     correctness::{check_key_in_index_map, check_key_not_in_index_map, FAILED},
     datetime::{millis_to_nanos, NANOSECONDS_IN_MILLISECOND, NANOSECONDS_IN_SECOND},
+    UnixNanos, UUID4,
 };
 use nautilus_model::{
     data::{
-        Bar, BarType, Data, DataType, OrderBookDelta, OrderBookDeltas, OrderBookDepth10, QuoteTick,
+        Bar, BarSpecification, BarType, Data, DataType, FundingRateUpdate, OrderBookDelta,
+        OrderBookDeltas, OrderBookDepth10, ProjectedFundingPnl, QuoteTick, ReferencePriceUpdate,
         TradeTick,
     },
-    enums::{AggregationSource, BarAggregation, BookType, PriceType, RecordFlag},
-    identifiers::{ClientId, InstrumentId, Venue},
+    enums::{
+        AggregationSource, BarAggregation, BookType, PriceType, RecordFlag, ReferencePriceType,
+    },
+    identifiers::{ClientId, InstrumentId, Symbol, Venue},
     instruments::{InstrumentAny, SyntheticInstrument},
     orderbook::OrderBook,
+    types::Quantity,
+};
+use recorder::DataRecorder;
+use reference::{
+    ReferencePriceCalculator, ReferencePriceInfo, RollingVwap, SessionTwap,
+    DEFAULT_VWAP_WINDOW_SECS,
 };
 use ustr::Ustr;
+use validation::{DataValidationPolicy, DataValidator, ValidationCounters};
 
 use crate::{
     aggregation::{
-        BarAggregator, TickBarAggregator, TimeBarAggregator, ValueBarAggregator,
+        BarAggregator, NewBarCallback, TickBarAggregator, TimeBarAggregator, ValueBarAggregator,
         VolumeBarAggregator,
     },
     client::DataClientAdapter,
@@ -95,12 +120,19 @@ pub struct DataEngine {
     default_client: Option<DataClientAdapter>,
     external_clients: HashSet<ClientId>,
     routing_map: IndexMap<Venue, ClientId>,
-    book_intervals: HashMap<NonZeroU64, HashSet<InstrumentId>>,
     book_updaters: HashMap<InstrumentId, Rc<BookUpdater>>,
-    book_snapshotters: HashMap<InstrumentId, Rc<BookSnapshotter>>,
-    bar_aggregators: HashMap<BarType, Box<dyn BarAggregator>>,
+    book_snapshotters: HashMap<Ustr, Rc<BookSnapshotter>>,
+    conflators: HashMap<Ustr, (Ustr, Rc<Conflator>)>,
+    bar_aggregators: HashMap<BarType, Rc<RefCell<dyn BarAggregator>>>,
+    bar_aggregator_handlers: HashMap<BarType, (Ustr, ShareableMessageHandler)>,
     synthetic_quote_feeds: HashMap<InstrumentId, Vec<SyntheticInstrument>>,
     synthetic_trade_feeds: HashMap<InstrumentId, Vec<SyntheticInstrument>>,
+    greeks_feeds: HashMap<InstrumentId, Vec<GreeksInfo>>,
+    reference_prices: HashMap<InstrumentId, Vec<ReferencePriceInfo>>,
+    catch_up_watermarks: HashMap<Ustr, UnixNanos>,
+    validator: DataValidator,
+    feed_arbiters: HashMap<InstrumentId, FeedArbiter>,
+    recorder: Option<Box<dyn DataRecorder>>,
     buffered_deltas_map: HashMap<InstrumentId, Vec<OrderBookDelta>>, // TODO: Use OrderBookDeltas?
     msgbus_priority: u8,
     command_queue: VecDeque<SubscriptionCommand>,
@@ -116,6 +148,11 @@ impl DataEngine {
         msgbus: Rc<RefCell<MessageBus>>,
         config: Option<DataEngineConfig>,
     ) -> Self {
+        let config = config.unwrap_or_default();
+        let validator = DataValidator::new(
+            config.validation_policy.clone(),
+            config.validation_policies.clone(),
+        );
         Self {
             clock,
             cache,
@@ -124,16 +161,23 @@ impl DataEngine {
             default_client: None,
             external_clients: HashSet::new(),
             routing_map: IndexMap::new(),
-            book_intervals: HashMap::new(),
             book_updaters: HashMap::new(),
             book_snapshotters: HashMap::new(),
+            conflators: HashMap::new(),
             bar_aggregators: HashMap::new(),
+            bar_aggregator_handlers: HashMap::new(),
             synthetic_quote_feeds: HashMap::new(),
             synthetic_trade_feeds: HashMap::new(),
+            greeks_feeds: HashMap::new(),
+            reference_prices: HashMap::new(),
+            catch_up_watermarks: HashMap::new(),
+            validator,
+            feed_arbiters: HashMap::new(),
+            recorder: None,
             buffered_deltas_map: HashMap::new(),
             msgbus_priority: 10, // High-priority for built-in component
             command_queue: VecDeque::new(),
-            config: config.unwrap_or_default(),
+            config,
         }
     }
 
@@ -158,20 +202,49 @@ impl DataEngine {
         self.default_client = Some(client);
     }
 
+    /// Sets the validation `policy` for the quotes and trades of the `instrument_id`.
+    ///
+    /// Any existing policy for the instrument will be overwritten.
+    pub fn set_validation_policy(
+        &mut self,
+        instrument_id: InstrumentId,
+        policy: DataValidationPolicy,
+    ) {
+        self.validator.set_policy(instrument_id, policy);
+    }
+
+    /// Registers the given `recorder` with the engine, to record all data processed.
+    ///
+    /// # Warnings
+    ///
+    /// Any existing recorder will be flushed and overwritten.
+    pub fn register_recorder(&mut self, recorder: Box<dyn DataRecorder>) {
+        if let Some(mut existing) = self.recorder.replace(recorder) {
+            existing.flush();
+        }
+        log::info!("Registered data recorder");
+    }
+
     pub fn start(self) {
         self.clients.values().for_each(|client| client.start());
     }
 
-    pub fn stop(self) {
+    pub fn stop(mut self) {
         self.clients.values().for_each(|client| client.stop());
+        if let Some(recorder) = &mut self.recorder {
+            recorder.flush();
+        }
     }
 
     pub fn reset(self) {
         self.clients.values().for_each(|client| client.reset());
     }
 
-    pub fn dispose(self) {
+    pub fn dispose(mut self) {
         self.clients.values().for_each(|client| client.dispose());
+        if let Some(recorder) = &mut self.recorder {
+            recorder.flush();
+        }
         self.clock.borrow_mut().cancel_timers();
     }
 
@@ -270,6 +343,97 @@ impl DataEngine {
         self.collect_subscriptions(|client| &client.subscriptions_trade_tick)
     }
 
+    #[must_use]
+    pub fn subscribed_synthetic_quotes(&self) -> Vec<InstrumentId> {
+        collect_synthetics(&self.synthetic_quote_feeds)
+    }
+
+    #[must_use]
+    pub fn subscribed_synthetic_trades(&self) -> Vec<InstrumentId> {
+        collect_synthetics(&self.synthetic_trade_feeds)
+    }
+
+    #[must_use]
+    pub fn subscribed_greeks(&self) -> Vec<InstrumentId> {
+        self.greeks_feeds
+            .values()
+            .flatten()
+            .map(|info| info.instrument_id)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    #[must_use]
+    pub fn subscribed_reference_prices(&self) -> Vec<(InstrumentId, ReferencePriceType)> {
+        self.reference_prices
+            .values()
+            .flatten()
+            .map(|info| (info.instrument_id, info.price_type()))
+            .collect()
+    }
+
+    /// Returns the latest reference price of the `price_type` calculated for the `instrument_id`.
+    #[must_use]
+    pub fn reference_price(
+        &self,
+        instrument_id: &InstrumentId,
+        price_type: ReferencePriceType,
+    ) -> Option<ReferencePriceUpdate> {
+        self.reference_prices
+            .get(instrument_id)?
+            .iter()
+            .find(|info| info.price_type() == price_type)
+            .and_then(|info| info.last)
+    }
+
+    #[must_use]
+    pub fn validation_counters(&self, instrument_id: &InstrumentId) -> ValidationCounters {
+        self.validator
+            .counters(instrument_id)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn active_feed(&self, instrument_id: &InstrumentId) -> Option<ClientId> {
+        self.feed_arbiters
+            .get(instrument_id)
+            .and_then(FeedArbiter::active)
+    }
+
+    /// Returns the funding PnL projected at the latest funding rate of the `instrument_id`,
+    /// for each of its open positions.
+    #[must_use]
+    pub fn projected_funding_pnls(&self, instrument_id: &InstrumentId) -> Vec<ProjectedFundingPnl> {
+        let cache = self.cache.borrow();
+        let Some(update) = cache.funding_rate(instrument_id) else {
+            return Vec::new();
+        };
+        let ts_now = self.clock.borrow().timestamp_ns();
+        funding::project_funding_pnls(&cache, update, ts_now)
+    }
+
+    /// Resamples the cached bars of the `bar_type` into bars of the `target` specification,
+    /// e.g. 5-minute bars from cached 1-minute bars.
+    ///
+    /// Returns the resampled bars in ascending order, including the trailing partial bar only
+    /// where `include_partial` is true.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the bar types cannot be resampled.
+    pub fn resample_bars(
+        &self,
+        bar_type: &BarType,
+        target: BarSpecification,
+        include_partial: bool,
+    ) -> anyhow::Result<Vec<Bar>> {
+        let mut bars = self.cache.borrow().bars(bar_type).unwrap_or_default();
+        bars.reverse(); // Cached bars are most recent first
+        resample::resample_bars(bar_type, target, &bars, include_partial)
+    }
+
     #[must_use]
     pub fn subscribed_bars(&self) -> Vec<BarType> {
         self.collect_subscriptions(|client| &client.subscriptions_bar)
@@ -345,13 +509,35 @@ impl DataEngine {
             Action::Subscribe => match cmd.data_type.type_name() {
                 stringify!(OrderBookDelta) => self.handle_subscribe_book_deltas(&cmd),
                 stringify!(OrderBook) => self.handle_subscribe_book_snapshots(&cmd),
+                stringify!(OrderBookDepth10) if is_throttled(&cmd.data_type) => {
+                    self.handle_subscribe_book_snapshots(&cmd)
+                }
                 stringify!(Bar) => self.handle_subscribe_bars(&cmd),
+                stringify!(QuoteTick) | stringify!(TradeTick) if is_throttled(&cmd.data_type) => {
+                    self.handle_subscribe_conflated(&cmd)
+                }
+                stringify!(QuoteTick) | stringify!(TradeTick) if is_synthetic(&cmd.data_type) => {
+                    self.handle_subscribe_synthetic(&cmd)
+                }
+                stringify!(GreeksData) => self.handle_subscribe_greeks(&cmd),
+                stringify!(ReferencePriceUpdate) => self.handle_subscribe_reference_price(&cmd),
                 _ => Ok(()), // No other actions for engine
             },
             Action::Unsubscribe => match cmd.data_type.type_name() {
                 stringify!(OrderBookDelta) => self.handle_unsubscribe_book_deltas(&cmd),
                 stringify!(OrderBook) => self.handle_unsubscribe_book_snapshots(&cmd),
+                stringify!(OrderBookDepth10) if is_throttled(&cmd.data_type) => {
+                    self.handle_unsubscribe_book_snapshots(&cmd)
+                }
                 stringify!(Bar) => self.handle_unsubscribe_bars(&cmd),
+                stringify!(QuoteTick) | stringify!(TradeTick) if is_throttled(&cmd.data_type) => {
+                    self.handle_unsubscribe_conflated(&cmd)
+                }
+                stringify!(QuoteTick) | stringify!(TradeTick) if is_synthetic(&cmd.data_type) => {
+                    self.handle_unsubscribe_synthetic(&cmd)
+                }
+                stringify!(GreeksData) => self.handle_unsubscribe_greeks(&cmd),
+                stringify!(ReferencePriceUpdate) => self.handle_unsubscribe_reference_price(&cmd),
                 _ => Ok(()), // No other actions for engine
             },
         };
@@ -361,6 +547,24 @@ impl DataEngine {
             return;
         }
 
+        if matches!(cmd.action, Action::Unsubscribe)
+            && is_throttled(&cmd.data_type)
+            && self.is_consumed(&cmd.data_type)
+        {
+            return; // The stream is still consumed at its full rate
+        }
+
+        if is_synthetic(&cmd.data_type)
+            || matches!(
+                cmd.data_type.type_name(),
+                stringify!(GreeksData) | stringify!(ReferencePriceUpdate)
+            )
+        {
+            return; // Synthetic data, greeks and reference prices are derived by the engine
+        }
+
+        let catch_up = self.catch_up_request(&cmd);
+
         if let Some(client) = self.get_client_mut(&cmd.client_id, &cmd.venue) {
             client.execute(cmd);
         } else {
@@ -368,6 +572,124 @@ impl DataEngine {
                 "Cannot handle command: no client found for {}",
                 cmd.client_id
             );
+            return;
+        }
+
+        // Live data is subscribed before requesting the history, so none is missed between them
+        if let Some((req, topic)) = catch_up {
+            self.catch_up(req, topic);
+        }
+    }
+
+    /// Returns a request for the history of a subscription with a `lookback_ms` parameter,
+    /// along with the topic the subscription publishes on.
+    fn catch_up_request(&mut self, cmd: &SubscriptionCommand) -> Option<(DataRequest, Ustr)> {
+        if !matches!(cmd.action, Action::Subscribe) {
+            return None;
+        }
+
+        let lookback_ms = cmd.params.as_ref()?.get("lookback_ms")?;
+        let lookback_ms = match lookback_ms.parse::<u64>() {
+            Ok(lookback_ms) => lookback_ms,
+            Err(e) => {
+                log::error!("Cannot request history: invalid 'lookback_ms' {lookback_ms}, {e}");
+                return None;
+            }
+        };
+
+        let topic = {
+            let switchboard = &mut self.msgbus.borrow_mut().switchboard;
+            match cmd.data_type.type_name() {
+                stringify!(QuoteTick) => {
+                    switchboard.get_quotes_topic(cmd.data_type.instrument_id()?)
+                }
+                stringify!(TradeTick) => {
+                    switchboard.get_trades_topic(cmd.data_type.instrument_id()?)
+                }
+                stringify!(Bar)
+                    if cmd.data_type.bar_type().aggregation_source()
+                        == AggregationSource::External =>
+                {
+                    switchboard.get_bars_topic(cmd.data_type.bar_type())
+                }
+                _ => {
+                    log::warn!("Cannot request history for {} subscriptions", cmd.data_type);
+                    return None;
+                }
+            }
+        };
+
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let start = ts_now.saturating_sub(millis_to_nanos(lookback_ms as f64));
+        let mut metadata = cmd.data_type.metadata().cloned().unwrap_or_default();
+        metadata.insert("start".to_string(), start.to_string());
+        metadata.insert("end".to_string(), ts_now.to_string());
+
+        let req = DataRequest {
+            correlation_id: UUID4::new(),
+            client_id: cmd.client_id,
+            venue: cmd.venue,
+            data_type: DataType::new(cmd.data_type.type_name(), Some(metadata)),
+            ts_init: ts_now,
+            params: cmd.params.clone(),
+        };
+
+        Some((req, topic))
+    }
+
+    /// Requests the history of a subscription from its client, publishing it on the `topic` as
+    /// a response flagged as historical.
+    ///
+    /// Live data up to the last historical timestamp is then dropped as a duplicate, so
+    /// subscribers continue from the history without overlap.
+    fn catch_up(&mut self, req: DataRequest, topic: Ustr) {
+        let Some(client) = self.get_client(&req.client_id, &req.venue) else {
+            return;
+        };
+        let mut resp = client.request(req);
+
+        let ts_last = match resp.data_type.type_name() {
+            stringify!(QuoteTick) => resp
+                .data
+                .downcast_ref::<Vec<QuoteTick>>()
+                .and_then(|quotes| quotes.last().map(|quote| quote.ts_event)),
+            stringify!(TradeTick) => resp
+                .data
+                .downcast_ref::<Vec<TradeTick>>()
+                .and_then(|trades| trades.last().map(|trade| trade.ts_event)),
+            stringify!(Bar) => resp
+                .data
+                .downcast_ref::<Vec<Bar>>()
+                .and_then(|bars| bars.last().map(|bar| bar.ts_event)),
+            _ => None,
+        };
+
+        let Some(ts_last) = ts_last else {
+            log::debug!("No history to catch up for {topic}");
+            return;
+        };
+
+        resp.params
+            .get_or_insert_with(HashMap::new)
+            .insert("historical".to_string(), "true".to_string());
+
+        self.response(resp.clone());
+        self.msgbus.borrow().publish_response(&topic, &resp);
+        self.catch_up_watermarks.insert(topic, ts_last);
+    }
+
+    /// Returns whether live data at `ts_event` was already published as history on the `topic`.
+    fn is_caught_up(&mut self, topic: Ustr, ts_event: UnixNanos) -> bool {
+        match self.catch_up_watermarks.get(&topic) {
+            Some(ts_last) if ts_event <= *ts_last => {
+                log::debug!("Dropping live data on {topic} already published as history");
+                true
+            }
+            Some(_) => {
+                self.catch_up_watermarks.remove(&topic);
+                false
+            }
+            None => false,
         }
     }
 
@@ -386,12 +708,18 @@ impl DataEngine {
     pub fn process(&mut self, data: &dyn Any) {
         if let Some(instrument) = data.downcast_ref::<InstrumentAny>() {
             self.handle_instrument(instrument.clone());
+        } else if let Some(update) = data.downcast_ref::<FundingRateUpdate>() {
+            self.handle_funding_rate(*update);
         } else {
             log::error!("Cannot process data {data:?}, type is unrecognized");
         }
     }
 
     pub fn process_data(&mut self, data: Data) {
+        if let Some(recorder) = &mut self.recorder {
+            recorder.record(&data);
+        }
+
         match data {
             Data::Delta(delta) => self.handle_delta(delta),
             Data::Deltas(deltas) => self.handle_deltas(deltas.into_inner()),
@@ -402,7 +730,25 @@ impl DataEngine {
         }
     }
 
-    pub fn response(&self, resp: DataResponse) {
+    /// Processes the `data` received from the feed of the `client_id`.
+    ///
+    /// Where an instrument is subscribed from the feeds of multiple clients, only the data of
+    /// the active feed is processed, failing over to another feed when it goes stale.
+    pub fn process_feed_data(&mut self, client_id: ClientId, data: Data) {
+        let instrument_id = data.instrument_id();
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let stale_timeout_ns = millis_to_nanos(self.config.feed_stale_timeout_ms as f64);
+        let arbiter = self
+            .feed_arbiters
+            .entry(instrument_id)
+            .or_insert_with(|| FeedArbiter::new(instrument_id, stale_timeout_ns));
+
+        if arbiter.arbitrate(client_id, &data, ts_now) {
+            self.process_data(data);
+        }
+    }
+
+    pub fn response(&mut self, resp: DataResponse) {
         log::debug!("{}", format!("{RECV}{RES} {resp:?}"));
 
         match resp.data_type.type_name() {
@@ -414,12 +760,30 @@ impl DataEngine {
             stringify!(QuoteTick) => {
                 let quotes = Arc::downcast::<Vec<QuoteTick>>(resp.data.clone())
                     .expect("Invalid response data");
-                self.handle_quotes(quotes);
+                self.handle_quotes(quotes.clone());
+                self.handle_aggregated_bars(
+                    &resp,
+                    quotes.first().map(|quote| quote.ts_event),
+                    |aggregator| {
+                        quotes
+                            .iter()
+                            .for_each(|quote| aggregator.handle_quote(*quote));
+                    },
+                );
             }
             stringify!(TradeTick) => {
                 let trades = Arc::downcast::<Vec<TradeTick>>(resp.data.clone())
                     .expect("Invalid response data");
-                self.handle_trades(trades);
+                self.handle_trades(trades.clone());
+                self.handle_aggregated_bars(
+                    &resp,
+                    trades.first().map(|trade| trade.ts_event),
+                    |aggregator| {
+                        trades
+                            .iter()
+                            .for_each(|trade| aggregator.handle_trade(*trade));
+                    },
+                );
             }
             stringify!(Bar) => {
                 let bars =
@@ -449,6 +813,29 @@ impl DataEngine {
         msgbus.publish(&topic, &instrument as &dyn Any); // TODO: Optimize
     }
 
+    fn handle_funding_rate(&mut self, update: FundingRateUpdate) {
+        self.cache.as_ref().borrow_mut().add_funding_rate(update);
+
+        let (rates_topic, pnl_topic) = {
+            let mut msgbus = self.msgbus.borrow_mut();
+            (
+                msgbus
+                    .switchboard
+                    .get_funding_rates_topic(update.instrument_id),
+                msgbus
+                    .switchboard
+                    .get_funding_pnl_topic(update.instrument_id),
+            )
+        };
+
+        let msgbus = self.msgbus.borrow();
+        msgbus.publish(&rates_topic, &update as &dyn Any);
+
+        for projection in self.projected_funding_pnls(&update.instrument_id) {
+            msgbus.publish(&pnl_topic, &projection as &dyn Any);
+        }
+    }
+
     fn handle_delta(&mut self, delta: OrderBookDelta) {
         let deltas = if self.config.buffer_deltas {
             let buffer_deltas = self
@@ -517,30 +904,230 @@ impl DataEngine {
     }
 
     fn handle_quote(&mut self, quote: QuoteTick) {
+        let topic = self
+            .msgbus
+            .borrow_mut()
+            .switchboard
+            .get_quotes_topic(quote.instrument_id);
+
+        if self.is_caught_up(topic, quote.ts_event) {
+            return;
+        }
+
+        let Some(quote) = self.validator.validate_quote(quote) else {
+            return;
+        };
+
         if let Err(e) = self.cache.as_ref().borrow_mut().add_quote(quote) {
             log::error!("Error on cache insert: {e}");
         }
 
-        // TODO: Handle synthetics
+        // Bar aggregators publish re-entrantly, so the bus is only borrowed to publish
+        self.msgbus.borrow().publish(&topic, &quote as &dyn Any); // TODO: Optimize
 
-        let mut msgbus = self.msgbus.borrow_mut();
-        let topic = msgbus.switchboard.get_quotes_topic(quote.instrument_id);
-        msgbus.publish(&topic, &quote as &dyn Any); // TODO: Optimize
+        if let Some(mut synthetics) = self.synthetic_quote_feeds.remove(&quote.instrument_id) {
+            for synthetic in &mut synthetics {
+                match self.derive_synthetic_quote(synthetic, &quote) {
+                    Ok(synthetic_quote) => self.handle_quote(synthetic_quote),
+                    Err(e) => log::error!("Cannot update synthetic {}: {e}", synthetic.id),
+                }
+            }
+            self.synthetic_quote_feeds
+                .insert(quote.instrument_id, synthetics);
+        }
+
+        if let Some(feeds) = self.greeks_feeds.get(&quote.instrument_id).cloned() {
+            for info in &feeds {
+                self.update_greeks(info, &quote);
+            }
+        }
+
+        self.update_reference_prices(&quote.instrument_id, |info| info.update_quote(&quote));
+    }
+
+    /// Updates the reference prices of the `instrument_id` with the `update` function,
+    /// publishing each reference price it returns.
+    fn update_reference_prices(
+        &mut self,
+        instrument_id: &InstrumentId,
+        update: impl Fn(&mut ReferencePriceInfo) -> Option<ReferencePriceUpdate>,
+    ) {
+        let Some(infos) = self.reference_prices.get_mut(instrument_id) else {
+            return;
+        };
+
+        for info in infos {
+            if let Some(reference_price) = update(info) {
+                self.msgbus
+                    .borrow()
+                    .publish(&info.topic, &reference_price as &dyn Any);
+            }
+        }
+    }
+
+    /// Calculates and publishes the greeks of the option of the `info` on a `quote` for the
+    /// option or its underlying, using the latest cached quote of the other.
+    fn update_greeks(&self, info: &GreeksInfo, quote: &QuoteTick) {
+        let greeks = {
+            let cache = self.cache.borrow();
+            let Some(InstrumentAny::OptionContract(option)) = cache.instrument(&info.instrument_id)
+            else {
+                log::error!(
+                    "Cannot update greeks: no option {} in cache",
+                    info.instrument_id
+                );
+                return;
+            };
+            let (Some(option_quote), Some(underlying_quote)) = (
+                cache.quote(&info.instrument_id),
+                cache.quote(&info.underlying_id),
+            ) else {
+                log::debug!(
+                    "Cannot update greeks for {}: missing quotes",
+                    info.instrument_id
+                );
+                return;
+            };
+
+            greeks::calculate_greeks(
+                info,
+                option,
+                greeks::mid_price(option_quote),
+                greeks::mid_price(underlying_quote),
+                quote.ts_event,
+                quote.ts_init,
+            )
+        };
+
+        match greeks {
+            Ok(greeks) => self
+                .msgbus
+                .borrow()
+                .publish(&info.topic, &greeks as &dyn Any),
+            Err(e) => log::error!("Cannot update greeks for {}: {e}", info.instrument_id),
+        }
     }
 
     fn handle_trade(&mut self, trade: TradeTick) {
+        let topic = self
+            .msgbus
+            .borrow_mut()
+            .switchboard
+            .get_trades_topic(trade.instrument_id);
+
+        if self.is_caught_up(topic, trade.ts_event) {
+            return;
+        }
+
+        let Some(trade) = self.validator.validate_trade(trade) else {
+            return;
+        };
+
         if let Err(e) = self.cache.as_ref().borrow_mut().add_trade(trade) {
             log::error!("Error on cache insert: {e}");
         }
 
-        // TODO: Handle synthetics
+        self.msgbus.borrow().publish(&topic, &trade as &dyn Any); // TODO: Optimize
 
-        let mut msgbus = self.msgbus.borrow_mut();
-        let topic = msgbus.switchboard.get_trades_topic(trade.instrument_id);
-        msgbus.publish(&topic, &trade as &dyn Any); // TODO: Optimize
+        if let Some(mut synthetics) = self.synthetic_trade_feeds.remove(&trade.instrument_id) {
+            for synthetic in &mut synthetics {
+                match self.derive_synthetic_trade(synthetic, &trade) {
+                    Ok(synthetic_trade) => self.handle_trade(synthetic_trade),
+                    Err(e) => log::error!("Cannot update synthetic {}: {e}", synthetic.id),
+                }
+            }
+            self.synthetic_trade_feeds
+                .insert(trade.instrument_id, synthetics);
+        }
+
+        self.update_reference_prices(&trade.instrument_id, |info| info.update_trade(&trade));
+    }
+
+    /// Derives a quote for the `synthetic` from its formula, using the prices of the `quote`
+    /// and the latest cached quotes of the other components.
+    fn derive_synthetic_quote(
+        &self,
+        synthetic: &mut SyntheticInstrument,
+        quote: &QuoteTick,
+    ) -> anyhow::Result<QuoteTick> {
+        let cache = self.cache.borrow();
+        let mut bid_prices = Vec::with_capacity(synthetic.components.len());
+        let mut ask_prices = Vec::with_capacity(synthetic.components.len());
+
+        for component in &synthetic.components {
+            let component_quote = if *component == quote.instrument_id {
+                quote
+            } else {
+                cache
+                    .quote(component)
+                    .ok_or_else(|| anyhow::anyhow!("Missing quote for formula input {component}"))?
+            };
+            bid_prices.push(component_quote.bid_price.as_f64());
+            ask_prices.push(component_quote.ask_price.as_f64());
+        }
+
+        let bid_price = synthetic.calculate(&bid_prices)?;
+        let ask_price = synthetic.calculate(&ask_prices)?;
+        let size_one = Quantity::new(1.0, 0);
+
+        QuoteTick::new_checked(
+            synthetic.id,
+            bid_price,
+            ask_price,
+            size_one,
+            size_one,
+            quote.ts_event,
+            quote.ts_init,
+        )
+    }
+
+    /// Derives a trade for the `synthetic` from its formula, using the price of the `trade`
+    /// and the latest cached trades of the other components.
+    fn derive_synthetic_trade(
+        &self,
+        synthetic: &mut SyntheticInstrument,
+        trade: &TradeTick,
+    ) -> anyhow::Result<TradeTick> {
+        let cache = self.cache.borrow();
+        let mut prices = Vec::with_capacity(synthetic.components.len());
+
+        for component in &synthetic.components {
+            let price = if *component == trade.instrument_id {
+                trade.price
+            } else {
+                cache
+                    .trade(component)
+                    .ok_or_else(|| anyhow::anyhow!("Missing trade for formula input {component}"))?
+                    .price
+            };
+            prices.push(price.as_f64());
+        }
+
+        let price = synthetic.calculate(&prices)?;
+        let size_one = Quantity::new(1.0, 0);
+
+        TradeTick::new_checked(
+            synthetic.id,
+            price,
+            size_one,
+            trade.aggressor_side,
+            trade.trade_id,
+            trade.ts_event,
+            trade.ts_init,
+        )
     }
 
     fn handle_bar(&mut self, bar: Bar) {
+        let topic = self
+            .msgbus
+            .borrow_mut()
+            .switchboard
+            .get_bars_topic(bar.bar_type);
+
+        if self.is_caught_up(topic, bar.ts_event) {
+            return;
+        }
+
         // TODO: Handle additional bar logic
         if self.config.validate_data_sequence {
             if let Some(last_bar) = self.cache.as_ref().borrow().bar(&bar.bar_type) {
@@ -566,9 +1153,7 @@ impl DataEngine {
             log::error!("Error on cache insert: {e}");
         }
 
-        let mut msgbus = self.msgbus.borrow_mut();
-        let topic = msgbus.switchboard.get_bars_topic(bar.bar_type);
-        msgbus.publish(&topic, &bar as &dyn Any); // TODO: Optimize
+        self.msgbus.borrow().publish(&topic, &bar as &dyn Any); // TODO: Optimize
     }
 
     // -- SUBSCRIPTION HANDLERS -------------------------------------------------------------------
@@ -588,8 +1173,8 @@ impl DataEngine {
             anyhow::bail!("Cannot subscribe for synthetic instrument `OrderBookDelta` data");
         }
 
-        if !self.subscribed_order_book_deltas().contains(&instrument_id) {
-            return Ok(());
+        if self.subscribed_order_book_deltas().contains(&instrument_id) {
+            return Ok(()); // Already subscribed
         }
 
         let data_type = command.data_type.clone();
@@ -613,68 +1198,185 @@ impl DataEngine {
             )
         })?;
 
-        if self.subscribed_order_book_deltas().contains(&instrument_id) {
-            return Ok(());
-        }
-
         if instrument_id.is_synthetic() {
-            anyhow::bail!("Cannot subscribe for synthetic instrument `OrderBookDelta` data");
+            anyhow::bail!("Cannot subscribe for synthetic instrument `OrderBook` snapshots");
         }
 
         let data_type = command.data_type.clone();
         let book_type = data_type.book_type();
         let depth = data_type.depth();
         let interval_ms = data_type.interval_ms();
-        let managed = data_type.managed();
+        let depth10 = data_type.type_name() == stringify!(OrderBookDepth10);
 
-        {
-            if !self.book_intervals.contains_key(&interval_ms) {
-                let interval_ns = millis_to_nanos(interval_ms.get() as f64);
-                let mut msgbus = self.msgbus.borrow_mut();
-                let topic = msgbus.switchboard.get_book_snapshots_topic(instrument_id);
-
-                let snap_info = BookSnapshotInfo {
-                    instrument_id,
-                    venue: instrument_id.venue,
-                    is_composite: instrument_id.symbol.is_composite(),
-                    root: Ustr::from(instrument_id.symbol.root()),
-                    topic,
-                    interval_ms,
-                };
+        let topic = {
+            let mut msgbus = self.msgbus.borrow_mut();
+            if depth10 {
+                msgbus.switchboard.get_depth_snapshots_topic(instrument_id)
+            } else {
+                msgbus.switchboard.get_book_snapshots_topic(instrument_id)
+            }
+        };
 
-                let now_ns = self.clock.borrow().timestamp_ns().as_u64();
-                let mut start_time_ns = now_ns - (now_ns % interval_ns);
+        if !self.book_snapshotters.contains_key(&topic) {
+            let interval_ns = millis_to_nanos(interval_ms.get() as f64);
+            let snap_info = BookSnapshotInfo {
+                instrument_id,
+                venue: instrument_id.venue,
+                is_composite: instrument_id.symbol.is_composite(),
+                root: Ustr::from(instrument_id.symbol.root()),
+                topic,
+                interval_ms,
+                depth10,
+            };
+
+            let now_ns = self.clock.borrow().timestamp_ns().as_u64();
+            let mut start_time_ns = now_ns - (now_ns % interval_ns);
+
+            if start_time_ns <= now_ns + NANOSECONDS_IN_MILLISECOND {
+                start_time_ns += NANOSECONDS_IN_SECOND; // Add one second
+            }
 
-                if start_time_ns - NANOSECONDS_IN_MILLISECOND <= now_ns {
-                    start_time_ns += NANOSECONDS_IN_SECOND; // Add one second
-                }
+            let snapshotter = Rc::new(BookSnapshotter::new(
+                snap_info,
+                self.cache.clone(),
+                self.msgbus.clone(),
+            ));
+            self.book_snapshotters.insert(topic, snapshotter.clone());
+            let timer_name = snapshotter.timer_name;
+
+            let callback =
+                TimeEventCallback::Rust(Rc::new(move |event| snapshotter.snapshot(event)));
+
+            self.clock.borrow_mut().set_timer_ns(
+                &timer_name,
+                interval_ns,
+                start_time_ns.into(),
+                None,
+                Some(callback),
+            )?;
+        }
 
-                let snapshotter = Rc::new(BookSnapshotter::new(
-                    snap_info,
-                    self.cache.clone(),
-                    self.msgbus.clone(),
-                ));
-                self.book_snapshotters
-                    .insert(instrument_id, snapshotter.clone());
-                let timer_name = snapshotter.timer_name;
+        // Snapshots are taken from the managed book
+        self.setup_order_book(&instrument_id, book_type, depth, false, true)?;
 
-                let callback =
-                    TimeEventCallback::Rust(Rc::new(move |event| snapshotter.snapshot(event)));
+        Ok(())
+    }
 
-                self.clock
-                    .borrow_mut()
-                    .set_timer_ns(
-                        &timer_name,
-                        interval_ns,
-                        start_time_ns.into(),
-                        None,
-                        Some(callback),
-                    )
-                    .expect(FAILED);
+    fn handle_subscribe_synthetic(&mut self, command: &SubscriptionCommand) -> anyhow::Result<()> {
+        let instrument_id = command.data_type.instrument_id().ok_or_else(|| {
+            anyhow::anyhow!(
+                "Invalid synthetic subscription: did not contain an 'instrument_id', {}",
+                command.data_type
+            )
+        })?;
+
+        let synthetic = self
+            .cache
+            .borrow()
+            .synthetic(&instrument_id)
+            .cloned()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Cannot subscribe for synthetic instrument data: no synthetic instrument {instrument_id} in cache"
+                )
+            })?;
+
+        let feeds = if command.data_type.type_name() == stringify!(QuoteTick) {
+            &mut self.synthetic_quote_feeds
+        } else {
+            &mut self.synthetic_trade_feeds
+        };
+
+        for component in &synthetic.components {
+            let synthetics = feeds.entry(*component).or_default();
+            if !synthetics.contains(&synthetic) {
+                synthetics.push(synthetic.clone());
             }
         }
 
-        self.setup_order_book(&instrument_id, book_type, depth, false, managed)?;
+        Ok(())
+    }
+
+    fn handle_subscribe_greeks(&mut self, command: &SubscriptionCommand) -> anyhow::Result<()> {
+        let info = self.greeks_info(command)?;
+
+        if self.subscribed_greeks().contains(&info.instrument_id) {
+            return Ok(()); // Already subscribed
+        }
+
+        for instrument_id in [info.instrument_id, info.underlying_id] {
+            self.greeks_feeds
+                .entry(instrument_id)
+                .or_default()
+                .push(info.clone());
+        }
+
+        self.execute_greeks_data(command, &info);
+
+        Ok(())
+    }
+
+    fn handle_subscribe_reference_price(
+        &mut self,
+        command: &SubscriptionCommand,
+    ) -> anyhow::Result<()> {
+        let info = self.reference_price_info(command)?;
+        let price_type = info.price_type();
+
+        if self
+            .subscribed_reference_prices()
+            .contains(&(info.instrument_id, price_type))
+        {
+            return Ok(()); // Already subscribed
+        }
+
+        self.reference_prices
+            .entry(info.instrument_id)
+            .or_default()
+            .push(info.clone());
+
+        self.execute_reference_price(command, &info);
+
+        Ok(())
+    }
+
+    fn handle_subscribe_conflated(&mut self, command: &SubscriptionCommand) -> anyhow::Result<()> {
+        let (stream_topic, info) = self.conflation_info(command)?;
+
+        if is_synthetic(&command.data_type) {
+            self.handle_subscribe_synthetic(command)?;
+        }
+
+        if self.conflators.contains_key(&info.topic) {
+            return Ok(());
+        }
+
+        let interval_ns = millis_to_nanos(info.interval_ms.get() as f64);
+        let conflated_topic = info.topic;
+        let conflator = Rc::new(Conflator::new(info, self.msgbus.clone()));
+        let timer_name = conflator.timer_name;
+
+        let callback = {
+            let conflator = conflator.clone();
+            TimeEventCallback::Rust(Rc::new(move |event| conflator.flush(event)))
+        };
+
+        let start_time_ns = self.clock.borrow().timestamp_ns();
+        self.clock.borrow_mut().set_timer_ns(
+            &timer_name,
+            interval_ns,
+            start_time_ns,
+            None,
+            Some(callback),
+        )?;
+
+        self.msgbus.borrow_mut().subscribe(
+            stream_topic,
+            ShareableMessageHandler(conflator.clone()),
+            Some(self.msgbus_priority),
+        );
+        self.conflators
+            .insert(conflated_topic, (stream_topic, conflator));
 
         Ok(())
     }
@@ -686,6 +1388,7 @@ impl DataEngine {
             AggregationSource::Internal => {
                 if !self.bar_aggregators.contains_key(&bar_type.standard()) {
                     self.start_bar_aggregator(bar_type)?;
+                    self.execute_bar_aggregator_data(command, bar_type);
                 }
             }
             AggregationSource::External => {
@@ -722,11 +1425,12 @@ impl DataEngine {
                 msgbus.switchboard.get_deltas_topic(instrument_id),
                 msgbus.switchboard.get_depth_topic(instrument_id),
                 msgbus.switchboard.get_book_snapshots_topic(instrument_id),
+                msgbus.switchboard.get_depth_snapshots_topic(instrument_id),
             ]
         };
 
         self.maintain_book_updater(&instrument_id, &topics);
-        self.maintain_book_snapshotter(&instrument_id);
+        self.maintain_book_snapshotters(&topics);
 
         Ok(())
     }
@@ -742,7 +1446,10 @@ impl DataEngine {
             )
         })?;
 
-        if !self.subscribed_order_book_deltas().contains(&instrument_id) {
+        if !self
+            .subscribed_order_book_snapshots()
+            .contains(&instrument_id)
+        {
             log::warn!("Cannot unsubscribe from `OrderBook` snapshots: not subscribed");
             return Ok(());
         }
@@ -753,20 +1460,126 @@ impl DataEngine {
                 msgbus.switchboard.get_deltas_topic(instrument_id),
                 msgbus.switchboard.get_depth_topic(instrument_id),
                 msgbus.switchboard.get_book_snapshots_topic(instrument_id),
+                msgbus.switchboard.get_depth_snapshots_topic(instrument_id),
             ]
         };
 
         self.maintain_book_updater(&instrument_id, &topics);
-        self.maintain_book_snapshotter(&instrument_id);
+        self.maintain_book_snapshotters(&topics);
 
         Ok(())
     }
 
-    const fn handle_unsubscribe_bars(
+    fn handle_unsubscribe_synthetic(
         &mut self,
         command: &SubscriptionCommand,
     ) -> anyhow::Result<()> {
-        // TODO: Handle aggregators
+        let instrument_id = command.data_type.instrument_id().ok_or_else(|| {
+            anyhow::anyhow!(
+                "Invalid synthetic subscription: did not contain an 'instrument_id', {}",
+                command.data_type
+            )
+        })?;
+
+        let feeds = if command.data_type.type_name() == stringify!(QuoteTick) {
+            &mut self.synthetic_quote_feeds
+        } else {
+            &mut self.synthetic_trade_feeds
+        };
+
+        feeds.retain(|_, synthetics| {
+            synthetics.retain(|synthetic| synthetic.id != instrument_id);
+            !synthetics.is_empty()
+        });
+
+        Ok(())
+    }
+
+    fn handle_unsubscribe_greeks(&mut self, command: &SubscriptionCommand) -> anyhow::Result<()> {
+        let instrument_id = command.data_type.instrument_id().ok_or_else(|| {
+            anyhow::anyhow!(
+                "Invalid greeks subscription: did not contain an 'instrument_id', {}",
+                command.data_type
+            )
+        })?;
+
+        let Some(info) = self
+            .greeks_feeds
+            .get(&instrument_id)
+            .and_then(|feeds| {
+                feeds
+                    .iter()
+                    .find(|info| info.instrument_id == instrument_id)
+            })
+            .cloned()
+        else {
+            return Ok(()); // Not subscribed
+        };
+
+        self.greeks_feeds.retain(|_, feeds| {
+            feeds.retain(|feed| feed.instrument_id != instrument_id);
+            !feeds.is_empty()
+        });
+
+        self.execute_greeks_data(command, &info);
+
+        Ok(())
+    }
+
+    fn handle_unsubscribe_reference_price(
+        &mut self,
+        command: &SubscriptionCommand,
+    ) -> anyhow::Result<()> {
+        let info = self.reference_price_info(command)?;
+        let price_type = info.price_type();
+
+        let Some(infos) = self.reference_prices.get_mut(&info.instrument_id) else {
+            return Ok(()); // Not subscribed
+        };
+        let Some(index) = infos
+            .iter()
+            .position(|feed| feed.price_type() == price_type)
+        else {
+            return Ok(()); // Not subscribed
+        };
+
+        infos.remove(index);
+        if infos.is_empty() {
+            self.reference_prices.remove(&info.instrument_id);
+        }
+
+        self.execute_reference_price(command, &info);
+
+        Ok(())
+    }
+
+    fn handle_unsubscribe_conflated(
+        &mut self,
+        command: &SubscriptionCommand,
+    ) -> anyhow::Result<()> {
+        let (stream_topic, info) = self.conflation_info(command)?;
+
+        self.maintain_conflators(&[info.topic]);
+
+        if is_synthetic(&command.data_type)
+            && self.msgbus.borrow().subscriptions_count(stream_topic) == 0
+        {
+            self.handle_unsubscribe_synthetic(command)?;
+        }
+
+        Ok(())
+    }
+
+    fn handle_unsubscribe_bars(&mut self, command: &SubscriptionCommand) -> anyhow::Result<()> {
+        let bar_type = command.data_type.bar_type();
+
+        if bar_type.aggregation_source() == AggregationSource::Internal
+            && self.bar_aggregators.contains_key(&bar_type.standard())
+        {
+            self.stop_bar_aggregator(bar_type)?;
+            self.execute_bar_aggregator_data(command, bar_type);
+        }
+
         Ok(())
     }
 
@@ -796,25 +1609,220 @@ impl DataEngine {
         }
     }
 
-    fn maintain_book_snapshotter(&mut self, instrument_id: &InstrumentId) {
-        if let Some(snapshotter) = self.book_snapshotters.get(instrument_id) {
-            let mut msgbus = self.msgbus.borrow_mut();
-
-            let topic = msgbus.switchboard.get_book_snapshots_topic(*instrument_id);
-
+    fn maintain_book_snapshotters(&mut self, topics: &[Ustr]) {
+        for topic in topics {
             // Check remaining snapshot subscriptions, if none then remove snapshotter
-            if msgbus.subscriptions_count(topic) == 0 {
+            if self.msgbus.borrow().subscriptions_count(*topic) > 0 {
+                continue;
+            }
+
+            if let Some(snapshotter) = self.book_snapshotters.remove(topic) {
                 let timer_name = snapshotter.timer_name;
-                self.book_snapshotters.remove(instrument_id);
                 let mut clock = self.clock.borrow_mut();
                 if clock.timer_names().contains(&timer_name.as_str()) {
                     clock.cancel_timer(&timer_name);
                 }
-                log::debug!("Removed BookSnapshotter for instrument ID {instrument_id}");
+                log::debug!("Removed BookSnapshotter for {topic}");
+            }
+        }
+    }
+
+    fn maintain_conflators(&mut self, topics: &[Ustr]) {
+        for topic in topics {
+            // Check remaining conflated subscriptions, if none then remove conflator
+            if self.msgbus.borrow().subscriptions_count(*topic) > 0 {
+                continue;
+            }
+
+            if let Some((stream_topic, conflator)) = self.conflators.remove(topic) {
+                let timer_name = conflator.timer_name;
+                {
+                    let mut clock = self.clock.borrow_mut();
+                    if clock.timer_names().contains(&timer_name.as_str()) {
+                        clock.cancel_timer(&timer_name);
+                    }
+                }
+                self.msgbus
+                    .borrow_mut()
+                    .unsubscribe(stream_topic, ShareableMessageHandler(conflator));
+                log::debug!("Removed Conflator for {topic}");
             }
         }
     }
 
+    /// Returns the topic of the quote or trade stream of the conflated subscription, along with
+    /// the information for conflating it.
+    fn conflation_info(
+        &self,
+        command: &SubscriptionCommand,
+    ) -> anyhow::Result<(Ustr, ConflationInfo)> {
+        let data_type = &command.data_type;
+        let instrument_id = data_type.instrument_id().ok_or_else(|| {
+            anyhow::anyhow!(
+                "Invalid conflated subscription: did not contain an 'instrument_id', {data_type}"
+            )
+        })?;
+        let mode = match data_type.metadata().and_then(|m| m.get("conflation")) {
+            Some(mode) => mode.parse::<ConflationMode>()?,
+            None => ConflationMode::default(),
+        };
+        let interval_ms = data_type.interval_ms();
+        let quotes = data_type.type_name() == stringify!(QuoteTick);
+
+        let mut msgbus = self.msgbus.borrow_mut();
+        let stream_topic = if quotes {
+            msgbus.switchboard.get_quotes_topic(instrument_id)
+        } else {
+            msgbus.switchboard.get_trades_topic(instrument_id)
+        };
+        let topic =
+            msgbus
+                .switchboard
+                .get_conflated_topic(stream_topic, mode.as_str(), interval_ms.get());
+
+        let info = ConflationInfo {
+            instrument_id,
+            mode,
+            interval_ms,
+            topic,
+            quotes,
+        };
+        Ok((stream_topic, info))
+    }
+
+    /// Returns the information for the greeks subscription, where the underlying, model,
+    /// interest rate and dividend yield may be given in the metadata.
+    fn greeks_info(&self, command: &SubscriptionCommand) -> anyhow::Result<GreeksInfo> {
+        let data_type = &command.data_type;
+        let instrument_id = data_type.instrument_id().ok_or_else(|| {
+            anyhow::anyhow!(
+                "Invalid greeks subscription: did not contain an 'instrument_id', {data_type}"
+            )
+        })?;
+
+        let underlying = match self.cache.borrow().instrument(&instrument_id) {
+            Some(InstrumentAny::OptionContract(option)) => option.underlying,
+            Some(_) => {
+                anyhow::bail!("Cannot subscribe for greeks: {instrument_id} is not an option")
+            }
+            None => {
+                anyhow::bail!("Cannot subscribe for greeks: no option {instrument_id} in cache")
+            }
+        };
+
+        let metadata = data_type.metadata().expect("metadata was `None`");
+        let underlying_id = match metadata.get("underlying_id") {
+            Some(underlying_id) => InstrumentId::from_str(underlying_id)?,
+            None => InstrumentId::new(Symbol::from(underlying), instrument_id.venue),
+        };
+        let model = match metadata.get("model") {
+            Some(model) => model.parse::<GreeksModel>()?,
+            None => GreeksModel::default(),
+        };
+        let parse_rate = |key: &str| -> anyhow::Result<f64> {
+            metadata.get(key).map_or(Ok(0.0), |rate| {
+                rate.parse::<f64>()
+                    .map_err(|e| anyhow::anyhow!("Invalid '{key}' {rate}, {e}"))
+            })
+        };
+        let interest_rate = parse_rate("interest_rate")?;
+        let dividend_yield = parse_rate("dividend_yield")?;
+        let topic = self
+            .msgbus
+            .borrow_mut()
+            .switchboard
+            .get_greeks_topic(instrument_id);
+
+        Ok(GreeksInfo {
+            instrument_id,
+            underlying_id,
+            model,
+            interest_rate,
+            dividend_yield,
+            topic,
+        })
+    }
+
+    /// Returns the information for the reference price subscription, where the 'price_type'
+    /// is given in the metadata along with either the rolling 'window_secs' of a VWAP, or the
+    /// daily 'session_start' of a TWAP as an 'HH:MM' UTC time.
+    fn reference_price_info(
+        &self,
+        command: &SubscriptionCommand,
+    ) -> anyhow::Result<ReferencePriceInfo> {
+        let data_type = &command.data_type;
+        let instrument_id = data_type.instrument_id().ok_or_else(|| {
+            anyhow::anyhow!(
+                "Invalid reference price subscription: did not contain an 'instrument_id', {data_type}"
+            )
+        })?;
+
+        let metadata = data_type.metadata().expect("metadata was `None`");
+        let price_type = metadata
+            .get("price_type")
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid reference price subscription: did not contain a 'price_type', {data_type}"
+                )
+            })?
+            .parse::<ReferencePriceType>()?;
+
+        let calculator = match price_type {
+            ReferencePriceType::Vwap => {
+                let window_secs = match metadata.get("window_secs") {
+                    Some(window_secs) => window_secs
+                        .parse::<u64>()
+                        .map_err(|e| anyhow::anyhow!("Invalid 'window_secs' {window_secs}, {e}"))?,
+                    None => DEFAULT_VWAP_WINDOW_SECS,
+                };
+                ReferencePriceCalculator::Vwap(RollingVwap::new(
+                    window_secs * NANOSECONDS_IN_SECOND,
+                ))
+            }
+            ReferencePriceType::Twap => {
+                let session_offset_ns = match metadata.get("session_start") {
+                    Some(session_start) => {
+                        let time =
+                            NaiveTime::parse_from_str(session_start, "%H:%M").map_err(|e| {
+                                anyhow::anyhow!("Invalid 'session_start' {session_start}, {e}")
+                            })?;
+                        u64::from(time.num_seconds_from_midnight()) * NANOSECONDS_IN_SECOND
+                    }
+                    None => 0,
+                };
+                ReferencePriceCalculator::Twap(SessionTwap::new(session_offset_ns))
+            }
+        };
+
+        let topic = self
+            .msgbus
+            .borrow_mut()
+            .switchboard
+            .get_reference_price_topic(instrument_id, price_type);
+
+        Ok(ReferencePriceInfo {
+            instrument_id,
+            calculator,
+            topic,
+            last: None,
+        })
+    }
+
+    /// Returns whether the quote or trade stream of the conflated subscription still has
+    /// subscribers, including conflators over other intervals.
+    fn is_consumed(&self, data_type: &DataType) -> bool {
+        let Some(instrument_id) = data_type.instrument_id() else {
+            return false;
+        };
+        let mut msgbus = self.msgbus.borrow_mut();
+        let stream_topic = if data_type.type_name() == stringify!(QuoteTick) {
+            msgbus.switchboard.get_quotes_topic(instrument_id)
+        } else {
+            msgbus.switchboard.get_trades_topic(instrument_id)
+        };
+        msgbus.subscriptions_count(stream_topic) > 0
+    }
+
     // -- RESPONSE HANDLERS -----------------------------------------------------------------------
 
     fn handle_instruments(&self, instruments: Arc<Vec<InstrumentAny>>) {
@@ -845,6 +1853,73 @@ impl DataEngine {
         }
     }
 
+    /// Aggregates the historical data of the response into bars for each of the comma separated
+    /// bar types of its `bar_types` parameter, adding the bars to the cache.
+    ///
+    /// Aggregators run in batch mode from the time of the first data point, so the bars are
+    /// not published. A running aggregator for the bar type is caught up by the data and
+    /// continues from it, otherwise a temporary aggregator is created.
+    fn handle_aggregated_bars(
+        &mut self,
+        resp: &DataResponse,
+        ts_start: Option<UnixNanos>,
+        update: impl Fn(&mut dyn BarAggregator),
+    ) {
+        let Some(bar_types) = resp
+            .params
+            .as_ref()
+            .and_then(|params| params.get("bar_types"))
+        else {
+            return;
+        };
+        let Some(ts_start) = ts_start else {
+            return; // No data to aggregate
+        };
+
+        for value in bar_types.split(',') {
+            let bar_type = match BarType::from_str(value.trim()) {
+                Ok(bar_type) => bar_type,
+                Err(e) => {
+                    log::error!("Cannot aggregate bars: invalid bar type '{value}', {e}");
+                    continue;
+                }
+            };
+
+            let aggregator = match self.bar_aggregators.get(&bar_type.standard()) {
+                Some(aggregator) => aggregator.clone(),
+                None => match self.create_bar_aggregator(bar_type, false) {
+                    Ok(aggregator) => aggregator,
+                    Err(e) => {
+                        log::error!("{e}");
+                        continue;
+                    }
+                },
+            };
+
+            let bars = Rc::new(RefCell::new(Vec::new()));
+            {
+                let mut aggregator = aggregator.borrow_mut();
+                let batch_bars = bars.clone();
+                aggregator.start_batch_update(
+                    Box::new(move |bar| batch_bars.borrow_mut().push(bar)),
+                    ts_start,
+                );
+                update(&mut *aggregator);
+                aggregator.stop_batch_update();
+            }
+
+            let bars = bars.take();
+            log::debug!("Aggregated {} historical bars for {bar_type}", bars.len());
+            if bars.is_empty() {
+                continue;
+            }
+
+            if let Err(e) = self.cache.borrow_mut().add_bars(&bars) {
+                log::error!("Error on cache insert: {e}");
+            }
+        }
+    }
+
     // -- INTERNAL --------------------------------------------------------------------------------
 
     #[allow(clippy::too_many_arguments)]
@@ -884,22 +1959,41 @@ impl DataEngine {
         Ok(())
     }
 
+    /// Creates an aggregator for the `bar_type` which publishes the bars it builds, starting the
+    /// timer of a time bar aggregator if `start_timer`.
     fn create_bar_aggregator(
         &mut self,
-        instrument: &InstrumentAny,
         bar_type: BarType,
-    ) -> Box<dyn BarAggregator> {
+        start_timer: bool,
+    ) -> anyhow::Result<Rc<RefCell<dyn BarAggregator>>> {
+        let instrument = self
+            .cache
+            .borrow()
+            .instrument(&bar_type.instrument_id())
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Cannot start bar aggregation: no instrument found for {}",
+                    bar_type.instrument_id(),
+                )
+            })?
+            .clone();
+
+        let bar_type = bar_type.standard();
         let cache = self.cache.clone();
         let msgbus = self.msgbus.clone();
+        // The topic is resolved up front, as bars are built while the bus is publishing
+        let topic = self
+            .msgbus
+            .borrow_mut()
+            .switchboard
+            .get_bars_topic(bar_type);
 
         let handler = move |bar: Bar| {
             if let Err(e) = cache.as_ref().borrow_mut().add_bar(bar) {
                 log::error!("Error on cache insert: {e}");
             }
 
-            let mut msgbus = msgbus.borrow_mut();
-            let topic = msgbus.switchboard.get_bars_topic(bar.bar_type);
-            msgbus.publish(&topic, &bar as &dyn Any);
+            msgbus.borrow().publish(&topic, &bar as &dyn Any);
         };
 
         let clock = self.clock.clone();
@@ -908,8 +2002,8 @@ impl DataEngine {
         let price_precision = instrument.price_precision();
         let size_precision = instrument.size_precision();
 
-        if bar_type.spec().is_time_aggregated() {
-            Box::new(TimeBarAggregator::new(
+        let aggregator: Rc<RefCell<dyn BarAggregator>> = if bar_type.spec().is_time_aggregated() {
+            let aggregator = Rc::new(RefCell::new(TimeBarAggregator::new(
                 bar_type,
                 price_precision,
                 size_precision,
@@ -922,65 +2016,164 @@ impl DataEngine {
                 None,  // TODO: Implement
                 20,    // TODO: TBD, composite bar build delay
                 false, // TODO: skip_first_non_full_bar, make it config dependent
-            ))
+            )));
+            if start_timer {
+                let callback = NewBarCallback::new(aggregator.clone());
+                aggregator.borrow_mut().start(callback)?;
+            }
+            aggregator
         } else {
             match bar_type.spec().aggregation {
-                BarAggregation::Tick => Box::new(TickBarAggregator::new(
+                BarAggregation::Tick => Rc::new(RefCell::new(TickBarAggregator::new(
                     bar_type,
                     price_precision,
                     size_precision,
                     handler,
                     false,
-                )) as Box<dyn BarAggregator>,
-                BarAggregation::Volume => Box::new(VolumeBarAggregator::new(
+                ))),
+                BarAggregation::Volume => Rc::new(RefCell::new(VolumeBarAggregator::new(
                     bar_type,
                     price_precision,
                     size_precision,
                     handler,
                     false,
-                )) as Box<dyn BarAggregator>,
-                BarAggregation::Value => Box::new(ValueBarAggregator::new(
+                ))),
+                BarAggregation::Value => Rc::new(RefCell::new(ValueBarAggregator::new(
                     bar_type,
                     price_precision,
                     size_precision,
                     handler,
                     false,
-                )) as Box<dyn BarAggregator>,
-                _ => panic!(
+                ))),
+                _ => anyhow::bail!(
                     "Cannot create aggregator: {} aggregation not currently supported",
                     bar_type.spec().aggregation
                 ),
             }
-        }
+        };
+
+        Ok(aggregator)
     }
 
     fn start_bar_aggregator(&mut self, bar_type: BarType) -> anyhow::Result<()> {
-        let instrument = {
-            let cache = self.cache.borrow();
-            cache
-                .instrument(&bar_type.instrument_id())
-                .ok_or_else(|| {
-                    anyhow::anyhow!(
-                        "Cannot start bar aggregation: no instrument found for {}",
-                        bar_type.instrument_id(),
-                    )
-                })?
-                .clone()
+        let standard_bar_type = bar_type.standard();
+        let aggregator = if let Some(aggregator) = self.bar_aggregators.get(&standard_bar_type) {
+            aggregator.clone()
+        } else {
+            let aggregator = self.create_bar_aggregator(bar_type, true)?;
+            self.bar_aggregators
+                .insert(standard_bar_type, aggregator.clone());
+            aggregator
         };
 
-        let aggregator = if let Some(aggregator) = self.bar_aggregators.get_mut(&bar_type) {
-            aggregator
+        // Subscribe the aggregator to the data it aggregates
+        let handler = ShareableMessageHandler(Rc::new(BarAggregatorHandler::new(
+            standard_bar_type,
+            aggregator.clone(),
+        )));
+        let topic = {
+            let mut msgbus = self.msgbus.borrow_mut();
+            let topic = if bar_type.is_composite() {
+                msgbus.switchboard.get_bars_topic(bar_type.composite())
+            } else if bar_type.spec().price_type == PriceType::Last {
+                msgbus
+                    .switchboard
+                    .get_trades_topic(bar_type.instrument_id())
+            } else {
+                msgbus
+                    .switchboard
+                    .get_quotes_topic(bar_type.instrument_id())
+            };
+            msgbus.subscribe(topic, handler.clone(), Some(self.msgbus_priority));
+            topic
+        };
+        self.bar_aggregator_handlers
+            .insert(standard_bar_type, (topic, handler));
+
+        aggregator.borrow_mut().set_is_running(true);
+
+        Ok(())
+    }
+
+    /// Subscribes or unsubscribes the client, per the `command` action, to the quotes or trades
+    /// aggregated into the `bar_type`. Composite bar types aggregate from bars already subscribed.
+    fn execute_bar_aggregator_data(&mut self, command: &SubscriptionCommand, bar_type: BarType) {
+        if bar_type.is_composite() {
+            return;
+        }
+
+        let type_name = if bar_type.spec().price_type == PriceType::Last {
+            stringify!(TradeTick)
         } else {
-            let aggregator = self.create_bar_aggregator(&instrument, bar_type);
-            self.bar_aggregators.insert(bar_type, aggregator);
-            self.bar_aggregators.get_mut(&bar_type).unwrap()
+            stringify!(QuoteTick)
         };
+        let metadata = IndexMap::from([(
+            "instrument_id".to_string(),
+            bar_type.instrument_id().to_string(),
+        )]);
+        let cmd = SubscriptionCommand::new(
+            command.client_id,
+            command.venue,
+            DataType::new(type_name, Some(metadata)),
+            command.action,
+            UUID4::new(),
+            command.ts_init,
+            None,
+        );
+
+        if let Some(client) = self.get_client_mut(&command.client_id, &command.venue) {
+            client.execute(cmd);
+        }
+    }
 
-        // TODO: Subscribe to data
+    /// Forwards the greeks subscription `command` to the client, as quote subscriptions for
+    /// the option and underlying of the `info`.
+    fn execute_greeks_data(&mut self, command: &SubscriptionCommand, info: &GreeksInfo) {
+        for instrument_id in [info.instrument_id, info.underlying_id] {
+            let metadata =
+                IndexMap::from([("instrument_id".to_string(), instrument_id.to_string())]);
+            let cmd = SubscriptionCommand::new(
+                command.client_id,
+                command.venue,
+                DataType::new(stringify!(QuoteTick), Some(metadata)),
+                command.action,
+                UUID4::new(),
+                command.ts_init,
+                None,
+            );
 
-        aggregator.set_is_running(true);
+            if let Some(client) = self.get_client_mut(&command.client_id, &command.venue) {
+                client.execute(cmd);
+            }
+        }
+    }
 
-        Ok(())
+    /// Forwards the reference price subscription `command` to the client, as a trade
+    /// subscription for a VWAP or a quote subscription for a TWAP.
+    fn execute_reference_price(
+        &mut self,
+        command: &SubscriptionCommand,
+        info: &ReferencePriceInfo,
+    ) {
+        let type_name = match info.price_type() {
+            ReferencePriceType::Vwap => stringify!(TradeTick),
+            ReferencePriceType::Twap => stringify!(QuoteTick),
+        };
+        let metadata =
+            IndexMap::from([("instrument_id".to_string(), info.instrument_id.to_string())]);
+        let cmd = SubscriptionCommand::new(
+            command.client_id,
+            command.venue,
+            DataType::new(type_name, Some(metadata)),
+            command.action,
+            UUID4::new(),
+            command.ts_init,
+            None,
+        );
+
+        if let Some(client) = self.get_client_mut(&command.client_id, &command.venue) {
+            client.execute(cmd);
+        }
     }
 
     fn stop_bar_aggregator(&mut self, bar_type: BarType) -> anyhow::Result<()> {
@@ -991,29 +2184,49 @@ impl DataEngine {
                 anyhow::anyhow!("Cannot stop bar aggregator: no aggregator to stop for {bar_type}")
             })?;
 
-        // TODO: If its a `TimeBarAggregator` then call `.stop()`
-        // if let Some(aggregator) = (aggregator as &dyn BarAggregator)
-        //     .as_any()
-        //     .downcast_ref::<TimeBarAggregator<_, _>>()
-        // {
-        //     aggregator.stop();
-        // };
+        {
+            let mut aggregator = aggregator.borrow_mut();
+            aggregator.stop();
+            aggregator.set_is_running(false);
+        }
 
-        if bar_type.is_composite() {
-            let composite_bar_type = bar_type.composite();
-            // TODO: Unsubscribe the `aggregator.handle_bar`
-        } else if bar_type.spec().price_type == PriceType::Last {
-            // TODO: Unsubscribe `aggregator.handle_trade_tick`
-            todo!()
-        } else {
-            // TODO: Unsubscribe `aggregator.handle_quote_tick`
-            todo!()
+        if let Some((topic, handler)) = self.bar_aggregator_handlers.remove(&bar_type.standard()) {
+            self.msgbus.borrow_mut().unsubscribe(topic, handler);
         }
 
         Ok(())
     }
 }
 
+/// Returns whether the subscription is for data of a synthetic instrument.
+fn is_synthetic(data_type: &DataType) -> bool {
+    data_type.metadata().is_some()
+        && data_type
+            .instrument_id()
+            .is_some_and(|instrument_id| instrument_id.is_synthetic())
+}
+
+/// Returns the IDs of the synthetic instruments derived from the component feeds.
+fn collect_synthetics(
+    feeds: &HashMap<InstrumentId, Vec<SyntheticInstrument>>,
+) -> Vec<InstrumentId> {
+    feeds
+        .values()
+        .flatten()
+        .map(|synthetic| synthetic.id)
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Returns whether the subscription is throttled to an `interval_ms`, as book snapshots or a
+/// conflated quote or trade stream.
+fn is_throttled(data_type: &DataType) -> bool {
+    data_type
+        .metadata()
+        .is_some_and(|metadata| metadata.contains_key("interval_ms"))
+}
+
 pub struct SubscriptionCommandHandler {
     pub id: Ustr,
     pub engine_ref: Rc<RefCell<DataEngine>>,
@@ -0,0 +1,30 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Recording of the data received by the data engine.
+
+use nautilus_model::data::Data;
+
+/// Records the data received by the data engine, such as to build a historical dataset.
+///
+/// Recording is a pass-through, so implementations should defer any blocking I/O to the
+/// background rather than hold up the processing of data.
+pub trait DataRecorder {
+    /// Records the received `data`.
+    fn record(&mut self, data: &Data);
+
+    /// Flushes any buffered data to the underlying storage.
+    fn flush(&mut self);
+}
@@ -0,0 +1,293 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Calculation of VWAP and TWAP benchmark reference prices.
+
+use std::collections::VecDeque;
+
+use nautilus_core::{datetime::NANOSECONDS_IN_SECOND, UnixNanos};
+use nautilus_model::{
+    data::{QuoteTick, ReferencePriceUpdate, TradeTick},
+    enums::ReferencePriceType,
+    identifiers::InstrumentId,
+    types::Price,
+};
+use ustr::Ustr;
+
+const NANOSECONDS_IN_DAY: u64 = 24 * 60 * 60 * NANOSECONDS_IN_SECOND;
+
+/// The default rolling window of a VWAP in seconds.
+pub const DEFAULT_VWAP_WINDOW_SECS: u64 = 300;
+
+/// Provides a volume-weighted average price of trades over a rolling time window.
+#[derive(Clone, Debug)]
+pub struct RollingVwap {
+    window_ns: u64,
+    trades: VecDeque<(UnixNanos, f64, f64)>,
+    notional: f64,
+    volume: f64,
+}
+
+impl RollingVwap {
+    /// Creates a new [`RollingVwap`] instance over a window of `window_ns` nanoseconds.
+    #[must_use]
+    pub fn new(window_ns: u64) -> Self {
+        Self {
+            window_ns,
+            trades: VecDeque::new(),
+            notional: 0.0,
+            volume: 0.0,
+        }
+    }
+
+    /// Updates the VWAP with a trade of `size` at `price`, expiring trades which occurred
+    /// more than the window before `ts_event`.
+    pub fn update(&mut self, price: f64, size: f64, ts_event: UnixNanos) {
+        self.trades.push_back((ts_event, price, size));
+        self.notional += price * size;
+        self.volume += size;
+
+        let window_start = self.window_start(ts_event);
+        while let Some(&(ts, price, size)) = self.trades.front() {
+            if ts > window_start {
+                break;
+            }
+            self.trades.pop_front();
+            self.notional -= price * size;
+            self.volume -= size;
+        }
+    }
+
+    /// Returns the start of the window ending at `ts_event`.
+    #[must_use]
+    pub fn window_start(&self, ts_event: UnixNanos) -> UnixNanos {
+        UnixNanos::from(ts_event.saturating_sub(self.window_ns))
+    }
+
+    /// Returns the VWAP, or `None` if there is no traded volume in the window.
+    #[must_use]
+    pub fn value(&self) -> Option<f64> {
+        if self.volume > 0.0 {
+            Some(self.notional / self.volume)
+        } else {
+            None
+        }
+    }
+}
+
+/// Provides a time-weighted average price since the start of the current daily session.
+///
+/// Each price is weighted by the time it prevailed for, until the next update.
+#[derive(Clone, Debug)]
+pub struct SessionTwap {
+    session_offset_ns: u64,
+    session_start: Option<UnixNanos>,
+    last: Option<(UnixNanos, f64)>,
+    weighted_sum: f64,
+    duration_ns: u64,
+}
+
+impl SessionTwap {
+    /// Creates a new [`SessionTwap`] instance with sessions starting `session_offset_ns`
+    /// nanoseconds after midnight UTC.
+    #[must_use]
+    pub fn new(session_offset_ns: u64) -> Self {
+        Self {
+            session_offset_ns: session_offset_ns % NANOSECONDS_IN_DAY,
+            session_start: None,
+            last: None,
+            weighted_sum: 0.0,
+            duration_ns: 0,
+        }
+    }
+
+    /// Returns the start of the session containing `ts_event`.
+    #[must_use]
+    pub fn session_start(&self, ts_event: UnixNanos) -> UnixNanos {
+        let ts = ts_event.as_u64() + NANOSECONDS_IN_DAY - self.session_offset_ns;
+        let start = ts - ts % NANOSECONDS_IN_DAY + self.session_offset_ns;
+        UnixNanos::from(start.saturating_sub(NANOSECONDS_IN_DAY))
+    }
+
+    /// Updates the TWAP with a `price` prevailing from `ts_event`, resetting the average
+    /// where a new session has started.
+    pub fn update(&mut self, price: f64, ts_event: UnixNanos) {
+        let session_start = self.session_start(ts_event);
+
+        if self.session_start != Some(session_start) {
+            self.session_start = Some(session_start);
+            self.last = None;
+            self.weighted_sum = 0.0;
+            self.duration_ns = 0;
+        }
+
+        let mut ts_last = ts_event;
+        if let Some((last_ts, last_price)) = self.last {
+            let elapsed_ns = ts_event.saturating_sub(*last_ts);
+            self.weighted_sum += last_price * elapsed_ns as f64;
+            self.duration_ns += elapsed_ns;
+            ts_last = ts_last.max(last_ts);
+        }
+
+        self.last = Some((ts_last, price));
+    }
+
+    /// Returns the TWAP, or the only price where no time has elapsed in the session.
+    #[must_use]
+    pub fn value(&self) -> Option<f64> {
+        if self.duration_ns > 0 {
+            Some(self.weighted_sum / self.duration_ns as f64)
+        } else {
+            self.last.map(|(_, price)| price)
+        }
+    }
+}
+
+/// The calculator for a reference price.
+#[derive(Clone, Debug)]
+pub enum ReferencePriceCalculator {
+    Vwap(RollingVwap),
+    Twap(SessionTwap),
+}
+
+/// Contains information for calculating a reference price of a specific instrument.
+#[derive(Clone, Debug)]
+pub struct ReferencePriceInfo {
+    pub instrument_id: InstrumentId,
+    pub calculator: ReferencePriceCalculator,
+    /// The topic the reference prices are published on.
+    pub topic: Ustr,
+    /// The latest reference price calculated.
+    pub last: Option<ReferencePriceUpdate>,
+}
+
+impl ReferencePriceInfo {
+    #[must_use]
+    pub const fn price_type(&self) -> ReferencePriceType {
+        match self.calculator {
+            ReferencePriceCalculator::Vwap(_) => ReferencePriceType::Vwap,
+            ReferencePriceCalculator::Twap(_) => ReferencePriceType::Twap,
+        }
+    }
+
+    /// Updates a VWAP with the `trade`, returning the reference price to publish.
+    pub fn update_trade(&mut self, trade: &TradeTick) -> Option<ReferencePriceUpdate> {
+        let ReferencePriceCalculator::Vwap(vwap) = &mut self.calculator else {
+            return None;
+        };
+        vwap.update(trade.price.as_f64(), trade.size.as_f64(), trade.ts_event);
+
+        let price = Price::new(vwap.value()?, trade.price.precision);
+        let period_start_ns = vwap.window_start(trade.ts_event);
+        Some(self.update_last(price, period_start_ns, trade.ts_event, trade.ts_init))
+    }
+
+    /// Updates a TWAP with the mid price of the `quote`, returning the reference price to publish.
+    pub fn update_quote(&mut self, quote: &QuoteTick) -> Option<ReferencePriceUpdate> {
+        let ReferencePriceCalculator::Twap(twap) = &mut self.calculator else {
+            return None;
+        };
+        let mid = (quote.bid_price.as_f64() + quote.ask_price.as_f64()) / 2.0;
+        twap.update(mid, quote.ts_event);
+
+        let precision = quote.bid_price.precision + 1; // Mid prices have an extra decimal
+        let price = Price::new(twap.value()?, precision);
+        let period_start_ns = twap.session_start(quote.ts_event);
+        Some(self.update_last(price, period_start_ns, quote.ts_event, quote.ts_init))
+    }
+
+    fn update_last(
+        &mut self,
+        price: Price,
+        period_start_ns: UnixNanos,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> ReferencePriceUpdate {
+        let update = ReferencePriceUpdate::new(
+            self.instrument_id,
+            self.price_type(),
+            price,
+            period_start_ns,
+            ts_event,
+            ts_init,
+        );
+        self.last = Some(update);
+        update
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const HOUR_NS: u64 = 60 * 60 * NANOSECONDS_IN_SECOND;
+
+    #[rstest]
+    fn test_rolling_vwap_expires_trades_outside_window() {
+        let mut vwap = RollingVwap::new(10);
+        assert_eq!(vwap.value(), None);
+
+        vwap.update(100.0, 1.0, UnixNanos::from(1));
+        vwap.update(110.0, 3.0, UnixNanos::from(5));
+        assert!((vwap.value().unwrap() - 107.5).abs() < 1e-9);
+
+        vwap.update(120.0, 1.0, UnixNanos::from(11)); // First trade expires
+        assert!((vwap.value().unwrap() - 112.5).abs() < 1e-9);
+        assert_eq!(vwap.window_start(UnixNanos::from(11)), UnixNanos::from(1));
+    }
+
+    #[rstest]
+    #[case(0, 5 * HOUR_NS, 0)]
+    #[case(8 * HOUR_NS, 9 * HOUR_NS, 8 * HOUR_NS)]
+    #[case(8 * HOUR_NS, 7 * HOUR_NS, 0)] // Before the first session, so clamped to zero
+    #[case(8 * HOUR_NS, 31 * HOUR_NS, 8 * HOUR_NS)]
+    #[case(8 * HOUR_NS, 33 * HOUR_NS, 32 * HOUR_NS)]
+    fn test_session_twap_session_start(
+        #[case] offset_ns: u64,
+        #[case] ts: u64,
+        #[case] expected: u64,
+    ) {
+        let twap = SessionTwap::new(offset_ns);
+        assert_eq!(
+            twap.session_start(UnixNanos::from(ts)),
+            UnixNanos::from(expected)
+        );
+    }
+
+    #[rstest]
+    fn test_session_twap_weights_prices_by_time() {
+        let mut twap = SessionTwap::new(0);
+
+        twap.update(100.0, UnixNanos::from(HOUR_NS));
+        assert_eq!(twap.value(), Some(100.0));
+
+        twap.update(110.0, UnixNanos::from(4 * HOUR_NS)); // 100 for three hours
+        twap.update(90.0, UnixNanos::from(5 * HOUR_NS)); // 110 for one hour
+        assert!((twap.value().unwrap() - 102.5).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_session_twap_resets_on_new_session() {
+        let mut twap = SessionTwap::new(0);
+
+        twap.update(100.0, UnixNanos::from(HOUR_NS));
+        twap.update(110.0, UnixNanos::from(2 * HOUR_NS));
+        twap.update(90.0, UnixNanos::from(NANOSECONDS_IN_DAY + HOUR_NS));
+
+        assert_eq!(twap.value(), Some(90.0));
+    }
+}
@@ -0,0 +1,257 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Resampling of time bars into bars of a longer interval.
+
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::{
+        bar::{get_bar_interval_ns, get_time_bar_start},
+        Bar, BarSpecification, BarType,
+    },
+    enums::{AggregationSource, BarAggregation},
+};
+
+fn check_resampleable(spec: &BarSpecification) -> anyhow::Result<()> {
+    match spec.aggregation {
+        BarAggregation::Millisecond
+        | BarAggregation::Second
+        | BarAggregation::Minute
+        | BarAggregation::Hour
+        | BarAggregation::Day
+        | BarAggregation::Week => Ok(()),
+        _ => anyhow::bail!("Cannot resample {} bars", spec.aggregation),
+    }
+}
+
+/// Returns the [`BarType`] of the bars resampled from `source` to the `target` specification.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If either specification is not a fixed interval time aggregation.
+/// - If the price types of the specifications differ.
+/// - If the `target` interval is not a whole multiple of the `source` interval.
+pub fn resampled_bar_type(source: &BarType, target: BarSpecification) -> anyhow::Result<BarType> {
+    let source_spec = source.spec();
+    check_resampleable(&source_spec)?;
+    check_resampleable(&target)?;
+
+    if source_spec.price_type != target.price_type {
+        anyhow::bail!(
+            "Cannot resample {} bars into {} bars, price types differ",
+            source_spec.price_type,
+            target.price_type
+        );
+    }
+
+    let bar_type = BarType::new(source.instrument_id(), target, AggregationSource::Internal);
+    let source_interval_ns = get_bar_interval_ns(source).as_u64();
+    let target_interval_ns = get_bar_interval_ns(&bar_type).as_u64();
+
+    if target_interval_ns < source_interval_ns
+        || !target_interval_ns.is_multiple_of(source_interval_ns)
+    {
+        anyhow::bail!(
+            "Cannot resample {source_spec} bars into {target} bars, interval is not a multiple"
+        );
+    }
+
+    Ok(bar_type)
+}
+
+/// Resamples the `bars` of the `source` bar type into bars of the `target` specification.
+///
+/// The `bars` must be timestamped on close and sorted in ascending order. Each resampled bar
+/// covers the window aligned the same as bars aggregated by a [`crate::aggregation::TimeBarAggregator`]
+/// with no origin offset, and is timestamped at the close of its window. Windows with no
+/// source bars produce no resampled bar.
+///
+/// The last window is partial where the source bars end before its close. A partial bar is only
+/// returned when `include_partial` is true, and is timestamped at the close of its last source bar.
+///
+/// # Errors
+///
+/// This function returns an error if the bar types cannot be resampled, see [`resampled_bar_type`].
+pub fn resample_bars(
+    source: &BarType,
+    target: BarSpecification,
+    bars: &[Bar],
+    include_partial: bool,
+) -> anyhow::Result<Vec<Bar>> {
+    let bar_type = resampled_bar_type(source, target)?;
+    let source_interval_ns = get_bar_interval_ns(source);
+    let target_interval_ns = get_bar_interval_ns(&bar_type);
+
+    let mut resampled: Vec<Bar> = Vec::new();
+    let mut current: Option<(UnixNanos, Bar)> = None; // (window close, bar)
+
+    for bar in bars {
+        let open_ns = bar.ts_event.saturating_sub(*source_interval_ns);
+
+        if let Some((close_ns, partial)) = current.as_mut() {
+            if UnixNanos::from(open_ns) < *close_ns {
+                partial.high = partial.high.max(bar.high);
+                partial.low = partial.low.min(bar.low);
+                partial.close = bar.close;
+                partial.volume += bar.volume;
+                partial.ts_event = bar.ts_event;
+                partial.ts_init = bar.ts_init;
+                continue;
+            }
+
+            resampled.push(complete(*partial, *close_ns));
+        }
+
+        let start = get_time_bar_start(UnixNanos::from(open_ns).to_datetime_utc(), &bar_type, None);
+        let close_ns = UnixNanos::from(start) + target_interval_ns;
+        let mut partial = *bar;
+        partial.bar_type = bar_type;
+        current = Some((close_ns, partial));
+    }
+
+    if let Some((close_ns, partial)) = current {
+        if partial.ts_event >= close_ns {
+            resampled.push(complete(partial, close_ns));
+        } else if include_partial {
+            resampled.push(partial);
+        }
+    }
+
+    Ok(resampled)
+}
+
+fn complete(mut bar: Bar, close_ns: UnixNanos) -> Bar {
+    bar.ts_event = close_ns;
+    bar.ts_init = bar.ts_init.max(close_ns);
+    bar
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::PriceType,
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    const MINUTE_NS: u64 = 60_000_000_000;
+
+    fn minute_bar_type() -> BarType {
+        BarType::from("AUD/USD.SIM-1-MINUTE-BID-EXTERNAL")
+    }
+
+    fn five_minute_spec() -> BarSpecification {
+        BarSpecification::new(5, BarAggregation::Minute, PriceType::Bid)
+    }
+
+    fn minute_bar(minute: u64, open: &str, high: &str, low: &str, close: &str) -> Bar {
+        let ts = UnixNanos::from(minute * MINUTE_NS);
+        Bar::new(
+            minute_bar_type(),
+            Price::from(open),
+            Price::from(high),
+            Price::from(low),
+            Price::from(close),
+            Quantity::from(100_000),
+            ts,
+            ts,
+        )
+    }
+
+    fn minute_bars(minutes: std::ops::RangeInclusive<u64>) -> Vec<Bar> {
+        minutes
+            .map(|minute| minute_bar(minute, "1.00000", "1.00010", "0.99990", "1.00000"))
+            .collect()
+    }
+
+    #[rstest]
+    fn test_resample_bars_aggregates_complete_windows() {
+        let bars = vec![
+            minute_bar(1, "1.00000", "1.00010", "0.99990", "1.00005"),
+            minute_bar(2, "1.00005", "1.00020", "1.00000", "1.00015"),
+            minute_bar(3, "1.00015", "1.00015", "0.99950", "0.99960"),
+            minute_bar(4, "0.99960", "1.00000", "0.99960", "0.99990"),
+            minute_bar(5, "0.99990", "1.00030", "0.99980", "1.00025"),
+        ];
+
+        let result = resample_bars(&minute_bar_type(), five_minute_spec(), &bars, false).unwrap();
+
+        assert_eq!(result.len(), 1);
+        let bar = result[0];
+        assert_eq!(
+            bar.bar_type,
+            BarType::from("AUD/USD.SIM-5-MINUTE-BID-INTERNAL")
+        );
+        assert_eq!(bar.open, Price::from("1.00000"));
+        assert_eq!(bar.high, Price::from("1.00030"));
+        assert_eq!(bar.low, Price::from("0.99950"));
+        assert_eq!(bar.close, Price::from("1.00025"));
+        assert_eq!(bar.volume, Quantity::from(500_000));
+        assert_eq!(bar.ts_event, UnixNanos::from(5 * MINUTE_NS));
+    }
+
+    #[rstest]
+    fn test_resample_bars_aligns_windows_to_interval() {
+        // Bars closing at minutes 3..=12 cover the windows closing at minutes 5, 10 and 15
+        let bars = minute_bars(3..=12);
+
+        let result = resample_bars(&minute_bar_type(), five_minute_spec(), &bars, false).unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].ts_event, UnixNanos::from(5 * MINUTE_NS));
+        assert_eq!(result[0].volume, Quantity::from(300_000));
+        assert_eq!(result[1].ts_event, UnixNanos::from(10 * MINUTE_NS));
+        assert_eq!(result[1].volume, Quantity::from(500_000));
+    }
+
+    #[rstest]
+    fn test_resample_bars_with_partial() {
+        let bars = minute_bars(1..=7);
+
+        let result = resample_bars(&minute_bar_type(), five_minute_spec(), &bars, true).unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[1].ts_event, UnixNanos::from(7 * MINUTE_NS));
+        assert_eq!(result[1].volume, Quantity::from(200_000));
+    }
+
+    #[rstest]
+    fn test_resample_bars_with_gap_in_window() {
+        let mut bars = minute_bars(1..=5);
+        bars.remove(4); // Window closing at minute 5 has no final bar
+
+        let result = resample_bars(&minute_bar_type(), five_minute_spec(), &bars, false).unwrap();
+        assert!(result.is_empty());
+
+        bars.extend(minute_bars(6..=6));
+        let result = resample_bars(&minute_bar_type(), five_minute_spec(), &bars, false).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].ts_event, UnixNanos::from(5 * MINUTE_NS));
+        assert_eq!(result[0].volume, Quantity::from(400_000));
+    }
+
+    #[rstest]
+    #[case(BarSpecification::new(90, BarAggregation::Second, PriceType::Bid))]
+    #[case(BarSpecification::new(5, BarAggregation::Minute, PriceType::Ask))]
+    #[case(BarSpecification::new(30, BarAggregation::Second, PriceType::Bid))]
+    #[case(BarSpecification::new(100, BarAggregation::Tick, PriceType::Bid))]
+    #[case(BarSpecification::new(1, BarAggregation::Month, PriceType::Bid))]
+    fn test_resampled_bar_type_invalid(#[case] target: BarSpecification) {
+        assert!(resampled_bar_type(&minute_bar_type(), target).is_err());
+    }
+}
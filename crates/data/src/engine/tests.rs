@@ -18,6 +18,7 @@
 use std::{
     any::Any,
     cell::{OnceCell, RefCell},
+    collections::HashMap,
     rc::Rc,
 };
 
@@ -25,31 +26,51 @@ use indexmap::indexmap;
 use nautilus_common::{
     cache::Cache,
     clock::{Clock, TestClock},
-    messages::data::{Action, SubscriptionCommand},
+    messages::data::{Action, DataResponse, SubscriptionCommand},
     msgbus::{
         handler::ShareableMessageHandler,
-        stubs::{get_message_saving_handler, get_saved_messages},
+        stubs::{get_message_saving_handler, get_saved_messages, get_saved_responses},
         switchboard::MessagingSwitchboard,
         MessageBus,
     },
     testing::init_logger_for_testing,
 };
-use nautilus_core::{UnixNanos, UUID4};
+use nautilus_core::{
+    datetime::{NANOSECONDS_IN_MILLISECOND, NANOSECONDS_IN_SECOND},
+    UnixNanos, UUID4,
+};
 use nautilus_model::{
     data::{
         stubs::{stub_delta, stub_deltas, stub_depth10},
-        Bar, BarType, Data, DataType, OrderBookDeltas, OrderBookDeltas_API, OrderBookDepth10,
-        QuoteTick, TradeTick,
+        Bar, BarSpecification, BarType, Data, DataType, FundingRateUpdate, GreeksData,
+        OrderBookDeltas, OrderBookDeltas_API, OrderBookDepth10, ProjectedFundingPnl, QuoteTick,
+        ReferencePriceUpdate, TradeTick, DEPTH10_LEN,
+    },
+    enums::{
+        AggressorSide, BarAggregation, BookType, OmsType, OrderSide, OrderType, PriceType,
+        ReferencePriceType,
     },
-    enums::BookType,
-    identifiers::{ClientId, TraderId, Venue},
-    instruments::{stubs::audusd_sim, CurrencyPair, InstrumentAny},
+    identifiers::{ClientId, InstrumentId, PositionId, TradeId, TraderId, Venue},
+    instruments::{
+        stubs::{audusd_sim, crypto_perpetual_ethusdt, option_contract_appl},
+        CryptoPerpetual, CurrencyPair, InstrumentAny, OptionContract, SyntheticInstrument,
+    },
+    orderbook::OrderBook,
+    orders::{builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+    position::Position,
+    types::{Money, Price, Quantity},
 };
 use rstest::*;
+use rust_decimal::Decimal;
 
 use crate::{
     client::DataClientAdapter,
-    engine::{DataEngine, SubscriptionCommandHandler},
+    engine::{
+        config::DataEngineConfig,
+        recorder::DataRecorder,
+        validation::{DataValidationPolicy, ValidationAction},
+        DataEngine, SubscriptionCommandHandler,
+    },
     mocks::MockDataClient,
 };
 
@@ -904,3 +925,1236 @@ fn test_process_bar(
     assert_eq!(messages.len(), 1);
     assert!(messages.contains(&bar));
 }
+
+fn subscribe_bars_command(
+    client_id: ClientId,
+    venue: Venue,
+    bar_type: BarType,
+    action: Action,
+) -> SubscriptionCommand {
+    let metadata = indexmap! {
+        "bar_type".to_string() => bar_type.to_string(),
+    };
+    SubscriptionCommand::new(
+        client_id,
+        venue,
+        DataType::new(stringify!(Bar), Some(metadata)),
+        action,
+        UUID4::new(),
+        UnixNanos::default(),
+        None,
+    )
+}
+
+fn audusd_trade(audusd_sim: &CurrencyPair, price: &str, ts: u64) -> TradeTick {
+    TradeTick::new(
+        audusd_sim.id,
+        Price::from(price),
+        Quantity::from(100_000),
+        AggressorSide::Buyer,
+        TradeId::new(ts.to_string()),
+        UnixNanos::from(ts),
+        UnixNanos::from(ts),
+    )
+}
+
+#[rstest]
+fn test_internal_bars_aggregated_from_trades(
+    audusd_sim: CurrencyPair,
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+    data_client: DataClientAdapter,
+) {
+    let client_id = data_client.client_id;
+    let venue = data_client.venue;
+    data_engine
+        .borrow_mut()
+        .process(&InstrumentAny::CurrencyPair(audusd_sim) as &dyn Any);
+    data_engine.borrow_mut().register_client(data_client, None);
+
+    let bar_type = BarType::from("AUD/USD.SIM-2-TICK-LAST-INTERNAL");
+    data_engine.borrow_mut().execute(subscribe_bars_command(
+        client_id,
+        venue,
+        bar_type,
+        Action::Subscribe,
+    ));
+
+    let handler = get_message_saving_handler::<Bar>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_bars_topic(bar_type);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    for (price, ts) in [("1.00000", 1), ("1.00010", 2), ("0.99990", 3)] {
+        let trade = audusd_trade(&audusd_sim, price, ts);
+        data_engine.borrow_mut().process_data(Data::Trade(trade));
+    }
+
+    let data_engine = data_engine.borrow();
+    let messages = get_saved_messages::<Bar>(handler);
+
+    assert!(data_engine
+        .subscribed_trade_ticks()
+        .contains(&audusd_sim.id));
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].bar_type, bar_type);
+    assert_eq!(messages[0].open, Price::from("1.00000"));
+    assert_eq!(messages[0].high, Price::from("1.00010"));
+    assert_eq!(messages[0].close, Price::from("1.00010"));
+    assert_eq!(data_engine.get_cache().bar(&bar_type), Some(&messages[0]));
+}
+
+#[rstest]
+fn test_unsubscribe_internal_bars_stops_aggregation(
+    audusd_sim: CurrencyPair,
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+    data_client: DataClientAdapter,
+) {
+    let client_id = data_client.client_id;
+    let venue = data_client.venue;
+    data_engine
+        .borrow_mut()
+        .process(&InstrumentAny::CurrencyPair(audusd_sim) as &dyn Any);
+    data_engine.borrow_mut().register_client(data_client, None);
+
+    let bar_type = BarType::from("AUD/USD.SIM-1-TICK-LAST-INTERNAL");
+    for action in [Action::Subscribe, Action::Unsubscribe] {
+        data_engine
+            .borrow_mut()
+            .execute(subscribe_bars_command(client_id, venue, bar_type, action));
+    }
+
+    let handler = get_message_saving_handler::<Bar>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_bars_topic(bar_type);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let trade = audusd_trade(&audusd_sim, "1.00000", 1);
+    data_engine.borrow_mut().process_data(Data::Trade(trade));
+
+    assert!(get_saved_messages::<Bar>(handler).is_empty());
+    assert!(!data_engine
+        .borrow()
+        .subscribed_trade_ticks()
+        .contains(&audusd_sim.id));
+}
+
+#[rstest]
+fn test_historical_quotes_response_aggregates_bars(
+    audusd_sim: CurrencyPair,
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+    data_client: DataClientAdapter,
+) {
+    let client_id = data_client.client_id;
+    let venue = data_client.venue;
+    data_engine
+        .borrow_mut()
+        .process(&InstrumentAny::CurrencyPair(audusd_sim) as &dyn Any);
+    data_engine.borrow_mut().register_client(data_client, None);
+
+    let bar_type = BarType::from("AUD/USD.SIM-2-TICK-BID-INTERNAL");
+    let handler = get_message_saving_handler::<Bar>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_bars_topic(bar_type);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let quotes: Vec<QuoteTick> = (1..=4)
+        .map(|ts| {
+            QuoteTick::new(
+                audusd_sim.id,
+                Price::from(format!("1.0000{ts}").as_str()),
+                Price::from("1.00010"),
+                Quantity::from(100_000),
+                Quantity::from(100_000),
+                UnixNanos::from(ts),
+                UnixNanos::from(ts),
+            )
+        })
+        .collect();
+    let params = HashMap::from([("bar_types".to_string(), bar_type.to_string())]);
+    let resp = DataResponse::new(
+        UUID4::new(),
+        client_id,
+        venue,
+        DataType::new(stringify!(QuoteTick), None),
+        quotes,
+        UnixNanos::default(),
+        Some(params),
+    );
+    data_engine.borrow_mut().response(resp);
+
+    let data_engine = data_engine.borrow();
+    let bars = data_engine.get_cache().bars(&bar_type).unwrap();
+
+    assert_eq!(bars.len(), 2);
+    assert_eq!(bars[0].close, Price::from("1.00004")); // Most recent bar first
+    assert_eq!(bars[1].open, Price::from("1.00001"));
+    assert!(get_saved_messages::<Bar>(handler).is_empty());
+}
+
+fn advance_clock(clock: &Rc<RefCell<TestClock>>, to_time_ns: u64) {
+    let handlers = {
+        let mut clock = clock.borrow_mut();
+        let events = clock.advance_time(to_time_ns.into(), true);
+        clock.match_handlers(events)
+    };
+    for handler in handlers {
+        handler.run();
+    }
+}
+
+fn book_snapshots_command(
+    client_id: ClientId,
+    venue: Venue,
+    type_name: &str,
+    instrument_id: InstrumentId,
+) -> SubscriptionCommand {
+    let metadata = indexmap! {
+        "instrument_id".to_string() => instrument_id.to_string(),
+        "book_type".to_string() => BookType::L2_MBP.to_string(),
+        "managed".to_string() => "true".to_string(),
+        "interval_ms".to_string() => "1000".to_string(),
+    };
+    SubscriptionCommand::new(
+        client_id,
+        venue,
+        DataType::new(type_name, Some(metadata)),
+        Action::Subscribe,
+        UUID4::new(),
+        UnixNanos::default(),
+        None,
+    )
+}
+
+#[rstest]
+fn test_book_snapshots_throttled_to_interval(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_client: DataClientAdapter,
+) {
+    let client_id = data_client.client_id;
+    let venue = data_client.venue;
+    let mut data_engine = DataEngine::new(clock.clone(), cache, msgbus.clone(), None);
+    data_engine.register_client(data_client, None);
+
+    let deltas = stub_deltas();
+    data_engine.execute(book_snapshots_command(
+        client_id,
+        venue,
+        stringify!(OrderBook),
+        deltas.instrument_id,
+    ));
+
+    let handler = get_message_saving_handler::<OrderBook>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_book_snapshots_topic(deltas.instrument_id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    data_engine.process_data(Data::Deltas(OrderBookDeltas_API::new(deltas.clone())));
+    advance_clock(&clock, 2 * NANOSECONDS_IN_SECOND);
+    advance_clock(&clock, 3 * NANOSECONDS_IN_SECOND); // No book updates in interval
+
+    let messages = get_saved_messages::<OrderBook>(handler);
+    let cache = data_engine.get_cache();
+    let book = cache.order_book(&deltas.instrument_id).unwrap();
+
+    assert_eq!(book.count, deltas.deltas.len() as u64);
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].count, book.count);
+    assert_eq!(messages[0].best_bid_price(), book.best_bid_price());
+    assert_eq!(messages[0].best_ask_price(), book.best_ask_price());
+}
+
+#[rstest]
+fn test_depth10_snapshots_built_from_book(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_client: DataClientAdapter,
+) {
+    let client_id = data_client.client_id;
+    let venue = data_client.venue;
+    let mut data_engine = DataEngine::new(clock.clone(), cache, msgbus.clone(), None);
+    data_engine.register_client(data_client, None);
+
+    let depth = stub_depth10();
+    data_engine.execute(book_snapshots_command(
+        client_id,
+        venue,
+        stringify!(OrderBookDepth10),
+        depth.instrument_id,
+    ));
+
+    let handler = get_message_saving_handler::<OrderBookDepth10>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_depth_snapshots_topic(depth.instrument_id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    data_engine.process_data(Data::Depth10(Box::new(depth)));
+    advance_clock(&clock, 2 * NANOSECONDS_IN_SECOND);
+
+    let messages = get_saved_messages::<OrderBookDepth10>(handler);
+
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].instrument_id, depth.instrument_id);
+    assert_eq!(
+        messages[0].ts_init,
+        UnixNanos::from(2 * NANOSECONDS_IN_SECOND)
+    );
+    assert_eq!(messages[0].bid_counts, [1; DEPTH10_LEN]);
+    assert_eq!(messages[0].ask_counts, [1; DEPTH10_LEN]);
+    for i in 0..DEPTH10_LEN {
+        assert_eq!(messages[0].bids[i].price, depth.bids[i].price);
+        assert_eq!(messages[0].bids[i].size, depth.bids[i].size);
+        assert_eq!(messages[0].asks[i].price, depth.asks[i].price);
+        assert_eq!(messages[0].asks[i].size, depth.asks[i].size);
+    }
+}
+
+fn synthetic_command(
+    client_id: ClientId,
+    venue: Venue,
+    type_name: &str,
+    instrument_id: InstrumentId,
+    action: Action,
+) -> SubscriptionCommand {
+    let metadata = indexmap! {
+        "instrument_id".to_string() => instrument_id.to_string(),
+    };
+    SubscriptionCommand::new(
+        client_id,
+        venue,
+        DataType::new(type_name, Some(metadata)),
+        action,
+        UUID4::new(),
+        UnixNanos::default(),
+        None,
+    )
+}
+
+#[rstest]
+fn test_synthetic_quotes_derived_from_component_quotes(
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+    data_client: DataClientAdapter,
+) {
+    let client_id = data_client.client_id;
+    let venue = data_client.venue;
+    let synthetic = SyntheticInstrument::default();
+    data_engine
+        .borrow()
+        .cache
+        .borrow_mut()
+        .add_synthetic(synthetic.clone())
+        .unwrap();
+    data_engine.borrow_mut().register_client(data_client, None);
+    data_engine.borrow_mut().execute(synthetic_command(
+        client_id,
+        venue,
+        stringify!(QuoteTick),
+        synthetic.id,
+        Action::Subscribe,
+    ));
+
+    let handler = get_message_saving_handler::<QuoteTick>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_quotes_topic(synthetic.id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    for (instrument_id, bid, ask) in [
+        (synthetic.components[0], "100.00", "101.00"),
+        (synthetic.components[1], "200.00", "203.00"),
+    ] {
+        let quote = QuoteTick::new(
+            instrument_id,
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(1),
+            Quantity::from(1),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        );
+        data_engine.borrow_mut().process_data(Data::Quote(quote));
+    }
+
+    let messages = get_saved_messages::<QuoteTick>(handler);
+
+    assert_eq!(
+        data_engine.borrow().subscribed_synthetic_quotes(),
+        vec![synthetic.id]
+    );
+    assert_eq!(messages.len(), 1); // First component quote is missing its formula input
+    assert_eq!(messages[0].instrument_id, synthetic.id);
+    assert_eq!(messages[0].bid_price, Price::from("150.00"));
+    assert_eq!(messages[0].ask_price, Price::from("152.00"));
+    assert_eq!(
+        data_engine.borrow().get_cache().quote(&synthetic.id),
+        Some(&messages[0])
+    );
+}
+
+#[rstest]
+fn test_synthetic_trades_stop_after_unsubscribe(
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+    data_client: DataClientAdapter,
+) {
+    let client_id = data_client.client_id;
+    let venue = data_client.venue;
+    let synthetic = SyntheticInstrument::default();
+    data_engine
+        .borrow()
+        .cache
+        .borrow_mut()
+        .add_synthetic(synthetic.clone())
+        .unwrap();
+    data_engine.borrow_mut().register_client(data_client, None);
+
+    let handler = get_message_saving_handler::<TradeTick>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_trades_topic(synthetic.id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let process_trades = |price: &str| {
+        for instrument_id in &synthetic.components {
+            let trade = TradeTick::new(
+                *instrument_id,
+                Price::from(price),
+                Quantity::from(1),
+                AggressorSide::Seller,
+                TradeId::from("1"),
+                UnixNanos::from(1),
+                UnixNanos::from(1),
+            );
+            data_engine.borrow_mut().process_data(Data::Trade(trade));
+        }
+    };
+
+    data_engine.borrow_mut().execute(synthetic_command(
+        client_id,
+        venue,
+        stringify!(TradeTick),
+        synthetic.id,
+        Action::Subscribe,
+    ));
+    process_trades("100.00");
+    data_engine.borrow_mut().execute(synthetic_command(
+        client_id,
+        venue,
+        stringify!(TradeTick),
+        synthetic.id,
+        Action::Unsubscribe,
+    ));
+    process_trades("200.00");
+
+    let messages = get_saved_messages::<TradeTick>(handler);
+
+    assert!(data_engine
+        .borrow()
+        .subscribed_synthetic_trades()
+        .is_empty());
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].price, Price::from("100.00"));
+    assert_eq!(messages[0].aggressor_side, AggressorSide::Seller);
+}
+
+#[rstest]
+fn test_subscribe_with_lookback_catches_up_then_continues_live(
+    audusd_sim: CurrencyPair,
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    client_id: ClientId,
+    venue: Venue,
+) {
+    let quote = |ts: u64| {
+        QuoteTick::new(
+            audusd_sim.id,
+            Price::from("1.00000"),
+            Price::from("1.00010"),
+            Quantity::from(100_000),
+            Quantity::from(100_000),
+            UnixNanos::from(ts * NANOSECONDS_IN_SECOND),
+            UnixNanos::from(ts * NANOSECONDS_IN_SECOND),
+        )
+    };
+
+    let mut client = MockDataClient::new(cache.clone(), msgbus.clone(), client_id, venue);
+    client.quotes = vec![quote(1), quote(2), quote(3)];
+    let data_client = DataClientAdapter::new(
+        client_id,
+        venue,
+        true,
+        true,
+        Box::new(client),
+        clock.clone(),
+    );
+    clock
+        .borrow_mut()
+        .set_time(UnixNanos::from(10 * NANOSECONDS_IN_SECOND));
+
+    let mut data_engine = DataEngine::new(clock, cache, msgbus.clone(), None);
+    data_engine.register_client(data_client, None);
+
+    let handler = get_message_saving_handler::<QuoteTick>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_quotes_topic(audusd_sim.id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let metadata = indexmap! {
+        "instrument_id".to_string() => audusd_sim.id.to_string(),
+    };
+    let params = HashMap::from([("lookback_ms".to_string(), "8500".to_string())]);
+    data_engine.execute(SubscriptionCommand::new(
+        client_id,
+        venue,
+        DataType::new(stringify!(QuoteTick), Some(metadata)),
+        Action::Subscribe,
+        UUID4::new(),
+        UnixNanos::default(),
+        Some(params),
+    ));
+
+    // Live data from the boundary already delivered as history is dropped
+    for ts in [3, 4] {
+        data_engine.process_data(Data::Quote(quote(ts)));
+    }
+
+    let responses = get_saved_responses::<QuoteTick>(handler.clone());
+    let messages = get_saved_messages::<QuoteTick>(handler);
+    let historical = responses[0].data.downcast_ref::<Vec<QuoteTick>>().unwrap();
+
+    assert_eq!(responses.len(), 1);
+    assert_eq!(
+        responses[0].params.as_ref().unwrap().get("historical"),
+        Some(&"true".to_string())
+    );
+    assert_eq!(historical, &vec![quote(2), quote(3)]); // Outside lookback window excluded
+    assert_eq!(messages, vec![quote(4)]);
+    assert_eq!(
+        data_engine
+            .get_cache()
+            .quotes(&audusd_sim.id)
+            .unwrap()
+            .len(),
+        3
+    );
+}
+
+fn conflated_command(
+    client_id: ClientId,
+    venue: Venue,
+    type_name: &str,
+    instrument_id: InstrumentId,
+    conflation: &str,
+    action: Action,
+) -> SubscriptionCommand {
+    let metadata = indexmap! {
+        "instrument_id".to_string() => instrument_id.to_string(),
+        "interval_ms".to_string() => "1000".to_string(),
+        "conflation".to_string() => conflation.to_string(),
+    };
+    SubscriptionCommand::new(
+        client_id,
+        venue,
+        DataType::new(type_name, Some(metadata)),
+        action,
+        UUID4::new(),
+        UnixNanos::default(),
+        None,
+    )
+}
+
+#[rstest]
+fn test_conflated_quotes_publish_latest_per_interval(
+    audusd_sim: CurrencyPair,
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_client: DataClientAdapter,
+) {
+    let client_id = data_client.client_id;
+    let venue = data_client.venue;
+    let mut data_engine = DataEngine::new(clock.clone(), cache, msgbus.clone(), None);
+    data_engine.register_client(data_client, None);
+
+    let handler = get_message_saving_handler::<QuoteTick>(None);
+    let conflated_handler = get_message_saving_handler::<QuoteTick>(None);
+    let conflated_topic = {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_quotes_topic(audusd_sim.id);
+        let conflated_topic = msgbus
+            .switchboard
+            .get_conflated_topic(topic, "latest", 1000);
+        msgbus.subscribe(topic, handler.clone(), None);
+        msgbus.subscribe(conflated_topic, conflated_handler.clone(), None);
+        conflated_topic
+    };
+
+    data_engine.execute(conflated_command(
+        client_id,
+        venue,
+        stringify!(QuoteTick),
+        audusd_sim.id,
+        "latest",
+        Action::Subscribe,
+    ));
+
+    let quote = |bid: &str, ts_ms: u64| {
+        QuoteTick::new(
+            audusd_sim.id,
+            Price::from(bid),
+            Price::from("1.00010"),
+            Quantity::from(100_000),
+            Quantity::from(100_000),
+            UnixNanos::from(ts_ms * NANOSECONDS_IN_MILLISECOND),
+            UnixNanos::from(ts_ms * NANOSECONDS_IN_MILLISECOND),
+        )
+    };
+    data_engine.process_data(Data::Quote(quote("1.00000", 100)));
+    data_engine.process_data(Data::Quote(quote("1.00001", 500)));
+    advance_clock(&clock, NANOSECONDS_IN_SECOND);
+    data_engine.process_data(Data::Quote(quote("1.00002", 1_200)));
+    advance_clock(&clock, 2 * NANOSECONDS_IN_SECOND);
+    advance_clock(&clock, 3 * NANOSECONDS_IN_SECOND); // No quotes in interval
+
+    // The full rate stream is unaffected by the conflated consumer leaving
+    msgbus
+        .borrow_mut()
+        .unsubscribe(conflated_topic, conflated_handler.clone());
+    data_engine.execute(conflated_command(
+        client_id,
+        venue,
+        stringify!(QuoteTick),
+        audusd_sim.id,
+        "latest",
+        Action::Unsubscribe,
+    ));
+    data_engine.process_data(Data::Quote(quote("1.00003", 3_500)));
+
+    let messages = get_saved_messages::<QuoteTick>(handler);
+    let conflated = get_saved_messages::<QuoteTick>(conflated_handler);
+    assert_eq!(messages.len(), 4);
+    assert_eq!(conflated.len(), 2);
+    assert_eq!(conflated[0], quote("1.00001", 500));
+    assert_eq!(conflated[1], quote("1.00002", 1_200));
+    assert_eq!(data_engine.subscribed_quote_ticks(), vec![audusd_sim.id]);
+    assert!(data_engine.conflators.is_empty());
+    assert!(clock.borrow().timer_names().is_empty());
+}
+
+#[rstest]
+fn test_conflated_trades_rolled_up_to_ohlc(
+    audusd_sim: CurrencyPair,
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_client: DataClientAdapter,
+) {
+    let client_id = data_client.client_id;
+    let venue = data_client.venue;
+    let mut data_engine = DataEngine::new(clock.clone(), cache, msgbus.clone(), None);
+    data_engine.register_client(data_client, None);
+
+    let handler = get_message_saving_handler::<Bar>(None);
+    let conflated_topic = {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_trades_topic(audusd_sim.id);
+        let conflated_topic = msgbus.switchboard.get_conflated_topic(topic, "ohlc", 1000);
+        msgbus.subscribe(conflated_topic, handler.clone(), None);
+        conflated_topic
+    };
+
+    data_engine.execute(conflated_command(
+        client_id,
+        venue,
+        stringify!(TradeTick),
+        audusd_sim.id,
+        "ohlc",
+        Action::Subscribe,
+    ));
+
+    let millis = NANOSECONDS_IN_MILLISECOND;
+    for (price, ts) in [
+        ("1.00000", 200),
+        ("1.00020", 400),
+        ("0.99990", 600),
+        ("1.00010", 800),
+    ] {
+        let trade = audusd_trade(&audusd_sim, price, ts * millis);
+        data_engine.process_data(Data::Trade(trade));
+    }
+    advance_clock(&clock, NANOSECONDS_IN_SECOND);
+
+    msgbus
+        .borrow_mut()
+        .unsubscribe(conflated_topic, handler.clone());
+    data_engine.execute(conflated_command(
+        client_id,
+        venue,
+        stringify!(TradeTick),
+        audusd_sim.id,
+        "ohlc",
+        Action::Unsubscribe,
+    ));
+
+    let bars = get_saved_messages::<Bar>(handler);
+    assert_eq!(bars.len(), 1);
+    assert_eq!(
+        bars[0].bar_type,
+        BarType::from("AUD/USD.SIM-1000-MILLISECOND-LAST-INTERNAL")
+    );
+    assert_eq!(bars[0].open, Price::from("1.00000"));
+    assert_eq!(bars[0].high, Price::from("1.00020"));
+    assert_eq!(bars[0].low, Price::from("0.99990"));
+    assert_eq!(bars[0].close, Price::from("1.00010"));
+    assert_eq!(bars[0].volume, Quantity::from(400_000));
+    assert_eq!(bars[0].ts_event, UnixNanos::from(NANOSECONDS_IN_SECOND));
+    assert!(data_engine.subscribed_trade_ticks().is_empty());
+    assert!(clock.borrow().timer_names().is_empty());
+}
+
+#[rstest]
+fn test_validation_rejects_and_repairs_quotes(
+    audusd_sim: CurrencyPair,
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+) {
+    let config = DataEngineConfig {
+        validation_policy: Some(DataValidationPolicy::default()),
+        ..Default::default()
+    };
+    let mut data_engine = DataEngine::new(clock, cache, msgbus.clone(), Some(config));
+    let quote = |bid: &str, ask: &str| {
+        QuoteTick::new(
+            audusd_sim.id,
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(100_000),
+            Quantity::from(200_000),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    };
+
+    let handler = get_message_saving_handler::<QuoteTick>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_quotes_topic(audusd_sim.id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    data_engine.process_data(Data::Quote(quote("1.00010", "1.00000")));
+    data_engine.set_validation_policy(
+        audusd_sim.id,
+        DataValidationPolicy {
+            crossed_quote: ValidationAction::Repair,
+            ..Default::default()
+        },
+    );
+    data_engine.process_data(Data::Quote(quote("1.00010", "1.00000")));
+
+    let messages = get_saved_messages::<QuoteTick>(handler);
+    let counters = data_engine.validation_counters(&audusd_sim.id);
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].bid_price, Price::from("1.00000"));
+    assert_eq!(messages[0].ask_size, Quantity::from(100_000));
+    assert_eq!(
+        data_engine.cache.borrow().quote(&audusd_sim.id),
+        Some(&messages[0])
+    );
+    assert_eq!(counters.crossed_quotes, 2);
+    assert_eq!(counters.rejected, 1);
+    assert_eq!(counters.repaired, 1);
+}
+
+#[rstest]
+fn test_validation_rejects_invalid_trades(
+    audusd_sim: CurrencyPair,
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+) {
+    let config = DataEngineConfig {
+        validation_policies: HashMap::from([(
+            audusd_sim.id,
+            DataValidationPolicy {
+                ts_tolerance_ns: NANOSECONDS_IN_SECOND,
+                ..Default::default()
+            },
+        )]),
+        ..Default::default()
+    };
+    let mut data_engine = DataEngine::new(clock, cache, msgbus.clone(), Some(config));
+
+    let handler = get_message_saving_handler::<TradeTick>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_trades_topic(audusd_sim.id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let second = NANOSECONDS_IN_SECOND;
+    data_engine.process_data(Data::Trade(audusd_trade(
+        &audusd_sim,
+        "1.00000",
+        3 * second,
+    )));
+    data_engine.process_data(Data::Trade(audusd_trade(
+        &audusd_sim,
+        "0.00000",
+        4 * second,
+    )));
+    data_engine.process_data(Data::Trade(audusd_trade(
+        &audusd_sim,
+        "1.00010",
+        2 * second,
+    )));
+    data_engine.process_data(Data::Trade(audusd_trade(&audusd_sim, "1.00020", second)));
+
+    let messages = get_saved_messages::<TradeTick>(handler);
+    let counters = data_engine.validation_counters(&audusd_sim.id);
+    assert_eq!(
+        messages.iter().map(|t| t.price).collect::<Vec<_>>(),
+        vec![Price::from("1.00000"), Price::from("1.00010")]
+    );
+    assert_eq!(counters.non_positive_prices, 1);
+    assert_eq!(counters.ts_regressions, 1);
+    assert_eq!(counters.rejected, 2);
+}
+
+#[rstest]
+fn test_feed_data_fails_over_to_redundant_feed_when_stale(
+    audusd_sim: CurrencyPair,
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+) {
+    let mut data_engine = DataEngine::new(clock.clone(), cache, msgbus.clone(), None);
+    let feed_a = ClientId::from("FEED-A");
+    let feed_b = ClientId::from("FEED-B");
+
+    let handler = get_message_saving_handler::<TradeTick>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_trades_topic(audusd_sim.id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let second = NANOSECONDS_IN_SECOND;
+    let trade = |price: &str, ts: u64| Data::Trade(audusd_trade(&audusd_sim, price, ts));
+    data_engine.process_feed_data(feed_a, trade("1.00000", 0));
+    data_engine.process_feed_data(feed_b, trade("1.00000", 0));
+    advance_clock(&clock, 3 * second); // Feed A goes stale
+    data_engine.process_feed_data(feed_b, trade("1.00010", 3 * second));
+    data_engine.process_feed_data(feed_a, trade("1.00010", 3 * second));
+
+    let messages = get_saved_messages::<TradeTick>(handler);
+    assert_eq!(
+        messages.iter().map(|t| t.price).collect::<Vec<_>>(),
+        vec![Price::from("1.00000"), Price::from("1.00010")]
+    );
+    assert_eq!(data_engine.active_feed(&audusd_sim.id), Some(feed_b));
+}
+
+#[derive(Default)]
+struct StubRecorder {
+    recorded: Rc<RefCell<Vec<Data>>>,
+    flushes: Rc<RefCell<usize>>,
+}
+
+impl DataRecorder for StubRecorder {
+    fn record(&mut self, data: &Data) {
+        self.recorded.borrow_mut().push(data.clone());
+    }
+
+    fn flush(&mut self) {
+        *self.flushes.borrow_mut() += 1;
+    }
+}
+
+#[rstest]
+fn test_recorder_records_all_data_and_flushes_on_stop(
+    audusd_sim: CurrencyPair,
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+) {
+    let mut data_engine = DataEngine::new(clock, cache, msgbus, None);
+    let recorder = StubRecorder::default();
+    let recorded = recorder.recorded.clone();
+    let flushes = recorder.flushes.clone();
+    data_engine.register_recorder(Box::new(recorder));
+
+    let trade = Data::Trade(audusd_trade(&audusd_sim, "1.00000", 0));
+    let delta = Data::Delta(stub_delta());
+    data_engine.process_data(trade.clone());
+    data_engine.process_data(delta.clone());
+    data_engine.stop();
+
+    assert_eq!(*recorded.borrow(), vec![trade, delta]);
+    assert_eq!(*flushes.borrow(), 1);
+}
+
+#[rstest]
+fn test_greeks_calculated_from_option_and_underlying_quotes(
+    option_contract_appl: OptionContract,
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+    data_client: DataClientAdapter,
+) {
+    let client_id = data_client.client_id;
+    let venue = data_client.venue;
+    let option_id = option_contract_appl.id;
+    let underlying_id = InstrumentId::from("AAPL.OPRA");
+    data_engine
+        .borrow()
+        .cache
+        .borrow_mut()
+        .add_instrument(InstrumentAny::OptionContract(option_contract_appl))
+        .unwrap();
+    data_engine.borrow_mut().register_client(data_client, None);
+    data_engine.borrow_mut().execute(synthetic_command(
+        client_id,
+        venue,
+        stringify!(GreeksData),
+        option_id,
+        Action::Subscribe,
+    ));
+
+    let handler = get_message_saving_handler::<GreeksData>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus.switchboard.get_greeks_topic(option_id);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let ts =
+        option_contract_appl.expiration_ns.as_u64() - 30 * 24 * 60 * 60 * NANOSECONDS_IN_SECOND;
+    for (instrument_id, bid, ask) in [
+        (underlying_id, "150.00", "150.10"),
+        (option_id, "4.00", "4.20"),
+        (underlying_id, "151.00", "151.10"),
+    ] {
+        let quote = QuoteTick::new(
+            instrument_id,
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(1),
+            Quantity::from(1),
+            UnixNanos::from(ts),
+            UnixNanos::from(ts),
+        );
+        data_engine.borrow_mut().process_data(Data::Quote(quote));
+    }
+
+    let messages = get_saved_messages::<GreeksData>(handler);
+    let mut subscribed_quotes = data_engine.borrow().subscribed_quote_ticks();
+    subscribed_quotes.sort();
+
+    assert_eq!(data_engine.borrow().subscribed_greeks(), vec![option_id]);
+    assert_eq!(subscribed_quotes, vec![underlying_id, option_id]);
+    assert_eq!(messages.len(), 2); // First underlying quote is missing the option quote
+    assert_eq!(messages[0].instrument_id, option_id);
+    assert!((messages[0].underlying_price - 150.05).abs() < 1e-9);
+    assert!((messages[1].underlying_price - 151.05).abs() < 1e-9);
+    assert!(messages[0].vol > 0.0);
+    assert!(messages[1].vol < messages[0].vol); // Same option price is cheaper in vol
+    assert!(messages[0].delta > 0.0 && messages[0].delta < 1.0);
+}
+
+#[rstest]
+fn test_funding_rate_publishes_projected_funding_pnl(
+    crypto_perpetual_ethusdt: CryptoPerpetual,
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+) {
+    let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+    let instrument_id = instrument.id();
+    let order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_id)
+        .side(OrderSide::Sell)
+        .quantity(Quantity::from("2.000"))
+        .build();
+    let fill = TestOrderEventStubs::order_filled(
+        &order,
+        &instrument,
+        None,
+        Some(PositionId::new("P-1")),
+        Some(Price::from("1900.00")),
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    let position = Position::new(&instrument, fill.into());
+    {
+        let mut cache = cache.borrow_mut();
+        cache.add_instrument(instrument).unwrap();
+        cache.add_position(position, OmsType::Netting).unwrap();
+        cache.add_mark_price(&instrument_id, Price::from("2000.00"));
+    }
+    let mut data_engine = DataEngine::new(clock, cache.clone(), msgbus.clone(), None);
+
+    let rates_handler = get_message_saving_handler::<FundingRateUpdate>(None);
+    let pnl_handler = get_message_saving_handler::<ProjectedFundingPnl>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let rates_topic = msgbus.switchboard.get_funding_rates_topic(instrument_id);
+        let pnl_topic = msgbus.switchboard.get_funding_pnl_topic(instrument_id);
+        msgbus.subscribe(rates_topic, rates_handler.clone(), None);
+        msgbus.subscribe(pnl_topic, pnl_handler.clone(), None);
+    }
+
+    let update = FundingRateUpdate::new(
+        instrument_id,
+        Decimal::new(1, 4),
+        None,
+        Some(UnixNanos::from(8 * 60 * 60 * NANOSECONDS_IN_SECOND)),
+        UnixNanos::from(1),
+        UnixNanos::from(1),
+    );
+    data_engine.process(&update as &dyn Any);
+
+    let rates = get_saved_messages::<FundingRateUpdate>(rates_handler);
+    let projections = get_saved_messages::<ProjectedFundingPnl>(pnl_handler);
+
+    assert_eq!(rates, vec![update]);
+    assert_eq!(cache.borrow().funding_rate(&instrument_id), Some(&update));
+    assert_eq!(projections.len(), 1);
+    assert_eq!(projections[0].position_id, PositionId::new("P-1"));
+    assert_eq!(projections[0].notional, Money::from("4000.00 USDT"));
+    assert_eq!(projections[0].pnl, Money::from("0.40 USDT")); // Short receives funding
+    assert_eq!(
+        data_engine.projected_funding_pnls(&instrument_id),
+        projections
+    );
+}
+
+#[rstest]
+fn test_resample_bars_from_cached_bars(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+) {
+    let bar_type = BarType::from("AUD/USD.SIM-1-MINUTE-LAST-EXTERNAL");
+    let minute_ns = 60 * NANOSECONDS_IN_SECOND;
+    let bars: Vec<Bar> = (1..=7)
+        .map(|minute| {
+            let ts = UnixNanos::from(minute * minute_ns);
+            let close = Price::from(format!("1.0000{minute}").as_str());
+            Bar::new(
+                bar_type,
+                Price::from("1.00000"),
+                close,
+                Price::from("1.00000"),
+                close,
+                Quantity::from(100_000),
+                ts,
+                ts,
+            )
+        })
+        .collect();
+    cache.borrow_mut().add_bars(&bars).unwrap();
+
+    let target = BarSpecification::new(5, BarAggregation::Minute, PriceType::Last);
+    let data_engine = DataEngine::new(clock, cache, msgbus, None);
+    let complete = data_engine.resample_bars(&bar_type, target, false).unwrap();
+    let with_partial = data_engine.resample_bars(&bar_type, target, true).unwrap();
+
+    assert_eq!(complete.len(), 1);
+    assert_eq!(
+        complete[0].bar_type,
+        BarType::from("AUD/USD.SIM-5-MINUTE-LAST-INTERNAL")
+    );
+    assert_eq!(complete[0].open, Price::from("1.00000"));
+    assert_eq!(complete[0].high, Price::from("1.00005"));
+    assert_eq!(complete[0].close, Price::from("1.00005"));
+    assert_eq!(complete[0].volume, Quantity::from(500_000));
+    assert_eq!(complete[0].ts_event, UnixNanos::from(5 * minute_ns));
+    assert_eq!(with_partial.len(), 2);
+    assert_eq!(with_partial[1].close, Price::from("1.00007"));
+    assert_eq!(with_partial[1].ts_event, UnixNanos::from(7 * minute_ns));
+}
+
+fn reference_price_command(
+    client_id: ClientId,
+    venue: Venue,
+    instrument_id: InstrumentId,
+    params: &[(&str, &str)],
+    action: Action,
+) -> SubscriptionCommand {
+    let mut metadata = indexmap! {
+        "instrument_id".to_string() => instrument_id.to_string(),
+    };
+    for (key, value) in params {
+        metadata.insert((*key).to_string(), (*value).to_string());
+    }
+    SubscriptionCommand::new(
+        client_id,
+        venue,
+        DataType::new(stringify!(ReferencePriceUpdate), Some(metadata)),
+        action,
+        UUID4::new(),
+        UnixNanos::default(),
+        None,
+    )
+}
+
+#[rstest]
+fn test_rolling_vwap_published_from_trades(
+    audusd_sim: CurrencyPair,
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+    data_client: DataClientAdapter,
+) {
+    let client_id = data_client.client_id;
+    let venue = data_client.venue;
+    let instrument_id = audusd_sim.id;
+    data_engine.borrow_mut().register_client(data_client, None);
+    data_engine.borrow_mut().execute(reference_price_command(
+        client_id,
+        venue,
+        instrument_id,
+        &[("price_type", "VWAP"), ("window_secs", "60")],
+        Action::Subscribe,
+    ));
+
+    let handler = get_message_saving_handler::<ReferencePriceUpdate>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_reference_price_topic(instrument_id, ReferencePriceType::Vwap);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    for (price, secs) in [("1.00000", 1), ("1.00020", 40), ("1.00040", 90)] {
+        let trade = audusd_trade(&audusd_sim, price, secs * NANOSECONDS_IN_SECOND);
+        data_engine.borrow_mut().process_data(Data::Trade(trade));
+    }
+
+    let messages = get_saved_messages::<ReferencePriceUpdate>(handler);
+
+    assert_eq!(
+        data_engine.borrow().subscribed_reference_prices(),
+        vec![(instrument_id, ReferencePriceType::Vwap)]
+    );
+    assert_eq!(
+        data_engine.borrow().subscribed_trade_ticks(),
+        vec![instrument_id]
+    );
+    assert_eq!(messages.len(), 3);
+    assert_eq!(messages[0].price, Price::from("1.00000"));
+    assert_eq!(messages[1].price, Price::from("1.00010"));
+    assert_eq!(messages[2].price, Price::from("1.00030")); // First trade left the window
+    assert_eq!(
+        messages[2].period_start_ns,
+        UnixNanos::from(30 * NANOSECONDS_IN_SECOND)
+    );
+    assert_eq!(
+        data_engine
+            .borrow()
+            .reference_price(&instrument_id, ReferencePriceType::Vwap),
+        Some(messages[2])
+    );
+}
+
+#[rstest]
+fn test_session_twap_published_from_quotes(
+    audusd_sim: CurrencyPair,
+    msgbus: Rc<RefCell<MessageBus>>,
+    data_engine: Rc<RefCell<DataEngine>>,
+    data_client: DataClientAdapter,
+) {
+    let client_id = data_client.client_id;
+    let venue = data_client.venue;
+    let instrument_id = audusd_sim.id;
+    let params = [("price_type", "TWAP"), ("session_start", "08:00")];
+    data_engine.borrow_mut().register_client(data_client, None);
+    data_engine.borrow_mut().execute(reference_price_command(
+        client_id,
+        venue,
+        instrument_id,
+        &params,
+        Action::Subscribe,
+    ));
+
+    let handler = get_message_saving_handler::<ReferencePriceUpdate>(None);
+    {
+        let mut msgbus = msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_reference_price_topic(instrument_id, ReferencePriceType::Twap);
+        msgbus.subscribe(topic, handler.clone(), None);
+    }
+
+    let hour_ns = 60 * 60 * NANOSECONDS_IN_SECOND;
+    for (bid, ask, hours) in [
+        ("1.00000", "1.00002", 9),
+        ("1.00010", "1.00012", 10),
+        ("1.00020", "1.00022", 12),
+    ] {
+        let quote = QuoteTick::new(
+            instrument_id,
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(100_000),
+            Quantity::from(100_000),
+            UnixNanos::from(hours * hour_ns),
+            UnixNanos::from(hours * hour_ns),
+        );
+        data_engine.borrow_mut().process_data(Data::Quote(quote));
+    }
+
+    let messages = get_saved_messages::<ReferencePriceUpdate>(handler);
+
+    assert_eq!(
+        data_engine.borrow().subscribed_quote_ticks(),
+        vec![instrument_id]
+    );
+    assert_eq!(messages.len(), 3);
+    assert_eq!(messages[0].price, Price::from("1.000010"));
+    assert_eq!(messages[0].period_start_ns, UnixNanos::from(8 * hour_ns));
+    assert_eq!(messages[2].price, Price::from("1.000077")); // (1.00001 + 2 * 1.00011) / 3
+
+    data_engine.borrow_mut().execute(reference_price_command(
+        client_id,
+        venue,
+        instrument_id,
+        &params,
+        Action::Unsubscribe,
+    ));
+
+    assert!(data_engine
+        .borrow()
+        .subscribed_reference_prices()
+        .is_empty());
+    assert!(data_engine.borrow().subscribed_quote_ticks().is_empty());
+}
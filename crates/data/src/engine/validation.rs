@@ -0,0 +1,398 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Validation and sanitization of the quotes and trades received by the data engine.
+
+use std::collections::HashMap;
+
+use nautilus_model::{
+    data::{QuoteTick, TradeTick},
+    identifiers::InstrumentId,
+};
+
+/// The action taken for a tick with a data anomaly.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ValidationAction {
+    /// Passes the tick through unchanged.
+    Allow,
+    /// Drops the tick.
+    #[default]
+    Reject,
+    /// Repairs the tick where possible, otherwise drops it.
+    Repair,
+}
+
+/// The anomaly checks applied to the ticks of an instrument.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DataValidationPolicy {
+    /// The action for zero or negative prices, repaired with the last valid price.
+    pub non_positive_price: ValidationAction,
+    /// The action for quotes with a bid above the ask, repaired by swapping the sides.
+    pub crossed_quote: ValidationAction,
+    /// The action for a `ts_event` earlier than the latest seen beyond the tolerance, repaired
+    /// with the latest `ts_event`.
+    pub ts_regression: ValidationAction,
+    /// The tolerance (nanoseconds) for a `ts_event` going backwards.
+    pub ts_tolerance_ns: u64,
+}
+
+impl Default for DataValidationPolicy {
+    /// Creates a new default [`DataValidationPolicy`] instance, rejecting all anomalies.
+    fn default() -> Self {
+        Self {
+            non_positive_price: ValidationAction::Reject,
+            crossed_quote: ValidationAction::Reject,
+            ts_regression: ValidationAction::Reject,
+            ts_tolerance_ns: 0,
+        }
+    }
+}
+
+/// Counts the anomalies detected in the ticks of an instrument, and the ticks they caused to
+/// be rejected or repaired.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ValidationCounters {
+    pub non_positive_prices: u64,
+    pub crossed_quotes: u64,
+    pub ts_regressions: u64,
+    pub rejected: u64,
+    pub repaired: u64,
+}
+
+/// Validates ticks against the policy for their instrument, rejecting or repairing those
+/// with anomalies.
+#[derive(Debug, Default)]
+pub struct DataValidator {
+    default_policy: Option<DataValidationPolicy>,
+    policies: HashMap<InstrumentId, DataValidationPolicy>,
+    last_quotes: HashMap<InstrumentId, QuoteTick>,
+    last_trades: HashMap<InstrumentId, TradeTick>,
+    counters: HashMap<InstrumentId, ValidationCounters>,
+}
+
+impl DataValidator {
+    /// Creates a new [`DataValidator`] instance.
+    ///
+    /// The `default_policy` applies to instruments without a policy of their own, with ticks
+    /// of any other instrument passed through unchecked.
+    #[must_use]
+    pub fn new(
+        default_policy: Option<DataValidationPolicy>,
+        policies: HashMap<InstrumentId, DataValidationPolicy>,
+    ) -> Self {
+        Self {
+            default_policy,
+            policies,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the `policy` for the ticks of the `instrument_id`.
+    pub fn set_policy(&mut self, instrument_id: InstrumentId, policy: DataValidationPolicy) {
+        self.policies.insert(instrument_id, policy);
+    }
+
+    /// Returns the policy for the ticks of the `instrument_id` (if any).
+    #[must_use]
+    pub fn policy(&self, instrument_id: &InstrumentId) -> Option<&DataValidationPolicy> {
+        self.policies
+            .get(instrument_id)
+            .or(self.default_policy.as_ref())
+    }
+
+    /// Returns the anomaly counters for the ticks of the `instrument_id` (if any validated).
+    #[must_use]
+    pub fn counters(&self, instrument_id: &InstrumentId) -> Option<&ValidationCounters> {
+        self.counters.get(instrument_id)
+    }
+
+    /// Returns the validated `quote`, or `None` if rejected.
+    pub fn validate_quote(&mut self, mut quote: QuoteTick) -> Option<QuoteTick> {
+        let instrument_id = quote.instrument_id;
+        let Some(policy) = self.policy(&instrument_id).cloned() else {
+            return Some(quote);
+        };
+        let last_quote = self.last_quotes.get(&instrument_id).copied();
+        let counters = self.counters.entry(instrument_id).or_default();
+        let mut is_repaired = false;
+
+        if quote.bid_price.raw <= 0 || quote.ask_price.raw <= 0 {
+            counters.non_positive_prices += 1;
+            match (policy.non_positive_price, last_quote) {
+                (ValidationAction::Allow, _) => {}
+                (ValidationAction::Repair, Some(last_quote)) => {
+                    if quote.bid_price.raw <= 0 {
+                        quote.bid_price = last_quote.bid_price;
+                    }
+                    if quote.ask_price.raw <= 0 {
+                        quote.ask_price = last_quote.ask_price;
+                    }
+                    is_repaired = true;
+                }
+                _ => return reject(counters, &quote, "non-positive price"),
+            }
+        }
+
+        if quote.bid_price > quote.ask_price {
+            counters.crossed_quotes += 1;
+            match policy.crossed_quote {
+                ValidationAction::Allow => {}
+                ValidationAction::Reject => return reject(counters, &quote, "crossed quote"),
+                ValidationAction::Repair => {
+                    std::mem::swap(&mut quote.bid_price, &mut quote.ask_price);
+                    std::mem::swap(&mut quote.bid_size, &mut quote.ask_size);
+                    is_repaired = true;
+                }
+            }
+        }
+
+        if let Some(last_quote) = last_quote {
+            if quote.ts_event.as_u64() + policy.ts_tolerance_ns < last_quote.ts_event.as_u64() {
+                counters.ts_regressions += 1;
+                match policy.ts_regression {
+                    ValidationAction::Allow => {}
+                    ValidationAction::Reject => {
+                        return reject(counters, &quote, "`ts_event` went backwards");
+                    }
+                    ValidationAction::Repair => {
+                        quote.ts_event = last_quote.ts_event;
+                        is_repaired = true;
+                    }
+                }
+            }
+        }
+
+        if is_repaired {
+            counters.repaired += 1;
+            log::debug!("Repaired {quote}");
+        }
+
+        // Regressions are measured from the latest `ts_event`, so tolerated ticks cannot drift
+        let mut last = quote;
+        last.ts_event = last
+            .ts_event
+            .max(last_quote.map_or(last.ts_event, |q| q.ts_event));
+        self.last_quotes.insert(instrument_id, last);
+        Some(quote)
+    }
+
+    /// Returns the validated `trade`, or `None` if rejected.
+    pub fn validate_trade(&mut self, mut trade: TradeTick) -> Option<TradeTick> {
+        let instrument_id = trade.instrument_id;
+        let Some(policy) = self.policy(&instrument_id).cloned() else {
+            return Some(trade);
+        };
+        let last_trade = self.last_trades.get(&instrument_id).copied();
+        let counters = self.counters.entry(instrument_id).or_default();
+        let mut is_repaired = false;
+
+        if trade.price.raw <= 0 {
+            counters.non_positive_prices += 1;
+            match (policy.non_positive_price, last_trade) {
+                (ValidationAction::Allow, _) => {}
+                (ValidationAction::Repair, Some(last_trade)) => {
+                    trade.price = last_trade.price;
+                    is_repaired = true;
+                }
+                _ => return reject(counters, &trade, "non-positive price"),
+            }
+        }
+
+        if let Some(last_trade) = last_trade {
+            if trade.ts_event.as_u64() + policy.ts_tolerance_ns < last_trade.ts_event.as_u64() {
+                counters.ts_regressions += 1;
+                match policy.ts_regression {
+                    ValidationAction::Allow => {}
+                    ValidationAction::Reject => {
+                        return reject(counters, &trade, "`ts_event` went backwards");
+                    }
+                    ValidationAction::Repair => {
+                        trade.ts_event = last_trade.ts_event;
+                        is_repaired = true;
+                    }
+                }
+            }
+        }
+
+        if is_repaired {
+            counters.repaired += 1;
+            log::debug!("Repaired {trade}");
+        }
+
+        let mut last = trade;
+        last.ts_event = last
+            .ts_event
+            .max(last_trade.map_or(last.ts_event, |t| t.ts_event));
+        self.last_trades.insert(instrument_id, last);
+        Some(trade)
+    }
+}
+
+fn reject<T: std::fmt::Display>(
+    counters: &mut ValidationCounters,
+    tick: &T,
+    reason: &str,
+) -> Option<T> {
+    counters.rejected += 1;
+    log::warn!("Rejected {tick}: {reason}");
+    None
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::UnixNanos;
+    use nautilus_model::{
+        enums::AggressorSide,
+        identifiers::TradeId,
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn quote(bid: &str, ask: &str, ts: u64) -> QuoteTick {
+        QuoteTick::new(
+            InstrumentId::from("AUD/USD.SIM"),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(1),
+            Quantity::from(2),
+            UnixNanos::from(ts),
+            UnixNanos::from(ts),
+        )
+    }
+
+    fn trade(price: &str, ts: u64) -> TradeTick {
+        TradeTick::new(
+            InstrumentId::from("AUD/USD.SIM"),
+            Price::from(price),
+            Quantity::from(1),
+            AggressorSide::Buyer,
+            TradeId::from("1"),
+            UnixNanos::from(ts),
+            UnixNanos::from(ts),
+        )
+    }
+
+    fn validator(policy: DataValidationPolicy) -> DataValidator {
+        DataValidator::new(Some(policy), HashMap::new())
+    }
+
+    #[rstest]
+    fn test_without_policy_passes_anomalies() {
+        let mut validator = DataValidator::default();
+        let crossed = quote("1.00010", "1.00000", 1);
+
+        assert_eq!(validator.validate_quote(crossed), Some(crossed));
+        assert!(validator.counters(&crossed.instrument_id).is_none());
+    }
+
+    #[rstest]
+    fn test_rejects_anomalies_by_default() {
+        let mut validator = validator(DataValidationPolicy::default());
+        let instrument_id = InstrumentId::from("AUD/USD.SIM");
+
+        assert!(validator
+            .validate_quote(quote("1.00000", "1.00010", 2))
+            .is_some());
+        assert!(validator
+            .validate_quote(quote("0.00000", "1.00010", 3))
+            .is_none());
+        assert!(validator
+            .validate_quote(quote("1.00010", "1.00000", 3))
+            .is_none());
+        assert!(validator
+            .validate_quote(quote("1.00000", "1.00010", 1))
+            .is_none());
+
+        let counters = validator.counters(&instrument_id).unwrap();
+        assert_eq!(counters.non_positive_prices, 1);
+        assert_eq!(counters.crossed_quotes, 1);
+        assert_eq!(counters.ts_regressions, 1);
+        assert_eq!(counters.rejected, 3);
+        assert_eq!(counters.repaired, 0);
+    }
+
+    #[rstest]
+    fn test_repairs_quotes() {
+        let mut validator = validator(DataValidationPolicy {
+            non_positive_price: ValidationAction::Repair,
+            crossed_quote: ValidationAction::Repair,
+            ts_regression: ValidationAction::Repair,
+            ts_tolerance_ns: 0,
+        });
+
+        validator.validate_quote(quote("1.00000", "1.00010", 2));
+        let repaired_price = validator
+            .validate_quote(quote("-1.00000", "1.00020", 3))
+            .unwrap();
+        let repaired_crossed = validator
+            .validate_quote(quote("1.00030", "1.00020", 1))
+            .unwrap();
+
+        assert_eq!(repaired_price.bid_price, Price::from("1.00000"));
+        assert_eq!(repaired_price.ask_price, Price::from("1.00020"));
+        assert_eq!(repaired_crossed.bid_price, Price::from("1.00020"));
+        assert_eq!(repaired_crossed.ask_price, Price::from("1.00030"));
+        assert_eq!(repaired_crossed.bid_size, Quantity::from(2));
+        assert_eq!(repaired_crossed.ts_event, UnixNanos::from(3));
+        assert_eq!(
+            validator
+                .counters(&repaired_price.instrument_id)
+                .unwrap()
+                .repaired,
+            2
+        );
+    }
+
+    #[rstest]
+    fn test_ts_regression_within_tolerance_passes() {
+        let mut validator = validator(DataValidationPolicy {
+            ts_tolerance_ns: 5,
+            ..Default::default()
+        });
+
+        validator.validate_trade(trade("1.00000", 10));
+
+        assert!(validator.validate_trade(trade("1.00000", 5)).is_some());
+        assert!(validator.validate_trade(trade("1.00000", 7)).is_some());
+        assert!(validator.validate_trade(trade("1.00000", 4)).is_none()); // Beyond 5ns of latest
+    }
+
+    #[rstest]
+    fn test_per_instrument_policy_overrides_default() {
+        let mut validator = validator(DataValidationPolicy::default());
+        let instrument_id = InstrumentId::from("AUD/USD.SIM");
+        validator.set_policy(
+            instrument_id,
+            DataValidationPolicy {
+                non_positive_price: ValidationAction::Allow,
+                ..Default::default()
+            },
+        );
+
+        assert!(validator.validate_trade(trade("0.00000", 1)).is_some());
+        assert_eq!(
+            validator
+                .counters(&instrument_id)
+                .unwrap()
+                .non_positive_prices,
+            1
+        );
+    }
+}
@@ -39,6 +39,12 @@ pub struct MockDataClient {
     msgbus: Rc<RefCell<MessageBus>>,
     pub client_id: ClientId,
     pub venue: Venue,
+    /// The historical quotes returned by quote requests.
+    pub quotes: Vec<QuoteTick>,
+    /// The historical trades returned by trade requests.
+    pub trades: Vec<TradeTick>,
+    /// The historical bars returned by bar requests.
+    pub bars: Vec<Bar>,
 }
 
 impl MockDataClient {
@@ -54,10 +60,18 @@ impl MockDataClient {
             msgbus,
             client_id,
             venue,
+            quotes: Vec::new(),
+            trades: Vec::new(),
+            bars: Vec::new(),
         }
     }
 }
 
+/// Returns whether `ts_event` is within the optional `start` and `end` of a request.
+fn is_in_range(ts_event: UnixNanos, start: Option<UnixNanos>, end: Option<UnixNanos>) -> bool {
+    start.is_none_or(|start| ts_event >= start) && end.is_none_or(|end| ts_event <= end)
+}
+
 impl DataClient for MockDataClient {
     fn client_id(&self) -> ClientId {
         self.client_id
@@ -292,7 +306,12 @@ impl DataClient for MockDataClient {
         limit: Option<usize>,
         params: &Option<HashMap<String, String>>,
     ) -> Vec<QuoteTick> {
-        todo!()
+        self.quotes
+            .iter()
+            .filter(|quote| quote.instrument_id == instrument_id)
+            .filter(|quote| is_in_range(quote.ts_event, start, end))
+            .copied()
+            .collect()
     }
 
     fn request_trade_ticks(
@@ -304,7 +323,12 @@ impl DataClient for MockDataClient {
         limit: Option<usize>,
         params: &Option<HashMap<String, String>>,
     ) -> Vec<TradeTick> {
-        todo!()
+        self.trades
+            .iter()
+            .filter(|trade| trade.instrument_id == instrument_id)
+            .filter(|trade| is_in_range(trade.ts_event, start, end))
+            .copied()
+            .collect()
     }
 
     fn request_bars(
@@ -316,6 +340,11 @@ impl DataClient for MockDataClient {
         limit: Option<usize>,
         params: &Option<HashMap<String, String>>,
     ) -> Vec<Bar> {
-        todo!()
+        self.bars
+            .iter()
+            .filter(|bar| bar.bar_type == bar_type)
+            .filter(|bar| is_in_range(bar.ts_event, start, end))
+            .copied()
+            .collect()
     }
 }
@@ -0,0 +1,403 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{cell::RefCell, collections::HashSet, rc::Rc};
+
+use indexmap::IndexMap;
+use nautilus_common::{cache::Cache, clock::Clock, timer::TimeEventCallback};
+use nautilus_core::{UnixNanos, UUID4};
+use nautilus_model::{
+    enums::TimeInForce,
+    identifiers::{ClientId, ClientOrderId, ExecAlgorithmId, InstrumentId},
+    orders::{LimitOrder, MarketOrder, OrderAny},
+    types::{Price, Quantity},
+};
+
+use crate::messages::{cancel::CancelOrder, submit::SubmitOrder, TradingCommand};
+
+/// A primary order being executed by an algorithm.
+#[derive(Clone, Debug)]
+pub struct PrimaryOrder {
+    /// The command the primary order was submitted with.
+    pub command: SubmitOrder,
+    /// The total quantity of the child orders spawned, less any quantity released by
+    /// child orders which closed without being filled.
+    pub spawned_qty: Quantity,
+    spawn_sequence: usize,
+    closed_spawns: HashSet<ClientOrderId>,
+}
+
+/// Provides the services an [`super::ExecAlgorithm`] executes primary orders through.
+///
+/// Commands and subscriptions are queued by the context, and sent by the scheduler once
+/// the algorithm callback has returned.
+pub struct ExecAlgorithmContext {
+    id: ExecAlgorithmId,
+    clock: Rc<RefCell<dyn Clock>>,
+    cache: Rc<RefCell<Cache>>,
+    primaries: IndexMap<ClientOrderId, PrimaryOrder>,
+    timer_callback: Option<TimeEventCallback>,
+    pub(crate) commands: Vec<TradingCommand>,
+    pub(crate) quote_subscriptions: Vec<(InstrumentId, ClientId)>,
+    pub(crate) trade_subscriptions: Vec<(InstrumentId, ClientId)>,
+}
+
+impl ExecAlgorithmContext {
+    /// Creates a new [`ExecAlgorithmContext`] instance for the algorithm `id`.
+    pub fn new(
+        id: ExecAlgorithmId,
+        clock: Rc<RefCell<dyn Clock>>,
+        cache: Rc<RefCell<Cache>>,
+    ) -> Self {
+        Self {
+            id,
+            clock,
+            cache,
+            primaries: IndexMap::new(),
+            timer_callback: None,
+            commands: Vec::new(),
+            quote_subscriptions: Vec::new(),
+            trade_subscriptions: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub const fn id(&self) -> ExecAlgorithmId {
+        self.id
+    }
+
+    #[must_use]
+    pub fn timestamp_ns(&self) -> UnixNanos {
+        self.clock.borrow().timestamp_ns()
+    }
+
+    #[must_use]
+    pub fn cache(&self) -> Rc<RefCell<Cache>> {
+        self.cache.clone()
+    }
+
+    /// Returns the primary order `primary_id`, if being executed.
+    #[must_use]
+    pub fn primary(&self, primary_id: &ClientOrderId) -> Option<&OrderAny> {
+        self.primaries
+            .get(primary_id)
+            .map(|primary| &primary.command.order)
+    }
+
+    /// Returns the IDs of the primary orders being executed, in submission order.
+    #[must_use]
+    pub fn primary_ids(&self) -> Vec<ClientOrderId> {
+        self.primaries.keys().copied().collect()
+    }
+
+    /// Returns the quantity of the primary order `primary_id` not yet spawned as child orders.
+    #[must_use]
+    pub fn remaining_qty(&self, primary_id: &ClientOrderId) -> Option<Quantity> {
+        self.primaries
+            .get(primary_id)
+            .map(|primary| primary.command.order.quantity() - primary.spawned_qty)
+    }
+
+    /// Returns the total quantity filled by the child orders of the primary order `primary_id`.
+    #[must_use]
+    pub fn filled_qty(&self, primary_id: &ClientOrderId) -> Quantity {
+        self.cache
+            .borrow()
+            .exec_spawn_total_filled_qty(primary_id, false)
+            .unwrap_or_else(|| {
+                let precision = self
+                    .primary(primary_id)
+                    .map_or(0, |order| order.quantity().precision);
+                Quantity::zero(precision)
+            })
+    }
+
+    pub(crate) fn add_primary(&mut self, command: SubmitOrder) {
+        let spawned_qty = Quantity::zero(command.order.quantity().precision);
+        self.primaries.insert(
+            command.client_order_id,
+            PrimaryOrder {
+                command,
+                spawned_qty,
+                spawn_sequence: 0,
+                closed_spawns: HashSet::new(),
+            },
+        );
+    }
+
+    /// Releases the unfilled quantity of the closed child `order` of `primary_id`, so it can
+    /// be spawned again.
+    pub(crate) fn release(&mut self, primary_id: &ClientOrderId, order: &OrderAny) {
+        if let Some(primary) = self.primaries.get_mut(primary_id) {
+            if primary.closed_spawns.insert(order.client_order_id()) {
+                unspawn(primary, order.leaves_qty());
+            }
+        }
+    }
+
+    pub(crate) fn set_timer_callback(&mut self, callback: TimeEventCallback) {
+        self.timer_callback = Some(callback);
+    }
+
+    /// Completes the execution of the primary order `primary_id`, no further child orders can
+    /// be spawned from it.
+    pub fn complete(&mut self, primary_id: &ClientOrderId) -> Option<PrimaryOrder> {
+        let primary = self.primaries.shift_remove(primary_id)?;
+        log::info!("Completed execution of {primary_id}");
+        Some(primary)
+    }
+
+    /// Spawns a market child order for `quantity` of the primary order `primary_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the primary order is not being executed, or `quantity` exceeds
+    /// its remaining quantity.
+    pub fn spawn_market(
+        &mut self,
+        primary_id: &ClientOrderId,
+        quantity: Quantity,
+        time_in_force: TimeInForce,
+    ) -> anyhow::Result<OrderAny> {
+        let (client_order_id, primary, ts_init) = self.next_spawn(primary_id, quantity)?;
+
+        Ok(OrderAny::Market(MarketOrder::new(
+            primary.trader_id(),
+            primary.strategy_id(),
+            primary.instrument_id(),
+            client_order_id,
+            primary.order_side(),
+            quantity,
+            time_in_force,
+            UUID4::new(),
+            ts_init,
+            primary.is_reduce_only(),
+            false,
+            None,
+            None,
+            None,
+            None,
+            Some(self.id),
+            primary.exec_algorithm_params(),
+            Some(*primary_id),
+            primary.tags(),
+        )))
+    }
+
+    /// Spawns a limit child order for `quantity` of the primary order `primary_id` at `price`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the primary order is not being executed, `quantity` exceeds its
+    /// remaining quantity, or the order is invalid.
+    pub fn spawn_limit(
+        &mut self,
+        primary_id: &ClientOrderId,
+        quantity: Quantity,
+        price: Price,
+        time_in_force: TimeInForce,
+    ) -> anyhow::Result<OrderAny> {
+        let (client_order_id, primary, ts_init) = self.next_spawn(primary_id, quantity)?;
+
+        let order = LimitOrder::new(
+            primary.trader_id(),
+            primary.strategy_id(),
+            primary.instrument_id(),
+            client_order_id,
+            primary.order_side(),
+            quantity,
+            price,
+            None,
+            None,
+            time_in_force,
+            primary.expire_time(),
+            primary.is_post_only(),
+            primary.is_reduce_only(),
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(self.id),
+            primary.exec_algorithm_params(),
+            Some(*primary_id),
+            primary.tags(),
+            UUID4::new(),
+            ts_init,
+        );
+
+        if order.is_err() {
+            if let Some(primary) = self.primaries.get_mut(primary_id) {
+                unspawn(primary, quantity);
+            }
+        }
+
+        Ok(OrderAny::Limit(order?))
+    }
+
+    fn next_spawn(
+        &mut self,
+        primary_id: &ClientOrderId,
+        quantity: Quantity,
+    ) -> anyhow::Result<(ClientOrderId, OrderAny, UnixNanos)> {
+        let ts_init = self.timestamp_ns();
+        let primary = self
+            .primaries
+            .get_mut(primary_id)
+            .ok_or_else(|| anyhow::anyhow!("No primary order {primary_id} being executed"))?;
+
+        let remaining_qty = primary.command.order.quantity() - primary.spawned_qty;
+        if quantity > remaining_qty {
+            anyhow::bail!(
+                "Cannot spawn {quantity} from {primary_id}, exceeds remaining quantity {remaining_qty}"
+            );
+        }
+
+        primary.spawn_sequence += 1;
+        primary.spawned_qty += quantity;
+        let client_order_id =
+            ClientOrderId::new(format!("{primary_id}-E{}", primary.spawn_sequence));
+
+        Ok((client_order_id, primary.command.order.clone(), ts_init))
+    }
+
+    /// Submits the spawned `order`, adding it to the cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order was not spawned from a primary order being executed,
+    /// or cannot be added to the cache.
+    pub fn submit_order(&mut self, order: OrderAny) -> anyhow::Result<()> {
+        let primary = self.primary_command(&order)?.clone();
+
+        self.cache.borrow_mut().add_order(
+            order.clone(),
+            primary.position_id,
+            Some(primary.client_id),
+            false,
+        )?;
+
+        let command = SubmitOrder::new(
+            primary.trader_id,
+            primary.client_id,
+            primary.strategy_id,
+            primary.instrument_id,
+            order.client_order_id(),
+            primary.venue_order_id,
+            order,
+            Some(self.id),
+            primary.position_id,
+            UUID4::new(),
+            self.timestamp_ns(),
+        )?;
+        self.commands.push(TradingCommand::SubmitOrder(command));
+
+        Ok(())
+    }
+
+    /// Cancels the spawned `order`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order was not spawned from a primary order being executed.
+    pub fn cancel_order(&mut self, order: &OrderAny) -> anyhow::Result<()> {
+        let primary = self.primary_command(order)?;
+
+        let command = CancelOrder::new(
+            primary.trader_id,
+            primary.client_id,
+            primary.strategy_id,
+            order.instrument_id(),
+            order.client_order_id(),
+            order.venue_order_id().unwrap_or(primary.venue_order_id),
+            UUID4::new(),
+            self.timestamp_ns(),
+        )?;
+        self.commands.push(TradingCommand::CancelOrder(command));
+
+        Ok(())
+    }
+
+    fn primary_command(&self, order: &OrderAny) -> anyhow::Result<&SubmitOrder> {
+        order
+            .exec_spawn_id()
+            .and_then(|primary_id| self.primaries.get(&primary_id))
+            .map(|primary| &primary.command)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Order {} was not spawned from a primary order being executed",
+                    order.client_order_id()
+                )
+            })
+    }
+
+    /// Sets a timer `name` alerting every `interval_ns` from `start_time_ns` until
+    /// `stop_time_ns`, with its events handled by [`super::ExecAlgorithm::on_time_event`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the timer is invalid.
+    pub fn set_timer(
+        &mut self,
+        name: &str,
+        interval_ns: u64,
+        start_time_ns: UnixNanos,
+        stop_time_ns: Option<UnixNanos>,
+    ) -> anyhow::Result<()> {
+        let callback = self
+            .timer_callback
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("Algorithm {} is not registered", self.id))?;
+
+        self.clock.borrow_mut().set_timer_ns(
+            name,
+            interval_ns,
+            start_time_ns,
+            stop_time_ns,
+            Some(callback),
+        )
+    }
+
+    pub fn cancel_timer(&mut self, name: &str) {
+        self.clock.borrow_mut().cancel_timer(name);
+    }
+
+    /// Subscribes to quotes for the instrument of the primary order `primary_id`, handled by
+    /// [`super::ExecAlgorithm::on_quote`].
+    pub fn subscribe_quotes(&mut self, primary_id: &ClientOrderId) {
+        if let Some(primary) = self.primaries.get(primary_id) {
+            let subscription = (primary.command.instrument_id, primary.command.client_id);
+            self.quote_subscriptions.push(subscription);
+        }
+    }
+
+    /// Subscribes to trades for the instrument of the primary order `primary_id`, handled by
+    /// [`super::ExecAlgorithm::on_trade`].
+    pub fn subscribe_trades(&mut self, primary_id: &ClientOrderId) {
+        if let Some(primary) = self.primaries.get(primary_id) {
+            let subscription = (primary.command.instrument_id, primary.command.client_id);
+            self.trade_subscriptions.push(subscription);
+        }
+    }
+}
+
+fn unspawn(primary: &mut PrimaryOrder, quantity: Quantity) {
+    if quantity <= primary.spawned_qty {
+        primary.spawned_qty -= quantity;
+    }
+}
@@ -0,0 +1,135 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{any::Any, cell::RefCell, rc::Weak};
+
+use nautilus_common::{messages::data::DataResponse, msgbus::handler::MessageHandler};
+use nautilus_model::{
+    data::{Data, QuoteTick, TradeTick},
+    events::{OrderDenied, OrderEventAny},
+    orders::OrderAny,
+};
+use ustr::Ustr;
+
+use super::scheduler::ExecAlgorithmScheduler;
+use crate::messages::TradingCommand;
+
+pub struct ExecAlgorithmExecuteHandler {
+    pub id: Ustr,
+    pub scheduler: Weak<RefCell<ExecAlgorithmScheduler>>,
+}
+
+impl MessageHandler for ExecAlgorithmExecuteHandler {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, msg: &dyn Any) {
+        let Some(scheduler) = self.scheduler.upgrade() else {
+            return;
+        };
+
+        if let Some(command) = msg.downcast_ref::<TradingCommand>() {
+            ExecAlgorithmScheduler::execute(&scheduler, command.clone());
+        } else {
+            log::error!("Invalid message type received: {msg:?}");
+        }
+    }
+    fn handle_response(&self, _resp: DataResponse) {}
+    fn handle_data(&self, _data: Data) {}
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+pub struct ExecAlgorithmOnEventHandler {
+    pub id: Ustr,
+    pub scheduler: Weak<RefCell<ExecAlgorithmScheduler>>,
+}
+
+impl MessageHandler for ExecAlgorithmOnEventHandler {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, msg: &dyn Any) {
+        let Some(scheduler) = self.scheduler.upgrade() else {
+            return;
+        };
+
+        // The execution engine publishes orders with the event applied, while denials
+        // and events from the emulator are published as the events themselves
+        if let Some(order) = msg.downcast_ref::<OrderAny>() {
+            ExecAlgorithmScheduler::handle_order_event(&scheduler, order);
+        } else if let Some(event) = msg.downcast_ref::<OrderEventAny>() {
+            ExecAlgorithmScheduler::handle_order_event_for(&scheduler, &event.client_order_id());
+        } else if let Some(denied) = msg.downcast_ref::<OrderDenied>() {
+            ExecAlgorithmScheduler::handle_order_event_for(&scheduler, &denied.client_order_id);
+        }
+    }
+    fn handle_response(&self, _resp: DataResponse) {}
+    fn handle_data(&self, _data: Data) {}
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+pub struct ExecAlgorithmQuoteHandler {
+    pub id: Ustr,
+    pub scheduler: Weak<RefCell<ExecAlgorithmScheduler>>,
+}
+
+impl MessageHandler for ExecAlgorithmQuoteHandler {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, msg: &dyn Any) {
+        if let (Some(scheduler), Some(quote)) =
+            (self.scheduler.upgrade(), msg.downcast_ref::<QuoteTick>())
+        {
+            ExecAlgorithmScheduler::handle_quote(&scheduler, quote);
+        }
+    }
+    fn handle_response(&self, _resp: DataResponse) {}
+    fn handle_data(&self, _data: Data) {}
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+pub struct ExecAlgorithmTradeHandler {
+    pub id: Ustr,
+    pub scheduler: Weak<RefCell<ExecAlgorithmScheduler>>,
+}
+
+impl MessageHandler for ExecAlgorithmTradeHandler {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, msg: &dyn Any) {
+        if let (Some(scheduler), Some(trade)) =
+            (self.scheduler.upgrade(), msg.downcast_ref::<TradeTick>())
+        {
+            ExecAlgorithmScheduler::handle_trade(&scheduler, trade);
+        }
+    }
+    fn handle_response(&self, _resp: DataResponse) {}
+    fn handle_data(&self, _data: Data) {}
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
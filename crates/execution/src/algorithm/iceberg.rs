@@ -0,0 +1,127 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An iceberg execution algorithm.
+
+use nautilus_model::{
+    enums::{OrderStatus, OrderType},
+    identifiers::{ClientOrderId, ExecAlgorithmId},
+    orders::OrderAny,
+    types::Quantity,
+};
+
+use super::{get_required_param, spawn_slice, ExecAlgorithm, ExecAlgorithmContext, PrimaryStates};
+
+/// Executes limit primary orders by only showing a display quantity of the order at a time.
+///
+/// Parameters:
+/// - `display_qty`: The quantity of each visible slice.
+///
+/// A limit slice of the display quantity is spawned at the primary price, with the next slice
+/// spawned once the current slice is filled. Execution completes where a slice is canceled,
+/// rejected or expires.
+#[derive(Debug)]
+pub struct IcebergAlgorithm {
+    id: ExecAlgorithmId,
+    display_qtys: PrimaryStates<Quantity>,
+}
+
+impl IcebergAlgorithm {
+    /// Creates a new [`IcebergAlgorithm`] instance.
+    #[must_use]
+    pub fn new(id: ExecAlgorithmId) -> Self {
+        Self {
+            id,
+            display_qtys: PrimaryStates::new(),
+        }
+    }
+
+    fn spawn_next(
+        ctx: &mut ExecAlgorithmContext,
+        primary_id: &ClientOrderId,
+        display_qty: Quantity,
+    ) -> anyhow::Result<bool> {
+        let Some(remaining_qty) = ctx.remaining_qty(primary_id) else {
+            return Ok(false);
+        };
+        if remaining_qty.is_zero() {
+            return Ok(false);
+        }
+
+        spawn_slice(ctx, primary_id, display_qty.min(remaining_qty))?;
+        Ok(true)
+    }
+
+    fn complete(&mut self, ctx: &mut ExecAlgorithmContext, primary_id: &ClientOrderId) {
+        ctx.complete(primary_id);
+        self.display_qtys.remove(primary_id);
+    }
+}
+
+impl Default for IcebergAlgorithm {
+    /// Creates a new default [`IcebergAlgorithm`] instance.
+    fn default() -> Self {
+        Self::new(ExecAlgorithmId::new("ICEBERG"))
+    }
+}
+
+impl ExecAlgorithm for IcebergAlgorithm {
+    fn id(&self) -> ExecAlgorithmId {
+        self.id
+    }
+
+    fn on_order(&mut self, ctx: &mut ExecAlgorithmContext, order: &OrderAny) -> anyhow::Result<()> {
+        if order.order_type() != OrderType::Limit {
+            anyhow::bail!("Cannot execute {} orders, only LIMIT", order.order_type());
+        }
+
+        let display_qty: f64 = get_required_param(order, "display_qty")?;
+        let display_qty = Quantity::new(display_qty, order.quantity().precision);
+        if display_qty.is_zero() {
+            anyhow::bail!("Invalid `display_qty` {display_qty}, must be positive");
+        }
+
+        let primary_id = order.client_order_id();
+        Self::spawn_next(ctx, &primary_id, display_qty)?;
+        self.display_qtys.insert(primary_id, display_qty);
+
+        Ok(())
+    }
+
+    fn on_order_event(&mut self, ctx: &mut ExecAlgorithmContext, order: &OrderAny) {
+        let Some(primary_id) = order.exec_spawn_id() else {
+            return;
+        };
+        let Some(display_qty) = self.display_qtys.get(&primary_id).copied() else {
+            return;
+        };
+
+        match order.status() {
+            OrderStatus::Filled => match Self::spawn_next(ctx, &primary_id, display_qty) {
+                Ok(true) => {}
+                Ok(false) => self.complete(ctx, &primary_id),
+                Err(e) => {
+                    log::error!("Cannot spawn slice of {primary_id}: {e}");
+                    self.complete(ctx, &primary_id);
+                }
+            },
+            OrderStatus::Canceled
+            | OrderStatus::Rejected
+            | OrderStatus::Expired
+            | OrderStatus::Denied => self.complete(ctx, &primary_id),
+            _ => {}
+        }
+    }
+}
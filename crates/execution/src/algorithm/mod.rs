@@ -0,0 +1,172 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Execution algorithms which slice primary orders into spawned child orders.
+//!
+//! A primary order is routed to an algorithm by its `exec_algorithm_id`, and is executed
+//! entirely through child orders spawned from it, with the algorithm parameters taken from
+//! the `exec_algorithm_params` of the primary order.
+
+pub mod context;
+pub mod handlers;
+pub mod iceberg;
+pub mod pov;
+pub mod scheduler;
+pub mod twap;
+pub mod vwap;
+
+#[cfg(test)]
+mod tests;
+
+use std::{collections::HashMap, str::FromStr};
+
+use nautilus_common::timer::TimeEvent;
+use nautilus_model::{
+    data::{QuoteTick, TradeTick},
+    enums::OrderType,
+    identifiers::{ClientOrderId, ExecAlgorithmId},
+    orders::OrderAny,
+    types::Quantity,
+};
+use ustr::Ustr;
+
+pub use self::context::ExecAlgorithmContext;
+
+/// Represents an execution algorithm which slices primary orders into child orders.
+///
+/// The callbacks are driven by an [`scheduler::ExecAlgorithmScheduler`], which routes timer
+/// events, market data and the events of spawned orders to the algorithm.
+pub trait ExecAlgorithm {
+    /// Returns the ID primary orders are routed to the algorithm by.
+    fn id(&self) -> ExecAlgorithmId;
+
+    /// Handles a primary `order` submitted to the algorithm for execution.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order cannot be executed by the algorithm, such as for invalid
+    /// parameters, in which case no child orders should have been spawned.
+    fn on_order(&mut self, ctx: &mut ExecAlgorithmContext, order: &OrderAny) -> anyhow::Result<()>;
+
+    /// Handles a timer `event` of a timer set through the context.
+    fn on_time_event(&mut self, _ctx: &mut ExecAlgorithmContext, _event: &TimeEvent) {}
+
+    /// Handles a `quote` for an instrument subscribed to through the context.
+    fn on_quote(&mut self, _ctx: &mut ExecAlgorithmContext, _quote: &QuoteTick) {}
+
+    /// Handles a `trade` for an instrument subscribed to through the context.
+    fn on_trade(&mut self, _ctx: &mut ExecAlgorithmContext, _trade: &TradeTick) {}
+
+    /// Handles an event of a spawned `order`, after the event has been applied to it.
+    fn on_order_event(&mut self, _ctx: &mut ExecAlgorithmContext, _order: &OrderAny) {}
+}
+
+/// Returns the execution algorithm parameter `key` of the `order` parsed as `T`, if present.
+///
+/// # Errors
+///
+/// Returns an error if the parameter cannot be parsed.
+pub fn get_param<T: FromStr>(order: &OrderAny, key: &str) -> anyhow::Result<Option<T>>
+where
+    T::Err: std::fmt::Display,
+{
+    let Some(value) = order
+        .exec_algorithm_params()
+        .and_then(|params| params.get(&Ustr::from(key)).copied())
+    else {
+        return Ok(None);
+    };
+
+    value
+        .parse::<T>()
+        .map(Some)
+        .map_err(|e| anyhow::anyhow!("Invalid '{key}' parameter '{value}', {e}"))
+}
+
+/// Returns the required execution algorithm parameter `key` of the `order` parsed as `T`.
+///
+/// # Errors
+///
+/// Returns an error if the parameter is missing or cannot be parsed.
+pub fn get_required_param<T: FromStr>(order: &OrderAny, key: &str) -> anyhow::Result<T>
+where
+    T::Err: std::fmt::Display,
+{
+    get_param(order, key)?
+        .ok_or_else(|| anyhow::anyhow!("Missing '{key}' parameter for {}", order.client_order_id()))
+}
+
+/// Splits the `total` quantity into slices proportional to the `weights`.
+///
+/// Each slice is rounded down to the precision of the `total`, with any remainder added to
+/// the final slice, so the slices always sum to the `total`.
+#[must_use]
+pub fn slice_quantity(total: Quantity, weights: &[f64]) -> Vec<Quantity> {
+    let precision = total.precision;
+    let scale = 10_f64.powi(i32::from(precision));
+    let total_units = (total.as_f64() * scale).round() as u64;
+    let weight_sum: f64 = weights.iter().sum();
+
+    if weights.is_empty() || weight_sum <= 0.0 {
+        return vec![total];
+    }
+
+    let mut units: Vec<u64> = weights
+        .iter()
+        .map(|weight| (total_units as f64 * weight / weight_sum).floor() as u64)
+        .collect();
+    let allocated: u64 = units.iter().sum();
+    if let Some(last) = units.last_mut() {
+        *last += total_units.saturating_sub(allocated);
+    }
+
+    units
+        .into_iter()
+        .map(|units| Quantity::new(units as f64 / scale, precision))
+        .collect()
+}
+
+/// Spawns a child order for `quantity` of the primary order, of the same type as the primary,
+/// and submits it.
+///
+/// Market primaries spawn market orders, and limit primaries spawn limit orders at the price
+/// of the primary.
+///
+/// # Errors
+///
+/// Returns an error if the order cannot be spawned or submitted.
+pub fn spawn_slice(
+    ctx: &mut ExecAlgorithmContext,
+    primary_id: &ClientOrderId,
+    quantity: Quantity,
+) -> anyhow::Result<()> {
+    let primary = ctx
+        .primary(primary_id)
+        .ok_or_else(|| anyhow::anyhow!("No primary order {primary_id}"))?;
+    let time_in_force = primary.time_in_force();
+
+    let order = match (primary.order_type(), primary.price()) {
+        (OrderType::Market, _) => ctx.spawn_market(primary_id, quantity, time_in_force)?,
+        (OrderType::Limit, Some(price)) => {
+            ctx.spawn_limit(primary_id, quantity, price, time_in_force)?
+        }
+        (order_type, _) => anyhow::bail!("Cannot spawn slices of {order_type} orders"),
+    };
+
+    ctx.submit_order(order)
+}
+
+/// Tracks state of an algorithm per primary order.
+pub type PrimaryStates<T> = HashMap<ClientOrderId, T>;
@@ -0,0 +1,145 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A percentage of volume (POV) execution algorithm.
+
+use nautilus_model::{
+    data::TradeTick,
+    identifiers::{ClientOrderId, ExecAlgorithmId},
+    orders::OrderAny,
+    types::Quantity,
+};
+
+use super::{get_required_param, spawn_slice, ExecAlgorithm, ExecAlgorithmContext, PrimaryStates};
+
+#[derive(Clone, Debug)]
+struct PovState {
+    participation: f64,
+    market_volume: f64,
+}
+
+/// Executes primary orders as a target fraction of the volume traded in the market.
+///
+/// Parameters:
+/// - `participation`: The target fraction of market volume to trade, in the range (0, 1].
+///
+/// Each trade in the instrument after the primary order is received raises the target
+/// quantity, with a slice spawned for any shortfall between the target and the quantity
+/// spawned so far.
+#[derive(Debug)]
+pub struct PovAlgorithm {
+    id: ExecAlgorithmId,
+    states: PrimaryStates<PovState>,
+}
+
+impl PovAlgorithm {
+    /// Creates a new [`PovAlgorithm`] instance.
+    #[must_use]
+    pub fn new(id: ExecAlgorithmId) -> Self {
+        Self {
+            id,
+            states: PrimaryStates::new(),
+        }
+    }
+
+    fn spawn_shortfall(
+        ctx: &mut ExecAlgorithmContext,
+        primary_id: &ClientOrderId,
+        state: &PovState,
+    ) -> anyhow::Result<bool> {
+        let (Some(primary), Some(remaining_qty)) =
+            (ctx.primary(primary_id), ctx.remaining_qty(primary_id))
+        else {
+            return Ok(true);
+        };
+
+        let quantity = primary.quantity();
+        let spawned_qty = quantity - remaining_qty;
+        let precision = quantity.precision;
+        let scale = 10_f64.powi(i32::from(precision));
+        let target = (state.participation * state.market_volume * scale).floor() / scale;
+        let target_qty = Quantity::new(target.min(quantity.as_f64()), precision);
+
+        if target_qty > spawned_qty {
+            spawn_slice(ctx, primary_id, target_qty - spawned_qty)?;
+        }
+
+        Ok(target_qty >= quantity)
+    }
+}
+
+impl Default for PovAlgorithm {
+    /// Creates a new default [`PovAlgorithm`] instance.
+    fn default() -> Self {
+        Self::new(ExecAlgorithmId::new("POV"))
+    }
+}
+
+impl ExecAlgorithm for PovAlgorithm {
+    fn id(&self) -> ExecAlgorithmId {
+        self.id
+    }
+
+    fn on_order(&mut self, ctx: &mut ExecAlgorithmContext, order: &OrderAny) -> anyhow::Result<()> {
+        let participation: f64 = get_required_param(order, "participation")?;
+        if !(participation > 0.0 && participation <= 1.0) {
+            anyhow::bail!("Invalid `participation` {participation}, must be in the range (0, 1]");
+        }
+
+        let primary_id = order.client_order_id();
+        ctx.subscribe_trades(&primary_id);
+        self.states.insert(
+            primary_id,
+            PovState {
+                participation,
+                market_volume: 0.0,
+            },
+        );
+
+        Ok(())
+    }
+
+    fn on_trade(&mut self, ctx: &mut ExecAlgorithmContext, trade: &TradeTick) {
+        let primary_ids: Vec<ClientOrderId> = self
+            .states
+            .keys()
+            .filter(|primary_id| {
+                ctx.primary(primary_id)
+                    .is_some_and(|order| order.instrument_id() == trade.instrument_id)
+            })
+            .copied()
+            .collect();
+
+        for primary_id in primary_ids {
+            let Some(state) = self.states.get_mut(&primary_id) else {
+                continue;
+            };
+            state.market_volume += trade.size.as_f64();
+
+            let completed = match Self::spawn_shortfall(ctx, &primary_id, state) {
+                Ok(completed) => completed,
+                Err(e) => {
+                    log::error!("Cannot spawn slice of {primary_id}: {e}");
+                    true
+                }
+            };
+
+            if completed {
+                ctx.complete(&primary_id);
+                self.states.remove(&primary_id);
+            }
+        }
+    }
+}
@@ -0,0 +1,492 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Hosts execution algorithms, routing primary orders, timer events, market data and order
+//! events to them and sending the commands they queue.
+//!
+//! Callbacks run with the scheduler borrowed, so the commands and subscriptions queued by an
+//! algorithm are only sent once the callback has returned and the borrow is released. Where
+//! the message bus is borrowed by the publisher of the event being handled, the remaining
+//! actions are deferred to a time alert rather than sent reentrantly.
+
+use std::{
+    any::Any,
+    cell::RefCell,
+    collections::{HashSet, VecDeque},
+    rc::{Rc, Weak},
+};
+
+use indexmap::{IndexMap, IndexSet};
+use nautilus_common::{
+    cache::Cache,
+    clock::Clock,
+    messages::data::{Action, SubscriptionCommand},
+    msgbus::{handler::ShareableMessageHandler, MessageBus},
+    timer::{TimeEvent, TimeEventCallback},
+};
+use nautilus_core::UUID4;
+use nautilus_model::{
+    data::{DataType, QuoteTick, TradeTick},
+    identifiers::{ClientId, ClientOrderId, ExecAlgorithmId, InstrumentId, StrategyId},
+    orders::OrderAny,
+};
+use ustr::Ustr;
+
+use super::{
+    context::ExecAlgorithmContext,
+    handlers::{
+        ExecAlgorithmExecuteHandler, ExecAlgorithmOnEventHandler, ExecAlgorithmQuoteHandler,
+        ExecAlgorithmTradeHandler,
+    },
+    ExecAlgorithm,
+};
+use crate::messages::TradingCommand;
+
+const FLUSH_ALERT_NAME: &str = "ExecAlgorithmScheduler.flush";
+const RISK_ENGINE_EXECUTE: &str = "RiskEngine.execute";
+
+struct AlgorithmEntry {
+    algorithm: Box<dyn ExecAlgorithm>,
+    ctx: ExecAlgorithmContext,
+    quote_instruments: HashSet<InstrumentId>,
+    trade_instruments: HashSet<InstrumentId>,
+}
+
+#[allow(clippy::large_enum_variant)]
+enum PendingMessage {
+    Trading(TradingCommand),
+    Data(SubscriptionCommand),
+}
+
+#[allow(clippy::large_enum_variant)]
+enum PendingAction {
+    Send(Ustr, PendingMessage),
+    Subscribe(Ustr, ShareableMessageHandler),
+}
+
+/// Hosts [`ExecAlgorithm`] implementations, executing the primary orders sent to the
+/// `{exec_algorithm_id}.execute` endpoint of each registered algorithm.
+pub struct ExecAlgorithmScheduler {
+    clock: Rc<RefCell<dyn Clock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    algorithms: IndexMap<ExecAlgorithmId, AlgorithmEntry>,
+    subscribed_quotes: IndexSet<InstrumentId>,
+    subscribed_trades: IndexSet<InstrumentId>,
+    subscribed_strategies: IndexSet<StrategyId>,
+    pending: VecDeque<PendingAction>,
+    data_engine_execute: Ustr,
+    weak_self: Weak<RefCell<Self>>,
+}
+
+impl ExecAlgorithmScheduler {
+    /// Creates a new shared [`ExecAlgorithmScheduler`] instance.
+    pub fn new(
+        clock: Rc<RefCell<dyn Clock>>,
+        cache: Rc<RefCell<Cache>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+    ) -> Rc<RefCell<Self>> {
+        let data_engine_execute = msgbus.borrow().switchboard.data_engine_execute;
+
+        Rc::new_cyclic(|weak_self| {
+            RefCell::new(Self {
+                clock,
+                cache,
+                msgbus,
+                algorithms: IndexMap::new(),
+                subscribed_quotes: IndexSet::new(),
+                subscribed_trades: IndexSet::new(),
+                subscribed_strategies: IndexSet::new(),
+                pending: VecDeque::new(),
+                data_engine_execute,
+                weak_self: weak_self.clone(),
+            })
+        })
+    }
+
+    /// Registers the `algorithm`, with primary orders sent to its `{id}.execute` endpoint.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an algorithm with the same ID is already registered.
+    pub fn register_algorithm(
+        scheduler: &Rc<RefCell<Self>>,
+        algorithm: Box<dyn ExecAlgorithm>,
+    ) -> anyhow::Result<()> {
+        let algorithm_id = algorithm.id();
+        let mut this = scheduler.borrow_mut();
+
+        if this.algorithms.contains_key(&algorithm_id) {
+            anyhow::bail!("Algorithm {algorithm_id} already registered");
+        }
+
+        let mut ctx =
+            ExecAlgorithmContext::new(algorithm_id, this.clock.clone(), this.cache.clone());
+        let weak_self = this.weak_self.clone();
+        ctx.set_timer_callback(TimeEventCallback::Rust(Rc::new(move |event: TimeEvent| {
+            if let Some(scheduler) = weak_self.upgrade() {
+                Self::handle_time_event(&scheduler, &algorithm_id, &event);
+            }
+        })));
+
+        let endpoint = format!("{algorithm_id}.execute");
+        let handler = ShareableMessageHandler(Rc::new(ExecAlgorithmExecuteHandler {
+            id: Ustr::from(&endpoint),
+            scheduler: this.weak_self.clone(),
+        }));
+        this.msgbus.borrow_mut().register(&endpoint, handler);
+
+        this.algorithms.insert(
+            algorithm_id,
+            AlgorithmEntry {
+                algorithm,
+                ctx,
+                quote_instruments: HashSet::new(),
+                trade_instruments: HashSet::new(),
+            },
+        );
+        log::info!("Registered {algorithm_id}");
+
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn algorithm_ids(&self) -> Vec<ExecAlgorithmId> {
+        self.algorithms.keys().copied().collect()
+    }
+
+    /// Returns the IDs of the primary orders being executed by the algorithm `algorithm_id`.
+    #[must_use]
+    pub fn primary_ids(&self, algorithm_id: &ExecAlgorithmId) -> Vec<ClientOrderId> {
+        self.algorithms
+            .get(algorithm_id)
+            .map(|entry| entry.ctx.primary_ids())
+            .unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn subscribed_quotes(&self) -> Vec<InstrumentId> {
+        self.subscribed_quotes.iter().copied().collect()
+    }
+
+    #[must_use]
+    pub fn subscribed_trades(&self) -> Vec<InstrumentId> {
+        self.subscribed_trades.iter().copied().collect()
+    }
+
+    /// Executes the primary order of a `SubmitOrder` command with its algorithm.
+    pub fn execute(scheduler: &Rc<RefCell<Self>>, command: TradingCommand) {
+        let TradingCommand::SubmitOrder(command) = command else {
+            log::error!("Cannot handle command: {command}, only `SubmitOrder` is supported");
+            return;
+        };
+
+        let Some(algorithm_id) = command
+            .exec_algorith_id
+            .or_else(|| command.order.exec_algorithm_id())
+        else {
+            log::error!(
+                "Cannot execute {}, no `exec_algorithm_id`",
+                command.client_order_id
+            );
+            return;
+        };
+
+        let primary_id = command.client_order_id;
+        let strategy_id = command.strategy_id;
+        let order = command.order.clone();
+
+        Self::dispatch(scheduler, &algorithm_id, |this| {
+            this.subscribe_order_events(strategy_id);
+            let entry = this.algorithms.get_mut(&algorithm_id)?;
+            entry.ctx.add_primary(command);
+
+            if let Err(e) = entry.algorithm.on_order(&mut entry.ctx, &order) {
+                log::error!("{algorithm_id} cannot execute {primary_id}: {e}");
+                entry.ctx.complete(&primary_id);
+            }
+            Some(())
+        });
+    }
+
+    /// Handles the `quote` with the algorithms subscribed to its instrument.
+    pub fn handle_quote(scheduler: &Rc<RefCell<Self>>, quote: &QuoteTick) {
+        let algorithm_ids: Vec<ExecAlgorithmId> = scheduler
+            .borrow()
+            .algorithms
+            .iter()
+            .filter(|(_, entry)| entry.quote_instruments.contains(&quote.instrument_id))
+            .map(|(algorithm_id, _)| *algorithm_id)
+            .collect();
+
+        for algorithm_id in algorithm_ids {
+            Self::dispatch(scheduler, &algorithm_id, |this| {
+                let entry = this.algorithms.get_mut(&algorithm_id)?;
+                entry.algorithm.on_quote(&mut entry.ctx, quote);
+                Some(())
+            });
+        }
+    }
+
+    /// Handles the `trade` with the algorithms subscribed to its instrument.
+    pub fn handle_trade(scheduler: &Rc<RefCell<Self>>, trade: &TradeTick) {
+        let algorithm_ids: Vec<ExecAlgorithmId> = scheduler
+            .borrow()
+            .algorithms
+            .iter()
+            .filter(|(_, entry)| entry.trade_instruments.contains(&trade.instrument_id))
+            .map(|(algorithm_id, _)| *algorithm_id)
+            .collect();
+
+        for algorithm_id in algorithm_ids {
+            Self::dispatch(scheduler, &algorithm_id, |this| {
+                let entry = this.algorithms.get_mut(&algorithm_id)?;
+                entry.algorithm.on_trade(&mut entry.ctx, trade);
+                Some(())
+            });
+        }
+    }
+
+    /// Handles an event applied to the `order`, where it was spawned by a registered algorithm.
+    pub fn handle_order_event(scheduler: &Rc<RefCell<Self>>, order: &OrderAny) {
+        let (Some(algorithm_id), Some(primary_id)) =
+            (order.exec_algorithm_id(), order.exec_spawn_id())
+        else {
+            return;
+        };
+
+        if primary_id == order.client_order_id() {
+            return; // Events for the primary order itself
+        }
+
+        Self::dispatch(scheduler, &algorithm_id, |this| {
+            let entry = this.algorithms.get_mut(&algorithm_id)?;
+            entry.ctx.primary(&primary_id)?;
+
+            if order.is_closed() {
+                entry.ctx.release(&primary_id, order);
+            }
+            entry.algorithm.on_order_event(&mut entry.ctx, order);
+            Some(())
+        });
+    }
+
+    /// Handles an event for the order `client_order_id`, with the order taken from the cache.
+    pub fn handle_order_event_for(scheduler: &Rc<RefCell<Self>>, client_order_id: &ClientOrderId) {
+        let cache = scheduler.borrow().cache.clone();
+        let order = cache.borrow().order(client_order_id).cloned();
+        if let Some(order) = order {
+            Self::handle_order_event(scheduler, &order);
+        }
+    }
+
+    fn handle_time_event(
+        scheduler: &Rc<RefCell<Self>>,
+        algorithm_id: &ExecAlgorithmId,
+        event: &TimeEvent,
+    ) {
+        Self::dispatch(scheduler, algorithm_id, |this| {
+            let entry = this.algorithms.get_mut(algorithm_id)?;
+            entry.algorithm.on_time_event(&mut entry.ctx, event);
+            Some(())
+        });
+    }
+
+    /// Calls `f` with the scheduler borrowed, then queues the actions of the algorithm
+    /// `algorithm_id` and sends them once the borrow is released.
+    fn dispatch(
+        scheduler: &Rc<RefCell<Self>>,
+        algorithm_id: &ExecAlgorithmId,
+        f: impl FnOnce(&mut Self) -> Option<()>,
+    ) {
+        {
+            let mut this = scheduler.borrow_mut();
+            if !this.algorithms.contains_key(algorithm_id) {
+                log::error!("Cannot find algorithm {algorithm_id}");
+                return;
+            }
+
+            f(&mut this);
+            this.queue_actions(algorithm_id);
+        }
+
+        Self::flush(scheduler);
+    }
+
+    fn subscribe_order_events(&mut self, strategy_id: StrategyId) {
+        if !self.subscribed_strategies.insert(strategy_id) {
+            return;
+        }
+
+        let topic = Ustr::from(&format!("events.order.{strategy_id}"));
+        let handler = ShareableMessageHandler(Rc::new(ExecAlgorithmOnEventHandler {
+            id: Ustr::from(&format!("ExecAlgorithmScheduler.on_event.{strategy_id}")),
+            scheduler: self.weak_self.clone(),
+        }));
+        self.pending
+            .push_back(PendingAction::Subscribe(topic, handler));
+    }
+
+    fn queue_actions(&mut self, algorithm_id: &ExecAlgorithmId) {
+        let Some(entry) = self.algorithms.get_mut(algorithm_id) else {
+            return;
+        };
+
+        let commands = std::mem::take(&mut entry.ctx.commands);
+        let quote_subscriptions = std::mem::take(&mut entry.ctx.quote_subscriptions);
+        let trade_subscriptions = std::mem::take(&mut entry.ctx.trade_subscriptions);
+
+        for (instrument_id, client_id) in quote_subscriptions {
+            if let Some(entry) = self.algorithms.get_mut(algorithm_id) {
+                entry.quote_instruments.insert(instrument_id);
+            }
+            if self.subscribed_quotes.insert(instrument_id) {
+                self.queue_subscription(stringify!(QuoteTick), instrument_id, client_id);
+            }
+        }
+
+        for (instrument_id, client_id) in trade_subscriptions {
+            if let Some(entry) = self.algorithms.get_mut(algorithm_id) {
+                entry.trade_instruments.insert(instrument_id);
+            }
+            if self.subscribed_trades.insert(instrument_id) {
+                self.queue_subscription(stringify!(TradeTick), instrument_id, client_id);
+            }
+        }
+
+        let endpoint = Ustr::from(RISK_ENGINE_EXECUTE);
+        for command in commands {
+            self.pending.push_back(PendingAction::Send(
+                endpoint,
+                PendingMessage::Trading(command),
+            ));
+        }
+    }
+
+    fn queue_subscription(
+        &mut self,
+        type_name: &str,
+        instrument_id: InstrumentId,
+        client_id: ClientId,
+    ) {
+        let (topic, handler) = match type_name {
+            stringify!(QuoteTick) => (
+                format!(
+                    "data.quotes.{}.{}",
+                    instrument_id.venue, instrument_id.symbol
+                ),
+                ShareableMessageHandler(Rc::new(ExecAlgorithmQuoteHandler {
+                    id: Ustr::from(&format!("ExecAlgorithmScheduler.quotes.{instrument_id}")),
+                    scheduler: self.weak_self.clone(),
+                })),
+            ),
+            _ => (
+                format!(
+                    "data.trades.{}.{}",
+                    instrument_id.venue, instrument_id.symbol
+                ),
+                ShareableMessageHandler(Rc::new(ExecAlgorithmTradeHandler {
+                    id: Ustr::from(&format!("ExecAlgorithmScheduler.trades.{instrument_id}")),
+                    scheduler: self.weak_self.clone(),
+                })),
+            ),
+        };
+        self.pending
+            .push_back(PendingAction::Subscribe(Ustr::from(&topic), handler));
+
+        let metadata = IndexMap::from([("instrument_id".to_string(), instrument_id.to_string())]);
+        let command = SubscriptionCommand::new(
+            client_id,
+            instrument_id.venue,
+            DataType::new(type_name, Some(metadata)),
+            Action::Subscribe,
+            UUID4::new(),
+            self.clock.borrow().timestamp_ns(),
+            None,
+        );
+        self.pending.push_back(PendingAction::Send(
+            self.data_engine_execute,
+            PendingMessage::Data(command),
+        ));
+    }
+
+    /// Sends the pending actions, deferring any remaining to a time alert where the message
+    /// bus is already borrowed.
+    fn flush(scheduler: &Rc<RefCell<Self>>) {
+        let msgbus = scheduler.borrow().msgbus.clone();
+
+        loop {
+            let Some(action) = scheduler.borrow_mut().pending.pop_front() else {
+                return;
+            };
+
+            match action {
+                PendingAction::Subscribe(topic, handler) => {
+                    let Ok(mut msgbus) = msgbus.try_borrow_mut() else {
+                        Self::defer(scheduler, PendingAction::Subscribe(topic, handler));
+                        return;
+                    };
+                    msgbus.subscribe(topic, handler, None);
+                }
+                PendingAction::Send(endpoint, message) => {
+                    // Release the message bus before handling, as the receiver may borrow it
+                    let Ok(handler) = msgbus
+                        .try_borrow()
+                        .map(|msgbus| msgbus.get_endpoint(endpoint).cloned())
+                    else {
+                        Self::defer(scheduler, PendingAction::Send(endpoint, message));
+                        return;
+                    };
+
+                    match (handler, &message) {
+                        (Some(handler), PendingMessage::Trading(command)) => {
+                            handler.0.handle(command as &dyn Any);
+                        }
+                        (Some(handler), PendingMessage::Data(command)) => {
+                            handler.0.handle(command as &dyn Any);
+                        }
+                        (None, _) => log::error!("No endpoint '{endpoint}' registered"),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the `action` to the front of the queue, to retry once the current handler
+    /// has returned and the message bus is released.
+    fn defer(scheduler: &Rc<RefCell<Self>>, action: PendingAction) {
+        let mut this = scheduler.borrow_mut();
+        this.pending.push_front(action);
+        this.schedule_flush();
+    }
+
+    fn schedule_flush(&self) {
+        let weak_self = self.weak_self.clone();
+        let callback = TimeEventCallback::Rust(Rc::new(move |_event: TimeEvent| {
+            if let Some(scheduler) = weak_self.upgrade() {
+                Self::flush(&scheduler);
+            }
+        }));
+
+        let Ok(mut clock) = self.clock.try_borrow_mut() else {
+            log::warn!("Cannot schedule flush of pending actions, clock is busy");
+            return;
+        };
+        let ts_now = clock.timestamp_ns();
+        if let Err(e) = clock.set_time_alert_ns(FLUSH_ALERT_NAME, ts_now, Some(callback)) {
+            log::error!("Cannot schedule flush of pending actions: {e}");
+        }
+    }
+}
@@ -0,0 +1,571 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{any::Any, cell::RefCell, rc::Rc};
+
+use indexmap::IndexMap;
+use nautilus_common::{
+    cache::Cache,
+    clock::{Clock, TestClock},
+    messages::data::SubscriptionCommand,
+    msgbus::{
+        handler::ShareableMessageHandler,
+        stubs::{get_message_saving_handler, get_saved_messages},
+        MessageBus,
+    },
+};
+use nautilus_core::{UnixNanos, UUID4};
+use nautilus_model::{
+    data::TradeTick,
+    enums::{AggressorSide, OrderType},
+    events::{OrderCanceled, OrderEventAny},
+    identifiers::{
+        AccountId, ClientId, ClientOrderId, ExecAlgorithmId, InstrumentId, TradeId, VenueOrderId,
+    },
+    instruments::{stubs::audusd_sim, InstrumentAny},
+    orders::{stubs::TestOrderEventStubs, OrderAny, OrderTestBuilder},
+    types::{Price, Quantity},
+};
+use rstest::rstest;
+use ustr::Ustr;
+
+use super::{
+    iceberg::IcebergAlgorithm, pov::PovAlgorithm, scheduler::ExecAlgorithmScheduler,
+    slice_quantity, twap::TwapAlgorithm, vwap::VwapAlgorithm, ExecAlgorithm,
+};
+use crate::messages::{submit::SubmitOrder, TradingCommand};
+
+const SECOND_NS: u64 = 1_000_000_000;
+
+struct TestHarness {
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    scheduler: Rc<RefCell<ExecAlgorithmScheduler>>,
+    risk_handler: ShareableMessageHandler,
+    data_handler: ShareableMessageHandler,
+}
+
+impl TestHarness {
+    fn new(algorithm: Box<dyn ExecAlgorithm>) -> Self {
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+
+        let risk_handler = get_message_saving_handler::<TradingCommand>(None);
+        let data_handler = get_message_saving_handler::<SubscriptionCommand>(None);
+        msgbus
+            .borrow_mut()
+            .register("RiskEngine.execute", risk_handler.clone());
+        msgbus
+            .borrow_mut()
+            .register("DataEngine.execute", data_handler.clone());
+
+        let scheduler = ExecAlgorithmScheduler::new(clock.clone(), cache.clone(), msgbus.clone());
+        ExecAlgorithmScheduler::register_algorithm(&scheduler, algorithm).unwrap();
+
+        Self {
+            clock,
+            cache,
+            msgbus,
+            scheduler,
+            risk_handler,
+            data_handler,
+        }
+    }
+
+    fn command(order: &OrderAny) -> SubmitOrder {
+        SubmitOrder::new(
+            order.trader_id(),
+            ClientId::from("SIM"),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            VenueOrderId::from("1"),
+            order.clone(),
+            order.exec_algorithm_id(),
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap()
+    }
+
+    fn submit(&self, order: &OrderAny) {
+        self.cache
+            .borrow_mut()
+            .add_order(order.clone(), None, None, false)
+            .unwrap();
+
+        // Sent as the order manager does, with the message bus borrowed, so the
+        // subscription to order events and all following actions are deferred
+        let endpoint = format!("{}.execute", order.exec_algorithm_id().unwrap());
+        self.msgbus.borrow().send(
+            &Ustr::from(&endpoint),
+            &TradingCommand::SubmitOrder(Self::command(order)) as &dyn Any,
+        );
+        self.flush_deferred();
+    }
+
+    fn advance_time(&self, to_time_ns: u64) {
+        let events = self
+            .clock
+            .borrow_mut()
+            .advance_time(UnixNanos::from(to_time_ns), true);
+        let handlers = self.clock.borrow().match_handlers(events);
+        for handler in handlers {
+            handler.run();
+        }
+    }
+
+    /// Runs actions deferred while the message bus was borrowed.
+    fn flush_deferred(&self) {
+        let ts_now = self.clock.borrow().timestamp_ns();
+        self.advance_time(ts_now.as_u64() + 1);
+    }
+
+    fn submitted_orders(&self) -> Vec<OrderAny> {
+        get_saved_messages::<TradingCommand>(self.risk_handler.clone())
+            .into_iter()
+            .filter_map(|command| match command {
+                TradingCommand::SubmitOrder(command) => Some(command.order),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn submitted_quantities(&self) -> Vec<Quantity> {
+        self.submitted_orders()
+            .iter()
+            .map(OrderAny::quantity)
+            .collect()
+    }
+
+    fn primary_ids(&self, algorithm_id: &str) -> Vec<ClientOrderId> {
+        self.scheduler
+            .borrow()
+            .primary_ids(&ExecAlgorithmId::from(algorithm_id))
+    }
+
+    /// Applies the `event` to the cached order, publishing it as the execution engine does.
+    fn apply_event(&self, event: OrderEventAny) {
+        let mut order = self
+            .cache
+            .borrow()
+            .order(&event.client_order_id())
+            .cloned()
+            .unwrap();
+        order.apply(event.clone()).unwrap();
+        self.cache.borrow_mut().update_order(&order).unwrap();
+
+        let mut msgbus = self.msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_event_orders_topic(event.strategy_id());
+        msgbus.publish(&topic, &order);
+    }
+
+    fn accept(&self, order: &OrderAny) {
+        let account_id = AccountId::from("SIM-001");
+        self.apply_event(TestOrderEventStubs::order_submitted(order, account_id));
+        self.apply_event(TestOrderEventStubs::order_accepted(
+            order,
+            account_id,
+            VenueOrderId::from("V-1"),
+        ));
+    }
+
+    fn fill(&self, order: &OrderAny) {
+        self.accept(order);
+        let order = self
+            .cache
+            .borrow()
+            .order(&order.client_order_id())
+            .cloned()
+            .unwrap();
+        self.apply_event(TestOrderEventStubs::order_filled(
+            &order,
+            &InstrumentAny::CurrencyPair(audusd_sim()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ));
+    }
+
+    fn cancel(&self, order: &OrderAny) {
+        self.accept(order);
+        let order = self
+            .cache
+            .borrow()
+            .order(&order.client_order_id())
+            .cloned()
+            .unwrap();
+        self.apply_event(OrderEventAny::Canceled(OrderCanceled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            false,
+            order.venue_order_id(),
+            order.account_id(),
+        )));
+    }
+}
+
+fn primary_order(
+    order_type: OrderType,
+    algorithm_id: &str,
+    quantity: &str,
+    params: &[(&str, &str)],
+) -> OrderAny {
+    let params: IndexMap<Ustr, Ustr> = params
+        .iter()
+        .map(|(key, value)| (Ustr::from(key), Ustr::from(value)))
+        .collect();
+
+    let mut builder = OrderTestBuilder::new(order_type);
+    builder
+        .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+        .client_order_id(ClientOrderId::from("O-001"))
+        .quantity(Quantity::from(quantity))
+        .exec_algorithm_id(ExecAlgorithmId::from(algorithm_id))
+        .exec_algorithm_params(params)
+        .exec_spawn_id(ClientOrderId::from("O-001"));
+    if order_type == OrderType::Limit {
+        builder.price(Price::from("1.00000"));
+    }
+    builder.build()
+}
+
+fn trade(size: &str) -> TradeTick {
+    TradeTick::new(
+        InstrumentId::from("AUD/USD.SIM"),
+        Price::from("1.00000"),
+        Quantity::from(size),
+        AggressorSide::Buyer,
+        TradeId::from("1"),
+        UnixNanos::default(),
+        UnixNanos::default(),
+    )
+}
+
+fn quantities(values: &[&str]) -> Vec<Quantity> {
+    values.iter().map(|value| Quantity::from(*value)).collect()
+}
+
+#[rstest]
+#[case("10", &[1.0, 1.0, 1.0], &["3", "3", "4"])]
+#[case("1.00", &[1.0, 2.0, 1.0], &["0.25", "0.50", "0.25"])]
+#[case("7", &[0.0, 1.0], &["0", "7"])]
+#[case("7", &[], &["7"])]
+fn test_slice_quantity(#[case] total: &str, #[case] weights: &[f64], #[case] expected: &[&str]) {
+    assert_eq!(
+        slice_quantity(Quantity::from(total), weights),
+        quantities(expected)
+    );
+}
+
+#[rstest]
+fn test_register_algorithm_registers_endpoint() {
+    let harness = TestHarness::new(Box::new(TwapAlgorithm::default()));
+
+    assert!(harness.msgbus.borrow().is_registered("TWAP.execute"));
+    assert_eq!(
+        harness.scheduler.borrow().algorithm_ids(),
+        vec![ExecAlgorithmId::from("TWAP")]
+    );
+}
+
+#[rstest]
+fn test_register_algorithm_twice_fails() {
+    let harness = TestHarness::new(Box::new(TwapAlgorithm::default()));
+
+    let result = ExecAlgorithmScheduler::register_algorithm(
+        &harness.scheduler,
+        Box::new(TwapAlgorithm::default()),
+    );
+
+    assert!(result.is_err());
+}
+
+#[rstest]
+fn test_execute_with_invalid_params_completes_primary() {
+    let harness = TestHarness::new(Box::new(TwapAlgorithm::default()));
+    let order = primary_order(OrderType::Market, "TWAP", "100", &[("horizon_secs", "x")]);
+
+    harness.submit(&order);
+
+    assert!(harness.submitted_orders().is_empty());
+    assert!(harness.primary_ids("TWAP").is_empty());
+}
+
+#[rstest]
+fn test_execute_sends_actions_when_message_bus_free() {
+    let harness = TestHarness::new(Box::new(TwapAlgorithm::default()));
+    let order = primary_order(OrderType::Market, "TWAP", "100", &[("horizon_secs", "0")]);
+
+    let command = TradingCommand::SubmitOrder(TestHarness::command(&order));
+    ExecAlgorithmScheduler::execute(&harness.scheduler, command);
+
+    assert_eq!(harness.submitted_quantities(), quantities(&["100"]));
+    assert!(harness
+        .msgbus
+        .borrow()
+        .topics()
+        .contains(&"events.order.S-001"));
+}
+
+#[rstest]
+fn test_actions_deferred_while_message_bus_borrowed() {
+    let harness = TestHarness::new(Box::new(TwapAlgorithm::default()));
+    let order = primary_order(OrderType::Market, "TWAP", "100", &[("horizon_secs", "0")]);
+
+    {
+        let _msgbus = harness.msgbus.borrow();
+        let command = TradingCommand::SubmitOrder(TestHarness::command(&order));
+        ExecAlgorithmScheduler::execute(&harness.scheduler, command);
+    }
+    assert!(harness.submitted_orders().is_empty());
+    assert!(harness.msgbus.borrow().topics().is_empty());
+
+    harness.flush_deferred();
+
+    assert_eq!(harness.submitted_quantities(), quantities(&["100"]));
+    assert!(harness
+        .msgbus
+        .borrow()
+        .topics()
+        .contains(&"events.order.S-001"));
+}
+
+#[rstest]
+fn test_twap_spawns_slices_on_timer() {
+    let harness = TestHarness::new(Box::new(TwapAlgorithm::default()));
+    let order = primary_order(
+        OrderType::Market,
+        "TWAP",
+        "100",
+        &[("horizon_secs", "3"), ("interval_secs", "1")],
+    );
+
+    harness.submit(&order);
+    assert_eq!(harness.submitted_quantities(), quantities(&["33"]));
+
+    harness.advance_time(SECOND_NS);
+    assert_eq!(harness.submitted_quantities(), quantities(&["33", "33"]));
+    assert_eq!(
+        harness.primary_ids("TWAP"),
+        vec![ClientOrderId::from("O-001")]
+    );
+
+    harness.advance_time(2 * SECOND_NS);
+    let orders = harness.submitted_orders();
+    assert_eq!(
+        harness.submitted_quantities(),
+        quantities(&["33", "33", "34"])
+    );
+    assert!(harness.primary_ids("TWAP").is_empty());
+
+    let client_order_ids: Vec<ClientOrderId> =
+        orders.iter().map(OrderAny::client_order_id).collect();
+    assert_eq!(
+        client_order_ids,
+        vec![
+            ClientOrderId::from("O-001-E1"),
+            ClientOrderId::from("O-001-E2"),
+            ClientOrderId::from("O-001-E3"),
+        ]
+    );
+    for spawned in &orders {
+        assert_eq!(spawned.order_type(), OrderType::Market);
+        assert_eq!(spawned.exec_spawn_id(), Some(order.client_order_id()));
+        assert_eq!(
+            spawned.exec_algorithm_id(),
+            Some(ExecAlgorithmId::from("TWAP"))
+        );
+        assert!(harness
+            .cache
+            .borrow()
+            .order(&spawned.client_order_id())
+            .is_some());
+    }
+
+    harness.advance_time(5 * SECOND_NS);
+    assert_eq!(harness.submitted_orders().len(), 3);
+}
+
+#[rstest]
+#[case(&[("horizon_secs", "1"), ("interval_secs", "2")])]
+#[case(&[("horizon_secs", "1"), ("interval_secs", "0")])]
+#[case(&[("horizon_secs", "1")])]
+#[case(&[("interval_secs", "1")])]
+fn test_twap_invalid_params(#[case] params: &[(&str, &str)]) {
+    let harness = TestHarness::new(Box::new(TwapAlgorithm::default()));
+
+    harness.submit(&primary_order(OrderType::Market, "TWAP", "100", params));
+
+    assert!(harness.submitted_orders().is_empty());
+    assert!(harness.primary_ids("TWAP").is_empty());
+}
+
+#[rstest]
+fn test_twap_limit_primary_spawns_limit_slices() {
+    let harness = TestHarness::new(Box::new(TwapAlgorithm::default()));
+    let order = primary_order(
+        OrderType::Limit,
+        "TWAP",
+        "100",
+        &[("horizon_secs", "2"), ("interval_secs", "1")],
+    );
+
+    harness.submit(&order);
+
+    let orders = harness.submitted_orders();
+    assert_eq!(orders.len(), 1);
+    assert_eq!(orders[0].order_type(), OrderType::Limit);
+    assert_eq!(orders[0].price(), Some(Price::from("1.00000")));
+    assert_eq!(orders[0].quantity(), Quantity::from("50"));
+}
+
+#[rstest]
+fn test_vwap_spawns_slices_by_volume_profile() {
+    let harness = TestHarness::new(Box::new(VwapAlgorithm::default()));
+    let order = primary_order(
+        OrderType::Market,
+        "VWAP",
+        "100",
+        &[("horizon_secs", "30"), ("volume_profile", "1,2,1")],
+    );
+
+    harness.submit(&order);
+    assert_eq!(harness.submitted_quantities(), quantities(&["25"]));
+
+    harness.advance_time(10 * SECOND_NS);
+    harness.advance_time(20 * SECOND_NS);
+
+    assert_eq!(
+        harness.submitted_quantities(),
+        quantities(&["25", "50", "25"])
+    );
+    assert!(harness.primary_ids("VWAP").is_empty());
+}
+
+#[rstest]
+fn test_pov_spawns_participation_of_trade_volume() {
+    let harness = TestHarness::new(Box::new(PovAlgorithm::default()));
+    let order = primary_order(OrderType::Market, "POV", "10", &[("participation", "0.1")]);
+
+    harness.submit(&order);
+
+    assert!(harness.submitted_orders().is_empty());
+    assert_eq!(
+        harness.scheduler.borrow().subscribed_trades(),
+        vec![InstrumentId::from("AUD/USD.SIM")]
+    );
+    let subscriptions = get_saved_messages::<SubscriptionCommand>(harness.data_handler.clone());
+    assert_eq!(subscriptions.len(), 1);
+    assert_eq!(
+        subscriptions[0].data_type.type_name(),
+        stringify!(TradeTick)
+    );
+
+    let topic = Ustr::from("data.trades.SIM.AUD/USD");
+    for size in ["50", "30", "100"] {
+        harness
+            .msgbus
+            .borrow()
+            .publish(&topic, &trade(size) as &dyn Any);
+    }
+
+    assert_eq!(harness.submitted_quantities(), quantities(&["5", "3", "2"]));
+    assert!(harness.primary_ids("POV").is_empty());
+}
+
+#[rstest]
+#[case("0")]
+#[case("1.5")]
+fn test_pov_invalid_participation(#[case] participation: &str) {
+    let harness = TestHarness::new(Box::new(PovAlgorithm::default()));
+
+    harness.submit(&primary_order(
+        OrderType::Market,
+        "POV",
+        "10",
+        &[("participation", participation)],
+    ));
+
+    assert!(harness.primary_ids("POV").is_empty());
+    assert!(harness.scheduler.borrow().subscribed_trades().is_empty());
+}
+
+#[rstest]
+fn test_iceberg_spawns_next_slice_when_filled() {
+    let harness = TestHarness::new(Box::new(IcebergAlgorithm::default()));
+    let order = primary_order(OrderType::Limit, "ICEBERG", "25", &[("display_qty", "10")]);
+
+    harness.submit(&order);
+    assert_eq!(harness.submitted_quantities(), quantities(&["10"]));
+
+    for expected in [&["10", "10"][..], &["10", "10", "5"][..]] {
+        let last = harness.submitted_orders().last().cloned().unwrap();
+        harness.fill(&last);
+        harness.flush_deferred();
+        assert_eq!(harness.submitted_quantities(), quantities(expected));
+    }
+
+    let last = harness.submitted_orders().last().cloned().unwrap();
+    harness.fill(&last);
+    harness.flush_deferred();
+
+    assert_eq!(harness.submitted_orders().len(), 3);
+    assert!(harness.primary_ids("ICEBERG").is_empty());
+}
+
+#[rstest]
+fn test_iceberg_completes_when_slice_canceled() {
+    let harness = TestHarness::new(Box::new(IcebergAlgorithm::default()));
+    let order = primary_order(OrderType::Limit, "ICEBERG", "25", &[("display_qty", "10")]);
+
+    harness.submit(&order);
+    let slice = harness.submitted_orders()[0].clone();
+    harness.cancel(&slice);
+    harness.flush_deferred();
+
+    assert_eq!(harness.submitted_orders().len(), 1);
+    assert!(harness.primary_ids("ICEBERG").is_empty());
+}
+
+#[rstest]
+fn test_iceberg_rejects_market_primary() {
+    let harness = TestHarness::new(Box::new(IcebergAlgorithm::default()));
+
+    harness.submit(&primary_order(
+        OrderType::Market,
+        "ICEBERG",
+        "25",
+        &[("display_qty", "10")],
+    ));
+
+    assert!(harness.submitted_orders().is_empty());
+    assert!(harness.primary_ids("ICEBERG").is_empty());
+}
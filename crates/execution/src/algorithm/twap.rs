@@ -0,0 +1,174 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A time-weighted average price (TWAP) execution algorithm.
+
+use std::collections::VecDeque;
+
+use nautilus_common::timer::TimeEvent;
+use nautilus_core::datetime::secs_to_nanos;
+use nautilus_model::{
+    identifiers::{ClientOrderId, ExecAlgorithmId},
+    orders::OrderAny,
+    types::Quantity,
+};
+
+use super::{
+    get_param, get_required_param, slice_quantity, spawn_slice, ExecAlgorithm,
+    ExecAlgorithmContext, PrimaryStates,
+};
+
+/// Slices of a primary order remaining to be spawned, one per timer interval.
+pub type SliceSchedule = VecDeque<Quantity>;
+
+/// Starts the `slices` schedule for the primary order `primary_id`, spawning the first slice
+/// immediately and the remaining slices every `interval_ns` on a timer named after the primary.
+///
+/// # Errors
+///
+/// Returns an error if the first slice cannot be spawned, or the timer cannot be set.
+pub fn start_schedule(
+    ctx: &mut ExecAlgorithmContext,
+    schedules: &mut PrimaryStates<SliceSchedule>,
+    primary_id: &ClientOrderId,
+    slices: Vec<Quantity>,
+    interval_ns: u64,
+) -> anyhow::Result<()> {
+    let mut schedule: SliceSchedule = slices.into();
+    spawn_next_slice(ctx, &mut schedule, primary_id)?;
+
+    if schedule.is_empty() {
+        ctx.complete(primary_id);
+        return Ok(());
+    }
+
+    let ts_now = ctx.timestamp_ns();
+    ctx.set_timer(primary_id.as_str(), interval_ns, ts_now, None)?;
+    schedules.insert(*primary_id, schedule);
+
+    Ok(())
+}
+
+/// Spawns the next slice of the schedule for the timer `event`, completing the primary order
+/// once all slices have been spawned.
+pub fn on_schedule_event(
+    ctx: &mut ExecAlgorithmContext,
+    schedules: &mut PrimaryStates<SliceSchedule>,
+    event: &TimeEvent,
+) {
+    let primary_id = ClientOrderId::new(event.name);
+    let Some(schedule) = schedules.get_mut(&primary_id) else {
+        return;
+    };
+
+    if let Err(e) = spawn_next_slice(ctx, schedule, &primary_id) {
+        log::error!("Cannot spawn slice of {primary_id}: {e}");
+        schedule.clear();
+    }
+
+    if schedule.is_empty() {
+        ctx.cancel_timer(primary_id.as_str());
+        ctx.complete(&primary_id);
+        schedules.remove(&primary_id);
+    }
+}
+
+fn spawn_next_slice(
+    ctx: &mut ExecAlgorithmContext,
+    schedule: &mut SliceSchedule,
+    primary_id: &ClientOrderId,
+) -> anyhow::Result<()> {
+    // Rounding can leave empty slices, which are skipped
+    while let Some(quantity) = schedule.pop_front() {
+        if !quantity.is_zero() {
+            return spawn_slice(ctx, primary_id, quantity);
+        }
+    }
+    Ok(())
+}
+
+/// Executes primary orders in equal slices spaced evenly over a time horizon.
+///
+/// Parameters:
+/// - `horizon_secs`: The time horizon to execute over, where zero executes immediately.
+/// - `interval_secs`: The interval between slices, required unless the horizon is zero.
+///
+/// The first slice is spawned immediately, followed by one slice per interval, for a total of
+/// `horizon_secs / interval_secs` (rounded down) slices.
+#[derive(Debug)]
+pub struct TwapAlgorithm {
+    id: ExecAlgorithmId,
+    schedules: PrimaryStates<SliceSchedule>,
+}
+
+impl TwapAlgorithm {
+    /// Creates a new [`TwapAlgorithm`] instance.
+    #[must_use]
+    pub fn new(id: ExecAlgorithmId) -> Self {
+        Self {
+            id,
+            schedules: PrimaryStates::new(),
+        }
+    }
+}
+
+impl Default for TwapAlgorithm {
+    /// Creates a new default [`TwapAlgorithm`] instance.
+    fn default() -> Self {
+        Self::new(ExecAlgorithmId::new("TWAP"))
+    }
+}
+
+impl ExecAlgorithm for TwapAlgorithm {
+    fn id(&self) -> ExecAlgorithmId {
+        self.id
+    }
+
+    fn on_order(&mut self, ctx: &mut ExecAlgorithmContext, order: &OrderAny) -> anyhow::Result<()> {
+        let horizon_secs: f64 = get_required_param(order, "horizon_secs")?;
+        let primary_id = order.client_order_id();
+
+        if horizon_secs <= 0.0 {
+            return start_schedule(
+                ctx,
+                &mut self.schedules,
+                &primary_id,
+                vec![order.quantity()],
+                0,
+            );
+        }
+
+        let interval_secs: f64 = get_param(order, "interval_secs")?.unwrap_or(0.0);
+        if interval_secs <= 0.0 || interval_secs > horizon_secs {
+            anyhow::bail!(
+                "Invalid `interval_secs` {interval_secs}, must be positive and not exceed `horizon_secs` {horizon_secs}"
+            );
+        }
+
+        let num_slices = (horizon_secs / interval_secs).floor() as usize;
+        let slices = slice_quantity(order.quantity(), &vec![1.0; num_slices]);
+        start_schedule(
+            ctx,
+            &mut self.schedules,
+            &primary_id,
+            slices,
+            secs_to_nanos(interval_secs),
+        )
+    }
+
+    fn on_time_event(&mut self, ctx: &mut ExecAlgorithmContext, event: &TimeEvent) {
+        on_schedule_event(ctx, &mut self.schedules, event);
+    }
+}
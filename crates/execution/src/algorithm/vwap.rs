@@ -0,0 +1,138 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A volume-weighted average price (VWAP) execution algorithm.
+
+use nautilus_common::timer::TimeEvent;
+use nautilus_core::datetime::secs_to_nanos;
+use nautilus_model::{identifiers::ExecAlgorithmId, orders::OrderAny};
+
+use super::{
+    get_required_param, slice_quantity,
+    twap::{on_schedule_event, start_schedule, SliceSchedule},
+    ExecAlgorithm, ExecAlgorithmContext, PrimaryStates,
+};
+
+/// Parses a volume profile of comma separated weights, such as `"1,2,4,2,1"`.
+///
+/// # Errors
+///
+/// Returns an error if any weight is not a non-negative number, or all weights are zero.
+pub fn parse_volume_profile(value: &str) -> anyhow::Result<Vec<f64>> {
+    let weights = value
+        .split(',')
+        .map(|weight| {
+            let weight: f64 = weight.trim().parse()?;
+            if !weight.is_finite() || weight < 0.0 {
+                anyhow::bail!("Invalid volume profile weight {weight}");
+            }
+            Ok(weight)
+        })
+        .collect::<anyhow::Result<Vec<f64>>>()?;
+
+    if weights.iter().sum::<f64>() <= 0.0 {
+        anyhow::bail!("Invalid volume profile '{value}', no positive weights");
+    }
+
+    Ok(weights)
+}
+
+/// Executes primary orders in slices weighted by an expected intraday volume profile, so
+/// the execution tracks the VWAP of the market over the time horizon.
+///
+/// Parameters:
+/// - `horizon_secs`: The time horizon to execute over.
+/// - `volume_profile`: The comma separated relative volumes expected in each interval of
+///   the horizon, such as `"1,2,4,2,1"`.
+///
+/// The horizon is divided into equal intervals, one per profile weight. The first slice is
+/// spawned immediately, followed by one slice per interval, each sized by its weight.
+#[derive(Debug)]
+pub struct VwapAlgorithm {
+    id: ExecAlgorithmId,
+    schedules: PrimaryStates<SliceSchedule>,
+}
+
+impl VwapAlgorithm {
+    /// Creates a new [`VwapAlgorithm`] instance.
+    #[must_use]
+    pub fn new(id: ExecAlgorithmId) -> Self {
+        Self {
+            id,
+            schedules: PrimaryStates::new(),
+        }
+    }
+}
+
+impl Default for VwapAlgorithm {
+    /// Creates a new default [`VwapAlgorithm`] instance.
+    fn default() -> Self {
+        Self::new(ExecAlgorithmId::new("VWAP"))
+    }
+}
+
+impl ExecAlgorithm for VwapAlgorithm {
+    fn id(&self) -> ExecAlgorithmId {
+        self.id
+    }
+
+    fn on_order(&mut self, ctx: &mut ExecAlgorithmContext, order: &OrderAny) -> anyhow::Result<()> {
+        let horizon_secs: f64 = get_required_param(order, "horizon_secs")?;
+        let profile: String = get_required_param(order, "volume_profile")?;
+        let weights = parse_volume_profile(&profile)?;
+
+        if horizon_secs <= 0.0 {
+            anyhow::bail!("Invalid `horizon_secs` {horizon_secs}, must be positive");
+        }
+
+        let interval_secs = horizon_secs / weights.len() as f64;
+        let slices = slice_quantity(order.quantity(), &weights);
+        start_schedule(
+            ctx,
+            &mut self.schedules,
+            &order.client_order_id(),
+            slices,
+            secs_to_nanos(interval_secs),
+        )
+    }
+
+    fn on_time_event(&mut self, ctx: &mut ExecAlgorithmContext, event: &TimeEvent) {
+        on_schedule_event(ctx, &mut self.schedules, event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("1,2,4,2,1", vec![1.0, 2.0, 4.0, 2.0, 1.0])]
+    #[case(" 0.5, 1.5 ", vec![0.5, 1.5])]
+    #[case("0,1", vec![0.0, 1.0])]
+    fn test_parse_volume_profile(#[case] value: &str, #[case] expected: Vec<f64>) {
+        assert_eq!(parse_volume_profile(value).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("1,x")]
+    #[case("1,-1")]
+    #[case("0,0")]
+    fn test_parse_volume_profile_invalid(#[case] value: &str) {
+        assert!(parse_volume_profile(value).is_err());
+    }
+}
@@ -19,13 +19,13 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
-use std::{any::Any, cell::RefCell, rc::Rc};
+use std::{any::Any, cell::RefCell, collections::HashMap, rc::Rc};
 
 use nautilus_common::{cache::Cache, msgbus::MessageBus};
 use nautilus_core::{AtomicTime, UnixNanos, UUID4};
 use nautilus_model::{
     accounts::AccountAny,
-    enums::{AccountType, LiquiditySide, OmsType, OrderSide, OrderType},
+    enums::{AccountType, LiquiditySide, OmsType, OrderSide, OrderType, PositionSide},
     events::{
         AccountState, OrderAccepted, OrderCancelRejected, OrderCanceled, OrderEventAny,
         OrderExpired, OrderFilled, OrderModifyRejected, OrderRejected, OrderSubmitted,
@@ -35,13 +35,18 @@ use nautilus_model::{
         AccountId, ClientId, ClientOrderId, InstrumentId, PositionId, StrategyId, TradeId,
         TraderId, Venue, VenueOrderId,
     },
+    orders::OrderAny,
     types::{AccountBalance, Currency, MarginBalance, Money, Price, Quantity},
 };
 use ustr::Ustr;
 
-use crate::messages::{
-    BatchCancelOrders, CancelAllOrders, CancelOrder, ModifyOrder, QueryOrder, SubmitOrder,
-    SubmitOrderList,
+use crate::{
+    engine::position_mode::{venue_position_side, PositionMode},
+    messages::{
+        BatchCancelOrders, BatchModifyOrders, CancelAllOrders, CancelOrder, ModifyOrder,
+        QueryOrder, SubmitOrder, SubmitOrderList,
+    },
+    reports::mass_status::ExecutionMassStatus,
 };
 
 pub struct ExecutionClient {
@@ -53,6 +58,21 @@ pub struct ExecutionClient {
     pub account_type: AccountType,
     pub base_currency: Option<Currency>,
     pub is_connected: bool,
+    /// If the venue supports modifying multiple orders in a single batch request.
+    pub supports_batch_modify: bool,
+    /// If the venue supports submitting an order list atomically in a single batch request.
+    pub supports_batch_submit: bool,
+    /// If the venue supports canceling all orders matching a filter in a single request.
+    pub supports_mass_cancel: bool,
+    /// If the venue supports GTD (good-til-date) orders, expiring them at their expire time.
+    pub supports_gtd_orders: bool,
+    /// If the venue supports post-only orders, otherwise the flag is emulated by the engine.
+    pub supports_post_only: bool,
+    /// If the venue supports reduce-only orders, otherwise the flag is emulated by the engine.
+    pub supports_reduce_only: bool,
+    /// The position mode of the venue (when the OMS type is netting).
+    pub position_mode: PositionMode,
+    position_mode_overrides: HashMap<InstrumentId, PositionMode>,
     clock: &'static AtomicTime,
     cache: Rc<RefCell<Cache>>,
     msgbus: Rc<RefCell<MessageBus>>,
@@ -60,7 +80,7 @@ pub struct ExecutionClient {
 
 impl ExecutionClient {
     #[allow(clippy::too_many_arguments)]
-    pub const fn new(
+    pub fn new(
         trader_id: TraderId,
         client_id: ClientId,
         venue: Venue,
@@ -81,6 +101,14 @@ impl ExecutionClient {
             account_type,
             base_currency,
             is_connected: false,
+            supports_batch_modify: false,
+            supports_batch_submit: false,
+            supports_mass_cancel: false,
+            supports_gtd_orders: false,
+            supports_post_only: true,
+            supports_reduce_only: true,
+            position_mode: PositionMode::OneWay,
+            position_mode_overrides: HashMap::new(),
             clock,
             cache,
             msgbus,
@@ -92,6 +120,30 @@ impl ExecutionClient {
         self.cache.borrow().account(&self.account_id).cloned()
     }
 
+    /// Sets the position mode of the instrument, overriding the position mode of the venue.
+    pub fn set_position_mode(&mut self, instrument_id: InstrumentId, mode: PositionMode) {
+        self.position_mode_overrides.insert(instrument_id, mode);
+    }
+
+    /// Returns the position mode of the instrument (if netting positions).
+    #[must_use]
+    pub fn position_mode(&self, instrument_id: &InstrumentId) -> PositionMode {
+        self.position_mode_overrides
+            .get(instrument_id)
+            .copied()
+            .unwrap_or(self.position_mode)
+    }
+
+    /// Returns the position side to route the `order` to at the venue, or `None` if the venue
+    /// holds a single position for both sides of the instrument.
+    #[must_use]
+    pub fn venue_position_side(&self, order: &OrderAny) -> Option<PositionSide> {
+        if self.oms_type == OmsType::Hedging {
+            return None;
+        }
+        venue_position_side(self.position_mode(&order.instrument_id()), order)
+    }
+
     // -- COMMAND HANDLERS ----------------------------------------------------
 
     pub fn submit_order(&self, command: SubmitOrder) -> anyhow::Result<()> {
@@ -106,6 +158,15 @@ impl ExecutionClient {
         todo!();
     }
 
+    /// Modifies a batch of orders, by default modifying each order in turn for venues
+    /// without native batch modify.
+    pub fn batch_modify_orders(&self, command: BatchModifyOrders) -> anyhow::Result<()> {
+        for modify in command.modifies {
+            self.modify_order(modify)?;
+        }
+        Ok(())
+    }
+
     pub fn cancel_order(&self, command: CancelOrder) -> anyhow::Result<()> {
         todo!();
     }
@@ -122,6 +183,17 @@ impl ExecutionClient {
         todo!();
     }
 
+    // -- EXECUTION REPORTS ---------------------------------------------------
+
+    /// Generates an execution mass status of the orders, fills and positions at the venue,
+    /// looking back `lookback_mins` (or the maximum available history).
+    pub fn generate_mass_status(
+        &self,
+        lookback_mins: Option<u32>,
+    ) -> anyhow::Result<Option<ExecutionMassStatus>> {
+        Ok(None) // No venue state to reconcile against by default
+    }
+
     pub fn generate_account_state(
         &self,
         balances: Vec<AccountBalance>,
@@ -0,0 +1,187 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Coalescing of rapid successive modify commands into batches.
+
+use indexmap::IndexMap;
+use nautilus_core::{UnixNanos, UUID4};
+use nautilus_model::identifiers::{ClientId, ClientOrderId, InstrumentId, StrategyId};
+
+use crate::messages::{BatchModifyOrders, ModifyOrder};
+
+/// Merges the `next` modify of an order into the `pending` modify, so the result holds the
+/// latest intent for each field the commands set.
+#[must_use]
+pub fn merge_modify(pending: &ModifyOrder, next: ModifyOrder) -> ModifyOrder {
+    ModifyOrder {
+        quantity: next.quantity.or(pending.quantity),
+        price: next.price.or(pending.price),
+        trigger_price: next.trigger_price.or(pending.trigger_price),
+        ..next
+    }
+}
+
+/// Holds the modify commands received within an interval, merging successive modifies of the
+/// same order, to be flushed as one batch per client, strategy and instrument.
+#[derive(Debug, Default)]
+pub struct ModifyCoalescer {
+    pending: IndexMap<ClientOrderId, ModifyOrder>,
+    merged_count: u64,
+}
+
+impl ModifyCoalescer {
+    /// Creates a new [`ModifyCoalescer`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the `command` to the pending modifies, merging with any pending modify of the order.
+    pub fn push(&mut self, command: ModifyOrder) {
+        let client_order_id = command.client_order_id;
+        let command = match self.pending.get(&client_order_id) {
+            Some(pending) => {
+                self.merged_count += 1;
+                merge_modify(pending, command)
+            }
+            None => command,
+        };
+        self.pending.insert(client_order_id, command);
+    }
+
+    /// Discards any pending modify of the order, such as when the order is canceled or closed.
+    pub fn remove(&mut self, client_order_id: &ClientOrderId) -> Option<ModifyOrder> {
+        self.pending.shift_remove(client_order_id)
+    }
+
+    /// Returns the pending modify of the order (if any).
+    #[must_use]
+    pub fn get(&self, client_order_id: &ClientOrderId) -> Option<&ModifyOrder> {
+        self.pending.get(client_order_id)
+    }
+
+    /// Returns the number of orders with a pending modify.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Returns whether no modifies are pending.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Returns the number of modifies merged into a pending modify (and so not sent).
+    #[must_use]
+    pub const fn merged_count(&self) -> u64 {
+        self.merged_count
+    }
+
+    /// Drains the pending modifies as batches, grouped by client, strategy and instrument in
+    /// the order first received.
+    pub fn drain_batches(&mut self, ts_init: UnixNanos) -> Vec<BatchModifyOrders> {
+        let mut batches: IndexMap<(ClientId, StrategyId, InstrumentId), BatchModifyOrders> =
+            IndexMap::new();
+
+        for (_, modify) in self.pending.drain(..) {
+            batches
+                .entry((modify.client_id, modify.strategy_id, modify.instrument_id))
+                .or_insert_with(|| BatchModifyOrders {
+                    trader_id: modify.trader_id,
+                    client_id: modify.client_id,
+                    strategy_id: modify.strategy_id,
+                    instrument_id: modify.instrument_id,
+                    modifies: Vec::new(),
+                    command_id: UUID4::new(),
+                    ts_init,
+                })
+                .modifies
+                .push(modify);
+        }
+
+        batches.into_values().collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::types::{Price, Quantity};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn modify(
+        client_order_id: &str,
+        instrument_id: &str,
+        quantity: Option<u64>,
+        price: Option<&str>,
+    ) -> ModifyOrder {
+        ModifyOrder {
+            client_id: ClientId::from("SIM"),
+            instrument_id: InstrumentId::from(instrument_id),
+            client_order_id: ClientOrderId::from(client_order_id),
+            quantity: quantity.map(Quantity::from),
+            price: price.map(Price::from),
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    fn test_successive_modifies_merge_into_latest_intent() {
+        let mut coalescer = ModifyCoalescer::new();
+
+        coalescer.push(modify("O-1", "AUD/USD.SIM", Some(100_000), Some("1.00000")));
+        coalescer.push(modify("O-1", "AUD/USD.SIM", None, Some("1.00010")));
+        coalescer.push(modify("O-1", "AUD/USD.SIM", None, Some("1.00020")));
+
+        let pending = coalescer.get(&ClientOrderId::from("O-1")).unwrap();
+        assert_eq!(coalescer.len(), 1);
+        assert_eq!(coalescer.merged_count(), 2);
+        assert_eq!(pending.quantity, Some(Quantity::from(100_000)));
+        assert_eq!(pending.price, Some(Price::from("1.00020")));
+    }
+
+    #[rstest]
+    fn test_drain_batches_groups_by_instrument() {
+        let mut coalescer = ModifyCoalescer::new();
+        coalescer.push(modify("O-1", "AUD/USD.SIM", None, Some("1.00000")));
+        coalescer.push(modify("O-2", "GBP/USD.SIM", None, Some("1.20000")));
+        coalescer.push(modify("O-3", "AUD/USD.SIM", None, Some("1.00010")));
+        coalescer.push(modify("O-1", "AUD/USD.SIM", None, Some("1.00005")));
+
+        let batches = coalescer.drain_batches(1.into());
+
+        assert!(coalescer.is_empty());
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].instrument_id, InstrumentId::from("AUD/USD.SIM"));
+        assert_eq!(batches[0].modifies.len(), 2);
+        assert_eq!(batches[0].modifies[0].price, Some(Price::from("1.00005")));
+        assert_eq!(batches[1].modifies.len(), 1);
+        assert_eq!(batches[1].ts_init, UnixNanos::from(1));
+    }
+
+    #[rstest]
+    fn test_remove_discards_pending_modify() {
+        let mut coalescer = ModifyCoalescer::new();
+        coalescer.push(modify("O-1", "AUD/USD.SIM", None, Some("1.00000")));
+
+        assert!(coalescer.remove(&ClientOrderId::from("O-1")).is_some());
+        assert!(coalescer.drain_batches(0.into()).is_empty());
+    }
+}
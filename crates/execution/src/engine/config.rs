@@ -13,8 +13,13 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::collections::HashMap;
+
+use nautilus_model::identifiers::{InstrumentId, StrategyId};
 use serde::{Deserialize, Serialize};
 
+use super::{position_mode::PositionMode, throttle::StrategyThrottleConfig};
+
 /// Configuration for `ExecutionEngine` instances.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExecutionEngineConfig {
@@ -36,12 +41,67 @@ pub struct ExecutionEngineConfig {
     /// If debug mode is active (will provide extra debug logging).
     #[serde(default)]
     pub debug: bool,
+    /// If contingent orders linked by OCO, OTO and OUO are managed by the engine, so that
+    /// linked orders are released, updated and canceled as the contingent order is filled.
+    #[serde(default)]
+    pub manage_contingent_orders: bool,
+    /// If execution state is reconciled against venue reports at startup.
+    #[serde(default = "default_true")]
+    pub reconciliation: bool,
+    /// The maximum lookback (minutes) for the reports requested at startup reconciliation.
+    /// If None then the execution clients will request the maximum available history.
+    #[serde(default)]
+    pub reconciliation_lookback_mins: Option<u32>,
+    /// The interval (milliseconds) between checks of in-flight orders, which are orders with
+    /// a command awaiting acknowledgement from the venue. If zero then no checks are made.
+    #[serde(default = "default_inflight_check_interval_ms")]
+    pub inflight_check_interval_ms: u32,
+    /// The time (milliseconds) after sending a command before an unacknowledged order is
+    /// queried from the venue.
+    #[serde(default = "default_inflight_check_threshold_ms")]
+    pub inflight_check_threshold_ms: u32,
+    /// The number of queries of an in-flight order before it is resolved locally.
+    #[serde(default = "default_inflight_check_retries")]
+    pub inflight_check_retries: u32,
+    /// The interval (milliseconds) over which modify commands are coalesced, merging successive
+    /// modifies of an order, before being sent as batches. If zero then modifies are sent
+    /// immediately.
+    #[serde(default)]
+    pub modify_coalesce_interval_ms: u32,
+    /// The position modes of instruments at venues with a netting OMS, overriding the position
+    /// mode of the venue execution client.
+    #[serde(default)]
+    pub position_modes: HashMap<InstrumentId, PositionMode>,
+    /// The execution throttles of strategies, enforced on order submission independently of
+    /// any venue rate limits.
+    #[serde(default)]
+    pub throttles: HashMap<StrategyId, StrategyThrottleConfig>,
+    /// If GTD orders submitted to venues without GTD support are expired by the engine, which
+    /// cancels the order at its expire time and reports the cancel as an expiration.
+    #[serde(default)]
+    pub manage_gtd_expiry: bool,
+    /// The delay (milliseconds) past the expire time before a GTD order is expired by the
+    /// engine, tolerating clock skew between the local and venue clocks.
+    #[serde(default)]
+    pub gtd_expiry_tolerance_ms: u32,
 }
 
 const fn default_true() -> bool {
     true
 }
 
+const fn default_inflight_check_interval_ms() -> u32 {
+    2_000
+}
+
+const fn default_inflight_check_threshold_ms() -> u32 {
+    5_000
+}
+
+const fn default_inflight_check_retries() -> u32 {
+    5
+}
+
 impl Default for ExecutionEngineConfig {
     fn default() -> Self {
         Self {
@@ -50,6 +110,17 @@ impl Default for ExecutionEngineConfig {
             snapshot_positions: false,
             snapshot_positions_interval_secs: None,
             debug: false,
+            manage_contingent_orders: false,
+            reconciliation: true,
+            reconciliation_lookback_mins: None,
+            inflight_check_interval_ms: default_inflight_check_interval_ms(),
+            inflight_check_threshold_ms: default_inflight_check_threshold_ms(),
+            inflight_check_retries: default_inflight_check_retries(),
+            modify_coalesce_interval_ms: 0,
+            position_modes: HashMap::new(),
+            throttles: HashMap::new(),
+            manage_gtd_expiry: false,
+            gtd_expiry_tolerance_ms: 0,
         }
     }
 }
@@ -0,0 +1,457 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Management of orders linked by OCO, OTO and OUO contingencies.
+//!
+//! Events on an order are resolved to the actions required on its linked contingent orders,
+//! which the `ExecutionEngine` then applies where managing contingent orders.
+
+use nautilus_common::cache::Cache;
+use nautilus_model::{
+    enums::{ContingencyType, OrderStatus},
+    events::OrderEventAny,
+    identifiers::ClientOrderId,
+    orders::OrderAny,
+    types::Quantity,
+};
+
+/// An action to apply to a contingent order following an event on a linked order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ContingencyAction {
+    /// Submit the held order with the given quantity.
+    Release(ClientOrderId, Quantity),
+    /// Modify the quantity of the order.
+    ModifyQuantity(ClientOrderId, Quantity),
+    /// Cancel the order, locally where it is still held.
+    Cancel(ClientOrderId),
+}
+
+/// Returns whether the `order` is a contingent order held locally, pending the fill of
+/// its OTO parent in the `orders`.
+#[must_use]
+pub fn is_held_by_oto_parent(order: &OrderAny, orders: &[OrderAny]) -> bool {
+    order.parent_order_id().is_some_and(|parent_order_id| {
+        orders.iter().any(|parent| {
+            parent.client_order_id() == parent_order_id
+                && parent.contingency_type() == Some(ContingencyType::Oto)
+        })
+    })
+}
+
+/// Returns the actions to apply to the contingent orders linked to the `order`, following
+/// the `event` which has been applied to it.
+///
+/// - OTO: Held children are released with the filled quantity of the parent on its first
+///   fill, then updated on further fills. Children are canceled where the parent closes
+///   without any fill.
+/// - OCO: Linked orders are canceled once the order is filled (partially or completely),
+///   or otherwise closes.
+/// - OUO: Linked orders are reduced to the leaves quantity of the order on each partial
+///   fill, and canceled once the order closes.
+#[must_use]
+pub fn contingency_actions(
+    cache: &Cache,
+    order: &OrderAny,
+    event: &OrderEventAny,
+) -> Vec<ContingencyAction> {
+    let Some(contingency_type) = order.contingency_type() else {
+        return Vec::new();
+    };
+    if contingency_type == ContingencyType::NoContingency {
+        return Vec::new();
+    }
+
+    let is_filled = matches!(
+        event,
+        OrderEventAny::PartiallyFilled(_) | OrderEventAny::Filled(_)
+    );
+    let is_closing = matches!(
+        event,
+        OrderEventAny::Canceled(_)
+            | OrderEventAny::Rejected(_)
+            | OrderEventAny::Expired(_)
+            | OrderEventAny::Denied(_)
+    );
+    if !is_filled && !is_closing {
+        return Vec::new();
+    }
+
+    let mut actions = Vec::new();
+    for client_order_id in order.linked_order_ids().unwrap_or_default() {
+        if client_order_id == order.client_order_id() {
+            continue;
+        }
+
+        let Some(contingent_order) = cache.order(&client_order_id) else {
+            log::error!("Cannot find contingent order {client_order_id}");
+            continue;
+        };
+        if contingent_order.is_closed() {
+            continue;
+        }
+
+        let action = match contingency_type {
+            ContingencyType::Oto => {
+                let filled_qty = order.filled_qty();
+                if is_filled && contingent_order.status() == OrderStatus::Initialized {
+                    Some(ContingencyAction::Release(client_order_id, filled_qty))
+                } else if is_filled && contingent_order.quantity() != filled_qty {
+                    Some(ContingencyAction::ModifyQuantity(
+                        client_order_id,
+                        filled_qty,
+                    ))
+                } else if is_closing && filled_qty.is_zero() {
+                    Some(ContingencyAction::Cancel(client_order_id))
+                } else {
+                    None
+                }
+            }
+            ContingencyType::Oco => Some(ContingencyAction::Cancel(client_order_id)),
+            ContingencyType::Ouo => {
+                if order.is_closed() {
+                    Some(ContingencyAction::Cancel(client_order_id))
+                } else if order.leaves_qty() != contingent_order.leaves_qty() {
+                    // Keep the same quantity open on the contingent order as the order
+                    let quantity = contingent_order.filled_qty() + order.leaves_qty();
+                    Some(ContingencyAction::ModifyQuantity(client_order_id, quantity))
+                } else {
+                    None
+                }
+            }
+            ContingencyType::NoContingency => None,
+        };
+
+        actions.extend(action);
+    }
+
+    actions
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{OrderSide, OrderType},
+        identifiers::{AccountId, TradeId, VenueOrderId},
+        instruments::{stubs::audusd_sim, InstrumentAny},
+        orders::{stubs::TestOrderEventStubs, OrderTestBuilder},
+        types::Price,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    struct Linked {
+        cache: Cache,
+        instrument: InstrumentAny,
+        orders: Vec<OrderAny>,
+    }
+
+    impl Linked {
+        /// Creates linked limit orders, where the first order is linked to the others
+        /// with the `contingency_type`, and the others are linked to each other by OCO.
+        fn new(contingency_type: ContingencyType, count: usize) -> Self {
+            let instrument = InstrumentAny::CurrencyPair(audusd_sim());
+            let ids: Vec<ClientOrderId> = (1..=count)
+                .map(|i| ClientOrderId::new(format!("O-{i}")))
+                .collect();
+
+            let orders = ids
+                .iter()
+                .enumerate()
+                .map(|(i, client_order_id)| {
+                    let mut builder = OrderTestBuilder::new(OrderType::Limit);
+                    builder
+                        .instrument_id(instrument.id())
+                        .client_order_id(*client_order_id)
+                        .side(if i == 0 {
+                            OrderSide::Buy
+                        } else {
+                            OrderSide::Sell
+                        })
+                        .price(Price::from("1.00000"))
+                        .quantity(Quantity::from(100_000));
+                    if i == 0 {
+                        builder
+                            .contingency_type(contingency_type)
+                            .linked_order_ids(ids[1..].to_vec());
+                    } else {
+                        let siblings: Vec<ClientOrderId> = ids[1..]
+                            .iter()
+                            .filter(|id| *id != client_order_id)
+                            .copied()
+                            .collect();
+                        let child_contingency_type = if contingency_type == ContingencyType::Oto {
+                            ContingencyType::Oco
+                        } else {
+                            contingency_type
+                        };
+                        builder
+                            .contingency_type(child_contingency_type)
+                            .linked_order_ids(
+                                std::iter::once(ids[0])
+                                    .filter(|_| contingency_type != ContingencyType::Oto)
+                                    .chain(siblings)
+                                    .collect(),
+                            );
+                        if contingency_type == ContingencyType::Oto {
+                            builder.parent_order_id(ids[0]);
+                        }
+                    }
+                    builder.build()
+                })
+                .collect::<Vec<_>>();
+
+            let mut cache = Cache::default();
+            for order in &orders {
+                cache.add_order(order.clone(), None, None, false).unwrap();
+            }
+
+            Self {
+                cache,
+                instrument,
+                orders,
+            }
+        }
+
+        fn id(&self, index: usize) -> ClientOrderId {
+            self.orders[index].client_order_id()
+        }
+
+        fn apply(&mut self, index: usize, event: OrderEventAny) -> OrderEventAny {
+            let order = &mut self.orders[index];
+            order.apply(event.clone()).unwrap();
+            self.cache.update_order(order).unwrap();
+            event
+        }
+
+        fn accept(&mut self, index: usize) {
+            let account_id = AccountId::from("SIM-001");
+            let submitted = TestOrderEventStubs::order_submitted(&self.orders[index], account_id);
+            self.apply(index, submitted);
+            let accepted = TestOrderEventStubs::order_accepted(
+                &self.orders[index],
+                account_id,
+                VenueOrderId::new(format!("V-{index}")),
+            );
+            self.apply(index, accepted);
+        }
+
+        fn fill(&mut self, index: usize, quantity: u64, trade_id: &str) -> OrderEventAny {
+            let filled = TestOrderEventStubs::order_filled(
+                &self.orders[index],
+                &self.instrument,
+                Some(TradeId::from(trade_id)),
+                None,
+                None,
+                Some(Quantity::from(quantity)),
+                None,
+                None,
+                None,
+                None,
+            );
+            // Partial fills are distinguished by the event variant
+            let filled = match filled {
+                OrderEventAny::Filled(fill) if fill.last_qty < self.orders[index].leaves_qty() => {
+                    OrderEventAny::PartiallyFilled(fill)
+                }
+                event => event,
+            };
+            self.apply(index, filled)
+        }
+
+        fn cancel(&mut self, index: usize) -> OrderEventAny {
+            let order = &self.orders[index];
+            let canceled = OrderEventAny::Canceled(nautilus_model::events::OrderCanceled::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                false,
+                order.venue_order_id(),
+                order.account_id(),
+            ));
+            self.apply(index, canceled)
+        }
+
+        fn actions(&self, index: usize, event: &OrderEventAny) -> Vec<ContingencyAction> {
+            contingency_actions(&self.cache, &self.orders[index], event)
+        }
+    }
+
+    #[rstest]
+    fn test_is_held_by_oto_parent() {
+        let linked = Linked::new(ContingencyType::Oto, 3);
+
+        assert!(!is_held_by_oto_parent(&linked.orders[0], &linked.orders));
+        assert!(is_held_by_oto_parent(&linked.orders[1], &linked.orders));
+        assert!(is_held_by_oto_parent(&linked.orders[2], &linked.orders));
+        assert!(!is_held_by_oto_parent(
+            &linked.orders[1],
+            &linked.orders[1..]
+        ));
+    }
+
+    #[rstest]
+    fn test_oto_partial_fills_release_then_modify_children() {
+        let mut linked = Linked::new(ContingencyType::Oto, 3);
+        linked.accept(0);
+
+        let event = linked.fill(0, 40_000, "1");
+        assert_eq!(
+            linked.actions(0, &event),
+            vec![
+                ContingencyAction::Release(linked.id(1), Quantity::from(40_000)),
+                ContingencyAction::Release(linked.id(2), Quantity::from(40_000)),
+            ]
+        );
+
+        // Children released and accepted with the partial quantity
+        for index in 1..=2 {
+            linked.orders[index].set_quantity(Quantity::from(40_000));
+            linked.orders[index].set_leaves_qty(Quantity::from(40_000));
+            linked.accept(index);
+        }
+
+        let event = linked.fill(0, 60_000, "2");
+        assert_eq!(
+            linked.actions(0, &event),
+            vec![
+                ContingencyAction::ModifyQuantity(linked.id(1), Quantity::from(100_000)),
+                ContingencyAction::ModifyQuantity(linked.id(2), Quantity::from(100_000)),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_oto_parent_canceled_without_fill_cancels_children() {
+        let mut linked = Linked::new(ContingencyType::Oto, 3);
+        linked.accept(0);
+
+        let event = linked.cancel(0);
+
+        assert_eq!(
+            linked.actions(0, &event),
+            vec![
+                ContingencyAction::Cancel(linked.id(1)),
+                ContingencyAction::Cancel(linked.id(2)),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_oto_parent_canceled_after_partial_fill_keeps_children() {
+        let mut linked = Linked::new(ContingencyType::Oto, 2);
+        linked.accept(0);
+        linked.fill(0, 40_000, "1");
+        linked.orders[1].set_quantity(Quantity::from(40_000));
+        linked.orders[1].set_leaves_qty(Quantity::from(40_000));
+        linked.accept(1);
+
+        let event = linked.cancel(0);
+
+        assert!(linked.actions(0, &event).is_empty());
+    }
+
+    #[rstest]
+    fn test_oco_partial_fill_cancels_linked_orders() {
+        let mut linked = Linked::new(ContingencyType::Oco, 3);
+        for index in 0..3 {
+            linked.accept(index);
+        }
+
+        let event = linked.fill(0, 40_000, "1");
+
+        assert_eq!(
+            linked.actions(0, &event),
+            vec![
+                ContingencyAction::Cancel(linked.id(1)),
+                ContingencyAction::Cancel(linked.id(2)),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_oco_skips_closed_linked_orders() {
+        let mut linked = Linked::new(ContingencyType::Oco, 3);
+        for index in 0..3 {
+            linked.accept(index);
+        }
+        linked.cancel(1);
+
+        let event = linked.cancel(0);
+
+        assert_eq!(
+            linked.actions(0, &event),
+            vec![ContingencyAction::Cancel(linked.id(2))]
+        );
+    }
+
+    #[rstest]
+    fn test_ouo_partial_fills_reduce_then_cancel_linked_order() {
+        let mut linked = Linked::new(ContingencyType::Ouo, 2);
+        linked.accept(0);
+        linked.accept(1);
+
+        let event = linked.fill(0, 40_000, "1");
+        assert_eq!(
+            linked.actions(0, &event),
+            vec![ContingencyAction::ModifyQuantity(
+                linked.id(1),
+                Quantity::from(60_000)
+            )]
+        );
+
+        let event = linked.fill(0, 60_000, "2");
+        assert_eq!(
+            linked.actions(0, &event),
+            vec![ContingencyAction::Cancel(linked.id(1))]
+        );
+    }
+
+    #[rstest]
+    fn test_ouo_partially_filled_linked_order_keeps_filled_quantity() {
+        let mut linked = Linked::new(ContingencyType::Ouo, 2);
+        linked.accept(0);
+        linked.accept(1);
+        linked.fill(1, 20_000, "1");
+
+        let event = linked.fill(0, 50_000, "2");
+
+        // The linked order has 20,000 filled, so keeps 50,000 open of 70,000
+        assert_eq!(
+            linked.actions(0, &event),
+            vec![ContingencyAction::ModifyQuantity(
+                linked.id(1),
+                Quantity::from(70_000)
+            )]
+        );
+    }
+
+    #[rstest]
+    fn test_no_contingency_returns_no_actions() {
+        let mut linked = Linked::new(ContingencyType::NoContingency, 2);
+        linked.accept(0);
+
+        let event = linked.fill(0, 100_000, "1");
+
+        assert!(linked.actions(0, &event).is_empty());
+    }
+}
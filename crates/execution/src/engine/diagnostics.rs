@@ -0,0 +1,179 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Diagnostics of invalid order state transitions persisted to the cache database.
+
+use std::fmt::Display;
+
+use bytes::Bytes;
+use nautilus_common::cache::Cache;
+use nautilus_core::{UnixNanos, UUID4};
+use nautilus_model::{
+    enums::OrderStatus,
+    events::{OrderEventAny, OrderEventType},
+    identifiers::{ClientOrderId, InstrumentId, StrategyId, TraderId},
+    orders::{fsm::valid_event_types, OrderAny},
+};
+use serde::{Deserialize, Serialize};
+
+/// The prefix of the cache keys diagnostics are persisted under.
+pub const INVALID_TRANSITION_KEY_PREFIX: &str = "invalid_transition:";
+
+/// Represents an event which could not be applied to an order, as the order status FSM had no
+/// transition for the event from the current order status.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InvalidTransitionDiagnostic {
+    pub trader_id: TraderId,
+    pub strategy_id: StrategyId,
+    pub instrument_id: InstrumentId,
+    pub client_order_id: ClientOrderId,
+    /// The order status when the event was applied.
+    pub status: OrderStatus,
+    /// The event which could not be applied.
+    pub event: OrderEventAny,
+    /// The types of event which are valid for the order status.
+    pub valid_events: Vec<OrderEventType>,
+    /// The full event history of the order.
+    pub events: Vec<OrderEventAny>,
+    pub event_id: UUID4,
+    pub ts_event: UnixNanos,
+}
+
+impl InvalidTransitionDiagnostic {
+    /// Creates a new [`InvalidTransitionDiagnostic`] instance for the `event` which could not be
+    /// applied to the `order`.
+    #[must_use]
+    pub fn new(order: &OrderAny, event: OrderEventAny, ts_event: UnixNanos) -> Self {
+        let status = order.status();
+        Self {
+            trader_id: order.trader_id(),
+            strategy_id: order.strategy_id(),
+            instrument_id: order.instrument_id(),
+            client_order_id: order.client_order_id(),
+            status,
+            event,
+            valid_events: valid_event_types(status),
+            events: order.events().into_iter().cloned().collect(),
+            event_id: UUID4::new(),
+            ts_event,
+        }
+    }
+
+    /// Returns the cache key of the diagnostic, ordering diagnostics by time.
+    #[must_use]
+    pub fn key(&self) -> String {
+        format!(
+            "{INVALID_TRANSITION_KEY_PREFIX}{:020}:{}",
+            self.ts_event.as_u64(),
+            self.event_id
+        )
+    }
+
+    /// Persists the diagnostic to the `cache` (and its database).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the diagnostic cannot be serialized or persisted.
+    pub fn persist(&self, cache: &mut Cache) -> anyhow::Result<()> {
+        let bytes = serde_json::to_vec(self)?;
+        cache.add(&self.key(), Bytes::from(bytes))
+    }
+}
+
+impl Display for InvalidTransitionDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let join = |event_types: Vec<OrderEventType>| {
+            event_types
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        write!(
+            f,
+            "{}(client_order_id={}, status={}, event={}, valid_events=[{}], history=[{}])",
+            stringify!(InvalidTransitionDiagnostic),
+            self.client_order_id,
+            self.status,
+            self.event.event_type(),
+            join(self.valid_events.clone()),
+            join(self.events.iter().map(OrderEventAny::event_type).collect()),
+        )
+    }
+}
+
+/// Returns the diagnostics in the `cache`, optionally for the `client_order_id` only, in time
+/// order.
+#[must_use]
+pub fn query_invalid_transitions(
+    cache: &Cache,
+    client_order_id: Option<&ClientOrderId>,
+) -> Vec<InvalidTransitionDiagnostic> {
+    cache
+        .get_prefixed(INVALID_TRANSITION_KEY_PREFIX)
+        .into_iter()
+        .filter_map(|(key, bytes)| {
+            match serde_json::from_slice::<InvalidTransitionDiagnostic>(bytes) {
+                Ok(diagnostic) => Some(diagnostic),
+                Err(e) => {
+                    log::error!("Cannot decode invalid transition diagnostic {key}: {e}");
+                    None
+                }
+            }
+        })
+        .filter(|diagnostic| client_order_id.is_none_or(|id| diagnostic.client_order_id == *id))
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{OrderSide, OrderType},
+        identifiers::AccountId,
+        instruments::{stubs::audusd_sim, CurrencyPair},
+        orders::{stubs::TestOrderEventStubs, OrderTestBuilder},
+        types::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_persist_and_query(audusd_sim: CurrencyPair) {
+        let mut cache = Cache::default();
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(audusd_sim.id)
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(100_000))
+            .build();
+        let event = TestOrderEventStubs::order_submitted(&order, AccountId::from("SIM-001"));
+        let mut submitted = order.clone();
+        submitted.apply(event.clone()).unwrap();
+        let diagnostic = InvalidTransitionDiagnostic::new(&submitted, event, 1.into());
+        diagnostic.persist(&mut cache).unwrap();
+
+        assert_eq!(diagnostic.status, OrderStatus::Submitted);
+        assert_eq!(diagnostic.events.len(), 2);
+        assert!(!diagnostic.valid_events.contains(&OrderEventType::Submitted));
+        assert_eq!(
+            query_invalid_transitions(&cache, Some(&order.client_order_id())),
+            vec![diagnostic]
+        );
+        assert!(query_invalid_transitions(&cache, Some(&ClientOrderId::from("O-2"))).is_empty());
+    }
+}
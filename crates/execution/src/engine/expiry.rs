@@ -0,0 +1,181 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Local expiry of GTD (good-til-date) orders at venues without native GTD support.
+
+use std::collections::{HashMap, HashSet};
+
+use nautilus_core::{UnixNanos, UUID4};
+use nautilus_model::{
+    enums::TimeInForce,
+    events::{OrderCanceled, OrderExpired},
+    identifiers::ClientOrderId,
+    orders::OrderAny,
+};
+
+/// Returns the name of the timer expiring the GTD order `client_order_id`.
+#[must_use]
+pub fn gtd_timer_name(client_order_id: &ClientOrderId) -> String {
+    format!("ExecEngine.expire_{client_order_id}")
+}
+
+/// Returns the time to expire the `order` locally, if a GTD order with an expire time.
+///
+/// The expiry is delayed by `tolerance_ns` past the expire time, so the order is not expired
+/// early when the local clock runs ahead of the venue clock, and is never before `ts_now`.
+#[must_use]
+pub fn gtd_alert_time(order: &OrderAny, tolerance_ns: u64, ts_now: UnixNanos) -> Option<UnixNanos> {
+    if order.time_in_force() != TimeInForce::Gtd {
+        return None;
+    }
+    let expire_time = order.expire_time()?;
+    Some(UnixNanos::from(expire_time.as_u64() + tolerance_ns).max(ts_now))
+}
+
+/// Returns the expiration of an order for the `canceled` event acknowledging its expiry.
+#[must_use]
+pub fn expired_from_canceled(canceled: &OrderCanceled) -> OrderExpired {
+    OrderExpired::new(
+        canceled.trader_id,
+        canceled.strategy_id,
+        canceled.instrument_id,
+        canceled.client_order_id,
+        UUID4::new(),
+        canceled.ts_event,
+        canceled.ts_init,
+        canceled.reconciliation != 0,
+        canceled.venue_order_id,
+        canceled.account_id,
+    )
+}
+
+/// Tracks the GTD orders expired locally, from the expiry timer being scheduled to the venue
+/// acknowledging the cancel sent at expiry.
+#[derive(Debug, Default)]
+pub struct GtdExpiries {
+    scheduled: HashMap<ClientOrderId, UnixNanos>,
+    expiring: HashSet<ClientOrderId>,
+}
+
+impl GtdExpiries {
+    /// Creates a new [`GtdExpiries`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the expiry timer of the order scheduled for `alert_time`.
+    pub fn schedule(&mut self, client_order_id: ClientOrderId, alert_time: UnixNanos) {
+        self.scheduled.insert(client_order_id, alert_time);
+    }
+
+    /// Returns the time the expiry timer of the order is scheduled for (if any).
+    #[must_use]
+    pub fn scheduled(&self, client_order_id: &ClientOrderId) -> Option<UnixNanos> {
+        self.scheduled.get(client_order_id).copied()
+    }
+
+    /// Records the order as expiring, with its expiry cancel sent to the venue.
+    pub fn mark_expiring(&mut self, client_order_id: ClientOrderId) {
+        self.scheduled.remove(&client_order_id);
+        self.expiring.insert(client_order_id);
+    }
+
+    /// Returns whether the order is expiring, clearing it so the next cancel of the order is
+    /// taken as its expiry.
+    pub fn take_expiring(&mut self, client_order_id: &ClientOrderId) -> bool {
+        self.expiring.remove(client_order_id)
+    }
+
+    /// Removes the order once closed, returning whether its expiry timer was still scheduled
+    /// (and so should be canceled).
+    pub fn remove(&mut self, client_order_id: &ClientOrderId) -> bool {
+        self.expiring.remove(client_order_id);
+        self.scheduled.remove(client_order_id).is_some()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{OrderSide, OrderType},
+        identifiers::InstrumentId,
+        orders::OrderTestBuilder,
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn gtd_order(expire_time: u64) -> OrderAny {
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .time_in_force(TimeInForce::Gtd)
+            .expire_time(expire_time.into())
+            .build()
+    }
+
+    #[rstest]
+    fn test_gtd_alert_time_applies_tolerance() {
+        let order = gtd_order(1_000);
+
+        assert_eq!(
+            gtd_alert_time(&order, 50, 0.into()),
+            Some(UnixNanos::from(1_050))
+        );
+        assert_eq!(
+            gtd_alert_time(&order, 50, 2_000.into()),
+            Some(UnixNanos::from(2_000))
+        );
+    }
+
+    #[rstest]
+    fn test_gtd_alert_time_none_for_gtc() {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+
+        assert_eq!(gtd_alert_time(&order, 0, 0.into()), None);
+    }
+
+    #[rstest]
+    fn test_expiries_lifecycle() {
+        let mut expiries = GtdExpiries::new();
+        let client_order_id = ClientOrderId::from("O-1");
+
+        expiries.schedule(client_order_id, 1_000.into());
+        assert_eq!(
+            expiries.scheduled(&client_order_id),
+            Some(UnixNanos::from(1_000))
+        );
+
+        expiries.mark_expiring(client_order_id);
+        assert_eq!(expiries.scheduled(&client_order_id), None);
+        assert!(expiries.take_expiring(&client_order_id));
+        assert!(!expiries.take_expiring(&client_order_id));
+
+        expiries.schedule(client_order_id, 1_000.into());
+        assert!(expiries.remove(&client_order_id));
+        assert!(!expiries.remove(&client_order_id));
+    }
+}
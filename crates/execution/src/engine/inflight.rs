@@ -0,0 +1,397 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Tracking of in-flight order commands awaiting acknowledgement from the venue.
+
+use indexmap::IndexMap;
+use nautilus_common::cache::Cache;
+use nautilus_core::{UnixNanos, UUID4};
+use nautilus_model::{
+    enums::OrderStatus,
+    events::{OrderAccepted, OrderCanceled, OrderEventAny, OrderRejected},
+    identifiers::{ClientId, ClientOrderId},
+    orders::OrderAny,
+};
+use ustr::Ustr;
+
+use crate::messages::QueryOrder;
+
+/// The reason for rejecting an order whose submission was never acknowledged.
+const INFLIGHT_TIMEOUT_REASON: &str = "INFLIGHT_TIMEOUT";
+
+#[derive(Clone, Copy, Debug)]
+struct InflightCommand {
+    client_id: ClientId,
+    ts_sent: UnixNanos,
+    queries: u32,
+}
+
+/// An action to take for an in-flight order which has exceeded the threshold.
+#[allow(clippy::large_enum_variant)] // Actions are only taken on timeouts
+#[derive(Clone, Debug)]
+pub enum InflightAction {
+    /// Query the status of the order from the venue.
+    Query(QueryOrder),
+    /// Resolve the order locally with the event, once queries have been exhausted.
+    Resolve(OrderEventAny),
+}
+
+/// Returns whether the order `status` is awaiting acknowledgement of a command by the venue.
+#[must_use]
+pub const fn is_inflight_status(status: OrderStatus) -> bool {
+    matches!(
+        status,
+        OrderStatus::Initialized
+            | OrderStatus::Submitted
+            | OrderStatus::PendingUpdate
+            | OrderStatus::PendingCancel
+    )
+}
+
+/// Returns the event resolving the in-flight `order` where the venue never acknowledged the
+/// command: a submitted order is rejected, a pending cancel is canceled, and a pending update
+/// is accepted as working unchanged.
+#[must_use]
+pub fn resolve_inflight_order(order: &OrderAny, ts_init: UnixNanos) -> Option<OrderEventAny> {
+    let Some(account_id) = order.account_id() else {
+        log::error!(
+            "Cannot resolve in-flight {}: no account ID",
+            order.client_order_id()
+        );
+        return None;
+    };
+
+    let event = match order.status() {
+        OrderStatus::Initialized | OrderStatus::Submitted => {
+            OrderEventAny::Rejected(OrderRejected::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                account_id,
+                Ustr::from(INFLIGHT_TIMEOUT_REASON),
+                UUID4::new(),
+                ts_init,
+                ts_init,
+                true,
+            ))
+        }
+        OrderStatus::PendingCancel => OrderEventAny::Canceled(OrderCanceled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            UUID4::new(),
+            ts_init,
+            ts_init,
+            true,
+            order.venue_order_id(),
+            Some(account_id),
+        )),
+        OrderStatus::PendingUpdate => OrderEventAny::Accepted(OrderAccepted::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            order.venue_order_id().unwrap_or_default(),
+            account_id,
+            UUID4::new(),
+            ts_init,
+            ts_init,
+            true,
+        )),
+        _ => return None,
+    };
+
+    Some(event)
+}
+
+/// Tracks the orders with commands sent to the venue which are awaiting acknowledgement.
+///
+/// Orders still in-flight past the threshold are queried from the venue, up to a maximum
+/// number of queries, after which they are resolved locally so they are not left in limbo.
+#[derive(Debug)]
+pub struct InflightOrders {
+    pub threshold_ns: u64,
+    pub max_queries: u32,
+    commands: IndexMap<ClientOrderId, InflightCommand>,
+}
+
+impl InflightOrders {
+    /// Creates a new [`InflightOrders`] instance.
+    #[must_use]
+    pub fn new(threshold_ns: u64, max_queries: u32) -> Self {
+        Self {
+            threshold_ns,
+            max_queries,
+            commands: IndexMap::new(),
+        }
+    }
+
+    /// Tracks a command for the order sent via the client at `ts_sent`.
+    pub fn track(
+        &mut self,
+        client_order_id: ClientOrderId,
+        client_id: ClientId,
+        ts_sent: UnixNanos,
+    ) {
+        self.commands.insert(
+            client_order_id,
+            InflightCommand {
+                client_id,
+                ts_sent,
+                queries: 0,
+            },
+        );
+    }
+
+    /// Stops tracking the order.
+    pub fn remove(&mut self, client_order_id: &ClientOrderId) {
+        self.commands.shift_remove(client_order_id);
+    }
+
+    /// Returns whether the order is tracked as in-flight.
+    #[must_use]
+    pub fn contains(&self, client_order_id: &ClientOrderId) -> bool {
+        self.commands.contains_key(client_order_id)
+    }
+
+    /// Returns the number of orders tracked as in-flight.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    /// Returns whether no orders are tracked as in-flight.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Checks the tracked orders at `ts_now`, returning the actions for those in-flight past
+    /// the threshold. Orders no longer in-flight are no longer tracked.
+    pub fn check(&mut self, cache: &Cache, ts_now: UnixNanos) -> Vec<InflightAction> {
+        let mut actions = Vec::new();
+
+        self.commands.retain(|client_order_id, command| {
+            let Some(order) = cache.order(client_order_id) else {
+                return false;
+            };
+            if !is_inflight_status(order.status()) {
+                return false;
+            }
+            if ts_now.as_u64().saturating_sub(command.ts_sent.as_u64()) < self.threshold_ns {
+                return true;
+            }
+
+            if command.queries < self.max_queries {
+                command.queries += 1;
+                command.ts_sent = ts_now;
+                log::warn!(
+                    "Querying in-flight {client_order_id} ({}/{})",
+                    command.queries,
+                    self.max_queries,
+                );
+
+                match QueryOrder::new(
+                    order.trader_id(),
+                    command.client_id,
+                    order.strategy_id(),
+                    order.instrument_id(),
+                    *client_order_id,
+                    order.venue_order_id().unwrap_or_default(),
+                    UUID4::new(),
+                    ts_now,
+                ) {
+                    Ok(query) => actions.push(InflightAction::Query(query)),
+                    Err(e) => log::error!("Cannot query in-flight {client_order_id}: {e}"),
+                }
+                return true;
+            }
+
+            log::warn!("Resolving in-flight {client_order_id} {}", order.status());
+            if let Some(event) = resolve_inflight_order(order, ts_now) {
+                actions.push(InflightAction::Resolve(event));
+            }
+            false
+        });
+
+        actions
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{OrderSide, OrderType},
+        events::{OrderPendingCancel, OrderPendingUpdate},
+        identifiers::{AccountId, VenueOrderId},
+        instruments::stubs::audusd_sim,
+        orders::{stubs::TestOrderEventStubs, OrderTestBuilder},
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    const THRESHOLD_NS: u64 = 5_000;
+
+    fn submitted_order() -> OrderAny {
+        let mut order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(audusd_sim().id)
+            .client_order_id(ClientOrderId::from("O-1"))
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        let submitted = TestOrderEventStubs::order_submitted(&order, AccountId::from("SIM-001"));
+        order.apply(submitted).unwrap();
+        order
+    }
+
+    fn accepted_order() -> OrderAny {
+        let mut order = submitted_order();
+        let accepted = TestOrderEventStubs::order_accepted(
+            &order,
+            AccountId::from("SIM-001"),
+            VenueOrderId::from("V-1"),
+        );
+        order.apply(accepted).unwrap();
+        order
+    }
+
+    fn pending_cancel_order() -> OrderAny {
+        let mut order = accepted_order();
+        let pending = OrderPendingCancel::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            AccountId::from("SIM-001"),
+            UUID4::new(),
+            0.into(),
+            0.into(),
+            false,
+            order.venue_order_id(),
+        );
+        order.apply(OrderEventAny::PendingCancel(pending)).unwrap();
+        order
+    }
+
+    fn tracked(order: &OrderAny) -> (Cache, InflightOrders) {
+        let mut cache = Cache::default();
+        cache.add_order(order.clone(), None, None, false).unwrap();
+        let mut inflight = InflightOrders::new(THRESHOLD_NS, 2);
+        inflight.track(order.client_order_id(), ClientId::from("SIM"), 0.into());
+        (cache, inflight)
+    }
+
+    #[rstest]
+    fn test_check_before_threshold_has_no_actions() {
+        let (cache, mut inflight) = tracked(&submitted_order());
+
+        let actions = inflight.check(&cache, (THRESHOLD_NS - 1).into());
+
+        assert!(actions.is_empty());
+        assert!(inflight.contains(&ClientOrderId::from("O-1")));
+    }
+
+    #[rstest]
+    fn test_check_queries_then_resolves_submitted_order_as_rejected() {
+        let (cache, mut inflight) = tracked(&submitted_order());
+
+        let first = inflight.check(&cache, THRESHOLD_NS.into());
+        let early = inflight.check(&cache, (THRESHOLD_NS * 2 - 1).into());
+        let second = inflight.check(&cache, (THRESHOLD_NS * 2).into());
+        let resolved = inflight.check(&cache, (THRESHOLD_NS * 3).into());
+
+        assert!(matches!(first[..], [InflightAction::Query(_)]));
+        assert!(early.is_empty());
+        assert!(matches!(second[..], [InflightAction::Query(_)]));
+        let [InflightAction::Resolve(OrderEventAny::Rejected(rejected))] = resolved[..] else {
+            panic!("Expected rejected resolution, was {resolved:?}");
+        };
+        assert_eq!(rejected.reason, Ustr::from(INFLIGHT_TIMEOUT_REASON));
+        assert!(inflight.is_empty());
+    }
+
+    #[rstest]
+    fn test_check_query_command_for_pending_cancel() {
+        let (cache, mut inflight) = tracked(&pending_cancel_order());
+
+        let actions = inflight.check(&cache, THRESHOLD_NS.into());
+
+        let [InflightAction::Query(query)] = &actions[..] else {
+            panic!("Expected query, was {actions:?}");
+        };
+        assert_eq!(query.client_id, ClientId::from("SIM"));
+        assert_eq!(query.client_order_id, ClientOrderId::from("O-1"));
+        assert_eq!(query.venue_order_id, VenueOrderId::from("V-1"));
+    }
+
+    #[rstest]
+    fn test_check_untracks_acknowledged_order() {
+        let (mut cache, mut inflight) = tracked(&submitted_order());
+        cache.update_order(&accepted_order()).unwrap();
+
+        let actions = inflight.check(&cache, THRESHOLD_NS.into());
+
+        assert!(actions.is_empty());
+        assert!(inflight.is_empty());
+    }
+
+    #[rstest]
+    fn test_resolve_pending_cancel_as_canceled() {
+        let mut order = pending_cancel_order();
+
+        let event = resolve_inflight_order(&order, 0.into()).unwrap();
+
+        assert!(matches!(event, OrderEventAny::Canceled(_)));
+        order.apply(event).unwrap();
+        assert_eq!(order.status(), OrderStatus::Canceled);
+    }
+
+    #[rstest]
+    fn test_resolve_pending_update_as_accepted() {
+        let mut order = accepted_order();
+        let pending = OrderPendingUpdate::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            AccountId::from("SIM-001"),
+            UUID4::new(),
+            0.into(),
+            0.into(),
+            false,
+            order.venue_order_id(),
+        );
+        order.apply(OrderEventAny::PendingUpdate(pending)).unwrap();
+
+        let event = resolve_inflight_order(&order, 0.into()).unwrap();
+
+        assert!(matches!(event, OrderEventAny::Accepted(_)));
+        order.apply(event).unwrap();
+        assert_eq!(order.status(), OrderStatus::Accepted);
+    }
+
+    #[rstest]
+    fn test_resolve_working_order_is_none() {
+        assert!(resolve_inflight_order(&accepted_order(), 0.into()).is_none());
+    }
+}
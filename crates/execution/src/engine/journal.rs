@@ -0,0 +1,472 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A sequence numbered journal of execution commands and events, for exactly-once processing
+//! of events across restarts.
+
+use std::{
+    collections::HashSet,
+    fs::{File, OpenOptions},
+    io::{BufRead, BufReader, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+};
+
+use nautilus_core::{UnixNanos, UUID4};
+use nautilus_model::{
+    events::OrderEventAny,
+    identifiers::{InstrumentId, TradeId},
+    orders::OrderAny,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::messages::TradingCommand;
+
+/// A command or event recorded in the journal.
+#[allow(clippy::large_enum_variant)] // Records are written once and replayed at startup
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum JournalRecord {
+    /// An outbound command sent to an execution client.
+    Command(TradingCommand),
+    /// An inbound execution event from an execution client.
+    Event(OrderEventAny),
+}
+
+/// A sequence numbered record in the journal.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// The sequence number of the entry, increasing from 1.
+    pub sequence: u64,
+    /// UNIX timestamp (nanoseconds) when the entry was journaled.
+    pub ts_init: UnixNanos,
+    /// The journaled command or event.
+    pub record: JournalRecord,
+}
+
+/// Provides persistence for an [`ExecutionJournal`].
+pub trait JournalStore {
+    /// Persists the `entry`, which must be durable before the entry is processed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the entry cannot be persisted.
+    fn append(&mut self, entry: &JournalEntry) -> anyhow::Result<()>;
+
+    /// Persists that the entries up to and including `sequence` have been processed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the checkpoint cannot be persisted.
+    fn checkpoint(&mut self, sequence: u64) -> anyhow::Result<()>;
+
+    /// Loads the persisted entries in sequence order, and the last processed sequence.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the journal cannot be read.
+    fn load(&self) -> anyhow::Result<(Vec<JournalEntry>, u64)>;
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Serialize, Deserialize)]
+enum JournalLine {
+    Entry(JournalEntry),
+    Checkpoint(u64),
+}
+
+/// A [`JournalStore`] appending JSON lines to a file.
+#[derive(Debug)]
+pub struct FileJournalStore {
+    path: PathBuf,
+    file: File,
+}
+
+impl FileJournalStore {
+    /// Creates a new [`FileJournalStore`] instance, opening (or creating) the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened.
+    pub fn new<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let mut file = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(&path)?;
+
+        // Terminate any partial last line so appended lines remain readable
+        let len = file.metadata()?.len();
+        if len > 0 {
+            let mut last = [0u8; 1];
+            file.seek(SeekFrom::Start(len - 1))?;
+            file.read_exact(&mut last)?;
+            if last[0] != b'\n' {
+                file.write_all(b"\n")?;
+            }
+        }
+
+        Ok(Self { path, file })
+    }
+
+    fn write_line(&mut self, line: &JournalLine) -> anyhow::Result<()> {
+        let mut buf = serde_json::to_vec(line)?;
+        buf.push(b'\n');
+        self.file.write_all(&buf)?;
+        self.file.sync_data()?;
+        Ok(())
+    }
+}
+
+impl JournalStore for FileJournalStore {
+    fn append(&mut self, entry: &JournalEntry) -> anyhow::Result<()> {
+        self.write_line(&JournalLine::Entry(entry.clone()))
+    }
+
+    fn checkpoint(&mut self, sequence: u64) -> anyhow::Result<()> {
+        self.write_line(&JournalLine::Checkpoint(sequence))
+    }
+
+    fn load(&self) -> anyhow::Result<(Vec<JournalEntry>, u64)> {
+        let mut entries = Vec::new();
+        let mut processed = 0;
+
+        for (i, line) in BufReader::new(File::open(&self.path)?).lines().enumerate() {
+            let line = line?;
+            match serde_json::from_str(&line) {
+                Ok(JournalLine::Entry(entry)) => entries.push(entry),
+                Ok(JournalLine::Checkpoint(sequence)) => processed = processed.max(sequence),
+                // A crash mid-write leaves a partial last line, which was never processed
+                Err(e) => log::warn!("Skipping unreadable journal line {}: {e}", i + 1),
+            }
+        }
+
+        Ok((entries, processed))
+    }
+}
+
+/// Journals the commands and events of the execution engine, so that after a restart the
+/// events journaled but not processed are replayed, and duplicate events are dropped.
+pub struct ExecutionJournal {
+    store: Box<dyn JournalStore>,
+    last_sequence: u64,
+    processed_sequence: u64,
+    unprocessed: Vec<JournalEntry>,
+    event_ids: HashSet<UUID4>,
+    trade_ids: HashSet<(InstrumentId, TradeId)>,
+}
+
+impl ExecutionJournal {
+    /// Creates a new [`ExecutionJournal`] instance, loading the entries persisted by `store`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the journal cannot be loaded.
+    pub fn new(store: Box<dyn JournalStore>) -> anyhow::Result<Self> {
+        let (entries, processed_sequence) = store.load()?;
+
+        let mut journal = Self {
+            store,
+            last_sequence: processed_sequence,
+            processed_sequence,
+            unprocessed: Vec::new(),
+            event_ids: HashSet::new(),
+            trade_ids: HashSet::new(),
+        };
+
+        for entry in entries {
+            journal.last_sequence = journal.last_sequence.max(entry.sequence);
+            if let JournalRecord::Event(event) = &entry.record {
+                journal.index_event(event);
+                if entry.sequence > processed_sequence {
+                    journal.unprocessed.push(entry);
+                }
+            }
+        }
+
+        Ok(journal)
+    }
+
+    /// Returns the sequence number of the last journaled entry.
+    #[must_use]
+    pub const fn last_sequence(&self) -> u64 {
+        self.last_sequence
+    }
+
+    /// Returns the sequence number of the last processed entry.
+    #[must_use]
+    pub const fn processed_sequence(&self) -> u64 {
+        self.processed_sequence
+    }
+
+    /// Journals the outbound `command`, returning its sequence number.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the entry cannot be persisted.
+    pub fn record_command(
+        &mut self,
+        command: &TradingCommand,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<u64> {
+        self.append(JournalRecord::Command(command.clone()), ts_init)
+    }
+
+    /// Journals the inbound `event`, returning its sequence number, or `None` if the event
+    /// is a duplicate of a journaled event (by event ID, or by trade ID for fills).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the entry cannot be persisted.
+    pub fn record_event(
+        &mut self,
+        event: &OrderEventAny,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Option<u64>> {
+        if self.is_duplicate(event) {
+            return Ok(None);
+        }
+
+        self.index_event(event);
+        self.append(JournalRecord::Event(event.clone()), ts_init)
+            .map(Some)
+    }
+
+    /// Marks the entries up to and including `sequence` as processed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the checkpoint cannot be persisted.
+    pub fn mark_processed(&mut self, sequence: u64) -> anyhow::Result<()> {
+        if sequence <= self.processed_sequence {
+            return Ok(());
+        }
+
+        self.store.checkpoint(sequence)?;
+        self.processed_sequence = sequence;
+        Ok(())
+    }
+
+    /// Takes the event entries journaled but not processed before the last restart, in
+    /// sequence order, for replay.
+    pub fn take_unprocessed(&mut self) -> Vec<JournalEntry> {
+        std::mem::take(&mut self.unprocessed)
+    }
+
+    fn append(&mut self, record: JournalRecord, ts_init: UnixNanos) -> anyhow::Result<u64> {
+        let entry = JournalEntry {
+            sequence: self.last_sequence + 1,
+            ts_init,
+            record,
+        };
+        self.store.append(&entry)?;
+        self.last_sequence = entry.sequence;
+        Ok(entry.sequence)
+    }
+
+    fn is_duplicate(&self, event: &OrderEventAny) -> bool {
+        let event = event.clone().into_boxed();
+        self.event_ids.contains(&event.id())
+            || event
+                .trade_id()
+                .is_some_and(|trade_id| self.trade_ids.contains(&(event.instrument_id(), trade_id)))
+    }
+
+    fn index_event(&mut self, event: &OrderEventAny) {
+        let event = event.clone().into_boxed();
+        self.event_ids.insert(event.id());
+        if let Some(trade_id) = event.trade_id() {
+            self.trade_ids.insert((event.instrument_id(), trade_id));
+        }
+    }
+}
+
+/// Returns whether the `event` has already been applied to the `order`, such as when the
+/// order state was persisted before the event was marked as processed.
+#[must_use]
+pub fn is_applied(order: &OrderAny, event: &OrderEventAny) -> bool {
+    let event = event.clone().into_boxed();
+    order.events().into_iter().any(|applied| {
+        let applied = applied.clone().into_boxed();
+        match (applied.trade_id(), event.trade_id()) {
+            (Some(applied_trade_id), Some(trade_id)) => applied_trade_id == trade_id,
+            _ => applied.id() == event.id(),
+        }
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{OrderSide, OrderType},
+        identifiers::{AccountId, ClientId, VenueOrderId},
+        instruments::{stubs::audusd_sim, InstrumentAny},
+        orders::{stubs::TestOrderEventStubs, OrderTestBuilder},
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::messages::QueryOrder;
+
+    fn accepted_order() -> OrderAny {
+        let account_id = AccountId::from("SIM-001");
+        let mut order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(audusd_sim().id)
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        order
+            .apply(TestOrderEventStubs::order_submitted(&order, account_id))
+            .unwrap();
+        order
+            .apply(TestOrderEventStubs::order_accepted(
+                &order,
+                account_id,
+                VenueOrderId::from("V-1"),
+            ))
+            .unwrap();
+        order
+    }
+
+    fn fill(order: &OrderAny, trade_id: &str) -> OrderEventAny {
+        TestOrderEventStubs::order_filled(
+            order,
+            &InstrumentAny::CurrencyPair(audusd_sim()),
+            Some(TradeId::from(trade_id)),
+            None,
+            None,
+            Some(Quantity::from(50_000)),
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn open_journal(dir: &TempDir) -> ExecutionJournal {
+        let store = FileJournalStore::new(dir.path().join("journal.jsonl")).unwrap();
+        ExecutionJournal::new(Box::new(store)).unwrap()
+    }
+
+    #[rstest]
+    fn test_duplicate_fills_are_dropped() {
+        let dir = TempDir::new().unwrap();
+        let mut journal = open_journal(&dir);
+        let order = accepted_order();
+
+        let first = journal
+            .record_event(&fill(&order, "T-1"), 0.into())
+            .unwrap();
+        let resent = journal
+            .record_event(&fill(&order, "T-1"), 0.into())
+            .unwrap();
+        let second = journal
+            .record_event(&fill(&order, "T-2"), 0.into())
+            .unwrap();
+
+        assert_eq!(first, Some(1));
+        assert_eq!(resent, None);
+        assert_eq!(second, Some(2));
+    }
+
+    #[rstest]
+    fn test_unprocessed_events_replayed_after_restart() {
+        let dir = TempDir::new().unwrap();
+        let order = accepted_order();
+        {
+            let mut journal = open_journal(&dir);
+            let query = TradingCommand::QueryOrder(QueryOrder {
+                client_id: ClientId::from("SIM"),
+                ..Default::default()
+            });
+            journal.record_command(&query, 0.into()).unwrap();
+            let sequence = journal
+                .record_event(&fill(&order, "T-1"), 0.into())
+                .unwrap()
+                .unwrap();
+            journal.mark_processed(sequence).unwrap();
+            journal
+                .record_event(&fill(&order, "T-2"), 0.into())
+                .unwrap();
+            // Crash before the second fill is processed
+        }
+
+        let mut journal = open_journal(&dir);
+        let unprocessed = journal.take_unprocessed();
+
+        assert_eq!(journal.last_sequence(), 3);
+        assert_eq!(journal.processed_sequence(), 2);
+        assert_eq!(unprocessed.len(), 1);
+        assert_eq!(unprocessed[0].sequence, 3);
+        assert!(matches!(
+            &unprocessed[0].record,
+            JournalRecord::Event(event) if event.clone().into_boxed().trade_id() == Some(TradeId::from("T-2"))
+        ));
+        // Fills journaled before the restart are still deduplicated
+        assert_eq!(
+            journal
+                .record_event(&fill(&order, "T-1"), 0.into())
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            journal
+                .record_event(&fill(&order, "T-3"), 0.into())
+                .unwrap(),
+            Some(4)
+        );
+    }
+
+    #[rstest]
+    fn test_partial_last_line_is_skipped() {
+        let dir = TempDir::new().unwrap();
+        let order = accepted_order();
+        {
+            let mut journal = open_journal(&dir);
+            journal
+                .record_event(&fill(&order, "T-1"), 0.into())
+                .unwrap();
+        }
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(dir.path().join("journal.jsonl"))
+            .unwrap();
+        file.write_all(b"{\"Entry\":{\"seq").unwrap();
+
+        let mut journal = open_journal(&dir);
+        journal
+            .record_event(&fill(&order, "T-2"), 0.into())
+            .unwrap();
+        let mut journal = open_journal(&dir);
+
+        assert_eq!(journal.last_sequence(), 2);
+        assert_eq!(journal.take_unprocessed().len(), 2);
+    }
+
+    #[rstest]
+    fn test_is_applied_by_trade_id() {
+        let mut order = accepted_order();
+        let filled = fill(&order, "T-1");
+        order.apply(filled.clone()).unwrap();
+
+        assert!(is_applied(&order, &filled));
+        assert!(!is_applied(&order, &fill(&order, "T-2")));
+    }
+}
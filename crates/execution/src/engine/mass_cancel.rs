@@ -0,0 +1,255 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Emulation of mass cancels for venues without native support.
+
+use nautilus_common::cache::Cache;
+use nautilus_core::{UnixNanos, UUID4};
+use nautilus_model::identifiers::{ClientId, ClientOrderId};
+
+use crate::messages::{CancelAllOrders, CancelOrder};
+
+/// Returns a cancel for each open order in the `cache` matching the filters of the `command`,
+/// for emulating the mass cancel one order at a time.
+#[must_use]
+pub fn emulated_cancels(cache: &Cache, command: &CancelAllOrders) -> Vec<CancelOrder> {
+    cache
+        .orders_open(
+            Some(&command.venue()),
+            command.instrument_filter(),
+            command.strategy_filter(),
+            command.side_filter(),
+        )
+        .into_iter()
+        .filter(|order| command.matches(order))
+        .map(|order| CancelOrder {
+            trader_id: command.trader_id,
+            client_id: command.client_id,
+            strategy_id: order.strategy_id(),
+            instrument_id: order.instrument_id(),
+            client_order_id: order.client_order_id(),
+            venue_order_id: order.venue_order_id().unwrap_or_default(),
+            command_id: UUID4::new(),
+            ts_init: command.ts_init,
+        })
+        .collect()
+}
+
+/// Returns a cancel of the order `client_order_id` in the `cache` sent to `client_id`, if the
+/// order is found and not closed.
+#[must_use]
+pub fn order_cancel(
+    cache: &Cache,
+    client_id: ClientId,
+    client_order_id: &ClientOrderId,
+    ts_init: UnixNanos,
+) -> Option<CancelOrder> {
+    let order = cache.order(client_order_id)?;
+    if order.is_closed() {
+        return None;
+    }
+
+    Some(CancelOrder {
+        trader_id: order.trader_id(),
+        client_id,
+        strategy_id: order.strategy_id(),
+        instrument_id: order.instrument_id(),
+        client_order_id: *client_order_id,
+        venue_order_id: order.venue_order_id().unwrap_or_default(),
+        command_id: UUID4::new(),
+        ts_init,
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{OrderSide, OrderType},
+        events::{OrderCanceled, OrderEventAny},
+        identifiers::{AccountId, InstrumentId, StrategyId, VenueOrderId},
+        orders::{stubs::TestOrderEventStubs, OrderAny, OrderTestBuilder},
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn add_open_order(
+        cache: &mut Cache,
+        client_order_id: &str,
+        instrument_id: &str,
+        strategy_id: &str,
+        side: OrderSide,
+    ) {
+        let mut order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(InstrumentId::from(instrument_id))
+            .strategy_id(StrategyId::from(strategy_id))
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .side(side)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        cache.add_order(order.clone(), None, None, false).unwrap();
+
+        let account_id = AccountId::from("SIM-001");
+        let submitted = TestOrderEventStubs::order_submitted(&order, account_id);
+        order.apply(submitted).unwrap();
+        let accepted = TestOrderEventStubs::order_accepted(
+            &order,
+            account_id,
+            VenueOrderId::from(format!("V-{client_order_id}").as_str()),
+        );
+        order.apply(accepted).unwrap();
+        cache.update_order(&order).unwrap();
+    }
+
+    fn cache_with_open_orders() -> Cache {
+        let mut cache = Cache::default();
+        add_open_order(&mut cache, "O-1", "AUD/USD.SIM", "S-001", OrderSide::Buy);
+        add_open_order(&mut cache, "O-2", "AUD/USD.SIM", "S-001", OrderSide::Sell);
+        add_open_order(&mut cache, "O-3", "GBP/USD.SIM", "S-001", OrderSide::Buy);
+        add_open_order(&mut cache, "O-4", "AUD/USD.SIM", "S-002", OrderSide::Buy);
+        add_open_order(
+            &mut cache,
+            "O-5",
+            "ETHUSDT.BINANCE",
+            "S-001",
+            OrderSide::Buy,
+        );
+        cache
+    }
+
+    fn cancel_all(order_side: OrderSide) -> CancelAllOrders {
+        CancelAllOrders {
+            strategy_id: StrategyId::from("S-001"),
+            instrument_id: InstrumentId::from("AUD/USD.SIM"),
+            order_side,
+            ..Default::default()
+        }
+    }
+
+    fn canceled_ids(cancels: &[CancelOrder]) -> Vec<&str> {
+        let mut ids: Vec<&str> = cancels
+            .iter()
+            .map(|cancel| cancel.client_order_id.as_str())
+            .collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    #[rstest]
+    fn test_emulated_cancels_for_strategy_instrument() {
+        let cache = cache_with_open_orders();
+
+        let cancels = emulated_cancels(&cache, &cancel_all(OrderSide::NoOrderSide));
+
+        assert_eq!(canceled_ids(&cancels), ["O-1", "O-2"]);
+        assert!(cancels.iter().all(
+            |cancel| cancel.venue_order_id.as_str() == format!("V-{}", cancel.client_order_id)
+        ));
+    }
+
+    #[rstest]
+    fn test_emulated_cancels_by_side() {
+        let cache = cache_with_open_orders();
+
+        let cancels = emulated_cancels(&cache, &cancel_all(OrderSide::Sell));
+
+        assert_eq!(canceled_ids(&cancels), ["O-2"]);
+    }
+
+    #[rstest]
+    fn test_emulated_cancels_venue_wide_for_all_strategies() {
+        let cache = cache_with_open_orders();
+        let command = cancel_all(OrderSide::Buy)
+            .with_venue_wide()
+            .with_all_strategies();
+
+        let cancels = emulated_cancels(&cache, &command);
+
+        assert_eq!(canceled_ids(&cancels), ["O-1", "O-3", "O-4"]);
+        assert_eq!(
+            cancels
+                .iter()
+                .filter(|c| c.strategy_id.as_str() == "S-002")
+                .count(),
+            1
+        );
+    }
+
+    #[rstest]
+    fn test_emulated_cancels_skips_closed_orders() {
+        let mut cache = cache_with_open_orders();
+        let mut order: OrderAny = cache.order(&ClientOrderId::from("O-1")).cloned().unwrap();
+        let canceled = OrderCanceled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            UUID4::new(),
+            0.into(),
+            0.into(),
+            false,
+            order.venue_order_id(),
+            order.account_id(),
+        );
+        order.apply(OrderEventAny::Canceled(canceled)).unwrap();
+        cache.update_order(&order).unwrap();
+
+        let cancels = emulated_cancels(&cache, &cancel_all(OrderSide::NoOrderSide));
+
+        assert_eq!(canceled_ids(&cancels), ["O-2"]);
+    }
+
+    #[rstest]
+    fn test_order_cancel_only_for_open_orders() {
+        let mut cache = cache_with_open_orders();
+        let client_order_id = ClientOrderId::from("O-1");
+
+        let cancel = order_cancel(&cache, ClientId::from("SIM"), &client_order_id, 1.into());
+
+        let cancel = cancel.unwrap();
+        assert_eq!(cancel.client_id, ClientId::from("SIM"));
+        assert_eq!(cancel.venue_order_id, VenueOrderId::from("V-O-1"));
+        assert!(order_cancel(
+            &cache,
+            ClientId::from("SIM"),
+            &ClientOrderId::from("O-9"),
+            1.into()
+        )
+        .is_none());
+
+        let mut order = cache.order(&client_order_id).unwrap().clone();
+        order
+            .apply(OrderEventAny::Canceled(OrderCanceled::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                client_order_id,
+                UUID4::new(),
+                0.into(),
+                0.into(),
+                false,
+                order.venue_order_id(),
+                order.account_id(),
+            )))
+            .unwrap();
+        cache.update_order(&order).unwrap();
+        assert!(order_cancel(&cache, ClientId::from("SIM"), &client_order_id, 1.into()).is_none());
+    }
+}
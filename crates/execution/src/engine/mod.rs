@@ -20,45 +20,81 @@
 //! includes sending commands to, and receiving events from, the trading venue
 //! endpoints via its registered execution clients.
 
+pub mod coalescing;
 pub mod config;
+pub mod contingency;
+pub mod diagnostics;
+pub mod expiry;
+pub mod inflight;
+pub mod journal;
+pub mod mass_cancel;
+pub mod order_flags;
+pub mod position_mode;
+pub mod reconciliation;
+pub mod sequenced;
+pub mod throttle;
 
 use std::{
+    any::Any,
     cell::RefCell,
     collections::{HashMap, HashSet},
     rc::Rc,
     time::SystemTime,
 };
 
+use coalescing::ModifyCoalescer;
 use config::ExecutionEngineConfig;
+use contingency::{contingency_actions, is_held_by_oto_parent, ContingencyAction};
+use diagnostics::{query_invalid_transitions, InvalidTransitionDiagnostic};
+use expiry::{expired_from_canceled, gtd_alert_time, gtd_timer_name, GtdExpiries};
+use inflight::{is_inflight_status, InflightAction, InflightOrders};
+use journal::{is_applied, ExecutionJournal, JournalRecord};
+use mass_cancel::{emulated_cancels, order_cancel};
 use nautilus_common::{
     cache::Cache,
     clock::Clock,
     generators::position_id::PositionIdGenerator,
     logging::{CMD, EVT, RECV},
     msgbus::MessageBus,
+    timer::{TimeEvent, TimeEventCallback},
 };
-use nautilus_core::UUID4;
+use nautilus_core::{datetime::NANOSECONDS_IN_MILLISECOND, UnixNanos, UUID4};
 use nautilus_model::{
-    enums::{ContingencyType, OmsType, OrderSide, PositionSide},
+    enums::{ContingencyType, OmsType, OrderSide, OrderStatus, PositionSide},
     events::{
-        OrderDenied, OrderEvent, OrderEventAny, OrderFilled, PositionChanged, PositionClosed,
-        PositionOpened,
+        OrderCanceled, OrderDenied, OrderEvent, OrderEventAny, OrderFilled, OrderUpdated,
+        PositionChanged, PositionClosed, PositionOpened,
+    },
+    identifiers::{
+        ClientId, ClientOrderId, InstrumentId, PositionId, StrategyId, Venue, VenueOrderId,
     },
-    identifiers::{ClientId, InstrumentId, PositionId, StrategyId, Venue},
     instruments::InstrumentAny,
-    orders::{OrderAny, OrderError},
+    orders::{OrderAny, OrderError, OrderLatency},
     position::Position,
     types::{Money, Price, Quantity},
 };
+use order_flags::{post_only_would_cross, reduce_only_check, ReduceOnlyCheck};
+use position_mode::{order_hedge_position_id, PositionMode};
+use reconciliation::{external_order, is_position_reconciled, reconcile_order};
+use sequenced::{SequenceRollback, SequencedSubmits};
+use throttle::{StrategyThrottleConfig, StrategyThrottled, StrategyThrottles, ThrottleReason};
+use ustr::Ustr;
 
 use crate::{
     client::ExecutionClient,
     messages::{
-        BatchCancelOrders, CancelAllOrders, CancelOrder, ModifyOrder, QueryOrder, SubmitOrder,
-        SubmitOrderList, TradingCommand,
+        BatchCancelOrders, BatchModifyOrders, CancelAllOrders, CancelOrder, ModifyOrder,
+        QueryOrder, SubmitOrder, SubmitOrderList, TradingCommand,
     },
+    reports::{fill::FillReport, mass_status::ExecutionMassStatus, order::OrderStatusReport},
 };
 
+/// The name of the timer checking in-flight orders.
+const INFLIGHT_CHECK_TIMER: &str = "ExecEngine.check_inflight_orders";
+
+/// The name of the timer flushing coalesced modifies.
+const MODIFY_COALESCE_TIMER: &str = "ExecEngine.flush_modifies";
+
 pub struct ExecutionEngine {
     clock: Rc<RefCell<dyn Clock>>,
     cache: Rc<RefCell<Cache>>,
@@ -69,6 +105,12 @@ pub struct ExecutionEngine {
     oms_overrides: HashMap<StrategyId, OmsType>,
     external_order_claims: HashMap<InstrumentId, StrategyId>,
     pos_id_generator: PositionIdGenerator,
+    inflight_orders: Rc<RefCell<InflightOrders>>,
+    modify_coalescer: Rc<RefCell<ModifyCoalescer>>,
+    gtd_expiries: Rc<RefCell<GtdExpiries>>,
+    sequenced_submits: RefCell<SequencedSubmits>,
+    journal: RefCell<Option<ExecutionJournal>>,
+    throttles: RefCell<StrategyThrottles>,
     config: ExecutionEngineConfig,
 }
 
@@ -80,6 +122,15 @@ impl ExecutionEngine {
         config: Option<ExecutionEngineConfig>,
     ) -> Self {
         let trader_id = msgbus.borrow().trader_id;
+        let config = config.unwrap_or_default();
+        let inflight_orders = InflightOrders::new(
+            u64::from(config.inflight_check_threshold_ms) * NANOSECONDS_IN_MILLISECOND,
+            config.inflight_check_retries,
+        );
+        let mut throttles = StrategyThrottles::new();
+        for (strategy_id, throttle_config) in &config.throttles {
+            throttles.set_config(*strategy_id, *throttle_config);
+        }
         Self {
             clock: clock.clone(),
             cache,
@@ -90,7 +141,13 @@ impl ExecutionEngine {
             oms_overrides: HashMap::new(),
             external_order_claims: HashMap::new(),
             pos_id_generator: PositionIdGenerator::new(trader_id, clock),
-            config: config.unwrap_or_default(),
+            inflight_orders: Rc::new(RefCell::new(inflight_orders)),
+            modify_coalescer: Rc::new(RefCell::new(ModifyCoalescer::new())),
+            gtd_expiries: Rc::new(RefCell::new(GtdExpiries::new())),
+            sequenced_submits: RefCell::new(SequencedSubmits::new()),
+            journal: RefCell::new(None),
+            throttles: RefCell::new(throttles),
+            config,
         }
     }
 
@@ -119,6 +176,16 @@ impl ExecutionEngine {
         self.cache.borrow().check_residuals()
     }
 
+    /// Returns the diagnostics of events which could not be applied to orders, optionally for
+    /// the `client_order_id` only.
+    #[must_use]
+    pub fn invalid_transitions(
+        &self,
+        client_order_id: Option<&ClientOrderId>,
+    ) -> Vec<InvalidTransitionDiagnostic> {
+        query_invalid_transitions(&self.cache.borrow(), client_order_id)
+    }
+
     #[must_use]
     pub fn get_external_order_claims_instruments(&self) -> HashSet<InstrumentId> {
         self.external_order_claims.keys().copied().collect()
@@ -126,11 +193,13 @@ impl ExecutionEngine {
 
     // -- REGISTRATION --------------------------------------------------------
 
-    pub fn register_client(&mut self, client: ExecutionClient) -> anyhow::Result<()> {
+    pub fn register_client(&mut self, mut client: ExecutionClient) -> anyhow::Result<()> {
         if self.clients.contains_key(&client.client_id) {
             anyhow::bail!("Client already registered with ID {}", client.client_id);
         }
 
+        self.apply_position_modes(&mut client);
+
         // If client has venue, register routing
         self.routing_map.insert(client.venue, client.client_id);
 
@@ -139,11 +208,18 @@ impl ExecutionEngine {
         Ok(())
     }
 
-    pub fn register_default_client(&mut self, client: ExecutionClient) {
+    pub fn register_default_client(&mut self, mut client: ExecutionClient) {
+        self.apply_position_modes(&mut client);
         log::info!("Registered default client {}", client.client_id);
         self.default_client = Some(client);
     }
 
+    fn apply_position_modes(&self, client: &mut ExecutionClient) {
+        for (instrument_id, mode) in &self.config.position_modes {
+            client.set_position_mode(*instrument_id, *mode);
+        }
+    }
+
     pub fn register_venue_routing(
         &mut self,
         client_id: ClientId,
@@ -206,13 +282,468 @@ impl ExecutionEngine {
     }
 
     pub fn process(&mut self, event: &OrderEventAny) {
+        let sequence = match self.journal.get_mut() {
+            Some(journal) => {
+                let ts_init = self.clock.borrow().timestamp_ns();
+                match journal.record_event(event, ts_init) {
+                    Ok(Some(sequence)) => Some(sequence),
+                    Ok(None) => {
+                        log::warn!("Dropping duplicate event {event}");
+                        return;
+                    }
+                    Err(e) => {
+                        log::error!("Failed to journal event {event}: {e}");
+                        None
+                    }
+                }
+            }
+            None => None,
+        };
+
         self.handle_event(event);
+
+        if let Some(sequence) = sequence {
+            self.mark_journal_processed(sequence);
+        }
     }
 
     pub fn execute(&self, command: TradingCommand) {
+        if let Some(journal) = self.journal.borrow_mut().as_mut() {
+            let ts_init = self.clock.borrow().timestamp_ns();
+            if let Err(e) = journal.record_command(&command, ts_init) {
+                log::error!("Failed to journal command {command}: {e}");
+            }
+        }
+
         self.execute_command(command);
     }
 
+    /// Registers the `journal` of commands and events, after which inbound events are journaled
+    /// before being processed, and duplicates of journaled events are dropped.
+    pub fn register_journal(&mut self, journal: ExecutionJournal) {
+        log::info!(
+            "Registered journal at sequence {} (processed {})",
+            journal.last_sequence(),
+            journal.processed_sequence(),
+        );
+        self.journal = RefCell::new(Some(journal));
+    }
+
+    /// Replays the events journaled but not processed before the last restart, in sequence
+    /// order, skipping events already applied to the cached orders.
+    ///
+    /// Journaled commands are not resent, as their outcome is determined by reconciliation.
+    pub fn replay_journal(&mut self) {
+        let Some(entries) = self
+            .journal
+            .get_mut()
+            .as_mut()
+            .map(ExecutionJournal::take_unprocessed)
+        else {
+            return;
+        };
+
+        for entry in entries {
+            let JournalRecord::Event(event) = &entry.record else {
+                continue;
+            };
+
+            let applied = self
+                .cache
+                .borrow()
+                .order(&event.client_order_id())
+                .is_some_and(|order| is_applied(order, event));
+            if applied {
+                log::info!("Skipping replay of applied event {event}");
+            } else {
+                log::info!("Replaying event {event}");
+                self.handle_event(event);
+            }
+
+            self.mark_journal_processed(entry.sequence);
+        }
+    }
+
+    fn mark_journal_processed(&mut self, sequence: u64) {
+        if let Some(journal) = self.journal.get_mut() {
+            if let Err(e) = journal.mark_processed(sequence) {
+                log::error!("Failed to mark journal processed at sequence {sequence}: {e}");
+            }
+        }
+    }
+
+    /// Sets the execution throttles of the strategy, replacing any configured throttles.
+    pub fn set_strategy_throttle(&self, strategy_id: StrategyId, config: StrategyThrottleConfig) {
+        self.throttles.borrow_mut().set_config(strategy_id, config);
+    }
+
+    /// Kills the strategy, denying all its order submissions until restored regardless of
+    /// its throttles.
+    pub fn kill_strategy(&self, strategy_id: StrategyId) {
+        log::warn!("Killed {strategy_id}, denying order submissions until restored");
+        self.throttles.borrow_mut().kill(strategy_id);
+        self.publish_throttled(strategy_id, ThrottleReason::Killed, None);
+    }
+
+    /// Restores the killed strategy, also ending any reject cooldown.
+    pub fn restore_strategy(&self, strategy_id: StrategyId) {
+        log::info!("Restored {strategy_id}");
+        self.throttles.borrow_mut().restore(&strategy_id);
+    }
+
+    #[must_use]
+    pub fn is_strategy_killed(&self, strategy_id: &StrategyId) -> bool {
+        self.throttles.borrow().is_killed(strategy_id)
+    }
+
+    /// Starts the timer checking in-flight orders every `inflight_check_interval_ms`.
+    ///
+    /// Orders with a command unacknowledged past `inflight_check_threshold_ms` are queried from
+    /// the venue, and once `inflight_check_retries` queries go unanswered the order is resolved
+    /// locally, with the query commands and resolving events sent to the engine endpoints.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the timer cannot be set.
+    pub fn start_inflight_check(&self) -> anyhow::Result<()> {
+        let interval_ms = self.config.inflight_check_interval_ms;
+        if interval_ms == 0 {
+            return Ok(());
+        }
+
+        let inflight_orders = self.inflight_orders.clone();
+        let cache = self.cache.clone();
+        let msgbus = self.msgbus.clone();
+        let callback = TimeEventCallback::Rust(Rc::new(move |event: TimeEvent| {
+            let actions = inflight_orders
+                .borrow_mut()
+                .check(&cache.borrow(), event.ts_event);
+
+            for action in actions {
+                match action {
+                    InflightAction::Query(query) => {
+                        let endpoint = msgbus.borrow().switchboard.exec_engine_execute;
+                        send_to_endpoint(
+                            &msgbus,
+                            endpoint,
+                            &TradingCommand::QueryOrder(query) as &dyn Any,
+                        );
+                    }
+                    InflightAction::Resolve(event) => {
+                        let endpoint = msgbus.borrow().switchboard.exec_engine_process;
+                        send_to_endpoint(&msgbus, endpoint, &event as &dyn Any);
+                    }
+                }
+            }
+        }));
+
+        let ts_now = self.clock.borrow().timestamp_ns();
+        self.clock.borrow_mut().set_timer_ns(
+            INFLIGHT_CHECK_TIMER,
+            u64::from(interval_ms) * NANOSECONDS_IN_MILLISECOND,
+            ts_now,
+            None,
+            Some(callback),
+        )
+    }
+
+    /// Starts the timer flushing coalesced modifies every `modify_coalesce_interval_ms`.
+    ///
+    /// While active, modify commands are held and merged with any later modify of the same
+    /// order, then sent to the engine endpoint as one batch per client, strategy and instrument.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the timer cannot be set.
+    pub fn start_modify_coalescing(&self) -> anyhow::Result<()> {
+        let interval_ms = self.config.modify_coalesce_interval_ms;
+        if interval_ms == 0 {
+            return Ok(());
+        }
+
+        let modify_coalescer = self.modify_coalescer.clone();
+        let msgbus = self.msgbus.clone();
+        let callback = TimeEventCallback::Rust(Rc::new(move |event: TimeEvent| {
+            let batches = modify_coalescer.borrow_mut().drain_batches(event.ts_event);
+
+            let endpoint = msgbus.borrow().switchboard.exec_engine_execute;
+            for batch in batches {
+                send_to_endpoint(
+                    &msgbus,
+                    endpoint,
+                    &TradingCommand::BatchModifyOrders(batch) as &dyn Any,
+                );
+            }
+        }));
+
+        let ts_now = self.clock.borrow().timestamp_ns();
+        self.clock.borrow_mut().set_timer_ns(
+            MODIFY_COALESCE_TIMER,
+            u64::from(interval_ms) * NANOSECONDS_IN_MILLISECOND,
+            ts_now,
+            None,
+            Some(callback),
+        )
+    }
+
+    /// Schedules the timer expiring the `order` if a GTD order submitted to a venue without
+    /// GTD support.
+    ///
+    /// At expiry the order is canceled at the venue, and the cancel acknowledged by the venue
+    /// is applied as an expiration of the order.
+    fn schedule_gtd_expiry(&self, client: &ExecutionClient, order: &OrderAny) {
+        if !self.config.manage_gtd_expiry || client.supports_gtd_orders {
+            return;
+        }
+
+        let tolerance_ns =
+            u64::from(self.config.gtd_expiry_tolerance_ms) * NANOSECONDS_IN_MILLISECOND;
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let Some(alert_time) = gtd_alert_time(order, tolerance_ns, ts_now) else {
+            return;
+        };
+
+        let client_id = client.client_id;
+        let client_order_id = order.client_order_id();
+        let gtd_expiries = self.gtd_expiries.clone();
+        let cache = self.cache.clone();
+        let msgbus = self.msgbus.clone();
+        let callback = TimeEventCallback::Rust(Rc::new(move |event: TimeEvent| {
+            let cancel = order_cancel(&cache.borrow(), client_id, &client_order_id, event.ts_event);
+            let Some(cancel) = cancel else {
+                gtd_expiries.borrow_mut().remove(&client_order_id);
+                return;
+            };
+
+            log::info!("Expiring GTD order {client_order_id}");
+            gtd_expiries.borrow_mut().mark_expiring(client_order_id);
+
+            let endpoint = msgbus.borrow().switchboard.exec_engine_execute;
+            send_to_endpoint(
+                &msgbus,
+                endpoint,
+                &TradingCommand::CancelOrder(cancel) as &dyn Any,
+            );
+        }));
+
+        if let Err(e) = self.clock.borrow_mut().set_time_alert_ns(
+            &gtd_timer_name(&client_order_id),
+            alert_time,
+            Some(callback),
+        ) {
+            log::error!("Failed to schedule expiry of GTD order {client_order_id}: {e}");
+            return;
+        }
+        self.gtd_expiries
+            .borrow_mut()
+            .schedule(client_order_id, alert_time);
+    }
+
+    /// Starts tracking the lifecycle latency of the `order`, submitted by its strategy at
+    /// `ts_submit` and received by the engine now.
+    fn track_latency(&self, order: &OrderAny, ts_submit: UnixNanos) {
+        let ts_engine = self.clock.borrow().timestamp_ns();
+        self.cache.borrow_mut().add_order_latency(OrderLatency::new(
+            order.client_order_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            ts_submit,
+            ts_engine,
+        ));
+    }
+
+    fn track_inflight(&self, client: &ExecutionClient, client_order_id: ClientOrderId) {
+        let ts_now = self.clock.borrow().timestamp_ns();
+        self.inflight_orders
+            .borrow_mut()
+            .track(client_order_id, client.client_id, ts_now);
+    }
+
+    // -- RECONCILIATION ------------------------------------------------------
+
+    /// Reconciles the cached execution state against the mass status reported by each
+    /// execution client, returning whether all state was reconciled.
+    pub fn reconcile_execution_state(&mut self) -> bool {
+        if !self.config.reconciliation {
+            log::warn!("Reconciliation deactivated");
+            return true;
+        }
+
+        let lookback_mins = self.config.reconciliation_lookback_mins;
+        let mut mass_statuses = Vec::new();
+        let mut result = true;
+
+        for client in self.clients.values().chain(self.default_client.iter()) {
+            match client.generate_mass_status(lookback_mins) {
+                Ok(Some(mass_status)) => mass_statuses.push(mass_status),
+                Ok(None) => log::warn!("No execution mass status for {}", client.client_id),
+                Err(e) => {
+                    log::error!("Error generating mass status for {}: {e}", client.client_id);
+                    result = false;
+                }
+            }
+        }
+
+        for mass_status in &mass_statuses {
+            result &= self.reconcile_mass_status(mass_status);
+        }
+
+        result
+    }
+
+    /// Reconciles the cached execution state against the `mass_status`, inferring the events
+    /// for any orders and fills missed, returning whether all state was reconciled.
+    pub fn reconcile_mass_status(&mut self, mass_status: &ExecutionMassStatus) -> bool {
+        let order_reports = mass_status.order_reports();
+        let mut fill_reports = mass_status.fill_reports();
+        let position_reports = mass_status.position_reports();
+
+        log::info!(
+            "Reconciling {} order, {} fill and {} position reports from {}",
+            order_reports.len(),
+            fill_reports.values().map(Vec::len).sum::<usize>(),
+            position_reports.values().map(Vec::len).sum::<usize>(),
+            mass_status.client_id,
+        );
+
+        let mut result = true;
+
+        for (venue_order_id, report) in &order_reports {
+            let fills = fill_reports
+                .shift_remove(venue_order_id)
+                .unwrap_or_default();
+            result &= self.reconcile_order_report(report, &fills, mass_status.client_id);
+        }
+
+        // Fills for orders without a status report
+        for (venue_order_id, fills) in &fill_reports {
+            result &= self.reconcile_fill_reports(venue_order_id, fills);
+        }
+
+        for report in position_reports.values().flatten() {
+            result &= is_position_reconciled(&self.cache.borrow(), report);
+        }
+
+        result
+    }
+
+    /// Reconciles the order of the `report` and its `fills`, creating an external order
+    /// where the order is not held in the cache.
+    pub fn reconcile_order_report(
+        &mut self,
+        report: &OrderStatusReport,
+        fills: &[FillReport],
+        client_id: ClientId,
+    ) -> bool {
+        let (client_order_id, order) = {
+            let cache = self.cache.borrow();
+            let client_order_id = report
+                .client_order_id
+                .or_else(|| cache.client_order_id(&report.venue_order_id).copied());
+            let order = client_order_id.and_then(|id| cache.order(&id).cloned());
+            (client_order_id, order)
+        };
+
+        let order = match order {
+            Some(order) => order,
+            None => match self.add_external_order(report, client_order_id, client_id) {
+                Some(order) => order,
+                None => return false,
+            },
+        };
+
+        self.apply_reconciliation(&order, Some(report), fills)
+    }
+
+    fn reconcile_fill_reports(
+        &mut self,
+        venue_order_id: &VenueOrderId,
+        fills: &[FillReport],
+    ) -> bool {
+        let order = {
+            let cache = self.cache.borrow();
+            cache
+                .client_order_id(venue_order_id)
+                .copied()
+                .or_else(|| fills.iter().find_map(|fill| fill.client_order_id))
+                .and_then(|client_order_id| cache.order(&client_order_id).cloned())
+        };
+
+        let Some(order) = order else {
+            log::error!("Cannot reconcile fills: no order found for {venue_order_id}");
+            return false;
+        };
+
+        self.apply_reconciliation(&order, None, fills)
+    }
+
+    fn add_external_order(
+        &mut self,
+        report: &OrderStatusReport,
+        client_order_id: Option<ClientOrderId>,
+        client_id: ClientId,
+    ) -> Option<OrderAny> {
+        let client_order_id =
+            client_order_id.unwrap_or_else(|| ClientOrderId::new(report.venue_order_id.as_str()));
+        let strategy_id = self
+            .external_order_claims
+            .get(&report.instrument_id)
+            .copied()
+            .unwrap_or_else(StrategyId::external);
+        let trader_id = self.msgbus.borrow().trader_id;
+        let ts_init = self.clock.borrow().timestamp_ns();
+
+        let order = match external_order(trader_id, strategy_id, client_order_id, report, ts_init) {
+            Ok(order) => order,
+            Err(e) => {
+                log::error!("Cannot create external order {client_order_id}: {e}");
+                return None;
+            }
+        };
+
+        if let Err(e) =
+            self.cache
+                .borrow_mut()
+                .add_order(order.clone(), None, Some(client_id), false)
+        {
+            log::error!("Error adding external order to cache: {e}");
+            return None;
+        }
+
+        log::info!("Reconciling external order {client_order_id} for {strategy_id}");
+        Some(order)
+    }
+
+    fn apply_reconciliation(
+        &mut self,
+        order: &OrderAny,
+        report: Option<&OrderStatusReport>,
+        fills: &[FillReport],
+    ) -> bool {
+        let instrument_id = order.instrument_id();
+        let Some(instrument) = self.cache.borrow().instrument(&instrument_id).cloned() else {
+            log::error!(
+                "Cannot reconcile {}: no instrument found for {instrument_id}",
+                order.client_order_id(),
+            );
+            return false;
+        };
+
+        let ts_init = self.clock.borrow().timestamp_ns();
+        match reconcile_order(order, report, fills, &instrument, ts_init) {
+            Ok(events) => {
+                for event in &events {
+                    self.handle_event(event);
+                }
+                true
+            }
+            Err(e) => {
+                log::error!("Cannot reconcile {}: {e}", order.client_order_id());
+                false
+            }
+        }
+    }
+
     // -- COMMAND HANDLERS ----------------------------------------------------
 
     fn execute_command(&self, command: TradingCommand) {
@@ -220,15 +751,8 @@ impl ExecutionEngine {
             log::debug!("{RECV}{CMD} {command:?}");
         }
 
-        let client = if let Some(client) = self
-            .clients
-            .get(&command.client_id())
-            .or_else(|| {
-                self.routing_map
-                    .get(&command.instrument_id().venue)
-                    .and_then(|client_id| self.clients.get(client_id))
-            })
-            .or(self.default_client.as_ref())
+        let client = if let Some(client) =
+            self.find_client(Some(&command.client_id()), &command.instrument_id().venue)
         {
             client
         } else {
@@ -244,6 +768,7 @@ impl ExecutionEngine {
             TradingCommand::SubmitOrder(cmd) => self.handle_submit_order(client, cmd),
             TradingCommand::SubmitOrderList(cmd) => self.handle_submit_order_list(client, cmd),
             TradingCommand::ModifyOrder(cmd) => self.handle_modify_order(client, cmd),
+            TradingCommand::BatchModifyOrders(cmd) => self.handle_batch_modify_orders(client, cmd),
             TradingCommand::CancelOrder(cmd) => self.handle_cancel_order(client, cmd),
             TradingCommand::CancelAllOrders(cmd) => self.handle_cancel_all_orders(client, cmd),
             TradingCommand::BatchCancelOrders(cmd) => self.handle_batch_cancel_orders(client, cmd),
@@ -251,12 +776,25 @@ impl ExecutionEngine {
         }
     }
 
+    fn find_client(&self, client_id: Option<&ClientId>, venue: &Venue) -> Option<&ExecutionClient> {
+        client_id
+            .and_then(|client_id| self.clients.get(client_id))
+            .or_else(|| {
+                self.routing_map
+                    .get(venue)
+                    .and_then(|client_id| self.clients.get(client_id))
+            })
+            .or(self.default_client.as_ref())
+    }
+
     fn handle_submit_order(&self, client: &ExecutionClient, command: SubmitOrder) {
         let mut command = command;
         let mut order = command.order.clone();
         let client_order_id = order.client_order_id();
         let instrument_id = order.instrument_id();
 
+        command.position_id = order_position_id(client, command.position_id, &order);
+
         // Check if the order exists in the cache
         if !self.cache.borrow().order_exists(&client_order_id) {
             // Add order to cache in a separate scope to drop the mutable borrow
@@ -277,6 +815,11 @@ impl ExecutionEngine {
                 self.create_order_state_snapshot(&order);
             }
         }
+        self.track_latency(&order, command.ts_init);
+
+        if !self.check_throttle(&order) {
+            return; // Denied
+        }
 
         // Get instrument in a separate scope to manage borrows
         let instrument = {
@@ -307,6 +850,10 @@ impl ExecutionEngine {
             }
         }
 
+        if !self.emulate_order_flags(client, &mut order, command.position_id) {
+            return; // Denied
+        }
+
         command.order = order;
 
         // Send the order to the execution client
@@ -316,22 +863,39 @@ impl ExecutionEngine {
                 &command.order,
                 &format!("failed-to-submit-order-to-client: {e}"),
             );
+        } else {
+            self.track_inflight(client, client_order_id);
+            self.schedule_gtd_expiry(client, &command.order);
         }
     }
 
     fn handle_submit_order_list(&self, client: &ExecutionClient, mut command: SubmitOrderList) {
         let orders = command.order_list.orders.clone();
 
+        if self.config.manage_contingent_orders {
+            // Hold OTO children locally until released by a fill of their parent
+            command
+                .order_list
+                .orders
+                .retain(|order| !is_held_by_oto_parent(order, &orders));
+        }
+
+        let position_ids: HashMap<ClientOrderId, PositionId> = orders
+            .iter()
+            .filter_map(|order| {
+                order_position_id(client, command.position_id, order)
+                    .map(|position_id| (order.client_order_id(), position_id))
+            })
+            .collect();
+
         // Cache orders
         let mut cache = self.cache.borrow_mut();
         for order in &orders {
             if !cache.order_exists(&order.client_order_id()) {
-                if let Err(e) = cache.add_order(
-                    order.clone(),
-                    command.position_id,
-                    Some(command.client_id),
-                    true,
-                ) {
+                let position_id = position_ids.get(&order.client_order_id()).copied();
+                if let Err(e) =
+                    cache.add_order(order.clone(), position_id, Some(command.client_id), true)
+                {
                     log::error!("Error adding order to cache: {e}");
                     return;
                 }
@@ -342,6 +906,18 @@ impl ExecutionEngine {
             }
         }
         drop(cache);
+        for order in &orders {
+            self.track_latency(order, command.ts_init);
+        }
+
+        if let Some(first) = orders.first() {
+            if !self.check_throttle(first) {
+                for order in &orders[1..] {
+                    self.deny_order(order, "strategy-throttled");
+                }
+                return; // Denied
+            }
+        }
 
         // Get instrument from cache
         let cache = self.cache.borrow();
@@ -385,34 +961,221 @@ impl ExecutionEngine {
                 }
             }
         }
+        drop(cache);
+
+        let mut denied_id = None;
+        for order in &mut command.order_list.orders {
+            let position_id = position_ids.get(&order.client_order_id()).copied();
+            if !self.emulate_order_flags(client, order, position_id) {
+                denied_id = Some(order.client_order_id());
+                break;
+            }
+        }
+        if let Some(denied_id) = denied_id {
+            for order in &command.order_list.orders {
+                if order.client_order_id() != denied_id {
+                    self.deny_order(order, &format!("order-list-member-denied {denied_id}"));
+                }
+            }
+            self.cancel_held_orders(&command.order_list.orders);
+            return; // Denied
+        }
+
+        if !client.supports_batch_submit {
+            let ts_now = self.clock.borrow().timestamp_ns();
+            let first = self
+                .sequenced_submits
+                .borrow_mut()
+                .start(command, position_ids, ts_now);
+            if let Some(first) = first {
+                self.submit_sequenced(client, first);
+            }
+            return;
+        }
 
         // Send to execution client
+        let submitted_orders = command.order_list.orders.clone();
         if let Err(e) = client.submit_order_list(command) {
             log::error!("Error submitting order list to client: {e}");
-            for order in &orders {
+            for order in &submitted_orders {
                 self.deny_order(
                     order,
                     &format!("failed-to-submit-order-list-to-client: {e}"),
                 );
             }
+            self.cancel_held_orders(&submitted_orders);
+        } else {
+            for order in &submitted_orders {
+                self.track_inflight(client, order.client_order_id());
+                self.schedule_gtd_expiry(client, order);
+            }
+        }
+    }
+
+    /// Submits the next order of a sequenced order list, rolling back the list if the order
+    /// cannot be submitted.
+    fn submit_sequenced(&self, client: &ExecutionClient, command: SubmitOrder) {
+        let order = command.order.clone();
+        if let Err(e) = client.submit_order(command) {
+            log::error!("Error submitting sequenced order to client: {e}");
+            self.deny_order(&order, &format!("failed-to-submit-order-to-client: {e}"));
+            let rollback = self
+                .sequenced_submits
+                .borrow_mut()
+                .on_rejected(&order.client_order_id());
+            if let Some(rollback) = rollback {
+                self.rollback_sequenced(client, rollback);
+            }
+            self.cancel_held_orders(&[order]);
+        } else {
+            self.track_inflight(client, order.client_order_id());
+            self.schedule_gtd_expiry(client, &order);
+        }
+    }
+
+    /// Rolls back a sequenced order list after one of its orders was rejected, canceling the
+    /// orders accepted and denying the orders not yet submitted.
+    fn rollback_sequenced(&self, client: &ExecutionClient, rollback: SequenceRollback) {
+        let ts_now = self.clock.borrow().timestamp_ns();
+        for client_order_id in rollback.accepted {
+            let cancel = order_cancel(
+                &self.cache.borrow(),
+                client.client_id,
+                &client_order_id,
+                ts_now,
+            );
+            if let Some(cancel) = cancel {
+                self.handle_cancel_order(client, cancel);
+            }
+        }
+
+        let mut denied = Vec::new();
+        for client_order_id in rollback.unsubmitted {
+            let order = self.cache.borrow().order(&client_order_id).cloned();
+            if let Some(order) = order {
+                self.deny_order(&order, "order-list-rolled-back");
+                denied.push(order);
+            }
+        }
+        self.cancel_held_orders(&denied);
+    }
+
+    /// Advances the sequenced order list of the order the `event` applies to, submitting the
+    /// next order on acceptance, or rolling back the list on rejection.
+    fn advance_sequenced(&self, event: &OrderEventAny) {
+        let client_order_id = event.client_order_id();
+        if !self.sequenced_submits.borrow().is_pending(&client_order_id) {
+            return;
+        }
+
+        let client_id = self.cache.borrow().client_id(&client_order_id).copied();
+        let Some(client) = self.find_client(client_id.as_ref(), &event.instrument_id().venue)
+        else {
+            log::error!("No execution client found for sequenced order {client_order_id}");
+            return;
+        };
+
+        match event {
+            OrderEventAny::Accepted(_)
+            | OrderEventAny::PartiallyFilled(_)
+            | OrderEventAny::Filled(_) => {
+                let ts_now = self.clock.borrow().timestamp_ns();
+                let next = self
+                    .sequenced_submits
+                    .borrow_mut()
+                    .on_accepted(&client_order_id, ts_now);
+                if let Some(next) = next {
+                    self.submit_sequenced(client, next);
+                }
+            }
+            OrderEventAny::Rejected(_)
+            | OrderEventAny::Denied(_)
+            | OrderEventAny::Canceled(_)
+            | OrderEventAny::Expired(_) => {
+                let rollback = self
+                    .sequenced_submits
+                    .borrow_mut()
+                    .on_rejected(&client_order_id);
+                if let Some(rollback) = rollback {
+                    log::warn!("Rolling back order list after {event}");
+                    self.rollback_sequenced(client, rollback);
+                }
+            }
+            _ => {}
         }
     }
 
     fn handle_modify_order(&self, client: &ExecutionClient, command: ModifyOrder) {
+        if self.config.modify_coalesce_interval_ms > 0 {
+            self.modify_coalescer.borrow_mut().push(command);
+            return;
+        }
+
+        self.send_modify_order(client, command);
+    }
+
+    fn handle_batch_modify_orders(&self, client: &ExecutionClient, command: BatchModifyOrders) {
+        if !client.supports_batch_modify || command.modifies.len() == 1 {
+            for modify in command.modifies {
+                self.send_modify_order(client, modify);
+            }
+            return;
+        }
+
+        let client_order_ids: Vec<ClientOrderId> = command
+            .modifies
+            .iter()
+            .map(|modify| modify.client_order_id)
+            .collect();
+        if let Err(e) = client.batch_modify_orders(command) {
+            log::error!("Error batch modifying orders: {e}");
+        } else {
+            for client_order_id in client_order_ids {
+                self.track_inflight(client, client_order_id);
+            }
+        }
+    }
+
+    fn send_modify_order(&self, client: &ExecutionClient, command: ModifyOrder) {
+        let client_order_id = command.client_order_id;
         if let Err(e) = client.modify_order(command) {
             log::error!("Error modifying order: {e}");
+        } else {
+            self.track_inflight(client, client_order_id);
         }
     }
 
     fn handle_cancel_order(&self, client: &ExecutionClient, command: CancelOrder) {
+        let client_order_id = command.client_order_id;
+
+        // A pending modify is superseded by the cancel
+        self.modify_coalescer.borrow_mut().remove(&client_order_id);
+
         if let Err(e) = client.cancel_order(command) {
             log::error!("Error canceling order: {e}");
+        } else {
+            self.track_inflight(client, client_order_id);
         }
     }
 
     fn handle_cancel_all_orders(&self, client: &ExecutionClient, command: CancelAllOrders) {
-        if let Err(e) = client.cancel_all_orders(command) {
-            log::error!("Error canceling all orders: {e}");
+        if client.supports_mass_cancel {
+            if let Err(e) = client.cancel_all_orders(command) {
+                log::error!("Error canceling all orders: {e}");
+            }
+            return;
+        }
+
+        // Emulate the mass cancel by canceling each matching open order
+        let cancels = emulated_cancels(&self.cache.borrow(), &command);
+
+        if cancels.is_empty() {
+            log::info!("No open orders to cancel for {command}");
+            return;
+        }
+
+        for cancel in cancels {
+            self.handle_cancel_order(client, cancel);
         }
     }
 
@@ -473,6 +1236,21 @@ impl ExecutionEngine {
             log::debug!("{RECV}{EVT} {event:?}");
         }
 
+        // Apply the cancel of an order expired by the engine as its expiration
+        let expired;
+        let event = match event {
+            OrderEventAny::Canceled(canceled)
+                if self
+                    .gtd_expiries
+                    .borrow_mut()
+                    .take_expiring(&canceled.client_order_id) =>
+            {
+                expired = OrderEventAny::Expired(expired_from_canceled(canceled));
+                &expired
+            }
+            _ => event,
+        };
+
         let client_order_id = event.client_order_id();
         let cache = self.cache.borrow();
         let mut order = if let Some(order) = cache.order(&client_order_id) {
@@ -520,7 +1298,7 @@ impl ExecutionEngine {
 
         drop(cache);
         match event {
-            OrderEventAny::Filled(order_filled) => {
+            OrderEventAny::PartiallyFilled(order_filled) | OrderEventAny::Filled(order_filled) => {
                 let oms_type = self.determine_oms_type(order_filled);
                 let position_id = self.determine_position_id(*order_filled, oms_type);
 
@@ -530,11 +1308,238 @@ impl ExecutionEngine {
                     order_filled.position_id = Some(position_id);
                 }
 
-                self.apply_event_to_order(&mut order, OrderEventAny::Filled(order_filled));
-                self.handle_order_fill(&order, order_filled, oms_type);
+                let event = if matches!(event, OrderEventAny::PartiallyFilled(_)) {
+                    OrderEventAny::PartiallyFilled(order_filled)
+                } else {
+                    OrderEventAny::Filled(order_filled)
+                };
+                if self.apply_event_to_order(&mut order, event.clone()) {
+                    self.handle_order_fill(&order, order_filled, oms_type);
+                    self.publish_spawn_progress(&order);
+                    self.handle_contingencies(&order, &event);
+                }
             }
             _ => {
-                self.apply_event_to_order(&mut order, event.clone());
+                if self.apply_event_to_order(&mut order, event.clone()) {
+                    self.handle_contingencies(&order, event);
+                }
+            }
+        }
+
+        match event {
+            OrderEventAny::Rejected(rejected) => {
+                let ts_now = self.clock.borrow().timestamp_ns();
+                let until = self
+                    .throttles
+                    .borrow_mut()
+                    .on_rejected(rejected.strategy_id, ts_now);
+                if let Some(until) = until {
+                    self.publish_throttled(
+                        rejected.strategy_id,
+                        ThrottleReason::RejectCooldown,
+                        Some(until),
+                    );
+                }
+            }
+            OrderEventAny::Accepted(accepted) => {
+                self.throttles
+                    .borrow_mut()
+                    .on_accepted(&accepted.strategy_id);
+            }
+            OrderEventAny::CancelRejected(rejected) => {
+                self.gtd_expiries
+                    .borrow_mut()
+                    .take_expiring(&rejected.client_order_id);
+            }
+            _ => {}
+        }
+
+        self.advance_sequenced(event);
+
+        if order.is_closed() {
+            self.modify_coalescer
+                .borrow_mut()
+                .remove(&order.client_order_id());
+            if self
+                .gtd_expiries
+                .borrow_mut()
+                .remove(&order.client_order_id())
+            {
+                self.clock
+                    .borrow_mut()
+                    .cancel_timer(&gtd_timer_name(&order.client_order_id()));
+            }
+        }
+
+        if !is_inflight_status(order.status()) {
+            self.inflight_orders
+                .borrow_mut()
+                .remove(&order.client_order_id());
+        }
+    }
+
+    fn handle_contingencies(&mut self, order: &OrderAny, event: &OrderEventAny) {
+        if !self.config.manage_contingent_orders {
+            return;
+        }
+
+        let actions = contingency_actions(&self.cache.borrow(), order, event);
+        for action in actions {
+            if self.config.debug {
+                log::debug!("Contingency for {}: {action:?}", order.client_order_id());
+            }
+
+            match action {
+                ContingencyAction::Release(client_order_id, quantity) => {
+                    self.release_contingent_order(&client_order_id, quantity);
+                }
+                ContingencyAction::ModifyQuantity(client_order_id, quantity) => {
+                    self.modify_contingent_order(&client_order_id, quantity);
+                }
+                ContingencyAction::Cancel(client_order_id) => {
+                    self.cancel_contingent_order(&client_order_id);
+                }
+            }
+        }
+    }
+
+    fn contingent_order(&self, client_order_id: &ClientOrderId) -> Option<(OrderAny, ClientId)> {
+        let cache = self.cache.borrow();
+        let Some(order) = cache.order(client_order_id) else {
+            log::error!("Cannot find contingent order {client_order_id}");
+            return None;
+        };
+        let client_id = cache
+            .client_id(client_order_id)
+            .copied()
+            .unwrap_or_else(|| ClientId::new(order.instrument_id().venue.as_str()));
+        Some((order.clone(), client_id))
+    }
+
+    fn release_contingent_order(&self, client_order_id: &ClientOrderId, quantity: Quantity) {
+        let Some((mut order, client_id)) = self.contingent_order(client_order_id) else {
+            return;
+        };
+
+        order.set_quantity(quantity);
+        order.set_leaves_qty(quantity);
+        if let Err(e) = self.cache.borrow_mut().update_order(&order) {
+            log::error!("Error updating order in cache: {e}");
+            return;
+        }
+
+        let ts_init = self.clock.borrow().timestamp_ns();
+        let command = match SubmitOrder::new(
+            order.trader_id(),
+            client_id,
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            order.venue_order_id().unwrap_or_default(),
+            order.clone(),
+            order.exec_algorithm_id(),
+            order.position_id(),
+            UUID4::new(),
+            ts_init,
+        ) {
+            Ok(command) => command,
+            Err(e) => {
+                log::error!("Cannot release contingent order {client_order_id}: {e}");
+                return;
+            }
+        };
+
+        log::info!("Releasing contingent order {client_order_id} for {quantity}");
+        self.execute_command(TradingCommand::SubmitOrder(command));
+    }
+
+    fn modify_contingent_order(&self, client_order_id: &ClientOrderId, quantity: Quantity) {
+        let Some((order, client_id)) = self.contingent_order(client_order_id) else {
+            return;
+        };
+
+        let ts_init = self.clock.borrow().timestamp_ns();
+        match ModifyOrder::new(
+            order.trader_id(),
+            client_id,
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            order.venue_order_id().unwrap_or_default(),
+            Some(quantity),
+            None,
+            None,
+            UUID4::new(),
+            ts_init,
+        ) {
+            Ok(command) => self.execute_command(TradingCommand::ModifyOrder(command)),
+            Err(e) => log::error!("Cannot modify contingent order {client_order_id}: {e}"),
+        }
+    }
+
+    fn cancel_contingent_order(&mut self, client_order_id: &ClientOrderId) {
+        let Some((order, client_id)) = self.contingent_order(client_order_id) else {
+            return;
+        };
+
+        let ts_init = self.clock.borrow().timestamp_ns();
+
+        // A held order was never submitted, so is canceled locally
+        if order.status() == OrderStatus::Initialized {
+            let canceled = canceled_locally(&order, ts_init);
+            self.handle_event(&OrderEventAny::Canceled(canceled));
+            return;
+        }
+
+        match CancelOrder::new(
+            order.trader_id(),
+            client_id,
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            order.venue_order_id().unwrap_or_default(),
+            UUID4::new(),
+            ts_init,
+        ) {
+            Ok(command) => self.execute_command(TradingCommand::CancelOrder(command)),
+            Err(e) => log::error!("Cannot cancel contingent order {client_order_id}: {e}"),
+        }
+    }
+
+    /// Cancels locally the contingent orders held for the `parents`, where a parent was denied
+    /// before it could be submitted.
+    fn cancel_held_orders(&self, parents: &[OrderAny]) {
+        if !self.config.manage_contingent_orders {
+            return;
+        }
+
+        for parent in parents {
+            let actions = {
+                let cache = self.cache.borrow();
+                let Some(parent) = cache.order(&parent.client_order_id()) else {
+                    continue;
+                };
+                if parent.status() != OrderStatus::Denied {
+                    continue;
+                }
+                contingency_actions(&cache, parent, parent.last_event())
+            };
+
+            for action in actions {
+                let ContingencyAction::Cancel(client_order_id) = action else {
+                    continue;
+                };
+                let order = self.cache.borrow().order(&client_order_id).cloned();
+                let Some(mut order) = order else {
+                    continue;
+                };
+                if order.status() != OrderStatus::Initialized {
+                    continue; // Not held
+                }
+
+                let ts_init = self.clock.borrow().timestamp_ns();
+                let canceled = canceled_locally(&order, ts_init);
+                self.apply_event_to_order(&mut order, OrderEventAny::Canceled(canceled));
             }
         }
     }
@@ -545,18 +1550,36 @@ impl ExecutionEngine {
             return *oms_type;
         }
 
-        // Use native venue OMS
-        if let Some(client_id) = self.routing_map.get(&fill.instrument_id.venue) {
+        // Use native venue OMS
+        if let Some(client_id) = self.routing_map.get(&fill.instrument_id.venue) {
+            if let Some(client) = self.clients.get(client_id) {
+                return client.oms_type;
+            }
+        }
+
+        if let Some(client) = &self.default_client {
+            return client.oms_type;
+        }
+
+        OmsType::Netting // Default fallback
+    }
+
+    fn determine_position_mode(&self, instrument_id: &InstrumentId) -> PositionMode {
+        if let Some(client_id) = self.routing_map.get(&instrument_id.venue) {
             if let Some(client) = self.clients.get(client_id) {
-                return client.oms_type;
+                return client.position_mode(instrument_id);
             }
         }
 
         if let Some(client) = &self.default_client {
-            return client.oms_type;
+            return client.position_mode(instrument_id);
         }
 
-        OmsType::Netting // Default fallback
+        self.config
+            .position_modes
+            .get(instrument_id)
+            .copied()
+            .unwrap_or_default()
     }
 
     fn determine_position_id(&mut self, fill: OrderFilled, oms_type: OmsType) -> PositionId {
@@ -610,20 +1633,38 @@ impl ExecutionEngine {
     }
 
     fn determine_netting_position_id(&self, fill: OrderFilled) -> PositionId {
+        if self.determine_position_mode(&fill.instrument_id) == PositionMode::Hedge {
+            let cache = self.cache.borrow();
+            if let Some(position_id) = cache.position_id(&fill.client_order_id) {
+                return *position_id;
+            }
+            if let Some(order) = cache.order(&fill.client_order_id) {
+                return order_hedge_position_id(order);
+            }
+        }
+
         PositionId::new(format!("{}-{}", fill.instrument_id, fill.strategy_id))
     }
 
-    fn apply_event_to_order(&self, order: &mut OrderAny, event: OrderEventAny) {
+    fn apply_event_to_order(&self, order: &mut OrderAny, event: OrderEventAny) -> bool {
         if let Err(e) = order.apply(event.clone()) {
             match e {
                 OrderError::InvalidStateTransition => {
-                    log::warn!("InvalidStateTrigger: {e}, did not apply {event}");
+                    let diagnostic = InvalidTransitionDiagnostic::new(
+                        order,
+                        event.clone(),
+                        self.clock.borrow().timestamp_ns(),
+                    );
+                    log::warn!("InvalidStateTrigger: {e}, did not apply {event}: {diagnostic}");
+                    if let Err(e) = diagnostic.persist(&mut self.cache.borrow_mut()) {
+                        log::error!("Error persisting invalid transition diagnostic: {e}");
+                    }
                 }
                 _ => {
                     log::error!("Error applying event: {e}, did not apply {event}");
                 }
             }
-            return;
+            return false;
         }
 
         if let Err(e) = self.cache.borrow_mut().update_order(order) {
@@ -636,9 +1677,13 @@ impl ExecutionEngine {
             .get_event_orders_topic(event.strategy_id());
         msgbus.publish(&topic, order);
 
+        drop(msgbus);
+
         if self.config.snapshot_orders {
             self.create_order_state_snapshot(order);
         }
+
+        true
     }
 
     fn handle_order_fill(&mut self, order: &OrderAny, fill: OrderFilled, oms_type: OmsType) {
@@ -668,36 +1713,40 @@ impl ExecutionEngine {
             return;
         };
 
-        let mut position = match self.cache.borrow().position(&position_id) {
-            Some(pos) if !pos.is_closed() => pos.clone(),
+        let cached_position = self.cache.borrow().position(&position_id).cloned();
+        let position = match cached_position {
+            Some(mut position) if !position.is_closed() => {
+                if self.will_flip_position(&position, fill) {
+                    self.flip_position(instrument, &mut position, fill, oms_type);
+                } else {
+                    self.update_position(&mut position, fill);
+                }
+                position
+            }
+            // Opening the position applies the fill
             _ => self
-                .open_position(instrument.clone(), None, fill, oms_type)
+                .open_position(instrument, None, fill, oms_type)
                 .unwrap(),
         };
 
-        if self.will_flip_position(&position, fill) {
-            self.flip_position(instrument, &mut position, fill, oms_type);
-        } else {
-            self.update_position(&mut position, fill);
-        }
-
         if matches!(order.contingency_type(), Some(ContingencyType::Oto)) && position.is_open() {
             for client_order_id in order.linked_order_ids().unwrap_or_default() {
                 let mut cache = self.cache.borrow_mut();
-                let contingent_order = cache.mut_order(&client_order_id);
-                if let Some(contingent_order) = contingent_order {
-                    if contingent_order.position_id().is_none() {
-                        contingent_order.set_position_id(Some(position_id));
-
-                        if let Err(e) = self.cache.borrow_mut().add_position_id(
-                            &position_id,
-                            &contingent_order.instrument_id().venue,
-                            &contingent_order.client_order_id(),
-                            &contingent_order.strategy_id(),
-                        ) {
-                            log::error!("Failed to add position ID: {e}");
-                        }
-                    }
+                let Some(contingent_order) = cache.mut_order(&client_order_id) else {
+                    continue;
+                };
+                if contingent_order.position_id().is_some() {
+                    continue;
+                }
+
+                contingent_order.set_position_id(Some(position_id));
+                let venue = contingent_order.instrument_id().venue;
+                let strategy_id = contingent_order.strategy_id();
+
+                if let Err(e) =
+                    cache.add_position_id(&position_id, &venue, &client_order_id, &strategy_id)
+                {
+                    log::error!("Failed to add position ID: {e}");
                 }
             }
         }
@@ -934,6 +1983,80 @@ impl ExecutionEngine {
         }
     }
 
+    /// Emulates the post-only and reduce-only flags of the `order` when not supported by the
+    /// venue of the `client`, denying a post-only order which would cross the market and
+    /// capping a reduce-only order (with an `OrderUpdated` event) at the net quantity of the
+    /// positions it reduces.
+    ///
+    /// Returns whether the order may be submitted.
+    fn emulate_order_flags(
+        &self,
+        client: &ExecutionClient,
+        order: &mut OrderAny,
+        position_id: Option<PositionId>,
+    ) -> bool {
+        let instrument_id = order.instrument_id();
+
+        if !client.supports_post_only
+            && post_only_would_cross(order, self.cache.borrow().quote(&instrument_id))
+        {
+            self.deny_order(order, "post-only-would-cross");
+            return false;
+        }
+
+        if !client.supports_reduce_only {
+            let check = {
+                let cache = self.cache.borrow();
+                let positions: Vec<&Position> = match position_id {
+                    Some(position_id) => cache
+                        .position(&position_id)
+                        .filter(|position| position.is_open())
+                        .into_iter()
+                        .collect(),
+                    None => cache.positions_open(None, Some(&instrument_id), None, None),
+                };
+                reduce_only_check(order, &positions)
+            };
+
+            match check {
+                ReduceOnlyCheck::Unchanged => {}
+                ReduceOnlyCheck::Capped(quantity) => {
+                    log::warn!(
+                        "Capping reduce-only order {} quantity {} at position quantity {quantity}",
+                        order.client_order_id(),
+                        order.quantity(),
+                    );
+                    let ts_init = self.clock.borrow().timestamp_ns();
+                    let updated = OrderUpdated::new(
+                        order.trader_id(),
+                        order.strategy_id(),
+                        instrument_id,
+                        order.client_order_id(),
+                        quantity,
+                        UUID4::new(),
+                        ts_init,
+                        ts_init,
+                        false,
+                        order.venue_order_id(),
+                        order.account_id(),
+                        None,
+                        None,
+                    );
+                    if !self.apply_event_to_order(order, OrderEventAny::Updated(updated)) {
+                        self.deny_order(order, "reduce-only-quantity-not-capped");
+                        return false;
+                    }
+                }
+                ReduceOnlyCheck::Denied => {
+                    self.deny_order(order, "reduce-only-would-increase-position");
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
     fn set_order_base_qty(&self, order: &mut OrderAny, base_qty: Quantity) {
         log::info!(
             "Setting {} order quote quantity {} to base quantity {}",
@@ -992,6 +2115,80 @@ impl ExecutionEngine {
         }
     }
 
+    /// Checks the submission of the `order` against the throttles of its strategy, denying the
+    /// order if throttled. Returns whether the order may be submitted.
+    fn check_throttle(&self, order: &OrderAny) -> bool {
+        let strategy_id = order.strategy_id();
+        let open_orders = {
+            let cache = self.cache.borrow();
+            cache.orders_open_count(None, None, Some(&strategy_id), None)
+                + cache.orders_inflight_count(None, None, Some(&strategy_id), None)
+        };
+        let ts_now = self.clock.borrow().timestamp_ns();
+
+        let result = self
+            .throttles
+            .borrow_mut()
+            .check_submit(strategy_id, open_orders, ts_now);
+        match result {
+            Ok(()) => true,
+            Err((reason, until, engaged)) => {
+                if engaged {
+                    self.publish_throttled(strategy_id, reason, until);
+                }
+                self.deny_order(order, &format!("strategy-throttled {reason}"));
+                false
+            }
+        }
+    }
+
+    fn publish_throttled(
+        &self,
+        strategy_id: StrategyId,
+        reason: ThrottleReason,
+        until: Option<UnixNanos>,
+    ) {
+        log::warn!("Throttle {reason} engaged for {strategy_id} until {until:?}");
+
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let mut msgbus = self.msgbus.borrow_mut();
+        let event = StrategyThrottled {
+            trader_id: msgbus.trader_id,
+            strategy_id,
+            reason,
+            until,
+            event_id: UUID4::new(),
+            ts_event: ts_now,
+            ts_init: ts_now,
+        };
+        let topic = msgbus.switchboard.get_event_throttle_topic(strategy_id);
+        msgbus.publish(&topic, &event);
+    }
+
+    /// Publishes the execution progress of the primary order the filled `order` was spawned
+    /// from, so strategies can track the slicing of the primary order.
+    fn publish_spawn_progress(&self, order: &OrderAny) {
+        if !order.is_spawned() {
+            return;
+        }
+        let Some(exec_spawn_id) = order.exec_spawn_id() else {
+            return;
+        };
+        let Some(progress) = self.cache.borrow().exec_spawn_progress(&exec_spawn_id) else {
+            return;
+        };
+
+        if self.config.debug {
+            log::debug!("{progress}");
+        }
+
+        let mut msgbus = self.msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_event_spawn_topic(order.strategy_id());
+        msgbus.publish(&topic, &progress);
+    }
+
     fn deny_order(&self, order: &OrderAny, reason: &str) {
         log::error!(
             "Order denied: {reason}, order ID: {}",
@@ -1033,6 +2230,47 @@ impl ExecutionEngine {
     }
 }
 
+/// Returns the position ID to submit the `order` with, routing the order to the position of
+/// its side when the venue is in hedge mode.
+fn order_position_id(
+    client: &ExecutionClient,
+    position_id: Option<PositionId>,
+    order: &OrderAny,
+) -> Option<PositionId> {
+    position_id.or_else(|| {
+        client
+            .venue_position_side(order)
+            .map(|_| order_hedge_position_id(order))
+    })
+}
+
+/// Returns the event canceling the `order` locally, where it was never submitted.
+fn canceled_locally(order: &OrderAny, ts_init: UnixNanos) -> OrderCanceled {
+    OrderCanceled::new(
+        order.trader_id(),
+        order.strategy_id(),
+        order.instrument_id(),
+        order.client_order_id(),
+        UUID4::new(),
+        ts_init,
+        ts_init,
+        false,
+        order.venue_order_id(),
+        order.account_id(),
+    )
+}
+
+/// Sends the `message` to the handler registered at the `endpoint`.
+///
+/// The message bus borrow is released before the message is handled, as the engine handlers
+/// borrow the bus again to publish (and clients may reply synchronously to commands).
+fn send_to_endpoint(msgbus: &RefCell<MessageBus>, endpoint: Ustr, message: &dyn Any) {
+    let handler = msgbus.borrow().get_endpoint(endpoint).cloned();
+    if let Some(handler) = handler {
+        handler.0.handle(message);
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -1040,10 +2278,33 @@ impl ExecutionEngine {
 mod tests {
     use std::{cell::RefCell, rc::Rc};
 
-    use nautilus_common::{cache::Cache, clock::TestClock, msgbus::MessageBus};
-    use rstest::fixture;
-
-    use super::*;
+    use nautilus_common::{
+        cache::Cache,
+        clock::TestClock,
+        messages::data::DataResponse,
+        msgbus::{
+            handler::{MessageHandler, ShareableMessageHandler},
+            stubs::{get_message_saving_handler, get_saved_messages},
+            MessageBus,
+        },
+    };
+    use nautilus_core::time::get_atomic_clock_static;
+    use nautilus_model::{
+        accounts::{stubs::margin_account, AccountAny},
+        data::{Data, QuoteTick},
+        enums::{AccountType, OrderType, TimeInForce},
+        events::{account::stubs::margin_account_state, OrderRejected},
+        identifiers::{AccountId, ExecAlgorithmId, OrderListId, TraderId},
+        instruments::stubs::audusd_sim,
+        orders::{
+            stubs::{TestOrderEventStubs, TestOrderStubs},
+            ExecSpawnProgress, OrderList, OrderTestBuilder,
+        },
+    };
+    use rstest::{fixture, rstest};
+    use ustr::Ustr;
+
+    use super::{journal::FileJournalStore, *};
 
     #[fixture]
     fn msgbus() -> MessageBus {
@@ -1070,5 +2331,1307 @@ mod tests {
         ExecutionEngine::new(clock, cache, msgbus, config)
     }
 
-    // TODO: After Implementing ExecutionClient & Strategy
+    type EngineCallback = Box<dyn Fn(&dyn Any)>;
+
+    struct EngineHandler {
+        id: Ustr,
+        callback: EngineCallback,
+    }
+
+    impl MessageHandler for EngineHandler {
+        fn id(&self) -> Ustr {
+            self.id
+        }
+
+        fn handle(&self, message: &dyn Any) {
+            (self.callback)(message);
+        }
+        fn handle_response(&self, _resp: DataResponse) {}
+        fn handle_data(&self, _data: Data) {}
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    /// Registers the `engine` to handle commands and events sent to the engine endpoints.
+    fn register_engine_handlers(
+        msgbus: &Rc<RefCell<MessageBus>>,
+        engine: &Rc<RefCell<ExecutionEngine>>,
+    ) {
+        let execute_engine = engine.clone();
+        let execute_handler = ShareableMessageHandler(Rc::new(EngineHandler {
+            id: Ustr::from("ExecEngine.execute"),
+            callback: Box::new(move |message: &dyn Any| {
+                let command = message.downcast_ref::<TradingCommand>().unwrap();
+                execute_engine.borrow().execute(command.clone());
+            }),
+        }));
+        let process_engine = engine.clone();
+        let process_handler = ShareableMessageHandler(Rc::new(EngineHandler {
+            id: Ustr::from("ExecEngine.process"),
+            callback: Box::new(move |message: &dyn Any| {
+                let event = message.downcast_ref::<OrderEventAny>().unwrap();
+                process_engine.borrow_mut().process(event);
+            }),
+        }));
+
+        let mut msgbus = msgbus.borrow_mut();
+        let endpoint = msgbus.switchboard.exec_engine_execute;
+        msgbus.register(endpoint, execute_handler);
+        let endpoint = msgbus.switchboard.exec_engine_process;
+        msgbus.register(endpoint, process_handler);
+    }
+
+    #[rstest]
+    fn test_held_oto_children_canceled_locally_when_parent_canceled(
+        msgbus: MessageBus,
+        simple_cache: Cache,
+        clock: TestClock,
+    ) {
+        let parent_id = ClientOrderId::from("O-1");
+        let child_ids = vec![ClientOrderId::from("O-2"), ClientOrderId::from("O-3")];
+        let mut parent = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .client_order_id(parent_id)
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .contingency_type(ContingencyType::Oto)
+            .linked_order_ids(child_ids.clone())
+            .build();
+        let children: Vec<OrderAny> = child_ids
+            .iter()
+            .map(|client_order_id| {
+                OrderTestBuilder::new(OrderType::Limit)
+                    .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+                    .client_order_id(*client_order_id)
+                    .side(OrderSide::Sell)
+                    .price(Price::from("1.10000"))
+                    .quantity(Quantity::from(100_000))
+                    .parent_order_id(parent_id)
+                    .build()
+            })
+            .collect();
+
+        let account_id = AccountId::from("SIM-001");
+        parent
+            .apply(TestOrderEventStubs::order_submitted(&parent, account_id))
+            .unwrap();
+        parent
+            .apply(TestOrderEventStubs::order_accepted(
+                &parent,
+                account_id,
+                VenueOrderId::from("V-1"),
+            ))
+            .unwrap();
+
+        let cache = Rc::new(RefCell::new(simple_cache));
+        for order in std::iter::once(&parent).chain(&children) {
+            cache
+                .borrow_mut()
+                .add_order(order.clone(), None, None, false)
+                .unwrap();
+        }
+
+        let config = ExecutionEngineConfig {
+            manage_contingent_orders: true,
+            ..Default::default()
+        };
+        let mut engine = _get_exec_engine(
+            Rc::new(RefCell::new(msgbus)),
+            cache.clone(),
+            Rc::new(RefCell::new(clock)),
+            Some(config),
+        );
+
+        let canceled = OrderCanceled::new(
+            parent.trader_id(),
+            parent.strategy_id(),
+            parent.instrument_id(),
+            parent_id,
+            UUID4::new(),
+            0.into(),
+            0.into(),
+            false,
+            parent.venue_order_id(),
+            parent.account_id(),
+        );
+        engine.process(&OrderEventAny::Canceled(canceled));
+
+        let cache = cache.borrow();
+        for client_order_id in std::iter::once(&parent_id).chain(&child_ids) {
+            assert_eq!(
+                cache.order(client_order_id).unwrap().status(),
+                OrderStatus::Canceled
+            );
+        }
+    }
+
+    #[rstest]
+    fn test_reconcile_mass_status_with_cached_and_external_orders(
+        msgbus: MessageBus,
+        simple_cache: Cache,
+        clock: TestClock,
+    ) {
+        let instrument = audusd_sim();
+        let account_id = AccountId::from("SIM-001");
+        let mut order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument.id)
+            .client_order_id(ClientOrderId::from("O-1"))
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        order
+            .apply(TestOrderEventStubs::order_submitted(&order, account_id))
+            .unwrap();
+
+        let cache = Rc::new(RefCell::new(simple_cache));
+        cache
+            .borrow_mut()
+            .add_instrument(InstrumentAny::CurrencyPair(instrument))
+            .unwrap();
+        cache
+            .borrow_mut()
+            .add_order(order, None, None, false)
+            .unwrap();
+
+        let mut engine = _get_exec_engine(
+            Rc::new(RefCell::new(msgbus)),
+            cache.clone(),
+            Rc::new(RefCell::new(clock)),
+            None,
+        );
+
+        let report = |client_order_id: Option<ClientOrderId>, venue_order_id: &str| {
+            OrderStatusReport::new(
+                account_id,
+                instrument.id,
+                client_order_id,
+                VenueOrderId::from(venue_order_id),
+                OrderSide::Buy,
+                OrderType::Limit,
+                TimeInForce::Gtc,
+                OrderStatus::Accepted,
+                Quantity::from(100_000),
+                Quantity::from(0),
+                0.into(),
+                0.into(),
+                0.into(),
+                None,
+            )
+            .with_price(Price::from("1.00000"))
+        };
+        let mut mass_status = ExecutionMassStatus::new(
+            ClientId::from("SIM"),
+            account_id,
+            Venue::from("SIM"),
+            0.into(),
+            None,
+        );
+        mass_status.add_order_reports(vec![
+            report(Some(ClientOrderId::from("O-1")), "V-1"),
+            report(None, "V-2"),
+        ]);
+
+        assert!(engine.reconcile_mass_status(&mass_status));
+
+        let cache = cache.borrow();
+        let order = cache.order(&ClientOrderId::from("O-1")).unwrap();
+        assert_eq!(order.status(), OrderStatus::Accepted);
+        assert_eq!(order.venue_order_id(), Some(VenueOrderId::from("V-1")));
+        let external = cache.order(&ClientOrderId::from("V-2")).unwrap();
+        assert_eq!(external.status(), OrderStatus::Accepted);
+        assert_eq!(external.strategy_id(), StrategyId::external());
+    }
+
+    #[rstest]
+    fn test_inflight_check_queries_then_resolves_unacknowledged_order(
+        msgbus: MessageBus,
+        simple_cache: Cache,
+        clock: TestClock,
+    ) {
+        let mut order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .client_order_id(ClientOrderId::from("O-1"))
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        order
+            .apply(TestOrderEventStubs::order_submitted(
+                &order,
+                AccountId::from("SIM-001"),
+            ))
+            .unwrap();
+
+        let cache = Rc::new(RefCell::new(simple_cache));
+        cache
+            .borrow_mut()
+            .add_order(order.clone(), None, None, false)
+            .unwrap();
+
+        let msgbus = Rc::new(RefCell::new(msgbus));
+        let execute_handler = get_message_saving_handler::<TradingCommand>(None);
+        let process_handler = get_message_saving_handler::<OrderEventAny>(None);
+        msgbus
+            .borrow_mut()
+            .register("ExecEngine.execute", execute_handler.clone());
+        msgbus
+            .borrow_mut()
+            .register("ExecEngine.process", process_handler.clone());
+
+        let clock = Rc::new(RefCell::new(clock));
+        let config = ExecutionEngineConfig {
+            inflight_check_interval_ms: 1,
+            inflight_check_threshold_ms: 1,
+            inflight_check_retries: 1,
+            ..Default::default()
+        };
+        let engine = _get_exec_engine(msgbus, cache, clock.clone(), Some(config));
+        engine.inflight_orders.borrow_mut().track(
+            order.client_order_id(),
+            ClientId::from("SIM"),
+            0.into(),
+        );
+        engine.start_inflight_check().unwrap();
+
+        let events = clock
+            .borrow_mut()
+            .advance_time((2 * NANOSECONDS_IN_MILLISECOND).into(), true);
+        let handlers = clock.borrow().match_handlers(events);
+        for handler in handlers {
+            handler.run();
+        }
+
+        let commands = get_saved_messages::<TradingCommand>(execute_handler);
+        let resolved = get_saved_messages::<OrderEventAny>(process_handler);
+        assert!(matches!(commands[..], [TradingCommand::QueryOrder(_)]));
+        assert!(matches!(resolved[..], [OrderEventAny::Rejected(_)]));
+        assert!(engine.inflight_orders.borrow().is_empty());
+    }
+
+    #[rstest]
+    fn test_inflight_check_resolves_through_registered_engine(
+        msgbus: MessageBus,
+        simple_cache: Cache,
+        clock: TestClock,
+    ) {
+        let mut order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .client_order_id(ClientOrderId::from("O-1"))
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        order
+            .apply(TestOrderEventStubs::order_submitted(
+                &order,
+                AccountId::from("SIM-001"),
+            ))
+            .unwrap();
+
+        let cache = Rc::new(RefCell::new(simple_cache));
+        cache
+            .borrow_mut()
+            .add_order(order.clone(), None, None, false)
+            .unwrap();
+
+        let msgbus = Rc::new(RefCell::new(msgbus));
+        let clock = Rc::new(RefCell::new(clock));
+        let config = ExecutionEngineConfig {
+            inflight_check_interval_ms: 1,
+            inflight_check_threshold_ms: 1,
+            inflight_check_retries: 1,
+            ..Default::default()
+        };
+        let engine = Rc::new(RefCell::new(_get_exec_engine(
+            msgbus.clone(),
+            cache.clone(),
+            clock.clone(),
+            Some(config),
+        )));
+        register_engine_handlers(&msgbus, &engine);
+
+        engine.borrow().inflight_orders.borrow_mut().track(
+            order.client_order_id(),
+            ClientId::from("SIM"),
+            0.into(),
+        );
+        engine.borrow().start_inflight_check().unwrap();
+
+        let events = clock
+            .borrow_mut()
+            .advance_time((2 * NANOSECONDS_IN_MILLISECOND).into(), true);
+        let handlers = clock.borrow().match_handlers(events);
+        for handler in handlers {
+            handler.run();
+        }
+
+        let cache = cache.borrow();
+        let order = cache.order(&order.client_order_id()).unwrap();
+        assert_eq!(order.status(), OrderStatus::Rejected);
+        assert!(engine.borrow().inflight_orders.borrow().is_empty());
+    }
+
+    #[rstest]
+    fn test_modify_coalescing_sends_latest_intent_as_batch(
+        msgbus: MessageBus,
+        simple_cache: Cache,
+        clock: TestClock,
+    ) {
+        let cache = Rc::new(RefCell::new(simple_cache));
+        let msgbus = Rc::new(RefCell::new(msgbus));
+        let execute_handler = get_message_saving_handler::<TradingCommand>(None);
+        msgbus
+            .borrow_mut()
+            .register("ExecEngine.execute", execute_handler.clone());
+
+        let clock = Rc::new(RefCell::new(clock));
+        let config = ExecutionEngineConfig {
+            modify_coalesce_interval_ms: 1,
+            ..Default::default()
+        };
+        let mut engine =
+            _get_exec_engine(msgbus.clone(), cache.clone(), clock.clone(), Some(config));
+        let client = ExecutionClient::new(
+            TraderId::default(),
+            ClientId::from("SIM"),
+            Venue::from("SIM"),
+            OmsType::Netting,
+            AccountId::from("SIM-001"),
+            AccountType::Margin,
+            None,
+            get_atomic_clock_static(),
+            cache,
+            msgbus,
+        );
+        engine.register_client(client).unwrap();
+        engine.start_modify_coalescing().unwrap();
+
+        for (client_order_id, quantity, price) in [
+            ("O-1", Some(50_000), None),
+            ("O-1", None, Some("1.00010")),
+            ("O-2", None, Some("1.10000")),
+            ("O-1", None, Some("1.00020")),
+        ] {
+            engine.execute(TradingCommand::ModifyOrder(ModifyOrder {
+                client_id: ClientId::from("SIM"),
+                instrument_id: InstrumentId::from("AUD/USD.SIM"),
+                client_order_id: ClientOrderId::from(client_order_id),
+                quantity: quantity.map(Quantity::from),
+                price: price.map(Price::from),
+                ..Default::default()
+            }));
+        }
+
+        let events = clock
+            .borrow_mut()
+            .advance_time(NANOSECONDS_IN_MILLISECOND.into(), true);
+        let handlers = clock.borrow().match_handlers(events);
+        for handler in handlers {
+            handler.run();
+        }
+
+        let commands = get_saved_messages::<TradingCommand>(execute_handler);
+        let [TradingCommand::BatchModifyOrders(batch)] = &commands[..] else {
+            panic!("Expected a batch modify, was {commands:?}");
+        };
+        assert_eq!(batch.modifies.len(), 2);
+        assert_eq!(
+            batch.modifies[0].client_order_id,
+            ClientOrderId::from("O-1")
+        );
+        assert_eq!(batch.modifies[0].quantity, Some(Quantity::from(50_000)));
+        assert_eq!(batch.modifies[0].price, Some(Price::from("1.00020")));
+        assert_eq!(
+            batch.modifies[1].client_order_id,
+            ClientOrderId::from("O-2")
+        );
+        assert_eq!(engine.modify_coalescer.borrow().merged_count(), 2);
+    }
+
+    #[rstest]
+    fn test_hedge_mode_holds_long_and_short_positions_concurrently(
+        msgbus: MessageBus,
+        simple_cache: Cache,
+        clock: TestClock,
+    ) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim());
+        let account_id = AccountId::from("SIM-001");
+        let cache = Rc::new(RefCell::new(simple_cache));
+        cache
+            .borrow_mut()
+            .add_instrument(instrument.clone())
+            .unwrap();
+        cache
+            .borrow_mut()
+            .add_account(AccountAny::Margin(margin_account(margin_account_state())))
+            .unwrap();
+
+        let msgbus = Rc::new(RefCell::new(msgbus));
+        let config = ExecutionEngineConfig {
+            position_modes: HashMap::from([(instrument.id(), PositionMode::Hedge)]),
+            ..Default::default()
+        };
+        let mut engine = _get_exec_engine(
+            msgbus.clone(),
+            cache.clone(),
+            Rc::new(RefCell::new(clock)),
+            Some(config),
+        );
+        let client = ExecutionClient::new(
+            TraderId::default(),
+            ClientId::from("SIM"),
+            Venue::from("SIM"),
+            OmsType::Netting,
+            account_id,
+            AccountType::Margin,
+            None,
+            get_atomic_clock_static(),
+            cache.clone(),
+            msgbus,
+        );
+        engine.register_client(client).unwrap();
+
+        for (client_order_id, side) in [("O-1", OrderSide::Buy), ("O-2", OrderSide::Sell)] {
+            let mut order = OrderTestBuilder::new(OrderType::Market)
+                .instrument_id(instrument.id())
+                .client_order_id(ClientOrderId::from(client_order_id))
+                .side(side)
+                .quantity(Quantity::from(100_000))
+                .build();
+            order
+                .apply(TestOrderEventStubs::order_submitted(&order, account_id))
+                .unwrap();
+            order
+                .apply(TestOrderEventStubs::order_accepted(
+                    &order,
+                    account_id,
+                    VenueOrderId::from(client_order_id.replace('O', "V").as_str()),
+                ))
+                .unwrap();
+            cache
+                .borrow_mut()
+                .add_order(order.clone(), None, None, false)
+                .unwrap();
+
+            let OrderEventAny::Filled(mut fill) = TestOrderEventStubs::order_filled(
+                &order,
+                &instrument,
+                None,
+                None,
+                Some(Price::from("1.00000")),
+                None,
+                None,
+                None,
+                None,
+                Some(account_id),
+            ) else {
+                unreachable!()
+            };
+            fill.position_id = None; // Not assigned by the venue
+            engine.process(&OrderEventAny::Filled(fill));
+        }
+
+        let cache = cache.borrow();
+        let positions = cache.positions_open(None, Some(&instrument.id()), None, None);
+        assert_eq!(positions.len(), 2);
+        let long = cache
+            .position(&PositionId::from("AUD/USD.SIM-S-001-LONG"))
+            .unwrap();
+        let short = cache
+            .position(&PositionId::from("AUD/USD.SIM-S-001-SHORT"))
+            .unwrap();
+        assert_eq!(long.side, PositionSide::Long);
+        assert_eq!(short.side, PositionSide::Short);
+    }
+
+    #[rstest]
+    fn test_journal_replays_unprocessed_fill_once_after_restart(
+        msgbus: MessageBus,
+        simple_cache: Cache,
+        clock: TestClock,
+    ) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim());
+        let account_id = AccountId::from("SIM-001");
+        let mut order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(100_000))
+            .build();
+        order
+            .apply(TestOrderEventStubs::order_submitted(&order, account_id))
+            .unwrap();
+        order
+            .apply(TestOrderEventStubs::order_accepted(
+                &order,
+                account_id,
+                VenueOrderId::from("V-1"),
+            ))
+            .unwrap();
+
+        let cache = Rc::new(RefCell::new(simple_cache));
+        cache
+            .borrow_mut()
+            .add_instrument(instrument.clone())
+            .unwrap();
+        cache
+            .borrow_mut()
+            .add_account(AccountAny::Margin(margin_account(margin_account_state())))
+            .unwrap();
+        cache
+            .borrow_mut()
+            .add_order(order.clone(), None, None, false)
+            .unwrap();
+
+        // The fill was journaled before a crash, but never processed
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("journal.jsonl");
+        let open_journal =
+            || ExecutionJournal::new(Box::new(FileJournalStore::new(&path).unwrap())).unwrap();
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            &instrument,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(account_id),
+        );
+        open_journal().record_event(&fill, 0.into()).unwrap();
+
+        let mut engine = _get_exec_engine(
+            Rc::new(RefCell::new(msgbus)),
+            cache.clone(),
+            Rc::new(RefCell::new(clock)),
+            None,
+        );
+        engine.register_journal(open_journal());
+        engine.replay_journal();
+
+        // The venue resends the fill on reconnecting
+        engine.process(&fill);
+
+        let client_order_id = order.client_order_id();
+        assert_eq!(
+            cache.borrow().order(&client_order_id).unwrap().status(),
+            OrderStatus::Filled
+        );
+        let position_id = cache
+            .borrow()
+            .position_id(&client_order_id)
+            .copied()
+            .unwrap();
+        assert_eq!(
+            cache.borrow().position(&position_id).unwrap().quantity,
+            Quantity::from(100_000)
+        );
+        assert_eq!(open_journal().processed_sequence(), 1);
+    }
+
+    #[rstest]
+    fn test_killed_strategy_submit_denied_and_throttle_published(
+        msgbus: MessageBus,
+        simple_cache: Cache,
+        clock: TestClock,
+    ) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim());
+        let cache = Rc::new(RefCell::new(simple_cache));
+        cache
+            .borrow_mut()
+            .add_instrument(instrument.clone())
+            .unwrap();
+        let msgbus = Rc::new(RefCell::new(msgbus));
+        let handler = get_message_saving_handler::<StrategyThrottled>(None);
+        msgbus
+            .borrow_mut()
+            .subscribe("events.throttle.S-001", handler.clone(), None);
+
+        let mut engine = _get_exec_engine(
+            msgbus.clone(),
+            cache.clone(),
+            Rc::new(RefCell::new(clock)),
+            None,
+        );
+        let client = ExecutionClient::new(
+            TraderId::default(),
+            ClientId::from("SIM"),
+            Venue::from("SIM"),
+            OmsType::Netting,
+            AccountId::from("SIM-001"),
+            AccountType::Margin,
+            None,
+            get_atomic_clock_static(),
+            cache.clone(),
+            msgbus,
+        );
+        engine.register_client(client).unwrap();
+
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(100_000))
+            .build();
+        let strategy_id = order.strategy_id();
+        engine.kill_strategy(strategy_id);
+        engine.execute(TradingCommand::SubmitOrder(
+            SubmitOrder::new(
+                TraderId::default(),
+                ClientId::from("SIM"),
+                strategy_id,
+                order.instrument_id(),
+                order.client_order_id(),
+                VenueOrderId::default(),
+                order.clone(),
+                None,
+                None,
+                UUID4::new(),
+                0.into(),
+            )
+            .unwrap(),
+        ));
+
+        let events = get_saved_messages::<StrategyThrottled>(handler);
+        assert!(engine.is_strategy_killed(&strategy_id));
+        assert_eq!(
+            cache
+                .borrow()
+                .order(&order.client_order_id())
+                .unwrap()
+                .status(),
+            OrderStatus::Denied
+        );
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].reason, ThrottleReason::Killed);
+    }
+
+    #[rstest]
+    fn test_spawned_fill_publishes_primary_progress(
+        msgbus: MessageBus,
+        simple_cache: Cache,
+        clock: TestClock,
+    ) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim());
+        let account_id = AccountId::from("SIM-001");
+        let primary_id = ClientOrderId::from("O-1");
+        let build = |client_order_id: ClientOrderId, quantity: u64| {
+            OrderTestBuilder::new(OrderType::Market)
+                .instrument_id(instrument.id())
+                .client_order_id(client_order_id)
+                .exec_algorithm_id(ExecAlgorithmId::from("TWAP"))
+                .exec_spawn_id(primary_id)
+                .side(OrderSide::Buy)
+                .quantity(Quantity::from(quantity))
+                .build()
+        };
+        let primary = build(primary_id, 200_000);
+        let mut child = build(ClientOrderId::from("O-1-E1"), 100_000);
+        child
+            .apply(TestOrderEventStubs::order_submitted(&child, account_id))
+            .unwrap();
+        child
+            .apply(TestOrderEventStubs::order_accepted(
+                &child,
+                account_id,
+                VenueOrderId::from("V-1"),
+            ))
+            .unwrap();
+
+        let cache = Rc::new(RefCell::new(simple_cache));
+        cache
+            .borrow_mut()
+            .add_instrument(instrument.clone())
+            .unwrap();
+        cache
+            .borrow_mut()
+            .add_account(AccountAny::Margin(margin_account(margin_account_state())))
+            .unwrap();
+        cache
+            .borrow_mut()
+            .add_order(primary, None, None, false)
+            .unwrap();
+        cache
+            .borrow_mut()
+            .add_order(child.clone(), None, None, false)
+            .unwrap();
+
+        let msgbus = Rc::new(RefCell::new(msgbus));
+        let handler = get_message_saving_handler::<ExecSpawnProgress>(None);
+        msgbus
+            .borrow_mut()
+            .subscribe("events.spawn.S-001", handler.clone(), None);
+        let mut engine = _get_exec_engine(msgbus, cache, Rc::new(RefCell::new(clock)), None);
+
+        let OrderEventAny::Filled(mut fill) = TestOrderEventStubs::order_filled(
+            &child,
+            &instrument,
+            None,
+            None,
+            Some(Price::from("1.00010")),
+            None,
+            None,
+            None,
+            None,
+            Some(account_id),
+        ) else {
+            unreachable!()
+        };
+        fill.position_id = None;
+        engine.process(&OrderEventAny::Filled(fill));
+
+        let progress = get_saved_messages::<ExecSpawnProgress>(handler);
+        assert_eq!(progress.len(), 1);
+        assert_eq!(progress[0].exec_spawn_id, primary_id);
+        assert_eq!(progress[0].filled_qty, Quantity::from(100_000));
+        assert_eq!(progress[0].leaves_qty, Quantity::from(100_000));
+        assert_eq!(progress[0].avg_px, Some(1.0001));
+    }
+
+    #[rstest]
+    fn test_gtd_order_expired_by_engine_at_venue_without_gtd_support(
+        msgbus: MessageBus,
+        simple_cache: Cache,
+        clock: TestClock,
+    ) {
+        let cache = Rc::new(RefCell::new(simple_cache));
+        let msgbus = Rc::new(RefCell::new(msgbus));
+        let execute_handler = get_message_saving_handler::<TradingCommand>(None);
+        msgbus
+            .borrow_mut()
+            .register("ExecEngine.execute", execute_handler.clone());
+
+        let clock = Rc::new(RefCell::new(clock));
+        let config = ExecutionEngineConfig {
+            manage_gtd_expiry: true,
+            gtd_expiry_tolerance_ms: 1,
+            ..Default::default()
+        };
+        let mut engine =
+            _get_exec_engine(msgbus.clone(), cache.clone(), clock.clone(), Some(config));
+        let client = ExecutionClient::new(
+            TraderId::default(),
+            ClientId::from("SIM"),
+            Venue::from("SIM"),
+            OmsType::Netting,
+            AccountId::from("SIM-001"),
+            AccountType::Margin,
+            None,
+            get_atomic_clock_static(),
+            cache.clone(),
+            msgbus,
+        );
+
+        let account_id = AccountId::from("SIM-001");
+        let mut order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .time_in_force(TimeInForce::Gtd)
+            .expire_time(NANOSECONDS_IN_MILLISECOND.into())
+            .build();
+        order
+            .apply(TestOrderEventStubs::order_submitted(&order, account_id))
+            .unwrap();
+        order
+            .apply(TestOrderEventStubs::order_accepted(
+                &order,
+                account_id,
+                VenueOrderId::from("V-1"),
+            ))
+            .unwrap();
+        cache
+            .borrow_mut()
+            .add_order(order.clone(), None, Some(ClientId::from("SIM")), false)
+            .unwrap();
+        engine.schedule_gtd_expiry(&client, &order);
+
+        let run_timers = |ts: u64| {
+            let events = clock.borrow_mut().advance_time(ts.into(), true);
+            let handlers = clock.borrow().match_handlers(events);
+            for handler in handlers {
+                handler.run();
+            }
+        };
+
+        // Not expired until the clock skew tolerance has passed
+        run_timers(NANOSECONDS_IN_MILLISECOND);
+        assert!(get_saved_messages::<TradingCommand>(execute_handler.clone()).is_empty());
+        run_timers(2 * NANOSECONDS_IN_MILLISECOND);
+
+        let commands = get_saved_messages::<TradingCommand>(execute_handler);
+        let [TradingCommand::CancelOrder(cancel)] = &commands[..] else {
+            panic!("Expected a cancel, was {commands:?}");
+        };
+        assert_eq!(cancel.client_order_id, order.client_order_id());
+
+        engine.process(&OrderEventAny::Canceled(OrderCanceled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            UUID4::new(),
+            (2 * NANOSECONDS_IN_MILLISECOND).into(),
+            (2 * NANOSECONDS_IN_MILLISECOND).into(),
+            false,
+            Some(VenueOrderId::from("V-1")),
+            Some(account_id),
+        )));
+
+        assert_eq!(
+            cache
+                .borrow()
+                .order(&order.client_order_id())
+                .unwrap()
+                .status(),
+            OrderStatus::Expired
+        );
+        assert!(engine
+            .gtd_expiries
+            .borrow()
+            .scheduled(&order.client_order_id())
+            .is_none());
+    }
+
+    #[rstest]
+    fn test_post_only_emulated_for_venue_without_support(
+        msgbus: MessageBus,
+        simple_cache: Cache,
+        clock: TestClock,
+    ) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim());
+        let cache = Rc::new(RefCell::new(simple_cache));
+        cache
+            .borrow_mut()
+            .add_instrument(instrument.clone())
+            .unwrap();
+        cache
+            .borrow_mut()
+            .add_quote(QuoteTick::new(
+                instrument.id(),
+                Price::from("1.00000"),
+                Price::from("1.00010"),
+                Quantity::from(1_000_000),
+                Quantity::from(1_000_000),
+                0.into(),
+                0.into(),
+            ))
+            .unwrap();
+        let msgbus = Rc::new(RefCell::new(msgbus));
+        let mut engine = _get_exec_engine(
+            msgbus.clone(),
+            cache.clone(),
+            Rc::new(RefCell::new(clock)),
+            None,
+        );
+        let mut client = ExecutionClient::new(
+            TraderId::default(),
+            ClientId::from("SIM"),
+            Venue::from("SIM"),
+            OmsType::Netting,
+            AccountId::from("SIM-001"),
+            AccountType::Margin,
+            None,
+            get_atomic_clock_static(),
+            cache.clone(),
+            msgbus,
+        );
+        client.supports_post_only = false;
+        engine.register_client(client).unwrap();
+
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00010"))
+            .quantity(Quantity::from(100_000))
+            .post_only(true)
+            .build();
+        engine.execute(TradingCommand::SubmitOrder(
+            SubmitOrder::new(
+                TraderId::default(),
+                ClientId::from("SIM"),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                VenueOrderId::default(),
+                order.clone(),
+                None,
+                None,
+                UUID4::new(),
+                0.into(),
+            )
+            .unwrap(),
+        ));
+
+        assert_eq!(
+            cache
+                .borrow()
+                .order(&order.client_order_id())
+                .unwrap()
+                .status(),
+            OrderStatus::Denied
+        );
+    }
+
+    #[rstest]
+    fn test_reduce_only_capped_at_net_position_across_strategies(
+        msgbus: MessageBus,
+        simple_cache: Cache,
+        clock: TestClock,
+    ) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim());
+        let cache = Rc::new(RefCell::new(simple_cache));
+        cache
+            .borrow_mut()
+            .add_instrument(instrument.clone())
+            .unwrap();
+        for (strategy_id, position_id, side, quantity) in [
+            ("S-001", "P-001", OrderSide::Buy, 100_000),
+            ("S-002", "P-002", OrderSide::Sell, 60_000),
+        ] {
+            let opening = OrderTestBuilder::new(OrderType::Market)
+                .instrument_id(instrument.id())
+                .strategy_id(StrategyId::from(strategy_id))
+                .side(side)
+                .quantity(Quantity::from(quantity))
+                .build();
+            let OrderEventAny::Filled(fill) = TestOrderEventStubs::order_filled(
+                &opening,
+                &instrument,
+                None,
+                Some(PositionId::from(position_id)),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ) else {
+                unreachable!()
+            };
+            cache
+                .borrow_mut()
+                .add_position(Position::new(&instrument, fill), OmsType::Netting)
+                .unwrap();
+        }
+        let msgbus = Rc::new(RefCell::new(msgbus));
+        let engine = _get_exec_engine(
+            msgbus.clone(),
+            cache.clone(),
+            Rc::new(RefCell::new(clock)),
+            None,
+        );
+        let mut client = ExecutionClient::new(
+            TraderId::default(),
+            ClientId::from("SIM"),
+            Venue::from("SIM"),
+            OmsType::Netting,
+            AccountId::from("SIM-001"),
+            AccountType::Margin,
+            None,
+            get_atomic_clock_static(),
+            cache.clone(),
+            msgbus,
+        );
+        client.supports_reduce_only = false;
+        let reduce_only = |client_order_id: &str, side: OrderSide| {
+            let order = OrderTestBuilder::new(OrderType::Market)
+                .instrument_id(instrument.id())
+                .strategy_id(StrategyId::from("S-001"))
+                .client_order_id(ClientOrderId::from(client_order_id))
+                .side(side)
+                .quantity(Quantity::from(100_000))
+                .reduce_only(true)
+                .build();
+            cache
+                .borrow_mut()
+                .add_order(order.clone(), None, None, false)
+                .unwrap();
+            order
+        };
+
+        let mut sell = reduce_only("O-001", OrderSide::Sell);
+        assert!(engine.emulate_order_flags(&client, &mut sell, None));
+        let mut buy = reduce_only("O-002", OrderSide::Buy);
+        assert!(!engine.emulate_order_flags(&client, &mut buy, None));
+
+        let cache = cache.borrow();
+        let sell = cache.order(&ClientOrderId::from("O-001")).unwrap();
+        assert_eq!(sell.quantity(), Quantity::from(40_000));
+        assert_eq!(sell.leaves_qty(), Quantity::from(40_000));
+        assert!(matches!(sell.last_event(), OrderEventAny::Updated(_)));
+        assert_eq!(
+            cache.order(&ClientOrderId::from("O-002")).unwrap().status(),
+            OrderStatus::Denied
+        );
+    }
+
+    #[rstest]
+    fn test_sequenced_order_list_rolled_back_on_first_reject(
+        msgbus: MessageBus,
+        simple_cache: Cache,
+        clock: TestClock,
+    ) {
+        let cache = Rc::new(RefCell::new(simple_cache));
+        let msgbus = Rc::new(RefCell::new(msgbus));
+        let mut engine = _get_exec_engine(
+            msgbus.clone(),
+            cache.clone(),
+            Rc::new(RefCell::new(clock)),
+            None,
+        );
+        let client = ExecutionClient::new(
+            TraderId::default(),
+            ClientId::from("SIM"),
+            Venue::from("SIM"),
+            OmsType::Netting,
+            AccountId::from("SIM-001"),
+            AccountType::Margin,
+            None,
+            get_atomic_clock_static(),
+            cache.clone(),
+            msgbus,
+        );
+        engine.register_client(client).unwrap();
+
+        let instrument_id = InstrumentId::from("AUD/USD.SIM");
+        let account_id = AccountId::from("SIM-001");
+        let mut orders: Vec<OrderAny> = ["O-1", "O-2"]
+            .iter()
+            .map(|client_order_id| {
+                OrderTestBuilder::new(OrderType::Limit)
+                    .instrument_id(instrument_id)
+                    .client_order_id(ClientOrderId::from(*client_order_id))
+                    .price(Price::from("1.00000"))
+                    .quantity(Quantity::from(100_000))
+                    .build()
+            })
+            .collect();
+        let submitted = TestOrderEventStubs::order_submitted(&orders[0], account_id);
+        orders[0].apply(submitted).unwrap();
+        for order in &orders {
+            cache
+                .borrow_mut()
+                .add_order(order.clone(), None, Some(ClientId::from("SIM")), false)
+                .unwrap();
+        }
+        let order_list = OrderList::new(
+            OrderListId::from("OL-1"),
+            instrument_id,
+            orders[0].strategy_id(),
+            orders.clone(),
+            0.into(),
+        );
+        let command = SubmitOrderList::new(
+            TraderId::default(),
+            ClientId::from("SIM"),
+            orders[0].strategy_id(),
+            instrument_id,
+            orders[0].client_order_id(),
+            VenueOrderId::default(),
+            order_list,
+            None,
+            None,
+            UUID4::new(),
+            0.into(),
+        )
+        .unwrap();
+        // The first order was submitted in sequence (the next awaits its acceptance)
+        engine
+            .sequenced_submits
+            .borrow_mut()
+            .start(command, HashMap::new(), 0.into())
+            .unwrap();
+
+        engine.process(&OrderEventAny::Rejected(OrderRejected::new(
+            TraderId::default(),
+            orders[0].strategy_id(),
+            instrument_id,
+            orders[0].client_order_id(),
+            account_id,
+            Ustr::from("INSUFFICIENT_MARGIN"),
+            UUID4::new(),
+            0.into(),
+            0.into(),
+            false,
+        )));
+
+        let cache = cache.borrow();
+        assert_eq!(
+            cache.order(&orders[0].client_order_id()).unwrap().status(),
+            OrderStatus::Rejected
+        );
+        assert_eq!(
+            cache.order(&orders[1].client_order_id()).unwrap().status(),
+            OrderStatus::Denied
+        );
+        assert!(!engine
+            .sequenced_submits
+            .borrow()
+            .is_pending(&orders[1].client_order_id()));
+    }
+
+    #[rstest]
+    fn test_held_oto_children_canceled_when_parent_denied_by_rollback(
+        msgbus: MessageBus,
+        simple_cache: Cache,
+        clock: TestClock,
+    ) {
+        let cache = Rc::new(RefCell::new(simple_cache));
+        let msgbus = Rc::new(RefCell::new(msgbus));
+        let config = ExecutionEngineConfig {
+            manage_contingent_orders: true,
+            ..Default::default()
+        };
+        let mut engine = _get_exec_engine(
+            msgbus.clone(),
+            cache.clone(),
+            Rc::new(RefCell::new(clock)),
+            Some(config),
+        );
+        let client = ExecutionClient::new(
+            TraderId::default(),
+            ClientId::from("SIM"),
+            Venue::from("SIM"),
+            OmsType::Netting,
+            AccountId::from("SIM-001"),
+            AccountType::Margin,
+            None,
+            get_atomic_clock_static(),
+            cache.clone(),
+            msgbus,
+        );
+        engine.register_client(client).unwrap();
+
+        let instrument_id = InstrumentId::from("AUD/USD.SIM");
+        let account_id = AccountId::from("SIM-001");
+        let parent_id = ClientOrderId::from("O-2");
+        let child_id = ClientOrderId::from("O-3");
+        let mut first = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_id)
+            .client_order_id(ClientOrderId::from("O-1"))
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        let parent = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_id)
+            .client_order_id(parent_id)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .contingency_type(ContingencyType::Oto)
+            .linked_order_ids(vec![child_id])
+            .build();
+        let child = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_id)
+            .client_order_id(child_id)
+            .side(OrderSide::Sell)
+            .price(Price::from("1.10000"))
+            .quantity(Quantity::from(100_000))
+            .parent_order_id(parent_id)
+            .build();
+        first
+            .apply(TestOrderEventStubs::order_submitted(&first, account_id))
+            .unwrap();
+        for order in [&first, &parent, &child] {
+            cache
+                .borrow_mut()
+                .add_order(order.clone(), None, Some(ClientId::from("SIM")), false)
+                .unwrap();
+        }
+
+        // The child is held for its OTO parent, so only the first two orders are sequenced
+        let order_list = OrderList::new(
+            OrderListId::from("OL-1"),
+            instrument_id,
+            first.strategy_id(),
+            vec![first.clone(), parent],
+            0.into(),
+        );
+        let command = SubmitOrderList::new(
+            TraderId::default(),
+            ClientId::from("SIM"),
+            first.strategy_id(),
+            instrument_id,
+            first.client_order_id(),
+            VenueOrderId::default(),
+            order_list,
+            None,
+            None,
+            UUID4::new(),
+            0.into(),
+        )
+        .unwrap();
+        engine
+            .sequenced_submits
+            .borrow_mut()
+            .start(command, HashMap::new(), 0.into())
+            .unwrap();
+
+        engine.process(&OrderEventAny::Rejected(OrderRejected::new(
+            TraderId::default(),
+            first.strategy_id(),
+            instrument_id,
+            first.client_order_id(),
+            account_id,
+            Ustr::from("INSUFFICIENT_MARGIN"),
+            UUID4::new(),
+            0.into(),
+            0.into(),
+            false,
+        )));
+
+        let cache = cache.borrow();
+        assert_eq!(
+            cache.order(&parent_id).unwrap().status(),
+            OrderStatus::Denied
+        );
+        assert_eq!(
+            cache.order(&child_id).unwrap().status(),
+            OrderStatus::Canceled
+        );
+    }
+
+    #[rstest]
+    fn test_invalid_transition_persists_diagnostic(
+        msgbus: MessageBus,
+        simple_cache: Cache,
+        clock: TestClock,
+    ) {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        let order = TestOrderStubs::make_accepted_order(&order);
+        let client_order_id = order.client_order_id();
+        let cache = Rc::new(RefCell::new(simple_cache));
+        cache
+            .borrow_mut()
+            .add_order(order.clone(), None, None, false)
+            .unwrap();
+        let mut engine = _get_exec_engine(
+            Rc::new(RefCell::new(msgbus)),
+            cache.clone(),
+            Rc::new(RefCell::new(clock)),
+            None,
+        );
+
+        let submitted = TestOrderEventStubs::order_submitted(&order, AccountId::from("SIM-001"));
+        engine.process(&submitted);
+
+        let diagnostics = engine.invalid_transitions(Some(&client_order_id));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].status, OrderStatus::Accepted);
+        assert_eq!(diagnostics[0].event, submitted);
+        assert_eq!(diagnostics[0].events.len(), 3);
+        assert_eq!(
+            cache.borrow().order(&client_order_id).unwrap().status(),
+            OrderStatus::Accepted
+        );
+    }
 }
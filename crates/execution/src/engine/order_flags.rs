@@ -0,0 +1,227 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Emulation of the post-only and reduce-only order flags for venues without native support.
+
+use nautilus_model::{
+    data::QuoteTick,
+    enums::{OrderSide, PositionSide},
+    orders::OrderAny,
+    position::Position,
+    types::Quantity,
+};
+
+/// Returns whether the post-only `order` would take liquidity against the `quote`, so must
+/// not be submitted.
+///
+/// Without a quote (or a limit price) the order cannot be checked and is assumed not to cross.
+#[must_use]
+pub fn post_only_would_cross(order: &OrderAny, quote: Option<&QuoteTick>) -> bool {
+    if !order.is_post_only() {
+        return false;
+    }
+    let (Some(price), Some(quote)) = (order.price(), quote) else {
+        return false;
+    };
+
+    match order.order_side() {
+        OrderSide::Buy => price >= quote.ask_price,
+        OrderSide::Sell => price <= quote.bid_price,
+        OrderSide::NoOrderSide => false,
+    }
+}
+
+/// The outcome of checking a reduce-only order against the positions it can reduce.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReduceOnlyCheck {
+    /// The order only reduces the positions as is.
+    Unchanged,
+    /// The order quantity must be capped at the quantity given to only reduce the positions.
+    Capped(Quantity),
+    /// The order cannot reduce any position, so must not be submitted.
+    Denied,
+}
+
+/// Checks the reduce-only `order` against the open `positions` of its instrument, capping the
+/// order quantity at the net quantity of the positions on the opposite side.
+///
+/// The positions are netted across sides (as a netting venue holds a single position for the
+/// instrument), so the order is denied when the positions on its own side cancel out the ones
+/// it would reduce.
+#[must_use]
+pub fn reduce_only_check(order: &OrderAny, positions: &[&Position]) -> ReduceOnlyCheck {
+    if !order.is_reduce_only() {
+        return ReduceOnlyCheck::Unchanged;
+    }
+
+    let (reducible_side, opposing_side) = match order.order_side() {
+        OrderSide::Buy => (PositionSide::Short, PositionSide::Long),
+        OrderSide::Sell => (PositionSide::Long, PositionSide::Short),
+        OrderSide::NoOrderSide => return ReduceOnlyCheck::Denied,
+    };
+    let side_qty = |side: PositionSide| {
+        positions
+            .iter()
+            .filter(|position| position.side == side)
+            .map(|position| position.quantity)
+            .reduce(|total, quantity| total + quantity)
+    };
+    let reducible_qty = match (side_qty(reducible_side), side_qty(opposing_side)) {
+        (None, _) => None,
+        (Some(reducible), None) => Some(reducible),
+        (Some(reducible), Some(opposing)) if reducible > opposing => Some(reducible - opposing),
+        (Some(_), Some(_)) => None,
+    };
+
+    match reducible_qty {
+        None => ReduceOnlyCheck::Denied,
+        Some(quantity) if quantity.is_zero() => ReduceOnlyCheck::Denied,
+        Some(quantity) if order.quantity() > quantity => ReduceOnlyCheck::Capped(quantity),
+        Some(_) => ReduceOnlyCheck::Unchanged,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::UnixNanos;
+    use nautilus_model::{
+        enums::OrderType,
+        events::OrderEventAny,
+        identifiers::InstrumentId,
+        instruments::{stubs::audusd_sim, CurrencyPair, InstrumentAny},
+        orders::{stubs::TestOrderEventStubs, OrderTestBuilder},
+        types::Price,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn quote() -> QuoteTick {
+        QuoteTick::new(
+            InstrumentId::from("AUD/USD.SIM"),
+            Price::from("1.00000"),
+            Price::from("1.00010"),
+            Quantity::from(1_000_000),
+            Quantity::from(1_000_000),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    }
+
+    fn limit(side: OrderSide, price: &str, quantity: u64, post_only: bool) -> OrderAny {
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .side(side)
+            .price(Price::from(price))
+            .quantity(Quantity::from(quantity))
+            .post_only(post_only)
+            .build()
+    }
+
+    fn position(instrument: &InstrumentAny, side: OrderSide, quantity: u64) -> Position {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(side)
+            .quantity(Quantity::from(quantity))
+            .build();
+        let OrderEventAny::Filled(fill) = TestOrderEventStubs::order_filled(
+            &order, instrument, None, None, None, None, None, None, None, None,
+        ) else {
+            unreachable!()
+        };
+        Position::new(instrument, fill)
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, "1.00010", true)]
+    #[case(OrderSide::Buy, "1.00005", false)]
+    #[case(OrderSide::Sell, "1.00000", true)]
+    #[case(OrderSide::Sell, "1.00005", false)]
+    fn test_post_only_would_cross(
+        #[case] side: OrderSide,
+        #[case] price: &str,
+        #[case] expected: bool,
+    ) {
+        let order = limit(side, price, 100_000, true);
+
+        assert_eq!(post_only_would_cross(&order, Some(&quote())), expected);
+        assert!(!post_only_would_cross(&order, None));
+    }
+
+    #[rstest]
+    fn test_post_only_check_ignores_orders_without_flag() {
+        let order = limit(OrderSide::Buy, "1.00020", 100_000, false);
+
+        assert!(!post_only_would_cross(&order, Some(&quote())));
+    }
+
+    #[rstest]
+    fn test_reduce_only_check(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let long = position(&instrument, OrderSide::Buy, 100_000);
+        let build = |side: OrderSide, quantity: u64| {
+            OrderTestBuilder::new(OrderType::Market)
+                .instrument_id(instrument.id())
+                .side(side)
+                .quantity(Quantity::from(quantity))
+                .reduce_only(true)
+                .build()
+        };
+
+        assert_eq!(
+            reduce_only_check(&build(OrderSide::Sell, 50_000), &[&long]),
+            ReduceOnlyCheck::Unchanged
+        );
+        assert_eq!(
+            reduce_only_check(&build(OrderSide::Sell, 150_000), &[&long]),
+            ReduceOnlyCheck::Capped(Quantity::from(100_000))
+        );
+        assert_eq!(
+            reduce_only_check(&build(OrderSide::Buy, 50_000), &[&long]),
+            ReduceOnlyCheck::Denied
+        );
+        assert_eq!(
+            reduce_only_check(&build(OrderSide::Sell, 50_000), &[]),
+            ReduceOnlyCheck::Denied
+        );
+    }
+
+    #[rstest]
+    fn test_reduce_only_check_nets_positions_across_sides(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let long = position(&instrument, OrderSide::Buy, 100_000);
+        let short = position(&instrument, OrderSide::Sell, 40_000);
+        let build = |side: OrderSide, quantity: u64| {
+            OrderTestBuilder::new(OrderType::Market)
+                .instrument_id(instrument.id())
+                .side(side)
+                .quantity(Quantity::from(quantity))
+                .reduce_only(true)
+                .build()
+        };
+
+        assert_eq!(
+            reduce_only_check(&build(OrderSide::Sell, 100_000), &[&long, &short]),
+            ReduceOnlyCheck::Capped(Quantity::from(60_000))
+        );
+        assert_eq!(
+            reduce_only_check(&build(OrderSide::Buy, 40_000), &[&long, &short]),
+            ReduceOnlyCheck::Denied
+        );
+    }
+}
@@ -0,0 +1,145 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Position modes for venues which net positions per instrument.
+
+use nautilus_model::{
+    enums::{OrderSide, PositionSide},
+    identifiers::{InstrumentId, PositionId, StrategyId},
+    orders::OrderAny,
+};
+use serde::{Deserialize, Serialize};
+
+/// How positions are held for an instrument at a venue with a netting OMS.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PositionMode {
+    /// A single net position per instrument (and strategy).
+    #[default]
+    OneWay,
+    /// Concurrent long and short positions per instrument (and strategy), with each order
+    /// routed to the position of its side.
+    Hedge,
+}
+
+/// Returns the side of the hedge mode position an order with `order_side` is routed to.
+///
+/// Orders which are not reduce-only open or increase the position of their side, while
+/// reduce-only orders reduce the position of the opposite side.
+#[must_use]
+pub const fn hedge_position_side(order_side: OrderSide, is_reduce_only: bool) -> PositionSide {
+    match (order_side, is_reduce_only) {
+        (OrderSide::Buy, false) | (OrderSide::Sell, true) => PositionSide::Long,
+        (OrderSide::Sell, false) | (OrderSide::Buy, true) => PositionSide::Short,
+        (OrderSide::NoOrderSide, _) => PositionSide::Flat,
+    }
+}
+
+/// Returns the position side the `order` is routed to at the venue in the `mode`, or `None`
+/// if the venue holds a single position for both sides.
+#[must_use]
+pub fn venue_position_side(mode: PositionMode, order: &OrderAny) -> Option<PositionSide> {
+    match mode {
+        PositionMode::OneWay => None,
+        PositionMode::Hedge => Some(hedge_position_side(
+            order.order_side(),
+            order.is_reduce_only(),
+        )),
+    }
+}
+
+/// Returns the ID of the hedge mode position of the strategy on the `position_side`.
+#[must_use]
+pub fn hedge_position_id(
+    instrument_id: InstrumentId,
+    strategy_id: StrategyId,
+    position_side: PositionSide,
+) -> PositionId {
+    PositionId::new(format!("{instrument_id}-{strategy_id}-{position_side}"))
+}
+
+/// Returns the ID of the hedge mode position the `order` is routed to.
+#[must_use]
+pub fn order_hedge_position_id(order: &OrderAny) -> PositionId {
+    hedge_position_id(
+        order.instrument_id(),
+        order.strategy_id(),
+        hedge_position_side(order.order_side(), order.is_reduce_only()),
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{enums::OrderType, orders::OrderTestBuilder, types::Quantity};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn order(side: OrderSide, reduce_only: bool) -> OrderAny {
+        OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(InstrumentId::from("ETHUSDT-PERP.BINANCE"))
+            .strategy_id(StrategyId::from("S-001"))
+            .side(side)
+            .quantity(Quantity::from(1))
+            .reduce_only(reduce_only)
+            .build()
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, false, PositionSide::Long)]
+    #[case(OrderSide::Sell, true, PositionSide::Long)]
+    #[case(OrderSide::Sell, false, PositionSide::Short)]
+    #[case(OrderSide::Buy, true, PositionSide::Short)]
+    fn test_hedge_position_side(
+        #[case] order_side: OrderSide,
+        #[case] is_reduce_only: bool,
+        #[case] expected: PositionSide,
+    ) {
+        assert_eq!(hedge_position_side(order_side, is_reduce_only), expected);
+    }
+
+    #[rstest]
+    fn test_venue_position_side_by_mode() {
+        let order = order(OrderSide::Sell, false);
+
+        assert_eq!(venue_position_side(PositionMode::OneWay, &order), None);
+        assert_eq!(
+            venue_position_side(PositionMode::Hedge, &order),
+            Some(PositionSide::Short)
+        );
+    }
+
+    #[rstest]
+    fn test_reduce_only_order_routed_to_opposite_position() {
+        let open_long = order(OrderSide::Buy, false);
+        let close_long = order(OrderSide::Sell, true);
+        let open_short = order(OrderSide::Sell, false);
+
+        assert_eq!(
+            order_hedge_position_id(&open_long),
+            PositionId::from("ETHUSDT-PERP.BINANCE-S-001-LONG")
+        );
+        assert_eq!(
+            order_hedge_position_id(&close_long),
+            order_hedge_position_id(&open_long)
+        );
+        assert_ne!(
+            order_hedge_position_id(&open_short),
+            order_hedge_position_id(&open_long)
+        );
+    }
+}
@@ -0,0 +1,769 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Reconciliation of cached execution state against the reports of a venue.
+//!
+//! Order status and fill reports are compared against the cached state of each order, and the
+//! events missed while offline are inferred, so that applying them in sequence brings the order
+//! in line with the venue. Position reports are checked against the cached open positions.
+
+use nautilus_common::cache::Cache;
+use nautilus_core::{UnixNanos, UUID4};
+use nautilus_model::{
+    enums::{LiquiditySide, OrderStatus},
+    events::{
+        OrderAccepted, OrderCanceled, OrderEventAny, OrderExpired, OrderFilled, OrderInitialized,
+        OrderRejected, OrderSubmitted, OrderTriggered, OrderUpdated,
+    },
+    identifiers::{ClientOrderId, StrategyId, TradeId, TraderId},
+    instruments::InstrumentAny,
+    orders::OrderAny,
+    types::Quantity,
+};
+use rust_decimal::{prelude::FromPrimitive, Decimal};
+use ustr::Ustr;
+
+use crate::reports::{fill::FillReport, order::OrderStatusReport, position::PositionStatusReport};
+
+/// Creates an order from the `report` of an order not held in the cache, such as an order
+/// submitted externally to the system.
+///
+/// # Errors
+///
+/// Returns an error if the order cannot be created from the report.
+pub fn external_order(
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    client_order_id: ClientOrderId,
+    report: &OrderStatusReport,
+    ts_init: UnixNanos,
+) -> anyhow::Result<OrderAny> {
+    let initialized = OrderInitialized::new(
+        trader_id,
+        strategy_id,
+        report.instrument_id,
+        client_order_id,
+        report.order_side,
+        report.order_type,
+        report.quantity,
+        report.time_in_force,
+        report.post_only,
+        report.reduce_only,
+        false,
+        true,
+        UUID4::new(),
+        report.ts_accepted,
+        ts_init,
+        report.price,
+        report.trigger_price,
+        report.trigger_type,
+        report.limit_offset.map(|offset| offset.as_decimal()),
+        report.trailing_offset.map(|offset| offset.as_decimal()),
+        report.trailing_offset.map(|_| report.trailing_offset_type),
+        report.expire_time,
+        report.display_qty,
+        None,
+        None,
+        Some(report.contingency_type),
+        report.order_list_id,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(vec![Ustr::from("EXTERNAL")]),
+    );
+
+    OrderAny::from_events(vec![OrderEventAny::Initialized(initialized)])
+}
+
+/// Returns the events to apply to the `order`, in sequence, to reconcile it with the `report`
+/// of its status at the venue and the `fills` reported for it.
+///
+/// Fills are reconciled by trade ID, and any remaining difference from the reported filled
+/// quantity is reconciled with an inferred fill at the price implied by the reported average
+/// price. Without a status report only the missing fills are reconciled.
+///
+/// # Errors
+///
+/// Returns an error if the order cannot be reconciled, such as where it has filled more than
+/// reported by the venue, or an inferred event is invalid for the state of the order.
+pub fn reconcile_order(
+    order: &OrderAny,
+    report: Option<&OrderStatusReport>,
+    fills: &[FillReport],
+    instrument: &InstrumentAny,
+    ts_init: UnixNanos,
+) -> anyhow::Result<Vec<OrderEventAny>> {
+    let mut inferred = InferredEvents::new(order, ts_init);
+
+    if let Some(report) = report {
+        if report.order_status == OrderStatus::Rejected {
+            if !inferred.order.is_closed() {
+                inferred.submit(report)?;
+                inferred.reject(report)?;
+            }
+            return Ok(inferred.events);
+        }
+
+        inferred.submit(report)?;
+        inferred.accept(report)?;
+        inferred.update(report)?;
+
+        if report.order_status == OrderStatus::Triggered
+            && inferred.order.status() == OrderStatus::Accepted
+        {
+            inferred.trigger(report)?;
+        }
+    }
+
+    for fill in fills {
+        inferred.fill(fill, instrument)?;
+    }
+
+    if let Some(report) = report {
+        inferred.infer_fill(report, instrument)?;
+
+        if !inferred.order.is_closed() {
+            match report.order_status {
+                OrderStatus::Canceled => inferred.cancel(report)?,
+                OrderStatus::Expired => inferred.expire(report)?,
+                _ => {}
+            }
+        }
+    }
+
+    Ok(inferred.events)
+}
+
+/// Returns whether the cached open positions for the instrument of the `report` match the
+/// reported position, where a venue position ID identifies a single (hedging) position.
+#[must_use]
+pub fn is_position_reconciled(cache: &Cache, report: &PositionStatusReport) -> bool {
+    let signed_qty: f64 = match report.venue_position_id {
+        Some(position_id) => cache
+            .position(&position_id)
+            .filter(|position| position.is_open())
+            .map_or(0.0, |position| position.signed_qty),
+        None => cache
+            .positions_open(None, Some(&report.instrument_id), None, None)
+            .iter()
+            .map(|position| position.signed_qty)
+            .sum(),
+    };
+
+    let precision = u32::from(report.quantity.precision);
+    let cached_qty = Decimal::from_f64(signed_qty)
+        .unwrap_or_default()
+        .round_dp(precision);
+
+    if cached_qty != report.signed_decimal_qty.round_dp(precision) {
+        log::error!(
+            "Position mismatch for {}: cached {cached_qty}, reported {}",
+            report.instrument_id,
+            report.signed_decimal_qty,
+        );
+        return false;
+    }
+
+    true
+}
+
+/// Events inferred for an order, applied to a working copy so that each event is
+/// generated against the state left by the previous events.
+struct InferredEvents {
+    order: OrderAny,
+    events: Vec<OrderEventAny>,
+    ts_init: UnixNanos,
+}
+
+impl InferredEvents {
+    fn new(order: &OrderAny, ts_init: UnixNanos) -> Self {
+        Self {
+            order: order.clone(),
+            events: Vec::new(),
+            ts_init,
+        }
+    }
+
+    fn push(&mut self, event: OrderEventAny) -> anyhow::Result<()> {
+        self.order
+            .apply(event.clone())
+            .map_err(|e| anyhow::anyhow!("Cannot apply inferred {event}: {e}"))?;
+        self.events.push(event);
+        Ok(())
+    }
+
+    fn submit(&mut self, report: &OrderStatusReport) -> anyhow::Result<()> {
+        if self.order.status() != OrderStatus::Released {
+            return Ok(());
+        }
+
+        let order = &self.order;
+        let event = OrderSubmitted::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            report.account_id,
+            UUID4::new(),
+            report.ts_accepted,
+            self.ts_init,
+        );
+        self.push(OrderEventAny::Submitted(event))
+    }
+
+    fn accept(&mut self, report: &OrderStatusReport) -> anyhow::Result<()> {
+        if !matches!(
+            self.order.status(),
+            OrderStatus::Initialized | OrderStatus::Submitted
+        ) {
+            return Ok(());
+        }
+
+        let order = &self.order;
+        let event = OrderAccepted::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            report.venue_order_id,
+            report.account_id,
+            UUID4::new(),
+            report.ts_accepted,
+            self.ts_init,
+            true,
+        );
+        self.push(OrderEventAny::Accepted(event))
+    }
+
+    fn reject(&mut self, report: &OrderStatusReport) -> anyhow::Result<()> {
+        let order = &self.order;
+        let reason = report.cancel_reason.as_deref().unwrap_or("UNKNOWN");
+        let event = OrderRejected::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            report.account_id,
+            Ustr::from(reason),
+            UUID4::new(),
+            report.ts_last,
+            self.ts_init,
+            true,
+        );
+        self.push(OrderEventAny::Rejected(event))
+    }
+
+    fn update(&mut self, report: &OrderStatusReport) -> anyhow::Result<()> {
+        let order = &self.order;
+        if order.status() != OrderStatus::Accepted {
+            return Ok(());
+        }
+
+        let price = report.price.filter(|price| Some(*price) != order.price());
+        let trigger_price = report
+            .trigger_price
+            .filter(|price| Some(*price) != order.trigger_price());
+        if report.quantity == order.quantity() && price.is_none() && trigger_price.is_none() {
+            return Ok(());
+        }
+
+        let event = OrderUpdated::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            report.quantity,
+            UUID4::new(),
+            report.ts_last,
+            self.ts_init,
+            true,
+            Some(report.venue_order_id),
+            Some(report.account_id),
+            price,
+            trigger_price,
+        );
+        self.push(OrderEventAny::Updated(event))
+    }
+
+    fn trigger(&mut self, report: &OrderStatusReport) -> anyhow::Result<()> {
+        let order = &self.order;
+        let event = OrderTriggered::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            UUID4::new(),
+            report.ts_triggered.unwrap_or(report.ts_last),
+            self.ts_init,
+            true,
+            Some(report.venue_order_id),
+            Some(report.account_id),
+        );
+        self.push(OrderEventAny::Triggered(event))
+    }
+
+    fn cancel(&mut self, report: &OrderStatusReport) -> anyhow::Result<()> {
+        let order = &self.order;
+        let event = OrderCanceled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            UUID4::new(),
+            report.ts_last,
+            self.ts_init,
+            true,
+            Some(report.venue_order_id),
+            Some(report.account_id),
+        );
+        self.push(OrderEventAny::Canceled(event))
+    }
+
+    fn expire(&mut self, report: &OrderStatusReport) -> anyhow::Result<()> {
+        let order = &self.order;
+        let event = OrderExpired::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            UUID4::new(),
+            report.ts_last,
+            self.ts_init,
+            true,
+            Some(report.venue_order_id),
+            Some(report.account_id),
+        );
+        self.push(OrderEventAny::Expired(event))
+    }
+
+    fn is_filled_by(&self, trade_id: TradeId) -> bool {
+        self.order.events().iter().any(|event| match event {
+            OrderEventAny::PartiallyFilled(fill) | OrderEventAny::Filled(fill) => {
+                fill.trade_id == trade_id
+            }
+            _ => false,
+        })
+    }
+
+    fn fill(&mut self, fill: &FillReport, instrument: &InstrumentAny) -> anyhow::Result<()> {
+        if self.is_filled_by(fill.trade_id) {
+            return Ok(());
+        }
+
+        let order = &self.order;
+        let event = OrderFilled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            fill.venue_order_id,
+            fill.account_id,
+            fill.trade_id,
+            order.order_side(),
+            order.order_type(),
+            fill.last_qty,
+            fill.last_px,
+            instrument.quote_currency(),
+            fill.liquidity_side,
+            UUID4::new(),
+            fill.ts_event,
+            self.ts_init,
+            true,
+            fill.venue_position_id,
+            Some(fill.commission),
+        );
+        self.push_fill(event)
+    }
+
+    fn infer_fill(
+        &mut self,
+        report: &OrderStatusReport,
+        instrument: &InstrumentAny,
+    ) -> anyhow::Result<()> {
+        let order = &self.order;
+        if report.filled_qty < order.filled_qty() {
+            anyhow::bail!(
+                "{} filled {} exceeds reported filled quantity {}",
+                order.client_order_id(),
+                order.filled_qty(),
+                report.filled_qty,
+            );
+        }
+        if report.filled_qty == order.filled_qty() {
+            return Ok(());
+        }
+
+        let last_qty = report.filled_qty - order.filled_qty();
+        let last_px = match report.avg_px {
+            // The price of the missing fills, given the fills already applied
+            Some(avg_px) => {
+                let filled_notional = order.avg_px().unwrap_or(0.0) * order.filled_qty().as_f64();
+                (avg_px * report.filled_qty.as_f64() - filled_notional) / last_qty.as_f64()
+            }
+            None => match report.price.or(order.price()) {
+                Some(price) => price.as_f64(),
+                None => anyhow::bail!(
+                    "Cannot infer fill for {}: no average price reported",
+                    order.client_order_id()
+                ),
+            },
+        };
+
+        let event = OrderFilled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            report.venue_order_id,
+            report.account_id,
+            TradeId::new(UUID4::new().to_string()),
+            order.order_side(),
+            order.order_type(),
+            last_qty,
+            instrument.make_price(last_px),
+            instrument.quote_currency(),
+            LiquiditySide::NoLiquiditySide,
+            UUID4::new(),
+            report.ts_last,
+            self.ts_init,
+            true,
+            report.venue_position_id,
+            None,
+        );
+        log::info!(
+            "Inferred fill for {} of {last_qty} @ {}",
+            order.client_order_id(),
+            event.last_px,
+        );
+        self.push_fill(event)
+    }
+
+    fn push_fill(&mut self, fill: OrderFilled) -> anyhow::Result<()> {
+        let leaves_qty: Quantity = self.order.leaves_qty();
+        if fill.last_qty > leaves_qty {
+            anyhow::bail!(
+                "Fill {} of {} exceeds leaves quantity {leaves_qty} of {}",
+                fill.trade_id,
+                fill.last_qty,
+                fill.client_order_id,
+            );
+        }
+
+        if fill.last_qty < leaves_qty {
+            self.push(OrderEventAny::PartiallyFilled(fill))
+        } else {
+            self.push(OrderEventAny::Filled(fill))
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{OrderSide, OrderType, PositionSide, TimeInForce},
+        identifiers::{AccountId, VenueOrderId},
+        instruments::stubs::audusd_sim,
+        orders::{stubs::TestOrderEventStubs, OrderTestBuilder},
+        types::{Money, Price},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn instrument() -> InstrumentAny {
+        InstrumentAny::CurrencyPair(audusd_sim())
+    }
+
+    fn limit_order() -> OrderAny {
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument().id())
+            .client_order_id(ClientOrderId::from("O-1"))
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build()
+    }
+
+    fn submitted_order() -> OrderAny {
+        let mut order = limit_order();
+        let submitted = TestOrderEventStubs::order_submitted(&order, AccountId::from("SIM-001"));
+        order.apply(submitted).unwrap();
+        order
+    }
+
+    fn report(order_status: OrderStatus, filled_qty: u64) -> OrderStatusReport {
+        OrderStatusReport::new(
+            AccountId::from("SIM-001"),
+            instrument().id(),
+            Some(ClientOrderId::from("O-1")),
+            VenueOrderId::from("V-1"),
+            OrderSide::Buy,
+            OrderType::Limit,
+            TimeInForce::Gtc,
+            order_status,
+            Quantity::from(100_000),
+            Quantity::from(filled_qty),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            None,
+        )
+        .with_price(Price::from("1.00000"))
+    }
+
+    fn fill_report(trade_id: &str, last_qty: u64, last_px: &str) -> FillReport {
+        FillReport::new(
+            AccountId::from("SIM-001"),
+            instrument().id(),
+            VenueOrderId::from("V-1"),
+            TradeId::from(trade_id),
+            OrderSide::Buy,
+            Quantity::from(last_qty),
+            Price::from(last_px),
+            Money::from("2 USD"),
+            LiquiditySide::Maker,
+            Some(ClientOrderId::from("O-1")),
+            None,
+            UnixNanos::default(),
+            UnixNanos::default(),
+            None,
+        )
+    }
+
+    fn apply(order: &OrderAny, events: &[OrderEventAny]) -> OrderAny {
+        let mut order = order.clone();
+        for event in events {
+            order.apply(event.clone()).unwrap();
+        }
+        order
+    }
+
+    #[rstest]
+    fn test_reconcile_submitted_order_accepted_while_offline() {
+        let order = submitted_order();
+
+        let events = reconcile_order(
+            &order,
+            Some(&report(OrderStatus::Accepted, 0)),
+            &[],
+            &instrument(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], OrderEventAny::Accepted(_)));
+        assert_eq!(apply(&order, &events).status(), OrderStatus::Accepted);
+    }
+
+    #[rstest]
+    fn test_reconcile_applies_missing_fills_by_trade_id() {
+        let order = submitted_order();
+        let fills = [
+            fill_report("T-1", 40_000, "1.00000"),
+            fill_report("T-2", 60_000, "1.00000"),
+        ];
+
+        let events = reconcile_order(
+            &order,
+            Some(&report(OrderStatus::Filled, 100_000)),
+            &fills,
+            &instrument(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[1], OrderEventAny::PartiallyFilled(_)));
+        assert!(matches!(events[2], OrderEventAny::Filled(_)));
+        let reconciled = apply(&order, &events);
+        assert_eq!(reconciled.status(), OrderStatus::Filled);
+
+        // Reconciling again infers nothing further
+        let events = reconcile_order(
+            &reconciled,
+            Some(&report(OrderStatus::Filled, 100_000)),
+            &fills,
+            &instrument(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        assert!(events.is_empty());
+    }
+
+    #[rstest]
+    fn test_reconcile_infers_fill_at_implied_price() {
+        let order = submitted_order();
+        let report = report(OrderStatus::PartiallyFilled, 60_000).with_avg_px(1.00002);
+
+        // Only the first fill was reported, the remainder is inferred from the average price
+        let events = reconcile_order(
+            &order,
+            Some(&report),
+            &[fill_report("T-1", 20_000, "1.00000")],
+            &instrument(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        assert_eq!(events.len(), 3);
+        let OrderEventAny::PartiallyFilled(inferred) = events[2] else {
+            panic!("Expected inferred partial fill, was {}", events[2]);
+        };
+        assert_eq!(inferred.last_qty, Quantity::from(40_000));
+        assert_eq!(inferred.last_px, Price::from("1.00003"));
+        assert!(inferred.reconciliation);
+        let reconciled = apply(&order, &events);
+        assert_eq!(reconciled.status(), OrderStatus::PartiallyFilled);
+        assert_eq!(reconciled.filled_qty(), Quantity::from(60_000));
+    }
+
+    #[rstest]
+    fn test_reconcile_partially_filled_then_canceled() {
+        let order = submitted_order();
+
+        let events = reconcile_order(
+            &order,
+            Some(&report(OrderStatus::Canceled, 30_000).with_avg_px(1.0)),
+            &[],
+            &instrument(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        let reconciled = apply(&order, &events);
+        assert_eq!(reconciled.status(), OrderStatus::Canceled);
+        assert_eq!(reconciled.filled_qty(), Quantity::from(30_000));
+    }
+
+    #[rstest]
+    fn test_reconcile_modified_order() {
+        let order = submitted_order();
+        let report = report(OrderStatus::Accepted, 0).with_price(Price::from("0.99000"));
+
+        let events = reconcile_order(
+            &order,
+            Some(&report),
+            &[],
+            &instrument(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        assert!(matches!(events[1], OrderEventAny::Updated(_)));
+        assert_eq!(apply(&order, &events).price(), Some(Price::from("0.99000")));
+    }
+
+    #[rstest]
+    fn test_reconcile_rejected_order() {
+        let order = submitted_order();
+        let report = report(OrderStatus::Rejected, 0).with_cancel_reason("INSUFFICIENT_MARGIN");
+
+        let events = reconcile_order(
+            &order,
+            Some(&report),
+            &[],
+            &instrument(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        let OrderEventAny::Rejected(rejected) = events[0] else {
+            panic!("Expected rejected, was {}", events[0]);
+        };
+        assert_eq!(rejected.reason, Ustr::from("INSUFFICIENT_MARGIN"));
+    }
+
+    #[rstest]
+    fn test_reconcile_order_overfilled_errors() {
+        let mut order = submitted_order();
+        let accepted = TestOrderEventStubs::order_accepted(
+            &order,
+            AccountId::from("SIM-001"),
+            VenueOrderId::from("V-1"),
+        );
+        order.apply(accepted).unwrap();
+        let filled = TestOrderEventStubs::order_filled(
+            &order,
+            &instrument(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        order.apply(filled).unwrap();
+
+        let result = reconcile_order(
+            &order,
+            Some(&report(OrderStatus::PartiallyFilled, 50_000)),
+            &[],
+            &instrument(),
+            UnixNanos::default(),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_external_order_from_report() {
+        let report = report(OrderStatus::Accepted, 0);
+
+        let order = external_order(
+            TraderId::from("TRADER-001"),
+            StrategyId::from("EXTERNAL"),
+            ClientOrderId::from("O-1"),
+            &report,
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Initialized);
+        assert_eq!(order.order_type(), OrderType::Limit);
+        assert_eq!(order.price(), Some(Price::from("1.00000")));
+        assert_eq!(order.quantity(), Quantity::from(100_000));
+    }
+
+    #[rstest]
+    #[case(PositionSide::Flat, 0, true)]
+    #[case(PositionSide::Long, 100_000, false)]
+    fn test_is_position_reconciled_without_positions(
+        #[case] position_side: PositionSide,
+        #[case] quantity: u64,
+        #[case] expected: bool,
+    ) {
+        let cache = Cache::default();
+        let report = PositionStatusReport::new(
+            AccountId::from("SIM-001"),
+            instrument().id(),
+            position_side,
+            Quantity::from(quantity),
+            None,
+            UnixNanos::default(),
+            UnixNanos::default(),
+            None,
+        );
+
+        assert_eq!(is_position_reconciled(&cache, &report), expected);
+    }
+}
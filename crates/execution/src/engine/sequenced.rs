@@ -0,0 +1,272 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Sequenced submission of order lists to venues without a batch submit endpoint.
+
+use std::collections::HashMap;
+
+use nautilus_core::{UnixNanos, UUID4};
+use nautilus_model::{
+    identifiers::{ClientOrderId, OrderListId, PositionId},
+    orders::OrderAny,
+};
+
+use crate::messages::{SubmitOrder, SubmitOrderList};
+
+/// The orders of a sequenced order list to roll back after one of its orders was rejected.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SequenceRollback {
+    /// The orders already accepted by the venue, to be canceled.
+    pub accepted: Vec<ClientOrderId>,
+    /// The orders not yet submitted, to be denied.
+    pub unsubmitted: Vec<ClientOrderId>,
+}
+
+#[derive(Debug)]
+struct SequencedOrderList {
+    command: SubmitOrderList,
+    position_ids: HashMap<ClientOrderId, PositionId>,
+    next: usize,
+}
+
+impl SequencedOrderList {
+    fn submit_command(&self, order: &OrderAny, ts_init: UnixNanos) -> SubmitOrder {
+        SubmitOrder {
+            trader_id: self.command.trader_id,
+            client_id: self.command.client_id,
+            strategy_id: self.command.strategy_id,
+            instrument_id: order.instrument_id(),
+            client_order_id: order.client_order_id(),
+            venue_order_id: self.command.venue_order_id,
+            order: order.clone(),
+            exec_algorith_id: self.command.exec_algorith_id,
+            position_id: self
+                .position_ids
+                .get(&order.client_order_id())
+                .copied()
+                .or(self.command.position_id),
+            command_id: UUID4::new(),
+            ts_init,
+        }
+    }
+}
+
+/// Submits the orders of lists one at a time, each once the previous order was accepted, so
+/// that a rejection rolls back the whole list.
+#[derive(Debug, Default)]
+pub struct SequencedSubmits {
+    lists: HashMap<OrderListId, SequencedOrderList>,
+    pending: HashMap<ClientOrderId, OrderListId>,
+}
+
+impl SequencedSubmits {
+    /// Creates a new [`SequencedSubmits`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts the sequenced submission of the order list `command`, returning the command
+    /// submitting its first order (if any).
+    ///
+    /// Each order is submitted with its position ID in the `position_ids`, otherwise with the
+    /// position ID of the list.
+    pub fn start(
+        &mut self,
+        command: SubmitOrderList,
+        position_ids: HashMap<ClientOrderId, PositionId>,
+        ts_init: UnixNanos,
+    ) -> Option<SubmitOrder> {
+        let list_id = command.order_list.id;
+        let mut list = SequencedOrderList {
+            command,
+            position_ids,
+            next: 0,
+        };
+        let submit = Self::advance(&mut list, ts_init)?;
+
+        self.pending.insert(submit.client_order_id, list_id);
+        self.lists.insert(list_id, list);
+        Some(submit)
+    }
+
+    /// Returns whether the order is awaiting acceptance as part of a sequenced order list.
+    #[must_use]
+    pub fn is_pending(&self, client_order_id: &ClientOrderId) -> bool {
+        self.pending.contains_key(client_order_id)
+    }
+
+    /// Records the acceptance of the pending order, returning the command submitting the next
+    /// order of its list (if any).
+    pub fn on_accepted(
+        &mut self,
+        client_order_id: &ClientOrderId,
+        ts_init: UnixNanos,
+    ) -> Option<SubmitOrder> {
+        let list_id = self.pending.remove(client_order_id)?;
+        let list = self.lists.get_mut(&list_id)?;
+
+        match Self::advance(list, ts_init) {
+            Some(submit) => {
+                self.pending.insert(submit.client_order_id, list_id);
+                Some(submit)
+            }
+            None => {
+                self.lists.remove(&list_id);
+                None
+            }
+        }
+    }
+
+    /// Records the rejection of the pending order, returning the orders of its list to roll
+    /// back (if the order was pending).
+    pub fn on_rejected(&mut self, client_order_id: &ClientOrderId) -> Option<SequenceRollback> {
+        let list_id = self.pending.remove(client_order_id)?;
+        let list = self.lists.remove(&list_id)?;
+        let orders = &list.command.order_list.orders;
+        let rejected = list.next - 1;
+
+        Some(SequenceRollback {
+            accepted: orders[..rejected]
+                .iter()
+                .map(OrderAny::client_order_id)
+                .collect(),
+            unsubmitted: orders[list.next..]
+                .iter()
+                .map(OrderAny::client_order_id)
+                .collect(),
+        })
+    }
+
+    fn advance(list: &mut SequencedOrderList, ts_init: UnixNanos) -> Option<SubmitOrder> {
+        let order = list.command.order_list.orders.get(list.next)?;
+        let submit = list.submit_command(order, ts_init);
+        list.next += 1;
+        Some(submit)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::OrderType,
+        identifiers::{ClientId, InstrumentId, StrategyId, TraderId, VenueOrderId},
+        orders::{OrderList, OrderTestBuilder},
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn order_list(client_order_ids: &[&str]) -> SubmitOrderList {
+        let instrument_id = InstrumentId::from("AUD/USD.SIM");
+        let orders: Vec<OrderAny> = client_order_ids
+            .iter()
+            .map(|client_order_id| {
+                OrderTestBuilder::new(OrderType::Limit)
+                    .instrument_id(instrument_id)
+                    .client_order_id(ClientOrderId::from(*client_order_id))
+                    .price(Price::from("1.00000"))
+                    .quantity(Quantity::from(100_000))
+                    .build()
+            })
+            .collect();
+        let order_list = OrderList::new(
+            OrderListId::from("OL-1"),
+            instrument_id,
+            StrategyId::from("S-001"),
+            orders,
+            0.into(),
+        );
+        SubmitOrderList::new(
+            TraderId::default(),
+            ClientId::from("SIM"),
+            StrategyId::from("S-001"),
+            instrument_id,
+            ClientOrderId::from(client_order_ids[0]),
+            VenueOrderId::default(),
+            order_list,
+            None,
+            None,
+            UUID4::new(),
+            0.into(),
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_orders_submitted_in_sequence_on_acceptance() {
+        let mut sequenced = SequencedSubmits::new();
+
+        let first = sequenced
+            .start(order_list(&["O-1", "O-2"]), HashMap::new(), 0.into())
+            .unwrap();
+        assert_eq!(first.client_order_id, ClientOrderId::from("O-1"));
+        assert!(sequenced.is_pending(&first.client_order_id));
+
+        let second = sequenced
+            .on_accepted(&first.client_order_id, 1.into())
+            .unwrap();
+        assert_eq!(second.client_order_id, ClientOrderId::from("O-2"));
+        assert_eq!(second.ts_init, UnixNanos::from(1));
+
+        assert!(sequenced
+            .on_accepted(&second.client_order_id, 2.into())
+            .is_none());
+        assert!(!sequenced.is_pending(&second.client_order_id));
+    }
+
+    #[rstest]
+    fn test_orders_submitted_with_own_position_ids() {
+        let mut sequenced = SequencedSubmits::new();
+        let position_ids = HashMap::from([(
+            ClientOrderId::from("O-2"),
+            PositionId::from("AUD/USD.SIM-SHORT"),
+        )]);
+
+        let first = sequenced
+            .start(order_list(&["O-1", "O-2"]), position_ids, 0.into())
+            .unwrap();
+        let second = sequenced
+            .on_accepted(&first.client_order_id, 1.into())
+            .unwrap();
+
+        assert_eq!(first.position_id, None);
+        assert_eq!(
+            second.position_id,
+            Some(PositionId::from("AUD/USD.SIM-SHORT"))
+        );
+    }
+
+    #[rstest]
+    fn test_rejection_rolls_back_list() {
+        let mut sequenced = SequencedSubmits::new();
+        let first = sequenced
+            .start(order_list(&["O-1", "O-2", "O-3"]), HashMap::new(), 0.into())
+            .unwrap();
+        let second = sequenced
+            .on_accepted(&first.client_order_id, 0.into())
+            .unwrap();
+
+        let rollback = sequenced.on_rejected(&second.client_order_id).unwrap();
+
+        assert_eq!(rollback.accepted, vec![ClientOrderId::from("O-1")]);
+        assert_eq!(rollback.unsubmitted, vec![ClientOrderId::from("O-3")]);
+        assert!(sequenced.on_rejected(&second.client_order_id).is_none());
+    }
+}
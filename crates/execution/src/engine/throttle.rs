@@ -0,0 +1,332 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Per strategy throttling of order submissions, independent of venue rate limits.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Display,
+};
+
+use nautilus_core::{
+    datetime::{NANOSECONDS_IN_MILLISECOND, NANOSECONDS_IN_SECOND},
+    UnixNanos, UUID4,
+};
+use nautilus_model::identifiers::{StrategyId, TraderId};
+use serde::{Deserialize, Serialize};
+use strum::Display as StrumDisplay;
+
+/// Configuration for the throttles of a strategy, where `None` disables a throttle.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StrategyThrottleConfig {
+    /// The maximum number of orders submitted in any one second.
+    #[serde(default)]
+    pub max_orders_per_sec: Option<u32>,
+    /// The maximum number of open and in-flight orders.
+    #[serde(default)]
+    pub max_open_orders: Option<usize>,
+    /// The number of consecutive order rejections after which submissions are paused.
+    #[serde(default)]
+    pub max_consecutive_rejects: Option<u32>,
+    /// The pause (milliseconds) in submissions after `max_consecutive_rejects` rejections.
+    #[serde(default)]
+    pub reject_cooldown_ms: u64,
+}
+
+/// The reason a strategy is throttled.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, StrumDisplay, Serialize, Deserialize)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum ThrottleReason {
+    /// The maximum order submission rate was reached.
+    OrderRate,
+    /// The maximum number of open orders was reached.
+    OpenOrders,
+    /// Submissions are paused after consecutive rejections.
+    RejectCooldown,
+    /// The strategy was killed, overriding all other throttles until restored.
+    Killed,
+}
+
+/// Represents an event where a throttle engaged for a strategy.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StrategyThrottled {
+    pub trader_id: TraderId,
+    pub strategy_id: StrategyId,
+    pub reason: ThrottleReason,
+    /// UNIX timestamp (nanoseconds) when the throttle disengages, if known.
+    pub until: Option<UnixNanos>,
+    pub event_id: UUID4,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl Display for StrategyThrottled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "StrategyThrottled(strategy_id={}, reason={}, until={:?})",
+            self.strategy_id, self.reason, self.until,
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+struct ThrottleState {
+    config: StrategyThrottleConfig,
+    submits: VecDeque<UnixNanos>,
+    consecutive_rejects: u32,
+    cooldown_until: Option<UnixNanos>,
+    killed: bool,
+    engaged: Option<ThrottleReason>,
+}
+
+/// Tracks the order submissions and rejections of strategies against their throttles.
+#[derive(Debug, Default)]
+pub struct StrategyThrottles {
+    states: HashMap<StrategyId, ThrottleState>,
+}
+
+impl StrategyThrottles {
+    /// Creates a new [`StrategyThrottles`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the throttles of the strategy.
+    pub fn set_config(&mut self, strategy_id: StrategyId, config: StrategyThrottleConfig) {
+        self.states.entry(strategy_id).or_default().config = config;
+    }
+
+    /// Kills the strategy, throttling all its submissions until restored.
+    pub fn kill(&mut self, strategy_id: StrategyId) {
+        let state = self.states.entry(strategy_id).or_default();
+        state.killed = true;
+        state.engaged = Some(ThrottleReason::Killed);
+    }
+
+    /// Restores the killed strategy, also clearing any reject cooldown.
+    pub fn restore(&mut self, strategy_id: &StrategyId) {
+        if let Some(state) = self.states.get_mut(strategy_id) {
+            state.killed = false;
+            state.consecutive_rejects = 0;
+            state.cooldown_until = None;
+            state.engaged = None;
+        }
+    }
+
+    /// Returns whether the strategy is killed.
+    #[must_use]
+    pub fn is_killed(&self, strategy_id: &StrategyId) -> bool {
+        self.states
+            .get(strategy_id)
+            .is_some_and(|state| state.killed)
+    }
+
+    /// Checks a submission of the strategy (with `open_orders` open and in-flight orders)
+    /// against its throttles at `ts_now`, recording the submission if admitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns the reason and disengage time (if known) of the throttle engaged, with
+    /// `true` if the throttle has newly engaged.
+    pub fn check_submit(
+        &mut self,
+        strategy_id: StrategyId,
+        open_orders: usize,
+        ts_now: UnixNanos,
+    ) -> Result<(), (ThrottleReason, Option<UnixNanos>, bool)> {
+        let Some(state) = self.states.get_mut(&strategy_id) else {
+            return Ok(());
+        };
+
+        while state
+            .submits
+            .front()
+            .is_some_and(|ts| ts.as_u64() + NANOSECONDS_IN_SECOND <= ts_now.as_u64())
+        {
+            state.submits.pop_front();
+        }
+        if state.cooldown_until.is_some_and(|until| ts_now >= until) {
+            state.cooldown_until = None;
+            state.consecutive_rejects = 0;
+        }
+
+        let config = state.config;
+        let throttle = if state.killed {
+            Some((ThrottleReason::Killed, None))
+        } else if let Some(until) = state.cooldown_until {
+            Some((ThrottleReason::RejectCooldown, Some(until)))
+        } else if config.max_open_orders.is_some_and(|max| open_orders >= max) {
+            Some((ThrottleReason::OpenOrders, None))
+        } else if config
+            .max_orders_per_sec
+            .is_some_and(|max| state.submits.len() >= max as usize)
+        {
+            let until = state
+                .submits
+                .front()
+                .map(|ts| UnixNanos::from(ts.as_u64() + NANOSECONDS_IN_SECOND));
+            Some((ThrottleReason::OrderRate, until))
+        } else {
+            None
+        };
+
+        match throttle {
+            Some((reason, until)) => {
+                let engaged = state.engaged != Some(reason);
+                state.engaged = Some(reason);
+                Err((reason, until, engaged))
+            }
+            None => {
+                state.engaged = None;
+                state.submits.push_back(ts_now);
+                Ok(())
+            }
+        }
+    }
+
+    /// Records an order rejection of the strategy at `ts_now`, returning the time the reject
+    /// cooldown ends if this rejection started it.
+    pub fn on_rejected(&mut self, strategy_id: StrategyId, ts_now: UnixNanos) -> Option<UnixNanos> {
+        let state = self.states.get_mut(&strategy_id)?;
+        let max_rejects = state.config.max_consecutive_rejects?;
+        if state.cooldown_until.is_some() {
+            return None;
+        }
+
+        state.consecutive_rejects += 1;
+        if state.consecutive_rejects < max_rejects {
+            return None;
+        }
+
+        let until = UnixNanos::from(
+            ts_now.as_u64() + state.config.reject_cooldown_ms * NANOSECONDS_IN_MILLISECOND,
+        );
+        state.cooldown_until = Some(until);
+        state.engaged = Some(ThrottleReason::RejectCooldown);
+        Some(until)
+    }
+
+    /// Records an order acceptance of the strategy, resetting its consecutive rejections.
+    pub fn on_accepted(&mut self, strategy_id: &StrategyId) {
+        if let Some(state) = self.states.get_mut(strategy_id) {
+            if state.cooldown_until.is_none() {
+                state.consecutive_rejects = 0;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const MS: u64 = NANOSECONDS_IN_MILLISECOND;
+
+    fn throttles(config: StrategyThrottleConfig) -> (StrategyThrottles, StrategyId) {
+        let strategy_id = StrategyId::from("S-001");
+        let mut throttles = StrategyThrottles::new();
+        throttles.set_config(strategy_id, config);
+        (throttles, strategy_id)
+    }
+
+    #[rstest]
+    fn test_order_rate_throttle_engages_within_window() {
+        let (mut throttles, strategy_id) = throttles(StrategyThrottleConfig {
+            max_orders_per_sec: Some(2),
+            ..Default::default()
+        });
+
+        assert!(throttles.check_submit(strategy_id, 0, 0.into()).is_ok());
+        assert!(throttles
+            .check_submit(strategy_id, 0, (100 * MS).into())
+            .is_ok());
+        assert_eq!(
+            throttles.check_submit(strategy_id, 0, (200 * MS).into()),
+            Err((ThrottleReason::OrderRate, Some((1_000 * MS).into()), true))
+        );
+        // Only engages once while throttled
+        assert_eq!(
+            throttles.check_submit(strategy_id, 0, (300 * MS).into()),
+            Err((ThrottleReason::OrderRate, Some((1_000 * MS).into()), false))
+        );
+        assert!(throttles
+            .check_submit(strategy_id, 0, (1_000 * MS).into())
+            .is_ok());
+    }
+
+    #[rstest]
+    fn test_open_orders_throttle() {
+        let (mut throttles, strategy_id) = throttles(StrategyThrottleConfig {
+            max_open_orders: Some(3),
+            ..Default::default()
+        });
+
+        assert!(throttles.check_submit(strategy_id, 2, 0.into()).is_ok());
+        assert_eq!(
+            throttles.check_submit(strategy_id, 3, 0.into()),
+            Err((ThrottleReason::OpenOrders, None, true))
+        );
+    }
+
+    #[rstest]
+    fn test_reject_cooldown_after_consecutive_rejects() {
+        let (mut throttles, strategy_id) = throttles(StrategyThrottleConfig {
+            max_consecutive_rejects: Some(2),
+            reject_cooldown_ms: 500,
+            ..Default::default()
+        });
+
+        assert_eq!(throttles.on_rejected(strategy_id, 0.into()), None);
+        throttles.on_accepted(&strategy_id);
+        assert_eq!(throttles.on_rejected(strategy_id, 0.into()), None);
+        assert_eq!(
+            throttles.on_rejected(strategy_id, (10 * MS).into()),
+            Some((510 * MS).into())
+        );
+        assert_eq!(
+            throttles.check_submit(strategy_id, 0, (100 * MS).into()),
+            Err((
+                ThrottleReason::RejectCooldown,
+                Some((510 * MS).into()),
+                false
+            ))
+        );
+        assert!(throttles
+            .check_submit(strategy_id, 0, (510 * MS).into())
+            .is_ok());
+    }
+
+    #[rstest]
+    fn test_kill_overrides_throttles_until_restored() {
+        let (mut throttles, strategy_id) = throttles(StrategyThrottleConfig::default());
+
+        throttles.kill(strategy_id);
+
+        assert!(throttles.is_killed(&strategy_id));
+        assert_eq!(
+            throttles.check_submit(strategy_id, 0, 0.into()),
+            Err((ThrottleReason::Killed, None, false))
+        );
+        throttles.restore(&strategy_id);
+        assert!(throttles.check_submit(strategy_id, 0, 0.into()).is_ok());
+    }
+}
@@ -36,6 +36,7 @@
 // Uncomment once we've added trivial debug impls everywhere
 // #![deny(missing_debug_implementations)]
 
+pub mod algorithm;
 pub mod client;
 pub mod engine;
 pub mod matching_core;
@@ -44,7 +45,9 @@ pub mod messages;
 pub mod models;
 pub mod order_emulator;
 pub mod order_manager;
+pub mod peg;
 pub mod reports;
+pub mod router;
 pub mod trailing;
 
 #[cfg(feature = "python")]
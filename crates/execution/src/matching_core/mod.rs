@@ -248,7 +248,9 @@ impl OrderMatchingCore {
     }
 
     pub fn match_stop_order(&mut self, order: &StopOrderAny) {
-        if self.is_stop_matched(order.order_side_specified(), order.stop_px()) {
+        if order.is_activated()
+            && self.is_stop_matched(order.order_side_specified(), order.stop_px())
+        {
             if let Some(handler) = &mut self.trigger_stop_order {
                 handler
                     .0
@@ -23,10 +23,18 @@ pub struct OrderMatchingEngineConfig {
     pub use_position_ids: bool,
     pub use_random_ids: bool,
     pub use_reduce_only: bool,
+    /// If limit orders with a display quantity fill one visible slice at a time as maker,
+    /// with the next slice joining the back of the queue.
+    pub support_iceberg_orders: bool,
+    /// The maximum fraction of the displayed liquidity at each price level an order may
+    /// take per iteration (`None` for no cap). Market orders keep working on later
+    /// iterations for any quantity remaining.
+    pub max_participation_rate: Option<f64>,
 }
 
 impl OrderMatchingEngineConfig {
     /// Creates a new default [`OrderMatchingEngineConfig`] instance.
+    #[allow(clippy::too_many_arguments)]
     #[must_use]
     pub const fn new(
         bar_execution: bool,
@@ -36,6 +44,8 @@ impl OrderMatchingEngineConfig {
         use_position_ids: bool,
         use_random_ids: bool,
         use_reduce_only: bool,
+        support_iceberg_orders: bool,
+        max_participation_rate: Option<f64>,
     ) -> Self {
         Self {
             bar_execution,
@@ -45,6 +55,8 @@ impl OrderMatchingEngineConfig {
             use_position_ids,
             use_random_ids,
             use_reduce_only,
+            support_iceberg_orders,
+            max_participation_rate,
         }
     }
 }
@@ -61,6 +73,8 @@ impl Default for OrderMatchingEngineConfig {
             use_position_ids: false,
             use_random_ids: false,
             use_reduce_only: false,
+            support_iceberg_orders: false,
+            max_participation_rate: None,
         }
     }
 }
@@ -28,7 +28,10 @@ use std::{
 
 use chrono::TimeDelta;
 use nautilus_common::{cache::Cache, msgbus::MessageBus};
-use nautilus_core::{AtomicTime, UnixNanos, UUID4};
+use nautilus_core::{
+    correctness::{check_equal, check_in_range_inclusive_f64, FAILED},
+    AtomicTime, UnixNanos, UUID4,
+};
 use nautilus_model::{
     data::{order::BookOrder, Bar, BarType, OrderBookDelta, OrderBookDeltas, QuoteTick, TradeTick},
     enums::{
@@ -45,24 +48,31 @@ use nautilus_model::{
         VenueOrderId,
     },
     instruments::{InstrumentAny, EXPIRING_INSTRUMENT_TYPES},
-    orderbook::OrderBook,
-    orders::{
-        Order, OrderAny, PassiveOrderAny, StopOrderAny, TrailingStopLimitOrder,
-        TrailingStopMarketOrder,
-    },
+    orderbook::{BookLevel, OrderBook},
+    orders::{LimitOrderAny, Order, OrderAny, PassiveOrderAny, StopOrderAny},
     position::Position,
-    types::{fixed::FIXED_PRECISION, Currency, Money, Price, Quantity},
+    types::{fixed::FIXED_PRECISION, quantity::QuantityRaw, Currency, Money, Price, Quantity},
 };
 use ustr::Ustr;
 
 use crate::{
     matching_core::OrderMatchingCore,
-    matching_engine::{config::OrderMatchingEngineConfig, ids_generator::IdsGenerator},
-    messages::{BatchCancelOrders, CancelAllOrders, CancelOrder, ModifyOrder, QueryOrder},
+    matching_engine::{
+        config::OrderMatchingEngineConfig, ids_generator::IdsGenerator,
+        snapshot::MatchingEngineSnapshot,
+    },
+    messages::{
+        BatchCancelOrders, BatchModifyOrders, CancelAllOrders, CancelOrder, ModifyOrder,
+        QueryOrder, TradingCommand,
+    },
     models::{
         fee::{FeeModel, FeeModelAny},
         fill::FillModel,
+        queue::QueuePosition,
+        slippage::SlippageModel,
     },
+    peg::peg_price_calculate,
+    trailing::trailing_stop_calculate,
 };
 
 /// An order matching engine for a single market.
@@ -89,6 +99,7 @@ pub struct OrderMatchingEngine {
     book: OrderBook,
     pub core: OrderMatchingCore,
     fill_model: FillModel,
+    slippage_model: Option<SlippageModel>,
     fee_model: FeeModelAny,
     target_bid: Option<Price>,
     target_ask: Option<Price>,
@@ -99,6 +110,7 @@ pub struct OrderMatchingEngine {
     execution_bar_deltas: HashMap<BarType, TimeDelta>,
     account_ids: HashMap<TraderId, AccountId>,
     cached_filled_qty: HashMap<ClientOrderId, Quantity>,
+    participating_orders: Vec<OrderAny>,
     ids_generator: IdsGenerator,
 }
 
@@ -117,6 +129,16 @@ impl OrderMatchingEngine {
         cache: Rc<RefCell<Cache>>,
         config: OrderMatchingEngineConfig,
     ) -> Self {
+        if let Some(max_participation_rate) = config.max_participation_rate {
+            check_in_range_inclusive_f64(
+                max_participation_rate,
+                0.0,
+                1.0,
+                "max_participation_rate",
+            )
+            .expect(FAILED);
+        }
+
         let book = OrderBook::new(instrument.id(), book_type);
         let core = OrderMatchingCore::new(
             instrument.id(),
@@ -139,6 +161,7 @@ impl OrderMatchingEngine {
             instrument,
             raw_id,
             fill_model,
+            slippage_model: None,
             fee_model,
             book_type,
             oms_type,
@@ -159,6 +182,7 @@ impl OrderMatchingEngine {
             execution_bar_deltas: HashMap::new(),
             account_ids: HashMap::new(),
             cached_filled_qty: HashMap::new(),
+            participating_orders: Vec::new(),
             ids_generator,
         }
     }
@@ -169,6 +193,10 @@ impl OrderMatchingEngine {
         self.execution_bar_deltas.clear();
         self.account_ids.clear();
         self.cached_filled_qty.clear();
+        self.participating_orders.clear();
+        if let Some(slippage_model) = &mut self.slippage_model {
+            slippage_model.reset();
+        }
         self.core.reset();
         self.target_bid = None;
         self.target_ask = None;
@@ -178,10 +206,195 @@ impl OrderMatchingEngine {
         log::info!("Reset {}", self.instrument.id());
     }
 
-    pub const fn set_fill_model(&mut self, fill_model: FillModel) {
+    pub fn set_fill_model(&mut self, fill_model: FillModel) {
         self.fill_model = fill_model;
     }
 
+    /// Sets the slippage model for market order fills, replacing the single tick slippage of
+    /// the fill model.
+    pub fn set_slippage_model(&mut self, slippage_model: Option<SlippageModel>) {
+        self.slippage_model = slippage_model;
+    }
+
+    pub fn set_fee_model(&mut self, fee_model: FeeModelAny) {
+        self.fee_model = fee_model;
+    }
+
+    /// Returns a snapshot of the matching state of the engine.
+    #[must_use]
+    pub fn snapshot(&self) -> MatchingEngineSnapshot {
+        let (position_count, order_count, execution_count) = self.ids_generator.counts();
+        let mut account_ids: Vec<(TraderId, AccountId)> = self
+            .account_ids
+            .iter()
+            .map(|(trader_id, account_id)| (*trader_id, *account_id))
+            .collect();
+        account_ids.sort();
+        MatchingEngineSnapshot {
+            instrument_id: self.instrument.id(),
+            market_status: self.market_status,
+            book_orders: self
+                .book
+                .bids(None)
+                .chain(self.book.asks(None))
+                .flat_map(BookLevel::get_orders)
+                .collect(),
+            book_sequence: self.book.sequence,
+            book_ts_last: self.book.ts_last,
+            bid: self.core.bid,
+            ask: self.core.ask,
+            last: self.core.last,
+            orders: self
+                .core
+                .get_orders()
+                .into_iter()
+                .map(OrderAny::from)
+                .collect(),
+            participating_orders: self.participating_orders.clone(),
+            account_ids,
+            position_count,
+            order_count,
+            execution_count,
+        }
+    }
+
+    /// Restores the matching state of the engine from the `snapshot`, replacing its book and
+    /// working orders.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the snapshot is for a different instrument.
+    pub fn restore(&mut self, snapshot: &MatchingEngineSnapshot) {
+        check_equal(
+            snapshot.instrument_id,
+            self.instrument.id(),
+            "Snapshot instrument ID",
+            "Matching engine instrument ID",
+        )
+        .expect(FAILED);
+
+        self.reset();
+        self.market_status = snapshot.market_status;
+        for order in &snapshot.book_orders {
+            self.book
+                .add(*order, 0, snapshot.book_sequence, snapshot.book_ts_last);
+        }
+        if let Some(bid) = snapshot.bid {
+            self.core.set_bid_raw(bid);
+        }
+        if let Some(ask) = snapshot.ask {
+            self.core.set_ask_raw(ask);
+        }
+        if let Some(last) = snapshot.last {
+            self.core.set_last_raw(last);
+        }
+        for order in &snapshot.orders {
+            let _ = self.core.add_order(order.clone().into());
+        }
+        self.participating_orders = snapshot.participating_orders.clone();
+        self.account_ids = snapshot.account_ids.iter().copied().collect();
+        self.ids_generator.set_counts(
+            snapshot.position_count,
+            snapshot.order_count,
+            snapshot.execution_count,
+        );
+    }
+
+    /// Applies a split of `split_to` new shares for every `split_from` held to the working
+    /// orders of the engine, scaling the remaining quantity of each order up (and its prices
+    /// down) by the split ratio, rounded to the instrument precisions.
+    ///
+    /// Orders whose remaining quantity rounds to zero are canceled. The book and the last
+    /// market prices are cleared, as prices quoted before the split are no longer comparable.
+    pub fn process_split(&mut self, split_to: u32, split_from: u32) {
+        let ratio = f64::from(split_to) / f64::from(split_from);
+
+        self.book
+            .clear(self.book.sequence, self.clock.get_time_ns());
+        self.core.bid = None;
+        self.core.ask = None;
+        self.core.last = None;
+        self.core.is_bid_initialized = false;
+        self.core.is_ask_initialized = false;
+        self.core.is_last_initialized = false;
+        self.target_bid = None;
+        self.target_ask = None;
+        self.target_last = None;
+        self.last_bar_bid = None;
+        self.last_bar_ask = None;
+
+        for passive_order in self.core.get_orders() {
+            let mut order = passive_order.to_any();
+            let leaves_qty = self
+                .instrument
+                .make_qty(order.leaves_qty().as_f64() * ratio);
+            if leaves_qty.is_zero() {
+                self.cancel_order(&order, Some(false));
+                continue;
+            }
+
+            let quantity = order.filled_qty() + leaves_qty;
+            let price = order
+                .price()
+                .map(|price| self.instrument.make_price(price.as_f64() / ratio));
+            let trigger_price = order
+                .trigger_price()
+                .map(|price| self.instrument.make_price(price.as_f64() / ratio));
+            self.update_order(
+                &mut order,
+                Some(quantity),
+                price,
+                trigger_price,
+                Some(false),
+            );
+
+            // Replace the working order with its adjusted state
+            let _ = self.core.delete_order(&passive_order);
+            if order.is_open() {
+                let _ = self.core.add_order(order.into());
+            }
+        }
+    }
+
+    /// Expires all working orders of the engine (such as when the instrument expires).
+    pub fn expire_open_orders(&mut self) {
+        self.expire_orders(|_| true);
+    }
+
+    /// Expires the working DAY orders of the engine (at the close of the trading day).
+    pub fn expire_day_orders(&mut self) {
+        self.expire_orders(|order| order.time_in_force() == TimeInForce::Day);
+    }
+
+    fn expire_orders(&mut self, predicate: impl Fn(&PassiveOrderAny) -> bool) {
+        for order in self.core.get_orders() {
+            if order.is_closed() || !predicate(&order) {
+                continue;
+            }
+            // SAFETY: We know this order is in the core
+            self.core.delete_order(&order).unwrap();
+            self.cached_filled_qty.remove(&order.client_order_id());
+            self.expire_order(&order);
+        }
+    }
+
+    /// Cancels all working orders of the engine (such as when the instrument is delisted
+    /// under its current ID).
+    pub fn cancel_open_orders(&mut self) {
+        let orders: Vec<OrderAny> = self
+            .core
+            .get_orders()
+            .into_iter()
+            .map(OrderAny::from)
+            .chain(self.participating_orders.iter().cloned())
+            .collect();
+        for order in &orders {
+            if order.is_open() {
+                self.cancel_order(order, Some(false));
+            }
+        }
+    }
+
     #[must_use]
     pub fn best_bid_price(&self) -> Option<Price> {
         self.book.best_bid_price()
@@ -197,6 +410,20 @@ impl OrderMatchingEngine {
         &self.book
     }
 
+    #[must_use]
+    pub const fn get_slippage_model(&self) -> Option<&SlippageModel> {
+        self.slippage_model.as_ref()
+    }
+
+    /// Returns the estimated queue position of the resting limit order, if the fill model
+    /// tracks queue positions.
+    #[must_use]
+    pub fn get_queue_position(&self, client_order_id: &ClientOrderId) -> Option<&QueuePosition> {
+        self.fill_model
+            .queue_positions()
+            .and_then(|queue_positions| queue_positions.get(client_order_id))
+    }
+
     #[must_use]
     pub fn get_open_bid_orders(&self) -> &[PassiveOrderAny] {
         self.core.get_orders_bid()
@@ -230,6 +457,7 @@ impl OrderMatchingEngine {
         if self.book_type == BookType::L2_MBP || self.book_type == BookType::L3_MBO {
             self.book.apply_delta(delta);
         }
+        self.update_queue_positions();
 
         self.iterate(delta.ts_event);
     }
@@ -240,6 +468,7 @@ impl OrderMatchingEngine {
         if self.book_type == BookType::L2_MBP || self.book_type == BookType::L3_MBO {
             self.book.apply_deltas(deltas);
         }
+        self.update_queue_positions();
 
         self.iterate(deltas.ts_event);
     }
@@ -250,6 +479,7 @@ impl OrderMatchingEngine {
         if self.book_type == BookType::L1_MBP {
             self.book.update_quote_tick(quote).unwrap();
         }
+        self.update_queue_positions();
 
         self.iterate(quote.ts_event);
     }
@@ -445,6 +675,11 @@ impl OrderMatchingEngine {
     pub fn process_trade_tick(&mut self, trade: &TradeTick) {
         log::debug!("Processing {trade}");
 
+        // Consume the queues before the book reflects the trade
+        if let Some(queue_positions) = self.fill_model.queue_positions_mut() {
+            queue_positions.update_from_trade(trade);
+        }
+
         if self.book_type == BookType::L1_MBP {
             self.book.update_trade_tick(trade).unwrap();
         }
@@ -453,6 +688,13 @@ impl OrderMatchingEngine {
         self.iterate(trade.ts_event);
     }
 
+    fn update_queue_positions(&mut self) {
+        if let Some(queue_positions) = self.fill_model.queue_positions_mut() {
+            queue_positions.retain(|client_order_id| self.core.order_exists(*client_order_id));
+            queue_positions.update_from_book(&self.book);
+        }
+    }
+
     pub fn process_status(&mut self, action: MarketStatusAction) {
         log::debug!("Processing {action}");
 
@@ -494,6 +736,20 @@ impl OrderMatchingEngine {
             // Index identifiers
             self.account_ids.insert(order.trader_id(), account_id);
 
+            // Check market is open for trading
+            if self.market_status != MarketStatus::Open {
+                self.generate_order_rejected(
+                    order,
+                    format!(
+                        "Market for {} is {}",
+                        self.instrument.id(),
+                        self.market_status.to_string().to_lowercase()
+                    )
+                    .into(),
+                );
+                return;
+            }
+
             // Check for instrument expiration or activation
             if EXPIRING_INSTRUMENT_TYPES.contains(&self.instrument.instrument_class()) {
                 if let Some(activation_ns) = self.instrument.activation_ns() {
@@ -597,6 +853,35 @@ impl OrderMatchingEngine {
                 return;
             }
 
+            // Check iceberg orders are supported
+            if let Some(display_qty) = order.display_qty() {
+                if display_qty < order.quantity() && !self.config.support_iceberg_orders {
+                    self.generate_order_rejected(
+                        order,
+                        format!(
+                            "Iceberg orders not supported for {}, `display_qty` {display_qty} was less than `quantity` {}",
+                            self.venue,
+                            order.quantity()
+                        )
+                        .into(),
+                    );
+                    return;
+                }
+            }
+
+            // Check GTX orders have a price to post to the book at
+            if order.time_in_force() == TimeInForce::Gtx && order.price().is_none() {
+                self.generate_order_rejected(
+                    order,
+                    format!(
+                        "GTX time in force not supported for {} orders",
+                        order.order_type().to_string().to_uppercase()
+                    )
+                    .into(),
+                );
+                return;
+            }
+
             // Check for valid order price precision
             if let Some(price) = order.price() {
                 if price.precision != self.instrument.price_precision() {
@@ -697,15 +982,36 @@ impl OrderMatchingEngine {
             OrderType::StopLimit => self.process_stop_limit_order(order),
             OrderType::MarketIfTouched => self.process_market_if_touched_order(order),
             OrderType::LimitIfTouched => self.process_limit_if_touched_order(order),
-            OrderType::TrailingStopMarket => self.process_trailing_stop_market_order(order),
-            OrderType::TrailingStopLimit => self.process_trailing_stop_limit_order(order),
+            OrderType::TrailingStopMarket | OrderType::TrailingStopLimit => {
+                self.process_trailing_stop_order(order);
+            }
         }
     }
 
     pub fn process_modify(&mut self, command: &ModifyOrder, account_id: AccountId) {
         if let Some(order) = self.core.get_order(command.client_order_id) {
+            let mut order = order.to_any();
+            if let (Some(quantity), Some(display_qty)) = (command.quantity, order.display_qty()) {
+                if quantity < display_qty {
+                    self.generate_order_modify_rejected(
+                        command.trader_id,
+                        command.strategy_id,
+                        command.instrument_id,
+                        command.client_order_id,
+                        Ustr::from(
+                            format!(
+                                "New quantity {quantity} was less than `display_qty` {display_qty}"
+                            )
+                            .as_str(),
+                        ),
+                        Some(command.venue_order_id),
+                        Some(account_id),
+                    );
+                    return;
+                }
+            }
             self.update_order(
-                &mut order.to_any(),
+                &mut order,
                 command.quantity,
                 command.price,
                 command.trigger_price,
@@ -731,38 +1037,58 @@ impl OrderMatchingEngine {
                     self.cancel_order(&OrderAny::from(passive_order.to_owned()), None);
                 }
             }
-            None => self.generate_order_cancel_rejected(
-                command.trader_id,
-                command.strategy_id,
-                account_id,
-                command.instrument_id,
-                command.client_order_id,
-                command.venue_order_id,
-                Ustr::from(format!("Order {} not found", command.client_order_id).as_str()),
-            ),
+            None => {
+                if let Some(order) = self
+                    .participating_orders
+                    .iter()
+                    .find(|order| order.client_order_id() == command.client_order_id)
+                    .cloned()
+                {
+                    self.cancel_order(&order, None);
+                    return;
+                }
+
+                self.generate_order_cancel_rejected(
+                    command.trader_id,
+                    command.strategy_id,
+                    account_id,
+                    command.instrument_id,
+                    command.client_order_id,
+                    command.venue_order_id,
+                    Ustr::from(format!("Order {} not found", command.client_order_id).as_str()),
+                );
+            }
         }
     }
 
+    /// Cancels the open orders of the instrument on the side of the `command`, as the venue
+    /// does regardless of the strategy the orders belong to.
     pub fn process_cancel_all(&mut self, command: &CancelAllOrders, account_id: AccountId) {
         let open_orders = self
             .cache
             .borrow()
-            .orders_open(None, Some(&command.instrument_id), None, None)
+            .orders_open(
+                None,
+                Some(&self.instrument.id()),
+                None,
+                command.side_filter(),
+            )
             .into_iter()
             .cloned()
             .collect::<Vec<OrderAny>>();
         for order in open_orders {
-            if command.order_side != OrderSide::NoOrderSide
-                && command.order_side != order.order_side()
-            {
-                continue;
-            }
             if order.is_inflight() || order.is_open() {
                 self.cancel_order(&order, None);
             }
         }
     }
 
+    pub fn process_batch_modify(&mut self, command: &BatchModifyOrders, account_id: AccountId) {
+        for modify in &command.modifies {
+            self.process_modify(modify, account_id);
+        }
+    }
+
     pub fn process_batch_cancel(&mut self, command: &BatchCancelOrders, account_id: AccountId) {
         for order in &command.cancels {
             self.process_cancel(order, account_id);
@@ -773,6 +1099,70 @@ impl OrderMatchingEngine {
         todo!("implement process_query_order")
     }
 
+    /// Rejects the trading `command` without processing it, generating the rejection event for
+    /// each order the command is for with the `reason`.
+    ///
+    /// Commands to cancel all orders or to query an order have no rejection event, so are
+    /// dropped with a warning.
+    pub fn reject_command(
+        &mut self,
+        command: &TradingCommand,
+        account_id: AccountId,
+        reason: Ustr,
+    ) {
+        match command {
+            TradingCommand::SubmitOrder(command) => {
+                self.account_ids.insert(command.trader_id, account_id);
+                self.generate_order_rejected(&command.order, reason);
+            }
+            TradingCommand::SubmitOrderList(command) => {
+                self.account_ids.insert(command.trader_id, account_id);
+                for order in &command.order_list.orders {
+                    self.generate_order_rejected(order, reason);
+                }
+            }
+            TradingCommand::ModifyOrder(command) => self.reject_modify(command, account_id, reason),
+            TradingCommand::BatchModifyOrders(command) => {
+                for modify in &command.modifies {
+                    self.reject_modify(modify, account_id, reason);
+                }
+            }
+            TradingCommand::CancelOrder(command) => self.reject_cancel(command, account_id, reason),
+            TradingCommand::BatchCancelOrders(command) => {
+                for cancel in &command.cancels {
+                    self.reject_cancel(cancel, account_id, reason);
+                }
+            }
+            TradingCommand::CancelAllOrders(_) | TradingCommand::QueryOrder(_) => {
+                log::warn!("Dropped {command:?}: {reason}");
+            }
+        }
+    }
+
+    fn reject_modify(&self, command: &ModifyOrder, account_id: AccountId, reason: Ustr) {
+        self.generate_order_modify_rejected(
+            command.trader_id,
+            command.strategy_id,
+            command.instrument_id,
+            command.client_order_id,
+            reason,
+            Some(command.venue_order_id),
+            Some(account_id),
+        );
+    }
+
+    fn reject_cancel(&self, command: &CancelOrder, account_id: AccountId, reason: Ustr) {
+        self.generate_order_cancel_rejected(
+            command.trader_id,
+            command.strategy_id,
+            account_id,
+            command.instrument_id,
+            command.client_order_id,
+            command.venue_order_id,
+            reason,
+        );
+    }
+
     fn process_market_order(&mut self, order: &mut OrderAny) {
         if order.time_in_force() == TimeInForce::AtTheOpen
             || order.time_in_force() == TimeInForce::AtTheClose
@@ -830,6 +1220,9 @@ impl OrderMatchingEngine {
         // Order is valid and accepted
         self.accept_order(order);
 
+        // Pegged orders are repriced on acceptance
+        let limit_px = order.price().expect("Limit order must have a price");
+
         // Check for immediate fill
         if self
             .core
@@ -844,6 +1237,14 @@ impl OrderMatchingEngine {
             self.fill_limit_order(order);
         } else if matches!(order.time_in_force(), TimeInForce::Fok | TimeInForce::Ioc) {
             self.cancel_order(order, None);
+        } else if let Some(queue_positions) = self.fill_model.queue_positions_mut() {
+            // Resting at the back of the queue for its price level
+            queue_positions.add_order(
+                order.client_order_id(),
+                order.order_side(),
+                limit_px,
+                &self.book,
+            );
         }
     }
 
@@ -924,6 +1325,7 @@ impl OrderMatchingEngine {
                 order.set_liquidity_side(LiquiditySide::Taker);
                 self.fill_limit_order(order);
             }
+            return;
         }
 
         // order is not matched but is valid and we accept it
@@ -1002,12 +1404,38 @@ impl OrderMatchingEngine {
         self.accept_order(order);
     }
 
-    fn process_trailing_stop_market_order(&mut self, order: &OrderAny) {
-        todo!("process_trailing_stop_market_order")
-    }
+    fn process_trailing_stop_order(&mut self, order: &mut OrderAny) {
+        // Orders awaiting activation have no live trigger price
+        if order.is_activated() == Some(true) {
+            let trigger_price = order
+                .trigger_price()
+                .expect("Trailing stop order must have a trigger price");
+            if self
+                .core
+                .is_stop_matched(order.order_side_specified(), trigger_price)
+            {
+                self.generate_order_rejected(
+                    order,
+                    format!(
+                        "{} {} order trigger stop px of {} was in the market: bid={}, ask={}",
+                        order.order_type(),
+                        order.order_side(),
+                        trigger_price,
+                        self.core
+                            .bid
+                            .map_or_else(|| "None".to_string(), |p| p.to_string()),
+                        self.core
+                            .ask
+                            .map_or_else(|| "None".to_string(), |p| p.to_string())
+                    )
+                    .into(),
+                );
+                return;
+            }
+        }
 
-    fn process_trailing_stop_limit_order(&mut self, order: &OrderAny) {
-        todo!("process_trailing_stop_limit_order")
+        // Order is valid and accepted
+        self.accept_order(order);
     }
 
     // -- ORDER PROCESSING ----------------------------------------------------
@@ -1029,6 +1457,19 @@ impl OrderMatchingEngine {
         self.core.bid = self.book.best_bid_price();
         self.core.ask = self.book.best_ask_price();
 
+        if let (Some(slippage_model), Some(bid), Some(ask)) =
+            (&mut self.slippage_model, self.core.bid, self.core.ask)
+        {
+            slippage_model.update_price(Price::from_raw((bid.raw + ask.raw) / 2, bid.precision));
+        }
+
+        if self.market_status != MarketStatus::Open {
+            // No matching while the market is closed or halted
+            return;
+        }
+
+        self.iterate_participating_orders();
+
         let orders_bid = self.core.get_orders_bid().to_vec();
         let orders_ask = self.core.get_orders_ask().to_vec();
 
@@ -1036,6 +1477,18 @@ impl OrderMatchingEngine {
         self.iterate_orders(timestamp_ns, &orders_ask);
     }
 
+    fn iterate_participating_orders(&mut self) {
+        let orders = std::mem::take(&mut self.participating_orders);
+        for mut order in orders {
+            if self.determine_market_price_and_volume(&order).is_empty() {
+                // No liquidity to take until the book updates
+                self.participating_orders.push(order);
+                continue;
+            }
+            self.fill_market_order(&mut order);
+        }
+    }
+
     fn iterate_orders(&mut self, timestamp_ns: UnixNanos, orders: &[PassiveOrderAny]) {
         for order in orders {
             if order.is_closed() {
@@ -1050,16 +1503,23 @@ impl OrderMatchingEngine {
                         self.core.delete_order(order).unwrap();
                         self.cached_filled_qty.remove(&order.client_order_id());
                         self.expire_order(order);
+                        continue;
                     }
                 }
             }
 
             // Manage trailing stop
-            if let PassiveOrderAny::Stop(o) = order {
-                match o {
-                    StopOrderAny::TrailingStopMarket(o) => self.update_trailing_stop_market(o),
-                    StopOrderAny::TrailingStopLimit(o) => self.update_trailing_stop_limit(o),
-                    _ => {}
+            if let PassiveOrderAny::Stop(
+                StopOrderAny::TrailingStopMarket(_) | StopOrderAny::TrailingStopLimit(_),
+            ) = order
+            {
+                self.iterate_trailing_stop_order(order.to_any());
+            }
+
+            // Manage pegged limit order
+            if let PassiveOrderAny::Limit(LimitOrderAny::Limit(limit)) = order {
+                if limit.peg_price_type.is_some() {
+                    self.iterate_pegged_order(order.to_any());
                 }
             }
 
@@ -1089,9 +1549,13 @@ impl OrderMatchingEngine {
             Some(order_price) => {
                 // construct book order with price as passive with limit order price
                 let book_order =
-                    BookOrder::new(order.order_side(), order_price, order.quantity(), 1);
+                    BookOrder::new(order.order_side(), order_price, self.leaves_qty(order), 1);
 
                 let mut fills = self.book.simulate_fills(&book_order);
+                if let Some(visible_qty) = self.iceberg_visible_qty(order) {
+                    fills = truncate_fills(fills, visible_qty);
+                }
+                fills = self.apply_participation_cap(order.order_side(), fills);
 
                 // return immediately if no fills
                 if fills.is_empty() {
@@ -1196,8 +1660,111 @@ impl OrderMatchingEngine {
         };
 
         // Construct BookOrder from order
-        let book_order = BookOrder::new(order.order_side(), price, order.quantity(), 0);
-        self.book.simulate_fills(&book_order)
+        let book_order = BookOrder::new(order.order_side(), price, self.leaves_qty(order), 0);
+        let fills = self.book.simulate_fills(&book_order);
+        self.apply_participation_cap(order.order_side(), fills)
+    }
+
+    /// Returns the quantity of the `order` still to be filled by the engine.
+    fn leaves_qty(&self, order: &OrderAny) -> Quantity {
+        match self.cached_filled_qty.get(&order.client_order_id()) {
+            Some(filled_qty) if *filled_qty < order.quantity() => order.quantity() - *filled_qty,
+            Some(_) => Quantity::zero(order.quantity().precision),
+            None => order.leaves_qty(),
+        }
+    }
+
+    /// Caps the `fills` at each price level to the configured participation rate of the
+    /// displayed liquidity, taking at least one size increment per level.
+    fn apply_participation_cap(
+        &self,
+        order_side: OrderSide,
+        fills: Vec<(Price, Quantity)>,
+    ) -> Vec<(Price, Quantity)> {
+        let Some(max_participation_rate) = self.config.max_participation_rate else {
+            return fills;
+        };
+
+        let size_increment_raw = self.instrument.size_increment().raw;
+        let mut remaining_caps: HashMap<Price, QuantityRaw> = HashMap::new();
+        let mut capped_fills = Vec::with_capacity(fills.len());
+        for (fill_px, fill_qty) in fills {
+            let remaining_cap = remaining_caps.entry(fill_px).or_insert_with(|| {
+                let level_size_raw = match order_side.as_specified() {
+                    OrderSideSpecified::Buy => self
+                        .book
+                        .asks(None)
+                        .find(|level| level.price.value == fill_px)
+                        .map(|level| level.size_raw()),
+                    OrderSideSpecified::Sell => self
+                        .book
+                        .bids(None)
+                        .find(|level| level.price.value == fill_px)
+                        .map(|level| level.size_raw()),
+                }
+                .unwrap_or(fill_qty.raw);
+                let cap_raw = (level_size_raw as f64 * max_participation_rate) as QuantityRaw;
+                (cap_raw - cap_raw % size_increment_raw).max(size_increment_raw)
+            });
+
+            let qty_raw = fill_qty.raw.min(*remaining_cap);
+            if qty_raw == 0 {
+                continue;
+            }
+            *remaining_cap -= qty_raw;
+            capped_fills.push((fill_px, Quantity::from_raw(qty_raw, fill_qty.precision)));
+        }
+        capped_fills
+    }
+
+    /// Returns the quantity remaining in the visible slice of an iceberg `order` filling as
+    /// maker, or `None` if the whole order is fillable.
+    fn iceberg_visible_qty(&self, order: &OrderAny) -> Option<Quantity> {
+        if !self.config.support_iceberg_orders
+            || order.liquidity_side() != Some(LiquiditySide::Maker)
+        {
+            return None;
+        }
+
+        let display_qty = order
+            .display_qty()
+            .filter(|display_qty| display_qty.is_positive() && *display_qty < order.quantity())?;
+        let filled_raw = order.quantity().raw - self.leaves_qty(order).raw;
+        let slice_filled_raw = filled_raw % display_qty.raw;
+
+        Some(Quantity::from_raw(
+            display_qty.raw - slice_filled_raw,
+            display_qty.precision,
+        ))
+    }
+
+    /// Sends the iceberg `order` to the back of the queue once its visible slice has been
+    /// filled and a new slice is displayed.
+    fn replenish_iceberg_order(&mut self, order: &OrderAny) {
+        let Some(display_qty) = order.display_qty() else {
+            return;
+        };
+        let leaves_qty = self.leaves_qty(order);
+        let filled_raw = order.quantity().raw - leaves_qty.raw;
+        let slice_filled_raw = filled_raw % display_qty.raw;
+        if leaves_qty.is_zero() || filled_raw == 0 || slice_filled_raw != 0 {
+            return;
+        }
+
+        log::debug!(
+            "Replenishing iceberg {} with {} of {leaves_qty} remaining",
+            order.client_order_id(),
+            min(display_qty, leaves_qty),
+        );
+
+        if let Some(queue_positions) = self.fill_model.queue_positions_mut() {
+            queue_positions.add_order(
+                order.client_order_id(),
+                order.order_side(),
+                order.price().expect("Limit order must have a price"),
+                &self.book,
+            );
+        }
     }
 
     pub fn fill_market_order(&mut self, order: &mut OrderAny) {
@@ -1232,8 +1799,37 @@ impl OrderMatchingEngine {
         }
         // set order side as taker
         order.set_liquidity_side(LiquiditySide::Taker);
-        let fills = self.determine_market_price_and_volume(order);
+        let mut fills = self.determine_market_price_and_volume(order);
+        if let Some(slippage_model) = &self.slippage_model {
+            // Capped fills are not walked beyond, the remainder is worked on later iterations
+            let quantity = if self.config.max_participation_rate.is_some() {
+                fills.iter().fold(
+                    Quantity::zero(order.quantity().precision),
+                    |total, (_, qty)| total + *qty,
+                )
+            } else {
+                self.leaves_qty(order)
+            };
+            fills = slippage_model.apply(
+                order.order_side().as_specified(),
+                quantity,
+                fills,
+                self.instrument.price_increment(),
+            );
+        }
         self.apply_fills(order, fills, LiquiditySide::Taker, None, position);
+
+        // Keep working the remaining quantity on later iterations
+        if self.config.max_participation_rate.is_some()
+            && order.order_type() == OrderType::Market
+            && !matches!(order.time_in_force(), TimeInForce::Ioc | TimeInForce::Fok)
+            && self
+                .cached_filled_qty
+                .get(&order.client_order_id())
+                .is_some_and(|filled_qty| *filled_qty < order.quantity())
+        {
+            self.participating_orders.push(order.clone());
+        }
     }
 
     pub fn fill_limit_order(&mut self, order: &mut OrderAny) {
@@ -1257,14 +1853,18 @@ impl OrderMatchingEngine {
                 {
                     if order.order_side() == OrderSide::Buy
                         && self.core.bid.is_some_and(|bid| bid == order_price)
-                        && !self.fill_model.is_limit_filled()
+                        && !self
+                            .fill_model
+                            .is_resting_limit_filled(&order.client_order_id())
                     {
                         // no filled
                         return;
                     }
                     if order.order_side() == OrderSide::Sell
                         && self.core.ask.is_some_and(|ask| ask == order_price)
-                        && !self.fill_model.is_limit_filled()
+                        && !self
+                            .fill_model
+                            .is_resting_limit_filled(&order.client_order_id())
                     {
                         // no filled
                         return;
@@ -1288,6 +1888,7 @@ impl OrderMatchingEngine {
                     return;
                 }
 
+                let is_iceberg = self.iceberg_visible_qty(order).is_some();
                 let fills = self.determine_limit_price_and_volume(order);
 
                 self.apply_fills(
@@ -1297,6 +1898,10 @@ impl OrderMatchingEngine {
                     venue_position_id,
                     position,
                 );
+
+                if is_iceberg {
+                    self.replenish_iceberg_order(order);
+                }
             }
             None => panic!("Limit order must have a price"),
         }
@@ -1375,7 +1980,10 @@ impl OrderMatchingEngine {
                 initial_market_to_limit_fill = true;
             }
 
-            if self.book_type == BookType::L1_MBP && self.fill_model.is_slipped() {
+            if self.book_type == BookType::L1_MBP
+                && self.slippage_model.is_none()
+                && self.fill_model.is_slipped()
+            {
                 fill_px = match order.order_side().as_specified() {
                     OrderSideSpecified::Buy => fill_px.add(self.instrument.price_increment()),
                     OrderSideSpecified::Sell => fill_px.sub(self.instrument.price_increment()),
@@ -1531,6 +2139,18 @@ impl OrderMatchingEngine {
             self.fill_limit_order(order);
             return;
         }
+
+        // Repricing loses queue priority
+        if order.price() != Some(price) {
+            if let Some(queue_positions) = self.fill_model.queue_positions_mut() {
+                queue_positions.add_order(
+                    order.client_order_id(),
+                    order.order_side(),
+                    price,
+                    &self.book,
+                );
+            }
+        }
         self.generate_order_updated(order, quantity, Some(price), None);
     }
 
@@ -1760,12 +2380,136 @@ impl OrderMatchingEngine {
         self.generate_order_updated(order, quantity, Some(price), Some(trigger_price));
     }
 
-    fn update_trailing_stop_market(&mut self, order: &TrailingStopMarketOrder) {
-        todo!()
+    fn iterate_trailing_stop_order(&mut self, mut order: OrderAny) {
+        if order.status() == OrderStatus::Triggered {
+            // Triggered order works as a limit order
+            let limit_px = order
+                .price()
+                .expect("Trailing stop limit order must have a price");
+            if self
+                .core
+                .is_limit_matched(order.order_side_specified(), limit_px)
+            {
+                order.set_liquidity_side(LiquiditySide::Maker);
+                self.fill_limit_order(&mut order);
+                self.delete_order_if_filled(&order);
+            }
+            return;
+        }
+
+        if self.update_trailing_stop_order(&mut order) {
+            // Replace the order in the matching core with the updated order
+            let passive_order = PassiveOrderAny::from(order.clone());
+            let _ = self.core.delete_order(&passive_order);
+            let _ = self.core.add_order(passive_order);
+        }
+
+        if order.is_activated() == Some(true)
+            && self
+                .core
+                .is_stop_matched(order.order_side_specified(), order.trigger_price().unwrap())
+        {
+            self.trigger_stop_order(&mut order);
+        }
     }
 
-    fn update_trailing_stop_limit(&mut self, order: &TrailingStopLimitOrder) {
-        todo!()
+    /// Trails the trigger (and limit) price of the trailing stop `order` behind the market,
+    /// returning whether the order was updated.
+    fn update_trailing_stop_order(&mut self, order: &mut OrderAny) -> bool {
+        let mark = self
+            .cache
+            .borrow()
+            .price(&self.instrument.id(), PriceType::Mark);
+        let (new_trigger_price, new_price) = match trailing_stop_calculate(
+            self.instrument.price_increment(),
+            order,
+            self.core.bid,
+            self.core.ask,
+            self.core.last,
+            mark,
+        ) {
+            Ok(output) => output,
+            Err(e) => {
+                log::warn!("Cannot calculate trailing stop order: {e}");
+                return false;
+            }
+        };
+
+        if new_trigger_price.is_none() && new_price.is_none() {
+            return false; // No updates
+        }
+
+        if order.is_activated() == Some(false) {
+            order.set_activated();
+        }
+
+        let quantity = order.quantity();
+        self.generate_order_updated(order, quantity, new_price, new_trigger_price);
+        true
+    }
+
+    fn iterate_pegged_order(&mut self, mut order: OrderAny) {
+        if !self.update_pegged_order(&mut order) {
+            return;
+        }
+
+        // Replace the order in the matching core with the repriced order
+        let passive_order = PassiveOrderAny::from(order.clone());
+        let _ = self.core.delete_order(&passive_order);
+        let _ = self.core.add_order(passive_order);
+
+        let limit_px = order.price().expect("Pegged order must have a price");
+        if self
+            .core
+            .is_limit_matched(order.order_side_specified(), limit_px)
+        {
+            // Repriced through the market, so filling as liquidity taker
+            order.set_liquidity_side(LiquiditySide::Taker);
+            self.fill_limit_order(&mut order);
+            self.delete_order_if_filled(&order);
+        }
+    }
+
+    /// Reprices the pegged limit `order` from its reference price, returning whether the order
+    /// was updated.
+    ///
+    /// Post-only orders are not repriced through the market.
+    fn update_pegged_order(&mut self, order: &mut OrderAny) -> bool {
+        let new_price = match peg_price_calculate(
+            self.instrument.price_increment(),
+            order,
+            self.core.bid,
+            self.core.ask,
+        ) {
+            Ok(Some(new_price)) => new_price,
+            Ok(None) => return false, // No updates
+            Err(e) => {
+                log::warn!("Cannot calculate pegged order price: {e}");
+                return false;
+            }
+        };
+
+        if order.is_post_only()
+            && self
+                .core
+                .is_limit_matched(order.order_side_specified(), new_price)
+        {
+            return false;
+        }
+
+        // Repricing loses queue priority
+        if let Some(queue_positions) = self.fill_model.queue_positions_mut() {
+            queue_positions.add_order(
+                order.client_order_id(),
+                order.order_side(),
+                new_price,
+                &self.book,
+            );
+        }
+
+        let quantity = order.quantity();
+        self.generate_order_updated(order, quantity, Some(new_price), None);
+        true
     }
 
     // -- EVENT HANDLING -----------------------------------------------------
@@ -1782,15 +2526,12 @@ impl OrderMatchingEngine {
             if matches!(
                 order.order_type(),
                 OrderType::TrailingStopLimit | OrderType::TrailingStopMarket
-            ) && order.trigger_price().is_none()
-            {
-                match order.order_type() {
-                    OrderType::TrailingStopLimit => self
-                        .update_trailing_stop_limit(&TrailingStopLimitOrder::from(order.clone())),
-                    OrderType::TrailingStopMarket => self
-                        .update_trailing_stop_market(&TrailingStopMarketOrder::from(order.clone())),
-                    _ => {}
-                }
+            ) {
+                self.update_trailing_stop_order(order);
+            }
+
+            if order.peg_price_type().is_some() {
+                self.update_pegged_order(order);
             }
         }
 
@@ -1819,10 +2560,18 @@ impl OrderMatchingEngine {
             return;
         }
 
-        // delete order from OrderMatchingCore
-        let _ = self
-            .core
-            .delete_order(&PassiveOrderAny::from(order.clone()));
+        if let Some(index) = self
+            .participating_orders
+            .iter()
+            .position(|o| o.client_order_id() == order.client_order_id())
+        {
+            self.participating_orders.remove(index);
+        } else {
+            // delete order from OrderMatchingCore
+            let _ = self
+                .core
+                .delete_order(&PassiveOrderAny::from(order.clone()));
+        }
         self.cached_filled_qty.remove(&order.client_order_id());
 
         let venue_order_id = self.ids_generator.get_venue_order_id(order).unwrap();
@@ -1873,13 +2622,13 @@ impl OrderMatchingEngine {
             }
             OrderAny::TrailingStopMarket(_) => {
                 let trigger_price = trigger_price.unwrap_or(order.trigger_price().unwrap());
-                self.update_market_if_touched_order(order, quantity, trigger_price);
+                self.update_stop_market_order(order, quantity, trigger_price);
             }
             OrderAny::TrailingStopLimit(trailing_stop_limit_order) => {
                 let price = price.unwrap_or(trailing_stop_limit_order.price().unwrap());
                 let trigger_price =
                     trigger_price.unwrap_or(trailing_stop_limit_order.trigger_price().unwrap());
-                self.update_limit_if_touched_order(order, quantity, price, trigger_price);
+                self.update_stop_limit_order(order, quantity, price, trigger_price);
             }
             _ => {
                 panic!(
@@ -1900,7 +2649,105 @@ impl OrderMatchingEngine {
     }
 
     pub fn trigger_stop_order(&mut self, order: &mut OrderAny) {
-        todo!("trigger_stop_order")
+        match order.order_type() {
+            OrderType::StopMarket | OrderType::MarketIfTouched | OrderType::TrailingStopMarket => {
+                let _ = self
+                    .core
+                    .delete_order(&PassiveOrderAny::from(order.clone()));
+                self.fill_market_order(order);
+            }
+            OrderType::StopLimit | OrderType::LimitIfTouched | OrderType::TrailingStopLimit => {
+                self.trigger_stop_limit_order(order);
+            }
+            _ => panic!(
+                "Unsupported order type {} for trigger_stop_order",
+                order.order_type()
+            ),
+        }
+    }
+
+    fn trigger_stop_limit_order(&mut self, order: &mut OrderAny) {
+        let trigger_price = order
+            .trigger_price()
+            .expect("Stop limit order must have a trigger price");
+        let price = order.price().expect("Stop limit order must have a price");
+        let side = order.order_side_specified();
+
+        let at_trigger = match side {
+            OrderSideSpecified::Buy => self.core.ask == Some(trigger_price),
+            OrderSideSpecified::Sell => self.core.bid == Some(trigger_price),
+        };
+        if at_trigger && !self.fill_model.is_stop_filled() {
+            return; // Not triggered
+        }
+
+        // Replace the order in the matching core with the triggered order
+        let _ = self
+            .core
+            .delete_order(&PassiveOrderAny::from(order.clone()));
+        self.generate_order_triggered(order);
+        let _ = self.core.add_order(order.clone().into());
+
+        // Check for immediate fill
+        let is_maker_fill = match side {
+            OrderSideSpecified::Buy => self
+                .core
+                .ask
+                .is_some_and(|ask| trigger_price > price && price > ask),
+            OrderSideSpecified::Sell => self
+                .core
+                .bid
+                .is_some_and(|bid| trigger_price < price && price < bid),
+        };
+        if is_maker_fill {
+            order.set_liquidity_side(LiquiditySide::Maker);
+            self.fill_limit_order(order);
+            self.delete_order_if_filled(order);
+            return;
+        }
+
+        if self.core.is_limit_matched(side, price) {
+            if order.is_post_only() {
+                // Would be liquidity taker
+                let _ = self
+                    .core
+                    .delete_order(&PassiveOrderAny::from(order.clone()));
+                self.cached_filled_qty.remove(&order.client_order_id());
+                self.generate_order_rejected(
+                    order,
+                    format!(
+                        "POST_ONLY {} {} order limit px of {} would have been a TAKER: bid={}, ask={}",
+                        order.order_type(),
+                        order.order_side(),
+                        price,
+                        self.core
+                            .bid
+                            .map_or_else(|| "None".to_string(), |p| p.to_string()),
+                        self.core
+                            .ask
+                            .map_or_else(|| "None".to_string(), |p| p.to_string())
+                    )
+                    .into(),
+                );
+                return;
+            }
+            order.set_liquidity_side(LiquiditySide::Taker);
+            self.fill_limit_order(order);
+            self.delete_order_if_filled(order);
+        }
+    }
+
+    fn delete_order_if_filled(&mut self, order: &OrderAny) {
+        if self
+            .cached_filled_qty
+            .get(&order.client_order_id())
+            .is_some_and(|filled_qty| *filled_qty >= order.quantity())
+        {
+            let _ = self
+                .core
+                .delete_order(&PassiveOrderAny::from(order.clone()));
+            self.cached_filled_qty.remove(&order.client_order_id());
+        }
     }
 
     fn update_contingent_order(&mut self, order: &OrderAny) {
@@ -2079,7 +2926,7 @@ impl OrderMatchingEngine {
         msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
     }
 
-    fn generate_order_triggered(&self, order: &OrderAny) {
+    fn generate_order_triggered(&self, order: &mut OrderAny) {
         let ts_now = self.clock.get_time_ns();
         let event = OrderEventAny::Triggered(OrderTriggered::new(
             order.trader_id(),
@@ -2095,6 +2942,9 @@ impl OrderMatchingEngine {
         ));
         let msgbus = self.msgbus.as_ref().borrow();
         msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
+
+        // TODO remove this when execution engine msgbus handlers are correctly set
+        order.apply(event).expect("Failed to apply order event");
     }
 
     fn generate_order_expired(&self, order: &OrderAny) {
@@ -2156,3 +3006,18 @@ impl OrderMatchingEngine {
         msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
     }
 }
+
+/// Truncates the `fills` so their total quantity does not exceed `max_qty`.
+fn truncate_fills(fills: Vec<(Price, Quantity)>, max_qty: Quantity) -> Vec<(Price, Quantity)> {
+    let mut remaining = max_qty;
+    let mut truncated = Vec::with_capacity(fills.len());
+    for (fill_px, fill_qty) in fills {
+        if remaining.is_zero() {
+            break;
+        }
+        let qty = min(fill_qty, remaining);
+        remaining -= qty;
+        truncated.push((fill_px, qty));
+    }
+    truncated
+}
@@ -63,6 +63,25 @@ impl IdsGenerator {
         self.execution_count = 0;
     }
 
+    /// Returns the number of position, order and execution IDs generated.
+    #[must_use]
+    pub const fn counts(&self) -> (usize, usize, usize) {
+        (self.position_count, self.order_count, self.execution_count)
+    }
+
+    /// Sets the number of position, order and execution IDs generated, continuing the
+    /// sequences of generated IDs from a snapshot.
+    pub const fn set_counts(
+        &mut self,
+        position_count: usize,
+        order_count: usize,
+        execution_count: usize,
+    ) {
+        self.position_count = position_count;
+        self.order_count = order_count;
+        self.execution_count = execution_count;
+    }
+
     pub fn get_venue_order_id(&mut self, order: &OrderAny) -> anyhow::Result<VenueOrderId> {
         // check existing on order
         if let Some(venue_order_id) = order.venue_order_id() {
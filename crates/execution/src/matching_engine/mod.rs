@@ -17,6 +17,7 @@ pub mod adapter;
 pub mod config;
 pub mod engine;
 pub mod ids_generator;
+pub mod snapshot;
 
 #[cfg(test)]
 mod tests;
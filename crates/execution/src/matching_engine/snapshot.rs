@@ -0,0 +1,63 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Snapshots of the state of an order matching engine, for checkpointing a simulation.
+
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::order::BookOrder,
+    enums::MarketStatus,
+    identifiers::{AccountId, InstrumentId, TraderId},
+    orders::OrderAny,
+    types::Price,
+};
+use serde::{Deserialize, Serialize};
+
+/// Represents the state of an [`OrderMatchingEngine`](super::engine::OrderMatchingEngine)
+/// needed to continue matching from the point it was taken.
+///
+/// Bar execution state, queue positions and the random state of the fill model are not
+/// captured, and start afresh when the snapshot is restored.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MatchingEngineSnapshot {
+    /// The instrument ID for the matching engine.
+    pub instrument_id: InstrumentId,
+    /// The market status of the instrument.
+    pub market_status: MarketStatus,
+    /// The orders resting in the order book.
+    pub book_orders: Vec<BookOrder>,
+    /// The last sequence number applied to the order book.
+    pub book_sequence: u64,
+    /// UNIX timestamp (nanoseconds) when the order book was last updated.
+    pub book_ts_last: UnixNanos,
+    /// The current bid price of the matching core.
+    pub bid: Option<Price>,
+    /// The current ask price of the matching core.
+    pub ask: Option<Price>,
+    /// The last traded price of the matching core.
+    pub last: Option<Price>,
+    /// The passive orders working in the matching core.
+    pub orders: Vec<OrderAny>,
+    /// The market orders still filling under the participation cap.
+    pub participating_orders: Vec<OrderAny>,
+    /// The account ID of each trader which has submitted orders.
+    pub account_ids: Vec<(TraderId, AccountId)>,
+    /// The number of venue position IDs generated.
+    pub position_count: usize,
+    /// The number of venue order IDs generated.
+    pub order_count: usize,
+    /// The number of trade IDs generated.
+    pub execution_count: usize,
+}
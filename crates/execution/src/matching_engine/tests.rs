@@ -26,10 +26,10 @@ use nautilus_common::{
 };
 use nautilus_core::{AtomicTime, UnixNanos, UUID4};
 use nautilus_model::{
-    data::{stubs::OrderBookDeltaTestBuilder, BookOrder, TradeTick},
+    data::{stubs::OrderBookDeltaTestBuilder, BookOrder, QuoteTick, TradeTick},
     enums::{
         AccountType, AggressorSide, BookAction, BookType, ContingencyType, LiquiditySide, OmsType,
-        OrderSide, OrderType, TimeInForce,
+        OrderSide, OrderType, PegPriceType, TimeInForce, TrailingOffsetType, TriggerType,
     },
     events::{
         order::rejected::OrderRejectedBuilder, OrderEventAny, OrderEventType, OrderFilled,
@@ -43,16 +43,20 @@ use nautilus_model::{
         stubs::{crypto_perpetual_ethusdt, equity_aapl, futures_contract_es},
         CryptoPerpetual, Equity, InstrumentAny,
     },
-    orders::{stubs::TestOrderStubs, OrderAny, OrderTestBuilder},
+    orders::{
+        stubs::{TestOrderEventStubs, TestOrderStubs},
+        OrderAny, OrderTestBuilder,
+    },
     types::{Price, Quantity},
 };
 use rstest::{fixture, rstest};
+use rust_decimal_macros::dec;
 use ustr::Ustr;
 
 use crate::{
     matching_engine::{config::OrderMatchingEngineConfig, engine::OrderMatchingEngine},
     messages::{BatchCancelOrders, CancelAllOrders, CancelOrder, ModifyOrder},
-    models::{fee::FeeModelAny, fill::FillModel},
+    models::{fee::FeeModelAny, fill::FillModel, slippage::SlippageModel},
 };
 
 static ATOMIC_TIME: LazyLock<AtomicTime> =
@@ -157,6 +161,8 @@ fn engine_config() -> OrderMatchingEngineConfig {
         use_position_ids: false,
         use_random_ids: false,
         use_reduce_only: true,
+        support_iceberg_orders: false,
+        max_participation_rate: None,
     }
 }
 // -- HELPERS ---------------------------------------------------------------------------
@@ -920,6 +926,75 @@ fn test_process_limit_post_only_order_that_would_be_a_taker(
     );
 }
 
+#[rstest]
+fn test_process_gtx_orders(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("1.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    // GTX limit order which would cross the book, and a GTX stop order without a price
+    let mut gtx_limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1501.00"))
+        .quantity(Quantity::from("1.000"))
+        .time_in_force(TimeInForce::Gtx)
+        .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-1"))
+        .build();
+    let mut gtx_stop_order = OrderTestBuilder::new(OrderType::StopMarket)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .trigger_price(Price::from("1510.00"))
+        .quantity(Quantity::from("1.000"))
+        .time_in_force(TimeInForce::Gtx)
+        .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-2"))
+        .build();
+    engine_l2.process_order(&mut gtx_limit_order, account_id);
+    engine_l2.process_order(&mut gtx_stop_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let reasons: Vec<Ustr> = saved_messages
+        .iter()
+        .map(|event| match event {
+            OrderEventAny::Rejected(order_rejected) => order_rejected.reason,
+            _ => panic!("Expected OrderRejected event"),
+        })
+        .collect();
+    assert!(gtx_limit_order.is_post_only());
+    assert_eq!(
+        reasons,
+        vec![
+            Ustr::from("POST_ONLY LIMIT BUY order limit px of 1501.00 would have been a TAKER: bid=None, ask=1500.00"),
+            Ustr::from("GTX time in force not supported for STOP_MARKET orders"),
+        ]
+    );
+}
+
 #[rstest]
 fn test_process_limit_order_not_matched_and_canceled_fok_order(
     instrument_eth_usdt: InstrumentAny,
@@ -1800,6 +1875,68 @@ fn test_expire_order(
     assert_eq!(order_expired.client_order_id, client_order_id);
 }
 
+#[rstest]
+fn test_expire_day_orders(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("1.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    let day_client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut day_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1495.00"))
+        .quantity(Quantity::from("1.000"))
+        .time_in_force(TimeInForce::Day)
+        .client_order_id(day_client_order_id)
+        .build();
+    let mut gtc_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1494.00"))
+        .quantity(Quantity::from("1.000"))
+        .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-2"))
+        .build();
+    engine_l2.process_order(&mut day_order, account_id);
+    engine_l2.process_order(&mut gtc_order, account_id);
+    engine_l2.expire_day_orders();
+
+    // Only the DAY order expires, the GTC order remains working
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 3);
+    let order_expired = match saved_messages.get(2).unwrap() {
+        OrderEventAny::Expired(order_expired) => order_expired,
+        _ => panic!("Expected OrderExpired event in third message"),
+    };
+    assert_eq!(order_expired.client_order_id, day_client_order_id);
+    assert_eq!(engine_l2.get_open_orders().len(), 1);
+}
+
 #[rstest]
 fn test_process_modify_order_rejected_not_found(
     instrument_eth_usdt: InstrumentAny,
@@ -2432,3 +2569,766 @@ fn test_update_limit_if_touched_order_valid(
     assert_eq!(order_updated.client_order_id, client_order_id);
     assert_eq!(order_updated.trigger_price.unwrap(), new_trigger_price);
 }
+
+#[rstest]
+fn test_resting_limit_order_fills_after_queue_ahead_is_traded(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    // Register saving message handler to exec engine endpoint
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    engine.set_fill_model(
+        FillModel::new(0.5, 0.5, 0.0, None)
+            .unwrap()
+            .with_queue_position(),
+    );
+
+    let quote = QuoteTick::new(
+        instrument_eth_usdt.id(),
+        Price::from("1500.00"),
+        Price::from("1501.00"),
+        Quantity::from("10.000"),
+        Quantity::from("10.000"),
+        UnixNanos::default(),
+        UnixNanos::default(),
+    );
+    engine.process_quote_tick(&quote);
+
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1500.00"))
+        .quantity(Quantity::from("1.000"))
+        .client_order_id(client_order_id)
+        .build();
+    engine.process_order(&mut limit_order, account_id);
+    limit_order.set_liquidity_side(LiquiditySide::Maker);
+
+    assert_eq!(
+        engine.get_queue_position(&client_order_id).unwrap().ahead,
+        10.0
+    );
+
+    // Trading part of the queue ahead touches the order price without filling
+    let trade = |size: &str| {
+        TradeTick::new(
+            instrument_eth_usdt.id(),
+            Price::from("1500.00"),
+            Quantity::from(size),
+            AggressorSide::Seller,
+            TradeId::new("1"),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    };
+    engine.process_trade_tick(&trade("4.000"));
+    engine.fill_limit_order(&mut limit_order);
+
+    assert_eq!(
+        engine.get_queue_position(&client_order_id).unwrap().ahead,
+        6.0
+    );
+    assert_eq!(
+        get_order_event_handler_messages(order_event_handler.clone()).len(),
+        1
+    );
+
+    // Trading the rest of the queue ahead fills the order
+    engine.process_trade_tick(&trade("6.000"));
+    engine.fill_limit_order(&mut limit_order);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    let order_filled = match saved_messages.get(1).unwrap() {
+        OrderEventAny::Filled(order_filled) => order_filled,
+        _ => panic!("Expected OrderFilled event in second message"),
+    };
+    assert_eq!(order_filled.client_order_id, client_order_id);
+    assert_eq!(order_filled.last_px, Price::from("1500.00"));
+}
+
+#[rstest]
+fn test_market_order_fills_across_levels_and_iterations_with_participation_cap(
+    instrument_eth_usdt: InstrumentAny,
+    order_event_handler: ShareableMessageHandler,
+    mut msgbus: MessageBus,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let engine_config = OrderMatchingEngineConfig {
+        max_participation_rate: Some(0.5),
+        ..Default::default()
+    };
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(engine_config),
+    );
+
+    for price in ["1500.00", "1510.00"] {
+        let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+            .book_action(BookAction::Add)
+            .book_order(BookOrder::new(
+                OrderSide::Sell,
+                Price::from(price),
+                Quantity::from("2.000"),
+                1,
+            ))
+            .build();
+        engine_l2.process_order_book_delta(&orderbook_delta_sell);
+    }
+
+    let mut market_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("3.000"))
+        .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-1"))
+        .build();
+    engine_l2.process_order(&mut market_order, account_id);
+
+    // Half of the displayed liquidity is taken from each level
+    let fills = |handler: ShareableMessageHandler| -> Vec<(Price, Quantity)> {
+        get_order_event_handler_messages(handler)
+            .iter()
+            .map(|event| match event {
+                OrderEventAny::Filled(order_filled) => {
+                    (order_filled.last_px, order_filled.last_qty)
+                }
+                _ => panic!("Expected OrderFilled event"),
+            })
+            .collect()
+    };
+    assert_eq!(
+        fills(order_event_handler.clone()),
+        vec![
+            (Price::from("1500.00"), Quantity::from("1.000")),
+            (Price::from("1510.00"), Quantity::from("1.000")),
+        ]
+    );
+
+    // The remaining quantity is filled on the next iterations
+    engine_l2.iterate(UnixNanos::from(1));
+    engine_l2.iterate(UnixNanos::from(2));
+
+    assert_eq!(
+        fills(order_event_handler),
+        vec![
+            (Price::from("1500.00"), Quantity::from("1.000")),
+            (Price::from("1510.00"), Quantity::from("1.000")),
+            (Price::from("1500.00"), Quantity::from("1.000")),
+        ]
+    );
+}
+
+#[rstest]
+#[case::walk_the_book(
+    SlippageModel::walk_the_book(),
+    vec![
+        (Price::from("1500.00"), Quantity::from("2.000")),
+        (Price::from("1500.01"), Quantity::from("1.000")),
+    ]
+)]
+#[case::fixed_bps(
+    SlippageModel::fixed_bps(10.0).unwrap(),
+    vec![(Price::from("1501.50"), Quantity::from("2.000"))]
+)]
+fn test_market_order_fills_with_slippage_model(
+    instrument_eth_usdt: InstrumentAny,
+    order_event_handler: ShareableMessageHandler,
+    mut msgbus: MessageBus,
+    account_id: AccountId,
+    #[case] slippage_model: SlippageModel,
+    #[case] expected_fills: Vec<(Price, Quantity)>,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    engine_l2.set_slippage_model(Some(slippage_model));
+
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("2.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    let mut market_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("3.000"))
+        .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-1"))
+        .build();
+    engine_l2.process_order(&mut market_order, account_id);
+
+    let fills: Vec<(Price, Quantity)> = get_order_event_handler_messages(order_event_handler)
+        .iter()
+        .filter_map(|event| match event {
+            OrderEventAny::Filled(order_filled) => {
+                Some((order_filled.last_px, order_filled.last_qty))
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(fills, expected_fills);
+}
+
+#[rstest]
+fn test_cancel_market_order_working_with_participation_cap(
+    instrument_eth_usdt: InstrumentAny,
+    order_event_handler: ShareableMessageHandler,
+    mut msgbus: MessageBus,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let engine_config = OrderMatchingEngineConfig {
+        max_participation_rate: Some(0.1),
+        ..Default::default()
+    };
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(engine_config),
+    );
+
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("10.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut market_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("5.000"))
+        .client_order_id(client_order_id)
+        .build();
+    market_order
+        .apply(TestOrderEventStubs::order_submitted(
+            &market_order,
+            account_id,
+        ))
+        .unwrap();
+    engine_l2.process_order(&mut market_order, account_id);
+
+    let cancel_command = CancelOrder::new(
+        market_order.trader_id(),
+        ClientId::default(),
+        market_order.strategy_id(),
+        market_order.instrument_id(),
+        client_order_id,
+        VenueOrderId::default(),
+        UUID4::new(),
+        UnixNanos::default(),
+    )
+    .unwrap();
+    engine_l2.process_cancel(&cancel_command, account_id);
+    engine_l2.iterate(UnixNanos::from(1));
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    assert_eq!(saved_messages[0].event_type(), OrderEventType::Filled);
+    assert_eq!(saved_messages[1].event_type(), OrderEventType::Canceled);
+}
+
+#[rstest]
+fn test_iceberg_order_fills_visible_slice_and_rejoins_queue(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let engine_config = OrderMatchingEngineConfig {
+        support_iceberg_orders: true,
+        ..Default::default()
+    };
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(engine_config),
+    );
+    engine.set_fill_model(FillModel::default().with_queue_position());
+
+    let quote = QuoteTick::new(
+        instrument_eth_usdt.id(),
+        Price::from("1500.00"),
+        Price::from("1501.00"),
+        Quantity::from("10.000"),
+        Quantity::from("10.000"),
+        UnixNanos::default(),
+        UnixNanos::default(),
+    );
+    engine.process_quote_tick(&quote);
+
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1500.00"))
+        .quantity(Quantity::from("3.000"))
+        .display_qty(Quantity::from("1.000"))
+        .client_order_id(client_order_id)
+        .build();
+    engine.process_order(&mut limit_order, account_id);
+    limit_order.set_liquidity_side(LiquiditySide::Maker);
+
+    // Trading through the queue ahead fills only the visible slice
+    let trade = TradeTick::new(
+        instrument_eth_usdt.id(),
+        Price::from("1500.00"),
+        Quantity::from("10.000"),
+        AggressorSide::Seller,
+        TradeId::new("1"),
+        UnixNanos::default(),
+        UnixNanos::default(),
+    );
+    engine.process_trade_tick(&trade);
+    engine.fill_limit_order(&mut limit_order);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler.clone());
+    assert_eq!(saved_messages.len(), 2);
+    let order_filled = match saved_messages.get(1).unwrap() {
+        OrderEventAny::Filled(order_filled) => order_filled,
+        _ => panic!("Expected OrderFilled event in second message"),
+    };
+    assert_eq!(order_filled.last_qty, Quantity::from("1.000"));
+
+    // The replenished slice joins the back of the queue
+    assert_eq!(
+        engine.get_queue_position(&client_order_id).unwrap().ahead,
+        10.0
+    );
+    engine.fill_limit_order(&mut limit_order);
+    assert_eq!(
+        get_order_event_handler_messages(order_event_handler).len(),
+        2
+    );
+}
+
+#[rstest]
+fn test_iceberg_order_rejected_when_not_supported(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1500.00"))
+        .quantity(Quantity::from("3.000"))
+        .display_qty(Quantity::from("1.000"))
+        .build();
+    engine.process_order(&mut limit_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    let order_rejected = match saved_messages.first().unwrap() {
+        OrderEventAny::Rejected(order_rejected) => order_rejected,
+        _ => panic!("Expected OrderRejected event in first message"),
+    };
+    assert_eq!(
+        order_rejected.reason,
+        Ustr::from(
+            "Iceberg orders not supported for BINANCE, `display_qty` 1.000 was less than `quantity` 3.000"
+        )
+    );
+}
+
+#[rstest]
+fn test_modify_iceberg_order_quantity_below_display_qty_rejected(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let engine_config = OrderMatchingEngineConfig {
+        support_iceberg_orders: true,
+        ..Default::default()
+    };
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(engine_config),
+    );
+
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1500.00"))
+        .quantity(Quantity::from("3.000"))
+        .display_qty(Quantity::from("2.000"))
+        .client_order_id(client_order_id)
+        .build();
+    engine.process_order(&mut limit_order, account_id);
+
+    let modify_order_command = ModifyOrder::new(
+        TraderId::from("TRADER-001"),
+        ClientId::from("CLIENT-001"),
+        StrategyId::from("STRATEGY-001"),
+        instrument_eth_usdt.id(),
+        client_order_id,
+        VenueOrderId::from("V1"),
+        Some(Quantity::from("1.000")),
+        None,
+        None,
+        UUID4::new(),
+        UnixNanos::default(),
+    )
+    .unwrap();
+    engine.process_modify(&modify_order_command, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    assert!(matches!(saved_messages[0], OrderEventAny::Accepted(_)));
+    let order_rejected = match &saved_messages[1] {
+        OrderEventAny::ModifyRejected(order_rejected) => order_rejected,
+        _ => panic!("Expected OrderModifyRejected event in second message"),
+    };
+    assert_eq!(
+        order_rejected.reason,
+        Ustr::from("New quantity 1.000 was less than `display_qty` 2.000")
+    );
+}
+
+#[rstest]
+fn test_restore_snapshot_continues_matching(
+    instrument_eth_usdt: InstrumentAny,
+    order_event_handler: ShareableMessageHandler,
+    mut msgbus: MessageBus,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let msgbus = Rc::new(RefCell::new(msgbus));
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        msgbus.clone(),
+        None,
+        None,
+        None,
+    );
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1510.00"),
+            Quantity::from("2.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1500.00"))
+        .quantity(Quantity::from("1.000"))
+        .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-1"))
+        .build();
+    engine_l2.process_order(&mut limit_order, account_id);
+
+    let snapshot = engine_l2.snapshot();
+    let mut restored =
+        get_order_matching_engine_l2(instrument_eth_usdt.clone(), msgbus, None, None, None);
+    restored.restore(&snapshot);
+
+    assert_eq!(restored.best_ask_price(), Some(Price::from("1510.00")));
+    assert_eq!(restored.get_book().sequence, snapshot.book_sequence);
+    assert!(restored.order_exists(limit_order.client_order_id()));
+
+    // The resting order fills once the market moves through its price
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1499.00"),
+            Quantity::from("2.000"),
+            2,
+        ))
+        .build();
+    restored.process_order_book_delta(&orderbook_delta_sell);
+    let mut resting_order = snapshot.orders[0].clone();
+    resting_order.set_liquidity_side(LiquiditySide::Maker);
+    restored.fill_limit_order(&mut resting_order);
+
+    assert!(matches!(
+        get_order_event_handler_messages(order_event_handler).last(),
+        Some(OrderEventAny::Filled(_))
+    ));
+}
+
+#[rstest]
+fn test_trailing_stop_market_order_trails_and_triggers(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    engine.set_fill_model(FillModel::new(0.5, 0.5, 0.0, None).unwrap());
+    let quote = |bid: &str, ask: &str| {
+        QuoteTick::new(
+            instrument_eth_usdt.id(),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from("10.000"),
+            Quantity::from("10.000"),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    };
+    engine.process_quote_tick(&quote("1500.00", "1501.00"));
+
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut trailing_stop_order = OrderTestBuilder::new(OrderType::TrailingStopMarket)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Sell)
+        .trigger_price(Price::from("1490.00"))
+        .trigger_type(TriggerType::BidAsk)
+        .trailing_offset_type(TrailingOffsetType::Price)
+        .trailing_offset(dec!(5.00))
+        .quantity(Quantity::from("1.000"))
+        .client_order_id(client_order_id)
+        .build();
+    engine.process_order(&mut trailing_stop_order, account_id);
+
+    // The trigger trails the rising bid, then triggers once the bid falls back by the offset
+    engine.process_quote_tick(&quote("1510.00", "1511.00"));
+    engine.process_quote_tick(&quote("1507.00", "1508.00"));
+    engine.process_quote_tick(&quote("1504.00", "1505.00"));
+    engine.process_quote_tick(&quote("1503.00", "1504.00"));
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 4);
+    assert!(matches!(saved_messages[0], OrderEventAny::Accepted(_)));
+    let trigger_prices: Vec<Price> = saved_messages[1..3]
+        .iter()
+        .map(|event| match event {
+            OrderEventAny::Updated(order_updated) => order_updated.trigger_price.unwrap(),
+            _ => panic!("Expected OrderUpdated event"),
+        })
+        .collect();
+    assert_eq!(
+        trigger_prices,
+        vec![Price::from("1495.00"), Price::from("1505.00")]
+    );
+    let order_filled = match &saved_messages[3] {
+        OrderEventAny::Filled(order_filled) => order_filled,
+        _ => panic!("Expected OrderFilled event in fourth message"),
+    };
+    assert_eq!(order_filled.client_order_id, client_order_id);
+    assert_eq!(order_filled.last_px, Price::from("1504.00"));
+    assert!(!engine.order_exists(client_order_id));
+}
+
+#[rstest]
+fn test_trailing_stop_limit_order_with_activation_price(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    let trade = |price: &str| {
+        TradeTick::new(
+            instrument_eth_usdt.id(),
+            Price::from(price),
+            Quantity::from("1.000"),
+            AggressorSide::Buyer,
+            TradeId::new("1"),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    };
+    engine.process_trade_tick(&trade("1500.00"));
+
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut trailing_stop_order = OrderTestBuilder::new(OrderType::TrailingStopLimit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Sell)
+        .price(Price::from("1480.00"))
+        .trigger_price(Price::from("1490.00"))
+        .trigger_type(TriggerType::LastPrice)
+        .limit_offset(dec!(100))
+        .trailing_offset_type(TrailingOffsetType::BasisPoints)
+        .trailing_offset(dec!(50))
+        .activation_price(Price::from("1520.00"))
+        .quantity(Quantity::from("1.000"))
+        .client_order_id(client_order_id)
+        .build();
+    engine.process_order(&mut trailing_stop_order, account_id);
+
+    // The order neither trails nor triggers before activation
+    engine.process_trade_tick(&trade("1485.00"));
+    assert_eq!(
+        get_order_event_handler_messages(order_event_handler.clone()).len(),
+        1
+    );
+
+    // Activated at 1520.00, trailing 50bp (7.60) with the limit price 100bp (15.20) below
+    engine.process_trade_tick(&trade("1520.00"));
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    let order_updated = match &saved_messages[1] {
+        OrderEventAny::Updated(order_updated) => order_updated,
+        _ => panic!("Expected OrderUpdated event in second message"),
+    };
+    assert_eq!(order_updated.trigger_price, Some(Price::from("1512.40")));
+    assert_eq!(order_updated.price, Some(Price::from("1504.80")));
+}
+
+#[rstest]
+fn test_pegged_limit_order_reprices_with_market(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    let quote = |bid: &str, ask: &str| {
+        QuoteTick::new(
+            instrument_eth_usdt.id(),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from("10.000"),
+            Quantity::from("10.000"),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    };
+    engine.process_quote_tick(&quote("1500.00", "1501.00"));
+
+    let mut pegged_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1490.00"))
+        .peg_price_type(PegPriceType::Primary)
+        .peg_offset(dec!(0.50))
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine.process_order(&mut pegged_order, account_id);
+
+    // The order is repriced on acceptance and then follows the bid
+    engine.process_quote_tick(&quote("1502.00", "1503.00"));
+    engine.process_quote_tick(&quote("1502.00", "1504.00"));
+    engine.process_quote_tick(&quote("1498.00", "1499.00"));
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 4);
+    assert!(matches!(saved_messages[0], OrderEventAny::Accepted(_)));
+    let prices: Vec<Price> = saved_messages[1..]
+        .iter()
+        .map(|event| match event {
+            OrderEventAny::Updated(order_updated) => order_updated.price.unwrap(),
+            _ => panic!("Expected OrderUpdated event"),
+        })
+        .collect();
+    assert_eq!(
+        prices,
+        vec![
+            Price::from("1499.50"),
+            Price::from("1501.50"),
+            Price::from("1497.50")
+        ]
+    );
+    assert!(engine.order_exists(pegged_order.client_order_id()));
+}
@@ -19,7 +19,8 @@ use derive_builder::Builder;
 use nautilus_core::{UnixNanos, UUID4};
 use nautilus_model::{
     enums::OrderSide,
-    identifiers::{ClientId, InstrumentId, StrategyId, TraderId},
+    identifiers::{ClientId, InstrumentId, StrategyId, TraderId, Venue},
+    orders::OrderAny,
 };
 use serde::{Deserialize, Serialize};
 
@@ -34,6 +35,12 @@ pub struct CancelAllOrders {
     pub order_side: OrderSide,
     pub command_id: UUID4,
     pub ts_init: UnixNanos,
+    /// If the orders of every instrument at the venue of `instrument_id` are canceled.
+    #[serde(default)]
+    pub venue_wide: bool,
+    /// If the orders of every strategy are canceled (rather than only those of `strategy_id`).
+    #[serde(default)]
+    pub all_strategies: bool,
 }
 
 impl CancelAllOrders {
@@ -56,16 +63,72 @@ impl CancelAllOrders {
             order_side,
             command_id,
             ts_init,
+            venue_wide: false,
+            all_strategies: false,
         })
     }
+
+    /// Widens the command to cancel the orders of every instrument at the venue.
+    #[must_use]
+    pub const fn with_venue_wide(mut self) -> Self {
+        self.venue_wide = true;
+        self
+    }
+
+    /// Widens the command to cancel the orders of every strategy.
+    #[must_use]
+    pub const fn with_all_strategies(mut self) -> Self {
+        self.all_strategies = true;
+        self
+    }
+
+    /// Returns the venue the command is for.
+    #[must_use]
+    pub fn venue(&self) -> Venue {
+        self.instrument_id.venue
+    }
+
+    /// Returns the instrument the canceled orders are filtered by (if not venue wide).
+    #[must_use]
+    pub fn instrument_filter(&self) -> Option<&InstrumentId> {
+        (!self.venue_wide).then_some(&self.instrument_id)
+    }
+
+    /// Returns the strategy the canceled orders are filtered by (if not all strategies).
+    #[must_use]
+    pub fn strategy_filter(&self) -> Option<&StrategyId> {
+        (!self.all_strategies).then_some(&self.strategy_id)
+    }
+
+    /// Returns the side the canceled orders are filtered by (if not both sides).
+    #[must_use]
+    pub fn side_filter(&self) -> Option<OrderSide> {
+        (self.order_side != OrderSide::NoOrderSide).then_some(self.order_side)
+    }
+
+    /// Returns whether the `order` is matched by the filters of the command.
+    #[must_use]
+    pub fn matches(&self, order: &OrderAny) -> bool {
+        let instrument_id = order.instrument_id();
+        instrument_id.venue == self.venue()
+            && self
+                .instrument_filter()
+                .is_none_or(|id| *id == instrument_id)
+            && self
+                .strategy_filter()
+                .is_none_or(|id| *id == order.strategy_id())
+            && self
+                .side_filter()
+                .is_none_or(|side| side == order.order_side())
+    }
 }
 
 impl Display for CancelAllOrders {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "CancelAllOrders(instrument_id={}, order_side={})",
-            self.instrument_id, self.order_side,
+            "CancelAllOrders(instrument_id={}, order_side={}, venue_wide={}, all_strategies={})",
+            self.instrument_id, self.order_side, self.venue_wide, self.all_strategies,
         )
     }
 }
@@ -74,4 +137,49 @@ impl Display for CancelAllOrders {
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use nautilus_model::{
+        enums::OrderType, instruments::stubs::audusd_sim, orders::OrderTestBuilder, types::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn order(instrument_id: &str, strategy_id: &str, side: OrderSide) -> OrderAny {
+        OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(InstrumentId::from(instrument_id))
+            .strategy_id(StrategyId::from(strategy_id))
+            .side(side)
+            .quantity(Quantity::from(100_000))
+            .build()
+    }
+
+    fn command(order_side: OrderSide) -> CancelAllOrders {
+        CancelAllOrders {
+            strategy_id: StrategyId::from("S-001"),
+            instrument_id: audusd_sim().id,
+            order_side,
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    fn test_matches_by_instrument_strategy_and_side() {
+        let command = command(OrderSide::Buy);
+
+        assert!(command.matches(&order("AUD/USD.SIM", "S-001", OrderSide::Buy)));
+        assert!(!command.matches(&order("AUD/USD.SIM", "S-001", OrderSide::Sell)));
+        assert!(!command.matches(&order("AUD/USD.SIM", "S-002", OrderSide::Buy)));
+        assert!(!command.matches(&order("GBP/USD.SIM", "S-001", OrderSide::Buy)));
+    }
+
+    #[rstest]
+    fn test_matches_venue_wide_for_all_strategies() {
+        let command = command(OrderSide::NoOrderSide)
+            .with_venue_wide()
+            .with_all_strategies();
+
+        assert!(command.matches(&order("GBP/USD.SIM", "S-002", OrderSide::Sell)));
+        assert!(!command.matches(&order("ETHUSDT.BINANCE", "S-001", OrderSide::Buy)));
+    }
+}
@@ -19,26 +19,31 @@ pub mod cancel;
 pub mod cancel_all;
 pub mod cancel_batch;
 pub mod modify;
+pub mod modify_batch;
 pub mod query;
 pub mod submit;
 pub mod submit_list;
 
+use nautilus_core::UnixNanos;
 use nautilus_model::identifiers::{ClientId, InstrumentId};
+use serde::{Deserialize, Serialize};
 use strum::Display;
 
 // Re-exports
 pub use self::{
     cancel::CancelOrder, cancel_all::CancelAllOrders, cancel_batch::BatchCancelOrders,
-    modify::ModifyOrder, query::QueryOrder, submit::SubmitOrder, submit_list::SubmitOrderList,
+    modify::ModifyOrder, modify_batch::BatchModifyOrders, query::QueryOrder, submit::SubmitOrder,
+    submit_list::SubmitOrderList,
 };
 
 // TODO
 #[allow(clippy::large_enum_variant)]
-#[derive(Clone, Debug, Display)]
+#[derive(Clone, Debug, Display, Serialize, Deserialize)]
 pub enum TradingCommand {
     SubmitOrder(SubmitOrder),
     SubmitOrderList(SubmitOrderList),
     ModifyOrder(ModifyOrder),
+    BatchModifyOrders(BatchModifyOrders),
     CancelOrder(CancelOrder),
     CancelAllOrders(CancelAllOrders),
     BatchCancelOrders(BatchCancelOrders),
@@ -52,6 +57,7 @@ impl TradingCommand {
             Self::SubmitOrder(command) => command.client_id,
             Self::SubmitOrderList(command) => command.client_id,
             Self::ModifyOrder(command) => command.client_id,
+            Self::BatchModifyOrders(command) => command.client_id,
             Self::CancelOrder(command) => command.client_id,
             Self::CancelAllOrders(command) => command.client_id,
             Self::BatchCancelOrders(command) => command.client_id,
@@ -65,10 +71,25 @@ impl TradingCommand {
             Self::SubmitOrder(command) => command.instrument_id,
             Self::SubmitOrderList(command) => command.instrument_id,
             Self::ModifyOrder(command) => command.instrument_id,
+            Self::BatchModifyOrders(command) => command.instrument_id,
             Self::CancelOrder(command) => command.instrument_id,
             Self::CancelAllOrders(command) => command.instrument_id,
             Self::BatchCancelOrders(command) => command.instrument_id,
             Self::QueryOrder(command) => command.instrument_id,
         }
     }
+
+    #[must_use]
+    pub const fn ts_init(&self) -> UnixNanos {
+        match self {
+            Self::SubmitOrder(command) => command.ts_init,
+            Self::SubmitOrderList(command) => command.ts_init,
+            Self::ModifyOrder(command) => command.ts_init,
+            Self::BatchModifyOrders(command) => command.ts_init,
+            Self::CancelOrder(command) => command.ts_init,
+            Self::CancelAllOrders(command) => command.ts_init,
+            Self::BatchCancelOrders(command) => command.ts_init,
+            Self::QueryOrder(command) => command.ts_init,
+        }
+    }
 }
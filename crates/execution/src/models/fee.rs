@@ -13,10 +13,14 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::{cell::Cell, rc::Rc};
+
+use nautilus_core::UnixNanos;
 use nautilus_model::{
     enums::LiquiditySide,
     instruments::InstrumentAny,
     orders::OrderAny,
+    position::Position,
     types::{Money, Price, Quantity},
 };
 use rust_decimal::prelude::ToPrimitive;
@@ -29,12 +33,34 @@ pub trait FeeModel {
         fill_px: Price,
         instrument: &InstrumentAny,
     ) -> anyhow::Result<Money>;
+
+    /// Returns the next funding time strictly after `ts`, or `None` if the model does not
+    /// charge funding.
+    fn next_funding_time(&self, _ts: UnixNanos) -> Option<UnixNanos> {
+        None
+    }
+
+    /// Returns the funding payment for the `position` marked at `mark_px` at a funding time,
+    /// or `None` if no funding applies to the `instrument`.
+    ///
+    /// A negative payment is paid by the account holding the position.
+    fn get_funding_payment(
+        &self,
+        _position: &Position,
+        _mark_px: Price,
+        _instrument: &InstrumentAny,
+    ) -> anyhow::Result<Option<Money>> {
+        Ok(None)
+    }
 }
 
 #[derive(Clone, Debug)]
 pub enum FeeModelAny {
     Fixed(FixedFeeModel),
     MakerTaker(MakerTakerFeeModel),
+    TieredMakerTaker(TieredMakerTakerFeeModel),
+    PerContract(PerContractFeeModel),
+    Funding(FundingFeeModel),
 }
 
 impl FeeModel for FeeModelAny {
@@ -50,6 +76,32 @@ impl FeeModel for FeeModelAny {
             Self::MakerTaker(model) => {
                 model.get_commission(order, fill_quantity, fill_px, instrument)
             }
+            Self::TieredMakerTaker(model) => {
+                model.get_commission(order, fill_quantity, fill_px, instrument)
+            }
+            Self::PerContract(model) => {
+                model.get_commission(order, fill_quantity, fill_px, instrument)
+            }
+            Self::Funding(model) => model.get_commission(order, fill_quantity, fill_px, instrument),
+        }
+    }
+
+    fn next_funding_time(&self, ts: UnixNanos) -> Option<UnixNanos> {
+        match self {
+            Self::Funding(model) => model.next_funding_time(ts),
+            _ => None,
+        }
+    }
+
+    fn get_funding_payment(
+        &self,
+        position: &Position,
+        mark_px: Price,
+        instrument: &InstrumentAny,
+    ) -> anyhow::Result<Option<Money>> {
+        match self {
+            Self::Funding(model) => model.get_funding_payment(position, mark_px, instrument),
+            _ => Ok(None),
         }
     }
 }
@@ -123,21 +175,275 @@ impl FeeModel for MakerTakerFeeModel {
     }
 }
 
+/// A fee tier applying once the cumulative traded notional reaches `min_notional`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FeeTier {
+    /// The cumulative traded notional from which the tier applies.
+    pub min_notional: f64,
+    /// The maker fee rate in basis points (negative for a rebate).
+    pub maker_bps: f64,
+    /// The taker fee rate in basis points.
+    pub taker_bps: f64,
+}
+
+impl FeeTier {
+    /// Creates a new [`FeeTier`] instance.
+    #[must_use]
+    pub const fn new(min_notional: f64, maker_bps: f64, taker_bps: f64) -> Self {
+        Self {
+            min_notional,
+            maker_bps,
+            taker_bps,
+        }
+    }
+}
+
+/// Charges maker/taker fees in basis points of the fill notional, with the tier selected by
+/// the cumulative notional traded through the model.
+///
+/// Clones share the traded notional, so a venue with a matching engine per instrument
+/// tiers on its total traded volume.
+#[derive(Debug, Clone)]
+pub struct TieredMakerTakerFeeModel {
+    tiers: Vec<FeeTier>,
+    traded_notional: Rc<Cell<f64>>,
+}
+
+impl TieredMakerTakerFeeModel {
+    /// Creates a new [`TieredMakerTakerFeeModel`] instance.
+    ///
+    /// The `tiers` are sorted by minimum notional, and one must start from zero.
+    pub fn new(mut tiers: Vec<FeeTier>) -> anyhow::Result<Self> {
+        for tier in &tiers {
+            if !(tier.min_notional.is_finite() && tier.min_notional >= 0.0) {
+                anyhow::bail!("Invalid tier minimum notional {}", tier.min_notional)
+            }
+            if !(tier.maker_bps.is_finite() && tier.taker_bps.is_finite()) {
+                anyhow::bail!(
+                    "Invalid tier fee rates: maker={}bps, taker={}bps",
+                    tier.maker_bps,
+                    tier.taker_bps
+                )
+            }
+        }
+        tiers.sort_by(|a, b| a.min_notional.total_cmp(&b.min_notional));
+        if tiers.first().is_none_or(|tier| tier.min_notional != 0.0) {
+            anyhow::bail!("Fee tiers must include a tier from zero notional")
+        }
+
+        Ok(Self {
+            tiers,
+            traded_notional: Rc::new(Cell::new(0.0)),
+        })
+    }
+
+    /// Returns the cumulative notional traded through the model.
+    #[must_use]
+    pub fn traded_notional(&self) -> f64 {
+        self.traded_notional.get()
+    }
+
+    /// Returns the tier for the current traded notional.
+    #[must_use]
+    pub fn current_tier(&self) -> &FeeTier {
+        let traded_notional = self.traded_notional.get();
+        self.tiers
+            .iter()
+            .rev()
+            .find(|tier| tier.min_notional <= traded_notional)
+            .unwrap_or(&self.tiers[0])
+    }
+}
+
+impl FeeModel for TieredMakerTakerFeeModel {
+    fn get_commission(
+        &self,
+        order: &OrderAny,
+        fill_quantity: Quantity,
+        fill_px: Price,
+        instrument: &InstrumentAny,
+    ) -> anyhow::Result<Money> {
+        let notional = instrument.calculate_notional_value(fill_quantity, fill_px, Some(false));
+        let tier = self.current_tier();
+        let fee_bps = match order.liquidity_side() {
+            Some(LiquiditySide::Maker) => tier.maker_bps,
+            Some(LiquiditySide::Taker) => tier.taker_bps,
+            Some(LiquiditySide::NoLiquiditySide) | None => anyhow::bail!("Liquidity side not set."),
+        };
+        let commission = notional.as_f64() * fee_bps / 10_000.0;
+
+        self.traded_notional
+            .set(self.traded_notional.get() + notional.as_f64().abs());
+
+        Ok(Money::new(commission, notional.currency))
+    }
+}
+
+/// Charges a fixed commission per contract filled.
+#[derive(Debug, Clone)]
+pub struct PerContractFeeModel {
+    commission_per_contract: Money,
+}
+
+impl PerContractFeeModel {
+    /// Creates a new [`PerContractFeeModel`] instance.
+    pub fn new(commission_per_contract: Money) -> anyhow::Result<Self> {
+        if commission_per_contract.as_f64() < 0.0 {
+            anyhow::bail!("Commission per contract must be greater than or equal to zero.")
+        }
+        Ok(Self {
+            commission_per_contract,
+        })
+    }
+}
+
+impl FeeModel for PerContractFeeModel {
+    fn get_commission(
+        &self,
+        _order: &OrderAny,
+        fill_quantity: Quantity,
+        _fill_px: Price,
+        _instrument: &InstrumentAny,
+    ) -> anyhow::Result<Money> {
+        Ok(Money::new(
+            self.commission_per_contract.as_f64() * fill_quantity.as_f64(),
+            self.commission_per_contract.currency,
+        ))
+    }
+}
+
+/// Charges periodic funding on perpetual positions at a fixed rate, with trading commissions
+/// from the wrapped `commission_model`.
+///
+/// Funding times are aligned to multiples of the interval from the UNIX epoch, so an eight
+/// hour interval settles at 00:00, 08:00 and 16:00 UTC.
+#[derive(Debug, Clone)]
+pub struct FundingFeeModel {
+    commission_model: Box<FeeModelAny>,
+    funding_rate: f64,
+    funding_interval_ns: u64,
+}
+
+impl FundingFeeModel {
+    /// Creates a new [`FundingFeeModel`] instance.
+    ///
+    /// The `funding_rate` is the fraction of the position notional paid by longs to shorts at
+    /// each funding time (negative for shorts paying longs).
+    pub fn new(
+        commission_model: FeeModelAny,
+        funding_rate: f64,
+        funding_interval_ns: u64,
+    ) -> anyhow::Result<Self> {
+        if !funding_rate.is_finite() {
+            anyhow::bail!("Invalid funding rate {funding_rate}")
+        }
+        if funding_interval_ns == 0 {
+            anyhow::bail!("Funding interval must be positive")
+        }
+        Ok(Self {
+            commission_model: Box::new(commission_model),
+            funding_rate,
+            funding_interval_ns,
+        })
+    }
+}
+
+impl FeeModel for FundingFeeModel {
+    fn get_commission(
+        &self,
+        order: &OrderAny,
+        fill_quantity: Quantity,
+        fill_px: Price,
+        instrument: &InstrumentAny,
+    ) -> anyhow::Result<Money> {
+        self.commission_model
+            .get_commission(order, fill_quantity, fill_px, instrument)
+    }
+
+    fn next_funding_time(&self, ts: UnixNanos) -> Option<UnixNanos> {
+        let periods = ts.as_u64() / self.funding_interval_ns + 1;
+        Some(UnixNanos::from(periods * self.funding_interval_ns))
+    }
+
+    fn get_funding_payment(
+        &self,
+        position: &Position,
+        mark_px: Price,
+        instrument: &InstrumentAny,
+    ) -> anyhow::Result<Option<Money>> {
+        if !matches!(instrument, InstrumentAny::CryptoPerpetual(_)) || position.is_closed() {
+            return Ok(None);
+        }
+
+        let notional = instrument.calculate_notional_value(position.quantity, mark_px, Some(false));
+        let direction = if position.is_long() { -1.0 } else { 1.0 };
+
+        Ok(Some(Money::new(
+            direction * notional.as_f64() * self.funding_rate,
+            notional.currency,
+        )))
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use nautilus_core::UnixNanos;
     use nautilus_model::{
         enums::{LiquiditySide, OrderSide, OrderType},
-        instruments::{stubs::audusd_sim, InstrumentAny},
+        instruments::{
+            stubs::{audusd_sim, crypto_perpetual_ethusdt},
+            InstrumentAny,
+        },
         orders::{
             builder::OrderTestBuilder,
             stubs::{TestOrderEventStubs, TestOrderStubs},
+            OrderAny,
         },
+        position::Position,
         types::{Currency, Money, Price, Quantity},
     };
     use rstest::rstest;
     use rust_decimal::prelude::ToPrimitive;
 
-    use super::{FeeModel, FixedFeeModel, MakerTakerFeeModel};
+    use super::{
+        FeeModel, FeeModelAny, FeeTier, FixedFeeModel, FundingFeeModel, MakerTakerFeeModel,
+        PerContractFeeModel, TieredMakerTakerFeeModel,
+    };
+
+    const EIGHT_HOURS_NS: u64 = 8 * 60 * 60 * 1_000_000_000;
+
+    fn filled_order(instrument: &InstrumentAny, liquidity_side: LiquiditySide) -> OrderAny {
+        let limit_order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .price(Price::from("1.0"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        TestOrderStubs::make_filled_order(&limit_order, instrument, liquidity_side)
+    }
+
+    fn perpetual_position(side: OrderSide) -> (InstrumentAny, Position) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt());
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(side)
+            .quantity(Quantity::from("2.000"))
+            .build();
+        let filled = TestOrderEventStubs::order_filled(
+            &order,
+            &instrument,
+            None,
+            None,
+            Some(Price::from("1000.00")),
+            None,
+            None,
+            Some(Money::from("0 USDT")),
+            None,
+            None,
+        );
+        let position = Position::new(&instrument, filled.into());
+        (instrument, position)
+    }
 
     #[rstest]
     fn test_fixed_model_single_fill() {
@@ -268,4 +574,182 @@ mod tests {
             .unwrap();
         assert_eq!(commission.as_f64(), expected_commission_amount);
     }
+
+    #[rstest]
+    fn test_tiered_fee_model_moves_to_next_tier_with_traded_notional() {
+        let fee_model = TieredMakerTakerFeeModel::new(vec![
+            FeeTier::new(150_000.0, 0.0, 3.0),
+            FeeTier::new(0.0, 1.0, 5.0),
+        ])
+        .unwrap();
+        let aud_usd = InstrumentAny::CurrencyPair(audusd_sim());
+        let order = filled_order(&aud_usd, LiquiditySide::Taker);
+
+        let commissions: Vec<Money> = (0..3)
+            .map(|_| {
+                fee_model
+                    .get_commission(
+                        &order,
+                        Quantity::from(100_000),
+                        Price::from("1.0"),
+                        &aud_usd,
+                    )
+                    .unwrap()
+            })
+            .collect();
+
+        assert_eq!(
+            commissions,
+            vec![
+                Money::from("50 USD"),
+                Money::from("50 USD"),
+                Money::from("30 USD"),
+            ]
+        );
+        assert_eq!(fee_model.traded_notional(), 300_000.0);
+    }
+
+    #[rstest]
+    fn test_tiered_fee_model_maker_rebate() {
+        let fee_model = TieredMakerTakerFeeModel::new(vec![FeeTier::new(0.0, -0.5, 2.0)]).unwrap();
+        let aud_usd = InstrumentAny::CurrencyPair(audusd_sim());
+        let order = filled_order(&aud_usd, LiquiditySide::Maker);
+
+        let commission = fee_model
+            .get_commission(
+                &order,
+                Quantity::from(100_000),
+                Price::from("1.0"),
+                &aud_usd,
+            )
+            .unwrap();
+
+        assert_eq!(commission, Money::from("-5 USD"));
+    }
+
+    #[rstest]
+    fn test_tiered_fee_model_clones_share_traded_notional() {
+        let fee_model = TieredMakerTakerFeeModel::new(vec![FeeTier::new(0.0, 1.0, 5.0)]).unwrap();
+        let fee_model_clone = fee_model.clone();
+        let aud_usd = InstrumentAny::CurrencyPair(audusd_sim());
+        let order = filled_order(&aud_usd, LiquiditySide::Taker);
+
+        fee_model_clone
+            .get_commission(
+                &order,
+                Quantity::from(100_000),
+                Price::from("1.0"),
+                &aud_usd,
+            )
+            .unwrap();
+
+        assert_eq!(fee_model.traded_notional(), 100_000.0);
+    }
+
+    #[rstest]
+    #[case(vec![])]
+    #[case(vec![FeeTier::new(1_000.0, 1.0, 5.0)])]
+    #[case(vec![FeeTier::new(f64::NAN, 1.0, 5.0)])]
+    #[case(vec![FeeTier::new(0.0, f64::INFINITY, 5.0)])]
+    fn test_tiered_fee_model_invalid_tiers(#[case] tiers: Vec<FeeTier>) {
+        assert!(TieredMakerTakerFeeModel::new(tiers).is_err());
+    }
+
+    #[rstest]
+    fn test_per_contract_fee_model() {
+        let fee_model = PerContractFeeModel::new(Money::from("0.85 USD")).unwrap();
+        let aud_usd = InstrumentAny::CurrencyPair(audusd_sim());
+        let order = filled_order(&aud_usd, LiquiditySide::Taker);
+
+        let commission = fee_model
+            .get_commission(&order, Quantity::from(3), Price::from("1.0"), &aud_usd)
+            .unwrap();
+
+        assert_eq!(commission, Money::from("2.55 USD"));
+    }
+
+    #[rstest]
+    fn test_per_contract_fee_model_negative_commission() {
+        assert!(PerContractFeeModel::new(Money::from("-1 USD")).is_err());
+    }
+
+    #[rstest]
+    #[case(0, EIGHT_HOURS_NS)]
+    #[case(EIGHT_HOURS_NS - 1, EIGHT_HOURS_NS)]
+    #[case(EIGHT_HOURS_NS, 2 * EIGHT_HOURS_NS)]
+    fn test_funding_fee_model_next_funding_time(#[case] ts: u64, #[case] expected: u64) {
+        let fee_model =
+            FundingFeeModel::new(FeeModelAny::default(), 0.0001, EIGHT_HOURS_NS).unwrap();
+
+        assert_eq!(
+            fee_model.next_funding_time(UnixNanos::from(ts)),
+            Some(UnixNanos::from(expected))
+        );
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, 0.0001, Money::from("-0.2 USDT"))]
+    #[case(OrderSide::Sell, 0.0001, Money::from("0.2 USDT"))]
+    #[case(OrderSide::Buy, -0.0001, Money::from("0.2 USDT"))]
+    fn test_funding_fee_model_payment(
+        #[case] side: OrderSide,
+        #[case] funding_rate: f64,
+        #[case] expected: Money,
+    ) {
+        let fee_model = FeeModelAny::Funding(
+            FundingFeeModel::new(FeeModelAny::default(), funding_rate, EIGHT_HOURS_NS).unwrap(),
+        );
+        let (instrument, position) = perpetual_position(side);
+
+        let payment = fee_model
+            .get_funding_payment(&position, Price::from("1000.00"), &instrument)
+            .unwrap();
+
+        assert_eq!(payment, Some(expected));
+    }
+
+    #[rstest]
+    fn test_funding_fee_model_no_payment_for_non_perpetual() {
+        let fee_model =
+            FundingFeeModel::new(FeeModelAny::default(), 0.0001, EIGHT_HOURS_NS).unwrap();
+        let (_, position) = perpetual_position(OrderSide::Buy);
+        let aud_usd = InstrumentAny::CurrencyPair(audusd_sim());
+
+        let payment = fee_model
+            .get_funding_payment(&position, Price::from("1.0"), &aud_usd)
+            .unwrap();
+
+        assert_eq!(payment, None);
+    }
+
+    #[rstest]
+    fn test_funding_fee_model_delegates_commission() {
+        let fee_model = FundingFeeModel::new(
+            FeeModelAny::PerContract(PerContractFeeModel::new(Money::from("1 USD")).unwrap()),
+            0.0001,
+            EIGHT_HOURS_NS,
+        )
+        .unwrap();
+        let aud_usd = InstrumentAny::CurrencyPair(audusd_sim());
+        let order = filled_order(&aud_usd, LiquiditySide::Taker);
+
+        let commission = fee_model
+            .get_commission(&order, Quantity::from(2), Price::from("1.0"), &aud_usd)
+            .unwrap();
+
+        assert_eq!(commission, Money::from("2 USD"));
+    }
+
+    #[rstest]
+    #[case(f64::NAN, EIGHT_HOURS_NS)]
+    #[case(0.0001, 0)]
+    fn test_funding_fee_model_invalid_params(
+        #[case] funding_rate: f64,
+        #[case] funding_interval_ns: u64,
+    ) {
+        assert!(
+            FundingFeeModel::new(FeeModelAny::default(), funding_rate, funding_interval_ns)
+                .is_err()
+        );
+    }
 }
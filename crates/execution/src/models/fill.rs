@@ -16,8 +16,11 @@
 use std::fmt::Display;
 
 use nautilus_core::correctness::{check_in_range_inclusive_f64, FAILED};
+use nautilus_model::identifiers::ClientOrderId;
 use rand::{rngs::StdRng, Rng, SeedableRng};
 
+use super::queue::QueuePositionTracker;
+
 #[derive(Debug, Clone)]
 pub struct FillModel {
     /// The probability of limit order filling if the market rests on its price.
@@ -28,6 +31,8 @@ pub struct FillModel {
     prob_slippage: f64,
     /// Random number generator
     rng: StdRng,
+    /// The queue positions of resting limit orders (if tracked).
+    queue_positions: Option<QueuePositionTracker>,
 }
 
 impl FillModel {
@@ -52,13 +57,43 @@ impl FillModel {
             prob_fill_on_stop,
             prob_slippage,
             rng,
+            queue_positions: None,
         })
     }
 
+    /// Enables tracking the queue positions of resting limit orders, so that orders resting
+    /// at the touch are only filled once the displayed quantity ahead of them is consumed
+    /// (rather than with `prob_fill_on_limit`).
+    #[must_use]
+    pub fn with_queue_position(mut self) -> Self {
+        self.queue_positions = Some(QueuePositionTracker::new());
+        self
+    }
+
+    /// Returns the queue positions of resting limit orders (if tracked).
+    #[must_use]
+    pub const fn queue_positions(&self) -> Option<&QueuePositionTracker> {
+        self.queue_positions.as_ref()
+    }
+
+    /// Returns the mutable queue positions of resting limit orders (if tracked).
+    pub const fn queue_positions_mut(&mut self) -> Option<&mut QueuePositionTracker> {
+        self.queue_positions.as_mut()
+    }
+
     pub fn is_limit_filled(&mut self) -> bool {
         self.event_success(self.prob_fill_on_limit)
     }
 
+    /// Returns whether the limit order resting at the touch is filled, from its queue position
+    /// if tracked, otherwise with `prob_fill_on_limit`.
+    pub fn is_resting_limit_filled(&mut self, client_order_id: &ClientOrderId) -> bool {
+        match &self.queue_positions {
+            Some(queue_positions) => queue_positions.is_depleted(client_order_id),
+            None => self.is_limit_filled(),
+        }
+    }
+
     pub fn is_stop_filled(&mut self) -> bool {
         self.event_success(self.prob_fill_on_stop)
     }
@@ -80,8 +115,11 @@ impl Display for FillModel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "FillModel(prob_fill_on_limit: {}, prob_fill_on_stop: {}, prob_slippage: {})",
-            self.prob_fill_on_limit, self.prob_fill_on_stop, self.prob_slippage
+            "FillModel(prob_fill_on_limit: {}, prob_fill_on_stop: {}, prob_slippage: {}, queue_position: {})",
+            self.prob_fill_on_limit,
+            self.prob_fill_on_stop,
+            self.prob_slippage,
+            self.queue_positions.is_some()
         )
     }
 }
@@ -13,12 +13,365 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::fmt::Display;
+//! Latency models for simulating the time taken for messages to travel between the
+//! trading node and a simulated venue.
 
-pub struct LatencyModel;
+use std::{fmt::Display, path::Path, sync::Arc};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// A distribution of latencies in nanoseconds.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LatencyDistribution {
+    /// A fixed latency.
+    Fixed(u64),
+    /// A normally distributed latency, truncated at zero.
+    Normal { mean_ns: f64, std_dev_ns: f64 },
+    /// A latency sampled uniformly from observed latencies.
+    Empirical(Arc<[u64]>),
+}
+
+impl LatencyDistribution {
+    /// Creates a normally distributed latency.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `mean_ns` or `std_dev_ns` is negative or not finite.
+    pub fn normal(mean_ns: f64, std_dev_ns: f64) -> anyhow::Result<Self> {
+        if !(mean_ns.is_finite() && mean_ns >= 0.0) {
+            anyhow::bail!("invalid `mean_ns` for normal latency, was {mean_ns}");
+        }
+        if !(std_dev_ns.is_finite() && std_dev_ns >= 0.0) {
+            anyhow::bail!("invalid `std_dev_ns` for normal latency, was {std_dev_ns}");
+        }
+        Ok(Self::Normal {
+            mean_ns,
+            std_dev_ns,
+        })
+    }
+
+    /// Creates a latency sampled from the observed `samples` (nanoseconds).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `samples` is empty.
+    pub fn empirical(samples: Vec<u64>) -> anyhow::Result<Self> {
+        if samples.is_empty() {
+            anyhow::bail!("Empirical latency requires at least one sample");
+        }
+        Ok(Self::Empirical(samples.into()))
+    }
+
+    /// Creates a latency sampled from the observed latencies in the file at `path`.
+    ///
+    /// The file contains one latency in nanoseconds per line, blank lines and lines
+    /// starting with `#` are ignored.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the file cannot be read, contains an invalid
+    /// latency, or contains no latencies.
+    pub fn empirical_from_file(path: &Path) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path)?;
+        let samples = contents
+            .lines()
+            .map(str::trim)
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+            .map(|(i, line)| {
+                line.parse::<u64>()
+                    .map_err(|e| anyhow::anyhow!("Invalid latency '{line}' on line {}: {e}", i + 1))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        Self::empirical(samples)
+    }
+
+    /// Samples a latency (nanoseconds) from the distribution.
+    pub fn sample(&self, rng: &mut StdRng) -> u64 {
+        match self {
+            Self::Fixed(latency_ns) => *latency_ns,
+            Self::Normal {
+                mean_ns,
+                std_dev_ns,
+            } => {
+                // Box-Muller transform
+                let u1: f64 = 1.0 - rng.random::<f64>();
+                let u2: f64 = rng.random();
+                let z = (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos();
+                (mean_ns + std_dev_ns * z).max(0.0).round() as u64
+            }
+            Self::Empirical(samples) => samples[rng.random_range(0..samples.len())],
+        }
+    }
+}
+
+impl Default for LatencyDistribution {
+    fn default() -> Self {
+        Self::Fixed(0)
+    }
+}
+
+impl Display for LatencyDistribution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Fixed(latency_ns) => write!(f, "Fixed({latency_ns}ns)"),
+            Self::Normal {
+                mean_ns,
+                std_dev_ns,
+            } => write!(f, "Normal(mean={mean_ns}ns, std_dev={std_dev_ns}ns)"),
+            Self::Empirical(samples) => write!(f, "Empirical({} samples)", samples.len()),
+        }
+    }
+}
+
+/// Models the latencies between the trading node and a simulated venue, with separate
+/// distributions for each outbound command type and for inbound market data.
+///
+/// Each simulated exchange holds its own latency model, so latencies are configured per venue.
+#[derive(Clone, Debug)]
+pub struct LatencyModel {
+    /// The latency for order submissions to reach the venue.
+    pub insert: LatencyDistribution,
+    /// The latency for order modifications to reach the venue.
+    pub update: LatencyDistribution,
+    /// The latency for order cancellations to reach the venue.
+    pub cancel: LatencyDistribution,
+    /// The latency for market data to reach the trading node from the venue.
+    pub market_data: LatencyDistribution,
+    /// Random number generator
+    rng: StdRng,
+}
+
+impl LatencyModel {
+    /// Creates a new [`LatencyModel`] instance.
+    #[must_use]
+    pub fn new(
+        insert: LatencyDistribution,
+        update: LatencyDistribution,
+        cancel: LatencyDistribution,
+        market_data: LatencyDistribution,
+        random_seed: Option<u64>,
+    ) -> Self {
+        let rng = match random_seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_os_rng(),
+        };
+        Self {
+            insert,
+            update,
+            cancel,
+            market_data,
+            rng,
+        }
+    }
+
+    /// Creates a new [`LatencyModel`] instance with the same fixed latency for all messages.
+    #[must_use]
+    pub fn fixed(latency_ns: u64) -> Self {
+        let latency = LatencyDistribution::Fixed(latency_ns);
+        Self::new(
+            latency.clone(),
+            latency.clone(),
+            latency.clone(),
+            latency,
+            None,
+        )
+    }
+
+    /// Creates a new [`LatencyModel`] instance with a fixed `base_latency_ns` for all messages,
+    /// plus the given additional fixed latencies for each command type.
+    #[must_use]
+    pub fn with_base_latency(
+        base_latency_ns: u64,
+        insert_latency_ns: u64,
+        update_latency_ns: u64,
+        cancel_latency_ns: u64,
+    ) -> Self {
+        Self::new(
+            LatencyDistribution::Fixed(base_latency_ns.saturating_add(insert_latency_ns)),
+            LatencyDistribution::Fixed(base_latency_ns.saturating_add(update_latency_ns)),
+            LatencyDistribution::Fixed(base_latency_ns.saturating_add(cancel_latency_ns)),
+            LatencyDistribution::Fixed(base_latency_ns),
+            None,
+        )
+    }
+
+    /// Samples the latency (nanoseconds) for an order submission.
+    pub fn insert_latency_ns(&mut self) -> u64 {
+        self.insert.sample(&mut self.rng)
+    }
+
+    /// Samples the latency (nanoseconds) for an order modification.
+    pub fn update_latency_ns(&mut self) -> u64 {
+        self.update.sample(&mut self.rng)
+    }
+
+    /// Samples the latency (nanoseconds) for an order cancellation.
+    pub fn cancel_latency_ns(&mut self) -> u64 {
+        self.cancel.sample(&mut self.rng)
+    }
+
+    /// Samples the latency (nanoseconds) for market data.
+    pub fn market_data_latency_ns(&mut self) -> u64 {
+        self.market_data.sample(&mut self.rng)
+    }
+}
+
+impl Default for LatencyModel {
+    /// Creates a new default [`LatencyModel`] instance with zero latency.
+    fn default() -> Self {
+        Self::fixed(0)
+    }
+}
 
 impl Display for LatencyModel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "LatencyModel()")
+        write!(
+            f,
+            "LatencyModel(insert: {}, update: {}, cancel: {}, market_data: {})",
+            self.insert, self.update, self.cancel, self.market_data
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn rng() -> StdRng {
+        StdRng::seed_from_u64(42)
+    }
+
+    #[rstest]
+    fn test_fixed_sample() {
+        assert_eq!(LatencyDistribution::Fixed(1_000).sample(&mut rng()), 1_000);
+    }
+
+    #[rstest]
+    fn test_normal_sample_without_deviation_is_mean() {
+        let latency = LatencyDistribution::normal(1_000.0, 0.0).unwrap();
+
+        assert_eq!(latency.sample(&mut rng()), 1_000);
+    }
+
+    #[rstest]
+    fn test_normal_samples_are_distributed_around_mean() {
+        let latency = LatencyDistribution::normal(1_000_000.0, 100_000.0).unwrap();
+        let mut rng = rng();
+
+        let samples: Vec<u64> = (0..10_000).map(|_| latency.sample(&mut rng)).collect();
+        let mean = samples.iter().sum::<u64>() as f64 / samples.len() as f64;
+
+        assert!((mean - 1_000_000.0).abs() < 5_000.0, "mean was {mean}");
+        assert!(samples.iter().any(|&s| s < 900_000));
+        assert!(samples.iter().any(|&s| s > 1_100_000));
+    }
+
+    #[rstest]
+    fn test_normal_samples_are_truncated_at_zero() {
+        let latency = LatencyDistribution::normal(0.0, 1_000.0).unwrap();
+        let mut rng = rng();
+
+        let zeros = (0..1_000).filter(|_| latency.sample(&mut rng) == 0).count();
+
+        assert!(zeros > 400, "zeros was {zeros}");
+    }
+
+    #[rstest]
+    #[case(-1.0, 0.0)]
+    #[case(0.0, -1.0)]
+    #[case(f64::NAN, 0.0)]
+    #[case(0.0, f64::INFINITY)]
+    fn test_normal_invalid_params(#[case] mean_ns: f64, #[case] std_dev_ns: f64) {
+        assert!(LatencyDistribution::normal(mean_ns, std_dev_ns).is_err());
+    }
+
+    #[rstest]
+    fn test_empirical_samples_from_observed() {
+        let latency = LatencyDistribution::empirical(vec![100, 200, 300]).unwrap();
+        let mut rng = rng();
+
+        for _ in 0..100 {
+            assert!([100, 200, 300].contains(&latency.sample(&mut rng)));
+        }
+    }
+
+    #[rstest]
+    fn test_empirical_requires_samples() {
+        assert!(LatencyDistribution::empirical(vec![]).is_err());
+    }
+
+    #[rstest]
+    fn test_empirical_from_file() {
+        let path = std::env::temp_dir().join(format!("latencies-{}.txt", std::process::id()));
+        std::fs::write(&path, "# latency_ns\n100\n\n 200 \n").unwrap();
+
+        let latency = LatencyDistribution::empirical_from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            latency,
+            LatencyDistribution::Empirical(vec![100, 200].into())
+        );
+    }
+
+    #[rstest]
+    fn test_empirical_from_file_invalid_latency() {
+        let path =
+            std::env::temp_dir().join(format!("latencies-invalid-{}.txt", std::process::id()));
+        std::fs::write(&path, "100\nfast\n").unwrap();
+
+        let result = LatencyDistribution::empirical_from_file(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        let err = result.unwrap_err();
+        assert!(err.to_string().contains("line 2"), "{err}");
+    }
+
+    #[rstest]
+    fn test_latency_model_samples_per_message_type() {
+        let mut model = LatencyModel::new(
+            LatencyDistribution::Fixed(1),
+            LatencyDistribution::Fixed(2),
+            LatencyDistribution::Fixed(3),
+            LatencyDistribution::Fixed(4),
+            Some(42),
+        );
+
+        assert_eq!(model.insert_latency_ns(), 1);
+        assert_eq!(model.update_latency_ns(), 2);
+        assert_eq!(model.cancel_latency_ns(), 3);
+        assert_eq!(model.market_data_latency_ns(), 4);
+    }
+
+    #[rstest]
+    fn test_latency_model_with_base_latency() {
+        let mut model = LatencyModel::with_base_latency(1_000, 100, 200, 300);
+
+        assert_eq!(model.insert_latency_ns(), 1_100);
+        assert_eq!(model.update_latency_ns(), 1_200);
+        assert_eq!(model.cancel_latency_ns(), 1_300);
+        assert_eq!(model.market_data_latency_ns(), 1_000);
+    }
+
+    #[rstest]
+    fn test_latency_model_display() {
+        let model = LatencyModel::new(
+            LatencyDistribution::Fixed(1),
+            LatencyDistribution::normal(2.0, 0.5).unwrap(),
+            LatencyDistribution::empirical(vec![3, 4]).unwrap(),
+            LatencyDistribution::default(),
+            None,
+        );
+
+        assert_eq!(
+            model.to_string(),
+            "LatencyModel(insert: Fixed(1ns), update: Normal(mean=2ns, std_dev=0.5ns), cancel: Empirical(2 samples), market_data: Fixed(0ns))"
+        );
     }
 }
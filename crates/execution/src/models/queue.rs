@@ -0,0 +1,422 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Queue position estimation for resting limit orders in the simulated order book.
+//!
+//! The displayed quantity ahead of an order is taken from its price level when the order
+//! starts resting. Trades at the level consume the queue from the front, while other level
+//! decreases (cancellations) are assumed to be spread proportionally over the quantity ahead
+//! of and behind the order. Quantity joining the level queues behind the order.
+
+use std::collections::HashMap;
+
+use nautilus_model::{
+    data::TradeTick,
+    enums::{AggressorSide, BookType, OrderSide},
+    identifiers::ClientOrderId,
+    orderbook::{BookLevel, OrderBook},
+    types::Price,
+};
+
+/// The estimated position of a resting limit order in the queue at its price level.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QueuePosition {
+    /// The order side.
+    pub side: OrderSide,
+    /// The order limit price.
+    pub price: Price,
+    /// The displayed quantity estimated to be ahead of the order.
+    pub ahead: f64,
+    /// The displayed quantity estimated to be behind the order.
+    pub behind: f64,
+    /// The level size last observed for the order price.
+    level_size: f64,
+}
+
+impl QueuePosition {
+    /// Returns whether all the displayed quantity ahead of the order has been consumed.
+    #[must_use]
+    pub fn is_depleted(&self) -> bool {
+        self.ahead <= 0.0
+    }
+
+    fn update_level_size(&mut self, level_size: f64) {
+        let change = level_size - self.level_size;
+        if change > 0.0 {
+            self.behind += change;
+        } else if change < 0.0 {
+            let queued = self.ahead + self.behind;
+            if queued > 0.0 {
+                let removed = -change;
+                self.ahead = (self.ahead - removed * self.ahead / queued).max(0.0);
+                self.behind = (self.behind - removed * self.behind / queued).max(0.0);
+            }
+        }
+        self.level_size = level_size;
+    }
+
+    fn clear(&mut self) {
+        self.ahead = 0.0;
+        self.behind = 0.0;
+        self.level_size = 0.0;
+    }
+
+    const fn is_traded_through(&self, price: Price) -> bool {
+        match self.side {
+            OrderSide::Buy => price.raw < self.price.raw,
+            _ => price.raw > self.price.raw,
+        }
+    }
+
+    fn is_hit_by(&self, aggressor_side: AggressorSide) -> bool {
+        match aggressor_side {
+            AggressorSide::Buyer => self.side == OrderSide::Sell,
+            AggressorSide::Seller => self.side == OrderSide::Buy,
+            AggressorSide::NoAggressor => true,
+        }
+    }
+}
+
+/// Tracks the estimated queue positions of resting limit orders from order book updates
+/// and trades.
+#[derive(Clone, Debug, Default)]
+pub struct QueuePositionTracker {
+    positions: HashMap<ClientOrderId, QueuePosition>,
+}
+
+impl QueuePositionTracker {
+    /// Creates a new [`QueuePositionTracker`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts tracking the order at the back of the queue for its price level in the `book`.
+    ///
+    /// An order which is already tracked loses its queue priority.
+    pub fn add_order(
+        &mut self,
+        client_order_id: ClientOrderId,
+        side: OrderSide,
+        price: Price,
+        book: &OrderBook,
+    ) {
+        let level_size = level_size(book, side, price).unwrap_or(0.0);
+        self.positions.insert(
+            client_order_id,
+            QueuePosition {
+                side,
+                price,
+                ahead: level_size,
+                behind: 0.0,
+                level_size,
+            },
+        );
+    }
+
+    /// Stops tracking the order.
+    pub fn remove_order(&mut self, client_order_id: &ClientOrderId) {
+        self.positions.remove(client_order_id);
+    }
+
+    /// Retains only the tracked orders for which `f` returns `true`.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&ClientOrderId) -> bool,
+    {
+        self.positions
+            .retain(|client_order_id, _| f(client_order_id));
+    }
+
+    /// Returns the estimated queue position of the order (if tracked).
+    #[must_use]
+    pub fn get(&self, client_order_id: &ClientOrderId) -> Option<&QueuePosition> {
+        self.positions.get(client_order_id)
+    }
+
+    /// Returns whether the queue ahead of the order is depleted (untracked orders are
+    /// considered to be at the front of the queue).
+    #[must_use]
+    pub fn is_depleted(&self, client_order_id: &ClientOrderId) -> bool {
+        self.positions
+            .get(client_order_id)
+            .is_none_or(QueuePosition::is_depleted)
+    }
+
+    /// Updates the queue positions from the level sizes of the `book`.
+    ///
+    /// A missing level is treated as fully consumed, except for an L1 book where the level
+    /// is only hidden behind a better price.
+    pub fn update_from_book(&mut self, book: &OrderBook) {
+        for position in self.positions.values_mut() {
+            match level_size(book, position.side, position.price) {
+                Some(size) => position.update_level_size(size),
+                None if book.book_type == BookType::L1_MBP
+                    && is_behind_top(book, position.side, position.price) => {}
+                None => position.clear(),
+            }
+        }
+    }
+
+    /// Updates the queue positions from the `trade`, consuming the queues at the trade price
+    /// from the front and clearing the queues traded through.
+    ///
+    /// This should be called before applying any book update for the trade, so that the
+    /// traded quantity is not also counted as a cancellation.
+    pub fn update_from_trade(&mut self, trade: &TradeTick) {
+        let size = trade.size.as_f64();
+        for position in self.positions.values_mut() {
+            if !position.is_hit_by(trade.aggressor_side) {
+                continue;
+            }
+            if position.is_traded_through(trade.price) {
+                position.clear();
+            } else if trade.price == position.price {
+                position.ahead = (position.ahead - size).max(0.0);
+                position.level_size = (position.level_size - size).max(0.0);
+            }
+        }
+    }
+}
+
+fn level_size(book: &OrderBook, side: OrderSide, price: Price) -> Option<f64> {
+    let level = match side {
+        OrderSide::Buy => book.bids(None).find(|level| level.price.value == price),
+        _ => book.asks(None).find(|level| level.price.value == price),
+    };
+    level.map(BookLevel::size)
+}
+
+fn is_behind_top(book: &OrderBook, side: OrderSide, price: Price) -> bool {
+    match side {
+        OrderSide::Buy => book.best_bid_price().is_some_and(|bid| bid > price),
+        _ => book.best_ask_price().is_some_and(|ask| ask < price),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::UnixNanos;
+    use nautilus_model::{
+        data::BookOrder,
+        identifiers::{InstrumentId, TradeId},
+        types::Quantity,
+    };
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    const BID_ORDER_ID: u64 = 1;
+    const ASK_ORDER_ID: u64 = 2;
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("ETHUSDT-PERP.BINANCE")
+    }
+
+    fn client_order_id() -> ClientOrderId {
+        ClientOrderId::from("O-19700101-000000-001-001-1")
+    }
+
+    fn set_level(book: &mut OrderBook, side: OrderSide, price: &str, size: &str) {
+        let order_id = if side == OrderSide::Buy {
+            BID_ORDER_ID
+        } else {
+            ASK_ORDER_ID
+        };
+        let order = BookOrder::new(side, Price::from(price), Quantity::from(size), order_id);
+        book.update(order, 0, 0, UnixNanos::default());
+    }
+
+    fn trade(price: &str, size: &str, aggressor_side: AggressorSide) -> TradeTick {
+        TradeTick::new(
+            instrument_id(),
+            Price::from(price),
+            Quantity::from(size),
+            aggressor_side,
+            TradeId::new("1"),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    }
+
+    #[fixture]
+    fn book() -> OrderBook {
+        let mut book = OrderBook::new(instrument_id(), BookType::L2_MBP);
+        set_level(&mut book, OrderSide::Buy, "100.00", "10");
+        set_level(&mut book, OrderSide::Sell, "101.00", "10");
+        book
+    }
+
+    #[fixture]
+    fn tracker(book: OrderBook) -> (QueuePositionTracker, OrderBook) {
+        let mut tracker = QueuePositionTracker::new();
+        tracker.add_order(
+            client_order_id(),
+            OrderSide::Buy,
+            Price::from("100.00"),
+            &book,
+        );
+        (tracker, book)
+    }
+
+    fn position(tracker: &QueuePositionTracker) -> QueuePosition {
+        *tracker.get(&client_order_id()).unwrap()
+    }
+
+    #[rstest]
+    fn test_add_order_queues_behind_level(tracker: (QueuePositionTracker, OrderBook)) {
+        let (tracker, _) = tracker;
+
+        assert_eq!(position(&tracker).ahead, 10.0);
+        assert_eq!(position(&tracker).behind, 0.0);
+        assert!(!tracker.is_depleted(&client_order_id()));
+    }
+
+    #[rstest]
+    fn test_add_order_at_empty_level_is_at_front(book: OrderBook) {
+        let mut tracker = QueuePositionTracker::new();
+        tracker.add_order(
+            client_order_id(),
+            OrderSide::Buy,
+            Price::from("100.50"),
+            &book,
+        );
+
+        assert!(tracker.is_depleted(&client_order_id()));
+    }
+
+    #[rstest]
+    fn test_untracked_order_is_depleted() {
+        let tracker = QueuePositionTracker::new();
+
+        assert!(tracker.is_depleted(&client_order_id()));
+    }
+
+    #[rstest]
+    fn test_level_increase_queues_behind(tracker: (QueuePositionTracker, OrderBook)) {
+        let (mut tracker, mut book) = tracker;
+        set_level(&mut book, OrderSide::Buy, "100.00", "15");
+
+        tracker.update_from_book(&book);
+
+        assert_eq!(position(&tracker).ahead, 10.0);
+        assert_eq!(position(&tracker).behind, 5.0);
+    }
+
+    #[rstest]
+    fn test_level_decrease_is_proportional(tracker: (QueuePositionTracker, OrderBook)) {
+        let (mut tracker, mut book) = tracker;
+        set_level(&mut book, OrderSide::Buy, "100.00", "20");
+        tracker.update_from_book(&book);
+
+        // Half of the queue is ahead, so half of the canceled quantity is ahead
+        set_level(&mut book, OrderSide::Buy, "100.00", "16");
+        tracker.update_from_book(&book);
+
+        assert_eq!(position(&tracker).ahead, 8.0);
+        assert_eq!(position(&tracker).behind, 8.0);
+    }
+
+    #[rstest]
+    fn test_trade_consumes_queue_from_front(tracker: (QueuePositionTracker, OrderBook)) {
+        let (mut tracker, mut book) = tracker;
+        set_level(&mut book, OrderSide::Buy, "100.00", "15");
+        tracker.update_from_book(&book);
+
+        tracker.update_from_trade(&trade("100.00", "4", AggressorSide::Seller));
+        // The book then reflects the traded quantity
+        set_level(&mut book, OrderSide::Buy, "100.00", "11");
+        tracker.update_from_book(&book);
+
+        assert_eq!(position(&tracker).ahead, 6.0);
+        assert_eq!(position(&tracker).behind, 5.0);
+    }
+
+    #[rstest]
+    fn test_trade_depletes_queue(tracker: (QueuePositionTracker, OrderBook)) {
+        let (mut tracker, _) = tracker;
+
+        tracker.update_from_trade(&trade("100.00", "4", AggressorSide::Seller));
+        tracker.update_from_trade(&trade("100.00", "7", AggressorSide::Seller));
+
+        assert!(tracker.is_depleted(&client_order_id()));
+    }
+
+    #[rstest]
+    #[case("100.00", AggressorSide::Buyer, 10.0)]
+    #[case("100.50", AggressorSide::Seller, 10.0)]
+    #[case("99.50", AggressorSide::Seller, 0.0)]
+    #[case("99.50", AggressorSide::NoAggressor, 0.0)]
+    fn test_trade_other_prices_and_sides(
+        tracker: (QueuePositionTracker, OrderBook),
+        #[case] price: &str,
+        #[case] aggressor_side: AggressorSide,
+        #[case] expected_ahead: f64,
+    ) {
+        let (mut tracker, _) = tracker;
+
+        tracker.update_from_trade(&trade(price, "1", aggressor_side));
+
+        assert_eq!(position(&tracker).ahead, expected_ahead);
+    }
+
+    #[rstest]
+    fn test_level_removed_clears_queue(tracker: (QueuePositionTracker, OrderBook)) {
+        let (mut tracker, mut book) = tracker;
+        let order = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("100.00"),
+            Quantity::from("10"),
+            BID_ORDER_ID,
+        );
+        book.delete(order, 0, 0, UnixNanos::default());
+
+        tracker.update_from_book(&book);
+
+        assert!(tracker.is_depleted(&client_order_id()));
+    }
+
+    #[rstest]
+    fn test_l1_level_hidden_behind_better_price_is_retained() {
+        let mut book = OrderBook::new(instrument_id(), BookType::L1_MBP);
+        set_level(&mut book, OrderSide::Buy, "100.00", "10");
+        let mut tracker = QueuePositionTracker::new();
+        tracker.add_order(
+            client_order_id(),
+            OrderSide::Buy,
+            Price::from("100.00"),
+            &book,
+        );
+
+        set_level(&mut book, OrderSide::Buy, "100.50", "3");
+        tracker.update_from_book(&book);
+
+        assert_eq!(position(&tracker).ahead, 10.0);
+    }
+
+    #[rstest]
+    fn test_retain_and_remove_order(tracker: (QueuePositionTracker, OrderBook)) {
+        let (mut tracker, _) = tracker;
+
+        tracker.retain(|_| true);
+        assert!(tracker.get(&client_order_id()).is_some());
+
+        tracker.remove_order(&client_order_id());
+        assert!(tracker.get(&client_order_id()).is_none());
+    }
+}
@@ -0,0 +1,372 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Slippage models for the fill prices of market orders on a simulated venue.
+
+use std::{collections::VecDeque, fmt::Display};
+
+use nautilus_model::{
+    enums::OrderSideSpecified,
+    types::{price::PriceRaw, Price, Quantity},
+};
+
+/// The method by which a [`SlippageModel`] determines slippage.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SlippageKind {
+    /// Fills beyond the displayed depth continue at successive price increments, each level
+    /// assumed to hold the quantity of the last displayed level.
+    WalkTheBook,
+    /// Fill prices move against the order by a fixed number of basis points.
+    FixedBps(f64),
+    /// Fill prices move against the order by a multiple of the volatility of the mid price
+    /// returns over a rolling window of book updates.
+    VolatilityScaled { multiplier: f64, window: usize },
+}
+
+impl Display for SlippageKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::WalkTheBook => write!(f, "WalkTheBook"),
+            Self::FixedBps(bps) => write!(f, "FixedBps({bps})"),
+            Self::VolatilityScaled { multiplier, window } => {
+                write!(
+                    f,
+                    "VolatilityScaled(multiplier={multiplier}, window={window})"
+                )
+            }
+        }
+    }
+}
+
+/// Models the slippage of market order fills from the prices available in the book.
+///
+/// Fill prices are moved against the order in whole price increments, rounding away from the
+/// order's favor.
+#[derive(Clone, Debug)]
+pub struct SlippageModel {
+    kind: SlippageKind,
+    prices: VecDeque<f64>,
+}
+
+impl SlippageModel {
+    /// Creates a new [`SlippageModel`] walking beyond the displayed depth of the book.
+    #[must_use]
+    pub const fn walk_the_book() -> Self {
+        Self {
+            kind: SlippageKind::WalkTheBook,
+            prices: VecDeque::new(),
+        }
+    }
+
+    /// Creates a new [`SlippageModel`] with a fixed slippage of `bps` basis points.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `bps` is negative or not finite.
+    pub fn fixed_bps(bps: f64) -> anyhow::Result<Self> {
+        if !(bps.is_finite() && bps >= 0.0) {
+            anyhow::bail!("invalid `bps` for fixed slippage, was {bps}");
+        }
+        Ok(Self {
+            kind: SlippageKind::FixedBps(bps),
+            prices: VecDeque::new(),
+        })
+    }
+
+    /// Creates a new [`SlippageModel`] with slippage of `multiplier` times the standard
+    /// deviation of the mid price returns over the last `window` book updates.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - `multiplier` is negative or not finite.
+    /// - `window` is less than 2.
+    pub fn volatility_scaled(multiplier: f64, window: usize) -> anyhow::Result<Self> {
+        if !(multiplier.is_finite() && multiplier >= 0.0) {
+            anyhow::bail!("invalid `multiplier` for volatility slippage, was {multiplier}");
+        }
+        if window < 2 {
+            anyhow::bail!("invalid `window` for volatility slippage, was {window}");
+        }
+        Ok(Self {
+            kind: SlippageKind::VolatilityScaled { multiplier, window },
+            prices: VecDeque::with_capacity(window + 1),
+        })
+    }
+
+    /// Returns the slippage method of the model.
+    #[must_use]
+    pub const fn kind(&self) -> &SlippageKind {
+        &self.kind
+    }
+
+    /// Updates the model with the latest mid `price` of the book.
+    pub fn update_price(&mut self, price: Price) {
+        let SlippageKind::VolatilityScaled { window, .. } = self.kind else {
+            return;
+        };
+        let price = price.as_f64();
+        if price <= 0.0 {
+            return;
+        }
+        self.prices.push_back(price);
+        while self.prices.len() > window + 1 {
+            self.prices.pop_front();
+        }
+    }
+
+    /// Returns the standard deviation of the mid price log returns, once the window is full.
+    #[must_use]
+    pub fn volatility(&self) -> Option<f64> {
+        let SlippageKind::VolatilityScaled { window, .. } = self.kind else {
+            return None;
+        };
+        if self.prices.len() <= window {
+            return None;
+        }
+
+        let returns: Vec<f64> = self
+            .prices
+            .iter()
+            .zip(self.prices.iter().skip(1))
+            .map(|(prev, next)| (next / prev).ln())
+            .collect();
+        let mean = returns.iter().sum::<f64>() / returns.len() as f64;
+        let variance =
+            returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (returns.len() - 1) as f64;
+        Some(variance.sqrt())
+    }
+
+    /// Resets the model's price history.
+    pub fn reset(&mut self) {
+        self.prices.clear();
+    }
+
+    /// Applies slippage to the `fills` of a market order on the `side` for `quantity`, with
+    /// prices moved in multiples of the `price_increment`.
+    #[must_use]
+    pub fn apply(
+        &self,
+        side: OrderSideSpecified,
+        quantity: Quantity,
+        fills: Vec<(Price, Quantity)>,
+        price_increment: Price,
+    ) -> Vec<(Price, Quantity)> {
+        match self.kind {
+            SlippageKind::WalkTheBook => walk_the_book(side, quantity, fills, price_increment),
+            SlippageKind::FixedBps(bps) => {
+                shift_fills(side, fills, bps / 10_000.0, price_increment)
+            }
+            SlippageKind::VolatilityScaled { multiplier, .. } => match self.volatility() {
+                Some(volatility) => {
+                    shift_fills(side, fills, multiplier * volatility, price_increment)
+                }
+                None => fills,
+            },
+        }
+    }
+}
+
+impl Display for SlippageModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SlippageModel({})", self.kind)
+    }
+}
+
+fn walk_the_book(
+    side: OrderSideSpecified,
+    quantity: Quantity,
+    mut fills: Vec<(Price, Quantity)>,
+    price_increment: Price,
+) -> Vec<(Price, Quantity)> {
+    let Some((mut level_px, level_qty)) = fills.last().copied() else {
+        // No market to walk from
+        return fills;
+    };
+
+    let filled_raw: u128 = fills.iter().map(|(_, qty)| u128::from(qty.raw)).sum();
+    let mut remaining_raw = u128::from(quantity.raw).saturating_sub(filled_raw);
+    while remaining_raw > 0 {
+        level_px = shift_price(side, level_px, 1, price_increment);
+        let fill_raw = if level_qty.raw == 0 {
+            remaining_raw
+        } else {
+            remaining_raw.min(u128::from(level_qty.raw))
+        };
+        fills.push((
+            level_px,
+            Quantity::from_raw(fill_raw as _, quantity.precision),
+        ));
+        remaining_raw -= fill_raw;
+    }
+    fills
+}
+
+fn shift_fills(
+    side: OrderSideSpecified,
+    fills: Vec<(Price, Quantity)>,
+    fraction: f64,
+    price_increment: Price,
+) -> Vec<(Price, Quantity)> {
+    let increment = price_increment.as_f64();
+    fills
+        .into_iter()
+        .map(|(px, qty)| {
+            // Tolerate floating point error when the slippage is a whole number of ticks
+            let ticks = (px.as_f64().abs() * fraction / increment - 1e-9)
+                .ceil()
+                .max(0.0) as PriceRaw;
+            (shift_price(side, px, ticks, price_increment), qty)
+        })
+        .collect()
+}
+
+fn shift_price(
+    side: OrderSideSpecified,
+    price: Price,
+    ticks: PriceRaw,
+    price_increment: Price,
+) -> Price {
+    let shift = ticks * price_increment.raw;
+    match side {
+        OrderSideSpecified::Buy => Price::from_raw(price.raw + shift, price.precision),
+        OrderSideSpecified::Sell => Price::from_raw(price.raw - shift, price.precision),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::OrderSideSpecified,
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::SlippageModel;
+
+    fn fills(levels: &[(&str, &str)]) -> Vec<(Price, Quantity)> {
+        levels
+            .iter()
+            .map(|(px, qty)| (Price::from(*px), Quantity::from(*qty)))
+            .collect()
+    }
+
+    #[rstest]
+    fn test_walk_the_book_extends_beyond_depth() {
+        let model = SlippageModel::walk_the_book();
+
+        let result = model.apply(
+            OrderSideSpecified::Buy,
+            Quantity::from("25"),
+            fills(&[("100.00", "10")]),
+            Price::from("0.01"),
+        );
+
+        assert_eq!(
+            result,
+            fills(&[("100.00", "10"), ("100.01", "10"), ("100.02", "5")])
+        );
+    }
+
+    #[rstest]
+    fn test_walk_the_book_within_depth_unchanged() {
+        let model = SlippageModel::walk_the_book();
+        let levels = fills(&[("100.00", "10"), ("99.99", "5")]);
+
+        let result = model.apply(
+            OrderSideSpecified::Sell,
+            Quantity::from("15"),
+            levels.clone(),
+            Price::from("0.01"),
+        );
+
+        assert_eq!(result, levels);
+    }
+
+    #[rstest]
+    fn test_walk_the_book_without_market() {
+        let model = SlippageModel::walk_the_book();
+
+        let result = model.apply(
+            OrderSideSpecified::Buy,
+            Quantity::from("10"),
+            vec![],
+            Price::from("0.01"),
+        );
+
+        assert!(result.is_empty());
+    }
+
+    #[rstest]
+    #[case(OrderSideSpecified::Buy, "100.05")]
+    #[case(OrderSideSpecified::Sell, "99.95")]
+    fn test_fixed_bps(#[case] side: OrderSideSpecified, #[case] expected: &str) {
+        let model = SlippageModel::fixed_bps(4.5).unwrap();
+
+        let result = model.apply(
+            side,
+            Quantity::from("10"),
+            fills(&[("100.00", "10")]),
+            Price::from("0.01"),
+        );
+
+        // 4.5 bps of 100.00 is 0.045, rounded against the order to 0.05
+        assert_eq!(result, fills(&[(expected, "10")]));
+    }
+
+    #[rstest]
+    fn test_volatility_scaled() {
+        let mut model = SlippageModel::volatility_scaled(1.0, 2).unwrap();
+        let levels = fills(&[("100.00", "10")]);
+
+        model.update_price(Price::from("100.00"));
+        model.update_price(Price::from("101.00"));
+
+        // Not enough returns for the window
+        assert_eq!(model.volatility(), None);
+        assert_eq!(
+            model.apply(
+                OrderSideSpecified::Buy,
+                Quantity::from("10"),
+                levels.clone(),
+                Price::from("0.01"),
+            ),
+            levels
+        );
+
+        model.update_price(Price::from("100.00"));
+        let volatility = model.volatility().unwrap();
+        let result = model.apply(
+            OrderSideSpecified::Buy,
+            Quantity::from("10"),
+            levels,
+            Price::from("0.01"),
+        );
+
+        assert!((volatility - 0.014_071).abs() < 1e-6);
+        assert_eq!(result, fills(&[("101.41", "10")]));
+    }
+
+    #[rstest]
+    fn test_invalid_params() {
+        assert!(SlippageModel::fixed_bps(-1.0).is_err());
+        assert!(SlippageModel::fixed_bps(f64::NAN).is_err());
+        assert!(SlippageModel::volatility_scaled(-1.0, 10).is_err());
+        assert!(SlippageModel::volatility_scaled(1.0, 1).is_err());
+    }
+}
@@ -32,7 +32,10 @@ use crate::{
     },
     order_emulator::{
         emulator::OrderEmulator,
-        handlers::{OrderEmulatorExecuteHandler, OrderEmulatorOnEventHandler},
+        handlers::{
+            OrderEmulatorExecuteHandler, OrderEmulatorOnBarHandler, OrderEmulatorOnEventHandler,
+            OrderEmulatorOnQuoteHandler, OrderEmulatorOnTradeHandler,
+        },
     },
 };
 
@@ -54,6 +57,7 @@ impl OrderEmulatorAdapter {
 
         Self::initialize_execute_handler(emulator.clone(), msgbus.clone());
         Self::initialize_on_event_handler(emulator.clone(), msgbus);
+        Self::initialize_data_handlers(emulator.clone());
         Self::initialize_submit_order_handler(emulator.clone());
         Self::initialize_cancel_order_handler(emulator.clone());
         Self::initialize_modify_order_handler(emulator.clone());
@@ -96,14 +100,35 @@ impl OrderEmulatorAdapter {
     ) {
         let handler = ShareableMessageHandler(Rc::new(OrderEmulatorOnEventHandler {
             id: Ustr::from(&UUID4::new().to_string()),
-            emulator,
+            emulator: emulator.clone(),
         }));
 
+        emulator.borrow_mut().set_on_event_handler(handler.clone());
         msgbus
             .borrow_mut()
             .register("OrderEmulator.on_event", handler);
     }
 
+    fn initialize_data_handlers(emulator: Rc<RefCell<OrderEmulator>>) {
+        let quote_handler = ShareableMessageHandler(Rc::new(OrderEmulatorOnQuoteHandler {
+            id: Ustr::from(&UUID4::new().to_string()),
+            emulator: emulator.clone(),
+        }));
+        let trade_handler = ShareableMessageHandler(Rc::new(OrderEmulatorOnTradeHandler {
+            id: Ustr::from(&UUID4::new().to_string()),
+            emulator: emulator.clone(),
+        }));
+        let bar_handler = ShareableMessageHandler(Rc::new(OrderEmulatorOnBarHandler {
+            id: Ustr::from(&UUID4::new().to_string()),
+            emulator: emulator.clone(),
+        }));
+
+        let mut emulator = emulator.borrow_mut();
+        emulator.set_on_quote_handler(quote_handler);
+        emulator.set_on_trade_handler(trade_handler);
+        emulator.set_on_bar_handler(bar_handler);
+    }
+
     #[must_use]
     pub fn get_emulator(&self) -> Ref<OrderEmulator> {
         self.emulator.borrow()
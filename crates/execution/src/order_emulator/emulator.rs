@@ -20,21 +20,25 @@ use std::{
 };
 
 use anyhow::Result;
+use indexmap::IndexMap;
 use nautilus_common::{
     cache::Cache,
     clock::Clock,
     logging::{CMD, EVT, RECV},
+    messages::data::{Action, SubscriptionCommand},
     msgbus::{handler::ShareableMessageHandler, MessageBus},
+    timer::{TimeEvent, TimeEventCallback},
 };
 use nautilus_core::uuid::UUID4;
 use nautilus_model::{
-    data::{OrderBookDeltas, QuoteTick, TradeTick},
-    enums::{ContingencyType, OrderSide, OrderStatus, OrderType, TriggerType},
+    data::{Bar, BarType, DataType, OrderBookDeltas, QuoteTick, TradeTick},
+    enums::{ContingencyType, OrderSide, OrderStatus, OrderType, PriceType, TriggerType},
     events::{OrderCanceled, OrderEmulated, OrderEventAny, OrderReleased, OrderUpdated},
-    identifiers::{ClientOrderId, InstrumentId, PositionId, StrategyId},
+    identifiers::{ClientId, ClientOrderId, InstrumentId, PositionId, StrategyId},
     orders::{LimitOrder, MarketOrder, Order, OrderAny, PassiveOrderAny},
     types::{Price, Quantity},
 };
+use ustr::Ustr;
 
 use crate::{
     matching_core::OrderMatchingCore,
@@ -47,6 +51,11 @@ use crate::{
     trailing::trailing_stop_calculate,
 };
 
+const SUBSCRIBE_ALERT_NAME: &str = "OrderEmulator.subscribe";
+
+/// Topic subscriptions waiting for the message bus to be released.
+type PendingSubscriptions = Rc<RefCell<Vec<(Ustr, ShareableMessageHandler)>>>;
+
 pub struct OrderEmulator {
     clock: Rc<RefCell<dyn Clock>>,
     cache: Rc<RefCell<Cache>>,
@@ -55,9 +64,15 @@ pub struct OrderEmulator {
     matching_cores: HashMap<InstrumentId, OrderMatchingCore>,
     subscribed_quotes: HashSet<InstrumentId>,
     subscribed_trades: HashSet<InstrumentId>,
+    subscribed_bars: HashSet<BarType>,
     subscribed_strategies: HashSet<StrategyId>,
     monitored_positions: HashSet<PositionId>,
+    trigger_bar_types: HashMap<InstrumentId, BarType>,
     on_event_handler: Option<ShareableMessageHandler>,
+    on_quote_handler: Option<ShareableMessageHandler>,
+    on_trade_handler: Option<ShareableMessageHandler>,
+    on_bar_handler: Option<ShareableMessageHandler>,
+    pending_subscriptions: PendingSubscriptions,
 }
 
 impl OrderEmulator {
@@ -88,9 +103,15 @@ impl OrderEmulator {
             matching_cores: HashMap::new(),
             subscribed_quotes: HashSet::new(),
             subscribed_trades: HashSet::new(),
+            subscribed_bars: HashSet::new(),
             subscribed_strategies: HashSet::new(),
             monitored_positions: HashSet::new(),
+            trigger_bar_types: HashMap::new(),
             on_event_handler: None,
+            on_quote_handler: None,
+            on_trade_handler: None,
+            on_bar_handler: None,
+            pending_subscriptions: Rc::new(RefCell::new(Vec::new())),
         }
     }
 
@@ -98,6 +119,27 @@ impl OrderEmulator {
         self.on_event_handler = Some(handler);
     }
 
+    pub fn set_on_quote_handler(&mut self, handler: ShareableMessageHandler) {
+        self.on_quote_handler = Some(handler);
+    }
+
+    pub fn set_on_trade_handler(&mut self, handler: ShareableMessageHandler) {
+        self.on_trade_handler = Some(handler);
+    }
+
+    pub fn set_on_bar_handler(&mut self, handler: ShareableMessageHandler) {
+        self.on_bar_handler = Some(handler);
+    }
+
+    /// Sets the `bar_type` to trigger `LastPrice` emulated orders for its instrument with,
+    /// in place of trade ticks.
+    ///
+    /// This supports emulation where only bar data is available for the trigger instrument.
+    pub fn set_trigger_bar_type(&mut self, bar_type: BarType) {
+        self.trigger_bar_types
+            .insert(bar_type.instrument_id(), bar_type);
+    }
+
     pub fn set_submit_order_handler(&mut self, handler: SubmitOrderHandlerAny) {
         self.manager.set_submit_order_handler(handler);
     }
@@ -124,6 +166,13 @@ impl OrderEmulator {
         trades
     }
 
+    #[must_use]
+    pub fn subscribed_bars(&self) -> Vec<BarType> {
+        let mut bars: Vec<_> = self.subscribed_bars.iter().copied().collect();
+        bars.sort();
+        bars
+    }
+
     #[must_use]
     pub fn get_submit_order_commands(&self) -> HashMap<ClientOrderId, SubmitOrder> {
         self.manager.get_submit_order_commands()
@@ -235,6 +284,7 @@ impl OrderEmulator {
     pub fn on_reset(&mut self) {
         self.manager.reset();
         self.matching_cores.clear();
+        self.pending_subscriptions.borrow_mut().clear();
     }
 
     pub const fn on_dispose(&self) {}
@@ -275,10 +325,11 @@ impl OrderEmulator {
             "command.order.emulation_trigger must not be TriggerType::NoTrigger"
         );
         assert!(
-            self.manager
+            !self
+                .manager
                 .get_submit_order_commands()
                 .contains_key(&order.client_order_id()),
-            "command.order.client_order_id must be in submit_order_commands"
+            "command.order.client_order_id must not be in submit_order_commands"
         );
 
         if !matches!(
@@ -294,6 +345,7 @@ impl OrderEmulator {
         }
 
         self.check_monitoring(command.strategy_id, command.position_id);
+        let client_id = command.client_id;
 
         // Get or create matching core
         let trigger_instrument_id = order
@@ -363,7 +415,7 @@ impl OrderEmulator {
         self.manager.cache_submit_order_command(command);
 
         // Check if immediately marketable
-        matching_core.match_order(&PassiveOrderAny::from(order.clone()), true);
+        self.match_order(&trigger_instrument_id, &mut order);
 
         // Handle data subscriptions
         match emulation_trigger.unwrap() {
@@ -373,15 +425,19 @@ impl OrderEmulator {
                         // TODO: Impl Actor Trait
                         // self.subscribe_order_book_deltas(&trigger_instrument_id);
                     }
-                    // TODO: Impl Actor Trait
-                    // self.subscribe_quote_ticks(&trigger_instrument_id)?;
+                    self.subscribe_quotes(trigger_instrument_id, client_id);
                     self.subscribed_quotes.insert(trigger_instrument_id);
                 }
             }
             TriggerType::LastPrice => {
-                if !self.subscribed_trades.contains(&trigger_instrument_id) {
-                    // TODO: Impl Actor Trait
-                    // self.subscribe_trade_ticks(&trigger_instrument_id)?;
+                if let Some(bar_type) = self.trigger_bar_types.get(&trigger_instrument_id) {
+                    let bar_type = *bar_type;
+                    if !self.subscribed_bars.contains(&bar_type) {
+                        self.subscribe_bars(bar_type, client_id);
+                        self.subscribed_bars.insert(bar_type);
+                    }
+                } else if !self.subscribed_trades.contains(&trigger_instrument_id) {
+                    self.subscribe_trades(trigger_instrument_id, client_id);
                     self.subscribed_trades.insert(trigger_instrument_id);
                 }
             }
@@ -552,26 +608,26 @@ impl OrderEmulator {
     }
 
     fn handle_cancel_all_orders(&mut self, command: CancelAllOrders) {
-        let matching_core = match self.matching_cores.get(&command.instrument_id) {
-            Some(core) => core,
-            None => return, // No orders to cancel
-        };
-
-        let orders_to_cancel = match command.order_side {
-            OrderSide::NoOrderSide => {
-                // Get both bid and ask orders
-                let mut all_orders = Vec::new();
-                all_orders.extend(matching_core.get_orders_bid().iter().cloned());
-                all_orders.extend(matching_core.get_orders_ask().iter().cloned());
-                all_orders
-            }
-            OrderSide::Buy => matching_core.get_orders_bid().to_vec(),
-            OrderSide::Sell => matching_core.get_orders_ask().to_vec(),
-        };
+        let orders_to_cancel: Vec<OrderAny> = self
+            .matching_cores
+            .iter()
+            .filter(|(instrument_id, _)| {
+                command
+                    .instrument_filter()
+                    .is_none_or(|id| id == *instrument_id)
+            })
+            .flat_map(|(_, core)| {
+                core.get_orders_bid()
+                    .iter()
+                    .chain(core.get_orders_ask())
+                    .cloned()
+            })
+            .map(OrderAny::from)
+            .filter(|order| command.matches(order))
+            .collect();
 
-        // Process all orders in a single iteration
         for order in orders_to_cancel {
-            self.manager.cancel_order(&OrderAny::from(order));
+            self.manager.cancel_order(&order);
         }
     }
 
@@ -669,13 +725,7 @@ impl OrderEmulator {
         log::debug!("Processing TradeTick:{}", tick);
 
         let instrument_id = &tick.instrument_id;
-        if let Some(matching_core) = self.matching_cores.get_mut(instrument_id) {
-            matching_core.set_last_raw(tick.price);
-            if !self.subscribed_quotes.contains(instrument_id) {
-                matching_core.set_bid_raw(tick.price);
-                matching_core.set_ask_raw(tick.price);
-            }
-
+        if self.set_last_price(instrument_id, tick.price) {
             self.iterate_orders(instrument_id);
         } else {
             log::error!(
@@ -685,10 +735,40 @@ impl OrderEmulator {
         }
     }
 
-    fn iterate_orders(&mut self, instrument_id: &InstrumentId) {
-        let orders = if let Some(matching_core) = self.matching_cores.get_mut(instrument_id) {
-            matching_core.iterate();
+    pub fn on_bar(&mut self, bar: Bar) {
+        log::debug!("Processing Bar:{}", bar);
 
+        let instrument_id = bar.instrument_id();
+        if !self.matching_cores.contains_key(&instrument_id) {
+            log::error!(
+                "Cannot handle `Bar`: no matching core for instrument {}",
+                instrument_id
+            );
+            return;
+        }
+
+        // Iterate the bar prices in the order they are assumed to have traded
+        for price in [bar.open, bar.high, bar.low, bar.close] {
+            self.set_last_price(&instrument_id, price);
+            self.iterate_orders(&instrument_id);
+        }
+    }
+
+    fn set_last_price(&mut self, instrument_id: &InstrumentId, price: Price) -> bool {
+        let Some(matching_core) = self.matching_cores.get_mut(instrument_id) else {
+            return false;
+        };
+
+        matching_core.set_last_raw(price);
+        if !self.subscribed_quotes.contains(instrument_id) {
+            matching_core.set_bid_raw(price);
+            matching_core.set_ask_raw(price);
+        }
+        true
+    }
+
+    fn iterate_orders(&mut self, instrument_id: &InstrumentId) {
+        let orders = if let Some(matching_core) = self.matching_cores.get(instrument_id) {
             matching_core.get_orders()
         } else {
             log::error!(
@@ -699,17 +779,52 @@ impl OrderEmulator {
         };
 
         for order in orders {
+            // The cache holds the latest state of the order, including any trailing updates
+            let cached_order = self.cache.borrow().order(&order.client_order_id()).cloned();
+            let mut order: OrderAny = cached_order.unwrap_or_else(|| order.into());
+
             if order.is_closed() {
                 continue;
             }
 
-            let mut order: OrderAny = order.clone().into();
             if matches!(
                 order.order_type(),
                 OrderType::TrailingStopMarket | OrderType::TrailingStopLimit
             ) {
                 self.update_trailing_stop_order(&mut order);
             }
+
+            self.match_order(instrument_id, &mut order);
+        }
+    }
+
+    /// Releases the `order` where triggered by the prices of the matching core for the
+    /// `trigger_instrument_id`.
+    fn match_order(&mut self, trigger_instrument_id: &InstrumentId, order: &mut OrderAny) {
+        let Some(matching_core) = self.matching_cores.get(trigger_instrument_id) else {
+            return;
+        };
+
+        let side = order.order_side_specified();
+        let is_matched = match order.order_type() {
+            OrderType::Limit => order
+                .price()
+                .is_some_and(|price| matching_core.is_limit_matched(side, price)),
+            OrderType::MarketIfTouched | OrderType::LimitIfTouched => order
+                .trigger_price()
+                .is_some_and(|price| matching_core.is_touch_triggered(side, price)),
+            _ => order
+                .trigger_price()
+                .is_some_and(|price| matching_core.is_stop_matched(side, price)),
+        };
+
+        if !is_matched {
+            return;
+        }
+
+        match order.order_type() {
+            OrderType::Limit => self.fill_limit_order(order),
+            _ => self.trigger_stop_order(order),
         }
     }
 
@@ -724,8 +839,11 @@ impl OrderEmulator {
             .unwrap_or(order.instrument_id());
 
         if let Some(matching_core) = self.matching_cores.get_mut(&trigger_instrument_id) {
-            if let Err(e) = matching_core.delete_order(&PassiveOrderAny::from(order.clone())) {
-                log::error!("Cannot delete order: {:?}", e);
+            // Orders released on submission were never held by the matching core
+            if matching_core.order_exists(order.client_order_id()) {
+                if let Err(e) = matching_core.delete_order(&PassiveOrderAny::from(order.clone())) {
+                    log::error!("Cannot delete order: {:?}", e);
+                }
             }
         }
 
@@ -754,17 +872,9 @@ impl OrderEmulator {
     fn check_monitoring(&mut self, strategy_id: StrategyId, position_id: Option<PositionId>) {
         if !self.subscribed_strategies.contains(&strategy_id) {
             // Subscribe to all strategy events
-            if let Some(handler) = &self.on_event_handler {
-                self.msgbus.borrow_mut().subscribe(
-                    format!("events.order.{strategy_id}"),
-                    handler.clone(),
-                    None,
-                );
-                self.msgbus.borrow_mut().subscribe(
-                    format!("events.position.{strategy_id}"),
-                    handler.clone(),
-                    None,
-                );
+            if let Some(handler) = self.on_event_handler.clone() {
+                self.subscribe_topic(format!("events.order.{strategy_id}"), handler.clone());
+                self.subscribe_topic(format!("events.position.{strategy_id}"), handler);
                 self.subscribed_strategies.insert(strategy_id);
                 log::info!(
                     "Subscribed to strategy {} order and position events",
@@ -780,12 +890,116 @@ impl OrderEmulator {
         }
     }
 
+    fn subscribe_quotes(&self, instrument_id: InstrumentId, client_id: ClientId) {
+        if let Some(handler) = self.on_quote_handler.clone() {
+            self.subscribe_topic(
+                format!(
+                    "data.quotes.{}.{}",
+                    instrument_id.venue, instrument_id.symbol
+                ),
+                handler,
+            );
+        }
+
+        let metadata = IndexMap::from([("instrument_id".to_string(), instrument_id.to_string())]);
+        self.send_subscribe_command(
+            DataType::new(stringify!(QuoteTick), Some(metadata)),
+            instrument_id,
+            client_id,
+        );
+    }
+
+    fn subscribe_trades(&self, instrument_id: InstrumentId, client_id: ClientId) {
+        if let Some(handler) = self.on_trade_handler.clone() {
+            self.subscribe_topic(
+                format!(
+                    "data.trades.{}.{}",
+                    instrument_id.venue, instrument_id.symbol
+                ),
+                handler,
+            );
+        }
+
+        let metadata = IndexMap::from([("instrument_id".to_string(), instrument_id.to_string())]);
+        self.send_subscribe_command(
+            DataType::new(stringify!(TradeTick), Some(metadata)),
+            instrument_id,
+            client_id,
+        );
+    }
+
+    fn subscribe_bars(&self, bar_type: BarType, client_id: ClientId) {
+        if let Some(handler) = self.on_bar_handler.clone() {
+            self.subscribe_topic(format!("data.bars.{bar_type}"), handler);
+        }
+
+        let metadata = IndexMap::from([("bar_type".to_string(), bar_type.to_string())]);
+        self.send_subscribe_command(
+            DataType::new(stringify!(Bar), Some(metadata)),
+            bar_type.instrument_id(),
+            client_id,
+        );
+    }
+
+    fn send_subscribe_command(
+        &self,
+        data_type: DataType,
+        instrument_id: InstrumentId,
+        client_id: ClientId,
+    ) {
+        let command = SubscriptionCommand::new(
+            client_id,
+            instrument_id.venue,
+            data_type,
+            Action::Subscribe,
+            UUID4::new(),
+            self.clock.borrow().timestamp_ns(),
+            None,
+        );
+
+        let msgbus = self.msgbus.borrow();
+        let endpoint = msgbus.switchboard.data_engine_execute;
+        msgbus.send(&endpoint, &command);
+    }
+
+    /// Subscribes the `handler` to the `topic`, deferring the subscription to a time alert
+    /// where the message bus is already borrowed, such as when handling a sent command.
+    fn subscribe_topic(&self, topic: String, handler: ShareableMessageHandler) {
+        if let Ok(mut msgbus) = self.msgbus.try_borrow_mut() {
+            msgbus.subscribe(topic, handler, None);
+            return;
+        }
+
+        let is_scheduled = !self.pending_subscriptions.borrow().is_empty();
+        self.pending_subscriptions
+            .borrow_mut()
+            .push((Ustr::from(&topic), handler));
+        if is_scheduled {
+            return;
+        }
+
+        let msgbus = self.msgbus.clone();
+        let pending_subscriptions = self.pending_subscriptions.clone();
+        let callback = TimeEventCallback::Rust(Rc::new(move |_event: TimeEvent| {
+            let mut msgbus = msgbus.borrow_mut();
+            for (topic, handler) in pending_subscriptions.borrow_mut().drain(..) {
+                msgbus.subscribe(topic, handler, None);
+            }
+        }));
+
+        let mut clock = self.clock.borrow_mut();
+        let ts_now = clock.timestamp_ns();
+        if let Err(e) = clock.set_time_alert_ns(SUBSCRIBE_ALERT_NAME, ts_now, Some(callback)) {
+            log::error!("Cannot schedule pending subscriptions: {e}");
+        }
+    }
+
     pub fn trigger_stop_order(&mut self, order: &mut OrderAny) {
         match order.order_type() {
             OrderType::StopLimit | OrderType::LimitIfTouched | OrderType::TrailingStopLimit => {
                 self.fill_limit_order(order);
             }
-            OrderType::Market | OrderType::MarketIfTouched | OrderType::TrailingStopMarket => {
+            OrderType::StopMarket | OrderType::MarketIfTouched | OrderType::TrailingStopMarket => {
                 self.fill_market_order(order);
             }
             _ => panic!("invalid `OrderType`, was {}", order.order_type()),
@@ -812,8 +1026,11 @@ impl OrderEmulator {
             .unwrap_or(order.instrument_id());
 
         if let Some(matching_core) = self.matching_cores.get_mut(&trigger_instrument_id) {
-            if let Err(e) = matching_core.delete_order(&PassiveOrderAny::from(order.clone())) {
-                log::error!("Error deleting order: {:?}", e);
+            // Orders released on submission were never held by the matching core
+            if matching_core.order_exists(order.client_order_id()) {
+                if let Err(e) = matching_core.delete_order(&PassiveOrderAny::from(order.clone())) {
+                    log::error!("Error deleting order: {:?}", e);
+                }
             }
 
             let emulation_trigger = TriggerType::NoTrigger;
@@ -827,6 +1044,8 @@ impl OrderEmulator {
                 order.order_side(),
                 order.quantity(),
                 order.price().unwrap(),
+                None,
+                None,
                 order.time_in_force(),
                 order.expire_time(),
                 order.is_post_only(),
@@ -952,8 +1171,10 @@ impl OrderEmulator {
             .unwrap_or(order.instrument_id());
 
         if let Some(matching_core) = self.matching_cores.get_mut(&trigger_instrument_id) {
-            if let Err(e) = matching_core.delete_order(&PassiveOrderAny::from(order.clone())) {
-                log::error!("Cannot delete order: {:?}", e);
+            if matching_core.order_exists(order.client_order_id()) {
+                if let Err(e) = matching_core.delete_order(&PassiveOrderAny::from(order.clone())) {
+                    log::error!("Cannot delete order: {:?}", e);
+                }
             }
 
             order.set_emulation_trigger(Some(TriggerType::NoTrigger));
@@ -1096,9 +1317,13 @@ impl OrderEmulator {
             if last.is_none() && trade_tick.is_some() {
                 last = Some(trade_tick.unwrap().price);
             }
+            let mark = self
+                .cache
+                .borrow()
+                .price(&matching_core.instrument_id, PriceType::Mark);
 
             let (new_trigger_price, new_price) = if let Ok((new_trigger_price, new_price)) =
-                trailing_stop_calculate(matching_core.price_increment, order, bid, ask, last)
+                trailing_stop_calculate(matching_core.price_increment, order, bid, ask, last, mark)
             {
                 (new_trigger_price, new_price)
             } else {
@@ -1111,6 +1336,10 @@ impl OrderEmulator {
                 _ => (new_trigger_price, new_price),
             };
 
+            if order.is_activated() == Some(false) {
+                order.set_activated();
+            }
+
             // Generate event
             let ts_now = self.clock.borrow().timestamp_ns();
             let event = OrderUpdated::new(
@@ -16,7 +16,11 @@
 use std::{any::Any, cell::RefCell, rc::Rc};
 
 use nautilus_common::{messages::data::DataResponse, msgbus::handler::MessageHandler};
-use nautilus_model::{data::Data, events::OrderEventAny};
+use nautilus_model::{
+    data::{Bar, Data, QuoteTick, TradeTick},
+    events::OrderEventAny,
+    orders::OrderAny,
+};
 use ustr::Ustr;
 
 use crate::{messages::TradingCommand, order_emulator::emulator::OrderEmulator};
@@ -32,12 +36,11 @@ impl MessageHandler for OrderEmulatorExecuteHandler {
     }
 
     fn handle(&self, msg: &dyn Any) {
-        self.emulator.borrow_mut().execute(
-            msg.downcast_ref::<&TradingCommand>()
-                .unwrap()
-                .to_owned()
-                .clone(),
-        );
+        if let Some(command) = msg.downcast_ref::<TradingCommand>() {
+            self.emulator.borrow_mut().execute(command.clone());
+        } else {
+            log::error!("Invalid message type received: {msg:?}");
+        }
     }
     fn handle_response(&self, _resp: DataResponse) {}
     fn handle_data(&self, _data: Data) {}
@@ -57,12 +60,84 @@ impl MessageHandler for OrderEmulatorOnEventHandler {
     }
 
     fn handle(&self, msg: &dyn Any) {
-        self.emulator.borrow_mut().on_event(
-            msg.downcast_ref::<&OrderEventAny>()
-                .unwrap()
-                .to_owned()
-                .clone(),
-        );
+        // Events published by the emulator itself are received while it is borrowed,
+        // and require no further handling
+        let Ok(mut emulator) = self.emulator.try_borrow_mut() else {
+            return;
+        };
+
+        // The execution engine publishes orders with the event applied
+        if let Some(event) = msg.downcast_ref::<OrderEventAny>() {
+            emulator.on_event(event.clone());
+        } else if let Some(order) = msg.downcast_ref::<OrderAny>() {
+            emulator.on_event(order.last_event().clone());
+        }
+    }
+    fn handle_response(&self, _resp: DataResponse) {}
+    fn handle_data(&self, _data: Data) {}
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+pub struct OrderEmulatorOnQuoteHandler {
+    pub id: Ustr,
+    pub emulator: Rc<RefCell<OrderEmulator>>,
+}
+
+impl MessageHandler for OrderEmulatorOnQuoteHandler {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, msg: &dyn Any) {
+        if let Some(quote) = msg.downcast_ref::<QuoteTick>() {
+            self.emulator.borrow_mut().on_quote_tick(*quote);
+        }
+    }
+    fn handle_response(&self, _resp: DataResponse) {}
+    fn handle_data(&self, _data: Data) {}
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+pub struct OrderEmulatorOnTradeHandler {
+    pub id: Ustr,
+    pub emulator: Rc<RefCell<OrderEmulator>>,
+}
+
+impl MessageHandler for OrderEmulatorOnTradeHandler {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, msg: &dyn Any) {
+        if let Some(trade) = msg.downcast_ref::<TradeTick>() {
+            self.emulator.borrow_mut().on_trade_tick(*trade);
+        }
+    }
+    fn handle_response(&self, _resp: DataResponse) {}
+    fn handle_data(&self, _data: Data) {}
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+pub struct OrderEmulatorOnBarHandler {
+    pub id: Ustr,
+    pub emulator: Rc<RefCell<OrderEmulator>>,
+}
+
+impl MessageHandler for OrderEmulatorOnBarHandler {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, msg: &dyn Any) {
+        if let Some(bar) = msg.downcast_ref::<Bar>() {
+            self.emulator.borrow_mut().on_bar(*bar);
+        }
     }
     fn handle_response(&self, _resp: DataResponse) {}
     fn handle_data(&self, _data: Data) {}
@@ -13,9 +13,415 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::{cell::RefCell, rc::Rc, str::FromStr};
+
+use nautilus_common::{
+    cache::Cache,
+    clock::TestClock,
+    messages::data::SubscriptionCommand,
+    msgbus::{
+        handler::ShareableMessageHandler,
+        stubs::{get_message_saving_handler, get_saved_messages},
+        MessageBus,
+    },
+};
+use nautilus_core::{UnixNanos, UUID4};
+use nautilus_model::{
+    data::{Bar, BarType, QuoteTick, TradeTick},
+    enums::{AggressorSide, OrderSide, OrderStatus, OrderType, TrailingOffsetType, TriggerType},
+    identifiers::{ClientId, ClientOrderId, InstrumentId, TradeId, VenueOrderId},
+    instruments::{stubs::audusd_sim, InstrumentAny},
+    orders::{OrderAny, OrderTestBuilder},
+    types::{Price, Quantity},
+};
 use rstest::rstest;
+use rust_decimal_macros::dec;
+use ustr::Ustr;
+
+use crate::{
+    messages::{SubmitOrder, TradingCommand},
+    order_emulator::adapter::OrderEmulatorAdapter,
+};
+
+struct TestHarness {
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    adapter: OrderEmulatorAdapter,
+    exec_handler: ShareableMessageHandler,
+    data_handler: ShareableMessageHandler,
+    instrument_id: InstrumentId,
+}
+
+impl TestHarness {
+    fn new() -> Self {
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim());
+        let instrument_id = instrument.id();
+        cache.borrow_mut().add_instrument(instrument).unwrap();
+
+        let exec_handler = get_message_saving_handler::<TradingCommand>(None);
+        let data_handler = get_message_saving_handler::<SubscriptionCommand>(None);
+        msgbus
+            .borrow_mut()
+            .register("ExecEngine.execute", exec_handler.clone());
+        msgbus
+            .borrow_mut()
+            .register("DataEngine.execute", data_handler.clone());
+
+        let adapter = OrderEmulatorAdapter::new(clock.clone(), cache.clone(), msgbus.clone());
+
+        Self {
+            clock,
+            cache,
+            msgbus,
+            adapter,
+            exec_handler,
+            data_handler,
+            instrument_id,
+        }
+    }
+
+    fn submit(&self, order: &OrderAny) {
+        self.cache
+            .borrow_mut()
+            .add_order(order.clone(), None, None, false)
+            .unwrap();
+
+        let command = SubmitOrder::new(
+            order.trader_id(),
+            ClientId::from("SIM"),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            VenueOrderId::from("1"),
+            order.clone(),
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        self.adapter
+            .get_emulator_mut()
+            .execute(TradingCommand::SubmitOrder(command));
+    }
+
+    fn publish_quote(&self, bid: &str, ask: &str) {
+        let quote = QuoteTick::new(
+            self.instrument_id,
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(100_000),
+            Quantity::from(100_000),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+        self.msgbus
+            .borrow()
+            .publish(&Ustr::from("data.quotes.SIM.AUD/USD"), &quote);
+    }
+
+    fn publish_trade(&self, price: &str) {
+        let trade = TradeTick::new(
+            self.instrument_id,
+            Price::from(price),
+            Quantity::from(100_000),
+            AggressorSide::Buyer,
+            TradeId::from("1"),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+        self.msgbus
+            .borrow()
+            .publish(&Ustr::from("data.trades.SIM.AUD/USD"), &trade);
+    }
+
+    fn released_orders(&self) -> Vec<OrderAny> {
+        get_saved_messages::<TradingCommand>(self.exec_handler.clone())
+            .into_iter()
+            .filter_map(|command| match command {
+                TradingCommand::SubmitOrder(command) => Some(command.order),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn subscribed_data_types(&self) -> Vec<String> {
+        get_saved_messages::<SubscriptionCommand>(self.data_handler.clone())
+            .into_iter()
+            .map(|command| command.data_type.type_name().to_string())
+            .collect()
+    }
+
+    fn order_status(&self, client_order_id: &ClientOrderId) -> OrderStatus {
+        self.cache.borrow().order(client_order_id).unwrap().status()
+    }
+}
+
+fn stop_order(
+    order_type: OrderType,
+    side: OrderSide,
+    trigger_price: &str,
+    emulation_trigger: TriggerType,
+) -> OrderAny {
+    let mut builder = OrderTestBuilder::new(order_type);
+    builder
+        .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+        .side(side)
+        .quantity(Quantity::from(100_000))
+        .trigger_price(Price::from(trigger_price))
+        .emulation_trigger(emulation_trigger);
+    if order_type == OrderType::StopLimit {
+        builder.price(Price::from(trigger_price));
+    }
+    builder.build()
+}
+
+#[rstest]
+fn test_submit_bid_ask_emulated_order_subscribes_to_quotes() {
+    let harness = TestHarness::new();
+    let order = stop_order(
+        OrderType::StopMarket,
+        OrderSide::Buy,
+        "1.00010",
+        TriggerType::BidAsk,
+    );
+
+    harness.submit(&order);
+
+    let emulator = harness.adapter.get_emulator();
+    assert_eq!(emulator.subscribed_quotes(), vec![harness.instrument_id]);
+    assert!(emulator.subscribed_trades().is_empty());
+    assert!(harness
+        .msgbus
+        .borrow()
+        .has_subscribers("data.quotes.SIM.AUD/USD"));
+    assert_eq!(harness.subscribed_data_types(), vec!["QuoteTick"]);
+    assert_eq!(
+        harness.order_status(&order.client_order_id()),
+        OrderStatus::Emulated
+    );
+}
+
+#[rstest]
+fn test_submit_last_price_emulated_order_subscribes_to_trades() {
+    let harness = TestHarness::new();
+    let order = stop_order(
+        OrderType::StopMarket,
+        OrderSide::Sell,
+        "0.99990",
+        TriggerType::LastPrice,
+    );
+
+    harness.submit(&order);
+
+    let emulator = harness.adapter.get_emulator();
+    assert_eq!(emulator.subscribed_trades(), vec![harness.instrument_id]);
+    assert!(emulator.subscribed_quotes().is_empty());
+    assert_eq!(harness.subscribed_data_types(), vec!["TradeTick"]);
+}
+
+#[rstest]
+fn test_submit_while_msgbus_borrowed_defers_subscription() {
+    let harness = TestHarness::new();
+    let order = stop_order(
+        OrderType::StopMarket,
+        OrderSide::Buy,
+        "1.00010",
+        TriggerType::BidAsk,
+    );
+
+    {
+        // Commands sent to the emulator are handled with the message bus borrowed
+        let _msgbus = harness.msgbus.borrow();
+        harness.submit(&order);
+    }
+    assert!(!harness
+        .msgbus
+        .borrow()
+        .has_subscribers("data.quotes.SIM.AUD/USD"));
+
+    let events = harness
+        .clock
+        .borrow_mut()
+        .advance_time(UnixNanos::from(1), true);
+    let handlers = harness.clock.borrow().match_handlers(events);
+    for handler in handlers {
+        handler.run();
+    }
+
+    assert!(harness
+        .msgbus
+        .borrow()
+        .has_subscribers("data.quotes.SIM.AUD/USD"));
+}
+
+#[rstest]
+fn test_stop_market_released_as_market_order_when_triggered_by_quote() {
+    let harness = TestHarness::new();
+    let order = stop_order(
+        OrderType::StopMarket,
+        OrderSide::Buy,
+        "1.00010",
+        TriggerType::BidAsk,
+    );
+    harness.submit(&order);
+
+    harness.publish_quote("1.00000", "1.00002");
+    assert!(harness.released_orders().is_empty());
+
+    harness.publish_quote("1.00008", "1.00010");
+
+    let released = harness.released_orders();
+    assert_eq!(released.len(), 1);
+    assert_eq!(released[0].order_type(), OrderType::Market);
+    assert_eq!(released[0].client_order_id(), order.client_order_id());
+    assert_eq!(
+        harness.order_status(&order.client_order_id()),
+        OrderStatus::Released
+    );
+    assert!(harness
+        .adapter
+        .get_emulator()
+        .get_submit_order_commands()
+        .is_empty());
+}
+
+#[rstest]
+fn test_stop_limit_released_as_limit_order_when_triggered_by_trade() {
+    let harness = TestHarness::new();
+    let order = stop_order(
+        OrderType::StopLimit,
+        OrderSide::Sell,
+        "0.99990",
+        TriggerType::LastPrice,
+    );
+    harness.submit(&order);
+
+    harness.publish_trade("1.00000");
+    assert!(harness.released_orders().is_empty());
+
+    harness.publish_trade("0.99985");
+
+    let released = harness.released_orders();
+    assert_eq!(released.len(), 1);
+    assert_eq!(released[0].order_type(), OrderType::Limit);
+    assert_eq!(released[0].price(), Some(Price::from("0.99990")));
+}
 
 #[rstest]
-fn test_init() {
-    assert_eq!(1, 1);
+fn test_trailing_stop_market_trails_quotes_then_released() {
+    let harness = TestHarness::new();
+    let order = OrderTestBuilder::new(OrderType::TrailingStopMarket)
+        .instrument_id(harness.instrument_id)
+        .side(OrderSide::Sell)
+        .quantity(Quantity::from(100_000))
+        .trigger_price(Price::from("0.99990"))
+        .trigger_type(TriggerType::BidAsk)
+        .trailing_offset(dec!(0.00010))
+        .trailing_offset_type(TrailingOffsetType::Price)
+        .emulation_trigger(TriggerType::BidAsk)
+        .build();
+    harness.submit(&order);
+
+    harness.publish_quote("1.00020", "1.00022");
+
+    let trigger_price = harness
+        .cache
+        .borrow()
+        .order(&order.client_order_id())
+        .unwrap()
+        .trigger_price();
+    assert_eq!(trigger_price, Some(Price::from("1.00010")));
+    assert!(harness.released_orders().is_empty());
+
+    harness.publish_quote("1.00010", "1.00012");
+
+    let released = harness.released_orders();
+    assert_eq!(released.len(), 1);
+    assert_eq!(released[0].order_type(), OrderType::Market);
+}
+
+#[rstest]
+fn test_last_price_emulated_order_triggered_by_configured_bars() {
+    let harness = TestHarness::new();
+    let bar_type = BarType::from_str("AUD/USD.SIM-1-MINUTE-LAST-EXTERNAL").unwrap();
+    harness
+        .adapter
+        .get_emulator_mut()
+        .set_trigger_bar_type(bar_type);
+
+    let order = stop_order(
+        OrderType::StopMarket,
+        OrderSide::Sell,
+        "0.99990",
+        TriggerType::LastPrice,
+    );
+    harness.submit(&order);
+
+    assert_eq!(
+        harness.adapter.get_emulator().subscribed_bars(),
+        vec![bar_type]
+    );
+    assert!(harness
+        .adapter
+        .get_emulator()
+        .subscribed_trades()
+        .is_empty());
+    assert_eq!(harness.subscribed_data_types(), vec!["Bar"]);
+
+    let bar = Bar::new(
+        bar_type,
+        Price::from("1.00000"),
+        Price::from("1.00010"),
+        Price::from("0.99980"),
+        Price::from("1.00000"),
+        Quantity::from(100_000),
+        UnixNanos::default(),
+        UnixNanos::default(),
+    );
+    harness
+        .msgbus
+        .borrow()
+        .publish(&Ustr::from(&format!("data.bars.{bar_type}")), &bar);
+
+    let released = harness.released_orders();
+    assert_eq!(released.len(), 1);
+    assert_eq!(released[0].order_type(), OrderType::Market);
+}
+
+#[rstest]
+fn test_immediately_triggered_order_released_on_submit() {
+    let harness = TestHarness::new();
+    let first = stop_order(
+        OrderType::StopMarket,
+        OrderSide::Buy,
+        "1.00020",
+        TriggerType::BidAsk,
+    );
+    harness.submit(&first);
+    harness.publish_quote("1.00008", "1.00010");
+
+    let order = OrderTestBuilder::new(OrderType::StopMarket)
+        .instrument_id(harness.instrument_id)
+        .client_order_id(ClientOrderId::from("O-2"))
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from(100_000))
+        .trigger_price(Price::from("1.00005"))
+        .emulation_trigger(TriggerType::BidAsk)
+        .build();
+    harness.submit(&order);
+
+    let released = harness.released_orders();
+    assert_eq!(released.len(), 1);
+    assert_eq!(released[0].client_order_id(), order.client_order_id());
+    assert_eq!(
+        harness.order_status(&order.client_order_id()),
+        OrderStatus::Released
+    );
 }
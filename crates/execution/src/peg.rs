@@ -0,0 +1,180 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::{
+    enums::{OrderSideSpecified, OrderType, PegPriceType},
+    orders::OrderAny,
+    types::Price,
+};
+use rust_decimal::{prelude::*, Decimal};
+
+/// Calculates the price of a pegged limit `order` from the current best `bid` and `ask`.
+///
+/// A positive peg offset prices the order more passively than its reference price, i.e. lower
+/// for buy orders and higher for sell orders. Prices between ticks are rounded passively.
+///
+/// Returns the new price if it differs from the current order price, or `None` if the price is
+/// unchanged or the reference price is not yet available.
+///
+/// # Errors
+///
+/// Returns an error if the `order` is not a pegged limit order.
+pub fn peg_price_calculate(
+    price_increment: Price,
+    order: &OrderAny,
+    bid: Option<Price>,
+    ask: Option<Price>,
+) -> anyhow::Result<Option<Price>> {
+    let order_type = order.order_type();
+    if order_type != OrderType::Limit {
+        anyhow::bail!("Invalid `OrderType` {order_type} for peg price calculation");
+    }
+    let Some(peg_price_type) = order.peg_price_type() else {
+        anyhow::bail!("Invalid order: no `peg_price_type` for peg price calculation");
+    };
+
+    let order_side = order.order_side_specified();
+    let reference = match (peg_price_type, order_side) {
+        (PegPriceType::Primary, OrderSideSpecified::Buy)
+        | (PegPriceType::Market, OrderSideSpecified::Sell) => bid.map(|bid| bid.as_decimal()),
+        (PegPriceType::Primary, OrderSideSpecified::Sell)
+        | (PegPriceType::Market, OrderSideSpecified::Buy) => ask.map(|ask| ask.as_decimal()),
+        (PegPriceType::Midpoint, _) => match (bid, ask) {
+            (Some(bid), Some(ask)) => Some((bid.as_decimal() + ask.as_decimal()) / Decimal::TWO),
+            _ => None,
+        },
+    };
+    let Some(reference) = reference else {
+        return Ok(None);
+    };
+
+    let offset = order.peg_offset().unwrap_or_default();
+    let increment = price_increment.as_decimal();
+    let value = match order_side {
+        OrderSideSpecified::Buy => ((reference - offset) / increment).floor() * increment,
+        OrderSideSpecified::Sell => ((reference + offset) / increment).ceil() * increment,
+    };
+    let new_price = Price::new(
+        value.to_f64().expect("Invalid peg price"),
+        price_increment.precision,
+    );
+
+    if order.price() == Some(new_price) {
+        return Ok(None);
+    }
+
+    Ok(Some(new_price))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{enums::OrderSide, orders::builder::OrderTestBuilder, types::Quantity};
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn pegged_order(side: OrderSide, peg_price_type: PegPriceType, offset: Decimal) -> OrderAny {
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id("BTCUSDT-PERP.BINANCE".into())
+            .side(side)
+            .price(Price::new(90.0, 2))
+            .peg_price_type(peg_price_type)
+            .peg_offset(offset)
+            .quantity(Quantity::from(1))
+            .build()
+    }
+
+    #[rstest]
+    fn test_calculate_with_non_pegged_order() {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id("BTCUSDT-PERP.BINANCE".into())
+            .side(OrderSide::Buy)
+            .price(Price::new(90.0, 2))
+            .quantity(Quantity::from(1))
+            .build();
+
+        let result = peg_price_calculate(
+            Price::new(0.01, 2),
+            &order,
+            Some(Price::new(100.0, 2)),
+            Some(Price::new(101.0, 2)),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, PegPriceType::Primary, dec!(0), 100.0)]
+    #[case(OrderSide::Buy, PegPriceType::Primary, dec!(0.5), 99.5)]
+    #[case(OrderSide::Sell, PegPriceType::Primary, dec!(0.5), 101.5)]
+    #[case(OrderSide::Buy, PegPriceType::Market, dec!(0), 101.0)]
+    #[case(OrderSide::Sell, PegPriceType::Market, dec!(-0.25), 99.75)]
+    #[case(OrderSide::Buy, PegPriceType::Midpoint, dec!(0), 100.5)]
+    #[case(OrderSide::Sell, PegPriceType::Midpoint, dec!(0.1), 100.6)]
+    fn test_calculate_peg_price(
+        #[case] side: OrderSide,
+        #[case] peg_price_type: PegPriceType,
+        #[case] offset: Decimal,
+        #[case] expected: f64,
+    ) {
+        let order = pegged_order(side, peg_price_type, offset);
+
+        let price = peg_price_calculate(
+            Price::new(0.01, 2),
+            &order,
+            Some(Price::new(100.0, 2)),
+            Some(Price::new(101.0, 2)),
+        )
+        .unwrap();
+
+        assert_eq!(price, Some(Price::new(expected, 2)));
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, 100.0)]
+    #[case(OrderSide::Sell, 100.5)]
+    fn test_calculate_midpoint_rounds_passively(#[case] side: OrderSide, #[case] expected: f64) {
+        let order = pegged_order(side, PegPriceType::Midpoint, dec!(0));
+
+        let price = peg_price_calculate(
+            Price::new(0.5, 1),
+            &order,
+            Some(Price::new(100.0, 1)),
+            Some(Price::new(100.5, 1)),
+        )
+        .unwrap();
+
+        assert_eq!(price, Some(Price::new(expected, 1)));
+    }
+
+    #[rstest]
+    fn test_calculate_when_unchanged_or_no_reference() {
+        let order = pegged_order(OrderSide::Buy, PegPriceType::Primary, dec!(0));
+
+        let unchanged =
+            peg_price_calculate(Price::new(0.01, 2), &order, Some(Price::new(90.0, 2)), None)
+                .unwrap();
+        let no_bid =
+            peg_price_calculate(Price::new(0.01, 2), &order, None, Some(Price::new(91.0, 2)))
+                .unwrap();
+
+        assert_eq!(unchanged, None);
+        assert_eq!(no_bid, None);
+    }
+}
@@ -0,0 +1,241 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Allocation of an order quantity across venues by fee adjusted displayed price.
+
+use nautilus_model::{enums::OrderSide, identifiers::InstrumentId};
+use serde::{Deserialize, Serialize};
+
+/// The displayed liquidity and taker fee of a venue instrument an order can be routed to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VenueLiquidity {
+    pub instrument_id: InstrumentId,
+    /// The displayed `(price, size)` levels on the side the order takes, best first.
+    pub levels: Vec<(f64, f64)>,
+    /// The fee rate charged for taking liquidity.
+    pub fee_rate: f64,
+}
+
+impl VenueLiquidity {
+    /// Returns the `price` of the venue adjusted for the fee paid (or rebate received).
+    #[must_use]
+    pub fn effective_price(&self, side: OrderSide, price: f64) -> f64 {
+        match side {
+            OrderSide::Sell => price * (1.0 - self.fee_rate),
+            _ => price * (1.0 + self.fee_rate),
+        }
+    }
+}
+
+/// The quantity of an order allocated to a venue instrument.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RouteAllocation {
+    pub instrument_id: InstrumentId,
+    /// The quantity allocated to the venue.
+    pub quantity: f64,
+    /// The quantity allocated against displayed liquidity (the rest is expected to rest).
+    pub displayed_qty: f64,
+    /// The average displayed price of the quantity allocated against displayed liquidity.
+    pub avg_px: Option<f64>,
+    /// The fee rate charged for taking liquidity at the venue.
+    pub fee_rate: f64,
+}
+
+/// Returns whether the `effective` price is better than the `other` for the `side`.
+fn is_better(side: OrderSide, effective: f64, other: f64) -> bool {
+    match side {
+        OrderSide::Sell => effective > other,
+        _ => effective < other,
+    }
+}
+
+/// Allocates `quantity` of an order on `side` across the `venues`, taking the displayed levels
+/// with the best fee adjusted price first (for equal prices the venue listed first), limited
+/// to levels at or better than `limit_px`.
+///
+/// Any quantity beyond the displayed liquidity is allocated to the venue with the best fee
+/// adjusted top level price, or with no displayed liquidity the lowest fee rate.
+#[must_use]
+pub fn allocate(
+    side: OrderSide,
+    quantity: f64,
+    limit_px: Option<f64>,
+    venues: &[VenueLiquidity],
+) -> Vec<RouteAllocation> {
+    let within_limit = |price: f64| match (side, limit_px) {
+        (_, None) => true,
+        (OrderSide::Sell, Some(limit)) => price >= limit,
+        (_, Some(limit)) => price <= limit,
+    };
+
+    // (venue index, price, size, effective price)
+    let mut levels: Vec<(usize, f64, f64, f64)> = venues
+        .iter()
+        .enumerate()
+        .flat_map(|(i, venue)| {
+            venue
+                .levels
+                .iter()
+                .filter(|(price, size)| *size > 0.0 && within_limit(*price))
+                .map(move |(price, size)| (i, *price, *size, venue.effective_price(side, *price)))
+        })
+        .collect();
+    levels.sort_by(|a, b| match side {
+        OrderSide::Sell => b.3.total_cmp(&a.3),
+        _ => a.3.total_cmp(&b.3),
+    });
+
+    let mut taken = vec![(0.0, 0.0); venues.len()]; // (quantity, notional)
+    let mut remaining = quantity;
+    for (i, price, size, _) in levels {
+        if remaining <= 0.0 {
+            break;
+        }
+        let fill_qty = size.min(remaining);
+        taken[i].0 += fill_qty;
+        taken[i].1 += fill_qty * price;
+        remaining -= fill_qty;
+    }
+
+    let mut allocations: Vec<RouteAllocation> = venues
+        .iter()
+        .zip(&taken)
+        .map(|(venue, (qty, notional))| RouteAllocation {
+            instrument_id: venue.instrument_id,
+            quantity: *qty,
+            displayed_qty: *qty,
+            avg_px: (*qty > 0.0).then(|| notional / qty),
+            fee_rate: venue.fee_rate,
+        })
+        .collect();
+
+    if remaining > 0.0 {
+        if let Some(i) = residual_venue(side, venues) {
+            allocations[i].quantity += remaining;
+        }
+    }
+
+    allocations.retain(|allocation| allocation.quantity > 0.0);
+    allocations
+}
+
+/// Returns the index of the venue to allocate the quantity beyond the displayed liquidity to.
+fn residual_venue(side: OrderSide, venues: &[VenueLiquidity]) -> Option<usize> {
+    let best_top = venues
+        .iter()
+        .enumerate()
+        .filter_map(|(i, venue)| {
+            venue
+                .levels
+                .first()
+                .map(|(price, _)| (i, venue.effective_price(side, *price)))
+        })
+        .reduce(|best, next| {
+            if is_better(side, next.1, best.1) {
+                next
+            } else {
+                best
+            }
+        });
+
+    best_top.map(|(i, _)| i).or_else(|| {
+        venues
+            .iter()
+            .enumerate()
+            .reduce(|best, next| {
+                if next.1.fee_rate < best.1.fee_rate {
+                    next
+                } else {
+                    best
+                }
+            })
+            .map(|(i, _)| i)
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn venue(instrument_id: &str, levels: &[(f64, f64)], fee_rate: f64) -> VenueLiquidity {
+        VenueLiquidity {
+            instrument_id: InstrumentId::from(instrument_id),
+            levels: levels.to_vec(),
+            fee_rate,
+        }
+    }
+
+    #[rstest]
+    fn test_allocate_takes_best_prices_across_venues() {
+        let venues = [
+            venue("BTCUSD.A", &[(100.0, 1.0), (101.0, 5.0)], 0.0),
+            venue("BTCUSD.B", &[(100.5, 2.0), (102.0, 5.0)], 0.0),
+        ];
+
+        let allocations = allocate(OrderSide::Buy, 4.0, None, &venues);
+
+        assert_eq!(allocations.len(), 2);
+        assert_eq!(allocations[0].instrument_id, InstrumentId::from("BTCUSD.A"));
+        assert_eq!(allocations[0].quantity, 2.0); // 1 @ 100 then 1 @ 101
+        assert_eq!(allocations[0].avg_px, Some(100.5));
+        assert_eq!(allocations[1].quantity, 2.0); // 2 @ 100.5
+    }
+
+    #[rstest]
+    fn test_allocate_accounts_for_fees() {
+        let venues = [
+            venue("BTCUSD.A", &[(100.0, 5.0)], 0.01),
+            venue("BTCUSD.B", &[(100.5, 5.0)], 0.0),
+        ];
+
+        let allocations = allocate(OrderSide::Buy, 1.0, None, &venues);
+
+        assert_eq!(allocations.len(), 1);
+        assert_eq!(allocations[0].instrument_id, InstrumentId::from("BTCUSD.B"));
+    }
+
+    #[rstest]
+    fn test_allocate_sell_respects_limit_and_routes_residual_to_best_venue() {
+        let venues = [
+            venue("BTCUSD.A", &[(99.0, 1.0), (98.0, 5.0)], 0.0),
+            venue("BTCUSD.B", &[(99.5, 1.0), (97.0, 5.0)], 0.0),
+        ];
+
+        let allocations = allocate(OrderSide::Sell, 3.0, Some(99.0), &venues);
+
+        assert_eq!(allocations.len(), 2);
+        assert_eq!(allocations[0].instrument_id, InstrumentId::from("BTCUSD.A"));
+        assert_eq!(allocations[0].displayed_qty, 1.0);
+        assert_eq!(allocations[1].instrument_id, InstrumentId::from("BTCUSD.B"));
+        assert_eq!(allocations[1].displayed_qty, 1.0);
+        assert_eq!(allocations[1].quantity, 2.0); // Residual to the best top level
+    }
+
+    #[rstest]
+    fn test_allocate_without_liquidity_routes_to_lowest_fee_venue() {
+        let venues = [venue("BTCUSD.A", &[], 0.002), venue("BTCUSD.B", &[], 0.001)];
+
+        let allocations = allocate(OrderSide::Buy, 1.0, None, &venues);
+
+        assert_eq!(allocations.len(), 1);
+        assert_eq!(allocations[0].instrument_id, InstrumentId::from("BTCUSD.B"));
+        assert_eq!(allocations[0].avg_px, None);
+    }
+}
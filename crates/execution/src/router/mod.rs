@@ -0,0 +1,503 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Smart order routing of orders for an instrument traded on multiple venues.
+
+pub mod allocation;
+
+use std::{cell::RefCell, collections::HashMap, fmt::Display, rc::Rc};
+
+use nautilus_common::{cache::Cache, clock::Clock, msgbus::MessageBus};
+use nautilus_core::{UnixNanos, UUID4};
+use nautilus_model::{
+    enums::OrderSide,
+    identifiers::{ClientId, ClientOrderId, InstrumentId, StrategyId, TraderId},
+    orders::{LimitOrder, MarketOrder, OrderAny},
+    types::Quantity,
+};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use self::allocation::{allocate, RouteAllocation, VenueLiquidity};
+use crate::messages::SubmitOrder;
+
+/// A child order routed to a venue.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RoutedOrder {
+    pub client_order_id: ClientOrderId,
+    pub client_id: ClientId,
+    pub allocation: RouteAllocation,
+}
+
+/// Represents a decision routing an order across venues, as child orders per venue.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OrderRouted {
+    pub trader_id: TraderId,
+    pub strategy_id: StrategyId,
+    /// The canonical instrument the order was routed for.
+    pub canonical_id: Ustr,
+    pub client_order_id: ClientOrderId,
+    pub order_side: OrderSide,
+    pub quantity: Quantity,
+    pub routes: Vec<RoutedOrder>,
+    pub event_id: UUID4,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl Display for OrderRouted {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let routes: Vec<String> = self
+            .routes
+            .iter()
+            .map(|route| {
+                format!(
+                    "{}:{}@{}",
+                    route.allocation.instrument_id,
+                    route.allocation.quantity,
+                    route
+                        .allocation
+                        .avg_px
+                        .map_or_else(|| "None".to_string(), |px| px.to_string()),
+                )
+            })
+            .collect();
+        write!(
+            f,
+            "OrderRouted(client_order_id={}, canonical_id={}, side={}, quantity={}, routes=[{}])",
+            self.client_order_id,
+            self.canonical_id,
+            self.order_side,
+            self.quantity,
+            routes.join(", "),
+        )
+    }
+}
+
+/// Routes orders for a canonical instrument traded on multiple venues, splitting each order
+/// across the venues by best fee adjusted displayed price and available liquidity.
+///
+/// Routing decisions are published as [`OrderRouted`] events on the routing topic of the
+/// strategy.
+pub struct SmartOrderRouter {
+    clock: Rc<RefCell<dyn Clock>>,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    book_depth: Option<usize>,
+    venues: HashMap<Ustr, Vec<(InstrumentId, ClientId)>>,
+    canonical_ids: HashMap<InstrumentId, Ustr>,
+}
+
+impl SmartOrderRouter {
+    /// Creates a new [`SmartOrderRouter`] instance, considering `book_depth` levels of the
+    /// venue order books (or all levels if `None`).
+    pub fn new(
+        clock: Rc<RefCell<dyn Clock>>,
+        cache: Rc<RefCell<Cache>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+        book_depth: Option<usize>,
+    ) -> Self {
+        Self {
+            clock,
+            cache,
+            msgbus,
+            book_depth,
+            venues: HashMap::new(),
+            canonical_ids: HashMap::new(),
+        }
+    }
+
+    /// Registers the venue instruments (and the clients executing them) the canonical
+    /// instrument `canonical_id` is traded on.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `venues` is empty, or an instrument is already registered for
+    /// another canonical instrument.
+    pub fn register_venues(
+        &mut self,
+        canonical_id: Ustr,
+        venues: Vec<(InstrumentId, ClientId)>,
+    ) -> anyhow::Result<()> {
+        if venues.is_empty() {
+            anyhow::bail!("No venues to register for {canonical_id}");
+        }
+        for (instrument_id, _) in &venues {
+            if let Some(existing) = self.canonical_ids.get(instrument_id) {
+                if *existing != canonical_id {
+                    anyhow::bail!("{instrument_id} already registered for {existing}");
+                }
+            }
+        }
+
+        for (instrument_id, _) in &venues {
+            self.canonical_ids.insert(*instrument_id, canonical_id);
+        }
+        log::info!("Registered {} venues for {canonical_id}", venues.len());
+        self.venues.insert(canonical_id, venues);
+        Ok(())
+    }
+
+    /// Returns the canonical instrument the venue instrument is registered for.
+    #[must_use]
+    pub fn canonical_id(&self, instrument_id: &InstrumentId) -> Option<Ustr> {
+        self.canonical_ids.get(instrument_id).copied()
+    }
+
+    /// Routes the order of the `command` (for any venue instrument of a canonical instrument)
+    /// across the venues, returning a command for each child order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the instrument is not registered, or the order is not a market
+    /// or limit order.
+    pub fn route(&self, command: &SubmitOrder) -> anyhow::Result<Vec<SubmitOrder>> {
+        let order = &command.order;
+        let canonical_id = self
+            .canonical_id(&command.instrument_id)
+            .ok_or_else(|| anyhow::anyhow!("No venues registered for {}", command.instrument_id))?;
+        if !matches!(order, OrderAny::Market(_) | OrderAny::Limit(_)) {
+            anyhow::bail!("Cannot route {} orders", order.order_type());
+        }
+
+        let venues = &self.venues[&canonical_id];
+        let liquidity = self.venue_liquidity(order.order_side(), venues);
+        let allocations = allocate(
+            order.order_side(),
+            order.quantity().as_f64(),
+            order.price().map(|price| price.as_f64()),
+            &liquidity,
+        );
+
+        let ts_init = self.clock.borrow().timestamp_ns();
+        let mut commands = Vec::with_capacity(allocations.len());
+        let mut routes = Vec::with_capacity(allocations.len());
+
+        for allocation in allocations {
+            let Some(client_id) = venues
+                .iter()
+                .find(|(instrument_id, _)| *instrument_id == allocation.instrument_id)
+                .map(|(_, client_id)| *client_id)
+            else {
+                continue;
+            };
+            let Some(quantity) = self.make_qty(&allocation) else {
+                continue;
+            };
+
+            let client_order_id =
+                ClientOrderId::new(format!("{}-{}", order.client_order_id(), routes.len() + 1));
+            let child = child_order(
+                order,
+                allocation.instrument_id,
+                client_order_id,
+                quantity,
+                ts_init,
+            )?;
+            commands.push(SubmitOrder::new(
+                command.trader_id,
+                client_id,
+                command.strategy_id,
+                allocation.instrument_id,
+                client_order_id,
+                command.venue_order_id,
+                child,
+                None,
+                command.position_id,
+                UUID4::new(),
+                ts_init,
+            )?);
+            routes.push(RoutedOrder {
+                client_order_id,
+                client_id,
+                allocation,
+            });
+        }
+
+        let event = OrderRouted {
+            trader_id: command.trader_id,
+            strategy_id: command.strategy_id,
+            canonical_id,
+            client_order_id: order.client_order_id(),
+            order_side: order.order_side(),
+            quantity: order.quantity(),
+            routes,
+            event_id: UUID4::new(),
+            ts_event: ts_init,
+            ts_init,
+        };
+        log::info!("{event}");
+
+        let mut msgbus = self.msgbus.borrow_mut();
+        let topic = msgbus
+            .switchboard
+            .get_event_routing_topic(command.strategy_id);
+        msgbus.publish(&topic, &event);
+
+        Ok(commands)
+    }
+
+    fn venue_liquidity(
+        &self,
+        side: OrderSide,
+        venues: &[(InstrumentId, ClientId)],
+    ) -> Vec<VenueLiquidity> {
+        let cache = self.cache.borrow();
+        venues
+            .iter()
+            .filter_map(|(instrument_id, _)| {
+                let Some(instrument) = cache.instrument(instrument_id) else {
+                    log::warn!("Cannot route to {instrument_id}: no instrument found");
+                    return None;
+                };
+
+                let levels = if let Some(book) = cache.order_book(instrument_id) {
+                    let levels: Box<dyn Iterator<Item = _>> = match side {
+                        OrderSide::Sell => Box::new(book.bids(self.book_depth)),
+                        _ => Box::new(book.asks(self.book_depth)),
+                    };
+                    levels
+                        .map(|level| (level.price.value.as_f64(), level.size()))
+                        .collect()
+                } else if let Some(quote) = cache.quote(instrument_id) {
+                    match side {
+                        OrderSide::Sell => {
+                            vec![(quote.bid_price.as_f64(), quote.bid_size.as_f64())]
+                        }
+                        _ => vec![(quote.ask_price.as_f64(), quote.ask_size.as_f64())],
+                    }
+                } else {
+                    Vec::new()
+                };
+
+                Some(VenueLiquidity {
+                    instrument_id: *instrument_id,
+                    levels,
+                    fee_rate: instrument.taker_fee().try_into().unwrap_or(0.0),
+                })
+            })
+            .collect()
+    }
+
+    fn make_qty(&self, allocation: &RouteAllocation) -> Option<Quantity> {
+        let cache = self.cache.borrow();
+        let instrument = cache.instrument(&allocation.instrument_id)?;
+        let quantity = instrument.make_qty(allocation.quantity);
+        (quantity.is_positive()).then_some(quantity)
+    }
+}
+
+/// Returns the child of the market or limit `order` routed to the venue `instrument_id`.
+fn child_order(
+    order: &OrderAny,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    quantity: Quantity,
+    ts_init: UnixNanos,
+) -> anyhow::Result<OrderAny> {
+    let child = match order {
+        OrderAny::Market(_) => OrderAny::Market(MarketOrder::new(
+            order.trader_id(),
+            order.strategy_id(),
+            instrument_id,
+            client_order_id,
+            order.order_side(),
+            quantity,
+            order.time_in_force(),
+            UUID4::new(),
+            ts_init,
+            order.is_reduce_only(),
+            order.is_quote_quantity(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            order.tags(),
+        )),
+        OrderAny::Limit(limit) => OrderAny::Limit(LimitOrder::new(
+            order.trader_id(),
+            order.strategy_id(),
+            instrument_id,
+            client_order_id,
+            order.order_side(),
+            quantity,
+            limit.price,
+            limit.peg_price_type,
+            limit.peg_offset,
+            order.time_in_force(),
+            order.expire_time(),
+            order.is_post_only(),
+            order.is_reduce_only(),
+            order.is_quote_quantity(),
+            order.display_qty(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            order.tags(),
+            UUID4::new(),
+            ts_init,
+        )?),
+        _ => anyhow::bail!("Cannot route {} orders", order.order_type()),
+    };
+    Ok(child)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::{
+        clock::TestClock,
+        msgbus::stubs::{get_message_saving_handler, get_saved_messages},
+    };
+    use nautilus_model::{
+        data::QuoteTick,
+        enums::OrderType,
+        identifiers::{Symbol, Venue, VenueOrderId},
+        instruments::{stubs::crypto_perpetual_ethusdt, InstrumentAny},
+        orders::OrderTestBuilder,
+        types::Price,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn venue_instrument(venue: &str) -> InstrumentAny {
+        let mut instrument = crypto_perpetual_ethusdt();
+        instrument.id = InstrumentId::new(Symbol::from("ETHUSDT-PERP"), Venue::from(venue));
+        InstrumentAny::CryptoPerpetual(instrument)
+    }
+
+    fn quote(instrument_id: InstrumentId, ask: &str, ask_size: &str) -> QuoteTick {
+        QuoteTick::new(
+            instrument_id,
+            Price::from("1000.00"),
+            Price::from(ask),
+            Quantity::from("100.000"),
+            Quantity::from(ask_size),
+            0.into(),
+            0.into(),
+        )
+    }
+
+    #[rstest]
+    fn test_route_splits_order_across_venues_and_publishes_decision() {
+        let a = venue_instrument("VENUEA");
+        let b = venue_instrument("VENUEB");
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        for (instrument, ask) in [(&a, "1001.00"), (&b, "1001.50")] {
+            let mut cache = cache.borrow_mut();
+            cache.add_instrument(instrument.clone()).unwrap();
+            cache
+                .add_quote(quote(instrument.id(), ask, "1.000"))
+                .unwrap();
+        }
+
+        let msgbus = Rc::new(RefCell::new(MessageBus::default()));
+        let handler = get_message_saving_handler::<OrderRouted>(None);
+        msgbus
+            .borrow_mut()
+            .subscribe("events.routing.S-001", handler.clone(), None);
+
+        let mut router =
+            SmartOrderRouter::new(Rc::new(RefCell::new(TestClock::new())), cache, msgbus, None);
+        router
+            .register_venues(
+                Ustr::from("ETH/USDT"),
+                vec![
+                    (a.id(), ClientId::from("VENUEA")),
+                    (b.id(), ClientId::from("VENUEB")),
+                ],
+            )
+            .unwrap();
+
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(a.id())
+            .client_order_id(ClientOrderId::from("O-1"))
+            .side(OrderSide::Buy)
+            .price(Price::from("1002.00"))
+            .quantity(Quantity::from("1.500"))
+            .build();
+        let command = SubmitOrder::new(
+            TraderId::default(),
+            ClientId::from("VENUEA"),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            VenueOrderId::default(),
+            order,
+            None,
+            None,
+            UUID4::new(),
+            0.into(),
+        )
+        .unwrap();
+
+        let commands = router.route(&command).unwrap();
+
+        assert_eq!(commands.len(), 2);
+        assert_eq!(commands[0].client_id, ClientId::from("VENUEA"));
+        assert_eq!(commands[0].client_order_id, ClientOrderId::from("O-1-1"));
+        assert_eq!(commands[0].order.quantity(), Quantity::from("1.000"));
+        assert_eq!(commands[1].instrument_id, b.id());
+        assert_eq!(commands[1].order.quantity(), Quantity::from("0.500"));
+        assert_eq!(commands[1].order.price(), Some(Price::from("1002.00")));
+
+        let events = get_saved_messages::<OrderRouted>(handler);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].client_order_id, ClientOrderId::from("O-1"));
+        assert_eq!(events[0].routes.len(), 2);
+    }
+
+    #[rstest]
+    fn test_route_unregistered_instrument_errors() {
+        let router = SmartOrderRouter::new(
+            Rc::new(RefCell::new(TestClock::new())),
+            Rc::new(RefCell::new(Cache::default())),
+            Rc::new(RefCell::new(MessageBus::default())),
+            None,
+        );
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(InstrumentId::from("ETHUSDT-PERP.VENUEA"))
+            .quantity(Quantity::from("1.000"))
+            .build();
+        let command = SubmitOrder::new(
+            TraderId::default(),
+            ClientId::from("VENUEA"),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            VenueOrderId::default(),
+            order,
+            None,
+            None,
+            UUID4::new(),
+            0.into(),
+        )
+        .unwrap();
+
+        assert!(router.route(&command).is_err());
+    }
+}
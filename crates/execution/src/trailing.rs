@@ -28,6 +28,7 @@ pub fn trailing_stop_calculate(
     bid: Option<Price>,
     ask: Option<Price>,
     last: Option<Price>,
+    mark: Option<Price>,
 ) -> anyhow::Result<(Option<Price>, Option<Price>)> {
     let order_side = order.order_side_specified();
     let order_type = order.order_type();
@@ -54,9 +55,38 @@ pub fn trailing_stop_calculate(
         price = order.price();
     }
 
+    // Until activated the order does not trail, then trails from the activating price
+    if order.is_activated() == Some(false) {
+        let activation_price = order.activation_price().expect("Invalid order");
+        if !is_activation_reached(
+            trigger_type,
+            order_side,
+            activation_price,
+            bid,
+            ask,
+            last,
+            mark,
+        ) {
+            return Ok((None, None));
+        }
+        trigger_price = None;
+        price = None;
+    }
+
     match trigger_type {
-        TriggerType::Default | TriggerType::LastPrice | TriggerType::MarkPrice => {
-            let last = last.ok_or(OrderError::InvalidStateTransition)?;
+        TriggerType::Default
+        | TriggerType::LastPrice
+        | TriggerType::MarkPrice
+        | TriggerType::MidPoint => {
+            let last = match trigger_type {
+                TriggerType::MarkPrice => mark.ok_or_else(|| {
+                    anyhow::anyhow!("`MarkPrice` calculation requires `mark` price")
+                })?,
+                TriggerType::MidPoint => mid_price(bid, ask).ok_or_else(|| {
+                    anyhow::anyhow!("`MidPoint` calculation requires `bid` and `ask` prices")
+                })?,
+                _ => last.ok_or(OrderError::InvalidStateTransition)?,
+            };
 
             let temp_trigger_price = trailing_stop_calculate_with_last(
                 price_increment,
@@ -344,6 +374,45 @@ pub fn trailing_stop_calculate(
     Ok((new_trigger_price, new_price))
 }
 
+/// Returns whether the market has reached the `activation_price` of a trailing stop order,
+/// being at or below it for buy orders and at or above it for sell orders.
+#[must_use]
+pub fn is_activation_reached(
+    trigger_type: TriggerType,
+    side: OrderSideSpecified,
+    activation_price: Price,
+    bid: Option<Price>,
+    ask: Option<Price>,
+    last: Option<Price>,
+    mark: Option<Price>,
+) -> bool {
+    let reference = match trigger_type {
+        TriggerType::MarkPrice => mark,
+        TriggerType::MidPoint => mid_price(bid, ask),
+        TriggerType::BidAsk => match side {
+            OrderSideSpecified::Buy => ask,
+            OrderSideSpecified::Sell => bid,
+        },
+        TriggerType::LastOrBidAsk => last.or(match side {
+            OrderSideSpecified::Buy => ask,
+            OrderSideSpecified::Sell => bid,
+        }),
+        _ => last,
+    };
+
+    reference.is_some_and(|reference| match side {
+        OrderSideSpecified::Buy => reference <= activation_price,
+        OrderSideSpecified::Sell => reference >= activation_price,
+    })
+}
+
+fn mid_price(bid: Option<Price>, ask: Option<Price>) -> Option<Price> {
+    match (bid, ask) {
+        (Some(bid), Some(ask)) => Some(Price::from_raw((bid.raw + ask.raw) / 2, bid.precision)),
+        _ => None,
+    }
+}
+
 pub fn trailing_stop_calculate_with_last(
     price_increment: Price,
     trailing_offset_type: TrailingOffsetType,
@@ -429,7 +498,7 @@ mod tests {
             .quantity(Quantity::from(1))
             .build();
 
-        let result = trailing_stop_calculate(Price::new(0.01, 2), &order, None, None, None);
+        let result = trailing_stop_calculate(Price::new(0.01, 2), &order, None, None, None, None);
 
         // TODO: Basic error assert for now
         assert!(result.is_err());
@@ -449,7 +518,7 @@ mod tests {
             .quantity(Quantity::from(1))
             .build();
 
-        let result = trailing_stop_calculate(Price::new(0.01, 2), &order, None, None, None);
+        let result = trailing_stop_calculate(Price::new(0.01, 2), &order, None, None, None, None);
 
         // TODO: Basic error assert for now
         assert!(result.is_err());
@@ -469,7 +538,7 @@ mod tests {
             .quantity(Quantity::from(1))
             .build();
 
-        let result = trailing_stop_calculate(Price::new(0.01, 2), &order, None, None, None);
+        let result = trailing_stop_calculate(Price::new(0.01, 2), &order, None, None, None, None);
 
         // TODO: Basic error assert for now
         assert!(result.is_err());
@@ -487,7 +556,7 @@ mod tests {
             .quantity(Quantity::from(1))
             .build();
 
-        let result = trailing_stop_calculate(Price::new(0.01, 2), &order, None, None, None);
+        let result = trailing_stop_calculate(Price::new(0.01, 2), &order, None, None, None, None);
 
         // TODO: Basic error assert for now
         assert!(result.is_err());
@@ -521,6 +590,7 @@ mod tests {
             None,
             None,
             Some(Price::new(last_price, 2)),
+            None,
         );
 
         let actual_trigger = result.unwrap().0;
@@ -559,6 +629,7 @@ mod tests {
             None,
             None,
             Some(Price::new(last_price, 2)),
+            None,
         );
 
         let actual_trigger = result.unwrap().0;
@@ -598,6 +669,7 @@ mod tests {
             Some(Price::new(bid, 2)),
             Some(Price::new(ask, 2)),
             None, // last price not needed for BidAsk trigger type
+            None,
         );
 
         let actual_trigger = result.unwrap().0;
@@ -636,6 +708,7 @@ mod tests {
             None,
             None,
             Some(Price::new(last_price, 2)),
+            None,
         );
 
         let actual_trigger = result.unwrap().0;
@@ -676,6 +749,7 @@ mod tests {
             Some(Price::new(bid, 2)),
             Some(Price::new(ask, 2)),
             Some(Price::new(last_price, 2)),
+            None,
         );
 
         let actual_trigger = result.unwrap().0;
@@ -685,4 +759,73 @@ mod tests {
             _ => panic!("Expected trigger {expected_trigger:?} but got {actual_trigger:?}"),
         }
     }
+
+    #[rstest]
+    #[case(TriggerType::MarkPrice, 99.0)] // Mark 98 + 1
+    #[case(TriggerType::MidPoint, 98.0)] // Mid of 96.5 and 97.5 + 1
+    fn test_trailing_stop_market_mark_and_mid(
+        #[case] trigger_type: TriggerType,
+        #[case] expected_trigger: f64,
+    ) {
+        let order = OrderTestBuilder::new(OrderType::TrailingStopMarket)
+            .instrument_id("BTCUSDT-PERP.BINANCE".into())
+            .side(OrderSide::Buy)
+            .trigger_price(Price::new(100.0, 2))
+            .trailing_offset_type(TrailingOffsetType::Price)
+            .trailing_offset(dec!(1.0))
+            .trigger_type(trigger_type)
+            .quantity(Quantity::from(1))
+            .build();
+
+        let result = trailing_stop_calculate(
+            Price::new(0.01, 2),
+            &order,
+            Some(Price::new(96.5, 2)),
+            Some(Price::new(97.5, 2)),
+            Some(Price::new(95.0, 2)),
+            Some(Price::new(98.0, 2)),
+        );
+
+        assert_eq!(result.unwrap().0, Some(Price::new(expected_trigger, 2)));
+    }
+
+    #[rstest]
+    #[case(OrderSide::Sell, 104.0, None)] // Below activation, not trailing
+    #[case(OrderSide::Sell, 106.0, Some(105.0))] // Activated, trails from 106 - 1
+    #[case(OrderSide::Buy, 96.0, None)] // Above activation, not trailing
+    #[case(OrderSide::Buy, 94.0, Some(95.0))] // Activated, trails from 94 + 1
+    fn test_trailing_stop_market_activation_price(
+        #[case] side: OrderSide,
+        #[case] last_price: f64,
+        #[case] expected_trigger: Option<f64>,
+    ) {
+        let activation_price = match side {
+            OrderSide::Sell => Price::new(105.0, 2),
+            _ => Price::new(95.0, 2),
+        };
+        let order = OrderTestBuilder::new(OrderType::TrailingStopMarket)
+            .instrument_id("BTCUSDT-PERP.BINANCE".into())
+            .side(side)
+            .trigger_price(Price::new(100.0, 2))
+            .trailing_offset_type(TrailingOffsetType::Price)
+            .trailing_offset(dec!(1.0))
+            .trigger_type(TriggerType::LastPrice)
+            .activation_price(activation_price)
+            .quantity(Quantity::from(1))
+            .build();
+
+        let result = trailing_stop_calculate(
+            Price::new(0.01, 2),
+            &order,
+            None,
+            None,
+            Some(Price::new(last_price, 2)),
+            None,
+        );
+
+        assert_eq!(
+            result.unwrap().0,
+            expected_trigger.map(|p| Price::new(p, 2))
+        );
+    }
 }
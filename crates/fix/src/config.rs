@@ -0,0 +1,58 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use serde::{Deserialize, Serialize};
+
+/// Provides a configuration for a FIX 4.4 initiator session.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixSessionConfig {
+    /// The FIX acceptor host.
+    pub host: String,
+    /// The FIX acceptor port.
+    pub port: u16,
+    /// The `SenderCompID(49)` of this session.
+    pub sender_comp_id: String,
+    /// The `TargetCompID(56)` of the counterparty.
+    pub target_comp_id: String,
+    /// The optional `Username(553)` sent on logon.
+    pub username: Option<String>,
+    /// The optional `Password(554)` sent on logon.
+    pub password: Option<String>,
+    /// The heartbeat interval (seconds) sent on logon as `HeartBtInt(108)`.
+    pub heartbeat_interval_secs: u64,
+    /// If sequence numbers are reset to 1 on logon (with `ResetSeqNumFlag(141)`).
+    pub reset_on_logon: bool,
+    /// The timeout (seconds) for establishing the TCP connection.
+    pub connection_timeout_secs: u64,
+    /// The timeout (seconds) for the counterparty to respond to the logon.
+    pub logon_timeout_secs: u64,
+}
+
+impl Default for FixSessionConfig {
+    fn default() -> Self {
+        Self {
+            host: "127.0.0.1".to_string(),
+            port: 9876,
+            sender_comp_id: "CLIENT".to_string(),
+            target_comp_id: "SERVER".to_string(),
+            username: None,
+            password: None,
+            heartbeat_interval_secs: 30,
+            reset_on_logon: true,
+            connection_timeout_secs: 10,
+            logon_timeout_secs: 10,
+        }
+    }
+}
@@ -0,0 +1,435 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Drives a [`FixSession`] over a TCP connection.
+
+use std::time::Duration;
+
+use nautilus_core::{time::get_atomic_clock_realtime, UnixNanos};
+use tokio::{
+    io::{AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    net::TcpStream,
+    sync::mpsc::{Sender, UnboundedReceiver},
+    time::MissedTickBehavior,
+};
+
+use crate::{
+    config::FixSessionConfig,
+    message::{FixFramer, FixMessage},
+    session::{FixSession, SessionAction},
+};
+
+/// The interval for the session timer (heartbeats and timeouts).
+const TIMER_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug)]
+pub enum FixCommand {
+    /// Send the application message (queued until the session is logged on).
+    Send(FixMessage),
+    /// Log out of the session, with the optional logout text.
+    Logout(Option<String>),
+    /// Log out (if logged on) and close the connection.
+    Close,
+}
+
+#[derive(Debug)]
+pub enum FixEvent {
+    /// The session is logged on.
+    LoggedOn,
+    /// An application (or session `Reject`) message was received.
+    Message(FixMessage),
+    /// The session was logged out, with the counterparty logout text (if any).
+    LoggedOut(Option<String>),
+    /// The connection failed or was lost.
+    Error(anyhow::Error),
+    /// The connection was closed.
+    Close,
+}
+
+/// Handles a TCP connection to a FIX 4.4 acceptor for a [`FixSession`].
+///
+/// [`FixCommand`]s are received across an unbounded channel, and [`FixEvent`]s are sent
+/// on a bounded tokio channel back to a message processing task.
+///
+/// The session is retained across calls to [`FixSessionHandler::run`], so the handler
+/// can be run again to reconnect and resume the session sequence numbers (unless the
+/// session is configured to reset on logon).
+#[derive(Debug)]
+pub struct FixSessionHandler {
+    config: FixSessionConfig,
+    session: FixSession,
+    cmd_rx: UnboundedReceiver<FixCommand>,
+    event_tx: Sender<FixEvent>,
+    queued: Vec<FixMessage>,
+}
+
+impl FixSessionHandler {
+    /// Creates a new [`FixSessionHandler`] instance.
+    #[must_use]
+    pub fn new(
+        config: FixSessionConfig,
+        cmd_rx: UnboundedReceiver<FixCommand>,
+        event_tx: Sender<FixEvent>,
+    ) -> Self {
+        let session = FixSession::new(&config);
+        Self {
+            config,
+            session,
+            cmd_rx,
+            event_tx,
+            queued: Vec::new(),
+        }
+    }
+
+    /// Returns a reference to the session.
+    #[must_use]
+    pub const fn session(&self) -> &FixSession {
+        &self.session
+    }
+
+    /// Returns a mutable reference to the session (such as to restore sequence numbers).
+    pub fn session_mut(&mut self) -> &mut FixSession {
+        &mut self.session
+    }
+
+    /// Run the handler to connect and log on, then process commands and messages until
+    /// the session is closed or the connection is lost.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if connecting fails, or the connection is lost.
+    pub async fn run(&mut self) -> anyhow::Result<()> {
+        let addr = format!("{}:{}", self.config.host, self.config.port);
+        tracing::debug!("Connecting to {addr}");
+
+        let timeout = Duration::from_secs(self.config.connection_timeout_secs);
+        let stream = match tokio::time::timeout(timeout, TcpStream::connect(&addr)).await {
+            Ok(Ok(stream)) => stream,
+            Ok(Err(e)) => {
+                return self
+                    .fail(anyhow::anyhow!("Failed to connect to {addr}: {e}"))
+                    .await
+            }
+            Err(_) => {
+                return self
+                    .fail(anyhow::anyhow!("Timeout connecting to {addr}"))
+                    .await
+            }
+        };
+        let (mut reader, mut writer) = stream.into_split();
+        tracing::info!("Connected to {addr}");
+
+        // Read bytes on a separate task, as reading is not cancellation safe
+        let (bytes_tx, mut bytes_rx) = tokio::sync::mpsc::unbounded_channel();
+        let reader_task = tokio::spawn(async move {
+            let mut buf = vec![0; 8192];
+            loop {
+                let result = reader.read(&mut buf).await.map(|n| buf[..n].to_vec());
+                let is_done = result.as_ref().map_or(true, Vec::is_empty);
+                if bytes_tx.send(result).is_err() || is_done {
+                    break;
+                }
+            }
+        });
+
+        let logon = self.session.logon(now());
+        let result = match write_message(&mut writer, &logon).await {
+            Ok(()) => self.run_session(&mut writer, &mut bytes_rx).await,
+            Err(e) => Err(e),
+        };
+
+        reader_task.abort();
+        self.session.on_disconnected(now());
+
+        match result {
+            Ok(()) => {
+                self.send_event(FixEvent::Close).await;
+                Ok(())
+            }
+            Err(e) => self.fail(e).await,
+        }
+    }
+
+    async fn run_session<W: AsyncWrite + Unpin>(
+        &mut self,
+        writer: &mut W,
+        bytes_rx: &mut UnboundedReceiver<std::io::Result<Vec<u8>>>,
+    ) -> anyhow::Result<()> {
+        let mut framer = FixFramer::new();
+        let mut timer = tokio::time::interval(TIMER_INTERVAL);
+        timer.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        let mut is_closing = false;
+
+        loop {
+            let actions = tokio::select! {
+                cmd = self.cmd_rx.recv(), if !is_closing => match cmd {
+                    Some(FixCommand::Send(msg)) => {
+                        if self.session.is_logged_on() {
+                            let msg = self.session.send(msg, now());
+                            write_message(writer, &msg).await?;
+                        } else {
+                            tracing::debug!("Queuing message until logged on: {msg}");
+                            self.queued.push(msg);
+                        }
+                        continue;
+                    }
+                    Some(FixCommand::Logout(text)) => {
+                        let logout = self.session.logout(text.as_deref(), now());
+                        write_message(writer, &logout).await?;
+                        continue;
+                    }
+                    Some(FixCommand::Close) | None => {
+                        tracing::debug!("Closing");
+                        self.cmd_rx.close();
+                        if !self.session.is_logged_on() {
+                            return Ok(());
+                        }
+                        is_closing = true;
+                        let logout = self.session.logout(None, now());
+                        write_message(writer, &logout).await?;
+                        continue;
+                    }
+                },
+                bytes = bytes_rx.recv() => match bytes {
+                    Some(Ok(bytes)) if !bytes.is_empty() => {
+                        framer.extend(&bytes);
+                        let mut actions = Vec::new();
+                        while let Some(msg) = framer.next_message()? {
+                            tracing::trace!("Received {msg}");
+                            actions.extend(self.session.on_message(msg, now()));
+                        }
+                        actions
+                    }
+                    Some(Ok(_)) | None => anyhow::bail!("Connection closed by counterparty"),
+                    Some(Err(e)) => anyhow::bail!("Connection lost: {e}"),
+                },
+                _ = timer.tick() => self.session.on_timer(now()),
+            };
+
+            if let Some(reason) = self.handle_actions(actions, writer).await? {
+                if is_closing || reason == "Logged out" {
+                    return Ok(());
+                }
+                anyhow::bail!("Disconnected: {reason}");
+            }
+        }
+    }
+
+    // Returns the disconnect reason, if the connection should be closed
+    async fn handle_actions<W: AsyncWrite + Unpin>(
+        &mut self,
+        actions: Vec<SessionAction>,
+        writer: &mut W,
+    ) -> anyhow::Result<Option<String>> {
+        for action in actions {
+            match action {
+                SessionAction::Send(msg) => write_message(writer, &msg).await?,
+                SessionAction::LoggedOn => {
+                    self.send_event(FixEvent::LoggedOn).await;
+                    for msg in std::mem::take(&mut self.queued) {
+                        let msg = self.session.send(msg, now());
+                        write_message(writer, &msg).await?;
+                    }
+                }
+                SessionAction::Deliver(msg) => self.send_event(FixEvent::Message(msg)).await,
+                SessionAction::LoggedOut(text) => {
+                    self.send_event(FixEvent::LoggedOut(text)).await;
+                }
+                SessionAction::Disconnect(reason) => return Ok(Some(reason)),
+            }
+        }
+        Ok(None)
+    }
+
+    async fn fail(&mut self, e: anyhow::Error) -> anyhow::Result<()> {
+        tracing::error!("{e}");
+        self.send_event(FixEvent::Error(anyhow::anyhow!("{e}")))
+            .await;
+        Err(e)
+    }
+
+    async fn send_event(&mut self, event: FixEvent) {
+        tracing::trace!("Sending {event:?}");
+        if let Err(e) = self.event_tx.send(event).await {
+            tracing::error!("Error sending event: {e}");
+        }
+    }
+}
+
+async fn write_message<W: AsyncWrite + Unpin>(
+    writer: &mut W,
+    msg: &FixMessage,
+) -> anyhow::Result<()> {
+    tracing::trace!("Sending {msg}");
+    writer.write_all(&msg.encode()).await?;
+    Ok(())
+}
+
+fn now() -> UnixNanos {
+    get_atomic_clock_realtime().get_time_ns()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use tokio::{
+        net::TcpListener,
+        sync::mpsc::{self, Receiver},
+    };
+
+    use super::*;
+    use crate::{
+        messages::ExecutionReport,
+        tags::{msg_type, tag},
+    };
+
+    async fn drain_events(mut event_rx: Receiver<FixEvent>) -> Vec<FixEvent> {
+        let mut events = Vec::new();
+        while let Some(event) = event_rx.recv().await {
+            events.push(event);
+        }
+        events
+    }
+
+    async fn read_message(stream: &mut TcpStream, framer: &mut FixFramer) -> FixMessage {
+        let mut buf = vec![0; 1024];
+        loop {
+            if let Some(msg) = framer.next_message().unwrap() {
+                return msg;
+            }
+            let n = stream.read(&mut buf).await.unwrap();
+            assert!(n > 0, "Connection closed");
+            framer.extend(&buf[..n]);
+        }
+    }
+
+    fn acceptor_message(msg_type: &str, seq: u64) -> FixMessage {
+        FixMessage::new(msg_type)
+            .with(tag::SENDER_COMP_ID, "SERVER")
+            .with(tag::TARGET_COMP_ID, "CLIENT")
+            .with(tag::MSG_SEQ_NUM, seq)
+            .with(tag::SENDING_TIME, "20250101-00:00:00.000")
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler_logs_on_sends_order_and_receives_execution_report() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        // Mock acceptor
+        let acceptor = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut framer = FixFramer::new();
+
+            let logon = read_message(&mut stream, &mut framer).await;
+            assert_eq!(logon.msg_type(), msg_type::LOGON);
+            assert_eq!(logon.seq_num(), Some(1));
+            let response = acceptor_message(msg_type::LOGON, 1).with(tag::HEART_BT_INT, 30);
+            stream.write_all(&response.encode()).await.unwrap();
+
+            // The order queued before logon is sent once logged on
+            let order = read_message(&mut stream, &mut framer).await;
+            assert_eq!(order.msg_type(), msg_type::NEW_ORDER_SINGLE);
+            assert_eq!(order.seq_num(), Some(2));
+            assert_eq!(order.get(tag::CL_ORD_ID), Some("O-1"));
+            let report = acceptor_message(msg_type::EXECUTION_REPORT, 2)
+                .with(tag::ORDER_ID, "12345")
+                .with(tag::CL_ORD_ID, "O-1")
+                .with(tag::EXEC_ID, "E-1")
+                .with(tag::EXEC_TYPE, "0")
+                .with(tag::ORD_STATUS, "0")
+                .with(tag::SYMBOL, "AAPL")
+                .with(tag::SIDE, "1")
+                .with(tag::LEAVES_QTY, "100")
+                .with(tag::CUM_QTY, "0");
+            stream.write_all(&report.encode()).await.unwrap();
+
+            let logout = read_message(&mut stream, &mut framer).await;
+            assert_eq!(logout.msg_type(), msg_type::LOGOUT);
+            assert_eq!(logout.seq_num(), Some(3));
+            let response = acceptor_message(msg_type::LOGOUT, 3);
+            stream.write_all(&response.encode()).await.unwrap();
+        });
+
+        let config = FixSessionConfig {
+            port,
+            ..Default::default()
+        };
+        let (cmd_tx, cmd_rx) = mpsc::unbounded_channel();
+        let (event_tx, mut event_rx) = mpsc::channel(100);
+        let mut handler = FixSessionHandler::new(config, cmd_rx, event_tx);
+        cmd_tx
+            .send(FixCommand::Send(
+                FixMessage::new(msg_type::NEW_ORDER_SINGLE).with(tag::CL_ORD_ID, "O-1"),
+            ))
+            .unwrap();
+
+        let handler_task = tokio::spawn(async move {
+            let result = handler.run().await;
+            (handler, result)
+        });
+
+        assert!(matches!(event_rx.recv().await, Some(FixEvent::LoggedOn)));
+        let Some(FixEvent::Message(msg)) = event_rx.recv().await else {
+            panic!("Expected execution report");
+        };
+        let report = ExecutionReport::from_message(&msg).unwrap();
+        assert_eq!(report.exec_id, "E-1");
+
+        cmd_tx.send(FixCommand::Close).unwrap();
+        let (handler, result) = handler_task.await.unwrap();
+        acceptor.await.unwrap();
+        drop(handler);
+
+        assert!(result.is_ok());
+        let events = drain_events(event_rx).await;
+        assert!(matches!(
+            &events[..],
+            [FixEvent::LoggedOut(None), FixEvent::Close]
+        ));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_handler_connection_closed_returns_error() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let acceptor = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            drop(stream);
+        });
+
+        let config = FixSessionConfig {
+            port,
+            ..Default::default()
+        };
+        let (_cmd_tx, cmd_rx) = mpsc::unbounded_channel();
+        let (event_tx, event_rx) = mpsc::channel(100);
+        let mut handler = FixSessionHandler::new(config, cmd_rx, event_tx);
+
+        let result = handler.run().await;
+        acceptor.await.unwrap();
+        drop(handler);
+
+        assert!(result.is_err());
+        let events = drain_events(event_rx).await;
+        assert!(matches!(&events[..], [FixEvent::Error(_)]));
+    }
+}
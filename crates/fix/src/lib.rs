@@ -0,0 +1,37 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A [FIX 4.4](https://www.fixtrading.org/standards/fix-4-4/) engine for order entry.
+//!
+//! Provides the session layer (logon, heartbeats, test requests, sequence resets and
+//! resend requests) and the application messages for order entry (`NewOrderSingle`,
+//! `OrderCancelRequest`, `OrderCancelReplaceRequest` and `ExecutionReport`), so that
+//! institutional venues and prime brokers can be integrated without a third-party bridge.
+//!
+//! The [`session::FixSession`] state machine is independent of any I/O, and is driven
+//! over a TCP connection by the [`handler::FixSessionHandler`].
+
+#![warn(rustc::all)]
+#![deny(unsafe_code)]
+#![deny(nonstandard_style)]
+#![deny(missing_debug_implementations)]
+#![deny(rustdoc::broken_intra_doc_links)]
+
+pub mod config;
+pub mod handler;
+pub mod message;
+pub mod messages;
+pub mod session;
+pub mod tags;
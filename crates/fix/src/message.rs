@@ -0,0 +1,560 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! FIX tag=value message encoding, decoding and framing.
+//!
+//! Messages are sequences of `tag=value` fields delimited by SOH (0x01), starting with the
+//! `BeginString(8)` and `BodyLength(9)` fields and ending with the `CheckSum(10)` field.
+
+use std::{fmt::Display, str::FromStr};
+
+use chrono::{DateTime, NaiveDateTime};
+use nautilus_core::UnixNanos;
+
+use crate::tags::tag;
+
+/// The field delimiter.
+pub const SOH: u8 = 0x01;
+
+/// The `BeginString(8)` of FIX 4.4 messages.
+pub const BEGIN_STRING: &str = "FIX.4.4";
+
+/// The maximum body length accepted from the counterparty.
+pub const MAX_BODY_LENGTH: usize = 1_000_000;
+
+/// The standard header fields, which are encoded first (in this order) after the `MsgType(35)`.
+const HEADER_TAGS: [u32; 6] = [
+    tag::SENDER_COMP_ID,
+    tag::TARGET_COMP_ID,
+    tag::MSG_SEQ_NUM,
+    tag::POSS_DUP_FLAG,
+    tag::SENDING_TIME,
+    tag::ORIG_SENDING_TIME,
+];
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The error that could happen while encoding or decoding FIX messages.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The message is not a well formed sequence of `tag=value` fields.
+    #[error("Malformed message: {0}")]
+    Malformed(String),
+    /// The `BeginString(8)` is not FIX 4.4.
+    #[error("Unsupported BeginString '{0}'")]
+    UnsupportedBeginString(String),
+    /// The `BodyLength(9)` does not match the message.
+    #[error("Invalid BodyLength {declared} (actual {actual})")]
+    InvalidBodyLength {
+        /// The declared body length.
+        declared: usize,
+        /// The actual body length.
+        actual: usize,
+    },
+    /// The `CheckSum(10)` does not match the message.
+    #[error("Invalid CheckSum {declared} (calculated {calculated})")]
+    InvalidCheckSum {
+        /// The declared checksum.
+        declared: String,
+        /// The calculated checksum.
+        calculated: String,
+    },
+    /// A required field is missing.
+    #[error("Missing required field {0}")]
+    MissingField(u32),
+    /// A field value could not be parsed as the expected type.
+    #[error("Invalid value '{value}' for field {tag}")]
+    InvalidField {
+        /// The field tag.
+        tag: u32,
+        /// The raw field value.
+        value: String,
+    },
+}
+
+/// Represents a FIX message.
+///
+/// The `BeginString(8)`, `BodyLength(9)` and `CheckSum(10)` fields are derived when the
+/// message is encoded, so the fields hold the standard header fields (such as
+/// `MsgSeqNum(34)`) and the message body in order. Repeated tags are retained in order,
+/// for repeating groups.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixMessage {
+    msg_type: String,
+    fields: Vec<(u32, String)>,
+}
+
+impl FixMessage {
+    /// Creates a new empty [`FixMessage`] instance of the given message type.
+    #[must_use]
+    pub fn new(msg_type: &str) -> Self {
+        Self {
+            msg_type: msg_type.to_string(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// Returns the `MsgType(35)` of the message.
+    #[must_use]
+    pub fn msg_type(&self) -> &str {
+        &self.msg_type
+    }
+
+    /// Returns the fields of the message (excluding the `BeginString(8)`, `BodyLength(9)`,
+    /// `MsgType(35)` and `CheckSum(10)` fields).
+    #[must_use]
+    pub fn fields(&self) -> &[(u32, String)] {
+        &self.fields
+    }
+
+    /// Appends the field to the message, returning the message (for building messages).
+    #[must_use]
+    pub fn with(mut self, tag: u32, value: impl Display) -> Self {
+        self.push(tag, value);
+        self
+    }
+
+    /// Appends the field to the message.
+    pub fn push(&mut self, tag: u32, value: impl Display) {
+        self.fields.push((tag, value.to_string()));
+    }
+
+    /// Sets the value of the field, replacing the first occurrence of the tag (if any).
+    pub fn set(&mut self, tag: u32, value: impl Display) {
+        let value = value.to_string();
+        match self.fields.iter_mut().find(|(t, _)| *t == tag) {
+            Some(field) => field.1 = value,
+            None => self.fields.push((tag, value)),
+        }
+    }
+
+    /// Removes all occurrences of the field from the message.
+    pub fn remove(&mut self, tag: u32) {
+        self.fields.retain(|(t, _)| *t != tag);
+    }
+
+    /// Returns the value of the first occurrence of the field (if present).
+    #[must_use]
+    pub fn get(&self, tag: u32) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(t, _)| *t == tag)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Returns the parsed value of the field, or `None` if the field is not present.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value cannot be parsed.
+    pub fn get_parsed<T: FromStr>(&self, tag: u32) -> Result<Option<T>> {
+        self.get(tag)
+            .map(|value| {
+                value.parse().map_err(|_| Error::InvalidField {
+                    tag,
+                    value: value.to_string(),
+                })
+            })
+            .transpose()
+    }
+
+    /// Returns the parsed value of the required field.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the field is missing, or the value cannot be parsed.
+    pub fn get_required<T: FromStr>(&self, tag: u32) -> Result<T> {
+        self.get_parsed(tag)?.ok_or(Error::MissingField(tag))
+    }
+
+    /// Returns the `MsgSeqNum(34)` of the message (if present and valid).
+    #[must_use]
+    pub fn seq_num(&self) -> Option<u64> {
+        self.get_parsed(tag::MSG_SEQ_NUM).ok().flatten()
+    }
+
+    /// Returns whether the message has the `PossDupFlag(43)` set.
+    #[must_use]
+    pub fn is_poss_dup(&self) -> bool {
+        self.get(tag::POSS_DUP_FLAG) == Some("Y")
+    }
+
+    /// Encodes the message to bytes, with the `BeginString(8)`, `BodyLength(9)` and
+    /// `CheckSum(10)` fields.
+    #[must_use]
+    pub fn encode(&self) -> Vec<u8> {
+        let mut body = Vec::with_capacity(256);
+        write_field(&mut body, tag::MSG_TYPE, &self.msg_type);
+        for header_tag in HEADER_TAGS {
+            if let Some(value) = self.get(header_tag) {
+                write_field(&mut body, header_tag, value);
+            }
+        }
+        for (t, value) in &self.fields {
+            if !HEADER_TAGS.contains(t) {
+                write_field(&mut body, *t, value);
+            }
+        }
+
+        let mut buf = Vec::with_capacity(body.len() + 32);
+        write_field(&mut buf, tag::BEGIN_STRING, BEGIN_STRING);
+        write_field(&mut buf, tag::BODY_LENGTH, &body.len().to_string());
+        buf.extend_from_slice(&body);
+        let checksum = checksum(&buf);
+        write_field(&mut buf, tag::CHECK_SUM, &checksum);
+        buf
+    }
+
+    /// Decodes a complete message from the given bytes, validating the `BeginString(8)`,
+    /// `BodyLength(9)` and `CheckSum(10)` fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes are not a valid FIX 4.4 message.
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        let text = std::str::from_utf8(bytes)
+            .map_err(|_| Error::Malformed("message is not valid UTF-8".to_string()))?;
+        let text = text
+            .strip_suffix(SOH as char)
+            .ok_or_else(|| Error::Malformed("message does not end with SOH".to_string()))?;
+
+        let mut fields = Vec::new();
+        for field in text.split(SOH as char) {
+            let (t, value) = field
+                .split_once('=')
+                .ok_or_else(|| Error::Malformed(format!("field '{field}' has no '='")))?;
+            let t: u32 = t
+                .parse()
+                .map_err(|_| Error::Malformed(format!("invalid tag '{t}'")))?;
+            fields.push((t, value.to_string()));
+        }
+
+        if fields.len() < 4 {
+            return Err(Error::Malformed("too few fields".to_string()));
+        }
+        let (t, begin_string) = &fields[0];
+        if *t != tag::BEGIN_STRING {
+            return Err(Error::MissingField(tag::BEGIN_STRING));
+        }
+        if begin_string != BEGIN_STRING {
+            return Err(Error::UnsupportedBeginString(begin_string.clone()));
+        }
+
+        let (t, body_length) = &fields[1];
+        if *t != tag::BODY_LENGTH {
+            return Err(Error::MissingField(tag::BODY_LENGTH));
+        }
+        let declared: usize = body_length.parse().map_err(|_| Error::InvalidField {
+            tag: tag::BODY_LENGTH,
+            value: body_length.clone(),
+        })?;
+
+        let (t, declared_checksum) = &fields[fields.len() - 1];
+        if *t != tag::CHECK_SUM {
+            return Err(Error::MissingField(tag::CHECK_SUM));
+        }
+
+        let header_len = format!("8={BEGIN_STRING}\x019={body_length}\x01").len();
+        let trailer_start = bytes.len() - format!("10={declared_checksum}\x01").len();
+        let actual = trailer_start.saturating_sub(header_len);
+        if declared != actual {
+            return Err(Error::InvalidBodyLength { declared, actual });
+        }
+
+        let calculated = checksum(&bytes[..trailer_start]);
+        if *declared_checksum != calculated {
+            return Err(Error::InvalidCheckSum {
+                declared: declared_checksum.clone(),
+                calculated,
+            });
+        }
+
+        let (t, msg_type) = &fields[2];
+        if *t != tag::MSG_TYPE {
+            return Err(Error::MissingField(tag::MSG_TYPE));
+        }
+
+        Ok(Self {
+            msg_type: msg_type.clone(),
+            fields: fields[3..fields.len() - 1].to_vec(),
+        })
+    }
+}
+
+impl Display for FixMessage {
+    // Displays the encoded message with `|` delimiters (for logging)
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let encoded = self.encode();
+        let text = String::from_utf8_lossy(&encoded).replace(SOH as char, "|");
+        write!(f, "{text}")
+    }
+}
+
+fn write_field(buf: &mut Vec<u8>, tag: u32, value: &str) {
+    buf.extend_from_slice(tag.to_string().as_bytes());
+    buf.push(b'=');
+    buf.extend_from_slice(value.as_bytes());
+    buf.push(SOH);
+}
+
+fn checksum(bytes: &[u8]) -> String {
+    let sum = bytes.iter().fold(0_u32, |acc, b| acc + u32::from(*b));
+    format!("{:03}", sum % 256)
+}
+
+/// Frames complete messages from a stream of bytes.
+#[derive(Debug, Default)]
+pub struct FixFramer {
+    buf: Vec<u8>,
+}
+
+impl FixFramer {
+    /// Creates a new empty [`FixFramer`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends the bytes received to the buffer.
+    pub fn extend(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+
+    /// Returns the next complete message from the buffer, or `None` if more bytes are needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffered bytes are not a valid message, in which case the
+    /// stream cannot be resynchronized and the connection should be closed.
+    pub fn next_message(&mut self) -> Result<Option<FixMessage>> {
+        let Some(len) = self.frame_length()? else {
+            return Ok(None);
+        };
+        let frame: Vec<u8> = self.buf.drain(..len).collect();
+        FixMessage::decode(&frame).map(Some)
+    }
+
+    fn frame_length(&self) -> Result<Option<usize>> {
+        let Some(begin_end) = self.buf.iter().position(|b| *b == SOH) else {
+            return Ok(None);
+        };
+        if !self.buf.starts_with(b"8=") {
+            return Err(Error::Malformed(
+                "message does not start with BeginString".to_string(),
+            ));
+        }
+
+        let rest = &self.buf[begin_end + 1..];
+        let Some(length_end) = rest.iter().position(|b| *b == SOH) else {
+            return Ok(None);
+        };
+        let length_field = &rest[..length_end];
+        let declared = length_field
+            .strip_prefix(b"9=")
+            .and_then(|value| std::str::from_utf8(value).ok())
+            .and_then(|value| value.parse::<usize>().ok())
+            .ok_or(Error::MissingField(tag::BODY_LENGTH))?;
+        if declared > MAX_BODY_LENGTH {
+            return Err(Error::InvalidBodyLength {
+                declared,
+                actual: MAX_BODY_LENGTH,
+            });
+        }
+
+        // The trailer is `10=NNN<SOH>` (7 bytes)
+        let len = begin_end + 1 + length_end + 1 + declared + 7;
+        Ok((self.buf.len() >= len).then_some(len))
+    }
+}
+
+/// Formats the UNIX timestamp as a FIX `UTCTimestamp` (with milliseconds).
+#[must_use]
+pub fn format_utc_timestamp(timestamp: UnixNanos) -> String {
+    DateTime::from_timestamp_nanos(timestamp.as_u64() as i64)
+        .format("%Y%m%d-%H:%M:%S%.3f")
+        .to_string()
+}
+
+/// Parses a FIX `UTCTimestamp` (with optional fractional seconds) to a UNIX timestamp.
+///
+/// # Errors
+///
+/// Returns an error if the value is not a valid `UTCTimestamp`.
+pub fn parse_utc_timestamp(tag: u32, value: &str) -> Result<UnixNanos> {
+    NaiveDateTime::parse_from_str(value, "%Y%m%d-%H:%M:%S%.f")
+        .ok()
+        .and_then(|dt| dt.and_utc().timestamp_nanos_opt())
+        .map(|nanos| UnixNanos::from(nanos as u64))
+        .ok_or_else(|| Error::InvalidField {
+            tag,
+            value: value.to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::tags::msg_type;
+
+    fn soh(text: &str) -> Vec<u8> {
+        text.replace('|', "\x01").into_bytes()
+    }
+
+    #[rstest]
+    fn test_encode_heartbeat() {
+        let msg = FixMessage::new(msg_type::HEARTBEAT)
+            .with(tag::SENDING_TIME, "20250101-00:00:00.000")
+            .with(tag::MSG_SEQ_NUM, 2)
+            .with(tag::TARGET_COMP_ID, "SERVER")
+            .with(tag::SENDER_COMP_ID, "CLIENT");
+
+        let bytes = msg.encode();
+
+        // Header fields are encoded first, in the standard order
+        assert_eq!(
+            bytes,
+            soh("8=FIX.4.4|9=55|35=0|49=CLIENT|56=SERVER|34=2|52=20250101-00:00:00.000|10=072|")
+        );
+    }
+
+    #[rstest]
+    fn test_decode_round_trip() {
+        let msg = FixMessage::new(msg_type::NEW_ORDER_SINGLE)
+            .with(tag::SENDER_COMP_ID, "CLIENT")
+            .with(tag::TARGET_COMP_ID, "SERVER")
+            .with(tag::MSG_SEQ_NUM, 10)
+            .with(tag::CL_ORD_ID, "O-1")
+            .with(tag::TEXT, "a=b");
+
+        let decoded = FixMessage::decode(&msg.encode()).unwrap();
+
+        assert_eq!(decoded, msg);
+        assert_eq!(decoded.msg_type(), msg_type::NEW_ORDER_SINGLE);
+        assert_eq!(decoded.seq_num(), Some(10));
+        assert_eq!(decoded.get(tag::TEXT), Some("a=b"));
+    }
+
+    #[rstest]
+    fn test_decode_invalid_checksum() {
+        let bytes = soh("8=FIX.4.4|9=5|35=0|10=000|");
+
+        let result = FixMessage::decode(&bytes);
+
+        assert!(matches!(result, Err(Error::InvalidCheckSum { .. })));
+    }
+
+    #[rstest]
+    fn test_decode_invalid_body_length() {
+        let bytes = soh("8=FIX.4.4|9=6|35=0|10=000|");
+
+        let result = FixMessage::decode(&bytes);
+
+        assert!(matches!(
+            result,
+            Err(Error::InvalidBodyLength {
+                declared: 6,
+                actual: 5
+            })
+        ));
+    }
+
+    #[rstest]
+    fn test_decode_unsupported_begin_string() {
+        let bytes = soh("8=FIX.4.2|9=5|35=0|10=000|");
+
+        let result = FixMessage::decode(&bytes);
+
+        assert!(matches!(result, Err(Error::UnsupportedBeginString(_))));
+    }
+
+    #[rstest]
+    fn test_get_parsed_and_required() {
+        let msg = FixMessage::new(msg_type::EXECUTION_REPORT)
+            .with(tag::CUM_QTY, "1.5")
+            .with(tag::LEAVES_QTY, "abc");
+
+        assert_eq!(msg.get_required::<f64>(tag::CUM_QTY).unwrap(), 1.5);
+        assert!(matches!(
+            msg.get_parsed::<f64>(tag::LEAVES_QTY),
+            Err(Error::InvalidField { tag: 151, .. })
+        ));
+        assert!(matches!(
+            msg.get_required::<f64>(tag::AVG_PX),
+            Err(Error::MissingField(6))
+        ));
+    }
+
+    #[rstest]
+    fn test_set_replaces_and_remove() {
+        let mut msg = FixMessage::new(msg_type::HEARTBEAT).with(tag::MSG_SEQ_NUM, 1);
+
+        msg.set(tag::MSG_SEQ_NUM, 2);
+        msg.set(tag::POSS_DUP_FLAG, "Y");
+
+        assert_eq!(msg.seq_num(), Some(2));
+        assert!(msg.is_poss_dup());
+
+        msg.remove(tag::POSS_DUP_FLAG);
+
+        assert!(!msg.is_poss_dup());
+    }
+
+    #[rstest]
+    fn test_framer_partial_and_multiple_messages() {
+        let first = FixMessage::new(msg_type::HEARTBEAT).with(tag::MSG_SEQ_NUM, 1);
+        let second = FixMessage::new(msg_type::TEST_REQUEST)
+            .with(tag::MSG_SEQ_NUM, 2)
+            .with(tag::TEST_REQ_ID, "TEST");
+        let mut bytes = first.encode();
+        bytes.extend(second.encode());
+        let mut framer = FixFramer::new();
+
+        framer.extend(&bytes[..10]);
+        assert_eq!(framer.next_message().unwrap(), None);
+
+        framer.extend(&bytes[10..]);
+        assert_eq!(framer.next_message().unwrap(), Some(first));
+        assert_eq!(framer.next_message().unwrap(), Some(second));
+        assert_eq!(framer.next_message().unwrap(), None);
+    }
+
+    #[rstest]
+    fn test_framer_garbage_returns_error() {
+        let mut framer = FixFramer::new();
+
+        framer.extend(b"GET / HTTP/1.1\x01");
+
+        assert!(framer.next_message().is_err());
+    }
+
+    #[rstest]
+    #[case("20250101-12:30:45.123", 1_735_734_645_123_000_000)]
+    #[case("20250101-12:30:45", 1_735_734_645_000_000_000)]
+    #[case("20250101-12:30:45.123456", 1_735_734_645_123_456_000)]
+    fn test_parse_utc_timestamp(#[case] value: &str, #[case] expected: u64) {
+        let result = parse_utc_timestamp(tag::TRANSACT_TIME, value).unwrap();
+
+        assert_eq!(result, UnixNanos::from(expected));
+    }
+
+    #[rstest]
+    fn test_format_utc_timestamp() {
+        let result = format_utc_timestamp(UnixNanos::from(1_735_734_645_123_456_789));
+
+        assert_eq!(result, "20250101-12:30:45.123");
+    }
+}
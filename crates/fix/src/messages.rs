@@ -0,0 +1,834 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! FIX 4.4 application messages for order entry.
+//!
+//! Outbound orders are built from Nautilus types, and inbound reports are parsed with
+//! quantities and prices as decimals, as the instrument precisions are not known to the
+//! FIX engine.
+
+use std::str::FromStr;
+
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    enums::{LiquiditySide, OrderSide, OrderStatus, OrderType, TimeInForce},
+    identifiers::{ClientOrderId, VenueOrderId},
+    types::{Price, Quantity},
+};
+use rust_decimal::Decimal;
+
+use crate::{
+    message::{format_utc_timestamp, parse_utc_timestamp, FixMessage},
+    tags::{msg_type, tag},
+};
+
+/// `ExecInst(18)` for post-only (participate don't initiate) orders.
+const EXEC_INST_POST_ONLY: &str = "6";
+
+/// `ExecInst(18)` for reduce-only (do not increase) orders.
+const EXEC_INST_REDUCE_ONLY: &str = "E";
+
+/// The `OrderID(37)` value used when the venue order ID is not known.
+const UNKNOWN_ORDER_ID: &str = "NONE";
+
+/// Returns the FIX `Side(54)` value for the order side.
+///
+/// # Errors
+///
+/// Returns an error if the order side is `NoOrderSide`.
+pub fn side_to_fix(side: OrderSide) -> anyhow::Result<&'static str> {
+    match side {
+        OrderSide::Buy => Ok("1"),
+        OrderSide::Sell => Ok("2"),
+        OrderSide::NoOrderSide => anyhow::bail!("Invalid order side {side}"),
+    }
+}
+
+/// Returns the FIX `OrdType(40)` value for the order type.
+///
+/// # Errors
+///
+/// Returns an error if the order type has no FIX 4.4 equivalent.
+pub fn order_type_to_fix(order_type: OrderType) -> anyhow::Result<&'static str> {
+    match order_type {
+        OrderType::Market => Ok("1"),
+        OrderType::Limit => Ok("2"),
+        OrderType::StopMarket => Ok("3"),
+        OrderType::StopLimit => Ok("4"),
+        OrderType::MarketIfTouched => Ok("J"),
+        OrderType::MarketToLimit => Ok("K"),
+        _ => anyhow::bail!("Unsupported order type {order_type} for FIX 4.4"),
+    }
+}
+
+/// Returns the FIX `TimeInForce(59)` value for the time in force.
+#[must_use]
+pub const fn time_in_force_to_fix(time_in_force: TimeInForce) -> &'static str {
+    match time_in_force {
+        TimeInForce::Day => "0",
+        TimeInForce::Gtc => "1",
+        TimeInForce::AtTheOpen => "2",
+        TimeInForce::Ioc => "3",
+        TimeInForce::Fok => "4",
+        TimeInForce::Gtx => "5",
+        TimeInForce::Gtd => "6",
+        TimeInForce::AtTheClose => "7",
+    }
+}
+
+/// Parses the FIX `Side(54)` value (short sales are sells).
+///
+/// # Errors
+///
+/// Returns an error if the value is not a buy or sell side.
+pub fn parse_side(value: &str) -> anyhow::Result<OrderSide> {
+    match value {
+        "1" => Ok(OrderSide::Buy),
+        "2" | "5" | "6" => Ok(OrderSide::Sell),
+        _ => anyhow::bail!("Unsupported Side '{value}'"),
+    }
+}
+
+/// Parses the FIX `OrdType(40)` value.
+///
+/// # Errors
+///
+/// Returns an error if the value has no Nautilus equivalent.
+pub fn parse_order_type(value: &str) -> anyhow::Result<OrderType> {
+    match value {
+        "1" => Ok(OrderType::Market),
+        "2" => Ok(OrderType::Limit),
+        "3" => Ok(OrderType::StopMarket),
+        "4" => Ok(OrderType::StopLimit),
+        "J" => Ok(OrderType::MarketIfTouched),
+        "K" => Ok(OrderType::MarketToLimit),
+        _ => anyhow::bail!("Unsupported OrdType '{value}'"),
+    }
+}
+
+/// Parses the FIX `TimeInForce(59)` value.
+///
+/// # Errors
+///
+/// Returns an error if the value has no Nautilus equivalent.
+pub fn parse_time_in_force(value: &str) -> anyhow::Result<TimeInForce> {
+    match value {
+        "0" => Ok(TimeInForce::Day),
+        "1" => Ok(TimeInForce::Gtc),
+        "2" => Ok(TimeInForce::AtTheOpen),
+        "3" => Ok(TimeInForce::Ioc),
+        "4" => Ok(TimeInForce::Fok),
+        "5" => Ok(TimeInForce::Gtx),
+        "6" => Ok(TimeInForce::Gtd),
+        "7" => Ok(TimeInForce::AtTheClose),
+        _ => anyhow::bail!("Unsupported TimeInForce '{value}'"),
+    }
+}
+
+/// Parses the FIX `OrdStatus(39)` value.
+///
+/// Statuses for which the order remains working (such as `DoneForDay` and `Suspended`)
+/// are accepted.
+///
+/// # Errors
+///
+/// Returns an error if the value is not a valid `OrdStatus`.
+pub fn parse_order_status(value: &str) -> anyhow::Result<OrderStatus> {
+    match value {
+        "0" | "3" | "5" | "7" | "9" | "B" | "D" => Ok(OrderStatus::Accepted),
+        "1" => Ok(OrderStatus::PartiallyFilled),
+        "2" => Ok(OrderStatus::Filled),
+        "4" => Ok(OrderStatus::Canceled),
+        "6" => Ok(OrderStatus::PendingCancel),
+        "8" => Ok(OrderStatus::Rejected),
+        "A" => Ok(OrderStatus::Submitted),
+        "C" => Ok(OrderStatus::Expired),
+        "E" => Ok(OrderStatus::PendingUpdate),
+        _ => anyhow::bail!("Invalid OrdStatus '{value}'"),
+    }
+}
+
+/// Represents the FIX `ExecType(150)` of an execution report.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FixExecType {
+    New,
+    DoneForDay,
+    Canceled,
+    Replaced,
+    PendingCancel,
+    Stopped,
+    Rejected,
+    Suspended,
+    PendingNew,
+    Calculated,
+    Expired,
+    Restated,
+    PendingReplace,
+    Trade,
+    TradeCorrect,
+    TradeCancel,
+    OrderStatus,
+}
+
+impl FromStr for FixExecType {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> anyhow::Result<Self> {
+        Ok(match value {
+            "0" => Self::New,
+            "3" => Self::DoneForDay,
+            "4" => Self::Canceled,
+            "5" => Self::Replaced,
+            "6" => Self::PendingCancel,
+            "7" => Self::Stopped,
+            "8" => Self::Rejected,
+            "9" => Self::Suspended,
+            "A" => Self::PendingNew,
+            "B" => Self::Calculated,
+            "C" => Self::Expired,
+            "D" => Self::Restated,
+            "E" => Self::PendingReplace,
+            "F" => Self::Trade,
+            "G" => Self::TradeCorrect,
+            "H" => Self::TradeCancel,
+            "I" => Self::OrderStatus,
+            _ => anyhow::bail!("Invalid ExecType '{value}'"),
+        })
+    }
+}
+
+/// Represents a `NewOrderSingle <D>` message.
+#[derive(Clone, Debug)]
+pub struct NewOrderSingle {
+    pub client_order_id: ClientOrderId,
+    pub symbol: String,
+    pub side: OrderSide,
+    pub order_type: OrderType,
+    pub quantity: Quantity,
+    pub price: Option<Price>,
+    pub trigger_price: Option<Price>,
+    pub time_in_force: TimeInForce,
+    pub expire_time: Option<UnixNanos>,
+    pub display_qty: Option<Quantity>,
+    pub is_post_only: bool,
+    pub is_reduce_only: bool,
+    pub account: Option<String>,
+    pub transact_time: UnixNanos,
+}
+
+impl NewOrderSingle {
+    /// Returns the FIX message for the order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order side or type is not supported, or a required price
+    /// for the order type is missing.
+    pub fn to_message(&self) -> anyhow::Result<FixMessage> {
+        let mut msg =
+            FixMessage::new(msg_type::NEW_ORDER_SINGLE).with(tag::CL_ORD_ID, self.client_order_id);
+        push_optional(&mut msg, tag::ACCOUNT, self.account.as_ref());
+        push_exec_inst(&mut msg, self.is_post_only, self.is_reduce_only);
+        push_optional(&mut msg, tag::MAX_FLOOR, self.display_qty);
+        msg.push(tag::SYMBOL, &self.symbol);
+        msg.push(tag::SIDE, side_to_fix(self.side)?);
+        msg.push(tag::TRANSACT_TIME, format_utc_timestamp(self.transact_time));
+        msg.push(tag::ORDER_QTY, self.quantity);
+        push_order_type(
+            &mut msg,
+            self.order_type,
+            self.price,
+            self.trigger_price,
+            self.time_in_force,
+            self.expire_time,
+        )?;
+        Ok(msg)
+    }
+}
+
+/// Represents an `OrderCancelRequest <F>` message.
+#[derive(Clone, Debug)]
+pub struct OrderCancelRequest {
+    pub client_order_id: ClientOrderId,
+    pub orig_client_order_id: ClientOrderId,
+    pub venue_order_id: Option<VenueOrderId>,
+    pub symbol: String,
+    pub side: OrderSide,
+    pub quantity: Option<Quantity>,
+    pub account: Option<String>,
+    pub transact_time: UnixNanos,
+}
+
+impl OrderCancelRequest {
+    /// Returns the FIX message for the cancel request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order side is not supported.
+    pub fn to_message(&self) -> anyhow::Result<FixMessage> {
+        let mut msg = FixMessage::new(msg_type::ORDER_CANCEL_REQUEST)
+            .with(tag::ORIG_CL_ORD_ID, self.orig_client_order_id);
+        push_optional(&mut msg, tag::ORDER_ID, self.venue_order_id);
+        msg.push(tag::CL_ORD_ID, self.client_order_id);
+        push_optional(&mut msg, tag::ACCOUNT, self.account.as_ref());
+        msg.push(tag::SYMBOL, &self.symbol);
+        msg.push(tag::SIDE, side_to_fix(self.side)?);
+        msg.push(tag::TRANSACT_TIME, format_utc_timestamp(self.transact_time));
+        push_optional(&mut msg, tag::ORDER_QTY, self.quantity);
+        Ok(msg)
+    }
+}
+
+/// Represents an `OrderCancelReplaceRequest <G>` message.
+///
+/// The request replaces the whole order, so all order fields are sent (not only those
+/// being modified).
+#[derive(Clone, Debug)]
+pub struct OrderCancelReplaceRequest {
+    pub client_order_id: ClientOrderId,
+    pub orig_client_order_id: ClientOrderId,
+    pub venue_order_id: Option<VenueOrderId>,
+    pub symbol: String,
+    pub side: OrderSide,
+    pub order_type: OrderType,
+    pub quantity: Quantity,
+    pub price: Option<Price>,
+    pub trigger_price: Option<Price>,
+    pub time_in_force: TimeInForce,
+    pub expire_time: Option<UnixNanos>,
+    pub account: Option<String>,
+    pub transact_time: UnixNanos,
+}
+
+impl OrderCancelReplaceRequest {
+    /// Returns the FIX message for the cancel/replace request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order side or type is not supported, or a required price
+    /// for the order type is missing.
+    pub fn to_message(&self) -> anyhow::Result<FixMessage> {
+        let mut msg = FixMessage::new(msg_type::ORDER_CANCEL_REPLACE_REQUEST);
+        push_optional(&mut msg, tag::ORDER_ID, self.venue_order_id);
+        msg.push(tag::ORIG_CL_ORD_ID, self.orig_client_order_id);
+        msg.push(tag::CL_ORD_ID, self.client_order_id);
+        push_optional(&mut msg, tag::ACCOUNT, self.account.as_ref());
+        msg.push(tag::SYMBOL, &self.symbol);
+        msg.push(tag::SIDE, side_to_fix(self.side)?);
+        msg.push(tag::TRANSACT_TIME, format_utc_timestamp(self.transact_time));
+        msg.push(tag::ORDER_QTY, self.quantity);
+        push_order_type(
+            &mut msg,
+            self.order_type,
+            self.price,
+            self.trigger_price,
+            self.time_in_force,
+            self.expire_time,
+        )?;
+        Ok(msg)
+    }
+}
+
+/// Represents an `ExecutionReport <8>` message.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExecutionReport {
+    pub venue_order_id: Option<VenueOrderId>,
+    pub client_order_id: Option<ClientOrderId>,
+    pub orig_client_order_id: Option<ClientOrderId>,
+    pub exec_id: String,
+    pub exec_type: FixExecType,
+    pub order_status: OrderStatus,
+    pub symbol: String,
+    pub side: OrderSide,
+    pub order_type: Option<OrderType>,
+    pub time_in_force: Option<TimeInForce>,
+    pub quantity: Option<Decimal>,
+    pub price: Option<Decimal>,
+    pub trigger_price: Option<Decimal>,
+    pub last_qty: Option<Decimal>,
+    pub last_px: Option<Decimal>,
+    pub leaves_qty: Decimal,
+    pub cum_qty: Decimal,
+    pub avg_px: Option<Decimal>,
+    pub commission: Option<Decimal>,
+    pub currency: Option<String>,
+    pub liquidity_side: LiquiditySide,
+    pub ord_rej_reason: Option<u32>,
+    pub text: Option<String>,
+    pub transact_time: Option<UnixNanos>,
+}
+
+impl ExecutionReport {
+    /// Parses the execution report from the FIX message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message is not an execution report, or a required field
+    /// is missing or invalid.
+    pub fn from_message(msg: &FixMessage) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            msg.msg_type() == msg_type::EXECUTION_REPORT,
+            "Expected ExecutionReport, was MsgType {}",
+            msg.msg_type()
+        );
+
+        let liquidity_side = match msg.get(tag::LAST_LIQUIDITY_IND) {
+            Some("1") => LiquiditySide::Maker,
+            Some("2") => LiquiditySide::Taker,
+            _ => LiquiditySide::NoLiquiditySide,
+        };
+
+        Ok(Self {
+            venue_order_id: parse_venue_order_id(msg),
+            client_order_id: msg.get(tag::CL_ORD_ID).map(ClientOrderId::new),
+            orig_client_order_id: msg.get(tag::ORIG_CL_ORD_ID).map(ClientOrderId::new),
+            exec_id: msg.get_required(tag::EXEC_ID)?,
+            exec_type: msg.get_required(tag::EXEC_TYPE)?,
+            order_status: parse_order_status(&msg.get_required::<String>(tag::ORD_STATUS)?)?,
+            symbol: msg.get_required(tag::SYMBOL)?,
+            side: parse_side(&msg.get_required::<String>(tag::SIDE)?)?,
+            order_type: msg.get(tag::ORD_TYPE).map(parse_order_type).transpose()?,
+            time_in_force: msg
+                .get(tag::TIME_IN_FORCE)
+                .map(parse_time_in_force)
+                .transpose()?,
+            quantity: msg.get_parsed(tag::ORDER_QTY)?,
+            price: msg.get_parsed(tag::PRICE)?,
+            trigger_price: msg.get_parsed(tag::STOP_PX)?,
+            last_qty: msg.get_parsed(tag::LAST_QTY)?,
+            last_px: msg.get_parsed(tag::LAST_PX)?,
+            leaves_qty: msg.get_required(tag::LEAVES_QTY)?,
+            cum_qty: msg.get_required(tag::CUM_QTY)?,
+            avg_px: msg.get_parsed(tag::AVG_PX)?,
+            commission: msg.get_parsed(tag::COMMISSION)?,
+            currency: msg.get(tag::CURRENCY).map(ToString::to_string),
+            liquidity_side,
+            ord_rej_reason: msg.get_parsed(tag::ORD_REJ_REASON)?,
+            text: msg.get(tag::TEXT).map(ToString::to_string),
+            transact_time: msg
+                .get(tag::TRANSACT_TIME)
+                .map(|value| parse_utc_timestamp(tag::TRANSACT_TIME, value))
+                .transpose()?,
+        })
+    }
+
+    /// Returns whether the report is for a fill (with the last quantity and price).
+    #[must_use]
+    pub fn is_fill(&self) -> bool {
+        self.exec_type == FixExecType::Trade
+    }
+}
+
+/// Represents the request an `OrderCancelReject <9>` responds to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CxlRejResponseTo {
+    OrderCancelRequest,
+    OrderCancelReplaceRequest,
+}
+
+/// Represents an `OrderCancelReject <9>` message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrderCancelReject {
+    pub venue_order_id: Option<VenueOrderId>,
+    pub client_order_id: ClientOrderId,
+    pub orig_client_order_id: Option<ClientOrderId>,
+    pub order_status: OrderStatus,
+    pub response_to: CxlRejResponseTo,
+    pub reason: Option<u32>,
+    pub text: Option<String>,
+}
+
+impl OrderCancelReject {
+    /// Parses the cancel reject from the FIX message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message is not a cancel reject, or a required field is
+    /// missing or invalid.
+    pub fn from_message(msg: &FixMessage) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            msg.msg_type() == msg_type::ORDER_CANCEL_REJECT,
+            "Expected OrderCancelReject, was MsgType {}",
+            msg.msg_type()
+        );
+
+        let response_to = match msg
+            .get_required::<String>(tag::CXL_REJ_RESPONSE_TO)?
+            .as_str()
+        {
+            "1" => CxlRejResponseTo::OrderCancelRequest,
+            "2" => CxlRejResponseTo::OrderCancelReplaceRequest,
+            value => anyhow::bail!("Invalid CxlRejResponseTo '{value}'"),
+        };
+
+        Ok(Self {
+            venue_order_id: parse_venue_order_id(msg),
+            client_order_id: ClientOrderId::new(msg.get_required::<String>(tag::CL_ORD_ID)?),
+            orig_client_order_id: msg.get(tag::ORIG_CL_ORD_ID).map(ClientOrderId::new),
+            order_status: parse_order_status(&msg.get_required::<String>(tag::ORD_STATUS)?)?,
+            response_to,
+            reason: msg.get_parsed(tag::CXL_REJ_REASON)?,
+            text: msg.get(tag::TEXT).map(ToString::to_string),
+        })
+    }
+}
+
+fn parse_venue_order_id(msg: &FixMessage) -> Option<VenueOrderId> {
+    msg.get(tag::ORDER_ID)
+        .filter(|value| !value.is_empty() && *value != UNKNOWN_ORDER_ID)
+        .map(VenueOrderId::new)
+}
+
+fn push_optional<T: std::fmt::Display>(msg: &mut FixMessage, tag: u32, value: Option<T>) {
+    if let Some(value) = value {
+        msg.push(tag, value);
+    }
+}
+
+fn push_exec_inst(msg: &mut FixMessage, is_post_only: bool, is_reduce_only: bool) {
+    // Multiple instructions are space delimited
+    let exec_inst: Vec<&str> = [
+        (is_post_only, EXEC_INST_POST_ONLY),
+        (is_reduce_only, EXEC_INST_REDUCE_ONLY),
+    ]
+    .into_iter()
+    .filter_map(|(is_set, value)| is_set.then_some(value))
+    .collect();
+    if !exec_inst.is_empty() {
+        msg.push(tag::EXEC_INST, exec_inst.join(" "));
+    }
+}
+
+fn push_order_type(
+    msg: &mut FixMessage,
+    order_type: OrderType,
+    price: Option<Price>,
+    trigger_price: Option<Price>,
+    time_in_force: TimeInForce,
+    expire_time: Option<UnixNanos>,
+) -> anyhow::Result<()> {
+    msg.push(tag::ORD_TYPE, order_type_to_fix(order_type)?);
+    if matches!(order_type, OrderType::Limit | OrderType::StopLimit) {
+        let price = price.ok_or_else(|| anyhow::anyhow!("{order_type} order requires a price"))?;
+        msg.push(tag::PRICE, price);
+    }
+    if matches!(
+        order_type,
+        OrderType::StopMarket | OrderType::StopLimit | OrderType::MarketIfTouched
+    ) {
+        let trigger_price = trigger_price
+            .ok_or_else(|| anyhow::anyhow!("{order_type} order requires a trigger price"))?;
+        msg.push(tag::STOP_PX, trigger_price);
+    }
+    msg.push(tag::TIME_IN_FORCE, time_in_force_to_fix(time_in_force));
+    if time_in_force == TimeInForce::Gtd {
+        let expire_time =
+            expire_time.ok_or_else(|| anyhow::anyhow!("GTD order requires an expire time"))?;
+        msg.push(tag::EXPIRE_TIME, format_utc_timestamp(expire_time));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    const TRANSACT_TIME: u64 = 1_735_689_600_000_000_000;
+
+    fn new_order_single() -> NewOrderSingle {
+        NewOrderSingle {
+            client_order_id: ClientOrderId::new("O-1"),
+            symbol: "AAPL".to_string(),
+            side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            quantity: Quantity::from(100),
+            price: Some(Price::from("189.50")),
+            trigger_price: None,
+            time_in_force: TimeInForce::Day,
+            expire_time: None,
+            display_qty: None,
+            is_post_only: false,
+            is_reduce_only: false,
+            account: Some("ACC-1".to_string()),
+            transact_time: UnixNanos::from(TRANSACT_TIME),
+        }
+    }
+
+    fn fields(msg: &FixMessage) -> Vec<(u32, &str)> {
+        msg.fields().iter().map(|(t, v)| (*t, v.as_str())).collect()
+    }
+
+    #[rstest]
+    fn test_new_order_single_limit() {
+        let msg = new_order_single().to_message().unwrap();
+
+        assert_eq!(msg.msg_type(), msg_type::NEW_ORDER_SINGLE);
+        assert_eq!(
+            fields(&msg),
+            vec![
+                (tag::CL_ORD_ID, "O-1"),
+                (tag::ACCOUNT, "ACC-1"),
+                (tag::SYMBOL, "AAPL"),
+                (tag::SIDE, "1"),
+                (tag::TRANSACT_TIME, "20250101-00:00:00.000"),
+                (tag::ORDER_QTY, "100"),
+                (tag::ORD_TYPE, "2"),
+                (tag::PRICE, "189.50"),
+                (tag::TIME_IN_FORCE, "0"),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_new_order_single_stop_limit_gtd_post_only() {
+        let order = NewOrderSingle {
+            side: OrderSide::Sell,
+            order_type: OrderType::StopLimit,
+            trigger_price: Some(Price::from("190.00")),
+            time_in_force: TimeInForce::Gtd,
+            expire_time: Some(UnixNanos::from(TRANSACT_TIME + 3_600_000_000_000)),
+            display_qty: Some(Quantity::from(10)),
+            is_post_only: true,
+            is_reduce_only: true,
+            account: None,
+            ..new_order_single()
+        };
+
+        let msg = order.to_message().unwrap();
+
+        assert_eq!(msg.get(tag::SIDE), Some("2"));
+        assert_eq!(msg.get(tag::ORD_TYPE), Some("4"));
+        assert_eq!(msg.get(tag::PRICE), Some("189.50"));
+        assert_eq!(msg.get(tag::STOP_PX), Some("190.00"));
+        assert_eq!(msg.get(tag::TIME_IN_FORCE), Some("6"));
+        assert_eq!(msg.get(tag::EXPIRE_TIME), Some("20250101-01:00:00.000"));
+        assert_eq!(msg.get(tag::EXEC_INST), Some("6 E"));
+        assert_eq!(msg.get(tag::MAX_FLOOR), Some("10"));
+        assert_eq!(msg.get(tag::ACCOUNT), None);
+    }
+
+    #[rstest]
+    #[case(OrderType::Limit, None, None, "requires a price")]
+    #[case(OrderType::StopMarket, None, None, "requires a trigger price")]
+    #[case(OrderType::LimitIfTouched, None, None, "Unsupported order type")]
+    fn test_new_order_single_invalid(
+        #[case] order_type: OrderType,
+        #[case] price: Option<Price>,
+        #[case] trigger_price: Option<Price>,
+        #[case] expected: &str,
+    ) {
+        let order = NewOrderSingle {
+            order_type,
+            price,
+            trigger_price,
+            ..new_order_single()
+        };
+
+        let result = order.to_message();
+
+        assert!(result.unwrap_err().to_string().contains(expected));
+    }
+
+    #[rstest]
+    fn test_order_cancel_request() {
+        let request = OrderCancelRequest {
+            client_order_id: ClientOrderId::new("O-1-C"),
+            orig_client_order_id: ClientOrderId::new("O-1"),
+            venue_order_id: Some(VenueOrderId::new("12345")),
+            symbol: "AAPL".to_string(),
+            side: OrderSide::Buy,
+            quantity: Some(Quantity::from(100)),
+            account: None,
+            transact_time: UnixNanos::from(TRANSACT_TIME),
+        };
+
+        let msg = request.to_message().unwrap();
+
+        assert_eq!(msg.msg_type(), msg_type::ORDER_CANCEL_REQUEST);
+        assert_eq!(
+            fields(&msg),
+            vec![
+                (tag::ORIG_CL_ORD_ID, "O-1"),
+                (tag::ORDER_ID, "12345"),
+                (tag::CL_ORD_ID, "O-1-C"),
+                (tag::SYMBOL, "AAPL"),
+                (tag::SIDE, "1"),
+                (tag::TRANSACT_TIME, "20250101-00:00:00.000"),
+                (tag::ORDER_QTY, "100"),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_order_cancel_replace_request() {
+        let request = OrderCancelReplaceRequest {
+            client_order_id: ClientOrderId::new("O-1-R"),
+            orig_client_order_id: ClientOrderId::new("O-1"),
+            venue_order_id: None,
+            symbol: "AAPL".to_string(),
+            side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            quantity: Quantity::from(200),
+            price: Some(Price::from("189.45")),
+            trigger_price: None,
+            time_in_force: TimeInForce::Gtc,
+            expire_time: None,
+            account: None,
+            transact_time: UnixNanos::from(TRANSACT_TIME),
+        };
+
+        let msg = request.to_message().unwrap();
+
+        assert_eq!(msg.msg_type(), msg_type::ORDER_CANCEL_REPLACE_REQUEST);
+        assert_eq!(msg.get(tag::ORDER_ID), None);
+        assert_eq!(msg.get(tag::ORIG_CL_ORD_ID), Some("O-1"));
+        assert_eq!(msg.get(tag::CL_ORD_ID), Some("O-1-R"));
+        assert_eq!(msg.get(tag::ORDER_QTY), Some("200"));
+        assert_eq!(msg.get(tag::PRICE), Some("189.45"));
+        assert_eq!(msg.get(tag::TIME_IN_FORCE), Some("1"));
+    }
+
+    #[rstest]
+    fn test_parse_execution_report_fill() {
+        let msg = FixMessage::new(msg_type::EXECUTION_REPORT)
+            .with(tag::ORDER_ID, "12345")
+            .with(tag::CL_ORD_ID, "O-1")
+            .with(tag::EXEC_ID, "E-1")
+            .with(tag::EXEC_TYPE, "F")
+            .with(tag::ORD_STATUS, "1")
+            .with(tag::SYMBOL, "AAPL")
+            .with(tag::SIDE, "1")
+            .with(tag::ORD_TYPE, "2")
+            .with(tag::TIME_IN_FORCE, "0")
+            .with(tag::ORDER_QTY, "100")
+            .with(tag::PRICE, "189.50")
+            .with(tag::LAST_QTY, "40")
+            .with(tag::LAST_PX, "189.49")
+            .with(tag::LEAVES_QTY, "60")
+            .with(tag::CUM_QTY, "40")
+            .with(tag::AVG_PX, "189.49")
+            .with(tag::COMMISSION, "0.40")
+            .with(tag::CURRENCY, "USD")
+            .with(tag::LAST_LIQUIDITY_IND, "1")
+            .with(tag::TRANSACT_TIME, "20250101-14:30:00.250");
+
+        let report = ExecutionReport::from_message(&msg).unwrap();
+
+        assert!(report.is_fill());
+        assert_eq!(report.venue_order_id, Some(VenueOrderId::new("12345")));
+        assert_eq!(report.client_order_id, Some(ClientOrderId::new("O-1")));
+        assert_eq!(report.exec_id, "E-1");
+        assert_eq!(report.order_status, OrderStatus::PartiallyFilled);
+        assert_eq!(report.side, OrderSide::Buy);
+        assert_eq!(report.order_type, Some(OrderType::Limit));
+        assert_eq!(report.time_in_force, Some(TimeInForce::Day));
+        assert_eq!(report.last_qty, Some(dec!(40)));
+        assert_eq!(report.last_px, Some(dec!(189.49)));
+        assert_eq!(report.leaves_qty, dec!(60));
+        assert_eq!(report.cum_qty, dec!(40));
+        assert_eq!(report.commission, Some(dec!(0.40)));
+        assert_eq!(report.currency.as_deref(), Some("USD"));
+        assert_eq!(report.liquidity_side, LiquiditySide::Maker);
+        assert_eq!(
+            report.transact_time,
+            Some(UnixNanos::from(1_735_741_800_250_000_000))
+        );
+    }
+
+    #[rstest]
+    fn test_parse_execution_report_rejected() {
+        let msg = FixMessage::new(msg_type::EXECUTION_REPORT)
+            .with(tag::ORDER_ID, "NONE")
+            .with(tag::CL_ORD_ID, "O-1")
+            .with(tag::EXEC_ID, "E-2")
+            .with(tag::EXEC_TYPE, "8")
+            .with(tag::ORD_STATUS, "8")
+            .with(tag::SYMBOL, "AAPL")
+            .with(tag::SIDE, "5")
+            .with(tag::LEAVES_QTY, "0")
+            .with(tag::CUM_QTY, "0")
+            .with(tag::ORD_REJ_REASON, "3")
+            .with(tag::TEXT, "Order exceeds limit");
+
+        let report = ExecutionReport::from_message(&msg).unwrap();
+
+        assert!(!report.is_fill());
+        assert_eq!(report.venue_order_id, None);
+        assert_eq!(report.exec_type, FixExecType::Rejected);
+        assert_eq!(report.order_status, OrderStatus::Rejected);
+        assert_eq!(report.side, OrderSide::Sell);
+        assert_eq!(report.ord_rej_reason, Some(3));
+        assert_eq!(report.text.as_deref(), Some("Order exceeds limit"));
+        assert_eq!(report.liquidity_side, LiquiditySide::NoLiquiditySide);
+    }
+
+    #[rstest]
+    fn test_parse_execution_report_missing_field() {
+        let msg = FixMessage::new(msg_type::EXECUTION_REPORT).with(tag::EXEC_ID, "E-1");
+
+        let result = ExecutionReport::from_message(&msg);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_parse_order_cancel_reject() {
+        let msg = FixMessage::new(msg_type::ORDER_CANCEL_REJECT)
+            .with(tag::ORDER_ID, "12345")
+            .with(tag::CL_ORD_ID, "O-1-C")
+            .with(tag::ORIG_CL_ORD_ID, "O-1")
+            .with(tag::ORD_STATUS, "2")
+            .with(tag::CXL_REJ_RESPONSE_TO, "1")
+            .with(tag::CXL_REJ_REASON, "0")
+            .with(tag::TEXT, "Too late to cancel");
+
+        let reject = OrderCancelReject::from_message(&msg).unwrap();
+
+        assert_eq!(reject.client_order_id, ClientOrderId::new("O-1-C"));
+        assert_eq!(reject.orig_client_order_id, Some(ClientOrderId::new("O-1")));
+        assert_eq!(reject.order_status, OrderStatus::Filled);
+        assert_eq!(reject.response_to, CxlRejResponseTo::OrderCancelRequest);
+        assert_eq!(reject.reason, Some(0));
+        assert_eq!(reject.text.as_deref(), Some("Too late to cancel"));
+    }
+
+    #[rstest]
+    #[case("0", OrderStatus::Accepted)]
+    #[case("3", OrderStatus::Accepted)]
+    #[case("4", OrderStatus::Canceled)]
+    #[case("A", OrderStatus::Submitted)]
+    #[case("C", OrderStatus::Expired)]
+    #[case("E", OrderStatus::PendingUpdate)]
+    fn test_parse_order_status(#[case] value: &str, #[case] expected: OrderStatus) {
+        assert_eq!(parse_order_status(value).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case(TimeInForce::Day)]
+    #[case(TimeInForce::Gtc)]
+    #[case(TimeInForce::AtTheOpen)]
+    #[case(TimeInForce::Ioc)]
+    #[case(TimeInForce::Fok)]
+    #[case(TimeInForce::Gtx)]
+    #[case(TimeInForce::Gtd)]
+    #[case(TimeInForce::AtTheClose)]
+    fn test_time_in_force_round_trip(#[case] time_in_force: TimeInForce) {
+        let value = time_in_force_to_fix(time_in_force);
+
+        assert_eq!(parse_time_in_force(value).unwrap(), time_in_force);
+    }
+}
@@ -0,0 +1,890 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! The FIX 4.4 session layer state machine.
+//!
+//! The [`FixSession`] is independent of any I/O: messages received and timer ticks are
+//! passed in, and the resulting [`SessionAction`]s are returned for the handler to carry
+//! out (sending messages, delivering application messages, or disconnecting).
+
+use std::collections::BTreeMap;
+
+use nautilus_core::UnixNanos;
+
+use crate::{
+    config::FixSessionConfig,
+    message::{format_utc_timestamp, FixMessage},
+    tags::{msg_type, tag},
+};
+
+const NANOSECONDS_IN_SECOND: u64 = 1_000_000_000;
+
+/// `SessionRejectReason(373)` for a CompID problem.
+const REJECT_REASON_COMP_ID_PROBLEM: u32 = 9;
+
+/// `SessionRejectReason(373)` for a value out of range.
+const REJECT_REASON_VALUE_INCORRECT: u32 = 5;
+
+/// The state of a FIX session.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SessionState {
+    /// Not connected, or the connection is being established.
+    Disconnected,
+    /// The logon has been sent, and the session is awaiting the logon response.
+    LogonSent,
+    /// The session is logged on.
+    Active,
+    /// The logout has been sent, and the session is awaiting the logout response.
+    LogoutSent,
+}
+
+/// An action to carry out as a result of a message received or timer tick.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SessionAction {
+    /// Send the message to the counterparty.
+    Send(FixMessage),
+    /// The session is logged on, and application messages can be sent.
+    LoggedOn,
+    /// Deliver the application (or session `Reject`) message received.
+    Deliver(FixMessage),
+    /// The session is logged out, with the counterparty logout text (if any).
+    LoggedOut(Option<String>),
+    /// Close the connection for the given reason.
+    Disconnect(String),
+}
+
+/// Provides a FIX 4.4 initiator session.
+///
+/// Outbound messages are stamped with the standard header and sequence number, and
+/// application messages are stored so they can be resent in response to a resend request
+/// (administrative messages are replaced with a `SequenceReset-GapFill`).
+///
+/// Inbound sequence gaps are detected and recovered with a `ResendRequest`, with messages
+/// received ahead of the gap queued until the gap is filled, so application messages are
+/// always delivered in sequence. A message received with a sequence number lower than
+/// expected (and without the `PossDupFlag(43)`) is a fatal error for the session.
+///
+/// Sequence numbers are retained across connections (unless reset on logon), and can
+/// be restored with [`FixSession::set_sequence_numbers`].
+#[derive(Debug)]
+pub struct FixSession {
+    sender_comp_id: String,
+    target_comp_id: String,
+    username: Option<String>,
+    password: Option<String>,
+    heartbeat_interval_ns: u64,
+    logon_timeout_ns: u64,
+    reset_on_logon: bool,
+    state: SessionState,
+    next_sender_seq: u64,
+    next_target_seq: u64,
+    sent: BTreeMap<u64, FixMessage>,
+    queued: BTreeMap<u64, FixMessage>,
+    resend_end: Option<u64>,
+    state_ns: UnixNanos,
+    last_sent_ns: UnixNanos,
+    last_received_ns: UnixNanos,
+    test_request: Option<(String, UnixNanos)>,
+}
+
+impl FixSession {
+    /// Creates a new [`FixSession`] instance.
+    #[must_use]
+    pub fn new(config: &FixSessionConfig) -> Self {
+        Self {
+            sender_comp_id: config.sender_comp_id.clone(),
+            target_comp_id: config.target_comp_id.clone(),
+            username: config.username.clone(),
+            password: config.password.clone(),
+            heartbeat_interval_ns: config.heartbeat_interval_secs * NANOSECONDS_IN_SECOND,
+            logon_timeout_ns: config.logon_timeout_secs * NANOSECONDS_IN_SECOND,
+            reset_on_logon: config.reset_on_logon,
+            state: SessionState::Disconnected,
+            next_sender_seq: 1,
+            next_target_seq: 1,
+            sent: BTreeMap::new(),
+            queued: BTreeMap::new(),
+            resend_end: None,
+            state_ns: UnixNanos::default(),
+            last_sent_ns: UnixNanos::default(),
+            last_received_ns: UnixNanos::default(),
+            test_request: None,
+        }
+    }
+
+    /// Returns the current state of the session.
+    #[must_use]
+    pub const fn state(&self) -> SessionState {
+        self.state
+    }
+
+    /// Returns whether the session is logged on.
+    #[must_use]
+    pub fn is_logged_on(&self) -> bool {
+        self.state == SessionState::Active
+    }
+
+    /// Returns the next outbound `MsgSeqNum(34)`.
+    #[must_use]
+    pub const fn next_sender_seq(&self) -> u64 {
+        self.next_sender_seq
+    }
+
+    /// Returns the next expected inbound `MsgSeqNum(34)`.
+    #[must_use]
+    pub const fn next_target_seq(&self) -> u64 {
+        self.next_target_seq
+    }
+
+    /// Sets the next outbound and expected inbound sequence numbers (such as when
+    /// restoring a session from persisted sequence numbers).
+    ///
+    /// # Panics
+    ///
+    /// Panics if either sequence number is zero.
+    pub fn set_sequence_numbers(&mut self, next_sender_seq: u64, next_target_seq: u64) {
+        assert!(next_sender_seq > 0, "`next_sender_seq` was zero");
+        assert!(next_target_seq > 0, "`next_target_seq` was zero");
+        self.next_sender_seq = next_sender_seq;
+        self.next_target_seq = next_target_seq;
+    }
+
+    /// Returns the `Logon` message to initiate the session.
+    pub fn logon(&mut self, now: UnixNanos) -> FixMessage {
+        let mut msg = FixMessage::new(msg_type::LOGON)
+            .with(tag::ENCRYPT_METHOD, 0)
+            .with(
+                tag::HEART_BT_INT,
+                self.heartbeat_interval_ns / NANOSECONDS_IN_SECOND,
+            );
+        if self.reset_on_logon {
+            self.next_sender_seq = 1;
+            self.next_target_seq = 1;
+            self.sent.clear();
+            msg.push(tag::RESET_SEQ_NUM_FLAG, "Y");
+        }
+        if let Some(username) = &self.username {
+            msg.push(tag::USERNAME, username);
+        }
+        if let Some(password) = &self.password {
+            msg.push(tag::PASSWORD, password);
+        }
+
+        self.queued.clear();
+        self.resend_end = None;
+        self.test_request = None;
+        self.last_received_ns = now;
+        self.set_state(SessionState::LogonSent, now);
+        self.stamp(msg, now)
+    }
+
+    /// Returns the `Logout` message to end the session.
+    pub fn logout(&mut self, text: Option<&str>, now: UnixNanos) -> FixMessage {
+        let mut msg = FixMessage::new(msg_type::LOGOUT);
+        if let Some(text) = text {
+            msg.push(tag::TEXT, text);
+        }
+        self.set_state(SessionState::LogoutSent, now);
+        self.stamp(msg, now)
+    }
+
+    /// Returns the message stamped with the standard header and next sequence number,
+    /// ready to send to the counterparty.
+    pub fn send(&mut self, msg: FixMessage, now: UnixNanos) -> FixMessage {
+        self.stamp(msg, now)
+    }
+
+    /// Resets the session state when the connection is closed.
+    pub fn on_disconnected(&mut self, now: UnixNanos) {
+        self.queued.clear();
+        self.resend_end = None;
+        self.test_request = None;
+        self.set_state(SessionState::Disconnected, now);
+    }
+
+    /// Processes the message received from the counterparty.
+    pub fn on_message(&mut self, msg: FixMessage, now: UnixNanos) -> Vec<SessionAction> {
+        self.last_received_ns = now;
+        let mut actions = Vec::new();
+
+        if msg.get(tag::SENDER_COMP_ID) != Some(self.target_comp_id.as_str())
+            || msg.get(tag::TARGET_COMP_ID) != Some(self.sender_comp_id.as_str())
+        {
+            let text = "CompID problem";
+            let reject = self.reject(&msg, REJECT_REASON_COMP_ID_PROBLEM, text);
+            actions.push(SessionAction::Send(self.stamp(reject, now)));
+            actions.push(SessionAction::Send(self.logout(Some(text), now)));
+            actions.push(SessionAction::Disconnect(text.to_string()));
+            return actions;
+        }
+
+        if self.state == SessionState::LogonSent
+            && !matches!(msg.msg_type(), msg_type::LOGON | msg_type::LOGOUT)
+        {
+            let reason = format!("Received MsgType {} before logon", msg.msg_type());
+            actions.push(SessionAction::Disconnect(reason));
+            return actions;
+        }
+
+        let Some(seq) = msg.seq_num() else {
+            actions.push(SessionAction::Send(
+                self.logout(Some("Missing MsgSeqNum"), now),
+            ));
+            actions.push(SessionAction::Disconnect("Missing MsgSeqNum".to_string()));
+            return actions;
+        };
+
+        // A `SequenceReset-Reset` ignores the sequence number of the message itself
+        if msg.msg_type() == msg_type::SEQUENCE_RESET && msg.get(tag::GAP_FILL_FLAG) != Some("Y") {
+            self.handle_sequence_reset(&msg, now, &mut actions);
+            return actions;
+        }
+
+        if seq > self.next_target_seq {
+            tracing::warn!(
+                "Sequence gap detected: expected {}, received {seq}",
+                self.next_target_seq
+            );
+            if matches!(msg.msg_type(), msg_type::LOGON | msg_type::LOGOUT) {
+                self.process(msg.clone(), now, &mut actions);
+            }
+            if self.state == SessionState::Disconnected {
+                return actions;
+            }
+
+            self.queued.insert(seq, msg);
+            if self.resend_end.is_none() {
+                let request = FixMessage::new(msg_type::RESEND_REQUEST)
+                    .with(tag::BEGIN_SEQ_NO, self.next_target_seq)
+                    .with(tag::END_SEQ_NO, 0);
+                actions.push(SessionAction::Send(self.stamp(request, now)));
+            }
+            self.resend_end = Some(self.resend_end.unwrap_or(0).max(seq));
+            return actions;
+        }
+
+        if seq < self.next_target_seq {
+            if msg.is_poss_dup() {
+                tracing::debug!("Ignoring possible duplicate with MsgSeqNum {seq}");
+                return actions;
+            }
+            let text = format!(
+                "MsgSeqNum too low, expecting {} but received {seq}",
+                self.next_target_seq
+            );
+            actions.push(SessionAction::Send(self.logout(Some(&text), now)));
+            actions.push(SessionAction::Disconnect(text));
+            return actions;
+        }
+
+        self.next_target_seq += 1;
+        self.process(msg, now, &mut actions);
+
+        // Process messages received ahead of the gap, now in sequence
+        loop {
+            self.queued = self.queued.split_off(&self.next_target_seq);
+            let Some(queued) = self.queued.remove(&self.next_target_seq) else {
+                break;
+            };
+            self.next_target_seq += 1;
+            self.process(queued, now, &mut actions);
+        }
+
+        if self
+            .resend_end
+            .is_some_and(|end| self.next_target_seq > end)
+        {
+            tracing::info!("Sequence gap recovered");
+            self.resend_end = None;
+        }
+
+        actions
+    }
+
+    /// Processes a timer tick, sending heartbeats and test requests on the heartbeat
+    /// interval, and disconnecting if the counterparty is unresponsive.
+    ///
+    /// The timer should tick at least once per second.
+    pub fn on_timer(&mut self, now: UnixNanos) -> Vec<SessionAction> {
+        let mut actions = Vec::new();
+        match self.state {
+            SessionState::Disconnected => {}
+            SessionState::LogonSent | SessionState::LogoutSent => {
+                if elapsed(self.state_ns, now) >= self.logon_timeout_ns {
+                    let reason = match self.state {
+                        SessionState::LogonSent => "Logon timeout",
+                        _ => "Logout timeout",
+                    };
+                    actions.push(SessionAction::Disconnect(reason.to_string()));
+                }
+            }
+            SessionState::Active => {
+                if elapsed(self.last_sent_ns, now) >= self.heartbeat_interval_ns {
+                    let heartbeat = FixMessage::new(msg_type::HEARTBEAT);
+                    actions.push(SessionAction::Send(self.stamp(heartbeat, now)));
+                }
+
+                match &self.test_request {
+                    Some((_, sent_ns)) => {
+                        if elapsed(*sent_ns, now) >= self.heartbeat_interval_ns {
+                            actions
+                                .push(SessionAction::Disconnect("Heartbeat timeout".to_string()));
+                        }
+                    }
+                    None => {
+                        // Allow for transmission delay before probing the counterparty
+                        let timeout = self.heartbeat_interval_ns + self.heartbeat_interval_ns / 5;
+                        if elapsed(self.last_received_ns, now) >= timeout {
+                            let test_req_id = now.as_u64().to_string();
+                            let request = FixMessage::new(msg_type::TEST_REQUEST)
+                                .with(tag::TEST_REQ_ID, &test_req_id);
+                            actions.push(SessionAction::Send(self.stamp(request, now)));
+                            self.test_request = Some((test_req_id, now));
+                        }
+                    }
+                }
+            }
+        }
+        actions
+    }
+
+    fn process(&mut self, msg: FixMessage, now: UnixNanos, actions: &mut Vec<SessionAction>) {
+        match msg.msg_type() {
+            msg_type::LOGON => {
+                if self.state == SessionState::LogonSent {
+                    tracing::info!(
+                        "Logged on {} -> {}",
+                        self.sender_comp_id,
+                        self.target_comp_id
+                    );
+                    self.set_state(SessionState::Active, now);
+                    actions.push(SessionAction::LoggedOn);
+                }
+            }
+            msg_type::HEARTBEAT => {
+                if let Some((test_req_id, _)) = &self.test_request {
+                    if msg.get(tag::TEST_REQ_ID) == Some(test_req_id.as_str()) {
+                        self.test_request = None;
+                    }
+                }
+            }
+            msg_type::TEST_REQUEST => {
+                let mut heartbeat = FixMessage::new(msg_type::HEARTBEAT);
+                if let Some(test_req_id) = msg.get(tag::TEST_REQ_ID) {
+                    heartbeat.push(tag::TEST_REQ_ID, test_req_id);
+                }
+                actions.push(SessionAction::Send(self.stamp(heartbeat, now)));
+            }
+            msg_type::RESEND_REQUEST => {
+                let begin = msg.get_parsed(tag::BEGIN_SEQ_NO).ok().flatten();
+                let end = msg.get_parsed(tag::END_SEQ_NO).ok().flatten();
+                match (begin, end) {
+                    (Some(begin), Some(end)) => {
+                        tracing::info!("Resending messages {begin} to {end}");
+                        for resend in self.resend(begin, end, now) {
+                            actions.push(SessionAction::Send(resend));
+                        }
+                    }
+                    _ => {
+                        let text = "Invalid ResendRequest range";
+                        let reject = self.reject(&msg, REJECT_REASON_VALUE_INCORRECT, text);
+                        actions.push(SessionAction::Send(self.stamp(reject, now)));
+                    }
+                }
+            }
+            msg_type::SEQUENCE_RESET => {
+                // Gap fill, as the sequence number of the message has been checked
+                match msg.get_parsed::<u64>(tag::NEW_SEQ_NO) {
+                    Ok(Some(new_seq)) if new_seq >= self.next_target_seq => {
+                        self.next_target_seq = new_seq;
+                    }
+                    _ => {
+                        let text = "Invalid NewSeqNo for gap fill";
+                        let reject = self.reject(&msg, REJECT_REASON_VALUE_INCORRECT, text);
+                        actions.push(SessionAction::Send(self.stamp(reject, now)));
+                    }
+                }
+            }
+            msg_type::REJECT => {
+                tracing::warn!("Session level reject: {msg}");
+                actions.push(SessionAction::Deliver(msg));
+            }
+            msg_type::LOGOUT => {
+                let text = msg.get(tag::TEXT).map(ToString::to_string);
+                if self.state != SessionState::LogoutSent {
+                    let logout = FixMessage::new(msg_type::LOGOUT);
+                    actions.push(SessionAction::Send(self.stamp(logout, now)));
+                }
+                tracing::info!("Logged out: {}", text.as_deref().unwrap_or("no reason"));
+                self.set_state(SessionState::Disconnected, now);
+                actions.push(SessionAction::LoggedOut(text));
+                actions.push(SessionAction::Disconnect("Logged out".to_string()));
+            }
+            _ => {
+                if self.state == SessionState::Active {
+                    actions.push(SessionAction::Deliver(msg));
+                } else {
+                    tracing::warn!("Dropping message received while not logged on: {msg}");
+                }
+            }
+        }
+    }
+
+    fn handle_sequence_reset(
+        &mut self,
+        msg: &FixMessage,
+        now: UnixNanos,
+        actions: &mut Vec<SessionAction>,
+    ) {
+        match msg.get_parsed::<u64>(tag::NEW_SEQ_NO) {
+            Ok(Some(new_seq)) if new_seq >= self.next_target_seq => {
+                tracing::info!("Sequence reset from {} to {new_seq}", self.next_target_seq);
+                self.next_target_seq = new_seq;
+                self.queued = self.queued.split_off(&new_seq);
+                if self.resend_end.is_some_and(|end| new_seq > end) {
+                    self.resend_end = None;
+                }
+            }
+            _ => {
+                let text = "Invalid NewSeqNo for sequence reset";
+                let reject = self.reject(msg, REJECT_REASON_VALUE_INCORRECT, text);
+                actions.push(SessionAction::Send(self.stamp(reject, now)));
+            }
+        }
+    }
+
+    fn resend(&mut self, begin: u64, end: u64, now: UnixNanos) -> Vec<FixMessage> {
+        let last = self.next_sender_seq - 1;
+        let end = if end == 0 || end > last { last } else { end };
+        let sending_time = format_utc_timestamp(now);
+
+        let mut msgs = Vec::new();
+        let mut gap_start = None;
+        for seq in begin.max(1)..=end {
+            match self.sent.get(&seq) {
+                Some(stored) => {
+                    if let Some(start) = gap_start.take() {
+                        msgs.push(self.gap_fill(start, seq, &sending_time));
+                    }
+                    let mut msg = stored.clone();
+                    if let Some(orig_sending_time) = stored.get(tag::SENDING_TIME) {
+                        msg.set(tag::ORIG_SENDING_TIME, orig_sending_time);
+                    }
+                    msg.set(tag::POSS_DUP_FLAG, "Y");
+                    msg.set(tag::SENDING_TIME, &sending_time);
+                    msgs.push(msg);
+                }
+                None => {
+                    gap_start.get_or_insert(seq);
+                }
+            }
+        }
+        if let Some(start) = gap_start {
+            msgs.push(self.gap_fill(start, end + 1, &sending_time));
+        }
+
+        if !msgs.is_empty() {
+            self.last_sent_ns = now;
+        }
+        msgs
+    }
+
+    fn gap_fill(&self, seq: u64, new_seq: u64, sending_time: &str) -> FixMessage {
+        FixMessage::new(msg_type::SEQUENCE_RESET)
+            .with(tag::SENDER_COMP_ID, &self.sender_comp_id)
+            .with(tag::TARGET_COMP_ID, &self.target_comp_id)
+            .with(tag::MSG_SEQ_NUM, seq)
+            .with(tag::POSS_DUP_FLAG, "Y")
+            .with(tag::SENDING_TIME, sending_time)
+            .with(tag::ORIG_SENDING_TIME, sending_time)
+            .with(tag::GAP_FILL_FLAG, "Y")
+            .with(tag::NEW_SEQ_NO, new_seq)
+    }
+
+    fn reject(&self, msg: &FixMessage, reason: u32, text: &str) -> FixMessage {
+        let mut reject = FixMessage::new(msg_type::REJECT);
+        if let Some(seq) = msg.get(tag::MSG_SEQ_NUM) {
+            reject.push(tag::REF_SEQ_NUM, seq);
+        }
+        reject.push(tag::REF_MSG_TYPE, msg.msg_type());
+        reject.push(tag::SESSION_REJECT_REASON, reason);
+        reject.push(tag::TEXT, text);
+        reject
+    }
+
+    fn stamp(&mut self, mut msg: FixMessage, now: UnixNanos) -> FixMessage {
+        let seq = self.next_sender_seq;
+        msg.set(tag::SENDER_COMP_ID, &self.sender_comp_id);
+        msg.set(tag::TARGET_COMP_ID, &self.target_comp_id);
+        msg.set(tag::MSG_SEQ_NUM, seq);
+        msg.set(tag::SENDING_TIME, format_utc_timestamp(now));
+        self.next_sender_seq += 1;
+        self.last_sent_ns = now;
+
+        if !msg_type::is_admin(msg.msg_type()) {
+            self.sent.insert(seq, msg.clone());
+        }
+        msg
+    }
+
+    fn set_state(&mut self, state: SessionState, now: UnixNanos) {
+        self.state = state;
+        self.state_ns = now;
+    }
+}
+
+fn elapsed(since: UnixNanos, now: UnixNanos) -> u64 {
+    now.as_u64().saturating_sub(since.as_u64())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    const SECS: u64 = NANOSECONDS_IN_SECOND;
+
+    fn ts(secs: u64) -> UnixNanos {
+        UnixNanos::from(1_735_689_600 * SECS + secs * SECS)
+    }
+
+    fn inbound(msg_type: &str, seq: u64) -> FixMessage {
+        FixMessage::new(msg_type)
+            .with(tag::SENDER_COMP_ID, "SERVER")
+            .with(tag::TARGET_COMP_ID, "CLIENT")
+            .with(tag::MSG_SEQ_NUM, seq)
+    }
+
+    fn execution_report(seq: u64) -> FixMessage {
+        inbound(msg_type::EXECUTION_REPORT, seq).with(tag::EXEC_ID, format!("E-{seq}"))
+    }
+
+    fn sent(actions: &[SessionAction]) -> Vec<&FixMessage> {
+        actions
+            .iter()
+            .filter_map(|action| match action {
+                SessionAction::Send(msg) => Some(msg),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn delivered(actions: &[SessionAction]) -> Vec<&str> {
+        actions
+            .iter()
+            .filter_map(|action| match action {
+                SessionAction::Deliver(msg) => msg.get(tag::EXEC_ID),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[fixture]
+    fn session() -> FixSession {
+        let config = FixSessionConfig {
+            username: Some("user".to_string()),
+            password: Some("pass".to_string()),
+            ..Default::default()
+        };
+        let mut session = FixSession::new(&config);
+        let _ = session.logon(ts(0));
+        let actions = session.on_message(inbound(msg_type::LOGON, 1), ts(0));
+        assert_eq!(actions, vec![SessionAction::LoggedOn]);
+        session
+    }
+
+    #[rstest]
+    fn test_logon_message() {
+        let mut session = FixSession::new(&FixSessionConfig {
+            username: Some("user".to_string()),
+            password: Some("pass".to_string()),
+            ..Default::default()
+        });
+        session.set_sequence_numbers(5, 5);
+
+        let msg = session.logon(ts(0));
+
+        assert_eq!(msg.msg_type(), msg_type::LOGON);
+        assert_eq!(msg.get(tag::SENDER_COMP_ID), Some("CLIENT"));
+        assert_eq!(msg.get(tag::TARGET_COMP_ID), Some("SERVER"));
+        assert_eq!(msg.seq_num(), Some(1)); // Reset on logon
+        assert_eq!(msg.get(tag::SENDING_TIME), Some("20250101-00:00:00.000"));
+        assert_eq!(msg.get(tag::HEART_BT_INT), Some("30"));
+        assert_eq!(msg.get(tag::RESET_SEQ_NUM_FLAG), Some("Y"));
+        assert_eq!(msg.get(tag::USERNAME), Some("user"));
+        assert_eq!(msg.get(tag::PASSWORD), Some("pass"));
+        assert_eq!(session.state(), SessionState::LogonSent);
+    }
+
+    #[rstest]
+    fn test_logon_response(session: FixSession) {
+        assert!(session.is_logged_on());
+        assert_eq!(session.next_sender_seq(), 2);
+        assert_eq!(session.next_target_seq(), 2);
+    }
+
+    #[rstest]
+    fn test_message_before_logon_disconnects() {
+        let mut session = FixSession::new(&FixSessionConfig::default());
+        let _ = session.logon(ts(0));
+
+        let actions = session.on_message(execution_report(1), ts(1));
+
+        assert!(matches!(&actions[..], [SessionAction::Disconnect(_)]));
+    }
+
+    #[rstest]
+    fn test_logon_timeout() {
+        let mut session = FixSession::new(&FixSessionConfig::default());
+        let _ = session.logon(ts(0));
+
+        assert!(session.on_timer(ts(9)).is_empty());
+        assert_eq!(
+            session.on_timer(ts(10)),
+            vec![SessionAction::Disconnect("Logon timeout".to_string())]
+        );
+    }
+
+    #[rstest]
+    fn test_comp_id_mismatch_rejects_and_disconnects(mut session: FixSession) {
+        let mut msg = execution_report(2);
+        msg.set(tag::SENDER_COMP_ID, "OTHER");
+
+        let actions = session.on_message(msg, ts(1));
+
+        let sent = sent(&actions);
+        assert_eq!(sent[0].msg_type(), msg_type::REJECT);
+        assert_eq!(sent[0].get(tag::SESSION_REJECT_REASON), Some("9"));
+        assert_eq!(sent[1].msg_type(), msg_type::LOGOUT);
+        assert!(matches!(actions.last(), Some(SessionAction::Disconnect(_))));
+    }
+
+    #[rstest]
+    fn test_send_stamps_and_increments_sequence(mut session: FixSession) {
+        let msg = session.send(FixMessage::new(msg_type::NEW_ORDER_SINGLE), ts(1));
+
+        assert_eq!(msg.seq_num(), Some(2));
+        assert_eq!(msg.get(tag::SENDING_TIME), Some("20250101-00:00:01.000"));
+        assert_eq!(session.next_sender_seq(), 3);
+    }
+
+    #[rstest]
+    fn test_application_messages_delivered(mut session: FixSession) {
+        let actions = session.on_message(execution_report(2), ts(1));
+
+        assert_eq!(delivered(&actions), vec!["E-2"]);
+        assert_eq!(session.next_target_seq(), 3);
+    }
+
+    #[rstest]
+    fn test_heartbeat_sent_on_interval(mut session: FixSession) {
+        assert!(session.on_timer(ts(29)).is_empty());
+
+        let actions = session.on_timer(ts(30));
+
+        let sent = sent(&actions);
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].msg_type(), msg_type::HEARTBEAT);
+    }
+
+    #[rstest]
+    fn test_test_request_sent_then_heartbeat_timeout(mut session: FixSession) {
+        let _ = session.send(FixMessage::new(msg_type::NEW_ORDER_SINGLE), ts(35));
+
+        let actions = session.on_timer(ts(36));
+
+        let sent = sent(&actions);
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].msg_type(), msg_type::TEST_REQUEST);
+        let test_req_id = sent[0].get(tag::TEST_REQ_ID).unwrap().to_string();
+
+        // Answered by a heartbeat with the test request ID
+        let heartbeat = inbound(msg_type::HEARTBEAT, 2).with(tag::TEST_REQ_ID, &test_req_id);
+        assert!(session.on_message(heartbeat, ts(37)).is_empty());
+        assert!(session
+            .on_timer(ts(66))
+            .iter()
+            .all(|action| !matches!(action, SessionAction::Disconnect(_))));
+
+        // Unanswered
+        let actions = session.on_timer(ts(110));
+        assert!(sent_types(&actions).contains(&msg_type::TEST_REQUEST));
+        let actions = session.on_timer(ts(140));
+        assert!(
+            matches!(actions.last(), Some(SessionAction::Disconnect(reason)) if reason == "Heartbeat timeout")
+        );
+    }
+
+    fn sent_types(actions: &[SessionAction]) -> Vec<&str> {
+        sent(actions).iter().map(|msg| msg.msg_type()).collect()
+    }
+
+    #[rstest]
+    fn test_test_request_answered_with_heartbeat(mut session: FixSession) {
+        let request = inbound(msg_type::TEST_REQUEST, 2).with(tag::TEST_REQ_ID, "PING");
+
+        let actions = session.on_message(request, ts(1));
+
+        let sent = sent(&actions);
+        assert_eq!(sent[0].msg_type(), msg_type::HEARTBEAT);
+        assert_eq!(sent[0].get(tag::TEST_REQ_ID), Some("PING"));
+    }
+
+    #[rstest]
+    fn test_sequence_gap_requests_resend_and_queues(mut session: FixSession) {
+        let actions = session.on_message(execution_report(4), ts(1));
+
+        let sent = sent(&actions);
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].msg_type(), msg_type::RESEND_REQUEST);
+        assert_eq!(sent[0].get(tag::BEGIN_SEQ_NO), Some("2"));
+        assert_eq!(sent[0].get(tag::END_SEQ_NO), Some("0"));
+        assert!(delivered(&actions).is_empty());
+
+        // Further messages ahead of the gap do not repeat the resend request
+        let actions = session.on_message(execution_report(5), ts(1));
+        assert!(actions.is_empty());
+
+        // Resent messages fill the gap, then the queued messages are delivered in order
+        let actions = session.on_message(execution_report(2).with(tag::POSS_DUP_FLAG, "Y"), ts(2));
+        assert_eq!(delivered(&actions), vec!["E-2"]);
+        let actions = session.on_message(execution_report(3).with(tag::POSS_DUP_FLAG, "Y"), ts(2));
+        assert_eq!(delivered(&actions), vec!["E-3", "E-4", "E-5"]);
+        assert_eq!(session.next_target_seq(), 6);
+    }
+
+    #[rstest]
+    fn test_gap_fill_fills_sequence_gap(mut session: FixSession) {
+        let _ = session.on_message(execution_report(5), ts(1));
+        let gap_fill = inbound(msg_type::SEQUENCE_RESET, 2)
+            .with(tag::POSS_DUP_FLAG, "Y")
+            .with(tag::GAP_FILL_FLAG, "Y")
+            .with(tag::NEW_SEQ_NO, 5);
+
+        let actions = session.on_message(gap_fill, ts(2));
+
+        assert_eq!(delivered(&actions), vec!["E-5"]);
+        assert_eq!(session.next_target_seq(), 6);
+    }
+
+    #[rstest]
+    fn test_sequence_reset_ignores_message_sequence(mut session: FixSession) {
+        let reset = inbound(msg_type::SEQUENCE_RESET, 100).with(tag::NEW_SEQ_NO, 10);
+
+        let actions = session.on_message(reset, ts(1));
+
+        assert!(actions.is_empty());
+        assert_eq!(session.next_target_seq(), 10);
+    }
+
+    #[rstest]
+    fn test_sequence_reset_to_lower_sequence_rejected(mut session: FixSession) {
+        let reset = inbound(msg_type::SEQUENCE_RESET, 1).with(tag::NEW_SEQ_NO, 1);
+
+        let actions = session.on_message(reset, ts(1));
+
+        assert_eq!(sent_types(&actions), vec![msg_type::REJECT]);
+        assert_eq!(session.next_target_seq(), 2);
+    }
+
+    #[rstest]
+    fn test_sequence_too_low_logs_out(mut session: FixSession) {
+        let _ = session.on_message(execution_report(2), ts(1));
+
+        let actions = session.on_message(execution_report(2), ts(2));
+
+        assert_eq!(sent_types(&actions), vec![msg_type::LOGOUT]);
+        assert!(
+            matches!(actions.last(), Some(SessionAction::Disconnect(reason)) if reason.starts_with("MsgSeqNum too low"))
+        );
+    }
+
+    #[rstest]
+    fn test_possible_duplicate_with_low_sequence_ignored(mut session: FixSession) {
+        let _ = session.on_message(execution_report(2), ts(1));
+
+        let actions = session.on_message(execution_report(2).with(tag::POSS_DUP_FLAG, "Y"), ts(2));
+
+        assert!(actions.is_empty());
+    }
+
+    #[rstest]
+    fn test_resend_request_resends_application_messages_and_gap_fills(mut session: FixSession) {
+        // Sequence 1 is the logon, 2 an order, 3 a heartbeat, 4 an order
+        let order = session.send(
+            FixMessage::new(msg_type::NEW_ORDER_SINGLE).with(tag::CL_ORD_ID, "O-1"),
+            ts(1),
+        );
+        let _ = session.on_timer(ts(31));
+        let _ = session.send(
+            FixMessage::new(msg_type::NEW_ORDER_SINGLE).with(tag::CL_ORD_ID, "O-2"),
+            ts(32),
+        );
+        let request = inbound(msg_type::RESEND_REQUEST, 2)
+            .with(tag::BEGIN_SEQ_NO, 1)
+            .with(tag::END_SEQ_NO, 0);
+
+        let actions = session.on_message(request, ts(40));
+
+        let sent = sent(&actions);
+        assert_eq!(sent.len(), 4);
+        assert_eq!(sent[0].msg_type(), msg_type::SEQUENCE_RESET);
+        assert_eq!(sent[0].seq_num(), Some(1));
+        assert_eq!(sent[0].get(tag::GAP_FILL_FLAG), Some("Y"));
+        assert_eq!(sent[0].get(tag::NEW_SEQ_NO), Some("2"));
+        assert_eq!(sent[1].seq_num(), Some(2));
+        assert_eq!(sent[1].get(tag::CL_ORD_ID), Some("O-1"));
+        assert!(sent[1].is_poss_dup());
+        assert_eq!(
+            sent[1].get(tag::ORIG_SENDING_TIME),
+            order.get(tag::SENDING_TIME)
+        );
+        assert_eq!(
+            sent[1].get(tag::SENDING_TIME),
+            Some("20250101-00:00:40.000")
+        );
+        assert_eq!(sent[2].get(tag::NEW_SEQ_NO), Some("4"));
+        assert_eq!(sent[3].get(tag::CL_ORD_ID), Some("O-2"));
+        assert_eq!(session.next_sender_seq(), 5);
+    }
+
+    #[rstest]
+    fn test_logout_received_replies_and_disconnects(mut session: FixSession) {
+        let logout = inbound(msg_type::LOGOUT, 2).with(tag::TEXT, "End of day");
+
+        let actions = session.on_message(logout, ts(1));
+
+        assert_eq!(sent_types(&actions), vec![msg_type::LOGOUT]);
+        assert!(actions.contains(&SessionAction::LoggedOut(Some("End of day".to_string()))));
+        assert_eq!(session.state(), SessionState::Disconnected);
+    }
+
+    #[rstest]
+    fn test_logout_response_disconnects(mut session: FixSession) {
+        let _ = session.logout(None, ts(1));
+
+        let actions = session.on_message(inbound(msg_type::LOGOUT, 2), ts(2));
+
+        assert!(sent_types(&actions).is_empty());
+        assert_eq!(
+            actions,
+            vec![
+                SessionAction::LoggedOut(None),
+                SessionAction::Disconnect("Logged out".to_string())
+            ]
+        );
+    }
+}
@@ -45,6 +45,7 @@ use tokio::try_join;
 use ustr::Ustr;
 
 use crate::sql::{
+    models::instruments::InstrumentTermsModel,
     pg::{connect_pg, get_postgres_connect_options},
     queries::DatabaseQueries,
 };
@@ -862,6 +863,16 @@ async fn drain_buffer(pool: &PgPool, buffer: &mut VecDeque<DatabaseQuery>) {
                     DatabaseQueries::add_instrument(pool, "BINARY_OPTION", Box::new(instrument))
                         .await
                 }
+                InstrumentAny::Bond(instrument) => {
+                    let terms = InstrumentTermsModel::from(&instrument);
+                    DatabaseQueries::add_instrument_with_terms(
+                        pool,
+                        "BOND",
+                        Box::new(instrument),
+                        terms,
+                    )
+                    .await
+                }
                 InstrumentAny::CryptoFuture(instrument) => {
                     DatabaseQueries::add_instrument(pool, "CRYPTO_FUTURE", Box::new(instrument))
                         .await
@@ -885,6 +896,26 @@ async fn drain_buffer(pool: &PgPool, buffer: &mut VecDeque<DatabaseQuery>) {
                     DatabaseQueries::add_instrument(pool, "FUTURES_SPREAD", Box::new(instrument))
                         .await
                 }
+                InstrumentAny::FxForward(instrument) => {
+                    let terms = InstrumentTermsModel::from(&instrument);
+                    DatabaseQueries::add_instrument_with_terms(
+                        pool,
+                        "FX_FORWARD",
+                        Box::new(instrument),
+                        terms,
+                    )
+                    .await
+                }
+                InstrumentAny::FxSwap(instrument) => {
+                    let terms = InstrumentTermsModel::from(&instrument);
+                    DatabaseQueries::add_instrument_with_terms(
+                        pool,
+                        "FX_SWAP",
+                        Box::new(instrument),
+                        terms,
+                    )
+                    .await
+                }
                 InstrumentAny::OptionContract(instrument) => {
                     DatabaseQueries::add_instrument(pool, "OPTION_CONTRACT", Box::new(instrument))
                         .await
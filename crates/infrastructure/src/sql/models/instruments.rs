@@ -21,11 +21,12 @@ use std::str::FromStr;
 
 use nautilus_core::UnixNanos;
 use nautilus_model::{
-    enums::OptionKind,
+    enums::{DayCountConvention, ExerciseStyle, OptionKind, SettlementType},
     identifiers::{InstrumentId, Symbol},
     instruments::{
-        BettingInstrument, BinaryOption, CryptoFuture, CryptoPerpetual, CurrencyPair, Equity,
-        FuturesContract, FuturesSpread, InstrumentAny, OptionContract, OptionSpread,
+        BettingInstrument, BinaryOption, Bond, CryptoFuture, CryptoPerpetual, CurrencyPair, Equity,
+        FuturesContract, FuturesSpread, FxForward, FxSwap, InstrumentAny, OptionContract,
+        OptionSpread,
     },
     types::{Currency, Money, Price, Quantity},
 };
@@ -38,15 +39,65 @@ use crate::sql::models::enums::AssetClassModel;
 pub struct InstrumentAnyModel(pub InstrumentAny);
 pub struct BettingInstrumentModel(pub BettingInstrument);
 pub struct BinaryOptionModel(pub BinaryOption);
+pub struct BondModel(pub Bond);
 pub struct CryptoFutureModel(pub CryptoFuture);
 pub struct CryptoPerpetualModel(pub CryptoPerpetual);
 pub struct CurrencyPairModel(pub CurrencyPair);
 pub struct EquityModel(pub Equity);
 pub struct FuturesContractModel(pub FuturesContract);
 pub struct FuturesSpreadModel(pub FuturesSpread);
+pub struct FxForwardModel(pub FxForward);
+pub struct FxSwapModel(pub FxSwap);
 pub struct OptionContractModel(pub OptionContract);
 pub struct OptionSpreadModel(pub OptionSpread);
 
+/// The terms of an instrument stored alongside the fields common to all instruments.
+#[derive(Debug, Default)]
+pub struct InstrumentTermsModel {
+    pub coupon_rate: Option<String>,
+    pub coupon_frequency: Option<i32>,
+    pub day_count: Option<String>,
+    pub face_value: Option<String>,
+    pub value_date_ns: Option<String>,
+    pub far_value_date_ns: Option<String>,
+    pub fixing_ns: Option<String>,
+    pub points_precision: Option<i32>,
+}
+
+impl From<&Bond> for InstrumentTermsModel {
+    fn from(bond: &Bond) -> Self {
+        Self {
+            coupon_rate: Some(bond.coupon_rate.to_string()),
+            coupon_frequency: Some(i32::from(bond.coupon_frequency)),
+            day_count: Some(bond.day_count.to_string()),
+            face_value: Some(bond.face_value.to_string()),
+            ..Self::default()
+        }
+    }
+}
+
+impl From<&FxForward> for InstrumentTermsModel {
+    fn from(forward: &FxForward) -> Self {
+        Self {
+            value_date_ns: Some(forward.value_date_ns.to_string()),
+            fixing_ns: forward.fixing_ns.map(|x| x.to_string()),
+            points_precision: Some(i32::from(forward.points_precision)),
+            ..Self::default()
+        }
+    }
+}
+
+impl From<&FxSwap> for InstrumentTermsModel {
+    fn from(swap: &FxSwap) -> Self {
+        Self {
+            value_date_ns: Some(swap.near_value_ns.to_string()),
+            far_value_date_ns: Some(swap.far_value_ns.to_string()),
+            points_precision: Some(i32::from(swap.points_precision)),
+            ..Self::default()
+        }
+    }
+}
+
 // TBD
 impl<'r> FromRow<'r, PgRow> for InstrumentAnyModel {
     fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
@@ -59,6 +110,10 @@ impl<'r> FromRow<'r, PgRow> for InstrumentAnyModel {
             Ok(InstrumentAnyModel(InstrumentAny::BinaryOption(
                 BinaryOptionModel::from_row(row).unwrap().0,
             )))
+        } else if kind == "BOND" {
+            Ok(InstrumentAnyModel(InstrumentAny::Bond(
+                BondModel::from_row(row).unwrap().0,
+            )))
         } else if kind == "CRYPTO_FUTURE" {
             Ok(InstrumentAnyModel(InstrumentAny::CryptoFuture(
                 CryptoFutureModel::from_row(row).unwrap().0,
@@ -83,6 +138,14 @@ impl<'r> FromRow<'r, PgRow> for InstrumentAnyModel {
             Ok(InstrumentAnyModel(InstrumentAny::FuturesSpread(
                 FuturesSpreadModel::from_row(row).unwrap().0,
             )))
+        } else if kind == "FX_FORWARD" {
+            Ok(InstrumentAnyModel(InstrumentAny::FxForward(
+                FxForwardModel::from_row(row).unwrap().0,
+            )))
+        } else if kind == "FX_SWAP" {
+            Ok(InstrumentAnyModel(InstrumentAny::FxSwap(
+                FxSwapModel::from_row(row).unwrap().0,
+            )))
         } else if kind == "OPTION_CONTRACT" {
             Ok(InstrumentAnyModel(InstrumentAny::OptionContract(
                 OptionContractModel::from_row(row).unwrap().0,
@@ -783,12 +846,297 @@ impl<'r> FromRow<'r, PgRow> for FuturesContractModel {
     }
 }
 
+impl<'r> FromRow<'r, PgRow> for BondModel {
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        let id = row.try_get::<String, _>("id").map(InstrumentId::from)?;
+        let raw_symbol = row.try_get::<String, _>("raw_symbol").map(Symbol::from)?;
+        let isin = row
+            .try_get::<Option<String>, _>("isin")
+            .map(|res| res.map(|s| Ustr::from(s.as_str())))?;
+        let exchange = row
+            .try_get::<Option<String>, _>("exchange")
+            .map(|res| res.map(|s| Ustr::from(s.as_str())))?;
+        let currency = row
+            .try_get::<String, _>("quote_currency")
+            .map(Currency::from)?;
+        let coupon_rate = row
+            .try_get::<String, _>("coupon_rate")
+            .map(|res| Decimal::from_str(res.as_str()).unwrap())?;
+        let coupon_frequency = row.try_get::<i32, _>("coupon_frequency")?;
+        let day_count = row
+            .try_get::<String, _>("day_count")
+            .map(|res| DayCountConvention::from_str(res.as_str()).unwrap())?;
+        let face_value = row
+            .try_get::<String, _>("face_value")
+            .map(|res| Quantity::from(res.as_str()))?;
+        let issue_ns = row
+            .try_get::<String, _>("activation_ns")
+            .map(UnixNanos::from)?;
+        let maturity_ns = row
+            .try_get::<String, _>("expiration_ns")
+            .map(UnixNanos::from)?;
+        let price_precision = row.try_get::<i32, _>("price_precision")?;
+        let price_increment = row
+            .try_get::<String, _>("price_increment")
+            .map(|res| Price::from_str(res.as_str()).unwrap())?;
+        let size_precision = row.try_get::<i32, _>("size_precision")?;
+        let size_increment = row
+            .try_get::<String, _>("size_increment")
+            .map(|res| Quantity::from_str(res.as_str()).unwrap())?;
+        let lot_size = row
+            .try_get::<Option<String>, _>("lot_size")
+            .map(|res| res.map(|s| Quantity::from_str(s.as_str()).unwrap()))?;
+        let max_quantity = row
+            .try_get::<Option<String>, _>("max_quantity")
+            .ok()
+            .and_then(|res| res.map(|s| Quantity::from_str(s.as_str()).unwrap()));
+        let min_quantity = row
+            .try_get::<Option<String>, _>("min_quantity")
+            .ok()
+            .and_then(|res| res.map(|s| Quantity::from_str(s.as_str()).unwrap()));
+        let max_price = row
+            .try_get::<Option<String>, _>("max_price")
+            .ok()
+            .and_then(|res| res.map(|s| Price::from(s.as_str())));
+        let min_price = row
+            .try_get::<Option<String>, _>("min_price")
+            .ok()
+            .and_then(|res| res.map(|s| Price::from(s.as_str())));
+        let margin_init = row
+            .try_get::<String, _>("margin_init")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let margin_maint = row
+            .try_get::<String, _>("margin_maint")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let maker_fee = row
+            .try_get::<String, _>("maker_fee")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let taker_fee = row
+            .try_get::<String, _>("taker_fee")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let ts_event = row.try_get::<String, _>("ts_event").map(UnixNanos::from)?;
+        let ts_init = row.try_get::<String, _>("ts_init").map(UnixNanos::from)?;
+
+        let inst = Bond::new(
+            id,
+            raw_symbol,
+            isin,
+            exchange,
+            currency,
+            coupon_rate,
+            coupon_frequency as u8,
+            day_count,
+            face_value,
+            issue_ns,
+            maturity_ns,
+            price_precision as u8,
+            price_increment,
+            size_precision as u8,
+            size_increment,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_price,
+            min_price,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+            ts_event,
+            ts_init,
+        );
+        Ok(BondModel(inst))
+    }
+}
+
 impl<'r> FromRow<'r, PgRow> for FuturesSpreadModel {
     fn from_row(_row: &'r PgRow) -> Result<Self, sqlx::Error> {
         todo!("Implement FromRow for FuturesSpread")
     }
 }
 
+impl<'r> FromRow<'r, PgRow> for FxForwardModel {
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        let id = row.try_get::<String, _>("id").map(InstrumentId::from)?;
+        let raw_symbol = row.try_get::<String, _>("raw_symbol").map(Symbol::from)?;
+        let base_currency = row
+            .try_get::<String, _>("base_currency")
+            .map(Currency::from)?;
+        let quote_currency = row
+            .try_get::<String, _>("quote_currency")
+            .map(Currency::from)?;
+        let settlement_currency = row
+            .try_get::<String, _>("settlement_currency")
+            .map(Currency::from)?;
+        let settlement_type = row
+            .try_get::<String, _>("settlement_type")
+            .map(|res| SettlementType::from_str(res.as_str()).unwrap())?;
+        let value_date_ns = row
+            .try_get::<String, _>("value_date_ns")
+            .map(UnixNanos::from)?;
+        let fixing_ns = row
+            .try_get::<Option<String>, _>("fixing_ns")
+            .map(|res| res.map(UnixNanos::from))?;
+        let points_precision = row.try_get::<i32, _>("points_precision")?;
+        let price_precision = row.try_get::<i32, _>("price_precision")?;
+        let price_increment = row
+            .try_get::<String, _>("price_increment")
+            .map(|res| Price::from_str(res.as_str()).unwrap())?;
+        let size_precision = row.try_get::<i32, _>("size_precision")?;
+        let size_increment = row
+            .try_get::<String, _>("size_increment")
+            .map(|res| Quantity::from_str(res.as_str()).unwrap())?;
+        let lot_size = row
+            .try_get::<Option<String>, _>("lot_size")
+            .map(|res| res.map(|s| Quantity::from_str(s.as_str()).unwrap()))?;
+        let max_quantity = row
+            .try_get::<Option<String>, _>("max_quantity")
+            .ok()
+            .and_then(|res| res.map(|s| Quantity::from_str(s.as_str()).unwrap()));
+        let min_quantity = row
+            .try_get::<Option<String>, _>("min_quantity")
+            .ok()
+            .and_then(|res| res.map(|s| Quantity::from_str(s.as_str()).unwrap()));
+        let max_price = row
+            .try_get::<Option<String>, _>("max_price")
+            .ok()
+            .and_then(|res| res.map(|s| Price::from(s.as_str())));
+        let min_price = row
+            .try_get::<Option<String>, _>("min_price")
+            .ok()
+            .and_then(|res| res.map(|s| Price::from(s.as_str())));
+        let margin_init = row
+            .try_get::<String, _>("margin_init")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let margin_maint = row
+            .try_get::<String, _>("margin_maint")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let maker_fee = row
+            .try_get::<String, _>("maker_fee")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let taker_fee = row
+            .try_get::<String, _>("taker_fee")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let ts_event = row.try_get::<String, _>("ts_event").map(UnixNanos::from)?;
+        let ts_init = row.try_get::<String, _>("ts_init").map(UnixNanos::from)?;
+
+        let inst = FxForward::new(
+            id,
+            raw_symbol,
+            base_currency,
+            quote_currency,
+            settlement_currency,
+            settlement_type,
+            value_date_ns,
+            fixing_ns,
+            points_precision as u8,
+            price_precision as u8,
+            price_increment,
+            size_precision as u8,
+            size_increment,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_price,
+            min_price,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+            ts_event,
+            ts_init,
+        );
+        Ok(FxForwardModel(inst))
+    }
+}
+
+impl<'r> FromRow<'r, PgRow> for FxSwapModel {
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        let id = row.try_get::<String, _>("id").map(InstrumentId::from)?;
+        let raw_symbol = row.try_get::<String, _>("raw_symbol").map(Symbol::from)?;
+        let base_currency = row
+            .try_get::<String, _>("base_currency")
+            .map(Currency::from)?;
+        let quote_currency = row
+            .try_get::<String, _>("quote_currency")
+            .map(Currency::from)?;
+        let near_value_ns = row
+            .try_get::<String, _>("value_date_ns")
+            .map(UnixNanos::from)?;
+        let far_value_ns = row
+            .try_get::<String, _>("far_value_date_ns")
+            .map(UnixNanos::from)?;
+        let points_precision = row.try_get::<i32, _>("points_precision")?;
+        let price_precision = row.try_get::<i32, _>("price_precision")?;
+        let price_increment = row
+            .try_get::<String, _>("price_increment")
+            .map(|res| Price::from_str(res.as_str()).unwrap())?;
+        let size_precision = row.try_get::<i32, _>("size_precision")?;
+        let size_increment = row
+            .try_get::<String, _>("size_increment")
+            .map(|res| Quantity::from_str(res.as_str()).unwrap())?;
+        let lot_size = row
+            .try_get::<Option<String>, _>("lot_size")
+            .map(|res| res.map(|s| Quantity::from_str(s.as_str()).unwrap()))?;
+        let max_quantity = row
+            .try_get::<Option<String>, _>("max_quantity")
+            .ok()
+            .and_then(|res| res.map(|s| Quantity::from_str(s.as_str()).unwrap()));
+        let min_quantity = row
+            .try_get::<Option<String>, _>("min_quantity")
+            .ok()
+            .and_then(|res| res.map(|s| Quantity::from_str(s.as_str()).unwrap()));
+        let max_price = row
+            .try_get::<Option<String>, _>("max_price")
+            .ok()
+            .and_then(|res| res.map(|s| Price::from(s.as_str())));
+        let min_price = row
+            .try_get::<Option<String>, _>("min_price")
+            .ok()
+            .and_then(|res| res.map(|s| Price::from(s.as_str())));
+        let margin_init = row
+            .try_get::<String, _>("margin_init")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let margin_maint = row
+            .try_get::<String, _>("margin_maint")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let maker_fee = row
+            .try_get::<String, _>("maker_fee")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let taker_fee = row
+            .try_get::<String, _>("taker_fee")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let ts_event = row.try_get::<String, _>("ts_event").map(UnixNanos::from)?;
+        let ts_init = row.try_get::<String, _>("ts_init").map(UnixNanos::from)?;
+
+        let inst = FxSwap::new(
+            id,
+            raw_symbol,
+            base_currency,
+            quote_currency,
+            near_value_ns,
+            far_value_ns,
+            points_precision as u8,
+            price_precision as u8,
+            price_increment,
+            size_precision as u8,
+            size_increment,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_price,
+            min_price,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+            ts_event,
+            ts_init,
+        );
+        Ok(FxSwapModel(inst))
+    }
+}
+
 impl<'r> FromRow<'r, PgRow> for OptionContractModel {
     fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
         let id = row.try_get::<String, _>("id").map(InstrumentId::from)?;
@@ -815,8 +1163,19 @@ impl<'r> FromRow<'r, PgRow> for OptionContractModel {
             .try_get::<String, _>("strike_price")
             .map(|res| Price::from_str(res.as_str()).unwrap())?;
         let currency = row
+            .try_get::<String, _>("settlement_currency")
+            .map(Currency::from)?;
+        let premium_currency = row
             .try_get::<String, _>("quote_currency")
             .map(Currency::from)?;
+        let exercise_style = row
+            .try_get::<Option<String>, _>("exercise_style")
+            .ok()
+            .and_then(|res| res.map(|s| ExerciseStyle::from_str(s.as_str()).unwrap()));
+        let settlement_type = row
+            .try_get::<Option<String>, _>("settlement_type")
+            .ok()
+            .and_then(|res| res.map(|s| SettlementType::from_str(s.as_str()).unwrap()));
         let price_precision = row.try_get::<i32, _>("price_precision").unwrap();
         let price_increment = row
             .try_get::<String, _>("price_increment")
@@ -882,6 +1241,9 @@ impl<'r> FromRow<'r, PgRow> for OptionContractModel {
             margin_maint,
             maker_fee,
             taker_fee,
+            exercise_style,
+            settlement_type,
+            (premium_currency != currency).then_some(premium_currency),
             ts_event,
             ts_init,
         );
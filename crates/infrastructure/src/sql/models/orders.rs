@@ -31,6 +31,7 @@ use nautilus_model::{
         AccountId, ClientOrderId, ExecAlgorithmId, InstrumentId, OrderListId, PositionId,
         StrategyId, TradeId, TraderId, VenueOrderId,
     },
+    orders::OrderAmendment,
     types::{Currency, Money, Price, Quantity},
 };
 use rust_decimal::Decimal;
@@ -222,7 +223,12 @@ impl<'r> FromRow<'r, PgRow> for OrderInitializedModel {
             .ok()
             .and_then(|x| x.map(|x| serde_json::from_value::<Vec<String>>(x).unwrap()))
             .map(|x| x.into_iter().map(|x| Ustr::from(x.as_str())).collect());
-        let order_event = OrderInitialized::new(
+        let metadata: Option<IndexMap<Ustr, Ustr>> = row
+            .try_get::<Option<serde_json::Value>, _>("metadata")
+            .ok()
+            .flatten()
+            .and_then(|x| serde_json::from_value(x).ok());
+        let mut order_event = OrderInitialized::new(
             trader_id,
             strategy_id,
             instrument_id,
@@ -257,6 +263,7 @@ impl<'r> FromRow<'r, PgRow> for OrderInitializedModel {
             exec_spawn_id,
             tags,
         );
+        order_event.metadata = metadata;
         Ok(OrderInitializedModel(order_event))
     }
 }
@@ -614,6 +621,17 @@ impl<'r> FromRow<'r, PgRow> for OrderSnapshotModel {
                             .collect::<Vec<Ustr>>()
                     })
             });
+        let metadata: Option<IndexMap<Ustr, Ustr>> = row
+            .try_get::<Option<serde_json::Value>, _>("metadata")
+            .ok()
+            .flatten()
+            .and_then(|x| serde_json::from_value(x).ok());
+        let amendments: Vec<OrderAmendment> = row
+            .try_get::<Option<serde_json::Value>, _>("amendments")
+            .ok()
+            .flatten()
+            .and_then(|x| serde_json::from_value(x).ok())
+            .unwrap_or_default();
         let init_id = row.try_get::<&str, _>("init_id").map(UUID4::from)?;
         let ts_init = row.try_get::<String, _>("ts_init").map(UnixNanos::from)?;
         let ts_last = row.try_get::<String, _>("ts_last").map(UnixNanos::from)?;
@@ -658,6 +676,8 @@ impl<'r> FromRow<'r, PgRow> for OrderSnapshotModel {
             exec_algorithm_params,
             exec_spawn_id,
             tags,
+            metadata,
+            amendments,
             init_id,
             ts_init,
             ts_last,
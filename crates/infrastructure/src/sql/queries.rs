@@ -43,7 +43,7 @@ use crate::sql::models::{
         CurrencyTypeModel, PriceTypeModel, TrailingOffsetTypeModel,
     },
     general::{GeneralRow, OrderEventOrderClientIdCombination},
-    instruments::InstrumentAnyModel,
+    instruments::{InstrumentAnyModel, InstrumentTermsModel},
     orders::OrderEventAnyModel,
     types::CurrencyModel,
 };
@@ -120,21 +120,36 @@ impl DatabaseQueries {
         pool: &PgPool,
         kind: &str,
         instrument: Box<dyn Instrument>,
+    ) -> anyhow::Result<()> {
+        Self::add_instrument_with_terms(pool, kind, instrument, InstrumentTermsModel::default())
+            .await
+    }
+
+    /// Adds the instrument along with the `terms` specific to its kind (such as the coupon of a bond).
+    pub async fn add_instrument_with_terms(
+        pool: &PgPool,
+        kind: &str,
+        instrument: Box<dyn Instrument>,
+        terms: InstrumentTermsModel,
     ) -> anyhow::Result<()> {
         sqlx::query(r#"
             INSERT INTO "instrument" (
                 id, kind, raw_symbol, base_currency, underlying, quote_currency, settlement_currency, isin, asset_class, exchange,
                 multiplier, option_kind, is_inverse, strike_price, activation_ns, expiration_ns, price_precision, size_precision,
                 price_increment, size_increment, maker_fee, taker_fee, margin_init, margin_maint, lot_size, max_quantity, min_quantity, max_notional,
-                min_notional, max_price, min_price, ts_init, ts_event, created_at, updated_at
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9::asset_class, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26, $27, $28, $29, $30, $31, $32, $33, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+                min_notional, max_price, min_price, ts_init, ts_event, exercise_style, settlement_type, coupon_rate, coupon_frequency, day_count, face_value,
+                value_date_ns, far_value_date_ns, fixing_ns, points_precision, created_at, updated_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9::asset_class, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26, $27, $28, $29, $30, $31, $32, $33, $34, $35, $36, $37, $38, $39, $40, $41, $42, $43, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
             ON CONFLICT (id)
             DO UPDATE
             SET
                 kind = $2, raw_symbol = $3, base_currency= $4, underlying = $5, quote_currency = $6, settlement_currency = $7, isin = $8, asset_class = $9, exchange = $10,
                  multiplier = $11, option_kind = $12, is_inverse = $13, strike_price = $14, activation_ns = $15, expiration_ns = $16 , price_precision = $17, size_precision = $18,
                  price_increment = $19, size_increment = $20, maker_fee = $21, taker_fee = $22, margin_init = $23, margin_maint = $24, lot_size = $25, max_quantity = $26,
-                 min_quantity = $27, max_notional = $28, min_notional = $29, max_price = $30, min_price = $31, ts_init = $32,  ts_event = $33, updated_at = CURRENT_TIMESTAMP
+                 min_quantity = $27, max_notional = $28, min_notional = $29, max_price = $30, min_price = $31, ts_init = $32,  ts_event = $33,
+                 exercise_style = $34, settlement_type = $35, coupon_rate = $36, coupon_frequency = $37, day_count = $38, face_value = $39,
+                 value_date_ns = $40, far_value_date_ns = $41, fixing_ns = $42, points_precision = $43,
+                 updated_at = CURRENT_TIMESTAMP
             "#)
             .bind(instrument.id().to_string())
             .bind(kind)
@@ -169,6 +184,16 @@ impl DatabaseQueries {
             .bind(instrument.min_price().map(|x| x.to_string()))
             .bind(instrument.ts_init().to_string())
             .bind(instrument.ts_event().to_string())
+            .bind(instrument.exercise_style().map(|x| x.to_string()))
+            .bind(instrument.settlement_type().map(|x| x.to_string()))
+            .bind(terms.coupon_rate)
+            .bind(terms.coupon_frequency)
+            .bind(terms.day_count)
+            .bind(terms.face_value)
+            .bind(terms.value_date_ns)
+            .bind(terms.far_value_date_ns)
+            .bind(terms.fixing_ns)
+            .bind(terms.points_precision)
             .execute(pool)
             .await
             .map(|_| ())
@@ -297,11 +322,11 @@ impl DatabaseQueries {
                 is_post_only, is_reduce_only, is_quote_quantity, display_qty, emulation_trigger,
                 trigger_instrument_id, contingency_type, order_list_id, linked_order_ids,
                 parent_order_id, exec_algorithm_id, exec_algorithm_params, exec_spawn_id, tags, init_id, ts_init, ts_last,
-                created_at, updated_at
+                metadata, amendments, created_at, updated_at
             ) VALUES (
                 $1, $2, $3, $4, $1, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16,
                 $17::TRAILING_OFFSET_TYPE, $18, $19, $20, $21, $22, $23, $24, $25, $26, $27, $28,
-                $29, $30, $31, $32, $33, $34, $35, $36, $37, $38, $39, $40, $41, $42,
+                $29, $30, $31, $32, $33, $34, $35, $36, $37, $38, $39, $40, $41, $42, $43, $44,
                 CURRENT_TIMESTAMP, CURRENT_TIMESTAMP
             )
             ON CONFLICT (id)
@@ -347,6 +372,8 @@ impl DatabaseQueries {
                 init_id = $40,
                 ts_init = $41,
                 ts_last = $42,
+                metadata = $43,
+                amendments = $44,
                 updated_at = CURRENT_TIMESTAMP
         "#)
             .bind(snapshot.client_order_id.to_string())  // Used for both id and client_order_id
@@ -391,6 +418,8 @@ impl DatabaseQueries {
             .bind(snapshot.init_id.to_string())
             .bind(snapshot.ts_init.to_string())
             .bind(snapshot.ts_last.to_string())
+            .bind(snapshot.metadata.map(|x| serde_json::to_value(x).unwrap()))
+            .bind(serde_json::to_value(snapshot.amendments).unwrap())
             .execute(&mut *transaction)
             .await
             .map(|_| ())
@@ -570,11 +599,11 @@ impl DatabaseQueries {
                 post_only, reduce_only, quote_quantity, reconciliation, price, last_px, last_qty, trigger_price, trigger_type, limit_offset, trailing_offset,
                 trailing_offset_type, expire_time, display_qty, emulation_trigger, trigger_instrument_id, contingency_type,
                 order_list_id, linked_order_ids, parent_order_id,
-                exec_algorithm_id, exec_spawn_id, venue_order_id, account_id, position_id, commission, ts_event, ts_init, created_at, updated_at
+                exec_algorithm_id, exec_spawn_id, venue_order_id, account_id, position_id, commission, ts_event, ts_init, metadata, created_at, updated_at
             ) VALUES (
                 $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20,
                 $21, $22, $23, $24, $25::trailing_offset_type, $26, $27, $28, $29, $30, $31, $32, $33, $34,
-                $35, $36, $37, $38, $39, $40, $41, $42,  CURRENT_TIMESTAMP, CURRENT_TIMESTAMP
+                $35, $36, $37, $38, $39, $40, $41, $42, $43, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP
             )
             ON CONFLICT (id)
             DO UPDATE
@@ -583,7 +612,7 @@ impl DatabaseQueries {
                 quantity = $12, time_in_force = $13, liquidity_side = $14, post_only = $15, reduce_only = $16, quote_quantity = $17, reconciliation = $18, price = $19, last_px = $20,
                 last_qty = $21, trigger_price = $22, trigger_type = $23, limit_offset = $24, trailing_offset = $25, trailing_offset_type = $26, expire_time = $27, display_qty = $28,
                 emulation_trigger = $29, trigger_instrument_id = $30, contingency_type = $31, order_list_id = $32, linked_order_ids = $33, parent_order_id = $34, exec_algorithm_id = $35,
-                exec_spawn_id = $36, venue_order_id = $37, account_id = $38, position_id = $39, commission = $40, ts_event = $41, ts_init = $42, updated_at = CURRENT_TIMESTAMP
+                exec_spawn_id = $36, venue_order_id = $37, account_id = $38, position_id = $39, commission = $40, ts_event = $41, ts_init = $42, metadata = $43, updated_at = CURRENT_TIMESTAMP
 
         "#)
             .bind(order_event.id().to_string())
@@ -628,6 +657,7 @@ impl DatabaseQueries {
             .bind(order_event.commission().map(|x| x.to_string()))
             .bind(order_event.ts_event().to_string())
             .bind(order_event.ts_init().to_string())
+            .bind(order_event.metadata().map(|x| serde_json::to_value(x).unwrap()))
             .execute(&mut *transaction)
             .await
             .map(|_| ())
@@ -42,8 +42,9 @@ mod serial_tests {
         },
         instruments::{
             stubs::{
-                audusd_sim, binary_option, crypto_future_btcusdt, crypto_perpetual_ethusdt,
-                currency_pair_ethusdt, equity_aapl, futures_contract_es, option_contract_appl,
+                audusd_sim, binary_option, bond_ust, crypto_future_btcusdt,
+                crypto_perpetual_ethusdt, currency_pair_ethusdt, equity_aapl, futures_contract_es,
+                fx_forward_usdinr_ndf, fx_swap_eurusd, option_contract_appl,
             },
             Instrument, InstrumentAny,
         },
@@ -302,6 +303,83 @@ mod serial_tests {
         pg_cache.close().unwrap();
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_add_bond_and_load_terms() {
+        let mut pg_cache = get_pg_cache_database().await.unwrap();
+
+        let bond = bond_ust();
+        pg_cache.add_currency(&bond.currency).unwrap();
+        pg_cache.add_instrument(&InstrumentAny::Bond(bond)).unwrap();
+        wait_until_async(
+            || async { pg_cache.load_instruments().await.unwrap().len() == 1 },
+            Duration::from_secs(2),
+        )
+        .await;
+
+        let loaded = pg_cache.load_instrument(&bond.id).await.unwrap().unwrap();
+        let InstrumentAny::Bond(loaded) = loaded else {
+            panic!("Expected a bond, was {loaded:?}");
+        };
+        assert_eq!(loaded.coupon_rate, bond.coupon_rate);
+        assert_eq!(loaded.coupon_frequency, bond.coupon_frequency);
+        assert_eq!(loaded.day_count, bond.day_count);
+        assert_eq!(loaded.face_value, bond.face_value);
+        assert_eq!(loaded.issue_ns, bond.issue_ns);
+        assert_eq!(loaded.maturity_ns, bond.maturity_ns);
+        assert_eq!(loaded.coupon_dates(), bond.coupon_dates());
+
+        pg_cache.flush().unwrap();
+        pg_cache.close().unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_add_fx_forward_and_swap_and_load_value_dates() {
+        let mut pg_cache = get_pg_cache_database().await.unwrap();
+
+        let forward = fx_forward_usdinr_ndf();
+        let swap = fx_swap_eurusd();
+        for currency in [Currency::EUR(), Currency::INR(), Currency::USD()] {
+            pg_cache.add_currency(&currency).unwrap();
+        }
+        pg_cache
+            .add_instrument(&InstrumentAny::FxForward(forward))
+            .unwrap();
+        pg_cache
+            .add_instrument(&InstrumentAny::FxSwap(swap))
+            .unwrap();
+        wait_until_async(
+            || async { pg_cache.load_instruments().await.unwrap().len() == 2 },
+            Duration::from_secs(2),
+        )
+        .await;
+
+        let loaded = pg_cache
+            .load_instrument(&forward.id)
+            .await
+            .unwrap()
+            .unwrap();
+        let InstrumentAny::FxForward(loaded) = loaded else {
+            panic!("Expected an FX forward, was {loaded:?}");
+        };
+        assert_eq!(loaded.settlement_currency, forward.settlement_currency);
+        assert_eq!(loaded.settlement_type, forward.settlement_type);
+        assert_eq!(loaded.value_date_ns, forward.value_date_ns);
+        assert_eq!(loaded.fixing_ns, forward.fixing_ns);
+        assert_eq!(loaded.points_precision, forward.points_precision);
+
+        let loaded = pg_cache.load_instrument(&swap.id).await.unwrap().unwrap();
+        let InstrumentAny::FxSwap(loaded) = loaded else {
+            panic!("Expected an FX swap, was {loaded:?}");
+        };
+        assert_eq!(loaded.near_value_ns, swap.near_value_ns);
+        assert_eq!(loaded.far_value_ns, swap.far_value_ns);
+        assert_eq!(loaded.points_precision, swap.points_precision);
+        assert_eq!(loaded.multiplier, swap.multiplier);
+
+        pg_cache.flush().unwrap();
+        pg_cache.close().unwrap();
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn test_truncate() {
         let mut pg_cache = get_pg_cache_database().await.unwrap();
@@ -155,6 +155,9 @@ impl Runner for LiveRunner {
                 Some(RunnerEvent::Data(resp)) => match resp {
                     DataEvent::Response(resp) => engine.response(resp),
                     DataEvent::Data(data) => engine.process_data(data),
+                    DataEvent::FeedData(client_id, data) => {
+                        engine.process_feed_data(client_id, data);
+                    }
                 },
                 Some(RunnerEvent::Timer(event)) => self.clock.borrow().get_handler(event).run(),
                 None => break,
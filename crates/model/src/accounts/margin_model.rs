@@ -0,0 +1,256 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Margin models computing the initial and maintenance margin of derivatives positions.
+
+use std::fmt::Display;
+
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{InstrumentClass, PositionSide},
+    instruments::InstrumentAny,
+    types::{Money, Price, Quantity},
+};
+
+/// Represents a leverage tier of a venue, applying to positions with a notional value up to
+/// `max_notional` (in the settlement currency of the instrument).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LeverageTier {
+    /// The maximum notional value of a position in the tier.
+    pub max_notional: f64,
+    /// The maximum leverage allowed in the tier.
+    pub max_leverage: f64,
+    /// The maintenance margin rate of the tier, as a fraction of the notional value.
+    pub maint_rate: f64,
+}
+
+/// The formula a venue computes margin requirements with.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum MarginModel {
+    /// The notional value divided by the account leverage, scaled by the instrument margin rates
+    /// and including taker fees (the formula of `MarginAccount`).
+    #[default]
+    Leveraged,
+    /// The notional value scaled by the instrument margin rates, where the account leverage is
+    /// ignored (as for exchange-set futures margins).
+    Standard,
+    /// Leverage tiers by notional value, where the leverage is capped at the tier maximum and the
+    /// maintenance margin uses the tier rate. Tiers are expected in ascending `max_notional`
+    /// order, with notional values beyond the last tier using the last tier.
+    Tiered(Vec<LeverageTier>),
+}
+
+impl Display for MarginModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Leveraged => write!(f, "LEVERAGED"),
+            Self::Standard => write!(f, "STANDARD"),
+            Self::Tiered(tiers) => write!(f, "TIERED(tiers={})", tiers.len()),
+        }
+    }
+}
+
+/// Represents the initial and maintenance margin required for a position.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MarginRequirement {
+    pub initial: Money,
+    pub maintenance: Money,
+}
+
+impl MarginModel {
+    /// Calculates the margin required for a position of `quantity` at `price` on the given
+    /// `side`, with the account `leverage` for the instrument.
+    ///
+    /// Long option positions require their premium in full and no maintenance margin, while
+    /// short option positions are margined by the model on their premium.
+    #[must_use]
+    pub fn calculate(
+        &self,
+        instrument: &InstrumentAny,
+        side: PositionSide,
+        quantity: Quantity,
+        price: Price,
+        leverage: f64,
+        use_quote_for_inverse: Option<bool>,
+    ) -> MarginRequirement {
+        let notional = instrument.calculate_notional_value(quantity, price, use_quote_for_inverse);
+        let currency = notional.currency;
+        let notional = notional.as_f64();
+        let leverage = if leverage > 0.0 { leverage } else { 1.0 };
+
+        let is_option = matches!(
+            instrument.instrument_class(),
+            InstrumentClass::Option | InstrumentClass::OptionSpread
+        );
+        if is_option && side == PositionSide::Long {
+            return MarginRequirement {
+                initial: Money::new(notional, currency),
+                maintenance: Money::new(0.0, currency),
+            };
+        }
+
+        let margin_init = instrument.margin_init().to_f64().unwrap_or_default();
+        let margin_maint = instrument.margin_maint().to_f64().unwrap_or_default();
+        let (initial, maintenance) = match self {
+            Self::Leveraged => {
+                let adjusted_notional = notional / leverage;
+                let taker_fee = instrument.taker_fee().to_f64().unwrap_or_default();
+                (
+                    adjusted_notional * margin_init + adjusted_notional * taker_fee * 2.0,
+                    adjusted_notional * margin_maint + adjusted_notional * taker_fee,
+                )
+            }
+            Self::Standard => (notional * margin_init, notional * margin_maint),
+            Self::Tiered(tiers) => {
+                match tiers
+                    .iter()
+                    .find(|tier| notional <= tier.max_notional)
+                    .or_else(|| tiers.last())
+                {
+                    Some(tier) => (
+                        notional / leverage.min(tier.max_leverage),
+                        notional * tier.maint_rate,
+                    ),
+                    None => (notional / leverage, 0.0),
+                }
+            }
+        };
+
+        MarginRequirement {
+            initial: Money::new(initial, currency),
+            maintenance: Money::new(maintenance, currency),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        instruments::{
+            stubs::{crypto_perpetual_ethusdt, futures_contract_es, option_contract_appl},
+            CryptoPerpetual, OptionContract,
+        },
+        types::Currency,
+    };
+
+    fn tiers() -> Vec<LeverageTier> {
+        vec![
+            LeverageTier {
+                max_notional: 50_000.0,
+                max_leverage: 20.0,
+                maint_rate: 0.01,
+            },
+            LeverageTier {
+                max_notional: 250_000.0,
+                max_leverage: 10.0,
+                maint_rate: 0.025,
+            },
+        ]
+    }
+
+    #[rstest]
+    fn test_leveraged_matches_margin_account_formula(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+
+        let margin = MarginModel::Leveraged.calculate(
+            &instrument,
+            PositionSide::Long,
+            Quantity::from("10.000"),
+            Price::from("2000.00"),
+            10.0,
+            None,
+        );
+
+        // 20_000 / 10 * (1.0 + 2 * 0.0004) and 20_000 / 10 * (0.35 + 0.0004)
+        assert_eq!(margin.initial, Money::new(2_001.60, Currency::USDT()));
+        assert_eq!(margin.maintenance, Money::new(700.80, Currency::USDT()));
+    }
+
+    #[rstest]
+    fn test_standard_ignores_leverage(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+
+        let margin = MarginModel::Standard.calculate(
+            &instrument,
+            PositionSide::Short,
+            Quantity::from("1.000"),
+            Price::from("2000.00"),
+            50.0,
+            None,
+        );
+
+        assert_eq!(margin.initial, Money::new(2_000.0, Currency::USDT()));
+        assert_eq!(margin.maintenance, Money::new(700.0, Currency::USDT()));
+    }
+
+    #[rstest]
+    #[case(100, 20.0, 1_000.0, 200.0)] // Tier 1 capped at 20x
+    #[case(100, 5.0, 4_000.0, 200.0)] // Account leverage below the cap
+    #[case(1_000, 20.0, 20_000.0, 5_000.0)] // Tier 2 capped at 10x
+    #[case(2_000, 20.0, 40_000.0, 10_000.0)] // Beyond the last tier
+    fn test_tiered(
+        #[case] quantity: u64,
+        #[case] leverage: f64,
+        #[case] expected_initial: f64,
+        #[case] expected_maintenance: f64,
+    ) {
+        let instrument = InstrumentAny::FuturesContract(futures_contract_es(None, None));
+        let model = MarginModel::Tiered(tiers());
+
+        let margin = model.calculate(
+            &instrument,
+            PositionSide::Long,
+            Quantity::from(quantity),
+            Price::from("200.00"),
+            leverage,
+            None,
+        );
+
+        assert_eq!(
+            margin.initial,
+            Money::new(expected_initial, Currency::USD())
+        );
+        assert_eq!(
+            margin.maintenance,
+            Money::new(expected_maintenance, Currency::USD())
+        );
+    }
+
+    #[rstest]
+    fn test_long_option_requires_premium_only(option_contract_appl: OptionContract) {
+        let instrument = InstrumentAny::OptionContract(option_contract_appl);
+        let model = MarginModel::Tiered(tiers());
+
+        let margin = model.calculate(
+            &instrument,
+            PositionSide::Long,
+            Quantity::from(10),
+            Price::from("5.00"),
+            10.0,
+            None,
+        );
+
+        assert_eq!(margin.initial, Money::new(50.0, Currency::USD()));
+        assert_eq!(margin.maintenance, Money::new(0.0, Currency::USD()));
+    }
+}
@@ -19,6 +19,7 @@ pub mod any;
 pub mod base;
 pub mod cash;
 pub mod margin;
+pub mod margin_model;
 
 #[cfg(feature = "stubs")]
 pub mod stubs;
@@ -29,4 +30,5 @@ pub use crate::accounts::{
     base::{Account, BaseAccount},
     cash::CashAccount,
     margin::MarginAccount,
+    margin_model::{LeverageTier, MarginModel, MarginRequirement},
 };
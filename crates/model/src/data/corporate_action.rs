@@ -0,0 +1,282 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A `CorporateAction` data type representing a split, dividend or symbol change of an instrument.
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    hash::Hash,
+};
+
+use nautilus_core::{
+    correctness::{check_positive_u64, check_predicate_true},
+    serialization::Serializable,
+    UnixNanos,
+};
+use serde::{Deserialize, Serialize};
+
+use super::GetTsInit;
+use crate::{enums::CorporateActionType, identifiers::InstrumentId, types::Money};
+
+/// Represents a corporate action affecting an instrument from its effective date.
+///
+/// The `ts_event` of the action is the effective date, at which point positions, working orders
+/// and the instrument definition are adjusted.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct CorporateAction {
+    /// The instrument ID the action applies to.
+    pub instrument_id: InstrumentId,
+    /// The corporate action type.
+    pub action_type: CorporateActionType,
+    /// The number of shares held after a split, for every `split_from` shares held before.
+    pub split_to: u32,
+    /// The number of shares held before a split.
+    pub split_from: u32,
+    /// The cash amount paid per share for a dividend.
+    pub dividend: Option<Money>,
+    /// The new instrument ID for a symbol change.
+    pub new_instrument_id: Option<InstrumentId>,
+    /// UNIX timestamp (nanoseconds) when the action becomes effective.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the struct was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl CorporateAction {
+    /// Creates a new split [`CorporateAction`] instance, where every `split_from` shares
+    /// become `split_to` shares (a reverse split has `split_to` less than `split_from`).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `split_to` or `split_from` is zero.
+    /// - If `split_to` equals `split_from`.
+    pub fn split(
+        instrument_id: InstrumentId,
+        split_to: u32,
+        split_from: u32,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        check_positive_u64(u64::from(split_to), stringify!(split_to))?;
+        check_positive_u64(u64::from(split_from), stringify!(split_from))?;
+        check_predicate_true(
+            split_to != split_from,
+            "`split_to` must differ from `split_from`",
+        )?;
+        Ok(Self {
+            instrument_id,
+            action_type: CorporateActionType::Split,
+            split_to,
+            split_from,
+            dividend: None,
+            new_instrument_id: None,
+            ts_event,
+            ts_init,
+        })
+    }
+
+    /// Creates a new cash dividend [`CorporateAction`] instance paying `amount` per share.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `amount` is not positive.
+    pub fn dividend(
+        instrument_id: InstrumentId,
+        amount: Money,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        check_predicate_true(amount.raw > 0, "`amount` must be positive")?;
+        Ok(Self {
+            instrument_id,
+            action_type: CorporateActionType::Dividend,
+            split_to: 1,
+            split_from: 1,
+            dividend: Some(amount),
+            new_instrument_id: None,
+            ts_event,
+            ts_init,
+        })
+    }
+
+    /// Creates a new symbol change [`CorporateAction`] instance, after which the instrument
+    /// trades as `new_instrument_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `new_instrument_id` equals `instrument_id`.
+    /// - If `new_instrument_id` is for a different venue.
+    pub fn symbol_change(
+        instrument_id: InstrumentId,
+        new_instrument_id: InstrumentId,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        check_predicate_true(
+            new_instrument_id != instrument_id,
+            "`new_instrument_id` must differ from `instrument_id`",
+        )?;
+        check_predicate_true(
+            new_instrument_id.venue == instrument_id.venue,
+            "`new_instrument_id` must be for the same venue",
+        )?;
+        Ok(Self {
+            instrument_id,
+            action_type: CorporateActionType::SymbolChange,
+            split_to: 1,
+            split_from: 1,
+            dividend: None,
+            new_instrument_id: Some(new_instrument_id),
+            ts_event,
+            ts_init,
+        })
+    }
+
+    /// Returns the number of shares held after the action for each share held before.
+    #[must_use]
+    pub fn split_ratio(&self) -> f64 {
+        f64::from(self.split_to) / f64::from(self.split_from)
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(instrument_id: &InstrumentId) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+        metadata
+    }
+}
+
+impl Display for CorporateAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.action_type {
+            CorporateActionType::Split => write!(
+                f,
+                "{},{},{}:{},{}",
+                self.instrument_id, self.action_type, self.split_to, self.split_from, self.ts_event,
+            ),
+            CorporateActionType::Dividend => write!(
+                f,
+                "{},{},{},{}",
+                self.instrument_id,
+                self.action_type,
+                self.dividend.map_or_else(String::new, |d| d.to_string()),
+                self.ts_event,
+            ),
+            CorporateActionType::SymbolChange => write!(
+                f,
+                "{},{},{},{}",
+                self.instrument_id,
+                self.action_type,
+                self.new_instrument_id
+                    .map_or_else(String::new, |id| id.to_string()),
+                self.ts_event,
+            ),
+        }
+    }
+}
+
+impl Serializable for CorporateAction {}
+
+impl GetTsInit for CorporateAction {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::serialization::Serializable;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_split_to_string() {
+        let action = CorporateAction::split(
+            InstrumentId::from("AAPL.XNAS"),
+            4,
+            1,
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+        )
+        .unwrap();
+        assert_eq!(action.to_string(), "AAPL.XNAS,SPLIT,4:1,1");
+        assert_eq!(action.split_ratio(), 4.0);
+    }
+
+    #[rstest]
+    #[case(0, 1)]
+    #[case(1, 0)]
+    #[case(2, 2)]
+    fn test_split_with_invalid_ratio(#[case] split_to: u32, #[case] split_from: u32) {
+        let result = CorporateAction::split(
+            InstrumentId::from("AAPL.XNAS"),
+            split_to,
+            split_from,
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+        );
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_dividend_with_non_positive_amount() {
+        let result = CorporateAction::dividend(
+            InstrumentId::from("AAPL.XNAS"),
+            Money::from("0 USD"),
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+        );
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_symbol_change_to_different_venue() {
+        let result = CorporateAction::symbol_change(
+            InstrumentId::from("FB.XNAS"),
+            InstrumentId::from("META.XNYS"),
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+        );
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_json_serialization() {
+        let action = CorporateAction::dividend(
+            InstrumentId::from("AAPL.XNAS"),
+            Money::from("0.24 USD"),
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+        )
+        .unwrap();
+        let serialized = action.as_json_bytes().unwrap();
+        let deserialized = CorporateAction::from_json_bytes(serialized.as_ref()).unwrap();
+        assert_eq!(deserialized, action);
+    }
+}
@@ -0,0 +1,202 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Funding rate data types for perpetual instruments.
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    hash::Hash,
+};
+
+use nautilus_core::{serialization::Serializable, UnixNanos};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::GetTsInit;
+use crate::{
+    identifiers::{InstrumentId, PositionId},
+    types::Money,
+};
+
+/// Represents an update of the funding rate of a perpetual instrument.
+///
+/// A positive rate is paid by long positions to short positions at the next funding time.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct FundingRateUpdate {
+    /// The instrument ID for the funding rate.
+    pub instrument_id: InstrumentId,
+    /// The funding rate applied at the next funding time.
+    pub rate: Decimal,
+    /// The predicted funding rate for the funding interval after the next.
+    pub predicted_rate: Option<Decimal>,
+    /// UNIX timestamp (nanoseconds) of the next funding time.
+    pub next_funding_ns: Option<UnixNanos>,
+    /// UNIX timestamp (nanoseconds) when the funding rate event occurred.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the struct was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl FundingRateUpdate {
+    /// Creates a new [`FundingRateUpdate`] instance.
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        rate: Decimal,
+        predicted_rate: Option<Decimal>,
+        next_funding_ns: Option<UnixNanos>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            rate,
+            predicted_rate,
+            next_funding_ns,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(instrument_id: &InstrumentId) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+        metadata
+    }
+}
+
+impl Display for FundingRateUpdate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{}",
+            self.instrument_id,
+            self.rate,
+            self.predicted_rate
+                .map_or_else(|| "None".to_string(), |rate| rate.to_string()),
+            self.next_funding_ns
+                .map_or_else(|| "None".to_string(), |ts| ts.to_string()),
+            self.ts_event,
+        )
+    }
+}
+
+impl Serializable for FundingRateUpdate {}
+
+impl GetTsInit for FundingRateUpdate {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+/// Represents the projected funding PnL of an open position at the next funding time.
+///
+/// The PnL is positive where the position receives funding, and negative where it pays.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct ProjectedFundingPnl {
+    /// The instrument ID of the position.
+    pub instrument_id: InstrumentId,
+    /// The position ID.
+    pub position_id: PositionId,
+    /// The notional value of the position the funding is applied to.
+    pub notional: Money,
+    /// The funding PnL projected at the funding rate for the next funding time.
+    pub pnl: Money,
+    /// The funding PnL projected at the predicted rate for the interval after the next.
+    pub predicted_pnl: Option<Money>,
+    /// UNIX timestamp (nanoseconds) of the next funding time.
+    pub next_funding_ns: Option<UnixNanos>,
+    /// UNIX timestamp (nanoseconds) when the projection was made.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the struct was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl Display for ProjectedFundingPnl {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{}",
+            self.instrument_id, self.position_id, self.notional, self.pnl, self.ts_event,
+        )
+    }
+}
+
+impl Serializable for ProjectedFundingPnl {}
+
+impl GetTsInit for ProjectedFundingPnl {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    fn funding_rate() -> FundingRateUpdate {
+        FundingRateUpdate::new(
+            InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            Decimal::from_str("0.0001").unwrap(),
+            Some(Decimal::from_str("-0.0002").unwrap()),
+            Some(UnixNanos::from(28_800_000_000_000)),
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+        )
+    }
+
+    #[rstest]
+    fn test_funding_rate_display() {
+        assert_eq!(
+            funding_rate().to_string(),
+            "ETHUSDT-PERP.BINANCE,0.0001,-0.0002,28800000000000,1"
+        );
+    }
+
+    #[rstest]
+    fn test_funding_rate_ts_init() {
+        assert_eq!(funding_rate().ts_init(), UnixNanos::from(2));
+    }
+
+    #[rstest]
+    fn test_funding_rate_json_round_trip() {
+        let funding_rate = funding_rate();
+        let json = funding_rate.as_json_bytes().unwrap();
+        let result = FundingRateUpdate::from_json_bytes(&json).unwrap();
+        assert_eq!(result, funding_rate);
+    }
+}
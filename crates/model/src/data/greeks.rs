@@ -13,7 +13,14 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::fmt::{Display, Formatter};
+
 use implied_vol::{implied_black_volatility, norm_cdf, norm_pdf};
+use nautilus_core::UnixNanos;
+use serde::{Deserialize, Serialize};
+
+use super::GetTsInit;
+use crate::identifiers::InstrumentId;
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
@@ -72,7 +79,7 @@ pub fn black_scholes_greeks(
 }
 
 pub fn imply_vol(s: f64, r: f64, b: f64, is_call: bool, k: f64, t: f64, price: f64) -> f64 {
-    let forward = s * b.exp();
+    let forward = s * (b * t).exp();
     let forward_price = price * (r * t).exp();
 
     implied_black_volatility(forward_price, forward, k, t, is_call)
@@ -117,6 +124,96 @@ pub fn imply_vol_and_greeks(
     }
 }
 
+/// Represents the implied volatility and greeks of an option at a point in time.
+///
+/// The greeks are per contract, so are scaled by the multiplier of the option.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct GreeksData {
+    /// The option instrument ID.
+    pub instrument_id: InstrumentId,
+    /// The underlying price (or forward price for Black-76) used in the calculation.
+    pub underlying_price: f64,
+    /// The continuously compounded risk-free interest rate used in the calculation.
+    pub interest_rate: f64,
+    /// The time to expiry in years.
+    pub expiry_in_years: f64,
+    /// The implied volatility.
+    pub vol: f64,
+    /// The model price of the option.
+    pub price: f64,
+    /// The sensitivity of the price to the underlying price.
+    pub delta: f64,
+    /// The sensitivity of delta to the underlying price.
+    pub gamma: f64,
+    /// The sensitivity of the price to a one percentage point change in volatility.
+    pub vega: f64,
+    /// The sensitivity of the price to the passing of one calendar day.
+    pub theta: f64,
+    /// UNIX timestamp (nanoseconds) when the prices used in the calculation occurred.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the struct was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl GreeksData {
+    /// Creates a new [`GreeksData`] instance from the result of an implied volatility and
+    /// greeks calculation.
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        underlying_price: f64,
+        interest_rate: f64,
+        expiry_in_years: f64,
+        result: ImplyVolAndGreeksResult,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            underlying_price,
+            interest_rate,
+            expiry_in_years,
+            vol: result.vol,
+            price: result.price,
+            delta: result.delta,
+            gamma: result.gamma,
+            vega: result.vega,
+            theta: result.theta,
+            ts_event,
+            ts_init,
+        }
+    }
+}
+
+impl Display for GreeksData {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},vol={:.4},price={:.4},delta={:.4},gamma={:.4},vega={:.4},theta={:.4},{}",
+            self.instrument_id,
+            self.vol,
+            self.price,
+            self.delta,
+            self.gamma,
+            self.vega,
+            self.theta,
+            self.ts_event,
+        )
+    }
+}
+
+impl GetTsInit for GreeksData {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -300,4 +397,43 @@ mod tests {
             "Theta difference exceeds tolerance"
         );
     }
+
+    #[rstest]
+    fn test_imply_vol_with_cost_of_carry_over_partial_year() {
+        let s = 100.0;
+        let k = 95.0;
+        let t = 0.25;
+        let r = 0.05;
+        let b = 0.03;
+        let sigma = 0.3;
+
+        let price = black_scholes_greeks(s, r, b, sigma, true, k, t, 1.0).price;
+        let vol = imply_vol(s, r, b, true, k, t, price);
+
+        assert!(
+            (vol - sigma).abs() < 1e-6,
+            "Vol difference exceeds tolerance"
+        );
+    }
+
+    #[rstest]
+    fn test_greeks_data_display() {
+        let result = imply_vol_and_greeks(100.0, 0.0, 0.0, true, 100.0, 1.0, 7.965_567, 1.0);
+        let greeks = GreeksData::new(
+            InstrumentId::from("AAPL211217C00150000.OPRA"),
+            100.0,
+            0.0,
+            1.0,
+            result,
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+        );
+
+        assert_eq!(greeks.ts_init(), UnixNanos::from(2));
+        assert!((greeks.vol - 0.2).abs() < 1e-5);
+        assert_eq!(
+            greeks.to_string(),
+            "AAPL211217C00150000.OPRA,vol=0.2000,price=7.9656,delta=0.5398,gamma=0.0198,vega=0.3970,theta=-0.0109,1"
+        );
+    }
 }
@@ -17,12 +17,15 @@
 
 pub mod bar;
 pub mod bet;
+pub mod corporate_action;
 pub mod delta;
 pub mod deltas;
 pub mod depth;
+pub mod funding;
 pub mod greeks;
 pub mod order;
 pub mod quote;
+pub mod reference;
 pub mod status;
 pub mod trade;
 
@@ -44,12 +47,15 @@ use serde_json::to_string;
 // Re-exports
 #[rustfmt::skip]  // Keep these grouped
 pub use bar::{Bar, BarSpecification, BarType};
+pub use corporate_action::CorporateAction;
 pub use delta::OrderBookDelta;
 pub use deltas::{OrderBookDeltas, OrderBookDeltas_API};
 pub use depth::{OrderBookDepth10, DEPTH10_LEN};
-pub use greeks::{black_scholes_greeks, BlackScholesGreeksResult};
+pub use funding::{FundingRateUpdate, ProjectedFundingPnl};
+pub use greeks::{black_scholes_greeks, BlackScholesGreeksResult, GreeksData};
 pub use order::{BookOrder, NULL_ORDER};
 pub use quote::QuoteTick;
+pub use reference::ReferencePriceUpdate;
 pub use status::InstrumentStatus;
 pub use trade::TradeTick;
 
@@ -0,0 +1,142 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Benchmark reference price data types, such as VWAP and TWAP.
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    hash::Hash,
+};
+
+use nautilus_core::{serialization::Serializable, UnixNanos};
+use serde::{Deserialize, Serialize};
+
+use super::GetTsInit;
+use crate::{enums::ReferencePriceType, identifiers::InstrumentId, types::Price};
+
+/// Represents an update of a benchmark reference price of an instrument.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct ReferencePriceUpdate {
+    /// The instrument ID for the reference price.
+    pub instrument_id: InstrumentId,
+    /// The method the reference price is calculated with.
+    pub price_type: ReferencePriceType,
+    /// The reference price.
+    pub price: Price,
+    /// UNIX timestamp (nanoseconds) of the start of the period the price is calculated over.
+    pub period_start_ns: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the reference price event occurred.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the struct was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl ReferencePriceUpdate {
+    /// Creates a new [`ReferencePriceUpdate`] instance.
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        price_type: ReferencePriceType,
+        price: Price,
+        period_start_ns: UnixNanos,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            instrument_id,
+            price_type,
+            price,
+            period_start_ns,
+            ts_event,
+            ts_init,
+        }
+    }
+
+    /// Returns the metadata for the type, for use with serialization formats.
+    #[must_use]
+    pub fn get_metadata(
+        instrument_id: &InstrumentId,
+        price_type: ReferencePriceType,
+        price_precision: u8,
+    ) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+        metadata.insert("price_type".to_string(), price_type.to_string());
+        metadata.insert("price_precision".to_string(), price_precision.to_string());
+        metadata
+    }
+}
+
+impl Display for ReferencePriceUpdate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{}",
+            self.instrument_id, self.price_type, self.price, self.period_start_ns, self.ts_event,
+        )
+    }
+}
+
+impl Serializable for ReferencePriceUpdate {}
+
+impl GetTsInit for ReferencePriceUpdate {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn reference_price() -> ReferencePriceUpdate {
+        ReferencePriceUpdate::new(
+            InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            ReferencePriceType::Vwap,
+            Price::from("2000.50"),
+            UnixNanos::from(1),
+            UnixNanos::from(2),
+            UnixNanos::from(3),
+        )
+    }
+
+    #[rstest]
+    fn test_reference_price_display() {
+        assert_eq!(
+            reference_price().to_string(),
+            "ETHUSDT-PERP.BINANCE,VWAP,2000.50,1,2"
+        );
+    }
+
+    #[rstest]
+    fn test_reference_price_json_round_trip() {
+        let reference_price = reference_price();
+        let json = reference_price.as_json_bytes().unwrap();
+        let result = ReferencePriceUpdate::from_json_bytes(&json).unwrap();
+        assert_eq!(result, reference_price);
+    }
+}
@@ -518,6 +518,38 @@ pub enum ContingencyType {
     Ouo = 3,
 }
 
+/// The type of a corporate action affecting an instrument.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    FromRepr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.model.enums")
+)]
+pub enum CorporateActionType {
+    /// A stock split (or reverse split) changing the number of shares outstanding.
+    Split = 1,
+    /// A cash dividend paid per share held.
+    Dividend = 2,
+    /// A change of the instrument symbol.
+    SymbolChange = 3,
+}
+
 /// The broad currency type.
 #[repr(C)]
 #[derive(
@@ -550,6 +582,74 @@ pub enum CurrencyType {
     CommodityBacked = 3,
 }
 
+/// The day count convention for the accrual of interest on a fixed income instrument.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    FromRepr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.model.enums")
+)]
+pub enum DayCountConvention {
+    /// Actual days elapsed over a 360 day year (money markets and bills).
+    Actual360 = 1,
+    /// Actual days elapsed over a 365 day year.
+    Actual365Fixed = 2,
+    /// Actual days elapsed over the actual days in the coupon period (ICMA, government bonds).
+    ActualActual = 3,
+    /// Days elapsed assuming 30 day months over a 360 day year (US bond basis, corporate bonds).
+    Thirty360 = 4,
+}
+
+/// The style of exercise for an option contract.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    FromRepr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.model.enums")
+)]
+pub enum ExerciseStyle {
+    /// The option may be exercised at any time up to and including the expiration date.
+    #[default]
+    American = 1,
+    /// The option may only be exercised on the expiration date.
+    European = 2,
+    /// The option may only be exercised on a set of specified dates prior to expiration.
+    Bermudan = 3,
+}
+
 /// The type of event for an instrument close.
 #[repr(C)]
 #[derive(
@@ -1022,6 +1122,38 @@ pub enum OrderType {
     TrailingStopLimit = 9,
 }
 
+/// The reference price a pegged order is repriced from as the market moves.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    FromRepr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.model.enums")
+)]
+pub enum PegPriceType {
+    /// Pegged to the best price on the same side of the book (the bid for buy orders).
+    Primary = 1,
+    /// Pegged to the midpoint of the best bid and ask prices.
+    Midpoint = 2,
+    /// Pegged to the best price on the opposite side of the book (the ask for buy orders).
+    Market = 3,
+}
+
 /// The market side for a specific position, or action related to positions.
 #[repr(C)]
 #[derive(
@@ -1098,6 +1230,36 @@ pub enum PriceType {
     Mark = 5,
 }
 
+/// The method of calculating a benchmark reference price over a period of market activity.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    FromRepr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.model.enums")
+)]
+pub enum ReferencePriceType {
+    /// The volume-weighted average price of trades over a rolling window.
+    Vwap = 1,
+    /// The time-weighted average price since the start of the trading session.
+    Twap = 2,
+}
+
 /// A record flag bit field, indicating event end and data information.
 #[repr(C)]
 #[derive(
@@ -1145,6 +1307,38 @@ impl RecordFlag {
     }
 }
 
+/// The settlement of an option contract on exercise.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    FromRepr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.model.enums")
+)]
+pub enum SettlementType {
+    /// The deliverable (underlying) is delivered on exercise.
+    #[default]
+    Physical = 1,
+    /// The intrinsic value is settled in cash on exercise.
+    Cash = 2,
+}
+
 /// The 'Time in Force' instruction for an order.
 #[repr(C)]
 #[derive(
@@ -1177,12 +1371,15 @@ pub enum TimeInForce {
     Fok = 3,
     /// Good-Till-Date/Time (GTD) - the order is active until a specified date or time.
     Gtd = 4,
-    /// Day - the order is active until the end of the current trading session.
+    /// Day - the order is active until the close of the current trading day at the venue.
     Day = 5,
     /// At the Opening (ATO) - the order is scheduled to be executed at the market's opening.
     AtTheOpen = 6,
     /// At the Closing (ATC) - the order is scheduled to be executed at the market's closing.
     AtTheClose = 7,
+    /// Good-Till-Crossing (GTX) - the order is active until canceled, and is only posted to the
+    /// book as a maker (it is rejected if it would cross the book on entry).
+    Gtx = 8,
 }
 
 /// The trading state for a node.
@@ -1313,7 +1510,10 @@ enum_strum_serde!(BarIntervalType);
 enum_strum_serde!(BookAction);
 enum_strum_serde!(BookType);
 enum_strum_serde!(ContingencyType);
+enum_strum_serde!(CorporateActionType);
 enum_strum_serde!(CurrencyType);
+enum_strum_serde!(DayCountConvention);
+enum_strum_serde!(ExerciseStyle);
 enum_strum_serde!(InstrumentCloseType);
 enum_strum_serde!(LiquiditySide);
 enum_strum_serde!(MarketStatus);
@@ -1323,9 +1523,12 @@ enum_strum_serde!(OptionKind);
 enum_strum_serde!(OrderSide);
 enum_strum_serde!(OrderStatus);
 enum_strum_serde!(OrderType);
+enum_strum_serde!(PegPriceType);
 enum_strum_serde!(PositionSide);
 enum_strum_serde!(PriceType);
+enum_strum_serde!(ReferencePriceType);
 enum_strum_serde!(RecordFlag);
+enum_strum_serde!(SettlementType);
 enum_strum_serde!(TimeInForce);
 enum_strum_serde!(TradingState);
 enum_strum_serde!(TrailingOffsetType);
@@ -117,6 +117,9 @@ pub struct OrderInitialized {
     pub exec_spawn_id: Option<ClientOrderId>,
     /// The custom user tags for the order.
     pub tags: Option<Vec<Ustr>>,
+    /// The structured metadata (key-value tags) for the order.
+    #[serde(default)]
+    pub metadata: Option<IndexMap<Ustr, Ustr>>,
 }
 
 impl Default for OrderInitialized {
@@ -152,6 +155,7 @@ impl Default for OrderInitialized {
             exec_algorithm_params: Default::default(),
             exec_spawn_id: Default::default(),
             tags: Default::default(),
+            metadata: Default::default(),
             event_id: Default::default(),
             ts_event: Default::default(),
             ts_init: Default::default(),
@@ -232,6 +236,7 @@ impl OrderInitialized {
             exec_algorithm_params,
             exec_spawn_id,
             tags,
+            metadata: None,
         }
     }
 }
@@ -557,6 +562,10 @@ impl OrderEvent for OrderInitialized {
         self.exec_spawn_id
     }
 
+    fn metadata(&self) -> Option<IndexMap<Ustr, Ustr>> {
+        self.metadata.clone()
+    }
+
     fn venue_order_id(&self) -> Option<VenueOrderId> {
         None
     }
@@ -13,8 +13,11 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use indexmap::IndexMap;
 use nautilus_core::{UnixNanos, UUID4};
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter};
 use ustr::Ustr;
 
 use crate::{
@@ -52,7 +55,7 @@ pub mod updated;
 pub mod stubs;
 
 /// Represents a type of [`OrderEvent`].
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, Display, Hash, PartialEq, Eq, EnumIter, Serialize, Deserialize)]
 pub enum OrderEventType {
     Initialized,
     Denied,
@@ -110,6 +113,10 @@ pub trait OrderEvent: 'static + Send {
     fn parent_order_id(&self) -> Option<ClientOrderId>;
     fn exec_algorithm_id(&self) -> Option<ExecAlgorithmId>;
     fn exec_spawn_id(&self) -> Option<ClientOrderId>;
+    /// Returns the order metadata, which only the `OrderInitialized` event carries.
+    fn metadata(&self) -> Option<IndexMap<Ustr, Ustr>> {
+        None
+    }
     fn venue_order_id(&self) -> Option<VenueOrderId>;
     fn account_id(&self) -> Option<AccountId>;
     fn position_id(&self) -> Option<PositionId>;
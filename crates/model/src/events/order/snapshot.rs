@@ -28,7 +28,7 @@ use crate::{
         AccountId, ClientOrderId, ExecAlgorithmId, InstrumentId, OrderListId, PositionId,
         StrategyId, TradeId, TraderId, VenueOrderId,
     },
-    orders::OrderAny,
+    orders::{OrderAmendment, OrderAny},
     types::{Money, Price, Quantity},
 };
 
@@ -117,6 +117,12 @@ pub struct OrderSnapshot {
     pub exec_spawn_id: Option<ClientOrderId>,
     /// The order custom user tags.
     pub tags: Option<Vec<Ustr>>,
+    /// The order structured metadata (key-value tags).
+    #[serde(default)]
+    pub metadata: Option<IndexMap<Ustr, Ustr>>,
+    /// The bounded history of amendments to the order quantity and prices.
+    #[serde(default)]
+    pub amendments: Vec<OrderAmendment>,
     /// The event ID of the `OrderInitialized` event.
     pub init_id: UUID4,
     /// UNIX timestamp (nanoseconds) when the object was initialized.
@@ -167,6 +173,8 @@ impl From<OrderAny> for OrderSnapshot {
             exec_algorithm_params: order.exec_algorithm_params(),
             exec_spawn_id: order.exec_spawn_id(),
             tags: order.tags(),
+            metadata: order.metadata(),
+            amendments: order.amendments().into_iter().copied().collect(),
             init_id: order.init_id(),
             ts_init: order.ts_init(),
             ts_last: order.ts_last(),
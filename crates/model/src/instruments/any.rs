@@ -19,10 +19,11 @@ use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
 use super::{
-    betting::BettingInstrument, binary_option::BinaryOption, crypto_future::CryptoFuture,
-    crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair, equity::Equity,
-    futures_contract::FuturesContract, futures_spread::FuturesSpread,
-    option_contract::OptionContract, option_spread::OptionSpread, Instrument,
+    betting::BettingInstrument, binary_option::BinaryOption, bond::Bond,
+    crypto_future::CryptoFuture, crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair,
+    equity::Equity, futures_contract::FuturesContract, futures_spread::FuturesSpread,
+    fx_forward::FxForward, fx_swap::FxSwap, option_contract::OptionContract,
+    option_spread::OptionSpread, Instrument,
 };
 use crate::{
     enums::InstrumentClass,
@@ -34,12 +35,15 @@ use crate::{
 pub enum InstrumentAny {
     Betting(BettingInstrument),
     BinaryOption(BinaryOption),
+    Bond(Bond),
     CryptoFuture(CryptoFuture),
     CryptoPerpetual(CryptoPerpetual),
     CurrencyPair(CurrencyPair),
     Equity(Equity),
     FuturesContract(FuturesContract),
     FuturesSpread(FuturesSpread),
+    FxForward(FxForward),
+    FxSwap(FxSwap),
     OptionContract(OptionContract),
     OptionSpread(OptionSpread),
 }
@@ -51,12 +55,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => Box::new(inst),
             Self::BinaryOption(inst) => Box::new(inst),
+            Self::Bond(inst) => Box::new(inst),
             Self::CryptoFuture(inst) => Box::new(inst),
             Self::CryptoPerpetual(inst) => Box::new(inst),
             Self::CurrencyPair(inst) => Box::new(inst),
             Self::Equity(inst) => Box::new(inst),
             Self::FuturesContract(inst) => Box::new(inst),
             Self::FuturesSpread(inst) => Box::new(inst),
+            Self::FxForward(inst) => Box::new(inst),
+            Self::FxSwap(inst) => Box::new(inst),
             Self::OptionContract(inst) => Box::new(inst),
             Self::OptionSpread(inst) => Box::new(inst),
         }
@@ -67,12 +74,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.instrument_class(),
             Self::BinaryOption(inst) => inst.instrument_class(),
+            Self::Bond(inst) => inst.instrument_class(),
             Self::CryptoFuture(inst) => inst.instrument_class(),
             Self::CryptoPerpetual(inst) => inst.instrument_class(),
             Self::CurrencyPair(inst) => inst.instrument_class(),
             Self::Equity(inst) => inst.instrument_class(),
             Self::FuturesContract(inst) => inst.instrument_class(),
             Self::FuturesSpread(inst) => inst.instrument_class(),
+            Self::FxForward(inst) => inst.instrument_class(),
+            Self::FxSwap(inst) => inst.instrument_class(),
             Self::OptionContract(inst) => inst.instrument_class(),
             Self::OptionSpread(inst) => inst.instrument_class(),
         }
@@ -83,28 +93,96 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.id,
             Self::BinaryOption(inst) => inst.id,
+            Self::Bond(inst) => inst.id,
             Self::CryptoFuture(inst) => inst.id,
             Self::CryptoPerpetual(inst) => inst.id,
             Self::CurrencyPair(inst) => inst.id,
             Self::Equity(inst) => inst.id,
             Self::FuturesContract(inst) => inst.id,
             Self::FuturesSpread(inst) => inst.id,
+            Self::FxForward(inst) => inst.id,
+            Self::FxSwap(inst) => inst.id,
             Self::OptionContract(inst) => inst.id,
             Self::OptionSpread(inst) => inst.id,
         }
     }
 
+    /// Returns a copy of the instrument listed under the new `id`, with the raw symbol
+    /// set to the symbol of the new ID (such as following a symbol change).
+    #[must_use]
+    pub fn with_id(&self, id: InstrumentId) -> Self {
+        let mut instrument = self.clone();
+        match &mut instrument {
+            Self::Betting(inst) => {
+                inst.id = id;
+                inst.raw_symbol = id.symbol;
+            }
+            Self::BinaryOption(inst) => {
+                inst.id = id;
+                inst.raw_symbol = id.symbol;
+            }
+            Self::Bond(inst) => {
+                inst.id = id;
+                inst.raw_symbol = id.symbol;
+            }
+            Self::CryptoFuture(inst) => {
+                inst.id = id;
+                inst.raw_symbol = id.symbol;
+            }
+            Self::CryptoPerpetual(inst) => {
+                inst.id = id;
+                inst.raw_symbol = id.symbol;
+            }
+            Self::CurrencyPair(inst) => {
+                inst.id = id;
+                inst.raw_symbol = id.symbol;
+            }
+            Self::Equity(inst) => {
+                inst.id = id;
+                inst.raw_symbol = id.symbol;
+            }
+            Self::FuturesContract(inst) => {
+                inst.id = id;
+                inst.raw_symbol = id.symbol;
+            }
+            Self::FuturesSpread(inst) => {
+                inst.id = id;
+                inst.raw_symbol = id.symbol;
+            }
+            Self::FxForward(inst) => {
+                inst.id = id;
+                inst.raw_symbol = id.symbol;
+            }
+            Self::FxSwap(inst) => {
+                inst.id = id;
+                inst.raw_symbol = id.symbol;
+            }
+            Self::OptionContract(inst) => {
+                inst.id = id;
+                inst.raw_symbol = id.symbol;
+            }
+            Self::OptionSpread(inst) => {
+                inst.id = id;
+                inst.raw_symbol = id.symbol;
+            }
+        }
+        instrument
+    }
+
     #[must_use]
     pub fn symbol(&self) -> Symbol {
         match self {
             Self::Betting(inst) => inst.id.symbol,
             Self::BinaryOption(inst) => inst.id.symbol,
+            Self::Bond(inst) => inst.id.symbol,
             Self::CryptoFuture(inst) => inst.id.symbol,
             Self::CryptoPerpetual(inst) => inst.id.symbol,
             Self::CurrencyPair(inst) => inst.id.symbol,
             Self::Equity(inst) => inst.id.symbol,
             Self::FuturesContract(inst) => inst.id.symbol,
             Self::FuturesSpread(inst) => inst.id.symbol,
+            Self::FxForward(inst) => inst.id.symbol,
+            Self::FxSwap(inst) => inst.id.symbol,
             Self::OptionContract(inst) => inst.id.symbol,
             Self::OptionSpread(inst) => inst.id.symbol,
         }
@@ -115,12 +193,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.id.venue,
             Self::BinaryOption(inst) => inst.id.venue,
+            Self::Bond(inst) => inst.id.venue,
             Self::CryptoFuture(inst) => inst.id.venue,
             Self::CryptoPerpetual(inst) => inst.id.venue,
             Self::CurrencyPair(inst) => inst.id.venue,
             Self::Equity(inst) => inst.id.venue,
             Self::FuturesContract(inst) => inst.id.venue,
             Self::FuturesSpread(inst) => inst.id.venue,
+            Self::FxForward(inst) => inst.id.venue,
+            Self::FxSwap(inst) => inst.id.venue,
             Self::OptionContract(inst) => inst.id.venue,
             Self::OptionSpread(inst) => inst.id.venue,
         }
@@ -131,12 +212,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.raw_symbol(),
             Self::BinaryOption(inst) => inst.raw_symbol(),
+            Self::Bond(inst) => inst.raw_symbol(),
             Self::CryptoFuture(inst) => inst.raw_symbol(),
             Self::CryptoPerpetual(inst) => inst.raw_symbol(),
             Self::CurrencyPair(inst) => inst.raw_symbol(),
             Self::Equity(inst) => inst.raw_symbol(),
             Self::FuturesContract(inst) => inst.raw_symbol(),
             Self::FuturesSpread(inst) => inst.raw_symbol(),
+            Self::FxForward(inst) => inst.raw_symbol(),
+            Self::FxSwap(inst) => inst.raw_symbol(),
             Self::OptionContract(inst) => inst.raw_symbol(),
             Self::OptionSpread(inst) => inst.raw_symbol(),
         }
@@ -147,12 +231,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(_) => None,
             Self::BinaryOption(_) => None,
+            Self::Bond(_) => None,
             Self::CryptoFuture(inst) => Some(&inst.underlying.code),
             Self::CryptoPerpetual(_) => None,
             Self::CurrencyPair(_) => None,
             Self::Equity(_) => None,
             Self::FuturesContract(inst) => Some(&inst.underlying),
             Self::FuturesSpread(inst) => Some(&inst.underlying),
+            Self::FxForward(_) => None,
+            Self::FxSwap(_) => None,
             Self::OptionContract(inst) => Some(&inst.underlying),
             Self::OptionSpread(inst) => Some(&inst.underlying),
         }
@@ -163,12 +250,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.base_currency(),
             Self::BinaryOption(inst) => inst.base_currency(),
+            Self::Bond(inst) => inst.base_currency(),
             Self::CryptoFuture(inst) => inst.base_currency(),
             Self::CryptoPerpetual(inst) => inst.base_currency(),
             Self::CurrencyPair(inst) => inst.base_currency(),
             Self::Equity(inst) => inst.base_currency(),
             Self::FuturesContract(inst) => inst.base_currency(),
             Self::FuturesSpread(inst) => inst.base_currency(),
+            Self::FxForward(inst) => inst.base_currency(),
+            Self::FxSwap(inst) => inst.base_currency(),
             Self::OptionContract(inst) => inst.base_currency(),
             Self::OptionSpread(inst) => inst.base_currency(),
         }
@@ -179,12 +269,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.quote_currency(),
             Self::BinaryOption(inst) => inst.quote_currency(),
+            Self::Bond(inst) => inst.quote_currency(),
             Self::CryptoFuture(inst) => inst.quote_currency(),
             Self::CryptoPerpetual(inst) => inst.quote_currency(),
             Self::CurrencyPair(inst) => inst.quote_currency(),
             Self::Equity(inst) => inst.quote_currency(),
             Self::FuturesContract(inst) => inst.quote_currency(),
             Self::FuturesSpread(inst) => inst.quote_currency(),
+            Self::FxForward(inst) => inst.quote_currency(),
+            Self::FxSwap(inst) => inst.quote_currency(),
             Self::OptionContract(inst) => inst.quote_currency(),
             Self::OptionSpread(inst) => inst.quote_currency(),
         }
@@ -195,12 +288,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.settlement_currency(),
             Self::BinaryOption(inst) => inst.settlement_currency(),
+            Self::Bond(inst) => inst.settlement_currency(),
             Self::CryptoFuture(inst) => inst.settlement_currency(),
             Self::CryptoPerpetual(inst) => inst.settlement_currency(),
             Self::CurrencyPair(inst) => inst.settlement_currency(),
             Self::Equity(inst) => inst.settlement_currency(),
             Self::FuturesContract(inst) => inst.settlement_currency(),
             Self::FuturesSpread(inst) => inst.settlement_currency(),
+            Self::FxForward(inst) => inst.settlement_currency(),
+            Self::FxSwap(inst) => inst.settlement_currency(),
             Self::OptionContract(inst) => inst.settlement_currency(),
             Self::OptionSpread(inst) => inst.settlement_currency(),
         }
@@ -211,12 +307,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.is_inverse(),
             Self::BinaryOption(inst) => inst.is_inverse(),
+            Self::Bond(inst) => inst.is_inverse(),
             Self::CryptoFuture(inst) => inst.is_inverse(),
             Self::CryptoPerpetual(inst) => inst.is_inverse(),
             Self::CurrencyPair(inst) => inst.is_inverse(),
             Self::Equity(inst) => inst.is_inverse(),
             Self::FuturesContract(inst) => inst.is_inverse(),
             Self::FuturesSpread(inst) => inst.is_inverse(),
+            Self::FxForward(inst) => inst.is_inverse(),
+            Self::FxSwap(inst) => inst.is_inverse(),
             Self::OptionContract(inst) => inst.is_inverse(),
             Self::OptionSpread(inst) => inst.is_inverse(),
         }
@@ -227,12 +326,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.price_precision(),
             Self::BinaryOption(inst) => inst.price_precision(),
+            Self::Bond(inst) => inst.price_precision(),
             Self::CryptoFuture(inst) => inst.price_precision(),
             Self::CryptoPerpetual(inst) => inst.price_precision(),
             Self::CurrencyPair(inst) => inst.price_precision(),
             Self::Equity(inst) => inst.price_precision(),
             Self::FuturesContract(inst) => inst.price_precision(),
             Self::FuturesSpread(inst) => inst.price_precision(),
+            Self::FxForward(inst) => inst.price_precision(),
+            Self::FxSwap(inst) => inst.price_precision(),
             Self::OptionContract(inst) => inst.price_precision(),
             Self::OptionSpread(inst) => inst.price_precision(),
         }
@@ -243,12 +345,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.size_precision(),
             Self::BinaryOption(inst) => inst.size_precision(),
+            Self::Bond(inst) => inst.size_precision(),
             Self::CryptoFuture(inst) => inst.size_precision(),
             Self::CryptoPerpetual(inst) => inst.size_precision(),
             Self::CurrencyPair(inst) => inst.size_precision(),
             Self::Equity(inst) => inst.size_precision(),
             Self::FuturesContract(inst) => inst.size_precision(),
             Self::FuturesSpread(inst) => inst.size_precision(),
+            Self::FxForward(inst) => inst.size_precision(),
+            Self::FxSwap(inst) => inst.size_precision(),
             Self::OptionContract(inst) => inst.size_precision(),
             Self::OptionSpread(inst) => inst.size_precision(),
         }
@@ -259,12 +364,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.price_increment(),
             Self::BinaryOption(inst) => inst.price_increment(),
+            Self::Bond(inst) => inst.price_increment(),
             Self::CryptoFuture(inst) => inst.price_increment(),
             Self::CryptoPerpetual(inst) => inst.price_increment(),
             Self::CurrencyPair(inst) => inst.price_increment(),
             Self::Equity(inst) => inst.price_increment(),
             Self::FuturesContract(inst) => inst.price_increment(),
             Self::FuturesSpread(inst) => inst.price_increment(),
+            Self::FxForward(inst) => inst.price_increment(),
+            Self::FxSwap(inst) => inst.price_increment(),
             Self::OptionContract(inst) => inst.price_increment(),
             Self::OptionSpread(inst) => inst.price_increment(),
         }
@@ -275,12 +383,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.size_increment(),
             Self::BinaryOption(inst) => inst.size_increment(),
+            Self::Bond(inst) => inst.size_increment(),
             Self::CryptoFuture(inst) => inst.size_increment(),
             Self::CryptoPerpetual(inst) => inst.size_increment(),
             Self::CurrencyPair(inst) => inst.size_increment(),
             Self::Equity(inst) => inst.size_increment(),
             Self::FuturesContract(inst) => inst.size_increment(),
             Self::FuturesSpread(inst) => inst.size_increment(),
+            Self::FxForward(inst) => inst.size_increment(),
+            Self::FxSwap(inst) => inst.size_increment(),
             Self::OptionContract(inst) => inst.size_increment(),
             Self::OptionSpread(inst) => inst.size_increment(),
         }
@@ -291,12 +402,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.multiplier(),
             Self::BinaryOption(inst) => inst.multiplier(),
+            Self::Bond(inst) => inst.multiplier(),
             Self::CryptoFuture(inst) => inst.multiplier(),
             Self::CryptoPerpetual(inst) => inst.multiplier(),
             Self::CurrencyPair(inst) => inst.multiplier(),
             Self::Equity(inst) => inst.multiplier(),
             Self::FuturesContract(inst) => inst.multiplier(),
             Self::FuturesSpread(inst) => inst.multiplier(),
+            Self::FxForward(inst) => inst.multiplier(),
+            Self::FxSwap(inst) => inst.multiplier(),
             Self::OptionContract(inst) => inst.multiplier(),
             Self::OptionSpread(inst) => inst.multiplier(),
         }
@@ -307,12 +421,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.activation_ns(),
             Self::BinaryOption(inst) => inst.activation_ns(),
+            Self::Bond(inst) => inst.activation_ns(),
             Self::CryptoFuture(inst) => inst.activation_ns(),
             Self::CryptoPerpetual(inst) => inst.activation_ns(),
             Self::CurrencyPair(inst) => inst.activation_ns(),
             Self::Equity(inst) => inst.activation_ns(),
             Self::FuturesContract(inst) => inst.activation_ns(),
             Self::FuturesSpread(inst) => inst.activation_ns(),
+            Self::FxForward(inst) => inst.activation_ns(),
+            Self::FxSwap(inst) => inst.activation_ns(),
             Self::OptionContract(inst) => inst.activation_ns(),
             Self::OptionSpread(inst) => inst.activation_ns(),
         }
@@ -323,12 +440,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.expiration_ns(),
             Self::BinaryOption(inst) => inst.expiration_ns(),
+            Self::Bond(inst) => inst.expiration_ns(),
             Self::CryptoFuture(inst) => inst.expiration_ns(),
             Self::CryptoPerpetual(inst) => inst.expiration_ns(),
             Self::CurrencyPair(inst) => inst.expiration_ns(),
             Self::Equity(inst) => inst.expiration_ns(),
             Self::FuturesContract(inst) => inst.expiration_ns(),
             Self::FuturesSpread(inst) => inst.expiration_ns(),
+            Self::FxForward(inst) => inst.expiration_ns(),
+            Self::FxSwap(inst) => inst.expiration_ns(),
             Self::OptionContract(inst) => inst.expiration_ns(),
             Self::OptionSpread(inst) => inst.expiration_ns(),
         }
@@ -338,12 +458,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.max_quantity(),
             Self::BinaryOption(inst) => inst.max_quantity(),
+            Self::Bond(inst) => inst.max_quantity(),
             Self::CryptoFuture(inst) => inst.max_quantity(),
             Self::CryptoPerpetual(inst) => inst.max_quantity(),
             Self::CurrencyPair(inst) => inst.max_quantity(),
             Self::Equity(inst) => inst.max_quantity(),
             Self::FuturesContract(inst) => inst.max_quantity(),
             Self::FuturesSpread(inst) => inst.max_quantity(),
+            Self::FxForward(inst) => inst.max_quantity(),
+            Self::FxSwap(inst) => inst.max_quantity(),
             Self::OptionContract(inst) => inst.max_quantity(),
             Self::OptionSpread(inst) => inst.max_quantity(),
         }
@@ -353,12 +476,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.min_quantity(),
             Self::BinaryOption(inst) => inst.min_quantity(),
+            Self::Bond(inst) => inst.min_quantity(),
             Self::CryptoFuture(inst) => inst.min_quantity(),
             Self::CryptoPerpetual(inst) => inst.min_quantity(),
             Self::CurrencyPair(inst) => inst.min_quantity(),
             Self::Equity(inst) => inst.min_quantity(),
             Self::FuturesContract(inst) => inst.min_quantity(),
             Self::FuturesSpread(inst) => inst.min_quantity(),
+            Self::FxForward(inst) => inst.min_quantity(),
+            Self::FxSwap(inst) => inst.min_quantity(),
             Self::OptionContract(inst) => inst.min_quantity(),
             Self::OptionSpread(inst) => inst.min_quantity(),
         }
@@ -368,12 +494,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.max_notional(),
             Self::BinaryOption(inst) => inst.max_notional(),
+            Self::Bond(inst) => inst.max_notional(),
             Self::CryptoFuture(inst) => inst.max_notional(),
             Self::CryptoPerpetual(inst) => inst.max_notional(),
             Self::CurrencyPair(inst) => inst.max_notional(),
             Self::Equity(inst) => inst.max_notional(),
             Self::FuturesContract(inst) => inst.max_notional(),
             Self::FuturesSpread(inst) => inst.max_notional(),
+            Self::FxForward(inst) => inst.max_notional(),
+            Self::FxSwap(inst) => inst.max_notional(),
             Self::OptionContract(inst) => inst.max_notional(),
             Self::OptionSpread(inst) => inst.max_notional(),
         }
@@ -383,12 +512,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.min_notional(),
             Self::BinaryOption(inst) => inst.min_notional(),
+            Self::Bond(inst) => inst.min_notional(),
             Self::CryptoFuture(inst) => inst.min_notional(),
             Self::CryptoPerpetual(inst) => inst.min_notional(),
             Self::CurrencyPair(inst) => inst.min_notional(),
             Self::Equity(inst) => inst.min_notional(),
             Self::FuturesContract(inst) => inst.min_notional(),
             Self::FuturesSpread(inst) => inst.min_notional(),
+            Self::FxForward(inst) => inst.min_notional(),
+            Self::FxSwap(inst) => inst.min_notional(),
             Self::OptionContract(inst) => inst.min_notional(),
             Self::OptionSpread(inst) => inst.min_notional(),
         }
@@ -398,12 +530,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.ts_event,
             Self::BinaryOption(inst) => inst.ts_event,
+            Self::Bond(inst) => inst.ts_event,
             Self::CryptoFuture(inst) => inst.ts_event,
             Self::CryptoPerpetual(inst) => inst.ts_event,
             Self::CurrencyPair(inst) => inst.ts_event,
             Self::Equity(inst) => inst.ts_event,
             Self::FuturesContract(inst) => inst.ts_event,
             Self::FuturesSpread(inst) => inst.ts_event,
+            Self::FxForward(inst) => inst.ts_event,
+            Self::FxSwap(inst) => inst.ts_event,
             Self::OptionContract(inst) => inst.ts_event,
             Self::OptionSpread(inst) => inst.ts_event,
         }
@@ -413,12 +548,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.ts_init,
             Self::BinaryOption(inst) => inst.ts_init,
+            Self::Bond(inst) => inst.ts_init,
             Self::CryptoFuture(inst) => inst.ts_init,
             Self::CryptoPerpetual(inst) => inst.ts_init,
             Self::CurrencyPair(inst) => inst.ts_init,
             Self::Equity(inst) => inst.ts_init,
             Self::FuturesContract(inst) => inst.ts_init,
             Self::FuturesSpread(inst) => inst.ts_init,
+            Self::FxForward(inst) => inst.ts_init,
+            Self::FxSwap(inst) => inst.ts_init,
             Self::OptionContract(inst) => inst.ts_init,
             Self::OptionSpread(inst) => inst.ts_init,
         }
@@ -428,12 +566,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.make_price(value),
             Self::BinaryOption(inst) => inst.make_price(value),
+            Self::Bond(inst) => inst.make_price(value),
             Self::CryptoFuture(inst) => inst.make_price(value),
             Self::CryptoPerpetual(inst) => inst.make_price(value),
             Self::CurrencyPair(inst) => inst.make_price(value),
             Self::Equity(inst) => inst.make_price(value),
             Self::FuturesContract(inst) => inst.make_price(value),
             Self::FuturesSpread(inst) => inst.make_price(value),
+            Self::FxForward(inst) => inst.make_price(value),
+            Self::FxSwap(inst) => inst.make_price(value),
             Self::OptionContract(inst) => inst.make_price(value),
             Self::OptionSpread(inst) => inst.make_price(value),
         }
@@ -443,12 +584,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.make_qty(value),
             Self::BinaryOption(inst) => inst.make_qty(value),
+            Self::Bond(inst) => inst.make_qty(value),
             Self::CryptoFuture(inst) => inst.make_qty(value),
             Self::CryptoPerpetual(inst) => inst.make_qty(value),
             Self::CurrencyPair(inst) => inst.make_qty(value),
             Self::Equity(inst) => inst.make_qty(value),
             Self::FuturesContract(inst) => inst.make_qty(value),
             Self::FuturesSpread(inst) => inst.make_qty(value),
+            Self::FxForward(inst) => inst.make_qty(value),
+            Self::FxSwap(inst) => inst.make_qty(value),
             Self::OptionContract(inst) => inst.make_qty(value),
             Self::OptionSpread(inst) => inst.make_qty(value),
         }
@@ -468,6 +612,9 @@ impl InstrumentAny {
             Self::BinaryOption(inst) => {
                 inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
             }
+            Self::Bond(inst) => {
+                inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
+            }
             Self::CryptoFuture(inst) => {
                 inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
             }
@@ -486,6 +633,12 @@ impl InstrumentAny {
             Self::FuturesSpread(inst) => {
                 inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
             }
+            Self::FxForward(inst) => {
+                inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
+            }
+            Self::FxSwap(inst) => {
+                inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
+            }
             Self::OptionContract(inst) => {
                 inst.calculate_notional_value(quantity, price, use_quote_for_inverse)
             }
@@ -501,12 +654,15 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.maker_fee(),
             Self::BinaryOption(inst) => inst.maker_fee(),
+            Self::Bond(inst) => inst.maker_fee(),
             Self::CryptoFuture(inst) => inst.maker_fee(),
             Self::CryptoPerpetual(inst) => inst.maker_fee(),
             Self::CurrencyPair(inst) => inst.maker_fee(),
             Self::Equity(inst) => inst.maker_fee(),
             Self::FuturesContract(inst) => inst.maker_fee(),
             Self::FuturesSpread(inst) => inst.maker_fee(),
+            Self::FxForward(inst) => inst.maker_fee(),
+            Self::FxSwap(inst) => inst.maker_fee(),
             Self::OptionContract(inst) => inst.maker_fee(),
             Self::OptionSpread(inst) => inst.maker_fee(),
         }
@@ -518,27 +674,71 @@ impl InstrumentAny {
         match self {
             Self::Betting(inst) => inst.taker_fee(),
             Self::BinaryOption(inst) => inst.taker_fee(),
+            Self::Bond(inst) => inst.taker_fee(),
             Self::CryptoFuture(inst) => inst.taker_fee(),
             Self::CryptoPerpetual(inst) => inst.taker_fee(),
             Self::CurrencyPair(inst) => inst.taker_fee(),
             Self::Equity(inst) => inst.taker_fee(),
             Self::FuturesContract(inst) => inst.taker_fee(),
             Self::FuturesSpread(inst) => inst.taker_fee(),
+            Self::FxForward(inst) => inst.taker_fee(),
+            Self::FxSwap(inst) => inst.taker_fee(),
             Self::OptionContract(inst) => inst.taker_fee(),
             Self::OptionSpread(inst) => inst.taker_fee(),
         }
     }
 
+    #[must_use]
+    pub fn margin_init(&self) -> Decimal {
+        match self {
+            Self::Betting(inst) => inst.margin_init(),
+            Self::BinaryOption(inst) => inst.margin_init(),
+            Self::Bond(inst) => inst.margin_init(),
+            Self::CryptoFuture(inst) => inst.margin_init(),
+            Self::CryptoPerpetual(inst) => inst.margin_init(),
+            Self::CurrencyPair(inst) => inst.margin_init(),
+            Self::Equity(inst) => inst.margin_init(),
+            Self::FuturesContract(inst) => inst.margin_init(),
+            Self::FuturesSpread(inst) => inst.margin_init(),
+            Self::FxForward(inst) => inst.margin_init(),
+            Self::FxSwap(inst) => inst.margin_init(),
+            Self::OptionContract(inst) => inst.margin_init(),
+            Self::OptionSpread(inst) => inst.margin_init(),
+        }
+    }
+
+    #[must_use]
+    pub fn margin_maint(&self) -> Decimal {
+        match self {
+            Self::Betting(inst) => inst.margin_maint(),
+            Self::BinaryOption(inst) => inst.margin_maint(),
+            Self::Bond(inst) => inst.margin_maint(),
+            Self::CryptoFuture(inst) => inst.margin_maint(),
+            Self::CryptoPerpetual(inst) => inst.margin_maint(),
+            Self::CurrencyPair(inst) => inst.margin_maint(),
+            Self::Equity(inst) => inst.margin_maint(),
+            Self::FuturesContract(inst) => inst.margin_maint(),
+            Self::FuturesSpread(inst) => inst.margin_maint(),
+            Self::FxForward(inst) => inst.margin_maint(),
+            Self::FxSwap(inst) => inst.margin_maint(),
+            Self::OptionContract(inst) => inst.margin_maint(),
+            Self::OptionSpread(inst) => inst.margin_maint(),
+        }
+    }
+
     pub fn get_base_quantity(&self, quantity: Quantity, last_px: Price) -> Quantity {
         match self {
             Self::Betting(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::BinaryOption(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::Bond(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::CryptoFuture(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::CryptoPerpetual(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::CurrencyPair(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::Equity(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::FuturesContract(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::FuturesSpread(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::FxForward(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::FxSwap(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::OptionContract(inst) => inst.calculate_base_quantity(quantity, last_px),
             Self::OptionSpread(inst) => inst.calculate_base_quantity(quantity, last_px),
         }
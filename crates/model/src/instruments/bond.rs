@@ -0,0 +1,717 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::hash::{Hash, Hasher};
+
+use chrono::{Datelike, NaiveDate};
+use nautilus_core::{
+    correctness::{check_equal_u8, check_predicate_true, check_valid_string_optional, FAILED},
+    datetime::subtract_n_months_nanos,
+    UnixNanos,
+};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use super::{any::InstrumentAny, Instrument};
+use crate::{
+    enums::{AssetClass, DayCountConvention, InstrumentClass, OptionKind},
+    identifiers::{InstrumentId, Symbol},
+    types::{
+        currency::Currency,
+        money::Money,
+        price::{check_positive_price, Price},
+        quantity::{check_positive_quantity, Quantity},
+    },
+};
+
+/// Represents a fixed coupon bond instrument.
+///
+/// Prices are quoted as a percentage of the face value (clean, excluding accrued interest), and
+/// coupons are paid at regular intervals working back from the maturity date.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct Bond {
+    /// The instrument ID.
+    pub id: InstrumentId,
+    /// The raw/local/native symbol for the instrument, assigned by the venue.
+    pub raw_symbol: Symbol,
+    /// The instruments International Securities Identification Number (ISIN).
+    pub isin: Option<Ustr>,
+    /// The exchange ISO 10383 Market Identifier Code (MIC) where the instrument trades.
+    pub exchange: Option<Ustr>,
+    /// The bond currency.
+    pub currency: Currency,
+    /// The annual coupon rate as a fraction of the face value (e.g. 0.045 for 4.5%).
+    pub coupon_rate: Decimal,
+    /// The number of coupon payments per year (zero for a zero coupon bond).
+    pub coupon_frequency: u8,
+    /// The day count convention for the accrual of interest.
+    pub day_count: DayCountConvention,
+    /// The face (par) value of a single bond.
+    pub face_value: Quantity,
+    /// UNIX timestamp (nanoseconds) for the issue (dated) date, from which interest accrues.
+    pub issue_ns: UnixNanos,
+    /// UNIX timestamp (nanoseconds) for the maturity date.
+    pub maturity_ns: UnixNanos,
+    /// The price decimal precision.
+    pub price_precision: u8,
+    /// The minimum price increment (tick size).
+    pub price_increment: Price,
+    /// The trading size decimal precision.
+    pub size_precision: u8,
+    /// The minimum size increment.
+    pub size_increment: Quantity,
+    /// The contract multiplier, being the face value per point of price.
+    pub multiplier: Quantity,
+    /// The rounded lot unit size (standard/board).
+    pub lot_size: Option<Quantity>,
+    /// The initial (order) margin requirement in percentage of order value.
+    pub margin_init: Decimal,
+    /// The maintenance (position) margin in percentage of position value.
+    pub margin_maint: Decimal,
+    /// The fee rate for liquidity makers as a percentage of order value.
+    pub maker_fee: Decimal,
+    /// The fee rate for liquidity takers as a percentage of order value.
+    pub taker_fee: Decimal,
+    /// The maximum allowable order quantity.
+    pub max_quantity: Option<Quantity>,
+    /// The minimum allowable order quantity.
+    pub min_quantity: Option<Quantity>,
+    /// The maximum allowable quoted price.
+    pub max_price: Option<Price>,
+    /// The minimum allowable quoted price.
+    pub min_price: Option<Price>,
+    /// UNIX timestamp (nanoseconds) when the data event occurred.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the data object was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl Bond {
+    /// Creates a new [`Bond`] instance with correctness checking.
+    ///
+    /// # Notes
+    ///
+    /// PyO3 requires a `Result` type for proper error handling and stacktrace printing in Python.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_checked(
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        isin: Option<Ustr>,
+        exchange: Option<Ustr>,
+        currency: Currency,
+        coupon_rate: Decimal,
+        coupon_frequency: u8,
+        day_count: DayCountConvention,
+        face_value: Quantity,
+        issue_ns: UnixNanos,
+        maturity_ns: UnixNanos,
+        price_precision: u8,
+        price_increment: Price,
+        size_precision: u8,
+        size_increment: Quantity,
+        lot_size: Option<Quantity>,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_price: Option<Price>,
+        min_price: Option<Price>,
+        margin_init: Option<Decimal>,
+        margin_maint: Option<Decimal>,
+        maker_fee: Option<Decimal>,
+        taker_fee: Option<Decimal>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        check_valid_string_optional(isin.map(|u| u.as_str()), stringify!(isin))?;
+        check_valid_string_optional(exchange.map(|u| u.as_str()), stringify!(exchange))?;
+        check_predicate_true(
+            !coupon_rate.is_sign_negative(),
+            "invalid `coupon_rate`, was negative",
+        )?;
+        check_predicate_true(
+            coupon_frequency <= 12 && (coupon_frequency == 0 || 12 % coupon_frequency == 0),
+            "invalid `coupon_frequency`, must evenly divide a year into months",
+        )?;
+        check_predicate_true(
+            coupon_frequency > 0 || coupon_rate.is_zero(),
+            "invalid `coupon_rate`, must be zero for a zero coupon bond",
+        )?;
+        check_predicate_true(
+            issue_ns < maturity_ns,
+            "invalid `maturity_ns`, must be after `issue_ns`",
+        )?;
+        check_equal_u8(
+            price_precision,
+            price_increment.precision,
+            stringify!(price_precision),
+            stringify!(price_increment.precision),
+        )?;
+        check_equal_u8(
+            size_precision,
+            size_increment.precision,
+            stringify!(size_precision),
+            stringify!(size_increment.precision),
+        )?;
+        check_positive_price(price_increment.raw, stringify!(price_increment.raw))?;
+        check_positive_quantity(size_increment.raw, stringify!(size_increment.raw))?;
+        check_positive_quantity(face_value.raw, stringify!(face_value.raw))?;
+
+        // Prices are quoted per 100 of face value
+        let multiplier =
+            Quantity::new_checked(face_value.as_f64() / 100.0, face_value.precision + 2)?;
+
+        Ok(Self {
+            id,
+            raw_symbol,
+            isin,
+            exchange,
+            currency,
+            coupon_rate,
+            coupon_frequency,
+            day_count,
+            face_value,
+            issue_ns,
+            maturity_ns,
+            price_precision,
+            price_increment,
+            size_precision,
+            size_increment,
+            multiplier,
+            lot_size,
+            margin_init: margin_init.unwrap_or_default(),
+            margin_maint: margin_maint.unwrap_or_default(),
+            maker_fee: maker_fee.unwrap_or_default(),
+            taker_fee: taker_fee.unwrap_or_default(),
+            max_quantity,
+            min_quantity,
+            max_price,
+            min_price,
+            ts_event,
+            ts_init,
+        })
+    }
+
+    /// Creates a new [`Bond`] instance.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        isin: Option<Ustr>,
+        exchange: Option<Ustr>,
+        currency: Currency,
+        coupon_rate: Decimal,
+        coupon_frequency: u8,
+        day_count: DayCountConvention,
+        face_value: Quantity,
+        issue_ns: UnixNanos,
+        maturity_ns: UnixNanos,
+        price_precision: u8,
+        price_increment: Price,
+        size_precision: u8,
+        size_increment: Quantity,
+        lot_size: Option<Quantity>,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_price: Option<Price>,
+        min_price: Option<Price>,
+        margin_init: Option<Decimal>,
+        margin_maint: Option<Decimal>,
+        maker_fee: Option<Decimal>,
+        taker_fee: Option<Decimal>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self::new_checked(
+            id,
+            raw_symbol,
+            isin,
+            exchange,
+            currency,
+            coupon_rate,
+            coupon_frequency,
+            day_count,
+            face_value,
+            issue_ns,
+            maturity_ns,
+            price_precision,
+            price_increment,
+            size_precision,
+            size_increment,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_price,
+            min_price,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+            ts_event,
+            ts_init,
+        )
+        .expect(FAILED)
+    }
+
+    /// Returns whether the bond pays no coupons.
+    #[must_use]
+    pub const fn is_zero_coupon(&self) -> bool {
+        self.coupon_frequency == 0
+    }
+
+    /// Returns the coupon paid per period, in price points (per 100 of face value).
+    #[must_use]
+    pub fn coupon(&self) -> Decimal {
+        if self.is_zero_coupon() {
+            return Decimal::ZERO;
+        }
+        Decimal::ONE_HUNDRED * self.coupon_rate / Decimal::from(self.coupon_frequency)
+    }
+
+    /// Returns the coupon payment dates of the bond, in ascending order up to and including the
+    /// maturity date.
+    #[must_use]
+    pub fn coupon_dates(&self) -> Vec<UnixNanos> {
+        let Some(months) = self.coupon_months() else {
+            return Vec::new();
+        };
+
+        let mut dates: Vec<UnixNanos> = (0..)
+            .map(|n| subtract_n_months_nanos(self.maturity_ns, n * months))
+            .take_while(|date| *date > self.issue_ns)
+            .collect();
+        dates.reverse();
+        dates
+    }
+
+    /// Returns the start and end of the coupon period containing the `settlement_ns`, or `None`
+    /// if the bond is zero coupon or `settlement_ns` is outside the life of the bond.
+    ///
+    /// The start of the first coupon period may precede the issue date (the quasi-coupon date).
+    #[must_use]
+    pub fn coupon_period(&self, settlement_ns: UnixNanos) -> Option<(UnixNanos, UnixNanos)> {
+        let months = self.coupon_months()?;
+        if settlement_ns < self.issue_ns || settlement_ns >= self.maturity_ns {
+            return None;
+        }
+
+        // Work back from maturity so the schedule does not drift across month ends
+        (0..).find_map(|n| {
+            let start = subtract_n_months_nanos(self.maturity_ns, (n + 1) * months);
+            (start <= settlement_ns)
+                .then(|| (start, subtract_n_months_nanos(self.maturity_ns, n * months)))
+        })
+    }
+
+    /// Returns the interest accrued since the last coupon date at the `settlement_ns`, in price
+    /// points (per 100 of face value).
+    #[must_use]
+    pub fn accrued_interest(&self, settlement_ns: UnixNanos) -> Decimal {
+        let Some((period_start, period_end)) = self.coupon_period(settlement_ns) else {
+            return Decimal::ZERO;
+        };
+        let accrual_start = period_start.max(self.issue_ns);
+        let annual_coupon = Decimal::ONE_HUNDRED * self.coupon_rate;
+
+        match self.day_count {
+            DayCountConvention::Actual360 => {
+                annual_coupon * actual_days(accrual_start, settlement_ns) / Decimal::from(360)
+            }
+            DayCountConvention::Actual365Fixed => {
+                annual_coupon * actual_days(accrual_start, settlement_ns) / Decimal::from(365)
+            }
+            DayCountConvention::ActualActual => {
+                self.coupon() * actual_days(accrual_start, settlement_ns)
+                    / actual_days(period_start, period_end)
+            }
+            DayCountConvention::Thirty360 => {
+                annual_coupon * thirty_360_days(accrual_start, settlement_ns) / Decimal::from(360)
+            }
+        }
+    }
+
+    /// Returns the accrued interest for the `quantity` of bonds at the `settlement_ns`.
+    #[must_use]
+    pub fn accrued_interest_amount(&self, quantity: Quantity, settlement_ns: UnixNanos) -> Money {
+        let accrued = self
+            .accrued_interest(settlement_ns)
+            .to_f64()
+            .unwrap_or_default();
+        Money::new(
+            accrued * self.multiplier.as_f64() * quantity.as_f64(),
+            self.currency,
+        )
+    }
+
+    /// Returns the dirty (invoice) price for the `clean_price` at the `settlement_ns`.
+    #[must_use]
+    pub fn dirty_price(&self, clean_price: Price, settlement_ns: UnixNanos) -> Decimal {
+        clean_price.as_decimal() + self.accrued_interest(settlement_ns)
+    }
+
+    /// Returns the clean price for the `dirty_price` at the `settlement_ns`.
+    #[must_use]
+    pub fn clean_price(&self, dirty_price: Decimal, settlement_ns: UnixNanos) -> Decimal {
+        dirty_price - self.accrued_interest(settlement_ns)
+    }
+
+    /// Returns the gross basis of the bond against a bond futures contract, being the
+    /// `clean_price` less the `futures_price` scaled by the `conversion_factor` of the bond
+    /// for delivery into the contract.
+    #[must_use]
+    pub fn gross_basis(
+        &self,
+        clean_price: Price,
+        futures_price: Price,
+        conversion_factor: Decimal,
+    ) -> Decimal {
+        clean_price.as_decimal() - futures_price.as_decimal() * conversion_factor
+    }
+
+    fn coupon_months(&self) -> Option<u32> {
+        (!self.is_zero_coupon()).then(|| 12 / u32::from(self.coupon_frequency))
+    }
+}
+
+fn date(unix_nanos: UnixNanos) -> NaiveDate {
+    unix_nanos.to_datetime_utc().date_naive()
+}
+
+fn actual_days(start: UnixNanos, end: UnixNanos) -> Decimal {
+    Decimal::from((date(end) - date(start)).num_days())
+}
+
+// US (NASD) 30/360 bond basis
+fn thirty_360_days(start: UnixNanos, end: UnixNanos) -> Decimal {
+    let (start, end) = (date(start), date(end));
+    let day_start = start.day().min(30);
+    let day_end = if day_start == 30 {
+        end.day().min(30)
+    } else {
+        end.day()
+    };
+    let days = 360 * i64::from(end.year() - start.year())
+        + 30 * (i64::from(end.month()) - i64::from(start.month()))
+        + (i64::from(day_end) - i64::from(day_start));
+    Decimal::from(days)
+}
+
+impl PartialEq<Self> for Bond {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Bond {}
+
+impl Hash for Bond {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl Instrument for Bond {
+    fn into_any(self) -> InstrumentAny {
+        InstrumentAny::Bond(self)
+    }
+
+    fn id(&self) -> InstrumentId {
+        self.id
+    }
+
+    fn raw_symbol(&self) -> Symbol {
+        self.raw_symbol
+    }
+
+    fn asset_class(&self) -> AssetClass {
+        AssetClass::Debt
+    }
+
+    fn instrument_class(&self) -> InstrumentClass {
+        InstrumentClass::Bond
+    }
+
+    fn underlying(&self) -> Option<Ustr> {
+        None
+    }
+
+    fn base_currency(&self) -> Option<Currency> {
+        None
+    }
+
+    fn quote_currency(&self) -> Currency {
+        self.currency
+    }
+
+    fn settlement_currency(&self) -> Currency {
+        self.currency
+    }
+
+    fn isin(&self) -> Option<Ustr> {
+        self.isin
+    }
+
+    fn option_kind(&self) -> Option<OptionKind> {
+        None
+    }
+
+    fn exchange(&self) -> Option<Ustr> {
+        self.exchange
+    }
+
+    fn strike_price(&self) -> Option<Price> {
+        None
+    }
+
+    fn activation_ns(&self) -> Option<UnixNanos> {
+        Some(self.issue_ns)
+    }
+
+    fn expiration_ns(&self) -> Option<UnixNanos> {
+        Some(self.maturity_ns)
+    }
+
+    fn is_inverse(&self) -> bool {
+        false
+    }
+
+    fn price_precision(&self) -> u8 {
+        self.price_precision
+    }
+
+    fn size_precision(&self) -> u8 {
+        self.size_precision
+    }
+
+    fn price_increment(&self) -> Price {
+        self.price_increment
+    }
+
+    fn size_increment(&self) -> Quantity {
+        self.size_increment
+    }
+
+    fn multiplier(&self) -> Quantity {
+        self.multiplier
+    }
+
+    fn lot_size(&self) -> Option<Quantity> {
+        self.lot_size
+    }
+
+    fn max_quantity(&self) -> Option<Quantity> {
+        self.max_quantity
+    }
+
+    fn min_quantity(&self) -> Option<Quantity> {
+        self.min_quantity
+    }
+
+    fn max_notional(&self) -> Option<Money> {
+        None
+    }
+
+    fn min_notional(&self) -> Option<Money> {
+        None
+    }
+
+    fn max_price(&self) -> Option<Price> {
+        self.max_price
+    }
+
+    fn min_price(&self) -> Option<Price> {
+        self.min_price
+    }
+
+    fn ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::instruments::stubs::*;
+
+    fn ts(value: &str) -> UnixNanos {
+        let datetime: DateTime<Utc> = format!("{value}T00:00:00Z").parse().unwrap();
+        UnixNanos::from(datetime)
+    }
+
+    #[rstest]
+    fn test_equality(bond_ust: Bond) {
+        let cloned = bond_ust;
+        assert_eq!(bond_ust, cloned);
+    }
+
+    #[rstest]
+    fn test_multiplier_and_notional(bond_ust: Bond) {
+        let notional =
+            bond_ust.calculate_notional_value(Quantity::from(10), Price::from("99.500"), None);
+
+        assert_eq!(bond_ust.multiplier, Quantity::from("10.00"));
+        assert_eq!(notional, Money::new(9_950.0, Currency::USD()));
+    }
+
+    #[rstest]
+    fn test_coupon_dates(bond_ust: Bond) {
+        let dates = bond_ust.coupon_dates();
+
+        assert_eq!(dates.len(), 20);
+        assert_eq!(dates[0], ts("2024-11-15"));
+        assert_eq!(dates[1], ts("2025-05-15"));
+        assert_eq!(dates[19], ts("2034-05-15"));
+    }
+
+    #[rstest]
+    #[case("2024-05-15", "0")]
+    #[case("2024-08-15", "1.0625")]
+    #[case("2024-11-15", "0")]
+    #[case("2034-05-15", "0")]
+    fn test_accrued_interest_actual_actual(
+        bond_ust: Bond,
+        #[case] settlement: &str,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(
+            bond_ust
+                .accrued_interest(ts(settlement))
+                .normalize()
+                .to_string(),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn test_accrued_interest_from_issue_in_first_period(mut bond_ust: Bond) {
+        bond_ust.issue_ns = ts("2024-06-01");
+
+        assert_eq!(
+            bond_ust.coupon_period(ts("2024-08-15")),
+            Some((ts("2024-05-15"), ts("2024-11-15")))
+        );
+        // 75 of 184 days accrued from the issue date
+        assert_eq!(
+            bond_ust.accrued_interest(ts("2024-08-15")),
+            dec!(2.125) * dec!(75) / dec!(184)
+        );
+    }
+
+    #[rstest]
+    #[case(DayCountConvention::Thirty360, "2024-03-01", "0.766667")] // 46 days
+    #[case(DayCountConvention::Thirty360, "2024-05-31", "2.266667")] // 136 days
+    #[case(DayCountConvention::Actual360, "2024-05-31", "2.283333")] // 137 days
+    #[case(DayCountConvention::Actual365Fixed, "2024-05-31", "2.252055")] // 137 days
+    fn test_accrued_interest_day_count(
+        mut bond_ust: Bond,
+        #[case] day_count: DayCountConvention,
+        #[case] settlement: &str,
+        #[case] expected: &str,
+    ) {
+        bond_ust.coupon_rate = dec!(0.06);
+        bond_ust.day_count = day_count;
+        bond_ust.issue_ns = ts("2024-01-15");
+        bond_ust.maturity_ns = ts("2029-07-15");
+
+        assert_eq!(
+            bond_ust
+                .accrued_interest(ts(settlement))
+                .round_dp(6)
+                .to_string(),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn test_clean_and_dirty_prices(bond_ust: Bond) {
+        let settlement = ts("2024-08-15");
+
+        let dirty = bond_ust.dirty_price(Price::from("99.500"), settlement);
+
+        assert_eq!(dirty, dec!(100.5625));
+        assert_eq!(bond_ust.clean_price(dirty, settlement), dec!(99.5));
+        assert_eq!(
+            bond_ust.accrued_interest_amount(Quantity::from(10), settlement),
+            Money::new(106.25, Currency::USD())
+        );
+    }
+
+    #[rstest]
+    fn test_gross_basis(bond_ust: Bond) {
+        let basis =
+            bond_ust.gross_basis(Price::from("99.500"), Price::from("110.250"), dec!(0.9000));
+
+        assert_eq!(basis, dec!(0.275));
+    }
+
+    #[rstest]
+    fn test_zero_coupon(mut bond_ust: Bond) {
+        bond_ust.coupon_rate = Decimal::ZERO;
+        bond_ust.coupon_frequency = 0;
+
+        assert!(bond_ust.is_zero_coupon());
+        assert!(bond_ust.coupon_dates().is_empty());
+        assert_eq!(bond_ust.accrued_interest(ts("2024-08-15")), Decimal::ZERO);
+    }
+
+    #[rstest]
+    fn test_new_checked_with_invalid_coupon_frequency(bond_ust: Bond) {
+        let result = Bond::new_checked(
+            bond_ust.id,
+            bond_ust.raw_symbol,
+            bond_ust.isin,
+            bond_ust.exchange,
+            bond_ust.currency,
+            bond_ust.coupon_rate,
+            5,
+            bond_ust.day_count,
+            bond_ust.face_value,
+            bond_ust.issue_ns,
+            bond_ust.maturity_ns,
+            bond_ust.price_precision,
+            bond_ust.price_increment,
+            bond_ust.size_precision,
+            bond_ust.size_increment,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            bond_ust.ts_event,
+            bond_ust.ts_init,
+        );
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,677 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::hash::{Hash, Hasher};
+
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+use nautilus_core::{
+    correctness::{check_equal_u8, check_predicate_true, FAILED},
+    UnixNanos,
+};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use super::{any::InstrumentAny, Instrument};
+use crate::{
+    enums::{AssetClass, InstrumentClass, OptionKind, OrderSide, SettlementType},
+    identifiers::{InstrumentId, Symbol},
+    types::{
+        currency::Currency,
+        money::Money,
+        price::{check_positive_price, Price},
+        quantity::{check_positive_quantity, Quantity},
+    },
+};
+
+/// Represents an outright FX forward contract to exchange currencies on a future value date.
+///
+/// Prices are quoted as the outright forward rate (the spot rate plus forward points). A
+/// non-deliverable forward (NDF) is cash settled in the settlement currency against the
+/// fixing rate observed on the fixing date, rather than exchanging the currency notionals.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct FxForward {
+    /// The instrument ID.
+    pub id: InstrumentId,
+    /// The raw/local/native symbol for the instrument, assigned by the venue.
+    pub raw_symbol: Symbol,
+    /// The base currency.
+    pub base_currency: Currency,
+    /// The quote currency.
+    pub quote_currency: Currency,
+    /// The currency the contract settles in (the base or quote currency).
+    pub settlement_currency: Currency,
+    /// The settlement of the contract, either delivery of both currencies or cash (NDF).
+    pub settlement_type: SettlementType,
+    /// UNIX timestamp (nanoseconds) for the value (settlement) date.
+    pub value_date_ns: UnixNanos,
+    /// UNIX timestamp (nanoseconds) for the fixing date of a non-deliverable forward.
+    pub fixing_ns: Option<UnixNanos>,
+    /// The decimal precision of a forward point (pip), e.g. 4 for EUR/USD or 2 for USD/JPY.
+    pub points_precision: u8,
+    /// The price decimal precision.
+    pub price_precision: u8,
+    /// The minimum price increment (tick size).
+    pub price_increment: Price,
+    /// The trading size decimal precision.
+    pub size_precision: u8,
+    /// The minimum size increment.
+    pub size_increment: Quantity,
+    /// The rounded lot unit size.
+    pub lot_size: Option<Quantity>,
+    /// The initial (order) margin requirement in percentage of order value.
+    pub margin_init: Decimal,
+    /// The maintenance (position) margin in percentage of position value.
+    pub margin_maint: Decimal,
+    /// The fee rate for liquidity makers as a percentage of order value.
+    pub maker_fee: Decimal,
+    /// The fee rate for liquidity takers as a percentage of order value.
+    pub taker_fee: Decimal,
+    /// The maximum allowable order quantity.
+    pub max_quantity: Option<Quantity>,
+    /// The minimum allowable order quantity.
+    pub min_quantity: Option<Quantity>,
+    /// The maximum allowable quoted price.
+    pub max_price: Option<Price>,
+    /// The minimum allowable quoted price.
+    pub min_price: Option<Price>,
+    /// UNIX timestamp (nanoseconds) when the data event occurred.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the data object was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl FxForward {
+    /// Creates a new [`FxForward`] instance with correctness checking.
+    ///
+    /// # Notes
+    ///
+    /// PyO3 requires a `Result` type for proper error handling and stacktrace printing in Python.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_checked(
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        base_currency: Currency,
+        quote_currency: Currency,
+        settlement_currency: Currency,
+        settlement_type: SettlementType,
+        value_date_ns: UnixNanos,
+        fixing_ns: Option<UnixNanos>,
+        points_precision: u8,
+        price_precision: u8,
+        price_increment: Price,
+        size_precision: u8,
+        size_increment: Quantity,
+        lot_size: Option<Quantity>,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_price: Option<Price>,
+        min_price: Option<Price>,
+        margin_init: Option<Decimal>,
+        margin_maint: Option<Decimal>,
+        maker_fee: Option<Decimal>,
+        taker_fee: Option<Decimal>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        check_predicate_true(
+            base_currency != quote_currency,
+            "invalid `quote_currency`, must differ from `base_currency`",
+        )?;
+        check_predicate_true(
+            settlement_currency == base_currency || settlement_currency == quote_currency,
+            "invalid `settlement_currency`, must be the base or quote currency",
+        )?;
+        match settlement_type {
+            SettlementType::Physical => check_predicate_true(
+                fixing_ns.is_none(),
+                "invalid `fixing_ns`, must be `None` for a deliverable forward",
+            )?,
+            SettlementType::Cash => check_predicate_true(
+                fixing_ns.is_some_and(|fixing_ns| fixing_ns <= value_date_ns),
+                "invalid `fixing_ns`, must be on or before `value_date_ns` for a non-deliverable forward",
+            )?,
+        }
+        check_predicate_true(
+            points_precision <= price_precision,
+            "invalid `points_precision`, must not exceed `price_precision`",
+        )?;
+        check_equal_u8(
+            price_precision,
+            price_increment.precision,
+            stringify!(price_precision),
+            stringify!(price_increment.precision),
+        )?;
+        check_equal_u8(
+            size_precision,
+            size_increment.precision,
+            stringify!(size_precision),
+            stringify!(size_increment.precision),
+        )?;
+        check_positive_price(price_increment.raw, stringify!(price_increment.raw))?;
+        check_positive_quantity(size_increment.raw, stringify!(size_increment.raw))?;
+
+        Ok(Self {
+            id,
+            raw_symbol,
+            base_currency,
+            quote_currency,
+            settlement_currency,
+            settlement_type,
+            value_date_ns,
+            fixing_ns,
+            points_precision,
+            price_precision,
+            price_increment,
+            size_precision,
+            size_increment,
+            lot_size,
+            margin_init: margin_init.unwrap_or_default(),
+            margin_maint: margin_maint.unwrap_or_default(),
+            maker_fee: maker_fee.unwrap_or_default(),
+            taker_fee: taker_fee.unwrap_or_default(),
+            max_quantity,
+            min_quantity,
+            max_price,
+            min_price,
+            ts_event,
+            ts_init,
+        })
+    }
+
+    /// Creates a new [`FxForward`] instance.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        base_currency: Currency,
+        quote_currency: Currency,
+        settlement_currency: Currency,
+        settlement_type: SettlementType,
+        value_date_ns: UnixNanos,
+        fixing_ns: Option<UnixNanos>,
+        points_precision: u8,
+        price_precision: u8,
+        price_increment: Price,
+        size_precision: u8,
+        size_increment: Quantity,
+        lot_size: Option<Quantity>,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_price: Option<Price>,
+        min_price: Option<Price>,
+        margin_init: Option<Decimal>,
+        margin_maint: Option<Decimal>,
+        maker_fee: Option<Decimal>,
+        taker_fee: Option<Decimal>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self::new_checked(
+            id,
+            raw_symbol,
+            base_currency,
+            quote_currency,
+            settlement_currency,
+            settlement_type,
+            value_date_ns,
+            fixing_ns,
+            points_precision,
+            price_precision,
+            price_increment,
+            size_precision,
+            size_increment,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_price,
+            min_price,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+            ts_event,
+            ts_init,
+        )
+        .expect(FAILED)
+    }
+
+    /// Returns whether the forward is cash settled against a fixing rate (NDF).
+    #[must_use]
+    pub fn is_non_deliverable(&self) -> bool {
+        self.settlement_type == SettlementType::Cash
+    }
+
+    /// Returns whether the forward has reached its value date at `ts_now`.
+    #[must_use]
+    pub fn is_settled(&self, ts_now: UnixNanos) -> bool {
+        ts_now >= self.value_date_ns
+    }
+
+    /// Returns the number of calendar days from `ts_now` until the value date.
+    #[must_use]
+    pub fn days_to_value(&self, ts_now: UnixNanos) -> i64 {
+        (date(self.value_date_ns) - date(ts_now)).num_days()
+    }
+
+    /// Returns the forward points of the `outright` rate over the `spot` rate, in pips.
+    #[must_use]
+    pub fn forward_points(&self, spot: Price, outright: Price) -> Decimal {
+        (outright.as_decimal() - spot.as_decimal()) * pip_scale(self.points_precision)
+    }
+
+    /// Returns the outright forward rate for the `spot` rate plus the forward `points`.
+    #[must_use]
+    pub fn outright_price(&self, spot: Price, points: Decimal) -> Price {
+        let outright = spot.as_decimal() + points / pip_scale(self.points_precision);
+        self.make_price(outright.to_f64().unwrap_or_default())
+    }
+
+    /// Returns the base and quote currency amounts exchanged on the value date for a
+    /// deliverable forward traded on the `side` for the `quantity` (in the base currency)
+    /// at the outright `price`, signed as received (positive) or paid (negative).
+    #[must_use]
+    pub fn settlement_amounts(
+        &self,
+        side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+    ) -> (Money, Money) {
+        let base_amount = side_sign(side) * quantity.as_f64();
+        (
+            Money::new(base_amount, self.base_currency),
+            Money::new(-base_amount * price.as_f64(), self.quote_currency),
+        )
+    }
+
+    /// Returns the amount paid in the settlement currency on the value date of a
+    /// non-deliverable forward traded on the `side` for the `quantity` (in the base currency)
+    /// at the outright `price`, given the `fixing_price` observed on the fixing date.
+    #[must_use]
+    pub fn cash_settlement_amount(
+        &self,
+        side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+        fixing_price: Price,
+    ) -> Money {
+        let quote_amount =
+            side_sign(side) * quantity.as_f64() * (fixing_price.as_f64() - price.as_f64());
+        if self.settlement_currency == self.quote_currency {
+            Money::new(quote_amount, self.settlement_currency)
+        } else {
+            Money::new(
+                quote_amount / fixing_price.as_f64(),
+                self.settlement_currency,
+            )
+        }
+    }
+
+    /// Returns the spot value date for a trade on the `trade_date_ns` under the market
+    /// convention for the currency pair.
+    #[must_use]
+    pub fn spot_value_date(&self, trade_date_ns: UnixNanos) -> UnixNanos {
+        fx_spot_value_date(trade_date_ns, self.base_currency, self.quote_currency)
+    }
+}
+
+/// Returns the number of business days from the trade date to the spot value date for the
+/// currency pair, being T+1 for USD against CAD, TRY, RUB or PHP and T+2 otherwise.
+#[must_use]
+pub fn fx_spot_lag(base_currency: Currency, quote_currency: Currency) -> u64 {
+    const T_PLUS_ONE: [&str; 4] = ["CAD", "TRY", "RUB", "PHP"];
+
+    let (base, quote) = (base_currency.code.as_str(), quote_currency.code.as_str());
+    let is_t_plus_one = (base == "USD" && T_PLUS_ONE.contains(&quote))
+        || (quote == "USD" && T_PLUS_ONE.contains(&base));
+    if is_t_plus_one {
+        1
+    } else {
+        2
+    }
+}
+
+/// Returns the spot value date (at midnight UTC) for a trade on the `trade_date_ns`, rolling
+/// forward over weekends.
+///
+/// Currency holiday calendars are not applied.
+#[must_use]
+pub fn fx_spot_value_date(
+    trade_date_ns: UnixNanos,
+    base_currency: Currency,
+    quote_currency: Currency,
+) -> UnixNanos {
+    let mut value_date = date(trade_date_ns);
+    for _ in 0..fx_spot_lag(base_currency, quote_currency) {
+        value_date = next_business_day(value_date);
+    }
+    UnixNanos::from(value_date.and_hms_opt(0, 0, 0).unwrap().and_utc())
+}
+
+fn next_business_day(date: NaiveDate) -> NaiveDate {
+    let mut next = date + Days::new(1);
+    while matches!(next.weekday(), Weekday::Sat | Weekday::Sun) {
+        next = next + Days::new(1);
+    }
+    next
+}
+
+pub(crate) fn date(unix_nanos: UnixNanos) -> NaiveDate {
+    unix_nanos.to_datetime_utc().date_naive()
+}
+
+pub(crate) fn pip_scale(points_precision: u8) -> Decimal {
+    Decimal::from(10_u64.pow(u32::from(points_precision)))
+}
+
+pub(crate) const fn side_sign(side: OrderSide) -> f64 {
+    match side {
+        OrderSide::Sell => -1.0,
+        _ => 1.0,
+    }
+}
+
+impl PartialEq<Self> for FxForward {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for FxForward {}
+
+impl Hash for FxForward {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl Instrument for FxForward {
+    fn into_any(self) -> InstrumentAny {
+        InstrumentAny::FxForward(self)
+    }
+
+    fn id(&self) -> InstrumentId {
+        self.id
+    }
+
+    fn raw_symbol(&self) -> Symbol {
+        self.raw_symbol
+    }
+
+    fn asset_class(&self) -> AssetClass {
+        AssetClass::FX
+    }
+
+    fn instrument_class(&self) -> InstrumentClass {
+        InstrumentClass::Forward
+    }
+
+    fn underlying(&self) -> Option<Ustr> {
+        None
+    }
+
+    fn base_currency(&self) -> Option<Currency> {
+        Some(self.base_currency)
+    }
+
+    fn quote_currency(&self) -> Currency {
+        self.quote_currency
+    }
+
+    fn settlement_currency(&self) -> Currency {
+        self.settlement_currency
+    }
+
+    fn isin(&self) -> Option<Ustr> {
+        None
+    }
+
+    fn option_kind(&self) -> Option<OptionKind> {
+        None
+    }
+
+    fn exchange(&self) -> Option<Ustr> {
+        None
+    }
+
+    fn strike_price(&self) -> Option<Price> {
+        None
+    }
+
+    fn settlement_type(&self) -> Option<SettlementType> {
+        Some(self.settlement_type)
+    }
+
+    fn activation_ns(&self) -> Option<UnixNanos> {
+        None
+    }
+
+    fn expiration_ns(&self) -> Option<UnixNanos> {
+        // Trading in a non-deliverable forward ceases at the fixing
+        Some(self.fixing_ns.unwrap_or(self.value_date_ns))
+    }
+
+    fn is_inverse(&self) -> bool {
+        false
+    }
+
+    fn price_precision(&self) -> u8 {
+        self.price_precision
+    }
+
+    fn size_precision(&self) -> u8 {
+        self.size_precision
+    }
+
+    fn price_increment(&self) -> Price {
+        self.price_increment
+    }
+
+    fn size_increment(&self) -> Quantity {
+        self.size_increment
+    }
+
+    fn multiplier(&self) -> Quantity {
+        Quantity::from(1)
+    }
+
+    fn lot_size(&self) -> Option<Quantity> {
+        self.lot_size
+    }
+
+    fn max_quantity(&self) -> Option<Quantity> {
+        self.max_quantity
+    }
+
+    fn min_quantity(&self) -> Option<Quantity> {
+        self.min_quantity
+    }
+
+    fn max_notional(&self) -> Option<Money> {
+        None
+    }
+
+    fn min_notional(&self) -> Option<Money> {
+        None
+    }
+
+    fn max_price(&self) -> Option<Price> {
+        self.max_price
+    }
+
+    fn min_price(&self) -> Option<Price> {
+        self.min_price
+    }
+
+    fn margin_init(&self) -> Decimal {
+        self.margin_init
+    }
+
+    fn margin_maint(&self) -> Decimal {
+        self.margin_maint
+    }
+
+    fn maker_fee(&self) -> Decimal {
+        self.maker_fee
+    }
+
+    fn taker_fee(&self) -> Decimal {
+        self.taker_fee
+    }
+
+    fn ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::instruments::stubs::*;
+
+    fn ts(value: &str) -> UnixNanos {
+        let datetime: DateTime<Utc> = format!("{value}T00:00:00Z").parse().unwrap();
+        UnixNanos::from(datetime)
+    }
+
+    #[rstest]
+    fn test_equality(fx_forward_eurusd: FxForward) {
+        let cloned = fx_forward_eurusd;
+        assert_eq!(fx_forward_eurusd, cloned);
+    }
+
+    #[rstest]
+    fn test_forward_points_and_outright_price(fx_forward_eurusd: FxForward) {
+        let spot = Price::from("1.08500");
+
+        let points = fx_forward_eurusd.forward_points(spot, Price::from("1.09125"));
+
+        assert_eq!(points, dec!(62.5));
+        assert_eq!(
+            fx_forward_eurusd.outright_price(spot, points),
+            Price::from("1.09125")
+        );
+        assert_eq!(
+            fx_forward_eurusd.outright_price(spot, dec!(-12.3)),
+            Price::from("1.08377")
+        );
+    }
+
+    #[rstest]
+    fn test_settlement_amounts(fx_forward_eurusd: FxForward) {
+        let (base, quote) = fx_forward_eurusd.settlement_amounts(
+            OrderSide::Sell,
+            Quantity::from(1_000_000),
+            Price::from("1.09125"),
+        );
+
+        assert_eq!(base, Money::new(-1_000_000.0, Currency::EUR()));
+        assert_eq!(quote, Money::new(1_091_250.0, Currency::USD()));
+    }
+
+    #[rstest]
+    fn test_value_date_queries(fx_forward_eurusd: FxForward) {
+        assert_eq!(fx_forward_eurusd.days_to_value(ts("2025-01-31")), 90);
+        assert!(!fx_forward_eurusd.is_settled(ts("2025-04-30")));
+        assert!(fx_forward_eurusd.is_settled(ts("2025-05-01")));
+        assert_eq!(
+            fx_forward_eurusd.expiration_ns(),
+            Some(fx_forward_eurusd.value_date_ns)
+        );
+    }
+
+    #[rstest]
+    fn test_non_deliverable_cash_settlement(fx_forward_usdinr_ndf: FxForward) {
+        // Long USD 1,000,000 at 84.00 fixed at 85.00, settled in USD
+        let amount = fx_forward_usdinr_ndf.cash_settlement_amount(
+            OrderSide::Buy,
+            Quantity::from(1_000_000),
+            Price::from("84.0000"),
+            Price::from("85.0000"),
+        );
+
+        assert!(fx_forward_usdinr_ndf.is_non_deliverable());
+        assert_eq!(amount, Money::new(11_764.71, Currency::USD()));
+        assert_eq!(
+            fx_forward_usdinr_ndf.expiration_ns(),
+            fx_forward_usdinr_ndf.fixing_ns
+        );
+    }
+
+    #[rstest]
+    #[case("2025-01-30", "EUR", "USD", "2025-02-03")] // Thursday rolls over the weekend
+    #[case("2025-01-28", "EUR", "USD", "2025-01-30")]
+    #[case("2025-01-31", "USD", "CAD", "2025-02-03")] // T+1
+    #[case("2025-01-28", "USD", "CAD", "2025-01-29")]
+    fn test_spot_value_date(
+        #[case] trade_date: &str,
+        #[case] base: &str,
+        #[case] quote: &str,
+        #[case] expected: &str,
+    ) {
+        let value_date =
+            fx_spot_value_date(ts(trade_date), Currency::from(base), Currency::from(quote));
+
+        assert_eq!(value_date, ts(expected));
+    }
+
+    #[rstest]
+    fn test_new_checked_with_missing_fixing(fx_forward_usdinr_ndf: FxForward) {
+        let result = FxForward::new_checked(
+            fx_forward_usdinr_ndf.id,
+            fx_forward_usdinr_ndf.raw_symbol,
+            fx_forward_usdinr_ndf.base_currency,
+            fx_forward_usdinr_ndf.quote_currency,
+            fx_forward_usdinr_ndf.settlement_currency,
+            SettlementType::Cash,
+            fx_forward_usdinr_ndf.value_date_ns,
+            None,
+            fx_forward_usdinr_ndf.points_precision,
+            fx_forward_usdinr_ndf.price_precision,
+            fx_forward_usdinr_ndf.price_increment,
+            fx_forward_usdinr_ndf.size_precision,
+            fx_forward_usdinr_ndf.size_increment,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            fx_forward_usdinr_ndf.ts_event,
+            fx_forward_usdinr_ndf.ts_init,
+        );
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,588 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::hash::{Hash, Hasher};
+
+use nautilus_core::{
+    correctness::{check_equal_u8, check_predicate_true, FAILED},
+    UnixNanos,
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use super::{
+    any::InstrumentAny,
+    fx_forward::{date, fx_spot_value_date, pip_scale, side_sign},
+    Instrument,
+};
+use crate::{
+    enums::{AssetClass, InstrumentClass, OptionKind, OrderSide, SettlementType},
+    identifiers::{InstrumentId, Symbol},
+    types::{
+        currency::Currency,
+        money::Money,
+        price::{check_positive_price, Price},
+        quantity::{check_positive_quantity, Quantity},
+    },
+};
+
+/// Represents an FX swap, exchanging currencies on a near value date and re-exchanging them
+/// on a far value date.
+///
+/// Prices are quoted in swap points (the far rate less the near rate, in pips). Buying the swap
+/// sells the base currency on the near leg and buys it back on the far leg, so the position
+/// gains as the swap points rise.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct FxSwap {
+    /// The instrument ID.
+    pub id: InstrumentId,
+    /// The raw/local/native symbol for the instrument, assigned by the venue.
+    pub raw_symbol: Symbol,
+    /// The base currency.
+    pub base_currency: Currency,
+    /// The quote currency.
+    pub quote_currency: Currency,
+    /// UNIX timestamp (nanoseconds) for the value date of the near leg.
+    pub near_value_ns: UnixNanos,
+    /// UNIX timestamp (nanoseconds) for the value date of the far leg.
+    pub far_value_ns: UnixNanos,
+    /// The decimal precision of a swap point (pip), e.g. 4 for EUR/USD or 2 for USD/JPY.
+    pub points_precision: u8,
+    /// The price decimal precision.
+    pub price_precision: u8,
+    /// The minimum price increment (tick size).
+    pub price_increment: Price,
+    /// The trading size decimal precision.
+    pub size_precision: u8,
+    /// The minimum size increment.
+    pub size_increment: Quantity,
+    /// The contract multiplier, being the pip size converting swap points to a rate.
+    pub multiplier: Quantity,
+    /// The rounded lot unit size.
+    pub lot_size: Option<Quantity>,
+    /// The initial (order) margin requirement in percentage of order value.
+    pub margin_init: Decimal,
+    /// The maintenance (position) margin in percentage of position value.
+    pub margin_maint: Decimal,
+    /// The fee rate for liquidity makers as a percentage of order value.
+    pub maker_fee: Decimal,
+    /// The fee rate for liquidity takers as a percentage of order value.
+    pub taker_fee: Decimal,
+    /// The maximum allowable order quantity.
+    pub max_quantity: Option<Quantity>,
+    /// The minimum allowable order quantity.
+    pub min_quantity: Option<Quantity>,
+    /// The maximum allowable quoted price.
+    pub max_price: Option<Price>,
+    /// The minimum allowable quoted price.
+    pub min_price: Option<Price>,
+    /// UNIX timestamp (nanoseconds) when the data event occurred.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the data object was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl FxSwap {
+    /// Creates a new [`FxSwap`] instance with correctness checking.
+    ///
+    /// # Notes
+    ///
+    /// PyO3 requires a `Result` type for proper error handling and stacktrace printing in Python.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_checked(
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        base_currency: Currency,
+        quote_currency: Currency,
+        near_value_ns: UnixNanos,
+        far_value_ns: UnixNanos,
+        points_precision: u8,
+        price_precision: u8,
+        price_increment: Price,
+        size_precision: u8,
+        size_increment: Quantity,
+        lot_size: Option<Quantity>,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_price: Option<Price>,
+        min_price: Option<Price>,
+        margin_init: Option<Decimal>,
+        margin_maint: Option<Decimal>,
+        maker_fee: Option<Decimal>,
+        taker_fee: Option<Decimal>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        check_predicate_true(
+            base_currency != quote_currency,
+            "invalid `quote_currency`, must differ from `base_currency`",
+        )?;
+        check_predicate_true(
+            near_value_ns < far_value_ns,
+            "invalid `far_value_ns`, must be after `near_value_ns`",
+        )?;
+        check_equal_u8(
+            price_precision,
+            price_increment.precision,
+            stringify!(price_precision),
+            stringify!(price_increment.precision),
+        )?;
+        check_equal_u8(
+            size_precision,
+            size_increment.precision,
+            stringify!(size_precision),
+            stringify!(size_increment.precision),
+        )?;
+        check_positive_price(price_increment.raw, stringify!(price_increment.raw))?;
+        check_positive_quantity(size_increment.raw, stringify!(size_increment.raw))?;
+
+        let multiplier = Quantity::new_checked(
+            1.0 / 10_f64.powi(i32::from(points_precision)),
+            points_precision,
+        )?;
+
+        Ok(Self {
+            id,
+            raw_symbol,
+            base_currency,
+            quote_currency,
+            near_value_ns,
+            far_value_ns,
+            points_precision,
+            price_precision,
+            price_increment,
+            size_precision,
+            size_increment,
+            multiplier,
+            lot_size,
+            margin_init: margin_init.unwrap_or_default(),
+            margin_maint: margin_maint.unwrap_or_default(),
+            maker_fee: maker_fee.unwrap_or_default(),
+            taker_fee: taker_fee.unwrap_or_default(),
+            max_quantity,
+            min_quantity,
+            max_price,
+            min_price,
+            ts_event,
+            ts_init,
+        })
+    }
+
+    /// Creates a new [`FxSwap`] instance.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        base_currency: Currency,
+        quote_currency: Currency,
+        near_value_ns: UnixNanos,
+        far_value_ns: UnixNanos,
+        points_precision: u8,
+        price_precision: u8,
+        price_increment: Price,
+        size_precision: u8,
+        size_increment: Quantity,
+        lot_size: Option<Quantity>,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_price: Option<Price>,
+        min_price: Option<Price>,
+        margin_init: Option<Decimal>,
+        margin_maint: Option<Decimal>,
+        maker_fee: Option<Decimal>,
+        taker_fee: Option<Decimal>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self::new_checked(
+            id,
+            raw_symbol,
+            base_currency,
+            quote_currency,
+            near_value_ns,
+            far_value_ns,
+            points_precision,
+            price_precision,
+            price_increment,
+            size_precision,
+            size_increment,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_price,
+            min_price,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+            ts_event,
+            ts_init,
+        )
+        .expect(FAILED)
+    }
+
+    /// Returns whether the near leg is a spot value date for a trade on the `trade_date_ns`.
+    #[must_use]
+    pub fn is_spot_start(&self, trade_date_ns: UnixNanos) -> bool {
+        fx_spot_value_date(trade_date_ns, self.base_currency, self.quote_currency)
+            == self.near_value_ns
+    }
+
+    /// Returns the number of calendar days between the near and far value dates.
+    #[must_use]
+    pub fn tenor_days(&self) -> i64 {
+        (date(self.far_value_ns) - date(self.near_value_ns)).num_days()
+    }
+
+    /// Returns whether the far leg has reached its value date at `ts_now`.
+    #[must_use]
+    pub fn is_settled(&self, ts_now: UnixNanos) -> bool {
+        ts_now >= self.far_value_ns
+    }
+
+    /// Returns the swap points between the `near_rate` and `far_rate`, in pips.
+    #[must_use]
+    pub fn swap_points(&self, near_rate: Price, far_rate: Price) -> Decimal {
+        (far_rate.as_decimal() - near_rate.as_decimal()) * pip_scale(self.points_precision)
+    }
+
+    /// Returns the far leg rate for the `near_rate` plus the swap `points`.
+    #[must_use]
+    pub fn far_rate(&self, near_rate: Price, points: Price) -> Decimal {
+        near_rate.as_decimal() + points.as_decimal() / pip_scale(self.points_precision)
+    }
+
+    /// Returns the base and quote currency amounts exchanged on the near value date for a
+    /// swap traded on the `side` for the `quantity` (in the base currency) at the `near_rate`,
+    /// signed as received (positive) or paid (negative).
+    #[must_use]
+    pub fn near_leg_amounts(
+        &self,
+        side: OrderSide,
+        quantity: Quantity,
+        near_rate: Price,
+    ) -> (Money, Money) {
+        let base_amount = -side_sign(side) * quantity.as_f64();
+        (
+            Money::new(base_amount, self.base_currency),
+            Money::new(-base_amount * near_rate.as_f64(), self.quote_currency),
+        )
+    }
+
+    /// Returns the base and quote currency amounts exchanged on the far value date for a
+    /// swap traded on the `side` for the `quantity` (in the base currency) at the `near_rate`
+    /// and swap `points`, signed as received (positive) or paid (negative).
+    #[must_use]
+    pub fn far_leg_amounts(
+        &self,
+        side: OrderSide,
+        quantity: Quantity,
+        near_rate: Price,
+        points: Price,
+    ) -> (Money, Money) {
+        let base_amount = side_sign(side) * quantity.as_f64();
+        let far_rate = near_rate.as_f64() + points.as_f64() * self.multiplier.as_f64();
+        (
+            Money::new(base_amount, self.base_currency),
+            Money::new(-base_amount * far_rate, self.quote_currency),
+        )
+    }
+
+    /// Returns the net quote currency amount of both legs for a swap traded on the `side` for
+    /// the `quantity` (in the base currency) at the swap `points`, the base currency notionals
+    /// offsetting.
+    #[must_use]
+    pub fn net_settlement_amount(
+        &self,
+        side: OrderSide,
+        quantity: Quantity,
+        points: Price,
+    ) -> Money {
+        let amount =
+            -side_sign(side) * quantity.as_f64() * points.as_f64() * self.multiplier.as_f64();
+        Money::new(amount, self.quote_currency)
+    }
+}
+
+impl PartialEq<Self> for FxSwap {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for FxSwap {}
+
+impl Hash for FxSwap {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl Instrument for FxSwap {
+    fn into_any(self) -> InstrumentAny {
+        InstrumentAny::FxSwap(self)
+    }
+
+    fn id(&self) -> InstrumentId {
+        self.id
+    }
+
+    fn raw_symbol(&self) -> Symbol {
+        self.raw_symbol
+    }
+
+    fn asset_class(&self) -> AssetClass {
+        AssetClass::FX
+    }
+
+    fn instrument_class(&self) -> InstrumentClass {
+        InstrumentClass::Swap
+    }
+
+    fn underlying(&self) -> Option<Ustr> {
+        None
+    }
+
+    fn base_currency(&self) -> Option<Currency> {
+        Some(self.base_currency)
+    }
+
+    fn quote_currency(&self) -> Currency {
+        self.quote_currency
+    }
+
+    fn settlement_currency(&self) -> Currency {
+        self.quote_currency
+    }
+
+    fn isin(&self) -> Option<Ustr> {
+        None
+    }
+
+    fn option_kind(&self) -> Option<OptionKind> {
+        None
+    }
+
+    fn exchange(&self) -> Option<Ustr> {
+        None
+    }
+
+    fn strike_price(&self) -> Option<Price> {
+        None
+    }
+
+    fn settlement_type(&self) -> Option<SettlementType> {
+        Some(SettlementType::Physical)
+    }
+
+    fn activation_ns(&self) -> Option<UnixNanos> {
+        None
+    }
+
+    fn expiration_ns(&self) -> Option<UnixNanos> {
+        Some(self.near_value_ns)
+    }
+
+    fn is_inverse(&self) -> bool {
+        false
+    }
+
+    fn price_precision(&self) -> u8 {
+        self.price_precision
+    }
+
+    fn size_precision(&self) -> u8 {
+        self.size_precision
+    }
+
+    fn price_increment(&self) -> Price {
+        self.price_increment
+    }
+
+    fn size_increment(&self) -> Quantity {
+        self.size_increment
+    }
+
+    fn multiplier(&self) -> Quantity {
+        self.multiplier
+    }
+
+    fn lot_size(&self) -> Option<Quantity> {
+        self.lot_size
+    }
+
+    fn max_quantity(&self) -> Option<Quantity> {
+        self.max_quantity
+    }
+
+    fn min_quantity(&self) -> Option<Quantity> {
+        self.min_quantity
+    }
+
+    fn max_notional(&self) -> Option<Money> {
+        None
+    }
+
+    fn min_notional(&self) -> Option<Money> {
+        None
+    }
+
+    fn max_price(&self) -> Option<Price> {
+        self.max_price
+    }
+
+    fn min_price(&self) -> Option<Price> {
+        self.min_price
+    }
+
+    fn margin_init(&self) -> Decimal {
+        self.margin_init
+    }
+
+    fn margin_maint(&self) -> Decimal {
+        self.margin_maint
+    }
+
+    fn maker_fee(&self) -> Decimal {
+        self.maker_fee
+    }
+
+    fn taker_fee(&self) -> Decimal {
+        self.taker_fee
+    }
+
+    fn ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::instruments::stubs::*;
+
+    fn ts(value: &str) -> UnixNanos {
+        let datetime: DateTime<Utc> = format!("{value}T00:00:00Z").parse().unwrap();
+        UnixNanos::from(datetime)
+    }
+
+    #[rstest]
+    fn test_equality(fx_swap_eurusd: FxSwap) {
+        let cloned = fx_swap_eurusd;
+        assert_eq!(fx_swap_eurusd, cloned);
+    }
+
+    #[rstest]
+    fn test_tenor_and_spot_start(fx_swap_eurusd: FxSwap) {
+        assert_eq!(fx_swap_eurusd.tenor_days(), 28);
+        assert!(fx_swap_eurusd.is_spot_start(ts("2025-01-30")));
+        assert!(!fx_swap_eurusd.is_spot_start(ts("2025-01-31")));
+        assert!(!fx_swap_eurusd.is_settled(ts("2025-03-02")));
+        assert!(fx_swap_eurusd.is_settled(ts("2025-03-03")));
+    }
+
+    #[rstest]
+    fn test_swap_points_and_far_rate(fx_swap_eurusd: FxSwap) {
+        let near_rate = Price::from("1.08500");
+
+        assert_eq!(
+            fx_swap_eurusd.swap_points(near_rate, Price::from("1.08621")),
+            dec!(12.1)
+        );
+        assert_eq!(
+            fx_swap_eurusd.far_rate(near_rate, Price::from("12.10")),
+            dec!(1.08621)
+        );
+    }
+
+    #[rstest]
+    fn test_leg_amounts_net_to_swap_points(fx_swap_eurusd: FxSwap) {
+        let quantity = Quantity::from(1_000_000);
+        let near_rate = Price::from("1.08500");
+        let points = Price::from("12.10");
+
+        let (near_base, near_quote) =
+            fx_swap_eurusd.near_leg_amounts(OrderSide::Buy, quantity, near_rate);
+        let (far_base, far_quote) =
+            fx_swap_eurusd.far_leg_amounts(OrderSide::Buy, quantity, near_rate, points);
+        let net = fx_swap_eurusd.net_settlement_amount(OrderSide::Buy, quantity, points);
+
+        assert_eq!(near_base, Money::new(-1_000_000.0, Currency::EUR()));
+        assert_eq!(near_quote, Money::new(1_085_000.0, Currency::USD()));
+        assert_eq!(far_base, Money::new(1_000_000.0, Currency::EUR()));
+        assert_eq!(far_quote, Money::new(-1_086_210.0, Currency::USD()));
+        assert_eq!(net, near_quote + far_quote);
+        assert_eq!(net, Money::new(-1_210.0, Currency::USD()));
+    }
+
+    #[rstest]
+    fn test_notional_value_in_points(fx_swap_eurusd: FxSwap) {
+        let notional = fx_swap_eurusd.calculate_notional_value(
+            Quantity::from(1_000_000),
+            Price::from("-5.50"),
+            None,
+        );
+
+        assert_eq!(fx_swap_eurusd.multiplier, Quantity::from("0.0001"));
+        assert_eq!(notional, Money::new(-550.0, Currency::USD()));
+    }
+
+    #[rstest]
+    fn test_new_checked_with_inverted_value_dates(fx_swap_eurusd: FxSwap) {
+        let result = FxSwap::new_checked(
+            fx_swap_eurusd.id,
+            fx_swap_eurusd.raw_symbol,
+            fx_swap_eurusd.base_currency,
+            fx_swap_eurusd.quote_currency,
+            fx_swap_eurusd.far_value_ns,
+            fx_swap_eurusd.near_value_ns,
+            fx_swap_eurusd.points_precision,
+            fx_swap_eurusd.price_precision,
+            fx_swap_eurusd.price_increment,
+            fx_swap_eurusd.size_precision,
+            fx_swap_eurusd.size_increment,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            fx_swap_eurusd.ts_event,
+            fx_swap_eurusd.ts_init,
+        );
+
+        assert!(result.is_err());
+    }
+}
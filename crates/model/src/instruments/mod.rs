@@ -18,12 +18,16 @@
 pub mod any;
 pub mod betting;
 pub mod binary_option;
+pub mod bond;
 pub mod crypto_future;
 pub mod crypto_perpetual;
 pub mod currency_pair;
 pub mod equity;
 pub mod futures_contract;
 pub mod futures_spread;
+pub mod fx_forward;
+pub mod fx_swap;
+pub mod option_chain;
 pub mod option_contract;
 pub mod option_spread;
 pub mod synthetic;
@@ -38,13 +42,25 @@ use ustr::Ustr;
 
 // Re-exports
 pub use crate::instruments::{
-    any::InstrumentAny, betting::BettingInstrument, binary_option::BinaryOption,
-    crypto_future::CryptoFuture, crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair,
-    equity::Equity, futures_contract::FuturesContract, futures_spread::FuturesSpread,
-    option_contract::OptionContract, option_spread::OptionSpread, synthetic::SyntheticInstrument,
+    any::InstrumentAny,
+    betting::BettingInstrument,
+    binary_option::BinaryOption,
+    bond::Bond,
+    crypto_future::CryptoFuture,
+    crypto_perpetual::CryptoPerpetual,
+    currency_pair::CurrencyPair,
+    equity::Equity,
+    futures_contract::FuturesContract,
+    futures_spread::FuturesSpread,
+    fx_forward::{fx_spot_value_date, FxForward},
+    fx_swap::FxSwap,
+    option_chain::{OptionChain, OptionStrike},
+    option_contract::OptionContract,
+    option_spread::OptionSpread,
+    synthetic::SyntheticInstrument,
 };
 use crate::{
-    enums::{AssetClass, InstrumentClass, OptionKind},
+    enums::{AssetClass, ExerciseStyle, InstrumentClass, OptionKind, SettlementType},
     identifiers::{InstrumentId, Symbol, Venue},
     types::{Currency, Money, Price, Quantity},
 };
@@ -69,6 +85,12 @@ pub trait Instrument: 'static + Send {
     fn option_kind(&self) -> Option<OptionKind>;
     fn exchange(&self) -> Option<Ustr>;
     fn strike_price(&self) -> Option<Price>;
+    fn exercise_style(&self) -> Option<ExerciseStyle> {
+        None
+    }
+    fn settlement_type(&self) -> Option<SettlementType> {
+        None
+    }
     fn activation_ns(&self) -> Option<UnixNanos>;
     fn expiration_ns(&self) -> Option<UnixNanos>;
     fn is_inverse(&self) -> bool;
@@ -153,9 +175,11 @@ pub trait Instrument: 'static + Send {
     }
 }
 
-pub const EXPIRING_INSTRUMENT_TYPES: [InstrumentClass; 4] = [
+pub const EXPIRING_INSTRUMENT_TYPES: [InstrumentClass; 6] = [
     InstrumentClass::Future,
     InstrumentClass::FuturesSpread,
+    InstrumentClass::Forward,
     InstrumentClass::Option,
     InstrumentClass::OptionSpread,
+    InstrumentClass::Bond,
 ];
@@ -0,0 +1,335 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A chain of option contracts on an underlying, grouped by expiration and strike price.
+
+use std::collections::BTreeMap;
+
+use nautilus_core::UnixNanos;
+use ustr::Ustr;
+
+use super::{any::InstrumentAny, option_contract::OptionContract};
+use crate::{enums::OptionKind, types::Price};
+
+/// Represents the call and put option contracts at a strike price for an expiration.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OptionStrike {
+    /// The call option contract at the strike price.
+    pub call: Option<OptionContract>,
+    /// The put option contract at the strike price.
+    pub put: Option<OptionContract>,
+}
+
+impl OptionStrike {
+    /// Returns the option contract of the given `option_kind` (if found).
+    #[must_use]
+    pub const fn get(&self, option_kind: OptionKind) -> Option<&OptionContract> {
+        match option_kind {
+            OptionKind::Call => self.call.as_ref(),
+            OptionKind::Put => self.put.as_ref(),
+        }
+    }
+}
+
+/// Represents a chain of option contracts on the same underlying, grouped by expiration then
+/// strike price.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OptionChain {
+    /// The underlying asset of the option contracts in the chain.
+    pub underlying: Ustr,
+    expirations: BTreeMap<UnixNanos, BTreeMap<Price, OptionStrike>>,
+}
+
+impl OptionChain {
+    /// Creates a new empty [`OptionChain`] instance for the `underlying`.
+    #[must_use]
+    pub fn new(underlying: Ustr) -> Self {
+        Self {
+            underlying,
+            expirations: BTreeMap::new(),
+        }
+    }
+
+    /// Creates a new [`OptionChain`] instance from the option contracts on the `underlying`
+    /// within the given `instruments` (other instruments are ignored).
+    #[must_use]
+    pub fn from_instruments<'a>(
+        underlying: Ustr,
+        instruments: impl IntoIterator<Item = &'a InstrumentAny>,
+    ) -> Self {
+        let mut chain = Self::new(underlying);
+        for instrument in instruments {
+            if let InstrumentAny::OptionContract(option) = instrument {
+                if option.underlying == underlying {
+                    chain.insert(*option);
+                }
+            }
+        }
+        chain
+    }
+
+    /// Adds the `option` contract to the chain, replacing any contract of the same kind at the
+    /// same expiration and strike price.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying of the `option` is not the underlying of the chain.
+    pub fn add(&mut self, option: OptionContract) -> anyhow::Result<()> {
+        if option.underlying != self.underlying {
+            anyhow::bail!(
+                "Invalid option {} for chain: underlying {} was not {}",
+                option.id,
+                option.underlying,
+                self.underlying,
+            );
+        }
+        self.insert(option);
+        Ok(())
+    }
+
+    fn insert(&mut self, option: OptionContract) {
+        let strike = self
+            .expirations
+            .entry(option.expiration_ns)
+            .or_default()
+            .entry(option.strike_price)
+            .or_default();
+        match option.option_kind {
+            OptionKind::Call => strike.call = Some(option),
+            OptionKind::Put => strike.put = Some(option),
+        }
+    }
+
+    /// Returns the number of option contracts in the chain.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.expirations
+            .values()
+            .flat_map(BTreeMap::values)
+            .map(|strike| usize::from(strike.call.is_some()) + usize::from(strike.put.is_some()))
+            .sum()
+    }
+
+    /// Returns whether the chain contains no option contracts.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.expirations.is_empty()
+    }
+
+    /// Returns the expirations in the chain, in ascending order.
+    #[must_use]
+    pub fn expirations(&self) -> Vec<UnixNanos> {
+        self.expirations.keys().copied().collect()
+    }
+
+    /// Returns the strike prices for the `expiration`, in ascending order.
+    #[must_use]
+    pub fn strikes(&self, expiration: UnixNanos) -> Vec<Price> {
+        self.expirations
+            .get(&expiration)
+            .map(|strikes| strikes.keys().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the call and put option contracts at the `strike_price` for the `expiration`
+    /// (if found).
+    #[must_use]
+    pub fn strike(&self, expiration: UnixNanos, strike_price: Price) -> Option<&OptionStrike> {
+        self.expirations.get(&expiration)?.get(&strike_price)
+    }
+
+    /// Returns the option contract of the `option_kind` at the `strike_price` for the
+    /// `expiration` (if found).
+    #[must_use]
+    pub fn get(
+        &self,
+        expiration: UnixNanos,
+        strike_price: Price,
+        option_kind: OptionKind,
+    ) -> Option<&OptionContract> {
+        self.strike(expiration, strike_price)?.get(option_kind)
+    }
+
+    /// Returns the option contracts for the `expiration`, ordered by strike price with calls
+    /// before puts.
+    #[must_use]
+    pub fn options(&self, expiration: UnixNanos) -> Vec<&OptionContract> {
+        self.expirations
+            .get(&expiration)
+            .map(|strikes| {
+                strikes
+                    .values()
+                    .flat_map(|strike| strike.call.iter().chain(strike.put.iter()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the nearest expiration at or after `ts` (if found).
+    #[must_use]
+    pub fn nearest_expiration(&self, ts: UnixNanos) -> Option<UnixNanos> {
+        self.expirations.range(ts..).next().map(|(ts, _)| *ts)
+    }
+
+    /// Returns the strike price for the `expiration` nearest to the given `price` (such as the
+    /// at-the-money strike for the underlying price), preferring the lower strike when equidistant.
+    #[must_use]
+    pub fn nearest_strike(&self, expiration: UnixNanos, price: Price) -> Option<Price> {
+        let strikes = self.expirations.get(&expiration)?;
+        let below = strikes.range(..=price).next_back().map(|(p, _)| *p);
+        let above = strikes.range(price..).next().map(|(p, _)| *p);
+        match (below, above) {
+            (Some(below), Some(above)) => {
+                let distance_below = price.as_decimal() - below.as_decimal();
+                let distance_above = above.as_decimal() - price.as_decimal();
+                Some(if distance_above < distance_below {
+                    above
+                } else {
+                    below
+                })
+            }
+            (below, above) => below.or(above),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        identifiers::InstrumentId,
+        instruments::{stubs::*, Instrument},
+    };
+
+    fn option(
+        template: OptionContract,
+        expiration: u64,
+        strike_price: &str,
+        option_kind: OptionKind,
+    ) -> OptionContract {
+        let mut option = template;
+        option.id = InstrumentId::from(
+            format!("AAPL-{expiration}-{strike_price}-{option_kind}.OPRA").as_str(),
+        );
+        option.expiration_ns = UnixNanos::from(expiration);
+        option.strike_price = Price::from(strike_price);
+        option.option_kind = option_kind;
+        option
+    }
+
+    fn chain(template: OptionContract) -> OptionChain {
+        let instruments = [
+            option(template, 2, "150.00", OptionKind::Call),
+            option(template, 2, "150.00", OptionKind::Put),
+            option(template, 2, "140.00", OptionKind::Call),
+            option(template, 1, "145.00", OptionKind::Put),
+        ]
+        .map(Instrument::into_any);
+        OptionChain::from_instruments(template.underlying, &instruments)
+    }
+
+    #[rstest]
+    fn test_from_instruments(option_contract_appl: OptionContract) {
+        let chain = chain(option_contract_appl);
+
+        assert_eq!(chain.len(), 4);
+        assert!(!chain.is_empty());
+        assert_eq!(
+            chain.expirations(),
+            vec![UnixNanos::from(1), UnixNanos::from(2)]
+        );
+        assert_eq!(
+            chain.strikes(UnixNanos::from(2)),
+            vec![Price::from("140.00"), Price::from("150.00")]
+        );
+        assert!(chain.strikes(UnixNanos::from(3)).is_empty());
+    }
+
+    #[rstest]
+    fn test_get_and_options(option_contract_appl: OptionContract) {
+        let chain = chain(option_contract_appl);
+        let expiration = UnixNanos::from(2);
+
+        let put = chain
+            .get(expiration, Price::from("150.00"), OptionKind::Put)
+            .unwrap();
+        assert_eq!(put.option_kind, OptionKind::Put);
+        assert!(chain
+            .get(expiration, Price::from("140.00"), OptionKind::Put)
+            .is_none());
+        assert_eq!(
+            chain
+                .options(expiration)
+                .iter()
+                .map(|o| (o.strike_price, o.option_kind))
+                .collect::<Vec<_>>(),
+            vec![
+                (Price::from("140.00"), OptionKind::Call),
+                (Price::from("150.00"), OptionKind::Call),
+                (Price::from("150.00"), OptionKind::Put),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case(0, Some(1))]
+    #[case(1, Some(1))]
+    #[case(2, Some(2))]
+    #[case(3, None)]
+    fn test_nearest_expiration(
+        option_contract_appl: OptionContract,
+        #[case] ts: u64,
+        #[case] expected: Option<u64>,
+    ) {
+        let chain = chain(option_contract_appl);
+
+        assert_eq!(
+            chain.nearest_expiration(UnixNanos::from(ts)),
+            expected.map(UnixNanos::from)
+        );
+    }
+
+    #[rstest]
+    #[case("130.00", "140.00")]
+    #[case("144.99", "140.00")]
+    #[case("145.00", "140.00")]
+    #[case("145.01", "150.00")]
+    #[case("160.00", "150.00")]
+    fn test_nearest_strike(
+        option_contract_appl: OptionContract,
+        #[case] price: &str,
+        #[case] expected: &str,
+    ) {
+        let chain = chain(option_contract_appl);
+
+        assert_eq!(
+            chain.nearest_strike(UnixNanos::from(2), Price::from(price)),
+            Some(Price::from(expected))
+        );
+    }
+
+    #[rstest]
+    fn test_add_with_other_underlying_errors(option_contract_appl: OptionContract) {
+        let mut chain = OptionChain::new(Ustr::from("MSFT"));
+
+        assert!(chain.add(option_contract_appl).is_err());
+        assert!(chain.is_empty());
+    }
+}
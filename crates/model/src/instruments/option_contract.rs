@@ -25,7 +25,7 @@ use ustr::Ustr;
 
 use super::{any::InstrumentAny, Instrument};
 use crate::{
-    enums::{AssetClass, InstrumentClass, OptionKind},
+    enums::{AssetClass, ExerciseStyle, InstrumentClass, OptionKind, SettlementType},
     identifiers::{InstrumentId, Symbol},
     types::{
         currency::Currency,
@@ -55,14 +55,24 @@ pub struct OptionContract {
     pub underlying: Ustr,
     /// The kind of option (PUT | CALL).
     pub option_kind: OptionKind,
+    /// The exercise style of the option (AMERICAN | EUROPEAN | BERMUDAN).
+    #[serde(default)]
+    pub exercise_style: ExerciseStyle,
+    /// The settlement of the option on exercise (PHYSICAL | CASH).
+    #[serde(default)]
+    pub settlement_type: SettlementType,
     /// The option strike price.
     pub strike_price: Price,
     /// UNIX timestamp (nanoseconds) for contract activation.
     pub activation_ns: UnixNanos,
     /// UNIX timestamp (nanoseconds) for contract expiration.
     pub expiration_ns: UnixNanos,
-    /// The option contract currency.
+    /// The option contract currency (of the strike price and settlement).
     pub currency: Currency,
+    /// The currency the option premium is quoted and paid in, if different to the contract
+    /// currency.
+    #[serde(default)]
+    pub premium_currency: Option<Currency>,
     /// The price decimal precision.
     pub price_precision: u8,
     /// The minimum price increment (tick size).
@@ -71,7 +81,8 @@ pub struct OptionContract {
     pub size_increment: Quantity,
     /// The trading size decimal precision.
     pub size_precision: u8,
-    /// The option multiplier.
+    /// The contract multiplier, being the units of the deliverable per contract which the
+    /// premium and intrinsic value are scaled by.
     pub multiplier: Quantity,
     /// The rounded lot unit size (standard/board).
     pub lot_size: Quantity,
@@ -127,6 +138,9 @@ impl OptionContract {
         margin_maint: Option<Decimal>,
         maker_fee: Option<Decimal>,
         taker_fee: Option<Decimal>,
+        exercise_style: Option<ExerciseStyle>,
+        settlement_type: Option<SettlementType>,
+        premium_currency: Option<Currency>,
         ts_event: UnixNanos,
         ts_init: UnixNanos,
     ) -> anyhow::Result<Self> {
@@ -149,10 +163,13 @@ impl OptionContract {
             exchange,
             underlying,
             option_kind,
+            exercise_style: exercise_style.unwrap_or_default(),
+            settlement_type: settlement_type.unwrap_or_default(),
             activation_ns,
             expiration_ns,
             strike_price,
             currency,
+            premium_currency,
             price_precision,
             price_increment,
             size_precision: 0,
@@ -197,6 +214,9 @@ impl OptionContract {
         margin_maint: Option<Decimal>,
         maker_fee: Option<Decimal>,
         taker_fee: Option<Decimal>,
+        exercise_style: Option<ExerciseStyle>,
+        settlement_type: Option<SettlementType>,
+        premium_currency: Option<Currency>,
         ts_event: UnixNanos,
         ts_init: UnixNanos,
     ) -> Self {
@@ -223,11 +243,55 @@ impl OptionContract {
             margin_maint,
             maker_fee,
             taker_fee,
+            exercise_style,
+            settlement_type,
+            premium_currency,
             ts_event,
             ts_init,
         )
         .expect(FAILED)
     }
+
+    /// Returns the currency the option premium is quoted and paid in.
+    #[must_use]
+    pub fn premium_currency(&self) -> Currency {
+        self.premium_currency.unwrap_or(self.currency)
+    }
+
+    /// Calculates the premium for the given `quantity` of contracts at the given `price`,
+    /// scaled by the contract multiplier.
+    #[must_use]
+    pub fn calculate_premium(&self, quantity: Quantity, price: Price) -> Money {
+        let amount = quantity.as_f64() * self.multiplier.as_f64() * price.as_f64();
+        Money::new(amount, self.premium_currency())
+    }
+
+    /// Returns the quantity of the deliverable (underlying) for the given `quantity` of contracts
+    /// on exercise, or `None` if the option is cash settled.
+    #[must_use]
+    pub fn deliverable_quantity(&self, quantity: Quantity) -> Option<Decimal> {
+        match self.settlement_type {
+            SettlementType::Physical => Some(quantity.as_decimal() * self.multiplier.as_decimal()),
+            SettlementType::Cash => None,
+        }
+    }
+
+    /// Returns the intrinsic value of a single contract for the given `underlying_price`,
+    /// scaled by the contract multiplier.
+    #[must_use]
+    pub fn intrinsic_value(&self, underlying_price: Price) -> Decimal {
+        let moneyness = match self.option_kind {
+            OptionKind::Call => underlying_price.as_decimal() - self.strike_price.as_decimal(),
+            OptionKind::Put => self.strike_price.as_decimal() - underlying_price.as_decimal(),
+        };
+        moneyness.max(Decimal::ZERO) * self.multiplier.as_decimal()
+    }
+
+    /// Returns whether the option may be exercised prior to the expiration date.
+    #[must_use]
+    pub const fn is_early_exercisable(&self) -> bool {
+        !matches!(self.exercise_style, ExerciseStyle::European)
+    }
 }
 
 impl PartialEq<Self> for OptionContract {
@@ -273,7 +337,7 @@ impl Instrument for OptionContract {
     }
 
     fn quote_currency(&self) -> Currency {
-        self.currency
+        self.premium_currency()
     }
 
     fn settlement_currency(&self) -> Currency {
@@ -296,6 +360,14 @@ impl Instrument for OptionContract {
         Some(self.strike_price)
     }
 
+    fn exercise_style(&self) -> Option<ExerciseStyle> {
+        Some(self.exercise_style)
+    }
+
+    fn settlement_type(&self) -> Option<SettlementType> {
+        Some(self.settlement_type)
+    }
+
     fn activation_ns(&self) -> Option<UnixNanos> {
         Some(self.activation_ns)
     }
@@ -371,12 +443,92 @@ impl Instrument for OptionContract {
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
+    use rust_decimal_macros::dec;
 
-    use crate::instruments::{stubs::*, OptionContract};
+    use crate::{
+        enums::{ExerciseStyle, OptionKind, SettlementType},
+        instruments::{stubs::*, Instrument, OptionContract},
+        types::{Currency, Money, Price, Quantity},
+    };
 
     #[rstest]
     fn test_equality(option_contract_appl: OptionContract) {
         let option_contract_appl2 = option_contract_appl;
         assert_eq!(option_contract_appl, option_contract_appl2);
     }
+
+    #[rstest]
+    fn test_premium_scaled_by_multiplier(mut option_contract_appl: OptionContract) {
+        option_contract_appl.multiplier = Quantity::from(100);
+
+        let premium =
+            option_contract_appl.calculate_premium(Quantity::from(2), Price::from("1.50"));
+
+        assert_eq!(premium, Money::new(300.0, Currency::USD()));
+        assert_eq!(
+            option_contract_appl.deliverable_quantity(Quantity::from(2)),
+            Some(dec!(200))
+        );
+        assert_eq!(
+            option_contract_appl.intrinsic_value(Price::from("150.50")),
+            dec!(150)
+        );
+    }
+
+    #[rstest]
+    #[case(OptionKind::Call, "148.00", "0")]
+    #[case(OptionKind::Call, "151.00", "2")]
+    #[case(OptionKind::Put, "148.00", "1")]
+    #[case(OptionKind::Put, "151.00", "0")]
+    fn test_intrinsic_value(
+        mut option_contract_appl: OptionContract,
+        #[case] option_kind: OptionKind,
+        #[case] underlying_price: &str,
+        #[case] expected: &str,
+    ) {
+        option_contract_appl.option_kind = option_kind;
+
+        assert_eq!(
+            option_contract_appl
+                .intrinsic_value(Price::from(underlying_price))
+                .normalize()
+                .to_string(),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn test_cash_settled_premium_currency(mut option_contract_appl: OptionContract) {
+        option_contract_appl.exercise_style = ExerciseStyle::European;
+        option_contract_appl.settlement_type = SettlementType::Cash;
+        option_contract_appl.premium_currency = Some(Currency::BTC());
+
+        assert!(!option_contract_appl.is_early_exercisable());
+        assert_eq!(
+            option_contract_appl.deliverable_quantity(Quantity::from(1)),
+            None
+        );
+        assert_eq!(option_contract_appl.quote_currency(), Currency::BTC());
+        assert_eq!(option_contract_appl.settlement_currency(), Currency::USD());
+        assert_eq!(
+            option_contract_appl.settlement_type(),
+            Some(SettlementType::Cash)
+        );
+    }
+
+    #[rstest]
+    fn test_deserialize_without_option_conventions(option_contract_appl: OptionContract) {
+        let mut value = serde_json::to_value(option_contract_appl).unwrap();
+        let fields = value.as_object_mut().unwrap();
+        fields.remove("exercise_style");
+        fields.remove("settlement_type");
+        fields.remove("premium_currency");
+
+        let json = serde_json::to_string(&value).unwrap();
+        let deserialized: OptionContract = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized.exercise_style, ExerciseStyle::American);
+        assert_eq!(deserialized.settlement_type, SettlementType::Physical);
+        assert_eq!(deserialized.premium_currency(), Currency::USD());
+    }
 }
@@ -21,11 +21,12 @@ use rust_decimal_macros::dec;
 use ustr::Ustr;
 
 use super::{
-    betting::BettingInstrument, binary_option::BinaryOption, futures_spread::FuturesSpread,
+    betting::BettingInstrument, binary_option::BinaryOption, bond::Bond,
+    futures_spread::FuturesSpread, fx_forward::FxForward, fx_swap::FxSwap,
     option_spread::OptionSpread, synthetic::SyntheticInstrument,
 };
 use crate::{
-    enums::{AssetClass, OptionKind},
+    enums::{AssetClass, DayCountConvention, ExerciseStyle, OptionKind, SettlementType},
     identifiers::{InstrumentId, Symbol, Venue},
     instruments::{
         CryptoFuture, CryptoPerpetual, CurrencyPair, Equity, FuturesContract, OptionContract,
@@ -315,6 +316,44 @@ pub fn equity_aapl() -> Equity {
     )
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Bond
+////////////////////////////////////////////////////////////////////////////////
+
+#[fixture]
+pub fn bond_ust() -> Bond {
+    let issue = Utc.with_ymd_and_hms(2024, 5, 15, 0, 0, 0).unwrap();
+    let maturity = Utc.with_ymd_and_hms(2034, 5, 15, 0, 0, 0).unwrap();
+    Bond::new(
+        InstrumentId::from("912828ZT0.XCBT"),
+        Symbol::from("912828ZT0"),
+        Some(Ustr::from("US912828ZT04")),
+        None,
+        Currency::USD(),
+        dec!(0.0425),
+        2,
+        DayCountConvention::ActualActual,
+        Quantity::from(1_000),
+        UnixNanos::from(issue),
+        UnixNanos::from(maturity),
+        3,
+        Price::from("0.001"),
+        0,
+        Quantity::from(1),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        UnixNanos::default(),
+        UnixNanos::default(),
+    )
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // FuturesContract
 ////////////////////////////////////////////////////////////////////////////////
@@ -396,6 +435,107 @@ pub fn futures_spread_es() -> FuturesSpread {
     )
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// FxForward
+////////////////////////////////////////////////////////////////////////////////
+
+#[fixture]
+pub fn fx_forward_eurusd() -> FxForward {
+    let value_date = Utc.with_ymd_and_hms(2025, 5, 1, 0, 0, 0).unwrap();
+    FxForward::new(
+        InstrumentId::from("EUR/USD-20250501.SIM"),
+        Symbol::from("EUR/USD-20250501"),
+        Currency::EUR(),
+        Currency::USD(),
+        Currency::USD(),
+        SettlementType::Physical,
+        UnixNanos::from(value_date),
+        None,
+        4,
+        5,
+        Price::from("0.00001"),
+        0,
+        Quantity::from(1),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        UnixNanos::default(),
+        UnixNanos::default(),
+    )
+}
+
+#[fixture]
+pub fn fx_forward_usdinr_ndf() -> FxForward {
+    let fixing = Utc.with_ymd_and_hms(2025, 5, 1, 0, 0, 0).unwrap();
+    let value_date = Utc.with_ymd_and_hms(2025, 5, 5, 0, 0, 0).unwrap();
+    FxForward::new(
+        InstrumentId::from("USD/INR-NDF-20250505.SIM"),
+        Symbol::from("USD/INR-NDF-20250505"),
+        Currency::USD(),
+        Currency::INR(),
+        Currency::USD(),
+        SettlementType::Cash,
+        UnixNanos::from(value_date),
+        Some(UnixNanos::from(fixing)),
+        2,
+        4,
+        Price::from("0.0001"),
+        0,
+        Quantity::from(1),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        UnixNanos::default(),
+        UnixNanos::default(),
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// FxSwap
+////////////////////////////////////////////////////////////////////////////////
+
+#[fixture]
+pub fn fx_swap_eurusd() -> FxSwap {
+    let near_value = Utc.with_ymd_and_hms(2025, 2, 3, 0, 0, 0).unwrap();
+    let far_value = Utc.with_ymd_and_hms(2025, 3, 3, 0, 0, 0).unwrap();
+    FxSwap::new(
+        InstrumentId::from("EUR/USD-SW-20250303.SIM"),
+        Symbol::from("EUR/USD-SW-20250303"),
+        Currency::EUR(),
+        Currency::USD(),
+        UnixNanos::from(near_value),
+        UnixNanos::from(far_value),
+        4,
+        2,
+        Price::from("0.01"),
+        0,
+        Quantity::from(1),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        UnixNanos::default(),
+        UnixNanos::default(),
+    )
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // OptionContract
 ////////////////////////////////////////////////////////////////////////////////
@@ -427,6 +567,9 @@ pub fn option_contract_appl() -> OptionContract {
         None,
         None,
         None,
+        Some(ExerciseStyle::American),
+        Some(SettlementType::Physical),
+        None,
         UnixNanos::default(),
         UnixNanos::default(),
     )
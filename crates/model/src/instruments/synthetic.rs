@@ -188,19 +188,18 @@ impl SyntheticInstrument {
 
     /// Calculates the price of the synthetic instrument based on the given component input prices
     /// provided as a map.
-    #[allow(dead_code)]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any component input price is missing from `inputs`.
     pub fn calculate_from_map(&mut self, inputs: &HashMap<String, f64>) -> anyhow::Result<Price> {
-        let mut input_values = Vec::new();
+        let mut input_values = Vec::with_capacity(self.variables.len());
 
         for variable in &self.variables {
-            if let Some(&value) = inputs.get(variable) {
-                input_values.push(value);
-                self.context
-                    .set_value(variable.clone(), Value::Float(value))
-                    .expect("TODO: Unable to set value");
-            } else {
-                panic!("Missing price for component: {variable}");
-            }
+            let value = inputs
+                .get(variable)
+                .ok_or_else(|| anyhow::anyhow!("Missing price for component: {variable}"))?;
+            input_values.push(*value);
         }
 
         self.calculate(&input_values)
@@ -267,6 +266,19 @@ mod tests {
         );
     }
 
+    #[rstest]
+    fn test_calculate_from_map_with_missing_input() {
+        let mut synth = SyntheticInstrument::default();
+        let mut inputs = HashMap::new();
+        inputs.insert("BTC.BINANCE".to_string(), 100.0);
+        let result = synth.calculate_from_map(&inputs);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Missing price for component: LTC.BINANCE"
+        );
+    }
+
     #[rstest]
     fn test_calculate() {
         let mut synth = SyntheticInstrument::default();
@@ -0,0 +1,266 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Bounded history of amendments to the quantity and prices of an order.
+
+use std::fmt::Display;
+
+use nautilus_core::{UnixNanos, UUID4};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, Display as StrumDisplay, EnumString};
+
+use super::any::OrderAny;
+use crate::{
+    events::OrderUpdated,
+    types::{Price, Quantity},
+};
+
+/// The maximum number of amendments retained in the history of an order.
+pub const ORDER_AMENDMENTS_MAX: usize = 32;
+
+/// The reason for an amendment to an order.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    AsRefStr,
+    StrumDisplay,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderAmendmentReason {
+    /// The order was modified by a modify order command.
+    Modified,
+    /// The order was updated to the venue state during reconciliation.
+    Reconciliation,
+}
+
+/// Represents an amendment to the quantity and/or prices of an order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrderAmendment {
+    /// The order quantity prior to the amendment.
+    pub previous_quantity: Quantity,
+    /// The order quantity after the amendment.
+    pub quantity: Quantity,
+    /// The order price prior to the amendment (LIMIT).
+    pub previous_price: Option<Price>,
+    /// The order price after the amendment (LIMIT).
+    pub price: Option<Price>,
+    /// The order trigger price prior to the amendment (STOP).
+    pub previous_trigger_price: Option<Price>,
+    /// The order trigger price after the amendment (STOP).
+    pub trigger_price: Option<Price>,
+    /// The reason for the amendment.
+    pub reason: OrderAmendmentReason,
+    /// The event ID of the `OrderUpdated` event which amended the order.
+    pub event_id: UUID4,
+    /// UNIX timestamp (nanoseconds) when the amendment occurred.
+    pub ts_event: UnixNanos,
+}
+
+impl OrderAmendment {
+    /// Creates a new [`OrderAmendment`] instance for the `event` amending the `order`, prior to
+    /// the event being applied.
+    #[must_use]
+    pub fn new(order: &OrderAny, event: &OrderUpdated) -> Self {
+        let reason = if event.reconciliation == 0 {
+            OrderAmendmentReason::Modified
+        } else {
+            OrderAmendmentReason::Reconciliation
+        };
+        Self {
+            previous_quantity: order.quantity(),
+            quantity: event.quantity,
+            previous_price: order.price(),
+            price: event.price.or(order.price()),
+            previous_trigger_price: order.trigger_price(),
+            trigger_price: event.trigger_price.or(order.trigger_price()),
+            reason,
+            event_id: event.event_id,
+            ts_event: event.ts_event,
+        }
+    }
+
+    /// Returns the change in the order quantity (negative if reduced).
+    #[must_use]
+    pub fn quantity_change(&self) -> Decimal {
+        self.quantity.as_decimal() - self.previous_quantity.as_decimal()
+    }
+
+    /// Returns the change in the order price, or `None` if the order has no price.
+    #[must_use]
+    pub fn price_change(&self) -> Option<Decimal> {
+        price_change(self.previous_price, self.price)
+    }
+
+    /// Returns the change in the order trigger price, or `None` if the order has no trigger price.
+    #[must_use]
+    pub fn trigger_price_change(&self) -> Option<Decimal> {
+        price_change(self.previous_trigger_price, self.trigger_price)
+    }
+}
+
+fn price_change(previous: Option<Price>, current: Option<Price>) -> Option<Decimal> {
+    match (previous, current) {
+        (Some(previous), Some(current)) => Some(current.as_decimal() - previous.as_decimal()),
+        _ => None,
+    }
+}
+
+impl Display for OrderAmendment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let fmt_price = |price: Option<Price>| price.map_or("None".to_string(), |p| p.to_string());
+        write!(
+            f,
+            "{}(quantity={}->{}, price={}->{}, trigger_price={}->{}, reason={}, ts_event={})",
+            stringify!(OrderAmendment),
+            self.previous_quantity,
+            self.quantity,
+            fmt_price(self.previous_price),
+            fmt_price(self.price),
+            fmt_price(self.previous_trigger_price),
+            fmt_price(self.trigger_price),
+            self.reason,
+            self.ts_event,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::{
+        enums::{OrderSide, OrderType},
+        events::OrderEventAny,
+        orders::{stubs::TestOrderStubs, OrderTestBuilder},
+    };
+
+    fn accepted_limit_order() -> OrderAny {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id("AUD/USD.SIM".into())
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        TestOrderStubs::make_accepted_order(&order)
+    }
+
+    fn updated(
+        order: &OrderAny,
+        quantity: Quantity,
+        price: Price,
+        reconciliation: bool,
+        ts_event: u64,
+    ) -> OrderEventAny {
+        OrderEventAny::Updated(OrderUpdated::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            quantity,
+            UUID4::new(),
+            ts_event.into(),
+            ts_event.into(),
+            reconciliation,
+            None,
+            None,
+            Some(price),
+            None,
+        ))
+    }
+
+    #[rstest]
+    fn test_amendments_recorded_on_update() {
+        let mut order = accepted_limit_order();
+        let event = updated(
+            &order,
+            Quantity::from(50_000),
+            Price::from("1.00010"),
+            false,
+            1,
+        );
+
+        order.apply(event).unwrap();
+
+        let amendments = order.amendments();
+        assert_eq!(amendments.len(), 1);
+        assert_eq!(order.amendment_count(), 1);
+        let amendment = amendments[0];
+        assert_eq!(amendment.previous_price, Some(Price::from("1.00000")));
+        assert_eq!(amendment.price, Some(Price::from("1.00010")));
+        assert_eq!(amendment.price_change(), Some(dec!(0.00010)));
+        assert_eq!(amendment.quantity_change(), dec!(-50_000));
+        assert_eq!(amendment.trigger_price_change(), None);
+        assert_eq!(amendment.reason, OrderAmendmentReason::Modified);
+        assert_eq!(amendment.ts_event, UnixNanos::from(1));
+        assert_eq!(
+            amendment.to_string(),
+            "OrderAmendment(quantity=100000->50000, price=1.00000->1.00010, \
+            trigger_price=None->None, reason=MODIFIED, ts_event=1)"
+        );
+    }
+
+    #[rstest]
+    fn test_amendments_bounded() {
+        let mut order = accepted_limit_order();
+        let total = ORDER_AMENDMENTS_MAX + 3;
+        for i in 1..=total {
+            let price = Price::new(1.0 + i as f64 * 0.0001, 5);
+            let event = updated(&order, order.quantity(), price, i == total, i as u64);
+            order.apply(event).unwrap();
+        }
+
+        let amendments = order.amendments();
+        assert_eq!(amendments.len(), ORDER_AMENDMENTS_MAX);
+        assert_eq!(order.amendment_count(), total as u64);
+        assert_eq!(amendments[0].ts_event, UnixNanos::from(4));
+        assert_eq!(
+            amendments[ORDER_AMENDMENTS_MAX - 1].reason,
+            OrderAmendmentReason::Reconciliation
+        );
+    }
+
+    #[rstest]
+    fn test_amendments_serialized_with_order() {
+        let mut order = accepted_limit_order();
+        let event = updated(
+            &order,
+            Quantity::from(100_000),
+            Price::from("0.99990"),
+            false,
+            1,
+        );
+        order.apply(event).unwrap();
+
+        let json = serde_json::to_string(&order).unwrap();
+        let deserialized: OrderAny = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized.amendments(), order.amendments());
+        assert_eq!(deserialized.amendment_count(), 1);
+    }
+}
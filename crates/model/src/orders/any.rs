@@ -22,6 +22,7 @@ use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
 use super::{
+    amendment::OrderAmendment,
     base::{Order, OrderError},
     limit::LimitOrder,
     limit_if_touched::LimitIfTouchedOrder,
@@ -36,7 +37,7 @@ use super::{
 use crate::{
     enums::{
         ContingencyType, LiquiditySide, OrderSide, OrderSideSpecified, OrderStatus, OrderType,
-        PositionSide, TimeInForce, TrailingOffsetType, TriggerType,
+        PegPriceType, PositionSide, TimeInForce, TrailingOffsetType, TriggerType,
     },
     events::OrderEventAny,
     identifiers::{
@@ -68,6 +69,11 @@ impl Default for OrderAny {
 impl OrderAny {
     /// Applies the given `event` to the order.
     pub fn apply(&mut self, event: OrderEventAny) -> Result<(), OrderError> {
+        let amendment = match &event {
+            OrderEventAny::Updated(updated) => Some(OrderAmendment::new(self, updated)),
+            _ => None,
+        };
+
         match self {
             OrderAny::Limit(order) => order.apply(event),
             OrderAny::LimitIfTouched(order) => order.apply(event),
@@ -78,7 +84,13 @@ impl OrderAny {
             OrderAny::StopMarket(order) => order.apply(event),
             OrderAny::TrailingStopLimit(order) => order.apply(event),
             OrderAny::TrailingStopMarket(order) => order.apply(event),
+        }?;
+
+        if let Some(amendment) = amendment {
+            self.record_amendment(amendment);
         }
+
+        Ok(())
     }
 
     #[must_use]
@@ -404,6 +416,14 @@ impl OrderAny {
         }
     }
 
+    /// Returns whether the order is a child order spawned from a primary order, in which case
+    /// its `exec_spawn_id` is the client order ID of the primary order.
+    #[must_use]
+    pub fn is_spawned(&self) -> bool {
+        self.exec_spawn_id()
+            .is_some_and(|exec_spawn_id| exec_spawn_id != self.client_order_id())
+    }
+
     #[must_use]
     pub fn order_side(&self) -> OrderSide {
         match self {
@@ -569,6 +589,28 @@ impl OrderAny {
         }
     }
 
+    #[must_use]
+    pub fn metadata(&self) -> Option<IndexMap<Ustr, Ustr>> {
+        match self {
+            Self::Limit(order) => order.metadata.clone(),
+            Self::LimitIfTouched(order) => order.metadata.clone(),
+            Self::Market(order) => order.metadata.clone(),
+            Self::MarketIfTouched(order) => order.metadata.clone(),
+            Self::MarketToLimit(order) => order.metadata.clone(),
+            Self::StopLimit(order) => order.metadata.clone(),
+            Self::StopMarket(order) => order.metadata.clone(),
+            Self::TrailingStopLimit(order) => order.metadata.clone(),
+            Self::TrailingStopMarket(order) => order.metadata.clone(),
+        }
+    }
+
+    /// Returns the value of the metadata `key` for the order (if found).
+    #[must_use]
+    pub fn metadata_value(&self, key: &str) -> Option<Ustr> {
+        self.metadata()
+            .and_then(|metadata| metadata.get(&Ustr::from(key)).copied())
+    }
+
     #[must_use]
     pub fn emulation_trigger(&self) -> Option<TriggerType> {
         match self {
@@ -898,6 +940,61 @@ impl OrderAny {
         }
     }
 
+    /// Returns the price which activates the trailing of a trailing stop order.
+    #[must_use]
+    pub fn activation_price(&self) -> Option<Price> {
+        match self {
+            Self::Limit(_) => None,
+            Self::LimitIfTouched(_) => None,
+            Self::Market(_) => None,
+            Self::MarketIfTouched(_) => None,
+            Self::MarketToLimit(_) => None,
+            Self::StopLimit(_) => None,
+            Self::StopMarket(_) => None,
+            Self::TrailingStopLimit(order) => order.activation_price,
+            Self::TrailingStopMarket(order) => order.activation_price,
+        }
+    }
+
+    /// Returns whether a trailing stop order is trailing, being either without an activation
+    /// price or activated by the market reaching it.
+    #[must_use]
+    pub fn is_activated(&self) -> Option<bool> {
+        match self {
+            Self::Limit(_) => None,
+            Self::LimitIfTouched(_) => None,
+            Self::Market(_) => None,
+            Self::MarketIfTouched(_) => None,
+            Self::MarketToLimit(_) => None,
+            Self::StopLimit(_) => None,
+            Self::StopMarket(_) => None,
+            Self::TrailingStopLimit(order) => {
+                Some(order.activation_price.is_none() || order.is_activated)
+            }
+            Self::TrailingStopMarket(order) => {
+                Some(order.activation_price.is_none() || order.is_activated)
+            }
+        }
+    }
+
+    /// Returns the reference price a pegged limit order is repriced from.
+    #[must_use]
+    pub fn peg_price_type(&self) -> Option<PegPriceType> {
+        match self {
+            Self::Limit(order) => order.peg_price_type,
+            _ => None,
+        }
+    }
+
+    /// Returns the offset of a pegged limit order from its reference price.
+    #[must_use]
+    pub fn peg_offset(&self) -> Option<Decimal> {
+        match self {
+            Self::Limit(order) => order.peg_offset,
+            _ => None,
+        }
+    }
+
     #[must_use]
     pub fn would_reduce_only(&self, side: PositionSide, position_qty: Quantity) -> bool {
         match self {
@@ -1065,6 +1162,81 @@ impl OrderAny {
         };
     }
 
+    /// Marks a trailing stop order as activated, having no effect on other order types.
+    pub fn set_activated(&mut self) {
+        match self {
+            Self::TrailingStopLimit(order) => order.is_activated = true,
+            Self::TrailingStopMarket(order) => order.is_activated = true,
+            _ => {}
+        }
+    }
+
+    /// Returns the bounded history of amendments to the order, oldest first.
+    #[must_use]
+    pub fn amendments(&self) -> Vec<&OrderAmendment> {
+        match self {
+            Self::Limit(order) => order.amendments.iter().collect(),
+            Self::LimitIfTouched(order) => order.amendments.iter().collect(),
+            Self::Market(order) => order.amendments.iter().collect(),
+            Self::MarketIfTouched(order) => order.amendments.iter().collect(),
+            Self::MarketToLimit(order) => order.amendments.iter().collect(),
+            Self::StopLimit(order) => order.amendments.iter().collect(),
+            Self::StopMarket(order) => order.amendments.iter().collect(),
+            Self::TrailingStopLimit(order) => order.amendments.iter().collect(),
+            Self::TrailingStopMarket(order) => order.amendments.iter().collect(),
+        }
+    }
+
+    /// Returns the total number of amendments to the order, including those no longer retained
+    /// in the amendment history.
+    #[must_use]
+    pub fn amendment_count(&self) -> u64 {
+        match self {
+            Self::Limit(order) => order.amendment_count,
+            Self::LimitIfTouched(order) => order.amendment_count,
+            Self::Market(order) => order.amendment_count,
+            Self::MarketIfTouched(order) => order.amendment_count,
+            Self::MarketToLimit(order) => order.amendment_count,
+            Self::StopLimit(order) => order.amendment_count,
+            Self::StopMarket(order) => order.amendment_count,
+            Self::TrailingStopLimit(order) => order.amendment_count,
+            Self::TrailingStopMarket(order) => order.amendment_count,
+        }
+    }
+
+    fn record_amendment(&mut self, amendment: OrderAmendment) {
+        match self {
+            Self::Limit(order) => order.record_amendment(amendment),
+            Self::LimitIfTouched(order) => order.record_amendment(amendment),
+            Self::Market(order) => order.record_amendment(amendment),
+            Self::MarketIfTouched(order) => order.record_amendment(amendment),
+            Self::MarketToLimit(order) => order.record_amendment(amendment),
+            Self::StopLimit(order) => order.record_amendment(amendment),
+            Self::StopMarket(order) => order.record_amendment(amendment),
+            Self::TrailingStopLimit(order) => order.record_amendment(amendment),
+            Self::TrailingStopMarket(order) => order.record_amendment(amendment),
+        }
+    }
+
+    /// Sets the structured metadata (key-value tags) of the order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order status is not `INITIALIZED`.
+    pub fn set_metadata(&mut self, metadata: IndexMap<Ustr, Ustr>) -> anyhow::Result<()> {
+        match self {
+            Self::Limit(order) => order.set_metadata(metadata),
+            Self::LimitIfTouched(order) => order.set_metadata(metadata),
+            Self::Market(order) => order.set_metadata(metadata),
+            Self::MarketIfTouched(order) => order.set_metadata(metadata),
+            Self::MarketToLimit(order) => order.set_metadata(metadata),
+            Self::StopLimit(order) => order.set_metadata(metadata),
+            Self::StopMarket(order) => order.set_metadata(metadata),
+            Self::TrailingStopLimit(order) => order.set_metadata(metadata),
+            Self::TrailingStopMarket(order) => order.set_metadata(metadata),
+        }
+    }
+
     pub fn set_is_quote_quantity(&mut self, is_quote_quantity: bool) {
         match self {
             Self::Limit(order) => order.is_quote_quantity = is_quote_quantity,
@@ -1280,6 +1452,14 @@ impl PassiveOrderAny {
         }
     }
 
+    #[must_use]
+    pub fn time_in_force(&self) -> TimeInForce {
+        match self {
+            Self::Limit(order) => order.time_in_force(),
+            Self::Stop(order) => order.time_in_force(),
+        }
+    }
+
     #[must_use]
     pub fn expire_time(&self) -> Option<UnixNanos> {
         match self {
@@ -1383,6 +1563,16 @@ impl LimitOrderAny {
         }
     }
 
+    #[must_use]
+    pub fn time_in_force(&self) -> TimeInForce {
+        match self {
+            Self::Limit(order) => order.time_in_force,
+            Self::MarketToLimit(order) => order.time_in_force,
+            Self::StopLimit(order) => order.time_in_force,
+            Self::TrailingStopLimit(order) => order.time_in_force,
+        }
+    }
+
     #[must_use]
     pub fn expire_time(&self) -> Option<UnixNanos> {
         match self {
@@ -1462,6 +1652,24 @@ impl StopOrderAny {
         }
     }
 
+    /// Returns whether the stop price is live, being `false` for trailing stop orders awaiting
+    /// activation.
+    #[must_use]
+    pub fn is_activated(&self) -> bool {
+        match self {
+            Self::LimitIfTouched(_) => true,
+            Self::MarketIfTouched(_) => true,
+            Self::StopLimit(_) => true,
+            Self::StopMarket(_) => true,
+            Self::TrailingStopLimit(order) => {
+                order.activation_price.is_none() || order.is_activated
+            }
+            Self::TrailingStopMarket(order) => {
+                order.activation_price.is_none() || order.is_activated
+            }
+        }
+    }
+
     #[must_use]
     pub fn is_closed(&self) -> bool {
         match self {
@@ -1498,6 +1706,18 @@ impl StopOrderAny {
         }
     }
 
+    #[must_use]
+    pub fn time_in_force(&self) -> TimeInForce {
+        match self {
+            Self::LimitIfTouched(order) => order.time_in_force,
+            Self::MarketIfTouched(order) => order.time_in_force,
+            Self::StopLimit(order) => order.time_in_force,
+            Self::StopMarket(order) => order.time_in_force,
+            Self::TrailingStopLimit(order) => order.time_in_force,
+            Self::TrailingStopMarket(order) => order.time_in_force,
+        }
+    }
+
     #[must_use]
     pub fn expire_time(&self) -> Option<UnixNanos> {
         match self {
@@ -13,13 +13,18 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::collections::VecDeque;
+
 use indexmap::IndexMap;
-use nautilus_core::{UnixNanos, UUID4};
+use nautilus_core::{correctness::FAILED, UnixNanos, UUID4};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
-use super::any::OrderAny;
+use super::{
+    amendment::{OrderAmendment, ORDER_AMENDMENTS_MAX},
+    any::OrderAny,
+};
 use crate::{
     enums::{
         ContingencyType, LiquiditySide, OrderSide, OrderStatus, OrderType, PositionSide,
@@ -27,9 +32,9 @@ use crate::{
     },
     events::{
         OrderAccepted, OrderCancelRejected, OrderCanceled, OrderDenied, OrderEmulated,
-        OrderEventAny, OrderExpired, OrderFilled, OrderInitialized, OrderModifyRejected,
-        OrderPendingCancel, OrderPendingUpdate, OrderRejected, OrderReleased, OrderSubmitted,
-        OrderTriggered, OrderUpdated,
+        OrderEventAny, OrderEventType, OrderExpired, OrderFilled, OrderInitialized,
+        OrderModifyRejected, OrderPendingCancel, OrderPendingUpdate, OrderRejected, OrderReleased,
+        OrderSubmitted, OrderTriggered, OrderUpdated,
     },
     identifiers::{
         AccountId, ClientOrderId, ExecAlgorithmId, InstrumentId, OrderListId, PositionId,
@@ -88,75 +93,101 @@ pub fn str_indexmap_to_ustr(h: IndexMap<String, String>) -> IndexMap<Ustr, Ustr>
         .collect()
 }
 
+/// Checks the `display_qty` of an order is not greater than its `quantity`.
+///
+/// # Errors
+///
+/// Returns an error if the check fails.
+pub fn check_display_qty(display_qty: Option<Quantity>, quantity: Quantity) -> anyhow::Result<()> {
+    if let Some(display_qty) = display_qty {
+        if display_qty > quantity {
+            anyhow::bail!(
+                "{FAILED}: `display_qty` {display_qty} was greater than `quantity` {quantity}"
+            )
+        }
+    }
+    Ok(())
+}
+
 impl OrderStatus {
+    /// Returns the status an order with this status transitions to on an event of the given
+    /// `event_type`, or `None` if the order status FSM has no such transition.
     #[rustfmt::skip]
-    pub fn transition(&mut self, event: &OrderEventAny) -> Result<Self, OrderError> {
-        let new_state = match (self, event) {
-            (Self::Initialized, OrderEventAny::Denied(_)) => Self::Denied,
-            (Self::Initialized, OrderEventAny::Emulated(_)) => Self::Emulated,  // Emulated orders
-            (Self::Initialized, OrderEventAny::Released(_)) => Self::Released,  // Emulated orders
-            (Self::Initialized, OrderEventAny::Submitted(_)) => Self::Submitted,
-            (Self::Initialized, OrderEventAny::Rejected(_)) => Self::Rejected,  // External orders
-            (Self::Initialized, OrderEventAny::Accepted(_)) => Self::Accepted,  // External orders
-            (Self::Initialized, OrderEventAny::Canceled(_)) => Self::Canceled,  // External orders
-            (Self::Initialized, OrderEventAny::Expired(_)) => Self::Expired,  // External orders
-            (Self::Initialized, OrderEventAny::Triggered(_)) => Self::Triggered, // External orders
-            (Self::Emulated, OrderEventAny::Canceled(_)) => Self::Canceled,  // Emulated orders
-            (Self::Emulated, OrderEventAny::Expired(_)) => Self::Expired,  // Emulated orders
-            (Self::Emulated, OrderEventAny::Released(_)) => Self::Released,  // Emulated orders
-            (Self::Released, OrderEventAny::Submitted(_)) => Self::Submitted,  // Emulated orders
-            (Self::Released, OrderEventAny::Denied(_)) => Self::Denied,  // Emulated orders
-            (Self::Released, OrderEventAny::Canceled(_)) => Self::Canceled,  // Execution algo
-            (Self::Submitted, OrderEventAny::PendingUpdate(_)) => Self::PendingUpdate,
-            (Self::Submitted, OrderEventAny::PendingCancel(_)) => Self::PendingCancel,
-            (Self::Submitted, OrderEventAny::Rejected(_)) => Self::Rejected,
-            (Self::Submitted, OrderEventAny::Canceled(_)) => Self::Canceled,  // FOK and IOC cases
-            (Self::Submitted, OrderEventAny::Accepted(_)) => Self::Accepted,
-            (Self::Submitted, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
-            (Self::Submitted, OrderEventAny::Filled(_)) => Self::Filled,
-            (Self::Accepted, OrderEventAny::Rejected(_)) => Self::Rejected,  // StopLimit order
-            (Self::Accepted, OrderEventAny::PendingUpdate(_)) => Self::PendingUpdate,
-            (Self::Accepted, OrderEventAny::PendingCancel(_)) => Self::PendingCancel,
-            (Self::Accepted, OrderEventAny::Canceled(_)) => Self::Canceled,
-            (Self::Accepted, OrderEventAny::Triggered(_)) => Self::Triggered,
-            (Self::Accepted, OrderEventAny::Expired(_)) => Self::Expired,
-            (Self::Accepted, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
-            (Self::Accepted, OrderEventAny::Filled(_)) => Self::Filled,
-            (Self::Accepted, OrderEventAny::Updated(_)) => Self::Accepted,  // Updates should preserve state
-            (Self::Canceled, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,  // Real world possibility
-            (Self::Canceled, OrderEventAny::Filled(_)) => Self::Filled,  // Real world possibility
-            (Self::PendingUpdate, OrderEventAny::Rejected(_)) => Self::Rejected,
-            (Self::PendingUpdate, OrderEventAny::Accepted(_)) => Self::Accepted,
-            (Self::PendingUpdate, OrderEventAny::Canceled(_)) => Self::Canceled,
-            (Self::PendingUpdate, OrderEventAny::Expired(_)) => Self::Expired,
-            (Self::PendingUpdate, OrderEventAny::Triggered(_)) => Self::Triggered,
-            (Self::PendingUpdate, OrderEventAny::PendingUpdate(_)) => Self::PendingUpdate,  // Allow multiple requests
-            (Self::PendingUpdate, OrderEventAny::PendingCancel(_)) => Self::PendingCancel,
-            (Self::PendingUpdate, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
-            (Self::PendingUpdate, OrderEventAny::Filled(_)) => Self::Filled,
-            (Self::PendingCancel, OrderEventAny::Rejected(_)) => Self::Rejected,
-            (Self::PendingCancel, OrderEventAny::PendingCancel(_)) => Self::PendingCancel,  // Allow multiple requests
-            (Self::PendingCancel, OrderEventAny::Canceled(_)) => Self::Canceled,
-            (Self::PendingCancel, OrderEventAny::Expired(_)) => Self::Expired,
-            (Self::PendingCancel, OrderEventAny::Accepted(_)) => Self::Accepted,  // Allow failed cancel requests
-            (Self::PendingCancel, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
-            (Self::PendingCancel, OrderEventAny::Filled(_)) => Self::Filled,
-            (Self::Triggered, OrderEventAny::Rejected(_)) => Self::Rejected,
-            (Self::Triggered, OrderEventAny::PendingUpdate(_)) => Self::PendingUpdate,
-            (Self::Triggered, OrderEventAny::PendingCancel(_)) => Self::PendingCancel,
-            (Self::Triggered, OrderEventAny::Canceled(_)) => Self::Canceled,
-            (Self::Triggered, OrderEventAny::Expired(_)) => Self::Expired,
-            (Self::Triggered, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
-            (Self::Triggered, OrderEventAny::Filled(_)) => Self::Filled,
-            (Self::PartiallyFilled, OrderEventAny::PendingUpdate(_)) => Self::PendingUpdate,
-            (Self::PartiallyFilled, OrderEventAny::PendingCancel(_)) => Self::PendingCancel,
-            (Self::PartiallyFilled, OrderEventAny::Canceled(_)) => Self::Canceled,
-            (Self::PartiallyFilled, OrderEventAny::Expired(_)) => Self::Expired,
-            (Self::PartiallyFilled, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
-            (Self::PartiallyFilled, OrderEventAny::Filled(_)) => Self::Filled,
-            _ => return Err(OrderError::InvalidStateTransition),
+    #[must_use]
+    pub fn next_status(&self, event_type: OrderEventType) -> Option<Self> {
+        let new_state = match (self, event_type) {
+            (Self::Initialized, OrderEventType::Denied) => Self::Denied,
+            (Self::Initialized, OrderEventType::Emulated) => Self::Emulated,  // Emulated orders
+            (Self::Initialized, OrderEventType::Released) => Self::Released,  // Emulated orders
+            (Self::Initialized, OrderEventType::Submitted) => Self::Submitted,
+            (Self::Initialized, OrderEventType::Rejected) => Self::Rejected,  // External orders
+            (Self::Initialized, OrderEventType::Accepted) => Self::Accepted,  // External orders
+            (Self::Initialized, OrderEventType::Canceled) => Self::Canceled,  // External orders
+            (Self::Initialized, OrderEventType::Expired) => Self::Expired,  // External orders
+            (Self::Initialized, OrderEventType::Triggered) => Self::Triggered, // External orders
+            (Self::Initialized, OrderEventType::Updated) => Self::Initialized,  // Emulated orders
+            (Self::Emulated, OrderEventType::Canceled) => Self::Canceled,  // Emulated orders
+            (Self::Emulated, OrderEventType::Expired) => Self::Expired,  // Emulated orders
+            (Self::Emulated, OrderEventType::Released) => Self::Released,  // Emulated orders
+            (Self::Emulated, OrderEventType::Updated) => Self::Emulated,  // Emulated orders
+            (Self::Released, OrderEventType::Submitted) => Self::Submitted,  // Emulated orders
+            (Self::Released, OrderEventType::Denied) => Self::Denied,  // Emulated orders
+            (Self::Released, OrderEventType::Canceled) => Self::Canceled,  // Execution algo
+            (Self::Submitted, OrderEventType::PendingUpdate) => Self::PendingUpdate,
+            (Self::Submitted, OrderEventType::PendingCancel) => Self::PendingCancel,
+            (Self::Submitted, OrderEventType::Rejected) => Self::Rejected,
+            (Self::Submitted, OrderEventType::Canceled) => Self::Canceled,  // FOK and IOC cases
+            (Self::Submitted, OrderEventType::Accepted) => Self::Accepted,
+            (Self::Submitted, OrderEventType::PartiallyFilled) => Self::PartiallyFilled,
+            (Self::Submitted, OrderEventType::Filled) => Self::Filled,
+            (Self::Accepted, OrderEventType::Rejected) => Self::Rejected,  // StopLimit order
+            (Self::Accepted, OrderEventType::PendingUpdate) => Self::PendingUpdate,
+            (Self::Accepted, OrderEventType::PendingCancel) => Self::PendingCancel,
+            (Self::Accepted, OrderEventType::Canceled) => Self::Canceled,
+            (Self::Accepted, OrderEventType::Triggered) => Self::Triggered,
+            (Self::Accepted, OrderEventType::Expired) => Self::Expired,
+            (Self::Accepted, OrderEventType::PartiallyFilled) => Self::PartiallyFilled,
+            (Self::Accepted, OrderEventType::Filled) => Self::Filled,
+            (Self::Accepted, OrderEventType::Updated) => Self::Accepted,  // Updates should preserve state
+            (Self::Canceled, OrderEventType::PartiallyFilled) => Self::PartiallyFilled,  // Real world possibility
+            (Self::Canceled, OrderEventType::Filled) => Self::Filled,  // Real world possibility
+            (Self::PendingUpdate, OrderEventType::Rejected) => Self::Rejected,
+            (Self::PendingUpdate, OrderEventType::Accepted) => Self::Accepted,
+            (Self::PendingUpdate, OrderEventType::Canceled) => Self::Canceled,
+            (Self::PendingUpdate, OrderEventType::Expired) => Self::Expired,
+            (Self::PendingUpdate, OrderEventType::Triggered) => Self::Triggered,
+            (Self::PendingUpdate, OrderEventType::PendingUpdate) => Self::PendingUpdate,  // Allow multiple requests
+            (Self::PendingUpdate, OrderEventType::PendingCancel) => Self::PendingCancel,
+            (Self::PendingUpdate, OrderEventType::PartiallyFilled) => Self::PartiallyFilled,
+            (Self::PendingUpdate, OrderEventType::Filled) => Self::Filled,
+            (Self::PendingCancel, OrderEventType::Rejected) => Self::Rejected,
+            (Self::PendingCancel, OrderEventType::PendingCancel) => Self::PendingCancel,  // Allow multiple requests
+            (Self::PendingCancel, OrderEventType::Canceled) => Self::Canceled,
+            (Self::PendingCancel, OrderEventType::Expired) => Self::Expired,
+            (Self::PendingCancel, OrderEventType::Accepted) => Self::Accepted,  // Allow failed cancel requests
+            (Self::PendingCancel, OrderEventType::PartiallyFilled) => Self::PartiallyFilled,
+            (Self::PendingCancel, OrderEventType::Filled) => Self::Filled,
+            (Self::Triggered, OrderEventType::Rejected) => Self::Rejected,
+            (Self::Triggered, OrderEventType::PendingUpdate) => Self::PendingUpdate,
+            (Self::Triggered, OrderEventType::PendingCancel) => Self::PendingCancel,
+            (Self::Triggered, OrderEventType::Canceled) => Self::Canceled,
+            (Self::Triggered, OrderEventType::Expired) => Self::Expired,
+            (Self::Triggered, OrderEventType::PartiallyFilled) => Self::PartiallyFilled,
+            (Self::Triggered, OrderEventType::Filled) => Self::Filled,
+            (Self::PartiallyFilled, OrderEventType::PendingUpdate) => Self::PendingUpdate,
+            (Self::PartiallyFilled, OrderEventType::PendingCancel) => Self::PendingCancel,
+            (Self::PartiallyFilled, OrderEventType::Canceled) => Self::Canceled,
+            (Self::PartiallyFilled, OrderEventType::Expired) => Self::Expired,
+            (Self::PartiallyFilled, OrderEventType::PartiallyFilled) => Self::PartiallyFilled,
+            (Self::PartiallyFilled, OrderEventType::Filled) => Self::Filled,
+            _ => return None,
         };
-        Ok(new_state)
+        Some(new_state)
+    }
+
+    pub fn transition(&mut self, event: &OrderEventAny) -> Result<Self, OrderError> {
+        self.next_status(event.event_type())
+            .ok_or(OrderError::InvalidStateTransition)
     }
 }
 
@@ -199,6 +230,7 @@ pub trait Order: 'static + Send {
     fn exec_algorithm_params(&self) -> Option<&IndexMap<Ustr, Ustr>>;
     fn exec_spawn_id(&self) -> Option<ClientOrderId>;
     fn tags(&self) -> Option<&[Ustr]>;
+    fn metadata(&self) -> Option<&IndexMap<Ustr, Ustr>>;
     fn filled_qty(&self) -> Quantity;
     fn leaves_qty(&self) -> Quantity;
     fn avg_px(&self) -> Option<f64>;
@@ -388,6 +420,7 @@ where
             exec_algorithm_params: order.exec_algorithm_params().map(|x| x.to_owned()),
             exec_spawn_id: order.exec_spawn_id(),
             tags: order.tags().map(|x| x.to_vec()),
+            metadata: order.metadata().cloned(),
             event_id: order.init_id(),
             ts_event: order.ts_init(),
             ts_init: order.ts_init(),
@@ -428,6 +461,12 @@ pub struct OrderCore {
     pub exec_algorithm_params: Option<IndexMap<Ustr, Ustr>>,
     pub exec_spawn_id: Option<ClientOrderId>,
     pub tags: Option<Vec<Ustr>>,
+    #[serde(default)]
+    pub metadata: Option<IndexMap<Ustr, Ustr>>,
+    #[serde(default)]
+    pub amendments: VecDeque<OrderAmendment>,
+    #[serde(default)]
+    pub amendment_count: u64,
     pub filled_qty: Quantity,
     pub leaves_qty: Quantity,
     pub avg_px: Option<f64>,
@@ -474,6 +513,9 @@ impl OrderCore {
             exec_algorithm_params: init.exec_algorithm_params,
             exec_spawn_id: init.exec_spawn_id,
             tags: init.tags,
+            metadata: init.metadata,
+            amendments: VecDeque::new(),
+            amendment_count: 0,
             filled_qty: Quantity::zero(init.quantity.precision),
             leaves_qty: init.quantity,
             avg_px: None,
@@ -679,6 +721,43 @@ impl OrderCore {
     pub fn init_event(&self) -> Option<OrderEventAny> {
         self.events.first().cloned()
     }
+
+    /// Sets the structured metadata (key-value tags) of the order.
+    ///
+    /// The metadata is recorded on the `OrderInitialized` event, so can only be set prior to
+    /// the order being submitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order status is not `INITIALIZED`.
+    pub fn set_metadata(&mut self, metadata: IndexMap<Ustr, Ustr>) -> anyhow::Result<()> {
+        if self.status != OrderStatus::Initialized {
+            anyhow::bail!(
+                "Cannot set metadata of order {} with status {}",
+                self.client_order_id,
+                self.status
+            );
+        }
+        self.replace_metadata(Some(metadata));
+        Ok(())
+    }
+
+    pub(crate) fn replace_metadata(&mut self, metadata: Option<IndexMap<Ustr, Ustr>>) {
+        if let Some(OrderEventAny::Initialized(init)) = self.events.first_mut() {
+            init.metadata.clone_from(&metadata);
+        }
+        self.metadata = metadata;
+    }
+
+    /// Records the `amendment` in the order amendment history, discarding the oldest amendment
+    /// once the history holds [`ORDER_AMENDMENTS_MAX`] amendments.
+    pub(crate) fn record_amendment(&mut self, amendment: OrderAmendment) {
+        if self.amendments.len() == ORDER_AMENDMENTS_MAX {
+            self.amendments.pop_front();
+        }
+        self.amendments.push_back(amendment);
+        self.amendment_count += 1;
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -808,4 +887,36 @@ mod tests {
         assert_eq!(order.commission(&Currency::USD()), None);
         assert_eq!(order.commissions(), IndexMap::new());
     }
+
+    #[rstest]
+    fn test_set_metadata_recorded_on_initialized_event() {
+        let mut order: MarketOrder = OrderInitializedBuilder::default().build().unwrap().into();
+        let metadata = IndexMap::from([(Ustr::from("basket"), Ustr::from("alpha1"))]);
+
+        order.set_metadata(metadata.clone()).unwrap();
+
+        let OrderEventAny::Initialized(init) = order.init_event().unwrap() else {
+            panic!("Expected `OrderInitialized` event");
+        };
+        let rebuilt: MarketOrder = init.clone().into();
+        assert_eq!(order.metadata(), Some(&metadata));
+        assert_eq!(init.metadata, Some(metadata.clone()));
+        assert_eq!(rebuilt.metadata(), Some(&metadata));
+        assert_eq!(rebuilt.init_event(), order.init_event());
+    }
+
+    #[rstest]
+    fn test_set_metadata_when_submitted() {
+        let mut order: MarketOrder = OrderInitializedBuilder::default().build().unwrap().into();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        order.apply(OrderEventAny::Submitted(submitted)).unwrap();
+
+        let result = order.set_metadata(IndexMap::from([(
+            Ustr::from("basket"),
+            Ustr::from("alpha1"),
+        )]));
+
+        assert!(result.is_err());
+        assert_eq!(order.metadata(), None);
+    }
 }
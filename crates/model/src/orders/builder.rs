@@ -20,8 +20,8 @@ use ustr::Ustr;
 
 use crate::{
     enums::{
-        ContingencyType, LiquiditySide, OrderSide, OrderType, TimeInForce, TrailingOffsetType,
-        TriggerType,
+        ContingencyType, LiquiditySide, OrderSide, OrderType, PegPriceType, TimeInForce,
+        TrailingOffsetType, TriggerType,
     },
     identifiers::{
         ClientOrderId, ExecAlgorithmId, InstrumentId, OrderListId, StrategyId, TradeId, TraderId,
@@ -52,6 +52,9 @@ pub struct OrderTestBuilder {
     limit_offset: Option<Decimal>,
     trailing_offset: Option<Decimal>,
     trailing_offset_type: Option<TrailingOffsetType>,
+    activation_price: Option<Price>,
+    peg_price_type: Option<PegPriceType>,
+    peg_offset: Option<Decimal>,
     time_in_force: Option<TimeInForce>,
     expire_time: Option<UnixNanos>,
     reduce_only: Option<bool>,
@@ -93,6 +96,9 @@ impl OrderTestBuilder {
             limit_offset: None,
             trailing_offset: None,
             trailing_offset_type: None,
+            activation_price: None,
+            peg_price_type: None,
+            peg_offset: None,
             time_in_force: None,
             contingency_type: None,
             expire_time: None,
@@ -262,6 +268,36 @@ impl OrderTestBuilder {
             .unwrap_or(TrailingOffsetType::NoTrailingOffset)
     }
 
+    // ----------- ActivationPrice ----------
+    pub fn activation_price(&mut self, activation_price: Price) -> &mut Self {
+        self.activation_price = Some(activation_price);
+        self
+    }
+
+    fn get_activation_price(&self) -> Option<Price> {
+        self.activation_price
+    }
+
+    // ----------- PegPriceType ----------
+    pub fn peg_price_type(&mut self, peg_price_type: PegPriceType) -> &mut Self {
+        self.peg_price_type = Some(peg_price_type);
+        self
+    }
+
+    fn get_peg_price_type(&self) -> Option<PegPriceType> {
+        self.peg_price_type
+    }
+
+    // ----------- PegOffset ----------
+    pub fn peg_offset(&mut self, peg_offset: Decimal) -> &mut Self {
+        self.peg_offset = Some(peg_offset);
+        self
+    }
+
+    fn get_peg_offset(&self) -> Option<Decimal> {
+        self.peg_offset
+    }
+
     // ----------- TimeInForce ----------
     pub fn time_in_force(&mut self, time_in_force: TimeInForce) -> &mut Self {
         self.time_in_force = Some(time_in_force);
@@ -500,6 +536,8 @@ impl OrderTestBuilder {
                     self.get_side(),
                     self.get_quantity(),
                     self.get_price(),
+                    self.get_peg_price_type(),
+                    self.get_peg_offset(),
                     self.get_time_in_force(),
                     self.get_expire_time(),
                     self.get_post_only(),
@@ -669,6 +707,7 @@ impl OrderTestBuilder {
                     self.get_trigger_type(),
                     self.get_trailing_offset(),
                     self.get_trailing_offset_type(),
+                    self.get_activation_price(),
                     self.get_time_in_force(),
                     self.get_expire_time(),
                     self.get_reduce_only(),
@@ -702,6 +741,7 @@ impl OrderTestBuilder {
                     self.get_limit_offset(),
                     self.get_trailing_offset(),
                     self.get_trailing_offset_type(),
+                    self.get_activation_price(),
                     self.get_time_in_force(),
                     self.get_expire_time(),
                     self.get_post_only(),
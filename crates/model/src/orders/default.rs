@@ -39,6 +39,8 @@ impl Default for LimitOrder {
             OrderSide::Buy,
             Quantity::from(100_000),
             Price::from("1.00000"),
+            None,
+            None,
             TimeInForce::Gtc,
             None,
             false,
@@ -271,6 +273,7 @@ impl Default for TrailingStopLimitOrder {
             dec!(0.001),
             dec!(0.001),
             TrailingOffsetType::Price,
+            None,
             TimeInForce::Gtc,
             None,
             false,
@@ -307,6 +310,7 @@ impl Default for TrailingStopMarketOrder {
             TriggerType::BidAsk,
             dec!(0.001),
             TrailingOffsetType::Price,
+            None,
             TimeInForce::Gtc,
             None,
             false,
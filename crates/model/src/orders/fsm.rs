@@ -0,0 +1,114 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Export of the order status finite state machine (FSM) transition table.
+
+use std::fmt::Write;
+
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use crate::{enums::OrderStatus, events::OrderEventType};
+
+/// Represents a transition of the order status FSM.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OrderStatusTransition {
+    /// The order status the transition is from.
+    pub from: OrderStatus,
+    /// The type of event triggering the transition.
+    pub event: OrderEventType,
+    /// The order status the transition is to.
+    pub to: OrderStatus,
+}
+
+/// Returns all transitions of the order status FSM, ordered by status then event type.
+#[must_use]
+pub fn order_status_transitions() -> Vec<OrderStatusTransition> {
+    OrderStatus::iter()
+        .flat_map(|from| {
+            OrderEventType::iter().filter_map(move |event| {
+                from.next_status(event)
+                    .map(|to| OrderStatusTransition { from, event, to })
+            })
+        })
+        .collect()
+}
+
+/// Returns the types of event which are valid for an order with the given `status`.
+#[must_use]
+pub fn valid_event_types(status: OrderStatus) -> Vec<OrderEventType> {
+    OrderEventType::iter()
+        .filter(|event| status.next_status(*event).is_some())
+        .collect()
+}
+
+/// Returns the order status FSM in Graphviz DOT format, for rendering as a state diagram.
+#[must_use]
+pub fn order_status_transitions_dot() -> String {
+    let mut dot = String::from("digraph OrderStatus {\n    rankdir=LR;\n");
+    for transition in order_status_transitions() {
+        writeln!(
+            dot,
+            "    \"{}\" -> \"{}\" [label=\"{}\"];",
+            transition.from, transition.to, transition.event
+        )
+        .expect("Writing to a `String` cannot fail");
+    }
+    dot.push('}');
+    dot
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_order_status_transitions_match_fsm() {
+        let transitions = order_status_transitions();
+
+        assert!(transitions.contains(&OrderStatusTransition {
+            from: OrderStatus::Submitted,
+            event: OrderEventType::Accepted,
+            to: OrderStatus::Accepted,
+        }));
+        assert!(transitions
+            .iter()
+            .all(|t| t.from.next_status(t.event) == Some(t.to)));
+        assert!(!transitions.iter().any(|t| t.from == OrderStatus::Filled));
+    }
+
+    #[rstest]
+    fn test_valid_event_types() {
+        assert_eq!(
+            valid_event_types(OrderStatus::Canceled),
+            vec![OrderEventType::PartiallyFilled, OrderEventType::Filled]
+        );
+        assert!(valid_event_types(OrderStatus::Denied).is_empty());
+    }
+
+    #[rstest]
+    fn test_order_status_transitions_dot() {
+        let dot = order_status_transitions_dot();
+
+        assert!(dot.starts_with("digraph OrderStatus {"));
+        assert!(dot.contains("    \"SUBMITTED\" -> \"ACCEPTED\" [label=\"Accepted\"];\n"));
+        assert_eq!(dot.lines().count(), order_status_transitions().len() + 3);
+    }
+}
@@ -0,0 +1,260 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Latency breakdown of an order across the hops of its execution lifecycle.
+
+use std::fmt::Display;
+
+use nautilus_core::{nanos::DurationNanos, UnixNanos};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    events::OrderEventAny,
+    identifiers::{ClientOrderId, InstrumentId, StrategyId},
+};
+
+/// Represents the timestamps of an order at each hop from the strategy to the venue, and the
+/// latencies between them.
+///
+/// Venue acknowledgements and fills are timestamped when received locally (their `ts_init`),
+/// so all latencies are measured against the same clock.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrderLatency {
+    /// The client order ID of the order.
+    pub client_order_id: ClientOrderId,
+    /// The strategy ID of the order.
+    pub strategy_id: StrategyId,
+    /// The instrument ID of the order.
+    pub instrument_id: InstrumentId,
+    /// UNIX timestamp (nanoseconds) when the strategy created the submit command.
+    pub ts_submit: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the submit command reached the execution engine
+    /// (having passed the risk engine).
+    pub ts_engine: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the execution client sent the order to the venue.
+    pub ts_sent: Option<UnixNanos>,
+    /// UNIX timestamp (nanoseconds) when the venue acknowledgement of the order was received.
+    pub ts_accepted: Option<UnixNanos>,
+    /// UNIX timestamp (nanoseconds) when the first fill of the order was received.
+    pub ts_first_fill: Option<UnixNanos>,
+    /// UNIX timestamp (nanoseconds) when the last fill of the order was received.
+    pub ts_last_fill: Option<UnixNanos>,
+}
+
+impl OrderLatency {
+    /// Creates a new [`OrderLatency`] instance for an order submitted by the strategy at
+    /// `ts_submit` and received by the execution engine at `ts_engine`.
+    #[must_use]
+    pub const fn new(
+        client_order_id: ClientOrderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        ts_submit: UnixNanos,
+        ts_engine: UnixNanos,
+    ) -> Self {
+        Self {
+            client_order_id,
+            strategy_id,
+            instrument_id,
+            ts_submit,
+            ts_engine,
+            ts_sent: None,
+            ts_accepted: None,
+            ts_first_fill: None,
+            ts_last_fill: None,
+        }
+    }
+
+    /// Records the timestamp of the hop reached by the order with the given `event`.
+    pub fn apply(&mut self, event: &OrderEventAny) {
+        match event {
+            OrderEventAny::Submitted(event) => {
+                self.ts_sent.get_or_insert(event.ts_event);
+            }
+            OrderEventAny::Accepted(event) => {
+                self.ts_accepted.get_or_insert(event.ts_init);
+            }
+            OrderEventAny::PartiallyFilled(event) | OrderEventAny::Filled(event) => {
+                self.ts_first_fill.get_or_insert(event.ts_init);
+                self.ts_last_fill = Some(event.ts_init);
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the latency from the strategy submitting the order to the execution engine
+    /// receiving it, including the pre-trade risk checks.
+    #[must_use]
+    pub fn risk_latency(&self) -> DurationNanos {
+        elapsed(Some(self.ts_submit), Some(self.ts_engine)).unwrap_or_default()
+    }
+
+    /// Returns the latency from the execution engine receiving the order to the execution
+    /// client sending it (if sent).
+    #[must_use]
+    pub fn send_latency(&self) -> Option<DurationNanos> {
+        elapsed(Some(self.ts_engine), self.ts_sent)
+    }
+
+    /// Returns the round trip latency from the order being sent to the venue acknowledgement
+    /// being received (if acknowledged).
+    #[must_use]
+    pub fn ack_latency(&self) -> Option<DurationNanos> {
+        elapsed(self.ts_sent, self.ts_accepted)
+    }
+
+    /// Returns the latency from the venue acknowledgement to the first fill (if filled).
+    #[must_use]
+    pub fn fill_latency(&self) -> Option<DurationNanos> {
+        elapsed(self.ts_accepted, self.ts_first_fill)
+    }
+
+    /// Returns the total latency from the strategy submitting the order to the venue
+    /// acknowledgement being received (if acknowledged).
+    #[must_use]
+    pub fn submit_to_ack_latency(&self) -> Option<DurationNanos> {
+        elapsed(Some(self.ts_submit), self.ts_accepted)
+    }
+
+    /// Returns the total latency from the strategy submitting the order to the first fill
+    /// (if filled).
+    #[must_use]
+    pub fn submit_to_fill_latency(&self) -> Option<DurationNanos> {
+        elapsed(Some(self.ts_submit), self.ts_first_fill)
+    }
+}
+
+fn elapsed(from: Option<UnixNanos>, to: Option<UnixNanos>) -> Option<DurationNanos> {
+    Some(to?.as_u64().saturating_sub(from?.as_u64()))
+}
+
+impl Display for OrderLatency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(client_order_id={}, risk={}, send={:?}, ack={:?}, fill={:?})",
+            stringify!(OrderLatency),
+            self.client_order_id,
+            self.risk_latency(),
+            self.send_latency(),
+            self.ack_latency(),
+            self.fill_latency(),
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::UUID4;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        enums::OrderType,
+        events::{OrderAccepted, OrderSubmitted},
+        identifiers::{AccountId, TraderId, VenueOrderId},
+        instruments::{stubs::audusd_sim, CurrencyPair, InstrumentAny},
+        orders::{stubs::TestOrderEventStubs, OrderAny, OrderTestBuilder},
+        types::Quantity,
+    };
+
+    fn order(instrument: &InstrumentAny) -> OrderAny {
+        OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .quantity(Quantity::from(100_000))
+            .build()
+    }
+
+    #[rstest]
+    fn test_latency_breakdown(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let order = order(&instrument);
+        let account_id = AccountId::from("SIM-001");
+        let mut latency = OrderLatency::new(
+            order.client_order_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            100.into(),
+            150.into(),
+        );
+
+        latency.apply(&OrderEventAny::Submitted(OrderSubmitted::new(
+            TraderId::default(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            account_id,
+            UUID4::new(),
+            200.into(),
+            200.into(),
+        )));
+        latency.apply(&OrderEventAny::Accepted(OrderAccepted::new(
+            TraderId::default(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            VenueOrderId::from("V-1"),
+            account_id,
+            UUID4::new(),
+            450.into(),
+            500.into(),
+            false,
+        )));
+        let OrderEventAny::Filled(mut fill) = TestOrderEventStubs::order_filled(
+            &order,
+            &instrument,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ) else {
+            unreachable!()
+        };
+        fill.ts_init = 800.into();
+        latency.apply(&OrderEventAny::Filled(fill));
+
+        assert_eq!(latency.risk_latency(), 50);
+        assert_eq!(latency.send_latency(), Some(50));
+        assert_eq!(latency.ack_latency(), Some(300));
+        assert_eq!(latency.fill_latency(), Some(300));
+        assert_eq!(latency.submit_to_ack_latency(), Some(400));
+        assert_eq!(latency.submit_to_fill_latency(), Some(700));
+        assert_eq!(latency.ts_last_fill, Some(UnixNanos::from(800)));
+    }
+
+    #[rstest]
+    fn test_latency_before_acknowledgement(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let order = order(&instrument);
+        let latency = OrderLatency::new(
+            order.client_order_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            100.into(),
+            150.into(),
+        );
+
+        assert_eq!(latency.send_latency(), None);
+        assert_eq!(latency.ack_latency(), None);
+        assert_eq!(latency.submit_to_fill_latency(), None);
+    }
+}
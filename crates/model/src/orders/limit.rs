@@ -26,12 +26,12 @@ use ustr::Ustr;
 
 use super::{
     any::OrderAny,
-    base::{Order, OrderCore},
+    base::{check_display_qty, Order, OrderCore},
 };
 use crate::{
     enums::{
-        ContingencyType, LiquiditySide, OrderSide, OrderStatus, OrderType, TimeInForce,
-        TrailingOffsetType, TriggerType,
+        ContingencyType, LiquiditySide, OrderSide, OrderStatus, OrderType, PegPriceType,
+        TimeInForce, TrailingOffsetType, TriggerType,
     },
     events::{OrderEventAny, OrderInitialized, OrderUpdated},
     identifiers::{
@@ -50,6 +50,10 @@ use crate::{
 pub struct LimitOrder {
     core: OrderCore,
     pub price: Price,
+    #[serde(default)]
+    pub peg_price_type: Option<PegPriceType>,
+    #[serde(default)]
+    pub peg_offset: Option<Decimal>,
     pub expire_time: Option<UnixNanos>,
     pub is_post_only: bool,
     pub display_qty: Option<Quantity>,
@@ -67,6 +71,8 @@ impl LimitOrder {
         order_side: OrderSide,
         quantity: Quantity,
         price: Price,
+        peg_price_type: Option<PegPriceType>,
+        peg_offset: Option<Decimal>,
         time_in_force: TimeInForce,
         expire_time: Option<UnixNanos>,
         post_only: bool,
@@ -87,6 +93,10 @@ impl LimitOrder {
         ts_init: UnixNanos,
     ) -> anyhow::Result<Self> {
         check_quantity_positive(quantity)?;
+        check_display_qty(display_qty, quantity)?;
+        if peg_offset.is_some() && peg_price_type.is_none() {
+            anyhow::bail!("Condition failed: `peg_offset` requires a `peg_price_type`")
+        }
         if time_in_force == TimeInForce::Gtd {
             if expire_time.is_none() {
                 anyhow::bail!("Condition failed: `expire_time` is required for `GTD` order")
@@ -97,6 +107,8 @@ impl LimitOrder {
                 }
             }
         }
+        // GTX orders only post to the book as a maker
+        let post_only = post_only || time_in_force == TimeInForce::Gtx;
         let init_order = OrderInitialized::new(
             trader_id,
             strategy_id,
@@ -136,6 +148,8 @@ impl LimitOrder {
         Ok(Self {
             core: OrderCore::new(init_order),
             price,
+            peg_price_type,
+            peg_offset,
             expire_time: expire_time.or(Some(UnixNanos::default())),
             is_post_only: post_only,
             display_qty,
@@ -317,6 +331,10 @@ impl Order for LimitOrder {
         self.tags.as_deref()
     }
 
+    fn metadata(&self) -> Option<&IndexMap<Ustr, Ustr>> {
+        self.metadata.as_ref()
+    }
+
     fn filled_qty(&self) -> Quantity {
         self.filled_qty
     }
@@ -441,7 +459,7 @@ impl From<OrderAny> for LimitOrder {
 
 impl From<OrderInitialized> for LimitOrder {
     fn from(event: OrderInitialized) -> Self {
-        Self::new(
+        let mut order = Self::new(
             event.trader_id,
             event.strategy_id,
             event.instrument_id,
@@ -451,6 +469,8 @@ impl From<OrderInitialized> for LimitOrder {
             event
                 .price // TODO: Improve this error, model order domain errors
                 .expect("Error initializing order: `price` was `None` for `LimitOrder"),
+            None,
+            None,
             event.time_in_force,
             event.expire_time,
             event.post_only,
@@ -470,7 +490,9 @@ impl From<OrderInitialized> for LimitOrder {
             event.event_id,
             event.ts_event,
         )
-        .unwrap()
+        .unwrap();
+        order.replace_metadata(event.metadata);
+        order
     }
 }
 
@@ -517,6 +539,18 @@ mod tests {
             .build();
     }
 
+    #[rstest]
+    #[should_panic(expected = "Condition failed: `display_qty` 2 was greater than `quantity` 1")]
+    fn test_display_qty_condition(audusd_sim: CurrencyPair) {
+        let _ = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(audusd_sim.id)
+            .side(OrderSide::Buy)
+            .price(Price::from("0.8"))
+            .quantity(Quantity::from(1))
+            .display_qty(Quantity::from(2))
+            .build();
+    }
+
     #[rstest]
     #[should_panic(expected = "Condition failed: `expire_time` is required for `GTD` order")]
     fn test_correct_expiration_with_time_in_force_gtd(audusd_sim: CurrencyPair) {
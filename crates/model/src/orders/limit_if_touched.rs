@@ -16,14 +16,14 @@
 use std::ops::{Deref, DerefMut};
 
 use indexmap::IndexMap;
-use nautilus_core::{UnixNanos, UUID4};
+use nautilus_core::{correctness::FAILED, UnixNanos, UUID4};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
 use super::{
     any::OrderAny,
-    base::{Order, OrderCore, OrderError},
+    base::{check_display_qty, Order, OrderCore, OrderError},
 };
 use crate::{
     enums::{
@@ -88,6 +88,9 @@ impl LimitIfTouchedOrder {
         init_id: UUID4,
         ts_init: UnixNanos,
     ) -> Self {
+        check_display_qty(display_qty, quantity).expect(FAILED);
+        // GTX orders only post to the book as a maker
+        let post_only = post_only || time_in_force == TimeInForce::Gtx;
         let init_order = OrderInitialized::new(
             trader_id,
             strategy_id,
@@ -305,6 +308,10 @@ impl Order for LimitIfTouchedOrder {
         self.tags.as_deref()
     }
 
+    fn metadata(&self) -> Option<&IndexMap<Ustr, Ustr>> {
+        self.metadata.as_ref()
+    }
+
     fn filled_qty(&self) -> Quantity {
         self.filled_qty
     }
@@ -390,7 +397,7 @@ impl From<OrderAny> for LimitIfTouchedOrder {
 
 impl From<OrderInitialized> for LimitIfTouchedOrder {
     fn from(event: OrderInitialized) -> Self {
-        Self::new(
+        let mut order = Self::new(
             event.trader_id,
             event.strategy_id,
             event.instrument_id,
@@ -426,6 +433,8 @@ impl From<OrderInitialized> for LimitIfTouchedOrder {
             event.tags,
             event.event_id,
             event.ts_event,
-        )
+        );
+        order.replace_metadata(event.metadata);
+        order
     }
 }
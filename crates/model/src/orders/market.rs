@@ -130,6 +130,10 @@ impl MarketOrder {
             time_in_force == TimeInForce::Gtd,
             "GTD not supported for Market orders",
         )?;
+        check_predicate_false(
+            time_in_force == TimeInForce::Gtx,
+            "GTX not supported for Market orders",
+        )?;
         let init_order = OrderInitialized::new(
             trader_id,
             strategy_id,
@@ -345,6 +349,10 @@ impl Order for MarketOrder {
         self.tags.as_deref()
     }
 
+    fn metadata(&self) -> Option<&IndexMap<Ustr, Ustr>> {
+        self.metadata.as_ref()
+    }
+
     fn filled_qty(&self) -> Quantity {
         self.filled_qty
     }
@@ -462,7 +470,7 @@ impl From<OrderAny> for MarketOrder {
 
 impl From<OrderInitialized> for MarketOrder {
     fn from(event: OrderInitialized) -> Self {
-        Self::new(
+        let mut order = Self::new(
             event.trader_id,
             event.strategy_id,
             event.instrument_id,
@@ -482,7 +490,9 @@ impl From<OrderInitialized> for MarketOrder {
             event.exec_algorithm_params,
             event.exec_spawn_id,
             event.tags,
-        )
+        );
+        order.replace_metadata(event.metadata);
+        order
     }
 }
 
@@ -16,14 +16,14 @@
 use std::ops::{Deref, DerefMut};
 
 use indexmap::IndexMap;
-use nautilus_core::{UnixNanos, UUID4};
+use nautilus_core::{correctness::FAILED, UnixNanos, UUID4};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
 use super::{
     any::OrderAny,
-    base::{Order, OrderCore, OrderError},
+    base::{check_display_qty, Order, OrderCore, OrderError},
 };
 use crate::{
     enums::{
@@ -84,6 +84,7 @@ impl MarketIfTouchedOrder {
         init_id: UUID4,
         ts_init: UnixNanos,
     ) -> Self {
+        check_display_qty(display_qty, quantity).expect(FAILED);
         let init_order = OrderInitialized::new(
             trader_id,
             strategy_id,
@@ -299,6 +300,10 @@ impl Order for MarketIfTouchedOrder {
         self.tags.as_deref()
     }
 
+    fn metadata(&self) -> Option<&IndexMap<Ustr, Ustr>> {
+        self.metadata.as_ref()
+    }
+
     fn filled_qty(&self) -> Quantity {
         self.filled_qty
     }
@@ -382,7 +387,7 @@ impl From<OrderAny> for MarketIfTouchedOrder {
 
 impl From<OrderInitialized> for MarketIfTouchedOrder {
     fn from(event: OrderInitialized) -> Self {
-        Self::new(
+        let mut order = Self::new(
             event.trader_id,
             event.strategy_id,
             event.instrument_id,
@@ -414,6 +419,8 @@ impl From<OrderInitialized> for MarketIfTouchedOrder {
             event.tags,
             event.event_id,
             event.ts_event,
-        )
+        );
+        order.replace_metadata(event.metadata);
+        order
     }
 }
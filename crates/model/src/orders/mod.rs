@@ -17,16 +17,21 @@
 
 #![allow(dead_code)]
 
+pub mod amendment;
 pub mod any;
 pub mod base;
 pub mod builder;
 pub mod default;
+pub mod fsm;
+pub mod latency;
 pub mod limit;
 pub mod limit_if_touched;
 pub mod list;
 pub mod market;
 pub mod market_if_touched;
 pub mod market_to_limit;
+pub mod spawn;
+pub mod spread;
 pub mod stop_limit;
 pub mod stop_market;
 pub mod trailing_stop_limit;
@@ -37,15 +42,19 @@ pub mod stubs;
 
 // Re-exports
 pub use crate::orders::{
+    amendment::{OrderAmendment, OrderAmendmentReason},
     any::{LimitOrderAny, OrderAny, PassiveOrderAny, StopOrderAny},
     base::{Order, OrderError},
     builder::OrderTestBuilder,
+    latency::OrderLatency,
     limit::LimitOrder,
     limit_if_touched::LimitIfTouchedOrder,
     list::OrderList,
     market::MarketOrder,
     market_if_touched::MarketIfTouchedOrder,
     market_to_limit::MarketToLimitOrder,
+    spawn::ExecSpawnProgress,
+    spread::{SpreadLeg, SpreadOrder},
     stop_limit::StopLimitOrder,
     stop_market::StopMarketOrder,
     trailing_stop_limit::TrailingStopLimitOrder,
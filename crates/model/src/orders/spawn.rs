@@ -0,0 +1,229 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Aggregated execution progress of a primary (parent) order across its spawned child orders.
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{identifiers::ClientOrderId, orders::OrderAny, types::Quantity};
+
+/// Represents the execution progress of a primary order, aggregated over the child orders
+/// spawned from it (sharing its `exec_spawn_id`).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExecSpawnProgress {
+    /// The client order ID of the primary order the children were spawned from.
+    pub exec_spawn_id: ClientOrderId,
+    /// The quantity of the primary order (or the total child quantity if not known).
+    pub quantity: Quantity,
+    /// The total quantity of the child orders spawned.
+    pub spawned_qty: Quantity,
+    /// The total quantity filled across the child orders.
+    pub filled_qty: Quantity,
+    /// The quantity of the primary order not yet filled.
+    pub leaves_qty: Quantity,
+    /// The average fill price across the child orders, weighted by filled quantity.
+    pub avg_px: Option<f64>,
+    /// The number of child orders spawned.
+    pub child_count: usize,
+    /// The number of child orders not yet closed.
+    pub open_child_count: usize,
+}
+
+impl ExecSpawnProgress {
+    /// Creates a new [`ExecSpawnProgress`] from the `orders` of `exec_spawn_id`, where the
+    /// order with the client order ID `exec_spawn_id` (if any) is the primary order.
+    ///
+    /// Returns `None` if there are no orders.
+    #[must_use]
+    pub fn from_orders(exec_spawn_id: ClientOrderId, orders: &[&OrderAny]) -> Option<Self> {
+        let primary = orders
+            .iter()
+            .find(|order| order.client_order_id() == exec_spawn_id);
+        let children: Vec<&OrderAny> = orders
+            .iter()
+            .filter(|order| order.client_order_id() != exec_spawn_id)
+            .copied()
+            .collect();
+
+        let precision = primary.or(orders.first())?.quantity().precision;
+        let zero = Quantity::zero(precision);
+        let mut spawned_qty = zero;
+        let mut filled_qty = zero;
+        let mut notional = 0.0;
+        let mut open_child_count = 0;
+        for child in &children {
+            spawned_qty += child.quantity();
+            filled_qty += child.filled_qty();
+            if let Some(avg_px) = child.avg_px() {
+                notional += avg_px * child.filled_qty().as_f64();
+            }
+            if !child.is_closed() {
+                open_child_count += 1;
+            }
+        }
+
+        let quantity = primary.map_or(spawned_qty, |order| order.quantity());
+        let leaves_qty = if filled_qty >= quantity {
+            zero
+        } else {
+            quantity - filled_qty
+        };
+
+        Some(Self {
+            exec_spawn_id,
+            quantity,
+            spawned_qty,
+            filled_qty,
+            leaves_qty,
+            avg_px: (!filled_qty.is_zero()).then(|| notional / filled_qty.as_f64()),
+            child_count: children.len(),
+            open_child_count,
+        })
+    }
+
+    /// Returns whether the full quantity of the primary order has been filled.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.leaves_qty.is_zero()
+    }
+}
+
+impl Display for ExecSpawnProgress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(exec_spawn_id={}, quantity={}, spawned_qty={}, filled_qty={}, avg_px={:?}, children={}, open_children={})",
+            stringify!(ExecSpawnProgress),
+            self.exec_spawn_id,
+            self.quantity,
+            self.spawned_qty,
+            self.filled_qty,
+            self.avg_px,
+            self.child_count,
+            self.open_child_count,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        enums::{OrderSide, OrderType},
+        events::OrderEventAny,
+        identifiers::{AccountId, VenueOrderId},
+        instruments::{stubs::audusd_sim, CurrencyPair, InstrumentAny},
+        orders::{stubs::TestOrderEventStubs, OrderTestBuilder},
+        types::Price,
+    };
+
+    fn child(
+        instrument: &InstrumentAny,
+        client_order_id: &str,
+        quantity: u64,
+        fill: Option<(u64, &str)>,
+    ) -> OrderAny {
+        let mut order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .exec_spawn_id(ClientOrderId::from("O-1"))
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(quantity))
+            .build();
+        if let Some((last_qty, last_px)) = fill {
+            let account_id = AccountId::from("SIM-001");
+            order
+                .apply(TestOrderEventStubs::order_submitted(&order, account_id))
+                .unwrap();
+            order
+                .apply(TestOrderEventStubs::order_accepted(
+                    &order,
+                    account_id,
+                    VenueOrderId::from(client_order_id),
+                ))
+                .unwrap();
+            let OrderEventAny::Filled(fill) = TestOrderEventStubs::order_filled(
+                &order,
+                instrument,
+                None,
+                None,
+                Some(Price::from(last_px)),
+                Some(Quantity::from(last_qty)),
+                None,
+                None,
+                None,
+                None,
+            ) else {
+                unreachable!()
+            };
+            let fill = if last_qty < quantity {
+                OrderEventAny::PartiallyFilled(fill)
+            } else {
+                OrderEventAny::Filled(fill)
+            };
+            order.apply(fill).unwrap();
+        }
+        order
+    }
+
+    #[rstest]
+    fn test_progress_aggregates_child_fills(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let primary = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .client_order_id(ClientOrderId::from("O-1"))
+            .exec_spawn_id(ClientOrderId::from("O-1"))
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(300_000))
+            .build();
+        let child1 = child(&instrument, "O-1-E1", 100_000, Some((100_000, "1.00000")));
+        let child2 = child(&instrument, "O-1-E2", 100_000, Some((50_000, "1.00030")));
+
+        let progress = ExecSpawnProgress::from_orders(
+            ClientOrderId::from("O-1"),
+            &[&child1, &primary, &child2],
+        )
+        .unwrap();
+
+        assert_eq!(progress.quantity, Quantity::from(300_000));
+        assert_eq!(progress.spawned_qty, Quantity::from(200_000));
+        assert_eq!(progress.filled_qty, Quantity::from(150_000));
+        assert_eq!(progress.leaves_qty, Quantity::from(150_000));
+        assert!((progress.avg_px.unwrap() - 1.0001).abs() < 1e-9);
+        assert_eq!(progress.child_count, 2);
+        assert_eq!(progress.open_child_count, 1);
+        assert!(!progress.is_complete());
+    }
+
+    #[rstest]
+    fn test_progress_without_primary_uses_spawned_quantity(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let child1 = child(&instrument, "O-1-E1", 100_000, Some((100_000, "1.00000")));
+
+        let progress =
+            ExecSpawnProgress::from_orders(ClientOrderId::from("O-1"), &[&child1]).unwrap();
+
+        assert_eq!(progress.quantity, Quantity::from(100_000));
+        assert!(progress.is_complete());
+        assert!(ExecSpawnProgress::from_orders(ClientOrderId::from("O-1"), &[]).is_none());
+    }
+}
@@ -0,0 +1,371 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Multi-leg spread (combo) orders, executed at a single net price.
+
+use std::{collections::HashSet, fmt::Display};
+
+use nautilus_core::UnixNanos;
+use rust_decimal::{prelude::ToPrimitive, Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+
+use super::any::OrderAny;
+use crate::{
+    data::QuoteTick,
+    enums::{OrderSide, OrderType},
+    identifiers::{InstrumentId, OrderListId, StrategyId},
+    types::{quantity::QuantityRaw, Price, Quantity},
+};
+
+/// Represents a leg of a spread, with a signed `ratio` of leg units per spread unit.
+///
+/// A positive ratio trades the leg on the same side as the spread, and a negative ratio on the
+/// opposite side.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SpreadLeg {
+    pub instrument_id: InstrumentId,
+    pub ratio: i64,
+}
+
+impl SpreadLeg {
+    /// Creates a new [`SpreadLeg`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `ratio` is zero.
+    pub fn new(instrument_id: InstrumentId, ratio: i64) -> anyhow::Result<Self> {
+        if ratio == 0 {
+            anyhow::bail!("Invalid `ratio` for spread leg {instrument_id}, was zero");
+        }
+        Ok(Self {
+            instrument_id,
+            ratio,
+        })
+    }
+
+    /// Returns the side the leg trades on for the given `spread_side`.
+    #[must_use]
+    pub fn order_side(&self, spread_side: OrderSide) -> OrderSide {
+        match (spread_side, self.ratio > 0) {
+            (OrderSide::Buy, true) | (OrderSide::Sell, false) => OrderSide::Buy,
+            (OrderSide::Sell, true) | (OrderSide::Buy, false) => OrderSide::Sell,
+            (OrderSide::NoOrderSide, _) => OrderSide::NoOrderSide,
+        }
+    }
+
+    /// Returns the leg quantity for the given `spread_qty`.
+    #[must_use]
+    pub fn quantity(&self, spread_qty: Quantity) -> Quantity {
+        Quantity::from_raw(
+            spread_qty.raw * self.ratio.unsigned_abs() as QuantityRaw,
+            spread_qty.precision,
+        )
+    }
+}
+
+/// Represents a multi-leg spread (combo) order, being a single order for the spread instrument
+/// at a net price per spread unit, together with the legs it executes as.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SpreadOrder {
+    pub id: OrderListId,
+    /// The spread instrument the order is for.
+    pub instrument_id: InstrumentId,
+    pub strategy_id: StrategyId,
+    pub legs: Vec<SpreadLeg>,
+    /// The order for the spread, with a net price for limit orders.
+    pub order: OrderAny,
+    pub ts_init: UnixNanos,
+}
+
+impl SpreadOrder {
+    /// Creates a new [`SpreadOrder`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - There are less than two `legs`, or a leg instrument is repeated.
+    /// - The `order` is not a market or limit order.
+    pub fn new(
+        order_list_id: OrderListId,
+        legs: Vec<SpreadLeg>,
+        order: OrderAny,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        if legs.len() < 2 {
+            anyhow::bail!(
+                "Invalid spread order: {} legs, expected at least 2",
+                legs.len()
+            );
+        }
+        let mut instrument_ids = HashSet::new();
+        for leg in &legs {
+            if !instrument_ids.insert(leg.instrument_id) {
+                anyhow::bail!("Invalid spread order: repeated leg {}", leg.instrument_id);
+            }
+        }
+        let order_type = order.order_type();
+        if !matches!(order_type, OrderType::Market | OrderType::Limit) {
+            anyhow::bail!("Invalid `OrderType` {order_type} for spread order");
+        }
+
+        Ok(Self {
+            id: order_list_id,
+            instrument_id: order.instrument_id(),
+            strategy_id: order.strategy_id(),
+            legs,
+            order,
+            ts_init,
+        })
+    }
+
+    /// Returns the side and quantity each leg executes for the spread order.
+    #[must_use]
+    pub fn leg_orders(&self) -> Vec<(InstrumentId, OrderSide, Quantity)> {
+        let side = self.order.order_side();
+        let quantity = self.order.quantity();
+        self.legs
+            .iter()
+            .map(|leg| {
+                (
+                    leg.instrument_id,
+                    leg.order_side(side),
+                    leg.quantity(quantity),
+                )
+            })
+            .collect()
+    }
+}
+
+impl PartialEq for SpreadOrder {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Display for SpreadOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let legs = self
+            .legs
+            .iter()
+            .map(|leg| format!("{:+}x{}", leg.ratio, leg.instrument_id))
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(
+            f,
+            "SpreadOrder(id={}, instrument_id={}, strategy_id={}, legs=[{}], order={})",
+            self.id, self.instrument_id, self.strategy_id, legs, self.order,
+        )
+    }
+}
+
+/// Returns the quote for a spread implied by the quotes of its `legs`, being the net prices
+/// to sell and buy the spread by trading each leg at its touch.
+///
+/// The `leg_quotes` are in the order of the `legs`. Returns `None` if the implied size on either
+/// side is zero.
+#[must_use]
+pub fn implied_spread_quote(
+    instrument_id: InstrumentId,
+    legs: &[SpreadLeg],
+    leg_quotes: &[QuoteTick],
+    price_precision: u8,
+    size_precision: u8,
+    ts_init: UnixNanos,
+) -> Option<QuoteTick> {
+    if legs.is_empty() || legs.len() != leg_quotes.len() {
+        return None;
+    }
+
+    let mut bid = Decimal::ZERO;
+    let mut ask = Decimal::ZERO;
+    let mut bid_size: Option<Decimal> = None;
+    let mut ask_size: Option<Decimal> = None;
+    let mut ts_event = UnixNanos::default();
+    for (leg, quote) in legs.iter().zip(leg_quotes) {
+        let ratio = Decimal::from(leg.ratio);
+        let units = Decimal::from(leg.ratio.unsigned_abs());
+        // Selling the spread sells long legs at the bid and buys short legs at the ask
+        let (bid_leg_px, bid_leg_size, ask_leg_px, ask_leg_size) = if leg.ratio > 0 {
+            (
+                quote.bid_price,
+                quote.bid_size,
+                quote.ask_price,
+                quote.ask_size,
+            )
+        } else {
+            (
+                quote.ask_price,
+                quote.ask_size,
+                quote.bid_price,
+                quote.bid_size,
+            )
+        };
+        bid += ratio * bid_leg_px.as_decimal();
+        ask += ratio * ask_leg_px.as_decimal();
+        let leg_bid_size = bid_leg_size.as_decimal() / units;
+        let leg_ask_size = ask_leg_size.as_decimal() / units;
+        bid_size = Some(bid_size.map_or(leg_bid_size, |size| size.min(leg_bid_size)));
+        ask_size = Some(ask_size.map_or(leg_ask_size, |size| size.min(leg_ask_size)));
+        ts_event = ts_event.max(quote.ts_event);
+    }
+
+    let to_size = |size: Option<Decimal>| {
+        let size = size?.round_dp_with_strategy(size_precision.into(), RoundingStrategy::ToZero);
+        if size <= Decimal::ZERO {
+            return None;
+        }
+        Some(Quantity::new(size.to_f64()?, size_precision))
+    };
+    let bid_size = to_size(bid_size)?;
+    let ask_size = to_size(ask_size)?;
+    let to_price = |value: Decimal| Price::new(value.to_f64().unwrap_or_default(), price_precision);
+
+    Some(QuoteTick::new(
+        instrument_id,
+        to_price(bid),
+        to_price(ask),
+        bid_size,
+        ask_size,
+        ts_event,
+        ts_init,
+    ))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::orders::OrderTestBuilder;
+
+    fn legs() -> Vec<SpreadLeg> {
+        vec![
+            SpreadLeg::new(InstrumentId::from("ESM5.XCME"), 1).unwrap(),
+            SpreadLeg::new(InstrumentId::from("ESU5.XCME"), -2).unwrap(),
+        ]
+    }
+
+    fn quote(instrument_id: &str, bid: &str, ask: &str, bid_size: u64, ask_size: u64) -> QuoteTick {
+        QuoteTick::new(
+            InstrumentId::from(instrument_id),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(bid_size),
+            Quantity::from(ask_size),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        )
+    }
+
+    #[rstest]
+    fn test_leg_with_zero_ratio() {
+        assert!(SpreadLeg::new(InstrumentId::from("ESM5.XCME"), 0).is_err());
+    }
+
+    #[rstest]
+    fn test_spread_order_leg_orders() {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(InstrumentId::from("ESM5-ESU5.XCME"))
+            .side(OrderSide::Sell)
+            .price(Price::from("-10.00"))
+            .quantity(Quantity::from(3))
+            .build();
+
+        let spread = SpreadOrder::new(OrderListId::from("OL-1"), legs(), order, 0.into()).unwrap();
+
+        assert_eq!(spread.instrument_id, InstrumentId::from("ESM5-ESU5.XCME"));
+        assert_eq!(
+            spread.leg_orders(),
+            vec![
+                (
+                    InstrumentId::from("ESM5.XCME"),
+                    OrderSide::Sell,
+                    Quantity::from(3)
+                ),
+                (
+                    InstrumentId::from("ESU5.XCME"),
+                    OrderSide::Buy,
+                    Quantity::from(6)
+                ),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_spread_order_validation() {
+        let order = OrderTestBuilder::new(OrderType::StopMarket)
+            .instrument_id(InstrumentId::from("ESM5-ESU5.XCME"))
+            .side(OrderSide::Buy)
+            .trigger_price(Price::from("1.00"))
+            .quantity(Quantity::from(1))
+            .build();
+        let leg = legs()[0];
+
+        assert!(
+            SpreadOrder::new(OrderListId::from("OL-1"), legs(), order.clone(), 0.into()).is_err()
+        );
+        assert!(
+            SpreadOrder::new(OrderListId::from("OL-1"), vec![leg, leg], order, 0.into()).is_err()
+        );
+    }
+
+    #[rstest]
+    fn test_implied_spread_quote() {
+        let quotes = [
+            quote("ESM5.XCME", "5000.00", "5000.25", 10, 8),
+            quote("ESU5.XCME", "2510.00", "2510.25", 7, 9),
+        ];
+
+        let implied = implied_spread_quote(
+            InstrumentId::from("ESM5-ESU5.XCME"),
+            &legs(),
+            &quotes,
+            2,
+            0,
+            2.into(),
+        )
+        .unwrap();
+
+        // Bid sells ESM5 at 5000.00 and buys 2 ESU5 at 2510.25
+        assert_eq!(implied.bid_price, Price::from("-20.50"));
+        // Ask buys ESM5 at 5000.25 and sells 2 ESU5 at 2510.00
+        assert_eq!(implied.ask_price, Price::from("-19.75"));
+        assert_eq!(implied.bid_size, Quantity::from(4));
+        assert_eq!(implied.ask_size, Quantity::from(3));
+        assert_eq!(implied.ts_event, UnixNanos::from(1));
+    }
+
+    #[rstest]
+    fn test_implied_spread_quote_when_no_size() {
+        let quotes = [
+            quote("ESM5.XCME", "5000.00", "5000.25", 10, 8),
+            quote("ESU5.XCME", "2510.00", "2510.25", 1, 1),
+        ];
+
+        let implied = implied_spread_quote(
+            InstrumentId::from("ESM5-ESU5.XCME"),
+            &legs(),
+            &quotes,
+            2,
+            0,
+            2.into(),
+        );
+
+        assert!(implied.is_none());
+    }
+}
@@ -19,14 +19,14 @@ use std::{
 };
 
 use indexmap::IndexMap;
-use nautilus_core::{UnixNanos, UUID4};
+use nautilus_core::{correctness::FAILED, UnixNanos, UUID4};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
 use super::{
     any::OrderAny,
-    base::{Order, OrderCore, OrderError},
+    base::{check_display_qty, Order, OrderCore, OrderError},
 };
 use crate::{
     enums::{
@@ -91,6 +91,9 @@ impl StopLimitOrder {
         init_id: UUID4,
         ts_init: UnixNanos,
     ) -> Self {
+        check_display_qty(display_qty, quantity).expect(FAILED);
+        // GTX orders only post to the book as a maker
+        let post_only = post_only || time_in_force == TimeInForce::Gtx;
         let init_order = OrderInitialized::new(
             trader_id,
             strategy_id,
@@ -314,6 +317,10 @@ impl Order for StopLimitOrder {
         self.tags.as_deref()
     }
 
+    fn metadata(&self) -> Option<&IndexMap<Ustr, Ustr>> {
+        self.metadata.as_ref()
+    }
+
     fn filled_qty(&self) -> Quantity {
         self.filled_qty
     }
@@ -401,7 +408,7 @@ impl From<OrderAny> for StopLimitOrder {
 
 impl From<OrderInitialized> for StopLimitOrder {
     fn from(event: OrderInitialized) -> Self {
-        Self::new(
+        let mut order = Self::new(
             event.trader_id,
             event.strategy_id,
             event.instrument_id,
@@ -435,7 +442,9 @@ impl From<OrderInitialized> for StopLimitOrder {
             event.tags,
             event.event_id,
             event.ts_event,
-        )
+        );
+        order.replace_metadata(event.metadata);
+        order
     }
 }
 
@@ -16,14 +16,14 @@
 use std::ops::{Deref, DerefMut};
 
 use indexmap::IndexMap;
-use nautilus_core::{UnixNanos, UUID4};
+use nautilus_core::{correctness::FAILED, UnixNanos, UUID4};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
 use super::{
     any::OrderAny,
-    base::{Order, OrderCore},
+    base::{check_display_qty, Order, OrderCore},
 };
 use crate::{
     enums::{
@@ -85,6 +85,7 @@ impl StopMarketOrder {
         init_id: UUID4,
         ts_init: UnixNanos,
     ) -> Self {
+        check_display_qty(display_qty, quantity).expect(FAILED);
         let init_order = OrderInitialized::new(
             trader_id,
             strategy_id,
@@ -300,6 +301,10 @@ impl Order for StopMarketOrder {
         self.tags.as_deref()
     }
 
+    fn metadata(&self) -> Option<&IndexMap<Ustr, Ustr>> {
+        self.metadata.as_ref()
+    }
+
     fn filled_qty(&self) -> Quantity {
         self.filled_qty
     }
@@ -369,7 +374,7 @@ impl Order for StopMarketOrder {
 
 impl From<OrderInitialized> for StopMarketOrder {
     fn from(event: OrderInitialized) -> Self {
-        Self::new(
+        let mut order = Self::new(
             event.trader_id,
             event.strategy_id,
             event.instrument_id,
@@ -401,6 +406,8 @@ impl From<OrderInitialized> for StopMarketOrder {
             event.tags,
             event.event_id,
             event.ts_event,
-        )
+        );
+        order.replace_metadata(event.metadata);
+        order
     }
 }
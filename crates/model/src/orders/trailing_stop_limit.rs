@@ -16,14 +16,14 @@
 use std::ops::{Deref, DerefMut};
 
 use indexmap::IndexMap;
-use nautilus_core::{UnixNanos, UUID4};
+use nautilus_core::{correctness::FAILED, UnixNanos, UUID4};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
 use super::{
     any::OrderAny,
-    base::{Order, OrderCore, OrderError},
+    base::{check_display_qty, Order, OrderCore, OrderError},
 };
 use crate::{
     enums::{
@@ -51,6 +51,10 @@ pub struct TrailingStopLimitOrder {
     pub limit_offset: Decimal,
     pub trailing_offset: Decimal,
     pub trailing_offset_type: TrailingOffsetType,
+    #[serde(default)]
+    pub activation_price: Option<Price>,
+    #[serde(default)]
+    pub is_activated: bool,
     pub expire_time: Option<UnixNanos>,
     pub is_post_only: bool,
     pub display_qty: Option<Quantity>,
@@ -75,6 +79,7 @@ impl TrailingStopLimitOrder {
         limit_offset: Decimal,
         trailing_offset: Decimal,
         trailing_offset_type: TrailingOffsetType,
+        activation_price: Option<Price>,
         time_in_force: TimeInForce,
         expire_time: Option<UnixNanos>,
         post_only: bool,
@@ -94,6 +99,9 @@ impl TrailingStopLimitOrder {
         init_id: UUID4,
         ts_init: UnixNanos,
     ) -> Self {
+        check_display_qty(display_qty, quantity).expect(FAILED);
+        // GTX orders only post to the book as a maker
+        let post_only = post_only || time_in_force == TimeInForce::Gtx;
         let init_order = OrderInitialized::new(
             trader_id,
             strategy_id,
@@ -137,6 +145,8 @@ impl TrailingStopLimitOrder {
             limit_offset,
             trailing_offset,
             trailing_offset_type,
+            activation_price,
+            is_activated: false,
             expire_time,
             is_post_only: post_only,
             display_qty,
@@ -314,6 +324,10 @@ impl Order for TrailingStopLimitOrder {
         self.tags.as_deref()
     }
 
+    fn metadata(&self) -> Option<&IndexMap<Ustr, Ustr>> {
+        self.metadata.as_ref()
+    }
+
     fn filled_qty(&self) -> Quantity {
         self.filled_qty
     }
@@ -399,7 +413,7 @@ impl From<OrderAny> for TrailingStopLimitOrder {
 
 impl From<OrderInitialized> for TrailingStopLimitOrder {
     fn from(event: OrderInitialized) -> Self {
-        Self::new(
+        let mut order = Self::new(
             event.trader_id,
             event.strategy_id,
             event.instrument_id,
@@ -420,6 +434,7 @@ impl From<OrderInitialized> for TrailingStopLimitOrder {
             event.limit_offset.unwrap(),  // TODO
             event.trailing_offset.unwrap(),  // TODO
             event.trailing_offset_type.unwrap(),  // TODO
+            None,
             event.time_in_force,
             event.expire_time,
             event.post_only,
@@ -438,6 +453,8 @@ impl From<OrderInitialized> for TrailingStopLimitOrder {
             event.tags,
             event.event_id,
             event.ts_event,
-        )
+        );
+        order.replace_metadata(event.metadata);
+        order
     }
 }
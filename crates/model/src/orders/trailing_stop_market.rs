@@ -16,14 +16,14 @@
 use std::ops::{Deref, DerefMut};
 
 use indexmap::IndexMap;
-use nautilus_core::{UnixNanos, UUID4};
+use nautilus_core::{correctness::FAILED, UnixNanos, UUID4};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
 use super::{
     any::OrderAny,
-    base::{Order, OrderCore},
+    base::{check_display_qty, Order, OrderCore},
 };
 use crate::{
     enums::{
@@ -50,6 +50,10 @@ pub struct TrailingStopMarketOrder {
     pub trigger_type: TriggerType,
     pub trailing_offset: Decimal,
     pub trailing_offset_type: TrailingOffsetType,
+    #[serde(default)]
+    pub activation_price: Option<Price>,
+    #[serde(default)]
+    pub is_activated: bool,
     pub expire_time: Option<UnixNanos>,
     pub display_qty: Option<Quantity>,
     pub trigger_instrument_id: Option<InstrumentId>,
@@ -71,6 +75,7 @@ impl TrailingStopMarketOrder {
         trigger_type: TriggerType,
         trailing_offset: Decimal,
         trailing_offset_type: TrailingOffsetType,
+        activation_price: Option<Price>,
         time_in_force: TimeInForce,
         expire_time: Option<UnixNanos>,
         reduce_only: bool,
@@ -89,6 +94,7 @@ impl TrailingStopMarketOrder {
         init_id: UUID4,
         ts_init: UnixNanos,
     ) -> Self {
+        check_display_qty(display_qty, quantity).expect(FAILED);
         let init_order = OrderInitialized::new(
             trader_id,
             strategy_id,
@@ -130,6 +136,8 @@ impl TrailingStopMarketOrder {
             trigger_type,
             trailing_offset,
             trailing_offset_type,
+            activation_price,
+            is_activated: false,
             expire_time,
             display_qty,
             trigger_instrument_id,
@@ -306,6 +314,10 @@ impl Order for TrailingStopMarketOrder {
         self.tags.as_deref()
     }
 
+    fn metadata(&self) -> Option<&IndexMap<Ustr, Ustr>> {
+        self.metadata.as_ref()
+    }
+
     fn filled_qty(&self) -> Quantity {
         self.filled_qty
     }
@@ -389,7 +401,7 @@ impl From<OrderAny> for TrailingStopMarketOrder {
 
 impl From<OrderInitialized> for TrailingStopMarketOrder {
     fn from(event: OrderInitialized) -> Self {
-        Self::new(
+        let mut order = Self::new(
             event.trader_id,
             event.strategy_id,
             event.instrument_id,
@@ -406,6 +418,7 @@ impl From<OrderInitialized> for TrailingStopMarketOrder {
                 .expect("Error initializing order: `trigger_type` was `None` for `TrailingStopMarketOrder`"),
             event.trailing_offset.unwrap(),  // TODO
             event.trailing_offset_type.unwrap(),  // TODO
+            None,
             event.time_in_force,
             event.expire_time,
             event.reduce_only,
@@ -423,6 +436,8 @@ impl From<OrderInitialized> for TrailingStopMarketOrder {
             event.tags,
             event.event_id,
             event.ts_event,
-        )
+        );
+        order.replace_metadata(event.metadata);
+        order
     }
 }
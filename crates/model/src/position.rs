@@ -205,6 +205,24 @@ impl Position {
         self.ts_last = fill.ts_event;
     }
 
+    /// Applies a split of `split_to` new shares for every `split_from` held, scaling the
+    /// position quantities up and its average prices down by the split ratio.
+    ///
+    /// Realized PnL is unaffected, as the value of the position is unchanged by a split.
+    pub fn apply_split(&mut self, split_to: u32, split_from: u32) {
+        let ratio = f64::from(split_to) / f64::from(split_from);
+        let precision = self.size_precision;
+        let scale = |qty: Quantity| Quantity::new(qty.as_f64() * ratio, precision);
+
+        self.signed_qty *= ratio;
+        self.quantity = Quantity::new(self.signed_qty.abs(), self.size_precision);
+        self.peak_qty = scale(self.peak_qty);
+        self.buy_qty = scale(self.buy_qty);
+        self.sell_qty = scale(self.sell_qty);
+        self.avg_px_open /= ratio;
+        self.avg_px_close = self.avg_px_close.map(|px| px / ratio);
+    }
+
     pub fn handle_buy_order_fill(&mut self, fill: &OrderFilled) {
         // Handle case where commission could be None or not settlement currency
         let mut realized_pnl = if let Some(commission) = fill.commission {
@@ -647,6 +665,43 @@ mod tests {
         );
     }
 
+    #[rstest]
+    fn test_position_apply_split(audusd_sim: CurrencyPair) {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(audusd_sim.id())
+            .side(OrderSide::Sell)
+            .quantity(Quantity::from(100_000))
+            .build();
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            &audusd_sim,
+            None,
+            None,
+            Some(Price::from("1.00002")),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let mut position = Position::new(&audusd_sim, fill.into());
+        let unrealized_pnl = position.unrealized_pnl(Price::from("1.00000"));
+
+        position.apply_split(2, 1);
+
+        assert_eq!(position.quantity, Quantity::from(200_000));
+        assert_eq!(position.peak_qty, Quantity::from(200_000));
+        assert_eq!(position.sell_qty, Quantity::from(200_000));
+        assert_eq!(position.signed_qty, -200_000.0);
+        assert_eq!(position.avg_px_open, 0.50001);
+        assert_eq!(position.side, PositionSide::Short);
+        assert_eq!(
+            position.unrealized_pnl(Price::from("0.50000")),
+            unrealized_pnl
+        );
+    }
+
     #[rstest]
     fn test_position_filled_with_sell_order(audusd_sim: CurrencyPair) {
         let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
@@ -2011,6 +2011,12 @@ impl TimeInForce {
     fn py_at_the_close() -> Self {
         Self::AtTheClose
     }
+
+    #[classattr]
+    #[pyo3(name = "GTX")]
+    fn py_gtx() -> Self {
+        Self::Gtx
+    }
 }
 
 #[pymethods]
@@ -0,0 +1,372 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use nautilus_core::python::{
+    serialization::from_dict_pyo3, to_pyvalue_err, IntoPyObjectNautilusExt,
+};
+use pyo3::{basic::CompareOp, prelude::*, types::PyDict};
+use rust_decimal::Decimal;
+use ustr::Ustr;
+
+use crate::{
+    enums::DayCountConvention,
+    identifiers::{InstrumentId, Symbol},
+    instruments::Bond,
+    types::{Currency, Money, Price, Quantity},
+};
+
+#[pymethods]
+impl Bond {
+    #[allow(clippy::too_many_arguments)]
+    #[new]
+    #[pyo3(signature = (id, raw_symbol, currency, coupon_rate, coupon_frequency, day_count, face_value, issue_ns, maturity_ns, price_precision, price_increment, size_precision, size_increment, ts_event, ts_init, isin=None, exchange=None, lot_size=None, max_quantity=None, min_quantity=None, max_price=None, min_price=None, margin_init=None, margin_maint=None, maker_fee=None, taker_fee=None))]
+    fn py_new(
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        currency: Currency,
+        coupon_rate: Decimal,
+        coupon_frequency: u8,
+        day_count: DayCountConvention,
+        face_value: Quantity,
+        issue_ns: u64,
+        maturity_ns: u64,
+        price_precision: u8,
+        price_increment: Price,
+        size_precision: u8,
+        size_increment: Quantity,
+        ts_event: u64,
+        ts_init: u64,
+        isin: Option<String>,
+        exchange: Option<String>,
+        lot_size: Option<Quantity>,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_price: Option<Price>,
+        min_price: Option<Price>,
+        margin_init: Option<Decimal>,
+        margin_maint: Option<Decimal>,
+        maker_fee: Option<Decimal>,
+        taker_fee: Option<Decimal>,
+    ) -> PyResult<Self> {
+        Self::new_checked(
+            id,
+            raw_symbol,
+            isin.map(|x| Ustr::from(&x)),
+            exchange.map(|x| Ustr::from(&x)),
+            currency,
+            coupon_rate,
+            coupon_frequency,
+            day_count,
+            face_value,
+            issue_ns.into(),
+            maturity_ns.into(),
+            price_precision,
+            price_increment,
+            size_precision,
+            size_increment,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_price,
+            min_price,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+            ts_event.into(),
+            ts_init.into(),
+        )
+        .map_err(to_pyvalue_err)
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        match op {
+            CompareOp::Ne => self.ne(other).into_py_any_unwrap(py),
+            CompareOp::Eq => self.eq(other).into_py_any_unwrap(py),
+            _ => py.NotImplemented(),
+        }
+    }
+
+    fn __hash__(&self) -> isize {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish() as isize
+    }
+
+    #[getter]
+    fn type_str(&self) -> &str {
+        stringify!(Bond)
+    }
+
+    #[getter]
+    #[pyo3(name = "id")]
+    fn py_id(&self) -> InstrumentId {
+        self.id
+    }
+
+    #[getter]
+    #[pyo3(name = "raw_symbol")]
+    fn py_raw_symbol(&self) -> Symbol {
+        self.raw_symbol
+    }
+
+    #[getter]
+    #[pyo3(name = "isin")]
+    fn py_isin(&self) -> Option<&str> {
+        self.isin.as_ref().map(Ustr::as_str)
+    }
+
+    #[getter]
+    #[pyo3(name = "exchange")]
+    fn py_exchange(&self) -> Option<&str> {
+        self.exchange.as_ref().map(Ustr::as_str)
+    }
+
+    #[getter]
+    #[pyo3(name = "currency")]
+    fn py_currency(&self) -> Currency {
+        self.currency
+    }
+
+    #[getter]
+    #[pyo3(name = "coupon_rate")]
+    fn py_coupon_rate(&self) -> Decimal {
+        self.coupon_rate
+    }
+
+    #[getter]
+    #[pyo3(name = "coupon_frequency")]
+    fn py_coupon_frequency(&self) -> u8 {
+        self.coupon_frequency
+    }
+
+    #[getter]
+    #[pyo3(name = "day_count")]
+    fn py_day_count(&self) -> DayCountConvention {
+        self.day_count
+    }
+
+    #[getter]
+    #[pyo3(name = "face_value")]
+    fn py_face_value(&self) -> Quantity {
+        self.face_value
+    }
+
+    #[getter]
+    #[pyo3(name = "issue_ns")]
+    fn py_issue_ns(&self) -> u64 {
+        self.issue_ns.as_u64()
+    }
+
+    #[getter]
+    #[pyo3(name = "maturity_ns")]
+    fn py_maturity_ns(&self) -> u64 {
+        self.maturity_ns.as_u64()
+    }
+
+    #[getter]
+    #[pyo3(name = "price_precision")]
+    fn py_price_precision(&self) -> u8 {
+        self.price_precision
+    }
+
+    #[getter]
+    #[pyo3(name = "price_increment")]
+    fn py_price_increment(&self) -> Price {
+        self.price_increment
+    }
+
+    #[getter]
+    #[pyo3(name = "size_precision")]
+    fn py_size_precision(&self) -> u8 {
+        self.size_precision
+    }
+
+    #[getter]
+    #[pyo3(name = "size_increment")]
+    fn py_size_increment(&self) -> Quantity {
+        self.size_increment
+    }
+
+    #[getter]
+    #[pyo3(name = "multiplier")]
+    fn py_multiplier(&self) -> Quantity {
+        self.multiplier
+    }
+
+    #[getter]
+    #[pyo3(name = "lot_size")]
+    fn py_lot_size(&self) -> Option<Quantity> {
+        self.lot_size
+    }
+
+    #[getter]
+    #[pyo3(name = "max_quantity")]
+    fn py_max_quantity(&self) -> Option<Quantity> {
+        self.max_quantity
+    }
+
+    #[getter]
+    #[pyo3(name = "min_quantity")]
+    fn py_min_quantity(&self) -> Option<Quantity> {
+        self.min_quantity
+    }
+
+    #[getter]
+    #[pyo3(name = "max_price")]
+    fn py_max_price(&self) -> Option<Price> {
+        self.max_price
+    }
+
+    #[getter]
+    #[pyo3(name = "min_price")]
+    fn py_min_price(&self) -> Option<Price> {
+        self.min_price
+    }
+
+    #[getter]
+    #[pyo3(name = "margin_init")]
+    fn py_margin_init(&self) -> Decimal {
+        self.margin_init
+    }
+
+    #[getter]
+    #[pyo3(name = "margin_maint")]
+    fn py_margin_maint(&self) -> Decimal {
+        self.margin_maint
+    }
+
+    #[getter]
+    #[pyo3(name = "maker_fee")]
+    fn py_maker_fee(&self) -> Decimal {
+        self.maker_fee
+    }
+
+    #[getter]
+    #[pyo3(name = "taker_fee")]
+    fn py_taker_fee(&self) -> Decimal {
+        self.taker_fee
+    }
+
+    #[getter]
+    #[pyo3(name = "info")]
+    fn py_info(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(PyDict::new(py).into())
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_event")]
+    fn py_ts_event(&self) -> u64 {
+        self.ts_event.as_u64()
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_init")]
+    fn py_ts_init(&self) -> u64 {
+        self.ts_init.as_u64()
+    }
+
+    #[pyo3(name = "coupon_dates")]
+    fn py_coupon_dates(&self) -> Vec<u64> {
+        self.coupon_dates().iter().map(|ts| ts.as_u64()).collect()
+    }
+
+    #[pyo3(name = "accrued_interest")]
+    fn py_accrued_interest(&self, settlement_ns: u64) -> Decimal {
+        self.accrued_interest(settlement_ns.into())
+    }
+
+    #[pyo3(name = "accrued_interest_amount")]
+    fn py_accrued_interest_amount(&self, quantity: Quantity, settlement_ns: u64) -> Money {
+        self.accrued_interest_amount(quantity, settlement_ns.into())
+    }
+
+    #[pyo3(name = "dirty_price")]
+    fn py_dirty_price(&self, clean_price: Price, settlement_ns: u64) -> Decimal {
+        self.dirty_price(clean_price, settlement_ns.into())
+    }
+
+    #[pyo3(name = "clean_price")]
+    fn py_clean_price(&self, dirty_price: Decimal, settlement_ns: u64) -> Decimal {
+        self.clean_price(dirty_price, settlement_ns.into())
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_dict")]
+    fn py_from_dict(py: Python<'_>, values: Py<PyDict>) -> PyResult<Self> {
+        from_dict_pyo3(py, values)
+    }
+
+    #[pyo3(name = "to_dict")]
+    fn py_to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+        dict.set_item("type", stringify!(Bond))?;
+        dict.set_item("id", self.id.to_string())?;
+        dict.set_item("raw_symbol", self.raw_symbol.to_string())?;
+        dict.set_item("currency", self.currency.code.to_string())?;
+        dict.set_item("coupon_rate", self.coupon_rate.to_string())?;
+        dict.set_item("coupon_frequency", self.coupon_frequency)?;
+        dict.set_item("day_count", self.day_count.to_string())?;
+        dict.set_item("face_value", self.face_value.to_string())?;
+        dict.set_item("issue_ns", self.issue_ns.as_u64())?;
+        dict.set_item("maturity_ns", self.maturity_ns.as_u64())?;
+        dict.set_item("price_precision", self.price_precision)?;
+        dict.set_item("price_increment", self.price_increment.to_string())?;
+        dict.set_item("size_precision", self.size_precision)?;
+        dict.set_item("size_increment", self.size_increment.to_string())?;
+        dict.set_item("multiplier", self.multiplier.to_string())?;
+        dict.set_item("margin_init", self.margin_init.to_string())?;
+        dict.set_item("margin_maint", self.margin_maint.to_string())?;
+        dict.set_item("maker_fee", self.maker_fee.to_string())?;
+        dict.set_item("taker_fee", self.taker_fee.to_string())?;
+        dict.set_item("info", PyDict::new(py))?;
+        dict.set_item("ts_event", self.ts_event.as_u64())?;
+        dict.set_item("ts_init", self.ts_init.as_u64())?;
+        match self.isin {
+            Some(value) => dict.set_item("isin", value.to_string())?,
+            None => dict.set_item("isin", py.None())?,
+        }
+        match self.exchange {
+            Some(value) => dict.set_item("exchange", value.to_string())?,
+            None => dict.set_item("exchange", py.None())?,
+        }
+        match self.lot_size {
+            Some(value) => dict.set_item("lot_size", value.to_string())?,
+            None => dict.set_item("lot_size", py.None())?,
+        }
+        match self.max_quantity {
+            Some(value) => dict.set_item("max_quantity", value.to_string())?,
+            None => dict.set_item("max_quantity", py.None())?,
+        }
+        match self.min_quantity {
+            Some(value) => dict.set_item("min_quantity", value.to_string())?,
+            None => dict.set_item("min_quantity", py.None())?,
+        }
+        match self.max_price {
+            Some(value) => dict.set_item("max_price", value.to_string())?,
+            None => dict.set_item("max_price", py.None())?,
+        }
+        match self.min_price {
+            Some(value) => dict.set_item("min_price", value.to_string())?,
+            None => dict.set_item("min_price", py.None())?,
+        }
+        Ok(dict.into())
+    }
+}
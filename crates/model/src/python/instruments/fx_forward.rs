@@ -0,0 +1,367 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use nautilus_core::python::{
+    serialization::from_dict_pyo3, to_pyvalue_err, IntoPyObjectNautilusExt,
+};
+use pyo3::{basic::CompareOp, prelude::*, types::PyDict};
+use rust_decimal::Decimal;
+
+use crate::{
+    enums::{OrderSide, SettlementType},
+    identifiers::{InstrumentId, Symbol},
+    instruments::FxForward,
+    types::{Currency, Money, Price, Quantity},
+};
+
+#[pymethods]
+impl FxForward {
+    #[allow(clippy::too_many_arguments)]
+    #[new]
+    #[pyo3(signature = (id, raw_symbol, base_currency, quote_currency, settlement_currency, settlement_type, value_date_ns, points_precision, price_precision, price_increment, size_precision, size_increment, ts_event, ts_init, fixing_ns=None, lot_size=None, max_quantity=None, min_quantity=None, max_price=None, min_price=None, margin_init=None, margin_maint=None, maker_fee=None, taker_fee=None))]
+    fn py_new(
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        base_currency: Currency,
+        quote_currency: Currency,
+        settlement_currency: Currency,
+        settlement_type: SettlementType,
+        value_date_ns: u64,
+        points_precision: u8,
+        price_precision: u8,
+        price_increment: Price,
+        size_precision: u8,
+        size_increment: Quantity,
+        ts_event: u64,
+        ts_init: u64,
+        fixing_ns: Option<u64>,
+        lot_size: Option<Quantity>,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_price: Option<Price>,
+        min_price: Option<Price>,
+        margin_init: Option<Decimal>,
+        margin_maint: Option<Decimal>,
+        maker_fee: Option<Decimal>,
+        taker_fee: Option<Decimal>,
+    ) -> PyResult<Self> {
+        Self::new_checked(
+            id,
+            raw_symbol,
+            base_currency,
+            quote_currency,
+            settlement_currency,
+            settlement_type,
+            value_date_ns.into(),
+            fixing_ns.map(Into::into),
+            points_precision,
+            price_precision,
+            price_increment,
+            size_precision,
+            size_increment,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_price,
+            min_price,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+            ts_event.into(),
+            ts_init.into(),
+        )
+        .map_err(to_pyvalue_err)
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        match op {
+            CompareOp::Ne => self.ne(other).into_py_any_unwrap(py),
+            CompareOp::Eq => self.eq(other).into_py_any_unwrap(py),
+            _ => py.NotImplemented(),
+        }
+    }
+
+    fn __hash__(&self) -> isize {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish() as isize
+    }
+
+    #[getter]
+    fn type_str(&self) -> &str {
+        stringify!(FxForward)
+    }
+
+    #[getter]
+    #[pyo3(name = "id")]
+    fn py_id(&self) -> InstrumentId {
+        self.id
+    }
+
+    #[getter]
+    #[pyo3(name = "raw_symbol")]
+    fn py_raw_symbol(&self) -> Symbol {
+        self.raw_symbol
+    }
+
+    #[getter]
+    #[pyo3(name = "base_currency")]
+    fn py_base_currency(&self) -> Currency {
+        self.base_currency
+    }
+
+    #[getter]
+    #[pyo3(name = "quote_currency")]
+    fn py_quote_currency(&self) -> Currency {
+        self.quote_currency
+    }
+
+    #[getter]
+    #[pyo3(name = "settlement_currency")]
+    fn py_settlement_currency(&self) -> Currency {
+        self.settlement_currency
+    }
+
+    #[getter]
+    #[pyo3(name = "settlement_type")]
+    fn py_settlement_type(&self) -> SettlementType {
+        self.settlement_type
+    }
+
+    #[getter]
+    #[pyo3(name = "value_date_ns")]
+    fn py_value_date_ns(&self) -> u64 {
+        self.value_date_ns.as_u64()
+    }
+
+    #[getter]
+    #[pyo3(name = "fixing_ns")]
+    fn py_fixing_ns(&self) -> Option<u64> {
+        self.fixing_ns.map(|ts| ts.as_u64())
+    }
+
+    #[getter]
+    #[pyo3(name = "points_precision")]
+    fn py_points_precision(&self) -> u8 {
+        self.points_precision
+    }
+
+    #[getter]
+    #[pyo3(name = "price_precision")]
+    fn py_price_precision(&self) -> u8 {
+        self.price_precision
+    }
+
+    #[getter]
+    #[pyo3(name = "price_increment")]
+    fn py_price_increment(&self) -> Price {
+        self.price_increment
+    }
+
+    #[getter]
+    #[pyo3(name = "size_precision")]
+    fn py_size_precision(&self) -> u8 {
+        self.size_precision
+    }
+
+    #[getter]
+    #[pyo3(name = "size_increment")]
+    fn py_size_increment(&self) -> Quantity {
+        self.size_increment
+    }
+
+    #[getter]
+    #[pyo3(name = "lot_size")]
+    fn py_lot_size(&self) -> Option<Quantity> {
+        self.lot_size
+    }
+
+    #[getter]
+    #[pyo3(name = "max_quantity")]
+    fn py_max_quantity(&self) -> Option<Quantity> {
+        self.max_quantity
+    }
+
+    #[getter]
+    #[pyo3(name = "min_quantity")]
+    fn py_min_quantity(&self) -> Option<Quantity> {
+        self.min_quantity
+    }
+
+    #[getter]
+    #[pyo3(name = "max_price")]
+    fn py_max_price(&self) -> Option<Price> {
+        self.max_price
+    }
+
+    #[getter]
+    #[pyo3(name = "min_price")]
+    fn py_min_price(&self) -> Option<Price> {
+        self.min_price
+    }
+
+    #[getter]
+    #[pyo3(name = "margin_init")]
+    fn py_margin_init(&self) -> Decimal {
+        self.margin_init
+    }
+
+    #[getter]
+    #[pyo3(name = "margin_maint")]
+    fn py_margin_maint(&self) -> Decimal {
+        self.margin_maint
+    }
+
+    #[getter]
+    #[pyo3(name = "maker_fee")]
+    fn py_maker_fee(&self) -> Decimal {
+        self.maker_fee
+    }
+
+    #[getter]
+    #[pyo3(name = "taker_fee")]
+    fn py_taker_fee(&self) -> Decimal {
+        self.taker_fee
+    }
+
+    #[getter]
+    #[pyo3(name = "info")]
+    fn py_info(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(PyDict::new(py).into())
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_event")]
+    fn py_ts_event(&self) -> u64 {
+        self.ts_event.as_u64()
+    }
+
+    #[getter]
+    #[pyo3(name = "ts_init")]
+    fn py_ts_init(&self) -> u64 {
+        self.ts_init.as_u64()
+    }
+
+    #[pyo3(name = "is_non_deliverable")]
+    fn py_is_non_deliverable(&self) -> bool {
+        self.is_non_deliverable()
+    }
+
+    #[pyo3(name = "days_to_value")]
+    fn py_days_to_value(&self, ts_now: u64) -> i64 {
+        self.days_to_value(ts_now.into())
+    }
+
+    #[pyo3(name = "forward_points")]
+    fn py_forward_points(&self, spot: Price, outright: Price) -> Decimal {
+        self.forward_points(spot, outright)
+    }
+
+    #[pyo3(name = "outright_price")]
+    fn py_outright_price(&self, spot: Price, points: Decimal) -> Price {
+        self.outright_price(spot, points)
+    }
+
+    #[pyo3(name = "settlement_amounts")]
+    fn py_settlement_amounts(
+        &self,
+        side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+    ) -> (Money, Money) {
+        self.settlement_amounts(side, quantity, price)
+    }
+
+    #[pyo3(name = "cash_settlement_amount")]
+    fn py_cash_settlement_amount(
+        &self,
+        side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+        fixing_price: Price,
+    ) -> Money {
+        self.cash_settlement_amount(side, quantity, price, fixing_price)
+    }
+
+    #[pyo3(name = "spot_value_date")]
+    fn py_spot_value_date(&self, trade_date_ns: u64) -> u64 {
+        self.spot_value_date(trade_date_ns.into()).as_u64()
+    }
+
+    #[staticmethod]
+    #[pyo3(name = "from_dict")]
+    fn py_from_dict(py: Python<'_>, values: Py<PyDict>) -> PyResult<Self> {
+        from_dict_pyo3(py, values)
+    }
+
+    #[pyo3(name = "to_dict")]
+    fn py_to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+        dict.set_item("type", stringify!(FxForward))?;
+        dict.set_item("id", self.id.to_string())?;
+        dict.set_item("raw_symbol", self.raw_symbol.to_string())?;
+        dict.set_item("base_currency", self.base_currency.code.to_string())?;
+        dict.set_item("quote_currency", self.quote_currency.code.to_string())?;
+        dict.set_item(
+            "settlement_currency",
+            self.settlement_currency.code.to_string(),
+        )?;
+        dict.set_item("settlement_type", self.settlement_type.to_string())?;
+        dict.set_item("value_date_ns", self.value_date_ns.as_u64())?;
+        dict.set_item("points_precision", self.points_precision)?;
+        dict.set_item("price_precision", self.price_precision)?;
+        dict.set_item("price_increment", self.price_increment.to_string())?;
+        dict.set_item("size_precision", self.size_precision)?;
+        dict.set_item("size_increment", self.size_increment.to_string())?;
+        dict.set_item("margin_init", self.margin_init.to_string())?;
+        dict.set_item("margin_maint", self.margin_maint.to_string())?;
+        dict.set_item("maker_fee", self.maker_fee.to_string())?;
+        dict.set_item("taker_fee", self.taker_fee.to_string())?;
+        dict.set_item("info", PyDict::new(py))?;
+        dict.set_item("ts_event", self.ts_event.as_u64())?;
+        dict.set_item("ts_init", self.ts_init.as_u64())?;
+        match self.fixing_ns {
+            Some(value) => dict.set_item("fixing_ns", value.as_u64())?,
+            None => dict.set_item("fixing_ns", py.None())?,
+        }
+        match self.lot_size {
+            Some(value) => dict.set_item("lot_size", value.to_string())?,
+            None => dict.set_item("lot_size", py.None())?,
+        }
+        match self.max_quantity {
+            Some(value) => dict.set_item("max_quantity", value.to_string())?,
+            None => dict.set_item("max_quantity", py.None())?,
+        }
+        match self.min_quantity {
+            Some(value) => dict.set_item("min_quantity", value.to_string())?,
+            None => dict.set_item("min_quantity", py.None())?,
+        }
+        match self.max_price {
+            Some(value) => dict.set_item("max_price", value.to_string())?,
+            None => dict.set_item("max_price", py.None())?,
+        }
+        match self.min_price {
+            Some(value) => dict.set_item("min_price", value.to_string())?,
+            None => dict.set_item("min_price", py.None())?,
+        }
+        Ok(dict.into())
+    }
+}
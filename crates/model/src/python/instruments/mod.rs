@@ -19,18 +19,21 @@ use nautilus_core::python::to_pyvalue_err;
 use pyo3::{IntoPyObjectExt, PyObject, PyResult, Python};
 
 use crate::instruments::{
-    BettingInstrument, BinaryOption, CryptoFuture, CryptoPerpetual, CurrencyPair, Equity,
-    FuturesContract, FuturesSpread, InstrumentAny, OptionContract, OptionSpread,
+    BettingInstrument, BinaryOption, Bond, CryptoFuture, CryptoPerpetual, CurrencyPair, Equity,
+    FuturesContract, FuturesSpread, FxForward, FxSwap, InstrumentAny, OptionContract, OptionSpread,
 };
 
 pub mod betting;
 pub mod binary_option;
+pub mod bond;
 pub mod crypto_future;
 pub mod crypto_perpetual;
 pub mod currency_pair;
 pub mod equity;
 pub mod futures_contract;
 pub mod futures_spread;
+pub mod fx_forward;
+pub mod fx_swap;
 pub mod option_contract;
 pub mod option_spread;
 
@@ -38,12 +41,15 @@ pub fn instrument_any_to_pyobject(py: Python, instrument: InstrumentAny) -> PyRe
     match instrument {
         InstrumentAny::Betting(inst) => inst.into_py_any(py),
         InstrumentAny::BinaryOption(inst) => inst.into_py_any(py),
+        InstrumentAny::Bond(inst) => inst.into_py_any(py),
         InstrumentAny::CryptoFuture(inst) => inst.into_py_any(py),
         InstrumentAny::CryptoPerpetual(inst) => inst.into_py_any(py),
         InstrumentAny::CurrencyPair(inst) => inst.into_py_any(py),
         InstrumentAny::Equity(inst) => inst.into_py_any(py),
         InstrumentAny::FuturesContract(inst) => inst.into_py_any(py),
         InstrumentAny::FuturesSpread(inst) => inst.into_py_any(py),
+        InstrumentAny::FxForward(inst) => inst.into_py_any(py),
+        InstrumentAny::FxSwap(inst) => inst.into_py_any(py),
         InstrumentAny::OptionContract(inst) => inst.into_py_any(py),
         InstrumentAny::OptionSpread(inst) => inst.into_py_any(py),
     }
@@ -57,6 +63,7 @@ pub fn pyobject_to_instrument_any(py: Python, instrument: PyObject) -> PyResult<
         stringify!(BinaryOption) => Ok(InstrumentAny::BinaryOption(
             instrument.extract::<BinaryOption>(py)?,
         )),
+        stringify!(Bond) => Ok(InstrumentAny::Bond(instrument.extract::<Bond>(py)?)),
         stringify!(CryptoFuture) => Ok(InstrumentAny::CryptoFuture(
             instrument.extract::<CryptoFuture>(py)?,
         )),
@@ -73,6 +80,10 @@ pub fn pyobject_to_instrument_any(py: Python, instrument: PyObject) -> PyResult<
         stringify!(FuturesSpread) => Ok(InstrumentAny::FuturesSpread(
             instrument.extract::<FuturesSpread>(py)?,
         )),
+        stringify!(FxForward) => Ok(InstrumentAny::FxForward(
+            instrument.extract::<FxForward>(py)?,
+        )),
+        stringify!(FxSwap) => Ok(InstrumentAny::FxSwap(instrument.extract::<FxSwap>(py)?)),
         stringify!(OptionContract) => Ok(InstrumentAny::OptionContract(
             instrument.extract::<OptionContract>(py)?,
         )),
@@ -26,7 +26,7 @@ use rust_decimal::Decimal;
 use ustr::Ustr;
 
 use crate::{
-    enums::{AssetClass, OptionKind},
+    enums::{AssetClass, ExerciseStyle, OptionKind, SettlementType},
     identifiers::{InstrumentId, Symbol},
     instruments::OptionContract,
     types::{Currency, Price, Quantity},
@@ -36,7 +36,7 @@ use crate::{
 impl OptionContract {
     #[allow(clippy::too_many_arguments)]
     #[new]
-    #[pyo3(signature = (id, raw_symbol, asset_class, underlying, option_kind, strike_price, currency, activation_ns, expiration_ns, price_precision, price_increment, multiplier, lot_size, ts_event, ts_init,  max_quantity=None, min_quantity=None, max_price=None, min_price=None, margin_init=None, margin_maint=None, maker_fee=None, taker_fee=None, exchange=None))]
+    #[pyo3(signature = (id, raw_symbol, asset_class, underlying, option_kind, strike_price, currency, activation_ns, expiration_ns, price_precision, price_increment, multiplier, lot_size, ts_event, ts_init,  max_quantity=None, min_quantity=None, max_price=None, min_price=None, margin_init=None, margin_maint=None, maker_fee=None, taker_fee=None, exchange=None, exercise_style=None, settlement_type=None, premium_currency=None))]
     fn py_new(
         id: InstrumentId,
         raw_symbol: Symbol,
@@ -62,6 +62,9 @@ impl OptionContract {
         maker_fee: Option<Decimal>,
         taker_fee: Option<Decimal>,
         exchange: Option<String>,
+        exercise_style: Option<ExerciseStyle>,
+        settlement_type: Option<SettlementType>,
+        premium_currency: Option<Currency>,
     ) -> PyResult<Self> {
         Self::new_checked(
             id,
@@ -86,6 +89,9 @@ impl OptionContract {
             margin_maint,
             maker_fee,
             taker_fee,
+            exercise_style,
+            settlement_type,
+            premium_currency,
             ts_event.into(),
             ts_init.into(),
         )
@@ -147,6 +153,18 @@ impl OptionContract {
         self.option_kind
     }
 
+    #[getter]
+    #[pyo3(name = "exercise_style")]
+    fn py_exercise_style(&self) -> ExerciseStyle {
+        self.exercise_style
+    }
+
+    #[getter]
+    #[pyo3(name = "settlement_type")]
+    fn py_settlement_type(&self) -> SettlementType {
+        self.settlement_type
+    }
+
     #[getter]
     #[pyo3(name = "activation_ns")]
     fn py_activation_ns(&self) -> u64 {
@@ -171,6 +189,12 @@ impl OptionContract {
         self.currency
     }
 
+    #[getter]
+    #[pyo3(name = "premium_currency")]
+    fn py_premium_currency(&self) -> Currency {
+        self.premium_currency()
+    }
+
     #[getter]
     #[pyo3(name = "price_precision")]
     fn py_price_precision(&self) -> u8 {
@@ -276,6 +300,8 @@ impl OptionContract {
         dict.set_item("asset_class", self.asset_class.to_string())?;
         dict.set_item("underlying", self.underlying.to_string())?;
         dict.set_item("option_kind", self.option_kind.to_string())?;
+        dict.set_item("exercise_style", self.exercise_style.to_string())?;
+        dict.set_item("settlement_type", self.settlement_type.to_string())?;
         dict.set_item("activation_ns", self.activation_ns.as_u64())?;
         dict.set_item("expiration_ns", self.expiration_ns.as_u64())?;
         dict.set_item("strike_price", self.strike_price.to_string())?;
@@ -309,6 +335,10 @@ impl OptionContract {
             Some(value) => dict.set_item("min_price", value.to_string())?,
             None => dict.set_item("min_price", py.None())?,
         }
+        match self.premium_currency {
+            Some(value) => dict.set_item("premium_currency", value.code.to_string())?,
+            None => dict.set_item("premium_currency", py.None())?,
+        }
         match self.exchange {
             Some(value) => dict.set_item("exchange", value.to_string())?,
             None => dict.set_item("exchange", py.None())?,
@@ -23,12 +23,13 @@ use pyo3::{
     prelude::*,
     types::{PyDict, PyList},
 };
+use rust_decimal::Decimal;
 use ustr::Ustr;
 
 use crate::{
     enums::{
-        ContingencyType, LiquiditySide, OrderSide, OrderStatus, OrderType, PositionSide,
-        TimeInForce, TriggerType,
+        ContingencyType, LiquiditySide, OrderSide, OrderStatus, OrderType, PegPriceType,
+        PositionSide, TimeInForce, TriggerType,
     },
     events::order::initialized::OrderInitialized,
     identifiers::{
@@ -46,7 +47,7 @@ use crate::{
 impl LimitOrder {
     #[new]
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (trader_id, strategy_id, instrument_id, client_order_id, order_side, quantity, price, time_in_force, post_only, reduce_only, quote_quantity, init_id, ts_init, expire_time=None, display_qty=None, emulation_trigger=None, trigger_instrument_id=None, contingency_type=None, order_list_id=None, linked_order_ids=None, parent_order_id=None, exec_algorithm_id=None, exec_algorithm_params=None, exec_spawn_id=None, tags=None))]
+    #[pyo3(signature = (trader_id, strategy_id, instrument_id, client_order_id, order_side, quantity, price, time_in_force, post_only, reduce_only, quote_quantity, init_id, ts_init, expire_time=None, display_qty=None, emulation_trigger=None, trigger_instrument_id=None, contingency_type=None, order_list_id=None, linked_order_ids=None, parent_order_id=None, exec_algorithm_id=None, exec_algorithm_params=None, exec_spawn_id=None, tags=None, peg_price_type=None, peg_offset=None))]
     fn py_new(
         trader_id: TraderId,
         strategy_id: StrategyId,
@@ -73,6 +74,8 @@ impl LimitOrder {
         exec_algorithm_params: Option<IndexMap<String, String>>,
         exec_spawn_id: Option<ClientOrderId>,
         tags: Option<Vec<String>>,
+        peg_price_type: Option<PegPriceType>,
+        peg_offset: Option<Decimal>,
     ) -> PyResult<Self> {
         let exec_algorithm_params = exec_algorithm_params.map(str_indexmap_to_ustr);
         Self::new(
@@ -83,6 +86,8 @@ impl LimitOrder {
             order_side,
             quantity,
             price,
+            peg_price_type,
+            peg_offset,
             time_in_force,
             expire_time.map(UnixNanos::from),
             post_only,
@@ -499,6 +504,8 @@ impl LimitOrder {
             order_side,
             quantity,
             price,
+            None,
+            None,
             time_in_force,
             expire_time,
             is_post_only,
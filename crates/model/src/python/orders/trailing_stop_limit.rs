@@ -37,7 +37,7 @@ use crate::{
 impl TrailingStopLimitOrder {
     #[new]
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (trader_id, strategy_id, instrument_id, client_order_id, order_side, quantity, price, trigger_price, trigger_type, limit_offset, trailing_offset, trailing_offset_type, time_in_force, post_only, reduce_only, quote_quantity, init_id, ts_init, expire_time=None, display_qty=None, emulation_trigger=None, trigger_instrument_id=None, contingency_type=None, order_list_id=None, linked_order_ids=None, parent_order_id=None, exec_algorithm_id=None, exec_algorithm_params=None, exec_spawn_id=None, tags=None))]
+    #[pyo3(signature = (trader_id, strategy_id, instrument_id, client_order_id, order_side, quantity, price, trigger_price, trigger_type, limit_offset, trailing_offset, trailing_offset_type, time_in_force, post_only, reduce_only, quote_quantity, init_id, ts_init, expire_time=None, display_qty=None, emulation_trigger=None, trigger_instrument_id=None, contingency_type=None, order_list_id=None, linked_order_ids=None, parent_order_id=None, exec_algorithm_id=None, exec_algorithm_params=None, exec_spawn_id=None, tags=None, activation_price=None))]
     fn py_new(
         trader_id: TraderId,
         strategy_id: StrategyId,
@@ -69,6 +69,7 @@ impl TrailingStopLimitOrder {
         exec_algorithm_params: Option<IndexMap<String, String>>,
         exec_spawn_id: Option<ClientOrderId>,
         tags: Option<Vec<String>>,
+        activation_price: Option<Price>,
     ) -> Self {
         let exec_algorithm_params = exec_algorithm_params.map(str_indexmap_to_ustr);
         Self::new(
@@ -84,6 +85,7 @@ impl TrailingStopLimitOrder {
             limit_offset,
             trailing_offset,
             trailing_offset_type,
+            activation_price,
             time_in_force,
             expire_time.map(std::convert::Into::into),
             post_only,
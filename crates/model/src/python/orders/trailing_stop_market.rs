@@ -37,7 +37,7 @@ use crate::{
 impl TrailingStopMarketOrder {
     #[new]
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (trader_id, strategy_id, instrument_id, client_order_id, order_side, quantity, trigger_price, trigger_type, trailing_offset, trailing_offset_type, time_in_force, reduce_only, quote_quantity, init_id, ts_init, expire_time=None, display_qty=None, emulation_trigger=None, trigger_instrument_id=None, contingency_type=None, order_list_id=None, linked_order_ids=None, parent_order_id=None, exec_algorithm_id=None, exec_algorithm_params=None, exec_spawn_id=None, tags=None))]
+    #[pyo3(signature = (trader_id, strategy_id, instrument_id, client_order_id, order_side, quantity, trigger_price, trigger_type, trailing_offset, trailing_offset_type, time_in_force, reduce_only, quote_quantity, init_id, ts_init, expire_time=None, display_qty=None, emulation_trigger=None, trigger_instrument_id=None, contingency_type=None, order_list_id=None, linked_order_ids=None, parent_order_id=None, exec_algorithm_id=None, exec_algorithm_params=None, exec_spawn_id=None, tags=None, activation_price=None))]
     fn py_new(
         trader_id: TraderId,
         strategy_id: StrategyId,
@@ -66,6 +66,7 @@ impl TrailingStopMarketOrder {
         exec_algorithm_params: Option<IndexMap<String, String>>,
         exec_spawn_id: Option<ClientOrderId>,
         tags: Option<Vec<String>>,
+        activation_price: Option<Price>,
     ) -> Self {
         let exec_algorithm_params = exec_algorithm_params.map(str_indexmap_to_ustr);
         Self::new(
@@ -79,6 +80,7 @@ impl TrailingStopMarketOrder {
             trigger_type,
             trailing_offset,
             trailing_offset_type,
+            activation_price,
             time_in_force,
             expire_time.map(std::convert::Into::into),
             reduce_only,
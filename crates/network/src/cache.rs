@@ -0,0 +1,344 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An HTTP response cache honoring the `ETag`, `Last-Modified` and `Cache-Control` headers.
+//!
+//! Responses to `GET` requests are cached by URL. A response is served from the cache while
+//! it is fresh (within its `max-age`), after which the request is revalidated with the
+//! `If-None-Match` and `If-Modified-Since` headers, and a `304 Not Modified` response is
+//! answered with the cached response.
+
+use std::{collections::HashMap, time::Duration};
+
+use dashmap::DashMap;
+
+use crate::http::{header_value, HttpResponse};
+
+/// The response header with the entity tag of the resource.
+pub const ETAG_HEADER: &str = "ETag";
+
+/// The response header with the time the resource was last modified.
+pub const LAST_MODIFIED_HEADER: &str = "Last-Modified";
+
+/// The response header with the caching directives.
+pub const CACHE_CONTROL_HEADER: &str = "Cache-Control";
+
+/// The request header to revalidate a cached response by entity tag.
+pub const IF_NONE_MATCH_HEADER: &str = "If-None-Match";
+
+/// The request header to revalidate a cached response by modification time.
+pub const IF_MODIFIED_SINCE_HEADER: &str = "If-Modified-Since";
+
+/// The caching directives of a `Cache-Control` response header.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct CacheControl {
+    no_store: bool,
+    no_cache: bool,
+    max_age: Option<u64>,
+}
+
+impl CacheControl {
+    fn parse(value: &str) -> Self {
+        let mut cache_control = Self::default();
+        for directive in value.split(',').map(str::trim) {
+            let (name, arg) = directive
+                .split_once('=')
+                .map_or((directive, None), |(name, arg)| (name.trim(), Some(arg)));
+            match name.to_ascii_lowercase().as_str() {
+                "no-store" => cache_control.no_store = true,
+                "no-cache" => cache_control.no_cache = true,
+                "max-age" => {
+                    cache_control.max_age =
+                        arg.and_then(|arg| arg.trim().trim_matches('"').parse().ok());
+                }
+                _ => {}
+            }
+        }
+        cache_control
+    }
+
+    fn from_headers(headers: &HashMap<String, String>) -> Self {
+        header_value(headers, CACHE_CONTROL_HEADER).map_or_else(Self::default, Self::parse)
+    }
+
+    /// Returns the time (since the UNIX epoch) a response received at `now` is fresh until.
+    fn fresh_until(&self, now: Duration) -> Option<Duration> {
+        if self.no_cache {
+            return None;
+        }
+        self.max_age
+            .map(|max_age| now + Duration::from_secs(max_age))
+    }
+}
+
+#[derive(Clone, Debug)]
+struct CacheEntry {
+    response: HttpResponse,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    fresh_until: Option<Duration>,
+}
+
+impl CacheEntry {
+    fn is_fresh(&self, now: Duration) -> bool {
+        self.fresh_until
+            .is_some_and(|fresh_until| now < fresh_until)
+    }
+}
+
+/// A cache of HTTP responses keyed by URL.
+///
+/// Successful responses are cached when they can be revalidated (with an `ETag` or
+/// `Last-Modified` header) or have a `max-age`, unless the response has a `no-store` directive.
+/// The cache is keyed by URL only, so should not be used for responses which vary by the
+/// request headers.
+#[derive(Debug, Default)]
+pub struct HttpResponseCache {
+    entries: DashMap<String, CacheEntry>,
+}
+
+impl HttpResponseCache {
+    /// Creates a new empty [`HttpResponseCache`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of cached responses.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether there are no cached responses.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes all cached responses.
+    pub fn clear(&self) {
+        self.entries.clear();
+    }
+
+    /// Returns the cached response for the `url` if it is still fresh at `now`.
+    #[must_use]
+    pub fn get_fresh(&self, url: &str, now: Duration) -> Option<HttpResponse> {
+        self.entries
+            .get(url)
+            .filter(|entry| entry.is_fresh(now))
+            .map(|entry| entry.response.clone())
+    }
+
+    /// Adds the headers to revalidate the cached response for the `url` (if any) to `headers`.
+    pub fn add_validators(&self, url: &str, headers: &mut HashMap<String, String>) {
+        let Some(entry) = self.entries.get(url) else {
+            return;
+        };
+        if let Some(etag) = &entry.etag {
+            headers.insert(IF_NONE_MATCH_HEADER.to_string(), etag.clone());
+        }
+        if let Some(last_modified) = &entry.last_modified {
+            headers.insert(IF_MODIFIED_SINCE_HEADER.to_string(), last_modified.clone());
+        }
+    }
+
+    /// Updates the cache with the `response` for the `url` received at `now`, returning
+    /// the response to use.
+    ///
+    /// A `304 Not Modified` response is answered with the cached response (refreshing its
+    /// freshness), and a `200 OK` response replaces the cached response.
+    pub fn update(&self, url: &str, response: HttpResponse, now: Duration) -> HttpResponse {
+        let cache_control = CacheControl::from_headers(&response.headers);
+
+        match response.status.as_u16() {
+            304 => {
+                let Some(mut entry) = self.entries.get_mut(url) else {
+                    return response;
+                };
+                if cache_control.no_store {
+                    let cached = entry.response.clone();
+                    drop(entry);
+                    self.entries.remove(url);
+                    return cached;
+                }
+                if let Some(etag) = header_value(&response.headers, ETAG_HEADER) {
+                    entry.etag = Some(etag.to_string());
+                }
+                entry.fresh_until = cache_control.fresh_until(now);
+                tracing::trace!("Revalidated cached response for {url}");
+                entry.response.clone()
+            }
+            200 => {
+                let etag = header_value(&response.headers, ETAG_HEADER).map(ToString::to_string);
+                let last_modified =
+                    header_value(&response.headers, LAST_MODIFIED_HEADER).map(ToString::to_string);
+                let fresh_until = cache_control.fresh_until(now);
+
+                if cache_control.no_store
+                    || (etag.is_none() && last_modified.is_none() && fresh_until.is_none())
+                {
+                    self.entries.remove(url);
+                    return response;
+                }
+
+                self.entries.insert(
+                    url.to_string(),
+                    CacheEntry {
+                        response: response.clone(),
+                        etag,
+                        last_modified,
+                        fresh_until,
+                    },
+                );
+                response
+            }
+            _ => response,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::http::HttpStatus;
+
+    const URL: &str = "https://api.venue.com/instruments";
+
+    fn response(status: StatusCode, headers: &[(&str, &str)], body: &'static str) -> HttpResponse {
+        HttpResponse {
+            status: HttpStatus::new(status),
+            headers: headers
+                .iter()
+                .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+                .collect(),
+            body: body.into(),
+        }
+    }
+
+    #[rstest]
+    #[case("max-age=60", CacheControl { no_store: false, no_cache: false, max_age: Some(60) })]
+    #[case("public, max-age=\"30\"", CacheControl { no_store: false, no_cache: false, max_age: Some(30) })]
+    #[case("no-cache, max-age=60", CacheControl { no_store: false, no_cache: true, max_age: Some(60) })]
+    #[case("No-Store", CacheControl { no_store: true, no_cache: false, max_age: None })]
+    #[case("private", CacheControl::default())]
+    fn test_parse_cache_control(#[case] value: &str, #[case] expected: CacheControl) {
+        assert_eq!(CacheControl::parse(value), expected);
+    }
+
+    #[rstest]
+    fn test_fresh_response_served_until_max_age() {
+        let cache = HttpResponseCache::new();
+        let now = Duration::from_secs(1_000);
+        cache.update(
+            URL,
+            response(StatusCode::OK, &[("Cache-Control", "max-age=60")], "data"),
+            now,
+        );
+
+        assert_eq!(cache.len(), 1);
+        assert_eq!(
+            cache
+                .get_fresh(URL, now + Duration::from_secs(59))
+                .unwrap()
+                .body,
+            "data"
+        );
+        assert!(cache
+            .get_fresh(URL, now + Duration::from_secs(60))
+            .is_none());
+    }
+
+    #[rstest]
+    fn test_revalidate_with_etag() {
+        let cache = HttpResponseCache::new();
+        let now = Duration::from_secs(1_000);
+        cache.update(
+            URL,
+            response(
+                StatusCode::OK,
+                &[
+                    ("etag", "\"v1\""),
+                    ("Last-Modified", "Wed, 21 Oct 2015 07:28:00 GMT"),
+                ],
+                "data",
+            ),
+            now,
+        );
+        assert!(cache.get_fresh(URL, now).is_none());
+
+        let mut headers = HashMap::new();
+        cache.add_validators(URL, &mut headers);
+        assert_eq!(headers.get(IF_NONE_MATCH_HEADER).unwrap(), "\"v1\"");
+        assert_eq!(
+            headers.get(IF_MODIFIED_SINCE_HEADER).unwrap(),
+            "Wed, 21 Oct 2015 07:28:00 GMT"
+        );
+
+        let revalidated = cache.update(URL, response(StatusCode::NOT_MODIFIED, &[], ""), now);
+
+        assert_eq!(revalidated.status.as_u16(), 200);
+        assert_eq!(revalidated.body, "data");
+    }
+
+    #[rstest]
+    fn test_modified_response_replaces_cached() {
+        let cache = HttpResponseCache::new();
+        let now = Duration::from_secs(1_000);
+        cache.update(URL, response(StatusCode::OK, &[("ETag", "v1")], "old"), now);
+        cache.update(URL, response(StatusCode::OK, &[("ETag", "v2")], "new"), now);
+
+        let mut headers = HashMap::new();
+        cache.add_validators(URL, &mut headers);
+        let revalidated = cache.update(URL, response(StatusCode::NOT_MODIFIED, &[], ""), now);
+
+        assert_eq!(headers.get(IF_NONE_MATCH_HEADER).unwrap(), "v2");
+        assert_eq!(revalidated.body, "new");
+    }
+
+    #[rstest]
+    #[case(StatusCode::OK, &[("ETag", "v1"), ("Cache-Control", "no-store")])]
+    #[case(StatusCode::OK, &[])]
+    #[case(StatusCode::NOT_FOUND, &[("ETag", "v1")])]
+    fn test_response_not_cached(#[case] status: StatusCode, #[case] headers: &[(&str, &str)]) {
+        let cache = HttpResponseCache::new();
+        cache.update(URL, response(status, headers, "data"), Duration::ZERO);
+
+        assert!(cache.is_empty());
+    }
+
+    #[rstest]
+    fn test_no_store_evicts_cached_response() {
+        let cache = HttpResponseCache::new();
+        cache.update(
+            URL,
+            response(StatusCode::OK, &[("ETag", "v1")], "data"),
+            Duration::ZERO,
+        );
+        cache.update(
+            URL,
+            response(StatusCode::OK, &[("Cache-Control", "no-store")], "data"),
+            Duration::ZERO,
+        );
+
+        assert!(cache.is_empty());
+    }
+}
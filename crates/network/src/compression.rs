@@ -0,0 +1,155 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Decompression of compressed WebSocket message payloads.
+//!
+//! Some venues send binary messages with a compressed payload rather than negotiating the
+//! `permessage-deflate` extension, for example Huobi (gzip) and OKX (raw deflate). The
+//! `permessage-deflate` extension itself is not negotiated, as the underlying `tungstenite`
+//! version does not support compressed frames (the client requests no extensions, so
+//! servers send uncompressed frames).
+
+use std::io::{self, Read};
+
+use flate2::read::{DeflateDecoder, GzDecoder, ZlibDecoder};
+use strum::{AsRefStr, Display, EnumString};
+
+/// The compression format of binary WebSocket message payloads.
+#[derive(Clone, Copy, Debug, Display, Hash, PartialEq, Eq, AsRefStr, EnumString)]
+#[strum(ascii_case_insensitive, serialize_all = "UPPERCASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.network")
+)]
+pub enum PayloadCompression {
+    /// Gzip (RFC 1952) compressed payloads.
+    Gzip,
+    /// Zlib (RFC 1950) compressed payloads.
+    Zlib,
+    /// Raw deflate (RFC 1951) compressed payloads.
+    Deflate,
+    /// Gzip or zlib compressed payloads (detected from the header), otherwise uncompressed.
+    Auto,
+}
+
+/// The magic bytes of a gzip stream.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Returns whether `data` starts with a valid zlib header (deflate method with a valid check).
+fn is_zlib(data: &[u8]) -> bool {
+    match data {
+        [cmf, flg, ..] => cmf & 0x0f == 8 && (u16::from(*cmf) << 8 | u16::from(*flg)) % 31 == 0,
+        _ => false,
+    }
+}
+
+/// Decompresses the binary message payload `data` with the given `compression`.
+///
+/// With [`PayloadCompression::Auto`] payloads which are not gzip or zlib compressed
+/// are returned unchanged.
+///
+/// # Errors
+///
+/// Returns an error if the payload is not valid for the compression format.
+pub fn decompress_payload(data: &[u8], compression: PayloadCompression) -> io::Result<Vec<u8>> {
+    let mut decompressed = Vec::with_capacity(data.len() * 4);
+    match compression {
+        PayloadCompression::Gzip => GzDecoder::new(data).read_to_end(&mut decompressed)?,
+        PayloadCompression::Zlib => ZlibDecoder::new(data).read_to_end(&mut decompressed)?,
+        PayloadCompression::Deflate => DeflateDecoder::new(data).read_to_end(&mut decompressed)?,
+        PayloadCompression::Auto if data.starts_with(&GZIP_MAGIC) => {
+            GzDecoder::new(data).read_to_end(&mut decompressed)?
+        }
+        PayloadCompression::Auto if is_zlib(data) => {
+            ZlibDecoder::new(data).read_to_end(&mut decompressed)?
+        }
+        PayloadCompression::Auto => return Ok(data.to_vec()),
+    };
+    Ok(decompressed)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::{
+        write::{DeflateEncoder, GzEncoder, ZlibEncoder},
+        Compression,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    const PAYLOAD: &[u8] = br#"{"ch":"market.btcusdt.trade.detail","ts":1630994963175}"#;
+
+    fn compress(compression: PayloadCompression) -> Vec<u8> {
+        match compression {
+            PayloadCompression::Gzip => {
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(PAYLOAD).unwrap();
+                encoder.finish().unwrap()
+            }
+            PayloadCompression::Zlib => {
+                let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(PAYLOAD).unwrap();
+                encoder.finish().unwrap()
+            }
+            PayloadCompression::Deflate => {
+                let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(PAYLOAD).unwrap();
+                encoder.finish().unwrap()
+            }
+            PayloadCompression::Auto => PAYLOAD.to_vec(),
+        }
+    }
+
+    #[rstest]
+    #[case(PayloadCompression::Gzip, PayloadCompression::Gzip)]
+    #[case(PayloadCompression::Zlib, PayloadCompression::Zlib)]
+    #[case(PayloadCompression::Deflate, PayloadCompression::Deflate)]
+    #[case(PayloadCompression::Gzip, PayloadCompression::Auto)]
+    #[case(PayloadCompression::Zlib, PayloadCompression::Auto)]
+    #[case(PayloadCompression::Auto, PayloadCompression::Auto)]
+    fn test_decompress_payload(
+        #[case] compressed_with: PayloadCompression,
+        #[case] compression: PayloadCompression,
+    ) {
+        let data = compress(compressed_with);
+
+        let result = decompress_payload(&data, compression).unwrap();
+
+        assert_eq!(result, PAYLOAD);
+    }
+
+    #[rstest]
+    fn test_decompress_invalid_payload() {
+        let result = decompress_payload(PAYLOAD, PayloadCompression::Gzip);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case("gzip", PayloadCompression::Gzip)]
+    #[case("DEFLATE", PayloadCompression::Deflate)]
+    fn test_payload_compression_from_str(
+        #[case] value: &str,
+        #[case] expected: PayloadCompression,
+    ) {
+        assert_eq!(value.parse::<PayloadCompression>().unwrap(), expected);
+    }
+}
@@ -0,0 +1,223 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Framing strategies for messages on a raw byte stream.
+//!
+//! A [`SocketFraming`] encodes outbound messages into frames, and decodes complete
+//! messages from the buffer of received bytes:
+//! - Delimited messages, such as newline delimited JSON (`b"\n"`) or `b"\r\n"`.
+//! - Length prefixed messages, with a big-endian unsigned integer header of the body length.
+//! - FIX messages, with SOH (0x01) delimited fields ending with the `10=` checksum field.
+
+/// The FIX field delimiter.
+const SOH: u8 = 0x01;
+
+/// The start of the FIX checksum field, which is the last field of a message.
+const FIX_CHECKSUM_FIELD: &[u8] = b"\x0110=";
+
+/// A strategy for framing messages on a raw byte stream.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, module = "nautilus_trader.core.nautilus_pyo3.network")
+)]
+pub enum SocketFraming {
+    /// Messages are separated by the delimiter, which is appended to each sent message.
+    Delimiter { delimiter: Vec<u8> },
+    /// Messages are prefixed with their length as a big-endian unsigned integer of
+    /// `header_len` bytes (1, 2, 4 or 8), excluding the header.
+    LengthPrefixed { header_len: u8 },
+    /// FIX messages, which end with the SOH terminated `10=` checksum field.
+    Fix {},
+}
+
+impl SocketFraming {
+    /// Creates a newline (`\n`) delimited framing, such as for newline delimited JSON.
+    #[must_use]
+    pub fn newline() -> Self {
+        Self::Delimiter {
+            delimiter: b"\n".to_vec(),
+        }
+    }
+
+    /// Checks the framing is valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delimiter is empty, or the length header is not 1, 2, 4 or 8 bytes.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        match self {
+            Self::Delimiter { delimiter } if delimiter.is_empty() => {
+                anyhow::bail!("invalid `delimiter`, was empty")
+            }
+            Self::LengthPrefixed { header_len } if !matches!(header_len, 1 | 2 | 4 | 8) => {
+                anyhow::bail!("invalid `header_len`, expected 1, 2, 4 or 8, was {header_len}")
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Encodes the message `data` into a frame.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `data` cannot be represented by the length header.
+    #[must_use]
+    pub fn encode(&self, data: &[u8]) -> Vec<u8> {
+        match self {
+            Self::Delimiter { delimiter } => {
+                let mut frame = Vec::with_capacity(data.len() + delimiter.len());
+                frame.extend_from_slice(data);
+                frame.extend_from_slice(delimiter);
+                frame
+            }
+            Self::LengthPrefixed { header_len } => {
+                let header_len = usize::from(*header_len);
+                let len = data.len() as u64;
+                assert!(
+                    header_len == 8 || len < 1 << (header_len * 8),
+                    "message length {len} exceeds the {header_len} byte length header"
+                );
+                let mut frame = Vec::with_capacity(header_len + data.len());
+                frame.extend_from_slice(&len.to_be_bytes()[8 - header_len..]);
+                frame.extend_from_slice(data);
+                frame
+            }
+            Self::Fix {} => data.to_vec(),
+        }
+    }
+
+    /// Decodes the next complete message from the received bytes in `buf`, draining its frame.
+    ///
+    /// Returns `None` when `buf` does not yet contain a complete message.
+    pub fn decode(&self, buf: &mut Vec<u8>) -> Option<Vec<u8>> {
+        match self {
+            Self::Delimiter { delimiter } => {
+                let i = find(buf, delimiter)?;
+                let mut data: Vec<u8> = buf.drain(..i + delimiter.len()).collect();
+                data.truncate(i);
+                Some(data)
+            }
+            Self::LengthPrefixed { header_len } => {
+                let header_len = usize::from(*header_len);
+                if buf.len() < header_len {
+                    return None;
+                }
+                let mut len_bytes = [0u8; 8];
+                len_bytes[8 - header_len..].copy_from_slice(&buf[..header_len]);
+                let len = usize::try_from(u64::from_be_bytes(len_bytes)).ok()?;
+                if buf.len() < header_len + len {
+                    return None;
+                }
+                let data = buf.drain(..header_len + len).skip(header_len).collect();
+                Some(data)
+            }
+            Self::Fix {} => {
+                let checksum_start = find(buf, FIX_CHECKSUM_FIELD)? + FIX_CHECKSUM_FIELD.len();
+                let end = checksum_start + buf[checksum_start..].iter().position(|b| *b == SOH)?;
+                Some(buf.drain(..=end).collect())
+            }
+        }
+    }
+}
+
+/// Returns the index of the first occurrence of `pattern` in `buf`.
+fn find(buf: &[u8], pattern: &[u8]) -> Option<usize> {
+    buf.windows(pattern.len())
+        .position(|window| window == pattern)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const FIX_MESSAGE: &[u8] = b"8=FIX.4.4\x019=5\x0135=0\x0110=163\x01";
+
+    #[rstest]
+    #[case(SocketFraming::newline(), b"{\"a\":1}\n".to_vec())]
+    #[case(SocketFraming::Delimiter { delimiter: b"\r\n".to_vec() }, b"{\"a\":1}\r\n".to_vec())]
+    #[case(SocketFraming::LengthPrefixed { header_len: 2 }, b"\x00\x07{\"a\":1}".to_vec())]
+    #[case(SocketFraming::LengthPrefixed { header_len: 4 }, b"\x00\x00\x00\x07{\"a\":1}".to_vec())]
+    fn test_encode_decode_round_trip(#[case] framing: SocketFraming, #[case] expected: Vec<u8>) {
+        let frame = framing.encode(b"{\"a\":1}");
+        let mut buf = frame.clone();
+
+        let decoded = framing.decode(&mut buf);
+
+        assert_eq!(frame, expected);
+        assert_eq!(decoded.unwrap(), b"{\"a\":1}");
+        assert!(buf.is_empty());
+    }
+
+    #[rstest]
+    #[case(SocketFraming::newline())]
+    #[case(SocketFraming::LengthPrefixed { header_len: 4 })]
+    #[case(SocketFraming::Fix {})]
+    fn test_decode_partial_frames(#[case] framing: SocketFraming) {
+        let mut stream = framing.encode(FIX_MESSAGE);
+        stream.extend(framing.encode(b"second"));
+        let mut buf = Vec::new();
+        let mut messages = Vec::new();
+
+        // Receive the stream a few bytes at a time
+        for chunk in stream.chunks(3) {
+            buf.extend_from_slice(chunk);
+            while let Some(message) = framing.decode(&mut buf) {
+                messages.push(message);
+            }
+        }
+
+        assert_eq!(messages[0], FIX_MESSAGE);
+        if framing == (SocketFraming::Fix {}) {
+            // Unterminated data remains buffered until the checksum field is received
+            assert_eq!(messages.len(), 1);
+            assert_eq!(buf, b"second");
+        } else {
+            assert_eq!(messages, vec![FIX_MESSAGE.to_vec(), b"second".to_vec()]);
+            assert!(buf.is_empty());
+        }
+    }
+
+    #[rstest]
+    fn test_decode_fix_ignores_checksum_tag_in_other_fields() {
+        let framing = SocketFraming::Fix {};
+        let mut buf = b"8=FIX.4.4\x019=12\x0135=0\x01110=5\x0110=000\x01".to_vec();
+
+        let decoded = framing.decode(&mut buf).unwrap();
+
+        assert!(decoded.ends_with(b"\x0110=000\x01"));
+        assert!(buf.is_empty());
+    }
+
+    #[rstest]
+    #[case(SocketFraming::Delimiter { delimiter: vec![] }, false)]
+    #[case(SocketFraming::LengthPrefixed { header_len: 3 }, false)]
+    #[case(SocketFraming::LengthPrefixed { header_len: 8 }, true)]
+    #[case(SocketFraming::Fix {}, true)]
+    fn test_validate(#[case] framing: SocketFraming, #[case] expected: bool) {
+        assert_eq!(framing.validate().is_ok(), expected);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "exceeds the 1 byte length header")]
+    fn test_encode_length_overflow() {
+        let _ = SocketFraming::LengthPrefixed { header_len: 1 }.encode(&[0; 256]);
+    }
+}
@@ -15,16 +15,40 @@
 
 //! A high-performance HTTP client implementation.
 
-use std::{collections::HashMap, hash::Hash, str::FromStr, sync::Arc, time::Duration};
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use bytes::Bytes;
 use http::{status::InvalidStatusCode, HeaderValue, StatusCode};
+use nautilus_core::time::duration_since_unix_epoch;
 use reqwest::{
     header::{HeaderMap, HeaderName},
     Method, Response, Url,
 };
 
-use crate::ratelimiter::{clock::MonotonicClock, quota::Quota, RateLimiter};
+use crate::{
+    cache::{HttpResponseCache, CACHE_CONTROL_HEADER, ETAG_HEADER, LAST_MODIFIED_HEADER},
+    latency::HttpLatency,
+    proxy::ProxyConfig,
+    ratelimiter::{
+        clock::{Clock, MonotonicClock},
+        quota::Quota,
+        RateLimiter,
+    },
+    retry::HttpRetryPolicy,
+    tls::TlsConfig,
+};
+
+/// The response header with the number of seconds to wait before making a new request.
+const RETRY_AFTER_HEADER: &str = "Retry-After";
+
+/// The pause for all requests when rate limited without a `Retry-After` header.
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
 
 /// Represents a HTTP status code.
 ///
@@ -150,6 +174,63 @@ pub struct HttpResponse {
     pub body: Bytes,
 }
 
+/// A venue rate limit reported through a response header, used to adaptively throttle requests.
+///
+/// The header reports the usage of the limit in the current interval, for example the Binance
+/// `X-MBX-USED-WEIGHT-1M` header reports the request weight used in the current minute. When the
+/// usage reaches the `threshold` fraction of the `limit`, requests for the `key` (or all requests
+/// if `None`) are paused until the interval resets, with intervals aligned to the UNIX epoch.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.network")
+)]
+pub struct RateLimitHeader {
+    /// The response header name (case-insensitive).
+    pub name: String,
+    /// The limit for each interval.
+    pub limit: u32,
+    /// The interval the limit applies to.
+    pub interval: Duration,
+    /// The fraction of the limit at which requests are paused (e.g. 0.9).
+    pub threshold: f64,
+    /// The rate limit key to pause, or `None` to pause all requests.
+    pub key: Option<String>,
+}
+
+impl RateLimitHeader {
+    /// Creates a new [`RateLimitHeader`] instance.
+    #[must_use]
+    pub const fn new(
+        name: String,
+        limit: u32,
+        interval: Duration,
+        threshold: f64,
+        key: Option<String>,
+    ) -> Self {
+        Self {
+            name,
+            limit,
+            interval,
+            threshold,
+            key,
+        }
+    }
+
+    /// Returns the pause until the interval resets if the `usage` reached the threshold,
+    /// where `now` is the duration since the UNIX epoch.
+    #[must_use]
+    pub fn pause_for(&self, usage: u32, now: Duration) -> Option<Duration> {
+        let interval_ns = self.interval.as_nanos();
+        if interval_ns == 0 || f64::from(usage) < f64::from(self.limit) * self.threshold {
+            return None;
+        }
+
+        let remaining_ns = interval_ns - now.as_nanos() % interval_ns;
+        Some(Duration::from_nanos(remaining_ns as u64))
+    }
+}
+
 /// Errors returned by the HTTP client.
 ///
 /// Includes generic transport errors and timeouts.
@@ -180,8 +261,10 @@ impl From<String> for HttpClientError {
 
 /// An HTTP client that supports rate limiting and timeouts.
 ///
-/// Built on `reqwest` for async I/O. Allows per-endpoint and default quotas
-/// through a rate limiter.
+/// Built on `reqwest` for async I/O. Allows global, per-endpoint and default quotas
+/// through a rate limiter, which is adaptively throttled from the venue rate limit
+/// response headers (and the `Retry-After` header of rate limited responses).
+/// Responses to `GET` requests can optionally be cached (see [`HttpResponseCache`]).
 ///
 /// This struct is designed to handle HTTP requests efficiently, providing
 /// support for rate limiting, timeouts, and custom headers. The client is
@@ -197,16 +280,36 @@ pub struct HttpClient {
     pub(crate) client: InnerHttpClient,
     /// The rate limiter to control the request rate.
     pub(crate) rate_limiter: Arc<RateLimiter<String, MonotonicClock>>,
+    /// The venue rate limit headers to throttle requests from.
+    pub(crate) rate_limit_headers: Arc<Vec<RateLimitHeader>>,
+    /// The optional cache for responses to `GET` requests.
+    pub(crate) cache: Option<Arc<HttpResponseCache>>,
+    /// The optional policy for retrying failed requests.
+    pub(crate) retry_policy: Option<Arc<HttpRetryPolicy>>,
 }
 
 impl HttpClient {
     /// Creates a new [`HttpClient`] instance.
+    ///
+    /// The `global_quota` applies to every request, in addition to the keyed (or default)
+    /// quotas for the request keys.
+    ///
+    /// Requests are sent through the `proxy` if given, otherwise through the proxy
+    /// configured by the environment (if any).
+    ///
+    /// The `tls` configuration adds trusted CA certificates and a client certificate
+    /// for venues which mandate mutual TLS.
     #[must_use]
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         headers: HashMap<String, String>,
         header_keys: Vec<String>,
         keyed_quotas: Vec<(String, Quota)>,
         default_quota: Option<Quota>,
+        global_quota: Option<Quota>,
+        rate_limit_headers: Vec<RateLimitHeader>,
+        proxy: Option<ProxyConfig>,
+        tls: Option<TlsConfig>,
     ) -> Self {
         // Build default headers
         let mut header_map = HeaderMap::new();
@@ -216,23 +319,85 @@ impl HttpClient {
             header_map.insert(header_name, header_value);
         }
 
-        let client = reqwest::Client::builder()
-            .default_headers(header_map)
-            .build()
-            .expect("Failed to build reqwest client");
+        let mut builder = reqwest::Client::builder().default_headers(header_map);
+        if let Some(proxy) = proxy {
+            builder = builder.proxy(proxy.to_reqwest().expect("Invalid proxy"));
+        }
+        if let Some(tls) = tls {
+            builder = tls
+                .apply_to_reqwest(builder)
+                .expect("Invalid TLS configuration");
+        }
+        let client = builder.build().expect("Failed to build reqwest client");
+
+        // Retain the headers required to throttle requests from the responses
+        let mut header_keys = header_keys;
+        let throttle_keys = std::iter::once(RETRY_AFTER_HEADER)
+            .chain(rate_limit_headers.iter().map(|header| header.name.as_str()));
+        retain_header_keys(&mut header_keys, throttle_keys);
 
         let client = InnerHttpClient {
             client,
             header_keys: Arc::new(header_keys),
+            latency: Arc::new(HttpLatency::default()),
         };
-        let rate_limiter = Arc::new(RateLimiter::new_with_quota(default_quota, keyed_quotas));
+
+        let mut rate_limiter = RateLimiter::new_with_quota(default_quota, keyed_quotas);
+        if let Some(global_quota) = global_quota {
+            rate_limiter = rate_limiter.with_global_quota(global_quota);
+        }
 
         Self {
             client,
-            rate_limiter,
+            rate_limiter: Arc::new(rate_limiter),
+            rate_limit_headers: Arc::new(rate_limit_headers),
+            cache: None,
+            retry_policy: None,
         }
     }
 
+    /// Enables caching of the responses to `GET` requests.
+    ///
+    /// Fresh cached responses are returned without sending a request, otherwise cached
+    /// responses are revalidated with the `ETag` and `Last-Modified` response headers.
+    #[must_use]
+    pub fn with_response_cache(mut self) -> Self {
+        let mut header_keys = self.client.header_keys.as_ref().clone();
+        retain_header_keys(
+            &mut header_keys,
+            [ETAG_HEADER, LAST_MODIFIED_HEADER, CACHE_CONTROL_HEADER].into_iter(),
+        );
+        self.client.header_keys = Arc::new(header_keys);
+        self.cache = Some(Arc::new(HttpResponseCache::new()));
+        self
+    }
+
+    /// Sets the policy for retrying failed requests.
+    ///
+    /// Requests are retried on transport errors and retryable statuses, respecting the rate
+    /// limits (including the pauses from rate limited responses) before each attempt.
+    #[must_use]
+    pub fn with_retry_policy(mut self, retry_policy: HttpRetryPolicy) -> Self {
+        self.retry_policy = Some(Arc::new(retry_policy));
+        self
+    }
+
+    /// Returns the response cache, if enabled.
+    #[must_use]
+    pub fn response_cache(&self) -> Option<&HttpResponseCache> {
+        self.cache.as_deref()
+    }
+
+    /// Returns the latencies recorded for the requests sent by the client.
+    ///
+    /// Connection establishment (DNS, TCP connect and TLS) is included in the time to
+    /// first byte of the requests which open a new pooled connection, as `reqwest`
+    /// does not report the individual phases.
+    #[must_use]
+    pub fn latency(&self) -> &HttpLatency {
+        &self.client.latency
+    }
+
     /// Sends an HTTP request.
     ///
     /// - `method`: The [`Method`] to use (GET, POST, etc.).
@@ -254,15 +419,189 @@ impl HttpClient {
         keys: Option<Vec<String>>,
         timeout_secs: Option<u64>,
     ) -> Result<HttpResponse, HttpClientError> {
-        let rate_limiter = self.rate_limiter.clone();
+        let cache = self.cache.as_ref().filter(|_| method == Method::GET);
+        let mut headers = headers;
+        if let Some(cache) = cache {
+            if let Some(response) = cache.get_fresh(&url, duration_since_unix_epoch()) {
+                tracing::trace!("Using cached response for {url}");
+                return Ok(response);
+            }
+            cache.add_validators(&url, headers.get_or_insert_with(HashMap::new));
+        }
 
-        rate_limiter.await_keys_ready(keys).await;
-        self.client
-            .send_request(method, url, headers, body, timeout_secs)
-            .await
+        let response = match &self.retry_policy {
+            Some(retry_policy) => {
+                self.send_with_retry(
+                    retry_policy,
+                    method,
+                    &url,
+                    headers,
+                    body,
+                    keys,
+                    timeout_secs,
+                )
+                .await?
+            }
+            None => {
+                self.send_throttled(method, &url, headers, body, keys, timeout_secs)
+                    .await?
+            }
+        };
+
+        let now = duration_since_unix_epoch();
+        match cache {
+            Some(cache) => Ok(cache.update(&url, response, now)),
+            None => Ok(response),
+        }
+    }
+
+    /// Sends a request once the rate limits for the `keys` allow, throttling later requests
+    /// from the response.
+    async fn send_throttled(
+        &self,
+        method: Method,
+        url: &str,
+        headers: Option<HashMap<String, String>>,
+        body: Option<Vec<u8>>,
+        keys: Option<Vec<String>>,
+        timeout_secs: Option<u64>,
+    ) -> Result<HttpResponse, HttpClientError> {
+        self.rate_limiter.await_keys_ready(keys).await;
+        let response = self
+            .client
+            .send_request(method, url.to_string(), headers, body, timeout_secs)
+            .await?;
+
+        let now = duration_since_unix_epoch();
+        throttle_from_response(&self.rate_limiter, &self.rate_limit_headers, &response, now);
+        Ok(response)
+    }
+
+    /// Sends a request, retrying it as allowed by the `retry_policy`.
+    #[allow(clippy::too_many_arguments)]
+    async fn send_with_retry(
+        &self,
+        retry_policy: &HttpRetryPolicy,
+        method: Method,
+        url: &str,
+        headers: Option<HashMap<String, String>>,
+        body: Option<Vec<u8>>,
+        keys: Option<Vec<String>>,
+        timeout_secs: Option<u64>,
+    ) -> Result<HttpResponse, HttpClientError> {
+        let mut headers = headers;
+        let retryable = retry_policy.prepare_request(&method, &mut headers);
+        let mut backoff = retry_policy.backoff();
+        let start = Instant::now();
+        let mut retries = 0;
+
+        loop {
+            let result = self
+                .send_throttled(
+                    method.clone(),
+                    url,
+                    headers.clone(),
+                    body.clone(),
+                    keys.clone(),
+                    timeout_secs,
+                )
+                .await;
+
+            let reason = match &result {
+                Ok(response) if retry_policy.is_retryable_status(&response.status) => {
+                    format!("status {}", response.status.as_u16())
+                }
+                Ok(_) => return result,
+                Err(e) => e.to_string(),
+            };
+
+            let delay = backoff.next_duration();
+            if !retryable
+                || retries >= retry_policy.max_retries
+                || !retry_policy.within_budget(start.elapsed() + delay)
+            {
+                return result;
+            }
+
+            retries += 1;
+            tracing::warn!(
+                "Retrying {method} {url} in {}ms ({retries}/{}) after {reason}",
+                delay.as_millis(),
+                retry_policy.max_retries,
+            );
+            tokio::time::sleep(delay).await;
+        }
     }
 }
 
+/// Pauses requests when the `response` reports a rate limit was reached.
+///
+/// Rate limited responses (`429 Too Many Requests` or the Binance `418` IP ban) pause all
+/// requests for the `Retry-After` duration (or a default of one second), as does a
+/// `503 Service Unavailable` with a `Retry-After` header. The `rate_limit_headers` pause
+/// requests until their interval resets once the usage reaches the threshold.
+pub(crate) fn throttle_from_response<C: Clock>(
+    rate_limiter: &RateLimiter<String, C>,
+    rate_limit_headers: &[RateLimitHeader],
+    response: &HttpResponse,
+    now: Duration,
+) {
+    let retry_after = header_value(&response.headers, RETRY_AFTER_HEADER)
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(Duration::from_secs);
+    let pause = match response.status.as_u16() {
+        418 | 429 => Some(retry_after.unwrap_or(DEFAULT_RETRY_AFTER)),
+        503 => retry_after,
+        _ => None,
+    };
+    if let Some(pause) = pause {
+        tracing::warn!(
+            "Rate limited (status {}), pausing all requests for {pause:?}",
+            response.status.as_u16()
+        );
+        rate_limiter.pause_all(pause);
+    }
+
+    for header in rate_limit_headers {
+        let Some(usage) = header_value(&response.headers, &header.name)
+            .and_then(|value| value.trim().parse::<u32>().ok())
+        else {
+            continue;
+        };
+
+        if let Some(pause) = header.pause_for(usage, now) {
+            tracing::warn!(
+                "Rate limit usage {usage}/{} for {}, pausing requests for {pause:?}",
+                header.limit,
+                header.name,
+            );
+            match &header.key {
+                Some(key) => rate_limiter.pause_key(key.clone(), pause),
+                None => rate_limiter.pause_all(pause),
+            }
+        }
+    }
+}
+
+fn retain_header_keys<'a>(header_keys: &mut Vec<String>, keys: impl Iterator<Item = &'a str>) {
+    for key in keys {
+        if !header_keys.iter().any(|k| k.eq_ignore_ascii_case(key)) {
+            header_keys.push(key.to_string());
+        }
+    }
+}
+
+/// Returns the value of the header `name` (compared case-insensitively) from `headers`.
+pub(crate) fn header_value<'a>(
+    headers: &'a HashMap<String, String>,
+    name: &str,
+) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
 /// Internal implementation backing [`HttpClient`].
 ///
 /// The client is backed by a [`reqwest::Client`] which keeps connections alive and
@@ -275,6 +614,7 @@ impl HttpClient {
 pub struct InnerHttpClient {
     pub(crate) client: reqwest::Client,
     pub(crate) header_keys: Arc<Vec<String>>,
+    pub(crate) latency: Arc<HttpLatency>,
 }
 
 impl InnerHttpClient {
@@ -325,13 +665,18 @@ impl InnerHttpClient {
 
         tracing::trace!("{request:?}");
 
+        let start = Instant::now();
         let response = self
             .client
             .execute(request)
             .await
             .map_err(HttpClientError::from)?;
+        self.latency.first_byte.record(start.elapsed());
+
+        let response = self.to_response(response).await?;
+        self.latency.total.record(start.elapsed());
 
-        self.to_response(response).await
+        Ok(response)
     }
 
     /// Converts a `reqwest::Response` into an `HttpResponse`.
@@ -365,6 +710,7 @@ impl Default for InnerHttpClient {
         Self {
             client,
             header_keys: Default::default(),
+            latency: Default::default(),
         }
     }
 }
@@ -378,10 +724,11 @@ mod tests {
     use std::net::{SocketAddr, TcpListener};
 
     use axum::{
-        routing::{delete, get, patch, post},
+        routing::{any, delete, get, patch, post},
         serve, Router,
     };
     use http::status::StatusCode;
+    use rstest::rstest;
 
     use super::*;
 
@@ -398,12 +745,44 @@ mod tests {
     }
 
     fn create_router() -> Router {
+        // The idempotency keys of the requests to the flaky route, which fails twice
+        let flaky_keys = Arc::new(std::sync::Mutex::new(Vec::<String>::new()));
+
         Router::new()
             .route("/get", get(|| async { "hello-world!" }))
             .route("/post", post(|| async { StatusCode::OK }))
             .route("/patch", patch(|| async { StatusCode::OK }))
             .route("/delete", delete(|| async { StatusCode::OK }))
             .route("/notfound", get(|| async { StatusCode::NOT_FOUND }))
+            .route(
+                "/etag",
+                get(|headers: http::HeaderMap| async move {
+                    let etag = [("ETag", "\"v1\"")];
+                    match headers.get("If-None-Match") {
+                        Some(value) if value == "\"v1\"" => (StatusCode::NOT_MODIFIED, etag, ""),
+                        _ => (StatusCode::OK, etag, "instruments"),
+                    }
+                }),
+            )
+            .route(
+                "/ratelimited",
+                get(|| async { (StatusCode::TOO_MANY_REQUESTS, [("Retry-After", "30")]) }),
+            )
+            .route(
+                "/flaky",
+                any(move |headers: http::HeaderMap| async move {
+                    let mut keys = flaky_keys.lock().unwrap();
+                    let key = headers
+                        .get("Idempotency-Key")
+                        .and_then(|value| value.to_str().ok());
+                    keys.push(key.unwrap_or_default().to_string());
+                    if keys.len() < 3 {
+                        (StatusCode::SERVICE_UNAVAILABLE, String::new())
+                    } else {
+                        (StatusCode::OK, keys.join(","))
+                    }
+                }),
+            )
             .route(
                 "/slow",
                 get(|| async {
@@ -442,6 +821,126 @@ mod tests {
         assert_eq!(String::from_utf8_lossy(&response.body), "hello-world!");
     }
 
+    /// Starts a minimal HTTPS server on `localhost` which requires a client certificate.
+    async fn start_mtls_server() -> u16 {
+        let acceptor = crate::tls::tests::mtls_acceptor();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let acceptor = acceptor.clone();
+                tokio::spawn(async move {
+                    let Ok(mut stream) = acceptor.accept(stream).await else {
+                        return;
+                    };
+                    let mut request = Vec::new();
+                    let mut buf = [0u8; 1024];
+                    while !request.ends_with(b"\r\n\r\n") {
+                        match tokio::io::AsyncReadExt::read(&mut stream, &mut buf).await {
+                            Ok(0) | Err(_) => return,
+                            Ok(n) => request.extend_from_slice(&buf[..n]),
+                        }
+                    }
+                    let response =
+                        b"HTTP/1.1 200 OK\r\ncontent-length: 6\r\nconnection: close\r\n\r\nmutual";
+                    let _ = tokio::io::AsyncWriteExt::write_all(&mut stream, response).await;
+                    let _ = tokio::io::AsyncWriteExt::shutdown(&mut stream).await;
+                });
+            }
+        });
+
+        port
+    }
+
+    #[rstest]
+    #[case(true, true)]
+    #[case(false, false)]
+    #[tokio::test]
+    async fn test_request_with_mutual_tls(#[case] mutual: bool, #[case] expected_ok: bool) {
+        let port = start_mtls_server().await;
+        let tls = crate::tls::tests::test_tls_config(mutual);
+
+        let client = HttpClient::new(
+            HashMap::new(),
+            vec![],
+            vec![],
+            None,
+            None,
+            vec![],
+            None,
+            Some(tls),
+        );
+        let result = client
+            .request(
+                Method::GET,
+                format!("https://localhost:{port}/get"),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+
+        assert_eq!(result.is_ok(), expected_ok, "{result:?}");
+        if let Ok(response) = result {
+            assert!(response.status.is_success());
+            assert_eq!(String::from_utf8_lossy(&response.body), "mutual");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_through_proxy() {
+        let addr = start_test_server().await.unwrap();
+        // The test server routes proxied (absolute-form) requests by path
+        let proxy = ProxyConfig::new(&format!("http://{addr}"), vec![]).unwrap();
+
+        let client = HttpClient::new(
+            HashMap::new(),
+            vec![],
+            vec![],
+            None,
+            None,
+            vec![],
+            Some(proxy),
+            None,
+        );
+        let response = client
+            .request(
+                Method::GET,
+                "http://venue.invalid/get".to_string(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert!(response.status.is_success());
+        assert_eq!(String::from_utf8_lossy(&response.body), "hello-world!");
+    }
+
+    #[tokio::test]
+    async fn test_request_records_latency() {
+        let addr = start_test_server().await.unwrap();
+        let url = format!("http://{addr}");
+
+        let client = InnerHttpClient::default();
+        for _ in 0..2 {
+            client
+                .send_request(reqwest::Method::GET, format!("{url}/get"), None, None, None)
+                .await
+                .unwrap();
+        }
+
+        let first_byte = client.latency.first_byte.snapshot();
+        let total = client.latency.total.snapshot();
+        assert_eq!(first_byte.count, 2);
+        assert_eq!(total.count, 2);
+        assert!(total.max_us >= first_byte.max_us);
+    }
+
     #[tokio::test]
     async fn test_post() {
         let addr = start_test_server().await.unwrap();
@@ -570,4 +1069,249 @@ mod tests {
             Ok(resp) => panic!("Expected a timeout error, but got a successful response: {resp:?}"),
         }
     }
+
+    #[tokio::test]
+    async fn test_rate_limited_response_pauses_requests() {
+        let addr = start_test_server().await.unwrap();
+        let url = format!("http://{addr}/ratelimited");
+        let client = HttpClient::new(
+            HashMap::new(),
+            vec![],
+            vec![],
+            None,
+            None,
+            vec![],
+            None,
+            None,
+        );
+
+        let response = client
+            .request(Method::GET, url, None, None, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(response.status.as_u16(), 429);
+        assert_eq!(response.headers.get("Retry-After").unwrap(), "30");
+        let pause = client.rate_limiter.paused_for(None).unwrap();
+        assert!(pause > Duration::from_secs(29) && pause <= Duration::from_secs(30));
+    }
+
+    #[tokio::test]
+    async fn test_response_cache_revalidates_with_etag() {
+        let addr = start_test_server().await.unwrap();
+        let url = format!("http://{addr}/etag");
+        let client = HttpClient::new(
+            HashMap::new(),
+            vec![],
+            vec![],
+            None,
+            None,
+            vec![],
+            None,
+            None,
+        )
+        .with_response_cache();
+
+        let first = client
+            .request(Method::GET, url.clone(), None, None, None, None)
+            .await
+            .unwrap();
+        let second = client
+            .request(Method::GET, url, None, None, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(first.status.as_u16(), 200);
+        assert_eq!(second.status.as_u16(), 200);
+        assert_eq!(second.body, first.body);
+        assert_eq!(second.body, "instruments");
+        assert_eq!(client.response_cache().unwrap().len(), 1);
+    }
+
+    fn retry_policy(max_retries: u32, idempotency_header: Option<&str>) -> HttpRetryPolicy {
+        HttpRetryPolicy {
+            max_retries,
+            delay_initial_ms: 10,
+            jitter_ms: 0,
+            idempotency_header: idempotency_header.map(ToString::to_string),
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    #[case(Method::GET, 3, None, 200)]
+    #[case(Method::GET, 1, None, 503)]
+    #[case(Method::POST, 3, None, 503)]
+    #[case(Method::POST, 3, Some("Idempotency-Key"), 200)]
+    #[tokio::test]
+    async fn test_request_retry_policy(
+        #[case] method: Method,
+        #[case] max_retries: u32,
+        #[case] idempotency_header: Option<&str>,
+        #[case] expected_status: u16,
+    ) {
+        let addr = start_test_server().await.unwrap();
+        let client = HttpClient::new(
+            HashMap::new(),
+            vec![],
+            vec![],
+            None,
+            None,
+            vec![],
+            None,
+            None,
+        )
+        .with_retry_policy(retry_policy(max_retries, idempotency_header));
+
+        let response = client
+            .request(
+                method,
+                format!("http://{addr}/flaky"),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status.as_u16(), expected_status);
+    }
+
+    #[tokio::test]
+    async fn test_request_retry_reuses_idempotency_key() {
+        let addr = start_test_server().await.unwrap();
+        let client = HttpClient::new(
+            HashMap::new(),
+            vec![],
+            vec![],
+            None,
+            None,
+            vec![],
+            None,
+            None,
+        )
+        .with_retry_policy(retry_policy(3, Some("Idempotency-Key")));
+        let headers = HashMap::from([("Idempotency-Key".to_string(), "O-123".to_string())]);
+
+        let response = client
+            .request(
+                Method::POST,
+                format!("http://{addr}/flaky"),
+                Some(headers),
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status.as_u16(), 200);
+        assert_eq!(response.body, "O-123,O-123,O-123");
+    }
+
+    fn used_weight_header(key: Option<&str>) -> RateLimitHeader {
+        RateLimitHeader::new(
+            "X-MBX-USED-WEIGHT-1M".to_string(),
+            6000,
+            Duration::from_secs(60),
+            0.9,
+            key.map(ToString::to_string),
+        )
+    }
+
+    fn response_with_headers(status: u16, headers: &[(&str, &str)]) -> HttpResponse {
+        HttpResponse {
+            status: HttpStatus::from(status).unwrap(),
+            headers: headers
+                .iter()
+                .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+                .collect(),
+            body: Bytes::new(),
+        }
+    }
+
+    #[rstest]
+    #[case(5399, None)]
+    #[case(5400, Some(Duration::from_secs(45)))]
+    #[case(6100, Some(Duration::from_secs(45)))]
+    fn test_rate_limit_header_pause_for(#[case] usage: u32, #[case] expected: Option<Duration>) {
+        let header = used_weight_header(None);
+        let now = Duration::from_secs(1_699_999_995); // 15 seconds into the minute
+
+        assert_eq!(header.pause_for(usage, now), expected);
+    }
+
+    #[rstest]
+    #[case(429, None, Some(DEFAULT_RETRY_AFTER))]
+    #[case(418, Some("120"), Some(Duration::from_secs(120)))]
+    #[case(503, Some("5"), Some(Duration::from_secs(5)))]
+    #[case(503, None, None)]
+    #[case(200, Some("5"), None)]
+    fn test_throttle_from_retry_after(
+        #[case] status: u16,
+        #[case] retry_after: Option<&str>,
+        #[case] expected: Option<Duration>,
+    ) {
+        let rate_limiter: RateLimiter<String, MonotonicClock> =
+            RateLimiter::new_with_quota(None, vec![]);
+        let headers: Vec<(&str, &str)> = retry_after
+            .map(|value| vec![("retry-after", value)])
+            .unwrap_or_default();
+        let response = response_with_headers(status, &headers);
+
+        throttle_from_response(&rate_limiter, &[], &response, Duration::ZERO);
+
+        let pause = rate_limiter.paused_for(None);
+        match expected {
+            Some(expected) => {
+                let pause = pause.unwrap();
+                assert!(pause <= expected && pause > expected - Duration::from_millis(100));
+            }
+            None => assert!(pause.is_none()),
+        }
+    }
+
+    #[rstest]
+    fn test_throttle_from_rate_limit_header_pauses_key() {
+        let rate_limiter: RateLimiter<String, MonotonicClock> =
+            RateLimiter::new_with_quota(None, vec![]);
+        let header = used_weight_header(Some("order"));
+        let response = response_with_headers(200, &[("X-MBX-USED-WEIGHT-1M", "5950")]);
+
+        throttle_from_response(
+            &rate_limiter,
+            &[header],
+            &response,
+            Duration::from_secs(1_700_000_030),
+        );
+
+        let pause = rate_limiter.paused_for(Some(&"order".to_string())).unwrap();
+        assert!(pause <= Duration::from_secs(10) && pause > Duration::from_secs(9));
+        assert!(rate_limiter
+            .paused_for(Some(&"other".to_string()))
+            .is_none());
+    }
+
+    #[rstest]
+    fn test_new_retains_throttle_header_keys() {
+        let client = HttpClient::new(
+            HashMap::new(),
+            vec!["retry-after".to_string()],
+            vec![],
+            None,
+            None,
+            vec![used_weight_header(None)],
+            None,
+            None,
+        );
+
+        assert_eq!(
+            *client.client.header_keys,
+            vec![
+                "retry-after".to_string(),
+                "X-MBX-USED-WEIGHT-1M".to_string()
+            ]
+        );
+    }
 }
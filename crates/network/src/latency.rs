@@ -0,0 +1,284 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Latency measurement for the network clients.
+//!
+//! Latencies are recorded into [`LatencyHistogram`]s with power-of-two microsecond buckets,
+//! so percentiles are approximate (reported as the upper bound of their bucket) while
+//! recording is lock-free and allocation free.
+//!
+//! The HTTP client records the time to first byte (until the response headers are received)
+//! and the total request time, and the WebSocket client records the connection time
+//! (including DNS resolution, TCP connect, TLS and the upgrade handshake) and the
+//! venue-to-local latency of messages which carry an exchange timestamp.
+
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+/// The number of histogram buckets, where bucket `i` counts latencies below `2^i` microseconds
+/// (and the last bucket counts all longer latencies).
+const BUCKET_COUNT: usize = 40;
+
+/// A concurrent histogram of latencies.
+#[derive(Debug)]
+pub struct LatencyHistogram {
+    buckets: [AtomicU64; BUCKET_COUNT],
+    count: AtomicU64,
+    sum_us: AtomicU64,
+    max_us: AtomicU64,
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> Self {
+        Self {
+            buckets: std::array::from_fn(|_| AtomicU64::new(0)),
+            count: AtomicU64::new(0),
+            sum_us: AtomicU64::new(0),
+            max_us: AtomicU64::new(0),
+        }
+    }
+}
+
+impl LatencyHistogram {
+    /// Creates a new empty [`LatencyHistogram`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the `latency`.
+    pub fn record(&self, latency: Duration) {
+        let us = u64::try_from(latency.as_micros()).unwrap_or(u64::MAX);
+        let index = ((u64::BITS - us.leading_zeros()) as usize).min(BUCKET_COUNT - 1);
+        self.buckets[index].fetch_add(1, Ordering::Relaxed);
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.sum_us.fetch_add(us, Ordering::Relaxed);
+        self.max_us.fetch_max(us, Ordering::Relaxed);
+    }
+
+    /// Returns the number of recorded latencies.
+    #[must_use]
+    pub fn count(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    /// Returns the approximate latency at the quantile `q` (in the range [0, 1]),
+    /// or `None` if no latencies are recorded.
+    #[must_use]
+    pub fn quantile(&self, q: f64) -> Option<Duration> {
+        let count = self.count();
+        if count == 0 {
+            return None;
+        }
+
+        let rank = ((q.clamp(0.0, 1.0) * count as f64).ceil() as u64).max(1);
+        let mut cumulative = 0;
+        for (index, bucket) in self.buckets.iter().enumerate() {
+            cumulative += bucket.load(Ordering::Relaxed);
+            if cumulative >= rank {
+                // The last bucket is unbounded, so report the maximum latency
+                let upper_us = if index == BUCKET_COUNT - 1 {
+                    u64::MAX
+                } else {
+                    1u64 << index
+                };
+                let max_us = self.max_us.load(Ordering::Relaxed);
+                return Some(Duration::from_micros(upper_us.min(max_us)));
+            }
+        }
+        Some(Duration::from_micros(self.max_us.load(Ordering::Relaxed)))
+    }
+
+    /// Returns a snapshot of the recorded latencies.
+    #[must_use]
+    pub fn snapshot(&self) -> LatencySnapshot {
+        let count = self.count();
+        let as_us =
+            |latency: Option<Duration>| latency.map_or(0, |latency| latency.as_micros() as u64);
+        LatencySnapshot {
+            count,
+            mean_us: self
+                .sum_us
+                .load(Ordering::Relaxed)
+                .checked_div(count)
+                .unwrap_or(0),
+            p50_us: as_us(self.quantile(0.5)),
+            p90_us: as_us(self.quantile(0.9)),
+            p99_us: as_us(self.quantile(0.99)),
+            max_us: self.max_us.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Clears the recorded latencies.
+    pub fn reset(&self) {
+        for bucket in &self.buckets {
+            bucket.store(0, Ordering::Relaxed);
+        }
+        self.count.store(0, Ordering::Relaxed);
+        self.sum_us.store(0, Ordering::Relaxed);
+        self.max_us.store(0, Ordering::Relaxed);
+    }
+}
+
+/// A snapshot of the latencies recorded by a [`LatencyHistogram`], in microseconds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.network", get_all)
+)]
+pub struct LatencySnapshot {
+    /// The number of recorded latencies.
+    pub count: u64,
+    /// The mean latency.
+    pub mean_us: u64,
+    /// The approximate median latency.
+    pub p50_us: u64,
+    /// The approximate 90th percentile latency.
+    pub p90_us: u64,
+    /// The approximate 99th percentile latency.
+    pub p99_us: u64,
+    /// The maximum latency.
+    pub max_us: u64,
+}
+
+/// The latencies recorded by the HTTP client.
+#[derive(Debug, Default)]
+pub struct HttpLatency {
+    /// The time from sending a request until the response headers are received.
+    pub first_byte: LatencyHistogram,
+    /// The time from sending a request until the response body is received.
+    pub total: LatencyHistogram,
+}
+
+impl HttpLatency {
+    /// Returns snapshots of the latencies keyed by name.
+    #[must_use]
+    pub fn snapshots(&self) -> HashMap<String, LatencySnapshot> {
+        HashMap::from([
+            ("first_byte".to_string(), self.first_byte.snapshot()),
+            ("total".to_string(), self.total.snapshot()),
+        ])
+    }
+}
+
+/// The latencies recorded by the WebSocket client.
+#[derive(Debug, Default)]
+pub struct WebSocketLatency {
+    /// The time to establish a connection (DNS, TCP, TLS and the upgrade handshake).
+    pub connect: LatencyHistogram,
+    /// The time from the exchange timestamp of a message until it is received.
+    pub venue_to_local: LatencyHistogram,
+}
+
+impl WebSocketLatency {
+    /// Records the venue-to-local latency of a message with the exchange timestamp
+    /// `ts_event` received at `ts_recv` (both UNIX nanoseconds).
+    ///
+    /// Messages timestamped after they are received (from clock skew) record zero latency.
+    pub fn record_venue_latency(&self, ts_event: u64, ts_recv: u64) {
+        self.venue_to_local
+            .record(Duration::from_nanos(ts_recv.saturating_sub(ts_event)));
+    }
+
+    /// Returns snapshots of the latencies keyed by name.
+    #[must_use]
+    pub fn snapshots(&self) -> HashMap<String, LatencySnapshot> {
+        HashMap::from([
+            ("connect".to_string(), self.connect.snapshot()),
+            ("venue_to_local".to_string(), self.venue_to_local.snapshot()),
+        ])
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_empty_histogram() {
+        let histogram = LatencyHistogram::new();
+
+        assert_eq!(histogram.quantile(0.5), None);
+        assert_eq!(histogram.snapshot(), LatencySnapshot::default());
+    }
+
+    #[rstest]
+    fn test_histogram_quantiles() {
+        let histogram = LatencyHistogram::new();
+        for ms in 1..=100 {
+            histogram.record(Duration::from_millis(ms));
+        }
+
+        let snapshot = histogram.snapshot();
+
+        assert_eq!(snapshot.count, 100);
+        assert_eq!(snapshot.mean_us, 50_500);
+        assert_eq!(snapshot.max_us, 100_000);
+        // Quantiles are the upper bound of their power-of-two microsecond bucket
+        assert_eq!(snapshot.p50_us, 65_536);
+        assert_eq!(snapshot.p90_us, 100_000);
+        assert_eq!(snapshot.p99_us, 100_000);
+    }
+
+    #[rstest]
+    #[case(Duration::ZERO, 0)]
+    #[case(Duration::from_nanos(500), 0)]
+    #[case(Duration::from_micros(3), 3)]
+    #[case(Duration::from_secs(10_000_000), 10_000_000_000_000)]
+    fn test_histogram_record_bounds(#[case] latency: Duration, #[case] expected_us: u64) {
+        let histogram = LatencyHistogram::new();
+
+        histogram.record(latency);
+
+        assert_eq!(
+            histogram.quantile(1.0),
+            Some(Duration::from_micros(expected_us))
+        );
+    }
+
+    #[rstest]
+    fn test_histogram_reset() {
+        let histogram = LatencyHistogram::new();
+        histogram.record(Duration::from_millis(5));
+
+        histogram.reset();
+
+        assert_eq!(histogram.count(), 0);
+        assert_eq!(histogram.snapshot(), LatencySnapshot::default());
+    }
+
+    #[rstest]
+    #[case(1_000_000, 3_500_000, 2_500)]
+    #[case(3_500_000, 1_000_000, 0)]
+    fn test_record_venue_latency(
+        #[case] ts_event: u64,
+        #[case] ts_recv: u64,
+        #[case] expected_us: u64,
+    ) {
+        let latency = WebSocketLatency::default();
+
+        latency.record_venue_latency(ts_event, ts_recv);
+
+        assert_eq!(latency.venue_to_local.snapshot().max_us, expected_us);
+    }
+}
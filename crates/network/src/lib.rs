@@ -32,14 +32,24 @@
 // #![deny(clippy::missing_errors_doc)]
 
 pub mod backoff;
+pub mod cache;
+pub mod compression;
+pub mod framing;
 pub mod http;
+pub mod latency;
+pub mod liveness;
 pub mod mode;
+pub mod proxy;
+pub mod retry;
+pub mod sharding;
 pub mod socket;
+pub mod tls;
+#[cfg(unix)]
+pub mod uds;
 pub mod websocket;
 
 #[allow(dead_code)]
 mod ratelimiter;
-mod tls;
 
 #[cfg(feature = "python")]
 pub mod python;
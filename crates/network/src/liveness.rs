@@ -0,0 +1,176 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Liveness monitoring of WebSocket connections.
+//!
+//! A connection is stale when no message (including pings and pongs) is received within
+//! the idle timeout, or when too many consecutive heartbeat pings go without a pong. The
+//! client controller checks the [`LivenessMonitor`] of the connection and either reconnects
+//! or only notifies the status handler, depending on the [`LivenessPolicy`].
+
+use std::{
+    sync::atomic::{AtomicU32, AtomicU64, Ordering},
+    time::{Duration, Instant},
+};
+
+use strum::{AsRefStr, Display, EnumString};
+
+/// The action taken when a WebSocket connection becomes stale.
+#[derive(Clone, Copy, Debug, Default, Display, Hash, PartialEq, Eq, AsRefStr, EnumString)]
+#[strum(ascii_case_insensitive, serialize_all = "UPPERCASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.network")
+)]
+pub enum LivenessPolicy {
+    /// Emit the stale status and reconnect.
+    #[default]
+    Reconnect,
+    /// Only emit the stale status.
+    Notify,
+}
+
+/// Tracks the inbound activity of a WebSocket connection.
+#[derive(Debug)]
+pub struct LivenessMonitor {
+    start: Instant,
+    last_received_ns: AtomicU64,
+    missed_pongs: AtomicU32,
+}
+
+impl Default for LivenessMonitor {
+    fn default() -> Self {
+        Self {
+            start: Instant::now(),
+            last_received_ns: AtomicU64::new(0),
+            missed_pongs: AtomicU32::new(0),
+        }
+    }
+}
+
+impl LivenessMonitor {
+    /// Creates a new [`LivenessMonitor`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records that a message was received.
+    pub fn on_message(&self) {
+        self.last_received_ns
+            .store(self.elapsed_ns(), Ordering::Relaxed);
+    }
+
+    /// Records that a pong was received.
+    pub fn on_pong(&self) {
+        self.on_message();
+        self.missed_pongs.store(0, Ordering::Relaxed);
+    }
+
+    /// Records that a heartbeat ping was sent.
+    pub fn on_ping_sent(&self) {
+        self.missed_pongs.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Resets the monitor for a new connection.
+    pub fn reset(&self) {
+        self.on_pong();
+    }
+
+    /// Returns the time since a message was last received (or the monitor was reset).
+    #[must_use]
+    pub fn idle(&self) -> Duration {
+        let last_received_ns = self.last_received_ns.load(Ordering::Relaxed);
+        Duration::from_nanos(self.elapsed_ns().saturating_sub(last_received_ns))
+    }
+
+    /// Returns the number of heartbeat pings sent since a pong was last received
+    /// (the first of which may still be awaiting its pong).
+    #[must_use]
+    pub fn missed_pongs(&self) -> u32 {
+        self.missed_pongs.load(Ordering::Relaxed)
+    }
+
+    /// Returns whether the connection is stale, having been idle for longer than the
+    /// `idle_timeout` or having sent more than `max_missed_pongs` pings without a pong.
+    #[must_use]
+    pub fn is_stale(&self, idle_timeout: Option<Duration>, max_missed_pongs: Option<u32>) -> bool {
+        idle_timeout.is_some_and(|timeout| self.idle() > timeout)
+            || max_missed_pongs.is_some_and(|max_missed| self.missed_pongs() > max_missed)
+    }
+
+    fn elapsed_ns(&self) -> u64 {
+        u64::try_from(self.start.elapsed().as_nanos()).unwrap_or(u64::MAX)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_not_stale_without_limits() {
+        let monitor = LivenessMonitor::new();
+        monitor.on_ping_sent();
+
+        assert!(!monitor.is_stale(None, None));
+    }
+
+    #[rstest]
+    fn test_stale_when_idle() {
+        let monitor = LivenessMonitor::new();
+        std::thread::sleep(Duration::from_millis(20));
+
+        assert!(monitor.is_stale(Some(Duration::from_millis(10)), None));
+
+        monitor.on_message();
+
+        assert!(!monitor.is_stale(Some(Duration::from_millis(10)), None));
+    }
+
+    #[rstest]
+    fn test_stale_after_missed_pongs() {
+        let monitor = LivenessMonitor::new();
+        monitor.on_ping_sent();
+        monitor.on_ping_sent();
+
+        assert!(!monitor.is_stale(None, Some(2)));
+
+        monitor.on_ping_sent();
+
+        assert_eq!(monitor.missed_pongs(), 3);
+        assert!(monitor.is_stale(None, Some(2)));
+
+        monitor.on_pong();
+
+        assert_eq!(monitor.missed_pongs(), 0);
+        assert!(!monitor.is_stale(None, Some(2)));
+    }
+
+    #[rstest]
+    fn test_message_does_not_reset_missed_pongs() {
+        let monitor = LivenessMonitor::new();
+        monitor.on_ping_sent();
+
+        monitor.on_message();
+
+        assert_eq!(monitor.missed_pongs(), 1);
+    }
+}
@@ -16,6 +16,7 @@
 use std::{
     collections::{hash_map::DefaultHasher, HashMap},
     hash::{Hash, Hasher},
+    time::Duration,
 };
 
 use bytes::Bytes;
@@ -23,8 +24,12 @@ use nautilus_core::python::to_pyvalue_err;
 use pyo3::{create_exception, exceptions::PyException, prelude::*};
 
 use crate::{
-    http::{HttpClient, HttpClientError, HttpMethod, HttpResponse, HttpStatus},
+    http::{HttpClient, HttpClientError, HttpMethod, HttpResponse, HttpStatus, RateLimitHeader},
+    latency::LatencySnapshot,
+    proxy::ProxyConfig,
     ratelimiter::quota::Quota,
+    retry::HttpRetryPolicy,
+    tls::TlsConfig,
 };
 
 // Python exception class for generic HTTP errors.
@@ -82,6 +87,50 @@ impl HttpResponse {
     }
 }
 
+#[pymethods]
+impl RateLimitHeader {
+    #[new]
+    #[pyo3(signature = (name, limit, interval_secs, threshold = 0.9, key = None))]
+    fn py_new(
+        name: String,
+        limit: u32,
+        interval_secs: u64,
+        threshold: f64,
+        key: Option<String>,
+    ) -> PyResult<Self> {
+        if !(threshold > 0.0 && threshold <= 1.0) {
+            return Err(to_pyvalue_err(format!(
+                "invalid `threshold`, expected in range (0, 1], was {threshold}"
+            )));
+        }
+        Ok(Self::new(
+            name,
+            limit,
+            Duration::from_secs(interval_secs),
+            threshold,
+            key,
+        ))
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    fn py_name(&self) -> &str {
+        &self.name
+    }
+
+    #[getter]
+    #[pyo3(name = "limit")]
+    const fn py_limit(&self) -> u32 {
+        self.limit
+    }
+
+    #[getter]
+    #[pyo3(name = "key")]
+    fn py_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+}
+
 #[pymethods]
 impl HttpClient {
     /// Creates a new HttpClient.
@@ -91,6 +140,10 @@ impl HttpClient {
     /// `keyed_quota`: A list of string quota pairs that gives quota for specific key values.
     /// `default_quota`: The default rate limiting quota for any request.
     /// Default quota is optional and no quota is passthrough.
+    /// `global_quota`: The rate limiting quota applied to every request, in addition to the keyed quotas.
+    /// `rate_limit_headers`: The venue rate limit headers to adaptively throttle requests from.
+    /// `cache_responses`: If responses to GET requests should be cached (honoring `ETag`,
+    /// `Last-Modified` and `Cache-Control`), such as for instrument definition endpoints.
     ///
     /// Rate limiting can be configured on a per-endpoint basis by passing
     /// key-value pairs of endpoint URLs and their respective quotas.
@@ -106,16 +159,56 @@ impl HttpClient {
     /// When a request is made the URL should be split into all the keys within it.
     ///
     /// For request /foo/bar, should pass keys ["foo/bar", "foo"] for rate limiting.
+    ///
+    /// Rate limited responses (status 429 or 418) pause all requests for the `Retry-After`
+    /// duration, and each rate limit header pauses requests until its interval resets once
+    /// the reported usage reaches the threshold of the limit.
+    ///
+    /// Failed requests are retried as allowed by the optional `retry_policy`.
+    ///
+    /// The optional `tls` configuration adds trusted CA certificates and a client
+    /// certificate for venues which mandate mutual TLS.
     #[new]
-    #[pyo3(signature = (default_headers = HashMap::new(), header_keys = Vec::new(), keyed_quotas = Vec::new(), default_quota = None))]
+    #[pyo3(signature = (default_headers = HashMap::new(), header_keys = Vec::new(), keyed_quotas = Vec::new(), default_quota = None, global_quota = None, rate_limit_headers = Vec::new(), cache_responses = false, proxy = None, retry_policy = None, tls = None))]
     #[must_use]
+    #[allow(clippy::too_many_arguments)]
     pub fn py_new(
         default_headers: HashMap<String, String>,
         header_keys: Vec<String>,
         keyed_quotas: Vec<(String, Quota)>,
         default_quota: Option<Quota>,
+        global_quota: Option<Quota>,
+        rate_limit_headers: Vec<RateLimitHeader>,
+        cache_responses: bool,
+        proxy: Option<ProxyConfig>,
+        retry_policy: Option<HttpRetryPolicy>,
+        tls: Option<TlsConfig>,
     ) -> Self {
-        Self::new(default_headers, header_keys, keyed_quotas, default_quota)
+        let client = Self::new(
+            default_headers,
+            header_keys,
+            keyed_quotas,
+            default_quota,
+            global_quota,
+            rate_limit_headers,
+            proxy,
+            tls,
+        );
+        let client = match retry_policy {
+            Some(retry_policy) => client.with_retry_policy(retry_policy),
+            None => client,
+        };
+        if cache_responses {
+            client.with_response_cache()
+        } else {
+            client
+        }
+    }
+
+    /// Returns snapshots of the request latencies keyed by name (`first_byte` and `total`).
+    #[pyo3(name = "latency")]
+    fn py_latency(&self) -> HashMap<String, LatencySnapshot> {
+        self.latency().snapshots()
     }
 
     /// Sends an HTTP request.
@@ -144,13 +237,11 @@ impl HttpClient {
         timeout_secs: Option<u64>,
         py: Python<'py>,
     ) -> PyResult<Bound<'py, PyAny>> {
-        let client = self.client.clone();
-        let rate_limiter = self.rate_limiter.clone();
+        let client = self.clone();
 
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            rate_limiter.await_keys_ready(keys).await;
             client
-                .send_request(method.into(), url, headers, body, timeout_secs)
+                .request(method.into(), url, headers, body, keys, timeout_secs)
                 .await
                 .map_err(HttpClientError::into_py_err)
         })
@@ -22,7 +22,13 @@
 #![allow(unexpected_cfgs)]
 
 pub mod http;
+pub mod proxy;
+pub mod retry;
+pub mod sharding;
 pub mod socket;
+pub mod tls;
+#[cfg(unix)]
+pub mod uds;
 pub mod websocket;
 
 use pyo3::{prelude::*, PyTypeCheck};
@@ -42,11 +48,24 @@ pub fn network(_: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<crate::http::HttpClient>()?;
     m.add_class::<crate::http::HttpMethod>()?;
     m.add_class::<crate::http::HttpResponse>()?;
+    m.add_class::<crate::http::RateLimitHeader>()?;
+    m.add_class::<crate::latency::LatencySnapshot>()?;
+    m.add_class::<crate::proxy::ProxyConfig>()?;
+    m.add_class::<crate::retry::HttpRetryPolicy>()?;
     m.add_class::<crate::ratelimiter::quota::Quota>()?;
     m.add_class::<crate::websocket::WebSocketClient>()?;
     m.add_class::<crate::websocket::WebSocketConfig>()?;
+    m.add_class::<crate::compression::PayloadCompression>()?;
+    m.add_class::<crate::liveness::LivenessPolicy>()?;
+    m.add_class::<crate::framing::SocketFraming>()?;
+    m.add_class::<crate::sharding::ShardedWebSocketClient>()?;
     m.add_class::<crate::socket::SocketClient>()?;
     m.add_class::<crate::socket::SocketConfig>()?;
+    m.add_class::<crate::tls::TlsConfig>()?;
+    #[cfg(unix)]
+    m.add_class::<crate::uds::UnixSocketServer>()?;
+    #[cfg(unix)]
+    m.add_class::<crate::uds::UnixSocketClient>()?;
 
     // Add error classes
     m.add(
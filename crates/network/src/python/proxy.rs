@@ -0,0 +1,52 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::python::to_pyvalue_err;
+use pyo3::prelude::*;
+
+use crate::proxy::ProxyConfig;
+
+#[pymethods]
+impl ProxyConfig {
+    #[new]
+    #[pyo3(signature = (url, no_proxy = Vec::new()))]
+    fn py_new(url: &str, no_proxy: Vec<String>) -> PyResult<Self> {
+        Self::new(url, no_proxy).map_err(to_pyvalue_err)
+    }
+
+    #[getter]
+    #[pyo3(name = "url")]
+    fn py_url(&self) -> String {
+        self.url.to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "protocol")]
+    fn py_protocol(&self) -> String {
+        self.protocol.to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "no_proxy")]
+    fn py_no_proxy(&self) -> Vec<String> {
+        self.no_proxy.clone()
+    }
+
+    /// Returns whether connections to the `host` bypass the proxy.
+    #[pyo3(name = "bypasses")]
+    fn py_bypasses(&self, host: &str) -> bool {
+        self.bypasses(host)
+    }
+}
@@ -0,0 +1,65 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::python::to_pyvalue_err;
+use pyo3::prelude::*;
+
+use crate::retry::HttpRetryPolicy;
+
+#[pymethods]
+impl HttpRetryPolicy {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (max_retries = 3, retry_status_codes = None, retry_server_errors = true, delay_initial_ms = 500, delay_max_ms = 10_000, backoff_factor = 2.0, jitter_ms = 100, max_elapsed_ms = None, idempotency_header = None))]
+    fn py_new(
+        max_retries: u32,
+        retry_status_codes: Option<Vec<u16>>,
+        retry_server_errors: bool,
+        delay_initial_ms: u64,
+        delay_max_ms: u64,
+        backoff_factor: f64,
+        jitter_ms: u64,
+        max_elapsed_ms: Option<u64>,
+        idempotency_header: Option<String>,
+    ) -> PyResult<Self> {
+        if backoff_factor < 1.0 {
+            return Err(to_pyvalue_err("`backoff_factor` must be at least 1.0"));
+        }
+        Ok(Self {
+            max_retries,
+            retry_status_codes: retry_status_codes
+                .unwrap_or_else(|| Self::default().retry_status_codes),
+            retry_server_errors,
+            delay_initial_ms,
+            delay_max_ms,
+            backoff_factor,
+            jitter_ms,
+            max_elapsed_ms,
+            idempotency_header,
+        })
+    }
+
+    #[getter]
+    #[pyo3(name = "max_retries")]
+    const fn py_max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    #[getter]
+    #[pyo3(name = "idempotency_header")]
+    fn py_idempotency_header(&self) -> Option<String> {
+        self.idempotency_header.clone()
+    }
+}
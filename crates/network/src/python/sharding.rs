@@ -0,0 +1,132 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::python::to_pyvalue_err;
+use pyo3::prelude::*;
+
+use crate::{
+    python::websocket::to_websocket_pyerr, ratelimiter::quota::Quota,
+    sharding::ShardedWebSocketClient, websocket::WebSocketConfig,
+};
+
+#[pymethods]
+impl ShardedWebSocketClient {
+    #[new]
+    #[pyo3(signature = (config, max_subscriptions, max_connections = None, keyed_quotas = Vec::new(), default_quota = None))]
+    fn py_new(
+        config: WebSocketConfig,
+        max_subscriptions: usize,
+        max_connections: Option<usize>,
+        keyed_quotas: Vec<(String, Quota)>,
+        default_quota: Option<Quota>,
+    ) -> PyResult<Self> {
+        if max_subscriptions == 0 || max_connections == Some(0) {
+            return Err(to_pyvalue_err(
+                "`max_subscriptions` and `max_connections` must be positive",
+            ));
+        }
+        Ok(Self::new(
+            config,
+            max_subscriptions,
+            max_connections,
+            keyed_quotas,
+            default_quota,
+        ))
+    }
+
+    /// Subscribe to the topic by sending the UTF-8 encoded subscribe message.
+    #[pyo3(name = "subscribe")]
+    #[pyo3(signature = (topic, message, keys = None))]
+    fn py_subscribe<'py>(
+        &self,
+        topic: String,
+        message: Vec<u8>,
+        py: Python<'py>,
+        keys: Option<Vec<String>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let message = String::from_utf8(message).map_err(to_pyvalue_err)?;
+        let client = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            client
+                .subscribe(topic, message, keys)
+                .await
+                .map_err(to_websocket_pyerr)
+        })
+    }
+
+    /// Unsubscribe from the topic by sending the UTF-8 encoded unsubscribe message.
+    #[pyo3(name = "unsubscribe")]
+    #[pyo3(signature = (topic, message, keys = None))]
+    fn py_unsubscribe<'py>(
+        &self,
+        topic: String,
+        message: Vec<u8>,
+        py: Python<'py>,
+        keys: Option<Vec<String>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let message = String::from_utf8(message).map_err(to_pyvalue_err)?;
+        let client = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            client
+                .unsubscribe(&topic, message, keys)
+                .await
+                .map_err(to_websocket_pyerr)
+        })
+    }
+
+    /// Send the UTF-8 encoded text data on every connection.
+    #[pyo3(name = "send_text_all")]
+    #[pyo3(signature = (data, keys = None))]
+    fn py_send_text_all<'py>(
+        &self,
+        data: Vec<u8>,
+        py: Python<'py>,
+        keys: Option<Vec<String>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let data = String::from_utf8(data).map_err(to_pyvalue_err)?;
+        let client = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            client
+                .send_text_all(data, keys)
+                .await
+                .map_err(to_websocket_pyerr)
+        })
+    }
+
+    /// Disconnect all connections.
+    #[pyo3(name = "disconnect")]
+    fn py_disconnect<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            client.disconnect().await;
+            Ok(())
+        })
+    }
+
+    /// Return the subscribed topics of each connection.
+    #[pyo3(name = "subscriptions")]
+    fn py_subscriptions<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(
+            py,
+            async move { Ok(client.subscriptions().await) },
+        )
+    }
+}
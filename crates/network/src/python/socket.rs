@@ -24,6 +24,7 @@ use tokio::io::AsyncWriteExt;
 use tokio_tungstenite::tungstenite::stream::Mode;
 
 use crate::{
+    framing::SocketFraming,
     mode::ConnectionMode,
     socket::{SocketClient, SocketConfig},
 };
@@ -31,7 +32,7 @@ use crate::{
 #[pymethods]
 impl SocketConfig {
     #[new]
-    #[pyo3(signature = (url, ssl, suffix, handler, heartbeat=None, reconnect_timeout_ms=10_000, reconnect_delay_initial_ms=2_000, reconnect_delay_max_ms=30_000, reconnect_backoff_factor=1.5, reconnect_jitter_ms=100, certs_dir=None))]
+    #[pyo3(signature = (url, ssl, suffix, handler, heartbeat=None, reconnect_timeout_ms=10_000, reconnect_delay_initial_ms=2_000, reconnect_delay_max_ms=30_000, reconnect_backoff_factor=1.5, reconnect_jitter_ms=100, certs_dir=None, framing=None))]
     #[allow(clippy::too_many_arguments)]
     fn py_new(
         url: String,
@@ -45,6 +46,7 @@ impl SocketConfig {
         reconnect_backoff_factor: Option<f64>,
         reconnect_jitter_ms: Option<u64>,
         certs_dir: Option<String>,
+        framing: Option<SocketFraming>,
     ) -> Self {
         let mode = if ssl { Mode::Tls } else { Mode::Plain };
         Self {
@@ -59,6 +61,7 @@ impl SocketConfig {
             reconnect_backoff_factor,
             reconnect_jitter_ms,
             certs_dir,
+            framing,
         }
     }
 }
@@ -199,10 +202,10 @@ impl SocketClient {
     #[pyo3(name = "send")]
     fn py_send<'py>(
         slf: PyRef<'_, Self>,
-        mut data: Vec<u8>,
+        data: Vec<u8>,
         py: Python<'py>,
     ) -> PyResult<Bound<'py, PyAny>> {
-        data.extend(&slf.suffix);
+        let data = slf.framing.encode(&data);
         tracing::trace!("Sending {}", String::from_utf8_lossy(&data));
 
         let writer = slf.writer.clone();
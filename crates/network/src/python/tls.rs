@@ -0,0 +1,58 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::path::PathBuf;
+
+use nautilus_core::python::to_pyvalue_err;
+use pyo3::prelude::*;
+
+use crate::tls::TlsConfig;
+
+#[pymethods]
+impl TlsConfig {
+    #[new]
+    #[pyo3(signature = (ca_bundle_path = None, client_cert_path = None, client_key_path = None))]
+    fn py_new(
+        ca_bundle_path: Option<PathBuf>,
+        client_cert_path: Option<PathBuf>,
+        client_key_path: Option<PathBuf>,
+    ) -> PyResult<Self> {
+        Self::new(ca_bundle_path, client_cert_path, client_key_path).map_err(to_pyvalue_err)
+    }
+
+    #[getter]
+    #[pyo3(name = "ca_bundle_path")]
+    fn py_ca_bundle_path(&self) -> Option<PathBuf> {
+        self.ca_bundle_path.clone()
+    }
+
+    #[getter]
+    #[pyo3(name = "client_cert_path")]
+    fn py_client_cert_path(&self) -> Option<PathBuf> {
+        self.client_cert_path.clone()
+    }
+
+    #[getter]
+    #[pyo3(name = "client_key_path")]
+    fn py_client_key_path(&self) -> Option<PathBuf> {
+        self.client_key_path.clone()
+    }
+
+    /// Returns whether a client certificate is presented to the server (mutual TLS).
+    #[pyo3(name = "has_client_identity")]
+    const fn py_has_client_identity(&self) -> bool {
+        self.has_client_identity()
+    }
+}
@@ -0,0 +1,174 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::sync::Arc;
+
+use nautilus_core::python::{to_pyruntime_err, to_pyvalue_err};
+use pyo3::{prelude::*, types::PyBytes};
+
+use crate::{
+    framing::SocketFraming,
+    uds::{
+        broadcast_frame, close_writer, default_framing, write_frame, UnixMessageHandler,
+        UnixServerHandler, UnixSocketClient, UnixSocketServer,
+    },
+};
+
+#[pymethods]
+impl UnixSocketServer {
+    /// Bind a server to the socket file at `path`, calling the `handler` with the
+    /// connection ID and bytes of each received message.
+    ///
+    /// Messages are prefixed with a 4-byte length header unless a `framing` is given.
+    #[staticmethod]
+    #[pyo3(name = "bind", signature = (path, handler, framing = None))]
+    fn py_bind(
+        py: Python<'_>,
+        path: String,
+        handler: PyObject,
+        framing: Option<SocketFraming>,
+    ) -> PyResult<Bound<'_, PyAny>> {
+        let handler: UnixServerHandler = Arc::new(move |connection_id, data| {
+            Python::with_gil(|py| {
+                if let Err(e) = handler.call1(py, (connection_id, PyBytes::new(py, &data))) {
+                    tracing::error!("Error calling handler: {e}");
+                }
+            });
+        });
+        let framing = framing.unwrap_or_else(default_framing);
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            Self::bind(path, framing, handler)
+                .await
+                .map_err(to_pyvalue_err)
+        })
+    }
+
+    #[getter]
+    #[pyo3(name = "path")]
+    fn py_path(&self) -> String {
+        self.path().display().to_string()
+    }
+
+    #[pyo3(name = "connection_ids")]
+    fn py_connection_ids(&self) -> Vec<u64> {
+        self.connection_ids()
+    }
+
+    /// Send bytes data to the connection with the `connection_id`.
+    ///
+    /// # Errors
+    ///
+    /// - Raises `RuntimeError` if the connection is not open or the send fails.
+    #[pyo3(name = "send")]
+    fn py_send<'py>(
+        &self,
+        py: Python<'py>,
+        connection_id: u64,
+        data: Vec<u8>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let writer = self.writer(connection_id).map_err(to_pyruntime_err)?;
+        let framing = self.framing().clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            write_frame(&writer, &framing, &data)
+                .await
+                .map_err(to_pyruntime_err)
+        })
+    }
+
+    /// Send bytes data to every open connection, returning the number of connections sent to.
+    #[pyo3(name = "broadcast")]
+    fn py_broadcast<'py>(&self, py: Python<'py>, data: Vec<u8>) -> PyResult<Bound<'py, PyAny>> {
+        let writers = self.writers();
+        let framing = self.framing().clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            Ok(broadcast_frame(writers, &framing, &data).await)
+        })
+    }
+
+    #[pyo3(name = "close")]
+    fn py_close(&self) {
+        self.close();
+    }
+}
+
+#[pymethods]
+impl UnixSocketClient {
+    /// Connect to the server at the socket file `path`, calling the optional `handler`
+    /// with the bytes of each received message.
+    ///
+    /// Messages are prefixed with a 4-byte length header unless a `framing` is given.
+    #[staticmethod]
+    #[pyo3(name = "connect", signature = (path, handler = None, framing = None))]
+    fn py_connect(
+        py: Python<'_>,
+        path: String,
+        handler: Option<PyObject>,
+        framing: Option<SocketFraming>,
+    ) -> PyResult<Bound<'_, PyAny>> {
+        let handler = handler.map(|handler| -> UnixMessageHandler {
+            Arc::new(move |data| {
+                Python::with_gil(|py| {
+                    if let Err(e) = handler.call1(py, (PyBytes::new(py, &data),)) {
+                        tracing::error!("Error calling handler: {e}");
+                    }
+                });
+            })
+        });
+        let framing = framing.unwrap_or_else(default_framing);
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            Self::connect(path, framing, handler)
+                .await
+                .map_err(to_pyruntime_err)
+        })
+    }
+
+    /// Send bytes data to the server.
+    ///
+    /// # Errors
+    ///
+    /// - Raises `RuntimeError` if the send fails.
+    #[pyo3(name = "send")]
+    fn py_send<'py>(&self, py: Python<'py>, data: Vec<u8>) -> PyResult<Bound<'py, PyAny>> {
+        let writer = self.writer.clone();
+        let framing = self.framing.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            write_frame(&writer, &framing, &data)
+                .await
+                .map_err(to_pyruntime_err)
+        })
+    }
+
+    #[pyo3(name = "is_closed")]
+    fn py_is_closed(&self) -> bool {
+        self.is_closed()
+    }
+
+    #[pyo3(name = "close")]
+    fn py_close<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let writer = self.writer.clone();
+        let read_task = self.read_task.abort_handle();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            close_writer(&writer).await;
+            read_task.abort();
+            Ok(())
+        })
+    }
+}
@@ -14,6 +14,7 @@
 // -------------------------------------------------------------------------------------------------
 
 use std::{
+    collections::HashMap,
     sync::{atomic::Ordering, Arc},
     time::Duration,
 };
@@ -24,22 +25,27 @@ use pyo3::{create_exception, exceptions::PyException, prelude::*};
 use tokio_tungstenite::tungstenite::{Message, Utf8Bytes};
 
 use crate::{
+    compression::PayloadCompression,
+    latency::LatencySnapshot,
+    liveness::LivenessPolicy,
     mode::ConnectionMode,
+    proxy::ProxyConfig,
     ratelimiter::quota::Quota,
+    tls::TlsConfig,
     websocket::{WebSocketClient, WebSocketConfig},
 };
 
 // Python exception class for websocket errors
 create_exception!(network, WebSocketClientError, PyException);
 
-fn to_websocket_pyerr(e: tokio_tungstenite::tungstenite::Error) -> PyErr {
+pub(crate) fn to_websocket_pyerr(e: tokio_tungstenite::tungstenite::Error) -> PyErr {
     PyErr::new::<WebSocketClientError, _>(e.to_string())
 }
 
 #[pymethods]
 impl WebSocketConfig {
     #[new]
-    #[pyo3(signature = (url, handler, headers, heartbeat=None, heartbeat_msg=None, ping_handler=None, reconnect_timeout_ms=10_000, reconnect_delay_initial_ms=2_000, reconnect_delay_max_ms=30_000, reconnect_backoff_factor=1.5, reconnect_jitter_ms=100))]
+    #[pyo3(signature = (url, handler, headers, heartbeat=None, heartbeat_msg=None, ping_handler=None, reconnect_timeout_ms=10_000, reconnect_delay_initial_ms=2_000, reconnect_delay_max_ms=30_000, reconnect_backoff_factor=1.5, reconnect_jitter_ms=100, reconnect_max_attempts=None, status_handler=None, payload_compression=None, proxy=None, idle_timeout_ms=None, max_missed_pongs=None, liveness_policy=None, tls=None))]
     #[allow(clippy::too_many_arguments)]
     fn py_new(
         url: String,
@@ -53,6 +59,14 @@ impl WebSocketConfig {
         reconnect_delay_max_ms: Option<u64>,
         reconnect_backoff_factor: Option<f64>,
         reconnect_jitter_ms: Option<u64>,
+        reconnect_max_attempts: Option<u32>,
+        status_handler: Option<PyObject>,
+        payload_compression: Option<PayloadCompression>,
+        proxy: Option<ProxyConfig>,
+        idle_timeout_ms: Option<u64>,
+        max_missed_pongs: Option<u32>,
+        liveness_policy: Option<LivenessPolicy>,
+        tls: Option<TlsConfig>,
     ) -> Self {
         Self {
             url,
@@ -66,6 +80,14 @@ impl WebSocketConfig {
             reconnect_delay_max_ms,
             reconnect_backoff_factor,
             reconnect_jitter_ms,
+            reconnect_max_attempts,
+            status_handler: status_handler.map(Arc::new),
+            payload_compression,
+            proxy,
+            idle_timeout_ms,
+            max_missed_pongs,
+            liveness_policy,
+            tls,
         }
     }
 }
@@ -166,6 +188,19 @@ impl WebSocketClient {
         slf.is_closed()
     }
 
+    /// Returns snapshots of the latencies keyed by name (`connect` and `venue_to_local`).
+    #[pyo3(name = "latency")]
+    fn py_latency(slf: PyRef<'_, Self>) -> HashMap<String, LatencySnapshot> {
+        slf.latency().snapshots()
+    }
+
+    /// Records the venue-to-local latency of a message with the exchange timestamp
+    /// `ts_event` (UNIX nanoseconds), received now.
+    #[pyo3(name = "record_venue_latency")]
+    fn py_record_venue_latency(slf: PyRef<'_, Self>, ts_event: u64) {
+        slf.record_venue_latency(ts_event.into());
+    }
+
     /// Send bytes data to the server.
     ///
     /// # Errors
@@ -282,7 +317,10 @@ mod tests {
     };
     use tracing_test::traced_test;
 
-    use crate::websocket::{WebSocketClient, WebSocketConfig};
+    use crate::{
+        liveness::LivenessPolicy,
+        websocket::{WebSocketClient, WebSocketConfig},
+    };
 
     struct TestServer {
         task: JoinHandle<()>,
@@ -381,6 +419,7 @@ class Counter:
     def __init__(self):
         self.count = 0
         self.check = False
+        self.statuses = []
 
     def handler(self, bytes):
         msg = bytes.decode()
@@ -395,6 +434,9 @@ class Counter:
     def get_count(self):
         return self.count
 
+    def on_status(self, status, attempt):
+        self.statuses.append(status)
+
 counter = Counter()
 ";
 
@@ -439,6 +481,14 @@ counter = Counter()
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         );
         let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None)
             .await
@@ -518,6 +568,14 @@ counter = Counter()
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         );
         let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None)
             .await
@@ -540,4 +598,90 @@ counter = Counter()
         client.disconnect().await;
         assert!(client.is_disconnected());
     }
+
+    fn idle_config(port: u16, counter: &PyObject, policy: LivenessPolicy) -> WebSocketConfig {
+        let (handler, status_handler) = Python::with_gil(|py| {
+            (
+                counter.getattr(py, "handler").unwrap(),
+                counter.getattr(py, "on_status").unwrap(),
+            )
+        });
+        WebSocketConfig::py_new(
+            format!("ws://127.0.0.1:{port}"),
+            handler,
+            vec![("hello-custom-key".to_string(), "value".to_string())],
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(status_handler),
+            None,
+            None,
+            Some(200),
+            None,
+            Some(policy),
+            None,
+        )
+    }
+
+    fn get_statuses(counter: &PyObject) -> Vec<String> {
+        Python::with_gil(|py| {
+            counter
+                .getattr(py, "statuses")
+                .unwrap()
+                .extract(py)
+                .unwrap()
+        })
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn stale_connection_notify_test() {
+        prepare_freethreaded_python();
+
+        let server = TestServer::setup("hello-custom-key".to_string(), "value".to_string()).await;
+        let (counter, _) = create_test_handler();
+        let config = idle_config(server.port, &counter, LivenessPolicy::Notify);
+        let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None)
+            .await
+            .unwrap();
+
+        sleep(Duration::from_millis(500)).await;
+        assert_eq!(get_statuses(&counter), vec!["CONNECTED", "STALE"]);
+
+        // An echoed message ends the stale period, so the next idle period notifies again
+        client.send_bytes(b"ping".to_vec(), None).await.unwrap();
+        sleep(Duration::from_millis(500)).await;
+        assert_eq!(get_statuses(&counter), vec!["CONNECTED", "STALE", "STALE"]);
+        assert!(client.is_active());
+
+        client.disconnect().await;
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn stale_connection_reconnect_test() {
+        prepare_freethreaded_python();
+
+        let server = TestServer::setup("hello-custom-key".to_string(), "value".to_string()).await;
+        let (counter, _) = create_test_handler();
+        let config = idle_config(server.port, &counter, LivenessPolicy::Reconnect);
+        let client = WebSocketClient::connect(config, None, None, None, Vec::new(), None)
+            .await
+            .unwrap();
+
+        sleep(Duration::from_millis(350)).await;
+        let statuses = get_statuses(&counter);
+        assert_eq!(
+            statuses[..4],
+            ["CONNECTED", "STALE", "RECONNECTING", "RECONNECTED"]
+        );
+
+        client.disconnect().await;
+    }
 }
@@ -16,12 +16,17 @@
 //! A rate limiter implementation heavily inspired by [governor](https://github.com/antifuchs/governor)
 //!
 //! The governor does not support different quota for different key. It is an open [issue](https://github.com/antifuchs/governor/issues/193)
+//!
+//! Quotas are hierarchical: an optional global quota applies to every request, with a quota
+//! for each key (or the default quota) applied in addition. Requests can also be paused,
+//! either for a key or for all requests, to back off when a venue reports a rate limit.
 pub mod clock;
 mod gcra;
 mod nanos;
 pub mod quota;
 
 use std::{
+    cmp,
     hash::Hash,
     num::NonZeroU64,
     sync::atomic::{AtomicU64, Ordering},
@@ -33,7 +38,7 @@ use futures_util::StreamExt;
 use tokio::time::sleep;
 
 use self::{
-    clock::{Clock, FakeRelativeClock, MonotonicClock},
+    clock::{Clock, FakeRelativeClock, MonotonicClock, Reference},
     gcra::{Gcra, NotUntil},
     nanos::Nanos,
     quota::Quota,
@@ -126,13 +131,30 @@ impl<K: Hash + Eq + Clone> StateStore for DashMapStateStore<K> {
     }
 }
 
+impl StateStore for InMemoryState {
+    type Key = ();
+
+    fn measure_and_replace<T, F, E>(&self, _key: &Self::Key, f: F) -> Result<T, E>
+    where
+        F: Fn(Option<Nanos>) -> Result<(T, Nanos), E>,
+    {
+        self.measure_and_replace_one(f)
+    }
+}
+
 pub struct RateLimiter<K, C>
 where
     C: Clock,
 {
     default_gcra: Option<Gcra>,
+    global_gcra: Option<Gcra>,
+    global_state: InMemoryState,
     state: DashMapStateStore<K>,
     gcra: DashMap<K, Gcra>,
+    /// The time (nanoseconds since `start`) all requests are paused until, zero if not paused.
+    global_paused_until: AtomicU64,
+    /// The time (nanoseconds since `start`) requests for each key are paused until.
+    paused_until: DashMap<K, Nanos>,
     clock: C,
     start: C::Instant,
 }
@@ -147,8 +169,12 @@ where
         let gcra = DashMap::from_iter(keyed_quotas.into_iter().map(|(k, q)| (k, Gcra::new(q))));
         Self {
             default_gcra: base_quota.map(Gcra::new),
+            global_gcra: None,
+            global_state: InMemoryState::default(),
             state: DashMapStateStore::new(),
             gcra,
+            global_paused_until: AtomicU64::new(0),
+            paused_until: DashMap::new(),
             clock,
             start,
         }
@@ -169,10 +195,23 @@ where
     K: Hash + Eq + Clone,
     C: Clock,
 {
+    /// Sets the global quota, which applies to every request in addition to the keyed quotas.
+    #[must_use]
+    pub fn with_global_quota(mut self, quota: Quota) -> Self {
+        self.global_gcra = Some(Gcra::new(quota));
+        self
+    }
+
     pub fn add_quota_for_key(&self, key: K, value: Quota) {
         self.gcra.insert(key, Gcra::new(value));
     }
 
+    pub fn check_global(&self) -> Result<(), NotUntil<C::Instant>> {
+        self.global_gcra.as_ref().map_or(Ok(()), |gcra| {
+            gcra.test_and_update(self.start, &(), &self.global_state, self.clock.now())
+        })
+    }
+
     pub fn check_key(&self, key: &K) -> Result<(), NotUntil<C::Instant>> {
         match self.gcra.get(key) {
             Some(quota) => quota.test_and_update(self.start, key, &self.state, self.clock.now()),
@@ -182,8 +221,63 @@ where
         }
     }
 
+    /// Pauses all requests for the given duration from now (extending any existing pause).
+    pub fn pause_all(&self, duration: Duration) {
+        let until = self.elapsed() + duration;
+        self.global_paused_until
+            .fetch_max(until.as_u64(), Ordering::AcqRel);
+    }
+
+    /// Pauses requests for the key for the given duration from now (extending any existing pause).
+    pub fn pause_key(&self, key: K, duration: Duration) {
+        let until = self.elapsed() + duration;
+        self.paused_until
+            .entry(key)
+            .and_modify(|paused_until| *paused_until = cmp::max(*paused_until, until))
+            .or_insert(until);
+    }
+
+    /// Returns the remaining pause for the key (or for all requests if `None`),
+    /// or `None` if requests are not paused.
+    pub fn paused_for(&self, key: Option<&K>) -> Option<Duration> {
+        let mut until = Nanos::from(self.global_paused_until.load(Ordering::Acquire));
+        if let Some(key_until) = key.and_then(|key| self.paused_until.get(key)) {
+            until = cmp::max(until, *key_until);
+        }
+
+        let elapsed = self.elapsed();
+        (until > elapsed).then(|| until.saturating_sub(elapsed).into())
+    }
+
+    fn elapsed(&self) -> Nanos {
+        self.clock.now().duration_since(self.start)
+    }
+
+    pub async fn until_global_ready(&self) {
+        loop {
+            if let Some(pause) = self.paused_for(None) {
+                sleep(pause).await;
+                continue;
+            }
+
+            match self.check_global() {
+                Ok(()) => {
+                    break;
+                }
+                Err(neg) => {
+                    sleep(neg.wait_time_from(self.clock.now())).await;
+                }
+            }
+        }
+    }
+
     pub async fn until_key_ready(&self, key: &K) {
         loop {
+            if let Some(pause) = self.paused_for(Some(key)) {
+                sleep(pause).await;
+                continue;
+            }
+
             match self.check_key(key) {
                 Ok(()) => {
                     break;
@@ -196,6 +290,8 @@ where
     }
 
     pub async fn await_keys_ready(&self, keys: Option<Vec<K>>) {
+        self.until_global_ready().await;
+
         let keys = keys.unwrap_or_default();
         let tasks = keys.iter().map(|key| self.until_key_ready(key));
 
@@ -212,7 +308,7 @@ where
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use std::{num::NonZeroU32, time::Duration};
+    use std::{num::NonZeroU32, sync::atomic::AtomicU64, time::Duration};
 
     use dashmap::DashMap;
 
@@ -220,7 +316,7 @@ mod tests {
         clock::{Clock, FakeRelativeClock},
         gcra::Gcra,
         quota::Quota,
-        DashMapStateStore, RateLimiter,
+        DashMapStateStore, InMemoryState, RateLimiter,
     };
 
     fn initialize_mock_rate_limiter() -> RateLimiter<String, FakeRelativeClock> {
@@ -230,8 +326,12 @@ mod tests {
         let base_quota = Quota::per_second(NonZeroU32::new(2).unwrap());
         RateLimiter {
             default_gcra: Some(Gcra::new(base_quota)),
+            global_gcra: None,
+            global_state: InMemoryState::default(),
             state: DashMapStateStore::new(),
             gcra,
+            global_paused_until: AtomicU64::new(0),
+            paused_until: DashMap::new(),
             clock,
             start,
         }
@@ -363,4 +463,87 @@ mod tests {
             .await;
         assert!(mock_limiter.check_key(&"default".to_string()).is_ok());
     }
+
+    #[test]
+    fn test_global_quota() {
+        let mock_limiter = initialize_mock_rate_limiter()
+            .with_global_quota(Quota::per_second(NonZeroU32::new(3).unwrap()));
+
+        // Check global quota applies across all requests
+        assert!(mock_limiter.check_global().is_ok());
+        assert!(mock_limiter.check_global().is_ok());
+        assert!(mock_limiter.check_global().is_ok());
+        assert!(mock_limiter.check_global().is_err());
+
+        // Check keyed quotas are independent of the global quota
+        assert!(mock_limiter.check_key(&"default".to_string()).is_ok());
+
+        // Increment clock and check global quota is reset
+        mock_limiter.advance_clock(Duration::from_secs(1));
+        assert!(mock_limiter.check_global().is_ok());
+    }
+
+    #[test]
+    fn test_pause_key() {
+        let mock_limiter = initialize_mock_rate_limiter();
+        let key = "order".to_string();
+
+        mock_limiter.pause_key(key.clone(), Duration::from_secs(2));
+
+        assert_eq!(
+            mock_limiter.paused_for(Some(&key)),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(mock_limiter.paused_for(Some(&"other".to_string())), None);
+        assert_eq!(mock_limiter.paused_for(None), None);
+
+        // A shorter pause does not shorten the existing pause
+        mock_limiter.pause_key(key.clone(), Duration::from_secs(1));
+        assert_eq!(
+            mock_limiter.paused_for(Some(&key)),
+            Some(Duration::from_secs(2))
+        );
+
+        mock_limiter.advance_clock(Duration::from_millis(1500));
+        assert_eq!(
+            mock_limiter.paused_for(Some(&key)),
+            Some(Duration::from_millis(500))
+        );
+
+        mock_limiter.advance_clock(Duration::from_millis(500));
+        assert_eq!(mock_limiter.paused_for(Some(&key)), None);
+    }
+
+    #[test]
+    fn test_pause_all() {
+        let mock_limiter = initialize_mock_rate_limiter();
+        let key = "order".to_string();
+
+        mock_limiter.pause_key(key.clone(), Duration::from_secs(1));
+        mock_limiter.pause_all(Duration::from_secs(3));
+
+        assert_eq!(mock_limiter.paused_for(None), Some(Duration::from_secs(3)));
+        assert_eq!(
+            mock_limiter.paused_for(Some(&key)),
+            Some(Duration::from_secs(3))
+        );
+
+        mock_limiter.advance_clock(Duration::from_secs(3));
+        assert_eq!(mock_limiter.paused_for(None), None);
+        assert_eq!(mock_limiter.paused_for(Some(&key)), None);
+    }
+
+    #[tokio::test]
+    async fn test_await_keys_ready_after_pause() {
+        let mock_limiter = initialize_mock_rate_limiter();
+        mock_limiter.pause_all(Duration::from_millis(10));
+
+        // Advance past the pause so waiting completes
+        mock_limiter.advance_clock(Duration::from_millis(10));
+        mock_limiter
+            .await_keys_ready(Some(vec!["default".to_string()]))
+            .await;
+        assert!(mock_limiter.check_key(&"default".to_string()).is_ok());
+        assert!(mock_limiter.check_key(&"default".to_string()).is_err());
+    }
 }
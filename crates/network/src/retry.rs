@@ -0,0 +1,242 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Retry policy for HTTP requests.
+//!
+//! Safe and idempotent requests (`GET`, `PUT`, `DELETE`, ...) are retried on transport errors
+//! and retryable statuses. Unsafe requests (`POST` and `PATCH`) are only retried when the
+//! policy has an idempotency header, which is sent with the same key on every attempt so the
+//! venue can deduplicate them. Callers can supply their own key in the request headers (such as
+//! a client order ID), otherwise a key is generated for the request.
+
+use std::{collections::HashMap, time::Duration};
+
+use nautilus_core::UUID4;
+use reqwest::Method;
+
+use crate::{
+    backoff::ExponentialBackoff,
+    http::{header_value, HttpStatus},
+};
+
+/// The statuses retried by default (in addition to server errors).
+const DEFAULT_RETRY_STATUS_CODES: [u16; 2] = [408, 429];
+
+/// Configuration for retrying failed HTTP requests.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.network")
+)]
+pub struct HttpRetryPolicy {
+    /// The maximum number of retries for a request.
+    pub max_retries: u32,
+    /// The status codes to retry.
+    pub retry_status_codes: Vec<u16>,
+    /// If server error (5xx) statuses are retried.
+    pub retry_server_errors: bool,
+    /// The initial delay (milliseconds) before retrying.
+    pub delay_initial_ms: u64,
+    /// The maximum delay (milliseconds) between retries.
+    pub delay_max_ms: u64,
+    /// The exponential backoff factor for the delays between retries.
+    pub backoff_factor: f64,
+    /// The maximum jitter (milliseconds) added to the delays between retries.
+    pub jitter_ms: u64,
+    /// The time budget (milliseconds) for a request including its retries, after which
+    /// no further retries are made.
+    pub max_elapsed_ms: Option<u64>,
+    /// The header carrying the idempotency key of unsafe requests (such as `Idempotency-Key`),
+    /// which are not retried if `None`.
+    pub idempotency_header: Option<String>,
+}
+
+impl Default for HttpRetryPolicy {
+    /// Creates a new default [`HttpRetryPolicy`] instance.
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            retry_status_codes: DEFAULT_RETRY_STATUS_CODES.to_vec(),
+            retry_server_errors: true,
+            delay_initial_ms: 500,
+            delay_max_ms: 10_000,
+            backoff_factor: 2.0,
+            jitter_ms: 100,
+            max_elapsed_ms: None,
+            idempotency_header: None,
+        }
+    }
+}
+
+impl HttpRetryPolicy {
+    /// Returns whether requests with the `method` are idempotent.
+    #[must_use]
+    pub fn is_idempotent(method: &Method) -> bool {
+        method != Method::POST && method != Method::PATCH
+    }
+
+    /// Prepares a request with the `method` and `headers` for retrying, returning whether
+    /// it can be retried.
+    ///
+    /// Unsafe requests are given an idempotency key (unless the `headers` already have one).
+    pub fn prepare_request(
+        &self,
+        method: &Method,
+        headers: &mut Option<HashMap<String, String>>,
+    ) -> bool {
+        if Self::is_idempotent(method) {
+            return true;
+        }
+
+        let Some(idempotency_header) = &self.idempotency_header else {
+            return false;
+        };
+        let headers = headers.get_or_insert_with(HashMap::new);
+        if header_value(headers, idempotency_header).is_none() {
+            headers.insert(idempotency_header.clone(), UUID4::new().to_string());
+        }
+        true
+    }
+
+    /// Returns whether responses with the `status` are retried.
+    #[must_use]
+    pub fn is_retryable_status(&self, status: &HttpStatus) -> bool {
+        (self.retry_server_errors && status.is_server_error())
+            || self.retry_status_codes.contains(&status.as_u16())
+    }
+
+    /// Returns whether the retry after `elapsed` is within the time budget.
+    #[must_use]
+    pub fn within_budget(&self, elapsed: Duration) -> bool {
+        self.max_elapsed_ms
+            .is_none_or(|max_elapsed_ms| elapsed <= Duration::from_millis(max_elapsed_ms))
+    }
+
+    /// Returns the backoff for the delays between the retries of a request.
+    #[must_use]
+    pub fn backoff(&self) -> ExponentialBackoff {
+        ExponentialBackoff::new(
+            Duration::from_millis(self.delay_initial_ms),
+            Duration::from_millis(self.delay_max_ms),
+            self.backoff_factor,
+            self.jitter_ms,
+            false, // delay the first retry
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(Method::GET, true)]
+    #[case(Method::PUT, true)]
+    #[case(Method::DELETE, true)]
+    #[case(Method::POST, false)]
+    #[case(Method::PATCH, false)]
+    fn test_prepare_request_without_idempotency_header(
+        #[case] method: Method,
+        #[case] expected: bool,
+    ) {
+        let policy = HttpRetryPolicy::default();
+        let mut headers = None;
+
+        assert_eq!(policy.prepare_request(&method, &mut headers), expected);
+        assert!(headers.is_none());
+    }
+
+    #[rstest]
+    fn test_prepare_request_generates_idempotency_key() {
+        let policy = HttpRetryPolicy {
+            idempotency_header: Some("Idempotency-Key".to_string()),
+            ..Default::default()
+        };
+        let mut headers = None;
+
+        assert!(policy.prepare_request(&Method::POST, &mut headers));
+
+        let key = headers.unwrap().remove("Idempotency-Key").unwrap();
+        assert!(key.parse::<UUID4>().is_ok());
+    }
+
+    #[rstest]
+    fn test_prepare_request_keeps_given_idempotency_key() {
+        let policy = HttpRetryPolicy {
+            idempotency_header: Some("Idempotency-Key".to_string()),
+            ..Default::default()
+        };
+        let mut headers = Some(HashMap::from([(
+            "idempotency-key".to_string(),
+            "O-123".to_string(),
+        )]));
+
+        assert!(policy.prepare_request(&Method::POST, &mut headers));
+        assert_eq!(
+            headers.unwrap(),
+            HashMap::from([("idempotency-key".to_string(), "O-123".to_string())])
+        );
+    }
+
+    #[rstest]
+    #[case(200, false)]
+    #[case(400, false)]
+    #[case(408, true)]
+    #[case(429, true)]
+    #[case(500, true)]
+    #[case(503, true)]
+    fn test_is_retryable_status(#[case] code: u16, #[case] expected: bool) {
+        let policy = HttpRetryPolicy::default();
+
+        assert_eq!(
+            policy.is_retryable_status(&HttpStatus::from(code).unwrap()),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn test_is_retryable_status_without_server_errors() {
+        let policy = HttpRetryPolicy {
+            retry_server_errors: false,
+            retry_status_codes: vec![503],
+            ..Default::default()
+        };
+
+        assert!(!policy.is_retryable_status(&HttpStatus::from(500).unwrap()));
+        assert!(policy.is_retryable_status(&HttpStatus::from(503).unwrap()));
+    }
+
+    #[rstest]
+    #[case(None, Duration::from_secs(60), true)]
+    #[case(Some(1_000), Duration::from_millis(1_000), true)]
+    #[case(Some(1_000), Duration::from_millis(1_001), false)]
+    fn test_within_budget(
+        #[case] max_elapsed_ms: Option<u64>,
+        #[case] elapsed: Duration,
+        #[case] expected: bool,
+    ) {
+        let policy = HttpRetryPolicy {
+            max_elapsed_ms,
+            ..Default::default()
+        };
+
+        assert_eq!(policy.within_budget(elapsed), expected);
+    }
+}
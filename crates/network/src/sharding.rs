@@ -0,0 +1,394 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Sharding of subscriptions across multiple WebSocket connections.
+//!
+//! Venues commonly cap the number of subscriptions per connection (for example Binance allows
+//! 1024 streams per connection). The [`ShardedWebSocketClient`] spreads subscriptions across as
+//! many connections as required, exposed to adapters as a single logical client:
+//! - New subscriptions are added to the connection with the fewest subscriptions, opening a
+//!   new connection when all connections are at the limit.
+//! - Inbound messages from every connection are passed to the same handler.
+//! - Subscriptions are replayed on a connection after it reconnects.
+
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+use tokio_tungstenite::tungstenite::Error;
+
+use crate::{
+    ratelimiter::quota::Quota,
+    websocket::{WebSocketClient, WebSocketConfig},
+};
+
+/// A connection of a [`ShardedWebSocketClient`] with its subscriptions.
+struct Shard {
+    client: Arc<WebSocketClient>,
+    topics: Vec<String>,
+}
+
+/// A WebSocket client which shards subscriptions across multiple connections.
+///
+/// Each connection uses the same configuration (and so the same message handler), with its
+/// own rate limiter for the keyed and default quotas.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.network")
+)]
+pub struct ShardedWebSocketClient {
+    pub(crate) config: WebSocketConfig,
+    pub(crate) max_subscriptions: usize,
+    pub(crate) max_connections: Option<usize>,
+    pub(crate) keyed_quotas: Vec<(String, Quota)>,
+    pub(crate) default_quota: Option<Quota>,
+    shards: Arc<Mutex<Vec<Shard>>>,
+}
+
+impl ShardedWebSocketClient {
+    /// Creates a new [`ShardedWebSocketClient`] instance.
+    ///
+    /// Connections are opened as subscriptions are added, with up to `max_subscriptions`
+    /// per connection and up to `max_connections` connections (unlimited if `None`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_subscriptions` or `max_connections` is zero.
+    #[must_use]
+    pub fn new(
+        config: WebSocketConfig,
+        max_subscriptions: usize,
+        max_connections: Option<usize>,
+        keyed_quotas: Vec<(String, Quota)>,
+        default_quota: Option<Quota>,
+    ) -> Self {
+        assert!(
+            max_subscriptions > 0,
+            "`max_subscriptions` must be positive"
+        );
+        assert!(
+            max_connections != Some(0),
+            "`max_connections` must be positive"
+        );
+        Self {
+            config,
+            max_subscriptions,
+            max_connections,
+            keyed_quotas,
+            default_quota,
+            shards: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Returns the number of open connections.
+    pub async fn connection_count(&self) -> usize {
+        self.shards.lock().await.len()
+    }
+
+    /// Returns the subscribed topics of each connection.
+    pub async fn subscriptions(&self) -> Vec<Vec<String>> {
+        self.shards
+            .lock()
+            .await
+            .iter()
+            .map(|shard| shard.topics.clone())
+            .collect()
+    }
+
+    /// Returns whether the `topic` is subscribed.
+    pub async fn is_subscribed(&self, topic: &str) -> bool {
+        self.shards
+            .lock()
+            .await
+            .iter()
+            .any(|shard| shard.topics.iter().any(|t| t == topic))
+    }
+
+    /// Subscribes to the `topic` by sending the subscribe `message` on the connection with
+    /// the fewest subscriptions, opening a new connection if all connections are full.
+    ///
+    /// The `message` is replayed if the connection reconnects. Subscribing to a topic which
+    /// is already subscribed does nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the maximum connections are all full, or if connecting or sending fails.
+    pub async fn subscribe(
+        &self,
+        topic: String,
+        message: String,
+        keys: Option<Vec<String>>,
+    ) -> Result<(), Error> {
+        let mut shards = self.shards.lock().await;
+        if shards.iter().any(|shard| shard.topics.contains(&topic)) {
+            tracing::debug!("Already subscribed to {topic}");
+            return Ok(());
+        }
+
+        let index = match self.select_shard(&shards) {
+            Some(index) => index,
+            None => {
+                if self
+                    .max_connections
+                    .is_some_and(|max_connections| shards.len() >= max_connections)
+                {
+                    return Err(Error::Io(std::io::Error::other(format!(
+                        "cannot subscribe to {topic}: all {} connections have {} subscriptions",
+                        shards.len(),
+                        self.max_subscriptions,
+                    ))));
+                }
+                tracing::debug!("Opening connection {} for subscriptions", shards.len() + 1);
+                let client = WebSocketClient::connect(
+                    self.config.clone(),
+                    None,
+                    None,
+                    None,
+                    self.keyed_quotas.clone(),
+                    self.default_quota,
+                )
+                .await?;
+                shards.push(Shard {
+                    client: Arc::new(client),
+                    topics: Vec::new(),
+                });
+                shards.len() - 1
+            }
+        };
+
+        let shard = &mut shards[index];
+        shard.client.send_text(message.clone(), keys).await?;
+        shard.client.add_replay_message(topic.clone(), message);
+        shard.topics.push(topic);
+        Ok(())
+    }
+
+    /// Unsubscribes from the `topic` by sending the unsubscribe `message` on its connection.
+    ///
+    /// A connection left with no subscriptions is closed (unless it is the only connection).
+    /// Unsubscribing from a topic which is not subscribed does nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if sending the message fails.
+    pub async fn unsubscribe(
+        &self,
+        topic: &str,
+        message: String,
+        keys: Option<Vec<String>>,
+    ) -> Result<(), Error> {
+        let mut shards = self.shards.lock().await;
+        let Some(index) = shards
+            .iter()
+            .position(|shard| shard.topics.iter().any(|t| t == topic))
+        else {
+            tracing::debug!("Not subscribed to {topic}");
+            return Ok(());
+        };
+
+        let shard = &mut shards[index];
+        shard.client.remove_replay_message(topic);
+        shard.topics.retain(|t| t != topic);
+        shard.client.send_text(message, keys).await?;
+
+        if shard.topics.is_empty() && shards.len() > 1 {
+            let shard = shards.remove(index);
+            tracing::debug!("Closing connection with no subscriptions");
+            shard.client.disconnect().await;
+        }
+        Ok(())
+    }
+
+    /// Sends the text `data` on every connection, such as for application level pings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if sending on any connection fails.
+    pub async fn send_text_all(
+        &self,
+        data: String,
+        keys: Option<Vec<String>>,
+    ) -> Result<(), Error> {
+        let clients: Vec<Arc<WebSocketClient>> = self
+            .shards
+            .lock()
+            .await
+            .iter()
+            .map(|shard| shard.client.clone())
+            .collect();
+        for client in clients {
+            client.send_text(data.clone(), keys.clone()).await?;
+        }
+        Ok(())
+    }
+
+    /// Disconnects all connections, clearing the subscriptions.
+    pub async fn disconnect(&self) {
+        let shards: Vec<Shard> = self.shards.lock().await.drain(..).collect();
+        for shard in shards {
+            shard.client.disconnect().await;
+        }
+    }
+
+    /// Returns the index of the open connection with the fewest subscriptions below the
+    /// maximum, preferring the earliest connection on ties.
+    fn select_shard(&self, shards: &[Shard]) -> Option<usize> {
+        shards
+            .iter()
+            .enumerate()
+            .filter(|(_, shard)| shard.topics.len() < self.max_subscriptions)
+            .filter(|(_, shard)| !shard.client.is_closed())
+            .min_by_key(|(_, shard)| shard.topics.len())
+            .map(|(index, _)| index)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+#[cfg(target_os = "linux")] // Only run network tests on Linux (CI stability)
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use futures_util::StreamExt;
+    use tokio::{net::TcpListener, task};
+    use tokio_tungstenite::accept_async;
+
+    use super::*;
+
+    /// Starts a server which accepts connections and reads messages, returning its
+    /// port and the count of accepted connections.
+    async fn start_server() -> (u16, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let connections = Arc::new(AtomicUsize::new(0));
+        let count = connections.clone();
+
+        task::spawn(async move {
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                count.fetch_add(1, Ordering::SeqCst);
+                task::spawn(async move {
+                    let mut websocket = accept_async(stream).await.unwrap();
+                    while let Some(Ok(_)) = websocket.next().await {}
+                });
+            }
+        });
+
+        (port, connections)
+    }
+
+    fn config(port: u16) -> WebSocketConfig {
+        WebSocketConfig {
+            url: format!("ws://127.0.0.1:{port}"),
+            headers: vec![],
+            handler: None,
+            heartbeat: None,
+            heartbeat_msg: None,
+            ping_handler: None,
+            reconnect_timeout_ms: None,
+            reconnect_delay_initial_ms: None,
+            reconnect_delay_max_ms: None,
+            reconnect_backoff_factor: None,
+            reconnect_jitter_ms: None,
+            reconnect_max_attempts: None,
+            status_handler: None,
+            payload_compression: None,
+            proxy: None,
+            idle_timeout_ms: None,
+            max_missed_pongs: None,
+            liveness_policy: None,
+            tls: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscriptions_sharded_across_connections() {
+        let (port, connections) = start_server().await;
+        let client = ShardedWebSocketClient::new(config(port), 2, None, vec![], None);
+
+        for topic in ["a", "b", "c", "a"] {
+            client
+                .subscribe(topic.to_string(), format!("sub {topic}"), None)
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(client.connection_count().await, 2);
+        assert_eq!(connections.load(Ordering::SeqCst), 2);
+        assert_eq!(
+            client.subscriptions().await,
+            vec![
+                vec!["a".to_string(), "b".to_string()],
+                vec!["c".to_string()]
+            ]
+        );
+
+        client.disconnect().await;
+        assert_eq!(client.connection_count().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_balances_to_least_loaded_connection() {
+        let (port, _) = start_server().await;
+        let client = ShardedWebSocketClient::new(config(port), 2, None, vec![], None);
+        for topic in ["a", "b", "c"] {
+            client
+                .subscribe(topic.to_string(), format!("sub {topic}"), None)
+                .await
+                .unwrap();
+        }
+
+        client
+            .unsubscribe("a", "unsub a".to_string(), None)
+            .await
+            .unwrap();
+        client
+            .unsubscribe("b", "unsub b".to_string(), None)
+            .await
+            .unwrap();
+        client
+            .subscribe("d".to_string(), "sub d".to_string(), None)
+            .await
+            .unwrap();
+
+        // The emptied connection was closed, so both remaining topics share one connection
+        assert_eq!(client.connection_count().await, 1);
+        assert!(client.is_subscribed("d").await);
+        assert!(!client.is_subscribed("a").await);
+
+        client.disconnect().await;
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_fails_when_max_connections_full() {
+        let (port, _) = start_server().await;
+        let client = ShardedWebSocketClient::new(config(port), 1, Some(1), vec![], None);
+        client
+            .subscribe("a".to_string(), "sub a".to_string(), None)
+            .await
+            .unwrap();
+
+        let result = client
+            .subscribe("b".to_string(), "sub b".to_string(), None)
+            .await;
+
+        assert!(result.is_err());
+        assert!(!client.is_subscribed("b").await);
+
+        client.disconnect().await;
+    }
+}
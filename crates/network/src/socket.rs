@@ -15,6 +15,9 @@
 
 //! High-performance raw TCP client implementation with TLS capability, automatic reconnection
 //! with exponential backoff and state management.
+//!
+//! Messages are framed on the byte stream with a pluggable [`SocketFraming`] strategy
+//! (delimited, length prefixed or FIX), for implementing binary venue protocols.
 
 use std::{
     path::Path,
@@ -39,6 +42,7 @@ use tokio_tungstenite::{
 
 use crate::{
     backoff::ExponentialBackoff,
+    framing::SocketFraming,
     mode::ConnectionMode,
     tls::{create_tls_config_from_certs_dir, tcp_tls, Connector},
 };
@@ -76,6 +80,20 @@ pub struct SocketConfig {
     pub reconnect_jitter_ms: Option<u64>,
     /// The path to the certificates directory.
     pub certs_dir: Option<String>,
+    /// The message framing, if `None` messages are delimited by the `suffix`.
+    pub framing: Option<SocketFraming>,
+}
+
+impl SocketConfig {
+    /// Returns the framing of messages on the byte stream.
+    #[must_use]
+    pub fn message_framing(&self) -> SocketFraming {
+        self.framing
+            .clone()
+            .unwrap_or_else(|| SocketFraming::Delimiter {
+                delimiter: self.suffix.clone(),
+            })
+    }
 }
 
 /// Creates a TcpStream with the server.
@@ -90,9 +108,9 @@ pub struct SocketConfig {
 /// The heartbeat is optional and can be configured with an interval and data to
 /// send.
 ///
-/// The client uses a suffix to separate messages on the byte stream. It is
-/// appended to all sent messages and heartbeats. It is also used to split
-/// the received byte stream.
+/// The client uses a framing strategy (by default the suffix delimiter) to
+/// separate messages on the byte stream. It is applied to all sent messages
+/// and heartbeats, and is also used to split the received byte stream.
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.network")
@@ -116,7 +134,7 @@ impl SocketClientInner {
             url,
             mode,
             heartbeat,
-            suffix,
+            suffix: _,
             handler,
             reconnect_timeout_ms,
             reconnect_delay_initial_ms,
@@ -124,7 +142,11 @@ impl SocketClientInner {
             reconnect_backoff_factor,
             reconnect_jitter_ms,
             certs_dir,
+            framing: _,
         } = &config;
+        let framing = config.message_framing();
+        framing.validate()?;
+
         let connector = if let Some(dir) = certs_dir {
             let config = create_tls_config_from_certs_dir(Path::new(dir))?;
             Some(Connector::Rustls(Arc::new(config)))
@@ -138,7 +160,7 @@ impl SocketClientInner {
         let connection_mode = Arc::new(AtomicU8::new(ConnectionMode::Active.as_u8()));
 
         let handler = Python::with_gil(|py| handler.clone_ref(py));
-        let read_task = Arc::new(Self::spawn_read_task(reader, handler, suffix.clone()));
+        let read_task = Arc::new(Self::spawn_read_task(reader, handler, framing.clone()));
 
         // Optionally spawn a heartbeat task to periodically ping server
         let heartbeat_task = heartbeat.as_ref().map(|heartbeat| {
@@ -146,7 +168,7 @@ impl SocketClientInner {
                 connection_mode.clone(),
                 heartbeat.clone(),
                 writer.clone(),
-                suffix.clone(),
+                framing.clone(),
             )
         });
 
@@ -201,11 +223,12 @@ impl SocketClientInner {
             )
             .await;
 
+            let framing = self.config.message_framing();
             let SocketConfig {
                 url,
                 mode,
                 heartbeat,
-                suffix,
+                suffix: _,
                 handler,
                 reconnect_timeout_ms: _,
                 reconnect_delay_initial_ms: _,
@@ -213,6 +236,7 @@ impl SocketClientInner {
                 reconnect_delay_max_ms: _,
                 reconnect_jitter_ms: _,
                 certs_dir: _,
+                framing: _,
             } = &self.config;
             // Create a fresh connection
             let connector = self.connector.clone();
@@ -225,7 +249,7 @@ impl SocketClientInner {
             self.read_task = Arc::new(Self::spawn_read_task(
                 reader,
                 handler_for_read,
-                suffix.clone(),
+                framing.clone(),
             ));
 
             // Optionally spawn new heartbeat task
@@ -234,7 +258,7 @@ impl SocketClientInner {
                     self.connection_mode.clone(),
                     heartbeat.clone(),
                     writer.clone(),
-                    suffix.clone(),
+                    framing.clone(),
                 )
             });
 
@@ -272,7 +296,7 @@ impl SocketClientInner {
     fn spawn_read_task(
         mut reader: TcpReader,
         handler: PyObject,
-        suffix: Vec<u8>,
+        framing: SocketFraming,
     ) -> tokio::task::JoinHandle<()> {
         tracing::debug!("Started task 'read'");
 
@@ -294,16 +318,9 @@ impl SocketClientInner {
                     Ok(bytes) => {
                         tracing::trace!("Received <binary> {bytes} bytes");
 
-                        // While received data has a line break
+                        // While received data has a complete message
                         // drain it and pass it to the handler
-                        while let Some((i, _)) = &buf
-                            .windows(suffix.len())
-                            .enumerate()
-                            .find(|(_, pair)| pair.eq(&suffix))
-                        {
-                            let mut data: Vec<u8> = buf.drain(0..i + suffix.len()).collect();
-                            data.truncate(data.len() - suffix.len());
-
+                        while let Some(data) = framing.decode(&mut buf) {
                             if let Err(e) =
                                 Python::with_gil(|py| handler.call1(py, (data.as_slice(),)))
                             {
@@ -323,14 +340,14 @@ impl SocketClientInner {
         connection_state: Arc<AtomicU8>,
         heartbeat: (u64, Vec<u8>),
         writer: SharedTcpWriter,
-        suffix: Vec<u8>,
+        framing: SocketFraming,
     ) -> tokio::task::JoinHandle<()> {
         tracing::debug!("Started task 'heartbeat'");
-        let (interval_secs, mut message) = heartbeat;
+        let (interval_secs, message) = heartbeat;
 
         tokio::task::spawn(async move {
             let interval = Duration::from_secs(interval_secs);
-            message.extend(suffix);
+            let message = framing.encode(&message);
 
             loop {
                 tokio::time::sleep(interval).await;
@@ -421,7 +438,7 @@ pub struct SocketClient {
     pub(crate) writer: SharedTcpWriter,
     pub(crate) controller_task: tokio::task::JoinHandle<()>,
     pub(crate) connection_mode: Arc<AtomicU8>,
-    pub(crate) suffix: Vec<u8>,
+    pub(crate) framing: SocketFraming,
 }
 
 impl SocketClient {
@@ -431,7 +448,7 @@ impl SocketClient {
         post_reconnection: Option<PyObject>,
         post_disconnection: Option<PyObject>,
     ) -> anyhow::Result<Self> {
-        let suffix = config.suffix.clone();
+        let framing = config.message_framing();
         let inner = SocketClientInner::connect_url(config).await?;
         let writer = inner.writer.clone();
         let connection_mode = inner.connection_mode.clone();
@@ -454,7 +471,7 @@ impl SocketClient {
             writer,
             controller_task,
             connection_mode,
-            suffix,
+            framing,
         })
     }
 
@@ -577,9 +594,9 @@ impl SocketClient {
             }
         }
 
+        let frame = self.framing.encode(data);
         let mut writer = self.writer.lock().await;
-        writer.write_all(data).await?;
-        writer.write_all(&self.suffix).await
+        writer.write_all(&frame).await
     }
 
     fn spawn_controller_task(
@@ -771,6 +788,7 @@ counter = Counter()
             reconnect_delay_max_ms: None,
             reconnect_jitter_ms: None,
             certs_dir: None,
+            framing: None,
         };
 
         let client = SocketClient::connect(config, None, None, None)
@@ -807,6 +825,7 @@ counter = Counter()
             reconnect_delay_max_ms: None,
             reconnect_jitter_ms: None,
             certs_dir: None,
+            framing: None,
         };
 
         let client_res = SocketClient::connect(config, None, None, None).await;
@@ -847,6 +866,7 @@ counter = Counter()
             reconnect_delay_max_ms: None,
             reconnect_jitter_ms: None,
             certs_dir: None,
+            framing: None,
         };
 
         let client = SocketClient::connect(config, None, None, None)
@@ -906,6 +926,7 @@ counter = Counter()
             reconnect_delay_max_ms: None,
             reconnect_jitter_ms: None,
             certs_dir: None,
+            framing: None,
         };
 
         let client = SocketClient::connect(config, None, None, None)
@@ -974,6 +995,7 @@ def handler(bytes_data):
             reconnect_delay_max_ms: None,
             reconnect_jitter_ms: None,
             certs_dir: None,
+            framing: None,
         };
 
         let client = SocketClient::connect(config, None, None, None)
@@ -1032,6 +1054,7 @@ def handler(bytes_data):
             reconnect_backoff_factor: Some(2.0),
             reconnect_jitter_ms: Some(50),
             certs_dir: None,
+            framing: None,
         };
 
         let client = SocketClient::connect(config, None, None, None)
@@ -15,8 +15,14 @@
 
 //! Module for wrapping raw socket streams with TLS encryption.
 
-use std::{fs::File, io::BufReader, path::Path};
+use std::{
+    fs::File,
+    io::BufReader,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
+use nautilus_cryptography::providers::install_cryptographic_provider;
 use rustls::pki_types::{CertificateDer, PrivateKeyDer};
 use tokio::io::{AsyncRead, AsyncWrite};
 use tokio_tungstenite::{
@@ -185,6 +191,166 @@ pub fn create_tls_config_from_certs_dir(certs_dir: &Path) -> anyhow::Result<rust
         .with_client_auth_cert(cert, key)?)
 }
 
+/// Client TLS configuration for venues and gateways which mandate mutual TLS, or which
+/// present certificates issued by a private certificate authority.
+///
+/// The certificates in the CA bundle are trusted in addition to the default roots. The
+/// client private key must be PEM encoded PKCS#8 (`BEGIN PRIVATE KEY`), as required by
+/// both the HTTP and WebSocket clients.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.network")
+)]
+pub struct TlsConfig {
+    /// The path to the PEM bundle of additional CA certificates to trust.
+    pub ca_bundle_path: Option<PathBuf>,
+    /// The path to the PEM client certificate (chain) presented to the server.
+    pub client_cert_path: Option<PathBuf>,
+    /// The path to the PEM PKCS#8 private key for the client certificate.
+    pub client_key_path: Option<PathBuf>,
+    ca_bundle_pem: Option<Vec<u8>>,
+    client_cert_pem: Option<Vec<u8>>,
+    client_key_pem: Option<Vec<u8>>,
+}
+
+impl TlsConfig {
+    /// Creates a new [`TlsConfig`] instance, loading and validating the PEM files.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - Neither a CA bundle nor a client certificate is specified.
+    /// - Only one of the client certificate and private key is specified.
+    /// - A file cannot be read, or contains no valid certificates or PKCS#8 private key.
+    /// - The client private key does not match the certificate.
+    pub fn new(
+        ca_bundle_path: Option<PathBuf>,
+        client_cert_path: Option<PathBuf>,
+        client_key_path: Option<PathBuf>,
+    ) -> anyhow::Result<Self> {
+        if client_cert_path.is_some() != client_key_path.is_some() {
+            anyhow::bail!("Client certificate and private key must be specified together");
+        }
+        if ca_bundle_path.is_none() && client_cert_path.is_none() {
+            anyhow::bail!("Either a CA bundle or a client certificate must be specified");
+        }
+
+        let read = |path: &Option<PathBuf>,
+                    parse: fn(&[u8]) -> anyhow::Result<()>|
+         -> anyhow::Result<Option<Vec<u8>>> {
+            path.as_ref()
+                .map(|path| {
+                    std::fs::read(path)
+                        .map_err(anyhow::Error::from)
+                        .and_then(|pem| parse(&pem).map(|()| pem))
+                        .map_err(|e| anyhow::anyhow!("Invalid {}: {e}", path.display()))
+                })
+                .transpose()
+        };
+
+        let config = Self {
+            ca_bundle_pem: read(&ca_bundle_path, |pem| parse_certs(pem).map(drop))?,
+            client_cert_pem: read(&client_cert_path, |pem| parse_certs(pem).map(drop))?,
+            client_key_pem: read(&client_key_path, |pem| parse_pkcs8_key(pem).map(drop))?,
+            ca_bundle_path,
+            client_cert_path,
+            client_key_path,
+        };
+
+        // Fail on construction (e.g. mismatched key) rather than on the first connection
+        config.to_rustls()?;
+        config
+            .apply_to_reqwest(reqwest::Client::builder())?
+            .build()?;
+
+        Ok(config)
+    }
+
+    /// Returns whether a client certificate is presented to the server (mutual TLS).
+    #[must_use]
+    pub const fn has_client_identity(&self) -> bool {
+        self.client_cert_pem.is_some()
+    }
+
+    /// Creates a `rustls` client configuration trusting the default roots and the CA bundle,
+    /// and presenting the client certificate (if any).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the certificates or private key are invalid.
+    pub fn to_rustls(&self) -> anyhow::Result<Arc<rustls::ClientConfig>> {
+        install_cryptographic_provider();
+
+        let mut root_store = rustls::RootCertStore::empty();
+        root_store.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+
+        if let Some(pem) = &self.ca_bundle_pem {
+            for cert in parse_certs(pem)? {
+                root_store.add(cert)?;
+            }
+        }
+
+        let builder = rustls::ClientConfig::builder().with_root_certificates(root_store);
+        let config = match (&self.client_cert_pem, &self.client_key_pem) {
+            (Some(cert_pem), Some(key_pem)) => {
+                builder.with_client_auth_cert(parse_certs(cert_pem)?, parse_pkcs8_key(key_pem)?)?
+            }
+            _ => builder.with_no_client_auth(),
+        };
+
+        Ok(Arc::new(config))
+    }
+
+    /// Adds the CA bundle certificates and client identity (if any) to the `reqwest` client.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the certificates or private key are invalid.
+    pub fn apply_to_reqwest(
+        &self,
+        mut builder: reqwest::ClientBuilder,
+    ) -> anyhow::Result<reqwest::ClientBuilder> {
+        if let Some(pem) = &self.ca_bundle_pem {
+            for cert in reqwest::Certificate::from_pem_bundle(pem)? {
+                builder = builder.add_root_certificate(cert);
+            }
+        }
+
+        if let (Some(cert_pem), Some(key_pem)) = (&self.client_cert_pem, &self.client_key_pem) {
+            let identity = reqwest::Identity::from_pkcs8_pem(cert_pem, key_pem)?;
+            builder = builder.identity(identity);
+        }
+
+        Ok(builder)
+    }
+
+    /// Returns the connector for establishing WebSocket connections with this configuration.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the certificates or private key are invalid.
+    pub fn connector(&self) -> anyhow::Result<tokio_tungstenite::Connector> {
+        Ok(tokio_tungstenite::Connector::Rustls(self.to_rustls()?))
+    }
+}
+
+fn parse_certs(pem: &[u8]) -> anyhow::Result<Vec<CertificateDer<'static>>> {
+    let certs = rustls_pemfile::certs(&mut &pem[..]).collect::<Result<Vec<_>, _>>()?;
+    if certs.is_empty() {
+        anyhow::bail!("no valid PEM certificates found");
+    }
+    Ok(certs)
+}
+
+fn parse_pkcs8_key(pem: &[u8]) -> anyhow::Result<PrivateKeyDer<'static>> {
+    rustls_pemfile::pkcs8_private_keys(&mut &pem[..])
+        .next()
+        .transpose()?
+        .map(Into::into)
+        .ok_or_else(|| anyhow::anyhow!("no valid PEM PKCS#8 private key found"))
+}
+
 fn load_private_key(path: &Path) -> anyhow::Result<PrivateKeyDer<'static>> {
     let file = File::open(path)?;
     let mut reader = BufReader::new(file);
@@ -221,3 +387,120 @@ fn load_certs(path: &Path) -> anyhow::Result<Vec<CertificateDer<'static>>> {
         .collect();
     Ok(certs)
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+pub(crate) mod tests {
+    use rstest::rstest;
+    use tokio_rustls::TlsAcceptor;
+
+    use super::*;
+
+    /// Returns the path of a test certificate (signed by `ca.pem`) or PKCS#8 key.
+    pub(crate) fn tls_test_data(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("test_data")
+            .join("tls")
+            .join(name)
+    }
+
+    /// Returns a TLS config trusting the test CA, with the client identity if `mutual`.
+    pub(crate) fn test_tls_config(mutual: bool) -> TlsConfig {
+        TlsConfig::new(
+            Some(tls_test_data("ca.pem")),
+            mutual.then(|| tls_test_data("client.pem")),
+            mutual.then(|| tls_test_data("client.key")),
+        )
+        .unwrap()
+    }
+
+    /// Returns an acceptor for `localhost` which requires a client certificate.
+    pub(crate) fn mtls_acceptor() -> TlsAcceptor {
+        install_cryptographic_provider();
+
+        let mut client_roots = rustls::RootCertStore::empty();
+        for cert in load_certs(&tls_test_data("ca.pem")).unwrap() {
+            client_roots.add(cert).unwrap();
+        }
+        let verifier = rustls::server::WebPkiClientVerifier::builder(Arc::new(client_roots))
+            .build()
+            .unwrap();
+
+        let config = rustls::ServerConfig::builder()
+            .with_client_cert_verifier(verifier)
+            .with_single_cert(
+                load_certs(&tls_test_data("server.pem")).unwrap(),
+                load_private_key(&tls_test_data("server.key")).unwrap(),
+            )
+            .unwrap();
+
+        TlsAcceptor::from(Arc::new(config))
+    }
+
+    #[rstest]
+    fn test_new_with_client_identity() {
+        let config = test_tls_config(true);
+
+        assert!(config.has_client_identity());
+        assert!(config.connector().is_ok());
+    }
+
+    #[rstest]
+    fn test_new_with_ca_bundle_only() {
+        let config = test_tls_config(false);
+
+        assert!(!config.has_client_identity());
+        assert!(config.connector().is_ok());
+    }
+
+    #[rstest]
+    #[case(None, Some("client.pem"), None)]
+    #[case(None, None, Some("client.key"))]
+    #[case(None, None, None)]
+    fn test_new_rejects_incomplete_config(
+        #[case] ca_bundle: Option<&str>,
+        #[case] client_cert: Option<&str>,
+        #[case] client_key: Option<&str>,
+    ) {
+        let result = TlsConfig::new(
+            ca_bundle.map(tls_test_data),
+            client_cert.map(tls_test_data),
+            client_key.map(tls_test_data),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case(Some("missing.pem"), None, None, "missing.pem")]
+    #[case(Some("client.key"), None, None, "no valid PEM certificates")]
+    #[case(None, Some("client.pem"), Some("client.pem"), "no valid PEM PKCS#8")]
+    fn test_new_rejects_invalid_files(
+        #[case] ca_bundle: Option<&str>,
+        #[case] client_cert: Option<&str>,
+        #[case] client_key: Option<&str>,
+        #[case] expected: &str,
+    ) {
+        let err = TlsConfig::new(
+            ca_bundle.map(tls_test_data),
+            client_cert.map(tls_test_data),
+            client_key.map(tls_test_data),
+        )
+        .unwrap_err();
+
+        assert!(err.to_string().contains(expected), "{err}");
+    }
+
+    #[rstest]
+    fn test_new_rejects_mismatched_key() {
+        let result = TlsConfig::new(
+            None,
+            Some(tls_test_data("client.pem")),
+            Some(tls_test_data("server.key")),
+        );
+
+        assert!(result.is_err());
+    }
+}
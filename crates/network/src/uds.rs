@@ -0,0 +1,509 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Unix domain socket transport for co-located components.
+//!
+//! A [`UnixSocketServer`] accepts connections from [`UnixSocketClient`]s on the same host
+//! (such as a data node feeding a strategy node), avoiding the overhead of the TCP stack.
+//! Messages are framed on the byte stream with a [`SocketFraming`] strategy, by default
+//! prefixed with a 4-byte length header.
+
+use std::{
+    io,
+    os::unix::fs::FileTypeExt,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use dashmap::DashMap;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{
+        unix::{OwnedReadHalf, OwnedWriteHalf},
+        UnixListener, UnixStream,
+    },
+    sync::{oneshot, Mutex},
+    task::{AbortHandle, JoinHandle},
+};
+
+use crate::framing::SocketFraming;
+
+/// The handler for the messages received by a [`UnixSocketClient`].
+pub type UnixMessageHandler = Arc<dyn Fn(Vec<u8>) + Send + Sync>;
+
+/// The handler for the messages received by a [`UnixSocketServer`], called with the
+/// connection ID and the message.
+pub type UnixServerHandler = Arc<dyn Fn(u64, Vec<u8>) + Send + Sync>;
+
+pub(crate) type SharedUnixWriter = Arc<Mutex<OwnedWriteHalf>>;
+
+/// The connections of a [`UnixSocketServer`] keyed by connection ID.
+type UnixConnections = Arc<DashMap<u64, (SharedUnixWriter, AbortHandle)>>;
+
+/// Returns the default framing for Unix domain socket messages (a 4-byte length header).
+#[must_use]
+pub const fn default_framing() -> SocketFraming {
+    SocketFraming::LengthPrefixed { header_len: 4 }
+}
+
+/// Reads framed messages from the `reader` until the connection closes.
+async fn read_frames<F>(mut reader: OwnedReadHalf, framing: SocketFraming, handler: F)
+where
+    F: Fn(Vec<u8>),
+{
+    let mut buf = Vec::new();
+    loop {
+        match reader.read_buf(&mut buf).await {
+            Ok(0) => {
+                tracing::debug!("Unix socket connection closed");
+                break;
+            }
+            Err(e) => {
+                tracing::debug!("Unix socket connection ended: {e}");
+                break;
+            }
+            Ok(bytes) => {
+                tracing::trace!("Received <binary> {bytes} bytes");
+                while let Some(data) = framing.decode(&mut buf) {
+                    handler(data);
+                }
+            }
+        }
+    }
+}
+
+/// Writes the message `data` to the `writer` as a frame.
+pub(crate) async fn write_frame(
+    writer: &SharedUnixWriter,
+    framing: &SocketFraming,
+    data: &[u8],
+) -> io::Result<()> {
+    let frame = framing.encode(data);
+    writer.lock().await.write_all(&frame).await
+}
+
+/// Writes the message `data` to each of the `writers` as a frame, returning the number
+/// of writers sent to.
+pub(crate) async fn broadcast_frame(
+    writers: Vec<(u64, SharedUnixWriter)>,
+    framing: &SocketFraming,
+    data: &[u8],
+) -> usize {
+    let mut sent = 0;
+    for (id, writer) in writers {
+        match write_frame(&writer, framing, data).await {
+            Ok(()) => sent += 1,
+            Err(e) => tracing::error!("Error sending to Unix socket connection {id}: {e}"),
+        }
+    }
+    sent
+}
+
+/// A server accepting Unix domain socket connections.
+///
+/// The socket file is removed when the server is closed or dropped.
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.network")
+)]
+pub struct UnixSocketServer {
+    path: PathBuf,
+    framing: SocketFraming,
+    connections: UnixConnections,
+    accept_task: JoinHandle<()>,
+}
+
+impl UnixSocketServer {
+    /// Binds a new [`UnixSocketServer`] to the socket file at `path`, calling the `handler`
+    /// with each message received from a connection.
+    ///
+    /// A stale socket file at `path` (left by a server which did not close) is replaced.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `framing` is invalid, a file other than a socket exists at
+    /// `path`, or binding the socket fails.
+    pub async fn bind(
+        path: impl AsRef<Path>,
+        framing: SocketFraming,
+        handler: UnixServerHandler,
+    ) -> anyhow::Result<Self> {
+        framing.validate()?;
+        let path = path.as_ref().to_path_buf();
+        if let Ok(metadata) = std::fs::symlink_metadata(&path) {
+            if !metadata.file_type().is_socket() {
+                anyhow::bail!("Path {} exists and is not a socket", path.display());
+            }
+            std::fs::remove_file(&path)?;
+        }
+
+        let listener = UnixListener::bind(&path)?;
+        tracing::debug!("Listening on Unix socket {}", path.display());
+
+        let connections = UnixConnections::default();
+        let accept_task =
+            Self::spawn_accept_task(listener, framing.clone(), connections.clone(), handler);
+
+        Ok(Self {
+            path,
+            framing,
+            connections,
+            accept_task,
+        })
+    }
+
+    fn spawn_accept_task(
+        listener: UnixListener,
+        framing: SocketFraming,
+        connections: UnixConnections,
+        handler: UnixServerHandler,
+    ) -> JoinHandle<()> {
+        tokio::task::spawn(async move {
+            let next_id = AtomicU64::new(1);
+            loop {
+                let stream = match listener.accept().await {
+                    Ok((stream, _)) => stream,
+                    Err(e) => {
+                        tracing::error!("Error accepting Unix socket connection: {e}");
+                        continue;
+                    }
+                };
+
+                let id = next_id.fetch_add(1, Ordering::Relaxed);
+                tracing::debug!("Accepted Unix socket connection {id}");
+                let (reader, writer) = stream.into_split();
+
+                // The read task removes the connection once closed, after it is added
+                let (added_tx, added_rx) = oneshot::channel();
+                let handler = handler.clone();
+                let framing = framing.clone();
+                let connections_clone = connections.clone();
+                let read_task = tokio::task::spawn(async move {
+                    let _ = added_rx.await;
+                    read_frames(reader, framing, |data| handler(id, data)).await;
+                    connections_clone.remove(&id);
+                });
+
+                let writer = Arc::new(Mutex::new(writer));
+                connections.insert(id, (writer, read_task.abort_handle()));
+                let _ = added_tx.send(());
+            }
+        })
+    }
+
+    /// Returns the path of the socket file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the IDs of the open connections.
+    #[must_use]
+    pub fn connection_ids(&self) -> Vec<u64> {
+        let mut ids: Vec<u64> = self.connections.iter().map(|entry| *entry.key()).collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// Sends the message `data` to the connection with the `connection_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection is not open, or writing to it fails.
+    pub async fn send(&self, connection_id: u64, data: &[u8]) -> io::Result<()> {
+        let writer = self.writer(connection_id)?;
+        write_frame(&writer, &self.framing, data).await
+    }
+
+    /// Sends the message `data` to every open connection, returning the number of
+    /// connections sent to.
+    pub async fn broadcast(&self, data: &[u8]) -> usize {
+        broadcast_frame(self.writers(), &self.framing, data).await
+    }
+
+    /// Returns the framing of the messages.
+    #[must_use]
+    pub const fn framing(&self) -> &SocketFraming {
+        &self.framing
+    }
+
+    pub(crate) fn writer(&self, connection_id: u64) -> io::Result<SharedUnixWriter> {
+        self.connections
+            .get(&connection_id)
+            .map(|connection| connection.0.clone())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotConnected,
+                    format!("Unix socket connection {connection_id} not open"),
+                )
+            })
+    }
+
+    pub(crate) fn writers(&self) -> Vec<(u64, SharedUnixWriter)> {
+        self.connections
+            .iter()
+            .map(|entry| (*entry.key(), entry.0.clone()))
+            .collect()
+    }
+
+    /// Closes the server and its connections, removing the socket file.
+    pub fn close(&self) {
+        self.accept_task.abort();
+        for entry in self.connections.iter() {
+            entry.1.abort();
+        }
+        self.connections.clear();
+        if let Err(e) = std::fs::remove_file(&self.path) {
+            if e.kind() != io::ErrorKind::NotFound {
+                tracing::error!("Error removing Unix socket {}: {e}", self.path.display());
+            }
+        }
+    }
+}
+
+impl Drop for UnixSocketServer {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+/// A client connected to a [`UnixSocketServer`].
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.network")
+)]
+pub struct UnixSocketClient {
+    pub(crate) writer: SharedUnixWriter,
+    pub(crate) read_task: JoinHandle<()>,
+    pub(crate) framing: SocketFraming,
+}
+
+impl UnixSocketClient {
+    /// Connects a new [`UnixSocketClient`] to the server at the socket file `path`, calling
+    /// the optional `handler` with each message received from the server.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `framing` is invalid, or connecting to the socket fails.
+    pub async fn connect(
+        path: impl AsRef<Path>,
+        framing: SocketFraming,
+        handler: Option<UnixMessageHandler>,
+    ) -> anyhow::Result<Self> {
+        framing.validate()?;
+        let stream = UnixStream::connect(path.as_ref()).await?;
+        tracing::debug!("Connected to Unix socket {}", path.as_ref().display());
+
+        let (reader, writer) = stream.into_split();
+        let read_task = tokio::task::spawn(read_frames(reader, framing.clone(), move |data| {
+            if let Some(handler) = &handler {
+                handler(data);
+            }
+        }));
+
+        Ok(Self {
+            writer: Arc::new(Mutex::new(writer)),
+            read_task,
+            framing,
+        })
+    }
+
+    /// Sends the message `data` to the server.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the socket fails.
+    pub async fn send(&self, data: &[u8]) -> io::Result<()> {
+        write_frame(&self.writer, &self.framing, data).await
+    }
+
+    /// Check if the connection is closed.
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        self.read_task.is_finished()
+    }
+
+    /// Closes the connection.
+    pub async fn close(&self) {
+        close_writer(&self.writer).await;
+        self.read_task.abort();
+    }
+}
+
+/// Shuts down the `writer`, closing the connection for the peer.
+pub(crate) async fn close_writer(writer: &SharedUnixWriter) {
+    if let Err(e) = writer.lock().await.shutdown().await {
+        tracing::debug!("Error shutting down Unix socket: {e}");
+    }
+}
+
+impl Drop for UnixSocketClient {
+    fn drop(&mut self) {
+        self.read_task.abort();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::{
+        sync::mpsc::{self, UnboundedReceiver},
+        time::timeout,
+    };
+
+    use super::*;
+
+    fn socket_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("nautilus-uds-{}-{name}.sock", std::process::id()))
+    }
+
+    async fn start_server(name: &str) -> (UnixSocketServer, UnboundedReceiver<(u64, Vec<u8>)>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let handler: UnixServerHandler = Arc::new(move |id, data| {
+            let _ = tx.send((id, data));
+        });
+        let server = UnixSocketServer::bind(socket_path(name), default_framing(), handler)
+            .await
+            .unwrap();
+        (server, rx)
+    }
+
+    async fn connect_client(path: &Path) -> (UnixSocketClient, UnboundedReceiver<Vec<u8>>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let handler: UnixMessageHandler = Arc::new(move |data| {
+            let _ = tx.send(data);
+        });
+        let client = UnixSocketClient::connect(path, default_framing(), Some(handler))
+            .await
+            .unwrap();
+        (client, rx)
+    }
+
+    async fn recv<T>(rx: &mut UnboundedReceiver<T>) -> T {
+        timeout(Duration::from_secs(2), rx.recv())
+            .await
+            .expect("timed out waiting for message")
+            .unwrap()
+    }
+
+    async fn wait_for_connections(server: &UnixSocketServer, count: usize) {
+        timeout(Duration::from_secs(2), async {
+            while server.connection_ids().len() != count {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("timed out waiting for connections");
+    }
+
+    #[tokio::test]
+    async fn test_round_trip() {
+        let (server, mut server_rx) = start_server("round-trip").await;
+        let (client, mut client_rx) = connect_client(server.path()).await;
+
+        client.send(b"subscribe").await.unwrap();
+        client.send(b"").await.unwrap();
+        let (id, message) = recv(&mut server_rx).await;
+        let (_, empty) = recv(&mut server_rx).await;
+
+        assert_eq!(message, b"subscribe");
+        assert!(empty.is_empty());
+        assert_eq!(server.connection_ids(), vec![id]);
+
+        server.send(id, b"quote").await.unwrap();
+
+        assert_eq!(recv(&mut client_rx).await, b"quote");
+    }
+
+    #[tokio::test]
+    async fn test_broadcast() {
+        let (server, _server_rx) = start_server("broadcast").await;
+        let (_client1, mut rx1) = connect_client(server.path()).await;
+        let (_client2, mut rx2) = connect_client(server.path()).await;
+        wait_for_connections(&server, 2).await;
+
+        let sent = server.broadcast(b"bar").await;
+
+        assert_eq!(sent, 2);
+        assert_eq!(recv(&mut rx1).await, b"bar");
+        assert_eq!(recv(&mut rx2).await, b"bar");
+    }
+
+    #[tokio::test]
+    async fn test_closed_connection_removed() {
+        let (server, _server_rx) = start_server("closed").await;
+        let (client, _client_rx) = connect_client(server.path()).await;
+        wait_for_connections(&server, 1).await;
+        let id = server.connection_ids()[0];
+
+        client.close().await;
+        wait_for_connections(&server, 0).await;
+
+        assert!(server.send(id, b"quote").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_client_closed_when_server_closes() {
+        let (server, _server_rx) = start_server("server-closes").await;
+        let path = server.path().to_path_buf();
+        let (client, _client_rx) = connect_client(&path).await;
+        wait_for_connections(&server, 1).await;
+
+        drop(server);
+        timeout(Duration::from_secs(2), async {
+            while !client.is_closed() {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap();
+
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_bind_replaces_stale_socket() {
+        // A dropped standard library listener leaves its socket file behind
+        let path = socket_path("stale");
+        drop(std::os::unix::net::UnixListener::bind(&path).unwrap());
+        assert!(path.exists());
+
+        let (server, _rx) = start_server("stale").await;
+        let (client, _client_rx) = connect_client(&path).await;
+        wait_for_connections(&server, 1).await;
+
+        assert!(!client.is_closed());
+    }
+
+    #[tokio::test]
+    async fn test_bind_rejects_non_socket_file() {
+        let path = socket_path("regular-file");
+        std::fs::write(&path, b"data").unwrap();
+        let handler: UnixServerHandler = Arc::new(|_, _| {});
+
+        let result = UnixSocketServer::bind(&path, default_framing(), handler).await;
+
+        assert!(result.is_err());
+        assert!(path.exists());
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -18,7 +18,9 @@
 
 //! **Key features**:
 //! - Connection state tracking (ACTIVE/RECONNECTING/DISCONNECTING/CLOSED)
-//! - Synchronized reconnection with backoff
+//! - Synchronized reconnection with jittered backoff and optional maximum attempts
+//! - Connection status events for reconnection and disconnection
+//! - Transparent decompression of gzip/zlib/deflate compressed message payloads
 //! - Clean shutdown sequence
 //! - Split read/write architecture
 //! - Python callback integration
@@ -34,7 +36,7 @@ use std::{
         atomic::{AtomicU8, Ordering},
         Arc,
     },
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use futures_util::{
@@ -42,24 +44,35 @@ use futures_util::{
     SinkExt, StreamExt,
 };
 use http::HeaderName;
+use nautilus_core::{time::get_atomic_clock_realtime, UnixNanos};
 use nautilus_cryptography::providers::install_cryptographic_provider;
 use pyo3::{prelude::*, types::PyBytes};
+use strum::{AsRefStr, Display};
 use tokio::{net::TcpStream, sync::Mutex};
 use tokio_tungstenite::{
-    connect_async,
-    tungstenite::{client::IntoClientRequest, http::HeaderValue, Error, Message},
+    client_async_tls_with_config, connect_async_tls_with_config,
+    tungstenite::{
+        client::IntoClientRequest, handshake::client::Request, http::HeaderValue, Error, Message,
+    },
     MaybeTlsStream, WebSocketStream,
 };
 
 use crate::{
     backoff::ExponentialBackoff,
+    compression::{decompress_payload, PayloadCompression},
+    latency::WebSocketLatency,
+    liveness::{LivenessMonitor, LivenessPolicy},
     mode::ConnectionMode,
+    proxy::ProxyConfig,
     ratelimiter::{clock::MonotonicClock, quota::Quota, RateLimiter},
+    tls::TlsConfig,
 };
 type MessageWriter = SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>;
 type SharedMessageWriter =
     Arc<Mutex<SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>>>;
 pub type MessageReader = SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>>;
+/// The keyed text messages (such as subscriptions) replayed after each reconnection.
+type ReplayMessages = Arc<std::sync::Mutex<Vec<(String, String)>>>;
 
 #[derive(Debug, Clone)]
 #[cfg_attr(
@@ -89,6 +102,52 @@ pub struct WebSocketConfig {
     pub reconnect_backoff_factor: Option<f64>,
     /// The maximum jitter (milliseconds) added to reconnection delays.
     pub reconnect_jitter_ms: Option<u64>,
+    /// The maximum number of consecutive reconnection attempts before the client closes
+    /// (unlimited if `None`).
+    pub reconnect_max_attempts: Option<u32>,
+    /// The handler for connection status events, called with the status and reconnection attempt.
+    pub status_handler: Option<Arc<PyObject>>,
+    /// The compression of binary message payloads, decompressed before calling the handler.
+    pub payload_compression: Option<PayloadCompression>,
+    /// The proxy to connect through, otherwise the proxy configured by the environment (if any).
+    pub proxy: Option<ProxyConfig>,
+    /// The maximum time (milliseconds) without receiving a message before the connection is stale.
+    pub idle_timeout_ms: Option<u64>,
+    /// The maximum consecutive heartbeat pings without a pong before the connection is stale.
+    pub max_missed_pongs: Option<u32>,
+    /// The action taken when the connection is stale (defaults to reconnecting).
+    pub liveness_policy: Option<LivenessPolicy>,
+    /// The TLS configuration for custom CA certificates and mutual TLS.
+    pub tls: Option<TlsConfig>,
+}
+
+/// Connection status events emitted by the [`WebSocketClient`] controller.
+#[derive(Clone, Copy, Debug, Display, Hash, PartialEq, Eq, AsRefStr)]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum ConnectionStatus {
+    /// The client has connected to the server.
+    Connected,
+    /// The connection was lost and a reconnection attempt is starting.
+    Reconnecting,
+    /// The client has reconnected to the server.
+    Reconnected,
+    /// The maximum reconnection attempts were reached, the client will close.
+    ReconnectFailed,
+    /// No message or pong was received within the configured liveness window.
+    Stale,
+    /// The client has disconnected from the server and is closed.
+    Disconnected,
+}
+
+/// Calls the status handler (if any) with the `status` and reconnection `attempt`.
+fn emit_status(handler: Option<&Arc<PyObject>>, status: ConnectionStatus, attempt: u32) {
+    tracing::debug!("Connection status {status} (attempt {attempt})");
+
+    if let Some(handler) = handler {
+        if let Err(e) = Python::with_gil(|py| handler.call1(py, (status.as_ref(), attempt))) {
+            tracing::error!("Error calling status handler: {e}");
+        }
+    }
 }
 
 /// `WebSocketClient` connects to a websocket server to read and send messages.
@@ -114,6 +173,8 @@ struct WebSocketClientInner {
     connection_mode: Arc<AtomicU8>,
     reconnect_timeout: Duration,
     backoff: ExponentialBackoff,
+    latency: Arc<WebSocketLatency>,
+    liveness: Arc<LivenessMonitor>,
 }
 
 impl WebSocketClientInner {
@@ -134,16 +195,35 @@ impl WebSocketClientInner {
             reconnect_delay_max_ms,
             reconnect_backoff_factor,
             reconnect_jitter_ms,
+            reconnect_max_attempts,
+            status_handler,
+            payload_compression,
+            proxy,
+            idle_timeout_ms,
+            max_missed_pongs,
+            liveness_policy,
+            tls,
         } = &config;
-        let (writer, reader) = Self::connect_with_server(url, headers.clone()).await?;
+        let latency = Arc::new(WebSocketLatency::default());
+        let start = Instant::now();
+        let (writer, reader) =
+            Self::connect_with_server(url, headers.clone(), proxy.as_ref(), tls.as_ref()).await?;
+        latency.connect.record(start.elapsed());
         let writer = Arc::new(Mutex::new(writer));
 
         let connection_mode = Arc::new(AtomicU8::new(ConnectionMode::Active.as_u8()));
+        let liveness = Arc::new(LivenessMonitor::new());
 
         // Only spawn read task if handler is provided
-        let read_task = handler
-            .as_ref()
-            .map(|handler| Self::spawn_read_task(reader, handler.clone(), ping_handler.clone()));
+        let read_task = handler.as_ref().map(|handler| {
+            Self::spawn_read_task(
+                reader,
+                handler.clone(),
+                ping_handler.clone(),
+                *payload_compression,
+                liveness.clone(),
+            )
+        });
 
         // Optionally spawn a heartbeat task to periodically ping server
         let heartbeat_task = heartbeat.as_ref().map(|heartbeat_secs| {
@@ -152,6 +232,7 @@ impl WebSocketClientInner {
                 *heartbeat_secs,
                 heartbeat_msg.clone(),
                 writer.clone(),
+                liveness.clone(),
             )
         });
 
@@ -172,6 +253,8 @@ impl WebSocketClientInner {
             connection_mode,
             reconnect_timeout,
             backoff,
+            latency,
+            liveness,
         })
     }
 
@@ -180,6 +263,8 @@ impl WebSocketClientInner {
     pub async fn connect_with_server(
         url: &str,
         headers: Vec<(String, String)>,
+        proxy: Option<&ProxyConfig>,
+        tls: Option<&TlsConfig>,
     ) -> Result<(MessageWriter, MessageReader), Error> {
         let mut request = url.into_client_request()?;
         let req_headers = request.headers_mut();
@@ -192,7 +277,9 @@ impl WebSocketClientInner {
             req_headers.insert(header_name, header_value);
         }
 
-        connect_async(request).await.map(|resp| resp.0.split())
+        connect_request(request, proxy, tls)
+            .await
+            .map(|stream| stream.split())
     }
 
     /// Reconnect with server.
@@ -210,8 +297,16 @@ impl WebSocketClientInner {
             )
             .await;
 
-            let (new_writer, reader) =
-                Self::connect_with_server(&self.config.url, self.config.headers.clone()).await?;
+            let start = Instant::now();
+            let (new_writer, reader) = Self::connect_with_server(
+                &self.config.url,
+                self.config.headers.clone(),
+                self.config.proxy.as_ref(),
+                self.config.tls.as_ref(),
+            )
+            .await?;
+            self.latency.connect.record(start.elapsed());
+            self.liveness.reset();
 
             {
                 let mut guard = self.writer.lock().await;
@@ -225,6 +320,8 @@ impl WebSocketClientInner {
                     reader,
                     handler.clone(),
                     self.config.ping_handler.clone(),
+                    self.config.payload_compression,
+                    self.liveness.clone(),
                 ));
             }
 
@@ -235,6 +332,7 @@ impl WebSocketClientInner {
                     *heartbeat_secs,
                     self.config.heartbeat_msg.clone(),
                     self.writer.clone(),
+                    self.liveness.clone(),
                 )
             });
 
@@ -272,18 +370,52 @@ impl WebSocketClientInner {
         }
     }
 
+    /// Check if the connection is stale.
+    ///
+    /// Only connections with a read task are monitored, as the inbound messages of a
+    /// stream used directly are not observed.
+    #[must_use]
+    pub fn is_stale(&self) -> bool {
+        self.read_task.is_some()
+            && self.liveness.is_stale(
+                self.config.idle_timeout_ms.map(Duration::from_millis),
+                self.config.max_missed_pongs,
+            )
+    }
+
     fn spawn_read_task(
         mut reader: MessageReader,
         handler: Arc<PyObject>,
         ping_handler: Option<Arc<PyObject>>,
+        payload_compression: Option<PayloadCompression>,
+        liveness: Arc<LivenessMonitor>,
     ) -> tokio::task::JoinHandle<()> {
         tracing::debug!("Started task 'read'");
 
         tokio::task::spawn(async move {
             loop {
-                match reader.next().await {
+                let message = reader.next().await;
+                match &message {
+                    Some(Ok(Message::Pong(_))) => liveness.on_pong(),
+                    Some(Ok(_)) => liveness.on_message(),
+                    _ => (),
+                }
+
+                match message {
                     Some(Ok(Message::Binary(data))) => {
                         tracing::trace!("Received message <binary> {} bytes", data.len());
+                        let data = match payload_compression {
+                            Some(compression) => match decompress_payload(&data, compression) {
+                                Ok(decompressed) => decompressed.into(),
+                                Err(e) => {
+                                    tracing::error!(
+                                        "Error decompressing {compression} message: {e}"
+                                    );
+                                    continue;
+                                }
+                            },
+                            None => data,
+                        };
                         if let Err(e) =
                             Python::with_gil(|py| handler.call1(py, (PyBytes::new(py, &data),)))
                         {
@@ -342,6 +474,7 @@ impl WebSocketClientInner {
         heartbeat_secs: u64,
         message: Option<String>,
         writer: SharedMessageWriter,
+        liveness: Arc<LivenessMonitor>,
     ) -> tokio::task::JoinHandle<()> {
         tracing::debug!("Started task 'heartbeat'");
 
@@ -359,7 +492,12 @@ impl WebSocketClientInner {
                         };
 
                         match guard_send_response {
-                            Ok(()) => tracing::trace!("Sent ping"),
+                            Ok(()) => {
+                                if message.is_none() {
+                                    liveness.on_ping_sent();
+                                }
+                                tracing::trace!("Sent ping");
+                            }
                             Err(e) => tracing::error!("Error sending ping: {e}"),
                         }
                     }
@@ -373,6 +511,46 @@ impl WebSocketClientInner {
     }
 }
 
+/// Connects the websocket `request` through the `proxy` (otherwise the proxy configured
+/// by the environment), unless the proxy is bypassed for the host.
+///
+/// Secure connections use the `tls` configuration if given, otherwise the platform roots.
+async fn connect_request(
+    request: Request,
+    proxy: Option<&ProxyConfig>,
+    tls: Option<&TlsConfig>,
+) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, Error> {
+    let connector = tls
+        .map(TlsConfig::connector)
+        .transpose()
+        .map_err(|e| Error::Io(std::io::Error::other(e)))?;
+    let secure = request.uri().scheme_str() == Some("wss");
+    let host = request.uri().host().unwrap_or_default().to_string();
+    let env_proxy = proxy
+        .is_none()
+        .then(|| ProxyConfig::from_env(secure))
+        .flatten();
+
+    match proxy
+        .or(env_proxy.as_ref())
+        .filter(|proxy| !proxy.bypasses(&host))
+    {
+        Some(proxy) => {
+            let port = request
+                .uri()
+                .port_u16()
+                .unwrap_or(if secure { 443 } else { 80 });
+            let stream = proxy.connect(&host, port).await?;
+            client_async_tls_with_config(request, stream, None, connector)
+                .await
+                .map(|resp| resp.0)
+        }
+        None => connect_async_tls_with_config(request, None, false, connector)
+            .await
+            .map(|resp| resp.0),
+    }
+}
+
 /// Shutdown websocket connection.
 ///
 /// Performs orderly WebSocket shutdown:
@@ -460,6 +638,8 @@ pub struct WebSocketClient {
     pub(crate) controller_task: tokio::task::JoinHandle<()>,
     pub(crate) connection_mode: Arc<AtomicU8>,
     pub(crate) rate_limiter: Arc<RateLimiter<String, MonotonicClock>>,
+    pub(crate) replay_messages: ReplayMessages,
+    pub(crate) latency: Arc<WebSocketLatency>,
 }
 
 impl WebSocketClient {
@@ -470,17 +650,22 @@ impl WebSocketClient {
         keyed_quotas: Vec<(String, Quota)>,
         default_quota: Option<Quota>,
     ) -> Result<(MessageReader, Self), Error> {
-        let (ws_stream, _) = connect_async(config.url.clone().into_client_request()?).await?;
+        let request = config.url.clone().into_client_request()?;
+        let ws_stream =
+            connect_request(request, config.proxy.as_ref(), config.tls.as_ref()).await?;
         let (writer, reader) = ws_stream.split();
         let writer = Arc::new(Mutex::new(writer));
 
         let inner = WebSocketClientInner::connect_url(config).await?;
         let connection_mode = inner.connection_mode.clone();
+        let latency = inner.latency.clone();
         let rate_limiter = Arc::new(RateLimiter::new_with_quota(default_quota, keyed_quotas));
+        let replay_messages = ReplayMessages::default();
 
         let controller_task = Self::spawn_controller_task(
             inner,
             connection_mode.clone(),
+            replay_messages.clone(),
             None, // no post_reconnection
             None, // no post_disconnection
         );
@@ -492,6 +677,8 @@ impl WebSocketClient {
                 controller_task,
                 connection_mode,
                 rate_limiter,
+                replay_messages,
+                latency,
             },
         ))
     }
@@ -512,10 +699,13 @@ impl WebSocketClient {
         let inner = WebSocketClientInner::connect_url(config.clone()).await?;
         let writer = inner.writer.clone();
         let connection_mode = inner.connection_mode.clone();
+        let latency = inner.latency.clone();
+        let replay_messages = ReplayMessages::default();
 
         let controller_task = Self::spawn_controller_task(
             inner,
             connection_mode.clone(),
+            replay_messages.clone(),
             post_reconnection,
             post_disconnection,
         );
@@ -527,12 +717,19 @@ impl WebSocketClient {
                 Err(e) => tracing::error!("Error calling `post_connection` handler: {e}"),
             });
         };
+        emit_status(
+            config.status_handler.as_ref(),
+            ConnectionStatus::Connected,
+            0,
+        );
 
         Ok(Self {
             writer,
             controller_task,
             connection_mode,
             rate_limiter,
+            replay_messages,
+            latency,
         })
     }
 
@@ -632,6 +829,54 @@ impl WebSocketClient {
         guard.send(Message::Binary(data.into())).await
     }
 
+    /// Adds a text message to replay after each reconnection (such as a subscription),
+    /// replacing any message with the same `key`.
+    ///
+    /// Messages are replayed in the order added, before the `post_reconnection` handler is called.
+    pub fn add_replay_message(&self, key: String, message: String) {
+        let mut replay_messages = self
+            .replay_messages
+            .lock()
+            .expect("replay messages poisoned");
+        match replay_messages.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, existing)) => *existing = message,
+            None => replay_messages.push((key, message)),
+        }
+    }
+
+    /// Removes the message to replay after each reconnection for the `key`.
+    pub fn remove_replay_message(&self, key: &str) {
+        self.replay_messages
+            .lock()
+            .expect("replay messages poisoned")
+            .retain(|(k, _)| k != key);
+    }
+
+    /// Returns the keys of the messages replayed after each reconnection.
+    #[must_use]
+    pub fn replay_keys(&self) -> Vec<String> {
+        self.replay_messages
+            .lock()
+            .expect("replay messages poisoned")
+            .iter()
+            .map(|(key, _)| key.clone())
+            .collect()
+    }
+
+    /// Returns the latencies recorded by the client.
+    #[must_use]
+    pub fn latency(&self) -> &WebSocketLatency {
+        &self.latency
+    }
+
+    /// Records the venue-to-local latency of a message with the exchange timestamp
+    /// `ts_event` (UNIX nanoseconds), received now.
+    pub fn record_venue_latency(&self, ts_event: UnixNanos) {
+        let ts_recv = get_atomic_clock_realtime().get_time_ns();
+        self.latency
+            .record_venue_latency(ts_event.as_u64(), ts_recv.as_u64());
+    }
+
     pub async fn send_close_message(&self) {
         let mut guard = self.writer.lock().await;
         match guard.send(Message::Close(None)).await {
@@ -643,6 +888,7 @@ impl WebSocketClient {
     fn spawn_controller_task(
         mut inner: WebSocketClientInner,
         connection_mode: Arc<AtomicU8>,
+        replay_messages: ReplayMessages,
         post_reconnection: Option<PyObject>,
         post_disconnection: Option<PyObject>,
     ) -> tokio::task::JoinHandle<()> {
@@ -650,6 +896,11 @@ impl WebSocketClient {
             tracing::debug!("Starting task 'controller'");
 
             let check_interval = Duration::from_millis(10);
+            let status_handler = inner.config.status_handler.clone();
+            let status_handler = status_handler.as_ref();
+            let liveness_policy = inner.config.liveness_policy.unwrap_or_default();
+            let mut attempt: u32 = 0;
+            let mut stale = false;
 
             loop {
                 tokio::time::sleep(check_interval).await;
@@ -663,24 +914,60 @@ impl WebSocketClient {
                         inner.writer.clone(),
                     )
                     .await;
-
-                    if let Some(ref handler) = post_disconnection {
-                        Python::with_gil(|py| match handler.call0(py) {
-                            Ok(_) => tracing::debug!("Called `post_disconnection` handler"),
-                            Err(e) => {
-                                tracing::error!("Error calling `post_disconnection` handler: {e}");
-                            }
-                        });
-                    }
                     break; // Controller finished
                 }
 
-                if mode.is_reconnect() || (mode.is_active() && !inner.is_alive()) {
+                // Notify once per stale period, reconnecting if required by the policy
+                let is_stale = mode.is_active() && inner.is_alive() && inner.is_stale();
+                if is_stale && !stale {
+                    tracing::warn!(
+                        "Connection stale: idle for {:?} with {} missed pongs",
+                        inner.liveness.idle(),
+                        inner.liveness.missed_pongs(),
+                    );
+                    emit_status(status_handler, ConnectionStatus::Stale, attempt);
+                }
+                stale = is_stale;
+                let stale_reconnect = is_stale && liveness_policy == LivenessPolicy::Reconnect;
+
+                if mode.is_reconnect()
+                    || (mode.is_active() && (!inner.is_alive() || stale_reconnect))
+                {
+                    // Pause the heartbeat while reconnecting (unless a disconnect was signalled)
+                    let _ = connection_mode.compare_exchange(
+                        ConnectionMode::Active.as_u8(),
+                        ConnectionMode::Reconnect.as_u8(),
+                        Ordering::SeqCst,
+                        Ordering::SeqCst,
+                    );
+
+                    attempt += 1;
+                    emit_status(status_handler, ConnectionStatus::Reconnecting, attempt);
+
                     match inner.reconnect().await {
                         Ok(()) => {
                             tracing::debug!("Reconnected successfully");
                             inner.backoff.reset();
 
+                            let messages: Vec<String> = replay_messages
+                                .lock()
+                                .expect("replay messages poisoned")
+                                .iter()
+                                .map(|(_, message)| message.clone())
+                                .collect();
+                            if !messages.is_empty() {
+                                tracing::debug!("Replaying {} messages", messages.len());
+                                let mut writer = inner.writer.lock().await;
+                                for message in messages {
+                                    if let Err(e) = writer.send(Message::Text(message.into())).await
+                                    {
+                                        tracing::error!("Error replaying message: {e}");
+                                    }
+                                }
+                            }
+                            emit_status(status_handler, ConnectionStatus::Reconnected, attempt);
+                            attempt = 0;
+
                             if let Some(ref handler) = post_reconnection {
                                 Python::with_gil(|py| match handler.call0(py) {
                                     Ok(_) => tracing::debug!("Called `post_reconnection` handler"),
@@ -691,8 +978,31 @@ impl WebSocketClient {
                             }
                         }
                         Err(e) => {
+                            tracing::warn!("Reconnect attempt {attempt} failed: {e}");
+
+                            if inner
+                                .config
+                                .reconnect_max_attempts
+                                .is_some_and(|max_attempts| attempt >= max_attempts)
+                            {
+                                tracing::error!(
+                                    "Reconnect failed after {attempt} attempts, closing client"
+                                );
+                                emit_status(
+                                    status_handler,
+                                    ConnectionStatus::ReconnectFailed,
+                                    attempt,
+                                );
+                                shutdown(
+                                    inner.read_task.take(),
+                                    inner.heartbeat_task.take(),
+                                    inner.writer.clone(),
+                                )
+                                .await;
+                                break; // Controller finished
+                            }
+
                             let duration = inner.backoff.next_duration();
-                            tracing::warn!("Reconnect attempt failed: {e}");
                             if !duration.is_zero() {
                                 tracing::warn!("Backing off for {}s...", duration.as_secs_f64());
                             }
@@ -701,6 +1011,17 @@ impl WebSocketClient {
                     }
                 }
             }
+
+            if let Some(ref handler) = post_disconnection {
+                Python::with_gil(|py| match handler.call0(py) {
+                    Ok(_) => tracing::debug!("Called `post_disconnection` handler"),
+                    Err(e) => {
+                        tracing::error!("Error calling `post_disconnection` handler: {e}");
+                    }
+                });
+            }
+            emit_status(status_handler, ConnectionStatus::Disconnected, attempt);
+
             inner
                 .connection_mode
                 .store(ConnectionMode::Closed.as_u8(), Ordering::SeqCst);
@@ -714,11 +1035,19 @@ impl WebSocketClient {
 #[cfg(test)]
 #[cfg(target_os = "linux")] // Only run network tests on Linux (CI stability)
 mod tests {
-    use std::{num::NonZeroU32, sync::Arc};
+    use std::{
+        num::NonZeroU32,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    };
 
     use futures_util::{SinkExt, StreamExt};
+    use rstest::rstest;
     use tokio::{
-        net::TcpListener,
+        io::{copy_bidirectional, AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
         task::{self, JoinHandle},
     };
     use tokio_tungstenite::{
@@ -730,6 +1059,8 @@ mod tests {
     };
 
     use crate::{
+        mode::ConnectionMode,
+        proxy::ProxyConfig,
         ratelimiter::quota::Quota,
         websocket::{WebSocketClient, WebSocketConfig},
     };
@@ -827,8 +1158,8 @@ mod tests {
         }
     }
 
-    async fn setup_test_client(port: u16) -> WebSocketClient {
-        let config = WebSocketConfig {
+    fn test_config(port: u16) -> WebSocketConfig {
+        WebSocketConfig {
             url: format!("ws://127.0.0.1:{port}"),
             headers: vec![("test".into(), "test".into())],
             handler: None,
@@ -840,8 +1171,19 @@ mod tests {
             reconnect_backoff_factor: None,
             reconnect_delay_max_ms: None,
             reconnect_jitter_ms: None,
-        };
-        WebSocketClient::connect(config, None, None, None, vec![], None)
+            reconnect_max_attempts: None,
+            status_handler: None,
+            payload_compression: None,
+            proxy: None,
+            idle_timeout_ms: None,
+            max_missed_pongs: None,
+            liveness_policy: None,
+            tls: None,
+        }
+    }
+
+    async fn setup_test_client(port: u16) -> WebSocketClient {
+        WebSocketClient::connect(test_config(port), None, None, None, vec![], None)
             .await
             .expect("Failed to connect")
     }
@@ -857,6 +1199,108 @@ mod tests {
         assert!(client.is_disconnected());
     }
 
+    /// Starts an HTTP `CONNECT` proxy tunnelling every connection, returning its port
+    /// and the number of tunnelled connections.
+    async fn start_connect_proxy() -> (u16, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let tunnels = Arc::new(AtomicUsize::new(0));
+        let tunnels_clone = tunnels.clone();
+
+        task::spawn(async move {
+            loop {
+                let (mut inbound, _) = listener.accept().await.unwrap();
+                tunnels_clone.fetch_add(1, Ordering::Relaxed);
+                task::spawn(async move {
+                    let mut request = Vec::new();
+                    let mut buf = [0u8; 512];
+                    while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+                        let n = inbound.read(&mut buf).await.unwrap();
+                        request.extend_from_slice(&buf[..n]);
+                    }
+                    let request = String::from_utf8(request).unwrap();
+                    let target = request.split_whitespace().nth(1).unwrap().to_string();
+                    let mut outbound = TcpStream::connect(target).await.unwrap();
+                    inbound
+                        .write_all(b"HTTP/1.1 200 Connection established\r\n\r\n")
+                        .await
+                        .unwrap();
+                    let _ = copy_bidirectional(&mut inbound, &mut outbound).await;
+                });
+            }
+        });
+
+        (port, tunnels)
+    }
+
+    #[tokio::test]
+    async fn test_websocket_connect_through_proxy() {
+        let server = TestServer::setup().await;
+        let (proxy_port, tunnels) = start_connect_proxy().await;
+        let mut config = test_config(server.port);
+        config.proxy =
+            Some(ProxyConfig::new(&format!("http://127.0.0.1:{proxy_port}"), vec![]).unwrap());
+
+        let client = WebSocketClient::connect(config, None, None, None, vec![], None)
+            .await
+            .unwrap();
+
+        assert!(!client.is_disconnected());
+        assert_eq!(tunnels.load(Ordering::Relaxed), 1);
+        client.send_text("hello".to_string(), None).await.unwrap();
+        client.disconnect().await;
+    }
+
+    /// Starts a `wss://localhost` echo server which requires a client certificate.
+    async fn start_mtls_server() -> u16 {
+        let acceptor = crate::tls::tests::mtls_acceptor();
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        task::spawn(async move {
+            while let Ok((conn, _)) = listener.accept().await {
+                let acceptor = acceptor.clone();
+                task::spawn(async move {
+                    let Ok(stream) = acceptor.accept(conn).await else {
+                        return;
+                    };
+                    let Ok(mut websocket) = tokio_tungstenite::accept_async(stream).await else {
+                        return;
+                    };
+                    while let Some(Ok(msg)) = websocket.next().await {
+                        if msg.is_text() && websocket.send(msg).await.is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+        });
+
+        port
+    }
+
+    #[rstest]
+    #[case(true, true)]
+    #[case(false, false)]
+    #[tokio::test]
+    async fn test_websocket_connect_with_mutual_tls(
+        #[case] mutual: bool,
+        #[case] expected_ok: bool,
+    ) {
+        let port = start_mtls_server().await;
+        let mut config = test_config(port);
+        config.url = format!("wss://localhost:{port}");
+        config.tls = Some(crate::tls::tests::test_tls_config(mutual));
+
+        let result = WebSocketClient::connect(config, None, None, None, vec![], None).await;
+
+        assert_eq!(result.is_ok(), expected_ok);
+        if let Ok(client) = result {
+            client.send_text("hello".to_string(), None).await.unwrap();
+            client.disconnect().await;
+        }
+    }
+
     #[tokio::test]
     async fn test_websocket_heartbeat() {
         let server = TestServer::setup().await;
@@ -884,6 +1328,14 @@ mod tests {
             reconnect_backoff_factor: None,
             reconnect_delay_max_ms: None,
             reconnect_jitter_ms: None,
+            reconnect_max_attempts: None,
+            status_handler: None,
+            payload_compression: None,
+            proxy: None,
+            idle_timeout_ms: None,
+            max_missed_pongs: None,
+            liveness_policy: None,
+            tls: None,
         };
         let res = WebSocketClient::connect(config, None, None, None, vec![], None).await;
         assert!(res.is_err(), "Should fail quickly with no server");
@@ -911,6 +1363,51 @@ mod tests {
         assert!(client.is_disconnected());
     }
 
+    #[tokio::test]
+    async fn test_websocket_reconnect_max_attempts_closes() {
+        let server = TestServer::setup().await;
+        let config = WebSocketConfig {
+            url: format!("ws://127.0.0.1:{}", server.port),
+            headers: vec![("test".into(), "test".into())],
+            handler: None,
+            heartbeat: None,
+            heartbeat_msg: None,
+            ping_handler: None,
+            reconnect_timeout_ms: Some(1_000),
+            reconnect_delay_initial_ms: Some(10),
+            reconnect_backoff_factor: None,
+            reconnect_delay_max_ms: Some(50),
+            reconnect_jitter_ms: Some(0),
+            reconnect_max_attempts: Some(2),
+            status_handler: None,
+            payload_compression: None,
+            proxy: None,
+            idle_timeout_ms: None,
+            max_missed_pongs: None,
+            liveness_policy: None,
+            tls: None,
+        };
+        let client = WebSocketClient::connect(config, None, None, None, vec![], None)
+            .await
+            .unwrap();
+
+        // Stop accepting connections, then signal the client to reconnect
+        drop(server);
+        client
+            .connection_mode
+            .store(ConnectionMode::Reconnect.as_u8(), Ordering::SeqCst);
+
+        tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            while !client.is_closed() {
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("Client should close after the maximum reconnect attempts");
+
+        assert!(client.is_disconnected());
+    }
+
     #[tokio::test]
     async fn test_rate_limiter() {
         let server = TestServer::setup().await;
@@ -928,6 +1425,14 @@ mod tests {
             reconnect_backoff_factor: None,
             reconnect_delay_max_ms: None,
             reconnect_jitter_ms: None,
+            reconnect_max_attempts: None,
+            status_handler: None,
+            payload_compression: None,
+            proxy: None,
+            idle_timeout_ms: None,
+            max_missed_pongs: None,
+            liveness_policy: None,
+            tls: None,
         };
 
         let client = WebSocketClient::connect(
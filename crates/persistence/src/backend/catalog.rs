@@ -13,18 +13,25 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::path::PathBuf;
+use std::{fs::File, path::PathBuf};
 
-use datafusion::{arrow::record_batch::RecordBatch, error::Result};
+use datafusion::{
+    arrow::record_batch::RecordBatch,
+    error::{DataFusionError, Result},
+    parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder,
+};
 use heck::ToSnakeCase;
 use itertools::Itertools;
 use log::info;
 use nautilus_core::UnixNanos;
 use nautilus_model::data::{
-    Bar, Data, GetTsInit, OrderBookDelta, OrderBookDepth10, QuoteTick, TradeTick,
+    Bar, CorporateAction, Data, GetTsInit, OrderBookDelta, OrderBookDepth10, QuoteTick, TradeTick,
 };
 use nautilus_serialization::{
-    arrow::{DecodeDataFromRecordBatch, EncodeToRecordBatch},
+    arrow::{
+        corporate_action::decode_corporate_action_batch, DecodeDataFromRecordBatch,
+        EncodeToRecordBatch,
+    },
     parquet::write_batches_to_parquet,
 };
 use serde::Serialize;
@@ -49,6 +56,18 @@ impl ParquetDataCatalog {
     }
 
     fn make_path(&self, type_name: &str, instrument_id: Option<&String>) -> PathBuf {
+        self.make_file_path(type_name, instrument_id, "data.parquet")
+    }
+
+    /// Returns the path of the file named `file_name` for the data type and identifier,
+    /// creating its directory if needed.
+    #[must_use]
+    pub fn make_file_path(
+        &self,
+        type_name: &str,
+        instrument_id: Option<&String>,
+        file_name: &str,
+    ) -> PathBuf {
         let mut path = self.base_path.join("data").join(type_name);
 
         if let Some(id) = instrument_id {
@@ -56,7 +75,7 @@ impl ParquetDataCatalog {
         }
 
         std::fs::create_dir_all(&path).expect("Failed to create directory");
-        let file_path = path.join("data.parquet");
+        let file_path = path.join(file_name);
         info!("Created directory path: {:?}", file_path);
         file_path
     }
@@ -212,6 +231,54 @@ impl ParquetDataCatalog {
         Ok(self.session.get_query_result())
     }
 
+    /// Query corporate actions stored in the catalog for the given instrument IDs (or all
+    /// instruments if empty), effective between `start` and `end` inclusive.
+    ///
+    /// Corporate actions are not market data, so are read eagerly and returned sorted by
+    /// their effective timestamp rather than streamed through the session.
+    pub fn query_corporate_actions(
+        &self,
+        instrument_ids: Vec<String>,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> Result<Vec<CorporateAction>> {
+        let base_path = self
+            .base_path
+            .join("data")
+            .join(CorporateAction::path_prefix());
+
+        let mut paths = Vec::new();
+        if instrument_ids.is_empty() {
+            if base_path.exists() {
+                for entry in std::fs::read_dir(&base_path)? {
+                    paths.push(entry?.path().join("data.parquet"));
+                }
+            }
+        } else {
+            for instrument_id in &instrument_ids {
+                paths.push(base_path.join(instrument_id).join("data.parquet"));
+            }
+        }
+
+        let mut actions = Vec::new();
+        for path in paths.iter().filter(|path| path.exists()) {
+            let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(path)?)?;
+            let metadata = builder.schema().metadata().clone();
+            for batch in builder.build()? {
+                let decoded = decode_corporate_action_batch(&metadata, &batch?)
+                    .map_err(|e| DataFusionError::External(Box::new(e)))?;
+                actions.extend(decoded);
+            }
+        }
+
+        actions.retain(|action| {
+            start.is_none_or(|start| action.ts_event >= start)
+                && end.is_none_or(|end| action.ts_event <= end)
+        });
+        actions.sort_by_key(|action| action.ts_event);
+        Ok(actions)
+    }
+
     pub fn write_data_enum(&self, data: Vec<Data>) {
         let mut delta: Vec<OrderBookDelta> = Vec::new();
         let mut depth10: Vec<OrderBookDepth10> = Vec::new();
@@ -267,3 +334,4 @@ impl_catalog_path_prefix!(TradeTick, "trades");
 impl_catalog_path_prefix!(OrderBookDelta, "order_book_deltas");
 impl_catalog_path_prefix!(OrderBookDepth10, "order_book_depths");
 impl_catalog_path_prefix!(Bar, "bars");
+impl_catalog_path_prefix!(CorporateAction, "corporate_actions");
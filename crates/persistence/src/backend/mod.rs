@@ -18,4 +18,5 @@
 pub mod catalog;
 pub mod feather;
 pub mod kmerge_batch;
+pub mod recorder;
 pub mod session;
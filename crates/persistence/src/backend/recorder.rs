@@ -0,0 +1,349 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A data recorder appending the data received by the data engine to a Parquet catalog.
+
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::mpsc::{channel, Sender},
+    thread::JoinHandle,
+};
+
+use nautilus_core::UnixNanos;
+use nautilus_data::engine::recorder::DataRecorder;
+use nautilus_model::data::{
+    Bar, Data, GetTsInit, OrderBookDelta, OrderBookDepth10, QuoteTick, TradeTick,
+};
+use nautilus_serialization::arrow::EncodeToRecordBatch;
+
+use super::{
+    catalog::{CatalogPathPrefix, ParquetDataCatalog},
+    feather::RotationConfig,
+};
+
+enum RecorderMessage {
+    Data(Data),
+    Flush,
+}
+
+/// Records data to a [`ParquetDataCatalog`], writing on a background thread.
+///
+/// Data is buffered per data type and instrument (or bar type), with each buffer written to
+/// its own file in the catalog when rotated according to the rotation config, or flushed.
+/// Rotation is by the `ts_init` of the data, so files cover contiguous periods of the session.
+/// Any buffered data is flushed when the recorder is dropped.
+pub struct CatalogRecorder {
+    tx: Option<Sender<RecorderMessage>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl CatalogRecorder {
+    /// Creates a new [`CatalogRecorder`] instance, recording to the catalog at `base_path`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the background writer thread cannot be spawned.
+    #[must_use]
+    pub fn new(base_path: PathBuf, rotation_config: RotationConfig) -> Self {
+        let (tx, rx) = channel::<RecorderMessage>();
+        let handle = std::thread::Builder::new()
+            .name("catalog-recorder".to_string())
+            .spawn(move || {
+                let mut writer = RecorderWriter::new(base_path, rotation_config);
+                // Runs until the recorder is dropped, so closing the channel
+                while let Ok(msg) = rx.recv() {
+                    match msg {
+                        RecorderMessage::Data(data) => writer.record(data),
+                        RecorderMessage::Flush => writer.flush(),
+                    }
+                }
+                writer.flush();
+            })
+            .expect("Failed to spawn recorder thread");
+
+        Self {
+            tx: Some(tx),
+            handle: Some(handle),
+        }
+    }
+
+    fn send(&self, msg: RecorderMessage) {
+        if let Some(tx) = &self.tx {
+            if let Err(e) = tx.send(msg) {
+                log::error!("Error sending to recorder thread: {e}");
+            }
+        }
+    }
+}
+
+impl DataRecorder for CatalogRecorder {
+    fn record(&mut self, data: &Data) {
+        self.send(RecorderMessage::Data(data.clone()));
+    }
+
+    fn flush(&mut self) {
+        self.send(RecorderMessage::Flush);
+    }
+}
+
+impl Drop for CatalogRecorder {
+    fn drop(&mut self) {
+        self.tx = None; // Closes the channel, so the thread flushes and exits
+        if let Some(handle) = self.handle.take() {
+            if handle.join().is_err() {
+                log::error!("Recorder thread panicked");
+            }
+        }
+    }
+}
+
+struct RecorderWriter {
+    catalog: ParquetDataCatalog,
+    rotation_config: RotationConfig,
+    deltas: HashMap<String, Vec<OrderBookDelta>>,
+    depths: HashMap<String, Vec<OrderBookDepth10>>,
+    quotes: HashMap<String, Vec<QuoteTick>>,
+    trades: HashMap<String, Vec<TradeTick>>,
+    bars: HashMap<String, Vec<Bar>>,
+}
+
+impl RecorderWriter {
+    fn new(base_path: PathBuf, rotation_config: RotationConfig) -> Self {
+        Self {
+            catalog: ParquetDataCatalog::new(base_path, None),
+            rotation_config,
+            deltas: HashMap::new(),
+            depths: HashMap::new(),
+            quotes: HashMap::new(),
+            trades: HashMap::new(),
+            bars: HashMap::new(),
+        }
+    }
+
+    fn record(&mut self, data: Data) {
+        let catalog = &self.catalog;
+        let rotation = &self.rotation_config;
+        match data {
+            Data::Delta(delta) => {
+                let key = delta.instrument_id.to_string();
+                append(catalog, rotation, &mut self.deltas, key, delta);
+            }
+            Data::Deltas(deltas) => {
+                let key = deltas.instrument_id.to_string();
+                for delta in &deltas.deltas {
+                    append(catalog, rotation, &mut self.deltas, key.clone(), *delta);
+                }
+            }
+            Data::Depth10(depth) => {
+                let key = depth.instrument_id.to_string();
+                append(catalog, rotation, &mut self.depths, key, *depth);
+            }
+            Data::Quote(quote) => {
+                let key = quote.instrument_id.to_string();
+                append(catalog, rotation, &mut self.quotes, key, quote);
+            }
+            Data::Trade(trade) => {
+                let key = trade.instrument_id.to_string();
+                append(catalog, rotation, &mut self.trades, key, trade);
+            }
+            Data::Bar(bar) => {
+                let key = bar.bar_type.to_string();
+                append(catalog, rotation, &mut self.bars, key, bar);
+            }
+        }
+    }
+
+    fn flush(&mut self) {
+        flush_buffers(&self.catalog, &mut self.deltas);
+        flush_buffers(&self.catalog, &mut self.depths);
+        flush_buffers(&self.catalog, &mut self.quotes);
+        flush_buffers(&self.catalog, &mut self.trades);
+        flush_buffers(&self.catalog, &mut self.bars);
+    }
+}
+
+fn append<T>(
+    catalog: &ParquetDataCatalog,
+    rotation_config: &RotationConfig,
+    buffers: &mut HashMap<String, Vec<T>>,
+    key: String,
+    item: T,
+) where
+    T: GetTsInit + EncodeToRecordBatch + CatalogPathPrefix,
+{
+    let buffer = buffers.entry(key).or_default();
+    if let Some(first) = buffer.first() {
+        let size = std::mem::size_of_val(buffer.as_slice()) as u64;
+        if should_rotate(rotation_config, first.ts_init(), item.ts_init(), size) {
+            write_file(catalog, std::mem::take(buffer));
+        }
+    }
+    buffer.push(item);
+}
+
+fn flush_buffers<T>(catalog: &ParquetDataCatalog, buffers: &mut HashMap<String, Vec<T>>)
+where
+    T: GetTsInit + EncodeToRecordBatch + CatalogPathPrefix,
+{
+    for (_, buffer) in buffers.drain() {
+        write_file(catalog, buffer);
+    }
+}
+
+/// Returns whether a buffer starting at `ts_start`, of `size` bytes, should be rotated before
+/// buffering data at `ts_init`.
+fn should_rotate(
+    rotation_config: &RotationConfig,
+    ts_start: UnixNanos,
+    ts_init: UnixNanos,
+    size: u64,
+) -> bool {
+    match rotation_config {
+        RotationConfig::Size { max_size } => size >= *max_size,
+        RotationConfig::Interval { interval_ns } => {
+            ts_init.as_u64().saturating_sub(ts_start.as_u64()) >= *interval_ns
+        }
+        RotationConfig::ScheduledDates {
+            interval_ns,
+            schedule_ns,
+        } => {
+            let period = |ts: UnixNanos| {
+                ts.as_u64().saturating_sub(schedule_ns.as_u64()) / (*interval_ns).max(1)
+            };
+            period(ts_init) > period(ts_start)
+        }
+        RotationConfig::NoRotation => false,
+    }
+}
+
+/// Writes the `records` to a new file in the catalog, named for the period they cover.
+fn write_file<T>(catalog: &ParquetDataCatalog, mut records: Vec<T>)
+where
+    T: GetTsInit + EncodeToRecordBatch + CatalogPathPrefix,
+{
+    // Live data can arrive slightly out of order between clients
+    records.sort_by_key(GetTsInit::ts_init);
+
+    let (Some(first), Some(last)) = (records.first(), records.last()) else {
+        return;
+    };
+
+    let metadata = T::metadata(first);
+    let identifier = metadata
+        .get("bar_type")
+        .or_else(|| metadata.get("instrument_id"));
+    let file_name = format!("{}-{}.parquet", first.ts_init(), last.ts_init());
+    let path = catalog.make_file_path(T::path_prefix(), identifier, &file_name);
+    let _ = catalog.write_to_parquet(records, Some(path), None, None);
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        identifiers::InstrumentId,
+        types::{Price, Quantity},
+    };
+    use rstest::rstest;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn quote(ts: u64) -> QuoteTick {
+        QuoteTick::new(
+            InstrumentId::from("ETHUSDT.BINANCE"),
+            Price::from("1000.00"),
+            Price::from("1000.10"),
+            Quantity::from("1.000"),
+            Quantity::from("1.000"),
+            UnixNanos::from(ts),
+            UnixNanos::from(ts),
+        )
+    }
+
+    fn recorded_files(base_path: &std::path::Path) -> Vec<String> {
+        let dir = base_path
+            .join("data")
+            .join("quotes")
+            .join("ETHUSDT.BINANCE");
+        let mut files: Vec<String> = std::fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        files.sort();
+        files
+    }
+
+    #[rstest]
+    #[case(RotationConfig::NoRotation, 0, 1_000, 1_000, false)]
+    #[case(RotationConfig::Size { max_size: 1_000 }, 0, 1, 999, false)]
+    #[case(RotationConfig::Size { max_size: 1_000 }, 0, 1, 1_000, true)]
+    #[case(RotationConfig::Interval { interval_ns: 10 }, 5, 14, 0, false)]
+    #[case(RotationConfig::Interval { interval_ns: 10 }, 5, 15, 0, true)]
+    #[case(RotationConfig::ScheduledDates { interval_ns: 10, schedule_ns: UnixNanos::from(2) }, 5, 11, 0, false)]
+    #[case(RotationConfig::ScheduledDates { interval_ns: 10, schedule_ns: UnixNanos::from(2) }, 5, 12, 0, true)]
+    fn test_should_rotate(
+        #[case] rotation_config: RotationConfig,
+        #[case] ts_start: u64,
+        #[case] ts_init: u64,
+        #[case] size: u64,
+        #[case] expected: bool,
+    ) {
+        let result = should_rotate(
+            &rotation_config,
+            UnixNanos::from(ts_start),
+            UnixNanos::from(ts_init),
+            size,
+        );
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    fn test_recorder_writes_rotated_files_on_drop() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut recorder = CatalogRecorder::new(
+            temp_dir.path().to_path_buf(),
+            RotationConfig::Interval { interval_ns: 10 },
+        );
+
+        for ts in [1, 5, 11, 12, 25] {
+            recorder.record(&Data::Quote(quote(ts)));
+        }
+        drop(recorder);
+
+        assert_eq!(
+            recorded_files(temp_dir.path()),
+            vec!["1-5.parquet", "11-12.parquet", "25-25.parquet"]
+        );
+    }
+
+    #[rstest]
+    fn test_recorder_flush_writes_buffered_data() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut recorder =
+            CatalogRecorder::new(temp_dir.path().to_path_buf(), RotationConfig::NoRotation);
+
+        recorder.record(&Data::Quote(quote(2)));
+        recorder.record(&Data::Quote(quote(1)));
+        recorder.flush();
+        recorder.record(&Data::Quote(quote(3)));
+        drop(recorder);
+
+        assert_eq!(
+            recorded_files(temp_dir.path()),
+            vec!["1-2.parquet", "3-3.parquet"]
+        );
+    }
+}
@@ -16,9 +16,13 @@
 use std::path::PathBuf;
 
 use nautilus_core::{ffi::cvec::CVec, python::IntoPyObjectNautilusExt};
-use nautilus_model::data::{
-    is_monotonically_increasing_by_init, to_variant, Bar, Data, OrderBookDelta, QuoteTick,
-    TradeTick,
+use nautilus_model::{
+    data::{
+        is_monotonically_increasing_by_init, to_variant, Bar, CorporateAction, Data,
+        OrderBookDelta, QuoteTick, TradeTick,
+    },
+    identifiers::InstrumentId,
+    types::Money,
 };
 use nautilus_persistence::{
     backend::{
@@ -492,3 +496,30 @@ fn test_catalog_export_functionality() {
         assert_eq!(original, final_quote, "Quotes don't match");
     }
 }
+
+#[rstest]
+fn test_corporate_actions_parquet_round_trip() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let catalog = ParquetDataCatalog::new(temp_dir.path().to_path_buf(), None);
+
+    let aapl = InstrumentId::from("AAPL.XNAS");
+    let msft = InstrumentId::from("MSFT.XNAS");
+    let aapl_actions = vec![
+        CorporateAction::split(aapl, 4, 1, 1.into(), 1.into()).unwrap(),
+        CorporateAction::dividend(aapl, Money::from("0.24 USD"), 3.into(), 3.into()).unwrap(),
+    ];
+    let msft_actions =
+        vec![CorporateAction::dividend(msft, Money::from("0.83 USD"), 2.into(), 2.into()).unwrap()];
+    let _ = catalog.write_to_parquet(aapl_actions.clone(), None, None, None);
+    let _ = catalog.write_to_parquet(msft_actions.clone(), None, None, None);
+
+    let actions = catalog
+        .query_corporate_actions(vec![aapl.to_string()], None, None)
+        .unwrap();
+    assert_eq!(actions, aapl_actions);
+
+    let actions = catalog
+        .query_corporate_actions(Vec::new(), Some(2.into()), None)
+        .unwrap();
+    assert_eq!(actions, vec![msft_actions[0], aapl_actions[1]]);
+}
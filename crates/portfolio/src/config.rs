@@ -13,6 +13,9 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::collections::HashMap;
+
+use nautilus_model::{accounts::MarginModel, identifiers::Venue};
 use serde::{Deserialize, Serialize};
 
 /// Configuration for `Portfolio` instances.
@@ -32,6 +35,10 @@ pub struct PortfolioConfig {
     /// If external bars should be considered for updating unrealized pnls.
     #[serde(default = "default_true")]
     pub bar_updates: bool,
+    /// The margin models per venue for margin requirement calculations (defaults to
+    /// `MarginModel::Leveraged`).
+    #[serde(default)]
+    pub margin_models: HashMap<Venue, MarginModel>,
     /// If debug mode is active (will provide extra debug logging).
     #[serde(default)]
     pub debug: bool,
@@ -47,6 +54,7 @@ impl Default for PortfolioConfig {
             use_mark_prices: false,
             use_mark_xrates: false,
             bar_updates: true,
+            margin_models: HashMap::new(),
             debug: false,
         }
     }
@@ -147,6 +147,12 @@ impl AccountsManager {
                     instrument.make_price(position.avg_px_open),
                     None,
                 ),
+                InstrumentAny::Bond(i) => account.calculate_maintenance_margin(
+                    i,
+                    position.quantity,
+                    instrument.make_price(position.avg_px_open),
+                    None,
+                ),
                 InstrumentAny::CryptoFuture(i) => account.calculate_maintenance_margin(
                     i,
                     position.quantity,
@@ -183,6 +189,18 @@ impl AccountsManager {
                     instrument.make_price(position.avg_px_open),
                     None,
                 ),
+                InstrumentAny::FxForward(i) => account.calculate_maintenance_margin(
+                    i,
+                    position.quantity,
+                    instrument.make_price(position.avg_px_open),
+                    None,
+                ),
+                InstrumentAny::FxSwap(i) => account.calculate_maintenance_margin(
+                    i,
+                    position.quantity,
+                    instrument.make_price(position.avg_px_open),
+                    None,
+                ),
                 InstrumentAny::OptionContract(i) => account.calculate_maintenance_margin(
                     i,
                     position.quantity,
@@ -368,6 +386,9 @@ impl AccountsManager {
                 InstrumentAny::BinaryOption(i) => {
                     account.calculate_initial_margin(i, order.quantity(), price?, None)
                 }
+                InstrumentAny::Bond(i) => {
+                    account.calculate_initial_margin(i, order.quantity(), price?, None)
+                }
                 InstrumentAny::CryptoFuture(i) => {
                     account.calculate_initial_margin(i, order.quantity(), price?, None)
                 }
@@ -386,6 +407,12 @@ impl AccountsManager {
                 InstrumentAny::FuturesSpread(i) => {
                     account.calculate_initial_margin(i, order.quantity(), price?, None)
                 }
+                InstrumentAny::FxForward(i) => {
+                    account.calculate_initial_margin(i, order.quantity(), price?, None)
+                }
+                InstrumentAny::FxSwap(i) => {
+                    account.calculate_initial_margin(i, order.quantity(), price?, None)
+                }
                 InstrumentAny::OptionContract(i) => {
                     account.calculate_initial_margin(i, order.quantity(), price?, None)
                 }
@@ -30,7 +30,7 @@ use nautilus_common::{
     msgbus::{handler::ShareableMessageHandler, MessageBus},
 };
 use nautilus_model::{
-    accounts::AccountAny,
+    accounts::{AccountAny, MarginRequirement},
     data::{Bar, QuoteTick},
     enums::{OrderSide, OrderType, PositionSide, PriceType},
     events::{position::PositionEvent, AccountState, OrderEventAny},
@@ -299,6 +299,99 @@ impl Portfolio {
         )
     }
 
+    /// Returns the initial and maintenance margin required for the open positions at the
+    /// `venue`, calculated with the margin model configured for the venue at current prices.
+    #[must_use]
+    pub fn margin_requirements(
+        &self,
+        venue: &Venue,
+    ) -> Option<HashMap<Currency, MarginRequirement>> {
+        let cache = self.cache.borrow();
+        let margin_account = match cache.account_for_venue(venue) {
+            Some(AccountAny::Margin(margin_account)) => margin_account,
+            Some(AccountAny::Cash(_)) => {
+                log::warn!("Margin requirements not applicable for cash account");
+                return None;
+            }
+            None => {
+                log::error!(
+                    "Cannot calculate margin requirements: no account registered for {}",
+                    venue
+                );
+                return None; // Cannot calculate
+            }
+        };
+
+        let margin_model = self
+            .config
+            .margin_models
+            .get(venue)
+            .cloned()
+            .unwrap_or_default();
+
+        let mut requirements: HashMap<Currency, MarginRequirement> = HashMap::new();
+        for position in cache.positions_open(Some(venue), None, None, None) {
+            let instrument = if let Some(instrument) = cache.instrument(&position.instrument_id) {
+                instrument
+            } else {
+                log::error!(
+                    "Cannot calculate margin requirements: no instrument for {}",
+                    position.instrument_id
+                );
+                return None; // Cannot calculate
+            };
+
+            let price = if let Some(price) = self.get_price(position) {
+                price
+            } else {
+                log::error!(
+                    "Cannot calculate margin requirements: no prices for {}",
+                    position.instrument_id
+                );
+                return None; // Cannot calculate
+            };
+
+            let margin = margin_model.calculate(
+                instrument,
+                position.side,
+                position.quantity,
+                price,
+                margin_account.get_leverage(&position.instrument_id),
+                None,
+            );
+            let requirement =
+                requirements
+                    .entry(margin.initial.currency)
+                    .or_insert(MarginRequirement {
+                        initial: Money::new(0.0, margin.initial.currency),
+                        maintenance: Money::new(0.0, margin.maintenance.currency),
+                    });
+            requirement.initial += margin.initial;
+            requirement.maintenance += margin.maintenance;
+        }
+
+        Some(requirements)
+    }
+
+    /// Returns the margin utilization of the account at the `venue` per currency, as the
+    /// initial margin required for the open positions divided by the total balance.
+    #[must_use]
+    pub fn margin_utilization(&self, venue: &Venue) -> Option<HashMap<Currency, f64>> {
+        let requirements = self.margin_requirements(venue)?;
+        let cache = self.cache.borrow();
+        let account = cache.account_for_venue(venue)?;
+
+        Some(
+            requirements
+                .into_iter()
+                .filter_map(|(currency, requirement)| {
+                    let total = account.balance(Some(currency))?.total.as_f64();
+                    (total > 0.0).then(|| (currency, requirement.initial.as_f64() / total))
+                })
+                .collect(),
+        )
+    }
+
     #[must_use]
     pub fn unrealized_pnls(&mut self, venue: &Venue) -> HashMap<Currency, Money> {
         let instrument_ids = {
@@ -557,6 +650,45 @@ impl Portfolio {
         Some(Money::new(net_exposure, settlement_currency))
     }
 
+    /// Returns the cash flows of the open FX forward and swap positions at the `venue` which
+    /// are yet to reach their value date, netted per currency.
+    ///
+    /// Deliverable forwards contribute both currency legs at the average open price. Swaps
+    /// contribute the net swap points amount in the quote currency, the base currency notionals
+    /// of the near and far legs offsetting. Non-deliverable forwards exchange only the
+    /// difference to the fixing rate, which is reported as unrealized PnL instead.
+    #[must_use]
+    pub fn unsettled_cash_flows(&self, venue: &Venue) -> HashMap<Currency, Money> {
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let cache = self.cache.borrow();
+
+        let mut cash_flows: HashMap<Currency, f64> = HashMap::new();
+
+        for position in cache.positions_open(Some(venue), None, None, None) {
+            let price = Price::new(position.avg_px_open, position.price_precision);
+            match cache.instrument(&position.instrument_id) {
+                Some(InstrumentAny::FxForward(forward))
+                    if !forward.is_non_deliverable() && !forward.is_settled(ts_now) =>
+                {
+                    let (base, quote) =
+                        forward.settlement_amounts(position.entry, position.quantity, price);
+                    *cash_flows.entry(base.currency).or_insert(0.0) += base.as_f64();
+                    *cash_flows.entry(quote.currency).or_insert(0.0) += quote.as_f64();
+                }
+                Some(InstrumentAny::FxSwap(swap)) if !swap.is_settled(ts_now) => {
+                    let net = swap.net_settlement_amount(position.entry, position.quantity, price);
+                    *cash_flows.entry(net.currency).or_insert(0.0) += net.as_f64();
+                }
+                _ => continue, // Settles immediately or not a forward
+            }
+        }
+
+        cash_flows
+            .into_iter()
+            .map(|(currency, amount)| (currency, Money::new(amount, currency)))
+            .collect()
+    }
+
     #[must_use]
     pub fn net_position(&self, instrument_id: &InstrumentId) -> Decimal {
         self.inner
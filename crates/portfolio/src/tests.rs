@@ -33,8 +33,11 @@ mod tests {
             AccountId, ClientOrderId, PositionId, StrategyId, Symbol, TradeId, VenueOrderId,
         },
         instruments::{
-            stubs::{audusd_sim, currency_pair_btcusdt, default_fx_ccy, ethusdt_bitmex},
-            CryptoPerpetual, CurrencyPair, InstrumentAny,
+            stubs::{
+                audusd_sim, currency_pair_btcusdt, default_fx_ccy, ethusdt_bitmex,
+                fx_forward_eurusd, fx_swap_eurusd,
+            },
+            CryptoPerpetual, CurrencyPair, FxForward, FxSwap, InstrumentAny,
         },
         orders::{OrderAny, OrderTestBuilder},
         position::Position,
@@ -916,6 +919,92 @@ mod tests {
         assert!(!portfolio.is_completely_flat());
     }
 
+    #[rstest]
+    fn test_margin_requirements_and_utilization_for_open_position(
+        mut portfolio: Portfolio,
+        instrument_audusd: InstrumentAny,
+    ) {
+        let account_state = get_margin_account(None);
+        portfolio.update_account(&account_state);
+
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_audusd.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("10.00"))
+            .build();
+
+        let mut fill = fill_order(&order);
+        fill.position_id = Some(PositionId::new("SSD"));
+
+        let last = get_quote_tick(&instrument_audusd, 10510.0, 10511.0, 1.0, 1.0);
+        portfolio.cache.borrow_mut().add_quote(last).unwrap();
+        portfolio.update_quote_tick(&last);
+
+        let position = Position::new(&instrument_audusd, fill);
+        portfolio
+            .cache
+            .borrow_mut()
+            .add_position(position.clone(), OmsType::Hedging)
+            .unwrap();
+
+        let requirements = portfolio.margin_requirements(&Venue::from("SIM")).unwrap();
+        let utilization = portfolio.margin_utilization(&Venue::from("SIM")).unwrap();
+
+        // Notional of 10510 USD at 3% margin, plus two taker fees for initial and one for
+        // maintenance, against a total balance of 10 USD
+        let requirement = requirements.get(&Currency::USD()).unwrap();
+        assert_eq!(requirement.initial, Money::new(315.72, Currency::USD()));
+        assert_eq!(requirement.maintenance, Money::new(315.51, Currency::USD()));
+        assert!((utilization.get(&Currency::USD()).unwrap() - 31.572).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_unsettled_cash_flows_for_fx_forward_and_swap_positions(
+        portfolio: Portfolio,
+        fx_forward_eurusd: FxForward,
+        fx_swap_eurusd: FxSwap,
+    ) {
+        let forward = InstrumentAny::FxForward(fx_forward_eurusd);
+        let swap = InstrumentAny::FxSwap(fx_swap_eurusd);
+        let mut cache = portfolio.cache.borrow_mut();
+        cache.add_instrument(forward.clone()).unwrap();
+        cache.add_instrument(swap.clone()).unwrap();
+
+        for (instrument, side, px, position_id) in [
+            (&forward, OrderSide::Sell, "1.09125", "P-FWD"),
+            (&swap, OrderSide::Buy, "12.10", "P-SWAP"),
+        ] {
+            let order = OrderTestBuilder::new(OrderType::Market)
+                .instrument_id(instrument.id())
+                .side(side)
+                .quantity(Quantity::from(1_000_000))
+                .build();
+            let mut fill = fill_order(&order);
+            fill.order_side = side;
+            fill.last_qty = Quantity::from(1_000_000);
+            fill.last_px = Price::from(px);
+            fill.currency = Currency::USD();
+            fill.position_id = Some(PositionId::new(position_id));
+
+            let position = Position::new(instrument, fill);
+            cache.add_position(position, OmsType::Hedging).unwrap();
+        }
+        drop(cache);
+
+        let cash_flows = portfolio.unsettled_cash_flows(&Venue::from("SIM"));
+
+        // Sold EUR 1m forward at 1.09125, and paid 12.10 points on EUR 1m swapped
+        assert_eq!(cash_flows.len(), 2);
+        assert_eq!(
+            cash_flows.get(&Currency::EUR()),
+            Some(&Money::new(-1_000_000.0, Currency::EUR()))
+        );
+        assert_eq!(
+            cash_flows.get(&Currency::USD()),
+            Some(&Money::new(1_090_040.0, Currency::USD()))
+        );
+    }
+
     #[rstest]
     fn test_opening_one_long_position_updates_portfolio_with_bar(
         mut portfolio: Portfolio,
@@ -0,0 +1,273 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Detection of pathological order flow from runaway strategies.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Display,
+};
+
+use nautilus_core::{datetime::NANOSECONDS_IN_SECOND, UnixNanos, UUID4};
+use nautilus_model::{
+    identifiers::{ClientOrderId, StrategyId, TraderId},
+    types::Price,
+};
+use serde::{Deserialize, Serialize};
+use strum::Display as StrumDisplay;
+
+/// Configuration for the detection of runaway strategies, where each pattern is detected once
+/// its count within the sliding window reaches the maximum.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnomalyConfig {
+    /// The sliding window (nanoseconds) patterns are counted over.
+    pub window_ns: u64,
+    /// The maximum number of canceled orders of a strategy.
+    pub max_cancels: usize,
+    /// The maximum number of rejected orders of a strategy.
+    pub max_rejections: usize,
+    /// The maximum number of price direction reversals of modifies to a single order.
+    pub max_modify_reversals: usize,
+}
+
+impl Default for AnomalyConfig {
+    fn default() -> Self {
+        Self {
+            window_ns: 60 * NANOSECONDS_IN_SECOND,
+            max_cancels: 100,
+            max_rejections: 10,
+            max_modify_reversals: 10,
+        }
+    }
+}
+
+/// The kind of pathological order flow detected.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, StrumDisplay, Serialize, Deserialize)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum AnomalyKind {
+    /// Orders repeatedly submitted and canceled.
+    OrderCancelLoop,
+    /// Orders repeatedly rejected by the venue.
+    RepeatedRejections,
+    /// An order repeatedly modified back and forth in price.
+    OscillatingModifies,
+}
+
+/// Represents an incident where a strategy was detected as runaway and suspended.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RunawayStrategyDetected {
+    pub trader_id: TraderId,
+    pub strategy_id: StrategyId,
+    pub kind: AnomalyKind,
+    /// The count of the pattern within the window.
+    pub count: usize,
+    pub window_ns: u64,
+    /// The details of the last occurrence of the pattern.
+    pub details: String,
+    pub event_id: UUID4,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl Display for RunawayStrategyDetected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(strategy_id={}, kind={}, count={}, window_ns={}, details={})",
+            stringify!(RunawayStrategyDetected),
+            self.strategy_id,
+            self.kind,
+            self.count,
+            self.window_ns,
+            self.details,
+        )
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct ModifyState {
+    strategy_id: Option<StrategyId>,
+    direction: i8,
+    reversals: VecDeque<UnixNanos>,
+}
+
+/// Counts the patterns of order flow per strategy over a sliding window.
+#[derive(Clone, Debug)]
+pub struct AnomalyDetector {
+    config: AnomalyConfig,
+    cancels: HashMap<StrategyId, VecDeque<UnixNanos>>,
+    rejections: HashMap<StrategyId, VecDeque<UnixNanos>>,
+    modifies: HashMap<ClientOrderId, ModifyState>,
+}
+
+impl AnomalyDetector {
+    /// Creates a new [`AnomalyDetector`] instance.
+    #[must_use]
+    pub fn new(config: AnomalyConfig) -> Self {
+        Self {
+            config,
+            cancels: HashMap::new(),
+            rejections: HashMap::new(),
+            modifies: HashMap::new(),
+        }
+    }
+
+    /// Returns the configuration of the detector.
+    #[must_use]
+    pub const fn config(&self) -> &AnomalyConfig {
+        &self.config
+    }
+
+    /// Records a canceled order of the strategy, returning the count if the maximum is reached.
+    pub fn on_canceled(&mut self, strategy_id: StrategyId, ts: UnixNanos) -> Option<usize> {
+        let window = self.cancels.entry(strategy_id).or_default();
+        record(window, ts, self.config.window_ns, self.config.max_cancels)
+    }
+
+    /// Records a rejected order of the strategy, returning the count if the maximum is reached.
+    pub fn on_rejected(&mut self, strategy_id: StrategyId, ts: UnixNanos) -> Option<usize> {
+        let window = self.rejections.entry(strategy_id).or_default();
+        record(
+            window,
+            ts,
+            self.config.window_ns,
+            self.config.max_rejections,
+        )
+    }
+
+    /// Records a modify of the order price from `from` to `to`, returning the count of price
+    /// direction reversals if the maximum is reached.
+    pub fn on_modify(
+        &mut self,
+        strategy_id: StrategyId,
+        client_order_id: ClientOrderId,
+        from: Price,
+        to: Price,
+        ts: UnixNanos,
+    ) -> Option<usize> {
+        let direction = match to.cmp(&from) {
+            std::cmp::Ordering::Greater => 1,
+            std::cmp::Ordering::Less => -1,
+            std::cmp::Ordering::Equal => return None,
+        };
+
+        let state = self.modifies.entry(client_order_id).or_default();
+        state.strategy_id = Some(strategy_id);
+        let reversed = state.direction == -direction;
+        state.direction = direction;
+        if !reversed {
+            return None;
+        }
+        record(
+            &mut state.reversals,
+            ts,
+            self.config.window_ns,
+            self.config.max_modify_reversals,
+        )
+    }
+
+    /// Stops tracking the modifies of the closed order.
+    pub fn on_order_closed(&mut self, client_order_id: &ClientOrderId) {
+        self.modifies.remove(client_order_id);
+    }
+
+    /// Clears the counts of the strategy (once an incident is raised).
+    pub fn reset(&mut self, strategy_id: &StrategyId) {
+        self.cancels.remove(strategy_id);
+        self.rejections.remove(strategy_id);
+        self.modifies
+            .retain(|_, state| state.strategy_id.as_ref() != Some(strategy_id));
+    }
+}
+
+fn record(
+    window: &mut VecDeque<UnixNanos>,
+    ts: UnixNanos,
+    window_ns: u64,
+    max: usize,
+) -> Option<usize> {
+    window.push_back(ts);
+    let start = ts.as_u64().saturating_sub(window_ns);
+    while window.front().is_some_and(|front| front.as_u64() < start) {
+        window.pop_front();
+    }
+    (window.len() >= max).then_some(window.len())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn detector() -> AnomalyDetector {
+        AnomalyDetector::new(AnomalyConfig {
+            window_ns: 100,
+            max_cancels: 3,
+            max_rejections: 2,
+            max_modify_reversals: 2,
+        })
+    }
+
+    #[rstest]
+    fn test_cancels_counted_within_window() {
+        let mut detector = detector();
+        let strategy_id = StrategyId::from("S-001");
+
+        assert_eq!(detector.on_canceled(strategy_id, 0.into()), None);
+        assert_eq!(detector.on_canceled(strategy_id, 60.into()), None);
+        // The first cancel falls out of the window
+        assert_eq!(detector.on_canceled(strategy_id, 110.into()), None);
+        assert_eq!(detector.on_canceled(strategy_id, 120.into()), Some(3));
+    }
+
+    #[rstest]
+    fn test_rejections_reset() {
+        let mut detector = detector();
+        let strategy_id = StrategyId::from("S-001");
+
+        assert_eq!(detector.on_rejected(strategy_id, 0.into()), None);
+        assert_eq!(detector.on_rejected(strategy_id, 1.into()), Some(2));
+
+        detector.reset(&strategy_id);
+
+        assert_eq!(detector.on_rejected(strategy_id, 2.into()), None);
+    }
+
+    #[rstest]
+    fn test_oscillating_modifies() {
+        let mut detector = detector();
+        let strategy_id = StrategyId::from("S-001");
+        let client_order_id = ClientOrderId::from("O-1");
+        let mut modify = |from: &str, to: &str, ts: u64| {
+            detector.on_modify(
+                strategy_id,
+                client_order_id,
+                Price::from(from),
+                Price::from(to),
+                ts.into(),
+            )
+        };
+
+        // Trending modifies are not reversals
+        assert_eq!(modify("1.00", "1.01", 0), None);
+        assert_eq!(modify("1.01", "1.02", 1), None);
+        assert_eq!(modify("1.02", "1.01", 2), None);
+        assert_eq!(modify("1.01", "1.02", 3), Some(2));
+    }
+}
@@ -0,0 +1,251 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An audit trail of risk decisions persisted to the cache database.
+
+use std::fmt::Display;
+
+use bytes::Bytes;
+use nautilus_common::cache::Cache;
+use nautilus_core::{UnixNanos, UUID4};
+use nautilus_model::identifiers::{ClientOrderId, InstrumentId, StrategyId, TraderId};
+use serde::{Deserialize, Serialize};
+use strum::Display as StrumDisplay;
+
+/// The prefix of the cache keys audit records are persisted under.
+pub const RISK_AUDIT_KEY_PREFIX: &str = "risk_audit:";
+
+/// The decision taken by the risk engine.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, StrumDisplay, Serialize, Deserialize)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum RiskDecision {
+    /// The command passed all checks and was sent for execution.
+    Approved,
+    /// The command failed a check.
+    Denied,
+    /// The command exceeded a rate limit.
+    Throttled,
+    /// The trading state of the trader or a strategy changed.
+    TradingStateChanged,
+}
+
+/// Represents a risk decision, recording the rule and reason behind any blocked order.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RiskAuditRecord {
+    pub trader_id: TraderId,
+    pub strategy_id: Option<StrategyId>,
+    pub instrument_id: Option<InstrumentId>,
+    pub client_order_id: Option<ClientOrderId>,
+    pub decision: RiskDecision,
+    /// The rule responsible for the decision (e.g. `MAX_NOTIONAL`).
+    pub rule: Option<String>,
+    pub reason: Option<String>,
+    pub event_id: UUID4,
+    pub ts_event: UnixNanos,
+}
+
+impl RiskAuditRecord {
+    /// Creates a new [`RiskAuditRecord`] instance, parsing the rule from the `reason`.
+    #[must_use]
+    pub fn new(
+        trader_id: TraderId,
+        strategy_id: Option<StrategyId>,
+        instrument_id: Option<InstrumentId>,
+        client_order_id: Option<ClientOrderId>,
+        decision: RiskDecision,
+        reason: Option<&str>,
+        ts_event: UnixNanos,
+    ) -> Self {
+        Self {
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            decision,
+            rule: reason.and_then(rule_of),
+            reason: reason.map(ToString::to_string),
+            event_id: UUID4::new(),
+            ts_event,
+        }
+    }
+
+    /// Returns the cache key of the record, ordering records by time.
+    #[must_use]
+    pub fn key(&self) -> String {
+        format!(
+            "{RISK_AUDIT_KEY_PREFIX}{:020}:{}",
+            self.ts_event.as_u64(),
+            self.event_id
+        )
+    }
+
+    /// Persists the record to the `cache` (and its database).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the record cannot be serialized or persisted.
+    pub fn persist(&self, cache: &mut Cache) -> anyhow::Result<()> {
+        let bytes = serde_json::to_vec(self)?;
+        cache.add(&self.key(), Bytes::from(bytes))
+    }
+}
+
+impl Display for RiskAuditRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(decision={}, client_order_id={}, rule={}, reason={})",
+            stringify!(RiskAuditRecord),
+            self.decision,
+            self.client_order_id
+                .map_or_else(|| "None".to_string(), |id| id.to_string()),
+            self.rule.as_deref().unwrap_or("None"),
+            self.reason.as_deref().unwrap_or("None"),
+        )
+    }
+}
+
+/// Returns the rule of a denial `reason`, being its leading `SCREAMING_SNAKE_CASE` token.
+fn rule_of(reason: &str) -> Option<String> {
+    if reason.contains("TradingState::") {
+        return Some("TRADING_STATE".to_string());
+    }
+
+    let token = reason.split(": ").next()?;
+    let is_rule = !token.is_empty()
+        && token
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_');
+    is_rule.then(|| token.to_string())
+}
+
+/// A filter of audit records, where each `None` criterion matches all records.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RiskAuditFilter {
+    pub strategy_id: Option<StrategyId>,
+    pub instrument_id: Option<InstrumentId>,
+    pub client_order_id: Option<ClientOrderId>,
+    pub decision: Option<RiskDecision>,
+    /// The inclusive start of the records time range.
+    pub start: Option<UnixNanos>,
+    /// The inclusive end of the records time range.
+    pub end: Option<UnixNanos>,
+}
+
+impl RiskAuditFilter {
+    /// Returns whether the `record` matches the filter.
+    #[must_use]
+    pub fn matches(&self, record: &RiskAuditRecord) -> bool {
+        self.strategy_id
+            .is_none_or(|id| record.strategy_id == Some(id))
+            && self
+                .instrument_id
+                .is_none_or(|id| record.instrument_id == Some(id))
+            && self
+                .client_order_id
+                .is_none_or(|id| record.client_order_id == Some(id))
+            && self.decision.is_none_or(|d| record.decision == d)
+            && self.start.is_none_or(|start| record.ts_event >= start)
+            && self.end.is_none_or(|end| record.ts_event <= end)
+    }
+}
+
+/// Returns the audit records in the `cache` matching the `filter`, in time order.
+#[must_use]
+pub fn query_audit_records(cache: &Cache, filter: &RiskAuditFilter) -> Vec<RiskAuditRecord> {
+    cache
+        .get_prefixed(RISK_AUDIT_KEY_PREFIX)
+        .into_iter()
+        .filter_map(
+            |(key, bytes)| match serde_json::from_slice::<RiskAuditRecord>(bytes) {
+                Ok(record) => Some(record),
+                Err(e) => {
+                    log::error!("Cannot decode risk audit record {key}: {e}");
+                    None
+                }
+            },
+        )
+        .filter(|record| filter.matches(record))
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("MAX_NOTIONAL: S-001 max_notional=500", Some("MAX_NOTIONAL"))]
+    #[case(
+        "NOTIONAL_EXCEEDS_FREE_BALANCE: free=10.00 USD, notional=20.00 USD",
+        Some("NOTIONAL_EXCEEDS_FREE_BALANCE")
+    )]
+    #[case("TradingState::HALTED", Some("TRADING_STATE"))]
+    #[case(
+        "BUY when TradingState::REDUCING and LONG AUD/USD.SIM",
+        Some("TRADING_STATE")
+    )]
+    #[case("REJECTED BY THROTTLER", None)]
+    fn test_rule_of(#[case] reason: &str, #[case] expected: Option<&str>) {
+        assert_eq!(rule_of(reason).as_deref(), expected);
+    }
+
+    #[rstest]
+    fn test_persist_and_query() {
+        let mut cache = Cache::default();
+        let record = |client_order_id: &str, decision, reason, ts: u64| {
+            RiskAuditRecord::new(
+                TraderId::from("TRADER-001"),
+                Some(StrategyId::from("S-001")),
+                Some(InstrumentId::from("AUD/USD.SIM")),
+                Some(ClientOrderId::from(client_order_id)),
+                decision,
+                reason,
+                ts.into(),
+            )
+        };
+        let approved = record("O-1", RiskDecision::Approved, None, 2);
+        let denied = record("O-2", RiskDecision::Denied, Some("MAX_POSITION: S-001"), 1);
+        approved.persist(&mut cache).unwrap();
+        denied.persist(&mut cache).unwrap();
+
+        assert_eq!(
+            query_audit_records(&cache, &RiskAuditFilter::default()),
+            vec![denied.clone(), approved]
+        );
+        assert_eq!(
+            query_audit_records(
+                &cache,
+                &RiskAuditFilter {
+                    decision: Some(RiskDecision::Denied),
+                    ..Default::default()
+                }
+            ),
+            vec![denied]
+        );
+        assert!(query_audit_records(
+            &cache,
+            &RiskAuditFilter {
+                start: Some(3.into()),
+                ..Default::default()
+            }
+        )
+        .is_empty());
+    }
+}
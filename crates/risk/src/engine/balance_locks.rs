@@ -0,0 +1,224 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Balance locked against the estimated cost of the open orders of cash accounts.
+
+use std::collections::HashMap;
+
+use nautilus_model::{
+    enums::OrderSide,
+    identifiers::ClientOrderId,
+    instruments::InstrumentAny,
+    orders::OrderAny,
+    types::{Currency, Money, Price, Quantity},
+};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+/// Returns the estimated cost of the `order` at `price`, being the balance it could consume.
+///
+/// Buy orders cost their notional value plus the taker fee, with the `slippage_buffer` (as a
+/// fraction of the notional) added for orders without a limit price. Sell orders of instruments
+/// with a base currency cost their quantity of the base currency.
+#[must_use]
+pub fn estimate_order_cost(
+    instrument: &InstrumentAny,
+    order: &OrderAny,
+    price: Price,
+    slippage_buffer: Decimal,
+) -> Option<Money> {
+    match order.order_side() {
+        OrderSide::Buy => {
+            let notional = instrument.calculate_notional_value(order.quantity(), price, None);
+            let mut rate = Decimal::ONE + instrument.taker_fee();
+            if order.price().is_none() {
+                rate += slippage_buffer;
+            }
+            Some(Money::new(
+                notional.as_f64() * rate.to_f64().unwrap_or(1.0),
+                notional.currency,
+            ))
+        }
+        OrderSide::Sell => {
+            if instrument.is_inverse() {
+                return None;
+            }
+            instrument
+                .base_currency()
+                .map(|base_currency| Money::new(order.quantity().as_f64(), base_currency))
+        }
+        OrderSide::NoOrderSide => None,
+    }
+}
+
+#[derive(Clone, Debug)]
+struct OrderLock {
+    amount: Money,
+    leaves_qty: f64,
+}
+
+/// The balance locked per order, released as orders are filled or closed.
+#[derive(Clone, Debug, Default)]
+pub struct BalanceLocks {
+    locks: HashMap<ClientOrderId, OrderLock>,
+}
+
+impl BalanceLocks {
+    /// Locks `amount` for the order of `quantity`, replacing any existing lock.
+    pub fn lock(&mut self, client_order_id: ClientOrderId, amount: Money, quantity: Quantity) {
+        self.locks.insert(
+            client_order_id,
+            OrderLock {
+                amount,
+                leaves_qty: quantity.as_f64(),
+            },
+        );
+    }
+
+    /// Releases the share of the order lock for the `filled_qty`, releasing it entirely once
+    /// the order is completely filled.
+    pub fn release_fill(&mut self, client_order_id: &ClientOrderId, filled_qty: Quantity) {
+        let Some(lock) = self.locks.get_mut(client_order_id) else {
+            return;
+        };
+
+        let filled_qty = filled_qty.as_f64();
+        if filled_qty >= lock.leaves_qty {
+            self.locks.remove(client_order_id);
+            return;
+        }
+
+        let remaining = (lock.leaves_qty - filled_qty) / lock.leaves_qty;
+        lock.amount = Money::new(lock.amount.as_f64() * remaining, lock.amount.currency);
+        lock.leaves_qty -= filled_qty;
+    }
+
+    /// Releases the lock of the order, returning the amount released.
+    pub fn release(&mut self, client_order_id: &ClientOrderId) -> Option<Money> {
+        self.locks.remove(client_order_id).map(|lock| lock.amount)
+    }
+
+    /// Returns the amount locked for the order.
+    #[must_use]
+    pub fn order_locked(&self, client_order_id: &ClientOrderId) -> Option<Money> {
+        self.locks.get(client_order_id).map(|lock| lock.amount)
+    }
+
+    /// Returns the total amount locked in the `currency`.
+    #[must_use]
+    pub fn locked(&self, currency: Currency) -> Money {
+        let raw = self
+            .locks
+            .values()
+            .filter(|lock| lock.amount.currency == currency)
+            .map(|lock| lock.amount.raw)
+            .sum();
+        Money::from_raw(raw, currency)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_model::{
+        enums::OrderType,
+        instruments::{stubs::currency_pair_btcusdt, CurrencyPair},
+        orders::OrderTestBuilder,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(
+        OrderType::Limit,
+        OrderSide::Buy,
+        Money::new(50_050.0, Currency::USDT())
+    )]
+    #[case(
+        OrderType::Market,
+        OrderSide::Buy,
+        Money::new(50_550.0, Currency::USDT())
+    )]
+    #[case(OrderType::Limit, OrderSide::Sell, Money::new(1.0, Currency::BTC()))]
+    fn test_estimate_order_cost(
+        currency_pair_btcusdt: CurrencyPair,
+        #[case] order_type: OrderType,
+        #[case] side: OrderSide,
+        #[case] expected: Money,
+    ) {
+        let instrument = InstrumentAny::CurrencyPair(currency_pair_btcusdt);
+        let mut builder = OrderTestBuilder::new(order_type);
+        builder
+            .instrument_id(instrument.id())
+            .side(side)
+            .quantity(Quantity::from("1.000000"));
+        if order_type == OrderType::Limit {
+            builder.price(Price::from("50000.00"));
+        }
+        let order = builder.build();
+
+        // Taker fee of 0.1% and slippage buffer of 1%
+        let cost = estimate_order_cost(
+            &instrument,
+            &order,
+            Price::from("50000.00"),
+            Decimal::from_str("0.01").unwrap(),
+        );
+
+        assert_eq!(cost, Some(expected));
+    }
+
+    #[rstest]
+    fn test_locks_released_on_fills() {
+        let mut locks = BalanceLocks::default();
+        let first = ClientOrderId::from("O-1");
+        let second = ClientOrderId::from("O-2");
+        locks.lock(
+            first,
+            Money::new(1_000.0, Currency::USD()),
+            Quantity::from(100),
+        );
+        locks.lock(
+            second,
+            Money::new(500.0, Currency::USD()),
+            Quantity::from(50),
+        );
+
+        locks.release_fill(&first, Quantity::from(25));
+        assert_eq!(
+            locks.order_locked(&first),
+            Some(Money::new(750.0, Currency::USD()))
+        );
+        assert_eq!(
+            locks.locked(Currency::USD()),
+            Money::new(1_250.0, Currency::USD())
+        );
+
+        locks.release_fill(&first, Quantity::from(75));
+        assert_eq!(locks.order_locked(&first), None);
+        assert_eq!(
+            locks.release(&second),
+            Some(Money::new(500.0, Currency::USD()))
+        );
+        assert_eq!(
+            locks.locked(Currency::USD()),
+            Money::new(0.0, Currency::USD())
+        );
+    }
+}
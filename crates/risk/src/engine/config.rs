@@ -19,9 +19,17 @@ use std::collections::HashMap;
 
 use nautilus_common::throttler::RateLimit;
 use nautilus_core::datetime::NANOSECONDS_IN_SECOND;
-use nautilus_model::identifiers::InstrumentId;
+use nautilus_model::{
+    accounts::MarginModel,
+    identifiers::{AccountId, InstrumentId, StrategyId, Venue},
+};
 use rust_decimal::Decimal;
 
+use super::{
+    anomaly::AnomalyConfig, exposure::ExposureGroup, limits::PreTradeLimits,
+    loss_limits::LossLimits, stress::StressScenario,
+};
+
 #[derive(Debug)]
 /// Configuration for `RiskEngineConfig` instances.
 pub struct RiskEngineConfig {
@@ -29,6 +37,21 @@ pub struct RiskEngineConfig {
     pub max_order_submit: RateLimit,
     pub max_order_modify: RateLimit,
     pub max_notional_per_order: HashMap<InstrumentId, Decimal>,
+    pub trader_limits: Option<PreTradeLimits>,
+    pub instrument_limits: HashMap<InstrumentId, PreTradeLimits>,
+    pub strategy_limits: HashMap<StrategyId, PreTradeLimits>,
+    pub exposure_groups: Vec<ExposureGroup>,
+    pub cancel_orders_on_halt: bool,
+    pub flatten_positions_on_halt: bool,
+    pub strategy_loss_limits: HashMap<StrategyId, LossLimits>,
+    pub account_loss_limits: HashMap<AccountId, LossLimits>,
+    pub margin_models: HashMap<Venue, MarginModel>,
+    pub lock_order_balances: bool,
+    pub slippage_buffer: Decimal,
+    pub anomaly_detection: Option<AnomalyConfig>,
+    pub stress_scenarios: Vec<StressScenario>,
+    pub stress_test_interval_ms: u32,
+    pub audit_trail: bool,
     pub debug: bool,
 }
 
@@ -40,6 +63,21 @@ impl Default for RiskEngineConfig {
             max_order_submit: RateLimit::new(100, NANOSECONDS_IN_SECOND),
             max_order_modify: RateLimit::new(100, NANOSECONDS_IN_SECOND),
             max_notional_per_order: HashMap::new(),
+            trader_limits: None,
+            instrument_limits: HashMap::new(),
+            strategy_limits: HashMap::new(),
+            exposure_groups: Vec::new(),
+            cancel_orders_on_halt: false,
+            flatten_positions_on_halt: false,
+            strategy_loss_limits: HashMap::new(),
+            account_loss_limits: HashMap::new(),
+            margin_models: HashMap::new(),
+            lock_order_balances: false,
+            slippage_buffer: Decimal::ZERO,
+            anomaly_detection: None,
+            stress_scenarios: Vec::new(),
+            stress_test_interval_ms: 0,
+            audit_trail: false,
             debug: false,
         }
     }
@@ -0,0 +1,131 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Net exposure limits aggregated across groups of correlated instruments.
+
+use std::collections::HashMap;
+
+use nautilus_model::{identifiers::InstrumentId, types::Currency};
+
+use super::limits::{RiskRule, RiskViolation};
+
+/// A group of correlated instruments (e.g. all BTC-linked spot, perpetual and futures products)
+/// whose net exposure per strategy is aggregated against a single limit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExposureGroup {
+    /// The name of the group.
+    pub name: String,
+    /// The instruments of the group, with the weights scaling their notional exposure to the
+    /// common underlying (e.g. 1.0 for delta-one products).
+    pub instruments: HashMap<InstrumentId, f64>,
+    /// The currency exposure is measured in, notional values in other currencies are excluded.
+    pub currency: Currency,
+    /// The maximum absolute net exposure of a strategy across the group.
+    pub max_net_exposure: f64,
+}
+
+impl ExposureGroup {
+    /// Returns the weight of the instrument in the group, if a member.
+    #[must_use]
+    pub fn weight(&self, instrument_id: &InstrumentId) -> Option<f64> {
+        self.instruments.get(instrument_id).copied()
+    }
+
+    /// Checks an order adding the signed `order_exposure` to the signed `net_exposure` of a
+    /// strategy across the group. Orders reducing the absolute net exposure always pass.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RiskViolation`] if the projected net exposure exceeds the group limit.
+    pub fn check(
+        &self,
+        strategy_id: &str,
+        net_exposure: f64,
+        order_exposure: f64,
+    ) -> Result<(), RiskViolation> {
+        let projected = net_exposure + order_exposure;
+        if projected.abs() > self.max_net_exposure && projected.abs() > net_exposure.abs() {
+            return Err(RiskViolation {
+                rule: RiskRule::MaxGroupExposure,
+                scope: self.name.clone(),
+                message: format!(
+                    "strategy_id={strategy_id}, max_net_exposure={:.2} {}, projected_exposure={projected:.2} {}",
+                    self.max_net_exposure, self.currency, self.currency,
+                ),
+            });
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn group() -> ExposureGroup {
+        ExposureGroup {
+            name: "BTC".to_string(),
+            instruments: HashMap::from([
+                (InstrumentId::from("BTCUSDT.BINANCE"), 1.0),
+                (InstrumentId::from("BTCUSDT-PERP.BINANCE"), 1.0),
+            ]),
+            currency: Currency::USDT(),
+            max_net_exposure: 100_000.0,
+        }
+    }
+
+    #[rstest]
+    #[case(60_000.0, 30_000.0, true)]
+    #[case(60_000.0, 50_000.0, false)]
+    #[case(-60_000.0, -50_000.0, false)]
+    #[case(150_000.0, -20_000.0, true)] // Reducing
+    #[case(150_000.0, -280_000.0, true)] // Flipping to a smaller exposure
+    fn test_check(
+        #[case] net_exposure: f64,
+        #[case] order_exposure: f64,
+        #[case] expected_ok: bool,
+    ) {
+        assert_eq!(
+            group().check("S-001", net_exposure, order_exposure).is_ok(),
+            expected_ok
+        );
+    }
+
+    #[rstest]
+    fn test_violation_reason() {
+        let violation = group().check("S-001", 60_000.0, 50_000.0).unwrap_err();
+
+        assert_eq!(
+            violation.to_string(),
+            "MAX_GROUP_EXPOSURE: BTC strategy_id=S-001, max_net_exposure=100000.00 USDT, projected_exposure=110000.00 USDT"
+        );
+    }
+
+    #[rstest]
+    fn test_weight() {
+        let group = group();
+
+        assert_eq!(
+            group.weight(&InstrumentId::from("BTCUSDT.BINANCE")),
+            Some(1.0)
+        );
+        assert_eq!(group.weight(&InstrumentId::from("ETHUSDT.BINANCE")), None);
+    }
+}
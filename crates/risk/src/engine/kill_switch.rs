@@ -0,0 +1,223 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Kill switch transitioning the trading state globally or per strategy.
+
+use std::{collections::HashMap, fmt::Display};
+
+use bytes::Bytes;
+use nautilus_core::{UnixNanos, UUID4};
+use nautilus_model::{
+    enums::{OrderSide, PositionSide, TimeInForce, TradingState},
+    identifiers::{ClientOrderId, StrategyId, TraderId},
+    orders::{MarketOrder, OrderAny},
+    position::Position,
+};
+use serde::{Deserialize, Serialize};
+
+use super::rules::ReloadRiskRules;
+
+/// The cache key the trading states are persisted under.
+pub const TRADING_STATES_KEY: &str = "RiskEngine.trading_states";
+
+/// Represents a command to set the trading state of a strategy, or globally.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SetTradingState {
+    pub trader_id: TraderId,
+    /// The strategy to set the state of, or `None` to set the global state.
+    pub strategy_id: Option<StrategyId>,
+    pub state: TradingState,
+    pub command_id: UUID4,
+    pub ts_init: UnixNanos,
+}
+
+/// A command handled by the risk engine.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RiskCommand {
+    SetTradingState(SetTradingState),
+    ReloadRules(ReloadRiskRules),
+}
+
+/// Represents an event where the trading state of a strategy, or the global state, changed.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TradingStateChanged {
+    pub trader_id: TraderId,
+    /// The strategy the state changed for, or `None` for the global state.
+    pub strategy_id: Option<StrategyId>,
+    pub state: TradingState,
+    pub event_id: UUID4,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl Display for TradingStateChanged {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(strategy_id={}, state={})",
+            stringify!(TradingStateChanged),
+            self.strategy_id
+                .map_or_else(|| "GLOBAL".to_string(), |id| id.to_string()),
+            self.state,
+        )
+    }
+}
+
+/// The trading states persisted across restarts.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TradingStates {
+    pub global: TradingState,
+    pub strategies: HashMap<StrategyId, TradingState>,
+}
+
+impl TradingStates {
+    /// Serializes the trading states for persisting in the cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_bytes(&self) -> anyhow::Result<Bytes> {
+        Ok(Bytes::from(serde_json::to_vec(self)?))
+    }
+
+    /// Deserializes the trading states persisted in the cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if deserialization fails.
+    pub fn from_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+}
+
+/// Returns the most restrictive of the trading states `a` and `b`.
+#[must_use]
+pub const fn most_restrictive(a: TradingState, b: TradingState) -> TradingState {
+    match (a, b) {
+        (TradingState::Halted, _) | (_, TradingState::Halted) => TradingState::Halted,
+        (TradingState::Reducing, _) | (_, TradingState::Reducing) => TradingState::Reducing,
+        _ => TradingState::Active,
+    }
+}
+
+/// Returns a reduce-only market order flattening the open `position`, if any.
+#[must_use]
+pub fn flatten_order(
+    position: &Position,
+    trader_id: TraderId,
+    ts_init: UnixNanos,
+) -> Option<OrderAny> {
+    let order_side = match position.side {
+        PositionSide::Long => OrderSide::Sell,
+        PositionSide::Short => OrderSide::Buy,
+        PositionSide::Flat | PositionSide::NoPositionSide => return None,
+    };
+
+    Some(OrderAny::Market(MarketOrder::new(
+        trader_id,
+        position.strategy_id,
+        position.instrument_id,
+        ClientOrderId::new(format!("O-FLATTEN-{}", UUID4::new())),
+        order_side,
+        position.quantity,
+        TimeInForce::Gtc,
+        UUID4::new(),
+        ts_init,
+        true,
+        false,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::OrderType,
+        events::OrderEventAny,
+        instruments::{stubs::audusd_sim, CurrencyPair, InstrumentAny},
+        orders::{stubs::TestOrderEventStubs, OrderTestBuilder},
+        types::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(TradingState::Active, TradingState::Active, TradingState::Active)]
+    #[case(TradingState::Active, TradingState::Reducing, TradingState::Reducing)]
+    #[case(TradingState::Halted, TradingState::Reducing, TradingState::Halted)]
+    #[case(TradingState::Reducing, TradingState::Halted, TradingState::Halted)]
+    fn test_most_restrictive(
+        #[case] a: TradingState,
+        #[case] b: TradingState,
+        #[case] expected: TradingState,
+    ) {
+        assert_eq!(most_restrictive(a, b), expected);
+    }
+
+    #[rstest]
+    fn test_trading_states_round_trip() {
+        let states = TradingStates {
+            global: TradingState::Reducing,
+            strategies: HashMap::from([(StrategyId::from("S-001"), TradingState::Halted)]),
+        };
+
+        let bytes = states.to_bytes().unwrap();
+
+        assert_eq!(TradingStates::from_bytes(&bytes).unwrap(), states);
+    }
+
+    #[rstest]
+    fn test_flatten_order_closes_position(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(100_000))
+            .build();
+        let OrderEventAny::Filled(fill) = TestOrderEventStubs::order_filled(
+            &order,
+            &instrument,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ) else {
+            unreachable!()
+        };
+        let position = Position::new(&instrument, fill);
+
+        let flatten = flatten_order(&position, TraderId::default(), 0.into()).unwrap();
+
+        assert_eq!(flatten.order_side(), OrderSide::Sell);
+        assert_eq!(flatten.quantity(), Quantity::from(100_000));
+        assert_eq!(flatten.strategy_id(), position.strategy_id);
+        assert!(flatten.is_reduce_only());
+    }
+}
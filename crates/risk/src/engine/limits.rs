@@ -0,0 +1,240 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Configurable pre-trade limits on the orders of an instrument or strategy.
+
+use std::fmt::Display;
+
+use nautilus_model::{
+    enums::OrderSide,
+    orders::OrderAny,
+    types::{Money, Price, Quantity},
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use strum::{Display as StrumDisplay, EnumString};
+
+/// The pre-trade limits applied to the orders of an instrument or strategy, where `None`
+/// disables a check.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PreTradeLimits {
+    /// The maximum notional value of an order (in the instrument quote currency).
+    #[serde(default)]
+    pub max_notional: Option<Decimal>,
+    /// The maximum deviation of a limit price from the reference price, as a fraction of the
+    /// reference price (e.g. 0.05 collars prices within 5%).
+    #[serde(default)]
+    pub max_price_deviation: Option<Decimal>,
+    /// The maximum absolute net position an order may result in.
+    #[serde(default)]
+    pub max_position: Option<Quantity>,
+}
+
+/// The pre-trade rule violated by a denied order, carried as the prefix of its denial reason.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, StrumDisplay, EnumString)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum RiskRule {
+    /// The order notional exceeds the maximum.
+    MaxNotional,
+    /// The order price deviates from the reference price by more than the collar.
+    PriceCollar,
+    /// The order would result in a net position exceeding the maximum.
+    MaxPosition,
+    /// The order would result in a net exposure across an instrument group exceeding the maximum.
+    MaxGroupExposure,
+}
+
+/// Represents the violation of a pre-trade rule by an order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RiskViolation {
+    pub rule: RiskRule,
+    /// The scope of the violated limit (e.g. the instrument or strategy).
+    pub scope: String,
+    pub message: String,
+}
+
+impl Display for RiskViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} {}", self.rule, self.scope, self.message)
+    }
+}
+
+impl PreTradeLimits {
+    /// Checks the `order` against the limits of the given `scope`.
+    ///
+    /// The `notional` and `reference_px` are skipped when not known, and `net_position` is the
+    /// signed net position quantity the order adds to. Orders reducing the net position always
+    /// pass the position check.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`RiskViolation`] of the limits.
+    pub fn check(
+        &self,
+        scope: &str,
+        order: &OrderAny,
+        notional: Option<Money>,
+        reference_px: Option<Price>,
+        net_position: f64,
+    ) -> Result<(), RiskViolation> {
+        let violation = |rule, message| RiskViolation {
+            rule,
+            scope: scope.to_string(),
+            message,
+        };
+
+        if let (Some(max_notional), Some(notional)) = (self.max_notional, notional) {
+            if notional.as_decimal() > max_notional {
+                return Err(violation(
+                    RiskRule::MaxNotional,
+                    format!("max_notional={max_notional}, notional={notional}"),
+                ));
+            }
+        }
+
+        // Conditional orders are not collared, as their prices are relative to the trigger
+        if let (Some(max_deviation), Some(price), Some(reference_px), None) = (
+            self.max_price_deviation,
+            order.price(),
+            reference_px,
+            order.trigger_price(),
+        ) {
+            let reference = reference_px.as_decimal();
+            if reference > Decimal::ZERO {
+                let deviation = (price.as_decimal() - reference).abs() / reference;
+                if deviation > max_deviation {
+                    return Err(violation(
+                        RiskRule::PriceCollar,
+                        format!(
+                            "max_deviation={max_deviation}, price={price}, reference_px={reference_px}"
+                        ),
+                    ));
+                }
+            }
+        }
+
+        if let Some(max_position) = self.max_position {
+            let order_qty = order.quantity().as_f64();
+            let projected = match order.order_side() {
+                OrderSide::Buy => net_position + order_qty,
+                OrderSide::Sell => net_position - order_qty,
+                OrderSide::NoOrderSide => net_position,
+            };
+            if projected.abs() > max_position.as_f64() && projected.abs() > net_position.abs() {
+                return Err(violation(
+                    RiskRule::MaxPosition,
+                    format!("max_position={max_position}, projected_position={projected}"),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_model::{
+        enums::OrderType, identifiers::InstrumentId, orders::OrderTestBuilder, types::Currency,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn limit(side: OrderSide, price: &str, quantity: u64) -> OrderAny {
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .side(side)
+            .price(Price::from(price))
+            .quantity(Quantity::from(quantity))
+            .build()
+    }
+
+    #[rstest]
+    fn test_max_notional() {
+        let limits = PreTradeLimits {
+            max_notional: Some(Decimal::from(100_000)),
+            ..Default::default()
+        };
+        let order = limit(OrderSide::Buy, "1.00000", 200_000);
+
+        let violation = limits
+            .check(
+                "AUD/USD.SIM",
+                &order,
+                Some(Money::new(200_000.0, Currency::USD())),
+                None,
+                0.0,
+            )
+            .unwrap_err();
+
+        assert_eq!(violation.rule, RiskRule::MaxNotional);
+        assert!(violation
+            .to_string()
+            .starts_with("MAX_NOTIONAL: AUD/USD.SIM"));
+        assert!(limits.check("AUD/USD.SIM", &order, None, None, 0.0).is_ok());
+    }
+
+    #[rstest]
+    #[case("1.04000", true)]
+    #[case("0.96000", true)]
+    #[case("1.06000", false)]
+    #[case("0.94000", false)]
+    fn test_price_collar(#[case] price: &str, #[case] expected_ok: bool) {
+        let limits = PreTradeLimits {
+            max_price_deviation: Some(Decimal::from_str("0.05").unwrap()),
+            ..Default::default()
+        };
+        let order = limit(OrderSide::Buy, price, 100_000);
+
+        let result = limits.check("S-001", &order, None, Some(Price::from("1.00000")), 0.0);
+
+        assert_eq!(result.is_ok(), expected_ok);
+        if let Err(violation) = result {
+            assert_eq!(violation.rule, RiskRule::PriceCollar);
+        }
+    }
+
+    #[rstest]
+    fn test_max_position_allows_reducing_orders() {
+        let limits = PreTradeLimits {
+            max_position: Some(Quantity::from(100_000)),
+            ..Default::default()
+        };
+        let buy = limit(OrderSide::Buy, "1.00000", 50_000);
+        let sell = limit(OrderSide::Sell, "1.00000", 50_000);
+
+        let violation = limits
+            .check("S-001", &buy, None, None, 80_000.0)
+            .unwrap_err();
+        assert_eq!(violation.rule, RiskRule::MaxPosition);
+        assert!(limits.check("S-001", &buy, None, None, 50_000.0).is_ok());
+        assert!(limits.check("S-001", &sell, None, None, 150_000.0).is_ok());
+        assert!(limits.check("S-001", &sell, None, None, -80_000.0).is_err());
+    }
+
+    #[rstest]
+    fn test_rule_parsed_from_reason() {
+        assert_eq!(
+            RiskRule::from_str("PRICE_COLLAR").unwrap(),
+            RiskRule::PriceCollar
+        );
+    }
+}
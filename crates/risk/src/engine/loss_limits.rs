@@ -0,0 +1,237 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Daily-loss and max-drawdown limits monitored per strategy and account.
+
+use std::fmt::Display;
+
+use nautilus_core::{datetime::NANOSECONDS_IN_SECOND, UnixNanos, UUID4};
+use nautilus_model::{
+    identifiers::{AccountId, StrategyId, TraderId},
+    types::Currency,
+};
+use serde::{Deserialize, Serialize};
+use strum::Display as StrumDisplay;
+
+const NANOSECONDS_IN_DAY: u64 = 86_400 * NANOSECONDS_IN_SECOND;
+
+/// The action taken when a loss limit is breached.
+#[derive(
+    Copy, Clone, Debug, Default, PartialEq, Eq, Hash, StrumDisplay, Serialize, Deserialize,
+)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum LossLimitAction {
+    /// Only publish the breach.
+    #[default]
+    Notify,
+    /// Halt trading, blocking new orders.
+    BlockNewOrders,
+    /// Halt trading, canceling open orders and flattening open positions.
+    Flatten,
+}
+
+/// The kind of loss limit breached.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, StrumDisplay, Serialize, Deserialize)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum LossLimitKind {
+    /// The loss since the start of the (UTC) day.
+    DailyLoss,
+    /// The loss from the peak value.
+    Drawdown,
+}
+
+/// Configuration for the loss limits of a strategy or account, where `None` disables a limit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LossLimits {
+    /// The currency the PnL is measured in, positions settling in other currencies are excluded.
+    pub currency: Currency,
+    /// The maximum loss since the start of the (UTC) day.
+    pub max_daily_loss: Option<f64>,
+    /// The maximum loss from the peak value.
+    pub max_drawdown: Option<f64>,
+    /// The action taken when a limit is breached.
+    pub action: LossLimitAction,
+}
+
+/// The scope a loss limit is monitored for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LossLimitScope {
+    /// The realized and unrealized PnL of a strategy.
+    Strategy(StrategyId),
+    /// The equity (total balance and unrealized PnL) of an account.
+    Account(AccountId),
+}
+
+impl Display for LossLimitScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Strategy(strategy_id) => write!(f, "{strategy_id}"),
+            Self::Account(account_id) => write!(f, "{account_id}"),
+        }
+    }
+}
+
+/// Represents an event where a loss limit was breached.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LossLimitBreached {
+    pub trader_id: TraderId,
+    pub scope: LossLimitScope,
+    pub kind: LossLimitKind,
+    pub loss: f64,
+    pub limit: f64,
+    pub currency: Currency,
+    pub action: LossLimitAction,
+    pub event_id: UUID4,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl Display for LossLimitBreached {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(scope={}, kind={}, loss={:.2} {}, limit={:.2} {}, action={})",
+            stringify!(LossLimitBreached),
+            self.scope,
+            self.kind,
+            self.loss,
+            self.currency,
+            self.limit,
+            self.currency,
+            self.action,
+        )
+    }
+}
+
+/// Tracks the value monitored for a scope: its value at the start of the day, its peak, and
+/// which limits were breached (so each breach triggers once).
+#[derive(Clone, Debug, PartialEq)]
+pub struct LossTracker {
+    day: u64,
+    day_start: f64,
+    peak: f64,
+    daily_loss_breached: bool,
+    drawdown_breached: bool,
+}
+
+impl LossTracker {
+    /// Creates a new [`LossTracker`] instance starting from `value`.
+    #[must_use]
+    pub fn new(value: f64, ts_now: UnixNanos) -> Self {
+        Self {
+            day: ts_now.as_u64() / NANOSECONDS_IN_DAY,
+            day_start: value,
+            peak: value,
+            daily_loss_breached: false,
+            drawdown_breached: false,
+        }
+    }
+
+    /// Updates the tracker with the current `value`, returning the newly breached limits with
+    /// the loss and limit breached.
+    ///
+    /// The daily loss limit rearms at the start of each (UTC) day, while the drawdown limit
+    /// stays breached until the tracker is recreated.
+    pub fn update(
+        &mut self,
+        limits: &LossLimits,
+        value: f64,
+        ts_now: UnixNanos,
+    ) -> Vec<(LossLimitKind, f64, f64)> {
+        let day = ts_now.as_u64() / NANOSECONDS_IN_DAY;
+        if day > self.day {
+            self.day = day;
+            self.day_start = value;
+            self.daily_loss_breached = false;
+        }
+        self.peak = self.peak.max(value);
+
+        let mut breaches = Vec::new();
+        if let Some(max_daily_loss) = limits.max_daily_loss {
+            let loss = self.day_start - value;
+            if loss > max_daily_loss && !self.daily_loss_breached {
+                self.daily_loss_breached = true;
+                breaches.push((LossLimitKind::DailyLoss, loss, max_daily_loss));
+            }
+        }
+        if let Some(max_drawdown) = limits.max_drawdown {
+            let drawdown = self.peak - value;
+            if drawdown > max_drawdown && !self.drawdown_breached {
+                self.drawdown_breached = true;
+                breaches.push((LossLimitKind::Drawdown, drawdown, max_drawdown));
+            }
+        }
+        breaches
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn limits() -> LossLimits {
+        LossLimits {
+            currency: Currency::USD(),
+            max_daily_loss: Some(1_000.0),
+            max_drawdown: Some(1_500.0),
+            action: LossLimitAction::Notify,
+        }
+    }
+
+    #[rstest]
+    fn test_daily_loss_breached_once_and_rearmed_next_day() {
+        let limits = limits();
+        let mut tracker = LossTracker::new(0.0, 0.into());
+
+        assert!(tracker.update(&limits, -500.0, 1.into()).is_empty());
+        assert_eq!(
+            tracker.update(&limits, -1_200.0, 2.into()),
+            vec![(LossLimitKind::DailyLoss, 1_200.0, 1_000.0)]
+        );
+        assert!(tracker.update(&limits, -1_300.0, 3.into()).is_empty());
+
+        // The next day starts from the value at rollover
+        let next_day = UnixNanos::from(NANOSECONDS_IN_DAY);
+        assert!(tracker.update(&limits, -1_300.0, next_day).is_empty());
+        assert_eq!(
+            tracker.update(&limits, -2_400.0, next_day),
+            vec![
+                (LossLimitKind::DailyLoss, 1_100.0, 1_000.0),
+                (LossLimitKind::Drawdown, 2_400.0, 1_500.0),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_drawdown_measured_from_peak() {
+        let limits = LossLimits {
+            max_daily_loss: None,
+            ..limits()
+        };
+        let mut tracker = LossTracker::new(0.0, 0.into());
+
+        assert!(tracker.update(&limits, 2_000.0, 1.into()).is_empty());
+        assert!(tracker.update(&limits, 600.0, 2.into()).is_empty());
+        assert_eq!(
+            tracker.update(&limits, 400.0, 3.into()),
+            vec![(LossLimitKind::Drawdown, 1_600.0, 1_500.0)]
+        );
+    }
+}
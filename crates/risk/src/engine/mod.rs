@@ -17,30 +17,58 @@
 
 use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
+use anomaly::{AnomalyDetector, AnomalyKind, RunawayStrategyDetected};
+use audit::{query_audit_records, RiskAuditFilter, RiskAuditRecord, RiskDecision};
+use balance_locks::{estimate_order_cost, BalanceLocks};
 use config::RiskEngineConfig;
+use exposure::ExposureGroup;
+use kill_switch::{
+    flatten_order, most_restrictive, RiskCommand, SetTradingState, TradingStateChanged,
+    TradingStates, TRADING_STATES_KEY,
+};
+use limits::PreTradeLimits;
+use loss_limits::{LossLimitAction, LossLimitBreached, LossLimitScope, LossLimits, LossTracker};
 use nautilus_common::{
     cache::Cache,
     clock::Clock,
     logging::{CMD, EVT, RECV},
     msgbus::MessageBus,
     throttler::Throttler,
+    timer::{TimeEvent, TimeEventCallback},
+};
+use nautilus_core::{datetime::NANOSECONDS_IN_MILLISECOND, UUID4};
+use nautilus_execution::messages::{
+    CancelAllOrders, ModifyOrder, SubmitOrder, SubmitOrderList, TradingCommand,
 };
-use nautilus_core::UUID4;
-use nautilus_execution::messages::{ModifyOrder, SubmitOrder, SubmitOrderList, TradingCommand};
 use nautilus_model::{
-    accounts::{Account, AccountAny},
-    enums::{InstrumentClass, OrderSide, OrderStatus, TradingState},
+    accounts::{Account, AccountAny, CashAccount, MarginAccount},
+    data::GreeksData,
+    enums::{InstrumentClass, OrderSide, OrderStatus, PositionSide, PriceType, TradingState},
     events::{OrderDenied, OrderEventAny, OrderModifyRejected},
-    identifiers::InstrumentId,
+    identifiers::{AccountId, ClientId, ClientOrderId, InstrumentId, StrategyId, VenueOrderId},
     instruments::InstrumentAny,
     orders::{OrderAny, OrderList},
     types::{Currency, Money, Price, Quantity},
 };
 use nautilus_portfolio::Portfolio;
+use rules::{ReloadRiskRules, RiskRuleSet, RiskRulesReloaded};
 use rust_decimal::{prelude::ToPrimitive, Decimal};
+use stress::{StressScenario, StressTestReport, StressTester};
 use ustr::Ustr;
 
+pub mod anomaly;
+pub mod audit;
+pub mod balance_locks;
 pub mod config;
+pub mod exposure;
+pub mod kill_switch;
+pub mod limits;
+pub mod loss_limits;
+pub mod rules;
+pub mod stress;
+
+/// The name of the timer running the configured stress scenarios.
+const STRESS_TEST_TIMER: &str = "RiskEngine.run_stress_tests";
 
 type SubmitOrderFn = Box<dyn Fn(SubmitOrder)>;
 type ModifyOrderFn = Box<dyn Fn(ModifyOrder)>;
@@ -53,7 +81,18 @@ pub struct RiskEngine {
     pub throttled_submit_order: Throttler<SubmitOrder, SubmitOrderFn>,
     pub throttled_modify_order: Throttler<ModifyOrder, ModifyOrderFn>,
     max_notional_per_order: HashMap<InstrumentId, Decimal>,
+    trader_limits: Option<PreTradeLimits>,
+    instrument_limits: HashMap<InstrumentId, PreTradeLimits>,
+    strategy_limits: HashMap<StrategyId, PreTradeLimits>,
+    exposure_groups: Vec<ExposureGroup>,
+    rules_version: u64,
     trading_state: TradingState,
+    strategy_trading_states: HashMap<StrategyId, TradingState>,
+    loss_limits: HashMap<LossLimitScope, LossLimits>,
+    loss_trackers: HashMap<LossLimitScope, LossTracker>,
+    anomaly_detector: Option<AnomalyDetector>,
+    balance_locks: RefCell<BalanceLocks>,
+    stress_tester: Rc<RefCell<StressTester>>,
     config: RiskEngineConfig,
 }
 
@@ -79,7 +118,21 @@ impl RiskEngine {
             msgbus.clone(),
         );
 
-        Self {
+        let loss_limits = config
+            .strategy_loss_limits
+            .iter()
+            .map(|(strategy_id, limits)| (LossLimitScope::Strategy(*strategy_id), limits.clone()))
+            .chain(
+                config
+                    .account_loss_limits
+                    .iter()
+                    .map(|(account_id, limits)| {
+                        (LossLimitScope::Account(*account_id), limits.clone())
+                    }),
+            )
+            .collect();
+
+        let mut engine = Self {
             clock,
             cache,
             msgbus,
@@ -87,9 +140,24 @@ impl RiskEngine {
             throttled_submit_order,
             throttled_modify_order,
             max_notional_per_order: HashMap::new(),
+            trader_limits: config.trader_limits.clone(),
+            instrument_limits: config.instrument_limits.clone(),
+            strategy_limits: config.strategy_limits.clone(),
+            exposure_groups: config.exposure_groups.clone(),
+            rules_version: 0,
             trading_state: TradingState::Active,
+            strategy_trading_states: HashMap::new(),
+            loss_limits,
+            loss_trackers: HashMap::new(),
+            anomaly_detector: config.anomaly_detection.clone().map(AnomalyDetector::new),
+            balance_locks: RefCell::new(BalanceLocks::default()),
+            stress_tester: Rc::new(RefCell::new(StressTester::new(
+                config.margin_models.clone(),
+            ))),
             config,
-        }
+        };
+        engine.load_trading_states();
+        engine
     }
 
     fn create_submit_order_throttler(
@@ -98,9 +166,21 @@ impl RiskEngine {
         cache: Rc<RefCell<Cache>>,
         msgbus: Rc<RefCell<MessageBus>>,
     ) -> Throttler<SubmitOrder, SubmitOrderFn> {
+        let audit_trail = config.audit_trail;
         let success_handler = {
             let msgbus = msgbus.clone();
+            let cache = cache.clone();
+            let clock = clock.clone();
             Box::new(move |submit_order: SubmitOrder| {
+                if audit_trail {
+                    Self::audit_command(
+                        &cache,
+                        &clock,
+                        &submit_order.order,
+                        RiskDecision::Approved,
+                        None,
+                    );
+                }
                 msgbus.borrow_mut().send(
                     &Ustr::from("ExecEngine.execute"),
                     &TradingCommand::SubmitOrder(submit_order),
@@ -121,6 +201,15 @@ impl RiskEngine {
                 );
 
                 Self::handle_submit_order_cache(&cache, &submit_order);
+                if audit_trail {
+                    Self::audit_command(
+                        &cache,
+                        &clock,
+                        &submit_order.order,
+                        RiskDecision::Throttled,
+                        Some(reason),
+                    );
+                }
 
                 let denied = Self::create_order_denied(&submit_order, reason, &clock);
 
@@ -145,9 +234,23 @@ impl RiskEngine {
         cache: Rc<RefCell<Cache>>,
         msgbus: Rc<RefCell<MessageBus>>,
     ) -> Throttler<ModifyOrder, ModifyOrderFn> {
+        let audit_trail = config.audit_trail;
         let success_handler = {
             let msgbus = msgbus.clone();
+            let cache = cache.clone();
+            let clock = clock.clone();
             Box::new(move |order: ModifyOrder| {
+                if audit_trail {
+                    if let Some(existing) = Self::get_existing_order(&cache, &order) {
+                        Self::audit_command(
+                            &cache,
+                            &clock,
+                            &existing,
+                            RiskDecision::Approved,
+                            None,
+                        );
+                    }
+                }
                 msgbus.borrow_mut().send(
                     &Ustr::from("ExecEngine.execute"),
                     &TradingCommand::ModifyOrder(order),
@@ -172,6 +275,16 @@ impl RiskEngine {
                     None => return,
                 };
 
+                if audit_trail {
+                    Self::audit_command(
+                        &cache,
+                        &clock,
+                        &order,
+                        RiskDecision::Throttled,
+                        Some(reason),
+                    );
+                }
+
                 let rejected = Self::create_modify_rejected(&order, reason, &clock);
 
                 msgbus
@@ -253,43 +366,661 @@ impl RiskEngine {
         ))
     }
 
+    fn audit_command(
+        cache: &Rc<RefCell<Cache>>,
+        clock: &Rc<RefCell<dyn Clock>>,
+        order: &OrderAny,
+        decision: RiskDecision,
+        reason: Option<&str>,
+    ) {
+        let record = RiskAuditRecord::new(
+            order.trader_id(),
+            Some(order.strategy_id()),
+            Some(order.instrument_id()),
+            Some(order.client_order_id()),
+            decision,
+            reason,
+            clock.borrow().timestamp_ns(),
+        );
+        Self::persist_audit_record(cache, &record);
+    }
+
+    fn persist_audit_record(cache: &Rc<RefCell<Cache>>, record: &RiskAuditRecord) {
+        if let Err(e) = record.persist(&mut cache.borrow_mut()) {
+            log::error!("Cannot persist risk audit record {record}: {e}");
+        }
+    }
+
     // -- COMMANDS --------------------------------------------------------------------------------
 
     pub fn execute(&mut self, command: TradingCommand) {
+        // Block new orders as soon as a loss limit is breached
+        if matches!(
+            command,
+            TradingCommand::SubmitOrder(_) | TradingCommand::SubmitOrderList(_)
+        ) {
+            self.evaluate_loss_limits();
+        }
+
+        if let TradingCommand::ModifyOrder(modify_order) = &command {
+            self.detect_modify_anomaly(modify_order);
+        }
+
         // This will extend to other commands such as `RiskCommand`
         self.handle_command(command);
     }
 
     pub fn process(&mut self, event: OrderEventAny) {
+        let is_fill = matches!(
+            event,
+            OrderEventAny::PartiallyFilled(_) | OrderEventAny::Filled(_)
+        );
+
         // This will extend to other events such as `RiskEvent`
         self.handle_event(event);
+
+        if is_fill {
+            self.evaluate_loss_limits();
+        }
+    }
+
+    pub fn execute_risk(&mut self, command: RiskCommand) {
+        if self.config.debug {
+            log::debug!("{}{} {:?}", CMD, RECV, command);
+        }
+
+        match command {
+            RiskCommand::SetTradingState(command) => self.handle_set_trading_state(command),
+            RiskCommand::ReloadRules(command) => self.handle_reload_rules(command),
+        }
     }
 
     pub fn set_trading_state(&mut self, state: TradingState) {
-        if state == self.trading_state {
-            log::warn!("No change to trading state: already set to {state:?}");
+        self.update_trading_state(None, state);
+    }
+
+    pub fn set_strategy_trading_state(&mut self, strategy_id: StrategyId, state: TradingState) {
+        self.update_trading_state(Some(strategy_id), state);
+    }
+
+    /// Returns the trading state in effect for the `strategy_id`, being the most restrictive of
+    /// its own and the global trading state.
+    #[must_use]
+    pub fn trading_state_for(&self, strategy_id: &StrategyId) -> TradingState {
+        self.strategy_trading_states
+            .get(strategy_id)
+            .map_or(self.trading_state, |state| {
+                most_restrictive(*state, self.trading_state)
+            })
+    }
+
+    pub fn set_max_notional_per_order(&mut self, instrument_id: InstrumentId, new_value: Decimal) {
+        self.max_notional_per_order.insert(instrument_id, new_value);
+
+        let new_value_str = new_value.to_string();
+        log::info!("Set MAX_NOTIONAL_PER_ORDER: {instrument_id} {new_value_str}");
+    }
+
+    /// Returns the active risk rule set.
+    #[must_use]
+    pub fn active_rules(&self) -> RiskRuleSet {
+        RiskRuleSet {
+            version: self.rules_version,
+            trader_limits: self.trader_limits.clone(),
+            strategy_limits: self.strategy_limits.clone(),
+            instrument_limits: self.instrument_limits.clone(),
+            max_notional_per_order: self.max_notional_per_order.clone(),
+        }
+    }
+
+    /// Returns the persisted risk decisions matching the `filter`, in time order.
+    #[must_use]
+    pub fn audit_records(&self, filter: &RiskAuditFilter) -> Vec<RiskAuditRecord> {
+        query_audit_records(&self.cache.borrow(), filter)
+    }
+
+    pub fn set_trader_limits(&mut self, limits: PreTradeLimits) {
+        log::info!("Set pre-trade limits: trader {limits:?}");
+        self.trader_limits = Some(limits);
+    }
+
+    pub fn set_instrument_limits(&mut self, instrument_id: InstrumentId, limits: PreTradeLimits) {
+        log::info!("Set pre-trade limits: {instrument_id} {limits:?}");
+        self.instrument_limits.insert(instrument_id, limits);
+    }
+
+    pub fn set_strategy_limits(&mut self, strategy_id: StrategyId, limits: PreTradeLimits) {
+        log::info!("Set pre-trade limits: {strategy_id} {limits:?}");
+        self.strategy_limits.insert(strategy_id, limits);
+    }
+
+    pub fn set_exposure_group(&mut self, group: ExposureGroup) {
+        log::info!("Set exposure group: {} {group:?}", group.name);
+        self.exposure_groups
+            .retain(|existing| existing.name != group.name);
+        self.exposure_groups.push(group);
+    }
+
+    pub fn set_loss_limits(&mut self, scope: LossLimitScope, limits: LossLimits) {
+        log::info!("Set loss limits: {scope} {limits:?}");
+        self.loss_trackers.remove(&scope);
+        self.loss_limits.insert(scope, limits);
+    }
+
+    /// Evaluates the loss limits of each strategy and account against their current PnL,
+    /// taking the configured action for any newly breached limit.
+    ///
+    /// Evaluated on each fill and order submission, and may also be called periodically to
+    /// monitor unrealized PnL between fills.
+    pub fn evaluate_loss_limits(&mut self) {
+        if self.loss_limits.is_empty() {
             return;
         }
 
-        self.trading_state = state;
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let mut breaches = Vec::new();
+        for (scope, limits) in &self.loss_limits {
+            let value = match scope {
+                LossLimitScope::Strategy(strategy_id) => {
+                    Some(self.strategy_pnl(strategy_id, limits.currency))
+                }
+                LossLimitScope::Account(account_id) => {
+                    self.account_equity(account_id, limits.currency)
+                }
+            };
+            let Some(value) = value else {
+                continue;
+            };
+
+            let tracker = self
+                .loss_trackers
+                .entry(*scope)
+                .or_insert_with(|| LossTracker::new(value, ts_now));
+            for (kind, loss, limit) in tracker.update(limits, value, ts_now) {
+                breaches.push(LossLimitBreached {
+                    trader_id: self.msgbus.borrow().trader_id,
+                    scope: *scope,
+                    kind,
+                    loss,
+                    limit,
+                    currency: limits.currency,
+                    action: limits.action,
+                    event_id: UUID4::new(),
+                    ts_event: ts_now,
+                    ts_init: ts_now,
+                });
+            }
+        }
+
+        for breach in breaches {
+            self.handle_loss_limit_breach(&breach);
+        }
+    }
+
+    // -- STRESS TESTING --------------------------------------------------------------------------
+
+    /// Updates the latest greeks used to revalue option positions in stress tests.
+    pub fn update_greeks(&self, greeks: GreeksData) {
+        self.stress_tester.borrow_mut().update_greeks(greeks);
+    }
+
+    /// Returns the hypothetical PnL and margin impact of the `scenario` on the open positions.
+    #[must_use]
+    pub fn stress_test(&self, scenario: &StressScenario) -> StressTestReport {
+        let ts_now = self.clock.borrow().timestamp_ns();
+        self.stress_tester.borrow().run(
+            &self.cache.borrow(),
+            scenario,
+            self.msgbus.borrow().trader_id,
+            ts_now,
+        )
+    }
+
+    /// Starts the timer running the configured stress scenarios every `stress_test_interval_ms`,
+    /// publishing each report on the risk events topic.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the timer cannot be set.
+    pub fn start_stress_testing(&self) -> anyhow::Result<()> {
+        let interval_ms = self.config.stress_test_interval_ms;
+        if interval_ms == 0 || self.config.stress_scenarios.is_empty() {
+            return Ok(());
+        }
+
+        let stress_tester = self.stress_tester.clone();
+        let scenarios = self.config.stress_scenarios.clone();
+        let cache = self.cache.clone();
+        let msgbus = self.msgbus.clone();
+        let callback = TimeEventCallback::Rust(Rc::new(move |event: TimeEvent| {
+            let stress_tester = stress_tester.borrow();
+            let cache = cache.borrow();
+            let msgbus = msgbus.borrow();
+            for scenario in &scenarios {
+                let report = stress_tester.run(&cache, scenario, msgbus.trader_id, event.ts_event);
+                log::info!("{report}");
+                msgbus.publish(&Ustr::from("events.risk"), &report);
+            }
+        }));
+
+        let ts_now = self.clock.borrow().timestamp_ns();
+        self.clock.borrow_mut().set_timer_ns(
+            STRESS_TEST_TIMER,
+            u64::from(interval_ms) * NANOSECONDS_IN_MILLISECOND,
+            ts_now,
+            None,
+            Some(callback),
+        )
+    }
 
-        let _ts_now = self.clock.borrow().timestamp_ns();
+    // -- RULES -----------------------------------------------------------------------------------
 
-        // TODO: Create a new Event "TradingStateChanged" in OrderEventAny enum.
-        // let event = OrderEventAny::TradingStateChanged(TradingStateChanged::new(..,self.trading_state,..));
+    fn handle_reload_rules(&mut self, command: ReloadRiskRules) {
+        let rules = command.rules;
+        let previous_version = self.rules_version;
+        if rules.version <= previous_version {
+            log::error!(
+                "Cannot reload risk rules: version {} not greater than active version {previous_version}",
+                rules.version
+            );
+            return;
+        }
 
+        self.rules_version = rules.version;
+        self.trader_limits = rules.trader_limits;
+        self.strategy_limits = rules.strategy_limits;
+        self.instrument_limits = rules.instrument_limits;
+        self.max_notional_per_order = rules.max_notional_per_order;
+
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let event = RiskRulesReloaded {
+            trader_id: self.msgbus.borrow().trader_id,
+            version: rules.version,
+            previous_version,
+            event_id: UUID4::new(),
+            ts_event: ts_now,
+            ts_init: ts_now,
+        };
+        log::info!("{event}");
         self.msgbus
             .borrow_mut()
-            .publish(&Ustr::from("events.risk"), &"message"); // TODO: Send the new Event here
+            .publish(&Ustr::from("events.risk"), &event);
+    }
+
+    // -- ANOMALY DETECTION -----------------------------------------------------------------------
+
+    fn detect_event_anomaly(&mut self, event: &OrderEventAny) {
+        let Some(detector) = self.anomaly_detector.as_mut() else {
+            return;
+        };
+
+        let strategy_id = event.strategy_id();
+        let detected = match event {
+            OrderEventAny::Canceled(canceled) => {
+                detector.on_order_closed(&canceled.client_order_id);
+                detector
+                    .on_canceled(strategy_id, canceled.ts_event)
+                    .map(|count| {
+                        (
+                            AnomalyKind::OrderCancelLoop,
+                            count,
+                            format!("last canceled {}", canceled.client_order_id),
+                        )
+                    })
+            }
+            OrderEventAny::Rejected(rejected) => {
+                detector.on_order_closed(&rejected.client_order_id);
+                detector
+                    .on_rejected(strategy_id, rejected.ts_event)
+                    .map(|count| {
+                        (
+                            AnomalyKind::RepeatedRejections,
+                            count,
+                            format!(
+                                "last rejected {}: {}",
+                                rejected.client_order_id, rejected.reason
+                            ),
+                        )
+                    })
+            }
+            OrderEventAny::Filled(_) | OrderEventAny::Expired(_) => {
+                detector.on_order_closed(&event.client_order_id());
+                None
+            }
+            _ => None,
+        };
 
-        log::info!("Trading state set to {state:?}");
+        if let Some((kind, count, details)) = detected {
+            self.handle_runaway_strategy(strategy_id, kind, count, details);
+        }
     }
 
-    pub fn set_max_notional_per_order(&mut self, instrument_id: InstrumentId, new_value: Decimal) {
-        self.max_notional_per_order.insert(instrument_id, new_value);
+    fn detect_modify_anomaly(&mut self, command: &ModifyOrder) {
+        let Some(detector) = self.anomaly_detector.as_mut() else {
+            return;
+        };
 
-        let new_value_str = new_value.to_string();
-        log::info!("Set MAX_NOTIONAL_PER_ORDER: {instrument_id} {new_value_str}");
+        let current = {
+            let cache = self.cache.borrow();
+            cache
+                .order(&command.client_order_id)
+                .map(|order| (order.price(), order.trigger_price()))
+        };
+        let Some((price, trigger_price)) = current else {
+            return;
+        };
+        let (from, to) = match (price, command.price, trigger_price, command.trigger_price) {
+            (Some(from), Some(to), _, _) | (_, _, Some(from), Some(to)) => (from, to),
+            _ => return, // Quantity only modify
+        };
+
+        if let Some(count) = detector.on_modify(
+            command.strategy_id,
+            command.client_order_id,
+            from,
+            to,
+            command.ts_init,
+        ) {
+            let details = format!(
+                "last modified {} from {from} to {to}",
+                command.client_order_id
+            );
+            self.handle_runaway_strategy(
+                command.strategy_id,
+                AnomalyKind::OscillatingModifies,
+                count,
+                details,
+            );
+        }
+    }
+
+    fn handle_runaway_strategy(
+        &mut self,
+        strategy_id: StrategyId,
+        kind: AnomalyKind,
+        count: usize,
+        details: String,
+    ) {
+        let Some(detector) = self.anomaly_detector.as_mut() else {
+            return;
+        };
+        detector.reset(&strategy_id);
+
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let incident = RunawayStrategyDetected {
+            trader_id: self.msgbus.borrow().trader_id,
+            strategy_id,
+            kind,
+            count,
+            window_ns: detector.config().window_ns,
+            details,
+            event_id: UUID4::new(),
+            ts_event: ts_now,
+            ts_init: ts_now,
+        };
+        log::error!("{incident}");
+        self.msgbus
+            .borrow_mut()
+            .publish(&Ustr::from("events.risk"), &incident);
+
+        // Suspend the strategy until manually reactivated
+        self.update_trading_state(Some(strategy_id), TradingState::Halted);
+    }
+
+    // -- LOSS LIMITS -----------------------------------------------------------------------------
+
+    fn handle_loss_limit_breach(&mut self, breach: &LossLimitBreached) {
+        log::warn!("{breach}");
+        self.msgbus
+            .borrow_mut()
+            .publish(&Ustr::from("events.risk"), breach);
+
+        // Account breaches halt trading globally
+        let strategy_id = match breach.scope {
+            LossLimitScope::Strategy(strategy_id) => Some(strategy_id),
+            LossLimitScope::Account(_) => None,
+        };
+        match breach.action {
+            LossLimitAction::Notify => {}
+            LossLimitAction::BlockNewOrders => {
+                self.update_trading_state(strategy_id, TradingState::Halted);
+            }
+            LossLimitAction::Flatten => {
+                self.update_trading_state(strategy_id, TradingState::Halted);
+                if !self.config.cancel_orders_on_halt {
+                    self.cancel_open_orders(strategy_id.as_ref());
+                }
+                if !self.config.flatten_positions_on_halt {
+                    self.flatten_positions(strategy_id.as_ref());
+                }
+            }
+        }
+    }
+
+    /// Returns the realized and unrealized PnL of the positions of `strategy_id` settling in
+    /// `currency`.
+    fn strategy_pnl(&self, strategy_id: &StrategyId, currency: Currency) -> f64 {
+        let cache = self.cache.borrow();
+        cache
+            .positions(None, None, Some(strategy_id), None)
+            .iter()
+            .filter(|position| position.settlement_currency == currency)
+            .map(|position| {
+                let realized = position.realized_pnl.map_or(0.0, |pnl| pnl.as_f64());
+                let unrealized = Self::last_price(&cache, &position.instrument_id)
+                    .filter(|_| position.is_open())
+                    .map_or(0.0, |last| position.unrealized_pnl(last).as_f64());
+                realized + unrealized
+            })
+            .sum()
+    }
+
+    /// Returns the equity of `account_id` in `currency`: its total balance and the unrealized
+    /// PnL of its open positions settling in `currency` (if the account is found).
+    fn account_equity(&self, account_id: &AccountId, currency: Currency) -> Option<f64> {
+        let cache = self.cache.borrow();
+        let balance = cache
+            .account(account_id)?
+            .balances()
+            .get(&currency)
+            .map_or(0.0, |balance| balance.total.as_f64());
+        let unrealized: f64 = cache
+            .positions_open(None, None, None, None)
+            .iter()
+            .filter(|position| {
+                position.account_id == *account_id && position.settlement_currency == currency
+            })
+            .filter_map(|position| {
+                Self::last_price(&cache, &position.instrument_id)
+                    .map(|last| position.unrealized_pnl(last).as_f64())
+            })
+            .sum();
+        Some(balance + unrealized)
+    }
+
+    fn last_price(cache: &Cache, instrument_id: &InstrumentId) -> Option<Price> {
+        cache
+            .price(instrument_id, PriceType::Mark)
+            .or_else(|| cache.price(instrument_id, PriceType::Last))
+            .or_else(|| cache.price(instrument_id, PriceType::Mid))
+    }
+
+    // -- KILL SWITCH -----------------------------------------------------------------------------
+
+    fn handle_set_trading_state(&mut self, command: SetTradingState) {
+        self.update_trading_state(command.strategy_id, command.state);
+    }
+
+    fn update_trading_state(&mut self, strategy_id: Option<StrategyId>, state: TradingState) {
+        let current = match strategy_id {
+            Some(strategy_id) => self
+                .strategy_trading_states
+                .get(&strategy_id)
+                .copied()
+                .unwrap_or(TradingState::Active),
+            None => self.trading_state,
+        };
+        if state == current {
+            log::warn!("No change to trading state: already set to {state:?}");
+            return;
+        }
+
+        match strategy_id {
+            Some(strategy_id) if state == TradingState::Active => {
+                self.strategy_trading_states.remove(&strategy_id);
+            }
+            Some(strategy_id) => {
+                self.strategy_trading_states.insert(strategy_id, state);
+            }
+            None => self.trading_state = state,
+        }
+        self.persist_trading_states();
+
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let event = TradingStateChanged {
+            trader_id: self.msgbus.borrow().trader_id,
+            strategy_id,
+            state,
+            event_id: UUID4::new(),
+            ts_event: ts_now,
+            ts_init: ts_now,
+        };
+        self.msgbus
+            .borrow_mut()
+            .publish(&Ustr::from("events.risk"), &event);
+
+        log::info!("Trading state set to {state:?} ({event})");
+
+        if self.config.audit_trail {
+            let record = RiskAuditRecord::new(
+                event.trader_id,
+                strategy_id,
+                None,
+                None,
+                RiskDecision::TradingStateChanged,
+                Some(&format!("{current} -> {state}")),
+                ts_now,
+            );
+            Self::persist_audit_record(&self.cache, &record);
+        }
+
+        if state == TradingState::Halted {
+            if self.config.cancel_orders_on_halt {
+                self.cancel_open_orders(strategy_id.as_ref());
+            }
+            if self.config.flatten_positions_on_halt {
+                self.flatten_positions(strategy_id.as_ref());
+            }
+        }
+    }
+
+    fn load_trading_states(&mut self) {
+        let states = match self.cache.borrow().get(TRADING_STATES_KEY) {
+            Ok(Some(bytes)) => TradingStates::from_bytes(bytes),
+            _ => return,
+        };
+
+        match states {
+            Ok(states) => {
+                self.trading_state = states.global;
+                self.strategy_trading_states = states.strategies;
+                log::info!("Loaded trading state {:?}", self.trading_state);
+            }
+            Err(e) => log::error!("Cannot load trading states: {e}"),
+        }
+    }
+
+    fn persist_trading_states(&self) {
+        let states = TradingStates {
+            global: self.trading_state,
+            strategies: self.strategy_trading_states.clone(),
+        };
+
+        let result = states
+            .to_bytes()
+            .and_then(|bytes| self.cache.borrow_mut().add(TRADING_STATES_KEY, bytes));
+        if let Err(e) = result {
+            log::error!("Cannot persist trading states: {e}");
+        }
+    }
+
+    /// Cancels the open orders of the `strategy_id` (or of all strategies).
+    fn cancel_open_orders(&self, strategy_id: Option<&StrategyId>) {
+        let mut targets: Vec<(ClientId, StrategyId, InstrumentId)> = Vec::new();
+        {
+            let cache = self.cache.borrow();
+            for order in cache.orders_open(None, None, strategy_id, None) {
+                let instrument_id = order.instrument_id();
+                let client_id = cache
+                    .client_id(&order.client_order_id())
+                    .copied()
+                    .unwrap_or_else(|| ClientId::new(instrument_id.venue.as_str()));
+                let target = (client_id, order.strategy_id(), instrument_id);
+                if !targets.contains(&target) {
+                    targets.push(target);
+                }
+            }
+        }
+
+        let trader_id = self.msgbus.borrow().trader_id;
+        let ts_now = self.clock.borrow().timestamp_ns();
+        for (client_id, strategy_id, instrument_id) in targets {
+            log::warn!("Canceling open orders of {strategy_id} for {instrument_id} on HALT");
+            match CancelAllOrders::new(
+                trader_id,
+                client_id,
+                strategy_id,
+                instrument_id,
+                OrderSide::NoOrderSide,
+                UUID4::new(),
+                ts_now,
+            ) {
+                Ok(command) => self.send_to_execution(TradingCommand::CancelAllOrders(command)),
+                Err(e) => log::error!("Cannot cancel orders of {strategy_id}: {e}"),
+            }
+        }
+    }
+
+    /// Flattens the open positions of the `strategy_id` (or of all strategies) with
+    /// reduce-only market orders.
+    fn flatten_positions(&self, strategy_id: Option<&StrategyId>) {
+        let trader_id = self.msgbus.borrow().trader_id;
+        let ts_now = self.clock.borrow().timestamp_ns();
+
+        let mut commands = Vec::new();
+        {
+            let cache = self.cache.borrow();
+            for position in cache.positions_open(None, None, strategy_id, None) {
+                let Some(order) = flatten_order(position, trader_id, ts_now) else {
+                    continue;
+                };
+                let client_id = cache
+                    .client_id(&position.opening_order_id)
+                    .copied()
+                    .unwrap_or_else(|| ClientId::new(position.instrument_id.venue.as_str()));
+                log::warn!("Flattening {} on HALT", position.id);
+                match SubmitOrder::new(
+                    trader_id,
+                    client_id,
+                    position.strategy_id,
+                    position.instrument_id,
+                    order.client_order_id(),
+                    VenueOrderId::default(),
+                    order,
+                    None,
+                    Some(position.id),
+                    UUID4::new(),
+                    ts_now,
+                ) {
+                    Ok(command) => commands.push(command),
+                    Err(e) => log::error!("Cannot flatten {}: {e}", position.id),
+                }
+            }
+        }
+
+        for command in commands {
+            self.send_to_execution(TradingCommand::SubmitOrder(command));
+        }
     }
 
     // -- COMMAND HANDLERS ------------------------------------------------------------------------
@@ -368,6 +1099,14 @@ impl RiskEngine {
             return; // Denied
         }
 
+        if !self.check_orders_limits(&instrument, std::slice::from_ref(order)) {
+            return; // Denied
+        }
+
+        if !self.check_orders_exposure(&instrument, std::slice::from_ref(order)) {
+            return; // Denied
+        }
+
         if !self.check_orders_risk(instrument.clone(), Vec::from([order.clone()])) {
             return; // Denied
         }
@@ -405,6 +1144,22 @@ impl RiskEngine {
             }
         }
 
+        if !self.check_orders_limits(&instrument, &command.order_list.orders) {
+            self.deny_order_list(
+                command.order_list.clone(),
+                &format!("OrderList {} DENIED", command.order_list.id),
+            );
+            return; // Denied
+        }
+
+        if !self.check_orders_exposure(&instrument, &command.order_list.orders) {
+            self.deny_order_list(
+                command.order_list.clone(),
+                &format!("OrderList {} DENIED", command.order_list.id),
+            );
+            return; // Denied
+        }
+
         if !self.check_orders_risk(instrument.clone(), command.order_list.clone().orders) {
             self.deny_order_list(
                 command.order_list.clone(),
@@ -493,7 +1248,7 @@ impl RiskEngine {
         }
 
         // Check TradingState
-        match self.trading_state {
+        match self.trading_state_for(&order.strategy_id()) {
             TradingState::Halted => {
                 self.reject_modify_order(order, "TradingState is HALTED: Cannot modify order");
                 return; // Denied
@@ -604,7 +1359,9 @@ impl RiskEngine {
         };
         let cash_account = match account {
             AccountAny::Cash(cash_account) => cash_account,
-            AccountAny::Margin(_) => return true, // TODO: Determine risk controls for margin
+            AccountAny::Margin(margin_account) => {
+                return self.check_orders_margin(&instrument, &margin_account, &orders);
+            }
         };
         let free = cash_account.balance_free(Some(instrument.quote_currency()));
         if self.config.debug {
@@ -833,17 +1590,316 @@ impl RiskEngine {
             }
         }
 
+        if self.config.lock_order_balances
+            && !self.check_orders_balance_locks(&instrument, &cash_account, &orders)
+        {
+            return false; // Denied
+        }
+
         // Finally
         true // Passed
     }
 
-    fn check_price(&self, instrument: &InstrumentAny, price: Option<Price>) -> Option<String> {
-        let price_val = price?;
-
-        if price_val.precision > instrument.price_precision() {
-            return Some(format!(
-                "price {} invalid (precision {} > {})",
-                price_val,
+    fn check_orders_balance_locks(
+        &self,
+        instrument: &InstrumentAny,
+        cash_account: &CashAccount,
+        orders: &[OrderAny],
+    ) -> bool {
+        let mut pending: Vec<(ClientOrderId, Money, Quantity)> = Vec::new();
+        for order in orders {
+            let Some(price) = self.order_price(instrument, order) else {
+                log::warn!(
+                    "Cannot lock {} order balance: no prices for {}",
+                    order.order_type(),
+                    instrument.id()
+                );
+                continue;
+            };
+            let Some(cost) =
+                estimate_order_cost(instrument, order, price, self.config.slippage_buffer)
+            else {
+                continue;
+            };
+            let Some(balance) = cash_account.base_balance(Some(cost.currency)) else {
+                continue;
+            };
+
+            // The account locks the balance of open orders once acknowledged, while the engine
+            // also locks orders in flight, so the greater of the two is taken
+            let pending_locked: i64 = pending
+                .iter()
+                .filter(|(_, amount, _)| amount.currency == cost.currency)
+                .map(|(_, amount, _)| amount.raw)
+                .sum();
+            let engine_locked =
+                self.balance_locks.borrow().locked(cost.currency).raw + pending_locked;
+            let locked = engine_locked.max(balance.locked.raw);
+            if locked + cost.raw > balance.total.raw {
+                let free = Money::from_raw(balance.total.raw - locked, cost.currency);
+                self.deny_order(
+                    order.clone(),
+                    &format!("COST_EXCEEDS_FREE_BALANCE: free={free}, cost={cost}"),
+                );
+                return false; // Denied
+            }
+            pending.push((order.client_order_id(), cost, order.quantity()));
+        }
+
+        let mut balance_locks = self.balance_locks.borrow_mut();
+        for (client_order_id, cost, quantity) in pending {
+            balance_locks.lock(client_order_id, cost, quantity);
+        }
+        true
+    }
+
+    /// Returns the price the `order` is expected to fill at: its limit or trigger price, or
+    /// the current price of the side it would take (falling back to the last trade).
+    fn order_price(&self, instrument: &InstrumentAny, order: &OrderAny) -> Option<Price> {
+        order.price().or(order.trigger_price()).or_else(|| {
+            let cache = self.cache.borrow();
+            cache
+                .quote(&instrument.id())
+                .map(|quote| {
+                    if order.is_buy() {
+                        quote.ask_price
+                    } else {
+                        quote.bid_price
+                    }
+                })
+                .or_else(|| cache.trade(&instrument.id()).map(|trade| trade.price))
+        })
+    }
+
+    fn check_orders_margin(
+        &self,
+        instrument: &InstrumentAny,
+        margin_account: &MarginAccount,
+        orders: &[OrderAny],
+    ) -> bool {
+        let margin_model = self
+            .config
+            .margin_models
+            .get(&instrument.id().venue)
+            .cloned()
+            .unwrap_or_default();
+        let leverage = margin_account.get_leverage(&instrument.id());
+
+        let mut cum_margin: HashMap<Currency, Money> = HashMap::new();
+        for order in orders {
+            // Reduce-only orders release margin rather than requiring it
+            if order.is_reduce_only() {
+                continue;
+            }
+
+            let Some(last_px) = self.order_price(instrument, order) else {
+                log::warn!(
+                    "Cannot check {} order margin: no prices for {}",
+                    order.order_type(),
+                    instrument.id()
+                );
+                continue;
+            };
+
+            let side = if order.is_buy() {
+                PositionSide::Long
+            } else {
+                PositionSide::Short
+            };
+            let margin = margin_model
+                .calculate(instrument, side, order.quantity(), last_px, leverage, None)
+                .initial;
+
+            if self.config.debug {
+                log::debug!("Initial margin ({margin_model}): {margin:?}");
+            }
+
+            let cum = cum_margin
+                .entry(margin.currency)
+                .or_insert_with(|| Money::new(0.0, margin.currency));
+            cum.raw += margin.raw;
+
+            if let Some(free) = margin_account.balance_free(Some(margin.currency)) {
+                if cum.raw > free.raw {
+                    self.deny_order(
+                        order.clone(),
+                        &format!("MARGIN_EXCEEDS_FREE_BALANCE: free={free}, margin_init={cum}"),
+                    );
+                    return false; // Denied
+                }
+            }
+        }
+
+        true // Passed
+    }
+
+    fn check_orders_limits(&self, instrument: &InstrumentAny, orders: &[OrderAny]) -> bool {
+        let instrument_id = instrument.id();
+        let instrument_limits = self.instrument_limits.get(&instrument_id);
+        let trader_limits = self.trader_limits.as_ref();
+        if instrument_limits.is_none() && trader_limits.is_none() && self.strategy_limits.is_empty()
+        {
+            return true; // No limits
+        }
+
+        // Collar prices around the mid, falling back to the last trade
+        let reference_px = {
+            let cache = self.cache.borrow();
+            cache
+                .quote(&instrument_id)
+                .map(|quote| quote.extract_price(PriceType::Mid))
+                .or_else(|| cache.trade(&instrument_id).map(|trade| trade.price))
+        };
+
+        for order in orders {
+            let strategy_id = order.strategy_id();
+            let strategy_limits = self.strategy_limits.get(&strategy_id);
+            if instrument_limits.is_none() && trader_limits.is_none() && strategy_limits.is_none() {
+                continue;
+            }
+
+            let notional = order
+                .price()
+                .or(order.trigger_price())
+                .or(reference_px)
+                .map(|px| instrument.calculate_notional_value(order.quantity(), px, Some(true)));
+
+            let result = {
+                let cache = self.cache.borrow();
+                let net_position = |strategy_id: Option<&StrategyId>| {
+                    cache
+                        .positions_open(None, Some(&instrument_id), strategy_id, None)
+                        .iter()
+                        .map(|position| position.signed_qty)
+                        .sum::<f64>()
+                };
+
+                trader_limits
+                    .map_or(Ok(()), |limits| {
+                        limits.check(
+                            order.trader_id().as_ref(),
+                            order,
+                            notional,
+                            reference_px,
+                            net_position(None),
+                        )
+                    })
+                    .and_then(|()| {
+                        instrument_limits.map_or(Ok(()), |limits| {
+                            limits.check(
+                                &instrument_id.to_string(),
+                                order,
+                                notional,
+                                reference_px,
+                                net_position(None),
+                            )
+                        })
+                    })
+                    .and_then(|()| {
+                        strategy_limits.map_or(Ok(()), |limits| {
+                            limits.check(
+                                strategy_id.as_str(),
+                                order,
+                                notional,
+                                reference_px,
+                                net_position(Some(&strategy_id)),
+                            )
+                        })
+                    })
+            };
+
+            if let Err(violation) = result {
+                self.deny_order(order.clone(), &violation.to_string());
+                return false; // Denied
+            }
+        }
+
+        true
+    }
+
+    fn check_orders_exposure(&self, instrument: &InstrumentAny, orders: &[OrderAny]) -> bool {
+        let instrument_id = instrument.id();
+        let groups: Vec<&ExposureGroup> = self
+            .exposure_groups
+            .iter()
+            .filter(|group| group.weight(&instrument_id).is_some())
+            .collect();
+        if groups.is_empty() {
+            return true; // No groups
+        }
+
+        // Net exposures per (strategy, group), including the prior orders of a list
+        let mut net_exposures: HashMap<(StrategyId, usize), f64> = HashMap::new();
+        for order in orders {
+            let strategy_id = order.strategy_id();
+            let price = order.price().or(order.trigger_price()).or_else(|| {
+                let cache = self.cache.borrow();
+                Self::last_price(&cache, &instrument_id)
+            });
+            let Some(price) = price else {
+                log::warn!(
+                    "Cannot check {} order exposure: no prices for {instrument_id}",
+                    order.order_type()
+                );
+                continue;
+            };
+
+            let notional = instrument.calculate_notional_value(order.quantity(), price, None);
+            let sign = if order.is_buy() { 1.0 } else { -1.0 };
+
+            for (index, group) in groups.iter().enumerate() {
+                if notional.currency != group.currency {
+                    continue; // Measured in another currency
+                }
+                let order_exposure =
+                    sign * notional.as_f64() * group.weight(&instrument_id).unwrap_or_default();
+                let net_exposure = net_exposures
+                    .entry((strategy_id, index))
+                    .or_insert_with(|| self.group_exposure(group, &strategy_id));
+
+                if let Err(violation) =
+                    group.check(strategy_id.as_str(), *net_exposure, order_exposure)
+                {
+                    self.deny_order(order.clone(), &violation.to_string());
+                    return false; // Denied
+                }
+                *net_exposure += order_exposure;
+            }
+        }
+
+        true
+    }
+
+    /// Returns the signed net exposure of the open positions of the strategy across the group,
+    /// valued at the last prices (falling back to the average open prices).
+    fn group_exposure(&self, group: &ExposureGroup, strategy_id: &StrategyId) -> f64 {
+        let cache = self.cache.borrow();
+        cache
+            .positions_open(None, None, Some(strategy_id), None)
+            .iter()
+            .filter_map(|position| {
+                let weight = group.weight(&position.instrument_id)?;
+                let instrument = cache.instrument(&position.instrument_id)?;
+                let price =
+                    Self::last_price(&cache, &position.instrument_id).unwrap_or_else(|| {
+                        Price::new(position.avg_px_open, instrument.price_precision())
+                    });
+                let notional = instrument.calculate_notional_value(position.quantity, price, None);
+                if notional.currency != group.currency {
+                    return None; // Measured in another currency
+                }
+                Some(position.signed_qty.signum() * notional.as_f64() * weight)
+            })
+            .sum()
+    }
+
+    fn check_price(&self, instrument: &InstrumentAny, price: Option<Price>) -> Option<String> {
+        let price_val = price?;
+
+        if price_val.precision > instrument.price_precision() {
+            return Some(format!(
+                "price {} invalid (precision {} > {})",
+                price_val,
                 price_val.precision,
                 instrument.price_precision()
             ));
@@ -917,6 +1973,20 @@ impl RiskEngine {
             reason
         );
 
+        if self.config.audit_trail {
+            Self::audit_command(
+                &self.cache,
+                &self.clock,
+                &order,
+                RiskDecision::Denied,
+                Some(reason),
+            );
+        }
+
+        self.balance_locks
+            .borrow_mut()
+            .release(&order.client_order_id());
+
         if order.status() != OrderStatus::Initialized {
             return;
         }
@@ -956,6 +2026,16 @@ impl RiskEngine {
     }
 
     fn reject_modify_order(&self, order: OrderAny, reason: &str) {
+        if self.config.audit_trail {
+            Self::audit_command(
+                &self.cache,
+                &self.clock,
+                &order,
+                RiskDecision::Denied,
+                Some(reason),
+            );
+        }
+
         let ts_event = self.clock.borrow().timestamp_ns();
         let denied = OrderEventAny::ModifyRejected(OrderModifyRejected::new(
             order.trader_id(),
@@ -979,7 +2059,17 @@ impl RiskEngine {
     // -- EGRESS ----------------------------------------------------------------------------------
 
     fn execution_gateway(&self, instrument: InstrumentAny, command: TradingCommand) {
-        match self.trading_state {
+        let trading_state = match &command {
+            TradingCommand::SubmitOrder(submit_order) => {
+                self.trading_state_for(&submit_order.strategy_id)
+            }
+            TradingCommand::SubmitOrderList(submit_order_list) => {
+                self.trading_state_for(&submit_order_list.strategy_id)
+            }
+            _ => self.trading_state,
+        };
+
+        match trading_state {
             TradingState::Halted => match command {
                 TradingCommand::SubmitOrder(submit_order) => {
                     self.deny_order(submit_order.order, "TradingState::HALTED");
@@ -1056,11 +2146,29 @@ impl RiskEngine {
     }
 
     fn handle_event(&mut self, event: OrderEventAny) {
-        // We intend to extend the risk engine to be able to handle additional events.
-        // For now we just log.
         if self.config.debug {
             log::debug!("{}{} {event:?}", RECV, EVT);
         }
+
+        // Release the balance locked for the order as it fills or closes
+        match &event {
+            OrderEventAny::PartiallyFilled(fill) | OrderEventAny::Filled(fill) => {
+                self.balance_locks
+                    .borrow_mut()
+                    .release_fill(&fill.client_order_id, fill.last_qty);
+            }
+            OrderEventAny::Denied(_)
+            | OrderEventAny::Rejected(_)
+            | OrderEventAny::Canceled(_)
+            | OrderEventAny::Expired(_) => {
+                self.balance_locks
+                    .borrow_mut()
+                    .release(&event.client_order_id());
+            }
+            _ => {}
+        }
+
+        self.detect_event_anomaly(&event);
     }
 }
 
@@ -1092,10 +2200,11 @@ mod tests {
             AccountAny,
         },
         data::{stubs::quote_audusd, QuoteTick},
-        enums::{AccountType, LiquiditySide, OrderSide, OrderType, TradingState},
+        enums::{AccountType, LiquiditySide, OmsType, OrderSide, OrderType, TradingState},
         events::{
             account::stubs::cash_account_state_million_usd, AccountState, OrderAccepted,
-            OrderDenied, OrderEventAny, OrderEventType, OrderFilled, OrderSubmitted,
+            OrderCanceled, OrderDenied, OrderEventAny, OrderEventType, OrderFilled, OrderRejected,
+            OrderSubmitted,
         },
         identifiers::{
             stubs::{
@@ -1106,10 +2215,14 @@ mod tests {
             Symbol, TradeId, TraderId, VenueOrderId,
         },
         instruments::{
-            stubs::{audusd_sim, crypto_perpetual_ethusdt, xbtusd_bitmex},
+            stubs::{
+                audusd_sim, crypto_future_btcusdt, crypto_perpetual_ethusdt, currency_pair_btcusdt,
+                xbtusd_bitmex,
+            },
             CryptoPerpetual, CurrencyPair, InstrumentAny,
         },
-        orders::{OrderAny, OrderList, OrderTestBuilder},
+        orders::{stubs::TestOrderEventStubs, OrderAny, OrderList, OrderTestBuilder},
+        position::Position,
         types::{fixed::FIXED_PRECISION, AccountBalance, Currency, Money, Price, Quantity},
     };
     use nautilus_portfolio::Portfolio;
@@ -1117,7 +2230,18 @@ mod tests {
     use rust_decimal::{prelude::FromPrimitive, Decimal};
     use ustr::Ustr;
 
-    use super::{config::RiskEngineConfig, RiskEngine};
+    use super::{
+        anomaly::AnomalyConfig,
+        audit::{RiskAuditFilter, RiskDecision},
+        config::RiskEngineConfig,
+        exposure::ExposureGroup,
+        kill_switch::{RiskCommand, SetTradingState},
+        limits::PreTradeLimits,
+        loss_limits::{LossLimitAction, LossLimitScope, LossLimits},
+        rules::{ReloadRiskRules, RiskRuleSet},
+        stress::{Shock, StressScenario},
+        RiskEngine,
+    };
 
     #[fixture]
     fn msgbus() -> MessageBus {
@@ -1216,6 +2340,21 @@ mod tests {
             max_order_submit,
             max_order_modify,
             max_notional_per_order,
+            trader_limits: None,
+            instrument_limits: HashMap::new(),
+            strategy_limits: HashMap::new(),
+            exposure_groups: Vec::new(),
+            cancel_orders_on_halt: false,
+            flatten_positions_on_halt: false,
+            strategy_loss_limits: HashMap::new(),
+            account_loss_limits: HashMap::new(),
+            margin_models: HashMap::new(),
+            lock_order_balances: false,
+            slippage_buffer: Decimal::ZERO,
+            anomaly_detection: None,
+            stress_scenarios: Vec::new(),
+            stress_test_interval_ms: 0,
+            audit_trail: false,
         }
     }
 
@@ -1316,6 +2455,21 @@ mod tests {
             max_order_submit: RateLimit::new(10, 1000),
             max_order_modify: RateLimit::new(5, 1000),
             max_notional_per_order: HashMap::new(),
+            trader_limits: None,
+            instrument_limits: HashMap::new(),
+            strategy_limits: HashMap::new(),
+            exposure_groups: Vec::new(),
+            cancel_orders_on_halt: false,
+            flatten_positions_on_halt: false,
+            strategy_loss_limits: HashMap::new(),
+            account_loss_limits: HashMap::new(),
+            margin_models: HashMap::new(),
+            lock_order_balances: false,
+            slippage_buffer: Decimal::ZERO,
+            anomaly_detection: None,
+            stress_scenarios: Vec::new(),
+            stress_test_interval_ms: 0,
+            audit_trail: false,
         });
         let clock = clock.unwrap_or(Rc::new(RefCell::new(TestClock::new())));
         let portfolio = Portfolio::new(msgbus.clone(), cache.clone(), clock.clone(), None);
@@ -1462,102 +2616,339 @@ mod tests {
     }
 
     #[rstest]
-    fn test_max_order_submit_rate_when_no_risk_config_returns_10_per_second(msgbus: MessageBus) {
-        let risk_engine = get_risk_engine(Rc::new(RefCell::new(msgbus)), None, None, None, false);
-
-        assert_eq!(risk_engine.config.max_order_submit.limit, 10);
-        assert_eq!(risk_engine.config.max_order_submit.interval_ns, 1000);
-    }
-
-    #[rstest]
-    fn test_max_order_modify_rate_when_no_risk_config_returns_5_per_second(msgbus: MessageBus) {
-        let risk_engine = get_risk_engine(Rc::new(RefCell::new(msgbus)), None, None, None, false);
-
-        assert_eq!(risk_engine.config.max_order_modify.limit, 5);
-        assert_eq!(risk_engine.config.max_order_modify.interval_ns, 1000);
-    }
-
-    #[rstest]
-    fn test_max_notionals_per_order_when_no_risk_config_returns_empty_hashmap(msgbus: MessageBus) {
-        let risk_engine = get_risk_engine(Rc::new(RefCell::new(msgbus)), None, None, None, false);
-
-        assert_eq!(risk_engine.max_notional_per_order, HashMap::new());
-    }
-
-    #[rstest]
-    fn test_set_max_notional_per_order_changes_setting(
-        msgbus: MessageBus,
-        instrument_audusd: InstrumentAny,
-    ) {
-        let mut risk_engine =
-            get_risk_engine(Rc::new(RefCell::new(msgbus)), None, None, None, false);
-
-        risk_engine
-            .set_max_notional_per_order(instrument_audusd.id(), Decimal::from_i64(100000).unwrap());
-
-        let mut expected = HashMap::new();
-        expected.insert(instrument_audusd.id(), Decimal::from_i64(100000).unwrap());
-        assert_eq!(risk_engine.max_notional_per_order, expected);
-    }
-
-    #[rstest]
-    fn test_given_random_command_then_logs_and_continues(
-        msgbus: MessageBus,
-        strategy_id_ema_cross: StrategyId,
-        client_id_binance: ClientId,
-        trader_id: TraderId,
-        client_order_id: ClientOrderId,
+    fn test_strategy_halt_cancels_orders_flattens_positions_and_persists(
+        mut msgbus: MessageBus,
         instrument_audusd: InstrumentAny,
-        venue_order_id: VenueOrderId,
+        execute_order_event_handler: ShareableMessageHandler,
+        mut simple_cache: Cache,
     ) {
-        let mut risk_engine =
-            get_risk_engine(Rc::new(RefCell::new(msgbus)), None, None, None, false);
+        msgbus.register(
+            msgbus.switchboard.exec_engine_execute,
+            execute_order_event_handler.clone(),
+        );
 
-        let order = OrderTestBuilder::new(OrderType::Limit)
+        let mut order = OrderTestBuilder::new(OrderType::Limit)
             .instrument_id(instrument_audusd.id())
             .side(OrderSide::Buy)
-            .price(Price::from_raw(100, 0))
+            .price(Price::from("0.75000"))
             .quantity(Quantity::from("1000"))
             .build();
+        let strategy_id = order.strategy_id();
+        simple_cache
+            .add_order(order.clone(), None, None, false)
+            .unwrap();
+        order
+            .apply(OrderEventAny::Submitted(order_submitted(&order)))
+            .unwrap();
+        order
+            .apply(OrderEventAny::Accepted(order_accepted(&order, None)))
+            .unwrap();
+        simple_cache.update_order(&order).unwrap();
 
-        let submit_order = SubmitOrder::new(
-            trader_id,
-            client_id_binance,
-            strategy_id_ema_cross,
-            instrument_audusd.id(),
-            client_order_id,
-            venue_order_id,
-            order,
+        let entry = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_audusd.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("2000"))
+            .build();
+        let OrderEventAny::Filled(fill) = TestOrderEventStubs::order_filled(
+            &entry,
+            &instrument_audusd,
             None,
+            Some(PositionId::from("P-1")),
             None,
-            UUID4::new(),
-            risk_engine.clock.borrow().timestamp_ns(),
-        )
-        .unwrap();
+            None,
+            None,
+            None,
+            None,
+            None,
+        ) else {
+            unreachable!()
+        };
+        simple_cache
+            .add_position(Position::new(&instrument_audusd, fill), OmsType::Netting)
+            .unwrap();
 
-        let random_command = TradingCommand::SubmitOrder(submit_order);
+        let msgbus = Rc::new(RefCell::new(msgbus));
+        let cache = Rc::new(RefCell::new(simple_cache));
+        let config = RiskEngineConfig {
+            cancel_orders_on_halt: true,
+            flatten_positions_on_halt: true,
+            ..Default::default()
+        };
+        let mut risk_engine = get_risk_engine(
+            msgbus.clone(),
+            Some(cache.clone()),
+            Some(config),
+            None,
+            false,
+        );
 
-        risk_engine.execute(random_command);
+        risk_engine.execute_risk(RiskCommand::SetTradingState(SetTradingState {
+            trader_id: TraderId::default(),
+            strategy_id: Some(strategy_id),
+            state: TradingState::Halted,
+            command_id: UUID4::new(),
+            ts_init: UnixNanos::default(),
+        }));
+
+        assert_eq!(risk_engine.trading_state, TradingState::Active);
+        assert_eq!(
+            risk_engine.trading_state_for(&strategy_id),
+            TradingState::Halted
+        );
+        let saved_execute_messages =
+            get_execute_order_event_handler_messages(execute_order_event_handler);
+        assert_eq!(saved_execute_messages.len(), 2);
+        assert!(matches!(
+            &saved_execute_messages[0],
+            TradingCommand::CancelAllOrders(cancel) if cancel.strategy_id == strategy_id
+        ));
+        let TradingCommand::SubmitOrder(flatten) = &saved_execute_messages[1] else {
+            panic!("expected flattening order");
+        };
+        assert_eq!(flatten.order.order_side(), OrderSide::Sell);
+        assert_eq!(flatten.order.quantity(), Quantity::from("2000"));
+        assert!(flatten.order.is_reduce_only());
+        assert_eq!(flatten.position_id, Some(PositionId::from("P-1")));
+
+        // The trading state is restored on restart
+        let restarted = get_risk_engine(msgbus, Some(cache), None, None, false);
+        assert_eq!(
+            restarted.trading_state_for(&strategy_id),
+            TradingState::Halted
+        );
     }
 
     #[rstest]
-    fn test_given_random_event_then_logs_and_continues(
+    fn test_strategy_daily_loss_limit_blocks_new_orders(
         msgbus: MessageBus,
         instrument_audusd: InstrumentAny,
+        mut simple_cache: Cache,
     ) {
-        let mut risk_engine =
-            get_risk_engine(Rc::new(RefCell::new(msgbus)), None, None, None, false);
-
-        let order = OrderTestBuilder::new(OrderType::Limit)
+        let quote = |bid: &str, ask: &str| {
+            QuoteTick::new(
+                instrument_audusd.id(),
+                Price::from(bid),
+                Price::from(ask),
+                Quantity::from("500000"),
+                Quantity::from("500000"),
+                UnixNanos::default(),
+                UnixNanos::default(),
+            )
+        };
+        let entry = OrderTestBuilder::new(OrderType::Market)
             .instrument_id(instrument_audusd.id())
             .side(OrderSide::Buy)
-            .price(Price::from_raw(100, 0))
-            .quantity(Quantity::from("1000"))
+            .quantity(Quantity::from("100000"))
             .build();
-
-        let random_event = OrderEventAny::Denied(OrderDenied::new(
-            order.trader_id(),
-            order.strategy_id(),
+        let strategy_id = entry.strategy_id();
+        let OrderEventAny::Filled(fill) = TestOrderEventStubs::order_filled(
+            &entry,
+            &instrument_audusd,
+            None,
+            Some(PositionId::from("P-1")),
+            Some(Price::from("0.75000")),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ) else {
+            unreachable!()
+        };
+        simple_cache
+            .add_position(Position::new(&instrument_audusd, fill), OmsType::Netting)
+            .unwrap();
+        simple_cache.add_quote(quote("0.75000", "0.75000")).unwrap();
+
+        let cache = Rc::new(RefCell::new(simple_cache));
+        let mut risk_engine = get_risk_engine(
+            Rc::new(RefCell::new(msgbus)),
+            Some(cache.clone()),
+            None,
+            None,
+            false,
+        );
+        risk_engine.set_loss_limits(
+            LossLimitScope::Strategy(strategy_id),
+            LossLimits {
+                currency: Currency::USD(),
+                max_daily_loss: Some(500.0),
+                max_drawdown: None,
+                action: LossLimitAction::BlockNewOrders,
+            },
+        );
+
+        risk_engine.evaluate_loss_limits();
+        assert_eq!(
+            risk_engine.trading_state_for(&strategy_id),
+            TradingState::Active
+        );
+
+        cache
+            .borrow_mut()
+            .add_quote(quote("0.74000", "0.74000"))
+            .unwrap();
+        risk_engine.evaluate_loss_limits();
+
+        assert_eq!(
+            risk_engine.trading_state_for(&strategy_id),
+            TradingState::Halted
+        );
+        assert_eq!(risk_engine.trading_state, TradingState::Active);
+    }
+
+    #[rstest]
+    fn test_repeated_rejections_suspend_runaway_strategy(
+        msgbus: MessageBus,
+        instrument_audusd: InstrumentAny,
+    ) {
+        let config = RiskEngineConfig {
+            anomaly_detection: Some(AnomalyConfig {
+                max_rejections: 3,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut risk_engine = get_risk_engine(
+            Rc::new(RefCell::new(msgbus)),
+            None,
+            Some(config),
+            None,
+            false,
+        );
+        let runaway = StrategyId::from("S-001");
+        let other = StrategyId::from("S-002");
+        let rejected = |strategy_id: StrategyId, ts: u64| {
+            OrderEventAny::Rejected(OrderRejected::new(
+                TraderId::default(),
+                strategy_id,
+                instrument_audusd.id(),
+                ClientOrderId::new(format!("O-{strategy_id}-{ts}")),
+                AccountId::from("SIM-001"),
+                Ustr::from("POST_ONLY_WOULD_CROSS"),
+                UUID4::new(),
+                ts.into(),
+                ts.into(),
+                false,
+            ))
+        };
+
+        risk_engine.process(rejected(runaway, 1));
+        risk_engine.process(rejected(other, 2));
+        risk_engine.process(rejected(runaway, 3));
+        assert_eq!(
+            risk_engine.trading_state_for(&runaway),
+            TradingState::Active
+        );
+
+        risk_engine.process(rejected(runaway, 4));
+
+        assert_eq!(
+            risk_engine.trading_state_for(&runaway),
+            TradingState::Halted
+        );
+        assert_eq!(risk_engine.trading_state_for(&other), TradingState::Active);
+        assert_eq!(risk_engine.trading_state, TradingState::Active);
+    }
+
+    #[rstest]
+    fn test_max_order_submit_rate_when_no_risk_config_returns_10_per_second(msgbus: MessageBus) {
+        let risk_engine = get_risk_engine(Rc::new(RefCell::new(msgbus)), None, None, None, false);
+
+        assert_eq!(risk_engine.config.max_order_submit.limit, 10);
+        assert_eq!(risk_engine.config.max_order_submit.interval_ns, 1000);
+    }
+
+    #[rstest]
+    fn test_max_order_modify_rate_when_no_risk_config_returns_5_per_second(msgbus: MessageBus) {
+        let risk_engine = get_risk_engine(Rc::new(RefCell::new(msgbus)), None, None, None, false);
+
+        assert_eq!(risk_engine.config.max_order_modify.limit, 5);
+        assert_eq!(risk_engine.config.max_order_modify.interval_ns, 1000);
+    }
+
+    #[rstest]
+    fn test_max_notionals_per_order_when_no_risk_config_returns_empty_hashmap(msgbus: MessageBus) {
+        let risk_engine = get_risk_engine(Rc::new(RefCell::new(msgbus)), None, None, None, false);
+
+        assert_eq!(risk_engine.max_notional_per_order, HashMap::new());
+    }
+
+    #[rstest]
+    fn test_set_max_notional_per_order_changes_setting(
+        msgbus: MessageBus,
+        instrument_audusd: InstrumentAny,
+    ) {
+        let mut risk_engine =
+            get_risk_engine(Rc::new(RefCell::new(msgbus)), None, None, None, false);
+
+        risk_engine
+            .set_max_notional_per_order(instrument_audusd.id(), Decimal::from_i64(100000).unwrap());
+
+        let mut expected = HashMap::new();
+        expected.insert(instrument_audusd.id(), Decimal::from_i64(100000).unwrap());
+        assert_eq!(risk_engine.max_notional_per_order, expected);
+    }
+
+    #[rstest]
+    fn test_given_random_command_then_logs_and_continues(
+        msgbus: MessageBus,
+        strategy_id_ema_cross: StrategyId,
+        client_id_binance: ClientId,
+        trader_id: TraderId,
+        client_order_id: ClientOrderId,
+        instrument_audusd: InstrumentAny,
+        venue_order_id: VenueOrderId,
+    ) {
+        let mut risk_engine =
+            get_risk_engine(Rc::new(RefCell::new(msgbus)), None, None, None, false);
+
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_audusd.id())
+            .side(OrderSide::Buy)
+            .price(Price::from_raw(100, 0))
+            .quantity(Quantity::from("1000"))
+            .build();
+
+        let submit_order = SubmitOrder::new(
+            trader_id,
+            client_id_binance,
+            strategy_id_ema_cross,
+            instrument_audusd.id(),
+            client_order_id,
+            venue_order_id,
+            order,
+            None,
+            None,
+            UUID4::new(),
+            risk_engine.clock.borrow().timestamp_ns(),
+        )
+        .unwrap();
+
+        let random_command = TradingCommand::SubmitOrder(submit_order);
+
+        risk_engine.execute(random_command);
+    }
+
+    #[rstest]
+    fn test_given_random_event_then_logs_and_continues(
+        msgbus: MessageBus,
+        instrument_audusd: InstrumentAny,
+    ) {
+        let mut risk_engine =
+            get_risk_engine(Rc::new(RefCell::new(msgbus)), None, None, None, false);
+
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_audusd.id())
+            .side(OrderSide::Buy)
+            .price(Price::from_raw(100, 0))
+            .quantity(Quantity::from("1000"))
+            .build();
+
+        let random_event = OrderEventAny::Denied(OrderDenied::new(
+            order.trader_id(),
+            order.strategy_id(),
             order.instrument_id(),
             order.client_order_id(),
             Ustr::from("DENIED"),
@@ -2811,53 +4202,482 @@ mod tests {
             Decimal::from_i64(100000000).unwrap(),
         );
 
-        let order = OrderTestBuilder::new(OrderType::Market)
-            .instrument_id(instrument_xbtusd_bitmex.id())
-            .side(OrderSide::Buy)
-            .quantity(Quantity::from_str("10000001").unwrap())
-            .build();
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_xbtusd_bitmex.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from_str("10000001").unwrap())
+            .build();
+
+        let submit_order = SubmitOrder::new(
+            trader_id,
+            client_id_binance,
+            strategy_id_ema_cross,
+            instrument_xbtusd_bitmex.id(),
+            client_order_id,
+            venue_order_id,
+            order,
+            None,
+            None,
+            UUID4::new(),
+            risk_engine.clock.borrow().timestamp_ns(),
+        )
+        .unwrap();
+
+        risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+        let saved_process_messages =
+            get_process_order_event_handler_messages(process_order_event_handler);
+        assert_eq!(saved_process_messages.len(), 1);
+
+        assert_eq!(
+            saved_process_messages.first().unwrap().event_type(),
+            OrderEventType::Denied
+        );
+        assert_eq!(
+            saved_process_messages.first().unwrap().message().unwrap(),
+            Ustr::from("NOTIONAL_GREATER_THAN_MAX_FOR_INSTRUMENT: max_notional=Money(10000000.00, USD), notional=Money(10000001.00, USD)")
+        );
+    }
+
+    #[rstest]
+    fn test_submit_order_when_buy_market_order_and_over_max_notional_then_denies(
+        mut msgbus: MessageBus,
+        strategy_id_ema_cross: StrategyId,
+        client_id_binance: ClientId,
+        trader_id: TraderId,
+        client_order_id: ClientOrderId,
+        instrument_audusd: InstrumentAny,
+        venue_order_id: VenueOrderId,
+        process_order_event_handler: ShareableMessageHandler,
+        cash_account_state_million_usd: AccountState,
+        mut simple_cache: Cache,
+    ) {
+        msgbus.register(
+            msgbus.switchboard.exec_engine_process,
+            process_order_event_handler.clone(),
+        );
+
+        simple_cache
+            .add_instrument(instrument_audusd.clone())
+            .unwrap();
+
+        simple_cache
+            .add_account(AccountAny::Cash(cash_account(
+                cash_account_state_million_usd,
+            )))
+            .unwrap();
+
+        let quote = QuoteTick::new(
+            instrument_audusd.id(),
+            Price::from("0.75000"),
+            Price::from("0.75005"),
+            Quantity::from("500000"),
+            Quantity::from("500000"),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+
+        simple_cache.add_quote(quote).unwrap();
+
+        let mut risk_engine = get_risk_engine(
+            Rc::new(RefCell::new(msgbus)),
+            Some(Rc::new(RefCell::new(simple_cache))),
+            None,
+            None,
+            false,
+        );
+        risk_engine
+            .set_max_notional_per_order(instrument_audusd.id(), Decimal::from_i64(100000).unwrap());
+
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_audusd.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from_str("1000000").unwrap())
+            .build();
+
+        let submit_order = SubmitOrder::new(
+            trader_id,
+            client_id_binance,
+            strategy_id_ema_cross,
+            instrument_audusd.id(),
+            client_order_id,
+            venue_order_id,
+            order,
+            None,
+            None,
+            UUID4::new(),
+            risk_engine.clock.borrow().timestamp_ns(),
+        )
+        .unwrap();
+
+        risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+        let saved_process_messages =
+            get_process_order_event_handler_messages(process_order_event_handler);
+        assert_eq!(saved_process_messages.len(), 1);
+
+        assert_eq!(
+            saved_process_messages.first().unwrap().event_type(),
+            OrderEventType::Denied
+        );
+        assert_eq!(
+            saved_process_messages.first().unwrap().message().unwrap(),
+            Ustr::from("NOTIONAL_EXCEEDS_MAX_PER_ORDER: max_notional=Money(100000.00, USD), notional=Money(750050.00, USD)")
+        );
+    }
+
+    #[rstest]
+    fn test_submit_order_when_price_outside_strategy_collar_then_denies(
+        mut msgbus: MessageBus,
+        client_id_binance: ClientId,
+        trader_id: TraderId,
+        instrument_audusd: InstrumentAny,
+        venue_order_id: VenueOrderId,
+        process_order_event_handler: ShareableMessageHandler,
+        mut simple_cache: Cache,
+    ) {
+        msgbus.register(
+            msgbus.switchboard.exec_engine_process,
+            process_order_event_handler.clone(),
+        );
+
+        simple_cache
+            .add_instrument(instrument_audusd.clone())
+            .unwrap();
+
+        let quote = QuoteTick::new(
+            instrument_audusd.id(),
+            Price::from("0.75000"),
+            Price::from("0.75010"),
+            Quantity::from("500000"),
+            Quantity::from("500000"),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+
+        simple_cache.add_quote(quote).unwrap();
+
+        let mut risk_engine = get_risk_engine(
+            Rc::new(RefCell::new(msgbus)),
+            Some(Rc::new(RefCell::new(simple_cache))),
+            None,
+            None,
+            false,
+        );
+
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_audusd.id())
+            .side(OrderSide::Buy)
+            .price(Price::from("0.80000"))
+            .quantity(Quantity::from_str("1000").unwrap())
+            .build();
+        risk_engine.set_strategy_limits(
+            order.strategy_id(),
+            PreTradeLimits {
+                max_price_deviation: Some(Decimal::from_str("0.05").unwrap()),
+                ..Default::default()
+            },
+        );
+
+        let submit_order = SubmitOrder::new(
+            trader_id,
+            client_id_binance,
+            order.strategy_id(),
+            instrument_audusd.id(),
+            order.client_order_id(),
+            venue_order_id,
+            order.clone(),
+            None,
+            None,
+            UUID4::new(),
+            risk_engine.clock.borrow().timestamp_ns(),
+        )
+        .unwrap();
+
+        risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+        let saved_process_messages =
+            get_process_order_event_handler_messages(process_order_event_handler);
+        assert_eq!(saved_process_messages.len(), 1);
+
+        assert_eq!(
+            saved_process_messages.first().unwrap().event_type(),
+            OrderEventType::Denied
+        );
+        assert_eq!(
+            saved_process_messages.first().unwrap().message().unwrap(),
+            Ustr::from(&format!(
+                "PRICE_COLLAR: {} max_deviation=0.05, price=0.80000, reference_px=0.750050",
+                order.strategy_id()
+            ))
+        );
+    }
+
+    #[rstest]
+    fn test_reload_rules_replaces_active_rules_when_version_increases(
+        mut msgbus: MessageBus,
+        client_id_binance: ClientId,
+        trader_id: TraderId,
+        instrument_audusd: InstrumentAny,
+        venue_order_id: VenueOrderId,
+        process_order_event_handler: ShareableMessageHandler,
+        mut simple_cache: Cache,
+    ) {
+        msgbus.register(
+            msgbus.switchboard.exec_engine_process,
+            process_order_event_handler.clone(),
+        );
+
+        simple_cache
+            .add_instrument(instrument_audusd.clone())
+            .unwrap();
+
+        let mut risk_engine = get_risk_engine(
+            Rc::new(RefCell::new(msgbus)),
+            Some(Rc::new(RefCell::new(simple_cache))),
+            None,
+            None,
+            false,
+        );
+        let reload = |version: u64, max_notional: i64| {
+            RiskCommand::ReloadRules(ReloadRiskRules {
+                trader_id,
+                rules: RiskRuleSet {
+                    version,
+                    trader_limits: Some(PreTradeLimits {
+                        max_notional: Some(Decimal::from(max_notional)),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                command_id: UUID4::new(),
+                ts_init: UnixNanos::default(),
+            })
+        };
+
+        risk_engine.execute_risk(reload(2, 500));
+        // Stale versions are ignored
+        risk_engine.execute_risk(reload(1, 1_000_000));
+
+        let active_rules = risk_engine.active_rules();
+        assert_eq!(active_rules.version, 2);
+        assert_eq!(
+            active_rules.trader_limits.unwrap().max_notional,
+            Some(Decimal::from(500))
+        );
+
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_audusd.id())
+            .side(OrderSide::Buy)
+            .price(Price::from("0.80000"))
+            .quantity(Quantity::from_str("1000").unwrap())
+            .build();
+        let submit_order = SubmitOrder::new(
+            trader_id,
+            client_id_binance,
+            order.strategy_id(),
+            instrument_audusd.id(),
+            order.client_order_id(),
+            venue_order_id,
+            order,
+            None,
+            None,
+            UUID4::new(),
+            risk_engine.clock.borrow().timestamp_ns(),
+        )
+        .unwrap();
+
+        risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+        let saved_process_messages =
+            get_process_order_event_handler_messages(process_order_event_handler);
+        assert_eq!(saved_process_messages.len(), 1);
+        assert_eq!(
+            saved_process_messages.first().unwrap().event_type(),
+            OrderEventType::Denied
+        );
+        assert!(saved_process_messages
+            .first()
+            .unwrap()
+            .message()
+            .unwrap()
+            .starts_with(&format!("MAX_NOTIONAL: {trader_id}")));
+    }
+
+    #[rstest]
+    fn test_stress_test_reports_pnl_and_margin_impact(
+        msgbus: MessageBus,
+        instrument_audusd: InstrumentAny,
+        cash_account_state_million_usd: AccountState,
+        mut simple_cache: Cache,
+    ) {
+        simple_cache
+            .add_instrument(instrument_audusd.clone())
+            .unwrap();
+        simple_cache
+            .add_quote(QuoteTick::new(
+                instrument_audusd.id(),
+                Price::from("0.74995"),
+                Price::from("0.75005"),
+                Quantity::from("500000"),
+                Quantity::from("500000"),
+                UnixNanos::default(),
+                UnixNanos::default(),
+            ))
+            .unwrap();
+
+        let mut account = margin_account(cash_account_state_million_usd);
+        account.set_leverage(instrument_audusd.id(), 1.0);
+        simple_cache
+            .add_account(AccountAny::Margin(account))
+            .unwrap();
+
+        let entry = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_audusd.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("100000"))
+            .build();
+        let OrderEventAny::Filled(fill) = TestOrderEventStubs::order_filled(
+            &entry,
+            &instrument_audusd,
+            None,
+            Some(PositionId::from("P-1")),
+            Some(Price::from("0.75000")),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ) else {
+            unreachable!()
+        };
+        simple_cache
+            .add_position(Position::new(&instrument_audusd, fill), OmsType::Netting)
+            .unwrap();
+
+        let risk_engine = get_risk_engine(
+            Rc::new(RefCell::new(msgbus)),
+            Some(Rc::new(RefCell::new(simple_cache))),
+            None,
+            None,
+            false,
+        );
+
+        let report = risk_engine.stress_test(&StressScenario {
+            name: "AUD_CRASH".to_string(),
+            shocks: vec![Shock::Price {
+                instrument_id: Some(instrument_audusd.id()),
+                change: -0.1,
+            }],
+        });
+
+        assert_eq!(report.scenario, "AUD_CRASH");
+        assert_eq!(
+            report.pnl[&Currency::USD()],
+            Money::new(-7_500.0, Currency::USD())
+        );
+        assert_eq!(
+            report.position_pnls[&PositionId::from("P-1")],
+            Money::new(-7_500.0, Currency::USD())
+        );
+        // Initial margin at 3% plus 2x taker fee of the 75_000 and 67_500 notional values
+        assert_eq!(
+            report.margin_init[&Currency::USD()],
+            Money::new(2_253.0, Currency::USD())
+        );
+        assert_eq!(
+            report.margin_impact(&Currency::USD()),
+            Some(Money::new(-225.3, Currency::USD()))
+        );
+    }
+
+    #[rstest]
+    fn test_audit_trail_records_decisions_with_rules(
+        msgbus: MessageBus,
+        client_id_binance: ClientId,
+        trader_id: TraderId,
+        instrument_audusd: InstrumentAny,
+        venue_order_id: VenueOrderId,
+        mut simple_cache: Cache,
+    ) {
+        simple_cache
+            .add_instrument(instrument_audusd.clone())
+            .unwrap();
+
+        let config = RiskEngineConfig {
+            audit_trail: true,
+            ..Default::default()
+        };
+        let mut risk_engine = get_risk_engine(
+            Rc::new(RefCell::new(msgbus)),
+            Some(Rc::new(RefCell::new(simple_cache))),
+            Some(config),
+            None,
+            false,
+        );
+        risk_engine.set_trader_limits(PreTradeLimits {
+            max_notional: Some(Decimal::from(10_000)),
+            ..Default::default()
+        });
+
+        let mut submit = |client_order_id: &str, quantity: &str| {
+            let order = OrderTestBuilder::new(OrderType::Limit)
+                .client_order_id(ClientOrderId::from(client_order_id))
+                .instrument_id(instrument_audusd.id())
+                .side(OrderSide::Buy)
+                .price(Price::from("0.75000"))
+                .quantity(Quantity::from(quantity))
+                .build();
+            let client_order_id = order.client_order_id();
+            let submit_order = SubmitOrder::new(
+                trader_id,
+                client_id_binance,
+                order.strategy_id(),
+                instrument_audusd.id(),
+                client_order_id,
+                venue_order_id,
+                order,
+                None,
+                None,
+                UUID4::new(),
+                UnixNanos::default(),
+            )
+            .unwrap();
+            risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+            client_order_id
+        };
+        let approved = submit("O-1", "1000");
+        let denied = submit("O-2", "100000");
+        risk_engine.set_trading_state(TradingState::Halted);
 
-        let submit_order = SubmitOrder::new(
-            trader_id,
-            client_id_binance,
-            strategy_id_ema_cross,
-            instrument_xbtusd_bitmex.id(),
-            client_order_id,
-            venue_order_id,
-            order,
-            None,
-            None,
-            UUID4::new(),
-            risk_engine.clock.borrow().timestamp_ns(),
-        )
-        .unwrap();
+        let records = risk_engine.audit_records(&RiskAuditFilter::default());
+        assert_eq!(records.len(), 3);
 
-        risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
-        let saved_process_messages =
-            get_process_order_event_handler_messages(process_order_event_handler);
-        assert_eq!(saved_process_messages.len(), 1);
+        let denials = risk_engine.audit_records(&RiskAuditFilter {
+            client_order_id: Some(denied),
+            ..Default::default()
+        });
+        assert_eq!(denials.len(), 1);
+        assert_eq!(denials[0].decision, RiskDecision::Denied);
+        assert_eq!(denials[0].rule.as_deref(), Some("MAX_NOTIONAL"));
 
-        assert_eq!(
-            saved_process_messages.first().unwrap().event_type(),
-            OrderEventType::Denied
-        );
-        assert_eq!(
-            saved_process_messages.first().unwrap().message().unwrap(),
-            Ustr::from("NOTIONAL_GREATER_THAN_MAX_FOR_INSTRUMENT: max_notional=Money(10000000.00, USD), notional=Money(10000001.00, USD)")
-        );
+        let approvals = risk_engine.audit_records(&RiskAuditFilter {
+            decision: Some(RiskDecision::Approved),
+            ..Default::default()
+        });
+        assert_eq!(approvals.len(), 1);
+        assert_eq!(approvals[0].client_order_id, Some(approved));
+
+        let transitions = risk_engine.audit_records(&RiskAuditFilter {
+            decision: Some(RiskDecision::TradingStateChanged),
+            ..Default::default()
+        });
+        assert_eq!(transitions[0].reason.as_deref(), Some("ACTIVE -> HALTED"));
     }
 
     #[rstest]
-    fn test_submit_order_when_buy_market_order_and_over_max_notional_then_denies(
+    fn test_submit_order_when_group_exposure_exceeded_across_instruments_then_denies(
         mut msgbus: MessageBus,
-        strategy_id_ema_cross: StrategyId,
         client_id_binance: ClientId,
         trader_id: TraderId,
-        client_order_id: ClientOrderId,
-        instrument_audusd: InstrumentAny,
         venue_order_id: VenueOrderId,
         process_order_event_handler: ShareableMessageHandler,
-        cash_account_state_million_usd: AccountState,
         mut simple_cache: Cache,
     ) {
         msgbus.register(
@@ -2865,71 +4685,93 @@ mod tests {
             process_order_event_handler.clone(),
         );
 
+        let spot = InstrumentAny::CurrencyPair(currency_pair_btcusdt());
+        let future = InstrumentAny::CryptoFuture(crypto_future_btcusdt(
+            2,
+            6,
+            Price::from("0.01"),
+            Quantity::from("0.000001"),
+        ));
+        simple_cache.add_instrument(spot.clone()).unwrap();
+        simple_cache.add_instrument(future.clone()).unwrap();
         simple_cache
-            .add_instrument(instrument_audusd.clone())
+            .add_quote(QuoteTick::new(
+                spot.id(),
+                Price::from("49999.00"),
+                Price::from("50001.00"),
+                Quantity::from("1.000000"),
+                Quantity::from("1.000000"),
+                UnixNanos::default(),
+                UnixNanos::default(),
+            ))
             .unwrap();
 
+        // Long 1 BTC spot, valued at the mid
+        let entry = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(spot.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("1.000000"))
+            .build();
+        let OrderEventAny::Filled(fill) = TestOrderEventStubs::order_filled(
+            &entry, &spot, None, None, None, None, None, None, None, None,
+        ) else {
+            unreachable!()
+        };
         simple_cache
-            .add_account(AccountAny::Cash(cash_account(
-                cash_account_state_million_usd,
-            )))
+            .add_position(Position::new(&spot, fill), OmsType::Netting)
             .unwrap();
 
-        let quote = QuoteTick::new(
-            instrument_audusd.id(),
-            Price::from("0.75000"),
-            Price::from("0.75005"),
-            Quantity::from("500000"),
-            Quantity::from("500000"),
-            UnixNanos::default(),
-            UnixNanos::default(),
-        );
-
-        simple_cache.add_quote(quote).unwrap();
-
+        let config = RiskEngineConfig {
+            exposure_groups: vec![ExposureGroup {
+                name: "BTC".to_string(),
+                instruments: HashMap::from([(spot.id(), 1.0), (future.id(), 1.0)]),
+                currency: Currency::USDT(),
+                max_net_exposure: 100_000.0,
+            }],
+            ..Default::default()
+        };
         let mut risk_engine = get_risk_engine(
             Rc::new(RefCell::new(msgbus)),
             Some(Rc::new(RefCell::new(simple_cache))),
-            None,
+            Some(config),
             None,
             false,
         );
-        risk_engine
-            .set_max_notional_per_order(instrument_audusd.id(), Decimal::from_i64(100000).unwrap());
 
-        let order = OrderTestBuilder::new(OrderType::Market)
-            .instrument_id(instrument_audusd.id())
-            .side(OrderSide::Buy)
-            .quantity(Quantity::from_str("1000000").unwrap())
-            .build();
+        let submit = |quantity: &str| {
+            let order = OrderTestBuilder::new(OrderType::Limit)
+                .instrument_id(future.id())
+                .side(OrderSide::Buy)
+                .price(Price::from("50000.00"))
+                .quantity(Quantity::from(quantity))
+                .build();
+            SubmitOrder::new(
+                trader_id,
+                client_id_binance,
+                order.strategy_id(),
+                future.id(),
+                order.client_order_id(),
+                venue_order_id,
+                order,
+                None,
+                None,
+                UUID4::new(),
+                UnixNanos::default(),
+            )
+            .unwrap()
+        };
 
-        let submit_order = SubmitOrder::new(
-            trader_id,
-            client_id_binance,
-            strategy_id_ema_cross,
-            instrument_audusd.id(),
-            client_order_id,
-            venue_order_id,
-            order,
-            None,
-            None,
-            UUID4::new(),
-            risk_engine.clock.borrow().timestamp_ns(),
-        )
-        .unwrap();
+        risk_engine.execute(TradingCommand::SubmitOrder(submit("0.500000")));
+        risk_engine.execute(TradingCommand::SubmitOrder(submit("1.500000")));
 
-        risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
         let saved_process_messages =
             get_process_order_event_handler_messages(process_order_event_handler);
         assert_eq!(saved_process_messages.len(), 1);
-
-        assert_eq!(
-            saved_process_messages.first().unwrap().event_type(),
-            OrderEventType::Denied
-        );
         assert_eq!(
             saved_process_messages.first().unwrap().message().unwrap(),
-            Ustr::from("NOTIONAL_EXCEEDS_MAX_PER_ORDER: max_notional=Money(100000.00, USD), notional=Money(750050.00, USD)")
+            Ustr::from(
+                "MAX_GROUP_EXPOSURE: BTC strategy_id=S-001, max_net_exposure=100000.00 USDT, projected_exposure=125000.00 USDT"
+            )
         );
     }
 
@@ -4501,7 +6343,180 @@ mod tests {
         risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
         let saved_process_messages =
             get_process_order_event_handler_messages(process_order_event_handler);
-        assert_eq!(saved_process_messages.len(), 0); // Currently, it executes because check_orders_risk returns true for margin_account
+        assert_eq!(saved_process_messages.len(), 0); // Initial margin is within the free balance
+    }
+
+    #[rstest]
+    #[case(1.0, true)]
+    #[case(10.0, false)]
+    fn test_submit_order_when_initial_margin_over_free_balance_then_denies(
+        mut msgbus: MessageBus,
+        client_id_binance: ClientId,
+        trader_id: TraderId,
+        instrument_audusd: InstrumentAny,
+        venue_order_id: VenueOrderId,
+        process_order_event_handler: ShareableMessageHandler,
+        cash_account_state_million_usd: AccountState,
+        mut simple_cache: Cache,
+        #[case] leverage: f64,
+        #[case] expected_denied: bool,
+    ) {
+        msgbus.register(
+            msgbus.switchboard.exec_engine_process,
+            process_order_event_handler.clone(),
+        );
+
+        simple_cache
+            .add_instrument(instrument_audusd.clone())
+            .unwrap();
+
+        let mut account = margin_account(cash_account_state_million_usd);
+        account.set_leverage(instrument_audusd.id(), leverage);
+        simple_cache
+            .add_account(AccountAny::Margin(account))
+            .unwrap();
+
+        let mut risk_engine = get_risk_engine(
+            Rc::new(RefCell::new(msgbus)),
+            Some(Rc::new(RefCell::new(simple_cache))),
+            None,
+            None,
+            false,
+        );
+
+        // Initial margin of 40M notional at 3% margin and 2x taker fee, divided by leverage
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_audusd.id())
+            .side(OrderSide::Buy)
+            .price(Price::from("40.00000"))
+            .quantity(Quantity::from(1_000_000))
+            .build();
+
+        let submit_order = SubmitOrder::new(
+            trader_id,
+            client_id_binance,
+            order.strategy_id(),
+            instrument_audusd.id(),
+            order.client_order_id(),
+            venue_order_id,
+            order,
+            None,
+            None,
+            UUID4::new(),
+            risk_engine.clock.borrow().timestamp_ns(),
+        )
+        .unwrap();
+
+        risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+        let saved_process_messages =
+            get_process_order_event_handler_messages(process_order_event_handler);
+
+        if expected_denied {
+            assert_eq!(saved_process_messages.len(), 1);
+            assert_eq!(
+                saved_process_messages.first().unwrap().message().unwrap(),
+                Ustr::from(
+                    "MARGIN_EXCEEDS_FREE_BALANCE: free=1000000.00 USD, margin_init=1201600.00 USD"
+                )
+            );
+        } else {
+            assert_eq!(saved_process_messages.len(), 0);
+        }
+    }
+
+    #[rstest]
+    fn test_submit_order_when_cost_exceeds_balance_net_of_locks_then_denies_until_released(
+        mut msgbus: MessageBus,
+        client_id_binance: ClientId,
+        trader_id: TraderId,
+        instrument_audusd: InstrumentAny,
+        venue_order_id: VenueOrderId,
+        process_order_event_handler: ShareableMessageHandler,
+        cash_account_state_million_usd: AccountState,
+        mut simple_cache: Cache,
+    ) {
+        msgbus.register(
+            msgbus.switchboard.exec_engine_process,
+            process_order_event_handler.clone(),
+        );
+
+        simple_cache
+            .add_instrument(instrument_audusd.clone())
+            .unwrap();
+        simple_cache
+            .add_account(AccountAny::Cash(cash_account(
+                cash_account_state_million_usd,
+            )))
+            .unwrap();
+
+        let config = RiskEngineConfig {
+            lock_order_balances: true,
+            ..Default::default()
+        };
+        let mut risk_engine = get_risk_engine(
+            Rc::new(RefCell::new(msgbus)),
+            Some(Rc::new(RefCell::new(simple_cache))),
+            Some(config),
+            None,
+            false,
+        );
+
+        // Each order costs 600_000 USD plus the taker fee of 12 USD
+        let submit = |risk_engine: &mut RiskEngine| {
+            let order = OrderTestBuilder::new(OrderType::Limit)
+                .instrument_id(instrument_audusd.id())
+                .side(OrderSide::Buy)
+                .price(Price::from("0.60000"))
+                .quantity(Quantity::from(1_000_000))
+                .build();
+            let client_order_id = order.client_order_id();
+            let submit_order = SubmitOrder::new(
+                trader_id,
+                client_id_binance,
+                order.strategy_id(),
+                instrument_audusd.id(),
+                client_order_id,
+                venue_order_id,
+                order,
+                None,
+                None,
+                UUID4::new(),
+                UnixNanos::default(),
+            )
+            .unwrap();
+            risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+            client_order_id
+        };
+
+        let first = submit(&mut risk_engine);
+        submit(&mut risk_engine);
+
+        let saved_process_messages =
+            get_process_order_event_handler_messages(process_order_event_handler.clone());
+        assert_eq!(saved_process_messages.len(), 1);
+        assert_eq!(
+            saved_process_messages.first().unwrap().message().unwrap(),
+            Ustr::from("COST_EXCEEDS_FREE_BALANCE: free=399988.00 USD, cost=600012.00 USD")
+        );
+
+        // Canceling the first order releases its lock
+        risk_engine.process(OrderEventAny::Canceled(OrderCanceled::new(
+            trader_id,
+            StrategyId::from("S-001"),
+            instrument_audusd.id(),
+            first,
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            false,
+            None,
+            None,
+        )));
+        submit(&mut risk_engine);
+
+        let saved_process_messages =
+            get_process_order_event_handler_messages(process_order_event_handler);
+        assert_eq!(saved_process_messages.len(), 1);
     }
 
     #[rstest]
@@ -0,0 +1,157 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Declarative, versioned risk rule sets which can be reloaded at runtime.
+
+use std::{collections::HashMap, fmt::Display};
+
+use nautilus_core::{UnixNanos, UUID4};
+use nautilus_model::identifiers::{InstrumentId, StrategyId, TraderId};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::limits::PreTradeLimits;
+
+/// The pre-trade thresholds per trader, strategy and instrument, where absent entries disable
+/// the checks of their scope.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RiskRuleSet {
+    /// The version of the rule set, which must increase with each reload.
+    pub version: u64,
+    /// The limits applied to all orders of the trader.
+    #[serde(default)]
+    pub trader_limits: Option<PreTradeLimits>,
+    /// The limits applied to the orders of each strategy.
+    #[serde(default)]
+    pub strategy_limits: HashMap<StrategyId, PreTradeLimits>,
+    /// The limits applied to the orders of each instrument.
+    #[serde(default)]
+    pub instrument_limits: HashMap<InstrumentId, PreTradeLimits>,
+    /// The maximum notional value of an order per instrument.
+    #[serde(default)]
+    pub max_notional_per_order: HashMap<InstrumentId, Decimal>,
+}
+
+impl RiskRuleSet {
+    /// Parses a rule set from its JSON declaration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON is not a valid rule set.
+    pub fn from_json(json: &[u8]) -> anyhow::Result<Self> {
+        Ok(serde_json::from_slice(json)?)
+    }
+
+    /// Serializes the rule set to its JSON declaration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_json(&self) -> anyhow::Result<Vec<u8>> {
+        Ok(serde_json::to_vec(self)?)
+    }
+}
+
+/// Represents a command to replace the active risk rule set.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReloadRiskRules {
+    pub trader_id: TraderId,
+    pub rules: RiskRuleSet,
+    pub command_id: UUID4,
+    pub ts_init: UnixNanos,
+}
+
+/// Represents an event where the active risk rule set was replaced.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RiskRulesReloaded {
+    pub trader_id: TraderId,
+    pub version: u64,
+    pub previous_version: u64,
+    pub event_id: UUID4,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl Display for RiskRulesReloaded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}(version={}, previous_version={})",
+            stringify!(RiskRulesReloaded),
+            self.version,
+            self.previous_version,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use nautilus_model::types::Quantity;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_from_json_with_defaults() {
+        let json = br#"{
+            "version": 2,
+            "strategy_limits": {
+                "S-001": {"max_notional": "50000", "max_price_deviation": "0.05"}
+            },
+            "instrument_limits": {
+                "AUD/USD.SIM": {"max_position": "1000000"}
+            }
+        }"#;
+
+        let rules = RiskRuleSet::from_json(json).unwrap();
+
+        assert_eq!(rules.version, 2);
+        assert_eq!(rules.trader_limits, None);
+        assert!(rules.max_notional_per_order.is_empty());
+        assert_eq!(
+            rules.strategy_limits[&StrategyId::from("S-001")],
+            PreTradeLimits {
+                max_notional: Some(Decimal::from(50_000)),
+                max_price_deviation: Some(Decimal::from_str("0.05").unwrap()),
+                max_position: None,
+            }
+        );
+        assert_eq!(
+            rules.instrument_limits[&InstrumentId::from("AUD/USD.SIM")].max_position,
+            Some(Quantity::from(1_000_000))
+        );
+    }
+
+    #[rstest]
+    fn test_json_round_trip() {
+        let rules = RiskRuleSet {
+            version: 1,
+            trader_limits: Some(PreTradeLimits {
+                max_notional: Some(Decimal::from(1_000_000)),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let json = rules.to_json().unwrap();
+
+        assert_eq!(RiskRuleSet::from_json(&json).unwrap(), rules);
+    }
+}
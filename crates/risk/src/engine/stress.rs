@@ -0,0 +1,446 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Scenario-based stress testing of open positions.
+
+use std::{collections::HashMap, fmt::Display};
+
+use nautilus_common::cache::Cache;
+use nautilus_core::{UnixNanos, UUID4};
+use nautilus_model::{
+    accounts::{AccountAny, MarginModel},
+    data::GreeksData,
+    identifiers::{InstrumentId, PositionId, TraderId, Venue},
+    instruments::InstrumentAny,
+    position::Position,
+    types::{Currency, Money, Price},
+};
+use serde::{Deserialize, Serialize};
+
+use super::RiskEngine;
+
+/// A hypothetical shock to market conditions, applying to the given instrument or to all
+/// instruments when `instrument_id` is `None`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Shock {
+    /// Moves prices by `change` as a fraction (e.g. -0.1 for a 10% drop). For options with
+    /// greeks the move applies to the underlying price.
+    Price {
+        instrument_id: Option<InstrumentId>,
+        change: f64,
+    },
+    /// Shifts the implied volatility of options by `shift` percentage points.
+    Volatility {
+        instrument_id: Option<InstrumentId>,
+        shift: f64,
+    },
+    /// Charges perpetual positions funding at `rate` of their notional value, where a positive
+    /// rate is paid by longs to shorts.
+    Funding {
+        instrument_id: Option<InstrumentId>,
+        rate: f64,
+    },
+}
+
+impl Shock {
+    fn applies_to(&self, instrument_id: &InstrumentId) -> bool {
+        let target = match self {
+            Self::Price { instrument_id, .. }
+            | Self::Volatility { instrument_id, .. }
+            | Self::Funding { instrument_id, .. } => instrument_id,
+        };
+        target.is_none_or(|target| target == *instrument_id)
+    }
+}
+
+/// A named set of shocks applied together to the open positions.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StressScenario {
+    pub name: String,
+    pub shocks: Vec<Shock>,
+}
+
+impl StressScenario {
+    /// Returns the compounded price change of the instrument under the scenario.
+    #[must_use]
+    pub fn price_change(&self, instrument_id: &InstrumentId) -> f64 {
+        self.shocks
+            .iter()
+            .filter(|shock| shock.applies_to(instrument_id))
+            .fold(1.0, |factor, shock| match shock {
+                Shock::Price { change, .. } => factor * (1.0 + change),
+                _ => factor,
+            })
+            - 1.0
+    }
+
+    /// Returns the total volatility shift (percentage points) of the instrument.
+    #[must_use]
+    pub fn vol_shift(&self, instrument_id: &InstrumentId) -> f64 {
+        self.shocks
+            .iter()
+            .filter(|shock| shock.applies_to(instrument_id))
+            .map(|shock| match shock {
+                Shock::Volatility { shift, .. } => *shift,
+                _ => 0.0,
+            })
+            .sum()
+    }
+
+    /// Returns the total funding rate charged to the instrument.
+    #[must_use]
+    pub fn funding_rate(&self, instrument_id: &InstrumentId) -> f64 {
+        self.shocks
+            .iter()
+            .filter(|shock| shock.applies_to(instrument_id))
+            .map(|shock| match shock {
+                Shock::Funding { rate, .. } => *rate,
+                _ => 0.0,
+            })
+            .sum()
+    }
+
+    /// Returns the hypothetical PnL of the `position` at `price` under the scenario, along with
+    /// the shocked price.
+    ///
+    /// Options with `greeks` are revalued from their delta, gamma and vega, otherwise the option
+    /// price itself is shocked and volatility shifts have no effect.
+    #[must_use]
+    pub fn position_pnl(
+        &self,
+        instrument: &InstrumentAny,
+        position: &Position,
+        price: Price,
+        greeks: Option<&GreeksData>,
+    ) -> (Money, Price) {
+        let instrument_id = instrument.id();
+        let change = self.price_change(&instrument_id);
+
+        let (pnl, shocked_price) = if let Some(greeks) = greeks {
+            let underlying_move = greeks.underlying_price * change;
+            let price_move = greeks.delta * underlying_move
+                + 0.5 * greeks.gamma * underlying_move.powi(2)
+                + greeks.vega * self.vol_shift(&instrument_id);
+            let multiplier = instrument.multiplier().as_f64();
+            let shocked_price = (price.as_f64() + price_move / multiplier).max(0.0);
+            (
+                position.signed_qty * price_move,
+                Price::new(shocked_price, instrument.price_precision()),
+            )
+        } else {
+            let shocked_price = Price::new(
+                (price.as_f64() * (1.0 + change)).max(0.0),
+                instrument.price_precision(),
+            );
+            let pnl = position.unrealized_pnl(shocked_price).as_f64()
+                - position.unrealized_pnl(price).as_f64();
+            (pnl, shocked_price)
+        };
+
+        let funding = if matches!(instrument, InstrumentAny::CryptoPerpetual(_)) {
+            let notional = instrument
+                .calculate_notional_value(position.quantity, shocked_price, None)
+                .as_f64();
+            notional * position.signed_qty.signum() * self.funding_rate(&instrument_id)
+        } else {
+            0.0
+        };
+
+        (
+            Money::new(pnl - funding, position.settlement_currency),
+            shocked_price,
+        )
+    }
+}
+
+/// Represents the hypothetical impact of a stress scenario on the open positions.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StressTestReport {
+    pub trader_id: TraderId,
+    pub scenario: String,
+    /// The hypothetical PnL per settlement currency.
+    pub pnl: HashMap<Currency, Money>,
+    /// The hypothetical PnL per position.
+    pub position_pnls: HashMap<PositionId, Money>,
+    /// The initial margin of the positions at margin accounts at current prices.
+    pub margin_init: HashMap<Currency, Money>,
+    /// The initial margin of the positions at margin accounts at shocked prices.
+    pub stressed_margin_init: HashMap<Currency, Money>,
+    pub report_id: UUID4,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl StressTestReport {
+    /// Returns the change in initial margin for the `currency` under the scenario.
+    #[must_use]
+    pub fn margin_impact(&self, currency: &Currency) -> Option<Money> {
+        let stressed = self.stressed_margin_init.get(currency)?;
+        let current = self.margin_init.get(currency)?;
+        Some(*stressed - *current)
+    }
+}
+
+impl Display for StressTestReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut pnl: Vec<String> = self.pnl.values().map(Money::to_string).collect();
+        pnl.sort();
+        write!(
+            f,
+            "{}(scenario={}, positions={}, pnl=[{}])",
+            stringify!(StressTestReport),
+            self.scenario,
+            self.position_pnls.len(),
+            pnl.join(", "),
+        )
+    }
+}
+
+/// Runs stress scenarios against the open positions held in the cache.
+#[derive(Clone, Debug, Default)]
+pub struct StressTester {
+    margin_models: HashMap<Venue, MarginModel>,
+    greeks: HashMap<InstrumentId, GreeksData>,
+}
+
+impl StressTester {
+    /// Creates a new [`StressTester`] instance.
+    #[must_use]
+    pub fn new(margin_models: HashMap<Venue, MarginModel>) -> Self {
+        Self {
+            margin_models,
+            greeks: HashMap::new(),
+        }
+    }
+
+    /// Updates the latest greeks used to revalue option positions.
+    pub fn update_greeks(&mut self, greeks: GreeksData) {
+        self.greeks.insert(greeks.instrument_id, greeks);
+    }
+
+    /// Applies the `scenario` to the open positions in the `cache`, where positions without
+    /// an instrument or price are skipped.
+    #[must_use]
+    pub fn run(
+        &self,
+        cache: &Cache,
+        scenario: &StressScenario,
+        trader_id: TraderId,
+        ts_now: UnixNanos,
+    ) -> StressTestReport {
+        let mut report = StressTestReport {
+            trader_id,
+            scenario: scenario.name.clone(),
+            pnl: HashMap::new(),
+            position_pnls: HashMap::new(),
+            margin_init: HashMap::new(),
+            stressed_margin_init: HashMap::new(),
+            report_id: UUID4::new(),
+            ts_event: ts_now,
+            ts_init: ts_now,
+        };
+
+        for position in cache.positions_open(None, None, None, None) {
+            let Some(instrument) = cache.instrument(&position.instrument_id) else {
+                log::warn!(
+                    "Cannot stress position {}: no instrument for {}",
+                    position.id,
+                    position.instrument_id
+                );
+                continue;
+            };
+            let Some(price) = RiskEngine::last_price(cache, &position.instrument_id) else {
+                log::warn!(
+                    "Cannot stress position {}: no prices for {}",
+                    position.id,
+                    position.instrument_id
+                );
+                continue;
+            };
+
+            let greeks = self.greeks.get(&position.instrument_id);
+            let (pnl, shocked_price) = scenario.position_pnl(instrument, position, price, greeks);
+            report.position_pnls.insert(position.id, pnl);
+            add(&mut report.pnl, pnl);
+
+            let venue = position.instrument_id.venue;
+            if let Some(AccountAny::Margin(margin_account)) = cache.account_for_venue(&venue) {
+                let margin_model = self.margin_models.get(&venue).cloned().unwrap_or_default();
+                let leverage = margin_account.get_leverage(&position.instrument_id);
+                let margin = |price| {
+                    margin_model
+                        .calculate(
+                            instrument,
+                            position.side,
+                            position.quantity,
+                            price,
+                            leverage,
+                            None,
+                        )
+                        .initial
+                };
+                add(&mut report.margin_init, margin(price));
+                add(&mut report.stressed_margin_init, margin(shocked_price));
+            }
+        }
+
+        report
+    }
+}
+
+fn add(totals: &mut HashMap<Currency, Money>, amount: Money) {
+    totals
+        .entry(amount.currency)
+        .and_modify(|total| *total += amount)
+        .or_insert(amount);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::greeks::ImplyVolAndGreeksResult,
+        enums::{OrderSide, OrderType},
+        events::OrderEventAny,
+        instruments::{
+            stubs::{crypto_perpetual_ethusdt, option_contract_appl},
+            CryptoPerpetual, OptionContract,
+        },
+        orders::{stubs::TestOrderEventStubs, OrderTestBuilder},
+        types::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn position(instrument: &InstrumentAny, side: OrderSide, quantity: &str, px: &str) -> Position {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(side)
+            .quantity(Quantity::from(quantity))
+            .build();
+        let OrderEventAny::Filled(fill) = TestOrderEventStubs::order_filled(
+            &order,
+            instrument,
+            None,
+            Some(PositionId::from("P-1")),
+            Some(Price::from(px)),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ) else {
+            unreachable!()
+        };
+        Position::new(instrument, fill)
+    }
+
+    #[rstest]
+    fn test_shocks_compound_for_targeted_instruments() {
+        let eth = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let scenario = StressScenario {
+            name: "CRYPTO_CRASH".to_string(),
+            shocks: vec![
+                Shock::Price {
+                    instrument_id: None,
+                    change: -0.1,
+                },
+                Shock::Price {
+                    instrument_id: Some(eth),
+                    change: -0.1,
+                },
+                Shock::Funding {
+                    instrument_id: Some(eth),
+                    rate: 0.01,
+                },
+            ],
+        };
+
+        assert!((scenario.price_change(&eth) + 0.19).abs() < 1e-9);
+        assert!((scenario.price_change(&InstrumentId::from("AUD/USD.SIM")) + 0.1).abs() < 1e-9);
+        assert_eq!(scenario.funding_rate(&eth), 0.01);
+        assert_eq!(scenario.vol_shift(&eth), 0.0);
+    }
+
+    #[rstest]
+    fn test_perpetual_position_pnl_includes_funding(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let position = position(&instrument, OrderSide::Buy, "10.000", "2000.00");
+        let scenario = StressScenario {
+            name: "FUNDING_SPIKE".to_string(),
+            shocks: vec![
+                Shock::Price {
+                    instrument_id: None,
+                    change: -0.1,
+                },
+                Shock::Funding {
+                    instrument_id: None,
+                    rate: 0.01,
+                },
+            ],
+        };
+
+        let (pnl, shocked_price) =
+            scenario.position_pnl(&instrument, &position, Price::from("2000.00"), None);
+
+        // Price loss of 2_000 and funding of 1% on the 18_000 shocked notional
+        assert_eq!(shocked_price, Price::from("1800.00"));
+        assert_eq!(pnl, Money::new(-2_180.0, Currency::USDT()));
+    }
+
+    #[rstest]
+    fn test_option_position_pnl_from_greeks(option_contract_appl: OptionContract) {
+        let instrument = InstrumentAny::OptionContract(option_contract_appl);
+        let position = position(&instrument, OrderSide::Buy, "10", "5.00");
+        let greeks = GreeksData::new(
+            instrument.id(),
+            150.0,
+            0.05,
+            0.5,
+            ImplyVolAndGreeksResult {
+                vol: 0.2,
+                price: 5.0,
+                delta: 0.5,
+                gamma: 0.02,
+                vega: 0.3,
+                theta: -0.01,
+            },
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+        let scenario = StressScenario {
+            name: "VOL_SPIKE".to_string(),
+            shocks: vec![
+                Shock::Price {
+                    instrument_id: None,
+                    change: -0.1,
+                },
+                Shock::Volatility {
+                    instrument_id: None,
+                    shift: 5.0,
+                },
+            ],
+        };
+
+        let (pnl, _) =
+            scenario.position_pnl(&instrument, &position, Price::from("5.00"), Some(&greeks));
+
+        // Underlying move of -15 gives -7.50 delta, 2.25 gamma and 1.50 vega PnL per contract
+        assert_eq!(pnl, Money::new(-37.5, Currency::USD()));
+    }
+}
@@ -0,0 +1,216 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+use arrow::{
+    array::{Array, StringArray, StringBuilder, UInt32Array, UInt64Array, UInt8Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use nautilus_model::{
+    data::CorporateAction, enums::CorporateActionType, identifiers::InstrumentId, types::Money,
+};
+
+use super::{extract_column, EncodingError, KEY_INSTRUMENT_ID};
+use crate::arrow::{ArrowSchemaProvider, EncodeToRecordBatch};
+
+impl ArrowSchemaProvider for CorporateAction {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new("action_type", DataType::UInt8, false),
+            Field::new("split_to", DataType::UInt32, false),
+            Field::new("split_from", DataType::UInt32, false),
+            Field::new("dividend", DataType::Utf8, true),
+            Field::new("new_instrument_id", DataType::Utf8, true),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+fn parse_metadata(metadata: &HashMap<String, String>) -> Result<InstrumentId, EncodingError> {
+    let instrument_id_str = metadata
+        .get(KEY_INSTRUMENT_ID)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_INSTRUMENT_ID))?;
+    InstrumentId::from_str(instrument_id_str)
+        .map_err(|e| EncodingError::ParseError(KEY_INSTRUMENT_ID, e.to_string()))
+}
+
+impl EncodeToRecordBatch for CorporateAction {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut action_type_builder = UInt8Array::builder(data.len());
+        let mut split_to_builder = UInt32Array::builder(data.len());
+        let mut split_from_builder = UInt32Array::builder(data.len());
+        let mut dividend_builder = StringBuilder::new();
+        let mut new_instrument_id_builder = StringBuilder::new();
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for action in data {
+            action_type_builder.append_value(action.action_type as u8);
+            split_to_builder.append_value(action.split_to);
+            split_from_builder.append_value(action.split_from);
+            dividend_builder.append_option(action.dividend.map(|d| d.to_string()));
+            new_instrument_id_builder
+                .append_option(action.new_instrument_id.map(|id| id.to_string()));
+            ts_event_builder.append_value(action.ts_event.as_u64());
+            ts_init_builder.append_value(action.ts_init.as_u64());
+        }
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(action_type_builder.finish()),
+                Arc::new(split_to_builder.finish()),
+                Arc::new(split_from_builder.finish()),
+                Arc::new(dividend_builder.finish()),
+                Arc::new(new_instrument_id_builder.finish()),
+                Arc::new(ts_event_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+    }
+
+    fn metadata(&self) -> HashMap<String, String> {
+        CorporateAction::get_metadata(&self.instrument_id)
+    }
+}
+
+/// Decodes a `RecordBatch` of corporate actions encoded with [`EncodeToRecordBatch`].
+///
+/// Corporate actions are not market data, so are decoded directly rather than into [`Data`].
+///
+/// [`Data`]: nautilus_model::data::Data
+pub fn decode_corporate_action_batch(
+    metadata: &HashMap<String, String>,
+    record_batch: &RecordBatch,
+) -> Result<Vec<CorporateAction>, EncodingError> {
+    let instrument_id = parse_metadata(metadata)?;
+    let cols = record_batch.columns();
+
+    let action_type_values = extract_column::<UInt8Array>(cols, "action_type", 0, DataType::UInt8)?;
+    let split_to_values = extract_column::<UInt32Array>(cols, "split_to", 1, DataType::UInt32)?;
+    let split_from_values = extract_column::<UInt32Array>(cols, "split_from", 2, DataType::UInt32)?;
+    let dividend_values = extract_column::<StringArray>(cols, "dividend", 3, DataType::Utf8)?;
+    let new_instrument_id_values =
+        extract_column::<StringArray>(cols, "new_instrument_id", 4, DataType::Utf8)?;
+    let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 5, DataType::UInt64)?;
+    let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 6, DataType::UInt64)?;
+
+    (0..record_batch.num_rows())
+        .map(|i| {
+            let action_type_value = action_type_values.value(i);
+            let action_type = CorporateActionType::from_repr(action_type_value as usize)
+                .ok_or_else(|| {
+                    EncodingError::ParseError(
+                        stringify!(CorporateActionType),
+                        format!("Invalid enum value, was {action_type_value}"),
+                    )
+                })?;
+            let dividend = if dividend_values.is_null(i) {
+                None
+            } else {
+                Some(
+                    Money::from_str(dividend_values.value(i))
+                        .map_err(|e| EncodingError::ParseError("dividend", e.to_string()))?,
+                )
+            };
+            let new_instrument_id = if new_instrument_id_values.is_null(i) {
+                None
+            } else {
+                Some(
+                    InstrumentId::from_str(new_instrument_id_values.value(i)).map_err(|e| {
+                        EncodingError::ParseError("new_instrument_id", e.to_string())
+                    })?,
+                )
+            };
+
+            Ok(CorporateAction {
+                instrument_id,
+                action_type,
+                split_to: split_to_values.value(i),
+                split_from: split_from_values.value(i),
+                dividend,
+                new_instrument_id,
+                ts_event: ts_event_values.value(i).into(),
+                ts_init: ts_init_values.value(i).into(),
+            })
+        })
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_encode_decode_round_trip() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let metadata = CorporateAction::get_metadata(&instrument_id);
+        let data = vec![
+            CorporateAction::split(instrument_id, 4, 1, 1.into(), 1.into()).unwrap(),
+            CorporateAction::dividend(instrument_id, Money::from("0.24 USD"), 2.into(), 2.into())
+                .unwrap(),
+            CorporateAction::symbol_change(
+                instrument_id,
+                InstrumentId::from("AAPL2.XNAS"),
+                3.into(),
+                3.into(),
+            )
+            .unwrap(),
+        ];
+
+        let record_batch = CorporateAction::encode_batch(&metadata, &data).unwrap();
+        let dividend_values = record_batch.columns()[3]
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert!(dividend_values.is_null(0));
+        assert_eq!(dividend_values.value(1), "0.24 USD");
+
+        let decoded = decode_corporate_action_batch(&metadata, &record_batch).unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    #[rstest]
+    fn test_decode_without_instrument_id_metadata() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let data = vec![CorporateAction::split(instrument_id, 2, 1, 1.into(), 1.into()).unwrap()];
+        let record_batch =
+            CorporateAction::encode_batch(&CorporateAction::get_metadata(&instrument_id), &data)
+                .unwrap();
+
+        let result = decode_corporate_action_batch(&HashMap::new(), &record_batch);
+        assert!(matches!(
+            result,
+            Err(EncodingError::MissingMetadata(KEY_INSTRUMENT_ID))
+        ));
+    }
+}
@@ -16,6 +16,7 @@
 //! Defines the Apache Arrow schema for Nautilus types.
 
 pub mod bar;
+pub mod corporate_action;
 pub mod delta;
 pub mod depth;
 pub mod quote;
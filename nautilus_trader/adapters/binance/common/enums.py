@@ -525,7 +525,7 @@ class BinanceEnumParser:
         self.ext_to_int_time_in_force = {
             BinanceTimeInForce.FOK: TimeInForce.FOK,
             BinanceTimeInForce.GTC: TimeInForce.GTC,
-            BinanceTimeInForce.GTX: TimeInForce.GTC,  # Convert GTX to GTC
+            BinanceTimeInForce.GTX: TimeInForce.GTX,
             BinanceTimeInForce.GTE_GTC: TimeInForce.GTC,  # Undocumented
             BinanceTimeInForce.IOC: TimeInForce.IOC,
             BinanceTimeInForce.GTD: TimeInForce.GTD,
@@ -535,6 +535,7 @@ class BinanceEnumParser:
             TimeInForce.GTD: BinanceTimeInForce.GTD,
             TimeInForce.FOK: BinanceTimeInForce.FOK,
             TimeInForce.IOC: BinanceTimeInForce.IOC,
+            TimeInForce.GTX: BinanceTimeInForce.GTX,
         }
 
     def parse_binance_order_side(self, order_side: BinanceOrderSide) -> OrderSide:
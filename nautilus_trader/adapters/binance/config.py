@@ -123,6 +123,13 @@ class BinanceExecClientConfig(LiveExecClientConfig, frozen=True):
         The maximum number of times a submit, cancel or modify order request will be retried.
     retry_delay : PositiveFloat, optional
         The delay (seconds) between retries. Short delays with frequent retries may result in account bans.
+    isolated_margin_symbol : str, optional
+        The Binance symbol of the isolated margin account (e.g. 'BTCUSDT').
+        Must be provided when `account_type` is `ISOLATED_MARGIN`.
+    margin_interest_poll_interval_mins : PositiveInt, optional
+        The interval (minutes) between polls of the margin interest history, for which
+        `BinanceMarginInterest` data is published. If ``None`` then interest is not polled.
+        Only applicable to `MARGIN` and `ISOLATED_MARGIN` account types.
 
     Warnings
     --------
@@ -147,3 +154,5 @@ class BinanceExecClientConfig(LiveExecClientConfig, frozen=True):
     recv_window_ms: PositiveInt = 5_000
     max_retries: PositiveInt | None = None
     retry_delay: PositiveFloat | None = None
+    isolated_margin_symbol: str | None = None
+    margin_interest_poll_interval_mins: PositiveInt | None = 60
@@ -192,12 +192,20 @@ class BinanceCommonExecutionClient(LiveExecutionClient):
         self._ping_listen_keys_interval: int = 60 * 5  # Once every 5 mins (hard-coded)
         self._ping_listen_keys_task: asyncio.Task | None = None
         self._listen_key: str | None = None
+        self._listen_key_symbol: str | None = (
+            config.isolated_margin_symbol
+            if account_type == BinanceAccountType.ISOLATED_MARGIN
+            else None
+        )
+        self._listen_key_retry_delay_initial: float = 1.0  # Seconds (hard-coded)
+        self._listen_key_retry_delay_max: float = 60.0  # Seconds (hard-coded)
+        self._resync_user_stream_task: asyncio.Task | None = None
 
         # WebSocket API
         self._ws_client = BinanceWebSocketClient(
             clock=clock,
             handler=self._handle_user_ws_message,
-            handler_reconnect=None,
+            handler_reconnect=self._handle_user_ws_reconnect,
             base_url=base_url_ws,
             loop=self._loop,
         )
@@ -264,7 +272,9 @@ class BinanceCommonExecutionClient(LiveExecutionClient):
             await self._update_account_state()
             await self._init_dual_side_position()
 
-            response: BinanceListenKey = await self._http_user.create_listen_key()
+            response: BinanceListenKey = await self._http_user.create_listen_key(
+                symbol=self._listen_key_symbol,
+            )
         except BinanceError as e:
             self._log.exception(f"Error on connect: {e.message}", e)
             return
@@ -303,13 +313,103 @@ class BinanceCommonExecutionClient(LiveExecutionClient):
                 if self._listen_key:
                     self._log.debug(f"Pinging WebSocket listen key {self._listen_key}")
                     try:
-                        await self._http_user.keepalive_listen_key(listen_key=self._listen_key)
+                        await self._http_user.keepalive_listen_key(
+                            symbol=self._listen_key_symbol,
+                            listen_key=self._listen_key,
+                        )
                     except BinanceClientError as e:
-                        # We may see this if an old listen key was used for the ping
+                        # The listen key is no longer valid, so a new one must be created
                         self._log.error(f"Error pinging listen key: {e}")
+                        self._resync_user_stream("listen key keepalive failed", new_listen_key=True)
+                    except BinanceError as e:
+                        self._log.warning(f"Error pinging listen key: {e}")
         except asyncio.CancelledError:
             self._log.debug("Canceled task 'ping_listen_keys'")
 
+    async def _handle_user_ws_reconnect(self) -> None:
+        self._resync_user_stream("websocket reconnected", new_listen_key=False)
+
+    def _resync_user_stream(self, reason: str, new_listen_key: bool) -> None:
+        if self._resync_user_stream_task and not self._resync_user_stream_task.done():
+            self._log.debug(f"User data stream resync already in progress ({reason})")
+            return
+
+        self._log.warning(f"Resyncing user data stream: {reason}")
+        self._resync_user_stream_task = self.create_task(
+            self._resync_user_stream_inner(new_listen_key),
+            log_msg="resync_user_stream",
+        )
+
+    async def _resync_user_stream_inner(self, new_listen_key: bool) -> None:
+        try:
+            await self._refresh_listen_key(new_listen_key)
+            await self._reconcile_user_stream_state()
+        except asyncio.CancelledError:
+            self._log.debug("Canceled task 'resync_user_stream'")
+
+    async def _refresh_listen_key(self, new_listen_key: bool) -> None:
+        if self._listen_key and not new_listen_key:
+            try:
+                await self._http_user.keepalive_listen_key(
+                    symbol=self._listen_key_symbol,
+                    listen_key=self._listen_key,
+                )
+                return  # Listen key still valid, streams were resubscribed on reconnect
+            except BinanceError as e:
+                self._log.warning(f"Error pinging listen key {self._listen_key}: {e}")
+
+        delay = self._listen_key_retry_delay_initial
+        while True:
+            try:
+                response: BinanceListenKey = await self._http_user.create_listen_key(
+                    symbol=self._listen_key_symbol,
+                )
+                break
+            except BinanceError as e:
+                self._log.warning(f"Error creating listen key: {e}, retrying in {delay}s")
+                await asyncio.sleep(delay)
+                delay = min(delay * 2, self._listen_key_retry_delay_max)
+
+        old_listen_key = self._listen_key
+        self._listen_key = response.listenKey
+        if self._listen_key == old_listen_key:
+            return  # Binance extends and returns the existing key while it remains valid
+
+        self._log.info(f"Listen key {self._listen_key}")
+        if old_listen_key:
+            await self._ws_client.unsubscribe_listen_key(old_listen_key)
+        await self._ws_client.subscribe_listen_key(self._listen_key)
+
+    async def _reconcile_user_stream_state(self) -> None:
+        # Events may have been missed while the user data stream was down,
+        # so query the venue for the current account and open order state.
+        try:
+            await self._update_account_state()
+        except BinanceError as e:
+            self._log.error(f"Cannot update account state on resync: {e.message}")
+
+        reports = await self.generate_order_status_reports(open_only=True)
+        venue_order_ids: set[VenueOrderId] = set()
+        for report in reports:
+            venue_order_ids.add(report.venue_order_id)
+            self._send_order_status_report(report)
+
+        # Orders no longer open at the venue were closed while disconnected
+        for order in self._cache.orders_open(venue=self.venue):
+            if order.venue_order_id in venue_order_ids:
+                continue
+            if self._instrument_provider.find(order.instrument_id) is None:
+                continue  # Order belongs to another Binance account type
+            report = await self.generate_order_status_report(
+                instrument_id=order.instrument_id,
+                client_order_id=order.client_order_id,
+                venue_order_id=order.venue_order_id,
+            )
+            if report is not None:
+                self._send_order_status_report(report)
+
+        self._log.info("User data stream resync complete", LogColor.BLUE)
+
     async def _disconnect(self) -> None:
         # Cancel tasks
         if self._ping_listen_keys_task:
@@ -317,6 +417,11 @@ class BinanceCommonExecutionClient(LiveExecutionClient):
             self._ping_listen_keys_task.cancel()
             self._ping_listen_keys_task = None
 
+        if self._resync_user_stream_task:
+            self._log.debug("Canceling task 'resync_user_stream'")
+            self._resync_user_stream_task.cancel()
+            self._resync_user_stream_task = None
+
         await self._ws_client.disconnect()
 
     # -- EXECUTION REPORTS ------------------------------------------------------------------------
@@ -812,7 +917,12 @@ class BinanceCommonExecutionClient(LiveExecutionClient):
             )
             return
 
-        time_in_force = self._determine_time_in_force(order)
+        # Spot/Margin STOP_LOSS and TAKE_PROFIT orders execute as market orders
+        # and do not accept a time in force
+        time_in_force = None
+        if not self._binance_account_type.is_spot_or_margin:
+            time_in_force = self._determine_time_in_force(order)
+
         await self._http_account.new_order(
             symbol=order.instrument_id.symbol.value,
             side=self._enum_parser.parse_internal_order_side(order.side),
@@ -844,26 +954,6 @@ class BinanceCommonExecutionClient(LiveExecutionClient):
             )
             return
 
-        if order.trailing_offset_type != TrailingOffsetType.BASIS_POINTS:
-            self._log.error(
-                f"Cannot submit order: invalid `order.trailing_offset_type`, was "
-                f"{trailing_offset_type_to_str(order.trailing_offset_type)} (use `BASIS_POINTS`), "
-                f"{order}",
-            )
-            return
-
-        # Convert basis points to percentage rounded to 1 decimal place
-        callback_rate = Decimal(f"{order.trailing_offset / 100:.1f}")
-
-        if callback_rate < BINANCE_MIN_CALLBACK_RATE or callback_rate > BINANCE_MAX_CALLBACK_RATE:
-            self._log.error(
-                f"Cannot submit order: invalid `order.trailing_offset`, was "
-                f"{order.trailing_offset} {trailing_offset_type_to_str(order.trailing_offset_type)} "
-                f"rounded to {callback_rate}%, "
-                f"must in range [{BINANCE_MIN_CALLBACK_RATE}, {BINANCE_MAX_CALLBACK_RATE}]",
-            )
-            return
-
         # Ensure activation price
         activation_price: Price | None = order.trigger_price
         if not activation_price:
@@ -877,11 +967,25 @@ class BinanceCommonExecutionClient(LiveExecutionClient):
             elif trade:
                 activation_price = trade.price
             else:
-                self._log.error(
-                    "Cannot submit order: no trigger price specified for Binance activation price "
+                # Binance will activate from the latest price on submission
+                self._log.warning(
+                    "No trigger price specified for Binance activation price "
                     f"and could not find quotes or trades for {order.instrument_id}",
                 )
 
+        try:
+            callback_rate = self._calculate_callback_rate(order, activation_price)
+        except ValueError as e:
+            self._log.error(f"Cannot submit order: {e}, {order}")
+            self.generate_order_rejected(
+                strategy_id=order.strategy_id,
+                instrument_id=order.instrument_id,
+                client_order_id=order.client_order_id,
+                reason=str(e),
+                ts_event=self._clock.timestamp_ns(),
+            )
+            return
+
         time_in_force = self._determine_time_in_force(order)
         await self._http_account.new_order(
             symbol=order.instrument_id.symbol.value,
@@ -890,7 +994,7 @@ class BinanceCommonExecutionClient(LiveExecutionClient):
             time_in_force=time_in_force,
             good_till_date=self._determine_good_till_date(order, time_in_force),
             quantity=str(order.quantity),
-            activation_price=str(activation_price),
+            activation_price=str(activation_price) if activation_price is not None else None,
             callback_rate=str(callback_rate),
             working_type=working_type,
             reduce_only=self._determine_reduce_only_str(order),
@@ -899,6 +1003,47 @@ class BinanceCommonExecutionClient(LiveExecutionClient):
             position_side=position_side,
         )
 
+    def _calculate_callback_rate(
+        self,
+        order: TrailingStopMarketOrder,
+        activation_price: Price | None,
+    ) -> Decimal:
+        # Binance expresses the trailing distance as a callback rate percentage
+        offset_type = order.trailing_offset_type
+        if offset_type == TrailingOffsetType.BASIS_POINTS:
+            callback_rate = order.trailing_offset / 100
+        elif offset_type in (TrailingOffsetType.PRICE, TrailingOffsetType.TICKS):
+            if activation_price is None:
+                raise ValueError(
+                    f"cannot convert {trailing_offset_type_to_str(offset_type)} `trailing_offset` "
+                    "to a callback rate without an activation price",
+                )
+            offset = order.trailing_offset
+            if offset_type == TrailingOffsetType.TICKS:
+                instrument = self._cache.instrument(order.instrument_id)
+                if instrument is None:
+                    raise ValueError(f"no instrument found for {order.instrument_id}")
+                offset *= instrument.price_increment.as_decimal()
+            callback_rate = offset / activation_price.as_decimal() * 100
+        else:
+            raise ValueError(
+                f"invalid `order.trailing_offset_type`, was "
+                f"{trailing_offset_type_to_str(offset_type)} "
+                "(use `BASIS_POINTS`, `PRICE` or `TICKS`)",
+            )
+
+        # Rounded to 1 decimal place as required by Binance
+        callback_rate = Decimal(f"{callback_rate:.1f}")
+        if callback_rate < BINANCE_MIN_CALLBACK_RATE or callback_rate > BINANCE_MAX_CALLBACK_RATE:
+            raise ValueError(
+                f"invalid `order.trailing_offset`, was "
+                f"{order.trailing_offset} {trailing_offset_type_to_str(offset_type)} "
+                f"rounded to {callback_rate}%, "
+                f"must be in range [{BINANCE_MIN_CALLBACK_RATE}, {BINANCE_MAX_CALLBACK_RATE}]",
+            )
+
+        return callback_rate
+
     def _get_cached_instrument_id(self, symbol: str) -> InstrumentId:
         nautilus_symbol: str = BinanceSymbol(symbol).parse_as_nautilus(
             self._binance_account_type,
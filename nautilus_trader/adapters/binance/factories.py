@@ -38,6 +38,7 @@ from nautilus_trader.common.component import LiveClock
 from nautilus_trader.common.component import MessageBus
 from nautilus_trader.config import InstrumentProviderConfig
 from nautilus_trader.core.nautilus_pyo3 import Quota
+from nautilus_trader.core.nautilus_pyo3 import RateLimitHeader
 from nautilus_trader.live.factories import LiveDataClientFactory
 from nautilus_trader.live.factories import LiveExecClientFactory
 from nautilus_trader.model.identifiers import Venue
@@ -109,6 +110,7 @@ def get_cached_binance_http_client(
             ("order", Quota.rate_per_minute(3000)),
             ("allOrders", Quota.rate_per_minute(int(3000 / 20))),
         ]
+        ratelimiter_headers = [RateLimitHeader("X-MBX-USED-WEIGHT-1M", limit=6000, interval_secs=60)]
     else:
         # Futures
         ratelimiter_default_quota = Quota.rate_per_minute(2400)
@@ -116,6 +118,7 @@ def get_cached_binance_http_client(
             ("order", Quota.rate_per_minute(1200)),
             ("allOrders", Quota.rate_per_minute(int(1200 / 20))),
         ]
+        ratelimiter_headers = [RateLimitHeader("X-MBX-USED-WEIGHT-1M", limit=2400, interval_secs=60)]
 
     return BinanceHttpClient(
         clock=clock,
@@ -127,6 +130,7 @@ def get_cached_binance_http_client(
         base_url=base_url or default_http_base_url,
         ratelimiter_quotas=ratelimiter_quotas,
         ratelimiter_default_quota=ratelimiter_default_quota,
+        ratelimiter_headers=ratelimiter_headers,
     )
 
 
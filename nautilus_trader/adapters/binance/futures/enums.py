@@ -151,6 +151,7 @@ class BinanceFuturesEnumParser(BinanceEnumParser):
             TimeInForce.GTD,
             TimeInForce.FOK,
             TimeInForce.IOC,
+            TimeInForce.GTX,
         }
 
         self.futures_valid_order_types = {
@@ -305,7 +305,8 @@ class BinanceFuturesExecutionClient(BinanceCommonExecutionClient):
         self._log.info("Account config updated", LogColor.BLUE)  # Implement
 
     def _handle_listen_key_expired(self, raw: bytes) -> None:
-        self._log.warning("Listen key expired")  # Implement
+        self._log.warning("Listen key expired")
+        self._resync_user_stream("listen key expired", new_listen_key=True)
 
     def _handle_trade_lite(self, raw: bytes) -> None:
         trade_lite = self._decoder_futures_trade_lite_wrapper.decode(raw)
@@ -311,7 +311,23 @@ class BinanceFuturesOrderData(msgspec.Struct, kw_only=True, frozen=True):
 
         if self.x == BinanceExecutionType.NEW:
             if order.order_type == OrderType.TRAILING_STOP_MARKET and order.is_open:
-                return  # Already accepted: this is an update
+                # Already accepted: this is an update to the activation price
+                if self.AP is None:
+                    return
+                activation_price = Price(float(self.AP), price_precision)
+                if activation_price == order.trigger_price:
+                    return
+                exec_client.generate_order_updated(
+                    strategy_id=strategy_id,
+                    instrument_id=instrument_id,
+                    client_order_id=client_order_id,
+                    venue_order_id=venue_order_id,
+                    quantity=order.quantity,
+                    price=None,
+                    trigger_price=activation_price,
+                    ts_event=ts_event,
+                )
+                return
 
             exec_client.generate_order_accepted(
                 strategy_id=strategy_id,
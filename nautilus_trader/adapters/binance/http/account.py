@@ -38,14 +38,17 @@ class BinanceOrderHttp(BinanceHttpEndpoint):
 
     `GET /api/v3/order`
     `GET /api/v3/order/test`
+    `GET /sapi/v1/margin/order`
     `GET /fapi/v1/order`
     `GET /dapi/v1/order`
 
     `POST /api/v3/order`
+    `POST /sapi/v1/margin/order`
     `POST /fapi/v1/order`
     `POST /dapi/v1/order`
 
     `DELETE /api/v3/order`
+    `DELETE /sapi/v1/margin/order`
     `DELETE /fapi/v1/order`
     `DELETE /dapi/v1/order`
 
@@ -59,6 +62,7 @@ class BinanceOrderHttp(BinanceHttpEndpoint):
     References
     ----------
     https://binance-docs.github.io/apidocs/spot/en/#new-order-trade
+    https://binance-docs.github.io/apidocs/spot/en/#margin-account-new-order-trade
     https://binance-docs.github.io/apidocs/futures/en/#new-order-trade
     https://binance-docs.github.io/apidocs/delivery/en/#new-order-trade
     https://binance-docs.github.io/apidocs/futures/en/#modify-order-trade
@@ -105,6 +109,8 @@ class BinanceOrderHttp(BinanceHttpEndpoint):
             The order identifier.
         origClientOrderId : str, optional
             The client specified order identifier.
+        isIsolated : str, optional
+            If the order is for an isolated margin account ('TRUE'), MARGIN only.
         recvWindow : str, optional
             The millisecond timeout window.
 
@@ -118,6 +124,7 @@ class BinanceOrderHttp(BinanceHttpEndpoint):
         timestamp: str
         orderId: int | None = None
         origClientOrderId: str | None = None
+        isIsolated: str | None = None
         recvWindow: str | None = None
 
     class PostParameters(msgspec.Struct, omit_defaults=True, frozen=True):
@@ -207,6 +214,8 @@ class BinanceOrderHttp(BinanceHttpEndpoint):
             order the timestamp only retains second-level precision, ms part will be ignored.
             The goodTillDate timestamp must be greater than the current time plus 600 seconds and
             smaller than 253402300799000.
+        isIsolated : str, optional
+            If the order is for an isolated margin account ('TRUE'), MARGIN only.
         recvWindow : str, optional
             The response receive window in milliseconds for the request.
             Cannot exceed 60000.
@@ -236,6 +245,7 @@ class BinanceOrderHttp(BinanceHttpEndpoint):
         priceProtect: str | None = None
         newOrderRespType: BinanceNewOrderRespType | None = None
         goodTillDate: int | None = None
+        isIsolated: str | None = None
         recvWindow: str | None = None
 
     class PutParameters(msgspec.Struct, omit_defaults=True, frozen=True):
@@ -299,6 +309,7 @@ class BinanceAllOrdersHttp(BinanceHttpEndpoint):
     Endpoint of all account orders, active, cancelled or filled.
 
     `GET /api/v3/allOrders`
+    `GET /sapi/v1/margin/allOrders`
     `GET /fapi/v1/allOrders`
     `GET /dapi/v1/allOrders`
 
@@ -346,6 +357,8 @@ class BinanceAllOrdersHttp(BinanceHttpEndpoint):
         limit : int, optional
             The limit for the response.
             Default 500, max 1000
+        isIsolated : str, optional
+            If the orders are for an isolated margin account ('TRUE'), MARGIN only.
         recvWindow : str, optional
             The response receive window for the request (cannot be greater than 60000).
 
@@ -357,6 +370,7 @@ class BinanceAllOrdersHttp(BinanceHttpEndpoint):
         startTime: int | None = None
         endTime: int | None = None
         limit: int | None = None
+        isIsolated: str | None = None
         recvWindow: str | None = None
 
     async def get(self, params: GetParameters) -> list[BinanceOrder]:
@@ -370,6 +384,7 @@ class BinanceOpenOrdersHttp(BinanceHttpEndpoint):
     Endpoint of all open orders on a symbol.
 
     `GET /api/v3/openOrders`
+    `GET /sapi/v1/margin/openOrders`
     `GET /fapi/v1/openOrders`
     `GET /dapi/v1/openOrders`
 
@@ -414,6 +429,9 @@ class BinanceOpenOrdersHttp(BinanceHttpEndpoint):
             The millisecond timestamp of the request
         symbol : BinanceSymbol, optional
             The symbol of the orders
+        isIsolated : str, optional
+            If the orders are for an isolated margin account ('TRUE'), MARGIN only.
+            The symbol must then be provided.
         recvWindow : str, optional
             The response receive window for the request (cannot be greater than 60000).
 
@@ -421,6 +439,7 @@ class BinanceOpenOrdersHttp(BinanceHttpEndpoint):
 
         timestamp: str
         symbol: BinanceSymbol | None = None
+        isIsolated: str | None = None
         recvWindow: str | None = None
 
     async def get(self, params: GetParameters) -> list[BinanceOrder]:
@@ -434,6 +453,7 @@ class BinanceUserTradesHttp(BinanceHttpEndpoint):
     Endpoint of trades for a specific account and symbol.
 
     `GET /api/v3/myTrades`
+    `GET /sapi/v1/margin/myTrades`
     `GET /fapi/v1/userTrades`
     `GET /dapi/v1/userTrades`
 
@@ -482,6 +502,8 @@ class BinanceUserTradesHttp(BinanceHttpEndpoint):
         limit : int, optional
             The limit for the response.
             Default 500, max 1000
+        isIsolated : str, optional
+            If the trades are for an isolated margin account ('TRUE'), MARGIN only.
         recvWindow : str, optional
             The response receive window for the request (cannot be greater than 60000).
 
@@ -494,6 +516,7 @@ class BinanceUserTradesHttp(BinanceHttpEndpoint):
         endTime: int | None = None
         fromId: int | None = None
         limit: int | None = None
+        isIsolated: str | None = None
         recvWindow: str | None = None
 
     async def _get(self, params: GetParameters) -> list[BinanceUserTrade]:
@@ -529,9 +552,12 @@ class BinanceAccountHttpAPI:
         self.client = client
         self._clock = clock
 
-        if account_type.is_spot_or_margin:
+        if account_type.is_spot:
             self.base_endpoint = "/api/v3/"
             user_trades_url = self.base_endpoint + "myTrades"
+        elif account_type.is_margin:
+            self.base_endpoint = "/sapi/v1/margin/"
+            user_trades_url = self.base_endpoint + "myTrades"
         elif account_type == BinanceAccountType.USDT_FUTURE:
             self.base_endpoint = "/fapi/v1/"
             user_trades_url = self.base_endpoint + "userTrades"
@@ -543,6 +569,11 @@ class BinanceAccountHttpAPI:
                 f"invalid `BinanceAccountType`, was {account_type}",  # pragma: no cover
             )
 
+        # Isolated margin requests must be flagged (only sent for ISOLATED_MARGIN)
+        self._is_isolated: str | None = (
+            "TRUE" if account_type == BinanceAccountType.ISOLATED_MARGIN else None
+        )
+
         # Create endpoints
         self._endpoint_order = BinanceOrderHttp(client, self.base_endpoint)
         self._endpoint_all_orders = BinanceAllOrdersHttp(client, self.base_endpoint)
@@ -575,6 +606,7 @@ class BinanceAccountHttpAPI:
                 timestamp=self._timestamp(),
                 orderId=order_id,
                 origClientOrderId=orig_client_order_id,
+                isIsolated=self._is_isolated,
                 recvWindow=recv_window,
             ),
         )
@@ -608,6 +640,7 @@ class BinanceAccountHttpAPI:
                 timestamp=self._timestamp(),
                 orderId=order_id,
                 origClientOrderId=orig_client_order_id,
+                isIsolated=self._is_isolated,
                 recvWindow=recv_window,
             ),
         )
@@ -667,6 +700,7 @@ class BinanceAccountHttpAPI:
                 priceProtect=price_protect,
                 goodTillDate=good_till_date,
                 newOrderRespType=new_order_resp_type,
+                isIsolated=self._is_isolated,
                 recvWindow=recv_window,
             ),
         )
@@ -719,6 +753,7 @@ class BinanceAccountHttpAPI:
                 startTime=start_time,
                 endTime=end_time,
                 limit=limit,
+                isIsolated=self._is_isolated,
                 recvWindow=recv_window,
             ),
         )
@@ -735,6 +770,7 @@ class BinanceAccountHttpAPI:
             params=self._endpoint_open_orders.GetParameters(
                 symbol=BinanceSymbol(symbol) if symbol else None,
                 timestamp=self._timestamp(),
+                isIsolated=self._is_isolated,
                 recvWindow=recv_window,
             ),
         )
@@ -765,6 +801,7 @@ class BinanceAccountHttpAPI:
                 endTime=end_time,
                 fromId=from_id,
                 limit=limit,
+                isIsolated=self._is_isolated,
                 recvWindow=recv_window,
             ),
         )
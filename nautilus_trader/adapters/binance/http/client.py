@@ -29,6 +29,7 @@ from nautilus_trader.core.nautilus_pyo3 import HttpClient
 from nautilus_trader.core.nautilus_pyo3 import HttpMethod
 from nautilus_trader.core.nautilus_pyo3 import HttpResponse
 from nautilus_trader.core.nautilus_pyo3 import Quota
+from nautilus_trader.core.nautilus_pyo3 import RateLimitHeader
 from nautilus_trader.core.nautilus_pyo3 import ed25519_signature
 from nautilus_trader.core.nautilus_pyo3 import hmac_signature
 from nautilus_trader.core.nautilus_pyo3 import rsa_signature
@@ -58,6 +59,8 @@ class BinanceHttpClient:
         The keyed rate limiter quotas for the client.
     ratelimiter_quota : Quota, optional
         The default rate limiter quota for the client.
+    ratelimiter_headers : list[RateLimitHeader], optional
+        The used weight headers to adaptively throttle requests from.
 
     """
 
@@ -72,6 +75,7 @@ class BinanceHttpClient:
         ed25519_private_key: str | None = None,
         ratelimiter_quotas: list[tuple[str, Quota]] | None = None,
         ratelimiter_default_quota: Quota | None = None,
+        ratelimiter_headers: list[RateLimitHeader] | None = None,
     ) -> None:
         self._clock: LiveClock = clock
         self._log: Logger = Logger(type(self).__name__)
@@ -93,6 +97,7 @@ class BinanceHttpClient:
         self._client = HttpClient(
             keyed_quotas=ratelimiter_quotas or [],
             default_quota=ratelimiter_default_quota,
+            rate_limit_headers=ratelimiter_headers or [],
         )
 
     @property
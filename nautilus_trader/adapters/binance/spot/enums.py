@@ -100,6 +100,16 @@ class BinanceSpotEventType(Enum):
     listStatus = "listStatus"
 
 
+@unique
+class BinanceMarginLoanType(Enum):
+    """
+    Represents a Binance Margin loan (borrow/repay) type.
+    """
+
+    BORROW = "BORROW"
+    REPAY = "REPAY"
+
+
 class BinanceSpotEnumParser(BinanceEnumParser):
     """
     Provides parsing methods for enums used by the 'Binance Spot/Margin' exchange.
@@ -130,8 +140,10 @@ class BinanceSpotEnumParser(BinanceEnumParser):
         self.spot_valid_order_types = {
             OrderType.MARKET,
             OrderType.LIMIT,
-            OrderType.LIMIT_IF_TOUCHED,
+            OrderType.STOP_MARKET,
             OrderType.STOP_LIMIT,
+            OrderType.MARKET_IF_TOUCHED,
+            OrderType.LIMIT_IF_TOUCHED,
         }
 
     def parse_binance_order_type(self, order_type: BinanceOrderType) -> OrderType:
@@ -150,8 +162,12 @@ class BinanceSpotEnumParser(BinanceEnumParser):
                 return BinanceOrderType.LIMIT_MAKER
             else:
                 return BinanceOrderType.LIMIT
+        elif order.order_type == OrderType.STOP_MARKET:
+            return BinanceOrderType.STOP_LOSS
         elif order.order_type == OrderType.STOP_LIMIT:
             return BinanceOrderType.STOP_LOSS_LIMIT
+        elif order.order_type == OrderType.MARKET_IF_TOUCHED:
+            return BinanceOrderType.TAKE_PROFIT
         elif order.order_type == OrderType.LIMIT_IF_TOUCHED:
             return BinanceOrderType.TAKE_PROFIT_LIMIT
         else:
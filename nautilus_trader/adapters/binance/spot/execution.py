@@ -21,16 +21,24 @@ from nautilus_trader.adapters.binance.common.enums import BinanceAccountType
 from nautilus_trader.adapters.binance.config import BinanceExecClientConfig
 from nautilus_trader.adapters.binance.execution import BinanceCommonExecutionClient
 from nautilus_trader.adapters.binance.http.client import BinanceHttpClient
+from nautilus_trader.adapters.binance.http.error import BinanceError
 from nautilus_trader.adapters.binance.spot.enums import BinanceSpotEnumParser
 from nautilus_trader.adapters.binance.spot.enums import BinanceSpotEventType
 from nautilus_trader.adapters.binance.spot.http.account import BinanceSpotAccountHttpAPI
+from nautilus_trader.adapters.binance.spot.http.margin import BinanceSpotMarginHttpAPI
 from nautilus_trader.adapters.binance.spot.http.market import BinanceSpotMarketHttpAPI
 from nautilus_trader.adapters.binance.spot.http.user import BinanceSpotUserDataHttpAPI
+from nautilus_trader.adapters.binance.spot.messages import BINANCE_MARGIN_BORROW_REPAY_ENDPOINT
+from nautilus_trader.adapters.binance.spot.messages import BinanceMarginBorrowRepay
 from nautilus_trader.adapters.binance.spot.providers import BinanceSpotInstrumentProvider
 from nautilus_trader.adapters.binance.spot.schemas.account import BinanceSpotAccountInfo
+from nautilus_trader.adapters.binance.spot.schemas.margin import BinanceIsolatedMarginAccountInfo
+from nautilus_trader.adapters.binance.spot.schemas.margin import BinanceMarginAccountInfo
+from nautilus_trader.adapters.binance.spot.schemas.margin import BinanceMarginInterestRecord
 from nautilus_trader.adapters.binance.spot.schemas.user import BinanceSpotAccountUpdateWrapper
 from nautilus_trader.adapters.binance.spot.schemas.user import BinanceSpotOrderUpdateWrapper
 from nautilus_trader.adapters.binance.spot.schemas.user import BinanceSpotUserMsgWrapper
+from nautilus_trader.adapters.binance.spot.types import BinanceMarginInterest
 from nautilus_trader.cache.cache import Cache
 from nautilus_trader.common.component import LiveClock
 from nautilus_trader.common.component import MessageBus
@@ -41,6 +49,7 @@ from nautilus_trader.execution.messages import BatchCancelOrders
 from nautilus_trader.execution.reports import PositionStatusReport
 from nautilus_trader.model.enums import OrderType
 from nautilus_trader.model.enums import order_type_to_str
+from nautilus_trader.model.data import DataType
 from nautilus_trader.model.enums import time_in_force_to_str
 from nautilus_trader.model.orders import Order
 
@@ -91,11 +100,16 @@ class BinanceSpotExecutionClient(BinanceCommonExecutionClient):
             account_type.is_spot_or_margin,
             "account_type was not SPOT, MARGIN or ISOLATED_MARGIN",
         )
+        if account_type == BinanceAccountType.ISOLATED_MARGIN:
+            PyCondition.not_none(config.isolated_margin_symbol, "config.isolated_margin_symbol")
 
         # Spot HTTP API
         self._spot_http_account = BinanceSpotAccountHttpAPI(client, clock, account_type)
         self._spot_http_market = BinanceSpotMarketHttpAPI(client, account_type)
         self._spot_http_user = BinanceSpotUserDataHttpAPI(client, account_type)
+        self._spot_http_margin: BinanceSpotMarginHttpAPI | None = None
+        if account_type.is_margin:
+            self._spot_http_margin = BinanceSpotMarginHttpAPI(client, clock, account_type)
 
         # Spot enum parser
         self._spot_enum_parser = BinanceSpotEnumParser()
@@ -118,6 +132,27 @@ class BinanceSpotExecutionClient(BinanceCommonExecutionClient):
             config=config,
         )
 
+        # Margin
+        self._isolated_margin_symbol: str | None = (
+            config.isolated_margin_symbol
+            if account_type == BinanceAccountType.ISOLATED_MARGIN
+            else None
+        )
+        self._margin_interest_poll_interval_mins: int | None = (
+            config.margin_interest_poll_interval_mins
+        )
+        self._margin_interest_task: asyncio.Task | None = None
+        self._margin_interest_last_ms: int = 0
+        if account_type.is_margin:
+            self._log.info(f"{config.isolated_margin_symbol=}", LogColor.BLUE)
+            self._log.info(f"{config.margin_interest_poll_interval_mins=}", LogColor.BLUE)
+
+            # Register custom endpoint for margin loan commands
+            self._msgbus.register(
+                endpoint=BINANCE_MARGIN_BORROW_REPAY_ENDPOINT,
+                handler=self.borrow_repay,
+            )
+
         # Register spot websocket user data event handlers
         self._spot_user_ws_handlers = {
             BinanceSpotEventType.outboundAccountPosition: self._handle_account_update,
@@ -135,7 +170,28 @@ class BinanceSpotExecutionClient(BinanceCommonExecutionClient):
             BinanceSpotAccountUpdateWrapper,
         )
 
+    async def _connect(self) -> None:
+        await super()._connect()
+
+        if self._spot_http_margin is not None and self._margin_interest_poll_interval_mins:
+            self._margin_interest_last_ms = self._clock.timestamp_ms()
+            self._margin_interest_task = self.create_task(
+                self._poll_margin_interest(self._margin_interest_poll_interval_mins),
+            )
+
+    async def _disconnect(self) -> None:
+        if self._margin_interest_task:
+            self._log.debug("Canceling task 'poll_margin_interest'")
+            self._margin_interest_task.cancel()
+            self._margin_interest_task = None
+
+        await super()._disconnect()
+
     async def _update_account_state(self) -> None:
+        if self._spot_http_margin is not None:
+            await self._update_margin_account_state(self._spot_http_margin)
+            return
+
         account_info: BinanceSpotAccountInfo = (
             await self._spot_http_account.query_spot_account_info(
                 recv_window=str(5000),
@@ -155,10 +211,122 @@ class BinanceSpotExecutionClient(BinanceCommonExecutionClient):
         while self.get_account() is None:
             await asyncio.sleep(0.1)
 
+    async def _update_margin_account_state(self, http_margin: BinanceSpotMarginHttpAPI) -> None:
+        account_info: BinanceMarginAccountInfo | BinanceIsolatedMarginAccountInfo
+        if self._binance_account_type == BinanceAccountType.ISOLATED_MARGIN:
+            assert self._isolated_margin_symbol  # Checked at initialization
+            account_info = await http_margin.query_isolated_margin_account(
+                symbols=[self._isolated_margin_symbol],
+                recv_window=str(self._recv_window),
+            )
+        else:
+            account_info = await http_margin.query_margin_account(
+                recv_window=str(self._recv_window),
+            )
+
+        info = account_info.parse_to_info()
+        self._log.info(f"Margin level {info['margin_level']}", LogColor.BLUE)
+        self.generate_account_state(
+            balances=account_info.parse_to_account_balances(),
+            margins=[],
+            reported=True,
+            ts_event=self._clock.timestamp_ns(),
+            info=info,
+        )
+        while self.get_account() is None:
+            await asyncio.sleep(0.1)
+
     async def _init_dual_side_position(self) -> None:
         self._is_dual_side_position = False
         self._log.info(f"Dual side position: {self._is_dual_side_position}", LogColor.BLUE)
 
+    # -- MARGIN -----------------------------------------------------------------------------------
+
+    def borrow_repay(self, command: BinanceMarginBorrowRepay) -> None:
+        """
+        Borrow or repay a margin loan (registered as a message bus endpoint).
+
+        Parameters
+        ----------
+        command : BinanceMarginBorrowRepay
+            The command to execute.
+
+        """
+        self.create_task(
+            self._borrow_repay(command),
+            log_msg=f"borrow_repay: {command}",
+        )
+
+    async def _borrow_repay(self, command: BinanceMarginBorrowRepay) -> None:
+        if self._spot_http_margin is None:
+            self._log.error(
+                f"Cannot execute {command}: "
+                f"not supported for {self._binance_account_type.value} accounts",
+            )
+            return
+
+        self._log.debug(f"{command}")
+        try:
+            response = await self._spot_http_margin.borrow_repay(
+                asset=command.currency.code,
+                amount=str(command.amount),
+                loan_type=command.loan_type,
+                symbol=command.symbol or self._isolated_margin_symbol,
+                recv_window=str(self._recv_window),
+            )
+        except BinanceError as e:
+            self._log.error(f"Error on {command}: {e.message}")
+            return
+
+        self._log.info(
+            f"Margin {command.loan_type.value} {command.amount} {command.currency} "
+            f"(tranId={response.tranId})",
+            LogColor.BLUE,
+        )
+        await self._update_account_state()
+
+    async def _poll_margin_interest(self, interval_mins: int) -> None:
+        try:
+            while True:
+                self._log.debug(
+                    f"Scheduled task 'poll_margin_interest' to run in {interval_mins} minutes",
+                )
+                await asyncio.sleep(interval_mins * 60)
+                await self._publish_margin_interest()
+        except asyncio.CancelledError:
+            self._log.debug("Canceled task 'poll_margin_interest'")
+
+    async def _publish_margin_interest(self) -> None:
+        assert self._spot_http_margin is not None  # Only polled for margin accounts
+        records: list[BinanceMarginInterestRecord] = []
+        page = 1
+        try:
+            while True:
+                history = await self._spot_http_margin.query_interest_history(
+                    isolated_symbol=self._isolated_margin_symbol,
+                    start_time=self._margin_interest_last_ms + 1,
+                    current=page,
+                    size=100,
+                    recv_window=str(self._recv_window),
+                )
+                records.extend(history.rows)
+                if not history.rows or len(records) >= history.total:
+                    break
+                page += 1
+        except BinanceError as e:
+            self._log.warning(f"Error querying margin interest history: {e.message}")
+            return
+
+        ts_init = self._clock.timestamp_ns()
+        topic = f"data.{DataType(BinanceMarginInterest).topic}"
+        for record in sorted(records, key=lambda r: r.interestAccuredTime):
+            interest = record.parse_to_margin_interest(ts_init)
+            self._msgbus.publish(topic=topic, msg=interest)
+            self._margin_interest_last_ms = max(
+                self._margin_interest_last_ms,
+                record.interestAccuredTime,
+            )
+
     # -- EXECUTION REPORTS ------------------------------------------------------------------------
 
     async def _get_binance_position_status_reports(
@@ -219,6 +387,11 @@ class BinanceSpotExecutionClient(BinanceCommonExecutionClient):
             self._log.exception(f"Error on handling {raw!r}", e)
 
     def _handle_account_update(self, raw: bytes) -> None:
+        if self._spot_http_margin is not None:
+            # Balance updates do not include liabilities or margin level
+            self.create_task(self._update_account_state())
+            return
+
         account_msg = self._decoder_spot_account_update_wrapper.decode(raw)
         account_msg.data.handle_account_update(self)
 
@@ -39,8 +39,10 @@ class BinanceSpotOpenOrdersHttp(BinanceOpenOrdersHttp):
     Endpoint of all SPOT/MARGIN open orders on a symbol.
 
     `GET /api/v3/openOrders` (inherited)
+    `GET /sapi/v1/margin/openOrders` (inherited)
 
     `DELETE /api/v3/openOrders`
+    `DELETE /sapi/v1/margin/openOrders`
 
     Warnings
     --------
@@ -80,6 +82,8 @@ class BinanceSpotOpenOrdersHttp(BinanceOpenOrdersHttp):
             The millisecond timestamp of the request
         symbol : BinanceSymbol
             The symbol of the orders
+        isIsolated : str, optional
+            If the orders are for an isolated margin account ('TRUE'), MARGIN only.
         recvWindow : str, optional
             The response receive window for the request (cannot be greater than 60000).
 
@@ -87,6 +91,7 @@ class BinanceSpotOpenOrdersHttp(BinanceOpenOrdersHttp):
 
         timestamp: str
         symbol: BinanceSymbol
+        isIsolated: str | None = None
         recvWindow: str | None = None
 
     async def _delete(self, params: DeleteParameters) -> list[dict[str, Any]]:
@@ -424,10 +429,14 @@ class BinanceSpotOpenOrderListHttp(BinanceHttpEndpoint):
 
 class BinanceSpotAccountHttp(BinanceHttpEndpoint):
     """
-    Endpoint of current SPOT/MARGIN account information.
+    Endpoint of current SPOT account information.
 
     `GET /api/v3/account`
 
+    Notes
+    -----
+    Margin account information is provided by `BinanceSpotMarginHttpAPI`.
+
     References
     ----------
     https://binance-docs.github.io/apidocs/spot/en/#account-information-user_data
@@ -563,7 +572,7 @@ class BinanceSpotAccountHttpAPI(BinanceAccountHttpAPI):
             client,
             self.base_endpoint,
         )
-        self._endpoint_spot_account = BinanceSpotAccountHttp(client, self.base_endpoint)
+        self._endpoint_spot_account = BinanceSpotAccountHttp(client, "/api/v3/")
         self._endpoint_spot_order_rate_limit = BinanceSpotOrderRateLimitHttp(
             client,
             self.base_endpoint,
@@ -664,6 +673,7 @@ class BinanceSpotAccountHttpAPI(BinanceAccountHttpAPI):
             params=self._endpoint_spot_open_orders.DeleteParameters(
                 timestamp=self._timestamp(),
                 symbol=BinanceSymbol(symbol),
+                isIsolated=self._is_isolated,
                 recvWindow=recv_window,
             ),
         )
@@ -740,7 +750,7 @@ class BinanceSpotAccountHttpAPI(BinanceAccountHttpAPI):
         recv_window: str | None = None,
     ) -> BinanceSpotAccountInfo:
         """
-        Check SPOT/MARGIN Binance account information.
+        Check SPOT Binance account information.
         """
         return await self._endpoint_spot_account.get(
             params=self._endpoint_spot_account.GetParameters(
@@ -0,0 +1,397 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import msgspec
+
+from nautilus_trader.adapters.binance.common.enums import BinanceAccountType
+from nautilus_trader.adapters.binance.common.enums import BinanceSecurityType
+from nautilus_trader.adapters.binance.common.symbol import BinanceSymbol
+from nautilus_trader.adapters.binance.http.client import BinanceHttpClient
+from nautilus_trader.adapters.binance.http.endpoint import BinanceHttpEndpoint
+from nautilus_trader.adapters.binance.spot.enums import BinanceMarginLoanType
+from nautilus_trader.adapters.binance.spot.schemas.margin import BinanceIsolatedMarginAccountInfo
+from nautilus_trader.adapters.binance.spot.schemas.margin import BinanceMarginAccountInfo
+from nautilus_trader.adapters.binance.spot.schemas.margin import BinanceMarginBorrowRepayResponse
+from nautilus_trader.adapters.binance.spot.schemas.margin import BinanceMarginInterestHistory
+from nautilus_trader.common.component import LiveClock
+from nautilus_trader.core.nautilus_pyo3 import HttpMethod
+
+
+class BinanceMarginBorrowRepayHttp(BinanceHttpEndpoint):
+    """
+    Endpoint for borrowing and repaying margin loans.
+
+    `POST /sapi/v1/margin/borrow-repay`
+
+    References
+    ----------
+    https://binance-docs.github.io/apidocs/spot/en/#margin-account-borrow-repay-margin
+
+    """
+
+    def __init__(
+        self,
+        client: BinanceHttpClient,
+        base_endpoint: str,
+    ):
+        methods = {
+            HttpMethod.POST: BinanceSecurityType.MARGIN,
+        }
+        super().__init__(
+            client,
+            methods,
+            base_endpoint + "borrow-repay",
+        )
+        self._post_resp_decoder = msgspec.json.Decoder(BinanceMarginBorrowRepayResponse)
+
+    class PostParameters(msgspec.Struct, omit_defaults=True, frozen=True):
+        """
+        Parameters of borrow-repay POST request.
+
+        Parameters
+        ----------
+        asset : str
+            The asset to borrow or repay.
+        isIsolated : str
+            If the loan is for an isolated margin account ('TRUE' or 'FALSE').
+        amount : str
+            The amount to borrow or repay.
+        type : BinanceMarginLoanType
+            The loan type (BORROW or REPAY).
+        timestamp : str
+            The millisecond timestamp of the request.
+        symbol : BinanceSymbol, optional
+            The isolated margin symbol (mandatory for isolated margin).
+        recvWindow : str, optional
+            The response receive window for the request (cannot be greater than 60000).
+
+        """
+
+        asset: str
+        isIsolated: str
+        amount: str
+        type: BinanceMarginLoanType
+        timestamp: str
+        symbol: BinanceSymbol | None = None
+        recvWindow: str | None = None
+
+    async def post(self, params: PostParameters) -> BinanceMarginBorrowRepayResponse:
+        method_type = HttpMethod.POST
+        raw = await self._method(method_type, params)
+        return self._post_resp_decoder.decode(raw)
+
+
+class BinanceMarginAccountHttp(BinanceHttpEndpoint):
+    """
+    Endpoint of current cross margin account information.
+
+    `GET /sapi/v1/margin/account`
+
+    References
+    ----------
+    https://binance-docs.github.io/apidocs/spot/en/#query-cross-margin-account-details-user_data
+
+    """
+
+    def __init__(
+        self,
+        client: BinanceHttpClient,
+        base_endpoint: str,
+    ):
+        methods = {
+            HttpMethod.GET: BinanceSecurityType.USER_DATA,
+        }
+        super().__init__(
+            client,
+            methods,
+            base_endpoint + "account",
+        )
+        self._get_resp_decoder = msgspec.json.Decoder(BinanceMarginAccountInfo)
+
+    class GetParameters(msgspec.Struct, omit_defaults=True, frozen=True):
+        """
+        Parameters of cross margin account GET request.
+
+        Parameters
+        ----------
+        timestamp : str
+            The millisecond timestamp of the request.
+        recvWindow : str, optional
+            The response receive window for the request (cannot be greater than 60000).
+
+        """
+
+        timestamp: str
+        recvWindow: str | None = None
+
+    async def get(self, params: GetParameters) -> BinanceMarginAccountInfo:
+        method_type = HttpMethod.GET
+        raw = await self._method(method_type, params)
+        return self._get_resp_decoder.decode(raw)
+
+
+class BinanceIsolatedMarginAccountHttp(BinanceHttpEndpoint):
+    """
+    Endpoint of current isolated margin account information.
+
+    `GET /sapi/v1/margin/isolated/account`
+
+    References
+    ----------
+    https://binance-docs.github.io/apidocs/spot/en/#query-isolated-margin-account-info-user_data
+
+    """
+
+    def __init__(
+        self,
+        client: BinanceHttpClient,
+        base_endpoint: str,
+    ):
+        methods = {
+            HttpMethod.GET: BinanceSecurityType.USER_DATA,
+        }
+        super().__init__(
+            client,
+            methods,
+            base_endpoint + "isolated/account",
+        )
+        self._get_resp_decoder = msgspec.json.Decoder(BinanceIsolatedMarginAccountInfo)
+
+    class GetParameters(msgspec.Struct, omit_defaults=True, frozen=True):
+        """
+        Parameters of isolated margin account GET request.
+
+        Parameters
+        ----------
+        timestamp : str
+            The millisecond timestamp of the request.
+        symbols : str, optional
+            The comma separated isolated symbols to query (max 5), otherwise all.
+        recvWindow : str, optional
+            The response receive window for the request (cannot be greater than 60000).
+
+        """
+
+        timestamp: str
+        symbols: str | None = None
+        recvWindow: str | None = None
+
+    async def get(self, params: GetParameters) -> BinanceIsolatedMarginAccountInfo:
+        method_type = HttpMethod.GET
+        raw = await self._method(method_type, params)
+        return self._get_resp_decoder.decode(raw)
+
+
+class BinanceMarginInterestHistoryHttp(BinanceHttpEndpoint):
+    """
+    Endpoint of margin interest history.
+
+    `GET /sapi/v1/margin/interestHistory`
+
+    References
+    ----------
+    https://binance-docs.github.io/apidocs/spot/en/#get-interest-history-user_data
+
+    """
+
+    def __init__(
+        self,
+        client: BinanceHttpClient,
+        base_endpoint: str,
+    ):
+        methods = {
+            HttpMethod.GET: BinanceSecurityType.USER_DATA,
+        }
+        super().__init__(
+            client,
+            methods,
+            base_endpoint + "interestHistory",
+        )
+        self._get_resp_decoder = msgspec.json.Decoder(BinanceMarginInterestHistory)
+
+    class GetParameters(msgspec.Struct, omit_defaults=True, frozen=True):
+        """
+        Parameters of interestHistory GET request.
+
+        Parameters
+        ----------
+        timestamp : str
+            The millisecond timestamp of the request.
+        asset : str, optional
+            The asset of the interest.
+        isolatedSymbol : BinanceSymbol, optional
+            The isolated margin symbol (isolated margin only).
+        startTime : int, optional
+            The start time (UNIX milliseconds) filter for the request.
+        endTime : int, optional
+            The end time (UNIX milliseconds) filter for the request.
+        current : int, optional
+            The page of results to query (starting at 1).
+        size : int, optional
+            The page size for the response.
+            Default 10, max 100.
+        recvWindow : str, optional
+            The response receive window for the request (cannot be greater than 60000).
+
+        """
+
+        timestamp: str
+        asset: str | None = None
+        isolatedSymbol: BinanceSymbol | None = None
+        startTime: int | None = None
+        endTime: int | None = None
+        current: int | None = None
+        size: int | None = None
+        recvWindow: str | None = None
+
+    async def get(self, params: GetParameters) -> BinanceMarginInterestHistory:
+        method_type = HttpMethod.GET
+        raw = await self._method(method_type, params)
+        return self._get_resp_decoder.decode(raw)
+
+
+class BinanceSpotMarginHttpAPI:
+    """
+    Provides access to the Binance Margin HTTP REST API (loans and margin accounts).
+
+    Parameters
+    ----------
+    client : BinanceHttpClient
+        The Binance REST API client.
+    clock : LiveClock
+        The clock for the API client.
+    account_type : BinanceAccountType, default 'MARGIN'
+        The Binance account type, either MARGIN (cross) or ISOLATED_MARGIN.
+
+    """
+
+    def __init__(
+        self,
+        client: BinanceHttpClient,
+        clock: LiveClock,
+        account_type: BinanceAccountType = BinanceAccountType.MARGIN,
+    ):
+        self.client = client
+        self._clock = clock
+        self.base_endpoint = "/sapi/v1/margin/"
+
+        if not account_type.is_margin:
+            raise RuntimeError(  # pragma: no cover (design-time error)
+                f"`BinanceAccountType` not MARGIN or ISOLATED_MARGIN, was {account_type}",  # pragma: no cover
+            )
+
+        self._is_isolated = account_type == BinanceAccountType.ISOLATED_MARGIN
+
+        # Create endpoints
+        self._endpoint_borrow_repay = BinanceMarginBorrowRepayHttp(client, self.base_endpoint)
+        self._endpoint_account = BinanceMarginAccountHttp(client, self.base_endpoint)
+        self._endpoint_isolated_account = BinanceIsolatedMarginAccountHttp(
+            client,
+            self.base_endpoint,
+        )
+        self._endpoint_interest_history = BinanceMarginInterestHistoryHttp(
+            client,
+            self.base_endpoint,
+        )
+
+    def _timestamp(self) -> str:
+        """
+        Create Binance timestamp from internal clock.
+        """
+        return str(self._clock.timestamp_ms())
+
+    async def borrow_repay(
+        self,
+        asset: str,
+        amount: str,
+        loan_type: BinanceMarginLoanType,
+        symbol: str | None = None,
+        recv_window: str | None = None,
+    ) -> BinanceMarginBorrowRepayResponse:
+        """
+        Borrow or repay a margin loan of the asset.
+
+        The `symbol` is mandatory for isolated margin accounts.
+
+        """
+        if self._is_isolated and symbol is None:
+            raise ValueError("`symbol` must be provided for isolated margin loans")
+        return await self._endpoint_borrow_repay.post(
+            params=self._endpoint_borrow_repay.PostParameters(
+                asset=asset,
+                isIsolated="TRUE" if self._is_isolated else "FALSE",
+                amount=amount,
+                type=loan_type,
+                timestamp=self._timestamp(),
+                symbol=BinanceSymbol(symbol) if self._is_isolated and symbol else None,
+                recvWindow=recv_window,
+            ),
+        )
+
+    async def query_margin_account(
+        self,
+        recv_window: str | None = None,
+    ) -> BinanceMarginAccountInfo:
+        """
+        Check cross margin account information.
+        """
+        return await self._endpoint_account.get(
+            params=self._endpoint_account.GetParameters(
+                timestamp=self._timestamp(),
+                recvWindow=recv_window,
+            ),
+        )
+
+    async def query_isolated_margin_account(
+        self,
+        symbols: list[str] | None = None,
+        recv_window: str | None = None,
+    ) -> BinanceIsolatedMarginAccountInfo:
+        """
+        Check isolated margin account information, for all symbols if none provided.
+        """
+        return await self._endpoint_isolated_account.get(
+            params=self._endpoint_isolated_account.GetParameters(
+                timestamp=self._timestamp(),
+                symbols=",".join(BinanceSymbol(s) for s in symbols) if symbols else None,
+                recvWindow=recv_window,
+            ),
+        )
+
+    async def query_interest_history(
+        self,
+        asset: str | None = None,
+        isolated_symbol: str | None = None,
+        start_time: int | None = None,
+        end_time: int | None = None,
+        current: int | None = None,
+        size: int | None = None,
+        recv_window: str | None = None,
+    ) -> BinanceMarginInterestHistory:
+        """
+        Query the interest accrued on margin loans, with provided filters.
+        """
+        return await self._endpoint_interest_history.get(
+            params=self._endpoint_interest_history.GetParameters(
+                timestamp=self._timestamp(),
+                asset=asset,
+                isolatedSymbol=BinanceSymbol(isolated_symbol) if isolated_symbol else None,
+                startTime=start_time,
+                endTime=end_time,
+                current=current,
+                size=size,
+                recvWindow=recv_window,
+            ),
+        )
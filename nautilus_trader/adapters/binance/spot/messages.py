@@ -0,0 +1,78 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from decimal import Decimal
+
+from nautilus_trader.adapters.binance.spot.enums import BinanceMarginLoanType
+from nautilus_trader.core.message import Command
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.model.objects import Currency
+
+
+# The message bus endpoint of the Binance Margin execution client for loan commands
+BINANCE_MARGIN_BORROW_REPAY_ENDPOINT = "binance.exec.margin.borrow_repay"
+
+
+class BinanceMarginBorrowRepay(Command):
+    """
+    Represents a command to borrow or repay a Binance Margin loan.
+
+    Send to the `BINANCE_MARGIN_BORROW_REPAY_ENDPOINT` message bus endpoint of a
+    connected Binance MARGIN or ISOLATED_MARGIN execution client.
+
+    Parameters
+    ----------
+    loan_type : BinanceMarginLoanType
+        The loan type (BORROW or REPAY).
+    currency : Currency
+        The currency (asset) to borrow or repay.
+    amount : Decimal
+        The amount to borrow or repay.
+    symbol : str, optional
+        The Binance symbol of the isolated margin account (e.g. 'BTCUSDT').
+        If ``None`` then the execution client `isolated_margin_symbol` is used.
+        Ignored for cross margin.
+    command_id : UUID4, optional
+        The command ID.
+    ts_init : int
+        UNIX timestamp (nanoseconds) when the object was initialized.
+
+    """
+
+    def __init__(
+        self,
+        loan_type: BinanceMarginLoanType,
+        currency: Currency,
+        amount: Decimal,
+        symbol: str | None = None,
+        command_id: UUID4 | None = None,
+        ts_init: int = 0,
+    ) -> None:
+        super().__init__(command_id or UUID4(), ts_init)
+
+        self.loan_type = loan_type
+        self.currency = currency
+        self.amount = amount
+        self.symbol = symbol
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"loan_type={self.loan_type.value}, "
+            f"currency={self.currency}, "
+            f"amount={self.amount}, "
+            f"symbol={self.symbol}, "
+            f"command_id={self.id})"
+        )
@@ -0,0 +1,213 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from decimal import Decimal
+from typing import Any
+
+import msgspec
+
+from nautilus_trader.adapters.binance.spot.types import BinanceMarginInterest
+from nautilus_trader.core.datetime import millis_to_nanos
+from nautilus_trader.model.objects import AccountBalance
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import Money
+
+
+################################################################################
+# HTTP responses
+################################################################################
+
+
+class BinanceMarginAsset(msgspec.Struct, frozen=True):
+    """
+    HTTP response 'inner struct' from Binance Margin GET /sapi/v1/margin/account and
+    GET /sapi/v1/margin/isolated/account (HMAC SHA256).
+    """
+
+    asset: str
+    free: str
+    locked: str
+    borrowed: str
+    interest: str
+    netAsset: str
+
+    def parse_to_account_balance(self) -> AccountBalance:
+        currency = Currency.from_str(self.asset)
+        free = Decimal(self.free)
+        locked = Decimal(self.locked)
+        total: Decimal = free + locked
+        return AccountBalance(
+            total=Money(total, currency),
+            locked=Money(locked, currency),
+            free=Money(free, currency),
+        )
+
+
+class BinanceMarginAccountInfo(msgspec.Struct, frozen=True):
+    """
+    HTTP response from Binance Margin GET /sapi/v1/margin/account (HMAC SHA256).
+    """
+
+    borrowEnabled: bool
+    marginLevel: str
+    totalAssetOfBtc: str
+    totalLiabilityOfBtc: str
+    totalNetAssetOfBtc: str
+    tradeEnabled: bool
+    userAssets: list[BinanceMarginAsset]
+    transferEnabled: bool | None = None
+    collateralMarginLevel: str | None = None
+    accountType: str | None = None
+
+    def parse_to_account_balances(self) -> list[AccountBalance]:
+        return [asset.parse_to_account_balance() for asset in self.userAssets]
+
+    def parse_to_info(self) -> dict[str, Any]:
+        """
+        Return the margin level and liabilities of the account, for the account state
+        `info`.
+        """
+        return {
+            "margin_level": self.marginLevel,
+            "total_liability_of_btc": self.totalLiabilityOfBtc,
+            **_parse_liabilities(self.userAssets),
+        }
+
+
+class BinanceIsolatedMarginSymbol(msgspec.Struct, frozen=True):
+    """
+    HTTP response 'inner struct' from Binance Margin GET /sapi/v1/margin/isolated/account
+    (HMAC SHA256).
+    """
+
+    symbol: str
+    baseAsset: BinanceMarginAsset
+    quoteAsset: BinanceMarginAsset
+    marginLevel: str
+    marginLevelStatus: str | None = None
+    liquidatePrice: str | None = None
+    tradeEnabled: bool | None = None
+
+
+class BinanceIsolatedMarginAccountInfo(msgspec.Struct, frozen=True):
+    """
+    HTTP response from Binance Margin GET /sapi/v1/margin/isolated/account (HMAC SHA256).
+    """
+
+    assets: list[BinanceIsolatedMarginSymbol]
+    totalAssetOfBtc: str | None = None
+    totalLiabilityOfBtc: str | None = None
+    totalNetAssetOfBtc: str | None = None
+
+    def parse_to_account_balances(self) -> list[AccountBalance]:
+        # Assets are held per symbol, so aggregate the balances of each currency
+        free: dict[str, Decimal] = {}
+        locked: dict[str, Decimal] = {}
+        for asset in self._margin_assets():
+            free[asset.asset] = free.get(asset.asset, Decimal(0)) + Decimal(asset.free)
+            locked[asset.asset] = locked.get(asset.asset, Decimal(0)) + Decimal(asset.locked)
+
+        balances: list[AccountBalance] = []
+        for code, asset_free in free.items():
+            currency = Currency.from_str(code)
+            asset_locked = locked[code]
+            balances.append(
+                AccountBalance(
+                    total=Money(asset_free + asset_locked, currency),
+                    locked=Money(asset_locked, currency),
+                    free=Money(asset_free, currency),
+                ),
+            )
+        return balances
+
+    def parse_to_info(self) -> dict[str, Any]:
+        """
+        Return the margin levels and liabilities of the account, for the account state
+        `info`.
+
+        The `margin_level` is the lowest margin level of the isolated symbols.
+
+        """
+        margin_levels = {symbol.symbol: symbol.marginLevel for symbol in self.assets}
+        margin_level = min(margin_levels.values(), key=Decimal) if margin_levels else None
+        return {
+            "margin_level": margin_level,
+            "margin_levels": margin_levels,
+            "total_liability_of_btc": self.totalLiabilityOfBtc,
+            **_parse_liabilities(self._margin_assets()),
+        }
+
+    def _margin_assets(self) -> list[BinanceMarginAsset]:
+        return [a for symbol in self.assets for a in (symbol.baseAsset, symbol.quoteAsset)]
+
+
+class BinanceMarginBorrowRepayResponse(msgspec.Struct, frozen=True):
+    """
+    HTTP response from Binance Margin POST /sapi/v1/margin/borrow-repay (HMAC SHA256).
+    """
+
+    tranId: int
+
+
+class BinanceMarginInterestRecord(msgspec.Struct, frozen=True):
+    """
+    HTTP response 'inner struct' from Binance Margin GET /sapi/v1/margin/interestHistory
+    (HMAC SHA256).
+    """
+
+    asset: str
+    interest: str
+    interestAccuredTime: int
+    interestRate: str
+    principal: str
+    type: str
+    isolatedSymbol: str | None = None
+    txId: int | None = None
+
+    def parse_to_margin_interest(self, ts_init: int) -> BinanceMarginInterest:
+        return BinanceMarginInterest(
+            currency=Currency.from_str(self.asset),
+            principal=Decimal(self.principal),
+            interest=Decimal(self.interest),
+            interest_rate=Decimal(self.interestRate),
+            interest_type=self.type,
+            isolated_symbol=self.isolatedSymbol,
+            ts_event=millis_to_nanos(self.interestAccuredTime),
+            ts_init=ts_init,
+        )
+
+
+class BinanceMarginInterestHistory(msgspec.Struct, frozen=True):
+    """
+    HTTP response from Binance Margin GET /sapi/v1/margin/interestHistory (HMAC SHA256).
+    """
+
+    rows: list[BinanceMarginInterestRecord]
+    total: int
+
+
+def _parse_liabilities(assets: list[BinanceMarginAsset]) -> dict[str, dict[str, str]]:
+    # Only assets with outstanding liabilities are included
+    borrowed: dict[str, Decimal] = {}
+    interest: dict[str, Decimal] = {}
+    for asset in assets:
+        if Decimal(asset.borrowed) > 0:
+            borrowed[asset.asset] = borrowed.get(asset.asset, Decimal(0)) + Decimal(asset.borrowed)
+        if Decimal(asset.interest) > 0:
+            interest[asset.asset] = interest.get(asset.asset, Decimal(0)) + Decimal(asset.interest)
+    return {
+        "borrowed": {code: str(value) for code, value in borrowed.items()},
+        "interest": {code: str(value) for code, value in interest.items()},
+    }
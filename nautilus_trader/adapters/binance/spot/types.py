@@ -0,0 +1,155 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from decimal import Decimal
+from typing import Any
+
+from nautilus_trader.core.data import Data
+from nautilus_trader.model.objects import Currency
+
+
+class BinanceMarginInterest(Data):
+    """
+    Represents interest accrued on a Binance Margin loan.
+
+    Parameters
+    ----------
+    currency : Currency
+        The currency of the loan (and interest).
+    principal : Decimal
+        The borrowed principal the interest accrued on.
+    interest : Decimal
+        The interest amount accrued.
+    interest_rate : Decimal
+        The (hourly) interest rate applied.
+    interest_type : str
+        The Binance interest type (e.g. 'PERIODIC', 'ON_BORROW').
+    isolated_symbol : str, optional
+        The Binance symbol of the isolated margin account (``None`` for cross margin).
+    ts_event : uint64_t
+        UNIX timestamp (nanoseconds) when the interest accrued.
+    ts_init : uint64_t
+        UNIX timestamp (nanoseconds) when the data object was initialized.
+
+    References
+    ----------
+    https://binance-docs.github.io/apidocs/spot/en/#get-interest-history-user_data
+
+    """
+
+    def __init__(
+        self,
+        currency: Currency,
+        principal: Decimal,
+        interest: Decimal,
+        interest_rate: Decimal,
+        interest_type: str,
+        isolated_symbol: str | None,
+        ts_event: int,
+        ts_init: int,
+    ):
+        self.currency = currency
+        self.principal = principal
+        self.interest = interest
+        self.interest_rate = interest_rate
+        self.interest_type = interest_type
+        self.isolated_symbol = isolated_symbol
+        self._ts_event = ts_event
+        self._ts_init = ts_init
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"currency={self.currency}, "
+            f"principal={self.principal}, "
+            f"interest={self.interest}, "
+            f"interest_rate={self.interest_rate}, "
+            f"interest_type={self.interest_type}, "
+            f"isolated_symbol={self.isolated_symbol}, "
+            f"ts_event={self.ts_event}, "
+            f"ts_init={self.ts_init})"
+        )
+
+    @property
+    def ts_event(self) -> int:
+        """
+        UNIX timestamp (nanoseconds) when the data event occurred.
+
+        Returns
+        -------
+        int
+
+        """
+        return self._ts_event
+
+    @property
+    def ts_init(self) -> int:
+        """
+        UNIX timestamp (nanoseconds) when the object was initialized.
+
+        Returns
+        -------
+        int
+
+        """
+        return self._ts_init
+
+    @staticmethod
+    def from_dict(values: dict[str, Any]) -> "BinanceMarginInterest":
+        """
+        Return a Binance Margin interest parsed from the given values.
+
+        Parameters
+        ----------
+        values : dict[str, Any]
+            The values for initialization.
+
+        Returns
+        -------
+        BinanceMarginInterest
+
+        """
+        return BinanceMarginInterest(
+            currency=Currency.from_str(values["currency"]),
+            principal=Decimal(values["principal"]),
+            interest=Decimal(values["interest"]),
+            interest_rate=Decimal(values["interest_rate"]),
+            interest_type=values["interest_type"],
+            isolated_symbol=values["isolated_symbol"],
+            ts_event=values["ts_event"],
+            ts_init=values["ts_init"],
+        )
+
+    @staticmethod
+    def to_dict(obj: "BinanceMarginInterest") -> dict[str, Any]:
+        """
+        Return a dictionary representation of this object.
+
+        Returns
+        -------
+        dict[str, Any]
+
+        """
+        return {
+            "type": type(obj).__name__,
+            "currency": obj.currency.code,
+            "principal": str(obj.principal),
+            "interest": str(obj.interest),
+            "interest_rate": str(obj.interest_rate),
+            "interest_type": obj.interest_type,
+            "isolated_symbol": obj.isolated_symbol,
+            "ts_event": obj.ts_event,
+            "ts_init": obj.ts_init,
+        }
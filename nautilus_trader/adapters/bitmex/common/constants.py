@@ -0,0 +1,53 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from typing import Final
+
+from nautilus_trader.model.identifiers import Venue
+
+
+BITMEX: Final[str] = "BITMEX"
+BITMEX_VENUE: Final[Venue] = Venue(BITMEX)
+
+# Settlement currency codes are reported in their minor units (e.g. `XBt` for satoshis),
+# with amounts (balances, margins and commissions) denominated in the minor unit
+BITMEX_CURRENCY_MINOR_UNITS: Final[dict[str, int]] = {
+    "XBt": 100_000_000,
+    "USDt": 1_000_000,
+}
+
+# BitMEX currency codes which differ from the standard codes
+BITMEX_CURRENCY_CODES: Final[dict[str, str]] = {
+    "XBT": "BTC",
+    "XBt": "BTC",
+    "USDt": "USDT",
+}
+
+# The instrument `typ` codes for perpetual swaps and futures
+BITMEX_PERPETUAL_TYPES: Final[set[str]] = {"FFWCSX", "FFWCSF"}
+BITMEX_FUTURE_TYPES: Final[set[str]] = {"FFCCSX"}
+
+# The number of seconds a signed request is valid for (the `api-expires` header)
+BITMEX_SIGNATURE_EXPIRY_SECS: Final[int] = 60
+
+BITMEX_RETRY_ERRORS: Final[set[int | str]] = {
+    429,  # Too many requests
+    502,  # Bad gateway
+    503,  # Service unavailable (system overloaded)
+    504,  # Gateway timeout
+}
+
+BITMEX_REST_RATE_LIMIT_KEY: Final[str] = "bitmex:rest"
+BITMEX_ORDER_RATE_LIMIT_KEY: Final[str] = "bitmex:order"
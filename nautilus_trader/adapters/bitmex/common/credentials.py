@@ -0,0 +1,32 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from nautilus_trader.adapters.env import get_env_key
+
+
+def get_api_key(is_testnet: bool) -> str:
+    name = "BITMEX_TESTNET_API_KEY" if is_testnet else "BITMEX_API_KEY"
+    key = get_env_key(name)
+    if not key:
+        raise ValueError(f"{name} environment variable not set")
+    return key
+
+
+def get_api_secret(is_testnet: bool) -> str:
+    name = "BITMEX_TESTNET_API_SECRET" if is_testnet else "BITMEX_API_SECRET"
+    secret = get_env_key(name)
+    if not secret:
+        raise ValueError(f"{name} environment variable not set")
+    return secret
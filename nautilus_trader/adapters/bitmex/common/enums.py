@@ -0,0 +1,200 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from enum import Enum
+from enum import unique
+
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import TimeInForce
+from nautilus_trader.model.enums import TriggerType
+from nautilus_trader.model.enums import trigger_type_to_str
+
+
+@unique
+class BitmexOrderSide(Enum):
+    BUY = "Buy"
+    SELL = "Sell"
+
+
+@unique
+class BitmexOrderType(Enum):
+    MARKET = "Market"
+    LIMIT = "Limit"
+    STOP = "Stop"
+    STOP_LIMIT = "StopLimit"
+    MARKET_IF_TOUCHED = "MarketIfTouched"
+    LIMIT_IF_TOUCHED = "LimitIfTouched"
+    PEGGED = "Pegged"
+
+
+@unique
+class BitmexTimeInForce(Enum):
+    DAY = "Day"
+    GTC = "GoodTillCancel"
+    IOC = "ImmediateOrCancel"
+    FOK = "FillOrKill"
+
+
+@unique
+class BitmexOrderStatus(Enum):
+    NEW = "New"
+    PARTIALLY_FILLED = "PartiallyFilled"
+    FILLED = "Filled"
+    CANCELED = "Canceled"
+    REJECTED = "Rejected"
+    EXPIRED = "Expired"
+
+
+@unique
+class BitmexExecInstruction(Enum):
+    PARTICIPATE_DO_NOT_INITIATE = "ParticipateDoNotInitiate"  # Post-only
+    REDUCE_ONLY = "ReduceOnly"
+    CLOSE = "Close"
+    MARK_PRICE = "MarkPrice"
+    LAST_PRICE = "LastPrice"
+    INDEX_PRICE = "IndexPrice"
+
+
+@unique
+class BitmexLiquidityIndicator(Enum):
+    ADDED = "AddedLiquidity"
+    REMOVED = "RemovedLiquidity"
+
+
+def check_dict_keys(key, data):
+    try:
+        return data[key]
+    except KeyError as e:
+        raise RuntimeError(
+            f"Unrecognized BitMEX {key} not found in {data}",
+        ) from e
+
+
+def parse_exec_instructions(exec_inst: str | None) -> set[BitmexExecInstruction]:
+    """
+    Parse the comma separated BitMEX execution instructions (unknown values are ignored).
+
+    Parameters
+    ----------
+    exec_inst : str, optional
+        The execution instructions, e.g. `ParticipateDoNotInitiate,ReduceOnly`.
+
+    Returns
+    -------
+    set[BitmexExecInstruction]
+
+    """
+    if not exec_inst:
+        return set()
+
+    values = {value.value for value in BitmexExecInstruction}
+    return {BitmexExecInstruction(inst) for inst in exec_inst.split(",") if inst in values}
+
+
+class BitmexEnumParser:
+    def __init__(self) -> None:
+        self.bitmex_to_nautilus_order_side = {
+            BitmexOrderSide.BUY: OrderSide.BUY,
+            BitmexOrderSide.SELL: OrderSide.SELL,
+        }
+        self.nautilus_to_bitmex_order_side = {
+            b: a for a, b in self.bitmex_to_nautilus_order_side.items()
+        }
+        self.bitmex_to_nautilus_order_type = {
+            BitmexOrderType.MARKET: OrderType.MARKET,
+            BitmexOrderType.LIMIT: OrderType.LIMIT,
+            BitmexOrderType.STOP: OrderType.STOP_MARKET,
+            BitmexOrderType.STOP_LIMIT: OrderType.STOP_LIMIT,
+            BitmexOrderType.MARKET_IF_TOUCHED: OrderType.MARKET_IF_TOUCHED,
+            BitmexOrderType.LIMIT_IF_TOUCHED: OrderType.LIMIT_IF_TOUCHED,
+            BitmexOrderType.PEGGED: OrderType.LIMIT,
+        }
+        self.nautilus_to_bitmex_order_type = {
+            OrderType.MARKET: BitmexOrderType.MARKET,
+            OrderType.LIMIT: BitmexOrderType.LIMIT,
+            OrderType.STOP_MARKET: BitmexOrderType.STOP,
+            OrderType.STOP_LIMIT: BitmexOrderType.STOP_LIMIT,
+            OrderType.MARKET_IF_TOUCHED: BitmexOrderType.MARKET_IF_TOUCHED,
+            OrderType.LIMIT_IF_TOUCHED: BitmexOrderType.LIMIT_IF_TOUCHED,
+        }
+        self.bitmex_to_nautilus_time_in_force = {
+            BitmexTimeInForce.DAY: TimeInForce.DAY,
+            BitmexTimeInForce.GTC: TimeInForce.GTC,
+            BitmexTimeInForce.IOC: TimeInForce.IOC,
+            BitmexTimeInForce.FOK: TimeInForce.FOK,
+        }
+        self.nautilus_to_bitmex_time_in_force = {
+            b: a for a, b in self.bitmex_to_nautilus_time_in_force.items()
+        }
+        self.bitmex_to_nautilus_order_status = {
+            BitmexOrderStatus.NEW: OrderStatus.ACCEPTED,
+            BitmexOrderStatus.PARTIALLY_FILLED: OrderStatus.PARTIALLY_FILLED,
+            BitmexOrderStatus.FILLED: OrderStatus.FILLED,
+            BitmexOrderStatus.CANCELED: OrderStatus.CANCELED,
+            BitmexOrderStatus.REJECTED: OrderStatus.REJECTED,
+            BitmexOrderStatus.EXPIRED: OrderStatus.EXPIRED,
+        }
+        self.bitmex_to_nautilus_trigger_type = {
+            BitmexExecInstruction.MARK_PRICE: TriggerType.MARK_PRICE,
+            BitmexExecInstruction.LAST_PRICE: TriggerType.LAST_PRICE,
+            BitmexExecInstruction.INDEX_PRICE: TriggerType.INDEX_PRICE,
+        }
+        self.nautilus_to_bitmex_trigger_type = {
+            b: a for a, b in self.bitmex_to_nautilus_trigger_type.items()
+        }
+
+    def parse_bitmex_order_side(self, order_side: BitmexOrderSide) -> OrderSide:
+        return check_dict_keys(order_side, self.bitmex_to_nautilus_order_side)
+
+    def parse_nautilus_order_side(self, order_side: OrderSide) -> BitmexOrderSide:
+        return check_dict_keys(order_side, self.nautilus_to_bitmex_order_side)
+
+    def parse_bitmex_order_type(self, order_type: BitmexOrderType) -> OrderType:
+        return check_dict_keys(order_type, self.bitmex_to_nautilus_order_type)
+
+    def parse_nautilus_order_type(self, order_type: OrderType) -> BitmexOrderType:
+        return check_dict_keys(order_type, self.nautilus_to_bitmex_order_type)
+
+    def parse_bitmex_time_in_force(self, time_in_force: BitmexTimeInForce) -> TimeInForce:
+        return check_dict_keys(time_in_force, self.bitmex_to_nautilus_time_in_force)
+
+    def parse_nautilus_time_in_force(self, time_in_force: TimeInForce) -> BitmexTimeInForce:
+        return check_dict_keys(time_in_force, self.nautilus_to_bitmex_time_in_force)
+
+    def parse_bitmex_order_status(self, order_status: BitmexOrderStatus) -> OrderStatus:
+        return check_dict_keys(order_status, self.bitmex_to_nautilus_order_status)
+
+    def parse_bitmex_trigger_type(
+        self,
+        exec_instructions: set[BitmexExecInstruction],
+    ) -> TriggerType:
+        # Stop orders trigger on the mark price unless instructed otherwise
+        for inst in exec_instructions:
+            trigger_type = self.bitmex_to_nautilus_trigger_type.get(inst)
+            if trigger_type is not None:
+                return trigger_type
+        return TriggerType.MARK_PRICE
+
+    def parse_nautilus_trigger_type(self, trigger_type: TriggerType) -> BitmexExecInstruction:
+        if trigger_type == TriggerType.DEFAULT:
+            return BitmexExecInstruction.MARK_PRICE
+        try:
+            return self.nautilus_to_bitmex_trigger_type[trigger_type]
+        except KeyError as e:
+            raise RuntimeError(
+                f"unrecognized BitMEX trigger type, was {trigger_type_to_str(trigger_type)}",  # pragma: no cover
+            ) from e
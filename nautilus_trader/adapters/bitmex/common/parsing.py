@@ -0,0 +1,71 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from decimal import Decimal
+
+from nautilus_trader.adapters.bitmex.common.constants import BITMEX_CURRENCY_CODES
+from nautilus_trader.adapters.bitmex.common.constants import BITMEX_CURRENCY_MINOR_UNITS
+from nautilus_trader.model.enums import AggressorSide
+
+
+def normalize_bitmex_currency(code: str) -> str:
+    """
+    Return the common currency code for the given BitMEX currency code.
+
+    BitMEX uses `XBT` in place of `BTC`, and reports settlement currencies in
+    their minor units (e.g. `XBt` for satoshis and `USDt` for micro USDT).
+
+    Parameters
+    ----------
+    code : str
+        The BitMEX currency code.
+
+    Returns
+    -------
+    str
+
+    """
+    return BITMEX_CURRENCY_CODES.get(code, code.upper())
+
+
+def parse_bitmex_amount(value: int | float, code: str) -> Decimal:
+    """
+    Return the amount in the major unit of the given BitMEX currency.
+
+    Parameters
+    ----------
+    value : int or float
+        The amount reported by BitMEX.
+    code : str
+        The BitMEX currency code (e.g. `XBt`).
+
+    Returns
+    -------
+    Decimal
+
+    """
+    return Decimal(str(value)) / BITMEX_CURRENCY_MINOR_UNITS.get(code, 1)
+
+
+def parse_aggressor_side(value: str) -> AggressorSide:
+    match value:
+        case "Buy":
+            return AggressorSide.BUYER
+        case "Sell":
+            return AggressorSide.SELLER
+        case _:
+            raise ValueError(f"Invalid aggressor side value, was '{value}'")
@@ -0,0 +1,42 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from nautilus_trader.adapters.bitmex.common.constants import BITMEX_VENUE
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import Symbol
+
+
+class BitmexSymbol(str):
+    """
+    Represents a BitMEX contract symbol, e.g. `XBTUSD`, `XBTUSDT` or `XBTZ25`.
+    """
+
+    def __new__(cls, symbol: str) -> BitmexSymbol:  # noqa: PYI034
+        PyCondition.valid_string(symbol, "symbol")
+        return super().__new__(cls, symbol.upper())
+
+    def to_instrument_id(self) -> InstrumentId:
+        """
+        Parse the BitMEX symbol into a Nautilus instrument ID.
+
+        Returns
+        -------
+        InstrumentId
+
+        """
+        return InstrumentId(Symbol(str(self)), BITMEX_VENUE)
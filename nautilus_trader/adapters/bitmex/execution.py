@@ -0,0 +1,807 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+from typing import Any
+
+import msgspec
+
+from nautilus_trader.adapters.bitmex.common.constants import BITMEX_VENUE
+from nautilus_trader.adapters.bitmex.common.enums import BitmexEnumParser
+from nautilus_trader.adapters.bitmex.common.enums import BitmexExecInstruction
+from nautilus_trader.adapters.bitmex.common.enums import BitmexOrderSide
+from nautilus_trader.adapters.bitmex.common.enums import BitmexOrderStatus
+from nautilus_trader.adapters.bitmex.common.symbol import BitmexSymbol
+from nautilus_trader.adapters.bitmex.http.account import BitmexAccountHttpAPI
+from nautilus_trader.adapters.bitmex.http.errors import BitmexError
+from nautilus_trader.adapters.bitmex.http.errors import should_retry
+from nautilus_trader.adapters.bitmex.http.trade import BitmexTradeHttpAPI
+from nautilus_trader.adapters.bitmex.http.trade import order_params
+from nautilus_trader.adapters.bitmex.schemas.order import BitmexExecution
+from nautilus_trader.adapters.bitmex.schemas.user import BitmexMargin
+from nautilus_trader.adapters.bitmex.schemas.ws import BitmexWsExecutionMsg
+from nautilus_trader.adapters.bitmex.schemas.ws import BitmexWsMarginMsg
+from nautilus_trader.adapters.bitmex.schemas.ws import BitmexWsMessageGeneral
+from nautilus_trader.adapters.bitmex.websocket.client import BitmexWebSocketClient
+from nautilus_trader.common.enums import LogColor
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.live.execution_client import LiveExecutionClient
+from nautilus_trader.live.retry import RetryManagerPool
+from nautilus_trader.model.enums import AccountType
+from nautilus_trader.model.enums import OmsType
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import order_type_to_str
+from nautilus_trader.model.enums import time_in_force_to_str
+from nautilus_trader.model.identifiers import AccountId
+from nautilus_trader.model.identifiers import ClientId
+from nautilus_trader.model.identifiers import ClientOrderId
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import TradeId
+from nautilus_trader.model.identifiers import VenueOrderId
+from nautilus_trader.model.orders import Order
+
+
+if TYPE_CHECKING:
+    import asyncio
+
+    import pandas as pd
+
+    from nautilus_trader.adapters.bitmex.config import BitmexExecClientConfig
+    from nautilus_trader.adapters.bitmex.http.client import BitmexHttpClient
+    from nautilus_trader.adapters.bitmex.providers import BitmexInstrumentProvider
+    from nautilus_trader.adapters.bitmex.schemas.order import BitmexOrder
+    from nautilus_trader.cache.cache import Cache
+    from nautilus_trader.common.component import LiveClock
+    from nautilus_trader.common.component import MessageBus
+    from nautilus_trader.execution.messages import CancelAllOrders
+    from nautilus_trader.execution.messages import CancelOrder
+    from nautilus_trader.execution.messages import ModifyOrder
+    from nautilus_trader.execution.messages import SubmitOrder
+    from nautilus_trader.execution.reports import FillReport
+    from nautilus_trader.execution.reports import OrderStatusReport
+    from nautilus_trader.execution.reports import PositionStatusReport
+    from nautilus_trader.model.instruments import Instrument
+
+
+# Order types which are triggered into a resting limit order
+_TRIGGERED_LIMIT_ORDER_TYPES = (OrderType.STOP_LIMIT, OrderType.LIMIT_IF_TOUCHED)
+
+# Order types which are sent with a trigger price (`stopPx`)
+_TRIGGER_ORDER_TYPES = (
+    OrderType.STOP_MARKET,
+    OrderType.STOP_LIMIT,
+    OrderType.MARKET_IF_TOUCHED,
+    OrderType.LIMIT_IF_TOUCHED,
+)
+
+
+class BitmexExecutionClient(LiveExecutionClient):
+    """
+    Provides an execution client for the BitMEX derivatives exchange.
+
+    Orders are sent through the signed REST API, and order events are streamed on
+    the authenticated `execution` and `margin` WebSocket tables.
+
+    Parameters
+    ----------
+    loop : asyncio.AbstractEventLoop
+        The event loop for the client.
+    client : BitmexHttpClient
+        The BitMEX HTTP client (with API credentials).
+    msgbus : MessageBus
+        The message bus for the client.
+    cache : Cache
+        The cache for the client.
+    clock : LiveClock
+        The clock for the client.
+    instrument_provider : BitmexInstrumentProvider
+        The instrument provider.
+    base_url_ws : str
+        The base URL for the WebSocket client.
+    config : BitmexExecClientConfig
+        The configuration for the client.
+    name : str, optional
+        The custom client ID.
+
+    """
+
+    def __init__(
+        self,
+        loop: asyncio.AbstractEventLoop,
+        client: BitmexHttpClient,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+        instrument_provider: BitmexInstrumentProvider,
+        base_url_ws: str,
+        config: BitmexExecClientConfig,
+        name: str | None,
+    ) -> None:
+        super().__init__(
+            loop=loop,
+            client_id=ClientId(name or BITMEX_VENUE.value),
+            venue=BITMEX_VENUE,
+            oms_type=OmsType.NETTING,
+            instrument_provider=instrument_provider,
+            account_type=AccountType.MARGIN,
+            base_currency=None,  # Margined in the settlement currency of each contract
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+        )
+
+        # Configuration
+        self._log.info(f"{config.testnet=}", LogColor.BLUE)
+        self._log.info(f"{config.max_retries=}", LogColor.BLUE)
+        self._log.info(f"{config.retry_delay=}", LogColor.BLUE)
+
+        self._enum_parser = BitmexEnumParser()
+
+        account_id = AccountId(f"{name or BITMEX_VENUE.value}-master")
+        self._set_account_id(account_id)
+
+        # HTTP API
+        self._http_account = BitmexAccountHttpAPI(client=client, clock=clock)
+        self._http_trade = BitmexTradeHttpAPI(client=client, clock=clock)
+
+        # WebSocket API
+        self._ws_client = BitmexWebSocketClient(
+            clock=clock,
+            base_url=base_url_ws,
+            handler=self._handle_ws_message,
+            handler_reconnect=None,
+            loop=loop,
+            api_key=client.api_key,
+            api_secret=client.api_secret,
+        )
+
+        # Decoders
+        self._decoder_ws_msg_general = msgspec.json.Decoder(BitmexWsMessageGeneral)
+        self._decoder_ws_execution = msgspec.json.Decoder(BitmexWsExecutionMsg)
+        self._decoder_ws_margin = msgspec.json.Decoder(BitmexWsMarginMsg)
+
+        # Hot caches
+        self._margins: dict[str, dict[str, Any]] = {}
+
+        self._retry_manager_pool = RetryManagerPool[None](
+            pool_size=100,
+            max_retries=config.max_retries or 0,
+            retry_delay_secs=config.retry_delay or 0.0,
+            logger=self._log,
+            exc_types=(BitmexError,),
+            retry_check=should_retry,
+        )
+
+    async def _connect(self) -> None:
+        await self._instrument_provider.initialize()
+        await self._update_account_state()
+
+        await self._ws_client.connect()
+        await self._ws_client.subscribe_executions()
+        await self._ws_client.subscribe_margin()
+
+    async def _disconnect(self) -> None:
+        await self._ws_client.disconnect()
+
+    def _stop(self) -> None:
+        self._retry_manager_pool.shutdown()
+
+    # -- EXECUTION REPORTS ------------------------------------------------------------------------
+
+    async def generate_order_status_reports(
+        self,
+        instrument_id: InstrumentId | None = None,
+        start: pd.Timestamp | None = None,
+        end: pd.Timestamp | None = None,
+        open_only: bool = False,
+    ) -> list[OrderStatusReport]:
+        self._log.debug("Requesting OrderStatusReports...")
+        reports: list[OrderStatusReport] = []
+
+        try:
+            bitmex_orders = await self._http_trade.fetch_orders(
+                symbol=instrument_id.symbol.value if instrument_id is not None else None,
+                open_only=open_only,
+                start_time=start.to_pydatetime() if start is not None else None,
+                end_time=end.to_pydatetime() if end is not None else None,
+            )
+            for bitmex_order in bitmex_orders:
+                report = self._parse_order_status_report(bitmex_order)
+                if report is None:
+                    continue
+                reports.append(report)
+                self._log.debug(f"Received {report}", LogColor.MAGENTA)
+        except BitmexError as e:
+            self._log.error(f"Failed to generate OrderStatusReports: {e}")
+
+        len_reports = len(reports)
+        plural = "" if len_reports == 1 else "s"
+        self._log.info(f"Received {len(reports)} OrderStatusReport{plural}")
+
+        return reports
+
+    async def generate_order_status_report(
+        self,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId | None = None,
+        venue_order_id: VenueOrderId | None = None,
+    ) -> OrderStatusReport | None:
+        PyCondition.is_false(
+            client_order_id is None and venue_order_id is None,
+            "both `client_order_id` and `venue_order_id` were `None`",
+        )
+
+        self._log.info(
+            f"Generating OrderStatusReport for "
+            f"{repr(client_order_id) if client_order_id else ''} "
+            f"{repr(venue_order_id) if venue_order_id else ''}",
+        )
+
+        try:
+            # Orders can be queried by venue order ID or client order ID (clOrdID)
+            bitmex_orders = await self._http_trade.fetch_orders(
+                symbol=instrument_id.symbol.value,
+                order_id=venue_order_id.value if venue_order_id is not None else None,
+                client_order_id=(
+                    client_order_id.value
+                    if venue_order_id is None and client_order_id is not None
+                    else None
+                ),
+                count=1,
+            )
+            if not bitmex_orders:
+                self._log.warning(f"No order found for {client_order_id!r} {venue_order_id!r}")
+                return None
+
+            report = self._parse_order_status_report(bitmex_orders[0])
+            if report is not None:
+                self._log.debug(f"Received {report}", LogColor.MAGENTA)
+            return report
+        except BitmexError as e:
+            self._log.error(f"Failed to generate OrderStatusReport: {e}")
+        return None
+
+    async def generate_fill_reports(
+        self,
+        instrument_id: InstrumentId | None = None,
+        venue_order_id: VenueOrderId | None = None,
+        start: pd.Timestamp | None = None,
+        end: pd.Timestamp | None = None,
+    ) -> list[FillReport]:
+        self._log.debug("Requesting FillReports...")
+        reports: list[FillReport] = []
+
+        try:
+            executions = await self._http_trade.fetch_trade_history(
+                symbol=instrument_id.symbol.value if instrument_id is not None else None,
+                order_id=venue_order_id.value if venue_order_id is not None else None,
+                start_time=start.to_pydatetime() if start is not None else None,
+                end_time=end.to_pydatetime() if end is not None else None,
+            )
+            for execution in executions:
+                if execution.execType != "Trade":
+                    continue  # Funding and settlement
+
+                instrument = self._get_cached_instrument(execution.symbol)
+                if instrument is None:
+                    continue
+
+                report = execution.parse_to_fill_report(
+                    account_id=self.account_id,
+                    instrument=instrument,
+                    client_order_id=self._parse_client_order_id(
+                        execution.clOrdID,
+                        VenueOrderId(execution.orderID),
+                    ),
+                    report_id=UUID4(),
+                    enum_parser=self._enum_parser,
+                    ts_init=self._clock.timestamp_ns(),
+                )
+                reports.append(report)
+                self._log.debug(f"Received {report}")
+        except BitmexError as e:
+            self._log.error(f"Failed to generate FillReports: {e}")
+
+        len_reports = len(reports)
+        plural = "" if len_reports == 1 else "s"
+        self._log.info(f"Received {len(reports)} FillReport{plural}")
+
+        return reports
+
+    async def generate_position_status_reports(
+        self,
+        instrument_id: InstrumentId | None = None,
+        start: pd.Timestamp | None = None,
+        end: pd.Timestamp | None = None,
+    ) -> list[PositionStatusReport]:
+        reports: list[PositionStatusReport] = []
+
+        try:
+            self._log.debug("Requesting PositionStatusReports...")
+            positions = await self._http_account.fetch_positions()
+            for position in positions:
+                instrument = self._get_cached_instrument(position.symbol)
+                if instrument is None:
+                    continue
+                if instrument_id is not None and instrument.id != instrument_id:
+                    continue
+
+                position_report = position.parse_to_position_status_report(
+                    account_id=self.account_id,
+                    instrument=instrument,
+                    report_id=UUID4(),
+                    ts_init=self._clock.timestamp_ns(),
+                )
+                self._log.debug(f"Received {position_report}")
+                reports.append(position_report)
+        except BitmexError as e:
+            self._log.error(f"Failed to generate PositionReports: {e}")
+
+        len_reports = len(reports)
+        plural = "" if len_reports == 1 else "s"
+        self._log.info(f"Received {len(reports)} PositionReport{plural}")
+
+        return reports
+
+    def _parse_order_status_report(self, bitmex_order: BitmexOrder) -> OrderStatusReport | None:
+        instrument = self._get_cached_instrument(bitmex_order.symbol)
+        if instrument is None:
+            return None
+
+        return bitmex_order.parse_to_order_status_report(
+            account_id=self.account_id,
+            instrument=instrument,
+            client_order_id=self._parse_client_order_id(
+                bitmex_order.clOrdID,
+                VenueOrderId(bitmex_order.orderID),
+            ),
+            report_id=UUID4(),
+            enum_parser=self._enum_parser,
+            ts_init=self._clock.timestamp_ns(),
+        )
+
+    def _get_cached_instrument(self, symbol: str) -> Instrument | None:
+        instrument_id = BitmexSymbol(symbol).to_instrument_id()
+        instrument = self._cache.instrument(instrument_id)
+        if instrument is None:
+            self._log.debug(f"No instrument found for {instrument_id}")
+        return instrument
+
+    def _parse_client_order_id(
+        self,
+        cl_ord_id: str | None,
+        venue_order_id: VenueOrderId,
+    ) -> ClientOrderId | None:
+        # Orders placed through the web interface have no clOrdID
+        if cl_ord_id:
+            return ClientOrderId(cl_ord_id)
+        return self._cache.client_order_id(venue_order_id)
+
+    async def _update_account_state(self) -> None:
+        try:
+            margins = await self._http_account.fetch_margins()
+            self._margins = {margin.currency: msgspec.to_builtins(margin) for margin in margins}
+            self._generate_account_state(margins)
+        except Exception as e:
+            self._log.error(f"Failed to generate AccountState: {e}")
+
+    def _generate_account_state(self, margins: list[BitmexMargin]) -> None:
+        self.generate_account_state(
+            balances=[margin.parse_to_account_balance() for margin in margins],
+            margins=[margin.parse_to_margin_balance() for margin in margins],
+            reported=True,
+            ts_event=self._clock.timestamp_ns(),
+        )
+
+    # -- COMMAND HANDLERS -------------------------------------------------------------------------
+
+    async def _cancel_order(self, command: CancelOrder) -> None:
+        order: Order | None = self._cache.order(command.client_order_id)
+        if order is None:
+            self._log.error(f"{command.client_order_id!r} not found in cache")
+            return
+
+        if order.is_closed:
+            self._log.warning(
+                f"`CancelOrder` command for {command.client_order_id!r} when order already {order.status_string()} "
+                "(will not send to exchange)",
+            )
+            return
+
+        venue_order_id = command.venue_order_id or order.venue_order_id
+
+        async with self._retry_manager_pool as retry_manager:
+            await retry_manager.run(
+                "cancel_order",
+                [order.client_order_id, venue_order_id],
+                self._cancel_order_inner,
+                order.client_order_id,
+                venue_order_id,
+            )
+            if not retry_manager.result:
+                self.generate_order_cancel_rejected(
+                    order.strategy_id,
+                    order.instrument_id,
+                    order.client_order_id,
+                    order.venue_order_id,
+                    retry_manager.message,
+                    self._clock.timestamp_ns(),
+                )
+
+    async def _cancel_order_inner(
+        self,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId | None,
+    ) -> None:
+        # Orders can be canceled by venue order ID or client order ID (clOrdID)
+        if venue_order_id is not None:
+            results = await self._http_trade.cancel_orders(order_ids=[venue_order_id.value])
+        else:
+            results = await self._http_trade.cancel_orders(client_order_ids=[client_order_id.value])
+
+        for result in results:
+            if result.error:
+                raise BitmexError(code=None, message=result.error)
+
+    async def _cancel_all_orders(self, command: CancelAllOrders) -> None:
+        orders_open = [
+            order
+            for order in self._cache.orders_open(instrument_id=command.instrument_id)
+            if command.order_side in (OrderSide.NO_ORDER_SIDE, order.side)
+        ]
+        if not orders_open:
+            self._log.info(f"No open orders to cancel for {command.instrument_id}")
+            return
+
+        side: BitmexOrderSide | None = None
+        if command.order_side != OrderSide.NO_ORDER_SIDE:
+            side = self._enum_parser.parse_nautilus_order_side(command.order_side)
+
+        async with self._retry_manager_pool as retry_manager:
+            await retry_manager.run(
+                "cancel_all_orders",
+                None,
+                self._http_trade.cancel_all_orders,
+                command.instrument_id.symbol.value,
+                side,
+            )
+            if not retry_manager.result:
+                for order in orders_open:
+                    if order.is_closed:
+                        continue
+                    self.generate_order_cancel_rejected(
+                        order.strategy_id,
+                        order.instrument_id,
+                        order.client_order_id,
+                        order.venue_order_id,
+                        retry_manager.message,
+                        self._clock.timestamp_ns(),
+                    )
+
+    async def _modify_order(self, command: ModifyOrder) -> None:
+        order: Order | None = self._cache.order(command.client_order_id)
+        if order is None:
+            self._log.error(f"{command.client_order_id!r} not found in cache")
+            return
+
+        if order.is_closed:
+            self._log.warning(
+                f"`ModifyOrder` command for {command.client_order_id!r} when order already {order.status_string()} "
+                "(will not send to exchange)",
+            )
+            return
+
+        venue_order_id = command.venue_order_id or order.venue_order_id
+
+        # The order is updated from the `Replaced` execution once amended
+        async with self._retry_manager_pool as retry_manager:
+            await retry_manager.run(
+                "modify_order",
+                [order.client_order_id, venue_order_id],
+                self._http_trade.amend_order,
+                order_id=venue_order_id.value if venue_order_id is not None else None,
+                client_order_id=order.client_order_id.value,
+                quantity=str(command.quantity) if command.quantity else None,
+                price=str(command.price) if command.price else None,
+                trigger_price=str(command.trigger_price) if command.trigger_price else None,
+            )
+            if not retry_manager.result:
+                self.generate_order_modify_rejected(
+                    order.strategy_id,
+                    order.instrument_id,
+                    order.client_order_id,
+                    order.venue_order_id,
+                    retry_manager.message,
+                    self._clock.timestamp_ns(),
+                )
+
+    async def _submit_order(self, command: SubmitOrder) -> None:
+        order = command.order
+        if order.is_closed:
+            self._log.warning(f"Order {order} is already closed")
+            return
+
+        if not self._check_order_validity(order):
+            return
+
+        # Generate order submitted event, to ensure correct ordering of event
+        self.generate_order_submitted(
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=order.client_order_id,
+            ts_event=self._clock.timestamp_ns(),
+        )
+
+        async with self._retry_manager_pool as retry_manager:
+            await retry_manager.run(
+                "submit_order",
+                [order.client_order_id],
+                self._submit_order_inner,
+                order,
+            )
+            if not retry_manager.result:
+                self.generate_order_rejected(
+                    strategy_id=order.strategy_id,
+                    instrument_id=order.instrument_id,
+                    client_order_id=order.client_order_id,
+                    reason=retry_manager.message,
+                    ts_event=self._clock.timestamp_ns(),
+                )
+
+    def _check_order_validity(self, order: Order) -> bool:
+        if order.order_type not in self._enum_parser.nautilus_to_bitmex_order_type:
+            self._log.error(
+                f"Cannot submit {order}: {order_type_to_str(order.order_type)} orders "
+                "not supported for BitMEX",
+            )
+            return False
+
+        if order.is_quote_quantity:
+            self._log.error(f"Cannot submit {order}: quote quantity not supported for BitMEX")
+            return False
+
+        if order.time_in_force not in self._enum_parser.nautilus_to_bitmex_time_in_force:
+            self._log.error(
+                f"Cannot submit {order}: time in force "
+                f"{time_in_force_to_str(order.time_in_force)} unsupported on BitMEX",
+            )
+            return False
+
+        if order.is_post_only and order.order_type not in (
+            OrderType.LIMIT,
+            OrderType.STOP_LIMIT,
+            OrderType.LIMIT_IF_TOUCHED,
+        ):
+            self._log.error(
+                f"Cannot submit {order} has invalid post only {order.is_post_only}, unsupported on BitMEX",
+            )
+            return False
+
+        return True
+
+    def _build_exec_instructions(self, order: Order) -> list[BitmexExecInstruction]:
+        exec_instructions: list[BitmexExecInstruction] = []
+        if order.is_post_only:
+            exec_instructions.append(BitmexExecInstruction.PARTICIPATE_DO_NOT_INITIATE)
+        if order.is_reduce_only:
+            exec_instructions.append(BitmexExecInstruction.REDUCE_ONLY)
+        if order.order_type in _TRIGGER_ORDER_TYPES:
+            exec_instructions.append(
+                self._enum_parser.parse_nautilus_trigger_type(order.trigger_type),
+            )
+        return exec_instructions
+
+    async def _submit_order_inner(self, order: Order) -> None:
+        params = order_params(
+            symbol=order.instrument_id.symbol.value,
+            side=self._enum_parser.parse_nautilus_order_side(order.side),
+            order_type=self._enum_parser.parse_nautilus_order_type(order.order_type),
+            quantity=str(order.quantity),
+            client_order_id=order.client_order_id.value,
+            time_in_force=(
+                self._enum_parser.parse_nautilus_time_in_force(order.time_in_force)
+                if order.order_type != OrderType.MARKET
+                else None
+            ),
+            price=str(order.price) if order.has_price else None,
+            trigger_price=str(order.trigger_price) if order.has_trigger_price else None,
+            exec_instructions=self._build_exec_instructions(order),
+            display_qty=(
+                str(order.display_qty)
+                if order.has_price and order.display_qty is not None
+                else None
+            ),
+        )
+        bitmex_order = await self._http_trade.place_order(params)
+
+        # The execution table may not have accepted the order yet
+        current_order = self._cache.order(order.client_order_id)
+        if current_order is None or current_order.status != OrderStatus.SUBMITTED:
+            return
+
+        if bitmex_order.ordStatus == BitmexOrderStatus.REJECTED:
+            self.generate_order_rejected(
+                strategy_id=order.strategy_id,
+                instrument_id=order.instrument_id,
+                client_order_id=order.client_order_id,
+                reason=bitmex_order.text or "Rejected",
+                ts_event=self._clock.timestamp_ns(),
+            )
+        else:
+            self.generate_order_accepted(
+                strategy_id=order.strategy_id,
+                instrument_id=order.instrument_id,
+                client_order_id=order.client_order_id,
+                venue_order_id=VenueOrderId(bitmex_order.orderID),
+                ts_event=self._clock.timestamp_ns(),
+            )
+
+    # -- WEBSOCKET HANDLERS -----------------------------------------------------------------------
+
+    def _handle_ws_message(self, raw: bytes) -> None:
+        try:
+            msg = self._decoder_ws_msg_general.decode(raw)
+            if msg.table == "execution":
+                execution_msg = self._decoder_ws_execution.decode(raw)
+                if execution_msg.action == "partial":
+                    return  # Executions on subscription are reconciled through reports
+                for execution in execution_msg.data:
+                    self._handle_execution(execution)
+            elif msg.table == "margin":
+                margin_msg = self._decoder_ws_margin.decode(raw)
+                self._handle_margin(margin_msg)
+        except Exception as e:
+            self._log.exception(f"Failed to handle websocket message: {raw.decode()}", e)
+
+    def _handle_execution(self, execution: BitmexExecution) -> None:
+        venue_order_id = VenueOrderId(execution.orderID)
+        client_order_id = self._parse_client_order_id(execution.clOrdID, venue_order_id)
+        if client_order_id is None:
+            self._log.debug(
+                f"Cannot process execution for {venue_order_id!r}: no `ClientOrderId` found "
+                "(most likely due to being an external order)",
+            )
+            return
+
+        order = self._cache.order(client_order_id)
+        if order is None:
+            self._log.debug(f"Cannot find {client_order_id!r} (most likely an external order)")
+            return
+
+        ts_event = execution.ts_event
+
+        match execution.execType:
+            case "New":
+                if order.status == OrderStatus.SUBMITTED:
+                    self.generate_order_accepted(
+                        strategy_id=order.strategy_id,
+                        instrument_id=order.instrument_id,
+                        client_order_id=client_order_id,
+                        venue_order_id=venue_order_id,
+                        ts_event=ts_event,
+                    )
+            case "TriggeredOrActivatedBySystem":
+                if order.order_type in _TRIGGERED_LIMIT_ORDER_TYPES:
+                    self.generate_order_triggered(
+                        strategy_id=order.strategy_id,
+                        instrument_id=order.instrument_id,
+                        client_order_id=client_order_id,
+                        venue_order_id=venue_order_id,
+                        ts_event=ts_event,
+                    )
+            case "Rejected":
+                if order.status == OrderStatus.SUBMITTED:
+                    self.generate_order_rejected(
+                        strategy_id=order.strategy_id,
+                        instrument_id=order.instrument_id,
+                        client_order_id=client_order_id,
+                        reason=execution.text or "Rejected",
+                        ts_event=ts_event,
+                    )
+            case "Canceled":
+                if order.is_closed:
+                    return
+                self.generate_order_canceled(
+                    strategy_id=order.strategy_id,
+                    instrument_id=order.instrument_id,
+                    client_order_id=client_order_id,
+                    venue_order_id=venue_order_id,
+                    ts_event=ts_event,
+                )
+            case "Replaced":
+                self._handle_order_replaced(order, execution)
+            case "Trade":
+                self._handle_fill(order, execution)
+            case _:
+                self._log.debug(
+                    f"Ignoring execution {execution.execType} for {client_order_id!r}",
+                )
+
+    def _handle_order_replaced(self, order: Order, execution: BitmexExecution) -> None:
+        if order.is_closed:
+            return
+
+        instrument = self._get_cached_instrument(execution.symbol)
+        if instrument is None:
+            raise ValueError(f"Cannot handle amend: instrument for {execution.symbol} not found")
+
+        self.generate_order_updated(
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=order.client_order_id,
+            venue_order_id=VenueOrderId(execution.orderID),
+            quantity=(
+                instrument.make_qty(execution.orderQty)
+                if execution.orderQty is not None
+                else order.quantity
+            ),
+            price=(
+                instrument.make_price(execution.price)
+                if order.has_price and execution.price is not None
+                else None
+            ),
+            trigger_price=(
+                instrument.make_price(execution.stopPx)
+                if order.has_trigger_price and execution.stopPx is not None
+                else None
+            ),
+            ts_event=execution.ts_event,
+        )
+
+    def _handle_fill(self, order: Order, execution: BitmexExecution) -> None:
+        instrument = self._get_cached_instrument(execution.symbol)
+        if instrument is None:
+            raise ValueError(f"Cannot handle fill: instrument for {execution.symbol} not found")
+
+        if execution.lastQty is None or execution.lastPx is None:
+            self._log.warning(f"Ignoring trade execution with no fill: {execution}")
+            return
+
+        self.generate_order_filled(
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=order.client_order_id,
+            venue_order_id=VenueOrderId(execution.orderID),
+            venue_position_id=None,
+            trade_id=TradeId(execution.execID),
+            order_side=order.side,
+            order_type=order.order_type,
+            last_qty=instrument.make_qty(execution.lastQty),
+            last_px=instrument.make_price(execution.lastPx),
+            quote_currency=instrument.quote_currency,
+            commission=execution.parse_commission(),
+            liquidity_side=execution.liquidity_side,
+            ts_event=execution.ts_event,
+        )
+
+    def _handle_margin(self, msg: BitmexWsMarginMsg) -> None:
+        # Updates only include the changed fields, so are merged per currency
+        if msg.action == "partial":
+            self._margins.clear()
+
+        for data in msg.data:
+            currency = data.get("currency")
+            if currency is None:
+                continue
+            self._margins.setdefault(currency, {}).update(data)
+
+        margins = [msgspec.convert(margin, BitmexMargin) for margin in self._margins.values()]
+        if margins:
+            self._generate_account_state(margins)
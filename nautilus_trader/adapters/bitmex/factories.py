@@ -0,0 +1,244 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import asyncio
+from functools import lru_cache
+
+from nautilus_trader.adapters.bitmex.common.constants import BITMEX_ORDER_RATE_LIMIT_KEY
+from nautilus_trader.adapters.bitmex.common.constants import BITMEX_REST_RATE_LIMIT_KEY
+from nautilus_trader.adapters.bitmex.common.credentials import get_api_key
+from nautilus_trader.adapters.bitmex.common.credentials import get_api_secret
+from nautilus_trader.adapters.bitmex.common.urls import get_http_base_url
+from nautilus_trader.adapters.bitmex.common.urls import get_ws_base_url
+from nautilus_trader.adapters.bitmex.config import BitmexDataClientConfig
+from nautilus_trader.adapters.bitmex.config import BitmexExecClientConfig
+from nautilus_trader.adapters.bitmex.data import BitmexDataClient
+from nautilus_trader.adapters.bitmex.execution import BitmexExecutionClient
+from nautilus_trader.adapters.bitmex.http.client import BitmexHttpClient
+from nautilus_trader.adapters.bitmex.providers import BitmexInstrumentProvider
+from nautilus_trader.cache.cache import Cache
+from nautilus_trader.common.component import LiveClock
+from nautilus_trader.common.component import MessageBus
+from nautilus_trader.config import InstrumentProviderConfig
+from nautilus_trader.core.nautilus_pyo3 import Quota
+from nautilus_trader.live.factories import LiveDataClientFactory
+from nautilus_trader.live.factories import LiveExecClientFactory
+
+
+@lru_cache(4)
+def get_cached_bitmex_http_client(
+    clock: LiveClock,
+    api_key: str | None = None,
+    api_secret: str | None = None,
+    base_url: str | None = None,
+    is_testnet: bool = False,
+) -> BitmexHttpClient:
+    """
+    Cache and return a BitMEX HTTP client.
+
+    If a cached client with matching parameters already exists, the cached client will be returned.
+
+    Parameters
+    ----------
+    clock : LiveClock
+        The clock for the client.
+    api_key : str, optional
+        The API key for the client (``None`` for public only access).
+    api_secret : str, optional
+        The API secret for the client.
+    base_url : str, optional
+        The base URL for the API endpoints.
+    is_testnet : bool, default False
+        If the client is connecting to the BitMEX testnet.
+
+    Returns
+    -------
+    BitmexHttpClient
+
+    """
+    base_url = base_url or get_http_base_url(is_testnet)
+
+    # Setup rate limit quotas
+    # https://www.bitmex.com/app/restAPI#Request-Rate-Limits
+    # REST requests are limited to 120 per minute, with a burst limit of 10
+    # requests per second for order placement, amendment and cancellation.
+    ratelimiter_default_quota = Quota.rate_per_minute(120)
+    ratelimiter_quotas: list[tuple[str, Quota]] = [
+        (BITMEX_REST_RATE_LIMIT_KEY, Quota.rate_per_minute(120)),
+        (BITMEX_ORDER_RATE_LIMIT_KEY, Quota.rate_per_second(10)),
+    ]
+
+    return BitmexHttpClient(
+        clock=clock,
+        base_url=base_url,
+        api_key=api_key,
+        api_secret=api_secret,
+        ratelimiter_quotas=ratelimiter_quotas,
+        ratelimiter_default_quota=ratelimiter_default_quota,
+    )
+
+
+@lru_cache(1)
+def get_cached_bitmex_instrument_provider(
+    client: BitmexHttpClient,
+    clock: LiveClock,
+    config: InstrumentProviderConfig,
+) -> BitmexInstrumentProvider:
+    """
+    Cache and return a BitMEX instrument provider.
+
+    If a cached provider already exists, then that provider will be returned.
+
+    Parameters
+    ----------
+    client : BitmexHttpClient
+        The BitMEX HTTP client.
+    clock : LiveClock
+        The clock instance.
+    config : InstrumentProviderConfig
+        The instrument provider configuration.
+
+    Returns
+    -------
+    BitmexInstrumentProvider
+
+    """
+    return BitmexInstrumentProvider(
+        client=client,
+        clock=clock,
+        config=config,
+    )
+
+
+class BitmexLiveDataClientFactory(LiveDataClientFactory):
+    """
+    Provides a BitMEX live data client factory.
+    """
+
+    @staticmethod
+    def create(  # type: ignore
+        loop: asyncio.AbstractEventLoop,
+        name: str,
+        config: BitmexDataClientConfig,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+    ) -> BitmexDataClient:
+        """
+        Create a new BitMEX data client.
+
+        Parameters
+        ----------
+        loop : asyncio.AbstractEventLoop
+            The event loop for the client.
+        name : str
+            The custom client ID.
+        config : BitmexDataClientConfig
+            The client configuration.
+        msgbus : MessageBus
+            The message bus for the client.
+        cache : Cache
+            The cache for the client.
+        clock: LiveClock
+            The clock for the instrument provider.
+
+        Returns
+        -------
+        BitmexDataClient
+
+        """
+        # Market data is public, so no credentials are required
+        client: BitmexHttpClient = get_cached_bitmex_http_client(
+            clock=clock,
+            base_url=config.base_url_http,
+            is_testnet=config.testnet,
+        )
+        provider = get_cached_bitmex_instrument_provider(
+            client=client,
+            clock=clock,
+            config=config.instrument_provider,
+        )
+        return BitmexDataClient(
+            loop=loop,
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+            instrument_provider=provider,
+            base_url_ws=config.base_url_ws or get_ws_base_url(config.testnet),
+            config=config,
+            name=name,
+        )
+
+
+class BitmexLiveExecClientFactory(LiveExecClientFactory):
+    """
+    Provides a BitMEX live execution client factory.
+    """
+
+    @staticmethod
+    def create(  # type: ignore
+        loop: asyncio.AbstractEventLoop,
+        name: str,
+        config: BitmexExecClientConfig,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+    ) -> BitmexExecutionClient:
+        """
+        Create a new BitMEX execution client.
+
+        Parameters
+        ----------
+        loop : asyncio.AbstractEventLoop
+            The event loop for the client.
+        name : str
+            The custom client ID.
+        config : BitmexExecClientConfig
+            The client configuration.
+        msgbus : MessageBus
+            The message bus for the client.
+        cache : Cache
+            The cache for the client.
+        clock : LiveClock
+            The clock for the client.
+
+        Returns
+        -------
+        BitmexExecutionClient
+
+        """
+        client: BitmexHttpClient = get_cached_bitmex_http_client(
+            clock=clock,
+            api_key=config.api_key or get_api_key(config.testnet),
+            api_secret=config.api_secret or get_api_secret(config.testnet),
+            base_url=config.base_url_http,
+            is_testnet=config.testnet,
+        )
+        provider = get_cached_bitmex_instrument_provider(
+            client=client,
+            clock=clock,
+            config=config.instrument_provider,
+        )
+        return BitmexExecutionClient(
+            loop=loop,
+            client=client,
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+            instrument_provider=provider,
+            base_url_ws=config.base_url_ws or get_ws_base_url(config.testnet),
+            config=config,
+            name=name,
+        )
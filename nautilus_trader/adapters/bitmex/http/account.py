@@ -0,0 +1,88 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import msgspec
+
+from nautilus_trader.adapters.bitmex.common.constants import BITMEX_REST_RATE_LIMIT_KEY
+from nautilus_trader.adapters.bitmex.schemas.instrument import BitmexCommission
+from nautilus_trader.adapters.bitmex.schemas.user import BitmexMargin
+from nautilus_trader.adapters.bitmex.schemas.user import BitmexPosition
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.core.nautilus_pyo3 import HttpMethod
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.adapters.bitmex.http.client import BitmexHttpClient
+    from nautilus_trader.common.component import LiveClock
+
+
+class BitmexAccountHttpAPI:
+    """
+    Provides access to the signed BitMEX account REST endpoints.
+
+    Parameters
+    ----------
+    client : BitmexHttpClient
+        The BitMEX HTTP client (with API credentials).
+    clock : LiveClock
+        The clock for the API client.
+
+    """
+
+    def __init__(
+        self,
+        client: BitmexHttpClient,
+        clock: LiveClock,
+    ) -> None:
+        PyCondition.not_none(client, "client")
+        self.client = client
+        self._clock = clock
+
+        self._decoder_margins = msgspec.json.Decoder(list[BitmexMargin])
+        self._decoder_positions = msgspec.json.Decoder(list[BitmexPosition])
+        self._decoder_commission = msgspec.json.Decoder(dict[str, BitmexCommission])
+
+    async def fetch_margins(self) -> list[BitmexMargin]:
+        raw = await self.client.send_request(
+            HttpMethod.GET,
+            "/user/margin",
+            payload={"currency": "all"},
+            signed=True,
+            ratelimiter_keys=[BITMEX_REST_RATE_LIMIT_KEY],
+        )
+        return self._decoder_margins.decode(raw)
+
+    async def fetch_positions(self) -> list[BitmexPosition]:
+        raw = await self.client.send_request(
+            HttpMethod.GET,
+            "/position",
+            signed=True,
+            ratelimiter_keys=[BITMEX_REST_RATE_LIMIT_KEY],
+        )
+        return self._decoder_positions.decode(raw)
+
+    async def fetch_commission(self) -> dict[str, BitmexCommission]:
+        # The fee schedule for the account, keyed by contract symbol
+        raw = await self.client.send_request(
+            HttpMethod.GET,
+            "/user/commission",
+            signed=True,
+            ratelimiter_keys=[BITMEX_REST_RATE_LIMIT_KEY],
+        )
+        return self._decoder_commission.decode(raw)
@@ -0,0 +1,194 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from typing import Any
+from urllib import parse
+
+import msgspec
+
+import nautilus_trader
+from nautilus_trader.adapters.bitmex.common.constants import BITMEX_SIGNATURE_EXPIRY_SECS
+from nautilus_trader.adapters.bitmex.http.errors import BitmexError
+from nautilus_trader.adapters.bitmex.http.errors import parse_error_message
+from nautilus_trader.common.component import LiveClock
+from nautilus_trader.common.component import Logger
+from nautilus_trader.core.nautilus_pyo3 import HttpClient
+from nautilus_trader.core.nautilus_pyo3 import HttpMethod
+from nautilus_trader.core.nautilus_pyo3 import HttpResponse
+from nautilus_trader.core.nautilus_pyo3 import Quota
+from nautilus_trader.core.nautilus_pyo3 import hmac_signature
+
+
+BITMEX_API_PATH = "/api/v1"
+
+
+class BitmexHttpClient:
+    """
+    Provides a BitMEX asynchronous HTTP client.
+
+    Signed requests are authenticated with the `api-expires`, `api-key` and
+    `api-signature` headers, where the signature is the hex encoded HMAC-SHA256
+    of the verb, path (including the query string), expiry and body.
+
+    Parameters
+    ----------
+    clock : LiveClock
+        The clock for the client.
+    base_url : str
+        The base endpoint URL for the client.
+    api_key : str, optional
+        The BitMEX API key for signed requests (``None`` for public only access).
+    api_secret : str, optional
+        The BitMEX API secret for signed requests.
+    ratelimiter_quotas : list[tuple[str, Quota]], optional
+        The keyed rate limiter quotas for the client.
+    ratelimiter_default_quota : Quota, optional
+        The default rate limiter quota for the client.
+
+    """
+
+    def __init__(
+        self,
+        clock: LiveClock,
+        base_url: str,
+        api_key: str | None = None,
+        api_secret: str | None = None,
+        ratelimiter_quotas: list[tuple[str, Quota]] | None = None,
+        ratelimiter_default_quota: Quota | None = None,
+    ) -> None:
+        self._clock: LiveClock = clock
+        self._log: Logger = Logger(name=type(self).__name__)
+        self._api_key = api_key
+        self._api_secret = api_secret
+
+        self._base_url: str = base_url
+        self._headers: dict[str, Any] = {
+            "Content-Type": "application/json",
+            "User-Agent": nautilus_trader.USER_AGENT,
+        }
+        self._client = HttpClient(
+            keyed_quotas=ratelimiter_quotas or [],
+            default_quota=ratelimiter_default_quota,
+        )
+
+    @property
+    def base_url(self) -> str:
+        return self._base_url
+
+    @property
+    def api_key(self) -> str | None:
+        return self._api_key
+
+    @property
+    def api_secret(self) -> str | None:
+        return self._api_secret
+
+    @property
+    def has_credentials(self) -> bool:
+        return self._api_key is not None and self._api_secret is not None
+
+    async def send_request(
+        self,
+        http_method: HttpMethod,
+        url_path: str,
+        payload: dict[str, Any] | None = None,
+        signed: bool = False,
+        ratelimiter_keys: list[str] | None = None,
+    ) -> bytes:
+        # Parameters are sent in the query string for `GET` requests, otherwise as a JSON body
+        path = BITMEX_API_PATH + url_path
+        body = b""
+        if payload:
+            if http_method == HttpMethod.GET:
+                path += "?" + parse.urlencode(payload)
+            else:
+                body = msgspec.json.encode(payload)
+
+        headers = self._headers
+        if signed:
+            headers = {**self._headers, **self.sign(_method_str(http_method), path, body)}
+
+        response: HttpResponse = await self._client.request(
+            http_method,
+            self._base_url + path,
+            headers,
+            body or None,
+            ratelimiter_keys,
+        )
+
+        response_body = response.body
+
+        if response.status >= 400:
+            try:
+                message = msgspec.json.decode(response_body) if response_body else None
+            except msgspec.DecodeError:
+                message = response_body.decode()
+
+            raise BitmexError(
+                code=response.status,
+                message=parse_error_message(message),
+            )
+
+        return response_body
+
+    def sign(self, verb: str, path: str, body: bytes = b"") -> dict[str, str]:
+        """
+        Return the authentication headers for a request.
+
+        Parameters
+        ----------
+        verb : str
+            The HTTP verb (e.g. `GET`).
+        path : str
+            The request path including the query string (e.g. `/api/v1/order?symbol=XBTUSD`).
+        body : bytes, default b""
+            The request body.
+
+        Returns
+        -------
+        dict[str, str]
+
+        Raises
+        ------
+        RuntimeError
+            If the client has no API credentials.
+
+        """
+        if self._api_key is None or self._api_secret is None:
+            raise RuntimeError("Cannot sign request: no API credentials (public access only)")
+
+        expires = str(int(self._clock.timestamp()) + BITMEX_SIGNATURE_EXPIRY_SECS)
+        message = verb + path + expires + body.decode()
+        return {
+            "api-expires": expires,
+            "api-key": self._api_key,
+            "api-signature": hmac_signature(self._api_secret, message),
+        }
+
+
+def _method_str(http_method: HttpMethod) -> str:
+    match http_method:
+        case HttpMethod.GET:
+            return "GET"
+        case HttpMethod.POST:
+            return "POST"
+        case HttpMethod.PUT:
+            return "PUT"
+        case HttpMethod.DELETE:
+            return "DELETE"
+        case HttpMethod.PATCH:
+            return "PATCH"
+        case _:
+            raise ValueError(f"Unsupported HTTP method {http_method}")
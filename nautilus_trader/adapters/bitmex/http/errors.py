@@ -0,0 +1,74 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from typing import Any
+
+from nautilus_trader.adapters.bitmex.common.constants import BITMEX_RETRY_ERRORS
+
+
+class BitmexError(Exception):
+    """
+    Represents BitMEX specific errors.
+
+    The `code` is the HTTP status code, or ``None`` for errors reported in the
+    body of a successful response (e.g. for individual orders of a cancel request).
+
+    """
+
+    def __init__(
+        self,
+        code: int | str | None,
+        message: str | None,
+    ) -> None:
+        super().__init__(message)
+        self.code = code
+        self.message = message
+
+    def __repr__(self) -> str:
+        return f"{type(self).__name__}(code={self.code}, message='{self.message}')"
+
+
+def parse_error_message(body: Any) -> Any:
+    """
+    Return the error message from the decoded BitMEX error response `body`.
+
+    Errors are reported as `{"error": {"message": ..., "name": ...}}`.
+
+    """
+    if isinstance(body, dict):
+        error = body.get("error")
+        if isinstance(error, dict) and "message" in error:
+            return error["message"]
+    return body
+
+
+def should_retry(error: BaseException) -> bool:
+    """
+    Determine if a retry should be attempted based on the error code.
+
+    Parameters
+    ----------
+    error : BaseException
+        The error to check.
+
+    Returns
+    -------
+    bool
+        True if should retry, otherwise False.
+
+    """
+    if isinstance(error, BitmexError):
+        return error.code in BITMEX_RETRY_ERRORS
+    return False
@@ -0,0 +1,64 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import msgspec
+
+from nautilus_trader.adapters.bitmex.common.constants import BITMEX_REST_RATE_LIMIT_KEY
+from nautilus_trader.adapters.bitmex.schemas.instrument import BitmexInstrument
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.core.nautilus_pyo3 import HttpMethod
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.adapters.bitmex.http.client import BitmexHttpClient
+    from nautilus_trader.common.component import LiveClock
+
+
+class BitmexMarketHttpAPI:
+    """
+    Provides access to the public BitMEX market data REST endpoints.
+
+    Parameters
+    ----------
+    client : BitmexHttpClient
+        The BitMEX HTTP client.
+    clock : LiveClock
+        The clock for the API client.
+
+    """
+
+    def __init__(
+        self,
+        client: BitmexHttpClient,
+        clock: LiveClock,
+    ) -> None:
+        PyCondition.not_none(client, "client")
+        self.client = client
+        self._clock = clock
+
+        self._decoder_instruments = msgspec.json.Decoder(list[BitmexInstrument])
+
+    async def fetch_active_instruments(self) -> list[BitmexInstrument]:
+        # Includes all open contracts and indices (which are filtered by the provider)
+        raw = await self.client.send_request(
+            HttpMethod.GET,
+            "/instrument/active",
+            ratelimiter_keys=[BITMEX_REST_RATE_LIMIT_KEY],
+        )
+        return self._decoder_instruments.decode(raw)
@@ -0,0 +1,264 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+from typing import Any
+
+import msgspec
+
+from nautilus_trader.adapters.bitmex.common.constants import BITMEX_ORDER_RATE_LIMIT_KEY
+from nautilus_trader.adapters.bitmex.common.constants import BITMEX_REST_RATE_LIMIT_KEY
+from nautilus_trader.adapters.bitmex.schemas.order import BitmexExecution
+from nautilus_trader.adapters.bitmex.schemas.order import BitmexOrder
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.core.nautilus_pyo3 import HttpMethod
+
+
+if TYPE_CHECKING:
+    import datetime
+
+    from nautilus_trader.adapters.bitmex.common.enums import BitmexExecInstruction
+    from nautilus_trader.adapters.bitmex.common.enums import BitmexOrderSide
+    from nautilus_trader.adapters.bitmex.common.enums import BitmexOrderType
+    from nautilus_trader.adapters.bitmex.common.enums import BitmexTimeInForce
+    from nautilus_trader.adapters.bitmex.http.client import BitmexHttpClient
+    from nautilus_trader.common.component import LiveClock
+
+
+_ORDER_RATE_LIMIT_KEYS = [BITMEX_REST_RATE_LIMIT_KEY, BITMEX_ORDER_RATE_LIMIT_KEY]
+
+
+def order_params(
+    symbol: str,
+    side: BitmexOrderSide,
+    order_type: BitmexOrderType,
+    quantity: str,
+    client_order_id: str,
+    time_in_force: BitmexTimeInForce | None = None,
+    price: str | None = None,
+    trigger_price: str | None = None,
+    exec_instructions: list[BitmexExecInstruction] | None = None,
+    display_qty: str | None = None,
+) -> dict[str, Any]:
+    """
+    Return the request parameters for a new order.
+
+    Quantities are in contracts, and prices and quantities must already be
+    rounded to the instrument tick and lot sizes.
+
+    """
+    params: dict[str, Any] = {
+        "symbol": symbol,
+        "side": side.value,
+        "ordType": order_type.value,
+        "orderQty": quantity,
+        "clOrdID": client_order_id,
+    }
+    if time_in_force is not None:
+        params["timeInForce"] = time_in_force.value
+    if price is not None:
+        params["price"] = price
+    if trigger_price is not None:
+        params["stopPx"] = trigger_price
+    if exec_instructions:
+        params["execInst"] = ",".join(inst.value for inst in exec_instructions)
+    if display_qty is not None:
+        params["displayQty"] = display_qty
+    return params
+
+
+class BitmexTradeHttpAPI:
+    """
+    Provides access to the signed BitMEX order and execution REST endpoints.
+
+    Parameters
+    ----------
+    client : BitmexHttpClient
+        The BitMEX HTTP client (with API credentials).
+    clock : LiveClock
+        The clock for the API client.
+
+    """
+
+    def __init__(
+        self,
+        client: BitmexHttpClient,
+        clock: LiveClock,
+    ) -> None:
+        PyCondition.not_none(client, "client")
+        self.client = client
+        self._clock = clock
+
+        self._decoder_order = msgspec.json.Decoder(BitmexOrder)
+        self._decoder_orders = msgspec.json.Decoder(list[BitmexOrder])
+        self._decoder_executions = msgspec.json.Decoder(list[BitmexExecution])
+
+    async def place_order(self, params: dict[str, Any]) -> BitmexOrder:
+        raw = await self.client.send_request(
+            HttpMethod.POST,
+            "/order",
+            payload=params,
+            signed=True,
+            ratelimiter_keys=_ORDER_RATE_LIMIT_KEYS,
+        )
+        return self._decoder_order.decode(raw)
+
+    async def amend_order(
+        self,
+        order_id: str | None = None,
+        client_order_id: str | None = None,
+        quantity: str | None = None,
+        price: str | None = None,
+        trigger_price: str | None = None,
+    ) -> BitmexOrder:
+        PyCondition.is_false(
+            order_id is None and client_order_id is None,
+            "both `order_id` and `client_order_id` were `None`",
+        )
+
+        params: dict[str, Any] = {}
+        if order_id is not None:
+            params["orderID"] = order_id
+        else:
+            params["origClOrdID"] = client_order_id
+        if quantity is not None:
+            params["orderQty"] = quantity
+        if price is not None:
+            params["price"] = price
+        if trigger_price is not None:
+            params["stopPx"] = trigger_price
+
+        raw = await self.client.send_request(
+            HttpMethod.PUT,
+            "/order",
+            payload=params,
+            signed=True,
+            ratelimiter_keys=_ORDER_RATE_LIMIT_KEYS,
+        )
+        return self._decoder_order.decode(raw)
+
+    async def cancel_orders(
+        self,
+        order_ids: list[str] | None = None,
+        client_order_ids: list[str] | None = None,
+    ) -> list[BitmexOrder]:
+        # Orders which could not be canceled are returned with an `error`
+        params: dict[str, Any] = {}
+        if order_ids:
+            params["orderID"] = order_ids
+        if client_order_ids:
+            params["clOrdID"] = client_order_ids
+
+        raw = await self.client.send_request(
+            HttpMethod.DELETE,
+            "/order",
+            payload=params,
+            signed=True,
+            ratelimiter_keys=_ORDER_RATE_LIMIT_KEYS,
+        )
+        return self._decoder_orders.decode(raw)
+
+    async def cancel_all_orders(
+        self,
+        symbol: str | None = None,
+        side: BitmexOrderSide | None = None,
+    ) -> list[BitmexOrder]:
+        params: dict[str, Any] = {}
+        if symbol is not None:
+            params["symbol"] = symbol
+        if side is not None:
+            params["filter"] = msgspec.json.encode({"side": side.value}).decode()
+
+        raw = await self.client.send_request(
+            HttpMethod.DELETE,
+            "/order/all",
+            payload=params,
+            signed=True,
+            ratelimiter_keys=_ORDER_RATE_LIMIT_KEYS,
+        )
+        return self._decoder_orders.decode(raw)
+
+    async def fetch_orders(
+        self,
+        symbol: str | None = None,
+        open_only: bool = False,
+        order_id: str | None = None,
+        client_order_id: str | None = None,
+        start_time: datetime.datetime | None = None,
+        end_time: datetime.datetime | None = None,
+        count: int = 500,
+    ) -> list[BitmexOrder]:
+        filters: dict[str, Any] = {}
+        if open_only:
+            filters["open"] = True
+        if order_id is not None:
+            filters["orderID"] = order_id
+        if client_order_id is not None:
+            filters["clOrdID"] = client_order_id
+
+        params = _query_params(symbol, filters, start_time, end_time, count)
+        raw = await self.client.send_request(
+            HttpMethod.GET,
+            "/order",
+            payload=params,
+            signed=True,
+            ratelimiter_keys=[BITMEX_REST_RATE_LIMIT_KEY],
+        )
+        return self._decoder_orders.decode(raw)
+
+    async def fetch_trade_history(
+        self,
+        symbol: str | None = None,
+        order_id: str | None = None,
+        start_time: datetime.datetime | None = None,
+        end_time: datetime.datetime | None = None,
+        count: int = 500,
+    ) -> list[BitmexExecution]:
+        # Only includes executions with an `execType` of `Trade` (and funding/settlement)
+        filters: dict[str, Any] = {}
+        if order_id is not None:
+            filters["orderID"] = order_id
+
+        params = _query_params(symbol, filters, start_time, end_time, count)
+        raw = await self.client.send_request(
+            HttpMethod.GET,
+            "/execution/tradeHistory",
+            payload=params,
+            signed=True,
+            ratelimiter_keys=[BITMEX_REST_RATE_LIMIT_KEY],
+        )
+        return self._decoder_executions.decode(raw)
+
+
+def _query_params(
+    symbol: str | None,
+    filters: dict[str, Any],
+    start_time: datetime.datetime | None,
+    end_time: datetime.datetime | None,
+    count: int,
+) -> dict[str, Any]:
+    # Results are requested newest first, up to the maximum `count` of 500
+    params: dict[str, Any] = {"count": count, "reverse": "true"}
+    if symbol is not None:
+        params["symbol"] = symbol
+    if filters:
+        params["filter"] = msgspec.json.encode(filters).decode()
+    if start_time is not None:
+        params["startTime"] = start_time.isoformat()
+    if end_time is not None:
+        params["endTime"] = end_time.isoformat()
+    return params
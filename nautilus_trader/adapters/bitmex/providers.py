@@ -0,0 +1,171 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from decimal import Decimal
+from typing import TYPE_CHECKING
+
+from nautilus_trader.adapters.bitmex.common.constants import BITMEX_VENUE
+from nautilus_trader.adapters.bitmex.common.parsing import normalize_bitmex_currency
+from nautilus_trader.adapters.bitmex.http.account import BitmexAccountHttpAPI
+from nautilus_trader.adapters.bitmex.http.errors import BitmexError
+from nautilus_trader.adapters.bitmex.http.market import BitmexMarketHttpAPI
+from nautilus_trader.common.providers import InstrumentProvider
+from nautilus_trader.core.correctness import PyCondition
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.adapters.bitmex.http.client import BitmexHttpClient
+    from nautilus_trader.adapters.bitmex.schemas.instrument import BitmexCommission
+    from nautilus_trader.adapters.bitmex.schemas.instrument import BitmexInstrument
+    from nautilus_trader.common.component import LiveClock
+    from nautilus_trader.config import InstrumentProviderConfig
+    from nautilus_trader.model.identifiers import InstrumentId
+
+
+class BitmexInstrumentProvider(InstrumentProvider):
+    """
+    Provides Nautilus instrument definitions from BitMEX perpetual and futures contracts.
+
+    When the HTTP client has API credentials, the maker and taker fees are taken from
+    the fee schedule of the account, otherwise from the standard instrument fees.
+
+    Parameters
+    ----------
+    client : BitmexHttpClient
+        The BitMEX HTTP client.
+    clock : LiveClock
+        The clock instance.
+    config : InstrumentProviderConfig, optional
+        The instrument provider configuration, by default None.
+
+    """
+
+    def __init__(
+        self,
+        client: BitmexHttpClient,
+        clock: LiveClock,
+        config: InstrumentProviderConfig | None = None,
+    ) -> None:
+        super().__init__(config=config)
+        self._clock = clock
+        self._client = client
+        self._http_market = BitmexMarketHttpAPI(client, clock)
+        self._http_account = BitmexAccountHttpAPI(client, clock)
+
+        self._log_warnings = config.log_warnings if config else True
+
+    async def load_all_async(self, filters: dict | None = None) -> None:
+        filters_str = "..." if not filters else f" with filters {filters}..."
+        self._log.info(f"Loading all instruments{filters_str}")
+
+        await self._load_instruments()
+
+        self._log.info(f"Loaded {len(self._instruments)} instruments")
+
+    async def load_ids_async(
+        self,
+        instrument_ids: list[InstrumentId],
+        filters: dict | None = None,
+    ) -> None:
+        if not instrument_ids:
+            self._log.warning("No instrument IDs given for loading")
+            return
+
+        # Check all instrument IDs
+        for instrument_id in instrument_ids:
+            PyCondition.equal(
+                instrument_id.venue,
+                BITMEX_VENUE,
+                "instrument_id.venue",
+                "BITMEX",
+            )
+
+        await self._load_instruments(set(instrument_ids))
+
+    async def load_async(self, instrument_id: InstrumentId, filters: dict | None = None) -> None:
+        PyCondition.not_none(instrument_id, "instrument_id")
+        await self.load_ids_async([instrument_id], filters)
+
+    async def _load_instruments(self, instrument_ids: set[InstrumentId] | None = None) -> None:
+        bitmex_instruments = await self._http_market.fetch_active_instruments()
+        commissions = await self._fetch_commissions()
+        for bitmex_instrument in bitmex_instruments:
+            if not (bitmex_instrument.is_perpetual or bitmex_instrument.is_future):
+                continue  # Indices and spot pairs
+            self._parse_instrument(
+                bitmex_instrument,
+                commissions.get(bitmex_instrument.symbol),
+                instrument_ids,
+            )
+
+    async def _fetch_commissions(self) -> dict[str, BitmexCommission]:
+        if not self._client.has_credentials:
+            return {}
+
+        try:
+            return await self._http_account.fetch_commission()
+        except BitmexError as e:
+            self._log.warning(f"Unable to fetch account fee schedule: {e}")
+            return {}
+
+    def _parse_instrument(
+        self,
+        bitmex_instrument: BitmexInstrument,
+        commission: BitmexCommission | None,
+        instrument_ids: set[InstrumentId] | None,
+    ) -> None:
+        try:
+            base_currency = self.currency(normalize_bitmex_currency(bitmex_instrument.underlying))
+            quote_currency = self.currency(
+                normalize_bitmex_currency(bitmex_instrument.quoteCurrency),
+            )
+            settlement_currency = self.currency(
+                normalize_bitmex_currency(
+                    bitmex_instrument.settlCurrency or bitmex_instrument.quoteCurrency,
+                ),
+            )
+            assert base_currency is not None  # Type checking
+            assert quote_currency is not None  # Type checking
+            assert settlement_currency is not None  # Type checking
+            self.add_currency(base_currency)
+            self.add_currency(quote_currency)
+            self.add_currency(settlement_currency)
+
+            maker_fee = bitmex_instrument.makerFee
+            taker_fee = bitmex_instrument.takerFee
+            if commission is not None:
+                maker_fee = commission.makerFee if commission.makerFee is not None else maker_fee
+                taker_fee = commission.takerFee if commission.takerFee is not None else taker_fee
+
+            ts_event = self._clock.timestamp_ns()
+            ts_init = self._clock.timestamp_ns()
+            instrument = bitmex_instrument.parse_to_instrument(
+                base_currency=base_currency,
+                quote_currency=quote_currency,
+                settlement_currency=settlement_currency,
+                maker_fee=Decimal(str(maker_fee or 0)),
+                taker_fee=Decimal(str(taker_fee or 0)),
+                ts_event=ts_event,
+                ts_init=ts_init,
+            )
+        except ValueError as e:
+            if self._log_warnings:
+                self._log.warning(f"Unable to parse instrument {bitmex_instrument.symbol}: {e}")
+            return
+
+        if instrument_ids is None or instrument.id in instrument_ids:
+            self.add(instrument=instrument)
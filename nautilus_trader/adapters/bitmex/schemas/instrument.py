@@ -0,0 +1,224 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+import datetime
+from decimal import Decimal
+
+import msgspec
+
+from nautilus_trader.adapters.bitmex.common.constants import BITMEX_FUTURE_TYPES
+from nautilus_trader.adapters.bitmex.common.constants import BITMEX_PERPETUAL_TYPES
+from nautilus_trader.adapters.bitmex.common.symbol import BitmexSymbol
+from nautilus_trader.adapters.bitmex.types import BitmexFundingRateUpdate
+from nautilus_trader.core.datetime import dt_to_unix_nanos
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import Symbol
+from nautilus_trader.model.instruments import CryptoFuture
+from nautilus_trader.model.instruments import CryptoPerpetual
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+
+
+def _to_decimal(value: float) -> Decimal:
+    # Values are decoded as floats, so are converted through their shortest repr
+    return Decimal(str(value))
+
+
+def _precision(value: Decimal) -> int:
+    exponent = value.normalize().as_tuple().exponent
+    assert isinstance(exponent, int)  # Finite values only
+    return max(-exponent, 0)
+
+
+class BitmexInstrument(msgspec.Struct, frozen=True):
+    symbol: str
+    typ: str
+    state: str
+    underlying: str
+    quoteCurrency: str
+    tickSize: float
+    timestamp: datetime.datetime
+    rootSymbol: str | None = None
+    settlCurrency: str | None = None
+    positionCurrency: str | None = None
+    listing: datetime.datetime | None = None
+    expiry: datetime.datetime | None = None
+    isQuanto: bool = False
+    isInverse: bool = False
+    lotSize: float | None = None
+    multiplier: float | None = None
+    underlyingToPositionMultiplier: float | None = None
+    initMargin: float | None = None
+    maintMargin: float | None = None
+    makerFee: float | None = None
+    takerFee: float | None = None
+    maxOrderQty: float | None = None
+    maxPrice: float | None = None
+    fundingRate: float | None = None
+    fundingTimestamp: datetime.datetime | None = None
+
+    @property
+    def is_perpetual(self) -> bool:
+        return self.typ in BITMEX_PERPETUAL_TYPES
+
+    @property
+    def is_future(self) -> bool:
+        return self.typ in BITMEX_FUTURE_TYPES
+
+    def parse_to_instrument(
+        self,
+        base_currency: Currency,
+        quote_currency: Currency,
+        settlement_currency: Currency,
+        maker_fee: Decimal,
+        taker_fee: Decimal,
+        ts_event: int,
+        ts_init: int,
+    ) -> CryptoPerpetual | CryptoFuture:
+        """
+        Parse the instrument definition into a Nautilus perpetual or future instrument.
+
+        Quantities are in contracts (multiples of the lot size). Inverse contracts have a
+        value of one quote currency unit, and linear contracts a value of
+        `1 / underlyingToPositionMultiplier` base currency units.
+
+        Raises
+        ------
+        ValueError
+            If the instrument is not a perpetual or future, or is a quanto contract.
+
+        """
+        if not (self.is_perpetual or self.is_future):
+            raise ValueError(f"unsupported instrument type {self.typ}")
+
+        if self.isQuanto:
+            raise ValueError("quanto contracts are not supported")
+
+        tick_size = _to_decimal(self.tickSize)
+        price_precision = _precision(tick_size)
+        lot_size = _to_decimal(self.lotSize or 1)
+        size_precision = _precision(lot_size)
+        size_increment = Quantity(float(lot_size), size_precision)
+
+        if self.isInverse or not self.underlyingToPositionMultiplier:
+            multiplier = Quantity.from_int(1)
+        else:
+            value = Decimal(1) / _to_decimal(self.underlyingToPositionMultiplier)
+            multiplier = Quantity(float(value), _precision(value))
+
+        instrument_id = BitmexSymbol(self.symbol).to_instrument_id()
+        common = {
+            "instrument_id": instrument_id,
+            "raw_symbol": Symbol(self.symbol),
+            "quote_currency": quote_currency,
+            "settlement_currency": settlement_currency,
+            "is_inverse": self.isInverse,
+            "price_precision": price_precision,
+            "size_precision": size_precision,
+            "price_increment": Price(float(tick_size), price_precision),
+            "size_increment": size_increment,
+            "multiplier": multiplier,
+            "max_quantity": (
+                Quantity(self.maxOrderQty, size_precision) if self.maxOrderQty else None
+            ),
+            "min_quantity": size_increment,
+            "max_notional": None,
+            "min_notional": None,
+            "max_price": Price(self.maxPrice, price_precision) if self.maxPrice else None,
+            "min_price": None,
+            "margin_init": _to_decimal(self.initMargin or 0),
+            "margin_maint": _to_decimal(self.maintMargin or 0),
+            "maker_fee": maker_fee,
+            "taker_fee": taker_fee,
+            "ts_event": ts_event,
+            "ts_init": ts_init,
+            "info": msgspec.to_builtins(self),
+        }
+
+        if self.is_perpetual:
+            return CryptoPerpetual(base_currency=base_currency, **common)
+
+        if self.expiry is None:
+            raise ValueError(f"no expiry for future {self.symbol}")
+
+        return CryptoFuture(
+            underlying=base_currency,
+            activation_ns=dt_to_unix_nanos(self.listing) if self.listing else 0,
+            expiration_ns=dt_to_unix_nanos(self.expiry),
+            **common,
+        )
+
+
+class BitmexInstrumentUpdate(msgspec.Struct, frozen=True):
+    """
+    Represents a (partial) update from the `instrument` table.
+
+    Only the changed fields are included in `update` actions.
+
+    """
+
+    symbol: str
+    timestamp: datetime.datetime | None = None
+    fundingRate: float | None = None
+    fundingTimestamp: datetime.datetime | None = None
+    markPrice: float | None = None
+    indexPrice: float | None = None
+
+    def parse_to_funding_rate_update(
+        self,
+        instrument_id: InstrumentId,
+        last: BitmexFundingRateUpdate | None,
+        ts_init: int,
+    ) -> BitmexFundingRateUpdate | None:
+        """
+        Parse the update into a funding rate update, merged with the `last` update.
+
+        Returns ``None`` if the funding rate is unknown.
+
+        """
+        if self.fundingRate is None and self.fundingTimestamp is None:
+            return None  # Funding unchanged
+
+        if self.fundingRate is not None:
+            funding_rate = _to_decimal(self.fundingRate)
+        elif last is not None:
+            funding_rate = last.funding_rate
+        else:
+            return None
+
+        if self.fundingTimestamp is not None:
+            ts_next_funding = dt_to_unix_nanos(self.fundingTimestamp)
+        elif last is not None:
+            ts_next_funding = last.ts_next_funding
+        else:
+            ts_next_funding = 0
+
+        return BitmexFundingRateUpdate(
+            instrument_id=instrument_id,
+            funding_rate=funding_rate,
+            ts_next_funding=ts_next_funding,
+            ts_event=dt_to_unix_nanos(self.timestamp) if self.timestamp else ts_init,
+            ts_init=ts_init,
+        )
+
+
+class BitmexCommission(msgspec.Struct, frozen=True):
+    makerFee: float | None = None
+    takerFee: float | None = None
+    settlementFee: float | None = None
+    maxFee: float | None = None
@@ -0,0 +1,213 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+import datetime
+from decimal import Decimal
+
+import msgspec
+
+from nautilus_trader.adapters.bitmex.common.enums import BitmexEnumParser
+from nautilus_trader.adapters.bitmex.common.enums import BitmexExecInstruction
+from nautilus_trader.adapters.bitmex.common.enums import BitmexLiquidityIndicator
+from nautilus_trader.adapters.bitmex.common.enums import BitmexOrderSide
+from nautilus_trader.adapters.bitmex.common.enums import BitmexOrderStatus
+from nautilus_trader.adapters.bitmex.common.enums import BitmexOrderType
+from nautilus_trader.adapters.bitmex.common.enums import BitmexTimeInForce
+from nautilus_trader.adapters.bitmex.common.enums import parse_exec_instructions
+from nautilus_trader.adapters.bitmex.common.parsing import normalize_bitmex_currency
+from nautilus_trader.adapters.bitmex.common.parsing import parse_bitmex_amount
+from nautilus_trader.core.datetime import dt_to_unix_nanos
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.execution.reports import FillReport
+from nautilus_trader.execution.reports import OrderStatusReport
+from nautilus_trader.model.enums import ContingencyType
+from nautilus_trader.model.enums import LiquiditySide
+from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import TimeInForce
+from nautilus_trader.model.enums import TriggerType
+from nautilus_trader.model.identifiers import AccountId
+from nautilus_trader.model.identifiers import ClientOrderId
+from nautilus_trader.model.identifiers import TradeId
+from nautilus_trader.model.identifiers import VenueOrderId
+from nautilus_trader.model.instruments import Instrument
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import Money
+
+
+# Order types with a trigger (stop) price
+_TRIGGER_ORDER_TYPES = (
+    OrderType.STOP_MARKET,
+    OrderType.STOP_LIMIT,
+    OrderType.MARKET_IF_TOUCHED,
+    OrderType.LIMIT_IF_TOUCHED,
+)
+
+
+class BitmexOrder(msgspec.Struct, frozen=True):
+    orderID: str
+    symbol: str
+    side: BitmexOrderSide
+    orderQty: float
+    ordType: BitmexOrderType
+    ordStatus: BitmexOrderStatus
+    timestamp: datetime.datetime
+    clOrdID: str | None = None
+    price: float | None = None
+    stopPx: float | None = None
+    timeInForce: BitmexTimeInForce | None = None
+    execInst: str | None = None
+    leavesQty: float | None = None
+    cumQty: float | None = None
+    avgPx: float | None = None
+    text: str | None = None
+    transactTime: datetime.datetime | None = None
+    error: str | None = None  # Reported for orders which could not be canceled
+
+    def parse_to_order_status_report(
+        self,
+        account_id: AccountId,
+        instrument: Instrument,
+        client_order_id: ClientOrderId | None,
+        report_id: UUID4,
+        enum_parser: BitmexEnumParser,
+        ts_init: int,
+    ) -> OrderStatusReport:
+        exec_instructions = parse_exec_instructions(self.execInst)
+        order_type = enum_parser.parse_bitmex_order_type(self.ordType)
+
+        trigger_price = None
+        trigger_type = TriggerType.NO_TRIGGER
+        if order_type in _TRIGGER_ORDER_TYPES and self.stopPx is not None:
+            trigger_price = instrument.make_price(self.stopPx)
+            trigger_type = enum_parser.parse_bitmex_trigger_type(exec_instructions)
+
+        time_in_force = TimeInForce.GTC
+        if self.timeInForce is not None:
+            time_in_force = enum_parser.parse_bitmex_time_in_force(self.timeInForce)
+
+        ts_accepted = dt_to_unix_nanos(self.transactTime or self.timestamp)
+
+        return OrderStatusReport(
+            account_id=account_id,
+            instrument_id=instrument.id,
+            client_order_id=client_order_id,
+            order_list_id=None,
+            venue_order_id=VenueOrderId(self.orderID),
+            order_side=enum_parser.parse_bitmex_order_side(self.side),
+            order_type=order_type,
+            contingency_type=ContingencyType.NO_CONTINGENCY,
+            time_in_force=time_in_force,
+            order_status=enum_parser.parse_bitmex_order_status(self.ordStatus),
+            price=instrument.make_price(self.price) if self.price is not None else None,
+            trigger_price=trigger_price,
+            trigger_type=trigger_type,
+            quantity=instrument.make_qty(self.orderQty),
+            filled_qty=instrument.make_qty(self.cumQty or 0),
+            avg_px=Decimal(str(self.avgPx)) if self.avgPx else None,
+            post_only=BitmexExecInstruction.PARTICIPATE_DO_NOT_INITIATE in exec_instructions,
+            reduce_only=BitmexExecInstruction.REDUCE_ONLY in exec_instructions,
+            cancel_reason=self.text if self.ordStatus == BitmexOrderStatus.CANCELED else None,
+            ts_accepted=ts_accepted,
+            ts_last=dt_to_unix_nanos(self.timestamp),
+            report_id=report_id,
+            ts_init=ts_init,
+        )
+
+
+class BitmexExecution(msgspec.Struct, frozen=True):
+    """
+    Represents an execution, as reported by the `execution` table and trade history.
+
+    Executions are reported for every order event (`execType` of `New`, `Trade`,
+    `Canceled`, `Replaced`, `Rejected`, `TriggeredOrActivatedBySystem`), and for
+    funding and settlement (which have no order).
+
+    """
+
+    execID: str
+    orderID: str
+    symbol: str
+    execType: str
+    timestamp: datetime.datetime
+    clOrdID: str | None = None
+    side: str | None = None
+    lastQty: float | None = None
+    lastPx: float | None = None
+    orderQty: float | None = None
+    price: float | None = None
+    stopPx: float | None = None
+    ordType: str | None = None
+    ordStatus: str | None = None
+    execInst: str | None = None
+    leavesQty: float | None = None
+    cumQty: float | None = None
+    avgPx: float | None = None
+    execComm: int | None = None
+    settlCurrency: str | None = None
+    lastLiquidityInd: str | None = None
+    trdMatchID: str | None = None
+    text: str | None = None
+    transactTime: datetime.datetime | None = None
+
+    @property
+    def liquidity_side(self) -> LiquiditySide:
+        match self.lastLiquidityInd:
+            case BitmexLiquidityIndicator.ADDED.value:
+                return LiquiditySide.MAKER
+            case BitmexLiquidityIndicator.REMOVED.value:
+                return LiquiditySide.TAKER
+            case _:
+                return LiquiditySide.NO_LIQUIDITY_SIDE
+
+    @property
+    def ts_event(self) -> int:
+        return dt_to_unix_nanos(self.transactTime or self.timestamp)
+
+    def parse_commission(self) -> Money:
+        # Commissions are reported in the minor unit of the settlement currency
+        # (negative for maker rebates)
+        code = self.settlCurrency or "XBt"
+        currency = Currency.from_str(normalize_bitmex_currency(code))
+        return Money(parse_bitmex_amount(self.execComm or 0, code), currency)
+
+    def parse_to_fill_report(
+        self,
+        account_id: AccountId,
+        instrument: Instrument,
+        client_order_id: ClientOrderId | None,
+        report_id: UUID4,
+        enum_parser: BitmexEnumParser,
+        ts_init: int,
+    ) -> FillReport:
+        if self.lastQty is None or self.lastPx is None or not self.side:
+            raise ValueError(f"Execution {self.execID} is not a trade")
+
+        return FillReport(
+            client_order_id=client_order_id,
+            venue_order_id=VenueOrderId(self.orderID),
+            trade_id=TradeId(self.execID),
+            account_id=account_id,
+            instrument_id=instrument.id,
+            order_side=enum_parser.parse_bitmex_order_side(BitmexOrderSide(self.side)),
+            last_qty=instrument.make_qty(self.lastQty),
+            last_px=instrument.make_price(self.lastPx),
+            commission=self.parse_commission(),
+            liquidity_side=self.liquidity_side,
+            report_id=report_id,
+            ts_event=self.ts_event,
+            ts_init=ts_init,
+        )
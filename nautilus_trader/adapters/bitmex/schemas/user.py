@@ -0,0 +1,117 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+import datetime
+from decimal import Decimal
+
+import msgspec
+
+from nautilus_trader.adapters.bitmex.common.parsing import normalize_bitmex_currency
+from nautilus_trader.adapters.bitmex.common.parsing import parse_bitmex_amount
+from nautilus_trader.core.datetime import dt_to_unix_nanos
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.execution.reports import PositionStatusReport
+from nautilus_trader.model.enums import PositionSide
+from nautilus_trader.model.identifiers import AccountId
+from nautilus_trader.model.instruments import Instrument
+from nautilus_trader.model.objects import AccountBalance
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import MarginBalance
+from nautilus_trader.model.objects import Money
+
+
+class BitmexMargin(msgspec.Struct, frozen=True):
+    """
+    Represents the margin status for a settlement currency (amounts in minor units).
+    """
+
+    account: int
+    currency: str
+    walletBalance: int | None = None
+    marginBalance: int | None = None
+    availableMargin: int | None = None
+    initMargin: int | None = None
+    maintMargin: int | None = None
+    unrealisedPnl: int | None = None
+    realisedPnl: int | None = None
+    timestamp: datetime.datetime | None = None
+
+    @property
+    def nautilus_currency(self) -> Currency:
+        return Currency.from_str(normalize_bitmex_currency(self.currency))
+
+    def parse_to_account_balance(self) -> AccountBalance:
+        # The margin balance includes unrealized PnL, and the available margin
+        # excludes margin used by positions and open orders
+        currency = self.nautilus_currency
+        total = parse_bitmex_amount(self.marginBalance or self.walletBalance or 0, self.currency)
+        free = min(parse_bitmex_amount(self.availableMargin or 0, self.currency), total)
+        free = max(free, Decimal(0))
+        return AccountBalance(
+            total=Money(total, currency),
+            locked=Money(total - free, currency),
+            free=Money(free, currency),
+        )
+
+    def parse_to_margin_balance(self) -> MarginBalance:
+        # The initial margin is held for open orders, and the maintenance margin for positions
+        currency = self.nautilus_currency
+        return MarginBalance(
+            initial=Money(parse_bitmex_amount(self.initMargin or 0, self.currency), currency),
+            maintenance=Money(parse_bitmex_amount(self.maintMargin or 0, self.currency), currency),
+        )
+
+
+class BitmexPosition(msgspec.Struct, frozen=True):
+    account: int
+    symbol: str
+    currency: str
+    currentQty: float | None = None
+    avgEntryPrice: float | None = None
+    markPrice: float | None = None
+    liquidationPrice: float | None = None
+    unrealisedPnl: int | None = None
+    leverage: float | None = None
+    crossMargin: bool | None = None
+    isOpen: bool | None = None
+    timestamp: datetime.datetime | None = None
+
+    def parse_to_position_status_report(
+        self,
+        account_id: AccountId,
+        instrument: Instrument,
+        report_id: UUID4,
+        ts_init: int,
+    ) -> PositionStatusReport:
+        # The signed quantity is negative for short positions
+        size = Decimal(str(self.currentQty or 0))
+        if size > 0:
+            position_side = PositionSide.LONG
+        elif size < 0:
+            position_side = PositionSide.SHORT
+        else:
+            position_side = PositionSide.FLAT
+
+        return PositionStatusReport(
+            account_id=account_id,
+            instrument_id=instrument.id,
+            position_side=position_side,
+            quantity=instrument.make_qty(abs(size)),
+            report_id=report_id,
+            ts_init=ts_init,
+            ts_last=dt_to_unix_nanos(self.timestamp) if self.timestamp else ts_init,
+        )
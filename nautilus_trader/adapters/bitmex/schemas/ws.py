@@ -0,0 +1,235 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+import datetime
+from typing import Any
+
+import msgspec
+
+from nautilus_trader.adapters.bitmex.common.enums import BitmexOrderSide
+from nautilus_trader.adapters.bitmex.common.parsing import parse_aggressor_side
+from nautilus_trader.adapters.bitmex.schemas.instrument import BitmexInstrumentUpdate
+from nautilus_trader.adapters.bitmex.schemas.order import BitmexExecution
+from nautilus_trader.core.datetime import dt_to_unix_nanos
+from nautilus_trader.model.data import BookOrder
+from nautilus_trader.model.data import OrderBookDelta
+from nautilus_trader.model.data import OrderBookDeltas
+from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.model.data import TradeTick
+from nautilus_trader.model.enums import BookAction
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import RecordFlag
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import TradeId
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+
+
+class BitmexWsMessageGeneral(msgspec.Struct):
+    """
+    Represents the common fields of all BitMEX WebSocket messages.
+
+    Table data messages have a `table` and `action`, while responses to operations
+    have a `success` (or `error` and `status`) and the original `request`.
+
+    """
+
+    table: str | None = None
+    action: str | None = None
+    success: bool | None = None
+    subscribe: str | None = None
+    unsubscribe: str | None = None
+    error: str | None = None
+    status: int | None = None
+    info: str | None = None
+    request: dict[str, Any] | None = None
+
+
+################################################################################
+# Market data
+################################################################################
+
+
+class BitmexWsBookLevel(msgspec.Struct):
+    symbol: str
+    id: int
+    side: BitmexOrderSide
+    price: float
+    timestamp: datetime.datetime
+    size: float | None = None  # Not included for `delete` actions
+
+
+class BitmexWsBookMsg(msgspec.Struct):
+    table: str
+    action: str  # `partial`, `insert`, `update` or `delete`
+    data: list[BitmexWsBookLevel]
+
+    @property
+    def symbols(self) -> set[str]:
+        return {level.symbol for level in self.data}
+
+    def parse_to_deltas(
+        self,
+        symbol: str,
+        instrument_id: InstrumentId,
+        price_precision: int,
+        size_precision: int,
+        ts_init: int,
+    ) -> OrderBookDeltas | None:
+        """
+        Parse the price level changes for the given `symbol` into order book deltas.
+
+        A `partial` action is a snapshot of the book, which clears the existing book.
+
+        """
+        levels = [level for level in self.data if level.symbol == symbol]
+        if not levels:
+            return None
+
+        ts_event = max(dt_to_unix_nanos(level.timestamp) for level in levels)
+        is_snapshot = self.action == "partial"
+        match self.action:
+            case "partial" | "insert":
+                action = BookAction.ADD
+            case "update":
+                action = BookAction.UPDATE
+            case "delete":
+                action = BookAction.DELETE
+            case _:
+                raise ValueError(f"Invalid book action, was '{self.action}'")
+
+        deltas: list[OrderBookDelta] = []
+        if is_snapshot:
+            deltas.append(OrderBookDelta.clear(instrument_id, 0, ts_event, ts_init))
+
+        levels_len = len(levels)
+        for idx, level in enumerate(levels):
+            flags = RecordFlag.F_SNAPSHOT if is_snapshot else 0
+            if idx == levels_len - 1:
+                # F_LAST, 1 << 7
+                # Last message in the book event or packet from the venue for a given `instrument_id`
+                flags |= RecordFlag.F_LAST
+
+            deltas.append(
+                OrderBookDelta(
+                    instrument_id=instrument_id,
+                    action=action,
+                    order=BookOrder(
+                        side=OrderSide.BUY if level.side == BitmexOrderSide.BUY else OrderSide.SELL,
+                        price=Price(level.price, price_precision),
+                        size=Quantity(level.size or 0, size_precision),
+                        order_id=0,
+                    ),
+                    flags=flags,
+                    sequence=0,
+                    ts_event=ts_event,
+                    ts_init=ts_init,
+                ),
+            )
+
+        return OrderBookDeltas(instrument_id=instrument_id, deltas=deltas)
+
+
+class BitmexWsQuote(msgspec.Struct):
+    symbol: str
+    timestamp: datetime.datetime
+    bidPrice: float | None = None
+    bidSize: float | None = None
+    askPrice: float | None = None
+    askSize: float | None = None
+
+    def parse_to_quote_tick(
+        self,
+        instrument_id: InstrumentId,
+        price_precision: int,
+        size_precision: int,
+        ts_init: int,
+    ) -> QuoteTick | None:
+        if self.bidPrice is None or self.askPrice is None:
+            return None  # One side of the book is empty
+
+        return QuoteTick(
+            instrument_id=instrument_id,
+            bid_price=Price(self.bidPrice, price_precision),
+            ask_price=Price(self.askPrice, price_precision),
+            bid_size=Quantity(self.bidSize or 0, size_precision),
+            ask_size=Quantity(self.askSize or 0, size_precision),
+            ts_event=dt_to_unix_nanos(self.timestamp),
+            ts_init=ts_init,
+        )
+
+
+class BitmexWsQuoteMsg(msgspec.Struct):
+    table: str
+    action: str
+    data: list[BitmexWsQuote]
+
+
+class BitmexWsTrade(msgspec.Struct):
+    symbol: str
+    side: str
+    size: float
+    price: float
+    trdMatchID: str
+    timestamp: datetime.datetime
+
+    def parse_to_trade_tick(
+        self,
+        instrument_id: InstrumentId,
+        price_precision: int,
+        size_precision: int,
+        ts_init: int,
+    ) -> TradeTick:
+        # The side is the side of the aggressing (taker) order
+        return TradeTick(
+            instrument_id=instrument_id,
+            price=Price(self.price, price_precision),
+            size=Quantity(self.size, size_precision),
+            aggressor_side=parse_aggressor_side(self.side),
+            trade_id=TradeId(self.trdMatchID),
+            ts_event=dt_to_unix_nanos(self.timestamp),
+            ts_init=ts_init,
+        )
+
+
+class BitmexWsTradeMsg(msgspec.Struct):
+    table: str
+    action: str
+    data: list[BitmexWsTrade]
+
+
+class BitmexWsInstrumentMsg(msgspec.Struct):
+    table: str
+    action: str
+    data: list[BitmexInstrumentUpdate]
+
+
+################################################################################
+# User
+################################################################################
+
+
+class BitmexWsExecutionMsg(msgspec.Struct):
+    table: str
+    action: str
+    data: list[BitmexExecution]
+
+
+class BitmexWsMarginMsg(msgspec.Struct):
+    table: str
+    action: str
+    data: list[dict[str, Any]]  # Updates only include the changed fields
@@ -0,0 +1,143 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from decimal import Decimal
+from typing import Any
+
+from nautilus_trader.core.data import Data
+from nautilus_trader.model.identifiers import InstrumentId
+
+
+class BitmexFundingRateUpdate(Data):
+    """
+    Represents a BitMEX perpetual funding rate update.
+
+    Parameters
+    ----------
+    instrument_id : InstrumentId
+        The instrument ID for the update.
+    funding_rate : Decimal
+        The funding rate for the current funding interval (typically eight hours).
+    ts_next_funding : uint64_t
+        UNIX timestamp (nanoseconds) when next funding will occur.
+    ts_event : uint64_t
+        UNIX timestamp (nanoseconds) when the data event occurred.
+    ts_init : uint64_t
+        UNIX timestamp (nanoseconds) when the data object was initialized.
+
+    References
+    ----------
+    https://www.bitmex.com/app/wsAPI
+
+    """
+
+    def __init__(
+        self,
+        instrument_id: InstrumentId,
+        funding_rate: Decimal,
+        ts_next_funding: int,
+        ts_event: int,
+        ts_init: int,
+    ):
+        self.instrument_id = instrument_id
+        self.funding_rate = funding_rate
+        self.ts_next_funding = ts_next_funding
+        self._ts_event = ts_event
+        self._ts_init = ts_init
+
+    def __eq__(self, other: object) -> bool:
+        if not isinstance(other, BitmexFundingRateUpdate):
+            return False
+        return (
+            self.instrument_id == other.instrument_id
+            and self.funding_rate == other.funding_rate
+            and self.ts_next_funding == other.ts_next_funding
+        )
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"instrument_id={self.instrument_id}, "
+            f"funding_rate={self.funding_rate}, "
+            f"ts_next_funding={self.ts_next_funding}, "
+            f"ts_event={self.ts_event}, "
+            f"ts_init={self.ts_init})"
+        )
+
+    @property
+    def ts_event(self) -> int:
+        """
+        UNIX timestamp (nanoseconds) when the data event occurred.
+
+        Returns
+        -------
+        int
+
+        """
+        return self._ts_event
+
+    @property
+    def ts_init(self) -> int:
+        """
+        UNIX timestamp (nanoseconds) when the object was initialized.
+
+        Returns
+        -------
+        int
+
+        """
+        return self._ts_init
+
+    @staticmethod
+    def from_dict(values: dict[str, Any]) -> "BitmexFundingRateUpdate":
+        """
+        Return a BitMEX funding rate update parsed from the given values.
+
+        Parameters
+        ----------
+        values : dict[str, Any]
+            The values for initialization.
+
+        Returns
+        -------
+        BitmexFundingRateUpdate
+
+        """
+        return BitmexFundingRateUpdate(
+            instrument_id=InstrumentId.from_str(values["instrument_id"]),
+            funding_rate=Decimal(values["funding_rate"]),
+            ts_next_funding=values["ts_next_funding"],
+            ts_event=values["ts_event"],
+            ts_init=values["ts_init"],
+        )
+
+    @staticmethod
+    def to_dict(obj: "BitmexFundingRateUpdate") -> dict[str, Any]:
+        """
+        Return a dictionary representation of this object.
+
+        Returns
+        -------
+        dict[str, Any]
+
+        """
+        return {
+            "type": type(obj).__name__,
+            "instrument_id": str(obj.instrument_id),
+            "funding_rate": str(obj.funding_rate),
+            "ts_next_funding": obj.ts_next_funding,
+            "ts_event": obj.ts_event,
+            "ts_init": obj.ts_init,
+        }
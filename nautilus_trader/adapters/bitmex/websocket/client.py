@@ -0,0 +1,327 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+import asyncio
+from typing import TYPE_CHECKING
+from typing import Any
+
+from msgspec import json as msgspec_json
+
+from nautilus_trader.adapters.bitmex.common.constants import BITMEX_SIGNATURE_EXPIRY_SECS
+from nautilus_trader.adapters.bitmex.schemas.ws import BitmexWsMessageGeneral
+from nautilus_trader.common.component import LiveClock
+from nautilus_trader.common.component import Logger
+from nautilus_trader.common.enums import LogColor
+from nautilus_trader.core.nautilus_pyo3 import WebSocketClient
+from nautilus_trader.core.nautilus_pyo3 import WebSocketClientError
+from nautilus_trader.core.nautilus_pyo3 import WebSocketConfig
+from nautilus_trader.core.nautilus_pyo3 import hmac_signature
+
+
+if TYPE_CHECKING:
+    from collections.abc import Awaitable
+    from collections.abc import Callable
+
+
+class BitmexWebSocketClient:
+    """
+    Provides a BitMEX streaming WebSocket client.
+
+    Public and private tables share a single connection, where private tables
+    (`execution`, `order`, `position`, `margin`) require the connection to be
+    authenticated with the `authKeyExpires` operation.
+
+    Parameters
+    ----------
+    clock : LiveClock
+        The clock instance.
+    base_url : str
+        The base URL for the WebSocket connection.
+    handler : Callable[[bytes], None]
+        The callback handler for table data messages.
+    handler_reconnect : Callable[..., Awaitable[None]], optional
+        The callback handler to be called on reconnect.
+    loop : asyncio.AbstractEventLoop
+        The event loop for the client.
+    api_key : str, optional
+        The BitMEX API key (required for private tables).
+    api_secret : str, optional
+        The BitMEX API secret (required for private tables).
+    auth_timeout_secs : float, default 10.0
+        The timeout for the authentication response.
+
+    """
+
+    def __init__(
+        self,
+        clock: LiveClock,
+        base_url: str,
+        handler: Callable[[bytes], None],
+        handler_reconnect: Callable[..., Awaitable[None]] | None,
+        loop: asyncio.AbstractEventLoop,
+        api_key: str | None = None,
+        api_secret: str | None = None,
+        auth_timeout_secs: float = 10.0,
+    ) -> None:
+        self._clock = clock
+        self._log: Logger = Logger(name=type(self).__name__)
+
+        self._base_url: str = base_url
+        self._handler: Callable[[bytes], None] = handler
+        self._handler_reconnect: Callable[..., Awaitable[None]] | None = handler_reconnect
+        self._loop = loop
+        self._api_key = api_key
+        self._api_secret = api_secret
+        self._auth_timeout_secs = auth_timeout_secs
+
+        self._client: WebSocketClient | None = None
+        self._is_running = False
+        self._reconnecting = False
+        self._auth_future: asyncio.Future[bool] | None = None
+
+        # Subscription topics, e.g. `quote:XBTUSD` (replayed on reconnect)
+        self._subscriptions: list[str] = []
+
+        self._decoder_ws_message_general = msgspec_json.Decoder(BitmexWsMessageGeneral)
+
+    @property
+    def subscriptions(self) -> list[str]:
+        return self._subscriptions
+
+    @property
+    def auth_required(self) -> bool:
+        return self._api_key is not None and self._api_secret is not None
+
+    def has_subscription(self, item: str) -> bool:
+        return item in self._subscriptions
+
+    async def connect(self) -> None:
+        self._is_running = True
+        self._log.debug(f"Connecting to {self._base_url} websocket stream")
+
+        # The server closes connections without a message for some time,
+        # so a `ping` is sent which is answered with a `pong`
+        config = WebSocketConfig(
+            url=self._base_url,
+            handler=self._msg_handler,
+            heartbeat=5,
+            heartbeat_msg="ping",
+            headers=[],
+        )
+        client = await WebSocketClient.connect(
+            config=config,
+            post_reconnection=self.reconnect,
+        )
+        self._client = client
+        self._log.info(f"Connected to {self._base_url}", LogColor.BLUE)
+
+        if self.auth_required:
+            await self._authenticate()
+
+    def reconnect(self) -> None:
+        """
+        Reconnect the client to the server and resubscribe to all streams.
+        """
+        if not self._is_running or self._reconnecting:
+            return
+
+        self._log.warning(f"Trying to reconnect to {self._base_url}")
+        self._reconnecting = True
+        self._loop.create_task(self._reconnect_wrapper())
+
+    async def _reconnect_wrapper(self) -> None:
+        try:
+            if self.auth_required:
+                await self._authenticate()
+
+            self._log.warning(f"Resubscribing to {len(self._subscriptions)} streams")
+
+            await self._subscribe_all()
+
+            if self._handler_reconnect:
+                await self._handler_reconnect()
+
+            self._log.warning(f"Reconnected to {self._base_url}")
+        except Exception as e:
+            self._log.error(f"Reconnection failed: {e}")
+        finally:
+            self._reconnecting = False
+
+    async def disconnect(self) -> None:
+        self._is_running = False
+        self._reconnecting = False
+
+        if self._client is None:
+            self._log.warning("Cannot disconnect: not connected.")
+            return
+
+        try:
+            await self._client.disconnect()
+        except WebSocketClientError as e:
+            self._log.error(str(e))
+
+        self._client = None  # Dispose (will go out of scope)
+
+        if self._auth_future is not None:
+            self._auth_future.cancel()
+            self._auth_future = None
+
+        self._log.info(f"Disconnected from {self._base_url}", LogColor.BLUE)
+
+    def _msg_handler(self, raw: bytes) -> None:
+        """
+        Handle pushed websocket messages.
+
+        Parameters
+        ----------
+        raw : bytes
+            The received message in bytes.
+
+        """
+        if raw == b"pong":
+            return
+
+        msg = self._decoder_ws_message_general.decode(raw)
+        if msg.table is not None:
+            self._handler(raw)
+            return
+
+        op = msg.request.get("op") if msg.request else None
+        if op == "authKeyExpires":
+            self._handle_auth_response(msg)
+        elif msg.error is not None:
+            self._log.error(f"Received error: {raw.decode()}")
+        elif msg.info is not None:
+            self._log.debug(f"Received info: {msg.info}")
+
+    ################################################################################
+    # Authentication
+    ################################################################################
+
+    def _handle_auth_response(self, msg: BitmexWsMessageGeneral) -> None:
+        if self._auth_future is None or self._auth_future.done():
+            return
+
+        if msg.success:
+            self._auth_future.set_result(True)
+        else:
+            self._auth_future.set_exception(
+                RuntimeError(f"Authentication failed: {msg.error}"),
+            )
+
+    async def _authenticate(self) -> None:
+        assert self._api_key is not None and self._api_secret is not None  # Type checking
+
+        # The signature is for a `GET` request to `/realtime` which expires (UNIX seconds)
+        expires = int(self._clock.timestamp()) + BITMEX_SIGNATURE_EXPIRY_SECS
+        signature = hmac_signature(self._api_secret, f"GET/realtime{expires}")
+
+        self._auth_future = self._loop.create_future()
+        await self._send({"op": "authKeyExpires", "args": [self._api_key, expires, signature]})
+
+        try:
+            await asyncio.wait_for(self._auth_future, self._auth_timeout_secs)
+            self._log.info("Authenticated", LogColor.GREEN)
+        except TimeoutError as e:
+            raise RuntimeError("Authentication timed out") from e
+        finally:
+            self._auth_future = None
+
+    ################################################################################
+    # Subscriptions
+    ################################################################################
+
+    async def _subscribe(self, topic: str) -> None:
+        self._log.debug(f"Subscribing to {topic}")
+        if topic in self._subscriptions:
+            self._log.warning(f"Cannot subscribe '{topic}': already subscribed")
+            return
+
+        self._subscriptions.append(topic)
+        await self._send({"op": "subscribe", "args": [topic]})
+
+    async def _unsubscribe(self, topic: str) -> None:
+        if topic not in self._subscriptions:
+            self._log.warning(f"Cannot unsubscribe '{topic}': not subscribed")
+            return
+
+        self._subscriptions.remove(topic)
+        await self._send({"op": "unsubscribe", "args": [topic]})
+
+    async def _subscribe_all(self) -> None:
+        if self._client is None:
+            self._log.error("Cannot subscribe all: not connected")
+            return
+
+        if self._subscriptions:
+            await self._send({"op": "subscribe", "args": self._subscriptions})
+
+    async def _send(self, msg: dict[str, Any]) -> None:
+        if self._client is None:
+            self._log.error(f"Cannot send message {msg}: not connected")
+            return
+
+        encoded = msgspec_json.encode(msg)
+        self._log.debug(f"SENDING: {encoded!r}")
+
+        try:
+            await self._client.send_text(encoded)
+        except WebSocketClientError as e:
+            self._log.error(str(e))
+
+    ################################################################################
+    # Public
+    ################################################################################
+
+    async def subscribe_order_book(self, symbol: str, depth: int = 25) -> None:
+        # The `orderBookL2_25` table publishes the top 25 levels, otherwise the full book
+        table = "orderBookL2_25" if 0 < depth <= 25 else "orderBookL2"
+        await self._subscribe(f"{table}:{symbol}")
+
+    async def subscribe_quotes(self, symbol: str) -> None:
+        await self._subscribe(f"quote:{symbol}")
+
+    async def subscribe_trades(self, symbol: str) -> None:
+        await self._subscribe(f"trade:{symbol}")
+
+    async def subscribe_instrument(self, symbol: str) -> None:
+        await self._subscribe(f"instrument:{symbol}")
+
+    async def unsubscribe_order_book(self, symbol: str) -> None:
+        for table in ("orderBookL2_25", "orderBookL2"):
+            topic = f"{table}:{symbol}"
+            if topic in self._subscriptions:
+                await self._unsubscribe(topic)
+
+    async def unsubscribe_quotes(self, symbol: str) -> None:
+        await self._unsubscribe(f"quote:{symbol}")
+
+    async def unsubscribe_trades(self, symbol: str) -> None:
+        await self._unsubscribe(f"trade:{symbol}")
+
+    async def unsubscribe_instrument(self, symbol: str) -> None:
+        await self._unsubscribe(f"instrument:{symbol}")
+
+    ################################################################################
+    # Private
+    ################################################################################
+
+    async def subscribe_executions(self) -> None:
+        await self._subscribe("execution")
+
+    async def subscribe_margin(self) -> None:
+        await self._subscribe("margin")
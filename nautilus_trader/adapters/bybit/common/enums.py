@@ -390,12 +390,13 @@ class BybitEnumParser:
             BybitTimeInForce.GTC: TimeInForce.GTC,
             BybitTimeInForce.IOC: TimeInForce.IOC,
             BybitTimeInForce.FOK: TimeInForce.FOK,
-            BybitTimeInForce.POST_ONLY: TimeInForce.GTC,
+            BybitTimeInForce.POST_ONLY: TimeInForce.GTX,
         }
         self.nautilus_to_bybit_time_in_force = {
             TimeInForce.GTC: BybitTimeInForce.GTC,
             TimeInForce.IOC: BybitTimeInForce.IOC,
             TimeInForce.FOK: BybitTimeInForce.FOK,
+            TimeInForce.GTX: BybitTimeInForce.POST_ONLY,
         }
 
         # fmt: off
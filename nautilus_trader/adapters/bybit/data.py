@@ -17,6 +17,7 @@ from __future__ import annotations
 
 import asyncio
 from collections import defaultdict
+from decimal import Decimal
 from functools import partial
 from typing import TYPE_CHECKING
 
@@ -41,6 +42,7 @@ from nautilus_trader.adapters.bybit.schemas.ws import BybitWsTickerLinearMsg
 from nautilus_trader.adapters.bybit.schemas.ws import decoder_ws_kline
 from nautilus_trader.adapters.bybit.schemas.ws import decoder_ws_orderbook
 from nautilus_trader.adapters.bybit.schemas.ws import decoder_ws_trade
+from nautilus_trader.adapters.bybit.types import BybitFundingRateUpdate
 from nautilus_trader.adapters.bybit.websocket.client import BybitWebSocketClient
 from nautilus_trader.common.enums import LogColor
 from nautilus_trader.core.datetime import millis_to_nanos
@@ -55,10 +57,12 @@ from nautilus_trader.data.messages import RequestInstruments
 from nautilus_trader.data.messages import RequestQuoteTicks
 from nautilus_trader.data.messages import RequestTradeTicks
 from nautilus_trader.data.messages import SubscribeBars
+from nautilus_trader.data.messages import SubscribeData
 from nautilus_trader.data.messages import SubscribeOrderBook
 from nautilus_trader.data.messages import SubscribeQuoteTicks
 from nautilus_trader.data.messages import SubscribeTradeTicks
 from nautilus_trader.data.messages import UnsubscribeBars
+from nautilus_trader.data.messages import UnsubscribeData
 from nautilus_trader.data.messages import UnsubscribeOrderBook
 from nautilus_trader.data.messages import UnsubscribeQuoteTicks
 from nautilus_trader.data.messages import UnsubscribeTradeTicks
@@ -177,6 +181,8 @@ class BybitDataClient(LiveMarketDataClient):
         self._decoder_ws_msg_general = msgspec.json.Decoder(BybitWsMessageGeneral)
 
         self._tob_quotes: set[InstrumentId] = set()
+        self._ticker_quotes: set[InstrumentId] = set()
+        self._funding_rates: set[InstrumentId] = set()
         self._depths: dict[InstrumentId, int] = {}
         self._topic_bar_type: dict[str, BarType] = {}
 
@@ -192,6 +198,7 @@ class BybitDataClient(LiveMarketDataClient):
         # Hot caches
         self._instrument_ids: dict[str, InstrumentId] = {}
         self._last_quotes: dict[InstrumentId, QuoteTick] = {}
+        self._last_funding_rates: dict[InstrumentId, BybitFundingRateUpdate] = {}
 
     async def fetch_send_tickers(
         self,
@@ -328,6 +335,53 @@ class BybitDataClient(LiveMarketDataClient):
         ws_client = self._ws_clients[bybit_symbol.product_type]
         await ws_client.subscribe_order_book(bybit_symbol.raw_symbol, depth=depth)
 
+    async def _subscribe(self, command: SubscribeData) -> None:
+        instrument_id: InstrumentId | None = command.data_type.metadata.get("instrument_id")
+        if instrument_id is None:
+            self._log.error(
+                f"Cannot subscribe to `{command.data_type.type}` no instrument ID in `data_type` metadata",
+            )
+            return
+
+        if command.data_type.type == BybitFundingRateUpdate:
+            bybit_symbol = BybitSymbol(instrument_id.symbol.value)
+            if not (bybit_symbol.is_linear or bybit_symbol.is_inverse):
+                self._log.error(
+                    f"Cannot subscribe to `BybitFundingRateUpdate` for {instrument_id}: "
+                    "funding rates are only available for LINEAR and INVERSE products",
+                )
+                return
+            self._funding_rates.add(instrument_id)
+            if instrument_id not in self._ticker_quotes:
+                ws_client = self._ws_clients[bybit_symbol.product_type]
+                await ws_client.subscribe_tickers(bybit_symbol.raw_symbol)
+        else:
+            self._log.error(
+                f"Cannot subscribe to {command.data_type.type} (not implemented)",
+            )
+
+    async def _unsubscribe(self, command: UnsubscribeData) -> None:
+        instrument_id: InstrumentId | None = command.data_type.metadata.get("instrument_id")
+        if instrument_id is None:
+            self._log.error(
+                f"Cannot unsubscribe from `{command.data_type.type}` no instrument ID in `data_type` metadata",
+            )
+            return
+
+        if command.data_type.type == BybitFundingRateUpdate:
+            if instrument_id not in self._funding_rates:
+                return
+            self._funding_rates.discard(instrument_id)
+            self._last_funding_rates.pop(instrument_id, None)
+            if instrument_id not in self._ticker_quotes:
+                bybit_symbol = BybitSymbol(instrument_id.symbol.value)
+                ws_client = self._ws_clients[bybit_symbol.product_type]
+                await ws_client.unsubscribe_tickers(bybit_symbol.raw_symbol)
+        else:
+            self._log.error(
+                f"Cannot unsubscribe from {command.data_type.type} (not implemented)",
+            )
+
     async def _subscribe_quote_ticks(self, command: SubscribeQuoteTicks) -> None:
         bybit_symbol = BybitSymbol(command.instrument_id.symbol.value)
         ws_client = self._ws_clients[bybit_symbol.product_type]
@@ -341,7 +395,9 @@ class BybitDataClient(LiveMarketDataClient):
             self._tob_quotes.add(command.instrument_id)
             await ws_client.subscribe_order_book(bybit_symbol.raw_symbol, depth=1)
         else:
-            await ws_client.subscribe_tickers(bybit_symbol.raw_symbol)
+            self._ticker_quotes.add(command.instrument_id)
+            if command.instrument_id not in self._funding_rates:
+                await ws_client.subscribe_tickers(bybit_symbol.raw_symbol)
 
     async def _subscribe_trade_ticks(self, command: SubscribeTradeTicks) -> None:
         bybit_symbol = BybitSymbol(command.instrument_id.symbol.value)
@@ -374,7 +430,9 @@ class BybitDataClient(LiveMarketDataClient):
         if command.instrument_id in self._tob_quotes:
             await ws_client.unsubscribe_order_book(bybit_symbol.raw_symbol, depth=1)
         else:
-            await ws_client.unsubscribe_tickers(bybit_symbol.raw_symbol)
+            self._ticker_quotes.discard(command.instrument_id)
+            if command.instrument_id not in self._funding_rates:
+                await ws_client.unsubscribe_tickers(bybit_symbol.raw_symbol)
 
     async def _unsubscribe_trade_ticks(self, command: UnsubscribeTradeTicks) -> None:
         bybit_symbol = BybitSymbol(command.instrument_id.symbol.value)
@@ -628,6 +686,12 @@ class BybitDataClient(LiveMarketDataClient):
                 self._log.error(f"Cannot parse trade data: no instrument for {instrument_id}")
                 return
 
+            if instrument_id in self._funding_rates:
+                self._handle_funding_rate(instrument_id, msg)
+
+            if instrument_id not in self._ticker_quotes:
+                return
+
             last_quote = self._last_quotes.get(instrument_id)
 
             bid_price = None
@@ -668,6 +732,43 @@ class BybitDataClient(LiveMarketDataClient):
         except Exception as e:
             self._log.error(f"Failed to parse ticker: {msg} with error {e}")
 
+    def _handle_funding_rate(self, instrument_id: InstrumentId, msg: BybitWsTickerLinearMsg) -> None:
+        # Ticker deltas only contain changed fields, so merge with the last update
+        last = self._last_funding_rates.get(instrument_id)
+        if not msg.data.fundingRate and not msg.data.nextFundingTime:
+            return  # No change to funding (or not a perpetual)
+
+        if msg.data.fundingRate:
+            funding_rate = Decimal(msg.data.fundingRate)
+        elif last is not None:
+            funding_rate = last.funding_rate
+        else:
+            return  # Wait for the initial snapshot
+
+        if msg.data.nextFundingTime:
+            ts_next_funding = millis_to_nanos(int(msg.data.nextFundingTime))
+        elif last is not None:
+            ts_next_funding = last.ts_next_funding
+        else:
+            return  # Wait for the initial snapshot
+
+        data = BybitFundingRateUpdate(
+            instrument_id=instrument_id,
+            funding_rate=funding_rate,
+            ts_next_funding=ts_next_funding,
+            ts_event=millis_to_nanos(msg.ts),
+            ts_init=self._clock.timestamp_ns(),
+        )
+        if data == last:
+            return  # Funding unchanged
+
+        self._last_funding_rates[instrument_id] = data
+        data_type = DataType(
+            BybitFundingRateUpdate,
+            metadata={"instrument_id": instrument_id},
+        )
+        self._handle_data(CustomData(data_type=data_type, data=data))
+
     def _handle_trade(self, product_type: BybitProductType, raw: bytes) -> None:
         msg = self._decoder_ws_trade.decode(raw)
         try:
@@ -42,6 +42,7 @@ from nautilus_trader.adapters.bybit.schemas.ws import BybitWsAccountExecutionFas
 from nautilus_trader.adapters.bybit.schemas.ws import BybitWsAccountExecutionFastMsg
 from nautilus_trader.adapters.bybit.schemas.ws import BybitWsAccountExecutionMsg
 from nautilus_trader.adapters.bybit.schemas.ws import BybitWsAccountOrderMsg
+from nautilus_trader.adapters.bybit.schemas.ws import BybitWsAccountPositionMsg
 from nautilus_trader.adapters.bybit.schemas.ws import BybitWsAccountWalletMsg
 from nautilus_trader.adapters.bybit.schemas.ws import BybitWsMessageGeneral
 from nautilus_trader.adapters.bybit.websocket.client import BybitWebSocketClient
@@ -252,7 +253,7 @@ class BybitExecutionClient(LiveExecutionClient):
         self._decoder_ws_account_execution_fast_update = msgspec.json.Decoder(
             BybitWsAccountExecutionFastMsg,
         )
-        # self._decoder_ws_account_position_update = msgspec.json.Decoder(BybitWsAccountPositionMsg)
+        self._decoder_ws_account_position_update = msgspec.json.Decoder(BybitWsAccountPositionMsg)
         self._decoder_ws_account_wallet_update = msgspec.json.Decoder(BybitWsAccountWalletMsg)
 
         # Hot caches
@@ -276,6 +277,7 @@ class BybitExecutionClient(LiveExecutionClient):
 
         await self._ws_private_client.subscribe_orders_update()
         await self._ws_private_client.subscribe_wallet_update()
+        await self._ws_private_client.subscribe_account_position_update()
 
         if self._use_ws_execution_fast:
             await self._ws_private_client.subscribe_executions_fast_update()
@@ -1004,6 +1006,8 @@ class BybitExecutionClient(LiveExecutionClient):
             # wallet has no `Categorised Topic`, `order` event should trigger `wallet` event
             elif "wallet" == topic:
                 self._handle_account_wallet_update(raw)
+            elif "position" in topic:
+                self._handle_account_position_update(raw)
             elif "execution" in topic:
                 if "execution.fast" in topic:
                     self._handle_account_execution_fast_update(raw)
@@ -1257,6 +1261,13 @@ class BybitExecutionClient(LiveExecutionClient):
         msg: BybitWsAccountWalletMsg = self._decoder_ws_account_wallet_update.decode(raw)
         msg.handle_account_wallet_update(self)
 
+    def _handle_account_position_update(self, raw: bytes) -> None:
+        try:
+            msg: BybitWsAccountPositionMsg = self._decoder_ws_account_position_update.decode(raw)
+            msg.handle_account_position_update(self)
+        except Exception as e:
+            self._log.exception(f"Failed to handle account position update: {e}", e)
+
     def _create_market_batch_order(
         self,
         order: MarketOrder,
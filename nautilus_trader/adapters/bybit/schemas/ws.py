@@ -567,6 +567,39 @@ class BybitWsAccountPositionMsg(msgspec.Struct):
     creationTime: int
     data: list[BybitWsAccountPosition]
 
+    def handle_account_position_update(self, exec_client: BybitExecutionClient):
+        account = exec_client.get_account()
+        if account is None:
+            return  # Margins applied once the initial account state has been generated
+
+        margins: dict[InstrumentId | None, MarginBalance] = account.margins()
+        for position in self.data:
+            if position.category == BybitProductType.SPOT:
+                continue  # No position margin for spot
+            instrument_id = exec_client._get_cached_instrument_id(
+                position.symbol,
+                position.category,
+            )
+            instrument = exec_client._cache.instrument(instrument_id)
+            if instrument is None:
+                exec_client._log.warning(
+                    f"Cannot apply position margin: no instrument for {instrument_id}",
+                )
+                continue
+            currency = instrument.get_settlement_currency()
+            margins[instrument_id] = MarginBalance(
+                initial=Money(Decimal(position.positionIM or 0), currency),
+                maintenance=Money(Decimal(position.positionMM or 0), currency),
+                instrument_id=instrument_id,
+            )
+
+        exec_client.generate_account_state(
+            balances=list(account.balances().values()),
+            margins=list(margins.values()),
+            reported=True,
+            ts_event=millis_to_nanos(self.creationTime),
+        )
+
 
 ################################################################################
 # Private - Account Order
@@ -0,0 +1,123 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+import base64
+import json
+
+from nautilus_trader.adapters.coinbase.common.constants import COINBASE_JWT_EXPIRY_SECS
+from nautilus_trader.core.nautilus_pyo3 import ed25519_signature
+from nautilus_trader.core.uuid import UUID4
+
+
+def _base64url(data: bytes) -> str:
+    return base64.urlsafe_b64encode(data).rstrip(b"=").decode()
+
+
+class CoinbaseJwtSigner:
+    """
+    Provides JSON Web Tokens (JWTs) for Coinbase Developer Platform (CDP) API keys.
+
+    Requests are authenticated with a short lived JWT, signed with the Ed25519 private
+    key of the API key. The API secret is the base64 encoded private key (the 32 byte
+    seed, optionally followed by the 32 byte public key), as downloaded when creating
+    an Ed25519 API key.
+
+    Parameters
+    ----------
+    api_key : str
+        The CDP API key name (e.g. `organizations/{org_id}/apiKeys/{key_id}`) or ID.
+    api_secret : str
+        The base64 encoded Ed25519 private key.
+
+    Raises
+    ------
+    ValueError
+        If `api_secret` is not a base64 encoded Ed25519 private key
+        (ECDSA keys in PEM format are not supported).
+
+    """
+
+    def __init__(self, api_key: str, api_secret: str) -> None:
+        if "BEGIN EC PRIVATE KEY" in api_secret:
+            raise ValueError(
+                "ECDSA API keys are not supported, create an API key with the Ed25519 signature algorithm",
+            )
+
+        try:
+            private_key = base64.b64decode(api_secret, validate=True)
+        except ValueError as e:
+            raise ValueError("invalid Coinbase API secret: not base64 encoded") from e
+
+        if len(private_key) not in (32, 64):
+            raise ValueError(
+                f"invalid Coinbase API secret: expected a 32 or 64 byte Ed25519 key, "
+                f"was {len(private_key)} bytes",
+            )
+
+        self._api_key = api_key
+        self._seed = private_key[:32]
+
+    @property
+    def api_key(self) -> str:
+        return self._api_key
+
+    def token(
+        self,
+        timestamp_secs: int,
+        uri: str | None = None,
+        nonce: str | None = None,
+    ) -> str:
+        """
+        Return a signed JWT, valid for two minutes from the given timestamp.
+
+        Parameters
+        ----------
+        timestamp_secs : int
+            The UNIX timestamp (seconds) the token is valid from.
+        uri : str, optional
+            The request URI for REST requests (e.g. `GET api.coinbase.com/api/v3/brokerage/accounts`).
+            WebSocket tokens have no URI.
+        nonce : str, optional
+            The nonce for the token (a random nonce if ``None``).
+
+        Returns
+        -------
+        str
+
+        """
+        header = {
+            "alg": "EdDSA",
+            "typ": "JWT",
+            "kid": self._api_key,
+            "nonce": nonce or UUID4().value.replace("-", ""),
+        }
+        payload: dict[str, str | int] = {
+            "sub": self._api_key,
+            "iss": "cdp",
+            "nbf": timestamp_secs,
+            "exp": timestamp_secs + COINBASE_JWT_EXPIRY_SECS,
+        }
+        if uri is not None:
+            payload["uri"] = uri
+
+        message = (
+            _base64url(json.dumps(header, separators=(",", ":")).encode())
+            + "."
+            + _base64url(json.dumps(payload, separators=(",", ":")).encode())
+        )
+        signature = bytes.fromhex(ed25519_signature(self._seed, message))
+        return f"{message}.{_base64url(signature)}"
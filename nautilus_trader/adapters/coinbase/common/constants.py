@@ -0,0 +1,48 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from decimal import Decimal
+from typing import Final
+
+from nautilus_trader.model.identifiers import Venue
+
+
+COINBASE: Final[str] = "COINBASE"
+COINBASE_VENUE: Final[Venue] = Venue(COINBASE)
+
+# The host of the Advanced Trade API, which is included in the JWT `uri` claim
+COINBASE_API_HOST: Final[str] = "api.coinbase.com"
+COINBASE_API_PATH: Final[str] = "/api/v3/brokerage"
+
+# JWTs are valid for two minutes, and a new token is generated for each request
+COINBASE_JWT_EXPIRY_SECS: Final[int] = 120
+
+# Fee rates of the lowest (intro) tier, used when the account fee tier is unknown
+COINBASE_DEFAULT_MAKER_FEE: Final[Decimal] = Decimal("0.006")
+COINBASE_DEFAULT_TAKER_FEE: Final[Decimal] = Decimal("0.012")
+
+# The maximum number of orders for each batch cancel request
+COINBASE_MAX_BATCH_CANCEL: Final[int] = 100
+
+COINBASE_RETRY_ERRORS: Final[set[int | str]] = {
+    429,  # Too many requests
+    500,  # Internal server error
+    502,  # Bad gateway
+    503,  # Service unavailable
+    504,  # Gateway timeout
+}
+
+COINBASE_PUBLIC_RATE_LIMIT_KEY: Final[str] = "coinbase:public"
+COINBASE_PRIVATE_RATE_LIMIT_KEY: Final[str] = "coinbase:private"
@@ -0,0 +1,164 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from enum import Enum
+from enum import unique
+
+from nautilus_trader.model.enums import LiquiditySide
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import TimeInForce
+
+
+@unique
+class CoinbaseProductType(Enum):
+    SPOT = "SPOT"
+    FUTURE = "FUTURE"
+
+
+@unique
+class CoinbaseOrderSide(Enum):
+    BUY = "BUY"
+    SELL = "SELL"
+
+
+@unique
+class CoinbaseOrderType(Enum):
+    MARKET = "MARKET"
+    LIMIT = "LIMIT"
+    STOP = "STOP"
+    STOP_LIMIT = "STOP_LIMIT"
+    BRACKET = "BRACKET"
+    UNKNOWN = "UNKNOWN_ORDER_TYPE"
+
+
+@unique
+class CoinbaseTimeInForce(Enum):
+    GTC = "GOOD_UNTIL_CANCELLED"
+    GTD = "GOOD_UNTIL_DATE_TIME"
+    IOC = "IMMEDIATE_OR_CANCEL"
+    FOK = "FILL_OR_KILL"
+    UNKNOWN = "UNKNOWN_TIME_IN_FORCE"
+
+
+@unique
+class CoinbaseOrderStatus(Enum):
+    PENDING = "PENDING"
+    OPEN = "OPEN"
+    FILLED = "FILLED"
+    CANCELLED = "CANCELLED"
+    EXPIRED = "EXPIRED"
+    FAILED = "FAILED"
+    QUEUED = "QUEUED"
+    CANCEL_QUEUED = "CANCEL_QUEUED"
+    UNKNOWN = "UNKNOWN_ORDER_STATUS"
+
+
+@unique
+class CoinbaseStopDirection(Enum):
+    UP = "STOP_DIRECTION_STOP_UP"
+    DOWN = "STOP_DIRECTION_STOP_DOWN"
+
+
+@unique
+class CoinbaseLiquidityIndicator(Enum):
+    MAKER = "MAKER"
+    TAKER = "TAKER"
+    UNKNOWN = "UNKNOWN_LIQUIDITY_INDICATOR"
+
+
+def check_dict_keys(key, data):
+    try:
+        return data[key]
+    except KeyError as e:
+        raise RuntimeError(
+            f"Unrecognized Coinbase {key} not found in {data}",
+        ) from e
+
+
+class CoinbaseEnumParser:
+    def __init__(self) -> None:
+        self.coinbase_to_nautilus_order_side = {
+            CoinbaseOrderSide.BUY: OrderSide.BUY,
+            CoinbaseOrderSide.SELL: OrderSide.SELL,
+        }
+        self.nautilus_to_coinbase_order_side = {
+            b: a for a, b in self.coinbase_to_nautilus_order_side.items()
+        }
+        self.coinbase_to_nautilus_order_type = {
+            CoinbaseOrderType.MARKET: OrderType.MARKET,
+            CoinbaseOrderType.LIMIT: OrderType.LIMIT,
+            CoinbaseOrderType.STOP_LIMIT: OrderType.STOP_LIMIT,
+            CoinbaseOrderType.STOP: OrderType.STOP_MARKET,
+        }
+        self.coinbase_to_nautilus_time_in_force = {
+            CoinbaseTimeInForce.GTC: TimeInForce.GTC,
+            CoinbaseTimeInForce.GTD: TimeInForce.GTD,
+            CoinbaseTimeInForce.IOC: TimeInForce.IOC,
+            CoinbaseTimeInForce.FOK: TimeInForce.FOK,
+        }
+        self.nautilus_to_coinbase_time_in_force = {
+            b: a for a, b in self.coinbase_to_nautilus_time_in_force.items()
+        }
+        self.coinbase_to_nautilus_order_status = {
+            CoinbaseOrderStatus.PENDING: OrderStatus.ACCEPTED,
+            CoinbaseOrderStatus.QUEUED: OrderStatus.ACCEPTED,
+            CoinbaseOrderStatus.OPEN: OrderStatus.ACCEPTED,
+            CoinbaseOrderStatus.CANCEL_QUEUED: OrderStatus.ACCEPTED,
+            CoinbaseOrderStatus.FILLED: OrderStatus.FILLED,
+            CoinbaseOrderStatus.CANCELLED: OrderStatus.CANCELED,
+            CoinbaseOrderStatus.EXPIRED: OrderStatus.EXPIRED,
+            CoinbaseOrderStatus.FAILED: OrderStatus.REJECTED,
+        }
+        self.coinbase_to_nautilus_liquidity_side = {
+            CoinbaseLiquidityIndicator.MAKER: LiquiditySide.MAKER,
+            CoinbaseLiquidityIndicator.TAKER: LiquiditySide.TAKER,
+            CoinbaseLiquidityIndicator.UNKNOWN: LiquiditySide.NO_LIQUIDITY_SIDE,
+        }
+
+    def parse_coinbase_order_side(self, order_side: CoinbaseOrderSide) -> OrderSide:
+        return check_dict_keys(order_side, self.coinbase_to_nautilus_order_side)
+
+    def parse_nautilus_order_side(self, order_side: OrderSide) -> CoinbaseOrderSide:
+        return check_dict_keys(order_side, self.nautilus_to_coinbase_order_side)
+
+    def parse_coinbase_order_type(self, order_type: CoinbaseOrderType) -> OrderType:
+        return check_dict_keys(order_type, self.coinbase_to_nautilus_order_type)
+
+    def parse_coinbase_time_in_force(self, time_in_force: CoinbaseTimeInForce) -> TimeInForce:
+        # Market orders are reported with an unknown time in force (always immediate)
+        if time_in_force == CoinbaseTimeInForce.UNKNOWN:
+            return TimeInForce.IOC
+        return check_dict_keys(time_in_force, self.coinbase_to_nautilus_time_in_force)
+
+    def parse_nautilus_time_in_force(self, time_in_force: TimeInForce) -> CoinbaseTimeInForce:
+        return check_dict_keys(time_in_force, self.nautilus_to_coinbase_time_in_force)
+
+    def parse_coinbase_order_status(
+        self,
+        order_status: CoinbaseOrderStatus,
+        is_partially_filled: bool,
+    ) -> OrderStatus:
+        status = check_dict_keys(order_status, self.coinbase_to_nautilus_order_status)
+        if status == OrderStatus.ACCEPTED and is_partially_filled:
+            return OrderStatus.PARTIALLY_FILLED
+        return status
+
+    def parse_coinbase_liquidity_side(
+        self,
+        liquidity_indicator: CoinbaseLiquidityIndicator,
+    ) -> LiquiditySide:
+        return check_dict_keys(liquidity_indicator, self.coinbase_to_nautilus_liquidity_side)
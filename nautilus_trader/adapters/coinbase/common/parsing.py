@@ -0,0 +1,86 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from decimal import Decimal
+
+import pandas as pd
+
+from nautilus_trader.model.enums import AggressorSide
+
+
+def parse_decimal(value: str | None) -> Decimal | None:
+    """
+    Parse the decimal string value, where an empty string is no value.
+
+    Parameters
+    ----------
+    value : str, optional
+        The value.
+
+    Returns
+    -------
+    Decimal or ``None``
+
+    """
+    if not value:
+        return None
+    return Decimal(value)
+
+
+def parse_timestamp(value: str) -> int:
+    """
+    Parse the RFC 3339 timestamp (e.g. `2024-05-31T09:59:59.123456789Z`) into UNIX nanoseconds.
+
+    Parameters
+    ----------
+    value : str
+        The timestamp.
+
+    Returns
+    -------
+    int
+
+    """
+    return pd.Timestamp(value).value
+
+
+def format_timestamp(timestamp_ns: int) -> str:
+    """
+    Format the UNIX nanoseconds timestamp as an RFC 3339 timestamp (e.g. `2024-05-31T09:59:59Z`).
+
+    Parameters
+    ----------
+    timestamp_ns : int
+        The UNIX timestamp in nanoseconds.
+
+    Returns
+    -------
+    str
+
+    """
+    return pd.Timestamp(timestamp_ns, tz="UTC").strftime("%Y-%m-%dT%H:%M:%S.%fZ")
+
+
+def parse_aggressor_side(side: str) -> AggressorSide:
+    # The side is the side of the taker order
+    match side:
+        case "BUY":
+            return AggressorSide.BUYER
+        case "SELL":
+            return AggressorSide.SELLER
+        case _:
+            return AggressorSide.NO_AGGRESSOR
@@ -0,0 +1,78 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from nautilus_trader.adapters.coinbase.common.constants import COINBASE_VENUE
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import Symbol
+
+
+class CoinbaseSymbol(str):
+    """
+    Represents a Coinbase Advanced Trade product ID.
+
+    Product IDs are the base and quote currency separated by a hyphen, for example
+    `BTC-USD` or `ETH-USDC`.
+
+    """
+
+    def __new__(cls, symbol: str) -> CoinbaseSymbol:  # noqa: PYI034
+        PyCondition.valid_string(symbol, "symbol")
+        return super().__new__(cls, symbol.upper())
+
+    @property
+    def base(self) -> str:
+        """
+        Return the base currency of the product (e.g. `BTC`).
+
+        Returns
+        -------
+        str
+
+        """
+        return self.split("-")[0]
+
+    @property
+    def quote(self) -> str:
+        """
+        Return the quote currency of the product (e.g. `USD`).
+
+        Returns
+        -------
+        str
+
+        Raises
+        ------
+        ValueError
+            If the symbol is not a valid product ID.
+
+        """
+        parts = self.split("-")
+        if len(parts) != 2:
+            raise ValueError(f"invalid Coinbase product ID, was '{self}'")
+        return parts[1]
+
+    def to_instrument_id(self) -> InstrumentId:
+        """
+        Parse the Coinbase product ID into a Nautilus instrument ID.
+
+        Returns
+        -------
+        InstrumentId
+
+        """
+        return InstrumentId(Symbol(str(self)), COINBASE_VENUE)
@@ -0,0 +1,80 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from nautilus_trader.config import LiveDataClientConfig
+from nautilus_trader.config import LiveExecClientConfig
+from nautilus_trader.config import PositiveFloat
+from nautilus_trader.config import PositiveInt
+
+
+class CoinbaseDataClientConfig(LiveDataClientConfig, frozen=True):
+    """
+    Configuration for ``CoinbaseDataClient`` instances.
+
+    Market data is public, so no credentials are required.
+
+    Parameters
+    ----------
+    base_url_http : str, optional
+        The base URL for the HTTP client.
+    base_url_ws : str, optional
+        The base URL for the market data WebSocket client.
+    update_instruments_interval_mins: PositiveInt or None, default 60
+        The interval (minutes) between reloading instruments from the venue.
+
+    """
+
+    base_url_http: str | None = None
+    base_url_ws: str | None = None
+    update_instruments_interval_mins: PositiveInt | None = 60
+
+
+class CoinbaseExecClientConfig(LiveExecClientConfig, frozen=True):
+    """
+    Configuration for ``CoinbaseExecutionClient`` instances.
+
+    Parameters
+    ----------
+    api_key : str, optional
+        The Coinbase Developer Platform (CDP) API key name.
+        If ``None`` then will source the `COINBASE_API_KEY` environment variable.
+    api_secret : str, optional
+        The base64 encoded Ed25519 private key of the API key.
+        If ``None`` then will source the `COINBASE_API_SECRET` environment variable.
+    base_url_http : str, optional
+        The base URL for the HTTP client.
+    base_url_ws : str, optional
+        The base URL for the user WebSocket client.
+    update_account_interval_secs : PositiveInt or None, default 60
+        The interval (seconds) between account balance updates. Balances are also
+        updated after each fill, and the interval reflects deposits and withdrawals.
+    max_retries : PositiveInt, optional
+        The maximum number of times a submit, cancel or modify order request will be retried.
+    retry_delay : PositiveFloat, optional
+        The delay (seconds) between retries. Short delays with frequent retries may result in account bans.
+
+    Warnings
+    --------
+    A short `retry_delay` with frequent retries may result in account bans.
+
+    """
+
+    api_key: str | None = None
+    api_secret: str | None = None
+    base_url_http: str | None = None
+    base_url_ws: str | None = None
+    update_account_interval_secs: PositiveInt | None = 60
+    max_retries: PositiveInt | None = None
+    retry_delay: PositiveFloat | None = None
@@ -0,0 +1,351 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+import asyncio
+from typing import TYPE_CHECKING
+
+import msgspec
+
+from nautilus_trader.adapters.coinbase.common.constants import COINBASE_VENUE
+from nautilus_trader.adapters.coinbase.common.parsing import parse_timestamp
+from nautilus_trader.adapters.coinbase.common.symbol import CoinbaseSymbol
+from nautilus_trader.adapters.coinbase.schemas.ws import CoinbaseWsL2Msg
+from nautilus_trader.adapters.coinbase.schemas.ws import CoinbaseWsMarketTradesMsg
+from nautilus_trader.adapters.coinbase.schemas.ws import CoinbaseWsMessageGeneral
+from nautilus_trader.adapters.coinbase.schemas.ws import CoinbaseWsTickerMsg
+from nautilus_trader.adapters.coinbase.websocket.client import CoinbaseWebSocketClient
+from nautilus_trader.common.enums import LogColor
+from nautilus_trader.data.messages import RequestBars
+from nautilus_trader.data.messages import RequestInstrument
+from nautilus_trader.data.messages import RequestInstruments
+from nautilus_trader.data.messages import RequestQuoteTicks
+from nautilus_trader.data.messages import RequestTradeTicks
+from nautilus_trader.data.messages import SubscribeOrderBook
+from nautilus_trader.data.messages import SubscribeQuoteTicks
+from nautilus_trader.data.messages import SubscribeTradeTicks
+from nautilus_trader.data.messages import UnsubscribeOrderBook
+from nautilus_trader.data.messages import UnsubscribeQuoteTicks
+from nautilus_trader.data.messages import UnsubscribeTradeTicks
+from nautilus_trader.live.data_client import LiveMarketDataClient
+from nautilus_trader.model.enums import BookType
+from nautilus_trader.model.identifiers import ClientId
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.adapters.coinbase.config import CoinbaseDataClientConfig
+    from nautilus_trader.adapters.coinbase.providers import CoinbaseInstrumentProvider
+    from nautilus_trader.cache.cache import Cache
+    from nautilus_trader.common.component import LiveClock
+    from nautilus_trader.common.component import MessageBus
+    from nautilus_trader.model.instruments import Instrument
+
+
+class CoinbaseDataClient(LiveMarketDataClient):
+    """
+    Provides a data client for the Coinbase Advanced Trade spot exchange.
+
+    Order books are streamed from the `level2` channel, quotes from the `ticker`
+    channel (updated on each trade) and trades from the `market_trades` channel.
+
+    Messages are sequenced for the connection, so a gap in the sequence (a dropped
+    message) resubscribes the order books for new snapshots.
+
+    Parameters
+    ----------
+    loop : asyncio.AbstractEventLoop
+        The event loop for the client.
+    msgbus : MessageBus
+        The message bus for the client.
+    cache : Cache
+        The cache for the client.
+    clock : LiveClock
+        The clock for the client.
+    instrument_provider : CoinbaseInstrumentProvider
+        The instrument provider.
+    base_url_ws : str
+        The base URL for the WebSocket client.
+    config : CoinbaseDataClientConfig
+        The configuration for the client.
+    name : str, optional
+        The custom client ID.
+
+    """
+
+    def __init__(
+        self,
+        loop: asyncio.AbstractEventLoop,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+        instrument_provider: CoinbaseInstrumentProvider,
+        base_url_ws: str,
+        config: CoinbaseDataClientConfig,
+        name: str | None,
+    ) -> None:
+        super().__init__(
+            loop=loop,
+            client_id=ClientId(name or COINBASE_VENUE.value),
+            venue=COINBASE_VENUE,
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+            instrument_provider=instrument_provider,
+        )
+        self._instrument_provider: CoinbaseInstrumentProvider = instrument_provider
+
+        # Configuration
+        self._log.info(f"{config.update_instruments_interval_mins=}", LogColor.BLUE)
+
+        # WebSocket API
+        self._ws_client = CoinbaseWebSocketClient(
+            clock=clock,
+            base_url=base_url_ws,
+            handler=self._handle_ws_message,
+            handler_reconnect=self._reconnect,
+            loop=loop,
+        )
+
+        # WebSocket decoders
+        self._decoder_ws_msg_general = msgspec.json.Decoder(CoinbaseWsMessageGeneral)
+        self._decoder_ws_l2 = msgspec.json.Decoder(CoinbaseWsL2Msg)
+        self._decoder_ws_ticker = msgspec.json.Decoder(CoinbaseWsTickerMsg)
+        self._decoder_ws_market_trades = msgspec.json.Decoder(CoinbaseWsMarketTradesMsg)
+
+        # Last sequence number of the connection (to detect dropped messages)
+        self._last_sequence_num: int | None = None
+
+        self._update_instruments_interval_mins: int | None = config.update_instruments_interval_mins
+        self._update_instruments_task: asyncio.Task | None = None
+
+    async def _connect(self) -> None:
+        await self._instrument_provider.initialize()
+        self._send_all_instruments_to_data_engine()
+
+        if self._update_instruments_interval_mins:
+            self._update_instruments_task = self.create_task(
+                self._update_instruments(self._update_instruments_interval_mins),
+            )
+
+        await self._ws_client.connect()
+
+    async def _disconnect(self) -> None:
+        if self._update_instruments_task:
+            self._log.debug("Canceling task 'update_instruments'")
+            self._update_instruments_task.cancel()
+            self._update_instruments_task = None
+
+        await self._ws_client.disconnect()
+
+    async def _reconnect(self) -> None:
+        # Sequence numbers restart for the new connection, and book snapshots are resent
+        self._last_sequence_num = None
+
+    def _send_all_instruments_to_data_engine(self) -> None:
+        for instrument in self._instrument_provider.get_all().values():
+            self._handle_data(instrument)
+
+        for currency in self._instrument_provider.currencies().values():
+            self._cache.add_currency(currency)
+
+    async def _update_instruments(self, interval_mins: int) -> None:
+        try:
+            while True:
+                self._log.debug(
+                    f"Scheduled task 'update_instruments' to run in {interval_mins} minutes",
+                )
+                await asyncio.sleep(interval_mins * 60)
+                await self._instrument_provider.initialize(reload=True)
+                self._send_all_instruments_to_data_engine()
+        except asyncio.CancelledError:
+            self._log.debug("Canceled task 'update_instruments'")
+
+    async def _subscribe_order_book_deltas(self, command: SubscribeOrderBook) -> None:
+        if command.book_type != BookType.L2_MBP:
+            self._log.error(
+                f"Cannot subscribe to order book deltas: "
+                f"{command.book_type} data is not published by Coinbase. "
+                "Valid book types are L2_MBP",
+            )
+            return
+
+        await self._ws_client.subscribe_order_book(command.instrument_id.symbol.value)
+
+    async def _subscribe_quote_ticks(self, command: SubscribeQuoteTicks) -> None:
+        await self._ws_client.subscribe_ticker(command.instrument_id.symbol.value)
+
+    async def _subscribe_trade_ticks(self, command: SubscribeTradeTicks) -> None:
+        await self._ws_client.subscribe_trades(command.instrument_id.symbol.value)
+
+    async def _unsubscribe_order_book_deltas(self, command: UnsubscribeOrderBook) -> None:
+        await self._ws_client.unsubscribe_order_book(command.instrument_id.symbol.value)
+
+    async def _unsubscribe_quote_ticks(self, command: UnsubscribeQuoteTicks) -> None:
+        await self._ws_client.unsubscribe_ticker(command.instrument_id.symbol.value)
+
+    async def _unsubscribe_trade_ticks(self, command: UnsubscribeTradeTicks) -> None:
+        await self._ws_client.unsubscribe_trades(command.instrument_id.symbol.value)
+
+    async def _request_instrument(self, request: RequestInstrument) -> None:
+        if request.start is not None:
+            self._log.warning(
+                f"Requesting instrument {request.instrument_id} with specified `start` which has no effect",
+            )
+
+        if request.end is not None:
+            self._log.warning(
+                f"Requesting instrument {request.instrument_id} with specified `end` which has no effect",
+            )
+
+        instrument: Instrument | None = self._instrument_provider.find(request.instrument_id)
+        if instrument is None:
+            self._log.error(f"Cannot find instrument for {request.instrument_id}")
+            return
+
+        self._handle_instrument(instrument, request.id, request.params)
+
+    async def _request_instruments(self, request: RequestInstruments) -> None:
+        if request.start is not None:
+            self._log.warning(
+                f"Requesting instruments for {request.venue} with specified `start` which has no effect",
+            )
+
+        if request.end is not None:
+            self._log.warning(
+                f"Requesting instruments for {request.venue} with specified `end` which has no effect",
+            )
+
+        all_instruments = self._instrument_provider.get_all()
+        target_instruments = []
+        for instrument in all_instruments.values():
+            if instrument.venue == request.venue:
+                target_instruments.append(instrument)
+
+        self._handle_instruments(
+            request.venue,
+            target_instruments,
+            request.id,
+            request.params,
+        )
+
+    async def _request_quote_ticks(self, request: RequestQuoteTicks) -> None:
+        self._log.error("Cannot request historical quotes: not published by Coinbase")
+
+    async def _request_trade_ticks(self, request: RequestTradeTicks) -> None:
+        self._log.error("Cannot request historical trades: not yet implemented for Coinbase")
+
+    async def _request_bars(self, request: RequestBars) -> None:
+        self._log.error("Cannot request historical bars: not yet implemented for Coinbase")
+
+    def _get_cached_instrument(self, product_id: str) -> Instrument | None:
+        instrument_id = CoinbaseSymbol(product_id).to_instrument_id()
+        instrument = self._cache.instrument(instrument_id)
+        if instrument is None:
+            self._log.error(f"Cannot parse data: no instrument for {instrument_id}")
+        return instrument
+
+    def _handle_ws_message(self, raw: bytes) -> None:
+        try:
+            ws_message = self._decoder_ws_msg_general.decode(raw)
+            if ws_message.type == "error":
+                self._log.error(f"Received error: {ws_message.message}")
+                return
+
+            self._check_sequence(ws_message.sequence_num)
+
+            match ws_message.channel:
+                case "l2_data":
+                    self._handle_l2_data(raw)
+                case "ticker":
+                    self._handle_ticker(raw)
+                case "market_trades":
+                    self._handle_market_trades(raw)
+                case "heartbeats" | "subscriptions":
+                    pass
+                case _:
+                    self._log.debug(f"Unhandled websocket message: {raw.decode()}")
+        except Exception as e:
+            self._log.exception(f"Failed to handle websocket message {raw.decode()}", e)
+
+    def _check_sequence(self, sequence_num: int | None) -> None:
+        if sequence_num is None:
+            return
+
+        last_sequence_num = self._last_sequence_num
+        self._last_sequence_num = sequence_num
+        if last_sequence_num is None or sequence_num == last_sequence_num + 1:
+            return
+
+        self._log.warning(
+            f"Message sequence gap (expected {last_sequence_num + 1}, was {sequence_num}): "
+            "resubscribing order books",
+        )
+        self.create_task(self._resubscribe_order_books())
+
+    async def _resubscribe_order_books(self) -> None:
+        product_ids = sorted(self._ws_client.subscriptions.get("level2", set()))
+        for product_id in product_ids:
+            await self._ws_client.unsubscribe_order_book(product_id)
+            await self._ws_client.subscribe_order_book(product_id)
+
+    def _handle_l2_data(self, raw: bytes) -> None:
+        msg = self._decoder_ws_l2.decode(raw)
+        ts_event = parse_timestamp(msg.timestamp)
+        for event in msg.events:
+            instrument = self._get_cached_instrument(event.product_id)
+            if instrument is None:
+                continue
+
+            deltas = event.parse_to_deltas(
+                instrument,
+                sequence=msg.sequence_num,
+                ts_event=ts_event,
+                ts_init=self._clock.timestamp_ns(),
+            )
+            self._handle_data(deltas)
+
+    def _handle_ticker(self, raw: bytes) -> None:
+        msg = self._decoder_ws_ticker.decode(raw)
+        ts_event = parse_timestamp(msg.timestamp)
+        for event in msg.events:
+            for ticker in event.tickers:
+                instrument = self._get_cached_instrument(ticker.product_id)
+                if instrument is None:
+                    continue
+
+                quote = ticker.parse_to_quote_tick(
+                    instrument,
+                    ts_event=ts_event,
+                    ts_init=self._clock.timestamp_ns(),
+                )
+                if quote is None:
+                    continue  # No two-sided market
+
+                self._handle_data(quote)
+
+    def _handle_market_trades(self, raw: bytes) -> None:
+        msg = self._decoder_ws_market_trades.decode(raw)
+        for event in msg.events:
+            if event.type == "snapshot":
+                continue  # Recent trades on subscribing, which may already have been handled
+
+            for data in event.trades:
+                instrument = self._get_cached_instrument(data.product_id)
+                if instrument is None:
+                    continue
+
+                trade = data.parse_to_trade_tick(instrument, ts_init=self._clock.timestamp_ns())
+                self._handle_data(trade)
@@ -0,0 +1,925 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+import asyncio
+from decimal import Decimal
+from typing import TYPE_CHECKING
+
+import msgspec
+
+from nautilus_trader.adapters.coinbase.common.constants import COINBASE_VENUE
+from nautilus_trader.adapters.coinbase.common.enums import CoinbaseEnumParser
+from nautilus_trader.adapters.coinbase.common.enums import CoinbaseOrderStatus
+from nautilus_trader.adapters.coinbase.common.enums import CoinbaseStopDirection
+from nautilus_trader.adapters.coinbase.common.enums import CoinbaseTimeInForce
+from nautilus_trader.adapters.coinbase.common.parsing import format_timestamp
+from nautilus_trader.adapters.coinbase.common.parsing import parse_decimal
+from nautilus_trader.adapters.coinbase.common.parsing import parse_timestamp
+from nautilus_trader.adapters.coinbase.common.symbol import CoinbaseSymbol
+from nautilus_trader.adapters.coinbase.http.account import CoinbaseAccountHttpAPI
+from nautilus_trader.adapters.coinbase.http.errors import CoinbaseError
+from nautilus_trader.adapters.coinbase.http.errors import should_retry
+from nautilus_trader.adapters.coinbase.http.trade import ORDER_CONFIGURATION_TYPES
+from nautilus_trader.adapters.coinbase.http.trade import CoinbaseTradeHttpAPI
+from nautilus_trader.adapters.coinbase.http.trade import order_configuration
+from nautilus_trader.adapters.coinbase.schemas.account import parse_account_balances
+from nautilus_trader.adapters.coinbase.schemas.ws import CoinbaseWsMessageGeneral
+from nautilus_trader.adapters.coinbase.schemas.ws import CoinbaseWsUserMsg
+from nautilus_trader.adapters.coinbase.websocket.client import CoinbaseWebSocketClient
+from nautilus_trader.common.enums import LogColor
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.live.execution_client import LiveExecutionClient
+from nautilus_trader.live.retry import RetryManagerPool
+from nautilus_trader.model.enums import AccountType
+from nautilus_trader.model.enums import LiquiditySide
+from nautilus_trader.model.enums import OmsType
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import TimeInForce
+from nautilus_trader.model.enums import order_type_to_str
+from nautilus_trader.model.enums import time_in_force_to_str
+from nautilus_trader.model.identifiers import AccountId
+from nautilus_trader.model.identifiers import ClientId
+from nautilus_trader.model.identifiers import ClientOrderId
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import TradeId
+from nautilus_trader.model.identifiers import VenueOrderId
+from nautilus_trader.model.objects import Money
+from nautilus_trader.model.orders import Order
+
+
+if TYPE_CHECKING:
+    import pandas as pd
+
+    from nautilus_trader.adapters.coinbase.config import CoinbaseExecClientConfig
+    from nautilus_trader.adapters.coinbase.http.client import CoinbaseHttpClient
+    from nautilus_trader.adapters.coinbase.providers import CoinbaseInstrumentProvider
+    from nautilus_trader.adapters.coinbase.schemas.order import CoinbaseFill
+    from nautilus_trader.adapters.coinbase.schemas.order import CoinbaseOrder
+    from nautilus_trader.adapters.coinbase.schemas.ws import CoinbaseWsUserOrder
+    from nautilus_trader.cache.cache import Cache
+    from nautilus_trader.common.component import LiveClock
+    from nautilus_trader.common.component import MessageBus
+    from nautilus_trader.execution.messages import CancelAllOrders
+    from nautilus_trader.execution.messages import CancelOrder
+    from nautilus_trader.execution.messages import ModifyOrder
+    from nautilus_trader.execution.messages import SubmitOrder
+    from nautilus_trader.execution.reports import FillReport
+    from nautilus_trader.execution.reports import OrderStatusReport
+    from nautilus_trader.execution.reports import PositionStatusReport
+    from nautilus_trader.model.instruments import Instrument
+
+
+# Fills may be listed shortly after the order update, so are requested a few times
+# before the remaining fill is derived from the cumulative order totals
+_FILL_FETCH_ATTEMPTS = 3
+_FILL_FETCH_DELAY_SECS = 0.5
+
+
+class _OrderFills:
+    """
+    The fills processed for an order, to determine new fills from the order totals.
+    """
+
+    def __init__(self, order: Order) -> None:
+        self.trade_ids: set[str] = {trade_id.value for trade_id in order.trade_ids}
+        self.filled_qty: Decimal = order.filled_qty.as_decimal()
+        self.notional: Decimal = Decimal(str(order.avg_px or 0)) * self.filled_qty
+        self.fees: Decimal = sum(
+            (commission.as_decimal() for commission in order.commissions()),
+            Decimal(0),
+        )
+
+
+class CoinbaseExecutionClient(LiveExecutionClient):
+    """
+    Provides an execution client for the Coinbase Advanced Trade spot exchange.
+
+    Orders are placed with the Nautilus client order ID as the Coinbase
+    `client_order_id`, and order events are streamed on the authenticated `user`
+    channel. Order updates only report the cumulative filled quantity, so the fills
+    of an order are requested when its filled quantity increases (with the remaining
+    fill derived from the order totals if not yet listed).
+
+    Account balances are updated after fills and on the configured interval.
+
+    Parameters
+    ----------
+    loop : asyncio.AbstractEventLoop
+        The event loop for the client.
+    client : CoinbaseHttpClient
+        The Coinbase HTTP client (with API credentials).
+    msgbus : MessageBus
+        The message bus for the client.
+    cache : Cache
+        The cache for the client.
+    clock : LiveClock
+        The clock for the client.
+    instrument_provider : CoinbaseInstrumentProvider
+        The instrument provider.
+    base_url_ws : str
+        The base URL for the user WebSocket client.
+    config : CoinbaseExecClientConfig
+        The configuration for the client.
+    name : str, optional
+        The custom client ID.
+
+    """
+
+    def __init__(
+        self,
+        loop: asyncio.AbstractEventLoop,
+        client: CoinbaseHttpClient,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+        instrument_provider: CoinbaseInstrumentProvider,
+        base_url_ws: str,
+        config: CoinbaseExecClientConfig,
+        name: str | None,
+    ) -> None:
+        PyCondition.is_true(client.has_credentials, "client has no API credentials")
+
+        super().__init__(
+            loop=loop,
+            client_id=ClientId(name or COINBASE_VENUE.value),
+            venue=COINBASE_VENUE,
+            oms_type=OmsType.NETTING,
+            instrument_provider=instrument_provider,
+            account_type=AccountType.CASH,
+            base_currency=None,
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+        )
+
+        # Configuration
+        self._update_account_interval_secs = config.update_account_interval_secs
+        self._log.info(f"{config.update_account_interval_secs=}", LogColor.BLUE)
+        self._log.info(f"{config.max_retries=}", LogColor.BLUE)
+        self._log.info(f"{config.retry_delay=}", LogColor.BLUE)
+
+        self._enum_parser = CoinbaseEnumParser()
+
+        account_id = AccountId(f"{name or COINBASE_VENUE.value}-master")
+        self._set_account_id(account_id)
+
+        # HTTP API
+        self._http_account = CoinbaseAccountHttpAPI(client=client, clock=clock)
+        self._http_trade = CoinbaseTradeHttpAPI(client=client, clock=clock)
+
+        # WebSocket API
+        self._ws_client = CoinbaseWebSocketClient(
+            clock=clock,
+            base_url=base_url_ws,
+            handler=self._handle_ws_message,
+            handler_reconnect=None,
+            loop=loop,
+            signer=client.signer,
+        )
+
+        # Decoders
+        self._decoder_ws_msg_general = msgspec.json.Decoder(CoinbaseWsMessageGeneral)
+        self._decoder_ws_user = msgspec.json.Decoder(CoinbaseWsUserMsg)
+
+        # Order updates are processed in sequence, as fills are requested for updates
+        self._order_updates: asyncio.Queue[tuple[CoinbaseWsUserOrder, int]] = asyncio.Queue()
+        self._order_updates_task: asyncio.Task | None = None
+        self._update_account_task: asyncio.Task | None = None
+
+        # Hot caches
+        self._order_fills: dict[VenueOrderId, _OrderFills] = {}
+
+        self._retry_manager_pool = RetryManagerPool[None](
+            pool_size=100,
+            max_retries=config.max_retries or 0,
+            retry_delay_secs=config.retry_delay or 0.0,
+            logger=self._log,
+            exc_types=(CoinbaseError,),
+            retry_check=should_retry,
+        )
+
+    async def _connect(self) -> None:
+        await self._instrument_provider.initialize()
+        await self._update_account_state()
+
+        self._order_updates_task = self.create_task(self._process_order_updates())
+        if self._update_account_interval_secs:
+            self._update_account_task = self.create_task(
+                self._update_account(self._update_account_interval_secs),
+            )
+
+        await self._ws_client.connect()
+        await self._ws_client.subscribe_user()
+
+    async def _disconnect(self) -> None:
+        if self._update_account_task:
+            self._log.debug("Canceling task 'update_account'")
+            self._update_account_task.cancel()
+            self._update_account_task = None
+
+        if self._order_updates_task:
+            self._log.debug("Canceling task 'process_order_updates'")
+            self._order_updates_task.cancel()
+            self._order_updates_task = None
+
+        await self._ws_client.disconnect()
+
+    def _stop(self) -> None:
+        self._retry_manager_pool.shutdown()
+
+    # -- EXECUTION REPORTS ------------------------------------------------------------------------
+
+    async def generate_order_status_reports(
+        self,
+        instrument_id: InstrumentId | None = None,
+        start: pd.Timestamp | None = None,
+        end: pd.Timestamp | None = None,
+        open_only: bool = False,
+    ) -> list[OrderStatusReport]:
+        self._log.debug("Requesting OrderStatusReports...")
+        reports: list[OrderStatusReport] = []
+
+        try:
+            coinbase_orders = await self._http_trade.fetch_orders(
+                product_ids=[instrument_id.symbol.value] if instrument_id is not None else None,
+                order_status=[CoinbaseOrderStatus.OPEN] if open_only else None,
+                start_date=format_timestamp(start.value) if start is not None else None,
+                end_date=format_timestamp(end.value) if end is not None else None,
+            )
+            for coinbase_order in coinbase_orders:
+                report = self._parse_order_status_report(coinbase_order)
+                if report is None:
+                    continue
+                reports.append(report)
+                self._log.debug(f"Received {report}", LogColor.MAGENTA)
+        except CoinbaseError as e:
+            self._log.error(f"Failed to generate OrderStatusReports: {e}")
+
+        len_reports = len(reports)
+        plural = "" if len_reports == 1 else "s"
+        self._log.info(f"Received {len(reports)} OrderStatusReport{plural}")
+
+        return reports
+
+    async def generate_order_status_report(
+        self,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId | None = None,
+        venue_order_id: VenueOrderId | None = None,
+    ) -> OrderStatusReport | None:
+        PyCondition.is_false(
+            client_order_id is None and venue_order_id is None,
+            "both `client_order_id` and `venue_order_id` were `None`",
+        )
+
+        self._log.info(
+            f"Generating OrderStatusReport for "
+            f"{repr(client_order_id) if client_order_id else ''} "
+            f"{repr(venue_order_id) if venue_order_id else ''}",
+        )
+
+        if venue_order_id is None and client_order_id is not None:
+            venue_order_id = self._cache.venue_order_id(client_order_id)
+
+        try:
+            if venue_order_id is not None:
+                coinbase_order = await self._http_trade.fetch_order(venue_order_id.value)
+            else:
+                # Orders without a venue order ID can only be found while open
+                open_orders = await self._http_trade.fetch_orders(
+                    product_ids=[instrument_id.symbol.value],
+                    order_status=[CoinbaseOrderStatus.OPEN],
+                )
+                assert client_order_id is not None  # Type checking
+                matches = [o for o in open_orders if o.client_order_id == client_order_id.value]
+                if not matches:
+                    self._log.warning(f"No order found for {client_order_id!r}")
+                    return None
+                coinbase_order = matches[0]
+
+            report = self._parse_order_status_report(coinbase_order)
+            if report is not None:
+                self._log.debug(f"Received {report}", LogColor.MAGENTA)
+            return report
+        except CoinbaseError as e:
+            self._log.error(f"Failed to generate OrderStatusReport: {e}")
+        return None
+
+    async def generate_fill_reports(
+        self,
+        instrument_id: InstrumentId | None = None,
+        venue_order_id: VenueOrderId | None = None,
+        start: pd.Timestamp | None = None,
+        end: pd.Timestamp | None = None,
+    ) -> list[FillReport]:
+        self._log.debug("Requesting FillReports...")
+        reports: list[FillReport] = []
+
+        try:
+            fills = await self._http_trade.fetch_fills(
+                order_ids=[venue_order_id.value] if venue_order_id is not None else None,
+                product_ids=[instrument_id.symbol.value] if instrument_id is not None else None,
+                start_sequence_timestamp=format_timestamp(start.value) if start is not None else None,
+                end_sequence_timestamp=format_timestamp(end.value) if end is not None else None,
+            )
+            for fill in fills:
+                instrument = self._get_cached_instrument(fill.product_id)
+                if instrument is None:
+                    continue
+
+                report = fill.parse_to_fill_report(
+                    account_id=self.account_id,
+                    instrument=instrument,
+                    client_order_id=self._cache.client_order_id(VenueOrderId(fill.order_id)),
+                    report_id=UUID4(),
+                    enum_parser=self._enum_parser,
+                    ts_init=self._clock.timestamp_ns(),
+                )
+                reports.append(report)
+                self._log.debug(f"Received {report}")
+        except CoinbaseError as e:
+            self._log.error(f"Failed to generate FillReports: {e}")
+
+        len_reports = len(reports)
+        plural = "" if len_reports == 1 else "s"
+        self._log.info(f"Received {len(reports)} FillReport{plural}")
+
+        return reports
+
+    async def generate_position_status_reports(
+        self,
+        instrument_id: InstrumentId | None = None,
+        start: pd.Timestamp | None = None,
+        end: pd.Timestamp | None = None,
+    ) -> list[PositionStatusReport]:
+        # Spot holdings are reported as account balances rather than positions
+        self._log.info("Received 0 PositionReports (spot holdings are account balances)")
+        return []
+
+    def _parse_order_status_report(self, coinbase_order: CoinbaseOrder) -> OrderStatusReport | None:
+        instrument = self._get_cached_instrument(coinbase_order.product_id)
+        if instrument is None:
+            return None
+
+        return coinbase_order.parse_to_order_status_report(
+            account_id=self.account_id,
+            instrument=instrument,
+            client_order_id=ClientOrderId(coinbase_order.client_order_id),
+            report_id=UUID4(),
+            enum_parser=self._enum_parser,
+            ts_init=self._clock.timestamp_ns(),
+        )
+
+    def _get_cached_instrument(self, product_id: str) -> Instrument | None:
+        instrument_id = CoinbaseSymbol(product_id).to_instrument_id()
+        instrument = self._cache.instrument(instrument_id)
+        if instrument is None:
+            self._log.debug(f"No instrument found for {instrument_id}")
+        return instrument
+
+    async def _update_account(self, interval_secs: int) -> None:
+        try:
+            while True:
+                await asyncio.sleep(interval_secs)
+                await self._update_account_state()
+        except asyncio.CancelledError:
+            self._log.debug("Canceled task 'update_account'")
+
+    async def _update_account_state(self) -> None:
+        try:
+            accounts = await self._http_account.fetch_accounts()
+            balances = parse_account_balances(accounts)
+            if not balances:
+                self._log.warning("No balances for account state")
+                return
+
+            self.generate_account_state(
+                balances=balances,
+                margins=[],
+                reported=True,
+                ts_event=self._clock.timestamp_ns(),
+            )
+        except Exception as e:
+            self._log.error(f"Failed to generate AccountState: {e}")
+
+    # -- COMMAND HANDLERS -------------------------------------------------------------------------
+
+    async def _cancel_order(self, command: CancelOrder) -> None:
+        order: Order | None = self._cache.order(command.client_order_id)
+        if order is None:
+            self._log.error(f"{command.client_order_id!r} not found in cache")
+            return
+
+        if order.is_closed:
+            self._log.warning(
+                f"`CancelOrder` command for {command.client_order_id!r} when order already {order.status_string()} "
+                "(will not send to exchange)",
+            )
+            return
+
+        # Orders can only be canceled by venue order ID
+        venue_order_id = command.venue_order_id or order.venue_order_id
+        if venue_order_id is None:
+            self.generate_order_cancel_rejected(
+                order.strategy_id,
+                order.instrument_id,
+                order.client_order_id,
+                None,
+                "no venue order ID (order not yet accepted)",
+                self._clock.timestamp_ns(),
+            )
+            return
+
+        await self._cancel_orders([order], [venue_order_id])
+
+    async def _cancel_all_orders(self, command: CancelAllOrders) -> None:
+        orders_open = [
+            order
+            for order in self._cache.orders_open(instrument_id=command.instrument_id)
+            if command.order_side in (OrderSide.NO_ORDER_SIDE, order.side)
+            and order.venue_order_id is not None
+        ]
+        if not orders_open:
+            self._log.info(f"No open orders to cancel for {command.instrument_id}")
+            return
+
+        await self._cancel_orders(
+            orders_open,
+            [order.venue_order_id for order in orders_open],  # type: ignore
+        )
+
+    async def _cancel_orders(self, orders: list[Order], venue_order_ids: list[VenueOrderId]) -> None:
+        # Orders are canceled in batches, where each order has its own result
+        async with self._retry_manager_pool as retry_manager:
+            await retry_manager.run(
+                "cancel_orders",
+                [order.client_order_id for order in orders],
+                self._cancel_orders_inner,
+                orders,
+                venue_order_ids,
+            )
+            if not retry_manager.result:
+                for order in orders:
+                    self.generate_order_cancel_rejected(
+                        order.strategy_id,
+                        order.instrument_id,
+                        order.client_order_id,
+                        order.venue_order_id,
+                        retry_manager.message,
+                        self._clock.timestamp_ns(),
+                    )
+
+    async def _cancel_orders_inner(
+        self,
+        orders: list[Order],
+        venue_order_ids: list[VenueOrderId],
+    ) -> None:
+        results = await self._http_trade.cancel_orders([v.value for v in venue_order_ids])
+        failures = {r.order_id: r.failure_reason for r in results if not r.success}
+        for order, venue_order_id in zip(orders, venue_order_ids, strict=True):
+            if venue_order_id.value not in failures:
+                continue  # The order is canceled from the `user` channel
+            self.generate_order_cancel_rejected(
+                order.strategy_id,
+                order.instrument_id,
+                order.client_order_id,
+                venue_order_id,
+                failures[venue_order_id.value] or "Cancel failed",
+                self._clock.timestamp_ns(),
+            )
+
+    async def _modify_order(self, command: ModifyOrder) -> None:
+        order: Order | None = self._cache.order(command.client_order_id)
+        if order is None:
+            self._log.error(f"{command.client_order_id!r} not found in cache")
+            return
+
+        if order.is_closed:
+            self._log.warning(
+                f"`ModifyOrder` command for {command.client_order_id!r} when order already {order.status_string()} "
+                "(will not send to exchange)",
+            )
+            return
+
+        reason = None
+        venue_order_id = command.venue_order_id or order.venue_order_id
+        if order.order_type != OrderType.LIMIT or order.time_in_force != TimeInForce.GTC:
+            reason = "only GTC limit orders can be modified"
+        elif command.trigger_price is not None:
+            reason = "trigger price cannot be modified"
+        elif venue_order_id is None:
+            reason = "no venue order ID (order not yet accepted)"
+
+        if reason is not None:
+            self.generate_order_modify_rejected(
+                order.strategy_id,
+                order.instrument_id,
+                order.client_order_id,
+                order.venue_order_id,
+                reason,
+                self._clock.timestamp_ns(),
+            )
+            return
+
+        assert venue_order_id is not None  # Type checking
+        price = command.price or order.price
+        quantity = command.quantity or order.quantity
+
+        async with self._retry_manager_pool as retry_manager:
+            await retry_manager.run(
+                "modify_order",
+                [order.client_order_id, venue_order_id],
+                self._http_trade.edit_order,
+                order_id=venue_order_id.value,
+                price=str(price),
+                size=str(quantity),
+            )
+            if not retry_manager.result:
+                self.generate_order_modify_rejected(
+                    order.strategy_id,
+                    order.instrument_id,
+                    order.client_order_id,
+                    order.venue_order_id,
+                    retry_manager.message,
+                    self._clock.timestamp_ns(),
+                )
+                return
+
+        # Edits are not reported on the `user` channel, so the order is updated on success
+        response = retry_manager.result
+        if not response.success:
+            self.generate_order_modify_rejected(
+                order.strategy_id,
+                order.instrument_id,
+                order.client_order_id,
+                venue_order_id,
+                response.reason,
+                self._clock.timestamp_ns(),
+            )
+            return
+
+        self.generate_order_updated(
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=order.client_order_id,
+            venue_order_id=venue_order_id,
+            quantity=quantity,
+            price=price,
+            trigger_price=None,
+            ts_event=self._clock.timestamp_ns(),
+        )
+
+    async def _submit_order(self, command: SubmitOrder) -> None:
+        order = command.order
+        if order.is_closed:
+            self._log.warning(f"Order {order} is already closed")
+            return
+
+        if not self._check_order_validity(order):
+            return
+
+        # Generate order submitted event, to ensure correct ordering of event
+        self.generate_order_submitted(
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=order.client_order_id,
+            ts_event=self._clock.timestamp_ns(),
+        )
+
+        async with self._retry_manager_pool as retry_manager:
+            await retry_manager.run(
+                "submit_order",
+                [order.client_order_id],
+                self._submit_order_inner,
+                order,
+            )
+            if not retry_manager.result:
+                self.generate_order_rejected(
+                    strategy_id=order.strategy_id,
+                    instrument_id=order.instrument_id,
+                    client_order_id=order.client_order_id,
+                    reason=retry_manager.message,
+                    ts_event=self._clock.timestamp_ns(),
+                )
+
+    def _check_order_validity(self, order: Order) -> bool:
+        if order.order_type not in (OrderType.MARKET, OrderType.LIMIT, OrderType.STOP_LIMIT):
+            self._log.error(
+                f"Cannot submit {order}: {order_type_to_str(order.order_type)} orders "
+                "not supported for Coinbase",
+            )
+            return False
+
+        if order.is_quote_quantity:
+            self._log.error(f"Cannot submit {order}: quote quantity not supported for Coinbase")
+            return False
+
+        if order.is_reduce_only:
+            self._log.error(f"Cannot submit {order}: reduce only not supported for Coinbase spot")
+            return False
+
+        if order.order_type != OrderType.MARKET:
+            time_in_force = self._enum_parser.nautilus_to_coinbase_time_in_force.get(
+                order.time_in_force,
+            )
+            if (order.order_type, time_in_force) not in ORDER_CONFIGURATION_TYPES:
+                self._log.error(
+                    f"Cannot submit {order}: time in force "
+                    f"{time_in_force_to_str(order.time_in_force)} unsupported on Coinbase "
+                    f"for {order_type_to_str(order.order_type)} orders",
+                )
+                return False
+
+        if order.is_post_only and (
+            order.order_type != OrderType.LIMIT
+            or order.time_in_force not in (TimeInForce.GTC, TimeInForce.GTD)
+        ):
+            self._log.error(
+                f"Cannot submit {order} has invalid post only {order.is_post_only}, unsupported on Coinbase",
+            )
+            return False
+
+        if self._cache.instrument(order.instrument_id) is None:
+            self._log.error(f"Cannot submit {order}: instrument {order.instrument_id} not found")
+            return False
+
+        return True
+
+    async def _submit_order_inner(self, order: Order) -> None:
+        time_in_force = CoinbaseTimeInForce.GTC
+        if order.order_type != OrderType.MARKET:
+            time_in_force = self._enum_parser.parse_nautilus_time_in_force(order.time_in_force)
+
+        # Buy stops trigger as the price rises, and sell stops as the price falls
+        stop_direction = None
+        if order.order_type == OrderType.STOP_LIMIT:
+            stop_direction = (
+                CoinbaseStopDirection.UP if order.side == OrderSide.BUY else CoinbaseStopDirection.DOWN
+            )
+
+        configuration = order_configuration(
+            order_type=order.order_type,
+            base_size=str(order.quantity),
+            time_in_force=time_in_force,
+            limit_price=str(order.price) if order.has_price else None,
+            stop_price=str(order.trigger_price) if order.has_trigger_price else None,
+            stop_direction=stop_direction,
+            end_time=(
+                format_timestamp(order.expire_time_ns)
+                if time_in_force == CoinbaseTimeInForce.GTD
+                else None
+            ),
+            post_only=order.is_post_only,
+        )
+        response = await self._http_trade.create_order(
+            client_order_id=order.client_order_id.value,
+            product_id=order.instrument_id.symbol.value,
+            side=self._enum_parser.parse_nautilus_order_side(order.side),
+            configuration=configuration,
+        )
+
+        # The `user` channel may have already accepted the order
+        current_order = self._cache.order(order.client_order_id)
+        if current_order is None or current_order.status != OrderStatus.SUBMITTED:
+            return
+
+        if not response.success or response.success_response is None:
+            self.generate_order_rejected(
+                strategy_id=order.strategy_id,
+                instrument_id=order.instrument_id,
+                client_order_id=order.client_order_id,
+                reason=response.reason,
+                ts_event=self._clock.timestamp_ns(),
+            )
+        else:
+            self.generate_order_accepted(
+                strategy_id=order.strategy_id,
+                instrument_id=order.instrument_id,
+                client_order_id=order.client_order_id,
+                venue_order_id=VenueOrderId(response.success_response.order_id),
+                ts_event=self._clock.timestamp_ns(),
+            )
+
+    # -- WEBSOCKET HANDLERS -----------------------------------------------------------------------
+
+    def _handle_ws_message(self, raw: bytes) -> None:
+        try:
+            msg = self._decoder_ws_msg_general.decode(raw)
+            if msg.type == "error":
+                self._log.error(f"Received error: {msg.message}")
+                return
+
+            if msg.channel != "user":
+                return
+
+            user_msg = self._decoder_ws_user.decode(raw)
+            ts_event = parse_timestamp(user_msg.timestamp)
+            for event in user_msg.events:
+                for update in event.orders:
+                    self._order_updates.put_nowait((update, ts_event))
+        except Exception as e:
+            self._log.exception(f"Failed to handle websocket message: {raw.decode()}", e)
+
+    async def _process_order_updates(self) -> None:
+        try:
+            while True:
+                update, ts_event = await self._order_updates.get()
+                try:
+                    await self._handle_order_update(update, ts_event)
+                except Exception as e:
+                    self._log.exception(f"Failed to handle order update {update}", e)
+        except asyncio.CancelledError:
+            self._log.debug("Canceled task 'process_order_updates'")
+
+    async def _handle_order_update(self, update: CoinbaseWsUserOrder, ts_event: int) -> None:
+        order = self._cache.order(ClientOrderId(update.client_order_id))
+        if order is None:
+            self._log.debug(
+                f"Cannot find {update.client_order_id!r} (most likely an external order)",
+            )
+            return
+
+        instrument = self._get_cached_instrument(update.product_id)
+        if instrument is None:
+            raise ValueError(f"Cannot handle order: instrument for {update.product_id} not found")
+
+        venue_order_id = VenueOrderId(update.order_id)
+
+        if order.status == OrderStatus.SUBMITTED:
+            if update.status == CoinbaseOrderStatus.FAILED:
+                self.generate_order_rejected(
+                    strategy_id=order.strategy_id,
+                    instrument_id=order.instrument_id,
+                    client_order_id=order.client_order_id,
+                    reason=update.reject_reason or "Rejected",
+                    ts_event=ts_event,
+                )
+                return
+
+            self.generate_order_accepted(
+                strategy_id=order.strategy_id,
+                instrument_id=order.instrument_id,
+                client_order_id=order.client_order_id,
+                venue_order_id=venue_order_id,
+                ts_event=ts_event,
+            )
+
+        if venue_order_id not in self._order_fills:
+            self._order_fills[venue_order_id] = _OrderFills(order)
+
+        order_fills = self._order_fills[venue_order_id]
+        if update.filled_qty > order_fills.filled_qty:
+            await self._handle_fills(order, instrument, update, order_fills)
+            await self._update_account_state()
+
+        match update.status:
+            case CoinbaseOrderStatus.CANCELLED:
+                if not order.is_closed:
+                    self.generate_order_canceled(
+                        strategy_id=order.strategy_id,
+                        instrument_id=order.instrument_id,
+                        client_order_id=order.client_order_id,
+                        venue_order_id=venue_order_id,
+                        ts_event=ts_event,
+                    )
+            case CoinbaseOrderStatus.EXPIRED:
+                if not order.is_closed:
+                    self.generate_order_expired(
+                        strategy_id=order.strategy_id,
+                        instrument_id=order.instrument_id,
+                        client_order_id=order.client_order_id,
+                        venue_order_id=venue_order_id,
+                        ts_event=ts_event,
+                    )
+            case _:
+                pass  # Fills are handled from the filled quantity
+
+        if update.status in (
+            CoinbaseOrderStatus.FILLED,
+            CoinbaseOrderStatus.CANCELLED,
+            CoinbaseOrderStatus.EXPIRED,
+            CoinbaseOrderStatus.FAILED,
+        ):
+            self._order_fills.pop(venue_order_id, None)
+
+    async def _handle_fills(
+        self,
+        order: Order,
+        instrument: Instrument,
+        update: CoinbaseWsUserOrder,
+        order_fills: _OrderFills,
+    ) -> None:
+        for attempt in range(_FILL_FETCH_ATTEMPTS):
+            if attempt > 0:
+                await asyncio.sleep(_FILL_FETCH_DELAY_SECS)
+
+            try:
+                fills = await self._http_trade.fetch_fills(order_ids=[update.order_id])
+            except CoinbaseError as e:
+                self._log.warning(f"Failed to fetch fills for {order.client_order_id!r}: {e}")
+                continue
+
+            for fill in sorted(fills, key=lambda f: f.trade_time):
+                if fill.trade_id not in order_fills.trade_ids:
+                    self._handle_fill(order, instrument, fill, order_fills)
+
+            if order_fills.filled_qty >= update.filled_qty:
+                return
+
+        self._handle_derived_fill(order, instrument, update, order_fills)
+
+    def _handle_fill(
+        self,
+        order: Order,
+        instrument: Instrument,
+        fill: CoinbaseFill,
+        order_fills: _OrderFills,
+    ) -> None:
+        last_qty = instrument.make_qty(fill.base_size)
+        last_px = instrument.make_price(Decimal(fill.price))
+        commission = Decimal(fill.commission or 0)
+
+        order_fills.trade_ids.add(fill.trade_id)
+        order_fills.filled_qty += last_qty.as_decimal()
+        order_fills.notional += last_qty.as_decimal() * last_px.as_decimal()
+        order_fills.fees += commission
+
+        self.generate_order_filled(
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=order.client_order_id,
+            venue_order_id=VenueOrderId(fill.order_id),
+            venue_position_id=None,
+            trade_id=TradeId(fill.trade_id),
+            order_side=order.side,
+            order_type=order.order_type,
+            last_qty=last_qty,
+            last_px=last_px,
+            quote_currency=instrument.quote_currency,
+            commission=Money(commission, instrument.quote_currency),
+            liquidity_side=self._enum_parser.parse_coinbase_liquidity_side(
+                fill.liquidity_indicator,
+            ),
+            ts_event=fill.ts_event,
+        )
+
+    def _handle_derived_fill(
+        self,
+        order: Order,
+        instrument: Instrument,
+        update: CoinbaseWsUserOrder,
+        order_fills: _OrderFills,
+    ) -> None:
+        # The fill is derived from the change in the cumulative totals of the order
+        filled_qty = update.filled_qty
+        last_qty = filled_qty - order_fills.filled_qty
+        avg_px = parse_decimal(update.avg_price) or Decimal(0)
+        notional = avg_px * filled_qty
+        last_px = (notional - order_fills.notional) / last_qty
+        fees = parse_decimal(update.total_fees) or Decimal(0)
+        commission = max(fees - order_fills.fees, Decimal(0))
+
+        self._log.warning(
+            f"Fills for {order.client_order_id!r} not yet listed, "
+            f"deriving fill of {last_qty} from the order totals",
+        )
+
+        trade_id = f"{update.order_id}-{filled_qty.normalize():f}"
+        order_fills.trade_ids.add(trade_id)
+        order_fills.filled_qty = filled_qty
+        order_fills.notional = notional
+        order_fills.fees = max(fees, order_fills.fees)
+
+        self.generate_order_filled(
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=order.client_order_id,
+            venue_order_id=VenueOrderId(update.order_id),
+            venue_position_id=None,
+            trade_id=TradeId(trade_id),
+            order_side=order.side,
+            order_type=order.order_type,
+            last_qty=instrument.make_qty(last_qty),
+            last_px=instrument.make_price(last_px),
+            quote_currency=instrument.quote_currency,
+            commission=Money(commission, instrument.quote_currency),
+            liquidity_side=LiquiditySide.NO_LIQUIDITY_SIDE,
+            ts_event=self._clock.timestamp_ns(),
+        )
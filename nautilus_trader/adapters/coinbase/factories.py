@@ -0,0 +1,240 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import asyncio
+from functools import lru_cache
+
+from nautilus_trader.adapters.coinbase.common.constants import COINBASE_PRIVATE_RATE_LIMIT_KEY
+from nautilus_trader.adapters.coinbase.common.constants import COINBASE_PUBLIC_RATE_LIMIT_KEY
+from nautilus_trader.adapters.coinbase.common.credentials import get_api_key
+from nautilus_trader.adapters.coinbase.common.credentials import get_api_secret
+from nautilus_trader.adapters.coinbase.common.urls import get_http_base_url
+from nautilus_trader.adapters.coinbase.common.urls import get_ws_market_data_url
+from nautilus_trader.adapters.coinbase.common.urls import get_ws_user_url
+from nautilus_trader.adapters.coinbase.config import CoinbaseDataClientConfig
+from nautilus_trader.adapters.coinbase.config import CoinbaseExecClientConfig
+from nautilus_trader.adapters.coinbase.data import CoinbaseDataClient
+from nautilus_trader.adapters.coinbase.execution import CoinbaseExecutionClient
+from nautilus_trader.adapters.coinbase.http.client import CoinbaseHttpClient
+from nautilus_trader.adapters.coinbase.providers import CoinbaseInstrumentProvider
+from nautilus_trader.cache.cache import Cache
+from nautilus_trader.common.component import LiveClock
+from nautilus_trader.common.component import MessageBus
+from nautilus_trader.config import InstrumentProviderConfig
+from nautilus_trader.core.nautilus_pyo3 import Quota
+from nautilus_trader.live.factories import LiveDataClientFactory
+from nautilus_trader.live.factories import LiveExecClientFactory
+
+
+@lru_cache(4)
+def get_cached_coinbase_http_client(
+    clock: LiveClock,
+    api_key: str | None = None,
+    api_secret: str | None = None,
+    base_url: str | None = None,
+) -> CoinbaseHttpClient:
+    """
+    Cache and return a Coinbase HTTP client.
+
+    If a cached client with matching parameters already exists, the cached client will be returned.
+
+    Parameters
+    ----------
+    clock : LiveClock
+        The clock for the client.
+    api_key : str, optional
+        The API key name for the client (``None`` for public only access).
+    api_secret : str, optional
+        The Ed25519 API secret for the client.
+    base_url : str, optional
+        The base URL for the API endpoints.
+
+    Returns
+    -------
+    CoinbaseHttpClient
+
+    """
+    base_url = base_url or get_http_base_url()
+
+    # Setup rate limit quotas
+    # https://docs.cdp.coinbase.com/advanced-trade/docs/rest-api-rate-limits
+    # Public endpoints are limited to 10 requests per second and private endpoints
+    # to 30 requests per second (per IP and per API key respectively).
+    ratelimiter_default_quota = Quota.rate_per_second(10)
+    ratelimiter_quotas: list[tuple[str, Quota]] = [
+        (COINBASE_PUBLIC_RATE_LIMIT_KEY, Quota.rate_per_second(10)),
+        (COINBASE_PRIVATE_RATE_LIMIT_KEY, Quota.rate_per_second(30)),
+    ]
+
+    return CoinbaseHttpClient(
+        clock=clock,
+        base_url=base_url,
+        api_key=api_key,
+        api_secret=api_secret,
+        ratelimiter_quotas=ratelimiter_quotas,
+        ratelimiter_default_quota=ratelimiter_default_quota,
+    )
+
+
+@lru_cache(1)
+def get_cached_coinbase_instrument_provider(
+    client: CoinbaseHttpClient,
+    clock: LiveClock,
+    config: InstrumentProviderConfig,
+) -> CoinbaseInstrumentProvider:
+    """
+    Cache and return a Coinbase instrument provider.
+
+    If a cached provider already exists, then that provider will be returned.
+
+    Parameters
+    ----------
+    client : CoinbaseHttpClient
+        The Coinbase HTTP client.
+    clock : LiveClock
+        The clock instance.
+    config : InstrumentProviderConfig
+        The instrument provider configuration.
+
+    Returns
+    -------
+    CoinbaseInstrumentProvider
+
+    """
+    return CoinbaseInstrumentProvider(
+        client=client,
+        clock=clock,
+        config=config,
+    )
+
+
+class CoinbaseLiveDataClientFactory(LiveDataClientFactory):
+    """
+    Provides a Coinbase live data client factory.
+    """
+
+    @staticmethod
+    def create(  # type: ignore
+        loop: asyncio.AbstractEventLoop,
+        name: str,
+        config: CoinbaseDataClientConfig,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+    ) -> CoinbaseDataClient:
+        """
+        Create a new Coinbase data client.
+
+        Parameters
+        ----------
+        loop : asyncio.AbstractEventLoop
+            The event loop for the client.
+        name : str
+            The custom client ID.
+        config : CoinbaseDataClientConfig
+            The client configuration.
+        msgbus : MessageBus
+            The message bus for the client.
+        cache : Cache
+            The cache for the client.
+        clock: LiveClock
+            The clock for the instrument provider.
+
+        Returns
+        -------
+        CoinbaseDataClient
+
+        """
+        # Market data is public, so no credentials are required
+        client: CoinbaseHttpClient = get_cached_coinbase_http_client(
+            clock=clock,
+            base_url=config.base_url_http,
+        )
+        provider = get_cached_coinbase_instrument_provider(
+            client=client,
+            clock=clock,
+            config=config.instrument_provider,
+        )
+        return CoinbaseDataClient(
+            loop=loop,
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+            instrument_provider=provider,
+            base_url_ws=config.base_url_ws or get_ws_market_data_url(),
+            config=config,
+            name=name,
+        )
+
+
+class CoinbaseLiveExecClientFactory(LiveExecClientFactory):
+    """
+    Provides a Coinbase live execution client factory.
+    """
+
+    @staticmethod
+    def create(  # type: ignore
+        loop: asyncio.AbstractEventLoop,
+        name: str,
+        config: CoinbaseExecClientConfig,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+    ) -> CoinbaseExecutionClient:
+        """
+        Create a new Coinbase execution client.
+
+        Parameters
+        ----------
+        loop : asyncio.AbstractEventLoop
+            The event loop for the client.
+        name : str
+            The custom client ID.
+        config : CoinbaseExecClientConfig
+            The client configuration.
+        msgbus : MessageBus
+            The message bus for the client.
+        cache : Cache
+            The cache for the client.
+        clock : LiveClock
+            The clock for the client.
+
+        Returns
+        -------
+        CoinbaseExecutionClient
+
+        """
+        client: CoinbaseHttpClient = get_cached_coinbase_http_client(
+            clock=clock,
+            api_key=config.api_key or get_api_key(),
+            api_secret=config.api_secret or get_api_secret(),
+            base_url=config.base_url_http,
+        )
+        provider = get_cached_coinbase_instrument_provider(
+            client=client,
+            clock=clock,
+            config=config.instrument_provider,
+        )
+        return CoinbaseExecutionClient(
+            loop=loop,
+            client=client,
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+            instrument_provider=provider,
+            base_url_ws=config.base_url_ws or get_ws_user_url(),
+            config=config,
+            name=name,
+        )
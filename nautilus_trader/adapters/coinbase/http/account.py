@@ -0,0 +1,86 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+from typing import Any
+
+import msgspec
+
+from nautilus_trader.adapters.coinbase.common.constants import COINBASE_PRIVATE_RATE_LIMIT_KEY
+from nautilus_trader.adapters.coinbase.schemas.account import CoinbaseAccount
+from nautilus_trader.adapters.coinbase.schemas.account import CoinbaseAccounts
+from nautilus_trader.adapters.coinbase.schemas.account import CoinbaseTransactionSummary
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.core.nautilus_pyo3 import HttpMethod
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.adapters.coinbase.http.client import CoinbaseHttpClient
+    from nautilus_trader.common.component import LiveClock
+
+
+class CoinbaseAccountHttpAPI:
+    """
+    Provides access to the private Coinbase Advanced Trade account endpoints.
+
+    Parameters
+    ----------
+    client : CoinbaseHttpClient
+        The Coinbase HTTP client (with API credentials).
+    clock : LiveClock
+        The clock for the API client.
+
+    """
+
+    def __init__(
+        self,
+        client: CoinbaseHttpClient,
+        clock: LiveClock,
+    ) -> None:
+        PyCondition.not_none(client, "client")
+        self.client = client
+        self._clock = clock
+
+        self._decoder_accounts = msgspec.json.Decoder(CoinbaseAccounts)
+        self._decoder_transaction_summary = msgspec.json.Decoder(CoinbaseTransactionSummary)
+
+    async def fetch_accounts(self) -> list[CoinbaseAccount]:
+        # Accounts are paginated by cursor, with at most 250 accounts per page
+        accounts: list[CoinbaseAccount] = []
+        params: dict[str, Any] = {"limit": 250}
+        while True:
+            raw = await self.client.send_request(
+                HttpMethod.GET,
+                "accounts",
+                params,
+                signed=True,
+                ratelimiter_keys=[COINBASE_PRIVATE_RATE_LIMIT_KEY],
+            )
+            page = self._decoder_accounts.decode(raw)
+            accounts += page.accounts
+            if not page.has_next or not page.cursor:
+                return accounts
+            params["cursor"] = page.cursor
+
+    async def fetch_transaction_summary(self) -> CoinbaseTransactionSummary:
+        raw = await self.client.send_request(
+            HttpMethod.GET,
+            "transaction_summary",
+            signed=True,
+            ratelimiter_keys=[COINBASE_PRIVATE_RATE_LIMIT_KEY],
+        )
+        return self._decoder_transaction_summary.decode(raw)
@@ -0,0 +1,211 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from typing import Any
+from urllib import parse
+
+import msgspec
+
+import nautilus_trader
+from nautilus_trader.adapters.coinbase.common.auth import CoinbaseJwtSigner
+from nautilus_trader.adapters.coinbase.common.constants import COINBASE_API_PATH
+from nautilus_trader.adapters.coinbase.http.errors import parse_error
+from nautilus_trader.common.component import LiveClock
+from nautilus_trader.common.component import Logger
+from nautilus_trader.core.nautilus_pyo3 import HttpClient
+from nautilus_trader.core.nautilus_pyo3 import HttpMethod
+from nautilus_trader.core.nautilus_pyo3 import HttpResponse
+from nautilus_trader.core.nautilus_pyo3 import Quota
+
+
+HTTP_METHOD_STRINGS = {
+    HttpMethod.GET: "GET",
+    HttpMethod.POST: "POST",
+    HttpMethod.PUT: "PUT",
+    HttpMethod.DELETE: "DELETE",
+    HttpMethod.PATCH: "PATCH",
+}
+
+
+class CoinbaseHttpClient:
+    """
+    Provides a Coinbase Advanced Trade asynchronous HTTP client.
+
+    Requests are sent to `/api/v3/brokerage/{path}`, where the public market data
+    endpoints (under `market/`) need no credentials. Private requests are authenticated
+    with a `Bearer` JWT for each request, which includes the request method, host and
+    path (excluding the query string) in the `uri` claim.
+
+    Parameters
+    ----------
+    clock : LiveClock
+        The clock for the client.
+    base_url : str
+        The base endpoint URL for the client.
+    api_key : str, optional
+        The CDP API key name for private endpoints (``None`` for public only access).
+    api_secret : str, optional
+        The base64 encoded Ed25519 private key of the API key.
+    ratelimiter_quotas : list[tuple[str, Quota]], optional
+        The keyed rate limiter quotas for the client.
+    ratelimiter_default_quota : Quota, optional
+        The default rate limiter quota for the client.
+
+    """
+
+    def __init__(
+        self,
+        clock: LiveClock,
+        base_url: str,
+        api_key: str | None = None,
+        api_secret: str | None = None,
+        ratelimiter_quotas: list[tuple[str, Quota]] | None = None,
+        ratelimiter_default_quota: Quota | None = None,
+    ) -> None:
+        self._clock: LiveClock = clock
+        self._log: Logger = Logger(name=type(self).__name__)
+        self._api_key = api_key
+        self._api_secret = api_secret
+        self._signer: CoinbaseJwtSigner | None = None
+        if api_key is not None and api_secret is not None:
+            self._signer = CoinbaseJwtSigner(api_key, api_secret)
+
+        self._base_url: str = base_url
+        self._host: str = parse.urlparse(base_url).netloc
+        self._headers: dict[str, Any] = {
+            "Content-Type": "application/json",
+            "User-Agent": nautilus_trader.USER_AGENT,
+        }
+        self._client = HttpClient(
+            keyed_quotas=ratelimiter_quotas or [],
+            default_quota=ratelimiter_default_quota,
+        )
+
+    @property
+    def base_url(self) -> str:
+        return self._base_url
+
+    @property
+    def api_key(self) -> str | None:
+        return self._api_key
+
+    @property
+    def api_secret(self) -> str | None:
+        return self._api_secret
+
+    @property
+    def signer(self) -> CoinbaseJwtSigner | None:
+        return self._signer
+
+    @property
+    def has_credentials(self) -> bool:
+        return self._signer is not None
+
+    async def send_request(
+        self,
+        http_method: HttpMethod,
+        path: str,
+        params: dict[str, Any] | None = None,
+        payload: dict[str, Any] | None = None,
+        signed: bool = False,
+        ratelimiter_keys: list[str] | None = None,
+    ) -> bytes:
+        """
+        Send a request for the API `path` (e.g. `orders/historical/batch`).
+
+        Parameters
+        ----------
+        http_method : HttpMethod
+            The HTTP method for the request.
+        path : str
+            The path relative to `/api/v3/brokerage`.
+        params : dict[str, Any], optional
+            The query parameters, where list values are repeated for each item.
+        payload : dict[str, Any], optional
+            The JSON body of the request.
+        signed : bool, default False
+            If the request is authenticated.
+        ratelimiter_keys : list[str], optional
+            The rate limiter keys for the request.
+
+        Returns
+        -------
+        bytes
+            The raw JSON response.
+
+        Raises
+        ------
+        CoinbaseError
+            If the request fails.
+
+        """
+        url_path = f"{COINBASE_API_PATH}/{path}"
+        headers = self._headers
+        if signed:
+            headers = {**self._headers, "Authorization": f"Bearer {self.sign(http_method, url_path)}"}
+
+        url = self._base_url + url_path
+        if params:
+            url += "?" + parse.urlencode(
+                {k: v for k, v in params.items() if v is not None},
+                doseq=True,
+            )
+
+        response: HttpResponse = await self._client.request(
+            http_method,
+            url,
+            headers,
+            msgspec.json.encode(payload) if payload is not None else None,
+            ratelimiter_keys,
+        )
+
+        response_body = response.body
+
+        if response.status >= 400:
+            try:
+                body = msgspec.json.decode(response_body) if response_body else None
+            except msgspec.DecodeError:
+                body = response_body.decode()
+
+            raise parse_error(response.status, body)
+
+        return response_body
+
+    def sign(self, http_method: HttpMethod, url_path: str) -> str:
+        """
+        Return the JWT for a request.
+
+        Parameters
+        ----------
+        http_method : HttpMethod
+            The HTTP method of the request.
+        url_path : str
+            The request path excluding the query string (e.g. `/api/v3/brokerage/accounts`).
+
+        Returns
+        -------
+        str
+
+        Raises
+        ------
+        RuntimeError
+            If the client has no API credentials.
+
+        """
+        if self._signer is None:
+            raise RuntimeError("Cannot sign request: no API credentials (public access only)")
+
+        uri = f"{HTTP_METHOD_STRINGS[http_method]} {self._host}{url_path}"
+        return self._signer.token(self._clock.timestamp_ms() // 1_000, uri=uri)
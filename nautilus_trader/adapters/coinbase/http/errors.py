@@ -0,0 +1,86 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from typing import Any
+
+from nautilus_trader.adapters.coinbase.common.constants import COINBASE_RETRY_ERRORS
+
+
+class CoinbaseError(Exception):
+    """
+    Represents Coinbase specific errors.
+
+    The `code` is the HTTP status code, and the `error` the error type of the response
+    (e.g. `INVALID_ARGUMENT` or `NOT_FOUND`).
+
+    """
+
+    def __init__(
+        self,
+        code: int | str | None,
+        message: str | None,
+        error: str | None = None,
+    ) -> None:
+        super().__init__(message)
+        self.code = code
+        self.message = message
+        self.error = error
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}(code={self.code}, error={self.error}, message='{self.message}')"
+        )
+
+
+def parse_error(status: int, body: Any) -> CoinbaseError:
+    """
+    Return the error for the decoded Coinbase error response `body`.
+
+    Errors are reported as `{"error": ..., "message": ..., "error_details": ...}`,
+    where the optional `error_details` has further detail of the error.
+
+    """
+    if isinstance(body, dict) and ("error" in body or "message" in body):
+        message = body.get("message") or body.get("error")
+        details = body.get("error_details")
+        if details and isinstance(details, str):
+            message = f"{message} ({details})"
+        error = body.get("error")
+        return CoinbaseError(
+            code=status,
+            message=message,
+            error=error if isinstance(error, str) else None,
+        )
+    return CoinbaseError(code=status, message=body)
+
+
+def should_retry(error: BaseException) -> bool:
+    """
+    Determine if a retry should be attempted based on the error code.
+
+    Parameters
+    ----------
+    error : BaseException
+        The error to check.
+
+    Returns
+    -------
+    bool
+        True if should retry, otherwise False.
+
+    """
+    if isinstance(error, CoinbaseError):
+        return error.code in COINBASE_RETRY_ERRORS
+    return False
@@ -0,0 +1,79 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import msgspec
+
+from nautilus_trader.adapters.coinbase.common.constants import COINBASE_PUBLIC_RATE_LIMIT_KEY
+from nautilus_trader.adapters.coinbase.common.enums import CoinbaseProductType
+from nautilus_trader.adapters.coinbase.schemas.product import CoinbaseProduct
+from nautilus_trader.adapters.coinbase.schemas.product import CoinbaseProducts
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.core.nautilus_pyo3 import HttpMethod
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.adapters.coinbase.http.client import CoinbaseHttpClient
+    from nautilus_trader.common.component import LiveClock
+
+
+class CoinbaseMarketHttpAPI:
+    """
+    Provides access to the public Coinbase Advanced Trade market data endpoints.
+
+    Parameters
+    ----------
+    client : CoinbaseHttpClient
+        The Coinbase HTTP client.
+    clock : LiveClock
+        The clock for the API client.
+
+    """
+
+    def __init__(
+        self,
+        client: CoinbaseHttpClient,
+        clock: LiveClock,
+    ) -> None:
+        PyCondition.not_none(client, "client")
+        self.client = client
+        self._clock = clock
+
+        self._decoder_product = msgspec.json.Decoder(CoinbaseProduct)
+        self._decoder_products = msgspec.json.Decoder(CoinbaseProducts)
+
+    async def fetch_products(
+        self,
+        product_type: CoinbaseProductType = CoinbaseProductType.SPOT,
+        product_ids: list[str] | None = None,
+    ) -> list[CoinbaseProduct]:
+        raw = await self.client.send_request(
+            HttpMethod.GET,
+            "market/products",
+            {"product_type": product_type.value, "product_ids": product_ids},
+            ratelimiter_keys=[COINBASE_PUBLIC_RATE_LIMIT_KEY],
+        )
+        return self._decoder_products.decode(raw).products
+
+    async def fetch_product(self, product_id: str) -> CoinbaseProduct:
+        raw = await self.client.send_request(
+            HttpMethod.GET,
+            f"market/products/{product_id}",
+            ratelimiter_keys=[COINBASE_PUBLIC_RATE_LIMIT_KEY],
+        )
+        return self._decoder_product.decode(raw)
@@ -0,0 +1,256 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+from typing import Any
+
+import msgspec
+
+from nautilus_trader.adapters.coinbase.common.constants import COINBASE_MAX_BATCH_CANCEL
+from nautilus_trader.adapters.coinbase.common.constants import COINBASE_PRIVATE_RATE_LIMIT_KEY
+from nautilus_trader.adapters.coinbase.common.enums import CoinbaseOrderStatus
+from nautilus_trader.adapters.coinbase.common.enums import CoinbaseStopDirection
+from nautilus_trader.adapters.coinbase.common.enums import CoinbaseTimeInForce
+from nautilus_trader.adapters.coinbase.schemas.order import CoinbaseCancelOrdersResponse
+from nautilus_trader.adapters.coinbase.schemas.order import CoinbaseCancelResult
+from nautilus_trader.adapters.coinbase.schemas.order import CoinbaseCreateOrderResponse
+from nautilus_trader.adapters.coinbase.schemas.order import CoinbaseEditOrderResponse
+from nautilus_trader.adapters.coinbase.schemas.order import CoinbaseFill
+from nautilus_trader.adapters.coinbase.schemas.order import CoinbaseFills
+from nautilus_trader.adapters.coinbase.schemas.order import CoinbaseOrder
+from nautilus_trader.adapters.coinbase.schemas.order import CoinbaseOrderResponse
+from nautilus_trader.adapters.coinbase.schemas.order import CoinbaseOrders
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.core.nautilus_pyo3 import HttpMethod
+from nautilus_trader.model.enums import OrderType
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.adapters.coinbase.common.enums import CoinbaseOrderSide
+    from nautilus_trader.adapters.coinbase.http.client import CoinbaseHttpClient
+    from nautilus_trader.common.component import LiveClock
+
+
+# The order configuration type for each supported order type and time in force
+# (market orders are always immediate or cancel)
+ORDER_CONFIGURATION_TYPES: dict[tuple[OrderType, CoinbaseTimeInForce], str] = {
+    (OrderType.LIMIT, CoinbaseTimeInForce.GTC): "limit_limit_gtc",
+    (OrderType.LIMIT, CoinbaseTimeInForce.GTD): "limit_limit_gtd",
+    (OrderType.LIMIT, CoinbaseTimeInForce.IOC): "sor_limit_ioc",
+    (OrderType.LIMIT, CoinbaseTimeInForce.FOK): "limit_limit_fok",
+    (OrderType.STOP_LIMIT, CoinbaseTimeInForce.GTC): "stop_limit_stop_limit_gtc",
+    (OrderType.STOP_LIMIT, CoinbaseTimeInForce.GTD): "stop_limit_stop_limit_gtd",
+}
+
+
+def order_configuration(
+    order_type: OrderType,
+    base_size: str,
+    time_in_force: CoinbaseTimeInForce = CoinbaseTimeInForce.GTC,
+    limit_price: str | None = None,
+    stop_price: str | None = None,
+    stop_direction: CoinbaseStopDirection | None = None,
+    end_time: str | None = None,
+    post_only: bool = False,
+) -> dict[str, Any]:
+    """
+    Return the `order_configuration` for a new order.
+
+    The configuration has a single entry keyed by the configuration type, which is
+    determined by the order type and time in force. Sizes and prices must already be
+    rounded to the product increments.
+
+    Raises
+    ------
+    ValueError
+        If the order type and time in force combination is not supported.
+
+    """
+    if order_type == OrderType.MARKET:
+        return {"market_market_ioc": {"base_size": base_size}}
+
+    configuration_type = ORDER_CONFIGURATION_TYPES.get((order_type, time_in_force))
+    if configuration_type is None:
+        raise ValueError(
+            f"unsupported order type and time in force combination, "
+            f"was {order_type.name} {time_in_force.value}",
+        )
+
+    configuration: dict[str, Any] = {"base_size": base_size, "limit_price": limit_price}
+    if order_type == OrderType.STOP_LIMIT:
+        PyCondition.not_none(stop_price, "stop_price")
+        PyCondition.not_none(stop_direction, "stop_direction")
+        configuration["stop_price"] = stop_price
+        configuration["stop_direction"] = stop_direction.value  # type: ignore
+    if time_in_force == CoinbaseTimeInForce.GTD:
+        PyCondition.not_none(end_time, "end_time")
+        configuration["end_time"] = end_time
+    if configuration_type in ("limit_limit_gtc", "limit_limit_gtd"):
+        configuration["post_only"] = post_only
+
+    return {configuration_type: configuration}
+
+
+class CoinbaseTradeHttpAPI:
+    """
+    Provides access to the private Coinbase Advanced Trade order and fill endpoints.
+
+    Parameters
+    ----------
+    client : CoinbaseHttpClient
+        The Coinbase HTTP client (with API credentials).
+    clock : LiveClock
+        The clock for the API client.
+
+    """
+
+    def __init__(
+        self,
+        client: CoinbaseHttpClient,
+        clock: LiveClock,
+    ) -> None:
+        PyCondition.not_none(client, "client")
+        self.client = client
+        self._clock = clock
+
+        self._decoder_create_order = msgspec.json.Decoder(CoinbaseCreateOrderResponse)
+        self._decoder_cancel_orders = msgspec.json.Decoder(CoinbaseCancelOrdersResponse)
+        self._decoder_edit_order = msgspec.json.Decoder(CoinbaseEditOrderResponse)
+        self._decoder_order = msgspec.json.Decoder(CoinbaseOrderResponse)
+        self._decoder_orders = msgspec.json.Decoder(CoinbaseOrders)
+        self._decoder_fills = msgspec.json.Decoder(CoinbaseFills)
+
+    async def create_order(
+        self,
+        client_order_id: str,
+        product_id: str,
+        side: CoinbaseOrderSide,
+        configuration: dict[str, Any],
+    ) -> CoinbaseCreateOrderResponse:
+        # The client order ID must be unique, and repeated requests return the existing order
+        raw = await self.client.send_request(
+            HttpMethod.POST,
+            "orders",
+            payload={
+                "client_order_id": client_order_id,
+                "product_id": product_id,
+                "side": side.value,
+                "order_configuration": configuration,
+            },
+            signed=True,
+            ratelimiter_keys=[COINBASE_PRIVATE_RATE_LIMIT_KEY],
+        )
+        return self._decoder_create_order.decode(raw)
+
+    async def cancel_orders(self, order_ids: list[str]) -> list[CoinbaseCancelResult]:
+        results: list[CoinbaseCancelResult] = []
+        for i in range(0, len(order_ids), COINBASE_MAX_BATCH_CANCEL):
+            raw = await self.client.send_request(
+                HttpMethod.POST,
+                "orders/batch_cancel",
+                payload={"order_ids": order_ids[i : i + COINBASE_MAX_BATCH_CANCEL]},
+                signed=True,
+                ratelimiter_keys=[COINBASE_PRIVATE_RATE_LIMIT_KEY],
+            )
+            results += self._decoder_cancel_orders.decode(raw).results
+        return results
+
+    async def edit_order(
+        self,
+        order_id: str,
+        price: str,
+        size: str,
+    ) -> CoinbaseEditOrderResponse:
+        # Only good til canceled limit orders can be edited, where the size is the new
+        # total size of the order (and a size increase loses queue priority)
+        raw = await self.client.send_request(
+            HttpMethod.POST,
+            "orders/edit",
+            payload={"order_id": order_id, "price": price, "size": size},
+            signed=True,
+            ratelimiter_keys=[COINBASE_PRIVATE_RATE_LIMIT_KEY],
+        )
+        return self._decoder_edit_order.decode(raw)
+
+    async def fetch_order(self, order_id: str) -> CoinbaseOrder:
+        raw = await self.client.send_request(
+            HttpMethod.GET,
+            f"orders/historical/{order_id}",
+            signed=True,
+            ratelimiter_keys=[COINBASE_PRIVATE_RATE_LIMIT_KEY],
+        )
+        return self._decoder_order.decode(raw).order
+
+    async def fetch_orders(
+        self,
+        product_ids: list[str] | None = None,
+        order_status: list[CoinbaseOrderStatus] | None = None,
+        start_date: str | None = None,
+        end_date: str | None = None,
+    ) -> list[CoinbaseOrder]:
+        # Orders are paginated by cursor, and listed most recent first
+        orders: list[CoinbaseOrder] = []
+        params: dict[str, Any] = {
+            "product_ids": product_ids,
+            "order_status": [status.value for status in order_status] if order_status else None,
+            "start_date": start_date,
+            "end_date": end_date,
+            "limit": 1000,
+        }
+        while True:
+            raw = await self.client.send_request(
+                HttpMethod.GET,
+                "orders/historical/batch",
+                params,
+                signed=True,
+                ratelimiter_keys=[COINBASE_PRIVATE_RATE_LIMIT_KEY],
+            )
+            page = self._decoder_orders.decode(raw)
+            orders += page.orders
+            if not page.has_next or not page.cursor:
+                return orders
+            params["cursor"] = page.cursor
+
+    async def fetch_fills(
+        self,
+        order_ids: list[str] | None = None,
+        product_ids: list[str] | None = None,
+        start_sequence_timestamp: str | None = None,
+        end_sequence_timestamp: str | None = None,
+    ) -> list[CoinbaseFill]:
+        # Fills are paginated by cursor, where the last page has no cursor
+        fills: list[CoinbaseFill] = []
+        params: dict[str, Any] = {
+            "order_ids": order_ids,
+            "product_ids": product_ids,
+            "start_sequence_timestamp": start_sequence_timestamp,
+            "end_sequence_timestamp": end_sequence_timestamp,
+            "limit": 1000,
+        }
+        while True:
+            raw = await self.client.send_request(
+                HttpMethod.GET,
+                "orders/historical/fills",
+                params,
+                signed=True,
+                ratelimiter_keys=[COINBASE_PRIVATE_RATE_LIMIT_KEY],
+            )
+            page = self._decoder_fills.decode(raw)
+            fills += page.fills
+            if not page.cursor or not page.fills:
+                return fills
+            params["cursor"] = page.cursor
@@ -0,0 +1,173 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from decimal import Decimal
+from typing import TYPE_CHECKING
+
+from nautilus_trader.adapters.coinbase.common.constants import COINBASE_DEFAULT_MAKER_FEE
+from nautilus_trader.adapters.coinbase.common.constants import COINBASE_DEFAULT_TAKER_FEE
+from nautilus_trader.adapters.coinbase.common.constants import COINBASE_VENUE
+from nautilus_trader.adapters.coinbase.http.account import CoinbaseAccountHttpAPI
+from nautilus_trader.adapters.coinbase.http.errors import CoinbaseError
+from nautilus_trader.adapters.coinbase.http.market import CoinbaseMarketHttpAPI
+from nautilus_trader.common.providers import InstrumentProvider
+from nautilus_trader.core.correctness import PyCondition
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.adapters.coinbase.http.client import CoinbaseHttpClient
+    from nautilus_trader.adapters.coinbase.schemas.product import CoinbaseProduct
+    from nautilus_trader.common.component import LiveClock
+    from nautilus_trader.config import InstrumentProviderConfig
+    from nautilus_trader.model.identifiers import InstrumentId
+
+
+class CoinbaseInstrumentProvider(InstrumentProvider):
+    """
+    Provides Nautilus instrument definitions from Coinbase Advanced Trade spot products.
+
+    Fees depend on the trading volume of the account, so with API credentials the fee
+    rates of the account fee tier are requested, otherwise the fee rates of the lowest
+    tier are used.
+
+    The products can be filtered by quote currency with a `quote_currencies` filter,
+    e.g. `filters={"quote_currencies": ["USD", "USDC"]}`.
+
+    Parameters
+    ----------
+    client : CoinbaseHttpClient
+        The Coinbase HTTP client.
+    clock : LiveClock
+        The clock instance.
+    config : InstrumentProviderConfig, optional
+        The instrument provider configuration, by default None.
+
+    """
+
+    def __init__(
+        self,
+        client: CoinbaseHttpClient,
+        clock: LiveClock,
+        config: InstrumentProviderConfig | None = None,
+    ) -> None:
+        super().__init__(config=config)
+        self._clock = clock
+        self._client = client
+        self._http_market = CoinbaseMarketHttpAPI(client, clock)
+        self._http_account = CoinbaseAccountHttpAPI(client, clock)
+
+        self._log_warnings = config.log_warnings if config else True
+
+    async def load_all_async(self, filters: dict | None = None) -> None:
+        filters_str = "..." if not filters else f" with filters {filters}..."
+        self._log.info(f"Loading all instruments{filters_str}")
+
+        await self._load_instruments(filters=filters)
+
+        self._log.info(f"Loaded {len(self._instruments)} instruments")
+
+    async def load_ids_async(
+        self,
+        instrument_ids: list[InstrumentId],
+        filters: dict | None = None,
+    ) -> None:
+        if not instrument_ids:
+            self._log.warning("No instrument IDs given for loading")
+            return
+
+        # Check all instrument IDs
+        for instrument_id in instrument_ids:
+            PyCondition.equal(
+                instrument_id.venue,
+                COINBASE_VENUE,
+                "instrument_id.venue",
+                "COINBASE",
+            )
+
+        await self._load_instruments(set(instrument_ids), filters)
+
+    async def load_async(self, instrument_id: InstrumentId, filters: dict | None = None) -> None:
+        PyCondition.not_none(instrument_id, "instrument_id")
+        await self.load_ids_async([instrument_id], filters)
+
+    async def _load_instruments(
+        self,
+        instrument_ids: set[InstrumentId] | None = None,
+        filters: dict | None = None,
+    ) -> None:
+        quote_currencies = (filters or {}).get("quote_currencies")
+        maker_fee, taker_fee = await self._fetch_fee_rates()
+
+        for product in await self._http_market.fetch_products():
+            if not product.is_tradable:
+                continue
+            if quote_currencies is not None and product.quote_currency_id not in quote_currencies:
+                continue
+            if (
+                instrument_ids is not None
+                and product.symbol.to_instrument_id() not in instrument_ids
+            ):
+                continue
+            self._parse_instrument(product, maker_fee, taker_fee)
+
+    async def _fetch_fee_rates(self) -> tuple[Decimal, Decimal]:
+        if not self._client.has_credentials:
+            return COINBASE_DEFAULT_MAKER_FEE, COINBASE_DEFAULT_TAKER_FEE
+
+        try:
+            summary = await self._http_account.fetch_transaction_summary()
+        except CoinbaseError as e:
+            self._log.warning(f"Unable to fetch fee tier, using default fee rates: {e}")
+            return COINBASE_DEFAULT_MAKER_FEE, COINBASE_DEFAULT_TAKER_FEE
+
+        fee_tier = summary.fee_tier
+        self._log.info(
+            f"Fee tier {fee_tier.pricing_tier}: "
+            f"maker {fee_tier.maker_fee_rate}, taker {fee_tier.taker_fee_rate}",
+        )
+        return Decimal(fee_tier.maker_fee_rate), Decimal(fee_tier.taker_fee_rate)
+
+    def _parse_instrument(
+        self,
+        product: CoinbaseProduct,
+        maker_fee: Decimal,
+        taker_fee: Decimal,
+    ) -> None:
+        try:
+            base_currency = self.currency(product.base_currency_id)
+            quote_currency = self.currency(product.quote_currency_id)
+            assert base_currency is not None  # Type checking
+            assert quote_currency is not None  # Type checking
+            self.add_currency(base_currency)
+            self.add_currency(quote_currency)
+
+            ts_event = self._clock.timestamp_ns()
+            ts_init = self._clock.timestamp_ns()
+            instrument = product.parse_to_instrument(
+                base_currency=base_currency,
+                quote_currency=quote_currency,
+                maker_fee=maker_fee,
+                taker_fee=taker_fee,
+                ts_event=ts_event,
+                ts_init=ts_init,
+            )
+        except ValueError as e:
+            if self._log_warnings:
+                self._log.warning(f"Unable to parse instrument {product.product_id}: {e}")
+            return
+
+        self.add(instrument=instrument)
@@ -0,0 +1,121 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from decimal import Decimal
+
+import msgspec
+
+from nautilus_trader.model.objects import AccountBalance
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import Money
+
+
+class CoinbaseAmount(msgspec.Struct, frozen=True):
+    value: str
+    currency: str
+
+
+class CoinbaseAccount(msgspec.Struct, frozen=True):
+    """
+    Represents a Coinbase account (wallet) for a currency, as listed by `/accounts`.
+
+    The `hold` is the amount reserved for open orders and pending transfers.
+
+    """
+
+    uuid: str
+    currency: str
+    available_balance: CoinbaseAmount
+    hold: CoinbaseAmount | None = None
+    name: str | None = None
+    type: str | None = None
+    active: bool = True
+    ready: bool = True
+
+    @property
+    def is_empty(self) -> bool:
+        hold = Decimal(self.hold.value) if self.hold is not None else Decimal(0)
+        return Decimal(self.available_balance.value) == 0 and hold == 0
+
+    def parse_to_account_balance(self) -> AccountBalance:
+        currency = Currency.from_str(self.currency)
+        free = Decimal(self.available_balance.value)
+        locked = Decimal(self.hold.value) if self.hold is not None else Decimal(0)
+        return AccountBalance(
+            total=Money(free + locked, currency),
+            locked=Money(locked, currency),
+            free=Money(free, currency),
+        )
+
+
+def parse_account_balances(accounts: list[CoinbaseAccount]) -> list[AccountBalance]:
+    """
+    Parse the accounts into balances for each currency.
+
+    Accounts with no balance are skipped, and balances of accounts with the same
+    currency are combined.
+
+    Parameters
+    ----------
+    accounts : list[CoinbaseAccount]
+        The accounts to parse.
+
+    Returns
+    -------
+    list[AccountBalance]
+
+    """
+    balances: dict[str, AccountBalance] = {}
+    for account in accounts:
+        if account.is_empty:
+            continue
+
+        balance = account.parse_to_account_balance()
+        existing = balances.get(account.currency)
+        if existing is not None:
+            currency = balance.currency
+            balance = AccountBalance(
+                total=Money(existing.total.as_decimal() + balance.total.as_decimal(), currency),
+                locked=Money(existing.locked.as_decimal() + balance.locked.as_decimal(), currency),
+                free=Money(existing.free.as_decimal() + balance.free.as_decimal(), currency),
+            )
+        balances[account.currency] = balance
+
+    return list(balances.values())
+
+
+class CoinbaseAccounts(msgspec.Struct, frozen=True):
+    accounts: list[CoinbaseAccount]
+    has_next: bool = False
+    cursor: str | None = None
+    size: int | None = None
+
+
+class CoinbaseFeeTier(msgspec.Struct, frozen=True):
+    pricing_tier: str
+    maker_fee_rate: str
+    taker_fee_rate: str
+
+
+class CoinbaseTransactionSummary(msgspec.Struct, frozen=True):
+    """
+    Represents the transaction summary of the user, with the current fee tier.
+    """
+
+    fee_tier: CoinbaseFeeTier
+    total_volume: float | None = None
+    total_fees: float | None = None
@@ -0,0 +1,311 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from decimal import Decimal
+from typing import Any
+
+import msgspec
+import pandas as pd
+
+from nautilus_trader.adapters.coinbase.common.enums import CoinbaseEnumParser
+from nautilus_trader.adapters.coinbase.common.enums import CoinbaseLiquidityIndicator
+from nautilus_trader.adapters.coinbase.common.enums import CoinbaseOrderSide
+from nautilus_trader.adapters.coinbase.common.enums import CoinbaseOrderStatus
+from nautilus_trader.adapters.coinbase.common.enums import CoinbaseOrderType
+from nautilus_trader.adapters.coinbase.common.enums import CoinbaseTimeInForce
+from nautilus_trader.adapters.coinbase.common.parsing import parse_decimal
+from nautilus_trader.adapters.coinbase.common.parsing import parse_timestamp
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.execution.reports import FillReport
+from nautilus_trader.execution.reports import OrderStatusReport
+from nautilus_trader.model.enums import ContingencyType
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import TriggerType
+from nautilus_trader.model.identifiers import AccountId
+from nautilus_trader.model.identifiers import ClientOrderId
+from nautilus_trader.model.identifiers import TradeId
+from nautilus_trader.model.identifiers import VenueOrderId
+from nautilus_trader.model.instruments import Instrument
+from nautilus_trader.model.objects import Money
+
+
+class CoinbaseOrder(msgspec.Struct, frozen=True):
+    """
+    Represents a Coinbase Advanced Trade order, as reported by `/orders/historical`.
+
+    The size, prices and expiry of the order are in the `order_configuration`, which
+    has a single entry keyed by the configuration type (e.g. `limit_limit_gtc`).
+
+    """
+
+    order_id: str
+    product_id: str
+    side: CoinbaseOrderSide
+    client_order_id: str
+    status: CoinbaseOrderStatus
+    created_time: str
+    order_configuration: dict[str, dict[str, Any]]
+    time_in_force: CoinbaseTimeInForce = CoinbaseTimeInForce.UNKNOWN
+    order_type: CoinbaseOrderType = CoinbaseOrderType.UNKNOWN
+    filled_size: str | None = None
+    average_filled_price: str | None = None
+    total_fees: str | None = None
+    number_of_fills: str | None = None
+    last_fill_time: str | None = None
+    pending_cancel: bool = False
+    size_in_quote: bool = False
+    reject_reason: str | None = None
+    reject_message: str | None = None
+    cancel_message: str | None = None
+    product_type: str | None = None
+    outstanding_hold_amount: str | None = None
+
+    @property
+    def configuration(self) -> dict[str, Any]:
+        return next(iter(self.order_configuration.values()), {})
+
+    @property
+    def base_size(self) -> Decimal | None:
+        return parse_decimal(self.configuration.get("base_size"))
+
+    @property
+    def limit_price(self) -> Decimal | None:
+        return parse_decimal(self.configuration.get("limit_price"))
+
+    @property
+    def stop_price(self) -> Decimal | None:
+        return parse_decimal(self.configuration.get("stop_price"))
+
+    @property
+    def end_time(self) -> str | None:
+        return self.configuration.get("end_time") or None
+
+    @property
+    def post_only(self) -> bool:
+        return bool(self.configuration.get("post_only"))
+
+    @property
+    def ts_accepted(self) -> int:
+        return parse_timestamp(self.created_time)
+
+    @property
+    def ts_last(self) -> int:
+        return parse_timestamp(self.last_fill_time or self.created_time)
+
+    def parse_to_order_status_report(
+        self,
+        account_id: AccountId,
+        instrument: Instrument,
+        client_order_id: ClientOrderId | None,
+        report_id: UUID4,
+        enum_parser: CoinbaseEnumParser,
+        ts_init: int,
+    ) -> OrderStatusReport:
+        filled_size = parse_decimal(self.filled_size) or Decimal(0)
+        order_status = enum_parser.parse_coinbase_order_status(
+            self.status,
+            is_partially_filled=filled_size > 0,
+        )
+
+        # Market orders sized in the quote currency have no base size
+        quantity = self.base_size or filled_size
+
+        order_type = (
+            enum_parser.parse_coinbase_order_type(self.order_type)
+            if self.order_type != CoinbaseOrderType.UNKNOWN
+            else OrderType.MARKET
+        )
+
+        limit_price = self.limit_price
+        stop_price = self.stop_price
+        avg_px = parse_decimal(self.average_filled_price)
+
+        cancel_reason = None
+        if order_status == OrderStatus.CANCELED and self.cancel_message:
+            cancel_reason = self.cancel_message
+        elif order_status == OrderStatus.REJECTED:
+            cancel_reason = self.reject_message or self.reject_reason
+
+        return OrderStatusReport(
+            account_id=account_id,
+            instrument_id=instrument.id,
+            client_order_id=client_order_id,
+            order_list_id=None,
+            venue_order_id=VenueOrderId(self.order_id),
+            order_side=enum_parser.parse_coinbase_order_side(self.side),
+            order_type=order_type,
+            contingency_type=ContingencyType.NO_CONTINGENCY,
+            time_in_force=enum_parser.parse_coinbase_time_in_force(self.time_in_force),
+            expire_time=pd.Timestamp(self.end_time) if self.end_time else None,
+            order_status=order_status,
+            price=instrument.make_price(limit_price) if limit_price else None,
+            trigger_price=instrument.make_price(stop_price) if stop_price else None,
+            trigger_type=TriggerType.LAST_PRICE if stop_price else TriggerType.NO_TRIGGER,
+            quantity=instrument.make_qty(quantity),
+            filled_qty=instrument.make_qty(filled_size),
+            avg_px=avg_px if avg_px else None,
+            post_only=self.post_only,
+            reduce_only=False,
+            cancel_reason=cancel_reason,
+            ts_accepted=self.ts_accepted,
+            ts_last=self.ts_last,
+            report_id=report_id,
+            ts_init=ts_init,
+        )
+
+
+class CoinbaseOrderResponse(msgspec.Struct, frozen=True):
+    order: CoinbaseOrder
+
+
+class CoinbaseOrders(msgspec.Struct, frozen=True):
+    orders: list[CoinbaseOrder]
+    has_next: bool = False
+    cursor: str | None = None
+
+
+class CoinbaseFill(msgspec.Struct, frozen=True):
+    """
+    Represents a fill of the user, as listed by `/orders/historical/fills`.
+
+    The `size` is in the quote currency when `size_in_quote` (for market orders sized
+    in the quote currency), and the `commission` is in the quote currency.
+
+    """
+
+    entry_id: str
+    trade_id: str
+    order_id: str
+    trade_time: str
+    price: str
+    size: str
+    commission: str
+    product_id: str
+    side: CoinbaseOrderSide
+    liquidity_indicator: CoinbaseLiquidityIndicator = CoinbaseLiquidityIndicator.UNKNOWN
+    trade_type: str | None = None
+    size_in_quote: bool = False
+    sequence_timestamp: str | None = None
+    user_id: str | None = None
+
+    @property
+    def ts_event(self) -> int:
+        return parse_timestamp(self.trade_time)
+
+    @property
+    def base_size(self) -> Decimal:
+        size = Decimal(self.size)
+        if self.size_in_quote:
+            return size / Decimal(self.price)
+        return size
+
+    def parse_to_fill_report(
+        self,
+        account_id: AccountId,
+        instrument: Instrument,
+        client_order_id: ClientOrderId | None,
+        report_id: UUID4,
+        enum_parser: CoinbaseEnumParser,
+        ts_init: int,
+    ) -> FillReport:
+        return FillReport(
+            client_order_id=client_order_id,
+            venue_order_id=VenueOrderId(self.order_id),
+            trade_id=TradeId(self.trade_id),
+            account_id=account_id,
+            instrument_id=instrument.id,
+            order_side=enum_parser.parse_coinbase_order_side(self.side),
+            last_qty=instrument.make_qty(self.base_size),
+            last_px=instrument.make_price(Decimal(self.price)),
+            commission=Money(Decimal(self.commission or 0), instrument.quote_currency),
+            liquidity_side=enum_parser.parse_coinbase_liquidity_side(self.liquidity_indicator),
+            report_id=report_id,
+            ts_event=self.ts_event,
+            ts_init=ts_init,
+        )
+
+
+class CoinbaseFills(msgspec.Struct, frozen=True):
+    fills: list[CoinbaseFill]
+    cursor: str | None = None
+
+
+class CoinbaseCreateOrderSuccess(msgspec.Struct, frozen=True):
+    order_id: str
+    product_id: str | None = None
+    side: str | None = None
+    client_order_id: str | None = None
+
+
+class CoinbaseCreateOrderError(msgspec.Struct, frozen=True):
+    error: str | None = None
+    message: str | None = None
+    error_details: str | None = None
+    preview_failure_reason: str | None = None
+    new_order_failure_reason: str | None = None
+
+
+class CoinbaseCreateOrderResponse(msgspec.Struct, frozen=True):
+    """
+    Represents the response to creating an order.
+
+    Orders failing validation are reported with `success` false (and an HTTP 200
+    status), with the reason in the `error_response`.
+
+    """
+
+    success: bool
+    success_response: CoinbaseCreateOrderSuccess | None = None
+    error_response: CoinbaseCreateOrderError | None = None
+    failure_reason: str | None = None
+
+    @property
+    def reason(self) -> str:
+        error = self.error_response
+        if error is None:
+            return self.failure_reason or "Rejected"
+        reason = error.message or error.error or "Rejected"
+        detail = error.error_details or error.new_order_failure_reason
+        return f"{reason} ({detail})" if detail else reason
+
+
+class CoinbaseCancelResult(msgspec.Struct, frozen=True):
+    success: bool
+    order_id: str
+    failure_reason: str | None = None
+
+
+class CoinbaseCancelOrdersResponse(msgspec.Struct, frozen=True):
+    results: list[CoinbaseCancelResult]
+
+
+class CoinbaseEditOrderError(msgspec.Struct, frozen=True):
+    edit_failure_reason: str | None = None
+    preview_failure_reason: str | None = None
+
+
+class CoinbaseEditOrderResponse(msgspec.Struct, frozen=True):
+    success: bool
+    errors: list[CoinbaseEditOrderError] = []
+
+    @property
+    def reason(self) -> str:
+        reasons = [
+            error.edit_failure_reason or error.preview_failure_reason or "" for error in self.errors
+        ]
+        return ", ".join(reason for reason in reasons if reason) or "Rejected"
@@ -0,0 +1,137 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from decimal import Decimal
+
+import msgspec
+
+from nautilus_trader.adapters.coinbase.common.enums import CoinbaseProductType
+from nautilus_trader.adapters.coinbase.common.parsing import parse_decimal
+from nautilus_trader.adapters.coinbase.common.symbol import CoinbaseSymbol
+from nautilus_trader.model.identifiers import Symbol
+from nautilus_trader.model.instruments import CurrencyPair
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import Money
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+
+
+class CoinbaseProduct(msgspec.Struct, frozen=True):
+    """
+    Represents a Coinbase Advanced Trade product, as listed by `/market/products`.
+
+    Products quoted in USDC are aliases of the USD products (e.g. `BTC-USDC` of `BTC-USD`),
+    which share the same order book.
+
+    """
+
+    product_id: str
+    base_currency_id: str
+    quote_currency_id: str
+    base_increment: str
+    quote_increment: str
+    base_min_size: str
+    base_max_size: str
+    quote_min_size: str
+    quote_max_size: str
+    product_type: CoinbaseProductType
+    price_increment: str | None = None
+    status: str | None = None
+    trading_disabled: bool = False
+    is_disabled: bool = False
+    cancel_only: bool = False
+    limit_only: bool = False
+    post_only: bool = False
+    auction_mode: bool = False
+    view_only: bool = False
+    alias: str | None = None
+    alias_to: list[str] = []
+    base_name: str | None = None
+    quote_name: str | None = None
+    price: str | None = None
+
+    @property
+    def symbol(self) -> CoinbaseSymbol:
+        return CoinbaseSymbol(self.product_id)
+
+    @property
+    def is_tradable(self) -> bool:
+        return not (self.trading_disabled or self.is_disabled or self.view_only)
+
+    def parse_to_instrument(
+        self,
+        base_currency: Currency,
+        quote_currency: Currency,
+        maker_fee: Decimal,
+        taker_fee: Decimal,
+        ts_event: int,
+        ts_init: int,
+    ) -> CurrencyPair:
+        """
+        Parse the product into a Nautilus currency pair instrument.
+
+        The price increment is the `price_increment` of the product, falling back to
+        the `quote_increment` (the increment of quote sizes for market orders).
+
+        Raises
+        ------
+        ValueError
+            If the product is not a spot product.
+
+        """
+        if self.product_type != CoinbaseProductType.SPOT:
+            raise ValueError(f"unsupported product type {self.product_type.value}")
+
+        price_increment = Price.from_str(
+            str(Decimal(self.price_increment or self.quote_increment).normalize()),
+        )
+        size_increment = Quantity.from_str(str(Decimal(self.base_increment).normalize()))
+
+        max_quantity = parse_decimal(self.base_max_size)
+        min_quantity = parse_decimal(self.base_min_size)
+        max_notional = parse_decimal(self.quote_max_size)
+        min_notional = parse_decimal(self.quote_min_size)
+
+        return CurrencyPair(
+            instrument_id=self.symbol.to_instrument_id(),
+            raw_symbol=Symbol(self.product_id),
+            base_currency=base_currency,
+            quote_currency=quote_currency,
+            price_precision=price_increment.precision,
+            size_precision=size_increment.precision,
+            price_increment=price_increment,
+            size_increment=size_increment,
+            margin_init=Decimal(0),
+            margin_maint=Decimal(0),
+            maker_fee=maker_fee,
+            taker_fee=taker_fee,
+            ts_event=ts_event,
+            ts_init=ts_init,
+            lot_size=None,
+            max_quantity=Quantity(max_quantity, size_increment.precision) if max_quantity else None,
+            min_quantity=Quantity(min_quantity, size_increment.precision) if min_quantity else None,
+            max_notional=Money(max_notional, quote_currency) if max_notional else None,
+            min_notional=Money(min_notional, quote_currency) if min_notional else None,
+            min_price=None,
+            max_price=None,
+            info=msgspec.to_builtins(self),
+        )
+
+
+class CoinbaseProducts(msgspec.Struct, frozen=True):
+    products: list[CoinbaseProduct]
+    num_products: int | None = None
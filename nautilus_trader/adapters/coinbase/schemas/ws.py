@@ -0,0 +1,268 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from decimal import Decimal
+
+import msgspec
+
+from nautilus_trader.adapters.coinbase.common.enums import CoinbaseOrderSide
+from nautilus_trader.adapters.coinbase.common.enums import CoinbaseOrderStatus
+from nautilus_trader.adapters.coinbase.common.parsing import parse_aggressor_side
+from nautilus_trader.adapters.coinbase.common.parsing import parse_decimal
+from nautilus_trader.adapters.coinbase.common.parsing import parse_timestamp
+from nautilus_trader.model.data import BookOrder
+from nautilus_trader.model.data import OrderBookDelta
+from nautilus_trader.model.data import OrderBookDeltas
+from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.model.data import TradeTick
+from nautilus_trader.model.enums import BookAction
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import RecordFlag
+from nautilus_trader.model.identifiers import TradeId
+from nautilus_trader.model.instruments import Instrument
+
+
+class CoinbaseWsMessageGeneral(msgspec.Struct):
+    """
+    Represents the common fields of all Coinbase Advanced Trade WebSocket messages.
+
+    Channel messages have the `channel` and a `sequence_num`, which is incremented for
+    each message of the connection (across all channels), whereas errors have a
+    `type` of `error` and the `message`.
+
+    """
+
+    channel: str | None = None
+    type: str | None = None
+    message: str | None = None
+    timestamp: str | None = None
+    sequence_num: int | None = None
+
+
+################################################################################
+# Market data
+################################################################################
+
+
+class CoinbaseWsL2Update(msgspec.Struct):
+    side: str  # `bid` or `offer`
+    price_level: str
+    new_quantity: str
+    event_time: str | None = None
+
+
+class CoinbaseWsL2Event(msgspec.Struct):
+    type: str  # `snapshot` or `update`
+    product_id: str
+    updates: list[CoinbaseWsL2Update]
+
+    @property
+    def is_snapshot(self) -> bool:
+        return self.type == "snapshot"
+
+    def parse_to_deltas(
+        self,
+        instrument: Instrument,
+        sequence: int,
+        ts_event: int,
+        ts_init: int,
+    ) -> OrderBookDeltas:
+        """
+        Parse the price level updates into order book deltas.
+
+        A snapshot clears the existing book, and updates with a zero `new_quantity`
+        delete the price level.
+
+        """
+        is_snapshot = self.is_snapshot
+        deltas: list[OrderBookDelta] = []
+        if is_snapshot:
+            deltas.append(OrderBookDelta.clear(instrument.id, sequence, ts_event, ts_init))
+
+        updates_len = len(self.updates)
+        for idx, update in enumerate(self.updates):
+            flags = RecordFlag.F_SNAPSHOT if is_snapshot else 0
+            if idx == updates_len - 1:
+                # F_LAST, 1 << 7
+                # Last message in the book event or packet from the venue for a given `instrument_id`
+                flags |= RecordFlag.F_LAST
+
+            size = Decimal(update.new_quantity)
+            if is_snapshot:
+                action = BookAction.ADD
+            elif size == 0:
+                action = BookAction.DELETE
+            else:
+                action = BookAction.UPDATE
+
+            deltas.append(
+                OrderBookDelta(
+                    instrument_id=instrument.id,
+                    action=action,
+                    order=BookOrder(
+                        side=OrderSide.BUY if update.side == "bid" else OrderSide.SELL,
+                        price=instrument.make_price(Decimal(update.price_level)),
+                        size=instrument.make_qty(size),
+                        order_id=0,
+                    ),
+                    flags=flags,
+                    sequence=sequence,
+                    ts_event=ts_event,
+                    ts_init=ts_init,
+                ),
+            )
+
+        return OrderBookDeltas(instrument_id=instrument.id, deltas=deltas)
+
+
+class CoinbaseWsL2Msg(msgspec.Struct):
+    channel: str
+    timestamp: str
+    sequence_num: int
+    events: list[CoinbaseWsL2Event]
+
+
+class CoinbaseWsTicker(msgspec.Struct):
+    product_id: str
+    price: str
+    type: str | None = None
+    best_bid: str | None = None
+    best_bid_quantity: str | None = None
+    best_ask: str | None = None
+    best_ask_quantity: str | None = None
+    volume_24_h: str | None = None
+    low_24_h: str | None = None
+    high_24_h: str | None = None
+
+    def parse_to_quote_tick(
+        self,
+        instrument: Instrument,
+        ts_event: int,
+        ts_init: int,
+    ) -> QuoteTick | None:
+        bid_size = parse_decimal(self.best_bid_quantity)
+        ask_size = parse_decimal(self.best_ask_quantity)
+        if not bid_size or not ask_size:
+            return None  # One side of the book is empty
+
+        return QuoteTick(
+            instrument_id=instrument.id,
+            bid_price=instrument.make_price(Decimal(self.best_bid or 0)),
+            ask_price=instrument.make_price(Decimal(self.best_ask or 0)),
+            bid_size=instrument.make_qty(bid_size),
+            ask_size=instrument.make_qty(ask_size),
+            ts_event=ts_event,
+            ts_init=ts_init,
+        )
+
+
+class CoinbaseWsTickerEvent(msgspec.Struct):
+    type: str
+    tickers: list[CoinbaseWsTicker]
+
+
+class CoinbaseWsTickerMsg(msgspec.Struct):
+    channel: str
+    timestamp: str
+    sequence_num: int
+    events: list[CoinbaseWsTickerEvent]
+
+
+class CoinbaseWsMarketTrade(msgspec.Struct):
+    trade_id: str
+    product_id: str
+    price: str
+    size: str
+    side: str
+    time: str
+
+    def parse_to_trade_tick(self, instrument: Instrument, ts_init: int) -> TradeTick:
+        return TradeTick(
+            instrument_id=instrument.id,
+            price=instrument.make_price(Decimal(self.price)),
+            size=instrument.make_qty(Decimal(self.size)),
+            aggressor_side=parse_aggressor_side(self.side),
+            trade_id=TradeId(self.trade_id),
+            ts_event=parse_timestamp(self.time),
+            ts_init=ts_init,
+        )
+
+
+class CoinbaseWsMarketTradesEvent(msgspec.Struct):
+    type: str  # `snapshot` (recent trades on subscribing) or `update`
+    trades: list[CoinbaseWsMarketTrade]
+
+
+class CoinbaseWsMarketTradesMsg(msgspec.Struct):
+    channel: str
+    timestamp: str
+    sequence_num: int
+    events: list[CoinbaseWsMarketTradesEvent]
+
+
+################################################################################
+# User
+################################################################################
+
+
+class CoinbaseWsUserOrder(msgspec.Struct):
+    """
+    Represents an order update on the `user` channel.
+
+    Updates have the cumulative filled quantity, average price and fees of the
+    order, rather than the individual fills.
+
+    """
+
+    order_id: str
+    client_order_id: str
+    product_id: str
+    status: CoinbaseOrderStatus
+    order_side: CoinbaseOrderSide
+    cumulative_quantity: str
+    leaves_quantity: str
+    avg_price: str
+    total_fees: str
+    creation_time: str
+    order_type: str | None = None
+    limit_price: str | None = None
+    stop_price: str | None = None
+    time_in_force: str | None = None
+    post_only: bool | None = None
+    number_of_fills: str | None = None
+    cancel_reason: str | None = None
+    reject_reason: str | None = msgspec.field(default=None, name="reject_Reason")
+
+    @property
+    def filled_qty(self) -> Decimal:
+        return parse_decimal(self.cumulative_quantity) or Decimal(0)
+
+    @property
+    def quantity(self) -> Decimal:
+        return self.filled_qty + (parse_decimal(self.leaves_quantity) or Decimal(0))
+
+
+class CoinbaseWsUserEvent(msgspec.Struct):
+    type: str  # `snapshot` (open orders on subscribing) or `update`
+    orders: list[CoinbaseWsUserOrder] = []
+
+
+class CoinbaseWsUserMsg(msgspec.Struct):
+    channel: str
+    timestamp: str
+    sequence_num: int
+    events: list[CoinbaseWsUserEvent]
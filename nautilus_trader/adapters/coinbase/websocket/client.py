@@ -0,0 +1,253 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+import asyncio
+from typing import TYPE_CHECKING
+from typing import Any
+
+from msgspec import json as msgspec_json
+
+from nautilus_trader.common.component import LiveClock
+from nautilus_trader.common.component import Logger
+from nautilus_trader.common.enums import LogColor
+from nautilus_trader.core.nautilus_pyo3 import WebSocketClient
+from nautilus_trader.core.nautilus_pyo3 import WebSocketClientError
+from nautilus_trader.core.nautilus_pyo3 import WebSocketConfig
+
+
+if TYPE_CHECKING:
+    from collections.abc import Awaitable
+    from collections.abc import Callable
+
+    from nautilus_trader.adapters.coinbase.common.auth import CoinbaseJwtSigner
+
+
+# Channel of the heartbeat messages, which keep the connection open when updates are sparse
+_HEARTBEATS_CHANNEL = "heartbeats"
+
+
+class CoinbaseWebSocketClient:
+    """
+    Provides a Coinbase Advanced Trade streaming WebSocket client.
+
+    Each channel is subscribed for a list of product IDs, where a channel subscribed
+    with no product IDs (such as the `user` channel) streams updates for all products.
+    Subscriptions must be made within five seconds of connecting, so the `heartbeats`
+    channel is subscribed on connect (which also keeps idle connections open).
+
+    When API credentials are given, a new JWT is sent with each subscription message
+    (required for the `user` channel).
+
+    Parameters
+    ----------
+    clock : LiveClock
+        The clock instance.
+    base_url : str
+        The base URL for the WebSocket connection.
+    handler : Callable[[bytes], None]
+        The callback handler for channel messages.
+    handler_reconnect : Callable[..., Awaitable[None]], optional
+        The callback handler to be called on reconnect.
+    loop : asyncio.AbstractEventLoop
+        The event loop for the client.
+    signer : CoinbaseJwtSigner, optional
+        The JWT signer for authenticated subscriptions.
+
+    """
+
+    def __init__(
+        self,
+        clock: LiveClock,
+        base_url: str,
+        handler: Callable[[bytes], None],
+        handler_reconnect: Callable[..., Awaitable[None]] | None,
+        loop: asyncio.AbstractEventLoop,
+        signer: CoinbaseJwtSigner | None = None,
+    ) -> None:
+        self._clock = clock
+        self._log: Logger = Logger(name=type(self).__name__)
+
+        self._base_url: str = base_url
+        self._handler: Callable[[bytes], None] = handler
+        self._handler_reconnect: Callable[..., Awaitable[None]] | None = handler_reconnect
+        self._loop = loop
+        self._signer = signer
+
+        self._client: WebSocketClient | None = None
+        self._is_running = False
+        self._reconnecting = False
+
+        # Product IDs subscribed for each channel (replayed on reconnect), where an empty
+        # set is a subscription for all products
+        self._subscriptions: dict[str, set[str]] = {}
+
+    @property
+    def subscriptions(self) -> dict[str, set[str]]:
+        return self._subscriptions
+
+    def has_subscription(self, channel: str, product_id: str | None = None) -> bool:
+        product_ids = self._subscriptions.get(channel)
+        if product_ids is None:
+            return False
+        return product_id is None or product_id in product_ids
+
+    async def connect(self) -> None:
+        self._is_running = True
+        self._log.debug(f"Connecting to {self._base_url} websocket stream")
+
+        config = WebSocketConfig(
+            url=self._base_url,
+            handler=self._handler,
+            heartbeat=None,
+            headers=[],
+        )
+        client = await WebSocketClient.connect(
+            config=config,
+            post_reconnection=self.reconnect,
+        )
+        self._client = client
+        self._log.info(f"Connected to {self._base_url}", LogColor.BLUE)
+
+        await self._send_subscription("subscribe", _HEARTBEATS_CHANNEL, [])
+
+    def reconnect(self) -> None:
+        """
+        Reconnect the client to the server and resubscribe to all channels.
+        """
+        if not self._is_running or self._reconnecting:
+            return
+
+        self._log.warning(f"Trying to reconnect to {self._base_url}")
+        self._reconnecting = True
+        self._loop.create_task(self._reconnect_wrapper())
+
+    async def _reconnect_wrapper(self) -> None:
+        try:
+            await self._send_subscription("subscribe", _HEARTBEATS_CHANNEL, [])
+
+            self._log.warning(f"Resubscribing to {len(self._subscriptions)} channels")
+
+            for channel, product_ids in self._subscriptions.items():
+                await self._send_subscription("subscribe", channel, sorted(product_ids))
+
+            if self._handler_reconnect:
+                await self._handler_reconnect()
+
+            self._log.warning(f"Reconnected to {self._base_url}")
+        except Exception as e:
+            self._log.error(f"Reconnection failed: {e}")
+        finally:
+            self._reconnecting = False
+
+    async def disconnect(self) -> None:
+        self._is_running = False
+        self._reconnecting = False
+
+        if self._client is None:
+            self._log.warning("Cannot disconnect: not connected.")
+            return
+
+        try:
+            await self._client.disconnect()
+        except WebSocketClientError as e:
+            self._log.error(str(e))
+
+        self._client = None  # Dispose (will go out of scope)
+
+        self._log.info(f"Disconnected from {self._base_url}", LogColor.BLUE)
+
+    ################################################################################
+    # Subscriptions
+    ################################################################################
+
+    async def _subscribe(self, channel: str, product_id: str | None = None) -> None:
+        self._log.debug(f"Subscribing to {channel} {product_id or ''}")
+        if self.has_subscription(channel, product_id):
+            self._log.warning(f"Cannot subscribe '{channel}' {product_id or ''}: already subscribed")
+            return
+
+        product_ids = self._subscriptions.setdefault(channel, set())
+        if product_id is not None:
+            product_ids.add(product_id)
+
+        await self._send_subscription("subscribe", channel, [product_id] if product_id else [])
+
+    async def _unsubscribe(self, channel: str, product_id: str | None = None) -> None:
+        if not self.has_subscription(channel, product_id):
+            self._log.warning(f"Cannot unsubscribe '{channel}' {product_id or ''}: not subscribed")
+            return
+
+        product_ids = self._subscriptions[channel]
+        if product_id is not None:
+            product_ids.discard(product_id)
+        if not product_ids:
+            del self._subscriptions[channel]
+
+        await self._send_subscription("unsubscribe", channel, [product_id] if product_id else [])
+
+    async def _send_subscription(self, op: str, channel: str, product_ids: list[str]) -> None:
+        msg: dict[str, Any] = {"type": op, "channel": channel}
+        if product_ids:
+            msg["product_ids"] = product_ids
+        if self._signer is not None:
+            # Tokens expire after two minutes, so a new token is sent with each message
+            msg["jwt"] = self._signer.token(self._clock.timestamp_ms() // 1_000)
+
+        await self._send(msg)
+
+    async def _send(self, msg: dict[str, Any]) -> None:
+        if self._client is None:
+            self._log.error(f"Cannot send message {msg}: not connected")
+            return
+
+        encoded = msgspec_json.encode(msg)
+        self._log.debug(f"SENDING: {encoded!r}")
+
+        try:
+            await self._client.send_text(encoded)
+        except WebSocketClientError as e:
+            self._log.error(str(e))
+
+    ################################################################################
+    # Public
+    ################################################################################
+
+    async def subscribe_order_book(self, product_id: str) -> None:
+        await self._subscribe("level2", product_id)
+
+    async def subscribe_ticker(self, product_id: str) -> None:
+        await self._subscribe("ticker", product_id)
+
+    async def subscribe_trades(self, product_id: str) -> None:
+        await self._subscribe("market_trades", product_id)
+
+    async def unsubscribe_order_book(self, product_id: str) -> None:
+        await self._unsubscribe("level2", product_id)
+
+    async def unsubscribe_ticker(self, product_id: str) -> None:
+        await self._unsubscribe("ticker", product_id)
+
+    async def unsubscribe_trades(self, product_id: str) -> None:
+        await self._unsubscribe("market_trades", product_id)
+
+    ################################################################################
+    # Private
+    ################################################################################
+
+    async def subscribe_user(self) -> None:
+        # Order updates for all products
+        await self._subscribe("user")
@@ -0,0 +1,41 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from typing import Final
+
+from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.model.objects import Currency
+
+
+COINBASE_INTX: Final[str] = "COINBASE_INTX"
+COINBASE_INTX_VENUE: Final[Venue] = Venue(COINBASE_INTX)
+
+COINBASE_INTX_API_PATH: Final[str] = "/api/v1"
+
+# Portfolio collateral, margin and PnL are all valued in USDC
+COINBASE_INTX_SETTLEMENT_CURRENCY: Final[Currency] = Currency.from_str("USDC")
+
+# The maximum number of transfers for each page of `/transfers`
+COINBASE_INTX_MAX_TRANSFERS: Final[int] = 100
+
+COINBASE_INTX_RETRY_ERRORS: Final[set[int | str]] = {
+    429,  # Too many requests
+    500,  # Internal server error
+    502,  # Bad gateway
+    503,  # Service unavailable
+    504,  # Gateway timeout
+}
+
+COINBASE_INTX_RATE_LIMIT_KEY: Final[str] = "coinbase_intx:private"
@@ -0,0 +1,56 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from enum import Enum
+from enum import unique
+
+
+@unique
+class CoinbaseIntxTransferType(Enum):
+    DEPOSIT = "DEPOSIT"
+    WITHDRAW = "WITHDRAW"
+    REBATE = "REBATE"
+    STIPEND = "STIPEND"
+    INTERNAL = "INTERNAL"
+    FUNDING = "FUNDING"
+
+
+@unique
+class CoinbaseIntxTransferStatus(Enum):
+    NEW = "NEW"
+    STARTED = "STARTED"
+    PROCESSED = "PROCESSED"
+    FAILED = "FAILED"
+
+
+@unique
+class CoinbaseIntxMarginType(Enum):
+    CROSS = "CROSS"
+    ISOLATED = "ISOLATED"
+
+
+@unique
+class CoinbaseIntxLiquidationStatus(Enum):
+    NOT_LIQUIDATING = "NOT_LIQUIDATING"
+    LIQUIDATING = "LIQUIDATING"
+    AUTO_CLOSING = "AUTO_CLOSING"
+    LSP_ASSIGNMENT = "LSP_ASSIGNMENT"
+
+
+@unique
+class CoinbaseIntxAccountUpdateReason(Enum):
+    RECONCILIATION = "RECONCILIATION"
+    FUNDING = "FUNDING"
+    TRANSFER = "TRANSFER"
@@ -0,0 +1,35 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+import pandas as pd
+
+
+def format_timestamp(timestamp_ns: int) -> str:
+    """
+    Format the UNIX nanoseconds timestamp as an ISO 8601 timestamp (e.g. `2024-05-31T09:59:59.000000Z`).
+
+    Parameters
+    ----------
+    timestamp_ns : int
+        The UNIX timestamp in nanoseconds.
+
+    Returns
+    -------
+    str
+
+    """
+    return pd.Timestamp(timestamp_ns, tz="UTC").strftime("%Y-%m-%dT%H:%M:%S.%fZ")
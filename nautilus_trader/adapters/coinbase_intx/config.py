@@ -0,0 +1,54 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from nautilus_trader.config import LiveExecClientConfig
+from nautilus_trader.config import PositiveInt
+
+
+class CoinbaseIntxExecClientConfig(LiveExecClientConfig, frozen=True):
+    """
+    Configuration for ``CoinbaseIntxExecutionClient`` instances.
+
+    Parameters
+    ----------
+    api_key : str, optional
+        The Coinbase International API key.
+        If ``None`` then will source the `COINBASE_INTX_API_KEY` environment variable.
+    api_secret : str, optional
+        The base64 encoded API secret.
+        If ``None`` then will source the `COINBASE_INTX_API_SECRET` environment variable.
+    passphrase : str, optional
+        The passphrase of the API key.
+        If ``None`` then will source the `COINBASE_INTX_API_PASSPHRASE` environment variable.
+    portfolio_id : str, optional
+        The ID (or UUID) of the portfolio for the account.
+        If ``None`` then will source the `COINBASE_INTX_PORTFOLIO_ID` environment variable.
+    base_url_http : str, optional
+        The base URL for the HTTP client.
+    testnet : bool, default False
+        If the client is connecting to the Coinbase International sandbox.
+    update_account_interval_secs : PositiveInt, default 15
+        The interval (seconds) between portfolio margin updates, where new funding
+        payments and transfers are also requested.
+
+    """
+
+    api_key: str | None = None
+    api_secret: str | None = None
+    passphrase: str | None = None
+    portfolio_id: str | None = None
+    base_url_http: str | None = None
+    testnet: bool = False
+    update_account_interval_secs: PositiveInt = 15
@@ -0,0 +1,362 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+import asyncio
+from typing import TYPE_CHECKING
+from typing import Any
+
+from nautilus_trader.adapters.coinbase_intx.common.constants import COINBASE_INTX_VENUE
+from nautilus_trader.adapters.coinbase_intx.common.enums import CoinbaseIntxAccountUpdateReason
+from nautilus_trader.adapters.coinbase_intx.common.enums import CoinbaseIntxTransferStatus
+from nautilus_trader.adapters.coinbase_intx.common.parsing import format_timestamp
+from nautilus_trader.adapters.coinbase_intx.http.errors import CoinbaseIntxError
+from nautilus_trader.adapters.coinbase_intx.http.portfolio import CoinbaseIntxPortfolioHttpAPI
+from nautilus_trader.adapters.coinbase_intx.schemas.portfolio import parse_account_balances
+from nautilus_trader.common.enums import LogColor
+from nautilus_trader.live.execution_client import LiveExecutionClient
+from nautilus_trader.model.enums import AccountType
+from nautilus_trader.model.enums import OmsType
+from nautilus_trader.model.identifiers import AccountId
+from nautilus_trader.model.identifiers import ClientId
+
+
+if TYPE_CHECKING:
+    import pandas as pd
+
+    from nautilus_trader.adapters.coinbase_intx.config import CoinbaseIntxExecClientConfig
+    from nautilus_trader.adapters.coinbase_intx.http.client import CoinbaseIntxHttpClient
+    from nautilus_trader.adapters.coinbase_intx.schemas.portfolio import CoinbaseIntxTransfer
+    from nautilus_trader.cache.cache import Cache
+    from nautilus_trader.common.component import LiveClock
+    from nautilus_trader.common.component import MessageBus
+    from nautilus_trader.common.providers import InstrumentProvider
+    from nautilus_trader.execution.messages import CancelAllOrders
+    from nautilus_trader.execution.messages import CancelOrder
+    from nautilus_trader.execution.messages import ModifyOrder
+    from nautilus_trader.execution.messages import SubmitOrder
+    from nautilus_trader.execution.reports import FillReport
+    from nautilus_trader.execution.reports import OrderStatusReport
+    from nautilus_trader.execution.reports import PositionStatusReport
+    from nautilus_trader.model.identifiers import ClientOrderId
+    from nautilus_trader.model.identifiers import InstrumentId
+    from nautilus_trader.model.identifiers import VenueOrderId
+    from nautilus_trader.model.objects import AccountBalance
+    from nautilus_trader.model.objects import MarginBalance
+
+
+_PENDING_TRANSFER_STATUSES = (CoinbaseIntxTransferStatus.NEW, CoinbaseIntxTransferStatus.STARTED)
+_ORDER_ROUTING_NOT_SUPPORTED = "order routing not supported for Coinbase International"
+
+
+class CoinbaseIntxExecutionClient(LiveExecutionClient):
+    """
+    Provides an execution client for a Coinbase International Exchange portfolio.
+
+    The portfolio balances and margin are reconciled from the REST API on connect,
+    and then on the configured interval, as ``AccountState`` events for the margin
+    account. Collateral held for the margin of open positions is reported as locked,
+    so the free balance checked by the risk engine reflects the margin in use.
+
+    Funding payments and transfers are requested on the same interval, and each
+    processed transfer is applied to the balance of its asset and published as an
+    ``AccountState`` event, with the reason (``FUNDING`` or ``TRANSFER``) and the
+    transfer in the `info` of the event. The account is then reconciled from the
+    REST API, with the reason ``RECONCILIATION``.
+
+    Order routing is not supported by this client, so submitted orders are rejected,
+    and modify and cancel commands are rejected with the same reason.
+
+    Parameters
+    ----------
+    loop : asyncio.AbstractEventLoop
+        The event loop for the client.
+    client : CoinbaseIntxHttpClient
+        The Coinbase International HTTP client.
+    msgbus : MessageBus
+        The message bus for the client.
+    cache : Cache
+        The cache for the client.
+    clock : LiveClock
+        The clock for the client.
+    instrument_provider : InstrumentProvider
+        The instrument provider.
+    portfolio_id : str
+        The ID of the portfolio for the account.
+    config : CoinbaseIntxExecClientConfig
+        The configuration for the client.
+    name : str, optional
+        The custom client ID.
+
+    """
+
+    def __init__(
+        self,
+        loop: asyncio.AbstractEventLoop,
+        client: CoinbaseIntxHttpClient,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+        instrument_provider: InstrumentProvider,
+        portfolio_id: str,
+        config: CoinbaseIntxExecClientConfig,
+        name: str | None,
+    ) -> None:
+        super().__init__(
+            loop=loop,
+            client_id=ClientId(name or COINBASE_INTX_VENUE.value),
+            venue=COINBASE_INTX_VENUE,
+            oms_type=OmsType.NETTING,
+            instrument_provider=instrument_provider,
+            account_type=AccountType.MARGIN,
+            base_currency=None,
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+        )
+
+        # Configuration
+        self._portfolio_id = portfolio_id
+        self._update_account_interval_secs = config.update_account_interval_secs
+        self._log.info(f"{portfolio_id=}", LogColor.BLUE)
+        self._log.info(f"{config.update_account_interval_secs=}", LogColor.BLUE)
+
+        account_id = AccountId(f"{name or COINBASE_INTX_VENUE.value}-{portfolio_id}")
+        self._set_account_id(account_id)
+
+        # HTTP API
+        self._http_portfolio = CoinbaseIntxPortfolioHttpAPI(client=client, clock=clock)
+
+        self._update_account_task: asyncio.Task | None = None
+
+        # Transfers are applied to the last reconciled balances and margins
+        self._balances: list[AccountBalance] = []
+        self._margins: list[MarginBalance] = []
+
+        # Transfers are requested from the creation time of the oldest pending transfer
+        # (or the latest processed transfer), so processed transfers are tracked by ID
+        self._transfers_time_from: int = 0
+        self._transfers_processed: set[str] = set()
+
+    async def _connect(self) -> None:
+        # Transfers before connecting are reflected in the reconciled balances
+        self._transfers_time_from = self._clock.timestamp_ns()
+        await self._update_account_state()
+
+        self._update_account_task = self.create_task(
+            self._update_account(self._update_account_interval_secs),
+        )
+
+    async def _disconnect(self) -> None:
+        if self._update_account_task:
+            self._log.debug("Canceling task 'update_account'")
+            self._update_account_task.cancel()
+            self._update_account_task = None
+
+    # -- EXECUTION REPORTS ------------------------------------------------------------------------
+
+    async def generate_order_status_report(
+        self,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId | None = None,
+        venue_order_id: VenueOrderId | None = None,
+    ) -> OrderStatusReport | None:
+        # Orders are not routed through this client
+        return None
+
+    async def generate_order_status_reports(
+        self,
+        instrument_id: InstrumentId | None = None,
+        start: pd.Timestamp | None = None,
+        end: pd.Timestamp | None = None,
+        open_only: bool = False,
+    ) -> list[OrderStatusReport]:
+        self._log.info("Received 0 OrderStatusReports (order routing not supported)")
+        return []
+
+    async def generate_fill_reports(
+        self,
+        instrument_id: InstrumentId | None = None,
+        venue_order_id: VenueOrderId | None = None,
+        start: pd.Timestamp | None = None,
+        end: pd.Timestamp | None = None,
+    ) -> list[FillReport]:
+        self._log.info("Received 0 FillReports (order routing not supported)")
+        return []
+
+    async def generate_position_status_reports(
+        self,
+        instrument_id: InstrumentId | None = None,
+        start: pd.Timestamp | None = None,
+        end: pd.Timestamp | None = None,
+    ) -> list[PositionStatusReport]:
+        # The margin of open positions is reported with the portfolio account state
+        self._log.info("Received 0 PositionReports (order routing not supported)")
+        return []
+
+    # -- ACCOUNT ----------------------------------------------------------------------------------
+
+    async def _update_account(self, interval_secs: int) -> None:
+        try:
+            while True:
+                await asyncio.sleep(interval_secs)
+                for transfer in await self._fetch_new_transfers():
+                    self._handle_transfer(transfer)
+                await self._update_account_state()
+        except asyncio.CancelledError:
+            self._log.debug("Canceled task 'update_account'")
+
+    async def _fetch_new_transfers(self) -> list[CoinbaseIntxTransfer]:
+        try:
+            transfers = await self._http_portfolio.fetch_transfers(
+                portfolio_id=self._portfolio_id,
+                time_from=format_timestamp(self._transfers_time_from),
+            )
+        except CoinbaseIntxError as e:
+            self._log.error(f"Failed to request transfers: {e}")
+            return []
+
+        new_transfers: list[CoinbaseIntxTransfer] = []
+        pending_ts: list[int] = []
+        for transfer in sorted(transfers, key=lambda t: t.ts_created):
+            if transfer.transfer_uuid in self._transfers_processed:
+                continue
+
+            if transfer.status in _PENDING_TRANSFER_STATUSES:
+                pending_ts.append(transfer.ts_created)
+                continue
+
+            self._transfers_processed.add(transfer.transfer_uuid)
+            if not transfer.is_processed:
+                self._log.warning(f"Transfer {transfer.transfer_uuid} {transfer.status.value}")
+                continue
+
+            new_transfers.append(transfer)
+
+        if pending_ts:
+            self._transfers_time_from = min(pending_ts)
+        elif transfers:
+            self._transfers_time_from = max(t.ts_created for t in transfers)
+
+        return new_transfers
+
+    def _handle_transfer(self, transfer: CoinbaseIntxTransfer) -> None:
+        amount = transfer.parse_to_money()
+        if transfer.is_funding:
+            self._log.info(
+                f"Funding payment {amount.to_formatted_str()} for {transfer.instrument_symbol}",
+                LogColor.BLUE,
+            )
+        else:
+            self._log.info(
+                f"Transfer {transfer.transfer_type.value} {amount.to_formatted_str()}",
+                LogColor.BLUE,
+            )
+
+        balances = transfer.apply_to_balances(self._balances)
+        if not balances:
+            # The next reconciliation reflects the transfer
+            self._log.warning(f"No balances to apply transfer {transfer.transfer_uuid} to")
+            return
+
+        self._balances = balances
+        self.generate_account_state(
+            balances=balances,
+            margins=self._margins,
+            reported=True,
+            ts_event=transfer.ts_event,
+            info=transfer.parse_to_info(),
+        )
+
+    async def _update_account_state(self) -> None:
+        try:
+            balances, summary = await asyncio.gather(
+                self._http_portfolio.fetch_balances(self._portfolio_id),
+                self._http_portfolio.fetch_portfolio_summary(self._portfolio_id),
+            )
+            account_balances = parse_account_balances(balances)
+            if not account_balances:
+                self._log.warning("No balances for account state")
+                return
+
+            status = summary.liquidation_status
+            if summary.is_liquidating and status is not None:
+                self._log.warning(f"Portfolio {self._portfolio_id} is {status.value}")
+
+            info: dict[str, Any] = {
+                "reason": CoinbaseIntxAccountUpdateReason.RECONCILIATION.value,
+                **summary.parse_to_info(),
+            }
+
+            self._balances = account_balances
+            self._margins = [summary.parse_to_margin_balance()]
+            self.generate_account_state(
+                balances=self._balances,
+                margins=self._margins,
+                reported=True,
+                ts_event=self._clock.timestamp_ns(),
+                info=info,
+            )
+        except Exception as e:
+            self._log.error(f"Failed to generate AccountState: {e}")
+
+    # -- COMMAND HANDLERS -------------------------------------------------------------------------
+
+    async def _submit_order(self, command: SubmitOrder) -> None:
+        order = command.order
+        self.generate_order_rejected(
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=order.client_order_id,
+            reason=_ORDER_ROUTING_NOT_SUPPORTED,
+            ts_event=self._clock.timestamp_ns(),
+        )
+
+    async def _modify_order(self, command: ModifyOrder) -> None:
+        self.generate_order_modify_rejected(
+            strategy_id=command.strategy_id,
+            instrument_id=command.instrument_id,
+            client_order_id=command.client_order_id,
+            venue_order_id=command.venue_order_id,
+            reason=_ORDER_ROUTING_NOT_SUPPORTED,
+            ts_event=self._clock.timestamp_ns(),
+        )
+
+    async def _cancel_order(self, command: CancelOrder) -> None:
+        self.generate_order_cancel_rejected(
+            strategy_id=command.strategy_id,
+            instrument_id=command.instrument_id,
+            client_order_id=command.client_order_id,
+            venue_order_id=command.venue_order_id,
+            reason=_ORDER_ROUTING_NOT_SUPPORTED,
+            ts_event=self._clock.timestamp_ns(),
+        )
+
+    async def _cancel_all_orders(self, command: CancelAllOrders) -> None:
+        orders = self._cache.orders_open(
+            instrument_id=command.instrument_id,
+            strategy_id=command.strategy_id,
+            side=command.order_side,
+        )
+        for order in orders:
+            self.generate_order_cancel_rejected(
+                strategy_id=order.strategy_id,
+                instrument_id=order.instrument_id,
+                client_order_id=order.client_order_id,
+                venue_order_id=order.venue_order_id,
+                reason=_ORDER_ROUTING_NOT_SUPPORTED,
+                ts_event=self._clock.timestamp_ns(),
+            )
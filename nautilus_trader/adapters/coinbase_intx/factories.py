@@ -0,0 +1,139 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import asyncio
+from functools import lru_cache
+
+from nautilus_trader.adapters.coinbase_intx.common.constants import COINBASE_INTX_RATE_LIMIT_KEY
+from nautilus_trader.adapters.coinbase_intx.common.credentials import get_api_key
+from nautilus_trader.adapters.coinbase_intx.common.credentials import get_api_passphrase
+from nautilus_trader.adapters.coinbase_intx.common.credentials import get_api_secret
+from nautilus_trader.adapters.coinbase_intx.common.credentials import get_portfolio_id
+from nautilus_trader.adapters.coinbase_intx.common.urls import get_http_base_url
+from nautilus_trader.adapters.coinbase_intx.config import CoinbaseIntxExecClientConfig
+from nautilus_trader.adapters.coinbase_intx.execution import CoinbaseIntxExecutionClient
+from nautilus_trader.adapters.coinbase_intx.http.client import CoinbaseIntxHttpClient
+from nautilus_trader.cache.cache import Cache
+from nautilus_trader.common.component import LiveClock
+from nautilus_trader.common.component import MessageBus
+from nautilus_trader.common.providers import InstrumentProvider
+from nautilus_trader.core.nautilus_pyo3 import Quota
+from nautilus_trader.live.factories import LiveExecClientFactory
+
+
+@lru_cache(1)
+def get_cached_coinbase_intx_http_client(
+    clock: LiveClock,
+    api_key: str,
+    api_secret: str,
+    passphrase: str,
+    base_url: str,
+) -> CoinbaseIntxHttpClient:
+    """
+    Cache and return a Coinbase International HTTP client.
+
+    If a cached client with matching parameters already exists, the cached client will be returned.
+
+    Parameters
+    ----------
+    clock : LiveClock
+        The clock for the client.
+    api_key : str
+        The API key for the client.
+    api_secret : str
+        The base64 encoded API secret for the client.
+    passphrase : str
+        The passphrase of the API key.
+    base_url : str
+        The base URL for the API endpoints.
+
+    Returns
+    -------
+    CoinbaseIntxHttpClient
+
+    """
+    # Portfolio requests are only made on the account update interval, so a
+    # conservative quota is well within the private endpoint limits
+    ratelimiter_default_quota = Quota.rate_per_second(10)
+    ratelimiter_quotas: list[tuple[str, Quota]] = [
+        (COINBASE_INTX_RATE_LIMIT_KEY, Quota.rate_per_second(10)),
+    ]
+
+    return CoinbaseIntxHttpClient(
+        clock=clock,
+        api_key=api_key,
+        api_secret=api_secret,
+        passphrase=passphrase,
+        base_url=base_url,
+        ratelimiter_quotas=ratelimiter_quotas,
+        ratelimiter_default_quota=ratelimiter_default_quota,
+    )
+
+
+class CoinbaseIntxLiveExecClientFactory(LiveExecClientFactory):
+    """
+    Provides a Coinbase International live execution client factory.
+    """
+
+    @staticmethod
+    def create(  # type: ignore
+        loop: asyncio.AbstractEventLoop,
+        name: str,
+        config: CoinbaseIntxExecClientConfig,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+    ) -> CoinbaseIntxExecutionClient:
+        """
+        Create a new Coinbase International execution client.
+
+        Parameters
+        ----------
+        loop : asyncio.AbstractEventLoop
+            The event loop for the client.
+        name : str
+            The custom client ID.
+        config : CoinbaseIntxExecClientConfig
+            The client configuration.
+        msgbus : MessageBus
+            The message bus for the client.
+        cache : Cache
+            The cache for the client.
+        clock : LiveClock
+            The clock for the client.
+
+        Returns
+        -------
+        CoinbaseIntxExecutionClient
+
+        """
+        client: CoinbaseIntxHttpClient = get_cached_coinbase_intx_http_client(
+            clock=clock,
+            api_key=config.api_key or get_api_key(),
+            api_secret=config.api_secret or get_api_secret(),
+            passphrase=config.passphrase or get_api_passphrase(),
+            base_url=config.base_url_http or get_http_base_url(config.testnet),
+        )
+        return CoinbaseIntxExecutionClient(
+            loop=loop,
+            client=client,
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+            instrument_provider=InstrumentProvider(config=config.instrument_provider),
+            portfolio_id=config.portfolio_id or get_portfolio_id(),
+            config=config,
+            name=name,
+        )
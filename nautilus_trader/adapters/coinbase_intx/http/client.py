@@ -0,0 +1,222 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import base64
+import hashlib
+import hmac
+from typing import Any
+from urllib import parse
+
+import msgspec
+
+import nautilus_trader
+from nautilus_trader.adapters.coinbase_intx.common.constants import COINBASE_INTX_API_PATH
+from nautilus_trader.adapters.coinbase_intx.http.errors import parse_error
+from nautilus_trader.common.component import LiveClock
+from nautilus_trader.common.component import Logger
+from nautilus_trader.core.nautilus_pyo3 import HttpClient
+from nautilus_trader.core.nautilus_pyo3 import HttpMethod
+from nautilus_trader.core.nautilus_pyo3 import HttpResponse
+from nautilus_trader.core.nautilus_pyo3 import Quota
+
+
+HTTP_METHOD_STRINGS = {
+    HttpMethod.GET: "GET",
+    HttpMethod.POST: "POST",
+    HttpMethod.PUT: "PUT",
+    HttpMethod.DELETE: "DELETE",
+    HttpMethod.PATCH: "PATCH",
+}
+
+
+def coinbase_intx_sign(secret: str, message: str) -> str:
+    """
+    Return the base64 encoded HMAC-SHA256 signature of the given message.
+
+    The message is signed with the base64 decoded API secret.
+
+    """
+    digest = hmac.new(base64.b64decode(secret), message.encode(), hashlib.sha256).digest()
+    return base64.b64encode(digest).decode()
+
+
+class CoinbaseIntxHttpClient:
+    """
+    Provides a Coinbase International Exchange asynchronous HTTP client.
+
+    Requests are sent to `/api/v1/{path}`, and are authenticated with the API key,
+    passphrase and an HMAC-SHA256 signature of the timestamp, method, request path
+    (including the query string) and body.
+
+    Parameters
+    ----------
+    clock : LiveClock
+        The clock for the client.
+    api_key : str
+        The Coinbase International API key.
+    api_secret : str
+        The base64 encoded API secret.
+    passphrase : str
+        The passphrase of the API key.
+    base_url : str
+        The base endpoint URL for the client.
+    ratelimiter_quotas : list[tuple[str, Quota]], optional
+        The keyed rate limiter quotas for the client.
+    ratelimiter_default_quota : Quota, optional
+        The default rate limiter quota for the client.
+
+    """
+
+    def __init__(
+        self,
+        clock: LiveClock,
+        api_key: str,
+        api_secret: str,
+        passphrase: str,
+        base_url: str,
+        ratelimiter_quotas: list[tuple[str, Quota]] | None = None,
+        ratelimiter_default_quota: Quota | None = None,
+    ) -> None:
+        self._clock: LiveClock = clock
+        self._log: Logger = Logger(name=type(self).__name__)
+        self._api_key: str = api_key
+        self._api_secret: str = api_secret
+        self._passphrase: str = passphrase
+
+        self._base_url: str = base_url
+        self._headers: dict[str, Any] = {
+            "Content-Type": "application/json",
+            "User-Agent": nautilus_trader.USER_AGENT,
+            "CB-ACCESS-KEY": api_key,
+            "CB-ACCESS-PASSPHRASE": passphrase,
+        }
+        self._client = HttpClient(
+            keyed_quotas=ratelimiter_quotas or [],
+            default_quota=ratelimiter_default_quota,
+        )
+
+    @property
+    def base_url(self) -> str:
+        return self._base_url
+
+    @property
+    def api_key(self) -> str:
+        return self._api_key
+
+    @property
+    def api_secret(self) -> str:
+        return self._api_secret
+
+    @property
+    def passphrase(self) -> str:
+        return self._passphrase
+
+    async def send_request(
+        self,
+        http_method: HttpMethod,
+        path: str,
+        params: dict[str, Any] | None = None,
+        payload: dict[str, Any] | None = None,
+        ratelimiter_keys: list[str] | None = None,
+    ) -> bytes:
+        """
+        Send a signed request for the API `path` (e.g. `portfolios/{portfolio}/balances`).
+
+        Parameters
+        ----------
+        http_method : HttpMethod
+            The HTTP method for the request.
+        path : str
+            The path relative to `/api/v1`.
+        params : dict[str, Any], optional
+            The query parameters (``None`` values are excluded).
+        payload : dict[str, Any], optional
+            The JSON body of the request.
+        ratelimiter_keys : list[str], optional
+            The rate limiter keys for the request.
+
+        Returns
+        -------
+        bytes
+            The raw JSON response.
+
+        Raises
+        ------
+        CoinbaseIntxError
+            If the request fails.
+
+        """
+        url_path = f"{COINBASE_INTX_API_PATH}/{path}"
+        if params:
+            query = parse.urlencode({k: v for k, v in params.items() if v is not None})
+            if query:
+                url_path += "?" + query
+
+        body = msgspec.json.encode(payload) if payload is not None else None
+        timestamp = str(self._clock.timestamp_ns() // 1_000_000_000)
+        signature = self.sign(
+            timestamp=timestamp,
+            http_method=http_method,
+            url_path=url_path,
+            body=body.decode() if body is not None else "",
+        )
+        headers = {
+            **self._headers,
+            "CB-ACCESS-TIMESTAMP": timestamp,
+            "CB-ACCESS-SIGN": signature,
+        }
+
+        response: HttpResponse = await self._client.request(
+            http_method,
+            self._base_url + url_path,
+            headers,
+            body,
+            ratelimiter_keys,
+        )
+
+        response_body = response.body
+
+        if response.status >= 400:
+            try:
+                decoded = msgspec.json.decode(response_body) if response_body else None
+            except msgspec.DecodeError:
+                decoded = response_body.decode()
+
+            raise parse_error(response.status, decoded)
+
+        return response_body
+
+    def sign(self, timestamp: str, http_method: HttpMethod, url_path: str, body: str) -> str:
+        """
+        Return the signature for a request.
+
+        Parameters
+        ----------
+        timestamp : str
+            The UNIX timestamp (seconds) of the request.
+        http_method : HttpMethod
+            The HTTP method of the request.
+        url_path : str
+            The request path including the query string (e.g. `/api/v1/portfolios`).
+        body : str
+            The JSON body of the request (empty for no body).
+
+        Returns
+        -------
+        str
+
+        """
+        message = timestamp + HTTP_METHOD_STRINGS[http_method] + url_path + body
+        return coinbase_intx_sign(self._api_secret, message)
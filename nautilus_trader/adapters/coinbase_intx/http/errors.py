@@ -0,0 +1,82 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from typing import Any
+
+from nautilus_trader.adapters.coinbase_intx.common.constants import COINBASE_INTX_RETRY_ERRORS
+
+
+class CoinbaseIntxError(Exception):
+    """
+    Represents Coinbase International specific errors.
+
+    The `code` is the HTTP status code, and the `title` the error title of the response.
+
+    """
+
+    def __init__(
+        self,
+        code: int | str | None,
+        message: str | None,
+        title: str | None = None,
+    ) -> None:
+        super().__init__(message)
+        self.code = code
+        self.message = message
+        self.title = title
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}(code={self.code}, title={self.title}, message='{self.message}')"
+        )
+
+
+def parse_error(status: int, body: Any) -> CoinbaseIntxError:
+    """
+    Return the error for the decoded Coinbase International error response `body`.
+
+    Errors are reported as `{"title": ..., "status": ..., "detail": ...}`, where the
+    optional `detail` has further detail of the error.
+
+    """
+    if isinstance(body, dict) and ("title" in body or "detail" in body):
+        title = body.get("title")
+        message = body.get("detail") or title
+        return CoinbaseIntxError(
+            code=status,
+            message=message,
+            title=title if isinstance(title, str) else None,
+        )
+    return CoinbaseIntxError(code=status, message=body)
+
+
+def should_retry(error: BaseException) -> bool:
+    """
+    Determine if a retry should be attempted based on the error code.
+
+    Parameters
+    ----------
+    error : BaseException
+        The error to check.
+
+    Returns
+    -------
+    bool
+        True if should retry, otherwise False.
+
+    """
+    if isinstance(error, CoinbaseIntxError):
+        return error.code in COINBASE_INTX_RETRY_ERRORS
+    return False
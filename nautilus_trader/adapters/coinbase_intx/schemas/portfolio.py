@@ -0,0 +1,286 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from decimal import Decimal
+from typing import Any
+
+import msgspec
+import pandas as pd
+
+from nautilus_trader.adapters.coinbase_intx.common.constants import COINBASE_INTX_SETTLEMENT_CURRENCY
+from nautilus_trader.adapters.coinbase_intx.common.enums import CoinbaseIntxAccountUpdateReason
+from nautilus_trader.adapters.coinbase_intx.common.enums import CoinbaseIntxLiquidationStatus
+from nautilus_trader.adapters.coinbase_intx.common.enums import CoinbaseIntxMarginType
+from nautilus_trader.adapters.coinbase_intx.common.enums import CoinbaseIntxTransferStatus
+from nautilus_trader.adapters.coinbase_intx.common.enums import CoinbaseIntxTransferType
+from nautilus_trader.model.objects import AccountBalance
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import MarginBalance
+from nautilus_trader.model.objects import Money
+
+
+def _quantize(value: Decimal, currency: Currency) -> Decimal:
+    # Amounts are rounded to the currency precision, so the balance parts sum exactly
+    return value.quantize(Decimal(1).scaleb(-currency.precision))
+
+
+################################################################################
+# Balances
+################################################################################
+
+
+class CoinbaseIntxBalance(msgspec.Struct, frozen=True):
+    """
+    Represents the balance of an asset in a Coinbase International portfolio.
+
+    The `max_withdraw_amount` excludes the holds for open orders and pending
+    transfers, and the collateral required for the margin of open positions.
+
+    """
+
+    asset_id: str
+    asset_name: str
+    quantity: str
+    hold: str = "0"
+    transfer_hold: str | None = None
+    collateral_value: str | None = None
+    max_withdraw_amount: str | None = None
+    loan: str | None = None
+
+    @property
+    def is_empty(self) -> bool:
+        return Decimal(self.quantity) == 0 and Decimal(self.hold) == 0
+
+    def parse_to_account_balance(self) -> AccountBalance:
+        currency = Currency.from_str(self.asset_name)
+
+        # Borrowed assets have a negative quantity, and are reported as the portfolio borrow
+        total = _quantize(max(Decimal(self.quantity), Decimal(0)), currency)
+        if self.max_withdraw_amount is not None:
+            free = Decimal(self.max_withdraw_amount)
+        else:
+            free = total - Decimal(self.hold)
+        free = _quantize(min(max(free, Decimal(0)), total), currency)
+
+        return AccountBalance(
+            total=Money(total, currency),
+            locked=Money(total - free, currency),
+            free=Money(free, currency),
+        )
+
+
+def parse_account_balances(balances: list[CoinbaseIntxBalance]) -> list[AccountBalance]:
+    """
+    Parse the portfolio balances into account balances, skipping assets with no balance.
+
+    Parameters
+    ----------
+    balances : list[CoinbaseIntxBalance]
+        The portfolio balances to parse.
+
+    Returns
+    -------
+    list[AccountBalance]
+
+    """
+    return [b.parse_to_account_balance() for b in balances if not b.is_empty]
+
+
+################################################################################
+# Portfolio summary
+################################################################################
+
+
+class CoinbaseIntxPortfolioSummary(msgspec.Struct, frozen=True):
+    """
+    Represents the margin summary of a Coinbase International portfolio.
+
+    The `portfolio_initial_margin` and `portfolio_maintenance_margin` are margin
+    rates, where the `portfolio_im_notional` and `portfolio_mm_notional` are the
+    margin requirements (in USDC) of the open positions and orders.
+
+    """
+
+    collateral: str
+    unrealized_pnl: str
+    portfolio_initial_margin: str
+    portfolio_im_notional: str
+    portfolio_maintenance_margin: str
+    portfolio_mm_notional: str
+    pending_fees: str = "0"
+    borrow: str = "0"
+    position_notional: str | None = None
+    open_position_notional: str | None = None
+    liquidation_percentage: str | None = None
+    liquidation_buffer: str | None = None
+    margin_type: CoinbaseIntxMarginType | None = None
+    liquidation_status: CoinbaseIntxLiquidationStatus | None = None
+
+    @property
+    def is_liquidating(self) -> bool:
+        return self.liquidation_status not in (
+            None,
+            CoinbaseIntxLiquidationStatus.NOT_LIQUIDATING,
+        )
+
+    def parse_to_margin_balance(self) -> MarginBalance:
+        currency = COINBASE_INTX_SETTLEMENT_CURRENCY
+        initial = max(Decimal(self.portfolio_im_notional), Decimal(0))
+        maintenance = max(Decimal(self.portfolio_mm_notional), Decimal(0))
+        return MarginBalance(
+            initial=Money(initial, currency),
+            maintenance=Money(maintenance, currency),
+        )
+
+    def parse_to_info(self) -> dict[str, Any]:
+        return {
+            "collateral": self.collateral,
+            "unrealized_pnl": self.unrealized_pnl,
+            "borrow": self.borrow,
+            "pending_fees": self.pending_fees,
+            "initial_margin_rate": self.portfolio_initial_margin,
+            "maintenance_margin_rate": self.portfolio_maintenance_margin,
+            "liquidation_buffer": self.liquidation_buffer,
+            "liquidation_status": (
+                self.liquidation_status.value if self.liquidation_status is not None else None
+            ),
+        }
+
+
+################################################################################
+# Transfers
+################################################################################
+
+
+class CoinbaseIntxPortfolioRef(msgspec.Struct, frozen=True):
+    id: str
+    uuid: str | None = None
+    name: str | None = None
+
+
+class CoinbaseIntxTransfer(msgspec.Struct, frozen=True):
+    """
+    Represents a transfer to or from a Coinbase International portfolio.
+
+    Funding payments of perpetual positions are reported as transfers of type
+    `FUNDING`, with the `amount` received (negative when paid) and the instrument.
+
+    """
+
+    transfer_uuid: str
+    transfer_type: CoinbaseIntxTransferType
+    amount: str
+    asset: str
+    status: CoinbaseIntxTransferStatus
+    created_at: str
+    updated_at: str | None = None
+    from_portfolio: CoinbaseIntxPortfolioRef | None = None
+    to_portfolio: CoinbaseIntxPortfolioRef | None = None
+    instrument_id: str | None = None
+    instrument_symbol: str | None = None
+    position_id: str | None = None
+
+    @property
+    def is_processed(self) -> bool:
+        return self.status == CoinbaseIntxTransferStatus.PROCESSED
+
+    @property
+    def is_funding(self) -> bool:
+        return self.transfer_type == CoinbaseIntxTransferType.FUNDING
+
+    @property
+    def ts_created(self) -> int:
+        return pd.Timestamp(self.created_at).value
+
+    @property
+    def ts_event(self) -> int:
+        return pd.Timestamp(self.updated_at or self.created_at).value
+
+    @property
+    def update_reason(self) -> CoinbaseIntxAccountUpdateReason:
+        if self.is_funding:
+            return CoinbaseIntxAccountUpdateReason.FUNDING
+        return CoinbaseIntxAccountUpdateReason.TRANSFER
+
+    def parse_to_money(self) -> Money:
+        return Money(Decimal(self.amount), Currency.from_str(self.asset))
+
+    def apply_to_balances(self, balances: list[AccountBalance]) -> list[AccountBalance]:
+        """
+        Return the `balances` with the transfer amount applied to the balance of the asset.
+
+        The amount is received into (or paid from) the free balance, where a balance
+        is added for an asset received with no balance.
+
+        Parameters
+        ----------
+        balances : list[AccountBalance]
+            The balances to apply the transfer to.
+
+        Returns
+        -------
+        list[AccountBalance]
+
+        """
+        currency = Currency.from_str(self.asset)
+        amount = Decimal(self.amount)
+
+        applied: list[AccountBalance] = []
+        for balance in balances:
+            if balance.currency != currency:
+                applied.append(balance)
+                continue
+            total = _quantize(max(balance.total.as_decimal() + amount, Decimal(0)), currency)
+            free = min(max(balance.free.as_decimal() + amount, Decimal(0)), total)
+            free = _quantize(free, currency)
+            applied.append(
+                AccountBalance(
+                    total=Money(total, currency),
+                    locked=Money(total - free, currency),
+                    free=Money(free, currency),
+                ),
+            )
+
+        if amount > 0 and not any(b.currency == currency for b in balances):
+            received = Money(_quantize(amount, currency), currency)
+            applied.append(
+                AccountBalance(total=received, locked=Money(0, currency), free=received),
+            )
+
+        return applied
+
+    def parse_to_info(self) -> dict[str, Any]:
+        info: dict[str, Any] = {
+            "reason": self.update_reason.value,
+            "transfer_id": self.transfer_uuid,
+            "transfer_type": self.transfer_type.value,
+            "amount": self.amount,
+            "asset": self.asset,
+        }
+        if self.instrument_symbol is not None:
+            info["instrument_symbol"] = self.instrument_symbol
+        return info
+
+
+class CoinbaseIntxPagination(msgspec.Struct, frozen=True):
+    result_limit: int
+    result_offset: int
+
+
+class CoinbaseIntxTransfers(msgspec.Struct, frozen=True):
+    pagination: CoinbaseIntxPagination
+    results: list[CoinbaseIntxTransfer]
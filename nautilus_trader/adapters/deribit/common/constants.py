@@ -0,0 +1,53 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from typing import Final
+
+from nautilus_trader.model.identifiers import Venue
+
+
+DERIBIT: Final[str] = "DERIBIT"
+DERIBIT_VENUE: Final[Venue] = Venue(DERIBIT)
+
+# The currencies instruments are loaded for by default (inverse contracts are settled in
+# `BTC` and `ETH`, and linear contracts in `USDC`)
+DERIBIT_DEFAULT_CURRENCIES: Final[tuple[str, ...]] = ("BTC", "ETH", "USDC")
+
+# The instrument kinds loaded by default
+DERIBIT_DEFAULT_INSTRUMENT_KINDS: Final[tuple[str, ...]] = (
+    "future",
+    "option",
+    "future_combo",
+    "option_combo",
+)
+
+# Perpetuals are listed with an expiration far in the future (year 3000)
+DERIBIT_PERPETUAL_EXPIRATION_MS: Final[int] = 32_503_708_800_000
+
+# The delay after an expiry before the instrument chain is reloaded, so the
+# expired instruments are settled and new expiries are listed
+DERIBIT_EXPIRY_RELOAD_DELAY_SECS: Final[int] = 60
+
+DERIBIT_RETRY_ERRORS: Final[set[int | str]] = {
+    10028,  # Too many requests
+    10040,  # Retry (the request can not be processed right now)
+    429,  # Too many requests (HTTP)
+    502,  # Bad gateway
+    503,  # Service unavailable
+    504,  # Gateway timeout
+}
+
+DERIBIT_REST_RATE_LIMIT_KEY: Final[str] = "deribit:rest"
+DERIBIT_ORDER_RATE_LIMIT_KEY: Final[str] = "deribit:order"
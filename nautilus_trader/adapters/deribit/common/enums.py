@@ -0,0 +1,194 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from enum import Enum
+from enum import unique
+
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import TimeInForce
+from nautilus_trader.model.enums import TriggerType
+from nautilus_trader.model.enums import trigger_type_to_str
+
+
+@unique
+class DeribitInstrumentKind(Enum):
+    FUTURE = "future"
+    OPTION = "option"
+    SPOT = "spot"
+    FUTURE_COMBO = "future_combo"
+    OPTION_COMBO = "option_combo"
+
+
+@unique
+class DeribitOptionType(Enum):
+    CALL = "call"
+    PUT = "put"
+
+
+@unique
+class DeribitDirection(Enum):
+    BUY = "buy"
+    SELL = "sell"
+    ZERO = "zero"  # Flat positions
+
+
+@unique
+class DeribitOrderType(Enum):
+    LIMIT = "limit"
+    MARKET = "market"
+    STOP_LIMIT = "stop_limit"
+    STOP_MARKET = "stop_market"
+    TAKE_LIMIT = "take_limit"
+    TAKE_MARKET = "take_market"
+    MARKET_LIMIT = "market_limit"
+    TRAILING_STOP = "trailing_stop"
+
+
+@unique
+class DeribitTimeInForce(Enum):
+    GTC = "good_til_cancelled"
+    GTD = "good_til_day"
+    FOK = "fill_or_kill"
+    IOC = "immediate_or_cancel"
+
+
+@unique
+class DeribitOrderState(Enum):
+    OPEN = "open"
+    FILLED = "filled"
+    REJECTED = "rejected"
+    CANCELLED = "cancelled"
+    UNTRIGGERED = "untriggered"
+
+
+@unique
+class DeribitTriggerType(Enum):
+    INDEX_PRICE = "index_price"
+    MARK_PRICE = "mark_price"
+    LAST_PRICE = "last_price"
+
+
+@unique
+class DeribitLiquidity(Enum):
+    MAKER = "M"
+    TAKER = "T"
+
+
+def check_dict_keys(key, data):
+    try:
+        return data[key]
+    except KeyError as e:
+        raise RuntimeError(
+            f"Unrecognized Deribit {key} not found in {data}",
+        ) from e
+
+
+class DeribitEnumParser:
+    def __init__(self) -> None:
+        self.deribit_to_nautilus_order_side = {
+            DeribitDirection.BUY: OrderSide.BUY,
+            DeribitDirection.SELL: OrderSide.SELL,
+        }
+        self.nautilus_to_deribit_order_side = {
+            b: a for a, b in self.deribit_to_nautilus_order_side.items()
+        }
+        self.deribit_to_nautilus_order_type = {
+            DeribitOrderType.LIMIT: OrderType.LIMIT,
+            DeribitOrderType.MARKET: OrderType.MARKET,
+            DeribitOrderType.STOP_LIMIT: OrderType.STOP_LIMIT,
+            DeribitOrderType.STOP_MARKET: OrderType.STOP_MARKET,
+            DeribitOrderType.TAKE_LIMIT: OrderType.LIMIT_IF_TOUCHED,
+            DeribitOrderType.TAKE_MARKET: OrderType.MARKET_IF_TOUCHED,
+            DeribitOrderType.MARKET_LIMIT: OrderType.MARKET_TO_LIMIT,
+            DeribitOrderType.TRAILING_STOP: OrderType.TRAILING_STOP_MARKET,
+        }
+        self.nautilus_to_deribit_order_type = {
+            OrderType.LIMIT: DeribitOrderType.LIMIT,
+            OrderType.MARKET: DeribitOrderType.MARKET,
+            OrderType.STOP_LIMIT: DeribitOrderType.STOP_LIMIT,
+            OrderType.STOP_MARKET: DeribitOrderType.STOP_MARKET,
+            OrderType.LIMIT_IF_TOUCHED: DeribitOrderType.TAKE_LIMIT,
+            OrderType.MARKET_IF_TOUCHED: DeribitOrderType.TAKE_MARKET,
+        }
+        self.deribit_to_nautilus_time_in_force = {
+            DeribitTimeInForce.GTC: TimeInForce.GTC,
+            DeribitTimeInForce.GTD: TimeInForce.DAY,
+            DeribitTimeInForce.FOK: TimeInForce.FOK,
+            DeribitTimeInForce.IOC: TimeInForce.IOC,
+        }
+        self.nautilus_to_deribit_time_in_force = {
+            b: a for a, b in self.deribit_to_nautilus_time_in_force.items()
+        }
+        self.deribit_to_nautilus_order_status = {
+            DeribitOrderState.OPEN: OrderStatus.ACCEPTED,
+            DeribitOrderState.UNTRIGGERED: OrderStatus.ACCEPTED,
+            DeribitOrderState.FILLED: OrderStatus.FILLED,
+            DeribitOrderState.CANCELLED: OrderStatus.CANCELED,
+            DeribitOrderState.REJECTED: OrderStatus.REJECTED,
+        }
+        self.deribit_to_nautilus_trigger_type = {
+            DeribitTriggerType.INDEX_PRICE: TriggerType.INDEX_PRICE,
+            DeribitTriggerType.MARK_PRICE: TriggerType.MARK_PRICE,
+            DeribitTriggerType.LAST_PRICE: TriggerType.LAST_PRICE,
+        }
+        self.nautilus_to_deribit_trigger_type = {
+            b: a for a, b in self.deribit_to_nautilus_trigger_type.items()
+        }
+
+    def parse_deribit_order_side(self, direction: DeribitDirection) -> OrderSide:
+        return check_dict_keys(direction, self.deribit_to_nautilus_order_side)
+
+    def parse_nautilus_order_side(self, order_side: OrderSide) -> DeribitDirection:
+        return check_dict_keys(order_side, self.nautilus_to_deribit_order_side)
+
+    def parse_deribit_order_type(self, order_type: DeribitOrderType) -> OrderType:
+        return check_dict_keys(order_type, self.deribit_to_nautilus_order_type)
+
+    def parse_nautilus_order_type(self, order_type: OrderType) -> DeribitOrderType:
+        return check_dict_keys(order_type, self.nautilus_to_deribit_order_type)
+
+    def parse_deribit_time_in_force(self, time_in_force: DeribitTimeInForce) -> TimeInForce:
+        return check_dict_keys(time_in_force, self.deribit_to_nautilus_time_in_force)
+
+    def parse_nautilus_time_in_force(self, time_in_force: TimeInForce) -> DeribitTimeInForce:
+        return check_dict_keys(time_in_force, self.nautilus_to_deribit_time_in_force)
+
+    def parse_deribit_order_status(
+        self,
+        order_state: DeribitOrderState,
+        is_partially_filled: bool,
+    ) -> OrderStatus:
+        order_status = check_dict_keys(order_state, self.deribit_to_nautilus_order_status)
+        if order_status == OrderStatus.ACCEPTED and is_partially_filled:
+            return OrderStatus.PARTIALLY_FILLED
+        return order_status
+
+    def parse_deribit_trigger_type(self, trigger: DeribitTriggerType | None) -> TriggerType:
+        if trigger is None:
+            return TriggerType.NO_TRIGGER
+        return check_dict_keys(trigger, self.deribit_to_nautilus_trigger_type)
+
+    def parse_nautilus_trigger_type(self, trigger_type: TriggerType) -> DeribitTriggerType:
+        # Trigger orders are triggered by the last price unless specified
+        if trigger_type == TriggerType.DEFAULT:
+            return DeribitTriggerType.LAST_PRICE
+        try:
+            return self.nautilus_to_deribit_trigger_type[trigger_type]
+        except KeyError as e:
+            raise RuntimeError(
+                f"unrecognized Deribit trigger type, was {trigger_type_to_str(trigger_type)}",  # pragma: no cover
+            ) from e
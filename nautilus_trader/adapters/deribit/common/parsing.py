@@ -0,0 +1,127 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from decimal import Decimal
+
+from nautilus_trader.model.enums import AggressorSide
+from nautilus_trader.model.enums import InstrumentClass
+from nautilus_trader.model.instruments import Instrument
+from nautilus_trader.model.objects import Quantity
+
+
+# Instrument classes with whole contracts, where each contract is an `amount`
+# (the instrument multiplier) of the underlying
+_CONTRACT_INSTRUMENT_CLASSES = (
+    InstrumentClass.OPTION,
+    InstrumentClass.OPTION_SPREAD,
+    InstrumentClass.FUTURES_SPREAD,
+)
+
+
+def to_decimal(value: float) -> Decimal:
+    """
+    Return the decimal for the given value, converted through its shortest repr.
+
+    Parameters
+    ----------
+    value : float
+        The value.
+
+    Returns
+    -------
+    Decimal
+
+    """
+    return Decimal(str(value))
+
+
+def decimal_places(value: Decimal) -> int:
+    """
+    Return the number of decimal places for the given value.
+
+    Parameters
+    ----------
+    value : Decimal
+        The value.
+
+    Returns
+    -------
+    int
+
+    """
+    exponent = value.normalize().as_tuple().exponent
+    assert isinstance(exponent, int)  # Finite values only
+    return max(-exponent, 0)
+
+
+def amount_to_quantity(amount: float, instrument: Instrument) -> Quantity:
+    """
+    Return the Nautilus quantity for the given Deribit `amount` of the instrument.
+
+    Amounts of futures and perpetuals are in USD for inverse contracts and in the base
+    currency for linear contracts, and map directly to quantities. Option and combo
+    quantities are in contracts of the minimum trade amount (the instrument multiplier).
+
+    Parameters
+    ----------
+    amount : float
+        The Deribit amount.
+    instrument : Instrument
+        The instrument for the amount.
+
+    Returns
+    -------
+    Quantity
+
+    """
+    value = to_decimal(amount)
+    if instrument.instrument_class in _CONTRACT_INSTRUMENT_CLASSES:
+        value /= instrument.multiplier.as_decimal()
+    return instrument.make_qty(value)
+
+
+def quantity_to_amount(quantity: Quantity, instrument: Instrument) -> str:
+    """
+    Return the Deribit `amount` for the given Nautilus quantity of the instrument.
+
+    Parameters
+    ----------
+    quantity : Quantity
+        The Nautilus quantity.
+    instrument : Instrument
+        The instrument for the quantity.
+
+    Returns
+    -------
+    str
+
+    """
+    value = quantity.as_decimal()
+    if instrument.instrument_class in _CONTRACT_INSTRUMENT_CLASSES:
+        value *= instrument.multiplier.as_decimal()
+    return f"{value.normalize():f}"
+
+
+def parse_aggressor_side(direction: str) -> AggressorSide:
+    # The direction is the side of the taker order
+    match direction:
+        case "buy":
+            return AggressorSide.BUYER
+        case "sell":
+            return AggressorSide.SELLER
+        case _:
+            raise ValueError(f"Invalid aggressor side value, was '{direction}'")
@@ -0,0 +1,81 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from nautilus_trader.adapters.deribit.common.constants import DERIBIT_VENUE
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import Symbol
+
+
+class DeribitSymbol(str):
+    """
+    Represents a Deribit instrument name.
+
+    For example `BTC-PERPETUAL`, `BTC-27DEC24` (future), `BTC-27DEC24-100000-C` (option),
+    `BTC_USDC-PERPETUAL` (linear perpetual) or `BTC-FS-27DEC24_PERP` (combo).
+
+    """
+
+    def __new__(cls, symbol: str) -> DeribitSymbol:  # noqa: PYI034
+        PyCondition.valid_string(symbol, "symbol")
+        return super().__new__(cls, symbol.upper())
+
+    @property
+    def currency(self) -> str:
+        """
+        Return the currency the instrument is listed under (e.g. `BTC` or `USDC`).
+
+        Linear instruments (e.g. `BTC_USDC-PERPETUAL`) are listed under the quote currency.
+
+        Returns
+        -------
+        str
+
+        """
+        prefix = self.split("-")[0]
+        return prefix.split("_")[1] if "_" in prefix else prefix
+
+    @property
+    def strategy_type(self) -> str:
+        """
+        Return the strategy type of a combo (e.g. `FS` for a future spread).
+
+        Returns
+        -------
+        str
+
+        Raises
+        ------
+        ValueError
+            If the symbol is not a combo instrument name.
+
+        """
+        parts = self.split("-")
+        if len(parts) < 3:
+            raise ValueError(f"invalid Deribit combo instrument name, was '{self}'")
+        return parts[1]
+
+    def to_instrument_id(self) -> InstrumentId:
+        """
+        Parse the Deribit instrument name into a Nautilus instrument ID.
+
+        Returns
+        -------
+        InstrumentId
+
+        """
+        return InstrumentId(Symbol(str(self)), DERIBIT_VENUE)
@@ -0,0 +1,93 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from nautilus_trader.adapters.deribit.common.constants import DERIBIT_DEFAULT_CURRENCIES
+from nautilus_trader.config import LiveDataClientConfig
+from nautilus_trader.config import LiveExecClientConfig
+from nautilus_trader.config import PositiveFloat
+from nautilus_trader.config import PositiveInt
+
+
+class DeribitDataClientConfig(LiveDataClientConfig, frozen=True):
+    """
+    Configuration for ``DeribitDataClient`` instances.
+
+    Market data is public, so no credentials are required.
+
+    Parameters
+    ----------
+    base_url_http : str, optional
+        The base URL for the HTTP client.
+    base_url_ws : str, optional
+        The base URL for the WebSocket client.
+    testnet : bool, default False
+        If the client is connecting to the Deribit testnet.
+    update_instruments_interval_mins: PositiveInt or None, default 60
+        The interval (minutes) between reloading instruments from the venue.
+        Instruments are also reloaded shortly after each expiry (even if ``None``), so
+        option chains and futures roll as instruments expire and new expiries are listed.
+    include_block_trades : bool, default True
+        If block trades (negotiated off the book) are included in trade ticks.
+
+    """
+
+    base_url_http: str | None = None
+    base_url_ws: str | None = None
+    testnet: bool = False
+    update_instruments_interval_mins: PositiveInt | None = 60
+    include_block_trades: bool = True
+
+
+class DeribitExecClientConfig(LiveExecClientConfig, frozen=True):
+    """
+    Configuration for ``DeribitExecutionClient`` instances.
+
+    Parameters
+    ----------
+    api_key : str, optional
+        The Deribit API client ID.
+        If ``None`` then will source the `DERIBIT_API_KEY` or
+        `DERIBIT_TESTNET_API_KEY` environment variables.
+    api_secret : str, optional
+        The Deribit API client secret.
+        If ``None`` then will source the `DERIBIT_API_SECRET` or
+        `DERIBIT_TESTNET_API_SECRET` environment variables.
+    base_url_http : str, optional
+        The base URL for the HTTP client.
+    base_url_ws : str, optional
+        The base URL for the WebSocket client.
+    testnet : bool, default False
+        If the client is connecting to the Deribit testnet.
+    currencies : tuple[str, ...], default ("BTC", "ETH", "USDC")
+        The currencies to report account state, positions and orders for.
+    max_retries : PositiveInt, optional
+        The maximum number of times a submit, cancel or modify order request will be retried.
+    retry_delay : PositiveFloat, optional
+        The delay (seconds) between retries. Short delays with frequent retries may result in account bans.
+
+    Warnings
+    --------
+    A short `retry_delay` with frequent retries may result in account bans.
+
+    """
+
+    api_key: str | None = None
+    api_secret: str | None = None
+    base_url_http: str | None = None
+    base_url_ws: str | None = None
+    testnet: bool = False
+    currencies: tuple[str, ...] = DERIBIT_DEFAULT_CURRENCIES
+    max_retries: PositiveInt | None = None
+    retry_delay: PositiveFloat | None = None
@@ -0,0 +1,371 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+import asyncio
+from typing import TYPE_CHECKING
+
+import msgspec
+
+from nautilus_trader.adapters.deribit.common.constants import DERIBIT_EXPIRY_RELOAD_DELAY_SECS
+from nautilus_trader.adapters.deribit.common.constants import DERIBIT_VENUE
+from nautilus_trader.adapters.deribit.common.symbol import DeribitSymbol
+from nautilus_trader.adapters.deribit.schemas.ws import DeribitWsBookMsg
+from nautilus_trader.adapters.deribit.schemas.ws import DeribitWsMessageGeneral
+from nautilus_trader.adapters.deribit.schemas.ws import DeribitWsQuoteMsg
+from nautilus_trader.adapters.deribit.schemas.ws import DeribitWsTradesMsg
+from nautilus_trader.adapters.deribit.websocket.client import DeribitWebSocketClient
+from nautilus_trader.common.enums import LogColor
+from nautilus_trader.core.datetime import nanos_to_secs
+from nautilus_trader.data.messages import RequestBars
+from nautilus_trader.data.messages import RequestInstrument
+from nautilus_trader.data.messages import RequestInstruments
+from nautilus_trader.data.messages import RequestQuoteTicks
+from nautilus_trader.data.messages import RequestTradeTicks
+from nautilus_trader.data.messages import SubscribeOrderBook
+from nautilus_trader.data.messages import SubscribeQuoteTicks
+from nautilus_trader.data.messages import SubscribeTradeTicks
+from nautilus_trader.data.messages import UnsubscribeOrderBook
+from nautilus_trader.data.messages import UnsubscribeQuoteTicks
+from nautilus_trader.data.messages import UnsubscribeTradeTicks
+from nautilus_trader.live.data_client import LiveMarketDataClient
+from nautilus_trader.model.enums import BookType
+from nautilus_trader.model.identifiers import ClientId
+from nautilus_trader.model.identifiers import InstrumentId
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.adapters.deribit.config import DeribitDataClientConfig
+    from nautilus_trader.adapters.deribit.providers import DeribitInstrumentProvider
+    from nautilus_trader.cache.cache import Cache
+    from nautilus_trader.common.component import LiveClock
+    from nautilus_trader.common.component import MessageBus
+    from nautilus_trader.model.instruments import Instrument
+
+
+class DeribitDataClient(LiveMarketDataClient):
+    """
+    Provides a data client for the Deribit derivatives exchange.
+
+    Instruments are reloaded on the configured interval, and shortly after each
+    instrument expiry, so option chains and futures roll to the newly listed expiries.
+
+    Parameters
+    ----------
+    loop : asyncio.AbstractEventLoop
+        The event loop for the client.
+    msgbus : MessageBus
+        The message bus for the client.
+    cache : Cache
+        The cache for the client.
+    clock : LiveClock
+        The clock for the client.
+    instrument_provider : DeribitInstrumentProvider
+        The instrument provider.
+    base_url_ws : str
+        The base URL for the WebSocket client.
+    config : DeribitDataClientConfig
+        The configuration for the client.
+    name : str, optional
+        The custom client ID.
+
+    """
+
+    def __init__(
+        self,
+        loop: asyncio.AbstractEventLoop,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+        instrument_provider: DeribitInstrumentProvider,
+        base_url_ws: str,
+        config: DeribitDataClientConfig,
+        name: str | None,
+    ) -> None:
+        super().__init__(
+            loop=loop,
+            client_id=ClientId(name or DERIBIT_VENUE.value),
+            venue=DERIBIT_VENUE,
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+            instrument_provider=instrument_provider,
+        )
+        self._instrument_provider: DeribitInstrumentProvider = instrument_provider
+
+        # Configuration
+        self._include_block_trades = config.include_block_trades
+        self._log.info(f"{config.testnet=}", LogColor.BLUE)
+        self._log.info(f"{config.update_instruments_interval_mins=}", LogColor.BLUE)
+        self._log.info(f"{config.include_block_trades=}", LogColor.BLUE)
+
+        # WebSocket API
+        self._ws_client = DeribitWebSocketClient(
+            clock=clock,
+            base_url=base_url_ws,
+            handler=self._handle_ws_message,
+            handler_reconnect=self._reconnect,
+            loop=loop,
+        )
+
+        # WebSocket decoders
+        self._decoder_ws_msg_general = msgspec.json.Decoder(DeribitWsMessageGeneral)
+        self._decoder_ws_book = msgspec.json.Decoder(DeribitWsBookMsg)
+        self._decoder_ws_quote = msgspec.json.Decoder(DeribitWsQuoteMsg)
+        self._decoder_ws_trades = msgspec.json.Decoder(DeribitWsTradesMsg)
+
+        # Last book change ID for each instrument (to detect gaps in the book changes)
+        self._book_change_ids: dict[InstrumentId, int] = {}
+
+        self._update_instruments_interval_mins: int | None = config.update_instruments_interval_mins
+        self._update_instruments_task: asyncio.Task | None = None
+
+    async def _connect(self) -> None:
+        await self._instrument_provider.initialize()
+        self._send_all_instruments_to_data_engine()
+
+        self._update_instruments_task = self.create_task(self._update_instruments())
+
+        await self._ws_client.connect()
+
+    async def _disconnect(self) -> None:
+        if self._update_instruments_task:
+            self._log.debug("Canceling task 'update_instruments'")
+            self._update_instruments_task.cancel()
+            self._update_instruments_task = None
+
+        await self._ws_client.disconnect()
+
+    async def _reconnect(self) -> None:
+        # Book snapshots are resent on resubscribing
+        self._book_change_ids.clear()
+
+    def _send_all_instruments_to_data_engine(self) -> None:
+        for instrument in self._instrument_provider.get_all().values():
+            self._handle_data(instrument)
+
+        for currency in self._instrument_provider.currencies().values():
+            self._cache.add_currency(currency)
+
+    def _next_update_delay_secs(self) -> float | None:
+        # The earlier of the update interval and shortly after the next expiry
+        delays: list[float] = []
+        if self._update_instruments_interval_mins:
+            delays.append(self._update_instruments_interval_mins * 60)
+
+        next_expiration_ns = self._instrument_provider.next_expiration_ns()
+        if next_expiration_ns is not None:
+            delay_ns = max(next_expiration_ns - self._clock.timestamp_ns(), 0)
+            delays.append(nanos_to_secs(delay_ns) + DERIBIT_EXPIRY_RELOAD_DELAY_SECS)
+
+        return min(delays, default=None)
+
+    async def _update_instruments(self) -> None:
+        try:
+            while True:
+                delay_secs = self._next_update_delay_secs()
+                if delay_secs is None:
+                    return  # No interval or expiring instruments
+
+                self._log.debug(
+                    f"Scheduled task 'update_instruments' to run in {delay_secs:.0f} seconds",
+                )
+                await asyncio.sleep(delay_secs)
+
+                expired = self._instrument_provider.remove_expired()
+                for instrument_id in expired:
+                    await self._unsubscribe_expired(instrument_id)
+
+                if expired:
+                    self._log.info(f"Removed {len(expired)} expired instruments")
+
+                await self._instrument_provider.initialize(reload=True)
+                self._send_all_instruments_to_data_engine()
+        except asyncio.CancelledError:
+            self._log.debug("Canceled task 'update_instruments'")
+
+    async def _unsubscribe_expired(self, instrument_id: InstrumentId) -> None:
+        # The venue stops publishing for expired instruments, so the channels are dropped
+        symbol = instrument_id.symbol.value
+        self._book_change_ids.pop(instrument_id, None)
+        if self._ws_client.has_subscription(f"book.{symbol}.100ms"):
+            await self._ws_client.unsubscribe_order_book(symbol)
+        if self._ws_client.has_subscription(f"quote.{symbol}"):
+            await self._ws_client.unsubscribe_quotes(symbol)
+        if self._ws_client.has_subscription(f"trades.{symbol}.100ms"):
+            await self._ws_client.unsubscribe_trades(symbol)
+
+    async def _subscribe_order_book_deltas(self, command: SubscribeOrderBook) -> None:
+        if command.book_type != BookType.L2_MBP:
+            self._log.error(
+                f"Cannot subscribe to order book deltas: "
+                f"{command.book_type} data is not published by Deribit. "
+                "Valid book types are L2_MBP",
+            )
+            return
+
+        await self._ws_client.subscribe_order_book(command.instrument_id.symbol.value)
+
+    async def _subscribe_quote_ticks(self, command: SubscribeQuoteTicks) -> None:
+        await self._ws_client.subscribe_quotes(command.instrument_id.symbol.value)
+
+    async def _subscribe_trade_ticks(self, command: SubscribeTradeTicks) -> None:
+        await self._ws_client.subscribe_trades(command.instrument_id.symbol.value)
+
+    async def _unsubscribe_order_book_deltas(self, command: UnsubscribeOrderBook) -> None:
+        self._book_change_ids.pop(command.instrument_id, None)
+        await self._ws_client.unsubscribe_order_book(command.instrument_id.symbol.value)
+
+    async def _unsubscribe_quote_ticks(self, command: UnsubscribeQuoteTicks) -> None:
+        await self._ws_client.unsubscribe_quotes(command.instrument_id.symbol.value)
+
+    async def _unsubscribe_trade_ticks(self, command: UnsubscribeTradeTicks) -> None:
+        await self._ws_client.unsubscribe_trades(command.instrument_id.symbol.value)
+
+    async def _request_instrument(self, request: RequestInstrument) -> None:
+        if request.start is not None:
+            self._log.warning(
+                f"Requesting instrument {request.instrument_id} with specified `start` which has no effect",
+            )
+
+        if request.end is not None:
+            self._log.warning(
+                f"Requesting instrument {request.instrument_id} with specified `end` which has no effect",
+            )
+
+        instrument: Instrument | None = self._instrument_provider.find(request.instrument_id)
+        if instrument is None:
+            self._log.error(f"Cannot find instrument for {request.instrument_id}")
+            return
+
+        self._handle_instrument(instrument, request.id, request.params)
+
+    async def _request_instruments(self, request: RequestInstruments) -> None:
+        if request.start is not None:
+            self._log.warning(
+                f"Requesting instruments for {request.venue} with specified `start` which has no effect",
+            )
+
+        if request.end is not None:
+            self._log.warning(
+                f"Requesting instruments for {request.venue} with specified `end` which has no effect",
+            )
+
+        all_instruments = self._instrument_provider.get_all()
+        target_instruments = []
+        for instrument in all_instruments.values():
+            if instrument.venue == request.venue:
+                target_instruments.append(instrument)
+
+        self._handle_instruments(
+            request.venue,
+            target_instruments,
+            request.id,
+            request.params,
+        )
+
+    async def _request_quote_ticks(self, request: RequestQuoteTicks) -> None:
+        self._log.error(
+            "Cannot request historical quotes: not yet implemented for Deribit "
+            "(historical data is available through the Tardis adapter)",
+        )
+
+    async def _request_trade_ticks(self, request: RequestTradeTicks) -> None:
+        self._log.error(
+            "Cannot request historical trades: not yet implemented for Deribit "
+            "(historical data is available through the Tardis adapter)",
+        )
+
+    async def _request_bars(self, request: RequestBars) -> None:
+        self._log.error(
+            "Cannot request historical bars: not yet implemented for Deribit "
+            "(historical data is available through the Tardis adapter)",
+        )
+
+    def _get_cached_instrument(self, instrument_name: str) -> Instrument | None:
+        instrument_id = DeribitSymbol(instrument_name).to_instrument_id()
+        instrument = self._cache.instrument(instrument_id)
+        if instrument is None:
+            self._log.error(f"Cannot parse data: no instrument for {instrument_id}")
+        return instrument
+
+    def _handle_ws_message(self, raw: bytes) -> None:
+        try:
+            ws_message = self._decoder_ws_msg_general.decode(raw)
+            channel = ws_message.params.channel if ws_message.params else None
+            if channel is None:
+                self._log.debug(f"Unhandled websocket message: {raw.decode()}")
+            elif channel.startswith("book."):
+                self._handle_book(raw)
+            elif channel.startswith("quote."):
+                self._handle_quote(raw)
+            elif channel.startswith("trades."):
+                self._handle_trades(raw)
+            else:
+                self._log.debug(f"Unhandled websocket message: {raw.decode()}")
+        except Exception as e:
+            self._log.exception(f"Failed to handle websocket message {raw.decode()}", e)
+
+    def _handle_book(self, raw: bytes) -> None:
+        data = self._decoder_ws_book.decode(raw).params.data
+        instrument = self._get_cached_instrument(data.instrument_name)
+        if instrument is None:
+            return
+
+        # Each change follows the previous change ID, a gap requires a new snapshot
+        last_change_id = self._book_change_ids.get(instrument.id)
+        if not data.is_snapshot and last_change_id != data.prev_change_id:
+            self._log.warning(
+                f"Book change gap for {instrument.id} "
+                f"(expected {last_change_id}, was {data.prev_change_id}): resubscribing",
+            )
+            self._book_change_ids.pop(instrument.id, None)
+            self.create_task(self._resubscribe_order_book(instrument.id))
+            return
+
+        self._book_change_ids[instrument.id] = data.change_id
+        deltas = data.parse_to_deltas(instrument, ts_init=self._clock.timestamp_ns())
+        self._handle_data(deltas)
+
+    async def _resubscribe_order_book(self, instrument_id: InstrumentId) -> None:
+        symbol = instrument_id.symbol.value
+        await self._ws_client.unsubscribe_order_book(symbol)
+        await self._ws_client.subscribe_order_book(symbol)
+
+    def _handle_quote(self, raw: bytes) -> None:
+        data = self._decoder_ws_quote.decode(raw).params.data
+        instrument = self._get_cached_instrument(data.instrument_name)
+        if instrument is None:
+            return
+
+        quote = data.parse_to_quote_tick(instrument, ts_init=self._clock.timestamp_ns())
+        if quote is None:
+            return  # No two-sided market
+
+        self._handle_data(quote)
+
+    def _handle_trades(self, raw: bytes) -> None:
+        msg = self._decoder_ws_trades.decode(raw)
+        for data in msg.params.data:
+            if data.is_block_trade and not self._include_block_trades:
+                continue
+
+            instrument = self._get_cached_instrument(data.instrument_name)
+            if instrument is None:
+                continue
+
+            trade = data.parse_to_trade_tick(instrument, ts_init=self._clock.timestamp_ns())
+            self._handle_data(trade)
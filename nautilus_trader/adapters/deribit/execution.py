@@ -0,0 +1,962 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from decimal import Decimal
+from typing import TYPE_CHECKING
+
+import msgspec
+
+from nautilus_trader.adapters.deribit.common.constants import DERIBIT_VENUE
+from nautilus_trader.adapters.deribit.common.enums import DeribitEnumParser
+from nautilus_trader.adapters.deribit.common.enums import DeribitOrderState
+from nautilus_trader.adapters.deribit.common.parsing import amount_to_quantity
+from nautilus_trader.adapters.deribit.common.parsing import quantity_to_amount
+from nautilus_trader.adapters.deribit.common.parsing import to_decimal
+from nautilus_trader.adapters.deribit.common.symbol import DeribitSymbol
+from nautilus_trader.adapters.deribit.http.account import DeribitAccountHttpAPI
+from nautilus_trader.adapters.deribit.http.errors import DeribitError
+from nautilus_trader.adapters.deribit.http.errors import should_retry
+from nautilus_trader.adapters.deribit.http.trade import DeribitTradeHttpAPI
+from nautilus_trader.adapters.deribit.http.trade import order_params
+from nautilus_trader.adapters.deribit.schemas.user import DeribitAccountSummary
+from nautilus_trader.adapters.deribit.schemas.user import parse_account_summaries
+from nautilus_trader.adapters.deribit.schemas.ws import DeribitWsMessageGeneral
+from nautilus_trader.adapters.deribit.schemas.ws import DeribitWsOrderMsg
+from nautilus_trader.adapters.deribit.schemas.ws import DeribitWsPortfolioMsg
+from nautilus_trader.adapters.deribit.schemas.ws import DeribitWsUserTradesMsg
+from nautilus_trader.adapters.deribit.websocket.client import DeribitWebSocketClient
+from nautilus_trader.common.enums import LogColor
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.live.execution_client import LiveExecutionClient
+from nautilus_trader.live.retry import RetryManagerPool
+from nautilus_trader.model.enums import AccountType
+from nautilus_trader.model.enums import InstrumentClass
+from nautilus_trader.model.enums import LiquiditySide
+from nautilus_trader.model.enums import OmsType
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import order_type_to_str
+from nautilus_trader.model.enums import time_in_force_to_str
+from nautilus_trader.model.identifiers import AccountId
+from nautilus_trader.model.identifiers import ClientId
+from nautilus_trader.model.identifiers import ClientOrderId
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import TradeId
+from nautilus_trader.model.identifiers import VenueOrderId
+from nautilus_trader.model.objects import Money
+from nautilus_trader.model.orders import Order
+
+
+if TYPE_CHECKING:
+    import asyncio
+
+    import pandas as pd
+
+    from nautilus_trader.adapters.deribit.config import DeribitExecClientConfig
+    from nautilus_trader.adapters.deribit.http.client import DeribitHttpClient
+    from nautilus_trader.adapters.deribit.providers import DeribitInstrumentProvider
+    from nautilus_trader.adapters.deribit.schemas.order import DeribitOrder
+    from nautilus_trader.adapters.deribit.schemas.order import DeribitUserTrade
+    from nautilus_trader.cache.cache import Cache
+    from nautilus_trader.common.component import LiveClock
+    from nautilus_trader.common.component import MessageBus
+    from nautilus_trader.execution.messages import CancelAllOrders
+    from nautilus_trader.execution.messages import CancelOrder
+    from nautilus_trader.execution.messages import ModifyOrder
+    from nautilus_trader.execution.messages import SubmitOrder
+    from nautilus_trader.execution.reports import FillReport
+    from nautilus_trader.execution.reports import OrderStatusReport
+    from nautilus_trader.execution.reports import PositionStatusReport
+    from nautilus_trader.model.instruments import Instrument
+
+
+# Order types which are triggered into a resting limit order
+_TRIGGERED_LIMIT_ORDER_TYPES = (OrderType.STOP_LIMIT, OrderType.LIMIT_IF_TOUCHED)
+
+# Order types which are sent with a trigger price
+_TRIGGER_ORDER_TYPES = (
+    OrderType.STOP_MARKET,
+    OrderType.STOP_LIMIT,
+    OrderType.MARKET_IF_TOUCHED,
+    OrderType.LIMIT_IF_TOUCHED,
+)
+
+# Instrument classes of combos, which are filled through trades of each leg
+_COMBO_INSTRUMENT_CLASSES = (InstrumentClass.FUTURES_SPREAD, InstrumentClass.OPTION_SPREAD)
+
+
+class DeribitExecutionClient(LiveExecutionClient):
+    """
+    Provides an execution client for the Deribit derivatives exchange.
+
+    Orders are sent through the private JSON-RPC API methods, and order events are
+    streamed on the authenticated `user.orders`, `user.trades` and `user.portfolio`
+    WebSocket channels.
+
+    Combo orders are filled through trades of each leg instrument, so fills of combo
+    orders are derived from the filled amount and average price of the combo order
+    (with leg commissions reported in the account state rather than the fills).
+
+    Parameters
+    ----------
+    loop : asyncio.AbstractEventLoop
+        The event loop for the client.
+    client : DeribitHttpClient
+        The Deribit HTTP client (with API credentials).
+    msgbus : MessageBus
+        The message bus for the client.
+    cache : Cache
+        The cache for the client.
+    clock : LiveClock
+        The clock for the client.
+    instrument_provider : DeribitInstrumentProvider
+        The instrument provider.
+    base_url_ws : str
+        The base URL for the WebSocket client.
+    config : DeribitExecClientConfig
+        The configuration for the client.
+    name : str, optional
+        The custom client ID.
+
+    """
+
+    def __init__(
+        self,
+        loop: asyncio.AbstractEventLoop,
+        client: DeribitHttpClient,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+        instrument_provider: DeribitInstrumentProvider,
+        base_url_ws: str,
+        config: DeribitExecClientConfig,
+        name: str | None,
+    ) -> None:
+        super().__init__(
+            loop=loop,
+            client_id=ClientId(name or DERIBIT_VENUE.value),
+            venue=DERIBIT_VENUE,
+            oms_type=OmsType.NETTING,
+            instrument_provider=instrument_provider,
+            account_type=AccountType.MARGIN,
+            base_currency=None,  # Margined in the settlement currency of each instrument
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+        )
+
+        # Configuration
+        self._currencies: tuple[str, ...] = config.currencies
+        self._log.info(f"{config.testnet=}", LogColor.BLUE)
+        self._log.info(f"{config.currencies=}", LogColor.BLUE)
+        self._log.info(f"{config.max_retries=}", LogColor.BLUE)
+        self._log.info(f"{config.retry_delay=}", LogColor.BLUE)
+
+        self._enum_parser = DeribitEnumParser()
+
+        account_id = AccountId(f"{name or DERIBIT_VENUE.value}-master")
+        self._set_account_id(account_id)
+
+        # HTTP API
+        self._http_account = DeribitAccountHttpAPI(client=client, clock=clock)
+        self._http_trade = DeribitTradeHttpAPI(client=client, clock=clock)
+
+        # WebSocket API
+        self._ws_client = DeribitWebSocketClient(
+            clock=clock,
+            base_url=base_url_ws,
+            handler=self._handle_ws_message,
+            handler_reconnect=None,
+            loop=loop,
+            api_key=client.api_key,
+            api_secret=client.api_secret,
+        )
+
+        # Decoders
+        self._decoder_ws_msg_general = msgspec.json.Decoder(DeribitWsMessageGeneral)
+        self._decoder_ws_order = msgspec.json.Decoder(DeribitWsOrderMsg)
+        self._decoder_ws_user_trades = msgspec.json.Decoder(DeribitWsUserTradesMsg)
+        self._decoder_ws_portfolio = msgspec.json.Decoder(DeribitWsPortfolioMsg)
+
+        # Hot caches
+        self._account_summaries: dict[str, DeribitAccountSummary] = {}
+
+        self._retry_manager_pool = RetryManagerPool[None](
+            pool_size=100,
+            max_retries=config.max_retries or 0,
+            retry_delay_secs=config.retry_delay or 0.0,
+            logger=self._log,
+            exc_types=(DeribitError,),
+            retry_check=should_retry,
+        )
+
+    async def _connect(self) -> None:
+        await self._instrument_provider.initialize()
+        await self._update_account_state()
+
+        await self._ws_client.connect()
+        await self._ws_client.subscribe_orders()
+        await self._ws_client.subscribe_user_trades()
+        await self._ws_client.subscribe_portfolio()
+
+    async def _disconnect(self) -> None:
+        await self._ws_client.disconnect()
+
+    def _stop(self) -> None:
+        self._retry_manager_pool.shutdown()
+
+    # -- EXECUTION REPORTS ------------------------------------------------------------------------
+
+    def _report_currencies(self, instrument_id: InstrumentId | None) -> tuple[str, ...]:
+        if instrument_id is not None:
+            return (DeribitSymbol(instrument_id.symbol.value).currency,)
+        return self._currencies
+
+    async def generate_order_status_reports(
+        self,
+        instrument_id: InstrumentId | None = None,
+        start: pd.Timestamp | None = None,
+        end: pd.Timestamp | None = None,
+        open_only: bool = False,
+    ) -> list[OrderStatusReport]:
+        self._log.debug("Requesting OrderStatusReports...")
+        reports: list[OrderStatusReport] = []
+
+        try:
+            deribit_orders: list[DeribitOrder] = []
+            for currency in self._report_currencies(instrument_id):
+                deribit_orders += await self._http_trade.fetch_open_orders(
+                    currency=currency,
+                    instrument_name=(
+                        instrument_id.symbol.value if instrument_id is not None else None
+                    ),
+                )
+                if not open_only:
+                    deribit_orders += await self._http_trade.fetch_order_history(currency)
+
+            for deribit_order in deribit_orders:
+                if instrument_id is not None and deribit_order.instrument_name != (
+                    instrument_id.symbol.value
+                ):
+                    continue
+
+                ts_event = deribit_order.ts_event
+                if start is not None and ts_event < start.value:
+                    continue
+                if end is not None and ts_event > end.value:
+                    continue
+
+                report = self._parse_order_status_report(deribit_order)
+                if report is None:
+                    continue
+                reports.append(report)
+                self._log.debug(f"Received {report}", LogColor.MAGENTA)
+        except DeribitError as e:
+            self._log.error(f"Failed to generate OrderStatusReports: {e}")
+
+        len_reports = len(reports)
+        plural = "" if len_reports == 1 else "s"
+        self._log.info(f"Received {len(reports)} OrderStatusReport{plural}")
+
+        return reports
+
+    async def generate_order_status_report(
+        self,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId | None = None,
+        venue_order_id: VenueOrderId | None = None,
+    ) -> OrderStatusReport | None:
+        PyCondition.is_false(
+            client_order_id is None and venue_order_id is None,
+            "both `client_order_id` and `venue_order_id` were `None`",
+        )
+
+        self._log.info(
+            f"Generating OrderStatusReport for "
+            f"{repr(client_order_id) if client_order_id else ''} "
+            f"{repr(venue_order_id) if venue_order_id else ''}",
+        )
+
+        if venue_order_id is None and client_order_id is not None:
+            venue_order_id = self._cache.venue_order_id(client_order_id)
+
+        try:
+            if venue_order_id is not None:
+                deribit_order = await self._http_trade.fetch_order(venue_order_id.value)
+            else:
+                # Orders without a venue order ID can only be found while open (by label)
+                open_orders = await self._http_trade.fetch_open_orders(
+                    instrument_name=instrument_id.symbol.value,
+                )
+                assert client_order_id is not None  # Type checking
+                matches = [o for o in open_orders if o.label == client_order_id.value]
+                if not matches:
+                    self._log.warning(f"No order found for {client_order_id!r}")
+                    return None
+                deribit_order = matches[0]
+
+            report = self._parse_order_status_report(deribit_order)
+            if report is not None:
+                self._log.debug(f"Received {report}", LogColor.MAGENTA)
+            return report
+        except DeribitError as e:
+            self._log.error(f"Failed to generate OrderStatusReport: {e}")
+        return None
+
+    async def generate_fill_reports(
+        self,
+        instrument_id: InstrumentId | None = None,
+        venue_order_id: VenueOrderId | None = None,
+        start: pd.Timestamp | None = None,
+        end: pd.Timestamp | None = None,
+    ) -> list[FillReport]:
+        self._log.debug("Requesting FillReports...")
+        reports: list[FillReport] = []
+
+        try:
+            trades: list[DeribitUserTrade] = []
+            if venue_order_id is not None:
+                trades = await self._http_trade.fetch_order_trades(venue_order_id.value)
+            else:
+                # Timestamps are in milliseconds
+                start_ms = start.value // 1_000_000 if start is not None else 0
+                end_ms = (end.value if end is not None else self._clock.timestamp_ns()) // 1_000_000
+                for currency in self._report_currencies(instrument_id):
+                    user_trades = await self._http_trade.fetch_user_trades(
+                        currency,
+                        start_timestamp=start_ms,
+                        end_timestamp=end_ms,
+                    )
+                    trades += user_trades.trades
+
+            for trade in trades:
+                if instrument_id is not None and trade.instrument_name != instrument_id.symbol.value:
+                    continue
+
+                instrument = self._get_cached_instrument(trade.instrument_name)
+                if instrument is None:
+                    continue
+
+                report = trade.parse_to_fill_report(
+                    account_id=self.account_id,
+                    instrument=instrument,
+                    client_order_id=self._parse_client_order_id(
+                        trade.label,
+                        VenueOrderId(trade.order_id),
+                    ),
+                    report_id=UUID4(),
+                    enum_parser=self._enum_parser,
+                    ts_init=self._clock.timestamp_ns(),
+                )
+                reports.append(report)
+                self._log.debug(f"Received {report}")
+        except DeribitError as e:
+            self._log.error(f"Failed to generate FillReports: {e}")
+
+        len_reports = len(reports)
+        plural = "" if len_reports == 1 else "s"
+        self._log.info(f"Received {len(reports)} FillReport{plural}")
+
+        return reports
+
+    async def generate_position_status_reports(
+        self,
+        instrument_id: InstrumentId | None = None,
+        start: pd.Timestamp | None = None,
+        end: pd.Timestamp | None = None,
+    ) -> list[PositionStatusReport]:
+        reports: list[PositionStatusReport] = []
+
+        try:
+            self._log.debug("Requesting PositionStatusReports...")
+            for currency in self._report_currencies(instrument_id):
+                positions = await self._http_account.fetch_positions(currency)
+                for position in positions:
+                    if position.size == 0:
+                        continue
+
+                    instrument = self._get_cached_instrument(position.instrument_name)
+                    if instrument is None:
+                        continue
+                    if instrument_id is not None and instrument.id != instrument_id:
+                        continue
+
+                    position_report = position.parse_to_position_status_report(
+                        account_id=self.account_id,
+                        instrument=instrument,
+                        report_id=UUID4(),
+                        ts_init=self._clock.timestamp_ns(),
+                    )
+                    self._log.debug(f"Received {position_report}")
+                    reports.append(position_report)
+        except DeribitError as e:
+            self._log.error(f"Failed to generate PositionReports: {e}")
+
+        len_reports = len(reports)
+        plural = "" if len_reports == 1 else "s"
+        self._log.info(f"Received {len(reports)} PositionReport{plural}")
+
+        return reports
+
+    def _parse_order_status_report(self, deribit_order: DeribitOrder) -> OrderStatusReport | None:
+        instrument = self._get_cached_instrument(deribit_order.instrument_name)
+        if instrument is None:
+            return None
+
+        return deribit_order.parse_to_order_status_report(
+            account_id=self.account_id,
+            instrument=instrument,
+            client_order_id=self._parse_client_order_id(
+                deribit_order.label,
+                VenueOrderId(deribit_order.order_id),
+            ),
+            report_id=UUID4(),
+            enum_parser=self._enum_parser,
+            ts_init=self._clock.timestamp_ns(),
+        )
+
+    def _get_cached_instrument(self, instrument_name: str) -> Instrument | None:
+        instrument_id = DeribitSymbol(instrument_name).to_instrument_id()
+        instrument = self._cache.instrument(instrument_id)
+        if instrument is None:
+            self._log.debug(f"No instrument found for {instrument_id}")
+        return instrument
+
+    def _parse_client_order_id(
+        self,
+        label: str | None,
+        venue_order_id: VenueOrderId,
+    ) -> ClientOrderId | None:
+        # Orders placed through the web interface have no label
+        if label:
+            return ClientOrderId(label)
+        return self._cache.client_order_id(venue_order_id)
+
+    async def _update_account_state(self) -> None:
+        try:
+            for currency in self._currencies:
+                summary = await self._http_account.fetch_account_summary(currency)
+                self._account_summaries[summary.currency.upper()] = summary
+            self._generate_account_state()
+        except Exception as e:
+            self._log.error(f"Failed to generate AccountState: {e}")
+
+    def _generate_account_state(self) -> None:
+        balances, margins = parse_account_summaries(list(self._account_summaries.values()))
+        if not balances:
+            self._log.warning("No balances for account state")
+            return
+
+        self.generate_account_state(
+            balances=balances,
+            margins=margins,
+            reported=True,
+            ts_event=self._clock.timestamp_ns(),
+        )
+
+    # -- COMMAND HANDLERS -------------------------------------------------------------------------
+
+    async def _cancel_order(self, command: CancelOrder) -> None:
+        order: Order | None = self._cache.order(command.client_order_id)
+        if order is None:
+            self._log.error(f"{command.client_order_id!r} not found in cache")
+            return
+
+        if order.is_closed:
+            self._log.warning(
+                f"`CancelOrder` command for {command.client_order_id!r} when order already {order.status_string()} "
+                "(will not send to exchange)",
+            )
+            return
+
+        venue_order_id = command.venue_order_id or order.venue_order_id
+
+        async with self._retry_manager_pool as retry_manager:
+            await retry_manager.run(
+                "cancel_order",
+                [order.client_order_id, venue_order_id],
+                self._cancel_order_inner,
+                order.client_order_id,
+                venue_order_id,
+            )
+            if not retry_manager.result:
+                self.generate_order_cancel_rejected(
+                    order.strategy_id,
+                    order.instrument_id,
+                    order.client_order_id,
+                    order.venue_order_id,
+                    retry_manager.message,
+                    self._clock.timestamp_ns(),
+                )
+
+    async def _cancel_order_inner(
+        self,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId | None,
+    ) -> None:
+        # Orders can be canceled by venue order ID or client order ID (label)
+        if venue_order_id is not None:
+            await self._http_trade.cancel_order(venue_order_id.value)
+            return
+
+        count = await self._http_trade.cancel_order_by_label(client_order_id.value)
+        if count == 0:
+            raise DeribitError(code=None, message=f"no open order with label {client_order_id}")
+
+    async def _cancel_all_orders(self, command: CancelAllOrders) -> None:
+        orders_open = [
+            order
+            for order in self._cache.orders_open(instrument_id=command.instrument_id)
+            if command.order_side in (OrderSide.NO_ORDER_SIDE, order.side)
+        ]
+        if not orders_open:
+            self._log.info(f"No open orders to cancel for {command.instrument_id}")
+            return
+
+        # Orders of one side are canceled individually (no side filter for the instrument)
+        if command.order_side != OrderSide.NO_ORDER_SIDE:
+            for order in orders_open:
+                await self._cancel_order_for_side(order)
+            return
+
+        async with self._retry_manager_pool as retry_manager:
+            await retry_manager.run(
+                "cancel_all_orders",
+                None,
+                self._http_trade.cancel_all_orders,
+                command.instrument_id.symbol.value,
+            )
+            if not retry_manager.result:
+                for order in orders_open:
+                    if order.is_closed:
+                        continue
+                    self.generate_order_cancel_rejected(
+                        order.strategy_id,
+                        order.instrument_id,
+                        order.client_order_id,
+                        order.venue_order_id,
+                        retry_manager.message,
+                        self._clock.timestamp_ns(),
+                    )
+
+    async def _cancel_order_for_side(self, order: Order) -> None:
+        async with self._retry_manager_pool as retry_manager:
+            await retry_manager.run(
+                "cancel_order",
+                [order.client_order_id, order.venue_order_id],
+                self._cancel_order_inner,
+                order.client_order_id,
+                order.venue_order_id,
+            )
+            if not retry_manager.result:
+                self.generate_order_cancel_rejected(
+                    order.strategy_id,
+                    order.instrument_id,
+                    order.client_order_id,
+                    order.venue_order_id,
+                    retry_manager.message,
+                    self._clock.timestamp_ns(),
+                )
+
+    async def _modify_order(self, command: ModifyOrder) -> None:
+        order: Order | None = self._cache.order(command.client_order_id)
+        if order is None:
+            self._log.error(f"{command.client_order_id!r} not found in cache")
+            return
+
+        if order.is_closed:
+            self._log.warning(
+                f"`ModifyOrder` command for {command.client_order_id!r} when order already {order.status_string()} "
+                "(will not send to exchange)",
+            )
+            return
+
+        venue_order_id = command.venue_order_id or order.venue_order_id
+        if venue_order_id is None:
+            self.generate_order_modify_rejected(
+                order.strategy_id,
+                order.instrument_id,
+                order.client_order_id,
+                order.venue_order_id,
+                "no venue order ID (order not yet accepted)",
+                self._clock.timestamp_ns(),
+            )
+            return
+
+        instrument = self._cache.instrument(order.instrument_id)
+        if instrument is None:
+            self._log.error(f"Cannot modify order: instrument for {order.instrument_id} not found")
+            return
+
+        # The order is updated from the `user.orders` channel once edited
+        async with self._retry_manager_pool as retry_manager:
+            await retry_manager.run(
+                "modify_order",
+                [order.client_order_id, venue_order_id],
+                self._http_trade.edit_order,
+                order_id=venue_order_id.value,
+                amount=quantity_to_amount(command.quantity or order.quantity, instrument),
+                price=str(command.price) if command.price else None,
+                trigger_price=str(command.trigger_price) if command.trigger_price else None,
+            )
+            if not retry_manager.result:
+                self.generate_order_modify_rejected(
+                    order.strategy_id,
+                    order.instrument_id,
+                    order.client_order_id,
+                    order.venue_order_id,
+                    retry_manager.message,
+                    self._clock.timestamp_ns(),
+                )
+
+    async def _submit_order(self, command: SubmitOrder) -> None:
+        order = command.order
+        if order.is_closed:
+            self._log.warning(f"Order {order} is already closed")
+            return
+
+        if not self._check_order_validity(order):
+            return
+
+        # Generate order submitted event, to ensure correct ordering of event
+        self.generate_order_submitted(
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=order.client_order_id,
+            ts_event=self._clock.timestamp_ns(),
+        )
+
+        async with self._retry_manager_pool as retry_manager:
+            await retry_manager.run(
+                "submit_order",
+                [order.client_order_id],
+                self._submit_order_inner,
+                order,
+            )
+            if not retry_manager.result:
+                self.generate_order_rejected(
+                    strategy_id=order.strategy_id,
+                    instrument_id=order.instrument_id,
+                    client_order_id=order.client_order_id,
+                    reason=retry_manager.message,
+                    ts_event=self._clock.timestamp_ns(),
+                )
+
+    def _check_order_validity(self, order: Order) -> bool:
+        if order.order_type not in self._enum_parser.nautilus_to_deribit_order_type:
+            self._log.error(
+                f"Cannot submit {order}: {order_type_to_str(order.order_type)} orders "
+                "not supported for Deribit",
+            )
+            return False
+
+        if order.is_quote_quantity:
+            self._log.error(f"Cannot submit {order}: quote quantity not supported for Deribit")
+            return False
+
+        if order.time_in_force not in self._enum_parser.nautilus_to_deribit_time_in_force:
+            self._log.error(
+                f"Cannot submit {order}: time in force "
+                f"{time_in_force_to_str(order.time_in_force)} unsupported on Deribit",
+            )
+            return False
+
+        if order.is_post_only and order.order_type not in (
+            OrderType.LIMIT,
+            OrderType.STOP_LIMIT,
+            OrderType.LIMIT_IF_TOUCHED,
+        ):
+            self._log.error(
+                f"Cannot submit {order} has invalid post only {order.is_post_only}, unsupported on Deribit",
+            )
+            return False
+
+        if self._cache.instrument(order.instrument_id) is None:
+            self._log.error(f"Cannot submit {order}: instrument {order.instrument_id} not found")
+            return False
+
+        return True
+
+    async def _submit_order_inner(self, order: Order) -> None:
+        instrument = self._cache.instrument(order.instrument_id)
+        assert instrument is not None  # Checked on submit
+
+        params = order_params(
+            instrument_name=order.instrument_id.symbol.value,
+            order_type=self._enum_parser.parse_nautilus_order_type(order.order_type),
+            amount=quantity_to_amount(order.quantity, instrument),
+            client_order_id=order.client_order_id.value,
+            time_in_force=self._enum_parser.parse_nautilus_time_in_force(order.time_in_force),
+            price=str(order.price) if order.has_price else None,
+            trigger_price=str(order.trigger_price) if order.has_trigger_price else None,
+            trigger=(
+                self._enum_parser.parse_nautilus_trigger_type(order.trigger_type)
+                if order.order_type in _TRIGGER_ORDER_TYPES
+                else None
+            ),
+            post_only=order.is_post_only,
+            reduce_only=order.is_reduce_only,
+            max_show=(
+                quantity_to_amount(order.display_qty, instrument)
+                if order.has_price and order.display_qty is not None
+                else None
+            ),
+        )
+        result = await self._http_trade.place_order(
+            self._enum_parser.parse_nautilus_order_side(order.side),
+            params,
+        )
+
+        # The `user.orders` channel may have already accepted the order
+        current_order = self._cache.order(order.client_order_id)
+        if current_order is None or current_order.status != OrderStatus.SUBMITTED:
+            return
+
+        if result.order.order_state == DeribitOrderState.REJECTED:
+            self.generate_order_rejected(
+                strategy_id=order.strategy_id,
+                instrument_id=order.instrument_id,
+                client_order_id=order.client_order_id,
+                reason=result.order.cancel_reason or "Rejected",
+                ts_event=self._clock.timestamp_ns(),
+            )
+        else:
+            self.generate_order_accepted(
+                strategy_id=order.strategy_id,
+                instrument_id=order.instrument_id,
+                client_order_id=order.client_order_id,
+                venue_order_id=VenueOrderId(result.order.order_id),
+                ts_event=self._clock.timestamp_ns(),
+            )
+
+    # -- WEBSOCKET HANDLERS -----------------------------------------------------------------------
+
+    def _handle_ws_message(self, raw: bytes) -> None:
+        try:
+            msg = self._decoder_ws_msg_general.decode(raw)
+            channel = msg.params.channel if msg.params else None
+            if channel is None:
+                return
+
+            if channel.startswith("user.orders."):
+                self._handle_order(self._decoder_ws_order.decode(raw).params.data)
+            elif channel.startswith("user.trades."):
+                for trade in self._decoder_ws_user_trades.decode(raw).params.data:
+                    self._handle_user_trade(trade)
+            elif channel.startswith("user.portfolio."):
+                self._handle_portfolio(self._decoder_ws_portfolio.decode(raw).params.data)
+        except Exception as e:
+            self._log.exception(f"Failed to handle websocket message: {raw.decode()}", e)
+
+    def _find_order(self, label: str | None, venue_order_id: VenueOrderId) -> Order | None:
+        client_order_id = self._parse_client_order_id(label, venue_order_id)
+        if client_order_id is None:
+            self._log.debug(
+                f"Cannot process update for {venue_order_id!r}: no `ClientOrderId` found "
+                "(most likely due to being an external order)",
+            )
+            return None
+
+        order = self._cache.order(client_order_id)
+        if order is None:
+            self._log.debug(f"Cannot find {client_order_id!r} (most likely an external order)")
+        return order
+
+    def _handle_order(self, deribit_order: DeribitOrder) -> None:
+        venue_order_id = VenueOrderId(deribit_order.order_id)
+        order = self._find_order(deribit_order.label, venue_order_id)
+        if order is None:
+            return
+
+        instrument = self._get_cached_instrument(deribit_order.instrument_name)
+        if instrument is None:
+            raise ValueError(
+                f"Cannot handle order: instrument for {deribit_order.instrument_name} not found",
+            )
+
+        ts_event = deribit_order.ts_event
+
+        if order.status == OrderStatus.SUBMITTED:
+            if deribit_order.order_state == DeribitOrderState.REJECTED:
+                self.generate_order_rejected(
+                    strategy_id=order.strategy_id,
+                    instrument_id=order.instrument_id,
+                    client_order_id=order.client_order_id,
+                    reason=deribit_order.cancel_reason or "Rejected",
+                    ts_event=ts_event,
+                )
+                return
+
+            self.generate_order_accepted(
+                strategy_id=order.strategy_id,
+                instrument_id=order.instrument_id,
+                client_order_id=order.client_order_id,
+                venue_order_id=venue_order_id,
+                ts_event=ts_event,
+            )
+
+        # Combo orders are filled through leg trades, so fills are derived from the order
+        if instrument.instrument_class in _COMBO_INSTRUMENT_CLASSES:
+            self._handle_combo_fill(order, instrument, deribit_order)
+
+        match deribit_order.order_state:
+            case DeribitOrderState.OPEN:
+                if (
+                    deribit_order.triggered
+                    and order.order_type in _TRIGGERED_LIMIT_ORDER_TYPES
+                    and order.status != OrderStatus.TRIGGERED
+                    and not order.is_closed
+                ):
+                    self.generate_order_triggered(
+                        strategy_id=order.strategy_id,
+                        instrument_id=order.instrument_id,
+                        client_order_id=order.client_order_id,
+                        venue_order_id=venue_order_id,
+                        ts_event=ts_event,
+                    )
+                self._handle_order_edited(order, instrument, deribit_order)
+            case DeribitOrderState.UNTRIGGERED:
+                self._handle_order_edited(order, instrument, deribit_order)
+            case DeribitOrderState.CANCELLED:
+                if order.is_closed:
+                    return
+                self.generate_order_canceled(
+                    strategy_id=order.strategy_id,
+                    instrument_id=order.instrument_id,
+                    client_order_id=order.client_order_id,
+                    venue_order_id=venue_order_id,
+                    ts_event=ts_event,
+                )
+            case _:
+                pass  # Fills are handled from the `user.trades` channel
+
+    def _handle_order_edited(
+        self,
+        order: Order,
+        instrument: Instrument,
+        deribit_order: DeribitOrder,
+    ) -> None:
+        if order.is_closed:
+            return
+
+        quantity = amount_to_quantity(deribit_order.amount, instrument)
+        price = None
+        if order.has_price and isinstance(deribit_order.price, float | int):
+            price = instrument.make_price(deribit_order.price)
+        trigger_price = None
+        if order.has_trigger_price and deribit_order.trigger_price is not None:
+            trigger_price = instrument.make_price(deribit_order.trigger_price)
+
+        if (
+            quantity == order.quantity
+            and (price is None or price == order.price)
+            and (trigger_price is None or trigger_price == order.trigger_price)
+        ):
+            return  # Not edited
+
+        self.generate_order_updated(
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=order.client_order_id,
+            venue_order_id=VenueOrderId(deribit_order.order_id),
+            quantity=quantity,
+            price=price,
+            trigger_price=trigger_price,
+            ts_event=deribit_order.ts_event,
+        )
+
+    def _handle_combo_fill(
+        self,
+        order: Order,
+        instrument: Instrument,
+        deribit_order: DeribitOrder,
+    ) -> None:
+        filled_qty = amount_to_quantity(deribit_order.filled_amount or 0, instrument)
+        if filled_qty <= order.filled_qty or not deribit_order.average_price:
+            return  # No new fill
+
+        # The fill price is derived from the change in average price of the combo order
+        last_qty = filled_qty.as_decimal() - order.filled_qty.as_decimal()
+        avg_px = to_decimal(deribit_order.average_price)
+        last_avg_px = Decimal(str(order.avg_px or 0))
+        last_px = (
+            avg_px * filled_qty.as_decimal() - last_avg_px * order.filled_qty.as_decimal()
+        ) / last_qty
+
+        self.generate_order_filled(
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=order.client_order_id,
+            venue_order_id=VenueOrderId(deribit_order.order_id),
+            venue_position_id=None,
+            trade_id=TradeId(f"{deribit_order.order_id}-{deribit_order.last_update_timestamp}"),
+            order_side=order.side,
+            order_type=order.order_type,
+            last_qty=instrument.make_qty(last_qty),
+            last_px=instrument.make_price(last_px),
+            quote_currency=instrument.quote_currency,
+            commission=Money(0, instrument.quote_currency),
+            liquidity_side=LiquiditySide.NO_LIQUIDITY_SIDE,
+            ts_event=deribit_order.ts_event,
+        )
+
+    def _handle_user_trade(self, trade: DeribitUserTrade) -> None:
+        venue_order_id = VenueOrderId(trade.order_id)
+        order = self._find_order(trade.label, venue_order_id)
+        if order is None:
+            if trade.is_block_trade:
+                self._log.info(
+                    f"Received block trade {trade.block_trade_id} for external order "
+                    f"{venue_order_id!r} (will be reconciled from position reports)",
+                )
+            return
+
+        # Leg trades of combo orders are reported for the leg instruments
+        if trade.instrument_name != order.instrument_id.symbol.value:
+            self._log.debug(
+                f"Ignoring combo leg trade {trade.trade_id} on {trade.instrument_name} "
+                f"for {order.client_order_id!r}",
+            )
+            return
+
+        instrument = self._get_cached_instrument(trade.instrument_name)
+        if instrument is None:
+            raise ValueError(f"Cannot handle fill: instrument for {trade.instrument_name} not found")
+
+        self.generate_order_filled(
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=order.client_order_id,
+            venue_order_id=venue_order_id,
+            venue_position_id=None,
+            trade_id=TradeId(trade.trade_id),
+            order_side=order.side,
+            order_type=order.order_type,
+            last_qty=amount_to_quantity(trade.amount, instrument),
+            last_px=instrument.make_price(trade.price),
+            quote_currency=instrument.quote_currency,
+            commission=trade.parse_commission(),
+            liquidity_side=trade.liquidity_side,
+            ts_event=trade.ts_event,
+        )
+
+    def _handle_portfolio(self, summary: DeribitAccountSummary) -> None:
+        # Portfolio updates are per currency, with account-wide totals for portfolio margin
+        self._account_summaries[summary.currency.upper()] = summary
+        self._generate_account_state()
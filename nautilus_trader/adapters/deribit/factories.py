@@ -0,0 +1,245 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import asyncio
+from functools import lru_cache
+
+from nautilus_trader.adapters.deribit.common.constants import DERIBIT_ORDER_RATE_LIMIT_KEY
+from nautilus_trader.adapters.deribit.common.constants import DERIBIT_REST_RATE_LIMIT_KEY
+from nautilus_trader.adapters.deribit.common.credentials import get_api_key
+from nautilus_trader.adapters.deribit.common.credentials import get_api_secret
+from nautilus_trader.adapters.deribit.common.urls import get_http_base_url
+from nautilus_trader.adapters.deribit.common.urls import get_ws_base_url
+from nautilus_trader.adapters.deribit.config import DeribitDataClientConfig
+from nautilus_trader.adapters.deribit.config import DeribitExecClientConfig
+from nautilus_trader.adapters.deribit.data import DeribitDataClient
+from nautilus_trader.adapters.deribit.execution import DeribitExecutionClient
+from nautilus_trader.adapters.deribit.http.client import DeribitHttpClient
+from nautilus_trader.adapters.deribit.providers import DeribitInstrumentProvider
+from nautilus_trader.cache.cache import Cache
+from nautilus_trader.common.component import LiveClock
+from nautilus_trader.common.component import MessageBus
+from nautilus_trader.config import InstrumentProviderConfig
+from nautilus_trader.core.nautilus_pyo3 import Quota
+from nautilus_trader.live.factories import LiveDataClientFactory
+from nautilus_trader.live.factories import LiveExecClientFactory
+
+
+@lru_cache(4)
+def get_cached_deribit_http_client(
+    clock: LiveClock,
+    api_key: str | None = None,
+    api_secret: str | None = None,
+    base_url: str | None = None,
+    is_testnet: bool = False,
+) -> DeribitHttpClient:
+    """
+    Cache and return a Deribit HTTP client.
+
+    If a cached client with matching parameters already exists, the cached client will be returned.
+
+    Parameters
+    ----------
+    clock : LiveClock
+        The clock for the client.
+    api_key : str, optional
+        The API key for the client (``None`` for public only access).
+    api_secret : str, optional
+        The API secret for the client.
+    base_url : str, optional
+        The base URL for the API endpoints.
+    is_testnet : bool, default False
+        If the client is connecting to the Deribit testnet.
+
+    Returns
+    -------
+    DeribitHttpClient
+
+    """
+    base_url = base_url or get_http_base_url(is_testnet)
+
+    # Setup rate limit quotas
+    # https://docs.deribit.com/#rate-limits
+    # Requests are limited by credits, which refill at a sustained rate of 20 requests
+    # per second (non matching engine) and 5 requests per second for order placement,
+    # editing and cancellation (matching engine) for the default account tier.
+    ratelimiter_default_quota = Quota.rate_per_second(20)
+    ratelimiter_quotas: list[tuple[str, Quota]] = [
+        (DERIBIT_REST_RATE_LIMIT_KEY, Quota.rate_per_second(20)),
+        (DERIBIT_ORDER_RATE_LIMIT_KEY, Quota.rate_per_second(5)),
+    ]
+
+    return DeribitHttpClient(
+        clock=clock,
+        base_url=base_url,
+        api_key=api_key,
+        api_secret=api_secret,
+        ratelimiter_quotas=ratelimiter_quotas,
+        ratelimiter_default_quota=ratelimiter_default_quota,
+    )
+
+
+@lru_cache(1)
+def get_cached_deribit_instrument_provider(
+    client: DeribitHttpClient,
+    clock: LiveClock,
+    config: InstrumentProviderConfig,
+) -> DeribitInstrumentProvider:
+    """
+    Cache and return a Deribit instrument provider.
+
+    If a cached provider already exists, then that provider will be returned.
+
+    Parameters
+    ----------
+    client : DeribitHttpClient
+        The Deribit HTTP client.
+    clock : LiveClock
+        The clock instance.
+    config : InstrumentProviderConfig
+        The instrument provider configuration.
+
+    Returns
+    -------
+    DeribitInstrumentProvider
+
+    """
+    return DeribitInstrumentProvider(
+        client=client,
+        clock=clock,
+        config=config,
+    )
+
+
+class DeribitLiveDataClientFactory(LiveDataClientFactory):
+    """
+    Provides a Deribit live data client factory.
+    """
+
+    @staticmethod
+    def create(  # type: ignore
+        loop: asyncio.AbstractEventLoop,
+        name: str,
+        config: DeribitDataClientConfig,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+    ) -> DeribitDataClient:
+        """
+        Create a new Deribit data client.
+
+        Parameters
+        ----------
+        loop : asyncio.AbstractEventLoop
+            The event loop for the client.
+        name : str
+            The custom client ID.
+        config : DeribitDataClientConfig
+            The client configuration.
+        msgbus : MessageBus
+            The message bus for the client.
+        cache : Cache
+            The cache for the client.
+        clock: LiveClock
+            The clock for the instrument provider.
+
+        Returns
+        -------
+        DeribitDataClient
+
+        """
+        # Market data is public, so no credentials are required
+        client: DeribitHttpClient = get_cached_deribit_http_client(
+            clock=clock,
+            base_url=config.base_url_http,
+            is_testnet=config.testnet,
+        )
+        provider = get_cached_deribit_instrument_provider(
+            client=client,
+            clock=clock,
+            config=config.instrument_provider,
+        )
+        return DeribitDataClient(
+            loop=loop,
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+            instrument_provider=provider,
+            base_url_ws=config.base_url_ws or get_ws_base_url(config.testnet),
+            config=config,
+            name=name,
+        )
+
+
+class DeribitLiveExecClientFactory(LiveExecClientFactory):
+    """
+    Provides a Deribit live execution client factory.
+    """
+
+    @staticmethod
+    def create(  # type: ignore
+        loop: asyncio.AbstractEventLoop,
+        name: str,
+        config: DeribitExecClientConfig,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+    ) -> DeribitExecutionClient:
+        """
+        Create a new Deribit execution client.
+
+        Parameters
+        ----------
+        loop : asyncio.AbstractEventLoop
+            The event loop for the client.
+        name : str
+            The custom client ID.
+        config : DeribitExecClientConfig
+            The client configuration.
+        msgbus : MessageBus
+            The message bus for the client.
+        cache : Cache
+            The cache for the client.
+        clock : LiveClock
+            The clock for the client.
+
+        Returns
+        -------
+        DeribitExecutionClient
+
+        """
+        client: DeribitHttpClient = get_cached_deribit_http_client(
+            clock=clock,
+            api_key=config.api_key or get_api_key(config.testnet),
+            api_secret=config.api_secret or get_api_secret(config.testnet),
+            base_url=config.base_url_http,
+            is_testnet=config.testnet,
+        )
+        provider = get_cached_deribit_instrument_provider(
+            client=client,
+            clock=clock,
+            config=config.instrument_provider,
+        )
+        return DeribitExecutionClient(
+            loop=loop,
+            client=client,
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+            instrument_provider=provider,
+            base_url_ws=config.base_url_ws or get_ws_base_url(config.testnet),
+            config=config,
+            name=name,
+        )
@@ -0,0 +1,87 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import msgspec
+
+from nautilus_trader.adapters.deribit.common.constants import DERIBIT_REST_RATE_LIMIT_KEY
+from nautilus_trader.adapters.deribit.schemas.response import DeribitResponse
+from nautilus_trader.adapters.deribit.schemas.user import DeribitAccountSummary
+from nautilus_trader.adapters.deribit.schemas.user import DeribitPosition
+from nautilus_trader.core.correctness import PyCondition
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.adapters.deribit.common.enums import DeribitInstrumentKind
+    from nautilus_trader.adapters.deribit.http.client import DeribitHttpClient
+    from nautilus_trader.common.component import LiveClock
+
+
+class DeribitAccountHttpAPI:
+    """
+    Provides access to the private Deribit account API methods.
+
+    Parameters
+    ----------
+    client : DeribitHttpClient
+        The Deribit HTTP client (with API credentials).
+    clock : LiveClock
+        The clock for the API client.
+
+    """
+
+    def __init__(
+        self,
+        client: DeribitHttpClient,
+        clock: LiveClock,
+    ) -> None:
+        PyCondition.not_none(client, "client")
+        self.client = client
+        self._clock = clock
+
+        self._decoder_account_summary = msgspec.json.Decoder(
+            DeribitResponse[DeribitAccountSummary],
+        )
+        self._decoder_positions = msgspec.json.Decoder(DeribitResponse[list[DeribitPosition]])
+
+    async def fetch_account_summary(self, currency: str) -> DeribitAccountSummary:
+        # The extended summary includes the margin model and account-wide USD totals
+        raw = await self.client.send_request(
+            "private/get_account_summary",
+            {"currency": currency, "extended": True},
+            signed=True,
+            ratelimiter_keys=[DERIBIT_REST_RATE_LIMIT_KEY],
+        )
+        return self._decoder_account_summary.decode(raw).result
+
+    async def fetch_positions(
+        self,
+        currency: str,
+        kind: DeribitInstrumentKind | None = None,
+    ) -> list[DeribitPosition]:
+        params = {"currency": currency}
+        if kind is not None:
+            params["kind"] = kind.value
+
+        raw = await self.client.send_request(
+            "private/get_positions",
+            params,
+            signed=True,
+            ratelimiter_keys=[DERIBIT_REST_RATE_LIMIT_KEY],
+        )
+        return self._decoder_positions.decode(raw).result
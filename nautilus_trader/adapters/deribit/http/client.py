@@ -0,0 +1,200 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from typing import Any
+from urllib import parse
+
+import msgspec
+
+import nautilus_trader
+from nautilus_trader.adapters.deribit.http.errors import DeribitError
+from nautilus_trader.adapters.deribit.http.errors import parse_error
+from nautilus_trader.common.component import LiveClock
+from nautilus_trader.common.component import Logger
+from nautilus_trader.core.nautilus_pyo3 import HttpClient
+from nautilus_trader.core.nautilus_pyo3 import HttpMethod
+from nautilus_trader.core.nautilus_pyo3 import HttpResponse
+from nautilus_trader.core.nautilus_pyo3 import Quota
+from nautilus_trader.core.nautilus_pyo3 import hmac_signature
+from nautilus_trader.core.uuid import UUID4
+
+
+DERIBIT_API_PATH = "/api/v2"
+
+
+class DeribitHttpClient:
+    """
+    Provides a Deribit asynchronous HTTP client.
+
+    API methods are called with `GET` requests to `/api/v2/{method}`, with the parameters
+    in the query string. Private methods are authenticated with a `deri-hmac-sha256`
+    authorization header, where the signature is the hex encoded HMAC-SHA256 of the
+    timestamp, nonce, verb, path (including the query string) and body.
+
+    Parameters
+    ----------
+    clock : LiveClock
+        The clock for the client.
+    base_url : str
+        The base endpoint URL for the client.
+    api_key : str, optional
+        The Deribit client ID for private methods (``None`` for public only access).
+    api_secret : str, optional
+        The Deribit client secret for private methods.
+    ratelimiter_quotas : list[tuple[str, Quota]], optional
+        The keyed rate limiter quotas for the client.
+    ratelimiter_default_quota : Quota, optional
+        The default rate limiter quota for the client.
+
+    """
+
+    def __init__(
+        self,
+        clock: LiveClock,
+        base_url: str,
+        api_key: str | None = None,
+        api_secret: str | None = None,
+        ratelimiter_quotas: list[tuple[str, Quota]] | None = None,
+        ratelimiter_default_quota: Quota | None = None,
+    ) -> None:
+        self._clock: LiveClock = clock
+        self._log: Logger = Logger(name=type(self).__name__)
+        self._api_key = api_key
+        self._api_secret = api_secret
+
+        self._base_url: str = base_url
+        self._headers: dict[str, Any] = {
+            "Content-Type": "application/json",
+            "User-Agent": nautilus_trader.USER_AGENT,
+        }
+        self._client = HttpClient(
+            keyed_quotas=ratelimiter_quotas or [],
+            default_quota=ratelimiter_default_quota,
+        )
+
+    @property
+    def base_url(self) -> str:
+        return self._base_url
+
+    @property
+    def api_key(self) -> str | None:
+        return self._api_key
+
+    @property
+    def api_secret(self) -> str | None:
+        return self._api_secret
+
+    @property
+    def has_credentials(self) -> bool:
+        return self._api_key is not None and self._api_secret is not None
+
+    async def send_request(
+        self,
+        method: str,
+        params: dict[str, Any] | None = None,
+        signed: bool = False,
+        ratelimiter_keys: list[str] | None = None,
+    ) -> bytes:
+        """
+        Send a request for the API `method` (e.g. `public/get_instruments`).
+
+        Returns
+        -------
+        bytes
+            The raw JSON-RPC response, with the `result`.
+
+        Raises
+        ------
+        DeribitError
+            If the request fails.
+
+        """
+        path = f"{DERIBIT_API_PATH}/{method}"
+        if params:
+            path += "?" + parse.urlencode({k: _param_str(v) for k, v in params.items()})
+
+        headers = self._headers
+        if signed:
+            headers = {**self._headers, "Authorization": self.sign("GET", path)}
+
+        response: HttpResponse = await self._client.request(
+            HttpMethod.GET,
+            self._base_url + path,
+            headers,
+            None,
+            ratelimiter_keys,
+        )
+
+        response_body = response.body
+
+        if response.status >= 400:
+            try:
+                body = msgspec.json.decode(response_body) if response_body else None
+            except msgspec.DecodeError:
+                body = response_body.decode()
+
+            raise parse_error(response.status, body)
+
+        return response_body
+
+    def sign(
+        self,
+        verb: str,
+        path: str,
+        body: bytes = b"",
+        nonce: str | None = None,
+    ) -> str:
+        """
+        Return the `Authorization` header value for a request.
+
+        Parameters
+        ----------
+        verb : str
+            The HTTP verb (e.g. `GET`).
+        path : str
+            The request path including the query string
+            (e.g. `/api/v2/private/get_account_summary?currency=BTC`).
+        body : bytes, default b""
+            The request body.
+        nonce : str, optional
+            The nonce for the request (a random nonce if ``None``).
+
+        Returns
+        -------
+        str
+
+        Raises
+        ------
+        RuntimeError
+            If the client has no API credentials.
+
+        """
+        if self._api_key is None or self._api_secret is None:
+            raise RuntimeError("Cannot sign request: no API credentials (public access only)")
+
+        timestamp = self._clock.timestamp_ms()
+        nonce = nonce or UUID4().value[:8]
+        message = f"{timestamp}\n{nonce}\n{verb}\n{path}\n{body.decode()}\n"
+        signature = hmac_signature(self._api_secret, message)
+        return (
+            f"deri-hmac-sha256 id={self._api_key},ts={timestamp},sig={signature},nonce={nonce}"
+        )
+
+
+def _param_str(value: Any) -> str:
+    # Booleans are sent as lowercase JSON literals
+    if isinstance(value, bool):
+        return "true" if value else "false"
+    return str(value)
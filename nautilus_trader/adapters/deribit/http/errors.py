@@ -0,0 +1,81 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from typing import Any
+
+from nautilus_trader.adapters.deribit.common.constants import DERIBIT_RETRY_ERRORS
+
+
+class DeribitError(Exception):
+    """
+    Represents Deribit specific errors.
+
+    The `code` is the JSON-RPC error code (e.g. `10009` for insufficient funds), or the
+    HTTP status code if the response has no JSON-RPC error.
+
+    """
+
+    def __init__(
+        self,
+        code: int | str | None,
+        message: str | None,
+    ) -> None:
+        super().__init__(message)
+        self.code = code
+        self.message = message
+
+    def __repr__(self) -> str:
+        return f"{type(self).__name__}(code={self.code}, message='{self.message}')"
+
+
+def parse_error(status: int, body: Any) -> DeribitError:
+    """
+    Return the error for the decoded Deribit error response `body`.
+
+    Errors are reported as `{"error": {"code": ..., "message": ..., "data": {...}}}`,
+    where the optional `data` has the `reason` and invalid `param`.
+
+    """
+    if isinstance(body, dict):
+        error = body.get("error")
+        if isinstance(error, dict) and "code" in error:
+            message = error.get("message")
+            data = error.get("data")
+            if isinstance(data, dict) and "reason" in data:
+                param = data.get("param")
+                reason = f"{param}: {data['reason']}" if param else data["reason"]
+                message = f"{message} ({reason})"
+            return DeribitError(code=error["code"], message=message)
+    return DeribitError(code=status, message=body)
+
+
+def should_retry(error: BaseException) -> bool:
+    """
+    Determine if a retry should be attempted based on the error code.
+
+    Parameters
+    ----------
+    error : BaseException
+        The error to check.
+
+    Returns
+    -------
+    bool
+        True if should retry, otherwise False.
+
+    """
+    if isinstance(error, DeribitError):
+        return error.code in DERIBIT_RETRY_ERRORS
+    return False
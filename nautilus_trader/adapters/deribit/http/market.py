@@ -0,0 +1,84 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import msgspec
+
+from nautilus_trader.adapters.deribit.common.constants import DERIBIT_REST_RATE_LIMIT_KEY
+from nautilus_trader.adapters.deribit.schemas.instrument import DeribitCombo
+from nautilus_trader.adapters.deribit.schemas.instrument import DeribitInstrument
+from nautilus_trader.adapters.deribit.schemas.response import DeribitResponse
+from nautilus_trader.core.correctness import PyCondition
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.adapters.deribit.common.enums import DeribitInstrumentKind
+    from nautilus_trader.adapters.deribit.http.client import DeribitHttpClient
+    from nautilus_trader.common.component import LiveClock
+
+
+class DeribitMarketHttpAPI:
+    """
+    Provides access to the public Deribit market data API methods.
+
+    Parameters
+    ----------
+    client : DeribitHttpClient
+        The Deribit HTTP client.
+    clock : LiveClock
+        The clock for the API client.
+
+    """
+
+    def __init__(
+        self,
+        client: DeribitHttpClient,
+        clock: LiveClock,
+    ) -> None:
+        PyCondition.not_none(client, "client")
+        self.client = client
+        self._clock = clock
+
+        self._decoder_instruments = msgspec.json.Decoder(DeribitResponse[list[DeribitInstrument]])
+        self._decoder_combos = msgspec.json.Decoder(DeribitResponse[list[DeribitCombo]])
+
+    async def fetch_instruments(
+        self,
+        currency: str,
+        kind: DeribitInstrumentKind | None = None,
+    ) -> list[DeribitInstrument]:
+        # Only unexpired instruments are listed
+        params: dict[str, str | bool] = {"currency": currency, "expired": False}
+        if kind is not None:
+            params["kind"] = kind.value
+
+        raw = await self.client.send_request(
+            "public/get_instruments",
+            params,
+            ratelimiter_keys=[DERIBIT_REST_RATE_LIMIT_KEY],
+        )
+        return self._decoder_instruments.decode(raw).result
+
+    async def fetch_combos(self, currency: str) -> list[DeribitCombo]:
+        # Lists the active combos with their legs
+        raw = await self.client.send_request(
+            "public/get_combos",
+            {"currency": currency},
+            ratelimiter_keys=[DERIBIT_REST_RATE_LIMIT_KEY],
+        )
+        return self._decoder_combos.decode(raw).result
@@ -0,0 +1,259 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+from typing import Any
+
+import msgspec
+
+from nautilus_trader.adapters.deribit.common.constants import DERIBIT_ORDER_RATE_LIMIT_KEY
+from nautilus_trader.adapters.deribit.common.constants import DERIBIT_REST_RATE_LIMIT_KEY
+from nautilus_trader.adapters.deribit.common.enums import DeribitDirection
+from nautilus_trader.adapters.deribit.common.enums import DeribitOrderType
+from nautilus_trader.adapters.deribit.schemas.order import DeribitOrder
+from nautilus_trader.adapters.deribit.schemas.order import DeribitOrderResult
+from nautilus_trader.adapters.deribit.schemas.order import DeribitUserTrade
+from nautilus_trader.adapters.deribit.schemas.order import DeribitUserTrades
+from nautilus_trader.adapters.deribit.schemas.response import DeribitResponse
+from nautilus_trader.core.correctness import PyCondition
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.adapters.deribit.common.enums import DeribitTimeInForce
+    from nautilus_trader.adapters.deribit.common.enums import DeribitTriggerType
+    from nautilus_trader.adapters.deribit.http.client import DeribitHttpClient
+    from nautilus_trader.common.component import LiveClock
+
+
+_ORDER_RATE_LIMIT_KEYS = [DERIBIT_REST_RATE_LIMIT_KEY, DERIBIT_ORDER_RATE_LIMIT_KEY]
+
+
+def order_params(
+    instrument_name: str,
+    order_type: DeribitOrderType,
+    amount: str,
+    client_order_id: str,
+    time_in_force: DeribitTimeInForce | None = None,
+    price: str | None = None,
+    trigger_price: str | None = None,
+    trigger: DeribitTriggerType | None = None,
+    post_only: bool = False,
+    reduce_only: bool = False,
+    max_show: str | None = None,
+) -> dict[str, Any]:
+    """
+    Return the request parameters for a new order.
+
+    The direction is given by the API method (`private/buy` or `private/sell`), and the
+    client order ID is sent as the order `label`. Amounts and prices must already be
+    rounded to the instrument minimum trade amount and tick size.
+
+    """
+    params: dict[str, Any] = {
+        "instrument_name": instrument_name,
+        "amount": amount,
+        "type": order_type.value,
+        "label": client_order_id,
+    }
+    if time_in_force is not None and order_type != DeribitOrderType.MARKET:
+        params["time_in_force"] = time_in_force.value
+    if price is not None:
+        params["price"] = price
+    if trigger_price is not None:
+        params["trigger_price"] = trigger_price
+    if trigger is not None:
+        params["trigger"] = trigger.value
+    if post_only:
+        # Reject rather than reprice orders which would cross the book
+        params["post_only"] = True
+        params["reject_post_only"] = True
+    if reduce_only:
+        params["reduce_only"] = True
+    if max_show is not None:
+        params["max_show"] = max_show
+    return params
+
+
+class DeribitTradeHttpAPI:
+    """
+    Provides access to the private Deribit order and trade API methods.
+
+    Parameters
+    ----------
+    client : DeribitHttpClient
+        The Deribit HTTP client (with API credentials).
+    clock : LiveClock
+        The clock for the API client.
+
+    """
+
+    def __init__(
+        self,
+        client: DeribitHttpClient,
+        clock: LiveClock,
+    ) -> None:
+        PyCondition.not_none(client, "client")
+        self.client = client
+        self._clock = clock
+
+        self._decoder_order = msgspec.json.Decoder(DeribitResponse[DeribitOrder])
+        self._decoder_orders = msgspec.json.Decoder(DeribitResponse[list[DeribitOrder]])
+        self._decoder_order_result = msgspec.json.Decoder(DeribitResponse[DeribitOrderResult])
+        self._decoder_count = msgspec.json.Decoder(DeribitResponse[int])
+        self._decoder_trades = msgspec.json.Decoder(DeribitResponse[list[DeribitUserTrade]])
+        self._decoder_user_trades = msgspec.json.Decoder(DeribitResponse[DeribitUserTrades])
+
+    async def place_order(
+        self,
+        direction: DeribitDirection,
+        params: dict[str, Any],
+    ) -> DeribitOrderResult:
+        PyCondition.is_true(direction != DeribitDirection.ZERO, "direction was `zero`")
+
+        raw = await self.client.send_request(
+            "private/buy" if direction == DeribitDirection.BUY else "private/sell",
+            params,
+            signed=True,
+            ratelimiter_keys=_ORDER_RATE_LIMIT_KEYS,
+        )
+        return self._decoder_order_result.decode(raw).result
+
+    async def edit_order(
+        self,
+        order_id: str,
+        amount: str,
+        price: str | None = None,
+        trigger_price: str | None = None,
+    ) -> DeribitOrderResult:
+        # The amount is required, and is the new total amount of the order
+        params: dict[str, Any] = {"order_id": order_id, "amount": amount}
+        if price is not None:
+            params["price"] = price
+        if trigger_price is not None:
+            params["trigger_price"] = trigger_price
+
+        raw = await self.client.send_request(
+            "private/edit",
+            params,
+            signed=True,
+            ratelimiter_keys=_ORDER_RATE_LIMIT_KEYS,
+        )
+        return self._decoder_order_result.decode(raw).result
+
+    async def cancel_order(self, order_id: str) -> DeribitOrder:
+        raw = await self.client.send_request(
+            "private/cancel",
+            {"order_id": order_id},
+            signed=True,
+            ratelimiter_keys=_ORDER_RATE_LIMIT_KEYS,
+        )
+        return self._decoder_order.decode(raw).result
+
+    async def cancel_order_by_label(self, client_order_id: str) -> int:
+        # Returns the number of canceled orders
+        raw = await self.client.send_request(
+            "private/cancel_by_label",
+            {"label": client_order_id},
+            signed=True,
+            ratelimiter_keys=_ORDER_RATE_LIMIT_KEYS,
+        )
+        return self._decoder_count.decode(raw).result
+
+    async def cancel_all_orders(self, instrument_name: str) -> int:
+        # Returns the number of canceled orders
+        raw = await self.client.send_request(
+            "private/cancel_all_by_instrument",
+            {"instrument_name": instrument_name},
+            signed=True,
+            ratelimiter_keys=_ORDER_RATE_LIMIT_KEYS,
+        )
+        return self._decoder_count.decode(raw).result
+
+    async def fetch_order(self, order_id: str) -> DeribitOrder:
+        raw = await self.client.send_request(
+            "private/get_order_state",
+            {"order_id": order_id},
+            signed=True,
+            ratelimiter_keys=[DERIBIT_REST_RATE_LIMIT_KEY],
+        )
+        return self._decoder_order.decode(raw).result
+
+    async def fetch_open_orders(
+        self,
+        currency: str | None = None,
+        instrument_name: str | None = None,
+    ) -> list[DeribitOrder]:
+        PyCondition.is_false(
+            currency is None and instrument_name is None,
+            "both `currency` and `instrument_name` were `None`",
+        )
+
+        if instrument_name is not None:
+            method = "private/get_open_orders_by_instrument"
+            params = {"instrument_name": instrument_name}
+        else:
+            method = "private/get_open_orders_by_currency"
+            params = {"currency": currency}
+
+        raw = await self.client.send_request(
+            method,
+            params,
+            signed=True,
+            ratelimiter_keys=[DERIBIT_REST_RATE_LIMIT_KEY],
+        )
+        return self._decoder_orders.decode(raw).result
+
+    async def fetch_order_history(self, currency: str, count: int = 100) -> list[DeribitOrder]:
+        # Lists the most recent filled and canceled orders
+        raw = await self.client.send_request(
+            "private/get_order_history_by_currency",
+            {"currency": currency, "count": count},
+            signed=True,
+            ratelimiter_keys=[DERIBIT_REST_RATE_LIMIT_KEY],
+        )
+        return self._decoder_orders.decode(raw).result
+
+    async def fetch_user_trades(
+        self,
+        currency: str,
+        start_timestamp: int,
+        end_timestamp: int,
+        count: int = 1000,
+    ) -> DeribitUserTrades:
+        # Timestamps are in milliseconds, and trades are listed most recent first
+        raw = await self.client.send_request(
+            "private/get_user_trades_by_currency_and_time",
+            {
+                "currency": currency,
+                "start_timestamp": start_timestamp,
+                "end_timestamp": end_timestamp,
+                "count": count,
+                "sorting": "desc",
+            },
+            signed=True,
+            ratelimiter_keys=[DERIBIT_REST_RATE_LIMIT_KEY],
+        )
+        return self._decoder_user_trades.decode(raw).result
+
+    async def fetch_order_trades(self, order_id: str) -> list[DeribitUserTrade]:
+        raw = await self.client.send_request(
+            "private/get_user_trades_by_order",
+            {"order_id": order_id},
+            signed=True,
+            ratelimiter_keys=[DERIBIT_REST_RATE_LIMIT_KEY],
+        )
+        return self._decoder_trades.decode(raw).result
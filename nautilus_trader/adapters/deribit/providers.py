@@ -0,0 +1,251 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from nautilus_trader.adapters.deribit.common.constants import DERIBIT_DEFAULT_CURRENCIES
+from nautilus_trader.adapters.deribit.common.constants import DERIBIT_DEFAULT_INSTRUMENT_KINDS
+from nautilus_trader.adapters.deribit.common.constants import DERIBIT_VENUE
+from nautilus_trader.adapters.deribit.common.enums import DeribitInstrumentKind
+from nautilus_trader.adapters.deribit.common.symbol import DeribitSymbol
+from nautilus_trader.adapters.deribit.http.errors import DeribitError
+from nautilus_trader.adapters.deribit.http.market import DeribitMarketHttpAPI
+from nautilus_trader.common.providers import InstrumentProvider
+from nautilus_trader.core.correctness import PyCondition
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.adapters.deribit.http.client import DeribitHttpClient
+    from nautilus_trader.adapters.deribit.schemas.instrument import DeribitComboLeg
+    from nautilus_trader.adapters.deribit.schemas.instrument import DeribitInstrument
+    from nautilus_trader.common.component import LiveClock
+    from nautilus_trader.config import InstrumentProviderConfig
+    from nautilus_trader.model.identifiers import InstrumentId
+    from nautilus_trader.model.instruments import Instrument
+
+
+class DeribitInstrumentProvider(InstrumentProvider):
+    """
+    Provides Nautilus instrument definitions from Deribit futures, perpetuals, options
+    and combos.
+
+    Instruments can be filtered with the `currencies` (e.g. ``["BTC", "ETH"]``) and
+    `kinds` (e.g. ``["option"]``) filters. Each load lists only unexpired instruments,
+    so reloading removes expired instruments and adds newly listed expiries.
+
+    Parameters
+    ----------
+    client : DeribitHttpClient
+        The Deribit HTTP client.
+    clock : LiveClock
+        The clock instance.
+    config : InstrumentProviderConfig, optional
+        The instrument provider configuration, by default None.
+
+    """
+
+    def __init__(
+        self,
+        client: DeribitHttpClient,
+        clock: LiveClock,
+        config: InstrumentProviderConfig | None = None,
+    ) -> None:
+        super().__init__(config=config)
+        self._clock = clock
+        self._client = client
+        self._http_market = DeribitMarketHttpAPI(client, clock)
+
+        self._log_warnings = config.log_warnings if config else True
+
+    async def load_all_async(self, filters: dict | None = None) -> None:
+        filters_str = "..." if not filters else f" with filters {filters}..."
+        self._log.info(f"Loading all instruments{filters_str}")
+
+        # Instruments no longer listed (expired or delisted) are removed
+        loaded = await self._load_instruments(filters)
+        for instrument_id in list(self._instruments):
+            if instrument_id not in loaded:
+                self._instruments.pop(instrument_id)
+
+        self._log.info(f"Loaded {len(self._instruments)} instruments")
+
+    async def load_ids_async(
+        self,
+        instrument_ids: list[InstrumentId],
+        filters: dict | None = None,
+    ) -> None:
+        if not instrument_ids:
+            self._log.warning("No instrument IDs given for loading")
+            return
+
+        # Check all instrument IDs
+        for instrument_id in instrument_ids:
+            PyCondition.equal(
+                instrument_id.venue,
+                DERIBIT_VENUE,
+                "instrument_id.venue",
+                "DERIBIT",
+            )
+
+        await self._load_instruments(filters, set(instrument_ids))
+
+    async def load_async(self, instrument_id: InstrumentId, filters: dict | None = None) -> None:
+        PyCondition.not_none(instrument_id, "instrument_id")
+        await self.load_ids_async([instrument_id], filters)
+
+    def remove_expired(self) -> list[InstrumentId]:
+        """
+        Remove the instruments which have expired.
+
+        Returns
+        -------
+        list[InstrumentId]
+            The IDs of the removed instruments.
+
+        """
+        now_ns = self._clock.timestamp_ns()
+        expired = [
+            instrument.id
+            for instrument in self._instruments.values()
+            if (expiration_ns := _expiration_ns(instrument)) is not None and expiration_ns <= now_ns
+        ]
+        for instrument_id in expired:
+            self._instruments.pop(instrument_id)
+        return expired
+
+    def next_expiration_ns(self) -> int | None:
+        """
+        Return the earliest expiration (UNIX nanoseconds) of the loaded instruments.
+
+        Returns
+        -------
+        int or ``None``
+            ``None`` if no loaded instrument expires (e.g. only perpetuals).
+
+        """
+        now_ns = self._clock.timestamp_ns()
+        expirations = [
+            expiration_ns
+            for instrument in self._instruments.values()
+            if (expiration_ns := _expiration_ns(instrument)) is not None and expiration_ns > now_ns
+        ]
+        return min(expirations, default=None)
+
+    async def _load_instruments(
+        self,
+        filters: dict | None,
+        instrument_ids: set[InstrumentId] | None = None,
+    ) -> set[InstrumentId]:
+        filters = filters or {}
+        currencies = filters.get("currencies", DERIBIT_DEFAULT_CURRENCIES)
+        kinds = [
+            DeribitInstrumentKind(kind)
+            for kind in filters.get("kinds", DERIBIT_DEFAULT_INSTRUMENT_KINDS)
+        ]
+        if instrument_ids is not None:
+            # Only list the currencies of the requested instruments
+            currencies = {DeribitSymbol(i.symbol.value).currency for i in instrument_ids}
+
+        loaded: set[InstrumentId] = set()
+        for currency in currencies:
+            combo_legs = await self._fetch_combo_legs(currency, kinds)
+            for kind in kinds:
+                try:
+                    deribit_instruments = await self._http_market.fetch_instruments(currency, kind)
+                except DeribitError as e:
+                    self._log.warning(f"Unable to load {currency} {kind.value} instruments: {e}")
+                    continue
+
+                for deribit_instrument in deribit_instruments:
+                    instrument_id = self._parse_instrument(
+                        deribit_instrument,
+                        combo_legs.get(deribit_instrument.instrument_name),
+                        instrument_ids,
+                    )
+                    if instrument_id is not None:
+                        loaded.add(instrument_id)
+
+        return loaded
+
+    async def _fetch_combo_legs(
+        self,
+        currency: str,
+        kinds: list[DeribitInstrumentKind],
+    ) -> dict[str, list[DeribitComboLeg]]:
+        if not any(kind.value.endswith("_combo") for kind in kinds):
+            return {}
+
+        # The combo ID is the instrument name of the combo
+        try:
+            combos = await self._http_market.fetch_combos(currency)
+        except DeribitError as e:
+            self._log.warning(f"Unable to fetch {currency} combo legs: {e}")
+            return {}
+
+        return {combo.id: combo.legs for combo in combos}
+
+    def _parse_instrument(
+        self,
+        deribit_instrument: DeribitInstrument,
+        legs: list[DeribitComboLeg] | None,
+        instrument_ids: set[InstrumentId] | None,
+    ) -> InstrumentId | None:
+        if not deribit_instrument.is_active:
+            return None
+
+        try:
+            base_currency = self.currency(deribit_instrument.base_currency.upper())
+            quote_currency = self.currency(deribit_instrument.quote_currency.upper())
+            settlement_currency = self.currency(
+                (
+                    deribit_instrument.settlement_currency or deribit_instrument.quote_currency
+                ).upper(),
+            )
+            assert base_currency is not None  # Type checking
+            assert quote_currency is not None  # Type checking
+            assert settlement_currency is not None  # Type checking
+            self.add_currency(base_currency)
+            self.add_currency(quote_currency)
+            self.add_currency(settlement_currency)
+
+            ts_event = self._clock.timestamp_ns()
+            ts_init = self._clock.timestamp_ns()
+            instrument = deribit_instrument.parse_to_instrument(
+                base_currency=base_currency,
+                quote_currency=quote_currency,
+                settlement_currency=settlement_currency,
+                legs=legs,
+                ts_event=ts_event,
+                ts_init=ts_init,
+            )
+        except ValueError as e:
+            if self._log_warnings:
+                self._log.warning(
+                    f"Unable to parse instrument {deribit_instrument.instrument_name}: {e}",
+                )
+            return None
+
+        if instrument_ids is not None and instrument.id not in instrument_ids:
+            return None
+
+        self.add(instrument=instrument)
+        return instrument.id
+
+
+def _expiration_ns(instrument: Instrument) -> int | None:
+    # Perpetuals have no expiration
+    return getattr(instrument, "expiration_ns", None)
@@ -0,0 +1,249 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from decimal import Decimal
+
+import msgspec
+
+from nautilus_trader.adapters.deribit.common.constants import DERIBIT_PERPETUAL_EXPIRATION_MS
+from nautilus_trader.adapters.deribit.common.enums import DeribitInstrumentKind
+from nautilus_trader.adapters.deribit.common.enums import DeribitOptionType
+from nautilus_trader.adapters.deribit.common.parsing import decimal_places
+from nautilus_trader.adapters.deribit.common.parsing import to_decimal
+from nautilus_trader.adapters.deribit.common.symbol import DeribitSymbol
+from nautilus_trader.core.datetime import millis_to_nanos
+from nautilus_trader.model.enums import AssetClass
+from nautilus_trader.model.enums import OptionKind
+from nautilus_trader.model.identifiers import Symbol
+from nautilus_trader.model.instruments import CryptoFuture
+from nautilus_trader.model.instruments import CryptoPerpetual
+from nautilus_trader.model.instruments import FuturesSpread
+from nautilus_trader.model.instruments import OptionContract
+from nautilus_trader.model.instruments import OptionSpread
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+
+
+class DeribitTickSizeStep(msgspec.Struct, frozen=True):
+    above_price: float
+    tick_size: float
+
+
+class DeribitComboLeg(msgspec.Struct, frozen=True):
+    instrument_name: str
+    amount: int  # Ratio of the leg, negative for sold legs
+
+
+class DeribitCombo(msgspec.Struct, frozen=True):
+    """
+    Represents a Deribit combo, as listed by `public/get_combos`.
+    """
+
+    id: str
+    state: str
+    legs: list[DeribitComboLeg]
+    instrument_id: int | None = None
+    creation_timestamp: int | None = None
+    state_timestamp: int | None = None
+
+
+class DeribitInstrument(msgspec.Struct, frozen=True):
+    """
+    Represents a Deribit instrument, as listed by `public/get_instruments`.
+    """
+
+    instrument_name: str
+    kind: DeribitInstrumentKind
+    base_currency: str
+    quote_currency: str
+    tick_size: float
+    min_trade_amount: float
+    contract_size: float
+    creation_timestamp: int
+    expiration_timestamp: int
+    is_active: bool = True
+    settlement_currency: str | None = None
+    counter_currency: str | None = None
+    settlement_period: str | None = None
+    instrument_type: str | None = None  # `reversed` (inverse) or `linear`
+    strike: float | None = None
+    option_type: DeribitOptionType | None = None
+    tick_size_steps: list[DeribitTickSizeStep] = []
+    maker_commission: float | None = None
+    taker_commission: float | None = None
+    block_trade_commission: float | None = None
+    block_trade_min_trade_amount: float | None = None
+    block_trade_tick_size: float | None = None
+    max_leverage: int | None = None
+    price_index: str | None = None
+    instrument_id: int | None = None
+
+    @property
+    def is_perpetual(self) -> bool:
+        return self.settlement_period == "perpetual"
+
+    @property
+    def is_inverse(self) -> bool:
+        return self.instrument_type == "reversed"
+
+    @property
+    def is_combo(self) -> bool:
+        return self.kind in (DeribitInstrumentKind.FUTURE_COMBO, DeribitInstrumentKind.OPTION_COMBO)
+
+    @property
+    def expiration_ns(self) -> int:
+        return millis_to_nanos(self.expiration_timestamp)
+
+    def parse_to_instrument(
+        self,
+        base_currency: Currency,
+        quote_currency: Currency,
+        settlement_currency: Currency,
+        legs: list[DeribitComboLeg] | None,
+        ts_event: int,
+        ts_init: int,
+    ) -> CryptoPerpetual | CryptoFuture | OptionContract | FuturesSpread | OptionSpread:
+        """
+        Parse the instrument definition into a Nautilus instrument.
+
+        Future and perpetual quantities are the Deribit amount (USD for inverse contracts
+        and the base currency for linear contracts). Options and combos are traded in
+        whole contracts, where each contract is the minimum trade amount (the instrument
+        multiplier) of the underlying.
+
+        The price increment is the smallest tick size, option prices above a tick size
+        step must be a multiple of the tick size of the step.
+
+        Raises
+        ------
+        ValueError
+            If the instrument kind is not supported.
+
+        """
+        tick_size = to_decimal(self.tick_size)
+        price_precision = decimal_places(tick_size)
+        price_increment = Price(float(tick_size), price_precision)
+        min_trade_amount = to_decimal(self.min_trade_amount)
+
+        instrument_id = DeribitSymbol(self.instrument_name).to_instrument_id()
+        raw_symbol = Symbol(self.instrument_name)
+        activation_ns = millis_to_nanos(self.creation_timestamp)
+        maker_fee = to_decimal(self.maker_commission or 0)
+        taker_fee = to_decimal(self.taker_commission or 0)
+
+        info = msgspec.to_builtins(self)
+        if legs is not None:
+            info["legs"] = msgspec.to_builtins(legs)
+
+        match self.kind:
+            case DeribitInstrumentKind.FUTURE:
+                size_precision = decimal_places(min_trade_amount)
+                size_increment = Quantity(float(min_trade_amount), size_precision)
+
+                # Margins are determined by the margin model of the account,
+                # and are reported with the account state
+                common = {
+                    "instrument_id": instrument_id,
+                    "raw_symbol": raw_symbol,
+                    "quote_currency": quote_currency,
+                    "settlement_currency": settlement_currency,
+                    "is_inverse": self.is_inverse,
+                    "price_precision": price_precision,
+                    "size_precision": size_precision,
+                    "price_increment": price_increment,
+                    "size_increment": size_increment,
+                    "multiplier": Quantity.from_int(1),
+                    "max_quantity": None,
+                    "min_quantity": size_increment,
+                    "max_notional": None,
+                    "min_notional": None,
+                    "max_price": None,
+                    "min_price": None,
+                    "margin_init": Decimal(0),
+                    "margin_maint": Decimal(0),
+                    "maker_fee": maker_fee,
+                    "taker_fee": taker_fee,
+                    "ts_event": ts_event,
+                    "ts_init": ts_init,
+                    "info": info,
+                }
+                if self.is_perpetual or self.expiration_timestamp >= DERIBIT_PERPETUAL_EXPIRATION_MS:
+                    return CryptoPerpetual(base_currency=base_currency, **common)
+
+                return CryptoFuture(
+                    underlying=base_currency,
+                    activation_ns=activation_ns,
+                    expiration_ns=self.expiration_ns,
+                    **common,
+                )
+            case DeribitInstrumentKind.OPTION:
+                if self.strike is None or self.option_type is None:
+                    raise ValueError(f"no strike or option type for option {self.instrument_name}")
+
+                strike = to_decimal(self.strike)
+                return OptionContract(
+                    instrument_id=instrument_id,
+                    raw_symbol=raw_symbol,
+                    asset_class=AssetClass.CRYPTOCURRENCY,
+                    currency=quote_currency,
+                    price_precision=price_precision,
+                    price_increment=price_increment,
+                    multiplier=Quantity(float(min_trade_amount), decimal_places(min_trade_amount)),
+                    lot_size=Quantity.from_int(1),
+                    underlying=base_currency.code,
+                    option_kind=(
+                        OptionKind.CALL
+                        if self.option_type == DeribitOptionType.CALL
+                        else OptionKind.PUT
+                    ),
+                    strike_price=Price(float(strike), decimal_places(strike)),
+                    activation_ns=activation_ns,
+                    expiration_ns=self.expiration_ns,
+                    ts_event=ts_event,
+                    ts_init=ts_init,
+                    maker_fee=maker_fee,
+                    taker_fee=taker_fee,
+                    info=info,
+                )
+            case DeribitInstrumentKind.FUTURE_COMBO | DeribitInstrumentKind.OPTION_COMBO:
+                spread_cls = (
+                    FuturesSpread
+                    if self.kind == DeribitInstrumentKind.FUTURE_COMBO
+                    else OptionSpread
+                )
+                return spread_cls(
+                    instrument_id=instrument_id,
+                    raw_symbol=raw_symbol,
+                    asset_class=AssetClass.CRYPTOCURRENCY,
+                    currency=quote_currency,
+                    price_precision=price_precision,
+                    price_increment=price_increment,
+                    multiplier=Quantity(float(min_trade_amount), decimal_places(min_trade_amount)),
+                    lot_size=Quantity.from_int(1),
+                    underlying=base_currency.code,
+                    strategy_type=DeribitSymbol(self.instrument_name).strategy_type,
+                    activation_ns=activation_ns,
+                    expiration_ns=self.expiration_ns,
+                    ts_event=ts_event,
+                    ts_init=ts_init,
+                    maker_fee=maker_fee,
+                    taker_fee=taker_fee,
+                    info=info,
+                )
+            case _:
+                raise ValueError(f"unsupported instrument kind {self.kind.value}")
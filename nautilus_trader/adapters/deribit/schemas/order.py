@@ -0,0 +1,228 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+import msgspec
+
+from nautilus_trader.adapters.deribit.common.enums import DeribitDirection
+from nautilus_trader.adapters.deribit.common.enums import DeribitEnumParser
+from nautilus_trader.adapters.deribit.common.enums import DeribitLiquidity
+from nautilus_trader.adapters.deribit.common.enums import DeribitOrderState
+from nautilus_trader.adapters.deribit.common.enums import DeribitOrderType
+from nautilus_trader.adapters.deribit.common.enums import DeribitTimeInForce
+from nautilus_trader.adapters.deribit.common.enums import DeribitTriggerType
+from nautilus_trader.adapters.deribit.common.parsing import amount_to_quantity
+from nautilus_trader.adapters.deribit.common.parsing import to_decimal
+from nautilus_trader.core.datetime import millis_to_nanos
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.execution.reports import FillReport
+from nautilus_trader.execution.reports import OrderStatusReport
+from nautilus_trader.model.enums import ContingencyType
+from nautilus_trader.model.enums import LiquiditySide
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.identifiers import AccountId
+from nautilus_trader.model.identifiers import ClientOrderId
+from nautilus_trader.model.identifiers import TradeId
+from nautilus_trader.model.identifiers import VenueOrderId
+from nautilus_trader.model.instruments import Instrument
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import Money
+
+
+class DeribitOrder(msgspec.Struct, frozen=True):
+    order_id: str
+    instrument_name: str
+    direction: DeribitDirection
+    amount: float
+    order_type: DeribitOrderType
+    order_state: DeribitOrderState
+    creation_timestamp: int
+    last_update_timestamp: int
+    price: float | str | None = None  # `market_price` for market orders
+    label: str | None = None  # The client order ID
+    filled_amount: float | None = None
+    average_price: float | None = None
+    time_in_force: DeribitTimeInForce | None = None
+    post_only: bool | None = None
+    reduce_only: bool | None = None
+    trigger_price: float | None = None
+    trigger: DeribitTriggerType | None = None
+    triggered: bool | None = None
+    max_show: float | None = None
+    combo_id: str | None = None
+    cancel_reason: str | None = None
+    reject_post_only: bool | None = None
+
+    @property
+    def ts_event(self) -> int:
+        return millis_to_nanos(self.last_update_timestamp)
+
+    def parse_to_order_status_report(
+        self,
+        account_id: AccountId,
+        instrument: Instrument,
+        client_order_id: ClientOrderId | None,
+        report_id: UUID4,
+        enum_parser: DeribitEnumParser,
+        ts_init: int,
+    ) -> OrderStatusReport:
+        filled_amount = self.filled_amount or 0
+        order_status = enum_parser.parse_deribit_order_status(
+            self.order_state,
+            is_partially_filled=filled_amount > 0,
+        )
+
+        trigger_price = None
+        if self.trigger_price is not None:
+            trigger_price = instrument.make_price(self.trigger_price)
+
+        price = None
+        if isinstance(self.price, float | int):
+            price = instrument.make_price(self.price)
+
+        time_in_force = enum_parser.parse_deribit_time_in_force(
+            self.time_in_force or DeribitTimeInForce.GTC,
+        )
+
+        cancel_reason = None
+        if order_status == OrderStatus.CANCELED and self.cancel_reason:
+            cancel_reason = self.cancel_reason
+
+        return OrderStatusReport(
+            account_id=account_id,
+            instrument_id=instrument.id,
+            client_order_id=client_order_id,
+            order_list_id=None,
+            venue_order_id=VenueOrderId(self.order_id),
+            order_side=enum_parser.parse_deribit_order_side(self.direction),
+            order_type=enum_parser.parse_deribit_order_type(self.order_type),
+            contingency_type=ContingencyType.NO_CONTINGENCY,
+            time_in_force=time_in_force,
+            order_status=order_status,
+            price=price,
+            trigger_price=trigger_price,
+            trigger_type=enum_parser.parse_deribit_trigger_type(self.trigger),
+            quantity=amount_to_quantity(self.amount, instrument),
+            filled_qty=amount_to_quantity(filled_amount, instrument),
+            display_qty=(
+                amount_to_quantity(self.max_show, instrument)
+                if self.max_show is not None and self.max_show < self.amount
+                else None
+            ),
+            avg_px=to_decimal(self.average_price) if self.average_price else None,
+            post_only=bool(self.post_only),
+            reduce_only=bool(self.reduce_only),
+            cancel_reason=cancel_reason,
+            ts_accepted=millis_to_nanos(self.creation_timestamp),
+            ts_last=self.ts_event,
+            report_id=report_id,
+            ts_init=ts_init,
+        )
+
+
+class DeribitUserTrade(msgspec.Struct, frozen=True):
+    """
+    Represents a trade of the user, as reported by trade history and the `user.trades`
+    channel.
+
+    Trades of combo orders are reported for each leg instrument (with the `combo_id` and
+    `combo_trade_id`), and block trades with the `block_trade_id`.
+
+    """
+
+    trade_id: str
+    order_id: str
+    instrument_name: str
+    direction: DeribitDirection
+    amount: float
+    price: float
+    fee: float
+    fee_currency: str
+    timestamp: int
+    liquidity: DeribitLiquidity | None = None
+    label: str | None = None
+    order_type: str | None = None
+    state: str | None = None
+    trade_seq: int | None = None
+    index_price: float | None = None
+    mark_price: float | None = None
+    block_trade_id: str | None = None
+    combo_id: str | None = None
+    combo_trade_id: int | None = None
+    reduce_only: bool | None = None
+    post_only: bool | None = None
+
+    @property
+    def liquidity_side(self) -> LiquiditySide:
+        match self.liquidity:
+            case DeribitLiquidity.MAKER:
+                return LiquiditySide.MAKER
+            case DeribitLiquidity.TAKER:
+                return LiquiditySide.TAKER
+            case _:
+                return LiquiditySide.NO_LIQUIDITY_SIDE
+
+    @property
+    def is_block_trade(self) -> bool:
+        return self.block_trade_id is not None
+
+    @property
+    def ts_event(self) -> int:
+        return millis_to_nanos(self.timestamp)
+
+    def parse_commission(self) -> Money:
+        # Fees are negative for maker rebates
+        currency = Currency.from_str(self.fee_currency.upper())
+        return Money(to_decimal(self.fee), currency)
+
+    def parse_to_fill_report(
+        self,
+        account_id: AccountId,
+        instrument: Instrument,
+        client_order_id: ClientOrderId | None,
+        report_id: UUID4,
+        enum_parser: DeribitEnumParser,
+        ts_init: int,
+    ) -> FillReport:
+        return FillReport(
+            client_order_id=client_order_id,
+            venue_order_id=VenueOrderId(self.order_id),
+            trade_id=TradeId(self.trade_id),
+            account_id=account_id,
+            instrument_id=instrument.id,
+            order_side=enum_parser.parse_deribit_order_side(self.direction),
+            last_qty=amount_to_quantity(self.amount, instrument),
+            last_px=instrument.make_price(self.price),
+            commission=self.parse_commission(),
+            liquidity_side=self.liquidity_side,
+            report_id=report_id,
+            ts_event=self.ts_event,
+            ts_init=ts_init,
+        )
+
+
+class DeribitOrderResult(msgspec.Struct, frozen=True):
+    """
+    Represents the result of placing or editing an order, with any immediate trades.
+    """
+
+    order: DeribitOrder
+    trades: list[DeribitUserTrade] = []
+
+
+class DeribitUserTrades(msgspec.Struct, frozen=True):
+    trades: list[DeribitUserTrade]
+    has_more: bool = False
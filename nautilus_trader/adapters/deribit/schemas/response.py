@@ -0,0 +1,33 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from typing import Generic
+from typing import TypeVar
+
+import msgspec
+
+
+T = TypeVar("T")
+
+
+class DeribitResponse(msgspec.Struct, Generic[T], frozen=True):
+    """
+    Represents a Deribit JSON-RPC response, with the `result` of the API method.
+    """
+
+    result: T
+    usIn: int | None = None
+    usOut: int | None = None
+    testnet: bool | None = None
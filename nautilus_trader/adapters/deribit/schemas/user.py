@@ -0,0 +1,192 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from decimal import Decimal
+
+import msgspec
+
+from nautilus_trader.adapters.deribit.common.enums import DeribitDirection
+from nautilus_trader.adapters.deribit.common.enums import DeribitInstrumentKind
+from nautilus_trader.adapters.deribit.common.parsing import amount_to_quantity
+from nautilus_trader.adapters.deribit.common.parsing import to_decimal
+from nautilus_trader.core.datetime import millis_to_nanos
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.execution.reports import PositionStatusReport
+from nautilus_trader.model.enums import PositionSide
+from nautilus_trader.model.identifiers import AccountId
+from nautilus_trader.model.instruments import Instrument
+from nautilus_trader.model.objects import AccountBalance
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import MarginBalance
+from nautilus_trader.model.objects import Money
+
+
+class DeribitAccountSummary(msgspec.Struct, frozen=True):
+    """
+    Represents the account summary for a currency, as reported by
+    `private/get_account_summary` and the `user.portfolio` channel.
+
+    With portfolio margin (and cross collateral) enabled, margins are determined across
+    all currencies and the `total_*_usd` fields report the account-wide USD values.
+
+    """
+
+    currency: str
+    equity: float
+    balance: float
+    available_funds: float
+    initial_margin: float
+    maintenance_margin: float
+    margin_balance: float | None = None
+    margin_model: str | None = None
+    portfolio_margining_enabled: bool | None = None
+    cross_collateral_enabled: bool | None = None
+    projected_initial_margin: float | None = None
+    projected_maintenance_margin: float | None = None
+    available_withdrawal_funds: float | None = None
+    total_pl: float | None = None
+    session_upl: float | None = None
+    session_rpl: float | None = None
+    total_equity_usd: float | None = None
+    total_margin_balance_usd: float | None = None
+    total_initial_margin_usd: float | None = None
+    total_maintenance_margin_usd: float | None = None
+
+    @property
+    def nautilus_currency(self) -> Currency:
+        return Currency.from_str(self.currency.upper())
+
+    @property
+    def is_cross_margin(self) -> bool:
+        # Margins of a cross collateral account are held in USD across currencies
+        return bool(self.cross_collateral_enabled)
+
+    def parse_to_account_balance(self) -> AccountBalance:
+        # The margin balance includes unrealized PnL, and the available funds exclude
+        # the initial margin for positions and open orders
+        currency = self.nautilus_currency
+        total = to_decimal(self.margin_balance if self.margin_balance is not None else self.equity)
+        total = max(total, Decimal(0))
+        free = min(max(to_decimal(self.available_funds), Decimal(0)), total)
+        return AccountBalance(
+            total=Money(total, currency),
+            locked=Money(total - free, currency),
+            free=Money(free, currency),
+        )
+
+    def parse_to_margin_balance(self) -> MarginBalance | None:
+        # Return the account-wide USD margin for cross collateral accounts
+        if self.is_cross_margin:
+            if self.total_initial_margin_usd is None or self.total_maintenance_margin_usd is None:
+                return None
+
+            usd = Currency.from_str("USD")
+            return MarginBalance(
+                initial=Money(to_decimal(self.total_initial_margin_usd), usd),
+                maintenance=Money(to_decimal(self.total_maintenance_margin_usd), usd),
+            )
+
+        currency = self.nautilus_currency
+        return MarginBalance(
+            initial=Money(to_decimal(self.initial_margin), currency),
+            maintenance=Money(to_decimal(self.maintenance_margin), currency),
+        )
+
+
+def parse_account_summaries(
+    summaries: list[DeribitAccountSummary],
+) -> tuple[list[AccountBalance], list[MarginBalance]]:
+    """
+    Parse the account summaries into balances and margins.
+
+    The account-wide USD margin of cross collateral accounts is reported once, and
+    currencies with no balance are skipped.
+
+    Parameters
+    ----------
+    summaries : list[DeribitAccountSummary]
+        The account summaries for each currency.
+
+    Returns
+    -------
+    tuple[list[AccountBalance], list[MarginBalance]]
+
+    """
+    balances: list[AccountBalance] = []
+    margins: list[MarginBalance] = []
+    has_cross_margin = False
+    for summary in summaries:
+        if summary.equity == 0 and summary.balance == 0:
+            continue
+
+        balances.append(summary.parse_to_account_balance())
+        if summary.is_cross_margin:
+            if has_cross_margin:
+                continue
+            has_cross_margin = True
+
+        margin = summary.parse_to_margin_balance()
+        if margin is not None:
+            margins.append(margin)
+
+    return balances, margins
+
+
+class DeribitPosition(msgspec.Struct, frozen=True):
+    instrument_name: str
+    kind: DeribitInstrumentKind
+    size: float  # Signed amount, negative for short positions
+    direction: DeribitDirection
+    average_price: float | None = None
+    mark_price: float | None = None
+    index_price: float | None = None
+    initial_margin: float | None = None
+    maintenance_margin: float | None = None
+    floating_profit_loss: float | None = None
+    realized_profit_loss: float | None = None
+    total_profit_loss: float | None = None
+    delta: float | None = None
+    leverage: int | None = None
+    settlement_price: float | None = None
+    creation_timestamp: int | None = None
+
+    def parse_to_position_status_report(
+        self,
+        account_id: AccountId,
+        instrument: Instrument,
+        report_id: UUID4,
+        ts_init: int,
+    ) -> PositionStatusReport:
+        size = to_decimal(self.size)
+        if size > 0:
+            position_side = PositionSide.LONG
+        elif size < 0:
+            position_side = PositionSide.SHORT
+        else:
+            position_side = PositionSide.FLAT
+
+        return PositionStatusReport(
+            account_id=account_id,
+            instrument_id=instrument.id,
+            position_side=position_side,
+            quantity=amount_to_quantity(abs(self.size), instrument),
+            report_id=report_id,
+            ts_init=ts_init,
+            ts_last=(
+                millis_to_nanos(self.creation_timestamp) if self.creation_timestamp else ts_init
+            ),
+        )
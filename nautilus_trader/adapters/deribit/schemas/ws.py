@@ -0,0 +1,255 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from typing import Any
+
+import msgspec
+
+from nautilus_trader.adapters.deribit.common.parsing import amount_to_quantity
+from nautilus_trader.adapters.deribit.common.parsing import parse_aggressor_side
+from nautilus_trader.adapters.deribit.schemas.order import DeribitOrder
+from nautilus_trader.adapters.deribit.schemas.order import DeribitUserTrade
+from nautilus_trader.adapters.deribit.schemas.user import DeribitAccountSummary
+from nautilus_trader.core.datetime import millis_to_nanos
+from nautilus_trader.model.data import BookOrder
+from nautilus_trader.model.data import OrderBookDelta
+from nautilus_trader.model.data import OrderBookDeltas
+from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.model.data import TradeTick
+from nautilus_trader.model.enums import BookAction
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import RecordFlag
+from nautilus_trader.model.identifiers import TradeId
+from nautilus_trader.model.instruments import Instrument
+
+
+class DeribitWsError(msgspec.Struct):
+    code: int
+    message: str
+    data: Any | None = None
+
+
+class DeribitWsParams(msgspec.Struct):
+    channel: str | None = None
+    type: str | None = None  # `heartbeat` or `test_request` for heartbeat notifications
+
+
+class DeribitWsMessageGeneral(msgspec.Struct):
+    """
+    Represents the common fields of all Deribit JSON-RPC WebSocket messages.
+
+    Responses to requests have the request `id` (and a `result` or `error`), while
+    subscription notifications have a `method` of `subscription` and the `channel`.
+
+    """
+
+    jsonrpc: str | None = None
+    id: int | None = None
+    method: str | None = None
+    params: DeribitWsParams | None = None
+    error: DeribitWsError | None = None
+
+
+################################################################################
+# Market data
+################################################################################
+
+
+_BOOK_ACTIONS = {
+    "new": BookAction.ADD,
+    "change": BookAction.UPDATE,
+    "delete": BookAction.DELETE,
+}
+
+
+class DeribitWsBook(msgspec.Struct):
+    type: str  # `snapshot` or `change`
+    instrument_name: str
+    timestamp: int
+    change_id: int
+    bids: list[tuple[str, float, float]]  # [action, price, amount]
+    asks: list[tuple[str, float, float]]
+    prev_change_id: int | None = None
+
+    @property
+    def is_snapshot(self) -> bool:
+        return self.type == "snapshot"
+
+    def parse_to_deltas(self, instrument: Instrument, ts_init: int) -> OrderBookDeltas:
+        """
+        Parse the price level changes into order book deltas.
+
+        A snapshot clears the existing book, and the `change_id` is the sequence of
+        the deltas (each change follows the `prev_change_id`).
+
+        """
+        ts_event = millis_to_nanos(self.timestamp)
+        is_snapshot = self.is_snapshot
+        deltas: list[OrderBookDelta] = []
+        if is_snapshot:
+            deltas.append(
+                OrderBookDelta.clear(instrument.id, self.change_id, ts_event, ts_init),
+            )
+
+        levels = [(OrderSide.BUY, level) for level in self.bids]
+        levels += [(OrderSide.SELL, level) for level in self.asks]
+        levels_len = len(levels)
+        for idx, (side, (action, price, amount)) in enumerate(levels):
+            flags = RecordFlag.F_SNAPSHOT if is_snapshot else 0
+            if idx == levels_len - 1:
+                # F_LAST, 1 << 7
+                # Last message in the book event or packet from the venue for a given `instrument_id`
+                flags |= RecordFlag.F_LAST
+
+            book_action = BookAction.ADD if is_snapshot else _BOOK_ACTIONS[action]
+            deltas.append(
+                OrderBookDelta(
+                    instrument_id=instrument.id,
+                    action=book_action,
+                    order=BookOrder(
+                        side=side,
+                        price=instrument.make_price(price),
+                        size=amount_to_quantity(
+                            0 if book_action == BookAction.DELETE else amount,
+                            instrument,
+                        ),
+                        order_id=0,
+                    ),
+                    flags=flags,
+                    sequence=self.change_id,
+                    ts_event=ts_event,
+                    ts_init=ts_init,
+                ),
+            )
+
+        return OrderBookDeltas(instrument_id=instrument.id, deltas=deltas)
+
+
+class DeribitWsQuote(msgspec.Struct):
+    instrument_name: str
+    timestamp: int
+    best_bid_price: float | None = None
+    best_bid_amount: float | None = None
+    best_ask_price: float | None = None
+    best_ask_amount: float | None = None
+
+    def parse_to_quote_tick(self, instrument: Instrument, ts_init: int) -> QuoteTick | None:
+        if not self.best_bid_amount or not self.best_ask_amount:
+            return None  # One side of the book is empty
+
+        return QuoteTick(
+            instrument_id=instrument.id,
+            bid_price=instrument.make_price(self.best_bid_price or 0),
+            ask_price=instrument.make_price(self.best_ask_price or 0),
+            bid_size=amount_to_quantity(self.best_bid_amount, instrument),
+            ask_size=amount_to_quantity(self.best_ask_amount, instrument),
+            ts_event=millis_to_nanos(self.timestamp),
+            ts_init=ts_init,
+        )
+
+
+class DeribitWsTrade(msgspec.Struct):
+    trade_id: str
+    instrument_name: str
+    direction: str
+    price: float
+    amount: float
+    timestamp: int
+    trade_seq: int | None = None
+    tick_direction: int | None = None
+    mark_price: float | None = None
+    index_price: float | None = None
+    iv: float | None = None
+    liquidation: str | None = None
+    block_trade_id: str | None = None
+    combo_id: str | None = None
+    combo_trade_id: int | None = None
+
+    @property
+    def is_block_trade(self) -> bool:
+        return self.block_trade_id is not None
+
+    def parse_to_trade_tick(self, instrument: Instrument, ts_init: int) -> TradeTick:
+        # The direction is the side of the aggressing (taker) order
+        return TradeTick(
+            instrument_id=instrument.id,
+            price=instrument.make_price(self.price),
+            size=amount_to_quantity(self.amount, instrument),
+            aggressor_side=parse_aggressor_side(self.direction),
+            trade_id=TradeId(self.trade_id),
+            ts_event=millis_to_nanos(self.timestamp),
+            ts_init=ts_init,
+        )
+
+
+class DeribitWsBookParams(msgspec.Struct):
+    channel: str
+    data: DeribitWsBook
+
+
+class DeribitWsBookMsg(msgspec.Struct):
+    params: DeribitWsBookParams
+
+
+class DeribitWsQuoteParams(msgspec.Struct):
+    channel: str
+    data: DeribitWsQuote
+
+
+class DeribitWsQuoteMsg(msgspec.Struct):
+    params: DeribitWsQuoteParams
+
+
+class DeribitWsTradesParams(msgspec.Struct):
+    channel: str
+    data: list[DeribitWsTrade]
+
+
+class DeribitWsTradesMsg(msgspec.Struct):
+    params: DeribitWsTradesParams
+
+
+################################################################################
+# User
+################################################################################
+
+
+class DeribitWsOrderParams(msgspec.Struct):
+    channel: str
+    data: DeribitOrder  # Raw order channels report each order change
+
+
+class DeribitWsOrderMsg(msgspec.Struct):
+    params: DeribitWsOrderParams
+
+
+class DeribitWsUserTradesParams(msgspec.Struct):
+    channel: str
+    data: list[DeribitUserTrade]
+
+
+class DeribitWsUserTradesMsg(msgspec.Struct):
+    params: DeribitWsUserTradesParams
+
+
+class DeribitWsPortfolioParams(msgspec.Struct):
+    channel: str
+    data: DeribitAccountSummary
+
+
+class DeribitWsPortfolioMsg(msgspec.Struct):
+    params: DeribitWsPortfolioParams
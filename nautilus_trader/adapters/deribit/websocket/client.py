@@ -0,0 +1,349 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+import asyncio
+import itertools
+from typing import TYPE_CHECKING
+from typing import Any
+
+from msgspec import json as msgspec_json
+
+from nautilus_trader.adapters.deribit.schemas.ws import DeribitWsMessageGeneral
+from nautilus_trader.common.component import LiveClock
+from nautilus_trader.common.component import Logger
+from nautilus_trader.common.enums import LogColor
+from nautilus_trader.core.nautilus_pyo3 import WebSocketClient
+from nautilus_trader.core.nautilus_pyo3 import WebSocketClientError
+from nautilus_trader.core.nautilus_pyo3 import WebSocketConfig
+from nautilus_trader.core.nautilus_pyo3 import hmac_signature
+from nautilus_trader.core.uuid import UUID4
+
+
+if TYPE_CHECKING:
+    from collections.abc import Awaitable
+    from collections.abc import Callable
+
+
+# Request ID of the heartbeat `public/test` requests (responses are ignored)
+_HEARTBEAT_REQUEST_ID = 0
+_HEARTBEAT_MSG = msgspec_json.encode(
+    {"jsonrpc": "2.0", "id": _HEARTBEAT_REQUEST_ID, "method": "public/test", "params": {}},
+).decode()
+
+
+class DeribitWebSocketClient:
+    """
+    Provides a Deribit JSON-RPC streaming WebSocket client.
+
+    Public and private channels share a single connection, where private channels
+    (prefixed `user.`) require the connection to be authenticated with `public/auth`
+    using a `client_signature` grant. Requests are matched to their responses by the
+    request `id`, and channel notifications are passed to the `handler`.
+
+    Parameters
+    ----------
+    clock : LiveClock
+        The clock instance.
+    base_url : str
+        The base URL for the WebSocket connection.
+    handler : Callable[[bytes], None]
+        The callback handler for subscription notifications.
+    handler_reconnect : Callable[..., Awaitable[None]], optional
+        The callback handler to be called on reconnect.
+    loop : asyncio.AbstractEventLoop
+        The event loop for the client.
+    api_key : str, optional
+        The Deribit client ID (required for private channels).
+    api_secret : str, optional
+        The Deribit client secret (required for private channels).
+    request_timeout_secs : float, default 10.0
+        The timeout for request responses.
+
+    """
+
+    def __init__(
+        self,
+        clock: LiveClock,
+        base_url: str,
+        handler: Callable[[bytes], None],
+        handler_reconnect: Callable[..., Awaitable[None]] | None,
+        loop: asyncio.AbstractEventLoop,
+        api_key: str | None = None,
+        api_secret: str | None = None,
+        request_timeout_secs: float = 10.0,
+    ) -> None:
+        self._clock = clock
+        self._log: Logger = Logger(name=type(self).__name__)
+
+        self._base_url: str = base_url
+        self._handler: Callable[[bytes], None] = handler
+        self._handler_reconnect: Callable[..., Awaitable[None]] | None = handler_reconnect
+        self._loop = loop
+        self._api_key = api_key
+        self._api_secret = api_secret
+        self._request_timeout_secs = request_timeout_secs
+
+        self._client: WebSocketClient | None = None
+        self._is_running = False
+        self._reconnecting = False
+        self._request_ids = itertools.count(_HEARTBEAT_REQUEST_ID + 1)
+        self._pending: dict[int, asyncio.Future[None]] = {}
+
+        # Subscription channels, e.g. `book.BTC-PERPETUAL.100ms` (replayed on reconnect)
+        self._subscriptions: list[str] = []
+
+        self._decoder_ws_message_general = msgspec_json.Decoder(DeribitWsMessageGeneral)
+
+    @property
+    def subscriptions(self) -> list[str]:
+        return self._subscriptions
+
+    @property
+    def auth_required(self) -> bool:
+        return self._api_key is not None and self._api_secret is not None
+
+    def has_subscription(self, item: str) -> bool:
+        return item in self._subscriptions
+
+    async def connect(self) -> None:
+        self._is_running = True
+        self._log.debug(f"Connecting to {self._base_url} websocket stream")
+
+        # The server closes idle connections, so a `public/test` request is sent periodically
+        config = WebSocketConfig(
+            url=self._base_url,
+            handler=self._msg_handler,
+            heartbeat=30,
+            heartbeat_msg=_HEARTBEAT_MSG,
+            headers=[],
+        )
+        client = await WebSocketClient.connect(
+            config=config,
+            post_reconnection=self.reconnect,
+        )
+        self._client = client
+        self._log.info(f"Connected to {self._base_url}", LogColor.BLUE)
+
+        if self.auth_required:
+            await self._authenticate()
+
+    def reconnect(self) -> None:
+        """
+        Reconnect the client to the server and resubscribe to all channels.
+        """
+        if not self._is_running or self._reconnecting:
+            return
+
+        self._log.warning(f"Trying to reconnect to {self._base_url}")
+        self._reconnecting = True
+        self._loop.create_task(self._reconnect_wrapper())
+
+    async def _reconnect_wrapper(self) -> None:
+        try:
+            if self.auth_required:
+                await self._authenticate()
+
+            self._log.warning(f"Resubscribing to {len(self._subscriptions)} channels")
+
+            await self._subscribe_all()
+
+            if self._handler_reconnect:
+                await self._handler_reconnect()
+
+            self._log.warning(f"Reconnected to {self._base_url}")
+        except Exception as e:
+            self._log.error(f"Reconnection failed: {e}")
+        finally:
+            self._reconnecting = False
+
+    async def disconnect(self) -> None:
+        self._is_running = False
+        self._reconnecting = False
+
+        if self._client is None:
+            self._log.warning("Cannot disconnect: not connected.")
+            return
+
+        try:
+            await self._client.disconnect()
+        except WebSocketClientError as e:
+            self._log.error(str(e))
+
+        self._client = None  # Dispose (will go out of scope)
+
+        for future in self._pending.values():
+            future.cancel()
+        self._pending.clear()
+
+        self._log.info(f"Disconnected from {self._base_url}", LogColor.BLUE)
+
+    def _msg_handler(self, raw: bytes) -> None:
+        """
+        Handle pushed websocket messages.
+
+        Parameters
+        ----------
+        raw : bytes
+            The received message in bytes.
+
+        """
+        msg = self._decoder_ws_message_general.decode(raw)
+        if msg.method == "subscription":
+            self._handler(raw)
+            return
+
+        if msg.id is None or msg.id == _HEARTBEAT_REQUEST_ID:
+            return
+
+        future = self._pending.pop(msg.id, None)
+        if future is None or future.done():
+            if msg.error is not None:
+                self._log.error(f"Received error: {raw.decode()}")
+            return
+
+        if msg.error is not None:
+            future.set_exception(
+                RuntimeError(f"Request failed: {msg.error.message} (code {msg.error.code})"),
+            )
+        else:
+            future.set_result(None)
+
+    async def _request(self, method: str, params: dict[str, Any]) -> None:
+        request_id = next(self._request_ids)
+        future: asyncio.Future[None] = self._loop.create_future()
+        self._pending[request_id] = future
+
+        await self._send({"jsonrpc": "2.0", "id": request_id, "method": method, "params": params})
+
+        try:
+            await asyncio.wait_for(future, self._request_timeout_secs)
+        except TimeoutError as e:
+            raise RuntimeError(f"Request {method} timed out") from e
+        finally:
+            self._pending.pop(request_id, None)
+
+    ################################################################################
+    # Authentication
+    ################################################################################
+
+    async def _authenticate(self) -> None:
+        assert self._api_key is not None and self._api_secret is not None  # Type checking
+
+        # The signature is of the timestamp (UNIX milliseconds), nonce and (empty) data
+        timestamp = self._clock.timestamp_ms()
+        nonce = UUID4().value[:8]
+        signature = hmac_signature(self._api_secret, f"{timestamp}\n{nonce}\n")
+
+        await self._request(
+            "public/auth",
+            {
+                "grant_type": "client_signature",
+                "client_id": self._api_key,
+                "timestamp": timestamp,
+                "signature": signature,
+                "nonce": nonce,
+                "data": "",
+            },
+        )
+        self._log.info("Authenticated", LogColor.GREEN)
+
+    ################################################################################
+    # Subscriptions
+    ################################################################################
+
+    async def _subscribe(self, channel: str) -> None:
+        self._log.debug(f"Subscribing to {channel}")
+        if channel in self._subscriptions:
+            self._log.warning(f"Cannot subscribe '{channel}': already subscribed")
+            return
+
+        self._subscriptions.append(channel)
+        await self._send_subscription("subscribe", [channel])
+
+    async def _unsubscribe(self, channel: str) -> None:
+        if channel not in self._subscriptions:
+            self._log.warning(f"Cannot unsubscribe '{channel}': not subscribed")
+            return
+
+        self._subscriptions.remove(channel)
+        await self._send_subscription("unsubscribe", [channel])
+
+    async def _subscribe_all(self) -> None:
+        if self._client is None:
+            self._log.error("Cannot subscribe all: not connected")
+            return
+
+        if self._subscriptions:
+            await self._send_subscription("subscribe", self._subscriptions)
+
+    async def _send_subscription(self, op: str, channels: list[str]) -> None:
+        # Private channels must be (un)subscribed with the private method
+        public = [channel for channel in channels if not channel.startswith("user.")]
+        private = [channel for channel in channels if channel.startswith("user.")]
+        if public:
+            await self._request(f"public/{op}", {"channels": public})
+        if private:
+            await self._request(f"private/{op}", {"channels": private})
+
+    async def _send(self, msg: dict[str, Any]) -> None:
+        if self._client is None:
+            self._log.error(f"Cannot send message {msg}: not connected")
+            return
+
+        encoded = msgspec_json.encode(msg)
+        self._log.debug(f"SENDING: {encoded!r}")
+
+        try:
+            await self._client.send_text(encoded)
+        except WebSocketClientError as e:
+            self._log.error(str(e))
+
+    ################################################################################
+    # Public
+    ################################################################################
+
+    async def subscribe_order_book(self, instrument_name: str) -> None:
+        # Incremental changes of the full book, aggregated over 100ms
+        await self._subscribe(f"book.{instrument_name}.100ms")
+
+    async def subscribe_quotes(self, instrument_name: str) -> None:
+        await self._subscribe(f"quote.{instrument_name}")
+
+    async def subscribe_trades(self, instrument_name: str) -> None:
+        await self._subscribe(f"trades.{instrument_name}.100ms")
+
+    async def unsubscribe_order_book(self, instrument_name: str) -> None:
+        await self._unsubscribe(f"book.{instrument_name}.100ms")
+
+    async def unsubscribe_quotes(self, instrument_name: str) -> None:
+        await self._unsubscribe(f"quote.{instrument_name}")
+
+    async def unsubscribe_trades(self, instrument_name: str) -> None:
+        await self._unsubscribe(f"trades.{instrument_name}.100ms")
+
+    ################################################################################
+    # Private
+    ################################################################################
+
+    async def subscribe_orders(self) -> None:
+        await self._subscribe("user.orders.any.any.raw")
+
+    async def subscribe_user_trades(self) -> None:
+        await self._subscribe("user.trades.any.any.raw")
+
+    async def subscribe_portfolio(self) -> None:
+        await self._subscribe("user.portfolio.any")
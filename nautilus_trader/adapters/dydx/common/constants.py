@@ -35,3 +35,9 @@ ACCOUNT_SEQUENCE_MISMATCH_ERROR_CODE = 32
 DYDX_RETRY_ERRORS_GRPC: Final[list[int]] = [
     32,  # Account sequence mismatch
 ]
+
+# Stateful (long-term and conditional) orders must expire within the stateful order
+# time window of the chain (95 days). Orders without an expire time are submitted as
+# good-til-time with the default window.
+DYDX_MAX_STATEFUL_ORDER_SECS: Final[int] = 95 * 24 * 60 * 60
+DYDX_DEFAULT_STATEFUL_ORDER_SECS: Final[int] = 90 * 24 * 60 * 60
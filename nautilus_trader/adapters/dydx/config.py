@@ -18,6 +18,7 @@ Define the dYdX configuration classes.
 
 from nautilus_trader.config import LiveDataClientConfig
 from nautilus_trader.config import LiveExecClientConfig
+from nautilus_trader.config import NonNegativeInt
 from nautilus_trader.config import PositiveFloat
 from nautilus_trader.config import PositiveInt
 
@@ -78,6 +79,13 @@ class DYDXExecClientConfig(LiveExecClientConfig, frozen=True):
         The maximum number of times a submit, cancel or modify order request will be retried.
     retry_delay : PositiveFloat, optional
         The delay (seconds) between retries. Short delays with frequent retries may result in account bans.
+    stateful_order_resubmit_blocks : PositiveInt, default 20
+        The number of blocks to wait for a long-term or conditional order to be reported
+        by the indexer, before assuming the transaction was evicted from the mempool and
+        resubmitting the order.
+    max_stateful_order_resubmits : NonNegativeInt, default 3
+        The maximum number of times a long-term or conditional order is resubmitted after
+        mempool eviction, before the order is rejected.
 
     """
 
@@ -89,3 +97,5 @@ class DYDXExecClientConfig(LiveExecClientConfig, frozen=True):
     is_testnet: bool = False
     max_retries: PositiveInt | None = None
     retry_delay: PositiveFloat | None = None
+    stateful_order_resubmit_blocks: PositiveInt = 20
+    max_stateful_order_resubmits: NonNegativeInt = 3
@@ -30,6 +30,8 @@ from v4_proto.dydxprotocol.clob.order_pb2 import OrderId as DYDXOrderId
 from v4_proto.dydxprotocol.clob.tx_pb2 import OrderBatch
 
 from nautilus_trader.adapters.dydx.common.common import DYDXOrderTags
+from nautilus_trader.adapters.dydx.common.constants import DYDX_DEFAULT_STATEFUL_ORDER_SECS
+from nautilus_trader.adapters.dydx.common.constants import DYDX_MAX_STATEFUL_ORDER_SECS
 from nautilus_trader.adapters.dydx.common.constants import DYDX_VENUE
 from nautilus_trader.adapters.dydx.common.credentials import get_mnemonic
 from nautilus_trader.adapters.dydx.common.credentials import get_wallet_address
@@ -44,6 +46,7 @@ from nautilus_trader.adapters.dydx.grpc.errors import DYDXGRPCError
 from nautilus_trader.adapters.dydx.grpc.order_builder import MAX_CLIENT_ID
 from nautilus_trader.adapters.dydx.grpc.order_builder import DYDXGRPCOrderType
 from nautilus_trader.adapters.dydx.grpc.order_builder import OrderBuilder
+from nautilus_trader.adapters.dydx.grpc.order_builder import OrderExecution
 from nautilus_trader.adapters.dydx.grpc.order_builder import OrderFlags
 from nautilus_trader.adapters.dydx.http.account import DYDXAccountHttpAPI
 from nautilus_trader.adapters.dydx.http.client import DYDXHttpClient
@@ -82,6 +85,7 @@ from nautilus_trader.live.retry import RetryManagerPool
 from nautilus_trader.model.enums import AccountType
 from nautilus_trader.model.enums import OmsType
 from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import OrderStatus
 from nautilus_trader.model.enums import OrderType
 from nautilus_trader.model.enums import PositionSide
 from nautilus_trader.model.enums import TimeInForce
@@ -103,6 +107,15 @@ if TYPE_CHECKING:
     from nautilus_trader.model.objects import Currency
 
 
+# Order types which are submitted as conditional (stateful) orders
+_CONDITIONAL_ORDER_TYPES = (
+    OrderType.STOP_MARKET,
+    OrderType.STOP_LIMIT,
+    OrderType.MARKET_IF_TOUCHED,
+    OrderType.LIMIT_IF_TOUCHED,
+)
+
+
 class ClientOrderIdHelper:
     """
     Generate integer client order IDs.
@@ -229,6 +242,8 @@ class DYDXExecutionClient(LiveExecutionClient):
             is_testnet=config.is_testnet,
         )
         self._subaccount = config.subaccount
+        self._stateful_order_resubmit_blocks = config.stateful_order_resubmit_blocks
+        self._max_stateful_order_resubmits = config.max_stateful_order_resubmits
 
         self._enum_parser = DYDXEnumParser()
         self._client_order_id_generator = ClientOrderIdHelper(cache=cache)
@@ -281,6 +296,10 @@ class DYDXExecutionClient(LiveExecutionClient):
         self._block_height: int = 0
         self._oracle_prices: dict[InstrumentId, Decimal] = {}
 
+        # Stateful orders broadcast but not yet reported by the indexer (order message and block height)
+        self._pending_stateful_orders: dict[ClientOrderId, tuple[DYDXOrder, int]] = {}
+        self._stateful_order_resubmits: dict[ClientOrderId, int] = {}
+
         self._retry_manager_pool = RetryManagerPool[None](
             pool_size=100,
             max_retries=config.max_retries or 0,
@@ -782,6 +801,7 @@ class DYDXExecutionClient(LiveExecutionClient):
                 raw,
             )
             self._block_height = int(msg.contents.blockHeight)
+            self._check_pending_stateful_orders()
 
         except Exception as e:
             self._log.error(
@@ -923,6 +943,9 @@ class DYDXExecutionClient(LiveExecutionClient):
         strategy_id = None
 
         if report.client_order_id:
+            # The stateful order transaction was included in a block
+            self._pending_stateful_orders.pop(report.client_order_id, None)
+            self._stateful_order_resubmits.pop(report.client_order_id, None)
             strategy_id = self._cache.strategy_id_for_order(report.client_order_id)
 
         if strategy_id is None:
@@ -1049,6 +1072,50 @@ class DYDXExecutionClient(LiveExecutionClient):
 
         return result
 
+    def _get_order_flags(self, order: Order, dydx_order_tags: DYDXOrderTags) -> OrderFlags:
+        """
+        Determine the order flags for a short term, long term or conditional order.
+
+        Conditional orders are always stateful orders on dYdX, regardless of the order tags.
+
+        """
+        if order.order_type in _CONDITIONAL_ORDER_TYPES:
+            return OrderFlags.CONDITIONAL
+
+        if dydx_order_tags.is_short_term_order:
+            return OrderFlags.SHORT_TERM
+
+        return OrderFlags.LONG_TERM
+
+    def _get_good_til_date_secs(self, order: Order) -> int:
+        """
+        Determine the good-til-time (seconds) for a long term or conditional order.
+        """
+        if order.expire_time_ns:
+            return int(nanos_to_secs(order.expire_time_ns))
+
+        # Stateful orders always require a good-til-time on dYdX
+        return int(nanos_to_secs(self._clock.timestamp_ns())) + DYDX_DEFAULT_STATEFUL_ORDER_SECS
+
+    def _get_order_execution(self, order: Order) -> OrderExecution:
+        """
+        Determine the execution of a conditional order once triggered.
+        """
+        if order.time_in_force == TimeInForce.FOK:
+            return OrderExecution.FOK
+
+        if order.time_in_force == TimeInForce.IOC:
+            return OrderExecution.IOC
+
+        if order.order_type in (OrderType.STOP_MARKET, OrderType.MARKET_IF_TOUCHED):
+            # Conditional market orders only support IOC or FOK execution
+            return OrderExecution.IOC
+
+        if order.is_post_only:
+            return OrderExecution.POST_ONLY
+
+        return OrderExecution.DEFAULT
+
     async def _submit_order_list(self, command: SubmitOrderList) -> None:
         """
         Submit a batch of orders at once.
@@ -1104,11 +1171,11 @@ class DYDXExecutionClient(LiveExecutionClient):
         )
 
         dydx_order_tags = self._parse_order_tags(order=order)
-        order_flags = OrderFlags.SHORT_TERM
+        order_flags = self._get_order_flags(order=order, dydx_order_tags=dydx_order_tags)
         good_til_date_secs: int | None = None
         good_til_block: int | None = None
 
-        if dydx_order_tags.is_short_term_order is False and order.order_type == OrderType.MARKET:
+        if order_flags == OrderFlags.LONG_TERM and order.order_type == OrderType.MARKET:
             rejection_reason = "Cannot submit order: long term market order not supported by dYdX"
             self.generate_order_rejected(
                 strategy_id=order.strategy_id,
@@ -1119,22 +1186,28 @@ class DYDXExecutionClient(LiveExecutionClient):
             )
             return
 
-        if dydx_order_tags.is_short_term_order:
+        if order_flags == OrderFlags.SHORT_TERM:
             good_til_block = self._block_height + dydx_order_tags.num_blocks_open
-
-        elif order.order_type in [OrderType.STOP_LIMIT, OrderType.STOP_MARKET]:
-            good_til_block = None
-            order_flags = OrderFlags.CONDITIONAL
-            good_til_date_secs = (
-                int(nanos_to_secs(order.expire_time_ns)) if order.expire_time_ns else None
-            )
-
         else:
-            order_flags = OrderFlags.LONG_TERM
-            good_til_date_secs = (
-                int(nanos_to_secs(order.expire_time_ns)) if order.expire_time_ns else None
+            good_til_date_secs = self._get_good_til_date_secs(order=order)
+            max_good_til_date_secs = (
+                int(nanos_to_secs(self._clock.timestamp_ns())) + DYDX_MAX_STATEFUL_ORDER_SECS
             )
 
+            if good_til_date_secs > max_good_til_date_secs:
+                rejection_reason = (
+                    "Cannot submit order: expire time exceeds the dYdX stateful order window "
+                    f"of {DYDX_MAX_STATEFUL_ORDER_SECS // (24 * 60 * 60)} days"
+                )
+                self.generate_order_rejected(
+                    strategy_id=order.strategy_id,
+                    instrument_id=order.instrument_id,
+                    client_order_id=order.client_order_id,
+                    reason=rejection_reason,
+                    ts_event=self._clock.timestamp_ns(),
+                )
+                return
+
         order_id = order_builder.create_order_id(
             address=self._wallet_address,
             subaccount_number=self._subaccount,
@@ -1146,6 +1219,8 @@ class DYDXExecutionClient(LiveExecutionClient):
             OrderType.MARKET: DYDXGRPCOrderType.MARKET,
             OrderType.STOP_MARKET: DYDXGRPCOrderType.STOP_MARKET,
             OrderType.STOP_LIMIT: DYDXGRPCOrderType.STOP_LIMIT,
+            OrderType.MARKET_IF_TOUCHED: DYDXGRPCOrderType.TAKE_PROFIT_MARKET,
+            OrderType.LIMIT_IF_TOUCHED: DYDXGRPCOrderType.TAKE_PROFIT_LIMIT,
         }
         order_side_map = {
             OrderSide.NO_ORDER_SIDE: DYDXOrder.Side.SIDE_UNSPECIFIED,
@@ -1170,10 +1245,10 @@ class DYDXExecutionClient(LiveExecutionClient):
                 if dydx_order_tags.market_order_price is not None
                 else 0
             )
-        elif order.order_type == OrderType.STOP_LIMIT:
+        elif order.order_type in (OrderType.STOP_LIMIT, OrderType.LIMIT_IF_TOUCHED):
             price = order.price.as_double()
             trigger_price = order.trigger_price.as_double()
-        elif order.order_type == OrderType.STOP_MARKET:
+        elif order.order_type in (OrderType.STOP_MARKET, OrderType.MARKET_IF_TOUCHED):
             price = (
                 dydx_order_tags.market_order_price.as_double()
                 if dydx_order_tags.market_order_price is not None
@@ -1204,6 +1279,7 @@ class DYDXExecutionClient(LiveExecutionClient):
             post_only=order.is_post_only,
             good_til_block=good_til_block,
             good_til_block_time=good_til_date_secs,
+            execution=self._get_order_execution(order=order),
             trigger_price=trigger_price,
         )
 
@@ -1239,6 +1315,72 @@ class DYDXExecutionClient(LiveExecutionClient):
                     reason=retry_manager.message,
                     ts_event=self._clock.timestamp_ns(),
                 )
+                return
+
+        if order_msg.order_id.order_flags != OrderFlags.SHORT_TERM:
+            # Passing `CheckTx` does not guarantee the transaction is included in a block,
+            # stateful orders are tracked until reported by the indexer.
+            self._pending_stateful_orders[order.client_order_id] = (order_msg, self._block_height)
+
+    def _check_pending_stateful_orders(self) -> None:
+        """
+        Resubmit stateful orders which were most likely evicted from the mempool.
+
+        Stateful orders which are not reported by the indexer within the configured
+        number of blocks are resubmitted, up to the configured maximum number of
+        resubmits after which the order is rejected.
+
+        """
+        for client_order_id, (order_msg, block_height) in list(
+            self._pending_stateful_orders.items(),
+        ):
+            order = self._cache.order(client_order_id)
+
+            if order is None or order.status != OrderStatus.SUBMITTED:
+                # Order state already progressed (e.g. through reconciliation)
+                self._pending_stateful_orders.pop(client_order_id, None)
+                self._stateful_order_resubmits.pop(client_order_id, None)
+                continue
+
+            if self._block_height - block_height < self._stateful_order_resubmit_blocks:
+                continue
+
+            self._pending_stateful_orders.pop(client_order_id)
+            resubmits = self._stateful_order_resubmits.get(client_order_id, 0)
+
+            if resubmits >= self._max_stateful_order_resubmits:
+                self._stateful_order_resubmits.pop(client_order_id, None)
+                self.generate_order_rejected(
+                    strategy_id=order.strategy_id,
+                    instrument_id=order.instrument_id,
+                    client_order_id=client_order_id,
+                    reason=(
+                        f"Order not included in a block after {resubmits} resubmits "
+                        "(evicted from the mempool)"
+                    ),
+                    ts_event=self._clock.timestamp_ns(),
+                )
+                continue
+
+            self._stateful_order_resubmits[client_order_id] = resubmits + 1
+            self._log.warning(
+                f"{client_order_id!r} not reported after {self._stateful_order_resubmit_blocks} blocks, "
+                f"resubmitting (attempt {resubmits + 1}/{self._max_stateful_order_resubmits})",
+            )
+            self.create_task(self._resubmit_stateful_order(order=order, order_msg=order_msg))
+
+    async def _resubmit_stateful_order(self, order: Order, order_msg: DYDXOrder) -> None:
+        if self._wallet is None:
+            self._log.error(f"Cannot resubmit {order.client_order_id!r}: no wallet available")
+            return
+
+        try:
+            # The sequence number of an evicted transaction is not consumed
+            await self._grpc_account.sync_sequence(self._wallet)
+        except AioRpcError as e:
+            self._log.warning(f"Failed to synchronize the wallet sequence number: {e}")
+
+        await self._place_order(order_msg=order_msg, order=order)
 
     async def _submit_order(self, command: SubmitOrder) -> None:
         await self._submit_order_single(order=command.order)
@@ -1274,7 +1416,10 @@ class DYDXExecutionClient(LiveExecutionClient):
         for order in valid_orders:
             dydx_order_tags = self._parse_order_tags(order=order)
 
-            if dydx_order_tags.is_short_term_order:
+            if (
+                self._get_order_flags(order=order, dydx_order_tags=dydx_order_tags)
+                == OrderFlags.SHORT_TERM
+            ):
                 short_term_orders.append(order)
             else:
                 long_term_orders.append(order)
@@ -1300,7 +1445,10 @@ class DYDXExecutionClient(LiveExecutionClient):
         for order in open_orders_strategy:
             dydx_order_tags = self._parse_order_tags(order=order)
 
-            if dydx_order_tags.is_short_term_order:
+            if (
+                self._get_order_flags(order=order, dydx_order_tags=dydx_order_tags)
+                == OrderFlags.SHORT_TERM
+            ):
                 short_term_orders.append(order)
             else:
                 long_term_orders.append(order)
@@ -1425,20 +1573,11 @@ class DYDXExecutionClient(LiveExecutionClient):
             return
 
         dydx_order_tags = self._parse_order_tags(order=order)
-        order_flags = OrderFlags.SHORT_TERM
+        order_flags = self._get_order_flags(order=order, dydx_order_tags=dydx_order_tags)
         good_til_date_secs: int | None = None
 
-        if dydx_order_tags.is_short_term_order is False:
-            order_flags = OrderFlags.LONG_TERM
-            good_til_date_secs = (
-                int(nanos_to_secs(order.expire_time_ns)) if order.expire_time_ns else None
-            )
-
-        if order.order_type in [OrderType.STOP_LIMIT, OrderType.STOP_MARKET]:
-            order_flags = OrderFlags.CONDITIONAL
-            good_til_date_secs = (
-                int(nanos_to_secs(order.expire_time_ns)) if order.expire_time_ns else None
-            )
+        if order_flags != OrderFlags.SHORT_TERM:
+            good_til_date_secs = self._get_good_til_date_secs(order=order)
 
         order_id = order_builder.create_order_id(
             address=self._wallet_address,
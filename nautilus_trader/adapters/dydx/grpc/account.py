@@ -64,6 +64,7 @@ from v4_proto.dydxprotocol.subaccounts.subaccount_pb2 import SubaccountId
 
 from nautilus_trader.adapters.dydx.common.constants import ACCOUNT_SEQUENCE_MISMATCH_ERROR_CODE
 from nautilus_trader.adapters.dydx.grpc.errors import DYDXGRPCError
+from nautilus_trader.adapters.dydx.grpc.order_builder import OrderFlags
 
 
 DEFAULT_FEE = Fee(
@@ -115,6 +116,22 @@ def get_signature(
     return private_key.sign(signdoc.SerializeToString(), sigencode=sigencode_string_canonize)
 
 
+def is_short_term_message(message: Message) -> bool:
+    """
+    Return whether the message only contains short-term order placements or cancels.
+
+    Short-term order messages do not consume the account sequence number, replay
+    protection is provided by the good til block instead.
+    """
+    if isinstance(message, MsgPlaceOrder):
+        return message.order.order_id.order_flags == OrderFlags.SHORT_TERM
+
+    if isinstance(message, MsgCancelOrder):
+        return message.order_id.order_flags == OrderFlags.SHORT_TERM
+
+    return isinstance(message, MsgBatchCancel)
+
+
 def bytes_from_mnemonic(mnemonic: str) -> bytes:
     """
     Create a Bib44 private signing key.
@@ -356,6 +373,24 @@ class DYDXAccountGRPCAPI:
         stub = fee_tier_query_grpc.QueryStub(self._channel)
         return await stub.UserFeeTier(fee_tier_query.QueryUserFeeTierRequest(user=address))
 
+    async def sync_sequence(self, wallet: Wallet) -> None:
+        """
+        Synchronize the wallet sequence number with the account sequence on chain.
+
+        A transaction which passed `CheckTx` can still be evicted from the mempool
+        before being included in a block, in which case its sequence number is not
+        consumed and the local sequence number is ahead of the chain.
+
+        Parameters
+        ----------
+        wallet : Wallet
+            The wallet to synchronize.
+
+        """
+        async with self._lock:
+            account = await self.get_account(wallet.address)
+            wallet.sequence = account.sequence
+
     async def place_order(self, wallet: Wallet, order: Order) -> BroadcastTxResponse:
         """
         Places an order.
@@ -498,7 +533,10 @@ class DYDXAccountGRPCAPI:
             response = await self.broadcast(self._transaction_builder.build(wallet, message), mode)
 
             if response.tx_response.code == 0:
-                wallet.sequence += 1
+                # Only stateful (long-term and conditional) order messages increment
+                # the account sequence number
+                if not is_short_term_message(message):
+                    wallet.sequence += 1
 
             # The sequence number is not correct. Retrieve it from the gRPC channel.
             # The retry manager can retry the transaction.
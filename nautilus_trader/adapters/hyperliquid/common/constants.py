@@ -0,0 +1,54 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from typing import Final
+
+from nautilus_trader.model.identifiers import Venue
+
+
+HYPERLIQUID: Final[str] = "HYPERLIQUID"
+HYPERLIQUID_VENUE: Final[Venue] = Venue(HYPERLIQUID)
+
+# Perpetual symbols are the coin name with this suffix, e.g. `BTC-PERP`
+HYPERLIQUID_PERP_SUFFIX: Final[str] = "-PERP"
+
+# Perpetual prices can have at most this many decimals minus the size decimals
+HYPERLIQUID_PERP_MAX_DECIMALS: Final[int] = 6
+
+# Prices can have at most this many significant figures (integer prices are always valid)
+HYPERLIQUID_MAX_SIGNIFICANT_FIGURES: Final[int] = 5
+
+# Orders must have a minimum notional value (USDC)
+HYPERLIQUID_MIN_NOTIONAL: Final[str] = "10"
+
+# Fee schedules are volume tiered per account, these are the base tier perpetual fees
+HYPERLIQUID_DEFAULT_MAKER_FEE: Final[str] = "0.00015"
+HYPERLIQUID_DEFAULT_TAKER_FEE: Final[str] = "0.00045"
+
+# Funding is paid every hour
+HYPERLIQUID_FUNDING_INTERVAL_SECS: Final[int] = 3600
+
+# Set of Hyperliquid errors for which Nautilus will attempt retries,
+# potentially temporary conditions where a retry might make sense.
+HYPERLIQUID_RETRY_ERRORS: Final[set[int | str]] = {
+    429,  # Too many requests
+    502,  # Bad gateway
+    503,  # Service unavailable
+    504,  # Gateway timeout
+}
+
+# Rate limiter keys for the HTTP client quotas
+HYPERLIQUID_INFO_RATE_LIMIT_KEY: Final[str] = "hyperliquid:info"
+HYPERLIQUID_EXCHANGE_RATE_LIMIT_KEY: Final[str] = "hyperliquid:exchange"
@@ -0,0 +1,143 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from enum import Enum
+from enum import unique
+
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import TimeInForce
+from nautilus_trader.model.enums import order_type_to_str
+from nautilus_trader.model.enums import time_in_force_to_str
+
+
+@unique
+class HyperliquidOrderSide(Enum):
+    BUY = "B"  # Bid
+    SELL = "A"  # Ask
+
+
+@unique
+class HyperliquidTimeInForce(Enum):
+    GTC = "Gtc"
+    IOC = "Ioc"
+    ALO = "Alo"  # Add liquidity only (post-only)
+
+
+@unique
+class HyperliquidTpsl(Enum):
+    TAKE_PROFIT = "tp"
+    STOP_LOSS = "sl"
+
+
+def check_dict_keys(key, data):
+    try:
+        return data[key]
+    except KeyError as e:
+        raise RuntimeError(
+            f"Unrecognized Hyperliquid {key} not found in {data}",
+        ) from e
+
+
+class HyperliquidEnumParser:
+    def __init__(self) -> None:
+        self.hyperliquid_to_nautilus_order_side = {
+            HyperliquidOrderSide.BUY: OrderSide.BUY,
+            HyperliquidOrderSide.SELL: OrderSide.SELL,
+        }
+        self.nautilus_to_hyperliquid_order_side = {
+            b: a for a, b in self.hyperliquid_to_nautilus_order_side.items()
+        }
+        self.hyperliquid_to_nautilus_time_in_force = {
+            HyperliquidTimeInForce.GTC: TimeInForce.GTC,
+            HyperliquidTimeInForce.IOC: TimeInForce.IOC,
+            HyperliquidTimeInForce.ALO: TimeInForce.GTC,
+        }
+
+    def parse_hyperliquid_order_side(self, order_side: HyperliquidOrderSide) -> OrderSide:
+        return check_dict_keys(order_side, self.hyperliquid_to_nautilus_order_side)
+
+    def parse_nautilus_order_side(self, order_side: OrderSide) -> HyperliquidOrderSide:
+        return check_dict_keys(order_side, self.nautilus_to_hyperliquid_order_side)
+
+    def parse_hyperliquid_time_in_force(
+        self,
+        time_in_force: HyperliquidTimeInForce,
+    ) -> TimeInForce:
+        return check_dict_keys(time_in_force, self.hyperliquid_to_nautilus_time_in_force)
+
+    def parse_nautilus_time_in_force(
+        self,
+        time_in_force: TimeInForce,
+        is_post_only: bool,
+    ) -> HyperliquidTimeInForce:
+        if is_post_only:
+            return HyperliquidTimeInForce.ALO
+        match time_in_force:
+            case TimeInForce.GTC:
+                return HyperliquidTimeInForce.GTC
+            case TimeInForce.IOC:
+                return HyperliquidTimeInForce.IOC
+            case _:
+                raise RuntimeError(
+                    f"unrecognized Hyperliquid time in force, was {time_in_force_to_str(time_in_force)}",  # pragma: no cover
+                )
+
+    def parse_nautilus_tpsl(self, order_type: OrderType) -> HyperliquidTpsl:
+        # Stop orders trigger when the price moves against the order side (buy above,
+        # sell below), and if-touched orders when the price moves in favor.
+        match order_type:
+            case OrderType.STOP_MARKET | OrderType.STOP_LIMIT:
+                return HyperliquidTpsl.STOP_LOSS
+            case OrderType.MARKET_IF_TOUCHED | OrderType.LIMIT_IF_TOUCHED:
+                return HyperliquidTpsl.TAKE_PROFIT
+            case _:
+                raise RuntimeError(
+                    f"unrecognized Hyperliquid trigger order type, was {order_type_to_str(order_type)}",  # pragma: no cover
+                )
+
+    def parse_hyperliquid_order_type(
+        self,
+        order_type: str | None,
+        is_trigger: bool,
+    ) -> OrderType:
+        # Order types are reported as display strings, e.g. `Limit`, `Stop Market`, `Take Profit Limit`
+        if not is_trigger or order_type is None:
+            return OrderType.LIMIT
+        is_market = order_type.endswith("Market")
+        if order_type.startswith("Stop"):
+            return OrderType.STOP_MARKET if is_market else OrderType.STOP_LIMIT
+        return OrderType.MARKET_IF_TOUCHED if is_market else OrderType.LIMIT_IF_TOUCHED
+
+    def parse_hyperliquid_order_status(self, status: str) -> OrderStatus:
+        # Cancel and reject statuses are reported with specific reasons,
+        # e.g. `marginCanceled`, `reduceOnlyCanceled`, `badAloPxRejected`
+        match status:
+            case "open":
+                return OrderStatus.ACCEPTED
+            case "filled":
+                return OrderStatus.FILLED
+            case "triggered":
+                return OrderStatus.TRIGGERED
+            case "rejected":
+                return OrderStatus.REJECTED
+            case "canceled" | "scheduledCancel":
+                return OrderStatus.CANCELED
+        if status.endswith("Rejected"):
+            return OrderStatus.REJECTED
+        if status.endswith("Canceled"):
+            return OrderStatus.CANCELED
+        raise RuntimeError(f"unrecognized Hyperliquid order status, was {status}")
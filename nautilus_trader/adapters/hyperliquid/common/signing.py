@@ -0,0 +1,261 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+import hashlib
+from decimal import Decimal
+from typing import Any
+from typing import Final
+
+import msgpack
+from eth_account import Account
+from eth_account.messages import encode_typed_data
+from eth_utils import keccak
+from eth_utils import to_hex
+
+from nautilus_trader.adapters.hyperliquid.common.constants import HYPERLIQUID_MAX_SIGNIFICANT_FIGURES
+from nautilus_trader.model.identifiers import ClientOrderId
+
+
+# L1 actions are signed as an EIP-712 `Agent` message with a fixed domain
+_L1_ACTION_DOMAIN: Final[dict[str, Any]] = {
+    "chainId": 1337,
+    "name": "Exchange",
+    "verifyingContract": "0x0000000000000000000000000000000000000000",
+    "version": "1",
+}
+_L1_ACTION_TYPES: Final[dict[str, list[dict[str, str]]]] = {
+    "Agent": [
+        {"name": "source", "type": "string"},
+        {"name": "connectionId", "type": "bytes32"},
+    ],
+    "EIP712Domain": [
+        {"name": "name", "type": "string"},
+        {"name": "version", "type": "string"},
+        {"name": "chainId", "type": "uint256"},
+        {"name": "verifyingContract", "type": "address"},
+    ],
+}
+
+_WIRE_DECIMAL_PLACES: Final[Decimal] = Decimal("1e-8")
+
+
+def float_to_wire(value: Decimal | float | str) -> str:
+    """
+    Return the given price or size in the Hyperliquid wire format.
+
+    Values are normalized without trailing zeros, as the msgpack encoded action
+    is hashed for signing and must match the representation used by the venue.
+
+    Parameters
+    ----------
+    value : Decimal, float or str
+        The value to format.
+
+    Returns
+    -------
+    str
+
+    Raises
+    ------
+    ValueError
+        If `value` has more than 8 decimal places.
+
+    """
+    decimal = Decimal(str(value))
+    rounded = decimal.quantize(_WIRE_DECIMAL_PLACES)
+    if rounded != decimal:
+        raise ValueError(f"Invalid wire value {value}: more than 8 decimal places")
+    wire = f"{rounded.normalize():f}"
+    return "0" if wire == "-0" else wire
+
+
+def round_price(price: Decimal, price_precision: int) -> Decimal:
+    """
+    Return the given price rounded to a valid Hyperliquid price.
+
+    Prices can have at most five significant figures (integer prices are always
+    valid), and at most `price_precision` decimal places.
+
+    Parameters
+    ----------
+    price : Decimal
+        The price to round.
+    price_precision : int
+        The maximum decimal places for the instrument.
+
+    Returns
+    -------
+    Decimal
+
+    """
+    decimals = HYPERLIQUID_MAX_SIGNIFICANT_FIGURES - price.adjusted() - 1
+    decimals = max(min(decimals, price_precision), 0)
+    return price.quantize(Decimal(10) ** -decimals)
+
+
+def client_order_id_to_cloid(client_order_id: ClientOrderId) -> str:
+    """
+    Return the Hyperliquid client order ID (cloid) for the given client order ID.
+
+    Hyperliquid cloids are 16 byte hex strings, so client order IDs are mapped
+    deterministically with an MD5 digest.
+
+    Parameters
+    ----------
+    client_order_id : ClientOrderId
+        The client order ID to map.
+
+    Returns
+    -------
+    str
+
+    """
+    return "0x" + hashlib.md5(client_order_id.value.encode()).hexdigest()  # noqa: S324
+
+
+def address_to_bytes(address: str) -> bytes:
+    return bytes.fromhex(address.removeprefix("0x"))
+
+
+def action_hash(action: dict[str, Any], vault_address: str | None, nonce: int) -> bytes:
+    """
+    Return the hash of the given L1 action which is signed as the `connectionId`.
+
+    Parameters
+    ----------
+    action : dict[str, Any]
+        The action (key order is significant for the msgpack encoding).
+    vault_address : str, optional
+        The vault or subaccount address the action is for.
+    nonce : int
+        The nonce for the action (UNIX milliseconds).
+
+    Returns
+    -------
+    bytes
+
+    """
+    data = msgpack.packb(action)
+    data += nonce.to_bytes(8, "big")
+    if vault_address is None:
+        data += b"\x00"
+    else:
+        data += b"\x01"
+        data += address_to_bytes(vault_address)
+    return keccak(data)
+
+
+def l1_action_typed_data(
+    action: dict[str, Any],
+    vault_address: str | None,
+    nonce: int,
+    is_mainnet: bool,
+) -> dict[str, Any]:
+    """
+    Return the EIP-712 typed data for the given L1 action.
+
+    The action is signed through a phantom agent, where the `source` identifies
+    the network (mainnet or testnet) for replay protection.
+
+    Parameters
+    ----------
+    action : dict[str, Any]
+        The action to sign.
+    vault_address : str, optional
+        The vault or subaccount address the action is for.
+    nonce : int
+        The nonce for the action (UNIX milliseconds).
+    is_mainnet : bool
+        If the action is for mainnet.
+
+    Returns
+    -------
+    dict[str, Any]
+
+    """
+    phantom_agent = {
+        "source": "a" if is_mainnet else "b",
+        "connectionId": action_hash(action, vault_address, nonce),
+    }
+    return {
+        "domain": _L1_ACTION_DOMAIN,
+        "types": _L1_ACTION_TYPES,
+        "primaryType": "Agent",
+        "message": phantom_agent,
+    }
+
+
+class HyperliquidSigner:
+    """
+    Provides EIP-712 signing of Hyperliquid L1 actions.
+
+    The private key can be for the account itself, or for an API wallet (agent)
+    approved by the account, in which case the account address is still used for
+    all info requests and private subscriptions.
+
+    Parameters
+    ----------
+    private_key : str
+        The hex encoded private key for signing.
+    is_mainnet : bool, default True
+        If signing actions for mainnet.
+
+    """
+
+    def __init__(self, private_key: str, is_mainnet: bool = True) -> None:
+        self._wallet = Account.from_key(private_key)
+        self._is_mainnet = is_mainnet
+
+    @property
+    def address(self) -> str:
+        """
+        Return the address of the signing wallet.
+
+        Returns
+        -------
+        str
+
+        """
+        return self._wallet.address
+
+    def sign_l1_action(
+        self,
+        action: dict[str, Any],
+        nonce: int,
+        vault_address: str | None = None,
+    ) -> dict[str, Any]:
+        """
+        Return the signature for the given L1 action.
+
+        Parameters
+        ----------
+        action : dict[str, Any]
+            The action to sign.
+        nonce : int
+            The nonce for the action (UNIX milliseconds).
+        vault_address : str, optional
+            The vault or subaccount address the action is for.
+
+        Returns
+        -------
+        dict[str, Any]
+            The signature with `r`, `s` and `v` components.
+
+        """
+        data = l1_action_typed_data(action, vault_address, nonce, self._is_mainnet)
+        signed = self._wallet.sign_message(encode_typed_data(full_message=data))
+        return {"r": to_hex(signed.r), "s": to_hex(signed.s), "v": signed.v}
@@ -0,0 +1,81 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from nautilus_trader.adapters.hyperliquid.common.constants import HYPERLIQUID_PERP_SUFFIX
+from nautilus_trader.adapters.hyperliquid.common.constants import HYPERLIQUID_VENUE
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import Symbol
+
+
+class HyperliquidSymbol(str):
+    """
+    Represents a Hyperliquid perpetual symbol, the coin name with a `-PERP` suffix.
+
+    Coin names are case sensitive (e.g. `kPEPE`), so the symbol case is preserved.
+
+    """
+
+    def __new__(cls, symbol: str) -> HyperliquidSymbol:  # noqa: PYI034
+        PyCondition.valid_string(symbol, "symbol")
+        if not symbol.endswith(HYPERLIQUID_PERP_SUFFIX):
+            raise ValueError(
+                f"Invalid symbol '{symbol}': "
+                f"does not contain the perpetual suffix '{HYPERLIQUID_PERP_SUFFIX}'",
+            )
+
+        return super().__new__(cls, symbol)
+
+    @property
+    def coin(self) -> str:
+        """
+        Return the Hyperliquid coin name (without the suffix).
+
+        Returns
+        -------
+        str
+
+        """
+        return str(self).removesuffix(HYPERLIQUID_PERP_SUFFIX)
+
+    def to_instrument_id(self) -> InstrumentId:
+        """
+        Parse the Hyperliquid symbol into a Nautilus instrument ID.
+
+        Returns
+        -------
+        InstrumentId
+
+        """
+        return InstrumentId(Symbol(str(self)), HYPERLIQUID_VENUE)
+
+    @staticmethod
+    def from_coin(coin: str) -> HyperliquidSymbol:
+        """
+        Create a Hyperliquid symbol from the given coin name.
+
+        Parameters
+        ----------
+        coin : str
+            The Hyperliquid coin name.
+
+        Returns
+        -------
+        HyperliquidSymbol
+
+        """
+        return HyperliquidSymbol(f"{coin}{HYPERLIQUID_PERP_SUFFIX}")
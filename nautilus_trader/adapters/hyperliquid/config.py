@@ -0,0 +1,98 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from nautilus_trader.config import LiveDataClientConfig
+from nautilus_trader.config import LiveExecClientConfig
+from nautilus_trader.config import PositiveFloat
+from nautilus_trader.config import PositiveInt
+
+
+class HyperliquidDataClientConfig(LiveDataClientConfig, frozen=True):
+    """
+    Configuration for ``HyperliquidDataClient`` instances.
+
+    Market data is public, so no credentials are required.
+
+    Parameters
+    ----------
+    base_url_http : str, optional
+        The base URL for the HTTP client.
+    base_url_ws : str, optional
+        The base URL for the WebSocket client.
+    testnet : bool, default False
+        If the client is connecting to the Hyperliquid testnet.
+    update_instruments_interval_mins: PositiveInt or None, default 60
+        The interval (minutes) between reloading instruments from the venue.
+
+    """
+
+    base_url_http: str | None = None
+    base_url_ws: str | None = None
+    testnet: bool = False
+    update_instruments_interval_mins: PositiveInt | None = 60
+
+
+class HyperliquidExecClientConfig(LiveExecClientConfig, frozen=True):
+    """
+    Configuration for ``HyperliquidExecutionClient`` instances.
+
+    Parameters
+    ----------
+    private_key : str, optional
+        The private key for signing exchange actions (the account or an approved API wallet).
+        If ``None`` then will source the `HYPERLIQUID_PK` or `HYPERLIQUID_TESTNET_PK`
+        environment variables.
+    wallet_address : str, optional
+        The address of the trading account, required when signing with an API wallet.
+        If ``None`` then will source the `HYPERLIQUID_WALLET_ADDRESS` or
+        `HYPERLIQUID_TESTNET_WALLET_ADDRESS` environment variables, and otherwise
+        default to the address of the `private_key`.
+    vault_address : str, optional
+        The vault or subaccount address to trade on behalf of.
+    base_url_http : str, optional
+        The base URL for the HTTP client.
+    base_url_ws : str, optional
+        The base URL for the WebSocket client.
+    testnet : bool, default False
+        If the client is connecting to the Hyperliquid testnet.
+    use_ws_trade_api : bool, default True
+        If exchange actions are sent as WebSocket `post` requests, otherwise via REST.
+    market_order_slippage : PositiveFloat, default 0.05
+        The maximum slippage (fraction of the reference price) for market orders,
+        which are sent as aggressive IOC limit orders.
+    max_retries : PositiveInt, optional
+        The maximum number of times a submit, cancel or modify order request will be retried.
+    retry_delay : PositiveFloat, optional
+        The delay (seconds) between retries. Short delays with frequent retries may result in account bans.
+    ws_post_timeout_secs : float, default 5.0
+        The timeout for WebSocket `post` requests.
+
+    Warnings
+    --------
+    A short `retry_delay` with frequent retries may result in account bans.
+
+    """
+
+    private_key: str | None = None
+    wallet_address: str | None = None
+    vault_address: str | None = None
+    base_url_http: str | None = None
+    base_url_ws: str | None = None
+    testnet: bool = False
+    use_ws_trade_api: bool = True
+    market_order_slippage: PositiveFloat = 0.05
+    max_retries: PositiveInt | None = None
+    retry_delay: PositiveFloat | None = None
+    ws_post_timeout_secs: float | None = 5.0
@@ -0,0 +1,391 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+import asyncio
+from typing import TYPE_CHECKING
+
+import msgspec
+
+from nautilus_trader.adapters.hyperliquid.common.constants import HYPERLIQUID_VENUE
+from nautilus_trader.adapters.hyperliquid.common.symbol import HyperliquidSymbol
+from nautilus_trader.adapters.hyperliquid.schemas.ws import HyperliquidWsActiveAssetCtxMsg
+from nautilus_trader.adapters.hyperliquid.schemas.ws import HyperliquidWsBboMsg
+from nautilus_trader.adapters.hyperliquid.schemas.ws import HyperliquidWsBookMsg
+from nautilus_trader.adapters.hyperliquid.schemas.ws import HyperliquidWsMessageGeneral
+from nautilus_trader.adapters.hyperliquid.schemas.ws import HyperliquidWsTradesMsg
+from nautilus_trader.adapters.hyperliquid.types import HyperliquidFundingRateUpdate
+from nautilus_trader.adapters.hyperliquid.websocket.client import HyperliquidWebSocketClient
+from nautilus_trader.common.enums import LogColor
+from nautilus_trader.data.messages import RequestBars
+from nautilus_trader.data.messages import RequestInstrument
+from nautilus_trader.data.messages import RequestInstruments
+from nautilus_trader.data.messages import RequestQuoteTicks
+from nautilus_trader.data.messages import RequestTradeTicks
+from nautilus_trader.data.messages import SubscribeData
+from nautilus_trader.data.messages import SubscribeOrderBook
+from nautilus_trader.data.messages import SubscribeQuoteTicks
+from nautilus_trader.data.messages import SubscribeTradeTicks
+from nautilus_trader.data.messages import UnsubscribeData
+from nautilus_trader.data.messages import UnsubscribeOrderBook
+from nautilus_trader.data.messages import UnsubscribeQuoteTicks
+from nautilus_trader.data.messages import UnsubscribeTradeTicks
+from nautilus_trader.live.data_client import LiveMarketDataClient
+from nautilus_trader.model.data import CustomData
+from nautilus_trader.model.data import DataType
+from nautilus_trader.model.enums import BookType
+from nautilus_trader.model.identifiers import ClientId
+from nautilus_trader.model.identifiers import InstrumentId
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.adapters.hyperliquid.config import HyperliquidDataClientConfig
+    from nautilus_trader.adapters.hyperliquid.providers import HyperliquidInstrumentProvider
+    from nautilus_trader.cache.cache import Cache
+    from nautilus_trader.common.component import LiveClock
+    from nautilus_trader.common.component import MessageBus
+    from nautilus_trader.model.instruments import Instrument
+
+
+class HyperliquidDataClient(LiveMarketDataClient):
+    """
+    Provides a data client for the Hyperliquid decentralized perpetuals exchange.
+
+    Parameters
+    ----------
+    loop : asyncio.AbstractEventLoop
+        The event loop for the client.
+    msgbus : MessageBus
+        The message bus for the client.
+    cache : Cache
+        The cache for the client.
+    clock : LiveClock
+        The clock for the client.
+    instrument_provider : HyperliquidInstrumentProvider
+        The instrument provider.
+    base_url_ws : str
+        The base URL for the WebSocket client.
+    config : HyperliquidDataClientConfig
+        The configuration for the client.
+    name : str, optional
+        The custom client ID.
+
+    """
+
+    def __init__(
+        self,
+        loop: asyncio.AbstractEventLoop,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+        instrument_provider: HyperliquidInstrumentProvider,
+        base_url_ws: str,
+        config: HyperliquidDataClientConfig,
+        name: str | None,
+    ) -> None:
+        super().__init__(
+            loop=loop,
+            client_id=ClientId(name or HYPERLIQUID_VENUE.value),
+            venue=HYPERLIQUID_VENUE,
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+            instrument_provider=instrument_provider,
+        )
+
+        # Configuration
+        self._log.info(f"{config.testnet=}", LogColor.BLUE)
+        self._log.info(f"{config.update_instruments_interval_mins=}", LogColor.BLUE)
+
+        # WebSocket API
+        self._ws_client = HyperliquidWebSocketClient(
+            clock=clock,
+            base_url=base_url_ws,
+            handler=self._handle_ws_message,
+            handler_reconnect=None,
+            loop=loop,
+        )
+
+        # WebSocket decoders
+        self._decoder_ws_msg_general = msgspec.json.Decoder(HyperliquidWsMessageGeneral)
+        self._decoder_ws_book = msgspec.json.Decoder(HyperliquidWsBookMsg)
+        self._decoder_ws_bbo = msgspec.json.Decoder(HyperliquidWsBboMsg)
+        self._decoder_ws_trades = msgspec.json.Decoder(HyperliquidWsTradesMsg)
+        self._decoder_ws_asset_ctx = msgspec.json.Decoder(HyperliquidWsActiveAssetCtxMsg)
+
+        self._funding_rates: set[InstrumentId] = set()
+        self._last_funding_rates: dict[InstrumentId, HyperliquidFundingRateUpdate] = {}
+
+        self._update_instruments_interval_mins: int | None = config.update_instruments_interval_mins
+        self._update_instruments_task: asyncio.Task | None = None
+
+    async def _connect(self) -> None:
+        await self._instrument_provider.initialize()
+        self._send_all_instruments_to_data_engine()
+
+        if self._update_instruments_interval_mins:
+            self._update_instruments_task = self.create_task(
+                self._update_instruments(self._update_instruments_interval_mins),
+            )
+
+        await self._ws_client.connect()
+
+    async def _disconnect(self) -> None:
+        if self._update_instruments_task:
+            self._log.debug("Canceling task 'update_instruments'")
+            self._update_instruments_task.cancel()
+            self._update_instruments_task = None
+
+        await self._ws_client.disconnect()
+
+    def _send_all_instruments_to_data_engine(self) -> None:
+        for instrument in self._instrument_provider.get_all().values():
+            self._handle_data(instrument)
+
+        for currency in self._instrument_provider.currencies().values():
+            self._cache.add_currency(currency)
+
+    async def _update_instruments(self, interval_mins: int) -> None:
+        try:
+            while True:
+                self._log.debug(
+                    f"Scheduled task 'update_instruments' to run in {interval_mins} minutes",
+                )
+                await asyncio.sleep(interval_mins * 60)
+                await self._instrument_provider.initialize(reload=True)
+                self._send_all_instruments_to_data_engine()
+        except asyncio.CancelledError:
+            self._log.debug("Canceled task 'update_instruments'")
+
+    def _get_coin(self, instrument_id: InstrumentId) -> str:
+        return HyperliquidSymbol(instrument_id.symbol.value).coin
+
+    async def _subscribe(self, command: SubscribeData) -> None:
+        instrument_id: InstrumentId | None = command.data_type.metadata.get("instrument_id")
+        if instrument_id is None:
+            self._log.error(
+                f"Cannot subscribe to `{command.data_type.type}` no instrument ID in `data_type` metadata",
+            )
+            return
+
+        if command.data_type.type == HyperliquidFundingRateUpdate:
+            self._funding_rates.add(instrument_id)
+            await self._ws_client.subscribe_asset_ctx(self._get_coin(instrument_id))
+        else:
+            self._log.error(
+                f"Cannot subscribe to {command.data_type.type} (not implemented)",
+            )
+
+    async def _unsubscribe(self, command: UnsubscribeData) -> None:
+        instrument_id: InstrumentId | None = command.data_type.metadata.get("instrument_id")
+        if instrument_id is None:
+            self._log.error(
+                f"Cannot unsubscribe from `{command.data_type.type}` no instrument ID in `data_type` metadata",
+            )
+            return
+
+        if command.data_type.type == HyperliquidFundingRateUpdate:
+            if instrument_id not in self._funding_rates:
+                return
+            self._funding_rates.discard(instrument_id)
+            self._last_funding_rates.pop(instrument_id, None)
+            await self._ws_client.unsubscribe_asset_ctx(self._get_coin(instrument_id))
+        else:
+            self._log.error(
+                f"Cannot unsubscribe from {command.data_type.type} (not implemented)",
+            )
+
+    async def _subscribe_order_book_deltas(self, command: SubscribeOrderBook) -> None:
+        if command.book_type != BookType.L2_MBP:
+            self._log.error(
+                f"Cannot subscribe to order book deltas: "
+                f"{command.book_type} data is not published by Hyperliquid. "
+                "Valid book types are L2_MBP",
+            )
+            return
+
+        if command.depth:
+            self._log.warning(
+                f"Subscribing to {command.instrument_id} order book with specified `depth` "
+                "which has no effect (Hyperliquid publishes the top 20 levels)",
+            )
+
+        await self._ws_client.subscribe_order_book(self._get_coin(command.instrument_id))
+
+    async def _subscribe_quote_ticks(self, command: SubscribeQuoteTicks) -> None:
+        await self._ws_client.subscribe_bbo(self._get_coin(command.instrument_id))
+
+    async def _subscribe_trade_ticks(self, command: SubscribeTradeTicks) -> None:
+        await self._ws_client.subscribe_trades(self._get_coin(command.instrument_id))
+
+    async def _unsubscribe_order_book_deltas(self, command: UnsubscribeOrderBook) -> None:
+        await self._ws_client.unsubscribe_order_book(self._get_coin(command.instrument_id))
+
+    async def _unsubscribe_quote_ticks(self, command: UnsubscribeQuoteTicks) -> None:
+        await self._ws_client.unsubscribe_bbo(self._get_coin(command.instrument_id))
+
+    async def _unsubscribe_trade_ticks(self, command: UnsubscribeTradeTicks) -> None:
+        await self._ws_client.unsubscribe_trades(self._get_coin(command.instrument_id))
+
+    async def _request_instrument(self, request: RequestInstrument) -> None:
+        if request.start is not None:
+            self._log.warning(
+                f"Requesting instrument {request.instrument_id} with specified `start` which has no effect",
+            )
+
+        if request.end is not None:
+            self._log.warning(
+                f"Requesting instrument {request.instrument_id} with specified `end` which has no effect",
+            )
+
+        instrument: Instrument | None = self._instrument_provider.find(request.instrument_id)
+        if instrument is None:
+            self._log.error(f"Cannot find instrument for {request.instrument_id}")
+            return
+
+        self._handle_instrument(instrument, request.id, request.params)
+
+    async def _request_instruments(self, request: RequestInstruments) -> None:
+        if request.start is not None:
+            self._log.warning(
+                f"Requesting instruments for {request.venue} with specified `start` which has no effect",
+            )
+
+        if request.end is not None:
+            self._log.warning(
+                f"Requesting instruments for {request.venue} with specified `end` which has no effect",
+            )
+
+        all_instruments = self._instrument_provider.get_all()
+        target_instruments = []
+        for instrument in all_instruments.values():
+            if instrument.venue == request.venue:
+                target_instruments.append(instrument)
+
+        self._handle_instruments(
+            request.venue,
+            target_instruments,
+            request.id,
+            request.params,
+        )
+
+    async def _request_quote_ticks(self, request: RequestQuoteTicks) -> None:
+        self._log.error(
+            "Cannot request historical quotes: not published by Hyperliquid",
+        )
+
+    async def _request_trade_ticks(self, request: RequestTradeTicks) -> None:
+        self._log.error(
+            "Cannot request historical trades: not published by Hyperliquid",
+        )
+
+    async def _request_bars(self, request: RequestBars) -> None:
+        self._log.error(
+            "Cannot request historical bars: not yet implemented for Hyperliquid",
+        )
+
+    def _get_cached_instrument(self, coin: str) -> Instrument | None:
+        instrument_id = HyperliquidSymbol.from_coin(coin).to_instrument_id()
+        instrument = self._cache.instrument(instrument_id)
+        if instrument is None:
+            self._log.error(f"Cannot parse data: no instrument for {instrument_id}")
+        return instrument
+
+    def _handle_ws_message(self, raw: bytes) -> None:
+        try:
+            ws_message = self._decoder_ws_msg_general.decode(raw)
+            channel = ws_message.channel
+            if channel == "l2Book":
+                self._handle_book(raw)
+            elif channel == "bbo":
+                self._handle_bbo(raw)
+            elif channel == "trades":
+                self._handle_trades(raw)
+            elif channel == "activeAssetCtx":
+                self._handle_asset_ctx(raw)
+            else:
+                self._log.debug(f"Unhandled websocket message: {raw.decode()}")
+        except Exception as e:
+            self._log.exception(f"Failed to handle websocket message {raw.decode()}", e)
+
+    def _handle_book(self, raw: bytes) -> None:
+        msg = self._decoder_ws_book.decode(raw)
+        instrument = self._get_cached_instrument(msg.data.coin)
+        if instrument is None:
+            return
+
+        deltas = msg.data.parse_to_deltas(
+            instrument_id=instrument.id,
+            price_precision=instrument.price_precision,
+            size_precision=instrument.size_precision,
+            ts_init=self._clock.timestamp_ns(),
+        )
+        self._handle_data(deltas)
+
+    def _handle_bbo(self, raw: bytes) -> None:
+        msg = self._decoder_ws_bbo.decode(raw)
+        instrument = self._get_cached_instrument(msg.data.coin)
+        if instrument is None:
+            return
+
+        quote = msg.data.parse_to_quote_tick(
+            instrument_id=instrument.id,
+            price_precision=instrument.price_precision,
+            size_precision=instrument.size_precision,
+            ts_init=self._clock.timestamp_ns(),
+        )
+        if quote is None:
+            return  # No two-sided market
+
+        self._handle_data(quote)
+
+    def _handle_trades(self, raw: bytes) -> None:
+        msg = self._decoder_ws_trades.decode(raw)
+        for data in msg.data:
+            instrument = self._get_cached_instrument(data.coin)
+            if instrument is None:
+                return
+
+            trade = data.parse_to_trade_tick(
+                instrument_id=instrument.id,
+                price_precision=instrument.price_precision,
+                size_precision=instrument.size_precision,
+                ts_init=self._clock.timestamp_ns(),
+            )
+            self._handle_data(trade)
+
+    def _handle_asset_ctx(self, raw: bytes) -> None:
+        msg = self._decoder_ws_asset_ctx.decode(raw)
+        instrument_id = HyperliquidSymbol.from_coin(msg.data.coin).to_instrument_id()
+        if instrument_id not in self._funding_rates:
+            return
+
+        # Asset context messages carry no timestamp
+        ts_init = self._clock.timestamp_ns()
+        data = msg.data.parse_to_funding_rate_update(
+            instrument_id=instrument_id,
+            ts_event=ts_init,
+            ts_init=ts_init,
+        )
+        if data == self._last_funding_rates.get(instrument_id):
+            return  # Funding unchanged
+
+        self._last_funding_rates[instrument_id] = data
+        data_type = DataType(
+            HyperliquidFundingRateUpdate,
+            metadata={"instrument_id": instrument_id},
+        )
+        self._handle_data(CustomData(data_type=data_type, data=data))
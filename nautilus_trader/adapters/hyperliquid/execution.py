@@ -0,0 +1,965 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from decimal import Decimal
+from typing import TYPE_CHECKING
+from typing import Any
+
+import msgspec
+
+from nautilus_trader.adapters.hyperliquid.common.constants import HYPERLIQUID_VENUE
+from nautilus_trader.adapters.hyperliquid.common.enums import HyperliquidEnumParser
+from nautilus_trader.adapters.hyperliquid.common.signing import client_order_id_to_cloid
+from nautilus_trader.adapters.hyperliquid.common.signing import float_to_wire
+from nautilus_trader.adapters.hyperliquid.common.signing import round_price
+from nautilus_trader.adapters.hyperliquid.common.symbol import HyperliquidSymbol
+from nautilus_trader.adapters.hyperliquid.http.errors import HyperliquidError
+from nautilus_trader.adapters.hyperliquid.http.errors import should_retry
+from nautilus_trader.adapters.hyperliquid.http.exchange import HyperliquidExchangeHttpAPI
+from nautilus_trader.adapters.hyperliquid.http.exchange import cancel_action
+from nautilus_trader.adapters.hyperliquid.http.exchange import cancel_by_cloid_action
+from nautilus_trader.adapters.hyperliquid.http.exchange import modify_action
+from nautilus_trader.adapters.hyperliquid.http.exchange import order_action
+from nautilus_trader.adapters.hyperliquid.http.exchange import order_wire
+from nautilus_trader.adapters.hyperliquid.http.info import HyperliquidInfoHttpAPI
+from nautilus_trader.adapters.hyperliquid.schemas.info import HyperliquidFill
+from nautilus_trader.adapters.hyperliquid.schemas.info import HyperliquidOrderInfo
+from nautilus_trader.adapters.hyperliquid.schemas.ws import HyperliquidWsMessageGeneral
+from nautilus_trader.adapters.hyperliquid.schemas.ws import HyperliquidWsOrderUpdatesMsg
+from nautilus_trader.adapters.hyperliquid.schemas.ws import HyperliquidWsUserFillsMsg
+from nautilus_trader.adapters.hyperliquid.websocket.client import HyperliquidWebSocketClient
+from nautilus_trader.common.enums import LogColor
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.core.datetime import millis_to_nanos
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.live.execution_client import LiveExecutionClient
+from nautilus_trader.live.retry import RetryManagerPool
+from nautilus_trader.model.enums import AccountType
+from nautilus_trader.model.enums import OmsType
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import TimeInForce
+from nautilus_trader.model.enums import TriggerType
+from nautilus_trader.model.enums import order_type_to_str
+from nautilus_trader.model.enums import time_in_force_to_str
+from nautilus_trader.model.enums import trigger_type_to_str
+from nautilus_trader.model.identifiers import AccountId
+from nautilus_trader.model.identifiers import ClientId
+from nautilus_trader.model.identifiers import ClientOrderId
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import TradeId
+from nautilus_trader.model.identifiers import VenueOrderId
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import Money
+from nautilus_trader.model.orders import Order
+
+
+if TYPE_CHECKING:
+    import asyncio
+
+    import pandas as pd
+
+    from nautilus_trader.adapters.hyperliquid.config import HyperliquidExecClientConfig
+    from nautilus_trader.adapters.hyperliquid.http.client import HyperliquidHttpClient
+    from nautilus_trader.adapters.hyperliquid.providers import HyperliquidInstrumentProvider
+    from nautilus_trader.adapters.hyperliquid.schemas.exchange import HyperliquidExchangeResponse
+    from nautilus_trader.cache.cache import Cache
+    from nautilus_trader.common.component import LiveClock
+    from nautilus_trader.common.component import MessageBus
+    from nautilus_trader.execution.messages import CancelAllOrders
+    from nautilus_trader.execution.messages import CancelOrder
+    from nautilus_trader.execution.messages import ModifyOrder
+    from nautilus_trader.execution.messages import SubmitOrder
+    from nautilus_trader.execution.reports import FillReport
+    from nautilus_trader.execution.reports import OrderStatusReport
+    from nautilus_trader.execution.reports import PositionStatusReport
+    from nautilus_trader.model.instruments import Instrument
+
+
+# Order types which are triggered into a resting limit order
+_TRIGGERED_LIMIT_ORDER_TYPES = (OrderType.STOP_LIMIT, OrderType.LIMIT_IF_TOUCHED)
+
+# Order types which are sent as trigger orders
+_TRIGGER_ORDER_TYPES = (
+    OrderType.STOP_MARKET,
+    OrderType.STOP_LIMIT,
+    OrderType.MARKET_IF_TOUCHED,
+    OrderType.LIMIT_IF_TOUCHED,
+)
+
+# Perpetuals are margined and settled in USDC
+_USDC = Currency.from_str("USDC")
+
+
+class HyperliquidExecutionClient(LiveExecutionClient):
+    """
+    Provides an execution client for the Hyperliquid decentralized perpetuals exchange.
+
+    Exchange actions are signed with the configured private key, and sent either as
+    WebSocket `post` requests or through the REST API. Order and fill updates are
+    streamed on the user channels for the account (or vault) address.
+
+    Parameters
+    ----------
+    loop : asyncio.AbstractEventLoop
+        The event loop for the client.
+    client : HyperliquidHttpClient
+        The Hyperliquid HTTP client (with a signer).
+    msgbus : MessageBus
+        The message bus for the client.
+    cache : Cache
+        The cache for the client.
+    clock : LiveClock
+        The clock for the client.
+    instrument_provider : HyperliquidInstrumentProvider
+        The instrument provider.
+    wallet_address : str
+        The address of the trading account.
+    base_url_ws : str
+        The base URL for the WebSocket client.
+    config : HyperliquidExecClientConfig
+        The configuration for the client.
+    name : str, optional
+        The custom client ID.
+
+    """
+
+    def __init__(
+        self,
+        loop: asyncio.AbstractEventLoop,
+        client: HyperliquidHttpClient,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+        instrument_provider: HyperliquidInstrumentProvider,
+        wallet_address: str,
+        base_url_ws: str,
+        config: HyperliquidExecClientConfig,
+        name: str | None,
+    ) -> None:
+        super().__init__(
+            loop=loop,
+            client_id=ClientId(name or HYPERLIQUID_VENUE.value),
+            venue=HYPERLIQUID_VENUE,
+            oms_type=OmsType.NETTING,
+            instrument_provider=instrument_provider,
+            account_type=AccountType.MARGIN,
+            base_currency=_USDC,
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+        )
+
+        # Configuration
+        # Account state, orders and fills for a vault are reported under the vault address
+        self._user: str = config.vault_address or wallet_address
+        self._use_ws_trade_api = config.use_ws_trade_api
+        self._market_order_slippage = Decimal(str(config.market_order_slippage))
+
+        self._log.info(f"User: {self._user}", LogColor.BLUE)
+        self._log.info(f"{config.testnet=}", LogColor.BLUE)
+        self._log.info(f"{config.use_ws_trade_api=}", LogColor.BLUE)
+        self._log.info(f"{config.market_order_slippage=}", LogColor.BLUE)
+        self._log.info(f"{config.max_retries=}", LogColor.BLUE)
+        self._log.info(f"{config.retry_delay=}", LogColor.BLUE)
+        self._log.info(f"{config.ws_post_timeout_secs=}", LogColor.BLUE)
+
+        self._enum_parser = HyperliquidEnumParser()
+        self._hyperliquid_instrument_provider = instrument_provider
+
+        account_id = AccountId(f"{name or HYPERLIQUID_VENUE.value}-{self._user}")
+        self._set_account_id(account_id)
+
+        # HTTP API
+        self._http_info = HyperliquidInfoHttpAPI(client=client, clock=clock)
+        self._http_exchange = HyperliquidExchangeHttpAPI(client=client, clock=clock)
+
+        # WebSocket API
+        self._ws_client = HyperliquidWebSocketClient(
+            clock=clock,
+            base_url=base_url_ws,
+            handler=self._handle_ws_message,
+            handler_reconnect=None,
+            loop=loop,
+            ws_post_timeout_secs=config.ws_post_timeout_secs,
+        )
+
+        # Decoders
+        self._decoder_ws_msg_general = msgspec.json.Decoder(HyperliquidWsMessageGeneral)
+        self._decoder_ws_order_updates = msgspec.json.Decoder(HyperliquidWsOrderUpdatesMsg)
+        self._decoder_ws_user_fills = msgspec.json.Decoder(HyperliquidWsUserFillsMsg)
+
+        # Hot caches
+        self._cloids: dict[str, ClientOrderId] = {}
+
+        self._retry_manager_pool = RetryManagerPool[None](
+            pool_size=100,
+            max_retries=config.max_retries or 0,
+            retry_delay_secs=config.retry_delay or 0.0,
+            logger=self._log,
+            exc_types=(HyperliquidError,),
+            retry_check=should_retry,
+        )
+
+    async def _connect(self) -> None:
+        await self._instrument_provider.initialize()
+        await self._update_account_state()
+
+        await self._ws_client.connect()
+        await self._ws_client.subscribe_order_updates(self._user)
+        await self._ws_client.subscribe_user_fills(self._user)
+
+    async def _disconnect(self) -> None:
+        await self._ws_client.disconnect()
+
+    def _stop(self) -> None:
+        self._retry_manager_pool.shutdown()
+
+    # -- EXECUTION REPORTS ------------------------------------------------------------------------
+
+    async def generate_order_status_reports(
+        self,
+        instrument_id: InstrumentId | None = None,
+        start: pd.Timestamp | None = None,
+        end: pd.Timestamp | None = None,
+        open_only: bool = False,
+    ) -> list[OrderStatusReport]:
+        self._log.debug("Requesting OrderStatusReports...")
+        reports: list[OrderStatusReport] = []
+
+        try:
+            # Hyperliquid only provides open orders, closed orders are reconciled through fills
+            open_orders = await self._http_info.fetch_open_orders(self._user)
+            for open_order in open_orders:
+                instrument = self._get_cached_instrument(open_order.coin)
+                if instrument is None:
+                    continue
+                if instrument_id is not None and instrument.id != instrument_id:
+                    continue
+
+                report = open_order.parse_to_order_status_report(
+                    account_id=self.account_id,
+                    instrument=instrument,
+                    client_order_id=self._parse_client_order_id(
+                        open_order.cloid,
+                        VenueOrderId(str(open_order.oid)),
+                    ),
+                    report_id=UUID4(),
+                    enum_parser=self._enum_parser,
+                    ts_init=self._clock.timestamp_ns(),
+                )
+                reports.append(report)
+                self._log.debug(f"Received {report}", LogColor.MAGENTA)
+        except HyperliquidError as e:
+            self._log.error(f"Failed to generate OrderStatusReports: {e}")
+
+        len_reports = len(reports)
+        plural = "" if len_reports == 1 else "s"
+        self._log.info(f"Received {len(reports)} OrderStatusReport{plural}")
+
+        return reports
+
+    async def generate_order_status_report(
+        self,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId | None = None,
+        venue_order_id: VenueOrderId | None = None,
+    ) -> OrderStatusReport | None:
+        PyCondition.is_false(
+            client_order_id is None and venue_order_id is None,
+            "both `client_order_id` and `venue_order_id` were `None`",
+        )
+
+        self._log.info(
+            f"Generating OrderStatusReport for "
+            f"{repr(client_order_id) if client_order_id else ''} "
+            f"{repr(venue_order_id) if venue_order_id else ''}",
+        )
+
+        instrument = self._cache.instrument(instrument_id)
+        if instrument is None:
+            self._log.error(f"Cannot generate report: no instrument for {instrument_id}")
+            return None
+
+        # Orders can be queried by venue order ID or cloid
+        oid: int | str
+        if venue_order_id is not None:
+            oid = int(venue_order_id.value)
+        else:
+            assert client_order_id is not None  # Checked above
+            oid = self._get_cloid(client_order_id)
+
+        try:
+            response = await self._http_info.fetch_order_status(self._user, oid)
+            if response.order is None:
+                self._log.warning(f"No order found for {client_order_id!r} {venue_order_id!r}")
+                return None
+
+            order_info = response.order
+            report = order_info.order.parse_to_order_status_report(
+                account_id=self.account_id,
+                instrument=instrument,
+                client_order_id=self._parse_client_order_id(
+                    order_info.order.cloid,
+                    VenueOrderId(str(order_info.order.oid)),
+                ),
+                report_id=UUID4(),
+                enum_parser=self._enum_parser,
+                ts_init=self._clock.timestamp_ns(),
+                order_status=self._enum_parser.parse_hyperliquid_order_status(order_info.status),
+                ts_last=millis_to_nanos(order_info.statusTimestamp),
+            )
+            self._log.debug(f"Received {report}", LogColor.MAGENTA)
+            return report
+        except HyperliquidError as e:
+            self._log.error(f"Failed to generate OrderStatusReport: {e}")
+        return None
+
+    async def generate_fill_reports(
+        self,
+        instrument_id: InstrumentId | None = None,
+        venue_order_id: VenueOrderId | None = None,
+        start: pd.Timestamp | None = None,
+        end: pd.Timestamp | None = None,
+    ) -> list[FillReport]:
+        self._log.debug("Requesting FillReports...")
+        reports: list[FillReport] = []
+
+        try:
+            fills = await self._http_info.fetch_fills(
+                self._user,
+                start_time=int(start.timestamp() * 1000) if start is not None else None,
+                end_time=int(end.timestamp() * 1000) if end is not None else None,
+            )
+            for fill in fills:
+                instrument = self._get_cached_instrument(fill.coin)
+                if instrument is None:
+                    continue
+                if instrument_id is not None and instrument.id != instrument_id:
+                    continue
+                if venue_order_id is not None and str(fill.oid) != venue_order_id.value:
+                    continue
+
+                report = fill.parse_to_fill_report(
+                    account_id=self.account_id,
+                    instrument=instrument,
+                    client_order_id=self._parse_client_order_id(
+                        fill.cloid,
+                        VenueOrderId(str(fill.oid)),
+                    ),
+                    report_id=UUID4(),
+                    enum_parser=self._enum_parser,
+                    ts_init=self._clock.timestamp_ns(),
+                )
+                reports.append(report)
+                self._log.debug(f"Received {report}")
+        except HyperliquidError as e:
+            self._log.error(f"Failed to generate FillReports: {e}")
+
+        len_reports = len(reports)
+        plural = "" if len_reports == 1 else "s"
+        self._log.info(f"Received {len(reports)} FillReport{plural}")
+
+        return reports
+
+    async def generate_position_status_reports(
+        self,
+        instrument_id: InstrumentId | None = None,
+        start: pd.Timestamp | None = None,
+        end: pd.Timestamp | None = None,
+    ) -> list[PositionStatusReport]:
+        reports: list[PositionStatusReport] = []
+
+        try:
+            self._log.debug("Requesting PositionStatusReports...")
+            state = await self._http_info.fetch_clearinghouse_state(self._user)
+            for asset_position in state.assetPositions:
+                position = asset_position.position
+                instrument = self._get_cached_instrument(position.coin)
+                if instrument is None:
+                    continue
+                if instrument_id is not None and instrument.id != instrument_id:
+                    continue
+
+                position_report = position.parse_to_position_status_report(
+                    account_id=self.account_id,
+                    instrument=instrument,
+                    report_id=UUID4(),
+                    ts_init=self._clock.timestamp_ns(),
+                )
+                self._log.debug(f"Received {position_report}")
+                reports.append(position_report)
+        except HyperliquidError as e:
+            self._log.error(f"Failed to generate PositionReports: {e}")
+
+        len_reports = len(reports)
+        plural = "" if len_reports == 1 else "s"
+        self._log.info(f"Received {len(reports)} PositionReport{plural}")
+
+        return reports
+
+    def _get_cached_instrument(self, coin: str) -> Instrument | None:
+        instrument_id = HyperliquidSymbol.from_coin(coin).to_instrument_id()
+        instrument = self._cache.instrument(instrument_id)
+        if instrument is None:
+            self._log.debug(f"No instrument found for {instrument_id}")
+        return instrument
+
+    def _get_asset_index(self, instrument_id: InstrumentId) -> int:
+        asset = self._hyperliquid_instrument_provider.asset_index(instrument_id)
+        if asset is None:
+            raise HyperliquidError(code=None, message=f"No asset index for {instrument_id}")
+        return asset
+
+    def _get_cloid(self, client_order_id: ClientOrderId) -> str:
+        cloid = client_order_id_to_cloid(client_order_id)
+        self._cloids[cloid] = client_order_id
+        return cloid
+
+    def _parse_client_order_id(
+        self,
+        cloid: str | None,
+        venue_order_id: VenueOrderId,
+    ) -> ClientOrderId | None:
+        if cloid:
+            client_order_id = self._cloids.get(cloid)
+            if client_order_id is not None:
+                return client_order_id
+
+        return self._cache.client_order_id(venue_order_id)
+
+    async def _update_account_state(self) -> None:
+        try:
+            state = await self._http_info.fetch_clearinghouse_state(self._user)
+            self.generate_account_state(
+                balances=[state.parse_to_account_balance(_USDC)],
+                margins=[state.parse_to_margin_balance(_USDC)],
+                reported=True,
+                ts_event=millis_to_nanos(state.time),
+            )
+        except Exception as e:
+            self._log.error(f"Failed to generate AccountState: {e}")
+
+    async def _send_action(self, action: dict[str, Any]) -> HyperliquidExchangeResponse:
+        if not self._use_ws_trade_api:
+            return await self._http_exchange.send_action(action)
+
+        # Actions are signed per attempt, as every request requires a new nonce
+        payload = self._http_exchange.client.sign_action(action)
+        response = await self._ws_client.post_action(payload)
+        return self._http_exchange.check_response(response)
+
+    # -- COMMAND HANDLERS -------------------------------------------------------------------------
+
+    async def _cancel_order(self, command: CancelOrder) -> None:
+        order: Order | None = self._cache.order(command.client_order_id)
+        if order is None:
+            self._log.error(f"{command.client_order_id!r} not found in cache")
+            return
+
+        if order.is_closed:
+            self._log.warning(
+                f"`CancelOrder` command for {command.client_order_id!r} when order already {order.status_string()} "
+                "(will not send to exchange)",
+            )
+            return
+
+        venue_order_id = command.venue_order_id or order.venue_order_id
+
+        async with self._retry_manager_pool as retry_manager:
+            try:
+                asset = self._get_asset_index(order.instrument_id)
+                if venue_order_id is not None:
+                    action = cancel_action([(asset, int(venue_order_id.value))])
+                else:
+                    cloid = self._get_cloid(order.client_order_id)
+                    action = cancel_by_cloid_action([(asset, cloid)])
+            except HyperliquidError as e:
+                self._log.error(f"Cannot cancel {order.client_order_id!r}: {e.message}")
+                return
+
+            await retry_manager.run(
+                "cancel_order",
+                [order.client_order_id, venue_order_id],
+                self._send_action,
+                action,
+            )
+            if not retry_manager.result:
+                self.generate_order_cancel_rejected(
+                    order.strategy_id,
+                    order.instrument_id,
+                    order.client_order_id,
+                    order.venue_order_id,
+                    retry_manager.message,
+                    self._clock.timestamp_ns(),
+                )
+
+    async def _cancel_all_orders(self, command: CancelAllOrders) -> None:
+        orders_open = [
+            order
+            for order in self._cache.orders_open(instrument_id=command.instrument_id)
+            if command.order_side in (OrderSide.NO_ORDER_SIDE, order.side)
+        ]
+        if not orders_open:
+            self._log.info(f"No open orders to cancel for {command.instrument_id}")
+            return
+
+        try:
+            asset = self._get_asset_index(command.instrument_id)
+        except HyperliquidError as e:
+            self._log.error(f"Cannot cancel all orders: {e.message}")
+            return
+
+        # Hyperliquid has no cancel all action, so open orders are canceled in a batch
+        # (orders without a venue order ID are canceled by cloid)
+        cancels = [
+            (asset, int(order.venue_order_id.value)) for order in orders_open if order.venue_order_id
+        ]
+        cloid_cancels = [
+            (asset, self._get_cloid(order.client_order_id))
+            for order in orders_open
+            if order.venue_order_id is None
+        ]
+
+        async with self._retry_manager_pool as retry_manager:
+            await retry_manager.run(
+                "cancel_all_orders",
+                None,
+                self._cancel_orders_batch,
+                cancels,
+                cloid_cancels,
+            )
+            if not retry_manager.result:
+                for order in orders_open:
+                    if order.is_closed:
+                        continue
+                    self.generate_order_cancel_rejected(
+                        order.strategy_id,
+                        order.instrument_id,
+                        order.client_order_id,
+                        order.venue_order_id,
+                        retry_manager.message,
+                        self._clock.timestamp_ns(),
+                    )
+
+    async def _cancel_orders_batch(
+        self,
+        cancels: list[tuple[int, int]],
+        cloid_cancels: list[tuple[int, str]],
+    ) -> None:
+        if cancels:
+            await self._send_action(cancel_action(cancels))
+        if cloid_cancels:
+            await self._send_action(cancel_by_cloid_action(cloid_cancels))
+
+    async def _modify_order(self, command: ModifyOrder) -> None:
+        order: Order | None = self._cache.order(command.client_order_id)
+        if order is None:
+            self._log.error(f"{command.client_order_id!r} not found in cache")
+            return
+
+        if order.is_closed:
+            self._log.warning(
+                f"`ModifyOrder` command for {command.client_order_id!r} when order already {order.status_string()} "
+                "(will not send to exchange)",
+            )
+            return
+
+        instrument = self._cache.instrument(order.instrument_id)
+        if instrument is None:
+            self._log.error(f"Cannot modify order: no instrument for {order.instrument_id}")
+            return
+
+        quantity = command.quantity or order.quantity
+        price = command.price or (order.price if order.has_price else None)
+        trigger_price = command.trigger_price or (
+            order.trigger_price if order.has_trigger_price else None
+        )
+
+        async with self._retry_manager_pool as retry_manager:
+            try:
+                # Modifications replace the whole order
+                wire = self._build_order_wire(
+                    order,
+                    instrument,
+                    quantity=quantity.as_decimal(),
+                    price=price.as_decimal() if price is not None else None,
+                    trigger_price=trigger_price.as_decimal() if trigger_price is not None else None,
+                )
+            except HyperliquidError as e:
+                self._log.error(f"Cannot modify {order.client_order_id!r}: {e.message}")
+                return
+
+            venue_order_id = command.venue_order_id or order.venue_order_id
+            oid: int | str = (
+                int(venue_order_id.value)
+                if venue_order_id is not None
+                else self._get_cloid(order.client_order_id)
+            )
+            await retry_manager.run(
+                "modify_order",
+                [order.client_order_id, venue_order_id],
+                self._modify_order_and_update,
+                order,
+                oid,
+                wire,
+            )
+            if not retry_manager.result:
+                self.generate_order_modify_rejected(
+                    order.strategy_id,
+                    order.instrument_id,
+                    order.client_order_id,
+                    order.venue_order_id,
+                    retry_manager.message,
+                    self._clock.timestamp_ns(),
+                )
+
+    async def _modify_order_and_update(
+        self,
+        order: Order,
+        oid: int | str,
+        wire: dict[str, Any],
+    ) -> None:
+        response = await self._send_action(modify_action(oid, wire))
+
+        # A modified order is assigned a new venue order ID
+        statuses = response.statuses
+        new_oid = statuses[0].oid if statuses and not isinstance(statuses[0], str) else None
+        instrument = self._cache.instrument(order.instrument_id)
+        if instrument is None:
+            return
+
+        self.generate_order_updated(
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=order.client_order_id,
+            venue_order_id=VenueOrderId(str(new_oid)) if new_oid is not None else None,
+            quantity=instrument.make_qty(wire["s"]),
+            price=instrument.make_price(wire["p"]) if order.has_price else None,
+            trigger_price=(
+                instrument.make_price(wire["t"]["trigger"]["triggerPx"])
+                if "trigger" in wire["t"]
+                else None
+            ),
+            ts_event=self._clock.timestamp_ns(),
+        )
+
+    async def _submit_order(self, command: SubmitOrder) -> None:
+        order = command.order
+        if order.is_closed:
+            self._log.warning(f"Order {order} is already closed")
+            return
+
+        if not self._check_order_validity(order):
+            return
+
+        # Generate order submitted event, to ensure correct ordering of event
+        self.generate_order_submitted(
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=order.client_order_id,
+            ts_event=self._clock.timestamp_ns(),
+        )
+
+        async with self._retry_manager_pool as retry_manager:
+            await retry_manager.run(
+                "submit_order",
+                [order.client_order_id],
+                self._submit_order_inner,
+                order,
+            )
+            if not retry_manager.result:
+                self.generate_order_rejected(
+                    strategy_id=order.strategy_id,
+                    instrument_id=order.instrument_id,
+                    client_order_id=order.client_order_id,
+                    reason=retry_manager.message,
+                    ts_event=self._clock.timestamp_ns(),
+                )
+
+    def _check_order_validity(self, order: Order) -> bool:
+        if order.order_type in (OrderType.TRAILING_STOP_MARKET, OrderType.TRAILING_STOP_LIMIT):
+            self._log.error(
+                f"Cannot submit {order}: {order_type_to_str(order.order_type)} orders "
+                "not supported for Hyperliquid",
+            )
+            return False
+
+        if order.is_quote_quantity:
+            self._log.error(f"Cannot submit {order}: quote quantity not supported for Hyperliquid")
+            return False
+
+        if order.is_post_only and order.order_type != OrderType.LIMIT:
+            self._log.error(
+                f"Cannot submit {order} has invalid post only {order.is_post_only}, unsupported on Hyperliquid",
+            )
+            return False
+
+        if order.order_type == OrderType.LIMIT and order.time_in_force not in (
+            TimeInForce.GTC,
+            TimeInForce.IOC,
+        ):
+            self._log.error(
+                f"Cannot submit {order}: time in force "
+                f"{time_in_force_to_str(order.time_in_force)} unsupported on Hyperliquid",
+            )
+            return False
+
+        if order.order_type in _TRIGGER_ORDER_TYPES and order.trigger_type not in (
+            TriggerType.DEFAULT,
+            TriggerType.MARK_PRICE,
+        ):
+            self._log.error(
+                f"Cannot submit {order}: trigger type "
+                f"{trigger_type_to_str(order.trigger_type)} unsupported on Hyperliquid "
+                "(orders trigger on the mark price)",
+            )
+            return False
+
+        return True
+
+    def _slippage_price(self, side: OrderSide, reference: Decimal, price_precision: int) -> Decimal:
+        # Market orders are aggressive limit orders, bounded by the slippage from the reference
+        if side == OrderSide.BUY:
+            price = reference * (1 + self._market_order_slippage)
+        else:
+            price = reference * (1 - self._market_order_slippage)
+        return round_price(price, price_precision)
+
+    async def _fetch_reference_price(self, order: Order) -> Decimal:
+        quote = self._cache.quote_tick(order.instrument_id)
+        if quote is not None:
+            if order.side == OrderSide.BUY:
+                return quote.ask_price.as_decimal()
+            return quote.bid_price.as_decimal()
+
+        # Fall back to the venue mid price when not subscribed to quotes
+        asset = self._get_asset_index(order.instrument_id)
+        _, asset_ctxs = await self._http_info.fetch_meta_and_asset_ctxs()
+        ctx = asset_ctxs[asset]
+        mid = ctx.midPx or ctx.markPx
+        if mid is None:
+            raise HyperliquidError(
+                code=None,
+                message=f"No reference price for market order on {order.instrument_id}",
+            )
+        return Decimal(mid)
+
+    def _build_order_wire(
+        self,
+        order: Order,
+        instrument: Instrument,
+        quantity: Decimal,
+        price: Decimal | None,
+        trigger_price: Decimal | None,
+    ) -> dict[str, Any]:
+        is_trigger = order.order_type in _TRIGGER_ORDER_TYPES
+        if is_trigger and trigger_price is not None and price is None:
+            # Trigger market orders still require a limit price for the slippage bound
+            price = self._slippage_price(order.side, trigger_price, instrument.price_precision)
+
+        if price is None:
+            raise HyperliquidError(code=None, message=f"No price for {order.client_order_id!r}")
+
+        return order_wire(
+            asset=self._get_asset_index(order.instrument_id),
+            is_buy=order.side == OrderSide.BUY,
+            price=float_to_wire(price),
+            size=float_to_wire(quantity),
+            reduce_only=order.is_reduce_only,
+            time_in_force=(
+                None
+                if is_trigger
+                else self._enum_parser.parse_nautilus_time_in_force(
+                    order.time_in_force,
+                    order.is_post_only,
+                )
+            ),
+            trigger_price=float_to_wire(trigger_price) if is_trigger and trigger_price else None,
+            is_market=order.order_type in (OrderType.STOP_MARKET, OrderType.MARKET_IF_TOUCHED),
+            tpsl=self._enum_parser.parse_nautilus_tpsl(order.order_type) if is_trigger else None,
+            cloid=self._get_cloid(order.client_order_id),
+        )
+
+    async def _submit_order_inner(self, order: Order) -> None:
+        instrument = self._cache.instrument(order.instrument_id)
+        if instrument is None:
+            raise HyperliquidError(code=None, message=f"No instrument for {order.instrument_id}")
+
+        if order.order_type == OrderType.MARKET:
+            reference = await self._fetch_reference_price(order)
+            wire = order_wire(
+                asset=self._get_asset_index(order.instrument_id),
+                is_buy=order.side == OrderSide.BUY,
+                price=float_to_wire(
+                    self._slippage_price(order.side, reference, instrument.price_precision),
+                ),
+                size=float_to_wire(order.quantity.as_decimal()),
+                reduce_only=order.is_reduce_only,
+                time_in_force=self._enum_parser.parse_nautilus_time_in_force(
+                    TimeInForce.IOC,
+                    is_post_only=False,
+                ),
+                cloid=self._get_cloid(order.client_order_id),
+            )
+        else:
+            wire = self._build_order_wire(
+                order,
+                instrument,
+                quantity=order.quantity.as_decimal(),
+                price=order.price.as_decimal() if order.has_price else None,
+                trigger_price=(
+                    order.trigger_price.as_decimal() if order.has_trigger_price else None
+                ),
+            )
+
+        response = await self._send_action(order_action([wire]))
+
+        # The order updates channel may not have accepted the order yet
+        statuses = response.statuses
+        oid = statuses[0].oid if statuses and not isinstance(statuses[0], str) else None
+        current_order = self._cache.order(order.client_order_id)
+        if oid is not None and current_order and current_order.status == OrderStatus.SUBMITTED:
+            self.generate_order_accepted(
+                strategy_id=order.strategy_id,
+                instrument_id=order.instrument_id,
+                client_order_id=order.client_order_id,
+                venue_order_id=VenueOrderId(str(oid)),
+                ts_event=self._clock.timestamp_ns(),
+            )
+
+    # -- WEBSOCKET HANDLERS -----------------------------------------------------------------------
+
+    def _handle_ws_message(self, raw: bytes) -> None:
+        try:
+            msg = self._decoder_ws_msg_general.decode(raw)
+            if msg.channel == "orderUpdates":
+                order_updates_msg = self._decoder_ws_order_updates.decode(raw)
+                for order_info in order_updates_msg.data:
+                    self._handle_order_update(order_info)
+            elif msg.channel == "userFills":
+                user_fills_msg = self._decoder_ws_user_fills.decode(raw)
+                if user_fills_msg.data.isSnapshot:
+                    return  # Fills on subscription are reconciled through reports
+                for fill in user_fills_msg.data.fills:
+                    self._handle_fill(fill)
+        except Exception as e:
+            self._log.exception(f"Failed to handle websocket message: {raw.decode()}", e)
+
+    def _handle_order_update(self, order_info: HyperliquidOrderInfo) -> None:
+        venue_order_id = VenueOrderId(str(order_info.order.oid))
+        client_order_id = self._parse_client_order_id(order_info.order.cloid, venue_order_id)
+        if client_order_id is None:
+            self._log.debug(
+                f"Cannot process order update for {venue_order_id!r}: no `ClientOrderId` found "
+                "(most likely due to being an external order)",
+            )
+            return
+
+        order = self._cache.order(client_order_id)
+        if order is None:
+            self._log.error(f"Cannot find {client_order_id!r}")
+            return
+
+        if order.venue_order_id is not None and order.venue_order_id != venue_order_id:
+            # Updates for an order replaced by a modification
+            return
+
+        ts_event = millis_to_nanos(order_info.statusTimestamp)
+        status = self._enum_parser.parse_hyperliquid_order_status(order_info.status)
+
+        match status:
+            case OrderStatus.ACCEPTED:
+                if order.status == OrderStatus.SUBMITTED:
+                    self.generate_order_accepted(
+                        strategy_id=order.strategy_id,
+                        instrument_id=order.instrument_id,
+                        client_order_id=client_order_id,
+                        venue_order_id=venue_order_id,
+                        ts_event=ts_event,
+                    )
+            case OrderStatus.TRIGGERED:
+                if order.order_type in _TRIGGERED_LIMIT_ORDER_TYPES:
+                    self.generate_order_triggered(
+                        strategy_id=order.strategy_id,
+                        instrument_id=order.instrument_id,
+                        client_order_id=client_order_id,
+                        venue_order_id=venue_order_id,
+                        ts_event=ts_event,
+                    )
+            case OrderStatus.REJECTED:
+                if order.status == OrderStatus.SUBMITTED:
+                    self.generate_order_rejected(
+                        strategy_id=order.strategy_id,
+                        instrument_id=order.instrument_id,
+                        client_order_id=client_order_id,
+                        reason=order_info.status,
+                        ts_event=ts_event,
+                    )
+            case OrderStatus.CANCELED:
+                if order.is_closed:
+                    return
+                self.generate_order_canceled(
+                    strategy_id=order.strategy_id,
+                    instrument_id=order.instrument_id,
+                    client_order_id=client_order_id,
+                    venue_order_id=venue_order_id,
+                    ts_event=ts_event,
+                )
+            case _:
+                # Fills are handled on the user fills channel
+                self._log.debug(f"Ignoring order update {order_info.status} for {client_order_id!r}")
+
+    def _handle_fill(self, fill: HyperliquidFill) -> None:
+        venue_order_id = VenueOrderId(str(fill.oid))
+        client_order_id = self._parse_client_order_id(fill.cloid, venue_order_id)
+        if client_order_id is None:
+            self._log.debug(
+                f"Cannot process fill for {venue_order_id!r}: no `ClientOrderId` found "
+                "(most likely due to being an external order)",
+            )
+            return
+
+        order = self._cache.order(client_order_id)
+        if order is None:
+            self._log.error(f"Cannot find {client_order_id!r}")
+            return
+
+        instrument = self._get_cached_instrument(fill.coin)
+        if instrument is None:
+            raise ValueError(f"Cannot handle fill: instrument for {fill.coin} not found")
+
+        self.generate_order_filled(
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=client_order_id,
+            venue_order_id=venue_order_id,
+            venue_position_id=None,
+            trade_id=TradeId(str(fill.tid)),
+            order_side=self._enum_parser.parse_hyperliquid_order_side(fill.side),
+            order_type=order.order_type,
+            last_qty=instrument.make_qty(fill.sz),
+            last_px=instrument.make_price(fill.px),
+            quote_currency=instrument.quote_currency,
+            commission=Money(Decimal(fill.fee), Currency.from_str(fill.feeToken)),
+            liquidity_side=fill.liquidity_side,
+            ts_event=millis_to_nanos(fill.time),
+        )
+
+        # Margin account balances are not streamed on the user channels
+        self.create_task(self._update_account_state())
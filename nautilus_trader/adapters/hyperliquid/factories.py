@@ -0,0 +1,258 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import asyncio
+from functools import lru_cache
+
+from nautilus_trader.adapters.hyperliquid.common.constants import HYPERLIQUID_EXCHANGE_RATE_LIMIT_KEY
+from nautilus_trader.adapters.hyperliquid.common.constants import HYPERLIQUID_INFO_RATE_LIMIT_KEY
+from nautilus_trader.adapters.hyperliquid.common.credentials import get_private_key
+from nautilus_trader.adapters.hyperliquid.common.credentials import get_wallet_address
+from nautilus_trader.adapters.hyperliquid.common.signing import HyperliquidSigner
+from nautilus_trader.adapters.hyperliquid.common.urls import get_http_base_url
+from nautilus_trader.adapters.hyperliquid.common.urls import get_ws_base_url
+from nautilus_trader.adapters.hyperliquid.config import HyperliquidDataClientConfig
+from nautilus_trader.adapters.hyperliquid.config import HyperliquidExecClientConfig
+from nautilus_trader.adapters.hyperliquid.data import HyperliquidDataClient
+from nautilus_trader.adapters.hyperliquid.execution import HyperliquidExecutionClient
+from nautilus_trader.adapters.hyperliquid.http.client import HyperliquidHttpClient
+from nautilus_trader.adapters.hyperliquid.providers import HyperliquidInstrumentProvider
+from nautilus_trader.cache.cache import Cache
+from nautilus_trader.common.component import LiveClock
+from nautilus_trader.common.component import MessageBus
+from nautilus_trader.config import InstrumentProviderConfig
+from nautilus_trader.core.nautilus_pyo3 import Quota
+from nautilus_trader.live.factories import LiveDataClientFactory
+from nautilus_trader.live.factories import LiveExecClientFactory
+
+
+@lru_cache(4)
+def get_cached_hyperliquid_http_client(
+    clock: LiveClock,
+    private_key: str | None = None,
+    vault_address: str | None = None,
+    base_url: str | None = None,
+    is_testnet: bool = False,
+) -> HyperliquidHttpClient:
+    """
+    Cache and return a Hyperliquid HTTP client.
+
+    If a cached client with matching parameters already exists, the cached client will be returned.
+
+    Parameters
+    ----------
+    clock : LiveClock
+        The clock for the client.
+    private_key : str, optional
+        The private key for signing exchange actions (``None`` for public only access).
+    vault_address : str, optional
+        The vault or subaccount address to trade on behalf of.
+    base_url : str, optional
+        The base URL for the API endpoints.
+    is_testnet : bool, default False
+        If the client is connecting to the Hyperliquid testnet.
+
+    Returns
+    -------
+    HyperliquidHttpClient
+
+    """
+    base_url = base_url or get_http_base_url(is_testnet)
+    signer = HyperliquidSigner(private_key, is_mainnet=not is_testnet) if private_key else None
+
+    # Setup rate limit quotas
+    # https://hyperliquid.gitbook.io/hyperliquid-docs/for-developers/api/rate-limits-and-user-limits
+    # REST requests share a weight budget of 1200 per minute per IP address,
+    # where exchange actions have a weight of 1 and most info requests 20.
+    ratelimiter_default_quota = Quota.rate_per_second(1)
+    ratelimiter_quotas: list[tuple[str, Quota]] = [
+        (HYPERLIQUID_INFO_RATE_LIMIT_KEY, Quota.rate_per_second(1)),
+        (HYPERLIQUID_EXCHANGE_RATE_LIMIT_KEY, Quota.rate_per_second(10)),
+    ]
+
+    return HyperliquidHttpClient(
+        clock=clock,
+        base_url=base_url,
+        signer=signer,
+        vault_address=vault_address,
+        ratelimiter_quotas=ratelimiter_quotas,
+        ratelimiter_default_quota=ratelimiter_default_quota,
+    )
+
+
+@lru_cache(1)
+def get_cached_hyperliquid_instrument_provider(
+    client: HyperliquidHttpClient,
+    clock: LiveClock,
+    config: InstrumentProviderConfig,
+) -> HyperliquidInstrumentProvider:
+    """
+    Cache and return a Hyperliquid instrument provider.
+
+    If a cached provider already exists, then that provider will be returned.
+
+    Parameters
+    ----------
+    client : HyperliquidHttpClient
+        The Hyperliquid HTTP client.
+    clock : LiveClock
+        The clock instance.
+    config : InstrumentProviderConfig
+        The instrument provider configuration.
+
+    Returns
+    -------
+    HyperliquidInstrumentProvider
+
+    """
+    return HyperliquidInstrumentProvider(
+        client=client,
+        clock=clock,
+        config=config,
+    )
+
+
+class HyperliquidLiveDataClientFactory(LiveDataClientFactory):
+    """
+    Provides a Hyperliquid live data client factory.
+    """
+
+    @staticmethod
+    def create(  # type: ignore
+        loop: asyncio.AbstractEventLoop,
+        name: str,
+        config: HyperliquidDataClientConfig,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+    ) -> HyperliquidDataClient:
+        """
+        Create a new Hyperliquid data client.
+
+        Parameters
+        ----------
+        loop : asyncio.AbstractEventLoop
+            The event loop for the client.
+        name : str
+            The custom client ID.
+        config : HyperliquidDataClientConfig
+            The client configuration.
+        msgbus : MessageBus
+            The message bus for the client.
+        cache : Cache
+            The cache for the client.
+        clock: LiveClock
+            The clock for the instrument provider.
+
+        Returns
+        -------
+        HyperliquidDataClient
+
+        """
+        # Market data is public, so no credentials are required
+        client: HyperliquidHttpClient = get_cached_hyperliquid_http_client(
+            clock=clock,
+            base_url=config.base_url_http,
+            is_testnet=config.testnet,
+        )
+        provider = get_cached_hyperliquid_instrument_provider(
+            client=client,
+            clock=clock,
+            config=config.instrument_provider,
+        )
+        return HyperliquidDataClient(
+            loop=loop,
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+            instrument_provider=provider,
+            base_url_ws=config.base_url_ws or get_ws_base_url(config.testnet),
+            config=config,
+            name=name,
+        )
+
+
+class HyperliquidLiveExecClientFactory(LiveExecClientFactory):
+    """
+    Provides a Hyperliquid live execution client factory.
+    """
+
+    @staticmethod
+    def create(  # type: ignore
+        loop: asyncio.AbstractEventLoop,
+        name: str,
+        config: HyperliquidExecClientConfig,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+    ) -> HyperliquidExecutionClient:
+        """
+        Create a new Hyperliquid execution client.
+
+        Parameters
+        ----------
+        loop : asyncio.AbstractEventLoop
+            The event loop for the client.
+        name : str
+            The custom client ID.
+        config : HyperliquidExecClientConfig
+            The client configuration.
+        msgbus : MessageBus
+            The message bus for the client.
+        cache : Cache
+            The cache for the client.
+        clock : LiveClock
+            The clock for the client.
+
+        Returns
+        -------
+        HyperliquidExecutionClient
+
+        """
+        private_key = config.private_key or get_private_key(config.testnet)
+        client: HyperliquidHttpClient = get_cached_hyperliquid_http_client(
+            clock=clock,
+            private_key=private_key,
+            vault_address=config.vault_address,
+            base_url=config.base_url_http,
+            is_testnet=config.testnet,
+        )
+
+        # Signing with an API wallet requires the address of the trading account
+        wallet_address = config.wallet_address
+        if wallet_address is None:
+            try:
+                wallet_address = get_wallet_address(config.testnet)
+            except ValueError:
+                assert client.signer is not None  # Created with a private key
+                wallet_address = client.signer.address
+
+        provider = get_cached_hyperliquid_instrument_provider(
+            client=client,
+            clock=clock,
+            config=config.instrument_provider,
+        )
+        return HyperliquidExecutionClient(
+            loop=loop,
+            client=client,
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+            instrument_provider=provider,
+            wallet_address=wallet_address,
+            base_url_ws=config.base_url_ws or get_ws_base_url(config.testnet),
+            config=config,
+            name=name,
+        )
@@ -0,0 +1,186 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+from typing import Any
+
+import msgspec
+
+import nautilus_trader
+from nautilus_trader.adapters.hyperliquid.common.constants import HYPERLIQUID_EXCHANGE_RATE_LIMIT_KEY
+from nautilus_trader.adapters.hyperliquid.common.constants import HYPERLIQUID_INFO_RATE_LIMIT_KEY
+from nautilus_trader.adapters.hyperliquid.http.errors import HyperliquidError
+from nautilus_trader.common.component import LiveClock
+from nautilus_trader.common.component import Logger
+from nautilus_trader.core.nautilus_pyo3 import HttpClient
+from nautilus_trader.core.nautilus_pyo3 import HttpMethod
+from nautilus_trader.core.nautilus_pyo3 import HttpResponse
+from nautilus_trader.core.nautilus_pyo3 import Quota
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.adapters.hyperliquid.common.signing import HyperliquidSigner
+
+
+class HyperliquidHttpClient:
+    """
+    Provides a Hyperliquid asynchronous HTTP client.
+
+    All requests are JSON `POST` requests, to the `/info` endpoint for market and
+    account data, and to the `/exchange` endpoint for signed actions.
+
+    Parameters
+    ----------
+    clock : LiveClock
+        The clock for the client.
+    base_url : str
+        The base endpoint URL for the client.
+    signer : HyperliquidSigner, optional
+        The signer for exchange actions (required for the `/exchange` endpoint).
+    vault_address : str, optional
+        The vault or subaccount address to trade on behalf of.
+    ratelimiter_quotas : list[tuple[str, Quota]], optional
+        The keyed rate limiter quotas for the client.
+    ratelimiter_default_quota : Quota, optional
+        The default rate limiter quota for the client.
+
+    """
+
+    def __init__(
+        self,
+        clock: LiveClock,
+        base_url: str,
+        signer: HyperliquidSigner | None = None,
+        vault_address: str | None = None,
+        ratelimiter_quotas: list[tuple[str, Quota]] | None = None,
+        ratelimiter_default_quota: Quota | None = None,
+    ) -> None:
+        self._clock: LiveClock = clock
+        self._log: Logger = Logger(name=type(self).__name__)
+        self._signer = signer
+        self._vault_address = vault_address
+
+        self._base_url: str = base_url
+        self._headers: dict[str, Any] = {
+            "Content-Type": "application/json",
+            "User-Agent": nautilus_trader.USER_AGENT,
+        }
+        self._client = HttpClient(
+            keyed_quotas=ratelimiter_quotas or [],
+            default_quota=ratelimiter_default_quota,
+        )
+        self._last_nonce: int = 0
+
+    @property
+    def base_url(self) -> str:
+        return self._base_url
+
+    @property
+    def signer(self) -> HyperliquidSigner | None:
+        return self._signer
+
+    @property
+    def vault_address(self) -> str | None:
+        return self._vault_address
+
+    async def send_request(
+        self,
+        url_path: str,
+        payload: dict[str, Any],
+        ratelimiter_keys: list[str] | None = None,
+    ) -> bytes:
+        url = self._base_url + url_path
+        body = msgspec.json.encode(payload)
+
+        response: HttpResponse = await self._client.request(
+            HttpMethod.POST,
+            url,
+            self._headers,
+            body,
+            ratelimiter_keys,
+        )
+
+        response_body = response.body
+
+        if response.status >= 400:
+            try:
+                message = msgspec.json.decode(response_body) if response_body else None
+            except msgspec.DecodeError:
+                message = response_body.decode()
+
+            raise HyperliquidError(
+                code=response.status,
+                message=message,
+            )
+
+        return response_body
+
+    async def post_info(self, payload: dict[str, Any]) -> bytes:
+        return await self.send_request(
+            url_path="/info",
+            payload=payload,
+            ratelimiter_keys=[HYPERLIQUID_INFO_RATE_LIMIT_KEY],
+        )
+
+    async def post_exchange(self, action: dict[str, Any]) -> bytes:
+        return await self.post_signed(self.sign_action(action))
+
+    async def post_signed(self, payload: dict[str, Any]) -> bytes:
+        return await self.send_request(
+            url_path="/exchange",
+            payload=payload,
+            ratelimiter_keys=[HYPERLIQUID_EXCHANGE_RATE_LIMIT_KEY],
+        )
+
+    def sign_action(self, action: dict[str, Any]) -> dict[str, Any]:
+        """
+        Return the signed request payload for the given exchange action.
+
+        The payload can be sent to the `/exchange` endpoint, or as a WebSocket
+        `post` request.
+
+        Parameters
+        ----------
+        action : dict[str, Any]
+            The exchange action to sign.
+
+        Returns
+        -------
+        dict[str, Any]
+
+        Raises
+        ------
+        RuntimeError
+            If the client has no signer.
+
+        """
+        if self._signer is None:
+            raise RuntimeError("Cannot sign action: no signer for client (public access only)")
+
+        nonce = self._next_nonce()
+        return {
+            "action": action,
+            "nonce": nonce,
+            "signature": self._signer.sign_l1_action(action, nonce, self._vault_address),
+            "vaultAddress": self._vault_address,
+        }
+
+    def _next_nonce(self) -> int:
+        # Nonces are UNIX milliseconds, and must be unique per signer
+        nonce = max(self._clock.timestamp_ms(), self._last_nonce + 1)
+        self._last_nonce = nonce
+        return nonce
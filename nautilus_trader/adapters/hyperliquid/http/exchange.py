@@ -0,0 +1,182 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+from typing import Any
+
+import msgspec
+
+from nautilus_trader.adapters.hyperliquid.http.errors import HyperliquidError
+from nautilus_trader.adapters.hyperliquid.schemas.exchange import HyperliquidExchangeResponse
+from nautilus_trader.core.correctness import PyCondition
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.adapters.hyperliquid.common.enums import HyperliquidTimeInForce
+    from nautilus_trader.adapters.hyperliquid.common.enums import HyperliquidTpsl
+    from nautilus_trader.adapters.hyperliquid.http.client import HyperliquidHttpClient
+    from nautilus_trader.common.component import LiveClock
+
+
+################################################################################
+# Actions
+################################################################################
+
+# Actions are hashed from their msgpack encoding for signing, so the key order
+# of each dictionary must match the order expected by the venue.
+
+
+def order_wire(
+    asset: int,
+    is_buy: bool,
+    price: str,
+    size: str,
+    reduce_only: bool,
+    time_in_force: HyperliquidTimeInForce | None = None,
+    trigger_price: str | None = None,
+    is_market: bool = False,
+    tpsl: HyperliquidTpsl | None = None,
+    cloid: str | None = None,
+) -> dict[str, Any]:
+    """
+    Return the wire representation of an order.
+
+    Orders with a `trigger_price` are trigger orders, otherwise limit orders.
+    Prices and sizes must already be in the wire format (see `float_to_wire`).
+
+    """
+    order_type: dict[str, Any]
+    if trigger_price is not None:
+        PyCondition.not_none(tpsl, "tpsl")
+        assert tpsl is not None  # Type checking
+        order_type = {
+            "trigger": {
+                "isMarket": is_market,
+                "triggerPx": trigger_price,
+                "tpsl": tpsl.value,
+            },
+        }
+    else:
+        PyCondition.not_none(time_in_force, "time_in_force")
+        assert time_in_force is not None  # Type checking
+        order_type = {"limit": {"tif": time_in_force.value}}
+
+    wire: dict[str, Any] = {
+        "a": asset,
+        "b": is_buy,
+        "p": price,
+        "s": size,
+        "r": reduce_only,
+        "t": order_type,
+    }
+    if cloid is not None:
+        wire["c"] = cloid
+    return wire
+
+
+def order_action(orders: list[dict[str, Any]]) -> dict[str, Any]:
+    return {
+        "type": "order",
+        "orders": orders,
+        "grouping": "na",
+    }
+
+
+def cancel_action(cancels: list[tuple[int, int]]) -> dict[str, Any]:
+    # Cancels are (asset, oid) pairs
+    return {
+        "type": "cancel",
+        "cancels": [{"a": asset, "o": oid} for asset, oid in cancels],
+    }
+
+
+def cancel_by_cloid_action(cancels: list[tuple[int, str]]) -> dict[str, Any]:
+    # Cancels are (asset, cloid) pairs
+    return {
+        "type": "cancelByCloid",
+        "cancels": [{"asset": asset, "cloid": cloid} for asset, cloid in cancels],
+    }
+
+
+def modify_action(oid: int | str, order: dict[str, Any]) -> dict[str, Any]:
+    return {
+        "type": "batchModify",
+        "modifies": [{"oid": oid, "order": order}],
+    }
+
+
+################################################################################
+# API
+################################################################################
+
+
+class HyperliquidExchangeHttpAPI:
+    """
+    Provides access to the Hyperliquid `/exchange` REST endpoint.
+
+    Parameters
+    ----------
+    client : HyperliquidHttpClient
+        The Hyperliquid HTTP client (must have a signer).
+    clock : LiveClock
+        The clock for the API client.
+
+    """
+
+    def __init__(
+        self,
+        client: HyperliquidHttpClient,
+        clock: LiveClock,
+    ) -> None:
+        PyCondition.not_none(client, "client")
+        self.client = client
+        self._clock = clock
+
+        self._decoder_response = msgspec.json.Decoder(HyperliquidExchangeResponse)
+
+    def check_response(self, response: HyperliquidExchangeResponse) -> HyperliquidExchangeResponse:
+        # Actions succeed at the HTTP level, with the outcome in the response body
+        error = response.error
+        if error is not None:
+            raise HyperliquidError(code=None, message=error)
+        return response
+
+    async def send_action(self, action: dict[str, Any]) -> HyperliquidExchangeResponse:
+        raw = await self.client.post_exchange(action)
+        return self.check_response(self._decoder_response.decode(raw))
+
+    async def place_order(self, order: dict[str, Any]) -> HyperliquidExchangeResponse:
+        return await self.send_action(order_action([order]))
+
+    async def cancel_order(
+        self,
+        asset: int,
+        oid: int | None = None,
+        cloid: str | None = None,
+    ) -> HyperliquidExchangeResponse:
+        if oid is not None:
+            return await self.send_action(cancel_action([(asset, oid)]))
+        PyCondition.not_none(cloid, "cloid")
+        assert cloid is not None  # Type checking
+        return await self.send_action(cancel_by_cloid_action([(asset, cloid)]))
+
+    async def modify_order(
+        self,
+        oid: int | str,
+        order: dict[str, Any],
+    ) -> HyperliquidExchangeResponse:
+        return await self.send_action(modify_action(oid, order))
@@ -0,0 +1,112 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+from typing import Any
+
+import msgspec
+
+from nautilus_trader.adapters.hyperliquid.schemas.info import HyperliquidAssetCtx
+from nautilus_trader.adapters.hyperliquid.schemas.info import HyperliquidClearinghouseState
+from nautilus_trader.adapters.hyperliquid.schemas.info import HyperliquidFill
+from nautilus_trader.adapters.hyperliquid.schemas.info import HyperliquidMeta
+from nautilus_trader.adapters.hyperliquid.schemas.info import HyperliquidOpenOrder
+from nautilus_trader.adapters.hyperliquid.schemas.info import HyperliquidOrderStatusResponse
+from nautilus_trader.core.correctness import PyCondition
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.adapters.hyperliquid.http.client import HyperliquidHttpClient
+    from nautilus_trader.common.component import LiveClock
+
+
+class HyperliquidInfoHttpAPI:
+    """
+    Provides access to the Hyperliquid `/info` REST endpoint.
+
+    Parameters
+    ----------
+    client : HyperliquidHttpClient
+        The Hyperliquid HTTP client.
+    clock : LiveClock
+        The clock for the API client.
+
+    """
+
+    def __init__(
+        self,
+        client: HyperliquidHttpClient,
+        clock: LiveClock,
+    ) -> None:
+        PyCondition.not_none(client, "client")
+        self.client = client
+        self._clock = clock
+
+        self._decoder_meta = msgspec.json.Decoder(HyperliquidMeta)
+        self._decoder_meta_and_asset_ctxs = msgspec.json.Decoder(
+            tuple[HyperliquidMeta, list[HyperliquidAssetCtx]],
+        )
+        self._decoder_clearinghouse_state = msgspec.json.Decoder(HyperliquidClearinghouseState)
+        self._decoder_open_orders = msgspec.json.Decoder(list[HyperliquidOpenOrder])
+        self._decoder_fills = msgspec.json.Decoder(list[HyperliquidFill])
+        self._decoder_order_status = msgspec.json.Decoder(HyperliquidOrderStatusResponse)
+
+    async def _post(self, request_type: str, **params: Any) -> bytes:
+        return await self.client.post_info({"type": request_type, **params})
+
+    async def fetch_meta(self) -> HyperliquidMeta:
+        raw = await self._post("meta")
+        return self._decoder_meta.decode(raw)
+
+    async def fetch_meta_and_asset_ctxs(
+        self,
+    ) -> tuple[HyperliquidMeta, list[HyperliquidAssetCtx]]:
+        raw = await self._post("metaAndAssetCtxs")
+        return self._decoder_meta_and_asset_ctxs.decode(raw)
+
+    async def fetch_clearinghouse_state(self, user: str) -> HyperliquidClearinghouseState:
+        raw = await self._post("clearinghouseState", user=user)
+        return self._decoder_clearinghouse_state.decode(raw)
+
+    async def fetch_open_orders(self, user: str) -> list[HyperliquidOpenOrder]:
+        # The frontend variant includes trigger and TP/SL details
+        raw = await self._post("frontendOpenOrders", user=user)
+        return self._decoder_open_orders.decode(raw)
+
+    async def fetch_fills(
+        self,
+        user: str,
+        start_time: int | None = None,
+        end_time: int | None = None,
+    ) -> list[HyperliquidFill]:
+        if start_time is None:
+            raw = await self._post("userFills", user=user)
+        else:
+            params: dict[str, Any] = {"user": user, "startTime": start_time}
+            if end_time is not None:
+                params["endTime"] = end_time
+            raw = await self._post("userFillsByTime", **params)
+        return self._decoder_fills.decode(raw)
+
+    async def fetch_order_status(
+        self,
+        user: str,
+        oid: int | str,
+    ) -> HyperliquidOrderStatusResponse:
+        # The `oid` is either the venue order ID or the hex client order ID (cloid)
+        raw = await self._post("orderStatus", user=user, oid=oid)
+        return self._decoder_order_status.decode(raw)
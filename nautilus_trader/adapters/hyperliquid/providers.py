@@ -0,0 +1,154 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from decimal import Decimal
+from typing import TYPE_CHECKING
+
+from nautilus_trader.adapters.hyperliquid.common.constants import HYPERLIQUID_DEFAULT_MAKER_FEE
+from nautilus_trader.adapters.hyperliquid.common.constants import HYPERLIQUID_DEFAULT_TAKER_FEE
+from nautilus_trader.adapters.hyperliquid.common.constants import HYPERLIQUID_VENUE
+from nautilus_trader.adapters.hyperliquid.http.info import HyperliquidInfoHttpAPI
+from nautilus_trader.common.providers import InstrumentProvider
+from nautilus_trader.core.correctness import PyCondition
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.adapters.hyperliquid.http.client import HyperliquidHttpClient
+    from nautilus_trader.adapters.hyperliquid.schemas.info import HyperliquidAssetMeta
+    from nautilus_trader.common.component import LiveClock
+    from nautilus_trader.config import InstrumentProviderConfig
+    from nautilus_trader.model.identifiers import InstrumentId
+
+
+class HyperliquidInstrumentProvider(InstrumentProvider):
+    """
+    Provides Nautilus instrument definitions from Hyperliquid perpetuals metadata.
+
+    Parameters
+    ----------
+    client : HyperliquidHttpClient
+        The Hyperliquid HTTP client.
+    clock : LiveClock
+        The clock instance.
+    config : InstrumentProviderConfig, optional
+        The instrument provider configuration, by default None.
+
+    """
+
+    def __init__(
+        self,
+        client: HyperliquidHttpClient,
+        clock: LiveClock,
+        config: InstrumentProviderConfig | None = None,
+    ) -> None:
+        super().__init__(config=config)
+        self._clock = clock
+        self._http_info = HyperliquidInfoHttpAPI(client, clock)
+
+        # Exchange actions reference perpetuals by their index in the universe
+        self._asset_indices: dict[InstrumentId, int] = {}
+
+        self._log_warnings = config.log_warnings if config else True
+
+    async def load_all_async(self, filters: dict | None = None) -> None:
+        filters_str = "..." if not filters else f" with filters {filters}..."
+        self._log.info(f"Loading all instruments{filters_str}")
+
+        await self._load_instruments()
+
+        self._log.info(f"Loaded {len(self._instruments)} instruments")
+
+    async def load_ids_async(
+        self,
+        instrument_ids: list[InstrumentId],
+        filters: dict | None = None,
+    ) -> None:
+        if not instrument_ids:
+            self._log.warning("No instrument IDs given for loading")
+            return
+
+        # Check all instrument IDs
+        for instrument_id in instrument_ids:
+            PyCondition.equal(
+                instrument_id.venue,
+                HYPERLIQUID_VENUE,
+                "instrument_id.venue",
+                "HYPERLIQUID",
+            )
+
+        await self._load_instruments(set(instrument_ids))
+
+    async def load_async(self, instrument_id: InstrumentId, filters: dict | None = None) -> None:
+        PyCondition.not_none(instrument_id, "instrument_id")
+        await self.load_ids_async([instrument_id], filters)
+
+    def asset_index(self, instrument_id: InstrumentId) -> int | None:
+        """
+        Return the Hyperliquid asset index for the given instrument ID (if found).
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the asset.
+
+        Returns
+        -------
+        int or ``None``
+
+        """
+        return self._asset_indices.get(instrument_id)
+
+    async def _load_instruments(self, instrument_ids: set[InstrumentId] | None = None) -> None:
+        # The universe is requested in full, as the asset index is the position in the list
+        meta = await self._http_info.fetch_meta()
+        for asset_index, asset in enumerate(meta.universe):
+            self._parse_instrument(asset_index, asset, instrument_ids)
+
+    def _parse_instrument(
+        self,
+        asset_index: int,
+        asset: HyperliquidAssetMeta,
+        instrument_ids: set[InstrumentId] | None,
+    ) -> None:
+        if asset.isDelisted:
+            return
+
+        try:
+            base_currency = self.currency(asset.name)
+            quote_currency = self.currency("USDC")
+            self.add_currency(base_currency)
+            self.add_currency(quote_currency)
+            ts_event = self._clock.timestamp_ns()
+            ts_init = self._clock.timestamp_ns()
+            instrument = asset.parse_to_instrument(
+                asset_index=asset_index,
+                base_currency=base_currency,
+                quote_currency=quote_currency,
+                maker_fee=Decimal(HYPERLIQUID_DEFAULT_MAKER_FEE),
+                taker_fee=Decimal(HYPERLIQUID_DEFAULT_TAKER_FEE),
+                ts_event=ts_event,
+                ts_init=ts_init,
+            )
+        except ValueError as e:
+            if self._log_warnings:
+                self._log.warning(f"Unable to parse instrument {asset.name}: {e}")
+            return
+
+        self._asset_indices[instrument.id] = asset_index
+
+        if instrument_ids is None or instrument.id in instrument_ids:
+            self.add(instrument=instrument)
@@ -0,0 +1,82 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+import msgspec
+
+
+class HyperliquidRestingOrder(msgspec.Struct, frozen=True):
+    oid: int
+    cloid: str | None = None
+
+
+class HyperliquidFilledOrder(msgspec.Struct, frozen=True):
+    totalSz: str
+    avgPx: str
+    oid: int
+    cloid: str | None = None
+
+
+class HyperliquidOrderStatusEntry(msgspec.Struct, frozen=True):
+    resting: HyperliquidRestingOrder | None = None
+    filled: HyperliquidFilledOrder | None = None
+    error: str | None = None
+
+    @property
+    def oid(self) -> int | None:
+        if self.resting is not None:
+            return self.resting.oid
+        if self.filled is not None:
+            return self.filled.oid
+        return None
+
+
+class HyperliquidStatuses(msgspec.Struct, frozen=True):
+    # Successful cancels are reported as the string `success`
+    statuses: list[HyperliquidOrderStatusEntry | str]
+
+
+class HyperliquidExchangeResponseData(msgspec.Struct, frozen=True):
+    type: str
+    data: HyperliquidStatuses | None = None
+
+
+class HyperliquidExchangeResponse(msgspec.Struct, frozen=True):
+    status: str  # `ok` or `err`
+    response: HyperliquidExchangeResponseData | str | None = None
+
+    @property
+    def is_ok(self) -> bool:
+        return self.status == "ok"
+
+    @property
+    def error(self) -> str | None:
+        """
+        Return the error for the response, or the first error for a batch of orders.
+        """
+        if not self.is_ok:
+            return str(self.response)
+        if isinstance(self.response, HyperliquidExchangeResponseData) and self.response.data:
+            for entry in self.response.data.statuses:
+                if isinstance(entry, HyperliquidOrderStatusEntry) and entry.error:
+                    return entry.error
+        return None
+
+    @property
+    def statuses(self) -> list[HyperliquidOrderStatusEntry | str]:
+        if isinstance(self.response, HyperliquidExchangeResponseData) and self.response.data:
+            return self.response.data.statuses
+        return []
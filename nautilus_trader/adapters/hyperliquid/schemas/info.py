@@ -0,0 +1,355 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from decimal import Decimal
+
+import msgspec
+
+from nautilus_trader.adapters.hyperliquid.common.constants import HYPERLIQUID_MIN_NOTIONAL
+from nautilus_trader.adapters.hyperliquid.common.constants import HYPERLIQUID_PERP_MAX_DECIMALS
+from nautilus_trader.adapters.hyperliquid.common.enums import HyperliquidEnumParser
+from nautilus_trader.adapters.hyperliquid.common.enums import HyperliquidOrderSide
+from nautilus_trader.adapters.hyperliquid.common.enums import HyperliquidTimeInForce
+from nautilus_trader.adapters.hyperliquid.common.symbol import HyperliquidSymbol
+from nautilus_trader.core.datetime import millis_to_nanos
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.execution.reports import FillReport
+from nautilus_trader.execution.reports import OrderStatusReport
+from nautilus_trader.execution.reports import PositionStatusReport
+from nautilus_trader.model.enums import ContingencyType
+from nautilus_trader.model.enums import LiquiditySide
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.enums import PositionSide
+from nautilus_trader.model.enums import TimeInForce
+from nautilus_trader.model.enums import TriggerType
+from nautilus_trader.model.identifiers import AccountId
+from nautilus_trader.model.identifiers import ClientOrderId
+from nautilus_trader.model.identifiers import Symbol
+from nautilus_trader.model.identifiers import TradeId
+from nautilus_trader.model.identifiers import VenueOrderId
+from nautilus_trader.model.instruments import CryptoPerpetual
+from nautilus_trader.model.instruments import Instrument
+from nautilus_trader.model.objects import AccountBalance
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import MarginBalance
+from nautilus_trader.model.objects import Money
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+
+
+################################################################################
+# Market
+################################################################################
+
+
+class HyperliquidAssetMeta(msgspec.Struct, frozen=True):
+    name: str
+    szDecimals: int
+    maxLeverage: int
+    onlyIsolated: bool = False
+    isDelisted: bool = False
+
+    def parse_to_instrument(
+        self,
+        asset_index: int,
+        base_currency: Currency,
+        quote_currency: Currency,
+        maker_fee: Decimal,
+        taker_fee: Decimal,
+        ts_event: int,
+        ts_init: int,
+    ) -> CryptoPerpetual:
+        """
+        Parse the asset metadata into a Nautilus perpetual instrument.
+
+        Perpetual prices can have up to `6 - szDecimals` decimal places (and at most
+        five significant figures), and sizes up to `szDecimals` decimal places.
+        Perpetuals are margined and settled in USDC.
+
+        """
+        price_precision = max(HYPERLIQUID_PERP_MAX_DECIMALS - self.szDecimals, 0)
+        price_increment = Price.from_str(
+            format(Decimal(10) ** -price_precision, f".{price_precision}f"),
+        )
+        size_increment = Quantity.from_str(
+            format(Decimal(10) ** -self.szDecimals, f".{self.szDecimals}f"),
+        )
+
+        # Margin requirements are derived from the maximum leverage,
+        # where the maintenance margin is half the initial margin at max leverage
+        margin_init = Decimal(1) / Decimal(self.maxLeverage)
+        margin_maint = margin_init / 2
+
+        return CryptoPerpetual(
+            instrument_id=HyperliquidSymbol.from_coin(self.name).to_instrument_id(),
+            raw_symbol=Symbol(self.name),
+            base_currency=base_currency,
+            quote_currency=quote_currency,
+            settlement_currency=quote_currency,
+            is_inverse=False,
+            price_precision=price_precision,
+            size_precision=self.szDecimals,
+            price_increment=price_increment,
+            size_increment=size_increment,
+            max_quantity=None,
+            min_quantity=size_increment,
+            max_notional=None,
+            min_notional=Money(Decimal(HYPERLIQUID_MIN_NOTIONAL), quote_currency),
+            max_price=None,
+            min_price=None,
+            margin_init=margin_init,
+            margin_maint=margin_maint,
+            maker_fee=maker_fee,
+            taker_fee=taker_fee,
+            ts_event=ts_event,
+            ts_init=ts_init,
+            info={**msgspec.structs.asdict(self), "assetIndex": asset_index},
+        )
+
+
+class HyperliquidMeta(msgspec.Struct, frozen=True):
+    universe: list[HyperliquidAssetMeta]
+
+
+class HyperliquidAssetCtx(msgspec.Struct, frozen=True):
+    funding: str
+    openInterest: str
+    oraclePx: str
+    markPx: str | None = None
+    midPx: str | None = None
+    premium: str | None = None
+    prevDayPx: str | None = None
+    dayNtlVlm: str | None = None
+
+
+################################################################################
+# Account
+################################################################################
+
+
+class HyperliquidMarginSummary(msgspec.Struct, frozen=True):
+    accountValue: str
+    totalNtlPos: str
+    totalRawUsd: str
+    totalMarginUsed: str
+
+
+class HyperliquidLeverage(msgspec.Struct, frozen=True):
+    type: str
+    value: int
+
+
+class HyperliquidPosition(msgspec.Struct, frozen=True):
+    coin: str
+    szi: str
+    positionValue: str
+    unrealizedPnl: str
+    marginUsed: str
+    entryPx: str | None = None
+    leverage: HyperliquidLeverage | None = None
+    liquidationPx: str | None = None
+
+    def parse_to_position_status_report(
+        self,
+        account_id: AccountId,
+        instrument: Instrument,
+        report_id: UUID4,
+        ts_init: int,
+    ) -> PositionStatusReport:
+        # The signed size is negative for short positions
+        size = Decimal(self.szi)
+        if size > 0:
+            position_side = PositionSide.LONG
+        elif size < 0:
+            position_side = PositionSide.SHORT
+        else:
+            position_side = PositionSide.FLAT
+
+        return PositionStatusReport(
+            account_id=account_id,
+            instrument_id=instrument.id,
+            position_side=position_side,
+            quantity=instrument.make_qty(abs(size)),
+            report_id=report_id,
+            ts_init=ts_init,
+            ts_last=ts_init,
+        )
+
+
+class HyperliquidAssetPosition(msgspec.Struct, frozen=True):
+    position: HyperliquidPosition
+    type: str
+
+
+class HyperliquidClearinghouseState(msgspec.Struct, frozen=True):
+    marginSummary: HyperliquidMarginSummary
+    crossMarginSummary: HyperliquidMarginSummary
+    crossMaintenanceMarginUsed: str
+    withdrawable: str
+    assetPositions: list[HyperliquidAssetPosition]
+    time: int
+
+    def parse_to_account_balance(self, currency: Currency) -> AccountBalance:
+        # The withdrawable amount excludes margin used and unrealized PnL
+        total = Decimal(self.marginSummary.accountValue)
+        free = min(Decimal(self.withdrawable), total)
+        return AccountBalance(
+            total=Money(total, currency),
+            locked=Money(total - free, currency),
+            free=Money(free, currency),
+        )
+
+    def parse_to_margin_balance(self, currency: Currency) -> MarginBalance:
+        return MarginBalance(
+            initial=Money(Decimal(self.marginSummary.totalMarginUsed), currency),
+            maintenance=Money(Decimal(self.crossMaintenanceMarginUsed), currency),
+        )
+
+
+################################################################################
+# Trade
+################################################################################
+
+
+class HyperliquidOpenOrder(msgspec.Struct, frozen=True):
+    coin: str
+    side: HyperliquidOrderSide
+    limitPx: str
+    sz: str
+    oid: int
+    timestamp: int
+    origSz: str
+    cloid: str | None = None
+    orderType: str | None = None
+    tif: HyperliquidTimeInForce | None = None
+    reduceOnly: bool = False
+    isTrigger: bool = False
+    triggerPx: str | None = None
+
+    def parse_to_order_status_report(
+        self,
+        account_id: AccountId,
+        instrument: Instrument,
+        client_order_id: ClientOrderId | None,
+        report_id: UUID4,
+        enum_parser: HyperliquidEnumParser,
+        ts_init: int,
+        order_status: OrderStatus | None = None,
+        ts_last: int | None = None,
+    ) -> OrderStatusReport:
+        quantity = instrument.make_qty(self.origSz)
+        filled_qty = instrument.make_qty(Decimal(self.origSz) - Decimal(self.sz))
+
+        if order_status is None:
+            order_status = (
+                OrderStatus.PARTIALLY_FILLED if filled_qty > 0 else OrderStatus.ACCEPTED
+            )
+
+        order_type = enum_parser.parse_hyperliquid_order_type(self.orderType, self.isTrigger)
+        trigger_price = None
+        trigger_type = TriggerType.NO_TRIGGER
+        if self.isTrigger and self.triggerPx is not None:
+            trigger_price = instrument.make_price(self.triggerPx)
+            trigger_type = TriggerType.MARK_PRICE  # Triggers are based on the mark price
+
+        time_in_force = TimeInForce.GTC
+        if self.tif is not None:
+            time_in_force = enum_parser.parse_hyperliquid_time_in_force(self.tif)
+
+        return OrderStatusReport(
+            account_id=account_id,
+            instrument_id=instrument.id,
+            client_order_id=client_order_id,
+            order_list_id=None,
+            venue_order_id=VenueOrderId(str(self.oid)),
+            order_side=enum_parser.parse_hyperliquid_order_side(self.side),
+            order_type=order_type,
+            contingency_type=ContingencyType.NO_CONTINGENCY,
+            time_in_force=time_in_force,
+            order_status=order_status,
+            price=instrument.make_price(self.limitPx),
+            trigger_price=trigger_price,
+            trigger_type=trigger_type,
+            quantity=quantity,
+            filled_qty=filled_qty,
+            avg_px=None,
+            post_only=self.tif == HyperliquidTimeInForce.ALO,
+            reduce_only=self.reduceOnly,
+            ts_accepted=millis_to_nanos(self.timestamp),
+            ts_last=ts_last if ts_last is not None else millis_to_nanos(self.timestamp),
+            report_id=report_id,
+            ts_init=ts_init,
+        )
+
+
+class HyperliquidOrderInfo(msgspec.Struct, frozen=True):
+    order: HyperliquidOpenOrder
+    status: str
+    statusTimestamp: int
+
+
+class HyperliquidOrderStatusResponse(msgspec.Struct, frozen=True):
+    status: str  # `order` if found, otherwise `unknownOid`
+    order: HyperliquidOrderInfo | None = None
+
+
+class HyperliquidFill(msgspec.Struct, frozen=True):
+    coin: str
+    px: str
+    sz: str
+    side: HyperliquidOrderSide
+    time: int
+    oid: int
+    tid: int
+    crossed: bool
+    fee: str
+    feeToken: str
+    hash: str | None = None
+    dir: str | None = None
+    closedPnl: str | None = None
+    startPosition: str | None = None
+    cloid: str | None = None
+
+    @property
+    def liquidity_side(self) -> LiquiditySide:
+        # Fills which crossed the spread took liquidity
+        return LiquiditySide.TAKER if self.crossed else LiquiditySide.MAKER
+
+    def parse_to_fill_report(
+        self,
+        account_id: AccountId,
+        instrument: Instrument,
+        client_order_id: ClientOrderId | None,
+        report_id: UUID4,
+        enum_parser: HyperliquidEnumParser,
+        ts_init: int,
+    ) -> FillReport:
+        return FillReport(
+            client_order_id=client_order_id,
+            venue_order_id=VenueOrderId(str(self.oid)),
+            trade_id=TradeId(str(self.tid)),
+            account_id=account_id,
+            instrument_id=instrument.id,
+            order_side=enum_parser.parse_hyperliquid_order_side(self.side),
+            last_qty=instrument.make_qty(self.sz),
+            last_px=instrument.make_price(self.px),
+            commission=Money(Decimal(self.fee), Currency.from_str(self.feeToken)),
+            liquidity_side=self.liquidity_side,
+            report_id=report_id,
+            ts_event=millis_to_nanos(self.time),
+            ts_init=ts_init,
+        )
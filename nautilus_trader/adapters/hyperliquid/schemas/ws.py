@@ -0,0 +1,260 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from decimal import Decimal
+
+import msgspec
+
+from nautilus_trader.adapters.hyperliquid.common.constants import HYPERLIQUID_FUNDING_INTERVAL_SECS
+from nautilus_trader.adapters.hyperliquid.common.enums import HyperliquidOrderSide
+from nautilus_trader.adapters.hyperliquid.schemas.exchange import HyperliquidExchangeResponse
+from nautilus_trader.adapters.hyperliquid.schemas.info import HyperliquidAssetCtx
+from nautilus_trader.adapters.hyperliquid.schemas.info import HyperliquidFill
+from nautilus_trader.adapters.hyperliquid.schemas.info import HyperliquidOrderInfo
+from nautilus_trader.adapters.hyperliquid.types import HyperliquidFundingRateUpdate
+from nautilus_trader.core.datetime import millis_to_nanos
+from nautilus_trader.model.data import BookOrder
+from nautilus_trader.model.data import OrderBookDelta
+from nautilus_trader.model.data import OrderBookDeltas
+from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.model.data import TradeTick
+from nautilus_trader.model.enums import AggressorSide
+from nautilus_trader.model.enums import BookAction
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import RecordFlag
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import TradeId
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+
+
+class HyperliquidWsMessageGeneral(msgspec.Struct):
+    channel: str
+
+
+################################################################################
+# Market data
+################################################################################
+
+
+class HyperliquidWsLevel(msgspec.Struct):
+    px: str
+    sz: str
+    n: int
+
+
+class HyperliquidWsBook(msgspec.Struct):
+    coin: str
+    time: int
+    levels: tuple[list[HyperliquidWsLevel], list[HyperliquidWsLevel]]
+
+    def parse_to_deltas(
+        self,
+        instrument_id: InstrumentId,
+        price_precision: int,
+        size_precision: int,
+        ts_init: int,
+    ) -> OrderBookDeltas:
+        # Every book message is a full snapshot of the top levels
+        ts_event = millis_to_nanos(self.time)
+        bids, asks = self.levels
+
+        levels = [(OrderSide.BUY, level) for level in bids]
+        levels += [(OrderSide.SELL, level) for level in asks]
+        levels_len = len(levels)
+
+        deltas: list[OrderBookDelta] = [
+            OrderBookDelta.clear(instrument_id, 0, ts_event, ts_init),
+        ]
+        for idx, (side, level) in enumerate(levels):
+            flags = 0
+            if idx == levels_len - 1:
+                # F_LAST, 1 << 7
+                # Last message in the book event or packet from the venue for a given `instrument_id`
+                flags = RecordFlag.F_LAST
+
+            deltas.append(
+                OrderBookDelta(
+                    instrument_id=instrument_id,
+                    action=BookAction.ADD,
+                    order=BookOrder(
+                        side=side,
+                        price=Price(float(level.px), price_precision),
+                        size=Quantity(float(level.sz), size_precision),
+                        order_id=0,
+                    ),
+                    flags=flags,
+                    sequence=0,
+                    ts_event=ts_event,
+                    ts_init=ts_init,
+                ),
+            )
+
+        return OrderBookDeltas(instrument_id=instrument_id, deltas=deltas)
+
+
+class HyperliquidWsBookMsg(msgspec.Struct):
+    channel: str
+    data: HyperliquidWsBook
+
+
+class HyperliquidWsBbo(msgspec.Struct):
+    coin: str
+    time: int
+    bbo: tuple[HyperliquidWsLevel | None, HyperliquidWsLevel | None]
+
+    def parse_to_quote_tick(
+        self,
+        instrument_id: InstrumentId,
+        price_precision: int,
+        size_precision: int,
+        ts_init: int,
+    ) -> QuoteTick | None:
+        bid, ask = self.bbo
+        if bid is None or ask is None:
+            return None  # One side of the book is empty
+
+        return QuoteTick(
+            instrument_id=instrument_id,
+            bid_price=Price(float(bid.px), price_precision),
+            ask_price=Price(float(ask.px), price_precision),
+            bid_size=Quantity(float(bid.sz), size_precision),
+            ask_size=Quantity(float(ask.sz), size_precision),
+            ts_event=millis_to_nanos(self.time),
+            ts_init=ts_init,
+        )
+
+
+class HyperliquidWsBboMsg(msgspec.Struct):
+    channel: str
+    data: HyperliquidWsBbo
+
+
+class HyperliquidWsTrade(msgspec.Struct):
+    coin: str
+    side: HyperliquidOrderSide
+    px: str
+    sz: str
+    time: int
+    tid: int
+    hash: str | None = None
+
+    def parse_to_trade_tick(
+        self,
+        instrument_id: InstrumentId,
+        price_precision: int,
+        size_precision: int,
+        ts_init: int,
+    ) -> TradeTick:
+        # The side is the side of the aggressing (taker) order
+        return TradeTick(
+            instrument_id=instrument_id,
+            price=Price(float(self.px), price_precision),
+            size=Quantity(float(self.sz), size_precision),
+            aggressor_side=(
+                AggressorSide.BUYER
+                if self.side == HyperliquidOrderSide.BUY
+                else AggressorSide.SELLER
+            ),
+            trade_id=TradeId(str(self.tid)),
+            ts_event=millis_to_nanos(self.time),
+            ts_init=ts_init,
+        )
+
+
+class HyperliquidWsTradesMsg(msgspec.Struct):
+    channel: str
+    data: list[HyperliquidWsTrade]
+
+
+class HyperliquidWsActiveAssetCtx(msgspec.Struct):
+    coin: str
+    ctx: HyperliquidAssetCtx
+
+    def parse_to_funding_rate_update(
+        self,
+        instrument_id: InstrumentId,
+        ts_event: int,
+        ts_init: int,
+    ) -> HyperliquidFundingRateUpdate:
+        # Funding is paid at the start of every hour
+        interval_ns = HYPERLIQUID_FUNDING_INTERVAL_SECS * 1_000_000_000
+        ts_next_funding = (ts_event // interval_ns + 1) * interval_ns
+
+        return HyperliquidFundingRateUpdate(
+            instrument_id=instrument_id,
+            funding_rate=Decimal(self.ctx.funding),
+            ts_next_funding=ts_next_funding,
+            ts_event=ts_event,
+            ts_init=ts_init,
+        )
+
+
+class HyperliquidWsActiveAssetCtxMsg(msgspec.Struct):
+    channel: str
+    data: HyperliquidWsActiveAssetCtx
+
+
+################################################################################
+# User
+################################################################################
+
+
+class HyperliquidWsOrderUpdatesMsg(msgspec.Struct):
+    channel: str
+    data: list[HyperliquidOrderInfo]
+
+
+class HyperliquidWsUserFills(msgspec.Struct):
+    user: str
+    fills: list[HyperliquidFill]
+    isSnapshot: bool = False
+
+
+class HyperliquidWsUserFillsMsg(msgspec.Struct):
+    channel: str
+    data: HyperliquidWsUserFills
+
+
+################################################################################
+# Post requests
+################################################################################
+
+
+class HyperliquidWsPostResponse(msgspec.Struct):
+    type: str  # `action`, `info` or `error`
+    payload: msgspec.Raw
+
+
+class HyperliquidWsPost(msgspec.Struct):
+    id: int
+    response: HyperliquidWsPostResponse
+
+
+class HyperliquidWsPostMsg(msgspec.Struct):
+    channel: str
+    data: HyperliquidWsPost
+
+    def parse_to_exchange_response(self) -> HyperliquidExchangeResponse:
+        response = self.data.response
+        if response.type == "error":
+            # Errors are reported as a string payload
+            return HyperliquidExchangeResponse(
+                status="err",
+                response=msgspec.json.decode(response.payload),
+            )
+        return msgspec.json.decode(response.payload, type=HyperliquidExchangeResponse)
@@ -0,0 +1,332 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+import asyncio
+import itertools
+from typing import TYPE_CHECKING
+from typing import Any
+
+from msgspec import json as msgspec_json
+
+from nautilus_trader.adapters.hyperliquid.http.errors import HyperliquidError
+from nautilus_trader.adapters.hyperliquid.schemas.exchange import HyperliquidExchangeResponse
+from nautilus_trader.adapters.hyperliquid.schemas.ws import HyperliquidWsMessageGeneral
+from nautilus_trader.adapters.hyperliquid.schemas.ws import HyperliquidWsPostMsg
+from nautilus_trader.common.component import LiveClock
+from nautilus_trader.common.component import Logger
+from nautilus_trader.common.enums import LogColor
+from nautilus_trader.core.nautilus_pyo3 import WebSocketClient
+from nautilus_trader.core.nautilus_pyo3 import WebSocketClientError
+from nautilus_trader.core.nautilus_pyo3 import WebSocketConfig
+
+
+if TYPE_CHECKING:
+    from collections.abc import Awaitable
+    from collections.abc import Callable
+
+
+WsPostResponseFuture = asyncio.Future[HyperliquidExchangeResponse]
+
+
+class HyperliquidWebSocketClient:
+    """
+    Provides a Hyperliquid streaming WebSocket client.
+
+    Public and user channels share a single connection. User channels are keyed
+    by the user address and require no authentication, while signed exchange
+    actions can be sent as `post` requests.
+
+    Parameters
+    ----------
+    clock : LiveClock
+        The clock instance.
+    base_url : str
+        The base URL for the WebSocket connection.
+    handler : Callable[[bytes], None]
+        The callback handler for message events.
+    handler_reconnect : Callable[..., Awaitable[None]], optional
+        The callback handler to be called on reconnect.
+    loop : asyncio.AbstractEventLoop
+        The event loop for the client.
+    ws_post_timeout_secs : float, default 5.0
+        The timeout for `post` request responses.
+
+    """
+
+    def __init__(
+        self,
+        clock: LiveClock,
+        base_url: str,
+        handler: Callable[[bytes], None],
+        handler_reconnect: Callable[..., Awaitable[None]] | None,
+        loop: asyncio.AbstractEventLoop,
+        ws_post_timeout_secs: float | None = 5.0,
+    ) -> None:
+        self._clock = clock
+        self._log: Logger = Logger(name=type(self).__name__)
+
+        self._base_url: str = base_url
+        self._handler: Callable[[bytes], None] = handler
+        self._handler_reconnect: Callable[..., Awaitable[None]] | None = handler_reconnect
+        self._loop = loop
+        self._ws_post_timeout_secs = ws_post_timeout_secs
+
+        self._client: WebSocketClient | None = None
+        self._is_running = False
+        self._reconnecting = False
+
+        # Subscription key -> subscription (replayed on reconnect)
+        self._subscriptions: dict[str, dict[str, Any]] = {}
+
+        self._post_ids = itertools.count(1)
+        self._pending_post_requests: dict[int, WsPostResponseFuture] = {}
+
+        self._decoder_ws_message_general = msgspec_json.Decoder(HyperliquidWsMessageGeneral)
+        self._decoder_ws_post = msgspec_json.Decoder(HyperliquidWsPostMsg)
+
+    @property
+    def subscriptions(self) -> list[str]:
+        return list(self._subscriptions)
+
+    def has_subscription(self, item: str) -> bool:
+        return item in self._subscriptions
+
+    async def connect(self) -> None:
+        self._is_running = True
+        self._log.debug(f"Connecting to {self._base_url} websocket stream")
+
+        # The server closes connections without a message in the last 60 seconds
+        config = WebSocketConfig(
+            url=self._base_url,
+            handler=self._msg_handler,
+            heartbeat=30,
+            heartbeat_msg=msgspec_json.encode({"method": "ping"}).decode(),
+            headers=[],
+        )
+        client = await WebSocketClient.connect(
+            config=config,
+            post_reconnection=self.reconnect,
+        )
+        self._client = client
+        self._log.info(f"Connected to {self._base_url}", LogColor.BLUE)
+
+    def reconnect(self) -> None:
+        """
+        Reconnect the client to the server and resubscribe to all streams.
+        """
+        if not self._is_running or self._reconnecting:
+            return
+
+        self._log.warning(f"Trying to reconnect to {self._base_url}")
+        self._reconnecting = True
+        self._loop.create_task(self._reconnect_wrapper())
+
+    async def _reconnect_wrapper(self) -> None:
+        try:
+            self._log.warning(f"Resubscribing to {len(self._subscriptions)} streams")
+
+            await self._subscribe_all()
+
+            if self._handler_reconnect:
+                await self._handler_reconnect()
+
+            self._log.warning(f"Reconnected to {self._base_url}")
+        except Exception as e:
+            self._log.error(f"Reconnection failed: {e}")
+        finally:
+            self._reconnecting = False
+
+    async def disconnect(self) -> None:
+        self._is_running = False
+        self._reconnecting = False
+
+        if self._client is None:
+            self._log.warning("Cannot disconnect: not connected.")
+            return
+
+        try:
+            await self._client.disconnect()
+        except WebSocketClientError as e:
+            self._log.error(str(e))
+
+        self._client = None  # Dispose (will go out of scope)
+
+        for future in self._pending_post_requests.values():
+            future.cancel()
+        self._pending_post_requests.clear()
+
+        self._log.info(f"Disconnected from {self._base_url}", LogColor.BLUE)
+
+    def _msg_handler(self, raw: bytes) -> None:
+        """
+        Handle pushed websocket messages.
+
+        Parameters
+        ----------
+        raw : bytes
+            The received message in bytes.
+
+        """
+        msg = self._decoder_ws_message_general.decode(raw)
+        match msg.channel:
+            case "post":
+                self._handle_post_response(raw)
+            case "pong" | "subscriptionResponse":
+                return
+            case "error":
+                self._log.error(f"Received error: {raw.decode()}")
+            case _:
+                self._handler(raw)
+
+    ################################################################################
+    # Subscriptions
+    ################################################################################
+
+    async def _subscribe(self, key: str, subscription: dict[str, Any]) -> None:
+        self._log.debug(f"Subscribing to {key}")
+        if key in self._subscriptions:
+            self._log.warning(f"Cannot subscribe '{key}': already subscribed")
+            return
+
+        self._subscriptions[key] = subscription
+        await self._send({"method": "subscribe", "subscription": subscription})
+
+    async def _unsubscribe(self, key: str) -> None:
+        subscription = self._subscriptions.pop(key, None)
+        if subscription is None:
+            self._log.warning(f"Cannot unsubscribe '{key}': not subscribed")
+            return
+
+        await self._send({"method": "unsubscribe", "subscription": subscription})
+
+    async def _subscribe_all(self) -> None:
+        if self._client is None:
+            self._log.error("Cannot subscribe all: not connected")
+            return
+
+        for subscription in self._subscriptions.values():
+            await self._send({"method": "subscribe", "subscription": subscription})
+
+    async def _send(self, msg: dict[str, Any]) -> None:
+        if self._client is None:
+            self._log.error(f"Cannot send message {msg}: not connected")
+            return
+
+        encoded = msgspec_json.encode(msg)
+        self._log.debug(f"SENDING: {encoded!r}")
+
+        try:
+            await self._client.send_text(encoded)
+        except WebSocketClientError as e:
+            self._log.error(str(e))
+
+    ################################################################################
+    # Public
+    ################################################################################
+
+    async def subscribe_order_book(self, coin: str) -> None:
+        await self._subscribe(f"l2Book.{coin}", {"type": "l2Book", "coin": coin})
+
+    async def subscribe_bbo(self, coin: str) -> None:
+        await self._subscribe(f"bbo.{coin}", {"type": "bbo", "coin": coin})
+
+    async def subscribe_trades(self, coin: str) -> None:
+        await self._subscribe(f"trades.{coin}", {"type": "trades", "coin": coin})
+
+    async def subscribe_asset_ctx(self, coin: str) -> None:
+        await self._subscribe(f"activeAssetCtx.{coin}", {"type": "activeAssetCtx", "coin": coin})
+
+    async def unsubscribe_order_book(self, coin: str) -> None:
+        await self._unsubscribe(f"l2Book.{coin}")
+
+    async def unsubscribe_bbo(self, coin: str) -> None:
+        await self._unsubscribe(f"bbo.{coin}")
+
+    async def unsubscribe_trades(self, coin: str) -> None:
+        await self._unsubscribe(f"trades.{coin}")
+
+    async def unsubscribe_asset_ctx(self, coin: str) -> None:
+        await self._unsubscribe(f"activeAssetCtx.{coin}")
+
+    ################################################################################
+    # User
+    ################################################################################
+
+    async def subscribe_order_updates(self, user: str) -> None:
+        await self._subscribe("orderUpdates", {"type": "orderUpdates", "user": user})
+
+    async def subscribe_user_fills(self, user: str) -> None:
+        await self._subscribe("userFills", {"type": "userFills", "user": user})
+
+    ################################################################################
+    # Post requests
+    ################################################################################
+
+    def _handle_post_response(self, raw: bytes) -> None:
+        try:
+            msg = self._decoder_ws_post.decode(raw)
+            response = msg.parse_to_exchange_response()
+        except Exception as e:
+            self._log.error(f"Failed to decode post response {raw!r}: {e}")
+            return
+
+        future = self._pending_post_requests.pop(msg.data.id, None)
+        if future is None:
+            self._log.warning(f"Received response for `unknown/timeout` id={msg.data.id}")
+            return
+
+        future.set_result(response)
+
+    async def post_action(self, payload: dict[str, Any]) -> HyperliquidExchangeResponse:
+        """
+        Send the signed exchange action `payload` and return the response.
+
+        Parameters
+        ----------
+        payload : dict[str, Any]
+            The signed action payload (see `HyperliquidHttpClient.sign_action`).
+
+        Returns
+        -------
+        HyperliquidExchangeResponse
+
+        Raises
+        ------
+        HyperliquidError
+            If the request times out.
+
+        """
+        post_id = next(self._post_ids)
+
+        future: WsPostResponseFuture = self._loop.create_future()
+        self._pending_post_requests[post_id] = future
+
+        await self._send(
+            {
+                "method": "post",
+                "id": post_id,
+                "request": {"type": "action", "payload": payload},
+            },
+        )
+
+        try:
+            return await asyncio.wait_for(future, self._ws_post_timeout_secs)
+        except TimeoutError as e:
+            self._log.error(f"Post request `{post_id}` timed out")
+            future.cancel()
+            self._pending_post_requests.pop(post_id, None)
+            raise HyperliquidError(code=None, message="Request timed out") from e
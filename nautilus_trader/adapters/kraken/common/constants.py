@@ -0,0 +1,100 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from typing import Final
+
+from nautilus_trader.adapters.kraken.common.enums import KrakenProductType
+from nautilus_trader.model.identifiers import Venue
+
+
+KRAKEN: Final[str] = "KRAKEN"
+KRAKEN_VENUE: Final[Venue] = Venue(KRAKEN)
+
+KRAKEN_ALL_PRODUCTS: Final[list[KrakenProductType]] = [
+    KrakenProductType.SPOT,
+    KrakenProductType.FUTURES,
+]
+
+# Set of Kraken errors for which Nautilus will attempt retries,
+# potentially temporary conditions where a retry might make sense.
+# Kraken Spot reports errors as `<severity><category>:<message>` strings,
+# and Kraken Futures as camel case error identifiers.
+KRAKEN_RETRY_ERRORS: Final[set[int | str]] = {
+    # > ------------------------------------------------------------
+    # > HTTP status codes
+    429,  # Too many requests
+    502,  # Bad gateway
+    503,  # Service unavailable
+    504,  # Gateway timeout
+    # > ------------------------------------------------------------
+    # > Kraken Spot
+    # > https://docs.kraken.com/api/docs/guides/spot-errors
+    "EAPI:Rate limit exceeded",
+    "EAPI:Invalid nonce",
+    "EGeneral:Temporary lockout",
+    "EOrder:Rate limit exceeded",
+    "EService:Unavailable",
+    "EService:Busy",
+    "EService:Timeout",
+    "EService:Market in cancel_only mode",
+    "EService:Market in post_only mode",
+    # > ------------------------------------------------------------
+    # > Kraken Futures
+    # > https://docs.kraken.com/api/docs/guides/futures-rest
+    "apiLimitExceeded",
+    "nonceBelowThreshold",
+    "nonceDuplicate",
+    "Server Error",
+    "Unavailable",
+}
+
+KRAKEN_SPOT_DEPTHS: Final[tuple[int, ...]] = (10, 25, 100, 500, 1000)
+
+# Kraken Futures fee schedules are account specific, these are the base tier fees
+KRAKEN_FUTURES_DEFAULT_MAKER_FEE: Final[str] = "0.0002"
+KRAKEN_FUTURES_DEFAULT_TAKER_FEE: Final[str] = "0.0005"
+
+# Kraken Spot accepts client order IDs as a UUID, or as free text up to this length
+KRAKEN_SPOT_MAX_CLIENT_ORDER_ID_LEN: Final[int] = 18
+
+# Legacy Kraken Spot asset codes mapped to their common codes
+KRAKEN_ASSET_ALIASES: Final[dict[str, str]] = {
+    "XBT": "BTC",
+    "XXBT": "BTC",
+    "XDG": "DOGE",
+    "XXDG": "DOGE",
+    "XETH": "ETH",
+    "XETC": "ETC",
+    "XLTC": "LTC",
+    "XMLN": "MLN",
+    "XREP": "REP",
+    "XXLM": "XLM",
+    "XXMR": "XMR",
+    "XXRP": "XRP",
+    "XZEC": "ZEC",
+    "ZAUD": "AUD",
+    "ZCAD": "CAD",
+    "ZCHF": "CHF",
+    "ZEUR": "EUR",
+    "ZGBP": "GBP",
+    "ZJPY": "JPY",
+    "ZUSD": "USD",
+}
+
+# Rate limiter keys for the HTTP client quotas
+KRAKEN_SPOT_PUBLIC_RATE_LIMIT_KEY: Final[str] = "kraken:spot:public"
+KRAKEN_SPOT_PRIVATE_RATE_LIMIT_KEY: Final[str] = "kraken:spot:private"
+KRAKEN_FUTURES_PUBLIC_RATE_LIMIT_KEY: Final[str] = "kraken:futures:public"
+KRAKEN_FUTURES_PRIVATE_RATE_LIMIT_KEY: Final[str] = "kraken:futures:private"
@@ -0,0 +1,44 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from nautilus_trader.adapters.env import get_env_key
+from nautilus_trader.adapters.kraken.common.enums import KrakenProductType
+
+
+def _get_env_var_name(product_type: KrakenProductType, is_demo: bool, suffix: str) -> str:
+    if product_type == KrakenProductType.SPOT:
+        if is_demo:
+            raise ValueError("Invalid configuration: Kraken Spot has no demo environment")
+        return f"KRAKEN_API_{suffix}"
+    elif is_demo:
+        return f"KRAKEN_FUTURES_DEMO_API_{suffix}"
+    else:
+        return f"KRAKEN_FUTURES_API_{suffix}"
+
+
+def get_api_key(product_type: KrakenProductType, is_demo: bool) -> str:
+    name = _get_env_var_name(product_type, is_demo, "KEY")
+    key = get_env_key(name)
+    if not key:
+        raise ValueError(f"{name} environment variable not set")
+    return key
+
+
+def get_api_secret(product_type: KrakenProductType, is_demo: bool) -> str:
+    name = _get_env_var_name(product_type, is_demo, "SECRET")
+    secret = get_env_key(name)
+    if not secret:
+        raise ValueError(f"{name} environment variable not set")
+    return secret
@@ -0,0 +1,310 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from enum import Enum
+from enum import unique
+
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import TimeInForce
+from nautilus_trader.model.enums import TriggerType
+from nautilus_trader.model.enums import order_type_to_str
+from nautilus_trader.model.enums import time_in_force_to_str
+from nautilus_trader.model.enums import trigger_type_to_str
+
+
+@unique
+class KrakenProductType(Enum):
+    SPOT = "spot"
+    FUTURES = "futures"
+
+
+@unique
+class KrakenOrderSide(Enum):
+    BUY = "buy"
+    SELL = "sell"
+
+
+@unique
+class KrakenSpotOrderType(Enum):
+    MARKET = "market"
+    LIMIT = "limit"
+    STOP_LOSS = "stop-loss"
+    STOP_LOSS_LIMIT = "stop-loss-limit"
+    TAKE_PROFIT = "take-profit"
+    TAKE_PROFIT_LIMIT = "take-profit-limit"
+    TRAILING_STOP = "trailing-stop"
+    TRAILING_STOP_LIMIT = "trailing-stop-limit"
+    ICEBERG = "iceberg"
+    SETTLE_POSITION = "settle-position"
+
+
+@unique
+class KrakenSpotOrderStatus(Enum):
+    # WebSocket v2 `executions` channel
+    PENDING_NEW = "pending_new"
+    NEW = "new"
+    PARTIALLY_FILLED = "partially_filled"
+    FILLED = "filled"
+    CANCELED = "canceled"
+    EXPIRED = "expired"
+    # REST API
+    PENDING = "pending"
+    OPEN = "open"
+    CLOSED = "closed"
+
+
+@unique
+class KrakenSpotExecType(Enum):
+    PENDING_NEW = "pending_new"
+    NEW = "new"
+    TRADE = "trade"
+    FILLED = "filled"
+    ICEBERG_REFILL = "iceberg_refill"
+    CANCELED = "canceled"
+    EXPIRED = "expired"
+    AMENDED = "amended"
+    RESTATED = "restated"
+    STATUS = "status"
+
+
+@unique
+class KrakenSpotTimeInForce(Enum):
+    GTC = "gtc"
+    GTD = "gtd"
+    IOC = "ioc"
+
+
+@unique
+class KrakenSpotTriggerReference(Enum):
+    LAST = "last"
+    INDEX = "index"
+
+
+@unique
+class KrakenFuturesOrderType(Enum):
+    LIMIT = "lmt"
+    POST_ONLY = "post"
+    IOC = "ioc"
+    MARKET = "mkt"
+    STOP = "stp"
+    TAKE_PROFIT = "take_profit"
+
+
+@unique
+class KrakenFuturesOrderStatus(Enum):
+    UNTOUCHED = "untouched"
+    PARTIALLY_FILLED = "partiallyFilled"
+
+
+@unique
+class KrakenFuturesTriggerSignal(Enum):
+    MARK = "mark"
+    INDEX = "index"
+    LAST = "last"
+
+
+@unique
+class KrakenFuturesInstrumentType(Enum):
+    FLEXIBLE_FUTURES = "flexible_futures"
+    FUTURES_INVERSE = "futures_inverse"
+    FUTURES_VANILLA = "futures_vanilla"
+    SPOT_INDEX = "spot index"
+    TURBO_PERPETUAL = "turbo_perpetual"
+
+
+def check_dict_keys(key, data):
+    try:
+        return data[key]
+    except KeyError as e:
+        raise RuntimeError(
+            f"Unrecognized Kraken {key} not found in {data}",
+        ) from e
+
+
+class KrakenEnumParser:
+    def __init__(self) -> None:
+        self.kraken_to_nautilus_order_side = {
+            KrakenOrderSide.BUY: OrderSide.BUY,
+            KrakenOrderSide.SELL: OrderSide.SELL,
+        }
+        self.nautilus_to_kraken_order_side = {
+            b: a for a, b in self.kraken_to_nautilus_order_side.items()
+        }
+
+        # Spot
+        self.kraken_spot_to_nautilus_order_type = {
+            KrakenSpotOrderType.MARKET: OrderType.MARKET,
+            KrakenSpotOrderType.LIMIT: OrderType.LIMIT,
+            KrakenSpotOrderType.STOP_LOSS: OrderType.STOP_MARKET,
+            KrakenSpotOrderType.STOP_LOSS_LIMIT: OrderType.STOP_LIMIT,
+            KrakenSpotOrderType.TAKE_PROFIT: OrderType.MARKET_IF_TOUCHED,
+            KrakenSpotOrderType.TAKE_PROFIT_LIMIT: OrderType.LIMIT_IF_TOUCHED,
+            KrakenSpotOrderType.TRAILING_STOP: OrderType.TRAILING_STOP_MARKET,
+            KrakenSpotOrderType.TRAILING_STOP_LIMIT: OrderType.TRAILING_STOP_LIMIT,
+        }
+        self.nautilus_to_kraken_spot_order_type = {
+            b: a for a, b in self.kraken_spot_to_nautilus_order_type.items()
+        }
+        self.kraken_spot_to_nautilus_order_status = {
+            KrakenSpotOrderStatus.PENDING_NEW: OrderStatus.SUBMITTED,
+            KrakenSpotOrderStatus.NEW: OrderStatus.ACCEPTED,
+            KrakenSpotOrderStatus.PARTIALLY_FILLED: OrderStatus.PARTIALLY_FILLED,
+            KrakenSpotOrderStatus.FILLED: OrderStatus.FILLED,
+            KrakenSpotOrderStatus.CANCELED: OrderStatus.CANCELED,
+            KrakenSpotOrderStatus.EXPIRED: OrderStatus.EXPIRED,
+            KrakenSpotOrderStatus.PENDING: OrderStatus.SUBMITTED,
+            KrakenSpotOrderStatus.OPEN: OrderStatus.ACCEPTED,
+            KrakenSpotOrderStatus.CLOSED: OrderStatus.FILLED,
+        }
+        self.kraken_spot_to_nautilus_time_in_force = {
+            KrakenSpotTimeInForce.GTC: TimeInForce.GTC,
+            KrakenSpotTimeInForce.GTD: TimeInForce.GTD,
+            KrakenSpotTimeInForce.IOC: TimeInForce.IOC,
+        }
+        self.nautilus_to_kraken_spot_time_in_force = {
+            b: a for a, b in self.kraken_spot_to_nautilus_time_in_force.items()
+        }
+        self.nautilus_to_kraken_spot_trigger_reference = {
+            TriggerType.DEFAULT: KrakenSpotTriggerReference.LAST,
+            TriggerType.LAST_PRICE: KrakenSpotTriggerReference.LAST,
+            TriggerType.INDEX_PRICE: KrakenSpotTriggerReference.INDEX,
+        }
+
+        # Futures
+        self.kraken_futures_to_nautilus_order_status = {
+            KrakenFuturesOrderStatus.UNTOUCHED: OrderStatus.ACCEPTED,
+            KrakenFuturesOrderStatus.PARTIALLY_FILLED: OrderStatus.PARTIALLY_FILLED,
+        }
+        self.nautilus_to_kraken_futures_trigger_signal = {
+            TriggerType.DEFAULT: KrakenFuturesTriggerSignal.LAST,
+            TriggerType.LAST_PRICE: KrakenFuturesTriggerSignal.LAST,
+            TriggerType.MARK_PRICE: KrakenFuturesTriggerSignal.MARK,
+            TriggerType.INDEX_PRICE: KrakenFuturesTriggerSignal.INDEX,
+        }
+        self.kraken_futures_to_nautilus_trigger_type = {
+            KrakenFuturesTriggerSignal.LAST: TriggerType.LAST_PRICE,
+            KrakenFuturesTriggerSignal.MARK: TriggerType.MARK_PRICE,
+            KrakenFuturesTriggerSignal.INDEX: TriggerType.INDEX_PRICE,
+        }
+
+    def parse_kraken_order_side(self, order_side: KrakenOrderSide) -> OrderSide:
+        return check_dict_keys(order_side, self.kraken_to_nautilus_order_side)
+
+    def parse_nautilus_order_side(self, order_side: OrderSide) -> KrakenOrderSide:
+        return check_dict_keys(order_side, self.nautilus_to_kraken_order_side)
+
+    def parse_kraken_spot_order_type(self, order_type: KrakenSpotOrderType) -> OrderType:
+        return check_dict_keys(order_type, self.kraken_spot_to_nautilus_order_type)
+
+    def parse_nautilus_spot_order_type(self, order_type: OrderType) -> KrakenSpotOrderType:
+        try:
+            return self.nautilus_to_kraken_spot_order_type[order_type]
+        except KeyError as e:
+            raise RuntimeError(
+                f"unrecognized Kraken spot order type, was {order_type_to_str(order_type)}",  # pragma: no cover
+            ) from e
+
+    def parse_kraken_spot_order_status(self, order_status: KrakenSpotOrderStatus) -> OrderStatus:
+        return check_dict_keys(order_status, self.kraken_spot_to_nautilus_order_status)
+
+    def parse_kraken_spot_time_in_force(self, time_in_force: KrakenSpotTimeInForce) -> TimeInForce:
+        return check_dict_keys(time_in_force, self.kraken_spot_to_nautilus_time_in_force)
+
+    def parse_nautilus_spot_time_in_force(self, time_in_force: TimeInForce) -> KrakenSpotTimeInForce:
+        try:
+            return self.nautilus_to_kraken_spot_time_in_force[time_in_force]
+        except KeyError as e:
+            raise RuntimeError(
+                f"unrecognized Kraken spot time in force, was {time_in_force_to_str(time_in_force)}",  # pragma: no cover
+            ) from e
+
+    def parse_nautilus_spot_trigger_reference(
+        self,
+        trigger_type: TriggerType,
+    ) -> KrakenSpotTriggerReference:
+        try:
+            return self.nautilus_to_kraken_spot_trigger_reference[trigger_type]
+        except KeyError as e:
+            raise RuntimeError(
+                f"unrecognized Kraken spot trigger reference, was {trigger_type_to_str(trigger_type)}",  # pragma: no cover
+            ) from e
+
+    def parse_kraken_futures_order_status(
+        self,
+        order_status: KrakenFuturesOrderStatus,
+    ) -> OrderStatus:
+        return check_dict_keys(order_status, self.kraken_futures_to_nautilus_order_status)
+
+    def parse_nautilus_futures_trigger_signal(
+        self,
+        trigger_type: TriggerType,
+    ) -> KrakenFuturesTriggerSignal:
+        try:
+            return self.nautilus_to_kraken_futures_trigger_signal[trigger_type]
+        except KeyError as e:
+            raise RuntimeError(
+                f"unrecognized Kraken futures trigger signal, was {trigger_type_to_str(trigger_type)}",  # pragma: no cover
+            ) from e
+
+    def parse_kraken_futures_trigger_signal(
+        self,
+        trigger_signal: KrakenFuturesTriggerSignal,
+    ) -> TriggerType:
+        return check_dict_keys(trigger_signal, self.kraken_futures_to_nautilus_trigger_type)
+
+    def parse_nautilus_futures_order_type(
+        self,
+        order_type: OrderType,
+        time_in_force: TimeInForce,
+        is_post_only: bool,
+    ) -> KrakenFuturesOrderType:
+        match order_type:
+            case OrderType.MARKET:
+                return KrakenFuturesOrderType.MARKET
+            case OrderType.LIMIT:
+                if is_post_only:
+                    return KrakenFuturesOrderType.POST_ONLY
+                if time_in_force == TimeInForce.IOC:
+                    return KrakenFuturesOrderType.IOC
+                return KrakenFuturesOrderType.LIMIT
+            case OrderType.STOP_MARKET | OrderType.STOP_LIMIT:
+                return KrakenFuturesOrderType.STOP
+            case OrderType.MARKET_IF_TOUCHED | OrderType.LIMIT_IF_TOUCHED:
+                return KrakenFuturesOrderType.TAKE_PROFIT
+            case _:
+                raise RuntimeError(
+                    f"unrecognized Kraken futures order type, was {order_type_to_str(order_type)}",  # pragma: no cover
+                )
+
+    def parse_kraken_futures_order_type(
+        self,
+        order_type: str,
+        has_limit_price: bool,
+    ) -> OrderType:
+        # Kraken Futures reports order types with varying case and naming between
+        # the REST and WebSocket APIs, so normalize before mapping
+        match order_type.lower():
+            case "mkt" | "market":
+                return OrderType.MARKET
+            case "lmt" | "limit" | "post" | "ioc":
+                return OrderType.LIMIT
+            case "stp" | "stop":
+                return OrderType.STOP_LIMIT if has_limit_price else OrderType.STOP_MARKET
+            case "take_profit":
+                return OrderType.LIMIT_IF_TOUCHED if has_limit_price else OrderType.MARKET_IF_TOUCHED
+            case _:
+                raise RuntimeError(f"unrecognized Kraken futures order type, was {order_type}")
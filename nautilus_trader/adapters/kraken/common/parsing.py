@@ -0,0 +1,95 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from nautilus_trader.adapters.kraken.common.constants import KRAKEN_ASSET_ALIASES
+from nautilus_trader.model.data import BookOrder
+from nautilus_trader.model.data import OrderBookDelta
+from nautilus_trader.model.enums import AggressorSide
+from nautilus_trader.model.enums import BookAction
+from nautilus_trader.model.enums import OrderSide
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.model.identifiers import InstrumentId
+    from nautilus_trader.model.objects import Price
+    from nautilus_trader.model.objects import Quantity
+
+
+def normalize_kraken_asset(asset: str) -> str:
+    """
+    Return the common currency code for the given Kraken asset code.
+
+    Kraken Spot still reports some assets with legacy `X` / `Z` prefixed codes
+    (e.g. `XXBT`, `ZUSD`), and uses `XBT` in place of `BTC`.
+
+    Parameters
+    ----------
+    asset : str
+        The Kraken asset code.
+
+    Returns
+    -------
+    str
+
+    """
+    asset = asset.upper()
+    return KRAKEN_ASSET_ALIASES.get(asset, asset)
+
+
+def parse_aggressor_side(value: str) -> AggressorSide:
+    match value:
+        case "buy":
+            return AggressorSide.BUYER
+        case "sell":
+            return AggressorSide.SELLER
+        case _:
+            raise ValueError(f"Invalid aggressor side value, was '{value}'")
+
+
+def parse_kraken_delta(
+    instrument_id: InstrumentId,
+    values: tuple[Price, Quantity],
+    side: OrderSide,
+    flags: int,
+    sequence: int,
+    ts_event: int,
+    ts_init: int,
+    snapshot: bool,
+) -> OrderBookDelta:
+    price = values[0]
+    size = values[1]
+    if snapshot:
+        action = BookAction.ADD
+    else:
+        action = BookAction.DELETE if size == 0 else BookAction.UPDATE
+
+    return OrderBookDelta(
+        instrument_id=instrument_id,
+        action=action,
+        order=BookOrder(
+            side=side,
+            price=price,
+            size=size,
+            order_id=0,
+        ),
+        flags=flags,
+        sequence=sequence,
+        ts_event=ts_event,
+        ts_init=ts_init,
+    )
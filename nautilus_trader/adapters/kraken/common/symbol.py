@@ -0,0 +1,155 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from typing import Final
+
+from nautilus_trader.adapters.kraken.common.constants import KRAKEN_VENUE
+from nautilus_trader.adapters.kraken.common.enums import KrakenProductType
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import Symbol
+
+
+VALID_SUFFIXES: Final[list[str]] = ["-SPOT", "-FUTURES"]
+
+
+def has_valid_kraken_suffix(symbol: str) -> bool:
+    """
+    Return whether the given `symbol` string ends with a valid Kraken suffix.
+
+    Parameters
+    ----------
+    symbol : str
+        The symbol string value to check.
+
+    Returns
+    -------
+    bool
+        True if contains a valid suffix, else False.
+
+    """
+    symbol = symbol.upper()
+    for suffix in VALID_SUFFIXES:
+        if symbol.endswith(suffix):
+            return True
+    return False
+
+
+class KrakenSymbol(str):
+    """
+    Represents a Kraken specific symbol containing a product type suffix.
+
+    Spot symbols use the WebSocket v2 pair format (e.g. `BTC/USD-SPOT`), and
+    futures symbols use the contract symbol (e.g. `PF_XBTUSD-FUTURES`).
+
+    """
+
+    def __new__(cls, symbol: str) -> KrakenSymbol:  # noqa: PYI034
+        PyCondition.valid_string(symbol, "symbol")
+        if not has_valid_kraken_suffix(symbol):
+            raise ValueError(
+                f"Invalid symbol '{symbol}': "
+                f"does not contain a valid suffix from {VALID_SUFFIXES}",
+            )
+
+        return super().__new__(
+            cls,
+            symbol.upper(),
+        )
+
+    @property
+    def raw_symbol(self) -> str:
+        """
+        Return the raw Kraken symbol (without the product type suffix).
+
+        Returns
+        -------
+        str
+
+        """
+        return str(self).rpartition("-")[0]
+
+    @property
+    def product_type(self) -> KrakenProductType:
+        """
+        Return the Kraken product type for the symbol.
+
+        Returns
+        -------
+        KrakenProductType
+
+        """
+        if self.endswith("-SPOT"):
+            return KrakenProductType.SPOT
+        elif self.endswith("-FUTURES"):
+            return KrakenProductType.FUTURES
+        else:
+            raise ValueError(f"Unknown product type for symbol {self}")
+
+    @property
+    def is_spot(self) -> bool:
+        """
+        Return whether a SPOT product type.
+
+        Returns
+        -------
+        bool
+
+        """
+        return self.product_type == KrakenProductType.SPOT
+
+    @property
+    def is_futures(self) -> bool:
+        """
+        Return whether a FUTURES product type.
+
+        Returns
+        -------
+        bool
+
+        """
+        return self.product_type == KrakenProductType.FUTURES
+
+    def to_instrument_id(self) -> InstrumentId:
+        """
+        Parse the Kraken symbol into a Nautilus instrument ID.
+
+        Returns
+        -------
+        InstrumentId
+
+        """
+        return InstrumentId(Symbol(str(self)), KRAKEN_VENUE)
+
+    @staticmethod
+    def from_raw(raw_symbol: str, product_type: KrakenProductType) -> KrakenSymbol:
+        """
+        Create a Kraken symbol from the given raw venue symbol and product type.
+
+        Parameters
+        ----------
+        raw_symbol : str
+            The raw Kraken symbol.
+        product_type : KrakenProductType
+            The product type for the symbol.
+
+        Returns
+        -------
+        KrakenSymbol
+
+        """
+        return KrakenSymbol(f"{raw_symbol}-{product_type.value.upper()}")
@@ -0,0 +1,49 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from nautilus_trader.adapters.kraken.common.enums import KrakenProductType
+
+
+def get_http_base_url(product_type: KrakenProductType, is_demo: bool) -> str:
+    if product_type == KrakenProductType.SPOT:
+        if is_demo:
+            raise ValueError("Invalid configuration: Kraken Spot has no demo environment")
+        return "https://api.kraken.com"
+    elif is_demo:
+        return "https://demo-futures.kraken.com"
+    else:
+        return "https://futures.kraken.com"
+
+
+def get_ws_base_url_public(product_type: KrakenProductType, is_demo: bool) -> str:
+    if product_type == KrakenProductType.SPOT:
+        if is_demo:
+            raise ValueError("Invalid configuration: Kraken Spot has no demo environment")
+        return "wss://ws.kraken.com/v2"
+    elif is_demo:
+        return "wss://demo-futures.kraken.com/ws/v1"
+    else:
+        return "wss://futures.kraken.com/ws/v1"
+
+
+def get_ws_base_url_private(product_type: KrakenProductType, is_demo: bool) -> str:
+    if product_type == KrakenProductType.SPOT:
+        if is_demo:
+            raise ValueError("Invalid configuration: Kraken Spot has no demo environment")
+        return "wss://ws-auth.kraken.com/v2"
+    elif is_demo:
+        return "wss://demo-futures.kraken.com/ws/v1"
+    else:
+        return "wss://futures.kraken.com/ws/v1"
@@ -0,0 +1,112 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from nautilus_trader.config import LiveDataClientConfig
+from nautilus_trader.config import LiveExecClientConfig
+from nautilus_trader.config import PositiveFloat
+from nautilus_trader.config import PositiveInt
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.adapters.kraken.common.enums import KrakenProductType
+
+
+class KrakenDataClientConfig(LiveDataClientConfig, frozen=True):
+    """
+    Configuration for ``KrakenDataClient`` instances.
+
+    Market data is public for both Kraken Spot and Kraken Futures,
+    so no API credentials are required.
+
+    Parameters
+    ----------
+    product_types : list[KrakenProductType], optional
+        The Kraken product types for the client.
+        If not specified then will use all products.
+    base_url_http_spot : str, optional
+        The base URL for the Kraken Spot HTTP client.
+    base_url_http_futures : str, optional
+        The base URL for the Kraken Futures HTTP client.
+    demo : bool, default False
+        If the client is connecting to the Kraken Futures demo environment.
+    update_instruments_interval_mins: PositiveInt or None, default 60
+        The interval (minutes) between reloading instruments from the venue.
+
+    """
+
+    product_types: list[KrakenProductType] | None = None
+    base_url_http_spot: str | None = None
+    base_url_http_futures: str | None = None
+    demo: bool = False
+    update_instruments_interval_mins: PositiveInt | None = 60
+
+
+class KrakenExecClientConfig(LiveExecClientConfig, frozen=True):
+    """
+    Configuration for ``KrakenExecutionClient`` instances.
+
+    Parameters
+    ----------
+    api_key : str, optional
+        The Kraken API public key.
+        If ``None`` then will source the `KRAKEN_API_KEY`, `KRAKEN_FUTURES_API_KEY` or
+        `KRAKEN_FUTURES_DEMO_API_KEY` environment variables (depending on the product type).
+    api_secret : str, optional
+        The Kraken API secret key.
+        If ``None`` then will source the `KRAKEN_API_SECRET`, `KRAKEN_FUTURES_API_SECRET` or
+        `KRAKEN_FUTURES_DEMO_API_SECRET` environment variables (depending on the product type).
+    product_types : list[KrakenProductType], optional
+        The Kraken product type for the client.
+        If None then will default to 'SPOT'. Kraken Spot and Kraken Futures are
+        separate accounts with separate credentials, so only a single product type
+        is supported per client. 'SPOT' uses a `CASH` account type, and 'FUTURES'
+        a `MARGIN` account type.
+    base_url_http : str, optional
+        The base URL for the HTTP client.
+    base_url_ws_private : str, optional
+        The base URL for the `private` WebSocket client.
+    demo : bool, default False
+        If the client is connecting to the Kraken Futures demo environment.
+    use_gtd : bool, default True
+        If False, then GTD time in force will be remapped to GTC
+        (this is useful if managing GTD orders locally).
+        Only supported for Kraken Spot.
+    max_retries : PositiveInt, optional
+        The maximum number of times a submit, cancel or modify order request will be retried.
+    retry_delay : PositiveFloat, optional
+        The delay (seconds) between retries. Short delays with frequent retries may result in account bans.
+    ws_trade_timeout_secs : float, default 5.0
+        The timeout for Kraken Spot WebSocket trading requests.
+
+    Warnings
+    --------
+    A short `retry_delay` with frequent retries may result in account bans.
+
+    """
+
+    api_key: str | None = None
+    api_secret: str | None = None
+    product_types: list[KrakenProductType] | None = None
+    base_url_http: str | None = None
+    base_url_ws_private: str | None = None
+    demo: bool = False
+    use_gtd: bool = True
+    max_retries: PositiveInt | None = None
+    retry_delay: PositiveFloat | None = None
+    ws_trade_timeout_secs: float | None = 5.0
@@ -0,0 +1,458 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+import asyncio
+from functools import partial
+from typing import TYPE_CHECKING
+
+import msgspec
+
+from nautilus_trader.adapters.kraken.common.constants import KRAKEN_SPOT_DEPTHS
+from nautilus_trader.adapters.kraken.common.constants import KRAKEN_VENUE
+from nautilus_trader.adapters.kraken.common.enums import KrakenProductType
+from nautilus_trader.adapters.kraken.common.symbol import KrakenSymbol
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenFuturesWsBookMsg
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenFuturesWsBookSnapshotMsg
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenFuturesWsMessageGeneral
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenFuturesWsTickerMsg
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenFuturesWsTradeMsg
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenSpotWsBookMsg
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenSpotWsMessageGeneral
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenSpotWsTickerMsg
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenSpotWsTradeMsg
+from nautilus_trader.adapters.kraken.websocket.client import KrakenWebSocketClient
+from nautilus_trader.common.enums import LogColor
+from nautilus_trader.core.datetime import dt_to_unix_nanos
+from nautilus_trader.data.messages import RequestBars
+from nautilus_trader.data.messages import RequestInstrument
+from nautilus_trader.data.messages import RequestInstruments
+from nautilus_trader.data.messages import RequestQuoteTicks
+from nautilus_trader.data.messages import RequestTradeTicks
+from nautilus_trader.data.messages import SubscribeOrderBook
+from nautilus_trader.data.messages import SubscribeQuoteTicks
+from nautilus_trader.data.messages import SubscribeTradeTicks
+from nautilus_trader.data.messages import UnsubscribeOrderBook
+from nautilus_trader.data.messages import UnsubscribeQuoteTicks
+from nautilus_trader.data.messages import UnsubscribeTradeTicks
+from nautilus_trader.live.data_client import LiveMarketDataClient
+from nautilus_trader.model.enums import BookType
+from nautilus_trader.model.identifiers import ClientId
+from nautilus_trader.model.identifiers import InstrumentId
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.adapters.kraken.config import KrakenDataClientConfig
+    from nautilus_trader.adapters.kraken.providers import KrakenInstrumentProvider
+    from nautilus_trader.cache.cache import Cache
+    from nautilus_trader.common.component import LiveClock
+    from nautilus_trader.common.component import MessageBus
+    from nautilus_trader.model.instruments import Instrument
+
+
+class KrakenDataClient(LiveMarketDataClient):
+    """
+    Provides a data client for the Kraken centralized crypto exchange.
+
+    Parameters
+    ----------
+    loop : asyncio.AbstractEventLoop
+        The event loop for the client.
+    msgbus : MessageBus
+        The message bus for the client.
+    cache : Cache
+        The cache for the client.
+    clock : LiveClock
+        The clock for the client.
+    instrument_provider : KrakenInstrumentProvider
+        The instrument provider.
+    product_types : list[KrakenProductType]
+        The product types for the client.
+    ws_base_urls: dict[KrakenProductType, str]
+        The product base urls for the WebSocket clients.
+    config : KrakenDataClientConfig
+        The configuration for the client.
+    name : str, optional
+        The custom client ID.
+
+    """
+
+    def __init__(
+        self,
+        loop: asyncio.AbstractEventLoop,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+        instrument_provider: KrakenInstrumentProvider,
+        product_types: list[KrakenProductType],
+        ws_base_urls: dict[KrakenProductType, str],
+        config: KrakenDataClientConfig,
+        name: str | None,
+    ) -> None:
+        super().__init__(
+            loop=loop,
+            client_id=ClientId(name or KRAKEN_VENUE.value),
+            venue=KRAKEN_VENUE,
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+            instrument_provider=instrument_provider,
+        )
+
+        # Configuration
+        self._log.info(f"Product types: {[p.value for p in product_types]}", LogColor.BLUE)
+        self._log.info(f"{config.update_instruments_interval_mins=}", LogColor.BLUE)
+
+        # WebSocket API
+        self._ws_clients: dict[KrakenProductType, KrakenWebSocketClient] = {}
+        for product_type in set(product_types):
+            self._ws_clients[product_type] = KrakenWebSocketClient(
+                clock=clock,
+                base_url=ws_base_urls[product_type],
+                handler=partial(self._handle_ws_message, product_type),
+                handler_reconnect=None,
+                product_type=product_type,
+                loop=loop,
+            )
+
+        # WebSocket decoders
+        self._decoder_spot_ws_msg_general = msgspec.json.Decoder(KrakenSpotWsMessageGeneral)
+        self._decoder_spot_ws_book = msgspec.json.Decoder(KrakenSpotWsBookMsg)
+        self._decoder_spot_ws_trade = msgspec.json.Decoder(KrakenSpotWsTradeMsg)
+        self._decoder_spot_ws_ticker = msgspec.json.Decoder(KrakenSpotWsTickerMsg)
+        self._decoder_futures_ws_msg_general = msgspec.json.Decoder(KrakenFuturesWsMessageGeneral)
+        self._decoder_futures_ws_book_snapshot = msgspec.json.Decoder(
+            KrakenFuturesWsBookSnapshotMsg,
+        )
+        self._decoder_futures_ws_book = msgspec.json.Decoder(KrakenFuturesWsBookMsg)
+        self._decoder_futures_ws_trade = msgspec.json.Decoder(KrakenFuturesWsTradeMsg)
+        self._decoder_futures_ws_ticker = msgspec.json.Decoder(KrakenFuturesWsTickerMsg)
+
+        self._depths: dict[InstrumentId, int] = {}
+
+        self._update_instruments_interval_mins: int | None = config.update_instruments_interval_mins
+        self._update_instruments_task: asyncio.Task | None = None
+
+    async def _connect(self) -> None:
+        await self._instrument_provider.initialize()
+        self._send_all_instruments_to_data_engine()
+
+        if self._update_instruments_interval_mins:
+            self._update_instruments_task = self.create_task(
+                self._update_instruments(self._update_instruments_interval_mins),
+            )
+
+        for ws_client in self._ws_clients.values():
+            await ws_client.connect()
+
+    async def _disconnect(self) -> None:
+        if self._update_instruments_task:
+            self._log.debug("Canceling task 'update_instruments'")
+            self._update_instruments_task.cancel()
+            self._update_instruments_task = None
+
+        for ws_client in self._ws_clients.values():
+            await ws_client.disconnect()
+
+    def _send_all_instruments_to_data_engine(self) -> None:
+        for instrument in self._instrument_provider.get_all().values():
+            self._handle_data(instrument)
+
+        for currency in self._instrument_provider.currencies().values():
+            self._cache.add_currency(currency)
+
+    async def _update_instruments(self, interval_mins: int) -> None:
+        try:
+            while True:
+                self._log.debug(
+                    f"Scheduled task 'update_instruments' to run in {interval_mins} minutes",
+                )
+                await asyncio.sleep(interval_mins * 60)
+                await self._instrument_provider.initialize(reload=True)
+                self._send_all_instruments_to_data_engine()
+        except asyncio.CancelledError:
+            self._log.debug("Canceled task 'update_instruments'")
+
+    def _get_ws_client(self, instrument_id: InstrumentId) -> tuple[KrakenWebSocketClient, str]:
+        kraken_symbol = KrakenSymbol(instrument_id.symbol.value)
+        return self._ws_clients[kraken_symbol.product_type], kraken_symbol.raw_symbol
+
+    async def _subscribe_order_book_deltas(self, command: SubscribeOrderBook) -> None:
+        if command.book_type != BookType.L2_MBP:
+            self._log.error(
+                f"Cannot subscribe to order book deltas: "
+                f"{command.book_type} data is not published by Kraken. "
+                "Valid book types are L2_MBP",
+            )
+            return
+
+        if command.instrument_id in self._depths:
+            self._log.warning(f"Already subscribed to {command.instrument_id} order book deltas")
+            return
+
+        kraken_symbol = KrakenSymbol(command.instrument_id.symbol.value)
+
+        # Kraken Futures only publishes the full book, without a depth parameter
+        depth = 0
+        if kraken_symbol.is_spot:
+            depth = command.depth or KRAKEN_SPOT_DEPTHS[0]
+            if depth not in KRAKEN_SPOT_DEPTHS:
+                self._log.error(
+                    f"Cannot subscribe to order book depth {depth} "
+                    f"for Kraken spot products, available depths are {KRAKEN_SPOT_DEPTHS}",
+                )
+                return
+
+        self._depths[command.instrument_id] = depth
+        ws_client = self._ws_clients[kraken_symbol.product_type]
+        await ws_client.subscribe_order_book(kraken_symbol.raw_symbol, depth=depth or None)
+
+    async def _subscribe_quote_ticks(self, command: SubscribeQuoteTicks) -> None:
+        ws_client, raw_symbol = self._get_ws_client(command.instrument_id)
+        await ws_client.subscribe_tickers(raw_symbol)
+
+    async def _subscribe_trade_ticks(self, command: SubscribeTradeTicks) -> None:
+        ws_client, raw_symbol = self._get_ws_client(command.instrument_id)
+        await ws_client.subscribe_trades(raw_symbol)
+
+    async def _unsubscribe_order_book_deltas(self, command: UnsubscribeOrderBook) -> None:
+        self._depths.pop(command.instrument_id, None)
+        ws_client, raw_symbol = self._get_ws_client(command.instrument_id)
+        await ws_client.unsubscribe_order_book(raw_symbol)
+
+    async def _unsubscribe_quote_ticks(self, command: UnsubscribeQuoteTicks) -> None:
+        ws_client, raw_symbol = self._get_ws_client(command.instrument_id)
+        await ws_client.unsubscribe_tickers(raw_symbol)
+
+    async def _unsubscribe_trade_ticks(self, command: UnsubscribeTradeTicks) -> None:
+        ws_client, raw_symbol = self._get_ws_client(command.instrument_id)
+        await ws_client.unsubscribe_trades(raw_symbol)
+
+    async def _request_instrument(self, request: RequestInstrument) -> None:
+        if request.start is not None:
+            self._log.warning(
+                f"Requesting instrument {request.instrument_id} with specified `start` which has no effect",
+            )
+
+        if request.end is not None:
+            self._log.warning(
+                f"Requesting instrument {request.instrument_id} with specified `end` which has no effect",
+            )
+
+        instrument: Instrument | None = self._instrument_provider.find(request.instrument_id)
+        if instrument is None:
+            self._log.error(f"Cannot find instrument for {request.instrument_id}")
+            return
+
+        self._handle_instrument(instrument, request.id, request.params)
+
+    async def _request_instruments(self, request: RequestInstruments) -> None:
+        if request.start is not None:
+            self._log.warning(
+                f"Requesting instruments for {request.venue} with specified `start` which has no effect",
+            )
+
+        if request.end is not None:
+            self._log.warning(
+                f"Requesting instruments for {request.venue} with specified `end` which has no effect",
+            )
+
+        all_instruments = self._instrument_provider.get_all()
+        target_instruments = []
+        for instrument in all_instruments.values():
+            if instrument.venue == request.venue:
+                target_instruments.append(instrument)
+
+        self._handle_instruments(
+            request.venue,
+            target_instruments,
+            request.id,
+            request.params,
+        )
+
+    async def _request_quote_ticks(self, request: RequestQuoteTicks) -> None:
+        self._log.error(
+            "Cannot request historical quotes: not published by Kraken",
+        )
+
+    async def _request_trade_ticks(self, request: RequestTradeTicks) -> None:
+        self._log.error(
+            "Cannot request historical trades: not yet implemented for Kraken",
+        )
+
+    async def _request_bars(self, request: RequestBars) -> None:
+        self._log.error(
+            "Cannot request historical bars: not yet implemented for Kraken",
+        )
+
+    def _get_cached_instrument(
+        self,
+        raw_symbol: str,
+        product_type: KrakenProductType,
+    ) -> Instrument | None:
+        instrument_id = KrakenSymbol.from_raw(raw_symbol, product_type).to_instrument_id()
+        instrument = self._cache.instrument(instrument_id)
+        if instrument is None:
+            self._log.error(f"Cannot parse data: no instrument for {instrument_id}")
+        return instrument
+
+    def _handle_ws_message(self, product_type: KrakenProductType, raw: bytes) -> None:
+        try:
+            if product_type == KrakenProductType.SPOT:
+                self._handle_spot_ws_message(raw)
+            else:
+                self._handle_futures_ws_message(raw)
+        except Exception as e:
+            self._log.exception(f"Failed to handle websocket message {raw.decode()}", e)
+
+    def _handle_spot_ws_message(self, raw: bytes) -> None:
+        ws_message = self._decoder_spot_ws_msg_general.decode(raw)
+        channel = ws_message.channel
+        if channel == "book":
+            self._handle_spot_book(raw)
+        elif channel == "trade":
+            self._handle_spot_trade(raw)
+        elif channel == "ticker":
+            self._handle_spot_ticker(raw)
+        else:
+            self._log.debug(f"Unhandled websocket message: {raw.decode()}")
+
+    def _handle_futures_ws_message(self, raw: bytes) -> None:
+        ws_message = self._decoder_futures_ws_msg_general.decode(raw)
+        feed = ws_message.feed
+        if feed == "book_snapshot":
+            self._handle_futures_book_snapshot(raw)
+        elif feed == "book":
+            self._handle_futures_book(raw)
+        elif feed == "trade":
+            self._handle_futures_trade(raw)
+        elif feed == "ticker":
+            self._handle_futures_ticker(raw)
+        elif feed == "trade_snapshot":
+            return  # Recent trades on subscription are not replayed
+        else:
+            self._log.debug(f"Unhandled websocket message: {raw.decode()}")
+
+    def _handle_spot_book(self, raw: bytes) -> None:
+        msg = self._decoder_spot_ws_book.decode(raw)
+        ts_init = self._clock.timestamp_ns()
+        for data in msg.data:
+            instrument = self._get_cached_instrument(data.symbol, KrakenProductType.SPOT)
+            if instrument is None:
+                return
+
+            deltas = data.parse_to_deltas(
+                instrument_id=instrument.id,
+                price_precision=instrument.price_precision,
+                size_precision=instrument.size_precision,
+                ts_event=dt_to_unix_nanos(data.timestamp) if data.timestamp else ts_init,
+                ts_init=ts_init,
+                snapshot=msg.type == "snapshot",
+            )
+            self._handle_data(deltas)
+
+    def _handle_spot_trade(self, raw: bytes) -> None:
+        msg = self._decoder_spot_ws_trade.decode(raw)
+        for data in msg.data:
+            instrument = self._get_cached_instrument(data.symbol, KrakenProductType.SPOT)
+            if instrument is None:
+                return
+
+            trade = data.parse_to_trade_tick(
+                instrument_id=instrument.id,
+                price_precision=instrument.price_precision,
+                size_precision=instrument.size_precision,
+                ts_event=dt_to_unix_nanos(data.timestamp),
+                ts_init=self._clock.timestamp_ns(),
+            )
+            self._handle_data(trade)
+
+    def _handle_spot_ticker(self, raw: bytes) -> None:
+        msg = self._decoder_spot_ws_ticker.decode(raw)
+        for data in msg.data:
+            instrument = self._get_cached_instrument(data.symbol, KrakenProductType.SPOT)
+            if instrument is None:
+                return
+
+            # Ticker messages carry no timestamp
+            ts_init = self._clock.timestamp_ns()
+            quote = data.parse_to_quote_tick(
+                instrument_id=instrument.id,
+                price_precision=instrument.price_precision,
+                size_precision=instrument.size_precision,
+                ts_event=ts_init,
+                ts_init=ts_init,
+            )
+            self._handle_data(quote)
+
+    def _handle_futures_book_snapshot(self, raw: bytes) -> None:
+        msg = self._decoder_futures_ws_book_snapshot.decode(raw)
+        instrument = self._get_cached_instrument(msg.product_id, KrakenProductType.FUTURES)
+        if instrument is None:
+            return
+
+        deltas = msg.parse_to_deltas(
+            instrument_id=instrument.id,
+            price_precision=instrument.price_precision,
+            size_precision=instrument.size_precision,
+            ts_init=self._clock.timestamp_ns(),
+        )
+        self._handle_data(deltas)
+
+    def _handle_futures_book(self, raw: bytes) -> None:
+        msg = self._decoder_futures_ws_book.decode(raw)
+        instrument = self._get_cached_instrument(msg.product_id, KrakenProductType.FUTURES)
+        if instrument is None:
+            return
+
+        deltas = msg.parse_to_deltas(
+            instrument_id=instrument.id,
+            price_precision=instrument.price_precision,
+            size_precision=instrument.size_precision,
+            ts_init=self._clock.timestamp_ns(),
+        )
+        self._handle_data(deltas)
+
+    def _handle_futures_trade(self, raw: bytes) -> None:
+        msg = self._decoder_futures_ws_trade.decode(raw)
+        instrument = self._get_cached_instrument(msg.product_id, KrakenProductType.FUTURES)
+        if instrument is None:
+            return
+
+        trade = msg.parse_to_trade_tick(
+            instrument_id=instrument.id,
+            price_precision=instrument.price_precision,
+            size_precision=instrument.size_precision,
+            ts_init=self._clock.timestamp_ns(),
+        )
+        self._handle_data(trade)
+
+    def _handle_futures_ticker(self, raw: bytes) -> None:
+        msg = self._decoder_futures_ws_ticker.decode(raw)
+        if msg.bid is None or msg.ask is None:
+            return  # No two-sided market
+
+        instrument = self._get_cached_instrument(msg.product_id, KrakenProductType.FUTURES)
+        if instrument is None:
+            return
+
+        quote = msg.parse_to_quote_tick(
+            instrument_id=instrument.id,
+            price_precision=instrument.price_precision,
+            size_precision=instrument.size_precision,
+            ts_init=self._clock.timestamp_ns(),
+        )
+        self._handle_data(quote)
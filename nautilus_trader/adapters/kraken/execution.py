@@ -0,0 +1,1184 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+import uuid
+from decimal import Decimal
+from typing import TYPE_CHECKING
+
+import msgspec
+
+from nautilus_trader.adapters.kraken.common.constants import KRAKEN_SPOT_MAX_CLIENT_ORDER_ID_LEN
+from nautilus_trader.adapters.kraken.common.constants import KRAKEN_VENUE
+from nautilus_trader.adapters.kraken.common.credentials import get_api_key
+from nautilus_trader.adapters.kraken.common.credentials import get_api_secret
+from nautilus_trader.adapters.kraken.common.enums import KrakenEnumParser
+from nautilus_trader.adapters.kraken.common.enums import KrakenProductType
+from nautilus_trader.adapters.kraken.common.enums import KrakenSpotExecType
+from nautilus_trader.adapters.kraken.common.parsing import normalize_kraken_asset
+from nautilus_trader.adapters.kraken.common.symbol import KrakenSymbol
+from nautilus_trader.adapters.kraken.http.errors import KrakenError
+from nautilus_trader.adapters.kraken.http.errors import should_retry
+from nautilus_trader.adapters.kraken.http.futures import KrakenFuturesHttpAPI
+from nautilus_trader.adapters.kraken.http.spot import KrakenSpotHttpAPI
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenFuturesWsFill
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenFuturesWsFillsMsg
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenFuturesWsMessageGeneral
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenFuturesWsOpenOrdersMsg
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenSpotWsExecution
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenSpotWsExecutionsMsg
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenSpotWsMessageGeneral
+from nautilus_trader.adapters.kraken.websocket.client import KrakenWebSocketClient
+from nautilus_trader.common.enums import LogColor
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.core.datetime import dt_to_unix_nanos
+from nautilus_trader.core.datetime import millis_to_nanos
+from nautilus_trader.core.datetime import unix_nanos_to_iso8601
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.live.execution_client import LiveExecutionClient
+from nautilus_trader.live.retry import RetryManagerPool
+from nautilus_trader.model.enums import AccountType
+from nautilus_trader.model.enums import LiquiditySide
+from nautilus_trader.model.enums import OmsType
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import TimeInForce
+from nautilus_trader.model.enums import account_type_to_str
+from nautilus_trader.model.enums import order_type_to_str
+from nautilus_trader.model.enums import time_in_force_to_str
+from nautilus_trader.model.identifiers import AccountId
+from nautilus_trader.model.identifiers import ClientId
+from nautilus_trader.model.identifiers import ClientOrderId
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import TradeId
+from nautilus_trader.model.identifiers import VenueOrderId
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import Money
+from nautilus_trader.model.orders import Order
+
+
+if TYPE_CHECKING:
+    import asyncio
+
+    import pandas as pd
+
+    from nautilus_trader.adapters.kraken.config import KrakenExecClientConfig
+    from nautilus_trader.adapters.kraken.http.client import KrakenHttpClient
+    from nautilus_trader.adapters.kraken.providers import KrakenInstrumentProvider
+    from nautilus_trader.cache.cache import Cache
+    from nautilus_trader.common.component import LiveClock
+    from nautilus_trader.common.component import MessageBus
+    from nautilus_trader.execution.messages import CancelAllOrders
+    from nautilus_trader.execution.messages import CancelOrder
+    from nautilus_trader.execution.messages import ModifyOrder
+    from nautilus_trader.execution.messages import SubmitOrder
+    from nautilus_trader.execution.reports import FillReport
+    from nautilus_trader.execution.reports import OrderStatusReport
+    from nautilus_trader.execution.reports import PositionStatusReport
+    from nautilus_trader.model.instruments import Instrument
+
+
+# Order types which are triggered into a resting limit order
+_TRIGGERED_LIMIT_ORDER_TYPES = (OrderType.STOP_LIMIT, OrderType.LIMIT_IF_TOUCHED)
+
+# Kraken Futures cancel reasons which are not cancellations by the user or venue
+_FUTURES_NON_CANCEL_REASONS = ("full_fill", "partial_fill")
+
+
+class KrakenExecutionClient(LiveExecutionClient):
+    """
+    Provides an execution client for the Kraken centralized crypto exchange.
+
+    Kraken Spot orders are submitted through the WebSocket v2 trading API, and
+    Kraken Futures orders through the REST API.
+
+    Parameters
+    ----------
+    loop : asyncio.AbstractEventLoop
+        The event loop for the client.
+    client : KrakenHttpClient
+        The Kraken HTTP client (for the configured product type).
+    msgbus : MessageBus
+        The message bus for the client.
+    cache : Cache
+        The cache for the client.
+    clock : LiveClock
+        The clock for the client.
+    instrument_provider : KrakenInstrumentProvider
+        The instrument provider.
+    product_types : list[KrakenProductType]
+        The product types for the client.
+    base_url_ws_private : str
+        The base URL for the `private` WebSocket client.
+    config : KrakenExecClientConfig
+        The configuration for the client.
+    name : str, optional
+        The custom client ID.
+
+    Raises
+    ------
+    ValueError
+        If `product_types` contains both `SPOT` and `FUTURES`.
+
+    """
+
+    def __init__(
+        self,
+        loop: asyncio.AbstractEventLoop,
+        client: KrakenHttpClient,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+        instrument_provider: KrakenInstrumentProvider,
+        product_types: list[KrakenProductType],
+        base_url_ws_private: str,
+        config: KrakenExecClientConfig,
+        name: str | None,
+    ) -> None:
+        if len(set(product_types)) > 1:
+            raise ValueError("Cannot configure SPOT with FUTURES (separate Kraken accounts)")
+
+        product_type = product_types[0]
+        if product_type == KrakenProductType.SPOT:
+            account_type = AccountType.CASH
+        else:
+            account_type = AccountType.MARGIN
+
+        super().__init__(
+            loop=loop,
+            client_id=ClientId(name or KRAKEN_VENUE.value),
+            venue=KRAKEN_VENUE,
+            oms_type=OmsType.NETTING,
+            instrument_provider=instrument_provider,
+            account_type=account_type,
+            base_currency=None,
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+        )
+
+        # Configuration
+        self._product_type = product_type
+        self._use_gtd = config.use_gtd
+
+        self._log.info(f"Account type: {account_type_to_str(account_type)}", LogColor.BLUE)
+        self._log.info(f"Product type: {product_type.value}", LogColor.BLUE)
+        self._log.info(f"{config.use_gtd=}", LogColor.BLUE)
+        self._log.info(f"{config.max_retries=}", LogColor.BLUE)
+        self._log.info(f"{config.retry_delay=}", LogColor.BLUE)
+        self._log.info(f"{config.ws_trade_timeout_secs=}", LogColor.BLUE)
+
+        self._enum_parser = KrakenEnumParser()
+        self._kraken_instrument_provider = instrument_provider
+
+        account_id = AccountId(f"{name or KRAKEN_VENUE.value}-{product_type.value.upper()}")
+        self._set_account_id(account_id)
+
+        # HTTP API
+        self._http_spot: KrakenSpotHttpAPI | None = None
+        self._http_futures: KrakenFuturesHttpAPI | None = None
+        if product_type == KrakenProductType.SPOT:
+            self._http_spot = KrakenSpotHttpAPI(client=client, clock=clock)
+        else:
+            self._http_futures = KrakenFuturesHttpAPI(client=client, clock=clock)
+
+        # WebSocket private client
+        is_demo = config.demo and product_type == KrakenProductType.FUTURES
+        self._ws_private_client = KrakenWebSocketClient(
+            clock=clock,
+            base_url=base_url_ws_private,
+            handler=self._handle_ws_message_private,
+            handler_reconnect=None,
+            product_type=product_type,
+            loop=loop,
+            api_key=config.api_key or get_api_key(product_type, is_demo),
+            api_secret=config.api_secret or get_api_secret(product_type, is_demo),
+            is_private=True,
+            token_provider=self._http_spot.fetch_ws_token if self._http_spot else None,
+            ws_trade_timeout_secs=config.ws_trade_timeout_secs,
+        )
+
+        # Decoders
+        self._decoder_spot_ws_msg_general = msgspec.json.Decoder(KrakenSpotWsMessageGeneral)
+        self._decoder_spot_ws_executions = msgspec.json.Decoder(KrakenSpotWsExecutionsMsg)
+        self._decoder_futures_ws_msg_general = msgspec.json.Decoder(KrakenFuturesWsMessageGeneral)
+        self._decoder_futures_ws_open_orders = msgspec.json.Decoder(KrakenFuturesWsOpenOrdersMsg)
+        self._decoder_futures_ws_fills = msgspec.json.Decoder(KrakenFuturesWsFillsMsg)
+
+        # Hot caches
+        self._kraken_client_order_ids: dict[str, ClientOrderId] = {}
+
+        self._retry_manager_pool = RetryManagerPool[None](
+            pool_size=100,
+            max_retries=config.max_retries or 0,
+            retry_delay_secs=config.retry_delay or 0.0,
+            logger=self._log,
+            exc_types=(KrakenError,),
+            retry_check=should_retry,
+        )
+
+    @property
+    def is_spot(self) -> bool:
+        return self._product_type == KrakenProductType.SPOT
+
+    async def _connect(self) -> None:
+        await self._instrument_provider.initialize()
+        await self._update_account_state()
+
+        await self._ws_private_client.connect()
+
+        if self.is_spot:
+            await self._ws_private_client.subscribe_executions()
+        else:
+            await self._ws_private_client.subscribe_open_orders()
+            await self._ws_private_client.subscribe_fills()
+
+    async def _disconnect(self) -> None:
+        await self._ws_private_client.disconnect()
+
+    def _stop(self) -> None:
+        self._retry_manager_pool.shutdown()
+
+    # -- EXECUTION REPORTS ------------------------------------------------------------------------
+
+    async def generate_order_status_reports(
+        self,
+        instrument_id: InstrumentId | None = None,
+        start: pd.Timestamp | None = None,
+        end: pd.Timestamp | None = None,
+        open_only: bool = False,
+    ) -> list[OrderStatusReport]:
+        self._log.debug("Requesting OrderStatusReports...")
+        reports: list[OrderStatusReport] = []
+
+        try:
+            if self._http_spot is not None:
+                kraken_orders = await self._http_spot.fetch_open_orders()
+                if not open_only:
+                    closed_orders = await self._http_spot.fetch_closed_orders(
+                        start_secs=int(start.timestamp()) if start is not None else None,
+                        end_secs=int(end.timestamp()) if end is not None else None,
+                    )
+                    kraken_orders = {**closed_orders, **kraken_orders}
+
+                for txid, kraken_order in kraken_orders.items():
+                    order_instrument_id = self._kraken_instrument_provider.find_spot_instrument_id(
+                        kraken_order.descr.pair,
+                    )
+                    if order_instrument_id is None:
+                        self._log.debug(f"No instrument for pair {kraken_order.descr.pair}")
+                        continue
+                    if instrument_id is not None and order_instrument_id != instrument_id:
+                        continue
+
+                    venue_order_id = VenueOrderId(txid)
+                    report = kraken_order.parse_to_order_status_report(
+                        account_id=self.account_id,
+                        instrument_id=order_instrument_id,
+                        client_order_id=self._parse_client_order_id(
+                            kraken_order.cl_ord_id,
+                            venue_order_id,
+                        ),
+                        venue_order_id=venue_order_id,
+                        report_id=UUID4(),
+                        enum_parser=self._enum_parser,
+                        ts_init=self._clock.timestamp_ns(),
+                    )
+                    reports.append(report)
+                    self._log.debug(f"Received {report}", LogColor.MAGENTA)
+            elif self._http_futures is not None:
+                # Kraken Futures only provides open orders, closed orders are
+                # reconciled through fills
+                futures_orders = await self._http_futures.fetch_open_orders()
+                for futures_order in futures_orders:
+                    instrument = self._get_futures_instrument(futures_order.symbol)
+                    if instrument is None:
+                        continue
+                    if instrument_id is not None and instrument.id != instrument_id:
+                        continue
+
+                    report = futures_order.parse_to_order_status_report(
+                        account_id=self.account_id,
+                        instrument=instrument,
+                        client_order_id=self._parse_client_order_id(
+                            futures_order.cliOrdId,
+                            VenueOrderId(futures_order.order_id),
+                        ),
+                        report_id=UUID4(),
+                        enum_parser=self._enum_parser,
+                        ts_init=self._clock.timestamp_ns(),
+                    )
+                    reports.append(report)
+                    self._log.debug(f"Received {report}", LogColor.MAGENTA)
+        except KrakenError as e:
+            self._log.error(f"Failed to generate OrderStatusReports: {e}")
+
+        len_reports = len(reports)
+        plural = "" if len_reports == 1 else "s"
+        self._log.info(f"Received {len(reports)} OrderStatusReport{plural}")
+
+        return reports
+
+    async def generate_order_status_report(
+        self,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId | None = None,
+        venue_order_id: VenueOrderId | None = None,
+    ) -> OrderStatusReport | None:
+        PyCondition.is_false(
+            client_order_id is None and venue_order_id is None,
+            "both `client_order_id` and `venue_order_id` were `None`",
+        )
+
+        self._log.info(
+            f"Generating OrderStatusReport for "
+            f"{repr(client_order_id) if client_order_id else ''} "
+            f"{repr(venue_order_id) if venue_order_id else ''}",
+        )
+
+        if venue_order_id is None and client_order_id is not None:
+            order = self._cache.order(client_order_id)
+            if order is not None:
+                venue_order_id = order.venue_order_id
+
+        try:
+            if self._http_spot is not None:
+                if venue_order_id is None:
+                    self._log.error(
+                        f"Cannot query Kraken Spot order {client_order_id!r}: no venue order ID",
+                    )
+                    return None
+
+                kraken_orders = await self._http_spot.query_orders([venue_order_id.value])
+                kraken_order = kraken_orders.get(venue_order_id.value)
+                if kraken_order is None:
+                    self._log.error(f"Received no order for {venue_order_id}")
+                    return None
+
+                report = kraken_order.parse_to_order_status_report(
+                    account_id=self.account_id,
+                    instrument_id=instrument_id,
+                    client_order_id=self._parse_client_order_id(
+                        kraken_order.cl_ord_id,
+                        venue_order_id,
+                    ),
+                    venue_order_id=venue_order_id,
+                    report_id=UUID4(),
+                    enum_parser=self._enum_parser,
+                    ts_init=self._clock.timestamp_ns(),
+                )
+                self._log.debug(f"Received {report}", LogColor.MAGENTA)
+                return report
+            elif self._http_futures is not None:
+                instrument = self._cache.instrument(instrument_id)
+                if instrument is None:
+                    self._log.error(f"Cannot generate report: no instrument for {instrument_id}")
+                    return None
+
+                futures_orders = await self._http_futures.fetch_open_orders()
+                for futures_order in futures_orders:
+                    if (
+                        venue_order_id is not None and futures_order.order_id == venue_order_id.value
+                    ) or (
+                        client_order_id is not None
+                        and futures_order.cliOrdId == client_order_id.value
+                    ):
+                        report = futures_order.parse_to_order_status_report(
+                            account_id=self.account_id,
+                            instrument=instrument,
+                            client_order_id=self._parse_client_order_id(
+                                futures_order.cliOrdId,
+                                VenueOrderId(futures_order.order_id),
+                            ),
+                            report_id=UUID4(),
+                            enum_parser=self._enum_parser,
+                            ts_init=self._clock.timestamp_ns(),
+                        )
+                        self._log.debug(f"Received {report}", LogColor.MAGENTA)
+                        return report
+
+                self._log.warning(
+                    f"No open order found for {client_order_id!r} {venue_order_id!r} "
+                    "(Kraken Futures only reports open orders)",
+                )
+        except KrakenError as e:
+            self._log.error(f"Failed to generate OrderStatusReport: {e}")
+        return None
+
+    async def generate_fill_reports(
+        self,
+        instrument_id: InstrumentId | None = None,
+        venue_order_id: VenueOrderId | None = None,
+        start: pd.Timestamp | None = None,
+        end: pd.Timestamp | None = None,
+    ) -> list[FillReport]:
+        self._log.debug("Requesting FillReports...")
+        reports: list[FillReport] = []
+
+        try:
+            if self._http_spot is not None:
+                trades = await self._http_spot.fetch_trades_history(
+                    start_secs=int(start.timestamp()) if start is not None else None,
+                    end_secs=int(end.timestamp()) if end is not None else None,
+                )
+                for trade_id, trade in trades.items():
+                    trade_instrument_id = self._kraken_instrument_provider.find_spot_instrument_id(
+                        trade.pair,
+                    )
+                    if trade_instrument_id is None:
+                        continue
+                    if instrument_id is not None and trade_instrument_id != instrument_id:
+                        continue
+                    if venue_order_id is not None and trade.ordertxid != venue_order_id.value:
+                        continue
+
+                    instrument = self._cache.instrument(trade_instrument_id)
+                    if instrument is None:
+                        continue
+
+                    report = trade.parse_to_fill_report(
+                        account_id=self.account_id,
+                        instrument_id=trade_instrument_id,
+                        trade_id=trade_id,
+                        client_order_id=self._cache.client_order_id(
+                            VenueOrderId(trade.ordertxid),
+                        ),
+                        quote_currency=instrument.quote_currency,
+                        report_id=UUID4(),
+                        enum_parser=self._enum_parser,
+                        ts_init=self._clock.timestamp_ns(),
+                    )
+                    reports.append(report)
+                    self._log.debug(f"Received {report}")
+            elif self._http_futures is not None:
+                fills = await self._http_futures.fetch_fills()
+                for fill in fills:
+                    instrument = self._get_futures_instrument(fill.symbol)
+                    if instrument is None:
+                        continue
+                    if instrument_id is not None and instrument.id != instrument_id:
+                        continue
+                    if venue_order_id is not None and fill.order_id != venue_order_id.value:
+                        continue
+
+                    report = fill.parse_to_fill_report(
+                        account_id=self.account_id,
+                        instrument=instrument,
+                        client_order_id=self._parse_client_order_id(
+                            fill.cliOrdId,
+                            VenueOrderId(fill.order_id),
+                        ),
+                        report_id=UUID4(),
+                        enum_parser=self._enum_parser,
+                        ts_init=self._clock.timestamp_ns(),
+                    )
+                    reports.append(report)
+                    self._log.debug(f"Received {report}")
+        except KrakenError as e:
+            self._log.error(f"Failed to generate FillReports: {e}")
+
+        len_reports = len(reports)
+        plural = "" if len_reports == 1 else "s"
+        self._log.info(f"Received {len(reports)} FillReport{plural}")
+
+        return reports
+
+    async def generate_position_status_reports(
+        self,
+        instrument_id: InstrumentId | None = None,
+        start: pd.Timestamp | None = None,
+        end: pd.Timestamp | None = None,
+    ) -> list[PositionStatusReport]:
+        reports: list[PositionStatusReport] = []
+
+        if self._http_futures is None:
+            return reports  # No positions on spot
+
+        try:
+            self._log.debug("Requesting PositionStatusReports...")
+            positions = await self._http_futures.fetch_open_positions()
+            for position in positions:
+                instrument = self._get_futures_instrument(position.symbol)
+                if instrument is None:
+                    continue
+                if instrument_id is not None and instrument.id != instrument_id:
+                    continue
+
+                position_report = position.parse_to_position_status_report(
+                    account_id=self.account_id,
+                    instrument=instrument,
+                    report_id=UUID4(),
+                    ts_init=self._clock.timestamp_ns(),
+                )
+                self._log.debug(f"Received {position_report}")
+                reports.append(position_report)
+        except KrakenError as e:
+            self._log.error(f"Failed to generate PositionReports: {e}")
+
+        len_reports = len(reports)
+        plural = "" if len_reports == 1 else "s"
+        self._log.info(f"Received {len(reports)} PositionReport{plural}")
+
+        return reports
+
+    def _get_futures_instrument(self, symbol: str) -> Instrument | None:
+        kraken_symbol = KrakenSymbol.from_raw(symbol.upper(), KrakenProductType.FUTURES)
+        instrument_id = kraken_symbol.to_instrument_id()
+        instrument = self._cache.instrument(instrument_id)
+        if instrument is None:
+            self._log.debug(f"No instrument found for {instrument_id}")
+        return instrument
+
+    def _get_kraken_client_order_id(self, client_order_id: ClientOrderId) -> str:
+        # Kraken Futures accepts client order IDs up to 100 characters
+        value = client_order_id.value
+        if not self.is_spot or len(value) <= KRAKEN_SPOT_MAX_CLIENT_ORDER_ID_LEN:
+            return value
+
+        # Longer Kraken Spot client order IDs are mapped to a deterministic UUID
+        kraken_client_order_id = str(uuid.uuid5(uuid.NAMESPACE_OID, value))
+        self._kraken_client_order_ids[kraken_client_order_id] = client_order_id
+        return kraken_client_order_id
+
+    def _parse_client_order_id(
+        self,
+        kraken_client_order_id: str | None,
+        venue_order_id: VenueOrderId,
+    ) -> ClientOrderId | None:
+        if kraken_client_order_id:
+            client_order_id = self._kraken_client_order_ids.get(kraken_client_order_id)
+            if client_order_id is not None:
+                return client_order_id
+            if self._cache.order(ClientOrderId(kraken_client_order_id)) is not None:
+                return ClientOrderId(kraken_client_order_id)
+
+        return self._cache.client_order_id(venue_order_id)
+
+    def _determine_time_in_force(self, order: Order) -> TimeInForce:
+        time_in_force: TimeInForce = order.time_in_force
+        if time_in_force == TimeInForce.GTD and not self._use_gtd:
+            time_in_force = TimeInForce.GTC
+            self._log.info(
+                f"Converted GTD `time_in_force` to GTC for {order.client_order_id}",
+                LogColor.BLUE,
+            )
+        return time_in_force
+
+    async def _update_account_state(self) -> None:
+        try:
+            if self._http_spot is not None:
+                kraken_balances = await self._http_spot.fetch_balances()
+                balances = [
+                    balance.parse_to_account_balance(asset)
+                    for asset, balance in kraken_balances.items()
+                    if Decimal(balance.balance) > 0
+                ]
+                margins = []
+            elif self._http_futures is not None:
+                accounts = await self._http_futures.fetch_accounts()
+                balances = accounts.parse_to_account_balances()
+                margins = accounts.parse_to_margin_balances()
+            else:
+                return  # Unreachable
+
+            self.generate_account_state(
+                balances=balances,
+                margins=margins,
+                reported=True,
+                ts_event=self._clock.timestamp_ns(),
+            )
+        except Exception as e:
+            self._log.error(f"Failed to generate AccountState: {e}")
+
+    # -- COMMAND HANDLERS -------------------------------------------------------------------------
+
+    async def _cancel_order(self, command: CancelOrder) -> None:
+        order: Order | None = self._cache.order(command.client_order_id)
+        if order is None:
+            self._log.error(f"{command.client_order_id!r} not found in cache")
+            return
+
+        if order.is_closed:
+            self._log.warning(
+                f"`CancelOrder` command for {command.client_order_id!r} when order already {order.status_string()} "
+                "(will not send to exchange)",
+            )
+            return
+
+        client_order_id = self._get_kraken_client_order_id(command.client_order_id)
+        venue_order_id = str(command.venue_order_id) if command.venue_order_id else None
+
+        async with self._retry_manager_pool as retry_manager:
+            if self.is_spot:
+                await retry_manager.run(
+                    "cancel_order",
+                    [client_order_id, venue_order_id],
+                    self._ws_private_client.cancel_order,
+                    cl_ord_ids=[client_order_id] if venue_order_id is None else None,
+                    order_ids=[venue_order_id] if venue_order_id else None,
+                )
+            else:
+                assert self._http_futures is not None  # Type checking
+                await retry_manager.run(
+                    "cancel_order",
+                    [client_order_id, venue_order_id],
+                    self._http_futures.cancel_order,
+                    client_order_id=client_order_id,
+                    venue_order_id=venue_order_id,
+                )
+            if not retry_manager.result:
+                self.generate_order_cancel_rejected(
+                    order.strategy_id,
+                    order.instrument_id,
+                    order.client_order_id,
+                    order.venue_order_id,
+                    retry_manager.message,
+                    self._clock.timestamp_ns(),
+                )
+
+    async def _cancel_all_orders(self, command: CancelAllOrders) -> None:
+        orders_open = self._cache.orders_open(instrument_id=command.instrument_id)
+
+        async with self._retry_manager_pool as retry_manager:
+            if self.is_spot:
+                # Kraken Spot can only cancel all orders across every pair,
+                # so cancel the open orders for the instrument by venue order ID.
+                venue_order_ids = [o.venue_order_id.value for o in orders_open if o.venue_order_id]
+                if not venue_order_ids:
+                    self._log.info(f"No open orders to cancel for {command.instrument_id}")
+                    return
+                await retry_manager.run(
+                    "cancel_all_orders",
+                    None,
+                    self._ws_private_client.cancel_order,
+                    order_ids=venue_order_ids,
+                )
+            else:
+                assert self._http_futures is not None  # Type checking
+                kraken_symbol = KrakenSymbol(command.instrument_id.symbol.value)
+                await retry_manager.run(
+                    "cancel_all_orders",
+                    None,
+                    self._http_futures.cancel_all_orders,
+                    symbol=kraken_symbol.raw_symbol,
+                )
+            if not retry_manager.result:
+                for order in orders_open:
+                    if order.is_closed:
+                        continue
+                    self.generate_order_cancel_rejected(
+                        order.strategy_id,
+                        order.instrument_id,
+                        order.client_order_id,
+                        order.venue_order_id,
+                        retry_manager.message,
+                        self._clock.timestamp_ns(),
+                    )
+
+    async def _modify_order(self, command: ModifyOrder) -> None:
+        order: Order | None = self._cache.order(command.client_order_id)
+        if order is None:
+            self._log.error(f"{command.client_order_id!r} not found in cache")
+            return
+
+        if order.is_closed:
+            self._log.warning(
+                f"`ModifyOrder` command for {command.client_order_id!r} when order already {order.status_string()} "
+                "(will not send to exchange)",
+            )
+            return
+
+        client_order_id = self._get_kraken_client_order_id(command.client_order_id)
+        venue_order_id = str(command.venue_order_id) if command.venue_order_id else None
+
+        async with self._retry_manager_pool as retry_manager:
+            if self.is_spot:
+                await retry_manager.run(
+                    "modify_order",
+                    [client_order_id, venue_order_id],
+                    self._ws_private_client.amend_order,
+                    cl_ord_id=client_order_id,
+                    order_id=venue_order_id,
+                    order_qty=float(command.quantity) if command.quantity else None,
+                    limit_price=float(command.price) if command.price else None,
+                    trigger_price=float(command.trigger_price) if command.trigger_price else None,
+                )
+            else:
+                assert self._http_futures is not None  # Type checking
+                await retry_manager.run(
+                    "modify_order",
+                    [client_order_id, venue_order_id],
+                    self._http_futures.edit_order,
+                    client_order_id=client_order_id,
+                    venue_order_id=venue_order_id,
+                    size=str(command.quantity) if command.quantity else None,
+                    limit_price=str(command.price) if command.price else None,
+                    stop_price=str(command.trigger_price) if command.trigger_price else None,
+                )
+            if not retry_manager.result:
+                self.generate_order_modify_rejected(
+                    order.strategy_id,
+                    order.instrument_id,
+                    order.client_order_id,
+                    order.venue_order_id,
+                    retry_manager.message,
+                    self._clock.timestamp_ns(),
+                )
+
+    async def _submit_order(self, command: SubmitOrder) -> None:
+        order = command.order
+        if order.is_closed:
+            self._log.warning(f"Order {order} is already closed")
+            return
+
+        if not self._check_order_validity(order):
+            return
+
+        # Generate order submitted event, to ensure correct ordering of event
+        self.generate_order_submitted(
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=order.client_order_id,
+            ts_event=self._clock.timestamp_ns(),
+        )
+
+        async with self._retry_manager_pool as retry_manager:
+            await retry_manager.run(
+                "submit_order",
+                [order.client_order_id],
+                self._submit_spot_order if self.is_spot else self._submit_futures_order,
+                order,
+            )
+            if not retry_manager.result:
+                self.generate_order_rejected(
+                    strategy_id=order.strategy_id,
+                    instrument_id=order.instrument_id,
+                    client_order_id=order.client_order_id,
+                    reason=retry_manager.message,
+                    ts_event=self._clock.timestamp_ns(),
+                )
+
+    def _check_order_validity(self, order: Order) -> bool:
+        if order.order_type in (OrderType.TRAILING_STOP_MARKET, OrderType.TRAILING_STOP_LIMIT):
+            self._log.error(
+                f"Cannot submit {order}: {order_type_to_str(order.order_type)} orders "
+                "not yet supported for Kraken",
+            )
+            return False
+
+        if order.is_quote_quantity:
+            self._log.error(f"Cannot submit {order}: quote quantity not supported for Kraken")
+            return False
+
+        if order.is_post_only and order.order_type != OrderType.LIMIT:
+            self._log.error(
+                f"Cannot submit {order} has invalid post only {order.is_post_only}, unsupported on Kraken",
+            )
+            return False
+
+        if self.is_spot:
+            if order.time_in_force not in (TimeInForce.GTC, TimeInForce.GTD, TimeInForce.IOC):
+                self._log.error(
+                    f"Cannot submit {order}: time in force "
+                    f"{time_in_force_to_str(order.time_in_force)} unsupported on Kraken Spot",
+                )
+                return False
+        elif order.time_in_force not in (TimeInForce.GTC, TimeInForce.IOC) and not (
+            order.time_in_force == TimeInForce.GTD and not self._use_gtd
+        ):
+            self._log.error(
+                f"Cannot submit {order}: time in force "
+                f"{time_in_force_to_str(order.time_in_force)} unsupported on Kraken Futures",
+            )
+            return False
+
+        return True
+
+    async def _submit_spot_order(self, order: Order) -> None:
+        kraken_symbol = KrakenSymbol(order.instrument_id.symbol.value)
+        order_type = self._enum_parser.parse_nautilus_spot_order_type(order.order_type)
+        order_side = self._enum_parser.parse_nautilus_order_side(order.side)
+
+        limit_price = None
+        if order.has_price:
+            limit_price = float(order.price)
+
+        triggers = None
+        if order.has_trigger_price:
+            triggers = {
+                "reference": self._enum_parser.parse_nautilus_spot_trigger_reference(
+                    order.trigger_type,
+                ).value,
+                "price": float(order.trigger_price),
+                "price_type": "static",
+            }
+
+        time_in_force = None
+        expire_time = None
+        if order.order_type != OrderType.MARKET:
+            nautilus_time_in_force = self._determine_time_in_force(order)
+            time_in_force = self._enum_parser.parse_nautilus_spot_time_in_force(
+                nautilus_time_in_force,
+            ).value
+            if nautilus_time_in_force == TimeInForce.GTD:
+                expire_time = unix_nanos_to_iso8601(order.expire_time_ns, nanos_precision=False)
+
+        await self._ws_private_client.add_order(
+            symbol=kraken_symbol.raw_symbol,
+            side=order_side.value,
+            order_type=order_type.value,
+            order_qty=float(order.quantity),
+            cl_ord_id=self._get_kraken_client_order_id(order.client_order_id),
+            limit_price=limit_price,
+            time_in_force=time_in_force,
+            expire_time=expire_time,
+            post_only=order.is_post_only,
+            reduce_only=order.is_reduce_only,
+            triggers=triggers,
+        )
+
+    async def _submit_futures_order(self, order: Order) -> None:
+        assert self._http_futures is not None  # Type checking
+        kraken_symbol = KrakenSymbol(order.instrument_id.symbol.value)
+        order_type = self._enum_parser.parse_nautilus_futures_order_type(
+            order.order_type,
+            order.time_in_force,
+            order.is_post_only,
+        )
+
+        trigger_signal = None
+        if order.has_trigger_price:
+            trigger_signal = self._enum_parser.parse_nautilus_futures_trigger_signal(
+                order.trigger_type,
+            )
+
+        result = await self._http_futures.send_order(
+            symbol=kraken_symbol.raw_symbol,
+            side=self._enum_parser.parse_nautilus_order_side(order.side),
+            order_type=order_type,
+            size=str(order.quantity),
+            client_order_id=self._get_kraken_client_order_id(order.client_order_id),
+            limit_price=str(order.price) if order.has_price else None,
+            stop_price=str(order.trigger_price) if order.has_trigger_price else None,
+            trigger_signal=trigger_signal,
+            reduce_only=order.is_reduce_only,
+        )
+
+        # The open orders feed may not have accepted the order yet
+        venue_order_id = result.order_id or result.orderId
+        current_order = self._cache.order(order.client_order_id)
+        if venue_order_id and current_order and current_order.status == OrderStatus.SUBMITTED:
+            self.generate_order_accepted(
+                strategy_id=order.strategy_id,
+                instrument_id=order.instrument_id,
+                client_order_id=order.client_order_id,
+                venue_order_id=VenueOrderId(venue_order_id),
+                ts_event=self._clock.timestamp_ns(),
+            )
+
+    # -- WEBSOCKET HANDLERS -----------------------------------------------------------------------
+
+    def _handle_ws_message_private(self, raw: bytes) -> None:
+        try:
+            if self.is_spot:
+                spot_msg = self._decoder_spot_ws_msg_general.decode(raw)
+                if spot_msg.channel == "executions":
+                    msg = self._decoder_spot_ws_executions.decode(raw)
+                    for execution in msg.data:
+                        self._handle_spot_execution(execution)
+                return
+
+            futures_msg = self._decoder_futures_ws_msg_general.decode(raw)
+            if futures_msg.feed == "open_orders":
+                self._handle_futures_open_orders(raw)
+            elif futures_msg.feed == "fills":
+                fills_msg = self._decoder_futures_ws_fills.decode(raw)
+                for fill in fills_msg.fills:
+                    self._handle_futures_fill(fill)
+            # Snapshots on subscription are ignored, as state is reconciled through reports
+        except Exception as e:
+            self._log.exception(f"Failed to handle websocket message: {raw.decode()}", e)
+
+    def _handle_spot_execution(self, execution: KrakenSpotWsExecution) -> None:  # noqa: C901
+        venue_order_id = VenueOrderId(execution.order_id)
+        client_order_id = self._parse_client_order_id(execution.cl_ord_id, venue_order_id)
+        if client_order_id is None:
+            self._log.debug(
+                f"Cannot process order execution for {venue_order_id!r}: no `ClientOrderId` found "
+                "(most likely due to being an external order)",
+            )
+            return
+
+        order = self._cache.order(client_order_id)
+        if order is None:
+            self._log.error(f"Cannot find {client_order_id!r}")
+            return
+
+        ts_event = dt_to_unix_nanos(execution.timestamp)
+
+        match execution.exec_type:
+            case KrakenSpotExecType.NEW:
+                if order.status == OrderStatus.SUBMITTED:
+                    self.generate_order_accepted(
+                        strategy_id=order.strategy_id,
+                        instrument_id=order.instrument_id,
+                        client_order_id=client_order_id,
+                        venue_order_id=venue_order_id,
+                        ts_event=ts_event,
+                    )
+            case KrakenSpotExecType.TRADE:
+                self._handle_spot_trade(order, execution, venue_order_id, ts_event)
+            case KrakenSpotExecType.CANCELED:
+                if order.status == OrderStatus.SUBMITTED:
+                    # Canceled before acceptance (e.g. post-only order would have taken liquidity)
+                    self.generate_order_rejected(
+                        strategy_id=order.strategy_id,
+                        instrument_id=order.instrument_id,
+                        client_order_id=client_order_id,
+                        reason=execution.reason or "canceled by venue",
+                        ts_event=ts_event,
+                    )
+                else:
+                    self.generate_order_canceled(
+                        strategy_id=order.strategy_id,
+                        instrument_id=order.instrument_id,
+                        client_order_id=client_order_id,
+                        venue_order_id=venue_order_id,
+                        ts_event=ts_event,
+                    )
+            case KrakenSpotExecType.EXPIRED:
+                self.generate_order_expired(
+                    strategy_id=order.strategy_id,
+                    instrument_id=order.instrument_id,
+                    client_order_id=client_order_id,
+                    venue_order_id=venue_order_id,
+                    ts_event=ts_event,
+                )
+            case KrakenSpotExecType.AMENDED | KrakenSpotExecType.RESTATED:
+                instrument = self._cache.instrument(order.instrument_id)
+                if instrument is None:
+                    self._log.error(f"Cannot handle amend: no instrument for {order.instrument_id}")
+                    return
+
+                trigger_price = order.trigger_price if order.has_trigger_price else None
+                if execution.triggers and execution.triggers.price is not None:
+                    trigger_price = instrument.make_price(execution.triggers.price)
+
+                self.generate_order_updated(
+                    strategy_id=order.strategy_id,
+                    instrument_id=order.instrument_id,
+                    client_order_id=client_order_id,
+                    venue_order_id=venue_order_id,
+                    quantity=(
+                        instrument.make_qty(execution.order_qty)
+                        if execution.order_qty is not None
+                        else order.quantity
+                    ),
+                    price=(
+                        instrument.make_price(execution.limit_price)
+                        if execution.limit_price is not None
+                        else (order.price if order.has_price else None)
+                    ),
+                    trigger_price=trigger_price,
+                    ts_event=ts_event,
+                )
+            case _:
+                # Remaining execution types are reflected by trades and order status events
+                self._log.debug(f"Ignoring execution {execution.exec_type.value} for {client_order_id!r}")
+
+    def _handle_spot_trade(
+        self,
+        order: Order,
+        execution: KrakenSpotWsExecution,
+        venue_order_id: VenueOrderId,
+        ts_event: int,
+    ) -> None:
+        instrument = self._cache.instrument(order.instrument_id)
+        if instrument is None:
+            raise ValueError(f"Cannot handle trade event: instrument {order.instrument_id} not found")
+
+        if execution.exec_id is None or execution.last_qty is None or execution.last_price is None:
+            self._log.error(f"Cannot handle trade event: incomplete execution {execution}")
+            return
+
+        commission = Money(0, instrument.quote_currency)
+        if execution.fees:
+            fee = execution.fees[0]
+            commission = Money(fee.qty, Currency.from_str(normalize_kraken_asset(fee.asset)))
+
+        is_maker = execution.liquidity_ind == "m"
+
+        self.generate_order_filled(
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=order.client_order_id,
+            venue_order_id=venue_order_id,
+            venue_position_id=None,
+            trade_id=TradeId(execution.exec_id),
+            order_side=order.side,
+            order_type=order.order_type,
+            last_qty=instrument.make_qty(execution.last_qty),
+            last_px=instrument.make_price(execution.last_price),
+            quote_currency=instrument.quote_currency,
+            commission=commission,
+            liquidity_side=LiquiditySide.MAKER if is_maker else LiquiditySide.TAKER,
+            ts_event=ts_event,
+        )
+
+        # Balances are not streamed for Kraken Spot
+        self.create_task(self._update_account_state())
+
+    def _handle_futures_open_orders(self, raw: bytes) -> None:
+        msg = self._decoder_futures_ws_open_orders.decode(raw)
+
+        if msg.order is not None:
+            venue_order_id = VenueOrderId(msg.order.order_id)
+            kraken_client_order_id = msg.order.cli_ord_id
+        elif msg.order_id is not None:
+            venue_order_id = VenueOrderId(msg.order_id)
+            kraken_client_order_id = msg.cli_ord_id
+        else:
+            return
+
+        client_order_id = self._parse_client_order_id(kraken_client_order_id, venue_order_id)
+        if client_order_id is None:
+            self._log.debug(
+                f"Cannot process order update for {venue_order_id!r}: no `ClientOrderId` found "
+                "(most likely due to being an external order)",
+            )
+            return
+
+        order = self._cache.order(client_order_id)
+        if order is None:
+            self._log.error(f"Cannot find {client_order_id!r}")
+            return
+
+        ts_event = self._clock.timestamp_ns()
+        if msg.order is not None:
+            ts_event = millis_to_nanos(msg.order.last_update_time)
+
+        if msg.is_cancel:
+            if msg.reason == "stop_order_triggered":
+                if order.order_type in _TRIGGERED_LIMIT_ORDER_TYPES:
+                    self.generate_order_triggered(
+                        strategy_id=order.strategy_id,
+                        instrument_id=order.instrument_id,
+                        client_order_id=client_order_id,
+                        venue_order_id=venue_order_id,
+                        ts_event=ts_event,
+                    )
+                return
+            if msg.reason in _FUTURES_NON_CANCEL_REASONS or order.is_closed:
+                return
+
+            self.generate_order_canceled(
+                strategy_id=order.strategy_id,
+                instrument_id=order.instrument_id,
+                client_order_id=client_order_id,
+                venue_order_id=venue_order_id,
+                ts_event=ts_event,
+            )
+            return
+
+        if msg.order is None:
+            return
+
+        if msg.reason == "new_placed_order_by_user":
+            if order.status == OrderStatus.SUBMITTED:
+                self.generate_order_accepted(
+                    strategy_id=order.strategy_id,
+                    instrument_id=order.instrument_id,
+                    client_order_id=client_order_id,
+                    venue_order_id=venue_order_id,
+                    ts_event=ts_event,
+                )
+        elif msg.reason == "edited_by_user":
+            instrument = self._cache.instrument(order.instrument_id)
+            if instrument is None:
+                self._log.error(f"Cannot handle edit: no instrument for {order.instrument_id}")
+                return
+
+            kraken_order = msg.order
+            self.generate_order_updated(
+                strategy_id=order.strategy_id,
+                instrument_id=order.instrument_id,
+                client_order_id=client_order_id,
+                venue_order_id=venue_order_id,
+                quantity=instrument.make_qty(kraken_order.qty),
+                price=(
+                    instrument.make_price(kraken_order.limit_price)
+                    if kraken_order.limit_price is not None
+                    else None
+                ),
+                trigger_price=(
+                    instrument.make_price(kraken_order.stop_price)
+                    if kraken_order.stop_price is not None
+                    else None
+                ),
+                ts_event=ts_event,
+            )
+
+    def _handle_futures_fill(self, fill: KrakenFuturesWsFill) -> None:
+        venue_order_id = VenueOrderId(fill.order_id)
+        client_order_id = self._parse_client_order_id(fill.cli_ord_id, venue_order_id)
+        if client_order_id is None:
+            self._log.debug(
+                f"Cannot process fill for {venue_order_id!r}: no `ClientOrderId` found "
+                "(most likely due to being an external order)",
+            )
+            return
+
+        order = self._cache.order(client_order_id)
+        if order is None:
+            self._log.error(f"Cannot find {client_order_id!r}")
+            return
+
+        instrument = self._get_futures_instrument(fill.instrument)
+        if instrument is None:
+            raise ValueError(f"Cannot handle fill: instrument for {fill.instrument} not found")
+
+        is_maker = fill.fill_type == "maker"
+        last_qty = instrument.make_qty(fill.qty)
+        last_px = instrument.make_price(fill.price)
+
+        if fill.fee_paid is not None and fill.fee_currency:
+            commission_currency = Currency.from_str(normalize_kraken_asset(fill.fee_currency))
+            commission = Money(fill.fee_paid, commission_currency)
+        else:
+            notional_value = instrument.notional_value(last_qty, last_px)
+            fee = instrument.maker_fee if is_maker else instrument.taker_fee
+            commission = Money(notional_value.as_decimal() * fee, notional_value.currency)
+
+        self.generate_order_filled(
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=client_order_id,
+            venue_order_id=venue_order_id,
+            venue_position_id=None,
+            trade_id=TradeId(fill.fill_id),
+            order_side=OrderSide.BUY if fill.buy else OrderSide.SELL,
+            order_type=order.order_type,
+            last_qty=last_qty,
+            last_px=last_px,
+            quote_currency=instrument.quote_currency,
+            commission=commission,
+            liquidity_side=LiquiditySide.MAKER if is_maker else LiquiditySide.TAKER,
+            ts_event=millis_to_nanos(fill.time),
+        )
+
+        # Margin account balances are not streamed on the subscribed feeds
+        self.create_task(self._update_account_state())
@@ -0,0 +1,306 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import asyncio
+from functools import lru_cache
+
+from nautilus_trader.adapters.kraken.common.constants import KRAKEN_FUTURES_PRIVATE_RATE_LIMIT_KEY
+from nautilus_trader.adapters.kraken.common.constants import KRAKEN_FUTURES_PUBLIC_RATE_LIMIT_KEY
+from nautilus_trader.adapters.kraken.common.constants import KRAKEN_SPOT_PRIVATE_RATE_LIMIT_KEY
+from nautilus_trader.adapters.kraken.common.constants import KRAKEN_SPOT_PUBLIC_RATE_LIMIT_KEY
+from nautilus_trader.adapters.kraken.common.credentials import get_api_key
+from nautilus_trader.adapters.kraken.common.credentials import get_api_secret
+from nautilus_trader.adapters.kraken.common.enums import KrakenProductType
+from nautilus_trader.adapters.kraken.common.urls import get_http_base_url
+from nautilus_trader.adapters.kraken.common.urls import get_ws_base_url_private
+from nautilus_trader.adapters.kraken.common.urls import get_ws_base_url_public
+from nautilus_trader.adapters.kraken.config import KrakenDataClientConfig
+from nautilus_trader.adapters.kraken.config import KrakenExecClientConfig
+from nautilus_trader.adapters.kraken.data import KrakenDataClient
+from nautilus_trader.adapters.kraken.execution import KrakenExecutionClient
+from nautilus_trader.adapters.kraken.http.client import KrakenHttpClient
+from nautilus_trader.adapters.kraken.providers import KrakenInstrumentProvider
+from nautilus_trader.cache.cache import Cache
+from nautilus_trader.common.component import LiveClock
+from nautilus_trader.common.component import MessageBus
+from nautilus_trader.config import InstrumentProviderConfig
+from nautilus_trader.core.nautilus_pyo3 import Quota
+from nautilus_trader.live.factories import LiveDataClientFactory
+from nautilus_trader.live.factories import LiveExecClientFactory
+
+
+@lru_cache(4)
+def get_cached_kraken_http_client(
+    clock: LiveClock,
+    product_type: KrakenProductType,
+    key: str,
+    secret: str,
+    base_url: str | None = None,
+    is_demo: bool = False,
+) -> KrakenHttpClient:
+    """
+    Cache and return a Kraken HTTP client for the given product type.
+
+    If a cached client with matching parameters already exists, the cached client will be returned.
+
+    Parameters
+    ----------
+    clock : LiveClock
+        The clock for the client.
+    product_type : KrakenProductType
+        The Kraken product type for the client (Spot and Futures are separate APIs).
+    key : str
+        The API key for the client (empty for public only access).
+    secret : str
+        The API secret for the client (empty for public only access).
+    base_url : str, optional
+        The base URL for the API endpoints.
+    is_demo : bool, default False
+        If the client is connecting to the Kraken Futures demo API.
+
+    Returns
+    -------
+    KrakenHttpClient
+
+    """
+    base_url = base_url or get_http_base_url(product_type, is_demo)
+
+    # Setup rate limit quotas
+    # https://docs.kraken.com/api/docs/guides/spot-rest-ratelimits
+    # https://docs.kraken.com/api/docs/guides/futures-rate-limits
+    if product_type == KrakenProductType.SPOT:
+        # Private endpoints use a decaying counter (starter tier: 15 max, -0.33/sec)
+        ratelimiter_default_quota = Quota.rate_per_second(1)
+        ratelimiter_quotas: list[tuple[str, Quota]] = [
+            (KRAKEN_SPOT_PUBLIC_RATE_LIMIT_KEY, Quota.rate_per_second(1)),
+            (KRAKEN_SPOT_PRIVATE_RATE_LIMIT_KEY, Quota.rate_per_minute(20)),
+        ]
+    else:
+        ratelimiter_default_quota = Quota.rate_per_second(5)
+        ratelimiter_quotas = [
+            (KRAKEN_FUTURES_PUBLIC_RATE_LIMIT_KEY, Quota.rate_per_second(5)),
+            (KRAKEN_FUTURES_PRIVATE_RATE_LIMIT_KEY, Quota.rate_per_second(5)),
+        ]
+
+    return KrakenHttpClient(
+        clock=clock,
+        api_key=key,
+        api_secret=secret,
+        base_url=base_url,
+        product_type=product_type,
+        ratelimiter_quotas=ratelimiter_quotas,
+        ratelimiter_default_quota=ratelimiter_default_quota,
+    )
+
+
+@lru_cache(1)
+def get_cached_kraken_instrument_provider(
+    clock: LiveClock,
+    product_types: tuple[KrakenProductType, ...],
+    spot_client: KrakenHttpClient | None,
+    futures_client: KrakenHttpClient | None,
+    config: InstrumentProviderConfig,
+) -> KrakenInstrumentProvider:
+    """
+    Cache and return a Kraken instrument provider.
+
+    If a cached provider already exists, then that provider will be returned.
+
+    Parameters
+    ----------
+    clock : LiveClock
+        The clock instance.
+    product_types : tuple[KrakenProductType, ...]
+        The product types to load.
+    spot_client : KrakenHttpClient, optional
+        The Kraken Spot HTTP client (required if loading `SPOT` instruments).
+    futures_client : KrakenHttpClient, optional
+        The Kraken Futures HTTP client (required if loading `FUTURES` instruments).
+    config : InstrumentProviderConfig
+        The instrument provider configuration.
+
+    Returns
+    -------
+    KrakenInstrumentProvider
+
+    """
+    return KrakenInstrumentProvider(
+        clock=clock,
+        product_types=list(product_types),
+        spot_client=spot_client,
+        futures_client=futures_client,
+        config=config,
+    )
+
+
+class KrakenLiveDataClientFactory(LiveDataClientFactory):
+    """
+    Provides a Kraken live data client factory.
+    """
+
+    @staticmethod
+    def create(  # type: ignore
+        loop: asyncio.AbstractEventLoop,
+        name: str,
+        config: KrakenDataClientConfig,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+    ) -> KrakenDataClient:
+        """
+        Create a new Kraken data client.
+
+        Parameters
+        ----------
+        loop : asyncio.AbstractEventLoop
+            The event loop for the client.
+        name : str
+            The custom client ID.
+        config : KrakenDataClientConfig
+            The client configuration.
+        msgbus : MessageBus
+            The message bus for the client.
+        cache : Cache
+            The cache for the client.
+        clock: LiveClock
+            The clock for the instrument provider.
+
+        Returns
+        -------
+        KrakenDataClient
+
+        """
+        product_types = config.product_types or list(KrakenProductType)
+
+        # Market data is public, so no credentials are required
+        spot_client: KrakenHttpClient | None = None
+        futures_client: KrakenHttpClient | None = None
+        if KrakenProductType.SPOT in product_types:
+            spot_client = get_cached_kraken_http_client(
+                clock=clock,
+                product_type=KrakenProductType.SPOT,
+                key="",
+                secret="",
+                base_url=config.base_url_http_spot,
+            )
+        if KrakenProductType.FUTURES in product_types:
+            futures_client = get_cached_kraken_http_client(
+                clock=clock,
+                product_type=KrakenProductType.FUTURES,
+                key="",
+                secret="",
+                base_url=config.base_url_http_futures,
+                is_demo=config.demo,
+            )
+
+        provider = get_cached_kraken_instrument_provider(
+            clock=clock,
+            product_types=tuple(product_types),
+            spot_client=spot_client,
+            futures_client=futures_client,
+            config=config.instrument_provider,
+        )
+        ws_base_urls: dict[KrakenProductType, str] = {}
+        for product_type in product_types:
+            ws_base_urls[product_type] = get_ws_base_url_public(
+                product_type,
+                config.demo and product_type == KrakenProductType.FUTURES,
+            )
+
+        return KrakenDataClient(
+            loop=loop,
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+            instrument_provider=provider,
+            product_types=product_types,
+            ws_base_urls=ws_base_urls,
+            config=config,
+            name=name,
+        )
+
+
+class KrakenLiveExecClientFactory(LiveExecClientFactory):
+    """
+    Provides a Kraken live execution client factory.
+    """
+
+    @staticmethod
+    def create(  # type: ignore
+        loop: asyncio.AbstractEventLoop,
+        name: str,
+        config: KrakenExecClientConfig,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+    ) -> KrakenExecutionClient:
+        """
+        Create a new Kraken execution client.
+
+        Parameters
+        ----------
+        loop : asyncio.AbstractEventLoop
+            The event loop for the client.
+        name : str
+            The custom client ID.
+        config : KrakenExecClientConfig
+            The client configuration.
+        msgbus : MessageBus
+            The message bus for the client.
+        cache : Cache
+            The cache for the client.
+        clock : LiveClock
+            The clock for the client.
+
+        Returns
+        -------
+        KrakenExecutionClient
+
+        """
+        product_types = config.product_types or [KrakenProductType.SPOT]
+        product_type = product_types[0]
+        is_demo = config.demo and product_type == KrakenProductType.FUTURES
+
+        client: KrakenHttpClient = get_cached_kraken_http_client(
+            clock=clock,
+            product_type=product_type,
+            key=config.api_key or get_api_key(product_type, is_demo),
+            secret=config.api_secret or get_api_secret(product_type, is_demo),
+            base_url=config.base_url_http,
+            is_demo=is_demo,
+        )
+        provider = get_cached_kraken_instrument_provider(
+            clock=clock,
+            product_types=tuple(product_types),
+            spot_client=client if product_type == KrakenProductType.SPOT else None,
+            futures_client=client if product_type == KrakenProductType.FUTURES else None,
+            config=config.instrument_provider,
+        )
+        base_url_ws_private = config.base_url_ws_private or get_ws_base_url_private(
+            product_type,
+            is_demo,
+        )
+        return KrakenExecutionClient(
+            loop=loop,
+            client=client,
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+            instrument_provider=provider,
+            product_types=product_types,
+            base_url_ws_private=base_url_ws_private,
+            config=config,
+            name=name,
+        )
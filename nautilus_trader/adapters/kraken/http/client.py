@@ -0,0 +1,231 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import base64
+import hashlib
+import hmac
+from typing import Any
+from urllib import parse
+
+import msgspec
+
+import nautilus_trader
+from nautilus_trader.adapters.kraken.common.enums import KrakenProductType
+from nautilus_trader.adapters.kraken.http.errors import KrakenError
+from nautilus_trader.common.component import LiveClock
+from nautilus_trader.common.component import Logger
+from nautilus_trader.core.nautilus_pyo3 import HttpClient
+from nautilus_trader.core.nautilus_pyo3 import HttpMethod
+from nautilus_trader.core.nautilus_pyo3 import HttpResponse
+from nautilus_trader.core.nautilus_pyo3 import Quota
+
+
+class KrakenSpotResponse(msgspec.Struct, frozen=True):
+    error: list[str]
+    result: Any | None = None
+
+
+class KrakenFuturesResponse(msgspec.Struct, frozen=True):
+    result: str
+    error: str | None = None
+    serverTime: str | None = None
+
+
+def kraken_sign(secret: str, message: bytes) -> str:
+    """
+    Return the base64 encoded HMAC-SHA512 signature of the given message.
+
+    Both Kraken Spot and Futures sign with the base64 decoded API secret.
+
+    """
+    digest = hmac.new(base64.b64decode(secret), message, hashlib.sha512).digest()
+    return base64.b64encode(digest).decode()
+
+
+class KrakenHttpClient:
+    """
+    Provides a Kraken asynchronous HTTP client.
+
+    Kraken Spot and Kraken Futures are separate APIs with separate credentials,
+    so a client is created per product type.
+
+    Parameters
+    ----------
+    clock : LiveClock
+        The clock for the client.
+    api_key : str
+        The Kraken API key for requests.
+    api_secret : str
+        The Kraken API secret for signed requests.
+    base_url : str
+        The base endpoint URL for the client.
+    product_type : KrakenProductType
+        The Kraken product type (API) for the client.
+    ratelimiter_quotas : list[tuple[str, Quota]], optional
+        The keyed rate limiter quotas for the client.
+    ratelimiter_default_quota : Quota, optional
+        The default rate limiter quota for the client.
+
+    """
+
+    def __init__(
+        self,
+        clock: LiveClock,
+        api_key: str,
+        api_secret: str,
+        base_url: str,
+        product_type: KrakenProductType,
+        ratelimiter_quotas: list[tuple[str, Quota]] | None = None,
+        ratelimiter_default_quota: Quota | None = None,
+    ) -> None:
+        self._clock: LiveClock = clock
+        self._log: Logger = Logger(name=type(self).__name__)
+        self._api_key: str = api_key
+        self._api_secret: str = api_secret
+        self._product_type: KrakenProductType = product_type
+
+        self._base_url: str = base_url
+        self._headers: dict[str, Any] = {
+            "User-Agent": nautilus_trader.USER_AGENT,
+        }
+        self._client = HttpClient(
+            keyed_quotas=ratelimiter_quotas or [],
+            default_quota=ratelimiter_default_quota,
+        )
+        self._decoder_spot_response = msgspec.json.Decoder(KrakenSpotResponse)
+        self._decoder_futures_response = msgspec.json.Decoder(KrakenFuturesResponse)
+        self._last_nonce: int = 0
+
+    @property
+    def api_key(self) -> str:
+        return self._api_key
+
+    @property
+    def api_secret(self) -> str:
+        return self._api_secret
+
+    @property
+    def base_url(self) -> str:
+        return self._base_url
+
+    @property
+    def product_type(self) -> KrakenProductType:
+        return self._product_type
+
+    async def send_request(
+        self,
+        http_method: HttpMethod,
+        url_path: str,
+        payload: dict[str, Any] | None = None,
+        headers: dict[str, str] | None = None,
+        ratelimiter_keys: list[str] | None = None,
+    ) -> bytes:
+        body: bytes | None = None
+        if payload:
+            encoded = parse.urlencode(payload)
+            if http_method == HttpMethod.GET:
+                url_path += "?" + encoded
+            else:
+                body = encoded.encode()
+
+        url = self._base_url + url_path
+        headers = {**self._headers, **(headers or {})}
+        if body is not None:
+            headers["Content-Type"] = "application/x-www-form-urlencoded"
+
+        response: HttpResponse = await self._client.request(
+            http_method,
+            url,
+            headers,
+            body,
+            ratelimiter_keys,
+        )
+
+        response_body = response.body
+
+        if response.status >= 400:
+            try:
+                message = msgspec.json.decode(response_body) if response_body else None
+            except msgspec.DecodeError:
+                message = response_body.decode()
+
+            raise KrakenError(
+                code=response.status,
+                message=message,
+            )
+
+        self._check_response(response_body)
+        return response_body
+
+    async def sign_request(
+        self,
+        http_method: HttpMethod,
+        url_path: str,
+        payload: dict[str, Any] | None = None,
+        ratelimiter_keys: list[str] | None = None,
+    ) -> bytes:
+        if payload is None:
+            payload = {}
+
+        nonce = self._next_nonce()
+        if self._product_type == KrakenProductType.SPOT:
+            payload = {"nonce": nonce, **payload}
+            headers = {
+                "API-Key": self._api_key,
+                "API-Sign": self._sign_spot_request(url_path, nonce, payload),
+            }
+        else:
+            headers = {
+                "APIKey": self._api_key,
+                "Nonce": nonce,
+                "Authent": self._sign_futures_request(url_path, nonce, payload),
+            }
+
+        return await self.send_request(
+            http_method=http_method,
+            url_path=url_path,
+            payload=payload,
+            headers=headers,
+            ratelimiter_keys=ratelimiter_keys,
+        )
+
+    def _next_nonce(self) -> str:
+        # Nonces must be strictly increasing per API key
+        nonce = max(self._clock.timestamp_us(), self._last_nonce + 1)
+        self._last_nonce = nonce
+        return str(nonce)
+
+    def _sign_spot_request(self, url_path: str, nonce: str, payload: dict[str, Any]) -> str:
+        post_data = parse.urlencode(payload)
+        sha256 = hashlib.sha256((nonce + post_data).encode()).digest()
+        return kraken_sign(self._api_secret, url_path.encode() + sha256)
+
+    def _sign_futures_request(self, url_path: str, nonce: str, payload: dict[str, Any]) -> str:
+        post_data = parse.urlencode(payload)
+        endpoint_path = url_path.removeprefix("/derivatives")
+        sha256 = hashlib.sha256((post_data + nonce + endpoint_path).encode()).digest()
+        return kraken_sign(self._api_secret, sha256)
+
+    def _check_response(self, response_body: bytes) -> None:
+        if self._product_type == KrakenProductType.SPOT:
+            spot_resp: KrakenSpotResponse = self._decoder_spot_response.decode(response_body)
+            if spot_resp.error:
+                raise KrakenError(code=spot_resp.error[0], message=", ".join(spot_resp.error))
+        else:
+            futures_resp: KrakenFuturesResponse = self._decoder_futures_response.decode(
+                response_body,
+            )
+            if futures_resp.result != "success":
+                raise KrakenError(code=futures_resp.error, message=futures_resp.error)
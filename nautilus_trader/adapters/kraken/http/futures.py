@@ -0,0 +1,209 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+from typing import Any
+
+import msgspec
+
+from nautilus_trader.adapters.kraken.common.constants import KRAKEN_FUTURES_PRIVATE_RATE_LIMIT_KEY
+from nautilus_trader.adapters.kraken.common.constants import KRAKEN_FUTURES_PUBLIC_RATE_LIMIT_KEY
+from nautilus_trader.adapters.kraken.common.enums import KrakenFuturesOrderType
+from nautilus_trader.adapters.kraken.common.enums import KrakenFuturesTriggerSignal
+from nautilus_trader.adapters.kraken.common.enums import KrakenOrderSide
+from nautilus_trader.adapters.kraken.http.errors import KrakenError
+from nautilus_trader.adapters.kraken.schemas.futures import KrakenFuturesAccountsResponse
+from nautilus_trader.adapters.kraken.schemas.futures import KrakenFuturesCancelOrderResponse
+from nautilus_trader.adapters.kraken.schemas.futures import KrakenFuturesEditOrderResponse
+from nautilus_trader.adapters.kraken.schemas.futures import KrakenFuturesFillsResponse
+from nautilus_trader.adapters.kraken.schemas.futures import KrakenFuturesInstrumentsResponse
+from nautilus_trader.adapters.kraken.schemas.futures import KrakenFuturesOpenOrdersResponse
+from nautilus_trader.adapters.kraken.schemas.futures import KrakenFuturesOpenPositionsResponse
+from nautilus_trader.adapters.kraken.schemas.futures import KrakenFuturesSendOrderResponse
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.core.nautilus_pyo3 import HttpMethod
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.adapters.kraken.http.client import KrakenHttpClient
+    from nautilus_trader.adapters.kraken.schemas.futures import KrakenFuturesFill
+    from nautilus_trader.adapters.kraken.schemas.futures import KrakenFuturesInstrument
+    from nautilus_trader.adapters.kraken.schemas.futures import KrakenFuturesOpenOrder
+    from nautilus_trader.adapters.kraken.schemas.futures import KrakenFuturesOpenPosition
+    from nautilus_trader.adapters.kraken.schemas.futures import KrakenFuturesOrderStatusResult
+    from nautilus_trader.common.component import LiveClock
+
+
+class KrakenFuturesHttpAPI:
+    """
+    Provides access to the Kraken Futures REST API.
+
+    Parameters
+    ----------
+    client : KrakenHttpClient
+        The Kraken Futures HTTP client.
+    clock : LiveClock
+        The clock for the API client.
+
+    """
+
+    def __init__(
+        self,
+        client: KrakenHttpClient,
+        clock: LiveClock,
+    ) -> None:
+        PyCondition.not_none(client, "client")
+        self.client = client
+        self._clock = clock
+        self.base_endpoint = "/derivatives/api/v3/"
+
+        self._decoder_instruments = msgspec.json.Decoder(KrakenFuturesInstrumentsResponse)
+        self._decoder_accounts = msgspec.json.Decoder(KrakenFuturesAccountsResponse)
+        self._decoder_open_orders = msgspec.json.Decoder(KrakenFuturesOpenOrdersResponse)
+        self._decoder_fills = msgspec.json.Decoder(KrakenFuturesFillsResponse)
+        self._decoder_open_positions = msgspec.json.Decoder(KrakenFuturesOpenPositionsResponse)
+        self._decoder_send_order = msgspec.json.Decoder(KrakenFuturesSendOrderResponse)
+        self._decoder_edit_order = msgspec.json.Decoder(KrakenFuturesEditOrderResponse)
+        self._decoder_cancel_order = msgspec.json.Decoder(KrakenFuturesCancelOrderResponse)
+
+    async def _get_public(self, endpoint: str) -> bytes:
+        return await self.client.send_request(
+            http_method=HttpMethod.GET,
+            url_path=self.base_endpoint + endpoint,
+            ratelimiter_keys=[KRAKEN_FUTURES_PUBLIC_RATE_LIMIT_KEY],
+        )
+
+    async def _sign(
+        self,
+        http_method: HttpMethod,
+        endpoint: str,
+        payload: dict[str, Any] | None = None,
+    ) -> bytes:
+        return await self.client.sign_request(
+            http_method=http_method,
+            url_path=self.base_endpoint + endpoint,
+            payload=payload,
+            ratelimiter_keys=[KRAKEN_FUTURES_PRIVATE_RATE_LIMIT_KEY],
+        )
+
+    def _check_status(
+        self,
+        result: KrakenFuturesOrderStatusResult,
+        expected: tuple[str, ...],
+    ) -> KrakenFuturesOrderStatusResult:
+        # Order requests succeed at the HTTP level, with the outcome in the status
+        if result.status not in expected:
+            raise KrakenError(code=result.status, message=result.status)
+        return result
+
+    async def fetch_instruments(self) -> list[KrakenFuturesInstrument]:
+        raw = await self._get_public("instruments")
+        return self._decoder_instruments.decode(raw).instruments
+
+    async def fetch_accounts(self) -> KrakenFuturesAccountsResponse:
+        raw = await self._sign(HttpMethod.GET, "accounts")
+        return self._decoder_accounts.decode(raw)
+
+    async def fetch_open_orders(self) -> list[KrakenFuturesOpenOrder]:
+        raw = await self._sign(HttpMethod.GET, "openorders")
+        return self._decoder_open_orders.decode(raw).openOrders
+
+    async def fetch_fills(self, last_fill_time: str | None = None) -> list[KrakenFuturesFill]:
+        payload = {"lastFillTime": last_fill_time} if last_fill_time else None
+        raw = await self._sign(HttpMethod.GET, "fills", payload)
+        return self._decoder_fills.decode(raw).fills
+
+    async def fetch_open_positions(self) -> list[KrakenFuturesOpenPosition]:
+        raw = await self._sign(HttpMethod.GET, "openpositions")
+        return self._decoder_open_positions.decode(raw).openPositions
+
+    async def send_order(
+        self,
+        symbol: str,
+        side: KrakenOrderSide,
+        order_type: KrakenFuturesOrderType,
+        size: str,
+        client_order_id: str,
+        limit_price: str | None = None,
+        stop_price: str | None = None,
+        trigger_signal: KrakenFuturesTriggerSignal | None = None,
+        reduce_only: bool = False,
+    ) -> KrakenFuturesOrderStatusResult:
+        payload: dict[str, Any] = {
+            "orderType": order_type.value,
+            "symbol": symbol,
+            "side": side.value,
+            "size": size,
+            "cliOrdId": client_order_id,
+        }
+        if limit_price is not None:
+            payload["limitPrice"] = limit_price
+        if stop_price is not None:
+            payload["stopPrice"] = stop_price
+        if trigger_signal is not None:
+            payload["triggerSignal"] = trigger_signal.value
+        if reduce_only:
+            payload["reduceOnly"] = "true"
+
+        raw = await self._sign(HttpMethod.POST, "sendorder", payload)
+        result = self._decoder_send_order.decode(raw).sendStatus
+        return self._check_status(result, ("placed",))
+
+    async def edit_order(
+        self,
+        client_order_id: str | None = None,
+        venue_order_id: str | None = None,
+        size: str | None = None,
+        limit_price: str | None = None,
+        stop_price: str | None = None,
+    ) -> KrakenFuturesOrderStatusResult:
+        payload: dict[str, Any] = {}
+        if venue_order_id is not None:
+            payload["orderId"] = venue_order_id
+        elif client_order_id is not None:
+            payload["cliOrdId"] = client_order_id
+        if size is not None:
+            payload["size"] = size
+        if limit_price is not None:
+            payload["limitPrice"] = limit_price
+        if stop_price is not None:
+            payload["stopPrice"] = stop_price
+
+        raw = await self._sign(HttpMethod.POST, "editorder", payload)
+        result = self._decoder_edit_order.decode(raw).editStatus
+        return self._check_status(result, ("edited",))
+
+    async def cancel_order(
+        self,
+        client_order_id: str | None = None,
+        venue_order_id: str | None = None,
+    ) -> KrakenFuturesOrderStatusResult:
+        payload: dict[str, Any] = {}
+        if venue_order_id is not None:
+            payload["order_id"] = venue_order_id
+        elif client_order_id is not None:
+            payload["cliOrdId"] = client_order_id
+
+        raw = await self._sign(HttpMethod.POST, "cancelorder", payload)
+        result = self._decoder_cancel_order.decode(raw).cancelStatus
+        return self._check_status(result, ("cancelled",))
+
+    async def cancel_all_orders(self, symbol: str | None = None) -> KrakenFuturesOrderStatusResult:
+        payload = {"symbol": symbol} if symbol else None
+        raw = await self._sign(HttpMethod.POST, "cancelallorders", payload)
+        result = self._decoder_cancel_order.decode(raw).cancelStatus
+        return self._check_status(result, ("cancelled", "noOrdersToCancel"))
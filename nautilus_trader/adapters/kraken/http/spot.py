@@ -0,0 +1,137 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import msgspec
+
+from nautilus_trader.adapters.kraken.common.constants import KRAKEN_SPOT_PRIVATE_RATE_LIMIT_KEY
+from nautilus_trader.adapters.kraken.common.constants import KRAKEN_SPOT_PUBLIC_RATE_LIMIT_KEY
+from nautilus_trader.adapters.kraken.schemas.spot import KrakenSpotAssetPairsResponse
+from nautilus_trader.adapters.kraken.schemas.spot import KrakenSpotBalanceResponse
+from nautilus_trader.adapters.kraken.schemas.spot import KrakenSpotClosedOrdersResponse
+from nautilus_trader.adapters.kraken.schemas.spot import KrakenSpotOpenOrdersResponse
+from nautilus_trader.adapters.kraken.schemas.spot import KrakenSpotQueryOrdersResponse
+from nautilus_trader.adapters.kraken.schemas.spot import KrakenSpotTradesHistoryResponse
+from nautilus_trader.adapters.kraken.schemas.spot import KrakenSpotWsTokenResponse
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.core.nautilus_pyo3 import HttpMethod
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.adapters.kraken.http.client import KrakenHttpClient
+    from nautilus_trader.adapters.kraken.schemas.spot import KrakenSpotAssetPair
+    from nautilus_trader.adapters.kraken.schemas.spot import KrakenSpotBalance
+    from nautilus_trader.adapters.kraken.schemas.spot import KrakenSpotOrder
+    from nautilus_trader.adapters.kraken.schemas.spot import KrakenSpotTrade
+    from nautilus_trader.common.component import LiveClock
+
+
+class KrakenSpotHttpAPI:
+    """
+    Provides access to the Kraken Spot REST API.
+
+    Parameters
+    ----------
+    client : KrakenHttpClient
+        The Kraken Spot HTTP client.
+    clock : LiveClock
+        The clock for the API client.
+
+    """
+
+    def __init__(
+        self,
+        client: KrakenHttpClient,
+        clock: LiveClock,
+    ) -> None:
+        PyCondition.not_none(client, "client")
+        self.client = client
+        self._clock = clock
+        self.base_endpoint_public = "/0/public/"
+        self.base_endpoint_private = "/0/private/"
+
+        self._decoder_asset_pairs = msgspec.json.Decoder(KrakenSpotAssetPairsResponse)
+        self._decoder_ws_token = msgspec.json.Decoder(KrakenSpotWsTokenResponse)
+        self._decoder_balance = msgspec.json.Decoder(KrakenSpotBalanceResponse)
+        self._decoder_open_orders = msgspec.json.Decoder(KrakenSpotOpenOrdersResponse)
+        self._decoder_closed_orders = msgspec.json.Decoder(KrakenSpotClosedOrdersResponse)
+        self._decoder_query_orders = msgspec.json.Decoder(KrakenSpotQueryOrdersResponse)
+        self._decoder_trades_history = msgspec.json.Decoder(KrakenSpotTradesHistoryResponse)
+
+    async def _get_public(self, endpoint: str, payload: dict | None = None) -> bytes:
+        return await self.client.send_request(
+            http_method=HttpMethod.GET,
+            url_path=self.base_endpoint_public + endpoint,
+            payload=payload,
+            ratelimiter_keys=[KRAKEN_SPOT_PUBLIC_RATE_LIMIT_KEY],
+        )
+
+    async def _post_private(self, endpoint: str, payload: dict | None = None) -> bytes:
+        return await self.client.sign_request(
+            http_method=HttpMethod.POST,
+            url_path=self.base_endpoint_private + endpoint,
+            payload=payload,
+            ratelimiter_keys=[KRAKEN_SPOT_PRIVATE_RATE_LIMIT_KEY],
+        )
+
+    async def fetch_asset_pairs(self) -> dict[str, KrakenSpotAssetPair]:
+        raw = await self._get_public("AssetPairs")
+        return self._decoder_asset_pairs.decode(raw).result
+
+    async def fetch_ws_token(self) -> str:
+        raw = await self._post_private("GetWebSocketsToken")
+        return self._decoder_ws_token.decode(raw).result.token
+
+    async def fetch_balances(self) -> dict[str, KrakenSpotBalance]:
+        raw = await self._post_private("BalanceEx")
+        return self._decoder_balance.decode(raw).result
+
+    async def fetch_open_orders(self) -> dict[str, KrakenSpotOrder]:
+        raw = await self._post_private("OpenOrders")
+        return self._decoder_open_orders.decode(raw).result.open
+
+    async def fetch_closed_orders(
+        self,
+        start_secs: int | None = None,
+        end_secs: int | None = None,
+    ) -> dict[str, KrakenSpotOrder]:
+        payload: dict[str, int] = {}
+        if start_secs is not None:
+            payload["start"] = start_secs
+        if end_secs is not None:
+            payload["end"] = end_secs
+        raw = await self._post_private("ClosedOrders", payload)
+        return self._decoder_closed_orders.decode(raw).result.closed
+
+    async def query_orders(self, venue_order_ids: list[str]) -> dict[str, KrakenSpotOrder]:
+        # Up to 50 transaction IDs per request
+        raw = await self._post_private("QueryOrders", {"txid": ",".join(venue_order_ids)})
+        return self._decoder_query_orders.decode(raw).result
+
+    async def fetch_trades_history(
+        self,
+        start_secs: int | None = None,
+        end_secs: int | None = None,
+    ) -> dict[str, KrakenSpotTrade]:
+        payload: dict[str, int] = {}
+        if start_secs is not None:
+            payload["start"] = start_secs
+        if end_secs is not None:
+            payload["end"] = end_secs
+        raw = await self._post_private("TradesHistory", payload)
+        return self._decoder_trades_history.decode(raw).result.trades
@@ -0,0 +1,205 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from decimal import Decimal
+from typing import TYPE_CHECKING
+
+from nautilus_trader.adapters.kraken.common.constants import KRAKEN_FUTURES_DEFAULT_MAKER_FEE
+from nautilus_trader.adapters.kraken.common.constants import KRAKEN_FUTURES_DEFAULT_TAKER_FEE
+from nautilus_trader.adapters.kraken.common.constants import KRAKEN_VENUE
+from nautilus_trader.adapters.kraken.common.enums import KrakenFuturesInstrumentType
+from nautilus_trader.adapters.kraken.common.enums import KrakenProductType
+from nautilus_trader.adapters.kraken.http.futures import KrakenFuturesHttpAPI
+from nautilus_trader.adapters.kraken.http.spot import KrakenSpotHttpAPI
+from nautilus_trader.common.providers import InstrumentProvider
+from nautilus_trader.core.correctness import PyCondition
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.adapters.kraken.http.client import KrakenHttpClient
+    from nautilus_trader.adapters.kraken.schemas.futures import KrakenFuturesInstrument
+    from nautilus_trader.adapters.kraken.schemas.spot import KrakenSpotAssetPair
+    from nautilus_trader.common.component import LiveClock
+    from nautilus_trader.config import InstrumentProviderConfig
+    from nautilus_trader.model.identifiers import InstrumentId
+
+
+class KrakenInstrumentProvider(InstrumentProvider):
+    """
+    Provides Nautilus instrument definitions from Kraken Spot and Kraken Futures.
+
+    Parameters
+    ----------
+    clock : LiveClock
+        The clock instance.
+    product_types : list[KrakenProductType]
+        The product types to load.
+    spot_client : KrakenHttpClient, optional
+        The Kraken Spot HTTP client (required to load `SPOT` instruments).
+    futures_client : KrakenHttpClient, optional
+        The Kraken Futures HTTP client (required to load `FUTURES` instruments).
+    config : InstrumentProviderConfig, optional
+        The instrument provider configuration, by default None.
+
+    """
+
+    def __init__(
+        self,
+        clock: LiveClock,
+        product_types: list[KrakenProductType],
+        spot_client: KrakenHttpClient | None = None,
+        futures_client: KrakenHttpClient | None = None,
+        config: InstrumentProviderConfig | None = None,
+    ) -> None:
+        super().__init__(config=config)
+        if KrakenProductType.SPOT in product_types:
+            PyCondition.not_none(spot_client, "spot_client")
+        if KrakenProductType.FUTURES in product_types:
+            PyCondition.not_none(futures_client, "futures_client")
+
+        self._clock = clock
+        self._product_types = product_types
+
+        self._http_spot = KrakenSpotHttpAPI(spot_client, clock) if spot_client else None
+        self._http_futures = KrakenFuturesHttpAPI(futures_client, clock) if futures_client else None
+
+        # Kraken Spot REST responses reference pairs by key or altname (e.g. `XXBTZUSD`)
+        self._spot_pair_ids: dict[str, InstrumentId] = {}
+
+        self._log_warnings = config.log_warnings if config else True
+
+    async def load_all_async(self, filters: dict | None = None) -> None:
+        filters_str = "..." if not filters else f" with filters {filters}..."
+        self._log.info(f"Loading all instruments{filters_str}")
+
+        await self._load_instruments()
+
+        self._log.info(f"Loaded {len(self._instruments)} instruments")
+
+    async def load_ids_async(
+        self,
+        instrument_ids: list[InstrumentId],
+        filters: dict | None = None,
+    ) -> None:
+        if not instrument_ids:
+            self._log.warning("No instrument IDs given for loading")
+            return
+
+        # Check all instrument IDs
+        for instrument_id in instrument_ids:
+            PyCondition.equal(instrument_id.venue, KRAKEN_VENUE, "instrument_id.venue", "KRAKEN")
+
+        await self._load_instruments(set(instrument_ids))
+
+    async def load_async(self, instrument_id: InstrumentId, filters: dict | None = None) -> None:
+        PyCondition.not_none(instrument_id, "instrument_id")
+        await self.load_ids_async([instrument_id], filters)
+
+    def find_spot_instrument_id(self, pair: str) -> InstrumentId | None:
+        """
+        Return the instrument ID for the given Kraken Spot REST `pair` name (if found).
+
+        Parameters
+        ----------
+        pair : str
+            The Kraken Spot pair key or altname.
+
+        Returns
+        -------
+        InstrumentId or ``None``
+
+        """
+        return self._spot_pair_ids.get(pair)
+
+    async def _load_instruments(self, instrument_ids: set[InstrumentId] | None = None) -> None:
+        # Kraken has no endpoints for single instruments, so all instruments are
+        # requested and filtered if specific instrument IDs were given.
+        if KrakenProductType.SPOT in self._product_types:
+            assert self._http_spot is not None  # Checked in constructor
+            pairs = await self._http_spot.fetch_asset_pairs()
+            for key, pair in pairs.items():
+                self._parse_spot_instrument(key, pair, instrument_ids)
+
+        if KrakenProductType.FUTURES in self._product_types:
+            assert self._http_futures is not None  # Checked in constructor
+            instruments = await self._http_futures.fetch_instruments()
+            for instrument in instruments:
+                self._parse_futures_instrument(instrument, instrument_ids)
+
+    def _parse_spot_instrument(
+        self,
+        key: str,
+        pair: KrakenSpotAssetPair,
+        instrument_ids: set[InstrumentId] | None,
+    ) -> None:
+        if pair.status not in (None, "online"):
+            return
+
+        try:
+            base_currency = self.currency(pair.base_code)
+            quote_currency = self.currency(pair.quote_code)
+            self.add_currency(base_currency)
+            self.add_currency(quote_currency)
+            ts_event = self._clock.timestamp_ns()
+            ts_init = self._clock.timestamp_ns()
+            instrument = pair.parse_to_instrument(
+                base_currency=base_currency,
+                quote_currency=quote_currency,
+                ts_event=ts_event,
+                ts_init=ts_init,
+            )
+        except ValueError as e:
+            if self._log_warnings:
+                self._log.warning(f"Unable to parse spot instrument {key}: {e}")
+            return
+
+        self._spot_pair_ids[key] = instrument.id
+        self._spot_pair_ids[pair.altname] = instrument.id
+
+        if instrument_ids is None or instrument.id in instrument_ids:
+            self.add(instrument=instrument)
+
+    def _parse_futures_instrument(
+        self,
+        data: KrakenFuturesInstrument,
+        instrument_ids: set[InstrumentId] | None,
+    ) -> None:
+        if not data.tradeable or data.type == KrakenFuturesInstrumentType.SPOT_INDEX:
+            return
+
+        try:
+            base_currency = self.currency(data.base_code)
+            quote_currency = self.currency(data.quote_code)
+            self.add_currency(base_currency)
+            self.add_currency(quote_currency)
+            ts_event = self._clock.timestamp_ns()
+            ts_init = self._clock.timestamp_ns()
+            instrument = data.parse_to_instrument(
+                base_currency=base_currency,
+                quote_currency=quote_currency,
+                maker_fee=Decimal(KRAKEN_FUTURES_DEFAULT_MAKER_FEE),
+                taker_fee=Decimal(KRAKEN_FUTURES_DEFAULT_TAKER_FEE),
+                ts_event=ts_event,
+                ts_init=ts_init,
+            )
+        except ValueError as e:
+            if self._log_warnings:
+                self._log.warning(f"Unable to parse futures instrument {data.symbol}: {e}")
+            return
+
+        if instrument_ids is None or instrument.id in instrument_ids:
+            self.add(instrument=instrument)
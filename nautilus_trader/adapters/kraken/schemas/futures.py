@@ -0,0 +1,465 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+import datetime
+from collections import defaultdict
+from decimal import Decimal
+
+import msgspec
+
+from nautilus_trader.adapters.kraken.common.enums import KrakenEnumParser
+from nautilus_trader.adapters.kraken.common.enums import KrakenFuturesInstrumentType
+from nautilus_trader.adapters.kraken.common.enums import KrakenFuturesOrderStatus
+from nautilus_trader.adapters.kraken.common.enums import KrakenFuturesTriggerSignal
+from nautilus_trader.adapters.kraken.common.enums import KrakenOrderSide
+from nautilus_trader.adapters.kraken.common.enums import KrakenProductType
+from nautilus_trader.adapters.kraken.common.parsing import normalize_kraken_asset
+from nautilus_trader.adapters.kraken.common.symbol import KrakenSymbol
+from nautilus_trader.core.datetime import dt_to_unix_nanos
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.execution.reports import FillReport
+from nautilus_trader.execution.reports import OrderStatusReport
+from nautilus_trader.execution.reports import PositionStatusReport
+from nautilus_trader.model.enums import ContingencyType
+from nautilus_trader.model.enums import LiquiditySide
+from nautilus_trader.model.enums import PositionSide
+from nautilus_trader.model.enums import TimeInForce
+from nautilus_trader.model.enums import TriggerType
+from nautilus_trader.model.identifiers import AccountId
+from nautilus_trader.model.identifiers import ClientOrderId
+from nautilus_trader.model.identifiers import Symbol
+from nautilus_trader.model.identifiers import TradeId
+from nautilus_trader.model.identifiers import VenueOrderId
+from nautilus_trader.model.instruments import CryptoFuture
+from nautilus_trader.model.instruments import CryptoPerpetual
+from nautilus_trader.model.instruments import Instrument
+from nautilus_trader.model.objects import AccountBalance
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import MarginBalance
+from nautilus_trader.model.objects import Money
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+
+
+# Perpetual contract symbol prefixes (`PF_` multi-collateral linear, `PI_` inverse)
+KRAKEN_FUTURES_PERPETUAL_PREFIXES = ("PF_", "PI_")
+
+
+################################################################################
+# Market
+################################################################################
+
+
+class KrakenFuturesInstrument(msgspec.Struct):
+    symbol: str
+    type: KrakenFuturesInstrumentType
+    tradeable: bool
+    tickSize: float | None = None
+    contractSize: float | None = None
+    contractValueTradePrecision: int | None = None
+    base: str | None = None
+    quote: str | None = None
+    pair: str | None = None
+    underlying: str | None = None
+    openingDate: datetime.datetime | None = None
+    lastTradingTime: datetime.datetime | None = None
+    postOnly: bool | None = None
+
+    @property
+    def is_perpetual(self) -> bool:
+        return self.symbol.upper().startswith(KRAKEN_FUTURES_PERPETUAL_PREFIXES)
+
+    @property
+    def is_inverse(self) -> bool:
+        return self.type == KrakenFuturesInstrumentType.FUTURES_INVERSE
+
+    @property
+    def base_code(self) -> str:
+        if self.base:
+            return normalize_kraken_asset(self.base)
+        if self.pair:
+            return normalize_kraken_asset(self.pair.partition(":")[0])
+        # Older contracts only carry the underlying pair in the symbol, e.g. `PI_XBTUSD`
+        underlying = self.symbol.upper().split("_")[1]
+        return normalize_kraken_asset(underlying[:-3])
+
+    @property
+    def quote_code(self) -> str:
+        if self.quote:
+            return normalize_kraken_asset(self.quote)
+        if self.pair:
+            return normalize_kraken_asset(self.pair.partition(":")[2])
+        underlying = self.symbol.upper().split("_")[1]
+        return normalize_kraken_asset(underlying[-3:])
+
+    def parse_to_instrument(
+        self,
+        base_currency: Currency,
+        quote_currency: Currency,
+        maker_fee: Decimal,
+        taker_fee: Decimal,
+        ts_event: int,
+        ts_init: int,
+    ) -> CryptoPerpetual | CryptoFuture:
+        assert base_currency.code == self.base_code
+        assert quote_currency.code == self.quote_code
+        if self.tickSize is None:
+            raise ValueError("no `tickSize` for instrument")
+
+        kraken_symbol = KrakenSymbol.from_raw(self.symbol.upper(), KrakenProductType.FUTURES)
+        instrument_id = kraken_symbol.to_instrument_id()
+        settlement_currency = base_currency if self.is_inverse else quote_currency
+
+        price_increment = Price.from_str(str(Decimal(str(self.tickSize)).normalize()))
+
+        # Trade precision can be negative, where sizes must be a multiple of a power of ten
+        size_precision = self.contractValueTradePrecision or 0
+        if size_precision >= 0:
+            size_increment = Quantity.from_str(
+                format(Decimal(10) ** -size_precision, f".{size_precision}f"),
+            )
+        else:
+            size_increment = Quantity.from_int(10**-size_precision)
+
+        multiplier = Quantity.from_str(str(Decimal(str(self.contractSize or 1)).normalize()))
+        info = msgspec.json.Decoder().decode(msgspec.json.Encoder().encode(self))
+
+        if self.is_perpetual:
+            return CryptoPerpetual(
+                instrument_id=instrument_id,
+                raw_symbol=Symbol(kraken_symbol.raw_symbol),
+                base_currency=base_currency,
+                quote_currency=quote_currency,
+                settlement_currency=settlement_currency,
+                is_inverse=self.is_inverse,
+                price_precision=price_increment.precision,
+                size_precision=size_increment.precision,
+                price_increment=price_increment,
+                size_increment=size_increment,
+                multiplier=multiplier,
+                max_quantity=None,
+                min_quantity=size_increment,
+                max_notional=None,
+                min_notional=None,
+                max_price=None,
+                min_price=None,
+                margin_init=Decimal("0.1"),
+                margin_maint=Decimal("0.1"),
+                maker_fee=maker_fee,
+                taker_fee=taker_fee,
+                ts_event=ts_event,
+                ts_init=ts_init,
+                info=info,
+            )
+
+        if self.lastTradingTime is None:
+            raise ValueError("no `lastTradingTime` for fixed maturity instrument")
+
+        activation_ns = dt_to_unix_nanos(self.openingDate) if self.openingDate else 0
+        return CryptoFuture(
+            instrument_id=instrument_id,
+            raw_symbol=Symbol(kraken_symbol.raw_symbol),
+            underlying=base_currency,
+            quote_currency=quote_currency,
+            settlement_currency=settlement_currency,
+            is_inverse=self.is_inverse,
+            activation_ns=activation_ns,
+            expiration_ns=dt_to_unix_nanos(self.lastTradingTime),
+            price_precision=price_increment.precision,
+            size_precision=size_increment.precision,
+            price_increment=price_increment,
+            size_increment=size_increment,
+            multiplier=multiplier,
+            max_quantity=None,
+            min_quantity=size_increment,
+            max_notional=None,
+            min_notional=None,
+            max_price=None,
+            min_price=None,
+            margin_init=Decimal("0.1"),
+            margin_maint=Decimal("0.1"),
+            maker_fee=maker_fee,
+            taker_fee=taker_fee,
+            ts_event=ts_event,
+            ts_init=ts_init,
+            info=info,
+        )
+
+
+class KrakenFuturesInstrumentsResponse(msgspec.Struct):
+    result: str
+    instruments: list[KrakenFuturesInstrument]
+
+
+################################################################################
+# Account
+################################################################################
+
+
+class KrakenFuturesFlexCurrency(msgspec.Struct):
+    quantity: float
+    available: float | None = None
+
+
+class KrakenFuturesAccount(msgspec.Struct):
+    type: str
+    currency: str | None = None
+    balances: dict[str, float] | None = None
+    currencies: dict[str, KrakenFuturesFlexCurrency] | None = None
+    initialMargin: float | None = None
+    maintenanceMargin: float | None = None
+    marginRequirements: dict[str, float] | None = None
+
+
+class KrakenFuturesAccountsResponse(msgspec.Struct):
+    result: str
+    accounts: dict[str, KrakenFuturesAccount]
+
+    def parse_to_account_balances(self) -> list[AccountBalance]:
+        # The same currency can be held across the multi-collateral (flex) account
+        # and the per-contract inverse margin accounts, so aggregate by currency
+        totals: dict[str, Decimal] = defaultdict(Decimal)
+        frees: dict[str, Decimal] = defaultdict(Decimal)
+        for account in self.accounts.values():
+            if account.currencies:
+                for code, flex in account.currencies.items():
+                    code = normalize_kraken_asset(code)
+                    totals[code] += Decimal(str(flex.quantity))
+                    available = flex.quantity if flex.available is None else flex.available
+                    frees[code] += Decimal(str(available))
+            elif account.balances and account.type == "marginAccount":
+                for code, quantity in account.balances.items():
+                    code = normalize_kraken_asset(code)
+                    totals[code] += Decimal(str(quantity))
+                    frees[code] += Decimal(str(quantity))
+
+        balances: list[AccountBalance] = []
+        for code, total in totals.items():
+            currency = Currency.from_str(code)
+            free = min(frees[code], total)
+            balances.append(
+                AccountBalance(
+                    total=Money(total, currency),
+                    locked=Money(total - free, currency),
+                    free=Money(free, currency),
+                ),
+            )
+        return balances
+
+    def parse_to_margin_balances(self) -> list[MarginBalance]:
+        margins: list[MarginBalance] = []
+        for account in self.accounts.values():
+            if account.type == "multiCollateralMarginAccount":
+                currency = Currency.from_str("USD")
+                margins.append(
+                    MarginBalance(
+                        initial=Money(Decimal(str(account.initialMargin or 0)), currency),
+                        maintenance=Money(Decimal(str(account.maintenanceMargin or 0)), currency),
+                    ),
+                )
+            elif account.type == "marginAccount" and account.currency:
+                currency = Currency.from_str(normalize_kraken_asset(account.currency))
+                requirements = account.marginRequirements or {}
+                margins.append(
+                    MarginBalance(
+                        initial=Money(Decimal(str(requirements.get("im", 0))), currency),
+                        maintenance=Money(Decimal(str(requirements.get("mm", 0))), currency),
+                    ),
+                )
+        return margins
+
+
+################################################################################
+# Trade
+################################################################################
+
+
+class KrakenFuturesOpenOrder(msgspec.Struct):
+    order_id: str
+    symbol: str
+    side: KrakenOrderSide
+    orderType: str
+    filledSize: float
+    unfilledSize: float
+    receivedTime: datetime.datetime
+    lastUpdateTime: datetime.datetime
+    status: KrakenFuturesOrderStatus
+    cliOrdId: str | None = None
+    limitPrice: float | None = None
+    stopPrice: float | None = None
+    reduceOnly: bool = False
+    triggerSignal: KrakenFuturesTriggerSignal | None = None
+
+    def parse_to_order_status_report(
+        self,
+        account_id: AccountId,
+        instrument: Instrument,
+        client_order_id: ClientOrderId | None,
+        report_id: UUID4,
+        enum_parser: KrakenEnumParser,
+        ts_init: int,
+    ) -> OrderStatusReport:
+        order_type = enum_parser.parse_kraken_futures_order_type(
+            self.orderType,
+            has_limit_price=self.limitPrice is not None,
+        )
+        price = instrument.make_price(self.limitPrice) if self.limitPrice is not None else None
+        trigger_price = (
+            instrument.make_price(self.stopPrice) if self.stopPrice is not None else None
+        )
+        trigger_type = TriggerType.NO_TRIGGER
+        if trigger_price is not None:
+            trigger_type = (
+                enum_parser.parse_kraken_futures_trigger_signal(self.triggerSignal)
+                if self.triggerSignal
+                else TriggerType.LAST_PRICE
+            )
+
+        filled_qty = instrument.make_qty(self.filledSize)
+        quantity = instrument.make_qty(self.filledSize + self.unfilledSize)
+
+        return OrderStatusReport(
+            account_id=account_id,
+            instrument_id=instrument.id,
+            client_order_id=client_order_id,
+            order_list_id=None,
+            venue_order_id=VenueOrderId(self.order_id),
+            order_side=enum_parser.parse_kraken_order_side(self.side),
+            order_type=order_type,
+            contingency_type=ContingencyType.NO_CONTINGENCY,
+            time_in_force=TimeInForce.GTC,
+            order_status=enum_parser.parse_kraken_futures_order_status(self.status),
+            price=price,
+            trigger_price=trigger_price,
+            trigger_type=trigger_type,
+            quantity=quantity,
+            filled_qty=filled_qty,
+            avg_px=None,
+            post_only=self.orderType == "post",
+            reduce_only=self.reduceOnly,
+            ts_accepted=dt_to_unix_nanos(self.receivedTime),
+            ts_last=dt_to_unix_nanos(self.lastUpdateTime),
+            report_id=report_id,
+            ts_init=ts_init,
+        )
+
+
+class KrakenFuturesOpenOrdersResponse(msgspec.Struct):
+    result: str
+    openOrders: list[KrakenFuturesOpenOrder]
+
+
+class KrakenFuturesFill(msgspec.Struct):
+    fill_id: str
+    symbol: str
+    side: KrakenOrderSide
+    order_id: str
+    size: float
+    price: float
+    fillTime: datetime.datetime
+    fillType: str
+    cliOrdId: str | None = None
+
+    def parse_to_fill_report(
+        self,
+        account_id: AccountId,
+        instrument: Instrument,
+        client_order_id: ClientOrderId | None,
+        report_id: UUID4,
+        enum_parser: KrakenEnumParser,
+        ts_init: int,
+    ) -> FillReport:
+        # Fees are not reported with fills, so estimate from the instrument fee rates
+        is_maker = self.fillType == "maker"
+        last_qty = instrument.make_qty(self.size)
+        last_px = instrument.make_price(self.price)
+        notional_value = instrument.notional_value(last_qty, last_px)
+        fee = instrument.maker_fee if is_maker else instrument.taker_fee
+        commission = Money(notional_value.as_decimal() * fee, notional_value.currency)
+
+        return FillReport(
+            client_order_id=client_order_id,
+            venue_order_id=VenueOrderId(self.order_id),
+            trade_id=TradeId(self.fill_id),
+            account_id=account_id,
+            instrument_id=instrument.id,
+            order_side=enum_parser.parse_kraken_order_side(self.side),
+            last_qty=last_qty,
+            last_px=last_px,
+            commission=commission,
+            liquidity_side=LiquiditySide.MAKER if is_maker else LiquiditySide.TAKER,
+            report_id=report_id,
+            ts_event=dt_to_unix_nanos(self.fillTime),
+            ts_init=ts_init,
+        )
+
+
+class KrakenFuturesFillsResponse(msgspec.Struct):
+    result: str
+    fills: list[KrakenFuturesFill]
+
+
+class KrakenFuturesOpenPosition(msgspec.Struct):
+    side: str
+    symbol: str
+    size: float
+    fillTime: datetime.datetime | None = None
+
+    def parse_to_position_status_report(
+        self,
+        account_id: AccountId,
+        instrument: Instrument,
+        report_id: UUID4,
+        ts_init: int,
+    ) -> PositionStatusReport:
+        position_side = PositionSide.LONG if self.side == "long" else PositionSide.SHORT
+        return PositionStatusReport(
+            account_id=account_id,
+            instrument_id=instrument.id,
+            position_side=position_side,
+            quantity=instrument.make_qty(self.size),
+            report_id=report_id,
+            ts_init=ts_init,
+            ts_last=ts_init,
+        )
+
+
+class KrakenFuturesOpenPositionsResponse(msgspec.Struct):
+    result: str
+    openPositions: list[KrakenFuturesOpenPosition]
+
+
+class KrakenFuturesOrderStatusResult(msgspec.Struct):
+    status: str
+    order_id: str | None = None
+    orderId: str | None = None
+    cliOrdId: str | None = None
+
+
+class KrakenFuturesSendOrderResponse(msgspec.Struct):
+    result: str
+    sendStatus: KrakenFuturesOrderStatusResult
+
+
+class KrakenFuturesEditOrderResponse(msgspec.Struct):
+    result: str
+    editStatus: KrakenFuturesOrderStatusResult
+
+
+class KrakenFuturesCancelOrderResponse(msgspec.Struct):
+    result: str
+    cancelStatus: KrakenFuturesOrderStatusResult
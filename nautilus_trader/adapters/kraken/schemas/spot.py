@@ -0,0 +1,346 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from decimal import Decimal
+import msgspec
+
+from nautilus_trader.adapters.kraken.common.enums import KrakenEnumParser
+from nautilus_trader.adapters.kraken.common.enums import KrakenOrderSide
+from nautilus_trader.adapters.kraken.common.enums import KrakenProductType
+from nautilus_trader.adapters.kraken.common.enums import KrakenSpotOrderStatus
+from nautilus_trader.adapters.kraken.common.enums import KrakenSpotOrderType
+from nautilus_trader.adapters.kraken.common.parsing import normalize_kraken_asset
+from nautilus_trader.adapters.kraken.common.symbol import KrakenSymbol
+from nautilus_trader.core.datetime import secs_to_nanos
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.execution.reports import FillReport
+from nautilus_trader.execution.reports import OrderStatusReport
+from nautilus_trader.model.enums import ContingencyType
+from nautilus_trader.model.enums import LiquiditySide
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import TimeInForce
+from nautilus_trader.model.enums import TriggerType
+from nautilus_trader.model.identifiers import AccountId
+from nautilus_trader.model.identifiers import ClientOrderId
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import Symbol
+from nautilus_trader.model.identifiers import TradeId
+from nautilus_trader.model.identifiers import VenueOrderId
+from nautilus_trader.model.instruments import CurrencyPair
+from nautilus_trader.model.objects import AccountBalance
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import Money
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+
+
+def _increment_str(decimals: int) -> str:
+    return format(Decimal(10) ** -decimals, f".{decimals}f")
+
+
+################################################################################
+# Market
+################################################################################
+
+
+class KrakenSpotAssetPair(msgspec.Struct):
+    altname: str
+    base: str
+    quote: str
+    pair_decimals: int
+    lot_decimals: int
+    wsname: str | None = None
+    cost_decimals: int | None = None
+    tick_size: str | None = None
+    ordermin: str | None = None
+    costmin: str | None = None
+    status: str | None = None
+    fees: list[list[float]] = []
+    fees_maker: list[list[float]] = []
+
+    @property
+    def base_code(self) -> str:
+        if self.wsname:
+            return normalize_kraken_asset(self.wsname.partition("/")[0])
+        return normalize_kraken_asset(self.base)
+
+    @property
+    def quote_code(self) -> str:
+        if self.wsname:
+            return normalize_kraken_asset(self.wsname.partition("/")[2])
+        return normalize_kraken_asset(self.quote)
+
+    @property
+    def ws_symbol(self) -> str:
+        # The WebSocket v2 API uses common asset codes (e.g. `BTC/USD` rather than `XBT/USD`)
+        return f"{self.base_code}/{self.quote_code}"
+
+    def parse_to_instrument(
+        self,
+        base_currency: Currency,
+        quote_currency: Currency,
+        ts_event: int,
+        ts_init: int,
+    ) -> CurrencyPair:
+        assert base_currency.code == self.base_code
+        assert quote_currency.code == self.quote_code
+        kraken_symbol = KrakenSymbol.from_raw(self.ws_symbol, KrakenProductType.SPOT)
+        instrument_id = kraken_symbol.to_instrument_id()
+        price_increment = Price.from_str(self.tick_size or _increment_str(self.pair_decimals))
+        size_increment = Quantity.from_str(_increment_str(self.lot_decimals))
+        min_quantity = (
+            Quantity(float(self.ordermin), self.lot_decimals) if self.ordermin else None
+        )
+        min_notional = Money(Decimal(self.costmin), quote_currency) if self.costmin else None
+
+        # Fees are given as percentages for the lowest volume tier
+        taker_fee = Decimal(str(self.fees[0][1])) / 100 if self.fees else Decimal()
+        maker_fee = Decimal(str(self.fees_maker[0][1])) / 100 if self.fees_maker else taker_fee
+
+        return CurrencyPair(
+            instrument_id=instrument_id,
+            raw_symbol=Symbol(kraken_symbol.raw_symbol),
+            base_currency=base_currency,
+            quote_currency=quote_currency,
+            price_precision=price_increment.precision,
+            size_precision=size_increment.precision,
+            price_increment=price_increment,
+            size_increment=size_increment,
+            margin_init=Decimal(0),
+            margin_maint=Decimal(0),
+            maker_fee=maker_fee,
+            taker_fee=taker_fee,
+            ts_event=ts_event,
+            ts_init=ts_init,
+            lot_size=None,
+            max_quantity=None,
+            min_quantity=min_quantity,
+            min_notional=min_notional,
+            min_price=None,
+            max_price=None,
+            info=msgspec.json.Decoder().decode(msgspec.json.Encoder().encode(self)),
+        )
+
+
+class KrakenSpotAssetPairsResponse(msgspec.Struct):
+    error: list[str]
+    result: dict[str, KrakenSpotAssetPair]
+
+
+################################################################################
+# Account
+################################################################################
+
+
+class KrakenSpotWsToken(msgspec.Struct):
+    token: str
+    expires: int
+
+
+class KrakenSpotWsTokenResponse(msgspec.Struct):
+    error: list[str]
+    result: KrakenSpotWsToken
+
+
+class KrakenSpotBalance(msgspec.Struct):
+    balance: str
+    hold_trade: str = "0"
+
+    def parse_to_account_balance(self, asset: str) -> AccountBalance:
+        currency = Currency.from_str(normalize_kraken_asset(asset))
+        total = Decimal(self.balance)
+        locked = Decimal(self.hold_trade)
+        free = total - locked
+        return AccountBalance(
+            total=Money(total, currency),
+            locked=Money(locked, currency),
+            free=Money(free, currency),
+        )
+
+
+class KrakenSpotBalanceResponse(msgspec.Struct):
+    error: list[str]
+    result: dict[str, KrakenSpotBalance]
+
+
+################################################################################
+# Trade
+################################################################################
+
+
+class KrakenSpotOrderDescription(msgspec.Struct):
+    pair: str
+    type: KrakenOrderSide
+    ordertype: KrakenSpotOrderType
+    price: str = "0"
+    price2: str = "0"
+
+
+class KrakenSpotOrder(msgspec.Struct):
+    status: KrakenSpotOrderStatus
+    opentm: float
+    descr: KrakenSpotOrderDescription
+    vol: str
+    vol_exec: str
+    price: str
+    oflags: str = ""
+    cl_ord_id: str | None = None
+    closetm: float | None = None
+    expiretm: float | None = None
+    trigger: str | None = None
+
+    def parse_to_order_status_report(
+        self,
+        account_id: AccountId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId | None,
+        venue_order_id: VenueOrderId,
+        report_id: UUID4,
+        enum_parser: KrakenEnumParser,
+        ts_init: int,
+    ) -> OrderStatusReport:
+        order_type = enum_parser.parse_kraken_spot_order_type(self.descr.ordertype)
+        order_status = enum_parser.parse_kraken_spot_order_status(self.status)
+        filled_qty = Quantity.from_str(self.vol_exec)
+        if order_status == OrderStatus.ACCEPTED and filled_qty > 0:
+            order_status = OrderStatus.PARTIALLY_FILLED
+
+        # For triggered order types `price` is the trigger price and `price2` the limit price
+        price: Price | None = None
+        trigger_price: Price | None = None
+        match order_type:
+            case OrderType.LIMIT:
+                price = Price.from_str(self.descr.price)
+            case OrderType.STOP_MARKET | OrderType.MARKET_IF_TOUCHED:
+                trigger_price = Price.from_str(self.descr.price)
+            case OrderType.STOP_LIMIT | OrderType.LIMIT_IF_TOUCHED:
+                trigger_price = Price.from_str(self.descr.price)
+                price = Price.from_str(self.descr.price2)
+
+        trigger_type = TriggerType.NO_TRIGGER
+        if trigger_price is not None:
+            trigger_type = (
+                TriggerType.INDEX_PRICE if self.trigger == "index" else TriggerType.LAST_PRICE
+            )
+
+        expire_time = None
+        time_in_force = TimeInForce.GTC
+        if self.expiretm:
+            time_in_force = TimeInForce.GTD
+            expire_time = secs_to_nanos(self.expiretm)
+
+        avg_px = Decimal(self.price) if filled_qty > 0 else None
+        ts_accepted = secs_to_nanos(self.opentm)
+
+        return OrderStatusReport(
+            account_id=account_id,
+            instrument_id=instrument_id,
+            client_order_id=client_order_id,
+            order_list_id=None,
+            venue_order_id=venue_order_id,
+            order_side=enum_parser.parse_kraken_order_side(self.descr.type),
+            order_type=order_type,
+            contingency_type=ContingencyType.NO_CONTINGENCY,
+            time_in_force=time_in_force,
+            expire_time=expire_time,
+            order_status=order_status,
+            price=price,
+            trigger_price=trigger_price,
+            trigger_type=trigger_type,
+            quantity=Quantity.from_str(self.vol),
+            filled_qty=filled_qty,
+            avg_px=avg_px,
+            post_only="post" in self.oflags,
+            reduce_only=False,
+            ts_accepted=ts_accepted,
+            ts_last=secs_to_nanos(self.closetm) if self.closetm else ts_accepted,
+            report_id=report_id,
+            ts_init=ts_init,
+        )
+
+
+class KrakenSpotOpenOrders(msgspec.Struct):
+    open: dict[str, KrakenSpotOrder]
+
+
+class KrakenSpotOpenOrdersResponse(msgspec.Struct):
+    error: list[str]
+    result: KrakenSpotOpenOrders
+
+
+class KrakenSpotClosedOrders(msgspec.Struct):
+    closed: dict[str, KrakenSpotOrder]
+    count: int
+
+
+class KrakenSpotClosedOrdersResponse(msgspec.Struct):
+    error: list[str]
+    result: KrakenSpotClosedOrders
+
+
+class KrakenSpotQueryOrdersResponse(msgspec.Struct):
+    error: list[str]
+    result: dict[str, KrakenSpotOrder]
+
+
+class KrakenSpotTrade(msgspec.Struct):
+    ordertxid: str
+    pair: str
+    time: float
+    type: KrakenOrderSide
+    price: str
+    fee: str
+    vol: str
+    maker: bool | None = None
+
+    def parse_to_fill_report(
+        self,
+        account_id: AccountId,
+        instrument_id: InstrumentId,
+        trade_id: str,
+        client_order_id: ClientOrderId | None,
+        quote_currency: Currency,
+        report_id: UUID4,
+        enum_parser: KrakenEnumParser,
+        ts_init: int,
+    ) -> FillReport:
+        return FillReport(
+            client_order_id=client_order_id,
+            venue_order_id=VenueOrderId(self.ordertxid),
+            trade_id=TradeId(trade_id),
+            account_id=account_id,
+            instrument_id=instrument_id,
+            order_side=enum_parser.parse_kraken_order_side(self.type),
+            last_qty=Quantity.from_str(self.vol),
+            last_px=Price.from_str(self.price),
+            commission=Money(Decimal(self.fee), quote_currency),
+            liquidity_side=LiquiditySide.MAKER if self.maker else LiquiditySide.TAKER,
+            report_id=report_id,
+            ts_event=secs_to_nanos(self.time),
+            ts_init=ts_init,
+        )
+
+
+class KrakenSpotTradesHistory(msgspec.Struct):
+    trades: dict[str, KrakenSpotTrade]
+    count: int
+
+
+class KrakenSpotTradesHistoryResponse(msgspec.Struct):
+    error: list[str]
+    result: KrakenSpotTradesHistory
@@ -0,0 +1,455 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+import datetime
+
+import msgspec
+
+from nautilus_trader.adapters.kraken.common.enums import KrakenOrderSide
+from nautilus_trader.adapters.kraken.common.enums import KrakenSpotExecType
+from nautilus_trader.adapters.kraken.common.enums import KrakenSpotOrderStatus
+from nautilus_trader.adapters.kraken.common.enums import KrakenSpotOrderType
+from nautilus_trader.adapters.kraken.common.parsing import parse_aggressor_side
+from nautilus_trader.adapters.kraken.common.parsing import parse_kraken_delta
+from nautilus_trader.core.datetime import millis_to_nanos
+from nautilus_trader.model.data import OrderBookDelta
+from nautilus_trader.model.data import OrderBookDeltas
+from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.model.data import TradeTick
+from nautilus_trader.model.enums import AggressorSide
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import RecordFlag
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import TradeId
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+
+
+def _parse_book_side(
+    instrument_id: InstrumentId,
+    levels: list[tuple[Price, Quantity]],
+    side: OrderSide,
+    is_last_side: bool,
+    sequence: int,
+    ts_event: int,
+    ts_init: int,
+    snapshot: bool,
+) -> list[OrderBookDelta]:
+    deltas: list[OrderBookDelta] = []
+    levels_len = len(levels)
+    for idx, level in enumerate(levels):
+        flags = 0
+        if is_last_side and idx == levels_len - 1:
+            # F_LAST, 1 << 7
+            # Last message in the book event or packet from the venue for a given `instrument_id`
+            flags = RecordFlag.F_LAST
+
+        delta = parse_kraken_delta(
+            instrument_id=instrument_id,
+            values=level,
+            side=side,
+            flags=flags,
+            sequence=sequence,
+            ts_event=ts_event,
+            ts_init=ts_init,
+            snapshot=snapshot,
+        )
+        deltas.append(delta)
+    return deltas
+
+
+################################################################################
+# Spot (WebSocket v2)
+################################################################################
+
+
+class KrakenSpotWsMessageGeneral(msgspec.Struct):
+    channel: str | None = None
+    type: str | None = None
+    method: str | None = None
+    success: bool | None = None
+    error: str | None = None
+    req_id: int | None = None
+
+
+class KrakenSpotWsBookLevel(msgspec.Struct):
+    price: float
+    qty: float
+
+
+class KrakenSpotWsBook(msgspec.Struct):
+    symbol: str
+    bids: list[KrakenSpotWsBookLevel]
+    asks: list[KrakenSpotWsBookLevel]
+    checksum: int | None = None
+    timestamp: datetime.datetime | None = None
+
+    def parse_to_deltas(
+        self,
+        instrument_id: InstrumentId,
+        price_precision: int,
+        size_precision: int,
+        ts_event: int,
+        ts_init: int,
+        snapshot: bool = False,
+    ) -> OrderBookDeltas:
+        bids_raw = [
+            (Price(d.price, price_precision), Quantity(d.qty, size_precision)) for d in self.bids
+        ]
+        asks_raw = [
+            (Price(d.price, price_precision), Quantity(d.qty, size_precision)) for d in self.asks
+        ]
+
+        deltas: list[OrderBookDelta] = []
+
+        if snapshot:
+            deltas.append(OrderBookDelta.clear(instrument_id, 0, ts_event, ts_init))
+
+        deltas += _parse_book_side(
+            instrument_id=instrument_id,
+            levels=bids_raw,
+            side=OrderSide.BUY,
+            is_last_side=len(asks_raw) == 0,
+            sequence=0,
+            ts_event=ts_event,
+            ts_init=ts_init,
+            snapshot=snapshot,
+        )
+        deltas += _parse_book_side(
+            instrument_id=instrument_id,
+            levels=asks_raw,
+            side=OrderSide.SELL,
+            is_last_side=True,
+            sequence=0,
+            ts_event=ts_event,
+            ts_init=ts_init,
+            snapshot=snapshot,
+        )
+
+        return OrderBookDeltas(instrument_id=instrument_id, deltas=deltas)
+
+
+class KrakenSpotWsBookMsg(msgspec.Struct):
+    channel: str
+    type: str
+    data: list[KrakenSpotWsBook]
+
+
+class KrakenSpotWsTrade(msgspec.Struct):
+    symbol: str
+    side: str
+    price: float
+    qty: float
+    trade_id: int
+    timestamp: datetime.datetime
+
+    def parse_to_trade_tick(
+        self,
+        instrument_id: InstrumentId,
+        price_precision: int,
+        size_precision: int,
+        ts_event: int,
+        ts_init: int,
+    ) -> TradeTick:
+        return TradeTick(
+            instrument_id=instrument_id,
+            price=Price(self.price, price_precision),
+            size=Quantity(self.qty, size_precision),
+            aggressor_side=parse_aggressor_side(self.side),
+            trade_id=TradeId(str(self.trade_id)),
+            ts_event=ts_event,
+            ts_init=ts_init,
+        )
+
+
+class KrakenSpotWsTradeMsg(msgspec.Struct):
+    channel: str
+    type: str
+    data: list[KrakenSpotWsTrade]
+
+
+class KrakenSpotWsTicker(msgspec.Struct):
+    symbol: str
+    bid: float
+    bid_qty: float
+    ask: float
+    ask_qty: float
+
+    def parse_to_quote_tick(
+        self,
+        instrument_id: InstrumentId,
+        price_precision: int,
+        size_precision: int,
+        ts_event: int,
+        ts_init: int,
+    ) -> QuoteTick:
+        return QuoteTick(
+            instrument_id=instrument_id,
+            bid_price=Price(self.bid, price_precision),
+            ask_price=Price(self.ask, price_precision),
+            bid_size=Quantity(self.bid_qty, size_precision),
+            ask_size=Quantity(self.ask_qty, size_precision),
+            ts_event=ts_event,
+            ts_init=ts_init,
+        )
+
+
+class KrakenSpotWsTickerMsg(msgspec.Struct):
+    channel: str
+    type: str
+    data: list[KrakenSpotWsTicker]
+
+
+class KrakenSpotWsFee(msgspec.Struct):
+    asset: str
+    qty: float
+
+
+class KrakenSpotWsTriggers(msgspec.Struct):
+    price: float | None = None
+    reference: str | None = None
+    status: str | None = None
+
+
+class KrakenSpotWsExecution(msgspec.Struct):
+    exec_type: KrakenSpotExecType
+    order_id: str
+    timestamp: datetime.datetime
+    cl_ord_id: str | None = None
+    symbol: str | None = None
+    side: KrakenOrderSide | None = None
+    order_type: KrakenSpotOrderType | None = None
+    order_status: KrakenSpotOrderStatus | None = None
+    order_qty: float | None = None
+    limit_price: float | None = None
+    triggers: KrakenSpotWsTriggers | None = None
+    exec_id: str | None = None
+    last_qty: float | None = None
+    last_price: float | None = None
+    liquidity_ind: str | None = None
+    fees: list[KrakenSpotWsFee] | None = None
+    reason: str | None = None
+
+
+class KrakenSpotWsExecutionsMsg(msgspec.Struct):
+    channel: str
+    type: str
+    data: list[KrakenSpotWsExecution]
+
+
+class KrakenSpotWsOrderResponseMsg(msgspec.Struct):
+    method: str
+    success: bool
+    req_id: int | None = None
+    result: dict[str, object] | None = None
+    error: str | None = None
+
+
+################################################################################
+# Futures (WebSocket v1)
+################################################################################
+
+
+class KrakenFuturesWsMessageGeneral(msgspec.Struct):
+    event: str | None = None
+    feed: str | None = None
+    message: str | None = None
+    product_id: str | None = None
+
+
+class KrakenFuturesWsBookLevel(msgspec.Struct):
+    price: float
+    qty: float
+
+
+class KrakenFuturesWsBookSnapshotMsg(msgspec.Struct):
+    feed: str
+    product_id: str
+    timestamp: int
+    seq: int
+    bids: list[KrakenFuturesWsBookLevel]
+    asks: list[KrakenFuturesWsBookLevel]
+
+    def parse_to_deltas(
+        self,
+        instrument_id: InstrumentId,
+        price_precision: int,
+        size_precision: int,
+        ts_init: int,
+    ) -> OrderBookDeltas:
+        ts_event = millis_to_nanos(self.timestamp)
+        bids_raw = [
+            (Price(d.price, price_precision), Quantity(d.qty, size_precision)) for d in self.bids
+        ]
+        asks_raw = [
+            (Price(d.price, price_precision), Quantity(d.qty, size_precision)) for d in self.asks
+        ]
+
+        deltas: list[OrderBookDelta] = [
+            OrderBookDelta.clear(instrument_id, self.seq, ts_event, ts_init),
+        ]
+        deltas += _parse_book_side(
+            instrument_id=instrument_id,
+            levels=bids_raw,
+            side=OrderSide.BUY,
+            is_last_side=len(asks_raw) == 0,
+            sequence=self.seq,
+            ts_event=ts_event,
+            ts_init=ts_init,
+            snapshot=True,
+        )
+        deltas += _parse_book_side(
+            instrument_id=instrument_id,
+            levels=asks_raw,
+            side=OrderSide.SELL,
+            is_last_side=True,
+            sequence=self.seq,
+            ts_event=ts_event,
+            ts_init=ts_init,
+            snapshot=True,
+        )
+
+        return OrderBookDeltas(instrument_id=instrument_id, deltas=deltas)
+
+
+class KrakenFuturesWsBookMsg(msgspec.Struct):
+    feed: str
+    product_id: str
+    side: KrakenOrderSide
+    seq: int
+    price: float
+    qty: float
+    timestamp: int
+
+    def parse_to_deltas(
+        self,
+        instrument_id: InstrumentId,
+        price_precision: int,
+        size_precision: int,
+        ts_init: int,
+    ) -> OrderBookDeltas:
+        delta = parse_kraken_delta(
+            instrument_id=instrument_id,
+            values=(Price(self.price, price_precision), Quantity(self.qty, size_precision)),
+            side=OrderSide.BUY if self.side == KrakenOrderSide.BUY else OrderSide.SELL,
+            flags=RecordFlag.F_LAST,
+            sequence=self.seq,
+            ts_event=millis_to_nanos(self.timestamp),
+            ts_init=ts_init,
+            snapshot=False,
+        )
+        return OrderBookDeltas(instrument_id=instrument_id, deltas=[delta])
+
+
+class KrakenFuturesWsTradeMsg(msgspec.Struct):
+    feed: str
+    product_id: str
+    uid: str
+    side: str
+    seq: int
+    time: int
+    qty: float
+    price: float
+
+    def parse_to_trade_tick(
+        self,
+        instrument_id: InstrumentId,
+        price_precision: int,
+        size_precision: int,
+        ts_init: int,
+    ) -> TradeTick:
+        return TradeTick(
+            instrument_id=instrument_id,
+            price=Price(self.price, price_precision),
+            size=Quantity(self.qty, size_precision),
+            aggressor_side=(
+                AggressorSide.BUYER if self.side == "buy" else AggressorSide.SELLER
+            ),
+            trade_id=TradeId(self.uid),
+            ts_event=millis_to_nanos(self.time),
+            ts_init=ts_init,
+        )
+
+
+class KrakenFuturesWsTickerMsg(msgspec.Struct):
+    feed: str
+    product_id: str
+    time: int
+    bid: float | None = None
+    ask: float | None = None
+    bid_size: float | None = None
+    ask_size: float | None = None
+
+    def parse_to_quote_tick(
+        self,
+        instrument_id: InstrumentId,
+        price_precision: int,
+        size_precision: int,
+        ts_init: int,
+    ) -> QuoteTick:
+        return QuoteTick(
+            instrument_id=instrument_id,
+            bid_price=Price(self.bid or 0, price_precision),
+            ask_price=Price(self.ask or 0, price_precision),
+            bid_size=Quantity(self.bid_size or 0, size_precision),
+            ask_size=Quantity(self.ask_size or 0, size_precision),
+            ts_event=millis_to_nanos(self.time),
+            ts_init=ts_init,
+        )
+
+
+class KrakenFuturesWsOpenOrder(msgspec.Struct):
+    instrument: str
+    time: int
+    last_update_time: int
+    qty: float
+    filled: float
+    type: str
+    order_id: str
+    direction: int  # 0 buy, 1 sell
+    cli_ord_id: str | None = None
+    limit_price: float | None = None
+    stop_price: float | None = None
+    reduce_only: bool = False
+
+
+class KrakenFuturesWsOpenOrdersMsg(msgspec.Struct):
+    feed: str
+    is_cancel: bool
+    reason: str
+    order: KrakenFuturesWsOpenOrder | None = None
+    order_id: str | None = None
+    cli_ord_id: str | None = None
+
+
+class KrakenFuturesWsFill(msgspec.Struct):
+    instrument: str
+    time: int
+    price: float
+    buy: bool
+    qty: float
+    order_id: str
+    fill_id: str
+    fill_type: str
+    cli_ord_id: str | None = None
+    fee_paid: float | None = None
+    fee_currency: str | None = None
+
+
+class KrakenFuturesWsFillsMsg(msgspec.Struct):
+    feed: str
+    fills: list[KrakenFuturesWsFill]
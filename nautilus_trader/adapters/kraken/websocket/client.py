@@ -0,0 +1,521 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+import asyncio
+import hashlib
+import itertools
+from typing import TYPE_CHECKING
+from typing import Any
+
+from msgspec import json as msgspec_json
+
+from nautilus_trader.adapters.kraken.common.enums import KrakenProductType
+from nautilus_trader.adapters.kraken.http.client import kraken_sign
+from nautilus_trader.adapters.kraken.http.errors import KrakenError
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenFuturesWsMessageGeneral
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenSpotWsMessageGeneral
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenSpotWsOrderResponseMsg
+from nautilus_trader.common.component import LiveClock
+from nautilus_trader.common.component import Logger
+from nautilus_trader.common.enums import LogColor
+from nautilus_trader.core.nautilus_pyo3 import WebSocketClient
+from nautilus_trader.core.nautilus_pyo3 import WebSocketClientError
+from nautilus_trader.core.nautilus_pyo3 import WebSocketConfig
+
+
+if TYPE_CHECKING:
+    from collections.abc import Awaitable
+    from collections.abc import Callable
+
+
+WsOrderResponseFuture = asyncio.Future[KrakenSpotWsOrderResponseMsg]
+
+
+class KrakenWebSocketClient:
+    """
+    Provides a Kraken streaming WebSocket client.
+
+    Kraken Spot uses the v2 WebSocket API, where private channels and trading
+    requests are authorized with a token obtained from the REST API. Kraken Futures
+    uses the v1 WebSocket API, where private feeds are authorized by signing a
+    challenge issued by the server.
+
+    Parameters
+    ----------
+    clock : LiveClock
+        The clock instance.
+    base_url : str
+        The base URL for the WebSocket connection.
+    handler : Callable[[bytes], None]
+        The callback handler for message events.
+    handler_reconnect : Callable[..., Awaitable[None]], optional
+        The callback handler to be called on reconnect.
+    product_type : KrakenProductType
+        The Kraken product type (API) for the client.
+    loop : asyncio.AbstractEventLoop
+        The event loop for the client.
+    api_key : str, optional
+        The Kraken API key (required for private Futures feeds).
+    api_secret : str, optional
+        The Kraken API secret (required for private Futures feeds).
+    is_private : bool, default False
+        Whether the client is for private channels.
+    token_provider : Callable[[], Awaitable[str]], optional
+        The provider of Kraken Spot WebSocket authentication tokens.
+    ws_trade_timeout_secs : float, default 5.0
+        The timeout for Kraken Spot trading request responses.
+
+    Raises
+    ------
+    ValueError
+        If `is_private` for Kraken Spot and no `token_provider` is given.
+
+    """
+
+    def __init__(
+        self,
+        clock: LiveClock,
+        base_url: str,
+        handler: Callable[[bytes], None],
+        handler_reconnect: Callable[..., Awaitable[None]] | None,
+        product_type: KrakenProductType,
+        loop: asyncio.AbstractEventLoop,
+        api_key: str | None = None,
+        api_secret: str | None = None,
+        is_private: bool = False,
+        token_provider: Callable[[], Awaitable[str]] | None = None,
+        ws_trade_timeout_secs: float | None = 5.0,
+    ) -> None:
+        if is_private and product_type == KrakenProductType.SPOT and token_provider is None:
+            raise ValueError("`token_provider` required for private Kraken Spot channels")
+
+        self._clock = clock
+        self._log: Logger = Logger(name=type(self).__name__)
+
+        self._base_url: str = base_url
+        self._handler: Callable[[bytes], None] = handler
+        self._handler_reconnect: Callable[..., Awaitable[None]] | None = handler_reconnect
+        self._product_type = product_type
+        self._loop = loop
+        self._ws_trade_timeout_secs = ws_trade_timeout_secs
+
+        self._client: WebSocketClient | None = None
+        self._api_key = api_key
+        self._api_secret = api_secret
+        self._token_provider = token_provider
+        self._is_private = is_private
+        self._is_running = False
+        self._reconnecting = False
+
+        # Subscription key -> subscribe params (replayed on reconnect)
+        self._subscriptions: dict[str, dict[str, Any]] = {}
+
+        # Spot authentication token
+        self._token: str | None = None
+
+        # Futures signed challenge
+        self._challenge: str | None = None
+        self._signed_challenge: str | None = None
+        self._challenge_event = asyncio.Event()
+
+        self._req_ids = itertools.count(1)
+        self._pending_order_requests: dict[int, WsOrderResponseFuture] = {}
+
+        self._decoder_spot_ws_message_general = msgspec_json.Decoder(KrakenSpotWsMessageGeneral)
+        self._decoder_futures_ws_message_general = msgspec_json.Decoder(
+            KrakenFuturesWsMessageGeneral,
+        )
+        self._decoder_spot_ws_order_response = msgspec_json.Decoder(KrakenSpotWsOrderResponseMsg)
+
+    @property
+    def subscriptions(self) -> list[str]:
+        return list(self._subscriptions)
+
+    def has_subscription(self, item: str) -> bool:
+        return item in self._subscriptions
+
+    @property
+    def channel_type(self) -> str:
+        return "Private" if self._is_private else "Public"
+
+    @property
+    def is_spot(self) -> bool:
+        return self._product_type == KrakenProductType.SPOT
+
+    async def connect(self) -> None:
+        self._is_running = True
+        self._log.debug(f"Connecting to {self._base_url} websocket stream")
+
+        if self.is_spot:
+            heartbeat_msg: str | None = msgspec_json.encode({"method": "ping"}).decode()
+        else:
+            heartbeat_msg = None  # Kraken Futures expects ping frames
+
+        config = WebSocketConfig(
+            url=self._base_url,
+            handler=self._msg_handler,
+            heartbeat=30,
+            heartbeat_msg=heartbeat_msg,
+            headers=[],
+        )
+        client = await WebSocketClient.connect(
+            config=config,
+            post_reconnection=self.reconnect,
+        )
+        self._client = client
+        self._log.info(f"Connected to {self._base_url}", LogColor.BLUE)
+
+        if self._is_private:
+            await self._authenticate()
+
+    def reconnect(self) -> None:
+        """
+        Reconnect the client to the server and resubscribe to all streams.
+        """
+        if not self._is_running or self._reconnecting:
+            return
+
+        self._log.warning(f"Trying to reconnect to {self._base_url}")
+        self._reconnecting = True
+        self._loop.create_task(self._reconnect_wrapper())
+
+    async def _reconnect_wrapper(self) -> None:
+        try:
+            if self._is_private:
+                await self._authenticate()
+
+            self._log.warning(
+                f"Resubscribing {self.channel_type} channel to {len(self._subscriptions)} streams",
+            )
+
+            await self._subscribe_all()
+
+            if self._handler_reconnect:
+                await self._handler_reconnect()
+
+            self._log.warning(f"Reconnected to {self._base_url}")
+        except Exception as e:
+            self._log.error(f"Reconnection failed: {e}")
+        finally:
+            self._reconnecting = False
+
+    async def disconnect(self) -> None:
+        self._is_running = False
+        self._reconnecting = False
+
+        if self._client is None:
+            self._log.warning("Cannot disconnect: not connected.")
+            return
+
+        try:
+            await self._client.disconnect()
+        except WebSocketClientError as e:
+            self._log.error(str(e))
+
+        self._client = None  # Dispose (will go out of scope)
+
+        for future in self._pending_order_requests.values():
+            future.cancel()
+        self._pending_order_requests.clear()
+
+        self._log.info(f"Disconnected from {self._base_url}", LogColor.BLUE)
+
+    def _msg_handler(self, raw: bytes) -> None:
+        """
+        Handle pushed websocket messages.
+
+        Parameters
+        ----------
+        raw : bytes
+            The received message in bytes.
+
+        """
+        if self.is_spot:
+            spot_msg = self._decoder_spot_ws_message_general.decode(raw)
+            if spot_msg.method in ("add_order", "amend_order", "cancel_order", "cancel_all"):
+                self._handle_order_ack(raw)
+                return
+            if spot_msg.method in ("subscribe", "unsubscribe") and spot_msg.success is False:
+                self._log.error(f"Subscription request failed: {spot_msg.error}")
+                return
+            if spot_msg.method == "pong" or spot_msg.channel in ("heartbeat", "status"):
+                return
+        else:
+            futures_msg = self._decoder_futures_ws_message_general.decode(raw)
+            if futures_msg.event == "challenge":
+                self._handle_challenge(futures_msg.message)
+                return
+            if futures_msg.event == "error" or futures_msg.event == "alert":
+                self._log.error(f"Received error: {futures_msg.message}")
+                return
+            if futures_msg.event in ("info", "subscribed", "unsubscribed"):
+                self._log.debug(f"Received {futures_msg.event}: {raw.decode()}")
+                return
+            if futures_msg.feed == "heartbeat":
+                return
+
+        self._handler(raw)
+
+    ################################################################################
+    # Authentication
+    ################################################################################
+
+    async def _authenticate(self) -> None:
+        if self.is_spot:
+            assert self._token_provider is not None  # Checked in constructor
+            self._token = await self._token_provider()
+            self._log.info(f"{self.channel_type} channel token acquired", LogColor.GREEN)
+            return
+
+        self._challenge = None
+        self._signed_challenge = None
+        self._challenge_event.clear()
+        await self._send({"event": "challenge", "api_key": self._api_key})
+        await asyncio.wait_for(self._challenge_event.wait(), timeout=10.0)
+        self._log.info(f"{self.channel_type} channel authenticated", LogColor.GREEN)
+
+    def _handle_challenge(self, challenge: str | None) -> None:
+        if not challenge or not self._api_secret:
+            self._log.error(f"Invalid challenge response: {challenge}")
+            return
+
+        message = hashlib.sha256(challenge.encode()).digest()
+        self._challenge = challenge
+        self._signed_challenge = kraken_sign(self._api_secret, message)
+        self._challenge_event.set()
+
+    ################################################################################
+    # Subscriptions
+    ################################################################################
+
+    def _build_subscribe_msg(self, params: dict[str, Any], subscribe: bool) -> dict[str, Any]:
+        if self.is_spot:
+            params = dict(params)
+            if self._is_private:
+                params["token"] = self._token
+            return {
+                "method": "subscribe" if subscribe else "unsubscribe",
+                "params": params,
+                "req_id": next(self._req_ids),
+            }
+
+        msg = {"event": "subscribe" if subscribe else "unsubscribe", **params}
+        if self._is_private:
+            msg["api_key"] = self._api_key
+            msg["original_challenge"] = self._challenge
+            msg["signed_challenge"] = self._signed_challenge
+        return msg
+
+    async def _subscribe(self, key: str, params: dict[str, Any]) -> None:
+        self._log.debug(f"Subscribing to {key}")
+        if key in self._subscriptions:
+            self._log.warning(f"Cannot subscribe '{key}': already subscribed")
+            return
+
+        self._subscriptions[key] = params
+        await self._send(self._build_subscribe_msg(params, subscribe=True))
+
+    async def _unsubscribe(self, key: str) -> None:
+        params = self._subscriptions.pop(key, None)
+        if params is None:
+            self._log.warning(f"Cannot unsubscribe '{key}': not subscribed")
+            return
+
+        await self._send(self._build_subscribe_msg(params, subscribe=False))
+
+    async def _subscribe_all(self) -> None:
+        if self._client is None:
+            self._log.error("Cannot subscribe all: not connected")
+            return
+
+        for params in self._subscriptions.values():
+            await self._send(self._build_subscribe_msg(params, subscribe=True))
+
+    async def _send(self, msg: dict[str, Any]) -> None:
+        await self._send_text(msgspec_json.encode(msg))
+
+    async def _send_text(self, msg: bytes) -> None:
+        if self._client is None:
+            self._log.error(f"Cannot send message {msg!r}: not connected")
+            return
+
+        self._log.debug(f"SENDING: {msg!r}")
+
+        try:
+            await self._client.send_text(msg)
+        except WebSocketClientError as e:
+            self._log.error(str(e))
+
+    ################################################################################
+    # Public
+    ################################################################################
+
+    async def subscribe_order_book(self, symbol: str, depth: int | None = None) -> None:
+        if self.is_spot:
+            params = {"channel": "book", "symbol": [symbol], "depth": depth or 10}
+        else:
+            params = {"feed": "book", "product_ids": [symbol]}
+        await self._subscribe(f"book.{symbol}", params)
+
+    async def subscribe_trades(self, symbol: str) -> None:
+        if self.is_spot:
+            params = {"channel": "trade", "symbol": [symbol], "snapshot": False}
+        else:
+            params = {"feed": "trade", "product_ids": [symbol]}
+        await self._subscribe(f"trade.{symbol}", params)
+
+    async def subscribe_tickers(self, symbol: str) -> None:
+        if self.is_spot:
+            # Ticker events on best bid and offer changes are used for quotes
+            params = {"channel": "ticker", "symbol": [symbol], "event_trigger": "bbo"}
+        else:
+            params = {"feed": "ticker", "product_ids": [symbol]}
+        await self._subscribe(f"ticker.{symbol}", params)
+
+    async def unsubscribe_order_book(self, symbol: str) -> None:
+        await self._unsubscribe(f"book.{symbol}")
+
+    async def unsubscribe_trades(self, symbol: str) -> None:
+        await self._unsubscribe(f"trade.{symbol}")
+
+    async def unsubscribe_tickers(self, symbol: str) -> None:
+        await self._unsubscribe(f"ticker.{symbol}")
+
+    ################################################################################
+    # Private
+    ################################################################################
+
+    async def subscribe_executions(self) -> None:
+        # Order state is reconciled through the REST API, so no snapshots are requested
+        params = {"channel": "executions", "snap_orders": False, "snap_trades": False}
+        await self._subscribe("executions", params)
+
+    async def subscribe_open_orders(self) -> None:
+        await self._subscribe("open_orders", {"feed": "open_orders"})
+
+    async def subscribe_fills(self) -> None:
+        await self._subscribe("fills", {"feed": "fills"})
+
+    ################################################################################
+    # Trade (Spot)
+    ################################################################################
+
+    def _handle_order_ack(self, raw: bytes) -> None:
+        try:
+            msg = self._decoder_spot_ws_order_response.decode(raw)
+        except Exception as e:
+            self._log.error(f"Failed to decode order ack response {raw!r}: {e}")
+            return
+
+        req_id = msg.req_id
+        if req_id is None:
+            self._log.debug(f"No `req_id` in order ack response: {msg}")
+            return
+
+        future = self._pending_order_requests.pop(req_id, None)
+        if future is None:
+            self._log.warning(f"Received ack for `unknown/timeout` req_id={req_id}, msg={msg}")
+            return
+
+        if msg.success:
+            future.set_result(msg)
+        else:
+            future.set_exception(KrakenError(code=msg.error, message=msg.error))
+
+    async def _order(self, method: str, params: dict[str, Any]) -> KrakenSpotWsOrderResponseMsg:
+        req_id = next(self._req_ids)
+
+        future: WsOrderResponseFuture = self._loop.create_future()
+        self._pending_order_requests[req_id] = future
+
+        params = {k: v for k, v in params.items() if v is not None}
+        params["token"] = self._token
+
+        await self._send({"method": method, "params": params, "req_id": req_id})
+
+        try:
+            return await asyncio.wait_for(future, self._ws_trade_timeout_secs)
+        except TimeoutError as e:
+            self._log.error(f"Order request `{req_id}` timed out. method={method}")
+            future.cancel()
+            self._pending_order_requests.pop(req_id, None)
+            raise KrakenError(code=-10_408, message="Request timed out") from e
+
+    async def add_order(
+        self,
+        symbol: str,
+        side: str,
+        order_type: str,
+        order_qty: float,
+        cl_ord_id: str,
+        limit_price: float | None = None,
+        time_in_force: str | None = None,
+        expire_time: str | None = None,
+        post_only: bool | None = None,
+        reduce_only: bool | None = None,
+        display_qty: float | None = None,
+        triggers: dict[str, Any] | None = None,
+    ) -> KrakenSpotWsOrderResponseMsg:
+        return await self._order(
+            "add_order",
+            {
+                "symbol": symbol,
+                "side": side,
+                "order_type": order_type,
+                "order_qty": order_qty,
+                "cl_ord_id": cl_ord_id,
+                "limit_price": limit_price,
+                "time_in_force": time_in_force,
+                "expire_time": expire_time,
+                "post_only": post_only or None,
+                "reduce_only": reduce_only or None,
+                "display_qty": display_qty,
+                "triggers": triggers,
+            },
+        )
+
+    async def amend_order(
+        self,
+        cl_ord_id: str | None = None,
+        order_id: str | None = None,
+        order_qty: float | None = None,
+        limit_price: float | None = None,
+        trigger_price: float | None = None,
+    ) -> KrakenSpotWsOrderResponseMsg:
+        return await self._order(
+            "amend_order",
+            {
+                "order_id": order_id,
+                "cl_ord_id": cl_ord_id if order_id is None else None,
+                "order_qty": order_qty,
+                "limit_price": limit_price,
+                "trigger_price": trigger_price,
+            },
+        )
+
+    async def cancel_order(
+        self,
+        cl_ord_ids: list[str] | None = None,
+        order_ids: list[str] | None = None,
+    ) -> KrakenSpotWsOrderResponseMsg:
+        return await self._order(
+            "cancel_order",
+            {"order_id": order_ids, "cl_ord_id": cl_ord_ids},
+        )
+
+    async def cancel_all_orders(self) -> KrakenSpotWsOrderResponseMsg:
+        return await self._order("cancel_all", {})
@@ -0,0 +1,75 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import msgspec
+
+from nautilus_trader.adapters.okx.common.enums import OKXEndpointType
+from nautilus_trader.adapters.okx.endpoints.endpoint import OKXHttpEndpoint
+from nautilus_trader.adapters.okx.http.client import OKXHttpClient
+from nautilus_trader.adapters.okx.schemas.trade import OKXAmendAlgoOrderResponse
+from nautilus_trader.core.nautilus_pyo3 import HttpMethod
+
+
+class OKXAmendAlgoOrderPostParams(msgspec.Struct, omit_defaults=True, frozen=True):
+    instId: str
+    algoId: str | None = None
+    algoClOrdId: str | None = None
+    cxlOnFail: bool = False  # if should automatically cancel when amendment fails
+    reqId: str | None = None  # client request id for the amendment
+    newSz: str | None = None
+    # 'trigger' orders
+    newTriggerPx: str | None = None
+    newOrdPx: str | None = None  # "-1" for market order
+    # 'conditional' and 'oco' orders
+    newTpTriggerPx: str | None = None  # if "0", take-profit is deleted
+    newTpOrdPx: str | None = None
+    newSlTriggerPx: str | None = None  # if "0", stop-loss is deleted
+    newSlOrdPx: str | None = None
+
+    def validate(self) -> None:
+        assert (
+            self.algoId or self.algoClOrdId
+        ), "either `algoId` or `algoClOrdId` is required to amend an algo order"
+
+        if self.newSz:
+            assert float(self.newSz) > 0, "`newSz` must be greater than 0 when provided"
+
+
+class OKXAmendAlgosEndpoint(OKXHttpEndpoint):
+    def __init__(
+        self,
+        client: OKXHttpClient,
+        base_endpoint: str,
+    ) -> None:
+        url_path = base_endpoint + "/amend-algos"
+        super().__init__(
+            client=client,
+            endpoint_type=OKXEndpointType.TRADE,
+            url_path=url_path,
+        )
+        self._resp_decoder = msgspec.json.Decoder(OKXAmendAlgoOrderResponse)
+
+    async def post(self, params: OKXAmendAlgoOrderPostParams) -> OKXAmendAlgoOrderResponse:
+        # Validate
+        params.validate()
+
+        method_type = HttpMethod.POST
+        raw = await self._method(method_type, params)  # , ratelimiter_keys=[self.url_path])
+        try:
+            return self._resp_decoder.decode(raw)
+        except Exception as e:
+            raise RuntimeError(
+                f"Failed to decode response from {self.url_path}: {raw.decode()} from error: {e}",
+            )
@@ -0,0 +1,94 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import msgspec
+
+from nautilus_trader.adapters.okx.common.enums import OKXAlgoOrderType
+from nautilus_trader.adapters.okx.common.enums import OKXEndpointType
+from nautilus_trader.adapters.okx.common.enums import OKXOrderSide
+from nautilus_trader.adapters.okx.common.enums import OKXPositionSide
+from nautilus_trader.adapters.okx.common.enums import OKXTradeMode
+from nautilus_trader.adapters.okx.common.enums import OKXTriggerType
+from nautilus_trader.adapters.okx.endpoints.endpoint import OKXHttpEndpoint
+from nautilus_trader.adapters.okx.http.client import OKXHttpClient
+from nautilus_trader.adapters.okx.schemas.trade import OKXPlaceAlgoOrderResponse
+from nautilus_trader.core.nautilus_pyo3 import HttpMethod
+
+
+class OKXPlaceAlgoOrderPostParams(msgspec.Struct, omit_defaults=True, frozen=True):
+    instId: str
+    tdMode: OKXTradeMode
+    side: OKXOrderSide
+    ordType: OKXAlgoOrderType
+    sz: str
+    ccy: str | None = None
+    algoClOrdId: str | None = None
+    tag: str | None = None
+    posSide: OKXPositionSide = OKXPositionSide.NET
+    reduceOnly: bool = False
+    # 'trigger' orders
+    triggerPx: str | None = None
+    orderPx: str | None = None  # assign "-1" for market order
+    triggerPxType: OKXTriggerType | None = None
+    # 'conditional' and 'oco' orders
+    tpTriggerPx: str | None = None
+    tpOrdPx: str | None = None  # assign "-1" for market order
+    tpTriggerPxType: OKXTriggerType | None = None
+    slTriggerPx: str | None = None
+    slOrdPx: str | None = None  # assign "-1" for market order
+    slTriggerPxType: OKXTriggerType | None = None
+
+    def validate(self) -> None:
+        if self.ordType == OKXAlgoOrderType.TRIGGER:
+            assert self.triggerPx, "`triggerPx` is required for 'trigger' algo orders"
+            assert self.orderPx, "`orderPx` is required for 'trigger' algo orders"
+
+        if self.ordType == OKXAlgoOrderType.OCO:
+            assert (
+                self.tpTriggerPx and self.slTriggerPx
+            ), "both `tpTriggerPx` and `slTriggerPx` are required for 'oco' algo orders"
+
+        if self.tpTriggerPx:
+            assert self.tpOrdPx, "`tpOrdPx` is required when `tpTriggerPx` is specified"
+        if self.slTriggerPx:
+            assert self.slOrdPx, "`slOrdPx` is required when `slTriggerPx` is specified"
+
+
+class OKXPlaceAlgoOrderEndpoint(OKXHttpEndpoint):
+    def __init__(
+        self,
+        client: OKXHttpClient,
+        base_endpoint: str,
+    ) -> None:
+        url_path = base_endpoint + "/order-algo"
+        super().__init__(
+            client=client,
+            endpoint_type=OKXEndpointType.TRADE,
+            url_path=url_path,
+        )
+        self._resp_decoder = msgspec.json.Decoder(OKXPlaceAlgoOrderResponse)
+
+    async def post(self, params: OKXPlaceAlgoOrderPostParams) -> OKXPlaceAlgoOrderResponse:
+        # Validate
+        params.validate()
+
+        method_type = HttpMethod.POST
+        raw = await self._method(method_type, params)  # , ratelimiter_keys=[self.url_path])
+        try:
+            return self._resp_decoder.decode(raw)
+        except Exception as e:
+            raise RuntimeError(
+                f"Failed to decode response from {self.url_path}: {raw.decode()} from error: {e}",
+            )
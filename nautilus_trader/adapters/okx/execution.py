@@ -24,6 +24,8 @@ from nautilus_trader.adapters.okx.common.constants import OKX_VENUE
 from nautilus_trader.adapters.okx.common.credentials import get_api_key
 from nautilus_trader.adapters.okx.common.credentials import get_api_secret
 from nautilus_trader.adapters.okx.common.credentials import get_passphrase
+from nautilus_trader.adapters.okx.common.enums import OKXAlgoOrderStatus
+from nautilus_trader.adapters.okx.common.enums import OKXAlgoOrderType
 from nautilus_trader.adapters.okx.common.enums import OKXEnumParser
 from nautilus_trader.adapters.okx.common.enums import OKXInstrumentType
 from nautilus_trader.adapters.okx.common.enums import OKXMarginMode
@@ -45,12 +47,16 @@ from nautilus_trader.adapters.okx.schemas.ws import OKXWsEventMsg
 from nautilus_trader.adapters.okx.schemas.ws import OKXWsFillsPushDataMsg
 from nautilus_trader.adapters.okx.schemas.ws import OKXWsGeneralMsg
 from nautilus_trader.adapters.okx.schemas.ws import OKXWsOrderMsg
+from nautilus_trader.adapters.okx.schemas.trade import OKXPlaceAlgoOrderResponse
 from nautilus_trader.adapters.okx.schemas.ws import OKXWsOrderMsgData
+from nautilus_trader.adapters.okx.schemas.ws import OKXWsOrdersAlgoData
+from nautilus_trader.adapters.okx.schemas.ws import OKXWsOrdersAlgoPushDataMsg
 from nautilus_trader.adapters.okx.schemas.ws import OKXWsOrdersPushDataMsg
 from nautilus_trader.adapters.okx.schemas.ws import OKXWsPushDataMsg
 from nautilus_trader.adapters.okx.schemas.ws import decoder_ws_account
 from nautilus_trader.adapters.okx.schemas.ws import decoder_ws_order
 from nautilus_trader.adapters.okx.schemas.ws import decoder_ws_orders
+from nautilus_trader.adapters.okx.schemas.ws import decoder_ws_orders_algo
 from nautilus_trader.adapters.okx.websocket.client import OKX_CHANNEL_WS_BASE_URL_TYPE_MAP
 from nautilus_trader.adapters.okx.websocket.client import OKXWebsocketClient
 from nautilus_trader.cache.cache import Cache
@@ -71,6 +77,7 @@ from nautilus_trader.execution.reports import OrderStatusReport
 from nautilus_trader.execution.reports import PositionStatusReport
 from nautilus_trader.live.execution_client import LiveExecutionClient
 from nautilus_trader.model.enums import AccountType
+from nautilus_trader.model.enums import ContingencyType
 from nautilus_trader.model.enums import OmsType
 from nautilus_trader.model.enums import OrderStatus
 from nautilus_trader.model.enums import OrderType
@@ -82,6 +89,7 @@ from nautilus_trader.model.identifiers import InstrumentId
 from nautilus_trader.model.identifiers import StrategyId
 from nautilus_trader.model.identifiers import TradeId
 from nautilus_trader.model.identifiers import VenueOrderId
+from nautilus_trader.model.instruments import Instrument
 from nautilus_trader.model.objects import Currency
 from nautilus_trader.model.objects import Money
 from nautilus_trader.model.orders import LimitIfTouchedOrder
@@ -249,6 +257,20 @@ class OKXExecutionClient(LiveExecutionClient):
             loop=self._loop,
         )
 
+        # Business WebSocket API (algo order updates)
+        self._ws_business_client = OKXWebsocketClient(
+            clock=clock,
+            handler=self._handle_ws_business_message,
+            handler_reconnect=None,
+            api_key=config.api_key or get_api_key(config.is_demo),
+            api_secret=config.api_secret or get_api_secret(config.is_demo),
+            passphrase=config.passphrase or get_passphrase(config.is_demo),
+            base_url=config.base_url_ws,
+            ws_base_url_type=OKXWsBaseUrlType.BUSINESS,
+            is_demo=config.is_demo,
+            loop=self._loop,
+        )
+
         # Http API
         self._http_account = OKXAccountHttpAPI(client=client, clock=clock)
         self._http_trade = OKXTradeHttpAPI(client=client, clock=clock)
@@ -257,14 +279,19 @@ class OKXExecutionClient(LiveExecutionClient):
         self._submit_order_methods = {
             OrderType.MARKET: self._submit_market_order,
             OrderType.LIMIT: self._submit_limit_order,
-            # OrderType.STOP_MARKET: self._submit_stop_market_order,
-            # OrderType.STOP_LIMIT: self._submit_stop_limit_order,
-            # OrderType.MARKET_IF_TOUCHED: self._submit_market_if_touched_order,
-            # OrderType.LIMIT_IF_TOUCHED: self._submit_limit_if_touched_order,
+            OrderType.STOP_MARKET: self._submit_stop_market_order,
+            OrderType.STOP_LIMIT: self._submit_stop_limit_order,
+            OrderType.MARKET_IF_TOUCHED: self._submit_market_if_touched_order,
+            OrderType.LIMIT_IF_TOUCHED: self._submit_limit_if_touched_order,
             # OrderType.TRAILING_STOP_MARKET: self._submit_trailing_stop_market,
             # OrderType.TRAILING_STOP_LIMIT: self._submit_trailing_stop_limit,
         }
 
+        # Order types submitted through the OKX algo order endpoints
+        self._stop_loss_order_types = {OrderType.STOP_MARKET, OrderType.STOP_LIMIT}
+        self._take_profit_order_types = {OrderType.MARKET_IF_TOUCHED, OrderType.LIMIT_IF_TOUCHED}
+        self._algo_order_types = self._stop_loss_order_types | self._take_profit_order_types
+
         # Decoders
         self._decoder_ws_general_msg = msgspec.json.Decoder(OKXWsGeneralMsg)
         self._decoder_ws_event_msg = msgspec.json.Decoder(OKXWsEventMsg)
@@ -273,6 +300,7 @@ class OKXExecutionClient(LiveExecutionClient):
         self._decoder_ws_account_msg = decoder_ws_account()
         # self._decoder_ws_fills_msg = decoder_ws_fills()  # "orders" channel provides fills
         self._decoder_ws_orders_msg = decoder_ws_orders()
+        self._decoder_ws_orders_algo_msg = decoder_ws_orders_algo()
         # self._decoder_ws_positions_msg = decoder_ws_positions()  # nautilus uses fills to updt pos
 
         # Hot cache
@@ -281,6 +309,12 @@ class OKXExecutionClient(LiveExecutionClient):
             tuple[OKXSymbol, VenueOrderId | None, ClientOrderId | None, StrategyId | None],
         ] = {}  # keys are msg id's created with `self._create_okx_order_msg_id()`
 
+        # Algo orders: keys are OKX algo ID's, values are the nautilus orders placed by the algo
+        # order (two legs for OCO algo orders)
+        self._algo_orders: dict[str, list[ClientOrderId]] = {}
+        self._algo_triggered_orders: dict[str, ClientOrderId] = {}
+        self._algo_pending_child_msgs: dict[str, list[bytes]] = {}
+
         # OKX client order id generator
         self._client_order_id_generator = ClientOrderIdGenerator(self._cache)
 
@@ -297,8 +331,13 @@ class OKXExecutionClient(LiveExecutionClient):
         await self._ws_client.subscribe_orders()
         # await self._ws_client.subscribe_positions()  # nautilus uses fills to updt pos
 
+        # Connect to business websocket for algo order updates
+        await self._ws_business_client.connect()
+        await self._ws_business_client.subscribe_orders_algo()
+
     async def _disconnect(self) -> None:
         await self._ws_client.disconnect()
+        await self._ws_business_client.disconnect()
 
     # -- EXECUTION REPORTS ------------------------------------------------------------------------
 
@@ -858,13 +897,6 @@ class OKXExecutionClient(LiveExecutionClient):
         #     self._log.debug(f"Set leverage {position.symbol} {leverage}X")
 
     async def _modify_order(self, command: ModifyOrder) -> None:
-        if command.trigger_price:
-            self._log.error(
-                f"ModifyOrder command has {command.trigger_price=} but stop-type orders are not "
-                "yet supported for OKX",
-            )
-            return
-
         okx_symbol = OKXSymbol(command.instrument_id.symbol.value)
 
         order: Order | None = self._cache.order(command.client_order_id)
@@ -874,6 +906,17 @@ class OKXExecutionClient(LiveExecutionClient):
         if not self._check_order_validity(order, okx_symbol.instrument_type):
             return
 
+        if self._is_algo_order(order):
+            await self._modify_algo_order(command, order, okx_symbol)
+            return
+
+        if command.trigger_price:
+            self._log.error(
+                f"ModifyOrder command has {command.trigger_price=} but {order.order_type=} has "
+                "no trigger price",
+            )
+            return
+
         venue_order_id = str(command.venue_order_id) if command.venue_order_id else None
         price = str(command.price) if command.price else None
         # trigger_price = str(command.trigger_price) if command.trigger_price else None
@@ -916,6 +959,10 @@ class OKXExecutionClient(LiveExecutionClient):
         if not self._check_order_validity(order, okx_symbol.instrument_type):
             return
 
+        if self._is_algo_order(order):
+            await self._cancel_algo_orders(okx_symbol, [order])
+            return
+
         venue_order_id = str(command.venue_order_id) if command.venue_order_id else None
 
         okx_client_order_id = self._client_order_id_generator.get_okx_client_order_id(
@@ -945,9 +992,13 @@ class OKXExecutionClient(LiveExecutionClient):
             strategy_id=command.strategy_id,
         )
 
+        algo_orders: list[Order] = []
         for order in open_orders_strategy:
             if not self._check_order_validity(order, okx_symbol.instrument_type):
                 continue
+            if self._is_algo_order(order):
+                algo_orders.append(order)
+                continue
             venue_order_id = str(order.venue_order_id) if order.venue_order_id else None
 
             okx_client_order_id = self._client_order_id_generator.get_okx_client_order_id(
@@ -968,6 +1019,9 @@ class OKXExecutionClient(LiveExecutionClient):
                 clOrdId=okx_client_order_id,
             )
 
+        if algo_orders:
+            await self._cancel_algo_orders(okx_symbol, algo_orders)
+
     def _check_order_validity(self, order: Order, instrument_type: OKXInstrumentType) -> bool:
         if order.order_type not in self._submit_order_methods:
             self._log.error(f"Cannot submit order, {order.order_type=} not yet implemented for OKX")
@@ -1008,9 +1062,13 @@ class OKXExecutionClient(LiveExecutionClient):
     async def _submit_order_list(self, command: SubmitOrderList) -> None:
         self._log.debug(f"Submit list of {len(command.order_list.orders)} orders", LogColor.CYAN)
 
+        oco_legs = self._get_oco_algo_order_legs(command.order_list.orders)
+        if oco_legs is not None:
+            await self._submit_oco_algo_order(*oco_legs)
+            return
+
         for order in command.order_list.orders:
-            order = command.order
-            okx_symbol = OKXSymbol(command.instrument_id.symbol.value)
+            okx_symbol = OKXSymbol(order.instrument_id.symbol.value)
             if not self._check_order_validity(order, okx_symbol.instrument_type):  # logs reason
                 continue
 
@@ -1125,20 +1183,268 @@ class OKXExecutionClient(LiveExecutionClient):
         )
 
     async def _submit_stop_market_order(self, order: StopMarketOrder) -> None:
-        raise NotImplementedError("Stop-market orders are not yet implemented for OKX")
+        await self._submit_trigger_algo_order(order)
 
     async def _submit_stop_limit_order(self, order: StopLimitOrder) -> None:
-        raise NotImplementedError("Stop-limit orders are not yet implemented for OKX")
+        await self._submit_trigger_algo_order(order)
 
     async def _submit_market_if_touched_order(self, order: MarketIfTouchedOrder) -> None:
-        raise NotImplementedError("Market-if-touched orders are not yet implemented for OKX")
+        await self._submit_trigger_algo_order(order)
 
     async def _submit_limit_if_touched_order(self, order: LimitIfTouchedOrder) -> None:
-        raise NotImplementedError("Limit-if-touched orders are not yet implemented for OKX")
+        await self._submit_trigger_algo_order(order)
 
     async def _submit_trailing_stop_market(self, order: TrailingStopMarketOrder) -> None:
         raise NotImplementedError("Trailing-stop orders are not yet implemented for OKX")
 
+    def _is_algo_order(self, order: Order) -> bool:
+        # Algo orders are managed through the algo order endpoints until triggered at the venue,
+        # after which they continue as regular orders
+        if order.order_type not in self._algo_order_types:
+            return False
+        if order.status in (OrderStatus.TRIGGERED, OrderStatus.PARTIALLY_FILLED):
+            return False
+        return order.client_order_id not in self._algo_triggered_orders.values()
+
+    def _get_algo_order_px(self, order: Order) -> str:
+        # OKX algo order price of "-1" executes a market order once triggered
+        return str(order.price) if order.has_price else "-1"
+
+    def _get_oco_algo_order_legs(self, orders: list[Order]) -> tuple[Order, Order] | None:
+        if len(orders) != 2:
+            return None
+        if any(o.contingency_type != ContingencyType.OCO for o in orders):
+            return None
+
+        stop_loss = [o for o in orders if o.order_type in self._stop_loss_order_types]
+        take_profit = [o for o in orders if o.order_type in self._take_profit_order_types]
+        if len(stop_loss) != 1 or len(take_profit) != 1:
+            return None
+
+        sl_order, tp_order = stop_loss[0], take_profit[0]
+        if (
+            sl_order.instrument_id != tp_order.instrument_id
+            or sl_order.side != tp_order.side
+            or sl_order.quantity != tp_order.quantity
+        ):
+            return None
+
+        return sl_order, tp_order
+
+    async def _submit_trigger_algo_order(self, order: Order) -> None:
+        okx_symbol = OKXSymbol(order.instrument_id.symbol.value)
+
+        okx_algo_client_order_id = self._client_order_id_generator.generate_okx_client_order_id(
+            order.client_order_id,
+        )
+
+        try:
+            response = await self._http_trade.place_algo_order(
+                instId=okx_symbol.raw_symbol,
+                tdMode=self._trade_mode,
+                side=self._enum_parser.parse_nautilus_order_side(order.side),
+                ordType=OKXAlgoOrderType.TRIGGER,
+                sz=str(order.quantity),
+                ccy=None,  # margin currency, only applicable to SPOT/FUTURES mode in CROSS tdMode
+                algoClOrdId=okx_algo_client_order_id,
+                tag=", ".join(order.tags) if order.tags else None,
+                posSide=self._position_side,
+                reduceOnly=order.is_reduce_only,
+                triggerPx=str(order.trigger_price),
+                orderPx=self._get_algo_order_px(order),
+                triggerPxType=self._enum_parser.parse_nautilus_trigger_type(order.trigger_type),
+            )
+        except Exception as e:
+            self._handle_place_algo_order_error([order], str(e))
+            return
+
+        self._handle_place_algo_order_response([order], response)
+
+    async def _submit_oco_algo_order(self, sl_order: Order, tp_order: Order) -> None:
+        okx_symbol = OKXSymbol(sl_order.instrument_id.symbol.value)
+        for order in (sl_order, tp_order):
+            if not self._check_order_validity(order, okx_symbol.instrument_type):  # logs reason
+                return
+
+        self._log.debug(f"Submitting OCO algo order for {sl_order} and {tp_order}")
+
+        for order in (sl_order, tp_order):
+            self.generate_order_submitted(
+                strategy_id=order.strategy_id,
+                instrument_id=order.instrument_id,
+                client_order_id=order.client_order_id,
+                ts_event=self._clock.timestamp_ns(),
+            )
+
+        # The algo client order ID resolves to the stop-loss leg
+        okx_algo_client_order_id = self._client_order_id_generator.generate_okx_client_order_id(
+            sl_order.client_order_id,
+        )
+
+        try:
+            response = await self._http_trade.place_algo_order(
+                instId=okx_symbol.raw_symbol,
+                tdMode=self._trade_mode,
+                side=self._enum_parser.parse_nautilus_order_side(sl_order.side),
+                ordType=OKXAlgoOrderType.OCO,
+                sz=str(sl_order.quantity),
+                ccy=None,  # margin currency, only applicable to SPOT/FUTURES mode in CROSS tdMode
+                algoClOrdId=okx_algo_client_order_id,
+                tag=", ".join(sl_order.tags) if sl_order.tags else None,
+                posSide=self._position_side,
+                reduceOnly=sl_order.is_reduce_only or tp_order.is_reduce_only,
+                tpTriggerPx=str(tp_order.trigger_price),
+                tpOrdPx=self._get_algo_order_px(tp_order),
+                tpTriggerPxType=self._enum_parser.parse_nautilus_trigger_type(
+                    tp_order.trigger_type,
+                ),
+                slTriggerPx=str(sl_order.trigger_price),
+                slOrdPx=self._get_algo_order_px(sl_order),
+                slTriggerPxType=self._enum_parser.parse_nautilus_trigger_type(
+                    sl_order.trigger_type,
+                ),
+            )
+        except Exception as e:
+            self._handle_place_algo_order_error([sl_order, tp_order], str(e))
+            return
+
+        self._handle_place_algo_order_response([sl_order, tp_order], response)
+
+    def _handle_place_algo_order_error(self, orders: list[Order], reason: str) -> None:
+        self._log.error(f"Failed to place algo order for {[o.client_order_id for o in orders]}")
+        for order in orders:
+            self.generate_order_rejected(
+                strategy_id=order.strategy_id,
+                instrument_id=order.instrument_id,
+                client_order_id=order.client_order_id,
+                reason=reason,
+                ts_event=self._clock.timestamp_ns(),
+            )
+
+    def _handle_place_algo_order_response(
+        self,
+        orders: list[Order],
+        response: OKXPlaceAlgoOrderResponse,
+    ) -> None:
+        data = next(iter(response.data), None)
+        if response.code != "0" or data is None or data.sCode != "0":
+            reason = f"{data.sCode}: {data.sMsg}" if data else f"{response.code}: {response.msg}"
+            self._handle_place_algo_order_error(orders, reason)
+            return
+
+        self._algo_orders[data.algoId] = [o.client_order_id for o in orders]
+
+        for order in orders:
+            # The "orders-algo" channel may have already accepted the order
+            if order.status != OrderStatus.SUBMITTED:
+                continue
+            self.generate_order_accepted(
+                strategy_id=order.strategy_id,
+                instrument_id=order.instrument_id,
+                client_order_id=order.client_order_id,
+                venue_order_id=VenueOrderId(data.algoId),
+                ts_event=self._clock.timestamp_ns(),
+            )
+
+    async def _modify_algo_order(
+        self,
+        command: ModifyOrder,
+        order: Order,
+        okx_symbol: OKXSymbol,
+    ) -> None:
+        algo_id = order.venue_order_id.value if order.venue_order_id else None
+        if algo_id is None:
+            self._log.error(f"Cannot modify algo order {order.client_order_id!r}: not yet accepted")
+            return
+
+        new_sz = str(command.quantity) if command.quantity else None
+        new_trigger_px = str(command.trigger_price) if command.trigger_price else None
+        new_ord_px = str(command.price) if command.price and order.has_price else None
+
+        is_oco = len(self._algo_orders.get(algo_id, [])) > 1
+        is_stop_loss = order.order_type in self._stop_loss_order_types
+
+        try:
+            response = await self._http_trade.amend_algo_order(
+                instId=okx_symbol.raw_symbol,
+                algoId=algo_id,
+                cxlOnFail=False,
+                newSz=new_sz,
+                newTriggerPx=None if is_oco else new_trigger_px,
+                newOrdPx=None if is_oco else new_ord_px,
+                newTpTriggerPx=new_trigger_px if is_oco and not is_stop_loss else None,
+                newTpOrdPx=new_ord_px if is_oco and not is_stop_loss else None,
+                newSlTriggerPx=new_trigger_px if is_oco and is_stop_loss else None,
+                newSlOrdPx=new_ord_px if is_oco and is_stop_loss else None,
+            )
+            data = next(iter(response.data), None)
+            if response.code != "0" or data is None or data.sCode != "0":
+                reason = (
+                    f"{data.sCode}: {data.sMsg}" if data else f"{response.code}: {response.msg}"
+                )
+            else:
+                return  # order updated event generated from the "orders-algo" channel
+        except Exception as e:
+            reason = str(e)
+
+        self._log.error(f"Failed to amend algo order {order.client_order_id!r}: {reason}")
+        self.generate_order_modify_rejected(
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=order.client_order_id,
+            venue_order_id=order.venue_order_id,
+            reason=reason,
+            ts_event=self._clock.timestamp_ns(),
+        )
+
+    async def _cancel_algo_orders(self, okx_symbol: OKXSymbol, orders: list[Order]) -> None:
+        orders_by_algo_id: dict[str, list[Order]] = {}
+        for order in orders:
+            if order.venue_order_id is None:
+                self._log.error(
+                    f"Cannot cancel algo order {order.client_order_id!r}: not yet accepted",
+                )
+                continue
+            orders_by_algo_id.setdefault(order.venue_order_id.value, []).append(order)
+
+        # OKX allows canceling up to 10 algo orders per request
+        algo_ids = list(orders_by_algo_id)
+        for i in range(0, len(algo_ids), 10):
+            batch = algo_ids[i : i + 10]
+            rejections: dict[str, str] = {}
+            try:
+                response = await self._http_trade.cancel_algo_orders(
+                    instId=okx_symbol.raw_symbol,
+                    algoIds=batch,
+                )
+                for data in response.data:
+                    if data.sCode != "0":
+                        rejections[data.algoId] = f"{data.sCode}: {data.sMsg}"
+                if response.code != "0" and not rejections:
+                    rejections = {algo_id: f"{response.code}: {response.msg}" for algo_id in batch}
+            except Exception as e:
+                rejections = {algo_id: str(e) for algo_id in batch}
+
+            # Order canceled events are generated from the "orders-algo" channel
+            for algo_id, reason in rejections.items():
+                for order in orders_by_algo_id.get(algo_id, []):
+                    self._log.error(f"Failed to cancel algo order {order.client_order_id!r}")
+                    self.generate_order_cancel_rejected(
+                        strategy_id=order.strategy_id,
+                        instrument_id=order.instrument_id,
+                        client_order_id=order.client_order_id,
+                        venue_order_id=order.venue_order_id,
+                        reason=reason,
+                        ts_event=self._clock.timestamp_ns(),
+                    )
+
+    def _handle_ws_business_message(self, raw: bytes) -> None:
+        if raw == b"pong":
+            self._ws_business_client._last_pong = self._clock.utc_now()
+            return
+
+        self._handle_ws_message(raw)
+
     def _handle_ws_message(self, raw: bytes) -> None:  # noqa: C901
         # Uncomment for development
         # self._log.info(str(json.dumps(msgspec.json.decode(raw), indent=4)), color=LogColor.MAGENTA)
@@ -1195,12 +1501,12 @@ class OKXExecutionClient(LiveExecutionClient):
                     channel = event_msg.channel  # channel won't be None here
                     if channel:
                         ws_base_url_type = OKX_CHANNEL_WS_BASE_URL_TYPE_MAP[channel]
-                        assert self._ws_client.ws_base_url_type == ws_base_url_type, (
-                            "The websocket client's base url type does not match the expected base url "
-                            f"type for this channel ({channel}), got client type: {ws_base_url_type=} "
-                            f"vs. channel inferred type: {ws_base_url_type}."
+                        ws_client = (
+                            self._ws_business_client
+                            if ws_base_url_type == OKXWsBaseUrlType.BUSINESS
+                            else self._ws_client
                         )
-                        self._ws_client.update_channel_count(channel, int(event_msg.connCount))
+                        ws_client.update_channel_count(channel, int(event_msg.connCount))
 
             elif msg.is_push_data_msg:
                 try:
@@ -1218,6 +1524,7 @@ class OKXExecutionClient(LiveExecutionClient):
                     "account",
                     "fills",
                     "orders",
+                    "orders-algo",
                     "positions",
                 ]
                 if channel not in EXEC_CLIENT_SUPPORTED_PUSH_DATA_CHANNELS:
@@ -1234,6 +1541,8 @@ class OKXExecutionClient(LiveExecutionClient):
                     self._handle_fills(raw)
                 elif channel == "orders":
                     self._handle_orders(raw)
+                elif channel == "orders-algo":
+                    self._handle_orders_algo(raw)
                 elif channel == "positions":
                     self._handle_positions(raw)
                 else:
@@ -1246,9 +1555,9 @@ class OKXExecutionClient(LiveExecutionClient):
                 self._handle_order_msg(raw)
 
             elif msg.is_algo_order_msg:
-                self._log.error(
-                    "Handling algo order websocket messages is not yet implemented. Raw message: "
-                    f"{raw.decode()}",
+                self._log.debug(
+                    "Algo orders are placed through the HTTP API, ignoring algo order websocket "
+                    f"message: {raw.decode()}",
                 )
             else:
                 self._log.error(
@@ -1388,12 +1697,23 @@ class OKXExecutionClient(LiveExecutionClient):
                 )
                 continue
 
-            client_order_id = self._client_order_id_generator.get_client_order_id(
-                order_data.clOrdId,
-            )
-
             venue_order_id = VenueOrderId(order_data.ordId)
 
+            # Orders placed by a triggered algo order reference the algo ID
+            is_algo_child = bool(order_data.algoId)
+            if is_algo_child:
+                if self._is_algo_trigger_pending(order_data.algoId):
+                    # Triggered OCO leg is not yet known, replay once "orders-algo" reports it
+                    self._algo_pending_child_msgs.setdefault(order_data.algoId, []).append(raw)
+                    continue
+                client_order_id = self._algo_triggered_orders.get(
+                    order_data.algoId,
+                ) or self._client_order_id_generator.get_client_order_id(order_data.algoClOrdId)
+            else:
+                client_order_id = self._client_order_id_generator.get_client_order_id(
+                    order_data.clOrdId,
+                )
+
             position_id = None
             if client_order_id:
                 position_id = self._cache.position_id(client_order_id)
@@ -1428,7 +1748,15 @@ class OKXExecutionClient(LiveExecutionClient):
                 self._send_order_status_report(report)
                 return
 
-            if order_data.state is OKXOrderStatus.LIVE:
+            if is_algo_child:
+                self._update_algo_child_venue_order_id(
+                    instrument,
+                    client_order_id,
+                    venue_order_id,
+                    ts_event,
+                )
+
+            if order_data.state is OKXOrderStatus.LIVE and not is_algo_child:
                 order = self._cache.order(report.client_order_id)
                 if order is None:
                     self._log.error(
@@ -1511,6 +1839,12 @@ class OKXExecutionClient(LiveExecutionClient):
                 return
 
             if order_data.state in [OKXOrderStatus.FILLED, OKXOrderStatus.PARTIALLY_FILLED]:
+                order_type = self._enum_parser.parse_okx_order_type(order_data.ordType)
+                if is_algo_child:
+                    cached_order = self._cache.order(client_order_id)
+                    if cached_order is not None:
+                        order_type = cached_order.order_type
+
                 self._log.debug("Generating order filled event", LogColor.MAGENTA)
                 self.generate_order_filled(
                     strategy_id=strategy_id,
@@ -1520,7 +1854,7 @@ class OKXExecutionClient(LiveExecutionClient):
                     venue_position_id=position_id,
                     trade_id=TradeId(order_data.tradeId),
                     order_side=self._enum_parser.parse_okx_order_side(order_data.side),
-                    order_type=self._enum_parser.parse_okx_order_type(order_data.ordType),
+                    order_type=order_type,
                     last_qty=order_data.get_fill_sz(instrument.size_precision),
                     last_px=order_data.get_fill_px(instrument.price_precision),
                     quote_currency=instrument.quote_currency,
@@ -1532,6 +1866,204 @@ class OKXExecutionClient(LiveExecutionClient):
                     ts_event=ts_event,
                 )
 
+    def _is_algo_trigger_pending(self, algo_id: str) -> bool:
+        client_order_ids = self._algo_orders.get(algo_id, [])
+        return len(client_order_ids) > 1 and algo_id not in self._algo_triggered_orders
+
+    def _update_algo_child_venue_order_id(
+        self,
+        instrument: Instrument,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId,
+        ts_event: int,
+    ) -> None:
+        order: Order | None = self._cache.order(client_order_id)
+        if order is None or order.venue_order_id == venue_order_id:
+            return
+
+        # The triggered algo order continues at the venue under a new order ID
+        self._log.debug(
+            f"Updating {client_order_id!r} venue order ID from {order.venue_order_id!r} to "
+            f"{venue_order_id!r} for triggered algo order",
+            LogColor.MAGENTA,
+        )
+        self.generate_order_updated(
+            strategy_id=order.strategy_id,
+            instrument_id=instrument.id,
+            client_order_id=client_order_id,
+            venue_order_id=venue_order_id,
+            quantity=order.quantity,
+            price=order.price if order.has_price else None,
+            trigger_price=order.trigger_price,
+            ts_event=ts_event,
+            venue_order_id_modified=True,
+        )
+
+    def _get_algo_client_order_ids(self, algo_data: OKXWsOrdersAlgoData) -> list[ClientOrderId]:
+        client_order_ids = self._algo_orders.get(algo_data.algoId)
+        if client_order_ids:
+            return client_order_ids
+
+        # Not placed during this session, the algo client order ID resolves to a single order
+        client_order_id = self._client_order_id_generator.get_client_order_id(
+            algo_data.algoClOrdId,
+        )
+        if client_order_id is None:
+            return []
+
+        self._algo_orders[algo_data.algoId] = [client_order_id]
+        return [client_order_id]
+
+    def _get_algo_leg_prices(self, order: Order, algo_data: OKXWsOrdersAlgoData) -> tuple[str, str]:
+        if algo_data.ordType != OKXAlgoOrderType.OCO:
+            return algo_data.triggerPx, algo_data.ordPx
+        if order.order_type in self._stop_loss_order_types:
+            return algo_data.slTriggerPx, algo_data.slOrdPx
+        return algo_data.tpTriggerPx, algo_data.tpOrdPx
+
+    def _handle_orders_algo(self, raw: bytes) -> None:  # noqa: C901
+        try:
+            orders_algo_push_data: OKXWsOrdersAlgoPushDataMsg = (
+                self._decoder_ws_orders_algo_msg.decode(raw)
+            )
+        except Exception as e:
+            self._log.error(
+                f"Failed to decode websocket orders-algo push data message: {raw.decode()} with "
+                f"error {e}",
+            )
+            return
+
+        for algo_data in orders_algo_push_data.data:
+            instrument = self._instrument_provider.find_conditional(algo_data.instId)
+            if instrument is None:
+                self._log.error(
+                    f"Could not find instrument for raw symbol {algo_data.instId!r}, which is "
+                    f"needed to correctly parse orders-algo push data message: {raw.decode()}",
+                )
+                continue
+
+            client_order_ids = self._get_algo_client_order_ids(algo_data)
+            orders: list[Order] = [
+                order for c in client_order_ids if (order := self._cache.order(c)) is not None
+            ]
+            if not orders:
+                self._log.debug(
+                    f"Cannot find orders in cache for algo order {algo_data.algoId!r}, this is "
+                    f"likely an EXTERNAL algo order. Algo order data received: {algo_data}",
+                    LogColor.MAGENTA,
+                )
+                continue
+
+            venue_order_id = VenueOrderId(algo_data.algoId)
+            ts_event = millis_to_nanos(Decimal(algo_data.uTime))
+
+            if algo_data.state == OKXAlgoOrderStatus.LIVE:
+                for order in orders:
+                    if order.status == OrderStatus.SUBMITTED:
+                        self.generate_order_accepted(
+                            strategy_id=order.strategy_id,
+                            instrument_id=instrument.id,
+                            client_order_id=order.client_order_id,
+                            venue_order_id=venue_order_id,
+                            ts_event=ts_event,
+                        )
+                    elif order.status == OrderStatus.PENDING_UPDATE and algo_data.amendResult:
+                        self._handle_algo_order_amended(instrument, order, algo_data, ts_event)
+
+            elif algo_data.state == OKXAlgoOrderStatus.EFFECTIVE:
+                triggered_order = orders[0]
+                if len(orders) > 1 and algo_data.is_triggered_take_profit:
+                    triggered_order = next(
+                        o for o in orders if o.order_type in self._take_profit_order_types
+                    )
+                self._algo_triggered_orders[algo_data.algoId] = triggered_order.client_order_id
+
+                for order in orders:
+                    if order.is_closed:
+                        continue
+                    if order is not triggered_order:
+                        # Remaining OCO leg is canceled by the venue
+                        self.generate_order_canceled(
+                            strategy_id=order.strategy_id,
+                            instrument_id=instrument.id,
+                            client_order_id=order.client_order_id,
+                            venue_order_id=order.venue_order_id,
+                            ts_event=ts_event,
+                        )
+                    elif order.order_type in (OrderType.STOP_LIMIT, OrderType.LIMIT_IF_TOUCHED):
+                        self.generate_order_triggered(
+                            strategy_id=order.strategy_id,
+                            instrument_id=instrument.id,
+                            client_order_id=order.client_order_id,
+                            venue_order_id=order.venue_order_id,
+                            ts_event=ts_event,
+                        )
+
+                for child_raw in self._algo_pending_child_msgs.pop(algo_data.algoId, []):
+                    self._handle_orders(child_raw)
+
+            elif algo_data.state in (OKXAlgoOrderStatus.CANCELED, OKXAlgoOrderStatus.ORDER_FAILED):
+                if algo_data.state == OKXAlgoOrderStatus.ORDER_FAILED:
+                    self._log.warning(
+                        f"Algo order {algo_data.algoId!r} failed with code {algo_data.failCode!r}",
+                    )
+                for order in orders:
+                    if order.status == OrderStatus.SUBMITTED:
+                        self.generate_order_rejected(
+                            strategy_id=order.strategy_id,
+                            instrument_id=instrument.id,
+                            client_order_id=order.client_order_id,
+                            reason=f"algo order {algo_data.state.value}: {algo_data.failCode}",
+                            ts_event=ts_event,
+                        )
+                    elif not order.is_closed:
+                        self.generate_order_canceled(
+                            strategy_id=order.strategy_id,
+                            instrument_id=instrument.id,
+                            client_order_id=order.client_order_id,
+                            venue_order_id=order.venue_order_id,
+                            ts_event=ts_event,
+                        )
+                self._algo_orders.pop(algo_data.algoId, None)
+                self._algo_pending_child_msgs.pop(algo_data.algoId, None)
+
+            else:
+                self._log.debug(f"Received algo order state {algo_data.state!r}: {algo_data}")
+
+    def _handle_algo_order_amended(
+        self,
+        instrument: Instrument,
+        order: Order,
+        algo_data: OKXWsOrdersAlgoData,
+        ts_event: int,
+    ) -> None:
+        if algo_data.amendResult != "0":
+            self.generate_order_modify_rejected(
+                strategy_id=order.strategy_id,
+                instrument_id=instrument.id,
+                client_order_id=order.client_order_id,
+                venue_order_id=order.venue_order_id,
+                reason=f"algo order amendment failed with result {algo_data.amendResult!r}",
+                ts_event=ts_event,
+            )
+            return
+
+        trigger_px, ord_px = self._get_algo_leg_prices(order, algo_data)
+        self.generate_order_updated(
+            strategy_id=order.strategy_id,
+            instrument_id=instrument.id,
+            client_order_id=order.client_order_id,
+            venue_order_id=order.venue_order_id,
+            quantity=instrument.make_qty(algo_data.sz),
+            price=(
+                instrument.make_price(ord_px)
+                if order.has_price and ord_px not in ("", "-1")
+                else None
+            ),
+            trigger_price=instrument.make_price(trigger_px) if trigger_px else None,
+            ts_event=ts_event,
+        )
+
     def _handle_positions(self, raw: bytes) -> None:
         self._log.debug(
             "Got positions message. Nothing to do because nautilus updates positions from fills. "
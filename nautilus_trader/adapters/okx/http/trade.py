@@ -15,6 +15,7 @@
 
 from typing import Literal
 
+from nautilus_trader.adapters.okx.common.enums import OKXAlgoOrderType
 from nautilus_trader.adapters.okx.common.enums import OKXInstrumentType
 from nautilus_trader.adapters.okx.common.enums import OKXMarginMode
 from nautilus_trader.adapters.okx.common.enums import OKXOrderSide
@@ -24,9 +25,14 @@ from nautilus_trader.adapters.okx.common.enums import OKXPositionSide
 from nautilus_trader.adapters.okx.common.enums import OKXSelfTradePreventionMode
 from nautilus_trader.adapters.okx.common.enums import OKXTradeMode
 from nautilus_trader.adapters.okx.common.enums import OKXTransactionType
+from nautilus_trader.adapters.okx.common.enums import OKXTriggerType
+from nautilus_trader.adapters.okx.endpoints.trade.amend_algos import OKXAmendAlgoOrderPostParams
+from nautilus_trader.adapters.okx.endpoints.trade.amend_algos import OKXAmendAlgosEndpoint
 from nautilus_trader.adapters.okx.endpoints.trade.amend_order import OKXAmendOrderAttachAlgoOrds
 from nautilus_trader.adapters.okx.endpoints.trade.amend_order import OKXAmendOrderEndpoint
 from nautilus_trader.adapters.okx.endpoints.trade.amend_order import OKXAmendOrderPostParams
+from nautilus_trader.adapters.okx.endpoints.trade.cancel_algos import OKXCancelAlgoOrderPostParams
+from nautilus_trader.adapters.okx.endpoints.trade.cancel_algos import OKXCancelAlgosEndpoint
 from nautilus_trader.adapters.okx.endpoints.trade.cancel_order import OKXCancelOrderEndpoint
 from nautilus_trader.adapters.okx.endpoints.trade.cancel_order import OKXCancelOrderPostParams
 from nautilus_trader.adapters.okx.endpoints.trade.close_position import OKXClosePositionEndpoint
@@ -41,16 +47,21 @@ from nautilus_trader.adapters.okx.endpoints.trade.orders_history import OKXOrder
 from nautilus_trader.adapters.okx.endpoints.trade.orders_history import OKXOrderHistoryGetParams
 from nautilus_trader.adapters.okx.endpoints.trade.orders_pending import OKXOrdersPendingEndpoint
 from nautilus_trader.adapters.okx.endpoints.trade.orders_pending import OKXOrdersPendingGetParams
+from nautilus_trader.adapters.okx.endpoints.trade.place_algo_order import OKXPlaceAlgoOrderEndpoint
+from nautilus_trader.adapters.okx.endpoints.trade.place_algo_order import OKXPlaceAlgoOrderPostParams
 from nautilus_trader.adapters.okx.endpoints.trade.place_order import OKXPlaceOrderAttachAlgoOrds
 from nautilus_trader.adapters.okx.endpoints.trade.place_order import OKXPlaceOrderEndpoint
 from nautilus_trader.adapters.okx.endpoints.trade.place_order import OKXPlaceOrderPostParams
 from nautilus_trader.adapters.okx.http.client import OKXHttpClient
+from nautilus_trader.adapters.okx.schemas.trade import OKXAmendAlgoOrderResponse
 from nautilus_trader.adapters.okx.schemas.trade import OKXAmendOrderResponse
+from nautilus_trader.adapters.okx.schemas.trade import OKXCancelAlgoOrderResponse
 from nautilus_trader.adapters.okx.schemas.trade import OKXCancelOrderResponse
 from nautilus_trader.adapters.okx.schemas.trade import OKXClosePositionResponse
 from nautilus_trader.adapters.okx.schemas.trade import OKXFillsHistoryResponse
 from nautilus_trader.adapters.okx.schemas.trade import OKXFillsResponse
 from nautilus_trader.adapters.okx.schemas.trade import OKXOrderDetailsResponse
+from nautilus_trader.adapters.okx.schemas.trade import OKXPlaceAlgoOrderResponse
 from nautilus_trader.adapters.okx.schemas.trade import OKXPlaceOrderResponse
 from nautilus_trader.common.component import LiveClock
 from nautilus_trader.core.correctness import PyCondition
@@ -76,6 +87,9 @@ class OKXTradeHttpAPI:
         self._endpoint_close_position = OKXClosePositionEndpoint(client, self.base_endpoint)
         self._endpoint_fills_history = OKXFillsHistoryEndpoint(client, self.base_endpoint)
         self._endpoint_fills = OKXFillsEndpoint(client, self.base_endpoint)
+        self._endpoint_place_algo_order = OKXPlaceAlgoOrderEndpoint(client, self.base_endpoint)
+        self._endpoint_amend_algos = OKXAmendAlgosEndpoint(client, self.base_endpoint)
+        self._endpoint_cancel_algos = OKXCancelAlgosEndpoint(client, self.base_endpoint)
 
     async def fetch_order_details(
         self,
@@ -301,6 +315,96 @@ class OKXTradeHttpAPI:
         )
         return response
 
+    async def place_algo_order(
+        self,
+        instId: str,
+        tdMode: OKXTradeMode,
+        side: OKXOrderSide,
+        ordType: OKXAlgoOrderType,
+        sz: str,
+        ccy: str | None = None,
+        algoClOrdId: str | None = None,
+        tag: str | None = None,
+        posSide: OKXPositionSide = OKXPositionSide.NET,
+        reduceOnly: bool = False,
+        triggerPx: str | None = None,
+        orderPx: str | None = None,
+        triggerPxType: OKXTriggerType | None = None,
+        tpTriggerPx: str | None = None,
+        tpOrdPx: str | None = None,
+        tpTriggerPxType: OKXTriggerType | None = None,
+        slTriggerPx: str | None = None,
+        slOrdPx: str | None = None,
+        slTriggerPxType: OKXTriggerType | None = None,
+    ) -> OKXPlaceAlgoOrderResponse:
+        response = await self._endpoint_place_algo_order.post(
+            OKXPlaceAlgoOrderPostParams(
+                instId=instId,
+                tdMode=tdMode,
+                side=side,
+                ordType=ordType,
+                sz=sz,
+                ccy=ccy,
+                algoClOrdId=algoClOrdId,
+                tag=tag,
+                posSide=posSide,
+                reduceOnly=reduceOnly,
+                triggerPx=triggerPx,
+                orderPx=orderPx,
+                triggerPxType=triggerPxType,
+                tpTriggerPx=tpTriggerPx,
+                tpOrdPx=tpOrdPx,
+                tpTriggerPxType=tpTriggerPxType,
+                slTriggerPx=slTriggerPx,
+                slOrdPx=slOrdPx,
+                slTriggerPxType=slTriggerPxType,
+            ),
+        )
+        return response
+
+    async def amend_algo_order(
+        self,
+        instId: str,
+        algoId: str | None = None,
+        algoClOrdId: str | None = None,
+        cxlOnFail: bool = False,  # if should automatically cancel when amendment fails
+        reqId: str | None = None,  # client request id for the amendment
+        newSz: str | None = None,
+        newTriggerPx: str | None = None,
+        newOrdPx: str | None = None,
+        newTpTriggerPx: str | None = None,
+        newTpOrdPx: str | None = None,
+        newSlTriggerPx: str | None = None,
+        newSlOrdPx: str | None = None,
+    ) -> OKXAmendAlgoOrderResponse:
+        response = await self._endpoint_amend_algos.post(
+            OKXAmendAlgoOrderPostParams(
+                instId=instId,
+                algoId=algoId,
+                algoClOrdId=algoClOrdId,
+                cxlOnFail=cxlOnFail,
+                reqId=reqId,
+                newSz=newSz,
+                newTriggerPx=newTriggerPx,
+                newOrdPx=newOrdPx,
+                newTpTriggerPx=newTpTriggerPx,
+                newTpOrdPx=newTpOrdPx,
+                newSlTriggerPx=newSlTriggerPx,
+                newSlOrdPx=newSlOrdPx,
+            ),
+        )
+        return response
+
+    async def cancel_algo_orders(
+        self,
+        instId: str,
+        algoIds: list[str],
+    ) -> OKXCancelAlgoOrderResponse:
+        response = await self._endpoint_cancel_algos.post(
+            [OKXCancelAlgoOrderPostParams(instId=instId, algoId=algoId) for algoId in algoIds],
+        )
+        return response
+
     async def close_position(
         self,
         instId: str,
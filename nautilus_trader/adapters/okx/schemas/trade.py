@@ -111,6 +111,71 @@ class OKXAmendOrderResponse(msgspec.Struct):
     outTime: str  # milliseconds when response leaves REST gateway
 
 
+################################################################################
+# Place Algo Order: POST /api/v5/trade/order-algo
+################################################################################
+
+
+class OKXPlaceAlgoOrderData(msgspec.Struct):
+    algoId: str
+    sCode: str  # event code, "0" means success
+    sMsg: str  # rejection or success message of event execution
+    algoClOrdId: str = ""
+    clOrdId: str = ""
+    tag: str = ""
+
+
+class OKXPlaceAlgoOrderResponse(msgspec.Struct):
+    code: str
+    msg: str
+    data: list[OKXPlaceAlgoOrderData]
+    inTime: str | None = None  # milliseconds when request hit REST gateway
+    outTime: str | None = None  # milliseconds when response leaves REST gateway
+
+
+################################################################################
+# Cancel Algo Orders: POST /api/v5/trade/cancel-algos
+################################################################################
+
+
+class OKXCancelAlgoOrderData(msgspec.Struct):
+    algoId: str
+    sCode: str  # event code, "0" means success
+    sMsg: str  # rejection or success message of event execution
+    algoClOrdId: str = ""
+    clOrdId: str = ""
+    tag: str = ""
+
+
+class OKXCancelAlgoOrderResponse(msgspec.Struct):
+    code: str
+    msg: str
+    data: list[OKXCancelAlgoOrderData]
+    inTime: str | None = None  # milliseconds when request hit REST gateway
+    outTime: str | None = None  # milliseconds when response leaves REST gateway
+
+
+################################################################################
+# Amend Algo Order: POST /api/v5/trade/amend-algos
+################################################################################
+
+
+class OKXAmendAlgoOrderData(msgspec.Struct):
+    algoId: str
+    sCode: str  # event code, "0" means success
+    sMsg: str  # rejection or success message of event execution
+    algoClOrdId: str = ""
+    reqId: str = ""  # Client Request ID as assigned by the client for order amendment
+
+
+class OKXAmendAlgoOrderResponse(msgspec.Struct):
+    code: str
+    msg: str
+    data: list[OKXAmendAlgoOrderData]
+    inTime: str | None = None  # milliseconds when request hit REST gateway
+    outTime: str | None = None  # milliseconds when response leaves REST gateway
+
+
 ################################################################################
 # Close Position: POST /api/v5/trade/close-position
 ################################################################################
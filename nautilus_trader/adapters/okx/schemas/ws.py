@@ -19,6 +19,8 @@ from typing import Literal
 
 import msgspec
 
+from nautilus_trader.adapters.okx.common.enums import OKXAlgoOrderStatus
+from nautilus_trader.adapters.okx.common.enums import OKXAlgoOrderType
 from nautilus_trader.adapters.okx.common.enums import OKXEnumParser
 from nautilus_trader.adapters.okx.common.enums import OKXExecutionType
 from nautilus_trader.adapters.okx.common.enums import OKXInstrumentType
@@ -1071,3 +1073,67 @@ def decoder_ws_fills() -> msgspec.json.Decoder:
 ################################################################################
 # Business - Candlesticks
 ################################################################################
+
+################################################################################
+# Business - Algo orders
+################################################################################
+
+
+class OKXWsOrdersAlgoData(msgspec.Struct):
+    instType: OKXInstrumentType
+    instId: str
+    algoId: str
+    ordType: OKXAlgoOrderType
+    side: OKXOrderSide
+    state: OKXAlgoOrderStatus
+    sz: str
+    uTime: str
+    cTime: str
+    algoClOrdId: str = ""
+    ordId: str = ""  # latest order ID, empty until the algo order is triggered
+    ordIdList: list[str] = []  # order ID list, for OCO orders there can be several
+    posSide: OKXPositionSide = OKXPositionSide.NET
+    tdMode: OKXTradeMode | None = None
+    reduceOnly: str = "false"
+    triggerPx: str = ""
+    triggerPxType: OKXTriggerType = OKXTriggerType.NONE
+    ordPx: str = ""  # "-1" for market orders
+    tpTriggerPx: str = ""
+    tpTriggerPxType: OKXTriggerType = OKXTriggerType.NONE
+    tpOrdPx: str = ""  # "-1" for market orders
+    slTriggerPx: str = ""
+    slTriggerPxType: OKXTriggerType = OKXTriggerType.NONE
+    slOrdPx: str = ""  # "-1" for market orders
+    actualSz: str = ""  # actual order quantity when triggered
+    actualPx: str = ""  # actual order price when triggered
+    actualSide: Literal["tp", "sl", ""] = ""  # which leg was triggered for OCO orders
+    triggerTime: str = ""
+    tag: str = ""
+    amendResult: str = ""  # "0" success, "-1" failure, "" if not amended
+    reqId: str = ""
+    failCode: str = ""  # "0" or "" unless the algo order failed to trigger
+
+    @property
+    def is_triggered_take_profit(self) -> bool:
+        return self.actualSide == "tp"
+
+    @property
+    def is_triggered_stop_loss(self) -> bool:
+        return self.actualSide == "sl"
+
+
+class OKXWsOrdersAlgoArg(msgspec.Struct):
+    channel: str
+    uid: str
+    instType: OKXInstrumentType
+    instFamily: str | None = None
+    instId: str | None = None
+
+
+class OKXWsOrdersAlgoPushDataMsg(msgspec.Struct):
+    arg: OKXWsOrdersAlgoArg
+    data: list[OKXWsOrdersAlgoData]
+
+
+def decoder_ws_orders_algo() -> msgspec.json.Decoder:
+    return msgspec.json.Decoder(OKXWsOrdersAlgoPushDataMsg)
@@ -107,6 +107,7 @@ OKX_CHANNEL_WS_BASE_URL_TYPE_MAP = {
     "fills": OKXWsBaseUrlType.PRIVATE,
     # Business:
     "trades-all": OKXWsBaseUrlType.BUSINESS,
+    "orders-algo": OKXWsBaseUrlType.BUSINESS,
     **{f"candle{bar_size.value}": OKXWsBaseUrlType.BUSINESS for bar_size in list(OKXBarSize)},
 }
 
@@ -562,6 +563,55 @@ class OKXWebsocketClient:
         payload = {"op": "unsubscribe", "args": [subscription]}
         await self._send(payload)
 
+    @check_business
+    async def subscribe_orders_algo(
+        self,
+        instType: OKXInstrumentType = OKXInstrumentType.ANY,
+        instFamily: str | None = None,
+        instId: str | None = None,
+    ) -> None:
+        if self._client is None:
+            self._log.warning("Cannot subscribe: not connected")
+            return
+
+        subscription = {"channel": "orders-algo", "instType": instType.value}
+        if instFamily:
+            subscription.update({"instFamily": instFamily})
+        if instId:
+            subscription.update({"instId": instId})
+
+        if subscription in self._subscriptions:
+            return
+
+        self._subscriptions.append(subscription)
+        payload = {"op": "subscribe", "args": [subscription]}
+        await self._send(payload)
+
+    @check_business
+    async def unsubscribe_orders_algo(
+        self,
+        instType: OKXInstrumentType = OKXInstrumentType.ANY,
+        instFamily: str | None = None,
+        instId: str | None = None,
+    ) -> None:
+        if self._client is None:
+            self._log.warning("Cannot unsubscribe: not connected")
+            return
+
+        subscription = {"channel": "orders-algo", "instType": instType.value}
+        if instFamily:
+            subscription.update({"instFamily": instFamily})
+        if instId:
+            subscription.update({"instId": instId})
+
+        if subscription not in self._subscriptions:
+            self._log.warning(f"Cannot unsubscribe '{subscription}': not subscribed")
+            return
+
+        self._subscriptions.remove(subscription)
+        payload = {"op": "unsubscribe", "args": [subscription]}
+        await self._send(payload)
+
     ################################################################################
     # Private
     ################################################################################
@@ -17,6 +17,7 @@ from decimal import Decimal
 from typing import Any
 
 import pandas as pd
+from py_clob_client.clob_types import OrderBookSummary
 
 from nautilus_trader.adapters.polymarket.common.enums import PolymarketLiquiditySide
 from nautilus_trader.adapters.polymarket.common.enums import PolymarketOrderSide
@@ -24,6 +25,8 @@ from nautilus_trader.adapters.polymarket.common.enums import PolymarketOrderStat
 from nautilus_trader.adapters.polymarket.common.enums import PolymarketOrderType
 from nautilus_trader.adapters.polymarket.common.symbol import get_polymarket_instrument_id
 from nautilus_trader.adapters.polymarket.common.symbol import get_polymarket_token_id
+from nautilus_trader.adapters.polymarket.schemas.book import PolymarketBookLevel
+from nautilus_trader.adapters.polymarket.schemas.book import PolymarketBookSnapshot
 from nautilus_trader.adapters.polymarket.schemas.book import PolymarketTickSizeChange
 from nautilus_trader.model.currencies import USDC
 from nautilus_trader.model.enums import AssetClass
@@ -84,6 +87,35 @@ def parse_order_status(order_status: PolymarketOrderStatus) -> OrderStatus:
             return OrderStatus.FILLED
 
 
+def is_neg_risk_market(market_info: dict[str, Any]) -> bool:
+    """
+    Return whether the market is part of a negative-risk (multi-outcome) event.
+
+    Orders for negative-risk markets must be signed for the Neg Risk CTF Exchange.
+
+    """
+    return bool(market_info.get("neg_risk", False))
+
+
+def parse_book_snapshot(summary: OrderBookSummary, ts_init: int) -> PolymarketBookSnapshot:
+    """
+    Parse the CLOB REST order book summary into a websocket book snapshot message.
+
+    Levels are sorted with the best price last, consistent with the websocket snapshots.
+
+    """
+    bids = sorted(summary.bids or [], key=lambda level: float(level.price))
+    asks = sorted(summary.asks or [], key=lambda level: float(level.price), reverse=True)
+
+    return PolymarketBookSnapshot(
+        market=summary.market,
+        asset_id=summary.asset_id,
+        bids=[PolymarketBookLevel(price=level.price, size=level.size) for level in bids],
+        asks=[PolymarketBookLevel(price=level.price, size=level.size) for level in asks],
+        timestamp=summary.timestamp or str(ts_init // 1_000_000),
+    )
+
+
 def parse_instrument(
     market_info: dict[str, Any],
     token_id: str,
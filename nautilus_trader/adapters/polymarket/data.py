@@ -22,6 +22,7 @@ from py_clob_client.client import ClobClient
 
 from nautilus_trader.adapters.polymarket.common.constants import POLYMARKET_VENUE
 from nautilus_trader.adapters.polymarket.common.deltas import compute_effective_deltas
+from nautilus_trader.adapters.polymarket.common.parsing import parse_book_snapshot
 from nautilus_trader.adapters.polymarket.common.parsing import update_instrument
 from nautilus_trader.adapters.polymarket.common.symbol import get_polymarket_instrument_id
 from nautilus_trader.adapters.polymarket.common.symbol import get_polymarket_token_id
@@ -132,6 +133,8 @@ class PolymarketDataClient(LiveMarketDataClient):
         # Hot caches
         self._last_quotes: dict[InstrumentId, QuoteTick] = {}
         self._local_books: dict[InstrumentId, OrderBook] = {}
+        self._last_book_ts: dict[InstrumentId, int] = {}
+        self._book_recovery_buffers: dict[InstrumentId, list[PolymarketQuotes]] = {}
 
     async def _connect(self) -> None:
         self._log.info("Initializing instruments...")
@@ -239,10 +242,6 @@ class PolymarketDataClient(LiveMarketDataClient):
             )
             return
 
-        if self._config.compute_effective_deltas:
-            local_book = OrderBook(command.instrument_id, book_type=BookType.L2_MBP)
-            self._local_books[command.instrument_id] = local_book
-
         await self._subscribe_asset_book(command.instrument_id)
 
     async def _subscribe_quote_ticks(self, command: SubscribeQuoteTicks) -> None:
@@ -371,6 +370,14 @@ class PolymarketDataClient(LiveMarketDataClient):
         instrument: BinaryOption,
         ws_message: PolymarketBookSnapshot,
     ) -> None:
+        ts_book = int(ws_message.timestamp)
+        last_book_ts = self._last_book_ts.get(instrument.id)
+        if last_book_ts is not None and ts_book < last_book_ts:
+            self._log.debug(f"Skipping stale book snapshot for {instrument.id}")
+            return
+
+        self._last_book_ts[instrument.id] = ts_book
+
         now_ns = self._clock.timestamp_ns()
         deltas = ws_message.parse_to_snapshot(instrument=instrument, ts_init=now_ns)
 
@@ -381,15 +388,24 @@ class PolymarketDataClient(LiveMarketDataClient):
             self._last_quotes[instrument.id] = quote
             self._handle_data(quote)
 
+        # Replay any price changes buffered during recovery which are newer than the snapshot
+        buffered = self._book_recovery_buffers.pop(instrument.id, [])
+        for msg in buffered:
+            if int(msg.timestamp) > ts_book:
+                self._handle_quote(instrument=instrument, ws_message=msg)
+
     def _handle_deltas(self, instrument: BinaryOption, deltas: OrderBookDeltas) -> None:
+        book_old = self._local_books.get(instrument.id)
+        book_new = OrderBook(instrument.id, book_type=BookType.L2_MBP)
+        book_new.apply_deltas(deltas)
+        self._local_books[instrument.id] = book_new
+
         if self._config.compute_effective_deltas:
             # Compute effective deltas (reduce snapshot based on old and new book states),
             # prioritizing a smaller data footprint over computational efficiency.
             t0 = self._clock.timestamp_ns()
-            book_old = self._local_books.get(instrument.id)
-            book_new = OrderBook(instrument.id, book_type=BookType.L2_MBP)
-            book_new.apply_deltas(deltas)
-            self._local_books[instrument.id] = book_new
+            if book_old is None:
+                book_old = OrderBook(instrument.id, book_type=BookType.L2_MBP)
             deltas = compute_effective_deltas(book_old, book_new, instrument)
 
             interval_ms = (self._clock.timestamp_ns() - t0) / 1_000_000
@@ -405,16 +421,32 @@ class PolymarketDataClient(LiveMarketDataClient):
         instrument: BinaryOption,
         ws_message: PolymarketQuotes,
     ) -> None:
+        if instrument.id in self._book_recovery_buffers:
+            self._book_recovery_buffers[instrument.id].append(ws_message)
+            return
+
+        last_book_ts = self._last_book_ts.get(instrument.id)
+        if last_book_ts is None:
+            self._start_book_recovery(instrument, "price change before book snapshot")
+            self._book_recovery_buffers[instrument.id].append(ws_message)
+            return
+
+        if int(ws_message.timestamp) < last_book_ts:
+            return  # Already reflected in the current book snapshot
+
         now_ns = self._clock.timestamp_ns()
         deltas = ws_message.parse_to_deltas(instrument=instrument, ts_init=now_ns)
 
-        if self._config.compute_effective_deltas:
-            local_book = self._local_books.get(instrument.id)
-            if local_book:
-                local_book.apply_deltas(deltas)
+        local_book = self._local_books[instrument.id]
+        local_book.apply_deltas(deltas)
 
         self._handle_data(deltas)
 
+        best_bid = local_book.best_bid_price()
+        best_ask = local_book.best_ask_price()
+        if best_bid is not None and best_ask is not None and best_bid >= best_ask:
+            self._start_book_recovery(instrument, f"crossed book {best_bid} >= {best_ask}")
+
         if instrument.id in self.subscribed_quote_ticks():
             last_quote = self._last_quotes.get(instrument.id)
             if last_quote is None:
@@ -438,6 +470,30 @@ class PolymarketDataClient(LiveMarketDataClient):
                 self._last_quotes[instrument.id] = quote
                 self._handle_data(quote)
 
+    def _start_book_recovery(self, instrument: BinaryOption, reason: str) -> None:
+        if instrument.id in self._book_recovery_buffers:
+            return  # Already recovering
+
+        self._log.warning(f"Order book gap detected for {instrument.id} ({reason}), recovering")
+        self._book_recovery_buffers[instrument.id] = []
+        self.create_task(
+            self._recover_book(instrument),
+            log_msg=f"Recover order book for {instrument.id}",
+        )
+
+    async def _recover_book(self, instrument: BinaryOption) -> None:
+        token_id = get_polymarket_token_id(instrument.id)
+        try:
+            response = await asyncio.to_thread(self._http_client.get_order_book, token_id)
+            snapshot = parse_book_snapshot(response, ts_init=self._clock.timestamp_ns())
+            self._handle_book_snapshot(instrument=instrument, ws_message=snapshot)
+            self._log.info(f"Recovered order book for {instrument.id}", LogColor.BLUE)
+        except Exception as e:
+            self._log.error(f"Failed to recover order book for {instrument.id}: {e}")
+        finally:
+            # Discard buffer if not consumed (the next gap will trigger a new recovery)
+            self._book_recovery_buffers.pop(instrument.id, None)
+
     def _handle_trade(
         self,
         instrument: BinaryOption,
@@ -38,6 +38,7 @@ from nautilus_trader.adapters.polymarket.common.conversion import usdce_from_uni
 from nautilus_trader.adapters.polymarket.common.credentials import PolymarketWebSocketAuth
 from nautilus_trader.adapters.polymarket.common.enums import PolymarketEventType
 from nautilus_trader.adapters.polymarket.common.enums import PolymarketLiquiditySide
+from nautilus_trader.adapters.polymarket.common.parsing import is_neg_risk_market
 from nautilus_trader.adapters.polymarket.common.parsing import parse_order_side
 from nautilus_trader.adapters.polymarket.common.symbol import get_polymarket_condition_id
 from nautilus_trader.adapters.polymarket.common.symbol import get_polymarket_instrument_id
@@ -562,7 +563,7 @@ class PolymarketExecutionClient(LiveExecutionClient):
 
                     instrument_id = get_polymarket_instrument_id(
                         polymarket_trade.market,
-                        polymarket_trade.asset_id,
+                        polymarket_trade.get_asset_id(self._wallet_address),
                     )
                     instrument = self._cache.instrument(instrument_id)
                     if instrument is None:
@@ -807,7 +808,11 @@ class PolymarketExecutionClient(LiveExecutionClient):
             side=order_side_to_str(order.side),
             expiration=int(nanos_to_secs(expire_time_ns)),
         )
-        options = PartialCreateOrderOptions(neg_risk=False)
+        # Negative-risk orders must be signed for the Neg Risk CTF Exchange, when the
+        # instrument is not cached the client resolves this from the CLOB instead
+        instrument = self._cache.instrument(order.instrument_id)
+        neg_risk = is_neg_risk_market(instrument.info) if instrument is not None else None
+        options = PartialCreateOrderOptions(neg_risk=neg_risk)
         signing_start = self._clock.timestamp()
         signed_order = await asyncio.to_thread(  # Send to thread to avoid blocking event loop
             self._http_client.create_order,
@@ -1014,7 +1019,10 @@ class PolymarketExecutionClient(LiveExecutionClient):
             return  # Handled by order update
 
         venue_order_id = msg.venue_order_id(self._wallet_address)
-        instrument_id = get_polymarket_instrument_id(msg.market, msg.asset_id)
+        instrument_id = get_polymarket_instrument_id(
+            msg.market,
+            msg.get_asset_id(self._wallet_address),
+        )
         instrument = self._cache.instrument(instrument_id)
         if instrument is None:
             raise ValueError(f"Cannot handle ws message: instrument {instrument_id} not found")
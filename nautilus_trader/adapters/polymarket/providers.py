@@ -20,6 +20,7 @@ import msgspec
 from py_clob_client.client import ClobClient
 
 from nautilus_trader.adapters.polymarket.common.constants import POLYMARKET_VENUE
+from nautilus_trader.adapters.polymarket.common.parsing import is_neg_risk_market
 from nautilus_trader.adapters.polymarket.common.parsing import parse_instrument
 from nautilus_trader.adapters.polymarket.common.symbol import get_polymarket_condition_id
 from nautilus_trader.adapters.polymarket.common.symbol import get_polymarket_token_id
@@ -60,6 +61,48 @@ class PolymarketInstrumentProvider(InstrumentProvider):
         self._decoder = msgspec.json.Decoder()
         self._encoder = msgspec.json.Encoder()
 
+        self._token_instrument_ids: dict[str, InstrumentId] = {}
+        self._neg_risk_instrument_ids: dict[str, set[InstrumentId]] = {}
+
+    def find_by_token_id(self, token_id: str) -> BinaryOption | None:
+        """
+        Return the instrument for the given outcome token ID (if found).
+
+        Parameters
+        ----------
+        token_id : str
+            The Polymarket outcome token (asset) ID.
+
+        Returns
+        -------
+        BinaryOption or ``None``
+
+        """
+        instrument_id = self._token_instrument_ids.get(token_id)
+        if instrument_id is None:
+            return None
+        return self.find(instrument_id)
+
+    def neg_risk_instrument_ids(self, neg_risk_market_id: str) -> list[InstrumentId]:
+        """
+        Return the instrument IDs for all loaded outcome tokens of a negative-risk event.
+
+        Each outcome of a negative-risk (multi-outcome) event is a separate binary market
+        sharing the same `neg_risk_market_id`, with YES and NO tokens for each outcome.
+
+        Parameters
+        ----------
+        neg_risk_market_id : str
+            The negative-risk market ID shared by the markets of the event.
+
+        Returns
+        -------
+        list[InstrumentId]
+
+        """
+        instrument_ids = self._neg_risk_instrument_ids.get(neg_risk_market_id, set())
+        return sorted(instrument_ids, key=lambda x: x.value)
+
     async def load_all_async(self, filters: dict | None = None) -> None:
         await self._load_markets([], filters)
 
@@ -208,4 +251,10 @@ class PolymarketInstrumentProvider(InstrumentProvider):
         if instrument.expiration_ns == 0:
             self._log.warning(f"{instrument.id} expiration was `None`")
         self.add(instrument)
+
+        self._token_instrument_ids[token_id] = instrument.id
+        neg_risk_market_id = market_info.get("neg_risk_market_id")
+        if is_neg_risk_market(market_info) and neg_risk_market_id:
+            self._neg_risk_instrument_ids.setdefault(neg_risk_market_id, set()).add(instrument.id)
+
         return instrument
@@ -178,6 +178,14 @@ class PolymarketUserTrade(msgspec.Struct, tag="trade", tag_field="event_type", f
 
         raise ValueError("Invalid trade with no maker order owned my `maker_address`")
 
+    def get_asset_id(self, maker_address: str) -> str:
+        # A maker order may rest on the complementary outcome token to the taker
+        if self.trader_side == PolymarketLiquiditySide.TAKER:
+            return self.asset_id
+        else:
+            order = self.get_maker_order(maker_address)
+            return order.asset_id
+
     def liquidity_side(self) -> LiquiditySide:
         if self.trader_side == PolymarketLiquiditySide.MAKER:
             return LiquiditySide.MAKER
@@ -0,0 +1,85 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from nautilus_trader.config import LiveDataClientConfig
+from nautilus_trader.config import LiveExecClientConfig
+from nautilus_trader.config import PositiveFloat
+from nautilus_trader.config import PositiveInt
+
+
+class RestPollingDataClientConfig(LiveDataClientConfig, frozen=True):
+    """
+    Configuration for ``RestPollingDataClient`` instances.
+
+    Parameters
+    ----------
+    mapping_path : str
+        The path to the REST schema mapping file (JSON or TOML) for the venue.
+    base_url_http : str, optional
+        The base URL for the HTTP client (overrides the mapping `base_url`).
+    quote_poll_interval_secs : PositiveFloat, default 1.0
+        The interval (seconds) between polls for quotes.
+    trade_poll_interval_secs : PositiveFloat, default 1.0
+        The interval (seconds) between polls for trades.
+    bar_poll_interval_secs : PositiveFloat, default 5.0
+        The interval (seconds) between polls for bars.
+    trades_limit : PositiveInt, default 100
+        The number of recent trades to request per poll.
+
+    """
+
+    mapping_path: str
+    base_url_http: str | None = None
+    quote_poll_interval_secs: PositiveFloat = 1.0
+    trade_poll_interval_secs: PositiveFloat = 1.0
+    bar_poll_interval_secs: PositiveFloat = 5.0
+    trades_limit: PositiveInt = 100
+
+
+class RestPollingExecClientConfig(LiveExecClientConfig, frozen=True):
+    """
+    Configuration for ``RestPollingExecutionClient`` instances.
+
+    Parameters
+    ----------
+    mapping_path : str
+        The path to the REST schema mapping file (JSON or TOML) for the venue.
+    api_key : str, optional
+        The API key for signed requests.
+        If ``None`` then will source the `{VENUE}_API_KEY` environment variable.
+    api_secret : str, optional
+        The API secret for signed requests.
+        If ``None`` then will source the `{VENUE}_API_SECRET` environment variable.
+    base_url_http : str, optional
+        The base URL for the HTTP client (overrides the mapping `base_url`).
+    order_poll_interval_secs : PositiveFloat, default 1.0
+        The interval (seconds) between polls for the status of open orders.
+    account_poll_interval_secs : PositiveFloat, default 5.0
+        The interval (seconds) between polls for the account balances.
+    max_retries : PositiveInt, optional
+        The maximum number of times a submit or cancel order request will be retried.
+    retry_delay : PositiveFloat, optional
+        The delay (seconds) between retries.
+
+    """
+
+    mapping_path: str
+    api_key: str | None = None
+    api_secret: str | None = None
+    base_url_http: str | None = None
+    order_poll_interval_secs: PositiveFloat = 1.0
+    account_poll_interval_secs: PositiveFloat = 5.0
+    max_retries: PositiveInt | None = None
+    retry_delay: PositiveFloat | None = None
@@ -0,0 +1,514 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+import asyncio
+from collections.abc import Awaitable
+from collections.abc import Callable
+from typing import TYPE_CHECKING
+from typing import Any
+
+from nautilus_trader.adapters.rest_polling.parsing import RestPollingParser
+from nautilus_trader.common.enums import LogColor
+from nautilus_trader.core.datetime import dt_to_unix_nanos
+from nautilus_trader.data.messages import RequestBars
+from nautilus_trader.data.messages import RequestInstrument
+from nautilus_trader.data.messages import RequestInstruments
+from nautilus_trader.data.messages import RequestQuoteTicks
+from nautilus_trader.data.messages import RequestTradeTicks
+from nautilus_trader.data.messages import SubscribeBars
+from nautilus_trader.data.messages import SubscribeOrderBook
+from nautilus_trader.data.messages import SubscribeQuoteTicks
+from nautilus_trader.data.messages import SubscribeTradeTicks
+from nautilus_trader.data.messages import UnsubscribeBars
+from nautilus_trader.data.messages import UnsubscribeOrderBook
+from nautilus_trader.data.messages import UnsubscribeQuoteTicks
+from nautilus_trader.data.messages import UnsubscribeTradeTicks
+from nautilus_trader.live.data_client import LiveMarketDataClient
+from nautilus_trader.model.data import BarType
+from nautilus_trader.model.enums import PriceType
+from nautilus_trader.model.identifiers import ClientId
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import TradeId
+from nautilus_trader.model.identifiers import Venue
+
+
+if TYPE_CHECKING:
+    import pandas as pd
+
+    from nautilus_trader.adapters.rest_polling.config import RestPollingDataClientConfig
+    from nautilus_trader.adapters.rest_polling.http.client import RestPollingHttpClient
+    from nautilus_trader.adapters.rest_polling.providers import RestPollingInstrumentProvider
+    from nautilus_trader.cache.cache import Cache
+    from nautilus_trader.common.component import LiveClock
+    from nautilus_trader.common.component import MessageBus
+    from nautilus_trader.model.data import QuoteTick
+    from nautilus_trader.model.data import TradeTick
+    from nautilus_trader.model.instruments import Instrument
+
+
+# The number of most recent klines requested per poll
+_BARS_POLL_LIMIT = 5
+
+
+class RestPollingDataClient(LiveMarketDataClient):
+    """
+    Provides a data client which polls the REST API of a venue described by a schema mapping.
+
+    Quotes, trades and bars are polled at the configured intervals per subscription.
+    Only data which arrives after the subscription is published, quotes are only
+    published when changed, and bars are only published once closed.
+
+    Parameters
+    ----------
+    loop : asyncio.AbstractEventLoop
+        The event loop for the client.
+    client : RestPollingHttpClient
+        The REST polling HTTP client.
+    msgbus : MessageBus
+        The message bus for the client.
+    cache : Cache
+        The cache for the client.
+    clock : LiveClock
+        The clock for the client.
+    instrument_provider : RestPollingInstrumentProvider
+        The instrument provider.
+    config : RestPollingDataClientConfig
+        The configuration for the client.
+    name : str, optional
+        The custom client ID.
+
+    """
+
+    def __init__(
+        self,
+        loop: asyncio.AbstractEventLoop,
+        client: RestPollingHttpClient,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+        instrument_provider: RestPollingInstrumentProvider,
+        config: RestPollingDataClientConfig,
+        name: str | None,
+    ) -> None:
+        mapping = client.mapping
+        super().__init__(
+            loop=loop,
+            client_id=ClientId(name or mapping.venue),
+            venue=Venue(mapping.venue),
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+            instrument_provider=instrument_provider,
+        )
+
+        # Configuration
+        self._quote_poll_interval_secs = config.quote_poll_interval_secs
+        self._trade_poll_interval_secs = config.trade_poll_interval_secs
+        self._bar_poll_interval_secs = config.bar_poll_interval_secs
+        self._trades_limit = config.trades_limit
+        self._log.info(f"{config.mapping_path=}", LogColor.BLUE)
+        self._log.info(f"{config.quote_poll_interval_secs=}", LogColor.BLUE)
+        self._log.info(f"{config.trade_poll_interval_secs=}", LogColor.BLUE)
+        self._log.info(f"{config.bar_poll_interval_secs=}", LogColor.BLUE)
+
+        # HTTP API
+        self._client = client
+        self._endpoints = mapping.endpoints
+        self._parser = RestPollingParser(mapping)
+
+        # Polling state
+        self._poll_tasks: dict[tuple[str, InstrumentId | BarType], asyncio.Task] = {}
+        self._last_quotes: dict[InstrumentId, QuoteTick] = {}
+        self._last_trades: dict[InstrumentId, tuple[int, set[TradeId]]] = {}
+        self._last_bar_ts: dict[BarType, int] = {}
+
+    async def _connect(self) -> None:
+        await self._instrument_provider.initialize()
+        self._send_all_instruments_to_data_engine()
+
+    async def _disconnect(self) -> None:
+        for key in list(self._poll_tasks):
+            self._stop_polling(key)
+
+    def _send_all_instruments_to_data_engine(self) -> None:
+        for instrument in self._instrument_provider.get_all().values():
+            self._handle_data(instrument)
+
+        for currency in self._instrument_provider.currencies().values():
+            self._cache.add_currency(currency)
+
+    def _start_polling(
+        self,
+        key: tuple[str, InstrumentId | BarType],
+        poll: Callable[[], Awaitable[None]],
+        interval_secs: float,
+    ) -> None:
+        if key in self._poll_tasks:
+            self._log.warning(f"Already polling {key[0]} for {key[1]}")
+            return
+
+        self._poll_tasks[key] = self.create_task(self._poll(key, poll, interval_secs))
+
+    def _stop_polling(self, key: tuple[str, InstrumentId | BarType]) -> None:
+        task = self._poll_tasks.pop(key, None)
+        if task is not None:
+            self._log.debug(f"Canceling task 'poll_{key[0]}' for {key[1]}")
+            task.cancel()
+
+    async def _poll(
+        self,
+        key: tuple[str, InstrumentId | BarType],
+        poll: Callable[[], Awaitable[None]],
+        interval_secs: float,
+    ) -> None:
+        try:
+            while True:
+                try:
+                    await poll()
+                except asyncio.CancelledError:
+                    raise
+                except Exception as e:
+                    self._log.error(f"Error polling {key[0]} for {key[1]}: {e!r}")
+                await asyncio.sleep(interval_secs)
+        except asyncio.CancelledError:
+            self._log.debug(f"Canceled task 'poll_{key[0]}' for {key[1]}")
+
+    def _get_instrument(self, instrument_id: InstrumentId) -> Instrument | None:
+        instrument = self._instrument_provider.find(instrument_id)
+        if instrument is None:
+            self._log.error(f"Cannot find instrument for {instrument_id}")
+        return instrument
+
+    def _check_bar_type(self, bar_type: BarType) -> str | None:
+        if bar_type.is_internally_aggregated():
+            self._log.error(
+                f"Cannot poll {bar_type} bars: only EXTERNAL aggregation is available",
+            )
+            return None
+
+        if not bar_type.spec.is_time_aggregated():
+            self._log.error(f"Cannot poll {bar_type} bars: only time bars are available")
+            return None
+
+        if bar_type.spec.price_type != PriceType.LAST:
+            self._log.error(f"Cannot poll {bar_type} bars: only LAST price bars are available")
+            return None
+
+        interval = self._parser.bar_interval(bar_type)
+        if interval is None:
+            self._log.error(
+                f"Cannot poll {bar_type} bars: no interval for {bar_type.spec} in `bar_intervals`",
+            )
+        return interval
+
+    async def _subscribe_order_book_deltas(self, command: SubscribeOrderBook) -> None:
+        self._log.error(
+            "Cannot subscribe to order book deltas: not supported by the REST polling adapter",
+        )
+
+    async def _subscribe_order_book_snapshots(self, command: SubscribeOrderBook) -> None:
+        self._log.error(
+            "Cannot subscribe to order book snapshots: not supported by the REST polling adapter",
+        )
+
+    async def _subscribe_quote_ticks(self, command: SubscribeQuoteTicks) -> None:
+        if self._endpoints.quote is None:
+            self._log.error("Cannot subscribe to quotes: no `quote` endpoint in the mapping")
+            return
+
+        instrument = self._get_instrument(command.instrument_id)
+        if instrument is None:
+            return
+
+        self._start_polling(
+            ("quotes", command.instrument_id),
+            lambda: self._poll_quotes(instrument),
+            self._quote_poll_interval_secs,
+        )
+
+    async def _subscribe_trade_ticks(self, command: SubscribeTradeTicks) -> None:
+        if self._endpoints.trades is None:
+            self._log.error("Cannot subscribe to trades: no `trades` endpoint in the mapping")
+            return
+
+        instrument = self._get_instrument(command.instrument_id)
+        if instrument is None:
+            return
+
+        self._start_polling(
+            ("trades", command.instrument_id),
+            lambda: self._poll_trades(instrument),
+            self._trade_poll_interval_secs,
+        )
+
+    async def _subscribe_bars(self, command: SubscribeBars) -> None:
+        if self._endpoints.klines is None:
+            self._log.error("Cannot subscribe to bars: no `klines` endpoint in the mapping")
+            return
+
+        bar_type = command.bar_type
+        interval = self._check_bar_type(bar_type)
+        if interval is None:
+            return
+
+        instrument = self._get_instrument(bar_type.instrument_id)
+        if instrument is None:
+            return
+
+        self._start_polling(
+            ("bars", bar_type),
+            lambda: self._poll_bars(bar_type, interval, instrument),
+            self._bar_poll_interval_secs,
+        )
+
+    async def _unsubscribe_order_book_deltas(self, command: UnsubscribeOrderBook) -> None:
+        pass  # Never subscribed
+
+    async def _unsubscribe_order_book_snapshots(self, command: UnsubscribeOrderBook) -> None:
+        pass  # Never subscribed
+
+    async def _unsubscribe_quote_ticks(self, command: UnsubscribeQuoteTicks) -> None:
+        self._stop_polling(("quotes", command.instrument_id))
+        self._last_quotes.pop(command.instrument_id, None)
+
+    async def _unsubscribe_trade_ticks(self, command: UnsubscribeTradeTicks) -> None:
+        self._stop_polling(("trades", command.instrument_id))
+        self._last_trades.pop(command.instrument_id, None)
+
+    async def _unsubscribe_bars(self, command: UnsubscribeBars) -> None:
+        self._stop_polling(("bars", command.bar_type))
+        self._last_bar_ts.pop(command.bar_type, None)
+
+    async def _poll_quotes(self, instrument: Instrument) -> None:
+        endpoint = self._endpoints.quote
+        assert endpoint is not None  # Checked on subscribe
+
+        response = await self._client.request(endpoint, {"symbol": instrument.raw_symbol.value})
+        quote = self._parser.parse_quote_tick(
+            response,
+            endpoint,
+            instrument,
+            self._clock.timestamp_ns(),
+        )
+        if quote is None:
+            return
+
+        last_quote = self._last_quotes.get(instrument.id)
+        if (
+            last_quote is not None
+            and quote.bid_price == last_quote.bid_price
+            and quote.ask_price == last_quote.ask_price
+            and quote.bid_size == last_quote.bid_size
+            and quote.ask_size == last_quote.ask_size
+        ):
+            return  # Unchanged
+
+        self._last_quotes[instrument.id] = quote
+        self._handle_data(quote)
+
+    async def _poll_trades(self, instrument: Instrument) -> None:
+        endpoint = self._endpoints.trades
+        assert endpoint is not None  # Checked on subscribe
+
+        response = await self._client.request(
+            endpoint,
+            {"symbol": instrument.raw_symbol.value, "limit": self._trades_limit},
+        )
+        trades = self._parser.parse_trade_ticks(
+            response,
+            endpoint,
+            instrument,
+            self._clock.timestamp_ns(),
+        )
+        if not trades:
+            return
+
+        last = self._last_trades.get(instrument.id)
+        self._last_trades[instrument.id] = self._next_trades_state(trades, last)
+        if last is None:
+            return  # Trades before the subscription are not published
+
+        last_ts, last_trade_ids = last
+        for trade in trades:
+            if trade.ts_event < last_ts:
+                continue
+            if trade.ts_event == last_ts and trade.trade_id in last_trade_ids:
+                continue
+            self._handle_data(trade)
+
+    @staticmethod
+    def _next_trades_state(
+        trades: list[TradeTick],
+        last: tuple[int, set[TradeId]] | None,
+    ) -> tuple[int, set[TradeId]]:
+        # Trades are deduplicated by the last timestamp seen, and the trade IDs at that
+        # timestamp (as several trades can share a timestamp across polls)
+        last_ts = trades[-1].ts_event
+        trade_ids = {t.trade_id for t in trades if t.ts_event == last_ts}
+        if last is not None and last[0] == last_ts:
+            trade_ids |= last[1]
+        return last_ts, trade_ids
+
+    async def _poll_bars(self, bar_type: BarType, interval: str, instrument: Instrument) -> None:
+        endpoint = self._endpoints.klines
+        assert endpoint is not None  # Checked on subscribe
+
+        response = await self._client.request(
+            endpoint,
+            {
+                "symbol": instrument.raw_symbol.value,
+                "interval": interval,
+                "limit": _BARS_POLL_LIMIT,
+            },
+        )
+        now_ns = self._clock.timestamp_ns()
+        bars = self._parser.parse_bars(response, endpoint, bar_type, instrument, now_ns)
+        bars = [bar for bar in bars if bar.ts_event <= now_ns]  # Closed bars only
+        if not bars:
+            return
+
+        last_ts = self._last_bar_ts.get(bar_type)
+        self._last_bar_ts[bar_type] = bars[-1].ts_event
+        if last_ts is None:
+            return  # Bars before the subscription are not published
+
+        for bar in bars:
+            if bar.ts_event > last_ts:
+                self._handle_data(bar)
+
+    async def _request_instrument(self, request: RequestInstrument) -> None:
+        if request.start is not None:
+            self._log.warning(
+                f"Requesting instrument {request.instrument_id} with specified `start` which has no effect",
+            )
+
+        if request.end is not None:
+            self._log.warning(
+                f"Requesting instrument {request.instrument_id} with specified `end` which has no effect",
+            )
+
+        instrument: Instrument | None = self._instrument_provider.find(request.instrument_id)
+        if instrument is None:
+            self._log.error(f"Cannot find instrument for {request.instrument_id}")
+            return
+
+        self._handle_instrument(instrument, request.id, request.params)
+
+    async def _request_instruments(self, request: RequestInstruments) -> None:
+        if request.start is not None:
+            self._log.warning(
+                f"Requesting instruments for {request.venue} with specified `start` which has no effect",
+            )
+
+        if request.end is not None:
+            self._log.warning(
+                f"Requesting instruments for {request.venue} with specified `end` which has no effect",
+            )
+
+        all_instruments = self._instrument_provider.get_all()
+        target_instruments = []
+        for instrument in all_instruments.values():
+            if instrument.venue == request.venue:
+                target_instruments.append(instrument)
+
+        self._handle_instruments(
+            request.venue,
+            target_instruments,
+            request.id,
+            request.params,
+        )
+
+    async def _request_quote_ticks(self, request: RequestQuoteTicks) -> None:
+        self._log.error(
+            "Cannot request historical quotes: not supported by the REST polling adapter",
+        )
+
+    async def _request_trade_ticks(self, request: RequestTradeTicks) -> None:
+        endpoint = self._endpoints.trades
+        if endpoint is None:
+            self._log.error("Cannot request trades: no `trades` endpoint in the mapping")
+            return
+
+        instrument = self._get_instrument(request.instrument_id)
+        if instrument is None:
+            return
+
+        # Only the most recent trades are available, which are filtered by `start` and `end`
+        response = await self._client.request(
+            endpoint,
+            {
+                "symbol": instrument.raw_symbol.value,
+                "limit": request.limit or self._trades_limit,
+            },
+        )
+        trades = self._parser.parse_trade_ticks(
+            response,
+            endpoint,
+            instrument,
+            self._clock.timestamp_ns(),
+        )
+        trades = self._filter_by_time(trades, request.start, request.end)
+
+        self._handle_trade_ticks(request.instrument_id, trades, request.id, request.params)
+
+    async def _request_bars(self, request: RequestBars) -> None:
+        endpoint = self._endpoints.klines
+        if endpoint is None:
+            self._log.error("Cannot request bars: no `klines` endpoint in the mapping")
+            return
+
+        bar_type = request.bar_type
+        interval = self._check_bar_type(bar_type)
+        if interval is None:
+            return
+
+        instrument = self._get_instrument(bar_type.instrument_id)
+        if instrument is None:
+            return
+
+        values: dict[str, Any] = {
+            "symbol": instrument.raw_symbol.value,
+            "interval": interval,
+            "limit": request.limit or None,
+        }
+        if request.start is not None:
+            values["start"] = self._parser.format_timestamp(dt_to_unix_nanos(request.start))
+        if request.end is not None:
+            values["end"] = self._parser.format_timestamp(dt_to_unix_nanos(request.end))
+
+        response = await self._client.request(endpoint, values)
+        now_ns = self._clock.timestamp_ns()
+        bars = self._parser.parse_bars(response, endpoint, bar_type, instrument, now_ns)
+        bars = [bar for bar in bars if bar.ts_event <= now_ns]  # Closed bars only
+        bars = self._filter_by_time(bars, request.start, request.end)
+
+        self._handle_bars(bar_type, bars, None, request.id, request.params)
+
+    @staticmethod
+    def _filter_by_time(
+        data: list,
+        start: pd.Timestamp | None,
+        end: pd.Timestamp | None,
+    ) -> list:
+        start_ns = dt_to_unix_nanos(start) if start is not None else None
+        end_ns = dt_to_unix_nanos(end) if end is not None else None
+        return [
+            d
+            for d in data
+            if (start_ns is None or d.ts_event >= start_ns)
+            and (end_ns is None or d.ts_event <= end_ns)
+        ]
@@ -0,0 +1,701 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+import asyncio
+from collections.abc import Awaitable
+from collections.abc import Callable
+from decimal import Decimal
+from typing import TYPE_CHECKING
+
+from nautilus_trader.adapters.rest_polling.http.errors import RestPollingError
+from nautilus_trader.adapters.rest_polling.http.errors import should_retry
+from nautilus_trader.adapters.rest_polling.parsing import RestPollingParser
+from nautilus_trader.common.enums import LogColor
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.live.execution_client import LiveExecutionClient
+from nautilus_trader.live.retry import RetryManagerPool
+from nautilus_trader.model.enums import AccountType
+from nautilus_trader.model.enums import LiquiditySide
+from nautilus_trader.model.enums import OmsType
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import order_type_to_str
+from nautilus_trader.model.enums import time_in_force_to_str
+from nautilus_trader.model.identifiers import AccountId
+from nautilus_trader.model.identifiers import ClientId
+from nautilus_trader.model.identifiers import ClientOrderId
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import Symbol
+from nautilus_trader.model.identifiers import TradeId
+from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.model.identifiers import VenueOrderId
+from nautilus_trader.model.objects import Money
+from nautilus_trader.model.orders import Order
+
+
+if TYPE_CHECKING:
+    import pandas as pd
+
+    from nautilus_trader.adapters.rest_polling.config import RestPollingExecClientConfig
+    from nautilus_trader.adapters.rest_polling.http.client import RestPollingHttpClient
+    from nautilus_trader.adapters.rest_polling.providers import RestPollingInstrumentProvider
+    from nautilus_trader.cache.cache import Cache
+    from nautilus_trader.common.component import LiveClock
+    from nautilus_trader.common.component import MessageBus
+    from nautilus_trader.execution.messages import CancelAllOrders
+    from nautilus_trader.execution.messages import CancelOrder
+    from nautilus_trader.execution.messages import ModifyOrder
+    from nautilus_trader.execution.messages import SubmitOrder
+    from nautilus_trader.execution.reports import FillReport
+    from nautilus_trader.execution.reports import OrderStatusReport
+    from nautilus_trader.execution.reports import PositionStatusReport
+    from nautilus_trader.model.instruments import Instrument
+
+
+class RestPollingExecutionClient(LiveExecutionClient):
+    """
+    Provides an execution client which polls the REST API of a venue described by a
+    schema mapping.
+
+    Orders are submitted and canceled through the mapped endpoints, and the status of
+    open orders is polled to generate order events. Fills are inferred from changes in
+    the filled quantity and average price of an order, so the commission is unknown
+    (reported as zero) and fills in the same poll interval are combined.
+
+    Parameters
+    ----------
+    loop : asyncio.AbstractEventLoop
+        The event loop for the client.
+    client : RestPollingHttpClient
+        The REST polling HTTP client.
+    msgbus : MessageBus
+        The message bus for the client.
+    cache : Cache
+        The cache for the client.
+    clock : LiveClock
+        The clock for the client.
+    instrument_provider : RestPollingInstrumentProvider
+        The instrument provider.
+    config : RestPollingExecClientConfig
+        The configuration for the client.
+    name : str, optional
+        The custom client ID.
+
+    Raises
+    ------
+    ValueError
+        If the mapping has no `submit_order`, `cancel_order`, `order_status` or
+        `balances` endpoint.
+
+    """
+
+    def __init__(
+        self,
+        loop: asyncio.AbstractEventLoop,
+        client: RestPollingHttpClient,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+        instrument_provider: RestPollingInstrumentProvider,
+        config: RestPollingExecClientConfig,
+        name: str | None,
+    ) -> None:
+        mapping = client.mapping
+        for endpoint in ("submit_order", "cancel_order", "order_status", "balances"):
+            if getattr(mapping.endpoints, endpoint) is None:
+                raise ValueError(f"Invalid mapping: no `{endpoint}` endpoint for execution")
+
+        super().__init__(
+            loop=loop,
+            client_id=ClientId(name or mapping.venue),
+            venue=Venue(mapping.venue),
+            oms_type=OmsType.NETTING,
+            instrument_provider=instrument_provider,
+            account_type=AccountType.CASH,
+            base_currency=None,
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+        )
+
+        # Configuration
+        self._order_poll_interval_secs = config.order_poll_interval_secs
+        self._account_poll_interval_secs = config.account_poll_interval_secs
+        self._log.info(f"{config.mapping_path=}", LogColor.BLUE)
+        self._log.info(f"{config.order_poll_interval_secs=}", LogColor.BLUE)
+        self._log.info(f"{config.account_poll_interval_secs=}", LogColor.BLUE)
+        self._log.info(f"{config.max_retries=}", LogColor.BLUE)
+        self._log.info(f"{config.retry_delay=}", LogColor.BLUE)
+
+        account_id = AccountId(f"{name or mapping.venue}-001")
+        self._set_account_id(account_id)
+
+        # HTTP API
+        self._client = client
+        self._endpoints = mapping.endpoints
+        self._parser = RestPollingParser(mapping)
+
+        # Polling state
+        self._open_orders: dict[ClientOrderId, VenueOrderId] = {}
+        self._filled: dict[ClientOrderId, tuple[Decimal, Decimal]] = {}
+        self._fill_counts: dict[ClientOrderId, int] = {}
+        self._poll_orders_task: asyncio.Task | None = None
+        self._poll_account_task: asyncio.Task | None = None
+
+        self._retry_manager_pool = RetryManagerPool[None](
+            pool_size=100,
+            max_retries=config.max_retries or 0,
+            retry_delay_secs=config.retry_delay or 0.0,
+            logger=self._log,
+            exc_types=(RestPollingError,),
+            retry_check=should_retry,
+        )
+
+    async def _connect(self) -> None:
+        await self._instrument_provider.initialize()
+        await self._update_account_state()
+
+        # Resume polling orders which are open from a previous session
+        for order in self._cache.orders_open(venue=self.venue):
+            if order.venue_order_id is not None:
+                self._track_order(order, order.venue_order_id)
+
+        self._poll_orders_task = self.create_task(
+            self._poll("orders", self._poll_open_orders, self._order_poll_interval_secs),
+        )
+        self._poll_account_task = self.create_task(
+            self._poll("account", self._update_account_state, self._account_poll_interval_secs),
+        )
+
+    async def _disconnect(self) -> None:
+        if self._poll_orders_task:
+            self._log.debug("Canceling task 'poll_orders'")
+            self._poll_orders_task.cancel()
+            self._poll_orders_task = None
+
+        if self._poll_account_task:
+            self._log.debug("Canceling task 'poll_account'")
+            self._poll_account_task.cancel()
+            self._poll_account_task = None
+
+    def _stop(self) -> None:
+        self._retry_manager_pool.shutdown()
+
+    async def _poll(
+        self,
+        name: str,
+        poll: Callable[[], Awaitable[None]],
+        interval_secs: float,
+    ) -> None:
+        try:
+            while True:
+                await asyncio.sleep(interval_secs)
+                try:
+                    await poll()
+                except asyncio.CancelledError:
+                    raise
+                except Exception as e:
+                    self._log.error(f"Error polling {name}: {e!r}")
+        except asyncio.CancelledError:
+            self._log.debug(f"Canceled task 'poll_{name}'")
+
+    # -- EXECUTION REPORTS ------------------------------------------------------------------------
+
+    async def generate_order_status_reports(
+        self,
+        instrument_id: InstrumentId | None = None,
+        start: pd.Timestamp | None = None,
+        end: pd.Timestamp | None = None,
+        open_only: bool = False,
+    ) -> list[OrderStatusReport]:
+        reports: list[OrderStatusReport] = []
+
+        endpoint = self._endpoints.open_orders
+        if endpoint is None:
+            self._log.warning("Cannot generate OrderStatusReports: no `open_orders` endpoint")
+            return reports
+
+        self._log.debug("Requesting OrderStatusReports...")
+
+        # Request per instrument, unless the symbol of each order is mapped
+        targets: list[Instrument | None] = [None]
+        if instrument_id is not None:
+            targets = [self._cache.instrument(instrument_id)]
+        elif "symbol" not in endpoint.fields:
+            targets = list(self._instrument_provider.get_all().values())
+
+        try:
+            for target in targets:
+                response = await self._client.request(
+                    endpoint,
+                    {"symbol": target.raw_symbol.value if target else None},
+                )
+                for item in self._parser.items(response, endpoint):
+                    instrument = target or self._find_instrument(
+                        self._parser.field(item, endpoint, "symbol"),
+                    )
+                    if instrument is None:
+                        continue
+
+                    report = self._parser.parse_order_status_report(
+                        item=item,
+                        endpoint=endpoint,
+                        account_id=self.account_id,
+                        instrument=instrument,
+                        report_id=UUID4(),
+                        ts_init=self._clock.timestamp_ns(),
+                    )
+                    reports.append(report)
+                    self._log.debug(f"Received {report}", LogColor.MAGENTA)
+        except (RestPollingError, ValueError) as e:
+            self._log.error(f"Failed to generate OrderStatusReports: {e}")
+
+        len_reports = len(reports)
+        plural = "" if len_reports == 1 else "s"
+        self._log.info(f"Received {len(reports)} OrderStatusReport{plural}")
+
+        return reports
+
+    async def generate_order_status_report(
+        self,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId | None = None,
+        venue_order_id: VenueOrderId | None = None,
+    ) -> OrderStatusReport | None:
+        PyCondition.is_false(
+            client_order_id is None and venue_order_id is None,
+            "both `client_order_id` and `venue_order_id` were `None`",
+        )
+
+        self._log.info(
+            f"Generating OrderStatusReport for "
+            f"{repr(client_order_id) if client_order_id else ''} "
+            f"{repr(venue_order_id) if venue_order_id else ''}",
+        )
+
+        if venue_order_id is None and client_order_id is not None:
+            order = self._cache.order(client_order_id)
+            if order is not None:
+                venue_order_id = order.venue_order_id
+
+        instrument = self._cache.instrument(instrument_id)
+        if instrument is None:
+            self._log.error(f"Cannot generate report: no instrument for {instrument_id}")
+            return None
+
+        try:
+            report = await self._fetch_order_status_report(
+                instrument,
+                client_order_id,
+                venue_order_id,
+            )
+            if report is None:
+                self._log.error(f"Received no order for {client_order_id!r} {venue_order_id!r}")
+                return None
+
+            self._log.debug(f"Received {report}", LogColor.MAGENTA)
+            return report
+        except (RestPollingError, ValueError) as e:
+            self._log.error(f"Failed to generate OrderStatusReport: {e}")
+        return None
+
+    async def generate_fill_reports(
+        self,
+        instrument_id: InstrumentId | None = None,
+        venue_order_id: VenueOrderId | None = None,
+        start: pd.Timestamp | None = None,
+        end: pd.Timestamp | None = None,
+    ) -> list[FillReport]:
+        # Fills are inferred from the order status reports during reconciliation
+        self._log.info("Received 0 FillReports (fills are inferred from order status)")
+        return []
+
+    async def generate_position_status_reports(
+        self,
+        instrument_id: InstrumentId | None = None,
+        start: pd.Timestamp | None = None,
+        end: pd.Timestamp | None = None,
+    ) -> list[PositionStatusReport]:
+        return []  # No positions for cash accounts
+
+    def _find_instrument(self, symbol: str | None) -> Instrument | None:
+        if symbol is None:
+            return None
+
+        instrument_id = InstrumentId(Symbol(str(symbol)), self.venue)
+        instrument = self._cache.instrument(instrument_id)
+        if instrument is None:
+            self._log.debug(f"No instrument found for {instrument_id}")
+        return instrument
+
+    async def _fetch_order_status_report(
+        self,
+        instrument: Instrument,
+        client_order_id: ClientOrderId | None,
+        venue_order_id: VenueOrderId | None,
+    ) -> OrderStatusReport | None:
+        endpoint = self._endpoints.order_status
+        assert endpoint is not None  # Checked on init
+
+        response = await self._client.request(
+            endpoint,
+            {
+                "symbol": instrument.raw_symbol.value,
+                "order_id": venue_order_id.value if venue_order_id else None,
+                "client_order_id": client_order_id.value if client_order_id else None,
+            },
+        )
+        items = self._parser.items(response, endpoint)
+        if not items:
+            return None
+
+        return self._parser.parse_order_status_report(
+            item=items[0],
+            endpoint=endpoint,
+            account_id=self.account_id,
+            instrument=instrument,
+            report_id=UUID4(),
+            ts_init=self._clock.timestamp_ns(),
+            client_order_id=client_order_id,
+        )
+
+    async def _update_account_state(self) -> None:
+        endpoint = self._endpoints.balances
+        assert endpoint is not None  # Checked on init
+
+        try:
+            response = await self._client.request(endpoint)
+            balances = self._parser.parse_account_balances(response, endpoint)
+            if not balances:
+                self._log.warning("No account balances received")
+                return
+
+            self.generate_account_state(
+                balances=balances,
+                margins=[],
+                reported=True,
+                ts_event=self._clock.timestamp_ns(),
+            )
+        except Exception as e:
+            self._log.error(f"Failed to generate AccountState: {e}")
+
+    # -- ORDER POLLING ----------------------------------------------------------------------------
+
+    def _track_order(self, order: Order, venue_order_id: VenueOrderId) -> None:
+        self._open_orders[order.client_order_id] = venue_order_id
+        self._filled[order.client_order_id] = (
+            order.filled_qty.as_decimal(),
+            Decimal(str(order.avg_px or 0)),
+        )
+
+    def _untrack_order(self, client_order_id: ClientOrderId) -> None:
+        self._open_orders.pop(client_order_id, None)
+        self._filled.pop(client_order_id, None)
+        self._fill_counts.pop(client_order_id, None)
+
+    async def _poll_open_orders(self) -> None:
+        for client_order_id in list(self._open_orders):
+            try:
+                await self._poll_order(client_order_id)
+            except (RestPollingError, ValueError) as e:
+                self._log.error(f"Failed to poll order {client_order_id!r}: {e}")
+
+    async def _poll_order(self, client_order_id: ClientOrderId) -> None:
+        venue_order_id = self._open_orders.get(client_order_id)
+        order = self._cache.order(client_order_id)
+        if venue_order_id is None or order is None or order.is_closed:
+            self._untrack_order(client_order_id)
+            return
+
+        instrument = self._cache.instrument(order.instrument_id)
+        if instrument is None:
+            self._log.error(f"Cannot poll order: no instrument for {order.instrument_id}")
+            return
+
+        report = await self._fetch_order_status_report(instrument, client_order_id, venue_order_id)
+        if report is None:
+            self._log.warning(f"Received no order status for {client_order_id!r}")
+            return
+
+        if client_order_id not in self._open_orders:
+            return  # Already closed by a concurrent poll
+
+        self._handle_order_status_report(order, instrument, report)
+
+    def _handle_order_status_report(
+        self,
+        order: Order,
+        instrument: Instrument,
+        report: OrderStatusReport,
+    ) -> None:
+        self._handle_filled_qty(order, instrument, report)
+
+        match report.order_status:
+            case OrderStatus.FILLED:
+                self._untrack_order(order.client_order_id)
+            case OrderStatus.CANCELED | OrderStatus.REJECTED:
+                # Orders are only polled once accepted, so rejections are cancellations
+                self._untrack_order(order.client_order_id)
+                self.generate_order_canceled(
+                    strategy_id=order.strategy_id,
+                    instrument_id=order.instrument_id,
+                    client_order_id=order.client_order_id,
+                    venue_order_id=report.venue_order_id,
+                    ts_event=report.ts_last,
+                )
+            case OrderStatus.EXPIRED:
+                self._untrack_order(order.client_order_id)
+                self.generate_order_expired(
+                    strategy_id=order.strategy_id,
+                    instrument_id=order.instrument_id,
+                    client_order_id=order.client_order_id,
+                    venue_order_id=report.venue_order_id,
+                    ts_event=report.ts_last,
+                )
+
+    def _handle_filled_qty(
+        self,
+        order: Order,
+        instrument: Instrument,
+        report: OrderStatusReport,
+    ) -> None:
+        client_order_id = order.client_order_id
+        last_filled, last_avg_px = self._filled.get(client_order_id, (Decimal(0), Decimal(0)))
+        filled = report.filled_qty.as_decimal()
+        if filled <= last_filled:
+            return  # No new fills
+
+        avg_px = report.avg_px
+        if avg_px is None and report.price is not None:
+            avg_px = report.price.as_decimal()
+        if avg_px is None:
+            self._log.error(f"Cannot handle fill for {client_order_id!r}: no average price")
+            return
+
+        # The price of the new fills is derived from the change in the average price
+        last_qty = filled - last_filled
+        last_px = (avg_px * filled - last_avg_px * last_filled) / last_qty
+
+        self._filled[client_order_id] = (filled, avg_px)
+        fill_count = self._fill_counts.get(client_order_id, 0) + 1
+        self._fill_counts[client_order_id] = fill_count
+
+        self.generate_order_filled(
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=client_order_id,
+            venue_order_id=report.venue_order_id,
+            venue_position_id=None,
+            trade_id=TradeId(f"{report.venue_order_id}-{fill_count}"),
+            order_side=order.side,
+            order_type=order.order_type,
+            last_qty=instrument.make_qty(last_qty),
+            last_px=instrument.make_price(last_px),
+            quote_currency=instrument.quote_currency,
+            commission=Money(0, instrument.quote_currency),
+            liquidity_side=LiquiditySide.NO_LIQUIDITY_SIDE,
+            ts_event=report.ts_last,
+        )
+
+    # -- COMMAND HANDLERS -------------------------------------------------------------------------
+
+    async def _cancel_order(self, command: CancelOrder) -> None:
+        order: Order | None = self._cache.order(command.client_order_id)
+        if order is None:
+            self._log.error(f"{command.client_order_id!r} not found in cache")
+            return
+
+        if order.is_closed:
+            self._log.warning(
+                f"`CancelOrder` command for {command.client_order_id!r} when order already {order.status_string()} "
+                "(will not send to exchange)",
+            )
+            return
+
+        await self._cancel(order)
+
+    async def _cancel_all_orders(self, command: CancelAllOrders) -> None:
+        orders_open = self._cache.orders_open(
+            instrument_id=command.instrument_id,
+            side=command.order_side,
+        )
+        if not orders_open:
+            self._log.info(f"No open orders to cancel for {command.instrument_id}")
+            return
+
+        # There is no mapped endpoint to cancel all orders, so cancel each order
+        for order in orders_open:
+            await self._cancel(order)
+
+    async def _cancel(self, order: Order) -> None:
+        async with self._retry_manager_pool as retry_manager:
+            await retry_manager.run(
+                "cancel_order",
+                [order.client_order_id, order.venue_order_id],
+                self._cancel_order_request,
+                order,
+            )
+            if not retry_manager.result:
+                self.generate_order_cancel_rejected(
+                    order.strategy_id,
+                    order.instrument_id,
+                    order.client_order_id,
+                    order.venue_order_id,
+                    retry_manager.message,
+                    self._clock.timestamp_ns(),
+                )
+                return
+
+        # Poll the order to confirm the cancel, including any fills before the cancel
+        try:
+            await self._poll_order(order.client_order_id)
+        except (RestPollingError, ValueError) as e:
+            self._log.error(f"Failed to poll order {order.client_order_id!r}: {e}")
+
+    async def _cancel_order_request(self, order: Order) -> None:
+        endpoint = self._endpoints.cancel_order
+        assert endpoint is not None  # Checked on init
+
+        instrument_id = order.instrument_id
+        await self._client.request(
+            endpoint,
+            {
+                "symbol": instrument_id.symbol.value,
+                "order_id": order.venue_order_id.value if order.venue_order_id else None,
+                "client_order_id": order.client_order_id.value,
+            },
+        )
+
+    async def _modify_order(self, command: ModifyOrder) -> None:
+        self.generate_order_modify_rejected(
+            command.strategy_id,
+            command.instrument_id,
+            command.client_order_id,
+            command.venue_order_id,
+            "modifying orders is not supported by the REST polling adapter",
+            self._clock.timestamp_ns(),
+        )
+
+    async def _submit_order(self, command: SubmitOrder) -> None:
+        order = command.order
+        if order.is_closed:
+            self._log.warning(f"Order {order} is already closed")
+            return
+
+        if not self._check_order_validity(order):
+            return
+
+        # Generate order submitted event, to ensure correct ordering of event
+        self.generate_order_submitted(
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=order.client_order_id,
+            ts_event=self._clock.timestamp_ns(),
+        )
+
+        async with self._retry_manager_pool as retry_manager:
+            await retry_manager.run(
+                "submit_order",
+                [order.client_order_id],
+                self._submit_order_request,
+                order,
+            )
+            if not retry_manager.result:
+                self.generate_order_rejected(
+                    strategy_id=order.strategy_id,
+                    instrument_id=order.instrument_id,
+                    client_order_id=order.client_order_id,
+                    reason=retry_manager.message,
+                    ts_event=self._clock.timestamp_ns(),
+                )
+
+    def _check_order_validity(self, order: Order) -> bool:
+        if self._parser.format_order_type(order.order_type) is None:
+            self._log.error(
+                f"Cannot submit {order}: {order_type_to_str(order.order_type)} orders "
+                "not mapped in `order_types`",
+            )
+            return False
+
+        if (
+            order.order_type != OrderType.MARKET
+            and self._parser.format_time_in_force(order.time_in_force) is None
+        ):
+            self._log.error(
+                f"Cannot submit {order}: time in force "
+                f"{time_in_force_to_str(order.time_in_force)} not mapped in `time_in_force`",
+            )
+            return False
+
+        if order.is_quote_quantity:
+            self._log.error(
+                f"Cannot submit {order}: quote quantity not supported by the REST polling adapter",
+            )
+            return False
+
+        if order.is_post_only:
+            self._log.error(
+                f"Cannot submit {order}: post only not supported by the REST polling adapter",
+            )
+            return False
+
+        if order.is_reduce_only:
+            self._log.error(
+                f"Cannot submit {order}: reduce only not supported for cash accounts",
+            )
+            return False
+
+        return True
+
+    async def _submit_order_request(self, order: Order) -> None:
+        endpoint = self._endpoints.submit_order
+        assert endpoint is not None  # Checked on init
+
+        time_in_force = None
+        if order.order_type != OrderType.MARKET:
+            time_in_force = self._parser.format_time_in_force(order.time_in_force)
+
+        response = await self._client.request(
+            endpoint,
+            {
+                "symbol": order.instrument_id.symbol.value,
+                "side": self._parser.format_order_side(order.side),
+                "type": self._parser.format_order_type(order.order_type),
+                "quantity": str(order.quantity),
+                "price": str(order.price) if order.has_price else None,
+                "time_in_force": time_in_force,
+                "client_order_id": order.client_order_id.value,
+            },
+        )
+
+        items = self._parser.items(response, endpoint)
+        order_id = self._parser.field(items[0], endpoint, "order_id") if items else None
+        if order_id is None:
+            raise RestPollingError(code=None, message=f"No order ID in response {response}")
+
+        venue_order_id = VenueOrderId(str(order_id))
+        self.generate_order_accepted(
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=order.client_order_id,
+            venue_order_id=venue_order_id,
+            ts_event=self._clock.timestamp_ns(),
+        )
+
+        # Fills (including immediate fills) are generated by polling the order status
+        self._track_order(order, venue_order_id)
@@ -0,0 +1,253 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import asyncio
+from functools import lru_cache
+
+from nautilus_trader.adapters.rest_polling.common.credentials import get_api_key
+from nautilus_trader.adapters.rest_polling.common.credentials import get_api_secret
+from nautilus_trader.adapters.rest_polling.config import RestPollingDataClientConfig
+from nautilus_trader.adapters.rest_polling.config import RestPollingExecClientConfig
+from nautilus_trader.adapters.rest_polling.data import RestPollingDataClient
+from nautilus_trader.adapters.rest_polling.execution import RestPollingExecutionClient
+from nautilus_trader.adapters.rest_polling.http.client import RestPollingHttpClient
+from nautilus_trader.adapters.rest_polling.providers import RestPollingInstrumentProvider
+from nautilus_trader.adapters.rest_polling.schema import RestPollingMapping
+from nautilus_trader.adapters.rest_polling.schema import load_mapping
+from nautilus_trader.cache.cache import Cache
+from nautilus_trader.common.component import LiveClock
+from nautilus_trader.common.component import MessageBus
+from nautilus_trader.config import InstrumentProviderConfig
+from nautilus_trader.live.factories import LiveDataClientFactory
+from nautilus_trader.live.factories import LiveExecClientFactory
+
+
+@lru_cache(4)
+def get_cached_rest_polling_mapping(path: str) -> RestPollingMapping:
+    """
+    Cache and return the REST schema mapping loaded from the given path.
+
+    Parameters
+    ----------
+    path : str
+        The path to the mapping file (JSON or TOML).
+
+    Returns
+    -------
+    RestPollingMapping
+
+    """
+    return load_mapping(path)
+
+
+@lru_cache(4)
+def get_cached_rest_polling_http_client(
+    clock: LiveClock,
+    mapping_path: str,
+    api_key: str | None = None,
+    api_secret: str | None = None,
+    base_url: str | None = None,
+) -> RestPollingHttpClient:
+    """
+    Cache and return a REST polling HTTP client for the venue of the given mapping.
+
+    If a cached client with matching parameters already exists, the cached client will be returned.
+
+    Parameters
+    ----------
+    clock : LiveClock
+        The clock for the client.
+    mapping_path : str
+        The path to the mapping file (JSON or TOML).
+    api_key : str, optional
+        The API key for signed requests (``None`` for public only access).
+    api_secret : str, optional
+        The API secret for signed requests (``None`` for public only access).
+    base_url : str, optional
+        The base URL for the API endpoints (overrides the mapping `base_url`).
+
+    Returns
+    -------
+    RestPollingHttpClient
+
+    """
+    return RestPollingHttpClient(
+        clock=clock,
+        mapping=get_cached_rest_polling_mapping(mapping_path),
+        api_key=api_key,
+        api_secret=api_secret,
+        base_url=base_url,
+    )
+
+
+@lru_cache(4)
+def get_cached_rest_polling_instrument_provider(
+    client: RestPollingHttpClient,
+    clock: LiveClock,
+    config: InstrumentProviderConfig,
+) -> RestPollingInstrumentProvider:
+    """
+    Cache and return a REST polling instrument provider.
+
+    If a cached provider already exists, then that provider will be returned.
+
+    Parameters
+    ----------
+    client : RestPollingHttpClient
+        The REST polling HTTP client (for the venue mapping).
+    clock : LiveClock
+        The clock instance.
+    config : InstrumentProviderConfig
+        The instrument provider configuration.
+
+    Returns
+    -------
+    RestPollingInstrumentProvider
+
+    """
+    return RestPollingInstrumentProvider(
+        mapping=client.mapping,
+        clock=clock,
+        config=config,
+    )
+
+
+class RestPollingLiveDataClientFactory(LiveDataClientFactory):
+    """
+    Provides a REST polling live data client factory.
+    """
+
+    @staticmethod
+    def create(  # type: ignore
+        loop: asyncio.AbstractEventLoop,
+        name: str,
+        config: RestPollingDataClientConfig,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+    ) -> RestPollingDataClient:
+        """
+        Create a new REST polling data client.
+
+        Parameters
+        ----------
+        loop : asyncio.AbstractEventLoop
+            The event loop for the client.
+        name : str
+            The custom client ID.
+        config : RestPollingDataClientConfig
+            The client configuration.
+        msgbus : MessageBus
+            The message bus for the client.
+        cache : Cache
+            The cache for the client.
+        clock : LiveClock
+            The clock for the instrument provider.
+
+        Returns
+        -------
+        RestPollingDataClient
+
+        """
+        # Market data is public, so no credentials are required
+        client = get_cached_rest_polling_http_client(
+            clock=clock,
+            mapping_path=config.mapping_path,
+            base_url=config.base_url_http,
+        )
+        provider = get_cached_rest_polling_instrument_provider(
+            client=client,
+            clock=clock,
+            config=config.instrument_provider,
+        )
+        return RestPollingDataClient(
+            loop=loop,
+            client=client,
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+            instrument_provider=provider,
+            config=config,
+            name=name,
+        )
+
+
+class RestPollingLiveExecClientFactory(LiveExecClientFactory):
+    """
+    Provides a REST polling live execution client factory.
+    """
+
+    @staticmethod
+    def create(  # type: ignore
+        loop: asyncio.AbstractEventLoop,
+        name: str,
+        config: RestPollingExecClientConfig,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+    ) -> RestPollingExecutionClient:
+        """
+        Create a new REST polling execution client.
+
+        Parameters
+        ----------
+        loop : asyncio.AbstractEventLoop
+            The event loop for the client.
+        name : str
+            The custom client ID.
+        config : RestPollingExecClientConfig
+            The client configuration.
+        msgbus : MessageBus
+            The message bus for the client.
+        cache : Cache
+            The cache for the client.
+        clock : LiveClock
+            The clock for the client.
+
+        Returns
+        -------
+        RestPollingExecutionClient
+
+        """
+        mapping = get_cached_rest_polling_mapping(config.mapping_path)
+
+        api_key = config.api_key
+        api_secret = config.api_secret
+        if mapping.auth is not None:
+            api_key = api_key or get_api_key(mapping.venue)
+            api_secret = api_secret or get_api_secret(mapping.venue)
+
+        client = get_cached_rest_polling_http_client(
+            clock=clock,
+            mapping_path=config.mapping_path,
+            api_key=api_key,
+            api_secret=api_secret,
+            base_url=config.base_url_http,
+        )
+        provider = get_cached_rest_polling_instrument_provider(
+            client=client,
+            clock=clock,
+            config=config.instrument_provider,
+        )
+        return RestPollingExecutionClient(
+            loop=loop,
+            client=client,
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+            instrument_provider=provider,
+            config=config,
+            name=name,
+        )
@@ -0,0 +1,261 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import base64
+import hashlib
+import hmac
+import re
+from typing import Any
+from urllib import parse
+
+import msgspec
+
+import nautilus_trader
+from nautilus_trader.adapters.rest_polling.http.errors import RestPollingError
+from nautilus_trader.adapters.rest_polling.schema import RestPollingEndpoint
+from nautilus_trader.adapters.rest_polling.schema import RestPollingMapping
+from nautilus_trader.adapters.rest_polling.schema import extract_field
+from nautilus_trader.common.component import LiveClock
+from nautilus_trader.common.component import Logger
+from nautilus_trader.core.nautilus_pyo3 import HttpClient
+from nautilus_trader.core.nautilus_pyo3 import HttpMethod
+from nautilus_trader.core.nautilus_pyo3 import HttpResponse
+from nautilus_trader.core.nautilus_pyo3 import Quota
+
+
+_PLACEHOLDER = re.compile(r"\{(\w+)\}")
+
+
+def render_template(template: str, values: dict[str, Any]) -> str:
+    """
+    Return the template formatted with the given values.
+
+    Raises
+    ------
+    ValueError
+        If a placeholder in the template has no value.
+
+    """
+    try:
+        return template.format_map({k: v for k, v in values.items() if v is not None})
+    except KeyError as e:
+        raise ValueError(f"No value for placeholder {e} in template '{template}'") from e
+
+
+def render_params(templates: dict[str, str], values: dict[str, Any]) -> dict[str, str]:
+    """
+    Return the parameters rendered from the given templates and values.
+
+    A parameter whose template is a single placeholder without a value is omitted.
+
+    """
+    params: dict[str, str] = {}
+    for key, template in templates.items():
+        match = _PLACEHOLDER.fullmatch(template)
+        if match and values.get(match.group(1)) is None:
+            continue  # Optional parameter
+        params[key] = render_template(template, values)
+    return params
+
+
+def sign_payload(secret: str, message: bytes, encoding: str = "hex") -> str:
+    """
+    Return the HMAC-SHA256 signature of the given message.
+
+    Parameters
+    ----------
+    secret : str
+        The API secret to sign with.
+    message : bytes
+        The message to sign.
+    encoding : str, default 'hex'
+        The signature encoding, either 'hex' or 'base64'.
+
+    Returns
+    -------
+    str
+
+    """
+    digest = hmac.new(secret.encode(), message, hashlib.sha256).digest()
+    if encoding == "base64":
+        return base64.b64encode(digest).decode()
+    return digest.hex()
+
+
+class RestPollingHttpClient:
+    """
+    Provides an asynchronous HTTP client for a venue described by a REST schema mapping.
+
+    Parameters
+    ----------
+    clock : LiveClock
+        The clock for the client.
+    mapping : RestPollingMapping
+        The REST schema mapping for the venue.
+    api_key : str, optional
+        The API key for signed requests.
+    api_secret : str, optional
+        The API secret for signed requests.
+    base_url : str, optional
+        The base endpoint URL for the client (overrides the mapping `base_url`).
+
+    """
+
+    def __init__(
+        self,
+        clock: LiveClock,
+        mapping: RestPollingMapping,
+        api_key: str | None = None,
+        api_secret: str | None = None,
+        base_url: str | None = None,
+    ) -> None:
+        self._clock: LiveClock = clock
+        self._log: Logger = Logger(name=type(self).__name__)
+        self._mapping: RestPollingMapping = mapping
+        self._api_key: str | None = api_key
+        self._api_secret: str | None = api_secret
+
+        self._base_url: str = (base_url or mapping.base_url).rstrip("/")
+        self._headers: dict[str, Any] = {
+            "User-Agent": nautilus_trader.USER_AGENT,
+        }
+        self._client = HttpClient(
+            keyed_quotas=[],
+            default_quota=Quota.rate_per_second(mapping.rate_limit_per_second),
+        )
+
+    @property
+    def mapping(self) -> RestPollingMapping:
+        return self._mapping
+
+    @property
+    def base_url(self) -> str:
+        return self._base_url
+
+    @property
+    def api_key(self) -> str | None:
+        return self._api_key
+
+    async def request(
+        self,
+        endpoint: RestPollingEndpoint,
+        values: dict[str, Any] | None = None,
+    ) -> Any:
+        """
+        Send a request to the mapped endpoint and return the decoded JSON response.
+
+        Parameters
+        ----------
+        endpoint : RestPollingEndpoint
+            The endpoint mapping for the request.
+        values : dict[str, Any], optional
+            The values to format the endpoint path and parameter templates with.
+
+        Returns
+        -------
+        Any
+            The decoded response, or ``None`` if the response body is empty.
+
+        Raises
+        ------
+        RestPollingError
+            If the venue responds with an error.
+
+        """
+        values = values or {}
+        url_path = render_template(endpoint.path, values)
+        params = render_params(endpoint.params, values)
+        headers = {**self._headers}
+
+        if endpoint.signed:
+            self._add_auth(headers, params)
+
+        query = ""
+        body: bytes | None = None
+        if endpoint.method == "GET" or endpoint.encoding == "query":
+            query = parse.urlencode(params)
+        elif endpoint.encoding == "json":
+            body = msgspec.json.encode(params)
+            headers["Content-Type"] = "application/json"
+        else:
+            body = parse.urlencode(params).encode()
+            headers["Content-Type"] = "application/x-www-form-urlencoded"
+
+        if endpoint.signed:
+            query = self._sign(headers, query, body)
+
+        url = self._base_url + url_path
+        if query:
+            url += "?" + query
+
+        response: HttpResponse = await self._client.request(
+            getattr(HttpMethod, endpoint.method),
+            url,
+            headers,
+            body,
+        )
+
+        response_body = response.body
+        if response.status >= 400:
+            try:
+                message = msgspec.json.decode(response_body) if response_body else None
+            except msgspec.DecodeError:
+                message = response_body.decode()
+
+            raise RestPollingError(
+                code=response.status,
+                message=message,
+            )
+
+        if not response_body:
+            return None
+
+        decoded = msgspec.json.decode(response_body)
+        self._check_response(decoded)
+        return decoded
+
+    def _add_auth(self, headers: dict[str, Any], params: dict[str, str]) -> None:
+        auth = self._mapping.auth
+        if auth is None:
+            raise ValueError("Cannot send signed request: no `auth` defined in the mapping")
+        if not self._api_key or not self._api_secret:
+            raise ValueError("Cannot send signed request: no API credentials")
+
+        headers[auth.api_key_header] = self._api_key
+        if auth.timestamp_param:
+            params[auth.timestamp_param] = str(self._clock.timestamp_ms())
+
+    def _sign(self, headers: dict[str, Any], query: str, body: bytes | None) -> str:
+        auth = self._mapping.auth
+        assert auth is not None  # Checked in _add_auth
+        assert self._api_secret is not None  # Checked in _add_auth
+
+        message = body if body is not None else query.encode()
+        signature = sign_payload(self._api_secret, message, auth.signature_encoding)
+
+        if auth.signature_header:
+            headers[auth.signature_header] = signature
+            return query
+
+        signature_param = parse.urlencode({auth.signature_param: signature})
+        return f"{query}&{signature_param}" if query else signature_param
+
+    def _check_response(self, decoded: Any) -> None:
+        code = extract_field(decoded, self._mapping.error_code)
+        if code is None or str(code) in self._mapping.success_codes:
+            return
+
+        message = extract_field(decoded, self._mapping.error_message)
+        raise RestPollingError(code=code, message=message if message is not None else decoded)
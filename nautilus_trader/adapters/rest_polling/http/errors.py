@@ -0,0 +1,62 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from typing import Any
+
+
+# HTTP status codes for which a request is safe to retry
+REST_POLLING_RETRY_STATUS_CODES: frozenset[int] = frozenset([408, 429, 500, 502, 503, 504])
+
+
+class RestPollingError(Exception):
+    """
+    Represents errors returned by a venue for the REST polling adapter.
+
+    The `code` is the HTTP status code, or the error code extracted from the
+    response body using the mapping `error_code` path.
+
+    """
+
+    def __init__(
+        self,
+        code: int | str | None,
+        message: Any,
+    ) -> None:
+        super().__init__(message)
+        self.code = code
+        self.message = message
+
+    def __repr__(self) -> str:
+        return f"{type(self).__name__}(code={self.code}, message='{self.message}')"
+
+
+def should_retry(error: BaseException) -> bool:
+    """
+    Determine if a retry should be attempted based on the error code.
+
+    Parameters
+    ----------
+    error : BaseException
+        The error to check.
+
+    Returns
+    -------
+    bool
+        True if should retry, otherwise False.
+
+    """
+    if isinstance(error, RestPollingError):
+        return error.code in REST_POLLING_RETRY_STATUS_CODES
+    return False
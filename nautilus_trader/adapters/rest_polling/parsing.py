@@ -0,0 +1,381 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from decimal import Decimal
+from typing import Any
+
+import pandas as pd
+
+from nautilus_trader.adapters.rest_polling.schema import RestPollingEndpoint
+from nautilus_trader.adapters.rest_polling.schema import RestPollingMapping
+from nautilus_trader.adapters.rest_polling.schema import extract_field
+from nautilus_trader.core.datetime import dt_to_unix_nanos
+from nautilus_trader.core.datetime import secs_to_nanos
+from nautilus_trader.core.datetime import unix_nanos_to_iso8601
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.execution.reports import OrderStatusReport
+from nautilus_trader.model.data import Bar
+from nautilus_trader.model.data import BarType
+from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.model.data import TradeTick
+from nautilus_trader.model.enums import AggressorSide
+from nautilus_trader.model.enums import ContingencyType
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import TimeInForce
+from nautilus_trader.model.enums import TriggerType
+from nautilus_trader.model.enums import bar_aggregation_to_str
+from nautilus_trader.model.identifiers import AccountId
+from nautilus_trader.model.identifiers import ClientOrderId
+from nautilus_trader.model.identifiers import TradeId
+from nautilus_trader.model.identifiers import VenueOrderId
+from nautilus_trader.model.instruments import Instrument
+from nautilus_trader.model.objects import AccountBalance
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import Money
+
+
+_TIMESTAMP_MULTIPLIERS: dict[str, int] = {
+    "s": 1_000_000_000,
+    "ms": 1_000_000,
+    "us": 1_000,
+    "ns": 1,
+}
+
+
+class RestPollingParser:
+    """
+    Provides parsing of venue responses and request values for a REST schema mapping.
+
+    Parameters
+    ----------
+    mapping : RestPollingMapping
+        The REST schema mapping for the venue.
+
+    """
+
+    def __init__(self, mapping: RestPollingMapping) -> None:
+        self._mapping = mapping
+
+        self._aggressor_sides: dict[str, AggressorSide] = {
+            k.lower(): AggressorSide[v] for k, v in mapping.aggressor_sides.items()
+        }
+        self._order_statuses: dict[str, OrderStatus] = {
+            k: OrderStatus[v] for k, v in mapping.order_statuses.items()
+        }
+        self._order_sides: dict[str, OrderSide] = {
+            v: OrderSide[k] for k, v in mapping.order_sides.items()
+        }
+        self._order_types: dict[str, OrderType] = {
+            v: OrderType[k] for k, v in mapping.order_types.items()
+        }
+        self._time_in_force: dict[str, TimeInForce] = {
+            v: TimeInForce[k] for k, v in mapping.time_in_force.items()
+        }
+
+    @staticmethod
+    def items(response: Any, endpoint: RestPollingEndpoint) -> list[Any]:
+        """
+        Return the items located by the endpoint `items` path in the response.
+
+        If the endpoint has no `items` path then the response itself is the single item.
+
+        """
+        if endpoint.items is None:
+            return [] if response is None else [response]
+
+        items = extract_field(response, endpoint.items)
+        if items is None:
+            return []
+        if isinstance(items, list):
+            return items
+        return [items]
+
+    @staticmethod
+    def field(item: Any, endpoint: RestPollingEndpoint, name: str) -> Any:
+        """
+        Return the value of the named field for the item, or ``None`` if not mapped.
+        """
+        return extract_field(item, endpoint.fields.get(name))
+
+    def parse_timestamp(self, value: Any) -> int:
+        """
+        Return the UNIX timestamp (nanoseconds) for the venue timestamp value.
+        """
+        unit = self._mapping.timestamp_unit
+        if unit == "iso":
+            return dt_to_unix_nanos(pd.Timestamp(value))
+        return int(Decimal(str(value)) * _TIMESTAMP_MULTIPLIERS[unit])
+
+    def format_timestamp(self, timestamp_ns: int) -> str:
+        """
+        Return the venue timestamp value for the UNIX timestamp (nanoseconds).
+        """
+        unit = self._mapping.timestamp_unit
+        if unit == "iso":
+            return unix_nanos_to_iso8601(timestamp_ns, nanos_precision=False)
+        return str(timestamp_ns // _TIMESTAMP_MULTIPLIERS[unit])
+
+    def bar_interval(self, bar_type: BarType) -> str | None:
+        """
+        Return the venue kline interval for the bar type, or ``None`` if not mapped.
+        """
+        spec = bar_type.spec
+        key = f"{spec.step}-{bar_aggregation_to_str(spec.aggregation)}"
+        return self._mapping.bar_intervals.get(key)
+
+    def format_order_side(self, order_side: OrderSide) -> str | None:
+        return self._mapping.order_sides.get(order_side.name)
+
+    def format_order_type(self, order_type: OrderType) -> str | None:
+        return self._mapping.order_types.get(order_type.name)
+
+    def format_time_in_force(self, time_in_force: TimeInForce) -> str | None:
+        return self._mapping.time_in_force.get(time_in_force.name)
+
+    def parse_order_status(self, value: Any) -> OrderStatus:
+        order_status = self._order_statuses.get(str(value))
+        if order_status is None:
+            raise ValueError(f"Unmapped venue order status '{value}'")
+        return order_status
+
+    def parse_quote_tick(
+        self,
+        response: Any,
+        endpoint: RestPollingEndpoint,
+        instrument: Instrument,
+        ts_init: int,
+    ) -> QuoteTick | None:
+        items = self.items(response, endpoint)
+        if not items:
+            return None
+
+        item = items[0]
+        bid_price = self.field(item, endpoint, "bid_price")
+        ask_price = self.field(item, endpoint, "ask_price")
+        bid_size = self.field(item, endpoint, "bid_size")
+        ask_size = self.field(item, endpoint, "ask_size")
+        if bid_price is None or ask_price is None or bid_size is None or ask_size is None:
+            return None  # One side of the book is empty
+
+        ts_event = self.field(item, endpoint, "ts_event")
+        return QuoteTick(
+            instrument_id=instrument.id,
+            bid_price=instrument.make_price(bid_price),
+            ask_price=instrument.make_price(ask_price),
+            bid_size=instrument.make_qty(bid_size),
+            ask_size=instrument.make_qty(ask_size),
+            ts_event=self.parse_timestamp(ts_event) if ts_event is not None else ts_init,
+            ts_init=ts_init,
+        )
+
+    def parse_trade_ticks(
+        self,
+        response: Any,
+        endpoint: RestPollingEndpoint,
+        instrument: Instrument,
+        ts_init: int,
+    ) -> list[TradeTick]:
+        """
+        Parse the trades response into trade ticks, sorted by `ts_event`.
+        """
+        trades: list[TradeTick] = []
+        for item in self.items(response, endpoint):
+            side = self.field(item, endpoint, "side")
+            aggressor_side = AggressorSide.NO_AGGRESSOR
+            if side is not None:
+                aggressor_side = self._aggressor_sides.get(
+                    str(side).lower(),
+                    AggressorSide.NO_AGGRESSOR,
+                )
+
+            ts_event = self.parse_timestamp(self.field(item, endpoint, "ts_event"))
+            trade_id = self.field(item, endpoint, "trade_id")
+            trades.append(
+                TradeTick(
+                    instrument_id=instrument.id,
+                    price=instrument.make_price(self.field(item, endpoint, "price")),
+                    size=instrument.make_qty(self.field(item, endpoint, "size")),
+                    aggressor_side=aggressor_side,
+                    trade_id=TradeId(str(trade_id if trade_id is not None else ts_event)),
+                    ts_event=ts_event,
+                    ts_init=ts_init,
+                ),
+            )
+
+        trades.sort(key=lambda t: t.ts_event)
+        return trades
+
+    def parse_bars(
+        self,
+        response: Any,
+        endpoint: RestPollingEndpoint,
+        bar_type: BarType,
+        instrument: Instrument,
+        ts_init: int,
+    ) -> list[Bar]:
+        """
+        Parse the klines response into bars, sorted by `ts_event`.
+
+        The `ts_event` field of a kline is the open time, and the bar `ts_event`
+        is the close time.
+
+        """
+        interval_ns = secs_to_nanos(bar_type.spec.timedelta.total_seconds())
+
+        bars: list[Bar] = []
+        for item in self.items(response, endpoint):
+            ts_open = self.parse_timestamp(self.field(item, endpoint, "ts_event"))
+            bars.append(
+                Bar(
+                    bar_type=bar_type,
+                    open=instrument.make_price(self.field(item, endpoint, "open")),
+                    high=instrument.make_price(self.field(item, endpoint, "high")),
+                    low=instrument.make_price(self.field(item, endpoint, "low")),
+                    close=instrument.make_price(self.field(item, endpoint, "close")),
+                    volume=instrument.make_qty(self.field(item, endpoint, "volume")),
+                    ts_event=ts_open + interval_ns,
+                    ts_init=ts_init,
+                ),
+            )
+
+        bars.sort(key=lambda b: b.ts_event)
+        return bars
+
+    def parse_order_status_report(
+        self,
+        item: Any,
+        endpoint: RestPollingEndpoint,
+        account_id: AccountId,
+        instrument: Instrument,
+        report_id: UUID4,
+        ts_init: int,
+        client_order_id: ClientOrderId | None = None,
+    ) -> OrderStatusReport:
+        """
+        Parse the order item into an order status report.
+
+        The `client_order_id` is used if the venue does not report the client order ID.
+
+        """
+        venue_order_id = self.field(item, endpoint, "order_id")
+        if venue_order_id is None:
+            raise ValueError("No `order_id` in order status")
+
+        venue_client_order_id = self.field(item, endpoint, "client_order_id")
+        if venue_client_order_id:
+            client_order_id = ClientOrderId(str(venue_client_order_id))
+
+        quantity = self.field(item, endpoint, "quantity")
+        filled_qty = self.field(item, endpoint, "filled_qty") or 0
+
+        # Venues may report a zero price for market orders
+        price_value = self.field(item, endpoint, "price")
+        price = instrument.make_price(price_value) if price_value and float(price_value) else None
+
+        avg_px = self.field(item, endpoint, "avg_px")
+        if avg_px is None:
+            # Some venues report the cumulative quote quantity rather than the average price
+            cum_quote_qty = self.field(item, endpoint, "cum_quote_qty")
+            if cum_quote_qty is not None and Decimal(str(filled_qty)):
+                avg_px = Decimal(str(cum_quote_qty)) / Decimal(str(filled_qty))
+
+        order_type_value = self.field(item, endpoint, "type")
+        order_type = self._order_types.get(str(order_type_value))
+        if order_type is None:
+            order_type = OrderType.LIMIT if price is not None else OrderType.MARKET
+
+        time_in_force = self._time_in_force.get(
+            str(self.field(item, endpoint, "time_in_force")),
+            TimeInForce.GTC,
+        )
+
+        ts_event = self.field(item, endpoint, "ts_event")
+        ts_last = self.parse_timestamp(ts_event) if ts_event is not None else ts_init
+
+        return OrderStatusReport(
+            account_id=account_id,
+            instrument_id=instrument.id,
+            client_order_id=client_order_id,
+            order_list_id=None,
+            venue_order_id=VenueOrderId(str(venue_order_id)),
+            order_side=self._order_sides.get(
+                str(self.field(item, endpoint, "side")),
+                OrderSide.NO_ORDER_SIDE,
+            ),
+            order_type=order_type,
+            contingency_type=ContingencyType.NO_CONTINGENCY,
+            time_in_force=time_in_force,
+            order_status=self.parse_order_status(self.field(item, endpoint, "status")),
+            price=price,
+            trigger_price=None,
+            trigger_type=TriggerType.NO_TRIGGER,
+            quantity=instrument.make_qty(quantity if quantity is not None else 0),
+            filled_qty=instrument.make_qty(filled_qty),
+            avg_px=Decimal(str(avg_px)) if avg_px and float(avg_px) else None,
+            post_only=False,
+            reduce_only=False,
+            ts_accepted=ts_last,
+            ts_last=ts_last,
+            report_id=report_id,
+            ts_init=ts_init,
+        )
+
+    def parse_account_balances(
+        self,
+        response: Any,
+        endpoint: RestPollingEndpoint,
+    ) -> list[AccountBalance]:
+        """
+        Parse the balances response into account balances.
+
+        Either the `total` or the `free` field must be mapped, and a missing `total`,
+        `free` or `locked` value is derived from the other two. Zero balances are skipped.
+
+        """
+        balances: list[AccountBalance] = []
+        for item in self.items(response, endpoint):
+            code = self.field(item, endpoint, "currency")
+            if code is None:
+                continue
+
+            currency = Currency.from_str(str(code))
+            total = self.field(item, endpoint, "total")
+            free = self.field(item, endpoint, "free")
+            locked = self.field(item, endpoint, "locked")
+
+            locked_dec = Decimal(str(locked)) if locked is not None else Decimal(0)
+            if total is not None:
+                total_dec = Decimal(str(total))
+                free_dec = Decimal(str(free)) if free is not None else total_dec - locked_dec
+            elif free is not None:
+                free_dec = Decimal(str(free))
+                total_dec = free_dec + locked_dec
+            else:
+                continue
+
+            if total_dec == 0:
+                continue
+
+            balances.append(
+                AccountBalance(
+                    total=Money(total_dec, currency),
+                    locked=Money(total_dec - free_dec, currency),
+                    free=Money(free_dec, currency),
+                ),
+            )
+
+        return balances
@@ -0,0 +1,123 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from nautilus_trader.common.providers import InstrumentProvider
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.model.identifiers import Venue
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.adapters.rest_polling.schema import RestPollingInstrument
+    from nautilus_trader.adapters.rest_polling.schema import RestPollingMapping
+    from nautilus_trader.common.component import LiveClock
+    from nautilus_trader.config import InstrumentProviderConfig
+    from nautilus_trader.model.identifiers import InstrumentId
+
+
+class RestPollingInstrumentProvider(InstrumentProvider):
+    """
+    Provides Nautilus instrument definitions from the instruments of a REST schema mapping.
+
+    Parameters
+    ----------
+    mapping : RestPollingMapping
+        The REST schema mapping for the venue.
+    clock : LiveClock
+        The clock instance.
+    config : InstrumentProviderConfig, optional
+        The instrument provider configuration, by default None.
+
+    """
+
+    def __init__(
+        self,
+        mapping: RestPollingMapping,
+        clock: LiveClock,
+        config: InstrumentProviderConfig | None = None,
+    ) -> None:
+        super().__init__(config=config)
+        self._mapping = mapping
+        self._clock = clock
+        self._venue = Venue(mapping.venue)
+
+        self._log_warnings = config.log_warnings if config else True
+
+    async def load_all_async(self, filters: dict | None = None) -> None:
+        filters_str = "..." if not filters else f" with filters {filters}..."
+        self._log.info(f"Loading all instruments{filters_str}")
+
+        self._load_instruments()
+
+        self._log.info(f"Loaded {len(self._instruments)} instruments")
+
+    async def load_ids_async(
+        self,
+        instrument_ids: list[InstrumentId],
+        filters: dict | None = None,
+    ) -> None:
+        if not instrument_ids:
+            self._log.warning("No instrument IDs given for loading")
+            return
+
+        # Check all instrument IDs
+        for instrument_id in instrument_ids:
+            PyCondition.equal(
+                instrument_id.venue,
+                self._venue,
+                "instrument_id.venue",
+                self._venue.value,
+            )
+
+        self._load_instruments(set(instrument_ids))
+
+    async def load_async(self, instrument_id: InstrumentId, filters: dict | None = None) -> None:
+        PyCondition.not_none(instrument_id, "instrument_id")
+        await self.load_ids_async([instrument_id], filters)
+
+    def _load_instruments(self, instrument_ids: set[InstrumentId] | None = None) -> None:
+        # Instruments are statically defined by the mapping, so no requests are made
+        for definition in self._mapping.instruments:
+            self._parse_instrument(definition, instrument_ids)
+
+    def _parse_instrument(
+        self,
+        definition: RestPollingInstrument,
+        instrument_ids: set[InstrumentId] | None,
+    ) -> None:
+        try:
+            base_currency = self.currency(definition.base)
+            quote_currency = self.currency(definition.quote)
+            self.add_currency(base_currency)
+            self.add_currency(quote_currency)
+            ts_event = self._clock.timestamp_ns()
+            ts_init = self._clock.timestamp_ns()
+            instrument = definition.parse_to_instrument(
+                venue=self._venue,
+                base_currency=base_currency,
+                quote_currency=quote_currency,
+                ts_event=ts_event,
+                ts_init=ts_init,
+            )
+        except ValueError as e:
+            if self._log_warnings:
+                self._log.warning(f"Unable to parse instrument {definition.symbol}: {e}")
+            return
+
+        if instrument_ids is None or instrument.id in instrument_ids:
+            self.add(instrument=instrument)
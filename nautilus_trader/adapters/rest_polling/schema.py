@@ -0,0 +1,308 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from decimal import Decimal
+from pathlib import Path
+from typing import Any
+
+import msgspec
+
+from nautilus_trader.model.enums import AggressorSide
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import Symbol
+from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.model.instruments import CurrencyPair
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import Money
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+
+
+REST_POLLING_ENCODINGS: tuple[str, ...] = ("query", "form", "json")
+REST_POLLING_HTTP_METHODS: tuple[str, ...] = ("GET", "POST", "PUT", "DELETE", "PATCH")
+REST_POLLING_SIGNATURE_ENCODINGS: tuple[str, ...] = ("hex", "base64")
+REST_POLLING_TIMESTAMP_UNITS: tuple[str, ...] = ("s", "ms", "us", "ns", "iso")
+
+
+def _increment_str(precision: int) -> str:
+    return format(Decimal(10) ** -precision, f".{precision}f")
+
+
+class RestPollingEndpoint(msgspec.Struct, frozen=True, kw_only=True):
+    """
+    Represents the mapping for a single REST endpoint.
+
+    The `path` and `params` values are templates which are formatted with the request
+    values, such as ``{symbol}`` or ``{quantity}``. A parameter whose template is a single
+    placeholder without a value (such as ``{price}`` for a market order) is omitted.
+
+    Response values are located with dotted paths, where integer segments index into
+    lists (for example ``result.0.price``). The `items` path locates the list of items
+    in the response (an empty string is the response root), and the `fields` map the
+    Nautilus field names to paths within each item.
+
+    """
+
+    path: str
+    method: str = "GET"
+    params: dict[str, str] = msgspec.field(default_factory=dict)
+    encoding: str = "query"
+    signed: bool = False
+    items: str | None = None
+    fields: dict[str, str] = msgspec.field(default_factory=dict)
+
+    def __post_init__(self) -> None:
+        if self.method not in REST_POLLING_HTTP_METHODS:
+            raise ValueError(
+                f"invalid `method` '{self.method}', valid values are {REST_POLLING_HTTP_METHODS}",
+            )
+        if self.encoding not in REST_POLLING_ENCODINGS:
+            raise ValueError(
+                f"invalid `encoding` '{self.encoding}', valid values are {REST_POLLING_ENCODINGS}",
+            )
+
+
+class RestPollingEndpoints(msgspec.Struct, frozen=True, kw_only=True):
+    """
+    Represents the mapped endpoints for a venue.
+
+    Only the endpoints for the data and functionality in use need to be mapped.
+
+    """
+
+    quote: RestPollingEndpoint | None = None
+    trades: RestPollingEndpoint | None = None
+    klines: RestPollingEndpoint | None = None
+    submit_order: RestPollingEndpoint | None = None
+    cancel_order: RestPollingEndpoint | None = None
+    order_status: RestPollingEndpoint | None = None
+    open_orders: RestPollingEndpoint | None = None
+    balances: RestPollingEndpoint | None = None
+
+
+class RestPollingAuth(msgspec.Struct, frozen=True, kw_only=True):
+    """
+    Represents the authentication scheme for signed endpoints.
+
+    Signed requests include the API key header and a timestamp parameter, and are signed
+    with an HMAC-SHA256 of the encoded query string (or body) using the API secret.
+    The signature is sent as the `signature_header` if set, otherwise as the
+    `signature_param` query parameter.
+
+    """
+
+    api_key_header: str = "X-API-KEY"
+    timestamp_param: str | None = "timestamp"
+    signature_param: str = "signature"
+    signature_header: str | None = None
+    signature_encoding: str = "hex"
+
+    def __post_init__(self) -> None:
+        if self.signature_encoding not in REST_POLLING_SIGNATURE_ENCODINGS:
+            raise ValueError(
+                f"invalid `signature_encoding` '{self.signature_encoding}', "
+                f"valid values are {REST_POLLING_SIGNATURE_ENCODINGS}",
+            )
+
+
+class RestPollingInstrument(msgspec.Struct, frozen=True, kw_only=True):
+    """
+    Represents a statically defined spot instrument.
+
+    The venue `symbol` is used as the Nautilus symbol.
+
+    """
+
+    symbol: str
+    base: str
+    quote: str
+    price_precision: int
+    size_precision: int
+    min_quantity: str | None = None
+    min_notional: str | None = None
+    maker_fee: str = "0"
+    taker_fee: str = "0"
+
+    def parse_to_instrument(
+        self,
+        venue: Venue,
+        base_currency: Currency,
+        quote_currency: Currency,
+        ts_event: int,
+        ts_init: int,
+    ) -> CurrencyPair:
+        price_increment = Price.from_str(_increment_str(self.price_precision))
+        size_increment = Quantity.from_str(_increment_str(self.size_precision))
+        min_quantity = (
+            Quantity(float(self.min_quantity), self.size_precision) if self.min_quantity else None
+        )
+        min_notional = (
+            Money(Decimal(self.min_notional), quote_currency) if self.min_notional else None
+        )
+
+        return CurrencyPair(
+            instrument_id=InstrumentId(Symbol(self.symbol), venue),
+            raw_symbol=Symbol(self.symbol),
+            base_currency=base_currency,
+            quote_currency=quote_currency,
+            price_precision=self.price_precision,
+            size_precision=self.size_precision,
+            price_increment=price_increment,
+            size_increment=size_increment,
+            margin_init=Decimal(0),
+            margin_maint=Decimal(0),
+            maker_fee=Decimal(self.maker_fee),
+            taker_fee=Decimal(self.taker_fee),
+            ts_event=ts_event,
+            ts_init=ts_init,
+            lot_size=None,
+            max_quantity=None,
+            min_quantity=min_quantity,
+            min_notional=min_notional,
+            min_price=None,
+            max_price=None,
+            info=msgspec.structs.asdict(self),
+        )
+
+
+class RestPollingMapping(msgspec.Struct, frozen=True, kw_only=True):
+    """
+    Represents a REST schema mapping for a venue.
+
+    The `order_sides`, `order_types` and `time_in_force` map Nautilus enum names to venue
+    values for requests. The `order_statuses` map venue values to Nautilus `OrderStatus`
+    names, and the `aggressor_sides` map venue values (compared as lowercase strings)
+    to Nautilus `AggressorSide` names. The `bar_intervals` map bar specifications
+    (for example ``1-MINUTE``) to venue kline intervals.
+
+    If `error_code` is set then every response is checked for an error code at that
+    path, and an error is raised unless the code is one of the `success_codes`.
+
+    The `rate_limit_per_second` is the default quota for all requests to the venue.
+
+    """
+
+    venue: str
+    base_url: str
+    instruments: list[RestPollingInstrument]
+    endpoints: RestPollingEndpoints
+    auth: RestPollingAuth | None = None
+    rate_limit_per_second: int = 5
+    timestamp_unit: str = "ms"
+    error_code: str | None = None
+    error_message: str | None = None
+    success_codes: list[str] = msgspec.field(default_factory=list)
+    bar_intervals: dict[str, str] = msgspec.field(default_factory=dict)
+    aggressor_sides: dict[str, str] = msgspec.field(
+        default_factory=lambda: {"buy": "BUYER", "sell": "SELLER"},
+    )
+    order_sides: dict[str, str] = msgspec.field(
+        default_factory=lambda: {"BUY": "BUY", "SELL": "SELL"},
+    )
+    order_types: dict[str, str] = msgspec.field(
+        default_factory=lambda: {"MARKET": "MARKET", "LIMIT": "LIMIT"},
+    )
+    time_in_force: dict[str, str] = msgspec.field(
+        default_factory=lambda: {"GTC": "GTC", "IOC": "IOC", "FOK": "FOK"},
+    )
+    order_statuses: dict[str, str] = msgspec.field(default_factory=dict)
+
+    def __post_init__(self) -> None:
+        if self.rate_limit_per_second <= 0:
+            raise ValueError("`rate_limit_per_second` must be positive")
+        if self.timestamp_unit not in REST_POLLING_TIMESTAMP_UNITS:
+            raise ValueError(
+                f"invalid `timestamp_unit` '{self.timestamp_unit}', "
+                f"valid values are {REST_POLLING_TIMESTAMP_UNITS}",
+            )
+        for value in self.aggressor_sides.values():
+            if value not in AggressorSide.__members__:
+                raise ValueError(f"invalid aggressor side '{value}' in `aggressor_sides`")
+        for value in self.order_statuses.values():
+            if value not in OrderStatus.__members__:
+                raise ValueError(f"invalid order status '{value}' in `order_statuses`")
+
+
+def load_mapping(path: str | Path) -> RestPollingMapping:
+    """
+    Load a REST schema mapping from the given JSON or TOML file.
+
+    Parameters
+    ----------
+    path : str or Path
+        The path to the mapping file.
+
+    Returns
+    -------
+    RestPollingMapping
+
+    Raises
+    ------
+    ValueError
+        If the file extension is not `.json` or `.toml`.
+    msgspec.ValidationError
+        If the mapping is invalid.
+
+    """
+    path = Path(path)
+    suffix = path.suffix.lower()
+    if suffix == ".json":
+        return msgspec.json.decode(path.read_bytes(), type=RestPollingMapping)
+    if suffix == ".toml":
+        return msgspec.toml.decode(path.read_bytes(), type=RestPollingMapping)
+
+    raise ValueError(f"Unsupported mapping file type '{path.suffix}', use JSON or TOML")
+
+
+def extract_field(obj: Any, path: str | None) -> Any:
+    """
+    Return the value at the given dotted path within the decoded JSON object.
+
+    Integer path segments index into lists, and an empty path returns the object itself.
+
+    Parameters
+    ----------
+    obj : Any
+        The decoded JSON object.
+    path : str, optional
+        The dotted path to the value.
+
+    Returns
+    -------
+    Any
+        The value, or ``None`` if the path is ``None`` or does not exist.
+
+    """
+    if path is None:
+        return None
+    if path == "":
+        return obj
+
+    for key in path.split("."):
+        if isinstance(obj, list):
+            try:
+                obj = obj[int(key)]
+            except (ValueError, IndexError):
+                return None
+        elif isinstance(obj, dict):
+            obj = obj.get(key)
+        else:
+            return None
+
+        if obj is None:
+            return None
+
+    return obj
@@ -0,0 +1,318 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from collections.abc import Iterable
+
+import pandas as pd
+
+from nautilus_trader.common.providers import InstrumentProvider
+from nautilus_trader.model.enums import InstrumentClass
+from nautilus_trader.model.enums import OptionKind
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.model.instruments import Instrument
+
+
+def canonical_symbol(
+    instrument: Instrument,
+    quote_aliases: dict[str, str] | None = None,
+) -> str | None:
+    """
+    Return the venue independent canonical symbol for the given instrument.
+
+    Canonical symbols are formed from the base (or underlying) and quote currencies, with
+    a suffix for derivatives:
+
+    - Spot: ``BTC/USDT``
+    - Perpetual: ``BTC/USDT-PERP``
+    - Future: ``BTC/USD-20241227``
+    - Option: ``BTC/USD-20241227-100000-C``
+
+    Parameters
+    ----------
+    instrument : Instrument
+        The instrument for the canonical symbol.
+    quote_aliases : dict[str, str], optional
+        The mapping of quote currency codes to the code used in canonical symbols,
+        e.g. ``{"USDT": "USD"}`` to treat USDT and USD quoted instruments as equivalent.
+
+    Returns
+    -------
+    str or ``None``
+        ``None`` if the instrument class has no canonical symbol (such as spreads).
+
+    """
+    base = instrument.get_base_currency()
+    underlying = getattr(instrument, "underlying", None)
+    if base is not None:
+        base_code = base.code
+    elif underlying is not None:
+        base_code = str(underlying)
+    else:
+        base_code = instrument.raw_symbol.value
+
+    quote_code = instrument.quote_currency.code
+    if quote_aliases:
+        quote_code = quote_aliases.get(quote_code, quote_code)
+
+    symbol = f"{base_code}/{quote_code}"
+    match instrument.instrument_class:
+        case InstrumentClass.SPOT:
+            return symbol
+        case InstrumentClass.SWAP:
+            return f"{symbol}-PERP"
+        case InstrumentClass.FUTURE:
+            return f"{symbol}-{_expiry(instrument)}"
+        case InstrumentClass.OPTION:
+            strike = instrument.strike_price.as_decimal().normalize()
+            kind = "C" if instrument.option_kind == OptionKind.CALL else "P"
+            return f"{symbol}-{_expiry(instrument)}-{strike:f}-{kind}"
+        case _:
+            return None
+
+
+def _expiry(instrument: Instrument) -> str:
+    return pd.Timestamp(instrument.expiration_ns, tz="UTC").strftime("%Y%m%d")
+
+
+class InstrumentSymbolRegistry:
+    """
+    Provides a registry of equivalent instruments across venues, keyed by canonical symbol.
+
+    Strategies trading the same instrument on several venues (such as cross-exchange
+    arbitrage) can use the registry to find the per-venue instrument IDs for a canonical
+    instrument, e.g. the ``BTC/USD-PERP`` perpetual on BitMEX and Deribit.
+
+    Canonical symbols are derived from the instruments (see `canonical_symbol`), and
+    may be specified explicitly where venues differ in the instrument definitions.
+
+    Parameters
+    ----------
+    quote_aliases : dict[str, str], optional
+        The mapping of quote currency codes to the code used in canonical symbols,
+        e.g. ``{"USDT": "USD", "USDC": "USD"}`` to group stablecoin quoted instruments.
+
+    """
+
+    def __init__(self, quote_aliases: dict[str, str] | None = None) -> None:
+        self._quote_aliases = quote_aliases or {}
+        self._instrument_ids: dict[str, list[InstrumentId]] = {}
+        self._canonicals: dict[InstrumentId, str] = {}
+
+    def add(self, instrument: Instrument, canonical: str | None = None) -> str | None:
+        """
+        Add the instrument to the registry.
+
+        Parameters
+        ----------
+        instrument : Instrument
+            The instrument to add.
+        canonical : str, optional
+            The canonical symbol for the instrument, if ``None`` then derived from the instrument.
+
+        Returns
+        -------
+        str or ``None``
+            The canonical symbol the instrument was added for, ``None`` if the instrument
+            has no canonical symbol (and was not added).
+
+        """
+        if canonical is None:
+            canonical = canonical_symbol(instrument, self._quote_aliases)
+            if canonical is None:
+                return None
+
+        self.remove(instrument.id)
+        self._instrument_ids.setdefault(canonical, []).append(instrument.id)
+        self._canonicals[instrument.id] = canonical
+        return canonical
+
+    def add_instruments(self, instruments: Iterable[Instrument]) -> None:
+        """
+        Add the instruments to the registry.
+
+        Parameters
+        ----------
+        instruments : Iterable[Instrument]
+            The instruments to add, e.g. from the cache with ``cache.instruments()``.
+
+        """
+        for instrument in instruments:
+            self.add(instrument)
+
+    def add_provider(self, provider: InstrumentProvider) -> None:
+        """
+        Add all instruments loaded by the instrument provider to the registry.
+
+        Parameters
+        ----------
+        provider : InstrumentProvider
+            The adapter instrument provider.
+
+        """
+        self.add_instruments(provider.list_all())
+
+    def remove(self, instrument_id: InstrumentId) -> None:
+        """
+        Remove the instrument from the registry (if registered).
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID to remove.
+
+        """
+        canonical = self._canonicals.pop(instrument_id, None)
+        if canonical is None:
+            return
+
+        instrument_ids = self._instrument_ids[canonical]
+        instrument_ids.remove(instrument_id)
+        if not instrument_ids:
+            del self._instrument_ids[canonical]
+
+    def canonical(self, instrument_id: InstrumentId) -> str | None:
+        """
+        Return the canonical symbol for the instrument ID.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the canonical symbol.
+
+        Returns
+        -------
+        str or ``None``
+
+        """
+        return self._canonicals.get(instrument_id)
+
+    def canonicals(self, min_venues: int = 1) -> list[str]:
+        """
+        Return the registered canonical symbols, listed on at least `min_venues` venues.
+
+        Parameters
+        ----------
+        min_venues : int, default 1
+            The minimum number of venues listing the instrument, e.g. 2 for the
+            instruments which can be traded across venues.
+
+        Returns
+        -------
+        list[str]
+
+        """
+        return sorted(
+            canonical
+            for canonical in self._instrument_ids
+            if len(self.venues(canonical)) >= min_venues
+        )
+
+    def venues(self, canonical: str) -> list[Venue]:
+        """
+        Return the venues listing the canonical instrument.
+
+        Parameters
+        ----------
+        canonical : str
+            The canonical symbol.
+
+        Returns
+        -------
+        list[Venue]
+
+        """
+        venues: list[Venue] = []
+        for instrument_id in self._instrument_ids.get(canonical, []):
+            if instrument_id.venue not in venues:
+                venues.append(instrument_id.venue)
+        return venues
+
+    def instrument_ids(self, canonical: str, venue: Venue | None = None) -> list[InstrumentId]:
+        """
+        Return the instrument IDs for the canonical instrument.
+
+        A venue may list more than one instrument for a canonical symbol when quote
+        currencies are aliased (e.g. both USDT and USDC quoted perpetuals).
+
+        Parameters
+        ----------
+        canonical : str
+            The canonical symbol.
+        venue : Venue, optional
+            The venue to filter the instrument IDs for.
+
+        Returns
+        -------
+        list[InstrumentId]
+
+        """
+        instrument_ids = self._instrument_ids.get(canonical, [])
+        if venue is None:
+            return list(instrument_ids)
+        return [instrument_id for instrument_id in instrument_ids if instrument_id.venue == venue]
+
+    def instrument_id(self, canonical: str, venue: Venue) -> InstrumentId | None:
+        """
+        Return the instrument ID for the canonical instrument on the venue.
+
+        Parameters
+        ----------
+        canonical : str
+            The canonical symbol.
+        venue : Venue
+            The venue for the instrument ID.
+
+        Returns
+        -------
+        InstrumentId or ``None``
+
+        Raises
+        ------
+        ValueError
+            If the venue lists more than one instrument for the canonical symbol.
+
+        """
+        instrument_ids = self.instrument_ids(canonical, venue)
+        if len(instrument_ids) > 1:
+            raise ValueError(
+                f"More than one instrument for {canonical} on {venue}: {instrument_ids}",
+            )
+        return instrument_ids[0] if instrument_ids else None
+
+    def equivalents(self, instrument_id: InstrumentId) -> list[InstrumentId]:
+        """
+        Return the equivalent instrument IDs on other venues for the instrument ID.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID to find equivalents for.
+
+        Returns
+        -------
+        list[InstrumentId]
+
+        """
+        canonical = self._canonicals.get(instrument_id)
+        if canonical is None:
+            return []
+        return [
+            other
+            for other in self._instrument_ids[canonical]
+            if other.venue != instrument_id.venue
+        ]
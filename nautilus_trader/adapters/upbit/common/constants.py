@@ -0,0 +1,70 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from decimal import Decimal
+from typing import Final
+
+from nautilus_trader.model.identifiers import Venue
+
+
+UPBIT: Final[str] = "UPBIT"
+UPBIT_VENUE: Final[Venue] = Venue(UPBIT)
+
+# Minimum order price units (tick sizes) for KRW markets, as (minimum price, tick size)
+UPBIT_KRW_TICK_SIZES: Final[tuple[tuple[Decimal, Decimal], ...]] = (
+    (Decimal(1_000_000), Decimal(1_000)),
+    (Decimal(500_000), Decimal(500)),
+    (Decimal(100_000), Decimal(100)),
+    (Decimal(50_000), Decimal(50)),
+    (Decimal(10_000), Decimal(10)),
+    (Decimal(5_000), Decimal(5)),
+    (Decimal(100), Decimal(1)),
+    (Decimal(10), Decimal("0.1")),
+    (Decimal(1), Decimal("0.01")),
+    (Decimal("0.1"), Decimal("0.001")),
+    (Decimal("0.01"), Decimal("0.0001")),
+    (Decimal("0.001"), Decimal("0.00001")),
+    (Decimal("0.0001"), Decimal("0.000001")),
+    (Decimal("0.00001"), Decimal("0.0000001")),
+    (Decimal(0), Decimal("0.00000001")),
+)
+
+# BTC markets have a fixed tick size of one satoshi
+UPBIT_BTC_TICK_SIZE: Final[Decimal] = Decimal("0.00000001")
+
+# Order volumes are in base currency units with up to 8 decimal places
+UPBIT_SIZE_PRECISION: Final[int] = 8
+
+# Prices are parsed with the precision of the tick size for a price this many times
+# lower than the last traded price, so prices in lower tick size bands are not rounded
+UPBIT_PRICE_PRECISION_HEADROOM: Final[Decimal] = Decimal(10)
+
+UPBIT_MIN_ORDER_NOTIONAL: Final[dict[str, Decimal]] = {
+    "KRW": Decimal(5_000),
+    "BTC": Decimal("0.00005"),
+}
+
+UPBIT_MAX_ORDER_NOTIONAL: Final[dict[str, Decimal]] = {
+    "KRW": Decimal(1_000_000_000),
+}
+
+UPBIT_TRADING_FEES: Final[dict[str, Decimal]] = {
+    "KRW": Decimal("0.0005"),
+    "BTC": Decimal("0.0025"),
+}
+
+UPBIT_SUPPORTED_QUOTE_CURRENCIES: Final[frozenset[str]] = frozenset(UPBIT_TRADING_FEES)
+
+UPBIT_REST_RATE_LIMIT_KEY: Final[str] = "upbit:quotation"
@@ -0,0 +1,128 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from decimal import Decimal
+
+from nautilus_trader.adapters.upbit.common.constants import UPBIT_BTC_TICK_SIZE
+from nautilus_trader.adapters.upbit.common.constants import UPBIT_KRW_TICK_SIZES
+from nautilus_trader.adapters.upbit.common.constants import UPBIT_PRICE_PRECISION_HEADROOM
+from nautilus_trader.model.enums import AggressorSide
+
+
+def get_tick_size(quote_currency: str, price: Decimal) -> Decimal:
+    """
+    Return the tick size for an order at the given price on an Upbit market.
+
+    The tick size of KRW markets depends on the price, from 1,000 KRW for prices
+    of 1,000,000 KRW and above, down to 0.00000001 KRW for the lowest prices.
+
+    Parameters
+    ----------
+    quote_currency : str
+        The quote currency of the market (e.g. `KRW`).
+    price : Decimal
+        The order price.
+
+    Returns
+    -------
+    Decimal
+
+    Raises
+    ------
+    ValueError
+        If the quote currency is not supported.
+
+    """
+    match quote_currency:
+        case "KRW":
+            for min_price, tick_size in UPBIT_KRW_TICK_SIZES:
+                if price >= min_price:
+                    return tick_size
+            return UPBIT_KRW_TICK_SIZES[-1][1]
+        case "BTC":
+            return UPBIT_BTC_TICK_SIZE
+        case _:
+            raise ValueError(f"unsupported Upbit quote currency, was '{quote_currency}'")
+
+
+def get_price_precision(quote_currency: str, price: Decimal | None) -> int:
+    """
+    Return the price precision for an Upbit market trading at the given price.
+
+    The precision is for the tick size a price band below, so prices are
+    represented exactly after large price moves (until the instrument is reloaded).
+
+    Parameters
+    ----------
+    quote_currency : str
+        The quote currency of the market (e.g. `KRW`).
+    price : Decimal, optional
+        The last traded price (if ``None`` then the finest tick size is used).
+
+    Returns
+    -------
+    int
+
+    """
+    if price is None:
+        price = Decimal(0)
+    tick_size = get_tick_size(quote_currency, price / UPBIT_PRICE_PRECISION_HEADROOM)
+    return decimal_places(tick_size)
+
+
+def decimal_places(value: Decimal) -> int:
+    """
+    Return the number of decimal places for the given value.
+
+    Parameters
+    ----------
+    value : Decimal
+        The value.
+
+    Returns
+    -------
+    int
+
+    """
+    exponent = value.normalize().as_tuple().exponent
+    assert isinstance(exponent, int)  # Finite values only
+    return max(-exponent, 0)
+
+
+def parse_aggressor_side(ask_bid: str) -> AggressorSide:
+    """
+    Parse the Upbit trade `ask_bid` side into an aggressor side.
+
+    The side is the side of the taker order, so `ASK` is a sell and `BID` a buy.
+
+    Parameters
+    ----------
+    ask_bid : str
+        The Upbit trade side.
+
+    Returns
+    -------
+    AggressorSide
+
+    """
+    match ask_bid:
+        case "BID":
+            return AggressorSide.BUYER
+        case "ASK":
+            return AggressorSide.SELLER
+        case _:
+            return AggressorSide.NO_AGGRESSOR
@@ -0,0 +1,41 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from nautilus_trader.config import LiveDataClientConfig
+from nautilus_trader.config import PositiveInt
+
+
+class UpbitDataClientConfig(LiveDataClientConfig, frozen=True):
+    """
+    Configuration for ``UpbitDataClient`` instances.
+
+    Market data is public, so no credentials are required.
+
+    Parameters
+    ----------
+    base_url_http : str, optional
+        The base URL for the HTTP client.
+    base_url_ws : str, optional
+        The base URL for the WebSocket client.
+    update_instruments_interval_mins: PositiveInt or None, default 60
+        The interval (minutes) between reloading instruments from the venue.
+        Instruments are reloaded to update the price increment of KRW markets,
+        which depends on the price.
+
+    """
+
+    base_url_http: str | None = None
+    base_url_ws: str | None = None
+    update_instruments_interval_mins: PositiveInt | None = 60
@@ -0,0 +1,324 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+import asyncio
+from typing import TYPE_CHECKING
+
+import msgspec
+
+from nautilus_trader.adapters.upbit.common.constants import UPBIT_VENUE
+from nautilus_trader.adapters.upbit.common.symbol import UpbitSymbol
+from nautilus_trader.adapters.upbit.schemas.ws import UpbitWsMessageGeneral
+from nautilus_trader.adapters.upbit.schemas.ws import UpbitWsOrderbookMsg
+from nautilus_trader.adapters.upbit.schemas.ws import UpbitWsTradeMsg
+from nautilus_trader.adapters.upbit.websocket.client import UpbitWebSocketClient
+from nautilus_trader.common.enums import LogColor
+from nautilus_trader.data.messages import RequestBars
+from nautilus_trader.data.messages import RequestInstrument
+from nautilus_trader.data.messages import RequestInstruments
+from nautilus_trader.data.messages import RequestQuoteTicks
+from nautilus_trader.data.messages import RequestTradeTicks
+from nautilus_trader.data.messages import SubscribeOrderBook
+from nautilus_trader.data.messages import SubscribeQuoteTicks
+from nautilus_trader.data.messages import SubscribeTradeTicks
+from nautilus_trader.data.messages import UnsubscribeOrderBook
+from nautilus_trader.data.messages import UnsubscribeQuoteTicks
+from nautilus_trader.data.messages import UnsubscribeTradeTicks
+from nautilus_trader.live.data_client import LiveMarketDataClient
+from nautilus_trader.model.enums import BookType
+from nautilus_trader.model.identifiers import ClientId
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.adapters.upbit.config import UpbitDataClientConfig
+    from nautilus_trader.adapters.upbit.providers import UpbitInstrumentProvider
+    from nautilus_trader.cache.cache import Cache
+    from nautilus_trader.common.component import LiveClock
+    from nautilus_trader.common.component import MessageBus
+    from nautilus_trader.model.instruments import Instrument
+
+
+class UpbitDataClient(LiveMarketDataClient):
+    """
+    Provides a data client for the Upbit spot exchange.
+
+    Order book deltas and quotes are both parsed from the order book stream, which
+    publishes a snapshot of the top levels of the book on every change.
+
+    Parameters
+    ----------
+    loop : asyncio.AbstractEventLoop
+        The event loop for the client.
+    msgbus : MessageBus
+        The message bus for the client.
+    cache : Cache
+        The cache for the client.
+    clock : LiveClock
+        The clock for the client.
+    instrument_provider : UpbitInstrumentProvider
+        The instrument provider.
+    base_url_ws : str
+        The base URL for the WebSocket client.
+    config : UpbitDataClientConfig
+        The configuration for the client.
+    name : str, optional
+        The custom client ID.
+
+    """
+
+    def __init__(
+        self,
+        loop: asyncio.AbstractEventLoop,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+        instrument_provider: UpbitInstrumentProvider,
+        base_url_ws: str,
+        config: UpbitDataClientConfig,
+        name: str | None,
+    ) -> None:
+        super().__init__(
+            loop=loop,
+            client_id=ClientId(name or UPBIT_VENUE.value),
+            venue=UPBIT_VENUE,
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+            instrument_provider=instrument_provider,
+        )
+
+        # Configuration
+        self._log.info(f"{config.update_instruments_interval_mins=}", LogColor.BLUE)
+
+        # WebSocket API
+        self._ws_client = UpbitWebSocketClient(
+            clock=clock,
+            base_url=base_url_ws,
+            handler=self._handle_ws_message,
+            handler_reconnect=None,
+            loop=loop,
+        )
+
+        # WebSocket decoders
+        self._decoder_ws_msg_general = msgspec.json.Decoder(UpbitWsMessageGeneral)
+        self._decoder_ws_orderbook = msgspec.json.Decoder(UpbitWsOrderbookMsg)
+        self._decoder_ws_trade = msgspec.json.Decoder(UpbitWsTradeMsg)
+
+        # Market codes subscribed through the order book stream
+        self._book_subs: set[str] = set()
+        self._quote_subs: set[str] = set()
+
+        self._update_instruments_interval_mins: int | None = config.update_instruments_interval_mins
+        self._update_instruments_task: asyncio.Task | None = None
+
+    async def _connect(self) -> None:
+        await self._instrument_provider.initialize()
+        self._send_all_instruments_to_data_engine()
+
+        if self._update_instruments_interval_mins:
+            self._update_instruments_task = self.create_task(
+                self._update_instruments(self._update_instruments_interval_mins),
+            )
+
+        await self._ws_client.connect()
+
+    async def _disconnect(self) -> None:
+        if self._update_instruments_task:
+            self._log.debug("Canceling task 'update_instruments'")
+            self._update_instruments_task.cancel()
+            self._update_instruments_task = None
+
+        await self._ws_client.disconnect()
+
+    def _send_all_instruments_to_data_engine(self) -> None:
+        for instrument in self._instrument_provider.get_all().values():
+            self._handle_data(instrument)
+
+        for currency in self._instrument_provider.currencies().values():
+            self._cache.add_currency(currency)
+
+    async def _update_instruments(self, interval_mins: int) -> None:
+        try:
+            while True:
+                self._log.debug(
+                    f"Scheduled task 'update_instruments' to run in {interval_mins} minutes",
+                )
+                await asyncio.sleep(interval_mins * 60)
+                await self._instrument_provider.initialize(reload=True)
+                self._send_all_instruments_to_data_engine()
+        except asyncio.CancelledError:
+            self._log.debug("Canceled task 'update_instruments'")
+
+    async def _subscribe_order_book_deltas(self, command: SubscribeOrderBook) -> None:
+        if command.book_type != BookType.L2_MBP:
+            self._log.error(
+                f"Cannot subscribe to order book deltas: "
+                f"{command.book_type} data is not published by Upbit. "
+                "Valid book types are L2_MBP",
+            )
+            return
+
+        code = command.instrument_id.symbol.value
+        self._book_subs.add(code)
+        await self._subscribe_orderbook_stream(code)
+
+    async def _subscribe_quote_ticks(self, command: SubscribeQuoteTicks) -> None:
+        code = command.instrument_id.symbol.value
+        self._quote_subs.add(code)
+        await self._subscribe_orderbook_stream(code)
+
+    async def _subscribe_trade_ticks(self, command: SubscribeTradeTicks) -> None:
+        await self._ws_client.subscribe_trades(command.instrument_id.symbol.value)
+
+    async def _unsubscribe_order_book_deltas(self, command: UnsubscribeOrderBook) -> None:
+        code = command.instrument_id.symbol.value
+        self._book_subs.discard(code)
+        await self._unsubscribe_orderbook_stream(code)
+
+    async def _unsubscribe_quote_ticks(self, command: UnsubscribeQuoteTicks) -> None:
+        code = command.instrument_id.symbol.value
+        self._quote_subs.discard(code)
+        await self._unsubscribe_orderbook_stream(code)
+
+    async def _unsubscribe_trade_ticks(self, command: UnsubscribeTradeTicks) -> None:
+        await self._ws_client.unsubscribe_trades(command.instrument_id.symbol.value)
+
+    async def _subscribe_orderbook_stream(self, code: str) -> None:
+        if self._ws_client.has_subscription("orderbook", code):
+            return  # Already streaming for deltas or quotes
+
+        await self._ws_client.subscribe_order_book(code)
+
+    async def _unsubscribe_orderbook_stream(self, code: str) -> None:
+        if code in self._book_subs or code in self._quote_subs:
+            return  # Still required for deltas or quotes
+
+        if not self._ws_client.has_subscription("orderbook", code):
+            return
+
+        await self._ws_client.unsubscribe_order_book(code)
+
+    async def _request_instrument(self, request: RequestInstrument) -> None:
+        if request.start is not None:
+            self._log.warning(
+                f"Requesting instrument {request.instrument_id} with specified `start` which has no effect",
+            )
+
+        if request.end is not None:
+            self._log.warning(
+                f"Requesting instrument {request.instrument_id} with specified `end` which has no effect",
+            )
+
+        instrument: Instrument | None = self._instrument_provider.find(request.instrument_id)
+        if instrument is None:
+            self._log.error(f"Cannot find instrument for {request.instrument_id}")
+            return
+
+        self._handle_instrument(instrument, request.id, request.params)
+
+    async def _request_instruments(self, request: RequestInstruments) -> None:
+        if request.start is not None:
+            self._log.warning(
+                f"Requesting instruments for {request.venue} with specified `start` which has no effect",
+            )
+
+        if request.end is not None:
+            self._log.warning(
+                f"Requesting instruments for {request.venue} with specified `end` which has no effect",
+            )
+
+        all_instruments = self._instrument_provider.get_all()
+        target_instruments = []
+        for instrument in all_instruments.values():
+            if instrument.venue == request.venue:
+                target_instruments.append(instrument)
+
+        self._handle_instruments(
+            request.venue,
+            target_instruments,
+            request.id,
+            request.params,
+        )
+
+    async def _request_quote_ticks(self, request: RequestQuoteTicks) -> None:
+        self._log.error("Cannot request historical quotes: not published by Upbit")
+
+    async def _request_trade_ticks(self, request: RequestTradeTicks) -> None:
+        self._log.error("Cannot request historical trades: not yet implemented for Upbit")
+
+    async def _request_bars(self, request: RequestBars) -> None:
+        self._log.error("Cannot request historical bars: not yet implemented for Upbit")
+
+    def _get_cached_instrument(self, code: str) -> Instrument | None:
+        instrument_id = UpbitSymbol(code).to_instrument_id()
+        instrument = self._cache.instrument(instrument_id)
+        if instrument is None:
+            self._log.error(f"Cannot parse data: no instrument for {instrument_id}")
+        return instrument
+
+    def _handle_ws_message(self, raw: bytes) -> None:
+        try:
+            ws_message = self._decoder_ws_msg_general.decode(raw)
+            if ws_message.type == "orderbook":
+                self._handle_orderbook(raw)
+            elif ws_message.type == "trade":
+                self._handle_trade(raw)
+            else:
+                self._log.debug(f"Unhandled websocket message: {raw.decode()}")
+        except Exception as e:
+            self._log.exception(f"Failed to handle websocket message {raw.decode()}", e)
+
+    def _handle_orderbook(self, raw: bytes) -> None:
+        msg = self._decoder_ws_orderbook.decode(raw)
+        instrument = self._get_cached_instrument(msg.code)
+        if instrument is None:
+            return
+
+        ts_init = self._clock.timestamp_ns()
+
+        if msg.code in self._book_subs:
+            deltas = msg.parse_to_deltas(
+                instrument_id=instrument.id,
+                price_precision=instrument.price_precision,
+                size_precision=instrument.size_precision,
+                ts_init=ts_init,
+            )
+            self._handle_data(deltas)
+
+        if msg.code in self._quote_subs:
+            quote = msg.parse_to_quote_tick(
+                instrument_id=instrument.id,
+                price_precision=instrument.price_precision,
+                size_precision=instrument.size_precision,
+                ts_init=ts_init,
+            )
+            if quote is not None:
+                self._handle_data(quote)
+
+    def _handle_trade(self, raw: bytes) -> None:
+        msg = self._decoder_ws_trade.decode(raw)
+        instrument = self._get_cached_instrument(msg.code)
+        if instrument is None:
+            return
+
+        trade = msg.parse_to_trade_tick(
+            instrument_id=instrument.id,
+            price_precision=instrument.price_precision,
+            size_precision=instrument.size_precision,
+            ts_init=self._clock.timestamp_ns(),
+        )
+        self._handle_data(trade)
@@ -0,0 +1,158 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import asyncio
+from functools import lru_cache
+
+from nautilus_trader.adapters.upbit.common.constants import UPBIT_REST_RATE_LIMIT_KEY
+from nautilus_trader.adapters.upbit.common.urls import UPBIT_HTTP_BASE_URL
+from nautilus_trader.adapters.upbit.common.urls import UPBIT_WS_BASE_URL
+from nautilus_trader.adapters.upbit.config import UpbitDataClientConfig
+from nautilus_trader.adapters.upbit.data import UpbitDataClient
+from nautilus_trader.adapters.upbit.http.client import UpbitHttpClient
+from nautilus_trader.adapters.upbit.providers import UpbitInstrumentProvider
+from nautilus_trader.cache.cache import Cache
+from nautilus_trader.common.component import LiveClock
+from nautilus_trader.common.component import MessageBus
+from nautilus_trader.config import InstrumentProviderConfig
+from nautilus_trader.core.nautilus_pyo3 import Quota
+from nautilus_trader.live.factories import LiveDataClientFactory
+
+
+@lru_cache(1)
+def get_cached_upbit_http_client(
+    clock: LiveClock,
+    base_url: str | None = None,
+) -> UpbitHttpClient:
+    """
+    Cache and return an Upbit HTTP client.
+
+    If a cached client with matching parameters already exists, the cached client will be returned.
+
+    Parameters
+    ----------
+    clock : LiveClock
+        The clock for the client.
+    base_url : str, optional
+        The base URL for the API endpoints.
+
+    Returns
+    -------
+    UpbitHttpClient
+
+    """
+    # Setup rate limit quotas
+    # Quotation (market data) requests are limited to 10 per second
+    ratelimiter_default_quota = Quota.rate_per_second(10)
+    ratelimiter_quotas: list[tuple[str, Quota]] = [
+        (UPBIT_REST_RATE_LIMIT_KEY, Quota.rate_per_second(10)),
+    ]
+
+    return UpbitHttpClient(
+        clock=clock,
+        base_url=base_url or UPBIT_HTTP_BASE_URL,
+        ratelimiter_quotas=ratelimiter_quotas,
+        ratelimiter_default_quota=ratelimiter_default_quota,
+    )
+
+
+@lru_cache(1)
+def get_cached_upbit_instrument_provider(
+    client: UpbitHttpClient,
+    clock: LiveClock,
+    config: InstrumentProviderConfig,
+) -> UpbitInstrumentProvider:
+    """
+    Cache and return an Upbit instrument provider.
+
+    If a cached provider already exists, then that provider will be returned.
+
+    Parameters
+    ----------
+    client : UpbitHttpClient
+        The Upbit HTTP client.
+    clock : LiveClock
+        The clock instance.
+    config : InstrumentProviderConfig
+        The instrument provider configuration.
+
+    Returns
+    -------
+    UpbitInstrumentProvider
+
+    """
+    return UpbitInstrumentProvider(
+        client=client,
+        clock=clock,
+        config=config,
+    )
+
+
+class UpbitLiveDataClientFactory(LiveDataClientFactory):
+    """
+    Provides an Upbit live data client factory.
+    """
+
+    @staticmethod
+    def create(  # type: ignore
+        loop: asyncio.AbstractEventLoop,
+        name: str,
+        config: UpbitDataClientConfig,
+        msgbus: MessageBus,
+        cache: Cache,
+        clock: LiveClock,
+    ) -> UpbitDataClient:
+        """
+        Create a new Upbit data client.
+
+        Parameters
+        ----------
+        loop : asyncio.AbstractEventLoop
+            The event loop for the client.
+        name : str
+            The custom client ID.
+        config : UpbitDataClientConfig
+            The client configuration.
+        msgbus : MessageBus
+            The message bus for the client.
+        cache : Cache
+            The cache for the client.
+        clock: LiveClock
+            The clock for the instrument provider.
+
+        Returns
+        -------
+        UpbitDataClient
+
+        """
+        client: UpbitHttpClient = get_cached_upbit_http_client(
+            clock=clock,
+            base_url=config.base_url_http,
+        )
+        provider = get_cached_upbit_instrument_provider(
+            client=client,
+            clock=clock,
+            config=config.instrument_provider,
+        )
+        return UpbitDataClient(
+            loop=loop,
+            msgbus=msgbus,
+            cache=cache,
+            clock=clock,
+            instrument_provider=provider,
+            base_url_ws=config.base_url_ws or UPBIT_WS_BASE_URL,
+            config=config,
+            name=name,
+        )
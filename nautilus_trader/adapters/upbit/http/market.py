@@ -0,0 +1,83 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import msgspec
+
+from nautilus_trader.adapters.upbit.common.constants import UPBIT_REST_RATE_LIMIT_KEY
+from nautilus_trader.adapters.upbit.schemas.market import UpbitMarket
+from nautilus_trader.adapters.upbit.schemas.market import UpbitTicker
+from nautilus_trader.core.correctness import PyCondition
+from nautilus_trader.core.nautilus_pyo3 import HttpMethod
+
+
+if TYPE_CHECKING:
+    from nautilus_trader.adapters.upbit.http.client import UpbitHttpClient
+    from nautilus_trader.common.component import LiveClock
+
+
+# The maximum number of markets per ticker request (to bound the URL length)
+_TICKER_BATCH_SIZE = 100
+
+
+class UpbitMarketHttpAPI:
+    """
+    Provides access to the public Upbit quotation REST endpoints.
+
+    Parameters
+    ----------
+    client : UpbitHttpClient
+        The Upbit HTTP client.
+    clock : LiveClock
+        The clock for the API client.
+
+    """
+
+    def __init__(
+        self,
+        client: UpbitHttpClient,
+        clock: LiveClock,
+    ) -> None:
+        PyCondition.not_none(client, "client")
+        self.client = client
+        self._clock = clock
+
+        self._decoder_markets = msgspec.json.Decoder(list[UpbitMarket])
+        self._decoder_tickers = msgspec.json.Decoder(list[UpbitTicker])
+
+    async def fetch_markets(self) -> list[UpbitMarket]:
+        raw = await self.client.send_request(
+            HttpMethod.GET,
+            "/market/all",
+            payload={"isDetails": "true"},
+            ratelimiter_keys=[UPBIT_REST_RATE_LIMIT_KEY],
+        )
+        return self._decoder_markets.decode(raw)
+
+    async def fetch_tickers(self, markets: list[str]) -> list[UpbitTicker]:
+        tickers: list[UpbitTicker] = []
+        for i in range(0, len(markets), _TICKER_BATCH_SIZE):
+            batch = markets[i : i + _TICKER_BATCH_SIZE]
+            raw = await self.client.send_request(
+                HttpMethod.GET,
+                "/ticker",
+                payload={"markets": ",".join(batch)},
+                ratelimiter_keys=[UPBIT_REST_RATE_LIMIT_KEY],
+            )
+            tickers.extend(self._decoder_tickers.decode(raw))
+        return tickers
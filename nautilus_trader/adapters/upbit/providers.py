@@ -0,0 +1,149 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from nautilus_trader.adapters.upbit.common.constants import UPBIT_SUPPORTED_QUOTE_CURRENCIES
+from nautilus_trader.adapters.upbit.common.constants import UPBIT_VENUE
+from nautilus_trader.adapters.upbit.http.market import UpbitMarketHttpAPI
+from nautilus_trader.common.providers import InstrumentProvider
+from nautilus_trader.core.correctness import PyCondition
+
+
+if TYPE_CHECKING:
+    from decimal import Decimal
+
+    from nautilus_trader.adapters.upbit.http.client import UpbitHttpClient
+    from nautilus_trader.adapters.upbit.schemas.market import UpbitMarket
+    from nautilus_trader.common.component import LiveClock
+    from nautilus_trader.config import InstrumentProviderConfig
+    from nautilus_trader.model.identifiers import InstrumentId
+
+
+class UpbitInstrumentProvider(InstrumentProvider):
+    """
+    Provides Nautilus instrument definitions from Upbit KRW and BTC markets.
+
+    The tick size of KRW markets depends on the price, so the last traded price of
+    each market is requested to determine the price increment and precision.
+
+    The markets can be filtered by quote currency with a `quote_currencies` filter,
+    e.g. `filters={"quote_currencies": ["KRW"]}`.
+
+    Parameters
+    ----------
+    client : UpbitHttpClient
+        The Upbit HTTP client.
+    clock : LiveClock
+        The clock instance.
+    config : InstrumentProviderConfig, optional
+        The instrument provider configuration, by default None.
+
+    """
+
+    def __init__(
+        self,
+        client: UpbitHttpClient,
+        clock: LiveClock,
+        config: InstrumentProviderConfig | None = None,
+    ) -> None:
+        super().__init__(config=config)
+        self._clock = clock
+        self._client = client
+        self._http_market = UpbitMarketHttpAPI(client, clock)
+
+        self._log_warnings = config.log_warnings if config else True
+
+    async def load_all_async(self, filters: dict | None = None) -> None:
+        filters_str = "..." if not filters else f" with filters {filters}..."
+        self._log.info(f"Loading all instruments{filters_str}")
+
+        await self._load_instruments(filters=filters)
+
+        self._log.info(f"Loaded {len(self._instruments)} instruments")
+
+    async def load_ids_async(
+        self,
+        instrument_ids: list[InstrumentId],
+        filters: dict | None = None,
+    ) -> None:
+        if not instrument_ids:
+            self._log.warning("No instrument IDs given for loading")
+            return
+
+        # Check all instrument IDs
+        for instrument_id in instrument_ids:
+            PyCondition.equal(
+                instrument_id.venue,
+                UPBIT_VENUE,
+                "instrument_id.venue",
+                "UPBIT",
+            )
+
+        await self._load_instruments(set(instrument_ids), filters)
+
+    async def load_async(self, instrument_id: InstrumentId, filters: dict | None = None) -> None:
+        PyCondition.not_none(instrument_id, "instrument_id")
+        await self.load_ids_async([instrument_id], filters)
+
+    async def _load_instruments(
+        self,
+        instrument_ids: set[InstrumentId] | None = None,
+        filters: dict | None = None,
+    ) -> None:
+        quote_currencies = set(
+            (filters or {}).get("quote_currencies", UPBIT_SUPPORTED_QUOTE_CURRENCIES),
+        )
+
+        markets: list[UpbitMarket] = []
+        for market in await self._http_market.fetch_markets():
+            if market.symbol.quote not in quote_currencies:
+                continue
+            if instrument_ids is not None and market.symbol.to_instrument_id() not in instrument_ids:
+                continue
+            markets.append(market)
+
+        tickers = await self._http_market.fetch_tickers([market.market for market in markets])
+        last_prices = {ticker.market: ticker.last_price for ticker in tickers}
+
+        for market in markets:
+            self._parse_instrument(market, last_prices.get(market.market))
+
+    def _parse_instrument(self, market: UpbitMarket, last_price: Decimal | None) -> None:
+        try:
+            base_currency = self.currency(market.symbol.base)
+            quote_currency = self.currency(market.symbol.quote)
+            assert base_currency is not None  # Type checking
+            assert quote_currency is not None  # Type checking
+            self.add_currency(base_currency)
+            self.add_currency(quote_currency)
+
+            ts_event = self._clock.timestamp_ns()
+            ts_init = self._clock.timestamp_ns()
+            instrument = market.parse_to_instrument(
+                base_currency=base_currency,
+                quote_currency=quote_currency,
+                last_price=last_price,
+                ts_event=ts_event,
+                ts_init=ts_init,
+            )
+        except ValueError as e:
+            if self._log_warnings:
+                self._log.warning(f"Unable to parse instrument {market.market}: {e}")
+            return
+
+        self.add(instrument=instrument)
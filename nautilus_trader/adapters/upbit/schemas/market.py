@@ -0,0 +1,136 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from decimal import Decimal
+from typing import Any
+
+import msgspec
+
+from nautilus_trader.adapters.upbit.common.constants import UPBIT_MAX_ORDER_NOTIONAL
+from nautilus_trader.adapters.upbit.common.constants import UPBIT_MIN_ORDER_NOTIONAL
+from nautilus_trader.adapters.upbit.common.constants import UPBIT_SIZE_PRECISION
+from nautilus_trader.adapters.upbit.common.constants import UPBIT_TRADING_FEES
+from nautilus_trader.adapters.upbit.common.parsing import get_price_precision
+from nautilus_trader.adapters.upbit.common.parsing import get_tick_size
+from nautilus_trader.adapters.upbit.common.symbol import UpbitSymbol
+from nautilus_trader.model.identifiers import Symbol
+from nautilus_trader.model.instruments import CurrencyPair
+from nautilus_trader.model.objects import MONEY_MAX
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import Money
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+
+
+class UpbitMarket(msgspec.Struct, frozen=True):
+    """
+    Represents an Upbit market, as listed by `/market/all`.
+    """
+
+    market: str
+    korean_name: str | None = None
+    english_name: str | None = None
+    market_warning: str | None = None
+    market_event: dict[str, Any] | None = None
+
+    @property
+    def symbol(self) -> UpbitSymbol:
+        return UpbitSymbol(self.market)
+
+    def parse_to_instrument(
+        self,
+        base_currency: Currency,
+        quote_currency: Currency,
+        last_price: Decimal | None,
+        ts_event: int,
+        ts_init: int,
+    ) -> CurrencyPair:
+        """
+        Parse the market into a Nautilus currency pair instrument.
+
+        The tick size of KRW markets depends on the price, so the price increment is the
+        tick size at the `last_price`, and the price precision is the precision of the
+        tick size a price band below (see `get_price_precision`).
+
+        Raises
+        ------
+        ValueError
+            If the quote currency of the market is not supported.
+
+        """
+        symbol = self.symbol
+        quote = symbol.quote
+        if quote not in UPBIT_TRADING_FEES:
+            raise ValueError(f"unsupported quote currency {quote}")
+
+        price_precision = get_price_precision(quote, last_price)
+        tick_size = get_tick_size(quote, last_price or Decimal(0))
+        price_increment = Price(float(tick_size), price_precision)
+        size_increment = Quantity(10**-UPBIT_SIZE_PRECISION, UPBIT_SIZE_PRECISION)
+
+        min_notional = UPBIT_MIN_ORDER_NOTIONAL.get(quote)
+        max_notional = UPBIT_MAX_ORDER_NOTIONAL.get(quote)
+        if max_notional is not None and max_notional > MONEY_MAX:
+            # KRW notional limits can exceed the range of standard precision money values
+            max_notional = None
+
+        fee = UPBIT_TRADING_FEES[quote]
+
+        return CurrencyPair(
+            instrument_id=symbol.to_instrument_id(),
+            raw_symbol=Symbol(self.market),
+            base_currency=base_currency,
+            quote_currency=quote_currency,
+            price_precision=price_precision,
+            size_precision=UPBIT_SIZE_PRECISION,
+            price_increment=price_increment,
+            size_increment=size_increment,
+            margin_init=Decimal(0),
+            margin_maint=Decimal(0),
+            maker_fee=fee,
+            taker_fee=fee,
+            ts_event=ts_event,
+            ts_init=ts_init,
+            lot_size=None,
+            max_quantity=None,
+            min_quantity=None,
+            max_notional=Money(max_notional, quote_currency) if max_notional else None,
+            min_notional=Money(min_notional, quote_currency) if min_notional else None,
+            min_price=None,
+            max_price=None,
+            info=msgspec.to_builtins(self),
+        )
+
+
+class UpbitTicker(msgspec.Struct, frozen=True):
+    """
+    Represents an Upbit ticker, as returned by `/ticker`.
+
+    The accumulated trade values are in the quote currency, and for KRW markets
+    can exceed the range of standard precision money values, so are kept as floats.
+
+    """
+
+    market: str
+    trade_price: float
+    timestamp: int
+    acc_trade_price_24h: float | None = None
+    acc_trade_volume_24h: float | None = None
+
+    @property
+    def last_price(self) -> Decimal:
+        return Decimal(str(self.trade_price))
@@ -0,0 +1,174 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from typing import Any
+
+import msgspec
+
+from nautilus_trader.adapters.upbit.common.parsing import parse_aggressor_side
+from nautilus_trader.core.datetime import millis_to_nanos
+from nautilus_trader.model.data import BookOrder
+from nautilus_trader.model.data import OrderBookDelta
+from nautilus_trader.model.data import OrderBookDeltas
+from nautilus_trader.model.data import QuoteTick
+from nautilus_trader.model.data import TradeTick
+from nautilus_trader.model.enums import BookAction
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import RecordFlag
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import TradeId
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+
+
+class UpbitWsMessageGeneral(msgspec.Struct):
+    """
+    Represents the common fields of all Upbit WebSocket messages.
+
+    Data messages have a `type` and market `code`, whereas the response to a
+    `PING` has a `status`, and errors an `error` object.
+
+    """
+
+    type: str | None = None
+    code: str | None = None
+    stream_type: str | None = None
+    status: str | None = None
+    error: dict[str, Any] | None = None
+
+
+class UpbitWsOrderbookUnit(msgspec.Struct):
+    ask_price: float
+    bid_price: float
+    ask_size: float
+    bid_size: float
+
+
+class UpbitWsOrderbookMsg(msgspec.Struct):
+    """
+    Represents an Upbit order book message.
+
+    Every message is a snapshot of the top levels of the book, where each unit
+    has the ask and bid level at the same depth.
+
+    """
+
+    type: str
+    code: str
+    timestamp: int
+    orderbook_units: list[UpbitWsOrderbookUnit]
+    total_ask_size: float | None = None
+    total_bid_size: float | None = None
+    stream_type: str | None = None
+
+    def parse_to_deltas(
+        self,
+        instrument_id: InstrumentId,
+        price_precision: int,
+        size_precision: int,
+        ts_init: int,
+    ) -> OrderBookDeltas:
+        ts_event = millis_to_nanos(self.timestamp)
+
+        levels: list[tuple[OrderSide, float, float]] = []
+        for unit in self.orderbook_units:
+            if unit.bid_size > 0:
+                levels.append((OrderSide.BUY, unit.bid_price, unit.bid_size))
+            if unit.ask_size > 0:
+                levels.append((OrderSide.SELL, unit.ask_price, unit.ask_size))
+
+        deltas: list[OrderBookDelta] = [
+            OrderBookDelta.clear(instrument_id, 0, ts_event, ts_init),
+        ]
+
+        levels_len = len(levels)
+        for idx, (side, price, size) in enumerate(levels):
+            flags = RecordFlag.F_SNAPSHOT
+            if idx == levels_len - 1:
+                # F_LAST, 1 << 7
+                # Last message in the book event or packet from the venue for a given `instrument_id`
+                flags |= RecordFlag.F_LAST
+
+            deltas.append(
+                OrderBookDelta(
+                    instrument_id=instrument_id,
+                    action=BookAction.ADD,
+                    order=BookOrder(
+                        side=side,
+                        price=Price(price, price_precision),
+                        size=Quantity(size, size_precision),
+                        order_id=0,
+                    ),
+                    flags=flags,
+                    sequence=0,
+                    ts_event=ts_event,
+                    ts_init=ts_init,
+                ),
+            )
+
+        return OrderBookDeltas(instrument_id=instrument_id, deltas=deltas)
+
+    def parse_to_quote_tick(
+        self,
+        instrument_id: InstrumentId,
+        price_precision: int,
+        size_precision: int,
+        ts_init: int,
+    ) -> QuoteTick | None:
+        if not self.orderbook_units:
+            return None  # Empty book
+
+        top = self.orderbook_units[0]
+        return QuoteTick(
+            instrument_id=instrument_id,
+            bid_price=Price(top.bid_price, price_precision),
+            ask_price=Price(top.ask_price, price_precision),
+            bid_size=Quantity(top.bid_size, size_precision),
+            ask_size=Quantity(top.ask_size, size_precision),
+            ts_event=millis_to_nanos(self.timestamp),
+            ts_init=ts_init,
+        )
+
+
+class UpbitWsTradeMsg(msgspec.Struct):
+    type: str
+    code: str
+    trade_price: float
+    trade_volume: float
+    ask_bid: str
+    trade_timestamp: int
+    sequential_id: int
+    timestamp: int | None = None
+    stream_type: str | None = None
+
+    def parse_to_trade_tick(
+        self,
+        instrument_id: InstrumentId,
+        price_precision: int,
+        size_precision: int,
+        ts_init: int,
+    ) -> TradeTick:
+        # The sequential ID is unique per trade for a market
+        return TradeTick(
+            instrument_id=instrument_id,
+            price=Price(self.trade_price, price_precision),
+            size=Quantity(self.trade_volume, size_precision),
+            aggressor_side=parse_aggressor_side(self.ask_bid),
+            trade_id=TradeId(str(self.sequential_id)),
+            ts_event=millis_to_nanos(self.trade_timestamp),
+            ts_init=ts_init,
+        )
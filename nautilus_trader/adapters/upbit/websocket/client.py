@@ -0,0 +1,244 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+from typing import Any
+
+from msgspec import json as msgspec_json
+
+from nautilus_trader.adapters.upbit.schemas.ws import UpbitWsMessageGeneral
+from nautilus_trader.common.component import LiveClock
+from nautilus_trader.common.component import Logger
+from nautilus_trader.common.enums import LogColor
+from nautilus_trader.core.nautilus_pyo3 import WebSocketClient
+from nautilus_trader.core.nautilus_pyo3 import WebSocketClientError
+from nautilus_trader.core.nautilus_pyo3 import WebSocketConfig
+from nautilus_trader.core.uuid import UUID4
+
+
+if TYPE_CHECKING:
+    import asyncio
+    from collections.abc import Awaitable
+    from collections.abc import Callable
+
+
+# Data types which are streamed in real time only (without an initial snapshot)
+_REALTIME_ONLY_TYPES = {"trade"}
+
+
+class UpbitWebSocketClient:
+    """
+    Provides an Upbit streaming WebSocket client.
+
+    Every subscription request replaces the subscriptions of the connection, so the
+    full set of data types and market codes is sent whenever a subscription changes.
+
+    Parameters
+    ----------
+    clock : LiveClock
+        The clock instance.
+    base_url : str
+        The base URL for the WebSocket connection.
+    handler : Callable[[bytes], None]
+        The callback handler for data messages.
+    handler_reconnect : Callable[..., Awaitable[None]], optional
+        The callback handler to be called on reconnect.
+    loop : asyncio.AbstractEventLoop
+        The event loop for the client.
+
+    """
+
+    def __init__(
+        self,
+        clock: LiveClock,
+        base_url: str,
+        handler: Callable[[bytes], None],
+        handler_reconnect: Callable[..., Awaitable[None]] | None,
+        loop: asyncio.AbstractEventLoop,
+    ) -> None:
+        self._clock = clock
+        self._log: Logger = Logger(name=type(self).__name__)
+
+        self._base_url: str = base_url
+        self._handler: Callable[[bytes], None] = handler
+        self._handler_reconnect: Callable[..., Awaitable[None]] | None = handler_reconnect
+        self._loop = loop
+
+        self._client: WebSocketClient | None = None
+        self._is_running = False
+        self._reconnecting = False
+
+        # Market codes per data type, e.g. {"trade": {"KRW-BTC"}} (replayed on reconnect)
+        self._subscriptions: dict[str, set[str]] = {}
+
+        self._decoder_ws_message_general = msgspec_json.Decoder(UpbitWsMessageGeneral)
+
+    @property
+    def subscriptions(self) -> dict[str, set[str]]:
+        return self._subscriptions
+
+    def has_subscription(self, data_type: str, code: str) -> bool:
+        return code in self._subscriptions.get(data_type, set())
+
+    async def connect(self) -> None:
+        self._is_running = True
+        self._log.debug(f"Connecting to {self._base_url} websocket stream")
+
+        # Idle connections are closed after 120 seconds, so a `PING` is sent
+        # which is answered with a `{"status":"UP"}` message
+        config = WebSocketConfig(
+            url=self._base_url,
+            handler=self._msg_handler,
+            heartbeat=60,
+            heartbeat_msg="PING",
+            headers=[],
+        )
+        client = await WebSocketClient.connect(
+            config=config,
+            post_reconnection=self.reconnect,
+        )
+        self._client = client
+        self._log.info(f"Connected to {self._base_url}", LogColor.BLUE)
+
+    def reconnect(self) -> None:
+        """
+        Reconnect the client to the server and resubscribe to all streams.
+        """
+        if not self._is_running or self._reconnecting:
+            return
+
+        self._log.warning(f"Trying to reconnect to {self._base_url}")
+        self._reconnecting = True
+        self._loop.create_task(self._reconnect_wrapper())
+
+    async def _reconnect_wrapper(self) -> None:
+        try:
+            self._log.warning(f"Resubscribing to {len(self._subscriptions)} data types")
+
+            await self._send_subscriptions()
+
+            if self._handler_reconnect:
+                await self._handler_reconnect()
+
+            self._log.warning(f"Reconnected to {self._base_url}")
+        except Exception as e:
+            self._log.error(f"Reconnection failed: {e}")
+        finally:
+            self._reconnecting = False
+
+    async def disconnect(self) -> None:
+        self._is_running = False
+        self._reconnecting = False
+
+        if self._client is None:
+            self._log.warning("Cannot disconnect: not connected.")
+            return
+
+        try:
+            await self._client.disconnect()
+        except WebSocketClientError as e:
+            self._log.error(str(e))
+
+        self._client = None  # Dispose (will go out of scope)
+
+        self._log.info(f"Disconnected from {self._base_url}", LogColor.BLUE)
+
+    def _msg_handler(self, raw: bytes) -> None:
+        """
+        Handle pushed websocket messages.
+
+        Parameters
+        ----------
+        raw : bytes
+            The received message in bytes.
+
+        """
+        msg = self._decoder_ws_message_general.decode(raw)
+        if msg.type is not None:
+            self._handler(raw)
+        elif msg.error is not None:
+            self._log.error(f"Received error: {raw.decode()}")
+        elif msg.status is not None:
+            self._log.debug(f"Received status: {msg.status}")
+
+    ################################################################################
+    # Subscriptions
+    ################################################################################
+
+    async def _subscribe(self, data_type: str, code: str) -> None:
+        self._log.debug(f"Subscribing to {data_type} {code}")
+        codes = self._subscriptions.setdefault(data_type, set())
+        if code in codes:
+            self._log.warning(f"Cannot subscribe {data_type} '{code}': already subscribed")
+            return
+
+        codes.add(code)
+        await self._send_subscriptions()
+
+    async def _unsubscribe(self, data_type: str, code: str) -> None:
+        codes = self._subscriptions.get(data_type, set())
+        if code not in codes:
+            self._log.warning(f"Cannot unsubscribe {data_type} '{code}': not subscribed")
+            return
+
+        codes.discard(code)
+        if not codes:
+            self._subscriptions.pop(data_type)
+
+        await self._send_subscriptions()
+
+    async def _send_subscriptions(self) -> None:
+        if self._client is None:
+            self._log.error("Cannot send subscriptions: not connected")
+            return
+
+        if not self._subscriptions:
+            # A request requires at least one data type, so messages for
+            # the previous subscriptions continue until reconnected
+            self._log.debug("No subscriptions to send")
+            return
+
+        request: list[dict[str, Any]] = [{"ticket": UUID4().value}]
+        for data_type, codes in self._subscriptions.items():
+            request.append(
+                {
+                    "type": data_type,
+                    "codes": sorted(codes),
+                    "is_only_realtime": data_type in _REALTIME_ONLY_TYPES,
+                },
+            )
+        request.append({"format": "DEFAULT"})
+
+        encoded = msgspec_json.encode(request)
+        self._log.debug(f"SENDING: {encoded!r}")
+
+        try:
+            await self._client.send_text(encoded)
+        except WebSocketClientError as e:
+            self._log.error(str(e))
+
+    async def subscribe_order_book(self, code: str) -> None:
+        await self._subscribe("orderbook", code)
+
+    async def subscribe_trades(self, code: str) -> None:
+        await self._subscribe("trade", code)
+
+    async def unsubscribe_order_book(self, code: str) -> None:
+        await self._unsubscribe("orderbook", code)
+
+    async def unsubscribe_trades(self, code: str) -> None:
+        await self._unsubscribe("trade", code)
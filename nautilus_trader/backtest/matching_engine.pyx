@@ -845,6 +845,14 @@ cdef class OrderMatchingEngine:
             )
             return  # Invalid order
 
+        # Check GTX orders have a price to post to the book at
+        if order.time_in_force == TimeInForce.GTX and not order.has_price_c():
+            self._generate_order_rejected(
+                order,
+                f"GTX time in force not supported for {order.type_string_c()} orders",
+            )
+            return  # Invalid order
+
         cdef Price price
         if order.has_price_c():
             # Check order price precision
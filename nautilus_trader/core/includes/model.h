@@ -19,6 +19,11 @@
  */
 #define TRADE_ID_LEN 37
 
+/**
+ * The maximum number of amendments retained in the history of an order.
+ */
+#define ORDER_AMENDMENTS_MAX 32
+
 #if defined(HIGH_PRECISION)
 /**
  * The maximum fixed-point precision.
@@ -739,7 +744,7 @@ typedef enum TimeInForce {
      */
     GTD = 4,
     /**
-     * Day - the order is active until the end of the current trading session.
+     * Day - the order is active until the close of the current trading day at the venue.
      */
     DAY = 5,
     /**
@@ -750,6 +755,11 @@ typedef enum TimeInForce {
      * At the Closing (ATC) - the order is scheduled to be executed at the market's closing.
      */
     AT_THE_CLOSE = 7,
+    /**
+     * Good-Till-Crossing (GTX) - the order is active until canceled, and is only posted to the
+     * book as a maker (it is rejected if it would cross the book on entry).
+     */
+    GTX = 8,
 } TimeInForce;
 
 /**
@@ -3249,6 +3249,12 @@ class HttpClient:
         header_keys: list[str] | None = None,
         keyed_quotas: list[tuple[str, Quota]] | None = None,
         default_quota: Quota | None = None,
+        global_quota: Quota | None = None,
+        rate_limit_headers: list[RateLimitHeader] | None = None,
+        cache_responses: bool = False,
+        proxy: ProxyConfig | None = None,
+        retry_policy: HttpRetryPolicy | None = None,
+        tls: TlsConfig | None = None,
     ) -> None: ...
     async def request(
         self,
@@ -3259,6 +3265,7 @@ class HttpClient:
         keys: list[str] | None = None,
         timeout_secs: int | None = None,
     ) -> HttpResponse: ...
+    def latency(self) -> dict[str, LatencySnapshot]: ...
 
 class HttpMethod(Enum):
     GET = "GET"
@@ -3275,6 +3282,79 @@ class HttpResponse:
     @property
     def headers(self) -> dict[str, str]: ...
 
+class RateLimitHeader:
+    def __init__(
+        self,
+        name: str,
+        limit: int,
+        interval_secs: int,
+        threshold: float = 0.9,
+        key: str | None = None,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def limit(self) -> int: ...
+    @property
+    def key(self) -> str | None: ...
+
+class LatencySnapshot:
+    @property
+    def count(self) -> int: ...
+    @property
+    def mean_us(self) -> int: ...
+    @property
+    def p50_us(self) -> int: ...
+    @property
+    def p90_us(self) -> int: ...
+    @property
+    def p99_us(self) -> int: ...
+    @property
+    def max_us(self) -> int: ...
+
+class HttpRetryPolicy:
+    def __init__(
+        self,
+        max_retries: int = 3,
+        retry_status_codes: list[int] | None = None,
+        retry_server_errors: bool = True,
+        delay_initial_ms: int = 500,
+        delay_max_ms: int = 10_000,
+        backoff_factor: float = 2.0,
+        jitter_ms: int = 100,
+        max_elapsed_ms: int | None = None,
+        idempotency_header: str | None = None,
+    ) -> None: ...
+    @property
+    def max_retries(self) -> int: ...
+    @property
+    def idempotency_header(self) -> str | None: ...
+
+class ProxyConfig:
+    def __init__(self, url: str, no_proxy: list[str] | None = None) -> None: ...
+    @property
+    def url(self) -> str: ...
+    @property
+    def protocol(self) -> str: ...
+    @property
+    def no_proxy(self) -> list[str]: ...
+    def bypasses(self, host: str) -> bool: ...
+
+class TlsConfig:
+    def __init__(
+        self,
+        ca_bundle_path: str | None = None,
+        client_cert_path: str | None = None,
+        client_key_path: str | None = None,
+    ) -> None: ...
+    @property
+    def ca_bundle_path(self) -> str | None: ...
+    @property
+    def client_cert_path(self) -> str | None: ...
+    @property
+    def client_key_path(self) -> str | None: ...
+    def has_client_identity(self) -> bool: ...
+
 class Quota:
     @classmethod
     def rate_per_second(cls, max_burst: int) -> Quota: ...
@@ -3286,6 +3366,16 @@ class Quota:
 class WebSocketClientError(Exception):
     ...
 
+class PayloadCompression(Enum):
+    GZIP = "GZIP"
+    ZLIB = "ZLIB"
+    DEFLATE = "DEFLATE"
+    AUTO = "AUTO"
+
+class LivenessPolicy(Enum):
+    RECONNECT = "RECONNECT"
+    NOTIFY = "NOTIFY"
+
 class WebSocketConfig:
     def __init__(
         self,
@@ -3300,6 +3390,14 @@ class WebSocketConfig:
         reconnect_delay_max_ms: int | None = 30_000,
         reconnect_backoff_factor: float | None = 1.5,
         reconnect_jitter_ms: int | None = 100,
+        reconnect_max_attempts: int | None = None,
+        status_handler: Callable[[str, int], None] | None = None,
+        payload_compression: PayloadCompression | None = None,
+        proxy: ProxyConfig | None = None,
+        idle_timeout_ms: int | None = None,
+        max_missed_pongs: int | None = None,
+        liveness_policy: LivenessPolicy | None = None,
+        tls: TlsConfig | None = None,
     ) -> None: ...
 
 class WebSocketClient:
@@ -3318,10 +3416,27 @@ class WebSocketClient:
     def is_reconnecting(self) -> bool: ...
     def is_disconnecting(self) -> bool: ...
     def is_closed(self) -> bool: ...
+    def latency(self) -> dict[str, LatencySnapshot]: ...
+    def record_venue_latency(self, ts_event: int) -> None: ...
     def send(self, data: bytes, keys: list[str] | None = None) -> Awaitable[None]: ...
     def send_text(self, data: bytes, keys: list[str] | None = None) -> Awaitable[None]: ...
     def send_pong(self, data: bytes) -> Awaitable[None]: ...
 
+class ShardedWebSocketClient:
+    def __init__(
+        self,
+        config: WebSocketConfig,
+        max_subscriptions: int,
+        max_connections: int | None = None,
+        keyed_quotas: list[tuple[str, Quota]] = [],
+        default_quota: Quota | None = None,
+    ) -> None: ...
+    def subscribe(self, topic: str, message: bytes, keys: list[str] | None = None) -> Awaitable[None]: ...
+    def unsubscribe(self, topic: str, message: bytes, keys: list[str] | None = None) -> Awaitable[None]: ...
+    def send_text_all(self, data: bytes, keys: list[str] | None = None) -> Awaitable[None]: ...
+    def disconnect(self) -> Awaitable[None]: ...
+    def subscriptions(self) -> Awaitable[list[list[str]]]: ...
+
 class SocketConfig:
     def __init__(
         self,
@@ -3336,8 +3451,17 @@ class SocketConfig:
         reconnect_backoff_factor: float | None = 1.5,
         reconnect_jitter_ms: int | None = 100,
         certs_dir: str | None = None,
+        framing: SocketFraming | None = None,
     ) -> None: ...
 
+class SocketFraming:
+    class Delimiter(SocketFraming):
+        def __init__(self, delimiter: bytes) -> None: ...
+    class LengthPrefixed(SocketFraming):
+        def __init__(self, header_len: int) -> None: ...
+    class Fix(SocketFraming):
+        def __init__(self) -> None: ...
+
 class SocketClient:
     @classmethod
     def connect(
@@ -3356,6 +3480,33 @@ class SocketClient:
     def close(self) -> Awaitable[None]: ...
     def send(self, data: bytes) -> Awaitable[None]: ...
 
+class UnixSocketServer:
+    @classmethod
+    def bind(
+        cls,
+        path: str,
+        handler: Callable[[int, bytes], None],
+        framing: SocketFraming | None = None,
+    ) -> Awaitable[UnixSocketServer]: ...
+    @property
+    def path(self) -> str: ...
+    def connection_ids(self) -> list[int]: ...
+    def send(self, connection_id: int, data: bytes) -> Awaitable[None]: ...
+    def broadcast(self, data: bytes) -> Awaitable[int]: ...
+    def close(self) -> None: ...
+
+class UnixSocketClient:
+    @classmethod
+    def connect(
+        cls,
+        path: str,
+        handler: Callable[[bytes], None] | None = None,
+        framing: SocketFraming | None = None,
+    ) -> Awaitable[UnixSocketClient]: ...
+    def send(self, data: bytes) -> Awaitable[None]: ...
+    def is_closed(self) -> bool: ...
+    def close(self) -> Awaitable[None]: ...
+
 ###################################################################################################
 # Persistence
 ###################################################################################################
@@ -4538,15 +4689,53 @@ class DatabentoDataLoader:
     def load_imbalance(self, filepath: str, instrument_id: InstrumentId | None = None, price_precision: int | None = None) -> list[DatabentoImbalance]: ...  # noqa: E501
     def load_statistics(self, filepath: str, instrument_id: InstrumentId | None = None, price_precision: int | None = None) -> list[DatabentoStatistics]: ...  # noqa: E501
 
+class ConsolidatedQuote:
+    @property
+    def quote(self) -> QuoteTick: ...
+    @property
+    def bid_venue(self) -> Venue: ...
+    @property
+    def ask_venue(self) -> Venue: ...
+    @property
+    def bid_venue_count(self) -> int: ...
+    @property
+    def ask_venue_count(self) -> int: ...
+    @property
+    def is_locked(self) -> bool: ...
+    @property
+    def is_crossed(self) -> bool: ...
+
+class NbboConsolidator:
+    def __init__(
+        self,
+        instrument_id: InstrumentId,
+        stale_timeout_ns: int | None = None,
+    ) -> None: ...
+    @property
+    def instrument_id(self) -> InstrumentId: ...
+    def venues(self) -> list[Venue]: ...
+    def last(self) -> ConsolidatedQuote | None: ...
+    def handle_quote(self, quote: QuoteTick) -> ConsolidatedQuote | None: ...
+    def remove_venue(self, venue: Venue, ts: int) -> ConsolidatedQuote | None: ...
+    def reset(self) -> None: ...
+
 class DatabentoHistoricalClient:
     def __init__(
         self,
         key: str,
         publishers_filepath: str,
+        symbology_cache_path: str | None = None,
     ) -> None: ...
     @property
     def key(self) -> str: ...
     async def get_dataset_range(self, dataset: str) -> dict[str, str]: ...
+    async def resolve_symbology(
+        self,
+        dataset: str,
+        symbols: list[str],
+        start: int,
+        end: int | None = None,
+    ) -> dict[str, list[tuple[str, str, str]]]: ...
     async def get_range_instruments(
         self,
         dataset: str,
@@ -4636,6 +4825,46 @@ class DatabentoLiveClient:
     ) -> Awaitable[None]: ...
     def close(self) -> None: ...
 
+# Interactive Brokers
+
+class InteractiveBrokersLiveClient:
+    def __init__(
+        self,
+        host: str | None = None,
+        port: int | None = None,
+        client_id: int | None = None,
+        connection_timeout_secs: int | None = None,
+    ) -> None: ...
+    @property
+    def host(self) -> str: ...
+    @property
+    def port(self) -> int: ...
+    @property
+    def client_id(self) -> int: ...
+    def is_running(self) -> bool: ...
+    def is_closed(self) -> bool: ...
+    def request_instrument(self, instrument_id: InstrumentId) -> None: ...
+    def subscribe_quotes(self, instrument_id: InstrumentId) -> None: ...
+    def subscribe_trades(self, instrument_id: InstrumentId) -> None: ...
+    def subscribe_book_deltas(
+        self,
+        instrument_id: InstrumentId,
+        depth: int | None = None,
+        is_smart_depth: bool | None = None,
+    ) -> None: ...
+    def subscribe_bars(self, bar_type: BarType) -> None: ...
+    def unsubscribe_quotes(self, instrument_id: InstrumentId) -> None: ...
+    def unsubscribe_trades(self, instrument_id: InstrumentId) -> None: ...
+    def unsubscribe_book_deltas(
+        self,
+        instrument_id: InstrumentId,
+        depth: int | None = None,
+        is_smart_depth: bool | None = None,
+    ) -> None: ...
+    def unsubscribe_bars(self, bar_type: BarType) -> None: ...
+    def start(self, callback: Callable) -> Awaitable[None]: ...
+    def close(self) -> None: ...
+
 # Tardis
 
 def tardis_exchange_from_venue_str(venue_str: str) -> list[str]: ...
@@ -14,6 +14,9 @@ cdef extern from "../includes/model.h":
     # The maximum length of ASCII characters for a `TradeId` string value (including null terminator).
     const uintptr_t TRADE_ID_LEN # = 37
 
+    # The maximum number of amendments retained in the history of an order.
+    const uintptr_t ORDER_AMENDMENTS_MAX # = 32
+
     IF HIGH_PRECISION:
         # The maximum fixed-point precision.
         const uint8_t FIXED_PRECISION # = 16
@@ -398,12 +401,15 @@ cdef extern from "../includes/model.h":
         FOK # = 3,
         # Good-Till-Date/Time (GTD) - the order is active until a specified date or time.
         GTD # = 4,
-        # Day - the order is active until the end of the current trading session.
+        # Day - the order is active until the close of the current trading day at the venue.
         DAY # = 5,
         # At the Opening (ATO) - the order is scheduled to be executed at the market's opening.
         AT_THE_OPEN # = 6,
         # At the Closing (ATC) - the order is scheduled to be executed at the market's closing.
         AT_THE_CLOSE # = 7,
+        # Good-Till-Crossing (GTX) - the order is active until canceled, and is only posted to the
+        # book as a maker (it is rejected if it would cross the book on entry).
+        GTX # = 8,
 
     # The trading state for a node.
     cpdef enum TradingState:
@@ -78,7 +78,7 @@ cdef class LimitOrder(Order):
         The order initialization event ID.
     ts_init : uint64_t
         UNIX timestamp (nanoseconds) when the object was initialized.
-    time_in_force : TimeInForce {``GTC``, ``IOC``, ``FOK``, ``GTD``, ``DAY``, ``AT_THE_OPEN``, ``AT_THE_CLOSE``}, default ``GTC``
+    time_in_force : TimeInForce {``GTC``, ``IOC``, ``FOK``, ``GTD``, ``DAY``, ``AT_THE_OPEN``, ``AT_THE_CLOSE``, ``GTX``}, default ``GTC``
         The order time in force.
     expire_time_ns : uint64_t, default 0 (no expiry)
         UNIX timestamp (nanoseconds) when the order will expire.
@@ -177,6 +177,9 @@ cdef class LimitOrder(Order):
             "expire_time_ns": expire_time_ns,
         }
 
+        # GTX orders only post to the book as a maker
+        post_only = post_only or time_in_force == TimeInForce.GTX
+
         # Create initialization event
         cdef OrderInitialized init = OrderInitialized(
             trader_id=trader_id,
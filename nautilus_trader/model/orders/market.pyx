@@ -104,7 +104,7 @@ cdef class MarketOrder(Order):
     ValueError
         If `quantity` is not positive (> 0).
     ValueError
-        If `time_in_force` is ``GTD``.
+        If `time_in_force` is ``GTD`` or ``GTX``.
 
     References
     ----------
@@ -135,6 +135,7 @@ cdef class MarketOrder(Order):
     ):
         Condition.not_equal(order_side, OrderSide.NO_ORDER_SIDE, "order_side", "NO_ORDER_SIDE")
         Condition.not_equal(time_in_force, TimeInForce.GTD, "time_in_force", "GTD")
+        Condition.not_equal(time_in_force, TimeInForce.GTX, "time_in_force", "GTX")
 
         # Create initialization event
         cdef OrderInitialized init = OrderInitialized(
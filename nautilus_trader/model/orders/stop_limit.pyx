@@ -195,6 +195,9 @@ cdef class StopLimitOrder(Order):
             "display_qty": str(display_qty) if display_qty is not None else None,
         }
 
+        # GTX orders only post to the book as a maker
+        post_only = post_only or time_in_force == TimeInForce.GTX
+
         # Create initialization event
         cdef OrderInitialized init = OrderInitialized(
             trader_id=trader_id,
@@ -60,6 +60,16 @@ CREATE TABLE IF NOT EXISTS "instrument" (
     exchange TEXT,
     option_kind TEXT,
     strike_price TEXT,
+    exercise_style TEXT,
+    settlement_type TEXT,
+    coupon_rate TEXT,
+    coupon_frequency INTEGER,
+    day_count TEXT,
+    face_value TEXT,
+    value_date_ns TEXT,
+    far_value_date_ns TEXT,
+    fixing_ns TEXT,
+    points_precision INTEGER,
     activation_ns TEXT,
     expiration_ns TEXT,
     price_precision INTEGER NOT NULL ,
@@ -126,6 +136,8 @@ CREATE TABLE IF NOT EXISTS "order" (
     exec_algorithm_params JSONB,
     exec_spawn_id TEXT,
     tags TEXT[],
+    metadata JSONB,
+    amendments JSONB,
     init_id TEXT NOT NULL,
     ts_init TEXT NOT NULL,
     ts_last TEXT NOT NULL,
@@ -176,6 +188,7 @@ CREATE TABLE IF NOT EXISTS "order_event" (
     position_id TEXT,
     commission TEXT,
     tags TEXT[],
+    metadata JSONB,
     ts_event TEXT NOT NULL,
     ts_init TEXT NOT NULL,
     created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP,
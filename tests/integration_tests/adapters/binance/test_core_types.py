@@ -19,6 +19,8 @@ from decimal import Decimal
 from nautilus_trader.adapters.binance.common.types import BinanceBar
 from nautilus_trader.adapters.binance.common.types import BinanceTicker
 from nautilus_trader.adapters.binance.futures.types import BinanceFuturesMarkPriceUpdate
+from nautilus_trader.adapters.binance.spot.types import BinanceMarginInterest
+from nautilus_trader.model.currencies import USDT
 from nautilus_trader.model.data import BarType
 from nautilus_trader.model.objects import Price
 from nautilus_trader.model.objects import Quantity
@@ -327,3 +329,34 @@ def test_binance_mark_price_pickling():
         "ts_event": 1650000000000000001,
         "ts_init": 1650000000000000000,
     }
+
+
+def test_binance_margin_interest_to_from_dict():
+    # Arrange
+    interest = BinanceMarginInterest(
+        currency=USDT,
+        principal=Decimal("100.00000000"),
+        interest=Decimal("0.00013233"),
+        interest_rate=Decimal("0.00000132"),
+        interest_type="PERIODIC",
+        isolated_symbol="BTCUSDT",
+        ts_event=1650000000000000001,
+        ts_init=1650000000000000000,
+    )
+
+    # Act
+    values = interest.to_dict(interest)
+
+    # Assert
+    assert BinanceMarginInterest.from_dict(values).to_dict(interest) == values
+    assert values == {
+        "type": "BinanceMarginInterest",
+        "currency": "USDT",
+        "principal": "100.00000000",
+        "interest": "0.00013233",
+        "interest_rate": "0.00000132",
+        "interest_type": "PERIODIC",
+        "isolated_symbol": "BTCUSDT",
+        "ts_event": 1650000000000000001,
+        "ts_init": 1650000000000000000,
+    }
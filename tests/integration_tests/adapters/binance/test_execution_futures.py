@@ -20,6 +20,7 @@ import pytest
 
 from nautilus_trader.adapters.binance.common.constants import BINANCE_VENUE
 from nautilus_trader.adapters.binance.common.enums import BinanceAccountType
+from nautilus_trader.adapters.binance.common.schemas.user import BinanceListenKey
 from nautilus_trader.adapters.binance.config import BinanceExecClientConfig
 from nautilus_trader.adapters.binance.futures.execution import BinanceFuturesExecutionClient
 from nautilus_trader.adapters.binance.futures.providers import BinanceFuturesInstrumentProvider
@@ -656,3 +657,133 @@ class TestBinanceFuturesExecutionClient:
         assert request[1]["payload"]["recvWindow"] == "5000"
         assert request[1]["payload"]["signature"] is not None
         assert request[1]["payload"]["positionSide"] == expected
+
+    @pytest.mark.asyncio()
+    async def test_trailing_stop_market_order_with_price_offset(self, mocker):
+        # Arrange
+        mock_send_request = mocker.patch(
+            target="nautilus_trader.adapters.binance.http.client.BinanceHttpClient.send_request",
+        )
+
+        order = self.strategy.order_factory.trailing_stop_market(
+            instrument_id=ETHUSDT_PERP_BINANCE.id,
+            order_side=OrderSide.SELL,
+            quantity=Quantity.from_int(10),
+            trailing_offset=Decimal("250.00"),
+            trailing_offset_type=TrailingOffsetType.PRICE,
+            trigger_price=Price.from_str("10000.00"),
+        )
+        self.cache.add_order(order, None)
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=self.strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=0,
+        )
+
+        # Act
+        self.exec_client.submit_order(submit_order)
+        await eventually(lambda: mock_send_request.call_args)
+
+        # Assert
+        request = mock_send_request.call_args
+        assert request[1]["payload"]["type"] == "TRAILING_STOP_MARKET"
+        assert request[1]["payload"]["activationPrice"] == "10000.00"
+        assert request[1]["payload"]["callbackRate"] == "2.5"
+        assert request[1]["payload"]["workingType"] == "CONTRACT_PRICE"
+
+    @pytest.mark.asyncio()
+    async def test_trailing_stop_market_order_with_invalid_callback_rate_rejects(self, mocker):
+        # Arrange
+        mock_send_request = mocker.patch(
+            target="nautilus_trader.adapters.binance.http.client.BinanceHttpClient.send_request",
+        )
+        mock_rejected = mocker.patch.object(self.exec_client, "generate_order_rejected")
+
+        order = self.strategy.order_factory.trailing_stop_market(
+            instrument_id=ETHUSDT_PERP_BINANCE.id,
+            order_side=OrderSide.SELL,
+            quantity=Quantity.from_int(10),
+            trailing_offset=Decimal(2_000),  # 20% exceeds the Binance maximum
+            trailing_offset_type=TrailingOffsetType.BASIS_POINTS,
+            trigger_price=Price.from_str("10000.00"),
+        )
+        self.cache.add_order(order, None)
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=self.strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=0,
+        )
+
+        # Act
+        self.exec_client.submit_order(submit_order)
+        await eventually(lambda: mock_rejected.call_args)
+
+        # Assert
+        assert mock_send_request.call_args is None
+        assert mock_rejected.call_args[1]["client_order_id"] == order.client_order_id
+
+    @pytest.mark.asyncio()
+    async def test_refresh_listen_key_resubscribes_new_listen_key(self, mocker):
+        # Arrange
+        mocker.patch.object(
+            self.exec_client._http_user,
+            "create_listen_key",
+            return_value=BinanceListenKey(listenKey="NEW_LISTEN_KEY"),
+        )
+        mock_subscribe = mocker.patch.object(self.exec_client._ws_client, "subscribe_listen_key")
+        mock_unsubscribe = mocker.patch.object(
+            self.exec_client._ws_client,
+            "unsubscribe_listen_key",
+        )
+        self.exec_client._listen_key = "OLD_LISTEN_KEY"
+
+        # Act
+        await self.exec_client._refresh_listen_key(new_listen_key=True)
+
+        # Assert
+        assert self.exec_client._listen_key == "NEW_LISTEN_KEY"
+        mock_unsubscribe.assert_called_once_with("OLD_LISTEN_KEY")
+        mock_subscribe.assert_called_once_with("NEW_LISTEN_KEY")
+
+    @pytest.mark.asyncio()
+    async def test_refresh_listen_key_when_still_valid_keeps_subscription(self, mocker):
+        # Arrange
+        mock_keepalive = mocker.patch.object(self.exec_client._http_user, "keepalive_listen_key")
+        mock_create = mocker.patch.object(self.exec_client._http_user, "create_listen_key")
+        mock_subscribe = mocker.patch.object(self.exec_client._ws_client, "subscribe_listen_key")
+        self.exec_client._listen_key = "LISTEN_KEY"
+
+        # Act
+        await self.exec_client._refresh_listen_key(new_listen_key=False)
+
+        # Assert
+        mock_keepalive.assert_called_once_with(listen_key="LISTEN_KEY")
+        mock_create.assert_not_called()
+        mock_subscribe.assert_not_called()
+
+    @pytest.mark.asyncio()
+    async def test_reconcile_user_stream_state_sends_open_order_reports(self, mocker):
+        # Arrange
+        mock_update_account = mocker.patch.object(self.exec_client, "_update_account_state")
+        report = mocker.MagicMock()
+        mocker.patch.object(
+            self.exec_client,
+            "generate_order_status_reports",
+            return_value=[report],
+        )
+        mock_send_report = mocker.patch.object(self.exec_client, "_send_order_status_report")
+
+        # Act
+        await self.exec_client._reconcile_user_stream_state()
+
+        # Assert
+        mock_update_account.assert_called_once()
+        mock_send_report.assert_called_once_with(report)
@@ -15,6 +15,7 @@
 
 import asyncio
 import pkgutil
+from decimal import Decimal
 
 import aiohttp
 import msgspec
@@ -24,7 +25,10 @@ from nautilus_trader.adapters.binance.common.constants import BINANCE_VENUE
 from nautilus_trader.adapters.binance.common.enums import BinanceAccountType
 from nautilus_trader.adapters.binance.config import BinanceExecClientConfig
 from nautilus_trader.adapters.binance.http.client import BinanceHttpClient
+from nautilus_trader.adapters.binance.spot.enums import BinanceMarginLoanType
 from nautilus_trader.adapters.binance.spot.execution import BinanceSpotExecutionClient
+from nautilus_trader.adapters.binance.spot.messages import BINANCE_MARGIN_BORROW_REPAY_ENDPOINT
+from nautilus_trader.adapters.binance.spot.messages import BinanceMarginBorrowRepay
 from nautilus_trader.adapters.binance.spot.providers import BinanceSpotInstrumentProvider
 from nautilus_trader.common.component import LiveClock
 from nautilus_trader.common.component import MessageBus
@@ -34,6 +38,7 @@ from nautilus_trader.core.uuid import UUID4
 from nautilus_trader.data.engine import DataEngine
 from nautilus_trader.execution.engine import ExecutionEngine
 from nautilus_trader.execution.messages import SubmitOrder
+from nautilus_trader.model.currencies import USDT
 from nautilus_trader.model.enums import OrderSide
 from nautilus_trader.model.identifiers import AccountId
 from nautilus_trader.model.objects import Price
@@ -388,6 +393,90 @@ class TestBinanceSpotExecutionClient:
         assert request[1]["payload"]["recvWindow"] == "5000"
         assert request[1]["payload"]["signature"] is not None
 
+    @pytest.mark.asyncio()
+    async def test_submit_stop_market_order(self, mocker):
+        # Arrange
+        mock_send_request = mocker.patch(
+            target="nautilus_trader.adapters.binance.http.client.BinanceHttpClient.send_request",
+        )
+
+        order = self.strategy.order_factory.stop_market(
+            instrument_id=ETHUSDT_BINANCE.id,
+            order_side=OrderSide.SELL,
+            quantity=Quantity.from_int(10),
+            trigger_price=Price.from_str("10000.00"),
+        )
+        self.cache.add_order(order, None)
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=self.strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=0,
+        )
+
+        # Act
+        self.exec_client.submit_order(submit_order)
+        await eventually(lambda: mock_send_request.call_args)
+
+        # Assert
+        request = mock_send_request.call_args
+        assert request[0][0] == HttpMethod.POST
+        assert request[0][1] == "/api/v3/order"
+        assert request[1]["payload"]["symbol"] == "ETHUSDT"
+        assert request[1]["payload"]["side"] == "SELL"
+        assert request[1]["payload"]["type"] == "STOP_LOSS"
+        assert "timeInForce" not in request[1]["payload"]
+        assert request[1]["payload"]["quantity"] == "10"
+        assert request[1]["payload"]["stopPrice"] == "10000.00"
+        assert request[1]["payload"]["newClientOrderId"] is not None
+        assert request[1]["payload"]["recvWindow"] == "5000"
+        assert request[1]["payload"]["signature"] is not None
+
+    @pytest.mark.asyncio()
+    async def test_submit_market_if_touched_order(self, mocker):
+        # Arrange
+        mock_send_request = mocker.patch(
+            target="nautilus_trader.adapters.binance.http.client.BinanceHttpClient.send_request",
+        )
+
+        order = self.strategy.order_factory.market_if_touched(
+            instrument_id=ETHUSDT_BINANCE.id,
+            order_side=OrderSide.SELL,
+            quantity=Quantity.from_int(10),
+            trigger_price=Price.from_str("10100.00"),
+        )
+        self.cache.add_order(order, None)
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=self.strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=0,
+        )
+
+        # Act
+        self.exec_client.submit_order(submit_order)
+        await eventually(lambda: mock_send_request.call_args)
+
+        # Assert
+        request = mock_send_request.call_args
+        assert request[0][0] == HttpMethod.POST
+        assert request[0][1] == "/api/v3/order"
+        assert request[1]["payload"]["symbol"] == "ETHUSDT"
+        assert request[1]["payload"]["side"] == "SELL"
+        assert request[1]["payload"]["type"] == "TAKE_PROFIT"
+        assert "timeInForce" not in request[1]["payload"]
+        assert request[1]["payload"]["quantity"] == "10"
+        assert request[1]["payload"]["stopPrice"] == "10100.00"
+        assert request[1]["payload"]["newClientOrderId"] is not None
+        assert request[1]["payload"]["recvWindow"] == "5000"
+        assert request[1]["payload"]["signature"] is not None
+
     @pytest.mark.asyncio()
     async def test_query_order(self, mocker):
         # Arrange
@@ -407,3 +496,86 @@ class TestBinanceSpotExecutionClient:
 
         # Assert
         await eventually(lambda: mock_query_order.called)
+
+    def _create_isolated_margin_exec_client(self) -> BinanceSpotExecutionClient:
+        return BinanceSpotExecutionClient(
+            loop=self.loop,
+            client=self.http_client,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            instrument_provider=self.provider,
+            base_url_ws="",  # Not required for testing
+            config=BinanceExecClientConfig(
+                account_type=BinanceAccountType.ISOLATED_MARGIN,
+                isolated_margin_symbol="ETHUSDT",
+            ),
+            account_type=BinanceAccountType.ISOLATED_MARGIN,
+            name="BINANCE-MARGIN",
+        )
+
+    @pytest.mark.asyncio()
+    async def test_submit_limit_order_isolated_margin(self, mocker):
+        # Arrange
+        mock_send_request = mocker.patch(
+            target="nautilus_trader.adapters.binance.http.client.BinanceHttpClient.send_request",
+        )
+        exec_client = self._create_isolated_margin_exec_client()
+
+        order = self.strategy.order_factory.limit(
+            instrument_id=ETHUSDT_BINANCE.id,
+            order_side=OrderSide.BUY,
+            quantity=Quantity.from_int(10),
+            price=Price.from_str("10050.80"),
+        )
+        self.cache.add_order(order, None)
+
+        submit_order = SubmitOrder(
+            trader_id=self.trader_id,
+            strategy_id=self.strategy.id,
+            position_id=None,
+            order=order,
+            command_id=UUID4(),
+            ts_init=0,
+        )
+
+        # Act
+        exec_client.submit_order(submit_order)
+        await eventually(lambda: mock_send_request.call_args)
+
+        # Assert
+        request = mock_send_request.call_args
+        assert request[0][0] == HttpMethod.POST
+        assert request[0][1] == "/sapi/v1/margin/order"
+        assert request[1]["payload"]["symbol"] == "ETHUSDT"
+        assert request[1]["payload"]["type"] == "LIMIT"
+        assert request[1]["payload"]["isIsolated"] == "TRUE"
+
+    @pytest.mark.asyncio()
+    async def test_borrow_repay_isolated_margin(self, mocker):
+        # Arrange
+        mock_send_request = mocker.patch(
+            target="nautilus_trader.adapters.binance.http.client.BinanceHttpClient.send_request",
+        )
+        self._create_isolated_margin_exec_client()
+
+        command = BinanceMarginBorrowRepay(
+            loan_type=BinanceMarginLoanType.BORROW,
+            currency=USDT,
+            amount=Decimal("100.5"),
+        )
+
+        # Act
+        self.msgbus.send(endpoint=BINANCE_MARGIN_BORROW_REPAY_ENDPOINT, msg=command)
+        await eventually(lambda: mock_send_request.call_args)
+
+        # Assert
+        request = mock_send_request.call_args
+        assert request[0][0] == HttpMethod.POST
+        assert request[0][1] == "/sapi/v1/margin/borrow-repay"
+        assert request[1]["payload"]["asset"] == "USDT"
+        assert request[1]["payload"]["amount"] == "100.5"
+        assert request[1]["payload"]["type"] == "BORROW"
+        assert request[1]["payload"]["isIsolated"] == "TRUE"
+        assert request[1]["payload"]["symbol"] == "ETHUSDT"
+        assert request[1]["payload"]["signature"] is not None
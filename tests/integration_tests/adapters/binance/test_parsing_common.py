@@ -18,6 +18,7 @@ import pytest
 from nautilus_trader.adapters.binance.common.enums import BinanceFuturesPositionSide
 from nautilus_trader.adapters.binance.common.enums import BinanceKlineInterval
 from nautilus_trader.adapters.binance.common.enums import BinanceOrderType
+from nautilus_trader.adapters.binance.common.enums import BinanceTimeInForce
 from nautilus_trader.adapters.binance.common.schemas.market import BinanceCandlestick
 from nautilus_trader.adapters.binance.futures.enums import BinanceFuturesEnumParser
 from nautilus_trader.adapters.binance.futures.schemas.account import BinanceFuturesBalanceInfo
@@ -29,6 +30,7 @@ from nautilus_trader.model.enums import AggregationSource
 from nautilus_trader.model.enums import BarAggregation
 from nautilus_trader.model.enums import OrderType
 from nautilus_trader.model.enums import PriceType
+from nautilus_trader.model.enums import TimeInForce
 from nautilus_trader.model.identifiers import PositionId
 from nautilus_trader.test_kit.providers import TestInstrumentProvider
 
@@ -61,6 +63,23 @@ class TestBinanceCommonParsing:
         # Assert
         assert result == expected
 
+    @pytest.mark.parametrize(
+        ("binance_time_in_force", "expected"),
+        [
+            [BinanceTimeInForce.GTC, TimeInForce.GTC],
+            [BinanceTimeInForce.IOC, TimeInForce.IOC],
+            [BinanceTimeInForce.FOK, TimeInForce.FOK],
+            [BinanceTimeInForce.GTX, TimeInForce.GTX],
+        ],
+    )
+    def test_parse_time_in_force_round_trip(self, binance_time_in_force, expected):
+        # Arrange, Act
+        result = self._futures_enum_parser.parse_binance_time_in_force(binance_time_in_force)
+
+        # Assert
+        assert result == expected
+        assert self._futures_enum_parser.parse_internal_time_in_force(result) == binance_time_in_force
+
     @pytest.mark.parametrize(
         ("resolution", "expected_type"),
         [
@@ -14,10 +14,17 @@
 # -------------------------------------------------------------------------------------------------
 
 import pkgutil
+from decimal import Decimal
 
 import msgspec
 
 from nautilus_trader.adapters.binance.common.schemas.market import BinanceDepth
+from nautilus_trader.adapters.binance.spot.schemas.margin import BinanceIsolatedMarginAccountInfo
+from nautilus_trader.adapters.binance.spot.schemas.margin import BinanceMarginAccountInfo
+from nautilus_trader.adapters.binance.spot.schemas.margin import BinanceMarginInterestHistory
+from nautilus_trader.model.currencies import BTC
+from nautilus_trader.model.currencies import USDT
+from nautilus_trader.model.objects import Money
 from nautilus_trader.model.objects import Price
 from nautilus_trader.model.objects import Quantity
 from nautilus_trader.test_kit.providers import TestInstrumentProvider
@@ -53,3 +60,78 @@ class TestBinanceHttpParsing:
         assert result.deltas[11].order.size == Quantity.from_str("0.61982")  # <-- Top ask
         assert result.sequence == 14527958487
         assert result.ts_init == 2
+
+    def test_parse_margin_account(self):
+        # Arrange
+        raw = pkgutil.get_data(
+            package="tests.integration_tests.adapters.binance.resources.http_responses",
+            resource="http_margin_account.json",
+        )
+        assert raw
+        decoder = msgspec.json.Decoder(BinanceMarginAccountInfo)
+
+        # Act
+        data = decoder.decode(raw)
+        balances = data.parse_to_account_balances()
+        info = data.parse_to_info()
+
+        # Assert
+        assert len(balances) == 4
+        assert balances[0].free == Money(0.00499500, BTC)
+        assert info == {
+            "margin_level": "11.64405625",
+            "total_liability_of_btc": "0.58633215",
+            "borrowed": {"BNB": "201.66666672"},
+            "interest": {"USDT": "0.01250000"},
+        }
+
+    def test_parse_isolated_margin_account(self):
+        # Arrange
+        raw = pkgutil.get_data(
+            package="tests.integration_tests.adapters.binance.resources.http_responses",
+            resource="http_margin_isolated_account.json",
+        )
+        assert raw
+        decoder = msgspec.json.Decoder(BinanceIsolatedMarginAccountInfo)
+
+        # Act
+        data = decoder.decode(raw)
+        balances = {balance.currency.code: balance for balance in data.parse_to_account_balances()}
+        info = data.parse_to_info()
+
+        # Assert
+        assert list(balances) == ["BTC", "USDT", "ETH"]
+        assert balances["USDT"].free == Money(350, USDT)  # <-- Aggregated across symbols
+        assert balances["USDT"].locked == Money(50, USDT)
+        assert balances["USDT"].total == Money(400, USDT)
+        assert info == {
+            "margin_level": "2.10000000",  # <-- Lowest of the symbols
+            "margin_levels": {"BTCUSDT": "15.20000000", "ETHUSDT": "2.10000000"},
+            "total_liability_of_btc": "0.00266667",
+            "borrowed": {"BTC": "0.00100000", "USDT": "100.00000000"},
+            "interest": {"BTC": "0.00000042", "USDT": "0.00500000"},
+        }
+
+    def test_parse_margin_interest_history(self):
+        # Arrange
+        raw = pkgutil.get_data(
+            package="tests.integration_tests.adapters.binance.resources.http_responses",
+            resource="http_margin_interest_history.json",
+        )
+        assert raw
+        decoder = msgspec.json.Decoder(BinanceMarginInterestHistory)
+
+        # Act
+        data = decoder.decode(raw)
+        result = data.rows[0].parse_to_margin_interest(ts_init=2)
+
+        # Assert
+        assert data.total == 2
+        assert result.currency == USDT
+        assert result.principal == Decimal("100.00000000")
+        assert result.interest == Decimal("0.00013233")
+        assert result.interest_rate == Decimal("0.00000132")
+        assert result.interest_type == "PERIODIC"
+        assert result.isolated_symbol == "BTCUSDT"
+        assert result.ts_event == 1672164000000000000
+        assert result.ts_init == 2
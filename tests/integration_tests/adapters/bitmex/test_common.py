@@ -0,0 +1,262 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from decimal import Decimal
+
+import pytest
+
+from nautilus_trader.adapters.bitmex.common.enums import BitmexEnumParser
+from nautilus_trader.adapters.bitmex.common.enums import BitmexExecInstruction
+from nautilus_trader.adapters.bitmex.common.enums import BitmexOrderSide
+from nautilus_trader.adapters.bitmex.common.enums import BitmexOrderType
+from nautilus_trader.adapters.bitmex.common.enums import BitmexTimeInForce
+from nautilus_trader.adapters.bitmex.common.enums import parse_exec_instructions
+from nautilus_trader.adapters.bitmex.common.parsing import normalize_bitmex_currency
+from nautilus_trader.adapters.bitmex.common.parsing import parse_bitmex_amount
+from nautilus_trader.adapters.bitmex.common.symbol import BitmexSymbol
+from nautilus_trader.adapters.bitmex.http.client import BitmexHttpClient
+from nautilus_trader.adapters.bitmex.http.errors import BitmexError
+from nautilus_trader.adapters.bitmex.http.errors import parse_error_message
+from nautilus_trader.adapters.bitmex.http.errors import should_retry
+from nautilus_trader.adapters.bitmex.http.trade import order_params
+from nautilus_trader.common.component import TestClock
+from nautilus_trader.core.datetime import secs_to_nanos
+from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import TimeInForce
+from nautilus_trader.model.enums import TriggerType
+from nautilus_trader.model.identifiers import InstrumentId
+
+
+# API credentials from the BitMEX authentication documentation (not a real account)
+TEST_API_KEY = "LAqUlngMIQkIUjXMUreyu3qn"
+TEST_API_SECRET = "chNOOS4KvNXR_Xq4k4c9qsfoKWvnDecLATCRlcBwyKDYnWgO"
+
+
+@pytest.mark.parametrize(
+    ("symbol", "expected"),
+    [
+        ["XBTUSD", "XBTUSD.BITMEX"],
+        ["xbtusdt", "XBTUSDT.BITMEX"],
+        ["XBTZ25", "XBTZ25.BITMEX"],
+    ],
+)
+def test_bitmex_symbol_to_instrument_id(symbol: str, expected: str) -> None:
+    # Arrange, Act
+    bitmex_symbol = BitmexSymbol(symbol)
+
+    # Assert
+    assert bitmex_symbol.to_instrument_id() == InstrumentId.from_str(expected)
+
+
+@pytest.mark.parametrize(
+    ("code", "expected"),
+    [
+        ["XBt", "BTC"],
+        ["XBT", "BTC"],
+        ["USDt", "USDT"],
+        ["ETH", "ETH"],
+    ],
+)
+def test_normalize_bitmex_currency(code: str, expected: str) -> None:
+    # Arrange, Act, Assert
+    assert normalize_bitmex_currency(code) == expected
+
+
+@pytest.mark.parametrize(
+    ("value", "code", "expected"),
+    [
+        [150_000_000, "XBt", Decimal("1.5")],
+        [2_500_000, "USDt", Decimal("2.5")],
+        [-1_000, "XBt", Decimal("-0.00001")],
+    ],
+)
+def test_parse_bitmex_amount(value: int, code: str, expected: Decimal) -> None:
+    # Arrange, Act, Assert
+    assert parse_bitmex_amount(value, code) == expected
+
+
+def test_parse_exec_instructions() -> None:
+    # Arrange, Act
+    result = parse_exec_instructions("ParticipateDoNotInitiate,LastPrice,Unknown")
+
+    # Assert
+    assert result == {
+        BitmexExecInstruction.PARTICIPATE_DO_NOT_INITIATE,
+        BitmexExecInstruction.LAST_PRICE,
+    }
+
+
+def test_enum_parser_round_trips_order_types() -> None:
+    # Arrange
+    parser = BitmexEnumParser()
+
+    # Act, Assert
+    for order_type, bitmex_order_type in parser.nautilus_to_bitmex_order_type.items():
+        assert parser.parse_nautilus_order_type(order_type) == bitmex_order_type
+        assert parser.parse_bitmex_order_type(bitmex_order_type) == order_type
+
+
+@pytest.mark.parametrize(
+    ("exec_instructions", "expected"),
+    [
+        [set(), TriggerType.MARK_PRICE],
+        [{BitmexExecInstruction.LAST_PRICE}, TriggerType.LAST_PRICE],
+        [
+            {BitmexExecInstruction.REDUCE_ONLY, BitmexExecInstruction.INDEX_PRICE},
+            TriggerType.INDEX_PRICE,
+        ],
+    ],
+)
+def test_enum_parser_trigger_type(
+    exec_instructions: set[BitmexExecInstruction],
+    expected: TriggerType,
+) -> None:
+    # Arrange
+    parser = BitmexEnumParser()
+
+    # Act, Assert
+    assert parser.parse_bitmex_trigger_type(exec_instructions) == expected
+
+
+def test_enum_parser_default_trigger_type_is_mark_price() -> None:
+    # Arrange
+    parser = BitmexEnumParser()
+
+    # Act
+    result = parser.parse_nautilus_trigger_type(TriggerType.DEFAULT)
+
+    # Assert
+    assert result == BitmexExecInstruction.MARK_PRICE
+    with pytest.raises(RuntimeError):
+        parser.parse_nautilus_trigger_type(TriggerType.BID_ASK)
+
+
+def test_order_params() -> None:
+    # Arrange, Act
+    params = order_params(
+        symbol="XBTUSD",
+        side=BitmexOrderSide.BUY,
+        order_type=BitmexOrderType.STOP_LIMIT,
+        quantity="100",
+        client_order_id="O-123",
+        time_in_force=BitmexTimeInForce.GTC,
+        price="50000.0",
+        trigger_price="49000.0",
+        exec_instructions=[BitmexExecInstruction.REDUCE_ONLY, BitmexExecInstruction.LAST_PRICE],
+    )
+
+    # Assert
+    assert params == {
+        "symbol": "XBTUSD",
+        "side": "Buy",
+        "ordType": "StopLimit",
+        "orderQty": "100",
+        "clOrdID": "O-123",
+        "timeInForce": "GoodTillCancel",
+        "price": "50000.0",
+        "stopPx": "49000.0",
+        "execInst": "ReduceOnly,LastPrice",
+    }
+
+
+def test_enum_parser_time_in_force() -> None:
+    # Arrange
+    parser = BitmexEnumParser()
+
+    # Act, Assert
+    assert parser.parse_nautilus_time_in_force(TimeInForce.IOC) == BitmexTimeInForce.IOC
+    assert parser.parse_nautilus_order_type(OrderType.STOP_MARKET) == BitmexOrderType.STOP
+
+
+def test_sign_request_matches_documented_signature() -> None:
+    # Arrange
+    clock = TestClock()
+    clock.set_time(secs_to_nanos(1518064236 - 60))
+    client = BitmexHttpClient(
+        clock=clock,
+        base_url="https://testnet.bitmex.com",
+        api_key=TEST_API_KEY,
+        api_secret=TEST_API_SECRET,
+    )
+
+    # Act
+    headers = client.sign("GET", "/api/v1/instrument")
+
+    # Assert
+    assert headers == {
+        "api-expires": "1518064236",
+        "api-key": TEST_API_KEY,
+        "api-signature": "c7682d435d0cfe87c16098df34ef2eb5a549d4c5a3c2b1f0f77b8af73423bf00",
+    }
+
+
+def test_sign_request_with_body() -> None:
+    # Arrange
+    clock = TestClock()
+    clock.set_time(secs_to_nanos(1518064238 - 60))
+    client = BitmexHttpClient(
+        clock=clock,
+        base_url="https://testnet.bitmex.com",
+        api_key=TEST_API_KEY,
+        api_secret=TEST_API_SECRET,
+    )
+    body = (
+        b'{"symbol":"XBTM15","price":219.0,"clOrdID":"mm_bitmex_1a/oemUeQ4CAJZgP3fjHsA",'
+        b'"orderQty":98}'
+    )
+
+    # Act
+    headers = client.sign("POST", "/api/v1/order", body)
+
+    # Assert
+    assert headers["api-signature"] == (
+        "1749cd2ccae4aa49048ae09f0b95110cee706e0944e6a14ad0b3a8cb45bd336b"
+    )
+
+
+def test_sign_request_without_credentials_raises() -> None:
+    # Arrange
+    client = BitmexHttpClient(clock=TestClock(), base_url="https://www.bitmex.com")
+
+    # Act, Assert
+    assert not client.has_credentials
+    with pytest.raises(RuntimeError):
+        client.sign("GET", "/api/v1/order")
+
+
+def test_parse_error_message() -> None:
+    # Arrange
+    body = {"error": {"message": "Invalid orderQty", "name": "ValidationError"}}
+
+    # Act, Assert
+    assert parse_error_message(body) == "Invalid orderQty"
+    assert parse_error_message("Bad Gateway") == "Bad Gateway"
+
+
+@pytest.mark.parametrize(
+    ("code", "expected"),
+    [
+        [429, True],
+        [503, True],
+        [400, False],
+        [None, False],
+    ],
+)
+def test_should_retry(code: int | None, expected: bool) -> None:
+    # Arrange
+    error = BitmexError(code=code, message="error")
+
+    # Act, Assert
+    assert should_retry(error) == expected
@@ -0,0 +1,433 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from decimal import Decimal
+
+import msgspec
+import pytest
+
+from nautilus_trader.adapters.bitmex.common.enums import BitmexEnumParser
+from nautilus_trader.adapters.bitmex.schemas.instrument import BitmexInstrument
+from nautilus_trader.adapters.bitmex.schemas.instrument import BitmexInstrumentUpdate
+from nautilus_trader.adapters.bitmex.schemas.order import BitmexExecution
+from nautilus_trader.adapters.bitmex.schemas.order import BitmexOrder
+from nautilus_trader.adapters.bitmex.schemas.user import BitmexMargin
+from nautilus_trader.adapters.bitmex.schemas.user import BitmexPosition
+from nautilus_trader.adapters.bitmex.schemas.ws import BitmexWsBookMsg
+from nautilus_trader.adapters.bitmex.schemas.ws import BitmexWsQuoteMsg
+from nautilus_trader.adapters.bitmex.schemas.ws import BitmexWsTradeMsg
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.model.enums import AggressorSide
+from nautilus_trader.model.enums import BookAction
+from nautilus_trader.model.enums import LiquiditySide
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import PositionSide
+from nautilus_trader.model.enums import RecordFlag
+from nautilus_trader.model.enums import TriggerType
+from nautilus_trader.model.identifiers import AccountId
+from nautilus_trader.model.identifiers import ClientOrderId
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.instruments import CryptoFuture
+from nautilus_trader.model.instruments import CryptoPerpetual
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import Money
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+
+
+XBTUSD = InstrumentId.from_str("XBTUSD.BITMEX")
+ACCOUNT_ID = AccountId("BITMEX-master")
+BTC = Currency.from_str("BTC")
+USD = Currency.from_str("USD")
+USDT = Currency.from_str("USDT")
+
+XBTUSD_RAW = (
+    b'{"symbol":"XBTUSD","rootSymbol":"XBT","state":"Open","typ":"FFWCSX",'
+    b'"listing":"2016-05-13T12:00:00.000Z","underlying":"XBT","quoteCurrency":"USD",'
+    b'"settlCurrency":"XBt","positionCurrency":"USD","isQuanto":false,"isInverse":true,'
+    b'"tickSize":0.5,"lotSize":100,"multiplier":-100000000,"initMargin":0.01,'
+    b'"maintMargin":0.0035,"makerFee":-0.0001,"takerFee":0.00075,"maxOrderQty":10000000,'
+    b'"maxPrice":1000000,"fundingRate":0.0001,"fundingTimestamp":"2025-01-01T04:00:00.000Z",'
+    b'"timestamp":"2025-01-01T00:00:00.000Z"}'
+)
+
+
+def _xbtusd() -> CryptoPerpetual:
+    bitmex_instrument = msgspec.json.decode(XBTUSD_RAW, type=BitmexInstrument)
+    instrument = bitmex_instrument.parse_to_instrument(
+        base_currency=BTC,
+        quote_currency=USD,
+        settlement_currency=BTC,
+        maker_fee=Decimal("-0.0001"),
+        taker_fee=Decimal("0.00075"),
+        ts_event=0,
+        ts_init=0,
+    )
+    assert isinstance(instrument, CryptoPerpetual)
+    return instrument
+
+
+def test_parse_inverse_perpetual_to_instrument() -> None:
+    # Arrange, Act
+    instrument = _xbtusd()
+
+    # Assert
+    assert instrument.id == XBTUSD
+    assert instrument.raw_symbol.value == "XBTUSD"
+    assert instrument.is_inverse
+    assert instrument.base_currency == BTC
+    assert instrument.quote_currency == USD
+    assert instrument.settlement_currency == BTC
+    assert instrument.price_increment == Price.from_str("0.5")
+    assert instrument.size_increment == Quantity.from_int(100)
+    assert instrument.min_quantity == Quantity.from_int(100)
+    assert instrument.multiplier == Quantity.from_int(1)
+    assert instrument.margin_init == Decimal("0.01")
+    assert instrument.margin_maint == Decimal("0.0035")
+    assert instrument.maker_fee == Decimal("-0.0001")
+    assert instrument.info["symbol"] == "XBTUSD"
+
+
+def test_parse_linear_perpetual_to_instrument() -> None:
+    # Arrange
+    raw = (
+        b'{"symbol":"XBTUSDT","state":"Open","typ":"FFWCSX","underlying":"XBT",'
+        b'"quoteCurrency":"USDT","settlCurrency":"USDt","isQuanto":false,"isInverse":false,'
+        b'"tickSize":0.5,"lotSize":1000,"underlyingToPositionMultiplier":1000000,'
+        b'"initMargin":0.01,"maintMargin":0.005,"timestamp":"2025-01-01T00:00:00.000Z"}'
+    )
+    bitmex_instrument = msgspec.json.decode(raw, type=BitmexInstrument)
+
+    # Act
+    instrument = bitmex_instrument.parse_to_instrument(
+        base_currency=BTC,
+        quote_currency=USDT,
+        settlement_currency=USDT,
+        maker_fee=Decimal("-0.0001"),
+        taker_fee=Decimal("0.00075"),
+        ts_event=0,
+        ts_init=0,
+    )
+
+    # Assert
+    assert isinstance(instrument, CryptoPerpetual)
+    assert not instrument.is_inverse
+    assert instrument.size_increment == Quantity.from_int(1000)
+    assert instrument.multiplier == Quantity.from_str("0.000001")
+    assert instrument.settlement_currency == USDT
+
+
+def test_parse_future_to_instrument() -> None:
+    # Arrange
+    raw = (
+        b'{"symbol":"XBTZ25","state":"Open","typ":"FFCCSX","underlying":"XBT",'
+        b'"quoteCurrency":"USD","settlCurrency":"XBt","isQuanto":false,"isInverse":true,'
+        b'"tickSize":0.5,"lotSize":100,"listing":"2025-06-27T12:00:00.000Z",'
+        b'"expiry":"2025-12-26T12:00:00.000Z","timestamp":"2025-07-01T00:00:00.000Z"}'
+    )
+    bitmex_instrument = msgspec.json.decode(raw, type=BitmexInstrument)
+
+    # Act
+    instrument = bitmex_instrument.parse_to_instrument(
+        base_currency=BTC,
+        quote_currency=USD,
+        settlement_currency=BTC,
+        maker_fee=Decimal("-0.0001"),
+        taker_fee=Decimal("0.00075"),
+        ts_event=0,
+        ts_init=0,
+    )
+
+    # Assert
+    assert isinstance(instrument, CryptoFuture)
+    assert instrument.id == InstrumentId.from_str("XBTZ25.BITMEX")
+    assert instrument.activation_ns == 1_751_025_600_000_000_000
+    assert instrument.expiration_ns == 1_766_750_400_000_000_000
+
+
+def test_parse_quanto_instrument_raises() -> None:
+    # Arrange
+    raw = (
+        b'{"symbol":"ETHUSD","state":"Open","typ":"FFWCSX","underlying":"ETH",'
+        b'"quoteCurrency":"USD","settlCurrency":"XBt","isQuanto":true,"isInverse":false,'
+        b'"tickSize":0.05,"lotSize":1,"timestamp":"2025-01-01T00:00:00.000Z"}'
+    )
+    bitmex_instrument = msgspec.json.decode(raw, type=BitmexInstrument)
+
+    # Act, Assert
+    with pytest.raises(ValueError, match="quanto"):
+        bitmex_instrument.parse_to_instrument(
+            base_currency=Currency.from_str("ETH"),
+            quote_currency=USD,
+            settlement_currency=BTC,
+            maker_fee=Decimal(0),
+            taker_fee=Decimal(0),
+            ts_event=0,
+            ts_init=0,
+        )
+
+
+def test_parse_instrument_update_merges_funding_rate() -> None:
+    # Arrange
+    first = msgspec.json.decode(
+        b'{"symbol":"XBTUSD","fundingRate":0.0001,'
+        b'"fundingTimestamp":"2025-01-01T04:00:00.000Z","timestamp":"2025-01-01T00:00:00.000Z"}',
+        type=BitmexInstrumentUpdate,
+    )
+    second = msgspec.json.decode(
+        b'{"symbol":"XBTUSD","fundingRate":-0.0002,"timestamp":"2025-01-01T00:00:05.000Z"}',
+        type=BitmexInstrumentUpdate,
+    )
+    mark = msgspec.json.decode(
+        b'{"symbol":"XBTUSD","markPrice":95000.5,"timestamp":"2025-01-01T00:00:06.000Z"}',
+        type=BitmexInstrumentUpdate,
+    )
+
+    # Act
+    last = first.parse_to_funding_rate_update(XBTUSD, last=None, ts_init=1)
+    update = second.parse_to_funding_rate_update(XBTUSD, last=last, ts_init=2)
+    unchanged = mark.parse_to_funding_rate_update(XBTUSD, last=update, ts_init=3)
+
+    # Assert
+    assert last is not None
+    assert update is not None
+    assert update.funding_rate == Decimal("-0.0002")
+    assert update.ts_next_funding == last.ts_next_funding == 1_735_704_000_000_000_000
+    assert unchanged is None
+
+
+def test_parse_margin_to_balances() -> None:
+    # Arrange
+    raw = (
+        b'{"account":12345,"currency":"XBt","walletBalance":100000000,'
+        b'"marginBalance":110000000,"availableMargin":80000000,"initMargin":20000000,'
+        b'"maintMargin":5000000,"unrealisedPnl":10000000,"timestamp":"2025-01-01T00:00:00.000Z"}'
+    )
+    margin = msgspec.json.decode(raw, type=BitmexMargin)
+
+    # Act
+    balance = margin.parse_to_account_balance()
+    margin_balance = margin.parse_to_margin_balance()
+
+    # Assert
+    assert balance.total == Money(Decimal("1.1"), BTC)
+    assert balance.free == Money(Decimal("0.8"), BTC)
+    assert balance.locked == Money(Decimal("0.3"), BTC)
+    assert margin_balance.initial == Money(Decimal("0.2"), BTC)
+    assert margin_balance.maintenance == Money(Decimal("0.05"), BTC)
+
+
+def test_parse_position_to_position_status_report() -> None:
+    # Arrange
+    raw = (
+        b'{"account":12345,"symbol":"XBTUSD","currency":"XBt","currentQty":-500,'
+        b'"avgEntryPrice":95000,"isOpen":true,"timestamp":"2025-01-01T00:00:00.000Z"}'
+    )
+    position = msgspec.json.decode(raw, type=BitmexPosition)
+
+    # Act
+    report = position.parse_to_position_status_report(
+        account_id=ACCOUNT_ID,
+        instrument=_xbtusd(),
+        report_id=UUID4(),
+        ts_init=0,
+    )
+
+    # Assert
+    assert report.position_side == PositionSide.SHORT
+    assert report.quantity == Quantity.from_int(500)
+
+
+def test_parse_stop_order_to_order_status_report() -> None:
+    # Arrange
+    raw = (
+        b'{"orderID":"0b7d1f1e-6c3f-4b1e-8a3e-1f1b2c3d4e5f","clOrdID":"O-123",'
+        b'"symbol":"XBTUSD","side":"Sell","orderQty":200,"price":94000,"stopPx":94500,'
+        b'"ordType":"StopLimit","timeInForce":"GoodTillCancel","execInst":"LastPrice,ReduceOnly",'
+        b'"ordStatus":"New","leavesQty":200,"cumQty":0,"avgPx":null,'
+        b'"transactTime":"2025-01-01T00:00:00.000Z","timestamp":"2025-01-01T00:00:01.000Z"}'
+    )
+    bitmex_order = msgspec.json.decode(raw, type=BitmexOrder)
+
+    # Act
+    report = bitmex_order.parse_to_order_status_report(
+        account_id=ACCOUNT_ID,
+        instrument=_xbtusd(),
+        client_order_id=ClientOrderId("O-123"),
+        report_id=UUID4(),
+        enum_parser=BitmexEnumParser(),
+        ts_init=0,
+    )
+
+    # Assert
+    assert report.order_side == OrderSide.SELL
+    assert report.order_type == OrderType.STOP_LIMIT
+    assert report.order_status == OrderStatus.ACCEPTED
+    assert report.price == Price.from_str("94000.0")
+    assert report.trigger_price == Price.from_str("94500.0")
+    assert report.trigger_type == TriggerType.LAST_PRICE
+    assert report.reduce_only
+    assert not report.post_only
+    assert report.quantity == Quantity.from_int(200)
+
+
+def test_parse_execution_to_fill_report() -> None:
+    # Arrange
+    raw = (
+        b'{"execID":"5c1b2f3a-0000-0000-0000-000000000001",'
+        b'"orderID":"0b7d1f1e-6c3f-4b1e-8a3e-1f1b2c3d4e5f","clOrdID":"O-123",'
+        b'"symbol":"XBTUSD","side":"Buy","lastQty":100,"lastPx":95000.5,"execType":"Trade",'
+        b'"ordStatus":"PartiallyFilled","execComm":-105,"settlCurrency":"XBt",'
+        b'"lastLiquidityInd":"AddedLiquidity","trdMatchID":"7f4ab7f6-0000-0000-0000-000000000001",'
+        b'"transactTime":"2025-01-01T00:00:00.000Z","timestamp":"2025-01-01T00:00:00.000Z"}'
+    )
+    execution = msgspec.json.decode(raw, type=BitmexExecution)
+
+    # Act
+    report = execution.parse_to_fill_report(
+        account_id=ACCOUNT_ID,
+        instrument=_xbtusd(),
+        client_order_id=ClientOrderId("O-123"),
+        report_id=UUID4(),
+        enum_parser=BitmexEnumParser(),
+        ts_init=0,
+    )
+
+    # Assert
+    assert report.order_side == OrderSide.BUY
+    assert report.last_qty == Quantity.from_int(100)
+    assert report.last_px == Price.from_str("95000.5")
+    assert report.commission == Money(Decimal("-0.00000105"), BTC)
+    assert report.liquidity_side == LiquiditySide.MAKER
+    assert report.trade_id.value == "5c1b2f3a-0000-0000-0000-000000000001"
+
+
+def test_parse_book_partial_to_snapshot_deltas() -> None:
+    # Arrange
+    raw = (
+        b'{"table":"orderBookL2_25","action":"partial","data":['
+        b'{"symbol":"XBTUSD","id":1,"side":"Sell","size":300,"price":95001,'
+        b'"timestamp":"2025-01-01T00:00:00.000Z"},'
+        b'{"symbol":"XBTUSD","id":2,"side":"Buy","size":200,"price":95000,'
+        b'"timestamp":"2025-01-01T00:00:00.000Z"}]}'
+    )
+    msg = msgspec.json.decode(raw, type=BitmexWsBookMsg)
+
+    # Act
+    deltas = msg.parse_to_deltas(
+        symbol="XBTUSD",
+        instrument_id=XBTUSD,
+        price_precision=1,
+        size_precision=0,
+        ts_init=0,
+    )
+
+    # Assert
+    assert deltas is not None
+    assert len(deltas.deltas) == 3
+    assert deltas.deltas[0].action == BookAction.CLEAR
+    assert deltas.deltas[1].action == BookAction.ADD
+    assert deltas.deltas[1].order.side == OrderSide.SELL
+    assert deltas.deltas[2].order.price == Price.from_str("95000.0")
+    assert deltas.deltas[2].order.size == Quantity.from_int(200)
+    assert deltas.deltas[2].flags == RecordFlag.F_SNAPSHOT | RecordFlag.F_LAST
+
+
+@pytest.mark.parametrize(
+    ("action", "expected"),
+    [
+        ["insert", BookAction.ADD],
+        ["update", BookAction.UPDATE],
+        ["delete", BookAction.DELETE],
+    ],
+)
+def test_parse_book_update_to_deltas(action: str, expected: BookAction) -> None:
+    # Arrange
+    raw = (
+        b'{"table":"orderBookL2","action":"' + action.encode() + b'","data":['
+        b'{"symbol":"XBTUSD","id":1,"side":"Sell","size":100,"price":95001,'
+        b'"timestamp":"2025-01-01T00:00:00.000Z"},'
+        b'{"symbol":"XBTUSDT","id":3,"side":"Buy","size":1000,"price":94999,'
+        b'"timestamp":"2025-01-01T00:00:00.000Z"}]}'
+    )
+    msg = msgspec.json.decode(raw, type=BitmexWsBookMsg)
+
+    # Act
+    deltas = msg.parse_to_deltas(
+        symbol="XBTUSD",
+        instrument_id=XBTUSD,
+        price_precision=1,
+        size_precision=0,
+        ts_init=0,
+    )
+
+    # Assert
+    assert msg.symbols == {"XBTUSD", "XBTUSDT"}
+    assert deltas is not None
+    assert len(deltas.deltas) == 1
+    assert deltas.deltas[0].action == expected
+    assert deltas.deltas[0].flags == RecordFlag.F_LAST
+
+
+def test_parse_quote_to_quote_tick() -> None:
+    # Arrange
+    raw = (
+        b'{"table":"quote","action":"insert","data":[{"symbol":"XBTUSD","bidSize":1000,'
+        b'"bidPrice":95000,"askPrice":95000.5,"askSize":2000,'
+        b'"timestamp":"2025-01-01T00:00:00.000Z"}]}'
+    )
+    msg = msgspec.json.decode(raw, type=BitmexWsQuoteMsg)
+
+    # Act
+    quote = msg.data[0].parse_to_quote_tick(
+        instrument_id=XBTUSD,
+        price_precision=1,
+        size_precision=0,
+        ts_init=0,
+    )
+
+    # Assert
+    assert quote is not None
+    assert quote.bid_price == Price.from_str("95000.0")
+    assert quote.ask_price == Price.from_str("95000.5")
+    assert quote.bid_size == Quantity.from_int(1000)
+    assert quote.ask_size == Quantity.from_int(2000)
+    assert quote.ts_event == 1_735_689_600_000_000_000
+
+
+def test_parse_trade_to_trade_tick() -> None:
+    # Arrange
+    raw = (
+        b'{"table":"trade","action":"insert","data":[{"symbol":"XBTUSD","side":"Sell",'
+        b'"size":500,"price":95000,"tickDirection":"MinusTick",'
+        b'"trdMatchID":"7f4ab7f6-0000-0000-0000-000000000001",'
+        b'"timestamp":"2025-01-01T00:00:00.000Z"}]}'
+    )
+    msg = msgspec.json.decode(raw, type=BitmexWsTradeMsg)
+
+    # Act
+    trade = msg.data[0].parse_to_trade_tick(
+        instrument_id=XBTUSD,
+        price_precision=1,
+        size_precision=0,
+        ts_init=0,
+    )
+
+    # Assert
+    assert trade.aggressor_side == AggressorSide.SELLER
+    assert trade.size == Quantity.from_int(500)
+    assert trade.trade_id.value == "7f4ab7f6-0000-0000-0000-000000000001"
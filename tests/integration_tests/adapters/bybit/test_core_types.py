@@ -0,0 +1,84 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pickle
+from decimal import Decimal
+
+from nautilus_trader.adapters.bybit.types import BybitFundingRateUpdate
+from nautilus_trader.model.identifiers import InstrumentId
+
+
+ETHUSDT_LINEAR_ID = InstrumentId.from_str("ETHUSDT-LINEAR.BYBIT")
+
+
+def test_bybit_funding_rate_update_repr():
+    # Arrange
+    update = BybitFundingRateUpdate(
+        instrument_id=ETHUSDT_LINEAR_ID,
+        funding_rate=Decimal("0.0001"),
+        ts_next_funding=1650000000000000002,
+        ts_event=1650000000000000001,
+        ts_init=1650000000000000000,
+    )
+
+    # Act, Assert
+    assert (
+        repr(update)
+        == "BybitFundingRateUpdate(instrument_id=ETHUSDT-LINEAR.BYBIT, funding_rate=0.0001, ts_next_funding=1650000000000000002, ts_event=1650000000000000001, ts_init=1650000000000000000)"  # noqa
+    )
+
+
+def test_bybit_funding_rate_update_to_from_dict():
+    # Arrange
+    update = BybitFundingRateUpdate(
+        instrument_id=ETHUSDT_LINEAR_ID,
+        funding_rate=Decimal("-0.00025"),
+        ts_next_funding=1650000000000000002,
+        ts_event=1650000000000000001,
+        ts_init=1650000000000000000,
+    )
+
+    # Act
+    values = update.to_dict(update)
+
+    # Assert
+    assert BybitFundingRateUpdate.from_dict(values) == update
+    assert values == {
+        "type": "BybitFundingRateUpdate",
+        "instrument_id": "ETHUSDT-LINEAR.BYBIT",
+        "funding_rate": "-0.00025",
+        "ts_next_funding": 1650000000000000002,
+        "ts_event": 1650000000000000001,
+        "ts_init": 1650000000000000000,
+    }
+
+
+def test_bybit_funding_rate_update_pickling():
+    # Arrange
+    update = BybitFundingRateUpdate(
+        instrument_id=ETHUSDT_LINEAR_ID,
+        funding_rate=Decimal("0.0001"),
+        ts_next_funding=1650000000000000002,
+        ts_event=1650000000000000001,
+        ts_init=1650000000000000000,
+    )
+
+    # Act
+    pickled = pickle.dumps(update)
+    unpickled = pickle.loads(pickled)  # noqa: S301 (pickle is safe here)
+
+    # Assert
+    assert unpickled == update
+    assert unpickled.ts_event == update.ts_event
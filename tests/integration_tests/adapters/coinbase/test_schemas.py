@@ -0,0 +1,412 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from decimal import Decimal
+
+import msgspec
+import pandas as pd
+import pytest
+
+from nautilus_trader.adapters.coinbase.common.enums import CoinbaseEnumParser
+from nautilus_trader.adapters.coinbase.schemas.account import CoinbaseAccounts
+from nautilus_trader.adapters.coinbase.schemas.account import parse_account_balances
+from nautilus_trader.adapters.coinbase.schemas.order import CoinbaseCreateOrderResponse
+from nautilus_trader.adapters.coinbase.schemas.order import CoinbaseEditOrderResponse
+from nautilus_trader.adapters.coinbase.schemas.order import CoinbaseFill
+from nautilus_trader.adapters.coinbase.schemas.order import CoinbaseOrder
+from nautilus_trader.adapters.coinbase.schemas.product import CoinbaseProduct
+from nautilus_trader.adapters.coinbase.schemas.product import CoinbaseProducts
+from nautilus_trader.adapters.coinbase.schemas.ws import CoinbaseWsL2Msg
+from nautilus_trader.adapters.coinbase.schemas.ws import CoinbaseWsMarketTradesMsg
+from nautilus_trader.adapters.coinbase.schemas.ws import CoinbaseWsMessageGeneral
+from nautilus_trader.adapters.coinbase.schemas.ws import CoinbaseWsTickerMsg
+from nautilus_trader.adapters.coinbase.schemas.ws import CoinbaseWsUserMsg
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.model.enums import AggressorSide
+from nautilus_trader.model.enums import BookAction
+from nautilus_trader.model.enums import LiquiditySide
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import RecordFlag
+from nautilus_trader.model.enums import TimeInForce
+from nautilus_trader.model.enums import TriggerType
+from nautilus_trader.model.identifiers import AccountId
+from nautilus_trader.model.identifiers import ClientOrderId
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.instruments import CurrencyPair
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import Money
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+
+
+ACCOUNT_ID = AccountId("COINBASE-master")
+BTC = Currency.from_str("BTC")
+USD = Currency.from_str("USD")
+
+BTC_USD_RAW = (
+    b'{"product_id":"BTC-USD","price":"67000.01","base_increment":"0.00000001",'
+    b'"quote_increment":"0.01","quote_min_size":"1","quote_max_size":"150000000",'
+    b'"base_min_size":"0.00000001","base_max_size":"3400","base_name":"Bitcoin",'
+    b'"quote_name":"US Dollar","status":"online","cancel_only":false,"limit_only":false,'
+    b'"post_only":false,"trading_disabled":false,"auction_mode":false,"product_type":"SPOT",'
+    b'"quote_currency_id":"USD","base_currency_id":"BTC","view_only":false,'
+    b'"price_increment":"0.01","alias":"","alias_to":["BTC-USDC"]}'
+)
+
+
+def _btc_usd() -> CurrencyPair:
+    return msgspec.json.decode(BTC_USD_RAW, type=CoinbaseProduct).parse_to_instrument(
+        base_currency=BTC,
+        quote_currency=USD,
+        maker_fee=Decimal("0.006"),
+        taker_fee=Decimal("0.012"),
+        ts_event=0,
+        ts_init=0,
+    )
+
+
+def test_decode_products_response() -> None:
+    # Arrange
+    raw = b'{"products":[' + BTC_USD_RAW + b'],"num_products":1}'
+
+    # Act
+    response = msgspec.json.decode(raw, type=CoinbaseProducts)
+
+    # Assert
+    assert len(response.products) == 1
+    assert response.products[0].is_tradable
+    assert response.products[0].symbol.quote == "USD"
+
+
+def test_parse_product_to_instrument() -> None:
+    # Arrange, Act
+    instrument = _btc_usd()
+
+    # Assert
+    assert instrument.id == InstrumentId.from_str("BTC-USD.COINBASE")
+    assert instrument.base_currency == BTC
+    assert instrument.quote_currency == USD
+    assert instrument.price_increment == Price.from_str("0.01")
+    assert instrument.size_increment == Quantity.from_str("0.00000001")
+    assert instrument.min_quantity == Quantity.from_str("0.00000001")
+    assert instrument.max_quantity == Quantity.from_str("3400.00000000")
+    assert instrument.min_notional == Money(1, USD)
+    assert instrument.taker_fee == Decimal("0.012")
+    assert instrument.info["alias_to"] == ["BTC-USDC"]
+
+
+def test_parse_non_spot_product_raises() -> None:
+    # Arrange
+    raw = BTC_USD_RAW.replace(b'"product_type":"SPOT"', b'"product_type":"FUTURE"')
+
+    # Act, Assert
+    with pytest.raises(ValueError, match="unsupported product type"):
+        msgspec.json.decode(raw, type=CoinbaseProduct).parse_to_instrument(
+            base_currency=BTC,
+            quote_currency=USD,
+            maker_fee=Decimal(0),
+            taker_fee=Decimal(0),
+            ts_event=0,
+            ts_init=0,
+        )
+
+
+def test_parse_order_to_order_status_report() -> None:
+    # Arrange
+    instrument = _btc_usd()
+    raw = (
+        b'{"order_id":"0000-000000-000000","product_id":"BTC-USD","side":"BUY",'
+        b'"client_order_id":"O-123","status":"OPEN","time_in_force":"GOOD_UNTIL_DATE_TIME",'
+        b'"created_time":"2024-05-31T09:59:59.123456Z","order_type":"LIMIT",'
+        b'"order_configuration":{"limit_limit_gtd":{"base_size":"0.01","limit_price":"50000.00",'
+        b'"end_time":"2024-06-01T00:00:00Z","post_only":true}},"filled_size":"0.004",'
+        b'"average_filled_price":"49999.99","total_fees":"1.2","number_of_fills":"2",'
+        b'"last_fill_time":"2024-05-31T10:00:00Z","pending_cancel":false,"size_in_quote":false}'
+    )
+    coinbase_order = msgspec.json.decode(raw, type=CoinbaseOrder)
+
+    # Act
+    report = coinbase_order.parse_to_order_status_report(
+        account_id=ACCOUNT_ID,
+        instrument=instrument,
+        client_order_id=ClientOrderId(coinbase_order.client_order_id),
+        report_id=UUID4(),
+        enum_parser=CoinbaseEnumParser(),
+        ts_init=0,
+    )
+
+    # Assert
+    assert report.client_order_id == ClientOrderId("O-123")
+    assert report.venue_order_id.value == "0000-000000-000000"
+    assert report.order_side == OrderSide.BUY
+    assert report.order_type == OrderType.LIMIT
+    assert report.time_in_force == TimeInForce.GTD
+    assert report.expire_time == pd.Timestamp("2024-06-01T00:00:00Z")
+    assert report.order_status == OrderStatus.PARTIALLY_FILLED
+    assert report.price == Price.from_str("50000.00")
+    assert report.quantity == instrument.make_qty(Decimal("0.01"))
+    assert report.filled_qty == instrument.make_qty(Decimal("0.004"))
+    assert report.avg_px == Decimal("49999.99")
+    assert report.post_only
+    assert report.ts_accepted == 1_717_149_599_123_456_000
+
+
+def test_parse_stop_limit_order_to_order_status_report() -> None:
+    # Arrange
+    instrument = _btc_usd()
+    raw = (
+        b'{"order_id":"0000-000000-000001","product_id":"BTC-USD","side":"SELL",'
+        b'"client_order_id":"O-124","status":"CANCELLED","time_in_force":"GOOD_UNTIL_CANCELLED",'
+        b'"created_time":"2024-05-31T09:59:59Z","order_type":"STOP_LIMIT",'
+        b'"order_configuration":{"stop_limit_stop_limit_gtc":{"base_size":"0.01",'
+        b'"limit_price":"49000.00","stop_price":"49500.00",'
+        b'"stop_direction":"STOP_DIRECTION_STOP_DOWN"}},"filled_size":"0",'
+        b'"cancel_message":"User requested cancel"}'
+    )
+    coinbase_order = msgspec.json.decode(raw, type=CoinbaseOrder)
+
+    # Act
+    report = coinbase_order.parse_to_order_status_report(
+        account_id=ACCOUNT_ID,
+        instrument=instrument,
+        client_order_id=ClientOrderId(coinbase_order.client_order_id),
+        report_id=UUID4(),
+        enum_parser=CoinbaseEnumParser(),
+        ts_init=0,
+    )
+
+    # Assert
+    assert report.order_type == OrderType.STOP_LIMIT
+    assert report.order_status == OrderStatus.CANCELED
+    assert report.trigger_price == Price.from_str("49500.00")
+    assert report.trigger_type == TriggerType.LAST_PRICE
+    assert report.cancel_reason == "User requested cancel"
+    assert report.expire_time is None
+
+
+def test_parse_fill_to_fill_report() -> None:
+    # Arrange
+    instrument = _btc_usd()
+    raw = (
+        b'{"entry_id":"22222","trade_id":"1111-11111-111111","order_id":"0000-000000-000000",'
+        b'"trade_time":"2024-05-31T10:00:00Z","trade_type":"FILL","price":"50000.00",'
+        b'"size":"100.00","commission":"0.6","product_id":"BTC-USD",'
+        b'"sequence_timestamp":"2024-05-31T10:00:00.000001Z","liquidity_indicator":"MAKER",'
+        b'"size_in_quote":true,"user_id":"3333-333333-333333","side":"BUY"}'
+    )
+    fill = msgspec.json.decode(raw, type=CoinbaseFill)
+
+    # Act
+    report = fill.parse_to_fill_report(
+        account_id=ACCOUNT_ID,
+        instrument=instrument,
+        client_order_id=None,
+        report_id=UUID4(),
+        enum_parser=CoinbaseEnumParser(),
+        ts_init=0,
+    )
+
+    # Assert
+    assert report.trade_id.value == "1111-11111-111111"
+    assert report.last_qty == instrument.make_qty(Decimal("0.002"))
+    assert report.last_px == Price.from_str("50000.00")
+    assert report.commission == Money(Decimal("0.6"), USD)
+    assert report.liquidity_side == LiquiditySide.MAKER
+    assert report.ts_event == 1_717_149_600_000_000_000
+
+
+def test_parse_account_balances() -> None:
+    # Arrange
+    raw = (
+        b'{"accounts":['
+        b'{"uuid":"a","name":"BTC Wallet","currency":"BTC",'
+        b'"available_balance":{"value":"1.5","currency":"BTC"},'
+        b'"hold":{"value":"0.5","currency":"BTC"},"active":true,"ready":true,"type":"ACCOUNT_TYPE_CRYPTO"},'
+        b'{"uuid":"b","name":"ETH Wallet","currency":"ETH",'
+        b'"available_balance":{"value":"0","currency":"ETH"},'
+        b'"hold":{"value":"0","currency":"ETH"},"active":true,"ready":true,"type":"ACCOUNT_TYPE_CRYPTO"}'
+        b'],"has_next":false,"cursor":"","size":2}'
+    )
+    accounts = msgspec.json.decode(raw, type=CoinbaseAccounts).accounts
+
+    # Act
+    balances = parse_account_balances(accounts)
+
+    # Assert
+    assert len(balances) == 1
+    assert balances[0].total == Money(2, BTC)
+    assert balances[0].locked == Money(Decimal("0.5"), BTC)
+    assert balances[0].free == Money(Decimal("1.5"), BTC)
+
+
+def test_create_order_response_reason() -> None:
+    # Arrange
+    raw = (
+        b'{"success":false,"failure_reason":"UNKNOWN_FAILURE_REASON","order_id":"",'
+        b'"error_response":{"error":"INSUFFICIENT_FUND","message":"Insufficient balance in source account",'
+        b'"error_details":"","preview_failure_reason":"PREVIEW_INSUFFICIENT_FUND"}}'
+    )
+
+    # Act
+    response = msgspec.json.decode(raw, type=CoinbaseCreateOrderResponse)
+
+    # Assert
+    assert not response.success
+    assert response.reason == "Insufficient balance in source account"
+
+
+def test_edit_order_response_reason() -> None:
+    # Arrange
+    raw = b'{"success":false,"errors":[{"edit_failure_reason":"ORDER_NOT_FOUND"}]}'
+
+    # Act
+    response = msgspec.json.decode(raw, type=CoinbaseEditOrderResponse)
+
+    # Assert
+    assert response.reason == "ORDER_NOT_FOUND"
+
+
+def test_parse_ws_l2_snapshot_to_deltas() -> None:
+    # Arrange
+    instrument = _btc_usd()
+    raw = (
+        b'{"channel":"l2_data","client_id":"","timestamp":"2024-05-31T10:00:00.000Z",'
+        b'"sequence_num":0,"events":[{"type":"snapshot","product_id":"BTC-USD","updates":['
+        b'{"side":"bid","event_time":"1970-01-01T00:00:00Z","price_level":"49999.99","new_quantity":"0.5"},'
+        b'{"side":"offer","event_time":"1970-01-01T00:00:00Z","price_level":"50000.01","new_quantity":"1.2"}'
+        b"]}]}"
+    )
+    msg = msgspec.json.decode(raw, type=CoinbaseWsL2Msg)
+
+    # Act
+    deltas = msg.events[0].parse_to_deltas(instrument, sequence=0, ts_event=1, ts_init=2)
+
+    # Assert
+    assert len(deltas.deltas) == 3
+    assert deltas.deltas[0].action == BookAction.CLEAR
+    assert deltas.deltas[1].action == BookAction.ADD
+    assert deltas.deltas[1].order.side == OrderSide.BUY
+    assert deltas.deltas[2].order.side == OrderSide.SELL
+    assert deltas.deltas[2].order.price == Price.from_str("50000.01")
+    assert deltas.deltas[2].flags == RecordFlag.F_SNAPSHOT | RecordFlag.F_LAST
+
+
+def test_parse_ws_l2_update_to_deltas() -> None:
+    # Arrange
+    instrument = _btc_usd()
+    raw = (
+        b'{"channel":"l2_data","client_id":"","timestamp":"2024-05-31T10:00:00.000Z",'
+        b'"sequence_num":5,"events":[{"type":"update","product_id":"BTC-USD","updates":['
+        b'{"side":"bid","event_time":"2024-05-31T10:00:00Z","price_level":"49999.99","new_quantity":"0"},'
+        b'{"side":"offer","event_time":"2024-05-31T10:00:00Z","price_level":"50000.01","new_quantity":"0.7"}'
+        b"]}]}"
+    )
+    msg = msgspec.json.decode(raw, type=CoinbaseWsL2Msg)
+
+    # Act
+    deltas = msg.events[0].parse_to_deltas(instrument, sequence=5, ts_event=1, ts_init=2)
+
+    # Assert
+    assert [delta.action for delta in deltas.deltas] == [BookAction.DELETE, BookAction.UPDATE]
+    assert deltas.deltas[0].flags == 0
+    assert deltas.deltas[1].flags == RecordFlag.F_LAST
+    assert deltas.deltas[1].order.size == Quantity.from_str("0.70000000")
+
+
+def test_parse_ws_ticker_to_quote_tick() -> None:
+    # Arrange
+    instrument = _btc_usd()
+    raw = (
+        b'{"channel":"ticker","client_id":"","timestamp":"2024-05-31T10:00:00.000Z",'
+        b'"sequence_num":1,"events":[{"type":"update","tickers":[{"type":"ticker",'
+        b'"product_id":"BTC-USD","price":"50000.00","volume_24_h":"1000","low_24_h":"49000",'
+        b'"high_24_h":"51000","best_bid":"49999.99","best_bid_quantity":"0.5",'
+        b'"best_ask":"50000.01","best_ask_quantity":"1.2"}]}]}'
+    )
+    msg = msgspec.json.decode(raw, type=CoinbaseWsTickerMsg)
+
+    # Act
+    quote = msg.events[0].tickers[0].parse_to_quote_tick(instrument, ts_event=1, ts_init=2)
+
+    # Assert
+    assert quote is not None
+    assert quote.bid_price == Price.from_str("49999.99")
+    assert quote.ask_price == Price.from_str("50000.01")
+    assert quote.ask_size == Quantity.from_str("1.20000000")
+
+
+def test_parse_ws_market_trade_to_trade_tick() -> None:
+    # Arrange
+    instrument = _btc_usd()
+    raw = (
+        b'{"channel":"market_trades","client_id":"","timestamp":"2024-05-31T10:00:00.000Z",'
+        b'"sequence_num":2,"events":[{"type":"update","trades":[{"trade_id":"123456",'
+        b'"product_id":"BTC-USD","price":"50000.00","size":"0.01","side":"SELL",'
+        b'"time":"2024-05-31T10:00:00Z"}]}]}'
+    )
+    msg = msgspec.json.decode(raw, type=CoinbaseWsMarketTradesMsg)
+
+    # Act
+    trade = msg.events[0].trades[0].parse_to_trade_tick(instrument, ts_init=2)
+
+    # Assert
+    assert trade.trade_id.value == "123456"
+    assert trade.aggressor_side == AggressorSide.SELLER
+    assert trade.size == Quantity.from_str("0.01000000")
+    assert trade.ts_event == 1_717_149_600_000_000_000
+
+
+def test_decode_ws_error_message() -> None:
+    # Arrange
+    raw = b'{"type":"error","message":"authentication failure"}'
+
+    # Act
+    msg = msgspec.json.decode(raw, type=CoinbaseWsMessageGeneral)
+
+    # Assert
+    assert msg.type == "error"
+    assert msg.channel is None
+    assert msg.message == "authentication failure"
+
+
+def test_decode_ws_user_message() -> None:
+    # Arrange
+    raw = (
+        b'{"channel":"user","client_id":"","timestamp":"2024-05-31T10:00:00.000Z",'
+        b'"sequence_num":3,"events":[{"type":"update","orders":[{"avg_price":"50000.00",'
+        b'"cancel_reason":"","client_order_id":"O-123","completion_percentage":"40.00",'
+        b'"contract_expiry_type":"UNKNOWN_CONTRACT_EXPIRY_TYPE","cumulative_quantity":"0.004",'
+        b'"filled_value":"200","leaves_quantity":"0.006","limit_price":"50000.00",'
+        b'"number_of_fills":"1","order_id":"0000-000000-000000","order_side":"BUY",'
+        b'"order_type":"Limit","outstanding_hold_amount":"300","post_only":false,'
+        b'"product_id":"BTC-USD","product_type":"SPOT","reject_Reason":"",'
+        b'"retail_portfolio_id":"","risk_managed_by":"UNKNOWN_RISK_MANAGEMENT_TYPE",'
+        b'"status":"OPEN","stop_price":"","time_in_force":"GOOD_UNTIL_CANCELLED",'
+        b'"total_fees":"1.2","total_value_after_fees":"201.2","trigger_status":"INVALID_ORDER_TYPE",'
+        b'"creation_time":"2024-05-31T09:59:59.123456Z","end_time":"0001-01-01T00:00:00Z",'
+        b'"start_time":"0001-01-01T00:00:00Z"}]}]}'
+    )
+
+    # Act
+    msg = msgspec.json.decode(raw, type=CoinbaseWsUserMsg)
+
+    # Assert
+    order = msg.events[0].orders[0]
+    assert order.client_order_id == "O-123"
+    assert order.filled_qty == Decimal("0.004")
+    assert order.quantity == Decimal("0.010")
+    assert order.reject_reason == ""
@@ -0,0 +1,168 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pytest
+
+from nautilus_trader.adapters.coinbase_intx.common.constants import COINBASE_INTX_VENUE
+from nautilus_trader.adapters.coinbase_intx.common.parsing import format_timestamp
+from nautilus_trader.adapters.coinbase_intx.common.urls import get_http_base_url
+from nautilus_trader.adapters.coinbase_intx.http.client import CoinbaseIntxHttpClient
+from nautilus_trader.adapters.coinbase_intx.http.client import coinbase_intx_sign
+from nautilus_trader.adapters.coinbase_intx.http.errors import CoinbaseIntxError
+from nautilus_trader.adapters.coinbase_intx.http.errors import parse_error
+from nautilus_trader.adapters.coinbase_intx.http.errors import should_retry
+from nautilus_trader.common.component import TestClock
+from nautilus_trader.core.nautilus_pyo3 import HttpMethod
+from nautilus_trader.model.identifiers import Venue
+
+
+# The base64 encoded secret 0x00, 0x01, ..., 0x1f
+TEST_API_SECRET = "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8="
+
+
+def _client() -> CoinbaseIntxHttpClient:
+    return CoinbaseIntxHttpClient(
+        clock=TestClock(),
+        api_key="test-key",
+        api_secret=TEST_API_SECRET,
+        passphrase="test-passphrase",
+        base_url=get_http_base_url(is_testnet=True),
+    )
+
+
+def test_venue() -> None:
+    # Arrange, Act, Assert
+    assert COINBASE_INTX_VENUE == Venue("COINBASE_INTX")
+
+
+def test_http_base_url() -> None:
+    # Arrange, Act, Assert
+    assert get_http_base_url(is_testnet=False) == "https://api.international.coinbase.com"
+    assert get_http_base_url(is_testnet=True) == "https://api-n5e1.coinbase.com"
+
+
+def test_format_timestamp() -> None:
+    # Arrange, Act, Assert
+    assert format_timestamp(1_717_149_599_123_456_000) == "2024-05-31T09:59:59.123456Z"
+
+
+@pytest.mark.parametrize(
+    ("message", "expected"),
+    [
+        [
+            "1717149599GET/api/v1/portfolios/1234/balances",
+            "64RNLUvQMYjYl5EI+rc/LB/AJ0p4WxexLEWpl9K1+DU=",
+        ],
+        [
+            '1717149599POST/api/v1/orders{"side":"BUY"}',
+            "lZucwKCCiM5bot/Zx8ayaijrWzFlwJp49LpGhKw3cgc=",
+        ],
+    ],
+)
+def test_coinbase_intx_sign(message: str, expected: str) -> None:
+    # Arrange, Act
+    signature = coinbase_intx_sign(TEST_API_SECRET, message)
+
+    # Assert
+    assert signature == expected
+
+
+def test_client_sign_includes_query_string() -> None:
+    # Arrange
+    client = _client()
+    url_path = (
+        "/api/v1/transfers?portfolios=1234&time_from=2024-05-31T09%3A59%3A59.000000Z"
+        "&result_limit=100&result_offset=0"
+    )
+
+    # Act
+    signature = client.sign(
+        timestamp="1717149599",
+        http_method=HttpMethod.GET,
+        url_path=url_path,
+        body="",
+    )
+
+    # Assert
+    assert signature == "TNpjSGWyo7DvsEP9LQT+Jg6xfluw+GP507R5+2EtQwU="
+
+
+def test_client_sign_matches_sign_function() -> None:
+    # Arrange
+    client = _client()
+
+    # Act
+    signature = client.sign(
+        timestamp="1717149599",
+        http_method=HttpMethod.GET,
+        url_path="/api/v1/portfolios/1234/balances",
+        body="",
+    )
+
+    # Assert
+    assert signature == "64RNLUvQMYjYl5EI+rc/LB/AJ0p4WxexLEWpl9K1+DU="
+    assert client.api_key == "test-key"
+    assert client.passphrase == "test-passphrase"
+
+
+def test_parse_error_with_detail() -> None:
+    # Arrange
+    body = {"title": "Not Found", "status": 404, "detail": "portfolio not found"}
+
+    # Act
+    error = parse_error(404, body)
+
+    # Assert
+    assert isinstance(error, CoinbaseIntxError)
+    assert error.code == 404
+    assert error.title == "Not Found"
+    assert error.message == "portfolio not found"
+
+
+def test_parse_error_without_detail() -> None:
+    # Arrange, Act
+    error = parse_error(401, {"title": "Unauthorized"})
+
+    # Assert
+    assert error.title == "Unauthorized"
+    assert error.message == "Unauthorized"
+
+
+def test_parse_error_raw_body() -> None:
+    # Arrange, Act
+    error = parse_error(502, "Bad Gateway")
+
+    # Assert
+    assert error.code == 502
+    assert error.title is None
+    assert error.message == "Bad Gateway"
+
+
+@pytest.mark.parametrize(
+    ("code", "expected"),
+    [
+        [429, True],
+        [503, True],
+        [400, False],
+        [401, False],
+    ],
+)
+def test_should_retry(code: int, expected: bool) -> None:
+    # Arrange
+    error = CoinbaseIntxError(code=code, message="error")
+
+    # Act, Assert
+    assert should_retry(error) == expected
+    assert not should_retry(ValueError("error"))
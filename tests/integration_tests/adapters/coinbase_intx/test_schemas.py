@@ -0,0 +1,333 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import msgspec
+
+from nautilus_trader.adapters.coinbase_intx.common.enums import CoinbaseIntxAccountUpdateReason
+from nautilus_trader.adapters.coinbase_intx.common.enums import CoinbaseIntxLiquidationStatus
+from nautilus_trader.adapters.coinbase_intx.common.enums import CoinbaseIntxMarginType
+from nautilus_trader.adapters.coinbase_intx.common.enums import CoinbaseIntxTransferStatus
+from nautilus_trader.adapters.coinbase_intx.common.enums import CoinbaseIntxTransferType
+from nautilus_trader.adapters.coinbase_intx.schemas.portfolio import CoinbaseIntxBalance
+from nautilus_trader.adapters.coinbase_intx.schemas.portfolio import CoinbaseIntxPortfolioSummary
+from nautilus_trader.adapters.coinbase_intx.schemas.portfolio import CoinbaseIntxTransfer
+from nautilus_trader.adapters.coinbase_intx.schemas.portfolio import CoinbaseIntxTransfers
+from nautilus_trader.adapters.coinbase_intx.schemas.portfolio import parse_account_balances
+from nautilus_trader.model.objects import AccountBalance
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import Money
+
+
+BTC = Currency.from_str("BTC")
+USDC = Currency.from_str("USDC")
+
+BALANCES_RAW = (
+    b'[{"asset_id":"1","asset_uuid":"2b92315d","asset_name":"USDC","quantity":"10000.123456789",'
+    b'"hold":"250","hold_available_for_collateral":"0","transfer_hold":"50",'
+    b'"collateral_value":"10000.123456789","max_withdraw_amount":"7500.5","loan":"0",'
+    b'"loan_collateral_requirement":"0"},'
+    b'{"asset_id":"2","asset_uuid":"5b71fc48","asset_name":"BTC","quantity":"0.5","hold":"0.1"},'
+    b'{"asset_id":"3","asset_uuid":"d85dce9b","asset_name":"ETH","quantity":"0","hold":"0"}]'
+)
+
+SUMMARY_RAW = (
+    b'{"collateral":"10000","unrealized_pnl":"-120.5","unrealized_pnl_percent":"-0.012",'
+    b'"position_notional":"25000","open_position_notional":"25002.5","pending_fees":"1.5",'
+    b'"borrow":"0","accrued_interest":"0","rolling_debt":"0","portfolio_initial_margin":"0.1",'
+    b'"portfolio_im_notional":"2500.25","portfolio_maintenance_margin":"0.05",'
+    b'"portfolio_mm_notional":"1250.125","liquidation_percentage":"0.125",'
+    b'"liquidation_buffer":"8749.875","margin_type":"CROSS","margin_flags":"NONE",'
+    b'"liquidation_status":"NOT_LIQUIDATING"}'
+)
+
+FUNDING_TRANSFER_RAW = (
+    b'{"transfer_uuid":"8e471d77-4208-45a8-9e5b-f3bd8a2c1fc0","transfer_type":"FUNDING",'
+    b'"amount":"-1.25","asset":"USDC","status":"PROCESSED","network_name":null,'
+    b'"created_at":"2024-05-31T08:00:00Z","updated_at":"2024-05-31T08:00:01Z",'
+    b'"from_portfolio":{"id":"1234","uuid":"018ff1a1","name":"Default"},'
+    b'"to_portfolio":{"id":"1234","uuid":"018ff1a1","name":"Default"},'
+    b'"instrument_id":"149264167780483072","instrument_symbol":"BTC-PERP",'
+    b'"position_id":"a1b2c3"}'
+)
+
+DEPOSIT_TRANSFER_RAW = (
+    b'{"transfer_uuid":"2a2b9c45-2d3e-4f1a-9f5b-1c2d3e4f5a6b","transfer_type":"DEPOSIT",'
+    b'"amount":"5000","asset":"USDC","status":"NEW","created_at":"2024-05-31T09:00:00Z",'
+    b'"to_portfolio":{"id":"1234"}}'
+)
+
+
+def test_decode_balances() -> None:
+    # Arrange, Act
+    balances = msgspec.json.decode(BALANCES_RAW, type=list[CoinbaseIntxBalance])
+
+    # Assert
+    assert len(balances) == 3
+    assert balances[0].asset_name == "USDC"
+    assert balances[0].transfer_hold == "50"
+    assert balances[1].max_withdraw_amount is None
+    assert balances[2].is_empty
+
+
+def test_parse_balance_free_is_max_withdraw_amount() -> None:
+    # Arrange
+    balances = msgspec.json.decode(BALANCES_RAW, type=list[CoinbaseIntxBalance])
+
+    # Act
+    balance = balances[0].parse_to_account_balance()
+
+    # Assert
+    assert balance.currency == USDC
+    assert balance.total == Money("10000.123457", USDC)
+    assert balance.free == Money("7500.500000", USDC)
+    assert balance.locked == Money("2499.623457", USDC)
+
+
+def test_parse_balance_without_max_withdraw_amount_locks_hold() -> None:
+    # Arrange
+    balances = msgspec.json.decode(BALANCES_RAW, type=list[CoinbaseIntxBalance])
+
+    # Act
+    balance = balances[1].parse_to_account_balance()
+
+    # Assert
+    assert balance.total == Money("0.5", BTC)
+    assert balance.free == Money("0.4", BTC)
+    assert balance.locked == Money("0.1", BTC)
+
+
+def test_parse_borrowed_balance_is_zero() -> None:
+    # Arrange
+    balance = CoinbaseIntxBalance(
+        asset_id="4",
+        asset_name="USDC",
+        quantity="-100",
+        max_withdraw_amount="0",
+    )
+
+    # Act
+    account_balance = balance.parse_to_account_balance()
+
+    # Assert
+    assert not balance.is_empty
+    assert account_balance.total == Money(0, USDC)
+    assert account_balance.free == Money(0, USDC)
+
+
+def test_parse_balance_max_withdraw_amount_capped_at_total() -> None:
+    # Arrange
+    balance = CoinbaseIntxBalance(
+        asset_id="1",
+        asset_name="USDC",
+        quantity="100",
+        max_withdraw_amount="150",
+    )
+
+    # Act
+    account_balance = balance.parse_to_account_balance()
+
+    # Assert
+    assert account_balance.free == Money(100, USDC)
+    assert account_balance.locked == Money(0, USDC)
+
+
+def test_parse_account_balances_skips_empty() -> None:
+    # Arrange
+    balances = msgspec.json.decode(BALANCES_RAW, type=list[CoinbaseIntxBalance])
+
+    # Act
+    account_balances = parse_account_balances(balances)
+
+    # Assert
+    assert [b.currency for b in account_balances] == [USDC, BTC]
+
+
+def test_decode_portfolio_summary() -> None:
+    # Arrange, Act
+    summary = msgspec.json.decode(SUMMARY_RAW, type=CoinbaseIntxPortfolioSummary)
+
+    # Assert
+    assert summary.margin_type == CoinbaseIntxMarginType.CROSS
+    assert summary.liquidation_status == CoinbaseIntxLiquidationStatus.NOT_LIQUIDATING
+    assert not summary.is_liquidating
+
+
+def test_parse_portfolio_summary_to_margin_balance() -> None:
+    # Arrange
+    summary = msgspec.json.decode(SUMMARY_RAW, type=CoinbaseIntxPortfolioSummary)
+
+    # Act
+    margin = summary.parse_to_margin_balance()
+
+    # Assert
+    assert margin.currency == USDC
+    assert margin.initial == Money("2500.25", USDC)
+    assert margin.maintenance == Money("1250.125", USDC)
+    assert margin.instrument_id is None
+
+
+def test_parse_portfolio_summary_to_info() -> None:
+    # Arrange
+    summary = msgspec.json.decode(SUMMARY_RAW, type=CoinbaseIntxPortfolioSummary)
+
+    # Act
+    info = summary.parse_to_info()
+
+    # Assert
+    assert info == {
+        "collateral": "10000",
+        "unrealized_pnl": "-120.5",
+        "borrow": "0",
+        "pending_fees": "1.5",
+        "initial_margin_rate": "0.1",
+        "maintenance_margin_rate": "0.05",
+        "liquidation_buffer": "8749.875",
+        "liquidation_status": "NOT_LIQUIDATING",
+    }
+
+
+def test_portfolio_summary_is_liquidating() -> None:
+    # Arrange
+    raw = SUMMARY_RAW.replace(b'"NOT_LIQUIDATING"', b'"LIQUIDATING"')
+
+    # Act
+    summary = msgspec.json.decode(raw, type=CoinbaseIntxPortfolioSummary)
+
+    # Assert
+    assert summary.is_liquidating
+
+
+def test_decode_funding_transfer() -> None:
+    # Arrange, Act
+    transfer = msgspec.json.decode(FUNDING_TRANSFER_RAW, type=CoinbaseIntxTransfer)
+
+    # Assert
+    assert transfer.transfer_type == CoinbaseIntxTransferType.FUNDING
+    assert transfer.status == CoinbaseIntxTransferStatus.PROCESSED
+    assert transfer.is_funding
+    assert transfer.is_processed
+    assert transfer.from_portfolio is not None
+    assert transfer.from_portfolio.id == "1234"
+    assert transfer.ts_created == 1_717_142_400_000_000_000
+    assert transfer.ts_event == 1_717_142_401_000_000_000
+
+
+def test_parse_funding_transfer() -> None:
+    # Arrange
+    transfer = msgspec.json.decode(FUNDING_TRANSFER_RAW, type=CoinbaseIntxTransfer)
+
+    # Act
+    amount = transfer.parse_to_money()
+    info = transfer.parse_to_info()
+
+    # Assert
+    assert amount == Money("-1.25", USDC)
+    assert transfer.update_reason == CoinbaseIntxAccountUpdateReason.FUNDING
+    assert info == {
+        "reason": "FUNDING",
+        "transfer_id": "8e471d77-4208-45a8-9e5b-f3bd8a2c1fc0",
+        "transfer_type": "FUNDING",
+        "amount": "-1.25",
+        "asset": "USDC",
+        "instrument_symbol": "BTC-PERP",
+    }
+
+
+def test_parse_pending_deposit_transfer() -> None:
+    # Arrange, Act
+    transfer = msgspec.json.decode(DEPOSIT_TRANSFER_RAW, type=CoinbaseIntxTransfer)
+
+    # Assert
+    assert not transfer.is_funding
+    assert not transfer.is_processed
+    assert transfer.update_reason == CoinbaseIntxAccountUpdateReason.TRANSFER
+    assert transfer.from_portfolio is None
+    assert transfer.ts_event == transfer.ts_created
+    assert "instrument_symbol" not in transfer.parse_to_info()
+
+
+def test_funding_transfer_applied_to_free_balance() -> None:
+    # Arrange
+    transfer = msgspec.json.decode(FUNDING_TRANSFER_RAW, type=CoinbaseIntxTransfer)
+    balances = parse_account_balances(
+        msgspec.json.decode(BALANCES_RAW, type=list[CoinbaseIntxBalance]),
+    )
+
+    # Act
+    applied = transfer.apply_to_balances(balances)
+
+    # Assert
+    assert applied[0].total == Money("9998.873457", USDC)
+    assert applied[0].free == Money("7499.250000", USDC)
+    assert applied[0].locked == balances[0].locked
+    assert applied[1] == balances[1]
+
+
+def test_funding_payment_above_free_balance_capped_at_zero() -> None:
+    # Arrange
+    transfer = msgspec.json.decode(FUNDING_TRANSFER_RAW, type=CoinbaseIntxTransfer)
+    balance = AccountBalance(
+        total=Money(10, USDC),
+        locked=Money(9, USDC),
+        free=Money(1, USDC),
+    )
+
+    # Act
+    applied = transfer.apply_to_balances([balance])
+
+    # Assert
+    assert applied[0].total == Money("8.75", USDC)
+    assert applied[0].free == Money(0, USDC)
+    assert applied[0].locked == Money("8.75", USDC)
+
+
+def test_deposit_transfer_adds_balance_for_new_asset() -> None:
+    # Arrange
+    transfer = msgspec.json.decode(DEPOSIT_TRANSFER_RAW, type=CoinbaseIntxTransfer)
+    balance = AccountBalance(
+        total=Money("0.5", BTC),
+        locked=Money(0, BTC),
+        free=Money("0.5", BTC),
+    )
+
+    # Act
+    applied = transfer.apply_to_balances([balance])
+
+    # Assert
+    assert applied[0] == balance
+    assert applied[1].total == Money(5000, USDC)
+    assert applied[1].free == Money(5000, USDC)
+    assert applied[1].locked == Money(0, USDC)
+
+
+def test_decode_transfers_page() -> None:
+    # Arrange
+    raw = (
+        b'{"pagination":{"result_limit":100,"result_offset":0},"results":['
+        + FUNDING_TRANSFER_RAW
+        + b","
+        + DEPOSIT_TRANSFER_RAW
+        + b"]}"
+    )
+
+    # Act
+    transfers = msgspec.json.decode(raw, type=CoinbaseIntxTransfers)
+
+    # Assert
+    assert transfers.pagination.result_limit == 100
+    assert [t.transfer_type for t in transfers.results] == [
+        CoinbaseIntxTransferType.FUNDING,
+        CoinbaseIntxTransferType.DEPOSIT,
+    ]
@@ -0,0 +1,212 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import asyncio
+from collections.abc import Sequence
+from decimal import Decimal
+
+from nautilus_trader.common.component import MessageBus
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.execution.engine import ExecutionEngine
+from nautilus_trader.execution.messages import CancelOrder
+from nautilus_trader.execution.messages import ModifyOrder
+from nautilus_trader.execution.messages import SubmitOrder
+from nautilus_trader.live.execution_client import LiveExecutionClient
+from nautilus_trader.model.events import OrderEvent
+from nautilus_trader.model.instruments import Instrument
+from nautilus_trader.model.orders import Order
+from nautilus_trader.test_kit.functions import eventually
+from nautilus_trader.trading.strategy import Strategy
+from tests.integration_tests.adapters.conformance.scenario import Cancel
+from tests.integration_tests.adapters.conformance.scenario import Disconnect
+from tests.integration_tests.adapters.conformance.scenario import Fill
+from tests.integration_tests.adapters.conformance.scenario import Modify
+from tests.integration_tests.adapters.conformance.scenario import Reconnect
+from tests.integration_tests.adapters.conformance.scenario import ScenarioStep
+from tests.integration_tests.adapters.conformance.scenario import Submit
+from tests.integration_tests.adapters.conformance.venue import MockVenue
+
+
+class ExecutionConformanceHarness:
+    """
+    Drives an execution client through a conformance scenario against a mock venue.
+
+    Commands are sent through the execution engine, so the order events emitted by the
+    client are applied to the order and published as in a live node. After each step the
+    harness asserts the types of the order events published for the strategy match the
+    step `expected` types, and once the scenario completes that no further events follow.
+
+    Parameters
+    ----------
+    exec_client : LiveExecutionClient
+        The execution client under test (registered with the `exec_engine`).
+    exec_engine : ExecutionEngine
+        The execution engine to send commands through.
+    msgbus : MessageBus
+        The message bus to collect order events from.
+    venue : MockVenue
+        The running mock venue the client is connected to.
+    strategy : Strategy
+        The registered strategy to create orders for.
+    instrument : Instrument
+        The instrument to trade (must be in the cache).
+    timeout_secs : float, default 5.0
+        The timeout (seconds) for each step to emit its expected events.
+    settle_secs : float, default 0.5
+        The delay (seconds) after steps which expect no events, and after the scenario,
+        for unexpected events to arrive.
+
+    """
+
+    def __init__(
+        self,
+        exec_client: LiveExecutionClient,
+        exec_engine: ExecutionEngine,
+        msgbus: MessageBus,
+        venue: MockVenue,
+        strategy: Strategy,
+        instrument: Instrument,
+        timeout_secs: float = 5.0,
+        settle_secs: float = 0.5,
+    ) -> None:
+        self.exec_client = exec_client
+        self.exec_engine = exec_engine
+        self.venue = venue
+        self.strategy = strategy
+        self.instrument = instrument
+        self.timeout_secs = timeout_secs
+        self.settle_secs = settle_secs
+        self.events: list[OrderEvent] = []
+        self.order: Order | None = None
+        self._cursor = 0
+
+        msgbus.subscribe(topic=f"events.order.{strategy.id}", handler=self.events.append)
+
+    async def run(self, scenario: Sequence[ScenarioStep]) -> None:
+        """
+        Run the scenario, connecting the client first and disconnecting it after.
+
+        Raises
+        ------
+        AssertionError
+            If the order events emitted for a step do not match the expected events.
+
+        """
+        self.exec_client.connect()
+        await eventually(lambda: self.exec_client.is_connected, timeout=self.timeout_secs)
+
+        try:
+            for step in scenario:
+                await self._execute(step)
+                await self._assert_events(step)
+
+            await asyncio.sleep(self.settle_secs)
+            unexpected = self.events[self._cursor :]
+            assert not unexpected, f"Unexpected order events after scenario: {unexpected}"
+        finally:
+            self.exec_client.disconnect()
+            await eventually(lambda: not self.exec_client.is_connected, timeout=self.timeout_secs)
+
+    async def _execute(self, step: ScenarioStep) -> None:
+        match step:
+            case Submit():
+                self._submit(step)
+            case Fill():
+                await self.venue.fill_order(
+                    self._scenario_order().client_order_id.value,
+                    Decimal(step.quantity),
+                    Decimal(step.price),
+                )
+            case Modify():
+                self._modify(step)
+            case Cancel():
+                self._cancel()
+            case Disconnect():
+                await self.venue.disconnect()
+            case Reconnect():
+                self.venue.reconnect()
+            case _:
+                raise TypeError(f"Unsupported scenario step {step!r}")
+
+    async def _assert_events(self, step: ScenarioStep) -> None:
+        expected_count = self._cursor + len(step.expected)
+        if step.expected:
+            try:
+                await eventually(lambda: len(self.events) >= expected_count, self.timeout_secs)
+            except asyncio.TimeoutError:
+                pass  # Asserted below with the events received
+        else:
+            await asyncio.sleep(self.settle_secs)
+
+        received = self.events[self._cursor :]
+        received_types = tuple(type(event) for event in received)
+        assert received_types == step.expected, (
+            f"Step {step!r} expected {[t.__name__ for t in step.expected]}, "
+            f"received {[t.__name__ for t in received_types]}: {received}"
+        )
+        self._cursor = expected_count
+
+    def _scenario_order(self) -> Order:
+        if self.order is None:
+            raise RuntimeError("No order submitted for the scenario")
+        return self.order
+
+    def _submit(self, step: Submit) -> None:
+        if self.order is not None:
+            raise RuntimeError("Scenario order already submitted")
+
+        self.order = self.strategy.order_factory.limit(
+            instrument_id=self.instrument.id,
+            order_side=step.side,
+            quantity=self.instrument.make_qty(Decimal(step.quantity)),
+            price=self.instrument.make_price(Decimal(step.price)),
+        )
+        command = SubmitOrder(
+            trader_id=self.order.trader_id,
+            strategy_id=self.order.strategy_id,
+            order=self.order,
+            command_id=UUID4(),
+            ts_init=0,
+        )
+        self.exec_engine.execute(command)
+
+    def _modify(self, step: Modify) -> None:
+        order = self._scenario_order()
+        command = ModifyOrder(
+            trader_id=order.trader_id,
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=order.client_order_id,
+            venue_order_id=order.venue_order_id,
+            quantity=self.instrument.make_qty(Decimal(step.quantity)) if step.quantity else None,
+            price=self.instrument.make_price(Decimal(step.price)) if step.price else None,
+            trigger_price=None,
+            command_id=UUID4(),
+            ts_init=0,
+        )
+        self.exec_engine.execute(command)
+
+    def _cancel(self) -> None:
+        order = self._scenario_order()
+        command = CancelOrder(
+            trader_id=order.trader_id,
+            strategy_id=order.strategy_id,
+            instrument_id=order.instrument_id,
+            client_order_id=order.client_order_id,
+            venue_order_id=order.venue_order_id,
+            command_id=UUID4(),
+            ts_init=0,
+        )
+        self.exec_engine.execute(command)
@@ -0,0 +1,139 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from dataclasses import dataclass
+from decimal import Decimal
+
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.events import OrderAccepted
+from nautilus_trader.model.events import OrderCanceled
+from nautilus_trader.model.events import OrderEvent
+from nautilus_trader.model.events import OrderFilled
+from nautilus_trader.model.events import OrderModifyRejected
+from nautilus_trader.model.events import OrderSubmitted
+from nautilus_trader.model.events import OrderUpdated
+
+
+@dataclass(frozen=True, kw_only=True)
+class ScenarioStep:
+    """
+    The base class for all conformance scenario steps.
+
+    The `expected` order event types must be emitted (in order) as a result of the step.
+
+    """
+
+    expected: tuple[type[OrderEvent], ...] = ()
+
+
+@dataclass(frozen=True, kw_only=True)
+class Submit(ScenarioStep):
+    """
+    Submit a limit order for the scenario instrument through the execution engine.
+    """
+
+    side: OrderSide
+    quantity: str
+    price: str
+
+
+@dataclass(frozen=True, kw_only=True)
+class Fill(ScenarioStep):
+    """
+    Fill the scenario order at the mock venue.
+    """
+
+    quantity: str
+    price: str
+
+
+@dataclass(frozen=True, kw_only=True)
+class Modify(ScenarioStep):
+    """
+    Modify the scenario order through the execution engine.
+    """
+
+    quantity: str | None = None
+    price: str | None = None
+
+
+@dataclass(frozen=True, kw_only=True)
+class Cancel(ScenarioStep):
+    """
+    Cancel the scenario order through the execution engine.
+    """
+
+
+@dataclass(frozen=True, kw_only=True)
+class Disconnect(ScenarioStep):
+    """
+    Disconnect the mock venue, dropping connections and rejecting requests.
+    """
+
+
+@dataclass(frozen=True, kw_only=True)
+class Reconnect(ScenarioStep):
+    """
+    Reconnect the mock venue, accepting requests again.
+    """
+
+
+def standard_scenario(
+    side: OrderSide,
+    quantity: str,
+    price: str,
+    amended_price: str,
+    supports_modify: bool = True,
+) -> list[ScenarioStep]:
+    """
+    Return the standard conformance scenario for an execution client.
+
+    A limit order is submitted and partially filled, then amended, and filled again
+    while the venue is disconnected (which must be recovered once reconnected), before
+    the remaining quantity is canceled.
+
+    Parameters
+    ----------
+    side : OrderSide
+        The order side.
+    quantity : str
+        The order quantity (each fill is a quarter of the quantity).
+    price : str
+        The order limit price.
+    amended_price : str
+        The limit price to amend the order to.
+    supports_modify : bool, default True
+        If the execution client supports modifying orders (otherwise the modify must be
+        rejected).
+
+    Returns
+    -------
+    list[ScenarioStep]
+
+    """
+    fill_qty = str(Decimal(quantity) / 4)
+    fill_px = amended_price if supports_modify else price
+    return [
+        Submit(side=side, quantity=quantity, price=price, expected=(OrderSubmitted, OrderAccepted)),
+        Fill(quantity=fill_qty, price=price, expected=(OrderFilled,)),
+        Modify(
+            price=amended_price,
+            expected=(OrderUpdated,) if supports_modify else (OrderModifyRejected,),
+        ),
+        Disconnect(),
+        Fill(quantity=fill_qty, price=fill_px),
+        Reconnect(expected=(OrderFilled,)),
+        Cancel(expected=(OrderCanceled,)),
+    ]
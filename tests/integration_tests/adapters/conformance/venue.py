@@ -0,0 +1,294 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import time
+from dataclasses import dataclass
+from decimal import Decimal
+from typing import Any
+
+import msgspec
+from aiohttp import WSMsgType
+from aiohttp import web
+from aiohttp.test_utils import TestServer
+
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.enums import OrderType
+
+
+@dataclass
+class MockVenueOrder:
+    """
+    Represents the state of an order held by a mock venue.
+    """
+
+    venue_order_id: str
+    client_order_id: str
+    symbol: str
+    side: OrderSide
+    order_type: OrderType
+    quantity: Decimal
+    price: Decimal | None
+    filled_qty: Decimal = Decimal(0)
+    avg_px: Decimal = Decimal(0)
+    status: OrderStatus = OrderStatus.ACCEPTED
+    ts_last: int = 0
+
+    @property
+    def leaves_qty(self) -> Decimal:
+        return self.quantity - self.filled_qty
+
+    @property
+    def is_open(self) -> bool:
+        return self.status in (OrderStatus.ACCEPTED, OrderStatus.PARTIALLY_FILLED)
+
+
+@dataclass(frozen=True)
+class MockVenueRequest:
+    """
+    Represents a request received by a mock venue.
+    """
+
+    method: str
+    path: str
+    query: dict[str, str]
+    body: bytes
+
+
+class MockVenue:
+    """
+    Provides a scripted mock venue serving a REST and WebSocket API for adapter tests.
+
+    The venue holds the state of its orders, which conformance scenarios act on directly
+    (e.g. to fill an order). Subclasses translate the venue API to and from this state by
+    implementing `routes`, and for streaming venues implement `order_updates` to push
+    order updates to the connected WebSocket clients.
+
+    While the venue is disconnected, all WebSocket connections are closed and every
+    request (including WebSocket upgrades) is answered with HTTP 503.
+
+    """
+
+    ws_path = "/ws"
+
+    def __init__(self) -> None:
+        self.orders: dict[str, MockVenueOrder] = {}
+        self.requests: list[MockVenueRequest] = []
+        self.is_available = True
+        self._websockets: set[web.WebSocketResponse] = set()
+        self._order_id_count = 0
+        self._server: TestServer | None = None
+
+    @property
+    def http_url(self) -> str:
+        """
+        Return the base HTTP URL of the running venue.
+        """
+        if self._server is None:
+            raise RuntimeError("Mock venue not started")
+        return str(self._server.make_url("")).rstrip("/")
+
+    @property
+    def ws_url(self) -> str:
+        """
+        Return the WebSocket URL of the running venue.
+        """
+        return self.http_url.replace("http", "ws", 1) + self.ws_path
+
+    def routes(self) -> list[web.RouteDef]:
+        """
+        Return the REST routes of the venue API.
+        """
+        raise NotImplementedError("method `routes` must be implemented in the subclass")
+
+    def order_updates(
+        self,
+        order: MockVenueOrder,
+        fill: tuple[Decimal, Decimal] | None,
+    ) -> list[Any]:
+        """
+        Return the WebSocket messages to push for an update of the given order.
+
+        The `fill` is the quantity and price of the last fill, if the update is a fill.
+        The default implementation pushes nothing (for REST only venues).
+
+        """
+        return []
+
+    async def on_ws_message(self, ws: web.WebSocketResponse, data: Any) -> None:
+        """
+        Handle a message received from a WebSocket client (e.g. a subscription or login).
+        """
+
+    async def start(self) -> None:
+        app = web.Application(middlewares=[self._middleware])
+        app.add_routes([web.get(self.ws_path, self._handle_ws), *self.routes()])
+        self._server = TestServer(app)
+        await self._server.start_server()
+
+    async def stop(self) -> None:
+        await self._close_websockets()
+        if self._server is not None:
+            await self._server.close()
+            self._server = None
+
+    async def disconnect(self) -> None:
+        """
+        Drop all WebSocket connections and reject all requests until reconnected.
+        """
+        self.is_available = False
+        await self._close_websockets()
+
+    def reconnect(self) -> None:
+        """
+        Accept requests and WebSocket connections again.
+        """
+        self.is_available = True
+
+    async def push(self, message: Any) -> None:
+        """
+        Push the message to all connected WebSocket clients.
+        """
+        data = msgspec.json.encode(message).decode()
+        for ws in list(self._websockets):
+            if not ws.closed:
+                await ws.send_str(data)
+
+    # -- ORDERS -----------------------------------------------------------------------------------
+
+    def add_order(
+        self,
+        client_order_id: str,
+        symbol: str,
+        side: OrderSide,
+        order_type: OrderType,
+        quantity: Decimal,
+        price: Decimal | None,
+    ) -> MockVenueOrder:
+        self._order_id_count += 1
+        order = MockVenueOrder(
+            venue_order_id=str(self._order_id_count),
+            client_order_id=client_order_id,
+            symbol=symbol,
+            side=side,
+            order_type=order_type,
+            quantity=quantity,
+            price=price,
+            ts_last=_timestamp_ms(),
+        )
+        self.orders[order.venue_order_id] = order
+        return order
+
+    def find_order(
+        self,
+        venue_order_id: str | None = None,
+        client_order_id: str | None = None,
+    ) -> MockVenueOrder | None:
+        if venue_order_id is not None:
+            return self.orders.get(venue_order_id)
+
+        for order in self.orders.values():
+            if order.client_order_id == client_order_id:
+                return order
+        return None
+
+    async def fill_order(self, client_order_id: str, quantity: Decimal, price: Decimal) -> None:
+        order = self._open_order(client_order_id)
+        if quantity > order.leaves_qty:
+            raise ValueError(f"Fill quantity {quantity} exceeds leaves quantity {order.leaves_qty}")
+
+        filled_qty = order.filled_qty + quantity
+        order.avg_px = (order.avg_px * order.filled_qty + price * quantity) / filled_qty
+        order.filled_qty = filled_qty
+        order.status = (
+            OrderStatus.FILLED if filled_qty == order.quantity else OrderStatus.PARTIALLY_FILLED
+        )
+        order.ts_last = _timestamp_ms()
+        await self.publish(order, fill=(quantity, price))
+
+    async def modify_order(
+        self,
+        client_order_id: str,
+        quantity: Decimal | None = None,
+        price: Decimal | None = None,
+    ) -> MockVenueOrder:
+        order = self._open_order(client_order_id)
+        if quantity is not None:
+            order.quantity = quantity
+        if price is not None:
+            order.price = price
+        order.ts_last = _timestamp_ms()
+        await self.publish(order)
+        return order
+
+    async def cancel_order(self, client_order_id: str) -> MockVenueOrder:
+        order = self._open_order(client_order_id)
+        order.status = OrderStatus.CANCELED
+        order.ts_last = _timestamp_ms()
+        await self.publish(order)
+        return order
+
+    async def publish(
+        self,
+        order: MockVenueOrder,
+        fill: tuple[Decimal, Decimal] | None = None,
+    ) -> None:
+        for message in self.order_updates(order, fill):
+            await self.push(message)
+
+    def _open_order(self, client_order_id: str) -> MockVenueOrder:
+        order = self.find_order(client_order_id=client_order_id)
+        if order is None or not order.is_open:
+            raise ValueError(f"No open order for {client_order_id}")
+        return order
+
+    # -- INTERNAL ---------------------------------------------------------------------------------
+
+    @web.middleware
+    async def _middleware(self, request: web.Request, handler: Any) -> web.StreamResponse:
+        body = await request.read()
+        self.requests.append(
+            MockVenueRequest(
+                method=request.method,
+                path=request.path,
+                query=dict(request.query),
+                body=body,
+            ),
+        )
+        if not self.is_available:
+            return web.json_response({"msg": "Service unavailable"}, status=503)
+
+        return await handler(request)
+
+    async def _handle_ws(self, request: web.Request) -> web.WebSocketResponse:
+        ws = web.WebSocketResponse()
+        await ws.prepare(request)
+        self._websockets.add(ws)
+        try:
+            async for msg in ws:
+                if msg.type == WSMsgType.TEXT:
+                    await self.on_ws_message(ws, msgspec.json.decode(msg.data))
+        finally:
+            self._websockets.discard(ws)
+        return ws
+
+    async def _close_websockets(self) -> None:
+        for ws in list(self._websockets):
+            await ws.close()
+        self._websockets.clear()
+
+
+def _timestamp_ms() -> int:
+    return time.time_ns() // 1_000_000
@@ -0,0 +1,271 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from decimal import Decimal
+
+import pytest
+
+from nautilus_trader.adapters.deribit.common.enums import DeribitEnumParser
+from nautilus_trader.adapters.deribit.common.enums import DeribitOrderState
+from nautilus_trader.adapters.deribit.common.enums import DeribitOrderType
+from nautilus_trader.adapters.deribit.common.enums import DeribitTimeInForce
+from nautilus_trader.adapters.deribit.common.enums import DeribitTriggerType
+from nautilus_trader.adapters.deribit.common.parsing import decimal_places
+from nautilus_trader.adapters.deribit.common.parsing import parse_aggressor_side
+from nautilus_trader.adapters.deribit.common.symbol import DeribitSymbol
+from nautilus_trader.adapters.deribit.http.client import DeribitHttpClient
+from nautilus_trader.adapters.deribit.http.errors import DeribitError
+from nautilus_trader.adapters.deribit.http.errors import parse_error
+from nautilus_trader.adapters.deribit.http.errors import should_retry
+from nautilus_trader.adapters.deribit.http.trade import order_params
+from nautilus_trader.common.component import TestClock
+from nautilus_trader.core.datetime import millis_to_nanos
+from nautilus_trader.model.enums import AggressorSide
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import TimeInForce
+from nautilus_trader.model.enums import TriggerType
+from nautilus_trader.model.identifiers import InstrumentId
+
+
+@pytest.mark.parametrize(
+    ("symbol", "expected"),
+    [
+        ["BTC-PERPETUAL", "BTC-PERPETUAL.DERIBIT"],
+        ["eth-27dec24", "ETH-27DEC24.DERIBIT"],
+        ["BTC-27DEC24-100000-C", "BTC-27DEC24-100000-C.DERIBIT"],
+        ["BTC-FS-27DEC24_PERP", "BTC-FS-27DEC24_PERP.DERIBIT"],
+    ],
+)
+def test_deribit_symbol_to_instrument_id(symbol: str, expected: str) -> None:
+    # Arrange, Act
+    deribit_symbol = DeribitSymbol(symbol)
+
+    # Assert
+    assert deribit_symbol.to_instrument_id() == InstrumentId.from_str(expected)
+
+
+@pytest.mark.parametrize(
+    ("symbol", "expected"),
+    [
+        ["BTC-PERPETUAL", "BTC"],
+        ["BTC_USDC-PERPETUAL", "USDC"],
+        ["ETH-27DEC24-4000-P", "ETH"],
+    ],
+)
+def test_deribit_symbol_currency(symbol: str, expected: str) -> None:
+    # Arrange, Act, Assert
+    assert DeribitSymbol(symbol).currency == expected
+
+
+def test_deribit_symbol_strategy_type() -> None:
+    # Arrange, Act, Assert
+    assert DeribitSymbol("BTC-FS-27DEC24_PERP").strategy_type == "FS"
+    assert DeribitSymbol("BTC-CS-27DEC24-100000_110000").strategy_type == "CS"
+    with pytest.raises(ValueError):
+        _ = DeribitSymbol("BTC-PERPETUAL").strategy_type
+
+
+@pytest.mark.parametrize(
+    ("value", "expected"),
+    [
+        [Decimal("0.5"), 1],
+        [Decimal("0.0001"), 4],
+        [Decimal("10"), 0],
+        [Decimal("2.50"), 1],
+    ],
+)
+def test_decimal_places(value: Decimal, expected: int) -> None:
+    # Arrange, Act, Assert
+    assert decimal_places(value) == expected
+
+
+def test_parse_aggressor_side() -> None:
+    # Arrange, Act, Assert
+    assert parse_aggressor_side("buy") == AggressorSide.BUYER
+    assert parse_aggressor_side("sell") == AggressorSide.SELLER
+    with pytest.raises(ValueError):
+        parse_aggressor_side("zero")
+
+
+def test_enum_parser_round_trips_order_types() -> None:
+    # Arrange
+    parser = DeribitEnumParser()
+
+    # Act, Assert
+    for order_type, deribit_order_type in parser.nautilus_to_deribit_order_type.items():
+        assert parser.parse_nautilus_order_type(order_type) == deribit_order_type
+        assert parser.parse_deribit_order_type(deribit_order_type) == order_type
+
+
+@pytest.mark.parametrize(
+    ("order_state", "is_partially_filled", "expected"),
+    [
+        [DeribitOrderState.OPEN, False, OrderStatus.ACCEPTED],
+        [DeribitOrderState.OPEN, True, OrderStatus.PARTIALLY_FILLED],
+        [DeribitOrderState.UNTRIGGERED, False, OrderStatus.ACCEPTED],
+        [DeribitOrderState.CANCELLED, True, OrderStatus.CANCELED],
+        [DeribitOrderState.FILLED, True, OrderStatus.FILLED],
+    ],
+)
+def test_enum_parser_order_status(
+    order_state: DeribitOrderState,
+    is_partially_filled: bool,
+    expected: OrderStatus,
+) -> None:
+    # Arrange
+    parser = DeribitEnumParser()
+
+    # Act, Assert
+    assert parser.parse_deribit_order_status(order_state, is_partially_filled) == expected
+
+
+def test_enum_parser_trigger_and_time_in_force() -> None:
+    # Arrange
+    parser = DeribitEnumParser()
+
+    # Act, Assert
+    assert parser.parse_nautilus_trigger_type(TriggerType.DEFAULT) == DeribitTriggerType.LAST_PRICE
+    assert parser.parse_deribit_trigger_type(None) == TriggerType.NO_TRIGGER
+    assert parser.parse_nautilus_time_in_force(TimeInForce.DAY) == DeribitTimeInForce.GTD
+    assert parser.parse_nautilus_order_type(OrderType.LIMIT_IF_TOUCHED) == (
+        DeribitOrderType.TAKE_LIMIT
+    )
+    with pytest.raises(RuntimeError):
+        parser.parse_nautilus_trigger_type(TriggerType.BID_ASK)
+
+
+def test_order_params() -> None:
+    # Arrange, Act
+    params = order_params(
+        instrument_name="BTC-PERPETUAL",
+        order_type=DeribitOrderType.STOP_LIMIT,
+        amount="100",
+        client_order_id="O-123",
+        time_in_force=DeribitTimeInForce.GTC,
+        price="50000.0",
+        trigger_price="49000.0",
+        trigger=DeribitTriggerType.MARK_PRICE,
+        post_only=True,
+        reduce_only=True,
+    )
+
+    # Assert
+    assert params == {
+        "instrument_name": "BTC-PERPETUAL",
+        "amount": "100",
+        "type": "stop_limit",
+        "label": "O-123",
+        "time_in_force": "good_til_cancelled",
+        "price": "50000.0",
+        "trigger_price": "49000.0",
+        "trigger": "mark_price",
+        "post_only": True,
+        "reject_post_only": True,
+        "reduce_only": True,
+    }
+
+
+def test_order_params_market_order_has_no_time_in_force() -> None:
+    # Arrange, Act
+    params = order_params(
+        instrument_name="BTC-27DEC24-100000-C",
+        order_type=DeribitOrderType.MARKET,
+        amount="0.5",
+        client_order_id="O-123",
+        time_in_force=DeribitTimeInForce.IOC,
+    )
+
+    # Assert
+    assert "time_in_force" not in params
+    assert params["type"] == "market"
+
+
+def test_sign_request() -> None:
+    # Arrange
+    clock = TestClock()
+    clock.set_time(millis_to_nanos(1_700_000_000_000))
+    client = DeribitHttpClient(
+        clock=clock,
+        base_url="https://test.deribit.com",
+        api_key="TEST_API_KEY",
+        api_secret="TEST_API_SECRET",
+    )
+
+    # Act
+    header = client.sign(
+        "GET",
+        "/api/v2/private/get_account_summary?currency=BTC",
+        nonce="abcd1234",
+    )
+
+    # Assert
+    assert header == (
+        "deri-hmac-sha256 id=TEST_API_KEY,ts=1700000000000,"
+        "sig=ec213a3fc57693d5b4f9e01262c64b0f7a89eaa7ebf9f1e8e42463a4552f5540,nonce=abcd1234"
+    )
+
+
+def test_sign_request_without_credentials_raises() -> None:
+    # Arrange
+    client = DeribitHttpClient(clock=TestClock(), base_url="https://test.deribit.com")
+
+    # Act, Assert
+    assert not client.has_credentials
+    with pytest.raises(RuntimeError):
+        client.sign("GET", "/api/v2/private/get_positions")
+
+
+def test_parse_error_with_data_reason() -> None:
+    # Arrange
+    body = {
+        "jsonrpc": "2.0",
+        "error": {
+            "code": -32602,
+            "message": "Invalid params",
+            "data": {"reason": "must be a multiple of contract size", "param": "amount"},
+        },
+    }
+
+    # Act
+    error = parse_error(400, body)
+
+    # Assert
+    assert error.code == -32602
+    assert error.message == "Invalid params (amount: must be a multiple of contract size)"
+
+
+def test_parse_error_without_json_rpc_error() -> None:
+    # Arrange, Act
+    error = parse_error(502, "Bad Gateway")
+
+    # Assert
+    assert error.code == 502
+    assert error.message == "Bad Gateway"
+
+
+@pytest.mark.parametrize(
+    ("error", "expected"),
+    [
+        [DeribitError(code=10028, message="too_many_requests"), True],
+        [DeribitError(code=503, message="Service Unavailable"), True],
+        [DeribitError(code=10009, message="not_enough_funds"), False],
+        [ValueError("other"), False],
+    ],
+)
+def test_should_retry(error: BaseException, expected: bool) -> None:
+    # Arrange, Act, Assert
+    assert should_retry(error) == expected
+
@@ -0,0 +1,566 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from decimal import Decimal
+
+import msgspec
+import pytest
+
+from nautilus_trader.adapters.deribit.common.enums import DeribitEnumParser
+from nautilus_trader.adapters.deribit.common.parsing import amount_to_quantity
+from nautilus_trader.adapters.deribit.common.parsing import quantity_to_amount
+from nautilus_trader.adapters.deribit.schemas.instrument import DeribitCombo
+from nautilus_trader.adapters.deribit.schemas.instrument import DeribitInstrument
+from nautilus_trader.adapters.deribit.schemas.order import DeribitOrder
+from nautilus_trader.adapters.deribit.schemas.order import DeribitUserTrade
+from nautilus_trader.adapters.deribit.schemas.response import DeribitResponse
+from nautilus_trader.adapters.deribit.schemas.user import DeribitAccountSummary
+from nautilus_trader.adapters.deribit.schemas.user import DeribitPosition
+from nautilus_trader.adapters.deribit.schemas.user import parse_account_summaries
+from nautilus_trader.adapters.deribit.schemas.ws import DeribitWsBookMsg
+from nautilus_trader.adapters.deribit.schemas.ws import DeribitWsQuoteMsg
+from nautilus_trader.adapters.deribit.schemas.ws import DeribitWsTradesMsg
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.model.enums import AggressorSide
+from nautilus_trader.model.enums import BookAction
+from nautilus_trader.model.enums import LiquiditySide
+from nautilus_trader.model.enums import OptionKind
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import PositionSide
+from nautilus_trader.model.enums import RecordFlag
+from nautilus_trader.model.enums import TriggerType
+from nautilus_trader.model.identifiers import AccountId
+from nautilus_trader.model.identifiers import ClientOrderId
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.instruments import CryptoFuture
+from nautilus_trader.model.instruments import CryptoPerpetual
+from nautilus_trader.model.instruments import FuturesSpread
+from nautilus_trader.model.instruments import OptionContract
+from nautilus_trader.model.instruments import OptionSpread
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import Money
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+
+
+ACCOUNT_ID = AccountId("DERIBIT-master")
+BTC = Currency.from_str("BTC")
+USD = Currency.from_str("USD")
+
+BTC_PERPETUAL_RAW = (
+    b'{"instrument_name":"BTC-PERPETUAL","kind":"future","base_currency":"BTC",'
+    b'"quote_currency":"USD","settlement_currency":"BTC","counter_currency":"USD",'
+    b'"tick_size":0.5,"min_trade_amount":10,"contract_size":10,'
+    b'"creation_timestamp":1534242287000,"expiration_timestamp":32503708800000,'
+    b'"is_active":true,"settlement_period":"perpetual","instrument_type":"reversed",'
+    b'"future_type":"reversed","maker_commission":0.0,"taker_commission":0.0005,'
+    b'"max_leverage":50,"price_index":"btc_usd","instrument_id":124972}'
+)
+
+BTC_OPTION_RAW = (
+    b'{"instrument_name":"BTC-27DEC24-100000-C","kind":"option","base_currency":"BTC",'
+    b'"quote_currency":"BTC","settlement_currency":"BTC","counter_currency":"USD",'
+    b'"tick_size":0.0001,"min_trade_amount":0.1,"contract_size":1,'
+    b'"creation_timestamp":1719561600000,"expiration_timestamp":1735286400000,'
+    b'"is_active":true,"settlement_period":"month","strike":100000.0,"option_type":"call",'
+    b'"tick_size_steps":[{"above_price":0.005,"tick_size":0.0005}],'
+    b'"maker_commission":0.0003,"taker_commission":0.0003,"price_index":"btc_usd"}'
+)
+
+
+def _decode_instrument(raw: bytes) -> DeribitInstrument:
+    return msgspec.json.decode(raw, type=DeribitInstrument)
+
+
+def _btc_perpetual() -> CryptoPerpetual:
+    instrument = _decode_instrument(BTC_PERPETUAL_RAW).parse_to_instrument(
+        base_currency=BTC,
+        quote_currency=USD,
+        settlement_currency=BTC,
+        legs=None,
+        ts_event=0,
+        ts_init=0,
+    )
+    assert isinstance(instrument, CryptoPerpetual)
+    return instrument
+
+
+def _btc_option() -> OptionContract:
+    instrument = _decode_instrument(BTC_OPTION_RAW).parse_to_instrument(
+        base_currency=BTC,
+        quote_currency=BTC,
+        settlement_currency=BTC,
+        legs=None,
+        ts_event=0,
+        ts_init=0,
+    )
+    assert isinstance(instrument, OptionContract)
+    return instrument
+
+
+def test_decode_instruments_response() -> None:
+    # Arrange
+    raw = b'{"jsonrpc":"2.0","result":[' + BTC_PERPETUAL_RAW + b'],"usIn":1,"usOut":2}'
+
+    # Act
+    response = msgspec.json.decode(raw, type=DeribitResponse[list[DeribitInstrument]])
+
+    # Assert
+    assert len(response.result) == 1
+    assert response.result[0].is_perpetual
+    assert response.result[0].is_inverse
+
+
+def test_parse_inverse_perpetual_to_instrument() -> None:
+    # Arrange, Act
+    instrument = _btc_perpetual()
+
+    # Assert
+    assert instrument.id == InstrumentId.from_str("BTC-PERPETUAL.DERIBIT")
+    assert instrument.is_inverse
+    assert instrument.base_currency == BTC
+    assert instrument.quote_currency == USD
+    assert instrument.settlement_currency == BTC
+    assert instrument.price_increment == Price.from_str("0.5")
+    assert instrument.size_increment == Quantity.from_int(10)
+    assert instrument.min_quantity == Quantity.from_int(10)
+    assert instrument.multiplier == Quantity.from_int(1)
+    assert instrument.taker_fee == Decimal("0.0005")
+    assert instrument.info["instrument_name"] == "BTC-PERPETUAL"
+
+
+def test_parse_future_to_instrument() -> None:
+    # Arrange
+    raw = (
+        b'{"instrument_name":"BTC-27DEC24","kind":"future","base_currency":"BTC",'
+        b'"quote_currency":"USD","settlement_currency":"BTC","tick_size":2.5,'
+        b'"min_trade_amount":10,"contract_size":10,"creation_timestamp":1719561600000,'
+        b'"expiration_timestamp":1735286400000,"is_active":true,"settlement_period":"month",'
+        b'"instrument_type":"reversed"}'
+    )
+
+    # Act
+    instrument = _decode_instrument(raw).parse_to_instrument(
+        base_currency=BTC,
+        quote_currency=USD,
+        settlement_currency=BTC,
+        legs=None,
+        ts_event=0,
+        ts_init=0,
+    )
+
+    # Assert
+    assert isinstance(instrument, CryptoFuture)
+    assert instrument.underlying == BTC
+    assert instrument.price_increment == Price.from_str("2.5")
+    assert instrument.activation_ns == 1_719_561_600_000_000_000
+    assert instrument.expiration_ns == 1_735_286_400_000_000_000
+
+
+def test_parse_option_to_instrument() -> None:
+    # Arrange, Act
+    instrument = _btc_option()
+
+    # Assert
+    assert instrument.id == InstrumentId.from_str("BTC-27DEC24-100000-C.DERIBIT")
+    assert instrument.option_kind == OptionKind.CALL
+    assert instrument.strike_price == Price.from_int(100_000)
+    assert instrument.underlying == "BTC"
+    assert instrument.quote_currency == BTC
+    assert instrument.price_increment == Price.from_str("0.0001")
+    assert instrument.multiplier == Quantity.from_str("0.1")
+    assert instrument.expiration_ns == 1_735_286_400_000_000_000
+    assert instrument.info["tick_size_steps"] == [{"above_price": 0.005, "tick_size": 0.0005}]
+
+
+def test_parse_option_without_strike_raises() -> None:
+    # Arrange
+    raw = BTC_OPTION_RAW.replace(b'"strike":100000.0,', b"")
+
+    # Act, Assert
+    with pytest.raises(ValueError, match="no strike"):
+        _decode_instrument(raw).parse_to_instrument(
+            base_currency=BTC,
+            quote_currency=BTC,
+            settlement_currency=BTC,
+            legs=None,
+            ts_event=0,
+            ts_init=0,
+        )
+
+
+@pytest.mark.parametrize(
+    ("kind", "name", "expected_type"),
+    [
+        ["future_combo", "BTC-FS-27DEC24_PERP", FuturesSpread],
+        ["option_combo", "BTC-CS-27DEC24-100000_110000", OptionSpread],
+    ],
+)
+def test_parse_combo_to_instrument(kind: str, name: str, expected_type: type) -> None:
+    # Arrange
+    raw = (
+        b'{"instrument_name":"' + name.encode() + b'","kind":"' + kind.encode() + b'",'
+        b'"base_currency":"BTC","quote_currency":"USD","settlement_currency":"BTC",'
+        b'"tick_size":0.5,"min_trade_amount":10,"contract_size":10,'
+        b'"creation_timestamp":1719561600000,"expiration_timestamp":1735286400000,'
+        b'"is_active":true}'
+    )
+    combo = msgspec.json.decode(
+        b'{"id":"' + name.encode() + b'","state":"active","legs":['
+        b'{"instrument_name":"BTC-PERPETUAL","amount":-1},'
+        b'{"instrument_name":"BTC-27DEC24","amount":1}]}',
+        type=DeribitCombo,
+    )
+
+    # Act
+    instrument = _decode_instrument(raw).parse_to_instrument(
+        base_currency=BTC,
+        quote_currency=USD,
+        settlement_currency=BTC,
+        legs=combo.legs,
+        ts_event=0,
+        ts_init=0,
+    )
+
+    # Assert
+    assert isinstance(instrument, expected_type)
+    assert instrument.strategy_type == name.split("-")[1]
+    assert instrument.multiplier == Quantity.from_int(10)
+    assert instrument.info["legs"] == [
+        {"instrument_name": "BTC-PERPETUAL", "amount": -1},
+        {"instrument_name": "BTC-27DEC24", "amount": 1},
+    ]
+
+
+def test_parse_spot_instrument_raises() -> None:
+    # Arrange
+    raw = (
+        b'{"instrument_name":"BTC_USDC","kind":"spot","base_currency":"BTC",'
+        b'"quote_currency":"USDC","tick_size":1,"min_trade_amount":0.0001,"contract_size":0.0001,'
+        b'"creation_timestamp":1719561600000,"expiration_timestamp":32503708800000}'
+    )
+
+    # Act, Assert
+    with pytest.raises(ValueError, match="unsupported instrument kind"):
+        _decode_instrument(raw).parse_to_instrument(
+            base_currency=BTC,
+            quote_currency=Currency.from_str("USDC"),
+            settlement_currency=Currency.from_str("USDC"),
+            legs=None,
+            ts_event=0,
+            ts_init=0,
+        )
+
+
+@pytest.mark.parametrize(
+    ("amount", "expected_quantity"),
+    [
+        [0.1, Quantity.from_int(1)],
+        [2.5, Quantity.from_int(25)],
+    ],
+)
+def test_option_amount_to_quantity_round_trip(amount: float, expected_quantity: Quantity) -> None:
+    # Arrange
+    instrument = _btc_option()
+
+    # Act
+    quantity = amount_to_quantity(amount, instrument)
+
+    # Assert
+    assert quantity == expected_quantity
+    assert quantity_to_amount(quantity, instrument) == str(amount)
+
+
+def test_perpetual_amount_to_quantity_round_trip() -> None:
+    # Arrange
+    instrument = _btc_perpetual()
+
+    # Act
+    quantity = amount_to_quantity(250, instrument)
+
+    # Assert
+    assert quantity == Quantity.from_int(250)
+    assert quantity_to_amount(quantity, instrument) == "250"
+
+
+def test_parse_account_summaries_with_cross_collateral() -> None:
+    # Arrange
+    btc = msgspec.json.decode(
+        b'{"currency":"BTC","equity":1.2,"balance":1.0,"margin_balance":1.15,'
+        b'"available_funds":0.9,"initial_margin":0.25,"maintenance_margin":0.1,'
+        b'"margin_model":"cross_pm","portfolio_margining_enabled":true,'
+        b'"cross_collateral_enabled":true,"total_initial_margin_usd":25000.0,'
+        b'"total_maintenance_margin_usd":10000.0}',
+        type=DeribitAccountSummary,
+    )
+    eth = msgspec.json.decode(
+        b'{"currency":"ETH","equity":10.0,"balance":10.0,"available_funds":12.0,'
+        b'"initial_margin":0.0,"maintenance_margin":0.0,"cross_collateral_enabled":true,'
+        b'"total_initial_margin_usd":25000.0,"total_maintenance_margin_usd":10000.0}',
+        type=DeribitAccountSummary,
+    )
+    empty = msgspec.json.decode(
+        b'{"currency":"USDC","equity":0.0,"balance":0.0,"available_funds":0.0,'
+        b'"initial_margin":0.0,"maintenance_margin":0.0}',
+        type=DeribitAccountSummary,
+    )
+
+    # Act
+    balances, margins = parse_account_summaries([btc, eth, empty])
+
+    # Assert
+    assert len(balances) == 2
+    assert balances[0].total == Money(Decimal("1.15"), BTC)
+    assert balances[0].free == Money(Decimal("0.9"), BTC)
+    assert balances[0].locked == Money(Decimal("0.25"), BTC)
+    assert balances[1].free == balances[1].total  # Available funds clamped to the total
+    assert len(margins) == 1
+    assert margins[0].initial == Money(Decimal("25000"), USD)
+    assert margins[0].maintenance == Money(Decimal("10000"), USD)
+    assert margins[0].instrument_id is None
+
+
+def test_parse_account_summary_margin_per_currency() -> None:
+    # Arrange
+    summary = msgspec.json.decode(
+        b'{"currency":"BTC","equity":1.0,"balance":1.0,"available_funds":0.8,'
+        b'"initial_margin":0.2,"maintenance_margin":0.05,"margin_model":"segregated_sm",'
+        b'"cross_collateral_enabled":false}',
+        type=DeribitAccountSummary,
+    )
+
+    # Act
+    margin = summary.parse_to_margin_balance()
+
+    # Assert
+    assert margin is not None
+    assert margin.initial == Money(Decimal("0.2"), BTC)
+    assert margin.maintenance == Money(Decimal("0.05"), BTC)
+
+
+def test_parse_option_position_to_position_status_report() -> None:
+    # Arrange
+    position = msgspec.json.decode(
+        b'{"instrument_name":"BTC-27DEC24-100000-C","kind":"option","size":-1.5,'
+        b'"direction":"sell","average_price":0.025,"mark_price":0.03}',
+        type=DeribitPosition,
+    )
+
+    # Act
+    report = position.parse_to_position_status_report(
+        account_id=ACCOUNT_ID,
+        instrument=_btc_option(),
+        report_id=UUID4(),
+        ts_init=0,
+    )
+
+    # Assert
+    assert report.position_side == PositionSide.SHORT
+    assert report.quantity == Quantity.from_int(15)
+
+
+def test_parse_stop_order_to_order_status_report() -> None:
+    # Arrange
+    deribit_order = msgspec.json.decode(
+        b'{"order_id":"29847566","instrument_name":"BTC-PERPETUAL","direction":"sell",'
+        b'"amount":200,"filled_amount":50,"average_price":94000.0,"price":94000.0,'
+        b'"order_type":"stop_limit","order_state":"open","time_in_force":"good_til_cancelled",'
+        b'"trigger_price":94500.0,"trigger":"mark_price","triggered":true,"reduce_only":true,'
+        b'"post_only":false,"label":"O-123","creation_timestamp":1735689600000,'
+        b'"last_update_timestamp":1735689601000}',
+        type=DeribitOrder,
+    )
+
+    # Act
+    report = deribit_order.parse_to_order_status_report(
+        account_id=ACCOUNT_ID,
+        instrument=_btc_perpetual(),
+        client_order_id=ClientOrderId("O-123"),
+        report_id=UUID4(),
+        enum_parser=DeribitEnumParser(),
+        ts_init=0,
+    )
+
+    # Assert
+    assert report.order_side == OrderSide.SELL
+    assert report.order_type == OrderType.STOP_LIMIT
+    assert report.order_status == OrderStatus.PARTIALLY_FILLED
+    assert report.price == Price.from_str("94000.0")
+    assert report.trigger_price == Price.from_str("94500.0")
+    assert report.trigger_type == TriggerType.MARK_PRICE
+    assert report.quantity == Quantity.from_int(200)
+    assert report.filled_qty == Quantity.from_int(50)
+    assert report.reduce_only
+    assert report.ts_last == 1_735_689_601_000_000_000
+
+
+def test_parse_market_order_without_price() -> None:
+    # Arrange
+    deribit_order = msgspec.json.decode(
+        b'{"order_id":"123","instrument_name":"BTC-27DEC24-100000-C","direction":"buy",'
+        b'"amount":0.5,"filled_amount":0.5,"average_price":0.0255,"price":"market_price",'
+        b'"order_type":"market","order_state":"filled","creation_timestamp":1735689600000,'
+        b'"last_update_timestamp":1735689600000}',
+        type=DeribitOrder,
+    )
+
+    # Act
+    report = deribit_order.parse_to_order_status_report(
+        account_id=ACCOUNT_ID,
+        instrument=_btc_option(),
+        client_order_id=None,
+        report_id=UUID4(),
+        enum_parser=DeribitEnumParser(),
+        ts_init=0,
+    )
+
+    # Assert
+    assert report.price is None
+    assert report.order_status == OrderStatus.FILLED
+    assert report.quantity == Quantity.from_int(5)
+    assert report.avg_px == Decimal("0.0255")
+
+
+def test_parse_block_trade_to_fill_report() -> None:
+    # Arrange
+    trade = msgspec.json.decode(
+        b'{"trade_id":"BTC-123456","order_id":"123","instrument_name":"BTC-27DEC24-100000-C",'
+        b'"direction":"buy","amount":1.0,"price":0.025,"fee":-0.0001,"fee_currency":"BTC",'
+        b'"liquidity":"M","timestamp":1735689600000,"label":"O-123",'
+        b'"block_trade_id":"154"}',
+        type=DeribitUserTrade,
+    )
+
+    # Act
+    report = trade.parse_to_fill_report(
+        account_id=ACCOUNT_ID,
+        instrument=_btc_option(),
+        client_order_id=ClientOrderId("O-123"),
+        report_id=UUID4(),
+        enum_parser=DeribitEnumParser(),
+        ts_init=0,
+    )
+
+    # Assert
+    assert trade.is_block_trade
+    assert report.order_side == OrderSide.BUY
+    assert report.last_qty == Quantity.from_int(10)
+    assert report.last_px == Price.from_str("0.0250")
+    assert report.commission == Money(Decimal("-0.0001"), BTC)
+    assert report.liquidity_side == LiquiditySide.MAKER
+    assert report.trade_id.value == "BTC-123456"
+
+
+def test_parse_book_snapshot_to_deltas() -> None:
+    # Arrange
+    raw = (
+        b'{"jsonrpc":"2.0","method":"subscription","params":{"channel":"book.BTC-PERPETUAL.100ms",'
+        b'"data":{"type":"snapshot","timestamp":1735689600000,"instrument_name":"BTC-PERPETUAL",'
+        b'"change_id":100,"bids":[["new",95000.0,1000.0]],"asks":[["new",95000.5,2000.0]]}}}'
+    )
+    data = msgspec.json.decode(raw, type=DeribitWsBookMsg).params.data
+
+    # Act
+    deltas = data.parse_to_deltas(_btc_perpetual(), ts_init=0)
+
+    # Assert
+    assert len(deltas.deltas) == 3
+    assert deltas.deltas[0].action == BookAction.CLEAR
+    assert deltas.deltas[1].action == BookAction.ADD
+    assert deltas.deltas[1].order.side == OrderSide.BUY
+    assert deltas.deltas[2].order.price == Price.from_str("95000.5")
+    assert deltas.deltas[2].order.size == Quantity.from_int(2000)
+    assert deltas.deltas[2].flags == RecordFlag.F_SNAPSHOT | RecordFlag.F_LAST
+    assert deltas.deltas[2].sequence == 100
+
+
+def test_parse_book_change_to_deltas() -> None:
+    # Arrange
+    raw = (
+        b'{"jsonrpc":"2.0","method":"subscription","params":{"channel":"book.BTC-PERPETUAL.100ms",'
+        b'"data":{"type":"change","timestamp":1735689600100,"instrument_name":"BTC-PERPETUAL",'
+        b'"prev_change_id":100,"change_id":101,'
+        b'"bids":[["change",95000.0,500.0],["delete",94999.5,0.0]],"asks":[]}}}'
+    )
+    data = msgspec.json.decode(raw, type=DeribitWsBookMsg).params.data
+
+    # Act
+    deltas = data.parse_to_deltas(_btc_perpetual(), ts_init=0)
+
+    # Assert
+    assert not data.is_snapshot
+    assert data.prev_change_id == 100
+    assert [delta.action for delta in deltas.deltas] == [BookAction.UPDATE, BookAction.DELETE]
+    assert deltas.deltas[1].flags == RecordFlag.F_LAST
+
+
+def test_parse_quote_to_quote_tick() -> None:
+    # Arrange
+    raw = (
+        b'{"jsonrpc":"2.0","method":"subscription","params":{"channel":"quote.BTC-27DEC24-100000-C",'
+        b'"data":{"timestamp":1735689600000,"instrument_name":"BTC-27DEC24-100000-C",'
+        b'"best_bid_price":0.0245,"best_bid_amount":2.0,"best_ask_price":0.0255,'
+        b'"best_ask_amount":0.5}}}'
+    )
+    data = msgspec.json.decode(raw, type=DeribitWsQuoteMsg).params.data
+
+    # Act
+    quote = data.parse_to_quote_tick(_btc_option(), ts_init=0)
+
+    # Assert
+    assert quote is not None
+    assert quote.bid_price == Price.from_str("0.0245")
+    assert quote.bid_size == Quantity.from_int(20)
+    assert quote.ask_size == Quantity.from_int(5)
+    assert quote.ts_event == 1_735_689_600_000_000_000
+
+
+def test_parse_one_sided_quote_returns_none() -> None:
+    # Arrange
+    raw = (
+        b'{"jsonrpc":"2.0","method":"subscription","params":{"channel":"quote.BTC-27DEC24-100000-C",'
+        b'"data":{"timestamp":1735689600000,"instrument_name":"BTC-27DEC24-100000-C",'
+        b'"best_bid_price":0.0245,"best_bid_amount":2.0,"best_ask_price":0.0,'
+        b'"best_ask_amount":0.0}}}'
+    )
+    data = msgspec.json.decode(raw, type=DeribitWsQuoteMsg).params.data
+
+    # Act, Assert
+    assert data.parse_to_quote_tick(_btc_option(), ts_init=0) is None
+
+
+def test_parse_trades_to_trade_ticks() -> None:
+    # Arrange
+    raw = (
+        b'{"jsonrpc":"2.0","method":"subscription","params":{"channel":"trades.BTC-PERPETUAL.100ms",'
+        b'"data":[{"trade_seq":1,"trade_id":"BTC-1","timestamp":1735689600000,"tick_direction":0,'
+        b'"price":95000.0,"instrument_name":"BTC-PERPETUAL","direction":"sell","amount":500.0},'
+        b'{"trade_seq":2,"trade_id":"BTC-2","timestamp":1735689600000,"tick_direction":0,'
+        b'"price":95000.0,"instrument_name":"BTC-PERPETUAL","direction":"buy","amount":100000.0,'
+        b'"block_trade_id":"154"}]}}'
+    )
+    data = msgspec.json.decode(raw, type=DeribitWsTradesMsg).params.data
+
+    # Act
+    trade = data[0].parse_to_trade_tick(_btc_perpetual(), ts_init=0)
+
+    # Assert
+    assert trade.aggressor_side == AggressorSide.SELLER
+    assert trade.size == Quantity.from_int(500)
+    assert trade.trade_id.value == "BTC-1"
+    assert not data[0].is_block_trade
+    assert data[1].is_block_trade
@@ -19,10 +19,16 @@ Unit tests for the GRPC client.
 import pytest
 from v4_proto.dydxprotocol.clob.order_pb2 import Order
 from v4_proto.dydxprotocol.clob.order_pb2 import OrderId
+from v4_proto.dydxprotocol.clob.tx_pb2 import MsgBatchCancel
+from v4_proto.dydxprotocol.clob.tx_pb2 import MsgCancelOrder
+from v4_proto.dydxprotocol.clob.tx_pb2 import MsgPlaceOrder
 from v4_proto.dydxprotocol.subaccounts.subaccount_pb2 import SubaccountId
 
+from nautilus_trader.adapters.dydx.grpc.account import is_short_term_message
 from nautilus_trader.adapters.dydx.grpc.order_builder import DYDXGRPCOrderType
 from nautilus_trader.adapters.dydx.grpc.order_builder import OrderBuilder
+from nautilus_trader.adapters.dydx.grpc.order_builder import OrderExecution
+from nautilus_trader.adapters.dydx.grpc.order_builder import OrderFlags
 
 
 @pytest.fixture
@@ -137,3 +143,84 @@ def test_create_order(order_builder: OrderBuilder) -> None:
     # Assert
     assert isinstance(result, Order)
     assert result == expected_result
+
+
+def test_create_conditional_stop_market_order(order_builder: OrderBuilder) -> None:
+    """
+    Test creating a conditional stop market order with IOC execution.
+    """
+    # Prepare
+    order_id = order_builder.create_order_id(
+        address="dydx1kzsvkf2ghjqlysuffdkhcdctknl4rsvcx5hkm5",
+        subaccount_number=0,
+        client_id=3,
+        order_flags=OrderFlags.CONDITIONAL,
+    )
+
+    # Act
+    result = order_builder.create_order(
+        order_id=order_id,
+        order_type=DYDXGRPCOrderType.STOP_MARKET,
+        side=Order.Side.SIDE_SELL,
+        size=0.001,
+        price=3000.0,
+        time_in_force=Order.TimeInForce.TIME_IN_FORCE_UNSPECIFIED,
+        reduce_only=True,
+        good_til_block_time=1_700_000_000,
+        execution=OrderExecution.IOC,
+        trigger_price=3100.0,
+    )
+
+    # Assert
+    assert result.time_in_force == Order.TimeInForce.TIME_IN_FORCE_IOC
+    assert result.condition_type == Order.ConditionType.CONDITION_TYPE_STOP_LOSS
+    assert result.conditional_order_trigger_subticks == 3_100_000_000
+    assert result.client_metadata == 1
+    assert result.good_til_block_time == 1_700_000_000
+
+
+@pytest.mark.parametrize(
+    ("order_flags", "expected_result"),
+    [
+        (OrderFlags.SHORT_TERM, True),
+        (OrderFlags.LONG_TERM, False),
+        (OrderFlags.CONDITIONAL, False),
+    ],
+)
+def test_is_short_term_message(
+    order_builder: OrderBuilder,
+    order_flags: OrderFlags,
+    expected_result: bool,
+) -> None:
+    """
+    Test only short-term order messages skip the account sequence number.
+    """
+    # Prepare
+    order_id = order_builder.create_order_id(
+        address="dydx1kzsvkf2ghjqlysuffdkhcdctknl4rsvcx5hkm5",
+        subaccount_number=0,
+        client_id=3,
+        order_flags=order_flags,
+    )
+    order = Order(order_id=order_id)
+
+    # Act, Assert
+    assert is_short_term_message(MsgPlaceOrder(order=order)) == expected_result
+    assert is_short_term_message(MsgCancelOrder(order_id=order_id)) == expected_result
+
+
+def test_is_short_term_message_batch_cancel() -> None:
+    """
+    Test a batch cancel message only contains short-term order cancels.
+    """
+    # Prepare
+    message = MsgBatchCancel(
+        subaccount_id=SubaccountId(
+            owner="dydx1kzsvkf2ghjqlysuffdkhcdctknl4rsvcx5hkm5",
+            number=0,
+        ),
+        good_til_block=100,
+    )
+
+    # Act, Assert
+    assert is_short_term_message(message)
@@ -0,0 +1,255 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from decimal import Decimal
+
+import pytest
+from eth_account import Account
+from eth_account.messages import encode_typed_data
+
+from nautilus_trader.adapters.hyperliquid.common.enums import HyperliquidEnumParser
+from nautilus_trader.adapters.hyperliquid.common.enums import HyperliquidTimeInForce
+from nautilus_trader.adapters.hyperliquid.common.enums import HyperliquidTpsl
+from nautilus_trader.adapters.hyperliquid.common.signing import HyperliquidSigner
+from nautilus_trader.adapters.hyperliquid.common.signing import client_order_id_to_cloid
+from nautilus_trader.adapters.hyperliquid.common.signing import float_to_wire
+from nautilus_trader.adapters.hyperliquid.common.signing import l1_action_typed_data
+from nautilus_trader.adapters.hyperliquid.common.signing import round_price
+from nautilus_trader.adapters.hyperliquid.common.symbol import HyperliquidSymbol
+from nautilus_trader.adapters.hyperliquid.http.exchange import order_action
+from nautilus_trader.adapters.hyperliquid.http.exchange import order_wire
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import TimeInForce
+from nautilus_trader.model.identifiers import ClientOrderId
+from nautilus_trader.model.identifiers import InstrumentId
+
+
+# Well known test private key (do not use with funds)
+TEST_PRIVATE_KEY = "0x0123456789012345678901234567890123456789012345678901234567890123"
+
+
+@pytest.mark.parametrize(
+    ("symbol", "expected_coin"),
+    [
+        ["BTC-PERP", "BTC"],
+        ["kPEPE-PERP", "kPEPE"],
+    ],
+)
+def test_hyperliquid_symbol(symbol: str, expected_coin: str) -> None:
+    # Arrange, Act
+    hyperliquid_symbol = HyperliquidSymbol(symbol)
+
+    # Assert
+    assert hyperliquid_symbol.coin == expected_coin
+    assert hyperliquid_symbol.to_instrument_id() == InstrumentId.from_str(f"{symbol}.HYPERLIQUID")
+
+
+def test_hyperliquid_symbol_invalid_suffix_raises() -> None:
+    # Arrange, Act, Assert
+    with pytest.raises(ValueError):
+        HyperliquidSymbol("BTC")
+
+
+def test_hyperliquid_symbol_from_coin() -> None:
+    # Arrange, Act
+    hyperliquid_symbol = HyperliquidSymbol.from_coin("ETH")
+
+    # Assert
+    assert hyperliquid_symbol == "ETH-PERP"
+
+
+@pytest.mark.parametrize(
+    ("value", "expected"),
+    [
+        [Decimal("100"), "100"],
+        [Decimal("100.50"), "100.5"],
+        [Decimal("0.00100000"), "0.001"],
+        [Decimal("-0"), "0"],
+        ["1E+3", "1000"],
+        [0.1, "0.1"],
+    ],
+)
+def test_float_to_wire(value: Decimal | str | float, expected: str) -> None:
+    # Arrange, Act, Assert
+    assert float_to_wire(value) == expected
+
+
+def test_float_to_wire_with_too_many_decimals_raises() -> None:
+    # Arrange, Act, Assert
+    with pytest.raises(ValueError):
+        float_to_wire(Decimal("0.000000001"))
+
+
+@pytest.mark.parametrize(
+    ("price", "price_precision", "expected"),
+    [
+        [Decimal("65123.7"), 1, Decimal("65124")],
+        [Decimal("123456.7"), 1, Decimal("123457")],
+        [Decimal("3456.789"), 2, Decimal("3456.8")],
+        [Decimal("0.123456789"), 5, Decimal("0.12346")],
+        [Decimal("0.00123456789"), 6, Decimal("0.001235")],
+    ],
+)
+def test_round_price(price: Decimal, price_precision: int, expected: Decimal) -> None:
+    # Arrange, Act, Assert
+    assert round_price(price, price_precision) == expected
+
+
+def test_client_order_id_to_cloid() -> None:
+    # Arrange, Act
+    cloid = client_order_id_to_cloid(ClientOrderId("O-20240101-000000-001-001-1"))
+
+    # Assert
+    assert cloid.startswith("0x")
+    assert len(cloid) == 34  # 16 bytes
+    assert cloid == client_order_id_to_cloid(ClientOrderId("O-20240101-000000-001-001-1"))
+
+
+@pytest.mark.parametrize(
+    ("status", "expected"),
+    [
+        ["open", OrderStatus.ACCEPTED],
+        ["filled", OrderStatus.FILLED],
+        ["triggered", OrderStatus.TRIGGERED],
+        ["canceled", OrderStatus.CANCELED],
+        ["marginCanceled", OrderStatus.CANCELED],
+        ["reduceOnlyCanceled", OrderStatus.CANCELED],
+        ["rejected", OrderStatus.REJECTED],
+        ["badAloPxRejected", OrderStatus.REJECTED],
+    ],
+)
+def test_parse_hyperliquid_order_status(status: str, expected: OrderStatus) -> None:
+    # Arrange
+    enum_parser = HyperliquidEnumParser()
+
+    # Act, Assert
+    assert enum_parser.parse_hyperliquid_order_status(status) == expected
+
+
+@pytest.mark.parametrize(
+    ("time_in_force", "is_post_only", "expected"),
+    [
+        [TimeInForce.GTC, False, HyperliquidTimeInForce.GTC],
+        [TimeInForce.IOC, False, HyperliquidTimeInForce.IOC],
+        [TimeInForce.GTC, True, HyperliquidTimeInForce.ALO],
+    ],
+)
+def test_parse_nautilus_time_in_force(
+    time_in_force: TimeInForce,
+    is_post_only: bool,
+    expected: HyperliquidTimeInForce,
+) -> None:
+    # Arrange
+    enum_parser = HyperliquidEnumParser()
+
+    # Act, Assert
+    assert enum_parser.parse_nautilus_time_in_force(time_in_force, is_post_only) == expected
+
+
+@pytest.mark.parametrize(
+    ("order_type", "expected"),
+    [
+        [OrderType.STOP_MARKET, HyperliquidTpsl.STOP_LOSS],
+        [OrderType.STOP_LIMIT, HyperliquidTpsl.STOP_LOSS],
+        [OrderType.MARKET_IF_TOUCHED, HyperliquidTpsl.TAKE_PROFIT],
+        [OrderType.LIMIT_IF_TOUCHED, HyperliquidTpsl.TAKE_PROFIT],
+    ],
+)
+def test_parse_nautilus_tpsl(order_type: OrderType, expected: HyperliquidTpsl) -> None:
+    # Arrange
+    enum_parser = HyperliquidEnumParser()
+
+    # Act, Assert
+    assert enum_parser.parse_nautilus_tpsl(order_type) == expected
+
+
+def test_order_wire_limit_key_order() -> None:
+    # Arrange, Act
+    wire = order_wire(
+        asset=0,
+        is_buy=True,
+        price="65000",
+        size="0.001",
+        reduce_only=False,
+        time_in_force=HyperliquidTimeInForce.ALO,
+        cloid="0x00000000000000000000000000000001",
+    )
+
+    # Assert
+    assert list(wire) == ["a", "b", "p", "s", "r", "t", "c"]
+    assert wire["t"] == {"limit": {"tif": "Alo"}}
+
+
+def test_order_wire_trigger() -> None:
+    # Arrange, Act
+    wire = order_wire(
+        asset=1,
+        is_buy=False,
+        price="2900",
+        size="0.1",
+        reduce_only=True,
+        trigger_price="3000",
+        is_market=True,
+        tpsl=HyperliquidTpsl.STOP_LOSS,
+    )
+
+    # Assert
+    assert "c" not in wire
+    assert wire["t"] == {"trigger": {"isMarket": True, "triggerPx": "3000", "tpsl": "sl"}}
+
+
+@pytest.mark.parametrize("is_mainnet", [True, False])
+@pytest.mark.parametrize("vault_address", [None, "0x1719884eb866cb12b2287399b15f7db5e7d775ea"])
+def test_sign_l1_action_recovers_signer(is_mainnet: bool, vault_address: str | None) -> None:
+    # Arrange
+    signer = HyperliquidSigner(TEST_PRIVATE_KEY, is_mainnet=is_mainnet)
+    action = order_action(
+        [
+            order_wire(
+                asset=0,
+                is_buy=True,
+                price="65000",
+                size="0.001",
+                reduce_only=False,
+                time_in_force=HyperliquidTimeInForce.GTC,
+            ),
+        ],
+    )
+    nonce = 1_700_000_000_000
+
+    # Act
+    signature = signer.sign_l1_action(action, nonce, vault_address)
+
+    # Assert
+    typed_data = l1_action_typed_data(action, vault_address, nonce, is_mainnet)
+    recovered = Account.recover_message(
+        encode_typed_data(full_message=typed_data),
+        vrs=(signature["v"], int(signature["r"], 16), int(signature["s"], 16)),
+    )
+    assert recovered == signer.address
+
+
+def test_sign_l1_action_depends_on_network() -> None:
+    # Arrange
+    action = {"type": "cancel", "cancels": [{"a": 0, "o": 1}]}
+    nonce = 1_700_000_000_000
+
+    # Act
+    mainnet = HyperliquidSigner(TEST_PRIVATE_KEY, is_mainnet=True).sign_l1_action(action, nonce)
+    testnet = HyperliquidSigner(TEST_PRIVATE_KEY, is_mainnet=False).sign_l1_action(action, nonce)
+
+    # Assert
+    assert mainnet != testnet
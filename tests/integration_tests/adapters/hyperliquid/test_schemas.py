@@ -0,0 +1,259 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from decimal import Decimal
+
+import msgspec
+
+from nautilus_trader.adapters.hyperliquid.schemas.exchange import HyperliquidExchangeResponse
+from nautilus_trader.adapters.hyperliquid.schemas.info import HyperliquidAssetMeta
+from nautilus_trader.adapters.hyperliquid.schemas.info import HyperliquidClearinghouseState
+from nautilus_trader.adapters.hyperliquid.schemas.ws import HyperliquidWsActiveAssetCtxMsg
+from nautilus_trader.adapters.hyperliquid.schemas.ws import HyperliquidWsBboMsg
+from nautilus_trader.adapters.hyperliquid.schemas.ws import HyperliquidWsBookMsg
+from nautilus_trader.adapters.hyperliquid.schemas.ws import HyperliquidWsPostMsg
+from nautilus_trader.adapters.hyperliquid.schemas.ws import HyperliquidWsTradesMsg
+from nautilus_trader.model.enums import AggressorSide
+from nautilus_trader.model.enums import BookAction
+from nautilus_trader.model.enums import RecordFlag
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+
+
+BTC_PERP = InstrumentId.from_str("BTC-PERP.HYPERLIQUID")
+
+
+def test_parse_asset_meta_to_instrument() -> None:
+    # Arrange
+    raw = b'{"name":"BTC","szDecimals":5,"maxLeverage":40}'
+    asset = msgspec.json.decode(raw, type=HyperliquidAssetMeta)
+
+    # Act
+    instrument = asset.parse_to_instrument(
+        asset_index=0,
+        base_currency=Currency.from_str("BTC"),
+        quote_currency=Currency.from_str("USDC"),
+        maker_fee=Decimal("0.00015"),
+        taker_fee=Decimal("0.00045"),
+        ts_event=0,
+        ts_init=0,
+    )
+
+    # Assert
+    assert instrument.id == BTC_PERP
+    assert instrument.raw_symbol.value == "BTC"
+    assert instrument.price_precision == 1
+    assert instrument.size_precision == 5
+    assert instrument.price_increment == Price.from_str("0.1")
+    assert instrument.size_increment == Quantity.from_str("0.00001")
+    assert instrument.margin_init == Decimal("0.025")
+    assert instrument.margin_maint == Decimal("0.0125")
+    assert instrument.info["assetIndex"] == 0
+
+
+def test_parse_clearinghouse_state_to_balances() -> None:
+    # Arrange
+    raw = (
+        b'{"marginSummary":{"accountValue":"1000.5","totalNtlPos":"500.0",'
+        b'"totalRawUsd":"1000.5","totalMarginUsed":"50.0"},'
+        b'"crossMarginSummary":{"accountValue":"1000.5","totalNtlPos":"500.0",'
+        b'"totalRawUsd":"1000.5","totalMarginUsed":"50.0"},'
+        b'"crossMaintenanceMarginUsed":"12.5","withdrawable":"950.5",'
+        b'"assetPositions":[],"time":1700000000000}'
+    )
+    state = msgspec.json.decode(raw, type=HyperliquidClearinghouseState)
+    usdc = Currency.from_str("USDC")
+
+    # Act
+    balance = state.parse_to_account_balance(usdc)
+    margin = state.parse_to_margin_balance(usdc)
+
+    # Assert
+    assert balance.total.as_decimal() == Decimal("1000.5")
+    assert balance.free.as_decimal() == Decimal("950.5")
+    assert balance.locked.as_decimal() == Decimal("50")
+    assert margin.initial.as_decimal() == Decimal("50")
+    assert margin.maintenance.as_decimal() == Decimal("12.5")
+
+
+def test_parse_ws_book_to_deltas() -> None:
+    # Arrange
+    raw = (
+        b'{"channel":"l2Book","data":{"coin":"BTC","time":1700000000000,"levels":['
+        b'[{"px":"65000.0","sz":"1.5","n":3},{"px":"64999.0","sz":"0.5","n":1}],'
+        b'[{"px":"65001.0","sz":"2.0","n":2}]]}}'
+    )
+    msg = msgspec.json.decode(raw, type=HyperliquidWsBookMsg)
+
+    # Act
+    deltas = msg.data.parse_to_deltas(
+        instrument_id=BTC_PERP,
+        price_precision=1,
+        size_precision=5,
+        ts_init=1,
+    )
+
+    # Assert
+    assert len(deltas.deltas) == 4
+    assert deltas.deltas[0].action == BookAction.CLEAR
+    assert deltas.deltas[1].order.price == Price.from_str("65000.0")
+    assert deltas.deltas[3].order.price == Price.from_str("65001.0")
+    assert deltas.deltas[3].flags == RecordFlag.F_LAST
+    assert deltas.deltas[1].ts_event == 1_700_000_000_000_000_000
+
+
+def test_parse_ws_bbo_to_quote_tick() -> None:
+    # Arrange
+    raw = (
+        b'{"channel":"bbo","data":{"coin":"BTC","time":1700000000000,"bbo":['
+        b'{"px":"65000.0","sz":"1.5","n":3},{"px":"65001.0","sz":"2.0","n":2}]}}'
+    )
+    msg = msgspec.json.decode(raw, type=HyperliquidWsBboMsg)
+
+    # Act
+    quote = msg.data.parse_to_quote_tick(
+        instrument_id=BTC_PERP,
+        price_precision=1,
+        size_precision=5,
+        ts_init=1,
+    )
+
+    # Assert
+    assert quote is not None
+    assert quote.bid_price == Price.from_str("65000.0")
+    assert quote.ask_price == Price.from_str("65001.0")
+    assert quote.ask_size == Quantity.from_str("2.00000")
+
+
+def test_parse_ws_bbo_with_empty_side_returns_none() -> None:
+    # Arrange
+    raw = (
+        b'{"channel":"bbo","data":{"coin":"BTC","time":1700000000000,"bbo":['
+        b'{"px":"65000.0","sz":"1.5","n":3},null]}}'
+    )
+    msg = msgspec.json.decode(raw, type=HyperliquidWsBboMsg)
+
+    # Act
+    quote = msg.data.parse_to_quote_tick(
+        instrument_id=BTC_PERP,
+        price_precision=1,
+        size_precision=5,
+        ts_init=1,
+    )
+
+    # Assert
+    assert quote is None
+
+
+def test_parse_ws_trades_to_trade_ticks() -> None:
+    # Arrange
+    raw = (
+        b'{"channel":"trades","data":[{"coin":"BTC","side":"A","px":"65000.0","sz":"0.1",'
+        b'"time":1700000000000,"tid":123,"hash":"0x00","users":["0x01","0x02"]}]}'
+    )
+    msg = msgspec.json.decode(raw, type=HyperliquidWsTradesMsg)
+
+    # Act
+    trade = msg.data[0].parse_to_trade_tick(
+        instrument_id=BTC_PERP,
+        price_precision=1,
+        size_precision=5,
+        ts_init=1,
+    )
+
+    # Assert
+    assert trade.aggressor_side == AggressorSide.SELLER
+    assert trade.trade_id.value == "123"
+    assert trade.size == Quantity.from_str("0.10000")
+
+
+def test_parse_ws_asset_ctx_to_funding_rate_update() -> None:
+    # Arrange
+    raw = (
+        b'{"channel":"activeAssetCtx","data":{"coin":"BTC","ctx":{"funding":"0.0000125",'
+        b'"openInterest":"1000.0","oraclePx":"65000.0","markPx":"65001.0"}}}'
+    )
+    msg = msgspec.json.decode(raw, type=HyperliquidWsActiveAssetCtxMsg)
+    ts_event = 1_700_000_100_000_000_000
+
+    # Act
+    update = msg.data.parse_to_funding_rate_update(
+        instrument_id=BTC_PERP,
+        ts_event=ts_event,
+        ts_init=ts_event,
+    )
+
+    # Assert
+    assert update.funding_rate == Decimal("0.0000125")
+    assert update.ts_next_funding == 1_700_002_800_000_000_000  # Next hour boundary
+
+
+def test_parse_ws_post_action_response() -> None:
+    # Arrange
+    raw = (
+        b'{"channel":"post","data":{"id":1,"response":{"type":"action","payload":'
+        b'{"status":"ok","response":{"type":"order","data":{"statuses":'
+        b'[{"resting":{"oid":77738308}}]}}}}}}'
+    )
+    msg = msgspec.json.decode(raw, type=HyperliquidWsPostMsg)
+
+    # Act
+    response = msg.parse_to_exchange_response()
+
+    # Assert
+    assert response.is_ok
+    assert response.error is None
+    assert response.statuses[0].oid == 77738308
+
+
+def test_exchange_response_with_order_error() -> None:
+    # Arrange
+    raw = (
+        b'{"status":"ok","response":{"type":"order","data":{"statuses":'
+        b'[{"error":"Order must have minimum value of $10."}]}}}'
+    )
+
+    # Act
+    response = msgspec.json.decode(raw, type=HyperliquidExchangeResponse)
+
+    # Assert
+    assert response.is_ok
+    assert response.error == "Order must have minimum value of $10."
+
+
+def test_exchange_response_with_cancel_success() -> None:
+    # Arrange
+    raw = b'{"status":"ok","response":{"type":"cancel","data":{"statuses":["success"]}}}'
+
+    # Act
+    response = msgspec.json.decode(raw, type=HyperliquidExchangeResponse)
+
+    # Assert
+    assert response.error is None
+    assert response.statuses == ["success"]
+
+
+def test_exchange_response_with_error_status() -> None:
+    # Arrange
+    raw = b'{"status":"err","response":"User or API Wallet does not exist."}'
+
+    # Act
+    response = msgspec.json.decode(raw, type=HyperliquidExchangeResponse)
+
+    # Assert
+    assert not response.is_ok
+    assert response.error == "User or API Wallet does not exist."
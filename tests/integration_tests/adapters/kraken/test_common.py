@@ -0,0 +1,427 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pytest
+
+from nautilus_trader.adapters.kraken.common.enums import KrakenEnumParser
+from nautilus_trader.adapters.kraken.common.enums import KrakenFuturesOrderStatus
+from nautilus_trader.adapters.kraken.common.enums import KrakenFuturesOrderType
+from nautilus_trader.adapters.kraken.common.enums import KrakenFuturesTriggerSignal
+from nautilus_trader.adapters.kraken.common.enums import KrakenOrderSide
+from nautilus_trader.adapters.kraken.common.enums import KrakenProductType
+from nautilus_trader.adapters.kraken.common.enums import KrakenSpotOrderStatus
+from nautilus_trader.adapters.kraken.common.enums import KrakenSpotOrderType
+from nautilus_trader.adapters.kraken.common.enums import KrakenSpotTimeInForce
+from nautilus_trader.adapters.kraken.common.enums import KrakenSpotTriggerReference
+from nautilus_trader.adapters.kraken.common.parsing import normalize_kraken_asset
+from nautilus_trader.adapters.kraken.common.parsing import parse_aggressor_side
+from nautilus_trader.adapters.kraken.common.symbol import KrakenSymbol
+from nautilus_trader.adapters.kraken.http.client import KrakenHttpClient
+from nautilus_trader.adapters.kraken.http.client import kraken_sign
+from nautilus_trader.adapters.kraken.http.errors import KrakenError
+from nautilus_trader.adapters.kraken.http.errors import should_retry
+from nautilus_trader.common.component import TestClock
+from nautilus_trader.model.enums import AggressorSide
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import TimeInForce
+from nautilus_trader.model.enums import TriggerType
+from nautilus_trader.model.identifiers import InstrumentId
+
+
+# The example private key from the Kraken Spot REST authentication guide
+# https://docs.kraken.com/api/docs/guides/spot-rest-auth
+TEST_API_SECRET = (
+    "kQH5HW/8p1uGOVjbgWA7FunAmGO8lsSUXNsu3eow76sz84Q18fWxnyRzBHCd3pd5nE9qa99HAZtuZuj6F1huXg=="
+)
+
+
+def _client(product_type: KrakenProductType) -> KrakenHttpClient:
+    return KrakenHttpClient(
+        clock=TestClock(),
+        api_key="test-key",
+        api_secret=TEST_API_SECRET,
+        base_url="https://api.kraken.com",
+        product_type=product_type,
+    )
+
+
+@pytest.mark.parametrize(
+    ("symbol", "expected_raw", "expected_product_type"),
+    [
+        ["BTC/USD-SPOT", "BTC/USD", KrakenProductType.SPOT],
+        ["PF_XBTUSD-FUTURES", "PF_XBTUSD", KrakenProductType.FUTURES],
+        ["pi_ethusd-futures", "PI_ETHUSD", KrakenProductType.FUTURES],
+    ],
+)
+def test_kraken_symbol(
+    symbol: str,
+    expected_raw: str,
+    expected_product_type: KrakenProductType,
+) -> None:
+    # Arrange, Act
+    kraken_symbol = KrakenSymbol(symbol)
+
+    # Assert
+    assert kraken_symbol.raw_symbol == expected_raw
+    assert kraken_symbol.product_type == expected_product_type
+
+
+def test_kraken_symbol_invalid_suffix_raises() -> None:
+    # Arrange, Act, Assert
+    with pytest.raises(ValueError):
+        KrakenSymbol("BTC/USD")
+
+
+def test_kraken_symbol_from_raw_to_instrument_id() -> None:
+    # Arrange, Act
+    kraken_symbol = KrakenSymbol.from_raw("PF_XBTUSD", KrakenProductType.FUTURES)
+
+    # Assert
+    assert kraken_symbol.is_futures
+    assert kraken_symbol.to_instrument_id() == InstrumentId.from_str("PF_XBTUSD-FUTURES.KRAKEN")
+
+
+@pytest.mark.parametrize(
+    ("asset", "expected"),
+    [
+        ["XXBT", "BTC"],
+        ["XBT", "BTC"],
+        ["ZUSD", "USD"],
+        ["XETH", "ETH"],
+        ["SOL", "SOL"],
+        ["usdt", "USDT"],
+    ],
+)
+def test_normalize_kraken_asset(asset: str, expected: str) -> None:
+    # Arrange, Act, Assert
+    assert normalize_kraken_asset(asset) == expected
+
+
+def test_parse_aggressor_side() -> None:
+    # Arrange, Act, Assert
+    assert parse_aggressor_side("buy") == AggressorSide.BUYER
+    assert parse_aggressor_side("sell") == AggressorSide.SELLER
+    with pytest.raises(ValueError):
+        parse_aggressor_side("BUY")
+
+
+def test_kraken_sign() -> None:
+    # Arrange, Act
+    signature = kraken_sign(TEST_API_SECRET, b"message")
+
+    # Assert
+    assert signature == (
+        "OGzBwWnT6ClH0Ewb9dJQJrlxL3i1iI+QEZlNd0DaaGjNFgqEUeNhkvjeh3Jhr7F+iQ/8/djnsCQSWURYIDLhuA=="
+    )
+
+
+def test_sign_spot_request_matches_kraken_example() -> None:
+    # Arrange
+    client = _client(KrakenProductType.SPOT)
+    nonce = "1616492376594"
+    payload = {
+        "nonce": nonce,
+        "ordertype": "limit",
+        "pair": "XBTUSD",
+        "price": 37500,
+        "type": "buy",
+        "volume": 1.25,
+    }
+
+    # Act
+    signature = client._sign_spot_request("/0/private/AddOrder", nonce, payload)
+
+    # Assert
+    assert signature == (
+        "4/dpxb3iT4tp/ZCVEwSnEsLxx0bqyhLpdfOpc6fn7OR8+UClSV5n9E6aSS8MPtnRfp32bAb0nmbRn6H8ndwLUQ=="
+    )
+
+
+def test_sign_futures_request() -> None:
+    # Arrange
+    client = _client(KrakenProductType.FUTURES)
+    payload = {
+        "orderType": "lmt",
+        "symbol": "PI_XBTUSD",
+        "side": "buy",
+        "size": 10000,
+        "limitPrice": 9400,
+    }
+
+    # Act
+    signature = client._sign_futures_request("/derivatives/api/v3/sendorder", "1415957147", payload)
+
+    # Assert
+    assert signature == (
+        "azrgjhi8I5M3wPrmC8kj0szfAnBCNbp38K4NXYew60tEi+ko9nCtVmtNLIlEx9i6x/G9mGQ8FLojYSKa9Koqhw=="
+    )
+
+
+def test_sign_futures_request_without_payload() -> None:
+    # Arrange
+    client = _client(KrakenProductType.FUTURES)
+
+    # Act
+    signature = client._sign_futures_request("/derivatives/api/v3/openpositions", "1415957147", {})
+
+    # Assert
+    assert signature == (
+        "H5bfYphYsdxxZYaHadjb1SsvPKtmPLo/ush2NSu8pURk8J4e0nQflP9wtcf/S6rAI20+R38PHgk3n3qaGHUGjw=="
+    )
+
+
+def test_sign_futures_request_strips_derivatives_prefix() -> None:
+    # Arrange
+    client = _client(KrakenProductType.FUTURES)
+    payload = {"symbol": "PF_XBTUSD"}
+
+    # Act
+    signature = client._sign_futures_request("/derivatives/api/v3/orderbook", "1", payload)
+
+    # Assert
+    assert signature == client._sign_futures_request("/api/v3/orderbook", "1", payload)
+
+
+def test_next_nonce_strictly_increasing() -> None:
+    # Arrange
+    client = _client(KrakenProductType.SPOT)
+
+    # Act
+    nonces = [int(client._next_nonce()) for _ in range(3)]
+
+    # Assert
+    assert nonces == sorted(set(nonces))
+
+
+@pytest.mark.parametrize(
+    ("code", "expected"),
+    [
+        [429, True],
+        ["EAPI:Invalid nonce", True],
+        ["nonceBelowThreshold", True],
+        ["EOrder:Insufficient funds", False],
+        [400, False],
+    ],
+)
+def test_should_retry(code: int | str, expected: bool) -> None:
+    # Arrange
+    error = KrakenError(code=code, message="error")
+
+    # Act, Assert
+    assert should_retry(error) == expected
+    assert not should_retry(ValueError("error"))
+
+
+def test_enum_parser_order_side() -> None:
+    # Arrange
+    parser = KrakenEnumParser()
+
+    # Act, Assert
+    assert parser.parse_kraken_order_side(KrakenOrderSide.BUY) == OrderSide.BUY
+    assert parser.parse_kraken_order_side(KrakenOrderSide.SELL) == OrderSide.SELL
+    assert parser.parse_nautilus_order_side(OrderSide.SELL) == KrakenOrderSide.SELL
+
+
+@pytest.mark.parametrize(
+    ("order_type", "expected"),
+    [
+        [KrakenSpotOrderType.MARKET, OrderType.MARKET],
+        [KrakenSpotOrderType.LIMIT, OrderType.LIMIT],
+        [KrakenSpotOrderType.STOP_LOSS, OrderType.STOP_MARKET],
+        [KrakenSpotOrderType.STOP_LOSS_LIMIT, OrderType.STOP_LIMIT],
+        [KrakenSpotOrderType.TAKE_PROFIT, OrderType.MARKET_IF_TOUCHED],
+        [KrakenSpotOrderType.TAKE_PROFIT_LIMIT, OrderType.LIMIT_IF_TOUCHED],
+        [KrakenSpotOrderType.TRAILING_STOP, OrderType.TRAILING_STOP_MARKET],
+        [KrakenSpotOrderType.TRAILING_STOP_LIMIT, OrderType.TRAILING_STOP_LIMIT],
+    ],
+)
+def test_enum_parser_spot_order_type_round_trip(
+    order_type: KrakenSpotOrderType,
+    expected: OrderType,
+) -> None:
+    # Arrange
+    parser = KrakenEnumParser()
+
+    # Act, Assert
+    assert parser.parse_kraken_spot_order_type(order_type) == expected
+    assert parser.parse_nautilus_spot_order_type(expected) == order_type
+
+
+def test_enum_parser_spot_order_type_unsupported() -> None:
+    # Arrange
+    parser = KrakenEnumParser()
+
+    # Act, Assert
+    with pytest.raises(RuntimeError):
+        parser.parse_kraken_spot_order_type(KrakenSpotOrderType.ICEBERG)
+
+
+@pytest.mark.parametrize(
+    ("order_status", "expected"),
+    [
+        [KrakenSpotOrderStatus.PENDING_NEW, OrderStatus.SUBMITTED],
+        [KrakenSpotOrderStatus.NEW, OrderStatus.ACCEPTED],
+        [KrakenSpotOrderStatus.PARTIALLY_FILLED, OrderStatus.PARTIALLY_FILLED],
+        [KrakenSpotOrderStatus.FILLED, OrderStatus.FILLED],
+        [KrakenSpotOrderStatus.CANCELED, OrderStatus.CANCELED],
+        [KrakenSpotOrderStatus.EXPIRED, OrderStatus.EXPIRED],
+        [KrakenSpotOrderStatus.PENDING, OrderStatus.SUBMITTED],
+        [KrakenSpotOrderStatus.OPEN, OrderStatus.ACCEPTED],
+        [KrakenSpotOrderStatus.CLOSED, OrderStatus.FILLED],
+    ],
+)
+def test_enum_parser_spot_order_status(
+    order_status: KrakenSpotOrderStatus,
+    expected: OrderStatus,
+) -> None:
+    # Arrange
+    parser = KrakenEnumParser()
+
+    # Act, Assert
+    assert parser.parse_kraken_spot_order_status(order_status) == expected
+
+
+def test_enum_parser_spot_time_in_force() -> None:
+    # Arrange
+    parser = KrakenEnumParser()
+
+    # Act, Assert
+    assert parser.parse_kraken_spot_time_in_force(KrakenSpotTimeInForce.GTD) == TimeInForce.GTD
+    assert parser.parse_nautilus_spot_time_in_force(TimeInForce.IOC) == KrakenSpotTimeInForce.IOC
+    with pytest.raises(RuntimeError):
+        parser.parse_nautilus_spot_time_in_force(TimeInForce.FOK)
+
+
+def test_enum_parser_spot_trigger_reference() -> None:
+    # Arrange
+    parser = KrakenEnumParser()
+
+    # Act, Assert
+    assert (
+        parser.parse_nautilus_spot_trigger_reference(TriggerType.DEFAULT)
+        == KrakenSpotTriggerReference.LAST
+    )
+    assert (
+        parser.parse_nautilus_spot_trigger_reference(TriggerType.INDEX_PRICE)
+        == KrakenSpotTriggerReference.INDEX
+    )
+    with pytest.raises(RuntimeError):
+        parser.parse_nautilus_spot_trigger_reference(TriggerType.MARK_PRICE)
+
+
+@pytest.mark.parametrize(
+    ("order_status", "expected"),
+    [
+        [KrakenFuturesOrderStatus.UNTOUCHED, OrderStatus.ACCEPTED],
+        [KrakenFuturesOrderStatus.PARTIALLY_FILLED, OrderStatus.PARTIALLY_FILLED],
+    ],
+)
+def test_enum_parser_futures_order_status(
+    order_status: KrakenFuturesOrderStatus,
+    expected: OrderStatus,
+) -> None:
+    # Arrange
+    parser = KrakenEnumParser()
+
+    # Act, Assert
+    assert parser.parse_kraken_futures_order_status(order_status) == expected
+
+
+@pytest.mark.parametrize(
+    ("order_type", "time_in_force", "is_post_only", "expected"),
+    [
+        [OrderType.MARKET, TimeInForce.GTC, False, KrakenFuturesOrderType.MARKET],
+        [OrderType.LIMIT, TimeInForce.GTC, False, KrakenFuturesOrderType.LIMIT],
+        [OrderType.LIMIT, TimeInForce.GTC, True, KrakenFuturesOrderType.POST_ONLY],
+        [OrderType.LIMIT, TimeInForce.IOC, False, KrakenFuturesOrderType.IOC],
+        [OrderType.STOP_MARKET, TimeInForce.GTC, False, KrakenFuturesOrderType.STOP],
+        [OrderType.STOP_LIMIT, TimeInForce.GTC, False, KrakenFuturesOrderType.STOP],
+        [OrderType.MARKET_IF_TOUCHED, TimeInForce.GTC, False, KrakenFuturesOrderType.TAKE_PROFIT],
+        [OrderType.LIMIT_IF_TOUCHED, TimeInForce.GTC, False, KrakenFuturesOrderType.TAKE_PROFIT],
+    ],
+)
+def test_enum_parser_nautilus_futures_order_type(
+    order_type: OrderType,
+    time_in_force: TimeInForce,
+    is_post_only: bool,
+    expected: KrakenFuturesOrderType,
+) -> None:
+    # Arrange
+    parser = KrakenEnumParser()
+
+    # Act, Assert
+    assert (
+        parser.parse_nautilus_futures_order_type(order_type, time_in_force, is_post_only)
+        == expected
+    )
+
+
+def test_enum_parser_nautilus_futures_order_type_unsupported() -> None:
+    # Arrange
+    parser = KrakenEnumParser()
+
+    # Act, Assert
+    with pytest.raises(RuntimeError):
+        parser.parse_nautilus_futures_order_type(
+            OrderType.TRAILING_STOP_MARKET,
+            TimeInForce.GTC,
+            False,
+        )
+
+
+@pytest.mark.parametrize(
+    ("order_type", "has_limit_price", "expected"),
+    [
+        ["mkt", False, OrderType.MARKET],
+        ["market", False, OrderType.MARKET],
+        ["lmt", True, OrderType.LIMIT],
+        ["LIMIT", True, OrderType.LIMIT],
+        ["post", True, OrderType.LIMIT],
+        ["ioc", True, OrderType.LIMIT],
+        ["stp", False, OrderType.STOP_MARKET],
+        ["stop", True, OrderType.STOP_LIMIT],
+        ["take_profit", False, OrderType.MARKET_IF_TOUCHED],
+        ["take_profit", True, OrderType.LIMIT_IF_TOUCHED],
+    ],
+)
+def test_enum_parser_kraken_futures_order_type(
+    order_type: str,
+    has_limit_price: bool,
+    expected: OrderType,
+) -> None:
+    # Arrange
+    parser = KrakenEnumParser()
+
+    # Act, Assert
+    assert parser.parse_kraken_futures_order_type(order_type, has_limit_price) == expected
+
+
+def test_enum_parser_futures_trigger_signal() -> None:
+    # Arrange
+    parser = KrakenEnumParser()
+
+    # Act, Assert
+    assert (
+        parser.parse_nautilus_futures_trigger_signal(TriggerType.MARK_PRICE)
+        == KrakenFuturesTriggerSignal.MARK
+    )
+    assert (
+        parser.parse_kraken_futures_trigger_signal(KrakenFuturesTriggerSignal.INDEX)
+        == TriggerType.INDEX_PRICE
+    )
+    with pytest.raises(RuntimeError):
+        parser.parse_nautilus_futures_trigger_signal(TriggerType.BID_ASK)
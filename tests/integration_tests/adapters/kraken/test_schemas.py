@@ -0,0 +1,1052 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import datetime
+from decimal import Decimal
+
+import msgspec
+import pytest
+
+from nautilus_trader.adapters.kraken.common.enums import KrakenEnumParser
+from nautilus_trader.adapters.kraken.common.enums import KrakenOrderSide
+from nautilus_trader.adapters.kraken.common.enums import KrakenSpotExecType
+from nautilus_trader.adapters.kraken.common.enums import KrakenSpotOrderStatus
+from nautilus_trader.adapters.kraken.common.enums import KrakenSpotOrderType
+from nautilus_trader.adapters.kraken.schemas.futures import KrakenFuturesAccountsResponse
+from nautilus_trader.adapters.kraken.schemas.futures import KrakenFuturesFillsResponse
+from nautilus_trader.adapters.kraken.schemas.futures import KrakenFuturesInstrument
+from nautilus_trader.adapters.kraken.schemas.futures import KrakenFuturesOpenOrdersResponse
+from nautilus_trader.adapters.kraken.schemas.futures import KrakenFuturesOpenPositionsResponse
+from nautilus_trader.adapters.kraken.schemas.futures import KrakenFuturesSendOrderResponse
+from nautilus_trader.adapters.kraken.schemas.spot import KrakenSpotAssetPair
+from nautilus_trader.adapters.kraken.schemas.spot import KrakenSpotAssetPairsResponse
+from nautilus_trader.adapters.kraken.schemas.spot import KrakenSpotBalanceResponse
+from nautilus_trader.adapters.kraken.schemas.spot import KrakenSpotOpenOrdersResponse
+from nautilus_trader.adapters.kraken.schemas.spot import KrakenSpotOrder
+from nautilus_trader.adapters.kraken.schemas.spot import KrakenSpotTradesHistoryResponse
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenFuturesWsBookMsg
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenFuturesWsBookSnapshotMsg
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenFuturesWsFillsMsg
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenFuturesWsMessageGeneral
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenFuturesWsOpenOrdersMsg
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenFuturesWsTickerMsg
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenFuturesWsTradeMsg
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenSpotWsBookMsg
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenSpotWsExecutionsMsg
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenSpotWsMessageGeneral
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenSpotWsOrderResponseMsg
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenSpotWsTickerMsg
+from nautilus_trader.adapters.kraken.schemas.ws import KrakenSpotWsTradeMsg
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.model.enums import AggressorSide
+from nautilus_trader.model.enums import BookAction
+from nautilus_trader.model.enums import LiquiditySide
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import PositionSide
+from nautilus_trader.model.enums import RecordFlag
+from nautilus_trader.model.enums import TimeInForce
+from nautilus_trader.model.enums import TriggerType
+from nautilus_trader.model.identifiers import AccountId
+from nautilus_trader.model.identifiers import ClientOrderId
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import VenueOrderId
+from nautilus_trader.model.instruments import CryptoFuture
+from nautilus_trader.model.instruments import CryptoPerpetual
+from nautilus_trader.model.instruments import CurrencyPair
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import Money
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+
+
+ACCOUNT_ID = AccountId("KRAKEN-master")
+BTC = Currency.from_str("BTC")
+USD = Currency.from_str("USD")
+
+BTC_USD_SPOT_ID = InstrumentId.from_str("BTC/USD-SPOT.KRAKEN")
+PF_XBTUSD_ID = InstrumentId.from_str("PF_XBTUSD-FUTURES.KRAKEN")
+
+XBTUSD_PAIR_RAW = (
+    b'{"altname":"XBTUSD","wsname":"XBT/USD","aclass_base":"currency","base":"XXBT",'
+    b'"aclass_quote":"currency","quote":"ZUSD","lot":"unit","cost_decimals":5,'
+    b'"pair_decimals":1,"lot_decimals":8,"lot_multiplier":1,"leverage_buy":[2,3],'
+    b'"leverage_sell":[2,3],"fees":[[0,0.4],[10000,0.35]],"fees_maker":[[0,0.25],[10000,0.2]],'
+    b'"fee_volume_currency":"ZUSD","margin_call":80,"margin_stop":40,"ordermin":"0.0001",'
+    b'"costmin":"0.5","tick_size":"0.1","status":"online"}'
+)
+
+PF_XBTUSD_RAW = (
+    b'{"symbol":"PF_XBTUSD","type":"flexible_futures","underlying":"rr_xbtusd","tickSize":0.5,'
+    b'"contractSize":1,"tradeable":true,"impactMidSize":1,"maxPositionSize":1000000,'
+    b'"openingDate":"2022-01-01T00:00:00.000Z","fundingRateCoefficient":8,'
+    b'"maxRelativeFundingRate":0.001,"contractValueTradePrecision":4,"postOnly":false,'
+    b'"base":"BTC","quote":"USD","pair":"BTC:USD"}'
+)
+
+PI_XBTUSD_RAW = (
+    b'{"symbol":"pi_xbtusd","type":"futures_inverse","underlying":"rr_xbtusd","tickSize":0.5,'
+    b'"contractSize":1,"tradeable":true,"contractValueTradePrecision":0,'
+    b'"openingDate":"2018-08-31T00:00:00.000Z"}'
+)
+
+FI_XBTUSD_RAW = (
+    b'{"symbol":"FI_XBTUSD_240628","type":"futures_inverse","underlying":"rr_xbtusd",'
+    b'"tickSize":0.5,"contractSize":1,"tradeable":true,"contractValueTradePrecision":0,'
+    b'"openingDate":"2024-03-01T00:00:00.000Z","lastTradingTime":"2024-06-28T08:00:00.000Z"}'
+)
+
+
+def _btc_usd_spot() -> CurrencyPair:
+    pair = msgspec.json.decode(XBTUSD_PAIR_RAW, type=KrakenSpotAssetPair)
+    return pair.parse_to_instrument(
+        base_currency=BTC,
+        quote_currency=USD,
+        ts_event=0,
+        ts_init=0,
+    )
+
+
+def _pf_xbtusd() -> CryptoPerpetual:
+    instrument = msgspec.json.decode(PF_XBTUSD_RAW, type=KrakenFuturesInstrument)
+    return instrument.parse_to_instrument(
+        base_currency=BTC,
+        quote_currency=USD,
+        maker_fee=Decimal("0.0002"),
+        taker_fee=Decimal("0.0005"),
+        ts_event=0,
+        ts_init=0,
+    )
+
+
+################################################################################
+# Spot (REST)
+################################################################################
+
+
+def test_decode_asset_pairs_response() -> None:
+    # Arrange
+    raw = b'{"error":[],"result":{"XXBTZUSD":' + XBTUSD_PAIR_RAW + b"}}"
+
+    # Act
+    response = msgspec.json.decode(raw, type=KrakenSpotAssetPairsResponse)
+
+    # Assert
+    pair = response.result["XXBTZUSD"]
+    assert pair.base_code == "BTC"
+    assert pair.quote_code == "USD"
+    assert pair.ws_symbol == "BTC/USD"
+
+
+def test_asset_pair_codes_without_wsname() -> None:
+    # Arrange
+    raw = XBTUSD_PAIR_RAW.replace(b'"wsname":"XBT/USD",', b"")
+
+    # Act
+    pair = msgspec.json.decode(raw, type=KrakenSpotAssetPair)
+
+    # Assert
+    assert pair.base_code == "BTC"
+    assert pair.quote_code == "USD"
+
+
+def test_parse_asset_pair_to_instrument() -> None:
+    # Arrange, Act
+    instrument = _btc_usd_spot()
+
+    # Assert
+    assert instrument.id == BTC_USD_SPOT_ID
+    assert instrument.raw_symbol.value == "BTC/USD"
+    assert instrument.base_currency == BTC
+    assert instrument.quote_currency == USD
+    assert instrument.price_increment == Price.from_str("0.1")
+    assert instrument.size_increment == Quantity.from_str("0.00000001")
+    assert instrument.min_quantity == Quantity.from_str("0.00010000")
+    assert instrument.min_notional == Money("0.50", USD)
+    assert instrument.taker_fee == Decimal("0.004")
+    assert instrument.maker_fee == Decimal("0.0025")
+
+
+def test_parse_asset_pair_without_tick_size_uses_pair_decimals() -> None:
+    # Arrange
+    raw = XBTUSD_PAIR_RAW.replace(b'"tick_size":"0.1",', b"")
+    pair = msgspec.json.decode(raw, type=KrakenSpotAssetPair)
+
+    # Act
+    instrument = pair.parse_to_instrument(
+        base_currency=BTC,
+        quote_currency=USD,
+        ts_event=0,
+        ts_init=0,
+    )
+
+    # Assert
+    assert instrument.price_increment == Price.from_str("0.1")
+    assert instrument.price_precision == 1
+
+
+def test_parse_balance_response_to_account_balances() -> None:
+    # Arrange
+    raw = (
+        b'{"error":[],"result":{"XXBT":{"balance":"1.5","hold_trade":"0.25"},'
+        b'"ZUSD":{"balance":"1000.00"}}}'
+    )
+    response = msgspec.json.decode(raw, type=KrakenSpotBalanceResponse)
+
+    # Act
+    balances = [b.parse_to_account_balance(asset) for asset, b in response.result.items()]
+
+    # Assert
+    assert balances[0].currency == BTC
+    assert balances[0].total == Money("1.5", BTC)
+    assert balances[0].locked == Money("0.25", BTC)
+    assert balances[0].free == Money("1.25", BTC)
+    assert balances[1].currency == USD
+    assert balances[1].free == Money("1000.00", USD)
+    assert balances[1].locked == Money(0, USD)
+
+
+def test_parse_open_limit_order_to_order_status_report() -> None:
+    # Arrange
+    raw = (
+        b'{"error":[],"result":{"open":{"OQCLML-BW3P3-BUCMWZ":{"refid":null,"userref":0,'
+        b'"cl_ord_id":"O-123","status":"open","opentm":1717149599.0,"starttm":0,"expiretm":0,'
+        b'"descr":{"pair":"XBTUSD","type":"buy","ordertype":"limit","price":"67000.0",'
+        b'"price2":"0","leverage":"none","order":"buy 0.01 XBTUSD @ limit 67000.0","close":""},'
+        b'"vol":"0.01000000","vol_exec":"0.00400000","cost":"268.0","fee":"0.7",'
+        b'"price":"67000.0","stopprice":"0","limitprice":"0","misc":"","oflags":"fciq,post"}}}}'
+    )
+    response = msgspec.json.decode(raw, type=KrakenSpotOpenOrdersResponse)
+    venue_order_id, kraken_order = next(iter(response.result.open.items()))
+
+    # Act
+    report = kraken_order.parse_to_order_status_report(
+        account_id=ACCOUNT_ID,
+        instrument_id=BTC_USD_SPOT_ID,
+        client_order_id=ClientOrderId("O-123"),
+        venue_order_id=VenueOrderId(venue_order_id),
+        report_id=UUID4(),
+        enum_parser=KrakenEnumParser(),
+        ts_init=0,
+    )
+
+    # Assert
+    assert report.venue_order_id == VenueOrderId("OQCLML-BW3P3-BUCMWZ")
+    assert report.order_side == OrderSide.BUY
+    assert report.order_type == OrderType.LIMIT
+    assert report.order_status == OrderStatus.PARTIALLY_FILLED
+    assert report.time_in_force == TimeInForce.GTC
+    assert report.price == Price.from_str("67000.0")
+    assert report.trigger_price is None
+    assert report.quantity == Quantity.from_str("0.01000000")
+    assert report.filled_qty == Quantity.from_str("0.00400000")
+    assert report.avg_px == Decimal("67000.0")
+    assert report.post_only
+    assert report.ts_accepted == 1_717_149_599_000_000_000
+    assert report.ts_last == report.ts_accepted
+
+
+def test_parse_closed_stop_loss_limit_order_to_order_status_report() -> None:
+    # Arrange
+    raw = (
+        b'{"status":"closed","opentm":1717149599.0,"closetm":1717149600.0,"expiretm":1717236000,'
+        b'"descr":{"pair":"XBTUSD","type":"sell","ordertype":"stop-loss-limit",'
+        b'"price":"65000.0","price2":"64900.0"},"vol":"0.01000000","vol_exec":"0.01000000",'
+        b'"price":"64900.0","trigger":"index"}'
+    )
+    kraken_order = msgspec.json.decode(raw, type=KrakenSpotOrder)
+
+    # Act
+    report = kraken_order.parse_to_order_status_report(
+        account_id=ACCOUNT_ID,
+        instrument_id=BTC_USD_SPOT_ID,
+        client_order_id=None,
+        venue_order_id=VenueOrderId("OFS2EG-X5JVS-7CNNCO"),
+        report_id=UUID4(),
+        enum_parser=KrakenEnumParser(),
+        ts_init=0,
+    )
+
+    # Assert
+    assert report.order_side == OrderSide.SELL
+    assert report.order_type == OrderType.STOP_LIMIT
+    assert report.order_status == OrderStatus.FILLED
+    assert report.trigger_price == Price.from_str("65000.0")
+    assert report.price == Price.from_str("64900.0")
+    assert report.trigger_type == TriggerType.INDEX_PRICE
+    assert report.time_in_force == TimeInForce.GTD
+    assert report.expire_time == 1_717_236_000_000_000_000
+    assert report.ts_last == 1_717_149_600_000_000_000
+
+
+def test_parse_stop_loss_order_uses_last_price_trigger() -> None:
+    # Arrange
+    raw = (
+        b'{"status":"pending","opentm":1717149599.0,"descr":{"pair":"XBTUSD","type":"sell",'
+        b'"ordertype":"stop-loss","price":"65000.0"},"vol":"0.01000000",'
+        b'"vol_exec":"0.00000000","price":"0"}'
+    )
+    kraken_order = msgspec.json.decode(raw, type=KrakenSpotOrder)
+
+    # Act
+    report = kraken_order.parse_to_order_status_report(
+        account_id=ACCOUNT_ID,
+        instrument_id=BTC_USD_SPOT_ID,
+        client_order_id=None,
+        venue_order_id=VenueOrderId("OFS2EG-X5JVS-7CNNCP"),
+        report_id=UUID4(),
+        enum_parser=KrakenEnumParser(),
+        ts_init=0,
+    )
+
+    # Assert
+    assert report.order_type == OrderType.STOP_MARKET
+    assert report.order_status == OrderStatus.SUBMITTED
+    assert report.price is None
+    assert report.trigger_price == Price.from_str("65000.0")
+    assert report.trigger_type == TriggerType.LAST_PRICE
+    assert report.avg_px is None
+    assert not report.post_only
+
+
+def test_parse_trades_history_to_fill_report() -> None:
+    # Arrange
+    raw = (
+        b'{"error":[],"result":{"trades":{"TCCCTY-WE2O6-P3NB37":{"ordertxid":"OQCLML-BW3P3-BUCMWZ",'
+        b'"postxid":"TKH2SE-M7IF5-CFI7LT","pair":"XXBTZUSD","time":1717149600.0,"type":"buy",'
+        b'"ordertype":"limit","price":"67000.0","cost":"670.0","fee":"1.072",'
+        b'"vol":"0.01000000","margin":"0","misc":"","maker":true}},"count":1}}'
+    )
+    response = msgspec.json.decode(raw, type=KrakenSpotTradesHistoryResponse)
+    trade_id, trade = next(iter(response.result.trades.items()))
+
+    # Act
+    report = trade.parse_to_fill_report(
+        account_id=ACCOUNT_ID,
+        instrument_id=BTC_USD_SPOT_ID,
+        trade_id=trade_id,
+        client_order_id=ClientOrderId("O-123"),
+        quote_currency=USD,
+        report_id=UUID4(),
+        enum_parser=KrakenEnumParser(),
+        ts_init=0,
+    )
+
+    # Assert
+    assert response.result.count == 1
+    assert report.trade_id.value == "TCCCTY-WE2O6-P3NB37"
+    assert report.venue_order_id == VenueOrderId("OQCLML-BW3P3-BUCMWZ")
+    assert report.order_side == OrderSide.BUY
+    assert report.last_qty == Quantity.from_str("0.01000000")
+    assert report.last_px == Price.from_str("67000.0")
+    assert report.commission == Money(Decimal("1.072"), USD)
+    assert report.liquidity_side == LiquiditySide.MAKER
+    assert report.ts_event == 1_717_149_600_000_000_000
+
+
+################################################################################
+# Futures (REST)
+################################################################################
+
+
+def test_parse_linear_perpetual_to_instrument() -> None:
+    # Arrange, Act
+    instrument = _pf_xbtusd()
+
+    # Assert
+    assert isinstance(instrument, CryptoPerpetual)
+    assert instrument.id == PF_XBTUSD_ID
+    assert instrument.base_currency == BTC
+    assert instrument.quote_currency == USD
+    assert instrument.settlement_currency == USD
+    assert not instrument.is_inverse
+    assert instrument.price_increment == Price.from_str("0.5")
+    assert instrument.size_increment == Quantity.from_str("0.0001")
+    assert instrument.multiplier == Quantity.from_int(1)
+    assert instrument.maker_fee == Decimal("0.0002")
+
+
+def test_parse_inverse_perpetual_to_instrument() -> None:
+    # Arrange
+    kraken_instrument = msgspec.json.decode(PI_XBTUSD_RAW, type=KrakenFuturesInstrument)
+
+    # Act
+    instrument = kraken_instrument.parse_to_instrument(
+        base_currency=BTC,
+        quote_currency=USD,
+        maker_fee=Decimal("0.0002"),
+        taker_fee=Decimal("0.0005"),
+        ts_event=0,
+        ts_init=0,
+    )
+
+    # Assert
+    assert kraken_instrument.is_perpetual
+    assert isinstance(instrument, CryptoPerpetual)
+    assert instrument.id == InstrumentId.from_str("PI_XBTUSD-FUTURES.KRAKEN")
+    assert instrument.is_inverse
+    assert instrument.settlement_currency == BTC
+    assert instrument.size_increment == Quantity.from_int(1)
+
+
+def test_parse_fixed_maturity_future_to_instrument() -> None:
+    # Arrange
+    kraken_instrument = msgspec.json.decode(FI_XBTUSD_RAW, type=KrakenFuturesInstrument)
+
+    # Act
+    instrument = kraken_instrument.parse_to_instrument(
+        base_currency=BTC,
+        quote_currency=USD,
+        maker_fee=Decimal("0.0002"),
+        taker_fee=Decimal("0.0005"),
+        ts_event=0,
+        ts_init=0,
+    )
+
+    # Assert
+    assert not kraken_instrument.is_perpetual
+    assert isinstance(instrument, CryptoFuture)
+    assert instrument.id == InstrumentId.from_str("FI_XBTUSD_240628-FUTURES.KRAKEN")
+    assert instrument.underlying == BTC
+    assert instrument.settlement_currency == BTC
+    assert instrument.activation_ns == 1_709_251_200_000_000_000
+    assert instrument.expiration_ns == 1_719_561_600_000_000_000
+
+
+def test_parse_instrument_negative_trade_precision() -> None:
+    # Arrange
+    raw = PF_XBTUSD_RAW.replace(
+        b'"contractValueTradePrecision":4',
+        b'"contractValueTradePrecision":-1',
+    )
+    kraken_instrument = msgspec.json.decode(raw, type=KrakenFuturesInstrument)
+
+    # Act
+    instrument = kraken_instrument.parse_to_instrument(
+        base_currency=BTC,
+        quote_currency=USD,
+        maker_fee=Decimal(0),
+        taker_fee=Decimal(0),
+        ts_event=0,
+        ts_init=0,
+    )
+
+    # Assert
+    assert instrument.size_increment == Quantity.from_int(10)
+
+
+def test_parse_instrument_without_tick_size_raises() -> None:
+    # Arrange
+    raw = PF_XBTUSD_RAW.replace(b'"tickSize":0.5,', b"")
+    kraken_instrument = msgspec.json.decode(raw, type=KrakenFuturesInstrument)
+
+    # Act, Assert
+    with pytest.raises(ValueError, match="no `tickSize`"):
+        kraken_instrument.parse_to_instrument(
+            base_currency=BTC,
+            quote_currency=USD,
+            maker_fee=Decimal(0),
+            taker_fee=Decimal(0),
+            ts_event=0,
+            ts_init=0,
+        )
+
+
+def test_parse_accounts_to_balances_and_margins() -> None:
+    # Arrange
+    raw = (
+        b'{"result":"success","accounts":{'
+        b'"flex":{"type":"multiCollateralMarginAccount","currencies":{'
+        b'"USD":{"quantity":1000.0,"value":1000.0,"collateral":1000.0,"available":900.0},'
+        b'"XBT":{"quantity":0.1,"value":6700.0,"collateral":6365.0,"available":0.1}},'
+        b'"initialMargin":100.0,"maintenanceMargin":50.0},'
+        b'"fi_xbtusd":{"type":"marginAccount","currency":"xbt","balances":{"xbt":0.05},'
+        b'"marginRequirements":{"im":0.01,"mm":0.005,"lt":0.004,"tt":0.003}},'
+        b'"cash":{"type":"cashAccount","balances":{"xbt":0.0,"usd":5.0}}},'
+        b'"serverTime":"2024-05-31T10:00:00.000Z"}'
+    )
+    response = msgspec.json.decode(raw, type=KrakenFuturesAccountsResponse)
+
+    # Act
+    balances = response.parse_to_account_balances()
+    margins = response.parse_to_margin_balances()
+
+    # Assert
+    assert [b.currency for b in balances] == [USD, BTC]
+    assert balances[0].total == Money("1000.00", USD)
+    assert balances[0].free == Money("900.00", USD)
+    assert balances[0].locked == Money("100.00", USD)
+    assert balances[1].total == Money("0.15", BTC)
+    assert balances[1].free == Money("0.15", BTC)
+    assert len(margins) == 2
+    assert margins[0].initial == Money("100.00", USD)
+    assert margins[0].maintenance == Money("50.00", USD)
+    assert margins[1].initial == Money("0.01", BTC)
+    assert margins[1].maintenance == Money("0.005", BTC)
+
+
+def test_parse_open_stop_order_to_order_status_report() -> None:
+    # Arrange
+    instrument = _pf_xbtusd()
+    raw = (
+        b'{"result":"success","openOrders":[{"order_id":"59302619-41d2-4f0b-941f-7e7914760ad3",'
+        b'"symbol":"PF_XBTUSD","side":"sell","orderType":"stop","limitPrice":65000.0,'
+        b'"stopPrice":65500.0,"unfilledSize":0.75,"receivedTime":"2024-05-31T09:59:59.123Z",'
+        b'"status":"partiallyFilled","filledSize":0.25,"reduceOnly":true,"triggerSignal":"mark",'
+        b'"lastUpdateTime":"2024-05-31T10:00:00.000Z","cliOrdId":"O-456"}],'
+        b'"serverTime":"2024-05-31T10:00:00.000Z"}'
+    )
+    response = msgspec.json.decode(raw, type=KrakenFuturesOpenOrdersResponse)
+    kraken_order = response.openOrders[0]
+
+    # Act
+    report = kraken_order.parse_to_order_status_report(
+        account_id=ACCOUNT_ID,
+        instrument=instrument,
+        client_order_id=ClientOrderId("O-456"),
+        report_id=UUID4(),
+        enum_parser=KrakenEnumParser(),
+        ts_init=0,
+    )
+
+    # Assert
+    assert report.venue_order_id == VenueOrderId("59302619-41d2-4f0b-941f-7e7914760ad3")
+    assert report.order_side == OrderSide.SELL
+    assert report.order_type == OrderType.STOP_LIMIT
+    assert report.order_status == OrderStatus.PARTIALLY_FILLED
+    assert report.price == Price.from_str("65000.0")
+    assert report.trigger_price == Price.from_str("65500.0")
+    assert report.trigger_type == TriggerType.MARK_PRICE
+    assert report.quantity == Quantity.from_str("1.0000")
+    assert report.filled_qty == Quantity.from_str("0.2500")
+    assert report.reduce_only
+    assert not report.post_only
+    assert report.ts_accepted == 1_717_149_599_123_000_000
+    assert report.ts_last == 1_717_149_600_000_000_000
+
+
+def test_parse_open_post_only_order_to_order_status_report() -> None:
+    # Arrange
+    instrument = _pf_xbtusd()
+    raw = (
+        b'{"result":"success","openOrders":[{"order_id":"a5b6c7d8","symbol":"PF_XBTUSD",'
+        b'"side":"buy","orderType":"post","limitPrice":66000.0,"unfilledSize":0.5,'
+        b'"receivedTime":"2024-05-31T09:59:59.000Z","status":"untouched","filledSize":0.0,'
+        b'"lastUpdateTime":"2024-05-31T09:59:59.000Z"}]}'
+    )
+    response = msgspec.json.decode(raw, type=KrakenFuturesOpenOrdersResponse)
+
+    # Act
+    report = response.openOrders[0].parse_to_order_status_report(
+        account_id=ACCOUNT_ID,
+        instrument=instrument,
+        client_order_id=None,
+        report_id=UUID4(),
+        enum_parser=KrakenEnumParser(),
+        ts_init=0,
+    )
+
+    # Assert
+    assert report.order_type == OrderType.LIMIT
+    assert report.order_status == OrderStatus.ACCEPTED
+    assert report.trigger_type == TriggerType.NO_TRIGGER
+    assert report.post_only
+
+
+def test_parse_fill_to_fill_report_estimates_commission() -> None:
+    # Arrange
+    instrument = _pf_xbtusd()
+    raw = (
+        b'{"result":"success","fills":[{"fill_id":"3d57ed09-fbd6-44f1-8e8b-b10e551c5e73",'
+        b'"symbol":"PF_XBTUSD","side":"buy","order_id":"693af756-055e-47ef-99d5-bcf4c456ebc5",'
+        b'"size":0.5,"price":67000.0,"fillTime":"2024-05-31T10:00:00.500Z","fillType":"maker",'
+        b'"cliOrdId":"O-789"}],"serverTime":"2024-05-31T10:00:01.000Z"}'
+    )
+    response = msgspec.json.decode(raw, type=KrakenFuturesFillsResponse)
+
+    # Act
+    report = response.fills[0].parse_to_fill_report(
+        account_id=ACCOUNT_ID,
+        instrument=instrument,
+        client_order_id=ClientOrderId("O-789"),
+        report_id=UUID4(),
+        enum_parser=KrakenEnumParser(),
+        ts_init=0,
+    )
+
+    # Assert
+    assert report.trade_id.value == "3d57ed09-fbd6-44f1-8e8b-b10e551c5e73"
+    assert report.order_side == OrderSide.BUY
+    assert report.last_qty == Quantity.from_str("0.5000")
+    assert report.last_px == Price.from_str("67000.0")
+    assert report.commission == Money("6.70", USD)
+    assert report.liquidity_side == LiquiditySide.MAKER
+    assert report.ts_event == 1_717_149_600_500_000_000
+
+
+def test_parse_open_position_to_position_status_report() -> None:
+    # Arrange
+    instrument = _pf_xbtusd()
+    raw = (
+        b'{"result":"success","openPositions":[{"side":"short","symbol":"PF_XBTUSD",'
+        b'"price":67000.0,"fillTime":"2024-05-31T10:00:00.000Z","size":0.5,'
+        b'"unrealizedFunding":0.0001}],"serverTime":"2024-05-31T10:00:01.000Z"}'
+    )
+    response = msgspec.json.decode(raw, type=KrakenFuturesOpenPositionsResponse)
+
+    # Act
+    report = response.openPositions[0].parse_to_position_status_report(
+        account_id=ACCOUNT_ID,
+        instrument=instrument,
+        report_id=UUID4(),
+        ts_init=0,
+    )
+
+    # Assert
+    assert report.instrument_id == PF_XBTUSD_ID
+    assert report.position_side == PositionSide.SHORT
+    assert report.quantity == Quantity.from_str("0.5000")
+
+
+def test_decode_send_order_response() -> None:
+    # Arrange
+    raw = (
+        b'{"result":"success","sendStatus":{"order_id":"179f9af8-e45e-469d-b3e9-2fd4675cb7d0",'
+        b'"status":"placed","receivedTime":"2024-05-31T10:00:00.000Z","cliOrdId":"O-789",'
+        b'"orderEvents":[]},"serverTime":"2024-05-31T10:00:00.000Z"}'
+    )
+
+    # Act
+    response = msgspec.json.decode(raw, type=KrakenFuturesSendOrderResponse)
+
+    # Assert
+    assert response.sendStatus.status == "placed"
+    assert response.sendStatus.order_id == "179f9af8-e45e-469d-b3e9-2fd4675cb7d0"
+    assert response.sendStatus.cliOrdId == "O-789"
+
+
+################################################################################
+# Spot (WebSocket v2)
+################################################################################
+
+
+@pytest.mark.parametrize(
+    ("raw", "expected_method", "expected_channel"),
+    [
+        [
+            b'{"method":"subscribe","result":{"channel":"book","symbol":"BTC/USD"},'
+            b'"success":true,"time_in":"2024-05-31T10:00:00.000000Z"}',
+            "subscribe",
+            None,
+        ],
+        [b'{"channel":"heartbeat"}', None, "heartbeat"],
+    ],
+)
+def test_decode_spot_ws_general(
+    raw: bytes,
+    expected_method: str | None,
+    expected_channel: str | None,
+) -> None:
+    # Arrange, Act
+    msg = msgspec.json.decode(raw, type=KrakenSpotWsMessageGeneral)
+
+    # Assert
+    assert msg.method == expected_method
+    assert msg.channel == expected_channel
+
+
+def test_parse_spot_ws_book_snapshot_to_deltas() -> None:
+    # Arrange
+    raw = (
+        b'{"channel":"book","type":"snapshot","data":[{"symbol":"BTC/USD",'
+        b'"bids":[{"price":67000.1,"qty":0.5},{"price":67000.0,"qty":1.25}],'
+        b'"asks":[{"price":67000.2,"qty":0.75}],"checksum":2439117997}]}'
+    )
+    msg = msgspec.json.decode(raw, type=KrakenSpotWsBookMsg)
+
+    # Act
+    deltas = msg.data[0].parse_to_deltas(
+        instrument_id=BTC_USD_SPOT_ID,
+        price_precision=1,
+        size_precision=8,
+        ts_event=1,
+        ts_init=2,
+        snapshot=True,
+    )
+
+    # Assert
+    assert msg.type == "snapshot"
+    assert len(deltas.deltas) == 4
+    assert deltas.deltas[0].action == BookAction.CLEAR
+    assert deltas.deltas[1].action == BookAction.ADD
+    assert deltas.deltas[1].order.side == OrderSide.BUY
+    assert deltas.deltas[1].order.price == Price.from_str("67000.1")
+    assert deltas.deltas[3].order.side == OrderSide.SELL
+    assert deltas.deltas[3].order.size == Quantity.from_str("0.75000000")
+    assert deltas.deltas[3].flags == RecordFlag.F_LAST
+    assert deltas.deltas[2].flags == 0
+
+
+def test_parse_spot_ws_book_update_to_deltas() -> None:
+    # Arrange
+    raw = (
+        b'{"channel":"book","type":"update","data":[{"symbol":"BTC/USD",'
+        b'"bids":[{"price":67000.0,"qty":0.0}],"asks":[],"checksum":2439117997,'
+        b'"timestamp":"2024-05-31T10:00:00.123456Z"}]}'
+    )
+    msg = msgspec.json.decode(raw, type=KrakenSpotWsBookMsg)
+
+    # Act
+    deltas = msg.data[0].parse_to_deltas(
+        instrument_id=BTC_USD_SPOT_ID,
+        price_precision=1,
+        size_precision=8,
+        ts_event=1,
+        ts_init=2,
+    )
+
+    # Assert
+    assert msg.data[0].timestamp == datetime.datetime(
+        2024,
+        5,
+        31,
+        10,
+        0,
+        0,
+        123456,
+        tzinfo=datetime.UTC,
+    )
+    assert len(deltas.deltas) == 1
+    assert deltas.deltas[0].action == BookAction.DELETE
+    assert deltas.deltas[0].flags == RecordFlag.F_LAST
+
+
+def test_parse_spot_ws_trade_to_trade_tick() -> None:
+    # Arrange
+    raw = (
+        b'{"channel":"trade","type":"update","data":[{"symbol":"BTC/USD","side":"sell",'
+        b'"price":67000.0,"qty":0.01,"ord_type":"market","trade_id":4665906,'
+        b'"timestamp":"2024-05-31T10:00:00.123456Z"}]}'
+    )
+    msg = msgspec.json.decode(raw, type=KrakenSpotWsTradeMsg)
+
+    # Act
+    trade = msg.data[0].parse_to_trade_tick(
+        instrument_id=BTC_USD_SPOT_ID,
+        price_precision=1,
+        size_precision=8,
+        ts_event=1,
+        ts_init=2,
+    )
+
+    # Assert
+    assert trade.price == Price.from_str("67000.0")
+    assert trade.size == Quantity.from_str("0.01000000")
+    assert trade.aggressor_side == AggressorSide.SELLER
+    assert trade.trade_id.value == "4665906"
+
+
+def test_parse_spot_ws_ticker_to_quote_tick() -> None:
+    # Arrange
+    raw = (
+        b'{"channel":"ticker","type":"update","data":[{"symbol":"BTC/USD","bid":67000.0,'
+        b'"bid_qty":0.5,"ask":67000.1,"ask_qty":1.0,"last":67000.0,"volume":1200.5,'
+        b'"vwap":66900.0,"low":66000.0,"high":67500.0,"change":500.0,"change_pct":0.75}]}'
+    )
+    msg = msgspec.json.decode(raw, type=KrakenSpotWsTickerMsg)
+
+    # Act
+    quote = msg.data[0].parse_to_quote_tick(
+        instrument_id=BTC_USD_SPOT_ID,
+        price_precision=1,
+        size_precision=8,
+        ts_event=1,
+        ts_init=2,
+    )
+
+    # Assert
+    assert quote.bid_price == Price.from_str("67000.0")
+    assert quote.ask_price == Price.from_str("67000.1")
+    assert quote.bid_size == Quantity.from_str("0.50000000")
+    assert quote.ask_size == Quantity.from_str("1.00000000")
+
+
+def test_decode_spot_ws_executions_trade() -> None:
+    # Arrange
+    raw = (
+        b'{"channel":"executions","type":"update","data":[{"order_id":"OK4GJX-KSTLS-7DZZO5",'
+        b'"cl_ord_id":"O-123","symbol":"BTC/USD","side":"buy","order_type":"limit",'
+        b'"order_qty":0.01,"limit_price":67000.0,"order_status":"partially_filled",'
+        b'"exec_type":"trade","exec_id":"TWXSDG-HMLKT-JEF3OF","last_qty":0.004,'
+        b'"last_price":67000.0,"liquidity_ind":"m","fees":[{"asset":"USD","qty":0.67}],'
+        b'"timestamp":"2024-05-31T10:00:00.123456Z"}]}'
+    )
+
+    # Act
+    msg = msgspec.json.decode(raw, type=KrakenSpotWsExecutionsMsg)
+
+    # Assert
+    execution = msg.data[0]
+    assert execution.exec_type == KrakenSpotExecType.TRADE
+    assert execution.order_status == KrakenSpotOrderStatus.PARTIALLY_FILLED
+    assert execution.order_type == KrakenSpotOrderType.LIMIT
+    assert execution.side == KrakenOrderSide.BUY
+    assert execution.last_qty == 0.004
+    assert execution.liquidity_ind == "m"
+    assert execution.fees is not None
+    assert execution.fees[0].asset == "USD"
+    assert execution.fees[0].qty == 0.67
+
+
+def test_decode_spot_ws_executions_stop_trigger() -> None:
+    # Arrange
+    raw = (
+        b'{"channel":"executions","type":"update","data":[{"order_id":"OK4GJX-KSTLS-7DZZO6",'
+        b'"symbol":"BTC/USD","side":"sell","order_type":"stop-loss","order_qty":0.01,'
+        b'"order_status":"new","exec_type":"new","triggers":{"price":65000.0,'
+        b'"reference":"last","status":"untriggered"},"timestamp":"2024-05-31T10:00:00Z"}]}'
+    )
+
+    # Act
+    msg = msgspec.json.decode(raw, type=KrakenSpotWsExecutionsMsg)
+
+    # Assert
+    execution = msg.data[0]
+    assert execution.exec_type == KrakenSpotExecType.NEW
+    assert execution.order_type == KrakenSpotOrderType.STOP_LOSS
+    assert execution.triggers is not None
+    assert execution.triggers.price == 65000.0
+    assert execution.cl_ord_id is None
+
+
+def test_decode_spot_ws_order_response() -> None:
+    # Arrange
+    raw = (
+        b'{"method":"add_order","req_id":1,"result":{"order_id":"OK4GJX-KSTLS-7DZZO5",'
+        b'"cl_ord_id":"O-123"},"success":true,"time_in":"2024-05-31T10:00:00.000000Z",'
+        b'"time_out":"2024-05-31T10:00:00.001000Z"}'
+    )
+
+    # Act
+    msg = msgspec.json.decode(raw, type=KrakenSpotWsOrderResponseMsg)
+
+    # Assert
+    assert msg.method == "add_order"
+    assert msg.success
+    assert msg.req_id == 1
+    assert msg.result == {"order_id": "OK4GJX-KSTLS-7DZZO5", "cl_ord_id": "O-123"}
+
+
+def test_decode_spot_ws_order_response_error() -> None:
+    # Arrange
+    raw = b'{"method":"add_order","req_id":2,"success":false,"error":"EOrder:Insufficient funds"}'
+
+    # Act
+    msg = msgspec.json.decode(raw, type=KrakenSpotWsOrderResponseMsg)
+
+    # Assert
+    assert not msg.success
+    assert msg.result is None
+    assert msg.error == "EOrder:Insufficient funds"
+
+
+################################################################################
+# Futures (WebSocket v1)
+################################################################################
+
+
+@pytest.mark.parametrize(
+    ("raw", "expected_event", "expected_message"),
+    [
+        [b'{"event":"subscribed","feed":"book","product_ids":["PF_XBTUSD"]}', "subscribed", None],
+        [b'{"event":"error","message":"Invalid product id"}', "error", "Invalid product id"],
+    ],
+)
+def test_decode_futures_ws_general(
+    raw: bytes,
+    expected_event: str,
+    expected_message: str | None,
+) -> None:
+    # Arrange, Act
+    msg = msgspec.json.decode(raw, type=KrakenFuturesWsMessageGeneral)
+
+    # Assert
+    assert msg.event == expected_event
+    assert msg.message == expected_message
+
+
+def test_parse_futures_ws_book_snapshot_to_deltas() -> None:
+    # Arrange
+    raw = (
+        b'{"feed":"book_snapshot","product_id":"PF_XBTUSD","timestamp":1717149600123,"seq":326,'
+        b'"tickSize":null,"bids":[{"price":67000.0,"qty":1.5}],'
+        b'"asks":[{"price":67000.5,"qty":2.0},{"price":67001.0,"qty":0.5}]}'
+    )
+    msg = msgspec.json.decode(raw, type=KrakenFuturesWsBookSnapshotMsg)
+
+    # Act
+    deltas = msg.parse_to_deltas(
+        instrument_id=PF_XBTUSD_ID,
+        price_precision=1,
+        size_precision=4,
+        ts_init=0,
+    )
+
+    # Assert
+    assert len(deltas.deltas) == 4
+    assert deltas.deltas[0].action == BookAction.CLEAR
+    assert deltas.deltas[0].sequence == 326
+    assert deltas.deltas[1].order.side == OrderSide.BUY
+    assert deltas.deltas[1].order.size == Quantity.from_str("1.5000")
+    assert deltas.deltas[3].order.price == Price.from_str("67001.0")
+    assert deltas.deltas[3].flags == RecordFlag.F_LAST
+    assert deltas.deltas[3].ts_event == 1_717_149_600_123_000_000
+
+
+def test_parse_futures_ws_book_delta_to_deltas() -> None:
+    # Arrange
+    raw = (
+        b'{"feed":"book","product_id":"PF_XBTUSD","side":"sell","seq":327,"price":67000.5,'
+        b'"qty":0.0,"timestamp":1717149600200}'
+    )
+    msg = msgspec.json.decode(raw, type=KrakenFuturesWsBookMsg)
+
+    # Act
+    deltas = msg.parse_to_deltas(
+        instrument_id=PF_XBTUSD_ID,
+        price_precision=1,
+        size_precision=4,
+        ts_init=0,
+    )
+
+    # Assert
+    delta = deltas.deltas[0]
+    assert delta.action == BookAction.DELETE
+    assert delta.order.side == OrderSide.SELL
+    assert delta.sequence == 327
+    assert delta.flags == RecordFlag.F_LAST
+    assert delta.ts_event == 1_717_149_600_200_000_000
+
+
+def test_parse_futures_ws_trade_to_trade_tick() -> None:
+    # Arrange
+    raw = (
+        b'{"feed":"trade","product_id":"PF_XBTUSD","uid":"05af78ac-a774-478c-a50c-8b9c234e071e",'
+        b'"side":"buy","type":"fill","seq":5,"time":1717149600300,"qty":0.1,"price":67000.5}'
+    )
+    msg = msgspec.json.decode(raw, type=KrakenFuturesWsTradeMsg)
+
+    # Act
+    trade = msg.parse_to_trade_tick(
+        instrument_id=PF_XBTUSD_ID,
+        price_precision=1,
+        size_precision=4,
+        ts_init=0,
+    )
+
+    # Assert
+    assert trade.price == Price.from_str("67000.5")
+    assert trade.size == Quantity.from_str("0.1000")
+    assert trade.aggressor_side == AggressorSide.BUYER
+    assert trade.trade_id.value == "05af78ac-a774-478c-a50c-8b9c234e071e"
+    assert trade.ts_event == 1_717_149_600_300_000_000
+
+
+def test_parse_futures_ws_ticker_to_quote_tick() -> None:
+    # Arrange
+    raw = (
+        b'{"time":1717149600400,"feed":"ticker","product_id":"PF_XBTUSD","bid":67000.0,'
+        b'"ask":67000.5,"bid_size":1.5,"ask_size":2.0,"volume":1000.0,"dtm":0,'
+        b'"leverage":"50x","index":67000.2,"premium":0.0,"last":67000.0,"change":0.5,'
+        b'"funding_rate":0.0001,"suspended":false,"tag":"perpetual","pair":"XBT:USD",'
+        b'"openInterest":500.0,"markPrice":67000.25,"maturityTime":0,"post_only":false}'
+    )
+    msg = msgspec.json.decode(raw, type=KrakenFuturesWsTickerMsg)
+
+    # Act
+    quote = msg.parse_to_quote_tick(
+        instrument_id=PF_XBTUSD_ID,
+        price_precision=1,
+        size_precision=4,
+        ts_init=0,
+    )
+
+    # Assert
+    assert quote.bid_price == Price.from_str("67000.0")
+    assert quote.ask_price == Price.from_str("67000.5")
+    assert quote.bid_size == Quantity.from_str("1.5000")
+    assert quote.ts_event == 1_717_149_600_400_000_000
+
+
+def test_decode_futures_ws_open_orders_update() -> None:
+    # Arrange
+    raw = (
+        b'{"feed":"open_orders","order":{"instrument":"PF_XBTUSD","time":1717149599123,'
+        b'"last_update_time":1717149600000,"qty":1.0,"filled":0.25,"limit_price":67000.0,'
+        b'"stop_price":0.0,"type":"limit","order_id":"59302619-41d2-4f0b-941f-7e7914760ad3",'
+        b'"cli_ord_id":"O-456","direction":1,"reduce_only":false},"is_cancel":false,'
+        b'"reason":"partial_fill"}'
+    )
+
+    # Act
+    msg = msgspec.json.decode(raw, type=KrakenFuturesWsOpenOrdersMsg)
+
+    # Assert
+    assert not msg.is_cancel
+    assert msg.reason == "partial_fill"
+    assert msg.order is not None
+    assert msg.order.direction == 1
+    assert msg.order.filled == 0.25
+    assert msg.order.cli_ord_id == "O-456"
+
+
+def test_decode_futures_ws_open_orders_cancel() -> None:
+    # Arrange
+    raw = (
+        b'{"feed":"open_orders","order_id":"59302619-41d2-4f0b-941f-7e7914760ad3",'
+        b'"cli_ord_id":"O-456","is_cancel":true,"reason":"cancelled_by_user"}'
+    )
+
+    # Act
+    msg = msgspec.json.decode(raw, type=KrakenFuturesWsOpenOrdersMsg)
+
+    # Assert
+    assert msg.is_cancel
+    assert msg.order is None
+    assert msg.order_id == "59302619-41d2-4f0b-941f-7e7914760ad3"
+
+
+def test_decode_futures_ws_fills() -> None:
+    # Arrange
+    raw = (
+        b'{"feed":"fills","username":"test","fills":[{"instrument":"PF_XBTUSD",'
+        b'"time":1717149600500,"price":67000.0,"seq":100,"buy":true,"qty":0.25,'
+        b'"remaining_order_qty":0.75,"order_id":"59302619-41d2-4f0b-941f-7e7914760ad3",'
+        b'"cli_ord_id":"O-456","fill_id":"3d57ed09-fbd6-44f1-8e8b-b10e551c5e73",'
+        b'"fill_type":"maker","fee_paid":0.00335,"fee_currency":"USD",'
+        b'"taker_order_type":"ioc","order_type":"limit"}]}'
+    )
+
+    # Act
+    msg = msgspec.json.decode(raw, type=KrakenFuturesWsFillsMsg)
+
+    # Assert
+    fill = msg.fills[0]
+    assert fill.buy
+    assert fill.qty == 0.25
+    assert fill.fill_type == "maker"
+    assert fill.fee_paid == 0.00335
+    assert fill.fee_currency == "USD"
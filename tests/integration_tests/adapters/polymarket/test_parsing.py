@@ -17,7 +17,10 @@ import pkgutil
 
 import msgspec
 import pytest
+from py_clob_client.utilities import parse_raw_orderbook_summary
 
+from nautilus_trader.adapters.polymarket.common.parsing import is_neg_risk_market
+from nautilus_trader.adapters.polymarket.common.parsing import parse_book_snapshot
 from nautilus_trader.adapters.polymarket.common.parsing import parse_instrument
 from nautilus_trader.adapters.polymarket.schemas.book import PolymarketBookSnapshot
 from nautilus_trader.adapters.polymarket.schemas.book import PolymarketQuotes
@@ -83,6 +86,42 @@ def test_parse_order_book_snapshots() -> None:
     assert snapshot.ts_init == 1728799418260000001
 
 
+def test_parse_book_snapshot_from_order_book_summary() -> None:
+    # Arrange
+    data = pkgutil.get_data(
+        "tests.integration_tests.adapters.polymarket.resources.http_responses",
+        "book.json",
+    )
+    assert data
+
+    summary = parse_raw_orderbook_summary(msgspec.json.decode(data))
+
+    # Act
+    ws_message = parse_book_snapshot(summary, ts_init=0)
+
+    # Assert
+    assert isinstance(ws_message, PolymarketBookSnapshot)
+    assert ws_message.asset_id == summary.asset_id
+    assert ws_message.timestamp == "1728799418260"
+    assert ws_message.bids[-1].price == "0.1"
+    assert ws_message.asks[-1].price == "0.14"
+    assert len(ws_message.bids) + len(ws_message.asks) == 12
+
+
+def test_is_neg_risk_market() -> None:
+    # Arrange
+    data = pkgutil.get_data(
+        "tests.integration_tests.adapters.polymarket.resources.http_responses",
+        "market.json",
+    )
+    assert data
+    market_info = msgspec.json.decode(data)
+
+    # Act, Assert
+    assert is_neg_risk_market(market_info)
+    assert not is_neg_risk_market({**market_info, "neg_risk": False})
+
+
 def test_parse_order_book_deltas() -> None:
     # Arrange
     data = pkgutil.get_data(
@@ -222,6 +261,26 @@ def test_parse_user_trade(data_file: str) -> None:
     assert isinstance(msg, PolymarketUserTrade)
 
 
+def test_user_trade_maker_asset_id_on_complementary_token() -> None:
+    # Arrange
+    data = pkgutil.get_data(
+        "tests.integration_tests.adapters.polymarket.resources.ws_messages",
+        "user_trade1.json",
+    )
+    assert data
+
+    msg = msgspec.json.decode(data, type=PolymarketUserTrade)
+
+    # Act
+    asset_id = msg.get_asset_id("0xFfd192468b7a05b38c37C82d09fA941289FaEB23")
+
+    # Assert
+    assert asset_id != msg.asset_id
+    assert asset_id == (
+        "48331043336612883890938759509493159234755048973500640148014422747788308965732"
+    )
+
+
 def test_parse_user_trade_to_dict() -> None:
     # Arrange
     data = pkgutil.get_data(
@@ -0,0 +1,49 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pkgutil
+from unittest.mock import MagicMock
+
+import msgspec
+
+from nautilus_trader.adapters.polymarket.providers import PolymarketInstrumentProvider
+from nautilus_trader.common.component import LiveClock
+
+
+def test_load_instrument_maps_tokens_and_neg_risk_market() -> None:
+    # Arrange
+    data = pkgutil.get_data(
+        "tests.integration_tests.adapters.polymarket.resources.http_responses",
+        "market.json",
+    )
+    assert data
+    market_info = msgspec.json.decode(data)
+    provider = PolymarketInstrumentProvider(client=MagicMock(), clock=LiveClock())
+
+    # Act
+    instruments = [
+        provider._load_instrument(market_info, token["token_id"], token["outcome"])
+        for token in market_info["tokens"]
+    ]
+
+    # Assert
+    assert len(instruments) == 2
+    for token, instrument in zip(market_info["tokens"], instruments, strict=True):
+        assert provider.find_by_token_id(token["token_id"]) == instrument
+    assert provider.find_by_token_id("unknown") is None
+    assert provider.neg_risk_instrument_ids(market_info["neg_risk_market_id"]) == sorted(
+        [instrument.id for instrument in instruments],
+        key=lambda x: x.value,
+    )
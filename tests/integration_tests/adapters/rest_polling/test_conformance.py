@@ -0,0 +1,230 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from decimal import Decimal
+from pathlib import Path
+
+import pytest
+import pytest_asyncio
+from aiohttp import web
+
+from nautilus_trader.adapters.rest_polling.config import RestPollingExecClientConfig
+from nautilus_trader.adapters.rest_polling.execution import RestPollingExecutionClient
+from nautilus_trader.adapters.rest_polling.http.client import RestPollingHttpClient
+from nautilus_trader.adapters.rest_polling.providers import RestPollingInstrumentProvider
+from nautilus_trader.adapters.rest_polling.schema import RestPollingMapping
+from nautilus_trader.adapters.rest_polling.schema import load_mapping
+from nautilus_trader.config import InstrumentProviderConfig
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.enums import order_side_from_str
+from nautilus_trader.model.enums import order_side_to_str
+from nautilus_trader.model.enums import order_type_from_str
+from nautilus_trader.model.enums import order_type_to_str
+from nautilus_trader.model.events import OrderFilled
+from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.model.instruments import CurrencyPair
+from nautilus_trader.model.objects import Currency
+from tests.integration_tests.adapters.conformance.harness import ExecutionConformanceHarness
+from tests.integration_tests.adapters.conformance.scenario import standard_scenario
+from tests.integration_tests.adapters.conformance.venue import MockVenue
+from tests.integration_tests.adapters.conformance.venue import MockVenueOrder
+
+
+RESOURCES_PATH = Path(__file__).parent / "resources"
+MAPPING_PATH = RESOURCES_PATH / "binance_spot.json"
+
+
+class BinanceSpotMockVenue(MockVenue):
+    """
+    A mock of the Binance Spot order endpoints described by the `binance_spot.json` mapping.
+    """
+
+    def routes(self) -> list[web.RouteDef]:
+        return [
+            web.post("/api/v3/order", self._handle_submit),
+            web.delete("/api/v3/order", self._handle_cancel),
+            web.get("/api/v3/order", self._handle_query),
+            web.get("/api/v3/openOrders", self._handle_open_orders),
+            web.get("/api/v3/account", self._handle_account),
+        ]
+
+    async def _handle_submit(self, request: web.Request) -> web.Response:
+        query = request.query
+        order = self.add_order(
+            client_order_id=query["newClientOrderId"],
+            symbol=query["symbol"],
+            side=order_side_from_str(query["side"]),
+            order_type=order_type_from_str(query["type"]),
+            quantity=Decimal(query["quantity"]),
+            price=Decimal(query["price"]) if "price" in query else None,
+        )
+        return web.json_response(_order_json(order))
+
+    async def _handle_cancel(self, request: web.Request) -> web.Response:
+        order = self.find_order(venue_order_id=request.query["orderId"])
+        if order is None or not order.is_open:
+            return web.json_response({"code": -2011, "msg": "Unknown order sent."}, status=400)
+
+        order = await self.cancel_order(order.client_order_id)
+        return web.json_response(_order_json(order))
+
+    async def _handle_query(self, request: web.Request) -> web.Response:
+        order = self.find_order(venue_order_id=request.query["orderId"])
+        if order is None:
+            return web.json_response({"code": -2013, "msg": "Order does not exist."}, status=400)
+
+        return web.json_response(_order_json(order))
+
+    async def _handle_open_orders(self, request: web.Request) -> web.Response:
+        orders = [_order_json(order) for order in self.orders.values() if order.is_open]
+        return web.json_response(orders)
+
+    async def _handle_account(self, request: web.Request) -> web.Response:
+        return web.json_response(
+            {
+                "balances": [
+                    {"asset": "BTC", "free": "1.00000000", "locked": "0.00000000"},
+                    {"asset": "USDT", "free": "100000.00000000", "locked": "0.00000000"},
+                ],
+            },
+        )
+
+
+_BINANCE_ORDER_STATUSES = {
+    OrderStatus.ACCEPTED: "NEW",
+    OrderStatus.PARTIALLY_FILLED: "PARTIALLY_FILLED",
+    OrderStatus.FILLED: "FILLED",
+    OrderStatus.CANCELED: "CANCELED",
+}
+
+
+def _order_json(order: MockVenueOrder) -> dict:
+    return {
+        "symbol": order.symbol,
+        "orderId": int(order.venue_order_id),
+        "clientOrderId": order.client_order_id,
+        "price": str(order.price or 0),
+        "origQty": str(order.quantity),
+        "executedQty": str(order.filled_qty),
+        "cummulativeQuoteQty": str(order.avg_px * order.filled_qty),
+        "status": _BINANCE_ORDER_STATUSES[order.status],
+        "timeInForce": "GTC",
+        "type": order_type_to_str(order.order_type),
+        "side": order_side_to_str(order.side),
+        "updateTime": order.ts_last,
+    }
+
+
+@pytest.fixture(name="mapping")
+def fixture_mapping() -> RestPollingMapping:
+    return load_mapping(MAPPING_PATH)
+
+
+@pytest.fixture()
+def instrument(mapping: RestPollingMapping) -> CurrencyPair:
+    return mapping.instruments[0].parse_to_instrument(
+        venue=Venue("BINANCE"),
+        base_currency=Currency.from_str("BTC"),
+        quote_currency=Currency.from_str("USDT"),
+        ts_event=0,
+        ts_init=0,
+    )
+
+
+@pytest_asyncio.fixture(name="mock_venue")
+async def fixture_mock_venue(event_loop):
+    venue = BinanceSpotMockVenue()
+    await venue.start()
+    yield venue
+    await venue.stop()
+
+
+@pytest.fixture()
+def exec_client(
+    event_loop,
+    mock_venue: BinanceSpotMockVenue,
+    mapping: RestPollingMapping,
+    msgbus,
+    cache,
+    clock,
+) -> RestPollingExecutionClient:
+    client = RestPollingHttpClient(
+        clock=clock,
+        mapping=mapping,
+        api_key="TEST_API_KEY",
+        api_secret="TEST_API_SECRET",
+        base_url=mock_venue.http_url,
+    )
+    return RestPollingExecutionClient(
+        loop=event_loop,
+        client=client,
+        msgbus=msgbus,
+        cache=cache,
+        clock=clock,
+        instrument_provider=RestPollingInstrumentProvider(
+            mapping=mapping,
+            clock=clock,
+            config=InstrumentProviderConfig(load_all=True),
+        ),
+        config=RestPollingExecClientConfig(
+            mapping_path=str(MAPPING_PATH),
+            order_poll_interval_secs=0.1,
+            account_poll_interval_secs=1.0,
+        ),
+        name=None,
+    )
+
+
+@pytest.mark.asyncio()
+async def test_standard_scenario(
+    exec_client,
+    exec_engine,
+    msgbus,
+    mock_venue,
+    strategy,
+    instrument,
+):
+    # Arrange
+    harness = ExecutionConformanceHarness(
+        exec_client=exec_client,
+        exec_engine=exec_engine,
+        msgbus=msgbus,
+        venue=mock_venue,
+        strategy=strategy,
+        instrument=instrument,
+    )
+    scenario = standard_scenario(
+        side=OrderSide.BUY,
+        quantity="0.01000",
+        price="50000.00",
+        amended_price="49900.00",
+        supports_modify=False,
+    )
+
+    # Act
+    await harness.run(scenario)
+
+    # Assert
+    fills = [event for event in harness.events if isinstance(event, OrderFilled)]
+    assert [fill.last_qty for fill in fills] == [
+        instrument.make_qty(Decimal("0.0025")),
+        instrument.make_qty(Decimal("0.0025")),
+    ]
+    assert harness.order.is_closed
+    assert harness.order.filled_qty == instrument.make_qty(Decimal("0.005"))
+    assert mock_venue.find_order(client_order_id=harness.order.client_order_id.value).status == (
+        OrderStatus.CANCELED
+    )
@@ -0,0 +1,101 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pytest
+
+from nautilus_trader.adapters.rest_polling.http.client import render_params
+from nautilus_trader.adapters.rest_polling.http.client import render_template
+from nautilus_trader.adapters.rest_polling.http.client import sign_payload
+from nautilus_trader.adapters.rest_polling.http.errors import RestPollingError
+from nautilus_trader.adapters.rest_polling.http.errors import should_retry
+
+
+def test_render_template() -> None:
+    # Arrange, Act
+    result = render_template("/orders/{symbol}/{order_id}", {"symbol": "BTCUSDT", "order_id": 1})
+
+    # Assert
+    assert result == "/orders/BTCUSDT/1"
+
+
+def test_render_template_with_missing_value_raises() -> None:
+    # Arrange, Act, Assert
+    with pytest.raises(ValueError, match="No value for placeholder 'order_id'"):
+        render_template("/orders/{order_id}", {"order_id": None})
+
+
+def test_render_params_omits_optional_params() -> None:
+    # Arrange
+    templates = {
+        "symbol": "{symbol}",
+        "price": "{price}",
+        "timeInForce": "{time_in_force}",
+        "pair": "{symbol}-SPOT",
+        "recvWindow": "5000",
+    }
+
+    # Act
+    result = render_params(templates, {"symbol": "BTCUSDT", "price": None})
+
+    # Assert
+    assert result == {"symbol": "BTCUSDT", "pair": "BTCUSDT-SPOT", "recvWindow": "5000"}
+
+
+def test_sign_payload_hex() -> None:
+    # Arrange (example from the Binance API documentation)
+    secret = "NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j"
+    message = (
+        b"symbol=LTCBTC&side=BUY&type=LIMIT&timeInForce=GTC&quantity=1"
+        b"&price=0.1&recvWindow=5000&timestamp=1499827319559"
+    )
+
+    # Act
+    result = sign_payload(secret, message)
+
+    # Assert
+    assert result == "c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71"
+
+
+def test_sign_payload_base64() -> None:
+    # Arrange
+    secret = "NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j"
+    message = (
+        b"symbol=LTCBTC&side=BUY&type=LIMIT&timeInForce=GTC&quantity=1"
+        b"&price=0.1&recvWindow=5000&timestamp=1499827319559"
+    )
+
+    # Act
+    result = sign_payload(secret, message, encoding="base64")
+
+    # Assert
+    assert result == "yNtWglrnHW15RHhJ5hcRX0qSD6Ks3KsrBTxLKDi9a3E="
+
+
+@pytest.mark.parametrize(
+    ("code", "expected"),
+    [
+        (429, True),
+        (503, True),
+        (400, False),
+        ("-2010", False),
+        (None, False),
+    ],
+)
+def test_should_retry(code: int | str | None, expected: bool) -> None:
+    # Arrange
+    error = RestPollingError(code=code, message="error")
+
+    # Act, Assert
+    assert should_retry(error) == expected
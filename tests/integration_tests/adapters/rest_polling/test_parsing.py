@@ -0,0 +1,371 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from decimal import Decimal
+from pathlib import Path
+
+import pytest
+
+from nautilus_trader.adapters.rest_polling.parsing import RestPollingParser
+from nautilus_trader.adapters.rest_polling.schema import RestPollingEndpoint
+from nautilus_trader.adapters.rest_polling.schema import RestPollingMapping
+from nautilus_trader.adapters.rest_polling.schema import load_mapping
+from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.model.data import BarType
+from nautilus_trader.model.enums import AggressorSide
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import OrderStatus
+from nautilus_trader.model.enums import OrderType
+from nautilus_trader.model.enums import TimeInForce
+from nautilus_trader.model.identifiers import AccountId
+from nautilus_trader.model.identifiers import ClientOrderId
+from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.model.instruments import CurrencyPair
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+
+
+RESOURCES_PATH = Path(__file__).parent / "resources"
+
+
+@pytest.fixture(name="mapping")
+def fixture_mapping() -> RestPollingMapping:
+    return load_mapping(RESOURCES_PATH / "binance_spot.json")
+
+
+@pytest.fixture(name="parser")
+def fixture_parser(mapping: RestPollingMapping) -> RestPollingParser:
+    return RestPollingParser(mapping)
+
+
+@pytest.fixture(name="instrument")
+def fixture_instrument(mapping: RestPollingMapping) -> CurrencyPair:
+    return mapping.instruments[0].parse_to_instrument(
+        venue=Venue("BINANCE"),
+        base_currency=Currency.from_str("BTC"),
+        quote_currency=Currency.from_str("USDT"),
+        ts_event=0,
+        ts_init=0,
+    )
+
+
+def _endpoint(endpoint: RestPollingEndpoint | None) -> RestPollingEndpoint:
+    assert endpoint is not None
+    return endpoint
+
+
+def test_parse_quote_tick(
+    mapping: RestPollingMapping,
+    parser: RestPollingParser,
+    instrument: CurrencyPair,
+) -> None:
+    # Arrange
+    response = {
+        "symbol": "BTCUSDT",
+        "bidPrice": "65000.01000000",
+        "bidQty": "1.50000000",
+        "askPrice": "65000.02000000",
+        "askQty": "0.25000000",
+    }
+
+    # Act
+    quote = parser.parse_quote_tick(response, _endpoint(mapping.endpoints.quote), instrument, 1)
+
+    # Assert
+    assert quote is not None
+    assert quote.bid_price == Price.from_str("65000.01")
+    assert quote.ask_price == Price.from_str("65000.02")
+    assert quote.bid_size == Quantity.from_str("1.50000")
+    assert quote.ask_size == Quantity.from_str("0.25000")
+    assert quote.ts_event == 1  # No venue timestamp
+
+
+def test_parse_quote_tick_with_missing_side_returns_none(
+    mapping: RestPollingMapping,
+    parser: RestPollingParser,
+    instrument: CurrencyPair,
+) -> None:
+    # Arrange
+    response = {"symbol": "BTCUSDT", "bidPrice": "65000.01000000", "bidQty": "1.50000000"}
+
+    # Act
+    quote = parser.parse_quote_tick(response, _endpoint(mapping.endpoints.quote), instrument, 1)
+
+    # Assert
+    assert quote is None
+
+
+def test_parse_trade_ticks(
+    mapping: RestPollingMapping,
+    parser: RestPollingParser,
+    instrument: CurrencyPair,
+) -> None:
+    # Arrange
+    response = [
+        {
+            "id": 2,
+            "price": "65000.02000000",
+            "qty": "0.10000000",
+            "time": 1700000001000,
+            "isBuyerMaker": False,
+        },
+        {
+            "id": 1,
+            "price": "65000.01000000",
+            "qty": "0.20000000",
+            "time": 1700000000000,
+            "isBuyerMaker": True,
+        },
+    ]
+
+    # Act
+    trades = parser.parse_trade_ticks(
+        response,
+        _endpoint(mapping.endpoints.trades),
+        instrument,
+        1,
+    )
+
+    # Assert
+    assert len(trades) == 2
+    assert trades[0].trade_id.value == "1"  # Sorted by `ts_event`
+    assert trades[0].aggressor_side == AggressorSide.SELLER
+    assert trades[0].ts_event == 1_700_000_000_000_000_000
+    assert trades[1].aggressor_side == AggressorSide.BUYER
+    assert trades[1].size == Quantity.from_str("0.10000")
+
+
+def test_parse_bars(
+    mapping: RestPollingMapping,
+    parser: RestPollingParser,
+    instrument: CurrencyPair,
+) -> None:
+    # Arrange
+    bar_type = BarType.from_str("BTCUSDT.BINANCE-1-MINUTE-LAST-EXTERNAL")
+    response = [
+        [
+            1700000000000,
+            "65000.00000000",
+            "65010.00000000",
+            "64990.00000000",
+            "65005.00000000",
+            "12.34567000",
+            1700000059999,
+            "802500.00000000",
+            100,
+            "6.00000000",
+            "390000.00000000",
+            "0",
+        ],
+    ]
+
+    # Act
+    bars = parser.parse_bars(
+        response,
+        _endpoint(mapping.endpoints.klines),
+        bar_type,
+        instrument,
+        1,
+    )
+
+    # Assert
+    assert len(bars) == 1
+    assert bars[0].open == Price.from_str("65000.00")
+    assert bars[0].high == Price.from_str("65010.00")
+    assert bars[0].low == Price.from_str("64990.00")
+    assert bars[0].close == Price.from_str("65005.00")
+    assert bars[0].volume == Quantity.from_str("12.34567")
+    assert bars[0].ts_event == 1_700_000_060_000_000_000  # Close time
+
+
+def test_bar_interval(parser: RestPollingParser) -> None:
+    # Arrange
+    bar_type_1m = BarType.from_str("BTCUSDT.BINANCE-1-MINUTE-LAST-EXTERNAL")
+    bar_type_2m = BarType.from_str("BTCUSDT.BINANCE-2-MINUTE-LAST-EXTERNAL")
+
+    # Act, Assert
+    assert parser.bar_interval(bar_type_1m) == "1m"
+    assert parser.bar_interval(bar_type_2m) is None
+
+
+def test_parse_order_status_report(
+    mapping: RestPollingMapping,
+    parser: RestPollingParser,
+    instrument: CurrencyPair,
+) -> None:
+    # Arrange
+    item = {
+        "symbol": "BTCUSDT",
+        "orderId": 28,
+        "clientOrderId": "O-20240101-000000-001-001-1",
+        "price": "65000.00000000",
+        "origQty": "0.10000000",
+        "executedQty": "0.05000000",
+        "cummulativeQuoteQty": "3249.50000000",
+        "status": "PARTIALLY_FILLED",
+        "timeInForce": "IOC",
+        "type": "LIMIT",
+        "side": "SELL",
+        "updateTime": 1700000000000,
+    }
+
+    # Act
+    report = parser.parse_order_status_report(
+        item=item,
+        endpoint=_endpoint(mapping.endpoints.order_status),
+        account_id=AccountId("BINANCE-001"),
+        instrument=instrument,
+        report_id=UUID4(),
+        ts_init=1,
+    )
+
+    # Assert
+    assert report.venue_order_id.value == "28"
+    assert report.client_order_id == ClientOrderId("O-20240101-000000-001-001-1")
+    assert report.order_status == OrderStatus.PARTIALLY_FILLED
+    assert report.order_side == OrderSide.SELL
+    assert report.order_type == OrderType.LIMIT
+    assert report.time_in_force == TimeInForce.IOC
+    assert report.price == Price.from_str("65000.00")
+    assert report.quantity == Quantity.from_str("0.10000")
+    assert report.filled_qty == Quantity.from_str("0.05000")
+    assert report.avg_px == Decimal("64990")
+    assert report.ts_last == 1_700_000_000_000_000_000
+
+
+def test_parse_order_status_report_for_market_order(
+    mapping: RestPollingMapping,
+    parser: RestPollingParser,
+    instrument: CurrencyPair,
+) -> None:
+    # Arrange
+    client_order_id = ClientOrderId("O-20240101-000000-001-001-2")
+    item = {
+        "orderId": 29,
+        "price": "0.00000000",
+        "origQty": "0.10000000",
+        "executedQty": "0.00000000",
+        "cummulativeQuoteQty": "0.00000000",
+        "status": "EXPIRED_IN_MATCH",
+        "type": "MARKET",
+        "side": "BUY",
+    }
+
+    # Act
+    report = parser.parse_order_status_report(
+        item=item,
+        endpoint=_endpoint(mapping.endpoints.order_status),
+        account_id=AccountId("BINANCE-001"),
+        instrument=instrument,
+        report_id=UUID4(),
+        ts_init=1,
+        client_order_id=client_order_id,
+    )
+
+    # Assert
+    assert report.client_order_id == client_order_id
+    assert report.order_status == OrderStatus.EXPIRED
+    assert report.order_type == OrderType.MARKET
+    assert report.price is None
+    assert report.avg_px is None
+    assert report.ts_last == 1
+
+
+def test_parse_order_status_report_with_unmapped_status_raises(
+    mapping: RestPollingMapping,
+    parser: RestPollingParser,
+    instrument: CurrencyPair,
+) -> None:
+    # Arrange
+    item = {"orderId": 30, "status": "UNKNOWN", "origQty": "0.1", "executedQty": "0"}
+
+    # Act, Assert
+    with pytest.raises(ValueError, match="Unmapped venue order status 'UNKNOWN'"):
+        parser.parse_order_status_report(
+            item=item,
+            endpoint=_endpoint(mapping.endpoints.order_status),
+            account_id=AccountId("BINANCE-001"),
+            instrument=instrument,
+            report_id=UUID4(),
+            ts_init=1,
+        )
+
+
+def test_parse_account_balances(mapping: RestPollingMapping, parser: RestPollingParser) -> None:
+    # Arrange
+    response = {
+        "balances": [
+            {"asset": "BTC", "free": "1.00000000", "locked": "0.50000000"},
+            {"asset": "USDT", "free": "1000.00000000", "locked": "0.00000000"},
+            {"asset": "ETH", "free": "0.00000000", "locked": "0.00000000"},
+        ],
+    }
+
+    # Act
+    balances = parser.parse_account_balances(response, _endpoint(mapping.endpoints.balances))
+
+    # Assert
+    assert len(balances) == 2  # Zero balances skipped
+    assert balances[0].currency == Currency.from_str("BTC")
+    assert balances[0].total.as_decimal() == Decimal("1.5")
+    assert balances[0].free.as_decimal() == Decimal("1")
+    assert balances[0].locked.as_decimal() == Decimal("0.5")
+    assert balances[1].total.as_decimal() == Decimal("1000")
+
+
+@pytest.mark.parametrize(
+    ("unit", "value", "expected"),
+    [
+        ("s", "1700000000.5", 1_700_000_000_500_000_000),
+        ("ms", 1700000000000, 1_700_000_000_000_000_000),
+        ("us", "1700000000000000", 1_700_000_000_000_000_000),
+        ("ns", 1700000000000000000, 1_700_000_000_000_000_000),
+        ("iso", "2023-11-14T22:13:20.000Z", 1_700_000_000_000_000_000),
+    ],
+)
+def test_parse_timestamp(
+    mapping: RestPollingMapping,
+    unit: str,
+    value: object,
+    expected: int,
+) -> None:
+    # Arrange
+    parser = RestPollingParser(
+        RestPollingMapping(
+            venue=mapping.venue,
+            base_url=mapping.base_url,
+            instruments=[],
+            endpoints=mapping.endpoints,
+            timestamp_unit=unit,
+        ),
+    )
+
+    # Act, Assert
+    assert parser.parse_timestamp(value) == expected
+
+
+def test_format_timestamp(parser: RestPollingParser) -> None:
+    # Arrange, Act, Assert
+    assert parser.format_timestamp(1_700_000_000_123_456_789) == "1700000000123"
+
+
+def test_format_request_values(parser: RestPollingParser) -> None:
+    # Arrange, Act, Assert
+    assert parser.format_order_side(OrderSide.BUY) == "BUY"
+    assert parser.format_order_type(OrderType.LIMIT) == "LIMIT"
+    assert parser.format_order_type(OrderType.STOP_MARKET) is None
+    assert parser.format_time_in_force(TimeInForce.FOK) == "FOK"
+    assert parser.format_time_in_force(TimeInForce.GTD) is None
@@ -0,0 +1,174 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from decimal import Decimal
+from pathlib import Path
+
+import msgspec
+import pytest
+
+from nautilus_trader.adapters.rest_polling.schema import RestPollingMapping
+from nautilus_trader.adapters.rest_polling.schema import extract_field
+from nautilus_trader.adapters.rest_polling.schema import load_mapping
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+
+
+RESOURCES_PATH = Path(__file__).parent / "resources"
+
+MINIMAL_TOML = """
+venue = "TEST"
+base_url = "https://api.test.com"
+
+[[instruments]]
+symbol = "BTC-USD"
+base = "BTC"
+quote = "USD"
+price_precision = 1
+size_precision = 4
+
+[endpoints.quote]
+path = "/ticker/{symbol}"
+fields = { bid_price = "bid", ask_price = "ask", bid_size = "bidSize", ask_size = "askSize" }
+"""
+
+
+def test_load_mapping_from_json() -> None:
+    # Arrange, Act
+    mapping = load_mapping(RESOURCES_PATH / "binance_spot.json")
+
+    # Assert
+    assert mapping.venue == "BINANCE"
+    assert mapping.rate_limit_per_second == 10
+    assert len(mapping.instruments) == 2
+    assert mapping.auth is not None
+    assert mapping.auth.api_key_header == "X-MBX-APIKEY"
+    assert mapping.endpoints.quote is not None
+    assert mapping.endpoints.quote.method == "GET"
+    assert mapping.endpoints.submit_order is not None
+    assert mapping.endpoints.submit_order.signed
+    assert mapping.order_statuses["EXPIRED_IN_MATCH"] == "EXPIRED"
+
+
+def test_load_mapping_from_toml(tmp_path: Path) -> None:
+    # Arrange
+    path = tmp_path / "mapping.toml"
+    path.write_text(MINIMAL_TOML)
+
+    # Act
+    mapping = load_mapping(path)
+
+    # Assert
+    assert mapping.venue == "TEST"
+    assert mapping.auth is None
+    assert mapping.endpoints.quote is not None
+    assert mapping.endpoints.quote.fields["bid_price"] == "bid"
+    assert mapping.endpoints.trades is None
+    assert mapping.order_sides == {"BUY": "BUY", "SELL": "SELL"}
+
+
+def test_load_mapping_with_unsupported_file_type_raises(tmp_path: Path) -> None:
+    # Arrange
+    path = tmp_path / "mapping.yaml"
+    path.write_text("venue: TEST")
+
+    # Act, Assert
+    with pytest.raises(ValueError, match="Unsupported mapping file type"):
+        load_mapping(path)
+
+
+@pytest.mark.parametrize(
+    ("patch", "message"),
+    [
+        ({"timestamp_unit": "minutes"}, "invalid `timestamp_unit`"),
+        ({"order_statuses": {"open": "OPEN"}}, "invalid order status"),
+        ({"aggressor_sides": {"b": "BUY"}}, "invalid aggressor side"),
+        ({"rate_limit_per_second": 0}, "must be positive"),
+        (
+            {"endpoints": {"quote": {"path": "/ticker", "method": "FETCH"}}},
+            "invalid `method`",
+        ),
+        (
+            {"endpoints": {"quote": {"path": "/ticker", "encoding": "xml"}}},
+            "invalid `encoding`",
+        ),
+    ],
+)
+def test_invalid_mapping_raises(patch: dict, message: str) -> None:
+    # Arrange
+    raw = {
+        "venue": "TEST",
+        "base_url": "https://api.test.com",
+        "instruments": [],
+        "endpoints": {},
+        **patch,
+    }
+
+    # Act, Assert
+    with pytest.raises(msgspec.ValidationError, match=message):
+        msgspec.json.decode(msgspec.json.encode(raw), type=RestPollingMapping)
+
+
+@pytest.mark.parametrize(
+    ("path", "expected"),
+    [
+        (None, None),
+        ("", {"result": [{"price": "1.5"}, [10, 20]]}),
+        ("result", [{"price": "1.5"}, [10, 20]]),
+        ("result.0.price", "1.5"),
+        ("result.1.1", 20),
+        ("result.2", None),
+        ("result.first", None),
+        ("missing.price", None),
+        ("result.0.price.value", None),
+    ],
+)
+def test_extract_field(path: str | None, expected: object) -> None:
+    # Arrange
+    obj = {"result": [{"price": "1.5"}, [10, 20]]}
+
+    # Act
+    result = extract_field(obj, path)
+
+    # Assert
+    assert result == expected
+
+
+def test_instrument_parse_to_instrument() -> None:
+    # Arrange
+    mapping = load_mapping(RESOURCES_PATH / "binance_spot.json")
+    definition = mapping.instruments[0]
+
+    # Act
+    instrument = definition.parse_to_instrument(
+        venue=Venue("BINANCE"),
+        base_currency=Currency.from_str("BTC"),
+        quote_currency=Currency.from_str("USDT"),
+        ts_event=0,
+        ts_init=0,
+    )
+
+    # Assert
+    assert instrument.id == InstrumentId.from_str("BTCUSDT.BINANCE")
+    assert instrument.raw_symbol.value == "BTCUSDT"
+    assert instrument.price_increment == Price.from_str("0.01")
+    assert instrument.size_increment == Quantity.from_str("0.00001")
+    assert instrument.min_quantity == Quantity.from_str("0.00001")
+    assert instrument.min_notional.as_decimal() == Decimal(5)
+    assert instrument.maker_fee == Decimal("0.001")
+    assert instrument.info["symbol"] == "BTCUSDT"
@@ -0,0 +1,133 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pytest
+
+from nautilus_trader.adapters.symbology import InstrumentSymbolRegistry
+from nautilus_trader.adapters.symbology import canonical_symbol
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.test_kit.providers import TestInstrumentProvider
+
+
+BINANCE = Venue("BINANCE")
+BITMEX = Venue("BITMEX")
+
+BTCUSDT_BINANCE = TestInstrumentProvider.btcusdt_binance()
+BTCUSDT_PERP_BINANCE = TestInstrumentProvider.btcusdt_perp_binance()
+ETHUSDT_PERP_BINANCE = TestInstrumentProvider.ethusdt_perp_binance()
+XBTUSD_BITMEX = TestInstrumentProvider.xbtusd_bitmex()
+ETHUSD_BITMEX = TestInstrumentProvider.ethusd_bitmex()
+
+
+@pytest.mark.parametrize(
+    ("instrument", "expected"),
+    [
+        [BTCUSDT_BINANCE, "BTC/USDT"],
+        [BTCUSDT_PERP_BINANCE, "BTC/USDT-PERP"],
+        [XBTUSD_BITMEX, "BTC/USD-PERP"],
+        [TestInstrumentProvider.btcusdt_future_binance(), "BTC/USDT-20220325"],
+        [TestInstrumentProvider.aapl_option(), "AAPL/USD-20211217-149-C"],
+    ],
+)
+def test_canonical_symbol(instrument, expected: str) -> None:
+    # Arrange, Act, Assert
+    assert canonical_symbol(instrument) == expected
+
+
+def test_canonical_symbol_with_quote_aliases() -> None:
+    # Arrange, Act
+    result = canonical_symbol(BTCUSDT_PERP_BINANCE, quote_aliases={"USDT": "USD"})
+
+    # Assert
+    assert result == "BTC/USD-PERP"
+
+
+def test_canonical_symbol_for_unsupported_instrument_class_returns_none() -> None:
+    # Arrange, Act, Assert
+    assert canonical_symbol(TestInstrumentProvider.betting_instrument()) is None
+
+
+def test_registry_maps_equivalent_instruments_across_venues() -> None:
+    # Arrange
+    registry = InstrumentSymbolRegistry(quote_aliases={"USDT": "USD"})
+
+    # Act
+    registry.add_instruments(
+        [BTCUSDT_BINANCE, BTCUSDT_PERP_BINANCE, ETHUSDT_PERP_BINANCE, XBTUSD_BITMEX, ETHUSD_BITMEX],
+    )
+
+    # Assert
+    assert registry.canonical(XBTUSD_BITMEX.id) == "BTC/USD-PERP"
+    assert registry.venues("BTC/USD-PERP") == [BINANCE, BITMEX]
+    assert registry.instrument_ids("BTC/USD-PERP") == [BTCUSDT_PERP_BINANCE.id, XBTUSD_BITMEX.id]
+    assert registry.instrument_id("BTC/USD-PERP", BITMEX) == XBTUSD_BITMEX.id
+    assert registry.instrument_id("BTC/USD", BITMEX) is None
+    assert registry.equivalents(BTCUSDT_PERP_BINANCE.id) == [XBTUSD_BITMEX.id]
+    assert registry.equivalents(BTCUSDT_BINANCE.id) == []
+    assert registry.canonicals() == ["BTC/USD", "BTC/USD-PERP", "ETH/USD-PERP"]
+    assert registry.canonicals(min_venues=2) == ["BTC/USD-PERP", "ETH/USD-PERP"]
+
+
+def test_registry_add_with_explicit_canonical() -> None:
+    # Arrange
+    registry = InstrumentSymbolRegistry()
+    registry.add(XBTUSD_BITMEX)
+
+    # Act
+    result = registry.add(BTCUSDT_PERP_BINANCE, canonical="BTC/USD-PERP")
+
+    # Assert
+    assert result == "BTC/USD-PERP"
+    assert registry.equivalents(XBTUSD_BITMEX.id) == [BTCUSDT_PERP_BINANCE.id]
+
+
+def test_registry_add_again_replaces_canonical() -> None:
+    # Arrange
+    registry = InstrumentSymbolRegistry()
+    registry.add(BTCUSDT_PERP_BINANCE)
+
+    # Act
+    registry.add(BTCUSDT_PERP_BINANCE, canonical="BTC/USD-PERP")
+
+    # Assert
+    assert registry.canonicals() == ["BTC/USD-PERP"]
+    assert registry.instrument_ids("BTC/USD-PERP") == [BTCUSDT_PERP_BINANCE.id]
+
+
+def test_registry_remove() -> None:
+    # Arrange
+    registry = InstrumentSymbolRegistry()
+    registry.add_instruments([XBTUSD_BITMEX, ETHUSD_BITMEX])
+
+    # Act
+    registry.remove(XBTUSD_BITMEX.id)
+    registry.remove(InstrumentId.from_str("UNKNOWN.BITMEX"))
+
+    # Assert
+    assert registry.canonical(XBTUSD_BITMEX.id) is None
+    assert registry.canonicals() == ["ETH/USD-PERP"]
+
+
+def test_registry_instrument_id_when_ambiguous_raises() -> None:
+    # Arrange
+    registry = InstrumentSymbolRegistry()
+    registry.add(BTCUSDT_BINANCE, canonical="BTC")
+    registry.add(BTCUSDT_PERP_BINANCE, canonical="BTC")
+
+    # Act, Assert
+    assert len(registry.instrument_ids("BTC", BINANCE)) == 2
+    with pytest.raises(ValueError, match="More than one instrument"):
+        registry.instrument_id("BTC", BINANCE)
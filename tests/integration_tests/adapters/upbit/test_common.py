@@ -0,0 +1,145 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from decimal import Decimal
+
+import pytest
+
+from nautilus_trader.adapters.upbit.common.parsing import get_price_precision
+from nautilus_trader.adapters.upbit.common.parsing import get_tick_size
+from nautilus_trader.adapters.upbit.common.parsing import parse_aggressor_side
+from nautilus_trader.adapters.upbit.common.symbol import UpbitSymbol
+from nautilus_trader.adapters.upbit.http.errors import parse_error_message
+from nautilus_trader.model.enums import AggressorSide
+from nautilus_trader.model.identifiers import InstrumentId
+
+
+@pytest.mark.parametrize(
+    ("code", "expected"),
+    [
+        ["KRW-BTC", "KRW-BTC.UPBIT"],
+        ["krw-eth", "KRW-ETH.UPBIT"],
+        ["BTC-XRP", "BTC-XRP.UPBIT"],
+    ],
+)
+def test_upbit_symbol_to_instrument_id(code: str, expected: str) -> None:
+    # Arrange, Act
+    symbol = UpbitSymbol(code)
+
+    # Assert
+    assert symbol.to_instrument_id() == InstrumentId.from_str(expected)
+
+
+def test_upbit_symbol_quote_and_base() -> None:
+    # Arrange, Act
+    symbol = UpbitSymbol("KRW-BTC")
+
+    # Assert
+    assert symbol.quote == "KRW"
+    assert symbol.base == "BTC"
+
+
+@pytest.mark.parametrize("code", ["KRWBTC", "KRW-BTC-1"])
+def test_upbit_symbol_with_invalid_code_raises(code: str) -> None:
+    # Arrange, Act, Assert
+    with pytest.raises(ValueError):
+        UpbitSymbol(code)
+
+
+@pytest.mark.parametrize(
+    ("price", "expected"),
+    [
+        [Decimal("140000000"), Decimal(1000)],
+        [Decimal("1000000"), Decimal(1000)],
+        [Decimal("999999"), Decimal(500)],
+        [Decimal("250000"), Decimal(100)],
+        [Decimal("75000"), Decimal(50)],
+        [Decimal("10000"), Decimal(10)],
+        [Decimal("5000"), Decimal(5)],
+        [Decimal("4999"), Decimal(1)],
+        [Decimal("100"), Decimal(1)],
+        [Decimal("99.9"), Decimal("0.1")],
+        [Decimal("1.5"), Decimal("0.01")],
+        [Decimal("0.5"), Decimal("0.001")],
+        [Decimal("0.05"), Decimal("0.0001")],
+        [Decimal("0.000001"), Decimal("0.00000001")],
+    ],
+)
+def test_get_tick_size_krw(price: Decimal, expected: Decimal) -> None:
+    # Arrange, Act
+    tick_size = get_tick_size("KRW", price)
+
+    # Assert
+    assert tick_size == expected
+
+
+def test_get_tick_size_btc() -> None:
+    # Arrange, Act
+    tick_size = get_tick_size("BTC", Decimal("0.00001234"))
+
+    # Assert
+    assert tick_size == Decimal("0.00000001")
+
+
+def test_get_tick_size_unsupported_quote_raises() -> None:
+    # Arrange, Act, Assert
+    with pytest.raises(ValueError):
+        get_tick_size("USDT", Decimal(1))
+
+
+@pytest.mark.parametrize(
+    ("price", "expected"),
+    [
+        [Decimal("140000000"), 0],
+        [Decimal("3500"), 0],
+        [Decimal("50"), 2],
+        [Decimal("800"), 1],
+        [Decimal("0.5"), 4],
+        [None, 8],
+    ],
+)
+def test_get_price_precision_krw(price: Decimal | None, expected: int) -> None:
+    # Arrange, Act
+    precision = get_price_precision("KRW", price)
+
+    # Assert
+    assert precision == expected
+
+
+@pytest.mark.parametrize(
+    ("ask_bid", "expected"),
+    [
+        ["BID", AggressorSide.BUYER],
+        ["ASK", AggressorSide.SELLER],
+        ["", AggressorSide.NO_AGGRESSOR],
+    ],
+)
+def test_parse_aggressor_side(ask_bid: str, expected: AggressorSide) -> None:
+    # Arrange, Act
+    aggressor_side = parse_aggressor_side(ask_bid)
+
+    # Assert
+    assert aggressor_side == expected
+
+
+def test_parse_error_message() -> None:
+    # Arrange
+    body = {"error": {"name": "404", "message": "Code not found"}}
+
+    # Act
+    message = parse_error_message(body)
+
+    # Assert
+    assert message == "Code not found"
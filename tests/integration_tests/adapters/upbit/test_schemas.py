@@ -0,0 +1,229 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from decimal import Decimal
+
+import msgspec
+import pytest
+
+from nautilus_trader.adapters.upbit.schemas.market import UpbitMarket
+from nautilus_trader.adapters.upbit.schemas.market import UpbitTicker
+from nautilus_trader.adapters.upbit.schemas.ws import UpbitWsOrderbookMsg
+from nautilus_trader.adapters.upbit.schemas.ws import UpbitWsTradeMsg
+from nautilus_trader.model.enums import AggressorSide
+from nautilus_trader.model.enums import BookAction
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.enums import RecordFlag
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.instruments import CurrencyPair
+from nautilus_trader.model.objects import Currency
+from nautilus_trader.model.objects import Money
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+
+
+KRW_BTC = InstrumentId.from_str("KRW-BTC.UPBIT")
+KRW = Currency.from_str("KRW")
+BTC = Currency.from_str("BTC")
+ETH = Currency.from_str("ETH")
+XRP = Currency.from_str("XRP")
+USDT = Currency.from_str("USDT")
+
+
+def _market(code: str) -> UpbitMarket:
+    return UpbitMarket(market=code, market_warning="NONE")
+
+
+def test_parse_krw_market_to_instrument() -> None:
+    # Arrange
+    market = _market("KRW-BTC")
+
+    # Act
+    instrument = market.parse_to_instrument(
+        base_currency=BTC,
+        quote_currency=KRW,
+        last_price=Decimal("140000000"),
+        ts_event=0,
+        ts_init=0,
+    )
+
+    # Assert
+    assert isinstance(instrument, CurrencyPair)
+    assert instrument.id == KRW_BTC
+    assert instrument.raw_symbol.value == "KRW-BTC"
+    assert instrument.base_currency == BTC
+    assert instrument.quote_currency == KRW
+    assert instrument.price_precision == 0
+    assert instrument.price_increment == Price.from_str("1000")
+    assert instrument.size_precision == 8
+    assert instrument.size_increment == Quantity.from_str("0.00000001")
+    assert instrument.min_notional == Money(5000, KRW)
+    assert instrument.max_notional == Money(1_000_000_000, KRW)
+    assert instrument.maker_fee == Decimal("0.0005")
+    assert instrument.taker_fee == Decimal("0.0005")
+
+
+def test_parse_low_priced_krw_market_to_instrument() -> None:
+    # Arrange
+    market = _market("KRW-XRP")
+
+    # Act
+    instrument = market.parse_to_instrument(
+        base_currency=XRP,
+        quote_currency=KRW,
+        last_price=Decimal("800"),
+        ts_event=0,
+        ts_init=0,
+    )
+
+    # Assert
+    assert instrument.price_precision == 1
+    assert instrument.price_increment == Price.from_str("1.0")
+    assert instrument.make_price(Decimal("79.9")) == Price.from_str("79.9")
+
+
+def test_parse_btc_market_to_instrument() -> None:
+    # Arrange
+    market = _market("BTC-ETH")
+
+    # Act
+    instrument = market.parse_to_instrument(
+        base_currency=ETH,
+        quote_currency=BTC,
+        last_price=Decimal("0.035"),
+        ts_event=0,
+        ts_init=0,
+    )
+
+    # Assert
+    assert instrument.id == InstrumentId.from_str("BTC-ETH.UPBIT")
+    assert instrument.price_precision == 8
+    assert instrument.price_increment == Price.from_str("0.00000001")
+    assert instrument.min_notional == Money(Decimal("0.00005"), BTC)
+    assert instrument.max_notional is None
+    assert instrument.taker_fee == Decimal("0.0025")
+
+
+def test_parse_unsupported_quote_market_raises() -> None:
+    # Arrange
+    market = _market("USDT-BTC")
+
+    # Act, Assert
+    with pytest.raises(ValueError):
+        market.parse_to_instrument(
+            base_currency=BTC,
+            quote_currency=USDT,
+            last_price=Decimal("100000"),
+            ts_event=0,
+            ts_init=0,
+        )
+
+
+def test_decode_ticker_with_large_krw_turnover() -> None:
+    # Arrange
+    raw = (
+        b'{"market":"KRW-BTC","trade_price":140123000.0,"timestamp":1735689600000,'
+        b'"acc_trade_price_24h":312345678901234.5678,"acc_trade_volume_24h":2234.5}'
+    )
+
+    # Act
+    ticker = msgspec.json.decode(raw, type=UpbitTicker)
+
+    # Assert
+    assert ticker.last_price == Decimal("140123000.0")
+    assert ticker.acc_trade_price_24h == pytest.approx(312345678901234.5678)
+
+
+ORDERBOOK_RAW = (
+    b'{"type":"orderbook","code":"KRW-BTC","timestamp":1735689600123,'
+    b'"total_ask_size":1.5,"total_bid_size":2.0,"orderbook_units":['
+    b'{"ask_price":140001000.0,"bid_price":140000000.0,"ask_size":0.5,"bid_size":1.25},'
+    b'{"ask_price":140002000.0,"bid_price":139999000.0,"ask_size":1.0,"bid_size":0.0}'
+    b'],"stream_type":"REALTIME"}'
+)
+
+
+def test_parse_orderbook_to_snapshot_deltas() -> None:
+    # Arrange
+    msg = msgspec.json.decode(ORDERBOOK_RAW, type=UpbitWsOrderbookMsg)
+
+    # Act
+    deltas = msg.parse_to_deltas(
+        instrument_id=KRW_BTC,
+        price_precision=0,
+        size_precision=8,
+        ts_init=1,
+    )
+
+    # Assert
+    assert len(deltas.deltas) == 4  # Clear and three non-empty levels
+    assert deltas.deltas[0].action == BookAction.CLEAR
+    assert [delta.order.side for delta in deltas.deltas[1:]] == [
+        OrderSide.BUY,
+        OrderSide.SELL,
+        OrderSide.SELL,
+    ]
+    assert deltas.deltas[1].order.price == Price.from_str("140000000")
+    assert deltas.deltas[1].order.size == Quantity.from_str("1.25000000")
+    assert deltas.deltas[-1].order.price == Price.from_str("140002000")
+    assert deltas.deltas[-1].flags == RecordFlag.F_SNAPSHOT | RecordFlag.F_LAST
+    assert deltas.deltas[1].ts_event == 1735689600123000000
+
+
+def test_parse_orderbook_to_quote_tick() -> None:
+    # Arrange
+    msg = msgspec.json.decode(ORDERBOOK_RAW, type=UpbitWsOrderbookMsg)
+
+    # Act
+    quote = msg.parse_to_quote_tick(
+        instrument_id=KRW_BTC,
+        price_precision=0,
+        size_precision=8,
+        ts_init=1,
+    )
+
+    # Assert
+    assert quote is not None
+    assert quote.bid_price == Price.from_str("140000000")
+    assert quote.ask_price == Price.from_str("140001000")
+    assert quote.bid_size == Quantity.from_str("1.25000000")
+    assert quote.ask_size == Quantity.from_str("0.50000000")
+
+
+def test_parse_trade_to_trade_tick() -> None:
+    # Arrange
+    raw = (
+        b'{"type":"trade","code":"KRW-BTC","timestamp":1735689600200,'
+        b'"trade_date":"2025-01-01","trade_time":"00:00:00","trade_timestamp":1735689600150,'
+        b'"trade_price":140001000.0,"trade_volume":0.0123,"ask_bid":"BID",'
+        b'"prev_closing_price":139000000.0,"change":"RISE","change_price":1001000.0,'
+        b'"sequential_id":1735689600150000,"stream_type":"REALTIME"}'
+    )
+    msg = msgspec.json.decode(raw, type=UpbitWsTradeMsg)
+
+    # Act
+    trade = msg.parse_to_trade_tick(
+        instrument_id=KRW_BTC,
+        price_precision=0,
+        size_precision=8,
+        ts_init=1,
+    )
+
+    # Assert
+    assert trade.price == Price.from_str("140001000")
+    assert trade.size == Quantity.from_str("0.01230000")
+    assert trade.aggressor_side == AggressorSide.BUYER
+    assert trade.trade_id.value == "1735689600150000"
+    assert trade.ts_event == 1735689600150000000